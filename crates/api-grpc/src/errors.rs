@@ -0,0 +1,24 @@
+use ferrous_dns_domain::DomainError;
+use tonic::Status;
+
+/// Maps a `DomainError` to the gRPC status that mirrors the REST status code.
+pub fn status_from_domain(err: DomainError) -> Status {
+    let message = err.to_string();
+    match err {
+        DomainError::NotFound(_)
+        | DomainError::BlocklistSourceNotFound(_)
+        | DomainError::GroupNotFound(_) => Status::not_found(message),
+
+        DomainError::InvalidInput(_)
+        | DomainError::InvalidBlocklistSource(_)
+        | DomainError::ConfigError(_) => Status::invalid_argument(message),
+
+        DomainError::InvalidCredentials | DomainError::AuthRequired => {
+            Status::unauthenticated(message)
+        }
+
+        DomainError::InsufficientPermissions => Status::permission_denied(message),
+
+        _ => Status::internal(message),
+    }
+}
@@ -0,0 +1,118 @@
+use ferrous_dns_domain::{BlocklistSource, DomainError};
+use tonic::{Request, Response, Status};
+
+use crate::{errors::status_from_domain, pb, state::GrpcAppState};
+
+/// Group assigned when a create request names none, matching the REST API.
+const DEFAULT_GROUP_ID: i64 = 1;
+
+pub async fn list_blocklist_sources(
+    state: &GrpcAppState,
+    _request: Request<pb::ListBlocklistSourcesRequest>,
+) -> Result<Response<pb::ListBlocklistSourcesResponse>, Status> {
+    let sources = state
+        .get_blocklist_sources
+        .get_all()
+        .await
+        .map_err(status_from_domain)?;
+
+    Ok(Response::new(pb::ListBlocklistSourcesResponse {
+        sources: sources.into_iter().map(to_message).collect(),
+    }))
+}
+
+pub async fn get_blocklist_source(
+    state: &GrpcAppState,
+    request: Request<pb::GetBlocklistSourceRequest>,
+) -> Result<Response<pb::BlocklistSource>, Status> {
+    let id = request.into_inner().id;
+    let source = state
+        .get_blocklist_sources
+        .get_by_id(id)
+        .await
+        .map_err(status_from_domain)?
+        .ok_or_else(|| status_from_domain(DomainError::BlocklistSourceNotFound(id)))?;
+
+    Ok(Response::new(to_message(source)))
+}
+
+pub async fn create_blocklist_source(
+    state: &GrpcAppState,
+    request: Request<pb::CreateBlocklistSourceRequest>,
+) -> Result<Response<pb::BlocklistSource>, Status> {
+    let req = request.into_inner();
+    let group_ids = if req.group_ids.is_empty() {
+        vec![DEFAULT_GROUP_ID]
+    } else {
+        req.group_ids
+    };
+
+    let source = state
+        .create_blocklist_source
+        .execute(
+            req.name,
+            req.url,
+            group_ids,
+            req.comment,
+            req.enabled.unwrap_or(true),
+        )
+        .await
+        .map_err(status_from_domain)?;
+
+    Ok(Response::new(to_message(source)))
+}
+
+pub async fn update_blocklist_source(
+    state: &GrpcAppState,
+    request: Request<pb::UpdateBlocklistSourceRequest>,
+) -> Result<Response<pb::BlocklistSource>, Status> {
+    let req = request.into_inner();
+    let url = req.url.map(|u| if u.is_empty() { None } else { Some(u) });
+    let group_ids = if req.group_ids.is_empty() {
+        None
+    } else {
+        Some(req.group_ids)
+    };
+
+    let source = state
+        .update_blocklist_source
+        .execute(
+            req.id,
+            req.name,
+            url,
+            group_ids,
+            req.comment,
+            req.enabled,
+            None,
+        )
+        .await
+        .map_err(status_from_domain)?;
+
+    Ok(Response::new(to_message(source)))
+}
+
+pub async fn delete_blocklist_source(
+    state: &GrpcAppState,
+    request: Request<pb::DeleteBlocklistSourceRequest>,
+) -> Result<Response<pb::DeleteBlocklistSourceResponse>, Status> {
+    state
+        .delete_blocklist_source
+        .execute(request.into_inner().id)
+        .await
+        .map_err(status_from_domain)?;
+
+    Ok(Response::new(pb::DeleteBlocklistSourceResponse {}))
+}
+
+fn to_message(source: BlocklistSource) -> pb::BlocklistSource {
+    pb::BlocklistSource {
+        id: source.id.unwrap_or_default(),
+        name: source.name.to_string(),
+        url: source.url.map(|u| u.to_string()),
+        group_ids: source.group_ids,
+        comment: source.comment.map(|c| c.to_string()),
+        enabled: source.enabled,
+        created_at: source.created_at,
+        updated_at: source.updated_at,
+    }
+}
@@ -0,0 +1,87 @@
+use ferrous_dns_domain::Config;
+use tonic::{Request, Response, Status};
+use tracing::{error, info};
+
+use crate::{pb, state::GrpcAppState};
+
+pub async fn get_config(
+    state: &GrpcAppState,
+    _request: Request<pb::GetConfigRequest>,
+) -> Result<Response<pb::ConfigSettings>, Status> {
+    let config = state.config.read().await;
+    Ok(Response::new(to_settings(&config)))
+}
+
+/// Applies the set fields, validates the result and saves it to the config
+/// file before swapping it into the shared config, so a failed save leaves
+/// the running config untouched.
+pub async fn update_config(
+    state: &GrpcAppState,
+    request: Request<pb::UpdateConfigRequest>,
+) -> Result<Response<pb::ConfigSettings>, Status> {
+    let req = request.into_inner();
+    let config_path = state
+        .resolve_config_path()
+        .ok_or_else(|| Status::failed_precondition("No config file found"))?;
+
+    let mut new_config = state.config.read().await.clone();
+    if let Some(enabled) = req.blocking_enabled {
+        new_config.blocking.enabled = enabled;
+    }
+    if let Some(enabled) = req.cache_enabled {
+        new_config.dns.cache_enabled = enabled;
+    }
+    if let Some(enabled) = req.dnssec_enabled {
+        new_config.dns.dnssec_enabled = enabled;
+    }
+    if let Some(block) = req.block_non_fqdn {
+        new_config.dns.block_non_fqdn = block;
+    }
+    if let Some(block) = req.block_private_ptr {
+        new_config.dns.block_private_ptr = block;
+    }
+    if let Some(local_domain) = req.local_domain {
+        new_config.dns.local_domain = if local_domain.is_empty() {
+            None
+        } else {
+            Some(local_domain)
+        };
+    }
+
+    let errors = new_config.field_errors();
+    if !errors.is_empty() {
+        let errors: Vec<String> = errors.iter().map(ToString::to_string).collect();
+        return Err(Status::invalid_argument(format!(
+            "Invalid configuration: {}",
+            errors.join("; ")
+        )));
+    }
+    new_config
+        .validate()
+        .map_err(|e| Status::invalid_argument(format!("Config validation error: {}", e)))?;
+
+    state
+        .config_file_persistence
+        .save_config_to_file(&new_config, &config_path)
+        .map_err(|e| {
+            error!(error = %e, "Failed to save configuration");
+            Status::internal(format!("Failed to save configuration: {}", e))
+        })?;
+
+    let settings = to_settings(&new_config);
+    *state.config.write().await = new_config;
+    info!("Configuration updated via gRPC");
+
+    Ok(Response::new(settings))
+}
+
+fn to_settings(config: &Config) -> pb::ConfigSettings {
+    pb::ConfigSettings {
+        blocking_enabled: config.blocking.enabled,
+        cache_enabled: config.dns.cache_enabled,
+        dnssec_enabled: config.dns.dnssec_enabled,
+        block_non_fqdn: config.dns.block_non_fqdn,
+        block_private_ptr: config.dns.block_private_ptr,
+        local_domain: config.dns.local_domain.clone(),
+    }
+}
@@ -0,0 +1,4 @@
+pub mod blocklist_sources;
+pub mod config;
+pub mod queries;
+pub mod stats;
@@ -0,0 +1,109 @@
+use ferrous_dns_domain::query_log::QueryLog;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{Request, Response, Status};
+use tracing::{debug, warn};
+
+use crate::{errors::status_from_domain, pb, state::GrpcAppState};
+
+/// Window scanned for new entries on every poll.
+const STREAM_PERIOD_HOURS: f32 = 1.0;
+/// Upper bound on entries fetched per poll and on the initial backlog.
+const STREAM_BATCH_LIMIT: u32 = 1_000;
+const STREAM_CHANNEL_CAPACITY: usize = 256;
+
+pub type QueryStream = ReceiverStream<Result<pb::QueryLogEntry, Status>>;
+
+/// Sends the requested backlog, then every query log entry recorded after it,
+/// oldest first. The poll task exits as soon as the client disconnects.
+pub async fn stream_queries(
+    state: &GrpcAppState,
+    request: Request<pb::StreamQueriesRequest>,
+) -> Result<Response<QueryStream>, Status> {
+    let backlog = request.into_inner().backlog.min(STREAM_BATCH_LIMIT);
+
+    // The log is ordered by second-resolution timestamps, so fetch a full
+    // batch and pick the backlog by id rather than trusting the row order.
+    let initial = state
+        .get_recent_queries
+        .execute(STREAM_BATCH_LIMIT, STREAM_PERIOD_HOURS)
+        .await
+        .map_err(status_from_domain)?;
+    let mut last_id = initial.iter().filter_map(|q| q.id).max().unwrap_or(0);
+
+    let (tx, rx) = mpsc::channel(STREAM_CHANNEL_CAPACITY);
+    let mut backlog_entries = oldest_first(initial);
+    backlog_entries.drain(..backlog_entries.len().saturating_sub(backlog as usize));
+
+    let get_recent_queries = state.get_recent_queries.clone();
+    let poll_interval = state.stream_poll_interval;
+    tokio::spawn(async move {
+        for entry in backlog_entries {
+            if tx.send(Ok(entry)).await.is_err() {
+                return;
+            }
+        }
+
+        let mut ticker = tokio::time::interval(poll_interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        loop {
+            tokio::select! {
+                _ = tx.closed() => break,
+                _ = ticker.tick() => {}
+            }
+
+            let recent = match get_recent_queries
+                .execute(STREAM_BATCH_LIMIT, STREAM_PERIOD_HOURS)
+                .await
+            {
+                Ok(recent) => recent,
+                Err(e) => {
+                    warn!(error = %e, "Query stream poll failed");
+                    continue;
+                }
+            };
+
+            let fresh: Vec<QueryLog> = recent
+                .into_iter()
+                .filter(|q| q.id.is_some_and(|id| id > last_id))
+                .collect();
+            if let Some(max_id) = fresh.iter().filter_map(|q| q.id).max() {
+                last_id = max_id;
+            }
+
+            for entry in oldest_first(fresh) {
+                if tx.send(Ok(entry)).await.is_err() {
+                    debug!("Query stream client disconnected");
+                    return;
+                }
+            }
+        }
+    });
+
+    Ok(Response::new(ReceiverStream::new(rx)))
+}
+
+fn oldest_first(mut queries: Vec<QueryLog>) -> Vec<pb::QueryLogEntry> {
+    queries.sort_by_key(|q| q.id);
+    queries.into_iter().map(to_entry).collect()
+}
+
+fn to_entry(query: QueryLog) -> pb::QueryLogEntry {
+    pb::QueryLogEntry {
+        id: query.id.unwrap_or_default(),
+        domain: query.domain.to_string(),
+        record_type: query.record_type.as_str().to_string(),
+        client_ip: query.client_ip.to_string(),
+        client_hostname: query.client_hostname.map(|h| h.to_string()),
+        blocked: query.blocked,
+        response_time_us: query.response_time_us,
+        cache_hit: query.cache_hit,
+        upstream_server: query.upstream_server.map(|s| s.to_string()),
+        response_status: query.response_status.map(String::from),
+        block_source: query.block_source.map(|s| s.to_string()),
+        query_source: query.query_source.as_str().to_string(),
+        timestamp: query.timestamp,
+        client_mac: query.client_mac.map(|m| m.to_string()),
+        group_name: query.group_name.map(|g| g.to_string()),
+    }
+}
@@ -0,0 +1,36 @@
+use tonic::{Request, Response, Status};
+
+use crate::{errors::status_from_domain, pb, state::GrpcAppState};
+
+pub const DEFAULT_PERIOD_HOURS: f32 = 24.0;
+
+pub async fn get_stats(
+    state: &GrpcAppState,
+    request: Request<pb::GetStatsRequest>,
+) -> Result<Response<pb::Stats>, Status> {
+    let period_hours = match request.into_inner().period_hours {
+        p if p > 0.0 => p,
+        _ => DEFAULT_PERIOD_HOURS,
+    };
+
+    let stats = state
+        .get_stats
+        .execute(period_hours)
+        .await
+        .map_err(status_from_domain)?;
+
+    Ok(Response::new(pb::Stats {
+        queries_total: stats.queries_total,
+        queries_blocked: stats.queries_blocked,
+        queries_rate_limited: stats.queries_rate_limited,
+        queries_any_minimized: stats.queries_any_minimized,
+        queries_malware_detected: stats.queries_malware_detected,
+        unique_clients: stats.unique_clients,
+        uptime_seconds: stats.uptime_seconds,
+        cache_hit_rate: stats.cache_hit_rate,
+        avg_query_time_ms: stats.avg_query_time_ms,
+        avg_cache_time_ms: stats.avg_cache_time_ms,
+        avg_upstream_time_ms: stats.avg_upstream_time_ms,
+        source_stats: stats.source_stats,
+    }))
+}
@@ -0,0 +1,8 @@
+pub mod errors;
+pub mod handlers;
+pub mod pb;
+pub mod service;
+pub mod state;
+
+pub use service::AdminService;
+pub use state::GrpcAppState;
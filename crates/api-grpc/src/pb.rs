@@ -0,0 +1,189 @@
+//! Message types for `proto/admin.proto` (package `ferrous.admin.v1`).
+//!
+//! Written out by hand instead of generated at build time so the workspace
+//! does not need `protoc`. Keep tags and types in sync with the proto file.
+
+use std::collections::HashMap;
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct GetStatsRequest {
+    #[prost(float, tag = "1")]
+    pub period_hours: f32,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Stats {
+    #[prost(uint64, tag = "1")]
+    pub queries_total: u64,
+    #[prost(uint64, tag = "2")]
+    pub queries_blocked: u64,
+    #[prost(uint64, tag = "3")]
+    pub queries_rate_limited: u64,
+    #[prost(uint64, tag = "4")]
+    pub queries_malware_detected: u64,
+    #[prost(uint64, tag = "5")]
+    pub unique_clients: u64,
+    #[prost(uint64, tag = "6")]
+    pub uptime_seconds: u64,
+    #[prost(double, tag = "7")]
+    pub cache_hit_rate: f64,
+    #[prost(double, tag = "8")]
+    pub avg_query_time_ms: f64,
+    #[prost(double, tag = "9")]
+    pub avg_cache_time_ms: f64,
+    #[prost(double, tag = "10")]
+    pub avg_upstream_time_ms: f64,
+    #[prost(map = "string, uint64", tag = "11")]
+    pub source_stats: HashMap<String, u64>,
+    #[prost(uint64, tag = "12")]
+    pub queries_any_minimized: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct StreamQueriesRequest {
+    #[prost(uint32, tag = "1")]
+    pub backlog: u32,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryLogEntry {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    #[prost(string, tag = "2")]
+    pub domain: String,
+    #[prost(string, tag = "3")]
+    pub record_type: String,
+    #[prost(string, tag = "4")]
+    pub client_ip: String,
+    #[prost(string, optional, tag = "5")]
+    pub client_hostname: Option<String>,
+    #[prost(bool, tag = "6")]
+    pub blocked: bool,
+    #[prost(uint64, optional, tag = "7")]
+    pub response_time_us: Option<u64>,
+    #[prost(bool, tag = "8")]
+    pub cache_hit: bool,
+    #[prost(string, optional, tag = "9")]
+    pub upstream_server: Option<String>,
+    #[prost(string, optional, tag = "10")]
+    pub response_status: Option<String>,
+    #[prost(string, optional, tag = "11")]
+    pub block_source: Option<String>,
+    #[prost(string, tag = "12")]
+    pub query_source: String,
+    #[prost(string, optional, tag = "13")]
+    pub timestamp: Option<String>,
+    #[prost(string, optional, tag = "14")]
+    pub client_mac: Option<String>,
+    #[prost(string, optional, tag = "15")]
+    pub group_name: Option<String>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct BlocklistSource {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    #[prost(string, tag = "2")]
+    pub name: String,
+    #[prost(string, optional, tag = "3")]
+    pub url: Option<String>,
+    #[prost(int64, repeated, tag = "4")]
+    pub group_ids: Vec<i64>,
+    #[prost(string, optional, tag = "5")]
+    pub comment: Option<String>,
+    #[prost(bool, tag = "6")]
+    pub enabled: bool,
+    #[prost(string, optional, tag = "7")]
+    pub created_at: Option<String>,
+    #[prost(string, optional, tag = "8")]
+    pub updated_at: Option<String>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ListBlocklistSourcesRequest {}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ListBlocklistSourcesResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub sources: Vec<BlocklistSource>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct GetBlocklistSourceRequest {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct CreateBlocklistSourceRequest {
+    #[prost(string, tag = "1")]
+    pub name: String,
+    #[prost(string, optional, tag = "2")]
+    pub url: Option<String>,
+    #[prost(int64, repeated, tag = "3")]
+    pub group_ids: Vec<i64>,
+    #[prost(string, optional, tag = "4")]
+    pub comment: Option<String>,
+    #[prost(bool, optional, tag = "5")]
+    pub enabled: Option<bool>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct UpdateBlocklistSourceRequest {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    #[prost(string, optional, tag = "2")]
+    pub name: Option<String>,
+    #[prost(string, optional, tag = "3")]
+    pub url: Option<String>,
+    #[prost(int64, repeated, tag = "4")]
+    pub group_ids: Vec<i64>,
+    #[prost(string, optional, tag = "5")]
+    pub comment: Option<String>,
+    #[prost(bool, optional, tag = "6")]
+    pub enabled: Option<bool>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct DeleteBlocklistSourceRequest {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct DeleteBlocklistSourceResponse {}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct GetConfigRequest {}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ConfigSettings {
+    #[prost(bool, tag = "1")]
+    pub blocking_enabled: bool,
+    #[prost(bool, tag = "2")]
+    pub cache_enabled: bool,
+    #[prost(bool, tag = "3")]
+    pub dnssec_enabled: bool,
+    #[prost(bool, tag = "4")]
+    pub block_non_fqdn: bool,
+    #[prost(bool, tag = "5")]
+    pub block_private_ptr: bool,
+    #[prost(string, optional, tag = "6")]
+    pub local_domain: Option<String>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct UpdateConfigRequest {
+    #[prost(bool, optional, tag = "1")]
+    pub blocking_enabled: Option<bool>,
+    #[prost(bool, optional, tag = "2")]
+    pub cache_enabled: Option<bool>,
+    #[prost(bool, optional, tag = "3")]
+    pub dnssec_enabled: Option<bool>,
+    #[prost(bool, optional, tag = "4")]
+    pub block_non_fqdn: Option<bool>,
+    #[prost(bool, optional, tag = "5")]
+    pub block_private_ptr: Option<bool>,
+    #[prost(string, optional, tag = "6")]
+    pub local_domain: Option<String>,
+}
@@ -0,0 +1,133 @@
+use std::convert::Infallible;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use tonic::body::Body;
+use tonic::codegen::{http, Body as HttpBody, Service, StdError};
+use tonic::server::{Grpc, NamedService};
+use tonic::{Request, Status};
+use tonic_prost::ProstCodec;
+
+use crate::handlers::{blocklist_sources, config, queries, stats};
+use crate::state::GrpcAppState;
+
+/// Metadata key carrying the API token, same header as the REST API.
+pub const API_KEY_METADATA: &str = "x-api-key";
+
+type ResponseFuture =
+    Pin<Box<dyn Future<Output = Result<http::Response<Body>, Infallible>> + Send>>;
+
+/// Tower service for `ferrous.admin.v1.Admin`.
+///
+/// Routes each request on its `/<service>/<method>` path to the matching
+/// handler and rejects unknown methods with `UNIMPLEMENTED`. When auth is
+/// enabled, the `x-api-key` metadata entry is checked before dispatch.
+#[derive(Clone)]
+pub struct AdminService {
+    state: GrpcAppState,
+}
+
+impl AdminService {
+    pub fn new(state: GrpcAppState) -> Self {
+        Self { state }
+    }
+}
+
+impl NamedService for AdminService {
+    const NAME: &'static str = "ferrous.admin.v1.Admin";
+}
+
+macro_rules! unary {
+    ($state:expr, $req:expr, $handler:path) => {{
+        let state = $state;
+        let svc = tower::service_fn(move |request: Request<_>| {
+            let state = state.clone();
+            async move { $handler(&state, request).await }
+        });
+        Grpc::new(ProstCodec::default()).unary(svc, $req).await
+    }};
+}
+
+impl<B> Service<http::Request<B>> for AdminService
+where
+    B: HttpBody + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<Body>;
+    type Error = Infallible;
+    type Future = ResponseFuture;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: http::Request<B>) -> Self::Future {
+        let state = self.state.clone();
+        let api_key = req
+            .headers()
+            .get(API_KEY_METADATA)
+            .and_then(|v| v.to_str().ok())
+            .map(String::from);
+
+        Box::pin(async move {
+            if let Err(status) = authorize(&state, api_key.as_deref()).await {
+                return Ok(status.into_http());
+            }
+
+            let response = match req.uri().path() {
+                "/ferrous.admin.v1.Admin/GetStats" => unary!(state, req, stats::get_stats),
+                "/ferrous.admin.v1.Admin/StreamQueries" => {
+                    let svc = tower::service_fn(move |request: Request<_>| {
+                        let state = state.clone();
+                        async move { queries::stream_queries(&state, request).await }
+                    });
+                    Grpc::new(ProstCodec::default())
+                        .server_streaming(svc, req)
+                        .await
+                }
+                "/ferrous.admin.v1.Admin/ListBlocklistSources" => {
+                    unary!(state, req, blocklist_sources::list_blocklist_sources)
+                }
+                "/ferrous.admin.v1.Admin/GetBlocklistSource" => {
+                    unary!(state, req, blocklist_sources::get_blocklist_source)
+                }
+                "/ferrous.admin.v1.Admin/CreateBlocklistSource" => {
+                    unary!(state, req, blocklist_sources::create_blocklist_source)
+                }
+                "/ferrous.admin.v1.Admin/UpdateBlocklistSource" => {
+                    unary!(state, req, blocklist_sources::update_blocklist_source)
+                }
+                "/ferrous.admin.v1.Admin/DeleteBlocklistSource" => {
+                    unary!(state, req, blocklist_sources::delete_blocklist_source)
+                }
+                "/ferrous.admin.v1.Admin/GetConfig" => unary!(state, req, config::get_config),
+                "/ferrous.admin.v1.Admin/UpdateConfig" => {
+                    unary!(state, req, config::update_config)
+                }
+                _ => Status::unimplemented("Unknown method").into_http(),
+            };
+            Ok(response)
+        })
+    }
+}
+
+/// The admin service is global, so tenant-scoped tokens are refused.
+async fn authorize(state: &GrpcAppState, api_key: Option<&str>) -> Result<(), Status> {
+    if !state.config.read().await.auth.enabled {
+        return Ok(());
+    }
+
+    let token = api_key.ok_or_else(|| Status::unauthenticated("Missing x-api-key metadata"))?;
+    let api_token = state
+        .validate_api_token
+        .authenticate(token)
+        .await
+        .map_err(|_| Status::unauthenticated("Invalid API token"))?;
+    if api_token.tenant_id.is_some() {
+        return Err(Status::permission_denied(
+            "Tenant-scoped API tokens cannot use the admin service",
+        ));
+    }
+    Ok(())
+}
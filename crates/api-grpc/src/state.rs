@@ -0,0 +1,40 @@
+use ferrous_dns_application::ports::ConfigFilePersistence;
+use ferrous_dns_application::use_cases::{
+    CreateBlocklistSourceUseCase, DeleteBlocklistSourceUseCase, GetBlocklistSourcesUseCase,
+    GetQueryStatsUseCase, GetRecentQueriesUseCase, UpdateBlocklistSourceUseCase,
+    ValidateApiTokenUseCase,
+};
+use ferrous_dns_domain::Config;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+/// Shared state for the gRPC admin service.
+///
+/// Holds the same `Arc` use cases as the REST `AppState`; the gRPC layer
+/// only translates between protobuf messages and domain types.
+#[derive(Clone)]
+pub struct GrpcAppState {
+    pub get_stats: Arc<GetQueryStatsUseCase>,
+    pub get_recent_queries: Arc<GetRecentQueriesUseCase>,
+    pub get_blocklist_sources: Arc<GetBlocklistSourcesUseCase>,
+    pub create_blocklist_source: Arc<CreateBlocklistSourceUseCase>,
+    pub update_blocklist_source: Arc<UpdateBlocklistSourceUseCase>,
+    pub delete_blocklist_source: Arc<DeleteBlocklistSourceUseCase>,
+    pub validate_api_token: Arc<ValidateApiTokenUseCase>,
+    pub config: Arc<RwLock<Config>>,
+    pub config_file_persistence: Arc<dyn ConfigFilePersistence>,
+    pub config_path: Option<Arc<str>>,
+    /// How often `StreamQueries` polls the query log for new entries.
+    pub stream_poll_interval: Duration,
+}
+
+impl GrpcAppState {
+    /// Resolves the effective config file path: explicit CLI path first, then auto-discovery.
+    pub fn resolve_config_path(&self) -> Option<String> {
+        self.config_path
+            .as_deref()
+            .map(String::from)
+            .or_else(Config::get_config_path)
+    }
+}
@@ -0,0 +1,105 @@
+mod helpers;
+
+use ferrous_dns_api_grpc::handlers::blocklist_sources::{
+    create_blocklist_source, delete_blocklist_source, get_blocklist_source, list_blocklist_sources,
+    update_blocklist_source,
+};
+use ferrous_dns_api_grpc::pb;
+use tonic::{Code, Request};
+
+fn create_request(name: &str) -> Request<pb::CreateBlocklistSourceRequest> {
+    Request::new(pb::CreateBlocklistSourceRequest {
+        name: name.to_string(),
+        url: Some("https://example.com/hosts.txt".to_string()),
+        ..Default::default()
+    })
+}
+
+#[tokio::test]
+async fn create_defaults_to_protected_group_and_enabled() {
+    let ctx = helpers::create_test_context().await;
+
+    let source = create_blocklist_source(&ctx.state, create_request("StevenBlack"))
+        .await
+        .expect("CreateBlocklistSource failed")
+        .into_inner();
+
+    assert!(source.id > 0);
+    assert_eq!(source.group_ids, vec![1]);
+    assert!(source.enabled);
+
+    let listed =
+        list_blocklist_sources(&ctx.state, Request::new(pb::ListBlocklistSourcesRequest {}))
+            .await
+            .expect("ListBlocklistSources failed")
+            .into_inner();
+    assert!(listed.sources.iter().any(|s| s.name == "StevenBlack"));
+}
+
+#[tokio::test]
+async fn update_with_empty_url_clears_it() {
+    let ctx = helpers::create_test_context().await;
+    let created = create_blocklist_source(&ctx.state, create_request("Ads"))
+        .await
+        .expect("CreateBlocklistSource failed")
+        .into_inner();
+
+    let updated = update_blocklist_source(
+        &ctx.state,
+        Request::new(pb::UpdateBlocklistSourceRequest {
+            id: created.id,
+            url: Some(String::new()),
+            enabled: Some(false),
+            ..Default::default()
+        }),
+    )
+    .await
+    .expect("UpdateBlocklistSource failed")
+    .into_inner();
+
+    assert_eq!(updated.url, None);
+    assert!(!updated.enabled);
+    assert_eq!(updated.group_ids, created.group_ids);
+}
+
+#[tokio::test]
+async fn deleted_source_is_not_found() {
+    let ctx = helpers::create_test_context().await;
+    let created = create_blocklist_source(&ctx.state, create_request("Temp"))
+        .await
+        .expect("CreateBlocklistSource failed")
+        .into_inner();
+
+    delete_blocklist_source(
+        &ctx.state,
+        Request::new(pb::DeleteBlocklistSourceRequest { id: created.id }),
+    )
+    .await
+    .expect("DeleteBlocklistSource failed");
+
+    let status = get_blocklist_source(
+        &ctx.state,
+        Request::new(pb::GetBlocklistSourceRequest { id: created.id }),
+    )
+    .await
+    .expect_err("source should be gone");
+    assert_eq!(status.code(), Code::NotFound);
+}
+
+#[tokio::test]
+async fn create_with_unknown_group_is_not_found() {
+    let ctx = helpers::create_test_context().await;
+
+    let status = create_blocklist_source(
+        &ctx.state,
+        Request::new(pb::CreateBlocklistSourceRequest {
+            name: "Orphan".to_string(),
+            group_ids: vec![999],
+            ..Default::default()
+        }),
+    )
+    .await
+    .expect_err("unknown group should be rejected");
+
+    assert_eq!(status.code(), Code::NotFound);
+}
@@ -0,0 +1,58 @@
+mod helpers;
+
+use ferrous_dns_api_grpc::handlers::config::{get_config, update_config};
+use ferrous_dns_api_grpc::pb;
+use tonic::Request;
+
+#[tokio::test]
+async fn update_config_persists_and_applies_set_fields_only() {
+    let ctx = helpers::create_test_context().await;
+    let before = get_config(&ctx.state, Request::new(pb::GetConfigRequest {}))
+        .await
+        .expect("GetConfig failed")
+        .into_inner();
+
+    let after = update_config(
+        &ctx.state,
+        Request::new(pb::UpdateConfigRequest {
+            blocking_enabled: Some(!before.blocking_enabled),
+            local_domain: Some("lan".to_string()),
+            ..Default::default()
+        }),
+    )
+    .await
+    .expect("UpdateConfig failed")
+    .into_inner();
+
+    assert_eq!(after.blocking_enabled, !before.blocking_enabled);
+    assert_eq!(after.local_domain.as_deref(), Some("lan"));
+    assert_eq!(after.cache_enabled, before.cache_enabled);
+    assert_eq!(after.dnssec_enabled, before.dnssec_enabled);
+
+    let config = ctx.state.config.read().await;
+    assert_eq!(config.dns.local_domain.as_deref(), Some("lan"));
+
+    let saved = ctx.persistence.saved.lock().unwrap();
+    assert_eq!(saved.len(), 1);
+    assert_eq!(saved[0].0, "/tmp/ferrous-grpc-test.toml");
+    assert_eq!(saved[0].1.blocking.enabled, !before.blocking_enabled);
+}
+
+#[tokio::test]
+async fn empty_local_domain_clears_it() {
+    let ctx = helpers::create_test_context().await;
+    ctx.state.config.write().await.dns.local_domain = Some("home".to_string());
+
+    let after = update_config(
+        &ctx.state,
+        Request::new(pb::UpdateConfigRequest {
+            local_domain: Some(String::new()),
+            ..Default::default()
+        }),
+    )
+    .await
+    .expect("UpdateConfig failed")
+    .into_inner();
+
+    assert_eq!(after.local_domain, None);
+}
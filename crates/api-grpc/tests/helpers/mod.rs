@@ -0,0 +1,116 @@
+#![allow(dead_code)]
+
+use ferrous_dns_api_grpc::GrpcAppState;
+use ferrous_dns_application::ports::ConfigFilePersistence;
+use ferrous_dns_application::use_cases::{
+    CreateBlocklistSourceUseCase, DeleteBlocklistSourceUseCase, GetBlocklistSourcesUseCase,
+    GetQueryStatsUseCase, GetRecentQueriesUseCase, UpdateBlocklistSourceUseCase,
+    ValidateApiTokenUseCase,
+};
+use ferrous_dns_domain::config::DatabaseConfig;
+use ferrous_dns_domain::Config;
+use ferrous_dns_infrastructure::database::create_write_pool;
+use ferrous_dns_infrastructure::repositories::{
+    query_log_repository::SqliteQueryLogRepository, SqliteApiTokenRepository,
+    SqliteBlocklistSourceRepository, SqliteClientRepository, SqliteGroupRepository,
+};
+use sqlx::SqlitePool;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tempfile::TempDir;
+use tokio::sync::RwLock;
+
+// ---------------------------------------------------------------------------
+// Mock: ConfigFilePersistence
+// ---------------------------------------------------------------------------
+
+/// Records every save instead of writing to disk.
+#[derive(Default)]
+pub struct RecordingPersistence {
+    pub saved: Mutex<Vec<(String, Config)>>,
+}
+
+impl ConfigFilePersistence for RecordingPersistence {
+    fn save_config_to_file(&self, config: &Config, path: &str) -> Result<(), String> {
+        self.saved
+            .lock()
+            .unwrap()
+            .push((path.to_string(), config.clone()));
+        Ok(())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Test DB + state builder
+// ---------------------------------------------------------------------------
+
+pub struct TestContext {
+    pub state: GrpcAppState,
+    pub pool: SqlitePool,
+    pub persistence: Arc<RecordingPersistence>,
+    _dir: TempDir,
+}
+
+pub async fn create_test_context() -> TestContext {
+    let dir = tempfile::tempdir().expect("failed to create temp dir");
+    let url = format!("sqlite:{}", dir.path().join("ferrous.db").display());
+    let db_config = DatabaseConfig::default();
+    let pool = create_write_pool(&url, &db_config)
+        .await
+        .expect("failed to create migrated database");
+
+    let query_log_repo = Arc::new(SqliteQueryLogRepository::new(
+        pool.clone(),
+        pool.clone(),
+        pool.clone(),
+        &db_config,
+    ));
+    let client_repo = Arc::new(SqliteClientRepository::new(pool.clone(), &db_config));
+    let group_repo = Arc::new(SqliteGroupRepository::new(pool.clone()));
+    let blocklist_source_repo = Arc::new(SqliteBlocklistSourceRepository::new(pool.clone()));
+    let api_token_repo = Arc::new(SqliteApiTokenRepository::new(Arc::new(pool.clone())));
+    let persistence = Arc::new(RecordingPersistence::default());
+    let mut config = Config::default();
+    config.auth.enabled = false;
+
+    let state = GrpcAppState {
+        get_stats: Arc::new(GetQueryStatsUseCase::new(
+            query_log_repo.clone(),
+            client_repo,
+        )),
+        get_recent_queries: Arc::new(GetRecentQueriesUseCase::new(query_log_repo)),
+        get_blocklist_sources: Arc::new(GetBlocklistSourcesUseCase::new(
+            blocklist_source_repo.clone(),
+        )),
+        create_blocklist_source: Arc::new(CreateBlocklistSourceUseCase::new(
+            blocklist_source_repo.clone(),
+            group_repo.clone(),
+        )),
+        update_blocklist_source: Arc::new(UpdateBlocklistSourceUseCase::new(
+            blocklist_source_repo.clone(),
+            group_repo,
+        )),
+        delete_blocklist_source: Arc::new(DeleteBlocklistSourceUseCase::new(blocklist_source_repo)),
+        validate_api_token: Arc::new(ValidateApiTokenUseCase::new(api_token_repo)),
+        config: Arc::new(RwLock::new(config)),
+        config_file_persistence: persistence.clone(),
+        config_path: Some(Arc::from("/tmp/ferrous-grpc-test.toml")),
+        stream_poll_interval: Duration::from_millis(20),
+    };
+
+    TestContext {
+        state,
+        pool,
+        persistence,
+        _dir: dir,
+    }
+}
+
+pub async fn insert_query(pool: &SqlitePool, domain: &str, blocked: bool) {
+    sqlx::query("INSERT INTO query_log (domain, record_type, client_ip, blocked, response_time_ms) VALUES (?, 'A', '192.168.1.10', ?, 1)")
+        .bind(domain)
+        .bind(blocked)
+        .execute(pool)
+        .await
+        .expect("failed to insert query log row");
+}
@@ -0,0 +1,53 @@
+mod helpers;
+
+use ferrous_dns_api_grpc::handlers::queries::stream_queries;
+use ferrous_dns_api_grpc::pb;
+use std::time::Duration;
+use tokio_stream::StreamExt;
+use tonic::Request;
+
+async fn next_domain(stream: &mut ferrous_dns_api_grpc::handlers::queries::QueryStream) -> String {
+    tokio::time::timeout(Duration::from_secs(5), stream.next())
+        .await
+        .expect("timed out waiting for stream entry")
+        .expect("stream ended")
+        .expect("stream returned an error")
+        .domain
+}
+
+#[tokio::test]
+async fn stream_sends_backlog_oldest_first() {
+    let ctx = helpers::create_test_context().await;
+    helpers::insert_query(&ctx.pool, "first.example", false).await;
+    helpers::insert_query(&ctx.pool, "second.example", false).await;
+    helpers::insert_query(&ctx.pool, "third.example", false).await;
+
+    let mut stream = stream_queries(
+        &ctx.state,
+        Request::new(pb::StreamQueriesRequest { backlog: 2 }),
+    )
+    .await
+    .expect("StreamQueries failed")
+    .into_inner();
+
+    assert_eq!(next_domain(&mut stream).await, "second.example");
+    assert_eq!(next_domain(&mut stream).await, "third.example");
+}
+
+#[tokio::test]
+async fn stream_delivers_queries_logged_after_subscribe() {
+    let ctx = helpers::create_test_context().await;
+    helpers::insert_query(&ctx.pool, "before.example", false).await;
+
+    let mut stream = stream_queries(
+        &ctx.state,
+        Request::new(pb::StreamQueriesRequest { backlog: 0 }),
+    )
+    .await
+    .expect("StreamQueries failed")
+    .into_inner();
+
+    helpers::insert_query(&ctx.pool, "after.example", true).await;
+
+    assert_eq!(next_domain(&mut stream).await, "after.example");
+}
@@ -0,0 +1,69 @@
+mod helpers;
+
+use ferrous_dns_api_grpc::AdminService;
+use tonic::body::Body;
+use tonic::codegen::{http, Service};
+use tonic::Code;
+
+fn grpc_status(response: &http::Response<Body>) -> i32 {
+    response
+        .headers()
+        .get("grpc-status")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse().ok())
+        .expect("missing grpc-status header")
+}
+
+fn request(path: &str, api_key: Option<&str>) -> http::Request<Body> {
+    let mut builder = http::Request::builder()
+        .method("POST")
+        .uri(path)
+        .header("content-type", "application/grpc");
+    if let Some(key) = api_key {
+        builder = builder.header("x-api-key", key);
+    }
+    builder
+        .body(Body::empty())
+        .expect("failed to build request")
+}
+
+#[tokio::test]
+async fn unknown_method_is_unimplemented() {
+    let ctx = helpers::create_test_context().await;
+    let mut service = AdminService::new(ctx.state);
+
+    let response = service
+        .call(request("/ferrous.admin.v1.Admin/Nope", None))
+        .await
+        .unwrap();
+
+    assert_eq!(grpc_status(&response), Code::Unimplemented as i32);
+}
+
+#[tokio::test]
+async fn missing_api_key_is_unauthenticated_when_auth_enabled() {
+    let ctx = helpers::create_test_context().await;
+    ctx.state.config.write().await.auth.enabled = true;
+    let mut service = AdminService::new(ctx.state);
+
+    let response = service
+        .call(request("/ferrous.admin.v1.Admin/GetStats", None))
+        .await
+        .unwrap();
+
+    assert_eq!(grpc_status(&response), Code::Unauthenticated as i32);
+}
+
+#[tokio::test]
+async fn invalid_api_key_is_unauthenticated_when_auth_enabled() {
+    let ctx = helpers::create_test_context().await;
+    ctx.state.config.write().await.auth.enabled = true;
+    let mut service = AdminService::new(ctx.state);
+
+    let response = service
+        .call(request("/ferrous.admin.v1.Admin/GetStats", Some("bogus")))
+        .await
+        .unwrap();
+
+    assert_eq!(grpc_status(&response), Code::Unauthenticated as i32);
+}
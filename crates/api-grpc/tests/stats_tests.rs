@@ -0,0 +1,36 @@
+mod helpers;
+
+use ferrous_dns_api_grpc::handlers::stats::get_stats;
+use ferrous_dns_api_grpc::pb;
+use tonic::Request;
+
+#[tokio::test]
+async fn get_stats_returns_zero_counts_for_empty_log() {
+    let ctx = helpers::create_test_context().await;
+
+    let stats = get_stats(&ctx.state, Request::new(pb::GetStatsRequest::default()))
+        .await
+        .expect("GetStats failed")
+        .into_inner();
+
+    assert_eq!(stats.queries_total, 0);
+    assert_eq!(stats.queries_blocked, 0);
+}
+
+#[tokio::test]
+async fn get_stats_counts_logged_queries() {
+    let ctx = helpers::create_test_context().await;
+    helpers::insert_query(&ctx.pool, "example.com", false).await;
+    helpers::insert_query(&ctx.pool, "ads.example.com", true).await;
+
+    let stats = get_stats(
+        &ctx.state,
+        Request::new(pb::GetStatsRequest { period_hours: 1.0 }),
+    )
+    .await
+    .expect("GetStats failed")
+    .into_inner();
+
+    assert_eq!(stats.queries_total, 2);
+    assert_eq!(stats.queries_blocked, 1);
+}
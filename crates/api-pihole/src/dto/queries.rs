@@ -80,7 +80,7 @@ pub(crate) fn map_query_status(
     if blocked {
         match block_source {
             Some(BlockSource::RegexFilter) => "REGEX",
-            Some(BlockSource::ManagedDomain) => "DENYLIST",
+            Some(BlockSource::ManagedDomain) | Some(BlockSource::QueryPolicy) => "DENYLIST",
             Some(BlockSource::CnameCloaking) => "GRAVITY_CNAME",
             _ => "GRAVITY",
         }
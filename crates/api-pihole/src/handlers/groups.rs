@@ -90,7 +90,7 @@ pub async fn update_group(
     let result = state
         .groups
         .update_group
-        .execute(id, body.name, body.enabled, body.comment)
+        .execute(id, body.name, body.enabled, body.comment, None, None)
         .await?;
     Ok(Json(group_to_entry(&result)?))
 }
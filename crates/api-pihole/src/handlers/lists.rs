@@ -146,6 +146,7 @@ pub async fn update_list(
                 group_ids,
                 body.comment,
                 body.enabled,
+                None,
             )
             .await?;
         return Ok(Json(blocklist_to_entry(&result)?));
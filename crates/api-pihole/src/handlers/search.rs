@@ -43,7 +43,9 @@ pub async fn search_domain(
             };
             ("deny", kind, format!("{block_source:?}"), true)
         }
-        FilterDecision::Allow => ("allow", "exact", "allowed".to_string(), false),
+        FilterDecision::Audit(_) | FilterDecision::Allow => {
+            ("allow", "exact", "allowed".to_string(), false)
+        }
     };
 
     let results = vec![SearchResult {
@@ -8,7 +8,8 @@ use ferrous_dns_api_pihole::state::{
 };
 use ferrous_dns_api_pihole::{create_pihole_routes, PiholeAppState};
 use ferrous_dns_application::ports::{
-    BlockFilterEnginePort, FilterDecision, UpstreamGroupHealth, UpstreamHealthPort, UpstreamStatus,
+    BlockFilterEnginePort, FilterDecision, ResponseValidationStats, UpstreamGroupHealth,
+    UpstreamHealthPort, UpstreamRoute, UpstreamStatus,
 };
 use ferrous_dns_application::use_cases::{
     AssignClientGroupUseCase, CleanupOldQueryLogsUseCase, CreateBlocklistSourceUseCase,
@@ -51,6 +52,10 @@ impl BlockFilterEnginePort for MockBlockFilterEngine {
         1
     }
 
+    fn resolve_group_with_default(&self, _ip: IpAddr, _default_group_id: i64) -> i64 {
+        1
+    }
+
     fn check(&self, _domain: &str, _group_id: i64) -> FilterDecision {
         FilterDecision::Allow
     }
@@ -90,6 +95,14 @@ impl UpstreamHealthPort for MockUpstreamHealth {
     fn get_grouped_upstream_health(&self) -> Vec<UpstreamGroupHealth> {
         Vec::new()
     }
+
+    fn response_validation_stats(&self) -> ResponseValidationStats {
+        ResponseValidationStats::default()
+    }
+
+    fn preview_route(&self, _pool: Option<&str>) -> Option<UpstreamRoute> {
+        None
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -109,6 +122,9 @@ pub async fn create_test_db() -> sqlx::SqlitePool {
             enabled BOOLEAN NOT NULL DEFAULT 1,
             comment TEXT,
             is_default BOOLEAN NOT NULL DEFAULT 0,
+            filter_aaaa BOOLEAN NOT NULL DEFAULT 0,
+            audit_mode  BOOLEAN NOT NULL DEFAULT 0,
+            tenant_id INTEGER,
             created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
             updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
         )",
@@ -138,7 +154,10 @@ pub async fn create_test_db() -> sqlx::SqlitePool {
             last_hostname_update DATETIME,
             group_id INTEGER REFERENCES groups(id) ON DELETE SET NULL,
             created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
-            updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+            updated_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            display_name TEXT,
+            category TEXT,
+            notes TEXT
         )",
     )
     .execute(&pool)
@@ -158,10 +177,15 @@ pub async fn create_test_db() -> sqlx::SqlitePool {
             dnssec_status TEXT,
             upstream_server TEXT,
             upstream_pool TEXT,
+            upstream_strategy TEXT,
+            upstream_attempt INTEGER,
+            upstream_protocol TEXT,
             response_status TEXT,
             query_source TEXT NOT NULL DEFAULT 'client',
             group_id INTEGER,
+            tenant_id INTEGER,
             block_source TEXT,
+            plugin TEXT,
             created_at DATETIME NOT NULL DEFAULT (datetime('now'))
         )",
     )
@@ -169,6 +193,27 @@ pub async fn create_test_db() -> sqlx::SqlitePool {
     .await
     .expect("Failed to create query_log table");
 
+    sqlx::query(
+        "CREATE TABLE query_stats_rollup (
+            bucket TEXT PRIMARY KEY,
+            queries INTEGER NOT NULL DEFAULT 0,
+            blocked INTEGER NOT NULL DEFAULT 0,
+            rate_limited INTEGER NOT NULL DEFAULT 0,
+            cache_hits INTEGER NOT NULL DEFAULT 0,
+            local_dns INTEGER NOT NULL DEFAULT 0,
+            any_minimized INTEGER NOT NULL DEFAULT 0,
+            time_sum INTEGER NOT NULL DEFAULT 0,
+            cache_time_sum INTEGER NOT NULL DEFAULT 0,
+            upstream_queries INTEGER NOT NULL DEFAULT 0,
+            upstream_time_sum INTEGER NOT NULL DEFAULT 0,
+            latency_histogram BLOB,
+            last_id INTEGER NOT NULL DEFAULT 0
+        ) WITHOUT ROWID",
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create query_stats_rollup table");
+
     sqlx::query(
         "CREATE TABLE managed_domains (
             id         INTEGER PRIMARY KEY AUTOINCREMENT,
@@ -212,6 +257,8 @@ pub async fn create_test_db() -> sqlx::SqlitePool {
             group_id    INTEGER NOT NULL DEFAULT 1 REFERENCES groups(id) ON DELETE RESTRICT,
             comment     TEXT,
             enabled     BOOLEAN NOT NULL DEFAULT 1,
+            audit_mode  BOOLEAN NOT NULL DEFAULT 0,
+            tenant_id   INTEGER,
             created_at  DATETIME DEFAULT CURRENT_TIMESTAMP,
             updated_at  DATETIME DEFAULT CURRENT_TIMESTAMP
         )",
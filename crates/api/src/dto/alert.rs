@@ -0,0 +1,38 @@
+use ferrous_dns_domain::Alert;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+pub struct AlertsQuery {
+    pub limit: Option<u32>,
+    pub client: Option<String>,
+    /// `nxdomain_rate`, `subdomain_entropy` or `volume_spike`.
+    pub kind: Option<String>,
+}
+
+/// A per-client anomaly recorded by the detection job.
+#[derive(Debug, Serialize)]
+pub struct AlertResponse {
+    pub id: i64,
+    pub client: String,
+    pub kind: &'static str,
+    pub score: f64,
+    pub threshold: f64,
+    pub domain: Option<String>,
+    pub details: String,
+    pub created_at: Option<String>,
+}
+
+impl From<Alert> for AlertResponse {
+    fn from(alert: Alert) -> Self {
+        Self {
+            id: alert.id.unwrap_or(0),
+            client: alert.client_ip,
+            kind: alert.kind.as_str(),
+            score: alert.score,
+            threshold: alert.threshold,
+            domain: alert.domain,
+            details: alert.details,
+            created_at: alert.created_at,
+        }
+    }
+}
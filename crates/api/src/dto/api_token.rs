@@ -6,6 +6,9 @@ pub struct CreateApiTokenRequest {
     /// Optional custom token value (e.g. import an existing Pi-hole API key).
     /// When omitted, a secure random token is generated automatically.
     pub token: Option<String>,
+    /// Restricts the token to the `/tenants/{tenant_id}` endpoints.
+    #[serde(default)]
+    pub tenant_id: Option<i64>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -22,6 +25,8 @@ pub struct CreatedApiTokenResponse {
     pub name: String,
     pub key_prefix: String,
     pub token: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tenant_id: Option<i64>,
     pub created_at: Option<String>,
 }
 
@@ -31,6 +36,8 @@ pub struct ApiTokenResponse {
     pub name: String,
     pub key_prefix: String,
     pub token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tenant_id: Option<i64>,
     pub created_at: Option<String>,
     pub last_used_at: Option<String>,
 }
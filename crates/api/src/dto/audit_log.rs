@@ -0,0 +1,37 @@
+use ferrous_dns_domain::AuditEvent;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+pub struct AuditLogQuery {
+    pub limit: Option<u32>,
+    /// `dns_update`.
+    pub action: Option<String>,
+}
+
+/// A change recorded in the audit log, e.g. a dynamic DNS update.
+#[derive(Debug, Serialize)]
+pub struct AuditEventResponse {
+    pub id: i64,
+    pub action: &'static str,
+    pub client: String,
+    pub actor: Option<String>,
+    pub target: String,
+    pub result: String,
+    pub details: String,
+    pub created_at: Option<String>,
+}
+
+impl From<AuditEvent> for AuditEventResponse {
+    fn from(event: AuditEvent) -> Self {
+        Self {
+            id: event.id.unwrap_or(0),
+            action: event.action.as_str(),
+            client: event.client_ip,
+            actor: event.actor,
+            target: event.target,
+            result: event.result,
+            details: event.details,
+            created_at: event.created_at,
+        }
+    }
+}
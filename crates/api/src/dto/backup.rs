@@ -1,4 +1,6 @@
-use serde::Serialize;
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
 
 /// HTTP response returned after a successful import operation.
 #[derive(Debug, Clone, Serialize)]
@@ -19,3 +21,51 @@ pub struct ImportSummaryDto {
     pub local_records_imported: usize,
     pub local_records_skipped: usize,
 }
+
+#[derive(Debug, Deserialize)]
+pub struct BackupQuery {
+    /// `json` (default) or `tar.gz`.
+    pub format: Option<String>,
+}
+
+/// HTTP response returned after a full restore.
+#[derive(Debug, Clone, Serialize)]
+pub struct RestoreSummaryResponse {
+    pub success: bool,
+    pub ferrous_version: String,
+    pub exported_at: String,
+    /// Rows restored per table.
+    pub tables: BTreeMap<String, usize>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ExternalImportQuery {
+    /// `pihole` or `adguard`.
+    pub format: Option<String>,
+}
+
+/// HTTP response returned after importing a Pi-hole or AdGuard Home export.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExternalImportResponse {
+    pub success: bool,
+    pub format: &'static str,
+    pub summary: ExternalImportSummaryDto,
+    /// Entries that could not be translated and were skipped.
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExternalImportSummaryDto {
+    pub groups_imported: usize,
+    pub groups_skipped: usize,
+    pub lists_imported: usize,
+    pub lists_skipped: usize,
+    pub domains_imported: usize,
+    pub domains_skipped: usize,
+    pub regex_filters_imported: usize,
+    pub regex_filters_skipped: usize,
+    pub local_records_imported: usize,
+    pub local_records_skipped: usize,
+    pub clients_imported: usize,
+    pub clients_skipped: usize,
+}
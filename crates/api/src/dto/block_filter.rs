@@ -4,3 +4,33 @@ use serde::Serialize;
 pub struct BlockFilterStatsResponse {
     pub total_blocked_domains: usize,
 }
+
+/// Progress of the blocklist compile and the origin of the index in use.
+#[derive(Serialize, Debug, Clone)]
+pub struct BlocklistStatusResponse {
+    pub phase: &'static str,
+    pub origin: &'static str,
+    pub lists_total: usize,
+    pub lists_done: usize,
+    pub compiled_domains: usize,
+    pub built_at: Option<String>,
+    pub last_duration_ms: Option<u64>,
+    pub last_error: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct BlocklistSourceHitsResponse {
+    pub bit: u8,
+    /// `None` for the manual blocklist.
+    pub source_id: Option<i64>,
+    pub name: String,
+    pub entries: u64,
+    pub unique_blocks: u64,
+    pub redundant_blocks: u64,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct BlocklistSourceStatsResponse {
+    pub window_secs: u64,
+    pub sources: Vec<BlocklistSourceHitsResponse>,
+}
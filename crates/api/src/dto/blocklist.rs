@@ -1,27 +1,43 @@
-use serde::{Deserialize, Serialize};
+use ferrous_dns_application::use_cases::BulkAddSummary;
+use serde::Serialize;
 
-#[derive(Deserialize, Debug)]
-pub struct BlocklistQuery {
-    #[serde(default = "default_limit")]
-    pub limit: u32,
-    #[serde(default)]
-    pub offset: u32,
+#[derive(Serialize, Debug, Clone)]
+pub struct BlocklistResponse {
+    pub domain: String,
+    pub added_at: String,
 }
 
-fn default_limit() -> u32 {
-    100
+#[derive(Serialize, Debug)]
+pub struct BulkEntryErrorResponse {
+    /// Zero-based position in the JSON array, or zero-based line number for text bodies.
+    pub index: usize,
+    pub entry: String,
+    pub error: String,
 }
 
 #[derive(Serialize, Debug)]
-pub struct PaginatedBlocklist {
-    pub data: Vec<BlocklistResponse>,
-    pub total: u64,
-    pub limit: u32,
-    pub offset: u32,
+pub struct BulkAddResponse {
+    pub received: usize,
+    pub added: usize,
+    pub duplicates: usize,
+    pub errors: Vec<BulkEntryErrorResponse>,
 }
 
-#[derive(Serialize, Debug, Clone)]
-pub struct BlocklistResponse {
-    pub domain: String,
-    pub added_at: String,
+impl BulkAddResponse {
+    pub fn from_summary(summary: BulkAddSummary) -> Self {
+        Self {
+            received: summary.received,
+            added: summary.added,
+            duplicates: summary.duplicates,
+            errors: summary
+                .errors
+                .into_iter()
+                .map(|e| BulkEntryErrorResponse {
+                    index: e.index,
+                    entry: e.entry,
+                    error: e.error,
+                })
+                .collect(),
+        }
+    }
 }
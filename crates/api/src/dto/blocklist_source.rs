@@ -9,6 +9,9 @@ pub struct BlocklistSourceResponse {
     pub group_ids: Vec<i64>,
     pub comment: Option<String>,
     pub enabled: bool,
+    pub audit_mode: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant_id: Option<i64>,
     pub created_at: Option<String>,
     pub updated_at: Option<String>,
 }
@@ -22,6 +25,8 @@ impl BlocklistSourceResponse {
             group_ids: source.group_ids,
             comment: source.comment.as_ref().map(|s| s.to_string()),
             enabled: source.enabled,
+            audit_mode: source.audit_mode,
+            tenant_id: source.tenant_id,
             created_at: source.created_at,
             updated_at: source.updated_at,
         }
@@ -43,15 +48,21 @@ impl CreateBlocklistSourceRequest {
     /// Resolve the effective group_ids, preferring group_ids over legacy group_id.
     /// Falls back to default_group_id if neither is provided.
     pub fn resolved_group_ids(&self, default_group_id: i64) -> Vec<i64> {
+        let ids = self.requested_group_ids();
+        if ids.is_empty() {
+            return vec![default_group_id];
+        }
+        ids
+    }
+
+    /// Like `resolved_group_ids`, but empty when the request names no group.
+    pub fn requested_group_ids(&self) -> Vec<i64> {
         if let Some(ref ids) = self.group_ids {
             if !ids.is_empty() {
                 return ids.clone();
             }
         }
-        if let Some(gid) = self.group_id {
-            return vec![gid];
-        }
-        vec![default_group_id]
+        self.group_id.map(|gid| vec![gid]).unwrap_or_default()
     }
 }
 
@@ -65,6 +76,8 @@ pub struct UpdateBlocklistSourceRequest {
     pub group_ids: Option<Vec<i64>>,
     pub comment: Option<String>,
     pub enabled: Option<bool>,
+    /// Log matches as "would block" instead of blocking them.
+    pub audit_mode: Option<bool>,
 }
 
 impl UpdateBlocklistSourceRequest {
@@ -13,6 +13,8 @@ fn default_period() -> String {
 #[derive(Serialize, Debug, Clone)]
 pub struct CacheStatsResponse {
     pub total_entries: usize,
+    pub memory_bytes: u64,
+    pub max_memory_bytes: u64,
     pub total_hits: u64,
     pub total_misses: u64,
     pub total_refreshes: u64,
@@ -29,9 +31,65 @@ pub struct CacheMetricsResponse {
     pub evictions: u64,
     pub optimistic_refreshes: u64,
     pub stale_hits: u64,
+    pub l1_hits: u64,
+    pub memory_bytes: u64,
+    pub max_memory_bytes: u64,
     pub lazy_deletions: u64,
     pub compactions: u64,
     pub batch_evictions: u64,
     pub hit_rate: f64,
     pub transient_upstream_errors: u64,
+    pub refresh_budget: Option<RefreshBudgetResponse>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct RefreshBudgetResponse {
+    pub limit_qps: f64,
+    pub client_qps: f64,
+    pub health_factor: f64,
+    pub granted: u64,
+    pub deferred: u64,
+    /// Fraction of requested refreshes the budget allowed.
+    pub usage: f64,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CacheHotKeysQuery {
+    pub limit: Option<usize>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CacheFlushQuery {
+    pub suffix: String,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct CacheFlushResponse {
+    pub suffix: String,
+    /// Positive, negative and DNSSEC entries removed.
+    pub removed: usize,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct CacheHotKeysResponse {
+    pub window_secs: u64,
+    pub total_hits: u64,
+    pub shards: CacheShardsResponse,
+    pub keys: Vec<HotKeyResponse>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct CacheShardsResponse {
+    pub entries: Vec<usize>,
+    pub max_entries: usize,
+    pub mean_entries: f64,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct HotKeyResponse {
+    pub domain: String,
+    pub record_type: String,
+    pub hits: u64,
+    /// Fraction of all cache hits in the window.
+    pub share: f64,
 }
@@ -0,0 +1,51 @@
+use ferrous_dns_domain::CacheTtlOverride;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CacheTtlOverrideResponse {
+    pub id: i64,
+    pub domain: String,
+    pub min_ttl: Option<u32>,
+    pub max_ttl: Option<u32>,
+    pub enabled: bool,
+    pub comment: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+impl CacheTtlOverrideResponse {
+    pub fn from_domain(o: CacheTtlOverride) -> Self {
+        Self {
+            id: o.id.unwrap_or(0),
+            domain: o.domain.to_string(),
+            min_ttl: o.min_ttl,
+            max_ttl: o.max_ttl,
+            enabled: o.enabled,
+            comment: o.comment.as_ref().map(|s| s.to_string()),
+            created_at: o.created_at,
+            updated_at: o.updated_at,
+        }
+    }
+}
+
+/// Used for both create and update; an update replaces every field.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CacheTtlOverrideRequest {
+    pub domain: String,
+    pub min_ttl: Option<u32>,
+    pub max_ttl: Option<u32>,
+    pub enabled: Option<bool>,
+    pub comment: Option<String>,
+}
+
+impl CacheTtlOverrideRequest {
+    pub fn into_domain(self) -> CacheTtlOverride {
+        let domain = self.domain.trim().to_ascii_lowercase();
+        CacheTtlOverride {
+            enabled: self.enabled.unwrap_or(true),
+            comment: self.comment.map(|s| Arc::from(s.as_str())),
+            ..CacheTtlOverride::new(Arc::from(domain.as_str()), self.min_ttl, self.max_ttl)
+        }
+    }
+}
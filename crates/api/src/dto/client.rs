@@ -1,3 +1,5 @@
+use super::TimelineBucket;
+use ferrous_dns_domain::ClientCategory;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Debug, Clone)]
@@ -10,6 +12,9 @@ pub struct ClientResponse {
     pub last_seen: String,
     pub query_count: u64,
     pub group_id: Option<i64>,
+    pub display_name: Option<String>,
+    pub category: Option<ClientCategory>,
+    pub notes: Option<String>,
 }
 
 #[derive(Serialize, Debug)]
@@ -23,20 +28,60 @@ pub struct ClientStatsResponse {
 
 #[derive(Deserialize, Debug)]
 pub struct ClientsQuery {
-    #[serde(default = "default_limit")]
-    pub limit: u32,
-    #[serde(default)]
-    pub offset: u32,
     #[serde(default)]
     pub active_days: Option<u32>,
 }
 
-fn default_limit() -> u32 {
-    100
-}
-
 #[derive(Deserialize, Debug)]
 pub struct UpdateClientRequest {
     pub hostname: Option<String>,
     pub group_id: Option<i64>,
+    #[serde(default)]
+    pub display_name: Option<String>,
+    #[serde(default)]
+    pub category: Option<String>,
+    #[serde(default)]
+    pub notes: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ClientActivityQuery {
+    #[serde(default = "default_activity_period")]
+    pub period: String,
+    #[serde(default = "default_activity_granularity")]
+    pub granularity: String,
+    #[serde(default = "default_activity_limit")]
+    pub limit: u32,
+}
+
+fn default_activity_period() -> String {
+    "24h".to_string()
+}
+
+fn default_activity_granularity() -> String {
+    "hour".to_string()
+}
+
+fn default_activity_limit() -> u32 {
+    10
+}
+
+#[derive(Serialize, Debug)]
+pub struct ClientDomainCount {
+    pub domain: String,
+    pub count: u64,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ClientActivityResponse {
+    pub client_id: i64,
+    pub period: String,
+    pub granularity: String,
+    pub total_queries: u64,
+    pub blocked_queries: u64,
+    pub cache_hits: u64,
+    pub cache_hit_rate: f64,
+    pub timeline: Vec<TimelineBucket>,
+    pub top_domains: Vec<ClientDomainCount>,
+    pub top_blocked_domains: Vec<ClientDomainCount>,
 }
@@ -1,4 +1,6 @@
+use ferrous_dns_domain::LogFormat;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ConfigResponse {
@@ -108,6 +110,21 @@ pub struct BlockingConfigResponse {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct LoggingConfigResponse {
     pub level: String,
+    pub format: LogFormat,
+    pub modules: BTreeMap<String, String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct UpdateLogLevelRequest {
+    pub level: Option<String>,
+    /// Replaces every module override when present.
+    pub modules: Option<BTreeMap<String, String>>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct LogLevelResponse {
+    pub level: String,
+    pub modules: BTreeMap<String, String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
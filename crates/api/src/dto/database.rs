@@ -0,0 +1,51 @@
+use ferrous_dns_application::use_cases::{DatabaseMaintenanceReport, DatabaseStatus};
+use serde::Serialize;
+
+/// Database disk usage and the outcome of the last maintenance run.
+#[derive(Debug, Serialize)]
+pub struct DatabaseStatusResponse {
+    pub file_bytes: u64,
+    pub wal_bytes: u64,
+    pub used_bytes: u64,
+    pub free_bytes: u64,
+    pub incremental_vacuum: bool,
+    pub max_size_bytes: Option<u64>,
+    pub last_maintenance: Option<DatabaseMaintenanceResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DatabaseMaintenanceResponse {
+    pub started_at: String,
+    pub duration_ms: u64,
+    pub trimmed_rows: u64,
+    pub reclaimed_bytes: u64,
+    pub file_bytes_before: u64,
+    pub file_bytes_after: u64,
+}
+
+impl From<DatabaseMaintenanceReport> for DatabaseMaintenanceResponse {
+    fn from(r: DatabaseMaintenanceReport) -> Self {
+        Self {
+            started_at: r.started_at.to_rfc3339(),
+            duration_ms: r.duration_ms,
+            trimmed_rows: r.trimmed_rows,
+            reclaimed_bytes: r.reclaimed_bytes,
+            file_bytes_before: r.size_before.file_bytes,
+            file_bytes_after: r.size_after.file_bytes,
+        }
+    }
+}
+
+impl From<DatabaseStatus> for DatabaseStatusResponse {
+    fn from(s: DatabaseStatus) -> Self {
+        Self {
+            file_bytes: s.usage.file_bytes,
+            wal_bytes: s.usage.wal_bytes,
+            used_bytes: s.usage.used_bytes,
+            free_bytes: s.usage.free_bytes,
+            incremental_vacuum: s.usage.incremental_vacuum,
+            max_size_bytes: s.max_size_bytes,
+            last_maintenance: s.last_maintenance.map(DatabaseMaintenanceResponse::from),
+        }
+    }
+}
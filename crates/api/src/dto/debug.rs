@@ -0,0 +1,435 @@
+use ferrous_dns_application::ports::{
+    AllowlistMatch, BlocklistMatch, CacheEntrySnapshot, DnsResolution, FaultKind, FilterDecision,
+    FilterExplanation, InflightQueriesSnapshot, InflightQuery, InjectedFault, SourceBit,
+    UpstreamAttempt, UpstreamRoute,
+};
+use ferrous_dns_application::use_cases::{
+    DiagnosisOutcome, DomainDiagnosis, ResolutionTrace, TraceStep,
+};
+use ferrous_dns_domain::{GroupOverride, PolicyMatch, RewriteTarget};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Debug, Default)]
+pub struct DiagnoseDomainQuery {
+    pub client: Option<String>,
+    #[serde(rename = "type")]
+    pub record_type: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct TraceResolveRequest {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub record_type: Option<String>,
+    pub client: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+pub struct InflightQueriesQuery {
+    pub limit: Option<usize>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct InflightQueriesResponse {
+    pub total: usize,
+    pub leaders: u64,
+    pub coalesced: u64,
+    /// Share of cache misses answered by waiting on another query.
+    pub dedup_ratio: f64,
+    pub queries: Vec<InflightQueryResponse>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct InflightQueryResponse {
+    pub domain: String,
+    pub record_type: String,
+    pub waiters: usize,
+    pub elapsed_ms: u64,
+}
+
+/// `kind` is `upstream_down` (needs `server`), `latency` (needs `delay_ms`,
+/// `server` optional) or `servfail` (needs `pattern`).
+#[derive(Deserialize, Debug)]
+pub struct InjectFaultRequest {
+    pub kind: String,
+    pub server: Option<String>,
+    pub delay_ms: Option<u64>,
+    pub pattern: Option<String>,
+    /// Lifts the fault after this many seconds; kept until removed if unset.
+    pub duration_secs: Option<u64>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct FaultResponse {
+    pub id: u64,
+    pub kind: &'static str,
+    pub server: Option<String>,
+    pub delay_ms: Option<u64>,
+    pub pattern: Option<String>,
+    pub remaining_secs: Option<u64>,
+    pub hits: u64,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ClearFaultsResponse {
+    pub cleared: usize,
+}
+
+#[derive(Serialize, Debug)]
+pub struct DomainDiagnosisResponse {
+    pub domain: String,
+    pub record_type: String,
+    pub client: String,
+    pub group_id: i64,
+    pub outcome: &'static str,
+    pub block_source: Option<&'static str>,
+    pub policy: Option<PolicyMatchResponse>,
+    pub rewrite: Option<RewriteResponse>,
+    pub safe_search_target: Option<&'static str>,
+    pub filter: FilterExplanationResponse,
+    pub cache: Option<CacheEntryResponse>,
+    pub upstream: Option<UpstreamRouteResponse>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct PolicyMatchResponse {
+    pub policy_id: i64,
+    pub action: &'static str,
+    pub target: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct RewriteResponse {
+    pub cname: Option<String>,
+    pub addresses: Vec<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct FilterExplanationResponse {
+    pub decision: &'static str,
+    pub blocking_enabled: bool,
+    pub schedule_override: Option<ScheduleOverrideResponse>,
+    pub group_mask: u64,
+    pub allowlist: Vec<AllowlistMatchResponse>,
+    pub blocklist: Vec<BlocklistMatchResponse>,
+    pub sources: Vec<SourceBitResponse>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ScheduleOverrideResponse {
+    pub action: &'static str,
+    pub until: Option<u64>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct AllowlistMatchResponse {
+    pub list: &'static str,
+    pub rule: &'static str,
+}
+
+#[derive(Serialize, Debug)]
+pub struct BlocklistMatchResponse {
+    pub source: &'static str,
+    pub rule: &'static str,
+    pub bits: u64,
+    pub active: bool,
+}
+
+#[derive(Serialize, Debug)]
+pub struct SourceBitResponse {
+    pub bit: u8,
+    pub source_id: Option<i64>,
+    pub name: String,
+}
+
+#[derive(Serialize, Debug)]
+pub struct CacheEntryResponse {
+    pub state: &'static str,
+    pub ttl: Option<u32>,
+    pub remaining_ttl: u32,
+    pub dnssec_status: Option<&'static str>,
+    pub hit_count: u64,
+}
+
+#[derive(Serialize, Debug)]
+pub struct UpstreamRouteResponse {
+    pub pool: String,
+    pub strategy: &'static str,
+    pub servers: Vec<String>,
+    pub skipped_pools: Vec<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ResolutionTraceResponse {
+    pub diagnosis: DomainDiagnosisResponse,
+    pub steps: Vec<TraceStepResponse>,
+    pub resolved_name: Option<String>,
+    pub upstream_attempts: Vec<UpstreamAttemptResponse>,
+    pub answer: Option<TraceAnswerResponse>,
+    pub error: Option<String>,
+    pub elapsed_us: u64,
+}
+
+#[derive(Serialize, Debug)]
+pub struct TraceStepResponse {
+    pub stage: &'static str,
+    pub result: &'static str,
+    pub detail: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct UpstreamAttemptResponse {
+    pub server: String,
+    pub pool: String,
+    pub strategy: &'static str,
+    pub protocol: &'static str,
+    pub attempt: u32,
+    pub latency_us: u64,
+    pub error: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct TraceAnswerResponse {
+    pub addresses: Vec<String>,
+    pub cname_chain: Vec<String>,
+    pub cache_hit: bool,
+    pub local: bool,
+    pub dnssec_status: Option<&'static str>,
+    pub min_ttl: Option<u32>,
+    pub upstream_server: Option<String>,
+    pub upstream_pool: Option<String>,
+}
+
+impl From<ResolutionTrace> for ResolutionTraceResponse {
+    fn from(t: ResolutionTrace) -> Self {
+        Self {
+            diagnosis: t.diagnosis.into(),
+            steps: t.steps.into_iter().map(Into::into).collect(),
+            resolved_name: t.resolved_name,
+            upstream_attempts: t.upstream_attempts.into_iter().map(Into::into).collect(),
+            answer: t.resolution.map(TraceAnswerResponse::from),
+            error: t.error.map(|e| e.to_string()),
+            elapsed_us: t.elapsed_us,
+        }
+    }
+}
+
+impl From<TraceStep> for TraceStepResponse {
+    fn from(s: TraceStep) -> Self {
+        Self {
+            stage: s.stage.as_str(),
+            result: s.result,
+            detail: s.detail,
+        }
+    }
+}
+
+impl From<UpstreamAttempt> for UpstreamAttemptResponse {
+    fn from(a: UpstreamAttempt) -> Self {
+        Self {
+            server: a.server.to_string(),
+            pool: a.pool_name.to_string(),
+            strategy: a.strategy,
+            protocol: a.protocol,
+            attempt: a.attempt,
+            latency_us: a.latency_us,
+            error: a.error,
+        }
+    }
+}
+
+impl From<DnsResolution> for TraceAnswerResponse {
+    fn from(r: DnsResolution) -> Self {
+        Self {
+            addresses: r.addresses.iter().map(|ip| ip.to_string()).collect(),
+            cname_chain: r.cname_chain.iter().map(|c| c.to_string()).collect(),
+            cache_hit: r.cache_hit,
+            local: r.local_dns,
+            dnssec_status: r.dnssec_status,
+            min_ttl: r.min_ttl,
+            upstream_server: r.upstream_server.map(|s| s.to_string()),
+            upstream_pool: r.upstream_pool.map(|p| p.to_string()),
+        }
+    }
+}
+
+impl From<DomainDiagnosis> for DomainDiagnosisResponse {
+    fn from(d: DomainDiagnosis) -> Self {
+        let block_source = match d.outcome {
+            DiagnosisOutcome::Blocked(source) => Some(source.to_str()),
+            _ => None,
+        };
+        Self {
+            domain: d.domain,
+            record_type: d.record_type.as_str().to_string(),
+            client: d.client_ip.to_string(),
+            group_id: d.group_id,
+            outcome: d.outcome.as_str(),
+            block_source,
+            policy: d.policy.map(PolicyMatchResponse::from),
+            rewrite: d.rewrite.map(RewriteResponse::from),
+            safe_search_target: d.safe_search_target,
+            filter: d.filter.into(),
+            cache: d.cache.map(CacheEntryResponse::from),
+            upstream: d.upstream.map(UpstreamRouteResponse::from),
+        }
+    }
+}
+
+impl From<PolicyMatch> for PolicyMatchResponse {
+    fn from(m: PolicyMatch) -> Self {
+        Self {
+            policy_id: m.policy_id,
+            action: m.action.to_str(),
+            target: m.target.map(|t| t.to_string()),
+        }
+    }
+}
+
+impl From<RewriteTarget> for RewriteResponse {
+    fn from(target: RewriteTarget) -> Self {
+        match target {
+            RewriteTarget::Cname(name) => Self {
+                cname: Some(name.to_string()),
+                addresses: Vec::new(),
+            },
+            RewriteTarget::Addresses(ips) => Self {
+                cname: None,
+                addresses: ips.iter().map(|ip| ip.to_string()).collect(),
+            },
+        }
+    }
+}
+
+impl From<FilterExplanation> for FilterExplanationResponse {
+    fn from(e: FilterExplanation) -> Self {
+        Self {
+            decision: match e.decision {
+                FilterDecision::Block(_) => "block",
+                FilterDecision::Audit(_) => "audit",
+                FilterDecision::Allow => "allow",
+            },
+            blocking_enabled: e.blocking_enabled,
+            schedule_override: e.schedule_override.map(ScheduleOverrideResponse::from),
+            group_mask: e.group_mask,
+            allowlist: e.allowlist.into_iter().map(Into::into).collect(),
+            blocklist: e.blocklist.into_iter().map(Into::into).collect(),
+            sources: e.sources.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<GroupOverride> for ScheduleOverrideResponse {
+    fn from(o: GroupOverride) -> Self {
+        let (action, until) = match o {
+            GroupOverride::BlockAll => ("block_all", None),
+            GroupOverride::AllowAll => ("allow_all", None),
+            GroupOverride::TimedBypassUntil(ts) => ("allow_all", Some(ts)),
+            GroupOverride::TimedBlockUntil(ts) => ("block_all", Some(ts)),
+        };
+        Self { action, until }
+    }
+}
+
+impl From<AllowlistMatch> for AllowlistMatchResponse {
+    fn from(m: AllowlistMatch) -> Self {
+        let (list, rule) = match m {
+            AllowlistMatch::Global(rule) => ("global", rule.as_str()),
+            AllowlistMatch::Group(rule) => ("group", rule.as_str()),
+            AllowlistMatch::Regex => ("regex_filter", "regex"),
+        };
+        Self { list, rule }
+    }
+}
+
+impl From<BlocklistMatch> for BlocklistMatchResponse {
+    fn from(m: BlocklistMatch) -> Self {
+        Self {
+            source: m.source.to_str(),
+            rule: m.rule.as_str(),
+            bits: m.bits,
+            active: m.active,
+        }
+    }
+}
+
+impl From<SourceBit> for SourceBitResponse {
+    fn from(s: SourceBit) -> Self {
+        Self {
+            bit: s.bit,
+            source_id: s.source_id,
+            name: s.name.to_string(),
+        }
+    }
+}
+
+impl From<CacheEntrySnapshot> for CacheEntryResponse {
+    fn from(c: CacheEntrySnapshot) -> Self {
+        Self {
+            state: c.state.as_str(),
+            ttl: c.ttl,
+            remaining_ttl: c.remaining_ttl,
+            dnssec_status: c.dnssec_status,
+            hit_count: c.hit_count,
+        }
+    }
+}
+
+impl From<UpstreamRoute> for UpstreamRouteResponse {
+    fn from(r: UpstreamRoute) -> Self {
+        Self {
+            pool: r.pool_name,
+            strategy: r.strategy.as_str(),
+            servers: r.servers,
+            skipped_pools: r.skipped_pools,
+        }
+    }
+}
+
+impl From<InflightQuery> for InflightQueryResponse {
+    fn from(q: InflightQuery) -> Self {
+        Self {
+            domain: q.domain,
+            record_type: q.record_type.to_string(),
+            waiters: q.waiters,
+            elapsed_ms: q.elapsed_ms,
+        }
+    }
+}
+
+impl From<InflightQueriesSnapshot> for InflightQueriesResponse {
+    fn from(s: InflightQueriesSnapshot) -> Self {
+        let misses = s.leaders + s.coalesced;
+        Self {
+            total: s.total,
+            leaders: s.leaders,
+            coalesced: s.coalesced,
+            dedup_ratio: if misses == 0 {
+                0.0
+            } else {
+                s.coalesced as f64 / misses as f64
+            },
+            queries: s.queries.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<InjectedFault> for FaultResponse {
+    fn from(f: InjectedFault) -> Self {
+        let kind = f.kind.as_str();
+        let (server, delay_ms, pattern) = match f.kind {
+            FaultKind::UpstreamDown { server } => (Some(server), None, None),
+            FaultKind::Latency { server, delay_ms } => (server, Some(delay_ms), None),
+            FaultKind::Servfail { pattern } => (None, None, Some(pattern)),
+        };
+        Self {
+            id: f.id,
+            kind,
+            server,
+            delay_ms,
+            pattern,
+            remaining_secs: f.remaining_secs,
+            hits: f.hits,
+        }
+    }
+}
@@ -0,0 +1,210 @@
+use ferrous_dns_application::ports::DnsResolution;
+use ferrous_dns_domain::{DomainError, RecordType};
+use hickory_proto::op::Message;
+use hickory_proto::rr::{RData, Record, RecordType as HickoryRecordType};
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+
+/// TTL reported for answers that carry none, as the DNS server does.
+const DEFAULT_TTL: u32 = 60;
+
+#[derive(Deserialize, Debug)]
+pub struct DnsJsonQuery {
+    pub name: Option<String>,
+    /// Mnemonic (`AAAA`) or numeric (`28`) type; defaults to `A`.
+    #[serde(rename = "type")]
+    pub record_type: Option<String>,
+}
+
+/// Response in the JSON DoH dialect served by Google (`/resolve`) and
+/// Cloudflare (`application/dns-json`).
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct DnsJsonResponse {
+    pub status: u16,
+    #[serde(rename = "TC")]
+    pub tc: bool,
+    #[serde(rename = "RD")]
+    pub rd: bool,
+    #[serde(rename = "RA")]
+    pub ra: bool,
+    #[serde(rename = "AD")]
+    pub ad: bool,
+    #[serde(rename = "CD")]
+    pub cd: bool,
+    pub question: Vec<DnsJsonQuestion>,
+    pub answer: Vec<DnsJsonRecord>,
+    pub authority: Vec<DnsJsonRecord>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct DnsJsonQuestion {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub record_type: u16,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct DnsJsonRecord {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub record_type: u16,
+    #[serde(rename = "TTL")]
+    pub ttl: u32,
+    pub data: String,
+}
+
+impl DnsJsonResponse {
+    fn new(status: u16, domain: &str, record_type: RecordType) -> Self {
+        Self {
+            status,
+            tc: false,
+            rd: true,
+            ra: true,
+            ad: false,
+            cd: false,
+            question: vec![DnsJsonQuestion {
+                name: fqdn(domain),
+                record_type: record_type.to_u16(),
+            }],
+            answer: Vec::new(),
+            authority: Vec::new(),
+            comment: None,
+        }
+    }
+
+    /// Answer for a query the resolve use case answered. Address answers are
+    /// built from the resolution, preceded by the CNAME chain that led to
+    /// them; any other type is read from the upstream response.
+    pub fn from_resolution(
+        domain: &str,
+        record_type: RecordType,
+        resolution: &DnsResolution,
+    ) -> Self {
+        if resolution.addresses.is_empty() {
+            if let Some(wire) = resolution.upstream_wire_data.as_deref() {
+                if let Ok(mut response) = Self::from_wire(wire) {
+                    response.ad |= resolution.dnssec_status == Some("Secure");
+                    return response;
+                }
+            }
+        }
+
+        let mut response = Self::new(0, domain, record_type);
+        response.ad = resolution.dnssec_status == Some("Secure");
+        let ttl = resolution.min_ttl.unwrap_or(DEFAULT_TTL);
+
+        let mut owner = fqdn(domain);
+        for target in resolution.cname_chain.iter() {
+            let target = fqdn(target);
+            response.answer.push(DnsJsonRecord {
+                name: std::mem::replace(&mut owner, target.clone()),
+                record_type: RecordType::CNAME.to_u16(),
+                ttl,
+                data: target,
+            });
+        }
+        for address in resolution.addresses.iter() {
+            let address_type = match address {
+                IpAddr::V4(_) => RecordType::A,
+                IpAddr::V6(_) => RecordType::AAAA,
+            };
+            response.answer.push(DnsJsonRecord {
+                name: owner.clone(),
+                record_type: address_type.to_u16(),
+                ttl,
+                data: address.to_string(),
+            });
+        }
+        response
+    }
+
+    /// Answer for a query the resolve use case refused or failed, with the
+    /// RCODE the DNS server would send and the reason as `Comment`.
+    pub fn from_error(domain: &str, record_type: RecordType, error: &DomainError) -> Self {
+        let status = match error {
+            DomainError::AnyQueryMinimized => 0,
+            e => e.rcode().code(),
+        };
+        Self {
+            comment: Some(error.to_string()),
+            ..Self::new(status, domain, record_type)
+        }
+    }
+
+    /// Converts a DNS response in wire format.
+    pub fn from_wire(wire: &[u8]) -> Result<Self, DomainError> {
+        let msg =
+            Message::from_vec(wire).map_err(|e| DomainError::MalformedQuery(e.to_string()))?;
+
+        Ok(Self {
+            status: u16::from(msg.response_code()),
+            tc: msg.truncated(),
+            rd: msg.recursion_desired(),
+            ra: msg.recursion_available(),
+            ad: msg.authentic_data(),
+            cd: msg.checking_disabled(),
+            question: msg
+                .queries()
+                .iter()
+                .map(|q| DnsJsonQuestion {
+                    name: q.name().to_string(),
+                    record_type: u16::from(q.query_type()),
+                })
+                .collect(),
+            answer: json_records(msg.answers()),
+            authority: json_records(msg.name_servers()),
+            comment: None,
+        })
+    }
+}
+
+fn fqdn(name: &str) -> String {
+    if name.ends_with('.') {
+        name.to_string()
+    } else {
+        format!("{}.", name)
+    }
+}
+
+fn json_records(records: &[Record]) -> Vec<DnsJsonRecord> {
+    records
+        .iter()
+        .filter(|r| r.record_type() != HickoryRecordType::OPT)
+        .map(|r| DnsJsonRecord {
+            name: r.name().to_string(),
+            record_type: u16::from(r.record_type()),
+            ttl: r.ttl(),
+            data: format_rdata(r.data()),
+        })
+        .collect()
+}
+
+fn format_rdata(data: &RData) -> String {
+    match data {
+        RData::A(a) => a.0.to_string(),
+        RData::AAAA(aaaa) => aaaa.0.to_string(),
+        RData::CNAME(name) => name.to_utf8(),
+        RData::NS(name) => name.to_utf8(),
+        RData::PTR(name) => name.to_utf8(),
+        RData::MX(mx) => format!("{} {}", mx.preference(), mx.exchange()),
+        RData::TXT(txt) => txt
+            .iter()
+            .map(|b| String::from_utf8_lossy(b).to_string())
+            .collect::<Vec<_>>()
+            .join(" "),
+        RData::SOA(soa) => format!(
+            "{} {} {} {} {} {} {}",
+            soa.mname(),
+            soa.rname(),
+            soa.serial(),
+            soa.refresh(),
+            soa.retry(),
+            soa.expire(),
+            soa.minimum()
+        ),
+        _ => data.to_string(),
+    }
+}
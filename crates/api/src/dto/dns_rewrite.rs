@@ -0,0 +1,58 @@
+use ferrous_dns_domain::{DnsRewrite, RewriteAnswer};
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DnsRewriteResponse {
+    pub id: i64,
+    pub domain: String,
+    pub answer: String,
+    /// `A`, `AAAA` or `CNAME`, derived from `answer`.
+    pub answer_type: &'static str,
+    pub enabled: bool,
+    pub comment: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+impl DnsRewriteResponse {
+    pub fn from_domain(r: DnsRewrite) -> Self {
+        let answer_type = match RewriteAnswer::parse(&r.answer) {
+            RewriteAnswer::Address(IpAddr::V4(_)) => "A",
+            RewriteAnswer::Address(IpAddr::V6(_)) => "AAAA",
+            RewriteAnswer::Cname(_) => "CNAME",
+        };
+        Self {
+            id: r.id.unwrap_or(0),
+            domain: r.domain.to_string(),
+            answer: r.answer.to_string(),
+            answer_type,
+            enabled: r.enabled,
+            comment: r.comment.as_ref().map(|s| s.to_string()),
+            created_at: r.created_at,
+            updated_at: r.updated_at,
+        }
+    }
+}
+
+/// Used for both create and update; an update replaces every field.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DnsRewriteRequest {
+    pub domain: String,
+    pub answer: String,
+    pub enabled: Option<bool>,
+    pub comment: Option<String>,
+}
+
+impl DnsRewriteRequest {
+    pub fn into_domain(self) -> DnsRewrite {
+        let domain = self.domain.trim().to_ascii_lowercase();
+        let answer = self.answer.trim().to_ascii_lowercase();
+        DnsRewrite {
+            enabled: self.enabled.unwrap_or(true),
+            comment: self.comment.map(|s| Arc::from(s.as_str())),
+            ..DnsRewrite::new(Arc::from(domain.as_str()), Arc::from(answer.as_str()))
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use ferrous_dns_application::ports::DnssecCachedValidation;
+use ferrous_dns_application::use_cases::{DnssecDomainStatus, DnssecStats};
+use ferrous_dns_domain::{DnssecStatsSample, DnssecStatusChange, DnssecValidationCounts};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+pub struct DnssecStatusQuery {
+    pub domain: String,
+    /// Status changes to return; defaults to 50.
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DnssecStatsQuery {
+    /// Defaults to 24, capped at the 30 days of kept history.
+    pub period_hours: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DnssecCountsResponse {
+    pub secure: u64,
+    pub insecure: u64,
+    pub bogus: u64,
+    pub indeterminate: u64,
+    pub total: u64,
+}
+
+impl From<DnssecValidationCounts> for DnssecCountsResponse {
+    fn from(counts: DnssecValidationCounts) -> Self {
+        Self {
+            secure: counts.secure,
+            insecure: counts.insecure,
+            bogus: counts.bogus,
+            indeterminate: counts.indeterminate,
+            total: counts.total(),
+        }
+    }
+}
+
+/// A validation result held in the DNSSEC cache.
+#[derive(Debug, Serialize)]
+pub struct DnssecCachedValidationResponse {
+    pub record_type: String,
+    pub status: &'static str,
+    pub validated_secs_ago: u64,
+    pub expired: bool,
+}
+
+impl From<DnssecCachedValidation> for DnssecCachedValidationResponse {
+    fn from(validation: DnssecCachedValidation) -> Self {
+        Self {
+            record_type: validation.record_type.to_string(),
+            status: validation.status,
+            validated_secs_ago: validation.validated_secs_ago,
+            expired: validation.expired,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct DnssecStatusChangeResponse {
+    pub id: i64,
+    pub domain: String,
+    pub record_type: String,
+    pub status: &'static str,
+    pub previous_status: Option<&'static str>,
+    pub observed_at: String,
+}
+
+impl From<DnssecStatusChange> for DnssecStatusChangeResponse {
+    fn from(change: DnssecStatusChange) -> Self {
+        Self {
+            id: change.id.unwrap_or(0),
+            domain: change.domain,
+            record_type: change.record_type.to_string(),
+            status: change.status,
+            previous_status: change.previous_status,
+            observed_at: change.observed_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct DnssecStatusResponse {
+    pub domain: String,
+    pub cached: Vec<DnssecCachedValidationResponse>,
+    pub changes: Vec<DnssecStatusChangeResponse>,
+}
+
+impl From<DnssecDomainStatus> for DnssecStatusResponse {
+    fn from(status: DnssecDomainStatus) -> Self {
+        Self {
+            domain: status.domain,
+            cached: status.cached.into_iter().map(Into::into).collect(),
+            changes: status.changes.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct DnssecSampleResponse {
+    pub recorded_at: String,
+    #[serde(flatten)]
+    pub counts: DnssecCountsResponse,
+}
+
+impl From<DnssecStatsSample> for DnssecSampleResponse {
+    fn from(sample: DnssecStatsSample) -> Self {
+        Self {
+            recorded_at: sample.recorded_at,
+            counts: sample.counts.into(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct DnssecStatsResponse {
+    pub period_hours: u32,
+    pub totals: DnssecCountsResponse,
+    pub samples: Vec<DnssecSampleResponse>,
+    pub recent_bogus: Vec<DnssecStatusChangeResponse>,
+}
+
+impl From<DnssecStats> for DnssecStatsResponse {
+    fn from(stats: DnssecStats) -> Self {
+        Self {
+            period_hours: stats.period_hours,
+            totals: stats.totals.into(),
+            samples: stats.samples.into_iter().map(Into::into).collect(),
+            recent_bogus: stats.recent_bogus.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -8,7 +8,11 @@ pub struct GroupResponse {
     pub enabled: bool,
     pub comment: Option<String>,
     pub is_default: bool,
+    pub filter_aaaa: bool,
+    pub audit_mode: bool,
     pub client_count: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant_id: Option<i64>,
     pub created_at: Option<String>,
     pub updated_at: Option<String>,
 }
@@ -21,7 +25,10 @@ impl GroupResponse {
             enabled: group.enabled,
             comment: group.comment.as_ref().map(|s| s.to_string()),
             is_default: group.is_default,
+            filter_aaaa: group.filter_aaaa,
+            audit_mode: group.audit_mode,
             client_count,
+            tenant_id: group.tenant_id,
             created_at: group.created_at,
             updated_at: group.updated_at,
         }
@@ -40,6 +47,8 @@ pub struct UpdateGroupRequest {
     pub name: Option<String>,
     pub enabled: Option<bool>,
     pub comment: Option<String>,
+    pub filter_aaaa: Option<bool>,
+    pub audit_mode: Option<bool>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
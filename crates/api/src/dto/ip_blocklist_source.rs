@@ -0,0 +1,43 @@
+use ferrous_dns_domain::IpBlocklistSource;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IpBlocklistSourceResponse {
+    pub id: i64,
+    pub name: String,
+    pub url: String,
+    pub comment: Option<String>,
+    pub enabled: bool,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+impl IpBlocklistSourceResponse {
+    pub fn from_source(source: IpBlocklistSource) -> Self {
+        Self {
+            id: source.id.unwrap_or(0),
+            name: source.name.to_string(),
+            url: source.url.to_string(),
+            comment: source.comment.as_ref().map(|s| s.to_string()),
+            enabled: source.enabled,
+            created_at: source.created_at,
+            updated_at: source.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateIpBlocklistSourceRequest {
+    pub name: String,
+    pub url: String,
+    pub comment: Option<String>,
+    pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateIpBlocklistSourceRequest {
+    pub name: Option<String>,
+    pub url: Option<String>,
+    pub comment: Option<String>,
+    pub enabled: Option<bool>,
+}
@@ -1,4 +1,8 @@
+use ferrous_dns_domain::{
+    DomainError, LocalRecord, RecordType, ZoneFile, ZoneFileRecord, DEFAULT_LOCAL_RECORD_TTL,
+};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct LocalRecordDto {
@@ -6,47 +10,154 @@ pub struct LocalRecordDto {
     pub hostname: String,
     pub domain: Option<String>,
     pub fqdn: String,
-    pub ip: String,
+    pub value: String,
     pub record_type: String,
     pub ttl: u32,
+    pub view: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant_id: Option<i64>,
     pub created_at: Option<String>,
+    pub updated_at: Option<String>,
 }
 
 impl LocalRecordDto {
-    pub fn from_config(
-        record: &ferrous_dns_domain::LocalDnsRecord,
-        index: i64,
-        default_domain: &Option<String>,
-    ) -> Self {
-        let fqdn = record.fqdn(default_domain);
+    pub fn from_domain(record: &LocalRecord, default_domain: &Option<String>) -> Self {
+        Self {
+            id: record.id.unwrap_or(0),
+            hostname: record.hostname.to_string(),
+            domain: record.domain.as_deref().map(String::from),
+            fqdn: record.fqdn(default_domain),
+            value: record.value.to_string(),
+            record_type: record.record_type.to_string(),
+            ttl: record.ttl,
+            view: record.view.as_deref().map(String::from),
+            tenant_id: record.tenant_id,
+            created_at: record.created_at.clone(),
+            updated_at: record.updated_at.clone(),
+        }
+    }
+}
 
+#[derive(Debug, Deserialize, Default)]
+pub struct ExportLocalRecordsQuery {
+    /// `zonefile` (default) or `json`.
+    pub format: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ZoneExportDto {
+    pub zone: String,
+    pub records: Vec<ZoneRecordDto>,
+    pub skipped: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ZoneRecordDto {
+    pub name: String,
+    pub ttl: u32,
+    pub record_type: String,
+    pub data: String,
+}
+
+impl From<ZoneFile> for ZoneExportDto {
+    fn from(zone: ZoneFile) -> Self {
         Self {
-            id: index,
-            hostname: record.hostname.clone(),
-            domain: record.domain.clone(),
-            fqdn,
-            ip: record.ip.clone(),
-            record_type: record.record_type.clone(),
-            ttl: record.ttl.unwrap_or(300),
-            created_at: None,
+            zone: zone.origin,
+            records: zone.records.into_iter().map(Into::into).collect(),
+            skipped: zone.skipped,
         }
     }
 }
 
+impl From<ZoneFileRecord> for ZoneRecordDto {
+    fn from(record: ZoneFileRecord) -> Self {
+        Self {
+            name: record.name,
+            ttl: record.ttl,
+            record_type: record.record_type.to_string(),
+            data: record.data,
+        }
+    }
+}
+
+/// `value` is also accepted as `ip`, the field name used before records
+/// other than A/AAAA were supported.
 #[derive(Debug, Deserialize)]
 pub struct CreateLocalRecordRequest {
     pub hostname: String,
     pub domain: Option<String>,
-    pub ip: String,
+    #[serde(alias = "ip")]
+    pub value: String,
     pub record_type: String,
     pub ttl: Option<u32>,
+    #[serde(default)]
+    pub view: Option<String>,
+}
+
+impl CreateLocalRecordRequest {
+    pub fn into_domain(self) -> Result<LocalRecord, DomainError> {
+        build_record(
+            self.hostname,
+            self.domain,
+            self.value,
+            &self.record_type,
+            self.ttl,
+            self.view,
+        )
+    }
 }
 
+/// An update replaces every field of the record.
 #[derive(Debug, Deserialize)]
 pub struct UpdateLocalRecordRequest {
     pub hostname: String,
     pub domain: Option<String>,
-    pub ip: String,
+    #[serde(alias = "ip")]
+    pub value: String,
     pub record_type: String,
     pub ttl: Option<u32>,
+    #[serde(default)]
+    pub view: Option<String>,
+}
+
+impl UpdateLocalRecordRequest {
+    pub fn into_domain(self) -> Result<LocalRecord, DomainError> {
+        build_record(
+            self.hostname,
+            self.domain,
+            self.value,
+            &self.record_type,
+            self.ttl,
+            self.view,
+        )
+    }
+}
+
+fn build_record(
+    hostname: String,
+    domain: Option<String>,
+    value: String,
+    record_type: &str,
+    ttl: Option<u32>,
+    view: Option<String>,
+) -> Result<LocalRecord, DomainError> {
+    let record_type = record_type
+        .trim()
+        .to_ascii_uppercase()
+        .parse::<RecordType>()
+        .map_err(DomainError::InvalidLocalRecord)?;
+    let domain = domain
+        .map(|d| d.trim().to_string())
+        .filter(|d| !d.is_empty());
+    let view = view.map(|v| v.trim().to_string()).filter(|v| !v.is_empty());
+    Ok(LocalRecord {
+        domain: domain.map(Arc::from),
+        ttl: ttl.unwrap_or(DEFAULT_LOCAL_RECORD_TTL),
+        view: view.map(Arc::from),
+        ..LocalRecord::new(
+            Arc::from(hostname.trim()),
+            record_type,
+            Arc::from(value.trim()),
+        )
+    })
 }
@@ -1,4 +1,6 @@
+pub mod alert;
 pub mod api_token;
+pub mod audit_log;
 pub mod auth;
 pub mod backup;
 pub mod block_filter;
@@ -6,59 +8,111 @@ pub mod blocked_service;
 pub mod blocklist;
 pub mod blocklist_source;
 pub mod cache;
+pub mod cache_ttl_override;
 pub mod client;
 pub mod client_subnet;
 pub mod config;
 pub mod custom_service;
 pub mod dashboard;
+pub mod database;
+pub mod debug;
+pub mod dns_json;
+pub mod dns_rewrite;
+pub mod dnssec;
 pub mod group;
 pub mod hostname;
+pub mod ip_blocklist_source;
 pub mod local_record;
 pub mod managed_domain;
+pub mod pagination;
 pub mod query;
+pub mod query_policy;
 pub mod rate;
+pub mod record_type_policy;
 pub mod regex_filter;
 pub mod safe_search;
 pub mod schedule;
+pub mod setup;
 pub mod stats;
 pub mod system_info;
+pub mod tenant;
 pub mod timeline;
+pub mod tld_policy;
 pub mod tls;
+pub mod unblock_request;
 pub mod user;
 pub mod whitelist;
 pub mod whitelist_source;
 
+pub use alert::{AlertResponse, AlertsQuery};
+pub use audit_log::{AuditEventResponse, AuditLogQuery};
 pub use blocked_service::{BlockServiceRequest, BlockedServiceResponse, ServiceDefinitionResponse};
 pub use custom_service::{
     CreateCustomServiceRequest, CustomServiceResponse, UpdateCustomServiceRequest,
 };
-pub use local_record::{CreateLocalRecordRequest, LocalRecordDto};
+pub use local_record::{CreateLocalRecordRequest, LocalRecordDto, UpdateLocalRecordRequest};
 pub use managed_domain::{
-    CreateManagedDomainRequest, ManagedDomainQuery, ManagedDomainResponse, PaginatedManagedDomains,
-    UpdateManagedDomainRequest,
+    CreateManagedDomainRequest, ManagedDomainResponse, UpdateManagedDomainRequest,
 };
 pub use regex_filter::{CreateRegexFilterRequest, RegexFilterResponse, UpdateRegexFilterRequest};
+pub use tenant::{CreateTenantRequest, TenantResponse, TenantStatsResponse, UpdateTenantRequest};
 
-pub use blocklist::{BlocklistQuery, BlocklistResponse, PaginatedBlocklist};
+pub use blocklist::{BlocklistResponse, BulkAddResponse, BulkEntryErrorResponse};
 pub use blocklist_source::{
     BlocklistSourceResponse, CreateBlocklistSourceRequest, UpdateBlocklistSourceRequest,
 };
-pub use cache::{CacheMetricsResponse, CacheStatsQuery, CacheStatsResponse};
-pub use client::{ClientResponse, ClientStatsResponse, ClientsQuery, UpdateClientRequest};
+pub use cache::{
+    CacheFlushQuery, CacheFlushResponse, CacheHotKeysQuery, CacheHotKeysResponse,
+    CacheMetricsResponse, CacheShardsResponse, CacheStatsQuery, CacheStatsResponse, HotKeyResponse,
+    RefreshBudgetResponse,
+};
+pub use cache_ttl_override::{CacheTtlOverrideRequest, CacheTtlOverrideResponse};
+pub use client::{
+    ClientActivityQuery, ClientActivityResponse, ClientDomainCount, ClientResponse,
+    ClientStatsResponse, ClientsQuery, UpdateClientRequest,
+};
 pub use client_subnet::{
     ClientSubnetResponse, CreateClientSubnetRequest, CreateManualClientRequest,
 };
 pub use config::*;
 pub use dashboard::{DashboardQuery, DashboardResponse, TopBlockedDomain, TopClient};
+pub use database::{DatabaseMaintenanceResponse, DatabaseStatusResponse};
+pub use debug::{
+    ClearFaultsResponse, DiagnoseDomainQuery, DomainDiagnosisResponse, FaultResponse,
+    InflightQueriesQuery, InflightQueriesResponse, InjectFaultRequest, ResolutionTraceResponse,
+    TraceResolveRequest,
+};
+pub use dns_json::{DnsJsonQuery, DnsJsonQuestion, DnsJsonRecord, DnsJsonResponse};
+pub use dns_rewrite::{DnsRewriteRequest, DnsRewriteResponse};
+pub use dnssec::{
+    DnssecCachedValidationResponse, DnssecCountsResponse, DnssecSampleResponse, DnssecStatsQuery,
+    DnssecStatsResponse, DnssecStatusChangeResponse, DnssecStatusQuery, DnssecStatusResponse,
+};
 pub use group::{AssignGroupRequest, CreateGroupRequest, GroupResponse, UpdateGroupRequest};
 pub use hostname::HostnameResponse;
-pub use query::{PaginatedQueries, QueryParams, QueryResponse};
+pub use ip_blocklist_source::{
+    CreateIpBlocklistSourceRequest, IpBlocklistSourceResponse, UpdateIpBlocklistSourceRequest,
+};
+pub use pagination::{ListParams, NoMeta, Page};
+pub use query::{QueryPageMeta, QueryParams, QueryResponse};
+pub use query_policy::{QueryPolicyRequest, QueryPolicyResponse};
 pub use rate::{QueryRateResponse, RateQuery};
+pub use record_type_policy::{RecordTypePolicyResponse, SetRecordTypePolicyRequest};
 pub use safe_search::{SafeSearchConfigResponse, ToggleSafeSearchRequest};
-pub use stats::{QuerySourceStats, StatsQuery, StatsResponse, TopType, TypeDistribution};
-pub use system_info::SystemInfoResponse;
-pub use timeline::{TimelineBucket, TimelineQuery, TimelineResponse};
+pub use stats::{
+    PublicStatsResponse, QueryBreakdownResponse, QuerySourceStats, StatsQuery, StatsResponse,
+    TopType, TypeDistribution,
+};
+pub use system_info::{
+    CacheMemoryResponse, ChannelDepthResponse, DatabaseSizeResponse, RuntimeMetricsResponse,
+    SystemInfoResponse, SystemMetricsResponse, UdpPoolResponse, UdpServerPoolResponse,
+};
+pub use timeline::{TimelineBucket, TimelineQuery, TimelineResponse, TimelineSeries};
+pub use tld_policy::{SetTldPolicyRequest, TldPolicyResponse};
 pub use tls::{GenerateQuery, TlsStatusResponse, TlsUploadResponse};
+pub use unblock_request::{
+    ApproveUnblockRequest, SubmitUnblockRequest, UnblockRequestResponse, UnblockRequestsQuery,
+};
 pub use whitelist::WhitelistResponse;
 pub use whitelist_source::{
     CreateWhitelistSourceRequest, UpdateWhitelistSourceRequest, WhitelistSourceResponse,
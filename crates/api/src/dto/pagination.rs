@@ -0,0 +1,135 @@
+use crate::errors::ApiError;
+use axum::{
+    response::{IntoResponse, Response},
+    Json,
+};
+use ferrous_dns_domain::{DomainError, PageRequest, SortOrder};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Query parameters shared by every list endpoint.
+///
+/// `?limit=&offset=` select the window, `?sort=<field>&order=asc|desc` pick
+/// the ordering from the entity's allowlist, and `?fields=a,b` trims each
+/// item in the response to the named keys.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ListParams {
+    #[serde(default = "default_limit")]
+    pub limit: u32,
+    #[serde(default)]
+    pub offset: u32,
+    pub sort: Option<String>,
+    pub order: Option<String>,
+    pub fields: Option<String>,
+}
+
+fn default_limit() -> u32 {
+    PageRequest::DEFAULT_LIMIT
+}
+
+impl Default for ListParams {
+    fn default() -> Self {
+        Self {
+            limit: default_limit(),
+            offset: 0,
+            sort: None,
+            order: None,
+            fields: None,
+        }
+    }
+}
+
+impl ListParams {
+    /// Validates the parameters against `sort_fields` and builds the
+    /// repository page request.
+    pub fn page_request(&self, sort_fields: &[&'static str]) -> Result<PageRequest, ApiError> {
+        let page = PageRequest::new(self.limit, self.offset);
+        let order = match self.order.as_deref() {
+            Some(order) => order.parse::<SortOrder>()?,
+            None => SortOrder::default(),
+        };
+        match self.sort.as_deref() {
+            Some(field) => Ok(page.sorted_by(field, order, sort_fields)?),
+            None if self.order.is_some() => {
+                Err(DomainError::InvalidInput("'order' requires a 'sort' field".to_string()).into())
+            }
+            None => Ok(page),
+        }
+    }
+
+    /// Wraps one page of results in the standard envelope.
+    pub fn page<T>(&self, page: &PageRequest, data: Vec<T>, total: u64) -> Page<T> {
+        Page {
+            data,
+            total,
+            limit: page.limit,
+            offset: page.offset,
+            meta: NoMeta {},
+            fields: self.selected_fields(),
+        }
+    }
+
+    fn selected_fields(&self) -> Option<Vec<String>> {
+        self.fields.as_deref().map(|fields| {
+            fields
+                .split(',')
+                .map(str::trim)
+                .filter(|f| !f.is_empty())
+                .map(String::from)
+                .collect()
+        })
+    }
+}
+
+/// Extra top-level envelope fields for a list endpoint; the default adds none.
+#[derive(Serialize, Debug, Default)]
+pub struct NoMeta {}
+
+/// Response envelope for list endpoints: `{ data, total, limit, offset }`
+/// plus any endpoint-specific fields flattened from `meta`.
+#[derive(Serialize, Debug)]
+pub struct Page<T, M = NoMeta> {
+    pub data: Vec<T>,
+    pub total: u64,
+    pub limit: u32,
+    pub offset: u32,
+    #[serde(flatten)]
+    pub meta: M,
+    #[serde(skip)]
+    pub fields: Option<Vec<String>>,
+}
+
+impl<T> Page<T> {
+    /// Replaces the empty meta with endpoint-specific envelope fields.
+    pub fn with_meta<M>(self, meta: M) -> Page<T, M> {
+        Page {
+            data: self.data,
+            total: self.total,
+            limit: self.limit,
+            offset: self.offset,
+            meta,
+            fields: self.fields,
+        }
+    }
+}
+
+impl<T: Serialize, M: Serialize> IntoResponse for Page<T, M> {
+    fn into_response(self) -> Response {
+        let Some(fields) = self.fields.as_deref() else {
+            return Json(&self).into_response();
+        };
+
+        let mut body = match serde_json::to_value(&self) {
+            Ok(body) => body,
+            Err(e) => return ApiError(DomainError::IoError(e.to_string())).into_response(),
+        };
+        if let Some(Value::Array(items)) = body.get_mut("data") {
+            for item in items {
+                if let Value::Object(map) = item {
+                    map.retain(|key, _| fields.iter().any(|f| f == key));
+                }
+            }
+        }
+        Json(body).into_response()
+    }
+}
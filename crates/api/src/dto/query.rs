@@ -3,10 +3,6 @@ use std::sync::Arc;
 
 #[derive(Deserialize, Debug)]
 pub struct QueryParams {
-    #[serde(default = "default_limit")]
-    pub limit: u32,
-    #[serde(default)]
-    pub offset: u32,
     pub cursor: Option<i64>,
     #[serde(default = "default_period")]
     pub period: String,
@@ -16,21 +12,19 @@ pub struct QueryParams {
     #[serde(rename = "type")]
     pub record_type: Option<String>,
     pub upstream: Option<String>,
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub blocked: Option<bool>,
+    pub dnssec: Option<String>,
+    pub source: Option<String>,
 }
 
-fn default_limit() -> u32 {
-    100
-}
-
+/// Query-log specific fields of the `/queries` page envelope; `total`
+/// counts the records matching the applied filters.
 #[derive(Serialize, Debug)]
-pub struct PaginatedQueries {
-    pub data: Vec<QueryResponse>,
-    /// Total records matching the applied filters.
-    pub total: u64,
+pub struct QueryPageMeta {
     /// Total records in the period (without filters).
     pub records_total: u64,
-    pub limit: u32,
-    pub offset: u32,
     pub next_cursor: Option<i64>,
 }
 
@@ -44,6 +38,9 @@ pub struct QueryResponse {
     pub domain: Arc<str>,
     pub client: String,
     pub client_hostname: Option<Arc<str>>,
+    pub client_mac: Option<Arc<str>>,
+    /// Group the client was resolved into when the query was made.
+    pub group_name: Option<Arc<str>>,
     #[serde(rename = "type")]
     pub record_type: &'static str,
     pub blocked: bool,
@@ -53,7 +50,13 @@ pub struct QueryResponse {
     pub dnssec_status: Option<&'static str>,
     pub upstream_server: Option<Arc<str>>,
     pub upstream_pool: Option<Arc<str>>,
+    pub upstream_strategy: Option<&'static str>,
+    pub upstream_attempt: Option<u32>,
+    pub upstream_protocol: Option<&'static str>,
     pub query_source: &'static str,
     pub block_source: Option<&'static str>,
+    /// Plugin script that decided the query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plugin: Option<Arc<str>>,
     pub response_status: Option<&'static str>,
 }
@@ -0,0 +1,107 @@
+use ferrous_dns_domain::{
+    entities::query_policy::DEFAULT_POLICY_PRIORITY, DomainError, PolicyAction, QueryPolicy,
+    RecordType,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct QueryPolicyResponse {
+    pub id: i64,
+    pub name: String,
+    pub priority: i64,
+    pub enabled: bool,
+    pub client_cidr: Option<String>,
+    pub group_id: Option<i64>,
+    pub domain_pattern: Option<String>,
+    pub record_types: Vec<String>,
+    pub days: Option<u8>,
+    pub start_time: Option<String>,
+    pub end_time: Option<String>,
+    pub action: String,
+    pub action_target: Option<String>,
+    pub comment: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+impl QueryPolicyResponse {
+    pub fn from_domain(p: QueryPolicy) -> Self {
+        Self {
+            id: p.id.unwrap_or(0),
+            name: p.name.to_string(),
+            priority: p.priority,
+            enabled: p.enabled,
+            client_cidr: p.client_cidr.as_ref().map(|s| s.to_string()),
+            group_id: p.group_id,
+            domain_pattern: p.domain_pattern.as_ref().map(|s| s.to_string()),
+            record_types: p
+                .record_types
+                .iter()
+                .map(|t| t.as_str().to_string())
+                .collect(),
+            days: p.days,
+            start_time: p.start_time.as_ref().map(|s| s.to_string()),
+            end_time: p.end_time.as_ref().map(|s| s.to_string()),
+            action: p.action.to_str().to_string(),
+            action_target: p.action_target.as_ref().map(|s| s.to_string()),
+            comment: p.comment.as_ref().map(|s| s.to_string()),
+            created_at: p.created_at,
+            updated_at: p.updated_at,
+        }
+    }
+}
+
+/// Used for both create and update; an update replaces every field.
+#[derive(Debug, Clone, Deserialize)]
+pub struct QueryPolicyRequest {
+    pub name: String,
+    pub priority: Option<i64>,
+    pub enabled: Option<bool>,
+    pub client_cidr: Option<String>,
+    pub group_id: Option<i64>,
+    pub domain_pattern: Option<String>,
+    pub record_types: Option<Vec<String>>,
+    pub days: Option<u8>,
+    pub start_time: Option<String>,
+    pub end_time: Option<String>,
+    pub action: String,
+    pub action_target: Option<String>,
+    pub comment: Option<String>,
+}
+
+impl QueryPolicyRequest {
+    pub fn into_domain(self) -> Result<QueryPolicy, DomainError> {
+        let action = self.action.parse::<PolicyAction>().map_err(|_| {
+            DomainError::InvalidQueryPolicy(format!(
+                "Invalid action '{}': must be one of allow, block, forward_to_pool, rewrite, log_only",
+                self.action
+            ))
+        })?;
+
+        let record_types = self
+            .record_types
+            .unwrap_or_default()
+            .iter()
+            .map(|t| {
+                t.parse::<RecordType>().map_err(|_| {
+                    DomainError::InvalidQueryPolicy(format!("Invalid record type '{}'", t))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut policy = QueryPolicy::new(Arc::from(self.name.as_str()), action);
+        policy.priority = self.priority.unwrap_or(DEFAULT_POLICY_PRIORITY);
+        policy.enabled = self.enabled.unwrap_or(true);
+        policy.client_cidr = self.client_cidr.map(|s| Arc::from(s.as_str()));
+        policy.group_id = self.group_id;
+        policy.domain_pattern = self.domain_pattern.map(|s| Arc::from(s.as_str()));
+        policy.record_types = record_types;
+        policy.days = self.days;
+        policy.start_time = self.start_time.map(|s| Arc::from(s.as_str()));
+        policy.end_time = self.end_time.map(|s| Arc::from(s.as_str()));
+        policy.action_target = self.action_target.map(|s| Arc::from(s.as_str()));
+        policy.comment = self.comment.map(|s| Arc::from(s.as_str()));
+        Ok(policy)
+    }
+}
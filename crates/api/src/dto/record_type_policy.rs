@@ -0,0 +1,55 @@
+use ferrous_dns_domain::{DomainError, RecordType, RecordTypeFilterMode, RecordTypePolicy};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordTypePolicyResponse {
+    pub id: Option<i64>,
+    pub group_id: i64,
+    pub mode: String,
+    pub record_types: Vec<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+impl RecordTypePolicyResponse {
+    pub fn from_entity(p: RecordTypePolicy) -> Self {
+        Self {
+            id: p.id,
+            group_id: p.group_id,
+            mode: p.mode.to_str().to_string(),
+            record_types: p
+                .record_types
+                .iter()
+                .map(|t| t.as_str().to_string())
+                .collect(),
+            created_at: p.created_at,
+            updated_at: p.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SetRecordTypePolicyRequest {
+    pub mode: String,
+    pub record_types: Vec<String>,
+}
+
+impl SetRecordTypePolicyRequest {
+    pub fn into_entity(self, group_id: i64) -> Result<RecordTypePolicy, DomainError> {
+        let mode = self.mode.parse::<RecordTypeFilterMode>().map_err(|_| {
+            DomainError::InvalidRecordTypePolicy(format!(
+                "Invalid mode '{}': must be 'allow' or 'deny'",
+                self.mode
+            ))
+        })?;
+        let record_types = self
+            .record_types
+            .iter()
+            .map(|t| {
+                t.parse::<RecordType>()
+                    .map_err(DomainError::InvalidRecordTypePolicy)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(RecordTypePolicy::new(group_id, mode, record_types))
+    }
+}
@@ -0,0 +1,108 @@
+use ferrous_dns_application::use_cases::{SetupSummary, SuggestedBlocklist};
+use ferrous_dns_domain::UpstreamPreset;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize)]
+pub struct SetupOptionsResponse {
+    pub upstream_preset: Option<UpstreamPreset>,
+    pub upstream_presets: Vec<UpstreamPresetResponse>,
+    pub blocklists: Vec<SuggestedBlocklistResponse>,
+    pub listeners: SetupListenersDto,
+    pub interfaces: Vec<NetworkInterfaceResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UpstreamPresetResponse {
+    pub id: UpstreamPreset,
+    pub pools: Vec<UpstreamPresetPoolResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UpstreamPresetPoolResponse {
+    pub name: String,
+    pub strategy: String,
+    pub priority: u8,
+    pub servers: Vec<String>,
+}
+
+impl UpstreamPresetResponse {
+    pub fn from_preset(preset: UpstreamPreset) -> Self {
+        Self {
+            id: preset,
+            pools: preset
+                .pools()
+                .into_iter()
+                .map(|pool| UpstreamPresetPoolResponse {
+                    name: pool.name,
+                    strategy: pool.strategy.to_string(),
+                    priority: pool.priority,
+                    servers: pool.servers,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SuggestedBlocklistResponse {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub url: &'static str,
+    pub description: &'static str,
+    pub recommended: bool,
+}
+
+impl From<&SuggestedBlocklist> for SuggestedBlocklistResponse {
+    fn from(list: &SuggestedBlocklist) -> Self {
+        Self {
+            id: list.id,
+            name: list.name,
+            url: list.url,
+            description: list.description,
+            recommended: list.recommended,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SetupListenersDto {
+    pub bind_address: String,
+    pub dns_port: u16,
+    pub web_port: u16,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NetworkInterfaceResponse {
+    pub name: String,
+    pub address: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SetupRequestDto {
+    pub password: String,
+    #[serde(default)]
+    pub upstream_preset: Option<UpstreamPreset>,
+    #[serde(default)]
+    pub blocklists: Vec<String>,
+    #[serde(default)]
+    pub listeners: Option<SetupListenersDto>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SetupSummaryResponse {
+    pub blocklists_added: usize,
+    pub blocklists_skipped: usize,
+    pub restart_required: bool,
+    pub warnings: Vec<String>,
+}
+
+impl From<SetupSummary> for SetupSummaryResponse {
+    fn from(summary: SetupSummary) -> Self {
+        Self {
+            blocklists_added: summary.blocklists_added,
+            blocklists_skipped: summary.blocklists_skipped,
+            restart_required: summary.restart_required,
+            warnings: summary.warnings,
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use ferrous_dns_domain::{QueryCountBreakdown, QueryStats};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -18,19 +19,80 @@ pub struct StatsResponse {
     pub queries_total: u64,
     pub queries_blocked: u64,
     pub queries_rate_limited: u64,
+    pub queries_any_minimized: u64,
     pub queries_malware_detected: u64,
+    pub blocked_percentage: f64,
     pub clients: u64,
     pub uptime: u64,
     pub cache_hit_rate: f64,
     pub avg_query_time_ms: f64,
     pub avg_cache_time_ms: f64,
     pub avg_upstream_time_ms: f64,
+    pub latency_p50_ms: f64,
+    pub latency_p95_ms: f64,
+    pub latency_p99_ms: f64,
 
     pub queries_by_type: HashMap<String, u64>,
     pub most_queried_type: Option<String>,
     pub record_type_distribution: Vec<TypeDistribution>,
     pub top_10_types: Vec<TopType>,
     pub source_stats: QuerySourceStats,
+
+    pub queries_by_group: Vec<QueryBreakdownResponse>,
+    pub top_clients: Vec<QueryBreakdownResponse>,
+}
+
+/// Summary served on the public stats endpoint. Carries no domains, client
+/// addresses or per-client counts, so it can be shown to anyone.
+#[derive(Serialize, Debug, Clone)]
+pub struct PublicStatsResponse {
+    pub period_hours: u32,
+    pub queries_total: u64,
+    pub queries_blocked: u64,
+    pub blocked_percentage: f64,
+    pub cache_hit_rate: f64,
+    pub avg_query_time_ms: f64,
+    pub uptime: u64,
+}
+
+impl PublicStatsResponse {
+    pub fn new(period_hours: u32, stats: &QueryStats) -> Self {
+        Self {
+            period_hours,
+            queries_total: stats.queries_total,
+            queries_blocked: stats.queries_blocked,
+            blocked_percentage: stats.blocked_percentage(),
+            cache_hit_rate: stats.cache_hit_rate,
+            avg_query_time_ms: stats.avg_query_time_ms,
+            uptime: stats.uptime_seconds,
+        }
+    }
+}
+
+/// Counts for one group (`key` is the group id) or client (`key` is the IP).
+#[derive(Serialize, Debug, Clone)]
+pub struct QueryBreakdownResponse {
+    pub key: String,
+    pub label: Option<String>,
+    pub queries_total: u64,
+    pub queries_blocked: u64,
+    pub blocked_percentage: f64,
+    pub cache_hits: u64,
+    pub cache_hit_rate: f64,
+}
+
+impl From<&QueryCountBreakdown> for QueryBreakdownResponse {
+    fn from(entry: &QueryCountBreakdown) -> Self {
+        Self {
+            key: entry.key.clone(),
+            label: entry.label.clone(),
+            queries_total: entry.queries_total,
+            queries_blocked: entry.queries_blocked,
+            blocked_percentage: entry.blocked_percentage(),
+            cache_hits: entry.cache_hits,
+            cache_hit_rate: entry.cache_hit_rate(),
+        }
+    }
 }
 
 #[derive(Serialize, Debug, Clone)]
@@ -51,18 +113,25 @@ impl Default for StatsResponse {
             queries_total: 0,
             queries_blocked: 0,
             queries_rate_limited: 0,
+            queries_any_minimized: 0,
             queries_malware_detected: 0,
+            blocked_percentage: 0.0,
             clients: 0,
             uptime: 0,
             cache_hit_rate: 0.0,
             avg_query_time_ms: 0.0,
             avg_cache_time_ms: 0.0,
             avg_upstream_time_ms: 0.0,
+            latency_p50_ms: 0.0,
+            latency_p95_ms: 0.0,
+            latency_p99_ms: 0.0,
             queries_by_type: HashMap::new(),
             most_queried_type: None,
             record_type_distribution: Vec::new(),
             top_10_types: Vec::new(),
             source_stats: HashMap::new(),
+            queries_by_group: Vec::new(),
+            top_clients: Vec::new(),
         }
     }
 }
@@ -1,3 +1,4 @@
+use ferrous_dns_application::ports::{ChannelDepth, UdpPoolMetrics, UdpServerPoolMetrics};
 use serde::Serialize;
 
 /// System information snapshot: kernel version, CPU load averages, and memory usage.
@@ -12,3 +13,109 @@ pub struct SystemInfoResponse {
     pub mem_available_kb: u64,
     pub mem_used_percent: f32,
 }
+
+/// Resource usage of the Ferrous DNS process itself, for the health page.
+/// Values the platform cannot report are `null`.
+#[derive(Debug, Serialize)]
+pub struct SystemMetricsResponse {
+    pub uptime_secs: u64,
+    pub rss_bytes: Option<u64>,
+    pub open_fds: Option<u64>,
+    pub runtime: RuntimeMetricsResponse,
+    pub channels: Vec<ChannelDepthResponse>,
+    pub cache: CacheMemoryResponse,
+    /// `null` when the database size could not be read.
+    pub database: Option<DatabaseSizeResponse>,
+    /// `null` when upstream queries do not go through the UDP socket pool.
+    pub udp_pool: Option<UdpPoolResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RuntimeMetricsResponse {
+    pub workers: Option<usize>,
+    pub alive_tasks: Option<usize>,
+    pub global_queue_depth: Option<usize>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ChannelDepthResponse {
+    pub name: &'static str,
+    pub queued: usize,
+    pub capacity: usize,
+}
+
+impl From<ChannelDepth> for ChannelDepthResponse {
+    fn from(channel: ChannelDepth) -> Self {
+        Self {
+            name: channel.name,
+            queued: channel.queued,
+            capacity: channel.capacity,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CacheMemoryResponse {
+    pub entries: usize,
+    pub memory_bytes: u64,
+    /// `0` when the cache has no memory cap.
+    pub max_memory_bytes: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DatabaseSizeResponse {
+    pub file_bytes: u64,
+    pub wal_bytes: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UdpPoolResponse {
+    pub sockets_created: u64,
+    pub sockets_reused: u64,
+    pub idle_sockets: usize,
+    pub in_flight: usize,
+    pub max_idle_per_server: usize,
+    pub port_exhaustion_errors: u64,
+    pub socket_errors: u64,
+    pub servers: Vec<UdpServerPoolResponse>,
+}
+
+impl From<UdpPoolMetrics> for UdpPoolResponse {
+    fn from(pool: UdpPoolMetrics) -> Self {
+        Self {
+            sockets_created: pool.sockets_created,
+            sockets_reused: pool.sockets_reused,
+            idle_sockets: pool.idle_sockets,
+            in_flight: pool.in_flight,
+            max_idle_per_server: pool.max_idle_per_server,
+            port_exhaustion_errors: pool.port_exhaustion_errors,
+            socket_errors: pool.socket_errors,
+            servers: pool.servers.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct UdpServerPoolResponse {
+    pub server: String,
+    pub idle_sockets: usize,
+    pub in_flight: usize,
+    pub target_idle: usize,
+    pub unreachable_errors: u64,
+    pub timeouts: u64,
+    pub other_errors: u64,
+}
+
+impl From<UdpServerPoolMetrics> for UdpServerPoolResponse {
+    fn from(server: UdpServerPoolMetrics) -> Self {
+        Self {
+            server: server.server,
+            idle_sockets: server.idle_sockets,
+            in_flight: server.in_flight,
+            target_idle: server.target_idle,
+            unreachable_errors: server.unreachable_errors,
+            timeouts: server.timeouts,
+            other_errors: server.other_errors,
+        }
+    }
+}
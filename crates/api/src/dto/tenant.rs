@@ -0,0 +1,118 @@
+use ferrous_dns_domain::{Tenant, TenantStats};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TenantResponse {
+    pub id: i64,
+    pub name: String,
+    pub listener: Option<String>,
+    pub subnets: Vec<String>,
+    pub default_group_id: Option<i64>,
+    pub comment: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+impl TenantResponse {
+    pub fn from_tenant(tenant: Tenant) -> Self {
+        Self {
+            id: tenant.id.unwrap_or(0),
+            name: tenant.name.to_string(),
+            listener: tenant.listener.as_deref().map(String::from),
+            subnets: tenant.subnets.iter().map(|s| s.to_string()).collect(),
+            default_group_id: tenant.default_group_id,
+            comment: tenant.comment.as_deref().map(String::from),
+            created_at: tenant.created_at,
+            updated_at: tenant.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateTenantRequest {
+    pub name: String,
+    #[serde(default)]
+    pub listener: Option<String>,
+    #[serde(default)]
+    pub subnets: Vec<String>,
+    #[serde(default)]
+    pub comment: Option<String>,
+}
+
+impl CreateTenantRequest {
+    pub fn into_domain(self) -> Tenant {
+        Tenant {
+            listener: non_empty(self.listener),
+            subnets: trimmed(self.subnets),
+            comment: non_empty(self.comment),
+            ..Tenant::new(Arc::from(self.name.trim()))
+        }
+    }
+}
+
+/// An update replaces every field of the tenant.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateTenantRequest {
+    pub name: String,
+    #[serde(default)]
+    pub listener: Option<String>,
+    #[serde(default)]
+    pub subnets: Vec<String>,
+    pub default_group_id: Option<i64>,
+    #[serde(default)]
+    pub comment: Option<String>,
+}
+
+impl UpdateTenantRequest {
+    pub fn into_domain(self) -> Tenant {
+        Tenant {
+            listener: non_empty(self.listener),
+            subnets: trimmed(self.subnets),
+            default_group_id: self.default_group_id,
+            comment: non_empty(self.comment),
+            ..Tenant::new(Arc::from(self.name.trim()))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TenantStatsResponse {
+    pub tenant_id: i64,
+    pub period_hours: f32,
+    pub queries_total: u64,
+    pub queries_blocked: u64,
+    pub clients: u64,
+    pub groups: u64,
+    pub local_records: u64,
+}
+
+impl TenantStatsResponse {
+    pub fn from_stats(tenant_id: i64, period_hours: f32, stats: TenantStats) -> Self {
+        Self {
+            tenant_id,
+            period_hours,
+            queries_total: stats.queries_total,
+            queries_blocked: stats.queries_blocked,
+            clients: stats.unique_clients,
+            groups: stats.groups,
+            local_records: stats.local_records,
+        }
+    }
+}
+
+fn non_empty(value: Option<String>) -> Option<Arc<str>> {
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+        .map(Arc::from)
+}
+
+fn trimmed(values: Vec<String>) -> Vec<Arc<str>> {
+    values
+        .iter()
+        .map(|v| v.trim())
+        .filter(|v| !v.is_empty())
+        .map(Arc::from)
+        .collect()
+}
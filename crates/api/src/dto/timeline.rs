@@ -8,6 +8,8 @@ pub struct TimelineBucket {
     pub blocked: u64,
     pub unblocked: u64,
     pub malware_detected: u64,
+    pub cached: u64,
+    pub forwarded: u64,
 }
 
 impl From<ports::TimelineBucket> for TimelineBucket {
@@ -18,6 +20,8 @@ impl From<ports::TimelineBucket> for TimelineBucket {
             blocked: b.blocked,
             unblocked: b.unblocked,
             malware_detected: b.malware_detected,
+            cached: b.cached,
+            forwarded: b.forwarded,
         }
     }
 }
@@ -28,6 +32,25 @@ pub struct TimelineResponse {
     pub period: String,
     pub granularity: String,
     pub total_buckets: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub series: Option<Vec<TimelineSeries>>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct TimelineSeries {
+    pub key: String,
+    pub label: Option<String>,
+    pub buckets: Vec<TimelineBucket>,
+}
+
+impl From<ports::TimelineSeries> for TimelineSeries {
+    fn from(s: ports::TimelineSeries) -> Self {
+        Self {
+            key: s.key,
+            label: s.label,
+            buckets: s.buckets.into_iter().map(TimelineBucket::from).collect(),
+        }
+    }
 }
 
 #[derive(Deserialize, Debug)]
@@ -36,6 +59,15 @@ pub struct TimelineQuery {
     pub period: String,
     #[serde(default = "default_granularity")]
     pub granularity: String,
+    /// `client` or `group` adds one series per key.
+    pub breakdown: Option<String>,
+    /// Number of busiest clients or groups in the breakdown.
+    #[serde(default = "default_series_limit")]
+    pub limit: u32,
+}
+
+fn default_series_limit() -> u32 {
+    10
 }
 
 fn default_period() -> String {
@@ -0,0 +1,43 @@
+use ferrous_dns_domain::{DomainError, TldFilterMode, TldPolicy};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TldPolicyResponse {
+    pub id: Option<i64>,
+    pub group_id: i64,
+    pub mode: String,
+    pub tlds: Vec<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+impl TldPolicyResponse {
+    pub fn from_entity(p: TldPolicy) -> Self {
+        Self {
+            id: p.id,
+            group_id: p.group_id,
+            mode: p.mode.to_str().to_string(),
+            tlds: p.tlds,
+            created_at: p.created_at,
+            updated_at: p.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SetTldPolicyRequest {
+    pub mode: String,
+    pub tlds: Vec<String>,
+}
+
+impl SetTldPolicyRequest {
+    pub fn into_entity(self, group_id: i64) -> Result<TldPolicy, DomainError> {
+        let mode = self.mode.parse::<TldFilterMode>().map_err(|_| {
+            DomainError::InvalidTldPolicy(format!(
+                "Invalid mode '{}': must be 'allow' or 'deny'",
+                self.mode
+            ))
+        })?;
+        Ok(TldPolicy::new(group_id, mode, self.tlds))
+    }
+}
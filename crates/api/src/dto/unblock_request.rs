@@ -0,0 +1,55 @@
+use ferrous_dns_domain::UnblockRequest;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+pub struct UnblockRequestsQuery {
+    /// `pending`, `approved` or `denied`; every request when absent.
+    pub status: Option<String>,
+}
+
+/// Body of `POST /api/public/requests`.
+#[derive(Debug, Deserialize)]
+pub struct SubmitUnblockRequest {
+    pub domain: String,
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ApproveUnblockRequest {
+    /// Group to allow the domain for; defaults to the requesting client's.
+    #[serde(default)]
+    pub group_id: Option<i64>,
+}
+
+/// An unblock request filed by a client on the network.
+#[derive(Debug, Serialize)]
+pub struct UnblockRequestResponse {
+    pub id: i64,
+    pub domain: String,
+    pub client_ip: String,
+    pub group_id: Option<i64>,
+    pub block_source: Option<String>,
+    pub message: Option<String>,
+    pub status: &'static str,
+    pub managed_domain_id: Option<i64>,
+    pub created_at: Option<String>,
+    pub reviewed_at: Option<String>,
+}
+
+impl UnblockRequestResponse {
+    pub fn from_domain(request: UnblockRequest) -> Self {
+        Self {
+            id: request.id.unwrap_or(0),
+            domain: request.domain.to_string(),
+            client_ip: request.client_ip.to_string(),
+            group_id: request.group_id,
+            block_source: request.block_source.map(|s| s.to_string()),
+            message: request.message.map(|m| m.to_string()),
+            status: request.status.as_str(),
+            managed_domain_id: request.managed_domain_id,
+            created_at: request.created_at,
+            reviewed_at: request.reviewed_at,
+        }
+    }
+}
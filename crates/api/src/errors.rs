@@ -1,11 +1,13 @@
+use crate::i18n::{error_message, Locale, Message};
 use axum::{
-    http::StatusCode,
+    http::{header, HeaderValue, StatusCode},
     response::{IntoResponse, Response},
     Json,
 };
 use ferrous_dns_domain::DomainError;
 use serde_json::json;
 
+#[derive(Debug, Clone)]
 pub struct ApiError(pub DomainError);
 
 impl From<DomainError> for ApiError {
@@ -14,55 +16,56 @@ impl From<DomainError> for ApiError {
     }
 }
 
-impl IntoResponse for ApiError {
-    fn into_response(self) -> Response {
-        let (status, message) = match &self.0 {
+impl ApiError {
+    pub fn status(&self) -> StatusCode {
+        match &self.0 {
             DomainError::NotFound(_)
             | DomainError::BlocklistSourceNotFound(_)
             | DomainError::WhitelistSourceNotFound(_)
+            | DomainError::IpBlocklistSourceNotFound(_)
             | DomainError::ManagedDomainNotFound(_)
             | DomainError::RegexFilterNotFound(_)
+            | DomainError::QueryPolicyNotFound(_)
+            | DomainError::DnsRewriteNotFound(_)
+            | DomainError::CacheTtlOverrideNotFound(_)
+            | DomainError::UnblockRequestNotFound(_)
+            | DomainError::LocalRecordNotFound(_)
+            | DomainError::TenantNotFound(_)
+            | DomainError::AlertNotFound(_)
             | DomainError::CustomServiceNotFound(_)
             | DomainError::ClientNotFound(_)
             | DomainError::SubnetNotFound(_)
             | DomainError::ServiceNotFoundInCatalog(_)
             | DomainError::ScheduleProfileNotFound(_)
             | DomainError::TimeSlotNotFound(_)
-            | DomainError::GroupHasNoSchedule(_) => (StatusCode::NOT_FOUND, self.0.to_string()),
+            | DomainError::GroupHasNoSchedule(_) => StatusCode::NOT_FOUND,
 
             DomainError::ApiTokenNotFound(_)
             | DomainError::UserNotFound(_)
-            | DomainError::SessionNotFound => (StatusCode::NOT_FOUND, self.0.to_string()),
+            | DomainError::SessionNotFound => StatusCode::NOT_FOUND,
 
             DomainError::InvalidCredentials
             | DomainError::AuthRequired
-            | DomainError::PasswordNotConfigured => (StatusCode::UNAUTHORIZED, self.0.to_string()),
-
-            DomainError::InsufficientPermissions | DomainError::ProtectedUser => {
-                (StatusCode::FORBIDDEN, self.0.to_string())
-            }
-
-            DomainError::Blocked => (StatusCode::FORBIDDEN, "blocked".to_string()),
+            | DomainError::PasswordNotConfigured => StatusCode::UNAUTHORIZED,
 
-            DomainError::DnsTunnelingDetected => {
-                (StatusCode::FORBIDDEN, "DNS tunneling detected".to_string())
-            }
+            DomainError::InsufficientPermissions
+            | DomainError::ProtectedUser
+            | DomainError::Blocked(_)
+            | DomainError::DnsTunnelingDetected => StatusCode::FORBIDDEN,
 
             DomainError::RateLimited
             | DomainError::DnsRateLimited
-            | DomainError::DnsRateLimitedSlip => {
-                (StatusCode::TOO_MANY_REQUESTS, self.0.to_string())
-            }
+            | DomainError::DnsRateLimitedSlip => StatusCode::TOO_MANY_REQUESTS,
 
             DomainError::DuplicateApiTokenName(_)
             | DomainError::DuplicateUsername(_)
-            | DomainError::PasswordAlreadyConfigured => (StatusCode::CONFLICT, self.0.to_string()),
+            | DomainError::PasswordAlreadyConfigured => StatusCode::CONFLICT,
 
             DomainError::InvalidUsername(_)
             | DomainError::InvalidPassword(_)
-            | DomainError::InvalidInput(_) => (StatusCode::BAD_REQUEST, self.0.to_string()),
+            | DomainError::InvalidInput(_) => StatusCode::BAD_REQUEST,
 
-            DomainError::GroupNotFound(_) => (StatusCode::NOT_FOUND, self.0.to_string()),
+            DomainError::GroupNotFound(_) => StatusCode::NOT_FOUND,
 
             DomainError::InvalidDomainName(_)
             | DomainError::InvalidIpAddress(_)
@@ -71,13 +74,20 @@ impl IntoResponse for ApiError {
             | DomainError::InvalidTimeSlot(_)
             | DomainError::InvalidTimezone(_)
             | DomainError::InvalidScheduleProfile(_)
+            | DomainError::InvalidQueryPolicy(_)
+            | DomainError::InvalidDnsRewrite(_)
+            | DomainError::InvalidCacheTtlOverride(_)
+            | DomainError::InvalidUnblockRequest(_)
+            | DomainError::InvalidLocalRecord(_)
+            | DomainError::InvalidTenant(_)
+            | DomainError::InvalidRecordTypePolicy(_)
+            | DomainError::InvalidTldPolicy(_)
             | DomainError::ProtectedGroupCannotBeDisabled
-            | DomainError::ProtectedGroupCannotBeDeleted => {
-                (StatusCode::BAD_REQUEST, self.0.to_string())
-            }
+            | DomainError::ProtectedGroupCannotBeDeleted => StatusCode::BAD_REQUEST,
 
             DomainError::InvalidBlocklistSource(_)
             | DomainError::InvalidWhitelistSource(_)
+            | DomainError::InvalidIpBlocklistSource(_)
             | DomainError::InvalidManagedDomain(_)
             | DomainError::InvalidRegexFilter(_)
             | DomainError::InvalidGroupName(_)
@@ -85,14 +95,47 @@ impl IntoResponse for ApiError {
             | DomainError::BlockedServiceAlreadyExists(_)
             | DomainError::CustomServiceAlreadyExists(_)
             | DomainError::SubnetConflict(_)
-            | DomainError::GroupHasAssignedClients(_) => (StatusCode::CONFLICT, self.0.to_string()),
+            | DomainError::LocalRecordConflict(_)
+            | DomainError::UnblockRequestAlreadyReviewed(_)
+            | DomainError::GroupHasAssignedClients(_) => StatusCode::CONFLICT,
+
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    /// Body text in `locale`. Errors that map to 500 are not detailed.
+    pub fn message(&self, locale: Locale) -> String {
+        match &self.0 {
+            DomainError::Blocked(_) => Message::Blocked.text(locale).to_string(),
+            DomainError::DnsTunnelingDetected => {
+                Message::DnsTunnelingDetected.text(locale).to_string()
+            }
+            _ if self.status() == StatusCode::INTERNAL_SERVER_ERROR => {
+                Message::InternalError.text(locale).to_string()
+            }
+            error => error_message(locale, error),
+        }
+    }
 
-            _ => (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "internal error".to_string(),
-            ),
-        };
+    pub fn to_response(&self, locale: Locale) -> Response {
+        let mut response = (
+            self.status(),
+            Json(json!({ "error": self.message(locale) })),
+        )
+            .into_response();
+        response.headers_mut().insert(
+            header::CONTENT_LANGUAGE,
+            HeaderValue::from_static(locale.tag()),
+        );
+        response
+    }
+}
 
-        (status, Json(json!({ "error": message }))).into_response()
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let mut response = self.to_response(Locale::En);
+        // Kept so `localize_errors` can render the body in the client's language.
+        response.extensions_mut().insert(self);
+        response
     }
 }
@@ -0,0 +1,32 @@
+use crate::state::AppState;
+use axum::{extract::State, Json};
+use ferrous_dns_domain::AclAction;
+use serde::Serialize;
+
+/// One listener ACL with its rejection counters.
+#[derive(Debug, Serialize)]
+pub struct AccessControlResponse {
+    pub name: String,
+    pub allowed_clients: Vec<String>,
+    pub action: AclAction,
+    pub refused: u64,
+    pub dropped: u64,
+}
+
+pub async fn get_access_control(State(state): State<AppState>) -> Json<Vec<AccessControlResponse>> {
+    let acls = state
+        .dns
+        .access_control
+        .stats()
+        .into_iter()
+        .map(|acl| AccessControlResponse {
+            name: acl.name,
+            allowed_clients: acl.allowed_clients,
+            action: acl.action,
+            refused: acl.refused,
+            dropped: acl.dropped,
+        })
+        .collect();
+
+    Json(acls)
+}
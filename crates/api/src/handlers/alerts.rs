@@ -0,0 +1,56 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::Json,
+    routing::{delete, get},
+    Router,
+};
+use ferrous_dns_domain::{AlertKind, DomainError};
+use tracing::debug;
+
+use crate::{
+    dto::{AlertResponse, AlertsQuery},
+    errors::ApiError,
+    state::AppState,
+};
+
+const DEFAULT_LIMIT: u32 = 100;
+const MAX_LIMIT: u32 = 1000;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/alerts", get(get_alerts))
+        .route("/alerts/{id}", delete(delete_alert))
+}
+
+async fn get_alerts(
+    State(state): State<AppState>,
+    Query(params): Query<AlertsQuery>,
+) -> Result<Json<Vec<AlertResponse>>, ApiError> {
+    let kind = params
+        .kind
+        .as_deref()
+        .map(|k| {
+            k.parse::<AlertKind>()
+                .map_err(|_| DomainError::InvalidInput(format!("Unknown alert kind: {}", k)))
+        })
+        .transpose()?;
+    let limit = params.limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+
+    let alerts = state
+        .query
+        .get_alerts
+        .execute(limit, params.client.as_deref(), kind)
+        .await?;
+    debug!(count = alerts.len(), "Alerts retrieved successfully");
+
+    Ok(Json(alerts.into_iter().map(AlertResponse::from).collect()))
+}
+
+async fn delete_alert(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, ApiError> {
+    state.query.delete_alert.execute(id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
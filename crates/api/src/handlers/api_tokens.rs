@@ -33,6 +33,7 @@ async fn get_all_api_tokens(
                 name: t.name.to_string(),
                 key_prefix: t.key_prefix.to_string(),
                 token: None,
+                tenant_id: t.tenant_id,
                 created_at: t.created_at,
                 last_used_at: t.last_used_at,
             })
@@ -45,12 +46,20 @@ async fn create_api_token(
     Json(req): Json<CreateApiTokenRequest>,
 ) -> Result<(StatusCode, Json<CreatedApiTokenResponse>), ApiError> {
     let custom = req.token.as_deref();
+    if let Some(tenant_id) = req.tenant_id {
+        state.tenants.get_tenants.get_by_id(tenant_id).await?;
+    }
     let created = state
         .auth
         .create_api_token
-        .execute(&req.name, custom)
+        .execute_for_tenant(&req.name, custom, req.tenant_id)
         .await?;
-    debug!(name = %req.name, imported = custom.is_some(), "API token created via API");
+    debug!(
+        name = %req.name,
+        imported = custom.is_some(),
+        tenant_id = ?req.tenant_id,
+        "API token created via API"
+    );
     Ok((
         StatusCode::CREATED,
         Json(CreatedApiTokenResponse {
@@ -58,6 +67,7 @@ async fn create_api_token(
             name: created.token.name.to_string(),
             key_prefix: created.token.key_prefix.to_string(),
             token: created.raw_token,
+            tenant_id: created.token.tenant_id,
             created_at: created.token.created_at,
         }),
     ))
@@ -80,6 +90,7 @@ async fn update_api_token(
         name: updated.name.to_string(),
         key_prefix: updated.key_prefix.to_string(),
         token: updated.key_raw.map(|r| r.to_string()),
+        tenant_id: updated.tenant_id,
         created_at: updated.created_at,
         last_used_at: updated.last_used_at,
     }))
@@ -0,0 +1,43 @@
+use axum::{
+    extract::{Query, State},
+    response::Json,
+    routing::get,
+    Router,
+};
+use ferrous_dns_domain::{AuditAction, DomainError};
+use tracing::debug;
+
+use crate::{
+    dto::{AuditEventResponse, AuditLogQuery},
+    errors::ApiError,
+    state::AppState,
+};
+
+const DEFAULT_LIMIT: u32 = 100;
+const MAX_LIMIT: u32 = 1000;
+
+pub fn routes() -> Router<AppState> {
+    Router::new().route("/audit-log", get(get_audit_log))
+}
+
+async fn get_audit_log(
+    State(state): State<AppState>,
+    Query(params): Query<AuditLogQuery>,
+) -> Result<Json<Vec<AuditEventResponse>>, ApiError> {
+    let action = params
+        .action
+        .as_deref()
+        .map(|a| {
+            a.parse::<AuditAction>()
+                .map_err(|_| DomainError::InvalidInput(format!("Unknown audit action: {}", a)))
+        })
+        .transpose()?;
+    let limit = params.limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+
+    let events = state.query.get_audit_log.execute(limit, action).await?;
+    debug!(count = events.len(), "Audit log retrieved successfully");
+
+    Ok(Json(
+        events.into_iter().map(AuditEventResponse::from).collect(),
+    ))
+}
@@ -1,25 +1,43 @@
 use axum::{
     body::Bytes,
-    extract::{Multipart, State},
+    extract::{DefaultBodyLimit, Multipart, Query, State},
     http::{header, StatusCode},
     response::{IntoResponse, Response},
     routing::{get, post},
     Json, Router,
 };
 use chrono::Utc;
-use ferrous_dns_application::use_cases::{BackupSnapshot, ImportSummary};
+use ferrous_dns_application::ports::ExternalFormat;
+use ferrous_dns_application::use_cases::{
+    BackupFormat, BackupSnapshot, ExternalImportSummary, ImportSummary, RestoreSummary,
+};
 use tracing::{error, info, instrument};
 
 use crate::{
-    dto::backup::{ImportSummaryDto, ImportSummaryResponse},
+    dto::backup::{
+        BackupQuery, ExternalImportQuery, ExternalImportResponse, ExternalImportSummaryDto,
+        ImportSummaryDto, ImportSummaryResponse, RestoreSummaryResponse,
+    },
     errors::ApiError,
     state::AppState,
 };
 
+/// Full backups carry every list and client, well past axum's 2 MB default.
+const MAX_RESTORE_UPLOAD_BYTES: usize = 64 * 1024 * 1024;
+
 pub fn routes() -> Router<AppState> {
     Router::new()
         .route("/config/export", get(export_config))
         .route("/config/import", post(import_config))
+        .route("/backup", get(create_backup))
+        .route(
+            "/restore",
+            post(restore_backup).layer(DefaultBodyLimit::max(MAX_RESTORE_UPLOAD_BYTES)),
+        )
+        .route(
+            "/import",
+            post(import_external).layer(DefaultBodyLimit::max(MAX_RESTORE_UPLOAD_BYTES)),
+        )
 }
 
 #[instrument(skip(state), name = "api_export_config")]
@@ -60,6 +78,83 @@ async fn import_config(
     Ok(Json(into_response(summary)))
 }
 
+#[instrument(skip(state), name = "api_create_backup")]
+async fn create_backup(
+    State(state): State<AppState>,
+    Query(params): Query<BackupQuery>,
+) -> Result<Response, ApiError> {
+    let format = parse_backup_format(params.format.as_deref())?;
+    let bytes = state.backup.create.execute(format).await?;
+
+    let filename = format!(
+        "ferrous-full-backup-{}.{}",
+        Utc::now().format("%Y-%m-%d"),
+        format.extension()
+    );
+    let content_disposition = format!("attachment; filename=\"{}\"", filename);
+
+    Ok((
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE.as_str(), format.content_type()),
+            (
+                header::CONTENT_DISPOSITION.as_str(),
+                content_disposition.as_str(),
+            ),
+        ],
+        bytes,
+    )
+        .into_response())
+}
+
+#[instrument(skip(state, multipart), name = "api_restore_backup")]
+async fn restore_backup(
+    State(state): State<AppState>,
+    mut multipart: Multipart,
+) -> Result<Json<RestoreSummaryResponse>, ApiError> {
+    let file_bytes = read_backup_file_from_multipart(&mut multipart).await?;
+
+    let summary = state.backup.restore.execute(&file_bytes).await?;
+
+    Ok(Json(into_restore_response(summary)))
+}
+
+#[instrument(skip(state, multipart), name = "api_import_external")]
+async fn import_external(
+    State(state): State<AppState>,
+    Query(params): Query<ExternalImportQuery>,
+    mut multipart: Multipart,
+) -> Result<Json<ExternalImportResponse>, ApiError> {
+    let format: ExternalFormat = params
+        .format
+        .as_deref()
+        .ok_or_else(|| {
+            ApiError(ferrous_dns_domain::DomainError::InvalidInput(
+                "Missing 'format' query parameter: use 'pihole' or 'adguard'".to_string(),
+            ))
+        })?
+        .parse()?;
+    let file_bytes = read_backup_file_from_multipart(&mut multipart).await?;
+
+    let summary = state
+        .backup
+        .external_import
+        .execute(format, &file_bytes)
+        .await?;
+
+    Ok(Json(into_external_response(format, summary)))
+}
+
+fn parse_backup_format(format: Option<&str>) -> Result<BackupFormat, ApiError> {
+    match format.unwrap_or("json") {
+        "json" => Ok(BackupFormat::Json),
+        "tar.gz" | "tgz" => Ok(BackupFormat::TarGz),
+        other => Err(ApiError(ferrous_dns_domain::DomainError::InvalidInput(
+            format!("Unknown backup format '{}': use 'json' or 'tar.gz'", other),
+        ))),
+    }
+}
+
 async fn read_backup_file_from_multipart(multipart: &mut Multipart) -> Result<Bytes, ApiError> {
     while let Some(field) = multipart.next_field().await.map_err(|e| {
         ApiError(ferrous_dns_domain::DomainError::InvalidInput(format!(
@@ -110,3 +205,37 @@ fn into_response(summary: ImportSummary) -> ImportSummaryResponse {
         errors: summary.errors,
     }
 }
+
+fn into_restore_response(summary: RestoreSummary) -> RestoreSummaryResponse {
+    RestoreSummaryResponse {
+        success: true,
+        ferrous_version: summary.ferrous_version,
+        exported_at: summary.exported_at,
+        tables: summary.tables,
+    }
+}
+
+fn into_external_response(
+    format: ExternalFormat,
+    summary: ExternalImportSummary,
+) -> ExternalImportResponse {
+    ExternalImportResponse {
+        success: summary.warnings.is_empty(),
+        format: format.as_str(),
+        summary: ExternalImportSummaryDto {
+            groups_imported: summary.groups_imported,
+            groups_skipped: summary.groups_skipped,
+            lists_imported: summary.lists_imported,
+            lists_skipped: summary.lists_skipped,
+            domains_imported: summary.domains_imported,
+            domains_skipped: summary.domains_skipped,
+            regex_filters_imported: summary.regex_filters_imported,
+            regex_filters_skipped: summary.regex_filters_skipped,
+            local_records_imported: summary.local_records_imported,
+            local_records_skipped: summary.local_records_skipped,
+            clients_imported: summary.clients_imported,
+            clients_skipped: summary.clients_skipped,
+        },
+        warnings: summary.warnings,
+    }
+}
@@ -1,9 +1,18 @@
 use axum::{extract::State, routing::get, Json, Router};
 
-use crate::{dto::block_filter::BlockFilterStatsResponse, state::AppState};
+use crate::{
+    dto::block_filter::{
+        BlockFilterStatsResponse, BlocklistSourceHitsResponse, BlocklistSourceStatsResponse,
+        BlocklistStatusResponse,
+    },
+    state::AppState,
+};
 
 pub fn routes() -> Router<AppState> {
-    Router::new().route("/block-filter/stats", get(get_block_filter_stats))
+    Router::new()
+        .route("/block-filter/stats", get(get_block_filter_stats))
+        .route("/blocklist/status", get(get_blocklist_status))
+        .route("/blocklist/sources/stats", get(get_blocklist_source_stats))
 }
 
 pub async fn get_block_filter_stats(
@@ -14,3 +23,41 @@ pub async fn get_block_filter_stats(
         total_blocked_domains: total,
     })
 }
+
+pub async fn get_blocklist_status(State(state): State<AppState>) -> Json<BlocklistStatusResponse> {
+    let status = state.blocking.get_block_filter_stats.compile_status();
+    Json(BlocklistStatusResponse {
+        phase: status.phase.as_str(),
+        origin: status.origin.as_str(),
+        lists_total: status.lists_total,
+        lists_done: status.lists_done,
+        compiled_domains: status.compiled_domains,
+        built_at: status
+            .built_at
+            .and_then(|secs| chrono::DateTime::from_timestamp(secs, 0))
+            .map(|at| at.to_rfc3339()),
+        last_duration_ms: status.last_duration_ms,
+        last_error: status.last_error,
+    })
+}
+
+pub async fn get_blocklist_source_stats(
+    State(state): State<AppState>,
+) -> Json<BlocklistSourceStatsResponse> {
+    let report = state.blocking.get_block_filter_stats.source_hit_stats();
+    Json(BlocklistSourceStatsResponse {
+        window_secs: report.window_secs,
+        sources: report
+            .sources
+            .into_iter()
+            .map(|stats| BlocklistSourceHitsResponse {
+                bit: stats.source.bit,
+                source_id: stats.source.source_id,
+                name: stats.source.name.to_string(),
+                entries: stats.entries,
+                unique_blocks: stats.unique_blocks,
+                redundant_blocks: stats.redundant_blocks,
+            })
+            .collect(),
+    })
+}
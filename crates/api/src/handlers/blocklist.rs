@@ -1,30 +1,31 @@
 use crate::{
-    dto::{BlocklistQuery, BlocklistResponse, PaginatedBlocklist},
+    dto::{BlocklistResponse, BulkAddResponse, ListParams, Page},
     errors::ApiError,
     state::AppState,
 };
 use axum::{
+    body::Bytes,
     extract::{Query, State},
+    http::{header, HeaderMap},
     Json,
 };
+use ferrous_dns_domain::{blocklist::BlockedDomain, DomainError};
 use tracing::{debug, instrument};
 
 #[instrument(skip(state), name = "api_get_blocklist")]
 pub async fn get_blocklist(
     State(state): State<AppState>,
-    Query(params): Query<BlocklistQuery>,
-) -> Result<Json<PaginatedBlocklist>, ApiError> {
+    Query(params): Query<ListParams>,
+) -> Result<Page<BlocklistResponse>, ApiError> {
+    let page = params.page_request(BlockedDomain::SORT_FIELDS)?;
     debug!(
-        limit = params.limit,
-        offset = params.offset,
+        limit = page.limit,
+        offset = page.offset,
+        sort = ?page.sort,
         "Fetching blocklist"
     );
 
-    let (domains, total) = state
-        .blocking
-        .get_blocklist
-        .execute_paged(params.limit, params.offset)
-        .await?;
+    let (domains, total) = state.blocking.get_blocklist.execute_paged(&page).await?;
 
     debug!(
         count = domains.len(),
@@ -39,10 +40,42 @@ pub async fn get_blocklist(
         })
         .collect();
 
-    Ok(Json(PaginatedBlocklist {
-        data,
-        total,
-        limit: params.limit,
-        offset: params.offset,
-    }))
+    Ok(params.page(&page, data, total))
+}
+
+#[instrument(skip(state, headers, body), name = "api_bulk_add_blocklist")]
+pub async fn bulk_add_blocklist(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Json<BulkAddResponse>, ApiError> {
+    let entries = parse_bulk_body(&headers, &body)?;
+    debug!(entries = entries.len(), "Bulk adding to blocklist");
+
+    let summary = state.blocking.bulk_add_blocklist.execute(&entries).await?;
+    Ok(Json(BulkAddResponse::from_summary(summary)))
+}
+
+/// Reads a bulk body: a JSON array of strings when the content type is
+/// `application/json`, otherwise one domain per line.
+pub(crate) fn parse_bulk_body(headers: &HeaderMap, body: &[u8]) -> Result<Vec<String>, ApiError> {
+    let is_json = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|ct| ct.starts_with("application/json"));
+
+    if is_json {
+        return serde_json::from_slice(body).map_err(|e| {
+            ApiError(DomainError::InvalidInput(format!(
+                "Expected a JSON array of domains: {e}"
+            )))
+        });
+    }
+
+    let text = std::str::from_utf8(body).map_err(|_| {
+        ApiError(DomainError::InvalidInput(
+            "Request body is not valid UTF-8".to_string(),
+        ))
+    })?;
+    Ok(text.lines().map(String::from).collect())
 }
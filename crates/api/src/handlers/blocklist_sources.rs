@@ -1,15 +1,16 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::Json,
     routing::{delete, get, post, put},
     Router,
 };
-use ferrous_dns_domain::DomainError;
+use ferrous_dns_domain::{BlocklistSource, DomainError};
 use tracing::debug;
 
 use crate::{
     dto::{BlocklistSourceResponse, CreateBlocklistSourceRequest, UpdateBlocklistSourceRequest},
+    dto::{ListParams, Page},
     errors::ApiError,
     state::AppState,
 };
@@ -25,18 +26,19 @@ pub fn routes() -> Router<AppState> {
 
 async fn get_all_blocklist_sources(
     State(state): State<AppState>,
-) -> Result<Json<Vec<BlocklistSourceResponse>>, ApiError> {
-    let sources = state.blocking.get_blocklist_sources.get_all().await?;
+    Query(params): Query<ListParams>,
+) -> Result<Page<BlocklistSourceResponse>, ApiError> {
+    let page = params.page_request(BlocklistSource::SORT_FIELDS)?;
+    let (sources, total) = state.blocking.get_blocklist_sources.get_page(&page).await?;
     debug!(
         count = sources.len(),
-        "Blocklist sources retrieved successfully"
+        total, "Blocklist sources retrieved successfully"
     );
-    Ok(Json(
-        sources
-            .into_iter()
-            .map(BlocklistSourceResponse::from_source)
-            .collect(),
-    ))
+    let data = sources
+        .into_iter()
+        .map(BlocklistSourceResponse::from_source)
+        .collect();
+    Ok(params.page(&page, data, total))
 }
 
 async fn get_blocklist_source_by_id(
@@ -85,7 +87,15 @@ async fn update_blocklist_source(
     let source = state
         .blocking
         .update_blocklist_source
-        .execute(id, req.name, req.url, group_ids, req.comment, req.enabled)
+        .execute(
+            id,
+            req.name,
+            req.url,
+            group_ids,
+            req.comment,
+            req.enabled,
+            req.audit_mode,
+        )
         .await?;
     Ok(Json(BlocklistSourceResponse::from_source(source)))
 }
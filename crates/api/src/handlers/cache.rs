@@ -1,5 +1,9 @@
 use crate::{
-    dto::{CacheMetricsResponse, CacheStatsQuery, CacheStatsResponse},
+    dto::{
+        CacheFlushQuery, CacheFlushResponse, CacheHotKeysQuery, CacheHotKeysResponse,
+        CacheMetricsResponse, CacheShardsResponse, CacheStatsQuery, CacheStatsResponse,
+        HotKeyResponse, RefreshBudgetResponse,
+    },
     errors::ApiError,
     state::AppState,
     utils::{parse_period, validate_period},
@@ -8,8 +12,12 @@ use axum::{
     extract::{Query, State},
     Json,
 };
+use ferrous_dns_domain::{BlockedDomain, DomainError};
 use tracing::{debug, instrument};
 
+const DEFAULT_HOT_KEYS: usize = 10;
+const MAX_HOT_KEYS: usize = 50;
+
 #[instrument(skip(state), name = "api_get_cache_stats")]
 pub async fn get_cache_stats(
     State(state): State<AppState>,
@@ -25,6 +33,7 @@ pub async fn get_cache_stats(
 
     let stats = state.query.get_cache_stats.execute(period_hours).await?;
     let total_entries = state.dns.cache.cache_size();
+    let snapshot = state.dns.cache.cache_metrics_snapshot();
 
     debug!(
         total_entries = total_entries,
@@ -37,6 +46,8 @@ pub async fn get_cache_stats(
 
     Ok(Json(CacheStatsResponse {
         total_entries,
+        memory_bytes: snapshot.memory_bytes,
+        max_memory_bytes: snapshot.max_memory_bytes,
         total_hits: stats.total_hits,
         total_misses: stats.total_misses,
         total_refreshes: stats.total_refreshes,
@@ -68,10 +79,101 @@ pub async fn get_cache_metrics(State(state): State<AppState>) -> Json<CacheMetri
         evictions: snapshot.evictions,
         optimistic_refreshes: snapshot.optimistic_refreshes,
         stale_hits: snapshot.stale_hits,
+        l1_hits: snapshot.l1_hits,
+        memory_bytes: snapshot.memory_bytes,
+        max_memory_bytes: snapshot.max_memory_bytes,
         lazy_deletions: snapshot.lazy_deletions,
         compactions: snapshot.compactions,
         batch_evictions: snapshot.batch_evictions,
         hit_rate: snapshot.hit_rate,
         transient_upstream_errors: snapshot.transient_upstream_errors,
+        refresh_budget: snapshot.refresh_budget.map(|budget| {
+            let requested = budget.granted + budget.deferred;
+            RefreshBudgetResponse {
+                limit_qps: budget.limit_qps,
+                client_qps: budget.client_qps,
+                health_factor: budget.health_factor,
+                granted: budget.granted,
+                deferred: budget.deferred,
+                usage: if requested > 0 {
+                    budget.granted as f64 / requested as f64
+                } else {
+                    0.0
+                },
+            }
+        }),
     })
 }
+
+#[instrument(skip(state), name = "api_get_cache_hot_keys")]
+pub async fn get_cache_hot_keys(
+    State(state): State<AppState>,
+    Query(params): Query<CacheHotKeysQuery>,
+) -> Json<CacheHotKeysResponse> {
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_HOT_KEYS)
+        .clamp(1, MAX_HOT_KEYS);
+
+    let snapshot = state.dns.cache.hot_keys(limit);
+    let entries = state.dns.cache.shard_occupancy();
+    let max_entries = entries.iter().copied().max().unwrap_or(0);
+    let mean_entries = if entries.is_empty() {
+        0.0
+    } else {
+        entries.iter().sum::<usize>() as f64 / entries.len() as f64
+    };
+
+    debug!(
+        total_hits = snapshot.total_hits,
+        hot_keys = snapshot.keys.len(),
+        shards = entries.len(),
+        "Cache hot keys retrieved"
+    );
+
+    let total_hits = snapshot.total_hits;
+    Json(CacheHotKeysResponse {
+        window_secs: snapshot.window_secs,
+        total_hits,
+        shards: CacheShardsResponse {
+            entries,
+            max_entries,
+            mean_entries,
+        },
+        keys: snapshot
+            .keys
+            .into_iter()
+            .map(|key| HotKeyResponse {
+                share: if total_hits > 0 {
+                    key.hits.min(total_hits) as f64 / total_hits as f64
+                } else {
+                    0.0
+                },
+                domain: key.domain,
+                record_type: key.record_type.to_string(),
+                hits: key.hits,
+            })
+            .collect(),
+    })
+}
+
+/// Drops everything cached for `suffix` and the names below it, e.g. after
+/// fixing records at the registrar or a bad upstream answer.
+#[instrument(skip(state), name = "api_flush_cache")]
+pub async fn flush_cache(
+    State(state): State<AppState>,
+    Query(params): Query<CacheFlushQuery>,
+) -> Result<Json<CacheFlushResponse>, ApiError> {
+    let suffix = params
+        .suffix
+        .trim()
+        .trim_end_matches('.')
+        .to_ascii_lowercase();
+    BlockedDomain::validate_domain(&suffix).map_err(DomainError::InvalidDomainName)?;
+
+    let removed = state.dns.cache.flush_suffix(&suffix);
+
+    debug!(suffix = %suffix, removed, "Cache flushed by suffix");
+
+    Ok(Json(CacheFlushResponse { suffix, removed }))
+}
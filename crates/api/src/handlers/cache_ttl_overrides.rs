@@ -0,0 +1,91 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    routing::{delete, get, post, put},
+    Router,
+};
+use ferrous_dns_domain::DomainError;
+use tracing::debug;
+
+use crate::{
+    dto::{CacheTtlOverrideRequest, CacheTtlOverrideResponse},
+    errors::ApiError,
+    state::AppState,
+};
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/cache/ttl-overrides", get(get_all_ttl_overrides))
+        .route("/cache/ttl-overrides", post(create_ttl_override))
+        .route("/cache/ttl-overrides/{id}", get(get_ttl_override_by_id))
+        .route("/cache/ttl-overrides/{id}", put(update_ttl_override))
+        .route("/cache/ttl-overrides/{id}", delete(delete_ttl_override))
+}
+
+async fn get_all_ttl_overrides(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<CacheTtlOverrideResponse>>, ApiError> {
+    let overrides = state.policies.get_ttl_overrides.get_all().await?;
+    debug!(
+        count = overrides.len(),
+        "Cache TTL overrides retrieved successfully"
+    );
+    Ok(Json(
+        overrides
+            .into_iter()
+            .map(CacheTtlOverrideResponse::from_domain)
+            .collect(),
+    ))
+}
+
+async fn get_ttl_override_by_id(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<CacheTtlOverrideResponse>, ApiError> {
+    let ttl_override = state
+        .policies
+        .get_ttl_overrides
+        .get_by_id(id)
+        .await?
+        .ok_or(ApiError(DomainError::CacheTtlOverrideNotFound(id)))?;
+    Ok(Json(CacheTtlOverrideResponse::from_domain(ttl_override)))
+}
+
+async fn create_ttl_override(
+    State(state): State<AppState>,
+    Json(req): Json<CacheTtlOverrideRequest>,
+) -> Result<(StatusCode, Json<CacheTtlOverrideResponse>), ApiError> {
+    let ttl_override = state
+        .policies
+        .create_ttl_override
+        .execute(req.into_domain())
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(CacheTtlOverrideResponse::from_domain(ttl_override)),
+    ))
+}
+
+async fn update_ttl_override(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(req): Json<CacheTtlOverrideRequest>,
+) -> Result<Json<CacheTtlOverrideResponse>, ApiError> {
+    let ttl_override = state
+        .policies
+        .update_ttl_override
+        .execute(id, req.into_domain())
+        .await?;
+
+    Ok(Json(CacheTtlOverrideResponse::from_domain(ttl_override)))
+}
+
+async fn delete_ttl_override(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, ApiError> {
+    state.policies.delete_ttl_override.execute(id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
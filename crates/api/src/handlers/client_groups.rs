@@ -29,5 +29,8 @@ pub async fn assign_client_to_group(
         last_seen: client.last_seen.unwrap_or_default(),
         query_count: client.query_count,
         group_id: client.group_id,
+        display_name: client.display_name.map(|s| s.to_string()),
+        category: client.category,
+        notes: client.notes.map(|s| s.to_string()),
     }))
 }
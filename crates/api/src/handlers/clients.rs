@@ -1,37 +1,37 @@
-use crate::dto::{ClientResponse, ClientStatsResponse, ClientsQuery};
+use crate::dto::{
+    ClientActivityQuery, ClientActivityResponse, ClientDomainCount, ClientResponse,
+    ClientStatsResponse, ClientsQuery, ListParams, Page, TimelineBucket,
+};
 use crate::errors::ApiError;
 use crate::state::AppState;
+use crate::utils::{parse_granularity, parse_period, validate_period};
 use axum::{
-    extract::{Query, State},
+    extract::{Path, Query, State},
     Json,
 };
+use ferrous_dns_domain::Client;
 use tracing::{debug, instrument};
 
 #[instrument(skip(state), name = "api_get_clients")]
 pub async fn get_clients(
     State(state): State<AppState>,
+    Query(list): Query<ListParams>,
     Query(params): Query<ClientsQuery>,
-) -> Result<Json<Vec<ClientResponse>>, ApiError> {
+) -> Result<Page<ClientResponse>, ApiError> {
+    let page = list.page_request(Client::SORT_FIELDS)?;
     debug!(
-        limit = params.limit,
-        offset = params.offset,
+        limit = page.limit,
+        offset = page.offset,
+        sort = ?page.sort,
         active_days = ?params.active_days,
         "Fetching clients"
     );
 
-    let clients = if let Some(days) = params.active_days {
-        state
-            .clients
-            .get_clients
-            .get_active(days, params.limit)
-            .await?
-    } else {
-        state
-            .clients
-            .get_clients
-            .get_all(params.limit, params.offset)
-            .await?
-    };
+    let (clients, total) = state
+        .clients
+        .get_clients
+        .get_page(&page, params.active_days)
+        .await?;
 
     let response: Vec<ClientResponse> = clients
         .into_iter()
@@ -44,11 +44,17 @@ pub async fn get_clients(
             last_seen: c.last_seen.unwrap_or_default(),
             query_count: c.query_count,
             group_id: c.group_id,
+            display_name: c.display_name.map(|s| s.to_string()),
+            category: c.category,
+            notes: c.notes.map(|s| s.to_string()),
         })
         .collect();
 
-    debug!(count = response.len(), "Clients retrieved successfully");
-    Ok(Json(response))
+    debug!(
+        count = response.len(),
+        total, "Clients retrieved successfully"
+    );
+    Ok(list.page(&page, response, total))
 }
 
 #[instrument(skip(state), name = "api_get_client_stats")]
@@ -67,3 +73,56 @@ pub async fn get_client_stats(
         with_hostname: stats.with_hostname,
     }))
 }
+
+#[instrument(skip(state), name = "api_get_client_activity")]
+pub async fn get_client_activity(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Query(params): Query<ClientActivityQuery>,
+) -> Result<Json<ClientActivityResponse>, ApiError> {
+    debug!(
+        client_id = id,
+        period = %params.period,
+        granularity = %params.granularity,
+        "Fetching client activity"
+    );
+
+    let period_hours = parse_period(&params.period)
+        .map(|h| validate_period(h).ceil() as u32)
+        .unwrap_or(24);
+
+    let activity = state
+        .clients
+        .get_client_activity
+        .execute(
+            id,
+            period_hours,
+            parse_granularity(&params.granularity),
+            params.limit,
+        )
+        .await?;
+
+    let to_counts = |domains: Vec<(String, u64)>| {
+        domains
+            .into_iter()
+            .map(|(domain, count)| ClientDomainCount { domain, count })
+            .collect()
+    };
+
+    Ok(Json(ClientActivityResponse {
+        client_id: id,
+        period: params.period,
+        granularity: params.granularity,
+        total_queries: activity.total_queries,
+        blocked_queries: activity.blocked_queries,
+        cache_hits: activity.cache_hits,
+        cache_hit_rate: activity.cache_hit_rate,
+        timeline: activity
+            .timeline
+            .into_iter()
+            .map(TimelineBucket::from)
+            .collect(),
+        top_domains: to_counts(activity.top_domains),
+        top_blocked_domains: to_counts(activity.top_blocked_domains),
+    }))
+}
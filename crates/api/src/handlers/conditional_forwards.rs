@@ -0,0 +1,37 @@
+use crate::state::AppState;
+use axum::{extract::State, Json};
+use serde::Serialize;
+
+/// Counters of one `dns.conditional_forwards` rule since startup.
+#[derive(Debug, Serialize)]
+pub struct ConditionalForwardResponse {
+    pub zone: String,
+    pub servers: Vec<String>,
+    pub queries: u64,
+    pub answered: u64,
+    pub nxdomain: u64,
+    pub failures: u64,
+    pub avg_latency_ms: Option<f64>,
+}
+
+pub async fn get_conditional_forwards(
+    State(state): State<AppState>,
+) -> Json<Vec<ConditionalForwardResponse>> {
+    let rules = state
+        .dns
+        .conditional_forwards
+        .rule_stats()
+        .into_iter()
+        .map(|rule| ConditionalForwardResponse {
+            zone: rule.zone,
+            servers: rule.servers,
+            queries: rule.queries,
+            answered: rule.answered,
+            nxdomain: rule.nxdomain,
+            failures: rule.failures,
+            avg_latency_ms: rule.avg_latency_ms,
+        })
+        .collect();
+
+    Json(rules)
+}
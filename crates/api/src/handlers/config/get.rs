@@ -99,6 +99,8 @@ pub async fn get_config(State(state): State<AppState>) -> Json<ConfigResponse> {
         },
         logging: LoggingConfigResponse {
             level: config.logging.level.clone(),
+            format: config.logging.format,
+            modules: config.logging.modules.clone(),
         },
         database: DatabaseConfigResponse {
             path: config.database.path.clone(),
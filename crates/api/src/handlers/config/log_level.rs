@@ -0,0 +1,42 @@
+use crate::{
+    dto::{LogLevelResponse, UpdateLogLevelRequest},
+    errors::ApiError,
+    state::AppState,
+};
+use axum::{extract::State, Json};
+use ferrous_dns_domain::DomainError;
+use tracing::{info, instrument};
+
+/// Applies a new default level and module overrides to the running log
+/// filter and the in-memory config. The config file is not rewritten.
+#[instrument(skip(state), name = "api_update_log_level")]
+pub async fn update_log_level(
+    State(state): State<AppState>,
+    Json(request): Json<UpdateLogLevelRequest>,
+) -> Result<Json<LogLevelResponse>, ApiError> {
+    let mut config = state.config.write().await;
+
+    let mut logging = config.logging.clone();
+    if let Some(level) = request.level {
+        logging.level = level.trim().to_ascii_lowercase();
+    }
+    if let Some(modules) = request.modules {
+        logging.modules = modules
+            .into_iter()
+            .map(|(module, level)| (module.trim().to_string(), level.trim().to_ascii_lowercase()))
+            .collect();
+    }
+    logging.validate().map_err(DomainError::InvalidInput)?;
+
+    state
+        .log_levels
+        .set_levels(&logging.level, &logging.modules)?;
+    info!(level = %logging.level, overrides = logging.modules.len(), "Log level changed");
+
+    let response = LogLevelResponse {
+        level: logging.level.clone(),
+        modules: logging.modules.clone(),
+    };
+    config.logging = logging;
+    Ok(Json(response))
+}
@@ -1,5 +1,7 @@
 pub mod get;
+pub mod log_level;
 pub mod update;
 
 pub use get::{get_config, get_settings};
+pub use log_level::update_log_level;
 pub use update::{reload_config, update_config, update_settings};
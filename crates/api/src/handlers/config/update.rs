@@ -1,19 +1,21 @@
 use crate::{
     dto::{SettingsDto, UpdateConfigRequest},
+    i18n::{Locale, Message},
     state::AppState,
 };
 use axum::{extract::State, Json};
-use ferrous_dns_domain::{UpstreamPool, UpstreamStrategy};
-use tracing::{debug, error, info, instrument};
+use ferrous_dns_domain::{ConfigFieldError, UpstreamPool, UpstreamStrategy};
+use tracing::{debug, error, info, instrument, warn};
 
 async fn get_writable_config_path(
     state: &crate::state::AppState,
+    locale: Locale,
 ) -> Result<String, Json<serde_json::Value>> {
     let path = state.resolve_config_path().ok_or_else(|| {
         error!("No config file found");
         Json(serde_json::json!({
             "success": false,
-            "error": "No config file found. Cannot update configuration."
+            "error": Message::NoWritableConfigFile.text(locale)
         }))
     })?;
     if let Ok(metadata) = tokio::fs::metadata(&path).await {
@@ -21,27 +23,32 @@ async fn get_writable_config_path(
             error!("Config file is read-only");
             return Err(Json(serde_json::json!({
                 "success": false,
-                "error": "Permission denied: Config file is read-only. Please check file permissions."
+                "error": Message::ConfigFileReadOnly.text(locale)
             })));
         }
     }
     Ok(path)
 }
 
+/// Applies the fields present in the request to a copy of the running config
+/// and saves it only if the result is valid; otherwise every invalid field is
+/// returned in `errors` and nothing changes.
 #[instrument(skip(state), name = "api_update_config")]
 pub async fn update_config(
     State(state): State<AppState>,
+    locale: Locale,
     Json(request): Json<UpdateConfigRequest>,
 ) -> Json<serde_json::Value> {
     debug!("Updating configuration");
 
-    let config_path = match get_writable_config_path(&state).await {
+    let config_path = match get_writable_config_path(&state, locale).await {
         Ok(p) => p,
         Err(e) => return e,
     };
 
     let mut new_config = state.config.read().await.clone();
     let mut restart_required = false;
+    let mut errors = Vec::new();
 
     if let Some(server_update) = request.server {
         if let Some(pihole_compat) = server_update.pihole_compat {
@@ -66,20 +73,39 @@ pub async fn update_config(
         if let Some(pools) = dns_update.pools {
             new_config.dns.pools = pools
                 .into_iter()
-                .map(|p| {
-                    let strategy = if p.strategy.eq_ignore_ascii_case("failover") {
-                        UpstreamStrategy::Failover
-                    } else if p.strategy.eq_ignore_ascii_case("balanced") {
-                        UpstreamStrategy::Balanced
-                    } else {
-                        UpstreamStrategy::Parallel
+                .enumerate()
+                .map(|(i, p)| {
+                    let strategy = match p.strategy.to_ascii_lowercase().as_str() {
+                        "parallel" => UpstreamStrategy::Parallel,
+                        "failover" => UpstreamStrategy::Failover,
+                        "balanced" => UpstreamStrategy::Balanced,
+                        _ => {
+                            errors.push(ConfigFieldError::new(
+                                format!("dns.pools[{i}].strategy"),
+                                "must be parallel, balanced or failover",
+                            ));
+                            UpstreamStrategy::Parallel
+                        }
                     };
+                    // Key names and socket modes are only set in the config
+                    // file; keep them for pools the update does not rename.
+                    let existing = new_config
+                        .dns
+                        .pools
+                        .iter()
+                        .find(|existing| existing.name == p.name);
+                    let tsig_key = existing.and_then(|existing| existing.tsig_key.clone());
+                    let udp_socket = existing
+                        .map(|existing| existing.udp_socket)
+                        .unwrap_or_default();
                     UpstreamPool {
                         name: p.name,
                         strategy,
                         priority: p.priority,
                         servers: p.servers,
                         weight: None,
+                        tsig_key,
+                        udp_socket,
                     }
                 })
                 .collect();
@@ -220,6 +246,24 @@ pub async fn update_config(
         }
     }
 
+    errors.extend(new_config.field_errors());
+    if errors.is_empty() {
+        if let Err(e) = new_config.validate() {
+            errors.push(ConfigFieldError::new("config", e.to_string()));
+        }
+    }
+    if !errors.is_empty() {
+        warn!(
+            errors = errors.len(),
+            "Rejected invalid configuration update"
+        );
+        return Json(serde_json::json!({
+            "success": false,
+            "error": format!("{}: {}", Message::InvalidConfiguration.text(locale), errors[0]),
+            "errors": errors
+        }));
+    }
+
     match state
         .config_file_persistence
         .save_config_to_file(&new_config, &config_path)
@@ -228,13 +272,13 @@ pub async fn update_config(
             *state.config.write().await = new_config;
             info!("Configuration updated successfully");
             let message = if restart_required {
-                "Configuration saved. Restart the server for compatibility changes to take effect."
+                Message::ConfigSavedRestartRequired
             } else {
-                "Configuration saved successfully. Use 'Save & Apply Now' button to reload and apply changes immediately, or restart server later."
+                Message::ConfigSaved
             };
             Json(serde_json::json!({
                 "success": true,
-                "message": message,
+                "message": message.text(locale),
                 "reload_available": true,
                 "restart_required": restart_required
             }))
@@ -243,7 +287,7 @@ pub async fn update_config(
             error!(error = %e, "Failed to save configuration");
             Json(serde_json::json!({
                 "success": false,
-                "error": format!("Failed to save configuration: {}", e)
+                "error": format!("{}: {}", Message::SaveConfigFailed.text(locale), e)
             }))
         }
     }
@@ -252,9 +296,10 @@ pub async fn update_config(
 #[instrument(skip(state), name = "api_update_settings")]
 pub async fn update_settings(
     State(state): State<AppState>,
+    locale: Locale,
     Json(request): Json<SettingsDto>,
 ) -> Json<serde_json::Value> {
-    let config_path = match get_writable_config_path(&state).await {
+    let config_path = match get_writable_config_path(&state, locale).await {
         Ok(p) => p,
         Err(e) => return e,
     };
@@ -282,21 +327,24 @@ pub async fn update_settings(
             info!("DNS settings updated successfully");
             Json(serde_json::json!({
                 "success": true,
-                "message": "DNS settings saved successfully."
+                "message": Message::SettingsSaved.text(locale)
             }))
         }
         Err(e) => {
             error!(error = %e, "Failed to save DNS settings");
             Json(serde_json::json!({
                 "success": false,
-                "error": format!("Failed to save settings: {}", e)
+                "error": format!("{}: {}", Message::SaveSettingsFailed.text(locale), e)
             }))
         }
     }
 }
 
 #[instrument(skip(state), name = "api_reload_config")]
-pub async fn reload_config(State(state): State<AppState>) -> Json<serde_json::Value> {
+pub async fn reload_config(
+    State(state): State<AppState>,
+    locale: Locale,
+) -> Json<serde_json::Value> {
     info!("Config reload requested");
 
     let config_path = match state.resolve_config_path() {
@@ -305,26 +353,33 @@ pub async fn reload_config(State(state): State<AppState>) -> Json<serde_json::Va
             error!("No config file found");
             return Json(serde_json::json!({
                 "success": false,
-                "error": "No config file found"
+                "error": Message::NoConfigFile.text(locale)
             }));
         }
     };
 
     match ferrous_dns_domain::Config::load(Some(&config_path), Default::default()) {
         Ok(new_config) => {
+            state.dns.access_control.apply(&new_config.server);
+            if let Err(e) = state
+                .log_levels
+                .set_levels(&new_config.logging.level, &new_config.logging.modules)
+            {
+                warn!(error = %e, "Failed to apply reloaded log levels");
+            }
             let mut config = state.config.write().await;
             *config = new_config;
             info!("Configuration reloaded successfully");
             Json(serde_json::json!({
                 "success": true,
-                "message": "Configuration reloaded successfully"
+                "message": Message::ConfigReloaded.text(locale)
             }))
         }
         Err(e) => {
             error!(error = %e, "Failed to reload configuration");
             Json(serde_json::json!({
                 "success": false,
-                "error": format!("Failed to reload configuration: {}", e)
+                "error": format!("{}: {}", Message::ReloadConfigFailed.text(locale), e)
             }))
         }
     }
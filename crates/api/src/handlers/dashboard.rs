@@ -10,7 +10,7 @@ use axum::{
     extract::{Query, State},
     Json,
 };
-use ferrous_dns_application::{ports::TimeGranularity, use_cases::RateUnit};
+use ferrous_dns_application::use_cases::{GetTimelineUseCase, RateUnit};
 use tracing::{error, instrument};
 
 const DEFAULT_PERIOD_HOURS: f32 = 24.0;
@@ -65,11 +65,12 @@ pub async fn get_dashboard(
         if params.include_timeline {
             let timeline_state = state.clone();
             let period_u32 = period_hours as u32;
+            let granularity = GetTimelineUseCase::resolve_granularity(period_u32, None);
             let timeline_fut = async move {
                 timeline_state
                     .query
                     .get_timeline
-                    .execute(period_u32, TimeGranularity::QuarterHour)
+                    .execute(period_u32, granularity)
                     .await
             };
 
@@ -88,8 +89,9 @@ pub async fn get_dashboard(
                     Some(TimelineResponse {
                         total_buckets: buckets_dto.len(),
                         period: params.period.clone(),
-                        granularity: "15min".to_string(),
+                        granularity: granularity.as_str().to_string(),
                         buckets: buckets_dto,
+                        series: None,
                     })
                 }
                 Err(e) => {
@@ -141,18 +143,25 @@ pub async fn get_dashboard(
                 queries_total: stats.queries_total,
                 queries_blocked: stats.queries_blocked,
                 queries_rate_limited: stats.queries_rate_limited,
+                queries_any_minimized: stats.queries_any_minimized,
                 queries_malware_detected: stats.queries_malware_detected,
+                blocked_percentage: stats.blocked_percentage(),
                 clients: stats.unique_clients,
                 uptime: stats.uptime_seconds,
                 cache_hit_rate: stats.cache_hit_rate,
                 avg_query_time_ms: stats.avg_query_time_ms,
                 avg_cache_time_ms: stats.avg_cache_time_ms,
                 avg_upstream_time_ms: stats.avg_upstream_time_ms,
+                latency_p50_ms: stats.latency_p50_ms,
+                latency_p95_ms: stats.latency_p95_ms,
+                latency_p99_ms: stats.latency_p99_ms,
                 queries_by_type,
                 most_queried_type,
                 record_type_distribution,
                 top_10_types,
                 source_stats: stats.source_stats,
+                queries_by_group: stats.queries_by_group.iter().map(Into::into).collect(),
+                top_clients: stats.top_clients.iter().map(Into::into).collect(),
             }
         }
         Err(e) => {
@@ -0,0 +1,11 @@
+use crate::{dto::DatabaseStatusResponse, errors::ApiError, state::AppState};
+use axum::{extract::State, Json};
+use tracing::instrument;
+
+#[instrument(skip(state), name = "api_get_database_status")]
+pub async fn get_database_status(
+    State(state): State<AppState>,
+) -> Result<Json<DatabaseStatusResponse>, ApiError> {
+    let status = state.query.database_maintenance.status().await?;
+    Ok(Json(DatabaseStatusResponse::from(status)))
+}
@@ -0,0 +1,169 @@
+use crate::{
+    dto::{
+        ClearFaultsResponse, DiagnoseDomainQuery, DomainDiagnosisResponse, FaultResponse,
+        InflightQueriesQuery, InflightQueriesResponse, InjectFaultRequest, ResolutionTraceResponse,
+        TraceResolveRequest,
+    },
+    errors::ApiError,
+    state::AppState,
+};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use ferrous_dns_application::ports::{FaultInjectionPort, FaultKind};
+use ferrous_dns_domain::{DomainError, RecordType};
+use std::net::IpAddr;
+use std::sync::Arc;
+use tracing::{debug, instrument};
+
+const DEFAULT_INFLIGHT: usize = 100;
+const MAX_INFLIGHT: usize = 1000;
+
+fn parse_client_and_type(
+    client: Option<&str>,
+    record_type: Option<&str>,
+) -> Result<(Option<IpAddr>, RecordType), DomainError> {
+    let client_ip = client
+        .map(|c| {
+            c.parse::<IpAddr>()
+                .map_err(|_| DomainError::InvalidIpAddress(c.to_string()))
+        })
+        .transpose()?;
+    let record_type = match record_type {
+        Some(t) => t.parse::<RecordType>().map_err(DomainError::InvalidInput)?,
+        None => RecordType::A,
+    };
+    Ok((client_ip, record_type))
+}
+
+#[instrument(skip(state), name = "api_diagnose_domain")]
+pub async fn diagnose_domain(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+    Query(params): Query<DiagnoseDomainQuery>,
+) -> Result<Json<DomainDiagnosisResponse>, ApiError> {
+    let (client_ip, record_type) =
+        parse_client_and_type(params.client.as_deref(), params.record_type.as_deref())?;
+
+    let diagnosis = state
+        .dns
+        .diagnose_domain
+        .execute(&name, client_ip, record_type)?;
+    debug!(domain = %diagnosis.domain, outcome = diagnosis.outcome.as_str(), "Domain diagnosed");
+
+    Ok(Json(diagnosis.into()))
+}
+
+#[instrument(skip(state), name = "api_trace_resolve")]
+pub async fn trace_resolve(
+    State(state): State<AppState>,
+    Json(request): Json<TraceResolveRequest>,
+) -> Result<Json<ResolutionTraceResponse>, ApiError> {
+    let (client_ip, record_type) =
+        parse_client_and_type(request.client.as_deref(), request.record_type.as_deref())?;
+
+    let trace = state
+        .dns
+        .trace_resolve
+        .execute(&request.name, client_ip, record_type)
+        .await?;
+    debug!(
+        domain = %trace.diagnosis.domain,
+        steps = trace.steps.len(),
+        elapsed_us = trace.elapsed_us,
+        "Traced resolution"
+    );
+
+    Ok(Json(trace.into()))
+}
+
+#[instrument(skip(state), name = "api_get_inflight_queries")]
+pub async fn get_inflight_queries(
+    State(state): State<AppState>,
+    Query(params): Query<InflightQueriesQuery>,
+) -> Json<InflightQueriesResponse> {
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_INFLIGHT)
+        .clamp(1, MAX_INFLIGHT);
+
+    let snapshot = state.dns.inflight.inflight(limit);
+    debug!(
+        total = snapshot.total,
+        leaders = snapshot.leaders,
+        coalesced = snapshot.coalesced,
+        "In-flight queries retrieved"
+    );
+
+    Json(snapshot.into())
+}
+
+fn fault_injection(state: &AppState) -> Result<&Arc<dyn FaultInjectionPort>, DomainError> {
+    state
+        .dns
+        .faults
+        .as_ref()
+        .ok_or_else(|| DomainError::NotFound("Fault injection is not enabled".to_string()))
+}
+
+fn fault_kind(request: InjectFaultRequest) -> Result<FaultKind, DomainError> {
+    let missing = |field: &str| DomainError::InvalidInput(format!("{field} is required"));
+    match request.kind.as_str() {
+        "upstream_down" => Ok(FaultKind::UpstreamDown {
+            server: request.server.ok_or_else(|| missing("server"))?,
+        }),
+        "latency" => Ok(FaultKind::Latency {
+            server: request.server,
+            delay_ms: request.delay_ms.ok_or_else(|| missing("delay_ms"))?,
+        }),
+        "servfail" => Ok(FaultKind::Servfail {
+            pattern: request.pattern.ok_or_else(|| missing("pattern"))?,
+        }),
+        other => Err(DomainError::InvalidInput(format!(
+            "Unknown fault kind: {other} (expected upstream_down, latency or servfail)"
+        ))),
+    }
+}
+
+#[instrument(skip(state), name = "api_get_faults")]
+pub async fn get_faults(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<FaultResponse>>, ApiError> {
+    let faults = fault_injection(&state)?.faults();
+    debug!(count = faults.len(), "Injected faults retrieved");
+
+    Ok(Json(faults.into_iter().map(FaultResponse::from).collect()))
+}
+
+#[instrument(skip(state), name = "api_inject_fault")]
+pub async fn inject_fault(
+    State(state): State<AppState>,
+    Json(request): Json<InjectFaultRequest>,
+) -> Result<(StatusCode, Json<FaultResponse>), ApiError> {
+    let faults = fault_injection(&state)?;
+    let duration_secs = request.duration_secs;
+    let fault = faults.inject(fault_kind(request)?, duration_secs)?;
+
+    Ok((StatusCode::CREATED, Json(fault.into())))
+}
+
+#[instrument(skip(state), name = "api_remove_fault")]
+pub async fn remove_fault(
+    State(state): State<AppState>,
+    Path(id): Path<u64>,
+) -> Result<StatusCode, ApiError> {
+    if !fault_injection(&state)?.remove(id) {
+        return Err(DomainError::NotFound(format!("Fault {id} not found")).into());
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[instrument(skip(state), name = "api_clear_faults")]
+pub async fn clear_faults(
+    State(state): State<AppState>,
+) -> Result<Json<ClearFaultsResponse>, ApiError> {
+    let cleared = fault_injection(&state)?.clear();
+    Ok(Json(ClearFaultsResponse { cleared }))
+}
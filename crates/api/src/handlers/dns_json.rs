@@ -0,0 +1,74 @@
+use axum::{
+    extract::{ConnectInfo, Query, State},
+    http::header,
+    response::{IntoResponse, Json, Response},
+    routing::get,
+    Router,
+};
+use ferrous_dns_domain::{DnsRequest, DomainError, RecordType};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use tracing::{debug, instrument};
+
+use crate::{
+    dto::{DnsJsonQuery, DnsJsonResponse},
+    errors::ApiError,
+    state::AppState,
+};
+
+const DNS_JSON_CONTENT_TYPE: &str = "application/dns-json; charset=utf-8";
+
+pub fn routes() -> Router<AppState> {
+    Router::new().route("/resolve", get(resolve))
+}
+
+fn parse_record_type(record_type: Option<&str>) -> Result<RecordType, DomainError> {
+    let Some(t) = record_type else {
+        return Ok(RecordType::A);
+    };
+    match t.parse::<u16>() {
+        Ok(code) => RecordType::from_u16(code)
+            .ok_or_else(|| DomainError::InvalidInput(format!("Unknown record type: {}", t))),
+        Err(_) => t.parse::<RecordType>().map_err(DomainError::InvalidInput),
+    }
+}
+
+/// Resolves `name` through the same pipeline as DNS clients, attributed to
+/// the caller's address, and answers in the JSON DoH dialect. DNS failures
+/// are reported in `Status`, not as HTTP errors.
+#[instrument(skip(state, connect_info), name = "api_dns_json_resolve")]
+async fn resolve(
+    State(state): State<AppState>,
+    connect_info: Option<ConnectInfo<SocketAddr>>,
+    Query(params): Query<DnsJsonQuery>,
+) -> Result<Response, ApiError> {
+    let name = params.name.as_deref().map(str::trim).unwrap_or_default();
+    if name.is_empty() {
+        return Err(ApiError(DomainError::InvalidDomainName(
+            "name is required".to_string(),
+        )));
+    }
+    let record_type = parse_record_type(params.record_type.as_deref())?;
+    let domain = DnsRequest::normalize_domain(name);
+    let client_ip = connect_info
+        .map(|ConnectInfo(addr)| addr.ip())
+        .unwrap_or(IpAddr::V4(Ipv4Addr::LOCALHOST));
+
+    let request = DnsRequest::new(domain.as_ref(), record_type, client_ip);
+    let response = match state.dns.resolve_query.execute(&request).await {
+        Ok(resolution) => DnsJsonResponse::from_resolution(&domain, record_type, &resolution),
+        Err(e) => DnsJsonResponse::from_error(&domain, record_type, &e),
+    };
+    debug!(
+        domain = %domain,
+        record_type = %record_type,
+        status = response.status,
+        answers = response.answer.len(),
+        "JSON DNS query answered"
+    );
+
+    Ok((
+        [(header::CONTENT_TYPE, DNS_JSON_CONTENT_TYPE)],
+        Json(response),
+    )
+        .into_response())
+}
@@ -0,0 +1,91 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    routing::{delete, get, post, put},
+    Router,
+};
+use ferrous_dns_domain::DomainError;
+use tracing::debug;
+
+use crate::{
+    dto::{DnsRewriteRequest, DnsRewriteResponse},
+    errors::ApiError,
+    state::AppState,
+};
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/rewrites", get(get_all_rewrites))
+        .route("/rewrites", post(create_rewrite))
+        .route("/rewrites/{id}", get(get_rewrite_by_id))
+        .route("/rewrites/{id}", put(update_rewrite))
+        .route("/rewrites/{id}", delete(delete_rewrite))
+}
+
+async fn get_all_rewrites(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<DnsRewriteResponse>>, ApiError> {
+    let rewrites = state.policies.get_rewrites.get_all().await?;
+    debug!(
+        count = rewrites.len(),
+        "DNS rewrites retrieved successfully"
+    );
+    Ok(Json(
+        rewrites
+            .into_iter()
+            .map(DnsRewriteResponse::from_domain)
+            .collect(),
+    ))
+}
+
+async fn get_rewrite_by_id(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<DnsRewriteResponse>, ApiError> {
+    let rewrite = state
+        .policies
+        .get_rewrites
+        .get_by_id(id)
+        .await?
+        .ok_or(ApiError(DomainError::DnsRewriteNotFound(id)))?;
+    Ok(Json(DnsRewriteResponse::from_domain(rewrite)))
+}
+
+async fn create_rewrite(
+    State(state): State<AppState>,
+    Json(req): Json<DnsRewriteRequest>,
+) -> Result<(StatusCode, Json<DnsRewriteResponse>), ApiError> {
+    let rewrite = state
+        .policies
+        .create_rewrite
+        .execute(req.into_domain())
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(DnsRewriteResponse::from_domain(rewrite)),
+    ))
+}
+
+async fn update_rewrite(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(req): Json<DnsRewriteRequest>,
+) -> Result<Json<DnsRewriteResponse>, ApiError> {
+    let rewrite = state
+        .policies
+        .update_rewrite
+        .execute(id, req.into_domain())
+        .await?;
+
+    Ok(Json(DnsRewriteResponse::from_domain(rewrite)))
+}
+
+async fn delete_rewrite(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, ApiError> {
+    state.policies.delete_rewrite.execute(id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,67 @@
+use axum::{
+    extract::{Query, State},
+    response::Json,
+    routing::get,
+    Router,
+};
+use tracing::debug;
+
+use crate::{
+    dto::{DnssecStatsQuery, DnssecStatsResponse, DnssecStatusQuery, DnssecStatusResponse},
+    errors::ApiError,
+    state::AppState,
+};
+
+const DEFAULT_CHANGES_LIMIT: u32 = 50;
+const MAX_CHANGES_LIMIT: u32 = 1000;
+const DEFAULT_PERIOD_HOURS: u32 = 24;
+const MAX_PERIOD_HOURS: u32 = 30 * 24;
+const RECENT_BOGUS_LIMIT: u32 = 20;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/dnssec/status", get(get_dnssec_status))
+        .route("/dnssec/stats", get(get_dnssec_stats))
+}
+
+async fn get_dnssec_status(
+    State(state): State<AppState>,
+    Query(params): Query<DnssecStatusQuery>,
+) -> Result<Json<DnssecStatusResponse>, ApiError> {
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_CHANGES_LIMIT)
+        .min(MAX_CHANGES_LIMIT);
+
+    let status = state
+        .dns
+        .get_dnssec_status
+        .execute(&params.domain, limit)
+        .await?;
+    debug!(
+        domain = %status.domain,
+        cached = status.cached.len(),
+        changes = status.changes.len(),
+        "DNSSEC status retrieved"
+    );
+
+    Ok(Json(status.into()))
+}
+
+async fn get_dnssec_stats(
+    State(state): State<AppState>,
+    Query(params): Query<DnssecStatsQuery>,
+) -> Result<Json<DnssecStatsResponse>, ApiError> {
+    let period_hours = params
+        .period_hours
+        .unwrap_or(DEFAULT_PERIOD_HOURS)
+        .clamp(1, MAX_PERIOD_HOURS);
+
+    let stats = state
+        .dns
+        .get_dnssec_stats
+        .execute(period_hours, RECENT_BOGUS_LIMIT)
+        .await?;
+
+    Ok(Json(stats.into()))
+}
@@ -1,14 +1,17 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::Json,
     routing::{delete, get, post, put},
     Router,
 };
+use ferrous_dns_domain::Group;
 use tracing::debug;
 
 use crate::{
-    dto::{ClientResponse, CreateGroupRequest, GroupResponse, UpdateGroupRequest},
+    dto::{
+        ClientResponse, CreateGroupRequest, GroupResponse, ListParams, Page, UpdateGroupRequest,
+    },
     errors::ApiError,
     state::AppState,
 };
@@ -25,14 +28,23 @@ pub fn routes() -> Router<AppState> {
 
 async fn get_all_groups(
     State(state): State<AppState>,
-) -> Result<Json<Vec<GroupResponse>>, ApiError> {
-    let groups_with_counts = state.groups.get_groups.get_all_with_client_counts().await?;
+    Query(params): Query<ListParams>,
+) -> Result<Page<GroupResponse>, ApiError> {
+    let page = params.page_request(Group::SORT_FIELDS)?;
+    let (groups_with_counts, total) = state
+        .groups
+        .get_groups
+        .get_page_with_client_counts(&page)
+        .await?;
     let responses: Vec<GroupResponse> = groups_with_counts
         .into_iter()
         .map(|(group, count)| GroupResponse::from_group(group, Some(count)))
         .collect();
-    debug!(count = responses.len(), "Groups retrieved successfully");
-    Ok(Json(responses))
+    debug!(
+        count = responses.len(),
+        total, "Groups retrieved successfully"
+    );
+    Ok(params.page(&page, responses, total))
 }
 
 async fn get_group_by_id(
@@ -88,7 +100,14 @@ async fn update_group(
     let group = state
         .groups
         .update_group
-        .execute(id, req.name, req.enabled, req.comment)
+        .execute(
+            id,
+            req.name,
+            req.enabled,
+            req.comment,
+            req.filter_aaaa,
+            req.audit_mode,
+        )
         .await?;
     let client_count = state
         .groups
@@ -123,6 +142,9 @@ async fn get_group_clients(
             last_seen: c.last_seen.unwrap_or_default(),
             query_count: c.query_count,
             group_id: c.group_id,
+            display_name: c.display_name.map(|s| s.to_string()),
+            category: c.category,
+            notes: c.notes.map(|s| s.to_string()),
         })
         .collect();
     Ok(Json(response))
@@ -0,0 +1,107 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    routing::{delete, get, post, put},
+    Router,
+};
+use ferrous_dns_domain::DomainError;
+use tracing::debug;
+
+use crate::{
+    dto::{
+        CreateIpBlocklistSourceRequest, IpBlocklistSourceResponse, UpdateIpBlocklistSourceRequest,
+    },
+    errors::ApiError,
+    state::AppState,
+};
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/ip-blocklist-sources", get(get_all_ip_blocklist_sources))
+        .route("/ip-blocklist-sources", post(create_ip_blocklist_source))
+        .route(
+            "/ip-blocklist-sources/{id}",
+            get(get_ip_blocklist_source_by_id),
+        )
+        .route(
+            "/ip-blocklist-sources/{id}",
+            put(update_ip_blocklist_source),
+        )
+        .route(
+            "/ip-blocklist-sources/{id}",
+            delete(delete_ip_blocklist_source),
+        )
+}
+
+async fn get_all_ip_blocklist_sources(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<IpBlocklistSourceResponse>>, ApiError> {
+    let sources = state.blocking.get_ip_blocklist_sources.get_all().await?;
+    debug!(
+        count = sources.len(),
+        "IP blocklist sources retrieved successfully"
+    );
+    Ok(Json(
+        sources
+            .into_iter()
+            .map(IpBlocklistSourceResponse::from_source)
+            .collect(),
+    ))
+}
+
+async fn get_ip_blocklist_source_by_id(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<IpBlocklistSourceResponse>, ApiError> {
+    let source = state
+        .blocking
+        .get_ip_blocklist_sources
+        .get_by_id(id)
+        .await?
+        .ok_or(ApiError(DomainError::IpBlocklistSourceNotFound(id)))?;
+    Ok(Json(IpBlocklistSourceResponse::from_source(source)))
+}
+
+async fn create_ip_blocklist_source(
+    State(state): State<AppState>,
+    Json(req): Json<CreateIpBlocklistSourceRequest>,
+) -> Result<(StatusCode, Json<IpBlocklistSourceResponse>), ApiError> {
+    let enabled = req.enabled.unwrap_or(true);
+
+    let source = state
+        .blocking
+        .create_ip_blocklist_source
+        .execute(req.name, req.url, req.comment, enabled)
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(IpBlocklistSourceResponse::from_source(source)),
+    ))
+}
+
+async fn update_ip_blocklist_source(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(req): Json<UpdateIpBlocklistSourceRequest>,
+) -> Result<Json<IpBlocklistSourceResponse>, ApiError> {
+    let source = state
+        .blocking
+        .update_ip_blocklist_source
+        .execute(id, req.name, req.url, req.comment, req.enabled)
+        .await?;
+    Ok(Json(IpBlocklistSourceResponse::from_source(source)))
+}
+
+async fn delete_ip_blocklist_source(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .blocking
+        .delete_ip_blocklist_source
+        .execute(id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
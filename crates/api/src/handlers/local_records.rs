@@ -1,11 +1,13 @@
 use axum::{
-    extract::{Path, State},
-    http::StatusCode,
-    response::Json,
-    routing::{get, post, put},
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Json, Response},
+    routing::{get, post},
     Router,
 };
-use tracing::info;
+use chrono::Utc;
+use ferrous_dns_domain::{DomainError, ZoneFile};
+use tracing::debug;
 
 use crate::{dto::local_record::*, errors::ApiError, state::AppState};
 
@@ -13,50 +15,110 @@ pub fn routes() -> Router<AppState> {
     Router::new()
         .route("/local-records", get(get_all_records))
         .route("/local-records", post(create_record))
+        .route("/local-records/export", get(export_records))
         .route(
             "/local-records/{id}",
-            put(update_record).delete(delete_record),
+            get(get_record_by_id)
+                .put(update_record)
+                .delete(delete_record),
         )
 }
 
 async fn get_all_records(
     State(state): State<AppState>,
 ) -> Result<Json<Vec<LocalRecordDto>>, ApiError> {
-    let config = state.config.read().await;
-    let local_domain = &config.dns.local_domain;
+    let records = state.dns.get_local_records.get_all().await?;
+    let local_domain = state.config.read().await.dns.local_domain.clone();
+    debug!(
+        count = records.len(),
+        "Local records retrieved successfully"
+    );
+    Ok(Json(
+        records
+            .iter()
+            .map(|record| LocalRecordDto::from_domain(record, &local_domain))
+            .collect(),
+    ))
+}
 
-    let dtos: Vec<LocalRecordDto> = config
-        .dns
-        .local_records
-        .iter()
-        .enumerate()
-        .map(|(idx, record)| LocalRecordDto::from_config(record, idx as i64, local_domain))
-        .collect();
+async fn export_records(
+    State(state): State<AppState>,
+    Query(params): Query<ExportLocalRecordsQuery>,
+) -> Result<Response, ApiError> {
+    let records = state.dns.get_local_records.get_all().await?;
+    let local_domain = state.config.read().await.dns.local_domain.clone();
+    let zones = ZoneFile::from_records(&records, &local_domain);
+    debug!(zones = zones.len(), "Local records exported");
+
+    match params.format.as_deref().unwrap_or("zonefile") {
+        "json" => Ok(Json(
+            zones
+                .into_iter()
+                .map(ZoneExportDto::from)
+                .collect::<Vec<_>>(),
+        )
+        .into_response()),
+        "zonefile" => {
+            let now = Utc::now();
+            let serial = u32::try_from(now.timestamp()).unwrap_or(u32::MAX);
+            let text = zones
+                .iter()
+                .map(|zone| zone.to_text(serial))
+                .collect::<Vec<_>>()
+                .join("\n");
+            let content_disposition = format!(
+                "attachment; filename=\"ferrous-local-records-{}.zone\"",
+                now.format("%Y-%m-%d")
+            );
+            Ok((
+                StatusCode::OK,
+                [
+                    (header::CONTENT_TYPE.as_str(), "text/plain; charset=utf-8"),
+                    (
+                        header::CONTENT_DISPOSITION.as_str(),
+                        content_disposition.as_str(),
+                    ),
+                ],
+                text,
+            )
+                .into_response())
+        }
+        other => Err(DomainError::InvalidInput(format!(
+            "Unknown export format '{}' (expected zonefile or json)",
+            other
+        ))
+        .into()),
+    }
+}
 
-    Ok(Json(dtos))
+async fn get_record_by_id(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<LocalRecordDto>, ApiError> {
+    let record = state
+        .dns
+        .get_local_records
+        .get_by_id(id)
+        .await?
+        .ok_or(ApiError(DomainError::LocalRecordNotFound(id)))?;
+    let local_domain = state.config.read().await.dns.local_domain.clone();
+    Ok(Json(LocalRecordDto::from_domain(&record, &local_domain)))
 }
 
 async fn create_record(
     State(state): State<AppState>,
     Json(req): Json<CreateLocalRecordRequest>,
 ) -> Result<(StatusCode, Json<LocalRecordDto>), ApiError> {
-    let (new_record, new_index) = state
+    let record = state
         .dns
         .create_local_record
-        .execute(req.hostname, req.domain, req.ip, req.record_type, req.ttl)
+        .execute(req.into_domain()?)
         .await?;
-
     let local_domain = state.config.read().await.dns.local_domain.clone();
-
-    info!(
-        hostname = %new_record.hostname,
-        ip = %new_record.ip,
-        record_type = %new_record.record_type,
-        "Added local DNS record to config and cache"
-    );
-
-    let dto = LocalRecordDto::from_config(&new_record, new_index as i64, &local_domain);
-    Ok((StatusCode::CREATED, Json(dto)))
+    Ok((
+        StatusCode::CREATED,
+        Json(LocalRecordDto::from_domain(&record, &local_domain)),
+    ))
 }
 
 async fn update_record(
@@ -64,44 +126,19 @@ async fn update_record(
     Path(id): Path<i64>,
     Json(req): Json<UpdateLocalRecordRequest>,
 ) -> Result<Json<LocalRecordDto>, ApiError> {
-    let (updated_record, _old_record) = state
+    let (record, _old) = state
         .dns
         .update_local_record
-        .execute(
-            id,
-            req.hostname,
-            req.domain,
-            req.ip,
-            req.record_type,
-            req.ttl,
-        )
+        .execute(id, req.into_domain()?)
         .await?;
-
     let local_domain = state.config.read().await.dns.local_domain.clone();
-
-    info!(
-        hostname = %updated_record.hostname,
-        ip = %updated_record.ip,
-        record_type = %updated_record.record_type,
-        "Updated local DNS record in config and cache"
-    );
-
-    let dto = LocalRecordDto::from_config(&updated_record, id, &local_domain);
-    Ok(Json(dto))
+    Ok(Json(LocalRecordDto::from_domain(&record, &local_domain)))
 }
 
 async fn delete_record(
     State(state): State<AppState>,
     Path(id): Path<i64>,
 ) -> Result<StatusCode, ApiError> {
-    let removed_record = state.dns.delete_local_record.execute(id).await?;
-
-    info!(
-        hostname = %removed_record.hostname,
-        ip = %removed_record.ip,
-        record_type = %removed_record.record_type,
-        "Removed local DNS record from config and cache"
-    );
-
+    state.dns.delete_local_record.execute(id).await?;
     Ok(StatusCode::NO_CONTENT)
 }
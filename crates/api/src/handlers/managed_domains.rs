@@ -5,13 +5,13 @@ use axum::{
     routing::{delete, get, post, put},
     Router,
 };
-use ferrous_dns_domain::{DomainAction, DomainError};
+use ferrous_dns_domain::{DomainAction, DomainError, ManagedDomain};
 use tracing::debug;
 
 use crate::{
     dto::{
-        CreateManagedDomainRequest, ManagedDomainQuery, ManagedDomainResponse,
-        PaginatedManagedDomains, UpdateManagedDomainRequest,
+        CreateManagedDomainRequest, ListParams, ManagedDomainResponse, Page,
+        UpdateManagedDomainRequest,
     },
     errors::ApiError,
     state::AppState,
@@ -28,26 +28,23 @@ pub fn routes() -> Router<AppState> {
 
 async fn get_all_managed_domains(
     State(state): State<AppState>,
-    Query(params): Query<ManagedDomainQuery>,
-) -> Result<Json<PaginatedManagedDomains>, ApiError> {
+    Query(params): Query<ListParams>,
+) -> Result<Page<ManagedDomainResponse>, ApiError> {
+    let page = params.page_request(ManagedDomain::SORT_FIELDS)?;
     let (domains, total) = state
         .blocking
         .get_managed_domains
-        .get_all_paged(params.limit, params.offset)
+        .get_all_paged(&page)
         .await?;
     debug!(
         count = domains.len(),
         total, "Managed domains retrieved successfully"
     );
-    Ok(Json(PaginatedManagedDomains {
-        data: domains
-            .into_iter()
-            .map(ManagedDomainResponse::from_domain)
-            .collect(),
-        total,
-        limit: params.limit,
-        offset: params.offset,
-    }))
+    let data = domains
+        .into_iter()
+        .map(ManagedDomainResponse::from_domain)
+        .collect();
+    Ok(params.page(&page, data, total))
 }
 
 async fn get_managed_domain_by_id(
@@ -3,6 +3,7 @@ use axum::{
     http::StatusCode,
     response::Json,
 };
+use ferrous_dns_application::use_cases::ClientMetadataUpdate;
 use ferrous_dns_domain::DomainError;
 
 use crate::{
@@ -38,6 +39,9 @@ pub async fn create_manual_client(
             last_seen: client.last_seen.unwrap_or_default(),
             query_count: client.query_count,
             group_id: client.group_id,
+            display_name: client.display_name.map(|s| s.to_string()),
+            category: client.category,
+            notes: client.notes.map(|s| s.to_string()),
         }),
     ))
 }
@@ -47,12 +51,26 @@ pub async fn update_manual_client(
     Path(id): Path<i64>,
     Json(req): Json<UpdateClientRequest>,
 ) -> Result<Json<ClientResponse>, ApiError> {
-    let client = state
+    let metadata = ClientMetadataUpdate {
+        display_name: req.display_name,
+        category: req.category,
+        notes: req.notes,
+    };
+
+    let mut client = state
         .clients
         .update_client
         .execute(id, req.hostname, req.group_id)
         .await?;
 
+    if !metadata.is_empty() {
+        client = state
+            .clients
+            .update_client
+            .update_metadata(id, metadata)
+            .await?;
+    }
+
     Ok(Json(ClientResponse {
         id: client.id.unwrap_or(0),
         ip_address: client.ip_address.to_string(),
@@ -62,6 +80,9 @@ pub async fn update_manual_client(
         last_seen: client.last_seen.unwrap_or_default(),
         query_count: client.query_count,
         group_id: client.group_id,
+        display_name: client.display_name.map(|s| s.to_string()),
+        category: client.category,
+        notes: client.notes.map(|s| s.to_string()),
     }))
 }
 
@@ -1,4 +1,7 @@
+pub mod access_control;
+pub mod alerts;
 pub mod api_tokens;
+pub mod audit_log;
 pub mod auth;
 pub mod backup;
 pub mod block_filter;
@@ -6,44 +9,68 @@ pub mod blocked_services;
 pub mod blocklist;
 pub mod blocklist_sources;
 pub mod cache;
+pub mod cache_ttl_overrides;
 pub mod client_groups;
 pub mod client_subnets;
 pub mod clients;
+pub mod conditional_forwards;
 pub mod config;
 pub mod custom_services;
 pub mod dashboard;
+pub mod database;
+pub mod debug;
+pub mod dns_json;
+pub mod dns_rewrites;
+pub mod dnssec;
 pub mod groups;
 pub mod health;
 pub mod hostname;
+pub mod ip_blocklist_sources;
 pub mod local_records;
 pub mod managed_domains;
 pub mod manual_clients;
 pub mod queries;
+pub mod query_policies;
 pub mod rate;
+pub mod record_type_policies;
 pub mod regex_filters;
 pub mod stats;
 pub mod system_info;
+pub mod tenants;
 pub mod timeline;
+pub mod tld_policies;
 pub mod tls;
+pub mod unblock_requests;
 pub mod whitelist;
 pub mod whitelist_sources;
 
-pub use blocklist::get_blocklist;
-pub use cache::{get_cache_metrics, get_cache_stats};
+pub use blocklist::{bulk_add_blocklist, get_blocklist};
+pub use cache::{flush_cache, get_cache_hot_keys, get_cache_metrics, get_cache_stats};
 pub use client_groups::assign_client_to_group;
-pub use clients::{get_client_stats, get_clients};
-pub use config::{get_config, get_settings, reload_config, update_config, update_settings};
+pub use clients::{get_client_activity, get_client_stats, get_clients};
+pub use config::{
+    get_config, get_settings, reload_config, update_config, update_log_level, update_settings,
+};
 pub use dashboard::get_dashboard;
+pub use database::get_database_status;
+pub use debug::{
+    clear_faults, diagnose_domain, get_faults, get_inflight_queries, inject_fault, remove_fault,
+    trace_resolve,
+};
 pub use health::health_check;
 pub use hostname::get_hostname;
 pub use manual_clients::{create_manual_client, delete_manual_client, update_manual_client};
 pub use queries::get_queries;
 pub use rate::get_query_rate;
-pub use stats::get_stats;
-pub use system_info::get_system_info;
+pub use stats::{get_public_stats, get_stats};
+pub use system_info::{get_system_info, get_system_metrics};
 pub use timeline::get_timeline;
-pub use whitelist::get_whitelist;
+pub use whitelist::{bulk_add_whitelist, get_whitelist};
 pub mod safe_search;
 pub mod schedule_profiles;
+pub mod setup;
+pub mod sinkhole;
+pub mod slow_queries;
+pub mod standby;
 pub mod upstream;
 pub mod users;
@@ -1,31 +1,36 @@
 use crate::{
-    dto::{PaginatedQueries, QueryParams, QueryResponse},
+    dto::{ListParams, Page, QueryPageMeta, QueryParams, QueryResponse},
     errors::ApiError,
     state::AppState,
     utils::{parse_period, validate_period},
 };
-use axum::{
-    extract::{Query, State},
-    Json,
-};
+use axum::extract::{Query, State};
 use ferrous_dns_application::use_cases::PagedQueryInput;
 use tracing::{debug, instrument};
 
 #[instrument(skip(state), name = "api_get_queries")]
 pub async fn get_queries(
     State(state): State<AppState>,
+    Query(list): Query<ListParams>,
     Query(params): Query<QueryParams>,
-) -> Result<Json<PaginatedQueries>, ApiError> {
+) -> Result<Page<QueryResponse, QueryPageMeta>, ApiError> {
+    // The query log is always newest-first; cursor pagination depends on it.
+    let page = list.page_request(&[])?;
     debug!(
         period = %params.period,
-        limit = params.limit,
-        offset = params.offset,
+        limit = page.limit,
+        offset = page.offset,
         cursor = params.cursor,
         domain = ?params.domain,
         category = ?params.category,
         client = ?params.client,
         record_type = ?params.record_type,
         upstream = ?params.upstream,
+        from = ?params.from,
+        to = ?params.to,
+        blocked = ?params.blocked,
+        dnssec = ?params.dnssec,
+        source = ?params.source,
         "Fetching recent queries"
     );
 
@@ -34,8 +39,8 @@ pub async fn get_queries(
         .unwrap_or(24.0);
 
     let input = PagedQueryInput {
-        limit: params.limit,
-        offset: params.offset,
+        limit: page.limit,
+        offset: page.offset,
         period_hours,
         cursor: params.cursor,
         domain: params.domain.as_deref(),
@@ -43,6 +48,11 @@ pub async fn get_queries(
         client_ip: params.client.as_deref(),
         record_type: params.record_type.as_deref(),
         upstream: params.upstream.as_deref(),
+        from: params.from.as_deref(),
+        to: params.to.as_deref(),
+        blocked: params.blocked,
+        dnssec_status: params.dnssec.as_deref(),
+        query_source: params.source.as_deref(),
     };
 
     let result = state.query.get_queries.execute_paged(&input).await?;
@@ -55,6 +65,8 @@ pub async fn get_queries(
             domain: q.domain,
             client: q.client_ip.to_string(),
             client_hostname: q.client_hostname,
+            client_mac: q.client_mac,
+            group_name: q.group_name,
             record_type: q.record_type.as_str(),
             blocked: q.blocked,
             response_time_us: q.response_time_us,
@@ -63,8 +75,12 @@ pub async fn get_queries(
             dnssec_status: q.dnssec_status,
             upstream_server: q.upstream_server,
             upstream_pool: q.upstream_pool,
+            upstream_strategy: q.upstream_strategy,
+            upstream_attempt: q.upstream_attempt,
+            upstream_protocol: q.upstream_protocol,
             query_source: q.query_source.as_str(),
             block_source: q.block_source.map(|s| s.to_str()),
+            plugin: q.plugin,
             response_status: q.response_status,
         })
         .collect();
@@ -77,12 +93,10 @@ pub async fn get_queries(
         "Queries retrieved successfully"
     );
 
-    Ok(Json(PaginatedQueries {
-        data,
-        total: result.records_filtered,
-        records_total: result.records_total,
-        limit: params.limit,
-        offset: params.offset,
-        next_cursor: result.next_cursor,
-    }))
+    Ok(list
+        .page(&page, data, result.records_filtered)
+        .with_meta(QueryPageMeta {
+            records_total: result.records_total,
+            next_cursor: result.next_cursor,
+        }))
 }
@@ -0,0 +1,91 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    routing::{delete, get, post, put},
+    Router,
+};
+use ferrous_dns_domain::DomainError;
+use tracing::debug;
+
+use crate::{
+    dto::{QueryPolicyRequest, QueryPolicyResponse},
+    errors::ApiError,
+    state::AppState,
+};
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/query-policies", get(get_all_query_policies))
+        .route("/query-policies", post(create_query_policy))
+        .route("/query-policies/{id}", get(get_query_policy_by_id))
+        .route("/query-policies/{id}", put(update_query_policy))
+        .route("/query-policies/{id}", delete(delete_query_policy))
+}
+
+async fn get_all_query_policies(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<QueryPolicyResponse>>, ApiError> {
+    let policies = state.policies.get_policies.get_all().await?;
+    debug!(
+        count = policies.len(),
+        "Query policies retrieved successfully"
+    );
+    Ok(Json(
+        policies
+            .into_iter()
+            .map(QueryPolicyResponse::from_domain)
+            .collect(),
+    ))
+}
+
+async fn get_query_policy_by_id(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<QueryPolicyResponse>, ApiError> {
+    let policy = state
+        .policies
+        .get_policies
+        .get_by_id(id)
+        .await?
+        .ok_or(ApiError(DomainError::QueryPolicyNotFound(id)))?;
+    Ok(Json(QueryPolicyResponse::from_domain(policy)))
+}
+
+async fn create_query_policy(
+    State(state): State<AppState>,
+    Json(req): Json<QueryPolicyRequest>,
+) -> Result<(StatusCode, Json<QueryPolicyResponse>), ApiError> {
+    let policy = state
+        .policies
+        .create_policy
+        .execute(req.into_domain()?)
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(QueryPolicyResponse::from_domain(policy)),
+    ))
+}
+
+async fn update_query_policy(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(req): Json<QueryPolicyRequest>,
+) -> Result<Json<QueryPolicyResponse>, ApiError> {
+    let policy = state
+        .policies
+        .update_policy
+        .execute(id, req.into_domain()?)
+        .await?;
+
+    Ok(Json(QueryPolicyResponse::from_domain(policy)))
+}
+
+async fn delete_query_policy(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, ApiError> {
+    state.policies.delete_policy.execute(id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
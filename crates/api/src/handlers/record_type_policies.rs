@@ -0,0 +1,77 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    routing::{delete, get, put},
+    Router,
+};
+use ferrous_dns_domain::DomainError;
+
+use crate::{
+    dto::{RecordTypePolicyResponse, SetRecordTypePolicyRequest},
+    errors::ApiError,
+    state::AppState,
+};
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/record-type-policies", get(get_all_policies))
+        .route("/record-type-policies/{group_id}", get(get_policy_by_group))
+        .route("/record-type-policies/{group_id}", put(set_policy))
+        .route("/record-type-policies/{group_id}", delete(delete_policy))
+}
+
+async fn get_all_policies(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<RecordTypePolicyResponse>>, ApiError> {
+    let policies = state.policies.get_record_type_policies.get_all().await?;
+    Ok(Json(
+        policies
+            .into_iter()
+            .map(RecordTypePolicyResponse::from_entity)
+            .collect(),
+    ))
+}
+
+async fn get_policy_by_group(
+    State(state): State<AppState>,
+    Path(group_id): Path<i64>,
+) -> Result<Json<RecordTypePolicyResponse>, ApiError> {
+    let policy = state
+        .policies
+        .get_record_type_policies
+        .get_by_group(group_id)
+        .await?
+        .ok_or_else(|| {
+            ApiError(DomainError::NotFound(format!(
+                "Group {} has no record type policy",
+                group_id
+            )))
+        })?;
+    Ok(Json(RecordTypePolicyResponse::from_entity(policy)))
+}
+
+async fn set_policy(
+    State(state): State<AppState>,
+    Path(group_id): Path<i64>,
+    Json(req): Json<SetRecordTypePolicyRequest>,
+) -> Result<Json<RecordTypePolicyResponse>, ApiError> {
+    let policy = state
+        .policies
+        .set_record_type_policy
+        .execute(req.into_entity(group_id)?)
+        .await?;
+    Ok(Json(RecordTypePolicyResponse::from_entity(policy)))
+}
+
+async fn delete_policy(
+    State(state): State<AppState>,
+    Path(group_id): Path<i64>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .policies
+        .delete_record_type_policy
+        .execute(group_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,64 @@
+use axum::{extract::State, Json};
+use ferrous_dns_application::use_cases::{ListenerSettings, SetupRequest, SUGGESTED_BLOCKLISTS};
+use ferrous_dns_domain::UpstreamPreset;
+use tracing::debug;
+
+use crate::dto::setup::{
+    NetworkInterfaceResponse, SetupListenersDto, SetupOptionsResponse, SetupRequestDto,
+    SetupSummaryResponse, UpstreamPresetResponse,
+};
+use crate::errors::ApiError;
+use crate::state::AppState;
+
+/// Public: choices offered by the first-run wizard. Returns 409 once the
+/// admin password is set.
+pub async fn get_setup_public(
+    State(state): State<AppState>,
+) -> Result<Json<SetupOptionsResponse>, ApiError> {
+    let options = state.auth.setup_wizard.options().await?;
+    Ok(Json(SetupOptionsResponse {
+        upstream_preset: options.upstream_preset,
+        upstream_presets: UpstreamPreset::ALL
+            .into_iter()
+            .map(UpstreamPresetResponse::from_preset)
+            .collect(),
+        blocklists: SUGGESTED_BLOCKLISTS.iter().map(Into::into).collect(),
+        listeners: SetupListenersDto {
+            bind_address: options.listeners.bind_address,
+            dns_port: options.listeners.dns_port,
+            web_port: options.listeners.web_port,
+        },
+        interfaces: options
+            .interfaces
+            .into_iter()
+            .map(|i| NetworkInterfaceResponse {
+                name: i.name,
+                address: i.address.to_string(),
+            })
+            .collect(),
+    }))
+}
+
+/// Public: applies the wizard's choices and sets the admin password, which
+/// closes setup mode.
+pub async fn complete_setup_public(
+    State(state): State<AppState>,
+    Json(req): Json<SetupRequestDto>,
+) -> Result<Json<SetupSummaryResponse>, ApiError> {
+    let summary = state
+        .auth
+        .setup_wizard
+        .complete(SetupRequest {
+            password: req.password,
+            upstream_preset: req.upstream_preset,
+            blocklists: req.blocklists,
+            listeners: req.listeners.map(|l| ListenerSettings {
+                bind_address: l.bind_address,
+                dns_port: l.dns_port,
+                web_port: l.web_port,
+            }),
+        })
+        .await?;
+    debug!("First-run setup completed");
+    Ok(Json(summary.into()))
+}
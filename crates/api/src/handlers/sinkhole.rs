@@ -0,0 +1,53 @@
+use crate::state::AppState;
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+
+const DEFAULT_LIMIT: usize = 100;
+const MAX_LIMIT: usize = 1000;
+
+#[derive(Debug, Deserialize)]
+pub struct SinkholeTelemetryQuery {
+    pub limit: Option<usize>,
+}
+
+/// Connection attempts to the sinkhole for one blocked host.
+#[derive(Debug, Serialize)]
+pub struct SinkholeHostResponse {
+    pub host: String,
+    pub http_attempts: u64,
+    pub https_attempts: u64,
+    pub correlated_attempts: u64,
+    pub clients: usize,
+    pub last_client: IpAddr,
+    pub last_attempt_at: String,
+    pub last_blocked_query_at: Option<String>,
+}
+
+pub async fn get_sinkhole_telemetry(
+    State(state): State<AppState>,
+    Query(params): Query<SinkholeTelemetryQuery>,
+) -> Json<Vec<SinkholeHostResponse>> {
+    let limit = params.limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let hosts = state
+        .dns
+        .sinkhole_telemetry
+        .hosts(limit)
+        .into_iter()
+        .map(|host| SinkholeHostResponse {
+            host: host.host,
+            http_attempts: host.http_attempts,
+            https_attempts: host.https_attempts,
+            correlated_attempts: host.correlated_attempts,
+            clients: host.clients,
+            last_client: host.last_client,
+            last_attempt_at: host.last_attempt_at.to_rfc3339(),
+            last_blocked_query_at: host.last_blocked_query_at.map(|at| at.to_rfc3339()),
+        })
+        .collect();
+
+    Json(hosts)
+}
@@ -0,0 +1,62 @@
+use crate::state::AppState;
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_LIMIT: usize = 100;
+const MAX_LIMIT: usize = 1000;
+
+#[derive(Debug, Deserialize)]
+pub struct SlowQueriesQuery {
+    pub limit: Option<usize>,
+}
+
+/// A query that exceeded the slow-query threshold, with its phase breakdown.
+#[derive(Debug, Serialize)]
+pub struct SlowQueryResponse {
+    pub timestamp: String,
+    pub domain: String,
+    pub record_type: &'static str,
+    pub client: String,
+    pub response_status: Option<&'static str>,
+    pub cache_hit: bool,
+    pub upstream_server: Option<String>,
+    pub upstream_pool: Option<String>,
+    pub block_check_us: u64,
+    pub cache_us: u64,
+    pub upstream_us: u64,
+    pub dnssec_us: u64,
+    pub total_us: u64,
+}
+
+pub async fn get_slow_queries(
+    State(state): State<AppState>,
+    Query(params): Query<SlowQueriesQuery>,
+) -> Json<Vec<SlowQueryResponse>> {
+    let limit = params.limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let queries = state
+        .dns
+        .slow_query_log
+        .recent(limit)
+        .into_iter()
+        .map(|entry| SlowQueryResponse {
+            timestamp: entry.timestamp.to_rfc3339(),
+            domain: entry.domain.to_string(),
+            record_type: entry.record_type.as_str(),
+            client: entry.client_ip.to_string(),
+            response_status: entry.response_status,
+            cache_hit: entry.cache_hit,
+            upstream_server: entry.upstream_server.map(|s| s.to_string()),
+            upstream_pool: entry.upstream_pool.map(|s| s.to_string()),
+            block_check_us: entry.phases.block_check_us,
+            cache_us: entry.phases.cache_us,
+            upstream_us: entry.phases.upstream_us,
+            dnssec_us: entry.phases.dnssec_us,
+            total_us: entry.total_us,
+        })
+        .collect();
+
+    Json(queries)
+}
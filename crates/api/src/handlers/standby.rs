@@ -0,0 +1,106 @@
+use crate::{errors::ApiError, state::AppState};
+use axum::{
+    extract::{Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use ferrous_dns_application::ports::CacheExportEntry;
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_CACHE_ENTRIES: usize = 10_000;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/standby/status", get(get_standby_status))
+        .route("/standby/block-lists", get(get_block_lists))
+        .route("/standby/cache", get(get_cache_entries))
+}
+
+/// Role of this instance and, on a standby, how its syncs are going.
+#[derive(Debug, Serialize)]
+pub struct StandbyStatusResponse {
+    /// `primary` or `standby`.
+    pub role: &'static str,
+    pub ready: bool,
+    pub primary_url: Option<String>,
+    pub last_attempt_at: Option<String>,
+    pub last_success_at: Option<String>,
+    pub consecutive_failures: u32,
+    pub last_error: Option<String>,
+    pub config_updates: u64,
+    pub block_list_updates: u64,
+    pub cache_entries: usize,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CacheExportQuery {
+    pub limit: Option<usize>,
+}
+
+pub async fn get_standby_status(State(state): State<AppState>) -> Json<StandbyStatusResponse> {
+    let Some(sync) = &state.backup.standby else {
+        return Json(StandbyStatusResponse {
+            role: "primary",
+            ready: true,
+            primary_url: None,
+            last_attempt_at: None,
+            last_success_at: None,
+            consecutive_failures: 0,
+            last_error: None,
+            config_updates: 0,
+            block_list_updates: 0,
+            cache_entries: 0,
+        });
+    };
+    let status = sync.status();
+    let primary_url = state.config.read().await.standby.primary_url.clone();
+    Json(StandbyStatusResponse {
+        role: "standby",
+        ready: status.ready,
+        primary_url: Some(primary_url),
+        last_attempt_at: status.last_attempt_at.map(|t| t.to_rfc3339()),
+        last_success_at: status.last_success_at.map(|t| t.to_rfc3339()),
+        consecutive_failures: status.consecutive_failures,
+        last_error: status.last_error,
+        config_updates: status.config_updates,
+        block_list_updates: status.block_list_updates,
+        cache_entries: status.cache_entries,
+    })
+}
+
+/// The list snapshot behind the block index, for a standby to compile.
+pub async fn get_block_lists(State(state): State<AppState>) -> Result<Response, ApiError> {
+    match state.backup.primary_state.block_lists().await? {
+        Some(lists) => Ok((
+            StatusCode::OK,
+            [(header::CONTENT_TYPE, "text/plain; charset=utf-8")],
+            lists,
+        )
+            .into_response()),
+        None => Ok((StatusCode::NOT_FOUND, "no block list snapshot").into_response()),
+    }
+}
+
+pub async fn get_cache_entries(
+    State(state): State<AppState>,
+    Query(query): Query<CacheExportQuery>,
+) -> Json<Vec<CacheExportEntry>> {
+    Json(
+        state
+            .backup
+            .primary_state
+            .cache_entries(query.limit.unwrap_or(DEFAULT_CACHE_ENTRIES)),
+    )
+}
+
+/// Unauthenticated health check for keepalived's `vrrp_script`: 200 on a
+/// primary and on a standby in sync, 503 on a standby that is not.
+pub async fn get_standby_ready_public(State(state): State<AppState>) -> Response {
+    match &state.backup.standby {
+        None => (StatusCode::OK, "primary").into_response(),
+        Some(sync) if sync.status().ready => (StatusCode::OK, "ready").into_response(),
+        Some(_) => (StatusCode::SERVICE_UNAVAILABLE, "not ready").into_response(),
+    }
+}
@@ -1,16 +1,22 @@
 use crate::{
-    dto::{StatsQuery, StatsResponse, TopType, TypeDistribution},
+    dto::{PublicStatsResponse, StatsQuery, StatsResponse, TopType, TypeDistribution},
     errors::ApiError,
     state::AppState,
     utils::{parse_period, validate_period},
 };
 use axum::{
     extract::{Query, State},
+    http::header,
+    response::{IntoResponse, Response},
     Json,
 };
+use ferrous_dns_domain::DomainError;
 use tracing::instrument;
 
 const DEFAULT_PERIOD_HOURS: f32 = 24.0;
+/// `period` value answered from the in-memory counters since startup
+/// instead of the query log.
+const LIVE_PERIOD: &str = "live";
 const TOP_TYPES_LIMIT: usize = 10;
 
 #[instrument(skip(state), name = "api_get_stats")]
@@ -18,11 +24,14 @@ pub async fn get_stats(
     State(state): State<AppState>,
     Query(params): Query<StatsQuery>,
 ) -> Result<Json<StatsResponse>, ApiError> {
-    let period_hours = parse_period(&params.period)
-        .map(validate_period)
-        .unwrap_or(DEFAULT_PERIOD_HOURS);
-
-    let stats = state.query.get_stats.execute(period_hours).await?;
+    let stats = if params.period == LIVE_PERIOD {
+        state.query.live_metrics.snapshot()
+    } else {
+        let period_hours = parse_period(&params.period)
+            .map(validate_period)
+            .unwrap_or(DEFAULT_PERIOD_HOURS);
+        state.query.get_stats.execute(period_hours).await?
+    };
 
     let queries_by_type = stats
         .queries_by_type
@@ -54,17 +63,58 @@ pub async fn get_stats(
         queries_total: stats.queries_total,
         queries_blocked: stats.queries_blocked,
         queries_rate_limited: stats.queries_rate_limited,
+        queries_any_minimized: stats.queries_any_minimized,
         queries_malware_detected: stats.queries_malware_detected,
+        blocked_percentage: stats.blocked_percentage(),
         clients: stats.unique_clients,
         uptime: stats.uptime_seconds,
         cache_hit_rate: stats.cache_hit_rate,
         avg_query_time_ms: stats.avg_query_time_ms,
         avg_cache_time_ms: stats.avg_cache_time_ms,
         avg_upstream_time_ms: stats.avg_upstream_time_ms,
+        latency_p50_ms: stats.latency_p50_ms,
+        latency_p95_ms: stats.latency_p95_ms,
+        latency_p99_ms: stats.latency_p99_ms,
         queries_by_type,
         most_queried_type,
         record_type_distribution,
         top_10_types,
         source_stats: stats.source_stats,
+        queries_by_group: stats.queries_by_group.iter().map(Into::into).collect(),
+        top_clients: stats.top_clients.iter().map(Into::into).collect(),
     }))
 }
+
+/// How long browsers and proxies may reuse the public summary.
+const PUBLIC_STATS_MAX_AGE_SECS: u32 = 60;
+
+/// Unauthenticated stats summary for status widgets, enabled by
+/// `[server.public_stats]`. Readable from any origin so a page elsewhere
+/// can embed it; 404 while disabled.
+#[instrument(skip(state), name = "api_get_public_stats")]
+pub async fn get_public_stats(State(state): State<AppState>) -> Result<Response, ApiError> {
+    let public_stats = state.config.read().await.server.public_stats.clone();
+    if !public_stats.enabled {
+        return Err(ApiError(DomainError::NotFound(
+            "Public stats are not enabled".to_string(),
+        )));
+    }
+
+    let stats = state
+        .query
+        .get_stats
+        .execute(public_stats.period_hours as f32)
+        .await?;
+
+    Ok((
+        [
+            (header::ACCESS_CONTROL_ALLOW_ORIGIN, "*".to_string()),
+            (
+                header::CACHE_CONTROL,
+                format!("public, max-age={PUBLIC_STATS_MAX_AGE_SECS}"),
+            ),
+        ],
+        Json(PublicStatsResponse::new(public_stats.period_hours, &stats)),
+    )
+        .into_response())
+}
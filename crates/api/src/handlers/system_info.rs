@@ -1,6 +1,12 @@
-use crate::dto::SystemInfoResponse;
-use axum::Json;
-use tracing::instrument;
+use crate::{
+    dto::{
+        CacheMemoryResponse, DatabaseSizeResponse, RuntimeMetricsResponse, SystemInfoResponse,
+        SystemMetricsResponse,
+    },
+    state::AppState,
+};
+use axum::{extract::State, Json};
+use tracing::{instrument, warn};
 
 const PROC_VERSION: &str = "/proc/version";
 const PROC_LOADAVG: &str = "/proc/loadavg";
@@ -35,6 +41,41 @@ pub async fn get_system_info() -> Json<SystemInfoResponse> {
     })
 }
 
+#[instrument(skip(state), name = "api_get_system_metrics")]
+pub async fn get_system_metrics(State(state): State<AppState>) -> Json<SystemMetricsResponse> {
+    let process = state.system_metrics.process_metrics();
+    let cache = state.dns.cache.cache_metrics_snapshot();
+    let database = match state.query.database_maintenance.status().await {
+        Ok(status) => Some(DatabaseSizeResponse {
+            file_bytes: status.usage.file_bytes,
+            wal_bytes: status.usage.wal_bytes,
+        }),
+        Err(e) => {
+            warn!(error = %e, "Failed to read database size");
+            None
+        }
+    };
+
+    Json(SystemMetricsResponse {
+        uptime_secs: process.uptime_secs,
+        rss_bytes: process.rss_bytes,
+        open_fds: process.open_fds,
+        runtime: RuntimeMetricsResponse {
+            workers: process.runtime_workers,
+            alive_tasks: process.alive_tasks,
+            global_queue_depth: process.global_queue_depth,
+        },
+        channels: process.channels.into_iter().map(Into::into).collect(),
+        cache: CacheMemoryResponse {
+            entries: cache.total_entries,
+            memory_bytes: cache.memory_bytes,
+            max_memory_bytes: cache.max_memory_bytes,
+        },
+        database,
+        udp_pool: process.udp_pool.map(Into::into),
+    })
+}
+
 /// Extracts the kernel version string from `/proc/version` content.
 /// Returns just the version token (e.g. "6.12.75-1-lts") to keep the response concise.
 fn parse_proc_version(raw: &str) -> String {
@@ -0,0 +1,278 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::Json,
+    routing::{delete, get, put},
+    Router,
+};
+use ferrous_dns_domain::LocalRecord;
+use tracing::debug;
+
+use crate::{
+    dto::{
+        BlocklistSourceResponse, CreateBlocklistSourceRequest, CreateGroupRequest,
+        CreateLocalRecordRequest, CreateTenantRequest, GroupResponse, LocalRecordDto, StatsQuery,
+        TenantResponse, TenantStatsResponse, UpdateBlocklistSourceRequest, UpdateTenantRequest,
+    },
+    errors::ApiError,
+    state::AppState,
+    utils::{parse_period, validate_period},
+};
+
+const DEFAULT_PERIOD_HOURS: f32 = 24.0;
+
+/// Everything below `/tenants/{id}/` is also reachable with an API token
+/// scoped to that tenant.
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/tenants", get(get_all_tenants).post(create_tenant))
+        .route(
+            "/tenants/{id}",
+            get(get_tenant).put(update_tenant).delete(delete_tenant),
+        )
+        .route(
+            "/tenants/{id}/groups",
+            get(get_tenant_groups).post(create_tenant_group),
+        )
+        .route(
+            "/tenants/{id}/blocklist-sources",
+            get(get_tenant_blocklist_sources).post(create_tenant_blocklist_source),
+        )
+        .route(
+            "/tenants/{id}/blocklist-sources/{source_id}",
+            put(update_tenant_blocklist_source).delete(delete_tenant_blocklist_source),
+        )
+        .route(
+            "/tenants/{id}/local-records",
+            get(get_tenant_records).post(create_tenant_record),
+        )
+        .route(
+            "/tenants/{id}/local-records/{record_id}",
+            delete(delete_tenant_record),
+        )
+        .route("/tenants/{id}/stats", get(get_tenant_stats))
+}
+
+async fn get_all_tenants(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<TenantResponse>>, ApiError> {
+    let tenants = state.tenants.get_tenants.get_all().await?;
+    debug!(count = tenants.len(), "Tenants retrieved successfully");
+    Ok(Json(
+        tenants
+            .into_iter()
+            .map(TenantResponse::from_tenant)
+            .collect(),
+    ))
+}
+
+async fn get_tenant(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<TenantResponse>, ApiError> {
+    let tenant = state.tenants.get_tenants.get_by_id(id).await?;
+    Ok(Json(TenantResponse::from_tenant(tenant)))
+}
+
+async fn create_tenant(
+    State(state): State<AppState>,
+    Json(req): Json<CreateTenantRequest>,
+) -> Result<(StatusCode, Json<TenantResponse>), ApiError> {
+    let tenant = state
+        .tenants
+        .create_tenant
+        .execute(req.into_domain())
+        .await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(TenantResponse::from_tenant(tenant)),
+    ))
+}
+
+async fn update_tenant(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(req): Json<UpdateTenantRequest>,
+) -> Result<Json<TenantResponse>, ApiError> {
+    let tenant = state
+        .tenants
+        .update_tenant
+        .execute(id, req.into_domain())
+        .await?;
+    Ok(Json(TenantResponse::from_tenant(tenant)))
+}
+
+async fn delete_tenant(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, ApiError> {
+    state.tenants.delete_tenant.execute(id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn get_tenant_groups(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<Vec<GroupResponse>>, ApiError> {
+    let groups = state.tenants.get_tenants.get_groups(id).await?;
+    Ok(Json(
+        groups
+            .into_iter()
+            .map(|(group, count)| GroupResponse::from_group(group, Some(count)))
+            .collect(),
+    ))
+}
+
+async fn create_tenant_group(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(req): Json<CreateGroupRequest>,
+) -> Result<(StatusCode, Json<GroupResponse>), ApiError> {
+    let group = state
+        .tenants
+        .create_tenant_group
+        .execute(id, req.name, req.comment)
+        .await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(GroupResponse::from_group(group, Some(0))),
+    ))
+}
+
+async fn get_tenant_blocklist_sources(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<Vec<BlocklistSourceResponse>>, ApiError> {
+    let sources = state.tenants.blocklist_sources.get_all(id).await?;
+    Ok(Json(
+        sources
+            .into_iter()
+            .map(BlocklistSourceResponse::from_source)
+            .collect(),
+    ))
+}
+
+async fn create_tenant_blocklist_source(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(req): Json<CreateBlocklistSourceRequest>,
+) -> Result<(StatusCode, Json<BlocklistSourceResponse>), ApiError> {
+    // The server's default group is not the tenant's, so there is no fallback.
+    let group_ids = req.requested_group_ids();
+    let source = state
+        .tenants
+        .blocklist_sources
+        .create(
+            id,
+            req.name,
+            req.url,
+            group_ids,
+            req.comment,
+            req.enabled.unwrap_or(true),
+        )
+        .await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(BlocklistSourceResponse::from_source(source)),
+    ))
+}
+
+async fn update_tenant_blocklist_source(
+    State(state): State<AppState>,
+    Path((id, source_id)): Path<(i64, i64)>,
+    Json(req): Json<UpdateBlocklistSourceRequest>,
+) -> Result<Json<BlocklistSourceResponse>, ApiError> {
+    let group_ids = req.resolved_group_ids();
+    let source = state
+        .tenants
+        .blocklist_sources
+        .update(
+            id,
+            source_id,
+            req.name,
+            req.url,
+            group_ids,
+            req.comment,
+            req.enabled,
+            req.audit_mode,
+        )
+        .await?;
+    Ok(Json(BlocklistSourceResponse::from_source(source)))
+}
+
+async fn delete_tenant_blocklist_source(
+    State(state): State<AppState>,
+    Path((id, source_id)): Path<(i64, i64)>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .tenants
+        .blocklist_sources
+        .delete(id, source_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn get_tenant_records(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<Vec<LocalRecordDto>>, ApiError> {
+    state.tenants.get_tenants.get_by_id(id).await?;
+    let records = state.dns.get_local_records.get_all_in_tenant(id).await?;
+    let local_domain = state.config.read().await.dns.local_domain.clone();
+    Ok(Json(
+        records
+            .iter()
+            .map(|record| LocalRecordDto::from_domain(record, &local_domain))
+            .collect(),
+    ))
+}
+
+async fn create_tenant_record(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(req): Json<CreateLocalRecordRequest>,
+) -> Result<(StatusCode, Json<LocalRecordDto>), ApiError> {
+    state.tenants.get_tenants.get_by_id(id).await?;
+    let record = LocalRecord {
+        tenant_id: Some(id),
+        ..req.into_domain()?
+    };
+    let record = state.dns.create_local_record.execute(record).await?;
+    let local_domain = state.config.read().await.dns.local_domain.clone();
+    Ok((
+        StatusCode::CREATED,
+        Json(LocalRecordDto::from_domain(&record, &local_domain)),
+    ))
+}
+
+async fn delete_tenant_record(
+    State(state): State<AppState>,
+    Path((id, record_id)): Path<(i64, i64)>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .dns
+        .delete_local_record
+        .execute_in_tenant(id, record_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn get_tenant_stats(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Query(params): Query<StatsQuery>,
+) -> Result<Json<TenantStatsResponse>, ApiError> {
+    let period_hours = parse_period(&params.period)
+        .map(validate_period)
+        .unwrap_or(DEFAULT_PERIOD_HOURS);
+    let stats = state
+        .tenants
+        .get_tenants
+        .get_stats(id, period_hours)
+        .await?;
+    Ok(Json(TenantStatsResponse::from_stats(
+        id,
+        period_hours,
+        stats,
+    )))
+}
@@ -1,25 +1,18 @@
 use crate::{
-    dto::{TimelineBucket, TimelineQuery, TimelineResponse},
+    dto::{TimelineBucket, TimelineQuery, TimelineResponse, TimelineSeries},
     errors::ApiError,
     state::AppState,
-    utils::{parse_period, validate_period},
+    utils::{parse_period, parse_timeline_granularity, validate_period},
 };
 use axum::{
     extract::{Query, State},
     Json,
 };
-use ferrous_dns_application::ports::TimeGranularity;
+use ferrous_dns_application::ports::TimelineBreakdown;
+use ferrous_dns_application::use_cases::GetTimelineUseCase;
+use ferrous_dns_domain::DomainError;
 use tracing::{debug, instrument};
 
-fn parse_granularity(s: &str) -> TimeGranularity {
-    match s {
-        "minute" => TimeGranularity::Minute,
-        "15min" | "quarter_hour" => TimeGranularity::QuarterHour,
-        "day" => TimeGranularity::Day,
-        _ => TimeGranularity::Hour,
-    }
-}
-
 #[instrument(skip(state), name = "api_get_timeline")]
 pub async fn get_timeline(
     State(state): State<AppState>,
@@ -28,6 +21,7 @@ pub async fn get_timeline(
     debug!(
         period = %params.period,
         granularity = %params.granularity,
+        breakdown = ?params.breakdown,
         "Fetching query timeline"
     );
 
@@ -35,21 +29,47 @@ pub async fn get_timeline(
         .map(|h| validate_period(h) as u32)
         .unwrap_or(24);
 
-    let granularity = parse_granularity(&params.granularity);
+    let breakdown = match params.breakdown.as_deref() {
+        None | Some("") => None,
+        Some("client") => Some(TimelineBreakdown::Client),
+        Some("group") => Some(TimelineBreakdown::Group),
+        Some(other) => {
+            return Err(DomainError::InvalidInput(format!(
+                "invalid breakdown: '{other}' (expected 'client' or 'group')"
+            ))
+            .into())
+        }
+    };
+
+    let granularity = GetTimelineUseCase::resolve_granularity(
+        period_hours,
+        parse_timeline_granularity(&params.granularity),
+    );
 
-    let buckets = state
-        .query
-        .get_timeline
-        .execute(period_hours, granularity)
-        .await?;
-    debug!(buckets = buckets.len(), "Timeline retrieved successfully");
+    let timeline = &state.query.get_timeline;
+    let (buckets, series) = match breakdown {
+        Some(breakdown) => {
+            let (buckets, series) = tokio::join!(
+                timeline.execute(period_hours, granularity),
+                timeline.execute_breakdown(period_hours, granularity, breakdown, params.limit)
+            );
+            (buckets?, Some(series?))
+        }
+        None => (timeline.execute(period_hours, granularity).await?, None),
+    };
+    debug!(
+        buckets = buckets.len(),
+        series = series.as_ref().map(Vec::len),
+        "Timeline retrieved successfully"
+    );
 
     let buckets_dto: Vec<TimelineBucket> = buckets.into_iter().map(TimelineBucket::from).collect();
 
     Ok(Json(TimelineResponse {
         total_buckets: buckets_dto.len(),
         period: params.period,
-        granularity: params.granularity,
+        granularity: granularity.as_str().to_string(),
         buckets: buckets_dto,
+        series: series.map(|s| s.into_iter().map(TimelineSeries::from).collect()),
     }))
 }
@@ -0,0 +1,154 @@
+use axum::{
+    extract::{ConnectInfo, Path, Query, State},
+    http::StatusCode,
+    response::Json,
+    routing::{get, post},
+    Router,
+};
+use ferrous_dns_domain::{DomainError, UnblockRequest, UnblockRequestStatus};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tracing::debug;
+
+use crate::{
+    dto::{
+        ApproveUnblockRequest, SubmitUnblockRequest, UnblockRequestResponse, UnblockRequestsQuery,
+    },
+    errors::ApiError,
+    state::AppState,
+};
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/requests", get(get_unblock_requests))
+        .route(
+            "/requests/{id}",
+            get(get_unblock_request).delete(delete_unblock_request),
+        )
+        .route("/requests/{id}/approve", post(approve_unblock_request))
+        .route("/requests/{id}/deny", post(deny_unblock_request))
+}
+
+async fn get_unblock_requests(
+    State(state): State<AppState>,
+    Query(params): Query<UnblockRequestsQuery>,
+) -> Result<Json<Vec<UnblockRequestResponse>>, ApiError> {
+    let status = params
+        .status
+        .as_deref()
+        .map(|s| {
+            s.parse::<UnblockRequestStatus>().map_err(|_| {
+                DomainError::InvalidInput(format!("Unknown unblock request status: {}", s))
+            })
+        })
+        .transpose()?;
+
+    let requests = state.blocking.get_unblock_requests.get_all(status).await?;
+    debug!(
+        count = requests.len(),
+        "Unblock requests retrieved successfully"
+    );
+
+    Ok(Json(
+        requests
+            .into_iter()
+            .map(UnblockRequestResponse::from_domain)
+            .collect(),
+    ))
+}
+
+async fn get_unblock_request(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<UnblockRequestResponse>, ApiError> {
+    let request = state
+        .blocking
+        .get_unblock_requests
+        .get_by_id(id)
+        .await?
+        .ok_or(DomainError::UnblockRequestNotFound(id))?;
+    Ok(Json(UnblockRequestResponse::from_domain(request)))
+}
+
+async fn approve_unblock_request(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    body: Option<Json<ApproveUnblockRequest>>,
+) -> Result<Json<UnblockRequestResponse>, ApiError> {
+    let Json(body) = body.unwrap_or_default();
+    let request = state
+        .blocking
+        .approve_unblock_request
+        .execute(id, body.group_id)
+        .await?;
+    Ok(Json(UnblockRequestResponse::from_domain(request)))
+}
+
+async fn deny_unblock_request(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<UnblockRequestResponse>, ApiError> {
+    let request = state.blocking.deny_unblock_request.execute(id).await?;
+    Ok(Json(UnblockRequestResponse::from_domain(request)))
+}
+
+async fn delete_unblock_request(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, ApiError> {
+    state.blocking.delete_unblock_request.execute(id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Files an unblock request for the calling client. Unauthenticated, and
+/// only answered when `[blocking.unblock_requests] public_endpoint` is on.
+/// Served outside `/api`, next to the public stats.
+pub async fn submit_unblock_request_public(
+    State(state): State<AppState>,
+    connect_info: Option<ConnectInfo<SocketAddr>>,
+    Json(body): Json<SubmitUnblockRequest>,
+) -> Result<(StatusCode, Json<UnblockRequestResponse>), ApiError> {
+    if !state
+        .config
+        .read()
+        .await
+        .blocking
+        .unblock_requests
+        .public_endpoint
+    {
+        return Err(ApiError(DomainError::NotFound(
+            "Public unblock requests are not enabled".to_string(),
+        )));
+    }
+    let Some(ConnectInfo(peer_addr)) = connect_info else {
+        return Err(ApiError(DomainError::InvalidInput(
+            "Client address is unknown".to_string(),
+        )));
+    };
+
+    let domain = body
+        .domain
+        .trim()
+        .trim_end_matches('.')
+        .to_ascii_lowercase();
+    let mut request = UnblockRequest::new(
+        Arc::from(domain.as_str()),
+        Arc::from(peer_addr.ip().to_string()),
+    );
+    request.message = body
+        .message
+        .as_deref()
+        .map(str::trim)
+        .filter(|message| !message.is_empty())
+        .map(Arc::from);
+
+    let stored = state
+        .blocking
+        .submit_unblock_request
+        .execute(request)
+        .await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(UnblockRequestResponse::from_domain(stored)),
+    ))
+}
@@ -98,3 +98,27 @@ pub async fn get_upstream_health_detail(
 
     Json(response)
 }
+
+/// Counters for upstream responses rejected by forwarding-path validation.
+#[derive(Debug, Serialize)]
+pub struct ResponseValidationResponse {
+    pub id_mismatches: u64,
+    pub source_mismatches: u64,
+    pub question_mismatches: u64,
+    pub cookie_mismatches: u64,
+    pub out_of_bailiwick_records: u64,
+}
+
+pub async fn get_response_validation(
+    State(state): State<AppState>,
+) -> Json<ResponseValidationResponse> {
+    let stats = state.dns.upstream_health.response_validation_stats();
+
+    Json(ResponseValidationResponse {
+        id_mismatches: stats.id_mismatches,
+        source_mismatches: stats.source_mismatches,
+        question_mismatches: stats.question_mismatches,
+        cookie_mismatches: stats.cookie_mismatches,
+        out_of_bailiwick_records: stats.out_of_bailiwick_records,
+    })
+}
@@ -1,17 +1,38 @@
-use crate::{dto::WhitelistResponse, errors::ApiError, state::AppState};
-use axum::{extract::State, Json};
+use super::blocklist::parse_bulk_body;
+use crate::{
+    dto::{BulkAddResponse, ListParams, Page, WhitelistResponse},
+    errors::ApiError,
+    state::AppState,
+};
+use axum::{
+    body::Bytes,
+    extract::{Query, State},
+    http::HeaderMap,
+    Json,
+};
+use ferrous_dns_domain::whitelist::WhitelistedDomain;
 use tracing::{debug, instrument};
 
 #[instrument(skip(state), name = "api_get_whitelist")]
 pub async fn get_whitelist(
     State(state): State<AppState>,
-) -> Result<Json<Vec<WhitelistResponse>>, ApiError> {
-    debug!("Fetching whitelist");
+    Query(params): Query<ListParams>,
+) -> Result<Page<WhitelistResponse>, ApiError> {
+    let page = params.page_request(WhitelistedDomain::SORT_FIELDS)?;
+    debug!(
+        limit = page.limit,
+        offset = page.offset,
+        sort = ?page.sort,
+        "Fetching whitelist"
+    );
 
-    let domains = state.blocking.get_whitelist.execute().await?;
-    debug!(count = domains.len(), "Whitelist retrieved successfully");
+    let (domains, total) = state.blocking.get_whitelist.execute_paged(&page).await?;
+    debug!(
+        count = domains.len(),
+        total, "Whitelist retrieved successfully"
+    );
 
-    let response = domains
+    let data = domains
         .into_iter()
         .map(|d| WhitelistResponse {
             domain: d.domain,
@@ -19,5 +40,18 @@ pub async fn get_whitelist(
         })
         .collect();
 
-    Ok(Json(response))
+    Ok(params.page(&page, data, total))
+}
+
+#[instrument(skip(state, headers, body), name = "api_bulk_add_whitelist")]
+pub async fn bulk_add_whitelist(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Json<BulkAddResponse>, ApiError> {
+    let entries = parse_bulk_body(&headers, &body)?;
+    debug!(entries = entries.len(), "Bulk adding to whitelist");
+
+    let summary = state.blocking.bulk_add_whitelist.execute(&entries).await?;
+    Ok(Json(BulkAddResponse::from_summary(summary)))
 }
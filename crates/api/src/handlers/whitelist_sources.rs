@@ -1,15 +1,16 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::Json,
     routing::{delete, get, post, put},
     Router,
 };
-use ferrous_dns_domain::DomainError;
+use ferrous_dns_domain::{DomainError, WhitelistSource};
 use tracing::debug;
 
 use crate::{
     dto::{CreateWhitelistSourceRequest, UpdateWhitelistSourceRequest, WhitelistSourceResponse},
+    dto::{ListParams, Page},
     errors::ApiError,
     state::AppState,
 };
@@ -25,18 +26,19 @@ pub fn routes() -> Router<AppState> {
 
 async fn get_all_whitelist_sources(
     State(state): State<AppState>,
-) -> Result<Json<Vec<WhitelistSourceResponse>>, ApiError> {
-    let sources = state.blocking.get_whitelist_sources.get_all().await?;
+    Query(params): Query<ListParams>,
+) -> Result<Page<WhitelistSourceResponse>, ApiError> {
+    let page = params.page_request(WhitelistSource::SORT_FIELDS)?;
+    let (sources, total) = state.blocking.get_whitelist_sources.get_page(&page).await?;
     debug!(
         count = sources.len(),
-        "Whitelist sources retrieved successfully"
+        total, "Whitelist sources retrieved successfully"
     );
-    Ok(Json(
-        sources
-            .into_iter()
-            .map(WhitelistSourceResponse::from_source)
-            .collect(),
-    ))
+    let data = sources
+        .into_iter()
+        .map(WhitelistSourceResponse::from_source)
+        .collect();
+    Ok(params.page(&page, data, total))
 }
 
 async fn get_whitelist_source_by_id(
@@ -0,0 +1,221 @@
+use super::Locale;
+use ferrous_dns_domain::DomainError;
+
+/// Fixed error and status strings returned by the API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Message {
+    InternalError,
+    Blocked,
+    DnsTunnelingDetected,
+    NoConfigFile,
+    NoWritableConfigFile,
+    ConfigFileReadOnly,
+    InvalidConfiguration,
+    ConfigSaved,
+    ConfigSavedRestartRequired,
+    SaveConfigFailed,
+    SettingsSaved,
+    SaveSettingsFailed,
+    ConfigReloaded,
+    ReloadConfigFailed,
+}
+
+impl Message {
+    pub fn text(self, locale: Locale) -> &'static str {
+        let (en, pt_br) = match self {
+            Self::InternalError => ("internal error", "erro interno"),
+            Self::Blocked => ("blocked", "bloqueado"),
+            Self::DnsTunnelingDetected => ("DNS tunneling detected", "Tunelamento DNS detectado"),
+            Self::NoConfigFile => (
+                "No config file found",
+                "Nenhum arquivo de configuração encontrado",
+            ),
+            Self::NoWritableConfigFile => (
+                "No config file found. Cannot update configuration.",
+                "Nenhum arquivo de configuração encontrado. Não é possível atualizar a configuração.",
+            ),
+            Self::ConfigFileReadOnly => (
+                "Permission denied: Config file is read-only. Please check file permissions.",
+                "Permissão negada: o arquivo de configuração é somente leitura. Verifique as permissões do arquivo.",
+            ),
+            Self::InvalidConfiguration => ("Invalid configuration", "Configuração inválida"),
+            Self::ConfigSaved => (
+                "Configuration saved successfully. Use 'Save & Apply Now' button to reload and apply changes immediately, or restart server later.",
+                "Configuração salva com sucesso. Use o botão 'Salvar e aplicar agora' para recarregar e aplicar as alterações imediatamente, ou reinicie o servidor mais tarde.",
+            ),
+            Self::ConfigSavedRestartRequired => (
+                "Configuration saved. Restart the server for compatibility changes to take effect.",
+                "Configuração salva. Reinicie o servidor para que as alterações de compatibilidade tenham efeito.",
+            ),
+            Self::SaveConfigFailed => (
+                "Failed to save configuration",
+                "Falha ao salvar a configuração",
+            ),
+            Self::SettingsSaved => (
+                "DNS settings saved successfully.",
+                "Configurações de DNS salvas com sucesso.",
+            ),
+            Self::SaveSettingsFailed => (
+                "Failed to save settings",
+                "Falha ao salvar as configurações",
+            ),
+            Self::ConfigReloaded => (
+                "Configuration reloaded successfully",
+                "Configuração recarregada com sucesso",
+            ),
+            Self::ReloadConfigFailed => (
+                "Failed to reload configuration",
+                "Falha ao recarregar a configuração",
+            ),
+        };
+        match locale {
+            Locale::En => en,
+            Locale::PtBr => pt_br,
+        }
+    }
+}
+
+/// Client-facing text of `error` in `locale`. Details carried by the error
+/// (names, IDs, validation reasons) are kept as-is; errors without a
+/// translation fall back to the English text.
+pub fn error_message(locale: Locale, error: &DomainError) -> String {
+    match locale {
+        Locale::En => error.to_string(),
+        Locale::PtBr => pt_br(error).unwrap_or_else(|| error.to_string()),
+    }
+}
+
+fn pt_br(error: &DomainError) -> Option<String> {
+    let text = match error {
+        DomainError::NotFound(s) => format!("Recurso não encontrado: {s}"),
+        DomainError::GroupNotFound(id) => format!("Grupo não encontrado: {id}"),
+        DomainError::ClientNotFound(s) => format!("Cliente não encontrado: {s}"),
+        DomainError::SubnetNotFound(s) => format!("Sub-rede não encontrada: {s}"),
+        DomainError::BlocklistSourceNotFound(id) => {
+            format!("Fonte de lista de bloqueio não encontrada: {id}")
+        }
+        DomainError::WhitelistSourceNotFound(id) => {
+            format!("Fonte de lista de permissões não encontrada: {id}")
+        }
+        DomainError::IpBlocklistSourceNotFound(id) => {
+            format!("Fonte de lista de bloqueio de IPs não encontrada: {id}")
+        }
+        DomainError::ManagedDomainNotFound(id) => {
+            format!("Domínio gerenciado não encontrado: {id}")
+        }
+        DomainError::RegexFilterNotFound(id) => format!("Filtro regex não encontrado: {id}"),
+        DomainError::QueryPolicyNotFound(id) => {
+            format!("Política de consulta não encontrada: {id}")
+        }
+        DomainError::DnsRewriteNotFound(id) => format!("Reescrita de DNS não encontrada: {id}"),
+        DomainError::CacheTtlOverrideNotFound(id) => {
+            format!("Substituição de TTL de cache não encontrada: {id}")
+        }
+        DomainError::UnblockRequestNotFound(id) => {
+            format!("Pedido de desbloqueio não encontrado: {id}")
+        }
+        DomainError::LocalRecordNotFound(id) => format!("Registro local não encontrado: {id}"),
+        DomainError::TenantNotFound(id) => format!("Tenant não encontrado: {id}"),
+        DomainError::AlertNotFound(id) => format!("Alerta não encontrado: {id}"),
+        DomainError::CustomServiceNotFound(s) => {
+            format!("Serviço personalizado não encontrado: {s}")
+        }
+        DomainError::ServiceNotFoundInCatalog(s) => {
+            format!("Serviço não encontrado no catálogo: {s}")
+        }
+        DomainError::ScheduleProfileNotFound(id) => {
+            format!("Perfil de agendamento não encontrado: {id}")
+        }
+        DomainError::TimeSlotNotFound(id) => format!("Intervalo de horário não encontrado: {id}"),
+        DomainError::GroupHasNoSchedule(id) => {
+            format!("O grupo não tem agendamento atribuído: {id}")
+        }
+        DomainError::ApiTokenNotFound(id) => format!("Token de API não encontrado: {id}"),
+        DomainError::UserNotFound(s) => format!("Usuário não encontrado: {s}"),
+        DomainError::SessionNotFound => "Sessão não encontrada ou expirada".to_string(),
+
+        DomainError::InvalidCredentials => "Credenciais inválidas".to_string(),
+        DomainError::AuthRequired => "Autenticação necessária".to_string(),
+        DomainError::PasswordNotConfigured => {
+            "Senha não configurada, execute a configuração inicial".to_string()
+        }
+        DomainError::PasswordAlreadyConfigured => "Senha já configurada".to_string(),
+        DomainError::InsufficientPermissions => "Permissões insuficientes".to_string(),
+        DomainError::ProtectedUser => {
+            "Usuário protegido não pode ser alterado pela API".to_string()
+        }
+        DomainError::RateLimited => {
+            "Muitas tentativas de login, tente novamente mais tarde".to_string()
+        }
+        DomainError::DnsRateLimited => "Consulta DNS limitada por taxa".to_string(),
+        DomainError::DnsRateLimitedSlip => {
+            "Consulta DNS limitada por taxa (truncada, tente novamente via TCP)".to_string()
+        }
+
+        DomainError::DuplicateApiTokenName(s) => {
+            format!("Já existe um token de API com este nome: {s}")
+        }
+        DomainError::DuplicateUsername(s) => format!("Nome de usuário já existe: {s}"),
+        DomainError::DuplicateScheduleProfileName(s) => {
+            format!("Já existe um perfil de agendamento com este nome: {s}")
+        }
+        DomainError::BlockedServiceAlreadyExists(s) => format!("Serviço já bloqueado: {s}"),
+        DomainError::CustomServiceAlreadyExists(s) => {
+            format!("Serviço personalizado já existe: {s}")
+        }
+        DomainError::SubnetConflict(s) => format!("Sub-rede conflita com uma existente: {s}"),
+        DomainError::LocalRecordConflict(s) => format!("Conflito de registro local: {s}"),
+        DomainError::UnblockRequestAlreadyReviewed(id) => {
+            format!("O pedido de desbloqueio {id} já foi analisado")
+        }
+        DomainError::GroupHasAssignedClients(count) => {
+            format!("Não é possível excluir um grupo com {count} clientes atribuídos")
+        }
+        DomainError::ProtectedGroupCannotBeDisabled => {
+            "Grupo protegido não pode ser desativado".to_string()
+        }
+        DomainError::ProtectedGroupCannotBeDeleted => {
+            "Grupo protegido não pode ser excluído".to_string()
+        }
+
+        DomainError::InvalidInput(s) => format!("Entrada inválida: {s}"),
+        DomainError::InvalidUsername(s) => format!("Nome de usuário inválido: {s}"),
+        DomainError::InvalidPassword(s) => format!("Senha inválida: {s}"),
+        DomainError::InvalidDomainName(s) => format!("Nome de domínio inválido: {s}"),
+        DomainError::InvalidIpAddress(s) => format!("Endereço IP inválido: {s}"),
+        DomainError::InvalidCidr(s) => format!("Formato CIDR inválido: {s}"),
+        DomainError::InvalidGroupName(s) => format!("Nome de grupo inválido: {s}"),
+        DomainError::InvalidSafeSearchEngine(s) => {
+            format!("Mecanismo de Safe Search inválido: {s}")
+        }
+        DomainError::InvalidTimeSlot(s) => format!("Intervalo de horário inválido: {s}"),
+        DomainError::InvalidTimezone(s) => format!("Fuso horário inválido: {s}"),
+        DomainError::InvalidScheduleProfile(s) => format!("Perfil de agendamento inválido: {s}"),
+        DomainError::InvalidQueryPolicy(s) => format!("Política de consulta inválida: {s}"),
+        DomainError::InvalidDnsRewrite(s) => format!("Reescrita de DNS inválida: {s}"),
+        DomainError::InvalidCacheTtlOverride(s) => {
+            format!("Substituição de TTL de cache inválida: {s}")
+        }
+        DomainError::InvalidUnblockRequest(s) => format!("Pedido de desbloqueio inválido: {s}"),
+        DomainError::InvalidLocalRecord(s) => format!("Registro local inválido: {s}"),
+        DomainError::InvalidTenant(s) => format!("Tenant inválido: {s}"),
+        DomainError::InvalidRecordTypePolicy(s) => {
+            format!("Política de tipo de registro inválida: {s}")
+        }
+        DomainError::InvalidTldPolicy(s) => format!("Política de TLD inválida: {s}"),
+        DomainError::InvalidBlocklistSource(s) => {
+            format!("Fonte de lista de bloqueio inválida: {s}")
+        }
+        DomainError::InvalidWhitelistSource(s) => {
+            format!("Fonte de lista de permissões inválida: {s}")
+        }
+        DomainError::InvalidIpBlocklistSource(s) => {
+            format!("Fonte de lista de bloqueio de IPs inválida: {s}")
+        }
+        DomainError::InvalidManagedDomain(s) => format!("Domínio gerenciado inválido: {s}"),
+        DomainError::InvalidRegexFilter(s) => format!("Filtro regex inválido: {s}"),
+
+        _ => return None,
+    };
+    Some(text)
+}
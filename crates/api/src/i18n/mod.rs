@@ -0,0 +1,5 @@
+mod catalog;
+mod locale;
+
+pub use catalog::{error_message, Message};
+pub use locale::Locale;
@@ -1,14 +1,16 @@
 pub mod dto;
 pub mod errors;
 pub mod handlers;
+pub mod i18n;
 pub mod middleware;
 pub mod routes;
 pub mod state;
 pub mod utils;
 
 pub use errors::ApiError;
-pub use routes::create_api_routes;
+pub use routes::{create_api_routes, create_api_routes_with_limits, create_public_routes};
 pub use state::{
     AppState, AuthUseCases, BackupUseCases, BlockingUseCases, ClientUseCases, DnsUseCases,
-    GroupUseCases, QueryUseCases, SafeSearchUseCases, ScheduleUseCases, ServiceUseCases,
+    GroupUseCases, QueryPolicyUseCases, QueryUseCases, SafeSearchUseCases, ScheduleUseCases,
+    ServiceUseCases, TenantUseCases,
 };
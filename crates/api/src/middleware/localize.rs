@@ -0,0 +1,17 @@
+use crate::errors::ApiError;
+use crate::i18n::Locale;
+use axum::{extract::Request, middleware::Next, response::Response};
+
+/// Re-renders [`ApiError`] responses in the language picked from the
+/// request's `Accept-Language`. Handlers build error bodies in English; the
+/// error itself rides along as a response extension.
+pub async fn localize_errors(locale: Locale, request: Request, next: Next) -> Response {
+    let mut response = next.run(request).await;
+    if locale == Locale::En {
+        return response;
+    }
+    match response.extensions_mut().remove::<ApiError>() {
+        Some(error) => error.to_response(locale),
+        None => response,
+    }
+}
@@ -1,4 +1,8 @@
 pub mod api_key;
+pub mod localize;
+pub mod rate_limit;
 pub mod require_auth;
 
+pub use localize::localize_errors;
+pub use rate_limit::{limit_by_address, limit_by_token, ApiRateLimiter, AuthenticatedToken};
 pub use require_auth::require_auth;
@@ -0,0 +1,161 @@
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::{header, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use ferrous_dns_domain::ApiLimitsConfig;
+use lru::LruCache;
+use std::hash::Hash;
+use std::net::{IpAddr, SocketAddr};
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::debug;
+
+/// Buckets kept per limit; past this the least recently used one is
+/// dropped. A bucket idle long enough to refill behaves like a new one, so
+/// only clients beyond this many active at once can gain a fresh burst.
+const MAX_TRACKED_BUCKETS: usize = 10_000;
+
+/// Request extension set by `require_auth` when the request was
+/// authenticated with an API token.
+#[derive(Debug, Clone, Copy)]
+pub struct AuthenticatedToken {
+    pub id: i64,
+}
+
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+struct TokenBuckets<K> {
+    rate: f64,
+    burst: f64,
+    buckets: Mutex<LruCache<K, Bucket>>,
+}
+
+impl<K: Hash + Eq> TokenBuckets<K> {
+    fn new(rate: u32, burst: u32) -> Self {
+        Self {
+            rate: rate as f64,
+            burst: burst as f64,
+            buckets: Mutex::new(LruCache::new(
+                NonZeroUsize::new(MAX_TRACKED_BUCKETS).unwrap(),
+            )),
+        }
+    }
+
+    /// Takes one token for `key`, or returns how long until one is available.
+    fn acquire(&self, key: K, now: Instant) -> Result<(), Duration> {
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        let bucket = buckets.get_or_insert_mut(key, || Bucket {
+            tokens: self.burst,
+            refilled_at: now,
+        });
+        let elapsed = now.saturating_duration_since(bucket.refilled_at);
+        bucket.tokens = (bucket.tokens + elapsed.as_secs_f64() * self.rate).min(self.burst);
+        bucket.refilled_at = now;
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - bucket.tokens) / self.rate))
+        }
+    }
+}
+
+/// Per-address and per-token request limits for the REST API, from
+/// `[server.api_limits]`.
+#[derive(Clone)]
+pub struct ApiRateLimiter {
+    inner: Option<Arc<Limits>>,
+}
+
+struct Limits {
+    by_address: TokenBuckets<IpAddr>,
+    by_token: TokenBuckets<i64>,
+}
+
+impl ApiRateLimiter {
+    pub fn new(config: &ApiLimitsConfig) -> Self {
+        let inner = config.enabled.then(|| {
+            Arc::new(Limits {
+                by_address: TokenBuckets::new(config.requests_per_second, config.burst_size),
+                by_token: TokenBuckets::new(
+                    config.token_requests_per_second,
+                    config.token_burst_size,
+                ),
+            })
+        });
+        Self { inner }
+    }
+
+    /// Counts a request from `address`. Returns the time to wait before
+    /// retrying when the address is over its limit.
+    pub fn check_address(&self, address: IpAddr, now: Instant) -> Result<(), Duration> {
+        match &self.inner {
+            Some(limits) => limits.by_address.acquire(address, now),
+            None => Ok(()),
+        }
+    }
+
+    /// Counts a request made with the API token `token_id`.
+    pub fn check_token(&self, token_id: i64, now: Instant) -> Result<(), Duration> {
+        match &self.inner {
+            Some(limits) => limits.by_token.acquire(token_id, now),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Answers 429 Too Many Requests, with `Retry-After`, once the peer address
+/// exceeds its limit. Requests without a known peer address are not limited.
+pub async fn limit_by_address(
+    State(limiter): State<ApiRateLimiter>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let peer = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|info| info.0.ip());
+    if let Some(address) = peer {
+        if let Err(retry_after) = limiter.check_address(address, Instant::now()) {
+            debug!(client = %address, path = request.uri().path(), "API request rate limited");
+            return too_many_requests(retry_after);
+        }
+    }
+    next.run(request).await
+}
+
+/// Like [`limit_by_address`], for requests authenticated with an API token.
+/// Runs inside `require_auth`, which marks such requests.
+pub async fn limit_by_token(
+    State(limiter): State<ApiRateLimiter>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if let Some(token) = request.extensions().get::<AuthenticatedToken>() {
+        if let Err(retry_after) = limiter.check_token(token.id, Instant::now()) {
+            debug!(
+                token_id = token.id,
+                path = request.uri().path(),
+                "API token rate limited"
+            );
+            return too_many_requests(retry_after);
+        }
+    }
+    next.run(request).await
+}
+
+fn too_many_requests(retry_after: Duration) -> Response {
+    let secs = retry_after.as_secs_f64().ceil().max(1.0) as u64;
+    (
+        StatusCode::TOO_MANY_REQUESTS,
+        [(header::RETRY_AFTER, secs.to_string())],
+        "Too many requests",
+    )
+        .into_response()
+}
@@ -1,4 +1,5 @@
 use crate::handlers::auth::extract_session_cookie;
+use crate::middleware::rate_limit::AuthenticatedToken;
 use crate::state::AppState;
 use axum::{
     extract::{Request, State},
@@ -14,9 +15,13 @@ use axum::{
 /// 2. Check for session cookie (`ferrous_session`) → validate via `ValidateSessionUseCase`.
 /// 3. Check for `X-Api-Key` header → validate via `ValidateApiTokenUseCase`.
 /// 4. If neither is valid, return 401 Unauthorized.
+///
+/// A token scoped to a tenant only opens the paths below
+/// `/tenants/{tenant_id}/`; anything else is 403 Forbidden. Token requests
+/// are marked with [`AuthenticatedToken`] for the per-token rate limit.
 pub async fn require_auth(
     State(state): State<AppState>,
-    request: Request,
+    mut request: Request,
     next: Next,
 ) -> Result<Response, StatusCode> {
     if !state.auth_enabled().await {
@@ -36,8 +41,16 @@ pub async fn require_auth(
     }
 
     if let Some(token) = extract_api_token(&request) {
-        if state.auth.validate_api_token.execute(&token).await.is_ok() {
-            return Ok(next.run(request).await);
+        if let Ok(api_token) = state.auth.validate_api_token.authenticate(&token).await {
+            if let Some(id) = api_token.id {
+                request.extensions_mut().insert(AuthenticatedToken { id });
+            }
+            return match api_token.tenant_id {
+                Some(tenant_id) if !is_tenant_path(request.uri().path(), tenant_id) => {
+                    Err(StatusCode::FORBIDDEN)
+                }
+                _ => Ok(next.run(request).await),
+            };
         }
     }
 
@@ -51,3 +64,9 @@ fn extract_api_token(request: &Request) -> Option<String> {
         .and_then(|v| v.to_str().ok())
         .map(String::from)
 }
+
+fn is_tenant_path(path: &str, tenant_id: i64) -> bool {
+    path.strip_prefix("/tenants/")
+        .and_then(|rest| rest.split_once('/'))
+        .is_some_and(|(id, _)| id.parse::<i64>() == Ok(tenant_id))
+}
@@ -1,18 +1,41 @@
 use crate::handlers;
-use crate::middleware::require_auth;
+use crate::middleware::{
+    limit_by_address, limit_by_token, localize_errors, require_auth, ApiRateLimiter,
+};
 use crate::state::AppState;
 use axum::{
+    extract::DefaultBodyLimit,
     middleware,
     routing::{delete, get, patch, post, put},
     Router,
 };
+use ferrous_dns_domain::ApiLimitsConfig;
 
 pub fn create_api_routes(state: AppState) -> Router {
+    let limits = ApiLimitsConfig::default();
+    let rate_limiter = ApiRateLimiter::new(&limits);
+    create_api_routes_with_limits(state, &limits, rate_limiter)
+}
+
+/// Builds the API routes with the request rate and body size limits of
+/// `[server.api_limits]`. `rate_limiter` is shared with the other routers so
+/// a client has one budget however it reaches the server.
+pub fn create_api_routes_with_limits(
+    state: AppState,
+    limits: &ApiLimitsConfig,
+    rate_limiter: ApiRateLimiter,
+) -> Router {
     let public_auth_routes = Router::new()
         .route("/auth/status", get(handlers::auth::get_auth_status_public))
         .route("/auth/setup", post(handlers::auth::setup_password_public))
         .route("/auth/login", post(handlers::auth::login_public))
-        .route("/auth/logout", post(handlers::auth::logout_public));
+        .route("/auth/logout", post(handlers::auth::logout_public))
+        .route("/setup", get(handlers::setup::get_setup_public))
+        .route("/setup", post(handlers::setup::complete_setup_public))
+        .route(
+            "/standby/ready",
+            get(handlers::standby::get_standby_ready_public),
+        );
 
     let protected_routes = Router::new()
         .route("/health", get(handlers::health_check))
@@ -20,25 +43,54 @@ pub fn create_api_routes(state: AppState) -> Router {
         .route("/stats", get(handlers::get_stats))
         .route("/stats/rate", get(handlers::get_query_rate))
         .route("/queries/timeline", get(handlers::get_timeline))
+        .route(
+            "/queries/slow",
+            get(handlers::slow_queries::get_slow_queries),
+        )
         .route("/queries", get(handlers::get_queries))
         .route("/blocklist", get(handlers::get_blocklist))
+        .route(
+            "/blocklist/bulk",
+            post(handlers::bulk_add_blocklist)
+                .layer(DefaultBodyLimit::max(limits.max_bulk_body_bytes)),
+        )
         .route("/whitelist", get(handlers::get_whitelist))
+        .route(
+            "/whitelist/bulk",
+            post(handlers::bulk_add_whitelist)
+                .layer(DefaultBodyLimit::max(limits.max_bulk_body_bytes)),
+        )
         .route("/cache/stats", get(handlers::get_cache_stats))
         .route("/cache/metrics", get(handlers::get_cache_metrics))
+        .route("/cache/hotkeys", get(handlers::get_cache_hot_keys))
+        .route("/cache/flush", delete(handlers::flush_cache))
+        .route("/debug/domain/{name}", get(handlers::diagnose_domain))
+        .route("/debug/resolve", post(handlers::trace_resolve))
+        .route("/debug/inflight", get(handlers::get_inflight_queries))
+        .route(
+            "/debug/faults",
+            get(handlers::get_faults)
+                .post(handlers::inject_fault)
+                .delete(handlers::clear_faults),
+        )
+        .route("/debug/faults/{id}", delete(handlers::remove_fault))
         .route("/config", get(handlers::get_config))
         .route("/config", post(handlers::update_config))
         .route("/config/reload", post(handlers::reload_config))
+        .route("/config/log-level", put(handlers::update_log_level))
         .route("/hostname", get(handlers::get_hostname))
         .route("/clients", get(handlers::get_clients))
         .route("/clients", post(handlers::create_manual_client))
         .route("/clients/stats", get(handlers::get_client_stats))
         .route("/clients/{id}", patch(handlers::update_manual_client))
         .route("/clients/{id}", delete(handlers::delete_manual_client))
+        .route("/clients/{id}/activity", get(handlers::get_client_activity))
         .route("/clients/{id}/group", put(handlers::assign_client_to_group))
         .merge(handlers::groups::routes())
         .merge(handlers::client_subnets::routes())
         .merge(handlers::blocklist_sources::routes())
         .merge(handlers::whitelist_sources::routes())
+        .merge(handlers::ip_blocklist_sources::routes())
         .merge(handlers::managed_domains::routes())
         .merge(handlers::regex_filters::routes())
         .merge(handlers::blocked_services::routes())
@@ -49,6 +101,17 @@ pub fn create_api_routes(state: AppState) -> Router {
         .merge(handlers::block_filter::routes())
         .merge(handlers::safe_search::routes())
         .merge(handlers::schedule_profiles::routes())
+        .merge(handlers::query_policies::routes())
+        .merge(handlers::record_type_policies::routes())
+        .merge(handlers::tld_policies::routes())
+        .merge(handlers::dns_rewrites::routes())
+        .merge(handlers::cache_ttl_overrides::routes())
+        .merge(handlers::dns_json::routes())
+        .merge(handlers::alerts::routes())
+        .merge(handlers::dnssec::routes())
+        .merge(handlers::unblock_requests::routes())
+        .merge(handlers::audit_log::routes())
+        .merge(handlers::tenants::routes())
         .route(
             "/upstream/health",
             get(handlers::upstream::get_upstream_health),
@@ -57,7 +120,25 @@ pub fn create_api_routes(state: AppState) -> Router {
             "/upstream/health/detail",
             get(handlers::upstream::get_upstream_health_detail),
         )
+        .route(
+            "/upstream/validation",
+            get(handlers::upstream::get_response_validation),
+        )
+        .route(
+            "/access-control",
+            get(handlers::access_control::get_access_control),
+        )
+        .route(
+            "/forwarding/conditional",
+            get(handlers::conditional_forwards::get_conditional_forwards),
+        )
+        .route(
+            "/sinkhole/telemetry",
+            get(handlers::sinkhole::get_sinkhole_telemetry),
+        )
+        .route("/system", get(handlers::get_system_metrics))
         .route("/system/info", get(handlers::get_system_info))
+        .route("/system/database", get(handlers::get_database_status))
         .route("/tls/status", get(handlers::tls::get_tls_status))
         .route("/tls/upload", post(handlers::tls::upload_tls_certs))
         .route("/tls/generate", post(handlers::tls::generate_self_signed))
@@ -65,10 +146,44 @@ pub fn create_api_routes(state: AppState) -> Router {
         .merge(handlers::users::routes())
         .merge(handlers::api_tokens::routes())
         .merge(handlers::backup::routes())
+        .merge(handlers::standby::routes())
+        .layer(middleware::from_fn_with_state(
+            rate_limiter.clone(),
+            limit_by_token,
+        ))
         .layer(middleware::from_fn_with_state(state.clone(), require_auth));
 
     Router::new()
         .merge(public_auth_routes)
         .merge(protected_routes)
+        .layer(DefaultBodyLimit::max(limits.max_body_bytes))
+        .layer(middleware::from_fn_with_state(
+            rate_limiter,
+            limit_by_address,
+        ))
+        .layer(middleware::from_fn(localize_errors))
+        .with_state(state)
+}
+
+/// Unauthenticated routes meant to be reachable from outside the admin
+/// networks, such as the public stats summary. Mounted by the web server
+/// after `[server.admin_access]` is applied.
+pub fn create_public_routes(
+    state: AppState,
+    limits: &ApiLimitsConfig,
+    rate_limiter: ApiRateLimiter,
+) -> Router {
+    Router::new()
+        .route("/public/stats", get(handlers::get_public_stats))
+        .route(
+            "/public/requests",
+            post(handlers::unblock_requests::submit_unblock_request_public),
+        )
+        .layer(DefaultBodyLimit::max(limits.max_body_bytes))
+        .layer(middleware::from_fn_with_state(
+            rate_limiter,
+            limit_by_address,
+        ))
+        .layer(middleware::from_fn(localize_errors))
         .with_state(state)
 }
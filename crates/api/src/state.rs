@@ -1,31 +1,48 @@
 use ferrous_dns_application::ports::{
-    ConfigFilePersistence, DnsCachePort, TlsCertificatePort, UpstreamHealthPort,
+    AccessControlPort, ConditionalForwardStatsPort, ConfigFilePersistence, DnsCachePort,
+    FaultInjectionPort, InflightQueriesPort, LogLevelPort, QueryMetricsPort, SinkholeTelemetryPort,
+    SlowQueryLogPort, SystemMetricsPort, TlsCertificatePort, UpstreamHealthPort,
 };
 use ferrous_dns_application::services::SubnetMatcherService;
 use ferrous_dns_application::use_cases::{
-    AssignClientGroupUseCase, AssignScheduleProfileUseCase, BlockServiceUseCase,
-    ChangePasswordUseCase, CreateApiTokenUseCase, CreateBlocklistSourceUseCase,
-    CreateClientSubnetUseCase, CreateCustomServiceUseCase, CreateGroupUseCase,
+    ApproveUnblockRequestUseCase, AssignClientGroupUseCase, AssignScheduleProfileUseCase,
+    BlockServiceUseCase, BulkAddBlocklistUseCase, BulkAddWhitelistUseCase, ChangePasswordUseCase,
+    CreateApiTokenUseCase, CreateBackupUseCase, CreateBlocklistSourceUseCase,
+    CreateCacheTtlOverrideUseCase, CreateClientSubnetUseCase, CreateCustomServiceUseCase,
+    CreateDnsRewriteUseCase, CreateGroupUseCase, CreateIpBlocklistSourceUseCase,
     CreateLocalRecordUseCase, CreateManagedDomainUseCase, CreateManualClientUseCase,
-    CreateRegexFilterUseCase, CreateScheduleProfileUseCase, CreateUserUseCase,
-    CreateWhitelistSourceUseCase, DeleteApiTokenUseCase, DeleteBlocklistSourceUseCase,
-    DeleteClientSubnetUseCase, DeleteClientUseCase, DeleteCustomServiceUseCase, DeleteGroupUseCase,
-    DeleteLocalRecordUseCase, DeleteManagedDomainUseCase, DeleteRegexFilterUseCase,
-    DeleteSafeSearchConfigsUseCase, DeleteScheduleProfileUseCase, DeleteUserUseCase,
-    DeleteWhitelistSourceUseCase, ExportConfigUseCase, GetActiveSessionsUseCase,
-    GetApiTokensUseCase, GetAuthStatusUseCase, GetBlockFilterStatsUseCase,
+    CreateQueryPolicyUseCase, CreateRegexFilterUseCase, CreateScheduleProfileUseCase,
+    CreateTenantGroupUseCase, CreateTenantUseCase, CreateUserUseCase, CreateWhitelistSourceUseCase,
+    DatabaseMaintenanceUseCase, DeleteAlertUseCase, DeleteApiTokenUseCase,
+    DeleteBlocklistSourceUseCase, DeleteCacheTtlOverrideUseCase, DeleteClientSubnetUseCase,
+    DeleteClientUseCase, DeleteCustomServiceUseCase, DeleteDnsRewriteUseCase, DeleteGroupUseCase,
+    DeleteIpBlocklistSourceUseCase, DeleteLocalRecordUseCase, DeleteManagedDomainUseCase,
+    DeleteQueryPolicyUseCase, DeleteRecordTypePolicyUseCase, DeleteRegexFilterUseCase,
+    DeleteSafeSearchConfigsUseCase, DeleteScheduleProfileUseCase, DeleteTenantUseCase,
+    DeleteTldPolicyUseCase, DeleteUnblockRequestUseCase, DeleteUserUseCase,
+    DeleteWhitelistSourceUseCase, DenyUnblockRequestUseCase, DiagnoseDomainUseCase,
+    ExportConfigUseCase, ExportPrimaryStateUseCase, GetActiveSessionsUseCase, GetAlertsUseCase,
+    GetApiTokensUseCase, GetAuditLogUseCase, GetAuthStatusUseCase, GetBlockFilterStatsUseCase,
     GetBlockedServicesUseCase, GetBlocklistSourcesUseCase, GetBlocklistUseCase,
-    GetCacheStatsUseCase, GetClientSubnetsUseCase, GetClientsUseCase, GetCustomServicesUseCase,
-    GetGroupsUseCase, GetManagedDomainsUseCase, GetQueryRateUseCase, GetQueryStatsUseCase,
-    GetRecentQueriesUseCase, GetRegexFiltersUseCase, GetSafeSearchConfigsUseCase,
-    GetScheduleProfilesUseCase, GetServiceCatalogUseCase, GetTimelineUseCase,
-    GetTopBlockedDomainsUseCase, GetTopClientsUseCase, GetUsersUseCase, GetWhitelistSourcesUseCase,
-    GetWhitelistUseCase, ImportConfigUseCase, LoginUseCase, LogoutUseCase, ManageTimeSlotsUseCase,
-    SetupPasswordUseCase, ToggleSafeSearchUseCase, UnblockServiceUseCase, UpdateApiTokenUseCase,
-    UpdateBlocklistSourceUseCase, UpdateClientUseCase, UpdateCustomServiceUseCase,
-    UpdateGroupUseCase, UpdateLocalRecordUseCase, UpdateManagedDomainUseCase,
-    UpdateRegexFilterUseCase, UpdateScheduleProfileUseCase, UpdateWhitelistSourceUseCase,
-    ValidateApiTokenUseCase, ValidateSessionUseCase,
+    GetCacheStatsUseCase, GetCacheTtlOverridesUseCase, GetClientActivityUseCase,
+    GetClientSubnetsUseCase, GetClientsUseCase, GetCustomServicesUseCase, GetDnsRewritesUseCase,
+    GetDnssecStatsUseCase, GetDnssecStatusUseCase, GetGroupsUseCase, GetIpBlocklistSourcesUseCase,
+    GetLocalRecordsUseCase, GetManagedDomainsUseCase, GetQueryPoliciesUseCase, GetQueryRateUseCase,
+    GetQueryStatsUseCase, GetRecentQueriesUseCase, GetRecordTypePoliciesUseCase,
+    GetRegexFiltersUseCase, GetSafeSearchConfigsUseCase, GetScheduleProfilesUseCase,
+    GetServiceCatalogUseCase, GetTenantsUseCase, GetTimelineUseCase, GetTldPoliciesUseCase,
+    GetTopBlockedDomainsUseCase, GetTopClientsUseCase, GetUnblockRequestsUseCase, GetUsersUseCase,
+    GetWhitelistSourcesUseCase, GetWhitelistUseCase, HandleDnsQueryUseCase, ImportConfigUseCase,
+    ImportExternalConfigUseCase, LoginUseCase, LogoutUseCase, ManageTimeSlotsUseCase,
+    RestoreBackupUseCase, SetRecordTypePolicyUseCase, SetTldPolicyUseCase, SetupPasswordUseCase,
+    SetupWizardUseCase, SubmitUnblockRequestUseCase, SyncFromPrimaryUseCase,
+    TenantBlocklistSourcesUseCase, ToggleSafeSearchUseCase, TraceResolveUseCase,
+    UnblockServiceUseCase, UpdateApiTokenUseCase, UpdateBlocklistSourceUseCase,
+    UpdateCacheTtlOverrideUseCase, UpdateClientUseCase, UpdateCustomServiceUseCase,
+    UpdateDnsRewriteUseCase, UpdateGroupUseCase, UpdateIpBlocklistSourceUseCase,
+    UpdateLocalRecordUseCase, UpdateManagedDomainUseCase, UpdateQueryPolicyUseCase,
+    UpdateRegexFilterUseCase, UpdateScheduleProfileUseCase, UpdateTenantUseCase,
+    UpdateWhitelistSourceUseCase, ValidateApiTokenUseCase, ValidateSessionUseCase,
 };
 use ferrous_dns_domain::Config;
 use std::sync::Arc;
@@ -40,6 +57,12 @@ pub struct QueryUseCases {
     pub get_cache_stats: Arc<GetCacheStatsUseCase>,
     pub get_top_blocked_domains: Arc<GetTopBlockedDomainsUseCase>,
     pub get_top_clients: Arc<GetTopClientsUseCase>,
+    pub database_maintenance: Arc<DatabaseMaintenanceUseCase>,
+    pub get_alerts: Arc<GetAlertsUseCase>,
+    pub delete_alert: Arc<DeleteAlertUseCase>,
+    pub get_audit_log: Arc<GetAuditLogUseCase>,
+    /// Counts since startup, kept in memory by the DNS handler.
+    pub live_metrics: Arc<dyn QueryMetricsPort>,
 }
 
 #[derive(Clone)]
@@ -47,8 +70,23 @@ pub struct DnsUseCases {
     pub cache: Arc<dyn DnsCachePort>,
     pub create_local_record: Arc<CreateLocalRecordUseCase>,
     pub update_local_record: Arc<UpdateLocalRecordUseCase>,
+    pub get_local_records: Arc<GetLocalRecordsUseCase>,
     pub delete_local_record: Arc<DeleteLocalRecordUseCase>,
     pub upstream_health: Arc<dyn UpstreamHealthPort>,
+    pub access_control: Arc<dyn AccessControlPort>,
+    pub sinkhole_telemetry: Arc<dyn SinkholeTelemetryPort>,
+    pub conditional_forwards: Arc<dyn ConditionalForwardStatsPort>,
+    pub slow_query_log: Arc<dyn SlowQueryLogPort>,
+    pub inflight: Arc<dyn InflightQueriesPort>,
+    pub diagnose_domain: Arc<DiagnoseDomainUseCase>,
+    pub trace_resolve: Arc<TraceResolveUseCase>,
+    pub get_dnssec_status: Arc<GetDnssecStatusUseCase>,
+    pub get_dnssec_stats: Arc<GetDnssecStatsUseCase>,
+    /// Resolves queries exactly as the DNS listeners do; backs `/resolve`.
+    pub resolve_query: Arc<HandleDnsQueryUseCase>,
+    /// Set only in builds with the `fault-injection` feature and
+    /// `dns.fault_injection` enabled.
+    pub faults: Option<Arc<dyn FaultInjectionPort>>,
 }
 
 #[derive(Clone)]
@@ -66,6 +104,7 @@ pub struct ClientUseCases {
     pub create_manual_client: Arc<CreateManualClientUseCase>,
     pub update_client: Arc<UpdateClientUseCase>,
     pub delete_client: Arc<DeleteClientUseCase>,
+    pub get_client_activity: Arc<GetClientActivityUseCase>,
     pub get_client_subnets: Arc<GetClientSubnetsUseCase>,
     pub create_client_subnet: Arc<CreateClientSubnetUseCase>,
     pub delete_client_subnet: Arc<DeleteClientSubnetUseCase>,
@@ -75,15 +114,21 @@ pub struct ClientUseCases {
 #[derive(Clone)]
 pub struct BlockingUseCases {
     pub get_blocklist: Arc<GetBlocklistUseCase>,
+    pub bulk_add_blocklist: Arc<BulkAddBlocklistUseCase>,
     pub get_blocklist_sources: Arc<GetBlocklistSourcesUseCase>,
     pub create_blocklist_source: Arc<CreateBlocklistSourceUseCase>,
     pub update_blocklist_source: Arc<UpdateBlocklistSourceUseCase>,
     pub delete_blocklist_source: Arc<DeleteBlocklistSourceUseCase>,
     pub get_whitelist: Arc<GetWhitelistUseCase>,
+    pub bulk_add_whitelist: Arc<BulkAddWhitelistUseCase>,
     pub get_whitelist_sources: Arc<GetWhitelistSourcesUseCase>,
     pub create_whitelist_source: Arc<CreateWhitelistSourceUseCase>,
     pub update_whitelist_source: Arc<UpdateWhitelistSourceUseCase>,
     pub delete_whitelist_source: Arc<DeleteWhitelistSourceUseCase>,
+    pub get_ip_blocklist_sources: Arc<GetIpBlocklistSourcesUseCase>,
+    pub create_ip_blocklist_source: Arc<CreateIpBlocklistSourceUseCase>,
+    pub update_ip_blocklist_source: Arc<UpdateIpBlocklistSourceUseCase>,
+    pub delete_ip_blocklist_source: Arc<DeleteIpBlocklistSourceUseCase>,
     pub get_managed_domains: Arc<GetManagedDomainsUseCase>,
     pub create_managed_domain: Arc<CreateManagedDomainUseCase>,
     pub update_managed_domain: Arc<UpdateManagedDomainUseCase>,
@@ -93,6 +138,11 @@ pub struct BlockingUseCases {
     pub update_regex_filter: Arc<UpdateRegexFilterUseCase>,
     pub delete_regex_filter: Arc<DeleteRegexFilterUseCase>,
     pub get_block_filter_stats: Arc<GetBlockFilterStatsUseCase>,
+    pub get_unblock_requests: Arc<GetUnblockRequestsUseCase>,
+    pub submit_unblock_request: Arc<SubmitUnblockRequestUseCase>,
+    pub approve_unblock_request: Arc<ApproveUnblockRequestUseCase>,
+    pub deny_unblock_request: Arc<DenyUnblockRequestUseCase>,
+    pub delete_unblock_request: Arc<DeleteUnblockRequestUseCase>,
 }
 
 #[derive(Clone)]
@@ -124,12 +174,35 @@ pub struct ScheduleUseCases {
     pub assign_profile: Arc<AssignScheduleProfileUseCase>,
 }
 
+#[derive(Clone)]
+pub struct QueryPolicyUseCases {
+    pub get_policies: Arc<GetQueryPoliciesUseCase>,
+    pub create_policy: Arc<CreateQueryPolicyUseCase>,
+    pub update_policy: Arc<UpdateQueryPolicyUseCase>,
+    pub delete_policy: Arc<DeleteQueryPolicyUseCase>,
+    pub get_record_type_policies: Arc<GetRecordTypePoliciesUseCase>,
+    pub set_record_type_policy: Arc<SetRecordTypePolicyUseCase>,
+    pub delete_record_type_policy: Arc<DeleteRecordTypePolicyUseCase>,
+    pub get_tld_policies: Arc<GetTldPoliciesUseCase>,
+    pub set_tld_policy: Arc<SetTldPolicyUseCase>,
+    pub delete_tld_policy: Arc<DeleteTldPolicyUseCase>,
+    pub get_rewrites: Arc<GetDnsRewritesUseCase>,
+    pub create_rewrite: Arc<CreateDnsRewriteUseCase>,
+    pub update_rewrite: Arc<UpdateDnsRewriteUseCase>,
+    pub delete_rewrite: Arc<DeleteDnsRewriteUseCase>,
+    pub get_ttl_overrides: Arc<GetCacheTtlOverridesUseCase>,
+    pub create_ttl_override: Arc<CreateCacheTtlOverrideUseCase>,
+    pub update_ttl_override: Arc<UpdateCacheTtlOverrideUseCase>,
+    pub delete_ttl_override: Arc<DeleteCacheTtlOverrideUseCase>,
+}
+
 #[derive(Clone)]
 pub struct AuthUseCases {
     pub login: Arc<LoginUseCase>,
     pub logout: Arc<LogoutUseCase>,
     pub validate_session: Arc<ValidateSessionUseCase>,
     pub setup_password: Arc<SetupPasswordUseCase>,
+    pub setup_wizard: Arc<SetupWizardUseCase>,
     pub change_password: Arc<ChangePasswordUseCase>,
     pub get_auth_status: Arc<GetAuthStatusUseCase>,
     pub get_active_sessions: Arc<GetActiveSessionsUseCase>,
@@ -147,6 +220,22 @@ pub struct AuthUseCases {
 pub struct BackupUseCases {
     pub export: Arc<ExportConfigUseCase>,
     pub import: Arc<ImportConfigUseCase>,
+    pub create: Arc<CreateBackupUseCase>,
+    pub restore: Arc<RestoreBackupUseCase>,
+    pub external_import: Arc<ImportExternalConfigUseCase>,
+    pub primary_state: Arc<ExportPrimaryStateUseCase>,
+    /// Set when this instance runs as a standby (`standby.enabled`).
+    pub standby: Option<Arc<SyncFromPrimaryUseCase>>,
+}
+
+#[derive(Clone)]
+pub struct TenantUseCases {
+    pub get_tenants: Arc<GetTenantsUseCase>,
+    pub create_tenant: Arc<CreateTenantUseCase>,
+    pub update_tenant: Arc<UpdateTenantUseCase>,
+    pub delete_tenant: Arc<DeleteTenantUseCase>,
+    pub create_tenant_group: Arc<CreateTenantGroupUseCase>,
+    pub blocklist_sources: Arc<TenantBlocklistSourcesUseCase>,
 }
 
 #[derive(Clone)]
@@ -159,13 +248,17 @@ pub struct AppState {
     pub services: ServiceUseCases,
     pub safe_search: SafeSearchUseCases,
     pub schedule: ScheduleUseCases,
+    pub policies: QueryPolicyUseCases,
     pub auth: AuthUseCases,
     pub backup: BackupUseCases,
+    pub tenants: TenantUseCases,
     pub config: Arc<RwLock<Config>>,
     pub config_file_persistence: Arc<dyn ConfigFilePersistence>,
     pub config_path: Option<Arc<str>>,
     pub tls_cert: Arc<dyn TlsCertificatePort>,
     pub tls_enabled: bool,
+    pub log_levels: Arc<dyn LogLevelPort>,
+    pub system_metrics: Arc<dyn SystemMetricsPort>,
 }
 
 impl AppState {
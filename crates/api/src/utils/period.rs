@@ -1,3 +1,5 @@
+use ferrous_dns_application::ports::TimeGranularity;
+
 pub fn parse_period(period: &str) -> Option<f32> {
     if period.is_empty() {
         return Some(24.0);
@@ -26,3 +28,22 @@ pub fn parse_period(period: &str) -> Option<f32> {
 pub fn validate_period(hours: f32) -> f32 {
     hours.min(720.0)
 }
+
+pub fn parse_granularity(s: &str) -> TimeGranularity {
+    match s {
+        "minute" => TimeGranularity::Minute,
+        "10min" | "ten_minutes" => TimeGranularity::TenMinutes,
+        "15min" | "quarter_hour" => TimeGranularity::QuarterHour,
+        "day" => TimeGranularity::Day,
+        _ => TimeGranularity::Hour,
+    }
+}
+
+/// Like [`parse_granularity`], but `auto` yields `None` so the use case picks
+/// a granularity from the period.
+pub fn parse_timeline_granularity(s: &str) -> Option<TimeGranularity> {
+    match s {
+        "auto" => None,
+        other => Some(parse_granularity(other)),
+    }
+}
@@ -0,0 +1,117 @@
+use ferrous_dns_api::middleware::ApiRateLimiter;
+use ferrous_dns_domain::ApiLimitsConfig;
+use std::net::IpAddr;
+use std::time::{Duration, Instant};
+
+fn limits(requests_per_second: u32, burst_size: u32) -> ApiLimitsConfig {
+    ApiLimitsConfig {
+        requests_per_second,
+        burst_size,
+        token_requests_per_second: requests_per_second,
+        token_burst_size: burst_size,
+        ..Default::default()
+    }
+}
+
+fn ip(s: &str) -> IpAddr {
+    s.parse().unwrap()
+}
+
+#[test]
+fn allows_burst_then_refuses_with_retry_after() {
+    let limiter = ApiRateLimiter::new(&limits(2, 3));
+    let now = Instant::now();
+
+    for _ in 0..3 {
+        assert!(limiter.check_address(ip("10.0.0.5"), now).is_ok());
+    }
+    let retry_after = limiter.check_address(ip("10.0.0.5"), now).unwrap_err();
+    assert_eq!(retry_after, Duration::from_millis(500));
+}
+
+#[test]
+fn refills_at_configured_rate() {
+    let limiter = ApiRateLimiter::new(&limits(2, 1));
+    let now = Instant::now();
+
+    assert!(limiter.check_address(ip("10.0.0.5"), now).is_ok());
+    assert!(limiter.check_address(ip("10.0.0.5"), now).is_err());
+    assert!(limiter
+        .check_address(ip("10.0.0.5"), now + Duration::from_millis(500))
+        .is_ok());
+}
+
+#[test]
+fn addresses_and_tokens_have_separate_budgets() {
+    let limiter = ApiRateLimiter::new(&limits(1, 1));
+    let now = Instant::now();
+
+    assert!(limiter.check_address(ip("10.0.0.5"), now).is_ok());
+    assert!(limiter.check_address(ip("10.0.0.5"), now).is_err());
+    assert!(limiter.check_address(ip("10.0.0.6"), now).is_ok());
+
+    assert!(limiter.check_token(1, now).is_ok());
+    assert!(limiter.check_token(1, now).is_err());
+    assert!(limiter.check_token(2, now).is_ok());
+}
+
+#[test]
+fn clones_share_one_budget() {
+    let limiter = ApiRateLimiter::new(&limits(1, 2));
+    let other = limiter.clone();
+    let now = Instant::now();
+
+    assert!(limiter.check_address(ip("10.0.0.5"), now).is_ok());
+    assert!(other.check_address(ip("10.0.0.5"), now).is_ok());
+    assert!(limiter.check_address(ip("10.0.0.5"), now).is_err());
+    assert!(other.check_address(ip("10.0.0.5"), now).is_err());
+}
+
+#[test]
+fn active_client_stays_limited_while_many_others_arrive() {
+    let limiter = ApiRateLimiter::new(&limits(1, 1));
+    let now = Instant::now();
+
+    assert!(limiter.check_address(ip("10.0.0.5"), now).is_ok());
+    for n in 0..20_000u32 {
+        let address = IpAddr::from(std::net::Ipv4Addr::from(0x0b00_0000 + n));
+        assert!(limiter.check_address(address, now).is_ok());
+        if n % 1_000 == 0 {
+            assert!(limiter.check_address(ip("10.0.0.5"), now).is_err());
+        }
+    }
+    assert!(limiter.check_address(ip("10.0.0.5"), now).is_err());
+}
+
+#[test]
+fn disabled_limiter_allows_everything() {
+    let limiter = ApiRateLimiter::new(&ApiLimitsConfig {
+        enabled: false,
+        ..limits(1, 1)
+    });
+    let now = Instant::now();
+
+    for _ in 0..10 {
+        assert!(limiter.check_address(ip("10.0.0.5"), now).is_ok());
+        assert!(limiter.check_token(1, now).is_ok());
+    }
+}
+
+#[test]
+fn config_validation() {
+    assert!(ApiLimitsConfig::default().validate().is_ok());
+    assert!(limits(0, 10).validate().is_err());
+    assert!(ApiLimitsConfig {
+        enabled: false,
+        ..limits(0, 0)
+    }
+    .validate()
+    .is_ok());
+    assert!(ApiLimitsConfig {
+        max_bulk_body_bytes: 1024,
+        max_body_bytes: 4096,
+        ..Default::default()
+    }
+    .validate()
+    .is_err());
+}
@@ -10,25 +10,30 @@ use ferrous_dns_api::{
 use ferrous_dns_application::ports::{BlocklistSourceCreator, GroupCreator, LocalRecordCreator};
 use ferrous_dns_application::{
     ports::{
-        BlockFilterEnginePort, BlockedServiceRepository, ConfigFilePersistence, ConfigRepository,
-        FilterDecision, SafeSearchConfigRepository, SafeSearchEnginePort, ServiceCatalogPort,
+        BlockFilterEnginePort, BlockedServiceRepository, ConfigFilePersistence, FilterDecision,
+        LocalRecordRepository, SafeSearchConfigRepository, SafeSearchEnginePort,
+        ServiceCatalogPort,
     },
     services::SubnetMatcherService,
     use_cases::{
         AssignScheduleProfileUseCase, CreateBlocklistSourceUseCase, CreateGroupUseCase,
         CreateLocalRecordUseCase, CreateScheduleProfileUseCase, DeleteScheduleProfileUseCase,
-        ExportConfigUseCase, GetBlockFilterStatsUseCase, GetScheduleProfilesUseCase,
-        ImportConfigUseCase, ManageTimeSlotsUseCase, UpdateScheduleProfileUseCase, *,
+        ExportConfigUseCase, GetBlockFilterStatsUseCase, GetLocalRecordsUseCase,
+        GetScheduleProfilesUseCase, ImportConfigUseCase, ManageTimeSlotsUseCase,
+        UpdateScheduleProfileUseCase, *,
     },
 };
 use ferrous_dns_domain::{config::DatabaseConfig, Config, LocalDnsRecord};
 use ferrous_dns_infrastructure::{
+    backup::{SqliteBackupStore, TarGzBackupArchiver},
     dns::cache::DnsCache,
+    external_import::ExternalConfigFileReader,
     repositories::{
         blocklist_source_repository::SqliteBlocklistSourceRepository,
         client_repository::SqliteClientRepository,
         client_subnet_repository::SqliteClientSubnetRepository,
         group_repository::SqliteGroupRepository,
+        local_record_repository::SqliteLocalRecordRepository,
         managed_domain_repository::SqliteManagedDomainRepository,
         regex_filter_repository::SqliteRegexFilterRepository,
     },
@@ -51,6 +56,9 @@ impl BlockFilterEnginePort for NullBlockFilterEngine {
     fn resolve_group(&self, _ip: std::net::IpAddr) -> i64 {
         1
     }
+    fn resolve_group_with_default(&self, _ip: std::net::IpAddr, _default_group_id: i64) -> i64 {
+        1
+    }
     fn check(&self, _domain: &str, _group_id: i64) -> FilterDecision {
         FilterDecision::Allow
     }
@@ -160,18 +168,6 @@ impl ServiceCatalogPort for NullServiceCatalog {
     fn reload_custom(&self, _custom: Vec<ferrous_dns_domain::ServiceDefinition>) {}
 }
 
-struct NullConfigRepository;
-
-#[async_trait::async_trait]
-impl ConfigRepository for NullConfigRepository {
-    async fn save_local_records(
-        &self,
-        _config: &Config,
-    ) -> Result<(), ferrous_dns_domain::DomainError> {
-        Ok(())
-    }
-}
-
 struct NullConfigFilePersistence;
 
 impl ConfigFilePersistence for NullConfigFilePersistence {
@@ -317,6 +313,9 @@ async fn create_test_db() -> sqlx::SqlitePool {
             enabled BOOLEAN NOT NULL DEFAULT 1,
             comment TEXT,
             is_default BOOLEAN NOT NULL DEFAULT 0,
+            filter_aaaa BOOLEAN NOT NULL DEFAULT 0,
+            audit_mode  BOOLEAN NOT NULL DEFAULT 0,
+            tenant_id INTEGER,
             created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
             updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
         )
@@ -345,7 +344,10 @@ async fn create_test_db() -> sqlx::SqlitePool {
             last_hostname_update DATETIME,
             group_id INTEGER NOT NULL DEFAULT 1 REFERENCES groups(id) ON DELETE RESTRICT,
             created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
-            updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+            updated_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            display_name TEXT,
+            category TEXT,
+            notes TEXT
         )
         "#,
     )
@@ -379,6 +381,8 @@ async fn create_test_db() -> sqlx::SqlitePool {
             group_id    INTEGER NOT NULL DEFAULT 1 REFERENCES groups(id) ON DELETE RESTRICT,
             comment     TEXT,
             enabled     BOOLEAN NOT NULL DEFAULT 1,
+            audit_mode  BOOLEAN NOT NULL DEFAULT 0,
+            tenant_id   INTEGER,
             created_at  DATETIME DEFAULT CURRENT_TIMESTAMP,
             updated_at  DATETIME DEFAULT CURRENT_TIMESTAMP
         )
@@ -517,6 +521,33 @@ async fn create_test_db() -> sqlx::SqlitePool {
     .await
     .unwrap();
 
+    sqlx::query(
+        r#"
+        CREATE TABLE local_records (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            hostname TEXT NOT NULL,
+            domain TEXT,
+            record_type TEXT NOT NULL,
+            value TEXT NOT NULL,
+            ttl INTEGER NOT NULL DEFAULT 300,
+            view TEXT,
+            tenant_id INTEGER,
+            created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        "CREATE UNIQUE INDEX idx_local_records_unique ON local_records(hostname, COALESCE(domain, ''), record_type, value, COALESCE(view, ''), COALESCE(tenant_id, 0))",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
     pool
 }
 
@@ -554,10 +585,11 @@ async fn create_test_app() -> (Router, Arc<RwLock<Config>>, sqlx::SqlitePool) {
             refresh_sample_rate: 1.0,
             min_ttl: 0,
             max_ttl: 86_400,
+            l1_capacity: 1024,
         },
     ));
 
-    use ferrous_dns_domain::config::upstream::{UpstreamPool, UpstreamStrategy};
+    use ferrous_dns_domain::config::upstream::{UdpSocketMode, UpstreamPool, UpstreamStrategy};
     use ferrous_dns_infrastructure::dns::{PoolManager, QueryEventEmitter};
 
     let event_emitter = QueryEventEmitter::new_disabled();
@@ -567,6 +599,8 @@ async fn create_test_app() -> (Router, Arc<RwLock<Config>>, sqlx::SqlitePool) {
         priority: 1,
         servers: vec!["8.8.8.8:53".to_string()],
         weight: None,
+        tsig_key: None,
+        udp_socket: UdpSocketMode::Pooled,
     };
     let pool_manager = Arc::new(
         PoolManager::new(vec![test_pool], None, event_emitter)
@@ -584,25 +618,62 @@ async fn create_test_app() -> (Router, Arc<RwLock<Config>>, sqlx::SqlitePool) {
                 as Arc<dyn ferrous_dns_application::ports::BlocklistSourceRepository>,
             group_repo.clone() as Arc<dyn ferrous_dns_application::ports::GroupRepository>,
         ));
-    let local_record_creator: Arc<dyn LocalRecordCreator> = Arc::new(
-        CreateLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository)),
-    );
+    let local_record_creator: Arc<dyn LocalRecordCreator> =
+        Arc::new(CreateLocalRecordUseCase::new(
+            config.clone(),
+            Arc::new(SqliteLocalRecordRepository::new(pool.clone())),
+            Arc::new(helpers::NullLocalZone),
+        ));
 
     let backup = BackupUseCases {
-        export: Arc::new(ExportConfigUseCase::new(
-            config.clone(),
-            group_repo.clone() as Arc<dyn ferrous_dns_application::ports::GroupRepository>,
-            blocklist_source_repo.clone()
-                as Arc<dyn ferrous_dns_application::ports::BlocklistSourceRepository>,
-        )),
+        export: Arc::new(
+            ExportConfigUseCase::new(
+                config.clone(),
+                group_repo.clone() as Arc<dyn ferrous_dns_application::ports::GroupRepository>,
+                blocklist_source_repo.clone()
+                    as Arc<dyn ferrous_dns_application::ports::BlocklistSourceRepository>,
+            )
+            .with_local_record_repository(Arc::new(SqliteLocalRecordRepository::new(pool.clone()))),
+        ),
         import: Arc::new(ImportConfigUseCase::new(
             config.clone(),
             Arc::new(NullConfigFilePersistence),
             Some("ferrous-dns.toml".to_string()),
             group_creator,
             blocklist_source_creator,
-            local_record_creator,
+            local_record_creator.clone(),
+        )),
+        create: Arc::new(CreateBackupUseCase::new(
+            config.clone(),
+            Arc::new(SqliteBackupStore::new(pool.clone())),
+            Arc::new(TarGzBackupArchiver),
+        )),
+        restore: Arc::new(RestoreBackupUseCase::new(
+            config.clone(),
+            Arc::new(NullConfigFilePersistence),
+            Some("ferrous-dns.toml".to_string()),
+            Arc::new(SqliteBackupStore::new(pool.clone())),
+            Arc::new(TarGzBackupArchiver),
         )),
+        external_import: Arc::new(ImportExternalConfigUseCase::new(
+            Arc::new(ExternalConfigFileReader),
+            config.clone(),
+            group_repo.clone(),
+            blocklist_source_repo.clone(),
+            Arc::new(
+                ferrous_dns_infrastructure::repositories::whitelist_source_repository::SqliteWhitelistSourceRepository::new(pool.clone()),
+            ),
+            managed_domain_repo.clone(),
+            regex_filter_repo.clone(),
+            client_repo.clone(),
+            subnet_repo.clone(),
+            local_record_creator.clone(),
+        )),
+        primary_state: Arc::new(ExportPrimaryStateUseCase::new(
+            null_engine.clone(),
+            cache.clone() as Arc<dyn ferrous_dns_application::ports::DnsCachePort>,
+        )),
+        standby: None,
     };
 
     let ql_repo = || {
@@ -620,13 +691,43 @@ async fn create_test_app() -> (Router, Arc<RwLock<Config>>, sqlx::SqlitePool) {
             get_cache_stats: Arc::new(ferrous_dns_application::use_cases::GetCacheStatsUseCase::new(ql_repo())),
             get_top_blocked_domains: Arc::new(ferrous_dns_application::use_cases::GetTopBlockedDomainsUseCase::new(ql_repo())),
             get_top_clients: Arc::new(ferrous_dns_application::use_cases::GetTopClientsUseCase::new(ql_repo())),
+            database_maintenance: Arc::new(ferrous_dns_application::use_cases::DatabaseMaintenanceUseCase::new(Arc::new(ferrous_dns_infrastructure::database::SqliteDatabaseMaintenance::new(pool.clone())))),
+            get_alerts: Arc::new(ferrous_dns_application::use_cases::GetAlertsUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteAlertRepository::new(pool.clone())))),
+            delete_alert: Arc::new(ferrous_dns_application::use_cases::DeleteAlertUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteAlertRepository::new(pool.clone())))),
+            get_audit_log: Arc::new(ferrous_dns_application::use_cases::GetAuditLogUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteAuditLogRepository::new(pool.clone())))),
+            live_metrics: Arc::new(ferrous_dns_infrastructure::dns::QueryMetrics::new()),
         },
         dns: DnsUseCases {
-            cache: cache as Arc<dyn ferrous_dns_application::ports::DnsCachePort>,
-            create_local_record: Arc::new(CreateLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))),
-            update_local_record: Arc::new(UpdateLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))),
-            delete_local_record: Arc::new(DeleteLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))),
-            upstream_health: Arc::new(ferrous_dns_infrastructure::dns::UpstreamHealthAdapter::new(pool_manager, None)),
+            cache: cache.clone() as Arc<dyn ferrous_dns_application::ports::DnsCachePort>,
+            create_local_record: Arc::new(CreateLocalRecordUseCase::new(config.clone(), Arc::new(helpers::NullLocalRecordRepository), Arc::new(helpers::NullLocalZone))),
+            update_local_record: Arc::new(UpdateLocalRecordUseCase::new(config.clone(), Arc::new(helpers::NullLocalRecordRepository), Arc::new(helpers::NullLocalZone))),
+            get_local_records: Arc::new(GetLocalRecordsUseCase::new(Arc::new(helpers::NullLocalRecordRepository))),
+            delete_local_record: Arc::new(DeleteLocalRecordUseCase::new(config.clone(), Arc::new(helpers::NullLocalRecordRepository), Arc::new(helpers::NullLocalZone))),
+            upstream_health: Arc::new(ferrous_dns_infrastructure::dns::UpstreamHealthAdapter::new(pool_manager.clone(), None)),
+            access_control: Arc::new(ferrous_dns_infrastructure::dns::AccessControlRegistry::new()),
+            sinkhole_telemetry: Arc::new(ferrous_dns_infrastructure::dns::SinkholeTelemetry::new()),
+            conditional_forwards: Arc::new(
+                ferrous_dns_infrastructure::dns::resolver::ConditionalForwards::new(&[]),
+            ),
+            slow_query_log: Arc::new(ferrous_dns_infrastructure::dns::SlowQueryLog::new(100)),
+            inflight: Arc::new(ferrous_dns_infrastructure::dns::InflightRegistry::new(4)),
+            diagnose_domain: Arc::new(ferrous_dns_application::use_cases::DiagnoseDomainUseCase::new(
+                Arc::new(NullBlockFilterEngine),
+                cache.clone(),
+                Arc::new(ferrous_dns_infrastructure::dns::UpstreamHealthAdapter::new(pool_manager.clone(), None)),
+            )),
+            trace_resolve: Arc::new(ferrous_dns_application::use_cases::TraceResolveUseCase::new(
+                Arc::new(ferrous_dns_application::use_cases::DiagnoseDomainUseCase::new(
+                    Arc::new(NullBlockFilterEngine),
+                    cache,
+                    Arc::new(ferrous_dns_infrastructure::dns::UpstreamHealthAdapter::new(pool_manager, None)),
+                )),
+                Arc::new(helpers::FixedDnsResolver),
+            )),
+            get_dnssec_status: helpers::build_test_dnssec_status(pool.clone()),
+            get_dnssec_stats: helpers::build_test_dnssec_stats(pool.clone()),
+            resolve_query: helpers::build_test_resolve_query(pool.clone()),
+            faults: None,
         },
         groups: GroupUseCases {
             get_groups: Arc::new(GetGroupsUseCase::new(group_repo.clone())),
@@ -643,12 +744,20 @@ async fn create_test_app() -> (Router, Arc<RwLock<Config>>, sqlx::SqlitePool) {
             create_manual_client: Arc::new(CreateManualClientUseCase::new(client_repo.clone(), group_repo.clone())),
             update_client: Arc::new(UpdateClientUseCase::new(client_repo.clone())),
             delete_client: Arc::new(DeleteClientUseCase::new(client_repo.clone())),
+            get_client_activity: Arc::new(ferrous_dns_application::use_cases::GetClientActivityUseCase::new(
+                client_repo.clone(),
+                ql_repo(),
+            )),
             subnet_matcher: Arc::new(SubnetMatcherService::new(subnet_repo.clone())),
         },
         blocking: BlockingUseCases {
             get_blocklist: Arc::new(GetBlocklistUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::blocklist_repository::SqliteBlocklistRepository::new(pool.clone()),
             ))),
+            bulk_add_blocklist: Arc::new(ferrous_dns_application::use_cases::BulkAddBlocklistUseCase::new(
+                Arc::new(ferrous_dns_infrastructure::repositories::blocklist_repository::SqliteBlocklistRepository::new(pool.clone())),
+                Arc::new(NullBlockFilterEngine),
+            )),
             get_blocklist_sources: Arc::new(GetBlocklistSourcesUseCase::new(blocklist_source_repo.clone())),
             create_blocklist_source: Arc::new(CreateBlocklistSourceUseCase::new(blocklist_source_repo.clone(), group_repo.clone())),
             update_blocklist_source: Arc::new(UpdateBlocklistSourceUseCase::new(blocklist_source_repo.clone(), group_repo.clone())),
@@ -656,6 +765,10 @@ async fn create_test_app() -> (Router, Arc<RwLock<Config>>, sqlx::SqlitePool) {
             get_whitelist: Arc::new(ferrous_dns_application::use_cases::GetWhitelistUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::whitelist_repository::SqliteWhitelistRepository::new(pool.clone()),
             ))),
+            bulk_add_whitelist: Arc::new(ferrous_dns_application::use_cases::BulkAddWhitelistUseCase::new(
+                Arc::new(ferrous_dns_infrastructure::repositories::whitelist_repository::SqliteWhitelistRepository::new(pool.clone())),
+                Arc::new(NullBlockFilterEngine),
+            )),
             get_whitelist_sources: Arc::new(ferrous_dns_application::use_cases::GetWhitelistSourcesUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::whitelist_source_repository::SqliteWhitelistSourceRepository::new(pool.clone()),
             ))),
@@ -670,6 +783,10 @@ async fn create_test_app() -> (Router, Arc<RwLock<Config>>, sqlx::SqlitePool) {
             delete_whitelist_source: Arc::new(ferrous_dns_application::use_cases::DeleteWhitelistSourceUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::whitelist_source_repository::SqliteWhitelistSourceRepository::new(pool.clone()),
             ))),
+            get_ip_blocklist_sources: Arc::new(ferrous_dns_application::use_cases::GetIpBlocklistSourcesUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteIpBlocklistSourceRepository::new(pool.clone())))),
+            create_ip_blocklist_source: Arc::new(ferrous_dns_application::use_cases::CreateIpBlocklistSourceUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteIpBlocklistSourceRepository::new(pool.clone())))),
+            update_ip_blocklist_source: Arc::new(ferrous_dns_application::use_cases::UpdateIpBlocklistSourceUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteIpBlocklistSourceRepository::new(pool.clone())))),
+            delete_ip_blocklist_source: Arc::new(ferrous_dns_application::use_cases::DeleteIpBlocklistSourceUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteIpBlocklistSourceRepository::new(pool.clone())))),
             get_managed_domains: Arc::new(GetManagedDomainsUseCase::new(managed_domain_repo.clone())),
             create_managed_domain: Arc::new(CreateManagedDomainUseCase::new(managed_domain_repo.clone(), group_repo.clone(), null_engine.clone())),
             update_managed_domain: Arc::new(UpdateManagedDomainUseCase::new(managed_domain_repo.clone(), group_repo.clone(), null_engine.clone())),
@@ -679,6 +796,11 @@ async fn create_test_app() -> (Router, Arc<RwLock<Config>>, sqlx::SqlitePool) {
             update_regex_filter: Arc::new(ferrous_dns_application::use_cases::UpdateRegexFilterUseCase::new(regex_filter_repo.clone(), group_repo.clone(), null_engine.clone())),
             delete_regex_filter: Arc::new(ferrous_dns_application::use_cases::DeleteRegexFilterUseCase::new(regex_filter_repo.clone(), null_engine.clone())),
             get_block_filter_stats: Arc::new(GetBlockFilterStatsUseCase::new(Arc::new(NullBlockFilterEngine))),
+            get_unblock_requests: Arc::new(ferrous_dns_application::use_cases::GetUnblockRequestsUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository::new(pool.clone())))),
+            submit_unblock_request: Arc::new(ferrous_dns_application::use_cases::SubmitUnblockRequestUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository::new(pool.clone())), Arc::new(NullBlockFilterEngine))),
+            approve_unblock_request: Arc::new(ferrous_dns_application::use_cases::ApproveUnblockRequestUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::repositories::managed_domain_repository::SqliteManagedDomainRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::repositories::group_repository::SqliteGroupRepository::new(pool.clone())), Arc::new(NullBlockFilterEngine))),
+            deny_unblock_request: Arc::new(ferrous_dns_application::use_cases::DenyUnblockRequestUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository::new(pool.clone())))),
+            delete_unblock_request: Arc::new(ferrous_dns_application::use_cases::DeleteUnblockRequestUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository::new(pool.clone())))),
         },
         services: ServiceUseCases {
             get_service_catalog: Arc::new(GetServiceCatalogUseCase::new(Arc::new(NullServiceCatalog))),
@@ -703,13 +825,17 @@ async fn create_test_app() -> (Router, Arc<RwLock<Config>>, sqlx::SqlitePool) {
             manage_slots: Arc::new(ManageTimeSlotsUseCase::new(Arc::new(NullScheduleProfileRepository))),
             assign_profile: Arc::new(AssignScheduleProfileUseCase::new(Arc::new(NullScheduleProfileRepository), group_repo.clone())),
         },
+        policies: helpers::build_test_query_policy_use_cases(group_repo.clone()),
         auth: helpers::build_test_auth_use_cases(),
         backup,
+        tenants: helpers::build_test_tenant_use_cases(),
         config: config.clone(),
         config_file_persistence: Arc::new(ferrous_dns_infrastructure::repositories::TomlConfigFilePersistence),
         config_path: None,
         tls_cert: Arc::new(helpers::MockTlsCertificateService),
         tls_enabled: false,
+        log_levels: Arc::new(helpers::MockLogLevelControl::default()),
+        system_metrics: Arc::new(ferrous_dns_infrastructure::system::ProcessMetricsCollector::new()),
     };
 
     let app = create_api_routes(state);
@@ -791,6 +917,20 @@ async fn do_export(app: Router) -> (StatusCode, Value) {
     (status, json)
 }
 
+/// Records from the config file followed by those stored in the database.
+async fn local_records(
+    config: &Arc<RwLock<Config>>,
+    pool: &sqlx::SqlitePool,
+) -> Vec<LocalDnsRecord> {
+    let mut records = config.read().await.dns.local_records.clone();
+    let stored = SqliteLocalRecordRepository::new(pool.clone())
+        .get_all()
+        .await
+        .unwrap();
+    records.extend(stored.iter().map(|r| r.to_config_record()));
+    records
+}
+
 // ── Testes de Export ──────────────────────────────────────────────────────────
 
 #[tokio::test]
@@ -850,6 +990,7 @@ async fn test_export_includes_local_records_from_config() {
             ip: "192.168.1.100".to_string(),
             record_type: "A".to_string(),
             ttl: Some(300),
+            view: None,
         });
         cfg.dns.local_records.push(LocalDnsRecord {
             hostname: "pi".to_string(),
@@ -857,6 +998,7 @@ async fn test_export_includes_local_records_from_config() {
             ip: "192.168.1.5".to_string(),
             record_type: "A".to_string(),
             ttl: Some(60),
+            view: None,
         });
     }
 
@@ -927,8 +1069,8 @@ async fn test_import_valid_backup_returns_success_true() {
 }
 
 #[tokio::test]
-async fn test_import_new_local_record_is_added_to_config() {
-    let (app, config, _pool) = create_test_app().await;
+async fn test_import_new_local_record_is_stored() {
+    let (app, config, pool) = create_test_app().await;
 
     let mut backup = minimal_backup_json();
     backup["data"]["local_records"] = json!([{
@@ -950,12 +1092,31 @@ async fn test_import_new_local_record_is_added_to_config() {
     assert_eq!(json["summary"]["local_records_imported"], 1);
     assert_eq!(json["summary"]["local_records_skipped"], 0);
 
-    let cfg = config.read().await;
-    assert!(cfg
-        .dns
-        .local_records
-        .iter()
-        .any(|r| r.hostname == "printer"));
+    let records = local_records(&config, &pool).await;
+    assert!(records.iter().any(|r| r.hostname == "printer"));
+}
+
+#[tokio::test]
+async fn test_export_includes_local_records_from_database() {
+    let (app, _config, pool) = create_test_app().await;
+
+    let mut record = ferrous_dns_domain::LocalRecord::new(
+        "nas".into(),
+        ferrous_dns_domain::RecordType::A,
+        "10.0.0.20".into(),
+    );
+    record.domain = Some("lan".into());
+    SqliteLocalRecordRepository::new(pool.clone())
+        .create(&record)
+        .await
+        .unwrap();
+
+    let (_, json) = do_export(app).await;
+    let records = json["data"]["local_records"].as_array().unwrap();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0]["hostname"], "nas");
+    assert_eq!(records[0]["domain"], "lan");
+    assert_eq!(records[0]["ip"], "10.0.0.20");
 }
 
 #[tokio::test]
@@ -970,6 +1131,7 @@ async fn test_import_existing_local_record_is_skipped() {
             ip: "10.0.0.1".to_string(),
             record_type: "A".to_string(),
             ttl: Some(300),
+            view: None,
         });
     }
 
@@ -1016,9 +1178,12 @@ async fn test_import_is_idempotent() {
                 as Arc<dyn ferrous_dns_application::ports::BlocklistSourceRepository>,
             group_repo.clone() as Arc<dyn ferrous_dns_application::ports::GroupRepository>,
         ));
-    let local_record_creator: Arc<dyn LocalRecordCreator> = Arc::new(
-        CreateLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository)),
-    );
+    let local_record_creator: Arc<dyn LocalRecordCreator> =
+        Arc::new(CreateLocalRecordUseCase::new(
+            config.clone(),
+            Arc::new(SqliteLocalRecordRepository::new(pool.clone())),
+            Arc::new(helpers::NullLocalZone),
+        ));
 
     let import_uc = Arc::new(ImportConfigUseCase::new(
         config.clone(),
@@ -1161,7 +1326,7 @@ async fn test_import_multiple_records_counted_correctly() {
 
 #[tokio::test]
 async fn test_import_partial_overlap_counts_each_correctly() {
-    let (app, config, _pool) = create_test_app().await;
+    let (app, config, pool) = create_test_app().await;
 
     // Dois records já existem no config
     {
@@ -1172,6 +1337,7 @@ async fn test_import_partial_overlap_counts_each_correctly() {
             ip: "10.0.0.1".to_string(),
             record_type: "A".to_string(),
             ttl: Some(300),
+            view: None,
         });
         cfg.dns.local_records.push(LocalDnsRecord {
             hostname: "existing-b".to_string(),
@@ -1179,6 +1345,7 @@ async fn test_import_partial_overlap_counts_each_correctly() {
             ip: "10.0.0.2".to_string(),
             record_type: "A".to_string(),
             ttl: Some(300),
+            view: None,
         });
     }
 
@@ -1201,13 +1368,13 @@ async fn test_import_partial_overlap_counts_each_correctly() {
     assert_eq!(json["summary"]["local_records_imported"], 3);
     assert_eq!(json["summary"]["local_records_skipped"], 2);
 
-    let cfg = config.read().await;
-    assert_eq!(cfg.dns.local_records.len(), 5);
+    let records = local_records(&config, &pool).await;
+    assert_eq!(records.len(), 5);
 }
 
 #[tokio::test]
 async fn test_import_ipv6_aaaa_records() {
-    let (app, config, _pool) = create_test_app().await;
+    let (app, config, pool) = create_test_app().await;
 
     let mut backup = minimal_backup_json();
     backup["data"]["local_records"] = json!([
@@ -1223,17 +1390,15 @@ async fn test_import_ipv6_aaaa_records() {
     assert_eq!(json["success"], true);
     assert_eq!(json["summary"]["local_records_imported"], 2);
 
-    let cfg = config.read().await;
-    assert!(cfg
-        .dns
-        .local_records
+    let records = local_records(&config, &pool).await;
+    assert!(records
         .iter()
         .any(|r| r.hostname == "v6host" && r.record_type == "AAAA"));
 }
 
 #[tokio::test]
 async fn test_import_record_without_domain_is_distinct_from_record_with_domain() {
-    let (app, config, _pool) = create_test_app().await;
+    let (app, config, pool) = create_test_app().await;
 
     // Adiciona "server" sem domain
     {
@@ -1244,6 +1409,7 @@ async fn test_import_record_without_domain_is_distinct_from_record_with_domain()
             ip: "10.0.0.1".to_string(),
             record_type: "A".to_string(),
             ttl: Some(300),
+            view: None,
         });
     }
 
@@ -1262,13 +1428,13 @@ async fn test_import_record_without_domain_is_distinct_from_record_with_domain()
     assert_eq!(json["summary"]["local_records_imported"], 1);
     assert_eq!(json["summary"]["local_records_skipped"], 0);
 
-    let cfg = config.read().await;
-    assert_eq!(cfg.dns.local_records.len(), 2);
+    let records = local_records(&config, &pool).await;
+    assert_eq!(records.len(), 2);
 }
 
 #[tokio::test]
 async fn test_import_same_hostname_different_domain_are_distinct_records() {
-    let (app, config, _pool) = create_test_app().await;
+    let (app, config, pool) = create_test_app().await;
 
     let mut backup = minimal_backup_json();
     backup["data"]["local_records"] = json!([
@@ -1285,20 +1451,13 @@ async fn test_import_same_hostname_different_domain_are_distinct_records() {
     assert_eq!(json["success"], true);
     assert_eq!(json["summary"]["local_records_imported"], 3);
 
-    let cfg = config.read().await;
-    assert_eq!(
-        cfg.dns
-            .local_records
-            .iter()
-            .filter(|r| r.hostname == "pi")
-            .count(),
-        3
-    );
+    let records = local_records(&config, &pool).await;
+    assert_eq!(records.iter().filter(|r| r.hostname == "pi").count(), 3);
 }
 
 #[tokio::test]
 async fn test_sequential_imports_accumulate_distinct_records() {
-    let (app, config, _pool) = create_test_app().await;
+    let (app, config, pool) = create_test_app().await;
 
     // Primeiro import: 3 records
     let mut backup1 = minimal_backup_json();
@@ -1335,13 +1494,13 @@ async fn test_sequential_imports_accumulate_distinct_records() {
     assert_eq!(b2["summary"]["local_records_imported"], 2);
     assert_eq!(b2["summary"]["local_records_skipped"], 1);
 
-    let cfg = config.read().await;
-    assert_eq!(cfg.dns.local_records.len(), 5);
+    let records = local_records(&config, &pool).await;
+    assert_eq!(records.len(), 5);
 }
 
 #[tokio::test]
 async fn test_import_large_batch_of_records() {
-    let (app, config, _pool) = create_test_app().await;
+    let (app, config, pool) = create_test_app().await;
 
     let records: Vec<Value> = (1..=20)
         .map(|i| {
@@ -1367,13 +1526,13 @@ async fn test_import_large_batch_of_records() {
     assert_eq!(json["summary"]["local_records_imported"], 20);
     assert_eq!(json["summary"]["local_records_skipped"], 0);
 
-    let cfg = config.read().await;
-    assert_eq!(cfg.dns.local_records.len(), 20);
+    let records = local_records(&config, &pool).await;
+    assert_eq!(records.len(), 20);
 }
 
 #[tokio::test]
 async fn test_import_large_batch_second_run_skips_all() {
-    let (app, config, _pool) = create_test_app().await;
+    let (app, config, pool) = create_test_app().await;
 
     let records: Vec<Value> = (1..=10)
         .map(|i| {
@@ -1394,7 +1553,7 @@ async fn test_import_large_batch_second_run_skips_all() {
     // Primeiro import
     app.clone().oneshot(import_request(&payload)).await.unwrap();
 
-    assert_eq!(config.read().await.dns.local_records.len(), 10);
+    assert_eq!(local_records(&config, &pool).await.len(), 10);
 
     // Segundo import — tudo já existe
     let response = app.oneshot(import_request(&payload)).await.unwrap();
@@ -1406,13 +1565,13 @@ async fn test_import_large_batch_second_run_skips_all() {
     assert_eq!(json["summary"]["local_records_skipped"], 10);
 
     // Config não cresceu
-    let cfg = config.read().await;
-    assert_eq!(cfg.dns.local_records.len(), 10);
+    let records = local_records(&config, &pool).await;
+    assert_eq!(records.len(), 10);
 }
 
 #[tokio::test]
 async fn test_export_then_import_is_a_complete_round_trip() {
-    let (app, config, _pool) = create_test_app().await;
+    let (app, config, pool) = create_test_app().await;
 
     // Popula config com records e verifica que o export captura tudo
     {
@@ -1424,6 +1583,7 @@ async fn test_export_then_import_is_a_complete_round_trip() {
                 ip: format!("192.168.10.{i}"),
                 record_type: "A".to_string(),
                 ttl: Some(120),
+                view: None,
             });
         }
     }
@@ -1471,13 +1631,13 @@ async fn test_export_then_import_is_a_complete_round_trip() {
     assert_eq!(import_json["summary"]["local_records_skipped"], 5);
 
     // Config não duplicou
-    let cfg = config.read().await;
-    assert_eq!(cfg.dns.local_records.len(), 5);
+    let records = local_records(&config, &pool).await;
+    assert_eq!(records.len(), 5);
 }
 
 #[tokio::test]
 async fn test_import_mixed_ipv4_and_ipv6_records() {
-    let (app, config, _pool) = create_test_app().await;
+    let (app, config, pool) = create_test_app().await;
 
     let mut backup = minimal_backup_json();
     // Each hostname is unique — the duplicate key is hostname+domain, not record_type,
@@ -1498,20 +1658,344 @@ async fn test_import_mixed_ipv4_and_ipv6_records() {
     assert_eq!(json["summary"]["local_records_imported"], 4);
     assert_eq!(json["summary"]["local_records_skipped"], 0);
 
-    let cfg = config.read().await;
-    assert_eq!(cfg.dns.local_records.len(), 4);
-    let a_count = cfg
-        .dns
-        .local_records
-        .iter()
-        .filter(|r| r.record_type == "A")
-        .count();
-    let aaaa_count = cfg
-        .dns
-        .local_records
-        .iter()
-        .filter(|r| r.record_type == "AAAA")
-        .count();
+    let records = local_records(&config, &pool).await;
+    assert_eq!(records.len(), 4);
+    let a_count = records.iter().filter(|r| r.record_type == "A").count();
+    let aaaa_count = records.iter().filter(|r| r.record_type == "AAAA").count();
     assert_eq!(a_count, 2);
     assert_eq!(aaaa_count, 2);
 }
+
+// ── Full backup / restore ─────────────────────────────────────────────────────
+
+fn restore_request(bytes: &[u8]) -> Request<Body> {
+    upload_request("/restore", bytes)
+}
+
+fn upload_request(uri: &str, bytes: &[u8]) -> Request<Body> {
+    let boundary = "testboundary";
+    let body = build_multipart_body(boundary, bytes);
+    Request::builder()
+        .uri(uri)
+        .method("POST")
+        .header(
+            "content-type",
+            format!("multipart/form-data; boundary={boundary}"),
+        )
+        .body(Body::from(body))
+        .unwrap()
+}
+
+async fn do_full_backup(app: Router, query: &str) -> (StatusCode, String, Vec<u8>) {
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri(format!("/backup{query}"))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = response.status();
+    let content_type = response
+        .headers()
+        .get("content-type")
+        .map(|v| v.to_str().unwrap().to_string())
+        .unwrap_or_default();
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    (status, content_type, body.to_vec())
+}
+
+async fn group_names(pool: &sqlx::SqlitePool) -> Vec<String> {
+    sqlx::query_scalar("SELECT name FROM groups ORDER BY id")
+        .fetch_all(pool)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_full_backup_json_includes_tables_and_config() {
+    let (app, config, pool) = create_test_app().await;
+    config.write().await.auth.admin.password_hash = Some("$argon2id$secret".to_string());
+    sqlx::query("INSERT INTO clients (ip_address, hostname) VALUES ('192.168.1.20', 'laptop')")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let (status, content_type, body) = do_full_backup(app, "").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "application/json");
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["version"], "1");
+    assert_eq!(json["tables"]["groups"][0]["name"], "Protected");
+    assert_eq!(json["tables"]["clients"][0]["ip_address"], "192.168.1.20");
+    assert!(json["config"]["auth"]["admin"]["password_hash"].is_null());
+    assert!(!String::from_utf8_lossy(&body).contains("$argon2id$secret"));
+}
+
+#[tokio::test]
+async fn test_full_backup_excludes_query_log() {
+    let (app, _config, _pool) = create_test_app().await;
+    let (_, _, body) = do_full_backup(app, "").await;
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert!(json["tables"].get("query_log").is_none());
+}
+
+#[tokio::test]
+async fn test_full_backup_tar_gz_is_gzip() {
+    let (app, _config, _pool) = create_test_app().await;
+
+    let (status, content_type, body) = do_full_backup(app, "?format=tar.gz").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "application/gzip");
+    assert_eq!(&body[..2], &[0x1f, 0x8b]);
+}
+
+#[tokio::test]
+async fn test_full_backup_unknown_format_returns_bad_request() {
+    let (app, _config, _pool) = create_test_app().await;
+    let (status, _, _) = do_full_backup(app, "?format=zip").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_restore_json_replaces_tables() {
+    let (app, _config, pool) = create_test_app().await;
+    sqlx::query("INSERT INTO groups (id, name) VALUES (2, 'Kids')")
+        .execute(&pool)
+        .await
+        .unwrap();
+    let (_, _, backup) = do_full_backup(app.clone(), "").await;
+
+    sqlx::query("DELETE FROM groups WHERE id = 2")
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("INSERT INTO groups (id, name) VALUES (3, 'Guests')")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let response = app.oneshot(restore_request(&backup)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(json["success"], true);
+    assert_eq!(json["tables"]["groups"], 2);
+    assert_eq!(group_names(&pool).await, vec!["Protected", "Kids"]);
+}
+
+#[tokio::test]
+async fn test_restore_tar_gz_round_trip() {
+    let (app, _config, pool) = create_test_app().await;
+    sqlx::query("INSERT INTO blocklist (domain) VALUES ('ads.example.com')")
+        .execute(&pool)
+        .await
+        .unwrap();
+    let (_, _, backup) = do_full_backup(app.clone(), "?format=tar.gz").await;
+
+    sqlx::query("DELETE FROM blocklist")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let response = app.oneshot(restore_request(&backup)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let domains: Vec<String> = sqlx::query_scalar("SELECT domain FROM blocklist")
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+    assert_eq!(domains, vec!["ads.example.com"]);
+}
+
+#[tokio::test]
+async fn test_restore_keeps_local_password_hash() {
+    let (app, config, _pool) = create_test_app().await;
+    config.write().await.auth.admin.password_hash = Some("$argon2id$local".to_string());
+    let (_, _, backup) = do_full_backup(app.clone(), "").await;
+
+    let response = app.oneshot(restore_request(&backup)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    assert_eq!(
+        config.read().await.auth.admin.password_hash.as_deref(),
+        Some("$argon2id$local")
+    );
+}
+
+#[tokio::test]
+async fn test_restore_wrong_version_returns_bad_request() {
+    let (app, _config, pool) = create_test_app().await;
+    let (_, _, backup) = do_full_backup(app.clone(), "").await;
+    let mut json: Value = serde_json::from_slice(&backup).unwrap();
+    json["version"] = json!("99");
+    json["tables"]["groups"] = json!([]);
+
+    let response = app
+        .oneshot(restore_request(&serde_json::to_vec(&json).unwrap()))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(group_names(&pool).await, vec!["Protected"]);
+}
+
+#[tokio::test]
+async fn test_restore_is_all_or_nothing() {
+    let (app, _config, pool) = create_test_app().await;
+    let (_, _, backup) = do_full_backup(app.clone(), "").await;
+    let mut json: Value = serde_json::from_slice(&backup).unwrap();
+    json["tables"]["groups"] = json!([{ "id": 5, "name": "Restored" }]);
+    json["tables"]["clients"] = json!([{ "id": 1, "ip_address": "10.0.0.9", "group_id": 42 }]);
+
+    let response = app
+        .oneshot(restore_request(&serde_json::to_vec(&json).unwrap()))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(group_names(&pool).await, vec!["Protected"]);
+}
+
+#[tokio::test]
+async fn test_restore_config_export_file_is_rejected() {
+    let (app, _config, _pool) = create_test_app().await;
+    let payload = serde_json::to_vec(&minimal_backup_json()).unwrap();
+
+    let response = app.oneshot(restore_request(&payload)).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+// ── External import ──────────────────────────────────────────────────────────
+
+const ADGUARD_YAML: &str = r#"
+filters:
+  - enabled: true
+    url: https://example.org/filter.txt
+    name: Example filter
+user_rules:
+  - '||ads.example.com^'
+  - '@@||good.example.com^'
+filtering:
+  rewrites:
+    - domain: nas.lan
+      answer: 192.168.1.10
+"#;
+
+async fn do_external_import(app: Router, query: &str, bytes: &[u8]) -> (StatusCode, Value) {
+    let response = app
+        .oneshot(upload_request(&format!("/import{query}"), bytes))
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+}
+
+#[tokio::test]
+async fn test_external_import_adguard_creates_entries() {
+    let (app, config, pool) = create_test_app().await;
+
+    let (status, json) = do_external_import(app, "?format=adguard", ADGUARD_YAML.as_bytes()).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["format"], "adguard");
+    assert_eq!(json["summary"]["lists_imported"], 1);
+    assert_eq!(json["summary"]["domains_imported"], 4);
+    assert_eq!(json["summary"]["local_records_imported"], 1);
+    let urls: Vec<String> = sqlx::query_scalar("SELECT url FROM blocklist_sources")
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+    assert!(urls.contains(&"https://example.org/filter.txt".to_string()));
+    assert_eq!(local_records(&config, &pool).await[0].hostname, "nas");
+}
+
+#[tokio::test]
+async fn test_external_import_second_run_skips_everything() {
+    let (app, _config, _pool) = create_test_app().await;
+    do_external_import(app.clone(), "?format=adguard", ADGUARD_YAML.as_bytes()).await;
+
+    let (status, json) = do_external_import(app, "?format=adguard", ADGUARD_YAML.as_bytes()).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["summary"]["lists_imported"], 0);
+    assert_eq!(json["summary"]["domains_skipped"], 4);
+    assert_eq!(json["summary"]["local_records_skipped"], 1);
+}
+
+#[tokio::test]
+async fn test_external_import_requires_known_format() {
+    let (app, _config, _pool) = create_test_app().await;
+
+    let (missing, _) = do_external_import(app.clone(), "", ADGUARD_YAML.as_bytes()).await;
+    let (unknown, _) = do_external_import(app, "?format=opnsense", ADGUARD_YAML.as_bytes()).await;
+
+    assert_eq!(missing, StatusCode::BAD_REQUEST);
+    assert_eq!(unknown, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_external_import_rejects_non_teleporter_file() {
+    let (app, _config, _pool) = create_test_app().await;
+
+    let (status, _) = do_external_import(app, "?format=pihole", b"plain text").await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+// ── Runtime log level ────────────────────────────────────────────────────────
+
+async fn put_log_level(app: Router, body: Value) -> (StatusCode, Value) {
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("PUT")
+                .uri("/config/log-level")
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::to_vec(&body).unwrap()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+async fn test_update_log_level_applies_module_overrides() {
+    let (app, config, _pool) = create_test_app().await;
+
+    let (status, json) = put_log_level(
+        app,
+        json!({ "level": "WARN", "modules": { "dns::cache": "debug" } }),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["level"], "warn");
+    assert_eq!(json["modules"]["dns::cache"], "debug");
+
+    let config = config.read().await;
+    assert_eq!(config.logging.level, "warn");
+    assert_eq!(config.logging.modules["dns::cache"], "debug");
+}
+
+#[tokio::test]
+async fn test_update_log_level_rejects_unknown_level() {
+    let (app, config, _pool) = create_test_app().await;
+    let before = config.read().await.logging.level.clone();
+
+    let (status, _) = put_log_level(app, json!({ "modules": { "dns::cache": "chatty" } })).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let config = config.read().await;
+    assert_eq!(config.logging.level, before);
+    assert!(config.logging.modules.is_empty());
+}
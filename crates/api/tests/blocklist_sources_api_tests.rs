@@ -9,7 +9,7 @@ use ferrous_dns_api::{
 };
 use ferrous_dns_application::{
     ports::{
-        BlockFilterEnginePort, BlockedServiceRepository, ConfigRepository, FilterDecision,
+        BlockFilterEnginePort, BlockedServiceRepository, FilterDecision,
         SafeSearchConfigRepository, SafeSearchEnginePort, ServiceCatalogPort,
     },
     services::SubnetMatcherService,
@@ -27,6 +27,9 @@ impl BlockFilterEnginePort for NullBlockFilterEngine {
     fn resolve_group(&self, _ip: std::net::IpAddr) -> i64 {
         1
     }
+    fn resolve_group_with_default(&self, _ip: std::net::IpAddr, _default_group_id: i64) -> i64 {
+        1
+    }
     fn check(&self, _domain: &str, _group_id: i64) -> FilterDecision {
         FilterDecision::Allow
     }
@@ -135,17 +138,6 @@ impl ServiceCatalogPort for NullServiceCatalog {
     }
     fn reload_custom(&self, _custom: Vec<ferrous_dns_domain::ServiceDefinition>) {}
 }
-struct NullConfigRepository;
-#[async_trait::async_trait]
-impl ConfigRepository for NullConfigRepository {
-    async fn save_local_records(
-        &self,
-        _config: &Config,
-    ) -> Result<(), ferrous_dns_domain::DomainError> {
-        Ok(())
-    }
-}
-
 struct NullSafeSearchConfigRepository;
 #[async_trait::async_trait]
 impl SafeSearchConfigRepository for NullSafeSearchConfigRepository {
@@ -299,6 +291,9 @@ async fn create_test_db() -> sqlx::SqlitePool {
             enabled BOOLEAN NOT NULL DEFAULT 1,
             comment TEXT,
             is_default BOOLEAN NOT NULL DEFAULT 0,
+            filter_aaaa BOOLEAN NOT NULL DEFAULT 0,
+            audit_mode  BOOLEAN NOT NULL DEFAULT 0,
+            tenant_id INTEGER,
             created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
             updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
         )
@@ -329,7 +324,10 @@ async fn create_test_db() -> sqlx::SqlitePool {
             last_hostname_update DATETIME,
             group_id INTEGER NOT NULL DEFAULT 1 REFERENCES groups(id) ON DELETE RESTRICT,
             created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
-            updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+            updated_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            display_name TEXT,
+            category TEXT,
+            notes TEXT
         )
         "#,
     )
@@ -363,6 +361,8 @@ async fn create_test_db() -> sqlx::SqlitePool {
             group_id    INTEGER NOT NULL DEFAULT 1 REFERENCES groups(id) ON DELETE RESTRICT,
             comment     TEXT,
             enabled     BOOLEAN NOT NULL DEFAULT 1,
+            audit_mode  BOOLEAN NOT NULL DEFAULT 0,
+            tenant_id   INTEGER,
             created_at  DATETIME DEFAULT CURRENT_TIMESTAMP,
             updated_at  DATETIME DEFAULT CURRENT_TIMESTAMP
         )
@@ -404,6 +404,19 @@ async fn create_test_db() -> sqlx::SqlitePool {
     .await
     .unwrap();
 
+    sqlx::query(
+        r#"
+        CREATE TABLE blocklist (
+            id       INTEGER PRIMARY KEY AUTOINCREMENT,
+            domain   TEXT NOT NULL UNIQUE,
+            added_at DATETIME DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
     pool
 }
 
@@ -437,10 +450,11 @@ async fn create_test_app() -> (Router, sqlx::SqlitePool) {
             refresh_sample_rate: 1.0,
             min_ttl: 0,
             max_ttl: 86_400,
+            l1_capacity: 1024,
         },
     ));
 
-    use ferrous_dns_domain::config::upstream::{UpstreamPool, UpstreamStrategy};
+    use ferrous_dns_domain::config::upstream::{UdpSocketMode, UpstreamPool, UpstreamStrategy};
     use ferrous_dns_infrastructure::dns::{PoolManager, QueryEventEmitter};
 
     let event_emitter = QueryEventEmitter::new_disabled();
@@ -450,6 +464,8 @@ async fn create_test_app() -> (Router, sqlx::SqlitePool) {
         priority: 1,
         servers: vec!["8.8.8.8:53".to_string()],
         weight: None,
+        tsig_key: None,
+        udp_socket: UdpSocketMode::Pooled,
     };
 
     let pool_manager = Arc::new(
@@ -481,16 +497,46 @@ async fn create_test_app() -> (Router, sqlx::SqlitePool) {
             get_top_clients: Arc::new(ferrous_dns_application::use_cases::GetTopClientsUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &DatabaseConfig::default()),
             ))),
+            database_maintenance: Arc::new(ferrous_dns_application::use_cases::DatabaseMaintenanceUseCase::new(Arc::new(ferrous_dns_infrastructure::database::SqliteDatabaseMaintenance::new(pool.clone())))),
+            get_alerts: Arc::new(ferrous_dns_application::use_cases::GetAlertsUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteAlertRepository::new(pool.clone())))),
+            delete_alert: Arc::new(ferrous_dns_application::use_cases::DeleteAlertUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteAlertRepository::new(pool.clone())))),
+            get_audit_log: Arc::new(ferrous_dns_application::use_cases::GetAuditLogUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteAuditLogRepository::new(pool.clone())))),
+            live_metrics: Arc::new(ferrous_dns_infrastructure::dns::QueryMetrics::new()),
         },
         dns: DnsUseCases {
-            cache: cache as Arc<dyn ferrous_dns_application::ports::DnsCachePort>,
-            create_local_record: Arc::new(CreateLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))),
-            update_local_record: Arc::new(UpdateLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))),
-            delete_local_record: Arc::new(DeleteLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))),
+            cache: cache.clone() as Arc<dyn ferrous_dns_application::ports::DnsCachePort>,
+            create_local_record: Arc::new(CreateLocalRecordUseCase::new(config.clone(), Arc::new(helpers::NullLocalRecordRepository), Arc::new(helpers::NullLocalZone))),
+            update_local_record: Arc::new(UpdateLocalRecordUseCase::new(config.clone(), Arc::new(helpers::NullLocalRecordRepository), Arc::new(helpers::NullLocalZone))),
+            get_local_records: Arc::new(GetLocalRecordsUseCase::new(Arc::new(helpers::NullLocalRecordRepository))),
+            delete_local_record: Arc::new(DeleteLocalRecordUseCase::new(config.clone(), Arc::new(helpers::NullLocalRecordRepository), Arc::new(helpers::NullLocalZone))),
             upstream_health: Arc::new(ferrous_dns_infrastructure::dns::UpstreamHealthAdapter::new(
-                pool_manager,
+                pool_manager.clone(),
                 None,
             )),
+            access_control: Arc::new(ferrous_dns_infrastructure::dns::AccessControlRegistry::new()),
+            sinkhole_telemetry: Arc::new(ferrous_dns_infrastructure::dns::SinkholeTelemetry::new()),
+            conditional_forwards: Arc::new(
+                ferrous_dns_infrastructure::dns::resolver::ConditionalForwards::new(&[]),
+            ),
+            slow_query_log: Arc::new(ferrous_dns_infrastructure::dns::SlowQueryLog::new(100)),
+            inflight: Arc::new(ferrous_dns_infrastructure::dns::InflightRegistry::new(4)),
+            diagnose_domain: Arc::new(ferrous_dns_application::use_cases::DiagnoseDomainUseCase::new(
+                Arc::new(NullBlockFilterEngine),
+                cache.clone(),
+                Arc::new(ferrous_dns_infrastructure::dns::UpstreamHealthAdapter::new(pool_manager.clone(), None)),
+            )),
+            trace_resolve: Arc::new(ferrous_dns_application::use_cases::TraceResolveUseCase::new(
+                Arc::new(ferrous_dns_application::use_cases::DiagnoseDomainUseCase::new(
+                    Arc::new(NullBlockFilterEngine),
+                    cache,
+                    Arc::new(ferrous_dns_infrastructure::dns::UpstreamHealthAdapter::new(pool_manager, None)),
+                )),
+                Arc::new(helpers::FixedDnsResolver),
+            )),
+            get_dnssec_status: helpers::build_test_dnssec_status(pool.clone()),
+            get_dnssec_stats: helpers::build_test_dnssec_stats(pool.clone()),
+            resolve_query: helpers::build_test_resolve_query(pool.clone()),
+            faults: None,
         },
         groups: GroupUseCases {
             get_groups: Arc::new(GetGroupsUseCase::new(group_repo.clone())),
@@ -507,12 +553,20 @@ async fn create_test_app() -> (Router, sqlx::SqlitePool) {
             create_manual_client: Arc::new(CreateManualClientUseCase::new(client_repo.clone(), group_repo.clone())),
             update_client: Arc::new(UpdateClientUseCase::new(client_repo.clone())),
             delete_client: Arc::new(DeleteClientUseCase::new(client_repo.clone())),
+            get_client_activity: Arc::new(ferrous_dns_application::use_cases::GetClientActivityUseCase::new(
+                client_repo.clone(),
+                Arc::new(ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &DatabaseConfig::default())),
+            )),
             subnet_matcher: Arc::new(SubnetMatcherService::new(subnet_repo.clone())),
         },
         blocking: BlockingUseCases {
             get_blocklist: Arc::new(GetBlocklistUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::blocklist_repository::SqliteBlocklistRepository::new(pool.clone()),
             ))),
+            bulk_add_blocklist: Arc::new(ferrous_dns_application::use_cases::BulkAddBlocklistUseCase::new(
+                Arc::new(ferrous_dns_infrastructure::repositories::blocklist_repository::SqliteBlocklistRepository::new(pool.clone())),
+                Arc::new(NullBlockFilterEngine),
+            )),
             get_blocklist_sources: Arc::new(GetBlocklistSourcesUseCase::new(blocklist_source_repo.clone())),
             create_blocklist_source: Arc::new(CreateBlocklistSourceUseCase::new(
                 blocklist_source_repo.clone(),
@@ -528,6 +582,10 @@ async fn create_test_app() -> (Router, sqlx::SqlitePool) {
             get_whitelist: Arc::new(ferrous_dns_application::use_cases::GetWhitelistUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::whitelist_repository::SqliteWhitelistRepository::new(pool.clone()),
             ))),
+            bulk_add_whitelist: Arc::new(ferrous_dns_application::use_cases::BulkAddWhitelistUseCase::new(
+                Arc::new(ferrous_dns_infrastructure::repositories::whitelist_repository::SqliteWhitelistRepository::new(pool.clone())),
+                Arc::new(NullBlockFilterEngine),
+            )),
             get_whitelist_sources: Arc::new(ferrous_dns_application::use_cases::GetWhitelistSourcesUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::whitelist_source_repository::SqliteWhitelistSourceRepository::new(pool.clone()),
             ))),
@@ -542,6 +600,10 @@ async fn create_test_app() -> (Router, sqlx::SqlitePool) {
             delete_whitelist_source: Arc::new(ferrous_dns_application::use_cases::DeleteWhitelistSourceUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::whitelist_source_repository::SqliteWhitelistSourceRepository::new(pool.clone()),
             ))),
+            get_ip_blocklist_sources: Arc::new(ferrous_dns_application::use_cases::GetIpBlocklistSourcesUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteIpBlocklistSourceRepository::new(pool.clone())))),
+            create_ip_blocklist_source: Arc::new(ferrous_dns_application::use_cases::CreateIpBlocklistSourceUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteIpBlocklistSourceRepository::new(pool.clone())))),
+            update_ip_blocklist_source: Arc::new(ferrous_dns_application::use_cases::UpdateIpBlocklistSourceUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteIpBlocklistSourceRepository::new(pool.clone())))),
+            delete_ip_blocklist_source: Arc::new(ferrous_dns_application::use_cases::DeleteIpBlocklistSourceUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteIpBlocklistSourceRepository::new(pool.clone())))),
             get_managed_domains: Arc::new(ferrous_dns_application::use_cases::GetManagedDomainsUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::managed_domain_repository::SqliteManagedDomainRepository::new(pool.clone()),
             ))),
@@ -577,6 +639,11 @@ async fn create_test_app() -> (Router, sqlx::SqlitePool) {
                 Arc::new(NullBlockFilterEngine),
             )),
             get_block_filter_stats: Arc::new(GetBlockFilterStatsUseCase::new(Arc::new(NullBlockFilterEngine))),
+            get_unblock_requests: Arc::new(ferrous_dns_application::use_cases::GetUnblockRequestsUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository::new(pool.clone())))),
+            submit_unblock_request: Arc::new(ferrous_dns_application::use_cases::SubmitUnblockRequestUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository::new(pool.clone())), Arc::new(NullBlockFilterEngine))),
+            approve_unblock_request: Arc::new(ferrous_dns_application::use_cases::ApproveUnblockRequestUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::repositories::managed_domain_repository::SqliteManagedDomainRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::repositories::group_repository::SqliteGroupRepository::new(pool.clone())), Arc::new(NullBlockFilterEngine))),
+            deny_unblock_request: Arc::new(ferrous_dns_application::use_cases::DenyUnblockRequestUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository::new(pool.clone())))),
+            delete_unblock_request: Arc::new(ferrous_dns_application::use_cases::DeleteUnblockRequestUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository::new(pool.clone())))),
         },
         services: ServiceUseCases {
             get_service_catalog: Arc::new(GetServiceCatalogUseCase::new(Arc::new(NullServiceCatalog))),
@@ -622,13 +689,17 @@ async fn create_test_app() -> (Router, sqlx::SqlitePool) {
             manage_slots: Arc::new(ManageTimeSlotsUseCase::new(Arc::new(NullScheduleProfileRepository))),
             assign_profile: Arc::new(AssignScheduleProfileUseCase::new(Arc::new(NullScheduleProfileRepository), group_repo.clone())),
         },
+        policies: helpers::build_test_query_policy_use_cases(group_repo.clone()),
         auth: helpers::build_test_auth_use_cases(),
         backup: helpers::build_test_backup_use_cases(config.clone()),
+        tenants: helpers::build_test_tenant_use_cases(),
         config: config.clone(),
         config_file_persistence: Arc::new(ferrous_dns_infrastructure::repositories::TomlConfigFilePersistence),
         config_path: None,
         tls_cert: Arc::new(helpers::MockTlsCertificateService),
         tls_enabled: false,
+        log_levels: Arc::new(helpers::MockLogLevelControl::default()),
+        system_metrics: Arc::new(ferrous_dns_infrastructure::system::ProcessMetricsCollector::new()),
     };
 
     let app = create_api_routes(state);
@@ -654,8 +725,8 @@ async fn test_get_all_sources_empty() {
     let body = response.into_body().collect().await.unwrap().to_bytes();
     let json: Value = serde_json::from_slice(&body).unwrap();
 
-    assert!(json.is_array());
-    assert_eq!(json.as_array().unwrap().len(), 0);
+    assert!(json["data"].is_array());
+    assert_eq!(json["data"].as_array().unwrap().len(), 0);
 }
 
 #[tokio::test]
@@ -1037,6 +1108,98 @@ async fn test_get_all_sources_after_create() {
     let body = response.into_body().collect().await.unwrap().to_bytes();
     let json: Value = serde_json::from_slice(&body).unwrap();
 
-    assert!(json.is_array());
-    assert_eq!(json.as_array().unwrap().len(), 2);
+    assert!(json["data"].is_array());
+    assert_eq!(json["data"].as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_bulk_add_blocklist_json_array() {
+    let (app, _pool) = create_test_app().await;
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/blocklist/bulk")
+                .method("POST")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!(["Ads.Example.com.", "tracker.example.com", "ads.example.com"])
+                        .to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["received"], 3);
+    assert_eq!(json["added"], 2);
+    assert_eq!(json["duplicates"], 1);
+    assert_eq!(json["errors"].as_array().unwrap().len(), 0);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/blocklist")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["total"], 2);
+}
+
+#[tokio::test]
+async fn test_bulk_add_blocklist_text_body_reports_invalid_entries() {
+    let (app, _pool) = create_test_app().await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/blocklist/bulk")
+                .method("POST")
+                .header("content-type", "text/plain")
+                .body(Body::from(
+                    "# comment\nads.example.com\n\nnot a domain\n*.example.com\n",
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["received"], 3);
+    assert_eq!(json["added"], 1);
+
+    let errors = json["errors"].as_array().unwrap();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0]["index"], 3);
+    assert_eq!(errors[0]["entry"], "not a domain");
+    assert_eq!(errors[1]["index"], 4);
+}
+
+#[tokio::test]
+async fn test_bulk_add_blocklist_malformed_json() {
+    let (app, _pool) = create_test_app().await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/blocklist/bulk")
+                .method("POST")
+                .header("content-type", "application/json")
+                .body(Body::from(r#"{"domains": ["ads.example.com"]}"#))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
 }
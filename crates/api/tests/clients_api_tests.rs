@@ -9,14 +9,14 @@ use ferrous_dns_api::{
 };
 use ferrous_dns_application::{
     ports::{
-        BlockFilterEnginePort, BlockedServiceRepository, ClientRepository, ConfigRepository,
-        FilterDecision, SafeSearchConfigRepository, SafeSearchEnginePort, ServiceCatalogPort,
+        BlockFilterEnginePort, BlockedServiceRepository, ClientRepository, FilterDecision,
+        SafeSearchConfigRepository, SafeSearchEnginePort, ServiceCatalogPort,
     },
     use_cases::{
         AssignScheduleProfileUseCase, CreateLocalRecordUseCase, CreateScheduleProfileUseCase,
         DeleteClientUseCase, DeleteLocalRecordUseCase, DeleteSafeSearchConfigsUseCase,
         DeleteScheduleProfileUseCase, GetBlockFilterStatsUseCase, GetBlocklistUseCase,
-        GetClientsUseCase, GetQueryStatsUseCase, GetRecentQueriesUseCase,
+        GetClientsUseCase, GetLocalRecordsUseCase, GetQueryStatsUseCase, GetRecentQueriesUseCase,
         GetSafeSearchConfigsUseCase, GetScheduleProfilesUseCase, ManageTimeSlotsUseCase,
         ToggleSafeSearchUseCase, UpdateClientUseCase, UpdateLocalRecordUseCase,
         UpdateScheduleProfileUseCase,
@@ -30,6 +30,9 @@ impl BlockFilterEnginePort for NullBlockFilterEngine {
     fn resolve_group(&self, _ip: std::net::IpAddr) -> i64 {
         1
     }
+    fn resolve_group_with_default(&self, _ip: std::net::IpAddr, _default_group_id: i64) -> i64 {
+        1
+    }
     fn check(&self, _domain: &str, _group_id: i64) -> FilterDecision {
         FilterDecision::Allow
     }
@@ -138,17 +141,6 @@ impl ServiceCatalogPort for NullServiceCatalog {
     }
     fn reload_custom(&self, _custom: Vec<ferrous_dns_domain::ServiceDefinition>) {}
 }
-struct NullConfigRepository;
-#[async_trait::async_trait]
-impl ConfigRepository for NullConfigRepository {
-    async fn save_local_records(
-        &self,
-        _config: &Config,
-    ) -> Result<(), ferrous_dns_domain::DomainError> {
-        Ok(())
-    }
-}
-
 struct NullSafeSearchConfigRepository;
 #[async_trait::async_trait]
 impl SafeSearchConfigRepository for NullSafeSearchConfigRepository {
@@ -300,6 +292,9 @@ async fn create_test_db() -> sqlx::SqlitePool {
             enabled BOOLEAN NOT NULL DEFAULT 1,
             comment TEXT,
             is_default BOOLEAN NOT NULL DEFAULT 0,
+            filter_aaaa BOOLEAN NOT NULL DEFAULT 0,
+            audit_mode  BOOLEAN NOT NULL DEFAULT 0,
+            tenant_id INTEGER,
             created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
             updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
         )
@@ -333,7 +328,10 @@ async fn create_test_db() -> sqlx::SqlitePool {
             last_hostname_update DATETIME,
             group_id INTEGER NOT NULL DEFAULT 1 REFERENCES groups(id) ON DELETE RESTRICT,
             created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
-            updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+            updated_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            display_name TEXT,
+            category TEXT,
+            notes TEXT
         )
         "#,
     )
@@ -394,10 +392,11 @@ async fn create_test_app() -> (Router, Arc<SqliteClientRepository>, sqlx::Sqlite
             refresh_sample_rate: 1.0,
             min_ttl: 0,
             max_ttl: 86_400,
+            l1_capacity: 1024,
         },
     ));
 
-    use ferrous_dns_domain::config::upstream::{UpstreamPool, UpstreamStrategy};
+    use ferrous_dns_domain::config::upstream::{UdpSocketMode, UpstreamPool, UpstreamStrategy};
     use ferrous_dns_infrastructure::dns::{PoolManager, QueryEventEmitter};
 
     let event_emitter = QueryEventEmitter::new_disabled();
@@ -408,6 +407,8 @@ async fn create_test_app() -> (Router, Arc<SqliteClientRepository>, sqlx::Sqlite
         priority: 1,
         servers: vec!["8.8.8.8:53".to_string()],
         weight: None,
+        tsig_key: None,
+        udp_socket: UdpSocketMode::Pooled,
     };
 
     let pool_manager = Arc::new(
@@ -443,16 +444,46 @@ async fn create_test_app() -> (Router, Arc<SqliteClientRepository>, sqlx::Sqlite
             get_top_clients: Arc::new(ferrous_dns_application::use_cases::GetTopClientsUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &DatabaseConfig::default()),
             ))),
+            database_maintenance: Arc::new(ferrous_dns_application::use_cases::DatabaseMaintenanceUseCase::new(Arc::new(ferrous_dns_infrastructure::database::SqliteDatabaseMaintenance::new(pool.clone())))),
+            get_alerts: Arc::new(ferrous_dns_application::use_cases::GetAlertsUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteAlertRepository::new(pool.clone())))),
+            delete_alert: Arc::new(ferrous_dns_application::use_cases::DeleteAlertUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteAlertRepository::new(pool.clone())))),
+            get_audit_log: Arc::new(ferrous_dns_application::use_cases::GetAuditLogUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteAuditLogRepository::new(pool.clone())))),
+            live_metrics: Arc::new(ferrous_dns_infrastructure::dns::QueryMetrics::new()),
         },
         dns: DnsUseCases {
-            cache: cache as Arc<dyn ferrous_dns_application::ports::DnsCachePort>,
-            create_local_record: Arc::new(CreateLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))),
-            update_local_record: Arc::new(UpdateLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))),
-            delete_local_record: Arc::new(DeleteLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))),
+            cache: cache.clone() as Arc<dyn ferrous_dns_application::ports::DnsCachePort>,
+            create_local_record: Arc::new(CreateLocalRecordUseCase::new(config.clone(), Arc::new(helpers::NullLocalRecordRepository), Arc::new(helpers::NullLocalZone))),
+            update_local_record: Arc::new(UpdateLocalRecordUseCase::new(config.clone(), Arc::new(helpers::NullLocalRecordRepository), Arc::new(helpers::NullLocalZone))),
+            get_local_records: Arc::new(GetLocalRecordsUseCase::new(Arc::new(helpers::NullLocalRecordRepository))),
+            delete_local_record: Arc::new(DeleteLocalRecordUseCase::new(config.clone(), Arc::new(helpers::NullLocalRecordRepository), Arc::new(helpers::NullLocalZone))),
             upstream_health: Arc::new(ferrous_dns_infrastructure::dns::UpstreamHealthAdapter::new(
-                pool_manager,
+                pool_manager.clone(),
                 None,
             )),
+            access_control: Arc::new(ferrous_dns_infrastructure::dns::AccessControlRegistry::new()),
+            sinkhole_telemetry: Arc::new(ferrous_dns_infrastructure::dns::SinkholeTelemetry::new()),
+            conditional_forwards: Arc::new(
+                ferrous_dns_infrastructure::dns::resolver::ConditionalForwards::new(&[]),
+            ),
+            slow_query_log: Arc::new(ferrous_dns_infrastructure::dns::SlowQueryLog::new(100)),
+            inflight: Arc::new(ferrous_dns_infrastructure::dns::InflightRegistry::new(4)),
+            diagnose_domain: Arc::new(ferrous_dns_application::use_cases::DiagnoseDomainUseCase::new(
+                Arc::new(NullBlockFilterEngine),
+                cache.clone(),
+                Arc::new(ferrous_dns_infrastructure::dns::UpstreamHealthAdapter::new(pool_manager.clone(), None)),
+            )),
+            trace_resolve: Arc::new(ferrous_dns_application::use_cases::TraceResolveUseCase::new(
+                Arc::new(ferrous_dns_application::use_cases::DiagnoseDomainUseCase::new(
+                    Arc::new(NullBlockFilterEngine),
+                    cache,
+                    Arc::new(ferrous_dns_infrastructure::dns::UpstreamHealthAdapter::new(pool_manager, None)),
+                )),
+                Arc::new(helpers::FixedDnsResolver),
+            )),
+            get_dnssec_status: helpers::build_test_dnssec_status(pool.clone()),
+            get_dnssec_stats: helpers::build_test_dnssec_stats(pool.clone()),
+            resolve_query: helpers::build_test_resolve_query(pool.clone()),
+            faults: None,
         },
         groups: GroupUseCases {
             get_groups: Arc::new(ferrous_dns_application::use_cases::GetGroupsUseCase::new(Arc::new(
@@ -493,6 +524,10 @@ async fn create_test_app() -> (Router, Arc<SqliteClientRepository>, sqlx::Sqlite
             )),
             update_client: Arc::new(UpdateClientUseCase::new(client_repo.clone())),
             delete_client: Arc::new(DeleteClientUseCase::new(client_repo.clone())),
+            get_client_activity: Arc::new(ferrous_dns_application::use_cases::GetClientActivityUseCase::new(
+                client_repo.clone(),
+                Arc::new(ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &DatabaseConfig::default())),
+            )),
             subnet_matcher: Arc::new(ferrous_dns_application::services::SubnetMatcherService::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::client_subnet_repository::SqliteClientSubnetRepository::new(pool.clone()),
             ))),
@@ -501,6 +536,10 @@ async fn create_test_app() -> (Router, Arc<SqliteClientRepository>, sqlx::Sqlite
             get_blocklist: Arc::new(GetBlocklistUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::blocklist_repository::SqliteBlocklistRepository::new(pool.clone()),
             ))),
+            bulk_add_blocklist: Arc::new(ferrous_dns_application::use_cases::BulkAddBlocklistUseCase::new(
+                Arc::new(ferrous_dns_infrastructure::repositories::blocklist_repository::SqliteBlocklistRepository::new(pool.clone())),
+                Arc::new(NullBlockFilterEngine),
+            )),
             get_blocklist_sources: Arc::new(ferrous_dns_application::use_cases::GetBlocklistSourcesUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::blocklist_source_repository::SqliteBlocklistSourceRepository::new(pool.clone()),
             ))),
@@ -518,6 +557,10 @@ async fn create_test_app() -> (Router, Arc<SqliteClientRepository>, sqlx::Sqlite
             get_whitelist: Arc::new(ferrous_dns_application::use_cases::GetWhitelistUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::whitelist_repository::SqliteWhitelistRepository::new(pool.clone()),
             ))),
+            bulk_add_whitelist: Arc::new(ferrous_dns_application::use_cases::BulkAddWhitelistUseCase::new(
+                Arc::new(ferrous_dns_infrastructure::repositories::whitelist_repository::SqliteWhitelistRepository::new(pool.clone())),
+                Arc::new(NullBlockFilterEngine),
+            )),
             get_whitelist_sources: Arc::new(ferrous_dns_application::use_cases::GetWhitelistSourcesUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::whitelist_source_repository::SqliteWhitelistSourceRepository::new(pool.clone()),
             ))),
@@ -532,6 +575,10 @@ async fn create_test_app() -> (Router, Arc<SqliteClientRepository>, sqlx::Sqlite
             delete_whitelist_source: Arc::new(ferrous_dns_application::use_cases::DeleteWhitelistSourceUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::whitelist_source_repository::SqliteWhitelistSourceRepository::new(pool.clone()),
             ))),
+            get_ip_blocklist_sources: Arc::new(ferrous_dns_application::use_cases::GetIpBlocklistSourcesUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteIpBlocklistSourceRepository::new(pool.clone())))),
+            create_ip_blocklist_source: Arc::new(ferrous_dns_application::use_cases::CreateIpBlocklistSourceUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteIpBlocklistSourceRepository::new(pool.clone())))),
+            update_ip_blocklist_source: Arc::new(ferrous_dns_application::use_cases::UpdateIpBlocklistSourceUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteIpBlocklistSourceRepository::new(pool.clone())))),
+            delete_ip_blocklist_source: Arc::new(ferrous_dns_application::use_cases::DeleteIpBlocklistSourceUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteIpBlocklistSourceRepository::new(pool.clone())))),
             get_managed_domains: Arc::new(ferrous_dns_application::use_cases::GetManagedDomainsUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::managed_domain_repository::SqliteManagedDomainRepository::new(pool.clone()),
             ))),
@@ -567,6 +614,11 @@ async fn create_test_app() -> (Router, Arc<SqliteClientRepository>, sqlx::Sqlite
                 Arc::new(NullBlockFilterEngine),
             )),
             get_block_filter_stats: Arc::new(GetBlockFilterStatsUseCase::new(Arc::new(NullBlockFilterEngine))),
+            get_unblock_requests: Arc::new(ferrous_dns_application::use_cases::GetUnblockRequestsUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository::new(pool.clone())))),
+            submit_unblock_request: Arc::new(ferrous_dns_application::use_cases::SubmitUnblockRequestUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository::new(pool.clone())), Arc::new(NullBlockFilterEngine))),
+            approve_unblock_request: Arc::new(ferrous_dns_application::use_cases::ApproveUnblockRequestUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::repositories::managed_domain_repository::SqliteManagedDomainRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::repositories::group_repository::SqliteGroupRepository::new(pool.clone())), Arc::new(NullBlockFilterEngine))),
+            deny_unblock_request: Arc::new(ferrous_dns_application::use_cases::DenyUnblockRequestUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository::new(pool.clone())))),
+            delete_unblock_request: Arc::new(ferrous_dns_application::use_cases::DeleteUnblockRequestUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository::new(pool.clone())))),
         },
         services: ServiceUseCases {
             get_service_catalog: Arc::new(ferrous_dns_application::use_cases::GetServiceCatalogUseCase::new(Arc::new(NullServiceCatalog))),
@@ -612,13 +664,17 @@ async fn create_test_app() -> (Router, Arc<SqliteClientRepository>, sqlx::Sqlite
             manage_slots: Arc::new(ManageTimeSlotsUseCase::new(Arc::new(NullScheduleProfileRepository))),
             assign_profile: Arc::new(AssignScheduleProfileUseCase::new(Arc::new(NullScheduleProfileRepository), group_repo.clone())),
         },
+        policies: helpers::build_test_query_policy_use_cases(group_repo.clone()),
         auth: helpers::build_test_auth_use_cases(),
         backup: helpers::build_test_backup_use_cases(config.clone()),
+        tenants: helpers::build_test_tenant_use_cases(),
         config: config.clone(),
         config_file_persistence: Arc::new(ferrous_dns_infrastructure::repositories::TomlConfigFilePersistence),
         config_path: None,
         tls_cert: Arc::new(helpers::MockTlsCertificateService),
         tls_enabled: false,
+        log_levels: Arc::new(helpers::MockLogLevelControl::default()),
+        system_metrics: Arc::new(ferrous_dns_infrastructure::system::ProcessMetricsCollector::new()),
     };
 
     let app = create_api_routes(state);
@@ -644,8 +700,8 @@ async fn test_get_clients_empty() {
     let body = response.into_body().collect().await.unwrap().to_bytes();
     let json: Value = serde_json::from_slice(&body).unwrap();
 
-    assert!(json.is_array());
-    assert_eq!(json.as_array().unwrap().len(), 0);
+    assert!(json["data"].is_array());
+    assert_eq!(json["data"].as_array().unwrap().len(), 0);
 }
 
 #[tokio::test]
@@ -680,8 +736,8 @@ async fn test_get_clients_with_data() {
     let body = response.into_body().collect().await.unwrap().to_bytes();
     let json: Value = serde_json::from_slice(&body).unwrap();
 
-    assert!(json.is_array());
-    let clients = json.as_array().unwrap();
+    assert!(json["data"].is_array());
+    let clients = json["data"].as_array().unwrap();
     assert_eq!(clients.len(), 2);
 
     let client = &clients[0];
@@ -718,7 +774,7 @@ async fn test_get_clients_with_pagination() {
     assert_eq!(response.status(), StatusCode::OK);
     let body = response.into_body().collect().await.unwrap().to_bytes();
     let json: Value = serde_json::from_slice(&body).unwrap();
-    assert_eq!(json.as_array().unwrap().len(), 5);
+    assert_eq!(json["data"].as_array().unwrap().len(), 5);
 
     let response = app
         .oneshot(
@@ -733,7 +789,130 @@ async fn test_get_clients_with_pagination() {
     assert_eq!(response.status(), StatusCode::OK);
     let body = response.into_body().collect().await.unwrap().to_bytes();
     let json: Value = serde_json::from_slice(&body).unwrap();
-    assert_eq!(json.as_array().unwrap().len(), 5);
+    assert_eq!(json["data"].as_array().unwrap().len(), 5);
+}
+
+#[tokio::test]
+async fn test_get_clients_envelope_reports_total() {
+    let (app, repo, _pool) = create_test_app().await;
+
+    for i in 1..=7 {
+        let ip: IpAddr = format!("192.168.1.{}", i).parse().unwrap();
+        repo.update_last_seen(ip).await.unwrap();
+    }
+    repo.flush_writes().await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/clients?limit=3&offset=3")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["total"], 7);
+    assert_eq!(json["limit"], 3);
+    assert_eq!(json["offset"], 3);
+    assert_eq!(json["data"].as_array().unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_get_clients_sorted_by_ip_address() {
+    let (app, repo, _pool) = create_test_app().await;
+
+    for i in [3, 1, 2] {
+        let ip: IpAddr = format!("192.168.1.{}", i).parse().unwrap();
+        repo.update_last_seen(ip).await.unwrap();
+    }
+    repo.flush_writes().await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/clients?sort=ip_address&order=desc")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    let ips: Vec<&str> = json["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["ip_address"].as_str().unwrap())
+        .collect();
+    assert_eq!(ips, vec!["192.168.1.3", "192.168.1.2", "192.168.1.1"]);
+}
+
+#[tokio::test]
+async fn test_get_clients_rejects_unknown_sort_field() {
+    let (app, _repo, _pool) = create_test_app().await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/clients?sort=mac_address")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_get_clients_rejects_invalid_order() {
+    let (app, _repo, _pool) = create_test_app().await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/clients?sort=hostname&order=sideways")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_get_clients_field_selection() {
+    let (app, repo, _pool) = create_test_app().await;
+
+    let ip: IpAddr = "192.168.1.10".parse().unwrap();
+    repo.update_last_seen(ip).await.unwrap();
+    repo.flush_writes().await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/clients?fields=id,ip_address")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["total"], 1);
+    let client = json["data"][0].as_object().unwrap();
+    assert_eq!(client.len(), 2);
+    assert_eq!(client["ip_address"], "192.168.1.10");
+    assert!(client.contains_key("id"));
 }
 
 #[tokio::test]
@@ -807,7 +986,7 @@ async fn test_get_clients_json_structure() {
     let body = response.into_body().collect().await.unwrap().to_bytes();
     let json: Value = serde_json::from_slice(&body).unwrap();
 
-    let client = &json.as_array().unwrap()[0];
+    let client = &json["data"].as_array().unwrap()[0];
 
     assert!(client["id"].is_number());
     assert_eq!(client["ip_address"], "192.168.1.100");
@@ -849,7 +1028,7 @@ async fn test_get_clients_with_active_days_filter() {
     let body = response.into_body().collect().await.unwrap().to_bytes();
     let json: Value = serde_json::from_slice(&body).unwrap();
 
-    assert!(json.is_array());
+    assert!(json["data"].is_array());
 }
 
 #[tokio::test]
@@ -1105,7 +1284,7 @@ async fn test_delete_client_verifies_not_in_get_all() {
 
     let body = get_response.into_body().collect().await.unwrap().to_bytes();
     let json: Value = serde_json::from_slice(&body).unwrap();
-    let clients_after = json.as_array().unwrap();
+    let clients_after = json["data"].as_array().unwrap();
 
     assert_eq!(clients_after.len(), 2);
 
@@ -1113,3 +1292,87 @@ async fn test_delete_client_verifies_not_in_get_all() {
         .iter()
         .any(|c| c["ip_address"].as_str().unwrap() == delete_ip.to_string()));
 }
+
+#[tokio::test]
+async fn test_get_client_activity_nonexistent_client() {
+    let (app, _repo, _pool) = create_test_app().await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/clients/9999/activity?period=7d&granularity=day")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_patch_client_sets_display_metadata() {
+    let (app, repo, _pool) = create_test_app().await;
+
+    let ip: IpAddr = "192.168.1.73".parse().unwrap();
+    repo.update_last_seen(ip).await.unwrap();
+    repo.flush_writes().await;
+    let client_id = repo.get_all(100, 0).await.unwrap()[0].id.unwrap();
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("PATCH")
+                .uri(format!("/clients/{}", client_id))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    r#"{"display_name":"Living Room TV","category":"tv","notes":"Wall mounted"}"#,
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/clients")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    let client = &json["data"].as_array().unwrap()[0];
+
+    assert_eq!(client["display_name"], "Living Room TV");
+    assert_eq!(client["category"], "tv");
+    assert_eq!(client["notes"], "Wall mounted");
+}
+
+#[tokio::test]
+async fn test_patch_client_rejects_unknown_category() {
+    let (app, repo, _pool) = create_test_app().await;
+
+    let ip: IpAddr = "192.168.1.74".parse().unwrap();
+    repo.update_last_seen(ip).await.unwrap();
+    repo.flush_writes().await;
+    let client_id = repo.get_all(100, 0).await.unwrap()[0].id.unwrap();
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("PATCH")
+                .uri(format!("/clients/{}", client_id))
+                .header("content-type", "application/json")
+                .body(Body::from(r#"{"category":"toaster"}"#))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
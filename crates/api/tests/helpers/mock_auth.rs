@@ -1,14 +1,17 @@
 use ferrous_dns_api::AuthUseCases;
 use ferrous_dns_application::ports::{
-    ApiTokenRepository, PasswordHasher, SessionRepository, UserProvider, UserRepository,
+    ApiTokenRepository, BlocklistSourceCreator, ConfigFilePersistence, PasswordHasher,
+    SessionRepository, UserProvider, UserRepository,
 };
 use ferrous_dns_application::use_cases::{
     ChangePasswordUseCase, CreateApiTokenUseCase, CreateUserUseCase, DeleteApiTokenUseCase,
     DeleteUserUseCase, GetActiveSessionsUseCase, GetApiTokensUseCase, GetAuthStatusUseCase,
-    GetUsersUseCase, LoginUseCase, LogoutUseCase, SetupPasswordUseCase, UpdateApiTokenUseCase,
-    ValidateApiTokenUseCase, ValidateSessionUseCase,
+    GetUsersUseCase, LoginUseCase, LogoutUseCase, SetupPasswordUseCase, SetupWizardUseCase,
+    UpdateApiTokenUseCase, ValidateApiTokenUseCase, ValidateSessionUseCase,
+};
+use ferrous_dns_domain::{
+    ApiToken, AuthConfig, AuthSession, BlocklistSource, Config, DomainError, User,
 };
-use ferrous_dns_domain::{ApiToken, AuthConfig, AuthSession, Config, DomainError, User};
 use std::sync::Arc;
 
 pub struct NullSessionRepository;
@@ -105,6 +108,7 @@ impl ApiTokenRepository for NullApiTokenRepository {
         _key_prefix: &str,
         _key_hash: &str,
         _key_raw: &str,
+        _tenant_id: Option<i64>,
     ) -> Result<ApiToken, DomainError> {
         Err(DomainError::ConfigError("not implemented".to_string()))
     }
@@ -139,6 +143,33 @@ impl ApiTokenRepository for NullApiTokenRepository {
     async fn get_id_by_hash(&self, _key_hash: &str) -> Result<Option<i64>, DomainError> {
         Ok(None)
     }
+    async fn get_by_hash(&self, _key_hash: &str) -> Result<Option<ApiToken>, DomainError> {
+        Ok(None)
+    }
+}
+
+pub struct NullBlocklistSourceCreator;
+
+#[async_trait::async_trait]
+impl BlocklistSourceCreator for NullBlocklistSourceCreator {
+    async fn create_blocklist_source(
+        &self,
+        _name: String,
+        _url: Option<String>,
+        _group_ids: Vec<i64>,
+        _comment: Option<String>,
+        _enabled: bool,
+    ) -> Result<BlocklistSource, DomainError> {
+        Err(DomainError::ConfigError("not implemented".to_string()))
+    }
+}
+
+pub struct NullConfigFilePersistence;
+
+impl ConfigFilePersistence for NullConfigFilePersistence {
+    fn save_config_to_file(&self, _config: &Config, _path: &str) -> Result<(), String> {
+        Ok(())
+    }
 }
 
 pub fn build_test_auth_use_cases() -> AuthUseCases {
@@ -156,6 +187,12 @@ pub fn build_test_auth_use_cases() -> AuthUseCases {
         ..Config::default()
     }));
 
+    let setup_password = Arc::new(SetupPasswordUseCase::new(
+        user_provider.clone(),
+        password_hasher.clone(),
+        "admin".to_string(),
+    ));
+
     AuthUseCases {
         login: Arc::new(LoginUseCase::new(
             user_provider.clone(),
@@ -165,10 +202,13 @@ pub fn build_test_auth_use_cases() -> AuthUseCases {
         )),
         logout: Arc::new(LogoutUseCase::new(session_repo.clone())),
         validate_session: Arc::new(ValidateSessionUseCase::new(session_repo.clone())),
-        setup_password: Arc::new(SetupPasswordUseCase::new(
-            user_provider.clone(),
-            password_hasher.clone(),
-            "admin".to_string(),
+        setup_password: setup_password.clone(),
+        setup_wizard: Arc::new(SetupWizardUseCase::new(
+            config.clone(),
+            Arc::new(NullConfigFilePersistence),
+            None,
+            setup_password,
+            Arc::new(NullBlocklistSourceCreator),
         )),
         change_password: Arc::new(ChangePasswordUseCase::new(
             user_provider.clone(),
@@ -2,18 +2,30 @@
 
 use ferrous_dns_api::BackupUseCases;
 use ferrous_dns_application::ports::{
-    BlocklistSourceCreator, BlocklistSourceRepository, ConfigFilePersistence, ConfigRepository,
-    GroupCreator, GroupRepository, LocalRecordCreator,
+    BackupArchiver, BackupStore, BackupTables, BlocklistSourceCreator, BlocklistSourceRepository,
+    ConfigFilePersistence, GroupCreator, GroupRepository, LocalRecordCreator,
 };
 use ferrous_dns_application::use_cases::{
-    CreateBlocklistSourceUseCase, CreateGroupUseCase, CreateLocalRecordUseCase,
-    ExportConfigUseCase, ImportConfigUseCase,
+    CreateBackupUseCase, CreateBlocklistSourceUseCase, CreateGroupUseCase,
+    CreateLocalRecordUseCase, ExportConfigUseCase, ExportPrimaryStateUseCase,
+    GetLocalRecordsUseCase, ImportConfigUseCase, ImportExternalConfigUseCase, RestoreBackupUseCase,
 };
-use ferrous_dns_domain::{BlocklistSource, Client, Config, DomainError, Group};
+use ferrous_dns_domain::config::DatabaseConfig;
+use ferrous_dns_domain::{BlocklistSource, Client, Config, DomainError, Group, PageRequest};
+use ferrous_dns_infrastructure::dns::cache::DnsCache;
+use ferrous_dns_infrastructure::dns::{DnsCacheConfig, EvictionStrategy};
+use ferrous_dns_infrastructure::external_import::ExternalConfigFileReader;
+use ferrous_dns_infrastructure::repositories::{
+    SqliteBlocklistSourceRepository, SqliteClientRepository, SqliteClientSubnetRepository,
+    SqliteGroupRepository, SqliteManagedDomainRepository, SqliteRegexFilterRepository,
+    SqliteWhitelistSourceRepository,
+};
+use sqlx::SqlitePool;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
-struct NullGroupRepository;
+pub struct NullGroupRepository;
 
 #[async_trait::async_trait]
 impl GroupRepository for NullGroupRepository {
@@ -32,12 +44,26 @@ impl GroupRepository for NullGroupRepository {
     async fn get_all_with_client_counts(&self) -> Result<Vec<(Group, u64)>, DomainError> {
         Ok(vec![])
     }
+    async fn get_in_tenant_with_client_counts(
+        &self,
+        _tenant_id: i64,
+    ) -> Result<Vec<(Group, u64)>, DomainError> {
+        Ok(vec![])
+    }
+    async fn get_page_with_client_counts(
+        &self,
+        _page: &PageRequest,
+    ) -> Result<(Vec<(Group, u64)>, u64), DomainError> {
+        Ok((vec![], 0))
+    }
     async fn update(
         &self,
         _id: i64,
         _name: Option<String>,
         _enabled: Option<bool>,
         _comment: Option<String>,
+        _filter_aaaa: Option<bool>,
+        _audit_mode: Option<bool>,
     ) -> Result<Group, DomainError> {
         Err(DomainError::IoError("test stub".to_string()))
     }
@@ -52,7 +78,8 @@ impl GroupRepository for NullGroupRepository {
     }
 }
 
-struct NullBlocklistSourceRepository;
+/// Repository with no blocklist sources; writes are rejected.
+pub struct NullBlocklistSourceRepository;
 
 #[async_trait::async_trait]
 impl BlocklistSourceRepository for NullBlocklistSourceRepository {
@@ -66,12 +93,42 @@ impl BlocklistSourceRepository for NullBlocklistSourceRepository {
     ) -> Result<BlocklistSource, DomainError> {
         Err(DomainError::IoError("test stub".to_string()))
     }
+    async fn create_in_tenant(
+        &self,
+        _tenant_id: i64,
+        _name: String,
+        _url: Option<String>,
+        _group_ids: Vec<i64>,
+        _comment: Option<String>,
+        _enabled: bool,
+    ) -> Result<BlocklistSource, DomainError> {
+        Err(DomainError::IoError("test stub".to_string()))
+    }
     async fn get_by_id(&self, _id: i64) -> Result<Option<BlocklistSource>, DomainError> {
         Ok(None)
     }
+    async fn get_by_id_in_tenant(
+        &self,
+        _tenant_id: i64,
+        _id: i64,
+    ) -> Result<Option<BlocklistSource>, DomainError> {
+        Ok(None)
+    }
     async fn get_all(&self) -> Result<Vec<BlocklistSource>, DomainError> {
         Ok(vec![])
     }
+    async fn get_all_in_tenant(
+        &self,
+        _tenant_id: i64,
+    ) -> Result<Vec<BlocklistSource>, DomainError> {
+        Ok(vec![])
+    }
+    async fn get_page(
+        &self,
+        _page: &PageRequest,
+    ) -> Result<(Vec<BlocklistSource>, u64), DomainError> {
+        Ok((vec![], 0))
+    }
     async fn update(
         &self,
         _id: i64,
@@ -80,12 +137,16 @@ impl BlocklistSourceRepository for NullBlocklistSourceRepository {
         _group_ids: Option<Vec<i64>>,
         _comment: Option<String>,
         _enabled: Option<bool>,
+        _audit_mode: Option<bool>,
     ) -> Result<BlocklistSource, DomainError> {
         Err(DomainError::IoError("test stub".to_string()))
     }
     async fn delete(&self, _id: i64) -> Result<(), DomainError> {
         Ok(())
     }
+    async fn delete_in_tenant(&self, _tenant_id: i64, _id: i64) -> Result<(), DomainError> {
+        Ok(())
+    }
 }
 
 struct NullConfigFilePersistence;
@@ -96,12 +157,29 @@ impl ConfigFilePersistence for NullConfigFilePersistence {
     }
 }
 
-struct NullConfigRepository;
+struct NullBackupStore;
 
 #[async_trait::async_trait]
-impl ConfigRepository for NullConfigRepository {
-    async fn save_local_records(&self, _config: &Config) -> Result<(), DomainError> {
-        Ok(())
+impl BackupStore for NullBackupStore {
+    async fn dump(&self) -> Result<BackupTables, DomainError> {
+        Ok(BackupTables::new())
+    }
+    async fn restore(
+        &self,
+        _tables: &BackupTables,
+    ) -> Result<BTreeMap<String, usize>, DomainError> {
+        Ok(BTreeMap::new())
+    }
+}
+
+struct NullBackupArchiver;
+
+impl BackupArchiver for NullBackupArchiver {
+    fn pack(&self, _files: &[(String, Vec<u8>)]) -> Result<Vec<u8>, DomainError> {
+        Ok(vec![])
+    }
+    fn unpack(&self, _bytes: &[u8]) -> Result<Vec<(String, Vec<u8>)>, DomainError> {
+        Ok(vec![])
     }
 }
 
@@ -115,9 +193,12 @@ pub fn build_test_backup_use_cases(config: Arc<RwLock<Config>>) -> BackupUseCase
     let blocklist_source_creator: Arc<dyn BlocklistSourceCreator> = Arc::new(
         CreateBlocklistSourceUseCase::new(blocklist_source_repo.clone(), group_repo.clone()),
     );
-    let local_record_creator: Arc<dyn LocalRecordCreator> = Arc::new(
-        CreateLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository)),
-    );
+    let local_record_creator: Arc<dyn LocalRecordCreator> =
+        Arc::new(CreateLocalRecordUseCase::new(
+            config.clone(),
+            Arc::new(super::NullLocalRecordRepository),
+            Arc::new(super::NullLocalZone),
+        ));
 
     BackupUseCases {
         export: Arc::new(ExportConfigUseCase::new(
@@ -126,12 +207,74 @@ pub fn build_test_backup_use_cases(config: Arc<RwLock<Config>>) -> BackupUseCase
             blocklist_source_repo,
         )),
         import: Arc::new(ImportConfigUseCase::new(
-            config,
+            config.clone(),
             Arc::new(NullConfigFilePersistence),
             None,
             group_creator,
             blocklist_source_creator,
-            local_record_creator,
+            local_record_creator.clone(),
         )),
+        create: Arc::new(CreateBackupUseCase::new(
+            config.clone(),
+            Arc::new(NullBackupStore),
+            Arc::new(NullBackupArchiver),
+        )),
+        restore: Arc::new(RestoreBackupUseCase::new(
+            config.clone(),
+            Arc::new(NullConfigFilePersistence),
+            None,
+            Arc::new(NullBackupStore),
+            Arc::new(NullBackupArchiver),
+        )),
+        external_import: Arc::new(build_test_external_import(config, local_record_creator)),
+        primary_state: Arc::new(ExportPrimaryStateUseCase::new(
+            Arc::new(super::mock_tenants::NullBlockFilterEngine),
+            Arc::new(build_test_cache()),
+        )),
+        standby: None,
     }
 }
+
+fn build_test_cache() -> DnsCache {
+    DnsCache::new(DnsCacheConfig {
+        max_entries: 1024,
+        eviction_strategy: EvictionStrategy::LRU,
+        min_threshold: 0.0,
+        refresh_threshold: 0.0,
+        batch_eviction_percentage: 0.0,
+        adaptive_thresholds: false,
+        min_frequency: 0,
+        min_lfuk_score: 0.0,
+        shard_amount: 4,
+        access_window_secs: 7200,
+        eviction_sample_size: 8,
+        lfuk_k_value: 0.5,
+        refresh_sample_rate: 1.0,
+        min_ttl: 0,
+        max_ttl: 86_400,
+        l1_capacity: 1024,
+    })
+}
+
+/// External import over a never-connected pool; tests using it do not reach the database.
+fn build_test_external_import(
+    config: Arc<RwLock<Config>>,
+    local_record_creator: Arc<dyn LocalRecordCreator>,
+) -> ImportExternalConfigUseCase {
+    let pool = SqlitePool::connect_lazy("sqlite::memory:").expect("lazy pool");
+    ImportExternalConfigUseCase::new(
+        Arc::new(ExternalConfigFileReader),
+        config,
+        Arc::new(SqliteGroupRepository::new(pool.clone())),
+        Arc::new(SqliteBlocklistSourceRepository::new(pool.clone())),
+        Arc::new(SqliteWhitelistSourceRepository::new(pool.clone())),
+        Arc::new(SqliteManagedDomainRepository::new(pool.clone())),
+        Arc::new(SqliteRegexFilterRepository::new(pool.clone())),
+        Arc::new(SqliteClientRepository::new(
+            pool.clone(),
+            &DatabaseConfig::default(),
+        )),
+        Arc::new(SqliteClientSubnetRepository::new(pool)),
+        local_record_creator,
+    )
+}
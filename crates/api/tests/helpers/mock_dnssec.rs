@@ -0,0 +1,19 @@
+use ferrous_dns_application::use_cases::{GetDnssecStatsUseCase, GetDnssecStatusUseCase};
+use ferrous_dns_infrastructure::dns::dnssec::DnssecCache;
+use ferrous_dns_infrastructure::repositories::SqliteDnssecHistoryRepository;
+use sqlx::SqlitePool;
+use std::sync::Arc;
+
+/// Reads an empty DNSSEC cache and the history tables in `pool`.
+pub fn build_test_dnssec_status(pool: SqlitePool) -> Arc<GetDnssecStatusUseCase> {
+    Arc::new(GetDnssecStatusUseCase::new(
+        Arc::new(DnssecCache::new()),
+        Arc::new(SqliteDnssecHistoryRepository::new(pool)),
+    ))
+}
+
+pub fn build_test_dnssec_stats(pool: SqlitePool) -> Arc<GetDnssecStatsUseCase> {
+    Arc::new(GetDnssecStatsUseCase::new(Arc::new(
+        SqliteDnssecHistoryRepository::new(pool),
+    )))
+}
@@ -0,0 +1,50 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{LocalRecordRepository, LocalZoneAnswer, LocalZonePort};
+use ferrous_dns_domain::{DomainError, LocalRecord, RecordType};
+
+/// Repository with no records; writes are accepted and discarded.
+pub struct NullLocalRecordRepository;
+
+#[async_trait]
+impl LocalRecordRepository for NullLocalRecordRepository {
+    async fn create(&self, record: &LocalRecord) -> Result<LocalRecord, DomainError> {
+        Ok(LocalRecord {
+            id: Some(1),
+            ..record.clone()
+        })
+    }
+    async fn get_by_id(&self, _id: i64) -> Result<Option<LocalRecord>, DomainError> {
+        Ok(None)
+    }
+    async fn get_by_id_in_tenant(
+        &self,
+        _tenant_id: i64,
+        _id: i64,
+    ) -> Result<Option<LocalRecord>, DomainError> {
+        Ok(None)
+    }
+    async fn get_all(&self) -> Result<Vec<LocalRecord>, DomainError> {
+        Ok(vec![])
+    }
+    async fn get_all_in_tenant(&self, _tenant_id: i64) -> Result<Vec<LocalRecord>, DomainError> {
+        Ok(vec![])
+    }
+    async fn update(&self, record: &LocalRecord) -> Result<LocalRecord, DomainError> {
+        Err(DomainError::LocalRecordNotFound(record.id.unwrap_or(0)))
+    }
+    async fn delete(&self, id: i64) -> Result<(), DomainError> {
+        Err(DomainError::LocalRecordNotFound(id))
+    }
+}
+
+pub struct NullLocalZone;
+
+#[async_trait]
+impl LocalZonePort for NullLocalZone {
+    fn lookup(&self, _domain: &str, _record_type: RecordType) -> Option<LocalZoneAnswer> {
+        None
+    }
+    async fn reload(&self) -> Result<(), DomainError> {
+        Ok(())
+    }
+}
@@ -0,0 +1,21 @@
+use ferrous_dns_application::ports::LogLevelPort;
+use ferrous_dns_domain::DomainError;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+/// Records the last levels applied instead of touching the global subscriber.
+#[derive(Default)]
+pub struct MockLogLevelControl {
+    pub applied: Mutex<Option<(String, BTreeMap<String, String>)>>,
+}
+
+impl LogLevelPort for MockLogLevelControl {
+    fn set_levels(
+        &self,
+        level: &str,
+        modules: &BTreeMap<String, String>,
+    ) -> Result<(), DomainError> {
+        *self.applied.lock().unwrap() = Some((level.to_string(), modules.clone()));
+        Ok(())
+    }
+}
@@ -0,0 +1,259 @@
+use ferrous_dns_api::QueryPolicyUseCases;
+use ferrous_dns_application::ports::{
+    BlockFilterEnginePort, CacheTtlOverrideEnginePort, CacheTtlOverrideRepository,
+    DnsRewriteEnginePort, DnsRewriteRepository, GroupRepository, QueryPolicyEnginePort,
+    QueryPolicyRepository, RecordTypeFilterPort, RecordTypePolicyRepository, TldPolicyRepository,
+};
+use ferrous_dns_application::use_cases::{
+    CreateCacheTtlOverrideUseCase, CreateDnsRewriteUseCase, CreateQueryPolicyUseCase,
+    DeleteCacheTtlOverrideUseCase, DeleteDnsRewriteUseCase, DeleteQueryPolicyUseCase,
+    DeleteRecordTypePolicyUseCase, DeleteTldPolicyUseCase, GetCacheTtlOverridesUseCase,
+    GetDnsRewritesUseCase, GetQueryPoliciesUseCase, GetRecordTypePoliciesUseCase,
+    GetTldPoliciesUseCase, SetRecordTypePolicyUseCase, SetTldPolicyUseCase,
+    UpdateCacheTtlOverrideUseCase, UpdateDnsRewriteUseCase, UpdateQueryPolicyUseCase,
+};
+use ferrous_dns_domain::{
+    CacheTtlOverride, DnsRewrite, DomainError, PolicyMatch, QueryPolicy, RecordType,
+    RecordTypePolicy, RewriteTarget, TldPolicy, TtlBounds,
+};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use super::mock_tenants::NullBlockFilterEngine;
+
+struct NullQueryPolicyRepository;
+
+#[async_trait::async_trait]
+impl QueryPolicyRepository for NullQueryPolicyRepository {
+    async fn create(&self, policy: &QueryPolicy) -> Result<QueryPolicy, DomainError> {
+        Ok(policy.clone())
+    }
+    async fn get_by_id(&self, _id: i64) -> Result<Option<QueryPolicy>, DomainError> {
+        Ok(None)
+    }
+    async fn get_all(&self) -> Result<Vec<QueryPolicy>, DomainError> {
+        Ok(vec![])
+    }
+    async fn update(&self, policy: &QueryPolicy) -> Result<QueryPolicy, DomainError> {
+        Ok(policy.clone())
+    }
+    async fn delete(&self, id: i64) -> Result<(), DomainError> {
+        Err(DomainError::QueryPolicyNotFound(id))
+    }
+}
+
+struct NullQueryPolicyEngine;
+
+#[async_trait::async_trait]
+impl QueryPolicyEnginePort for NullQueryPolicyEngine {
+    fn evaluate(
+        &self,
+        _client_ip: IpAddr,
+        _group_id: i64,
+        _domain: &str,
+        _record_type: RecordType,
+    ) -> Option<PolicyMatch> {
+        None
+    }
+    async fn reload(&self) -> Result<(), DomainError> {
+        Ok(())
+    }
+}
+
+struct NullRecordTypePolicyRepository;
+
+#[async_trait::async_trait]
+impl RecordTypePolicyRepository for NullRecordTypePolicyRepository {
+    async fn get_all(&self) -> Result<Vec<RecordTypePolicy>, DomainError> {
+        Ok(vec![])
+    }
+    async fn get_by_group(&self, _group_id: i64) -> Result<Option<RecordTypePolicy>, DomainError> {
+        Ok(None)
+    }
+    async fn upsert(&self, policy: &RecordTypePolicy) -> Result<RecordTypePolicy, DomainError> {
+        Ok(policy.clone())
+    }
+    async fn delete_by_group(&self, _group_id: i64) -> Result<(), DomainError> {
+        Ok(())
+    }
+}
+
+struct NullRecordTypeFilter;
+
+#[async_trait::async_trait]
+impl RecordTypeFilterPort for NullRecordTypeFilter {
+    fn is_blocked(&self, _group_id: i64, _record_type: RecordType) -> bool {
+        false
+    }
+    async fn reload(&self) -> Result<(), DomainError> {
+        Ok(())
+    }
+}
+
+struct NullTldPolicyRepository;
+
+#[async_trait::async_trait]
+impl TldPolicyRepository for NullTldPolicyRepository {
+    async fn get_all(&self) -> Result<Vec<TldPolicy>, DomainError> {
+        Ok(vec![])
+    }
+    async fn get_by_group(&self, _group_id: i64) -> Result<Option<TldPolicy>, DomainError> {
+        Ok(None)
+    }
+    async fn upsert(&self, policy: &TldPolicy) -> Result<TldPolicy, DomainError> {
+        Ok(policy.clone())
+    }
+    async fn delete_by_group(&self, _group_id: i64) -> Result<(), DomainError> {
+        Ok(())
+    }
+}
+
+struct NullDnsRewriteRepository;
+
+#[async_trait::async_trait]
+impl DnsRewriteRepository for NullDnsRewriteRepository {
+    async fn create(&self, rewrite: &DnsRewrite) -> Result<DnsRewrite, DomainError> {
+        Ok(rewrite.clone())
+    }
+    async fn get_by_id(&self, _id: i64) -> Result<Option<DnsRewrite>, DomainError> {
+        Ok(None)
+    }
+    async fn get_all(&self) -> Result<Vec<DnsRewrite>, DomainError> {
+        Ok(vec![])
+    }
+    async fn update(&self, rewrite: &DnsRewrite) -> Result<DnsRewrite, DomainError> {
+        Ok(rewrite.clone())
+    }
+    async fn delete(&self, id: i64) -> Result<(), DomainError> {
+        Err(DomainError::DnsRewriteNotFound(id))
+    }
+}
+
+struct NullDnsRewriteEngine;
+
+#[async_trait::async_trait]
+impl DnsRewriteEnginePort for NullDnsRewriteEngine {
+    fn lookup(&self, _domain: &str) -> Option<RewriteTarget> {
+        None
+    }
+    async fn reload(&self) -> Result<(), DomainError> {
+        Ok(())
+    }
+}
+
+struct NullCacheTtlOverrideRepository;
+
+#[async_trait::async_trait]
+impl CacheTtlOverrideRepository for NullCacheTtlOverrideRepository {
+    async fn create(
+        &self,
+        ttl_override: &CacheTtlOverride,
+    ) -> Result<CacheTtlOverride, DomainError> {
+        Ok(ttl_override.clone())
+    }
+    async fn get_by_id(&self, _id: i64) -> Result<Option<CacheTtlOverride>, DomainError> {
+        Ok(None)
+    }
+    async fn get_all(&self) -> Result<Vec<CacheTtlOverride>, DomainError> {
+        Ok(vec![])
+    }
+    async fn update(
+        &self,
+        ttl_override: &CacheTtlOverride,
+    ) -> Result<CacheTtlOverride, DomainError> {
+        Ok(ttl_override.clone())
+    }
+    async fn delete(&self, id: i64) -> Result<(), DomainError> {
+        Err(DomainError::CacheTtlOverrideNotFound(id))
+    }
+}
+
+struct NullCacheTtlOverrideEngine;
+
+#[async_trait::async_trait]
+impl CacheTtlOverrideEnginePort for NullCacheTtlOverrideEngine {
+    fn bounds(&self, _domain: &str) -> Option<TtlBounds> {
+        None
+    }
+    async fn reload(&self) -> Result<(), DomainError> {
+        Ok(())
+    }
+}
+
+pub fn build_test_query_policy_use_cases(
+    group_repo: Arc<dyn GroupRepository>,
+) -> QueryPolicyUseCases {
+    let repo: Arc<dyn QueryPolicyRepository> = Arc::new(NullQueryPolicyRepository);
+    let engine: Arc<dyn QueryPolicyEnginePort> = Arc::new(NullQueryPolicyEngine);
+    let type_repo: Arc<dyn RecordTypePolicyRepository> = Arc::new(NullRecordTypePolicyRepository);
+    let type_filter: Arc<dyn RecordTypeFilterPort> = Arc::new(NullRecordTypeFilter);
+    let tld_repo: Arc<dyn TldPolicyRepository> = Arc::new(NullTldPolicyRepository);
+    let block_filter: Arc<dyn BlockFilterEnginePort> = Arc::new(NullBlockFilterEngine);
+    let rewrite_repo: Arc<dyn DnsRewriteRepository> = Arc::new(NullDnsRewriteRepository);
+    let rewrite_engine: Arc<dyn DnsRewriteEnginePort> = Arc::new(NullDnsRewriteEngine);
+    let ttl_repo: Arc<dyn CacheTtlOverrideRepository> = Arc::new(NullCacheTtlOverrideRepository);
+    let ttl_engine: Arc<dyn CacheTtlOverrideEnginePort> = Arc::new(NullCacheTtlOverrideEngine);
+
+    QueryPolicyUseCases {
+        get_policies: Arc::new(GetQueryPoliciesUseCase::new(repo.clone())),
+        create_policy: Arc::new(CreateQueryPolicyUseCase::new(
+            repo.clone(),
+            group_repo.clone(),
+            engine.clone(),
+        )),
+        update_policy: Arc::new(UpdateQueryPolicyUseCase::new(
+            repo.clone(),
+            group_repo.clone(),
+            engine.clone(),
+        )),
+        delete_policy: Arc::new(DeleteQueryPolicyUseCase::new(repo, engine)),
+        get_record_type_policies: Arc::new(GetRecordTypePoliciesUseCase::new(
+            type_repo.clone(),
+            group_repo.clone(),
+        )),
+        set_record_type_policy: Arc::new(SetRecordTypePolicyUseCase::new(
+            type_repo.clone(),
+            group_repo.clone(),
+            type_filter.clone(),
+        )),
+        delete_record_type_policy: Arc::new(DeleteRecordTypePolicyUseCase::new(
+            type_repo,
+            group_repo.clone(),
+            type_filter,
+        )),
+        get_tld_policies: Arc::new(GetTldPoliciesUseCase::new(
+            tld_repo.clone(),
+            group_repo.clone(),
+        )),
+        set_tld_policy: Arc::new(SetTldPolicyUseCase::new(
+            tld_repo.clone(),
+            group_repo.clone(),
+            block_filter.clone(),
+        )),
+        delete_tld_policy: Arc::new(DeleteTldPolicyUseCase::new(
+            tld_repo,
+            group_repo,
+            block_filter,
+        )),
+        get_rewrites: Arc::new(GetDnsRewritesUseCase::new(rewrite_repo.clone())),
+        create_rewrite: Arc::new(CreateDnsRewriteUseCase::new(
+            rewrite_repo.clone(),
+            rewrite_engine.clone(),
+        )),
+        update_rewrite: Arc::new(UpdateDnsRewriteUseCase::new(
+            rewrite_repo.clone(),
+            rewrite_engine.clone(),
+        )),
+        delete_rewrite: Arc::new(DeleteDnsRewriteUseCase::new(rewrite_repo, rewrite_engine)),
+        get_ttl_overrides: Arc::new(GetCacheTtlOverridesUseCase::new(ttl_repo.clone())),
+        create_ttl_override: Arc::new(CreateCacheTtlOverrideUseCase::new(
+            ttl_repo.clone(),
+            ttl_engine.clone(),
+        )),
+        update_ttl_override: Arc::new(UpdateCacheTtlOverrideUseCase::new(
+            ttl_repo.clone(),
+            ttl_engine.clone(),
+        )),
+        delete_ttl_override: Arc::new(DeleteCacheTtlOverrideUseCase::new(ttl_repo, ttl_engine)),
+    }
+}
@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{DnsResolution, DnsResolver};
+use ferrous_dns_application::use_cases::HandleDnsQueryUseCase;
+use ferrous_dns_domain::{config::DatabaseConfig, DnsQuery, DomainError, RecordType};
+use ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository;
+use sqlx::SqlitePool;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::sync::Arc;
+
+use super::mock_tenants::NullBlockFilterEngine;
+
+/// Answers every A/AAAA query with a documentation address, as if from one
+/// upstream server.
+pub struct FixedDnsResolver;
+
+#[async_trait]
+impl DnsResolver for FixedDnsResolver {
+    async fn resolve(&self, query: &DnsQuery) -> Result<DnsResolution, DomainError> {
+        let address = match query.record_type {
+            RecordType::A => IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)),
+            RecordType::AAAA => IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)),
+            _ => return Err(DomainError::NxDomain),
+        };
+        Ok(DnsResolution {
+            upstream_server: Some(Arc::from("udp://192.0.2.53:53")),
+            upstream_pool: Some(Arc::from("primary")),
+            min_ttl: Some(300),
+            ..DnsResolution::new(vec![address], false)
+        })
+    }
+}
+
+/// Query handler answering from [`FixedDnsResolver`] with nothing blocked.
+pub fn build_test_resolve_query(pool: SqlitePool) -> Arc<HandleDnsQueryUseCase> {
+    Arc::new(HandleDnsQueryUseCase::new(
+        Arc::new(FixedDnsResolver),
+        Arc::new(NullBlockFilterEngine),
+        Arc::new(SqliteQueryLogRepository::new(
+            pool.clone(),
+            pool.clone(),
+            pool,
+            &DatabaseConfig::default(),
+        )),
+    ))
+}
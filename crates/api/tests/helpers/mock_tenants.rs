@@ -0,0 +1,119 @@
+#![allow(dead_code)]
+
+use ferrous_dns_api::TenantUseCases;
+use ferrous_dns_application::ports::{
+    BlockFilterEnginePort, BlocklistSourceRepository, FilterDecision, GroupRepository,
+    TenantRepository,
+};
+use ferrous_dns_application::use_cases::{
+    CreateTenantGroupUseCase, CreateTenantUseCase, DeleteTenantUseCase, GetTenantsUseCase,
+    TenantBlocklistSourcesUseCase, UpdateTenantUseCase,
+};
+use ferrous_dns_domain::{DomainError, Tenant, TenantStats};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use super::mock_backup::{NullBlocklistSourceRepository, NullGroupRepository};
+use super::mock_local_records::NullLocalZone;
+
+/// Repository with no tenants; writes are rejected.
+pub struct NullTenantRepository;
+
+#[async_trait::async_trait]
+impl TenantRepository for NullTenantRepository {
+    async fn create(&self, _tenant: &Tenant) -> Result<Tenant, DomainError> {
+        Err(DomainError::IoError("test stub".to_string()))
+    }
+    async fn get_by_id(&self, _id: i64) -> Result<Option<Tenant>, DomainError> {
+        Ok(None)
+    }
+    async fn get_all(&self) -> Result<Vec<Tenant>, DomainError> {
+        Ok(vec![])
+    }
+    async fn update(&self, tenant: &Tenant) -> Result<Tenant, DomainError> {
+        Err(DomainError::TenantNotFound(tenant.id.unwrap_or(0)))
+    }
+    async fn delete(&self, id: i64) -> Result<(), DomainError> {
+        Err(DomainError::TenantNotFound(id))
+    }
+    async fn assign_group(&self, tenant_id: i64, _group_id: i64) -> Result<(), DomainError> {
+        Err(DomainError::TenantNotFound(tenant_id))
+    }
+    async fn get_group_ids(&self, _tenant_id: i64) -> Result<Vec<i64>, DomainError> {
+        Ok(vec![])
+    }
+    async fn get_stats(
+        &self,
+        _tenant_id: i64,
+        _period_hours: f32,
+    ) -> Result<TenantStats, DomainError> {
+        Ok(TenantStats::default())
+    }
+}
+
+/// Block filter that allows everything and reloads instantly.
+pub struct NullBlockFilterEngine;
+
+#[async_trait::async_trait]
+impl BlockFilterEnginePort for NullBlockFilterEngine {
+    fn resolve_group(&self, _ip: IpAddr) -> i64 {
+        1
+    }
+    fn resolve_group_with_default(&self, _ip: IpAddr, _default_group_id: i64) -> i64 {
+        1
+    }
+    fn check(&self, _domain: &str, _group_id: i64) -> FilterDecision {
+        FilterDecision::Allow
+    }
+    fn store_cname_decision(&self, _domain: &str, _group_id: i64, _ttl_secs: u64) {}
+    async fn reload(&self) -> Result<(), DomainError> {
+        Ok(())
+    }
+    async fn load_client_groups(&self) -> Result<(), DomainError> {
+        Ok(())
+    }
+    fn compiled_domain_count(&self) -> usize {
+        0
+    }
+    fn is_blocking_enabled(&self) -> bool {
+        true
+    }
+    fn set_blocking_enabled(&self, _enabled: bool) {}
+}
+
+/// Tenant use cases over a store with no tenants, for tests of other routes.
+pub fn build_test_tenant_use_cases() -> TenantUseCases {
+    let tenant_repo: Arc<dyn TenantRepository> = Arc::new(NullTenantRepository);
+    let group_repo: Arc<dyn GroupRepository> = Arc::new(NullGroupRepository);
+    let block_filter: Arc<dyn BlockFilterEnginePort> = Arc::new(NullBlockFilterEngine);
+    let source_repo: Arc<dyn BlocklistSourceRepository> = Arc::new(NullBlocklistSourceRepository);
+
+    TenantUseCases {
+        get_tenants: Arc::new(GetTenantsUseCase::new(
+            tenant_repo.clone(),
+            group_repo.clone(),
+        )),
+        create_tenant: Arc::new(CreateTenantUseCase::new(
+            tenant_repo.clone(),
+            group_repo.clone(),
+            block_filter.clone(),
+        )),
+        update_tenant: Arc::new(UpdateTenantUseCase::new(
+            tenant_repo.clone(),
+            block_filter.clone(),
+        )),
+        delete_tenant: Arc::new(DeleteTenantUseCase::new(
+            tenant_repo.clone(),
+            group_repo.clone(),
+            source_repo.clone(),
+            block_filter.clone(),
+            Arc::new(NullLocalZone),
+        )),
+        create_tenant_group: Arc::new(CreateTenantGroupUseCase::new(
+            tenant_repo.clone(),
+            group_repo,
+            block_filter,
+        )),
+        blocklist_sources: Arc::new(TenantBlocklistSourcesUseCase::new(tenant_repo, source_repo)),
+    }
+}
@@ -1,8 +1,20 @@
 #![allow(unused_imports)]
 pub mod mock_auth;
 pub mod mock_backup;
+pub mod mock_dnssec;
+pub mod mock_local_records;
+pub mod mock_log_level;
+pub mod mock_query_policy;
+pub mod mock_resolver;
+pub mod mock_tenants;
 pub mod mock_tls;
 
 pub use mock_auth::build_test_auth_use_cases;
 pub use mock_backup::build_test_backup_use_cases;
+pub use mock_dnssec::{build_test_dnssec_stats, build_test_dnssec_status};
+pub use mock_local_records::{NullLocalRecordRepository, NullLocalZone};
+pub use mock_log_level::MockLogLevelControl;
+pub use mock_query_policy::build_test_query_policy_use_cases;
+pub use mock_resolver::{build_test_resolve_query, FixedDnsResolver};
+pub use mock_tenants::build_test_tenant_use_cases;
 pub use mock_tls::MockTlsCertificateService;
@@ -0,0 +1,841 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use ferrous_dns_api::{
+    create_api_routes, AppState, BlockingUseCases, ClientUseCases, DnsUseCases, GroupUseCases,
+    QueryUseCases, SafeSearchUseCases, ScheduleUseCases, ServiceUseCases,
+};
+use ferrous_dns_application::{
+    ports::{
+        BlockFilterEnginePort, BlockedServiceRepository, FilterDecision,
+        SafeSearchConfigRepository, SafeSearchEnginePort, ServiceCatalogPort,
+    },
+    services::SubnetMatcherService,
+    use_cases::{
+        AssignScheduleProfileUseCase, CreateScheduleProfileUseCase, DeleteScheduleProfileUseCase,
+        GetBlockFilterStatsUseCase, GetScheduleProfilesUseCase, ManageTimeSlotsUseCase,
+        UpdateScheduleProfileUseCase, *,
+    },
+};
+
+struct NullBlockFilterEngine;
+
+#[async_trait::async_trait]
+impl BlockFilterEnginePort for NullBlockFilterEngine {
+    fn resolve_group(&self, _ip: std::net::IpAddr) -> i64 {
+        1
+    }
+    fn resolve_group_with_default(&self, _ip: std::net::IpAddr, _default_group_id: i64) -> i64 {
+        1
+    }
+    fn check(&self, _domain: &str, _group_id: i64) -> FilterDecision {
+        FilterDecision::Allow
+    }
+    async fn reload(&self) -> Result<(), ferrous_dns_domain::DomainError> {
+        Ok(())
+    }
+    async fn load_client_groups(&self) -> Result<(), ferrous_dns_domain::DomainError> {
+        Ok(())
+    }
+    fn compiled_domain_count(&self) -> usize {
+        0
+    }
+    fn store_cname_decision(&self, _domain: &str, _group_id: i64, _ttl_secs: u64) {}
+    fn is_blocking_enabled(&self) -> bool {
+        true
+    }
+    fn set_blocking_enabled(&self, _enabled: bool) {}
+}
+
+struct NullBlockedServiceRepository;
+
+#[async_trait::async_trait]
+impl BlockedServiceRepository for NullBlockedServiceRepository {
+    async fn block_service(
+        &self,
+        _service_id: &str,
+        _group_id: i64,
+    ) -> Result<ferrous_dns_domain::BlockedService, ferrous_dns_domain::DomainError> {
+        unimplemented!()
+    }
+    async fn unblock_service(
+        &self,
+        _service_id: &str,
+        _group_id: i64,
+    ) -> Result<(), ferrous_dns_domain::DomainError> {
+        Ok(())
+    }
+    async fn get_blocked_for_group(
+        &self,
+        _group_id: i64,
+    ) -> Result<Vec<ferrous_dns_domain::BlockedService>, ferrous_dns_domain::DomainError> {
+        Ok(vec![])
+    }
+    async fn get_all_blocked(
+        &self,
+    ) -> Result<Vec<ferrous_dns_domain::BlockedService>, ferrous_dns_domain::DomainError> {
+        Ok(vec![])
+    }
+    async fn delete_all_for_service(
+        &self,
+        _service_id: &str,
+    ) -> Result<u64, ferrous_dns_domain::DomainError> {
+        Ok(0)
+    }
+}
+
+struct NullCustomServiceRepository;
+
+#[async_trait::async_trait]
+impl ferrous_dns_application::ports::CustomServiceRepository for NullCustomServiceRepository {
+    async fn create(
+        &self,
+        _service_id: &str,
+        _name: &str,
+        _category_name: &str,
+        _domains: &[String],
+    ) -> Result<ferrous_dns_domain::CustomService, ferrous_dns_domain::DomainError> {
+        unimplemented!()
+    }
+    async fn get_by_service_id(
+        &self,
+        _service_id: &str,
+    ) -> Result<Option<ferrous_dns_domain::CustomService>, ferrous_dns_domain::DomainError> {
+        Ok(None)
+    }
+    async fn get_all(
+        &self,
+    ) -> Result<Vec<ferrous_dns_domain::CustomService>, ferrous_dns_domain::DomainError> {
+        Ok(vec![])
+    }
+    async fn update(
+        &self,
+        _service_id: &str,
+        _name: Option<String>,
+        _category_name: Option<String>,
+        _domains: Option<Vec<String>>,
+    ) -> Result<ferrous_dns_domain::CustomService, ferrous_dns_domain::DomainError> {
+        unimplemented!()
+    }
+    async fn delete(&self, _service_id: &str) -> Result<(), ferrous_dns_domain::DomainError> {
+        Ok(())
+    }
+}
+
+struct NullServiceCatalog;
+
+impl ServiceCatalogPort for NullServiceCatalog {
+    fn get_by_id(&self, _id: &str) -> Option<ferrous_dns_domain::ServiceDefinition> {
+        None
+    }
+    fn all(&self) -> Vec<ferrous_dns_domain::ServiceDefinition> {
+        vec![]
+    }
+    fn normalized_rules_for(&self, _service_id: &str) -> Vec<String> {
+        vec![]
+    }
+    fn reload_custom(&self, _custom: Vec<ferrous_dns_domain::ServiceDefinition>) {}
+}
+struct NullSafeSearchConfigRepository;
+#[async_trait::async_trait]
+impl SafeSearchConfigRepository for NullSafeSearchConfigRepository {
+    async fn get_all(
+        &self,
+    ) -> Result<Vec<ferrous_dns_domain::SafeSearchConfig>, ferrous_dns_domain::DomainError> {
+        Ok(vec![])
+    }
+    async fn get_by_group(
+        &self,
+        _group_id: i64,
+    ) -> Result<Vec<ferrous_dns_domain::SafeSearchConfig>, ferrous_dns_domain::DomainError> {
+        Ok(vec![])
+    }
+    async fn upsert(
+        &self,
+        _group_id: i64,
+        _engine: ferrous_dns_domain::SafeSearchEngine,
+        _enabled: bool,
+        _youtube_mode: ferrous_dns_domain::YouTubeMode,
+    ) -> Result<ferrous_dns_domain::SafeSearchConfig, ferrous_dns_domain::DomainError> {
+        unimplemented!()
+    }
+    async fn delete_by_group(&self, _group_id: i64) -> Result<(), ferrous_dns_domain::DomainError> {
+        Ok(())
+    }
+}
+
+struct NullSafeSearchEnginePort;
+#[async_trait::async_trait]
+impl SafeSearchEnginePort for NullSafeSearchEnginePort {
+    fn cname_for(&self, _domain: &str, _group_id: i64) -> Option<&'static str> {
+        None
+    }
+    async fn reload(&self) -> Result<(), ferrous_dns_domain::DomainError> {
+        Ok(())
+    }
+}
+
+use ferrous_dns_domain::{config::DatabaseConfig, Config};
+use ferrous_dns_infrastructure::{
+    dns::cache::DnsCache,
+    repositories::{
+        blocklist_source_repository::SqliteBlocklistSourceRepository,
+        client_repository::SqliteClientRepository,
+        client_subnet_repository::SqliteClientSubnetRepository,
+        group_repository::SqliteGroupRepository,
+        regex_filter_repository::SqliteRegexFilterRepository,
+        whitelist_repository::SqliteWhitelistRepository,
+        whitelist_source_repository::SqliteWhitelistSourceRepository,
+    },
+};
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use sqlx::sqlite::SqlitePoolOptions;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tower::ServiceExt;
+
+mod helpers;
+
+struct NullScheduleProfileRepository;
+
+#[async_trait::async_trait]
+impl ferrous_dns_application::ports::ScheduleProfileRepository for NullScheduleProfileRepository {
+    async fn create(
+        &self,
+        _name: String,
+        _tz: String,
+        _comment: Option<String>,
+    ) -> Result<ferrous_dns_domain::ScheduleProfile, ferrous_dns_domain::DomainError> {
+        unimplemented!()
+    }
+    async fn get_by_id(
+        &self,
+        _id: i64,
+    ) -> Result<Option<ferrous_dns_domain::ScheduleProfile>, ferrous_dns_domain::DomainError> {
+        Ok(None)
+    }
+    async fn get_all(
+        &self,
+    ) -> Result<Vec<ferrous_dns_domain::ScheduleProfile>, ferrous_dns_domain::DomainError> {
+        Ok(vec![])
+    }
+    async fn update(
+        &self,
+        _id: i64,
+        _name: Option<String>,
+        _tz: Option<String>,
+        _comment: Option<String>,
+    ) -> Result<ferrous_dns_domain::ScheduleProfile, ferrous_dns_domain::DomainError> {
+        unimplemented!()
+    }
+    async fn delete(&self, _id: i64) -> Result<(), ferrous_dns_domain::DomainError> {
+        Ok(())
+    }
+    async fn get_slots(
+        &self,
+        _profile_id: i64,
+    ) -> Result<Vec<ferrous_dns_domain::TimeSlot>, ferrous_dns_domain::DomainError> {
+        Ok(vec![])
+    }
+    async fn add_slot(
+        &self,
+        _pid: i64,
+        _days: u8,
+        _start: String,
+        _end: String,
+        _action: ferrous_dns_domain::ScheduleAction,
+    ) -> Result<ferrous_dns_domain::TimeSlot, ferrous_dns_domain::DomainError> {
+        unimplemented!()
+    }
+    async fn delete_slot(&self, _slot_id: i64) -> Result<(), ferrous_dns_domain::DomainError> {
+        Ok(())
+    }
+    async fn assign_to_group(
+        &self,
+        _group_id: i64,
+        _profile_id: i64,
+    ) -> Result<(), ferrous_dns_domain::DomainError> {
+        Ok(())
+    }
+    async fn unassign_from_group(
+        &self,
+        _group_id: i64,
+    ) -> Result<(), ferrous_dns_domain::DomainError> {
+        Ok(())
+    }
+    async fn get_group_assignment(
+        &self,
+        _group_id: i64,
+    ) -> Result<Option<i64>, ferrous_dns_domain::DomainError> {
+        Ok(None)
+    }
+    async fn get_all_group_assignments(
+        &self,
+    ) -> Result<Vec<(i64, i64)>, ferrous_dns_domain::DomainError> {
+        Ok(vec![])
+    }
+}
+
+async fn create_test_db() -> sqlx::SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE groups (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL UNIQUE,
+            enabled BOOLEAN NOT NULL DEFAULT 1,
+            comment TEXT,
+            is_default BOOLEAN NOT NULL DEFAULT 0,
+            filter_aaaa BOOLEAN NOT NULL DEFAULT 0,
+            audit_mode  BOOLEAN NOT NULL DEFAULT 0,
+            tenant_id INTEGER,
+            created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        "INSERT INTO groups (id, name, is_default) VALUES (1, 'Protected', 1), (2, 'Office', 0)",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE clients (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            ip_address TEXT NOT NULL UNIQUE,
+            mac_address TEXT,
+            hostname TEXT,
+            first_seen DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            last_seen DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            query_count INTEGER NOT NULL DEFAULT 0,
+            last_mac_update DATETIME,
+            last_hostname_update DATETIME,
+            group_id INTEGER NOT NULL DEFAULT 1 REFERENCES groups(id) ON DELETE RESTRICT,
+            created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            updated_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            display_name TEXT,
+            category TEXT,
+            notes TEXT
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE client_subnets (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            subnet_cidr TEXT NOT NULL UNIQUE,
+            group_id INTEGER NOT NULL,
+            comment TEXT,
+            created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            updated_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            FOREIGN KEY (group_id) REFERENCES groups(id) ON DELETE CASCADE
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE blocklist_sources (
+            id          INTEGER PRIMARY KEY AUTOINCREMENT,
+            name        TEXT    NOT NULL UNIQUE,
+            url         TEXT,
+            group_id    INTEGER NOT NULL DEFAULT 1 REFERENCES groups(id) ON DELETE RESTRICT,
+            comment     TEXT,
+            enabled     BOOLEAN NOT NULL DEFAULT 1,
+            audit_mode  BOOLEAN NOT NULL DEFAULT 0,
+            tenant_id   INTEGER,
+            created_at  DATETIME DEFAULT CURRENT_TIMESTAMP,
+            updated_at  DATETIME DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE whitelist (
+            id       INTEGER PRIMARY KEY AUTOINCREMENT,
+            domain   TEXT NOT NULL UNIQUE,
+            added_at DATETIME DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE whitelist_sources (
+            id         INTEGER PRIMARY KEY AUTOINCREMENT,
+            name       TEXT    NOT NULL UNIQUE,
+            url        TEXT,
+            group_id   INTEGER NOT NULL DEFAULT 1 REFERENCES groups(id) ON DELETE RESTRICT,
+            comment    TEXT,
+            enabled    BOOLEAN NOT NULL DEFAULT 1,
+            created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE whitelist_source_groups (
+            source_id INTEGER NOT NULL REFERENCES whitelist_sources(id) ON DELETE CASCADE,
+            group_id  INTEGER NOT NULL REFERENCES groups(id)            ON DELETE CASCADE,
+            PRIMARY KEY (source_id, group_id)
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE regex_filters (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL UNIQUE,
+            pattern TEXT NOT NULL,
+            action TEXT NOT NULL CHECK(action IN ('allow', 'deny')),
+            group_id INTEGER NOT NULL DEFAULT 1,
+            comment TEXT,
+            enabled INTEGER NOT NULL DEFAULT 1,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(include_str!(
+        "../../../migrations/20260312000001_create_ip_blocklist_sources.sql"
+    ))
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    pool
+}
+
+async fn create_test_app() -> (Router, sqlx::SqlitePool) {
+    let pool = create_test_db().await;
+
+    let client_repo = Arc::new(SqliteClientRepository::new(
+        pool.clone(),
+        &DatabaseConfig::default(),
+    ));
+    let group_repo = Arc::new(SqliteGroupRepository::new(pool.clone()));
+    let subnet_repo = Arc::new(SqliteClientSubnetRepository::new(pool.clone()));
+    let blocklist_source_repo = Arc::new(SqliteBlocklistSourceRepository::new(pool.clone()));
+    let whitelist_repo = Arc::new(SqliteWhitelistRepository::new(pool.clone()));
+    let whitelist_source_repo = Arc::new(SqliteWhitelistSourceRepository::new(pool.clone()));
+    let regex_filter_repo = Arc::new(SqliteRegexFilterRepository::new(pool.clone()));
+
+    let config = Arc::new(RwLock::new(Config::default()));
+    let cache = Arc::new(DnsCache::new(
+        ferrous_dns_infrastructure::dns::DnsCacheConfig {
+            max_entries: 0,
+            eviction_strategy: ferrous_dns_infrastructure::dns::EvictionStrategy::LRU,
+            min_threshold: 0.0,
+            refresh_threshold: 0.0,
+            batch_eviction_percentage: 0.0,
+            adaptive_thresholds: false,
+            min_frequency: 0,
+            min_lfuk_score: 0.0,
+            shard_amount: 4,
+            access_window_secs: 7200,
+            eviction_sample_size: 8,
+            lfuk_k_value: 0.5,
+            refresh_sample_rate: 1.0,
+            min_ttl: 0,
+            max_ttl: 86_400,
+            l1_capacity: 1024,
+        },
+    ));
+
+    use ferrous_dns_domain::config::upstream::{UdpSocketMode, UpstreamPool, UpstreamStrategy};
+    use ferrous_dns_infrastructure::dns::{PoolManager, QueryEventEmitter};
+
+    let event_emitter = QueryEventEmitter::new_disabled();
+    let test_pool = UpstreamPool {
+        name: "test".to_string(),
+        strategy: UpstreamStrategy::Parallel,
+        priority: 1,
+        servers: vec!["8.8.8.8:53".to_string()],
+        weight: None,
+        tsig_key: None,
+        udp_socket: UdpSocketMode::Pooled,
+    };
+
+    let pool_manager = Arc::new(
+        PoolManager::new(vec![test_pool], None, event_emitter)
+            .await
+            .expect("Failed to create PoolManager"),
+    );
+
+    let state = AppState {
+        query: QueryUseCases {
+            get_stats: Arc::new(GetQueryStatsUseCase::new(Arc::new(
+                ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &DatabaseConfig::default()),
+            ), client_repo.clone())),
+            get_queries: Arc::new(GetRecentQueriesUseCase::new(Arc::new(
+                ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &DatabaseConfig::default()),
+            ))),
+            get_timeline: Arc::new(GetTimelineUseCase::new(Arc::new(
+                ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &DatabaseConfig::default()),
+            ))),
+            get_query_rate: Arc::new(GetQueryRateUseCase::new(Arc::new(
+                ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &DatabaseConfig::default()),
+            ))),
+            get_cache_stats: Arc::new(GetCacheStatsUseCase::new(Arc::new(
+                ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &DatabaseConfig::default()),
+            ))),
+            get_top_blocked_domains: Arc::new(ferrous_dns_application::use_cases::GetTopBlockedDomainsUseCase::new(Arc::new(
+                ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &DatabaseConfig::default()),
+            ))),
+            get_top_clients: Arc::new(ferrous_dns_application::use_cases::GetTopClientsUseCase::new(Arc::new(
+                ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &DatabaseConfig::default()),
+            ))),
+            database_maintenance: Arc::new(ferrous_dns_application::use_cases::DatabaseMaintenanceUseCase::new(Arc::new(ferrous_dns_infrastructure::database::SqliteDatabaseMaintenance::new(pool.clone())))),
+            get_alerts: Arc::new(ferrous_dns_application::use_cases::GetAlertsUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteAlertRepository::new(pool.clone())))),
+            delete_alert: Arc::new(ferrous_dns_application::use_cases::DeleteAlertUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteAlertRepository::new(pool.clone())))),
+            get_audit_log: Arc::new(ferrous_dns_application::use_cases::GetAuditLogUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteAuditLogRepository::new(pool.clone())))),
+            live_metrics: Arc::new(ferrous_dns_infrastructure::dns::QueryMetrics::new()),
+        },
+        dns: DnsUseCases {
+            cache: cache.clone() as Arc<dyn ferrous_dns_application::ports::DnsCachePort>,
+            create_local_record: Arc::new(CreateLocalRecordUseCase::new(config.clone(), Arc::new(helpers::NullLocalRecordRepository), Arc::new(helpers::NullLocalZone))),
+            update_local_record: Arc::new(UpdateLocalRecordUseCase::new(config.clone(), Arc::new(helpers::NullLocalRecordRepository), Arc::new(helpers::NullLocalZone))),
+            get_local_records: Arc::new(GetLocalRecordsUseCase::new(Arc::new(helpers::NullLocalRecordRepository))),
+            delete_local_record: Arc::new(DeleteLocalRecordUseCase::new(config.clone(), Arc::new(helpers::NullLocalRecordRepository), Arc::new(helpers::NullLocalZone))),
+            upstream_health: Arc::new(ferrous_dns_infrastructure::dns::UpstreamHealthAdapter::new(
+                pool_manager.clone(),
+                None,
+            )),
+            access_control: Arc::new(ferrous_dns_infrastructure::dns::AccessControlRegistry::new()),
+            sinkhole_telemetry: Arc::new(ferrous_dns_infrastructure::dns::SinkholeTelemetry::new()),
+            conditional_forwards: Arc::new(
+                ferrous_dns_infrastructure::dns::resolver::ConditionalForwards::new(&[]),
+            ),
+            slow_query_log: Arc::new(ferrous_dns_infrastructure::dns::SlowQueryLog::new(100)),
+            inflight: Arc::new(ferrous_dns_infrastructure::dns::InflightRegistry::new(4)),
+            diagnose_domain: Arc::new(ferrous_dns_application::use_cases::DiagnoseDomainUseCase::new(
+                Arc::new(NullBlockFilterEngine),
+                cache.clone(),
+                Arc::new(ferrous_dns_infrastructure::dns::UpstreamHealthAdapter::new(pool_manager.clone(), None)),
+            )),
+            trace_resolve: Arc::new(ferrous_dns_application::use_cases::TraceResolveUseCase::new(
+                Arc::new(ferrous_dns_application::use_cases::DiagnoseDomainUseCase::new(
+                    Arc::new(NullBlockFilterEngine),
+                    cache,
+                    Arc::new(ferrous_dns_infrastructure::dns::UpstreamHealthAdapter::new(pool_manager, None)),
+                )),
+                Arc::new(helpers::FixedDnsResolver),
+            )),
+            get_dnssec_status: helpers::build_test_dnssec_status(pool.clone()),
+            get_dnssec_stats: helpers::build_test_dnssec_stats(pool.clone()),
+            resolve_query: helpers::build_test_resolve_query(pool.clone()),
+            faults: None,
+        },
+        groups: GroupUseCases {
+            get_groups: Arc::new(GetGroupsUseCase::new(group_repo.clone())),
+            create_group: Arc::new(CreateGroupUseCase::new(group_repo.clone())),
+            update_group: Arc::new(UpdateGroupUseCase::new(group_repo.clone())),
+            delete_group: Arc::new(DeleteGroupUseCase::new(group_repo.clone())),
+            assign_client_group: Arc::new(AssignClientGroupUseCase::new(client_repo.clone(), group_repo.clone(), Arc::new(NullBlockFilterEngine))),
+        },
+        clients: ClientUseCases {
+            get_clients: Arc::new(GetClientsUseCase::new(client_repo.clone())),
+            get_client_subnets: Arc::new(GetClientSubnetsUseCase::new(subnet_repo.clone())),
+            create_client_subnet: Arc::new(CreateClientSubnetUseCase::new(subnet_repo.clone(), group_repo.clone(), Arc::new(NullBlockFilterEngine))),
+            delete_client_subnet: Arc::new(DeleteClientSubnetUseCase::new(subnet_repo.clone(), Arc::new(NullBlockFilterEngine))),
+            create_manual_client: Arc::new(CreateManualClientUseCase::new(client_repo.clone(), group_repo.clone())),
+            update_client: Arc::new(UpdateClientUseCase::new(client_repo.clone())),
+            delete_client: Arc::new(DeleteClientUseCase::new(client_repo.clone())),
+            get_client_activity: Arc::new(ferrous_dns_application::use_cases::GetClientActivityUseCase::new(
+                client_repo.clone(),
+                Arc::new(ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &DatabaseConfig::default())),
+            )),
+            subnet_matcher: Arc::new(SubnetMatcherService::new(subnet_repo.clone())),
+        },
+        blocking: BlockingUseCases {
+            get_blocklist: Arc::new(GetBlocklistUseCase::new(Arc::new(
+                ferrous_dns_infrastructure::repositories::blocklist_repository::SqliteBlocklistRepository::new(pool.clone()),
+            ))),
+            bulk_add_blocklist: Arc::new(ferrous_dns_application::use_cases::BulkAddBlocklistUseCase::new(
+                Arc::new(ferrous_dns_infrastructure::repositories::blocklist_repository::SqliteBlocklistRepository::new(pool.clone())),
+                Arc::new(NullBlockFilterEngine),
+            )),
+            get_blocklist_sources: Arc::new(GetBlocklistSourcesUseCase::new(blocklist_source_repo.clone())),
+            create_blocklist_source: Arc::new(CreateBlocklistSourceUseCase::new(
+                blocklist_source_repo.clone(),
+                group_repo.clone(),
+            )),
+            update_blocklist_source: Arc::new(UpdateBlocklistSourceUseCase::new(
+                blocklist_source_repo.clone(),
+                group_repo.clone(),
+            )),
+            delete_blocklist_source: Arc::new(DeleteBlocklistSourceUseCase::new(
+                blocklist_source_repo.clone(),
+            )),
+            get_whitelist: Arc::new(GetWhitelistUseCase::new(whitelist_repo.clone())),
+            bulk_add_whitelist: Arc::new(ferrous_dns_application::use_cases::BulkAddWhitelistUseCase::new(
+                Arc::new(ferrous_dns_infrastructure::repositories::whitelist_repository::SqliteWhitelistRepository::new(pool.clone())),
+                Arc::new(NullBlockFilterEngine),
+            )),
+            get_whitelist_sources: Arc::new(GetWhitelistSourcesUseCase::new(whitelist_source_repo.clone())),
+            create_whitelist_source: Arc::new(CreateWhitelistSourceUseCase::new(
+                whitelist_source_repo.clone(),
+                group_repo.clone(),
+            )),
+            update_whitelist_source: Arc::new(UpdateWhitelistSourceUseCase::new(
+                whitelist_source_repo.clone(),
+                group_repo.clone(),
+            )),
+            delete_whitelist_source: Arc::new(DeleteWhitelistSourceUseCase::new(
+                whitelist_source_repo.clone(),
+            )),
+            get_ip_blocklist_sources: Arc::new(ferrous_dns_application::use_cases::GetIpBlocklistSourcesUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteIpBlocklistSourceRepository::new(pool.clone())))),
+            create_ip_blocklist_source: Arc::new(ferrous_dns_application::use_cases::CreateIpBlocklistSourceUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteIpBlocklistSourceRepository::new(pool.clone())))),
+            update_ip_blocklist_source: Arc::new(ferrous_dns_application::use_cases::UpdateIpBlocklistSourceUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteIpBlocklistSourceRepository::new(pool.clone())))),
+            delete_ip_blocklist_source: Arc::new(ferrous_dns_application::use_cases::DeleteIpBlocklistSourceUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteIpBlocklistSourceRepository::new(pool.clone())))),
+            get_managed_domains: Arc::new(ferrous_dns_application::use_cases::GetManagedDomainsUseCase::new(Arc::new(
+                ferrous_dns_infrastructure::repositories::managed_domain_repository::SqliteManagedDomainRepository::new(pool.clone()),
+            ))),
+            create_managed_domain: Arc::new(ferrous_dns_application::use_cases::CreateManagedDomainUseCase::new(
+                Arc::new(ferrous_dns_infrastructure::repositories::managed_domain_repository::SqliteManagedDomainRepository::new(pool.clone())),
+                Arc::new(ferrous_dns_infrastructure::repositories::group_repository::SqliteGroupRepository::new(pool.clone())),
+                Arc::new(NullBlockFilterEngine),
+            )),
+            update_managed_domain: Arc::new(ferrous_dns_application::use_cases::UpdateManagedDomainUseCase::new(
+                Arc::new(ferrous_dns_infrastructure::repositories::managed_domain_repository::SqliteManagedDomainRepository::new(pool.clone())),
+                Arc::new(ferrous_dns_infrastructure::repositories::group_repository::SqliteGroupRepository::new(pool.clone())),
+                Arc::new(NullBlockFilterEngine),
+            )),
+            delete_managed_domain: Arc::new(ferrous_dns_application::use_cases::DeleteManagedDomainUseCase::new(
+                Arc::new(ferrous_dns_infrastructure::repositories::managed_domain_repository::SqliteManagedDomainRepository::new(pool.clone())),
+                Arc::new(NullBlockFilterEngine),
+            )),
+            get_regex_filters: Arc::new(ferrous_dns_application::use_cases::GetRegexFiltersUseCase::new(
+                regex_filter_repo.clone(),
+            )),
+            create_regex_filter: Arc::new(ferrous_dns_application::use_cases::CreateRegexFilterUseCase::new(
+                regex_filter_repo.clone(),
+                group_repo.clone(),
+                Arc::new(NullBlockFilterEngine),
+            )),
+            update_regex_filter: Arc::new(ferrous_dns_application::use_cases::UpdateRegexFilterUseCase::new(
+                regex_filter_repo.clone(),
+                group_repo.clone(),
+                Arc::new(NullBlockFilterEngine),
+            )),
+            delete_regex_filter: Arc::new(ferrous_dns_application::use_cases::DeleteRegexFilterUseCase::new(
+                regex_filter_repo.clone(),
+                Arc::new(NullBlockFilterEngine),
+            )),
+            get_block_filter_stats: Arc::new(GetBlockFilterStatsUseCase::new(Arc::new(NullBlockFilterEngine))),
+            get_unblock_requests: Arc::new(ferrous_dns_application::use_cases::GetUnblockRequestsUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository::new(pool.clone())))),
+            submit_unblock_request: Arc::new(ferrous_dns_application::use_cases::SubmitUnblockRequestUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository::new(pool.clone())), Arc::new(NullBlockFilterEngine))),
+            approve_unblock_request: Arc::new(ferrous_dns_application::use_cases::ApproveUnblockRequestUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::repositories::managed_domain_repository::SqliteManagedDomainRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::repositories::group_repository::SqliteGroupRepository::new(pool.clone())), Arc::new(NullBlockFilterEngine))),
+            deny_unblock_request: Arc::new(ferrous_dns_application::use_cases::DenyUnblockRequestUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository::new(pool.clone())))),
+            delete_unblock_request: Arc::new(ferrous_dns_application::use_cases::DeleteUnblockRequestUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository::new(pool.clone())))),
+        },
+        services: ServiceUseCases {
+            get_service_catalog: Arc::new(GetServiceCatalogUseCase::new(Arc::new(NullServiceCatalog))),
+            get_blocked_services: Arc::new(GetBlockedServicesUseCase::new(Arc::new(NullBlockedServiceRepository))),
+            block_service: Arc::new(BlockServiceUseCase::new(
+                Arc::new(NullBlockedServiceRepository),
+                Arc::new(ferrous_dns_infrastructure::repositories::managed_domain_repository::SqliteManagedDomainRepository::new(pool.clone())),
+                group_repo.clone(),
+                Arc::new(NullBlockFilterEngine),
+                Arc::new(NullServiceCatalog),
+            )),
+            unblock_service: Arc::new(UnblockServiceUseCase::new(
+                Arc::new(NullBlockedServiceRepository),
+                Arc::new(ferrous_dns_infrastructure::repositories::managed_domain_repository::SqliteManagedDomainRepository::new(pool.clone())),
+                Arc::new(NullBlockFilterEngine),
+            )),
+            create_custom_service: Arc::new(ferrous_dns_application::use_cases::CreateCustomServiceUseCase::new(Arc::new(NullCustomServiceRepository), Arc::new(NullServiceCatalog))),
+            get_custom_services: Arc::new(ferrous_dns_application::use_cases::GetCustomServicesUseCase::new(Arc::new(NullCustomServiceRepository))),
+            update_custom_service: Arc::new(ferrous_dns_application::use_cases::UpdateCustomServiceUseCase::new(Arc::new(NullCustomServiceRepository), Arc::new(NullServiceCatalog), Arc::new(ferrous_dns_infrastructure::repositories::managed_domain_repository::SqliteManagedDomainRepository::new(pool.clone())), Arc::new(NullBlockedServiceRepository), Arc::new(NullBlockFilterEngine))),
+            delete_custom_service: Arc::new(ferrous_dns_application::use_cases::DeleteCustomServiceUseCase::new(Arc::new(NullCustomServiceRepository), Arc::new(NullServiceCatalog), Arc::new(NullBlockedServiceRepository), Arc::new(ferrous_dns_infrastructure::repositories::managed_domain_repository::SqliteManagedDomainRepository::new(pool.clone())), Arc::new(NullBlockFilterEngine))),
+        },
+        safe_search: SafeSearchUseCases {
+            get_configs: Arc::new(GetSafeSearchConfigsUseCase::new(
+                Arc::new(NullSafeSearchConfigRepository),
+                group_repo.clone(),
+            )),
+            toggle: Arc::new(ToggleSafeSearchUseCase::new(
+                Arc::new(NullSafeSearchConfigRepository),
+                group_repo.clone(),
+                Arc::new(NullSafeSearchEnginePort),
+            )),
+            delete_configs: Arc::new(DeleteSafeSearchConfigsUseCase::new(
+                Arc::new(NullSafeSearchConfigRepository),
+                group_repo.clone(),
+                Arc::new(NullSafeSearchEnginePort),
+            )),
+        },
+        schedule: ScheduleUseCases {
+            get_profiles: Arc::new(GetScheduleProfilesUseCase::new(Arc::new(NullScheduleProfileRepository))),
+            create_profile: Arc::new(CreateScheduleProfileUseCase::new(Arc::new(NullScheduleProfileRepository))),
+            update_profile: Arc::new(UpdateScheduleProfileUseCase::new(Arc::new(NullScheduleProfileRepository))),
+            delete_profile: Arc::new(DeleteScheduleProfileUseCase::new(Arc::new(NullScheduleProfileRepository))),
+            manage_slots: Arc::new(ManageTimeSlotsUseCase::new(Arc::new(NullScheduleProfileRepository))),
+            assign_profile: Arc::new(AssignScheduleProfileUseCase::new(Arc::new(NullScheduleProfileRepository), group_repo.clone())),
+        },
+        policies: helpers::build_test_query_policy_use_cases(group_repo.clone()),
+        auth: helpers::build_test_auth_use_cases(),
+        backup: helpers::build_test_backup_use_cases(config.clone()),
+        tenants: helpers::build_test_tenant_use_cases(),
+        config: config.clone(),
+        config_file_persistence: Arc::new(ferrous_dns_infrastructure::repositories::TomlConfigFilePersistence),
+        config_path: None,
+        tls_cert: Arc::new(helpers::MockTlsCertificateService),
+        tls_enabled: false,
+        log_levels: Arc::new(helpers::MockLogLevelControl::default()),
+        system_metrics: Arc::new(ferrous_dns_infrastructure::system::ProcessMetricsCollector::new()),
+    };
+
+    let app = create_api_routes(state);
+    (app, pool)
+}
+
+async fn send(app: Router, method: &str, uri: &str, body: Option<Value>) -> (StatusCode, Value) {
+    let request = Request::builder().uri(uri).method(method);
+    let request = match body {
+        Some(body) => request
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap(),
+        None => request.body(Body::empty()).unwrap(),
+    };
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let json = serde_json::from_slice(&bytes).unwrap_or(Value::Null);
+    (status, json)
+}
+
+#[tokio::test]
+async fn test_get_all_ip_blocklist_sources_empty() {
+    let (app, _pool) = create_test_app().await;
+
+    let (status, json) = send(app, "GET", "/ip-blocklist-sources", None).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json.as_array().unwrap().len(), 0);
+}
+
+#[tokio::test]
+async fn test_create_ip_blocklist_source_success() {
+    let (app, _pool) = create_test_app().await;
+
+    let payload = json!({
+        "name": "Feodo Tracker",
+        "url": "https://feodotracker.abuse.ch/downloads/ipblocklist.txt",
+        "comment": "Botnet C2"
+    });
+    let (status, json) = send(app, "POST", "/ip-blocklist-sources", Some(payload)).await;
+
+    assert_eq!(status, StatusCode::CREATED);
+    assert!(json["id"].is_number());
+    assert_eq!(json["name"], "Feodo Tracker");
+    assert_eq!(
+        json["url"],
+        "https://feodotracker.abuse.ch/downloads/ipblocklist.txt"
+    );
+    assert_eq!(json["comment"], "Botnet C2");
+    assert_eq!(json["enabled"], true);
+}
+
+#[tokio::test]
+async fn test_create_ip_blocklist_source_invalid_url() {
+    let (app, _pool) = create_test_app().await;
+
+    let payload = json!({ "name": "Bad", "url": "ftp://example.com/ips.txt" });
+    let (status, _) = send(app, "POST", "/ip-blocklist-sources", Some(payload)).await;
+
+    assert_eq!(status, StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_create_ip_blocklist_source_duplicate_name() {
+    let (app, _pool) = create_test_app().await;
+
+    let payload =
+        json!({ "name": "Spamhaus DROP", "url": "https://www.spamhaus.org/drop/drop.txt" });
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        "/ip-blocklist-sources",
+        Some(payload.clone()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (status, _) = send(app, "POST", "/ip-blocklist-sources", Some(payload)).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_update_ip_blocklist_source_toggle_enabled() {
+    let (app, _pool) = create_test_app().await;
+
+    let payload =
+        json!({ "name": "SSLBL", "url": "https://sslbl.abuse.ch/blacklist/sslipblacklist.txt" });
+    let (_, created) = send(app.clone(), "POST", "/ip-blocklist-sources", Some(payload)).await;
+    let uri = format!("/ip-blocklist-sources/{}", created["id"]);
+
+    let (status, json) = send(app, "PUT", &uri, Some(json!({ "enabled": false }))).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["enabled"], false);
+    assert_eq!(json["name"], "SSLBL");
+}
+
+#[tokio::test]
+async fn test_delete_ip_blocklist_source() {
+    let (app, _pool) = create_test_app().await;
+
+    let payload =
+        json!({ "name": "SSLBL", "url": "https://sslbl.abuse.ch/blacklist/sslipblacklist.txt" });
+    let (_, created) = send(app.clone(), "POST", "/ip-blocklist-sources", Some(payload)).await;
+    let uri = format!("/ip-blocklist-sources/{}", created["id"]);
+
+    let (status, _) = send(app.clone(), "DELETE", &uri, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, _) = send(app, "GET", &uri, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -9,8 +9,8 @@ use ferrous_dns_api::{
 };
 use ferrous_dns_application::{
     ports::{
-        BlockFilterEnginePort, BlockedServiceRepository, ConfigRepository, FilterDecision,
-        SafeSearchConfigRepository, SafeSearchEnginePort, ServiceCatalogPort,
+        BlockFilterEnginePort, BlockedServiceRepository, FilterDecision, LocalRecordRepository,
+        LocalZonePort, SafeSearchConfigRepository, SafeSearchEnginePort, ServiceCatalogPort,
     },
     services::SubnetMatcherService,
     use_cases::{
@@ -19,13 +19,14 @@ use ferrous_dns_application::{
         UpdateScheduleProfileUseCase, *,
     },
 };
-use ferrous_dns_domain::{config::DatabaseConfig, Config, LocalDnsRecord};
+use ferrous_dns_domain::{config::DatabaseConfig, Config};
 use ferrous_dns_infrastructure::{
-    dns::cache::DnsCache,
+    dns::{cache::DnsCache, LocalZoneStore},
     repositories::{
         client_repository::SqliteClientRepository,
         client_subnet_repository::SqliteClientSubnetRepository,
         group_repository::SqliteGroupRepository,
+        local_record_repository::SqliteLocalRecordRepository,
         managed_domain_repository::SqliteManagedDomainRepository,
         regex_filter_repository::SqliteRegexFilterRepository,
     },
@@ -46,6 +47,9 @@ impl BlockFilterEnginePort for NullBlockFilterEngine {
     fn resolve_group(&self, _ip: std::net::IpAddr) -> i64 {
         1
     }
+    fn resolve_group_with_default(&self, _ip: std::net::IpAddr, _default_group_id: i64) -> i64 {
+        1
+    }
     fn check(&self, _domain: &str, _group_id: i64) -> FilterDecision {
         FilterDecision::Allow
     }
@@ -154,17 +158,6 @@ impl ServiceCatalogPort for NullServiceCatalog {
     }
     fn reload_custom(&self, _custom: Vec<ferrous_dns_domain::ServiceDefinition>) {}
 }
-struct NullConfigRepository;
-#[async_trait::async_trait]
-impl ConfigRepository for NullConfigRepository {
-    async fn save_local_records(
-        &self,
-        _config: &Config,
-    ) -> Result<(), ferrous_dns_domain::DomainError> {
-        Ok(())
-    }
-}
-
 struct NullSafeSearchConfigRepository;
 #[async_trait::async_trait]
 impl SafeSearchConfigRepository for NullSafeSearchConfigRepository {
@@ -298,6 +291,9 @@ async fn create_test_db() -> sqlx::SqlitePool {
             enabled BOOLEAN NOT NULL DEFAULT 1,
             comment TEXT,
             is_default BOOLEAN NOT NULL DEFAULT 0,
+            filter_aaaa BOOLEAN NOT NULL DEFAULT 0,
+            audit_mode  BOOLEAN NOT NULL DEFAULT 0,
+            tenant_id INTEGER,
             created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
             updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
         )
@@ -328,7 +324,10 @@ async fn create_test_db() -> sqlx::SqlitePool {
             last_hostname_update DATETIME,
             group_id INTEGER NOT NULL DEFAULT 1 REFERENCES groups(id) ON DELETE RESTRICT,
             created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
-            updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+            updated_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            display_name TEXT,
+            category TEXT,
+            notes TEXT
         )
         "#,
     )
@@ -392,11 +391,48 @@ async fn create_test_db() -> sqlx::SqlitePool {
     .await
     .unwrap();
 
+    sqlx::query(
+        r#"
+        CREATE TABLE local_records (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            hostname TEXT NOT NULL,
+            domain TEXT,
+            record_type TEXT NOT NULL,
+            value TEXT NOT NULL,
+            ttl INTEGER NOT NULL DEFAULT 300,
+            view TEXT,
+            tenant_id INTEGER,
+            created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        "CREATE UNIQUE INDEX idx_local_records_unique ON local_records(hostname, COALESCE(domain, ''), record_type, value, COALESCE(view, ''), COALESCE(tenant_id, 0))",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
     pool
 }
 
 async fn create_test_app() -> (Router, Arc<RwLock<Config>>) {
+    create_test_app_with_config(Config::default()).await
+}
+
+async fn create_test_app_with_config(config: Config) -> (Router, Arc<RwLock<Config>>) {
     let pool = create_test_db().await;
+    let local_record_repo: Arc<dyn LocalRecordRepository> =
+        Arc::new(SqliteLocalRecordRepository::new(pool.clone()));
+    let local_zone: Arc<dyn LocalZonePort> =
+        LocalZoneStore::new(local_record_repo.clone(), config.dns.local_domain.clone())
+            .await
+            .unwrap();
 
     let client_repo = Arc::new(SqliteClientRepository::new(
         pool.clone(),
@@ -408,7 +444,7 @@ async fn create_test_app() -> (Router, Arc<RwLock<Config>>) {
     let regex_filter_repo = Arc::new(SqliteRegexFilterRepository::new(pool.clone()));
     let null_engine: Arc<dyn BlockFilterEnginePort> = Arc::new(NullBlockFilterEngine);
 
-    let config = Arc::new(RwLock::new(Config::default()));
+    let config = Arc::new(RwLock::new(config));
     let cache = Arc::new(DnsCache::new(
         ferrous_dns_infrastructure::dns::DnsCacheConfig {
             max_entries: 0,
@@ -426,10 +462,11 @@ async fn create_test_app() -> (Router, Arc<RwLock<Config>>) {
             refresh_sample_rate: 1.0,
             min_ttl: 0,
             max_ttl: 86_400,
+            l1_capacity: 1024,
         },
     ));
 
-    use ferrous_dns_domain::config::upstream::{UpstreamPool, UpstreamStrategy};
+    use ferrous_dns_domain::config::upstream::{UdpSocketMode, UpstreamPool, UpstreamStrategy};
     use ferrous_dns_infrastructure::dns::{PoolManager, QueryEventEmitter};
 
     let event_emitter = QueryEventEmitter::new_disabled();
@@ -439,6 +476,8 @@ async fn create_test_app() -> (Router, Arc<RwLock<Config>>) {
         priority: 1,
         servers: vec!["8.8.8.8:53".to_string()],
         weight: None,
+        tsig_key: None,
+        udp_socket: UdpSocketMode::Pooled,
     };
 
     let pool_manager = Arc::new(
@@ -470,16 +509,46 @@ async fn create_test_app() -> (Router, Arc<RwLock<Config>>) {
             get_top_clients: Arc::new(ferrous_dns_application::use_cases::GetTopClientsUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &DatabaseConfig::default()),
             ))),
+            database_maintenance: Arc::new(ferrous_dns_application::use_cases::DatabaseMaintenanceUseCase::new(Arc::new(ferrous_dns_infrastructure::database::SqliteDatabaseMaintenance::new(pool.clone())))),
+            get_alerts: Arc::new(ferrous_dns_application::use_cases::GetAlertsUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteAlertRepository::new(pool.clone())))),
+            delete_alert: Arc::new(ferrous_dns_application::use_cases::DeleteAlertUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteAlertRepository::new(pool.clone())))),
+            get_audit_log: Arc::new(ferrous_dns_application::use_cases::GetAuditLogUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteAuditLogRepository::new(pool.clone())))),
+            live_metrics: Arc::new(ferrous_dns_infrastructure::dns::QueryMetrics::new()),
         },
         dns: DnsUseCases {
-            cache: cache as Arc<dyn ferrous_dns_application::ports::DnsCachePort>,
-            create_local_record: Arc::new(CreateLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))),
-            update_local_record: Arc::new(UpdateLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))),
-            delete_local_record: Arc::new(DeleteLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))),
+            cache: cache.clone() as Arc<dyn ferrous_dns_application::ports::DnsCachePort>,
+            create_local_record: Arc::new(CreateLocalRecordUseCase::new(config.clone(), local_record_repo.clone(), local_zone.clone())),
+            update_local_record: Arc::new(UpdateLocalRecordUseCase::new(config.clone(), local_record_repo.clone(), local_zone.clone())),
+            get_local_records: Arc::new(GetLocalRecordsUseCase::new(local_record_repo.clone())),
+            delete_local_record: Arc::new(DeleteLocalRecordUseCase::new(config.clone(), local_record_repo, local_zone)),
             upstream_health: Arc::new(ferrous_dns_infrastructure::dns::UpstreamHealthAdapter::new(
-                pool_manager,
+                pool_manager.clone(),
                 None,
             )),
+            access_control: Arc::new(ferrous_dns_infrastructure::dns::AccessControlRegistry::new()),
+            sinkhole_telemetry: Arc::new(ferrous_dns_infrastructure::dns::SinkholeTelemetry::new()),
+            conditional_forwards: Arc::new(
+                ferrous_dns_infrastructure::dns::resolver::ConditionalForwards::new(&[]),
+            ),
+            slow_query_log: Arc::new(ferrous_dns_infrastructure::dns::SlowQueryLog::new(100)),
+            inflight: Arc::new(ferrous_dns_infrastructure::dns::InflightRegistry::new(4)),
+            diagnose_domain: Arc::new(ferrous_dns_application::use_cases::DiagnoseDomainUseCase::new(
+                Arc::new(NullBlockFilterEngine),
+                cache.clone(),
+                Arc::new(ferrous_dns_infrastructure::dns::UpstreamHealthAdapter::new(pool_manager.clone(), None)),
+            )),
+            trace_resolve: Arc::new(ferrous_dns_application::use_cases::TraceResolveUseCase::new(
+                Arc::new(ferrous_dns_application::use_cases::DiagnoseDomainUseCase::new(
+                    Arc::new(NullBlockFilterEngine),
+                    cache,
+                    Arc::new(ferrous_dns_infrastructure::dns::UpstreamHealthAdapter::new(pool_manager, None)),
+                )),
+                Arc::new(helpers::FixedDnsResolver),
+            )),
+            get_dnssec_status: helpers::build_test_dnssec_status(pool.clone()),
+            get_dnssec_stats: helpers::build_test_dnssec_stats(pool.clone()),
+            resolve_query: helpers::build_test_resolve_query(pool.clone()),
+            faults: None,
         },
         groups: GroupUseCases {
             get_groups: Arc::new(GetGroupsUseCase::new(group_repo.clone())),
@@ -496,12 +565,20 @@ async fn create_test_app() -> (Router, Arc<RwLock<Config>>) {
             create_manual_client: Arc::new(CreateManualClientUseCase::new(client_repo.clone(), group_repo.clone())),
             update_client: Arc::new(UpdateClientUseCase::new(client_repo.clone())),
             delete_client: Arc::new(DeleteClientUseCase::new(client_repo.clone())),
+            get_client_activity: Arc::new(ferrous_dns_application::use_cases::GetClientActivityUseCase::new(
+                client_repo.clone(),
+                Arc::new(ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &DatabaseConfig::default())),
+            )),
             subnet_matcher: Arc::new(SubnetMatcherService::new(subnet_repo.clone())),
         },
         blocking: BlockingUseCases {
             get_blocklist: Arc::new(GetBlocklistUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::blocklist_repository::SqliteBlocklistRepository::new(pool.clone()),
             ))),
+            bulk_add_blocklist: Arc::new(ferrous_dns_application::use_cases::BulkAddBlocklistUseCase::new(
+                Arc::new(ferrous_dns_infrastructure::repositories::blocklist_repository::SqliteBlocklistRepository::new(pool.clone())),
+                Arc::new(NullBlockFilterEngine),
+            )),
             get_blocklist_sources: Arc::new(GetBlocklistSourcesUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::blocklist_source_repository::SqliteBlocklistSourceRepository::new(pool.clone()),
             ))),
@@ -519,6 +596,10 @@ async fn create_test_app() -> (Router, Arc<RwLock<Config>>) {
             get_whitelist: Arc::new(ferrous_dns_application::use_cases::GetWhitelistUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::whitelist_repository::SqliteWhitelistRepository::new(pool.clone()),
             ))),
+            bulk_add_whitelist: Arc::new(ferrous_dns_application::use_cases::BulkAddWhitelistUseCase::new(
+                Arc::new(ferrous_dns_infrastructure::repositories::whitelist_repository::SqliteWhitelistRepository::new(pool.clone())),
+                Arc::new(NullBlockFilterEngine),
+            )),
             get_whitelist_sources: Arc::new(ferrous_dns_application::use_cases::GetWhitelistSourcesUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::whitelist_source_repository::SqliteWhitelistSourceRepository::new(pool.clone()),
             ))),
@@ -533,6 +614,10 @@ async fn create_test_app() -> (Router, Arc<RwLock<Config>>) {
             delete_whitelist_source: Arc::new(ferrous_dns_application::use_cases::DeleteWhitelistSourceUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::whitelist_source_repository::SqliteWhitelistSourceRepository::new(pool.clone()),
             ))),
+            get_ip_blocklist_sources: Arc::new(ferrous_dns_application::use_cases::GetIpBlocklistSourcesUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteIpBlocklistSourceRepository::new(pool.clone())))),
+            create_ip_blocklist_source: Arc::new(ferrous_dns_application::use_cases::CreateIpBlocklistSourceUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteIpBlocklistSourceRepository::new(pool.clone())))),
+            update_ip_blocklist_source: Arc::new(ferrous_dns_application::use_cases::UpdateIpBlocklistSourceUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteIpBlocklistSourceRepository::new(pool.clone())))),
+            delete_ip_blocklist_source: Arc::new(ferrous_dns_application::use_cases::DeleteIpBlocklistSourceUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteIpBlocklistSourceRepository::new(pool.clone())))),
             get_managed_domains: Arc::new(GetManagedDomainsUseCase::new(managed_domain_repo.clone())),
             create_managed_domain: Arc::new(CreateManagedDomainUseCase::new(
                 managed_domain_repo.clone(),
@@ -566,6 +651,11 @@ async fn create_test_app() -> (Router, Arc<RwLock<Config>>) {
                 null_engine.clone(),
             )),
             get_block_filter_stats: Arc::new(GetBlockFilterStatsUseCase::new(Arc::new(NullBlockFilterEngine))),
+            get_unblock_requests: Arc::new(ferrous_dns_application::use_cases::GetUnblockRequestsUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository::new(pool.clone())))),
+            submit_unblock_request: Arc::new(ferrous_dns_application::use_cases::SubmitUnblockRequestUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository::new(pool.clone())), Arc::new(NullBlockFilterEngine))),
+            approve_unblock_request: Arc::new(ferrous_dns_application::use_cases::ApproveUnblockRequestUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::repositories::managed_domain_repository::SqliteManagedDomainRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::repositories::group_repository::SqliteGroupRepository::new(pool.clone())), Arc::new(NullBlockFilterEngine))),
+            deny_unblock_request: Arc::new(ferrous_dns_application::use_cases::DenyUnblockRequestUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository::new(pool.clone())))),
+            delete_unblock_request: Arc::new(ferrous_dns_application::use_cases::DeleteUnblockRequestUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository::new(pool.clone())))),
         },
         services: ServiceUseCases {
             get_service_catalog: Arc::new(GetServiceCatalogUseCase::new(Arc::new(NullServiceCatalog))),
@@ -611,264 +701,457 @@ async fn create_test_app() -> (Router, Arc<RwLock<Config>>) {
             manage_slots: Arc::new(ManageTimeSlotsUseCase::new(Arc::new(NullScheduleProfileRepository))),
             assign_profile: Arc::new(AssignScheduleProfileUseCase::new(Arc::new(NullScheduleProfileRepository), group_repo.clone())),
         },
+        policies: helpers::build_test_query_policy_use_cases(group_repo.clone()),
         auth: helpers::build_test_auth_use_cases(),
         backup: helpers::build_test_backup_use_cases(config.clone()),
+        tenants: helpers::build_test_tenant_use_cases(),
         config: config.clone(),
         config_file_persistence: Arc::new(ferrous_dns_infrastructure::repositories::TomlConfigFilePersistence),
         config_path: None,
         tls_cert: Arc::new(helpers::MockTlsCertificateService),
         tls_enabled: false,
+        log_levels: Arc::new(helpers::MockLogLevelControl::default()),
+        system_metrics: Arc::new(ferrous_dns_infrastructure::system::ProcessMetricsCollector::new()),
     };
 
     let app = create_api_routes(state);
     (app, config)
 }
 
-#[tokio::test]
-async fn test_list_local_records_returns_empty_list() {
-    let (app, _config) = create_test_app().await;
-
+async fn send(
+    app: &Router,
+    method: &str,
+    uri: &str,
+    payload: Option<Value>,
+) -> (StatusCode, Value) {
+    let body = match payload {
+        Some(p) => Body::from(serde_json::to_string(&p).unwrap()),
+        None => Body::empty(),
+    };
     let response = app
+        .clone()
         .oneshot(
             Request::builder()
-                .uri("/local-records")
-                .body(Body::empty())
+                .uri(uri)
+                .method(method)
+                .header("content-type", "application/json")
+                .body(body)
                 .unwrap(),
         )
         .await
         .unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let json = serde_json::from_slice(&bytes).unwrap_or(Value::Null);
+    (status, json)
+}
 
-    assert_eq!(response.status(), StatusCode::OK);
+#[tokio::test]
+async fn test_list_local_records_returns_empty_list() {
+    let (app, _config) = create_test_app().await;
 
-    let body = response.into_body().collect().await.unwrap().to_bytes();
-    let json: Value = serde_json::from_slice(&body).unwrap();
+    let (status, json) = send(&app, "GET", "/local-records", None).await;
 
+    assert_eq!(status, StatusCode::OK);
     assert!(json.is_array());
     assert_eq!(json.as_array().unwrap().len(), 0);
 }
 
 #[tokio::test]
-async fn test_list_local_records_returns_preloaded_records() {
-    let (app, config) = create_test_app().await;
-
-    {
-        let mut cfg = config.write().await;
-        cfg.dns.local_records.push(LocalDnsRecord {
-            hostname: "server".to_string(),
-            domain: Some("local".to_string()),
-            ip: "192.168.1.10".to_string(),
-            record_type: "A".to_string(),
-            ttl: Some(300),
-        });
-        cfg.dns.local_records.push(LocalDnsRecord {
-            hostname: "ipv6host".to_string(),
+async fn test_create_local_record_returns_created_record() {
+    let (app, _config) = create_test_app().await;
+
+    let (status, json) = send(
+        &app,
+        "POST",
+        "/local-records",
+        Some(json!({
+            "hostname": "server",
+            "domain": "local",
+            "value": "192.168.1.10",
+            "record_type": "A",
+            "ttl": 120
+        })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::CREATED);
+    assert!(json["id"].as_i64().unwrap() > 0);
+    assert_eq!(json["hostname"], "server");
+    assert_eq!(json["fqdn"], "server.local");
+    assert_eq!(json["value"], "192.168.1.10");
+    assert_eq!(json["record_type"], "A");
+    assert_eq!(json["ttl"], 120);
+    assert!(json["created_at"].is_string());
+}
+
+#[tokio::test]
+async fn test_create_local_record_accepts_legacy_ip_field() {
+    let (app, _config) = create_test_app().await;
+
+    let (status, json) = send(
+        &app,
+        "POST",
+        "/local-records",
+        Some(json!({ "hostname": "nas", "ip": "10.0.0.5", "record_type": "A" })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(json["value"], "10.0.0.5");
+    assert_eq!(json["ttl"], 300);
+}
+
+#[tokio::test]
+async fn test_list_local_records_returns_created_records() {
+    let (app, _config) = create_test_app().await;
+
+    for payload in [
+        json!({ "hostname": "server", "domain": "local", "value": "192.168.1.10", "record_type": "A" }),
+        json!({ "hostname": "ipv6host", "value": "::1", "record_type": "AAAA" }),
+    ] {
+        let (status, _) = send(&app, "POST", "/local-records", Some(payload)).await;
+        assert_eq!(status, StatusCode::CREATED);
+    }
+
+    let (status, json) = send(&app, "GET", "/local-records", None).await;
+
+    assert_eq!(status, StatusCode::OK);
+    let records = json.as_array().unwrap();
+    assert_eq!(records.len(), 2);
+    assert!(records
+        .iter()
+        .any(|r| r["fqdn"] == "server.local" && r["record_type"] == "A"));
+    assert!(records
+        .iter()
+        .any(|r| r["fqdn"] == "ipv6host" && r["record_type"] == "AAAA"));
+}
+
+#[tokio::test]
+async fn test_list_local_records_does_not_include_config_file_records() {
+    let mut config = Config::default();
+    config
+        .dns
+        .local_records
+        .push(ferrous_dns_domain::LocalDnsRecord {
+            hostname: "static".to_string(),
             domain: None,
-            ip: "::1".to_string(),
-            record_type: "AAAA".to_string(),
+            ip: "10.1.1.1".to_string(),
+            record_type: "A".to_string(),
             ttl: None,
+            view: None,
         });
-    }
+    let (app, _config) = create_test_app_with_config(config).await;
 
-    let response = app
-        .oneshot(
-            Request::builder()
-                .uri("/local-records")
-                .body(Body::empty())
-                .unwrap(),
-        )
-        .await
-        .unwrap();
+    let (status, json) = send(&app, "GET", "/local-records", None).await;
 
-    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json.as_array().unwrap().len(), 0);
+}
 
-    let body = response.into_body().collect().await.unwrap().to_bytes();
-    let json: Value = serde_json::from_slice(&body).unwrap();
+#[tokio::test]
+async fn test_fqdn_uses_configured_local_domain() {
+    let mut config = Config::default();
+    config.dns.local_domain = Some("home.lan".to_string());
+    let (app, _config) = create_test_app_with_config(config).await;
+
+    let (status, json) = send(
+        &app,
+        "POST",
+        "/local-records",
+        Some(json!({ "hostname": "printer", "value": "10.0.0.9", "record_type": "A" })),
+    )
+    .await;
 
-    assert!(json.is_array());
-    assert_eq!(json.as_array().unwrap().len(), 2);
-    assert_eq!(json[0]["hostname"], "server");
-    assert_eq!(json[0]["ip"], "192.168.1.10");
-    assert_eq!(json[0]["record_type"], "A");
-    assert_eq!(json[0]["ttl"], 300);
-    assert_eq!(json[0]["fqdn"], "server.local");
-    assert_eq!(json[1]["hostname"], "ipv6host");
-    assert_eq!(json[1]["record_type"], "AAAA");
-    assert_eq!(json[1]["ttl"], 300);
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(json["fqdn"], "printer.home.lan");
 }
 
 #[tokio::test]
-async fn test_create_local_record_with_invalid_ip_returns_bad_request() {
+async fn test_list_local_records_fqdn_without_domain_uses_hostname() {
     let (app, _config) = create_test_app().await;
 
-    let payload = json!({
-        "hostname": "myserver",
-        "ip": "not-an-ip-address",
-        "record_type": "A"
-    });
+    send(
+        &app,
+        "POST",
+        "/local-records",
+        Some(json!({ "hostname": "standalone", "value": "10.10.10.1", "record_type": "A", "ttl": 60 })),
+    )
+    .await;
 
-    let response = app
-        .oneshot(
-            Request::builder()
-                .uri("/local-records")
-                .method("POST")
-                .header("content-type", "application/json")
-                .body(Body::from(serde_json::to_string(&payload).unwrap()))
-                .unwrap(),
+    let (status, json) = send(&app, "GET", "/local-records", None).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json[0]["fqdn"], "standalone");
+    assert_eq!(json[0]["ttl"], 60);
+}
+
+#[tokio::test]
+async fn test_create_local_record_supports_all_record_types() {
+    let (app, _config) = create_test_app().await;
+
+    for (record_type, value) in [
+        ("CNAME", "nas.local"),
+        ("TXT", "v=spf1 -all"),
+        ("MX", "10 mail.local"),
+        ("SRV", "0 5 5060 sip.local"),
+    ] {
+        let (status, json) = send(
+            &app,
+            "POST",
+            "/local-records",
+            Some(json!({ "hostname": format!("{}-host", record_type.to_lowercase()), "value": value, "record_type": record_type })),
         )
-        .await
-        .unwrap();
+        .await;
+        assert_eq!(status, StatusCode::CREATED, "{record_type}");
+        assert_eq!(json["record_type"], record_type);
+        assert_eq!(json["value"], value);
+    }
+}
+
+#[tokio::test]
+async fn test_get_local_record_by_id() {
+    let (app, _config) = create_test_app().await;
+    let (_, created) = send(
+        &app,
+        "POST",
+        "/local-records",
+        Some(json!({ "hostname": "mail", "value": "10 mx.local", "record_type": "MX" })),
+    )
+    .await;
+    let id = created["id"].as_i64().unwrap();
+
+    let (status, json) = send(&app, "GET", &format!("/local-records/{id}"), None).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["id"], id);
+    assert_eq!(json["value"], "10 mx.local");
+}
+
+#[tokio::test]
+async fn test_get_local_record_not_found_returns_not_found() {
+    let (app, _config) = create_test_app().await;
+
+    let (status, _) = send(&app, "GET", "/local-records/999", None).await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_create_local_record_with_invalid_ip_returns_bad_request() {
+    let (app, _config) = create_test_app().await;
+
+    let (status, _) = send(
+        &app,
+        "POST",
+        "/local-records",
+        Some(json!({ "hostname": "myserver", "ip": "not-an-ip-address", "record_type": "A" })),
+    )
+    .await;
 
-    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(status, StatusCode::BAD_REQUEST);
 }
 
 #[tokio::test]
 async fn test_create_local_record_with_invalid_record_type_returns_bad_request() {
     let (app, _config) = create_test_app().await;
 
-    let payload = json!({
-        "hostname": "myserver",
-        "ip": "10.0.0.1",
-        "record_type": "MX"
-    });
+    let (status, _) = send(
+        &app,
+        "POST",
+        "/local-records",
+        Some(json!({ "hostname": "myserver", "value": "10.0.0.1", "record_type": "PTR" })),
+    )
+    .await;
 
-    let response = app
-        .oneshot(
-            Request::builder()
-                .uri("/local-records")
-                .method("POST")
-                .header("content-type", "application/json")
-                .body(Body::from(serde_json::to_string(&payload).unwrap()))
-                .unwrap(),
-        )
-        .await
-        .unwrap();
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_create_local_record_with_malformed_mx_returns_bad_request() {
+    let (app, _config) = create_test_app().await;
+
+    let (status, _) = send(
+        &app,
+        "POST",
+        "/local-records",
+        Some(json!({ "hostname": "mail", "value": "mx.local", "record_type": "MX" })),
+    )
+    .await;
 
-    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(status, StatusCode::BAD_REQUEST);
 }
 
 #[tokio::test]
-async fn test_update_local_record_with_invalid_ip_returns_bad_request() {
+async fn test_create_wildcard_and_ip_template_records() {
     let (app, _config) = create_test_app().await;
 
-    let payload = json!({
-        "hostname": "myserver",
-        "ip": "bad-ip",
-        "record_type": "A"
-    });
+    let (status, json) = send(
+        &app,
+        "POST",
+        "/local-records",
+        Some(json!({ "hostname": "*", "domain": "dev.lan", "value": "127.0.0.1", "record_type": "A" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(json["fqdn"], "*.dev.lan");
+
+    let (status, json) = send(
+        &app,
+        "POST",
+        "/local-records",
+        Some(json!({ "hostname": "*", "domain": "nip.lan", "value": "{ip}", "record_type": "A" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(json["value"], "{ip}");
+}
+
+#[tokio::test]
+async fn test_create_ip_template_without_wildcard_returns_bad_request() {
+    let (app, _config) = create_test_app().await;
 
-    let response = app
-        .oneshot(
-            Request::builder()
-                .uri("/local-records/0")
-                .method("PUT")
-                .header("content-type", "application/json")
-                .body(Body::from(serde_json::to_string(&payload).unwrap()))
-                .unwrap(),
-        )
-        .await
-        .unwrap();
+    let (status, _) = send(
+        &app,
+        "POST",
+        "/local-records",
+        Some(json!({ "hostname": "nas", "value": "{ip}", "record_type": "A" })),
+    )
+    .await;
 
-    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(status, StatusCode::BAD_REQUEST);
 }
 
 #[tokio::test]
-async fn test_update_local_record_with_invalid_record_type_returns_bad_request() {
+async fn test_create_duplicate_local_record_returns_conflict() {
     let (app, _config) = create_test_app().await;
+    let payload = json!({ "hostname": "dup", "value": "10.0.0.1", "record_type": "A" });
 
-    let payload = json!({
-        "hostname": "myserver",
-        "ip": "10.0.0.1",
-        "record_type": "CNAME"
-    });
+    let (first, _) = send(&app, "POST", "/local-records", Some(payload.clone())).await;
+    let (second, _) = send(&app, "POST", "/local-records", Some(payload)).await;
 
-    let response = app
-        .oneshot(
-            Request::builder()
-                .uri("/local-records/0")
-                .method("PUT")
-                .header("content-type", "application/json")
-                .body(Body::from(serde_json::to_string(&payload).unwrap()))
-                .unwrap(),
-        )
-        .await
-        .unwrap();
+    assert_eq!(first, StatusCode::CREATED);
+    assert_eq!(second, StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_create_cname_next_to_existing_record_returns_conflict() {
+    let (app, _config) = create_test_app().await;
+
+    send(
+        &app,
+        "POST",
+        "/local-records",
+        Some(json!({ "hostname": "www", "value": "10.0.0.1", "record_type": "A" })),
+    )
+    .await;
+    let (status, _) = send(
+        &app,
+        "POST",
+        "/local-records",
+        Some(json!({ "hostname": "www", "value": "web.local", "record_type": "CNAME" })),
+    )
+    .await;
 
-    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(status, StatusCode::CONFLICT);
 }
 
 #[tokio::test]
-async fn test_update_local_record_not_found_returns_not_found() {
+async fn test_update_local_record_replaces_fields() {
     let (app, _config) = create_test_app().await;
+    let (_, created) = send(
+        &app,
+        "POST",
+        "/local-records",
+        Some(json!({ "hostname": "app", "value": "10.0.0.1", "record_type": "A" })),
+    )
+    .await;
+    let id = created["id"].as_i64().unwrap();
+
+    let (status, json) = send(
+        &app,
+        "PUT",
+        &format!("/local-records/{id}"),
+        Some(json!({ "hostname": "app", "value": "app-v2 release", "record_type": "TXT", "ttl": 30 })),
+    )
+    .await;
 
-    let payload = json!({
-        "hostname": "myserver",
-        "ip": "10.0.0.1",
-        "record_type": "A"
-    });
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["id"], id);
+    assert_eq!(json["record_type"], "TXT");
+    assert_eq!(json["value"], "app-v2 release");
+    assert_eq!(json["ttl"], 30);
+}
 
-    let response = app
-        .oneshot(
-            Request::builder()
-                .uri("/local-records/999")
-                .method("PUT")
-                .header("content-type", "application/json")
-                .body(Body::from(serde_json::to_string(&payload).unwrap()))
-                .unwrap(),
-        )
-        .await
-        .unwrap();
+#[tokio::test]
+async fn test_update_local_record_with_invalid_ip_returns_bad_request() {
+    let (app, _config) = create_test_app().await;
+
+    let (status, _) = send(
+        &app,
+        "PUT",
+        "/local-records/1",
+        Some(json!({ "hostname": "myserver", "ip": "bad-ip", "record_type": "A" })),
+    )
+    .await;
 
-    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(status, StatusCode::BAD_REQUEST);
 }
 
 #[tokio::test]
-async fn test_delete_local_record_not_found_returns_not_found() {
+async fn test_update_local_record_with_invalid_record_type_returns_bad_request() {
     let (app, _config) = create_test_app().await;
 
-    let response = app
-        .oneshot(
-            Request::builder()
-                .uri("/local-records/999")
-                .method("DELETE")
-                .body(Body::empty())
-                .unwrap(),
-        )
-        .await
-        .unwrap();
+    let (status, _) = send(
+        &app,
+        "PUT",
+        "/local-records/1",
+        Some(json!({ "hostname": "myserver", "ip": "10.0.0.1", "record_type": "NAPTR" })),
+    )
+    .await;
 
-    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(status, StatusCode::BAD_REQUEST);
 }
 
 #[tokio::test]
-async fn test_list_local_records_fqdn_without_domain_uses_hostname() {
-    let (app, config) = create_test_app().await;
+async fn test_update_local_record_not_found_returns_not_found() {
+    let (app, _config) = create_test_app().await;
 
-    {
-        let mut cfg = config.write().await;
-        cfg.dns.local_records.push(LocalDnsRecord {
-            hostname: "standalone".to_string(),
-            domain: None,
-            ip: "10.10.10.1".to_string(),
-            record_type: "A".to_string(),
-            ttl: Some(60),
-        });
-    }
+    let (status, _) = send(
+        &app,
+        "PUT",
+        "/local-records/999",
+        Some(json!({ "hostname": "myserver", "ip": "10.0.0.1", "record_type": "A" })),
+    )
+    .await;
 
-    let response = app
-        .oneshot(
-            Request::builder()
-                .uri("/local-records")
-                .body(Body::empty())
-                .unwrap(),
-        )
-        .await
-        .unwrap();
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
 
-    assert_eq!(response.status(), StatusCode::OK);
+#[tokio::test]
+async fn test_delete_local_record_removes_it() {
+    let (app, _config) = create_test_app().await;
+    let (_, created) = send(
+        &app,
+        "POST",
+        "/local-records",
+        Some(json!({ "hostname": "gone", "value": "10.0.0.1", "record_type": "A" })),
+    )
+    .await;
+    let id = created["id"].as_i64().unwrap();
 
-    let body = response.into_body().collect().await.unwrap().to_bytes();
-    let json: Value = serde_json::from_slice(&body).unwrap();
+    let (status, _) = send(&app, "DELETE", &format!("/local-records/{id}"), None).await;
+    let (_, list) = send(&app, "GET", "/local-records", None).await;
 
-    assert_eq!(json[0]["fqdn"], "standalone");
-    assert_eq!(json[0]["ttl"], 60);
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    assert_eq!(list.as_array().unwrap().len(), 0);
+}
+
+#[tokio::test]
+async fn test_delete_local_record_not_found_returns_not_found() {
+    let (app, _config) = create_test_app().await;
+
+    let (status, _) = send(&app, "DELETE", "/local-records/999", None).await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
 }
@@ -0,0 +1,163 @@
+use axum::{
+    body::Body,
+    http::{header, Request, StatusCode},
+    middleware,
+    response::Response,
+    routing::get,
+    Json, Router,
+};
+use ferrous_dns_api::i18n::{error_message, Locale, Message};
+use ferrous_dns_api::middleware::localize_errors;
+use ferrous_dns_api::ApiError;
+use ferrous_dns_domain::DomainError;
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+fn app() -> Router {
+    Router::new()
+        .route(
+            "/groups/7",
+            get(|| async { Err::<(), _>(ApiError(DomainError::GroupNotFound(7))) }),
+        )
+        .route(
+            "/database",
+            get(|| async { Err::<(), _>(ApiError(DomainError::DatabaseError("locked".into()))) }),
+        )
+        .route(
+            "/reload",
+            get(|locale: Locale| async move {
+                Json(json!({ "message": Message::ConfigReloaded.text(locale) }))
+            }),
+        )
+        .layer(middleware::from_fn(localize_errors))
+}
+
+async fn get_with_language(path: &str, accept_language: Option<&str>) -> Response {
+    let mut request = Request::builder().uri(path);
+    if let Some(value) = accept_language {
+        request = request.header(header::ACCEPT_LANGUAGE, value);
+    }
+    app()
+        .oneshot(request.body(Body::empty()).unwrap())
+        .await
+        .unwrap()
+}
+
+async fn json_body(response: Response) -> Value {
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    serde_json::from_slice(&body).unwrap()
+}
+
+#[test]
+fn test_accept_language_picks_highest_weighted_supported_locale() {
+    assert_eq!(
+        Locale::from_accept_language("pt-BR,pt;q=0.9,en;q=0.8"),
+        Locale::PtBr
+    );
+    assert_eq!(
+        Locale::from_accept_language("en-US,en;q=0.9,pt;q=0.5"),
+        Locale::En
+    );
+    assert_eq!(
+        Locale::from_accept_language("fr-FR, pt;q=0.7, en;q=0.3"),
+        Locale::PtBr
+    );
+    assert_eq!(Locale::from_accept_language("pt-PT"), Locale::PtBr);
+}
+
+#[test]
+fn test_accept_language_falls_back_to_english() {
+    assert_eq!(Locale::from_accept_language(""), Locale::En);
+    assert_eq!(Locale::from_accept_language("de-DE,fr;q=0.8"), Locale::En);
+    assert_eq!(Locale::from_accept_language("pt;q=0"), Locale::En);
+    assert_eq!(Locale::from_accept_language("*"), Locale::En);
+}
+
+#[test]
+fn test_every_message_has_a_translation() {
+    let messages = [
+        Message::InternalError,
+        Message::Blocked,
+        Message::DnsTunnelingDetected,
+        Message::NoConfigFile,
+        Message::NoWritableConfigFile,
+        Message::ConfigFileReadOnly,
+        Message::InvalidConfiguration,
+        Message::ConfigSaved,
+        Message::ConfigSavedRestartRequired,
+        Message::SaveConfigFailed,
+        Message::SettingsSaved,
+        Message::SaveSettingsFailed,
+        Message::ConfigReloaded,
+        Message::ReloadConfigFailed,
+    ];
+
+    for message in messages {
+        for locale in Locale::ALL {
+            assert!(!message.text(locale).is_empty(), "{message:?} {locale:?}");
+        }
+        assert_ne!(message.text(Locale::En), message.text(Locale::PtBr));
+    }
+}
+
+#[test]
+fn test_error_message_keeps_details() {
+    let error = DomainError::InvalidDomainName("bad domain!".into());
+
+    assert_eq!(
+        error_message(Locale::En, &error),
+        "Invalid domain name: bad domain!"
+    );
+    assert_eq!(
+        error_message(Locale::PtBr, &error),
+        "Nome de domínio inválido: bad domain!"
+    );
+}
+
+#[tokio::test]
+async fn test_errors_stay_english_without_accept_language() {
+    let response = get_with_language("/groups/7", None).await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(response.headers()[header::CONTENT_LANGUAGE], "en");
+    assert_eq!(
+        json_body(response).await,
+        json!({ "error": "Group not found: 7" })
+    );
+}
+
+#[tokio::test]
+async fn test_errors_are_localized_from_accept_language() {
+    let response = get_with_language("/groups/7", Some("pt-BR,pt;q=0.9")).await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(response.headers()[header::CONTENT_LANGUAGE], "pt-BR");
+    assert_eq!(
+        json_body(response).await,
+        json!({ "error": "Grupo não encontrado: 7" })
+    );
+}
+
+#[tokio::test]
+async fn test_internal_errors_are_not_detailed_in_any_language() {
+    let response = get_with_language("/database", Some("pt-BR")).await;
+
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(
+        json_body(response).await,
+        json!({ "error": "erro interno" })
+    );
+}
+
+#[tokio::test]
+async fn test_handlers_read_locale_from_request() {
+    let english = json_body(get_with_language("/reload", Some("en")).await).await;
+    let portuguese = json_body(get_with_language("/reload", Some("pt-BR")).await).await;
+
+    assert_eq!(english["message"], "Configuration reloaded successfully");
+    assert_eq!(
+        portuguese["message"],
+        "Configuração recarregada com sucesso"
+    );
+}
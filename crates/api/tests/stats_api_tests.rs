@@ -4,24 +4,26 @@ use axum::{
     Router,
 };
 use ferrous_dns_api::{
-    create_api_routes, AppState, BlockingUseCases, ClientUseCases, DnsUseCases, GroupUseCases,
-    QueryUseCases, SafeSearchUseCases, ScheduleUseCases, ServiceUseCases,
+    create_api_routes, create_public_routes, AppState, BlockingUseCases, ClientUseCases,
+    DnsUseCases, GroupUseCases, QueryUseCases, SafeSearchUseCases, ScheduleUseCases,
+    ServiceUseCases,
 };
 use ferrous_dns_application::{
     ports::{
-        BlockFilterEnginePort, BlockedServiceRepository, ConfigRepository, FilterDecision,
+        BlockFilterEnginePort, BlockedServiceRepository, FilterDecision,
         SafeSearchConfigRepository, SafeSearchEnginePort, ServiceCatalogPort,
     },
     use_cases::{
         AssignScheduleProfileUseCase, CreateLocalRecordUseCase, CreateScheduleProfileUseCase,
         DeleteLocalRecordUseCase, DeleteSafeSearchConfigsUseCase, DeleteScheduleProfileUseCase,
-        GetBlockFilterStatsUseCase, GetBlocklistUseCase, GetClientsUseCase, GetQueryStatsUseCase,
-        GetRecentQueriesUseCase, GetSafeSearchConfigsUseCase, GetScheduleProfilesUseCase,
-        ManageTimeSlotsUseCase, ToggleSafeSearchUseCase, UpdateLocalRecordUseCase,
-        UpdateScheduleProfileUseCase,
+        GetBlockFilterStatsUseCase, GetBlocklistUseCase, GetClientsUseCase, GetLocalRecordsUseCase,
+        GetQueryStatsUseCase, GetRecentQueriesUseCase, GetSafeSearchConfigsUseCase,
+        GetScheduleProfilesUseCase, ManageTimeSlotsUseCase, ToggleSafeSearchUseCase,
+        UpdateLocalRecordUseCase, UpdateScheduleProfileUseCase,
     },
 };
-use ferrous_dns_domain::{config::DatabaseConfig, Config};
+use ferrous_dns_api::middleware::ApiRateLimiter;
+use ferrous_dns_domain::{config::DatabaseConfig, ApiLimitsConfig, Config};
 use ferrous_dns_infrastructure::{
     dns::cache::DnsCache,
     repositories::{
@@ -45,6 +47,9 @@ impl BlockFilterEnginePort for NullBlockFilterEngine {
     fn resolve_group(&self, _ip: std::net::IpAddr) -> i64 {
         1
     }
+    fn resolve_group_with_default(&self, _ip: std::net::IpAddr, _default_group_id: i64) -> i64 {
+        1
+    }
     fn check(&self, _domain: &str, _group_id: i64) -> FilterDecision {
         FilterDecision::Allow
     }
@@ -153,17 +158,6 @@ impl ServiceCatalogPort for NullServiceCatalog {
     }
     fn reload_custom(&self, _custom: Vec<ferrous_dns_domain::ServiceDefinition>) {}
 }
-struct NullConfigRepository;
-#[async_trait::async_trait]
-impl ConfigRepository for NullConfigRepository {
-    async fn save_local_records(
-        &self,
-        _config: &Config,
-    ) -> Result<(), ferrous_dns_domain::DomainError> {
-        Ok(())
-    }
-}
-
 struct NullSafeSearchConfigRepository;
 #[async_trait::async_trait]
 impl SafeSearchConfigRepository for NullSafeSearchConfigRepository {
@@ -297,6 +291,9 @@ async fn create_test_db() -> sqlx::SqlitePool {
             enabled BOOLEAN NOT NULL DEFAULT 1,
             comment TEXT,
             is_default BOOLEAN NOT NULL DEFAULT 0,
+            filter_aaaa BOOLEAN NOT NULL DEFAULT 0,
+            audit_mode  BOOLEAN NOT NULL DEFAULT 0,
+            tenant_id INTEGER,
             created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
             updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
         )
@@ -330,7 +327,10 @@ async fn create_test_db() -> sqlx::SqlitePool {
             last_hostname_update DATETIME,
             group_id INTEGER NOT NULL DEFAULT 1 REFERENCES groups(id) ON DELETE RESTRICT,
             created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
-            updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+            updated_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            display_name TEXT,
+            category TEXT,
+            notes TEXT
         )
         "#,
     )
@@ -371,10 +371,15 @@ async fn create_test_db() -> sqlx::SqlitePool {
             dnssec_status TEXT,
             upstream_server TEXT,
             upstream_pool TEXT,
+            upstream_strategy TEXT,
+            upstream_attempt INTEGER,
+            upstream_protocol TEXT,
             response_status TEXT,
             query_source TEXT NOT NULL DEFAULT 'client',
             group_id INTEGER,
+            tenant_id INTEGER,
             block_source TEXT,
+            plugin TEXT,
             created_at DATETIME NOT NULL DEFAULT (datetime('now'))
         )
         "#,
@@ -383,10 +388,37 @@ async fn create_test_db() -> sqlx::SqlitePool {
     .await
     .unwrap();
 
+    sqlx::query(
+        r#"
+        CREATE TABLE query_stats_rollup (
+            bucket TEXT PRIMARY KEY,
+            queries INTEGER NOT NULL DEFAULT 0,
+            blocked INTEGER NOT NULL DEFAULT 0,
+            rate_limited INTEGER NOT NULL DEFAULT 0,
+            cache_hits INTEGER NOT NULL DEFAULT 0,
+            local_dns INTEGER NOT NULL DEFAULT 0,
+            any_minimized INTEGER NOT NULL DEFAULT 0,
+            time_sum INTEGER NOT NULL DEFAULT 0,
+            cache_time_sum INTEGER NOT NULL DEFAULT 0,
+            upstream_queries INTEGER NOT NULL DEFAULT 0,
+            upstream_time_sum INTEGER NOT NULL DEFAULT 0,
+            latency_histogram BLOB,
+            last_id INTEGER NOT NULL DEFAULT 0
+        ) WITHOUT ROWID
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
     pool
 }
 
 async fn create_test_app(pool: sqlx::SqlitePool) -> Router {
+    create_test_app_with_config(pool, Config::default()).await
+}
+
+async fn create_test_app_with_config(pool: sqlx::SqlitePool, config: Config) -> Router {
     let client_repo = Arc::new(SqliteClientRepository::new(
         pool.clone(),
         &DatabaseConfig::default(),
@@ -404,7 +436,7 @@ async fn create_test_app(pool: sqlx::SqlitePool) -> Router {
         &DatabaseConfig::default(),
     ));
 
-    let config = Arc::new(RwLock::new(Config::default()));
+    let config = Arc::new(RwLock::new(config));
     let cache = Arc::new(DnsCache::new(
         ferrous_dns_infrastructure::dns::DnsCacheConfig {
             max_entries: 0,
@@ -422,10 +454,11 @@ async fn create_test_app(pool: sqlx::SqlitePool) -> Router {
             refresh_sample_rate: 1.0,
             min_ttl: 0,
             max_ttl: 86_400,
+            l1_capacity: 1024,
         },
     ));
 
-    use ferrous_dns_domain::config::upstream::{UpstreamPool, UpstreamStrategy};
+    use ferrous_dns_domain::config::upstream::{UdpSocketMode, UpstreamPool, UpstreamStrategy};
     use ferrous_dns_infrastructure::dns::{PoolManager, QueryEventEmitter};
 
     let event_emitter = QueryEventEmitter::new_disabled();
@@ -435,6 +468,8 @@ async fn create_test_app(pool: sqlx::SqlitePool) -> Router {
         priority: 1,
         servers: vec!["8.8.8.8:53".to_string()],
         weight: None,
+        tsig_key: None,
+        udp_socket: UdpSocketMode::Pooled,
     };
     let pool_manager = Arc::new(
         PoolManager::new(vec![test_pool], None, event_emitter)
@@ -451,16 +486,46 @@ async fn create_test_app(pool: sqlx::SqlitePool) -> Router {
             get_cache_stats: Arc::new(ferrous_dns_application::use_cases::GetCacheStatsUseCase::new(query_log_repo.clone())),
             get_top_blocked_domains: Arc::new(ferrous_dns_application::use_cases::GetTopBlockedDomainsUseCase::new(query_log_repo.clone())),
             get_top_clients: Arc::new(ferrous_dns_application::use_cases::GetTopClientsUseCase::new(query_log_repo.clone())),
+            database_maintenance: Arc::new(ferrous_dns_application::use_cases::DatabaseMaintenanceUseCase::new(Arc::new(ferrous_dns_infrastructure::database::SqliteDatabaseMaintenance::new(pool.clone())))),
+            get_alerts: Arc::new(ferrous_dns_application::use_cases::GetAlertsUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteAlertRepository::new(pool.clone())))),
+            delete_alert: Arc::new(ferrous_dns_application::use_cases::DeleteAlertUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteAlertRepository::new(pool.clone())))),
+            get_audit_log: Arc::new(ferrous_dns_application::use_cases::GetAuditLogUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteAuditLogRepository::new(pool.clone())))),
+            live_metrics: Arc::new(ferrous_dns_infrastructure::dns::QueryMetrics::new()),
         },
         dns: DnsUseCases {
-            cache: cache as Arc<dyn ferrous_dns_application::ports::DnsCachePort>,
-            create_local_record: Arc::new(CreateLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))),
-            update_local_record: Arc::new(UpdateLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))),
-            delete_local_record: Arc::new(DeleteLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))),
+            cache: cache.clone() as Arc<dyn ferrous_dns_application::ports::DnsCachePort>,
+            create_local_record: Arc::new(CreateLocalRecordUseCase::new(config.clone(), Arc::new(helpers::NullLocalRecordRepository), Arc::new(helpers::NullLocalZone))),
+            update_local_record: Arc::new(UpdateLocalRecordUseCase::new(config.clone(), Arc::new(helpers::NullLocalRecordRepository), Arc::new(helpers::NullLocalZone))),
+            get_local_records: Arc::new(GetLocalRecordsUseCase::new(Arc::new(helpers::NullLocalRecordRepository))),
+            delete_local_record: Arc::new(DeleteLocalRecordUseCase::new(config.clone(), Arc::new(helpers::NullLocalRecordRepository), Arc::new(helpers::NullLocalZone))),
             upstream_health: Arc::new(ferrous_dns_infrastructure::dns::UpstreamHealthAdapter::new(
-                pool_manager,
+                pool_manager.clone(),
                 None,
             )),
+            access_control: Arc::new(ferrous_dns_infrastructure::dns::AccessControlRegistry::new()),
+            sinkhole_telemetry: Arc::new(ferrous_dns_infrastructure::dns::SinkholeTelemetry::new()),
+            conditional_forwards: Arc::new(
+                ferrous_dns_infrastructure::dns::resolver::ConditionalForwards::new(&[]),
+            ),
+            slow_query_log: Arc::new(ferrous_dns_infrastructure::dns::SlowQueryLog::new(100)),
+            inflight: Arc::new(ferrous_dns_infrastructure::dns::InflightRegistry::new(4)),
+            diagnose_domain: Arc::new(ferrous_dns_application::use_cases::DiagnoseDomainUseCase::new(
+                Arc::new(NullBlockFilterEngine),
+                cache.clone(),
+                Arc::new(ferrous_dns_infrastructure::dns::UpstreamHealthAdapter::new(pool_manager.clone(), None)),
+            )),
+            trace_resolve: Arc::new(ferrous_dns_application::use_cases::TraceResolveUseCase::new(
+                Arc::new(ferrous_dns_application::use_cases::DiagnoseDomainUseCase::new(
+                    Arc::new(NullBlockFilterEngine),
+                    cache,
+                    Arc::new(ferrous_dns_infrastructure::dns::UpstreamHealthAdapter::new(pool_manager, None)),
+                )),
+                Arc::new(helpers::FixedDnsResolver),
+            )),
+            get_dnssec_status: helpers::build_test_dnssec_status(pool.clone()),
+            get_dnssec_stats: helpers::build_test_dnssec_stats(pool.clone()),
+            resolve_query: helpers::build_test_resolve_query(pool.clone()),
+            faults: None,
         },
         groups: GroupUseCases {
             get_groups: Arc::new(ferrous_dns_application::use_cases::GetGroupsUseCase::new(group_repo.clone())),
@@ -493,6 +558,10 @@ async fn create_test_app(pool: sqlx::SqlitePool) -> Router {
             )),
             update_client: Arc::new(ferrous_dns_application::use_cases::UpdateClientUseCase::new(client_repo.clone())),
             delete_client: Arc::new(ferrous_dns_application::use_cases::DeleteClientUseCase::new(client_repo.clone())),
+            get_client_activity: Arc::new(ferrous_dns_application::use_cases::GetClientActivityUseCase::new(
+                client_repo.clone(),
+                query_log_repo.clone(),
+            )),
             subnet_matcher: Arc::new(ferrous_dns_application::services::SubnetMatcherService::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::client_subnet_repository::SqliteClientSubnetRepository::new(pool.clone()),
             ))),
@@ -501,6 +570,10 @@ async fn create_test_app(pool: sqlx::SqlitePool) -> Router {
             get_blocklist: Arc::new(GetBlocklistUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::blocklist_repository::SqliteBlocklistRepository::new(pool.clone()),
             ))),
+            bulk_add_blocklist: Arc::new(ferrous_dns_application::use_cases::BulkAddBlocklistUseCase::new(
+                Arc::new(ferrous_dns_infrastructure::repositories::blocklist_repository::SqliteBlocklistRepository::new(pool.clone())),
+                Arc::new(NullBlockFilterEngine),
+            )),
             get_blocklist_sources: Arc::new(ferrous_dns_application::use_cases::GetBlocklistSourcesUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::blocklist_source_repository::SqliteBlocklistSourceRepository::new(pool.clone()),
             ))),
@@ -518,6 +591,10 @@ async fn create_test_app(pool: sqlx::SqlitePool) -> Router {
             get_whitelist: Arc::new(ferrous_dns_application::use_cases::GetWhitelistUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::whitelist_repository::SqliteWhitelistRepository::new(pool.clone()),
             ))),
+            bulk_add_whitelist: Arc::new(ferrous_dns_application::use_cases::BulkAddWhitelistUseCase::new(
+                Arc::new(ferrous_dns_infrastructure::repositories::whitelist_repository::SqliteWhitelistRepository::new(pool.clone())),
+                Arc::new(NullBlockFilterEngine),
+            )),
             get_whitelist_sources: Arc::new(ferrous_dns_application::use_cases::GetWhitelistSourcesUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::whitelist_source_repository::SqliteWhitelistSourceRepository::new(pool.clone()),
             ))),
@@ -532,6 +609,10 @@ async fn create_test_app(pool: sqlx::SqlitePool) -> Router {
             delete_whitelist_source: Arc::new(ferrous_dns_application::use_cases::DeleteWhitelistSourceUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::whitelist_source_repository::SqliteWhitelistSourceRepository::new(pool.clone()),
             ))),
+            get_ip_blocklist_sources: Arc::new(ferrous_dns_application::use_cases::GetIpBlocklistSourcesUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteIpBlocklistSourceRepository::new(pool.clone())))),
+            create_ip_blocklist_source: Arc::new(ferrous_dns_application::use_cases::CreateIpBlocklistSourceUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteIpBlocklistSourceRepository::new(pool.clone())))),
+            update_ip_blocklist_source: Arc::new(ferrous_dns_application::use_cases::UpdateIpBlocklistSourceUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteIpBlocklistSourceRepository::new(pool.clone())))),
+            delete_ip_blocklist_source: Arc::new(ferrous_dns_application::use_cases::DeleteIpBlocklistSourceUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteIpBlocklistSourceRepository::new(pool.clone())))),
             get_managed_domains: Arc::new(ferrous_dns_application::use_cases::GetManagedDomainsUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::managed_domain_repository::SqliteManagedDomainRepository::new(pool.clone()),
             ))),
@@ -567,6 +648,11 @@ async fn create_test_app(pool: sqlx::SqlitePool) -> Router {
                 Arc::new(NullBlockFilterEngine),
             )),
             get_block_filter_stats: Arc::new(GetBlockFilterStatsUseCase::new(Arc::new(NullBlockFilterEngine))),
+            get_unblock_requests: Arc::new(ferrous_dns_application::use_cases::GetUnblockRequestsUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository::new(pool.clone())))),
+            submit_unblock_request: Arc::new(ferrous_dns_application::use_cases::SubmitUnblockRequestUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository::new(pool.clone())), Arc::new(NullBlockFilterEngine))),
+            approve_unblock_request: Arc::new(ferrous_dns_application::use_cases::ApproveUnblockRequestUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::repositories::managed_domain_repository::SqliteManagedDomainRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::repositories::group_repository::SqliteGroupRepository::new(pool.clone())), Arc::new(NullBlockFilterEngine))),
+            deny_unblock_request: Arc::new(ferrous_dns_application::use_cases::DenyUnblockRequestUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository::new(pool.clone())))),
+            delete_unblock_request: Arc::new(ferrous_dns_application::use_cases::DeleteUnblockRequestUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository::new(pool.clone())))),
         },
         services: ServiceUseCases {
             get_service_catalog: Arc::new(ferrous_dns_application::use_cases::GetServiceCatalogUseCase::new(Arc::new(NullServiceCatalog))),
@@ -612,16 +698,21 @@ async fn create_test_app(pool: sqlx::SqlitePool) -> Router {
             manage_slots: Arc::new(ManageTimeSlotsUseCase::new(Arc::new(NullScheduleProfileRepository))),
             assign_profile: Arc::new(AssignScheduleProfileUseCase::new(Arc::new(NullScheduleProfileRepository), group_repo.clone())),
         },
+        policies: helpers::build_test_query_policy_use_cases(group_repo.clone()),
         auth: helpers::build_test_auth_use_cases(),
         backup: helpers::build_test_backup_use_cases(config.clone()),
+        tenants: helpers::build_test_tenant_use_cases(),
         config: config.clone(),
         config_file_persistence: Arc::new(ferrous_dns_infrastructure::repositories::TomlConfigFilePersistence),
         config_path: None,
         tls_cert: Arc::new(helpers::MockTlsCertificateService),
         tls_enabled: false,
+        log_levels: Arc::new(helpers::MockLogLevelControl::default()),
+        system_metrics: Arc::new(ferrous_dns_infrastructure::system::ProcessMetricsCollector::new()),
     };
 
-    create_api_routes(state)
+    let limits = ApiLimitsConfig::default();
+    create_api_routes(state.clone()).merge(create_public_routes(state, &limits, ApiRateLimiter::new(&limits)))
 }
 
 async fn insert_query_log(
@@ -672,6 +763,60 @@ async fn test_get_stats_empty() {
     assert_eq!(json["queries_blocked"], 0);
 }
 
+#[tokio::test]
+async fn test_public_stats_not_found_when_disabled() {
+    let pool = create_test_db().await;
+    let app = create_test_app(pool).await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/public/stats")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_public_stats_exposes_only_summary() {
+    let pool = create_test_db().await;
+    insert_query_log(&pool, true, false, None).await;
+    insert_query_log(&pool, false, false, None).await;
+    insert_query_log(&pool, false, true, Some("blocklist")).await;
+
+    let mut config = Config::default();
+    config.server.public_stats.enabled = true;
+    let app = create_test_app_with_config(pool, config).await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/public/stats")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["access-control-allow-origin"], "*");
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(json["period_hours"], 24);
+    assert_eq!(json["queries_total"], 3);
+    assert_eq!(json["queries_blocked"], 1);
+    assert!(json["blocked_percentage"].is_number());
+    assert!(json["cache_hit_rate"].is_number());
+    for private in ["source_stats", "top_clients", "queries_by_group", "clients"] {
+        assert!(json.get(private).is_none(), "{private} must not be public");
+    }
+}
+
 #[tokio::test]
 async fn test_get_stats_has_source_stats_field() {
     let pool = create_test_db().await;
@@ -770,6 +915,31 @@ async fn test_get_stats_period_parameter() {
     assert_eq!(json["queries_total"], 1);
 }
 
+#[tokio::test]
+async fn test_get_stats_live_period_uses_in_memory_counters() {
+    let pool = create_test_db().await;
+
+    // Logged rows are not part of the live counters
+    insert_query_log(&pool, false, false, None).await;
+
+    let app = create_test_app(pool).await;
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/stats?period=live")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["queries_total"], 0);
+    assert!(json["source_stats"].is_object());
+}
+
 #[tokio::test]
 async fn test_dashboard_includes_top_blocked_and_clients() {
     let pool = create_test_db().await;
@@ -799,3 +969,434 @@ async fn test_dashboard_includes_top_blocked_and_clients() {
     assert_eq!(json["top_blocked_domains"][0]["count"], 1);
     assert!(!json["top_clients"].as_array().unwrap().is_empty());
 }
+
+#[tokio::test]
+async fn test_timeline_auto_granularity_with_client_breakdown() {
+    let pool = create_test_db().await;
+
+    insert_query_log(&pool, false, true, Some("blocklist")).await;
+    insert_query_log(&pool, true, false, None).await;
+
+    let app = create_test_app(pool).await;
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/queries/timeline?period=7d&granularity=auto&breakdown=client")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(json["granularity"], "hour");
+    assert_eq!(json["buckets"][0]["cached"], 1);
+    assert_eq!(json["buckets"][0]["blocked"], 1);
+    assert_eq!(json["series"].as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_timeline_rejects_unknown_breakdown() {
+    let pool = create_test_db().await;
+    let app = create_test_app(pool).await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/queries/timeline?breakdown=domain")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_diagnose_domain_reports_forwarded_for_unfiltered_domain() {
+    let pool = create_test_db().await;
+    let app = create_test_app(pool).await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/debug/domain/Example.COM.?client=192.168.1.10&type=aaaa")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(json["domain"], "example.com");
+    assert_eq!(json["record_type"], "AAAA");
+    assert_eq!(json["client"], "192.168.1.10");
+    assert_eq!(json["group_id"], 1);
+    assert_eq!(json["outcome"], "forwarded");
+    assert_eq!(json["filter"]["decision"], "allow");
+    assert!(json["cache"].is_null());
+}
+
+#[tokio::test]
+async fn test_diagnose_domain_rejects_invalid_client() {
+    let pool = create_test_db().await;
+    let app = create_test_app(pool).await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/debug/domain/example.com?client=not-an-ip")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_diagnose_domain_rejects_unknown_record_type() {
+    let pool = create_test_db().await;
+    let app = create_test_app(pool).await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/debug/domain/example.com?type=BOGUS")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_flush_cache_by_suffix_reports_normalized_suffix() {
+    let pool = create_test_db().await;
+    let app = create_test_app(pool).await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("DELETE")
+                .uri("/cache/flush?suffix=Example.COM.")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(json["suffix"], "example.com");
+    assert_eq!(json["removed"], 0);
+}
+
+#[tokio::test]
+async fn test_flush_cache_rejects_invalid_suffix() {
+    let pool = create_test_db().await;
+    let app = create_test_app(pool).await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("DELETE")
+                .uri("/cache/flush?suffix=.")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_resolve_answers_in_dns_json_format() {
+    let pool = create_test_db().await;
+    let app = create_test_app(pool).await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/resolve?name=Example.com&type=A")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()["content-type"],
+        "application/dns-json; charset=utf-8"
+    );
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(json["Status"], 0);
+    assert_eq!(json["RA"], true);
+    assert_eq!(json["Question"][0]["name"], "example.com.");
+    assert_eq!(json["Question"][0]["type"], 1);
+    assert_eq!(json["Answer"][0]["name"], "example.com.");
+    assert_eq!(json["Answer"][0]["type"], 1);
+    assert_eq!(json["Answer"][0]["TTL"], 300);
+    assert_eq!(json["Answer"][0]["data"], "192.0.2.1");
+}
+
+#[tokio::test]
+async fn test_resolve_accepts_numeric_type() {
+    let pool = create_test_db().await;
+    let app = create_test_app(pool).await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/resolve?name=example.com&type=28")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(json["Answer"][0]["type"], 28);
+    assert_eq!(json["Answer"][0]["data"], "2001:db8::1");
+}
+
+#[tokio::test]
+async fn test_resolve_reports_dns_failures_in_status() {
+    let pool = create_test_db().await;
+    let app = create_test_app(pool).await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/resolve?name=example.com&type=MX")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(json["Status"], 3);
+    assert_eq!(json["Answer"].as_array().unwrap().len(), 0);
+    assert!(json["Comment"].is_string());
+}
+
+#[tokio::test]
+async fn test_resolve_rejects_missing_name_and_unknown_type() {
+    let pool = create_test_db().await;
+    let app = create_test_app(pool).await;
+
+    for uri in ["/resolve", "/resolve?name=example.com&type=BOGUS"] {
+        let response = app
+            .clone()
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{}", uri);
+    }
+}
+
+#[tokio::test]
+async fn test_trace_resolve_reports_steps_and_answer() {
+    let pool = create_test_db().await;
+    let app = create_test_app(pool).await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/debug/resolve")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    r#"{"name":"example.com","client":"192.168.1.10"}"#,
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(json["diagnosis"]["outcome"], "forwarded");
+    assert_eq!(json["resolved_name"], "example.com");
+    assert_eq!(json["answer"]["addresses"][0], "192.0.2.1");
+    assert_eq!(json["answer"]["upstream_pool"], "primary");
+    let stages: Vec<&str> = json["steps"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|s| s["stage"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        stages,
+        [
+            "query_policy",
+            "rewrite",
+            "block_filter",
+            "safe_search",
+            "cache",
+            "upstream",
+            "dnssec",
+            "answer"
+        ]
+    );
+    assert_eq!(json["steps"][7]["result"], "noerror");
+}
+
+#[tokio::test]
+async fn test_trace_resolve_rejects_invalid_domain() {
+    let pool = create_test_db().await;
+    let app = create_test_app(pool).await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/debug/resolve")
+                .header("content-type", "application/json")
+                .body(Body::from(r#"{"name":"bad domain!"}"#))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_inflight_queries_empty() {
+    let pool = create_test_db().await;
+    let app = create_test_app(pool).await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/debug/inflight?limit=5")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(json["total"], 0);
+    assert_eq!(json["leaders"], 0);
+    assert_eq!(json["coalesced"], 0);
+    assert_eq!(json["dedup_ratio"], 0.0);
+    assert!(json["queries"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_system_metrics_reports_process_and_cache() {
+    let pool = create_test_db().await;
+    let app = create_test_app(pool).await;
+
+    let response = app
+        .oneshot(Request::builder().uri("/system").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+
+    assert!(json["uptime_secs"].is_u64());
+    assert!(json["runtime"]["workers"].as_u64().unwrap() >= 1);
+    assert!(json["runtime"]["alive_tasks"].is_u64());
+    assert!(json["channels"].is_array());
+    assert_eq!(json["cache"]["entries"], 0);
+    assert!(json["cache"]["memory_bytes"].is_u64());
+}
+
+#[tokio::test]
+async fn test_get_stats_breaks_down_by_group_and_client() {
+    let pool = create_test_db().await;
+
+    sqlx::query("INSERT INTO groups (id, name) VALUES (2, 'Kids')")
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query(
+        "INSERT INTO clients (ip_address, hostname, group_id) VALUES ('10.0.0.2', 'tablet', 2)",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    for (client_ip, group_id, blocked, cache_hit) in [
+        ("10.0.0.1", 1, 0, 1),
+        ("10.0.0.1", 1, 0, 0),
+        ("10.0.0.1", 1, 1, 0),
+        ("10.0.0.2", 2, 1, 0),
+    ] {
+        sqlx::query(
+            "INSERT INTO query_log (domain, record_type, client_ip, blocked, response_time_ms, cache_hit, query_source, group_id)
+             VALUES ('example.com', 'A', ?, ?, 100, ?, 'client', ?)",
+        )
+        .bind(client_ip)
+        .bind(blocked)
+        .bind(cache_hit)
+        .bind(group_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    }
+
+    let app = create_test_app(pool).await;
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/stats?period=24h")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(json["blocked_percentage"], 50.0);
+
+    let groups = json["queries_by_group"].as_array().unwrap();
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0]["key"], "1");
+    assert_eq!(groups[0]["label"], "Protected");
+    assert_eq!(groups[0]["queries_total"], 3);
+    assert_eq!(groups[0]["cache_hits"], 1);
+    assert_eq!(groups[1]["label"], "Kids");
+    assert_eq!(groups[1]["blocked_percentage"], 100.0);
+
+    let clients = json["top_clients"].as_array().unwrap();
+    assert_eq!(clients.len(), 2);
+    assert_eq!(clients[0]["key"], "10.0.0.1");
+    assert_eq!(clients[0]["label"], Value::Null);
+    assert_eq!(clients[1]["label"], "tablet");
+}
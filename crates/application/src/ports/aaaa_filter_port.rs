@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::DomainError;
+
+/// Hot-path port for the per-group "filter AAAA" flag.
+///
+/// Implementors hold the set of flagged groups in an index that can be
+/// swapped atomically on reload.
+#[async_trait]
+pub trait AaaaFilterPort: Send + Sync {
+    /// Returns `true` when AAAA answers must be dropped for this group.
+    fn filters_aaaa(&self, group_id: i64) -> bool;
+
+    /// Reloads the flagged groups from the repository.
+    async fn reload(&self) -> Result<(), DomainError>;
+}
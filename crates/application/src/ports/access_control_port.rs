@@ -0,0 +1,24 @@
+use ferrous_dns_domain::config::ServerConfig;
+use ferrous_dns_domain::AclAction;
+
+/// Snapshot of one listener ACL and how often it rejected a client.
+#[derive(Debug, Clone)]
+pub struct AccessControlStats {
+    /// `"default"` for the `bind_address` listeners, otherwise the listener's
+    /// bind address.
+    pub name: String,
+    pub allowed_clients: Vec<String>,
+    pub action: AclAction,
+    pub refused: u64,
+    pub dropped: u64,
+}
+
+/// Live access control lists enforced by the DNS listeners.
+pub trait AccessControlPort: Send + Sync {
+    /// Replaces the client lists and actions of running listeners from a
+    /// freshly loaded server config. Listeners that were added or removed
+    /// still need a restart.
+    fn apply(&self, server: &ServerConfig);
+
+    fn stats(&self) -> Vec<AccessControlStats>;
+}
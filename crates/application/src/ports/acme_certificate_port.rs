@@ -0,0 +1,10 @@
+use ferrous_dns_domain::DomainError;
+
+/// Keeps the encrypted DNS certificate issued by an ACME CA.
+#[async_trait::async_trait]
+pub trait AcmeCertificatePort: Send + Sync {
+    /// Orders a new certificate when the one on disk is missing, does not
+    /// cover the configured domains or expires within the renewal window.
+    /// Returns whether a certificate was issued.
+    async fn renew_if_due(&self) -> Result<bool, DomainError>;
+}
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::{Alert, AlertKind, DomainError};
+
+#[async_trait]
+pub trait AlertRepository: Send + Sync {
+    async fn insert(&self, alert: &Alert) -> Result<Alert, DomainError>;
+
+    /// Newest first, optionally narrowed to one client and/or kind.
+    async fn get_recent(
+        &self,
+        limit: u32,
+        client_ip: Option<&str>,
+        kind: Option<AlertKind>,
+    ) -> Result<Vec<Alert>, DomainError>;
+
+    async fn delete(&self, id: i64) -> Result<(), DomainError>;
+
+    async fn delete_older_than(&self, days: u32) -> Result<u64, DomainError>;
+}
@@ -7,13 +7,15 @@ use ferrous_dns_domain::{ApiToken, DomainError};
 /// for admin display but never exposed in listing endpoints.
 #[async_trait]
 pub trait ApiTokenRepository: Send + Sync {
-    /// Store a new token (name + hash + prefix + raw). Returns the persisted entity.
+    /// Store a new token (name + hash + prefix + raw), optionally scoped to
+    /// a tenant. Returns the persisted entity.
     async fn create(
         &self,
         name: &str,
         key_prefix: &str,
         key_hash: &str,
         key_raw: &str,
+        tenant_id: Option<i64>,
     ) -> Result<ApiToken, DomainError>;
 
     /// List all tokens (without raw keys — only prefix and metadata).
@@ -47,4 +49,7 @@ pub trait ApiTokenRepository: Send + Sync {
 
     /// Find a token ID by its SHA-256 hash (indexed lookup).
     async fn get_id_by_hash(&self, key_hash: &str) -> Result<Option<i64>, DomainError>;
+
+    /// Find a token by its SHA-256 hash (indexed lookup), including its tenant scope.
+    async fn get_by_hash(&self, key_hash: &str) -> Result<Option<ApiToken>, DomainError>;
 }
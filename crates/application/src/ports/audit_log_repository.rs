@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::{AuditAction, AuditEvent, DomainError};
+
+#[async_trait]
+pub trait AuditLogRepository: Send + Sync {
+    async fn insert(&self, event: &AuditEvent) -> Result<AuditEvent, DomainError>;
+
+    /// Newest first, optionally narrowed to one action.
+    async fn get_recent(
+        &self,
+        limit: u32,
+        action: Option<AuditAction>,
+    ) -> Result<Vec<AuditEvent>, DomainError>;
+
+    async fn delete_older_than(&self, days: u32) -> Result<u64, DomainError>;
+}
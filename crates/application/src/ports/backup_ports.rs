@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use ferrous_dns_domain::{BlocklistSource, DomainError, Group, LocalDnsRecord};
+use std::collections::BTreeMap;
 
 /// Port for creating a group during backup import.
 ///
@@ -37,5 +38,33 @@ pub trait LocalRecordCreator: Send + Sync {
         ip: String,
         record_type: String,
         ttl: Option<u32>,
+        view: Option<String>,
     ) -> Result<LocalDnsRecord, DomainError>;
+
+    /// Every local record already defined, from the config file and the
+    /// database, so that an import can skip the ones it would duplicate.
+    async fn existing_local_records(&self) -> Result<Vec<LocalDnsRecord>, DomainError>;
+}
+
+/// One table row as column name → value.
+pub type BackupRow = serde_json::Map<String, serde_json::Value>;
+
+/// Rows of every table covered by a full backup, keyed by table name.
+pub type BackupTables = BTreeMap<String, Vec<BackupRow>>;
+
+/// Port for dumping and restoring the database tables included in a full backup.
+#[async_trait]
+pub trait BackupStore: Send + Sync {
+    async fn dump(&self) -> Result<BackupTables, DomainError>;
+
+    /// Replace the contents of every table present in `tables` inside a single
+    /// transaction; yields the rows restored per table.
+    async fn restore(&self, tables: &BackupTables) -> Result<BTreeMap<String, usize>, DomainError>;
+}
+
+/// Port for packing backup files into a compressed archive.
+pub trait BackupArchiver: Send + Sync {
+    fn pack(&self, files: &[(String, Vec<u8>)]) -> Result<Vec<u8>, DomainError>;
+
+    fn unpack(&self, bytes: &[u8]) -> Result<Vec<(String, Vec<u8>)>, DomainError>;
 }
@@ -1,21 +1,236 @@
 use async_trait::async_trait;
-use ferrous_dns_domain::{BlockSource, DomainError};
+use ferrous_dns_domain::{BlockSource, DomainError, GroupOverride};
 use std::net::IpAddr;
+use std::sync::Arc;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FilterDecision {
     Block(BlockSource),
+    /// Only lists or groups in audit mode matched: the query is answered
+    /// normally and logged as one `source` would have blocked.
+    Audit(BlockSource),
     Allow,
 }
 
+/// Which part of a compiled list matched the domain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterRule {
+    Exact,
+    Wildcard,
+    Pattern,
+    Regex,
+    Tld,
+}
+
+impl FilterRule {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Exact => "exact",
+            Self::Wildcard => "wildcard",
+            Self::Pattern => "pattern",
+            Self::Regex => "regex",
+            Self::Tld => "tld",
+        }
+    }
+}
+
+/// Allowlist entry that matched, by where it was compiled from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AllowlistMatch {
+    /// The global `whitelist` table.
+    Global(FilterRule),
+    /// Allow-action managed domains and allowlist sources of the group.
+    Group(FilterRule),
+    /// An allow-action regex filter of the group.
+    Regex,
+}
+
+/// Blocklist entry that matched the domain, whether or not the group uses it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlocklistMatch {
+    pub source: BlockSource,
+    pub rule: FilterRule,
+    /// Source bits carrying the entry; zero for managed domains and regex filters.
+    pub bits: u64,
+    /// Whether the entry applies to the group, i.e. `bits` intersects its mask.
+    pub active: bool,
+}
+
+/// A blocklist source and the bit it was assigned in the compiled index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceBit {
+    pub bit: u8,
+    /// `None` for the manual blocklist.
+    pub source_id: Option<i64>,
+    pub name: Arc<str>,
+    /// Matches are logged instead of blocked.
+    pub audit_mode: bool,
+}
+
+/// Blocks attributed to one blocklist source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceHitStats {
+    pub source: SourceBit,
+    /// Entries the source contributed to the compiled index.
+    pub entries: u64,
+    /// Blocks no other list assigned to the client's group would have made.
+    pub unique_blocks: u64,
+    /// Blocks that other lists assigned to the group also matched.
+    pub redundant_blocks: u64,
+}
+
+/// Per-source blocklist hits over a rolling window.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourceHitReport {
+    pub window_secs: u64,
+    pub sources: Vec<SourceHitStats>,
+}
+
+/// Why `check` decides the way it does for a domain and group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilterExplanation {
+    pub decision: FilterDecision,
+    pub blocking_enabled: bool,
+    pub schedule_override: Option<GroupOverride>,
+    pub group_mask: u64,
+    pub allowlist: Vec<AllowlistMatch>,
+    pub blocklist: Vec<BlocklistMatch>,
+    /// Sources whose bits appear in `blocklist`.
+    pub sources: Vec<SourceBit>,
+}
+
+impl FilterExplanation {
+    /// An explanation carrying only the decision.
+    pub fn from_decision(decision: FilterDecision, blocking_enabled: bool) -> Self {
+        Self {
+            decision,
+            blocking_enabled,
+            schedule_override: None,
+            group_mask: 0,
+            allowlist: Vec::new(),
+            blocklist: Vec::new(),
+            sources: Vec::new(),
+        }
+    }
+}
+
+/// Step the block index compiler is at.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BlockFilterCompilePhase {
+    #[default]
+    Idle,
+    /// Rebuilding the index from the lists saved by the last compile.
+    LoadingSnapshot,
+    Fetching,
+    Building,
+}
+
+impl BlockFilterCompilePhase {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Idle => "idle",
+            Self::LoadingSnapshot => "loading_snapshot",
+            Self::Fetching => "fetching",
+            Self::Building => "building",
+        }
+    }
+}
+
+/// Where the block index currently answering queries came from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BlockIndexOrigin {
+    /// Nothing compiled yet; only blocking features outside the index apply.
+    #[default]
+    Empty,
+    /// Lists saved on disk by a previous run.
+    Snapshot,
+    /// Lists freshly fetched from their sources.
+    Sources,
+}
+
+impl BlockIndexOrigin {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Empty => "empty",
+            Self::Snapshot => "snapshot",
+            Self::Sources => "sources",
+        }
+    }
+}
+
+/// Progress of the block index compiler and the index in use.
+#[derive(Debug, Clone, Default)]
+pub struct BlockFilterCompileStatus {
+    pub phase: BlockFilterCompilePhase,
+    pub origin: BlockIndexOrigin,
+    /// Source lists the running compile has to load, and how many it has.
+    pub lists_total: usize,
+    pub lists_done: usize,
+    pub compiled_domains: usize,
+    /// Unix timestamp at which the index in use was built.
+    pub built_at: Option<i64>,
+    pub last_duration_ms: Option<u64>,
+    /// Error of the last compile, cleared by the next successful one.
+    pub last_error: Option<String>,
+}
+
 #[async_trait]
 pub trait BlockFilterEnginePort: Send + Sync {
     fn resolve_group(&self, ip: IpAddr) -> i64;
+    /// Like `resolve_group`, but a client with no group assignment and no
+    /// matching client subnet gets `default_group_id` instead of the global
+    /// default group.
+    fn resolve_group_with_default(&self, ip: IpAddr, default_group_id: i64) -> i64;
+    /// Tenant owning `group_id`, if any. Engines that do not track tenants
+    /// treat every group as shared.
+    fn tenant_of_group(&self, group_id: i64) -> Option<i64> {
+        let _ = group_id;
+        None
+    }
+    /// Group assigned to clients that match no other group, if the engine
+    /// has one.
+    fn default_group_id(&self) -> Option<i64> {
+        None
+    }
     fn check(&self, domain: &str, group_id: i64) -> FilterDecision;
+    /// Same decision as `check`, without counting it in the source hit
+    /// stats. For callers that check again before acting on it.
+    fn peek(&self, domain: &str, group_id: i64) -> FilterDecision {
+        self.check(domain, group_id)
+    }
+    /// Reports the matches behind `check` without reading or writing the
+    /// decision caches. Engines without an inspectable index report only the
+    /// decision.
+    fn explain(&self, domain: &str, group_id: i64) -> FilterExplanation {
+        FilterExplanation::from_decision(self.check(domain, group_id), self.is_blocking_enabled())
+    }
     fn store_cname_decision(&self, domain: &str, group_id: i64, ttl_secs: u64);
     async fn reload(&self) -> Result<(), DomainError>;
     async fn load_client_groups(&self) -> Result<(), DomainError>;
+    /// The source lists behind the index in use, in the on-disk snapshot
+    /// format. `None` when the engine keeps no snapshot.
+    async fn export_list_snapshot(&self) -> Result<Option<Vec<u8>>, DomainError> {
+        Ok(None)
+    }
+    /// Replaces the snapshot with one exported by another instance and
+    /// compiles the index from it, without fetching any source.
+    async fn import_list_snapshot(&self, snapshot: Vec<u8>) -> Result<(), DomainError> {
+        let _ = snapshot;
+        Err(DomainError::BlockFilterCompileError(
+            "this engine keeps no list snapshot".to_string(),
+        ))
+    }
     fn compiled_domain_count(&self) -> usize;
+    fn compile_status(&self) -> BlockFilterCompileStatus {
+        BlockFilterCompileStatus {
+            compiled_domains: self.compiled_domain_count(),
+            ..BlockFilterCompileStatus::default()
+        }
+    }
+    /// Engines that do not track blocklist hits report no sources.
+    fn source_hit_stats(&self) -> SourceHitReport {
+        SourceHitReport::default()
+    }
     fn is_blocking_enabled(&self) -> bool;
     fn set_blocking_enabled(&self, enabled: bool);
 }
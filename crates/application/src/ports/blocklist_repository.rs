@@ -1,15 +1,18 @@
 use async_trait::async_trait;
-use ferrous_dns_domain::{blocklist::BlockedDomain, DomainError};
+use ferrous_dns_domain::{blocklist::BlockedDomain, DomainError, PageRequest};
 
 #[async_trait]
 pub trait BlocklistRepository: Send + Sync {
     async fn get_all(&self) -> Result<Vec<BlockedDomain>, DomainError>;
+    /// Returns one page of domains and the total count.
     async fn get_all_paged(
         &self,
-        limit: u32,
-        offset: u32,
+        page: &PageRequest,
     ) -> Result<(Vec<BlockedDomain>, u64), DomainError>;
     async fn add_domain(&self, domain: &BlockedDomain) -> Result<(), DomainError>;
+    /// Inserts `domains` in a single transaction, skipping ones already
+    /// present. Returns one flag per input: `true` when it was newly added.
+    async fn add_domains(&self, domains: &[String]) -> Result<Vec<bool>, DomainError>;
     async fn remove_domain(&self, domain: &str) -> Result<(), DomainError>;
     async fn is_blocked(&self, domain: &str) -> Result<bool, DomainError>;
 }
@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use ferrous_dns_domain::{BlocklistSource, DomainError};
+use ferrous_dns_domain::{BlocklistSource, DomainError, PageRequest};
 
 #[async_trait]
 pub trait BlocklistSourceRepository: Send + Sync {
@@ -12,10 +12,38 @@ pub trait BlocklistSourceRepository: Send + Sync {
         enabled: bool,
     ) -> Result<BlocklistSource, DomainError>;
 
+    /// Stores a source owned by `tenant_id`.
+    async fn create_in_tenant(
+        &self,
+        tenant_id: i64,
+        name: String,
+        url: Option<String>,
+        group_ids: Vec<i64>,
+        comment: Option<String>,
+        enabled: bool,
+    ) -> Result<BlocklistSource, DomainError>;
+
     async fn get_by_id(&self, id: i64) -> Result<Option<BlocklistSource>, DomainError>;
 
+    /// Returns source `id` only if it is owned by `tenant_id`.
+    async fn get_by_id_in_tenant(
+        &self,
+        tenant_id: i64,
+        id: i64,
+    ) -> Result<Option<BlocklistSource>, DomainError>;
+
     async fn get_all(&self) -> Result<Vec<BlocklistSource>, DomainError>;
 
+    /// Returns the sources owned by `tenant_id`, ordered by name.
+    async fn get_all_in_tenant(&self, tenant_id: i64) -> Result<Vec<BlocklistSource>, DomainError>;
+
+    /// Returns one page of sources and the total count.
+    async fn get_page(
+        &self,
+        page: &PageRequest,
+    ) -> Result<(Vec<BlocklistSource>, u64), DomainError>;
+
+    #[allow(clippy::too_many_arguments)]
     async fn update(
         &self,
         id: i64,
@@ -24,7 +52,11 @@ pub trait BlocklistSourceRepository: Send + Sync {
         group_ids: Option<Vec<i64>>,
         comment: Option<String>,
         enabled: Option<bool>,
+        audit_mode: Option<bool>,
     ) -> Result<BlocklistSource, DomainError>;
 
     async fn delete(&self, id: i64) -> Result<(), DomainError>;
+
+    /// Deletes source `id` only if it is owned by `tenant_id`.
+    async fn delete_in_tenant(&self, tenant_id: i64, id: i64) -> Result<(), DomainError>;
 }
@@ -7,6 +7,8 @@ pub struct CacheRefreshOutcome {
     pub candidates_found: usize,
     pub refreshed: usize,
     pub failed: usize,
+    /// Candidates left for a later cycle because the refresh budget ran out.
+    pub deferred: usize,
     pub cache_size: usize,
 }
 
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::{DomainError, TtlBounds};
+
+/// Hot-path port for per-domain cache TTL overrides, consulted whenever an
+/// answer is stored in the cache.
+#[async_trait]
+pub trait CacheTtlOverrideEnginePort: Send + Sync {
+    /// Bounds of the most specific enabled override for the lowercased
+    /// `domain`, or `None` when the global TTL bounds apply.
+    fn bounds(&self, domain: &str) -> Option<TtlBounds>;
+
+    /// Recompiles the matcher from the repository.
+    async fn reload(&self) -> Result<(), DomainError>;
+}
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::{CacheTtlOverride, DomainError};
+
+#[async_trait]
+pub trait CacheTtlOverrideRepository: Send + Sync {
+    async fn create(
+        &self,
+        ttl_override: &CacheTtlOverride,
+    ) -> Result<CacheTtlOverride, DomainError>;
+
+    async fn get_by_id(&self, id: i64) -> Result<Option<CacheTtlOverride>, DomainError>;
+
+    /// Returns every override ordered by domain.
+    async fn get_all(&self) -> Result<Vec<CacheTtlOverride>, DomainError>;
+
+    /// Replaces every user-editable field of the override with `ttl_override.id`.
+    async fn update(
+        &self,
+        ttl_override: &CacheTtlOverride,
+    ) -> Result<CacheTtlOverride, DomainError>;
+
+    async fn delete(&self, id: i64) -> Result<(), DomainError>;
+}
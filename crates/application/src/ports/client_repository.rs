@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use ferrous_dns_domain::{Client, ClientStats, DomainError};
+use ferrous_dns_domain::{Client, ClientCategory, ClientStats, DomainError, PageRequest};
 use std::net::IpAddr;
 
 #[async_trait]
@@ -8,6 +8,14 @@ pub trait ClientRepository: Send + Sync {
 
     async fn update_last_seen(&self, ip_address: IpAddr) -> Result<(), DomainError>;
 
+    /// Like `update_last_seen`, but a client row created by this sighting is
+    /// placed in `initial_group_id`. Existing rows keep their group.
+    async fn update_last_seen_in_group(
+        &self,
+        ip_address: IpAddr,
+        initial_group_id: i64,
+    ) -> Result<(), DomainError>;
+
     async fn update_mac_address(&self, ip_address: IpAddr, mac: String) -> Result<(), DomainError>;
 
     async fn batch_update_mac_addresses(
@@ -25,6 +33,14 @@ pub trait ClientRepository: Send + Sync {
 
     async fn get_active(&self, days: u32, limit: u32) -> Result<Vec<Client>, DomainError>;
 
+    /// Returns one page of clients and the total count, optionally limited
+    /// to clients seen in the last `active_days` days.
+    async fn get_page(
+        &self,
+        page: &PageRequest,
+        active_days: Option<u32>,
+    ) -> Result<(Vec<Client>, u64), DomainError>;
+
     async fn get_stats(&self) -> Result<ClientStats, DomainError>;
 
     async fn count_active_since(&self, hours: f32) -> Result<u64, DomainError>;
@@ -39,5 +55,14 @@ pub trait ClientRepository: Send + Sync {
 
     async fn assign_group(&self, client_id: i64, group_id: i64) -> Result<(), DomainError>;
 
+    /// Overwrites the user-managed display name, category and notes.
+    async fn update_metadata(
+        &self,
+        client_id: i64,
+        display_name: Option<String>,
+        category: Option<ClientCategory>,
+        notes: Option<String>,
+    ) -> Result<(), DomainError>;
+
     async fn delete(&self, id: i64) -> Result<(), DomainError>;
 }
@@ -0,0 +1,20 @@
+/// Queries sent through one conditional forwarding rule since startup.
+#[derive(Debug, Clone)]
+pub struct ConditionalForwardStats {
+    pub zone: String,
+    pub servers: Vec<String>,
+    pub queries: u64,
+    /// Answers with data or an empty NOERROR.
+    pub answered: u64,
+    pub nxdomain: u64,
+    /// Queries no server answered: timeouts, network errors and SERVFAIL.
+    pub failures: u64,
+    /// Mean time to an answer or NXDOMAIN, across all attempts.
+    pub avg_latency_ms: Option<f64>,
+}
+
+/// Per-rule counters of the conditional forwarder.
+pub trait ConditionalForwardStatsPort: Send + Sync {
+    /// One entry per configured rule, in configuration order.
+    fn rule_stats(&self) -> Vec<ConditionalForwardStats>;
+}
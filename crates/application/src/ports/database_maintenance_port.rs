@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::DomainError;
+
+/// On-disk footprint of the SQLite database.
+#[derive(Debug, Default, Clone)]
+pub struct DatabaseUsage {
+    /// Size of the main database file.
+    pub file_bytes: u64,
+    /// Size of the write-ahead log file.
+    pub wal_bytes: u64,
+    /// Bytes held by pages in use.
+    pub used_bytes: u64,
+    /// Bytes held by free pages that a vacuum can return to the filesystem.
+    pub free_bytes: u64,
+    /// Whether `PRAGMA auto_vacuum` is `INCREMENTAL`.
+    pub incremental_vacuum: bool,
+    /// Size of the filesystem holding the database; 0 when unknown.
+    pub disk_total_bytes: u64,
+    /// Space on that filesystem available to unprivileged writers.
+    pub disk_available_bytes: u64,
+}
+
+/// Port for SQLite housekeeping (size reporting, vacuum, planner statistics).
+#[async_trait]
+pub trait DatabaseMaintenancePort: Send + Sync {
+    async fn usage(&self) -> Result<DatabaseUsage, DomainError>;
+
+    /// Refresh query planner statistics (`PRAGMA optimize`).
+    async fn optimize(&self) -> Result<(), DomainError>;
+
+    /// Return free pages to the filesystem; yields the bytes released.
+    async fn incremental_vacuum(&self) -> Result<u64, DomainError>;
+
+    /// Delete up to `limit` of the oldest query log rows; yields the rows deleted.
+    async fn delete_oldest_query_logs(&self, limit: u32) -> Result<u64, DomainError>;
+}
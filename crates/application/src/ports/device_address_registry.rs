@@ -0,0 +1,10 @@
+use std::net::IpAddr;
+
+/// Live map of MAC address → IP address of the devices on the network, fed
+/// from the ARP table. Lets queries tagged with the requester's MAC by a
+/// forwarder be attributed to the device instead of the forwarder.
+pub trait DeviceAddressRegistry: Send + Sync {
+    /// Replaces every mapping with `devices`, MAC addresses normalized as by
+    /// [`Device::normalize_mac`](ferrous_dns_domain::Device::normalize_mac).
+    fn replace(&self, devices: Vec<(String, IpAddr)>);
+}
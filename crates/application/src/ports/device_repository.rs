@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::{Device, DeviceIpHistory, DomainError, DuplicateClients};
+use std::net::IpAddr;
+
+#[async_trait]
+pub trait DeviceRepository: Send + Sync {
+    /// Records that `mac_address` currently holds `ip_address`, creating the
+    /// device on first sight and extending its IP history.
+    async fn record_sighting(
+        &self,
+        mac_address: &str,
+        ip_address: IpAddr,
+    ) -> Result<(), DomainError>;
+
+    async fn get_all(&self) -> Result<Vec<Device>, DomainError>;
+
+    async fn get_by_mac(&self, mac_address: &str) -> Result<Option<Device>, DomainError>;
+
+    async fn get_ip_history(&self, device_id: i64) -> Result<Vec<DeviceIpHistory>, DomainError>;
+
+    /// Returns every MAC address that is attached to more than one client row.
+    async fn find_duplicate_clients(&self) -> Result<Vec<DuplicateClients>, DomainError>;
+
+    /// Folds the statistics of `duplicate_ids` into `keep_id` and deletes the
+    /// duplicate rows. Returns the number of rows removed.
+    async fn merge_clients(&self, keep_id: i64, duplicate_ids: &[i64]) -> Result<u64, DomainError>;
+}
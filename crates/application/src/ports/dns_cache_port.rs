@@ -1,4 +1,5 @@
 use ferrous_dns_domain::RecordType;
+use serde::{Deserialize, Serialize};
 use std::net::IpAddr;
 
 /// Snapshot of DNS cache metrics for API exposure.
@@ -11,6 +12,12 @@ pub struct CacheMetricsSnapshot {
     pub evictions: u64,
     pub optimistic_refreshes: u64,
     pub stale_hits: u64,
+    /// Hits answered by the per-thread L1 cache; included in `hits`.
+    pub l1_hits: u64,
+    /// Approximate bytes held by cached entries.
+    pub memory_bytes: u64,
+    /// Memory cap from `cache_max_memory_mb`; `0` when unlimited.
+    pub max_memory_bytes: u64,
     pub lazy_deletions: u64,
     pub compactions: u64,
     pub batch_evictions: u64,
@@ -19,6 +26,97 @@ pub struct CacheMetricsSnapshot {
     /// reset, no healthy servers, invalid response, etc.) and therefore NOT
     /// cached as NXDOMAIN. Helps operators diagnose upstream instability.
     pub transient_upstream_errors: u64,
+    /// Background refresh budget; `None` when refreshes are unlimited.
+    pub refresh_budget: Option<RefreshBudgetSnapshot>,
+}
+
+/// State of the budget that caps optimistic and stale refreshes.
+#[derive(Debug, Clone, Default)]
+pub struct RefreshBudgetSnapshot {
+    /// Refreshes per second currently allowed.
+    pub limit_qps: f64,
+    /// Client query rate the limit was derived from.
+    pub client_qps: f64,
+    /// Upstream capacity factor in `[0, 1]` from health-check EWMAs.
+    pub health_factor: f64,
+    /// Refreshes allowed to go upstream since startup.
+    pub granted: u64,
+    /// Refreshes skipped because the budget was exhausted.
+    pub deferred: u64,
+}
+
+/// Freshness of a cache entry as reported by [`DnsCachePort::peek`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheEntryState {
+    Fresh,
+    /// Expired but still served while a background refresh runs.
+    Stale,
+    /// Local record that never expires.
+    Permanent,
+    /// Cached NXDOMAIN/NODATA.
+    Negative,
+}
+
+impl CacheEntryState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Fresh => "fresh",
+            Self::Stale => "stale",
+            Self::Permanent => "permanent",
+            Self::Negative => "negative",
+        }
+    }
+}
+
+/// Read-only view of one cache entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheEntrySnapshot {
+    pub state: CacheEntryState,
+    /// TTL the entry was stored with; `None` for negative entries.
+    pub ttl: Option<u32>,
+    pub remaining_ttl: u32,
+    pub dnssec_status: Option<&'static str>,
+    pub hit_count: u64,
+}
+
+/// Estimated hits for one cache key within [`HotKeysSnapshot::window_secs`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HotCacheKey {
+    pub domain: String,
+    pub record_type: RecordType,
+    pub hits: u64,
+}
+
+/// Most-hit cache keys over a sliding window. `hits` are count-min sketch
+/// estimates, so they may overcount but never undercount.
+#[derive(Debug, Clone, Default)]
+pub struct HotKeysSnapshot {
+    pub window_secs: u64,
+    /// All cache hits recorded in the window.
+    pub total_hits: u64,
+    pub keys: Vec<HotCacheKey>,
+}
+
+/// Answer held by an exported cache entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CacheExportData {
+    Addresses(Vec<IpAddr>),
+    CanonicalName(String),
+    /// Raw DNS wire bytes of any other record type.
+    WireData(Vec<u8>),
+}
+
+/// A positive cache entry as copied from one instance to another.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheExportEntry {
+    pub domain: String,
+    /// Record type mnemonic, e.g. `AAAA`.
+    pub record_type: String,
+    pub data: CacheExportData,
+    /// Seconds the entry had left when it was exported.
+    pub remaining_ttl: u32,
+    pub dnssec_status: Option<String>,
 }
 
 /// Port for DNS cache operations exposed to the API layer.
@@ -32,4 +130,19 @@ pub trait DnsCachePort: Send + Sync {
         addresses: Vec<IpAddr>,
     );
     fn remove_record(&self, domain: &str, record_type: &RecordType) -> bool;
+    /// Removes `suffix` and every name below it, all record types, from the
+    /// positive, negative and DNSSEC caches; local records stay. Returns
+    /// how many entries were removed.
+    fn flush_suffix(&self, suffix: &str) -> usize;
+    /// Looks up an entry without counting a hit, refreshing it or evicting it.
+    fn peek(&self, domain: &str, record_type: RecordType) -> Option<CacheEntrySnapshot>;
+    /// Entries currently held by each shard of the cache map.
+    fn shard_occupancy(&self) -> Vec<usize>;
+    fn hot_keys(&self, limit: usize) -> HotKeysSnapshot;
+    /// Up to `limit` fresh, positive entries shared by every client, most
+    /// hit first. Local records are left out.
+    fn export_entries(&self, limit: usize) -> Vec<CacheExportEntry>;
+    /// Stores entries exported by another instance with their remaining
+    /// TTL, skipping unknown record types. Returns how many were stored.
+    fn import_entries(&self, entries: &[CacheExportEntry]) -> usize;
 }
@@ -1,11 +1,54 @@
 use async_trait::async_trait;
 use bytes::Bytes;
 use ferrous_dns_domain::{DnsQuery, DomainError, RecordType};
+use std::cell::RefCell;
+use std::future::Future;
 use std::net::IpAddr;
 use std::sync::{Arc, LazyLock};
 
+/// Tracing target of the per-query spans (`dns_query` and its phase children)
+/// emitted along the resolver chain. Spans are created at `TRACE` level so they
+/// cost nothing unless the OpenTelemetry layer subscribes to this target.
+pub const QUERY_SPAN_TARGET: &str = "ferrous_dns::query";
+
 pub static EMPTY_CNAME_CHAIN: LazyLock<Arc<[Arc<str>]>> = LazyLock::new(|| Arc::from([]));
 
+/// One upstream exchange made while resolving inside [`trace_upstream_attempts`].
+#[derive(Debug, Clone)]
+pub struct UpstreamAttempt {
+    pub server: Arc<str>,
+    pub pool_name: Arc<str>,
+    pub strategy: &'static str,
+    pub protocol: &'static str,
+    /// 1-based position of the server among the servers tried in the pool.
+    pub attempt: u32,
+    pub latency_us: u64,
+    /// `None` when the server returned a valid response.
+    pub error: Option<String>,
+}
+
+tokio::task_local! {
+    static UPSTREAM_TRACE: RefCell<Vec<UpstreamAttempt>>;
+}
+
+/// Runs `fut` while collecting every upstream exchange it makes on the
+/// current task. Exchanges abandoned by a parallel race are not reported.
+pub async fn trace_upstream_attempts<F: Future>(fut: F) -> (F::Output, Vec<UpstreamAttempt>) {
+    UPSTREAM_TRACE
+        .scope(RefCell::new(Vec::new()), async {
+            let output = fut.await;
+            let attempts = UPSTREAM_TRACE.with(|trace| trace.take());
+            (output, attempts)
+        })
+        .await
+}
+
+/// Records an upstream exchange when called inside [`trace_upstream_attempts`];
+/// otherwise `attempt` is never evaluated.
+pub fn record_upstream_attempt(attempt: impl FnOnce() -> UpstreamAttempt) {
+    let _ = UPSTREAM_TRACE.try_with(|trace| trace.borrow_mut().push(attempt()));
+}
+
 #[derive(Debug, Clone)]
 pub struct DnsResolution {
     pub addresses: Arc<Vec<IpAddr>>,
@@ -15,6 +58,9 @@ pub struct DnsResolution {
     pub cname_chain: Arc<[Arc<str>]>,
     pub upstream_server: Option<Arc<str>>,
     pub upstream_pool: Option<Arc<str>>,
+    pub upstream_strategy: Option<&'static str>,
+    pub upstream_attempt: Option<u32>,
+    pub upstream_protocol: Option<&'static str>,
     pub min_ttl: Option<u32>,
     /// SOA minimum TTL extracted from upstream authority records.
     /// Used by cache layer to set TTL for negative responses.
@@ -35,6 +81,9 @@ impl DnsResolution {
             cname_chain: Arc::clone(&EMPTY_CNAME_CHAIN),
             upstream_server: None,
             upstream_pool: None,
+            upstream_strategy: None,
+            upstream_attempt: None,
+            upstream_protocol: None,
             min_ttl: None,
             negative_soa_ttl: None,
             upstream_wire_data: None,
@@ -62,6 +111,9 @@ impl DnsResolution {
             cname_chain: Arc::clone(&EMPTY_CNAME_CHAIN),
             upstream_server: None,
             upstream_pool: None,
+            upstream_strategy: None,
+            upstream_attempt: None,
+            upstream_protocol: None,
             min_ttl: None,
             negative_soa_ttl: None,
             upstream_wire_data: None,
@@ -73,6 +125,17 @@ impl DnsResolution {
 pub trait DnsResolver: Send + Sync {
     async fn resolve(&self, query: &DnsQuery) -> Result<DnsResolution, DomainError>;
 
+    /// Resolves `query` using only the upstream pool named `pool`, bypassing
+    /// the shared cache. Resolvers that cannot route by pool fall back to
+    /// `resolve`.
+    async fn resolve_via_pool(
+        &self,
+        query: &DnsQuery,
+        _pool: &str,
+    ) -> Result<DnsResolution, DomainError> {
+        self.resolve(query).await
+    }
+
     fn try_cache(&self, _query: &DnsQuery) -> Option<DnsResolution> {
         None
     }
@@ -84,4 +147,19 @@ pub trait DnsResolver: Send + Sync {
         let query = DnsQuery::new(Arc::from(domain), record_type);
         self.try_cache(&query)
     }
+
+    /// Like [`try_cache_str`](Self::try_cache_str), looking only at entries
+    /// stored under `partition` (see [`DnsQuery::cache_partition`]).
+    fn try_cache_partitioned(
+        &self,
+        domain: &str,
+        record_type: RecordType,
+        partition: u32,
+    ) -> Option<DnsResolution> {
+        if partition == 0 {
+            return self.try_cache_str(domain, record_type);
+        }
+        let query = DnsQuery::new(Arc::from(domain), record_type).with_cache_partition(partition);
+        self.try_cache(&query)
+    }
 }
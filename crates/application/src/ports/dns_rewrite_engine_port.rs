@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::{DomainError, RewriteTarget};
+
+/// Hot-path port for DNS rewrites.
+///
+/// Implementors hold the enabled rewrites compiled into a matcher that can
+/// be swapped atomically on reload.
+#[async_trait]
+pub trait DnsRewriteEnginePort: Send + Sync {
+    /// Returns the rewrite answer for `domain`, or `None` when no rewrite
+    /// applies and the query should take the normal path.
+    fn lookup(&self, domain: &str) -> Option<RewriteTarget>;
+
+    /// Recompiles the matcher from the repository.
+    async fn reload(&self) -> Result<(), DomainError>;
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::{DnsRewrite, DomainError};
+
+#[async_trait]
+pub trait DnsRewriteRepository: Send + Sync {
+    async fn create(&self, rewrite: &DnsRewrite) -> Result<DnsRewrite, DomainError>;
+
+    async fn get_by_id(&self, id: i64) -> Result<Option<DnsRewrite>, DomainError>;
+
+    /// Returns every rewrite ordered by domain, then id.
+    async fn get_all(&self) -> Result<Vec<DnsRewrite>, DomainError>;
+
+    /// Replaces every user-editable field of the rewrite with `rewrite.id`.
+    async fn update(&self, rewrite: &DnsRewrite) -> Result<DnsRewrite, DomainError>;
+
+    async fn delete(&self, id: i64) -> Result<(), DomainError>;
+}
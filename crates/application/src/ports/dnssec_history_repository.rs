@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::{
+    DnssecStatsSample, DnssecStatusChange, DnssecValidationCounts, DomainError,
+};
+
+#[async_trait]
+pub trait DnssecHistoryRepository: Send + Sync {
+    /// Stores the outcome counts of the interval ending now.
+    async fn insert_sample(&self, counts: &DnssecValidationCounts) -> Result<(), DomainError>;
+
+    async fn insert_changes(&self, changes: &[DnssecStatusChange]) -> Result<(), DomainError>;
+
+    /// Samples of the last `period_hours`, oldest first.
+    async fn get_samples(&self, period_hours: u32) -> Result<Vec<DnssecStatsSample>, DomainError>;
+
+    /// Newest first, optionally narrowed to one domain and/or new status.
+    async fn get_changes(
+        &self,
+        domain: Option<&str>,
+        status: Option<&'static str>,
+        limit: u32,
+    ) -> Result<Vec<DnssecStatusChange>, DomainError>;
+
+    /// Deletes samples and changes older than `days`.
+    async fn delete_older_than(&self, days: u32) -> Result<u64, DomainError>;
+}
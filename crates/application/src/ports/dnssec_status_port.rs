@@ -0,0 +1,30 @@
+use ferrous_dns_domain::{DnssecStatusChange, DnssecValidationCounts, RecordType};
+
+/// Validation result held in the DNSSEC cache for one record type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DnssecCachedValidation {
+    pub record_type: RecordType,
+    /// `Secure`, `Insecure`, `Bogus` or `Indeterminate`.
+    pub status: &'static str,
+    pub validated_secs_ago: u64,
+    /// Past its TTL; kept only to detect the next status change.
+    pub expired: bool,
+}
+
+/// Outcomes collected since the previous [`DnssecStatusPort::take_outcomes`].
+#[derive(Debug, Clone, Default)]
+pub struct DnssecOutcomes {
+    pub counts: DnssecValidationCounts,
+    /// Oldest first; `id` is unset.
+    pub changes: Vec<DnssecStatusChange>,
+}
+
+/// DNSSEC validation results recorded by the validation layer.
+pub trait DnssecStatusPort: Send + Sync {
+    /// Results cached for `domain`, one per validated record type.
+    fn cached(&self, domain: &str) -> Vec<DnssecCachedValidation>;
+
+    /// Drains the outcome counters and status changes gathered since the
+    /// last call, and forgets results that expired long ago.
+    fn take_outcomes(&self) -> DnssecOutcomes;
+}
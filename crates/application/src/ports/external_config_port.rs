@@ -0,0 +1,104 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::{DomainAction, DomainError};
+use std::str::FromStr;
+
+/// Product a configuration is imported from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExternalFormat {
+    /// Pi-hole Teleporter archive (v5 tar.gz or v6 zip).
+    PiHole,
+    /// AdGuard Home `AdGuardHome.yaml`.
+    AdGuardHome,
+}
+
+impl ExternalFormat {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::PiHole => "pihole",
+            Self::AdGuardHome => "adguard",
+        }
+    }
+}
+
+impl FromStr for ExternalFormat {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "pihole" | "pi-hole" => Ok(Self::PiHole),
+            "adguard" | "adguardhome" | "adguard-home" => Ok(Self::AdGuardHome),
+            other => Err(DomainError::InvalidInput(format!(
+                "Unknown import format '{}': use 'pihole' or 'adguard'",
+                other
+            ))),
+        }
+    }
+}
+
+/// Group references below are by name; an empty list means the default group.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExternalGroup {
+    pub name: String,
+    pub comment: Option<String>,
+    pub enabled: bool,
+}
+
+/// Subscribed list (adlist / filter). `allow` marks allowlists.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExternalList {
+    pub name: Option<String>,
+    pub url: String,
+    pub comment: Option<String>,
+    pub enabled: bool,
+    pub allow: bool,
+    pub groups: Vec<String>,
+}
+
+/// Single allow/deny entry; `regex` entries become regex filters.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExternalDomainRule {
+    pub value: String,
+    pub regex: bool,
+    pub action: DomainAction,
+    pub comment: Option<String>,
+    pub enabled: bool,
+    pub groups: Vec<String>,
+}
+
+/// Custom DNS answer: `name` resolves to `answer` (an IP or a CNAME target).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExternalRecord {
+    pub name: String,
+    pub answer: String,
+}
+
+/// Client identified by IP, CIDR, MAC or another product-specific id.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExternalClient {
+    pub id: String,
+    pub name: Option<String>,
+    pub comment: Option<String>,
+    pub groups: Vec<String>,
+}
+
+/// Product-neutral view of an imported configuration.
+#[derive(Debug, Clone, Default)]
+pub struct ExternalConfig {
+    pub groups: Vec<ExternalGroup>,
+    pub lists: Vec<ExternalList>,
+    pub domain_rules: Vec<ExternalDomainRule>,
+    pub records: Vec<ExternalRecord>,
+    pub clients: Vec<ExternalClient>,
+    /// Entries the reader could not translate.
+    pub warnings: Vec<String>,
+}
+
+/// Port for parsing another DNS filter's export into an [`ExternalConfig`].
+#[async_trait]
+pub trait ExternalConfigReader: Send + Sync {
+    async fn read(
+        &self,
+        format: ExternalFormat,
+        bytes: &[u8],
+    ) -> Result<ExternalConfig, DomainError>;
+}
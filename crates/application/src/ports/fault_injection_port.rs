@@ -0,0 +1,57 @@
+use ferrous_dns_domain::DomainError;
+
+/// A fault applied to upstream queries, to exercise failover, serve-stale
+/// and alerting without touching the network.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FaultKind {
+    /// Queries and health checks to `server` fail as if it were unreachable.
+    UpstreamDown { server: String },
+    /// Queries and health checks to `server`, or to every upstream when
+    /// unset, are held back for `delay_ms` before being sent.
+    Latency {
+        server: Option<String>,
+        delay_ms: u64,
+    },
+    /// Every upstream answers SERVFAIL for names at or under `pattern`;
+    /// `*` matches every name.
+    Servfail { pattern: String },
+}
+
+impl FaultKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::UpstreamDown { .. } => "upstream_down",
+            Self::Latency { .. } => "latency",
+            Self::Servfail { .. } => "servfail",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct InjectedFault {
+    pub id: u64,
+    pub kind: FaultKind,
+    /// Seconds until the fault is lifted on its own; `None` keeps it until
+    /// it is removed.
+    pub remaining_secs: Option<u64>,
+    /// Upstream queries and health checks the fault has applied to.
+    pub hits: u64,
+}
+
+/// Runtime fault injection for staging environments. Only available in
+/// builds with the `fault-injection` feature.
+pub trait FaultInjectionPort: Send + Sync {
+    fn inject(
+        &self,
+        kind: FaultKind,
+        duration_secs: Option<u64>,
+    ) -> Result<InjectedFault, DomainError>;
+
+    /// Active faults, oldest first.
+    fn faults(&self) -> Vec<InjectedFault>;
+
+    fn remove(&self, id: u64) -> bool;
+
+    /// Lifts every fault, returning how many were active.
+    fn clear(&self) -> usize;
+}
@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use ferrous_dns_domain::{Client, DomainError, Group};
+use ferrous_dns_domain::{Client, DomainError, Group, PageRequest};
 
 #[async_trait]
 pub trait GroupRepository: Send + Sync {
@@ -8,12 +8,26 @@ pub trait GroupRepository: Send + Sync {
     async fn get_by_name(&self, name: &str) -> Result<Option<Group>, DomainError>;
     async fn get_all(&self) -> Result<Vec<Group>, DomainError>;
     async fn get_all_with_client_counts(&self) -> Result<Vec<(Group, u64)>, DomainError>;
+    /// Groups owned by `tenant_id` with their client counts; never returns
+    /// another tenant's groups.
+    async fn get_in_tenant_with_client_counts(
+        &self,
+        tenant_id: i64,
+    ) -> Result<Vec<(Group, u64)>, DomainError>;
+    /// Returns one page of groups with their client counts, and the total
+    /// number of groups.
+    async fn get_page_with_client_counts(
+        &self,
+        page: &PageRequest,
+    ) -> Result<(Vec<(Group, u64)>, u64), DomainError>;
     async fn update(
         &self,
         id: i64,
         name: Option<String>,
         enabled: Option<bool>,
         comment: Option<String>,
+        filter_aaaa: Option<bool>,
+        audit_mode: Option<bool>,
     ) -> Result<Group, DomainError>;
     async fn delete(&self, id: i64) -> Result<(), DomainError>;
     async fn get_clients_in_group(&self, group_id: i64) -> Result<Vec<Client>, DomainError>;
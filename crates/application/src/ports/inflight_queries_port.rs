@@ -0,0 +1,30 @@
+use ferrous_dns_domain::RecordType;
+
+/// An upstream resolution other queries for the same name are waiting on.
+#[derive(Debug, Clone)]
+pub struct InflightQuery {
+    pub domain: String,
+    pub record_type: RecordType,
+    /// Queries coalesced onto this resolution, not counting the one that
+    /// started it.
+    pub waiters: usize,
+    pub elapsed_ms: u64,
+}
+
+/// In-flight upstream resolutions and query deduplication totals.
+#[derive(Debug, Clone, Default)]
+pub struct InflightQueriesSnapshot {
+    /// Longest-running resolutions first.
+    pub queries: Vec<InflightQuery>,
+    /// Resolutions currently in flight, including those beyond the limit.
+    pub total: usize,
+    /// Queries that went upstream themselves since startup.
+    pub leaders: u64,
+    /// Queries that waited on another query's resolution since startup.
+    pub coalesced: u64,
+}
+
+/// Visibility into the query-coalescing map of the cache layer.
+pub trait InflightQueriesPort: Send + Sync {
+    fn inflight(&self, limit: usize) -> InflightQueriesSnapshot;
+}
@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::{DomainError, IpBlocklistSource};
+
+#[async_trait]
+pub trait IpBlocklistSourceRepository: Send + Sync {
+    async fn create(
+        &self,
+        name: String,
+        url: String,
+        comment: Option<String>,
+        enabled: bool,
+    ) -> Result<IpBlocklistSource, DomainError>;
+
+    async fn get_by_id(&self, id: i64) -> Result<Option<IpBlocklistSource>, DomainError>;
+
+    async fn get_all(&self) -> Result<Vec<IpBlocklistSource>, DomainError>;
+
+    async fn update(
+        &self,
+        id: i64,
+        name: Option<String>,
+        url: Option<String>,
+        comment: Option<String>,
+        enabled: Option<bool>,
+    ) -> Result<IpBlocklistSource, DomainError>;
+
+    async fn delete(&self, id: i64) -> Result<(), DomainError>;
+}
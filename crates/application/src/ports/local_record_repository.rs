@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::{DomainError, LocalRecord};
+
+#[async_trait]
+pub trait LocalRecordRepository: Send + Sync {
+    async fn create(&self, record: &LocalRecord) -> Result<LocalRecord, DomainError>;
+
+    async fn get_by_id(&self, id: i64) -> Result<Option<LocalRecord>, DomainError>;
+
+    /// Returns record `id` only if it is owned by `tenant_id`.
+    async fn get_by_id_in_tenant(
+        &self,
+        tenant_id: i64,
+        id: i64,
+    ) -> Result<Option<LocalRecord>, DomainError>;
+
+    /// Returns every record ordered by hostname, domain, then id.
+    async fn get_all(&self) -> Result<Vec<LocalRecord>, DomainError>;
+
+    /// Returns the records owned by `tenant_id`, in `get_all` order.
+    async fn get_all_in_tenant(&self, tenant_id: i64) -> Result<Vec<LocalRecord>, DomainError>;
+
+    /// Replaces every user-editable field of the record with `record.id`.
+    async fn update(&self, record: &LocalRecord) -> Result<LocalRecord, DomainError>;
+
+    async fn delete(&self, id: i64) -> Result<(), DomainError>;
+}
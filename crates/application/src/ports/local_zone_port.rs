@@ -0,0 +1,53 @@
+use super::DnsResolution;
+use async_trait::async_trait;
+use ferrous_dns_domain::{DomainError, LocalDnsRecord, LocalRecord, RecordType};
+use std::sync::Arc;
+
+/// What the local zone answers for a query.
+#[derive(Debug, Clone)]
+pub enum LocalZoneAnswer {
+    /// Complete answer built from the zone's records.
+    Records(DnsResolution),
+    /// The name is an alias; `target` is resolved in its place.
+    Cname(Arc<str>),
+}
+
+/// Live lookup over the SQLite-backed local records.
+///
+/// Implementors hold the records compiled into a zone that can be swapped
+/// atomically on reload.
+#[async_trait]
+pub trait LocalZonePort: Send + Sync {
+    /// Returns the zone's answer for `domain`, or `None` when the zone has
+    /// no record of `record_type` for it and the query should take the
+    /// normal path.
+    fn lookup(&self, domain: &str, record_type: RecordType) -> Option<LocalZoneAnswer>;
+
+    /// Like `lookup`, for a client of `tenant_id`: the tenant's own records
+    /// shadow the shared zone, and other tenants' records are never answered.
+    fn lookup_for_tenant(
+        &self,
+        tenant_id: Option<i64>,
+        domain: &str,
+        record_type: RecordType,
+    ) -> Option<LocalZoneAnswer> {
+        let _ = tenant_id;
+        self.lookup(domain, record_type)
+    }
+
+    /// View-tagged records from the last load, to be compiled into the
+    /// split-horizon views alongside `[[dns.local_records]]`.
+    fn view_records(&self) -> Vec<LocalDnsRecord> {
+        Vec::new()
+    }
+
+    /// Replaces the records published by the [`RecordSource`](super::RecordSource)
+    /// named `source`. They are answered like shared records but never
+    /// stored, and take effect on the next `reload`.
+    fn set_source_records(&self, source: &str, records: Vec<LocalRecord>) {
+        let _ = (source, records);
+    }
+
+    /// Recompiles the zone from the repository.
+    async fn reload(&self) -> Result<(), DomainError>;
+}
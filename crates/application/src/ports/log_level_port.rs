@@ -0,0 +1,13 @@
+use ferrous_dns_domain::DomainError;
+use std::collections::BTreeMap;
+
+/// Runtime control over the log filter.
+pub trait LogLevelPort: Send + Sync {
+    /// Replaces the default level and every per-module override. Module keys
+    /// follow `logging.modules` in the config file.
+    fn set_levels(
+        &self,
+        level: &str,
+        modules: &BTreeMap<String, String>,
+    ) -> Result<(), DomainError>;
+}
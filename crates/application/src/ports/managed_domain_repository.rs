@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use ferrous_dns_domain::{DomainAction, DomainError, ManagedDomain};
+use ferrous_dns_domain::{DomainAction, DomainError, ManagedDomain, PageRequest};
 
 #[async_trait]
 pub trait ManagedDomainRepository: Send + Sync {
@@ -17,10 +17,10 @@ pub trait ManagedDomainRepository: Send + Sync {
 
     async fn get_all(&self) -> Result<Vec<ManagedDomain>, DomainError>;
 
+    /// Returns one page of managed domains and the total count.
     async fn get_all_paged(
         &self,
-        limit: u32,
-        offset: u32,
+        page: &PageRequest,
     ) -> Result<(Vec<ManagedDomain>, u64), DomainError>;
 
     #[allow(clippy::too_many_arguments)]
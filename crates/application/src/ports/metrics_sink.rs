@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::DomainError;
+
+/// Time-series database receiving exported metrics as InfluxDB line
+/// protocol (InfluxDB, VictoriaMetrics, ...).
+#[async_trait]
+pub trait MetricsSink: Send + Sync {
+    /// Writes newline-separated points.
+    async fn write(&self, lines: String) -> Result<(), DomainError>;
+}
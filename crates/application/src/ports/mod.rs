@@ -1,79 +1,190 @@
+mod aaaa_filter_port;
+mod access_control_port;
+mod acme_certificate_port;
+mod alert_repository;
 mod api_token_repository;
 mod arp_reader;
+mod audit_log_repository;
 mod backup_ports;
 mod block_filter_engine;
 mod blocked_service_repository;
 mod blocklist_repository;
 mod blocklist_source_repository;
 mod cache_maintenance_port;
+mod cache_ttl_override_engine_port;
+mod cache_ttl_override_repository;
 mod client_repository;
 mod client_subnet_repository;
+mod conditional_forward_stats_port;
 mod config_file_port;
 mod config_repository;
 mod custom_service_repository;
+mod database_maintenance_port;
+mod device_address_registry;
+mod device_repository;
 mod dga_flag_store;
 mod dns_cache_port;
 mod dns_resolver;
+mod dns_rewrite_engine_port;
+mod dns_rewrite_repository;
+mod dnssec_history_repository;
+mod dnssec_status_port;
+mod external_config_port;
+mod fault_injection_port;
 mod group_repository;
 mod hostname_resolver;
+mod inflight_queries_port;
+mod ip_blocklist_source_repository;
+mod local_record_repository;
+mod local_zone_port;
+mod log_level_port;
 mod managed_domain_repository;
+mod metrics_sink;
+mod network_interface_port;
+mod notification_sender;
 mod nxdomain_hijack_store;
+mod plugin_hook_port;
 mod ptr_record_registry;
 mod query_log_repository;
+mod query_metrics_port;
+mod query_policy_engine_port;
+mod query_policy_repository;
+mod record_source;
+mod record_type_filter_port;
+mod record_type_policy_repository;
 mod regex_filter_repository;
+mod report_sender;
 mod response_ip_filter_store;
 mod safe_search_config_repository;
 mod safe_search_engine_port;
 mod schedule_profile_repository;
 mod schedule_state_port;
+mod secondary_zone_port;
+mod secondary_zone_repository;
 mod service_catalog_port;
 mod session_repository;
+mod sinkhole_telemetry_port;
+mod slow_query_log_port;
+mod split_horizon_port;
+mod standby_port;
+mod system_metrics_port;
+mod tenant_repository;
+mod tld_policy_repository;
 mod tls_certificate_port;
 mod tunneling_flag_store;
+mod unblock_request_repository;
+mod upstream_address_refresh_port;
+mod upstream_address_repository;
 mod upstream_health_port;
 mod user_repository;
 mod whitelist_repository;
 mod whitelist_source_repository;
 
+pub use aaaa_filter_port::AaaaFilterPort;
+pub use access_control_port::{AccessControlPort, AccessControlStats};
+pub use acme_certificate_port::AcmeCertificatePort;
+pub use alert_repository::AlertRepository;
 pub use api_token_repository::ApiTokenRepository;
 pub use arp_reader::{ArpReader, ArpTable};
-pub use backup_ports::{BlocklistSourceCreator, GroupCreator, LocalRecordCreator};
-pub use block_filter_engine::{BlockFilterEnginePort, FilterDecision};
+pub use audit_log_repository::AuditLogRepository;
+pub use backup_ports::{
+    BackupArchiver, BackupRow, BackupStore, BackupTables, BlocklistSourceCreator, GroupCreator,
+    LocalRecordCreator,
+};
+pub use block_filter_engine::{
+    AllowlistMatch, BlockFilterCompilePhase, BlockFilterCompileStatus, BlockFilterEnginePort,
+    BlockIndexOrigin, BlocklistMatch, FilterDecision, FilterExplanation, FilterRule, SourceBit,
+    SourceHitReport, SourceHitStats,
+};
 pub use blocked_service_repository::BlockedServiceRepository;
 pub use blocklist_repository::BlocklistRepository;
 pub use blocklist_source_repository::BlocklistSourceRepository;
 pub use cache_maintenance_port::{
     CacheCompactionOutcome, CacheMaintenancePort, CacheRefreshOutcome,
 };
+pub use cache_ttl_override_engine_port::CacheTtlOverrideEnginePort;
+pub use cache_ttl_override_repository::CacheTtlOverrideRepository;
 pub use client_repository::ClientRepository;
 pub use client_subnet_repository::ClientSubnetRepository;
+pub use conditional_forward_stats_port::{ConditionalForwardStats, ConditionalForwardStatsPort};
 pub use config_file_port::ConfigFilePersistence;
 pub use config_repository::ConfigRepository;
 pub use custom_service_repository::CustomServiceRepository;
+pub use database_maintenance_port::{DatabaseMaintenancePort, DatabaseUsage};
+pub use device_address_registry::DeviceAddressRegistry;
+pub use device_repository::DeviceRepository;
 pub use dga_flag_store::{DgaEvictionTarget, DgaFlagStore};
-pub use dns_cache_port::{CacheMetricsSnapshot, DnsCachePort};
-pub use dns_resolver::{DnsResolution, DnsResolver, EMPTY_CNAME_CHAIN};
+pub use dns_cache_port::{
+    CacheEntrySnapshot, CacheEntryState, CacheExportData, CacheExportEntry, CacheMetricsSnapshot,
+    DnsCachePort, HotCacheKey, HotKeysSnapshot, RefreshBudgetSnapshot,
+};
+pub use dns_resolver::{
+    record_upstream_attempt, trace_upstream_attempts, DnsResolution, DnsResolver, UpstreamAttempt,
+    EMPTY_CNAME_CHAIN, QUERY_SPAN_TARGET,
+};
+pub use dns_rewrite_engine_port::DnsRewriteEnginePort;
+pub use dns_rewrite_repository::DnsRewriteRepository;
+pub use dnssec_history_repository::DnssecHistoryRepository;
+pub use dnssec_status_port::{DnssecCachedValidation, DnssecOutcomes, DnssecStatusPort};
+pub use external_config_port::{
+    ExternalClient, ExternalConfig, ExternalConfigReader, ExternalDomainRule, ExternalFormat,
+    ExternalGroup, ExternalList, ExternalRecord,
+};
+pub use fault_injection_port::{FaultInjectionPort, FaultKind, InjectedFault};
 pub use group_repository::GroupRepository;
 pub use hostname_resolver::HostnameResolver;
+pub use inflight_queries_port::{InflightQueriesPort, InflightQueriesSnapshot, InflightQuery};
+pub use ip_blocklist_source_repository::IpBlocklistSourceRepository;
+pub use local_record_repository::LocalRecordRepository;
+pub use local_zone_port::{LocalZoneAnswer, LocalZonePort};
+pub use log_level_port::LogLevelPort;
 pub use managed_domain_repository::ManagedDomainRepository;
+pub use metrics_sink::MetricsSink;
+pub use network_interface_port::{NetworkInterface, NetworkInterfacePort};
+pub use notification_sender::NotificationSender;
 pub use nxdomain_hijack_store::{NxdomainHijackIpStore, NxdomainHijackProbeTarget};
-pub use ptr_record_registry::PtrRecordRegistry;
+pub use plugin_hook_port::{PluginDecision, PluginHookPort, PluginQuery, PluginVerdict};
+pub use ptr_record_registry::{PtrRecordRegistry, CLIENT_PTR_TTL};
 pub use query_log_repository::{
-    CacheStats, PagedQueryResult, QueryLogRepository, TimeGranularity, TimelineBucket,
+    CacheStats, ClientActivity, ClientDomainCount, MetricsWindow, PagedQueryResult,
+    QueryLogRepository, TimeGranularity, TimelineBreakdown, TimelineBucket, TimelineSeries,
+    UpstreamWindowStats,
 };
+pub use query_metrics_port::QueryMetricsPort;
+pub use query_policy_engine_port::QueryPolicyEnginePort;
+pub use query_policy_repository::QueryPolicyRepository;
+pub use record_source::RecordSource;
+pub use record_type_filter_port::RecordTypeFilterPort;
+pub use record_type_policy_repository::RecordTypePolicyRepository;
 pub use regex_filter_repository::RegexFilterRepository;
+pub use report_sender::ReportSender;
 pub use response_ip_filter_store::{ResponseIpFilterEvictionTarget, ResponseIpFilterStore};
 pub use safe_search_config_repository::SafeSearchConfigRepository;
 pub use safe_search_engine_port::SafeSearchEnginePort;
 pub use schedule_profile_repository::ScheduleProfileRepository;
 pub use schedule_state_port::ScheduleStatePort;
+pub use secondary_zone_port::SecondaryZonePort;
+pub use secondary_zone_repository::SecondaryZoneRepository;
 pub use service_catalog_port::ServiceCatalogPort;
 pub use session_repository::SessionRepository;
+pub use sinkhole_telemetry_port::{SinkholeHostStats, SinkholeTelemetryPort};
+pub use slow_query_log_port::{QueryPhaseTimings, SlowQueryEntry, SlowQueryLogPort};
+pub use split_horizon_port::SplitHorizonPort;
+pub use standby_port::PrimaryInstanceClient;
+pub use system_metrics_port::{
+    ChannelDepth, ChannelDepthSource, ProcessMetrics, SystemMetricsPort, UdpPoolMetrics,
+    UdpPoolMetricsSource, UdpServerPoolMetrics,
+};
+pub use tenant_repository::TenantRepository;
+pub use tld_policy_repository::TldPolicyRepository;
 pub use tls_certificate_port::{TlsCertificateInfo, TlsCertificatePort};
 pub use tunneling_flag_store::{TunnelingEvictionTarget, TunnelingFlagStore};
+pub use unblock_request_repository::UnblockRequestRepository;
+pub use upstream_address_refresh_port::{UpstreamAddressRefresh, UpstreamAddressRefreshPort};
+pub use upstream_address_repository::UpstreamAddressRepository;
 pub use upstream_health_port::{
-    AggregateStatus, IpFamily, ResolvedEndpointHealth, UpstreamGroupHealth, UpstreamHealthPort,
-    UpstreamStatus,
+    AggregateStatus, IpFamily, ResolvedEndpointHealth, ResponseValidationStats,
+    UpstreamGroupHealth, UpstreamHealthPort, UpstreamRoute, UpstreamStatus,
 };
 pub use user_repository::{CreateUserInput, PasswordHasher, UserProvider, UserRepository};
 pub use whitelist_repository::WhitelistRepository;
@@ -0,0 +1,13 @@
+use std::net::IpAddr;
+
+/// An address assigned to one of the host's network interfaces.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkInterface {
+    pub name: String,
+    pub address: IpAddr,
+}
+
+/// Lists the addresses the server could bind its listeners to.
+pub trait NetworkInterfacePort: Send + Sync {
+    fn list_interfaces(&self) -> Vec<NetworkInterface>;
+}
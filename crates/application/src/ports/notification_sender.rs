@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::{DomainError, Notification};
+
+/// Delivery channel for notifications (webhook, Telegram, SMTP, ...).
+#[async_trait]
+pub trait NotificationSender: Send + Sync {
+    /// Short channel name used in logs.
+    fn name(&self) -> &'static str;
+
+    async fn send(&self, notification: &Notification) -> Result<(), DomainError>;
+}
@@ -0,0 +1,50 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::RecordType;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+/// Query fields handed to a plugin hook.
+#[derive(Debug, Clone)]
+pub struct PluginQuery {
+    pub domain: Arc<str>,
+    pub record_type: RecordType,
+    pub client_ip: IpAddr,
+    pub group_id: i64,
+}
+
+/// What a plugin decided for a query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PluginVerdict {
+    Block,
+    NxDomain,
+    /// Answer with these addresses instead.
+    Answer(Vec<IpAddr>),
+}
+
+/// A verdict and the script that returned it, for the query log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluginDecision {
+    pub plugin: Arc<str>,
+    pub verdict: PluginVerdict,
+}
+
+/// Hot-path port for user scripts hooked into the resolve pipeline.
+///
+/// Implementors bound every call in time; a failing or timed-out script
+/// lets the query continue.
+#[async_trait]
+pub trait PluginHookPort: Send + Sync {
+    /// Whether any hook runs for `domain`. Queries no hook covers keep the
+    /// cache fast paths.
+    fn intercepts(&self, domain: &str) -> bool;
+
+    /// Runs before the block filter. `None` lets the query continue.
+    async fn pre_filter(&self, query: &PluginQuery) -> Option<PluginDecision>;
+
+    /// Runs on every successful answer, with its addresses.
+    async fn post_resolve(
+        &self,
+        query: &PluginQuery,
+        addresses: &[IpAddr],
+    ) -> Option<PluginDecision>;
+}
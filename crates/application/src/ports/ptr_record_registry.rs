@@ -1,6 +1,9 @@
 use std::net::IpAddr;
 use std::sync::Arc;
 
+/// TTL of PTR answers generated from client hostnames.
+pub const CLIENT_PTR_TTL: u32 = 300;
+
 /// Live registry of IP address → PTR hostname mappings derived from local DNS records.
 ///
 /// Implementations keep an in-memory map updated at runtime so that PTR queries are
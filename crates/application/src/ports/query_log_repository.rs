@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 use ferrous_dns_domain::{
-    query_log::{QueryLog, QueryLogFilter, QueryStats},
+    query_log::{QueryCountBreakdown, QueryLog, QueryLogFilter, QueryStats},
     DomainError,
 };
 
@@ -24,6 +24,42 @@ pub enum TimeGranularity {
     Day,
 }
 
+impl TimeGranularity {
+    /// Ordered from finest to coarsest.
+    pub const ALL: [TimeGranularity; 5] = [
+        TimeGranularity::Minute,
+        TimeGranularity::TenMinutes,
+        TimeGranularity::QuarterHour,
+        TimeGranularity::Hour,
+        TimeGranularity::Day,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimeGranularity::Minute => "minute",
+            TimeGranularity::TenMinutes => "10min",
+            TimeGranularity::QuarterHour => "15min",
+            TimeGranularity::Hour => "hour",
+            TimeGranularity::Day => "day",
+        }
+    }
+
+    pub fn bucket_minutes(&self) -> u32 {
+        match self {
+            TimeGranularity::Minute => 1,
+            TimeGranularity::TenMinutes => 10,
+            TimeGranularity::QuarterHour => 15,
+            TimeGranularity::Hour => 60,
+            TimeGranularity::Day => 1_440,
+        }
+    }
+
+    /// Number of buckets needed to cover `period_hours`.
+    pub fn bucket_count(&self, period_hours: u32) -> u32 {
+        (period_hours * 60).div_ceil(self.bucket_minutes())
+    }
+}
+
 /// Aggregated query counts for a single time window in the timeline chart.
 #[derive(Debug, Clone)]
 pub struct TimelineBucket {
@@ -32,6 +68,73 @@ pub struct TimelineBucket {
     pub blocked: u64,
     pub unblocked: u64,
     pub malware_detected: u64,
+    pub cached: u64,
+    /// Answered by an upstream server (not blocked, cached, local or rate limited).
+    pub forwarded: u64,
+}
+
+/// Dimension used to split the timeline or the stats into one entry per key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimelineBreakdown {
+    Client,
+    Group,
+}
+
+/// Timeline of a single client or group.
+#[derive(Debug, Clone)]
+pub struct TimelineSeries {
+    /// Client IP or group id.
+    pub key: String,
+    /// Client display name or hostname, or group name.
+    pub label: Option<String>,
+    pub buckets: Vec<TimelineBucket>,
+}
+
+/// Query activity of a single client over a time window.
+#[derive(Debug, Clone, Default)]
+pub struct ClientActivity {
+    pub total_queries: u64,
+    pub blocked_queries: u64,
+    pub cache_hits: u64,
+    pub cache_hit_rate: f64,
+    pub timeline: Vec<TimelineBucket>,
+    pub top_domains: Vec<(String, u64)>,
+    pub top_blocked_domains: Vec<(String, u64)>,
+}
+
+/// Queries from one client for one domain within a window.
+#[derive(Debug, Clone)]
+pub struct ClientDomainCount {
+    pub client_ip: String,
+    pub domain: String,
+    pub queries: u64,
+    pub nxdomain: u64,
+}
+
+/// Client query aggregates over a short window, pushed by the metrics
+/// exporter.
+#[derive(Debug, Clone, Default)]
+pub struct MetricsWindow {
+    pub queries: u64,
+    pub blocked: u64,
+    pub cache_hits: u64,
+    /// Answered SERVFAIL or timed out.
+    pub errors: u64,
+    /// Response time percentiles in milliseconds, `None` without queries.
+    pub latency_p50_ms: Option<f64>,
+    pub latency_p90_ms: Option<f64>,
+    pub latency_p99_ms: Option<f64>,
+    pub upstreams: Vec<UpstreamWindowStats>,
+}
+
+/// Queries forwarded to one upstream server within a [`MetricsWindow`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpstreamWindowStats {
+    pub pool: String,
+    pub server: String,
+    pub queries: u64,
+    pub errors: u64,
+    pub avg_latency_ms: f64,
 }
 
 #[async_trait]
@@ -66,7 +169,25 @@ pub trait QueryLogRepository: Send + Sync {
         period_hours: u32,
         granularity: TimeGranularity,
     ) -> Result<Vec<TimelineBucket>, DomainError>;
+    /// Timeline split per client or group, keeping the `limit` busiest keys.
+    async fn get_timeline_breakdown(
+        &self,
+        period_hours: u32,
+        granularity: TimeGranularity,
+        breakdown: TimelineBreakdown,
+        limit: u32,
+    ) -> Result<Vec<TimelineSeries>, DomainError>;
+    /// Query, blocked and cache hit counts per client or group, busiest
+    /// first. `None` returns every key.
+    async fn get_query_counts_by(
+        &self,
+        period_hours: f32,
+        breakdown: TimelineBreakdown,
+        limit: Option<u32>,
+    ) -> Result<Vec<QueryCountBreakdown>, DomainError>;
     async fn count_queries_since(&self, seconds_ago: i64) -> Result<u64, DomainError>;
+    /// Aggregates of client queries logged in the last `seconds_ago` seconds.
+    async fn get_metrics_window(&self, seconds_ago: i64) -> Result<MetricsWindow, DomainError>;
     async fn get_cache_stats(&self, period_hours: f32) -> Result<CacheStats, DomainError>;
     async fn get_top_blocked_domains(
         &self,
@@ -83,6 +204,25 @@ pub trait QueryLogRepository: Send + Sync {
         limit: u32,
         period_hours: f32,
     ) -> Result<Vec<(String, Option<String>, u64)>, DomainError>;
+    /// Clients with the most blocked queries in the period, busiest first.
+    async fn get_top_blocked_clients(
+        &self,
+        limit: u32,
+        period_hours: f32,
+    ) -> Result<Vec<(String, u64)>, DomainError>;
+    /// Per client and domain query and NXDOMAIN counts for client-originated
+    /// queries logged in the last `seconds_ago` seconds.
+    async fn get_client_domain_counts(
+        &self,
+        seconds_ago: i64,
+    ) -> Result<Vec<ClientDomainCount>, DomainError>;
+    async fn get_client_activity(
+        &self,
+        client_ip: &str,
+        period_hours: u32,
+        granularity: TimeGranularity,
+        limit: u32,
+    ) -> Result<ClientActivity, DomainError>;
     async fn delete_older_than(&self, days: u32) -> Result<u64, DomainError>;
 }
 
@@ -0,0 +1,13 @@
+use ferrous_dns_domain::{QueryLog, QueryStats};
+
+/// In-memory counters of the client queries answered since startup, fed by
+/// the DNS path and read by the API without touching the query log.
+pub trait QueryMetricsPort: Send + Sync {
+    /// Counts one answered query. Called for every query, before query log
+    /// sampling.
+    fn record(&self, query: &QueryLog);
+
+    /// Stats of every query counted since startup. Per-client and per-group
+    /// breakdowns and latency percentiles are left empty.
+    fn snapshot(&self) -> QueryStats;
+}
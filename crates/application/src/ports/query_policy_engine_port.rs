@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::{DomainError, PolicyMatch, RecordType};
+use std::net::IpAddr;
+
+/// Hot-path port for the query policy engine.
+///
+/// Implementors hold the enabled policies compiled into a priority-ordered
+/// matcher that can be swapped atomically on reload.
+#[async_trait]
+pub trait QueryPolicyEnginePort: Send + Sync {
+    /// Returns the first policy matching the query, or `None` when no rule
+    /// applies and the query should take the normal path.
+    fn evaluate(
+        &self,
+        client_ip: IpAddr,
+        group_id: i64,
+        domain: &str,
+        record_type: RecordType,
+    ) -> Option<PolicyMatch>;
+
+    /// Recompiles the matcher from the repository.
+    async fn reload(&self) -> Result<(), DomainError>;
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::{DomainError, QueryPolicy};
+
+#[async_trait]
+pub trait QueryPolicyRepository: Send + Sync {
+    async fn create(&self, policy: &QueryPolicy) -> Result<QueryPolicy, DomainError>;
+
+    async fn get_by_id(&self, id: i64) -> Result<Option<QueryPolicy>, DomainError>;
+
+    /// Returns every policy ordered by evaluation order (priority, then id).
+    async fn get_all(&self) -> Result<Vec<QueryPolicy>, DomainError>;
+
+    /// Replaces every user-editable field of the policy with `policy.id`.
+    async fn update(&self, policy: &QueryPolicy) -> Result<QueryPolicy, DomainError>;
+
+    async fn delete(&self, id: i64) -> Result<(), DomainError>;
+}
@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::{DomainError, LocalRecord};
+
+/// A system whose hosts are published as local records without being
+/// stored, such as a Kubernetes cluster or the local Docker engine.
+#[async_trait]
+pub trait RecordSource: Send + Sync {
+    /// Short name used in logs and to keep each source's records apart.
+    fn name(&self) -> &'static str;
+
+    /// Seconds between two syncs.
+    fn sync_interval_secs(&self) -> u64;
+
+    /// Every record the source currently stands for. The result replaces
+    /// what the source published before.
+    async fn fetch(&self) -> Result<Vec<LocalRecord>, DomainError>;
+
+    /// Resolves when the source has seen a change worth an early sync.
+    /// Sources without change notifications never resolve and are only
+    /// synced on the interval.
+    async fn changed(&self) {
+        std::future::pending::<()>().await
+    }
+}
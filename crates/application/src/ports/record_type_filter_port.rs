@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::{DomainError, RecordType};
+
+/// Hot-path port for per-group record type filtering.
+///
+/// Implementors hold the group policies in an index that can be swapped
+/// atomically on reload.
+#[async_trait]
+pub trait RecordTypeFilterPort: Send + Sync {
+    /// Returns `true` when the group's policy refuses `record_type`.
+    /// Groups without a policy allow every type.
+    fn is_blocked(&self, group_id: i64, record_type: RecordType) -> bool;
+
+    /// Reloads the policy index from the repository.
+    async fn reload(&self) -> Result<(), DomainError>;
+}
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::{DomainError, RecordTypePolicy};
+
+/// Persistence port for per-group record type policies.
+#[async_trait]
+pub trait RecordTypePolicyRepository: Send + Sync {
+    /// Returns the policies of every group that has one.
+    async fn get_all(&self) -> Result<Vec<RecordTypePolicy>, DomainError>;
+
+    /// Returns the policy for a group, if one is set.
+    async fn get_by_group(&self, group_id: i64) -> Result<Option<RecordTypePolicy>, DomainError>;
+
+    /// Inserts or replaces the policy for `policy.group_id`.
+    async fn upsert(&self, policy: &RecordTypePolicy) -> Result<RecordTypePolicy, DomainError>;
+
+    /// Removes the policy for a group. Succeeds if none was set.
+    async fn delete_by_group(&self, group_id: i64) -> Result<(), DomainError>;
+}
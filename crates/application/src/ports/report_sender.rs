@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::DomainError;
+
+/// Delivery channel for scheduled usage reports (SMTP).
+#[async_trait]
+pub trait ReportSender: Send + Sync {
+    /// Sends one report as an HTML email.
+    async fn send(&self, subject: &str, html: &str) -> Result<(), DomainError>;
+}
@@ -0,0 +1,22 @@
+use super::DnsResolution;
+use async_trait::async_trait;
+use ferrous_dns_domain::RecordType;
+use std::time::Duration;
+
+/// Zones served authoritatively from copies transferred from a primary.
+#[async_trait]
+pub trait SecondaryZonePort: Send + Sync {
+    /// Whether `domain` is at or below the apex of a served zone. Cheap
+    /// enough for the fast path.
+    fn serves(&self, domain: &str) -> bool;
+
+    /// Authoritative answer for `domain`, or `None` when no served zone
+    /// holds it or it sits below a delegation and the query should take the
+    /// normal path.
+    fn lookup(&self, domain: &str, record_type: RecordType) -> Option<DnsResolution>;
+
+    /// Checks every zone whose refresh or retry timer has fired and
+    /// transfers the ones whose serial changed. Returns how long until the
+    /// next timer fires.
+    async fn refresh_due(&self) -> Duration;
+}
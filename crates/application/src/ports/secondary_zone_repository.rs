@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::{DomainError, SecondaryZone};
+
+#[async_trait]
+pub trait SecondaryZoneRepository: Send + Sync {
+    async fn get_all(&self) -> Result<Vec<SecondaryZone>, DomainError>;
+
+    /// Replaces the stored copy of `zone.zone`, records included, in one
+    /// transaction.
+    async fn save(&self, zone: &SecondaryZone) -> Result<(), DomainError>;
+
+    /// Records that a primary confirmed the stored copy of `zone` is current.
+    async fn mark_checked(&self, zone: &str, checked_at: i64) -> Result<(), DomainError>;
+
+    async fn delete(&self, zone: &str) -> Result<(), DomainError>;
+}
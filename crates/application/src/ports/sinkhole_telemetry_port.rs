@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use std::net::IpAddr;
+
+/// Connection attempts seen by the sinkhole responder for one host.
+#[derive(Debug, Clone)]
+pub struct SinkholeHostStats {
+    /// Host header (HTTP) or SNI (HTTPS) the client asked for.
+    pub host: String,
+    pub http_attempts: u64,
+    pub https_attempts: u64,
+    /// Attempts from a client that had been given a sinkhole answer for this
+    /// host, i.e. connections that follow a blocked query in the query log.
+    pub correlated_attempts: u64,
+    pub clients: usize,
+    pub last_client: IpAddr,
+    pub last_attempt_at: DateTime<Utc>,
+    pub last_blocked_query_at: Option<DateTime<Utc>>,
+}
+
+/// Telemetry collected from connections to the sinkhole address.
+pub trait SinkholeTelemetryPort: Send + Sync {
+    /// Hosts ordered by total attempts, most retried first.
+    fn hosts(&self, limit: usize) -> Vec<SinkholeHostStats>;
+}
@@ -0,0 +1,39 @@
+use chrono::{DateTime, Utc};
+use ferrous_dns_domain::RecordType;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+/// Time spent in each resolution phase of a single query, in microseconds.
+///
+/// Phases that did not run for the query stay at zero. `cache_us` covers the
+/// cache lookup and any wait on an in-flight upstream query for the same name.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct QueryPhaseTimings {
+    pub block_check_us: u64,
+    pub cache_us: u64,
+    pub upstream_us: u64,
+    pub dnssec_us: u64,
+}
+
+/// A query that took longer than the configured slow-query threshold.
+#[derive(Debug, Clone)]
+pub struct SlowQueryEntry {
+    pub domain: Arc<str>,
+    pub record_type: RecordType,
+    pub client_ip: IpAddr,
+    pub response_status: Option<&'static str>,
+    pub cache_hit: bool,
+    pub upstream_server: Option<Arc<str>>,
+    pub upstream_pool: Option<Arc<str>>,
+    pub phases: QueryPhaseTimings,
+    pub total_us: u64,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Bounded store of recent slow queries.
+pub trait SlowQueryLogPort: Send + Sync {
+    fn record(&self, entry: SlowQueryEntry);
+
+    /// Most recent entries first.
+    fn recent(&self, limit: usize) -> Vec<SlowQueryEntry>;
+}
@@ -0,0 +1,22 @@
+use ferrous_dns_domain::{DnsConfig, RecordType, ViewAnswer};
+use std::net::IpAddr;
+
+/// Hot-path port for split-horizon views.
+///
+/// Implementors hold the views and their local records compiled into a
+/// matcher that can be swapped atomically when the configuration changes.
+pub trait SplitHorizonPort: Send + Sync {
+    /// Returns the client's view answer for `domain`, or `None` when the
+    /// client's view does not override the name and the query should take
+    /// the normal path.
+    fn lookup(
+        &self,
+        client_ip: IpAddr,
+        group_id: i64,
+        domain: &str,
+        record_type: RecordType,
+    ) -> Option<ViewAnswer>;
+
+    /// Recompiles the matcher from the DNS configuration.
+    fn reload(&self, dns: &DnsConfig);
+}
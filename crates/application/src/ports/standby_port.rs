@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::DomainError;
+
+use super::CacheExportEntry;
+
+/// The primary an instance in standby mode copies its state from.
+#[async_trait]
+pub trait PrimaryInstanceClient: Send + Sync {
+    /// A full backup of the primary, as JSON.
+    async fn fetch_backup(&self) -> Result<Vec<u8>, DomainError>;
+    /// The primary's block list snapshot; `None` when it keeps none.
+    async fn fetch_block_lists(&self) -> Result<Option<Vec<u8>>, DomainError>;
+    /// Up to `limit` of the primary's most hit cache entries.
+    async fn fetch_cache(&self, limit: usize) -> Result<Vec<CacheExportEntry>, DomainError>;
+}
@@ -0,0 +1,67 @@
+/// Fill level of a bounded internal channel.
+#[derive(Debug, Clone)]
+pub struct ChannelDepth {
+    pub name: &'static str,
+    pub queued: usize,
+    pub capacity: usize,
+}
+
+/// A bounded channel whose backlog is worth watching.
+pub trait ChannelDepthSource: Send + Sync {
+    /// `None` when the channel is not in use.
+    fn channel_depth(&self) -> Option<ChannelDepth>;
+}
+
+/// Sockets and errors of one upstream server in the UDP socket pool.
+#[derive(Debug, Clone)]
+pub struct UdpServerPoolMetrics {
+    pub server: String,
+    pub idle_sockets: usize,
+    pub in_flight: usize,
+    /// Idle sockets the pool keeps for this server, following the peak
+    /// number of concurrent queries it saw recently.
+    pub target_idle: usize,
+    /// ICMP port, host or network unreachable errors.
+    pub unreachable_errors: u64,
+    pub timeouts: u64,
+    pub other_errors: u64,
+}
+
+/// Usage of the socket pool that upstream UDP queries are sent from.
+#[derive(Debug, Clone, Default)]
+pub struct UdpPoolMetrics {
+    pub sockets_created: u64,
+    pub sockets_reused: u64,
+    pub idle_sockets: usize,
+    pub in_flight: usize,
+    pub max_idle_per_server: usize,
+    /// Sockets that could not be opened because no local port was free.
+    pub port_exhaustion_errors: u64,
+    /// Sockets that could not be opened for any other reason.
+    pub socket_errors: u64,
+    pub servers: Vec<UdpServerPoolMetrics>,
+}
+
+pub trait UdpPoolMetricsSource: Send + Sync {
+    fn udp_pool_metrics(&self) -> UdpPoolMetrics;
+}
+
+/// Resource usage of the server process. Fields the platform cannot report
+/// are `None`.
+#[derive(Debug, Clone, Default)]
+pub struct ProcessMetrics {
+    pub rss_bytes: Option<u64>,
+    pub open_fds: Option<u64>,
+    pub runtime_workers: Option<usize>,
+    pub alive_tasks: Option<usize>,
+    /// Tasks waiting in the runtime's global injection queue.
+    pub global_queue_depth: Option<usize>,
+    pub channels: Vec<ChannelDepth>,
+    /// `None` when no UDP socket pool is attached.
+    pub udp_pool: Option<UdpPoolMetrics>,
+    pub uptime_secs: u64,
+}
+
+pub trait SystemMetricsPort: Send + Sync {
+    fn process_metrics(&self) -> ProcessMetrics;
+}
@@ -0,0 +1,38 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::{DomainError, Tenant, TenantStats};
+
+/// Persistence of tenants and the data they own.
+///
+/// Every `*_in_tenant` lookup must only ever return rows owned by the given
+/// tenant, so that tenant-scoped callers cannot reach other tenants' data.
+#[async_trait]
+pub trait TenantRepository: Send + Sync {
+    /// Stores the tenant with its subnets. Returns the persisted entity.
+    async fn create(&self, tenant: &Tenant) -> Result<Tenant, DomainError>;
+
+    async fn get_by_id(&self, id: i64) -> Result<Option<Tenant>, DomainError>;
+
+    /// Returns every tenant ordered by name.
+    async fn get_all(&self) -> Result<Vec<Tenant>, DomainError>;
+
+    /// Replaces every user-editable field, subnets included, of the tenant
+    /// with `tenant.id`.
+    async fn update(&self, tenant: &Tenant) -> Result<Tenant, DomainError>;
+
+    /// Deletes the tenant; its blocklist sources, local records, subnets and API
+    /// tokens go with it.
+    async fn delete(&self, id: i64) -> Result<(), DomainError>;
+
+    /// Assigns an existing group to the tenant.
+    async fn assign_group(&self, tenant_id: i64, group_id: i64) -> Result<(), DomainError>;
+
+    /// Ids of the groups owned by the tenant.
+    async fn get_group_ids(&self, tenant_id: i64) -> Result<Vec<i64>, DomainError>;
+
+    /// Query and ownership counts of the tenant over the last `period_hours`.
+    async fn get_stats(
+        &self,
+        tenant_id: i64,
+        period_hours: f32,
+    ) -> Result<TenantStats, DomainError>;
+}
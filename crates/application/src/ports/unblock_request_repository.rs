@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::{DomainError, UnblockRequest, UnblockRequestStatus};
+
+#[async_trait]
+pub trait UnblockRequestRepository: Send + Sync {
+    /// Stores `request`, replacing the message, block source and time of an
+    /// earlier pending request for the same domain and client. A request that
+    /// was already reviewed is returned unchanged.
+    async fn upsert(&self, request: &UnblockRequest) -> Result<UnblockRequest, DomainError>;
+
+    async fn get_by_id(&self, id: i64) -> Result<Option<UnblockRequest>, DomainError>;
+
+    /// Returns the requests with `status`, or every request, newest first.
+    async fn get_all(
+        &self,
+        status: Option<UnblockRequestStatus>,
+    ) -> Result<Vec<UnblockRequest>, DomainError>;
+
+    async fn count_pending(&self) -> Result<u64, DomainError>;
+
+    /// Records the review of a pending request. Fails with
+    /// `UnblockRequestAlreadyReviewed` when it is no longer pending.
+    async fn review(
+        &self,
+        id: i64,
+        status: UnblockRequestStatus,
+        managed_domain_id: Option<i64>,
+    ) -> Result<UnblockRequest, DomainError>;
+
+    async fn delete(&self, id: i64) -> Result<(), DomainError>;
+}
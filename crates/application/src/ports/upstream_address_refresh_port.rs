@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+/// Result of one re-resolution pass over the upstream hostnames.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UpstreamAddressRefresh {
+    /// Configured servers whose addresses changed and were swapped in.
+    pub changed: usize,
+    /// Configured servers that still have no address.
+    pub unresolved: usize,
+}
+
+/// Re-resolves the hostnames of the upstream servers and puts the new
+/// addresses into service.
+#[async_trait]
+pub trait UpstreamAddressRefreshPort: Send + Sync {
+    async fn refresh_addresses(&self) -> UpstreamAddressRefresh;
+}
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::DomainError;
+use std::collections::HashMap;
+use std::net::IpAddr;
+
+/// Last-known addresses of upstream hostnames, kept so encrypted upstreams
+/// can be reached when their names cannot be looked up at startup.
+#[async_trait]
+pub trait UpstreamAddressRepository: Send + Sync {
+    async fn get_all(&self) -> Result<HashMap<String, Vec<IpAddr>>, DomainError>;
+
+    /// Replaces the stored addresses of `hostname`.
+    async fn save(&self, hostname: &str, addrs: &[IpAddr]) -> Result<(), DomainError>;
+}
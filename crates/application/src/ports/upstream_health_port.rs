@@ -60,6 +60,35 @@ pub struct UpstreamGroupHealth {
     pub strategy: UpstreamStrategy,
 }
 
+/// Upstream responses rejected by forwarding-path validation since startup.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ResponseValidationStats {
+    /// Transaction ID did not match the outstanding query.
+    pub id_mismatches: u64,
+    /// UDP response arrived from an address other than the queried server.
+    pub source_mismatches: u64,
+    /// Question section (name, type or class) differed from the query,
+    /// including DNS 0x20 case mismatches.
+    pub question_mismatches: u64,
+    /// Response carried a DNS cookie that did not echo our client cookie.
+    pub cookie_mismatches: u64,
+    /// Answer records dropped because their owner was outside the query's
+    /// CNAME chain.
+    pub out_of_bailiwick_records: u64,
+}
+
+/// Where the next forwarded query would go, given current server health.
+#[derive(Debug, Clone)]
+pub struct UpstreamRoute {
+    pub pool_name: String,
+    pub strategy: UpstreamStrategy,
+    /// Healthy servers in the order the strategy would try them. `Parallel`
+    /// queries all of them at once.
+    pub servers: Vec<String>,
+    /// Higher-priority pools passed over because none of their servers are healthy.
+    pub skipped_pools: Vec<String>,
+}
+
 /// Port for querying upstream DNS server health status.
 pub trait UpstreamHealthPort: Send + Sync {
     /// Returns a flat list of (server_address, status) pairs.
@@ -68,4 +97,12 @@ pub trait UpstreamHealthPort: Send + Sync {
 
     /// Returns grouped health per configured server, with per-IP breakdown.
     fn get_grouped_upstream_health(&self) -> Vec<UpstreamGroupHealth>;
+
+    /// Returns counters for upstream responses rejected by validation.
+    fn response_validation_stats(&self) -> ResponseValidationStats;
+
+    /// Previews pool and server selection without sending a query. `pool`
+    /// restricts the choice to one pool, as a forward-to-pool policy does.
+    /// Returns `None` when no candidate pool has a healthy server.
+    fn preview_route(&self, pool: Option<&str>) -> Option<UpstreamRoute>;
 }
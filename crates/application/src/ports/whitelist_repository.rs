@@ -1,10 +1,18 @@
 use async_trait::async_trait;
-use ferrous_dns_domain::{whitelist::WhitelistedDomain, DomainError};
+use ferrous_dns_domain::{whitelist::WhitelistedDomain, DomainError, PageRequest};
 
 #[async_trait]
 pub trait WhitelistRepository: Send + Sync {
     async fn get_all(&self) -> Result<Vec<WhitelistedDomain>, DomainError>;
+    /// Returns one page of domains and the total count.
+    async fn get_all_paged(
+        &self,
+        page: &PageRequest,
+    ) -> Result<(Vec<WhitelistedDomain>, u64), DomainError>;
     async fn add_domain(&self, domain: &WhitelistedDomain) -> Result<(), DomainError>;
+    /// Inserts `domains` in a single transaction, skipping ones already
+    /// present. Returns one flag per input: `true` when it was newly added.
+    async fn add_domains(&self, domains: &[String]) -> Result<Vec<bool>, DomainError>;
     async fn remove_domain(&self, domain: &str) -> Result<(), DomainError>;
     async fn is_whitelisted(&self, domain: &str) -> Result<bool, DomainError>;
 }
@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use ferrous_dns_domain::{DomainError, WhitelistSource};
+use ferrous_dns_domain::{DomainError, PageRequest, WhitelistSource};
 
 #[async_trait]
 pub trait WhitelistSourceRepository: Send + Sync {
@@ -16,6 +16,12 @@ pub trait WhitelistSourceRepository: Send + Sync {
 
     async fn get_all(&self) -> Result<Vec<WhitelistSource>, DomainError>;
 
+    /// Returns one page of sources and the total count.
+    async fn get_page(
+        &self,
+        page: &PageRequest,
+    ) -> Result<(Vec<WhitelistSource>, u64), DomainError>;
+
     async fn update(
         &self,
         id: i64,
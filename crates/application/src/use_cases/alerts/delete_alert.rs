@@ -0,0 +1,20 @@
+use crate::ports::AlertRepository;
+use ferrous_dns_domain::DomainError;
+use std::sync::Arc;
+use tracing::info;
+
+pub struct DeleteAlertUseCase {
+    repository: Arc<dyn AlertRepository>,
+}
+
+impl DeleteAlertUseCase {
+    pub fn new(repository: Arc<dyn AlertRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, id: i64) -> Result<(), DomainError> {
+        self.repository.delete(id).await?;
+        info!(alert_id = id, "Alert deleted");
+        Ok(())
+    }
+}
@@ -0,0 +1,198 @@
+use crate::ports::{AlertRepository, QueryLogRepository};
+use crate::use_cases::dns::{extract_apex, shannon_entropy};
+use ferrous_dns_domain::{Alert, AlertKind, AnomalyDetectionConfig, DomainError};
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Arc, Mutex};
+use tracing::{info, warn};
+
+/// Weight of the newest window in each client's moving volume average.
+const BASELINE_ALPHA: f64 = 0.2;
+
+/// Per-client totals for one analysis window.
+#[derive(Default)]
+struct ClientWindow {
+    queries: u64,
+    nxdomain: u64,
+    /// Unique subdomain prefixes seen under each apex.
+    subdomains: HashMap<String, BTreeSet<String>>,
+}
+
+/// Scans the latest window of logged client queries and records an alert for
+/// each client with a high NXDOMAIN rate, high-entropy subdomains under one
+/// apex, or a volume spike against its own moving average.
+pub struct DetectAnomaliesUseCase {
+    query_log: Arc<dyn QueryLogRepository>,
+    alerts: Arc<dyn AlertRepository>,
+    config: AnomalyDetectionConfig,
+    baselines: Mutex<HashMap<String, f64>>,
+}
+
+impl DetectAnomaliesUseCase {
+    pub fn new(
+        query_log: Arc<dyn QueryLogRepository>,
+        alerts: Arc<dyn AlertRepository>,
+        config: AnomalyDetectionConfig,
+    ) -> Self {
+        Self {
+            query_log,
+            alerts,
+            config,
+            baselines: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn interval_secs(&self) -> u64 {
+        self.config.interval_secs
+    }
+
+    /// Analyses the last `interval_secs` of the query log and persists the
+    /// findings, returning them.
+    pub async fn execute(&self) -> Result<Vec<Alert>, DomainError> {
+        let counts = self
+            .query_log
+            .get_client_domain_counts(self.config.interval_secs as i64)
+            .await?;
+
+        let mut windows: HashMap<String, ClientWindow> = HashMap::new();
+        for count in counts {
+            let window = windows.entry(count.client_ip).or_default();
+            window.queries += count.queries;
+            window.nxdomain += count.nxdomain;
+
+            let apex = extract_apex(&count.domain);
+            if apex.len() < count.domain.len() {
+                let prefix = &count.domain[..count.domain.len() - apex.len() - 1];
+                window
+                    .subdomains
+                    .entry(apex.to_string())
+                    .or_default()
+                    .insert(prefix.to_string());
+            }
+        }
+
+        let mut findings = Vec::new();
+        for (client_ip, window) in &windows {
+            if window.queries < self.config.min_queries {
+                continue;
+            }
+            findings.extend(self.check_nxdomain_rate(client_ip, window));
+            findings.extend(self.check_subdomain_entropy(client_ip, window));
+        }
+        findings.extend(self.check_volume_spikes(&windows));
+
+        let mut stored = Vec::with_capacity(findings.len());
+        for alert in findings {
+            match self.alerts.insert(&alert).await {
+                Ok(alert) => {
+                    info!(
+                        client = %alert.client_ip,
+                        kind = alert.kind.as_str(),
+                        score = alert.score,
+                        "Anomaly detected"
+                    );
+                    stored.push(alert);
+                }
+                Err(e) => warn!(error = %e, "Failed to store anomaly alert"),
+            }
+        }
+        Ok(stored)
+    }
+
+    /// Deletes alerts older than the configured retention.
+    pub async fn purge_expired(&self) -> Result<u64, DomainError> {
+        self.alerts
+            .delete_older_than(self.config.retention_days)
+            .await
+    }
+
+    fn check_nxdomain_rate(&self, client_ip: &str, window: &ClientWindow) -> Option<Alert> {
+        let rate = window.nxdomain as f64 / window.queries as f64;
+        (rate >= self.config.nxdomain_rate_threshold).then(|| {
+            Alert::new(
+                client_ip,
+                AlertKind::NxdomainRate,
+                rate,
+                self.config.nxdomain_rate_threshold,
+                format!(
+                    "{} of {} queries answered NXDOMAIN in the last {}s",
+                    window.nxdomain, window.queries, self.config.interval_secs
+                ),
+            )
+        })
+    }
+
+    /// Reports the apex with the highest subdomain entropy, if it crosses the
+    /// threshold.
+    fn check_subdomain_entropy(&self, client_ip: &str, window: &ClientWindow) -> Option<Alert> {
+        window
+            .subdomains
+            .iter()
+            .filter(|(_, prefixes)| prefixes.len() >= self.config.min_unique_subdomains)
+            .map(|(apex, prefixes)| {
+                let joined: String = prefixes.iter().map(String::as_str).collect();
+                (
+                    apex,
+                    prefixes.len(),
+                    shannon_entropy(joined.as_bytes()) as f64,
+                )
+            })
+            .filter(|(_, _, entropy)| *entropy >= self.config.subdomain_entropy_threshold)
+            .max_by(|a, b| a.2.total_cmp(&b.2))
+            .map(|(apex, unique, entropy)| {
+                Alert::new(
+                    client_ip,
+                    AlertKind::SubdomainEntropy,
+                    entropy,
+                    self.config.subdomain_entropy_threshold,
+                    format!(
+                        "{} unique subdomains of {} with {:.2} bits/char entropy",
+                        unique, apex, entropy
+                    ),
+                )
+                .with_domain(apex.as_str())
+            })
+    }
+
+    /// Compares each client's window volume to its moving average, then folds
+    /// the window into the average. Clients idle long enough are forgotten.
+    fn check_volume_spikes(&self, windows: &HashMap<String, ClientWindow>) -> Vec<Alert> {
+        let mut baselines = self.baselines.lock().unwrap_or_else(|e| e.into_inner());
+        let mut alerts = Vec::new();
+
+        for (client_ip, window) in windows {
+            let current = window.queries as f64;
+            match baselines.get_mut(client_ip) {
+                Some(baseline) => {
+                    let ratio = current / baseline.max(1.0);
+                    if window.queries >= self.config.min_queries
+                        && ratio >= self.config.volume_spike_factor
+                    {
+                        alerts.push(Alert::new(
+                            client_ip.as_str(),
+                            AlertKind::VolumeSpike,
+                            ratio,
+                            self.config.volume_spike_factor,
+                            format!(
+                                "{} queries in the last {}s against an average of {:.0}",
+                                window.queries, self.config.interval_secs, baseline
+                            ),
+                        ));
+                    }
+                    *baseline = BASELINE_ALPHA * current + (1.0 - BASELINE_ALPHA) * *baseline;
+                }
+                None => {
+                    baselines.insert(client_ip.clone(), current);
+                }
+            }
+        }
+
+        baselines.retain(|client_ip, baseline| {
+            if windows.contains_key(client_ip) {
+                return true;
+            }
+            *baseline *= 1.0 - BASELINE_ALPHA;
+            *baseline >= 1.0
+        });
+        alerts
+    }
+}
@@ -0,0 +1,22 @@
+use crate::ports::AlertRepository;
+use ferrous_dns_domain::{Alert, AlertKind, DomainError};
+use std::sync::Arc;
+
+pub struct GetAlertsUseCase {
+    repository: Arc<dyn AlertRepository>,
+}
+
+impl GetAlertsUseCase {
+    pub fn new(repository: Arc<dyn AlertRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        limit: u32,
+        client_ip: Option<&str>,
+        kind: Option<AlertKind>,
+    ) -> Result<Vec<Alert>, DomainError> {
+        self.repository.get_recent(limit, client_ip, kind).await
+    }
+}
@@ -0,0 +1,7 @@
+mod delete_alert;
+mod detect_anomalies;
+mod get_alerts;
+
+pub use delete_alert::DeleteAlertUseCase;
+pub use detect_anomalies::DetectAnomaliesUseCase;
+pub use get_alerts::GetAlertsUseCase;
@@ -30,6 +30,18 @@ impl CreateApiTokenUseCase {
         &self,
         name: &str,
         custom_token: Option<&str>,
+    ) -> Result<CreatedApiToken, DomainError> {
+        self.execute_for_tenant(name, custom_token, None).await
+    }
+
+    /// Creates a token that, when `tenant_id` is set, only grants access to
+    /// that tenant's endpoints.
+    #[instrument(skip(self, custom_token))]
+    pub async fn execute_for_tenant(
+        &self,
+        name: &str,
+        custom_token: Option<&str>,
+        tenant_id: Option<i64>,
     ) -> Result<CreatedApiToken, DomainError> {
         ApiToken::validate_name(name)?;
 
@@ -47,12 +59,13 @@ impl CreateApiTokenUseCase {
 
         let token = self
             .repo
-            .create(name, key_prefix, &key_hash, &raw_token)
+            .create(name, key_prefix, &key_hash, &raw_token, tenant_id)
             .await?;
 
         info!(
             name = name,
             imported = custom_token.is_some(),
+            tenant_id = ?tenant_id,
             "API token created"
         );
         Ok(CreatedApiToken { token, raw_token })
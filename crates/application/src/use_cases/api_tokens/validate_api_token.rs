@@ -2,7 +2,7 @@ use std::sync::Arc;
 use tracing::instrument;
 
 use crate::ports::ApiTokenRepository;
-use ferrous_dns_domain::DomainError;
+use ferrous_dns_domain::{ApiToken, DomainError};
 
 /// Validates a raw API token against stored hashes.
 ///
@@ -30,4 +30,21 @@ impl ValidateApiTokenUseCase {
             None => Err(DomainError::InvalidCredentials),
         }
     }
+
+    /// Like `execute`, but returns the whole token so callers can enforce
+    /// its tenant scope.
+    #[instrument(skip(self, raw_token))]
+    pub async fn authenticate(&self, raw_token: &str) -> Result<ApiToken, DomainError> {
+        let incoming_hash = super::hash_token(raw_token);
+
+        match self.repo.get_by_hash(&incoming_hash).await? {
+            Some(token) => {
+                if let Some(id) = token.id {
+                    self.repo.update_last_used(id).await?;
+                }
+                Ok(token)
+            }
+            None => Err(DomainError::InvalidCredentials),
+        }
+    }
 }
@@ -0,0 +1,21 @@
+use crate::ports::AuditLogRepository;
+use ferrous_dns_domain::{AuditAction, AuditEvent, DomainError};
+use std::sync::Arc;
+
+pub struct GetAuditLogUseCase {
+    repository: Arc<dyn AuditLogRepository>,
+}
+
+impl GetAuditLogUseCase {
+    pub fn new(repository: Arc<dyn AuditLogRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        limit: u32,
+        action: Option<AuditAction>,
+    ) -> Result<Vec<AuditEvent>, DomainError> {
+        self.repository.get_recent(limit, action).await
+    }
+}
@@ -0,0 +1,3 @@
+mod get_audit_log;
+
+pub use get_audit_log::GetAuditLogUseCase;
@@ -1,11 +1,11 @@
 use std::sync::Arc;
 
 use chrono::Utc;
-use ferrous_dns_domain::{Config, DomainError};
+use ferrous_dns_domain::{Config, DomainError, LocalRecord};
 use tokio::sync::RwLock;
 use tracing::{info, instrument};
 
-use crate::ports::{BlocklistSourceRepository, GroupRepository};
+use crate::ports::{BlocklistSourceRepository, GroupRepository, LocalRecordRepository};
 
 use super::snapshot::{
     BackupSnapshot, BlocklistSourceSnapshot, GroupSnapshot, LocalRecordSnapshot,
@@ -20,6 +20,7 @@ pub struct ExportConfigUseCase {
     config: Arc<RwLock<Config>>,
     group_repo: Arc<dyn GroupRepository>,
     blocklist_source_repo: Arc<dyn BlocklistSourceRepository>,
+    local_record_repo: Option<Arc<dyn LocalRecordRepository>>,
 }
 
 impl ExportConfigUseCase {
@@ -32,9 +33,17 @@ impl ExportConfigUseCase {
             config,
             group_repo,
             blocklist_source_repo,
+            local_record_repo: None,
         }
     }
 
+    /// Includes the records stored in the database, next to the ones from
+    /// `[[dns.local_records]]`, in the exported snapshot.
+    pub fn with_local_record_repository(mut self, repo: Arc<dyn LocalRecordRepository>) -> Self {
+        self.local_record_repo = Some(repo);
+        self
+    }
+
     #[instrument(skip(self), name = "export_config")]
     pub async fn execute(&self) -> Result<Vec<u8>, DomainError> {
         let config = self.config.read().await;
@@ -62,16 +71,23 @@ impl ExportConfigUseCase {
             })
             .collect();
 
+        let stored_records = match self.local_record_repo {
+            Some(ref repo) => repo.get_all().await?,
+            None => Vec::new(),
+        };
         let local_record_snapshots: Vec<LocalRecordSnapshot> = config
             .dns
             .local_records
             .iter()
+            .cloned()
+            .chain(stored_records.iter().map(LocalRecord::to_config_record))
             .map(|r| LocalRecordSnapshot {
-                hostname: r.hostname.clone(),
-                domain: r.domain.clone(),
-                ip: r.ip.clone(),
-                record_type: r.record_type.clone(),
+                hostname: r.hostname,
+                domain: r.domain,
+                ip: r.ip,
+                record_type: r.record_type,
                 ttl: r.ttl,
+                view: r.view,
             })
             .collect();
 
@@ -0,0 +1,189 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use ferrous_dns_domain::{Config, DomainError};
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+use tracing::{info, instrument};
+
+use crate::ports::{BackupArchiver, BackupStore, BackupTables};
+
+pub(super) const FULL_BACKUP_FORMAT_VERSION: &str = "1";
+const FERROUS_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+const MANIFEST_FILE: &str = "manifest.json";
+const CONFIG_FILE: &str = "config.json";
+const TABLES_DIR: &str = "tables/";
+
+/// Complete backup: the whole configuration plus every configuration table.
+///
+/// Secrets (admin password hash, DNS cookie secret) are blanked on export
+/// and carried over from the running configuration on restore.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FullBackup {
+    pub version: String,
+    pub ferrous_version: String,
+    pub exported_at: String,
+    pub config: Config,
+    pub tables: BackupTables,
+}
+
+#[derive(Serialize, Deserialize)]
+struct BackupManifest {
+    version: String,
+    ferrous_version: String,
+    exported_at: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackupFormat {
+    /// Single JSON document.
+    Json,
+    /// Gzipped tar with a manifest, the config and one JSON file per table.
+    TarGz,
+}
+
+impl BackupFormat {
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Json => "application/json",
+            Self::TarGz => "application/gzip",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::TarGz => "tar.gz",
+        }
+    }
+}
+
+impl FullBackup {
+    /// Encode in the given format; tar.gz layout is `manifest.json`,
+    /// `config.json` and `tables/<table>.json`.
+    pub fn encode(
+        &self,
+        format: BackupFormat,
+        archiver: &dyn BackupArchiver,
+    ) -> Result<Vec<u8>, DomainError> {
+        match format {
+            BackupFormat::Json => serde_json::to_vec(self).map_err(serialize_error),
+            BackupFormat::TarGz => {
+                let manifest = BackupManifest {
+                    version: self.version.clone(),
+                    ferrous_version: self.ferrous_version.clone(),
+                    exported_at: self.exported_at.clone(),
+                };
+                let mut files = Vec::with_capacity(self.tables.len() + 2);
+                files.push((
+                    MANIFEST_FILE.to_string(),
+                    serde_json::to_vec_pretty(&manifest).map_err(serialize_error)?,
+                ));
+                files.push((
+                    CONFIG_FILE.to_string(),
+                    serde_json::to_vec_pretty(&self.config).map_err(serialize_error)?,
+                ));
+                for (table, rows) in &self.tables {
+                    files.push((
+                        format!("{TABLES_DIR}{table}.json"),
+                        serde_json::to_vec(rows).map_err(serialize_error)?,
+                    ));
+                }
+                archiver.pack(&files)
+            }
+        }
+    }
+
+    /// Decode a JSON or tar.gz backup, detected by the gzip magic bytes.
+    pub fn decode(bytes: &[u8], archiver: &dyn BackupArchiver) -> Result<Self, DomainError> {
+        if !bytes.starts_with(&[0x1f, 0x8b]) {
+            return serde_json::from_slice(bytes).map_err(parse_error);
+        }
+
+        let mut manifest: Option<BackupManifest> = None;
+        let mut config: Option<Config> = None;
+        let mut tables = BackupTables::new();
+        for (name, contents) in archiver.unpack(bytes)? {
+            if name == MANIFEST_FILE {
+                manifest = Some(serde_json::from_slice(&contents).map_err(parse_error)?);
+            } else if name == CONFIG_FILE {
+                config = Some(serde_json::from_slice(&contents).map_err(parse_error)?);
+            } else if let Some(table) = name
+                .strip_prefix(TABLES_DIR)
+                .and_then(|f| f.strip_suffix(".json"))
+            {
+                let rows = serde_json::from_slice(&contents).map_err(parse_error)?;
+                tables.insert(table.to_string(), rows);
+            }
+        }
+
+        let manifest = manifest.ok_or_else(|| missing_entry(MANIFEST_FILE))?;
+        let config = config.ok_or_else(|| missing_entry(CONFIG_FILE))?;
+        Ok(Self {
+            version: manifest.version,
+            ferrous_version: manifest.ferrous_version,
+            exported_at: manifest.exported_at,
+            config,
+            tables,
+        })
+    }
+}
+
+fn serialize_error(e: serde_json::Error) -> DomainError {
+    DomainError::IoError(format!("Failed to serialize backup: {}", e))
+}
+
+fn parse_error(e: serde_json::Error) -> DomainError {
+    DomainError::InvalidInput(format!("Invalid backup file format: {}", e))
+}
+
+fn missing_entry(name: &str) -> DomainError {
+    DomainError::InvalidInput(format!("Backup archive is missing '{}'", name))
+}
+
+pub struct CreateBackupUseCase {
+    config: Arc<RwLock<Config>>,
+    store: Arc<dyn BackupStore>,
+    archiver: Arc<dyn BackupArchiver>,
+}
+
+impl CreateBackupUseCase {
+    pub fn new(
+        config: Arc<RwLock<Config>>,
+        store: Arc<dyn BackupStore>,
+        archiver: Arc<dyn BackupArchiver>,
+    ) -> Self {
+        Self {
+            config,
+            store,
+            archiver,
+        }
+    }
+
+    #[instrument(skip(self), name = "create_backup")]
+    pub async fn execute(&self, format: BackupFormat) -> Result<Vec<u8>, DomainError> {
+        let mut config = self.config.read().await.clone();
+        config.auth.admin.password_hash = None;
+        config.dns.dns_cookies.server_secret.clear();
+
+        let tables = self.store.dump().await?;
+        let backup = FullBackup {
+            version: FULL_BACKUP_FORMAT_VERSION.to_string(),
+            ferrous_version: FERROUS_VERSION.to_string(),
+            exported_at: Utc::now().to_rfc3339(),
+            config,
+            tables,
+        };
+        let bytes = backup.encode(format, self.archiver.as_ref())?;
+
+        info!(
+            tables = backup.tables.len(),
+            rows = backup.tables.values().map(Vec::len).sum::<usize>(),
+            bytes = bytes.len(),
+            ?format,
+            "Full backup created"
+        );
+        Ok(bytes)
+    }
+}
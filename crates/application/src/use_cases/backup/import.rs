@@ -248,18 +248,23 @@ impl ImportConfigUseCase {
         let mut imported = 0usize;
         let mut skipped = 0usize;
 
+        let mut existing = match self.local_record_creator.existing_local_records().await {
+            Ok(records) => records,
+            Err(e) => {
+                errors.push(format!("Local records: {}", e));
+                return (imported, snapshot.data.local_records.len());
+            }
+        };
+
         for record in &snapshot.data.local_records {
-            {
-                let config = self.config.read().await;
-                let already_exists = config.dns.local_records.iter().any(|r| {
-                    r.hostname == record.hostname
-                        && r.domain.as_deref().unwrap_or("")
-                            == record.domain.as_deref().unwrap_or("")
-                });
-                if already_exists {
-                    skipped += 1;
-                    continue;
-                }
+            let already_exists = existing.iter().any(|r| {
+                r.hostname == record.hostname
+                    && r.domain.as_deref().unwrap_or("") == record.domain.as_deref().unwrap_or("")
+                    && r.view == record.view
+            });
+            if already_exists {
+                skipped += 1;
+                continue;
             }
 
             match self
@@ -270,10 +275,14 @@ impl ImportConfigUseCase {
                     record.ip.clone(),
                     record.record_type.clone(),
                     record.ttl,
+                    record.view.clone(),
                 )
                 .await
             {
-                Ok(_) => imported += 1,
+                Ok(created) => {
+                    existing.push(created);
+                    imported += 1;
+                }
                 Err(e) => {
                     warn!(hostname = %record.hostname, error = %e, "Skipping local record during import");
                     skipped += 1;
@@ -1,7 +1,11 @@
 pub mod export;
+pub mod full_backup;
 pub mod import;
+pub mod restore;
 pub mod snapshot;
 
 pub use export::ExportConfigUseCase;
+pub use full_backup::{BackupFormat, CreateBackupUseCase, FullBackup};
 pub use import::ImportConfigUseCase;
+pub use restore::{RestoreBackupUseCase, RestoreSummary};
 pub use snapshot::{BackupSnapshot, ImportSummary};
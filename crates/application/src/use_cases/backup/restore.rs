@@ -0,0 +1,136 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use ferrous_dns_domain::{Config, DomainError};
+use tokio::sync::RwLock;
+use tracing::{error, info, instrument, warn};
+
+use crate::ports::{BackupArchiver, BackupStore, BlockFilterEnginePort, ConfigFilePersistence};
+
+use super::full_backup::{FullBackup, FULL_BACKUP_FORMAT_VERSION};
+
+/// Rows restored per table by a full restore.
+#[derive(Debug, Clone)]
+pub struct RestoreSummary {
+    pub ferrous_version: String,
+    pub exported_at: String,
+    pub tables: BTreeMap<String, usize>,
+}
+
+/// Restores a [`FullBackup`] all-or-nothing.
+///
+/// The config file is written first and put back if the database restore
+/// fails, so a rejected backup leaves both untouched.
+pub struct RestoreBackupUseCase {
+    config: Arc<RwLock<Config>>,
+    config_file_persistence: Arc<dyn ConfigFilePersistence>,
+    config_path: Option<String>,
+    store: Arc<dyn BackupStore>,
+    archiver: Arc<dyn BackupArchiver>,
+    block_filter_engine: Option<Arc<dyn BlockFilterEnginePort>>,
+}
+
+impl RestoreBackupUseCase {
+    pub fn new(
+        config: Arc<RwLock<Config>>,
+        config_file_persistence: Arc<dyn ConfigFilePersistence>,
+        config_path: Option<String>,
+        store: Arc<dyn BackupStore>,
+        archiver: Arc<dyn BackupArchiver>,
+    ) -> Self {
+        Self {
+            config,
+            config_file_persistence,
+            config_path,
+            store,
+            archiver,
+            block_filter_engine: None,
+        }
+    }
+
+    /// Reload the block filter after a restore so restored lists apply immediately.
+    pub fn with_block_filter_engine(mut self, engine: Arc<dyn BlockFilterEnginePort>) -> Self {
+        self.block_filter_engine = Some(engine);
+        self
+    }
+
+    #[instrument(skip(self, bytes), name = "restore_backup")]
+    pub async fn execute(&self, bytes: &[u8]) -> Result<RestoreSummary, DomainError> {
+        let backup = FullBackup::decode(bytes, self.archiver.as_ref())?;
+        if backup.version != FULL_BACKUP_FORMAT_VERSION {
+            return Err(DomainError::InvalidInput(format!(
+                "Unsupported backup version '{}'. Expected '{}'.",
+                backup.version, FULL_BACKUP_FORMAT_VERSION
+            )));
+        }
+
+        let path = self
+            .config_path
+            .clone()
+            .or_else(Config::get_config_path)
+            .ok_or_else(|| {
+                DomainError::ConfigError("No config file path available for restore".to_string())
+            })?;
+
+        let previous = self.config.read().await.clone();
+        let restored = merge_config(backup.config, &previous);
+        restored
+            .validate()
+            .map_err(|e| DomainError::InvalidInput(format!("Backup config is invalid: {}", e)))?;
+
+        self.config_file_persistence
+            .save_config_to_file(&restored, &path)
+            .map_err(|e| DomainError::IoError(format!("Failed to persist config: {}", e)))?;
+
+        let tables = match self.store.restore(&backup.tables).await {
+            Ok(tables) => tables,
+            Err(e) => {
+                if let Err(revert) = self
+                    .config_file_persistence
+                    .save_config_to_file(&previous, &path)
+                {
+                    error!(error = %revert, "Failed to revert config file after restore failure");
+                }
+                return Err(e);
+            }
+        };
+
+        *self.config.write().await = restored;
+
+        if let Some(engine) = &self.block_filter_engine {
+            if let Err(e) = engine.load_client_groups().await {
+                warn!(error = %e, "Failed to reload client groups after restore");
+            }
+            if let Err(e) = engine.reload().await {
+                warn!(error = %e, "Failed to reload block filter after restore");
+            }
+        }
+
+        info!(
+            tables = tables.len(),
+            rows = tables.values().sum::<usize>(),
+            from_version = %backup.ferrous_version,
+            "Full backup restored"
+        );
+
+        Ok(RestoreSummary {
+            ferrous_version: backup.ferrous_version,
+            exported_at: backup.exported_at,
+            tables,
+        })
+    }
+}
+
+/// Take everything from the backup except what is tied to this installation:
+/// secrets stripped on export, the database location and the standby role.
+fn merge_config(mut restored: Config, current: &Config) -> Config {
+    if restored.auth.admin.password_hash.is_none() {
+        restored.auth.admin.password_hash = current.auth.admin.password_hash.clone();
+    }
+    if restored.dns.dns_cookies.server_secret.is_empty() {
+        restored.dns.dns_cookies.server_secret = current.dns.dns_cookies.server_secret.clone();
+    }
+    restored.database.path = current.database.path.clone();
+    restored.standby = current.standby.clone();
+    restored
+}
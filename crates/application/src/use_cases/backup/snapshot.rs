@@ -112,6 +112,9 @@ pub struct LocalRecordSnapshot {
     pub ip: String,
     pub record_type: String,
     pub ttl: Option<u32>,
+    /// Absent in backups taken before split-horizon views existed.
+    #[serde(default)]
+    pub view: Option<String>,
 }
 
 /// Summary returned to callers after a successful import.
@@ -1,4 +1,4 @@
-use crate::ports::BlockFilterEnginePort;
+use crate::ports::{BlockFilterCompileStatus, BlockFilterEnginePort, SourceHitReport};
 use std::sync::Arc;
 
 pub struct GetBlockFilterStatsUseCase {
@@ -13,4 +13,12 @@ impl GetBlockFilterStatsUseCase {
     pub fn execute(&self) -> usize {
         self.engine.compiled_domain_count()
     }
+
+    pub fn compile_status(&self) -> BlockFilterCompileStatus {
+        self.engine.compile_status()
+    }
+
+    pub fn source_hit_stats(&self) -> SourceHitReport {
+        self.engine.source_hit_stats()
+    }
 }
@@ -0,0 +1,113 @@
+use crate::ports::{BlockFilterEnginePort, BlocklistRepository};
+use ferrous_dns_domain::{blocklist::BlockedDomain, DomainError};
+use std::collections::HashSet;
+use std::sync::Arc;
+use tracing::{error, info, instrument};
+
+/// Upper bound on entries accepted by one bulk request.
+pub const MAX_BULK_DOMAINS: usize = 50_000;
+
+/// An entry rejected by validation, identified by its position in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BulkEntryError {
+    pub index: usize,
+    pub entry: String,
+    pub error: String,
+}
+
+/// Outcome of a bulk add: valid entries were written in one transaction.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BulkAddSummary {
+    /// Non-blank, non-comment entries in the request.
+    pub received: usize,
+    pub added: usize,
+    /// Entries already stored or repeated within the request.
+    pub duplicates: usize,
+    pub errors: Vec<BulkEntryError>,
+}
+
+/// Normalizes and validates raw entries.
+///
+/// Blank entries and `#` comments are skipped without counting. Returns the
+/// unique domains to insert and a summary with `received`, in-request
+/// duplicates and validation errors filled in.
+pub(crate) fn prepare_bulk_domains(
+    entries: &[String],
+    validate: fn(&str) -> Result<(), String>,
+) -> Result<(Vec<String>, BulkAddSummary), DomainError> {
+    let mut summary = BulkAddSummary::default();
+    let mut seen = HashSet::new();
+    let mut domains = Vec::new();
+
+    for (index, raw) in entries.iter().enumerate() {
+        let trimmed = raw.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        summary.received += 1;
+        if summary.received > MAX_BULK_DOMAINS {
+            return Err(DomainError::InvalidInput(format!(
+                "Bulk requests are limited to {MAX_BULK_DOMAINS} entries"
+            )));
+        }
+
+        let domain = trimmed.trim_end_matches('.').to_ascii_lowercase();
+        if let Err(error) = validate(&domain) {
+            summary.errors.push(BulkEntryError {
+                index,
+                entry: trimmed.to_string(),
+                error,
+            });
+            continue;
+        }
+        if seen.insert(domain.clone()) {
+            domains.push(domain);
+        } else {
+            summary.duplicates += 1;
+        }
+    }
+
+    Ok((domains, summary))
+}
+
+pub struct BulkAddBlocklistUseCase {
+    repository: Arc<dyn BlocklistRepository>,
+    block_filter_engine: Arc<dyn BlockFilterEnginePort>,
+}
+
+impl BulkAddBlocklistUseCase {
+    pub fn new(
+        repository: Arc<dyn BlocklistRepository>,
+        block_filter_engine: Arc<dyn BlockFilterEnginePort>,
+    ) -> Self {
+        Self {
+            repository,
+            block_filter_engine,
+        }
+    }
+
+    #[instrument(skip(self, entries), fields(entries = entries.len()))]
+    pub async fn execute(&self, entries: &[String]) -> Result<BulkAddSummary, DomainError> {
+        let (domains, mut summary) = prepare_bulk_domains(entries, BlockedDomain::validate_domain)?;
+
+        let added = self.repository.add_domains(&domains).await?;
+        summary.added = added.iter().filter(|a| **a).count();
+        summary.duplicates += domains.len() - summary.added;
+
+        info!(
+            received = summary.received,
+            added = summary.added,
+            duplicates = summary.duplicates,
+            errors = summary.errors.len(),
+            "Blocklist bulk add completed"
+        );
+
+        if summary.added > 0 {
+            if let Err(e) = self.block_filter_engine.reload().await {
+                error!(error = %e, "Failed to reload block filter after blocklist bulk add");
+            }
+        }
+
+        Ok(summary)
+    }
+}
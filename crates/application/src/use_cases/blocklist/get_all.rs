@@ -1,5 +1,5 @@
 use crate::ports::BlocklistRepository;
-use ferrous_dns_domain::{blocklist::BlockedDomain, DomainError};
+use ferrous_dns_domain::{blocklist::BlockedDomain, DomainError, PageRequest};
 use std::sync::Arc;
 
 pub struct GetBlocklistUseCase {
@@ -17,9 +17,8 @@ impl GetBlocklistUseCase {
 
     pub async fn execute_paged(
         &self,
-        limit: u32,
-        offset: u32,
+        page: &PageRequest,
     ) -> Result<(Vec<BlockedDomain>, u64), DomainError> {
-        self.repository.get_all_paged(limit, offset).await
+        self.repository.get_all_paged(page).await
     }
 }
@@ -1,2 +1,4 @@
+pub mod bulk_add;
 pub mod get_all;
+pub use bulk_add::{BulkAddBlocklistUseCase, BulkAddSummary, BulkEntryError, MAX_BULK_DOMAINS};
 pub use get_all::GetBlocklistUseCase;
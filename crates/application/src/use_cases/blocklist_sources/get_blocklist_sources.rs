@@ -1,4 +1,4 @@
-use ferrous_dns_domain::{BlocklistSource, DomainError};
+use ferrous_dns_domain::{BlocklistSource, DomainError, PageRequest};
 use std::sync::Arc;
 use tracing::instrument;
 
@@ -18,6 +18,14 @@ impl GetBlocklistSourcesUseCase {
         self.repo.get_all().await
     }
 
+    #[instrument(skip(self))]
+    pub async fn get_page(
+        &self,
+        page: &PageRequest,
+    ) -> Result<(Vec<BlocklistSource>, u64), DomainError> {
+        self.repo.get_page(page).await
+    }
+
     #[instrument(skip(self))]
     pub async fn get_by_id(&self, id: i64) -> Result<Option<BlocklistSource>, DomainError> {
         self.repo.get_by_id(id).await
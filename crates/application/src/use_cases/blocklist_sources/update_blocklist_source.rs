@@ -18,6 +18,7 @@ impl UpdateBlocklistSourceUseCase {
     }
 
     #[instrument(skip(self))]
+    #[allow(clippy::too_many_arguments)]
     pub async fn execute(
         &self,
         id: i64,
@@ -26,6 +27,7 @@ impl UpdateBlocklistSourceUseCase {
         group_ids: Option<Vec<i64>>,
         comment: Option<String>,
         enabled: Option<bool>,
+        audit_mode: Option<bool>,
     ) -> Result<BlocklistSource, DomainError> {
         self.repo
             .get_by_id(id)
@@ -57,13 +59,14 @@ impl UpdateBlocklistSourceUseCase {
 
         let updated = self
             .repo
-            .update(id, name, url, group_ids, comment, enabled)
+            .update(id, name, url, group_ids, comment, enabled, audit_mode)
             .await?;
 
         info!(
             source_id = ?id,
             name = %updated.name,
             enabled = %updated.enabled,
+            audit_mode = %updated.audit_mode,
             "Blocklist source updated successfully"
         );
 
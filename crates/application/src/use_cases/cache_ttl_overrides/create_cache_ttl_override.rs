@@ -0,0 +1,47 @@
+use ferrous_dns_domain::{CacheTtlOverride, DomainError};
+use std::sync::Arc;
+use tracing::{error, info, instrument};
+
+use super::check_wildcard_capacity;
+use crate::ports::{CacheTtlOverrideEnginePort, CacheTtlOverrideRepository};
+
+pub struct CreateCacheTtlOverrideUseCase {
+    repo: Arc<dyn CacheTtlOverrideRepository>,
+    engine: Arc<dyn CacheTtlOverrideEnginePort>,
+}
+
+impl CreateCacheTtlOverrideUseCase {
+    pub fn new(
+        repo: Arc<dyn CacheTtlOverrideRepository>,
+        engine: Arc<dyn CacheTtlOverrideEnginePort>,
+    ) -> Self {
+        Self { repo, engine }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        ttl_override: CacheTtlOverride,
+    ) -> Result<CacheTtlOverride, DomainError> {
+        ttl_override
+            .validate()
+            .map_err(DomainError::InvalidCacheTtlOverride)?;
+        check_wildcard_capacity(self.repo.as_ref(), &ttl_override).await?;
+
+        let created = self.repo.create(&ttl_override).await?;
+
+        info!(
+            override_id = ?created.id,
+            domain = %created.domain,
+            min_ttl = ?created.min_ttl,
+            max_ttl = ?created.max_ttl,
+            "Cache TTL override created successfully"
+        );
+
+        if let Err(e) = self.engine.reload().await {
+            error!(error = %e, "Failed to reload cache TTL overrides after creation");
+        }
+
+        Ok(created)
+    }
+}
@@ -0,0 +1,37 @@
+use ferrous_dns_domain::DomainError;
+use std::sync::Arc;
+use tracing::{error, info, instrument};
+
+use crate::ports::{CacheTtlOverrideEnginePort, CacheTtlOverrideRepository};
+
+pub struct DeleteCacheTtlOverrideUseCase {
+    repo: Arc<dyn CacheTtlOverrideRepository>,
+    engine: Arc<dyn CacheTtlOverrideEnginePort>,
+}
+
+impl DeleteCacheTtlOverrideUseCase {
+    pub fn new(
+        repo: Arc<dyn CacheTtlOverrideRepository>,
+        engine: Arc<dyn CacheTtlOverrideEnginePort>,
+    ) -> Self {
+        Self { repo, engine }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(&self, id: i64) -> Result<(), DomainError> {
+        self.repo
+            .get_by_id(id)
+            .await?
+            .ok_or(DomainError::CacheTtlOverrideNotFound(id))?;
+
+        self.repo.delete(id).await?;
+
+        info!(override_id = id, "Cache TTL override deleted successfully");
+
+        if let Err(e) = self.engine.reload().await {
+            error!(error = %e, "Failed to reload cache TTL overrides after deletion");
+        }
+
+        Ok(())
+    }
+}
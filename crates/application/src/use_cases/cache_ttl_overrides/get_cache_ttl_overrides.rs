@@ -0,0 +1,25 @@
+use ferrous_dns_domain::{CacheTtlOverride, DomainError};
+use std::sync::Arc;
+use tracing::instrument;
+
+use crate::ports::CacheTtlOverrideRepository;
+
+pub struct GetCacheTtlOverridesUseCase {
+    repo: Arc<dyn CacheTtlOverrideRepository>,
+}
+
+impl GetCacheTtlOverridesUseCase {
+    pub fn new(repo: Arc<dyn CacheTtlOverrideRepository>) -> Self {
+        Self { repo }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_all(&self) -> Result<Vec<CacheTtlOverride>, DomainError> {
+        self.repo.get_all().await
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_by_id(&self, id: i64) -> Result<Option<CacheTtlOverride>, DomainError> {
+        self.repo.get_by_id(id).await
+    }
+}
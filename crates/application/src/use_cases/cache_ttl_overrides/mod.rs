@@ -0,0 +1,37 @@
+mod create_cache_ttl_override;
+mod delete_cache_ttl_override;
+mod get_cache_ttl_overrides;
+mod update_cache_ttl_override;
+
+pub use create_cache_ttl_override::CreateCacheTtlOverrideUseCase;
+pub use delete_cache_ttl_override::DeleteCacheTtlOverrideUseCase;
+pub use get_cache_ttl_overrides::GetCacheTtlOverridesUseCase;
+pub use update_cache_ttl_override::UpdateCacheTtlOverrideUseCase;
+
+use crate::ports::CacheTtlOverrideRepository;
+use ferrous_dns_domain::{CacheTtlOverride, DomainError, MAX_WILDCARD_TTL_OVERRIDES};
+
+/// Rejects `candidate` when it would take the enabled wildcard overrides
+/// past [`MAX_WILDCARD_TTL_OVERRIDES`]. `candidate.id` is left out of the
+/// count so updates do not count themselves.
+async fn check_wildcard_capacity(
+    repo: &dyn CacheTtlOverrideRepository,
+    candidate: &CacheTtlOverride,
+) -> Result<(), DomainError> {
+    if !candidate.enabled || !candidate.is_wildcard() {
+        return Ok(());
+    }
+    let wildcards = repo
+        .get_all()
+        .await?
+        .iter()
+        .filter(|o| o.enabled && o.is_wildcard() && o.id != candidate.id)
+        .count();
+    if wildcards >= MAX_WILDCARD_TTL_OVERRIDES {
+        return Err(DomainError::InvalidCacheTtlOverride(format!(
+            "At most {} wildcard overrides can be enabled",
+            MAX_WILDCARD_TTL_OVERRIDES
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use ferrous_dns_domain::{CacheTtlOverride, DomainError};
+use std::sync::Arc;
+use tracing::{error, info, instrument};
+
+use super::check_wildcard_capacity;
+use crate::ports::{CacheTtlOverrideEnginePort, CacheTtlOverrideRepository};
+
+pub struct UpdateCacheTtlOverrideUseCase {
+    repo: Arc<dyn CacheTtlOverrideRepository>,
+    engine: Arc<dyn CacheTtlOverrideEnginePort>,
+}
+
+impl UpdateCacheTtlOverrideUseCase {
+    pub fn new(
+        repo: Arc<dyn CacheTtlOverrideRepository>,
+        engine: Arc<dyn CacheTtlOverrideEnginePort>,
+    ) -> Self {
+        Self { repo, engine }
+    }
+
+    /// Replaces the stored override `id` with `ttl_override`.
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        id: i64,
+        mut ttl_override: CacheTtlOverride,
+    ) -> Result<CacheTtlOverride, DomainError> {
+        self.repo
+            .get_by_id(id)
+            .await?
+            .ok_or(DomainError::CacheTtlOverrideNotFound(id))?;
+
+        ttl_override
+            .validate()
+            .map_err(DomainError::InvalidCacheTtlOverride)?;
+
+        ttl_override.id = Some(id);
+        check_wildcard_capacity(self.repo.as_ref(), &ttl_override).await?;
+        let updated = self.repo.update(&ttl_override).await?;
+
+        info!(
+            override_id = id,
+            domain = %updated.domain,
+            min_ttl = ?updated.min_ttl,
+            max_ttl = ?updated.max_ttl,
+            enabled = updated.enabled,
+            "Cache TTL override updated successfully"
+        );
+
+        if let Err(e) = self.engine.reload().await {
+            error!(error = %e, "Failed to reload cache TTL overrides after update");
+        }
+
+        Ok(updated)
+    }
+}
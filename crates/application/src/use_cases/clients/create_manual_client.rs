@@ -3,12 +3,17 @@ use std::net::IpAddr;
 use std::sync::Arc;
 use tracing::{error, info, instrument};
 
-use crate::ports::{BlockFilterEnginePort, ClientRepository, GroupRepository};
+use crate::ports::{
+    BlockFilterEnginePort, ClientRepository, GroupRepository, PtrRecordRegistry, CLIENT_PTR_TTL,
+};
+use crate::services::SubnetMatcherService;
 
 pub struct CreateManualClientUseCase {
     client_repo: Arc<dyn ClientRepository>,
     group_repo: Arc<dyn GroupRepository>,
     block_filter_engine: Option<Arc<dyn BlockFilterEnginePort>>,
+    subnet_matcher: Option<Arc<SubnetMatcherService>>,
+    ptr_registry: Option<Arc<dyn PtrRecordRegistry>>,
 }
 
 impl CreateManualClientUseCase {
@@ -20,14 +25,26 @@ impl CreateManualClientUseCase {
             client_repo,
             group_repo,
             block_filter_engine: None,
+            subnet_matcher: None,
+            ptr_registry: None,
         }
     }
 
+    pub fn with_ptr_registry(mut self, registry: Option<Arc<dyn PtrRecordRegistry>>) -> Self {
+        self.ptr_registry = registry;
+        self
+    }
+
     pub fn with_block_filter(mut self, engine: Arc<dyn BlockFilterEnginePort>) -> Self {
         self.block_filter_engine = Some(engine);
         self
     }
 
+    pub fn with_subnet_matcher(mut self, subnet_matcher: Arc<SubnetMatcherService>) -> Self {
+        self.subnet_matcher = Some(subnet_matcher);
+        self
+    }
+
     #[instrument(skip(self))]
     pub async fn execute(
         &self,
@@ -43,12 +60,21 @@ impl CreateManualClientUseCase {
                 .ok_or(DomainError::GroupNotFound(gid))?;
         }
 
+        let group_id = match (group_id, &self.subnet_matcher) {
+            (None, Some(matcher)) => matcher.find_group_for_ip(ip_address).await,
+            _ => group_id,
+        };
+
         let initial = self.client_repo.get_or_create(ip_address).await?;
 
         if let Some(hostname) = hostname {
+            let ptr_name: Arc<str> = Arc::from(hostname.as_str());
             self.client_repo
                 .update_hostname(ip_address, hostname)
                 .await?;
+            if let Some(ref registry) = self.ptr_registry {
+                registry.register(ip_address, ptr_name, CLIENT_PTR_TTL);
+            }
         }
 
         if let Some(mac) = mac_address {
@@ -2,11 +2,12 @@ use ferrous_dns_domain::DomainError;
 use std::sync::Arc;
 use tracing::{error, info, instrument};
 
-use crate::ports::{BlockFilterEnginePort, ClientRepository};
+use crate::ports::{BlockFilterEnginePort, ClientRepository, PtrRecordRegistry};
 
 pub struct DeleteClientUseCase {
     client_repo: Arc<dyn ClientRepository>,
     block_filter_engine: Option<Arc<dyn BlockFilterEnginePort>>,
+    ptr_registry: Option<Arc<dyn PtrRecordRegistry>>,
 }
 
 impl DeleteClientUseCase {
@@ -14,9 +15,15 @@ impl DeleteClientUseCase {
         Self {
             client_repo,
             block_filter_engine: None,
+            ptr_registry: None,
         }
     }
 
+    pub fn with_ptr_registry(mut self, registry: Option<Arc<dyn PtrRecordRegistry>>) -> Self {
+        self.ptr_registry = registry;
+        self
+    }
+
     pub fn with_block_filter(mut self, engine: Arc<dyn BlockFilterEnginePort>) -> Self {
         self.block_filter_engine = Some(engine);
         self
@@ -34,6 +41,10 @@ impl DeleteClientUseCase {
 
         self.client_repo.delete(id).await?;
 
+        if let Some(ref registry) = self.ptr_registry {
+            registry.unregister(client.ip_address);
+        }
+
         info!(
             client_id = id,
             ip_address = %client.ip_address,
@@ -0,0 +1,46 @@
+use ferrous_dns_domain::DomainError;
+use std::sync::Arc;
+use tracing::instrument;
+
+use crate::ports::{ClientActivity, ClientRepository, QueryLogRepository, TimeGranularity};
+
+pub struct GetClientActivityUseCase {
+    client_repo: Arc<dyn ClientRepository>,
+    query_log_repo: Arc<dyn QueryLogRepository>,
+}
+
+impl GetClientActivityUseCase {
+    pub fn new(
+        client_repo: Arc<dyn ClientRepository>,
+        query_log_repo: Arc<dyn QueryLogRepository>,
+    ) -> Self {
+        Self {
+            client_repo,
+            query_log_repo,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        client_id: i64,
+        period_hours: u32,
+        granularity: TimeGranularity,
+        limit: u32,
+    ) -> Result<ClientActivity, DomainError> {
+        let client = self
+            .client_repo
+            .get_by_id(client_id)
+            .await?
+            .ok_or(DomainError::ClientNotFound(client_id.to_string()))?;
+
+        self.query_log_repo
+            .get_client_activity(
+                &client.ip_address.to_string(),
+                period_hours.clamp(1, 720),
+                granularity,
+                limit.clamp(1, 100),
+            )
+            .await
+    }
+}
@@ -1,5 +1,5 @@
 use crate::ports::ClientRepository;
-use ferrous_dns_domain::{Client, ClientStats, DomainError};
+use ferrous_dns_domain::{Client, ClientStats, DomainError, PageRequest};
 use std::sync::Arc;
 
 pub struct GetClientsUseCase {
@@ -19,6 +19,14 @@ impl GetClientsUseCase {
         self.client_repo.get_active(days, limit).await
     }
 
+    pub async fn get_page(
+        &self,
+        page: &PageRequest,
+        active_days: Option<u32>,
+    ) -> Result<(Vec<Client>, u64), DomainError> {
+        self.client_repo.get_page(page, active_days).await
+    }
+
     pub async fn get_stats(&self) -> Result<ClientStats, DomainError> {
         self.client_repo.get_stats().await
     }
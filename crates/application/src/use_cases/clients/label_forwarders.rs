@@ -0,0 +1,49 @@
+use ferrous_dns_domain::{ClientCategory, DomainError};
+use std::net::IpAddr;
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use crate::ports::ClientRepository;
+
+/// Names the client entries of DNS forwarders from
+/// `dns.edns_client_id.forwarder_labels`, so the queries a forwarder cannot
+/// attribute to a device are reported under that forwarder's label. Names
+/// and categories the user already set are kept.
+pub struct LabelForwardersUseCase {
+    client_repo: Arc<dyn ClientRepository>,
+}
+
+impl LabelForwardersUseCase {
+    pub fn new(client_repo: Arc<dyn ClientRepository>) -> Self {
+        Self { client_repo }
+    }
+
+    /// Returns the number of client entries that were labelled.
+    #[instrument(skip(self))]
+    pub async fn execute(&self, forwarders: &[(IpAddr, String)]) -> Result<u64, DomainError> {
+        let mut labelled = 0;
+        for (ip, label) in forwarders {
+            let client = self.client_repo.get_or_create(*ip).await?;
+            let Some(client_id) = client.id else {
+                continue;
+            };
+            if client.display_name.is_some() {
+                continue;
+            }
+            self.client_repo
+                .update_metadata(
+                    client_id,
+                    Some(label.clone()),
+                    client.category.or(Some(ClientCategory::Router)),
+                    client.notes.map(|notes| notes.to_string()),
+                )
+                .await?;
+            labelled += 1;
+        }
+
+        if labelled > 0 {
+            info!(labelled, "Labelled forwarder clients");
+        }
+        Ok(labelled)
+    }
+}
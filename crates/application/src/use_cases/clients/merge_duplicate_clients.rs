@@ -0,0 +1,43 @@
+use ferrous_dns_domain::DomainError;
+use std::sync::Arc;
+use tracing::{info, instrument, warn};
+
+use crate::ports::DeviceRepository;
+
+/// Collapses client rows that belong to the same MAC address into the most
+/// recently seen row, so a device keeps one history across DHCP renumbering.
+pub struct MergeDuplicateClientsUseCase {
+    device_repo: Arc<dyn DeviceRepository>,
+}
+
+impl MergeDuplicateClientsUseCase {
+    pub fn new(device_repo: Arc<dyn DeviceRepository>) -> Self {
+        Self { device_repo }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(&self) -> Result<u64, DomainError> {
+        let duplicates = self.device_repo.find_duplicate_clients().await?;
+        let mut merged = 0;
+
+        for group in duplicates {
+            let Some((&keep_id, rest)) = group.client_ids.split_first() else {
+                continue;
+            };
+            if rest.is_empty() {
+                continue;
+            }
+            match self.device_repo.merge_clients(keep_id, rest).await {
+                Ok(removed) => merged += removed,
+                Err(e) => {
+                    warn!(mac = %group.mac_address, error = %e, "Failed to merge duplicate clients")
+                }
+            }
+        }
+
+        if merged > 0 {
+            info!(merged, "Merged duplicate client rows by MAC address");
+        }
+        Ok(merged)
+    }
+}
@@ -1,7 +1,10 @@
 pub mod cleanup_old_clients;
 pub mod create_manual_client;
 pub mod delete_client;
+pub mod get_client_activity;
 pub mod get_clients;
+pub mod label_forwarders;
+pub mod merge_duplicate_clients;
 pub mod sync_arp_cache;
 pub mod sync_hostnames;
 pub mod track_client;
@@ -10,8 +13,11 @@ pub mod update_client;
 pub use cleanup_old_clients::CleanupOldClientsUseCase;
 pub use create_manual_client::CreateManualClientUseCase;
 pub use delete_client::DeleteClientUseCase;
+pub use get_client_activity::GetClientActivityUseCase;
 pub use get_clients::GetClientsUseCase;
+pub use label_forwarders::LabelForwardersUseCase;
+pub use merge_duplicate_clients::MergeDuplicateClientsUseCase;
 pub use sync_arp_cache::SyncArpCacheUseCase;
 pub use sync_hostnames::SyncHostnamesUseCase;
 pub use track_client::TrackClientUseCase;
-pub use update_client::UpdateClientUseCase;
+pub use update_client::{ClientMetadataUpdate, UpdateClientUseCase};
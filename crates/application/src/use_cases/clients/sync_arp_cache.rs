@@ -1,11 +1,13 @@
-use crate::ports::{ArpReader, ClientRepository};
-use ferrous_dns_domain::DomainError;
+use crate::ports::{ArpReader, ClientRepository, DeviceAddressRegistry, DeviceRepository};
+use ferrous_dns_domain::{Device, DomainError};
 use std::sync::Arc;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 pub struct SyncArpCacheUseCase {
     arp_reader: Arc<dyn ArpReader>,
     client_repo: Arc<dyn ClientRepository>,
+    device_repo: Option<Arc<dyn DeviceRepository>>,
+    address_registry: Option<Arc<dyn DeviceAddressRegistry>>,
 }
 
 impl SyncArpCacheUseCase {
@@ -13,9 +15,24 @@ impl SyncArpCacheUseCase {
         Self {
             arp_reader,
             client_repo,
+            device_repo: None,
+            address_registry: None,
         }
     }
 
+    pub fn with_device_repo(mut self, device_repo: Arc<dyn DeviceRepository>) -> Self {
+        self.device_repo = Some(device_repo);
+        self
+    }
+
+    pub fn with_address_registry(
+        mut self,
+        registry: Option<Arc<dyn DeviceAddressRegistry>>,
+    ) -> Self {
+        self.address_registry = registry;
+        self
+    }
+
     pub async fn execute(&self) -> Result<u64, DomainError> {
         debug!("Reading ARP cache");
 
@@ -26,6 +43,26 @@ impl SyncArpCacheUseCase {
 
         let updates: Vec<_> = arp_table.into_iter().collect();
 
+        if let Some(ref registry) = self.address_registry {
+            registry.replace(
+                updates
+                    .iter()
+                    .filter_map(|(ip, mac)| Some((Device::normalize_mac(mac)?, *ip)))
+                    .collect(),
+            );
+        }
+
+        if let Some(ref device_repo) = self.device_repo {
+            for (ip, mac) in &updates {
+                let Some(mac) = Device::normalize_mac(mac) else {
+                    continue;
+                };
+                if let Err(e) = device_repo.record_sighting(&mac, *ip).await {
+                    warn!(%ip, mac = %mac, error = %e, "Failed to record device sighting");
+                }
+            }
+        }
+
         let updated = self.client_repo.batch_update_mac_addresses(updates).await?;
 
         info!(total = count, updated, "ARP cache synchronized");
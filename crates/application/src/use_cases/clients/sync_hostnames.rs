@@ -1,4 +1,4 @@
-use crate::ports::{ClientRepository, HostnameResolver};
+use crate::ports::{ClientRepository, HostnameResolver, PtrRecordRegistry, CLIENT_PTR_TTL};
 use ferrous_dns_domain::DomainError;
 use std::sync::Arc;
 use tracing::{debug, info, warn};
@@ -6,6 +6,7 @@ use tracing::{debug, info, warn};
 pub struct SyncHostnamesUseCase {
     client_repo: Arc<dyn ClientRepository>,
     hostname_resolver: Arc<dyn HostnameResolver>,
+    ptr_registry: Option<Arc<dyn PtrRecordRegistry>>,
 }
 
 impl SyncHostnamesUseCase {
@@ -16,9 +17,17 @@ impl SyncHostnamesUseCase {
         Self {
             client_repo,
             hostname_resolver,
+            ptr_registry: None,
         }
     }
 
+    /// Attaches the client reverse zone so resolved hostnames immediately
+    /// answer PTR queries for the client's address.
+    pub fn with_ptr_registry(mut self, registry: Option<Arc<dyn PtrRecordRegistry>>) -> Self {
+        self.ptr_registry = registry;
+        self
+    }
+
     pub async fn execute(&self, batch_size: u32) -> Result<u64, DomainError> {
         debug!(batch_size, "Resolving hostnames for clients");
 
@@ -35,12 +44,18 @@ impl SyncHostnamesUseCase {
                 .await
             {
                 Ok(Some(hostname)) => {
+                    let ptr_name: Arc<str> = Arc::from(hostname.as_str());
                     match self
                         .client_repo
                         .update_hostname(client.ip_address, hostname)
                         .await
                     {
-                        Ok(_) => resolved += 1,
+                        Ok(_) => {
+                            resolved += 1;
+                            if let Some(ref registry) = self.ptr_registry {
+                                registry.register(client.ip_address, ptr_name, CLIENT_PTR_TTL);
+                            }
+                        }
                         Err(e) => {
                             warn!(error = %e, ip = %client.ip_address, "Failed to update hostname")
                         }
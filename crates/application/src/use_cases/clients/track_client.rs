@@ -1,4 +1,5 @@
 use crate::ports::ClientRepository;
+use crate::services::SubnetMatcherService;
 use ferrous_dns_domain::DomainError;
 use std::net::IpAddr;
 use std::sync::Arc;
@@ -6,17 +7,38 @@ use tracing::debug;
 
 pub struct TrackClientUseCase {
     client_repo: Arc<dyn ClientRepository>,
+    subnet_matcher: Option<Arc<SubnetMatcherService>>,
 }
 
 impl TrackClientUseCase {
     pub fn new(client_repo: Arc<dyn ClientRepository>) -> Self {
-        Self { client_repo }
+        Self {
+            client_repo,
+            subnet_matcher: None,
+        }
+    }
+
+    pub fn with_subnet_matcher(mut self, subnet_matcher: Arc<SubnetMatcherService>) -> Self {
+        self.subnet_matcher = Some(subnet_matcher);
+        self
     }
 
     pub async fn execute(&self, client_ip: IpAddr) -> Result<(), DomainError> {
         debug!(ip = %client_ip, "Tracking client");
 
-        self.client_repo.update_last_seen(client_ip).await?;
+        let subnet_group = match &self.subnet_matcher {
+            Some(matcher) => matcher.find_group_for_ip(client_ip).await,
+            None => None,
+        };
+
+        match subnet_group {
+            Some(group_id) => {
+                self.client_repo
+                    .update_last_seen_in_group(client_ip, group_id)
+                    .await?
+            }
+            None => self.client_repo.update_last_seen(client_ip).await?,
+        }
 
         Ok(())
     }
@@ -1,13 +1,53 @@
-use ferrous_dns_domain::{Client, DomainError};
+use ferrous_dns_domain::entities::client::{MAX_DISPLAY_NAME_LEN, MAX_NOTES_LEN};
+use ferrous_dns_domain::{Client, ClientCategory, DomainError};
 use std::sync::Arc;
 use tracing::{error, info, instrument};
 
-use crate::ports::{BlockFilterEnginePort, ClientRepository, GroupRepository};
+use crate::ports::{
+    BlockFilterEnginePort, ClientRepository, GroupRepository, PtrRecordRegistry, CLIENT_PTR_TTL,
+};
+
+/// User-managed display fields for a client. `None` leaves a field
+/// unchanged; an empty string clears it.
+#[derive(Debug, Clone, Default)]
+pub struct ClientMetadataUpdate {
+    pub display_name: Option<String>,
+    pub category: Option<String>,
+    pub notes: Option<String>,
+}
+
+impl ClientMetadataUpdate {
+    pub fn is_empty(&self) -> bool {
+        self.display_name.is_none() && self.category.is_none() && self.notes.is_none()
+    }
+}
+
+fn merge_text(
+    update: Option<String>,
+    current: Option<&str>,
+    field: &str,
+    max_len: usize,
+) -> Result<Option<String>, DomainError> {
+    let Some(value) = update else {
+        return Ok(current.map(str::to_string));
+    };
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    if value.chars().count() > max_len {
+        return Err(DomainError::InvalidInput(format!(
+            "{field} must be at most {max_len} characters"
+        )));
+    }
+    Ok(Some(value.to_string()))
+}
 
 pub struct UpdateClientUseCase {
     client_repo: Arc<dyn ClientRepository>,
     group_repo: Option<Arc<dyn GroupRepository>>,
     block_filter_engine: Option<Arc<dyn BlockFilterEnginePort>>,
+    ptr_registry: Option<Arc<dyn PtrRecordRegistry>>,
 }
 
 impl UpdateClientUseCase {
@@ -16,9 +56,15 @@ impl UpdateClientUseCase {
             client_repo,
             group_repo: None,
             block_filter_engine: None,
+            ptr_registry: None,
         }
     }
 
+    pub fn with_ptr_registry(mut self, registry: Option<Arc<dyn PtrRecordRegistry>>) -> Self {
+        self.ptr_registry = registry;
+        self
+    }
+
     pub fn with_block_filter(mut self, engine: Arc<dyn BlockFilterEnginePort>) -> Self {
         self.block_filter_engine = Some(engine);
         self
@@ -46,6 +92,9 @@ impl UpdateClientUseCase {
             self.client_repo
                 .update_hostname(client.ip_address, h.clone())
                 .await?;
+            if let Some(ref registry) = self.ptr_registry {
+                registry.register(client.ip_address, Arc::from(h.as_str()), CLIENT_PTR_TTL);
+            }
         }
 
         let group_changed = group_id.is_some_and(|gid| client.group_id != Some(gid));
@@ -78,4 +127,48 @@ impl UpdateClientUseCase {
 
         Ok(updated)
     }
+
+    #[instrument(skip(self))]
+    pub async fn update_metadata(
+        &self,
+        client_id: i64,
+        update: ClientMetadataUpdate,
+    ) -> Result<Client, DomainError> {
+        let client = self
+            .client_repo
+            .get_by_id(client_id)
+            .await?
+            .ok_or(DomainError::ClientNotFound(client_id.to_string()))?;
+
+        let display_name = merge_text(
+            update.display_name,
+            client.display_name.as_deref(),
+            "display_name",
+            MAX_DISPLAY_NAME_LEN,
+        )?;
+        let notes = merge_text(
+            update.notes,
+            client.notes.as_deref(),
+            "notes",
+            MAX_NOTES_LEN,
+        )?;
+        let category = match update.category.as_deref().map(str::trim) {
+            None => client.category,
+            Some("") => None,
+            Some(value) => Some(value.parse::<ClientCategory>().map_err(|_| {
+                DomainError::InvalidInput(format!("Unknown client category '{value}'"))
+            })?),
+        };
+
+        self.client_repo
+            .update_metadata(client_id, display_name, category, notes)
+            .await?;
+
+        info!(client_id, "Client metadata updated");
+
+        self.client_repo
+            .get_by_id(client_id)
+            .await?
+            .ok_or(DomainError::ClientNotFound(client_id.to_string()))
+    }
 }
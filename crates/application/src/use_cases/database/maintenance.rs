@@ -0,0 +1,143 @@
+use crate::ports::{AuditLogRepository, DatabaseMaintenancePort, DatabaseUsage};
+use chrono::{DateTime, Utc};
+use ferrous_dns_domain::DomainError;
+use std::sync::{Arc, RwLock};
+use std::time::Instant;
+use tracing::{info, warn};
+
+/// Query log rows deleted per statement while enforcing the size cap.
+const TRIM_BATCH_ROWS: u32 = 10_000;
+
+/// Upper bound on trim batches in one run, so a run never holds the writer for long.
+const MAX_TRIM_BATCHES: u32 = 100;
+
+/// Result of a single maintenance run.
+#[derive(Debug, Clone)]
+pub struct DatabaseMaintenanceReport {
+    pub started_at: DateTime<Utc>,
+    pub duration_ms: u64,
+    pub trimmed_rows: u64,
+    pub reclaimed_bytes: u64,
+    pub size_before: DatabaseUsage,
+    pub size_after: DatabaseUsage,
+}
+
+/// Current database footprint plus the outcome of the last maintenance run.
+#[derive(Debug, Clone)]
+pub struct DatabaseStatus {
+    pub usage: DatabaseUsage,
+    pub max_size_bytes: Option<u64>,
+    pub last_maintenance: Option<DatabaseMaintenanceReport>,
+}
+
+pub struct DatabaseMaintenanceUseCase {
+    maintenance: Arc<dyn DatabaseMaintenancePort>,
+    max_size_bytes: Option<u64>,
+    audit_log: Option<(Arc<dyn AuditLogRepository>, u32)>,
+    last_report: RwLock<Option<DatabaseMaintenanceReport>>,
+}
+
+impl DatabaseMaintenanceUseCase {
+    pub fn new(maintenance: Arc<dyn DatabaseMaintenancePort>) -> Self {
+        Self {
+            maintenance,
+            max_size_bytes: None,
+            audit_log: None,
+            last_report: RwLock::new(None),
+        }
+    }
+
+    /// Caps the bytes held by used pages; `0` disables the cap.
+    pub fn with_max_size_bytes(mut self, max_size_bytes: u64) -> Self {
+        self.max_size_bytes = (max_size_bytes > 0).then_some(max_size_bytes);
+        self
+    }
+
+    /// Deletes audit log entries older than `retention_days` on each run.
+    pub fn with_audit_log(
+        mut self,
+        audit_log: Arc<dyn AuditLogRepository>,
+        retention_days: u32,
+    ) -> Self {
+        self.audit_log = Some((audit_log, retention_days));
+        self
+    }
+
+    /// Trims the oldest query log rows while over the size cap, then returns
+    /// free pages to the filesystem and refreshes planner statistics.
+    pub async fn run(&self) -> Result<DatabaseMaintenanceReport, DomainError> {
+        let started_at = Utc::now();
+        let start = Instant::now();
+        let size_before = self.maintenance.usage().await?;
+
+        if let Some((ref audit_log, retention_days)) = self.audit_log {
+            let deleted = audit_log.delete_older_than(retention_days).await?;
+            if deleted > 0 {
+                info!(deleted, retention_days, "Expired audit log entries deleted");
+            }
+        }
+
+        let trimmed_rows = match self.max_size_bytes {
+            Some(max) => self.enforce_max_size(max, size_before.used_bytes).await?,
+            None => 0,
+        };
+        let reclaimed_bytes = self.maintenance.incremental_vacuum().await?;
+        self.maintenance.optimize().await?;
+        let size_after = self.maintenance.usage().await?;
+
+        let report = DatabaseMaintenanceReport {
+            started_at,
+            duration_ms: start.elapsed().as_millis() as u64,
+            trimmed_rows,
+            reclaimed_bytes,
+            size_before,
+            size_after,
+        };
+        if let Ok(mut last) = self.last_report.write() {
+            *last = Some(report.clone());
+        }
+        Ok(report)
+    }
+
+    pub async fn status(&self) -> Result<DatabaseStatus, DomainError> {
+        let usage = self.maintenance.usage().await?;
+        let last_maintenance = self.last_report.read().ok().and_then(|last| last.clone());
+        Ok(DatabaseStatus {
+            usage,
+            max_size_bytes: self.max_size_bytes,
+            last_maintenance,
+        })
+    }
+
+    async fn enforce_max_size(&self, max: u64, mut used_bytes: u64) -> Result<u64, DomainError> {
+        let mut trimmed = 0;
+        for _ in 0..MAX_TRIM_BATCHES {
+            if used_bytes <= max {
+                return Ok(trimmed);
+            }
+            let deleted = self
+                .maintenance
+                .delete_oldest_query_logs(TRIM_BATCH_ROWS)
+                .await?;
+            if deleted == 0 {
+                warn!(
+                    used_bytes,
+                    max_size_bytes = max,
+                    "Database over size cap with no query log rows left to trim"
+                );
+                return Ok(trimmed);
+            }
+            trimmed += deleted;
+            used_bytes = self.maintenance.usage().await?.used_bytes;
+        }
+        if used_bytes > max {
+            info!(
+                trimmed,
+                used_bytes,
+                max_size_bytes = max,
+                "Database still over size cap, trimming continues next run"
+            );
+        }
+        Ok(trimmed)
+    }
+}
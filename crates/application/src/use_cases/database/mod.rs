@@ -0,0 +1,3 @@
+pub mod maintenance;
+
+pub use maintenance::{DatabaseMaintenanceReport, DatabaseMaintenanceUseCase, DatabaseStatus};
@@ -0,0 +1,188 @@
+use crate::ports::{
+    BlockFilterEnginePort, CacheEntrySnapshot, CacheEntryState, DnsCachePort, DnsRewriteEnginePort,
+    FilterDecision, FilterExplanation, QueryPolicyEnginePort, SafeSearchEnginePort,
+    UpstreamHealthPort, UpstreamRoute,
+};
+use ferrous_dns_domain::{
+    blocklist::BlockedDomain, BlockSource, DomainError, PolicyAction, PolicyMatch, RecordType,
+    RewriteTarget,
+};
+use std::net::{IpAddr, Ipv4Addr};
+use std::sync::Arc;
+use tracing::{debug, instrument};
+
+/// What the query handler would do with the query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosisOutcome {
+    Blocked(BlockSource),
+    PolicyRewrite,
+    Rewritten,
+    SafeSearch,
+    Cached,
+    NegativeCached,
+    Forwarded,
+}
+
+impl DiagnosisOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Blocked(_) => "blocked",
+            Self::PolicyRewrite => "policy_rewrite",
+            Self::Rewritten => "rewritten",
+            Self::SafeSearch => "safe_search",
+            Self::Cached => "cached",
+            Self::NegativeCached => "negative_cached",
+            Self::Forwarded => "forwarded",
+        }
+    }
+}
+
+/// Every input to the filtering and resolution decision for one query.
+#[derive(Debug, Clone)]
+pub struct DomainDiagnosis {
+    pub domain: String,
+    pub record_type: RecordType,
+    pub client_ip: IpAddr,
+    pub group_id: i64,
+    pub outcome: DiagnosisOutcome,
+    pub policy: Option<PolicyMatch>,
+    pub rewrite: Option<RewriteTarget>,
+    pub safe_search_target: Option<&'static str>,
+    pub filter: FilterExplanation,
+    pub cache: Option<CacheEntrySnapshot>,
+    pub upstream: Option<UpstreamRoute>,
+}
+
+/// Walks the same decision order as `HandleDnsQueryUseCase` for a domain and
+/// client, reading each stage without logging, caching or querying upstream.
+pub struct DiagnoseDomainUseCase {
+    block_filter: Arc<dyn BlockFilterEnginePort>,
+    cache: Arc<dyn DnsCachePort>,
+    upstream: Arc<dyn UpstreamHealthPort>,
+    query_policy: Option<Arc<dyn QueryPolicyEnginePort>>,
+    dns_rewrites: Option<Arc<dyn DnsRewriteEnginePort>>,
+    safe_search: Option<Arc<dyn SafeSearchEnginePort>>,
+}
+
+impl DiagnoseDomainUseCase {
+    pub fn new(
+        block_filter: Arc<dyn BlockFilterEnginePort>,
+        cache: Arc<dyn DnsCachePort>,
+        upstream: Arc<dyn UpstreamHealthPort>,
+    ) -> Self {
+        Self {
+            block_filter,
+            cache,
+            upstream,
+            query_policy: None,
+            dns_rewrites: None,
+            safe_search: None,
+        }
+    }
+
+    pub fn with_query_policy(mut self, query_policy: Arc<dyn QueryPolicyEnginePort>) -> Self {
+        self.query_policy = Some(query_policy);
+        self
+    }
+
+    pub fn with_dns_rewrites(mut self, dns_rewrites: Arc<dyn DnsRewriteEnginePort>) -> Self {
+        self.dns_rewrites = Some(dns_rewrites);
+        self
+    }
+
+    pub fn with_safe_search(mut self, safe_search: Arc<dyn SafeSearchEnginePort>) -> Self {
+        self.safe_search = Some(safe_search);
+        self
+    }
+
+    /// Without a client IP the query is diagnosed for the default group.
+    #[instrument(skip(self))]
+    pub fn execute(
+        &self,
+        domain: &str,
+        client_ip: Option<IpAddr>,
+        record_type: RecordType,
+    ) -> Result<DomainDiagnosis, DomainError> {
+        let domain = domain.trim().trim_end_matches('.').to_ascii_lowercase();
+        BlockedDomain::validate_domain(&domain).map_err(DomainError::InvalidDomainName)?;
+
+        let client_ip = client_ip.unwrap_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+        let group_id = self.block_filter.resolve_group(client_ip);
+
+        let policy = self
+            .query_policy
+            .as_deref()
+            .and_then(|engine| engine.evaluate(client_ip, group_id, &domain, record_type));
+        let rewrite = self
+            .dns_rewrites
+            .as_deref()
+            .and_then(|engine| engine.lookup(&domain));
+        let filter = self.block_filter.explain(&domain, group_id);
+        let safe_search_target = self
+            .safe_search
+            .as_deref()
+            .and_then(|engine| engine.cname_for(&domain, group_id));
+        let cache = self.cache.peek(&domain, record_type);
+
+        let forward_pool = policy
+            .as_ref()
+            .filter(|p| p.action == PolicyAction::ForwardToPool)
+            .and_then(|p| p.target.as_deref());
+        let upstream = self.upstream.preview_route(forward_pool);
+
+        let outcome = Self::outcome(
+            policy.as_ref(),
+            rewrite.is_some(),
+            filter.decision,
+            safe_search_target.is_some(),
+            cache.as_ref().filter(|_| forward_pool.is_none()),
+        );
+        debug!(%domain, group_id, outcome = outcome.as_str(), "Domain diagnosed");
+
+        Ok(DomainDiagnosis {
+            domain,
+            record_type,
+            client_ip,
+            group_id,
+            outcome,
+            policy,
+            rewrite,
+            safe_search_target,
+            filter,
+            cache,
+            upstream,
+        })
+    }
+
+    fn outcome(
+        policy: Option<&PolicyMatch>,
+        rewritten: bool,
+        decision: FilterDecision,
+        safe_search: bool,
+        cache: Option<&CacheEntrySnapshot>,
+    ) -> DiagnosisOutcome {
+        let policy_action = policy.map(|p| p.action);
+        if policy_action == Some(PolicyAction::Block) {
+            return DiagnosisOutcome::Blocked(BlockSource::QueryPolicy);
+        }
+        if policy.is_some_and(|p| p.action == PolicyAction::Rewrite && p.target.is_some()) {
+            return DiagnosisOutcome::PolicyRewrite;
+        }
+        if rewritten {
+            return DiagnosisOutcome::Rewritten;
+        }
+        if let FilterDecision::Block(source) = decision {
+            if policy_action != Some(PolicyAction::Allow) {
+                return DiagnosisOutcome::Blocked(source);
+            }
+        }
+        if safe_search {
+            return DiagnosisOutcome::SafeSearch;
+        }
+        match cache.map(|c| c.state) {
+            Some(CacheEntryState::Negative) => DiagnosisOutcome::NegativeCached,
+            Some(_) => DiagnosisOutcome::Cached,
+            None => DiagnosisOutcome::Forwarded,
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod diagnose_domain;
+pub mod trace_resolve;
+
+pub use diagnose_domain::{DiagnoseDomainUseCase, DiagnosisOutcome, DomainDiagnosis};
+pub use trace_resolve::{ResolutionTrace, TraceResolveUseCase, TraceStage, TraceStep};
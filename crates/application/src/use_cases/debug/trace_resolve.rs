@@ -0,0 +1,348 @@
+use super::diagnose_domain::{DiagnoseDomainUseCase, DiagnosisOutcome, DomainDiagnosis};
+use crate::ports::{
+    trace_upstream_attempts, DnsResolution, DnsResolver, FilterDecision, UpstreamAttempt,
+};
+use ferrous_dns_domain::{
+    BlockSource, DnsQuery, DomainError, PolicyAction, RecordType, RewriteTarget,
+};
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::Instant;
+use tracing::{debug, instrument};
+
+/// Pipeline stage reported by a [`TraceStep`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceStage {
+    QueryPolicy,
+    Rewrite,
+    BlockFilter,
+    SafeSearch,
+    Cache,
+    Upstream,
+    Dnssec,
+    Answer,
+}
+
+impl TraceStage {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::QueryPolicy => "query_policy",
+            Self::Rewrite => "rewrite",
+            Self::BlockFilter => "block_filter",
+            Self::SafeSearch => "safe_search",
+            Self::Cache => "cache",
+            Self::Upstream => "upstream",
+            Self::Dnssec => "dnssec",
+            Self::Answer => "answer",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceStep {
+    pub stage: TraceStage,
+    pub result: &'static str,
+    pub detail: Option<String>,
+}
+
+impl TraceStep {
+    fn new(stage: TraceStage, result: &'static str, detail: Option<String>) -> Self {
+        Self {
+            stage,
+            result,
+            detail,
+        }
+    }
+}
+
+/// Report of one live resolution, in pipeline order.
+#[derive(Debug, Clone)]
+pub struct ResolutionTrace {
+    pub diagnosis: DomainDiagnosis,
+    pub steps: Vec<TraceStep>,
+    /// Name actually sent to the resolver; differs from the query name after
+    /// a policy, rewrite or SafeSearch CNAME.
+    pub resolved_name: Option<String>,
+    pub upstream_attempts: Vec<UpstreamAttempt>,
+    pub resolution: Option<DnsResolution>,
+    pub error: Option<DomainError>,
+    pub elapsed_us: u64,
+}
+
+/// Resolves a query through the live resolver chain, the way
+/// `HandleDnsQueryUseCase` would, and records what each stage did. The
+/// resolution may populate the cache but is not written to the query log.
+pub struct TraceResolveUseCase {
+    diagnose: Arc<DiagnoseDomainUseCase>,
+    resolver: Arc<dyn DnsResolver>,
+}
+
+impl TraceResolveUseCase {
+    pub fn new(diagnose: Arc<DiagnoseDomainUseCase>, resolver: Arc<dyn DnsResolver>) -> Self {
+        Self { diagnose, resolver }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        domain: &str,
+        client_ip: Option<IpAddr>,
+        record_type: RecordType,
+    ) -> Result<ResolutionTrace, DomainError> {
+        let start = Instant::now();
+        let diagnosis = self.diagnose.execute(domain, client_ip, record_type)?;
+        let mut steps = Self::decision_steps(&diagnosis);
+
+        let target = match (&diagnosis.outcome, &diagnosis.rewrite) {
+            (DiagnosisOutcome::Blocked(source), _) => {
+                steps.push(TraceStep::new(
+                    TraceStage::Answer,
+                    "blocked",
+                    Some(source.to_str().to_string()),
+                ));
+                None
+            }
+            (DiagnosisOutcome::PolicyRewrite, _) => diagnosis
+                .policy
+                .as_ref()
+                .and_then(|p| p.target.as_deref())
+                .map(|t| (t.to_string(), None)),
+            (DiagnosisOutcome::Rewritten, Some(RewriteTarget::Cname(target))) => {
+                Some((target.to_string(), None))
+            }
+            (DiagnosisOutcome::Rewritten, Some(RewriteTarget::Addresses(ips))) => {
+                let addresses: Vec<IpAddr> = ips
+                    .iter()
+                    .copied()
+                    .filter(|ip| match record_type {
+                        RecordType::A => ip.is_ipv4(),
+                        RecordType::AAAA => ip.is_ipv6(),
+                        _ => false,
+                    })
+                    .collect();
+                steps.push(TraceStep::new(
+                    TraceStage::Answer,
+                    "local",
+                    Some(Self::format_addresses(&addresses)),
+                ));
+                let resolution = DnsResolution {
+                    local_dns: true,
+                    ..DnsResolution::new(addresses, false)
+                };
+                return Ok(ResolutionTrace {
+                    diagnosis,
+                    steps,
+                    resolved_name: None,
+                    upstream_attempts: Vec::new(),
+                    resolution: Some(resolution),
+                    error: None,
+                    elapsed_us: start.elapsed().as_micros() as u64,
+                });
+            }
+            (DiagnosisOutcome::SafeSearch, _) => {
+                diagnosis.safe_search_target.map(|t| (t.to_string(), None))
+            }
+            _ => {
+                let pool = diagnosis
+                    .policy
+                    .as_ref()
+                    .filter(|p| p.action == PolicyAction::ForwardToPool)
+                    .and_then(|p| p.target.clone());
+                Some((diagnosis.domain.clone(), pool))
+            }
+        };
+
+        let Some((name, pool)) = target else {
+            return Ok(ResolutionTrace {
+                diagnosis,
+                steps,
+                resolved_name: None,
+                upstream_attempts: Vec::new(),
+                resolution: None,
+                error: None,
+                elapsed_us: start.elapsed().as_micros() as u64,
+            });
+        };
+
+        let query = DnsQuery::new(Arc::from(name.as_str()), record_type);
+        let (result, upstream_attempts) = trace_upstream_attempts(async {
+            match pool.as_deref() {
+                Some(pool) => self.resolver.resolve_via_pool(&query, pool).await,
+                None => self.resolver.resolve(&query).await,
+            }
+        })
+        .await;
+
+        let (resolution, error) = match result {
+            Ok(resolution) => (Some(resolution), None),
+            Err(e) => (None, Some(e)),
+        };
+        steps.extend(Self::resolution_steps(
+            resolution.as_ref(),
+            error.as_ref(),
+            &upstream_attempts,
+        ));
+        debug!(
+            domain = %diagnosis.domain,
+            resolved = %name,
+            attempts = upstream_attempts.len(),
+            "Traced resolution finished"
+        );
+
+        Ok(ResolutionTrace {
+            diagnosis,
+            steps,
+            resolved_name: Some(name),
+            upstream_attempts,
+            resolution,
+            error,
+            elapsed_us: start.elapsed().as_micros() as u64,
+        })
+    }
+
+    fn decision_steps(d: &DomainDiagnosis) -> Vec<TraceStep> {
+        let mut steps = Vec::with_capacity(8);
+
+        steps.push(match &d.policy {
+            Some(p) => TraceStep::new(
+                TraceStage::QueryPolicy,
+                p.action.to_str(),
+                Some(match p.target.as_deref() {
+                    Some(target) => format!("policy {} -> {}", p.policy_id, target),
+                    None => format!("policy {}", p.policy_id),
+                }),
+            ),
+            None => TraceStep::new(TraceStage::QueryPolicy, "no_match", None),
+        });
+        if d.outcome == DiagnosisOutcome::Blocked(BlockSource::QueryPolicy)
+            || d.outcome == DiagnosisOutcome::PolicyRewrite
+        {
+            return steps;
+        }
+
+        steps.push(match &d.rewrite {
+            Some(RewriteTarget::Cname(target)) => {
+                TraceStep::new(TraceStage::Rewrite, "cname", Some(target.to_string()))
+            }
+            Some(RewriteTarget::Addresses(ips)) => TraceStep::new(
+                TraceStage::Rewrite,
+                "addresses",
+                Some(Self::format_addresses(ips)),
+            ),
+            None => TraceStep::new(TraceStage::Rewrite, "no_match", None),
+        });
+        if d.outcome == DiagnosisOutcome::Rewritten {
+            return steps;
+        }
+
+        let policy_allow = d
+            .policy
+            .as_ref()
+            .is_some_and(|p| p.action == PolicyAction::Allow);
+        steps.push(match d.filter.decision {
+            FilterDecision::Block(_) | FilterDecision::Audit(_) if policy_allow => TraceStep::new(
+                TraceStage::BlockFilter,
+                "skipped",
+                Some("allowed by policy".into()),
+            ),
+            FilterDecision::Block(source) => TraceStep::new(
+                TraceStage::BlockFilter,
+                "blocked",
+                Some(source.to_str().to_string()),
+            ),
+            FilterDecision::Audit(source) => TraceStep::new(
+                TraceStage::BlockFilter,
+                "audited",
+                Some(source.to_str().to_string()),
+            ),
+            FilterDecision::Allow if !d.filter.allowlist.is_empty() => {
+                TraceStep::new(TraceStage::BlockFilter, "allowlisted", None)
+            }
+            FilterDecision::Allow => TraceStep::new(TraceStage::BlockFilter, "allowed", None),
+        });
+        if matches!(d.outcome, DiagnosisOutcome::Blocked(_)) {
+            return steps;
+        }
+
+        steps.push(match d.safe_search_target {
+            Some(target) => {
+                TraceStep::new(TraceStage::SafeSearch, "cname", Some(target.to_string()))
+            }
+            None => TraceStep::new(TraceStage::SafeSearch, "no_match", None),
+        });
+        steps
+    }
+
+    fn resolution_steps(
+        resolution: Option<&DnsResolution>,
+        error: Option<&DomainError>,
+        attempts: &[UpstreamAttempt],
+    ) -> Vec<TraceStep> {
+        let mut steps = Vec::with_capacity(4);
+
+        let cache_hit = resolution.is_some_and(|r| r.cache_hit);
+        steps.push(TraceStep::new(
+            TraceStage::Cache,
+            if cache_hit { "hit" } else { "miss" },
+            None,
+        ));
+
+        match resolution {
+            Some(r) if r.local_dns => {
+                steps.push(TraceStep::new(TraceStage::Upstream, "local", None));
+            }
+            Some(r) if r.upstream_server.is_some() => steps.push(TraceStep::new(
+                TraceStage::Upstream,
+                "answered",
+                Some(format!(
+                    "{} via {} ({} attempt(s))",
+                    r.upstream_server.as_deref().unwrap_or_default(),
+                    r.upstream_pool.as_deref().unwrap_or("-"),
+                    attempts.len(),
+                )),
+            )),
+            _ if !attempts.is_empty() => steps.push(TraceStep::new(
+                TraceStage::Upstream,
+                if error.is_some() {
+                    "failed"
+                } else {
+                    "answered"
+                },
+                Some(format!("{} attempt(s)", attempts.len())),
+            )),
+            _ => steps.push(TraceStep::new(TraceStage::Upstream, "not_queried", None)),
+        }
+
+        steps.push(TraceStep::new(
+            TraceStage::Dnssec,
+            resolution
+                .and_then(|r| r.dnssec_status)
+                .unwrap_or("unchecked"),
+            None,
+        ));
+
+        steps.push(match (resolution, error) {
+            (Some(r), _) => {
+                let mut detail = Self::format_addresses(&r.addresses);
+                if !r.cname_chain.is_empty() {
+                    let chain: Vec<&str> = r.cname_chain.iter().map(|c| c.as_ref()).collect();
+                    detail = format!("{} -> {}", chain.join(" -> "), detail);
+                }
+                TraceStep::new(TraceStage::Answer, "noerror", Some(detail))
+            }
+            (None, Some(DomainError::NxDomain | DomainError::LocalNxDomain)) => {
+                TraceStep::new(TraceStage::Answer, "nxdomain", None)
+            }
+            (None, e) => TraceStep::new(TraceStage::Answer, "error", e.map(|e| e.to_string())),
+        });
+        steps
+    }
+
+    fn format_addresses(addresses: &[IpAddr]) -> String {
+        addresses
+            .iter()
+            .map(|ip| ip.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
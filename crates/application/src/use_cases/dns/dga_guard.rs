@@ -252,7 +252,7 @@ pub struct DgaAnalysisEvent {
 ///
 /// Uses a stack-allocated histogram `[u32; 256]` (~1 KB) — zero heap allocation.
 #[inline]
-fn shannon_entropy(data: &[u8]) -> f32 {
+pub(crate) fn shannon_entropy(data: &[u8]) -> f32 {
     if data.is_empty() {
         return 0.0;
     }
@@ -332,7 +332,7 @@ fn is_compound_tld(domain: &str) -> bool {
 }
 
 /// Extracts the apex domain from a domain name.
-fn extract_apex(domain: &str) -> &str {
+pub(crate) fn extract_apex(domain: &str) -> &str {
     let target_dots = if is_compound_tld(domain) { 3 } else { 2 };
     let bytes = domain.as_bytes();
     let mut dot_count = 0;
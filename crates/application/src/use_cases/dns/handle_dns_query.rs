@@ -2,20 +2,25 @@ use super::coarse_timer::coarse_now_ns;
 use super::cookie_guard::{CookieVerdict, DnsCookieGuard};
 use super::dga_guard::{DgaAnalysisEvent, DgaGuard, DgaVerdict};
 use super::nxdomain_hijack_guard::NxdomainHijackGuard;
+use super::phase_timings::{self, QueryPhase};
+use super::query_budget::{self, QueryBudget};
 use super::rate_limiter::{DnsRateLimiter, RateLimitDecision};
-use super::rebinding_guard::RebindingGuard;
 use super::response_ip_filter_guard::ResponseIpFilterGuard;
 use super::tsc_timer;
 use super::tunneling_guard::{TunnelingAnalysisEvent, TunnelingGuard, TunnelingVerdict};
 use crate::ports::{
-    BlockFilterEnginePort, ClientRepository, DgaFlagStore, DnsResolution, DnsResolver,
-    FilterDecision, NxdomainHijackIpStore, QueryLogRepository, ResponseIpFilterStore,
-    SafeSearchEnginePort, TunnelingFlagStore,
+    AaaaFilterPort, BlockFilterEnginePort, ClientRepository, DgaFlagStore, DnsResolution,
+    DnsResolver, DnsRewriteEnginePort, FilterDecision, LocalZoneAnswer, LocalZonePort,
+    NxdomainHijackIpStore, PluginDecision, PluginHookPort, PluginQuery, PluginVerdict,
+    QueryLogRepository, QueryMetricsPort, QueryPolicyEnginePort, RecordTypeFilterPort,
+    ResponseIpFilterStore, SafeSearchEnginePort, SecondaryZonePort, SlowQueryEntry,
+    SlowQueryLogPort, SplitHorizonPort, TunnelingFlagStore, QUERY_SPAN_TARGET,
 };
 use ferrous_dns_domain::{
     BlockSource, DgaDetectionAction, DgaDetectionConfig, DnsQuery, DnsRequest, DomainError,
-    NxdomainHijackAction, NxdomainHijackConfig, QueryLog, QuerySource, RecordType,
-    ResponseIpFilterAction, ResponseIpFilterConfig, TunnelingAction, TunnelingDetectionConfig,
+    NxdomainHijackAction, NxdomainHijackConfig, PolicyAction, QueryLog, QuerySource, RecordType,
+    ResponseIpFilterAction, ResponseIpFilterConfig, RewriteTarget, TunnelingAction,
+    TunnelingDetectionConfig,
 };
 use lru::LruCache;
 use std::cell::RefCell;
@@ -23,22 +28,42 @@ use std::net::IpAddr;
 use std::num::NonZeroUsize;
 use std::sync::Arc;
 use std::time::Duration;
+use tracing::field::Empty;
+use tracing::Instrument;
 
 const LAST_SEEN_CAPACITY: usize = 8_192;
+const DEFAULT_MAX_CNAME_CHAIN: usize = 8;
 
 thread_local! {
     static LAST_SEEN_TRACKER: RefCell<LruCache<IpAddr, u64>> =
         RefCell::new(LruCache::new(NonZeroUsize::new(LAST_SEEN_CAPACITY).unwrap()));
 }
 
+/// Whether `ip` can be an answer to a query of `record_type`.
+fn answers_type(ip: &IpAddr, record_type: RecordType) -> bool {
+    match record_type {
+        RecordType::A => ip.is_ipv4(),
+        RecordType::AAAA => ip.is_ipv6(),
+        _ => false,
+    }
+}
+
 pub struct HandleDnsQueryUseCase {
     resolver: Arc<dyn DnsResolver>,
     block_filter: Arc<dyn BlockFilterEnginePort>,
     safe_search: Option<Arc<dyn SafeSearchEnginePort>>,
+    query_policy: Option<Arc<dyn QueryPolicyEnginePort>>,
+    dns_rewrites: Option<Arc<dyn DnsRewriteEnginePort>>,
+    split_horizon: Option<Arc<dyn SplitHorizonPort>>,
+    local_zone: Option<Arc<dyn LocalZonePort>>,
+    secondary_zones: Option<Arc<dyn SecondaryZonePort>>,
+    record_type_filter: Option<Arc<dyn RecordTypeFilterPort>>,
+    aaaa_filter: Option<Arc<dyn AaaaFilterPort>>,
+    plugins: Option<Arc<dyn PluginHookPort>>,
     query_log: Arc<dyn QueryLogRepository>,
+    query_metrics: Option<Arc<dyn QueryMetricsPort>>,
     client_repo: Option<Arc<dyn ClientRepository>>,
     client_tracking_interval: Duration,
-    rebinding_guard: RebindingGuard,
     rate_limiter: Arc<DnsRateLimiter>,
     tunneling_guard: TunnelingGuard,
     tunneling_event_tx: Option<tokio::sync::mpsc::Sender<TunnelingAnalysisEvent>>,
@@ -49,6 +74,12 @@ pub struct HandleDnsQueryUseCase {
     dga_event_tx: Option<tokio::sync::mpsc::Sender<DgaAnalysisEvent>>,
     dga_flag_store: Option<Arc<dyn DgaFlagStore>>,
     cookie_guard: DnsCookieGuard,
+    slow_query_log: Option<Arc<dyn SlowQueryLogPort>>,
+    slow_query_threshold_us: u64,
+    query_budget: Option<QueryBudget>,
+    group_cache_partitions: bool,
+    max_cname_chain: usize,
+    minimal_any_responses: bool,
 }
 
 impl HandleDnsQueryUseCase {
@@ -61,10 +92,18 @@ impl HandleDnsQueryUseCase {
             resolver,
             block_filter,
             safe_search: None,
+            query_policy: None,
+            dns_rewrites: None,
+            split_horizon: None,
+            local_zone: None,
+            secondary_zones: None,
+            record_type_filter: None,
+            aaaa_filter: None,
+            plugins: None,
             query_log,
+            query_metrics: None,
             client_repo: None,
             client_tracking_interval: Duration::from_secs(60),
-            rebinding_guard: RebindingGuard::disabled(),
             rate_limiter: Arc::new(DnsRateLimiter::disabled()),
             tunneling_guard: TunnelingGuard::disabled(),
             tunneling_event_tx: None,
@@ -75,6 +114,12 @@ impl HandleDnsQueryUseCase {
             dga_event_tx: None,
             dga_flag_store: None,
             cookie_guard: DnsCookieGuard::disabled(),
+            slow_query_log: None,
+            slow_query_threshold_us: 0,
+            query_budget: None,
+            group_cache_partitions: false,
+            max_cname_chain: DEFAULT_MAX_CNAME_CHAIN,
+            minimal_any_responses: false,
         }
     }
 
@@ -83,6 +128,49 @@ impl HandleDnsQueryUseCase {
         self
     }
 
+    pub fn with_query_policy(mut self, query_policy: Arc<dyn QueryPolicyEnginePort>) -> Self {
+        self.query_policy = Some(query_policy);
+        self
+    }
+
+    pub fn with_dns_rewrites(mut self, dns_rewrites: Arc<dyn DnsRewriteEnginePort>) -> Self {
+        self.dns_rewrites = Some(dns_rewrites);
+        self
+    }
+
+    pub fn with_split_horizon(mut self, split_horizon: Arc<dyn SplitHorizonPort>) -> Self {
+        self.split_horizon = Some(split_horizon);
+        self
+    }
+
+    pub fn with_local_zone(mut self, local_zone: Arc<dyn LocalZonePort>) -> Self {
+        self.local_zone = Some(local_zone);
+        self
+    }
+
+    pub fn with_secondary_zones(mut self, secondary_zones: Arc<dyn SecondaryZonePort>) -> Self {
+        self.secondary_zones = Some(secondary_zones);
+        self
+    }
+
+    pub fn with_record_type_filter(mut self, filter: Arc<dyn RecordTypeFilterPort>) -> Self {
+        self.record_type_filter = Some(filter);
+        self
+    }
+
+    /// Answers AAAA queries with NODATA for groups flagged with `filter_aaaa`.
+    pub fn with_aaaa_filter(mut self, filter: Arc<dyn AaaaFilterPort>) -> Self {
+        self.aaaa_filter = Some(filter);
+        self
+    }
+
+    /// Runs plugin scripts before the block filter and after resolution.
+    /// Queries a script covers always take the full `execute` path.
+    pub fn with_plugins(mut self, plugins: Arc<dyn PluginHookPort>) -> Self {
+        self.plugins = Some(plugins);
+        self
+    }
+
     pub fn with_client_tracking(
         mut self,
         client_repo: Arc<dyn ClientRepository>,
@@ -93,19 +181,6 @@ impl HandleDnsQueryUseCase {
         self
     }
 
-    /// Enables DNS rebinding protection. Resolving a public domain to a private IP
-    /// is blocked unless the domain matches `local_domain` suffix, is served by the
-    /// local DNS server, or is explicitly listed in `allowlist`.
-    pub fn with_rebinding_protection(
-        mut self,
-        enabled: bool,
-        local_domain: Option<&str>,
-        allowlist: &[String],
-    ) -> Self {
-        self.rebinding_guard = RebindingGuard::new(enabled, local_domain, allowlist);
-        self
-    }
-
     /// Injects the DNS rate limiter for per-subnet query throttling.
     pub fn with_rate_limiter(mut self, rate_limiter: Arc<DnsRateLimiter>) -> Self {
         self.rate_limiter = rate_limiter;
@@ -180,12 +255,73 @@ impl HandleDnsQueryUseCase {
         self
     }
 
+    /// Captures queries slower than `threshold` with a per-phase breakdown.
+    pub fn with_slow_query_log(
+        mut self,
+        slow_query_log: Arc<dyn SlowQueryLogPort>,
+        threshold: Duration,
+    ) -> Self {
+        self.slow_query_log = Some(slow_query_log);
+        self.slow_query_threshold_us = threshold.as_micros() as u64;
+        self
+    }
+
+    /// Counts every answered query in `metrics`, ahead of query log sampling.
+    pub fn with_query_metrics(mut self, metrics: Arc<dyn QueryMetricsPort>) -> Self {
+        self.query_metrics = Some(metrics);
+        self
+    }
+
+    /// Bounds each query by `budget`; resolver layers read it through
+    /// [`query_budget`].
+    pub fn with_query_budget(mut self, budget: Option<QueryBudget>) -> Self {
+        self.query_budget = budget;
+        self
+    }
+
+    /// Keeps cache entries of clients outside the default group apart from
+    /// everyone else's, so one group's answers are never served to another.
+    pub fn with_group_cache_partitions(mut self, enabled: bool) -> Self {
+        self.group_cache_partitions = enabled;
+        self
+    }
+
+    /// Fails answers that chain through more than `max` CNAME records.
+    pub fn with_max_cname_chain(mut self, max: usize) -> Self {
+        self.max_cname_chain = max;
+        self
+    }
+
+    /// Answers ANY queries with [`DomainError::AnyQueryMinimized`] instead
+    /// of resolving them, leaving the minimal RFC 8482 response to the server.
+    pub fn with_minimal_any_responses(mut self, enabled: bool) -> Self {
+        self.minimal_any_responses = enabled;
+        self
+    }
+
     /// Exposes the cookie guard so the server handler can generate server
     /// cookies for inclusion in responses.
     pub fn cookie_guard(&self) -> &DnsCookieGuard {
         &self.cookie_guard
     }
 
+    /// Rejects `resolution` when its CNAME chain, plus the `followed` aliases
+    /// already taken before resolving, exceeds the configured limit.
+    fn limit_cname_chain(
+        &self,
+        resolution: DnsResolution,
+        followed: usize,
+    ) -> Result<DnsResolution, DomainError> {
+        let hops = resolution.cname_chain.len() + followed;
+        if hops > self.max_cname_chain {
+            return Err(DomainError::InvalidDnsResponse(format!(
+                "CNAME chain of {hops} exceeds the limit of {}",
+                self.max_cname_chain
+            )));
+        }
+        Ok(resolution)
+    }
+
     /// Applies the configured tunneling action, returning an error if blocked.
     fn apply_tunneling_action(
         &self,
@@ -276,11 +412,46 @@ impl HandleDnsQueryUseCase {
     }
 
     fn log(&self, query_log: &QueryLog) {
+        self.capture_slow_query(query_log);
+        if let Some(metrics) = &self.query_metrics {
+            metrics.record(query_log);
+        }
+        if tracing::enabled!(target: QUERY_SPAN_TARGET, tracing::Level::TRACE) {
+            let span = tracing::Span::current();
+            span.record("status", query_log.response_status.unwrap_or("NOERROR"));
+            span.record("blocked", query_log.blocked);
+            span.record("cache_hit", query_log.cache_hit);
+            if let Some(upstream) = query_log.upstream_server.as_deref() {
+                span.record("upstream", upstream);
+            }
+        }
         if let Err(e) = self.query_log.log_query_sync(query_log) {
             tracing::warn!(error = %e, domain = %query_log.domain, "Failed to log query");
         }
     }
 
+    fn capture_slow_query(&self, query_log: &QueryLog) {
+        let Some(slow_query_log) = &self.slow_query_log else {
+            return;
+        };
+        let total_us = query_log.response_time_us.unwrap_or(0);
+        if total_us < self.slow_query_threshold_us {
+            return;
+        }
+        slow_query_log.record(SlowQueryEntry {
+            domain: Arc::clone(&query_log.domain),
+            record_type: query_log.record_type,
+            client_ip: query_log.client_ip,
+            response_status: query_log.response_status,
+            cache_hit: query_log.cache_hit,
+            upstream_server: query_log.upstream_server.clone(),
+            upstream_pool: query_log.upstream_pool.clone(),
+            phases: phase_timings::current().unwrap_or_default(),
+            total_us,
+            timestamp: chrono::Utc::now(),
+        });
+    }
+
     fn base_query_log(request: &DnsRequest, response_time_us: u64, group_id: i64) -> QueryLog {
         QueryLog {
             id: None,
@@ -288,6 +459,7 @@ impl HandleDnsQueryUseCase {
             record_type: request.record_type,
             client_ip: request.client_ip,
             client_hostname: None,
+            client_mac: None,
             blocked: false,
             response_time_us: Some(response_time_us),
             cache_hit: false,
@@ -295,15 +467,55 @@ impl HandleDnsQueryUseCase {
             dnssec_status: None,
             upstream_server: None,
             upstream_pool: None,
+            upstream_strategy: None,
+            upstream_attempt: None,
+            upstream_protocol: None,
             response_status: Some("NOERROR"),
             timestamp: None,
             query_source: QuerySource::Client,
             group_id: Some(group_id),
+            group_name: None,
             block_source: None,
+            plugin: None,
+        }
+    }
+
+    fn check_block_filter(&self, domain: &str, group_id: i64) -> FilterDecision {
+        let _span = tracing::trace_span!(target: QUERY_SPAN_TARGET, "filter").entered();
+        let start = tsc_timer::now();
+        let decision = self.block_filter.check(domain, group_id);
+        phase_timings::record(QueryPhase::BlockCheck, tsc_timer::elapsed_us_since(start));
+        decision
+    }
+
+    fn try_cache(&self, query: &DnsQuery) -> Option<DnsResolution> {
+        let start = tsc_timer::now();
+        let cached = self.resolver.try_cache(query);
+        phase_timings::record(QueryPhase::Cache, tsc_timer::elapsed_us_since(start));
+        cached
+    }
+
+    /// Cache partition for `group_id`: the default group shares partition 0
+    /// with internal lookups, every other group gets its own.
+    #[inline]
+    fn cache_partition(&self, group_id: i64) -> u32 {
+        if !self.group_cache_partitions || self.block_filter.default_group_id() == Some(group_id) {
+            return 0;
+        }
+        group_id as u32
+    }
+
+    #[inline]
+    fn resolve_group(&self, client_ip: IpAddr, listener_group: Option<i64>) -> i64 {
+        match listener_group {
+            Some(default_group_id) => self
+                .block_filter
+                .resolve_group_with_default(client_ip, default_group_id),
+            None => self.block_filter.resolve_group(client_ip),
         }
     }
 
-    fn maybe_track_client(&self, client_ip: IpAddr) {
+    fn maybe_track_client(&self, client_ip: IpAddr, listener_group: Option<i64>) {
         let Some(client_repo) = &self.client_repo else {
             return;
         };
@@ -322,15 +534,173 @@ impl HandleDnsQueryUseCase {
         });
 
         if needs_update {
+            // For an unknown IP this is the matching client subnet's group (or
+            // the listener's or global default), which becomes the new
+            // client row's group.
+            let group_id = self.resolve_group(client_ip, listener_group);
             let client_repo = Arc::clone(client_repo);
             tokio::spawn(async move {
-                if let Err(e) = client_repo.update_last_seen(client_ip).await {
+                if let Err(e) = client_repo
+                    .update_last_seen_in_group(client_ip, group_id)
+                    .await
+                {
                     tracing::warn!(error = %e, ip = %client_ip, "Failed to track client");
                 }
             });
         }
     }
 
+    #[inline]
+    fn is_type_blocked(&self, group_id: i64, record_type: RecordType) -> bool {
+        self.record_type_filter
+            .as_deref()
+            .is_some_and(|filter| filter.is_blocked(group_id, record_type))
+    }
+
+    #[inline]
+    fn is_aaaa_filtered(&self, group_id: i64, record_type: RecordType) -> bool {
+        record_type == RecordType::AAAA
+            && self
+                .aaaa_filter
+                .as_deref()
+                .is_some_and(|filter| filter.filters_aaaa(group_id))
+    }
+
+    #[inline]
+    fn has_policy_match(
+        &self,
+        client_ip: IpAddr,
+        group_id: i64,
+        domain: &str,
+        record_type: RecordType,
+    ) -> bool {
+        self.query_policy.as_deref().is_some_and(|engine| {
+            engine
+                .evaluate(client_ip, group_id, domain, record_type)
+                .is_some()
+        })
+    }
+
+    #[inline]
+    fn has_rewrite(&self, domain: &str) -> bool {
+        self.dns_rewrites
+            .as_deref()
+            .is_some_and(|engine| engine.lookup(domain).is_some())
+    }
+
+    #[inline]
+    fn has_view_answer(
+        &self,
+        client_ip: IpAddr,
+        group_id: i64,
+        domain: &str,
+        record_type: RecordType,
+    ) -> bool {
+        self.split_horizon.as_deref().is_some_and(|views| {
+            views
+                .lookup(client_ip, group_id, domain, record_type)
+                .is_some()
+        })
+    }
+
+    #[inline]
+    fn has_local_record(&self, group_id: i64, domain: &str, record_type: RecordType) -> bool {
+        self.local_zone.as_deref().is_some_and(|zone| {
+            zone.lookup_for_tenant(
+                self.block_filter.tenant_of_group(group_id),
+                domain,
+                record_type,
+            )
+            .is_some()
+        })
+    }
+
+    #[inline]
+    fn serves_secondary_zone(&self, domain: &str) -> bool {
+        self.secondary_zones
+            .as_deref()
+            .is_some_and(|zones| zones.serves(domain))
+    }
+
+    /// Applies a plugin decision, returning the final result for the query.
+    fn apply_plugin_decision(
+        &self,
+        decision: PluginDecision,
+        request: &DnsRequest,
+        elapsed_us: u64,
+        group_id: i64,
+    ) -> Result<DnsResolution, DomainError> {
+        let plugin = Some(decision.plugin);
+        match decision.verdict {
+            PluginVerdict::Block => {
+                self.log(&QueryLog {
+                    blocked: true,
+                    response_status: Some("BLOCKED"),
+                    block_source: Some(BlockSource::Plugin),
+                    plugin,
+                    ..Self::base_query_log(request, elapsed_us, group_id)
+                });
+                Err(DomainError::Blocked(BlockSource::Plugin))
+            }
+            PluginVerdict::NxDomain => {
+                self.log(&QueryLog {
+                    response_status: Some("NXDOMAIN"),
+                    plugin,
+                    ..Self::base_query_log(request, elapsed_us, group_id)
+                });
+                Err(DomainError::NxDomain)
+            }
+            PluginVerdict::Answer(addresses) => {
+                let addresses = addresses
+                    .into_iter()
+                    .filter(|ip| answers_type(ip, request.record_type))
+                    .collect();
+                self.log(&QueryLog {
+                    response_status: Some("REWRITTEN"),
+                    plugin,
+                    ..Self::base_query_log(request, elapsed_us, group_id)
+                });
+                Ok(DnsResolution {
+                    local_dns: true,
+                    ..DnsResolution::new(addresses, false)
+                })
+            }
+        }
+    }
+
+    /// Plugin host, if one of its scripts covers the requested domain.
+    fn plugins_for(&self, domain: &str) -> Option<&dyn PluginHookPort> {
+        self.plugins
+            .as_deref()
+            .filter(|plugins| plugins.intercepts(domain))
+    }
+
+    fn plugin_query(request: &DnsRequest, group_id: i64) -> PluginQuery {
+        PluginQuery {
+            domain: Arc::clone(&request.domain),
+            record_type: request.record_type,
+            client_ip: request.client_ip,
+            group_id,
+        }
+    }
+
+    async fn run_post_resolve_hook(
+        &self,
+        request: &DnsRequest,
+        resolution: &DnsResolution,
+        elapsed_us: impl FnOnce() -> u64,
+        group_id: i64,
+    ) -> Option<Result<DnsResolution, DomainError>> {
+        let plugins = self.plugins_for(&request.domain)?;
+        let decision = plugins
+            .post_resolve(
+                &Self::plugin_query(request, group_id),
+                &resolution.addresses,
+            )
+            .await?;
+        Some(self.apply_plugin_decision(decision, request, elapsed_us(), group_id))
+    }
+
     fn blocked_cname(&self, cname_chain: &[Arc<str>], group_id: i64) -> Option<BlockSource> {
         cname_chain
             .iter()
@@ -351,14 +721,43 @@ impl HandleDnsQueryUseCase {
         domain: &str,
         record_type: RecordType,
         client_ip: IpAddr,
+        listener_group: Option<i64>,
     ) -> Option<(bytes::Bytes, u32)> {
+        if self.plugins_for(domain).is_some() {
+            return None; // fall through to execute() to run the plugin hooks
+        }
+
         let tsc_start = tsc_timer::now();
-        let group_id = self.block_filter.resolve_group(client_ip);
+        let group_id = self.resolve_group(client_ip, listener_group);
 
-        if let FilterDecision::Block(_) = self.block_filter.check(domain, group_id) {
+        if self.is_type_blocked(group_id, record_type) {
             return None;
         }
 
+        if self.has_policy_match(client_ip, group_id, domain, record_type) {
+            return None; // fall through to execute() to apply the policy
+        }
+
+        if self.has_rewrite(domain) {
+            return None; // fall through to execute() to apply the rewrite
+        }
+
+        if self.has_view_answer(client_ip, group_id, domain, record_type) {
+            return None; // fall through to execute() to answer from the view
+        }
+
+        if self.has_local_record(group_id, domain, record_type) {
+            return None; // fall through to execute() to answer from the local zone
+        }
+
+        if self.serves_secondary_zone(domain) {
+            return None; // fall through to execute() to answer from the secondary zone
+        }
+
+        if self.block_filter.peek(domain, group_id) != FilterDecision::Allow {
+            return None; // fall through to execute() to block or log the audited match
+        }
+
         if !self.rate_limiter.is_allowed(client_ip) {
             return None;
         }
@@ -375,7 +774,11 @@ impl HandleDnsQueryUseCase {
             }
         }
 
-        let resolution = self.resolver.try_cache_str(domain, record_type)?;
+        let resolution = self.resolver.try_cache_partitioned(
+            domain,
+            record_type,
+            self.cache_partition(group_id),
+        )?;
         let wire = resolution.upstream_wire_data?;
         let ttl = resolution.min_ttl.unwrap_or(0);
 
@@ -387,6 +790,7 @@ impl HandleDnsQueryUseCase {
             record_type,
             client_ip,
             client_hostname: None,
+            client_mac: None,
             blocked: false,
             response_time_us: Some(elapsed_us),
             cache_hit: true,
@@ -394,11 +798,16 @@ impl HandleDnsQueryUseCase {
             dnssec_status: resolution.dnssec_status,
             upstream_server: None,
             upstream_pool: None,
+            upstream_strategy: None,
+            upstream_attempt: None,
+            upstream_protocol: None,
             response_status: Some("NOERROR"),
             timestamp: None,
             query_source: QuerySource::Client,
             group_id: Some(group_id),
+            group_name: None,
             block_source: None,
+            plugin: None,
         });
 
         Some((wire, ttl))
@@ -409,14 +818,47 @@ impl HandleDnsQueryUseCase {
         domain: &str,
         record_type: RecordType,
         client_ip: IpAddr,
+        listener_group: Option<i64>,
     ) -> Option<(Arc<Vec<IpAddr>>, u32)> {
+        if self.plugins_for(domain).is_some() {
+            return None; // fall through to execute() to run the plugin hooks
+        }
+
         let tsc_start = tsc_timer::now();
-        let group_id = self.block_filter.resolve_group(client_ip);
+        let group_id = self.resolve_group(client_ip, listener_group);
 
-        if let FilterDecision::Block(_) = self.block_filter.check(domain, group_id) {
+        if self.is_type_blocked(group_id, record_type) {
             return None;
         }
 
+        if self.is_aaaa_filtered(group_id, record_type) {
+            return None; // fall through to execute() to answer NODATA
+        }
+
+        if self.has_policy_match(client_ip, group_id, domain, record_type) {
+            return None; // fall through to execute() to apply the policy
+        }
+
+        if self.has_rewrite(domain) {
+            return None; // fall through to execute() to apply the rewrite
+        }
+
+        if self.has_view_answer(client_ip, group_id, domain, record_type) {
+            return None; // fall through to execute() to answer from the view
+        }
+
+        if self.has_local_record(group_id, domain, record_type) {
+            return None; // fall through to execute() to answer from the local zone
+        }
+
+        if self.serves_secondary_zone(domain) {
+            return None; // fall through to execute() to answer from the secondary zone
+        }
+
+        if self.block_filter.peek(domain, group_id) != FilterDecision::Allow {
+            return None; // fall through to execute() to block or log the audited match
+        }
+
         if !self.rate_limiter.is_allowed(client_ip) {
             return None;
         }
@@ -433,7 +875,11 @@ impl HandleDnsQueryUseCase {
             }
         }
 
-        let resolution = self.resolver.try_cache_str(domain, record_type)?;
+        let resolution = self.resolver.try_cache_partitioned(
+            domain,
+            record_type,
+            self.cache_partition(group_id),
+        )?;
         if resolution.addresses.is_empty() {
             return None;
         }
@@ -452,6 +898,7 @@ impl HandleDnsQueryUseCase {
             record_type,
             client_ip,
             client_hostname: None,
+            client_mac: None,
             blocked: false,
             response_time_us: Some(elapsed_us),
             cache_hit: true,
@@ -459,23 +906,52 @@ impl HandleDnsQueryUseCase {
             dnssec_status: resolution.dnssec_status,
             upstream_server: None,
             upstream_pool: None,
+            upstream_strategy: None,
+            upstream_attempt: None,
+            upstream_protocol: None,
             response_status: Some("NOERROR"),
             timestamp: None,
             query_source: QuerySource::Client,
             group_id: Some(group_id),
+            group_name: None,
             block_source: None,
+            plugin: None,
         });
 
         Some((resolution.addresses, resolution.min_ttl.unwrap_or(60)))
     }
 
     pub async fn execute(&self, request: &DnsRequest) -> Result<DnsResolution, DomainError> {
+        let span = tracing::trace_span!(
+            target: QUERY_SPAN_TARGET,
+            "dns_query",
+            domain = %request.domain,
+            record_type = %request.record_type,
+            client = %request.client_ip,
+            status = Empty,
+            blocked = Empty,
+            cache_hit = Empty,
+            upstream = Empty,
+        );
+        let handle = self.handle(request).instrument(span);
+        match (self.query_budget, self.slow_query_log.is_some()) {
+            (Some(budget), true) => {
+                phase_timings::collect(query_budget::scope(budget, handle)).await
+            }
+            (Some(budget), false) => query_budget::scope(budget, handle).await,
+            (None, true) => phase_timings::collect(handle).await,
+            (None, false) => handle.await,
+        }
+    }
+
+    async fn handle(&self, request: &DnsRequest) -> Result<DnsResolution, DomainError> {
         let tsc_start = tsc_timer::now();
         let elapsed_us = || tsc_timer::elapsed_us_since(tsc_start);
 
-        self.maybe_track_client(request.client_ip);
+        self.maybe_track_client(request.client_ip, request.listener_group_id);
 
-        let group_id = self.block_filter.resolve_group(request.client_ip);
+        let group_id = self.resolve_group(request.client_ip, request.listener_group_id);
+        let partition = self.cache_partition(group_id);
 
         match self.rate_limiter.check(request.client_ip, false) {
             RateLimitDecision::Allow => {}
@@ -524,6 +1000,95 @@ impl HandleDnsQueryUseCase {
             }
         }
 
+        if self.minimal_any_responses && request.record_type == RecordType::ANY {
+            self.log(&QueryLog {
+                response_status: Some("ANY_MINIMIZED"),
+                ..Self::base_query_log(request, elapsed_us(), group_id)
+            });
+            return Err(DomainError::AnyQueryMinimized);
+        }
+
+        if let Some(answer) = self.split_horizon.as_deref().and_then(|views| {
+            views.lookup(
+                request.client_ip,
+                group_id,
+                &request.domain,
+                request.record_type,
+            )
+        }) {
+            tracing::debug!(
+                domain = %request.domain,
+                client = %request.client_ip,
+                view = %answer.view,
+                "Answered from split-horizon view"
+            );
+            self.log(&QueryLog {
+                response_status: Some("LOCAL_DNS"),
+                ..Self::base_query_log(request, elapsed_us(), group_id)
+            });
+            return Ok(DnsResolution {
+                local_dns: true,
+                min_ttl: Some(answer.ttl),
+                ..DnsResolution::new(answer.addresses, false)
+            });
+        }
+
+        let tenant_id = self.block_filter.tenant_of_group(group_id);
+        if let Some((zone, answer)) = self.local_zone.as_deref().and_then(|zone| {
+            zone.lookup_for_tenant(tenant_id, &request.domain, request.record_type)
+                .map(|answer| (zone, answer))
+        }) {
+            let resolution = match answer {
+                LocalZoneAnswer::Records(resolution) => resolution,
+                LocalZoneAnswer::Cname(target) => {
+                    // An alias to another local name is answered from the
+                    // zone; anything else goes through the resolver.
+                    match zone.lookup_for_tenant(tenant_id, &target, request.record_type) {
+                        Some(LocalZoneAnswer::Records(resolution)) => resolution,
+                        _ => {
+                            let aliased = DnsQuery::new(target, request.record_type)
+                                .with_cache_partition(partition);
+                            let resolution = self.resolver.resolve(&aliased).await?;
+                            self.limit_cname_chain(resolution, 1)?
+                        }
+                    }
+                }
+            };
+            tracing::debug!(
+                domain = %request.domain,
+                record_type = %request.record_type,
+                "Answered from local zone"
+            );
+            self.log(&QueryLog {
+                cache_hit: resolution.cache_hit,
+                upstream_server: resolution.upstream_server.clone(),
+                upstream_pool: resolution.upstream_pool.clone(),
+                upstream_strategy: resolution.upstream_strategy,
+                upstream_attempt: resolution.upstream_attempt,
+                upstream_protocol: resolution.upstream_protocol,
+                response_status: Some("LOCAL_DNS"),
+                ..Self::base_query_log(request, elapsed_us(), group_id)
+            });
+            return Ok(resolution);
+        }
+
+        if let Some(resolution) = self
+            .secondary_zones
+            .as_deref()
+            .and_then(|zones| zones.lookup(&request.domain, request.record_type))
+        {
+            tracing::debug!(
+                domain = %request.domain,
+                record_type = %request.record_type,
+                "Answered from secondary zone"
+            );
+            self.log(&QueryLog {
+                response_status: Some("LOCAL_DNS"),
+                ..Self::base_query_log(request, elapsed_us(), group_id)
+            });
+            return Ok(resolution);
+        }
+
         if let TunnelingVerdict::Detected {
             signal,
             measured,
@@ -572,48 +1137,229 @@ impl HandleDnsQueryUseCase {
             }
         }
 
-        let dns_query = DnsQuery::new(Arc::clone(&request.domain), request.record_type);
+        if self.is_type_blocked(group_id, request.record_type) {
+            self.log(&QueryLog {
+                blocked: true,
+                response_status: Some("TYPE_BLOCKED"),
+                block_source: Some(BlockSource::RecordTypeFilter),
+                ..Self::base_query_log(request, elapsed_us(), group_id)
+            });
+            return Err(DomainError::FilteredQuery(format!(
+                "{} queries are not allowed for this client group",
+                request.record_type
+            )));
+        }
+
+        if self.is_aaaa_filtered(group_id, request.record_type) {
+            self.log(&QueryLog {
+                response_status: Some("AAAA_FILTERED"),
+                ..Self::base_query_log(request, elapsed_us(), group_id)
+            });
+            return Ok(DnsResolution {
+                local_dns: true,
+                ..DnsResolution::new(vec![], false)
+            });
+        }
 
-        if let FilterDecision::Block(block_source) =
-            self.block_filter.check(&request.domain, group_id)
+        if let Some(plugins) = self.plugins_for(&request.domain) {
+            if let Some(decision) = plugins
+                .pre_filter(&Self::plugin_query(request, group_id))
+                .await
+            {
+                return self.apply_plugin_decision(decision, request, elapsed_us(), group_id);
+            }
+        }
+
+        let dns_query = DnsQuery::new(Arc::clone(&request.domain), request.record_type)
+            .with_cache_partition(partition);
+
+        let policy = self.query_policy.as_deref().and_then(|engine| {
+            engine.evaluate(
+                request.client_ip,
+                group_id,
+                &request.domain,
+                request.record_type,
+            )
+        });
+        let mut skip_blocking = false;
+        let mut forward_pool: Option<Arc<str>> = None;
+        if let Some(policy) = policy {
+            match policy.action {
+                PolicyAction::Allow => skip_blocking = true,
+                PolicyAction::Block => {
+                    self.log(&QueryLog {
+                        blocked: true,
+                        response_status: Some("BLOCKED"),
+                        block_source: Some(BlockSource::QueryPolicy),
+                        ..Self::base_query_log(request, elapsed_us(), group_id)
+                    });
+                    return Err(DomainError::Blocked(BlockSource::QueryPolicy));
+                }
+                PolicyAction::Rewrite => {
+                    if let Some(target) = policy.target {
+                        // The rewritten name is still subject to the group's
+                        // blocklists; only `Allow` bypasses them.
+                        let mut audited = None;
+                        match self.check_block_filter(&target, group_id) {
+                            FilterDecision::Block(block_source) => {
+                                self.log(&QueryLog {
+                                    blocked: true,
+                                    response_status: Some("BLOCKED"),
+                                    block_source: Some(block_source),
+                                    ..Self::base_query_log(request, elapsed_us(), group_id)
+                                });
+                                return Err(DomainError::Blocked(block_source));
+                            }
+                            FilterDecision::Audit(block_source) => audited = Some(block_source),
+                            FilterDecision::Allow => {}
+                        }
+                        let rewritten = DnsQuery::new(target, request.record_type)
+                            .with_cache_partition(partition);
+                        let resolution = self.resolver.resolve(&rewritten).await?;
+                        let resolution = self.limit_cname_chain(resolution, 1)?;
+                        if let Some(block_source) =
+                            self.blocked_cname(&resolution.cname_chain, group_id)
+                        {
+                            self.log(&QueryLog {
+                                blocked: true,
+                                response_status: Some("BLOCKED"),
+                                block_source: Some(block_source),
+                                ..Self::base_query_log(request, elapsed_us(), group_id)
+                            });
+                            return Err(DomainError::Blocked(block_source));
+                        }
+                        self.log(&QueryLog {
+                            cache_hit: resolution.cache_hit,
+                            upstream_server: resolution.upstream_server.clone(),
+                            upstream_pool: resolution.upstream_pool.clone(),
+                            upstream_strategy: resolution.upstream_strategy,
+                            upstream_attempt: resolution.upstream_attempt,
+                            upstream_protocol: resolution.upstream_protocol,
+                            response_status: Some("POLICY_REWRITE"),
+                            block_source: audited,
+                            ..Self::base_query_log(request, elapsed_us(), group_id)
+                        });
+                        return Ok(resolution);
+                    }
+                }
+                PolicyAction::ForwardToPool => forward_pool = policy.target,
+                PolicyAction::LogOnly => {
+                    tracing::info!(
+                        policy_id = policy.policy_id,
+                        domain = %request.domain,
+                        client = %request.client_ip,
+                        "Query policy matched (log only)"
+                    );
+                }
+            }
+        }
+
+        if let Some(rewrite) = self
+            .dns_rewrites
+            .as_deref()
+            .and_then(|engine| engine.lookup(&request.domain))
         {
+            let resolution = match rewrite {
+                RewriteTarget::Addresses(addresses) => {
+                    let addresses = addresses
+                        .iter()
+                        .copied()
+                        .filter(|ip| answers_type(ip, request.record_type))
+                        .collect();
+                    DnsResolution {
+                        local_dns: true,
+                        ..DnsResolution::new(addresses, false)
+                    }
+                }
+                RewriteTarget::Cname(target) => {
+                    let rewritten =
+                        DnsQuery::new(target, request.record_type).with_cache_partition(partition);
+                    let resolution = self.resolver.resolve(&rewritten).await?;
+                    self.limit_cname_chain(resolution, 1)?
+                }
+            };
             self.log(&QueryLog {
-                blocked: true,
-                response_status: Some("BLOCKED"),
-                block_source: Some(block_source),
+                cache_hit: resolution.cache_hit,
+                upstream_server: resolution.upstream_server.clone(),
+                upstream_pool: resolution.upstream_pool.clone(),
+                upstream_strategy: resolution.upstream_strategy,
+                upstream_attempt: resolution.upstream_attempt,
+                upstream_protocol: resolution.upstream_protocol,
+                response_status: Some("REWRITTEN"),
                 ..Self::base_query_log(request, elapsed_us(), group_id)
             });
-            return Err(DomainError::Blocked);
+            return Ok(resolution);
         }
 
+        let mut audited = None;
+        if skip_blocking {
+            // Allowed by policy: neither the block filter nor CNAME cloaking
+            // checks apply to this query.
+        } else {
+            match self.check_block_filter(&request.domain, group_id) {
+                FilterDecision::Block(block_source) => {
+                    self.log(&QueryLog {
+                        blocked: true,
+                        response_status: Some("BLOCKED"),
+                        block_source: Some(block_source),
+                        ..Self::base_query_log(request, elapsed_us(), group_id)
+                    });
+                    return Err(DomainError::Blocked(block_source));
+                }
+                FilterDecision::Audit(block_source) => audited = Some(block_source),
+                FilterDecision::Allow => {}
+            }
+        }
+        // A match in audit mode is answered normally and logged as allowed,
+        // with the source that would have blocked it.
+        let base_log = |response_time_us| QueryLog {
+            block_source: audited,
+            ..Self::base_query_log(request, response_time_us, group_id)
+        };
+
         if let Some(cname_target) = self
             .safe_search
             .as_deref()
             .and_then(|ss| ss.cname_for(&request.domain, group_id))
         {
-            let safe_query = DnsQuery::new(Arc::from(cname_target), request.record_type);
+            let safe_query = DnsQuery::new(Arc::from(cname_target), request.record_type)
+                .with_cache_partition(partition);
             let resolution = self.resolver.resolve(&safe_query).await?;
+            let resolution = self.limit_cname_chain(resolution, 1)?;
             self.log(&QueryLog {
                 cache_hit: resolution.cache_hit,
                 upstream_server: resolution.upstream_server.clone(),
                 upstream_pool: resolution.upstream_pool.clone(),
+                upstream_strategy: resolution.upstream_strategy,
+                upstream_attempt: resolution.upstream_attempt,
+                upstream_protocol: resolution.upstream_protocol,
                 response_status: Some("SAFE_SEARCH"),
-                ..Self::base_query_log(request, elapsed_us(), group_id)
+                ..base_log(elapsed_us())
             });
             return Ok(resolution);
         }
 
-        if let Some(cached) = self.resolver.try_cache(&dns_query) {
+        if let Some(cached) = forward_pool
+            .is_none()
+            .then(|| self.try_cache(&dns_query))
+            .flatten()
+        {
             if cached.has_response_data() {
                 if self.nxdomain_hijack_guard.is_hijacked_response(&cached)
                     || self.response_ip_filter_guard.has_blocked_ip(&cached)
+                    || cached.cname_chain.len() > self.max_cname_chain
                 {
                     // Fall through to full resolve path for logging and action.
+                } else if let Some(result) = self
+                    .run_post_resolve_hook(request, &cached, &elapsed_us, group_id)
+                    .await
+                {
+                    return result;
                 } else {
                     self.log(&QueryLog {
                         cache_hit: true,
                         dnssec_status: cached.dnssec_status,
-                        ..Self::base_query_log(request, elapsed_us(), group_id)
+                        ..base_log(elapsed_us())
                     });
                     return Ok(cached);
                 }
@@ -621,15 +1367,26 @@ impl HandleDnsQueryUseCase {
                 self.log(&QueryLog {
                     cache_hit: true,
                     response_status: Some("NXDOMAIN"),
-                    ..Self::base_query_log(request, elapsed_us(), group_id)
+                    ..base_log(elapsed_us())
                 });
                 return Err(DomainError::NxDomain);
             }
         }
 
-        match self.resolver.resolve(&dns_query).await {
+        let result = match forward_pool.as_deref() {
+            Some(pool) => self.resolver.resolve_via_pool(&dns_query, pool).await,
+            None => self.resolver.resolve(&dns_query).await,
+        }
+        .and_then(|resolution| self.limit_cname_chain(resolution, 0));
+
+        match result {
             Ok(resolution) => {
-                if let Some(block_source) = self.blocked_cname(&resolution.cname_chain, group_id) {
+                let cname_block = if skip_blocking {
+                    None
+                } else {
+                    self.blocked_cname(&resolution.cname_chain, group_id)
+                };
+                if let Some(block_source) = cname_block {
                     let ttl = resolution.min_ttl.map(|t| t as u64).unwrap_or(60).max(5);
                     self.block_filter
                         .store_cname_decision(&request.domain, group_id, ttl);
@@ -637,21 +1394,9 @@ impl HandleDnsQueryUseCase {
                         blocked: true,
                         response_status: Some("BLOCKED"),
                         block_source: Some(block_source),
-                        ..Self::base_query_log(request, elapsed_us(), group_id)
+                        ..base_log(elapsed_us())
                     });
-                    return Err(DomainError::Blocked);
-                }
-                if self
-                    .rebinding_guard
-                    .is_rebinding_attempt(&request.domain, &resolution)
-                {
-                    self.log(&QueryLog {
-                        blocked: true,
-                        response_status: Some("BLOCKED"),
-                        block_source: Some(BlockSource::DnsRebinding),
-                        ..Self::base_query_log(request, elapsed_us(), group_id)
-                    });
-                    return Err(DomainError::Blocked);
+                    return Err(DomainError::Blocked(block_source));
                 }
                 if self.nxdomain_hijack_guard.is_hijacked_response(&resolution) {
                     match self.nxdomain_hijack_guard.action() {
@@ -660,7 +1405,7 @@ impl HandleDnsQueryUseCase {
                                 blocked: true,
                                 response_status: Some("NXDOMAIN_HIJACK"),
                                 block_source: Some(BlockSource::NxdomainHijack),
-                                ..Self::base_query_log(request, elapsed_us(), group_id)
+                                ..base_log(elapsed_us())
                             });
                             return Err(DomainError::NxDomain);
                         }
@@ -679,9 +1424,9 @@ impl HandleDnsQueryUseCase {
                                 blocked: true,
                                 response_status: Some("RESPONSE_IP_BLOCKED"),
                                 block_source: Some(BlockSource::ResponseIpFilter),
-                                ..Self::base_query_log(request, elapsed_us(), group_id)
+                                ..base_log(elapsed_us())
                             });
-                            return Err(DomainError::Blocked);
+                            return Err(DomainError::Blocked(BlockSource::ResponseIpFilter));
                         }
                         ResponseIpFilterAction::Alert => {
                             tracing::info!(
@@ -691,6 +1436,12 @@ impl HandleDnsQueryUseCase {
                         }
                     }
                 }
+                if let Some(result) = self
+                    .run_post_resolve_hook(request, &resolution, &elapsed_us, group_id)
+                    .await
+                {
+                    return result;
+                }
                 let response_status = if resolution.local_dns {
                     Some("LOCAL_DNS")
                 } else {
@@ -703,15 +1454,27 @@ impl HandleDnsQueryUseCase {
                     dnssec_status: resolution.dnssec_status,
                     upstream_server: resolution.upstream_server.clone(),
                     upstream_pool: resolution.upstream_pool.clone(),
+                    upstream_strategy: resolution.upstream_strategy,
+                    upstream_attempt: resolution.upstream_attempt,
+                    upstream_protocol: resolution.upstream_protocol,
                     response_status,
-                    ..Self::base_query_log(request, elapsed_us(), group_id)
+                    ..base_log(elapsed_us())
                 });
                 Ok(resolution)
             }
+            Err(DomainError::DnsRebindingBlocked) => {
+                self.log(&QueryLog {
+                    blocked: true,
+                    response_status: Some("REBIND_BLOCKED"),
+                    block_source: Some(BlockSource::DnsRebinding),
+                    ..base_log(elapsed_us())
+                });
+                Err(DomainError::Blocked(BlockSource::DnsRebinding))
+            }
             Err(DomainError::LocalNxDomain) => {
                 self.log(&QueryLog {
                     response_status: Some("LOCAL_DNS"),
-                    ..Self::base_query_log(request, elapsed_us(), group_id)
+                    ..base_log(elapsed_us())
                 });
                 Err(DomainError::NxDomain)
             }
@@ -722,12 +1485,12 @@ impl HandleDnsQueryUseCase {
                         self.emit_dga_event(request);
                         "NXDOMAIN"
                     }
-                    DomainError::QueryTimeout => "TIMEOUT",
-                    _ => "SERVFAIL",
+                    DomainError::QueryTimeout | DomainError::QueryBudgetExceeded => "TIMEOUT",
+                    other => other.rcode().as_str(),
                 };
                 self.log(&QueryLog {
                     response_status: Some(response_status),
-                    ..Self::base_query_log(request, elapsed_us(), group_id)
+                    ..base_log(elapsed_us())
                 });
                 Err(e)
             }
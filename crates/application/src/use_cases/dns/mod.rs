@@ -3,13 +3,15 @@ mod cookie_guard;
 mod dga_guard;
 pub mod handle_dns_query;
 mod nxdomain_hijack_guard;
+pub mod phase_timings;
+pub mod query_budget;
 pub mod rate_limiter;
-mod rebinding_guard;
 mod response_ip_filter_guard;
 pub mod tsc_timer;
 mod tunneling_guard;
 pub use cookie_guard::DnsCookieGuard;
 pub use dga_guard::DgaAnalysisEvent;
+pub(crate) use dga_guard::{extract_apex, shannon_entropy};
 pub use handle_dns_query::HandleDnsQueryUseCase;
 pub use rate_limiter::{DnsRateLimiter, RateLimitDecision};
 pub use tunneling_guard::TunnelingAnalysisEvent;
@@ -74,6 +74,9 @@ mod tests {
             cname_chain: Arc::from([]),
             upstream_server: None,
             upstream_pool: None,
+            upstream_strategy: None,
+            upstream_attempt: None,
+            upstream_protocol: None,
             min_ttl: None,
             negative_soa_ttl: None,
             upstream_wire_data: None,
@@ -0,0 +1,57 @@
+//! Per-query phase timings for the slow-query log.
+//!
+//! The use case opens a task-local scope around a query only when slow-query
+//! capture is enabled; resolver layers add their durations to it. Outside a
+//! scope, recording is a no-op.
+
+use super::tsc_timer;
+use crate::ports::QueryPhaseTimings;
+use std::cell::Cell;
+use std::future::Future;
+
+tokio::task_local! {
+    static PHASE_TIMINGS: Cell<QueryPhaseTimings>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryPhase {
+    BlockCheck,
+    Cache,
+    Upstream,
+    Dnssec,
+}
+
+/// Runs `fut` with an empty timing scope that `record` and `measure` add to.
+pub async fn collect<F: Future>(fut: F) -> F::Output {
+    PHASE_TIMINGS
+        .scope(Cell::new(QueryPhaseTimings::default()), fut)
+        .await
+}
+
+/// Timings gathered so far in the current scope, if any.
+pub fn current() -> Option<QueryPhaseTimings> {
+    PHASE_TIMINGS.try_with(Cell::get).ok()
+}
+
+/// Adds `elapsed_us` to `phase` in the current scope.
+pub fn record(phase: QueryPhase, elapsed_us: u64) {
+    let _ = PHASE_TIMINGS.try_with(|cell| {
+        let mut timings = cell.get();
+        let slot = match phase {
+            QueryPhase::BlockCheck => &mut timings.block_check_us,
+            QueryPhase::Cache => &mut timings.cache_us,
+            QueryPhase::Upstream => &mut timings.upstream_us,
+            QueryPhase::Dnssec => &mut timings.dnssec_us,
+        };
+        *slot += elapsed_us;
+        cell.set(timings);
+    });
+}
+
+/// Awaits `fut` and adds its duration to `phase`.
+pub async fn measure<F: Future>(phase: QueryPhase, fut: F) -> F::Output {
+    let start = tsc_timer::now();
+    let output = fut.await;
+    record(phase, tsc_timer::elapsed_us_since(start));
+    output
+}
@@ -0,0 +1,90 @@
+//! Per-query time budget.
+//!
+//! The use case opens a task-local scope around each client query when
+//! `dns.query_budget` is enabled. Upstream attempts clamp their timeout to
+//! the upstream deadline, and the cache and DNSSEC phases are cut off at
+//! the overall deadline, so a query ends with SERVFAIL before the client
+//! gives up on it. Outside a scope every function is a no-op.
+
+use ferrous_dns_domain::{DomainError, QueryBudgetConfig};
+use std::future::Future;
+use std::time::Duration;
+use tokio::time::Instant;
+
+tokio::task_local! {
+    static DEADLINES: Deadlines;
+}
+
+/// How a query's budget is split across its phases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryBudget {
+    total: Duration,
+    /// Time upstream attempts may use, ending before the DNSSEC share.
+    upstream: Duration,
+    min_attempt: Duration,
+}
+
+impl QueryBudget {
+    /// `None` when the budget is disabled. The DNSSEC share is only held
+    /// back when validation is on.
+    pub fn from_config(config: &QueryBudgetConfig, dnssec_enabled: bool) -> Option<Self> {
+        if !config.is_enabled() {
+            return None;
+        }
+        let dnssec_ms = if dnssec_enabled { config.dnssec_ms } else { 0 };
+        Some(Self {
+            total: Duration::from_millis(config.total_ms),
+            upstream: Duration::from_millis(config.total_ms.saturating_sub(dnssec_ms)),
+            min_attempt: Duration::from_millis(config.min_attempt_ms),
+        })
+    }
+
+    pub fn total(&self) -> Duration {
+        self.total
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Deadlines {
+    total: Instant,
+    upstream: Instant,
+    min_attempt: Duration,
+}
+
+/// Runs `fut` with `budget` starting now.
+pub async fn scope<F: Future>(budget: QueryBudget, fut: F) -> F::Output {
+    let start = Instant::now();
+    let deadlines = Deadlines {
+        total: start + budget.total,
+        upstream: start + budget.upstream,
+        min_attempt: budget.min_attempt,
+    };
+    DEADLINES.scope(deadlines, fut).await
+}
+
+/// Timeout for the next upstream attempt: `timeout_ms`, cut to what is left
+/// of the upstream share. Fails once too little is left to start one.
+pub fn attempt_timeout_ms(timeout_ms: u64) -> Result<u64, DomainError> {
+    let Ok(deadlines) = DEADLINES.try_with(|d| *d) else {
+        return Ok(timeout_ms);
+    };
+    let left = deadlines.upstream.saturating_duration_since(Instant::now());
+    if left < deadlines.min_attempt || left.is_zero() {
+        return Err(DomainError::QueryBudgetExceeded);
+    }
+    Ok(timeout_ms.min(left.as_millis() as u64))
+}
+
+/// Awaits `fut`, failing with [`DomainError::QueryBudgetExceeded`] if it
+/// is still pending when the overall budget runs out.
+pub async fn within<F, T>(fut: F) -> Result<T, DomainError>
+where
+    F: Future<Output = Result<T, DomainError>>,
+{
+    match DEADLINES.try_with(|d| d.total) {
+        Ok(deadline) => tokio::time::timeout_at(deadline, fut)
+            .await
+            .unwrap_or(Err(DomainError::QueryBudgetExceeded)),
+        Err(_) => fut.await,
+    }
+}
@@ -0,0 +1,42 @@
+use ferrous_dns_domain::{DnsRewrite, DomainError};
+use std::sync::Arc;
+use tracing::{error, info, instrument};
+
+use crate::ports::{DnsRewriteEnginePort, DnsRewriteRepository};
+
+pub struct CreateDnsRewriteUseCase {
+    repo: Arc<dyn DnsRewriteRepository>,
+    rewrite_engine: Arc<dyn DnsRewriteEnginePort>,
+}
+
+impl CreateDnsRewriteUseCase {
+    pub fn new(
+        repo: Arc<dyn DnsRewriteRepository>,
+        rewrite_engine: Arc<dyn DnsRewriteEnginePort>,
+    ) -> Self {
+        Self {
+            repo,
+            rewrite_engine,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(&self, rewrite: DnsRewrite) -> Result<DnsRewrite, DomainError> {
+        rewrite.validate().map_err(DomainError::InvalidDnsRewrite)?;
+
+        let created = self.repo.create(&rewrite).await?;
+
+        info!(
+            rewrite_id = ?created.id,
+            domain = %created.domain,
+            answer = %created.answer,
+            "DNS rewrite created successfully"
+        );
+
+        if let Err(e) = self.rewrite_engine.reload().await {
+            error!(error = %e, "Failed to reload DNS rewrites after creation");
+        }
+
+        Ok(created)
+    }
+}
@@ -0,0 +1,40 @@
+use ferrous_dns_domain::DomainError;
+use std::sync::Arc;
+use tracing::{error, info, instrument};
+
+use crate::ports::{DnsRewriteEnginePort, DnsRewriteRepository};
+
+pub struct DeleteDnsRewriteUseCase {
+    repo: Arc<dyn DnsRewriteRepository>,
+    rewrite_engine: Arc<dyn DnsRewriteEnginePort>,
+}
+
+impl DeleteDnsRewriteUseCase {
+    pub fn new(
+        repo: Arc<dyn DnsRewriteRepository>,
+        rewrite_engine: Arc<dyn DnsRewriteEnginePort>,
+    ) -> Self {
+        Self {
+            repo,
+            rewrite_engine,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(&self, id: i64) -> Result<(), DomainError> {
+        self.repo
+            .get_by_id(id)
+            .await?
+            .ok_or(DomainError::DnsRewriteNotFound(id))?;
+
+        self.repo.delete(id).await?;
+
+        info!(rewrite_id = id, "DNS rewrite deleted successfully");
+
+        if let Err(e) = self.rewrite_engine.reload().await {
+            error!(error = %e, "Failed to reload DNS rewrites after deletion");
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use ferrous_dns_domain::{DnsRewrite, DomainError};
+use std::sync::Arc;
+use tracing::instrument;
+
+use crate::ports::DnsRewriteRepository;
+
+pub struct GetDnsRewritesUseCase {
+    repo: Arc<dyn DnsRewriteRepository>,
+}
+
+impl GetDnsRewritesUseCase {
+    pub fn new(repo: Arc<dyn DnsRewriteRepository>) -> Self {
+        Self { repo }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_all(&self) -> Result<Vec<DnsRewrite>, DomainError> {
+        self.repo.get_all().await
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_by_id(&self, id: i64) -> Result<Option<DnsRewrite>, DomainError> {
+        self.repo.get_by_id(id).await
+    }
+}
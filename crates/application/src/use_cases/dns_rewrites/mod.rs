@@ -0,0 +1,9 @@
+mod create_dns_rewrite;
+mod delete_dns_rewrite;
+mod get_dns_rewrites;
+mod update_dns_rewrite;
+
+pub use create_dns_rewrite::CreateDnsRewriteUseCase;
+pub use delete_dns_rewrite::DeleteDnsRewriteUseCase;
+pub use get_dns_rewrites::GetDnsRewritesUseCase;
+pub use update_dns_rewrite::UpdateDnsRewriteUseCase;
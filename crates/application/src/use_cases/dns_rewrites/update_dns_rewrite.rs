@@ -0,0 +1,54 @@
+use ferrous_dns_domain::{DnsRewrite, DomainError};
+use std::sync::Arc;
+use tracing::{error, info, instrument};
+
+use crate::ports::{DnsRewriteEnginePort, DnsRewriteRepository};
+
+pub struct UpdateDnsRewriteUseCase {
+    repo: Arc<dyn DnsRewriteRepository>,
+    rewrite_engine: Arc<dyn DnsRewriteEnginePort>,
+}
+
+impl UpdateDnsRewriteUseCase {
+    pub fn new(
+        repo: Arc<dyn DnsRewriteRepository>,
+        rewrite_engine: Arc<dyn DnsRewriteEnginePort>,
+    ) -> Self {
+        Self {
+            repo,
+            rewrite_engine,
+        }
+    }
+
+    /// Replaces the stored rewrite `id` with `rewrite`.
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        id: i64,
+        mut rewrite: DnsRewrite,
+    ) -> Result<DnsRewrite, DomainError> {
+        self.repo
+            .get_by_id(id)
+            .await?
+            .ok_or(DomainError::DnsRewriteNotFound(id))?;
+
+        rewrite.validate().map_err(DomainError::InvalidDnsRewrite)?;
+
+        rewrite.id = Some(id);
+        let updated = self.repo.update(&rewrite).await?;
+
+        info!(
+            rewrite_id = id,
+            domain = %updated.domain,
+            answer = %updated.answer,
+            enabled = updated.enabled,
+            "DNS rewrite updated successfully"
+        );
+
+        if let Err(e) = self.rewrite_engine.reload().await {
+            error!(error = %e, "Failed to reload DNS rewrites after update");
+        }
+
+        Ok(updated)
+    }
+}
@@ -0,0 +1,52 @@
+use crate::ports::DnssecHistoryRepository;
+use ferrous_dns_domain::{
+    DnssecStatsSample, DnssecStatusChange, DnssecValidationCounts, DomainError,
+};
+use std::sync::Arc;
+use tracing::instrument;
+
+/// DNSSEC outcome counts over a period.
+#[derive(Debug, Clone)]
+pub struct DnssecStats {
+    pub period_hours: u32,
+    pub totals: DnssecValidationCounts,
+    /// Oldest first, one per history interval.
+    pub samples: Vec<DnssecStatsSample>,
+    /// Latest names whose validation turned `Bogus`, newest first.
+    pub recent_bogus: Vec<DnssecStatusChange>,
+}
+
+pub struct GetDnssecStatsUseCase {
+    repository: Arc<dyn DnssecHistoryRepository>,
+}
+
+impl GetDnssecStatsUseCase {
+    pub fn new(repository: Arc<dyn DnssecHistoryRepository>) -> Self {
+        Self { repository }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        period_hours: u32,
+        bogus_limit: u32,
+    ) -> Result<DnssecStats, DomainError> {
+        let samples = self.repository.get_samples(period_hours).await?;
+        let recent_bogus = self
+            .repository
+            .get_changes(None, Some("Bogus"), bogus_limit)
+            .await?;
+
+        let mut totals = DnssecValidationCounts::default();
+        for sample in &samples {
+            totals.add(&sample.counts);
+        }
+
+        Ok(DnssecStats {
+            period_hours,
+            totals,
+            samples,
+            recent_bogus,
+        })
+    }
+}
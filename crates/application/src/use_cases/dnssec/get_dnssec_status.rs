@@ -0,0 +1,51 @@
+use crate::ports::{DnssecCachedValidation, DnssecHistoryRepository, DnssecStatusPort};
+use ferrous_dns_domain::{blocklist::BlockedDomain, DnssecStatusChange, DomainError};
+use std::sync::Arc;
+use tracing::instrument;
+
+/// What is known about DNSSEC validation of one domain.
+#[derive(Debug, Clone)]
+pub struct DnssecDomainStatus {
+    pub domain: String,
+    /// Results currently in the DNSSEC cache, one per record type.
+    pub cached: Vec<DnssecCachedValidation>,
+    /// Recorded status changes, newest first.
+    pub changes: Vec<DnssecStatusChange>,
+}
+
+pub struct GetDnssecStatusUseCase {
+    status: Arc<dyn DnssecStatusPort>,
+    repository: Arc<dyn DnssecHistoryRepository>,
+}
+
+impl GetDnssecStatusUseCase {
+    pub fn new(
+        status: Arc<dyn DnssecStatusPort>,
+        repository: Arc<dyn DnssecHistoryRepository>,
+    ) -> Self {
+        Self { status, repository }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        domain: &str,
+        changes_limit: u32,
+    ) -> Result<DnssecDomainStatus, DomainError> {
+        let domain = domain.trim().trim_end_matches('.').to_ascii_lowercase();
+        BlockedDomain::validate_domain(&domain).map_err(DomainError::InvalidDomainName)?;
+
+        let mut cached = self.status.cached(&domain);
+        cached.sort_by_key(|validation| validation.record_type.as_str());
+        let changes = self
+            .repository
+            .get_changes(Some(&domain), None, changes_limit)
+            .await?;
+
+        Ok(DnssecDomainStatus {
+            domain,
+            cached,
+            changes,
+        })
+    }
+}
@@ -0,0 +1,7 @@
+mod get_dnssec_stats;
+mod get_dnssec_status;
+mod record_dnssec_history;
+
+pub use get_dnssec_stats::{DnssecStats, GetDnssecStatsUseCase};
+pub use get_dnssec_status::{DnssecDomainStatus, GetDnssecStatusUseCase};
+pub use record_dnssec_history::RecordDnssecHistoryUseCase;
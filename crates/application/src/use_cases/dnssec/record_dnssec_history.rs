@@ -0,0 +1,58 @@
+use crate::ports::{DnssecHistoryRepository, DnssecStatusPort};
+use ferrous_dns_domain::{DnssecValidationCounts, DomainError};
+use std::sync::Arc;
+use tracing::{debug, instrument, warn};
+
+/// Days of DNSSEC samples and status changes kept in the database.
+const DNSSEC_HISTORY_RETENTION_DAYS: u32 = 30;
+
+/// Moves the outcomes gathered by the validation layer into the history
+/// tables: one sample of outcome counts per run, plus every status change.
+pub struct RecordDnssecHistoryUseCase {
+    status: Arc<dyn DnssecStatusPort>,
+    repository: Arc<dyn DnssecHistoryRepository>,
+}
+
+impl RecordDnssecHistoryUseCase {
+    pub fn new(
+        status: Arc<dyn DnssecStatusPort>,
+        repository: Arc<dyn DnssecHistoryRepository>,
+    ) -> Self {
+        Self { status, repository }
+    }
+
+    /// Returns the counts of the interval just recorded.
+    #[instrument(skip(self))]
+    pub async fn execute(&self) -> Result<DnssecValidationCounts, DomainError> {
+        let outcomes = self.status.take_outcomes();
+
+        self.repository.insert_sample(&outcomes.counts).await?;
+        if !outcomes.changes.is_empty() {
+            self.repository.insert_changes(&outcomes.changes).await?;
+        }
+
+        for change in outcomes.changes.iter().filter(|c| c.status == "Bogus") {
+            warn!(
+                domain = %change.domain,
+                record_type = %change.record_type,
+                previous = change.previous_status.unwrap_or("none"),
+                "DNSSEC validation turned Bogus"
+            );
+        }
+        debug!(
+            validations = outcomes.counts.total(),
+            bogus = outcomes.counts.bogus,
+            changes = outcomes.changes.len(),
+            "DNSSEC history recorded"
+        );
+
+        Ok(outcomes.counts)
+    }
+
+    /// Deletes history older than [`DNSSEC_HISTORY_RETENTION_DAYS`].
+    pub async fn purge_expired(&self) -> Result<u64, DomainError> {
+        self.repository
+            .delete_older_than(DNSSEC_HISTORY_RETENTION_DAYS)
+            .await
+    }
+}
@@ -0,0 +1,608 @@
+use std::collections::{HashMap, HashSet};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use ferrous_dns_domain::{BlocklistSource, Config, DomainError, Group, ManagedDomain, RegexFilter};
+use tokio::sync::RwLock;
+use tracing::{error, info, instrument, warn};
+
+use crate::ports::{
+    BlockFilterEnginePort, BlocklistSourceRepository, ClientRepository, ClientSubnetRepository,
+    ExternalConfig, ExternalConfigReader, ExternalDomainRule, ExternalFormat, ExternalList,
+    ExternalRecord, GroupRepository, LocalRecordCreator, ManagedDomainRepository,
+    RegexFilterRepository, WhitelistSourceRepository,
+};
+
+/// Counts of what an external import created or left alone.
+#[derive(Debug, Clone, Default)]
+pub struct ExternalImportSummary {
+    pub groups_imported: usize,
+    pub groups_skipped: usize,
+    pub lists_imported: usize,
+    pub lists_skipped: usize,
+    pub domains_imported: usize,
+    pub domains_skipped: usize,
+    pub regex_filters_imported: usize,
+    pub regex_filters_skipped: usize,
+    pub local_records_imported: usize,
+    pub local_records_skipped: usize,
+    pub clients_imported: usize,
+    pub clients_skipped: usize,
+    pub warnings: Vec<String>,
+}
+
+/// Translates a Pi-hole or AdGuard Home export into Ferrous groups, list
+/// sources, managed domains, regex filters, local records and clients.
+///
+/// Entries that already exist are skipped, so the same export can be
+/// imported more than once.
+pub struct ImportExternalConfigUseCase {
+    reader: Arc<dyn ExternalConfigReader>,
+    config: Arc<RwLock<Config>>,
+    group_repo: Arc<dyn GroupRepository>,
+    blocklist_source_repo: Arc<dyn BlocklistSourceRepository>,
+    whitelist_source_repo: Arc<dyn WhitelistSourceRepository>,
+    managed_domain_repo: Arc<dyn ManagedDomainRepository>,
+    regex_filter_repo: Arc<dyn RegexFilterRepository>,
+    client_repo: Arc<dyn ClientRepository>,
+    client_subnet_repo: Arc<dyn ClientSubnetRepository>,
+    local_record_creator: Arc<dyn LocalRecordCreator>,
+    block_filter_engine: Option<Arc<dyn BlockFilterEnginePort>>,
+}
+
+/// Imported group names (as written in the export) resolved to Ferrous ids.
+struct GroupIndex {
+    by_name: HashMap<String, i64>,
+    default_id: i64,
+}
+
+impl GroupIndex {
+    fn resolve(&self, names: &[String]) -> Vec<i64> {
+        let mut ids: Vec<i64> = Vec::new();
+        for name in names {
+            let id = self
+                .by_name
+                .get(&name.to_lowercase())
+                .copied()
+                .unwrap_or(self.default_id);
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+        if ids.is_empty() {
+            ids.push(self.default_id);
+        }
+        ids
+    }
+}
+
+impl ImportExternalConfigUseCase {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        reader: Arc<dyn ExternalConfigReader>,
+        config: Arc<RwLock<Config>>,
+        group_repo: Arc<dyn GroupRepository>,
+        blocklist_source_repo: Arc<dyn BlocklistSourceRepository>,
+        whitelist_source_repo: Arc<dyn WhitelistSourceRepository>,
+        managed_domain_repo: Arc<dyn ManagedDomainRepository>,
+        regex_filter_repo: Arc<dyn RegexFilterRepository>,
+        client_repo: Arc<dyn ClientRepository>,
+        client_subnet_repo: Arc<dyn ClientSubnetRepository>,
+        local_record_creator: Arc<dyn LocalRecordCreator>,
+    ) -> Self {
+        Self {
+            reader,
+            config,
+            group_repo,
+            blocklist_source_repo,
+            whitelist_source_repo,
+            managed_domain_repo,
+            regex_filter_repo,
+            client_repo,
+            client_subnet_repo,
+            local_record_creator,
+            block_filter_engine: None,
+        }
+    }
+
+    /// Reloads filters and client groups once, after the whole import.
+    pub fn with_block_filter_engine(mut self, engine: Arc<dyn BlockFilterEnginePort>) -> Self {
+        self.block_filter_engine = Some(engine);
+        self
+    }
+
+    #[instrument(skip(self, bytes), fields(format = format.as_str(), size = bytes.len()))]
+    pub async fn execute(
+        &self,
+        format: ExternalFormat,
+        bytes: &[u8],
+    ) -> Result<ExternalImportSummary, DomainError> {
+        let external = self.reader.read(format, bytes).await?;
+        let mut summary = ExternalImportSummary {
+            warnings: external.warnings.clone(),
+            ..Default::default()
+        };
+
+        let groups = self.import_groups(&external, &mut summary).await?;
+        self.import_lists(&external.lists, &groups, &mut summary)
+            .await?;
+        self.import_domain_rules(&external.domain_rules, &groups, &mut summary)
+            .await?;
+        self.import_records(&external.records, &mut summary).await;
+        self.import_clients(&external, &groups, &mut summary)
+            .await?;
+
+        if let Some(ref engine) = self.block_filter_engine {
+            if summary.lists_imported + summary.domains_imported + summary.regex_filters_imported
+                > 0
+            {
+                if let Err(e) = engine.reload().await {
+                    error!(error = %e, "Failed to reload block filter after external import");
+                }
+            }
+            if summary.groups_imported + summary.clients_imported > 0 {
+                if let Err(e) = engine.load_client_groups().await {
+                    error!(error = %e, "Failed to reload client groups after external import");
+                }
+            }
+        }
+
+        info!(
+            format = format.as_str(),
+            groups = summary.groups_imported,
+            lists = summary.lists_imported,
+            domains = summary.domains_imported,
+            regex_filters = summary.regex_filters_imported,
+            local_records = summary.local_records_imported,
+            clients = summary.clients_imported,
+            warnings = summary.warnings.len(),
+            "External configuration imported"
+        );
+
+        Ok(summary)
+    }
+
+    async fn import_groups(
+        &self,
+        external: &ExternalConfig,
+        summary: &mut ExternalImportSummary,
+    ) -> Result<GroupIndex, DomainError> {
+        let existing = self.group_repo.get_all().await?;
+        let default_id = existing
+            .iter()
+            .find(|g| g.is_default)
+            .and_then(|g| g.id)
+            .ok_or_else(|| DomainError::NotFound("Default group".to_string()))?;
+        let mut known: HashMap<String, i64> = existing
+            .iter()
+            .filter_map(|g| g.id.map(|id| (g.name.to_lowercase(), id)))
+            .collect();
+        let mut by_name = HashMap::new();
+
+        for group in &external.groups {
+            let name = sanitize_group_name(&group.name);
+            if Group::validate_name(&name).is_err() {
+                summary
+                    .warnings
+                    .push(format!("Group '{}': unusable name, skipped", group.name));
+                summary.groups_skipped += 1;
+                continue;
+            }
+            if let Some(&id) = known.get(&name.to_lowercase()) {
+                by_name.insert(group.name.to_lowercase(), id);
+                summary.groups_skipped += 1;
+                continue;
+            }
+
+            match self
+                .group_repo
+                .create(name.clone(), group.comment.clone())
+                .await
+            {
+                Ok(created) => {
+                    let Some(id) = created.id else { continue };
+                    if !group.enabled {
+                        self.group_repo
+                            .update(id, None, Some(false), None, None, None)
+                            .await?;
+                    }
+                    known.insert(name.to_lowercase(), id);
+                    by_name.insert(group.name.to_lowercase(), id);
+                    summary.groups_imported += 1;
+                }
+                Err(e) => {
+                    warn!(name = %name, error = %e, "Skipping group during external import");
+                    summary
+                        .warnings
+                        .push(format!("Group '{}': {}", group.name, e));
+                    summary.groups_skipped += 1;
+                }
+            }
+        }
+
+        Ok(GroupIndex {
+            by_name,
+            default_id,
+        })
+    }
+
+    async fn import_lists(
+        &self,
+        lists: &[ExternalList],
+        groups: &GroupIndex,
+        summary: &mut ExternalImportSummary,
+    ) -> Result<(), DomainError> {
+        let blocklists = self.blocklist_source_repo.get_all().await?;
+        let allowlists = self.whitelist_source_repo.get_all().await?;
+        let mut urls: HashSet<(bool, String)> = blocklists
+            .iter()
+            .filter_map(|s| s.url.as_ref().map(|u| (false, u.to_string())))
+            .chain(
+                allowlists
+                    .iter()
+                    .filter_map(|s| s.url.as_ref().map(|u| (true, u.to_string()))),
+            )
+            .collect();
+        let mut block_names: HashSet<String> =
+            blocklists.iter().map(|s| s.name.to_string()).collect();
+        let mut allow_names: HashSet<String> =
+            allowlists.iter().map(|s| s.name.to_string()).collect();
+
+        for list in lists {
+            let url = list.url.trim().to_string();
+            if urls.contains(&(list.allow, url.clone())) {
+                summary.lists_skipped += 1;
+                continue;
+            }
+            if let Err(e) = BlocklistSource::validate_url(&Some(Arc::from(url.as_str()))) {
+                summary.warnings.push(format!("List '{}': {}", url, e));
+                summary.lists_skipped += 1;
+                continue;
+            }
+
+            let names = if list.allow {
+                &mut allow_names
+            } else {
+                &mut block_names
+            };
+            let base = list
+                .name
+                .as_deref()
+                .map(str::trim)
+                .filter(|n| !n.is_empty())
+                .map(str::to_string)
+                .unwrap_or_else(|| name_from_url(&url));
+            let name = unique_name(&truncate(&base, 190), names);
+            let group_ids = groups.resolve(&list.groups);
+
+            let created = if list.allow {
+                self.whitelist_source_repo
+                    .create(
+                        name.clone(),
+                        Some(url.clone()),
+                        group_ids,
+                        list.comment.clone(),
+                        list.enabled,
+                    )
+                    .await
+                    .map(|_| ())
+            } else {
+                self.blocklist_source_repo
+                    .create(
+                        name.clone(),
+                        Some(url.clone()),
+                        group_ids,
+                        list.comment.clone(),
+                        list.enabled,
+                    )
+                    .await
+                    .map(|_| ())
+            };
+
+            match created {
+                Ok(()) => {
+                    names.insert(name);
+                    urls.insert((list.allow, url));
+                    summary.lists_imported += 1;
+                }
+                Err(e) => {
+                    warn!(url = %url, error = %e, "Skipping list during external import");
+                    summary.warnings.push(format!("List '{}': {}", url, e));
+                    summary.lists_skipped += 1;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn import_domain_rules(
+        &self,
+        rules: &[ExternalDomainRule],
+        groups: &GroupIndex,
+        summary: &mut ExternalImportSummary,
+    ) -> Result<(), DomainError> {
+        let domains = self.managed_domain_repo.get_all().await?;
+        let filters = self.regex_filter_repo.get_all().await?;
+        let mut domain_keys: HashSet<(String, &'static str, i64)> = domains
+            .iter()
+            .map(|d| (d.domain.to_lowercase(), d.action.to_str(), d.group_id))
+            .collect();
+        let mut regex_keys: HashSet<(String, &'static str, i64)> = filters
+            .iter()
+            .map(|f| (f.pattern.to_string(), f.action.to_str(), f.group_id))
+            .collect();
+        let mut domain_names: HashSet<String> =
+            domains.iter().map(|d| d.name.to_string()).collect();
+        let mut regex_names: HashSet<String> = filters.iter().map(|f| f.name.to_string()).collect();
+
+        for rule in rules {
+            let value = if rule.regex {
+                rule.value.clone()
+            } else {
+                rule.value.trim().trim_end_matches('.').to_lowercase()
+            };
+            let check = if rule.regex {
+                RegexFilter::validate_pattern(&value)
+            } else {
+                ManagedDomain::validate_domain(&value)
+            };
+            if let Err(e) = check {
+                summary
+                    .warnings
+                    .push(format!("Rule '{}': {}", rule.value, e));
+                if rule.regex {
+                    summary.regex_filters_skipped += 1;
+                } else {
+                    summary.domains_skipped += 1;
+                }
+                continue;
+            }
+
+            for group_id in groups.resolve(&rule.groups) {
+                let key = (value.clone(), rule.action.to_str(), group_id);
+                let result = if rule.regex {
+                    if regex_keys.contains(&key) {
+                        summary.regex_filters_skipped += 1;
+                        continue;
+                    }
+                    let name = unique_name(&truncate(&value, 190), &regex_names);
+                    self.regex_filter_repo
+                        .create(
+                            name.clone(),
+                            value.clone(),
+                            rule.action,
+                            group_id,
+                            rule.comment.clone(),
+                            rule.enabled,
+                        )
+                        .await
+                        .map(|_| {
+                            regex_names.insert(name);
+                            regex_keys.insert(key);
+                            summary.regex_filters_imported += 1;
+                        })
+                } else {
+                    if domain_keys.contains(&key) {
+                        summary.domains_skipped += 1;
+                        continue;
+                    }
+                    let name = unique_name(&truncate(&value, 190), &domain_names);
+                    self.managed_domain_repo
+                        .create(
+                            name.clone(),
+                            value.clone(),
+                            rule.action,
+                            group_id,
+                            rule.comment.clone(),
+                            rule.enabled,
+                        )
+                        .await
+                        .map(|_| {
+                            domain_names.insert(name);
+                            domain_keys.insert(key);
+                            summary.domains_imported += 1;
+                        })
+                };
+
+                if let Err(e) = result {
+                    warn!(rule = %rule.value, error = %e, "Skipping rule during external import");
+                    summary
+                        .warnings
+                        .push(format!("Rule '{}': {}", rule.value, e));
+                    if rule.regex {
+                        summary.regex_filters_skipped += 1;
+                    } else {
+                        summary.domains_skipped += 1;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn import_records(
+        &self,
+        records: &[ExternalRecord],
+        summary: &mut ExternalImportSummary,
+    ) {
+        let mut existing: HashSet<String> = {
+            let defined = match self.local_record_creator.existing_local_records().await {
+                Ok(defined) => defined,
+                Err(e) => {
+                    summary.warnings.push(format!("Local records: {}", e));
+                    summary.local_records_skipped += records.len();
+                    return;
+                }
+            };
+            let config = self.config.read().await;
+            defined
+                .iter()
+                .map(|r| r.fqdn(&config.dns.local_domain).to_lowercase())
+                .collect()
+        };
+
+        for record in records {
+            let name = record.name.trim().trim_end_matches('.').to_lowercase();
+            let Ok(ip) = record.answer.trim().parse::<IpAddr>() else {
+                summary.warnings.push(format!(
+                    "Record '{}' → '{}': only A/AAAA records can be imported",
+                    record.name, record.answer
+                ));
+                summary.local_records_skipped += 1;
+                continue;
+            };
+            if name.is_empty() || existing.contains(&name) {
+                summary.local_records_skipped += 1;
+                continue;
+            }
+
+            let (hostname, domain) = match name.split_once('.') {
+                Some((host, domain)) => (host.to_string(), Some(domain.to_string())),
+                None => (name.clone(), None),
+            };
+            let record_type = if ip.is_ipv4() { "A" } else { "AAAA" };
+
+            match self
+                .local_record_creator
+                .create_local_record(
+                    hostname,
+                    domain,
+                    ip.to_string(),
+                    record_type.to_string(),
+                    None,
+                    None,
+                )
+                .await
+            {
+                Ok(_) => {
+                    existing.insert(name);
+                    summary.local_records_imported += 1;
+                }
+                Err(e) => {
+                    warn!(name = %record.name, error = %e, "Skipping record during external import");
+                    summary
+                        .warnings
+                        .push(format!("Record '{}': {}", record.name, e));
+                    summary.local_records_skipped += 1;
+                }
+            }
+        }
+    }
+
+    async fn import_clients(
+        &self,
+        external: &ExternalConfig,
+        groups: &GroupIndex,
+        summary: &mut ExternalImportSummary,
+    ) -> Result<(), DomainError> {
+        for client in &external.clients {
+            let id = client.id.trim();
+            let group_id = groups.resolve(&client.groups)[0];
+            if client.groups.len() > 1 {
+                summary.warnings.push(format!(
+                    "Client '{}': belongs to several groups, assigned to the first one only",
+                    id
+                ));
+            }
+
+            if let Ok(ip) = id.parse::<IpAddr>() {
+                let created = self.client_repo.get_or_create(ip).await?;
+                let Some(client_id) = created.id else {
+                    continue;
+                };
+                self.client_repo.assign_group(client_id, group_id).await?;
+                if client.name.is_some() || client.comment.is_some() {
+                    self.client_repo
+                        .update_metadata(
+                            client_id,
+                            client
+                                .name
+                                .clone()
+                                .or_else(|| created.display_name.as_deref().map(str::to_string)),
+                            created.category,
+                            client
+                                .comment
+                                .clone()
+                                .or_else(|| created.notes.as_deref().map(str::to_string)),
+                        )
+                        .await?;
+                }
+                summary.clients_imported += 1;
+            } else if id.contains('/') && id.parse::<ipnetwork::IpNetwork>().is_ok() {
+                if self.client_subnet_repo.exists(id).await? {
+                    summary.clients_skipped += 1;
+                    continue;
+                }
+                let comment = client.comment.clone().or_else(|| client.name.clone());
+                match self
+                    .client_subnet_repo
+                    .create(id.to_string(), group_id, comment)
+                    .await
+                {
+                    Ok(_) => summary.clients_imported += 1,
+                    Err(e) => {
+                        summary.warnings.push(format!("Subnet '{}': {}", id, e));
+                        summary.clients_skipped += 1;
+                    }
+                }
+            } else {
+                summary.warnings.push(format!(
+                    "Client '{}': only IP addresses and subnets can be imported",
+                    id
+                ));
+                summary.clients_skipped += 1;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Replaces characters Ferrous does not accept in group names.
+fn sanitize_group_name(name: &str) -> String {
+    let cleaned: String = name
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == ' ' || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    truncate(&cleaned, 100)
+}
+
+fn name_from_url(url: &str) -> String {
+    url.split("://")
+        .nth(1)
+        .and_then(|rest| rest.split('/').next())
+        .filter(|host| !host.is_empty())
+        .unwrap_or(url)
+        .to_string()
+}
+
+fn truncate(value: &str, max: usize) -> String {
+    if value.len() <= max {
+        return value.to_string();
+    }
+    let mut end = max;
+    while !value.is_char_boundary(end) {
+        end -= 1;
+    }
+    value[..end].to_string()
+}
+
+/// Appends ` (2)`, ` (3)`, … until `base` is not taken.
+fn unique_name(base: &str, taken: &HashSet<String>) -> String {
+    if !taken.contains(base) {
+        return base.to_string();
+    }
+    (2..)
+        .map(|n| format!("{} ({})", base, n))
+        .find(|candidate| !taken.contains(candidate))
+        .unwrap_or_else(|| base.to_string())
+}
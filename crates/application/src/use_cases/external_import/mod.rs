@@ -0,0 +1,3 @@
+pub mod import;
+
+pub use import::{ExternalImportSummary, ImportExternalConfigUseCase};
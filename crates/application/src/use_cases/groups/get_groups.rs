@@ -1,4 +1,4 @@
-use ferrous_dns_domain::{Client, DomainError, Group};
+use ferrous_dns_domain::{Client, DomainError, Group, PageRequest};
 use std::sync::Arc;
 use tracing::instrument;
 
@@ -23,6 +23,14 @@ impl GetGroupsUseCase {
         self.group_repo.get_all_with_client_counts().await
     }
 
+    #[instrument(skip(self))]
+    pub async fn get_page_with_client_counts(
+        &self,
+        page: &PageRequest,
+    ) -> Result<(Vec<(Group, u64)>, u64), DomainError> {
+        self.group_repo.get_page_with_client_counts(page).await
+    }
+
     #[instrument(skip(self))]
     pub async fn get_by_id(&self, id: i64) -> Result<Option<Group>, DomainError> {
         self.group_repo.get_by_id(id).await
@@ -1,16 +1,27 @@
 use ferrous_dns_domain::{DomainError, Group};
 use std::sync::Arc;
-use tracing::{info, instrument};
+use tracing::{error, info, instrument};
 
-use crate::ports::GroupRepository;
+use crate::ports::{AaaaFilterPort, GroupRepository};
 
 pub struct UpdateGroupUseCase {
     group_repo: Arc<dyn GroupRepository>,
+    aaaa_filter: Option<Arc<dyn AaaaFilterPort>>,
 }
 
 impl UpdateGroupUseCase {
     pub fn new(group_repo: Arc<dyn GroupRepository>) -> Self {
-        Self { group_repo }
+        Self {
+            group_repo,
+            aaaa_filter: None,
+        }
+    }
+
+    /// Attaches the live AAAA filter so that toggling `filter_aaaa` takes
+    /// effect without a server restart.
+    pub fn with_aaaa_filter(mut self, aaaa_filter: Arc<dyn AaaaFilterPort>) -> Self {
+        self.aaaa_filter = Some(aaaa_filter);
+        self
     }
 
     #[instrument(skip(self))]
@@ -20,6 +31,8 @@ impl UpdateGroupUseCase {
         name: Option<String>,
         enabled: Option<bool>,
         comment: Option<String>,
+        filter_aaaa: Option<bool>,
+        audit_mode: Option<bool>,
     ) -> Result<Group, DomainError> {
         let group = self
             .group_repo
@@ -40,12 +53,23 @@ impl UpdateGroupUseCase {
             return Err(DomainError::ProtectedGroupCannotBeDisabled);
         }
 
-        let updated_group = self.group_repo.update(id, name, enabled, comment).await?;
+        let updated_group = self
+            .group_repo
+            .update(id, name, enabled, comment, filter_aaaa, audit_mode)
+            .await?;
+
+        if let Some(ref aaaa_filter) = self.aaaa_filter {
+            if let Err(e) = aaaa_filter.reload().await {
+                error!(error = %e, "Failed to reload AAAA filter after group update");
+            }
+        }
 
         info!(
             group_id = ?id,
             name = %updated_group.name,
             enabled = %updated_group.enabled,
+            filter_aaaa = %updated_group.filter_aaaa,
+            audit_mode = %updated_group.audit_mode,
             "Group updated successfully"
         );
 
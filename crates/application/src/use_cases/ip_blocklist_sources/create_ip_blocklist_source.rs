@@ -0,0 +1,44 @@
+use ferrous_dns_domain::{DomainError, IpBlocklistSource};
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use crate::ports::IpBlocklistSourceRepository;
+
+pub struct CreateIpBlocklistSourceUseCase {
+    repo: Arc<dyn IpBlocklistSourceRepository>,
+}
+
+impl CreateIpBlocklistSourceUseCase {
+    pub fn new(repo: Arc<dyn IpBlocklistSourceRepository>) -> Self {
+        Self { repo }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        name: String,
+        url: String,
+        comment: Option<String>,
+        enabled: bool,
+    ) -> Result<IpBlocklistSource, DomainError> {
+        IpBlocklistSource::validate_name(&name).map_err(DomainError::InvalidIpBlocklistSource)?;
+
+        IpBlocklistSource::validate_url(&url).map_err(DomainError::InvalidIpBlocklistSource)?;
+
+        IpBlocklistSource::validate_comment(&comment.as_deref().map(Arc::from))
+            .map_err(DomainError::InvalidIpBlocklistSource)?;
+
+        let source = self
+            .repo
+            .create(name.clone(), url, comment, enabled)
+            .await?;
+
+        info!(
+            source_id = ?source.id,
+            name = %name,
+            "IP blocklist source created successfully"
+        );
+
+        Ok(source)
+    }
+}
@@ -0,0 +1,29 @@
+use ferrous_dns_domain::DomainError;
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use crate::ports::IpBlocklistSourceRepository;
+
+pub struct DeleteIpBlocklistSourceUseCase {
+    repo: Arc<dyn IpBlocklistSourceRepository>,
+}
+
+impl DeleteIpBlocklistSourceUseCase {
+    pub fn new(repo: Arc<dyn IpBlocklistSourceRepository>) -> Self {
+        Self { repo }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(&self, id: i64) -> Result<(), DomainError> {
+        self.repo
+            .get_by_id(id)
+            .await?
+            .ok_or(DomainError::IpBlocklistSourceNotFound(id))?;
+
+        self.repo.delete(id).await?;
+
+        info!(source_id = ?id, "IP blocklist source deleted successfully");
+
+        Ok(())
+    }
+}
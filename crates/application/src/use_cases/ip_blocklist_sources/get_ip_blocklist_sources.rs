@@ -0,0 +1,25 @@
+use ferrous_dns_domain::{DomainError, IpBlocklistSource};
+use std::sync::Arc;
+use tracing::instrument;
+
+use crate::ports::IpBlocklistSourceRepository;
+
+pub struct GetIpBlocklistSourcesUseCase {
+    repo: Arc<dyn IpBlocklistSourceRepository>,
+}
+
+impl GetIpBlocklistSourcesUseCase {
+    pub fn new(repo: Arc<dyn IpBlocklistSourceRepository>) -> Self {
+        Self { repo }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_all(&self) -> Result<Vec<IpBlocklistSource>, DomainError> {
+        self.repo.get_all().await
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_by_id(&self, id: i64) -> Result<Option<IpBlocklistSource>, DomainError> {
+        self.repo.get_by_id(id).await
+    }
+}
@@ -0,0 +1,9 @@
+mod create_ip_blocklist_source;
+mod delete_ip_blocklist_source;
+mod get_ip_blocklist_sources;
+mod update_ip_blocklist_source;
+
+pub use create_ip_blocklist_source::CreateIpBlocklistSourceUseCase;
+pub use delete_ip_blocklist_source::DeleteIpBlocklistSourceUseCase;
+pub use get_ip_blocklist_sources::GetIpBlocklistSourcesUseCase;
+pub use update_ip_blocklist_source::UpdateIpBlocklistSourceUseCase;
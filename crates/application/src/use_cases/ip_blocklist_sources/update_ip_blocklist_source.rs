@@ -0,0 +1,54 @@
+use ferrous_dns_domain::{DomainError, IpBlocklistSource};
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use crate::ports::IpBlocklistSourceRepository;
+
+pub struct UpdateIpBlocklistSourceUseCase {
+    repo: Arc<dyn IpBlocklistSourceRepository>,
+}
+
+impl UpdateIpBlocklistSourceUseCase {
+    pub fn new(repo: Arc<dyn IpBlocklistSourceRepository>) -> Self {
+        Self { repo }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        id: i64,
+        name: Option<String>,
+        url: Option<String>,
+        comment: Option<String>,
+        enabled: Option<bool>,
+    ) -> Result<IpBlocklistSource, DomainError> {
+        self.repo
+            .get_by_id(id)
+            .await?
+            .ok_or(DomainError::IpBlocklistSourceNotFound(id))?;
+
+        if let Some(ref n) = name {
+            IpBlocklistSource::validate_name(n).map_err(DomainError::InvalidIpBlocklistSource)?;
+        }
+
+        if let Some(ref u) = url {
+            IpBlocklistSource::validate_url(u).map_err(DomainError::InvalidIpBlocklistSource)?;
+        }
+
+        if let Some(ref c) = comment {
+            IpBlocklistSource::validate_comment(&Some(Arc::from(c.as_str())))
+                .map_err(DomainError::InvalidIpBlocklistSource)?;
+        }
+
+        let updated = self.repo.update(id, name, url, comment, enabled).await?;
+
+        info!(
+            source_id = ?id,
+            name = %updated.name,
+            enabled = %updated.enabled,
+            "IP blocklist source updated successfully"
+        );
+
+        Ok(updated)
+    }
+}
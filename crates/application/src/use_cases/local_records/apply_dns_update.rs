@@ -0,0 +1,446 @@
+use std::sync::Arc;
+
+use ferrous_dns_domain::{
+    AuditAction, AuditEvent, Config, DnsRcode, DnsUpdate, DnsUpdateOutcome, LocalRecord,
+    LocalRecordData, RecordType, UpdateChange, UpdatePrerequisite, LOCAL_RECORD_TYPES,
+    MAX_LOCAL_RECORD_TTL,
+};
+use tokio::sync::{Mutex, RwLock};
+use tracing::{info, instrument, warn};
+
+use super::LivePropagation;
+use crate::ports::{
+    AuditLogRepository, DnsCachePort, LocalRecordRepository, LocalZonePort, PtrRecordRegistry,
+};
+
+/// Longest change summary kept in an audit event.
+const MAX_AUDIT_DETAILS: usize = 2000;
+
+/// A local record of the zone being updated.
+struct ZoneRecord {
+    fqdn: String,
+    data: Option<LocalRecordData>,
+    record: LocalRecord,
+}
+
+/// Applies dynamic updates (RFC 2136) to the local records of the zones in
+/// `dns.update_zones`.
+///
+/// Updates only see records without a view or tenant; `[[dns.local_records]]`
+/// from the config file are never changed.
+pub struct ApplyDnsUpdateUseCase {
+    config: Arc<RwLock<Config>>,
+    repo: Arc<dyn LocalRecordRepository>,
+    zone: Arc<dyn LocalZonePort>,
+    audit_log: Option<Arc<dyn AuditLogRepository>>,
+    live: LivePropagation,
+    /// Serialises updates, so the prerequisites are checked against the
+    /// records the changes are applied to.
+    write_lock: Mutex<()>,
+}
+
+impl ApplyDnsUpdateUseCase {
+    pub fn new(
+        config: Arc<RwLock<Config>>,
+        repo: Arc<dyn LocalRecordRepository>,
+        zone: Arc<dyn LocalZonePort>,
+    ) -> Self {
+        Self {
+            config,
+            repo,
+            zone,
+            audit_log: None,
+            live: LivePropagation::default(),
+            write_lock: Mutex::new(()),
+        }
+    }
+
+    /// Attaches a live PTR registry so that updated A/AAAA records are
+    /// answered for reverse lookups without a server restart.
+    pub fn with_ptr_registry(mut self, registry: Option<Arc<dyn PtrRecordRegistry>>) -> Self {
+        self.live.ptr_registry = registry;
+        self
+    }
+
+    /// Attaches a live DNS cache so that an update immediately evicts cached
+    /// answers for the names it changes.
+    pub fn with_dns_cache(mut self, cache: Option<Arc<dyn DnsCachePort>>) -> Self {
+        self.live.dns_cache = cache;
+        self
+    }
+
+    /// Records every update, applied or not, in the audit log.
+    pub fn with_audit_log(mut self, audit_log: Arc<dyn AuditLogRepository>) -> Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
+
+    #[instrument(skip(self, update), fields(zone = %update.zone, client = %update.client_ip))]
+    pub async fn execute(&self, update: &DnsUpdate) -> DnsUpdateOutcome {
+        let outcome = self.apply(update).await;
+        match &outcome {
+            DnsUpdateOutcome::Applied { added, removed } => info!(
+                added,
+                removed,
+                key = ?update.key_name,
+                "Dynamic update applied"
+            ),
+            DnsUpdateOutcome::Rejected { rcode, reason } => info!(
+                rcode = rcode.as_str(),
+                reason = %reason,
+                key = ?update.key_name,
+                "Dynamic update rejected"
+            ),
+        }
+        self.record_audit_event(update, &outcome).await;
+        outcome
+    }
+
+    async fn apply(&self, update: &DnsUpdate) -> DnsUpdateOutcome {
+        let config = self.config.read().await;
+        let Some(zone_config) = config
+            .dns
+            .update_zones
+            .iter()
+            .find(|zone| zone.normalized_zone() == *update.zone)
+        else {
+            return DnsUpdateOutcome::rejected(
+                DnsRcode::NotAuth,
+                format!("'{}' does not accept dynamic updates", update.zone),
+            );
+        };
+        if !zone_config.allows(update.client_ip, update.key_name.as_deref()) {
+            return DnsUpdateOutcome::rejected(
+                DnsRcode::Refused,
+                "client or key is not allowed to update the zone",
+            );
+        }
+
+        let names = update
+            .prerequisites
+            .iter()
+            .map(UpdatePrerequisite::name)
+            .chain(update.changes.iter().map(UpdateChange::name));
+        for name in names {
+            if !in_zone(name, &update.zone) {
+                return DnsUpdateOutcome::rejected(
+                    DnsRcode::NotZone,
+                    format!("'{}' is outside the zone", name),
+                );
+            }
+        }
+
+        // Prescan (RFC 2136 §3.4.1): every addition must be storable before
+        // anything is changed.
+        let mut additions = Vec::new();
+        for change in &update.changes {
+            if let UpdateChange::Add {
+                name,
+                record_type,
+                value,
+                ttl,
+            } = change
+            {
+                match new_record(name, *record_type, value, *ttl) {
+                    Ok(record) => additions.push(record),
+                    Err(rejection) => return rejection,
+                }
+            }
+        }
+
+        let _guard = self.write_lock.lock().await;
+        let stored = match self.repo.get_all().await {
+            Ok(stored) => stored,
+            Err(e) => return DnsUpdateOutcome::rejected(DnsRcode::ServFail, e.to_string()),
+        };
+        let local_domain = &config.dns.local_domain;
+        let mut records: Vec<ZoneRecord> = stored
+            .into_iter()
+            .filter(|record| record.view.is_none() && record.tenant_id.is_none())
+            .filter_map(|record| {
+                let fqdn = record.fqdn(local_domain);
+                in_zone(&fqdn, &update.zone).then(|| ZoneRecord {
+                    data: record.data().ok(),
+                    fqdn,
+                    record,
+                })
+            })
+            .collect();
+
+        if let Some(failure) = check_prerequisites(&update.prerequisites, &records) {
+            return failure;
+        }
+
+        let mut removed = Vec::new();
+        let mut additions = additions.into_iter();
+        for change in &update.changes {
+            match change {
+                UpdateChange::Add { .. } => {
+                    if let Some(record) = additions.next() {
+                        add(&mut records, &mut removed, record);
+                    }
+                }
+                UpdateChange::DeleteName(name) => {
+                    take(&mut records, &mut removed, |r| r.fqdn == **name);
+                }
+                UpdateChange::DeleteRrset { name, record_type } => {
+                    take(&mut records, &mut removed, |r| {
+                        r.fqdn == **name && r.record.record_type == *record_type
+                    });
+                }
+                UpdateChange::DeleteRecord {
+                    name,
+                    record_type,
+                    value,
+                } => {
+                    let data = LocalRecordData::parse(*record_type, value).ok();
+                    take(&mut records, &mut removed, |r| {
+                        r.fqdn == **name
+                            && r.record.record_type == *record_type
+                            && data.is_some()
+                            && r.data == data
+                    });
+                }
+            }
+        }
+
+        let mut failure = None;
+        let mut deleted = Vec::with_capacity(removed.len());
+        for record in removed {
+            let Some(id) = record.id else { continue };
+            match self.repo.delete(id).await {
+                Ok(()) => deleted.push(record),
+                Err(e) => {
+                    failure = Some(e);
+                    break;
+                }
+            }
+        }
+        let mut created = Vec::new();
+        if failure.is_none() {
+            for entry in records.iter().filter(|r| r.record.id.is_none()) {
+                match self.repo.create(&entry.record).await {
+                    Ok(record) => created.push(record),
+                    Err(e) => {
+                        failure = Some(e);
+                        break;
+                    }
+                }
+            }
+        }
+
+        if !deleted.is_empty() || !created.is_empty() {
+            let deleted: Vec<&LocalRecord> = deleted.iter().collect();
+            let created: Vec<&LocalRecord> = created.iter().collect();
+            self.live
+                .apply_all(self.zone.as_ref(), &config, &deleted, &created)
+                .await;
+        }
+
+        match failure {
+            Some(e) => DnsUpdateOutcome::rejected(
+                DnsRcode::ServFail,
+                format!("update was applied partially: {}", e),
+            ),
+            None => DnsUpdateOutcome::Applied {
+                added: created.len(),
+                removed: deleted.len(),
+            },
+        }
+    }
+
+    async fn record_audit_event(&self, update: &DnsUpdate, outcome: &DnsUpdateOutcome) {
+        let Some(ref audit_log) = self.audit_log else {
+            return;
+        };
+        let changes = update
+            .changes
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("; ");
+        let mut details = match outcome {
+            DnsUpdateOutcome::Applied { added, removed } => {
+                format!("added {}, removed {}: {}", added, removed, changes)
+            }
+            DnsUpdateOutcome::Rejected { reason, .. } if changes.is_empty() => reason.clone(),
+            DnsUpdateOutcome::Rejected { reason, .. } => format!("{}: {}", reason, changes),
+        };
+        if details.len() > MAX_AUDIT_DETAILS {
+            let mut end = MAX_AUDIT_DETAILS;
+            while !details.is_char_boundary(end) {
+                end -= 1;
+            }
+            details.truncate(end);
+        }
+
+        let mut event = AuditEvent::new(
+            AuditAction::DnsUpdate,
+            update.client_ip.to_string(),
+            update.zone.to_string(),
+            outcome.rcode_str(),
+            details,
+        );
+        if let Some(ref key) = update.key_name {
+            event = event.with_actor(key.to_string());
+        }
+        if let Err(e) = audit_log.insert(&event).await {
+            warn!(error = %e, "Failed to record dynamic update in the audit log");
+        }
+    }
+}
+
+/// Whether `name` is `zone` or a name below it.
+fn in_zone(name: &str, zone: &str) -> bool {
+    name.strip_suffix(zone)
+        .is_some_and(|rest| rest.is_empty() || rest.ends_with('.'))
+}
+
+/// The local record an addition creates. The first label of `name` becomes
+/// the hostname and the rest its domain.
+fn new_record(
+    name: &str,
+    record_type: RecordType,
+    value: &str,
+    ttl: u32,
+) -> Result<ZoneRecord, DnsUpdateOutcome> {
+    if !LOCAL_RECORD_TYPES.contains(&record_type) {
+        return Err(DnsUpdateOutcome::rejected(
+            DnsRcode::Refused,
+            format!("{} records cannot be stored in the local zone", record_type),
+        ));
+    }
+    let Some((hostname, domain)) = name.split_once('.') else {
+        return Err(DnsUpdateOutcome::rejected(
+            DnsRcode::Refused,
+            format!("'{}' has no parent domain", name),
+        ));
+    };
+    let record = LocalRecord {
+        domain: Some(Arc::from(domain)),
+        ttl: ttl.min(MAX_LOCAL_RECORD_TTL),
+        ..LocalRecord::new(Arc::from(hostname), record_type, Arc::from(value))
+    };
+    record
+        .validate()
+        .map_err(|e| DnsUpdateOutcome::rejected(DnsRcode::FormErr, e))?;
+    Ok(ZoneRecord {
+        fqdn: name.to_string(),
+        data: record.data().ok(),
+        record,
+    })
+}
+
+/// Returns the rejection for the first prerequisite `records` do not meet
+/// (RFC 2136 §3.2).
+fn check_prerequisites(
+    prerequisites: &[UpdatePrerequisite],
+    records: &[ZoneRecord],
+) -> Option<DnsUpdateOutcome> {
+    let at = |name: &str| records.iter().filter(move |r| r.fqdn == name);
+    for prerequisite in prerequisites {
+        let failure = match prerequisite {
+            UpdatePrerequisite::NameInUse(name) => at(name)
+                .next()
+                .is_none()
+                .then(|| (DnsRcode::NxDomain, format!("'{}' does not exist", name))),
+            UpdatePrerequisite::NameNotInUse(name) => at(name)
+                .next()
+                .is_some()
+                .then(|| (DnsRcode::YxDomain, format!("'{}' exists", name))),
+            UpdatePrerequisite::RrsetExists { name, record_type } => {
+                (!at(name).any(|r| r.record.record_type == *record_type)).then(|| {
+                    (
+                        DnsRcode::NxRrset,
+                        format!("'{}' has no {} records", name, record_type),
+                    )
+                })
+            }
+            UpdatePrerequisite::RrsetDoesNotExist { name, record_type } => at(name)
+                .any(|r| r.record.record_type == *record_type)
+                .then(|| {
+                    (
+                        DnsRcode::YxRrset,
+                        format!("'{}' has {} records", name, record_type),
+                    )
+                }),
+            UpdatePrerequisite::RrsetEquals {
+                name,
+                record_type,
+                values,
+            } => {
+                let expected: Option<Vec<LocalRecordData>> = values
+                    .iter()
+                    .map(|value| LocalRecordData::parse(*record_type, value).ok())
+                    .collect();
+                let actual: Vec<LocalRecordData> = at(name)
+                    .filter(|r| r.record.record_type == *record_type)
+                    .filter_map(|r| r.data.clone())
+                    .collect();
+                let equal = expected.is_some_and(|expected| {
+                    expected.iter().all(|data| actual.contains(data))
+                        && actual.iter().all(|data| expected.contains(data))
+                });
+                (!equal).then(|| {
+                    (
+                        DnsRcode::NxRrset,
+                        format!("the {} records of '{}' differ", record_type, name),
+                    )
+                })
+            }
+        };
+        if let Some((rcode, reason)) = failure {
+            return Some(DnsUpdateOutcome::rejected(rcode, reason));
+        }
+    }
+    None
+}
+
+/// Adds `new` to the zone (RFC 2136 §3.4.2.2). A CNAME and other data never
+/// share a name, so an addition that would break that is ignored; a CNAME
+/// replaces the name's CNAME, and a record with the data of an existing one
+/// only updates its TTL.
+fn add(records: &mut Vec<ZoneRecord>, removed: &mut Vec<LocalRecord>, new: ZoneRecord) {
+    let is_cname = new.record.record_type == RecordType::CNAME;
+    let conflict = records
+        .iter()
+        .any(|r| r.fqdn == new.fqdn && (r.record.record_type == RecordType::CNAME) != is_cname);
+    if conflict {
+        return;
+    }
+
+    let same = records.iter().position(|r| {
+        r.fqdn == new.fqdn
+            && r.record.record_type == new.record.record_type
+            && (is_cname || r.data == new.data)
+    });
+    if let Some(at) = same {
+        let existing = &records[at];
+        if existing.data == new.data && existing.record.ttl == new.record.ttl {
+            return;
+        }
+        let old = records.remove(at);
+        if old.record.id.is_some() {
+            removed.push(old.record);
+        }
+    }
+    records.push(new);
+}
+
+/// Removes the records matching `matches`, keeping the stored ones in
+/// `removed`.
+fn take(
+    records: &mut Vec<ZoneRecord>,
+    removed: &mut Vec<LocalRecord>,
+    matches: impl Fn(&ZoneRecord) -> bool,
+) {
+    records.retain(|r| {
+        if !matches(r) {
+            return true;
+        }
+        if r.record.id.is_some() {
+            removed.push(r.record.clone());
+        }
+        false
+    });
+}
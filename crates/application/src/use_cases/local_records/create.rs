@@ -1,100 +1,87 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use ferrous_dns_domain::{Config, DomainError, LocalDnsRecord, RecordType};
+use ferrous_dns_domain::{
+    Config, DomainError, LocalDnsRecord, LocalRecord, RecordType, DEFAULT_LOCAL_RECORD_TTL,
+};
 use tokio::sync::RwLock;
+use tracing::{info, instrument};
 
-use crate::ports::{ConfigRepository, DnsCachePort, LocalRecordCreator, PtrRecordRegistry};
+use super::{
+    ensure_no_cname_conflict, ensure_view_exists, ensure_wildcard_has_parent, LivePropagation,
+};
+use crate::ports::{
+    DnsCachePort, LocalRecordCreator, LocalRecordRepository, LocalZonePort, PtrRecordRegistry,
+    SplitHorizonPort,
+};
 
 pub struct CreateLocalRecordUseCase {
     config: Arc<RwLock<Config>>,
-    config_repo: Arc<dyn ConfigRepository>,
-    ptr_registry: Option<Arc<dyn PtrRecordRegistry>>,
-    dns_cache: Option<Arc<dyn DnsCachePort>>,
+    repo: Arc<dyn LocalRecordRepository>,
+    zone: Arc<dyn LocalZonePort>,
+    live: LivePropagation,
 }
 
 impl CreateLocalRecordUseCase {
-    pub fn new(config: Arc<RwLock<Config>>, config_repo: Arc<dyn ConfigRepository>) -> Self {
+    pub fn new(
+        config: Arc<RwLock<Config>>,
+        repo: Arc<dyn LocalRecordRepository>,
+        zone: Arc<dyn LocalZonePort>,
+    ) -> Self {
         Self {
             config,
-            config_repo,
-            ptr_registry: None,
-            dns_cache: None,
+            repo,
+            zone,
+            live: LivePropagation::default(),
         }
     }
 
     /// Attaches a live PTR registry so that a successful record creation immediately
     /// registers the new IP → FQDN mapping without requiring a server restart.
     pub fn with_ptr_registry(mut self, registry: Option<Arc<dyn PtrRecordRegistry>>) -> Self {
-        self.ptr_registry = registry;
+        self.live.ptr_registry = registry;
         self
     }
 
     /// Attaches a live DNS cache so that a successful record creation immediately
-    /// inserts the forward record (A/AAAA) into the cache without requiring a server restart.
+    /// evicts cached answers for the record's name.
     pub fn with_dns_cache(mut self, cache: Option<Arc<dyn DnsCachePort>>) -> Self {
-        self.dns_cache = cache;
+        self.live.dns_cache = cache;
         self
     }
 
-    pub async fn execute(
-        &self,
-        hostname: String,
-        domain: Option<String>,
-        ip: String,
-        record_type: String,
-        ttl: Option<u32>,
-    ) -> Result<(LocalDnsRecord, usize), DomainError> {
-        let parsed_ip = ip
-            .parse::<std::net::IpAddr>()
-            .map_err(|_| DomainError::InvalidIpAddress("Invalid IP address".to_string()))?;
-
-        let record_type_upper = record_type.to_uppercase();
-        let parsed_record_type = record_type_upper
-            .parse::<RecordType>()
-            .ok()
-            .filter(|rt| matches!(rt, RecordType::A | RecordType::AAAA))
-            .ok_or_else(|| {
-                DomainError::InvalidDomainName(
-                    "Invalid record type (must be A or AAAA)".to_string(),
-                )
-            })?;
-
-        let new_record = LocalDnsRecord {
-            hostname,
-            domain,
-            ip,
-            record_type: record_type_upper,
-            ttl,
-        };
+    /// Attaches the live split-horizon views so that a record created in a
+    /// view is answered for that view's clients without a server restart.
+    pub fn with_split_horizon(mut self, views: Option<Arc<dyn SplitHorizonPort>>) -> Self {
+        self.live.split_horizon = views;
+        self
+    }
 
-        let mut config = self.config.write().await;
-        config.dns.local_records.push(new_record.clone());
-        let new_index = config.dns.local_records.len() - 1;
+    #[instrument(skip(self))]
+    pub async fn execute(&self, record: LocalRecord) -> Result<LocalRecord, DomainError> {
+        record.validate().map_err(DomainError::InvalidLocalRecord)?;
 
-        if let Err(e) = self.config_repo.save_local_records(&config).await {
-            config.dns.local_records.pop();
-            return Err(DomainError::IoError(format!(
-                "Failed to save configuration: {}",
-                e
-            )));
-        }
+        let config = self.config.read().await;
+        ensure_view_exists(&config, &record)?;
+        ensure_wildcard_has_parent(&config, &record)?;
+        let existing = self.repo.get_all().await?;
+        ensure_no_cname_conflict(&config, &record, &existing)?;
 
-        let fqdn = new_record.fqdn(&config.dns.local_domain);
+        let created = self.repo.create(&record).await?;
 
-        if let Some(ref registry) = self.ptr_registry {
-            registry.register(
-                parsed_ip,
-                Arc::from(fqdn.as_str()),
-                new_record.ttl_or_default(),
-            );
-        }
+        info!(
+            record_id = ?created.id,
+            fqdn = %created.fqdn(&config.dns.local_domain),
+            record_type = %created.record_type,
+            value = %created.value,
+            "Local record created successfully"
+        );
 
-        if let Some(ref cache) = self.dns_cache {
-            cache.insert_permanent_record(&fqdn, parsed_record_type, vec![parsed_ip]);
-        }
+        self.live
+            .apply(self.zone.as_ref(), &config, None, Some(&created))
+            .await;
 
-        Ok((new_record, new_index))
+        Ok(created)
     }
 }
 
@@ -107,9 +94,26 @@ impl LocalRecordCreator for CreateLocalRecordUseCase {
         ip: String,
         record_type: String,
         ttl: Option<u32>,
+        view: Option<String>,
     ) -> Result<LocalDnsRecord, DomainError> {
-        self.execute(hostname, domain, ip, record_type, ttl)
+        let record_type = record_type
+            .parse::<RecordType>()
+            .map_err(DomainError::InvalidLocalRecord)?;
+        let record = LocalRecord {
+            domain: domain.map(Arc::from),
+            ttl: ttl.unwrap_or(DEFAULT_LOCAL_RECORD_TTL),
+            view: view.map(Arc::from),
+            ..LocalRecord::new(Arc::from(hostname), record_type, Arc::from(ip))
+        };
+        self.execute(record)
             .await
-            .map(|(record, _index)| record)
+            .map(|created| created.to_config_record())
+    }
+
+    async fn existing_local_records(&self) -> Result<Vec<LocalDnsRecord>, DomainError> {
+        let mut records = self.config.read().await.dns.local_records.clone();
+        let stored = self.repo.get_all().await?;
+        records.extend(stored.iter().map(LocalRecord::to_config_record));
+        Ok(records)
     }
 }
@@ -1,84 +1,100 @@
 use std::sync::Arc;
 
-use ferrous_dns_domain::{Config, DomainError, LocalDnsRecord, RecordType};
+use ferrous_dns_domain::{Config, DomainError, LocalRecord};
 use tokio::sync::RwLock;
-use tracing::warn;
+use tracing::{info, instrument};
 
-use crate::ports::{ConfigRepository, DnsCachePort, PtrRecordRegistry};
+use super::LivePropagation;
+use crate::ports::{
+    DnsCachePort, LocalRecordRepository, LocalZonePort, PtrRecordRegistry, SplitHorizonPort,
+};
 
 pub struct DeleteLocalRecordUseCase {
     config: Arc<RwLock<Config>>,
-    config_repo: Arc<dyn ConfigRepository>,
-    ptr_registry: Option<Arc<dyn PtrRecordRegistry>>,
-    dns_cache: Option<Arc<dyn DnsCachePort>>,
+    repo: Arc<dyn LocalRecordRepository>,
+    zone: Arc<dyn LocalZonePort>,
+    live: LivePropagation,
 }
 
 impl DeleteLocalRecordUseCase {
-    pub fn new(config: Arc<RwLock<Config>>, config_repo: Arc<dyn ConfigRepository>) -> Self {
+    pub fn new(
+        config: Arc<RwLock<Config>>,
+        repo: Arc<dyn LocalRecordRepository>,
+        zone: Arc<dyn LocalZonePort>,
+    ) -> Self {
         Self {
             config,
-            config_repo,
-            ptr_registry: None,
-            dns_cache: None,
+            repo,
+            zone,
+            live: LivePropagation::default(),
         }
     }
 
     /// Attaches a live PTR registry so that a successful record deletion immediately
     /// removes the IP → FQDN mapping without requiring a server restart.
     pub fn with_ptr_registry(mut self, registry: Option<Arc<dyn PtrRecordRegistry>>) -> Self {
-        self.ptr_registry = registry;
+        self.live.ptr_registry = registry;
         self
     }
 
     /// Attaches a live DNS cache so that a successful record deletion immediately
-    /// removes the forward record (A/AAAA) from the cache without requiring a server restart.
+    /// evicts cached answers for the record's name.
     pub fn with_dns_cache(mut self, cache: Option<Arc<dyn DnsCachePort>>) -> Self {
-        self.dns_cache = cache;
+        self.live.dns_cache = cache;
         self
     }
 
-    pub async fn execute(&self, id: i64) -> Result<LocalDnsRecord, DomainError> {
-        let mut config = self.config.write().await;
+    /// Attaches the live split-horizon views so that deleting a view record
+    /// stops it from being answered without a server restart.
+    pub fn with_split_horizon(mut self, views: Option<Arc<dyn SplitHorizonPort>>) -> Self {
+        self.live.split_horizon = views;
+        self
+    }
 
-        let idx = id as usize;
-        if idx >= config.dns.local_records.len() {
-            return Err(DomainError::NotFound(format!(
-                "Record with id {} not found",
-                id
-            )));
-        }
+    /// Deletes record `id` and returns it.
+    #[instrument(skip(self))]
+    pub async fn execute(&self, id: i64) -> Result<LocalRecord, DomainError> {
+        let removed = self
+            .repo
+            .get_by_id(id)
+            .await?
+            .ok_or(DomainError::LocalRecordNotFound(id))?;
 
-        let removed_record = config.dns.local_records.remove(idx);
+        self.remove(id, removed).await
+    }
 
-        if let Err(e) = self.config_repo.save_local_records(&config).await {
-            config.dns.local_records.insert(idx, removed_record.clone());
-            return Err(DomainError::IoError(format!(
-                "Failed to save configuration: {}",
-                e
-            )));
-        }
+    /// Deletes record `id` of `tenant_id`. Records of other tenants are
+    /// reported as not found.
+    #[instrument(skip(self))]
+    pub async fn execute_in_tenant(
+        &self,
+        tenant_id: i64,
+        id: i64,
+    ) -> Result<LocalRecord, DomainError> {
+        let removed = self
+            .repo
+            .get_by_id_in_tenant(tenant_id, id)
+            .await?
+            .ok_or(DomainError::LocalRecordNotFound(id))?;
 
-        if let Some(ref registry) = self.ptr_registry {
-            match removed_record.ip.parse() {
-                Ok(ip) => registry.unregister(ip),
-                Err(_) => {
-                    warn!(ip = %removed_record.ip, "PTR registry: failed to parse IP after delete");
-                }
-            }
-        }
+        self.remove(id, removed).await
+    }
 
-        if let Some(ref cache) = self.dns_cache {
-            let fqdn = removed_record.fqdn(&config.dns.local_domain);
-            if let Ok(record_type) = removed_record.record_type.parse::<RecordType>() {
-                cache.remove_record(&fqdn, &record_type);
-            } else {
-                warn!(
-                    record_type = %removed_record.record_type,
-                    "DNS cache: unrecognised record type on removed record, skipping eviction"
-                );
-            }
-        }
+    async fn remove(&self, id: i64, removed: LocalRecord) -> Result<LocalRecord, DomainError> {
+        self.repo.delete(id).await?;
+
+        let config = self.config.read().await;
+        info!(
+            record_id = id,
+            fqdn = %removed.fqdn(&config.dns.local_domain),
+            record_type = %removed.record_type,
+            "Local record deleted successfully"
+        );
+
+        self.live
+            .apply(self.zone.as_ref(), &config, Some(&removed), None)
+            .await;
 
-        Ok(removed_record)
+        Ok(removed)
     }
 }
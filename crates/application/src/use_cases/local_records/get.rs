@@ -0,0 +1,31 @@
+use ferrous_dns_domain::{DomainError, LocalRecord};
+use std::sync::Arc;
+use tracing::instrument;
+
+use crate::ports::LocalRecordRepository;
+
+pub struct GetLocalRecordsUseCase {
+    repo: Arc<dyn LocalRecordRepository>,
+}
+
+impl GetLocalRecordsUseCase {
+    pub fn new(repo: Arc<dyn LocalRecordRepository>) -> Self {
+        Self { repo }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_all(&self) -> Result<Vec<LocalRecord>, DomainError> {
+        self.repo.get_all().await
+    }
+
+    /// Records owned by `tenant_id`.
+    #[instrument(skip(self))]
+    pub async fn get_all_in_tenant(&self, tenant_id: i64) -> Result<Vec<LocalRecord>, DomainError> {
+        self.repo.get_all_in_tenant(tenant_id).await
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_by_id(&self, id: i64) -> Result<Option<LocalRecord>, DomainError> {
+        self.repo.get_by_id(id).await
+    }
+}
@@ -1,7 +1,146 @@
+pub mod apply_dns_update;
 pub mod create;
 pub mod delete;
+pub mod get;
+pub mod sync_source;
 pub mod update;
 
+pub use apply_dns_update::ApplyDnsUpdateUseCase;
 pub use create::CreateLocalRecordUseCase;
 pub use delete::DeleteLocalRecordUseCase;
+pub use get::GetLocalRecordsUseCase;
+pub use sync_source::{RecordSourceSync, SyncRecordSourceUseCase};
 pub use update::UpdateLocalRecordUseCase;
+
+use std::sync::Arc;
+
+use ferrous_dns_domain::{Config, DomainError, LocalRecord, RecordType, LOCAL_RECORD_TYPES};
+use tracing::error;
+
+use crate::ports::{
+    CacheEntryState, DnsCachePort, LocalZonePort, PtrRecordRegistry, SplitHorizonPort,
+};
+
+/// Rejects a record tagged with a view that is not defined in `dns.views`.
+fn ensure_view_exists(config: &Config, record: &LocalRecord) -> Result<(), DomainError> {
+    match record.view {
+        Some(ref view) if !config.dns.views.iter().any(|v| v.name == **view) => Err(
+            DomainError::InvalidInput(format!("Unknown split-horizon view '{}'", view)),
+        ),
+        _ => Ok(()),
+    }
+}
+
+/// Rejects a bare `*` record when there is no local domain to put it under,
+/// as it would otherwise match every name.
+fn ensure_wildcard_has_parent(config: &Config, record: &LocalRecord) -> Result<(), DomainError> {
+    if record.fqdn(&config.dns.local_domain) == "*" {
+        return Err(DomainError::InvalidLocalRecord(
+            "A '*' record needs a domain, or dns.local_domain to be set".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Rejects a CNAME sharing its name with any other zone record, and any
+/// record added next to an existing CNAME (RFC 1034 §3.6.2). `existing`
+/// must not contain the record being replaced. Records of different
+/// tenants never conflict.
+fn ensure_no_cname_conflict(
+    config: &Config,
+    record: &LocalRecord,
+    existing: &[LocalRecord],
+) -> Result<(), DomainError> {
+    if record.view.is_some() {
+        return Ok(());
+    }
+    let fqdn = record.fqdn(&config.dns.local_domain);
+    let clash = existing.iter().find(|other| {
+        other.id != record.id
+            && other.view.is_none()
+            && other.tenant_id == record.tenant_id
+            && (record.record_type == RecordType::CNAME || other.record_type == RecordType::CNAME)
+            && other.fqdn(&config.dns.local_domain) == fqdn
+    });
+    match clash {
+        Some(other) => Err(DomainError::LocalRecordConflict(format!(
+            "'{}' already has a {} record; a CNAME cannot share its name with other records",
+            fqdn, other.record_type
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Resolver state that follows local-record changes without a restart.
+#[derive(Default)]
+struct LivePropagation {
+    ptr_registry: Option<Arc<dyn PtrRecordRegistry>>,
+    dns_cache: Option<Arc<dyn DnsCachePort>>,
+    split_horizon: Option<Arc<dyn SplitHorizonPort>>,
+}
+
+impl LivePropagation {
+    /// Reloads the local zone, evicts cached answers for the names of
+    /// `removed` and `added`, and updates the PTR registry and the
+    /// split-horizon views.
+    async fn apply(
+        &self,
+        zone: &dyn LocalZonePort,
+        config: &Config,
+        removed: Option<&LocalRecord>,
+        added: Option<&LocalRecord>,
+    ) {
+        self.apply_all(zone, config, removed.as_slice(), added.as_slice())
+            .await
+    }
+
+    /// [`apply`](Self::apply) for a batch of changes, with a single reload.
+    async fn apply_all(
+        &self,
+        zone: &dyn LocalZonePort,
+        config: &Config,
+        removed: &[&LocalRecord],
+        added: &[&LocalRecord],
+    ) {
+        if let Err(e) = zone.reload().await {
+            error!(error = %e, "Failed to reload local zone after a record change");
+        }
+
+        let local_domain = &config.dns.local_domain;
+        if let Some(ref cache) = self.dns_cache {
+            for record in removed.iter().chain(added) {
+                let fqdn = record.fqdn(local_domain);
+                for record_type in LOCAL_RECORD_TYPES {
+                    // Permanent entries are `[[dns.local_records]]` from the
+                    // config file, which this change does not touch.
+                    let permanent = cache
+                        .peek(&fqdn, record_type)
+                        .is_some_and(|e| e.state == CacheEntryState::Permanent);
+                    if !permanent {
+                        cache.remove_record(&fqdn, &record_type);
+                    }
+                }
+            }
+        }
+
+        if let Some(ref registry) = self.ptr_registry {
+            for ip in removed.iter().filter_map(|record| record.ptr_address()) {
+                registry.unregister(ip);
+            }
+            for record in added {
+                if let Some(ip) = record.ptr_address() {
+                    registry.register(ip, Arc::from(record.fqdn(local_domain)), record.ttl);
+                }
+            }
+        }
+
+        let views_changed = removed.iter().chain(added).any(|r| r.view.is_some());
+        if views_changed {
+            if let Some(ref views) = self.split_horizon {
+                let mut dns = config.dns.clone();
+                dns.local_records.extend(zone.view_records());
+                views.reload(&dns);
+            }
+        }
+    }
+}
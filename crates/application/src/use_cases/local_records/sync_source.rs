@@ -0,0 +1,135 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use ferrous_dns_domain::{Config, DomainError, LocalRecord};
+use tokio::sync::{Mutex, RwLock};
+use tracing::{debug, info, instrument, warn};
+
+use super::LivePropagation;
+use crate::ports::{DnsCachePort, LocalZonePort, PtrRecordRegistry, RecordSource};
+
+/// What one sync changed in the local zone.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RecordSourceSync {
+    pub published: usize,
+    pub added: usize,
+    pub removed: usize,
+}
+
+/// Publishes the records of a [`RecordSource`] in the local zone. Only
+/// changes reach the zone, the cache and the PTR registry; a failed fetch
+/// keeps the previously published records.
+pub struct SyncRecordSourceUseCase {
+    config: Arc<RwLock<Config>>,
+    source: Arc<dyn RecordSource>,
+    zone: Arc<dyn LocalZonePort>,
+    live: LivePropagation,
+    published: Mutex<Vec<LocalRecord>>,
+}
+
+impl SyncRecordSourceUseCase {
+    pub fn new(
+        config: Arc<RwLock<Config>>,
+        source: Arc<dyn RecordSource>,
+        zone: Arc<dyn LocalZonePort>,
+    ) -> Self {
+        Self {
+            config,
+            source,
+            zone,
+            live: LivePropagation::default(),
+            published: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Attaches a live PTR registry so that published addresses also answer
+    /// reverse lookups.
+    pub fn with_ptr_registry(mut self, registry: Option<Arc<dyn PtrRecordRegistry>>) -> Self {
+        self.live.ptr_registry = registry;
+        self
+    }
+
+    /// Attaches a live DNS cache so that cached answers for added or removed
+    /// names are evicted.
+    pub fn with_dns_cache(mut self, cache: Option<Arc<dyn DnsCachePort>>) -> Self {
+        self.live.dns_cache = cache;
+        self
+    }
+
+    pub fn source_name(&self) -> &'static str {
+        self.source.name()
+    }
+
+    pub fn sync_interval_secs(&self) -> u64 {
+        self.source.sync_interval_secs()
+    }
+
+    /// Resolves when the source reports a change; see
+    /// [`RecordSource::changed`].
+    pub async fn changed(&self) {
+        self.source.changed().await
+    }
+
+    #[instrument(skip(self), fields(source = self.source.name()))]
+    pub async fn execute(&self) -> Result<RecordSourceSync, DomainError> {
+        let source = self.source.name();
+        let mut records = self.source.fetch().await?;
+        records.retain(|record| match record.validate() {
+            Ok(()) => true,
+            Err(e) => {
+                warn!(source, hostname = %record.hostname, error = %e, "Skipping invalid record");
+                false
+            }
+        });
+        let mut seen = HashSet::new();
+        records.retain(|record| seen.insert(record_key(record)));
+
+        let mut published = self.published.lock().await;
+        let before: HashSet<_> = published.iter().map(record_key).collect();
+        let after: HashSet<_> = records.iter().map(record_key).collect();
+        let removed: Vec<&LocalRecord> = published
+            .iter()
+            .filter(|r| !after.contains(&record_key(r)))
+            .collect();
+        let added: Vec<&LocalRecord> = records
+            .iter()
+            .filter(|r| !before.contains(&record_key(r)))
+            .collect();
+
+        let sync = RecordSourceSync {
+            published: records.len(),
+            added: added.len(),
+            removed: removed.len(),
+        };
+        if added.is_empty() && removed.is_empty() {
+            debug!(source, records = records.len(), "Record source unchanged");
+            return Ok(sync);
+        }
+
+        self.zone.set_source_records(source, records.clone());
+        let config = self.config.read().await;
+        self.live
+            .apply_all(self.zone.as_ref(), &config, &removed, &added)
+            .await;
+        info!(
+            source,
+            records = sync.published,
+            added = sync.added,
+            removed = sync.removed,
+            "Record source synced"
+        );
+
+        *published = records;
+        Ok(sync)
+    }
+}
+
+/// Identity of a record within a source: everything that is answered.
+fn record_key(record: &LocalRecord) -> (String, &'static str, Arc<str>, u32) {
+    (
+        record.fqdn(&None),
+        record.record_type.as_str(),
+        Arc::clone(&record.value),
+        record.ttl,
+    )
+}
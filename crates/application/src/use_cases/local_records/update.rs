@@ -1,125 +1,100 @@
 use std::sync::Arc;
 
-use ferrous_dns_domain::{Config, DomainError, LocalDnsRecord, RecordType};
+use ferrous_dns_domain::{Config, DomainError, LocalRecord};
 use tokio::sync::RwLock;
-use tracing::warn;
+use tracing::{info, instrument};
 
-use crate::ports::{ConfigRepository, DnsCachePort, PtrRecordRegistry};
+use super::{
+    ensure_no_cname_conflict, ensure_view_exists, ensure_wildcard_has_parent, LivePropagation,
+};
+use crate::ports::{
+    DnsCachePort, LocalRecordRepository, LocalZonePort, PtrRecordRegistry, SplitHorizonPort,
+};
 
 pub struct UpdateLocalRecordUseCase {
     config: Arc<RwLock<Config>>,
-    config_repo: Arc<dyn ConfigRepository>,
-    ptr_registry: Option<Arc<dyn PtrRecordRegistry>>,
-    dns_cache: Option<Arc<dyn DnsCachePort>>,
+    repo: Arc<dyn LocalRecordRepository>,
+    zone: Arc<dyn LocalZonePort>,
+    live: LivePropagation,
 }
 
 impl UpdateLocalRecordUseCase {
-    pub fn new(config: Arc<RwLock<Config>>, config_repo: Arc<dyn ConfigRepository>) -> Self {
+    pub fn new(
+        config: Arc<RwLock<Config>>,
+        repo: Arc<dyn LocalRecordRepository>,
+        zone: Arc<dyn LocalZonePort>,
+    ) -> Self {
         Self {
             config,
-            config_repo,
-            ptr_registry: None,
-            dns_cache: None,
+            repo,
+            zone,
+            live: LivePropagation::default(),
         }
     }
 
     /// Attaches a live PTR registry so that a successful record update immediately
-    /// swaps the old IP → FQDN mapping for the new one without requiring a server restart.
+    /// swaps the IP → FQDN mapping without requiring a server restart.
     pub fn with_ptr_registry(mut self, registry: Option<Arc<dyn PtrRecordRegistry>>) -> Self {
-        self.ptr_registry = registry;
+        self.live.ptr_registry = registry;
         self
     }
 
     /// Attaches a live DNS cache so that a successful record update immediately
-    /// swaps the old forward record (A/AAAA) for the new one without requiring a server restart.
+    /// evicts cached answers for both the old and the new name.
     pub fn with_dns_cache(mut self, cache: Option<Arc<dyn DnsCachePort>>) -> Self {
-        self.dns_cache = cache;
+        self.live.dns_cache = cache;
         self
     }
 
+    /// Attaches the live split-horizon views so that moving a record into or
+    /// out of a view takes effect without a server restart.
+    pub fn with_split_horizon(mut self, views: Option<Arc<dyn SplitHorizonPort>>) -> Self {
+        self.live.split_horizon = views;
+        self
+    }
+
+    /// Replaces the stored record `id` with `record`; returns the updated
+    /// and the previous record. The record keeps its tenant.
+    #[instrument(skip(self))]
     pub async fn execute(
         &self,
         id: i64,
-        hostname: String,
-        domain: Option<String>,
-        ip: String,
-        record_type: String,
-        ttl: Option<u32>,
-    ) -> Result<(LocalDnsRecord, LocalDnsRecord), DomainError> {
-        let new_parsed_ip = ip
-            .parse::<std::net::IpAddr>()
-            .map_err(|_| DomainError::InvalidIpAddress("Invalid IP address".to_string()))?;
-
-        let record_type_upper = record_type.to_uppercase();
-        let new_parsed_record_type = record_type_upper
-            .parse::<RecordType>()
-            .ok()
-            .filter(|rt| matches!(rt, RecordType::A | RecordType::AAAA))
-            .ok_or_else(|| {
-                DomainError::InvalidDomainName(
-                    "Invalid record type (must be A or AAAA)".to_string(),
-                )
-            })?;
-
-        let updated_record = LocalDnsRecord {
-            hostname,
-            domain,
-            ip,
-            record_type: record_type_upper,
-            ttl,
-        };
-
-        let mut config = self.config.write().await;
-
-        let idx = id as usize;
-        if idx >= config.dns.local_records.len() {
-            return Err(DomainError::NotFound(format!(
-                "Record with id {} not found",
-                id
-            )));
+        mut record: LocalRecord,
+    ) -> Result<(LocalRecord, LocalRecord), DomainError> {
+        record.validate().map_err(DomainError::InvalidLocalRecord)?;
+        record.id = Some(id);
+
+        let old = self
+            .repo
+            .get_by_id(id)
+            .await?
+            .ok_or(DomainError::LocalRecordNotFound(id))?;
+        // A record stays with the tenant that owns it.
+        record.tenant_id = old.tenant_id;
+        if record.tenant_id.is_some() {
+            record.validate().map_err(DomainError::InvalidLocalRecord)?;
         }
 
-        let old_record = config.dns.local_records[idx].clone();
-        config.dns.local_records[idx] = updated_record.clone();
+        let config = self.config.read().await;
+        ensure_view_exists(&config, &record)?;
+        ensure_wildcard_has_parent(&config, &record)?;
+        let existing = self.repo.get_all().await?;
+        ensure_no_cname_conflict(&config, &record, &existing)?;
 
-        if let Err(e) = self.config_repo.save_local_records(&config).await {
-            config.dns.local_records[idx] = old_record;
-            return Err(DomainError::IoError(format!(
-                "Failed to save configuration: {}",
-                e
-            )));
-        }
+        let updated = self.repo.update(&record).await?;
 
-        let old_fqdn = old_record.fqdn(&config.dns.local_domain);
-        let new_fqdn = updated_record.fqdn(&config.dns.local_domain);
+        info!(
+            record_id = id,
+            fqdn = %updated.fqdn(&config.dns.local_domain),
+            record_type = %updated.record_type,
+            value = %updated.value,
+            "Local record updated successfully"
+        );
 
-        if let Some(ref registry) = self.ptr_registry {
-            if let Ok(old_ip) = old_record.ip.parse() {
-                registry.unregister(old_ip);
-            } else {
-                warn!(ip = %old_record.ip, "PTR registry: failed to parse old IP after update");
-            }
-            registry.register(
-                new_parsed_ip,
-                Arc::from(new_fqdn.as_str()),
-                updated_record.ttl_or_default(),
-            );
-        }
-
-        if let Some(ref cache) = self.dns_cache {
-            // old_record.record_type was already validated when first created; parse
-            // defensively and skip eviction only if the stored value is somehow invalid.
-            if let Ok(old_rt) = old_record.record_type.parse::<RecordType>() {
-                cache.remove_record(&old_fqdn, &old_rt);
-            } else {
-                warn!(
-                    record_type = %old_record.record_type,
-                    "DNS cache: unrecognised record type on old record, skipping eviction"
-                );
-            }
-            cache.insert_permanent_record(&new_fqdn, new_parsed_record_type, vec![new_parsed_ip]);
-        }
+        self.live
+            .apply(self.zone.as_ref(), &config, Some(&old), Some(&updated))
+            .await;
 
-        Ok((updated_record, old_record))
+        Ok((updated, old))
     }
 }
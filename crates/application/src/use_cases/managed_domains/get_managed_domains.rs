@@ -1,4 +1,4 @@
-use ferrous_dns_domain::{DomainError, ManagedDomain};
+use ferrous_dns_domain::{DomainError, ManagedDomain, PageRequest};
 use std::sync::Arc;
 use tracing::instrument;
 
@@ -21,10 +21,9 @@ impl GetManagedDomainsUseCase {
     #[instrument(skip(self))]
     pub async fn get_all_paged(
         &self,
-        limit: u32,
-        offset: u32,
+        page: &PageRequest,
     ) -> Result<(Vec<ManagedDomain>, u64), DomainError> {
-        self.repo.get_all_paged(limit, offset).await
+        self.repo.get_all_paged(page).await
     }
 
     #[instrument(skip(self))]
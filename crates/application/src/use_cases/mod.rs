@@ -1,4 +1,6 @@
+pub mod alerts;
 pub mod api_tokens;
+pub mod audit_log;
 pub mod auth;
 pub mod backup;
 pub mod block_filter;
@@ -6,74 +8,129 @@ pub mod blocked_services;
 pub mod blocklist;
 pub mod blocklist_sources;
 pub mod cache;
+pub mod cache_ttl_overrides;
 pub mod client_subnets;
 pub mod clients;
 pub mod config;
 pub mod custom_services;
+pub mod database;
+pub mod debug;
 pub mod dns;
+pub mod dns_rewrites;
+pub mod dnssec;
+pub mod external_import;
 pub mod groups;
+pub mod ip_blocklist_sources;
 pub mod local_records;
 pub mod managed_domains;
 pub mod queries;
+pub mod query_policies;
+pub mod record_type_policies;
 pub mod regex_filters;
+pub mod reports;
 pub mod safe_search;
 pub mod schedule;
+pub mod setup;
+pub mod standby;
+pub mod tenants;
+pub mod tld_policies;
+pub mod unblock_requests;
 pub mod users;
 pub mod whitelist;
 pub mod whitelist_sources;
 
+pub use alerts::{DeleteAlertUseCase, DetectAnomaliesUseCase, GetAlertsUseCase};
 pub use api_tokens::{
     CreateApiTokenUseCase, CreatedApiToken, DeleteApiTokenUseCase, GetApiTokensUseCase,
     UpdateApiTokenUseCase, ValidateApiTokenUseCase,
 };
+pub use audit_log::GetAuditLogUseCase;
 pub use auth::{
     AuthStatus, ChangePasswordUseCase, GetActiveSessionsUseCase, GetAuthStatusUseCase,
     LoginUseCase, LogoutUseCase, SetupPasswordUseCase, ValidateSessionUseCase,
 };
-pub use backup::{BackupSnapshot, ExportConfigUseCase, ImportConfigUseCase, ImportSummary};
+pub use backup::{
+    BackupFormat, BackupSnapshot, CreateBackupUseCase, ExportConfigUseCase, FullBackup,
+    ImportConfigUseCase, ImportSummary, RestoreBackupUseCase, RestoreSummary,
+};
 pub use block_filter::GetBlockFilterStatsUseCase;
 pub use blocked_services::{
     BlockServiceUseCase, GetBlockedServicesUseCase, GetServiceCatalogUseCase, UnblockServiceUseCase,
 };
-pub use blocklist::GetBlocklistUseCase;
+pub use blocklist::{
+    BulkAddBlocklistUseCase, BulkAddSummary, BulkEntryError, GetBlocklistUseCase, MAX_BULK_DOMAINS,
+};
 pub use blocklist_sources::{
     CreateBlocklistSourceUseCase, DeleteBlocklistSourceUseCase, GetBlocklistSourcesUseCase,
     UpdateBlocklistSourceUseCase,
 };
 pub use cache::GetCacheStatsUseCase;
+pub use cache_ttl_overrides::{
+    CreateCacheTtlOverrideUseCase, DeleteCacheTtlOverrideUseCase, GetCacheTtlOverridesUseCase,
+    UpdateCacheTtlOverrideUseCase,
+};
 pub use client_subnets::{
     CreateClientSubnetUseCase, DeleteClientSubnetUseCase, GetClientSubnetsUseCase,
 };
 pub use clients::{
-    CleanupOldClientsUseCase, CreateManualClientUseCase, DeleteClientUseCase, GetClientsUseCase,
-    SyncArpCacheUseCase, SyncHostnamesUseCase, TrackClientUseCase, UpdateClientUseCase,
+    CleanupOldClientsUseCase, ClientMetadataUpdate, CreateManualClientUseCase, DeleteClientUseCase,
+    GetClientActivityUseCase, GetClientsUseCase, LabelForwardersUseCase,
+    MergeDuplicateClientsUseCase, SyncArpCacheUseCase, SyncHostnamesUseCase, TrackClientUseCase,
+    UpdateClientUseCase,
 };
 pub use config::ReloadConfigUseCase;
 pub use custom_services::{
     CreateCustomServiceUseCase, DeleteCustomServiceUseCase, GetCustomServicesUseCase,
     UpdateCustomServiceUseCase,
 };
+pub use database::{DatabaseMaintenanceReport, DatabaseMaintenanceUseCase, DatabaseStatus};
+pub use debug::{
+    DiagnoseDomainUseCase, DiagnosisOutcome, DomainDiagnosis, ResolutionTrace, TraceResolveUseCase,
+    TraceStage, TraceStep,
+};
 pub use dns::HandleDnsQueryUseCase;
+pub use dns_rewrites::{
+    CreateDnsRewriteUseCase, DeleteDnsRewriteUseCase, GetDnsRewritesUseCase,
+    UpdateDnsRewriteUseCase,
+};
+pub use dnssec::{
+    DnssecDomainStatus, DnssecStats, GetDnssecStatsUseCase, GetDnssecStatusUseCase,
+    RecordDnssecHistoryUseCase,
+};
+pub use external_import::{ExternalImportSummary, ImportExternalConfigUseCase};
 pub use groups::{
     AssignClientGroupUseCase, CreateGroupUseCase, DeleteGroupUseCase, GetGroupsUseCase,
     UpdateGroupUseCase,
 };
+pub use ip_blocklist_sources::{
+    CreateIpBlocklistSourceUseCase, DeleteIpBlocklistSourceUseCase, GetIpBlocklistSourcesUseCase,
+    UpdateIpBlocklistSourceUseCase,
+};
 pub use local_records::{
-    CreateLocalRecordUseCase, DeleteLocalRecordUseCase, UpdateLocalRecordUseCase,
+    ApplyDnsUpdateUseCase, CreateLocalRecordUseCase, DeleteLocalRecordUseCase,
+    GetLocalRecordsUseCase, RecordSourceSync, SyncRecordSourceUseCase, UpdateLocalRecordUseCase,
 };
 pub use managed_domains::{
     CreateManagedDomainUseCase, DeleteManagedDomainUseCase, GetManagedDomainsUseCase,
     UpdateManagedDomainUseCase,
 };
 pub use queries::{
-    CleanupOldQueryLogsUseCase, GetQueryRateUseCase, GetQueryStatsUseCase, GetRecentQueriesUseCase,
-    GetTimelineUseCase, GetTopAllowedDomainsUseCase, GetTopBlockedDomainsUseCase,
-    GetTopClientsUseCase, PagedQueryInput, QueryRate, RateUnit,
+    CleanupOldQueryLogsUseCase, ExportMetricsUseCase, GetQueryRateUseCase, GetQueryStatsUseCase,
+    GetRecentQueriesUseCase, GetTimelineUseCase, GetTopAllowedDomainsUseCase,
+    GetTopBlockedDomainsUseCase, GetTopClientsUseCase, PagedQueryInput, QueryRate, RateUnit,
+};
+pub use query_policies::{
+    CreateQueryPolicyUseCase, DeleteQueryPolicyUseCase, GetQueryPoliciesUseCase,
+    UpdateQueryPolicyUseCase,
+};
+pub use record_type_policies::{
+    DeleteRecordTypePolicyUseCase, GetRecordTypePoliciesUseCase, SetRecordTypePolicyUseCase,
 };
 pub use regex_filters::{
     CreateRegexFilterUseCase, DeleteRegexFilterUseCase, GetRegexFiltersUseCase,
     UpdateRegexFilterUseCase,
 };
+pub use reports::{SendUsageReportUseCase, UsageReport};
 pub use safe_search::{
     DeleteSafeSearchConfigsUseCase, GetSafeSearchConfigsUseCase, ToggleSafeSearchUseCase,
 };
@@ -81,8 +138,24 @@ pub use schedule::{
     AssignScheduleProfileUseCase, CreateScheduleProfileUseCase, DeleteScheduleProfileUseCase,
     GetScheduleProfilesUseCase, ManageTimeSlotsUseCase, UpdateScheduleProfileUseCase,
 };
+pub use setup::{
+    ListenerSettings, SetupOptions, SetupRequest, SetupSummary, SetupWizardUseCase,
+    SuggestedBlocklist, SUGGESTED_BLOCKLISTS,
+};
+pub use standby::{
+    ExportPrimaryStateUseCase, StandbyStatus, SyncFromPrimaryUseCase, MAX_EXPORTED_CACHE_ENTRIES,
+};
+pub use tenants::{
+    CreateTenantGroupUseCase, CreateTenantUseCase, DeleteTenantUseCase, GetTenantsUseCase,
+    TenantBlocklistSourcesUseCase, UpdateTenantUseCase,
+};
+pub use tld_policies::{DeleteTldPolicyUseCase, GetTldPoliciesUseCase, SetTldPolicyUseCase};
+pub use unblock_requests::{
+    ApproveUnblockRequestUseCase, DeleteUnblockRequestUseCase, DenyUnblockRequestUseCase,
+    GetUnblockRequestsUseCase, SubmitUnblockRequestUseCase,
+};
 pub use users::{CreateUserUseCase, DeleteUserUseCase, GetUsersUseCase};
-pub use whitelist::GetWhitelistUseCase;
+pub use whitelist::{BulkAddWhitelistUseCase, GetWhitelistUseCase};
 pub use whitelist_sources::{
     CreateWhitelistSourceUseCase, DeleteWhitelistSourceUseCase, GetWhitelistSourcesUseCase,
     UpdateWhitelistSourceUseCase,
@@ -0,0 +1,116 @@
+use crate::ports::{MetricsSink, MetricsWindow, QueryLogRepository};
+use ferrous_dns_domain::{DomainError, MetricsExportConfig};
+use std::fmt::Write;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::debug;
+
+/// Aggregates the last `interval_secs` of the query log and pushes it to a
+/// time-series database as InfluxDB line protocol: one `<measurement>` point
+/// with the totals and latency percentiles, and one `<measurement>_upstream`
+/// point per upstream server.
+pub struct ExportMetricsUseCase {
+    query_log: Arc<dyn QueryLogRepository>,
+    sink: Arc<dyn MetricsSink>,
+    config: MetricsExportConfig,
+}
+
+impl ExportMetricsUseCase {
+    pub fn new(
+        query_log: Arc<dyn QueryLogRepository>,
+        sink: Arc<dyn MetricsSink>,
+        config: MetricsExportConfig,
+    ) -> Self {
+        Self {
+            query_log,
+            sink,
+            config,
+        }
+    }
+
+    pub fn interval_secs(&self) -> u64 {
+        self.config.interval_secs
+    }
+
+    /// Writes one window of metrics, returning the number of points sent.
+    pub async fn execute(&self) -> Result<usize, DomainError> {
+        let window = self
+            .query_log
+            .get_metrics_window(self.config.interval_secs as i64)
+            .await?;
+        let timestamp_ns = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default();
+
+        let lines = self.render(&window, timestamp_ns);
+        let points = lines.lines().count();
+        self.sink.write(lines).await?;
+        debug!(points, queries = window.queries, "Metrics exported");
+        Ok(points)
+    }
+
+    fn render(&self, window: &MetricsWindow, timestamp_ns: u128) -> String {
+        let measurement = escape_measurement(&self.config.measurement);
+        let mut out = String::new();
+
+        out.push_str(&measurement);
+        self.push_tags(&mut out, &[]);
+        let _ = write!(
+            out,
+            " queries={}i,blocked={}i,cache_hits={}i,errors={}i",
+            window.queries, window.blocked, window.cache_hits, window.errors
+        );
+        for (field, value) in [
+            ("latency_p50_ms", window.latency_p50_ms),
+            ("latency_p90_ms", window.latency_p90_ms),
+            ("latency_p99_ms", window.latency_p99_ms),
+        ] {
+            if let Some(value) = value {
+                let _ = write!(out, ",{field}={value}");
+            }
+        }
+        let _ = writeln!(out, " {timestamp_ns}");
+
+        for upstream in &window.upstreams {
+            let _ = write!(out, "{measurement}_upstream");
+            self.push_tags(
+                &mut out,
+                &[("pool", &upstream.pool), ("server", &upstream.server)],
+            );
+            let _ = writeln!(
+                out,
+                " queries={}i,errors={}i,avg_latency_ms={} {timestamp_ns}",
+                upstream.queries, upstream.errors, upstream.avg_latency_ms
+            );
+        }
+        out
+    }
+
+    /// Configured tags plus `extra`, sorted by key as InfluxDB recommends.
+    fn push_tags(&self, out: &mut String, extra: &[(&str, &str)]) {
+        let mut tags: Vec<(&str, &str)> = self
+            .config
+            .tags
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .chain(extra.iter().copied())
+            .filter(|(_, v)| !v.is_empty())
+            .collect();
+        tags.sort_unstable_by_key(|(k, _)| *k);
+        for (key, value) in tags {
+            let _ = write!(out, ",{}={}", escape_tag(key), escape_tag(value));
+        }
+    }
+}
+
+fn escape_measurement(name: &str) -> String {
+    name.replace(',', "\\,").replace(' ', "\\ ")
+}
+
+fn escape_tag(value: &str) -> String {
+    value
+        .replace(',', "\\,")
+        .replace('=', "\\=")
+        .replace(' ', "\\ ")
+}
@@ -1,5 +1,8 @@
 use crate::ports::{PagedQueryResult, QueryLogRepository};
-use ferrous_dns_domain::query_log::{QueryCategory, QueryLog, QueryLogFilter};
+use chrono::{DateTime, Utc};
+use ferrous_dns_domain::query_log::{
+    parse_dnssec_status, QueryCategory, QueryLog, QueryLogFilter, QuerySource,
+};
 use ferrous_dns_domain::{DomainError, RecordType};
 use std::sync::Arc;
 
@@ -20,6 +23,13 @@ pub struct PagedQueryInput<'a> {
     pub client_ip: Option<&'a str>,
     pub record_type: Option<&'a str>,
     pub upstream: Option<&'a str>,
+    /// RFC 3339 timestamp or Unix seconds.
+    pub from: Option<&'a str>,
+    /// RFC 3339 timestamp or Unix seconds.
+    pub to: Option<&'a str>,
+    pub blocked: Option<bool>,
+    pub dnssec_status: Option<&'a str>,
+    pub query_source: Option<&'a str>,
 }
 
 pub struct GetRecentQueriesUseCase {
@@ -46,6 +56,8 @@ impl GetRecentQueriesUseCase {
     /// String parameters are validated and parsed into typed filter values.
     /// Invalid `category` or `record_type` returns `DomainError::InvalidInput`.
     /// Invalid `client_ip` format returns `DomainError::InvalidInput`.
+    /// Invalid `from`/`to`, `dnssec_status` or `query_source` values, and a
+    /// `from` that is not before `to`, return `DomainError::InvalidInput`.
     pub async fn execute_paged(
         &self,
         input: &PagedQueryInput<'_>,
@@ -73,12 +85,41 @@ impl GetRecentQueriesUseCase {
             })
             .transpose()?;
 
+        let from = parse_time_bound(input.from)?;
+        let to = parse_time_bound(input.to)?;
+        if let (Some(from), Some(to)) = (from, to) {
+            if from >= to {
+                return Err(DomainError::InvalidInput(
+                    "'from' must be earlier than 'to'".to_string(),
+                ));
+            }
+        }
+
+        let parsed_dnssec_status = input
+            .dnssec_status
+            .filter(|s| !s.is_empty())
+            .map(parse_dnssec_status)
+            .transpose()
+            .map_err(DomainError::InvalidInput)?;
+
+        let parsed_query_source = input
+            .query_source
+            .filter(|s| !s.is_empty())
+            .map(|s| s.parse::<QuerySource>())
+            .transpose()
+            .map_err(|e| DomainError::InvalidInput(e.to_string()))?;
+
         let filter = QueryLogFilter {
             domain: input.domain.filter(|d| !d.is_empty()).map(String::from),
             category: parsed_category,
             client_ip: parsed_client_ip,
             record_type: parsed_record_type,
             upstream: input.upstream.filter(|u| !u.is_empty()).map(String::from),
+            from,
+            to,
+            blocked: input.blocked,
+            dnssec_status: parsed_dnssec_status,
+            query_source: parsed_query_source,
         };
 
         self.repository
@@ -92,3 +133,17 @@ impl GetRecentQueriesUseCase {
             .await
     }
 }
+
+fn parse_time_bound(value: Option<&str>) -> Result<Option<DateTime<Utc>>, DomainError> {
+    let Some(value) = value.filter(|v| !v.is_empty()) else {
+        return Ok(None);
+    };
+    if let Ok(secs) = value.parse::<i64>() {
+        return DateTime::from_timestamp(secs, 0)
+            .map(Some)
+            .ok_or_else(|| DomainError::InvalidInput(format!("invalid timestamp: '{value}'")));
+    }
+    DateTime::parse_from_rfc3339(value)
+        .map(|t| Some(t.with_timezone(&Utc)))
+        .map_err(|_| DomainError::InvalidInput(format!("invalid timestamp: '{value}'")))
+}
@@ -1,10 +1,11 @@
-use crate::ports::{ClientRepository, QueryLogRepository};
+use crate::ports::{ClientRepository, QueryLogRepository, TimelineBreakdown};
 use ferrous_dns_domain::{query_log::QueryStats, DomainError};
 use std::sync::{Arc, RwLock};
 use std::time::{Duration, Instant};
 use tokio::sync::Mutex;
 
 const CACHE_TTL: Duration = Duration::from_secs(10);
+const TOP_CLIENTS_LIMIT: u32 = 10;
 
 struct CachedStats {
     computed_at: Instant,
@@ -53,12 +54,21 @@ impl GetQueryStatsUseCase {
             }
         }
 
-        let (stats_result, unique_clients) = tokio::join!(
+        let (stats_result, unique_clients, by_group, top_clients) = tokio::join!(
             self.repository.get_stats(period_hours),
-            self.client_repository.count_active_since(period_hours)
+            self.client_repository.count_active_since(period_hours),
+            self.repository
+                .get_query_counts_by(period_hours, TimelineBreakdown::Group, None),
+            self.repository.get_query_counts_by(
+                period_hours,
+                TimelineBreakdown::Client,
+                Some(TOP_CLIENTS_LIMIT)
+            )
         );
         let mut stats = stats_result?;
         stats.unique_clients = unique_clients?;
+        stats.queries_by_group = by_group?;
+        stats.top_clients = top_clients?;
 
         {
             let mut guard = self.cache.write().unwrap_or_else(|e| e.into_inner());
@@ -1,7 +1,18 @@
-use crate::ports::{QueryLogRepository, TimeGranularity, TimelineBucket};
+use crate::ports::{
+    QueryLogRepository, TimeGranularity, TimelineBreakdown, TimelineBucket, TimelineSeries,
+};
 use ferrous_dns_domain::DomainError;
 use std::sync::Arc;
 
+const MAX_PERIOD_HOURS: u32 = 720;
+const MAX_SERIES: u32 = 50;
+
+/// Upper bound on buckets per series; finer granularities are coarsened to fit.
+pub const MAX_TIMELINE_BUCKETS: u32 = 1_000;
+
+/// Bucket count `auto` aims to stay under, enough for a smooth chart.
+const AUTO_TARGET_BUCKETS: u32 = 300;
+
 pub struct GetTimelineUseCase {
     repository: Arc<dyn QueryLogRepository>,
 }
@@ -11,12 +22,50 @@ impl GetTimelineUseCase {
         Self { repository }
     }
 
+    /// Picks the granularity for a period.
+    ///
+    /// `None` (auto) selects the finest granularity that keeps the chart under
+    /// a few hundred buckets. An explicit granularity is honoured unless it
+    /// would exceed [`MAX_TIMELINE_BUCKETS`], in which case the next coarser
+    /// one that fits is used.
+    pub fn resolve_granularity(
+        period_hours: u32,
+        requested: Option<TimeGranularity>,
+    ) -> TimeGranularity {
+        let period = period_hours.clamp(1, MAX_PERIOD_HOURS);
+        let (floor, max_buckets) = match requested {
+            Some(g) => (g, MAX_TIMELINE_BUCKETS),
+            None => (TimeGranularity::Minute, AUTO_TARGET_BUCKETS),
+        };
+        TimeGranularity::ALL
+            .into_iter()
+            .filter(|g| g.bucket_minutes() >= floor.bucket_minutes())
+            .find(|g| g.bucket_count(period) <= max_buckets)
+            .unwrap_or(TimeGranularity::Day)
+    }
+
     pub async fn execute(
         &self,
         period_hours: u32,
         granularity: TimeGranularity,
     ) -> Result<Vec<TimelineBucket>, DomainError> {
-        let period = period_hours.min(720);
+        let period = period_hours.min(MAX_PERIOD_HOURS);
+        let granularity = Self::resolve_granularity(period, Some(granularity));
         self.repository.get_timeline(period, granularity).await
     }
+
+    /// Returns one timeline per client or group for the `limit` busiest keys.
+    pub async fn execute_breakdown(
+        &self,
+        period_hours: u32,
+        granularity: TimeGranularity,
+        breakdown: TimelineBreakdown,
+        limit: u32,
+    ) -> Result<Vec<TimelineSeries>, DomainError> {
+        let period = period_hours.min(MAX_PERIOD_HOURS);
+        let granularity = Self::resolve_granularity(period, Some(granularity));
+        self.repository
+            .get_timeline_breakdown(period, granularity, breakdown, limit.clamp(1, MAX_SERIES))
+            .await
+    }
 }
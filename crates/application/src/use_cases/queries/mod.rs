@@ -1,4 +1,5 @@
 pub mod cleanup_old_query_logs;
+pub mod export_metrics;
 pub mod get_rate;
 pub mod get_recent;
 pub mod get_stats;
@@ -8,6 +9,7 @@ pub mod get_top_blocked_domains;
 pub mod get_top_clients;
 
 pub use cleanup_old_query_logs::CleanupOldQueryLogsUseCase;
+pub use export_metrics::ExportMetricsUseCase;
 pub use get_rate::{GetQueryRateUseCase, QueryRate, RateUnit};
 pub use get_recent::{GetRecentQueriesUseCase, PagedQueryInput};
 pub use get_stats::GetQueryStatsUseCase;
@@ -0,0 +1,53 @@
+use ferrous_dns_domain::{DomainError, QueryPolicy};
+use std::sync::Arc;
+use tracing::{error, info, instrument};
+
+use crate::ports::{GroupRepository, QueryPolicyEnginePort, QueryPolicyRepository};
+
+pub struct CreateQueryPolicyUseCase {
+    repo: Arc<dyn QueryPolicyRepository>,
+    group_repo: Arc<dyn GroupRepository>,
+    policy_engine: Arc<dyn QueryPolicyEnginePort>,
+}
+
+impl CreateQueryPolicyUseCase {
+    pub fn new(
+        repo: Arc<dyn QueryPolicyRepository>,
+        group_repo: Arc<dyn GroupRepository>,
+        policy_engine: Arc<dyn QueryPolicyEnginePort>,
+    ) -> Self {
+        Self {
+            repo,
+            group_repo,
+            policy_engine,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(&self, policy: QueryPolicy) -> Result<QueryPolicy, DomainError> {
+        policy.validate().map_err(DomainError::InvalidQueryPolicy)?;
+
+        if let Some(gid) = policy.group_id {
+            self.group_repo
+                .get_by_id(gid)
+                .await?
+                .ok_or(DomainError::GroupNotFound(gid))?;
+        }
+
+        let created = self.repo.create(&policy).await?;
+
+        info!(
+            policy_id = ?created.id,
+            name = %created.name,
+            priority = created.priority,
+            action = %created.action.to_str(),
+            "Query policy created successfully"
+        );
+
+        if let Err(e) = self.policy_engine.reload().await {
+            error!(error = %e, "Failed to reload query policies after creation");
+        }
+
+        Ok(created)
+    }
+}
@@ -0,0 +1,40 @@
+use ferrous_dns_domain::DomainError;
+use std::sync::Arc;
+use tracing::{error, info, instrument};
+
+use crate::ports::{QueryPolicyEnginePort, QueryPolicyRepository};
+
+pub struct DeleteQueryPolicyUseCase {
+    repo: Arc<dyn QueryPolicyRepository>,
+    policy_engine: Arc<dyn QueryPolicyEnginePort>,
+}
+
+impl DeleteQueryPolicyUseCase {
+    pub fn new(
+        repo: Arc<dyn QueryPolicyRepository>,
+        policy_engine: Arc<dyn QueryPolicyEnginePort>,
+    ) -> Self {
+        Self {
+            repo,
+            policy_engine,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(&self, id: i64) -> Result<(), DomainError> {
+        self.repo
+            .get_by_id(id)
+            .await?
+            .ok_or(DomainError::QueryPolicyNotFound(id))?;
+
+        self.repo.delete(id).await?;
+
+        info!(policy_id = id, "Query policy deleted successfully");
+
+        if let Err(e) = self.policy_engine.reload().await {
+            error!(error = %e, "Failed to reload query policies after deletion");
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use ferrous_dns_domain::{DomainError, QueryPolicy};
+use std::sync::Arc;
+use tracing::instrument;
+
+use crate::ports::QueryPolicyRepository;
+
+pub struct GetQueryPoliciesUseCase {
+    repo: Arc<dyn QueryPolicyRepository>,
+}
+
+impl GetQueryPoliciesUseCase {
+    pub fn new(repo: Arc<dyn QueryPolicyRepository>) -> Self {
+        Self { repo }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_all(&self) -> Result<Vec<QueryPolicy>, DomainError> {
+        self.repo.get_all().await
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_by_id(&self, id: i64) -> Result<Option<QueryPolicy>, DomainError> {
+        self.repo.get_by_id(id).await
+    }
+}
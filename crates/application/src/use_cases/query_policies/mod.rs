@@ -0,0 +1,9 @@
+mod create_query_policy;
+mod delete_query_policy;
+mod get_query_policies;
+mod update_query_policy;
+
+pub use create_query_policy::CreateQueryPolicyUseCase;
+pub use delete_query_policy::DeleteQueryPolicyUseCase;
+pub use get_query_policies::GetQueryPoliciesUseCase;
+pub use update_query_policy::UpdateQueryPolicyUseCase;
@@ -0,0 +1,65 @@
+use ferrous_dns_domain::{DomainError, QueryPolicy};
+use std::sync::Arc;
+use tracing::{error, info, instrument};
+
+use crate::ports::{GroupRepository, QueryPolicyEnginePort, QueryPolicyRepository};
+
+pub struct UpdateQueryPolicyUseCase {
+    repo: Arc<dyn QueryPolicyRepository>,
+    group_repo: Arc<dyn GroupRepository>,
+    policy_engine: Arc<dyn QueryPolicyEnginePort>,
+}
+
+impl UpdateQueryPolicyUseCase {
+    pub fn new(
+        repo: Arc<dyn QueryPolicyRepository>,
+        group_repo: Arc<dyn GroupRepository>,
+        policy_engine: Arc<dyn QueryPolicyEnginePort>,
+    ) -> Self {
+        Self {
+            repo,
+            group_repo,
+            policy_engine,
+        }
+    }
+
+    /// Replaces the stored policy `id` with `policy`.
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        id: i64,
+        mut policy: QueryPolicy,
+    ) -> Result<QueryPolicy, DomainError> {
+        self.repo
+            .get_by_id(id)
+            .await?
+            .ok_or(DomainError::QueryPolicyNotFound(id))?;
+
+        policy.validate().map_err(DomainError::InvalidQueryPolicy)?;
+
+        if let Some(gid) = policy.group_id {
+            self.group_repo
+                .get_by_id(gid)
+                .await?
+                .ok_or(DomainError::GroupNotFound(gid))?;
+        }
+
+        policy.id = Some(id);
+        let updated = self.repo.update(&policy).await?;
+
+        info!(
+            policy_id = id,
+            name = %updated.name,
+            priority = updated.priority,
+            action = %updated.action.to_str(),
+            enabled = updated.enabled,
+            "Query policy updated successfully"
+        );
+
+        if let Err(e) = self.policy_engine.reload().await {
+            error!(error = %e, "Failed to reload query policies after update");
+        }
+
+        Ok(updated)
+    }
+}
@@ -0,0 +1,42 @@
+use ferrous_dns_domain::DomainError;
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use crate::ports::{GroupRepository, RecordTypeFilterPort, RecordTypePolicyRepository};
+
+/// Removes the record type policy of a group, allowing every type again.
+pub struct DeleteRecordTypePolicyUseCase {
+    repo: Arc<dyn RecordTypePolicyRepository>,
+    group_repo: Arc<dyn GroupRepository>,
+    filter: Arc<dyn RecordTypeFilterPort>,
+}
+
+impl DeleteRecordTypePolicyUseCase {
+    pub fn new(
+        repo: Arc<dyn RecordTypePolicyRepository>,
+        group_repo: Arc<dyn GroupRepository>,
+        filter: Arc<dyn RecordTypeFilterPort>,
+    ) -> Self {
+        Self {
+            repo,
+            group_repo,
+            filter,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(&self, group_id: i64) -> Result<(), DomainError> {
+        self.group_repo
+            .get_by_id(group_id)
+            .await?
+            .ok_or(DomainError::GroupNotFound(group_id))?;
+
+        self.repo.delete_by_group(group_id).await?;
+
+        info!(group_id = group_id, "Record type policy removed for group");
+
+        self.filter.reload().await?;
+
+        Ok(())
+    }
+}
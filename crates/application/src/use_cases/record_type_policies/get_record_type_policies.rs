@@ -0,0 +1,36 @@
+use ferrous_dns_domain::{DomainError, RecordTypePolicy};
+use std::sync::Arc;
+
+use crate::ports::{GroupRepository, RecordTypePolicyRepository};
+
+/// Retrieves record type policies, optionally for a single group.
+pub struct GetRecordTypePoliciesUseCase {
+    repo: Arc<dyn RecordTypePolicyRepository>,
+    group_repo: Arc<dyn GroupRepository>,
+}
+
+impl GetRecordTypePoliciesUseCase {
+    pub fn new(
+        repo: Arc<dyn RecordTypePolicyRepository>,
+        group_repo: Arc<dyn GroupRepository>,
+    ) -> Self {
+        Self { repo, group_repo }
+    }
+
+    pub async fn get_all(&self) -> Result<Vec<RecordTypePolicy>, DomainError> {
+        self.repo.get_all().await
+    }
+
+    /// Returns [`DomainError::GroupNotFound`] if `group_id` does not exist,
+    /// and `Ok(None)` if the group has no policy.
+    pub async fn get_by_group(
+        &self,
+        group_id: i64,
+    ) -> Result<Option<RecordTypePolicy>, DomainError> {
+        self.group_repo
+            .get_by_id(group_id)
+            .await?
+            .ok_or(DomainError::GroupNotFound(group_id))?;
+        self.repo.get_by_group(group_id).await
+    }
+}
@@ -0,0 +1,7 @@
+mod delete_record_type_policy;
+mod get_record_type_policies;
+mod set_record_type_policy;
+
+pub use delete_record_type_policy::DeleteRecordTypePolicyUseCase;
+pub use get_record_type_policies::GetRecordTypePoliciesUseCase;
+pub use set_record_type_policy::SetRecordTypePolicyUseCase;
@@ -0,0 +1,54 @@
+use ferrous_dns_domain::{DomainError, RecordTypePolicy};
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use crate::ports::{GroupRepository, RecordTypeFilterPort, RecordTypePolicyRepository};
+
+/// Sets (creates or replaces) the record type policy of a group.
+///
+/// After persisting the change, reloads the in-memory filter so the policy
+/// applies to the next DNS query without restart.
+pub struct SetRecordTypePolicyUseCase {
+    repo: Arc<dyn RecordTypePolicyRepository>,
+    group_repo: Arc<dyn GroupRepository>,
+    filter: Arc<dyn RecordTypeFilterPort>,
+}
+
+impl SetRecordTypePolicyUseCase {
+    pub fn new(
+        repo: Arc<dyn RecordTypePolicyRepository>,
+        group_repo: Arc<dyn GroupRepository>,
+        filter: Arc<dyn RecordTypeFilterPort>,
+    ) -> Self {
+        Self {
+            repo,
+            group_repo,
+            filter,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(&self, policy: RecordTypePolicy) -> Result<RecordTypePolicy, DomainError> {
+        policy
+            .validate()
+            .map_err(DomainError::InvalidRecordTypePolicy)?;
+
+        self.group_repo
+            .get_by_id(policy.group_id)
+            .await?
+            .ok_or(DomainError::GroupNotFound(policy.group_id))?;
+
+        let saved = self.repo.upsert(&policy).await?;
+
+        info!(
+            group_id = saved.group_id,
+            mode = saved.mode.to_str(),
+            types = saved.record_types.len(),
+            "Record type policy updated"
+        );
+
+        self.filter.reload().await?;
+
+        Ok(saved)
+    }
+}
@@ -0,0 +1,3 @@
+mod send_usage_report;
+
+pub use send_usage_report::{SendUsageReportUseCase, UsageReport};
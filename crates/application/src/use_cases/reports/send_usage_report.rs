@@ -0,0 +1,318 @@
+use crate::ports::{
+    AggregateStatus, ClientRepository, QueryLogRepository, ReportSender, UpstreamHealthPort,
+    UpstreamWindowStats,
+};
+use chrono::{Duration, Utc};
+use ferrous_dns_domain::{Client, DomainError, PageRequest, ReportPeriod, SortOrder};
+use std::fmt::Write;
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+/// Figures for one reporting period, rendered into the report email.
+#[derive(Debug, Clone)]
+pub struct UsageReport {
+    pub period: ReportPeriod,
+    pub period_hours: u32,
+    pub queries_total: u64,
+    pub queries_blocked: u64,
+    pub top_blocked_domains: Vec<(String, u64)>,
+    pub top_allowed_domains: Vec<(String, u64)>,
+    /// Address, hostname and query count, busiest first.
+    pub top_clients: Vec<(String, Option<String>, u64)>,
+    /// Clients first seen within the period, newest first, up to the top
+    /// count; `new_client_count` has the total.
+    pub new_clients: Vec<Client>,
+    pub new_client_count: usize,
+    /// Forwarded queries per upstream server over the period, busiest first.
+    pub upstreams: Vec<UpstreamWindowStats>,
+    /// Configured servers not fully healthy when the report was built.
+    pub degraded_upstreams: Vec<String>,
+}
+
+impl UsageReport {
+    pub fn blocked_percentage(&self) -> f64 {
+        if self.queries_total == 0 {
+            return 0.0;
+        }
+        self.queries_blocked as f64 / self.queries_total as f64 * 100.0
+    }
+
+    pub fn subject(&self) -> String {
+        format!("{} DNS report", self.period.title())
+    }
+
+    pub fn to_html(&self) -> String {
+        let mut out = String::new();
+        let _ = write!(
+            out,
+            "<!DOCTYPE html><html><body style=\"font-family:sans-serif;color:#222\">\
+             <h1>{}</h1><p>Last {} days.</p>",
+            escape(&self.subject()),
+            self.period_hours / 24
+        );
+
+        let _ = write!(
+            out,
+            "<h2>Summary</h2><table cellpadding=\"4\">\
+             <tr><td>Total queries</td><td><b>{}</b></td></tr>\
+             <tr><td>Blocked</td><td><b>{}</b> ({:.1}%)</td></tr>\
+             <tr><td>New devices</td><td><b>{}</b></td></tr></table>",
+            self.queries_total,
+            self.queries_blocked,
+            self.blocked_percentage(),
+            self.new_client_count
+        );
+
+        push_table(
+            &mut out,
+            "Top blocked domains",
+            &["Domain", "Queries"],
+            self.top_blocked_domains
+                .iter()
+                .map(|(domain, count)| vec![domain.clone(), count.to_string()]),
+        );
+        push_table(
+            &mut out,
+            "Top allowed domains",
+            &["Domain", "Queries"],
+            self.top_allowed_domains
+                .iter()
+                .map(|(domain, count)| vec![domain.clone(), count.to_string()]),
+        );
+        push_table(
+            &mut out,
+            "Top clients",
+            &["Client", "Hostname", "Queries"],
+            self.top_clients.iter().map(|(ip, hostname, count)| {
+                vec![
+                    ip.clone(),
+                    hostname.clone().unwrap_or_default(),
+                    count.to_string(),
+                ]
+            }),
+        );
+        push_table(
+            &mut out,
+            "New devices",
+            &["Client", "Hostname", "First seen"],
+            self.new_clients.iter().map(|client| {
+                vec![
+                    client.ip_address.to_string(),
+                    client
+                        .display_name
+                        .as_deref()
+                        .or(client.hostname.as_deref())
+                        .unwrap_or_default()
+                        .to_string(),
+                    client.first_seen.clone().unwrap_or_default(),
+                ]
+            }),
+        );
+        if self.new_client_count > self.new_clients.len() {
+            let _ = write!(
+                out,
+                "<p>… and {} more.</p>",
+                self.new_client_count - self.new_clients.len()
+            );
+        }
+
+        push_table(
+            &mut out,
+            "Upstream servers",
+            &["Server", "Pool", "Queries", "Errors", "Avg latency"],
+            self.upstreams.iter().map(|upstream| {
+                vec![
+                    upstream.server.clone(),
+                    upstream.pool.clone(),
+                    upstream.queries.to_string(),
+                    upstream.errors.to_string(),
+                    format!("{:.1} ms", upstream.avg_latency_ms),
+                ]
+            }),
+        );
+        if self.degraded_upstreams.is_empty() {
+            out.push_str("<p>All upstream servers are healthy.</p>");
+        } else {
+            out.push_str("<p>Not fully healthy now:</p><ul>");
+            for server in &self.degraded_upstreams {
+                let _ = write!(out, "<li>{}</li>", escape(server));
+            }
+            out.push_str("</ul>");
+        }
+
+        out.push_str("</body></html>");
+        out
+    }
+}
+
+/// Builds a usage report from the query log, client list and upstream
+/// health, and emails it.
+pub struct SendUsageReportUseCase {
+    query_log: Arc<dyn QueryLogRepository>,
+    clients: Arc<dyn ClientRepository>,
+    upstream_health: Arc<dyn UpstreamHealthPort>,
+    sender: Arc<dyn ReportSender>,
+    top_count: u32,
+}
+
+impl SendUsageReportUseCase {
+    pub fn new(
+        query_log: Arc<dyn QueryLogRepository>,
+        clients: Arc<dyn ClientRepository>,
+        upstream_health: Arc<dyn UpstreamHealthPort>,
+        sender: Arc<dyn ReportSender>,
+    ) -> Self {
+        Self {
+            query_log,
+            clients,
+            upstream_health,
+            sender,
+            top_count: 10,
+        }
+    }
+
+    /// Rows in the top tables and the new device list.
+    pub fn with_top_count(mut self, top_count: u32) -> Self {
+        self.top_count = top_count;
+        self
+    }
+
+    /// Builds and sends the report covering the last `period_hours`.
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        period: ReportPeriod,
+        period_hours: u32,
+    ) -> Result<UsageReport, DomainError> {
+        let report = self.build(period, period_hours).await?;
+        self.sender
+            .send(&report.subject(), &report.to_html())
+            .await?;
+        info!(
+            period = period.as_str(),
+            queries = report.queries_total,
+            "Usage report sent"
+        );
+        Ok(report)
+    }
+
+    pub async fn build(
+        &self,
+        period: ReportPeriod,
+        period_hours: u32,
+    ) -> Result<UsageReport, DomainError> {
+        let hours = period_hours as f32;
+        let stats = self.query_log.get_stats(hours).await?;
+        let top_blocked_domains = self
+            .query_log
+            .get_top_blocked_domains(self.top_count, hours)
+            .await?;
+        let top_allowed_domains = self
+            .query_log
+            .get_top_allowed_domains(self.top_count, hours)
+            .await?;
+        let top_clients = self
+            .query_log
+            .get_top_clients(self.top_count, hours)
+            .await?;
+        let upstreams = self
+            .query_log
+            .get_metrics_window(i64::from(period_hours) * 3600)
+            .await?
+            .upstreams;
+
+        let mut new_clients = self.new_clients(period_hours).await?;
+        let new_client_count = new_clients.len();
+        new_clients.truncate(self.top_count as usize);
+
+        let degraded_upstreams = self
+            .upstream_health
+            .get_grouped_upstream_health()
+            .into_iter()
+            .filter_map(|server| {
+                let status = match server.status {
+                    AggregateStatus::Partial => "partially reachable",
+                    AggregateStatus::Unhealthy => "unreachable",
+                    AggregateStatus::Healthy | AggregateStatus::Unknown => return None,
+                };
+                Some(format!(
+                    "{} ({}): {}",
+                    server.address, server.pool_name, status
+                ))
+            })
+            .collect();
+
+        Ok(UsageReport {
+            period,
+            period_hours,
+            queries_total: stats.queries_total,
+            queries_blocked: stats.queries_blocked,
+            top_blocked_domains,
+            top_allowed_domains,
+            top_clients,
+            new_clients,
+            new_client_count,
+            upstreams,
+            degraded_upstreams,
+        })
+    }
+
+    async fn new_clients(&self, period_hours: u32) -> Result<Vec<Client>, DomainError> {
+        let cutoff = (Utc::now() - Duration::hours(i64::from(period_hours)))
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string();
+        let page = PageRequest::new(PageRequest::MAX_LIMIT, 0).sorted_by(
+            "first_seen",
+            SortOrder::Desc,
+            Client::SORT_FIELDS,
+        )?;
+        let (clients, _) = self
+            .clients
+            .get_page(&page, Some(period_hours.div_ceil(24)))
+            .await?;
+
+        Ok(clients
+            .into_iter()
+            .filter(|client| {
+                client
+                    .first_seen
+                    .as_deref()
+                    .is_some_and(|first_seen| first_seen >= cutoff.as_str())
+            })
+            .collect())
+    }
+}
+
+fn push_table(
+    out: &mut String,
+    title: &str,
+    headers: &[&str],
+    rows: impl Iterator<Item = Vec<String>>,
+) {
+    let _ = write!(out, "<h2>{}</h2>", escape(title));
+    let mut rows = rows.peekable();
+    if rows.peek().is_none() {
+        out.push_str("<p>None.</p>");
+        return;
+    }
+    out.push_str("<table cellpadding=\"4\" style=\"border-collapse:collapse\"><tr>");
+    for header in headers {
+        let _ = write!(out, "<th align=\"left\">{}</th>", escape(header));
+    }
+    out.push_str("</tr>");
+    for row in rows {
+        out.push_str("<tr>");
+        for cell in row {
+            let _ = write!(out, "<td>{}</td>", escape(&cell));
+        }
+        out.push_str("</tr>");
+    }
+    out.push_str("</table>");
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,6 @@
+mod wizard;
+
+pub use wizard::{
+    ListenerSettings, SetupOptions, SetupRequest, SetupSummary, SetupWizardUseCase,
+    SuggestedBlocklist, SUGGESTED_BLOCKLISTS,
+};
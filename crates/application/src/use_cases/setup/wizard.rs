@@ -0,0 +1,317 @@
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use ferrous_dns_domain::{Config, DomainError, UpstreamPreset, User};
+use tokio::sync::{Mutex, RwLock};
+use tracing::{error, info, instrument, warn};
+
+use crate::ports::{
+    BlockFilterEnginePort, BlocklistSourceCreator, ConfigFilePersistence, NetworkInterface,
+    NetworkInterfacePort,
+};
+use crate::use_cases::auth::SetupPasswordUseCase;
+
+/// Group seeded by the initial migration; lists picked during setup apply
+/// to every client through it.
+const DEFAULT_GROUP_ID: i64 = 1;
+
+/// A well-known blocklist offered during first-run setup.
+#[derive(Debug, Clone, Copy)]
+pub struct SuggestedBlocklist {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub url: &'static str,
+    pub description: &'static str,
+    /// Pre-selected in the wizard.
+    pub recommended: bool,
+}
+
+pub const SUGGESTED_BLOCKLISTS: &[SuggestedBlocklist] = &[
+    SuggestedBlocklist {
+        id: "stevenblack",
+        name: "Steven Black Unified",
+        url: "https://raw.githubusercontent.com/StevenBlack/hosts/master/hosts",
+        description: "Ads and malware, ~120k domains",
+        recommended: true,
+    },
+    SuggestedBlocklist {
+        id: "oisd-small",
+        name: "OISD (small)",
+        url: "https://small.oisd.nl/domainswild",
+        description: "Balanced ad blocking with few false positives, ~50k domains",
+        recommended: false,
+    },
+    SuggestedBlocklist {
+        id: "oisd-big",
+        name: "OISD (big)",
+        url: "https://big.oisd.nl/domainswild",
+        description: "Comprehensive ad, tracker and malware blocking, ~200k domains",
+        recommended: false,
+    },
+    SuggestedBlocklist {
+        id: "hagezi-pro",
+        name: "HaGeZi Pro",
+        url: "https://raw.githubusercontent.com/hagezi/dns-blocklists/main/domains/pro.txt",
+        description: "Ads, tracking and telemetry, ~500k domains",
+        recommended: false,
+    },
+    SuggestedBlocklist {
+        id: "hagezi-tif",
+        name: "HaGeZi Threat Intelligence",
+        url: "https://raw.githubusercontent.com/hagezi/dns-blocklists/main/domains/tif.txt",
+        description: "Malware, phishing and scam domains, ~1M domains",
+        recommended: true,
+    },
+];
+
+/// Where the server listens; changes take effect after a restart.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListenerSettings {
+    pub bind_address: String,
+    pub dns_port: u16,
+    pub web_port: u16,
+}
+
+impl ListenerSettings {
+    fn validate(&self) -> Result<(), DomainError> {
+        self.bind_address.parse::<IpAddr>().map_err(|_| {
+            DomainError::InvalidInput(format!(
+                "Invalid bind address '{}': expected an IP address",
+                self.bind_address
+            ))
+        })?;
+        if self.dns_port == 0 || self.web_port == 0 {
+            return Err(DomainError::InvalidInput("Ports cannot be 0".to_string()));
+        }
+        if self.dns_port == self.web_port {
+            return Err(DomainError::InvalidInput(
+                "DNS and web ports must differ".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// What the wizard offers and the values it starts from.
+#[derive(Debug, Clone)]
+pub struct SetupOptions {
+    pub upstream_preset: Option<UpstreamPreset>,
+    pub listeners: ListenerSettings,
+    pub interfaces: Vec<NetworkInterface>,
+}
+
+/// Choices submitted by the wizard. Anything left `None` keeps the
+/// configured value.
+#[derive(Debug, Clone, Default)]
+pub struct SetupRequest {
+    pub password: String,
+    pub upstream_preset: Option<UpstreamPreset>,
+    /// Ids from [`SUGGESTED_BLOCKLISTS`].
+    pub blocklists: Vec<String>,
+    pub listeners: Option<ListenerSettings>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SetupSummary {
+    pub blocklists_added: usize,
+    pub blocklists_skipped: usize,
+    /// The upstreams or listeners changed and only apply after a restart.
+    pub restart_required: bool,
+    pub warnings: Vec<String>,
+}
+
+/// First-run setup. Available only while the admin has no password; the
+/// password is set once the request is validated, which closes the wizard
+/// for good, and the chosen settings are written to the config file last.
+pub struct SetupWizardUseCase {
+    config: Arc<RwLock<Config>>,
+    config_persistence: Arc<dyn ConfigFilePersistence>,
+    config_path: Option<String>,
+    setup_password: Arc<SetupPasswordUseCase>,
+    blocklist_source_creator: Arc<dyn BlocklistSourceCreator>,
+    interfaces: Option<Arc<dyn NetworkInterfacePort>>,
+    block_filter_engine: Option<Arc<dyn BlockFilterEnginePort>>,
+    /// Serializes submissions so two browsers cannot both pass the guard.
+    running: Mutex<()>,
+}
+
+impl SetupWizardUseCase {
+    pub fn new(
+        config: Arc<RwLock<Config>>,
+        config_persistence: Arc<dyn ConfigFilePersistence>,
+        config_path: Option<String>,
+        setup_password: Arc<SetupPasswordUseCase>,
+        blocklist_source_creator: Arc<dyn BlocklistSourceCreator>,
+    ) -> Self {
+        Self {
+            config,
+            config_persistence,
+            config_path,
+            setup_password,
+            blocklist_source_creator,
+            interfaces: None,
+            block_filter_engine: None,
+            running: Mutex::new(()),
+        }
+    }
+
+    pub fn with_interfaces(mut self, interfaces: Arc<dyn NetworkInterfacePort>) -> Self {
+        self.interfaces = Some(interfaces);
+        self
+    }
+
+    pub fn with_block_filter_engine(mut self, engine: Arc<dyn BlockFilterEnginePort>) -> Self {
+        self.block_filter_engine = Some(engine);
+        self
+    }
+
+    async fn ensure_open(&self) -> Result<(), DomainError> {
+        let config = self.config.read().await;
+        let configured = config
+            .auth
+            .admin
+            .password_hash
+            .as_ref()
+            .is_some_and(|h| !h.is_empty());
+        if configured {
+            return Err(DomainError::PasswordAlreadyConfigured);
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    pub async fn options(&self) -> Result<SetupOptions, DomainError> {
+        self.ensure_open().await?;
+        let config = self.config.read().await;
+        Ok(SetupOptions {
+            upstream_preset: config.dns.upstream_preset,
+            listeners: ListenerSettings {
+                bind_address: config.server.bind_address.clone(),
+                dns_port: config.server.dns_port,
+                web_port: config.server.web_port,
+            },
+            interfaces: self
+                .interfaces
+                .as_ref()
+                .map(|i| i.list_interfaces())
+                .unwrap_or_default(),
+        })
+    }
+
+    #[instrument(skip(self, request), fields(preset = ?request.upstream_preset, blocklists = request.blocklists.len()))]
+    pub async fn complete(&self, request: SetupRequest) -> Result<SetupSummary, DomainError> {
+        let _running = self.running.lock().await;
+        self.ensure_open().await?;
+
+        User::validate_password(&request.password).map_err(DomainError::InvalidPassword)?;
+        if let Some(listeners) = &request.listeners {
+            listeners.validate()?;
+        }
+        let blocklists = request
+            .blocklists
+            .iter()
+            .map(|id| {
+                SUGGESTED_BLOCKLISTS
+                    .iter()
+                    .find(|list| list.id == id)
+                    .ok_or_else(|| DomainError::InvalidInput(format!("Unknown blocklist '{id}'")))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Saving the password writes the config file as it is now, so the
+        // wizard's settings are written after it and the file keeps both.
+        self.setup_password.execute(&request.password).await?;
+
+        let mut summary = SetupSummary::default();
+        self.add_blocklists(&blocklists, &mut summary).await;
+        if let Err(e) = self.apply_config(&request, &mut summary).await {
+            warn!(error = %e, "Failed to apply settings during setup");
+            summary.restart_required = false;
+            summary.warnings.push(format!("Settings: {e}"));
+        }
+
+        info!(
+            blocklists_added = summary.blocklists_added,
+            restart_required = summary.restart_required,
+            "First-run setup completed"
+        );
+        Ok(summary)
+    }
+
+    async fn apply_config(
+        &self,
+        request: &SetupRequest,
+        summary: &mut SetupSummary,
+    ) -> Result<(), DomainError> {
+        let mut new_config = self.config.read().await.clone();
+
+        if let Some(preset) = request.upstream_preset {
+            // Written as the preset alone so the file stays one line; the
+            // pools are expanded again on load.
+            new_config.dns.upstream_preset = Some(preset);
+            new_config.dns.pools.clear();
+            summary.restart_required = true;
+        }
+        if let Some(listeners) = &request.listeners {
+            let server = &mut new_config.server;
+            if server.bind_address != listeners.bind_address
+                || server.dns_port != listeners.dns_port
+                || server.web_port != listeners.web_port
+            {
+                server.bind_address = listeners.bind_address.clone();
+                server.dns_port = listeners.dns_port;
+                server.web_port = listeners.web_port;
+                summary.restart_required = true;
+            }
+        }
+
+        if let Some(ref path) = self.config_path {
+            self.config_persistence
+                .save_config_to_file(&new_config, path)
+                .map_err(|e| DomainError::ConfigError(format!("Failed to save config: {e}")))?;
+        }
+        new_config.normalize_pools();
+        *self.config.write().await = new_config;
+        Ok(())
+    }
+
+    async fn add_blocklists(&self, lists: &[&SuggestedBlocklist], summary: &mut SetupSummary) {
+        for list in lists {
+            match self
+                .blocklist_source_creator
+                .create_blocklist_source(
+                    list.name.to_string(),
+                    Some(list.url.to_string()),
+                    vec![DEFAULT_GROUP_ID],
+                    Some(list.description.to_string()),
+                    true,
+                )
+                .await
+            {
+                Ok(_) => summary.blocklists_added += 1,
+                Err(DomainError::InvalidBlocklistSource(msg)) if msg.contains("already exists") => {
+                    summary.blocklists_skipped += 1;
+                }
+                Err(e) => {
+                    warn!(name = list.name, error = %e, "Failed to add blocklist during setup");
+                    summary.blocklists_skipped += 1;
+                    summary
+                        .warnings
+                        .push(format!("Blocklist '{}': {}", list.name, e));
+                }
+            }
+        }
+
+        if summary.blocklists_added > 0 {
+            if let Some(engine) = self.block_filter_engine.clone() {
+                // Downloading the lists can take a while; do not hold the
+                // wizard's request open for it.
+                tokio::spawn(async move {
+                    if let Err(e) = engine.reload().await {
+                        error!(error = %e, "Failed to reload block filter after setup");
+                    }
+                });
+            }
+        }
+    }
+}
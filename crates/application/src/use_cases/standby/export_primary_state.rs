@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use ferrous_dns_domain::DomainError;
+
+use crate::ports::{BlockFilterEnginePort, CacheExportEntry, DnsCachePort};
+
+/// Most cache entries a standby may ask for in one sync.
+pub const MAX_EXPORTED_CACHE_ENTRIES: usize = 100_000;
+
+/// Serves the state a standby pulls besides the backup: the block list
+/// snapshot and the hottest cache entries.
+pub struct ExportPrimaryStateUseCase {
+    block_filter: Arc<dyn BlockFilterEnginePort>,
+    cache: Arc<dyn DnsCachePort>,
+}
+
+impl ExportPrimaryStateUseCase {
+    pub fn new(block_filter: Arc<dyn BlockFilterEnginePort>, cache: Arc<dyn DnsCachePort>) -> Self {
+        Self {
+            block_filter,
+            cache,
+        }
+    }
+
+    /// The source lists behind the current block index, or `None` when
+    /// this instance keeps no snapshot of them.
+    pub async fn block_lists(&self) -> Result<Option<Vec<u8>>, DomainError> {
+        self.block_filter.export_list_snapshot().await
+    }
+
+    /// Up to `limit` cache entries, capped at `MAX_EXPORTED_CACHE_ENTRIES`.
+    pub fn cache_entries(&self, limit: usize) -> Vec<CacheExportEntry> {
+        self.cache
+            .export_entries(limit.min(MAX_EXPORTED_CACHE_ENTRIES))
+    }
+}
@@ -0,0 +1,5 @@
+pub mod export_primary_state;
+pub mod sync_from_primary;
+
+pub use export_primary_state::{ExportPrimaryStateUseCase, MAX_EXPORTED_CACHE_ENTRIES};
+pub use sync_from_primary::{StandbyStatus, SyncFromPrimaryUseCase};
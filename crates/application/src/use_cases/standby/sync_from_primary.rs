@@ -0,0 +1,203 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use chrono::{DateTime, Utc};
+use ferrous_dns_domain::{DomainError, StandbyConfig};
+use tracing::{debug, info, instrument, warn};
+
+use crate::ports::{BlockFilterEnginePort, DnsCachePort, PrimaryInstanceClient};
+use crate::use_cases::backup::RestoreBackupUseCase;
+
+/// Sync history of a standby, as reported to the API and to VRRP checks.
+#[derive(Debug, Clone, Default)]
+pub struct StandbyStatus {
+    /// Whether the last sync succeeded recently enough for this instance to
+    /// take over from the primary.
+    pub ready: bool,
+    pub last_attempt_at: Option<DateTime<Utc>>,
+    pub last_success_at: Option<DateTime<Utc>>,
+    pub consecutive_failures: u32,
+    /// Error of the last failed sync, cleared by the next successful one.
+    pub last_error: Option<String>,
+    /// Configuration changes restored since startup.
+    pub config_updates: u64,
+    /// Block list snapshots compiled since startup.
+    pub block_list_updates: u64,
+    /// Cache entries stored by the last successful sync.
+    pub cache_entries: usize,
+}
+
+#[derive(Debug, Default)]
+struct SyncOutcome {
+    config_updated: bool,
+    block_lists_updated: bool,
+    cache_entries: usize,
+}
+
+#[derive(Default)]
+struct SyncState {
+    status: StandbyStatus,
+    /// Digest of the last restored backup, `exported_at` left out.
+    backup_digest: Option<u64>,
+    lists_digest: Option<u64>,
+}
+
+/// Copies the primary's configuration, block lists and hottest cache
+/// entries into this instance.
+///
+/// The backup is restored and the block index recompiled only when they
+/// changed on the primary; cache entries are imported on every sync so the
+/// cache follows the primary's working set.
+pub struct SyncFromPrimaryUseCase {
+    primary: Arc<dyn PrimaryInstanceClient>,
+    restore: Arc<RestoreBackupUseCase>,
+    block_filter: Arc<dyn BlockFilterEnginePort>,
+    cache: Option<Arc<dyn DnsCachePort>>,
+    cache_entries: usize,
+    max_failed_syncs: u32,
+    interval_secs: u64,
+    state: Mutex<SyncState>,
+}
+
+impl SyncFromPrimaryUseCase {
+    pub fn new(
+        primary: Arc<dyn PrimaryInstanceClient>,
+        restore: Arc<RestoreBackupUseCase>,
+        block_filter: Arc<dyn BlockFilterEnginePort>,
+        config: &StandbyConfig,
+    ) -> Self {
+        Self {
+            primary,
+            restore,
+            block_filter,
+            cache: None,
+            cache_entries: config.cache_entries,
+            max_failed_syncs: config.max_failed_syncs.max(1),
+            interval_secs: config.sync_interval_secs,
+            state: Mutex::new(SyncState::default()),
+        }
+    }
+
+    /// Copy up to `standby.cache_entries` of the primary's entries into `cache`.
+    pub fn with_cache(mut self, cache: Arc<dyn DnsCachePort>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    pub fn interval_secs(&self) -> u64 {
+        self.interval_secs
+    }
+
+    pub fn status(&self) -> StandbyStatus {
+        self.state().status.clone()
+    }
+
+    #[instrument(skip(self), name = "standby_sync")]
+    pub async fn execute(&self) -> Result<(), DomainError> {
+        let result = self.sync().await;
+
+        let now = Utc::now();
+        let mut state = self.state();
+        let status = &mut state.status;
+        status.last_attempt_at = Some(now);
+        match result {
+            Ok(outcome) => {
+                status.last_success_at = Some(now);
+                status.consecutive_failures = 0;
+                status.last_error = None;
+                status.config_updates += u64::from(outcome.config_updated);
+                status.block_list_updates += u64::from(outcome.block_lists_updated);
+                status.cache_entries = outcome.cache_entries;
+                status.ready = true;
+                debug!(
+                    config_updated = outcome.config_updated,
+                    block_lists_updated = outcome.block_lists_updated,
+                    cache_entries = outcome.cache_entries,
+                    "Synced from primary"
+                );
+                Ok(())
+            }
+            Err(e) => {
+                status.consecutive_failures += 1;
+                status.last_error = Some(e.to_string());
+                if status.consecutive_failures >= self.max_failed_syncs && status.ready {
+                    warn!(
+                        failures = status.consecutive_failures,
+                        "Standby is no longer in sync with the primary"
+                    );
+                    status.ready = false;
+                }
+                Err(e)
+            }
+        }
+    }
+
+    async fn sync(&self) -> Result<SyncOutcome, DomainError> {
+        let mut outcome = SyncOutcome::default();
+
+        let backup = self.primary.fetch_backup().await?;
+        let backup_digest = backup_digest(&backup)?;
+        if self.state().backup_digest != Some(backup_digest) {
+            let summary = self.restore.execute(&backup).await?;
+            if let Err(e) = self.block_filter.load_client_groups().await {
+                warn!(error = %e, "Failed to reload client groups after standby sync");
+            }
+            self.state().backup_digest = Some(backup_digest);
+            outcome.config_updated = true;
+            info!(
+                tables = summary.tables.len(),
+                exported_at = %summary.exported_at,
+                "Configuration copied from primary"
+            );
+        }
+
+        match self.primary.fetch_block_lists().await? {
+            Some(lists) => {
+                let lists_digest = digest(&lists);
+                if outcome.config_updated || self.state().lists_digest != Some(lists_digest) {
+                    self.block_filter.import_list_snapshot(lists).await?;
+                    self.state().lists_digest = Some(lists_digest);
+                    outcome.block_lists_updated = true;
+                    info!(
+                        domains = self.block_filter.compiled_domain_count(),
+                        "Block lists copied from primary"
+                    );
+                }
+            }
+            // The primary keeps no snapshot: fetch the restored sources here.
+            None if outcome.config_updated => self.block_filter.reload().await?,
+            None => {}
+        }
+
+        if let Some(cache) = self.cache.as_ref().filter(|_| self.cache_entries > 0) {
+            let entries = self.primary.fetch_cache(self.cache_entries).await?;
+            outcome.cache_entries = cache.import_entries(&entries);
+        }
+
+        Ok(outcome)
+    }
+
+    fn state(&self) -> MutexGuard<'_, SyncState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Digest of a JSON backup without its export time, which changes on
+/// every request. Object keys are sorted when parsed, so the digest does
+/// not depend on the primary's map ordering.
+fn backup_digest(backup: &[u8]) -> Result<u64, DomainError> {
+    let mut value: serde_json::Value = serde_json::from_slice(backup).map_err(|e| {
+        DomainError::StandbySyncFailed(format!("primary sent an invalid backup: {e}"))
+    })?;
+    if let Some(object) = value.as_object_mut() {
+        object.remove("exported_at");
+    }
+    Ok(digest(value.to_string().as_bytes()))
+}
+
+fn digest(bytes: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
@@ -0,0 +1,140 @@
+use ferrous_dns_domain::{BlocklistSource, DomainError};
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use crate::ports::{BlocklistSourceRepository, TenantRepository};
+
+/// Blocklist sources owned by a tenant. Sources may only be assigned to the
+/// tenant's own groups, and sources of other tenants are reported as not found.
+pub struct TenantBlocklistSourcesUseCase {
+    tenant_repo: Arc<dyn TenantRepository>,
+    repo: Arc<dyn BlocklistSourceRepository>,
+}
+
+impl TenantBlocklistSourcesUseCase {
+    pub fn new(
+        tenant_repo: Arc<dyn TenantRepository>,
+        repo: Arc<dyn BlocklistSourceRepository>,
+    ) -> Self {
+        Self { tenant_repo, repo }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_all(&self, tenant_id: i64) -> Result<Vec<BlocklistSource>, DomainError> {
+        self.check_tenant(tenant_id).await?;
+        self.repo.get_all_in_tenant(tenant_id).await
+    }
+
+    #[instrument(skip(self))]
+    pub async fn create(
+        &self,
+        tenant_id: i64,
+        name: String,
+        url: Option<String>,
+        group_ids: Vec<i64>,
+        comment: Option<String>,
+        enabled: bool,
+    ) -> Result<BlocklistSource, DomainError> {
+        BlocklistSource::validate_name(&name).map_err(DomainError::InvalidBlocklistSource)?;
+        BlocklistSource::validate_url(&url.as_deref().map(Arc::from))
+            .map_err(DomainError::InvalidBlocklistSource)?;
+        BlocklistSource::validate_comment(&comment.as_deref().map(Arc::from))
+            .map_err(DomainError::InvalidBlocklistSource)?;
+        self.check_groups(tenant_id, &group_ids).await?;
+
+        let source = self
+            .repo
+            .create_in_tenant(tenant_id, name, url, group_ids, comment, enabled)
+            .await?;
+
+        info!(
+            tenant_id,
+            source_id = ?source.id,
+            name = %source.name,
+            group_ids = ?source.group_ids,
+            "Tenant blocklist source created successfully"
+        );
+
+        Ok(source)
+    }
+
+    #[instrument(skip(self))]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update(
+        &self,
+        tenant_id: i64,
+        id: i64,
+        name: Option<String>,
+        url: Option<Option<String>>,
+        group_ids: Option<Vec<i64>>,
+        comment: Option<String>,
+        enabled: Option<bool>,
+        audit_mode: Option<bool>,
+    ) -> Result<BlocklistSource, DomainError> {
+        self.repo
+            .get_by_id_in_tenant(tenant_id, id)
+            .await?
+            .ok_or(DomainError::BlocklistSourceNotFound(id))?;
+
+        if let Some(ref n) = name {
+            BlocklistSource::validate_name(n).map_err(DomainError::InvalidBlocklistSource)?;
+        }
+        if let Some(ref u_opt) = url {
+            BlocklistSource::validate_url(&u_opt.as_deref().map(Arc::from))
+                .map_err(DomainError::InvalidBlocklistSource)?;
+        }
+        if let Some(ref c) = comment {
+            BlocklistSource::validate_comment(&Some(Arc::from(c.as_str())))
+                .map_err(DomainError::InvalidBlocklistSource)?;
+        }
+        if let Some(ref ids) = group_ids {
+            self.check_groups(tenant_id, ids).await?;
+        }
+
+        let updated = self
+            .repo
+            .update(id, name, url, group_ids, comment, enabled, audit_mode)
+            .await?;
+
+        info!(
+            tenant_id,
+            source_id = id,
+            name = %updated.name,
+            "Tenant blocklist source updated successfully"
+        );
+
+        Ok(updated)
+    }
+
+    #[instrument(skip(self))]
+    pub async fn delete(&self, tenant_id: i64, id: i64) -> Result<(), DomainError> {
+        self.repo.delete_in_tenant(tenant_id, id).await?;
+
+        info!(
+            tenant_id,
+            source_id = id,
+            "Tenant blocklist source deleted successfully"
+        );
+
+        Ok(())
+    }
+
+    async fn check_tenant(&self, tenant_id: i64) -> Result<(), DomainError> {
+        self.tenant_repo
+            .get_by_id(tenant_id)
+            .await?
+            .ok_or(DomainError::TenantNotFound(tenant_id))?;
+        Ok(())
+    }
+
+    /// Groups outside the tenant are reported as not found, like the tenant's
+    /// other lookups.
+    async fn check_groups(&self, tenant_id: i64, group_ids: &[i64]) -> Result<(), DomainError> {
+        self.check_tenant(tenant_id).await?;
+        let owned = self.tenant_repo.get_group_ids(tenant_id).await?;
+        match group_ids.iter().find(|gid| !owned.contains(gid)) {
+            Some(&gid) => Err(DomainError::GroupNotFound(gid)),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use ferrous_dns_domain::{DomainError, Tenant};
+use std::sync::Arc;
+use tracing::{info, instrument, warn};
+
+use super::reload_tenant_routing;
+use crate::ports::{BlockFilterEnginePort, GroupRepository, TenantRepository};
+
+/// Creates a tenant together with its default group.
+pub struct CreateTenantUseCase {
+    tenant_repo: Arc<dyn TenantRepository>,
+    group_repo: Arc<dyn GroupRepository>,
+    block_filter: Arc<dyn BlockFilterEnginePort>,
+}
+
+impl CreateTenantUseCase {
+    pub fn new(
+        tenant_repo: Arc<dyn TenantRepository>,
+        group_repo: Arc<dyn GroupRepository>,
+        block_filter: Arc<dyn BlockFilterEnginePort>,
+    ) -> Self {
+        Self {
+            tenant_repo,
+            group_repo,
+            block_filter,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(&self, tenant: Tenant) -> Result<Tenant, DomainError> {
+        tenant.validate().map_err(DomainError::InvalidTenant)?;
+
+        let mut created = self
+            .tenant_repo
+            .create(&Tenant {
+                default_group_id: None,
+                ..tenant
+            })
+            .await?;
+        let tenant_id = created
+            .id
+            .ok_or_else(|| DomainError::DatabaseError("created tenant has no id".to_string()))?;
+
+        let group = match self.create_default_group(&created, tenant_id).await {
+            Ok(group) => group,
+            Err(e) => {
+                if let Err(cleanup) = self.tenant_repo.delete(tenant_id).await {
+                    warn!(tenant_id, error = %cleanup, "Failed to roll back tenant creation");
+                }
+                return Err(e);
+            }
+        };
+
+        created.default_group_id = Some(group);
+        let created = self.tenant_repo.update(&created).await?;
+
+        info!(
+            tenant_id,
+            name = %created.name,
+            default_group_id = group,
+            subnets = created.subnets.len(),
+            "Tenant created successfully"
+        );
+
+        reload_tenant_routing(self.block_filter.as_ref()).await;
+
+        Ok(created)
+    }
+
+    async fn create_default_group(
+        &self,
+        tenant: &Tenant,
+        tenant_id: i64,
+    ) -> Result<i64, DomainError> {
+        let group = self
+            .group_repo
+            .create(
+                tenant.default_group_name(),
+                Some(format!("Default group of tenant {}", tenant.name)),
+            )
+            .await?;
+        let group_id = group
+            .id
+            .ok_or_else(|| DomainError::DatabaseError("created group has no id".to_string()))?;
+        self.tenant_repo.assign_group(tenant_id, group_id).await?;
+        Ok(group_id)
+    }
+}
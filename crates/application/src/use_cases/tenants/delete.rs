@@ -0,0 +1,75 @@
+use ferrous_dns_domain::DomainError;
+use std::sync::Arc;
+use tracing::{error, info, instrument};
+
+use super::reload_tenant_routing;
+use crate::ports::{
+    BlockFilterEnginePort, BlocklistSourceRepository, GroupRepository, LocalZonePort,
+    TenantRepository,
+};
+
+/// Deletes a tenant with everything it owns. Fails while any of its groups
+/// still has clients assigned.
+pub struct DeleteTenantUseCase {
+    tenant_repo: Arc<dyn TenantRepository>,
+    group_repo: Arc<dyn GroupRepository>,
+    source_repo: Arc<dyn BlocklistSourceRepository>,
+    block_filter: Arc<dyn BlockFilterEnginePort>,
+    local_zone: Arc<dyn LocalZonePort>,
+}
+
+impl DeleteTenantUseCase {
+    pub fn new(
+        tenant_repo: Arc<dyn TenantRepository>,
+        group_repo: Arc<dyn GroupRepository>,
+        source_repo: Arc<dyn BlocklistSourceRepository>,
+        block_filter: Arc<dyn BlockFilterEnginePort>,
+        local_zone: Arc<dyn LocalZonePort>,
+    ) -> Self {
+        Self {
+            tenant_repo,
+            group_repo,
+            source_repo,
+            block_filter,
+            local_zone,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(&self, id: i64) -> Result<(), DomainError> {
+        let tenant = self
+            .tenant_repo
+            .get_by_id(id)
+            .await?
+            .ok_or(DomainError::TenantNotFound(id))?;
+
+        let group_ids = self.tenant_repo.get_group_ids(id).await?;
+        for &group_id in &group_ids {
+            let clients = self.group_repo.count_clients_in_group(group_id).await?;
+            if clients > 0 {
+                return Err(DomainError::GroupHasAssignedClients(clients));
+            }
+        }
+        // A source's legacy `group_id` column keeps its first group from being
+        // deleted, so the tenant's sources go first.
+        for source in self.source_repo.get_all_in_tenant(id).await? {
+            if let Some(source_id) = source.id {
+                self.source_repo.delete_in_tenant(id, source_id).await?;
+            }
+        }
+        for group_id in group_ids {
+            self.group_repo.delete(group_id).await?;
+        }
+
+        self.tenant_repo.delete(id).await?;
+
+        info!(tenant_id = id, name = %tenant.name, "Tenant deleted successfully");
+
+        if let Err(e) = self.local_zone.reload().await {
+            error!(error = %e, "Failed to reload local zone after tenant deletion");
+        }
+        reload_tenant_routing(self.block_filter.as_ref()).await;
+
+        Ok(())
+    }
+}
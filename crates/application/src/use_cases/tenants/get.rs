@@ -0,0 +1,54 @@
+use ferrous_dns_domain::{DomainError, Group, Tenant, TenantStats};
+use std::sync::Arc;
+use tracing::instrument;
+
+use crate::ports::{GroupRepository, TenantRepository};
+
+pub struct GetTenantsUseCase {
+    tenant_repo: Arc<dyn TenantRepository>,
+    group_repo: Arc<dyn GroupRepository>,
+}
+
+impl GetTenantsUseCase {
+    pub fn new(
+        tenant_repo: Arc<dyn TenantRepository>,
+        group_repo: Arc<dyn GroupRepository>,
+    ) -> Self {
+        Self {
+            tenant_repo,
+            group_repo,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_all(&self) -> Result<Vec<Tenant>, DomainError> {
+        self.tenant_repo.get_all().await
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_by_id(&self, id: i64) -> Result<Tenant, DomainError> {
+        self.tenant_repo
+            .get_by_id(id)
+            .await?
+            .ok_or(DomainError::TenantNotFound(id))
+    }
+
+    /// Groups owned by the tenant, with their client counts.
+    #[instrument(skip(self))]
+    pub async fn get_groups(&self, tenant_id: i64) -> Result<Vec<(Group, u64)>, DomainError> {
+        self.get_by_id(tenant_id).await?;
+        self.group_repo
+            .get_in_tenant_with_client_counts(tenant_id)
+            .await
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_stats(
+        &self,
+        tenant_id: i64,
+        period_hours: f32,
+    ) -> Result<TenantStats, DomainError> {
+        self.get_by_id(tenant_id).await?;
+        self.tenant_repo.get_stats(tenant_id, period_hours).await
+    }
+}
@@ -0,0 +1,63 @@
+use ferrous_dns_domain::{DomainError, Group};
+use std::sync::Arc;
+use tracing::{info, instrument, warn};
+
+use super::reload_tenant_routing;
+use crate::ports::{BlockFilterEnginePort, GroupRepository, TenantRepository};
+
+/// Creates a group owned by a tenant.
+pub struct CreateTenantGroupUseCase {
+    tenant_repo: Arc<dyn TenantRepository>,
+    group_repo: Arc<dyn GroupRepository>,
+    block_filter: Arc<dyn BlockFilterEnginePort>,
+}
+
+impl CreateTenantGroupUseCase {
+    pub fn new(
+        tenant_repo: Arc<dyn TenantRepository>,
+        group_repo: Arc<dyn GroupRepository>,
+        block_filter: Arc<dyn BlockFilterEnginePort>,
+    ) -> Self {
+        Self {
+            tenant_repo,
+            group_repo,
+            block_filter,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        tenant_id: i64,
+        name: String,
+        comment: Option<String>,
+    ) -> Result<Group, DomainError> {
+        self.tenant_repo
+            .get_by_id(tenant_id)
+            .await?
+            .ok_or(DomainError::TenantNotFound(tenant_id))?;
+
+        Group::validate_name(&name).map_err(DomainError::InvalidGroupName)?;
+        let comment_arc = comment.as_ref().map(|s| Arc::from(s.as_str()));
+        Group::validate_comment(&comment_arc).map_err(DomainError::InvalidGroupName)?;
+
+        let mut group = self.group_repo.create(name, comment).await?;
+        let group_id = group
+            .id
+            .ok_or_else(|| DomainError::DatabaseError("created group has no id".to_string()))?;
+
+        if let Err(e) = self.tenant_repo.assign_group(tenant_id, group_id).await {
+            if let Err(cleanup) = self.group_repo.delete(group_id).await {
+                warn!(group_id, error = %cleanup, "Failed to roll back tenant group creation");
+            }
+            return Err(e);
+        }
+        group.tenant_id = Some(tenant_id);
+
+        info!(tenant_id, group_id, name = %group.name, "Tenant group created successfully");
+
+        reload_tenant_routing(self.block_filter.as_ref()).await;
+
+        Ok(group)
+    }
+}
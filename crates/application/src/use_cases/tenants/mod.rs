@@ -0,0 +1,23 @@
+pub mod blocklist_sources;
+pub mod create;
+pub mod delete;
+pub mod get;
+pub mod groups;
+pub mod update;
+
+pub use blocklist_sources::TenantBlocklistSourcesUseCase;
+pub use create::CreateTenantUseCase;
+pub use delete::DeleteTenantUseCase;
+pub use get::GetTenantsUseCase;
+pub use groups::CreateTenantGroupUseCase;
+pub use update::UpdateTenantUseCase;
+
+use crate::ports::BlockFilterEnginePort;
+use tracing::error;
+
+/// Makes tenant subnet and group ownership changes visible to the DNS path.
+async fn reload_tenant_routing(block_filter: &dyn BlockFilterEnginePort) {
+    if let Err(e) = block_filter.load_client_groups().await {
+        error!(error = %e, "Failed to reload client groups after a tenant change");
+    }
+}
@@ -0,0 +1,63 @@
+use ferrous_dns_domain::{DomainError, Tenant};
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use super::reload_tenant_routing;
+use crate::ports::{BlockFilterEnginePort, TenantRepository};
+
+pub struct UpdateTenantUseCase {
+    tenant_repo: Arc<dyn TenantRepository>,
+    block_filter: Arc<dyn BlockFilterEnginePort>,
+}
+
+impl UpdateTenantUseCase {
+    pub fn new(
+        tenant_repo: Arc<dyn TenantRepository>,
+        block_filter: Arc<dyn BlockFilterEnginePort>,
+    ) -> Self {
+        Self {
+            tenant_repo,
+            block_filter,
+        }
+    }
+
+    /// Replaces the stored tenant `id` with `tenant`. The default group must
+    /// be one of the tenant's own groups.
+    #[instrument(skip(self))]
+    pub async fn execute(&self, id: i64, mut tenant: Tenant) -> Result<Tenant, DomainError> {
+        self.tenant_repo
+            .get_by_id(id)
+            .await?
+            .ok_or(DomainError::TenantNotFound(id))?;
+
+        tenant.id = Some(id);
+        tenant.validate().map_err(DomainError::InvalidTenant)?;
+
+        if let Some(group_id) = tenant.default_group_id {
+            if !self
+                .tenant_repo
+                .get_group_ids(id)
+                .await?
+                .contains(&group_id)
+            {
+                return Err(DomainError::InvalidTenant(format!(
+                    "Group {} does not belong to tenant {}",
+                    group_id, id
+                )));
+            }
+        }
+
+        let updated = self.tenant_repo.update(&tenant).await?;
+
+        info!(
+            tenant_id = id,
+            name = %updated.name,
+            subnets = updated.subnets.len(),
+            "Tenant updated successfully"
+        );
+
+        reload_tenant_routing(self.block_filter.as_ref()).await;
+
+        Ok(updated)
+    }
+}
@@ -0,0 +1,44 @@
+use ferrous_dns_domain::DomainError;
+use std::sync::Arc;
+use tracing::{error, info, instrument};
+
+use crate::ports::{BlockFilterEnginePort, GroupRepository, TldPolicyRepository};
+
+/// Removes the TLD policy of a group, allowing every TLD again.
+pub struct DeleteTldPolicyUseCase {
+    repo: Arc<dyn TldPolicyRepository>,
+    group_repo: Arc<dyn GroupRepository>,
+    block_filter_engine: Arc<dyn BlockFilterEnginePort>,
+}
+
+impl DeleteTldPolicyUseCase {
+    pub fn new(
+        repo: Arc<dyn TldPolicyRepository>,
+        group_repo: Arc<dyn GroupRepository>,
+        block_filter_engine: Arc<dyn BlockFilterEnginePort>,
+    ) -> Self {
+        Self {
+            repo,
+            group_repo,
+            block_filter_engine,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(&self, group_id: i64) -> Result<(), DomainError> {
+        self.group_repo
+            .get_by_id(group_id)
+            .await?
+            .ok_or(DomainError::GroupNotFound(group_id))?;
+
+        self.repo.delete_by_group(group_id).await?;
+
+        info!(group_id = group_id, "TLD policy removed for group");
+
+        if let Err(e) = self.block_filter_engine.reload().await {
+            error!(error = %e, "Failed to reload block filter after TLD policy removal");
+        }
+
+        Ok(())
+    }
+}
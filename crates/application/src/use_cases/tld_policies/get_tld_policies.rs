@@ -0,0 +1,30 @@
+use ferrous_dns_domain::{DomainError, TldPolicy};
+use std::sync::Arc;
+
+use crate::ports::{GroupRepository, TldPolicyRepository};
+
+/// Retrieves TLD policies, optionally for a single group.
+pub struct GetTldPoliciesUseCase {
+    repo: Arc<dyn TldPolicyRepository>,
+    group_repo: Arc<dyn GroupRepository>,
+}
+
+impl GetTldPoliciesUseCase {
+    pub fn new(repo: Arc<dyn TldPolicyRepository>, group_repo: Arc<dyn GroupRepository>) -> Self {
+        Self { repo, group_repo }
+    }
+
+    pub async fn get_all(&self) -> Result<Vec<TldPolicy>, DomainError> {
+        self.repo.get_all().await
+    }
+
+    /// Returns [`DomainError::GroupNotFound`] if `group_id` does not exist,
+    /// and `Ok(None)` if the group has no policy.
+    pub async fn get_by_group(&self, group_id: i64) -> Result<Option<TldPolicy>, DomainError> {
+        self.group_repo
+            .get_by_id(group_id)
+            .await?
+            .ok_or(DomainError::GroupNotFound(group_id))?;
+        self.repo.get_by_group(group_id).await
+    }
+}
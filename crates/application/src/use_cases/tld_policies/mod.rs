@@ -0,0 +1,7 @@
+mod delete_tld_policy;
+mod get_tld_policies;
+mod set_tld_policy;
+
+pub use delete_tld_policy::DeleteTldPolicyUseCase;
+pub use get_tld_policies::GetTldPoliciesUseCase;
+pub use set_tld_policy::SetTldPolicyUseCase;
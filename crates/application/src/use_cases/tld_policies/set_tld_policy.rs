@@ -0,0 +1,54 @@
+use ferrous_dns_domain::{DomainError, TldPolicy};
+use std::sync::Arc;
+use tracing::{error, info, instrument};
+
+use crate::ports::{BlockFilterEnginePort, GroupRepository, TldPolicyRepository};
+
+/// Sets (creates or replaces) the TLD policy of a group.
+///
+/// TLD policies are compiled into the block index, so the block filter is
+/// reloaded after the change is persisted.
+pub struct SetTldPolicyUseCase {
+    repo: Arc<dyn TldPolicyRepository>,
+    group_repo: Arc<dyn GroupRepository>,
+    block_filter_engine: Arc<dyn BlockFilterEnginePort>,
+}
+
+impl SetTldPolicyUseCase {
+    pub fn new(
+        repo: Arc<dyn TldPolicyRepository>,
+        group_repo: Arc<dyn GroupRepository>,
+        block_filter_engine: Arc<dyn BlockFilterEnginePort>,
+    ) -> Self {
+        Self {
+            repo,
+            group_repo,
+            block_filter_engine,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(&self, policy: TldPolicy) -> Result<TldPolicy, DomainError> {
+        policy.validate().map_err(DomainError::InvalidTldPolicy)?;
+
+        self.group_repo
+            .get_by_id(policy.group_id)
+            .await?
+            .ok_or(DomainError::GroupNotFound(policy.group_id))?;
+
+        let saved = self.repo.upsert(&policy).await?;
+
+        info!(
+            group_id = saved.group_id,
+            mode = saved.mode.to_str(),
+            tlds = saved.tlds.len(),
+            "TLD policy updated"
+        );
+
+        if let Err(e) = self.block_filter_engine.reload().await {
+            error!(error = %e, "Failed to reload block filter after TLD policy update");
+        }
+
+        Ok(saved)
+    }
+}
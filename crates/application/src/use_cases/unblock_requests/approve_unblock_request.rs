@@ -0,0 +1,90 @@
+use ferrous_dns_domain::{DomainAction, DomainError, UnblockRequest, UnblockRequestStatus};
+use std::sync::Arc;
+use tracing::{error, info, instrument};
+
+use crate::ports::{
+    BlockFilterEnginePort, GroupRepository, ManagedDomainRepository, UnblockRequestRepository,
+};
+
+/// Approves a pending unblock request by allowing its domain for a group,
+/// the requesting client's unless another one is given.
+pub struct ApproveUnblockRequestUseCase {
+    repo: Arc<dyn UnblockRequestRepository>,
+    managed_domain_repo: Arc<dyn ManagedDomainRepository>,
+    group_repo: Arc<dyn GroupRepository>,
+    block_filter_engine: Arc<dyn BlockFilterEnginePort>,
+}
+
+impl ApproveUnblockRequestUseCase {
+    pub fn new(
+        repo: Arc<dyn UnblockRequestRepository>,
+        managed_domain_repo: Arc<dyn ManagedDomainRepository>,
+        group_repo: Arc<dyn GroupRepository>,
+        block_filter_engine: Arc<dyn BlockFilterEnginePort>,
+    ) -> Self {
+        Self {
+            repo,
+            managed_domain_repo,
+            group_repo,
+            block_filter_engine,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        id: i64,
+        group_id: Option<i64>,
+    ) -> Result<UnblockRequest, DomainError> {
+        let request = self
+            .repo
+            .get_by_id(id)
+            .await?
+            .ok_or(DomainError::UnblockRequestNotFound(id))?;
+        if request.status != UnblockRequestStatus::Pending {
+            return Err(DomainError::UnblockRequestAlreadyReviewed(id));
+        }
+
+        let group_id = group_id.or(request.group_id).ok_or_else(|| {
+            DomainError::InvalidUnblockRequest(format!(
+                "Unblock request {} has no group; choose one to allow the domain for",
+                id
+            ))
+        })?;
+        self.group_repo
+            .get_by_id(group_id)
+            .await?
+            .ok_or(DomainError::GroupNotFound(group_id))?;
+
+        let managed_domain = self
+            .managed_domain_repo
+            .create(
+                format!("Unblock request {}", id),
+                request.domain.to_string(),
+                DomainAction::Allow,
+                group_id,
+                Some(format!("Unblock requested by {}", request.client_ip)),
+                true,
+            )
+            .await?;
+
+        let approved = self
+            .repo
+            .review(id, UnblockRequestStatus::Approved, managed_domain.id)
+            .await?;
+
+        info!(
+            request_id = id,
+            domain = %approved.domain,
+            group_id,
+            managed_domain_id = ?managed_domain.id,
+            "Unblock request approved"
+        );
+
+        if let Err(e) = self.block_filter_engine.reload().await {
+            error!(error = %e, "Failed to reload block filter after approving unblock request");
+        }
+
+        Ok(approved)
+    }
+}
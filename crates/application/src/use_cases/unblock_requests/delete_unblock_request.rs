@@ -0,0 +1,28 @@
+use ferrous_dns_domain::DomainError;
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use crate::ports::UnblockRequestRepository;
+
+pub struct DeleteUnblockRequestUseCase {
+    repo: Arc<dyn UnblockRequestRepository>,
+}
+
+impl DeleteUnblockRequestUseCase {
+    pub fn new(repo: Arc<dyn UnblockRequestRepository>) -> Self {
+        Self { repo }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(&self, id: i64) -> Result<(), DomainError> {
+        self.repo
+            .get_by_id(id)
+            .await?
+            .ok_or(DomainError::UnblockRequestNotFound(id))?;
+
+        self.repo.delete(id).await?;
+
+        info!(request_id = id, "Unblock request deleted successfully");
+        Ok(())
+    }
+}
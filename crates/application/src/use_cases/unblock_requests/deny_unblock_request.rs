@@ -0,0 +1,35 @@
+use ferrous_dns_domain::{DomainError, UnblockRequest, UnblockRequestStatus};
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use crate::ports::UnblockRequestRepository;
+
+pub struct DenyUnblockRequestUseCase {
+    repo: Arc<dyn UnblockRequestRepository>,
+}
+
+impl DenyUnblockRequestUseCase {
+    pub fn new(repo: Arc<dyn UnblockRequestRepository>) -> Self {
+        Self { repo }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(&self, id: i64) -> Result<UnblockRequest, DomainError> {
+        let request = self
+            .repo
+            .get_by_id(id)
+            .await?
+            .ok_or(DomainError::UnblockRequestNotFound(id))?;
+        if request.status != UnblockRequestStatus::Pending {
+            return Err(DomainError::UnblockRequestAlreadyReviewed(id));
+        }
+
+        let denied = self
+            .repo
+            .review(id, UnblockRequestStatus::Denied, None)
+            .await?;
+
+        info!(request_id = id, domain = %denied.domain, "Unblock request denied");
+        Ok(denied)
+    }
+}
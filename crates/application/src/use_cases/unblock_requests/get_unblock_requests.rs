@@ -0,0 +1,28 @@
+use ferrous_dns_domain::{DomainError, UnblockRequest, UnblockRequestStatus};
+use std::sync::Arc;
+use tracing::instrument;
+
+use crate::ports::UnblockRequestRepository;
+
+pub struct GetUnblockRequestsUseCase {
+    repo: Arc<dyn UnblockRequestRepository>,
+}
+
+impl GetUnblockRequestsUseCase {
+    pub fn new(repo: Arc<dyn UnblockRequestRepository>) -> Self {
+        Self { repo }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_all(
+        &self,
+        status: Option<UnblockRequestStatus>,
+    ) -> Result<Vec<UnblockRequest>, DomainError> {
+        self.repo.get_all(status).await
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_by_id(&self, id: i64) -> Result<Option<UnblockRequest>, DomainError> {
+        self.repo.get_by_id(id).await
+    }
+}
@@ -0,0 +1,11 @@
+mod approve_unblock_request;
+mod delete_unblock_request;
+mod deny_unblock_request;
+mod get_unblock_requests;
+mod submit_unblock_request;
+
+pub use approve_unblock_request::ApproveUnblockRequestUseCase;
+pub use delete_unblock_request::DeleteUnblockRequestUseCase;
+pub use deny_unblock_request::DenyUnblockRequestUseCase;
+pub use get_unblock_requests::GetUnblockRequestsUseCase;
+pub use submit_unblock_request::SubmitUnblockRequestUseCase;
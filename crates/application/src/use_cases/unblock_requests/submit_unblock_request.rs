@@ -0,0 +1,62 @@
+use ferrous_dns_domain::{DomainError, UnblockRequest, MAX_UNBLOCK_REQUESTS};
+use std::net::IpAddr;
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use crate::ports::{BlockFilterEnginePort, UnblockRequestRepository};
+
+/// Files an unblock request from the block page or the public endpoint.
+/// Anyone who can reach those can call this, so the number of pending
+/// requests is capped.
+pub struct SubmitUnblockRequestUseCase {
+    repo: Arc<dyn UnblockRequestRepository>,
+    block_filter_engine: Arc<dyn BlockFilterEnginePort>,
+}
+
+impl SubmitUnblockRequestUseCase {
+    pub fn new(
+        repo: Arc<dyn UnblockRequestRepository>,
+        block_filter_engine: Arc<dyn BlockFilterEnginePort>,
+    ) -> Self {
+        Self {
+            repo,
+            block_filter_engine,
+        }
+    }
+
+    /// Stores `request`, filling in the client's current group when unset.
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        mut request: UnblockRequest,
+    ) -> Result<UnblockRequest, DomainError> {
+        request
+            .validate()
+            .map_err(DomainError::InvalidUnblockRequest)?;
+
+        if request.group_id.is_none() {
+            if let Ok(ip) = request.client_ip.parse::<IpAddr>() {
+                request.group_id = Some(self.block_filter_engine.resolve_group(ip));
+            }
+        }
+
+        if self.repo.count_pending().await? >= MAX_UNBLOCK_REQUESTS as u64 {
+            return Err(DomainError::InvalidUnblockRequest(format!(
+                "At most {} unblock requests can be pending",
+                MAX_UNBLOCK_REQUESTS
+            )));
+        }
+
+        let stored = self.repo.upsert(&request).await?;
+
+        info!(
+            request_id = ?stored.id,
+            domain = %stored.domain,
+            client = %stored.client_ip,
+            status = stored.status.as_str(),
+            "Unblock request filed"
+        );
+
+        Ok(stored)
+    }
+}
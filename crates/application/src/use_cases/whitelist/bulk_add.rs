@@ -0,0 +1,48 @@
+use crate::ports::{BlockFilterEnginePort, WhitelistRepository};
+use crate::use_cases::blocklist::bulk_add::{prepare_bulk_domains, BulkAddSummary};
+use ferrous_dns_domain::{whitelist::WhitelistedDomain, DomainError};
+use std::sync::Arc;
+use tracing::{error, info, instrument};
+
+pub struct BulkAddWhitelistUseCase {
+    repository: Arc<dyn WhitelistRepository>,
+    block_filter_engine: Arc<dyn BlockFilterEnginePort>,
+}
+
+impl BulkAddWhitelistUseCase {
+    pub fn new(
+        repository: Arc<dyn WhitelistRepository>,
+        block_filter_engine: Arc<dyn BlockFilterEnginePort>,
+    ) -> Self {
+        Self {
+            repository,
+            block_filter_engine,
+        }
+    }
+
+    #[instrument(skip(self, entries), fields(entries = entries.len()))]
+    pub async fn execute(&self, entries: &[String]) -> Result<BulkAddSummary, DomainError> {
+        let (domains, mut summary) =
+            prepare_bulk_domains(entries, WhitelistedDomain::validate_domain)?;
+
+        let added = self.repository.add_domains(&domains).await?;
+        summary.added = added.iter().filter(|a| **a).count();
+        summary.duplicates += domains.len() - summary.added;
+
+        info!(
+            received = summary.received,
+            added = summary.added,
+            duplicates = summary.duplicates,
+            errors = summary.errors.len(),
+            "Whitelist bulk add completed"
+        );
+
+        if summary.added > 0 {
+            if let Err(e) = self.block_filter_engine.reload().await {
+                error!(error = %e, "Failed to reload block filter after whitelist bulk add");
+            }
+        }
+
+        Ok(summary)
+    }
+}
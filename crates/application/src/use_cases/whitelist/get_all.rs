@@ -1,5 +1,5 @@
 use crate::ports::WhitelistRepository;
-use ferrous_dns_domain::{whitelist::WhitelistedDomain, DomainError};
+use ferrous_dns_domain::{whitelist::WhitelistedDomain, DomainError, PageRequest};
 use std::sync::Arc;
 
 pub struct GetWhitelistUseCase {
@@ -14,4 +14,11 @@ impl GetWhitelistUseCase {
     pub async fn execute(&self) -> Result<Vec<WhitelistedDomain>, DomainError> {
         self.repository.get_all().await
     }
+
+    pub async fn execute_paged(
+        &self,
+        page: &PageRequest,
+    ) -> Result<(Vec<WhitelistedDomain>, u64), DomainError> {
+        self.repository.get_all_paged(page).await
+    }
 }
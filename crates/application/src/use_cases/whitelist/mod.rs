@@ -1,3 +1,5 @@
+mod bulk_add;
 mod get_all;
 
+pub use bulk_add::BulkAddWhitelistUseCase;
 pub use get_all::GetWhitelistUseCase;
@@ -0,0 +1,130 @@
+mod helpers;
+
+use ferrous_dns_application::ports::{AaaaFilterPort, DnsResolution, GroupRepository};
+use ferrous_dns_application::use_cases::{HandleDnsQueryUseCase, UpdateGroupUseCase};
+use ferrous_dns_domain::{DnsRequest, RecordType};
+use helpers::{
+    MockAaaaFilter, MockBlockFilterEngine, MockDnsResolver, MockGroupRepository,
+    MockQueryLogRepository,
+};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+const CLIENT_IP: IpAddr = IpAddr::V4(std::net::Ipv4Addr::new(192, 168, 1, 100));
+
+struct Fixture {
+    groups: Arc<MockGroupRepository>,
+    filter: Arc<MockAaaaFilter>,
+    log: Arc<MockQueryLogRepository>,
+    use_case: HandleDnsQueryUseCase,
+}
+
+async fn fixture(flag_default_group: bool) -> Fixture {
+    let groups = Arc::new(MockGroupRepository::new());
+    if flag_default_group {
+        groups
+            .update(1, None, None, None, Some(true))
+            .await
+            .unwrap();
+    }
+    let filter = Arc::new(MockAaaaFilter::new(groups.clone()));
+    filter.reload().await.unwrap();
+
+    let resolver = MockDnsResolver::new();
+    resolver
+        .set_response(
+            "example.com",
+            DnsResolution::new(vec!["2001:db8::1".parse().unwrap()], false),
+        )
+        .await;
+    resolver.set_cached_response(
+        "example.com",
+        DnsResolution::new(vec!["2001:db8::1".parse().unwrap()], true),
+    );
+    let log = Arc::new(MockQueryLogRepository::new());
+    let use_case = HandleDnsQueryUseCase::new(
+        Arc::new(resolver),
+        Arc::new(MockBlockFilterEngine::new()),
+        log.clone(),
+    )
+    .with_aaaa_filter(filter.clone());
+
+    Fixture {
+        groups,
+        filter,
+        log,
+        use_case,
+    }
+}
+
+#[tokio::test]
+async fn flagged_group_gets_nodata_for_aaaa() {
+    let f = fixture(true).await;
+
+    let request = DnsRequest::new("example.com", RecordType::AAAA, CLIENT_IP);
+    let result = f.use_case.execute(&request).await.unwrap();
+
+    assert!(result.addresses.is_empty());
+    assert!(result.local_dns);
+    let logs = f.log.get_sync_logs();
+    assert_eq!(logs.len(), 1);
+    assert!(!logs[0].blocked);
+    assert_eq!(logs[0].response_status, Some("AAAA_FILTERED"));
+}
+
+#[tokio::test]
+async fn unflagged_group_resolves_aaaa() {
+    let f = fixture(false).await;
+
+    let request = DnsRequest::new("example.com", RecordType::AAAA, CLIENT_IP);
+    let result = f.use_case.execute(&request).await.unwrap();
+
+    assert_eq!(result.addresses.len(), 1);
+}
+
+#[tokio::test]
+async fn flagged_group_still_resolves_a() {
+    let f = fixture(true).await;
+
+    let request = DnsRequest::new("example.com", RecordType::A, CLIENT_IP);
+    let result = f.use_case.execute(&request).await.unwrap();
+
+    assert_eq!(result.addresses.len(), 1);
+    assert!(!result.local_dns);
+}
+
+#[tokio::test]
+async fn cache_fast_path_skips_filtered_aaaa() {
+    let f = fixture(true).await;
+
+    assert!(f
+        .use_case
+        .try_cache_direct("example.com", RecordType::AAAA, CLIENT_IP, None)
+        .is_none());
+    assert_eq!(f.log.sync_log_count(), 0);
+}
+
+#[tokio::test]
+async fn listener_group_without_flag_is_not_filtered() {
+    let f = fixture(true).await;
+
+    assert!(f
+        .use_case
+        .try_cache_direct("example.com", RecordType::AAAA, CLIENT_IP, Some(7))
+        .is_some());
+}
+
+#[tokio::test]
+async fn updating_group_flag_reloads_filter() {
+    let f = fixture(false).await;
+    let update = UpdateGroupUseCase::new(f.groups.clone()).with_aaaa_filter(f.filter.clone());
+
+    let group = update
+        .execute(1, None, None, None, Some(true), None)
+        .await
+        .unwrap();
+
+    assert!(group.filter_aaaa);
+    assert_eq!(f.filter.reload_count(), 2);
+    assert!(f.filter.filters_aaaa(1));
+}
@@ -0,0 +1,186 @@
+use ferrous_dns_application::ports::QueryLogRepository;
+use ferrous_dns_application::use_cases::{DetectAnomaliesUseCase, GetAlertsUseCase};
+use ferrous_dns_domain::{AlertKind, AnomalyDetectionConfig, QueryLog, QuerySource, RecordType};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+mod helpers;
+use helpers::{MockAlertRepository, MockQueryLogRepository};
+
+fn make_log(client_ip: &str, domain: &str, response_status: &'static str) -> QueryLog {
+    QueryLog {
+        id: None,
+        domain: domain.into(),
+        record_type: RecordType::A,
+        client_ip: client_ip.parse::<IpAddr>().unwrap(),
+        client_hostname: None,
+        client_mac: None,
+        blocked: false,
+        response_time_us: Some(100),
+        cache_hit: false,
+        cache_refresh: false,
+        dnssec_status: None,
+        upstream_server: None,
+        upstream_pool: None,
+        upstream_strategy: None,
+        upstream_attempt: None,
+        upstream_protocol: None,
+        response_status: Some(response_status),
+        timestamp: None,
+        query_source: QuerySource::Client,
+        group_id: None,
+        group_name: None,
+        block_source: None,
+        plugin: None,
+    }
+}
+
+fn config() -> AnomalyDetectionConfig {
+    AnomalyDetectionConfig {
+        enabled: true,
+        min_queries: 20,
+        ..Default::default()
+    }
+}
+
+/// Deterministic base32-looking labels, as produced by tunneling tools.
+fn random_label(seed: u64, len: usize) -> String {
+    const ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz234567";
+    let mut state = seed
+        .wrapping_mul(6364136223846793005)
+        .wrapping_add(1442695040888963407);
+    (0..len)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ALPHABET[(state >> 59) as usize] as char
+        })
+        .collect()
+}
+
+async fn log_many(
+    repo: &MockQueryLogRepository,
+    client_ip: &str,
+    domain: &str,
+    status: &'static str,
+    n: usize,
+) {
+    for _ in 0..n {
+        repo.log_query(&make_log(client_ip, domain, status))
+            .await
+            .unwrap();
+    }
+}
+
+fn detector(
+    query_log: &Arc<MockQueryLogRepository>,
+    alerts: &Arc<MockAlertRepository>,
+    config: AnomalyDetectionConfig,
+) -> DetectAnomaliesUseCase {
+    DetectAnomaliesUseCase::new(query_log.clone(), alerts.clone(), config)
+}
+
+#[tokio::test]
+async fn test_high_nxdomain_rate_raises_alert() {
+    let query_log = Arc::new(MockQueryLogRepository::new());
+    let alerts = Arc::new(MockAlertRepository::new());
+    log_many(&query_log, "10.0.0.5", "nope.example.com", "NXDOMAIN", 30).await;
+    log_many(&query_log, "10.0.0.5", "example.com", "NOERROR", 10).await;
+    log_many(&query_log, "10.0.0.6", "example.com", "NOERROR", 40).await;
+
+    let found = detector(&query_log, &alerts, config())
+        .execute()
+        .await
+        .unwrap();
+
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].client_ip, "10.0.0.5");
+    assert_eq!(found[0].kind, AlertKind::NxdomainRate);
+    assert!((found[0].score - 0.75).abs() < 1e-9);
+    assert_eq!(alerts.all().await.len(), 1);
+}
+
+#[tokio::test]
+async fn test_clients_below_min_queries_are_ignored() {
+    let query_log = Arc::new(MockQueryLogRepository::new());
+    let alerts = Arc::new(MockAlertRepository::new());
+    log_many(&query_log, "10.0.0.5", "nope.example.com", "NXDOMAIN", 10).await;
+
+    let found = detector(&query_log, &alerts, config())
+        .execute()
+        .await
+        .unwrap();
+
+    assert!(found.is_empty());
+}
+
+#[tokio::test]
+async fn test_random_subdomains_raise_entropy_alert() {
+    let query_log = Arc::new(MockQueryLogRepository::new());
+    let alerts = Arc::new(MockAlertRepository::new());
+    for i in 0..30 {
+        let domain = format!("{}.t.example.net", random_label(i, 40));
+        log_many(&query_log, "10.0.0.7", &domain, "NOERROR", 1).await;
+    }
+    for name in ["www", "mail", "api", "cdn"] {
+        let domain = format!("{}.example.org", name);
+        log_many(&query_log, "10.0.0.8", &domain, "NOERROR", 10).await;
+    }
+
+    let found = detector(&query_log, &alerts, config())
+        .execute()
+        .await
+        .unwrap();
+
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].client_ip, "10.0.0.7");
+    assert_eq!(found[0].kind, AlertKind::SubdomainEntropy);
+    assert_eq!(found[0].domain.as_deref(), Some("example.net"));
+    assert!(found[0].score >= found[0].threshold);
+}
+
+#[tokio::test]
+async fn test_volume_spike_needs_a_baseline() {
+    let query_log = Arc::new(MockQueryLogRepository::new());
+    let alerts = Arc::new(MockAlertRepository::new());
+    let detector = detector(&query_log, &alerts, config());
+
+    log_many(&query_log, "10.0.0.9", "example.com", "NOERROR", 25).await;
+    assert!(detector.execute().await.unwrap().is_empty());
+
+    log_many(&query_log, "10.0.0.9", "example.com", "NOERROR", 200).await;
+    let found = detector.execute().await.unwrap();
+
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].kind, AlertKind::VolumeSpike);
+    assert!((found[0].score - 9.0).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn test_get_alerts_filters_by_client_and_kind() {
+    let query_log = Arc::new(MockQueryLogRepository::new());
+    let alerts = Arc::new(MockAlertRepository::new());
+    log_many(&query_log, "10.0.0.5", "nope.example.com", "NXDOMAIN", 30).await;
+    log_many(&query_log, "10.0.0.6", "gone.example.com", "NXDOMAIN", 30).await;
+    detector(&query_log, &alerts, config())
+        .execute()
+        .await
+        .unwrap();
+
+    let get_alerts = GetAlertsUseCase::new(alerts.clone());
+    let all = get_alerts.execute(100, None, None).await.unwrap();
+    let one = get_alerts
+        .execute(100, Some("10.0.0.6"), Some(AlertKind::NxdomainRate))
+        .await
+        .unwrap();
+    let none = get_alerts
+        .execute(100, None, Some(AlertKind::VolumeSpike))
+        .await
+        .unwrap();
+
+    assert_eq!(all.len(), 2);
+    assert_eq!(one.len(), 1);
+    assert_eq!(one[0].client_ip, "10.0.0.6");
+    assert!(none.is_empty());
+}
@@ -34,6 +34,7 @@ impl ApiTokenRepository for MockApiTokenRepo {
         key_prefix: &str,
         key_hash: &str,
         key_raw: &str,
+        tenant_id: Option<i64>,
     ) -> Result<ApiToken, DomainError> {
         let mut tokens = self.tokens.write().await;
         if tokens.iter().any(|t| t.name.as_ref() == name) {
@@ -48,6 +49,7 @@ impl ApiTokenRepository for MockApiTokenRepo {
             key_prefix: Arc::from(key_prefix),
             key_hash: Arc::from(key_hash),
             key_raw: Some(Arc::from(key_raw)),
+            tenant_id,
             created_at: Some("2026-01-01 00:00:00".to_string()),
             last_used_at: None,
         };
@@ -149,6 +151,16 @@ impl ApiTokenRepository for MockApiTokenRepo {
             .find(|t| t.key_hash.as_ref() == key_hash)
             .and_then(|t| t.id))
     }
+
+    async fn get_by_hash(&self, key_hash: &str) -> Result<Option<ApiToken>, DomainError> {
+        Ok(self
+            .tokens
+            .read()
+            .await
+            .iter()
+            .find(|t| t.key_hash.as_ref() == key_hash)
+            .cloned())
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -429,3 +441,30 @@ async fn validate_empty_repo_returns_invalid_credentials() {
     let err = validate.execute("any-token").await.unwrap_err();
     assert!(matches!(err, DomainError::InvalidCredentials));
 }
+
+#[tokio::test]
+async fn authenticate_returns_tenant_scope() {
+    let repo = Arc::new(MockApiTokenRepo::new());
+    let create = CreateApiTokenUseCase::new(repo.clone());
+    let created = create
+        .execute_for_tenant("tenant-token", None, Some(7))
+        .await
+        .unwrap();
+    assert_eq!(created.token.tenant_id, Some(7));
+
+    let validate = ValidateApiTokenUseCase::new(repo.clone());
+    let token = validate.authenticate(&created.raw_token).await.unwrap();
+    assert_eq!(token.tenant_id, Some(7));
+
+    let stored = repo.get_by_id(token.id.unwrap()).await.unwrap().unwrap();
+    assert!(stored.last_used_at.is_some());
+}
+
+#[tokio::test]
+async fn authenticate_wrong_token_returns_invalid_credentials() {
+    let repo = Arc::new(MockApiTokenRepo::new());
+    let validate = ValidateApiTokenUseCase::new(repo);
+
+    let err = validate.authenticate("any-token").await.unwrap_err();
+    assert!(matches!(err, DomainError::InvalidCredentials));
+}
@@ -0,0 +1,227 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{
+    BackupArchiver, BackupStore, BackupTables, ConfigFilePersistence,
+};
+use ferrous_dns_application::use_cases::{
+    BackupFormat, CreateBackupUseCase, FullBackup, RestoreBackupUseCase,
+};
+use ferrous_dns_domain::{Config, DomainError};
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::RwLock;
+
+struct InMemoryStore {
+    tables: Mutex<BackupTables>,
+    fail_restore: bool,
+}
+
+impl InMemoryStore {
+    fn new(fail_restore: bool) -> Self {
+        let mut tables = BackupTables::new();
+        tables.insert(
+            "groups".to_string(),
+            vec![json!({ "id": 1, "name": "Protected" })
+                .as_object()
+                .unwrap()
+                .clone()],
+        );
+        Self {
+            tables: Mutex::new(tables),
+            fail_restore,
+        }
+    }
+}
+
+#[async_trait]
+impl BackupStore for InMemoryStore {
+    async fn dump(&self) -> Result<BackupTables, DomainError> {
+        Ok(self.tables.lock().unwrap().clone())
+    }
+
+    async fn restore(&self, tables: &BackupTables) -> Result<BTreeMap<String, usize>, DomainError> {
+        if self.fail_restore {
+            return Err(DomainError::InvalidInput("constraint failed".to_string()));
+        }
+        *self.tables.lock().unwrap() = tables.clone();
+        Ok(tables.iter().map(|(k, v)| (k.clone(), v.len())).collect())
+    }
+}
+
+/// Flattens files into `name\0contents\0` records; enough to exercise the tar.gz path.
+struct PlainArchiver;
+
+impl BackupArchiver for PlainArchiver {
+    fn pack(&self, files: &[(String, Vec<u8>)]) -> Result<Vec<u8>, DomainError> {
+        let mut out = vec![0x1f, 0x8b];
+        for (name, contents) in files {
+            out.extend_from_slice(name.as_bytes());
+            out.push(0);
+            out.extend_from_slice(contents);
+            out.push(0);
+        }
+        Ok(out)
+    }
+
+    fn unpack(&self, bytes: &[u8]) -> Result<Vec<(String, Vec<u8>)>, DomainError> {
+        let parts: Vec<&[u8]> = bytes[2..].split(|b| *b == 0).collect();
+        Ok(parts
+            .chunks(2)
+            .filter(|c| c.len() == 2)
+            .map(|c| (String::from_utf8_lossy(c[0]).into_owned(), c[1].to_vec()))
+            .collect())
+    }
+}
+
+#[derive(Default)]
+struct RecordingPersistence {
+    saved: Mutex<Vec<Config>>,
+}
+
+impl ConfigFilePersistence for RecordingPersistence {
+    fn save_config_to_file(&self, config: &Config, _path: &str) -> Result<(), String> {
+        self.saved.lock().unwrap().push(config.clone());
+        Ok(())
+    }
+}
+
+fn config_with_secret() -> Arc<RwLock<Config>> {
+    let mut config = Config::default();
+    config.auth.admin.password_hash = Some("$argon2id$hash".to_string());
+    config.dns.dns_cookies.server_secret = "ab".repeat(32);
+    Arc::new(RwLock::new(config))
+}
+
+#[tokio::test]
+async fn test_create_backup_strips_secrets() {
+    let config = config_with_secret();
+    let use_case = CreateBackupUseCase::new(
+        config,
+        Arc::new(InMemoryStore::new(false)),
+        Arc::new(PlainArchiver),
+    );
+
+    let bytes = use_case.execute(BackupFormat::Json).await.unwrap();
+    let backup = FullBackup::decode(&bytes, &PlainArchiver).unwrap();
+
+    assert!(backup.config.auth.admin.password_hash.is_none());
+    assert!(backup.config.dns.dns_cookies.server_secret.is_empty());
+    assert_eq!(backup.tables["groups"].len(), 1);
+}
+
+#[tokio::test]
+async fn test_archive_format_round_trips() {
+    let config = config_with_secret();
+    let use_case = CreateBackupUseCase::new(
+        config,
+        Arc::new(InMemoryStore::new(false)),
+        Arc::new(PlainArchiver),
+    );
+
+    let bytes = use_case.execute(BackupFormat::TarGz).await.unwrap();
+    let backup = FullBackup::decode(&bytes, &PlainArchiver).unwrap();
+
+    assert_eq!(backup.version, "1");
+    assert_eq!(backup.tables["groups"][0]["name"], "Protected");
+}
+
+#[tokio::test]
+async fn test_restore_keeps_local_secrets_and_database_path() {
+    let config = config_with_secret();
+    let store = Arc::new(InMemoryStore::new(false));
+    let bytes = CreateBackupUseCase::new(config.clone(), store.clone(), Arc::new(PlainArchiver))
+        .execute(BackupFormat::Json)
+        .await
+        .unwrap();
+    let mut backup: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+    backup["config"]["database"]["path"] = json!("/elsewhere/ferrous.db");
+    backup["config"]["blocking"]["enabled"] = json!(false);
+
+    let use_case = RestoreBackupUseCase::new(
+        config.clone(),
+        Arc::new(RecordingPersistence::default()),
+        Some("ferrous-dns.toml".to_string()),
+        store,
+        Arc::new(PlainArchiver),
+    );
+    let summary = use_case
+        .execute(&serde_json::to_vec(&backup).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(summary.tables["groups"], 1);
+    let config = config.read().await;
+    assert!(!config.blocking.enabled);
+    assert_eq!(
+        config.auth.admin.password_hash.as_deref(),
+        Some("$argon2id$hash")
+    );
+    assert_eq!(config.dns.dns_cookies.server_secret, "ab".repeat(32));
+    assert_ne!(config.database.path, "/elsewhere/ferrous.db");
+}
+
+#[tokio::test]
+async fn test_failed_restore_reverts_config_file() {
+    let config = config_with_secret();
+    let bytes = CreateBackupUseCase::new(
+        config.clone(),
+        Arc::new(InMemoryStore::new(false)),
+        Arc::new(PlainArchiver),
+    )
+    .execute(BackupFormat::Json)
+    .await
+    .unwrap();
+    let mut backup: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+    backup["config"]["blocking"]["enabled"] = json!(false);
+
+    let persistence = Arc::new(RecordingPersistence::default());
+    let use_case = RestoreBackupUseCase::new(
+        config.clone(),
+        persistence.clone(),
+        Some("ferrous-dns.toml".to_string()),
+        Arc::new(InMemoryStore::new(true)),
+        Arc::new(PlainArchiver),
+    );
+
+    let result = use_case
+        .execute(&serde_json::to_vec(&backup).unwrap())
+        .await;
+
+    assert!(result.is_err());
+    assert!(config.read().await.blocking.enabled);
+    let saved = persistence.saved.lock().unwrap();
+    assert_eq!(saved.len(), 2);
+    assert!(!saved[0].blocking.enabled);
+    assert!(saved[1].blocking.enabled);
+}
+
+#[tokio::test]
+async fn test_restore_rejects_unsupported_version() {
+    let config = config_with_secret();
+    let bytes = CreateBackupUseCase::new(
+        config.clone(),
+        Arc::new(InMemoryStore::new(false)),
+        Arc::new(PlainArchiver),
+    )
+    .execute(BackupFormat::Json)
+    .await
+    .unwrap();
+    let mut backup: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+    backup["version"] = json!("2");
+
+    let persistence = Arc::new(RecordingPersistence::default());
+    let use_case = RestoreBackupUseCase::new(
+        config,
+        persistence.clone(),
+        Some("ferrous-dns.toml".to_string()),
+        Arc::new(InMemoryStore::new(false)),
+        Arc::new(PlainArchiver),
+    );
+
+    let result = use_case
+        .execute(&serde_json::to_vec(&backup).unwrap())
+        .await;
+
+    assert!(matches!(result, Err(DomainError::InvalidInput(_))));
+    assert!(persistence.saved.lock().unwrap().is_empty());
+}
@@ -248,7 +248,7 @@ async fn test_update_toggle_enabled() {
     let id = source.id.unwrap();
 
     let result = update_uc
-        .execute(id, None, None, None, None, Some(false))
+        .execute(id, None, None, None, None, Some(false), None)
         .await;
 
     assert!(result.is_ok());
@@ -271,7 +271,7 @@ async fn test_update_change_groups() {
     let id = source.id.unwrap();
 
     let result = update_uc
-        .execute(id, None, None, Some(vec![2]), None, None)
+        .execute(id, None, None, Some(vec![2]), None, None, None)
         .await;
 
     assert!(result.is_ok());
@@ -294,7 +294,7 @@ async fn test_update_assign_multiple_groups() {
     let id = source.id.unwrap();
 
     let result = update_uc
-        .execute(id, None, None, Some(vec![1, 2]), None, None)
+        .execute(id, None, None, Some(vec![1, 2]), None, None, None)
         .await;
 
     assert!(result.is_ok());
@@ -311,7 +311,7 @@ async fn test_update_source_not_found() {
     let use_case = UpdateBlocklistSourceUseCase::new(repo, group_repo);
 
     let result = use_case
-        .execute(999, None, None, None, None, Some(false))
+        .execute(999, None, None, None, None, Some(false), None)
         .await;
 
     assert!(result.is_err());
@@ -334,7 +334,15 @@ async fn test_update_invalid_group() {
         .unwrap();
 
     let result = update_uc
-        .execute(source.id.unwrap(), None, None, Some(vec![999]), None, None)
+        .execute(
+            source.id.unwrap(),
+            None,
+            None,
+            Some(vec![999]),
+            None,
+            None,
+            None,
+        )
         .await;
 
     assert!(result.is_err());
@@ -363,7 +371,7 @@ async fn test_update_clear_url() {
         .unwrap();
 
     let result = update_uc
-        .execute(source.id.unwrap(), None, Some(None), None, None, None)
+        .execute(source.id.unwrap(), None, Some(None), None, None, None, None)
         .await;
 
     assert!(result.is_ok());
@@ -1,10 +1,12 @@
 use ferrous_dns_application::ports::BlocklistRepository;
-use ferrous_dns_application::use_cases::blocklist::GetBlocklistUseCase;
+use ferrous_dns_application::use_cases::blocklist::{
+    BulkAddBlocklistUseCase, GetBlocklistUseCase, MAX_BULK_DOMAINS,
+};
 use ferrous_dns_domain::blocklist::BlockedDomain;
 use std::sync::Arc;
 
 mod helpers;
-use helpers::MockBlocklistRepository;
+use helpers::{MockBlockFilterEngine, MockBlocklistRepository};
 
 #[tokio::test]
 async fn test_get_empty_blocklist() {
@@ -324,3 +326,104 @@ async fn test_concurrent_add_remove() {
 
     assert_eq!(repository.count().await, 2);
 }
+
+fn entries(items: &[&str]) -> Vec<String> {
+    items.iter().map(|s| s.to_string()).collect()
+}
+
+#[tokio::test]
+async fn test_bulk_add_inserts_normalized_domains() {
+    let repository = Arc::new(MockBlocklistRepository::new());
+    let engine = Arc::new(MockBlockFilterEngine::new());
+    let use_case = BulkAddBlocklistUseCase::new(repository.clone(), engine.clone());
+
+    let summary = use_case
+        .execute(&entries(&[
+            "Ads.Example.com.",
+            "  tracker.com  ",
+            "",
+            "# comment",
+        ]))
+        .await
+        .unwrap();
+
+    assert_eq!(summary.received, 2);
+    assert_eq!(summary.added, 2);
+    assert_eq!(summary.duplicates, 0);
+    assert!(summary.errors.is_empty());
+    let stored: Vec<String> = repository
+        .get_all()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|d| d.domain)
+        .collect();
+    assert_eq!(stored, vec!["ads.example.com", "tracker.com"]);
+    assert_eq!(engine.reload_count().await, 1);
+}
+
+#[tokio::test]
+async fn test_bulk_add_reports_invalid_entries_by_index() {
+    let repository = Arc::new(MockBlocklistRepository::new());
+    let engine = Arc::new(MockBlockFilterEngine::new());
+    let use_case = BulkAddBlocklistUseCase::new(repository, engine);
+
+    let summary = use_case
+        .execute(&entries(&["good.com", "bad domain.com", "*.wild.com"]))
+        .await
+        .unwrap();
+
+    assert_eq!(summary.received, 3);
+    assert_eq!(summary.added, 1);
+    assert_eq!(summary.errors.len(), 2);
+    assert_eq!(summary.errors[0].index, 1);
+    assert_eq!(summary.errors[0].entry, "bad domain.com");
+    assert_eq!(summary.errors[1].index, 2);
+}
+
+#[tokio::test]
+async fn test_bulk_add_counts_duplicates() {
+    let repository = Arc::new(MockBlocklistRepository::with_blocked_domains(vec![
+        "existing.com",
+    ]));
+    let engine = Arc::new(MockBlockFilterEngine::new());
+    let use_case = BulkAddBlocklistUseCase::new(repository, engine.clone());
+
+    let summary = use_case
+        .execute(&entries(&["existing.com", "new.com", "NEW.com"]))
+        .await
+        .unwrap();
+
+    assert_eq!(summary.added, 1);
+    assert_eq!(summary.duplicates, 2);
+    assert_eq!(engine.reload_count().await, 1);
+}
+
+#[tokio::test]
+async fn test_bulk_add_skips_reload_when_nothing_added() {
+    let repository = Arc::new(MockBlocklistRepository::with_blocked_domains(vec![
+        "existing.com",
+    ]));
+    let engine = Arc::new(MockBlockFilterEngine::new());
+    let use_case = BulkAddBlocklistUseCase::new(repository, engine.clone());
+
+    let summary = use_case.execute(&entries(&["existing.com"])).await.unwrap();
+
+    assert_eq!(summary.added, 0);
+    assert_eq!(engine.reload_count().await, 0);
+}
+
+#[tokio::test]
+async fn test_bulk_add_rejects_oversized_request() {
+    let repository = Arc::new(MockBlocklistRepository::new());
+    let engine = Arc::new(MockBlockFilterEngine::new());
+    let use_case = BulkAddBlocklistUseCase::new(repository.clone(), engine);
+
+    let too_many: Vec<String> = (0..=MAX_BULK_DOMAINS)
+        .map(|i| format!("host{i}.example.com"))
+        .collect();
+    let result = use_case.execute(&too_many).await;
+
+    assert!(result.is_err());
+    assert!(repository.get_all().await.unwrap().is_empty());
+}
@@ -0,0 +1,96 @@
+use ferrous_dns_application::ports::{QueryLogRepository, TimeGranularity};
+use ferrous_dns_application::use_cases::GetClientActivityUseCase;
+use ferrous_dns_domain::{Client, DomainError, QueryLog, QuerySource, RecordType};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+mod helpers;
+use helpers::{MockClientRepository, MockQueryLogRepository};
+
+fn make_client(id: i64, ip: &str) -> Client {
+    let now = chrono::Utc::now().to_rfc3339();
+    Client {
+        id: Some(id),
+        ip_address: ip.parse().unwrap(),
+        mac_address: None,
+        hostname: None,
+        first_seen: Some(now.clone()),
+        last_seen: Some(now),
+        query_count: 0,
+        last_mac_update: None,
+        last_hostname_update: None,
+        group_id: None,
+        display_name: None,
+        category: None,
+        notes: None,
+    }
+}
+
+fn make_log(client_ip: &str, blocked: bool, cache_hit: bool) -> QueryLog {
+    QueryLog {
+        id: None,
+        domain: "example.com".into(),
+        record_type: RecordType::A,
+        client_ip: client_ip.parse::<IpAddr>().unwrap(),
+        client_hostname: None,
+        client_mac: None,
+        blocked,
+        response_time_us: Some(100),
+        cache_hit,
+        cache_refresh: false,
+        dnssec_status: None,
+        upstream_server: None,
+        upstream_pool: None,
+        upstream_strategy: None,
+        upstream_attempt: None,
+        upstream_protocol: None,
+        response_status: Some("NOERROR"),
+        timestamp: None,
+        query_source: QuerySource::Client,
+        group_id: None,
+        group_name: None,
+        block_source: None,
+        plugin: None,
+    }
+}
+
+#[tokio::test]
+async fn test_client_activity_only_counts_that_client() {
+    let clients = Arc::new(
+        MockClientRepository::with_clients(vec![
+            make_client(1, "192.168.1.10"),
+            make_client(2, "192.168.1.20"),
+        ])
+        .await,
+    );
+    let query_log = Arc::new(MockQueryLogRepository::new());
+    for log in [
+        make_log("192.168.1.10", false, true),
+        make_log("192.168.1.10", true, false),
+        make_log("192.168.1.10", false, false),
+        make_log("192.168.1.20", true, false),
+    ] {
+        query_log.log_query(&log).await.unwrap();
+    }
+
+    let use_case = GetClientActivityUseCase::new(clients, query_log);
+    let activity = use_case
+        .execute(1, 24, TimeGranularity::Hour, 10)
+        .await
+        .unwrap();
+
+    assert_eq!(activity.total_queries, 3);
+    assert_eq!(activity.blocked_queries, 1);
+    assert_eq!(activity.cache_hits, 1);
+}
+
+#[tokio::test]
+async fn test_client_activity_unknown_client_returns_not_found() {
+    let clients = Arc::new(MockClientRepository::new());
+    let query_log = Arc::new(MockQueryLogRepository::new());
+
+    let use_case = GetClientActivityUseCase::new(clients, query_log);
+    let result = use_case.execute(42, 24, TimeGranularity::Hour, 10).await;
+
+    assert!(matches!(result, Err(DomainError::ClientNotFound(_))));
+}
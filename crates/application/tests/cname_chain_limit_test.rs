@@ -0,0 +1,107 @@
+mod helpers;
+
+use ferrous_dns_application::ports::DnsResolution;
+use ferrous_dns_application::use_cases::HandleDnsQueryUseCase;
+use ferrous_dns_domain::{DnsRcode, DnsRequest, PolicyAction, RecordType};
+use helpers::{
+    MockBlockFilterEngine, MockDnsResolver, MockDnsRewriteEngine, MockQueryLogRepository,
+    MockQueryPolicyEngine,
+};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+const CLIENT_IP: IpAddr = IpAddr::V4(std::net::Ipv4Addr::new(192, 168, 1, 100));
+
+struct Fixture {
+    resolver: Arc<MockDnsResolver>,
+    rewrites: Arc<MockDnsRewriteEngine>,
+    policies: Arc<MockQueryPolicyEngine>,
+    log: Arc<MockQueryLogRepository>,
+}
+
+impl Fixture {
+    fn new() -> Self {
+        Self {
+            resolver: Arc::new(MockDnsResolver::new()),
+            rewrites: Arc::new(MockDnsRewriteEngine::new()),
+            policies: Arc::new(MockQueryPolicyEngine::new()),
+            log: Arc::new(MockQueryLogRepository::new()),
+        }
+    }
+
+    fn use_case(&self, max_cname_chain: usize) -> HandleDnsQueryUseCase {
+        HandleDnsQueryUseCase::new(
+            self.resolver.clone(),
+            Arc::new(MockBlockFilterEngine::new()),
+            self.log.clone(),
+        )
+        .with_dns_rewrites(self.rewrites.clone())
+        .with_query_policy(self.policies.clone())
+        .with_max_cname_chain(max_cname_chain)
+    }
+}
+
+/// An answer reached through `hops` CNAME records.
+fn chained(hops: usize) -> DnsResolution {
+    let chain: Vec<Arc<str>> = (0..hops)
+        .map(|i| Arc::from(format!("hop{i}.example.net").as_str()))
+        .collect();
+    DnsResolution {
+        cname_chain: chain.into(),
+        ..DnsResolution::new(vec!["5.6.7.8".parse().unwrap()], false)
+    }
+}
+
+#[tokio::test]
+async fn chain_within_limit_is_answered() {
+    let f = Fixture::new();
+    f.resolver.set_response("www.example.com", chained(3)).await;
+
+    let request = DnsRequest::new("www.example.com", RecordType::A, CLIENT_IP);
+    let result = f.use_case(3).execute(&request).await.unwrap();
+
+    assert_eq!(result.addresses[0].to_string(), "5.6.7.8");
+}
+
+#[tokio::test]
+async fn chain_over_limit_fails_with_servfail() {
+    let f = Fixture::new();
+    f.resolver.set_response("www.example.com", chained(4)).await;
+
+    let request = DnsRequest::new("www.example.com", RecordType::A, CLIENT_IP);
+    let err = f.use_case(3).execute(&request).await.unwrap_err();
+
+    assert_eq!(err.rcode(), DnsRcode::ServFail);
+    let logs = f.log.get_sync_logs();
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].response_status, Some("SERVFAIL"));
+}
+
+#[tokio::test]
+async fn rewrite_alias_counts_towards_limit() {
+    let f = Fixture::new();
+    f.resolver.set_response("cdn.example.net", chained(2)).await;
+    f.rewrites
+        .set_rewrites(&[("www.example.com", "cdn.example.net")]);
+    let request = DnsRequest::new("www.example.com", RecordType::A, CLIENT_IP);
+
+    assert!(f.use_case(3).execute(&request).await.is_ok());
+    let err = f.use_case(2).execute(&request).await.unwrap_err();
+    assert_eq!(err.rcode(), DnsRcode::ServFail);
+}
+
+#[tokio::test]
+async fn policy_rewrite_alias_counts_towards_limit() {
+    let f = Fixture::new();
+    f.resolver.set_response("cdn.example.net", chained(3)).await;
+    f.policies.set_policy(
+        "www.example.com",
+        PolicyAction::Rewrite,
+        Some("cdn.example.net"),
+    );
+    let request = DnsRequest::new("www.example.com", RecordType::A, CLIENT_IP);
+
+    assert!(f.use_case(4).execute(&request).await.is_ok());
+    let err = f.use_case(3).execute(&request).await.unwrap_err();
+    assert_eq!(err.rcode(), DnsRcode::ServFail);
+}
@@ -0,0 +1,146 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{DatabaseMaintenancePort, DatabaseUsage};
+use ferrous_dns_application::use_cases::DatabaseMaintenanceUseCase;
+use ferrous_dns_domain::DomainError;
+use std::sync::{Arc, Mutex};
+
+const ROW_BYTES: u64 = 100;
+
+/// Pages held by the schema, which trimming can never release.
+const SCHEMA_BYTES: u64 = 4096;
+
+/// Simulated database where each query log row holds `ROW_BYTES` of pages.
+struct FakeDatabase {
+    rows: Mutex<u64>,
+    free_bytes: Mutex<u64>,
+    optimized: Mutex<u32>,
+    delete_calls: Mutex<u32>,
+}
+
+impl FakeDatabase {
+    fn with_rows(rows: u64) -> Self {
+        Self {
+            rows: Mutex::new(rows),
+            free_bytes: Mutex::new(0),
+            optimized: Mutex::new(0),
+            delete_calls: Mutex::new(0),
+        }
+    }
+}
+
+#[async_trait]
+impl DatabaseMaintenancePort for FakeDatabase {
+    async fn usage(&self) -> Result<DatabaseUsage, DomainError> {
+        let used = SCHEMA_BYTES + *self.rows.lock().unwrap() * ROW_BYTES;
+        let free = *self.free_bytes.lock().unwrap();
+        Ok(DatabaseUsage {
+            file_bytes: used + free,
+            wal_bytes: 0,
+            used_bytes: used,
+            free_bytes: free,
+            incremental_vacuum: true,
+            disk_total_bytes: 0,
+            disk_available_bytes: 0,
+        })
+    }
+
+    async fn optimize(&self) -> Result<(), DomainError> {
+        *self.optimized.lock().unwrap() += 1;
+        Ok(())
+    }
+
+    async fn incremental_vacuum(&self) -> Result<u64, DomainError> {
+        let mut free = self.free_bytes.lock().unwrap();
+        let reclaimed = *free;
+        *free = 0;
+        Ok(reclaimed)
+    }
+
+    async fn delete_oldest_query_logs(&self, limit: u32) -> Result<u64, DomainError> {
+        *self.delete_calls.lock().unwrap() += 1;
+        let mut rows = self.rows.lock().unwrap();
+        let deleted = (*rows).min(limit as u64);
+        *rows -= deleted;
+        *self.free_bytes.lock().unwrap() += deleted * ROW_BYTES;
+        Ok(deleted)
+    }
+}
+
+#[tokio::test]
+async fn test_run_without_cap_does_not_trim() {
+    let db = Arc::new(FakeDatabase::with_rows(50_000));
+    let use_case = DatabaseMaintenanceUseCase::new(db.clone());
+
+    let report = use_case.run().await.unwrap();
+
+    assert_eq!(report.trimmed_rows, 0);
+    assert_eq!(*db.delete_calls.lock().unwrap(), 0);
+    assert_eq!(*db.rows.lock().unwrap(), 50_000);
+    assert_eq!(*db.optimized.lock().unwrap(), 1);
+}
+
+#[tokio::test]
+async fn test_zero_cap_is_treated_as_disabled() {
+    let db = Arc::new(FakeDatabase::with_rows(50_000));
+    let use_case = DatabaseMaintenanceUseCase::new(db.clone()).with_max_size_bytes(0);
+
+    let report = use_case.run().await.unwrap();
+
+    assert_eq!(report.trimmed_rows, 0);
+    assert!(use_case.status().await.unwrap().max_size_bytes.is_none());
+}
+
+#[tokio::test]
+async fn test_run_trims_oldest_rows_until_under_cap() {
+    let db = Arc::new(FakeDatabase::with_rows(50_000));
+    let use_case =
+        DatabaseMaintenanceUseCase::new(db.clone()).with_max_size_bytes(25_000 * ROW_BYTES);
+
+    let report = use_case.run().await.unwrap();
+
+    assert_eq!(report.trimmed_rows, 30_000);
+    assert!(report.size_after.used_bytes <= 25_000 * ROW_BYTES);
+    assert_eq!(report.reclaimed_bytes, 30_000 * ROW_BYTES);
+    assert_eq!(report.size_after.free_bytes, 0);
+}
+
+#[tokio::test]
+async fn test_run_under_cap_does_not_trim() {
+    let db = Arc::new(FakeDatabase::with_rows(1_000));
+    let use_case = DatabaseMaintenanceUseCase::new(db.clone()).with_max_size_bytes(1024 * 1024);
+
+    let report = use_case.run().await.unwrap();
+
+    assert_eq!(report.trimmed_rows, 0);
+    assert_eq!(*db.delete_calls.lock().unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_run_stops_when_no_rows_remain() {
+    let db = Arc::new(FakeDatabase::with_rows(5));
+    let use_case =
+        DatabaseMaintenanceUseCase::new(db.clone()).with_max_size_bytes(SCHEMA_BYTES - 1);
+
+    let report = use_case.run().await.unwrap();
+
+    assert_eq!(report.trimmed_rows, 5);
+    assert_eq!(*db.delete_calls.lock().unwrap(), 2);
+}
+
+#[tokio::test]
+async fn test_status_reports_last_run() {
+    let db = Arc::new(FakeDatabase::with_rows(10));
+    let use_case = DatabaseMaintenanceUseCase::new(db).with_max_size_bytes(1024 * 1024);
+
+    let before = use_case.status().await.unwrap();
+    assert!(before.last_maintenance.is_none());
+    assert_eq!(before.max_size_bytes, Some(1024 * 1024));
+    assert_eq!(before.usage.used_bytes, SCHEMA_BYTES + 10 * ROW_BYTES);
+
+    use_case.run().await.unwrap();
+
+    let after = use_case.status().await.unwrap();
+    let last = after.last_maintenance.expect("last run recorded");
+    assert_eq!(last.trimmed_rows, 0);
+    assert!(last.size_after.incremental_vacuum);
+}
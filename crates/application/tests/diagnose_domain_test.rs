@@ -0,0 +1,231 @@
+mod helpers;
+
+use ferrous_dns_application::ports::{CacheEntrySnapshot, CacheEntryState, FilterDecision};
+use ferrous_dns_application::use_cases::{DiagnoseDomainUseCase, DiagnosisOutcome};
+use ferrous_dns_domain::{BlockSource, DomainError, PolicyAction, RecordType, RewriteTarget};
+use helpers::{
+    MockBlockFilterEngine, MockDnsCache, MockDnsRewriteEngine, MockQueryPolicyEngine,
+    MockUpstreamHealth,
+};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+const CLIENT_IP: IpAddr = IpAddr::V4(std::net::Ipv4Addr::new(192, 168, 1, 100));
+
+struct Fixture {
+    filter: Arc<MockBlockFilterEngine>,
+    cache: Arc<MockDnsCache>,
+    policies: Arc<MockQueryPolicyEngine>,
+    rewrites: Arc<MockDnsRewriteEngine>,
+}
+
+impl Fixture {
+    fn new() -> Self {
+        Self {
+            filter: Arc::new(MockBlockFilterEngine::new()),
+            cache: Arc::new(MockDnsCache::new()),
+            policies: Arc::new(MockQueryPolicyEngine::new()),
+            rewrites: Arc::new(MockDnsRewriteEngine::new()),
+        }
+    }
+
+    fn use_case(&self) -> DiagnoseDomainUseCase {
+        DiagnoseDomainUseCase::new(
+            self.filter.clone(),
+            self.cache.clone(),
+            Arc::new(MockUpstreamHealth),
+        )
+        .with_query_policy(self.policies.clone())
+        .with_dns_rewrites(self.rewrites.clone())
+    }
+}
+
+fn cache_entry(state: CacheEntryState) -> CacheEntrySnapshot {
+    CacheEntrySnapshot {
+        state,
+        ttl: Some(300),
+        remaining_ttl: 120,
+        dnssec_status: Some("Secure"),
+        hit_count: 4,
+    }
+}
+
+#[test]
+fn test_unfiltered_uncached_domain_is_forwarded_to_default_pool() {
+    let f = Fixture::new();
+
+    let diagnosis = f
+        .use_case()
+        .execute("Example.COM.", Some(CLIENT_IP), RecordType::A)
+        .unwrap();
+
+    assert_eq!(diagnosis.domain, "example.com");
+    assert_eq!(diagnosis.group_id, 1);
+    assert_eq!(diagnosis.outcome, DiagnosisOutcome::Forwarded);
+    assert_eq!(diagnosis.filter.decision, FilterDecision::Allow);
+    assert!(diagnosis.cache.is_none());
+    assert_eq!(diagnosis.upstream.unwrap().pool_name, "default");
+}
+
+#[test]
+fn test_blocked_domain_reports_block_source() {
+    let f = Fixture::new();
+    f.filter.block_domain("ads.example.com");
+
+    let diagnosis = f
+        .use_case()
+        .execute("ads.example.com", Some(CLIENT_IP), RecordType::A)
+        .unwrap();
+
+    assert_eq!(
+        diagnosis.outcome,
+        DiagnosisOutcome::Blocked(BlockSource::Blocklist)
+    );
+    assert!(diagnosis.filter.blocking_enabled);
+}
+
+#[test]
+fn test_cached_entry_reports_ttl_and_dnssec_status() {
+    let f = Fixture::new();
+    f.cache.set_entry(
+        "example.com",
+        RecordType::AAAA,
+        cache_entry(CacheEntryState::Fresh),
+    );
+
+    let diagnosis = f
+        .use_case()
+        .execute("example.com", None, RecordType::AAAA)
+        .unwrap();
+
+    assert_eq!(diagnosis.outcome, DiagnosisOutcome::Cached);
+    let cache = diagnosis.cache.unwrap();
+    assert_eq!(cache.remaining_ttl, 120);
+    assert_eq!(cache.dnssec_status, Some("Secure"));
+}
+
+#[test]
+fn test_negative_cache_entry_is_reported_as_negative() {
+    let f = Fixture::new();
+    f.cache.set_entry(
+        "missing.example.com",
+        RecordType::A,
+        cache_entry(CacheEntryState::Negative),
+    );
+
+    let diagnosis = f
+        .use_case()
+        .execute("missing.example.com", None, RecordType::A)
+        .unwrap();
+
+    assert_eq!(diagnosis.outcome, DiagnosisOutcome::NegativeCached);
+}
+
+#[test]
+fn test_cache_lookup_is_per_record_type() {
+    let f = Fixture::new();
+    f.cache.set_entry(
+        "example.com",
+        RecordType::A,
+        cache_entry(CacheEntryState::Fresh),
+    );
+
+    let diagnosis = f
+        .use_case()
+        .execute("example.com", None, RecordType::AAAA)
+        .unwrap();
+
+    assert_eq!(diagnosis.outcome, DiagnosisOutcome::Forwarded);
+}
+
+#[test]
+fn test_forward_to_pool_policy_bypasses_cache_and_selects_pool() {
+    let f = Fixture::new();
+    f.policies.set_policy(
+        "corp.example.com",
+        PolicyAction::ForwardToPool,
+        Some("corp"),
+    );
+    f.cache.set_entry(
+        "corp.example.com",
+        RecordType::A,
+        cache_entry(CacheEntryState::Fresh),
+    );
+
+    let diagnosis = f
+        .use_case()
+        .execute("corp.example.com", Some(CLIENT_IP), RecordType::A)
+        .unwrap();
+
+    assert_eq!(diagnosis.outcome, DiagnosisOutcome::Forwarded);
+    assert_eq!(diagnosis.upstream.unwrap().pool_name, "corp");
+    assert!(diagnosis.cache.is_some());
+}
+
+#[test]
+fn test_allow_policy_overrides_filter_block() {
+    let f = Fixture::new();
+    f.filter.block_domain("ads.example.com");
+    f.policies
+        .set_policy("ads.example.com", PolicyAction::Allow, None);
+
+    let diagnosis = f
+        .use_case()
+        .execute("ads.example.com", Some(CLIENT_IP), RecordType::A)
+        .unwrap();
+
+    assert_eq!(diagnosis.outcome, DiagnosisOutcome::Forwarded);
+    assert_eq!(
+        diagnosis.filter.decision,
+        FilterDecision::Block(BlockSource::Blocklist)
+    );
+}
+
+#[test]
+fn test_block_policy_wins_over_rewrite() {
+    let f = Fixture::new();
+    f.rewrites
+        .set_rewrites(&[("nas.example.com", "192.168.1.10")]);
+    f.policies
+        .set_policy("nas.example.com", PolicyAction::Block, None);
+
+    let diagnosis = f
+        .use_case()
+        .execute("nas.example.com", Some(CLIENT_IP), RecordType::A)
+        .unwrap();
+
+    assert_eq!(
+        diagnosis.outcome,
+        DiagnosisOutcome::Blocked(BlockSource::QueryPolicy)
+    );
+    assert!(matches!(
+        diagnosis.rewrite,
+        Some(RewriteTarget::Addresses(_))
+    ));
+}
+
+#[test]
+fn test_rewrite_wins_over_filter_block() {
+    let f = Fixture::new();
+    f.filter.block_domain("nas.example.com");
+    f.rewrites
+        .set_rewrites(&[("nas.example.com", "192.168.1.10")]);
+
+    let diagnosis = f
+        .use_case()
+        .execute("nas.example.com", Some(CLIENT_IP), RecordType::A)
+        .unwrap();
+
+    assert_eq!(diagnosis.outcome, DiagnosisOutcome::Rewritten);
+}
+
+#[test]
+fn test_invalid_domain_is_rejected() {
+    let f = Fixture::new();
+
+    let result = f
+        .use_case()
+        .execute("bad domain!", Some(CLIENT_IP), RecordType::A);
+
+    assert!(matches!(result, Err(DomainError::InvalidDomainName(_))));
+}
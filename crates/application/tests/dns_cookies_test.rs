@@ -17,6 +17,7 @@ fn cookies_config(require_valid: bool) -> DnsCookiesConfig {
         server_secret: String::new(),
         secret_rotation_secs: 3600,
         require_valid_cookie: require_valid,
+        upstream: true,
     }
 }
 
@@ -0,0 +1,152 @@
+mod helpers;
+
+use ferrous_dns_application::ports::DnsResolution;
+use ferrous_dns_application::use_cases::HandleDnsQueryUseCase;
+use ferrous_dns_domain::{DnsRequest, DomainError, PolicyAction, RecordType};
+use helpers::{
+    MockBlockFilterEngine, MockDnsResolver, MockDnsRewriteEngine, MockQueryLogRepository,
+    MockQueryPolicyEngine,
+};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+const CLIENT_IP: IpAddr = IpAddr::V4(std::net::Ipv4Addr::new(192, 168, 1, 100));
+
+struct Fixture {
+    resolver: Arc<MockDnsResolver>,
+    filter: Arc<MockBlockFilterEngine>,
+    policies: Arc<MockQueryPolicyEngine>,
+    rewrites: Arc<MockDnsRewriteEngine>,
+    log: Arc<MockQueryLogRepository>,
+}
+
+impl Fixture {
+    fn new() -> Self {
+        Self {
+            resolver: Arc::new(MockDnsResolver::new()),
+            filter: Arc::new(MockBlockFilterEngine::new()),
+            policies: Arc::new(MockQueryPolicyEngine::new()),
+            rewrites: Arc::new(MockDnsRewriteEngine::new()),
+            log: Arc::new(MockQueryLogRepository::new()),
+        }
+    }
+
+    fn use_case(&self) -> HandleDnsQueryUseCase {
+        HandleDnsQueryUseCase::new(self.resolver.clone(), self.filter.clone(), self.log.clone())
+            .with_query_policy(self.policies.clone())
+            .with_dns_rewrites(self.rewrites.clone())
+    }
+}
+
+fn resolution(ip: &str) -> DnsResolution {
+    DnsResolution::new(vec![ip.parse().unwrap()], false)
+}
+
+#[tokio::test]
+async fn address_rewrite_answers_without_upstream() {
+    let f = Fixture::new();
+    f.resolver
+        .set_response("nas.example.com", resolution("1.2.3.4"))
+        .await;
+    f.rewrites
+        .set_rewrites(&[("nas.example.com", "192.168.1.10")]);
+
+    let request = DnsRequest::new("nas.example.com", RecordType::A, CLIENT_IP);
+    let result = f.use_case().execute(&request).await.unwrap();
+
+    assert_eq!(
+        result.addresses.as_slice(),
+        &["192.168.1.10".parse::<IpAddr>().unwrap()]
+    );
+    assert!(!result.cache_hit);
+    let logs = f.log.get_sync_logs();
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].response_status, Some("REWRITTEN"));
+    assert!(!logs[0].blocked);
+}
+
+#[tokio::test]
+async fn address_rewrite_filters_by_record_type() {
+    let f = Fixture::new();
+    f.rewrites.set_rewrites(&[
+        ("nas.example.com", "192.168.1.10"),
+        ("nas.example.com", "fd00::10"),
+    ]);
+    let use_case = f.use_case();
+
+    let aaaa = DnsRequest::new("nas.example.com", RecordType::AAAA, CLIENT_IP);
+    let result = use_case.execute(&aaaa).await.unwrap();
+    assert_eq!(
+        result.addresses.as_slice(),
+        &["fd00::10".parse::<IpAddr>().unwrap()]
+    );
+
+    let mx = DnsRequest::new("nas.example.com", RecordType::MX, CLIENT_IP);
+    let result = use_case.execute(&mx).await.unwrap();
+    assert!(result.addresses.is_empty());
+    assert!(!result.has_response_data());
+}
+
+#[tokio::test]
+async fn cname_rewrite_resolves_target() {
+    let f = Fixture::new();
+    f.resolver
+        .set_response("cdn.example.net", resolution("5.6.7.8"))
+        .await;
+    f.rewrites
+        .set_rewrites(&[("*.example.com", "cdn.example.net")]);
+
+    let request = DnsRequest::new("www.example.com", RecordType::A, CLIENT_IP);
+    let result = f.use_case().execute(&request).await.unwrap();
+
+    assert_eq!(result.addresses[0].to_string(), "5.6.7.8");
+    let logs = f.log.get_sync_logs();
+    assert_eq!(logs[0].response_status, Some("REWRITTEN"));
+}
+
+#[tokio::test]
+async fn rewrite_takes_precedence_over_cache_and_blocklist() {
+    let f = Fixture::new();
+    f.resolver
+        .set_cached_response("printer.lan", resolution("9.9.9.9"));
+    f.filter.block_domain("printer.lan");
+    f.rewrites.set_rewrites(&[("printer.lan", "192.168.1.20")]);
+
+    let request = DnsRequest::new("printer.lan", RecordType::A, CLIENT_IP);
+    let result = f.use_case().execute(&request).await.unwrap();
+
+    assert_eq!(result.addresses[0].to_string(), "192.168.1.20");
+}
+
+#[tokio::test]
+async fn block_policy_still_wins_over_rewrite() {
+    let f = Fixture::new();
+    f.rewrites.set_rewrites(&[("ads.com", "10.0.0.1")]);
+    f.policies.set_policy("ads.com", PolicyAction::Block, None);
+
+    let request = DnsRequest::new("ads.com", RecordType::A, CLIENT_IP);
+    let result = f.use_case().execute(&request).await;
+
+    assert!(matches!(result, Err(DomainError::Blocked(_))));
+}
+
+#[tokio::test]
+async fn cache_fast_path_defers_to_execute_when_rewrite_matches() {
+    let f = Fixture::new();
+    f.resolver.set_cached_response(
+        "nas.example.com",
+        DnsResolution::new(vec!["1.2.3.4".parse().unwrap()], true),
+    );
+    f.rewrites
+        .set_rewrites(&[("nas.example.com", "192.168.1.10")]);
+
+    let use_case = f.use_case();
+
+    assert!(use_case
+        .try_cache_direct("nas.example.com", RecordType::A, CLIENT_IP, None)
+        .is_none());
+    assert!(use_case
+        .try_cache_wire_direct("nas.example.com", RecordType::A, CLIENT_IP, None)
+        .is_none());
+    assert_eq!(f.log.sync_log_count(), 0);
+}
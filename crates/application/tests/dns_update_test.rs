@@ -0,0 +1,277 @@
+mod helpers;
+
+use ferrous_dns_application::ports::{LocalRecordRepository, LocalZonePort};
+use ferrous_dns_application::use_cases::ApplyDnsUpdateUseCase;
+use ferrous_dns_domain::{
+    AuditAction, Config, DnsRcode, DnsUpdate, DnsUpdateOutcome, LocalRecord, RecordType,
+    UpdateChange, UpdatePrerequisite, UpdateZoneConfig,
+};
+use helpers::{MockAuditLogRepository, MockLocalRecordRepository, MockLocalZone};
+use std::net::IpAddr;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+const DHCP: IpAddr = IpAddr::V4(std::net::Ipv4Addr::new(192, 168, 1, 1));
+
+fn record(hostname: &str, record_type: RecordType, value: &str) -> LocalRecord {
+    LocalRecord {
+        domain: Some(Arc::from("home.lan")),
+        ..LocalRecord::new(Arc::from(hostname), record_type, Arc::from(value))
+    }
+}
+
+fn update(prerequisites: Vec<UpdatePrerequisite>, changes: Vec<UpdateChange>) -> DnsUpdate {
+    DnsUpdate {
+        zone: Arc::from("home.lan"),
+        client_ip: DHCP,
+        key_name: None,
+        prerequisites,
+        changes,
+    }
+}
+
+fn add(name: &str, record_type: RecordType, value: &str, ttl: u32) -> UpdateChange {
+    UpdateChange::Add {
+        name: Arc::from(name),
+        record_type,
+        value: Arc::from(value),
+        ttl,
+    }
+}
+
+struct Fixture {
+    repo: Arc<MockLocalRecordRepository>,
+    zone: Arc<MockLocalZone>,
+    audit: Arc<MockAuditLogRepository>,
+    use_case: ApplyDnsUpdateUseCase,
+}
+
+impl Fixture {
+    async fn new(records: Vec<LocalRecord>) -> Self {
+        let mut config = Config::default();
+        config.dns.update_zones = vec![UpdateZoneConfig {
+            zone: "home.lan".to_string(),
+            allow_clients: vec!["192.168.1.0/24".to_string()],
+            tsig_keys: vec![],
+        }];
+        let repo = Arc::new(MockLocalRecordRepository::with_records(records).await);
+        let zone = Arc::new(MockLocalZone::new(repo.clone(), None));
+        zone.reload().await.unwrap();
+        let audit = Arc::new(MockAuditLogRepository::new());
+        let use_case =
+            ApplyDnsUpdateUseCase::new(Arc::new(RwLock::new(config)), repo.clone(), zone.clone())
+                .with_audit_log(audit.clone());
+        Self {
+            repo,
+            zone,
+            audit,
+            use_case,
+        }
+    }
+
+    async fn stored(&self) -> Vec<LocalRecord> {
+        self.repo.get_all().await.unwrap()
+    }
+}
+
+fn rejected_with(outcome: &DnsUpdateOutcome) -> DnsRcode {
+    match outcome {
+        DnsUpdateOutcome::Rejected { rcode, .. } => *rcode,
+        other => panic!("expected a rejection, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn added_record_is_stored_and_answered() {
+    let fixture = Fixture::new(vec![]).await;
+
+    let outcome = fixture
+        .use_case
+        .execute(&update(
+            vec![],
+            vec![add("laptop.home.lan", RecordType::A, "192.168.1.60", 600)],
+        ))
+        .await;
+
+    assert_eq!(
+        outcome,
+        DnsUpdateOutcome::Applied {
+            added: 1,
+            removed: 0
+        }
+    );
+    let stored = fixture.stored().await;
+    assert_eq!(stored.len(), 1);
+    assert_eq!(&*stored[0].hostname, "laptop");
+    assert_eq!(stored[0].domain.as_deref(), Some("home.lan"));
+    assert_eq!(stored[0].ttl, 600);
+    assert!(fixture
+        .zone
+        .lookup("laptop.home.lan", RecordType::A)
+        .is_some());
+
+    let events = fixture.audit.all().await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].action, AuditAction::DnsUpdate);
+    assert_eq!(events[0].target, "home.lan");
+    assert_eq!(events[0].result, "NOERROR");
+}
+
+#[tokio::test]
+async fn replacing_an_address_deletes_the_old_rrset() {
+    let fixture = Fixture::new(vec![record("laptop", RecordType::A, "192.168.1.60")]).await;
+
+    let outcome = fixture
+        .use_case
+        .execute(&update(
+            vec![UpdatePrerequisite::NameInUse(Arc::from("laptop.home.lan"))],
+            vec![
+                UpdateChange::DeleteRrset {
+                    name: Arc::from("laptop.home.lan"),
+                    record_type: RecordType::A,
+                },
+                add("laptop.home.lan", RecordType::A, "192.168.1.61", 300),
+            ],
+        ))
+        .await;
+
+    assert_eq!(
+        outcome,
+        DnsUpdateOutcome::Applied {
+            added: 1,
+            removed: 1
+        }
+    );
+    let stored = fixture.stored().await;
+    assert_eq!(stored.len(), 1);
+    assert_eq!(&*stored[0].value, "192.168.1.61");
+}
+
+#[tokio::test]
+async fn failed_prerequisite_changes_nothing() {
+    let fixture = Fixture::new(vec![record("laptop", RecordType::A, "192.168.1.60")]).await;
+
+    let outcome = fixture
+        .use_case
+        .execute(&update(
+            vec![UpdatePrerequisite::NameNotInUse(Arc::from(
+                "laptop.home.lan",
+            ))],
+            vec![add("laptop.home.lan", RecordType::A, "192.168.1.99", 300)],
+        ))
+        .await;
+
+    assert_eq!(rejected_with(&outcome), DnsRcode::YxDomain);
+    assert_eq!(fixture.stored().await.len(), 1);
+    assert_eq!(fixture.audit.all().await[0].result, "YXDOMAIN");
+}
+
+#[tokio::test]
+async fn rrset_equality_compares_record_data() {
+    let fixture = Fixture::new(vec![record("laptop", RecordType::A, "192.168.1.60")]).await;
+    let equals = |value: &str| UpdatePrerequisite::RrsetEquals {
+        name: Arc::from("laptop.home.lan"),
+        record_type: RecordType::A,
+        values: vec![Arc::from(value)],
+    };
+
+    let outcome = fixture
+        .use_case
+        .execute(&update(vec![equals("192.168.1.61")], vec![]))
+        .await;
+    assert_eq!(rejected_with(&outcome), DnsRcode::NxRrset);
+
+    let outcome = fixture
+        .use_case
+        .execute(&update(vec![equals("192.168.1.60")], vec![]))
+        .await;
+    assert!(matches!(outcome, DnsUpdateOutcome::Applied { .. }));
+}
+
+#[tokio::test]
+async fn names_outside_the_zone_are_rejected() {
+    let fixture = Fixture::new(vec![]).await;
+
+    let outcome = fixture
+        .use_case
+        .execute(&update(
+            vec![],
+            vec![add("laptop.office.lan", RecordType::A, "10.0.0.5", 300)],
+        ))
+        .await;
+
+    assert_eq!(rejected_with(&outcome), DnsRcode::NotZone);
+    assert!(fixture.stored().await.is_empty());
+}
+
+#[tokio::test]
+async fn clients_outside_the_allow_list_are_refused() {
+    let fixture = Fixture::new(vec![]).await;
+    let mut request = update(
+        vec![],
+        vec![add("laptop.home.lan", RecordType::A, "192.168.1.60", 300)],
+    );
+    request.client_ip = "10.0.0.8".parse().unwrap();
+
+    let outcome = fixture.use_case.execute(&request).await;
+
+    assert_eq!(rejected_with(&outcome), DnsRcode::Refused);
+    assert!(fixture.stored().await.is_empty());
+    let events = fixture.audit.all().await;
+    assert_eq!(events[0].client_ip, "10.0.0.8");
+    assert_eq!(events[0].result, "REFUSED");
+}
+
+#[tokio::test]
+async fn zones_without_updates_are_not_authoritative() {
+    let fixture = Fixture::new(vec![]).await;
+    let mut request = update(vec![], vec![]);
+    request.zone = Arc::from("office.lan");
+
+    let outcome = fixture.use_case.execute(&request).await;
+
+    assert_eq!(rejected_with(&outcome), DnsRcode::NotAuth);
+}
+
+#[tokio::test]
+async fn unsupported_types_are_refused_before_any_change() {
+    let fixture = Fixture::new(vec![record("laptop", RecordType::A, "192.168.1.60")]).await;
+
+    let outcome = fixture
+        .use_case
+        .execute(&update(
+            vec![],
+            vec![
+                UpdateChange::DeleteName(Arc::from("laptop.home.lan")),
+                add("laptop.home.lan", RecordType::NULL, "", 300),
+            ],
+        ))
+        .await;
+
+    assert_eq!(rejected_with(&outcome), DnsRcode::Refused);
+    assert_eq!(fixture.stored().await.len(), 1);
+}
+
+#[tokio::test]
+async fn record_next_to_a_cname_is_ignored() {
+    let fixture = Fixture::new(vec![record("files", RecordType::CNAME, "nas.home.lan")]).await;
+
+    let outcome = fixture
+        .use_case
+        .execute(&update(
+            vec![],
+            vec![add("files.home.lan", RecordType::A, "192.168.1.70", 300)],
+        ))
+        .await;
+
+    assert_eq!(
+        outcome,
+        DnsUpdateOutcome::Applied {
+            added: 0,
+            removed: 0
+        }
+    );
+    let stored = fixture.stored().await;
+    assert_eq!(stored.len(), 1);
+    assert_eq!(stored[0].record_type, RecordType::CNAME);
+}
@@ -0,0 +1,137 @@
+use ferrous_dns_application::ports::{
+    DnssecCachedValidation, DnssecHistoryRepository, DnssecOutcomes,
+};
+use ferrous_dns_application::use_cases::{
+    GetDnssecStatsUseCase, GetDnssecStatusUseCase, RecordDnssecHistoryUseCase,
+};
+use ferrous_dns_domain::{DnssecStatusChange, DnssecValidationCounts, DomainError, RecordType};
+use std::sync::Arc;
+
+mod helpers;
+use helpers::{MockDnssecHistoryRepository, MockDnssecStatus};
+
+fn change(
+    domain: &str,
+    status: &'static str,
+    previous: Option<&'static str>,
+) -> DnssecStatusChange {
+    DnssecStatusChange {
+        id: None,
+        domain: domain.to_string(),
+        record_type: RecordType::A,
+        status,
+        previous_status: previous,
+        observed_at: "2026-01-01 00:00:00".to_string(),
+    }
+}
+
+fn counts(secure: u64, insecure: u64, bogus: u64) -> DnssecValidationCounts {
+    DnssecValidationCounts {
+        secure,
+        insecure,
+        bogus,
+        indeterminate: 0,
+    }
+}
+
+#[tokio::test]
+async fn test_record_history_stores_sample_and_changes() {
+    let status = Arc::new(MockDnssecStatus::new().with_outcomes(DnssecOutcomes {
+        counts: counts(5, 3, 1),
+        changes: vec![change("example.com", "Bogus", Some("Secure"))],
+    }));
+    let repo = Arc::new(MockDnssecHistoryRepository::new());
+    let use_case = RecordDnssecHistoryUseCase::new(status, repo.clone());
+
+    let recorded = use_case.execute().await.unwrap();
+
+    assert_eq!(recorded, counts(5, 3, 1));
+    assert_eq!(repo.samples.read().await.len(), 1);
+    assert_eq!(repo.samples.read().await[0].counts, counts(5, 3, 1));
+    let changes = repo.changes.read().await;
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].status, "Bogus");
+    assert_eq!(changes[0].previous_status, Some("Secure"));
+}
+
+#[tokio::test]
+async fn test_record_history_writes_empty_sample_when_idle() {
+    let status = Arc::new(MockDnssecStatus::new());
+    let repo = Arc::new(MockDnssecHistoryRepository::new());
+    let use_case = RecordDnssecHistoryUseCase::new(status, repo.clone());
+
+    use_case.execute().await.unwrap();
+    use_case.execute().await.unwrap();
+
+    let samples = repo.samples.read().await;
+    assert_eq!(samples.len(), 2);
+    assert!(samples.iter().all(|s| s.counts.total() == 0));
+    assert!(repo.changes.read().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_status_normalizes_domain_and_lists_cache_and_changes() {
+    let status = Arc::new(MockDnssecStatus::new().with_cached(
+        "example.com",
+        DnssecCachedValidation {
+            record_type: RecordType::A,
+            status: "Secure",
+            validated_secs_ago: 12,
+            expired: false,
+        },
+    ));
+    let repo = Arc::new(MockDnssecHistoryRepository::new());
+    repo.changes.write().await.extend([
+        change("example.com", "Bogus", None),
+        change("other.org", "Bogus", None),
+        change("example.com", "Secure", Some("Bogus")),
+    ]);
+    let use_case = GetDnssecStatusUseCase::new(status, repo);
+
+    let result = use_case.execute(" Example.COM. ", 10).await.unwrap();
+
+    assert_eq!(result.domain, "example.com");
+    assert_eq!(result.cached.len(), 1);
+    assert_eq!(result.cached[0].status, "Secure");
+    let statuses: Vec<&str> = result.changes.iter().map(|c| c.status).collect();
+    assert_eq!(statuses, vec!["Secure", "Bogus"]);
+}
+
+#[tokio::test]
+async fn test_status_rejects_invalid_domain() {
+    let use_case = GetDnssecStatusUseCase::new(
+        Arc::new(MockDnssecStatus::new()),
+        Arc::new(MockDnssecHistoryRepository::new()),
+    );
+
+    let result = use_case.execute("  ", 10).await;
+
+    assert!(matches!(result, Err(DomainError::InvalidDomainName(_))));
+}
+
+#[tokio::test]
+async fn test_stats_sum_samples_and_list_recent_bogus() {
+    let repo = Arc::new(MockDnssecHistoryRepository::new());
+    let recorder = RecordDnssecHistoryUseCase::new(
+        Arc::new(MockDnssecStatus::new().with_outcomes(DnssecOutcomes {
+            counts: counts(4, 2, 0),
+            changes: vec![
+                change("broken.example", "Bogus", Some("Secure")),
+                change("fixed.example", "Secure", Some("Bogus")),
+            ],
+        })),
+        repo.clone(),
+    );
+    recorder.execute().await.unwrap();
+    repo.insert_sample(&counts(1, 0, 2)).await.unwrap();
+    let use_case = GetDnssecStatsUseCase::new(repo);
+
+    let stats = use_case.execute(24, 10).await.unwrap();
+
+    assert_eq!(stats.period_hours, 24);
+    assert_eq!(stats.samples.len(), 2);
+    assert_eq!(stats.totals, counts(5, 2, 2));
+    assert_eq!(stats.totals.total(), 9);
+    assert_eq!(stats.recent_bogus.len(), 1);
+    assert_eq!(stats.recent_bogus[0].domain, "broken.example");
+}
@@ -0,0 +1,138 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{MetricsSink, QueryLogRepository};
+use ferrous_dns_application::use_cases::ExportMetricsUseCase;
+use ferrous_dns_domain::{DomainError, MetricsExportConfig, QueryLog, QuerySource, RecordType};
+use std::collections::BTreeMap;
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
+
+mod helpers;
+use helpers::MockQueryLogRepository;
+
+#[derive(Default)]
+struct RecordingSink {
+    writes: Mutex<Vec<String>>,
+    fail: bool,
+}
+
+#[async_trait]
+impl MetricsSink for RecordingSink {
+    async fn write(&self, lines: String) -> Result<(), DomainError> {
+        if self.fail {
+            return Err(DomainError::MetricsExportFailed("HTTP 503".to_string()));
+        }
+        self.writes.lock().unwrap().push(lines);
+        Ok(())
+    }
+}
+
+fn make_log(blocked: bool, cache_hit: bool) -> QueryLog {
+    QueryLog {
+        id: None,
+        domain: "example.com".into(),
+        record_type: RecordType::A,
+        client_ip: IpAddr::from([192, 168, 1, 1]),
+        client_hostname: None,
+        client_mac: None,
+        blocked,
+        response_time_us: Some(100),
+        cache_hit,
+        cache_refresh: false,
+        dnssec_status: None,
+        upstream_server: None,
+        upstream_pool: None,
+        upstream_strategy: None,
+        upstream_attempt: None,
+        upstream_protocol: None,
+        response_status: Some("NOERROR"),
+        timestamp: None,
+        query_source: QuerySource::Client,
+        group_id: None,
+        group_name: None,
+        block_source: None,
+        plugin: None,
+    }
+}
+
+fn config(tags: &[(&str, &str)]) -> MetricsExportConfig {
+    MetricsExportConfig {
+        enabled: true,
+        url: "http://influx:8086/api/v2/write?org=home&bucket=dns".to_string(),
+        tags: tags
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect::<BTreeMap<_, _>>(),
+        ..Default::default()
+    }
+}
+
+async fn repository_with_logs() -> Arc<MockQueryLogRepository> {
+    let repo = Arc::new(MockQueryLogRepository::new());
+    repo.log_query(&make_log(true, false)).await.unwrap();
+    repo.log_query(&make_log(false, true)).await.unwrap();
+    repo.log_query(&make_log(false, false)).await.unwrap();
+    repo
+}
+
+#[tokio::test]
+async fn test_export_writes_totals_as_line_protocol() {
+    let sink = Arc::new(RecordingSink::default());
+    let use_case = ExportMetricsUseCase::new(
+        repository_with_logs().await,
+        sink.clone(),
+        config(&[("host", "pi")]),
+    );
+
+    let points = use_case.execute().await.unwrap();
+
+    assert_eq!(points, 1);
+    let writes = sink.writes.lock().unwrap();
+    assert!(
+        writes[0].starts_with("ferrous_dns,host=pi queries=3i,blocked=1i,cache_hits=1i,errors=0i ")
+    );
+}
+
+#[tokio::test]
+async fn test_export_omits_latency_fields_without_samples() {
+    let sink = Arc::new(RecordingSink::default());
+    let use_case = ExportMetricsUseCase::new(
+        Arc::new(MockQueryLogRepository::new()),
+        sink.clone(),
+        config(&[]),
+    );
+
+    use_case.execute().await.unwrap();
+
+    let line = sink.writes.lock().unwrap()[0].clone();
+    assert!(line.starts_with("ferrous_dns queries=0i,"));
+    assert!(!line.contains("latency"));
+}
+
+#[tokio::test]
+async fn test_export_escapes_and_sorts_tags() {
+    let sink = Arc::new(RecordingSink::default());
+    let use_case = ExportMetricsUseCase::new(
+        Arc::new(MockQueryLogRepository::new()),
+        sink.clone(),
+        config(&[("site", "home lab"), ("env", "a=b,c")]),
+    );
+
+    use_case.execute().await.unwrap();
+
+    let line = sink.writes.lock().unwrap()[0].clone();
+    assert!(line.starts_with(r"ferrous_dns,env=a\=b\,c,site=home\ lab "));
+}
+
+#[tokio::test]
+async fn test_export_propagates_sink_errors() {
+    let sink = Arc::new(RecordingSink {
+        fail: true,
+        ..Default::default()
+    });
+    let use_case =
+        ExportMetricsUseCase::new(Arc::new(MockQueryLogRepository::new()), sink, config(&[]));
+
+    let result = use_case.execute().await;
+
+    assert!(matches!(result, Err(DomainError::MetricsExportFailed(_))));
+}
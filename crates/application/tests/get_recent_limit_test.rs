@@ -1,9 +1,12 @@
 use async_trait::async_trait;
 use ferrous_dns_application::ports::{
-    CacheStats, PagedQueryResult, QueryLogRepository, TimeGranularity, TimelineBucket,
+    CacheStats, ClientActivity, ClientDomainCount, MetricsWindow, PagedQueryResult,
+    QueryLogRepository, TimeGranularity, TimelineBreakdown, TimelineBucket, TimelineSeries,
 };
 use ferrous_dns_application::use_cases::{GetRecentQueriesUseCase, PagedQueryInput};
-use ferrous_dns_domain::{query_log::QueryLog, DomainError, QueryLogFilter, QueryStats};
+use ferrous_dns_domain::{
+    query_log::QueryLog, DomainError, QueryCountBreakdown, QueryLogFilter, QueryStats,
+};
 use std::sync::{Arc, Mutex};
 
 struct CaptureLimitRepository {
@@ -50,10 +53,33 @@ impl QueryLogRepository for CaptureLimitRepository {
         unimplemented!()
     }
 
+    async fn get_timeline_breakdown(
+        &self,
+        _: u32,
+        _: TimeGranularity,
+        _: TimelineBreakdown,
+        _: u32,
+    ) -> Result<Vec<TimelineSeries>, DomainError> {
+        unimplemented!()
+    }
+
+    async fn get_query_counts_by(
+        &self,
+        _: f32,
+        _: TimelineBreakdown,
+        _: Option<u32>,
+    ) -> Result<Vec<QueryCountBreakdown>, DomainError> {
+        unimplemented!()
+    }
+
     async fn count_queries_since(&self, _: i64) -> Result<u64, DomainError> {
         unimplemented!()
     }
 
+    async fn get_metrics_window(&self, _: i64) -> Result<MetricsWindow, DomainError> {
+        unimplemented!()
+    }
+
     async fn get_cache_stats(&self, _: f32) -> Result<CacheStats, DomainError> {
         unimplemented!()
     }
@@ -82,6 +108,31 @@ impl QueryLogRepository for CaptureLimitRepository {
         unimplemented!()
     }
 
+    async fn get_top_blocked_clients(
+        &self,
+        _: u32,
+        _: f32,
+    ) -> Result<Vec<(String, u64)>, DomainError> {
+        unimplemented!()
+    }
+
+    async fn get_client_domain_counts(
+        &self,
+        _: i64,
+    ) -> Result<Vec<ClientDomainCount>, DomainError> {
+        unimplemented!()
+    }
+
+    async fn get_client_activity(
+        &self,
+        _: &str,
+        _: u32,
+        _: TimeGranularity,
+        _: u32,
+    ) -> Result<ClientActivity, DomainError> {
+        unimplemented!()
+    }
+
     async fn delete_older_than(&self, _: u32) -> Result<u64, DomainError> {
         unimplemented!()
     }
@@ -190,3 +241,89 @@ async fn test_invalid_category_returns_error() {
     assert!(result.is_err());
     assert!(matches!(result.unwrap_err(), DomainError::InvalidInput(_)));
 }
+
+#[tokio::test]
+async fn test_invalid_time_bound_returns_error() {
+    let captured = Arc::new(Mutex::new(0u32));
+    let repo = Arc::new(CaptureLimitRepository {
+        last_limit: captured.clone(),
+    });
+    let use_case = GetRecentQueriesUseCase::new(repo);
+
+    let input = PagedQueryInput {
+        limit: 100,
+        period_hours: 24.0,
+        from: Some("yesterday"),
+        ..Default::default()
+    };
+    let result = use_case.execute_paged(&input).await;
+
+    assert!(matches!(result.unwrap_err(), DomainError::InvalidInput(_)));
+}
+
+#[tokio::test]
+async fn test_inverted_time_range_returns_error() {
+    let captured = Arc::new(Mutex::new(0u32));
+    let repo = Arc::new(CaptureLimitRepository {
+        last_limit: captured.clone(),
+    });
+    let use_case = GetRecentQueriesUseCase::new(repo);
+
+    let input = PagedQueryInput {
+        limit: 100,
+        period_hours: 24.0,
+        from: Some("2026-03-10T12:00:00Z"),
+        to: Some("1773100800"),
+        ..Default::default()
+    };
+    let result = use_case.execute_paged(&input).await;
+
+    assert!(matches!(result.unwrap_err(), DomainError::InvalidInput(_)));
+}
+
+#[tokio::test]
+async fn test_invalid_dnssec_status_and_source_return_error() {
+    let captured = Arc::new(Mutex::new(0u32));
+    let repo = Arc::new(CaptureLimitRepository {
+        last_limit: captured.clone(),
+    });
+    let use_case = GetRecentQueriesUseCase::new(repo);
+
+    let input = PagedQueryInput {
+        limit: 100,
+        period_hours: 24.0,
+        dnssec_status: Some("trusted"),
+        ..Default::default()
+    };
+    assert!(use_case.execute_paged(&input).await.is_err());
+
+    let input = PagedQueryInput {
+        limit: 100,
+        period_hours: 24.0,
+        query_source: Some("upstream"),
+        ..Default::default()
+    };
+    assert!(use_case.execute_paged(&input).await.is_err());
+}
+
+#[tokio::test]
+async fn test_valid_extended_filters_are_accepted() {
+    let captured = Arc::new(Mutex::new(0u32));
+    let repo = Arc::new(CaptureLimitRepository {
+        last_limit: captured.clone(),
+    });
+    let use_case = GetRecentQueriesUseCase::new(repo);
+
+    let input = PagedQueryInput {
+        limit: 100,
+        period_hours: 24.0,
+        from: Some("1773100800"),
+        to: Some("2026-03-10T12:00:00+00:00"),
+        blocked: Some(true),
+        dnssec_status: Some("bogus"),
+        query_source: Some("internal"),
+        ..Default::default()
+    };
+
+    assert!(use_case.execute_paged(&input).await.is_ok());
+}
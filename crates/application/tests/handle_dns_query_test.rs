@@ -94,7 +94,7 @@ async fn test_execute_blocked_domain_returns_error_and_logs_blocked() {
 
     let result = use_case.execute(&request).await;
 
-    assert!(matches!(result, Err(DomainError::Blocked)));
+    assert!(matches!(result, Err(DomainError::Blocked(_))));
     let logs = log.get_sync_logs();
     assert_eq!(logs.len(), 1);
     assert!(logs[0].blocked);
@@ -169,6 +169,29 @@ async fn test_execute_generic_error_logs_servfail_status() {
     assert_eq!(logs[0].response_status, Some("SERVFAIL"));
 }
 
+#[tokio::test]
+async fn test_execute_malformed_query_logs_formerr_status() {
+    let resolver = Arc::new(MockDnsResolver::new());
+    let filter = Arc::new(MockBlockFilterEngine::new());
+    let log = Arc::new(MockQueryLogRepository::new());
+
+    resolver
+        .set_response_error(
+            "bad.com",
+            DomainError::MalformedQuery("label too long".into()),
+        )
+        .await;
+
+    let use_case = make_use_case(resolver, filter, log.clone());
+    let request = DnsRequest::new("bad.com", RecordType::A, CLIENT_IP);
+
+    let result = use_case.execute(&request).await;
+
+    assert!(matches!(result, Err(DomainError::MalformedQuery(_))));
+    let logs = log.get_sync_logs();
+    assert_eq!(logs[0].response_status, Some("FORMERR"));
+}
+
 #[tokio::test]
 async fn test_execute_local_nxdomain_logs_local_dns_and_returns_nxdomain() {
     let resolver = Arc::new(MockDnsResolver::new());
@@ -246,7 +269,7 @@ async fn test_try_cache_direct_returns_none_on_miss() {
 
     let use_case = make_use_case(resolver, filter, log.clone());
 
-    let result = use_case.try_cache_direct("google.com", RecordType::A, CLIENT_IP);
+    let result = use_case.try_cache_direct("google.com", RecordType::A, CLIENT_IP, None);
 
     assert!(result.is_none());
     assert_eq!(log.sync_log_count(), 0);
@@ -262,7 +285,7 @@ async fn test_try_cache_direct_returns_addresses_and_logs_on_hit() {
 
     let use_case = make_use_case(resolver, filter, log.clone());
 
-    let result = use_case.try_cache_direct("google.com", RecordType::A, CLIENT_IP);
+    let result = use_case.try_cache_direct("google.com", RecordType::A, CLIENT_IP, None);
 
     assert!(result.is_some());
     let (addresses, _ttl) = result.unwrap();
@@ -287,7 +310,7 @@ async fn test_try_cache_direct_returns_none_when_cached_addresses_empty() {
 
     let use_case = make_use_case(resolver, filter, log.clone());
 
-    let result = use_case.try_cache_direct("empty.com", RecordType::A, CLIENT_IP);
+    let result = use_case.try_cache_direct("empty.com", RecordType::A, CLIENT_IP, None);
 
     assert!(result.is_none());
     assert_eq!(log.sync_log_count(), 0);
@@ -362,7 +385,7 @@ async fn test_cname_cloaking_blocked_upstream_path() {
 
     let result = use_case.execute(&request).await;
 
-    assert!(matches!(result, Err(DomainError::Blocked)));
+    assert!(matches!(result, Err(DomainError::Blocked(_))));
     let logs = log.get_sync_logs();
     assert_eq!(logs.len(), 1);
     assert!(logs[0].blocked);
@@ -390,7 +413,7 @@ async fn test_cname_cloaking_blocked_then_cached_via_decision_cache() {
     let request = DnsRequest::new("analytics.seusite.com", RecordType::A, CLIENT_IP);
 
     let first = use_case.execute(&request).await;
-    assert!(matches!(first, Err(DomainError::Blocked)));
+    assert!(matches!(first, Err(DomainError::Blocked(_))));
     assert!(filter.is_cname_blocked("analytics.seusite.com"));
 
     let cached = DnsResolutionBuilder::new()
@@ -402,7 +425,7 @@ async fn test_cname_cloaking_blocked_then_cached_via_decision_cache() {
     log.clear_sync_logs();
 
     let second = use_case.execute(&request).await;
-    assert!(matches!(second, Err(DomainError::Blocked)));
+    assert!(matches!(second, Err(DomainError::Blocked(_))));
     let logs = log.get_sync_logs();
     assert_eq!(logs.len(), 1);
     assert!(logs[0].blocked);
@@ -431,7 +454,7 @@ async fn test_cname_chain_middle_hop_blocked() {
 
     let result = use_case.execute(&request).await;
 
-    assert!(matches!(result, Err(DomainError::Blocked)));
+    assert!(matches!(result, Err(DomainError::Blocked(_))));
     let logs = log.get_sync_logs();
     assert_eq!(logs.len(), 1);
     assert!(logs[0].blocked);
@@ -479,7 +502,7 @@ async fn test_try_cache_direct_returns_none_when_domain_blocked() {
 
     let use_case = make_use_case(resolver, filter, log.clone());
 
-    let result = use_case.try_cache_direct("analytics.seusite.com", RecordType::A, CLIENT_IP);
+    let result = use_case.try_cache_direct("analytics.seusite.com", RecordType::A, CLIENT_IP, None);
 
     assert!(result.is_none());
     assert_eq!(log.sync_log_count(), 0);
@@ -495,7 +518,7 @@ fn try_cache_wire_direct_returns_none_on_cache_miss() {
 
     let use_case = make_use_case(resolver, filter, log);
 
-    let result = use_case.try_cache_wire_direct("example.com", RecordType::MX, CLIENT_IP);
+    let result = use_case.try_cache_wire_direct("example.com", RecordType::MX, CLIENT_IP, None);
 
     assert!(result.is_none());
 }
@@ -518,6 +541,9 @@ fn try_cache_wire_direct_returns_wire_bytes_on_cache_hit() {
         cname_chain: Arc::clone(&EMPTY_CNAME_CHAIN),
         upstream_server: None,
         upstream_pool: None,
+        upstream_strategy: None,
+        upstream_attempt: None,
+        upstream_protocol: None,
         min_ttl: Some(300),
         negative_soa_ttl: None,
         upstream_wire_data: Some(wire_bytes.clone()),
@@ -526,7 +552,8 @@ fn try_cache_wire_direct_returns_wire_bytes_on_cache_hit() {
 
     let use_case = make_use_case(resolver, filter, log);
 
-    let result = use_case.try_cache_wire_direct("mail.example.com", RecordType::MX, CLIENT_IP);
+    let result =
+        use_case.try_cache_wire_direct("mail.example.com", RecordType::MX, CLIENT_IP, None);
 
     assert!(result.is_some());
     let (bytes, ttl) = result.unwrap();
@@ -548,7 +575,7 @@ fn try_cache_wire_direct_returns_none_when_cached_resolution_has_no_wire_data()
 
     let use_case = make_use_case(resolver, filter, log);
 
-    let result = use_case.try_cache_wire_direct("example.com", RecordType::MX, CLIENT_IP);
+    let result = use_case.try_cache_wire_direct("example.com", RecordType::MX, CLIENT_IP, None);
 
     assert!(result.is_none());
 }
@@ -572,6 +599,9 @@ fn try_cache_wire_direct_returns_none_when_domain_blocked() {
         cname_chain: Arc::clone(&EMPTY_CNAME_CHAIN),
         upstream_server: None,
         upstream_pool: None,
+        upstream_strategy: None,
+        upstream_attempt: None,
+        upstream_protocol: None,
         min_ttl: Some(60),
         negative_soa_ttl: None,
         upstream_wire_data: Some(Bytes::from_static(b"\xde\xad\xbe\xef")),
@@ -580,7 +610,8 @@ fn try_cache_wire_direct_returns_none_when_domain_blocked() {
 
     let use_case = make_use_case(resolver, filter, log);
 
-    let result = use_case.try_cache_wire_direct("blocked.example.com", RecordType::MX, CLIENT_IP);
+    let result =
+        use_case.try_cache_wire_direct("blocked.example.com", RecordType::MX, CLIENT_IP, None);
 
     assert!(result.is_none());
 }
@@ -627,3 +658,26 @@ async fn test_execute_log_records_response_time() {
     let logs = log.get_sync_logs();
     assert!(logs[0].response_time_us.is_some());
 }
+
+#[tokio::test]
+async fn test_execute_rebinding_rejection_logs_rebind_blocked() {
+    let resolver = Arc::new(MockDnsResolver::new());
+    let filter = Arc::new(MockBlockFilterEngine::new());
+    let log = Arc::new(MockQueryLogRepository::new());
+
+    resolver
+        .set_response_error("rebind.example.com", DomainError::DnsRebindingBlocked)
+        .await;
+
+    let use_case = make_use_case(resolver, filter, log.clone());
+    let request = DnsRequest::new("rebind.example.com", RecordType::A, CLIENT_IP);
+
+    let result = use_case.execute(&request).await;
+
+    assert!(matches!(result, Err(DomainError::Blocked(_))));
+    let logs = log.get_sync_logs();
+    assert_eq!(logs.len(), 1);
+    assert!(logs[0].blocked);
+    assert_eq!(logs[0].response_status, Some("REBIND_BLOCKED"));
+    assert_eq!(logs[0].block_source, Some(BlockSource::DnsRebinding));
+}
@@ -3,14 +3,17 @@
 
 use async_trait::async_trait;
 use ferrous_dns_application::ports::{
-    BlockFilterEnginePort, BlocklistRepository, BlocklistSourceRepository, ClientRepository,
-    DnsResolution, DnsResolver, FilterDecision, GroupRepository, ManagedDomainRepository,
-    QueryLogRepository, TimeGranularity, WhitelistRepository, WhitelistSourceRepository,
+    AlertRepository, BlockFilterEnginePort, BlocklistRepository, BlocklistSourceRepository,
+    ClientActivity, ClientDomainCount, ClientRepository, DnsResolution, DnsResolver,
+    FilterDecision, GroupRepository, ManagedDomainRepository, MetricsWindow, QueryLogRepository,
+    TimeGranularity, TimelineBreakdown, TimelineSeries, WhitelistRepository,
+    WhitelistSourceRepository,
 };
 use ferrous_dns_domain::{
-    blocklist::BlockedDomain, BlockSource, BlocklistSource, Client, ClientStats, DnsQuery,
-    DomainAction, DomainError, Group, ManagedDomain, QueryLog, QueryStats, RecordType,
-    WhitelistSource, WhitelistedDomain,
+    blocklist::BlockedDomain, Alert, AlertKind, BlockSource, BlocklistSource, Client,
+    ClientCategory, ClientStats, DnsQuery, DomainAction, DomainError, Group, ManagedDomain,
+    PageRequest, QueryCountBreakdown, QueryLog, QueryStats, RecordType, WhitelistSource,
+    WhitelistedDomain,
 };
 use std::collections::{HashMap, HashSet};
 use std::net::IpAddr;
@@ -18,6 +21,17 @@ use std::str::FromStr;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
+/// Applies a page window in insertion order; mocks ignore the sort key.
+fn page_of<T: Clone>(items: &[T], page: &PageRequest) -> (Vec<T>, u64) {
+    let paged = items
+        .iter()
+        .skip(page.offset as usize)
+        .take(page.limit as usize)
+        .cloned()
+        .collect();
+    (paged, items.len() as u64)
+}
+
 #[derive(Clone)]
 pub struct MockDnsResolver {
     responses: Arc<RwLock<HashMap<String, DnsResolution>>>,
@@ -107,6 +121,16 @@ impl DnsResolver for MockDnsResolver {
             })
     }
 
+    async fn resolve_via_pool(
+        &self,
+        query: &DnsQuery,
+        pool: &str,
+    ) -> Result<DnsResolution, DomainError> {
+        let mut resolution = self.resolve(query).await?;
+        resolution.upstream_pool = Some(Arc::from(pool));
+        Ok(resolution)
+    }
+
     fn try_cache(&self, query: &DnsQuery) -> Option<DnsResolution> {
         self.cache_responses
             .read()
@@ -177,18 +201,9 @@ impl BlocklistRepository for MockBlocklistRepository {
 
     async fn get_all_paged(
         &self,
-        limit: u32,
-        offset: u32,
+        page: &PageRequest,
     ) -> Result<(Vec<BlockedDomain>, u64), DomainError> {
-        let domains = self.blocked_domains.read().await;
-        let total = domains.len() as u64;
-        let paged = domains
-            .iter()
-            .skip(offset as usize)
-            .take(limit as usize)
-            .cloned()
-            .collect();
-        Ok((paged, total))
+        Ok(page_of(&self.blocked_domains.read().await, page))
     }
 
     async fn add_domain(&self, domain: &BlockedDomain) -> Result<(), DomainError> {
@@ -196,6 +211,21 @@ impl BlocklistRepository for MockBlocklistRepository {
         Ok(())
     }
 
+    async fn add_domains(&self, domains: &[String]) -> Result<Vec<bool>, DomainError> {
+        let mut stored = self.blocked_domains.write().await;
+        Ok(domains
+            .iter()
+            .map(|domain| {
+                if stored.iter().any(|d| &d.domain == domain) {
+                    false
+                } else {
+                    stored.push(BlockedDomain::new(domain.clone()));
+                    true
+                }
+            })
+            .collect())
+    }
+
     async fn remove_domain(&self, domain: &str) -> Result<(), DomainError> {
         let mut domains = self.blocked_domains.write().await;
         domains.retain(|d| d.domain != domain);
@@ -358,6 +388,7 @@ impl QueryLogRepository for MockQueryLogRepository {
             queries_total,
             queries_blocked,
             queries_rate_limited: 0,
+            queries_any_minimized: 0,
             queries_malware_detected: 0,
             unique_clients: 0,
             uptime_seconds: 0,
@@ -365,10 +396,15 @@ impl QueryLogRepository for MockQueryLogRepository {
             avg_query_time_ms: 0.0,
             avg_cache_time_ms: 0.0,
             avg_upstream_time_ms: 0.0,
+            latency_p50_ms: 0.0,
+            latency_p95_ms: 0.0,
+            latency_p99_ms: 0.0,
             source_stats,
             queries_by_type: HashMap::new(),
             most_queried_type: None,
             record_type_distribution: Vec::new(),
+            queries_by_group: Vec::new(),
+            top_clients: Vec::new(),
         })
     }
 
@@ -380,11 +416,65 @@ impl QueryLogRepository for MockQueryLogRepository {
         Ok(Vec::new())
     }
 
+    async fn get_timeline_breakdown(
+        &self,
+        _period_hours: u32,
+        _granularity: TimeGranularity,
+        _breakdown: TimelineBreakdown,
+        _limit: u32,
+    ) -> Result<Vec<TimelineSeries>, DomainError> {
+        Ok(Vec::new())
+    }
+
+    async fn get_query_counts_by(
+        &self,
+        _period_hours: f32,
+        breakdown: TimelineBreakdown,
+        limit: Option<u32>,
+    ) -> Result<Vec<QueryCountBreakdown>, DomainError> {
+        let logs = self.logs.read().await;
+        let mut counts: HashMap<String, QueryCountBreakdown> = HashMap::new();
+        for log in logs.iter() {
+            let key = match breakdown {
+                TimelineBreakdown::Client => log.client_ip.to_string(),
+                TimelineBreakdown::Group => match log.group_id {
+                    Some(id) => id.to_string(),
+                    None => continue,
+                },
+            };
+            let entry = counts
+                .entry(key.clone())
+                .or_insert_with(|| QueryCountBreakdown {
+                    key,
+                    ..Default::default()
+                });
+            entry.queries_total += 1;
+            entry.queries_blocked += u64::from(log.blocked);
+            entry.cache_hits += u64::from(log.cache_hit);
+        }
+        let mut rows: Vec<QueryCountBreakdown> = counts.into_values().collect();
+        rows.sort_by_key(|r| std::cmp::Reverse(r.queries_total));
+        if let Some(limit) = limit {
+            rows.truncate(limit as usize);
+        }
+        Ok(rows)
+    }
+
     async fn count_queries_since(&self, _seconds_ago: i64) -> Result<u64, DomainError> {
         let logs = self.logs.read().await;
         Ok(logs.len() as u64)
     }
 
+    async fn get_metrics_window(&self, _seconds_ago: i64) -> Result<MetricsWindow, DomainError> {
+        let logs = self.logs.read().await;
+        Ok(MetricsWindow {
+            queries: logs.len() as u64,
+            blocked: logs.iter().filter(|l| l.blocked).count() as u64,
+            cache_hits: logs.iter().filter(|l| l.cache_hit).count() as u64,
+            ..Default::default()
+        })
+    }
+
     async fn get_cache_stats(
         &self,
         _period_hours: f32,
@@ -446,6 +536,65 @@ impl QueryLogRepository for MockQueryLogRepository {
         Ok(Vec::new())
     }
 
+    async fn get_top_blocked_clients(
+        &self,
+        _limit: u32,
+        _period_hours: f32,
+    ) -> Result<Vec<(String, u64)>, DomainError> {
+        Ok(Vec::new())
+    }
+
+    async fn get_client_domain_counts(
+        &self,
+        _seconds_ago: i64,
+    ) -> Result<Vec<ClientDomainCount>, DomainError> {
+        let logs = self.logs.read().await;
+        let mut counts: HashMap<(String, String), (u64, u64)> = HashMap::new();
+        for log in logs.iter() {
+            let entry = counts
+                .entry((log.client_ip.to_string(), log.domain.to_string()))
+                .or_default();
+            entry.0 += 1;
+            if log.response_status == Some("NXDOMAIN") {
+                entry.1 += 1;
+            }
+        }
+        Ok(counts
+            .into_iter()
+            .map(
+                |((client_ip, domain), (queries, nxdomain))| ClientDomainCount {
+                    client_ip,
+                    domain,
+                    queries,
+                    nxdomain,
+                },
+            )
+            .collect())
+    }
+
+    async fn get_client_activity(
+        &self,
+        client_ip: &str,
+        _period_hours: u32,
+        _granularity: TimeGranularity,
+        _limit: u32,
+    ) -> Result<ClientActivity, DomainError> {
+        let logs = self.logs.read().await;
+        let client_logs: Vec<&QueryLog> = logs
+            .iter()
+            .filter(|log| log.client_ip.to_string() == client_ip)
+            .collect();
+        let total_queries = client_logs.len() as u64;
+        let blocked_queries = client_logs.iter().filter(|log| log.blocked).count() as u64;
+        let cache_hits = client_logs.iter().filter(|log| log.cache_hit).count() as u64;
+        Ok(ClientActivity {
+            total_queries,
+            blocked_queries,
+            cache_hits,
+            ..Default::default()
+        })
+    }
+
     async fn delete_older_than(&self, _days: u32) -> Result<u64, DomainError> {
         Ok(0)
     }
@@ -531,6 +680,9 @@ impl ClientRepository for MockClientRepository {
             last_mac_update: None,
             last_hostname_update: None,
             group_id: Some(1),
+            display_name: None,
+            category: None,
+            notes: None,
         };
 
         clients.insert(id, client.clone());
@@ -538,6 +690,14 @@ impl ClientRepository for MockClientRepository {
     }
 
     async fn update_last_seen(&self, ip_address: IpAddr) -> Result<(), DomainError> {
+        self.update_last_seen_in_group(ip_address, 1).await
+    }
+
+    async fn update_last_seen_in_group(
+        &self,
+        ip_address: IpAddr,
+        initial_group_id: i64,
+    ) -> Result<(), DomainError> {
         let mut clients = self.clients.write().await;
 
         if let Some(client) = clients.values_mut().find(|c| c.ip_address == ip_address) {
@@ -561,7 +721,10 @@ impl ClientRepository for MockClientRepository {
             query_count: 1,
             last_mac_update: None,
             last_hostname_update: None,
-            group_id: Some(1),
+            group_id: Some(initial_group_id),
+            display_name: None,
+            category: None,
+            notes: None,
         };
 
         clients.insert(id, client);
@@ -646,6 +809,18 @@ impl ClientRepository for MockClientRepository {
         Ok(active)
     }
 
+    async fn get_page(
+        &self,
+        page: &PageRequest,
+        active_days: Option<u32>,
+    ) -> Result<(Vec<Client>, u64), DomainError> {
+        let clients = match active_days {
+            Some(days) => self.get_active(days, u32::MAX).await?,
+            None => self.get_all(u32::MAX, 0).await?,
+        };
+        Ok(page_of(&clients, page))
+    }
+
     async fn get_stats(&self) -> Result<ClientStats, DomainError> {
         let clients = self.clients.read().await;
         let total_clients = clients.len() as u64;
@@ -767,6 +942,25 @@ impl ClientRepository for MockClientRepository {
         }
     }
 
+    async fn update_metadata(
+        &self,
+        client_id: i64,
+        display_name: Option<String>,
+        category: Option<ClientCategory>,
+        notes: Option<String>,
+    ) -> Result<(), DomainError> {
+        let mut clients = self.clients.write().await;
+        match clients.get_mut(&client_id) {
+            Some(client) => {
+                client.display_name = display_name.map(Arc::from);
+                client.category = category;
+                client.notes = notes.map(Arc::from);
+                Ok(())
+            }
+            None => Err(DomainError::ClientNotFound(client_id.to_string())),
+        }
+    }
+
     async fn delete(&self, id: i64) -> Result<(), DomainError> {
         let mut clients = self.clients.write().await;
 
@@ -810,10 +1004,10 @@ impl Default for MockBlocklistSourceRepository {
     }
 }
 
-#[async_trait]
-impl BlocklistSourceRepository for MockBlocklistSourceRepository {
-    async fn create(
+impl MockBlocklistSourceRepository {
+    async fn insert(
         &self,
+        tenant_id: Option<i64>,
         name: String,
         url: Option<String>,
         group_ids: Vec<i64>,
@@ -840,6 +1034,8 @@ impl BlocklistSourceRepository for MockBlocklistSourceRepository {
             group_ids,
             comment: comment.as_deref().map(Arc::from),
             enabled,
+            audit_mode: false,
+            tenant_id,
             created_at: Some("2026-01-01 00:00:00".to_string()),
             updated_at: Some("2026-01-01 00:00:00".to_string()),
         };
@@ -847,16 +1043,71 @@ impl BlocklistSourceRepository for MockBlocklistSourceRepository {
         sources.push(source.clone());
         Ok(source)
     }
+}
+
+#[async_trait]
+impl BlocklistSourceRepository for MockBlocklistSourceRepository {
+    async fn create(
+        &self,
+        name: String,
+        url: Option<String>,
+        group_ids: Vec<i64>,
+        comment: Option<String>,
+        enabled: bool,
+    ) -> Result<BlocklistSource, DomainError> {
+        self.insert(None, name, url, group_ids, comment, enabled)
+            .await
+    }
+
+    async fn create_in_tenant(
+        &self,
+        tenant_id: i64,
+        name: String,
+        url: Option<String>,
+        group_ids: Vec<i64>,
+        comment: Option<String>,
+        enabled: bool,
+    ) -> Result<BlocklistSource, DomainError> {
+        self.insert(Some(tenant_id), name, url, group_ids, comment, enabled)
+            .await
+    }
 
     async fn get_by_id(&self, id: i64) -> Result<Option<BlocklistSource>, DomainError> {
         let sources = self.sources.read().await;
         Ok(sources.iter().find(|s| s.id == Some(id)).cloned())
     }
 
+    async fn get_by_id_in_tenant(
+        &self,
+        tenant_id: i64,
+        id: i64,
+    ) -> Result<Option<BlocklistSource>, DomainError> {
+        Ok(self
+            .get_by_id(id)
+            .await?
+            .filter(|s| s.tenant_id == Some(tenant_id)))
+    }
+
     async fn get_all(&self) -> Result<Vec<BlocklistSource>, DomainError> {
         Ok(self.sources.read().await.clone())
     }
 
+    async fn get_all_in_tenant(&self, tenant_id: i64) -> Result<Vec<BlocklistSource>, DomainError> {
+        let sources = self.sources.read().await;
+        Ok(sources
+            .iter()
+            .filter(|s| s.tenant_id == Some(tenant_id))
+            .cloned()
+            .collect())
+    }
+
+    async fn get_page(
+        &self,
+        page: &PageRequest,
+    ) -> Result<(Vec<BlocklistSource>, u64), DomainError> {
+        Ok(page_of(&self.sources.read().await, page))
+    }
+
     async fn update(
         &self,
         id: i64,
@@ -865,6 +1116,7 @@ impl BlocklistSourceRepository for MockBlocklistSourceRepository {
         group_ids: Option<Vec<i64>>,
         comment: Option<String>,
         enabled: Option<bool>,
+        audit_mode: Option<bool>,
     ) -> Result<BlocklistSource, DomainError> {
         let mut sources = self.sources.write().await;
 
@@ -888,6 +1140,9 @@ impl BlocklistSourceRepository for MockBlocklistSourceRepository {
         if let Some(e) = enabled {
             source.enabled = e;
         }
+        if let Some(a) = audit_mode {
+            source.audit_mode = a;
+        }
 
         Ok(source.clone())
     }
@@ -901,6 +1156,13 @@ impl BlocklistSourceRepository for MockBlocklistSourceRepository {
         }
         Ok(())
     }
+
+    async fn delete_in_tenant(&self, tenant_id: i64, id: i64) -> Result<(), DomainError> {
+        if self.get_by_id_in_tenant(tenant_id, id).await?.is_none() {
+            return Err(DomainError::BlocklistSourceNotFound(id));
+        }
+        self.delete(id).await
+    }
 }
 
 #[derive(Clone)]
@@ -976,12 +1238,34 @@ impl GroupRepository for MockGroupRepository {
         Ok(groups.iter().map(|g| (g.clone(), 0u64)).collect())
     }
 
+    async fn get_in_tenant_with_client_counts(
+        &self,
+        tenant_id: i64,
+    ) -> Result<Vec<(Group, u64)>, DomainError> {
+        let groups = self.groups.read().await;
+        Ok(groups
+            .iter()
+            .filter(|g| g.tenant_id == Some(tenant_id))
+            .map(|g| (g.clone(), 0u64))
+            .collect())
+    }
+
+    async fn get_page_with_client_counts(
+        &self,
+        page: &PageRequest,
+    ) -> Result<(Vec<(Group, u64)>, u64), DomainError> {
+        let groups = self.get_all_with_client_counts().await?;
+        Ok(page_of(&groups, page))
+    }
+
     async fn update(
         &self,
         id: i64,
         name: Option<String>,
         enabled: Option<bool>,
         comment: Option<String>,
+        filter_aaaa: Option<bool>,
+        audit_mode: Option<bool>,
     ) -> Result<Group, DomainError> {
         let mut groups = self.groups.write().await;
         let group = groups
@@ -998,6 +1282,12 @@ impl GroupRepository for MockGroupRepository {
         if let Some(c) = comment {
             group.comment = Some(Arc::from(c.as_str()));
         }
+        if let Some(f) = filter_aaaa {
+            group.filter_aaaa = f;
+        }
+        if let Some(a) = audit_mode {
+            group.audit_mode = a;
+        }
         Ok(group.clone())
     }
 
@@ -1082,11 +1372,33 @@ impl WhitelistRepository for MockWhitelistRepository {
         Ok(self.whitelisted_domains.read().await.clone())
     }
 
+    async fn get_all_paged(
+        &self,
+        page: &PageRequest,
+    ) -> Result<(Vec<WhitelistedDomain>, u64), DomainError> {
+        Ok(page_of(&self.whitelisted_domains.read().await, page))
+    }
+
     async fn add_domain(&self, domain: &WhitelistedDomain) -> Result<(), DomainError> {
         self.whitelisted_domains.write().await.push(domain.clone());
         Ok(())
     }
 
+    async fn add_domains(&self, domains: &[String]) -> Result<Vec<bool>, DomainError> {
+        let mut stored = self.whitelisted_domains.write().await;
+        Ok(domains
+            .iter()
+            .map(|domain| {
+                if stored.iter().any(|d| &d.domain == domain) {
+                    false
+                } else {
+                    stored.push(WhitelistedDomain::new(domain.clone()));
+                    true
+                }
+            })
+            .collect())
+    }
+
     async fn remove_domain(&self, domain: &str) -> Result<(), DomainError> {
         let mut domains = self.whitelisted_domains.write().await;
         domains.retain(|d| d.domain != domain);
@@ -1175,6 +1487,13 @@ impl WhitelistSourceRepository for MockWhitelistSourceRepository {
         Ok(self.sources.read().await.clone())
     }
 
+    async fn get_page(
+        &self,
+        page: &PageRequest,
+    ) -> Result<(Vec<WhitelistSource>, u64), DomainError> {
+        Ok(page_of(&self.sources.read().await, page))
+    }
+
     async fn update(
         &self,
         id: i64,
@@ -1279,6 +1598,9 @@ impl DnsResolutionBuilder {
             cname_chain: self.cname_chain,
             upstream_server: self.upstream_server,
             upstream_pool: None,
+            upstream_strategy: None,
+            upstream_attempt: None,
+            upstream_protocol: None,
             min_ttl: None,
             negative_soa_ttl: None,
             upstream_wire_data: None,
@@ -1310,6 +1632,7 @@ mod tests {
         let query = DnsQuery {
             domain: "example.com".into(),
             record_type: RecordType::A,
+            cache_partition: 0,
         };
 
         let result = resolver.resolve(&query).await;
@@ -1338,6 +1661,7 @@ mod tests {
             record_type: RecordType::A,
             client_ip: IpAddr::from_str("192.168.1.1").unwrap(),
             client_hostname: None,
+            client_mac: None,
             blocked: false,
             response_time_us: Some(10),
             cache_hit: true,
@@ -1345,11 +1669,16 @@ mod tests {
             dnssec_status: None,
             upstream_server: None,
             upstream_pool: None,
+            upstream_strategy: None,
+            upstream_attempt: None,
+            upstream_protocol: None,
             response_status: None,
             timestamp: None,
             query_source: Default::default(),
             group_id: None,
+            group_name: None,
             block_source: None,
+            plugin: None,
         };
 
         log_repo.log_query(&log).await.unwrap();
@@ -1442,18 +1771,9 @@ impl ManagedDomainRepository for MockManagedDomainRepository {
 
     async fn get_all_paged(
         &self,
-        limit: u32,
-        offset: u32,
+        page: &PageRequest,
     ) -> Result<(Vec<ManagedDomain>, u64), DomainError> {
-        let domains = self.domains.read().await;
-        let total = domains.len() as u64;
-        let paged = domains
-            .iter()
-            .skip(offset as usize)
-            .take(limit as usize)
-            .cloned()
-            .collect();
-        Ok((paged, total))
+        Ok(page_of(&self.domains.read().await, page))
     }
 
     async fn update(
@@ -1558,6 +1878,7 @@ pub struct MockBlockFilterEngine {
     should_fail_reload: Arc<RwLock<bool>>,
     blocked_domains: Arc<std::sync::RwLock<HashSet<String>>>,
     cname_blocked_domains: Arc<std::sync::RwLock<HashSet<String>>>,
+    imported_snapshots: Arc<std::sync::RwLock<Vec<Vec<u8>>>>,
 }
 
 impl MockBlockFilterEngine {
@@ -1567,9 +1888,15 @@ impl MockBlockFilterEngine {
             should_fail_reload: Arc::new(RwLock::new(false)),
             blocked_domains: Arc::new(std::sync::RwLock::new(HashSet::new())),
             cname_blocked_domains: Arc::new(std::sync::RwLock::new(HashSet::new())),
+            imported_snapshots: Arc::new(std::sync::RwLock::new(Vec::new())),
         }
     }
 
+    /// List snapshots passed to `import_list_snapshot`, oldest first.
+    pub fn imported_snapshots(&self) -> Vec<Vec<u8>> {
+        self.imported_snapshots.read().unwrap().clone()
+    }
+
     pub async fn reload_count(&self) -> u32 {
         *self.reload_count.read().await
     }
@@ -1602,6 +1929,10 @@ impl BlockFilterEnginePort for MockBlockFilterEngine {
         1
     }
 
+    fn resolve_group_with_default(&self, _ip: IpAddr, default_group_id: i64) -> i64 {
+        default_group_id
+    }
+
     fn check(&self, domain: &str, _group_id: i64) -> FilterDecision {
         if self.cname_blocked_domains.read().unwrap().contains(domain) {
             return FilterDecision::Block(BlockSource::CnameCloaking);
@@ -1631,6 +1962,11 @@ impl BlockFilterEnginePort for MockBlockFilterEngine {
         Ok(())
     }
 
+    async fn import_list_snapshot(&self, snapshot: Vec<u8>) -> Result<(), DomainError> {
+        self.imported_snapshots.write().unwrap().push(snapshot);
+        Ok(())
+    }
+
     fn compiled_domain_count(&self) -> usize {
         0
     }
@@ -1738,34 +2074,1065 @@ impl ResponseIpFilterStore for MockResponseIpFilterStore {
     }
 }
 
-// ── MockDgaFlagStore ──────────────────────────────────────────────────────────
+// ── MockQueryPolicyEngine ─────────────────────────────────────────────────────
 
-use ferrous_dns_application::ports::DgaFlagStore;
+use ferrous_dns_application::ports::QueryPolicyEnginePort;
+use ferrous_dns_domain::{PolicyAction, PolicyMatch};
 
-pub struct MockDgaFlagStore {
-    flagged: std::sync::RwLock<HashSet<String>>,
+/// Matches on domain name only; the first rule set for a domain wins.
+pub struct MockQueryPolicyEngine {
+    rules: std::sync::RwLock<HashMap<String, PolicyMatch>>,
 }
 
-impl MockDgaFlagStore {
+impl MockQueryPolicyEngine {
     pub fn new() -> Self {
         Self {
-            flagged: std::sync::RwLock::new(HashSet::new()),
+            rules: std::sync::RwLock::new(HashMap::new()),
         }
     }
 
-    pub fn flag_domain(&self, domain: &str) {
-        self.flagged.write().unwrap().insert(domain.to_string());
+    pub fn set_policy(&self, domain: &str, action: PolicyAction, target: Option<&str>) {
+        let id = self.rules.read().unwrap().len() as i64 + 1;
+        self.rules.write().unwrap().insert(
+            domain.to_string(),
+            PolicyMatch {
+                policy_id: id,
+                action,
+                target: target.map(Arc::from),
+            },
+        );
     }
 }
 
-impl Default for MockDgaFlagStore {
+impl Default for MockQueryPolicyEngine {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl DgaFlagStore for MockDgaFlagStore {
-    fn is_flagged(&self, domain: &str) -> bool {
-        self.flagged.read().unwrap().contains(domain)
+#[async_trait]
+impl QueryPolicyEnginePort for MockQueryPolicyEngine {
+    fn evaluate(
+        &self,
+        _client_ip: IpAddr,
+        _group_id: i64,
+        domain: &str,
+        _record_type: RecordType,
+    ) -> Option<PolicyMatch> {
+        self.rules.read().unwrap().get(domain).cloned()
+    }
+
+    async fn reload(&self) -> Result<(), DomainError> {
+        Ok(())
+    }
+}
+
+// ── MockDnsRewriteEngine ──────────────────────────────────────────────────────
+
+use ferrous_dns_application::ports::DnsRewriteEnginePort;
+use ferrous_dns_domain::{DnsRewrite, DnsRewriteMatcher, RewriteTarget};
+
+/// Compiles the configured `(domain, answer)` pairs with the real matcher.
+pub struct MockDnsRewriteEngine {
+    matcher: std::sync::RwLock<DnsRewriteMatcher>,
+}
+
+impl MockDnsRewriteEngine {
+    pub fn new() -> Self {
+        Self {
+            matcher: std::sync::RwLock::new(DnsRewriteMatcher::empty()),
+        }
+    }
+
+    pub fn set_rewrites(&self, rewrites: &[(&str, &str)]) {
+        let rewrites = rewrites
+            .iter()
+            .map(|(domain, answer)| DnsRewrite::new(Arc::from(*domain), Arc::from(*answer)))
+            .collect();
+        *self.matcher.write().unwrap() = DnsRewriteMatcher::new(rewrites);
+    }
+}
+
+impl Default for MockDnsRewriteEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl DnsRewriteEnginePort for MockDnsRewriteEngine {
+    fn lookup(&self, domain: &str) -> Option<RewriteTarget> {
+        self.matcher.read().unwrap().lookup(domain)
+    }
+
+    async fn reload(&self) -> Result<(), DomainError> {
+        Ok(())
+    }
+}
+
+// ── MockSplitHorizon ──────────────────────────────────────────────────────────
+
+use ferrous_dns_application::ports::SplitHorizonPort;
+use ferrous_dns_domain::{DnsConfig, SplitHorizonMatcher, ViewAnswer};
+
+/// Compiles the views with the real matcher and counts reloads.
+pub struct MockSplitHorizon {
+    matcher: std::sync::RwLock<SplitHorizonMatcher>,
+    reloads: std::sync::atomic::AtomicUsize,
+}
+
+impl MockSplitHorizon {
+    pub fn new() -> Self {
+        Self {
+            matcher: std::sync::RwLock::new(SplitHorizonMatcher::empty()),
+            reloads: std::sync::atomic::AtomicUsize::new(0),
+        }
+    }
+
+    pub fn reload_count(&self) -> usize {
+        self.reloads.load(std::sync::atomic::Ordering::Relaxed)
+    }
+}
+
+impl Default for MockSplitHorizon {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SplitHorizonPort for MockSplitHorizon {
+    fn lookup(
+        &self,
+        client_ip: IpAddr,
+        group_id: i64,
+        domain: &str,
+        record_type: RecordType,
+    ) -> Option<ViewAnswer> {
+        self.matcher
+            .read()
+            .unwrap()
+            .lookup(client_ip, group_id, domain, record_type)
+    }
+
+    fn reload(&self, dns: &DnsConfig) {
+        *self.matcher.write().unwrap() =
+            SplitHorizonMatcher::new(&dns.views, &dns.local_records, &dns.local_domain);
+        self.reloads
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    }
+}
+
+// ── MockRecordTypeFilter ──────────────────────────────────────────────────────
+
+use ferrous_dns_application::ports::RecordTypeFilterPort;
+
+/// Blocks the configured `(group_id, record_type)` pairs.
+pub struct MockRecordTypeFilter {
+    blocked: std::sync::RwLock<HashSet<(i64, RecordType)>>,
+}
+
+impl MockRecordTypeFilter {
+    pub fn new() -> Self {
+        Self {
+            blocked: std::sync::RwLock::new(HashSet::new()),
+        }
+    }
+
+    pub fn block(&self, group_id: i64, record_type: RecordType) {
+        self.blocked
+            .write()
+            .unwrap()
+            .insert((group_id, record_type));
+    }
+}
+
+impl Default for MockRecordTypeFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl RecordTypeFilterPort for MockRecordTypeFilter {
+    fn is_blocked(&self, group_id: i64, record_type: RecordType) -> bool {
+        self.blocked
+            .read()
+            .unwrap()
+            .contains(&(group_id, record_type))
+    }
+
+    async fn reload(&self) -> Result<(), DomainError> {
+        Ok(())
+    }
+}
+
+// ── MockAaaaFilter ────────────────────────────────────────────────────────────
+
+use ferrous_dns_application::ports::AaaaFilterPort;
+
+/// Reads the flagged groups from a group repository on every reload.
+pub struct MockAaaaFilter {
+    repo: Arc<dyn GroupRepository>,
+    groups: std::sync::RwLock<HashSet<i64>>,
+    reloads: std::sync::atomic::AtomicUsize,
+}
+
+impl MockAaaaFilter {
+    pub fn new(repo: Arc<dyn GroupRepository>) -> Self {
+        Self {
+            repo,
+            groups: std::sync::RwLock::new(HashSet::new()),
+            reloads: std::sync::atomic::AtomicUsize::new(0),
+        }
+    }
+
+    pub fn reload_count(&self) -> usize {
+        self.reloads.load(std::sync::atomic::Ordering::Relaxed)
+    }
+}
+
+#[async_trait]
+impl AaaaFilterPort for MockAaaaFilter {
+    fn filters_aaaa(&self, group_id: i64) -> bool {
+        self.groups.read().unwrap().contains(&group_id)
+    }
+
+    async fn reload(&self) -> Result<(), DomainError> {
+        let groups = self
+            .repo
+            .get_all()
+            .await?
+            .into_iter()
+            .filter(|g| g.filter_aaaa)
+            .filter_map(|g| g.id)
+            .collect();
+        *self.groups.write().unwrap() = groups;
+        self.reloads
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+// ── MockDgaFlagStore ──────────────────────────────────────────────────────────
+
+use ferrous_dns_application::ports::DgaFlagStore;
+
+pub struct MockDgaFlagStore {
+    flagged: std::sync::RwLock<HashSet<String>>,
+}
+
+impl MockDgaFlagStore {
+    pub fn new() -> Self {
+        Self {
+            flagged: std::sync::RwLock::new(HashSet::new()),
+        }
+    }
+
+    pub fn flag_domain(&self, domain: &str) {
+        self.flagged.write().unwrap().insert(domain.to_string());
+    }
+}
+
+impl Default for MockDgaFlagStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DgaFlagStore for MockDgaFlagStore {
+    fn is_flagged(&self, domain: &str) -> bool {
+        self.flagged.read().unwrap().contains(domain)
+    }
+}
+
+#[derive(Default)]
+pub struct MockAlertRepository {
+    alerts: RwLock<Vec<Alert>>,
+}
+
+impl MockAlertRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub async fn all(&self) -> Vec<Alert> {
+        self.alerts.read().await.clone()
+    }
+}
+
+#[async_trait]
+impl AlertRepository for MockAlertRepository {
+    async fn insert(&self, alert: &Alert) -> Result<Alert, DomainError> {
+        let mut alerts = self.alerts.write().await;
+        let mut stored = alert.clone();
+        stored.id = Some(alerts.len() as i64 + 1);
+        alerts.push(stored.clone());
+        Ok(stored)
+    }
+
+    async fn get_recent(
+        &self,
+        limit: u32,
+        client_ip: Option<&str>,
+        kind: Option<AlertKind>,
+    ) -> Result<Vec<Alert>, DomainError> {
+        Ok(self
+            .alerts
+            .read()
+            .await
+            .iter()
+            .rev()
+            .filter(|a| client_ip.is_none_or(|ip| a.client_ip == ip))
+            .filter(|a| kind.is_none_or(|k| a.kind == k))
+            .take(limit as usize)
+            .cloned()
+            .collect())
+    }
+
+    async fn delete(&self, id: i64) -> Result<(), DomainError> {
+        let mut alerts = self.alerts.write().await;
+        let before = alerts.len();
+        alerts.retain(|a| a.id != Some(id));
+        if alerts.len() == before {
+            return Err(DomainError::AlertNotFound(id));
+        }
+        Ok(())
+    }
+
+    async fn delete_older_than(&self, _days: u32) -> Result<u64, DomainError> {
+        Ok(0)
+    }
+}
+
+// ── MockAuditLogRepository ───────────────────────────────────────────────────
+
+use ferrous_dns_application::ports::AuditLogRepository;
+use ferrous_dns_domain::{AuditAction, AuditEvent};
+
+#[derive(Default)]
+pub struct MockAuditLogRepository {
+    events: RwLock<Vec<AuditEvent>>,
+}
+
+impl MockAuditLogRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub async fn all(&self) -> Vec<AuditEvent> {
+        self.events.read().await.clone()
+    }
+}
+
+#[async_trait]
+impl AuditLogRepository for MockAuditLogRepository {
+    async fn insert(&self, event: &AuditEvent) -> Result<AuditEvent, DomainError> {
+        let mut events = self.events.write().await;
+        let mut stored = event.clone();
+        stored.id = Some(events.len() as i64 + 1);
+        events.push(stored.clone());
+        Ok(stored)
+    }
+
+    async fn get_recent(
+        &self,
+        limit: u32,
+        action: Option<AuditAction>,
+    ) -> Result<Vec<AuditEvent>, DomainError> {
+        Ok(self
+            .events
+            .read()
+            .await
+            .iter()
+            .rev()
+            .filter(|e| action.is_none_or(|a| e.action == a))
+            .take(limit as usize)
+            .cloned()
+            .collect())
+    }
+
+    async fn delete_older_than(&self, _days: u32) -> Result<u64, DomainError> {
+        Ok(0)
+    }
+}
+
+// ── MockDnsCache ──────────────────────────────────────────────────────────────
+
+use ferrous_dns_application::ports::{
+    CacheEntrySnapshot, CacheEntryState, CacheExportEntry, CacheMetricsSnapshot, DnsCachePort,
+    HotKeysSnapshot,
+};
+
+/// Serves `peek` from a fixed map of `(domain, record_type)` snapshots.
+pub struct MockDnsCache {
+    entries: std::sync::RwLock<HashMap<(String, RecordType), CacheEntrySnapshot>>,
+    imported: std::sync::RwLock<Vec<CacheExportEntry>>,
+}
+
+impl MockDnsCache {
+    pub fn new() -> Self {
+        Self {
+            entries: std::sync::RwLock::new(HashMap::new()),
+            imported: std::sync::RwLock::new(Vec::new()),
+        }
+    }
+
+    pub fn set_entry(&self, domain: &str, record_type: RecordType, entry: CacheEntrySnapshot) {
+        self.entries
+            .write()
+            .unwrap()
+            .insert((domain.to_string(), record_type), entry);
+    }
+
+    /// Entries passed to `import_entries`, in order.
+    pub fn imported(&self) -> Vec<CacheExportEntry> {
+        self.imported.read().unwrap().clone()
+    }
+}
+
+impl Default for MockDnsCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DnsCachePort for MockDnsCache {
+    fn cache_size(&self) -> usize {
+        self.entries.read().unwrap().len()
+    }
+
+    fn cache_metrics_snapshot(&self) -> CacheMetricsSnapshot {
+        CacheMetricsSnapshot {
+            total_entries: self.cache_size(),
+            hits: 0,
+            misses: 0,
+            insertions: 0,
+            evictions: 0,
+            optimistic_refreshes: 0,
+            stale_hits: 0,
+            l1_hits: 0,
+            memory_bytes: 0,
+            max_memory_bytes: 0,
+            lazy_deletions: 0,
+            compactions: 0,
+            batch_evictions: 0,
+            hit_rate: 0.0,
+            transient_upstream_errors: 0,
+            refresh_budget: None,
+        }
+    }
+
+    fn insert_permanent_record(
+        &self,
+        _domain: &str,
+        _record_type: RecordType,
+        _addresses: Vec<IpAddr>,
+    ) {
+    }
+
+    fn remove_record(&self, domain: &str, record_type: &RecordType) -> bool {
+        self.entries
+            .write()
+            .unwrap()
+            .remove(&(domain.to_string(), *record_type))
+            .is_some()
+    }
+
+    fn flush_suffix(&self, suffix: &str) -> usize {
+        let dotted = format!(".{suffix}");
+        let mut entries = self.entries.write().unwrap();
+        let before = entries.len();
+        entries.retain(|(domain, _), entry| {
+            entry.state == CacheEntryState::Permanent
+                || (domain != suffix && !domain.ends_with(&dotted))
+        });
+        before - entries.len()
+    }
+
+    fn peek(&self, domain: &str, record_type: RecordType) -> Option<CacheEntrySnapshot> {
+        self.entries
+            .read()
+            .unwrap()
+            .get(&(domain.to_string(), record_type))
+            .cloned()
+    }
+
+    fn shard_occupancy(&self) -> Vec<usize> {
+        vec![self.cache_size()]
+    }
+
+    fn hot_keys(&self, _limit: usize) -> HotKeysSnapshot {
+        HotKeysSnapshot::default()
+    }
+
+    fn export_entries(&self, _limit: usize) -> Vec<CacheExportEntry> {
+        Vec::new()
+    }
+
+    fn import_entries(&self, entries: &[CacheExportEntry]) -> usize {
+        self.imported.write().unwrap().extend_from_slice(entries);
+        entries.len()
+    }
+}
+
+// ── MockUpstreamHealth ────────────────────────────────────────────────────────
+
+use ferrous_dns_application::ports::{
+    ResponseValidationStats, UpstreamGroupHealth, UpstreamHealthPort, UpstreamRoute, UpstreamStatus,
+};
+use ferrous_dns_domain::UpstreamStrategy;
+
+/// Routes every query to one pool per name, each with a single server.
+pub struct MockUpstreamHealth;
+
+impl UpstreamHealthPort for MockUpstreamHealth {
+    fn get_all_upstream_status(&self) -> Vec<(String, UpstreamStatus)> {
+        Vec::new()
+    }
+
+    fn get_grouped_upstream_health(&self) -> Vec<UpstreamGroupHealth> {
+        Vec::new()
+    }
+
+    fn response_validation_stats(&self) -> ResponseValidationStats {
+        ResponseValidationStats::default()
+    }
+
+    fn preview_route(&self, pool: Option<&str>) -> Option<UpstreamRoute> {
+        let pool_name = pool.unwrap_or("default").to_string();
+        Some(UpstreamRoute {
+            servers: vec![format!("udp://{pool_name}.example:53")],
+            pool_name,
+            strategy: UpstreamStrategy::Parallel,
+            skipped_pools: Vec::new(),
+        })
+    }
+}
+
+// ── MockLocalRecordRepository ─────────────────────────────────────────────────
+
+use ferrous_dns_application::ports::{LocalRecordRepository, LocalZoneAnswer, LocalZonePort};
+use ferrous_dns_domain::{LocalRecord, LocalRecordData, LocalZone};
+
+/// In-memory record store; `failing()` makes every write return an error.
+#[derive(Default)]
+pub struct MockLocalRecordRepository {
+    records: RwLock<Vec<LocalRecord>>,
+    should_fail: bool,
+}
+
+impl MockLocalRecordRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn failing() -> Self {
+        Self {
+            should_fail: true,
+            ..Self::default()
+        }
+    }
+
+    pub async fn with_records(records: Vec<LocalRecord>) -> Self {
+        let repo = Self::new();
+        for record in records {
+            repo.create(&record).await.unwrap();
+        }
+        repo
+    }
+
+    fn check_writable(&self) -> Result<(), DomainError> {
+        if self.should_fail {
+            return Err(DomainError::DatabaseError("disk full".to_string()));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl LocalRecordRepository for MockLocalRecordRepository {
+    async fn create(&self, record: &LocalRecord) -> Result<LocalRecord, DomainError> {
+        self.check_writable()?;
+        let mut records = self.records.write().await;
+        let mut stored = record.clone();
+        stored.id = Some(records.iter().filter_map(|r| r.id).max().unwrap_or(0) + 1);
+        records.push(stored.clone());
+        Ok(stored)
+    }
+
+    async fn get_by_id(&self, id: i64) -> Result<Option<LocalRecord>, DomainError> {
+        Ok(self
+            .records
+            .read()
+            .await
+            .iter()
+            .find(|r| r.id == Some(id))
+            .cloned())
+    }
+
+    async fn get_by_id_in_tenant(
+        &self,
+        tenant_id: i64,
+        id: i64,
+    ) -> Result<Option<LocalRecord>, DomainError> {
+        Ok(self
+            .get_by_id(id)
+            .await?
+            .filter(|r| r.tenant_id == Some(tenant_id)))
+    }
+
+    async fn get_all(&self) -> Result<Vec<LocalRecord>, DomainError> {
+        Ok(self.records.read().await.clone())
+    }
+
+    async fn get_all_in_tenant(&self, tenant_id: i64) -> Result<Vec<LocalRecord>, DomainError> {
+        Ok(self
+            .records
+            .read()
+            .await
+            .iter()
+            .filter(|r| r.tenant_id == Some(tenant_id))
+            .cloned()
+            .collect())
+    }
+
+    async fn update(&self, record: &LocalRecord) -> Result<LocalRecord, DomainError> {
+        self.check_writable()?;
+        let id = record.id.unwrap_or(0);
+        let mut records = self.records.write().await;
+        let slot = records
+            .iter_mut()
+            .find(|r| r.id == Some(id))
+            .ok_or(DomainError::LocalRecordNotFound(id))?;
+        *slot = record.clone();
+        Ok(record.clone())
+    }
+
+    async fn delete(&self, id: i64) -> Result<(), DomainError> {
+        self.check_writable()?;
+        let mut records = self.records.write().await;
+        let before = records.len();
+        records.retain(|r| r.id != Some(id));
+        if records.len() == before {
+            return Err(DomainError::LocalRecordNotFound(id));
+        }
+        Ok(())
+    }
+}
+
+// ── MockLocalZone ─────────────────────────────────────────────────────────────
+
+/// Compiles the repository's records with the real `LocalZone` on reload and
+/// answers A/AAAA lookups and CNAME aliases from it.
+pub struct MockLocalZone {
+    repo: Arc<MockLocalRecordRepository>,
+    local_domain: Option<String>,
+    loaded: std::sync::RwLock<(LocalZone, Vec<LocalRecord>)>,
+    source_records: std::sync::Mutex<HashMap<String, Vec<LocalRecord>>>,
+    reloads: std::sync::atomic::AtomicUsize,
+}
+
+impl MockLocalZone {
+    pub fn new(repo: Arc<MockLocalRecordRepository>, local_domain: Option<String>) -> Self {
+        Self {
+            repo,
+            local_domain,
+            loaded: std::sync::RwLock::new((LocalZone::empty(), Vec::new())),
+            source_records: std::sync::Mutex::new(HashMap::new()),
+            reloads: std::sync::atomic::AtomicUsize::new(0),
+        }
+    }
+
+    pub fn reload_count(&self) -> usize {
+        self.reloads.load(std::sync::atomic::Ordering::Relaxed)
+    }
+}
+
+#[async_trait]
+impl LocalZonePort for MockLocalZone {
+    fn lookup(&self, domain: &str, record_type: RecordType) -> Option<LocalZoneAnswer> {
+        let loaded = self.loaded.read().unwrap();
+        let entries = loaded.0.lookup(domain)?;
+        let mut addresses = Vec::new();
+        for entry in entries.iter() {
+            match &entry.data {
+                LocalRecordData::Cname(target) if record_type != RecordType::CNAME => {
+                    return Some(LocalZoneAnswer::Cname(target.clone()));
+                }
+                LocalRecordData::Address(ip) if entry.data.record_type() == record_type => {
+                    addresses.push(*ip);
+                }
+                _ => {}
+            }
+        }
+        if addresses.is_empty() {
+            return None;
+        }
+        Some(LocalZoneAnswer::Records(DnsResolution {
+            local_dns: true,
+            ..DnsResolution::new(addresses, false)
+        }))
+    }
+
+    fn view_records(&self) -> Vec<ferrous_dns_domain::LocalDnsRecord> {
+        self.loaded
+            .read()
+            .unwrap()
+            .1
+            .iter()
+            .filter(|r| r.view.is_some())
+            .map(LocalRecord::to_config_record)
+            .collect()
+    }
+
+    fn set_source_records(&self, source: &str, records: Vec<LocalRecord>) {
+        self.source_records
+            .lock()
+            .unwrap()
+            .insert(source.to_string(), records);
+    }
+
+    async fn reload(&self) -> Result<(), DomainError> {
+        let mut records = self.repo.get_all().await?;
+        records.extend(
+            self.source_records
+                .lock()
+                .unwrap()
+                .values()
+                .flatten()
+                .cloned(),
+        );
+        let zone = LocalZone::new(&records, &self.local_domain);
+        *self.loaded.write().unwrap() = (zone, records);
+        self.reloads
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+// ── MockPluginHooks ───────────────────────────────────────────────────────────
+
+use ferrous_dns_application::ports::{PluginDecision, PluginHookPort, PluginQuery, PluginVerdict};
+
+/// Returns fixed verdicts per domain and records the addresses seen by the
+/// post-resolve hook. Only domains given a verdict or `watch`ed are
+/// intercepted.
+#[derive(Default)]
+pub struct MockPluginHooks {
+    watched: std::sync::RwLock<HashSet<String>>,
+    pre_filter: std::sync::RwLock<HashMap<String, PluginVerdict>>,
+    post_resolve: std::sync::RwLock<HashMap<String, PluginVerdict>>,
+    seen_answers: std::sync::Mutex<Vec<Vec<IpAddr>>>,
+}
+
+impl MockPluginHooks {
+    pub const PLUGIN: &'static str = "mock.lua";
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn watch(&self, domain: &str) {
+        self.watched.write().unwrap().insert(domain.to_string());
+    }
+
+    pub fn on_pre_filter(&self, domain: &str, verdict: PluginVerdict) {
+        self.watch(domain);
+        self.pre_filter
+            .write()
+            .unwrap()
+            .insert(domain.to_string(), verdict);
+    }
+
+    pub fn on_post_resolve(&self, domain: &str, verdict: PluginVerdict) {
+        self.watch(domain);
+        self.post_resolve
+            .write()
+            .unwrap()
+            .insert(domain.to_string(), verdict);
+    }
+
+    pub fn seen_answers(&self) -> Vec<Vec<IpAddr>> {
+        self.seen_answers.lock().unwrap().clone()
+    }
+
+    fn decision(verdicts: &HashMap<String, PluginVerdict>, domain: &str) -> Option<PluginDecision> {
+        verdicts.get(domain).cloned().map(|verdict| PluginDecision {
+            plugin: Arc::from(Self::PLUGIN),
+            verdict,
+        })
+    }
+}
+
+#[async_trait]
+impl PluginHookPort for MockPluginHooks {
+    fn intercepts(&self, domain: &str) -> bool {
+        self.watched.read().unwrap().contains(domain)
+    }
+
+    async fn pre_filter(&self, query: &PluginQuery) -> Option<PluginDecision> {
+        Self::decision(&self.pre_filter.read().unwrap(), &query.domain)
+    }
+
+    async fn post_resolve(
+        &self,
+        query: &PluginQuery,
+        addresses: &[IpAddr],
+    ) -> Option<PluginDecision> {
+        self.seen_answers.lock().unwrap().push(addresses.to_vec());
+        Self::decision(&self.post_resolve.read().unwrap(), &query.domain)
+    }
+}
+
+// ── MockUnblockRequestRepository ──────────────────────────────────────────────
+
+use ferrous_dns_application::ports::UnblockRequestRepository;
+use ferrous_dns_domain::{UnblockRequest, UnblockRequestStatus};
+
+/// In-memory unblock requests, keyed like the table on domain and client.
+#[derive(Default)]
+pub struct MockUnblockRequestRepository {
+    requests: RwLock<Vec<UnblockRequest>>,
+}
+
+impl MockUnblockRequestRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl UnblockRequestRepository for MockUnblockRequestRepository {
+    async fn upsert(&self, request: &UnblockRequest) -> Result<UnblockRequest, DomainError> {
+        let mut requests = self.requests.write().await;
+        if let Some(existing) = requests
+            .iter_mut()
+            .find(|r| r.domain == request.domain && r.client_ip == request.client_ip)
+        {
+            if existing.status == UnblockRequestStatus::Pending {
+                existing.group_id = request.group_id;
+                existing.block_source = request.block_source.clone();
+                existing.message = request.message.clone();
+            }
+            return Ok(existing.clone());
+        }
+
+        let mut stored = request.clone();
+        stored.id = Some(requests.len() as i64 + 1);
+        stored.created_at = Some("2026-01-01 00:00:00".to_string());
+        requests.push(stored.clone());
+        Ok(stored)
+    }
+
+    async fn get_by_id(&self, id: i64) -> Result<Option<UnblockRequest>, DomainError> {
+        let requests = self.requests.read().await;
+        Ok(requests.iter().find(|r| r.id == Some(id)).cloned())
+    }
+
+    async fn get_all(
+        &self,
+        status: Option<UnblockRequestStatus>,
+    ) -> Result<Vec<UnblockRequest>, DomainError> {
+        let requests = self.requests.read().await;
+        Ok(requests
+            .iter()
+            .rev()
+            .filter(|r| status.is_none_or(|s| r.status == s))
+            .cloned()
+            .collect())
+    }
+
+    async fn count_pending(&self) -> Result<u64, DomainError> {
+        let requests = self.requests.read().await;
+        Ok(requests
+            .iter()
+            .filter(|r| r.status == UnblockRequestStatus::Pending)
+            .count() as u64)
+    }
+
+    async fn review(
+        &self,
+        id: i64,
+        status: UnblockRequestStatus,
+        managed_domain_id: Option<i64>,
+    ) -> Result<UnblockRequest, DomainError> {
+        let mut requests = self.requests.write().await;
+        let request = requests
+            .iter_mut()
+            .find(|r| r.id == Some(id))
+            .ok_or(DomainError::UnblockRequestNotFound(id))?;
+        if request.status != UnblockRequestStatus::Pending {
+            return Err(DomainError::UnblockRequestAlreadyReviewed(id));
+        }
+        request.status = status;
+        request.managed_domain_id = managed_domain_id;
+        request.reviewed_at = Some("2026-01-02 00:00:00".to_string());
+        Ok(request.clone())
+    }
+
+    async fn delete(&self, id: i64) -> Result<(), DomainError> {
+        let mut requests = self.requests.write().await;
+        let len_before = requests.len();
+        requests.retain(|r| r.id != Some(id));
+        if requests.len() == len_before {
+            return Err(DomainError::UnblockRequestNotFound(id));
+        }
+        Ok(())
+    }
+}
+
+// ── MockReportSender ──────────────────────────────────────────────────────────
+
+use ferrous_dns_application::ports::ReportSender;
+
+/// Records every report as `(subject, html)` instead of emailing it.
+#[derive(Default)]
+pub struct MockReportSender {
+    pub sent: std::sync::Mutex<Vec<(String, String)>>,
+    pub fail: bool,
+}
+
+impl MockReportSender {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn failing() -> Self {
+        Self {
+            fail: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn sent(&self) -> Vec<(String, String)> {
+        self.sent.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl ReportSender for MockReportSender {
+    async fn send(&self, subject: &str, html: &str) -> Result<(), DomainError> {
+        if self.fail {
+            return Err(DomainError::NotificationDeliveryFailed(
+                "SMTP unavailable".to_string(),
+            ));
+        }
+        self.sent
+            .lock()
+            .unwrap()
+            .push((subject.to_string(), html.to_string()));
+        Ok(())
+    }
+}
+
+// ── MockDnssecStatus ──────────────────────────────────────────────────────────
+
+use ferrous_dns_application::ports::{
+    DnssecCachedValidation, DnssecHistoryRepository, DnssecOutcomes, DnssecStatusPort,
+};
+use ferrous_dns_domain::{DnssecStatsSample, DnssecStatusChange, DnssecValidationCounts};
+
+/// Serves fixed cached results and hands out queued outcomes once.
+#[derive(Default)]
+pub struct MockDnssecStatus {
+    pub cached: std::sync::Mutex<Vec<(String, DnssecCachedValidation)>>,
+    pub pending: std::sync::Mutex<DnssecOutcomes>,
+}
+
+impl MockDnssecStatus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_cached(self, domain: &str, validation: DnssecCachedValidation) -> Self {
+        self.cached
+            .lock()
+            .unwrap()
+            .push((domain.to_string(), validation));
+        self
+    }
+
+    pub fn with_outcomes(self, outcomes: DnssecOutcomes) -> Self {
+        *self.pending.lock().unwrap() = outcomes;
+        self
+    }
+}
+
+impl DnssecStatusPort for MockDnssecStatus {
+    fn cached(&self, domain: &str) -> Vec<DnssecCachedValidation> {
+        self.cached
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(cached_domain, _)| cached_domain == domain)
+            .map(|(_, validation)| validation.clone())
+            .collect()
+    }
+
+    fn take_outcomes(&self) -> DnssecOutcomes {
+        std::mem::take(&mut *self.pending.lock().unwrap())
+    }
+}
+
+// ── MockDnssecHistoryRepository ───────────────────────────────────────────────
+
+/// In-memory history; samples get a running `recorded_at` counter.
+#[derive(Default)]
+pub struct MockDnssecHistoryRepository {
+    pub samples: RwLock<Vec<DnssecStatsSample>>,
+    pub changes: RwLock<Vec<DnssecStatusChange>>,
+}
+
+impl MockDnssecHistoryRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl DnssecHistoryRepository for MockDnssecHistoryRepository {
+    async fn insert_sample(&self, counts: &DnssecValidationCounts) -> Result<(), DomainError> {
+        let mut samples = self.samples.write().await;
+        let recorded_at = format!("2026-01-01 00:{:02}:00", samples.len());
+        samples.push(DnssecStatsSample {
+            counts: *counts,
+            recorded_at,
+        });
+        Ok(())
+    }
+
+    async fn insert_changes(&self, changes: &[DnssecStatusChange]) -> Result<(), DomainError> {
+        let mut stored = self.changes.write().await;
+        for change in changes {
+            let mut change = change.clone();
+            change.id = Some(stored.len() as i64 + 1);
+            stored.push(change);
+        }
+        Ok(())
+    }
+
+    async fn get_samples(&self, _period_hours: u32) -> Result<Vec<DnssecStatsSample>, DomainError> {
+        Ok(self.samples.read().await.clone())
+    }
+
+    async fn get_changes(
+        &self,
+        domain: Option<&str>,
+        status: Option<&'static str>,
+        limit: u32,
+    ) -> Result<Vec<DnssecStatusChange>, DomainError> {
+        let changes = self.changes.read().await;
+        Ok(changes
+            .iter()
+            .rev()
+            .filter(|c| domain.is_none_or(|d| c.domain == d))
+            .filter(|c| status.is_none_or(|s| c.status == s))
+            .take(limit as usize)
+            .cloned()
+            .collect())
+    }
+
+    async fn delete_older_than(&self, _days: u32) -> Result<u64, DomainError> {
+        Ok(0)
     }
 }
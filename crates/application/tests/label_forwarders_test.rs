@@ -0,0 +1,75 @@
+use ferrous_dns_application::use_cases::LabelForwardersUseCase;
+use ferrous_dns_domain::{Client, ClientCategory};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+mod helpers;
+use helpers::MockClientRepository;
+
+fn ip(s: &str) -> IpAddr {
+    s.parse().unwrap()
+}
+
+fn find(clients: &[Client], address: &str) -> Client {
+    clients
+        .iter()
+        .find(|c| c.ip_address == ip(address))
+        .cloned()
+        .expect("client exists")
+}
+
+#[tokio::test]
+async fn test_creates_and_labels_new_forwarders() {
+    let repo = Arc::new(MockClientRepository::new());
+    let use_case = LabelForwardersUseCase::new(repo.clone());
+
+    let labelled = use_case
+        .execute(&[
+            (ip("192.168.1.1"), "Main router".to_string()),
+            (ip("192.168.2.1"), "Guest AP".to_string()),
+        ])
+        .await
+        .unwrap();
+
+    assert_eq!(labelled, 2);
+    let clients = repo.get_all_clients().await;
+    let router = find(&clients, "192.168.1.1");
+    assert_eq!(router.display_name.as_deref(), Some("Main router"));
+    assert_eq!(router.category, Some(ClientCategory::Router));
+    assert_eq!(
+        find(&clients, "192.168.2.1").display_name.as_deref(),
+        Some("Guest AP")
+    );
+}
+
+#[tokio::test]
+async fn test_keeps_names_and_categories_set_by_user() {
+    let mut named = Client::new(ip("192.168.1.1"));
+    named.id = Some(1);
+    named.display_name = Some(Arc::from("Upstairs"));
+    let mut categorised = Client::new(ip("192.168.2.1"));
+    categorised.id = Some(2);
+    categorised.category = Some(ClientCategory::Server);
+    categorised.notes = Some(Arc::from("pi in the closet"));
+    let repo = Arc::new(MockClientRepository::with_clients(vec![named, categorised]).await);
+    let use_case = LabelForwardersUseCase::new(repo.clone());
+
+    let labelled = use_case
+        .execute(&[
+            (ip("192.168.1.1"), "Main router".to_string()),
+            (ip("192.168.2.1"), "Guest AP".to_string()),
+        ])
+        .await
+        .unwrap();
+
+    assert_eq!(labelled, 1);
+    let clients = repo.get_all_clients().await;
+    assert_eq!(
+        find(&clients, "192.168.1.1").display_name.as_deref(),
+        Some("Upstairs")
+    );
+    let guest = find(&clients, "192.168.2.1");
+    assert_eq!(guest.display_name.as_deref(), Some("Guest AP"));
+    assert_eq!(guest.category, Some(ClientCategory::Server));
+    assert_eq!(guest.notes.as_deref(), Some("pi in the closet"));
+}
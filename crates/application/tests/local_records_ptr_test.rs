@@ -1,40 +1,15 @@
-use async_trait::async_trait;
-use ferrous_dns_application::ports::{ConfigRepository, PtrRecordRegistry};
+mod helpers;
+
+use ferrous_dns_application::ports::PtrRecordRegistry;
 use ferrous_dns_application::use_cases::{
     CreateLocalRecordUseCase, DeleteLocalRecordUseCase, UpdateLocalRecordUseCase,
 };
-use ferrous_dns_domain::{Config, DomainError, LocalDnsRecord};
+use ferrous_dns_domain::{Config, LocalRecord, RecordType};
+use helpers::{MockLocalRecordRepository, MockLocalZone};
 use std::net::IpAddr;
 use std::sync::{Arc, Mutex};
 use tokio::sync::RwLock;
 
-// ── Mock ConfigRepository ────────────────────────────────────────────────────
-
-struct MockConfigRepository {
-    should_fail: bool,
-}
-
-impl MockConfigRepository {
-    fn ok() -> Arc<Self> {
-        Arc::new(Self { should_fail: false })
-    }
-
-    fn failing() -> Arc<Self> {
-        Arc::new(Self { should_fail: true })
-    }
-}
-
-#[async_trait]
-impl ConfigRepository for MockConfigRepository {
-    async fn save_local_records(&self, _config: &Config) -> Result<(), DomainError> {
-        if self.should_fail {
-            Err(DomainError::IoError("disk full".to_string()))
-        } else {
-            Ok(())
-        }
-    }
-}
-
 // ── Mock PtrRecordRegistry ───────────────────────────────────────────────────
 
 #[derive(Default)]
@@ -68,16 +43,19 @@ fn default_config() -> Arc<RwLock<Config>> {
     Arc::new(RwLock::new(Config::default()))
 }
 
-fn config_with_record(ip: &str) -> Arc<RwLock<Config>> {
-    let mut config = Config::default();
-    config.dns.local_records.push(LocalDnsRecord {
-        hostname: "host".to_string(),
-        domain: Some("local".to_string()),
-        ip: ip.to_string(),
-        record_type: "A".to_string(),
-        ttl: Some(300),
-    });
-    Arc::new(RwLock::new(config))
+fn a_record(hostname: &str, ip: &str) -> LocalRecord {
+    LocalRecord {
+        domain: Some(Arc::from("local")),
+        ..LocalRecord::new(Arc::from(hostname), RecordType::A, Arc::from(ip))
+    }
+}
+
+fn zone_for(repo: &Arc<MockLocalRecordRepository>) -> Arc<MockLocalZone> {
+    Arc::new(MockLocalZone::new(repo.clone(), None))
+}
+
+async fn repo_with_record(ip: &str) -> Arc<MockLocalRecordRepository> {
+    Arc::new(MockLocalRecordRepository::with_records(vec![a_record("host", ip)]).await)
 }
 
 // ── Tests ────────────────────────────────────────────────────────────────────
@@ -85,18 +63,11 @@ fn config_with_record(ip: &str) -> Arc<RwLock<Config>> {
 #[tokio::test]
 async fn test_create_local_record_registers_ptr_in_registry() {
     let registry = MockPtrRegistry::new_arc();
-    let use_case = CreateLocalRecordUseCase::new(default_config(), MockConfigRepository::ok())
+    let repo = Arc::new(MockLocalRecordRepository::new());
+    let use_case = CreateLocalRecordUseCase::new(default_config(), repo.clone(), zone_for(&repo))
         .with_ptr_registry(Some(registry.clone() as Arc<dyn PtrRecordRegistry>));
 
-    let result = use_case
-        .execute(
-            "nas".to_string(),
-            Some("local".to_string()),
-            "10.0.10.5".to_string(),
-            "A".to_string(),
-            Some(300),
-        )
-        .await;
+    let result = use_case.execute(a_record("nas", "10.0.10.5")).await;
 
     assert!(result.is_ok());
     let registered = registry.registered.lock().unwrap();
@@ -108,29 +79,47 @@ async fn test_create_local_record_registers_ptr_in_registry() {
 
 #[tokio::test]
 async fn test_create_local_record_without_registry_succeeds() {
-    let use_case = CreateLocalRecordUseCase::new(default_config(), MockConfigRepository::ok());
-
-    let result = use_case
-        .execute(
-            "host".to_string(),
-            None,
-            "10.0.10.1".to_string(),
-            "A".to_string(),
-            None,
-        )
-        .await;
+    let repo = Arc::new(MockLocalRecordRepository::new());
+    let use_case = CreateLocalRecordUseCase::new(default_config(), repo.clone(), zone_for(&repo));
+
+    let result = use_case.execute(a_record("host", "10.0.10.1")).await;
 
     assert!(result.is_ok());
 }
 
+#[tokio::test]
+async fn test_create_non_address_record_does_not_register_ptr() {
+    let registry = MockPtrRegistry::new_arc();
+    let repo = Arc::new(MockLocalRecordRepository::new());
+    let use_case = CreateLocalRecordUseCase::new(default_config(), repo.clone(), zone_for(&repo))
+        .with_ptr_registry(Some(registry.clone() as Arc<dyn PtrRecordRegistry>));
+
+    let record = LocalRecord::new(Arc::from("mail"), RecordType::MX, Arc::from("10 mx.local"));
+    use_case.execute(record).await.unwrap();
+
+    assert!(registry.registered.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_create_wildcard_record_does_not_register_ptr() {
+    let registry = MockPtrRegistry::new_arc();
+    let repo = Arc::new(MockLocalRecordRepository::new());
+    let use_case = CreateLocalRecordUseCase::new(default_config(), repo.clone(), zone_for(&repo))
+        .with_ptr_registry(Some(registry.clone() as Arc<dyn PtrRecordRegistry>));
+
+    use_case.execute(a_record("*", "127.0.0.1")).await.unwrap();
+
+    assert!(registry.registered.lock().unwrap().is_empty());
+}
+
 #[tokio::test]
 async fn test_delete_local_record_unregisters_ptr_in_registry() {
     let registry = MockPtrRegistry::new_arc();
-    let use_case =
-        DeleteLocalRecordUseCase::new(config_with_record("10.0.10.1"), MockConfigRepository::ok())
-            .with_ptr_registry(Some(registry.clone() as Arc<dyn PtrRecordRegistry>));
+    let repo = repo_with_record("10.0.10.1").await;
+    let use_case = DeleteLocalRecordUseCase::new(default_config(), repo.clone(), zone_for(&repo))
+        .with_ptr_registry(Some(registry.clone() as Arc<dyn PtrRecordRegistry>));
 
-    let result = use_case.execute(0).await;
+    let result = use_case.execute(1).await;
 
     assert!(result.is_ok());
     let unregistered = registry.unregistered.lock().unwrap();
@@ -141,20 +130,15 @@ async fn test_delete_local_record_unregisters_ptr_in_registry() {
 #[tokio::test]
 async fn test_update_local_record_swaps_ptr_in_registry() {
     let registry = MockPtrRegistry::new_arc();
-    let use_case =
-        UpdateLocalRecordUseCase::new(config_with_record("10.0.10.1"), MockConfigRepository::ok())
-            .with_ptr_registry(Some(registry.clone() as Arc<dyn PtrRecordRegistry>));
-
-    let result = use_case
-        .execute(
-            0,
-            "newhost".to_string(),
-            Some("local".to_string()),
-            "10.0.10.9".to_string(),
-            "A".to_string(),
-            Some(600),
-        )
-        .await;
+    let repo = repo_with_record("10.0.10.1").await;
+    let use_case = UpdateLocalRecordUseCase::new(default_config(), repo.clone(), zone_for(&repo))
+        .with_ptr_registry(Some(registry.clone() as Arc<dyn PtrRecordRegistry>));
+
+    let record = LocalRecord {
+        ttl: 600,
+        ..a_record("newhost", "10.0.10.9")
+    };
+    let result = use_case.execute(1, record).await;
 
     assert!(result.is_ok());
     let unregistered = registry.unregistered.lock().unwrap();
@@ -162,28 +146,22 @@ async fn test_update_local_record_swaps_ptr_in_registry() {
     assert_eq!(unregistered[0], "10.0.10.1".parse::<IpAddr>().unwrap());
     assert_eq!(registered[0].0, "10.0.10.9".parse::<IpAddr>().unwrap());
     assert_eq!(registered[0].1, "newhost.local");
+    assert_eq!(registered[0].2, 600);
 }
 
 #[tokio::test]
 async fn test_create_local_record_does_not_register_on_save_failure() {
     let registry = MockPtrRegistry::new_arc();
-    let use_case = CreateLocalRecordUseCase::new(default_config(), MockConfigRepository::failing())
+    let repo = Arc::new(MockLocalRecordRepository::failing());
+    let use_case = CreateLocalRecordUseCase::new(default_config(), repo.clone(), zone_for(&repo))
         .with_ptr_registry(Some(registry.clone() as Arc<dyn PtrRecordRegistry>));
 
-    let result = use_case
-        .execute(
-            "nas".to_string(),
-            Some("local".to_string()),
-            "10.0.10.5".to_string(),
-            "A".to_string(),
-            Some(300),
-        )
-        .await;
+    let result = use_case.execute(a_record("nas", "10.0.10.5")).await;
 
     assert!(result.is_err());
     let registered = registry.registered.lock().unwrap();
     assert!(
         registered.is_empty(),
-        "register must NOT be called on rollback path"
+        "register must NOT be called when the record was not stored"
     );
 }
@@ -0,0 +1,416 @@
+mod helpers;
+
+use ferrous_dns_application::ports::{
+    CacheEntrySnapshot, CacheEntryState, DnsCachePort, DnsResolution, LocalRecordRepository,
+    LocalZonePort,
+};
+use ferrous_dns_application::use_cases::{
+    CreateLocalRecordUseCase, DeleteLocalRecordUseCase, HandleDnsQueryUseCase,
+    UpdateLocalRecordUseCase,
+};
+use ferrous_dns_domain::{Config, DnsRequest, DomainError, LocalRecord, RecordType};
+use helpers::{
+    MockBlockFilterEngine, MockDnsCache, MockDnsResolver, MockLocalRecordRepository, MockLocalZone,
+    MockQueryLogRepository,
+};
+use std::net::IpAddr;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+const CLIENT: IpAddr = IpAddr::V4(std::net::Ipv4Addr::new(192, 168, 1, 50));
+
+fn record(hostname: &str, record_type: RecordType, value: &str) -> LocalRecord {
+    LocalRecord {
+        domain: Some(Arc::from("home.lan")),
+        ..LocalRecord::new(Arc::from(hostname), record_type, Arc::from(value))
+    }
+}
+
+fn snapshot(state: CacheEntryState) -> CacheEntrySnapshot {
+    CacheEntrySnapshot {
+        state,
+        ttl: Some(300),
+        remaining_ttl: 300,
+        dnssec_status: None,
+        hit_count: 0,
+    }
+}
+
+fn ip(s: &str) -> IpAddr {
+    s.parse().unwrap()
+}
+
+struct Fixture {
+    config: Arc<RwLock<Config>>,
+    repo: Arc<MockLocalRecordRepository>,
+    zone: Arc<MockLocalZone>,
+    cache: Arc<MockDnsCache>,
+}
+
+impl Fixture {
+    async fn new(records: Vec<LocalRecord>) -> Self {
+        let repo = Arc::new(MockLocalRecordRepository::with_records(records).await);
+        let zone = Arc::new(MockLocalZone::new(repo.clone(), None));
+        zone.reload().await.unwrap();
+        Self {
+            config: Arc::new(RwLock::new(Config::default())),
+            repo,
+            zone,
+            cache: Arc::new(MockDnsCache::new()),
+        }
+    }
+
+    fn create(&self) -> CreateLocalRecordUseCase {
+        CreateLocalRecordUseCase::new(self.config.clone(), self.repo.clone(), self.zone.clone())
+            .with_dns_cache(Some(self.cache.clone() as Arc<dyn DnsCachePort>))
+    }
+
+    fn update(&self) -> UpdateLocalRecordUseCase {
+        UpdateLocalRecordUseCase::new(self.config.clone(), self.repo.clone(), self.zone.clone())
+            .with_dns_cache(Some(self.cache.clone() as Arc<dyn DnsCachePort>))
+    }
+
+    fn delete(&self) -> DeleteLocalRecordUseCase {
+        DeleteLocalRecordUseCase::new(self.config.clone(), self.repo.clone(), self.zone.clone())
+            .with_dns_cache(Some(self.cache.clone() as Arc<dyn DnsCachePort>))
+    }
+}
+
+#[tokio::test]
+async fn create_reloads_zone_so_record_is_answered_immediately() {
+    let f = Fixture::new(vec![]).await;
+
+    f.create()
+        .execute(record("nas", RecordType::A, "192.168.1.10"))
+        .await
+        .unwrap();
+
+    assert_eq!(f.zone.reload_count(), 2);
+    assert!(f.zone.lookup("nas.home.lan", RecordType::A).is_some());
+}
+
+#[tokio::test]
+async fn create_evicts_cached_answers_for_the_name() {
+    let f = Fixture::new(vec![]).await;
+    f.cache.set_entry(
+        "nas.home.lan",
+        RecordType::A,
+        snapshot(CacheEntryState::Fresh),
+    );
+    f.cache.set_entry(
+        "nas.home.lan",
+        RecordType::TXT,
+        snapshot(CacheEntryState::Negative),
+    );
+
+    f.create()
+        .execute(record("nas", RecordType::A, "192.168.1.10"))
+        .await
+        .unwrap();
+
+    assert!(f.cache.peek("nas.home.lan", RecordType::A).is_none());
+    assert!(f.cache.peek("nas.home.lan", RecordType::TXT).is_none());
+}
+
+#[tokio::test]
+async fn change_keeps_permanent_config_file_entries_in_cache() {
+    let f = Fixture::new(vec![]).await;
+    f.cache.set_entry(
+        "nas.home.lan",
+        RecordType::AAAA,
+        snapshot(CacheEntryState::Permanent),
+    );
+
+    f.create()
+        .execute(record("nas", RecordType::A, "192.168.1.10"))
+        .await
+        .unwrap();
+
+    assert!(f.cache.peek("nas.home.lan", RecordType::AAAA).is_some());
+}
+
+#[tokio::test]
+async fn update_evicts_both_old_and_new_names() {
+    let f = Fixture::new(vec![record("old", RecordType::A, "10.0.0.1")]).await;
+    f.cache.set_entry(
+        "old.home.lan",
+        RecordType::A,
+        snapshot(CacheEntryState::Fresh),
+    );
+    f.cache.set_entry(
+        "new.home.lan",
+        RecordType::A,
+        snapshot(CacheEntryState::Negative),
+    );
+
+    f.update()
+        .execute(1, record("new", RecordType::A, "10.0.0.2"))
+        .await
+        .unwrap();
+
+    assert!(f.cache.peek("old.home.lan", RecordType::A).is_none());
+    assert!(f.cache.peek("new.home.lan", RecordType::A).is_none());
+    assert!(f.zone.lookup("old.home.lan", RecordType::A).is_none());
+    assert!(f.zone.lookup("new.home.lan", RecordType::A).is_some());
+}
+
+#[tokio::test]
+async fn delete_removes_record_from_zone() {
+    let f = Fixture::new(vec![record("nas", RecordType::A, "10.0.0.1")]).await;
+
+    let removed = f.delete().execute(1).await.unwrap();
+
+    assert_eq!(removed.hostname.as_ref(), "nas");
+    assert!(f.zone.lookup("nas.home.lan", RecordType::A).is_none());
+}
+
+#[tokio::test]
+async fn update_missing_record_returns_not_found() {
+    let f = Fixture::new(vec![]).await;
+
+    let result = f
+        .update()
+        .execute(7, record("nas", RecordType::A, "10.0.0.1"))
+        .await;
+
+    assert!(matches!(result, Err(DomainError::LocalRecordNotFound(7))));
+}
+
+#[tokio::test]
+async fn delete_missing_record_returns_not_found() {
+    let f = Fixture::new(vec![]).await;
+
+    let result = f.delete().execute(7).await;
+
+    assert!(matches!(result, Err(DomainError::LocalRecordNotFound(7))));
+}
+
+#[tokio::test]
+async fn invalid_record_is_rejected_before_storage() {
+    let f = Fixture::new(vec![]).await;
+
+    let result = f
+        .create()
+        .execute(record(
+            "mail",
+            RecordType::MX,
+            "not-a-preference mx.home.lan",
+        ))
+        .await;
+
+    assert!(matches!(result, Err(DomainError::InvalidLocalRecord(_))));
+    assert!(f.repo.get_all().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn cname_next_to_other_record_is_rejected() {
+    let f = Fixture::new(vec![record("www", RecordType::A, "10.0.0.1")]).await;
+
+    let result = f
+        .create()
+        .execute(record("www", RecordType::CNAME, "web.home.lan"))
+        .await;
+
+    assert!(matches!(result, Err(DomainError::LocalRecordConflict(_))));
+}
+
+#[tokio::test]
+async fn record_next_to_existing_cname_is_rejected() {
+    let f = Fixture::new(vec![record("www", RecordType::CNAME, "web.home.lan")]).await;
+
+    let result = f
+        .create()
+        .execute(record("www", RecordType::TXT, "hello"))
+        .await;
+
+    assert!(matches!(result, Err(DomainError::LocalRecordConflict(_))));
+}
+
+#[tokio::test]
+async fn updating_cname_in_place_is_allowed() {
+    let f = Fixture::new(vec![record("www", RecordType::CNAME, "web.home.lan")]).await;
+
+    let result = f
+        .update()
+        .execute(1, record("www", RecordType::CNAME, "web2.home.lan"))
+        .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn bare_wildcard_without_domain_is_rejected() {
+    let f = Fixture::new(vec![]).await;
+
+    let result = f
+        .create()
+        .execute(LocalRecord::new(
+            Arc::from("*"),
+            RecordType::A,
+            Arc::from("127.0.0.1"),
+        ))
+        .await;
+
+    assert!(matches!(result, Err(DomainError::InvalidLocalRecord(_))));
+    assert!(f.repo.get_all().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn bare_wildcard_under_local_domain_is_accepted() {
+    let f = Fixture::new(vec![]).await;
+    f.config.write().await.dns.local_domain = Some("dev.lan".to_string());
+
+    let result = f
+        .create()
+        .execute(LocalRecord::new(
+            Arc::from("*"),
+            RecordType::A,
+            Arc::from("127.0.0.1"),
+        ))
+        .await;
+
+    assert!(result.is_ok());
+}
+
+// ── HandleDnsQueryUseCase ─────────────────────────────────────────────────────
+
+fn handler(f: &Fixture, resolver: Arc<MockDnsResolver>) -> HandleDnsQueryUseCase {
+    HandleDnsQueryUseCase::new(
+        resolver,
+        Arc::new(MockBlockFilterEngine::new()),
+        Arc::new(MockQueryLogRepository::new()),
+    )
+    .with_local_zone(f.zone.clone())
+}
+
+#[tokio::test]
+async fn query_is_answered_from_local_zone() {
+    let f = Fixture::new(vec![record("nas", RecordType::A, "192.168.1.10")]).await;
+    let log = Arc::new(MockQueryLogRepository::new());
+    let use_case = HandleDnsQueryUseCase::new(
+        Arc::new(MockDnsResolver::new()),
+        Arc::new(MockBlockFilterEngine::new()),
+        log.clone(),
+    )
+    .with_local_zone(f.zone.clone());
+
+    let request = DnsRequest::new("nas.home.lan", RecordType::A, CLIENT);
+    let result = use_case.execute(&request).await.unwrap();
+
+    assert_eq!(result.addresses.as_slice(), &[ip("192.168.1.10")]);
+    assert!(result.local_dns);
+    let logs = log.get_sync_logs();
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].response_status, Some("LOCAL_DNS"));
+}
+
+#[tokio::test]
+async fn local_zone_takes_precedence_over_block_filter() {
+    let f = Fixture::new(vec![record("nas", RecordType::A, "192.168.1.10")]).await;
+    let blocker = Arc::new(MockBlockFilterEngine::new());
+    blocker.block_domain("nas.home.lan");
+    let use_case = HandleDnsQueryUseCase::new(
+        Arc::new(MockDnsResolver::new()),
+        blocker,
+        Arc::new(MockQueryLogRepository::new()),
+    )
+    .with_local_zone(f.zone.clone());
+
+    let request = DnsRequest::new("nas.home.lan", RecordType::A, CLIENT);
+    let result = use_case.execute(&request).await.unwrap();
+
+    assert_eq!(result.addresses.as_slice(), &[ip("192.168.1.10")]);
+}
+
+#[tokio::test]
+async fn cname_to_local_name_is_answered_from_zone() {
+    let f = Fixture::new(vec![
+        record("nas", RecordType::A, "192.168.1.10"),
+        record("files", RecordType::CNAME, "nas.home.lan"),
+    ])
+    .await;
+    let use_case = handler(&f, Arc::new(MockDnsResolver::new()));
+
+    let request = DnsRequest::new("files.home.lan", RecordType::A, CLIENT);
+    let result = use_case.execute(&request).await.unwrap();
+
+    assert_eq!(result.addresses.as_slice(), &[ip("192.168.1.10")]);
+}
+
+#[tokio::test]
+async fn cname_to_external_name_is_resolved_upstream() {
+    let f = Fixture::new(vec![record("cdn", RecordType::CNAME, "cdn.example.com")]).await;
+    let resolver = Arc::new(MockDnsResolver::new());
+    resolver
+        .set_response(
+            "cdn.example.com",
+            DnsResolution::new(vec![ip("203.0.113.7")], false),
+        )
+        .await;
+    let use_case = handler(&f, resolver);
+
+    let request = DnsRequest::new("cdn.home.lan", RecordType::A, CLIENT);
+    let result = use_case.execute(&request).await.unwrap();
+
+    assert_eq!(result.addresses.as_slice(), &[ip("203.0.113.7")]);
+}
+
+#[tokio::test]
+async fn local_name_bypasses_cache_fast_path() {
+    let f = Fixture::new(vec![record("nas", RecordType::A, "192.168.1.10")]).await;
+    let resolver = Arc::new(MockDnsResolver::new());
+    for domain in ["nas.home.lan", "other.home.lan"] {
+        resolver.set_cached_response(domain, DnsResolution::new(vec![ip("10.9.9.9")], true));
+    }
+    let use_case = handler(&f, resolver);
+
+    assert!(use_case
+        .try_cache_direct("nas.home.lan", RecordType::A, CLIENT, None)
+        .is_none());
+    assert!(use_case
+        .try_cache_direct("other.home.lan", RecordType::A, CLIENT, None)
+        .is_some());
+}
+
+#[tokio::test]
+async fn type_without_local_record_takes_normal_path() {
+    let f = Fixture::new(vec![record("nas", RecordType::A, "192.168.1.10")]).await;
+    let resolver = Arc::new(MockDnsResolver::new());
+    resolver
+        .set_response(
+            "nas.home.lan",
+            DnsResolution::new(vec![ip("fd00::10")], false),
+        )
+        .await;
+    let use_case = handler(&f, resolver);
+
+    let request = DnsRequest::new("nas.home.lan", RecordType::AAAA, CLIENT);
+    let result = use_case.execute(&request).await.unwrap();
+
+    assert_eq!(result.addresses.as_slice(), &[ip("fd00::10")]);
+    assert!(!result.local_dns);
+}
+
+#[tokio::test]
+async fn wildcard_record_answers_names_below_it() {
+    let f = Fixture::new(vec![record("*.dev", RecordType::A, "127.0.0.1")]).await;
+    let use_case = handler(&f, Arc::new(MockDnsResolver::new()));
+
+    let request = DnsRequest::new("app.dev.home.lan", RecordType::A, CLIENT);
+    let result = use_case.execute(&request).await.unwrap();
+
+    assert_eq!(result.addresses.as_slice(), &[ip("127.0.0.1")]);
+    assert!(result.local_dns);
+}
+
+#[tokio::test]
+async fn ip_template_answers_embedded_address() {
+    let f = Fixture::new(vec![record("*.nip", RecordType::A, "{ip}")]).await;
+    let use_case = handler(&f, Arc::new(MockDnsResolver::new()));
+
+    let request = DnsRequest::new("web.10-1-2-3.nip.home.lan", RecordType::A, CLIENT);
+    let result = use_case.execute(&request).await.unwrap();
+
+    assert_eq!(result.addresses.as_slice(), &[ip("10.1.2.3")]);
+    assert!(result.local_dns);
+}
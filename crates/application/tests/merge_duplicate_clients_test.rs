@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::DeviceRepository;
+use ferrous_dns_application::use_cases::MergeDuplicateClientsUseCase;
+use ferrous_dns_domain::{Device, DeviceIpHistory, DomainError, DuplicateClients};
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
+
+struct RecordingDeviceRepository {
+    duplicates: Vec<DuplicateClients>,
+    merges: Mutex<Vec<(i64, Vec<i64>)>>,
+}
+
+#[async_trait]
+impl DeviceRepository for RecordingDeviceRepository {
+    async fn record_sighting(&self, _: &str, _: IpAddr) -> Result<(), DomainError> {
+        Ok(())
+    }
+
+    async fn get_all(&self) -> Result<Vec<Device>, DomainError> {
+        Ok(Vec::new())
+    }
+
+    async fn get_by_mac(&self, _: &str) -> Result<Option<Device>, DomainError> {
+        Ok(None)
+    }
+
+    async fn get_ip_history(&self, _: i64) -> Result<Vec<DeviceIpHistory>, DomainError> {
+        Ok(Vec::new())
+    }
+
+    async fn find_duplicate_clients(&self) -> Result<Vec<DuplicateClients>, DomainError> {
+        Ok(self.duplicates.clone())
+    }
+
+    async fn merge_clients(&self, keep_id: i64, duplicate_ids: &[i64]) -> Result<u64, DomainError> {
+        self.merges
+            .lock()
+            .unwrap()
+            .push((keep_id, duplicate_ids.to_vec()));
+        Ok(duplicate_ids.len() as u64)
+    }
+}
+
+#[tokio::test]
+async fn test_merge_keeps_first_client_of_each_group() {
+    let repo = Arc::new(RecordingDeviceRepository {
+        duplicates: vec![
+            DuplicateClients {
+                mac_address: Arc::from("aa:bb:cc:dd:ee:ff"),
+                client_ids: vec![7, 3, 1],
+            },
+            DuplicateClients {
+                mac_address: Arc::from("11:22:33:44:55:66"),
+                client_ids: vec![9],
+            },
+        ],
+        merges: Mutex::new(Vec::new()),
+    });
+
+    let merged = MergeDuplicateClientsUseCase::new(repo.clone())
+        .execute()
+        .await
+        .unwrap();
+
+    assert_eq!(merged, 2);
+    assert_eq!(*repo.merges.lock().unwrap(), vec![(7, vec![3, 1])]);
+}
@@ -0,0 +1,67 @@
+mod helpers;
+
+use ferrous_dns_application::ports::DnsResolution;
+use ferrous_dns_application::use_cases::HandleDnsQueryUseCase;
+use ferrous_dns_domain::{DnsRequest, DomainError, RecordType};
+use helpers::{MockBlockFilterEngine, MockDnsResolver, MockQueryLogRepository};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+const CLIENT_IP: IpAddr = IpAddr::V4(std::net::Ipv4Addr::new(192, 168, 1, 100));
+
+async fn setup(minimal: bool) -> (HandleDnsQueryUseCase, Arc<MockQueryLogRepository>) {
+    let resolver = Arc::new(MockDnsResolver::new());
+    resolver
+        .set_response(
+            "example.com",
+            DnsResolution::new(vec!["93.184.216.34".parse().unwrap()], false),
+        )
+        .await;
+    let log = Arc::new(MockQueryLogRepository::new());
+    let use_case = HandleDnsQueryUseCase::new(
+        resolver,
+        Arc::new(MockBlockFilterEngine::new()),
+        log.clone(),
+    )
+    .with_minimal_any_responses(minimal);
+    (use_case, log)
+}
+
+#[tokio::test]
+async fn any_query_is_minimized_without_resolving() {
+    let (use_case, log) = setup(true).await;
+
+    let request = DnsRequest::new("example.com", RecordType::ANY, CLIENT_IP);
+    let result = use_case.execute(&request).await;
+
+    assert!(matches!(result, Err(DomainError::AnyQueryMinimized)));
+    let logs = log.get_sync_logs();
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].response_status, Some("ANY_MINIMIZED"));
+    assert_eq!(logs[0].record_type, RecordType::ANY);
+    assert!(!logs[0].blocked);
+}
+
+#[tokio::test]
+async fn other_types_are_unaffected() {
+    let (use_case, _log) = setup(true).await;
+
+    let request = DnsRequest::new("example.com", RecordType::A, CLIENT_IP);
+    let result = use_case.execute(&request).await.unwrap();
+
+    assert_eq!(result.addresses[0].to_string(), "93.184.216.34");
+}
+
+#[tokio::test]
+async fn any_query_is_forwarded_when_disabled() {
+    let (use_case, log) = setup(false).await;
+
+    let request = DnsRequest::new("example.com", RecordType::ANY, CLIENT_IP);
+    let result = use_case.execute(&request).await.unwrap();
+
+    assert!(!result.addresses.is_empty());
+    assert_ne!(
+        log.get_sync_logs()[0].response_status,
+        Some("ANY_MINIMIZED")
+    );
+}
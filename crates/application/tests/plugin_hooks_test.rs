@@ -0,0 +1,151 @@
+mod helpers;
+
+use ferrous_dns_application::ports::{DnsResolution, PluginVerdict};
+use ferrous_dns_application::use_cases::HandleDnsQueryUseCase;
+use ferrous_dns_domain::{BlockSource, DnsRequest, DomainError, RecordType};
+use helpers::{MockBlockFilterEngine, MockDnsResolver, MockPluginHooks, MockQueryLogRepository};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+const CLIENT_IP: IpAddr = IpAddr::V4(std::net::Ipv4Addr::new(192, 168, 1, 100));
+
+async fn make_use_case(
+    plugins: Arc<MockPluginHooks>,
+) -> (HandleDnsQueryUseCase, Arc<MockQueryLogRepository>) {
+    let resolver = MockDnsResolver::new();
+    resolver
+        .set_response(
+            "example.com",
+            DnsResolution::new(vec!["1.2.3.4".parse().unwrap()], false),
+        )
+        .await;
+    resolver.set_cached_response(
+        "example.com",
+        DnsResolution::new(vec!["1.2.3.4".parse().unwrap()], true),
+    );
+    let log = Arc::new(MockQueryLogRepository::new());
+    let use_case = HandleDnsQueryUseCase::new(
+        Arc::new(resolver),
+        Arc::new(MockBlockFilterEngine::new()),
+        log.clone(),
+    )
+    .with_plugins(plugins);
+    (use_case, log)
+}
+
+#[tokio::test]
+async fn pre_filter_block_is_refused_and_logged() {
+    let plugins = Arc::new(MockPluginHooks::new());
+    plugins.on_pre_filter("example.com", PluginVerdict::Block);
+    let (use_case, log) = make_use_case(plugins.clone()).await;
+
+    let request = DnsRequest::new("example.com", RecordType::A, CLIENT_IP);
+    let result = use_case.execute(&request).await;
+
+    assert!(matches!(
+        result,
+        Err(DomainError::Blocked(BlockSource::Plugin))
+    ));
+    let logs = log.get_sync_logs();
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].block_source, Some(BlockSource::Plugin));
+    assert_eq!(logs[0].plugin.as_deref(), Some(MockPluginHooks::PLUGIN));
+    assert!(plugins.seen_answers().is_empty());
+}
+
+#[tokio::test]
+async fn pre_filter_answer_skips_resolution() {
+    let plugins = Arc::new(MockPluginHooks::new());
+    plugins.on_pre_filter(
+        "example.com",
+        PluginVerdict::Answer(vec!["10.0.0.1".parse().unwrap(), "::1".parse().unwrap()]),
+    );
+    let (use_case, log) = make_use_case(plugins).await;
+
+    let request = DnsRequest::new("example.com", RecordType::A, CLIENT_IP);
+    let resolution = use_case.execute(&request).await.unwrap();
+
+    assert_eq!(
+        resolution.addresses.as_slice(),
+        &["10.0.0.1".parse::<IpAddr>().unwrap()]
+    );
+    assert_eq!(log.get_sync_logs()[0].response_status, Some("REWRITTEN"));
+}
+
+#[tokio::test]
+async fn post_resolve_sees_answer_and_can_rewrite_it() {
+    let plugins = Arc::new(MockPluginHooks::new());
+    plugins.on_post_resolve(
+        "example.com",
+        PluginVerdict::Answer(vec!["10.0.0.2".parse().unwrap()]),
+    );
+    let (use_case, _log) = make_use_case(plugins.clone()).await;
+
+    let request = DnsRequest::new("example.com", RecordType::A, CLIENT_IP);
+    let resolution = use_case.execute(&request).await.unwrap();
+
+    assert_eq!(
+        plugins.seen_answers(),
+        vec![vec!["1.2.3.4".parse::<IpAddr>().unwrap()]]
+    );
+    assert_eq!(
+        resolution.addresses.as_slice(),
+        &["10.0.0.2".parse::<IpAddr>().unwrap()]
+    );
+}
+
+#[tokio::test]
+async fn post_resolve_nxdomain_overrides_answer() {
+    let plugins = Arc::new(MockPluginHooks::new());
+    plugins.on_post_resolve("example.com", PluginVerdict::NxDomain);
+    let (use_case, log) = make_use_case(plugins).await;
+
+    let request = DnsRequest::new("example.com", RecordType::A, CLIENT_IP);
+    assert!(matches!(
+        use_case.execute(&request).await,
+        Err(DomainError::NxDomain)
+    ));
+    let logs = log.get_sync_logs();
+    assert_eq!(logs[0].response_status, Some("NXDOMAIN"));
+    assert_eq!(logs[0].plugin.as_deref(), Some(MockPluginHooks::PLUGIN));
+}
+
+#[tokio::test]
+async fn continue_verdicts_resolve_normally() {
+    let plugins = Arc::new(MockPluginHooks::new());
+    plugins.watch("example.com");
+    let (use_case, _log) = make_use_case(plugins.clone()).await;
+
+    let request = DnsRequest::new("example.com", RecordType::A, CLIENT_IP);
+    let resolution = use_case.execute(&request).await.unwrap();
+
+    assert_eq!(
+        resolution.addresses.as_slice(),
+        &["1.2.3.4".parse::<IpAddr>().unwrap()]
+    );
+    assert_eq!(plugins.seen_answers().len(), 1);
+}
+
+#[tokio::test]
+async fn cache_fast_path_is_skipped_for_intercepted_domains() {
+    let plugins = Arc::new(MockPluginHooks::new());
+    plugins.watch("example.com");
+    let (use_case, log) = make_use_case(plugins).await;
+
+    assert!(use_case
+        .try_cache_direct("example.com", RecordType::A, CLIENT_IP, None)
+        .is_none());
+    assert_eq!(log.sync_log_count(), 0);
+}
+
+#[tokio::test]
+async fn cache_fast_path_serves_domains_no_hook_covers() {
+    let plugins = Arc::new(MockPluginHooks::new());
+    plugins.watch("other.com");
+    let (use_case, _log) = make_use_case(plugins.clone()).await;
+
+    assert!(use_case
+        .try_cache_direct("example.com", RecordType::A, CLIENT_IP, None)
+        .is_some());
+    assert!(plugins.seen_answers().is_empty());
+}
@@ -0,0 +1,123 @@
+mod helpers;
+
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{DnsResolution, DnsResolver};
+use ferrous_dns_application::use_cases::dns::query_budget::{self, QueryBudget};
+use ferrous_dns_application::use_cases::HandleDnsQueryUseCase;
+use ferrous_dns_domain::{DnsQuery, DnsRequest, DomainError, QueryBudgetConfig, RecordType};
+use helpers::{MockBlockFilterEngine, MockQueryLogRepository};
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+const CLIENT_IP: IpAddr = IpAddr::V4(std::net::Ipv4Addr::new(192, 168, 1, 100));
+
+fn budget(total_ms: u64, dnssec_ms: u64, min_attempt_ms: u64, dnssec: bool) -> QueryBudget {
+    QueryBudget::from_config(
+        &QueryBudgetConfig {
+            total_ms,
+            dnssec_ms,
+            min_attempt_ms,
+        },
+        dnssec,
+    )
+    .unwrap()
+}
+
+/// Upstream that answers after `delay`, bounded by the query budget like
+/// the DNSSEC layer is.
+struct SlowResolver {
+    delay: Duration,
+}
+
+#[async_trait]
+impl DnsResolver for SlowResolver {
+    async fn resolve(&self, _query: &DnsQuery) -> Result<DnsResolution, DomainError> {
+        query_budget::within(async {
+            tokio::time::sleep(self.delay).await;
+            Ok(DnsResolution::new(
+                vec!["93.184.216.34".parse().unwrap()],
+                false,
+            ))
+        })
+        .await
+    }
+
+    async fn resolve_via_pool(
+        &self,
+        query: &DnsQuery,
+        _pool: &str,
+    ) -> Result<DnsResolution, DomainError> {
+        self.resolve(query).await
+    }
+}
+
+fn make_use_case(delay: Duration, budget: Option<QueryBudget>) -> HandleDnsQueryUseCase {
+    HandleDnsQueryUseCase::new(
+        Arc::new(SlowResolver { delay }),
+        Arc::new(MockBlockFilterEngine::new()),
+        Arc::new(MockQueryLogRepository::new()),
+    )
+    .with_query_budget(budget)
+}
+
+#[test]
+fn test_disabled_budget_builds_nothing() {
+    assert!(QueryBudget::from_config(&QueryBudgetConfig::default(), true).is_none());
+}
+
+#[tokio::test]
+async fn test_attempt_timeout_is_untouched_outside_a_scope() {
+    assert_eq!(query_budget::attempt_timeout_ms(3000).unwrap(), 3000);
+}
+
+#[tokio::test]
+async fn test_attempt_timeout_leaves_room_for_dnssec() {
+    let timeout = query_budget::scope(budget(800, 300, 50, true), async {
+        query_budget::attempt_timeout_ms(3000)
+    })
+    .await
+    .unwrap();
+    assert!(timeout <= 500, "got {timeout}");
+    assert!(timeout > 400, "got {timeout}");
+}
+
+#[tokio::test]
+async fn test_dnssec_share_is_not_held_back_without_dnssec() {
+    let timeout = query_budget::scope(budget(800, 300, 50, false), async {
+        query_budget::attempt_timeout_ms(3000)
+    })
+    .await
+    .unwrap();
+    assert!(timeout > 700, "got {timeout}");
+}
+
+#[tokio::test]
+async fn test_no_attempt_is_started_with_too_little_left() {
+    let result = query_budget::scope(budget(100, 0, 50, false), async {
+        tokio::time::sleep(Duration::from_millis(70)).await;
+        query_budget::attempt_timeout_ms(3000)
+    })
+    .await;
+    assert!(matches!(result, Err(DomainError::QueryBudgetExceeded)));
+}
+
+#[tokio::test]
+async fn test_query_over_budget_fails_fast() {
+    let use_case = make_use_case(Duration::from_secs(5), Some(budget(100, 0, 20, false)));
+    let request = DnsRequest::new("slow.example.com", RecordType::A, CLIENT_IP);
+
+    let start = Instant::now();
+    let result = use_case.execute(&request).await;
+
+    assert!(matches!(result, Err(DomainError::QueryBudgetExceeded)));
+    assert!(start.elapsed() < Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn test_query_within_budget_answers() {
+    let use_case = make_use_case(Duration::from_millis(10), Some(budget(500, 0, 20, false)));
+    let request = DnsRequest::new("fast.example.com", RecordType::A, CLIENT_IP);
+
+    assert!(use_case.execute(&request).await.is_ok());
+}
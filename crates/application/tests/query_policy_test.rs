@@ -0,0 +1,213 @@
+mod helpers;
+
+use ferrous_dns_application::ports::DnsResolution;
+use ferrous_dns_application::use_cases::HandleDnsQueryUseCase;
+use ferrous_dns_domain::{BlockSource, DnsRequest, DomainError, PolicyAction, RecordType};
+use helpers::{
+    MockBlockFilterEngine, MockDnsResolver, MockQueryLogRepository, MockQueryPolicyEngine,
+};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+const CLIENT_IP: IpAddr = IpAddr::V4(std::net::Ipv4Addr::new(192, 168, 1, 100));
+
+struct Fixture {
+    resolver: Arc<MockDnsResolver>,
+    filter: Arc<MockBlockFilterEngine>,
+    policies: Arc<MockQueryPolicyEngine>,
+    log: Arc<MockQueryLogRepository>,
+}
+
+impl Fixture {
+    fn new() -> Self {
+        Self {
+            resolver: Arc::new(MockDnsResolver::new()),
+            filter: Arc::new(MockBlockFilterEngine::new()),
+            policies: Arc::new(MockQueryPolicyEngine::new()),
+            log: Arc::new(MockQueryLogRepository::new()),
+        }
+    }
+
+    fn use_case(&self) -> HandleDnsQueryUseCase {
+        HandleDnsQueryUseCase::new(self.resolver.clone(), self.filter.clone(), self.log.clone())
+            .with_query_policy(self.policies.clone())
+    }
+}
+
+fn resolution(ip: &str) -> DnsResolution {
+    DnsResolution::new(vec![ip.parse().unwrap()], false)
+}
+
+#[tokio::test]
+async fn block_policy_blocks_and_logs_source() {
+    let f = Fixture::new();
+    f.resolver
+        .set_response("ads.com", resolution("1.2.3.4"))
+        .await;
+    f.policies.set_policy("ads.com", PolicyAction::Block, None);
+
+    let request = DnsRequest::new("ads.com", RecordType::A, CLIENT_IP);
+    let result = f.use_case().execute(&request).await;
+
+    assert!(matches!(
+        result,
+        Err(DomainError::Blocked(BlockSource::QueryPolicy))
+    ));
+    let logs = f.log.get_sync_logs();
+    assert_eq!(logs.len(), 1);
+    assert!(logs[0].blocked);
+    assert_eq!(logs[0].block_source, Some(BlockSource::QueryPolicy));
+}
+
+#[tokio::test]
+async fn allow_policy_overrides_blocklist() {
+    let f = Fixture::new();
+    f.resolver
+        .set_response("tracker.com", resolution("1.2.3.4"))
+        .await;
+    f.filter.block_domain("tracker.com");
+    f.policies
+        .set_policy("tracker.com", PolicyAction::Allow, None);
+
+    let request = DnsRequest::new("tracker.com", RecordType::A, CLIENT_IP);
+    let result = f.use_case().execute(&request).await.unwrap();
+
+    assert_eq!(result.addresses[0].to_string(), "1.2.3.4");
+}
+
+#[tokio::test]
+async fn rewrite_policy_resolves_target() {
+    let f = Fixture::new();
+    f.resolver
+        .set_response("search.com", resolution("1.1.1.1"))
+        .await;
+    f.resolver
+        .set_response("safe.search.com", resolution("2.2.2.2"))
+        .await;
+    f.policies
+        .set_policy("search.com", PolicyAction::Rewrite, Some("safe.search.com"));
+
+    let request = DnsRequest::new("search.com", RecordType::A, CLIENT_IP);
+    let result = f.use_case().execute(&request).await.unwrap();
+
+    assert_eq!(result.addresses[0].to_string(), "2.2.2.2");
+    let logs = f.log.get_sync_logs();
+    assert_eq!(logs[0].response_status, Some("POLICY_REWRITE"));
+}
+
+#[tokio::test]
+async fn rewrite_policy_target_is_still_subject_to_blocking() {
+    let f = Fixture::new();
+    f.resolver
+        .set_response("ads.example.net", resolution("2.2.2.2"))
+        .await;
+    f.filter.block_domain("ads.example.net");
+    f.policies
+        .set_policy("search.com", PolicyAction::Rewrite, Some("ads.example.net"));
+
+    let request = DnsRequest::new("search.com", RecordType::A, CLIENT_IP);
+    let result = f.use_case().execute(&request).await;
+
+    assert!(matches!(
+        result,
+        Err(DomainError::Blocked(BlockSource::Blocklist))
+    ));
+    let logs = f.log.get_sync_logs();
+    assert!(logs[0].blocked);
+}
+
+#[tokio::test]
+async fn rewrite_policy_blocks_cname_chain_of_target() {
+    let f = Fixture::new();
+    let cloaked = DnsResolution {
+        cname_chain: vec![Arc::from("tracker.cdn.net")].into(),
+        ..resolution("2.2.2.2")
+    };
+    f.resolver.set_response("safe.search.com", cloaked).await;
+    f.filter.block_domain("tracker.cdn.net");
+    f.policies
+        .set_policy("search.com", PolicyAction::Rewrite, Some("safe.search.com"));
+
+    let request = DnsRequest::new("search.com", RecordType::A, CLIENT_IP);
+    let result = f.use_case().execute(&request).await;
+
+    assert!(matches!(
+        result,
+        Err(DomainError::Blocked(BlockSource::CnameCloaking))
+    ));
+}
+
+#[tokio::test]
+async fn forward_to_pool_policy_uses_named_pool_and_skips_cache() {
+    let f = Fixture::new();
+    f.resolver
+        .set_cached_response("corp.example", resolution("9.9.9.9"));
+    f.resolver
+        .set_response("corp.example", resolution("10.0.0.5"))
+        .await;
+    f.policies.set_policy(
+        "corp.example",
+        PolicyAction::ForwardToPool,
+        Some("internal"),
+    );
+
+    let request = DnsRequest::new("corp.example", RecordType::A, CLIENT_IP);
+    let result = f.use_case().execute(&request).await.unwrap();
+
+    assert_eq!(result.addresses[0].to_string(), "10.0.0.5");
+    assert_eq!(result.upstream_pool.as_deref(), Some("internal"));
+}
+
+#[tokio::test]
+async fn forward_to_pool_policy_is_still_subject_to_blocking() {
+    let f = Fixture::new();
+    f.resolver
+        .set_response("bad.example", resolution("10.0.0.5"))
+        .await;
+    f.filter.block_domain("bad.example");
+    f.policies
+        .set_policy("bad.example", PolicyAction::ForwardToPool, Some("internal"));
+
+    let request = DnsRequest::new("bad.example", RecordType::A, CLIENT_IP);
+    let result = f.use_case().execute(&request).await;
+
+    assert!(matches!(
+        result,
+        Err(DomainError::Blocked(BlockSource::Blocklist))
+    ));
+}
+
+#[tokio::test]
+async fn log_only_policy_resolves_normally() {
+    let f = Fixture::new();
+    f.resolver
+        .set_response("watch.com", resolution("3.3.3.3"))
+        .await;
+    f.policies
+        .set_policy("watch.com", PolicyAction::LogOnly, None);
+
+    let request = DnsRequest::new("watch.com", RecordType::A, CLIENT_IP);
+    let result = f.use_case().execute(&request).await.unwrap();
+
+    assert_eq!(result.addresses[0].to_string(), "3.3.3.3");
+}
+
+#[tokio::test]
+async fn cache_fast_path_defers_to_execute_when_policy_matches() {
+    let f = Fixture::new();
+    f.resolver.set_cached_response(
+        "ads.com",
+        DnsResolution::new(vec!["1.2.3.4".parse().unwrap()], true),
+    );
+    f.policies.set_policy("ads.com", PolicyAction::Block, None);
+
+    let use_case = f.use_case();
+
+    assert!(use_case
+        .try_cache_direct("ads.com", RecordType::A, CLIENT_IP, None)
+        .is_none());
+    assert!(use_case
+        .try_cache_wire_direct("ads.com", RecordType::A, CLIENT_IP, None)
+        .is_none());
+    assert_eq!(f.log.sync_log_count(), 0);
+}
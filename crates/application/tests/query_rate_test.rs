@@ -35,6 +35,7 @@ async fn test_get_query_rate_with_data() {
             record_type: RecordType::A,
             client_ip: IpAddr::from_str("192.168.1.1").unwrap(),
             client_hostname: None,
+            client_mac: None,
             blocked: false,
             response_time_us: Some(10),
             cache_hit: false,
@@ -42,11 +43,16 @@ async fn test_get_query_rate_with_data() {
             dnssec_status: None,
             upstream_server: Some(Arc::from("8.8.8.8")),
             upstream_pool: None,
+            upstream_strategy: None,
+            upstream_attempt: None,
+            upstream_protocol: None,
             response_status: None,
             timestamp: None,
             query_source: QuerySource::Client,
             group_id: None,
+            group_name: None,
             block_source: None,
+            plugin: None,
         };
         let _ = repository_mock.log_query(&query).await;
     }
@@ -74,6 +80,7 @@ async fn test_get_query_rate_formatted_with_k() {
             record_type: RecordType::A,
             client_ip: IpAddr::from_str("192.168.1.1").unwrap(),
             client_hostname: None,
+            client_mac: None,
             blocked: false,
             response_time_us: Some(10),
             cache_hit: false,
@@ -81,11 +88,16 @@ async fn test_get_query_rate_formatted_with_k() {
             dnssec_status: None,
             upstream_server: Some(Arc::from("8.8.8.8")),
             upstream_pool: None,
+            upstream_strategy: None,
+            upstream_attempt: None,
+            upstream_protocol: None,
             response_status: None,
             timestamp: None,
             query_source: QuerySource::Client,
             group_id: None,
+            group_name: None,
             block_source: None,
+            plugin: None,
         };
         let _ = repository_mock.log_query(&query).await;
     }
@@ -113,6 +125,7 @@ async fn test_get_query_rate_different_units() {
             record_type: RecordType::A,
             client_ip: IpAddr::from_str("192.168.1.1").unwrap(),
             client_hostname: None,
+            client_mac: None,
             blocked: i % 10 == 0,
             response_time_us: Some(10),
             cache_hit: i % 3 == 0,
@@ -120,11 +133,16 @@ async fn test_get_query_rate_different_units() {
             dnssec_status: None,
             upstream_server: Some(Arc::from("8.8.8.8")),
             upstream_pool: None,
+            upstream_strategy: None,
+            upstream_attempt: None,
+            upstream_protocol: None,
             response_status: None,
             timestamp: None,
             query_source: QuerySource::Client,
             group_id: None,
+            group_name: None,
             block_source: None,
+            plugin: None,
         };
         let _ = repository_mock.log_query(&query).await;
     }
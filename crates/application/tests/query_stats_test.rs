@@ -26,6 +26,7 @@ fn make_log_with_upstream(
         record_type: RecordType::A,
         client_ip: IpAddr::from([192, 168, 1, 1]),
         client_hostname: None,
+        client_mac: None,
         blocked,
         response_time_us: Some(100),
         cache_hit,
@@ -33,11 +34,16 @@ fn make_log_with_upstream(
         dnssec_status: None,
         upstream_server: upstream_server.map(Arc::from),
         upstream_pool: upstream_pool.map(Arc::from),
+        upstream_strategy: None,
+        upstream_attempt: None,
+        upstream_protocol: None,
         response_status: Some("NOERROR"),
         timestamp: None,
         query_source: QuerySource::Client,
         group_id: None,
+        group_name: None,
         block_source,
+        plugin: None,
     }
 }
 
@@ -192,3 +198,62 @@ async fn test_get_stats_multiple_upstream_servers() {
     assert_eq!(stats.source_stats.get("unknown:unknown"), Some(&1));
     assert_eq!(stats.source_stats.get("upstream"), None);
 }
+
+#[tokio::test]
+async fn test_get_stats_breaks_down_by_group_and_client() {
+    let repo = Arc::new(MockQueryLogRepository::new());
+    let client_mock = Arc::new(MockClientRepository::new());
+
+    for (client, group, blocked, cache_hit) in [
+        ([10, 0, 0, 1], Some(1), true, false),
+        ([10, 0, 0, 1], Some(1), false, true),
+        ([10, 0, 0, 1], Some(1), false, false),
+        ([10, 0, 0, 2], Some(2), true, false),
+        ([10, 0, 0, 3], None, false, false),
+    ] {
+        let mut log = make_log(cache_hit, blocked, None);
+        log.client_ip = IpAddr::from(client);
+        log.group_id = group;
+        repo.log_query(&log).await.unwrap();
+    }
+
+    let use_case = GetQueryStatsUseCase::new(
+        repo.clone() as Arc<dyn ferrous_dns_application::ports::QueryLogRepository>,
+        client_mock.clone() as Arc<dyn ferrous_dns_application::ports::ClientRepository>,
+    );
+    let stats = use_case.execute(24.0).await.unwrap();
+
+    assert_eq!(stats.blocked_percentage(), 40.0);
+
+    assert_eq!(stats.queries_by_group.len(), 2);
+    let protected = &stats.queries_by_group[0];
+    assert_eq!(protected.key, "1");
+    assert_eq!(protected.queries_total, 3);
+    assert_eq!(protected.queries_blocked, 1);
+    assert_eq!(protected.cache_hits, 1);
+
+    assert_eq!(stats.top_clients.len(), 3);
+    assert_eq!(stats.top_clients[0].key, "10.0.0.1");
+    assert_eq!(stats.top_clients[1].blocked_percentage(), 100.0);
+}
+
+#[tokio::test]
+async fn test_get_stats_limits_top_clients_to_ten() {
+    let repo = Arc::new(MockQueryLogRepository::new());
+    let client_mock = Arc::new(MockClientRepository::new());
+
+    for i in 1..=12u8 {
+        let mut log = make_log(false, false, None);
+        log.client_ip = IpAddr::from([10, 0, 0, i]);
+        repo.log_query(&log).await.unwrap();
+    }
+
+    let use_case = GetQueryStatsUseCase::new(
+        repo.clone() as Arc<dyn ferrous_dns_application::ports::QueryLogRepository>,
+        client_mock.clone() as Arc<dyn ferrous_dns_application::ports::ClientRepository>,
+    );
+    let stats = use_case.execute(24.0).await.unwrap();
+
+    assert_eq!(stats.top_clients.len(), 10);
+    assert!(stats.queries_by_group.is_empty());
+}
@@ -0,0 +1,194 @@
+mod helpers;
+
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{
+    LocalZoneAnswer, LocalZonePort, PtrRecordRegistry, RecordSource,
+};
+use ferrous_dns_application::use_cases::{RecordSourceSync, SyncRecordSourceUseCase};
+use ferrous_dns_domain::{Config, DomainError, LocalRecord, RecordType};
+use helpers::{MockLocalRecordRepository, MockLocalZone};
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
+use tokio::sync::RwLock;
+
+/// Returns whatever records were last set; `fail()` makes fetches error.
+#[derive(Default)]
+struct StaticSource {
+    records: Mutex<Vec<LocalRecord>>,
+    failing: Mutex<bool>,
+}
+
+impl StaticSource {
+    fn set(&self, records: Vec<LocalRecord>) {
+        *self.records.lock().unwrap() = records;
+    }
+
+    fn fail(&self) {
+        *self.failing.lock().unwrap() = true;
+    }
+}
+
+#[async_trait]
+impl RecordSource for StaticSource {
+    fn name(&self) -> &'static str {
+        "static"
+    }
+
+    fn sync_interval_secs(&self) -> u64 {
+        30
+    }
+
+    async fn fetch(&self) -> Result<Vec<LocalRecord>, DomainError> {
+        if *self.failing.lock().unwrap() {
+            return Err(DomainError::RecordSourceUnavailable(
+                "connection refused".to_string(),
+            ));
+        }
+        Ok(self.records.lock().unwrap().clone())
+    }
+}
+
+#[derive(Default)]
+struct MockPtrRegistry {
+    registered: Mutex<Vec<IpAddr>>,
+    unregistered: Mutex<Vec<IpAddr>>,
+}
+
+impl PtrRecordRegistry for MockPtrRegistry {
+    fn register(&self, ip: IpAddr, _fqdn: Arc<str>, _ttl: u32) {
+        self.registered.lock().unwrap().push(ip);
+    }
+
+    fn unregister(&self, ip: IpAddr) {
+        self.unregistered.lock().unwrap().push(ip);
+    }
+}
+
+fn a_record(hostname: &str, ip: &str) -> LocalRecord {
+    LocalRecord {
+        domain: Some(Arc::from("k8s.lan")),
+        ttl: 60,
+        ..LocalRecord::new(Arc::from(hostname), RecordType::A, Arc::from(ip))
+    }
+}
+
+struct Fixture {
+    source: Arc<StaticSource>,
+    zone: Arc<MockLocalZone>,
+    ptr: Arc<MockPtrRegistry>,
+    sync: SyncRecordSourceUseCase,
+}
+
+fn fixture() -> Fixture {
+    let source = Arc::new(StaticSource::default());
+    let zone = Arc::new(MockLocalZone::new(
+        Arc::new(MockLocalRecordRepository::new()),
+        None,
+    ));
+    let ptr = Arc::new(MockPtrRegistry::default());
+    let sync = SyncRecordSourceUseCase::new(
+        Arc::new(RwLock::new(Config::default())),
+        source.clone(),
+        zone.clone(),
+    )
+    .with_ptr_registry(Some(ptr.clone() as Arc<dyn PtrRecordRegistry>));
+    Fixture {
+        source,
+        zone,
+        ptr,
+        sync,
+    }
+}
+
+fn resolves_to(zone: &MockLocalZone, name: &str) -> Vec<IpAddr> {
+    match zone.lookup(name, RecordType::A) {
+        Some(LocalZoneAnswer::Records(resolution)) => resolution.addresses.to_vec(),
+        _ => Vec::new(),
+    }
+}
+
+#[tokio::test]
+async fn test_sync_publishes_source_records() {
+    let f = fixture();
+    f.source.set(vec![a_record("web.apps", "192.168.1.50")]);
+
+    let sync = f.sync.execute().await.unwrap();
+
+    assert_eq!(
+        sync,
+        RecordSourceSync {
+            published: 1,
+            added: 1,
+            removed: 0
+        }
+    );
+    assert_eq!(
+        resolves_to(&f.zone, "web.apps.k8s.lan"),
+        vec!["192.168.1.50".parse::<IpAddr>().unwrap()]
+    );
+    assert_eq!(f.ptr.registered.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_unchanged_source_does_not_reload_zone() {
+    let f = fixture();
+    f.source.set(vec![a_record("web.apps", "192.168.1.50")]);
+    f.sync.execute().await.unwrap();
+    let reloads = f.zone.reload_count();
+
+    let sync = f.sync.execute().await.unwrap();
+
+    assert_eq!(sync.added + sync.removed, 0);
+    assert_eq!(f.zone.reload_count(), reloads);
+}
+
+#[tokio::test]
+async fn test_removed_records_stop_resolving() {
+    let f = fixture();
+    f.source.set(vec![
+        a_record("web.apps", "192.168.1.50"),
+        a_record("db.apps", "192.168.1.51"),
+    ]);
+    f.sync.execute().await.unwrap();
+
+    f.source.set(vec![a_record("web.apps", "192.168.1.50")]);
+    let sync = f.sync.execute().await.unwrap();
+
+    assert_eq!(sync.removed, 1);
+    assert!(resolves_to(&f.zone, "db.apps.k8s.lan").is_empty());
+    assert_eq!(
+        *f.ptr.unregistered.lock().unwrap(),
+        vec!["192.168.1.51".parse::<IpAddr>().unwrap()]
+    );
+}
+
+#[tokio::test]
+async fn test_failed_fetch_keeps_published_records() {
+    let f = fixture();
+    f.source.set(vec![a_record("web.apps", "192.168.1.50")]);
+    f.sync.execute().await.unwrap();
+
+    f.source.fail();
+    let result = f.sync.execute().await;
+
+    assert!(matches!(
+        result,
+        Err(DomainError::RecordSourceUnavailable(_))
+    ));
+    assert_eq!(resolves_to(&f.zone, "web.apps.k8s.lan").len(), 1);
+}
+
+#[tokio::test]
+async fn test_invalid_and_duplicate_records_are_skipped() {
+    let f = fixture();
+    f.source.set(vec![
+        a_record("web.apps", "192.168.1.50"),
+        a_record("web.apps", "192.168.1.50"),
+        a_record("bad name", "192.168.1.52"),
+        a_record("db.apps", "not-an-ip"),
+    ]);
+
+    let sync = f.sync.execute().await.unwrap();
+
+    assert_eq!(sync.published, 1);
+}
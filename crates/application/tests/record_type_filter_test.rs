@@ -0,0 +1,106 @@
+mod helpers;
+
+use ferrous_dns_application::ports::DnsResolution;
+use ferrous_dns_application::use_cases::HandleDnsQueryUseCase;
+use ferrous_dns_domain::{BlockSource, DnsRequest, DomainError, RecordType};
+use helpers::{
+    MockBlockFilterEngine, MockDnsResolver, MockQueryLogRepository, MockRecordTypeFilter,
+};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+const CLIENT_IP: IpAddr = IpAddr::V4(std::net::Ipv4Addr::new(192, 168, 1, 100));
+
+async fn make_use_case(
+    filter: Arc<MockRecordTypeFilter>,
+) -> (HandleDnsQueryUseCase, Arc<MockQueryLogRepository>) {
+    let resolver = MockDnsResolver::new();
+    resolver
+        .set_response(
+            "example.com",
+            DnsResolution::new(vec!["1.2.3.4".parse().unwrap()], false),
+        )
+        .await;
+    resolver.set_cached_response(
+        "example.com",
+        DnsResolution::new(vec!["1.2.3.4".parse().unwrap()], true),
+    );
+    let log = Arc::new(MockQueryLogRepository::new());
+    let use_case = HandleDnsQueryUseCase::new(
+        Arc::new(resolver),
+        Arc::new(MockBlockFilterEngine::new()),
+        log.clone(),
+    )
+    .with_record_type_filter(filter);
+    (use_case, log)
+}
+
+#[tokio::test]
+async fn blocked_type_is_refused_and_logged() {
+    let filter = Arc::new(MockRecordTypeFilter::new());
+    filter.block(1, RecordType::HTTPS);
+    let (use_case, log) = make_use_case(filter).await;
+
+    let request = DnsRequest::new("example.com", RecordType::HTTPS, CLIENT_IP);
+    let result = use_case.execute(&request).await;
+
+    assert!(matches!(result, Err(DomainError::FilteredQuery(_))));
+    let logs = log.get_sync_logs();
+    assert_eq!(logs.len(), 1);
+    assert!(logs[0].blocked);
+    assert_eq!(logs[0].response_status, Some("TYPE_BLOCKED"));
+    assert_eq!(logs[0].block_source, Some(BlockSource::RecordTypeFilter));
+}
+
+#[tokio::test]
+async fn other_types_resolve_normally() {
+    let filter = Arc::new(MockRecordTypeFilter::new());
+    filter.block(1, RecordType::HTTPS);
+    let (use_case, _log) = make_use_case(filter).await;
+
+    let request = DnsRequest::new("example.com", RecordType::A, CLIENT_IP);
+    assert!(use_case.execute(&request).await.is_ok());
+}
+
+#[tokio::test]
+async fn cache_fast_path_skips_blocked_type() {
+    let filter = Arc::new(MockRecordTypeFilter::new());
+    filter.block(1, RecordType::A);
+    let (use_case, log) = make_use_case(filter).await;
+
+    assert!(use_case
+        .try_cache_direct("example.com", RecordType::A, CLIENT_IP, None)
+        .is_none());
+    assert_eq!(log.sync_log_count(), 0);
+}
+
+#[tokio::test]
+async fn listener_default_group_selects_policy() {
+    let filter = Arc::new(MockRecordTypeFilter::new());
+    filter.block(7, RecordType::HTTPS);
+    let (use_case, _log) = make_use_case(filter).await;
+
+    let plain = DnsRequest::new("example.com", RecordType::HTTPS, CLIENT_IP);
+    assert!(use_case.execute(&plain).await.is_ok());
+
+    let via_listener =
+        DnsRequest::new("example.com", RecordType::HTTPS, CLIENT_IP).with_listener_group(Some(7));
+    assert!(matches!(
+        use_case.execute(&via_listener).await,
+        Err(DomainError::FilteredQuery(_))
+    ));
+}
+
+#[tokio::test]
+async fn cache_fast_path_uses_listener_default_group() {
+    let filter = Arc::new(MockRecordTypeFilter::new());
+    filter.block(7, RecordType::A);
+    let (use_case, _log) = make_use_case(filter).await;
+
+    assert!(use_case
+        .try_cache_direct("example.com", RecordType::A, CLIENT_IP, None)
+        .is_some());
+    assert!(use_case
+        .try_cache_direct("example.com", RecordType::A, CLIENT_IP, Some(7))
+        .is_none());
+}
@@ -60,7 +60,10 @@ async fn blocked_c2_ip_returns_error() {
     let request = DnsRequest::new("malware.com", RecordType::A, CLIENT_IP);
     let result = use_case.execute(&request).await;
 
-    assert!(matches!(result, Err(DomainError::Blocked)));
+    assert!(matches!(
+        result,
+        Err(DomainError::Blocked(BlockSource::ResponseIpFilter))
+    ));
     let logs = log.get_sync_logs();
     assert_eq!(logs.len(), 1);
     assert!(logs[0].blocked);
@@ -138,7 +141,10 @@ async fn cache_hit_with_c2_ip_falls_through() {
     let request = DnsRequest::new("cached.com", RecordType::A, CLIENT_IP);
     let result = use_case.execute(&request).await;
 
-    assert!(matches!(result, Err(DomainError::Blocked)));
+    assert!(matches!(
+        result,
+        Err(DomainError::Blocked(BlockSource::ResponseIpFilter))
+    ));
     let logs = log.get_sync_logs();
     assert!(logs[0].blocked);
     assert_eq!(logs[0].block_source, Some(BlockSource::ResponseIpFilter));
@@ -183,7 +189,10 @@ async fn blocks_when_one_of_multiple_ips_is_c2() {
     let request = DnsRequest::new("multi.com", RecordType::A, CLIENT_IP);
     let result = use_case.execute(&request).await;
 
-    assert!(matches!(result, Err(DomainError::Blocked)));
+    assert!(matches!(
+        result,
+        Err(DomainError::Blocked(BlockSource::ResponseIpFilter))
+    ));
     let logs = log.get_sync_logs();
     assert!(logs[0].blocked);
     assert_eq!(logs[0].block_source, Some(BlockSource::ResponseIpFilter));
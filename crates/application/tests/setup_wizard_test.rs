@@ -0,0 +1,312 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{
+    BlocklistSourceRepository, ConfigFilePersistence, PasswordHasher, UserProvider,
+};
+use ferrous_dns_application::use_cases::blocklist_sources::CreateBlocklistSourceUseCase;
+use ferrous_dns_application::use_cases::{
+    ListenerSettings, SetupPasswordUseCase, SetupRequest, SetupWizardUseCase,
+};
+use ferrous_dns_domain::{Config, DomainError, UpstreamPreset, User};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::RwLock;
+
+mod helpers;
+use helpers::{MockBlocklistSourceRepository, MockGroupRepository};
+
+/// Stores the admin hash in the shared config, like the TOML admin does.
+struct ConfigAdminProvider {
+    config: Arc<RwLock<Config>>,
+}
+
+#[async_trait]
+impl UserProvider for ConfigAdminProvider {
+    async fn get_by_username(&self, _username: &str) -> Result<Option<User>, DomainError> {
+        Ok(None)
+    }
+    async fn get_all(&self) -> Result<Vec<User>, DomainError> {
+        Ok(vec![])
+    }
+    async fn update_password(
+        &self,
+        _username: &str,
+        password_hash: &str,
+    ) -> Result<(), DomainError> {
+        self.config.write().await.auth.admin.password_hash = Some(password_hash.to_string());
+        Ok(())
+    }
+}
+
+struct PlainHasher;
+
+impl PasswordHasher for PlainHasher {
+    fn hash(&self, password: &str) -> Result<String, DomainError> {
+        Ok(format!("hashed:{password}"))
+    }
+    fn verify(&self, password: &str, hash: &str) -> Result<bool, DomainError> {
+        Ok(hash == format!("hashed:{password}"))
+    }
+}
+
+#[derive(Default)]
+struct RecordingPersistence {
+    saved: Mutex<Vec<Config>>,
+    fail: AtomicBool,
+}
+
+impl ConfigFilePersistence for RecordingPersistence {
+    fn save_config_to_file(&self, config: &Config, _path: &str) -> Result<(), String> {
+        if self.fail.load(Ordering::Relaxed) {
+            return Err("read-only file system".to_string());
+        }
+        self.saved.lock().unwrap().push(config.clone());
+        Ok(())
+    }
+}
+
+struct Fixture {
+    config: Arc<RwLock<Config>>,
+    persistence: Arc<RecordingPersistence>,
+    sources: Arc<MockBlocklistSourceRepository>,
+    wizard: SetupWizardUseCase,
+}
+
+fn fixture() -> Fixture {
+    let config = Arc::new(RwLock::new(Config::default()));
+    let persistence = Arc::new(RecordingPersistence::default());
+    let sources = Arc::new(MockBlocklistSourceRepository::new());
+    let setup_password = Arc::new(SetupPasswordUseCase::new(
+        Arc::new(ConfigAdminProvider {
+            config: config.clone(),
+        }),
+        Arc::new(PlainHasher),
+        "admin".to_string(),
+    ));
+    let wizard = SetupWizardUseCase::new(
+        config.clone(),
+        persistence.clone(),
+        Some("ferrous-dns.toml".to_string()),
+        setup_password,
+        Arc::new(CreateBlocklistSourceUseCase::new(
+            sources.clone(),
+            Arc::new(MockGroupRepository::new()),
+        )),
+    );
+    Fixture {
+        config,
+        persistence,
+        sources,
+        wizard,
+    }
+}
+
+fn request() -> SetupRequest {
+    SetupRequest {
+        password: "correct-horse".to_string(),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_options_available_before_password_is_set() {
+    let f = fixture();
+
+    let options = f.wizard.options().await.unwrap();
+
+    assert_eq!(
+        options.listeners.dns_port,
+        Config::default().server.dns_port
+    );
+    assert!(options.interfaces.is_empty());
+}
+
+#[tokio::test]
+async fn test_complete_sets_password_and_locks_wizard() {
+    let f = fixture();
+
+    f.wizard.complete(request()).await.unwrap();
+
+    assert_eq!(
+        f.config.read().await.auth.admin.password_hash.as_deref(),
+        Some("hashed:correct-horse")
+    );
+    assert!(matches!(
+        f.wizard.options().await,
+        Err(DomainError::PasswordAlreadyConfigured)
+    ));
+    assert!(matches!(
+        f.wizard.complete(request()).await,
+        Err(DomainError::PasswordAlreadyConfigured)
+    ));
+}
+
+#[tokio::test]
+async fn test_complete_applies_preset_and_listeners() {
+    let f = fixture();
+
+    let summary = f
+        .wizard
+        .complete(SetupRequest {
+            upstream_preset: Some(UpstreamPreset::Quad9),
+            listeners: Some(ListenerSettings {
+                bind_address: "192.168.1.2".to_string(),
+                dns_port: 5353,
+                web_port: 8081,
+            }),
+            ..request()
+        })
+        .await
+        .unwrap();
+
+    assert!(summary.restart_required);
+    let saved = f.persistence.saved.lock().unwrap().last().cloned().unwrap();
+    assert_eq!(
+        saved.auth.admin.password_hash.as_deref(),
+        Some("hashed:correct-horse")
+    );
+    assert_eq!(saved.dns.upstream_preset, Some(UpstreamPreset::Quad9));
+    assert!(saved.dns.pools.is_empty());
+    assert_eq!(saved.server.bind_address, "192.168.1.2");
+    assert_eq!(saved.server.dns_port, 5353);
+
+    let live = f.config.read().await;
+    assert_eq!(live.dns.pools[0].name, "quad9");
+    assert_eq!(live.server.web_port, 8081);
+}
+
+#[tokio::test]
+async fn test_unsaved_settings_reported_after_password_is_set() {
+    let f = fixture();
+    f.persistence.fail.store(true, Ordering::Relaxed);
+
+    let summary = f
+        .wizard
+        .complete(SetupRequest {
+            upstream_preset: Some(UpstreamPreset::Quad9),
+            ..request()
+        })
+        .await
+        .unwrap();
+
+    assert!(!summary.restart_required);
+    assert_eq!(summary.warnings.len(), 1);
+    let live = f.config.read().await;
+    assert_eq!(
+        live.auth.admin.password_hash.as_deref(),
+        Some("hashed:correct-horse")
+    );
+    assert_eq!(live.dns.upstream_preset, None);
+}
+
+#[tokio::test]
+async fn test_complete_adds_selected_blocklists_to_default_group() {
+    let f = fixture();
+
+    let summary = f
+        .wizard
+        .complete(SetupRequest {
+            blocklists: vec!["stevenblack".to_string(), "hagezi-tif".to_string()],
+            ..request()
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(summary.blocklists_added, 2);
+    assert!(!summary.restart_required);
+    let sources = f.sources.get_all().await.unwrap();
+    assert_eq!(sources.len(), 2);
+    assert!(sources.iter().all(|s| s.group_ids == vec![1] && s.enabled));
+}
+
+#[tokio::test]
+async fn test_existing_blocklist_is_skipped() {
+    let f = fixture();
+    f.sources
+        .create(
+            "Steven Black Unified".to_string(),
+            Some("https://example.com/hosts".to_string()),
+            vec![1],
+            None,
+            true,
+        )
+        .await
+        .unwrap();
+
+    let summary = f
+        .wizard
+        .complete(SetupRequest {
+            blocklists: vec!["stevenblack".to_string()],
+            ..request()
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(summary.blocklists_added, 0);
+    assert_eq!(summary.blocklists_skipped, 1);
+    assert!(summary.warnings.is_empty());
+}
+
+#[tokio::test]
+async fn test_unknown_blocklist_rejected_without_changes() {
+    let f = fixture();
+
+    let result = f
+        .wizard
+        .complete(SetupRequest {
+            blocklists: vec!["no-such-list".to_string()],
+            ..request()
+        })
+        .await;
+
+    assert!(matches!(result, Err(DomainError::InvalidInput(_))));
+    assert!(f.config.read().await.auth.admin.password_hash.is_none());
+    assert!(f.persistence.saved.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_short_password_keeps_wizard_open() {
+    let f = fixture();
+
+    let result = f
+        .wizard
+        .complete(SetupRequest {
+            password: "short".to_string(),
+            ..Default::default()
+        })
+        .await;
+
+    assert!(matches!(result, Err(DomainError::InvalidPassword(_))));
+    assert!(f.wizard.options().await.is_ok());
+}
+
+#[tokio::test]
+async fn test_invalid_listeners_rejected() {
+    let f = fixture();
+
+    for listeners in [
+        ListenerSettings {
+            bind_address: "not-an-ip".to_string(),
+            dns_port: 53,
+            web_port: 8080,
+        },
+        ListenerSettings {
+            bind_address: "0.0.0.0".to_string(),
+            dns_port: 8080,
+            web_port: 8080,
+        },
+        ListenerSettings {
+            bind_address: "0.0.0.0".to_string(),
+            dns_port: 0,
+            web_port: 8080,
+        },
+    ] {
+        let result = f
+            .wizard
+            .complete(SetupRequest {
+                listeners: Some(listeners),
+                ..request()
+            })
+            .await;
+        assert!(matches!(result, Err(DomainError::InvalidInput(_))));
+    }
+}
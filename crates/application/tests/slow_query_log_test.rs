@@ -0,0 +1,127 @@
+mod helpers;
+
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{
+    DnsResolution, DnsResolver, SlowQueryEntry, SlowQueryLogPort,
+};
+use ferrous_dns_application::use_cases::dns::phase_timings::{self, QueryPhase};
+use ferrous_dns_application::use_cases::HandleDnsQueryUseCase;
+use ferrous_dns_domain::{DnsQuery, DnsRequest, DomainError, RecordType};
+use helpers::{MockBlockFilterEngine, MockQueryLogRepository};
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+const CLIENT_IP: IpAddr = IpAddr::V4(std::net::Ipv4Addr::new(192, 168, 1, 100));
+
+#[derive(Default)]
+struct RecordingSlowQueryLog {
+    entries: Mutex<Vec<SlowQueryEntry>>,
+}
+
+impl SlowQueryLogPort for RecordingSlowQueryLog {
+    fn record(&self, entry: SlowQueryEntry) {
+        self.entries.lock().unwrap().push(entry);
+    }
+
+    fn recent(&self, limit: usize) -> Vec<SlowQueryEntry> {
+        self.entries
+            .lock()
+            .unwrap()
+            .iter()
+            .rev()
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+}
+
+/// Answers after `delay`, reporting the wait as upstream time like `CoreResolver`.
+struct DelayedResolver {
+    delay: Duration,
+}
+
+#[async_trait]
+impl DnsResolver for DelayedResolver {
+    async fn resolve(&self, _query: &DnsQuery) -> Result<DnsResolution, DomainError> {
+        phase_timings::measure(QueryPhase::Upstream, tokio::time::sleep(self.delay)).await;
+        Ok(DnsResolution::new(
+            vec!["93.184.216.34".parse().unwrap()],
+            false,
+        ))
+    }
+
+    async fn resolve_via_pool(
+        &self,
+        query: &DnsQuery,
+        _pool: &str,
+    ) -> Result<DnsResolution, DomainError> {
+        self.resolve(query).await
+    }
+}
+
+fn make_use_case(
+    delay: Duration,
+    threshold: Duration,
+) -> (HandleDnsQueryUseCase, Arc<RecordingSlowQueryLog>) {
+    let slow_log = Arc::new(RecordingSlowQueryLog::default());
+    let use_case = HandleDnsQueryUseCase::new(
+        Arc::new(DelayedResolver { delay }),
+        Arc::new(MockBlockFilterEngine::new()),
+        Arc::new(MockQueryLogRepository::new()),
+    )
+    .with_slow_query_log(slow_log.clone(), threshold);
+    (use_case, slow_log)
+}
+
+#[tokio::test]
+async fn test_query_over_threshold_is_captured_with_upstream_phase() {
+    let (use_case, slow_log) = make_use_case(Duration::from_millis(30), Duration::from_millis(10));
+    let request = DnsRequest::new("slow.example.com", RecordType::A, CLIENT_IP);
+
+    use_case.execute(&request).await.unwrap();
+
+    let entries = slow_log.recent(10);
+    assert_eq!(entries.len(), 1);
+    let entry = &entries[0];
+    assert_eq!(entry.domain.as_ref(), "slow.example.com");
+    assert_eq!(entry.record_type, RecordType::A);
+    assert_eq!(entry.response_status, Some("NOERROR"));
+    assert!(entry.phases.upstream_us >= 25_000);
+    assert!(entry.total_us >= entry.phases.upstream_us);
+    assert_eq!(entry.phases.dnssec_us, 0);
+}
+
+#[tokio::test]
+async fn test_query_under_threshold_is_not_captured() {
+    let (use_case, slow_log) = make_use_case(Duration::ZERO, Duration::from_secs(5));
+    let request = DnsRequest::new("fast.example.com", RecordType::A, CLIENT_IP);
+
+    use_case.execute(&request).await.unwrap();
+
+    assert!(slow_log.recent(10).is_empty());
+}
+
+#[tokio::test]
+async fn test_phase_timings_accumulate_within_scope() {
+    let timings = phase_timings::collect(async {
+        phase_timings::record(QueryPhase::Cache, 5);
+        phase_timings::record(QueryPhase::Cache, 7);
+        phase_timings::record(QueryPhase::Dnssec, 11);
+        phase_timings::current()
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(timings.cache_us, 12);
+    assert_eq!(timings.dnssec_us, 11);
+    assert_eq!(timings.upstream_us, 0);
+    assert_eq!(timings.block_check_us, 0);
+}
+
+#[tokio::test]
+async fn test_phase_timings_outside_scope_are_ignored() {
+    phase_timings::record(QueryPhase::Upstream, 100);
+
+    assert!(phase_timings::current().is_none());
+}
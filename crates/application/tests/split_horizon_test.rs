@@ -0,0 +1,234 @@
+mod helpers;
+
+use ferrous_dns_application::ports::{
+    DnsResolution, LocalRecordRepository, LocalZonePort, SplitHorizonPort,
+};
+use ferrous_dns_application::use_cases::{
+    CreateLocalRecordUseCase, DeleteLocalRecordUseCase, HandleDnsQueryUseCase,
+};
+use ferrous_dns_domain::{
+    Config, DnsRequest, DnsViewConfig, DomainError, LocalDnsRecord, LocalRecord, RecordType,
+};
+use helpers::{
+    MockBlockFilterEngine, MockDnsResolver, MockLocalRecordRepository, MockLocalZone,
+    MockQueryLogRepository, MockSplitHorizon,
+};
+use std::net::IpAddr;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+const LAN_CLIENT: IpAddr = IpAddr::V4(std::net::Ipv4Addr::new(192, 168, 1, 100));
+const VPN_CLIENT: IpAddr = IpAddr::V4(std::net::Ipv4Addr::new(10, 8, 0, 5));
+
+fn record(hostname: &str, ip: &str, record_type: &str, view: Option<&str>) -> LocalDnsRecord {
+    LocalDnsRecord {
+        hostname: hostname.to_string(),
+        domain: Some("home.lan".to_string()),
+        ip: ip.to_string(),
+        record_type: record_type.to_string(),
+        ttl: Some(120),
+        view: view.map(String::from),
+    }
+}
+
+fn vpn_config() -> Config {
+    let mut config = Config::default();
+    config.dns.views.push(DnsViewConfig {
+        name: "vpn".to_string(),
+        clients: vec!["10.8.0.0/24".to_string()],
+        groups: vec![],
+    });
+    config
+        .dns
+        .local_records
+        .push(record("nas", "192.168.1.10", "A", None));
+    config
+        .dns
+        .local_records
+        .push(record("nas", "10.8.0.10", "A", Some("vpn")));
+    config
+}
+
+struct Fixture {
+    resolver: Arc<MockDnsResolver>,
+    views: Arc<MockSplitHorizon>,
+    log: Arc<MockQueryLogRepository>,
+}
+
+impl Fixture {
+    fn new(config: &Config) -> Self {
+        let views = Arc::new(MockSplitHorizon::new());
+        views.reload(&config.dns);
+        Self {
+            resolver: Arc::new(MockDnsResolver::new()),
+            views,
+            log: Arc::new(MockQueryLogRepository::new()),
+        }
+    }
+
+    fn use_case(&self) -> HandleDnsQueryUseCase {
+        HandleDnsQueryUseCase::new(
+            self.resolver.clone(),
+            Arc::new(MockBlockFilterEngine::new()),
+            self.log.clone(),
+        )
+        .with_split_horizon(self.views.clone())
+    }
+}
+
+fn ip(s: &str) -> IpAddr {
+    s.parse().unwrap()
+}
+
+#[tokio::test]
+async fn view_client_gets_view_address() {
+    let f = Fixture::new(&vpn_config());
+    f.resolver
+        .set_response(
+            "nas.home.lan",
+            DnsResolution::new(vec![ip("192.168.1.10")], true),
+        )
+        .await;
+
+    let request = DnsRequest::new("nas.home.lan", RecordType::A, VPN_CLIENT);
+    let result = f.use_case().execute(&request).await.unwrap();
+
+    assert_eq!(result.addresses.as_slice(), &[ip("10.8.0.10")]);
+    assert!(result.local_dns);
+    assert_eq!(result.min_ttl, Some(120));
+    let logs = f.log.get_sync_logs();
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].response_status, Some("LOCAL_DNS"));
+}
+
+#[tokio::test]
+async fn client_outside_view_takes_normal_path() {
+    let f = Fixture::new(&vpn_config());
+    f.resolver
+        .set_response(
+            "nas.home.lan",
+            DnsResolution::new(vec![ip("192.168.1.10")], true),
+        )
+        .await;
+
+    let request = DnsRequest::new("nas.home.lan", RecordType::A, LAN_CLIENT);
+    let result = f.use_case().execute(&request).await.unwrap();
+
+    assert_eq!(result.addresses.as_slice(), &[ip("192.168.1.10")]);
+}
+
+#[tokio::test]
+async fn view_without_queried_family_answers_nodata() {
+    let f = Fixture::new(&vpn_config());
+    f.resolver
+        .set_response(
+            "nas.home.lan",
+            DnsResolution::new(vec![ip("fd00::10")], true),
+        )
+        .await;
+
+    let request = DnsRequest::new("nas.home.lan", RecordType::AAAA, VPN_CLIENT);
+    let result = f.use_case().execute(&request).await.unwrap();
+
+    assert!(result.addresses.is_empty());
+    assert!(result.local_dns);
+}
+
+#[tokio::test]
+async fn view_client_bypasses_cache_fast_path() {
+    let f = Fixture::new(&vpn_config());
+    f.resolver.set_cached_response(
+        "nas.home.lan",
+        DnsResolution::new(vec![ip("192.168.1.10")], true),
+    );
+    let use_case = f.use_case();
+
+    assert!(use_case
+        .try_cache_direct("nas.home.lan", RecordType::A, VPN_CLIENT, None)
+        .is_none());
+    assert!(use_case
+        .try_cache_direct("nas.home.lan", RecordType::A, LAN_CLIENT, None)
+        .is_some());
+}
+
+#[tokio::test]
+async fn view_matches_client_group() {
+    let mut config = vpn_config();
+    config.dns.views[0].clients.clear();
+    config.dns.views[0].groups.push(1);
+    let f = Fixture::new(&config);
+
+    let request = DnsRequest::new("nas.home.lan", RecordType::A, LAN_CLIENT);
+    let result = f.use_case().execute(&request).await.unwrap();
+
+    assert_eq!(result.addresses.as_slice(), &[ip("10.8.0.10")]);
+}
+
+fn view_record(ip: &str) -> LocalRecord {
+    LocalRecord {
+        domain: Some(Arc::from("home.lan")),
+        view: Some(Arc::from("vpn")),
+        ..LocalRecord::new(Arc::from("nas"), RecordType::A, Arc::from(ip))
+    }
+}
+
+#[tokio::test]
+async fn creating_view_record_reloads_views() {
+    let mut config = vpn_config();
+    config.dns.local_records.clear();
+    let config = Arc::new(RwLock::new(config));
+    let views = Arc::new(MockSplitHorizon::new());
+    let repo = Arc::new(MockLocalRecordRepository::new());
+    let zone = Arc::new(MockLocalZone::new(repo.clone(), None));
+    let use_case = CreateLocalRecordUseCase::new(config.clone(), repo, zone)
+        .with_split_horizon(Some(views.clone() as Arc<dyn SplitHorizonPort>));
+
+    let created = use_case.execute(view_record("10.8.0.10")).await.unwrap();
+
+    assert_eq!(created.view.as_deref(), Some("vpn"));
+    assert_eq!(views.reload_count(), 1);
+    let answer = views
+        .lookup(VPN_CLIENT, 1, "nas.home.lan", RecordType::A)
+        .unwrap();
+    assert_eq!(answer.addresses, vec![ip("10.8.0.10")]);
+}
+
+#[tokio::test]
+async fn creating_record_in_unknown_view_fails() {
+    let config = Arc::new(RwLock::new(Config::default()));
+    let repo = Arc::new(MockLocalRecordRepository::new());
+    let zone = Arc::new(MockLocalZone::new(repo.clone(), None));
+    let use_case = CreateLocalRecordUseCase::new(config, repo.clone(), zone);
+
+    let result = use_case.execute(view_record("10.8.0.10")).await;
+
+    assert!(matches!(result, Err(DomainError::InvalidInput(_))));
+    assert!(repo.get_all().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn deleting_view_record_reloads_views() {
+    let mut config = vpn_config();
+    config.dns.local_records.retain(|r| r.view.is_none());
+    let config = Arc::new(RwLock::new(config));
+    let repo =
+        Arc::new(MockLocalRecordRepository::with_records(vec![view_record("10.8.0.10")]).await);
+    let zone = Arc::new(MockLocalZone::new(repo.clone(), None));
+    zone.reload().await.unwrap();
+    let views = Arc::new(MockSplitHorizon::new());
+    let mut dns = config.read().await.dns.clone();
+    dns.local_records.extend(zone.view_records());
+    views.reload(&dns);
+    assert!(views
+        .lookup(VPN_CLIENT, 1, "nas.home.lan", RecordType::A)
+        .is_some());
+    let use_case = DeleteLocalRecordUseCase::new(config.clone(), repo, zone)
+        .with_split_horizon(Some(views.clone() as Arc<dyn SplitHorizonPort>));
+
+    use_case.execute(1).await.unwrap();
+
+    assert_eq!(views.reload_count(), 2);
+    assert!(views
+        .lookup(VPN_CLIENT, 1, "nas.home.lan", RecordType::A)
+        .is_none());
+}
@@ -0,0 +1,271 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{
+    BackupArchiver, BackupStore, BackupTables, CacheExportData, CacheExportEntry,
+    ConfigFilePersistence, PrimaryInstanceClient,
+};
+use ferrous_dns_application::use_cases::{
+    FullBackup, RestoreBackupUseCase, SyncFromPrimaryUseCase,
+};
+use ferrous_dns_domain::{Config, DomainError, StandbyConfig};
+use std::collections::BTreeMap;
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
+use tokio::sync::RwLock;
+
+mod helpers;
+use helpers::{MockBlockFilterEngine, MockDnsCache};
+
+struct FakePrimary {
+    config: Mutex<Config>,
+    exports: Mutex<u32>,
+    lists: Mutex<Option<Vec<u8>>>,
+    down: Mutex<bool>,
+}
+
+impl FakePrimary {
+    fn new() -> Arc<Self> {
+        Arc::new(Self {
+            config: Mutex::new(Config::default()),
+            exports: Mutex::new(0),
+            lists: Mutex::new(Some(b"!ferrous-dns list snapshot v1\n".to_vec())),
+            down: Mutex::new(false),
+        })
+    }
+
+    fn check_up(&self) -> Result<(), DomainError> {
+        if *self.down.lock().unwrap() {
+            return Err(DomainError::StandbySyncFailed(
+                "connection refused".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl PrimaryInstanceClient for FakePrimary {
+    async fn fetch_backup(&self) -> Result<Vec<u8>, DomainError> {
+        self.check_up()?;
+        let mut exports = self.exports.lock().unwrap();
+        *exports += 1;
+        let backup = FullBackup {
+            version: "1".to_string(),
+            ferrous_version: "test".to_string(),
+            exported_at: format!("2026-10-16T10:00:{:02}+00:00", *exports),
+            config: self.config.lock().unwrap().clone(),
+            tables: BackupTables::new(),
+        };
+        Ok(serde_json::to_vec(&backup).unwrap())
+    }
+
+    async fn fetch_block_lists(&self) -> Result<Option<Vec<u8>>, DomainError> {
+        self.check_up()?;
+        Ok(self.lists.lock().unwrap().clone())
+    }
+
+    async fn fetch_cache(&self, limit: usize) -> Result<Vec<CacheExportEntry>, DomainError> {
+        self.check_up()?;
+        let entry = CacheExportEntry {
+            domain: "example.com".to_string(),
+            record_type: "A".to_string(),
+            data: CacheExportData::Addresses(vec!["93.184.216.34".parse::<IpAddr>().unwrap()]),
+            remaining_ttl: 120,
+            dnssec_status: None,
+        };
+        Ok(vec![entry; limit.min(2)])
+    }
+}
+
+struct EmptyStore;
+
+#[async_trait]
+impl BackupStore for EmptyStore {
+    async fn dump(&self) -> Result<BackupTables, DomainError> {
+        Ok(BackupTables::new())
+    }
+
+    async fn restore(&self, tables: &BackupTables) -> Result<BTreeMap<String, usize>, DomainError> {
+        Ok(tables.iter().map(|(k, v)| (k.clone(), v.len())).collect())
+    }
+}
+
+struct NoArchiver;
+
+impl BackupArchiver for NoArchiver {
+    fn pack(&self, _files: &[(String, Vec<u8>)]) -> Result<Vec<u8>, DomainError> {
+        unreachable!("standby syncs use JSON backups")
+    }
+
+    fn unpack(&self, _bytes: &[u8]) -> Result<Vec<(String, Vec<u8>)>, DomainError> {
+        unreachable!("standby syncs use JSON backups")
+    }
+}
+
+#[derive(Default)]
+struct RecordingPersistence {
+    saved: Mutex<Vec<Config>>,
+}
+
+impl ConfigFilePersistence for RecordingPersistence {
+    fn save_config_to_file(&self, config: &Config, _path: &str) -> Result<(), String> {
+        self.saved.lock().unwrap().push(config.clone());
+        Ok(())
+    }
+}
+
+struct Standby {
+    use_case: SyncFromPrimaryUseCase,
+    config: Arc<RwLock<Config>>,
+    persistence: Arc<RecordingPersistence>,
+    engine: MockBlockFilterEngine,
+    cache: Arc<MockDnsCache>,
+}
+
+fn standby(primary: Arc<FakePrimary>) -> Standby {
+    let standby_config = StandbyConfig {
+        enabled: true,
+        primary_url: "http://192.168.1.2:8080".to_string(),
+        cache_entries: 100,
+        max_failed_syncs: 2,
+        ..Default::default()
+    };
+    let mut config = Config::default();
+    config.standby = standby_config.clone();
+    let config = Arc::new(RwLock::new(config));
+    let persistence = Arc::new(RecordingPersistence::default());
+    let restore = RestoreBackupUseCase::new(
+        config.clone(),
+        persistence.clone(),
+        Some("/etc/ferrous-dns/config.toml".to_string()),
+        Arc::new(EmptyStore),
+        Arc::new(NoArchiver),
+    );
+    let engine = MockBlockFilterEngine::new();
+    let cache = Arc::new(MockDnsCache::new());
+    let use_case = SyncFromPrimaryUseCase::new(
+        primary,
+        Arc::new(restore),
+        Arc::new(engine.clone()),
+        &standby_config,
+    )
+    .with_cache(cache.clone());
+    Standby {
+        use_case,
+        config,
+        persistence,
+        engine,
+        cache,
+    }
+}
+
+#[tokio::test]
+async fn test_not_ready_before_first_sync() {
+    let standby = standby(FakePrimary::new());
+
+    let status = standby.use_case.status();
+    assert!(!status.ready);
+    assert!(status.last_attempt_at.is_none());
+}
+
+#[tokio::test]
+async fn test_first_sync_copies_config_lists_and_cache() {
+    let primary = FakePrimary::new();
+    primary.config.lock().unwrap().blocking.enabled = false;
+    let standby = standby(primary);
+
+    standby.use_case.execute().await.unwrap();
+
+    let config = standby.config.read().await;
+    assert!(!config.blocking.enabled);
+    assert!(config.standby.enabled, "the standby role must survive");
+    assert_eq!(standby.engine.imported_snapshots().len(), 1);
+    assert_eq!(standby.cache.imported().len(), 2);
+
+    let status = standby.use_case.status();
+    assert!(status.ready);
+    assert_eq!(status.config_updates, 1);
+    assert_eq!(status.block_list_updates, 1);
+    assert_eq!(status.cache_entries, 2);
+}
+
+#[tokio::test]
+async fn test_unchanged_primary_is_not_restored_again() {
+    let primary = FakePrimary::new();
+    let standby = standby(primary);
+
+    standby.use_case.execute().await.unwrap();
+    standby.use_case.execute().await.unwrap();
+
+    assert_eq!(standby.persistence.saved.lock().unwrap().len(), 1);
+    assert_eq!(standby.engine.imported_snapshots().len(), 1);
+    assert_eq!(standby.cache.imported().len(), 4);
+    assert_eq!(standby.use_case.status().config_updates, 1);
+}
+
+#[tokio::test]
+async fn test_changed_lists_are_imported_without_restore() {
+    let primary = FakePrimary::new();
+    let standby = standby(primary.clone());
+
+    standby.use_case.execute().await.unwrap();
+    *primary.lists.lock().unwrap() =
+        Some(b"!ferrous-dns list snapshot v1\n!source a\nads.example\n".to_vec());
+    standby.use_case.execute().await.unwrap();
+
+    assert_eq!(standby.persistence.saved.lock().unwrap().len(), 1);
+    assert_eq!(standby.engine.imported_snapshots().len(), 2);
+}
+
+#[tokio::test]
+async fn test_changed_config_is_restored() {
+    let primary = FakePrimary::new();
+    let standby = standby(primary.clone());
+
+    standby.use_case.execute().await.unwrap();
+    primary.config.lock().unwrap().dns.cache_max_entries = 1234;
+    standby.use_case.execute().await.unwrap();
+
+    assert_eq!(standby.persistence.saved.lock().unwrap().len(), 2);
+    assert_eq!(standby.config.read().await.dns.cache_max_entries, 1234);
+    assert_eq!(standby.use_case.status().config_updates, 2);
+}
+
+#[tokio::test]
+async fn test_primary_without_snapshot_reloads_sources() {
+    let primary = FakePrimary::new();
+    *primary.lists.lock().unwrap() = None;
+    let standby = standby(primary);
+
+    standby.use_case.execute().await.unwrap();
+    standby.use_case.execute().await.unwrap();
+
+    assert_eq!(standby.engine.reload_count().await, 1);
+    assert!(standby.engine.imported_snapshots().is_empty());
+}
+
+#[tokio::test]
+async fn test_stays_ready_until_max_failed_syncs() {
+    let primary = FakePrimary::new();
+    let standby = standby(primary.clone());
+    standby.use_case.execute().await.unwrap();
+
+    *primary.down.lock().unwrap() = true;
+    assert!(matches!(
+        standby.use_case.execute().await,
+        Err(DomainError::StandbySyncFailed(_))
+    ));
+    let status = standby.use_case.status();
+    assert!(status.ready);
+    assert_eq!(status.consecutive_failures, 1);
+    assert!(status.last_error.is_some());
+
+    assert!(standby.use_case.execute().await.is_err());
+    assert!(!standby.use_case.status().ready);
+
+    *primary.down.lock().unwrap() = false;
+    standby.use_case.execute().await.unwrap();
+    let status = standby.use_case.status();
+    assert!(status.ready);
+    assert_eq!(status.consecutive_failures, 0);
+    assert!(status.last_error.is_none());
+}
@@ -1,10 +1,14 @@
 use async_trait::async_trait;
 use ferrous_dns_application::ports::ClientSubnetRepository;
 use ferrous_dns_application::services::SubnetMatcherService;
+use ferrous_dns_application::use_cases::TrackClientUseCase;
 use ferrous_dns_domain::{ClientSubnet, DomainError};
 use std::net::IpAddr;
 use std::sync::{Arc, Mutex};
 
+mod helpers;
+use helpers::MockClientRepository;
+
 struct MockClientSubnetRepository {
     subnets: Arc<Mutex<Vec<ClientSubnet>>>,
 }
@@ -183,3 +187,35 @@ async fn test_subnet_matcher_mixed_ipv4_ipv6() {
     assert_eq!(matcher.find_group_for_ip(ip_v4).await, Some(1));
     assert_eq!(matcher.find_group_for_ip(ip_v6).await, Some(2));
 }
+
+#[tokio::test]
+async fn test_track_client_assigns_matching_subnet_group() {
+    let subnet_repo = Arc::new(MockClientSubnetRepository::new());
+    subnet_repo.add_subnet("192.168.30.0/24", 4);
+    let matcher = Arc::new(SubnetMatcherService::new(subnet_repo));
+    matcher.refresh().await.unwrap();
+
+    let client_repo = Arc::new(MockClientRepository::new());
+    let use_case = TrackClientUseCase::new(client_repo.clone()).with_subnet_matcher(matcher);
+
+    use_case
+        .execute("192.168.30.12".parse().unwrap())
+        .await
+        .unwrap();
+    use_case
+        .execute("192.168.1.12".parse().unwrap())
+        .await
+        .unwrap();
+
+    let clients = client_repo.get_all_clients().await;
+    let group_of = |ip: &str| {
+        let ip: IpAddr = ip.parse().unwrap();
+        clients
+            .iter()
+            .find(|c| c.ip_address == ip)
+            .unwrap()
+            .group_id
+    };
+    assert_eq!(group_of("192.168.30.12"), Some(4));
+    assert_eq!(group_of("192.168.1.12"), Some(1));
+}
@@ -0,0 +1,61 @@
+use ferrous_dns_application::ports::TimeGranularity;
+use ferrous_dns_application::use_cases::queries::get_timeline::MAX_TIMELINE_BUCKETS;
+use ferrous_dns_application::use_cases::GetTimelineUseCase;
+
+#[test]
+fn test_auto_picks_finest_granularity_for_short_ranges() {
+    assert_eq!(
+        GetTimelineUseCase::resolve_granularity(1, None),
+        TimeGranularity::Minute
+    );
+    assert_eq!(
+        GetTimelineUseCase::resolve_granularity(24, None),
+        TimeGranularity::TenMinutes
+    );
+}
+
+#[test]
+fn test_auto_coarsens_for_long_ranges() {
+    assert_eq!(
+        GetTimelineUseCase::resolve_granularity(72, None),
+        TimeGranularity::QuarterHour
+    );
+    assert_eq!(
+        GetTimelineUseCase::resolve_granularity(168, None),
+        TimeGranularity::Hour
+    );
+    assert_eq!(
+        GetTimelineUseCase::resolve_granularity(720, None),
+        TimeGranularity::Day
+    );
+}
+
+#[test]
+fn test_explicit_granularity_is_kept_when_it_fits() {
+    assert_eq!(
+        GetTimelineUseCase::resolve_granularity(6, Some(TimeGranularity::Minute)),
+        TimeGranularity::Minute
+    );
+    assert_eq!(
+        GetTimelineUseCase::resolve_granularity(24, Some(TimeGranularity::Day)),
+        TimeGranularity::Day
+    );
+}
+
+#[test]
+fn test_explicit_granularity_is_coarsened_past_bucket_cap() {
+    let granularity = GetTimelineUseCase::resolve_granularity(720, Some(TimeGranularity::Minute));
+
+    assert_eq!(granularity, TimeGranularity::Hour);
+    assert!(granularity.bucket_count(720) <= MAX_TIMELINE_BUCKETS);
+}
+
+#[test]
+fn test_every_resolution_stays_under_cap() {
+    for period in [1, 6, 24, 48, 168, 720, 10_000] {
+        for requested in TimeGranularity::ALL.map(Some).into_iter().chain([None]) {
+            let granularity = GetTimelineUseCase::resolve_granularity(period, requested);
+            assert!(granularity.bucket_count(period.min(720)) <= MAX_TIMELINE_BUCKETS);
+        }
+    }
+}
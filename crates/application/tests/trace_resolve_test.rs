@@ -0,0 +1,256 @@
+mod helpers;
+
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{
+    record_upstream_attempt, trace_upstream_attempts, DnsResolution, DnsResolver, UpstreamAttempt,
+};
+use ferrous_dns_application::use_cases::{DiagnoseDomainUseCase, TraceResolveUseCase, TraceStage};
+use ferrous_dns_domain::{DnsQuery, DomainError, PolicyAction, RecordType};
+use helpers::{
+    MockBlockFilterEngine, MockDnsCache, MockDnsResolver, MockDnsRewriteEngine,
+    MockQueryPolicyEngine, MockUpstreamHealth,
+};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+const CLIENT_IP: IpAddr = IpAddr::V4(std::net::Ipv4Addr::new(192, 168, 1, 100));
+
+struct Fixture {
+    resolver: Arc<MockDnsResolver>,
+    filter: Arc<MockBlockFilterEngine>,
+    policies: Arc<MockQueryPolicyEngine>,
+    rewrites: Arc<MockDnsRewriteEngine>,
+}
+
+impl Fixture {
+    fn new() -> Self {
+        Self {
+            resolver: Arc::new(MockDnsResolver::new()),
+            filter: Arc::new(MockBlockFilterEngine::new()),
+            policies: Arc::new(MockQueryPolicyEngine::new()),
+            rewrites: Arc::new(MockDnsRewriteEngine::new()),
+        }
+    }
+
+    fn use_case_with(&self, resolver: Arc<dyn DnsResolver>) -> TraceResolveUseCase {
+        let diagnose = DiagnoseDomainUseCase::new(
+            self.filter.clone(),
+            Arc::new(MockDnsCache::new()),
+            Arc::new(MockUpstreamHealth),
+        )
+        .with_query_policy(self.policies.clone())
+        .with_dns_rewrites(self.rewrites.clone());
+        TraceResolveUseCase::new(Arc::new(diagnose), resolver)
+    }
+
+    fn use_case(&self) -> TraceResolveUseCase {
+        self.use_case_with(self.resolver.clone())
+    }
+}
+
+fn upstream_answer(ip: &str) -> DnsResolution {
+    DnsResolution {
+        upstream_server: Some(Arc::from("udp://9.9.9.9:53")),
+        upstream_pool: Some(Arc::from("primary")),
+        dnssec_status: Some("Secure"),
+        ..DnsResolution::new(vec![ip.parse().unwrap()], false)
+    }
+}
+
+fn step_result(
+    trace: &ferrous_dns_application::use_cases::ResolutionTrace,
+    stage: TraceStage,
+) -> &'static str {
+    trace
+        .steps
+        .iter()
+        .find(|s| s.stage == stage)
+        .map(|s| s.result)
+        .unwrap_or("missing")
+}
+
+/// Records two upstream exchanges, the first one failing, before answering.
+struct FailoverResolver;
+
+#[async_trait]
+impl DnsResolver for FailoverResolver {
+    async fn resolve(&self, _query: &DnsQuery) -> Result<DnsResolution, DomainError> {
+        for (attempt, error) in [(1, Some("timeout".to_string())), (2, None)] {
+            record_upstream_attempt(|| UpstreamAttempt {
+                server: Arc::from(format!("udp://10.0.0.{attempt}:53")),
+                pool_name: Arc::from("primary"),
+                strategy: "failover",
+                protocol: "UDP",
+                attempt,
+                latency_us: 1_000 * attempt as u64,
+                error,
+            });
+        }
+        Ok(upstream_answer("93.184.216.34"))
+    }
+}
+
+#[tokio::test]
+async fn test_forwarded_query_reports_every_stage_and_the_answer() {
+    let f = Fixture::new();
+    f.resolver
+        .set_response("example.com", upstream_answer("93.184.216.34"))
+        .await;
+
+    let trace = f
+        .use_case()
+        .execute("example.com", Some(CLIENT_IP), RecordType::A)
+        .await
+        .unwrap();
+
+    let stages: Vec<TraceStage> = trace.steps.iter().map(|s| s.stage).collect();
+    assert_eq!(
+        stages,
+        [
+            TraceStage::QueryPolicy,
+            TraceStage::Rewrite,
+            TraceStage::BlockFilter,
+            TraceStage::SafeSearch,
+            TraceStage::Cache,
+            TraceStage::Upstream,
+            TraceStage::Dnssec,
+            TraceStage::Answer,
+        ]
+    );
+    assert_eq!(step_result(&trace, TraceStage::Cache), "miss");
+    assert_eq!(step_result(&trace, TraceStage::Upstream), "answered");
+    assert_eq!(step_result(&trace, TraceStage::Dnssec), "Secure");
+    assert_eq!(step_result(&trace, TraceStage::Answer), "noerror");
+    assert_eq!(trace.resolved_name.as_deref(), Some("example.com"));
+    assert!(trace.error.is_none());
+}
+
+#[tokio::test]
+async fn test_blocked_query_stops_before_resolution() {
+    let f = Fixture::new();
+    f.filter.block_domain("ads.example.com");
+
+    let trace = f
+        .use_case()
+        .execute("ads.example.com", Some(CLIENT_IP), RecordType::A)
+        .await
+        .unwrap();
+
+    assert_eq!(step_result(&trace, TraceStage::BlockFilter), "blocked");
+    assert_eq!(step_result(&trace, TraceStage::Answer), "blocked");
+    assert_eq!(step_result(&trace, TraceStage::Upstream), "missing");
+    assert!(trace.resolution.is_none());
+    assert!(trace.resolved_name.is_none());
+}
+
+#[tokio::test]
+async fn test_cname_rewrite_resolves_the_target() {
+    let f = Fixture::new();
+    f.rewrites
+        .set_rewrites(&[("www.example.com", "cdn.example.net")]);
+    f.resolver
+        .set_response("cdn.example.net", upstream_answer("198.51.100.7"))
+        .await;
+
+    let trace = f
+        .use_case()
+        .execute("www.example.com", Some(CLIENT_IP), RecordType::A)
+        .await
+        .unwrap();
+
+    assert_eq!(step_result(&trace, TraceStage::Rewrite), "cname");
+    assert_eq!(trace.resolved_name.as_deref(), Some("cdn.example.net"));
+    assert_eq!(
+        trace.resolution.unwrap().addresses[0],
+        "198.51.100.7".parse::<IpAddr>().unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_address_rewrite_answers_locally() {
+    let f = Fixture::new();
+    f.rewrites
+        .set_rewrites(&[("nas.example.com", "192.168.1.10")]);
+
+    let trace = f
+        .use_case()
+        .execute("nas.example.com", Some(CLIENT_IP), RecordType::A)
+        .await
+        .unwrap();
+
+    assert_eq!(step_result(&trace, TraceStage::Answer), "local");
+    assert!(trace.resolution.unwrap().local_dns);
+    assert!(trace.upstream_attempts.is_empty());
+}
+
+#[tokio::test]
+async fn test_forward_to_pool_policy_resolves_via_pool() {
+    let f = Fixture::new();
+    f.policies.set_policy(
+        "corp.example.com",
+        PolicyAction::ForwardToPool,
+        Some("corp"),
+    );
+    f.resolver
+        .set_response("corp.example.com", upstream_answer("10.1.2.3"))
+        .await;
+
+    let trace = f
+        .use_case()
+        .execute("corp.example.com", Some(CLIENT_IP), RecordType::A)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        step_result(&trace, TraceStage::QueryPolicy),
+        "forward_to_pool"
+    );
+    assert_eq!(
+        trace.resolution.unwrap().upstream_pool.as_deref(),
+        Some("corp")
+    );
+}
+
+#[tokio::test]
+async fn test_nxdomain_is_reported_in_the_answer_step() {
+    let f = Fixture::new();
+    f.resolver
+        .set_response_error("missing.example.com", DomainError::NxDomain)
+        .await;
+
+    let trace = f
+        .use_case()
+        .execute("missing.example.com", Some(CLIENT_IP), RecordType::A)
+        .await
+        .unwrap();
+
+    assert_eq!(step_result(&trace, TraceStage::Answer), "nxdomain");
+    assert!(matches!(trace.error, Some(DomainError::NxDomain)));
+    assert!(trace.resolution.is_none());
+}
+
+#[tokio::test]
+async fn test_upstream_attempts_are_collected_in_order() {
+    let f = Fixture::new();
+
+    let trace = f
+        .use_case_with(Arc::new(FailoverResolver))
+        .execute("example.com", Some(CLIENT_IP), RecordType::A)
+        .await
+        .unwrap();
+
+    assert_eq!(trace.upstream_attempts.len(), 2);
+    assert_eq!(trace.upstream_attempts[0].error.as_deref(), Some("timeout"));
+    assert_eq!(trace.upstream_attempts[1].attempt, 2);
+    assert!(trace.upstream_attempts[1].error.is_none());
+}
+
+#[tokio::test]
+async fn test_attempts_outside_a_trace_are_not_recorded() {
+    let query = DnsQuery::new("example.com", RecordType::A);
+    FailoverResolver.resolve(&query).await.unwrap();
+
+    let (_, attempts) = trace_upstream_attempts(async {}).await;
+
+    assert!(attempts.is_empty());
+}
@@ -0,0 +1,146 @@
+use ferrous_dns_application::ports::{GroupRepository, UnblockRequestRepository};
+use ferrous_dns_application::use_cases::unblock_requests::{
+    ApproveUnblockRequestUseCase, DenyUnblockRequestUseCase, SubmitUnblockRequestUseCase,
+};
+use ferrous_dns_domain::{DomainAction, DomainError, UnblockRequest, UnblockRequestStatus};
+use std::sync::Arc;
+
+mod helpers;
+use helpers::{
+    MockBlockFilterEngine, MockGroupRepository, MockManagedDomainRepository,
+    MockUnblockRequestRepository,
+};
+
+struct Fixture {
+    repo: Arc<MockUnblockRequestRepository>,
+    managed_domains: Arc<MockManagedDomainRepository>,
+    groups: Arc<MockGroupRepository>,
+    engine: Arc<MockBlockFilterEngine>,
+}
+
+impl Fixture {
+    fn new() -> Self {
+        Self {
+            repo: Arc::new(MockUnblockRequestRepository::new()),
+            managed_domains: Arc::new(MockManagedDomainRepository::new()),
+            groups: Arc::new(MockGroupRepository::new()),
+            engine: Arc::new(MockBlockFilterEngine::new()),
+        }
+    }
+
+    fn submit(&self) -> SubmitUnblockRequestUseCase {
+        SubmitUnblockRequestUseCase::new(self.repo.clone(), self.engine.clone())
+    }
+
+    fn approve(&self) -> ApproveUnblockRequestUseCase {
+        ApproveUnblockRequestUseCase::new(
+            self.repo.clone(),
+            self.managed_domains.clone(),
+            self.groups.clone(),
+            self.engine.clone(),
+        )
+    }
+
+    fn deny(&self) -> DenyUnblockRequestUseCase {
+        DenyUnblockRequestUseCase::new(self.repo.clone())
+    }
+
+    async fn file(&self, domain: &str) -> i64 {
+        self.submit()
+            .execute(UnblockRequest::new(
+                Arc::from(domain),
+                Arc::from("192.168.1.20"),
+            ))
+            .await
+            .unwrap()
+            .id
+            .unwrap()
+    }
+}
+
+#[tokio::test]
+async fn test_submit_fills_group_of_client() {
+    let fixture = Fixture::new();
+
+    let id = fixture.file("ads.example.com").await;
+
+    let stored = fixture.repo.get_by_id(id).await.unwrap().unwrap();
+    assert_eq!(stored.group_id, Some(1));
+    assert_eq!(stored.status, UnblockRequestStatus::Pending);
+}
+
+#[tokio::test]
+async fn test_approve_allows_domain_for_requesting_group() {
+    let fixture = Fixture::new();
+    let id = fixture.file("ads.example.com").await;
+
+    let approved = fixture.approve().execute(id, None).await.unwrap();
+
+    assert_eq!(approved.status, UnblockRequestStatus::Approved);
+    let domains = fixture.managed_domains.get_all_domains().await;
+    assert_eq!(domains.len(), 1);
+    assert_eq!(domains[0].domain.as_ref(), "ads.example.com");
+    assert_eq!(domains[0].action, DomainAction::Allow);
+    assert_eq!(domains[0].group_id, 1);
+    assert_eq!(approved.managed_domain_id, domains[0].id);
+    assert_eq!(fixture.engine.reload_count().await, 1);
+}
+
+#[tokio::test]
+async fn test_approve_can_target_another_group() {
+    let fixture = Fixture::new();
+    let kids = fixture
+        .groups
+        .create("Kids".to_string(), None)
+        .await
+        .unwrap();
+    let id = fixture.file("ads.example.com").await;
+
+    fixture.approve().execute(id, kids.id).await.unwrap();
+
+    let domains = fixture.managed_domains.get_all_domains().await;
+    assert_eq!(Some(domains[0].group_id), kids.id);
+}
+
+#[tokio::test]
+async fn test_approve_rejects_unknown_group() {
+    let fixture = Fixture::new();
+    let id = fixture.file("ads.example.com").await;
+
+    let result = fixture.approve().execute(id, Some(99)).await;
+
+    assert!(matches!(result, Err(DomainError::GroupNotFound(99))));
+    assert_eq!(fixture.managed_domains.count().await, 0);
+    let request = fixture.repo.get_by_id(id).await.unwrap().unwrap();
+    assert_eq!(request.status, UnblockRequestStatus::Pending);
+}
+
+#[tokio::test]
+async fn test_denied_request_cannot_be_approved() {
+    let fixture = Fixture::new();
+    let id = fixture.file("ads.example.com").await;
+
+    let denied = fixture.deny().execute(id).await.unwrap();
+    let result = fixture.approve().execute(id, None).await;
+
+    assert_eq!(denied.status, UnblockRequestStatus::Denied);
+    assert!(matches!(
+        result,
+        Err(DomainError::UnblockRequestAlreadyReviewed(reviewed)) if reviewed == id
+    ));
+    assert_eq!(fixture.managed_domains.count().await, 0);
+}
+
+#[tokio::test]
+async fn test_review_of_missing_request_fails() {
+    let fixture = Fixture::new();
+
+    assert!(matches!(
+        fixture.deny().execute(7).await,
+        Err(DomainError::UnblockRequestNotFound(7))
+    ));
+    assert!(matches!(
+        fixture.approve().execute(7, None).await,
+        Err(DomainError::UnblockRequestNotFound(7))
+    ));
+}
@@ -0,0 +1,102 @@
+use ferrous_dns_application::use_cases::{ClientMetadataUpdate, UpdateClientUseCase};
+use ferrous_dns_domain::{Client, ClientCategory, DomainError};
+use std::sync::Arc;
+
+mod helpers;
+use helpers::MockClientRepository;
+
+fn make_client(id: i64) -> Client {
+    let mut client = Client::new("192.168.1.73".parse().unwrap());
+    client.id = Some(id);
+    client
+}
+
+#[tokio::test]
+async fn test_update_metadata_sets_fields() {
+    let repo = Arc::new(MockClientRepository::with_clients(vec![make_client(1)]).await);
+    let use_case = UpdateClientUseCase::new(repo);
+
+    let client = use_case
+        .update_metadata(
+            1,
+            ClientMetadataUpdate {
+                display_name: Some("  Living Room TV ".to_string()),
+                category: Some("tv".to_string()),
+                notes: Some("Wall mounted".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(client.display_name.as_deref(), Some("Living Room TV"));
+    assert_eq!(client.category, Some(ClientCategory::Tv));
+    assert_eq!(client.notes.as_deref(), Some("Wall mounted"));
+}
+
+#[tokio::test]
+async fn test_update_metadata_keeps_omitted_and_clears_empty_fields() {
+    let mut existing = make_client(1);
+    existing.display_name = Some(Arc::from("Old name"));
+    existing.category = Some(ClientCategory::Phone);
+    existing.notes = Some(Arc::from("keep me"));
+    let repo = Arc::new(MockClientRepository::with_clients(vec![existing]).await);
+    let use_case = UpdateClientUseCase::new(repo);
+
+    let client = use_case
+        .update_metadata(
+            1,
+            ClientMetadataUpdate {
+                display_name: Some(String::new()),
+                category: None,
+                notes: None,
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(client.display_name, None);
+    assert_eq!(client.category, Some(ClientCategory::Phone));
+    assert_eq!(client.notes.as_deref(), Some("keep me"));
+}
+
+#[tokio::test]
+async fn test_update_metadata_rejects_invalid_input() {
+    let repo = Arc::new(MockClientRepository::with_clients(vec![make_client(1)]).await);
+    let use_case = UpdateClientUseCase::new(repo);
+
+    let unknown_category = use_case
+        .update_metadata(
+            1,
+            ClientMetadataUpdate {
+                category: Some("toaster".to_string()),
+                ..Default::default()
+            },
+        )
+        .await;
+    assert!(matches!(
+        unknown_category,
+        Err(DomainError::InvalidInput(_))
+    ));
+
+    let long_name = use_case
+        .update_metadata(
+            1,
+            ClientMetadataUpdate {
+                display_name: Some("x".repeat(65)),
+                ..Default::default()
+            },
+        )
+        .await;
+    assert!(matches!(long_name, Err(DomainError::InvalidInput(_))));
+}
+
+#[tokio::test]
+async fn test_update_metadata_unknown_client() {
+    let repo = Arc::new(MockClientRepository::new());
+    let use_case = UpdateClientUseCase::new(repo);
+
+    let result = use_case
+        .update_metadata(9, ClientMetadataUpdate::default())
+        .await;
+    assert!(matches!(result, Err(DomainError::ClientNotFound(_))));
+}
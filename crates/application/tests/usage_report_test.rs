@@ -0,0 +1,263 @@
+use ferrous_dns_application::ports::{
+    AggregateStatus, QueryLogRepository, ResponseValidationStats, UpstreamGroupHealth,
+    UpstreamHealthPort, UpstreamRoute, UpstreamStatus,
+};
+use ferrous_dns_application::use_cases::SendUsageReportUseCase;
+use ferrous_dns_domain::{
+    Client, DomainError, QueryLog, QuerySource, RecordType, ReportPeriod, UpstreamStrategy,
+};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+mod helpers;
+use helpers::{MockClientRepository, MockQueryLogRepository, MockReportSender, MockUpstreamHealth};
+
+/// Reports one healthy, one partial and one unreachable server.
+struct DegradedUpstreams;
+
+impl UpstreamHealthPort for DegradedUpstreams {
+    fn get_all_upstream_status(&self) -> Vec<(String, UpstreamStatus)> {
+        Vec::new()
+    }
+
+    fn get_grouped_upstream_health(&self) -> Vec<UpstreamGroupHealth> {
+        [
+            ("udp://1.1.1.1:53", AggregateStatus::Healthy),
+            ("doh://dns.example/dns-query", AggregateStatus::Partial),
+            ("udp://9.9.9.9:53", AggregateStatus::Unhealthy),
+        ]
+        .into_iter()
+        .map(|(address, status)| UpstreamGroupHealth {
+            address: address.to_string(),
+            status,
+            resolved: Vec::new(),
+            pool_name: "default".to_string(),
+            strategy: UpstreamStrategy::Parallel,
+        })
+        .collect()
+    }
+
+    fn response_validation_stats(&self) -> ResponseValidationStats {
+        ResponseValidationStats::default()
+    }
+
+    fn preview_route(&self, _pool: Option<&str>) -> Option<UpstreamRoute> {
+        None
+    }
+}
+
+fn make_log(blocked: bool) -> QueryLog {
+    QueryLog {
+        id: None,
+        domain: "example.com".into(),
+        record_type: RecordType::A,
+        client_ip: IpAddr::from([192, 168, 1, 1]),
+        client_hostname: None,
+        client_mac: None,
+        blocked,
+        response_time_us: Some(100),
+        cache_hit: false,
+        cache_refresh: false,
+        dnssec_status: None,
+        upstream_server: None,
+        upstream_pool: None,
+        upstream_strategy: None,
+        upstream_attempt: None,
+        upstream_protocol: None,
+        response_status: Some("NOERROR"),
+        timestamp: None,
+        query_source: QuerySource::Client,
+        group_id: None,
+        group_name: None,
+        block_source: None,
+        plugin: None,
+    }
+}
+
+fn make_client(id: i64, ip: &str, first_seen_days_ago: i64) -> Client {
+    let now = chrono::Utc::now();
+    let first_seen = now - chrono::Duration::days(first_seen_days_ago);
+    Client {
+        id: Some(id),
+        ip_address: ip.parse().unwrap(),
+        mac_address: None,
+        hostname: None,
+        first_seen: Some(first_seen.format("%Y-%m-%d %H:%M:%S").to_string()),
+        last_seen: Some(now.to_rfc3339()),
+        query_count: 0,
+        last_mac_update: None,
+        last_hostname_update: None,
+        group_id: None,
+        display_name: None,
+        category: None,
+        notes: None,
+    }
+}
+
+async fn use_case_with(
+    logs: &[bool],
+    clients: Vec<Client>,
+    upstream_health: Arc<dyn UpstreamHealthPort>,
+    sender: Arc<MockReportSender>,
+) -> SendUsageReportUseCase {
+    let query_log = Arc::new(MockQueryLogRepository::new());
+    for blocked in logs {
+        query_log.log_query(&make_log(*blocked)).await.unwrap();
+    }
+    SendUsageReportUseCase::new(
+        query_log,
+        Arc::new(MockClientRepository::with_clients(clients).await),
+        upstream_health,
+        sender,
+    )
+}
+
+#[tokio::test]
+async fn test_report_counts_queries_and_blocked_share() {
+    let sender = Arc::new(MockReportSender::new());
+    let use_case = use_case_with(
+        &[true, false, false, false],
+        Vec::new(),
+        Arc::new(MockUpstreamHealth),
+        sender.clone(),
+    )
+    .await;
+
+    let report = use_case.execute(ReportPeriod::Weekly, 168).await.unwrap();
+
+    assert_eq!(report.queries_total, 4);
+    assert_eq!(report.queries_blocked, 1);
+    assert_eq!(report.blocked_percentage(), 25.0);
+    assert!(report.degraded_upstreams.is_empty());
+}
+
+#[tokio::test]
+async fn test_report_is_emailed_as_html() {
+    let sender = Arc::new(MockReportSender::new());
+    let use_case = use_case_with(
+        &[true, false],
+        Vec::new(),
+        Arc::new(MockUpstreamHealth),
+        sender.clone(),
+    )
+    .await;
+
+    use_case.execute(ReportPeriod::Monthly, 720).await.unwrap();
+
+    let sent = sender.sent();
+    assert_eq!(sent.len(), 1);
+    let (subject, html) = &sent[0];
+    assert_eq!(subject, "Monthly DNS report");
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("Last 30 days."));
+    assert!(html.contains("(50.0%)"));
+    assert!(html.contains("<h2>Top blocked domains</h2><p>None.</p>"));
+    assert!(html.contains("All upstream servers are healthy."));
+}
+
+#[tokio::test]
+async fn test_report_lists_only_devices_first_seen_in_period() {
+    let sender = Arc::new(MockReportSender::new());
+    let use_case = use_case_with(
+        &[],
+        vec![
+            make_client(1, "192.168.1.10", 2),
+            make_client(2, "192.168.1.11", 30),
+            make_client(3, "192.168.1.12", 0),
+        ],
+        Arc::new(MockUpstreamHealth),
+        sender.clone(),
+    )
+    .await;
+
+    let report = use_case.build(ReportPeriod::Weekly, 168).await.unwrap();
+
+    assert_eq!(report.new_client_count, 2);
+    let ips: Vec<String> = report
+        .new_clients
+        .iter()
+        .map(|c| c.ip_address.to_string())
+        .collect();
+    assert!(ips.contains(&"192.168.1.10".to_string()));
+    assert!(ips.contains(&"192.168.1.12".to_string()));
+    assert!(sender.sent().is_empty());
+}
+
+#[tokio::test]
+async fn test_report_truncates_new_devices_to_top_count() {
+    let sender = Arc::new(MockReportSender::new());
+    let use_case = use_case_with(
+        &[],
+        (1..=5)
+            .map(|id| make_client(id, &format!("10.0.0.{id}"), 1))
+            .collect(),
+        Arc::new(MockUpstreamHealth),
+        sender.clone(),
+    )
+    .await
+    .with_top_count(2);
+
+    let report = use_case.execute(ReportPeriod::Weekly, 168).await.unwrap();
+
+    assert_eq!(report.new_client_count, 5);
+    assert_eq!(report.new_clients.len(), 2);
+    assert!(sender.sent()[0].1.contains("… and 3 more."));
+}
+
+#[tokio::test]
+async fn test_report_summarises_degraded_upstreams() {
+    let sender = Arc::new(MockReportSender::new());
+    let use_case =
+        use_case_with(&[], Vec::new(), Arc::new(DegradedUpstreams), sender.clone()).await;
+
+    let report = use_case.execute(ReportPeriod::Weekly, 168).await.unwrap();
+
+    assert_eq!(
+        report.degraded_upstreams,
+        vec![
+            "doh://dns.example/dns-query (default): partially reachable".to_string(),
+            "udp://9.9.9.9:53 (default): unreachable".to_string(),
+        ]
+    );
+    assert!(sender.sent()[0]
+        .1
+        .contains("<li>udp://9.9.9.9:53 (default): unreachable</li>"));
+}
+
+#[tokio::test]
+async fn test_report_escapes_client_names() {
+    let sender = Arc::new(MockReportSender::new());
+    let mut client = make_client(1, "192.168.1.10", 1);
+    client.hostname = Some("<script>&".to_string());
+    let use_case = use_case_with(
+        &[],
+        vec![client],
+        Arc::new(MockUpstreamHealth),
+        sender.clone(),
+    )
+    .await;
+
+    use_case.execute(ReportPeriod::Weekly, 168).await.unwrap();
+
+    let html = &sender.sent()[0].1;
+    assert!(html.contains("&lt;script&gt;&amp;"));
+    assert!(!html.contains("<script>"));
+}
+
+#[tokio::test]
+async fn test_report_send_failure_is_returned() {
+    let use_case = use_case_with(
+        &[false],
+        Vec::new(),
+        Arc::new(MockUpstreamHealth),
+        Arc::new(MockReportSender::failing()),
+    )
+    .await;
+
+    let result = use_case.execute(ReportPeriod::Weekly, 168).await;
+
+    assert!(matches!(
+        result,
+        Err(DomainError::NotificationDeliveryFailed(_))
+    ));
+}
@@ -1,10 +1,10 @@
 use ferrous_dns_application::ports::WhitelistRepository;
-use ferrous_dns_application::use_cases::whitelist::GetWhitelistUseCase;
+use ferrous_dns_application::use_cases::whitelist::{BulkAddWhitelistUseCase, GetWhitelistUseCase};
 use ferrous_dns_domain::whitelist::WhitelistedDomain;
 use std::sync::Arc;
 
 mod helpers;
-use helpers::MockWhitelistRepository;
+use helpers::{MockBlockFilterEngine, MockWhitelistRepository};
 
 #[tokio::test]
 async fn test_get_empty_whitelist() {
@@ -256,3 +256,24 @@ async fn test_concurrent_reads() {
     assert_eq!(count.unwrap(), 2);
     assert!(wl2.unwrap());
 }
+
+#[tokio::test]
+async fn test_bulk_add_accepts_wildcards() {
+    let repository = Arc::new(MockWhitelistRepository::new());
+    let engine = Arc::new(MockBlockFilterEngine::new());
+    let use_case = BulkAddWhitelistUseCase::new(repository.clone(), engine.clone());
+
+    let entries: Vec<String> = ["*.cdn.example.com", "safe.com", "safe.com", "not valid"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    let summary = use_case.execute(&entries).await.unwrap();
+
+    assert_eq!(summary.received, 4);
+    assert_eq!(summary.added, 2);
+    assert_eq!(summary.duplicates, 1);
+    assert_eq!(summary.errors.len(), 1);
+    assert_eq!(summary.errors[0].index, 3);
+    assert_eq!(repository.get_all().await.unwrap().len(), 2);
+    assert_eq!(engine.reload_count().await, 1);
+}
@@ -1,4 +1,9 @@
-use clap::Parser;
+use crate::bench::BenchProfile;
+use crate::replay::parse_speed;
+use clap::{Args, Parser, Subcommand};
+use ferrous_dns_domain::UpstreamPreset;
+use std::net::SocketAddr;
+use std::path::PathBuf;
 
 #[derive(Parser)]
 #[command(name = "ferrous-dns")]
@@ -22,4 +27,98 @@ pub struct Cli {
 
     #[arg(long)]
     pub log_level: Option<String>,
+
+    /// Use a built-in upstream setup instead of the configured pools:
+    /// cloudflare, quad9, google, mullvad, dot-privacy or family
+    #[arg(long, value_name = "PRESET")]
+    pub upstream_preset: Option<UpstreamPreset>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Generate DNS load and report latency percentiles and hit rates
+    Bench(BenchArgs),
+    /// Import lists, domains, local records and clients from Pi-hole or AdGuard Home
+    Import(ImportArgs),
+    /// Replay queries from a query log against a server and compare block decisions and latencies
+    Replay(ReplayArgs),
+}
+
+#[derive(Args)]
+pub struct BenchArgs {
+    /// DNS server to load (UDP)
+    #[arg(long, default_value = "127.0.0.1:53")]
+    pub target: SocketAddr,
+
+    /// Queries per second across all workers
+    #[arg(long, default_value_t = 10_000)]
+    pub qps: u32,
+
+    /// Run length in seconds
+    #[arg(long, default_value_t = 10)]
+    pub duration: u64,
+
+    #[arg(long, value_enum, default_value_t = BenchProfile::Cached)]
+    pub profile: BenchProfile,
+
+    /// UDP sockets, or concurrent tasks with --in-process
+    #[arg(long, default_value_t = 8)]
+    pub concurrency: usize,
+
+    /// Per-query timeout in milliseconds
+    #[arg(long, default_value_t = 2_000)]
+    pub timeout_ms: u64,
+
+    /// File with one domain per line, replacing the profile's built-in names
+    #[arg(long, value_name = "FILE")]
+    pub domains: Option<PathBuf>,
+
+    /// Resolve through a resolver built from --config instead of over the network
+    #[arg(long)]
+    pub in_process: bool,
+}
+
+#[derive(Args)]
+pub struct ImportArgs {
+    /// Source product: `pihole` (Teleporter .tar.gz/.zip) or `adguard` (AdGuardHome.yaml)
+    #[arg(long)]
+    pub format: String,
+
+    /// Export file to import
+    #[arg(value_name = "FILE")]
+    pub file: PathBuf,
+}
+
+#[derive(Args)]
+pub struct ReplayArgs {
+    /// Database holding the query log; defaults to the configured database
+    #[arg(long, value_name = "FILE")]
+    pub from: Option<PathBuf>,
+
+    /// DNS server to replay against (UDP)
+    #[arg(long, default_value = "127.0.0.1:53")]
+    pub target: SocketAddr,
+
+    /// Playback rate relative to the recorded timing, such as `2x` or `0.5x`
+    #[arg(long, default_value = "1x", value_parser = parse_speed)]
+    pub speed: f64,
+
+    /// Only replay queries logged at or after this UTC time (`YYYY-MM-DD HH:MM:SS`)
+    #[arg(long)]
+    pub since: Option<String>,
+
+    /// Replay at most this many queries
+    #[arg(long)]
+    pub limit: Option<u32>,
+
+    /// UDP sockets the queries are spread over
+    #[arg(long, default_value_t = 8)]
+    pub concurrency: usize,
+
+    /// Per-query timeout in milliseconds
+    #[arg(long, default_value_t = 2_000)]
+    pub timeout_ms: u64,
 }
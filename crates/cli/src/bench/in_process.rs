@@ -0,0 +1,97 @@
+use super::pace;
+use super::profile::QueryNames;
+use super::report::BenchStats;
+use crate::args::BenchArgs;
+use ferrous_dns::Resolver;
+use ferrous_dns_domain::{Config, DomainError, RecordType};
+use std::net::{IpAddr, Ipv4Addr};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::info;
+
+const BENCH_CLIENT: IpAddr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+
+/// Drives the embedded `Resolver`, built from the same config and
+/// database as the server, so results exclude network and wire-format costs.
+pub async fn run(
+    args: &BenchArgs,
+    config: Config,
+    domains: Arc<[Arc<str>]>,
+) -> anyhow::Result<BenchStats> {
+    // Benchmark traffic must not end up in the query log.
+    let resolver = Resolver::builder(config).log_queries(false).build().await?;
+
+    info!(
+        qps = args.qps,
+        duration = args.duration,
+        profile = args.profile.as_str(),
+        "Starting in-process benchmark"
+    );
+
+    let workers = args.concurrency.max(1);
+    let rate = f64::from(args.qps.max(1)) / workers as f64;
+    let duration = Duration::from_secs(args.duration);
+    let timeout = Duration::from_millis(args.timeout_ms);
+    let start = Instant::now();
+
+    let handles: Vec<_> = (0..workers)
+        .map(|worker| {
+            let resolver = resolver.clone();
+            let mut names = QueryNames::new(args.profile, domains.clone(), worker);
+            tokio::spawn(async move {
+                let mut stats = BenchStats {
+                    cache_hits: Some(0),
+                    ..BenchStats::default()
+                };
+                while start.elapsed() < duration {
+                    pace(start, rate, stats.sent).await;
+                    let name = names.next_name();
+                    stats.sent += 1;
+
+                    let sent_at = Instant::now();
+                    let Ok(result) = tokio::time::timeout(
+                        timeout,
+                        resolver.resolve(&name, RecordType::A, BENCH_CLIENT),
+                    )
+                    .await
+                    else {
+                        stats.timeouts += 1;
+                        continue;
+                    };
+                    stats.record_latency(sent_at.elapsed());
+                    record_result(result, &mut stats);
+                }
+                stats
+            })
+        })
+        .collect();
+
+    let mut stats = BenchStats::default();
+    for handle in handles {
+        stats.merge(handle.await?);
+    }
+    Ok(stats)
+}
+
+fn record_result(
+    result: Result<ferrous_dns_application::ports::DnsResolution, DomainError>,
+    stats: &mut BenchStats,
+) {
+    match result {
+        Ok(resolution) => {
+            stats.noerror += 1;
+            if resolution.cache_hit {
+                if let Some(hits) = stats.cache_hits.as_mut() {
+                    *hits += 1;
+                }
+            }
+        }
+        Err(DomainError::Blocked(_)) => {
+            stats.refused += 1;
+            stats.blocked += 1;
+        }
+        Err(DomainError::NxDomain | DomainError::LocalNxDomain) => stats.nxdomain += 1,
+        Err(DomainError::DnsRateLimited | DomainError::FilteredQuery(_)) => stats.refused += 1,
+        Err(_) => stats.servfail += 1,
+    }
+}
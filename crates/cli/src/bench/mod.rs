@@ -0,0 +1,63 @@
+//! `ferrous-dns bench`: open-loop DNS load generator.
+//!
+//! Each worker paces itself to its share of `--qps`; latency is measured per
+//! query and summarised as percentiles once the run ends.
+
+mod in_process;
+mod network;
+mod profile;
+mod report;
+
+pub(crate) use network::{connect, is_blocked};
+pub use profile::BenchProfile;
+pub(crate) use report::{percentile, ratio};
+
+use crate::args::BenchArgs;
+use anyhow::Context;
+use ferrous_dns_domain::Config;
+use std::time::{Duration, Instant};
+
+pub async fn run(args: &BenchArgs, config: Config) -> anyhow::Result<()> {
+    let custom = match &args.domains {
+        Some(path) => Some(read_domains(path)?),
+        None => None,
+    };
+    let domains = profile::QueryNames::domain_list(args.profile, custom);
+
+    let (target, stats) = if args.in_process {
+        let stats = in_process::run(args, config, domains).await?;
+        ("in-process resolver".to_string(), stats)
+    } else {
+        let stats = network::run(args, domains).await?;
+        (args.target.to_string(), stats)
+    };
+
+    report::print_report(
+        args.profile,
+        &target,
+        args.qps,
+        Duration::from_secs(args.duration),
+        stats,
+    );
+    Ok(())
+}
+
+fn read_domains(path: &std::path::Path) -> anyhow::Result<Vec<String>> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read domain list {}", path.display()))?;
+    Ok(contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| line.trim_end_matches('.').to_string())
+        .collect())
+}
+
+/// Waits until a worker sending `rate` queries per second is due to send
+/// query number `sent`.
+async fn pace(start: Instant, rate: f64, sent: u64) {
+    let due = start + Duration::from_secs_f64(sent as f64 / rate);
+    if due > Instant::now() {
+        tokio::time::sleep_until(due.into()).await;
+    }
+}
@@ -0,0 +1,206 @@
+use super::pace;
+use super::profile::QueryNames;
+use super::report::BenchStats;
+use crate::args::BenchArgs;
+use anyhow::Context;
+use ferrous_dns_domain::RecordType;
+use ferrous_dns_infrastructure::dns::ede;
+use ferrous_dns_infrastructure::dns::forwarding::MessageBuilder;
+use hickory_proto::op::{Message, ResponseCode};
+use hickory_proto::rr::rdata::opt::EdnsOption;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::net::UdpSocket;
+
+/// Send timestamps indexed by DNS message ID, in nanoseconds since the start
+/// of the run plus one; zero marks a free slot.
+type PendingQueries = Arc<[AtomicU64]>;
+
+pub async fn run(args: &BenchArgs, domains: Arc<[Arc<str>]>) -> anyhow::Result<BenchStats> {
+    let workers = args.concurrency.max(1);
+    let rate = f64::from(args.qps.max(1)) / workers as f64;
+    let duration = Duration::from_secs(args.duration);
+    let timeout = Duration::from_millis(args.timeout_ms);
+
+    let mut sockets = Vec::with_capacity(workers);
+    for _ in 0..workers {
+        sockets.push(connect(args.target).await?);
+    }
+
+    let start = Instant::now();
+    let handles: Vec<_> = sockets
+        .into_iter()
+        .enumerate()
+        .map(|(worker, socket)| {
+            let names = QueryNames::new(args.profile, domains.clone(), worker);
+            tokio::spawn(run_worker(socket, names, rate, start, duration, timeout))
+        })
+        .collect();
+
+    let mut stats = BenchStats::default();
+    for handle in handles {
+        stats.merge(handle.await??);
+    }
+    Ok(stats)
+}
+
+pub(crate) async fn connect(target: SocketAddr) -> anyhow::Result<Arc<UdpSocket>> {
+    let bind: SocketAddr = if target.is_ipv4() {
+        "0.0.0.0:0".parse()?
+    } else {
+        "[::]:0".parse()?
+    };
+    let socket = UdpSocket::bind(bind).await?;
+    socket
+        .connect(target)
+        .await
+        .with_context(|| format!("Failed to connect to {target}"))?;
+    Ok(Arc::new(socket))
+}
+
+async fn run_worker(
+    socket: Arc<UdpSocket>,
+    names: QueryNames,
+    rate: f64,
+    start: Instant,
+    duration: Duration,
+    timeout: Duration,
+) -> anyhow::Result<BenchStats> {
+    let pending: PendingQueries = (0..=u16::MAX).map(|_| AtomicU64::new(0)).collect();
+
+    let receiver = tokio::spawn(receive_responses(
+        socket.clone(),
+        pending.clone(),
+        start,
+        duration + timeout,
+        timeout,
+    ));
+    let (sent, lost) = send_queries(&socket, names, &pending, rate, start, duration).await?;
+
+    let mut stats = receiver.await?;
+    let unanswered = pending
+        .iter()
+        .filter(|slot| slot.load(Ordering::Relaxed) != 0)
+        .count() as u64;
+    stats.sent = sent;
+    stats.timeouts += lost + unanswered;
+    Ok(stats)
+}
+
+/// Returns the number of queries sent and of queries that were never answered
+/// before their message ID was reused.
+async fn send_queries(
+    socket: &UdpSocket,
+    mut names: QueryNames,
+    pending: &PendingQueries,
+    rate: f64,
+    start: Instant,
+    duration: Duration,
+) -> anyhow::Result<(u64, u64)> {
+    let mut sent = 0u64;
+    let mut lost = 0u64;
+
+    while start.elapsed() < duration {
+        pace(start, rate, sent).await;
+
+        let name = names.next_name();
+        let mut wire = MessageBuilder::build_query(&name, &RecordType::A, false)
+            .with_context(|| format!("Cannot build a query for '{name}'"))?;
+        let id = sent as u16;
+        wire[..2].copy_from_slice(&id.to_be_bytes());
+
+        let slot = &pending[usize::from(id)];
+        if slot.swap(elapsed_ns(start), Ordering::Relaxed) != 0 {
+            lost += 1;
+        }
+        if socket.send(&wire).await.is_err() {
+            slot.store(0, Ordering::Relaxed);
+            lost += 1;
+        }
+        sent += 1;
+    }
+
+    Ok((sent, lost))
+}
+
+async fn receive_responses(
+    socket: Arc<UdpSocket>,
+    pending: PendingQueries,
+    start: Instant,
+    stop_after: Duration,
+    timeout: Duration,
+) -> BenchStats {
+    let mut stats = BenchStats::default();
+    let deadline = tokio::time::Instant::from_std(start + stop_after);
+    let mut buf = vec![0u8; 4096];
+
+    loop {
+        let len = tokio::select! {
+            received = socket.recv(&mut buf) => match received {
+                Ok(len) => len,
+                Err(_) => continue,
+            },
+            _ = tokio::time::sleep_until(deadline) => break,
+        };
+        if len < 12 {
+            continue;
+        }
+
+        let id = u16::from_be_bytes([buf[0], buf[1]]);
+        let sent_at = pending[usize::from(id)].swap(0, Ordering::Relaxed);
+        if sent_at == 0 {
+            continue;
+        }
+        let latency = Duration::from_nanos(elapsed_ns(start).saturating_sub(sent_at));
+        if latency > timeout {
+            stats.timeouts += 1;
+            continue;
+        }
+
+        stats.record_latency(latency);
+        classify(&buf[..len], &mut stats);
+    }
+
+    stats
+}
+
+fn elapsed_ns(start: Instant) -> u64 {
+    start.elapsed().as_nanos() as u64 + 1
+}
+
+fn classify(wire: &[u8], stats: &mut BenchStats) {
+    let Ok(message) = Message::from_vec(wire) else {
+        stats.other += 1;
+        return;
+    };
+    match message.response_code() {
+        ResponseCode::NoError => stats.noerror += 1,
+        ResponseCode::NXDomain => stats.nxdomain += 1,
+        ResponseCode::ServFail => stats.servfail += 1,
+        ResponseCode::Refused => stats.refused += 1,
+        _ => stats.other += 1,
+    }
+    if is_blocked(&message) {
+        stats.blocked += 1;
+    }
+}
+
+/// Blocked answers carry Extended DNS Error 15 or 17, whether the server
+/// refuses them or answers with a sinkhole address.
+pub(crate) fn is_blocked(message: &Message) -> bool {
+    message.extensions().as_ref().is_some_and(|edns| {
+        edns.options().as_ref().iter().any(|(_, option)| {
+            matches!(
+                option,
+                EdnsOption::Unknown(ede::OPTION_CODE, data)
+                    if data.len() >= 2
+                        && matches!(
+                            u16::from_be_bytes([data[0], data[1]]),
+                            ede::codes::BLOCKED | ede::codes::FILTERED
+                        )
+            )
+        })
+    })
+}
@@ -0,0 +1,126 @@
+use clap::ValueEnum;
+use std::sync::Arc;
+
+/// Kind of load generated by `ferrous-dns bench`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum BenchProfile {
+    /// A small set of popular names, answered from cache after the first pass.
+    Cached,
+    /// A random label under each name, so every query goes upstream.
+    Uncached,
+    /// Well-known ad and tracker names that default blocklists cover.
+    Blocked,
+}
+
+impl BenchProfile {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Cached => "cached",
+            Self::Uncached => "uncached",
+            Self::Blocked => "blocked",
+        }
+    }
+
+    fn builtin_domains(self) -> &'static [&'static str] {
+        match self {
+            Self::Cached | Self::Uncached => POPULAR_DOMAINS,
+            Self::Blocked => AD_DOMAINS,
+        }
+    }
+}
+
+const POPULAR_DOMAINS: &[&str] = &[
+    "google.com",
+    "youtube.com",
+    "facebook.com",
+    "wikipedia.org",
+    "amazon.com",
+    "apple.com",
+    "microsoft.com",
+    "github.com",
+    "cloudflare.com",
+    "netflix.com",
+    "reddit.com",
+    "linkedin.com",
+    "instagram.com",
+    "whatsapp.com",
+    "yahoo.com",
+    "bing.com",
+    "office.com",
+    "live.com",
+    "twitch.tv",
+    "spotify.com",
+    "stackoverflow.com",
+    "mozilla.org",
+    "debian.org",
+    "rust-lang.org",
+    "crates.io",
+    "docker.com",
+    "ubuntu.com",
+    "zoom.us",
+    "dropbox.com",
+    "paypal.com",
+    "ebay.com",
+    "imdb.com",
+];
+
+const AD_DOMAINS: &[&str] = &[
+    "doubleclick.net",
+    "googleadservices.com",
+    "googlesyndication.com",
+    "adservice.google.com",
+    "pagead2.googlesyndication.com",
+    "ads.yahoo.com",
+    "adnxs.com",
+    "scorecardresearch.com",
+    "taboola.com",
+    "outbrain.com",
+    "criteo.com",
+    "moatads.com",
+    "advertising.com",
+    "adsrvr.org",
+    "pubmatic.com",
+    "rubiconproject.com",
+    "openx.net",
+    "casalemedia.com",
+    "quantserve.com",
+    "adform.net",
+];
+
+/// Produces query names for one worker.
+pub struct QueryNames {
+    profile: BenchProfile,
+    domains: Arc<[Arc<str>]>,
+    next: usize,
+}
+
+impl QueryNames {
+    pub fn new(profile: BenchProfile, domains: Arc<[Arc<str>]>, worker: usize) -> Self {
+        Self {
+            profile,
+            next: worker % domains.len().max(1),
+            domains,
+        }
+    }
+
+    /// Domain list for `profile`, or `custom` when a `--domains` file was given.
+    pub fn domain_list(profile: BenchProfile, custom: Option<Vec<String>>) -> Arc<[Arc<str>]> {
+        match custom {
+            Some(list) if !list.is_empty() => list.into_iter().map(Arc::from).collect(),
+            _ => profile
+                .builtin_domains()
+                .iter()
+                .map(|d| Arc::from(*d))
+                .collect(),
+        }
+    }
+
+    pub fn next_name(&mut self) -> String {
+        let domain = &self.domains[self.next];
+        self.next = (self.next + 1) % self.domains.len();
+        match self.profile {
+            BenchProfile::Uncached => format!("b{:012x}.{}", fastrand::u64(..) >> 16, domain),
+            BenchProfile::Cached | BenchProfile::Blocked => domain.to_string(),
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use super::profile::BenchProfile;
+use std::time::Duration;
+
+/// Counters and latency samples collected by one or more workers.
+#[derive(Debug, Default)]
+pub struct BenchStats {
+    pub sent: u64,
+    pub noerror: u64,
+    pub nxdomain: u64,
+    pub servfail: u64,
+    pub refused: u64,
+    pub other: u64,
+    pub blocked: u64,
+    pub timeouts: u64,
+    /// Only known when resolving in-process.
+    pub cache_hits: Option<u64>,
+    pub latencies_us: Vec<u32>,
+}
+
+impl BenchStats {
+    pub fn answered(&self) -> u64 {
+        self.noerror + self.nxdomain + self.servfail + self.refused + self.other
+    }
+
+    pub fn record_latency(&mut self, latency: Duration) {
+        self.latencies_us
+            .push(latency.as_micros().min(u32::MAX as u128) as u32);
+    }
+
+    pub fn merge(&mut self, other: BenchStats) {
+        self.sent += other.sent;
+        self.noerror += other.noerror;
+        self.nxdomain += other.nxdomain;
+        self.servfail += other.servfail;
+        self.refused += other.refused;
+        self.other += other.other;
+        self.blocked += other.blocked;
+        self.timeouts += other.timeouts;
+        self.cache_hits = match (self.cache_hits, other.cache_hits) {
+            (None, None) => None,
+            (a, b) => Some(a.unwrap_or(0) + b.unwrap_or(0)),
+        };
+        self.latencies_us.extend(other.latencies_us);
+    }
+}
+
+/// Value at percentile `p` (0–100) of an ascending slice.
+pub fn percentile(sorted: &[u32], p: f64) -> u32 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let rank = ((p / 100.0) * (sorted.len() - 1) as f64).round() as usize;
+    sorted[rank.min(sorted.len() - 1)]
+}
+
+pub(crate) fn ratio(part: u64, whole: u64) -> f64 {
+    if whole == 0 {
+        0.0
+    } else {
+        part as f64 * 100.0 / whole as f64
+    }
+}
+
+pub fn print_report(
+    profile: BenchProfile,
+    target: &str,
+    target_qps: u32,
+    elapsed: Duration,
+    mut stats: BenchStats,
+) {
+    stats.latencies_us.sort_unstable();
+    let answered = stats.answered();
+    let secs = elapsed.as_secs_f64().max(f64::EPSILON);
+    let lat = &stats.latencies_us;
+
+    println!();
+    println!("Profile:    {} against {}", profile.as_str(), target);
+    println!(
+        "Duration:   {:.1}s  target {} qps, achieved {:.0} qps sent / {:.0} qps answered",
+        secs,
+        target_qps,
+        stats.sent as f64 / secs,
+        answered as f64 / secs
+    );
+    println!(
+        "Queries:    {} sent, {} answered ({:.2}%), {} timed out ({:.2}%)",
+        stats.sent,
+        answered,
+        ratio(answered, stats.sent),
+        stats.timeouts,
+        ratio(stats.timeouts, stats.sent)
+    );
+    println!(
+        "Responses:  NOERROR {}  NXDOMAIN {}  SERVFAIL {}  REFUSED {}  other {}",
+        stats.noerror, stats.nxdomain, stats.servfail, stats.refused, stats.other
+    );
+    println!(
+        "Blocked:    {} ({:.2}% of answered)",
+        stats.blocked,
+        ratio(stats.blocked, answered)
+    );
+    if let Some(hits) = stats.cache_hits {
+        println!(
+            "Cache hits: {} ({:.2}% of answered)",
+            hits,
+            ratio(hits, answered)
+        );
+    }
+    println!(
+        "Latency µs: min {}  p50 {}  p90 {}  p99 {}  p99.9 {}  max {}",
+        lat.first().copied().unwrap_or(0),
+        percentile(lat, 50.0),
+        percentile(lat, 90.0),
+        percentile(lat, 99.0),
+        percentile(lat, 99.9),
+        lat.last().copied().unwrap_or(0)
+    );
+}
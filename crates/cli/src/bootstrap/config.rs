@@ -15,6 +15,13 @@ pub fn load_config(
         bind = %config.server.bind_address,
         "Configuration loaded"
     );
+    if let Some(preset) = config.dns.upstream_preset {
+        info!(
+            preset = %preset,
+            pools = config.dns.pools.len(),
+            "Using upstream preset"
+        );
+    }
 
     Ok(config)
 }
@@ -2,6 +2,7 @@ use ferrous_dns_domain::config::DatabaseConfig;
 use ferrous_dns_infrastructure::database::{
     create_query_log_pool, create_read_pool, create_write_pool,
 };
+use ferrous_dns_infrastructure::repositories::query_log_repository::query_log_tables;
 use sqlx::SqlitePool;
 use tracing::{error, info};
 
@@ -42,9 +43,22 @@ pub async fn init_database(
 }
 
 async fn warm_page_cache(pool: &SqlitePool) {
-    let result = sqlx::query("SELECT id FROM query_log ORDER BY id DESC LIMIT 5000")
-        .execute(pool)
-        .await;
+    // The newest query log partition is the one the dashboard reads first.
+    let table = match query_log_tables(pool).await {
+        Ok(tables) => tables
+            .last()
+            .cloned()
+            .unwrap_or_else(|| "query_log".to_string()),
+        Err(e) => {
+            error!(error = %e, "SQLite warmup query failed (non-critical)");
+            return;
+        }
+    };
+    let result = sqlx::query(&format!(
+        "SELECT id FROM {table} ORDER BY id DESC LIMIT 5000"
+    ))
+    .execute(pool)
+    .await;
     match result {
         Ok(r) => info!(rows = r.rows_affected(), "SQLite page cache warmed"),
         Err(e) => error!(error = %e, "SQLite warmup query failed (non-critical)"),
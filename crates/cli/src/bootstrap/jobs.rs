@@ -1,12 +1,22 @@
-use ferrous_dns_application::ports::CacheMaintenancePort;
+use ferrous_dns_application::ports::{CacheMaintenancePort, DnssecStatusPort, UpstreamHealthPort};
+use ferrous_dns_application::use_cases::{
+    DetectAnomaliesUseCase, ExportMetricsUseCase, RecordDnssecHistoryUseCase,
+    SendUsageReportUseCase,
+};
 use ferrous_dns_domain::Config;
+use ferrous_dns_infrastructure::metrics::LineProtocolMetricsSink;
+use ferrous_dns_infrastructure::notifications::{build_notification_senders, SmtpReportSender};
 use ferrous_dns_jobs::{
-    BlocklistSyncJob, CacheMaintenanceJob, ClientSyncJob, DgaEvictionJob, JobRunner,
-    NxdomainHijackEvictionJob, QueryLogRetentionJob, ResponseIpFilterEvictionJob, RetentionJob,
-    ScheduleEvaluatorJob, SessionCleanupJob, TunnelingEvictionJob, WalCheckpointJob,
+    AcmeRenewalJob, AnomalyDetectionJob, BlocklistSyncJob, CacheMaintenanceJob, ClientSyncJob,
+    DatabaseMaintenanceJob, DgaEvictionJob, DnssecHistoryJob, JobRunner, MetricsExportJob,
+    NotificationBus, NotificationDispatchJob, NotificationMonitorJob, NxdomainHijackEvictionJob,
+    QueryLogRetentionJob, RecordSourceSyncJob, ResponseIpFilterEvictionJob, RetentionJob,
+    ScheduleEvaluatorJob, SecondaryZoneRefreshJob, SessionCleanupJob, StandbySyncJob,
+    TunnelingEvictionJob, UpstreamAddressRefreshJob, UsageReportJob, WalCheckpointJob,
 };
 use sqlx::SqlitePool;
 use std::sync::Arc;
+use tracing::{error, info, warn};
 
 use crate::wiring::{Repositories, UseCases};
 
@@ -17,32 +27,55 @@ pub fn build_job_runner(
     config: &Config,
     wal_pool: SqlitePool,
     cache_maintenance: Option<Arc<dyn CacheMaintenancePort>>,
+    upstream_health: Arc<dyn UpstreamHealthPort>,
     tunneling_eviction: Option<TunnelingEvictionJob>,
     nxdomain_hijack_eviction: Option<NxdomainHijackEvictionJob>,
     response_ip_filter_eviction: Option<ResponseIpFilterEvictionJob>,
     dga_eviction: Option<DgaEvictionJob>,
+    secondary_zone_refresh: Option<SecondaryZoneRefreshJob>,
+    acme_renewal: Option<AcmeRenewalJob>,
+    upstream_address_refresh: Option<UpstreamAddressRefreshJob>,
+    record_source_sync: Vec<RecordSourceSyncJob>,
+    standby_sync: Option<StandbySyncJob>,
+    dnssec_status: Option<Arc<dyn DnssecStatusPort>>,
 ) -> JobRunner {
+    let notification_bus = config.notifications.enabled.then(NotificationBus::default);
+
+    let mut blocklist_sync = BlocklistSyncJob::new(repos.block_filter_engine.clone());
+    if let Some(bus) = &notification_bus {
+        blocklist_sync = blocklist_sync.with_notifications(bus.clone());
+    }
+
     let mut runner = JobRunner::new()
-        .with_client_sync(ClientSyncJob::new(
-            use_cases.sync_arp.clone(),
-            use_cases.sync_hostnames.clone(),
-        ))
+        .with_client_sync(
+            ClientSyncJob::new(use_cases.sync_arp.clone(), use_cases.sync_hostnames.clone())
+                .with_merge_duplicates(use_cases.merge_duplicate_clients.clone()),
+        )
         .with_retention(RetentionJob::new(use_cases.cleanup_clients.clone(), 30))
         .with_query_log_retention(QueryLogRetentionJob::new(
             use_cases.cleanup_query_logs.clone(),
             config.database.queries_log_stored,
         ))
-        .with_blocklist_sync(BlocklistSyncJob::new(repos.block_filter_engine.clone()))
         .with_wal_checkpoint(WalCheckpointJob::new(
             wal_pool,
             config.database.wal_checkpoint_interval_secs,
         ))
+        .with_database_maintenance(
+            DatabaseMaintenanceJob::new(use_cases.database_maintenance.clone())
+                .with_interval(config.database.maintenance_interval_secs),
+        )
         .with_schedule_evaluator(ScheduleEvaluatorJob::new(
             repos.schedule_profile.clone(),
             repos.schedule_state.clone(),
         ))
         .with_session_cleanup(SessionCleanupJob::new(repos.session.clone()).with_interval(3600));
 
+    // A standby compiles the primary's lists instead of fetching its own.
+    match standby_sync {
+        Some(sync) => runner = runner.with_standby_sync(sync),
+        None => runner = runner.with_blocklist_sync(blocklist_sync),
+    }
+
     if let Some(maintenance) = cache_maintenance {
         runner = runner.with_cache_maintenance(
             CacheMaintenanceJob::new(maintenance)
@@ -66,5 +99,122 @@ pub fn build_job_runner(
         runner = runner.with_dga_eviction(eviction);
     }
 
+    if let Some(refresh) = secondary_zone_refresh {
+        runner = runner.with_secondary_zone_refresh(refresh);
+    }
+
+    if let Some(renewal) = acme_renewal {
+        runner = runner.with_acme_renewal(renewal);
+    }
+
+    if let Some(refresh) = upstream_address_refresh {
+        runner = runner.with_upstream_address_refresh(refresh);
+    }
+
+    for job in record_source_sync {
+        runner = runner.with_record_source_sync(job);
+    }
+
+    let anomaly_detection = &config.dns.anomaly_detection;
+    if anomaly_detection.enabled {
+        if config.database.log_queries {
+            runner = runner.with_anomaly_detection(AnomalyDetectionJob::new(Arc::new(
+                DetectAnomaliesUseCase::new(
+                    repos.query_log.clone(),
+                    repos.alert.clone(),
+                    anomaly_detection.clone(),
+                ),
+            )));
+        } else {
+            warn!("Anomaly detection requires database.log_queries; not starting");
+        }
+    }
+
+    if let Some(status) = dnssec_status {
+        runner = runner.with_dnssec_history(DnssecHistoryJob::new(Arc::new(
+            RecordDnssecHistoryUseCase::new(status, repos.dnssec_history.clone()),
+        )));
+    }
+
+    let metrics_export = &config.logging.metrics_export;
+    if metrics_export.enabled {
+        if !config.database.log_queries {
+            warn!("Metrics export requires database.log_queries; not starting");
+        } else {
+            match LineProtocolMetricsSink::new(metrics_export) {
+                Ok(sink) => {
+                    info!(url = %metrics_export.url, "Metrics export enabled");
+                    runner = runner.with_metrics_export(MetricsExportJob::new(Arc::new(
+                        ExportMetricsUseCase::new(
+                            repos.query_log.clone(),
+                            Arc::new(sink),
+                            metrics_export.clone(),
+                        ),
+                    )));
+                }
+                Err(e) => error!(error = %e, "Failed to set up metrics export"),
+            }
+        }
+    }
+
+    let reports = &config.notifications.reports;
+    if reports.is_enabled() {
+        if !config.database.log_queries {
+            warn!("Usage reports require database.log_queries; not starting");
+        } else if let Some(smtp) = &config.notifications.smtp {
+            match SmtpReportSender::new(smtp) {
+                Ok(sender) => {
+                    info!(
+                        weekly = reports.weekly,
+                        monthly = reports.monthly,
+                        "Usage reports enabled"
+                    );
+                    runner = runner.with_usage_report(UsageReportJob::new(
+                        Arc::new(
+                            SendUsageReportUseCase::new(
+                                repos.query_log.clone(),
+                                repos.client.clone(),
+                                upstream_health.clone(),
+                                Arc::new(sender),
+                            )
+                            .with_top_count(reports.top_count),
+                        ),
+                        reports.clone(),
+                    ));
+                }
+                Err(e) => error!(error = %e, "Failed to set up usage reports"),
+            }
+        }
+    }
+
+    if let Some(bus) = notification_bus {
+        let notifications = &config.notifications;
+        match build_notification_senders(notifications) {
+            Ok(senders) => {
+                info!(channels = senders.len(), "Notifications enabled");
+                runner = runner
+                    .with_notification_dispatch(
+                        NotificationDispatchJob::new(&bus, senders)
+                            .with_events(notifications.events.clone())
+                            .with_cooldown(notifications.cooldown_secs),
+                    )
+                    .with_notification_monitor(
+                        NotificationMonitorJob::new(bus)
+                            .with_interval(notifications.check_interval_secs)
+                            .with_upstream_health(upstream_health)
+                            .with_query_log(
+                                repos.query_log.clone(),
+                                notifications.client_block_spike_threshold,
+                            )
+                            .with_database(
+                                repos.database_maintenance.clone(),
+                                notifications.disk_usage_percent,
+                            ),
+                    );
+            }
+            Err(e) => error!(error = %e, "Failed to set up notification channels"),
+        }
+    }
+
     runner
 }
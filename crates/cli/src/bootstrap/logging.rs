@@ -1,16 +1,177 @@
-use ferrous_dns_domain::Config;
-use tracing::info;
+use ferrous_dns_application::ports::{LogLevelPort, QUERY_SPAN_TARGET};
+use ferrous_dns_domain::{Config, DomainError, LogFormat, LoggingConfig, OtelConfig};
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::trace::{Sampler, SdkTracerProvider};
+use opentelemetry_sdk::Resource;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tracing::{info, warn};
+use tracing_subscriber::filter::{LevelFilter, Targets};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{reload, Layer, Registry};
 
-pub fn init_logging(config: &Config) {
-    let log_level = config.logging.level.parse().unwrap_or(tracing::Level::INFO);
+/// Crate prefixes a module override without one is expanded to.
+const CRATE_PREFIXES: &[&str] = &[
+    "ferrous_dns",
+    "ferrous_dns_domain",
+    "ferrous_dns_application",
+    "ferrous_dns_infrastructure",
+    "ferrous_dns_api",
+    "ferrous_dns_api_pihole",
+    "ferrous_dns_api_grpc",
+    "ferrous_dns_jobs",
+];
 
-    tracing_subscriber::fmt()
+/// Flushes buffered OpenTelemetry spans when dropped at shutdown.
+pub struct TelemetryGuard {
+    provider: Option<SdkTracerProvider>,
+    log_levels: Arc<LogLevelControl>,
+}
+
+impl Drop for TelemetryGuard {
+    fn drop(&mut self) {
+        if let Some(provider) = self.provider.take() {
+            if let Err(e) = provider.shutdown() {
+                eprintln!("Failed to flush OpenTelemetry spans: {e}");
+            }
+        }
+    }
+}
+
+impl TelemetryGuard {
+    pub fn log_level_control(&self) -> Arc<LogLevelControl> {
+        self.log_levels.clone()
+    }
+}
+
+/// Swaps the stdout log filter without restarting the subscriber.
+pub struct LogLevelControl {
+    handle: reload::Handle<Targets, Registry>,
+}
+
+impl LogLevelPort for LogLevelControl {
+    fn set_levels(
+        &self,
+        level: &str,
+        modules: &BTreeMap<String, String>,
+    ) -> Result<(), DomainError> {
+        let targets = build_targets(level, modules)?;
+        self.handle
+            .reload(targets)
+            .map_err(|e| DomainError::ConfigError(format!("Failed to reload log filter: {e}")))?;
+        info!(level, overrides = modules.len(), "Log levels updated");
+        Ok(())
+    }
+}
+
+pub fn init_logging(config: &Config) -> TelemetryGuard {
+    let logging = &config.logging;
+    let targets = build_targets(&logging.level, &logging.modules)
+        .unwrap_or_else(|_| Targets::new().with_default(LevelFilter::INFO));
+    let (filter, handle) = reload::Layer::new(targets);
+
+    let fmt_layer = tracing_subscriber::fmt::layer()
         .with_target(true)
         .with_thread_ids(false)
-        .with_level(true)
-        .with_max_level(log_level)
-        .with_ansi(true)
+        .with_level(true);
+    let fmt_layer: Box<dyn Layer<Registry> + Send + Sync> = match logging.format {
+        LogFormat::Text => Box::new(fmt_layer.with_ansi(true)),
+        LogFormat::Json => Box::new(fmt_layer.json().with_current_span(false)),
+    };
+    let fmt_layer = fmt_layer.with_filter(filter);
+
+    let otel = &config.logging.otel;
+    let (provider, otel_error) = if otel.enabled {
+        match build_tracer_provider(otel) {
+            Ok(provider) => (Some(provider), None),
+            Err(e) => (None, Some(e)),
+        }
+    } else {
+        (None, None)
+    };
+
+    // Only the per-query spans are exported; regular log events stay on stdout.
+    let otel_layer = provider.as_ref().map(|provider| {
+        tracing_opentelemetry::layer()
+            .with_tracer(provider.tracer("ferrous-dns"))
+            .with_filter(Targets::new().with_target(QUERY_SPAN_TARGET, tracing::Level::TRACE))
+    });
+
+    tracing_subscriber::registry()
+        .with(fmt_layer)
+        .with(otel_layer)
         .init();
 
     info!("Logging initialized at level: {}", config.logging.level);
+    if provider.is_some() {
+        info!(
+            endpoint = %otel.endpoint,
+            sample_ratio = otel.sample_ratio,
+            "OpenTelemetry query tracing enabled"
+        );
+    }
+    if let Some(e) = otel_error {
+        warn!(error = %e, "OpenTelemetry exporter unavailable, query tracing disabled");
+    }
+
+    TelemetryGuard {
+        provider,
+        log_levels: Arc::new(LogLevelControl { handle }),
+    }
+}
+
+/// Builds the stdout filter from the default level and per-module overrides.
+/// Overrides without a `ferrous_dns` prefix are applied under every crate.
+fn build_targets(level: &str, modules: &BTreeMap<String, String>) -> Result<Targets, DomainError> {
+    let mut targets = Targets::new().with_default(parse_level(level)?);
+    for (module, module_level) in modules {
+        let module = module.trim();
+        let module_level = parse_level(module_level)?;
+        targets = targets.with_target(module, module_level);
+        if !module.starts_with("ferrous_dns") {
+            for prefix in CRATE_PREFIXES {
+                targets = targets.with_target(format!("{prefix}::{module}"), module_level);
+            }
+        }
+    }
+    Ok(targets)
+}
+
+fn parse_level(level: &str) -> Result<LevelFilter, DomainError> {
+    if !LoggingConfig::is_valid_level(level) {
+        return Err(DomainError::InvalidInput(format!(
+            "'{level}' is not a log level"
+        )));
+    }
+    level
+        .trim()
+        .parse()
+        .map_err(|_| DomainError::InvalidInput(format!("'{level}' is not a log level")))
+}
+
+fn build_tracer_provider(otel: &OtelConfig) -> anyhow::Result<SdkTracerProvider> {
+    // The blocking reqwest client cannot be constructed on a runtime thread.
+    let endpoint = otel.endpoint.clone();
+    let exporter = std::thread::spawn(move || {
+        SpanExporter::builder()
+            .with_http()
+            .with_endpoint(endpoint)
+            .build()
+    })
+    .join()
+    .map_err(|_| anyhow::anyhow!("OTLP exporter thread panicked"))??;
+
+    Ok(SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_sampler(Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+            otel.sample_ratio,
+        ))))
+        .with_resource(
+            Resource::builder()
+                .with_service_name(otel.service_name.clone())
+                .build(),
+        )
+        .build())
 }
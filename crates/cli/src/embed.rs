@@ -0,0 +1,90 @@
+use crate::{bootstrap, wiring};
+use ferrous_dns_application::ports::DnsResolution;
+use ferrous_dns_application::use_cases::HandleDnsQueryUseCase;
+use ferrous_dns_domain::{Config, DnsRequest, DomainError, RecordType};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+/// In-process resolver stack: cache, block filter, local zone and upstream
+/// pools, wired exactly as the server wires them but without listeners,
+/// background jobs or the web layer.
+///
+/// Must be built and used inside a Tokio runtime.
+#[derive(Clone)]
+pub struct Resolver {
+    handler: Arc<HandleDnsQueryUseCase>,
+}
+
+impl Resolver {
+    pub fn builder(config: Config) -> ResolverBuilder {
+        ResolverBuilder::new(config)
+    }
+
+    /// Resolves `domain` as if `client_ip` had queried the server, applying
+    /// the client's group policy. Blocked, filtered and non-existent names
+    /// come back as the matching `DomainError`.
+    pub async fn resolve(
+        &self,
+        domain: &str,
+        record_type: RecordType,
+        client_ip: IpAddr,
+    ) -> Result<DnsResolution, DomainError> {
+        let domain = DnsRequest::normalize_domain(domain);
+        self.query(&DnsRequest::new(domain.as_ref(), record_type, client_ip))
+            .await
+    }
+
+    /// Like `resolve`, for callers that need to set request fields such as
+    /// the listener group.
+    pub async fn query(&self, request: &DnsRequest) -> Result<DnsResolution, DomainError> {
+        self.handler.execute(request).await
+    }
+}
+
+pub struct ResolverBuilder {
+    config: Config,
+}
+
+impl ResolverBuilder {
+    fn new(config: Config) -> Self {
+        Self { config }
+    }
+
+    /// SQLite file holding groups, blocklists and local records. Created
+    /// and migrated if missing. Defaults to `database.path` of the config.
+    pub fn database_path(mut self, path: impl Into<String>) -> Self {
+        self.config.database.path = path.into();
+        self
+    }
+
+    /// Whether resolved queries are written to the query log.
+    pub fn log_queries(mut self, enabled: bool) -> Self {
+        self.config.database.log_queries = enabled;
+        self
+    }
+
+    pub async fn build(self) -> anyhow::Result<Resolver> {
+        let mut config = self.config;
+        config.normalize_pools();
+        config.validate()?;
+
+        ferrous_dns_infrastructure::dns::cache::coarse_clock::start_clock_ticker();
+
+        let database_url = format!("sqlite:{}", config.database.path);
+        let (write_pool, query_log_pool, read_pool) =
+            bootstrap::init_database(&database_url, &config.database).await?;
+        let repos = wiring::Repositories::new(
+            write_pool,
+            query_log_pool,
+            read_pool,
+            &config.database,
+            &config.blocking,
+        )
+        .await?;
+        let dns_services = wiring::DnsServices::new(&config, &repos).await?;
+
+        Ok(Resolver {
+            handler: dns_services.handler_use_case,
+        })
+    }
+}
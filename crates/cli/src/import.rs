@@ -0,0 +1,92 @@
+//! `ferrous-dns import`: translate a Pi-hole or AdGuard Home export into the
+//! Ferrous database and config file without starting the server.
+
+use crate::args::ImportArgs;
+use crate::bootstrap;
+use anyhow::Context;
+use ferrous_dns_application::ports::ExternalFormat;
+use ferrous_dns_application::use_cases::{CreateLocalRecordUseCase, ImportExternalConfigUseCase};
+use ferrous_dns_domain::Config;
+use ferrous_dns_infrastructure::dns::LocalZoneStore;
+use ferrous_dns_infrastructure::external_import::ExternalConfigFileReader;
+use ferrous_dns_infrastructure::repositories::{
+    SqliteBlocklistSourceRepository, SqliteClientRepository, SqliteClientSubnetRepository,
+    SqliteGroupRepository, SqliteLocalRecordRepository, SqliteManagedDomainRepository,
+    SqliteRegexFilterRepository, SqliteWhitelistSourceRepository,
+};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+pub async fn run(args: &ImportArgs, config: Config) -> anyhow::Result<()> {
+    let format: ExternalFormat = args.format.parse().map_err(|e| anyhow::anyhow!("{}", e))?;
+    let bytes = std::fs::read(&args.file)
+        .with_context(|| format!("Failed to read {}", args.file.display()))?;
+
+    let database_url = format!("sqlite:{}", config.database.path);
+    let (write_pool, _, _) = bootstrap::init_database(&database_url, &config.database).await?;
+
+    let db_config = config.database.clone();
+    let local_record_repo = Arc::new(SqliteLocalRecordRepository::new(write_pool.clone()));
+    let local_zone =
+        LocalZoneStore::new(local_record_repo.clone(), config.dns.local_domain.clone()).await?;
+    let config = Arc::new(RwLock::new(config));
+
+    let use_case = ImportExternalConfigUseCase::new(
+        Arc::new(ExternalConfigFileReader),
+        config.clone(),
+        Arc::new(SqliteGroupRepository::new(write_pool.clone())),
+        Arc::new(SqliteBlocklistSourceRepository::new(write_pool.clone())),
+        Arc::new(SqliteWhitelistSourceRepository::new(write_pool.clone())),
+        Arc::new(SqliteManagedDomainRepository::new(write_pool.clone())),
+        Arc::new(SqliteRegexFilterRepository::new(write_pool.clone())),
+        Arc::new(SqliteClientRepository::new(write_pool.clone(), &db_config)),
+        Arc::new(SqliteClientSubnetRepository::new(write_pool.clone())),
+        Arc::new(CreateLocalRecordUseCase::new(
+            config,
+            local_record_repo,
+            local_zone,
+        )),
+    );
+
+    let summary = use_case
+        .execute(format, &bytes)
+        .await
+        .map_err(|e| anyhow::anyhow!("Import failed: {}", e))?;
+    write_pool.close().await;
+
+    println!(
+        "Imported from {} ({})",
+        args.file.display(),
+        format.as_str()
+    );
+    let rows = [
+        ("Groups", summary.groups_imported, summary.groups_skipped),
+        ("Lists", summary.lists_imported, summary.lists_skipped),
+        ("Domains", summary.domains_imported, summary.domains_skipped),
+        (
+            "Regex filters",
+            summary.regex_filters_imported,
+            summary.regex_filters_skipped,
+        ),
+        (
+            "Local records",
+            summary.local_records_imported,
+            summary.local_records_skipped,
+        ),
+        ("Clients", summary.clients_imported, summary.clients_skipped),
+    ];
+    for (label, imported, skipped) in rows {
+        println!(
+            "  {:<14} {:>6} imported, {:>6} skipped",
+            label, imported, skipped
+        );
+    }
+    if !summary.warnings.is_empty() {
+        println!("Warnings ({}):", summary.warnings.len());
+        for warning in &summary.warnings {
+            println!("  - {}", warning);
+        }
+    }
+    println!("Restart Ferrous DNS (or reload blocklists) if it is running.");
+    Ok(())
+}
@@ -0,0 +1,18 @@
+//! Ferrous DNS as a library.
+//!
+//! [`Resolver`] builds the same resolver stack as the `ferrous-dns` server
+//! and answers queries in-process, for proxies and tests that embed the
+//! engine. Build with `default-features = false` to leave out the REST,
+//! Pi-hole, gRPC and DoH servers.
+
+#[doc(hidden)]
+pub mod bootstrap;
+mod embed;
+#[doc(hidden)]
+pub mod server;
+#[doc(hidden)]
+pub mod wiring;
+
+pub use embed::{Resolver, ResolverBuilder};
+pub use ferrous_dns_application::ports::DnsResolution;
+pub use ferrous_dns_domain::{Config, DnsRequest, DomainError, RecordType};
@@ -5,8 +5,11 @@ static GLOBAL: MiMalloc = MiMalloc;
 
 use anyhow::Context;
 use clap::Parser;
+use ferrous_dns::{bootstrap, server, wiring};
+use ferrous_dns_application::use_cases::LabelForwardersUseCase;
 use ferrous_dns_domain::CliOverrides;
 use ferrous_dns_infrastructure::dns::server::DnsServerHandler;
+use ferrous_dns_infrastructure::dns::{ChaosIdentity, EdnsClientIdentifier, SinkholeProtocol};
 use std::net::SocketAddr;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
@@ -14,9 +17,9 @@ use tokio::sync::RwLock;
 use tracing::{error, info};
 
 mod args;
-mod bootstrap;
-mod server;
-mod wiring;
+mod bench;
+mod import;
+mod replay;
 
 fn main() -> anyhow::Result<()> {
     let core_ids = core_affinity::get_core_ids().unwrap_or_default();
@@ -54,11 +57,24 @@ async fn async_main() -> anyhow::Result<()> {
         bind_address: cli.bind.clone(),
         database_path: cli.database.clone(),
         log_level: cli.log_level.clone(),
+        upstream_preset: cli.upstream_preset,
     };
 
     let config = bootstrap::load_config(cli.config.as_deref(), cli_overrides)?;
 
-    bootstrap::init_logging(&config);
+    let telemetry_guard = bootstrap::init_logging(&config);
+
+    if let Some(args::Command::Bench(bench_args)) = &cli.command {
+        return bench::run(bench_args, config).await;
+    }
+
+    if let Some(args::Command::Import(import_args)) = &cli.command {
+        return import::run(import_args, config).await;
+    }
+
+    if let Some(args::Command::Replay(replay_args)) = &cli.command {
+        return replay::run(replay_args, &config).await;
+    }
 
     info!("Starting Ferrous DNS Server v{}", env!("CARGO_PKG_VERSION"));
 
@@ -76,30 +92,78 @@ async fn async_main() -> anyhow::Result<()> {
         query_log_pool,
         read_pool,
         &config.database,
-        config.blocking.enabled,
+        &config.blocking,
     )
     .await?;
     let mut dns_services = wiring::DnsServices::new(&config, &repos).await?;
+    let edns_client_id =
+        EdnsClientIdentifier::from_config(&config.dns.edns_client_id).map(Arc::new);
     let use_cases = wiring::UseCases::new(
         &repos,
         dns_services.pool_manager.clone(),
         config.dns.local_dns_server.clone(),
+        dns_services.client_ptr_registry.clone(),
+        edns_client_id
+            .clone()
+            .map(|id| id as Arc<dyn ferrous_dns_application::ports::DeviceAddressRegistry>),
+        config.database.max_size_mb,
+        config.database.audit_log_retention_days,
     );
 
     let tunneling_eviction_job = dns_services.tunneling_eviction_job.take();
     let nxdomain_hijack_job = dns_services.nxdomain_hijack_eviction_job.take();
     let response_ip_filter_job = dns_services.response_ip_filter_eviction_job.take();
     let dga_eviction_job = dns_services.dga_eviction_job.take();
+    let secondary_zone_job = dns_services.secondary_zone_refresh_job.take();
+    let upstream_address_job = dns_services.upstream_address_refresh_job.take();
+    let upstream_health: Arc<dyn ferrous_dns_application::ports::UpstreamHealthPort> =
+        Arc::new(ferrous_dns_infrastructure::dns::UpstreamHealthAdapter::new(
+            dns_services.pool_manager.clone(),
+            dns_services.health_checker.clone(),
+        ));
+
+    let acme_services =
+        wiring::AcmeServices::new(&config, &repos, dns_services.local_zone.clone()).await?;
+    let acme_cert_resolver = acme_services
+        .as_ref()
+        .map(|acme| acme.cert_resolver.clone());
+
+    let record_source_jobs = dns_services.record_source_jobs(&config, config_arc.clone());
+
+    let effective_config_path: Option<Arc<str>> =
+        cli.config.as_deref().map(Arc::from).or_else(|| {
+            ferrous_dns_domain::Config::get_config_path().map(|p| Arc::from(p.as_str()))
+        });
+
+    let standby = wiring::StandbyServices::new(
+        &config,
+        config_arc.clone(),
+        effective_config_path.as_deref(),
+        &repos,
+        &dns_services,
+    )?;
+    let standby_sync = standby.as_ref().map(|standby| standby.sync.clone());
+
     let runner = bootstrap::build_job_runner(
         &use_cases,
         &repos,
         &config,
         wal_pool,
         dns_services.cache_maintenance.clone(),
+        upstream_health.clone(),
         tunneling_eviction_job,
         nxdomain_hijack_job,
         response_ip_filter_job,
         dga_eviction_job,
+        secondary_zone_job,
+        acme_services.map(|acme| acme.renewal_job),
+        upstream_address_job,
+        record_source_jobs,
+        standby.map(|standby| standby.sync_job),
+        config.dns.dnssec_enabled.then(|| {
+            dns_services.cache.dnssec_cache()
+                as Arc<dyn ferrous_dns_application::ports::DnssecStatusPort>
+        }),
     );
 
     runner.start().await;
@@ -110,16 +174,13 @@ async fn async_main() -> anyhow::Result<()> {
     }
     info!("Subnet matcher cache loaded");
 
-    let effective_config_path: Option<Arc<str>> =
-        cli.config.as_deref().map(Arc::from).or_else(|| {
-            ferrous_dns_domain::Config::get_config_path().map(|p| Arc::from(p.as_str()))
-        });
-
-    let upstream_health: Arc<dyn ferrous_dns_application::ports::UpstreamHealthPort> =
-        Arc::new(ferrous_dns_infrastructure::dns::UpstreamHealthAdapter::new(
-            dns_services.pool_manager.clone(),
-            dns_services.health_checker.clone(),
-        ));
+    let forwarder_labels = config.dns.edns_client_id.labelled_forwarders();
+    if config.dns.edns_client_id.enabled && !forwarder_labels.is_empty() {
+        let label_forwarders = LabelForwardersUseCase::new(repos.client.clone());
+        if let Err(e) = label_forwarders.execute(&forwarder_labels).await {
+            error!(error = %e, "Failed to label forwarder clients");
+        }
+    }
 
     let pihole_state = wiring::build_pihole_state(
         &use_cases,
@@ -129,48 +190,145 @@ async fn async_main() -> anyhow::Result<()> {
         effective_config_path.clone(),
     );
 
+    let listeners = wiring::build_listeners(&config, &repos, &dns_services.access_control).await;
+    let default_policy = wiring::default_listener_policy(&config, &dns_services.access_control);
+
+    let dynamic_updates =
+        dns_services.dynamic_update_handler(&config, config_arc.clone(), &repos)?;
+    let chaos_identity = ChaosIdentity::from_config(&config.dns.chaos_identity).map(Arc::new);
+
+    let block_page =
+        wiring::BlockPageServices::new(&config, &repos, &dns_services, &use_cases).await?;
+
     let app_state = wiring::build_app_state(
         use_cases,
         &repos,
         &dns_services,
         config_arc,
         effective_config_path,
+        telemetry_guard.log_level_control(),
+        standby_sync,
     )
     .await;
 
-    let dns_addr = format!("{}:{}", config.server.bind_address, config.server.dns_port);
+    if let Some(grpc_port) = config.server.grpc_port {
+        let grpc_addr: SocketAddr = format!("{}:{}", config.server.bind_address, grpc_port)
+            .parse()
+            .context("Invalid gRPC bind address")?;
+        let grpc_state = wiring::build_grpc_state(&app_state);
+        tokio::spawn(async move {
+            if let Err(e) = server::start_grpc_server(grpc_addr, grpc_state).await {
+                error!(error = %e, "gRPC server error");
+            }
+        });
+    }
+
     let handler_use_case = dns_services.handler_use_case;
+    let sinkhole = dns_services.sinkhole;
+    if let Some(ref sinkhole) = sinkhole {
+        // The block page answers HTTPS only with its CA; the telemetry
+        // responder keeps the protocols it does not serve.
+        let block_page_protocols: &[SinkholeProtocol] = match &block_page {
+            Some(page) if page.cert_resolver.is_some() => {
+                &[SinkholeProtocol::Http, SinkholeProtocol::Https]
+            }
+            Some(_) => &[SinkholeProtocol::Http],
+            None => &[],
+        };
+
+        if let Some(ref page) = block_page {
+            let page_config = &config.blocking.sinkhole.block_page;
+            for ip in sinkhole.addresses() {
+                let bind_addr = SocketAddr::new(ip, page_config.http_port);
+                let state = page.state.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = server::start_block_page_server(bind_addr, state).await {
+                        error!(error = %e, bind_address = %bind_addr, "Block page error");
+                    }
+                });
+
+                if let Some(ref cert_resolver) = page.cert_resolver {
+                    let bind_addr = SocketAddr::new(ip, page_config.https_port);
+                    let state = page.state.clone();
+                    let cert_resolver = cert_resolver.clone();
+                    tokio::spawn(async move {
+                        if let Err(e) =
+                            server::start_https_block_page_server(bind_addr, state, cert_resolver)
+                                .await
+                        {
+                            error!(error = %e, bind_address = %bind_addr, "HTTPS block page error");
+                        }
+                    });
+                }
+            }
+        }
+
+        if config.blocking.sinkhole.telemetry.enabled {
+            let telemetry_config = &config.blocking.sinkhole.telemetry;
+            for ip in sinkhole.addresses() {
+                for (port, protocol) in [
+                    (telemetry_config.http_port, SinkholeProtocol::Http),
+                    (telemetry_config.https_port, SinkholeProtocol::Https),
+                ] {
+                    if block_page_protocols.contains(&protocol) {
+                        continue;
+                    }
+                    let telemetry = dns_services.sinkhole_telemetry.clone();
+                    let bind_addr = SocketAddr::new(ip, port);
+                    tokio::spawn(async move {
+                        if let Err(e) =
+                            server::start_sinkhole_responder(bind_addr, protocol, telemetry).await
+                        {
+                            error!(error = %e, bind_address = %bind_addr, "Sinkhole responder error");
+                        }
+                    });
+                }
+            }
+        }
+    }
     let tcp_conn_limiter = dns_services.tcp_conn_limiter;
     let dot_conn_limiter = dns_services.dot_conn_limiter;
-    let dns_handler = DnsServerHandler::new(handler_use_case.clone());
     let core_ids_for_dns = core_affinity::get_core_ids().unwrap_or_default();
     let num_dns_workers = core_ids_for_dns.len().max(1);
 
     let proxy_protocol_enabled = config.server.proxy_protocol_enabled;
-    tokio::spawn(async move {
-        if let Err(e) = server::start_dns_server(
-            dns_addr,
-            dns_handler,
-            num_dns_workers,
-            proxy_protocol_enabled,
-            core_ids_for_dns,
-            tcp_conn_limiter,
-        )
-        .await
-        {
-            error!(error = %e, "DNS server error");
-        }
-    });
+    for listener in listeners {
+        let dns_handler = DnsServerHandler::new(handler_use_case.clone())
+            .with_listener_policy(listener.policy)
+            .with_sinkhole(sinkhole.clone())
+            .with_dynamic_updates(dynamic_updates.clone())
+            .with_chaos_identity(chaos_identity.clone())
+            .with_edns_client_id(edns_client_id.clone())
+            .with_response_limits(&config.dns.response_limits);
+        let core_ids = core_ids_for_dns.clone();
+        let tcp_limiter = tcp_conn_limiter.clone();
+        tokio::spawn(async move {
+            if let Err(e) = server::start_dns_server(
+                listener.bind_addr.clone(),
+                dns_handler,
+                num_dns_workers,
+                proxy_protocol_enabled,
+                core_ids,
+                tcp_limiter,
+            )
+            .await
+            {
+                error!(error = %e, bind_address = %listener.bind_addr, "DNS server error");
+            }
+        });
+    }
 
     let tls_config =
-        if config.server.encrypted_dns.dot_enabled || config.server.encrypted_dns.doh_enabled {
+        if !(config.server.encrypted_dns.dot_enabled || config.server.encrypted_dns.doh_enabled) {
+            None
+        } else if let Some(resolver) = acme_cert_resolver.clone() {
+            Some(server::reloadable_server_tls_config(resolver))
+        } else {
             server::load_server_tls_config(
                 &config.server.encrypted_dns.tls_cert_path,
                 &config.server.encrypted_dns.tls_key_path,
                 "DoT/DoH",
             )?
-        } else {
-            None
         };
 
     if config.server.encrypted_dns.dot_enabled {
@@ -179,7 +337,15 @@ async fn async_main() -> anyhow::Result<()> {
                 "{}:{}",
                 config.server.bind_address, config.server.encrypted_dns.dot_port
             );
-            let dot_handler = Arc::new(DnsServerHandler::new(handler_use_case.clone()));
+            let dot_handler = Arc::new(
+                DnsServerHandler::new(handler_use_case.clone())
+                    .with_listener_policy(default_policy.clone())
+                    .with_sinkhole(sinkhole.clone())
+                    .with_dynamic_updates(dynamic_updates.clone())
+                    .with_chaos_identity(chaos_identity.clone())
+                    .with_edns_client_id(edns_client_id.clone())
+                    .with_response_limits(&config.dns.response_limits),
+            );
             tokio::spawn(async move {
                 if let Err(e) = server::start_dot_server(
                     dot_addr,
@@ -203,8 +369,15 @@ async fn async_main() -> anyhow::Result<()> {
                 let doh_addr: SocketAddr = format!("{}:{}", config.server.bind_address, doh_port)
                     .parse()
                     .context("Invalid DoH bind address")?;
-                let dedicated_doh_handler =
-                    Arc::new(DnsServerHandler::new(handler_use_case.clone()));
+                let dedicated_doh_handler = Arc::new(
+                    DnsServerHandler::new(handler_use_case.clone())
+                        .with_listener_policy(default_policy.clone())
+                        .with_sinkhole(sinkhole.clone())
+                        .with_dynamic_updates(dynamic_updates.clone())
+                        .with_chaos_identity(chaos_identity.clone())
+                        .with_edns_client_id(edns_client_id.clone())
+                        .with_response_limits(&config.dns.response_limits),
+                );
                 tokio::spawn(async move {
                     if let Err(e) = server::start_doh_server(doh_addr, dedicated_doh_handler).await
                     {
@@ -214,20 +387,46 @@ async fn async_main() -> anyhow::Result<()> {
             }
             None
         } else {
-            tls_config.map(|_| Arc::new(DnsServerHandler::new(handler_use_case)))
+            tls_config.map(|_| {
+                Arc::new(
+                    DnsServerHandler::new(handler_use_case)
+                        .with_listener_policy(default_policy)
+                        .with_sinkhole(sinkhole)
+                        .with_dynamic_updates(dynamic_updates)
+                        .with_chaos_identity(chaos_identity)
+                        .with_edns_client_id(edns_client_id)
+                        .with_response_limits(&config.dns.response_limits),
+                )
+            })
         }
     } else {
         None
     };
 
-    let web_tls_config = if config.server.web_tls.enabled {
-        server::load_server_tls_config(
-            &config.server.web_tls.tls_cert_path,
-            &config.server.web_tls.tls_key_path,
+    let web_tls = &config.server.web_tls;
+    let shares_acme_certificate = web_tls.tls_cert_path
+        == config.server.encrypted_dns.tls_cert_path
+        && web_tls.tls_key_path == config.server.encrypted_dns.tls_key_path;
+    // With DoH on the same port, clients without a certificate must still
+    // complete the handshake; the admin routes refuse them instead.
+    let client_verifier = match &config.server.admin_access.client_ca_path {
+        Some(ca_path) => server::load_client_cert_verifier(ca_path, doh_handler.is_some())?,
+        None => rustls::server::WebPkiClientVerifier::no_client_auth(),
+    };
+    let web_tls_config = if !web_tls.enabled {
+        None
+    } else if let Some(resolver) = acme_cert_resolver.filter(|_| shares_acme_certificate) {
+        Some(server::reloadable_server_tls_config_with_client_auth(
+            resolver,
+            client_verifier,
+        ))
+    } else {
+        server::load_server_tls_config_with_client_auth(
+            &web_tls.tls_cert_path,
+            &web_tls.tls_key_path,
             "Web HTTPS",
+            client_verifier,
         )?
-    } else {
-        None
     };
 
     let web_addr: SocketAddr = format!("{}:{}", config.server.bind_address, config.server.web_port)
@@ -242,6 +441,8 @@ async fn async_main() -> anyhow::Result<()> {
         config.server.pihole_compat,
         doh_handler,
         web_tls_config,
+        server::AdminAccessGuard::new(&config.server.admin_access),
+        &config.server.api_limits,
     )
     .await?;
 
@@ -0,0 +1,65 @@
+//! `ferrous-dns replay`: send the client queries recorded in a query log to a
+//! server again, keeping their order and relative timing, and compare the
+//! block decisions and latencies with the recorded ones.
+
+mod network;
+mod report;
+mod source;
+
+use crate::args::ReplayArgs;
+use ferrous_dns_domain::Config;
+use std::path::PathBuf;
+use std::time::Instant;
+
+pub async fn run(args: &ReplayArgs, config: &Config) -> anyhow::Result<()> {
+    let path = args
+        .from
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(&config.database.path));
+    let queries = source::load(&path, args.since.as_deref(), args.limit).await?;
+    if queries.is_empty() {
+        println!("No client queries to replay in {}", path.display());
+        return Ok(());
+    }
+
+    let start = Instant::now();
+    let outcomes = network::run(args, &queries).await?;
+    let elapsed = start.elapsed();
+
+    report::print_report(args, &path, elapsed, &queries, &outcomes);
+    Ok(())
+}
+
+/// Parses a `--speed` value: a positive factor with an optional `x` suffix.
+pub fn parse_speed(value: &str) -> Result<f64, String> {
+    let factor = value.trim();
+    let factor = factor
+        .strip_suffix(['x', 'X'])
+        .unwrap_or(factor)
+        .parse::<f64>()
+        .map_err(|_| format!("invalid speed '{value}', expected a factor such as 2x"))?;
+    if !factor.is_finite() || factor <= 0.0 {
+        return Err(format!("speed must be greater than zero, got '{value}'"));
+    }
+    Ok(factor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_speed_accepts_factor_with_or_without_suffix() {
+        assert_eq!(parse_speed("2x"), Ok(2.0));
+        assert_eq!(parse_speed("0.5X"), Ok(0.5));
+        assert_eq!(parse_speed("3"), Ok(3.0));
+    }
+
+    #[test]
+    fn parse_speed_rejects_zero_negative_and_garbage() {
+        assert!(parse_speed("0x").is_err());
+        assert!(parse_speed("-1").is_err());
+        assert!(parse_speed("fast").is_err());
+        assert!(parse_speed("infx").is_err());
+    }
+}
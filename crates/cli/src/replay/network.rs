@@ -0,0 +1,162 @@
+use super::source::LoggedQuery;
+use crate::args::ReplayArgs;
+use crate::bench::{connect, is_blocked};
+use anyhow::Context;
+use ferrous_dns_infrastructure::dns::forwarding::MessageBuilder;
+use hickory_proto::op::{Message, ResponseCode};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::net::UdpSocket;
+use tokio::sync::oneshot;
+
+/// How the target answered one replayed query.
+#[derive(Debug, Clone, Copy)]
+pub struct ReplayOutcome {
+    pub response_code: ResponseCode,
+    pub blocked: bool,
+    pub latency_us: u32,
+}
+
+/// Query index plus one, indexed by DNS message ID; zero marks a free slot.
+type PendingQueries = Arc<[AtomicU64]>;
+
+/// Sends every query at its recorded offset divided by `--speed` and returns
+/// the outcome of each, `None` for the ones left unanswered.
+pub async fn run(
+    args: &ReplayArgs,
+    queries: &[LoggedQuery],
+) -> anyhow::Result<Vec<Option<ReplayOutcome>>> {
+    let workers = args.concurrency.max(1);
+    let timeout = Duration::from_millis(args.timeout_ms);
+
+    let mut sockets = Vec::with_capacity(workers);
+    for _ in 0..workers {
+        sockets.push(connect(args.target).await?);
+    }
+    let sent_at: Arc<[AtomicU64]> = queries.iter().map(|_| AtomicU64::new(0)).collect();
+    let pending: Vec<PendingQueries> = sockets
+        .iter()
+        .map(|_| (0..=u16::MAX).map(|_| AtomicU64::new(0)).collect())
+        .collect();
+
+    let start = Instant::now();
+    let mut receivers = Vec::with_capacity(workers);
+    let mut stops = Vec::with_capacity(workers);
+    for (socket, pending) in sockets.iter().zip(&pending) {
+        let (stop, stopped) = oneshot::channel();
+        stops.push(stop);
+        receivers.push(tokio::spawn(receive_responses(
+            socket.clone(),
+            pending.clone(),
+            sent_at.clone(),
+            start,
+            timeout,
+            stopped,
+        )));
+    }
+
+    send_queries(queries, &sockets, &pending, &sent_at, args.speed, start).await?;
+    tokio::time::sleep(timeout).await;
+    for stop in stops {
+        let _ = stop.send(());
+    }
+
+    let mut outcomes = vec![None; queries.len()];
+    for receiver in receivers {
+        for (index, outcome) in receiver.await? {
+            outcomes[index] = Some(outcome);
+        }
+    }
+    Ok(outcomes)
+}
+
+async fn send_queries(
+    queries: &[LoggedQuery],
+    sockets: &[Arc<UdpSocket>],
+    pending: &[PendingQueries],
+    sent_at: &[AtomicU64],
+    speed: f64,
+    start: Instant,
+) -> anyhow::Result<()> {
+    let mut next_ids = vec![0u16; sockets.len()];
+
+    for (index, query) in queries.iter().enumerate() {
+        let due = start + query.offset.div_f64(speed);
+        if due > Instant::now() {
+            tokio::time::sleep_until(due.into()).await;
+        }
+
+        let worker = index % sockets.len();
+        let mut wire = MessageBuilder::build_query(&query.domain, &query.record_type, false)
+            .with_context(|| format!("Cannot build a query for '{}'", query.domain))?;
+        let id = next_ids[worker];
+        next_ids[worker] = id.wrapping_add(1);
+        wire[..2].copy_from_slice(&id.to_be_bytes());
+
+        sent_at[index].store(elapsed_ns(start), Ordering::Relaxed);
+        let slot = &pending[worker][usize::from(id)];
+        slot.store(index as u64 + 1, Ordering::Relaxed);
+        if sockets[worker].send(&wire).await.is_err() {
+            slot.store(0, Ordering::Relaxed);
+        }
+    }
+
+    Ok(())
+}
+
+async fn receive_responses(
+    socket: Arc<UdpSocket>,
+    pending: PendingQueries,
+    sent_at: Arc<[AtomicU64]>,
+    start: Instant,
+    timeout: Duration,
+    mut stopped: oneshot::Receiver<()>,
+) -> Vec<(usize, ReplayOutcome)> {
+    let mut outcomes = Vec::new();
+    let mut buf = vec![0u8; 4096];
+
+    loop {
+        let len = tokio::select! {
+            received = socket.recv(&mut buf) => match received {
+                Ok(len) => len,
+                Err(_) => continue,
+            },
+            _ = &mut stopped => break,
+        };
+        if len < 12 {
+            continue;
+        }
+
+        let id = u16::from_be_bytes([buf[0], buf[1]]);
+        let slot = pending[usize::from(id)].swap(0, Ordering::Relaxed);
+        if slot == 0 {
+            continue;
+        }
+        let index = (slot - 1) as usize;
+        let latency = Duration::from_nanos(
+            elapsed_ns(start).saturating_sub(sent_at[index].load(Ordering::Relaxed)),
+        );
+        if latency > timeout {
+            continue;
+        }
+        let Ok(message) = Message::from_vec(&buf[..len]) else {
+            continue;
+        };
+
+        outcomes.push((
+            index,
+            ReplayOutcome {
+                response_code: message.response_code(),
+                blocked: is_blocked(&message),
+                latency_us: latency.as_micros().min(u32::MAX as u128) as u32,
+            },
+        ));
+    }
+
+    outcomes
+}
+
+fn elapsed_ns(start: Instant) -> u64 {
+    start.elapsed().as_nanos() as u64 + 1
+}
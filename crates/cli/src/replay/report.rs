@@ -0,0 +1,178 @@
+use super::network::ReplayOutcome;
+use super::source::LoggedQuery;
+use crate::args::ReplayArgs;
+use crate::bench::{percentile, ratio};
+use hickory_proto::op::ResponseCode;
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::Duration;
+
+/// Names listed under each kind of changed block decision.
+const TOP_CHANGES: usize = 10;
+
+/// Queries whose block decision differs from the recorded one, counted per
+/// name and type.
+#[derive(Default)]
+struct ChangedDecisions {
+    total: u64,
+    by_query: HashMap<(&'static str, String), u64>,
+}
+
+impl ChangedDecisions {
+    fn record(&mut self, query: &LoggedQuery) {
+        self.total += 1;
+        *self
+            .by_query
+            .entry((query.record_type.as_str(), query.domain.clone()))
+            .or_default() += 1;
+    }
+
+    fn print(&self, label: &str) {
+        if self.total == 0 {
+            return;
+        }
+        let mut top: Vec<_> = self.by_query.iter().collect();
+        top.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+
+        println!("{label}:");
+        for ((record_type, domain), count) in top.into_iter().take(TOP_CHANGES) {
+            println!("  {count:>6}  {domain} {record_type}");
+        }
+    }
+}
+
+pub fn print_report(
+    args: &ReplayArgs,
+    source: &Path,
+    elapsed: Duration,
+    queries: &[LoggedQuery],
+    outcomes: &[Option<ReplayOutcome>],
+) {
+    let mut recorded_latencies = Vec::new();
+    let mut replay_latencies = Vec::new();
+    let (mut noerror, mut nxdomain, mut servfail, mut refused, mut other) = (0, 0, 0, 0, 0);
+    let (mut recorded_blocked, mut replay_blocked) = (0u64, 0u64);
+    let mut newly_blocked = ChangedDecisions::default();
+    let mut newly_allowed = ChangedDecisions::default();
+
+    for (query, outcome) in queries.iter().zip(outcomes) {
+        let Some(outcome) = outcome else {
+            continue;
+        };
+        match outcome.response_code {
+            ResponseCode::NoError => noerror += 1,
+            ResponseCode::NXDomain => nxdomain += 1,
+            ResponseCode::ServFail => servfail += 1,
+            ResponseCode::Refused => refused += 1,
+            _ => other += 1,
+        }
+        replay_latencies.push(outcome.latency_us);
+        if let Some(recorded) = query.response_time_us {
+            recorded_latencies.push(recorded);
+        }
+
+        recorded_blocked += u64::from(query.blocked);
+        replay_blocked += u64::from(outcome.blocked);
+        match (query.blocked, outcome.blocked) {
+            (false, true) => newly_blocked.record(query),
+            (true, false) => newly_allowed.record(query),
+            _ => {}
+        }
+    }
+    recorded_latencies.sort_unstable();
+    replay_latencies.sort_unstable();
+
+    let sent = queries.len() as u64;
+    let answered = replay_latencies.len() as u64;
+    let timeouts = sent - answered;
+    let unchanged = answered - newly_blocked.total - newly_allowed.total;
+
+    println!();
+    println!(
+        "Replay:     {} against {} at {}x",
+        source.display(),
+        args.target,
+        args.speed
+    );
+    println!(
+        "Duration:   {:.1}s, recorded span {:.1}s",
+        elapsed.as_secs_f64(),
+        queries
+            .last()
+            .map_or(0.0, |query| query.offset.as_secs_f64())
+    );
+    println!(
+        "Queries:    {} sent, {} answered ({:.2}%), {} timed out ({:.2}%)",
+        sent,
+        answered,
+        ratio(answered, sent),
+        timeouts,
+        ratio(timeouts, sent)
+    );
+    println!(
+        "Responses:  NOERROR {}  NXDOMAIN {}  SERVFAIL {}  REFUSED {}  other {}",
+        noerror, nxdomain, servfail, refused, other
+    );
+    println!(
+        "Blocked:    recorded {} ({:.2}%), replay {} ({:.2}%) of answered",
+        recorded_blocked,
+        ratio(recorded_blocked, answered),
+        replay_blocked,
+        ratio(replay_blocked, answered)
+    );
+    println!(
+        "Decisions:  {} unchanged ({:.2}%), {} newly blocked, {} newly allowed",
+        unchanged,
+        ratio(unchanged, answered),
+        newly_blocked.total,
+        newly_allowed.total
+    );
+    print_latencies("recorded", &recorded_latencies);
+    print_latencies("replay  ", &replay_latencies);
+
+    newly_blocked.print("Newly blocked");
+    newly_allowed.print("Newly allowed");
+}
+
+fn print_latencies(label: &str, sorted: &[u32]) {
+    println!(
+        "Latency µs: {}  p50 {}  p90 {}  p99 {}  p99.9 {}  max {}",
+        label,
+        percentile(sorted, 50.0),
+        percentile(sorted, 90.0),
+        percentile(sorted, 99.0),
+        percentile(sorted, 99.9),
+        sorted.last().copied().unwrap_or(0)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ferrous_dns_domain::RecordType;
+
+    fn logged(domain: &str, record_type: RecordType) -> LoggedQuery {
+        LoggedQuery {
+            domain: domain.to_string(),
+            record_type,
+            blocked: false,
+            response_time_us: None,
+            offset: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn counts_changed_decisions_per_name_and_type() {
+        let mut changes = ChangedDecisions::default();
+        changes.record(&logged("ads.example.com", RecordType::A));
+        changes.record(&logged("ads.example.com", RecordType::A));
+        changes.record(&logged("ads.example.com", RecordType::AAAA));
+
+        assert_eq!(changes.total, 3);
+        assert_eq!(changes.by_query[&("A", "ads.example.com".to_string())], 2);
+        assert_eq!(
+            changes.by_query[&("AAAA", "ads.example.com".to_string())],
+            1
+        );
+    }
+}
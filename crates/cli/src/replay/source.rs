@@ -0,0 +1,128 @@
+use anyhow::Context;
+use ferrous_dns_domain::RecordType;
+use ferrous_dns_infrastructure::repositories::query_log_repository::query_log_tables;
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+use sqlx::Row;
+use std::path::Path;
+use std::time::Duration;
+
+/// A client query read back from the query log.
+pub struct LoggedQuery {
+    pub domain: String,
+    pub record_type: RecordType,
+    pub blocked: bool,
+    /// Absent for queries logged without a response time.
+    pub response_time_us: Option<u32>,
+    /// Send time relative to the first query, at the recorded pace.
+    pub offset: Duration,
+}
+
+/// Reads the client queries of every query log table in `path`, oldest
+/// first. Queries with a record type the server does not know are skipped.
+pub async fn load(
+    path: &Path,
+    since: Option<&str>,
+    limit: Option<u32>,
+) -> anyhow::Result<Vec<LoggedQuery>> {
+    let options = SqliteConnectOptions::new().filename(path).read_only(true);
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect_with(options)
+        .await
+        .with_context(|| format!("Failed to open query log {}", path.display()))?;
+
+    let tables = query_log_tables(&pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to list query log tables: {}", e))?;
+    if tables.is_empty() {
+        anyhow::bail!("{} has no query log", path.display());
+    }
+
+    let filter = if since.is_some() {
+        "query_source = 'client' AND created_at >= ?"
+    } else {
+        "query_source = 'client'"
+    };
+    let selects: Vec<String> = tables
+        .iter()
+        .map(|table| {
+            format!(
+                "SELECT id, domain, record_type, blocked, response_time_ms, created_at,
+                        CAST(strftime('%s', created_at) AS INTEGER) AS logged_at
+                 FROM {table} WHERE {filter}"
+            )
+        })
+        .collect();
+    let sql = format!(
+        "{} ORDER BY created_at ASC, id ASC LIMIT ?",
+        selects.join(" UNION ALL ")
+    );
+
+    let mut query = sqlx::query(&sql);
+    if let Some(since) = since {
+        for _ in &tables {
+            query = query.bind(since);
+        }
+    }
+    let rows = query
+        .bind(limit.map_or(-1, i64::from))
+        .fetch_all(&pool)
+        .await
+        .context("Failed to read the query log")?;
+    pool.close().await;
+
+    let mut logged_at = Vec::with_capacity(rows.len());
+    let mut queries = Vec::with_capacity(rows.len());
+    for row in rows {
+        let record_type: String = row.get("record_type");
+        let Ok(record_type) = record_type.parse::<RecordType>() else {
+            continue;
+        };
+        logged_at.push(row.get::<Option<i64>, _>("logged_at").unwrap_or(0));
+        queries.push(LoggedQuery {
+            domain: row.get("domain"),
+            record_type,
+            blocked: row.get::<i64, _>("blocked") != 0,
+            response_time_us: row
+                .get::<Option<i64>, _>("response_time_ms")
+                .map(|us| us.clamp(0, i64::from(u32::MAX)) as u32),
+            offset: Duration::ZERO,
+        });
+    }
+
+    for (query, offset) in queries.iter_mut().zip(spread_offsets(&logged_at)) {
+        query.offset = offset;
+    }
+    Ok(queries)
+}
+
+/// Offsets from the first of the ascending `logged_at` Unix times. The log
+/// only keeps whole seconds, so the queries of one second are spread evenly
+/// across it instead of being sent as a burst.
+fn spread_offsets(logged_at: &[i64]) -> Vec<Duration> {
+    let first = logged_at.first().copied().unwrap_or(0);
+    let mut offsets = Vec::with_capacity(logged_at.len());
+    for second in logged_at.chunk_by(|a, b| a == b) {
+        let base = (second[0] - first).max(0) as f64;
+        let step = 1.0 / second.len() as f64;
+        offsets.extend((0..second.len()).map(|i| Duration::from_secs_f64(base + i as f64 * step)));
+    }
+    offsets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spreads_queries_logged_in_the_same_second() {
+        let offsets = spread_offsets(&[100, 100, 100, 100, 102]);
+        let millis: Vec<u128> = offsets.iter().map(Duration::as_millis).collect();
+        assert_eq!(millis, vec![0, 250, 500, 750, 2000]);
+    }
+
+    #[test]
+    fn empty_log_has_no_offsets() {
+        assert!(spread_offsets(&[]).is_empty());
+    }
+}
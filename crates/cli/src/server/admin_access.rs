@@ -0,0 +1,73 @@
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::StatusCode,
+    middleware::Next,
+    response::Response,
+};
+use ferrous_dns_domain::{AclAction, AdminAccessConfig};
+use ferrous_dns_infrastructure::dns::AccessControlList;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tracing::debug;
+
+/// Request extension set by the HTTPS server when the connection presented
+/// a client certificate accepted by the configured CA.
+#[derive(Debug, Clone, Copy)]
+pub struct VerifiedClientCertificate;
+
+/// Enforces `[server.admin_access]` on the dashboard and REST API routes.
+#[derive(Clone)]
+pub struct AdminAccessGuard {
+    networks: Arc<AccessControlList>,
+    require_client_certificate: bool,
+}
+
+impl AdminAccessGuard {
+    pub fn new(config: &AdminAccessConfig) -> Self {
+        Self {
+            networks: Arc::new(AccessControlList::new(
+                "admin",
+                &config.allowed_networks,
+                AclAction::Refuse,
+            )),
+            require_client_certificate: config.client_ca_path.is_some(),
+        }
+    }
+
+    /// Whether a request from `peer` may reach the admin routes.
+    pub fn permits(&self, peer: Option<SocketAddr>, client_certificate: bool) -> bool {
+        let network_allowed = match peer {
+            Some(addr) => self.networks.allows(addr.ip()),
+            None => self.networks.allows_everyone(),
+        };
+        network_allowed && (client_certificate || !self.require_client_certificate)
+    }
+}
+
+/// Rejects admin requests from outside `allowed_networks`, or without a
+/// client certificate when mutual TLS is configured, with 403 Forbidden.
+pub async fn enforce_admin_access(
+    State(guard): State<AdminAccessGuard>,
+    request: Request,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    let peer = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|info| info.0);
+    let client_certificate = request
+        .extensions()
+        .get::<VerifiedClientCertificate>()
+        .is_some();
+
+    if !guard.permits(peer, client_certificate) {
+        debug!(
+            client = ?peer,
+            client_certificate,
+            path = request.uri().path(),
+            "Admin API request refused"
+        );
+        return Err(StatusCode::FORBIDDEN);
+    }
+    Ok(next.run(request).await)
+}
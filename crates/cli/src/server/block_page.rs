@@ -0,0 +1,419 @@
+use axum::extract::{ConnectInfo, State};
+use axum::http::{header, HeaderMap, StatusCode, Uri};
+use axum::response::{Html, IntoResponse, Response};
+use axum::routing::post;
+use axum::{Form, Router};
+use ferrous_dns_application::ports::BlockFilterEnginePort;
+use ferrous_dns_application::use_cases::SubmitUnblockRequestUseCase;
+use ferrous_dns_domain::{
+    BlockPageConfig, BlockPageGroupConfig, BlockSource, DomainError, UnblockRequest,
+    UnblockRequestStatus,
+};
+use ferrous_dns_infrastructure::dns::{RecentBlocks, SinkholeProtocol, SinkholeTelemetry};
+use ferrous_dns_infrastructure::tls::BlockPageCertResolver;
+use hyper::body::Incoming;
+use hyper_util::rt::{TokioExecutor, TokioIo};
+use hyper_util::server::conn::auto::Builder;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tokio_rustls::TlsAcceptor;
+use tracing::{debug, error, info, warn};
+
+/// Form target of the unblock request button. Unlikely to clash with a path
+/// of the blocked site, which gets the block page for every other path.
+const UNBLOCK_PATH: &str = "/__ferrous/unblock-request";
+
+/// Block page text and options for one group, with its overrides applied.
+#[derive(Debug, Clone)]
+pub struct BlockPageSettings {
+    pub title: Arc<str>,
+    pub message: Arc<str>,
+    pub contact: Option<Arc<str>>,
+    pub unblock_requests: bool,
+}
+
+impl BlockPageSettings {
+    pub fn from_config(config: &BlockPageConfig) -> Self {
+        Self {
+            title: Arc::from(config.title.as_str()),
+            message: Arc::from(config.message.as_str()),
+            contact: config.contact.as_deref().map(Arc::from),
+            unblock_requests: config.unblock_requests,
+        }
+    }
+
+    pub fn with_group(&self, group: &BlockPageGroupConfig) -> Self {
+        Self {
+            title: group
+                .title
+                .as_deref()
+                .map_or_else(|| self.title.clone(), Arc::from),
+            message: group
+                .message
+                .as_deref()
+                .map_or_else(|| self.message.clone(), Arc::from),
+            contact: group
+                .contact
+                .as_deref()
+                .map(Arc::from)
+                .or_else(|| self.contact.clone()),
+            unblock_requests: group.unblock_requests.unwrap_or(self.unblock_requests),
+        }
+    }
+}
+
+/// Everything the block page needs to answer a client.
+#[derive(Clone)]
+pub struct BlockPageState {
+    pub defaults: Arc<BlockPageSettings>,
+    /// Settings of the groups with overrides, by group ID.
+    pub groups: Arc<HashMap<i64, BlockPageSettings>>,
+    pub block_filter: Arc<dyn BlockFilterEnginePort>,
+    pub recent_blocks: Arc<RecentBlocks>,
+    /// Set when sinkhole telemetry is on; page views count as attempts.
+    pub telemetry: Option<Arc<SinkholeTelemetry>>,
+    pub submit_unblock_request: Arc<SubmitUnblockRequestUseCase>,
+}
+
+impl BlockPageState {
+    fn settings_for(&self, group_id: i64) -> &BlockPageSettings {
+        self.groups.get(&group_id).unwrap_or(&self.defaults)
+    }
+}
+
+#[derive(Clone)]
+struct PageContext {
+    state: BlockPageState,
+    protocol: SinkholeProtocol,
+}
+
+#[derive(Debug, Deserialize)]
+struct UnblockForm {
+    domain: String,
+    #[serde(default)]
+    message: Option<String>,
+}
+
+fn router(state: BlockPageState, protocol: SinkholeProtocol) -> Router {
+    Router::new()
+        .route(UNBLOCK_PATH, post(submit_unblock_request))
+        .fallback(show_block_page)
+        .with_state(PageContext { state, protocol })
+}
+
+/// Serves the block page over plain HTTP on a sinkhole address.
+pub async fn start_block_page_server(
+    bind_addr: SocketAddr,
+    state: BlockPageState,
+) -> anyhow::Result<()> {
+    let listener = TcpListener::bind(bind_addr).await?;
+    info!(bind_address = %bind_addr, "Block page ready");
+
+    let app = router(state, SinkholeProtocol::Http);
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await?;
+    Ok(())
+}
+
+/// Serves the block page over HTTPS on a sinkhole address, with a
+/// certificate for each requested host signed by the block page CA.
+pub async fn start_https_block_page_server(
+    bind_addr: SocketAddr,
+    state: BlockPageState,
+    cert_resolver: Arc<BlockPageCertResolver>,
+) -> anyhow::Result<()> {
+    let mut tls_config = rustls::ServerConfig::builder()
+        .with_no_client_auth()
+        .with_cert_resolver(cert_resolver);
+    tls_config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+    let acceptor = TlsAcceptor::from(Arc::new(tls_config));
+
+    let listener = TcpListener::bind(bind_addr).await?;
+    info!(bind_address = %bind_addr, "HTTPS block page ready");
+
+    let app = router(state, SinkholeProtocol::Https);
+    loop {
+        let (stream, peer_addr) = match listener.accept().await {
+            Ok(conn) => conn,
+            Err(e) => {
+                error!(error = %e, "Block page accept error");
+                continue;
+            }
+        };
+
+        let acceptor = acceptor.clone();
+        let tower_service = app.clone();
+        tokio::spawn(async move {
+            let tls_stream = match acceptor.accept(stream).await {
+                Ok(s) => s,
+                Err(e) => {
+                    debug!(client = %peer_addr, error = %e, "Block page TLS handshake failed");
+                    return;
+                }
+            };
+            let io = TokioIo::new(tls_stream);
+
+            let hyper_svc = hyper::service::service_fn(move |req: hyper::Request<Incoming>| {
+                let mut svc = tower_service.clone();
+                async move {
+                    use tower::Service;
+                    let (mut parts, body) = req.into_parts();
+                    parts.extensions.insert(ConnectInfo(peer_addr));
+                    let req = hyper::Request::from_parts(parts, axum::body::Body::new(body));
+                    svc.call(req).await
+                }
+            });
+
+            if let Err(e) = Builder::new(TokioExecutor::new())
+                .serve_connection(io, hyper_svc)
+                .await
+            {
+                debug!(client = %peer_addr, error = %e, "Block page connection error");
+            }
+        });
+    }
+}
+
+async fn show_block_page(
+    State(context): State<PageContext>,
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
+    uri: Uri,
+    headers: HeaderMap,
+) -> Response {
+    let client = peer_addr.ip();
+    let host = request_host(&uri, &headers);
+    if let (Some(telemetry), Some(host)) = (&context.state.telemetry, &host) {
+        telemetry.record_attempt(client, host, context.protocol);
+    }
+
+    let settings = context
+        .state
+        .settings_for(context.state.block_filter.resolve_group(client));
+    let source = host
+        .as_deref()
+        .and_then(|host| context.state.recent_blocks.source(client, host));
+
+    let mut body = String::new();
+    let domain = host.as_deref().unwrap_or("This site");
+    let _ = write!(
+        body,
+        "<p class=\"domain\">{}</p><p>{}</p>",
+        escape(domain),
+        escape(&settings.message)
+    );
+    if let Some(source) = source {
+        let _ = write!(body, "<p class=\"reason\">Reason: {}</p>", reason(source));
+    }
+    if let Some(contact) = &settings.contact {
+        let _ = write!(body, "<p>Contact: {}</p>", escape(contact));
+    }
+    if settings.unblock_requests {
+        if let Some(host) = &host {
+            let _ = write!(
+                body,
+                "<form method=\"post\" action=\"{UNBLOCK_PATH}\">\
+                 <input type=\"hidden\" name=\"domain\" value=\"{}\">\
+                 <textarea name=\"message\" maxlength=\"500\" \
+                 placeholder=\"Why do you need this site? (optional)\"></textarea>\
+                 <button type=\"submit\">Request unblock</button></form>",
+                escape(host)
+            );
+        }
+    }
+
+    page(StatusCode::FORBIDDEN, &settings.title, &body)
+}
+
+async fn submit_unblock_request(
+    State(context): State<PageContext>,
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
+    Form(form): Form<UnblockForm>,
+) -> Response {
+    let client = peer_addr.ip();
+    let group_id = context.state.block_filter.resolve_group(client);
+    let settings = context.state.settings_for(group_id);
+    if !settings.unblock_requests {
+        return page(
+            StatusCode::FORBIDDEN,
+            &settings.title,
+            "<p>Unblock requests are not accepted on this network.</p>",
+        );
+    }
+
+    let domain = form
+        .domain
+        .trim()
+        .trim_end_matches('.')
+        .to_ascii_lowercase();
+    let mut request =
+        UnblockRequest::new(Arc::from(domain.as_str()), Arc::from(client.to_string()));
+    request.group_id = Some(group_id);
+    request.block_source = context
+        .state
+        .recent_blocks
+        .source(client, &domain)
+        .map(|source| Arc::from(source.to_str()));
+    request.message = form
+        .message
+        .as_deref()
+        .map(str::trim)
+        .filter(|message| !message.is_empty())
+        .map(Arc::from);
+
+    match context.state.submit_unblock_request.execute(request).await {
+        Ok(stored) => {
+            let outcome = match stored.status {
+                UnblockRequestStatus::Pending => {
+                    "Your unblock request was sent to the administrators."
+                }
+                UnblockRequestStatus::Approved => {
+                    "An administrator already approved unblocking this site. \
+                     It may take a moment to take effect."
+                }
+                UnblockRequestStatus::Denied => {
+                    "An administrator already declined unblocking this site."
+                }
+            };
+            page(
+                StatusCode::OK,
+                &settings.title,
+                &format!(
+                    "<p class=\"domain\">{}</p><p>{}</p>",
+                    escape(&domain),
+                    outcome
+                ),
+            )
+        }
+        Err(DomainError::InvalidUnblockRequest(reason)) => page(
+            StatusCode::BAD_REQUEST,
+            &settings.title,
+            &format!("<p>{}</p>", escape(&reason)),
+        ),
+        Err(e) => {
+            warn!(client = %client, domain, error = %e, "Failed to file unblock request");
+            page(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                &settings.title,
+                "<p>The unblock request could not be filed. Please try again later.</p>",
+            )
+        }
+    }
+}
+
+/// Host the client asked for, without the port.
+fn request_host(uri: &Uri, headers: &HeaderMap) -> Option<String> {
+    let host = uri
+        .host()
+        .or_else(|| headers.get(header::HOST)?.to_str().ok())?;
+    let host = match host.strip_prefix('[') {
+        Some(bracketed) => bracketed.split(']').next().unwrap_or(bracketed),
+        None => host.split(':').next().unwrap_or(host),
+    };
+    let host = host.trim_end_matches('.').to_ascii_lowercase();
+    (!host.is_empty()).then_some(host)
+}
+
+fn reason(source: BlockSource) -> &'static str {
+    match source {
+        BlockSource::Blocklist => "listed on a blocklist",
+        BlockSource::ManagedDomain => "blocked by an administrator",
+        BlockSource::RegexFilter => "matched a filter rule",
+        BlockSource::CnameCloaking => "points to a blocked domain",
+        BlockSource::Schedule => "blocked at this time of day",
+        BlockSource::DnsRebinding => "resolved to a private address",
+        BlockSource::RateLimit => "too many lookups from this device",
+        BlockSource::DnsTunneling => "looked like DNS tunneling",
+        BlockSource::NxdomainHijack => "answer was tampered with upstream",
+        BlockSource::ResponseIpFilter => "resolved to a blocked address",
+        BlockSource::DgaDetection => "looked like a generated malware domain",
+        BlockSource::QueryPolicy => "blocked by a network policy",
+        BlockSource::RecordTypeFilter => "lookup type not allowed",
+        BlockSource::Plugin => "blocked by a plugin",
+        BlockSource::TldPolicy => "top-level domain not allowed",
+    }
+}
+
+fn page(status: StatusCode, title: &str, body: &str) -> Response {
+    let title = escape(title);
+    let html = format!(
+        "<!DOCTYPE html><html><head><meta charset=\"utf-8\">\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\
+         <title>{title}</title><style>\
+         body{{font-family:system-ui,sans-serif;max-width:36rem;margin:4rem auto;padding:0 1rem;color:#222}}\
+         .domain{{font-family:monospace;font-size:1.2rem;word-break:break-all}}\
+         .reason{{color:#666}}\
+         textarea{{width:100%;min-height:4rem;margin:.5rem 0}}\
+         </style></head><body><h1>{title}</h1>{body}</body></html>"
+    );
+    (status, [(header::CACHE_CONTROL, "no-store")], Html(html)).into_response()
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_markup() {
+        assert_eq!(
+            escape("<a href=\"x\">&'</a>"),
+            "&lt;a href=&quot;x&quot;&gt;&amp;&#39;&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn host_drops_port_and_trailing_dot() {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::HOST, "Ads.Example.com.:8080".parse().unwrap());
+        let uri: Uri = "/banner".parse().unwrap();
+        assert_eq!(
+            request_host(&uri, &headers).as_deref(),
+            Some("ads.example.com")
+        );
+
+        headers.insert(header::HOST, "[2001:db8::1]:443".parse().unwrap());
+        assert_eq!(request_host(&uri, &headers).as_deref(), Some("2001:db8::1"));
+    }
+
+    #[test]
+    fn group_overrides_keep_unset_fields() {
+        let config = BlockPageConfig {
+            contact: Some("it@example.com".to_string()),
+            ..Default::default()
+        };
+        let defaults = BlockPageSettings::from_config(&config);
+        let kids = defaults.with_group(&BlockPageGroupConfig {
+            group: "Kids".to_string(),
+            title: Some("Not for you".to_string()),
+            message: None,
+            contact: None,
+            unblock_requests: Some(false),
+        });
+
+        assert_eq!(&*kids.title, "Not for you");
+        assert_eq!(kids.message, defaults.message);
+        assert_eq!(kids.contact.as_deref(), Some("it@example.com"));
+        assert!(!kids.unblock_requests);
+    }
+}
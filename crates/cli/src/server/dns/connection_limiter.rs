@@ -9,7 +9,7 @@ use std::sync::Arc;
 /// Uses an RAII guard: when a connection is accepted, `try_acquire()` returns
 /// a `ConnectionGuard` that decrements the count on drop.
 #[derive(Clone)]
-pub(crate) struct ConnectionLimiter {
+pub struct ConnectionLimiter {
     counts: Arc<DashMap<IpAddr, AtomicU32, FxBuildHasher>>,
     max_per_ip: u32,
 }
@@ -25,7 +25,7 @@ pub(crate) struct ConnectionGuard {
 
 impl ConnectionLimiter {
     /// Creates a new limiter. `max_per_ip = 0` means unlimited.
-    pub(crate) fn new(max_per_ip: u32) -> Self {
+    pub fn new(max_per_ip: u32) -> Self {
         Self {
             counts: Arc::new(DashMap::with_hasher(FxBuildHasher)),
             max_per_ip,
@@ -1,4 +1,4 @@
-pub(crate) mod connection_limiter;
+pub mod connection_limiter;
 pub mod dot;
 mod pktinfo;
 mod tcp;
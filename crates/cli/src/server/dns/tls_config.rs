@@ -1,5 +1,9 @@
 use anyhow::Context;
+use ferrous_dns_infrastructure::tls::ReloadableCertResolver;
 use rustls::pki_types::CertificateDer;
+use rustls::server::danger::ClientCertVerifier;
+use rustls::server::WebPkiClientVerifier;
+use rustls::RootCertStore;
 use std::fs::File;
 use std::io::BufReader;
 use std::path::Path;
@@ -15,6 +19,22 @@ pub fn load_server_tls_config(
     cert_path: &str,
     key_path: &str,
     listener_name: &str,
+) -> anyhow::Result<Option<Arc<rustls::ServerConfig>>> {
+    load_server_tls_config_with_client_auth(
+        cert_path,
+        key_path,
+        listener_name,
+        WebPkiClientVerifier::no_client_auth(),
+    )
+}
+
+/// Like [`load_server_tls_config`], with client certificates checked by
+/// `client_verifier`.
+pub fn load_server_tls_config_with_client_auth(
+    cert_path: &str,
+    key_path: &str,
+    listener_name: &str,
+    client_verifier: Arc<dyn ClientCertVerifier>,
 ) -> anyhow::Result<Option<Arc<rustls::ServerConfig>>> {
     if !Path::new(cert_path).exists() {
         warn!(
@@ -47,9 +67,65 @@ pub fn load_server_tls_config(
         .ok_or_else(|| anyhow::anyhow!("No private key found in {key_path}"))?;
 
     let config = rustls::ServerConfig::builder()
-        .with_no_client_auth()
+        .with_client_cert_verifier(client_verifier)
         .with_single_cert(certs, key)
         .context("Failed to build rustls ServerConfig")?;
 
     Ok(Some(Arc::new(config)))
 }
+
+/// Builds a `rustls::ServerConfig` that takes its certificate from
+/// `resolver`, so certificate renewals apply to new connections without
+/// restarting the listener.
+pub fn reloadable_server_tls_config(
+    resolver: Arc<ReloadableCertResolver>,
+) -> Arc<rustls::ServerConfig> {
+    reloadable_server_tls_config_with_client_auth(resolver, WebPkiClientVerifier::no_client_auth())
+}
+
+/// Like [`reloadable_server_tls_config`], with client certificates checked
+/// by `client_verifier`.
+pub fn reloadable_server_tls_config_with_client_auth(
+    resolver: Arc<ReloadableCertResolver>,
+    client_verifier: Arc<dyn ClientCertVerifier>,
+) -> Arc<rustls::ServerConfig> {
+    Arc::new(
+        rustls::ServerConfig::builder()
+            .with_client_cert_verifier(client_verifier)
+            .with_cert_resolver(resolver),
+    )
+}
+
+/// Builds a verifier accepting client certificates that chain to one of the
+/// CA certificates in `ca_path` (PEM).
+///
+/// With `optional`, clients without a certificate still complete the
+/// handshake — used when public endpoints share the port — and it is up to
+/// the application to refuse them where a certificate is required.
+pub fn load_client_cert_verifier(
+    ca_path: &str,
+    optional: bool,
+) -> anyhow::Result<Arc<dyn ClientCertVerifier>> {
+    let ca_file =
+        File::open(ca_path).with_context(|| format!("Failed to open client CA: {ca_path}"))?;
+    let mut roots = RootCertStore::empty();
+    for cert in rustls_pemfile::certs(&mut BufReader::new(ca_file)) {
+        let cert = cert.with_context(|| format!("Failed to parse client CA: {ca_path}"))?;
+        roots
+            .add(cert)
+            .with_context(|| format!("Invalid client CA certificate in {ca_path}"))?;
+    }
+    if roots.is_empty() {
+        anyhow::bail!("No CA certificates found in {ca_path}");
+    }
+
+    let builder = WebPkiClientVerifier::builder(Arc::new(roots));
+    let builder = if optional {
+        builder.allow_unauthenticated()
+    } else {
+        builder
+    };
+    builder
+        .build()
+        .context("Failed to build client certificate verifier")
+}
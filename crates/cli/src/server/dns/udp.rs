@@ -112,7 +112,9 @@ async fn run_udp_worker_batch(
                 let msg = batch.get_msg(i);
                 let client_ip = msg.src.ip();
 
-                if let Some(fast_query) = fast_path::parse_query(msg.data) {
+                if let Some(mut fast_query) = fast_path::parse_query(msg.data) {
+                    fast_query.client_max_size =
+                        handler.udp_payload_limit(fast_query.client_max_size);
                     match fast_query.kind {
                         FastPathKind::IpAddress => {
                             if let Some((addresses, ttl)) = handler.try_fast_path(
@@ -144,6 +146,7 @@ async fn run_udp_worker_batch(
                                 fast_query.domain(),
                                 fast_query.record_type,
                                 client_ip,
+                                fast_query.client_max_size,
                             ) {
                                 if let Some(patched) =
                                     wire_response::patch_wire_id(&wire_bytes, fast_query.id)
@@ -189,7 +192,7 @@ async fn run_udp_worker_batch(
                 let h = handler.clone();
                 let s = socket.clone();
                 tokio::spawn(async move {
-                    if let Some(resp) = h.handle_raw_udp_fallback(&buf, cip).await {
+                    if let Some(resp) = h.handle_udp_query(&buf, cip).await {
                         let _ = pktinfo::try_send_with_src_ip(s.get_ref(), &resp, from, dst_ip);
                     }
                 });
@@ -220,7 +223,9 @@ async fn run_udp_worker_single(
                     let query_buf = &recv_buf[..n];
                     let client_ip = from.ip();
 
-                    if let Some(fast_query) = fast_path::parse_query(query_buf) {
+                    if let Some(mut fast_query) = fast_path::parse_query(query_buf) {
+                        fast_query.client_max_size =
+                            handler.udp_payload_limit(fast_query.client_max_size);
                         match fast_query.kind {
                             FastPathKind::IpAddress => {
                                 if let Some((addresses, ttl)) = handler.try_fast_path(
@@ -251,6 +256,7 @@ async fn run_udp_worker_single(
                                     fast_query.domain(),
                                     fast_query.record_type,
                                     client_ip,
+                                    fast_query.client_max_size,
                                 ) {
                                     if let Some(patched) =
                                         wire_response::patch_wire_id(&wire_bytes, fast_query.id)
@@ -272,9 +278,8 @@ async fn run_udp_worker_single(
                     let socket_clone = socket.clone();
                     let owned_buf: Arc<[u8]> = Arc::from(query_buf);
                     tokio::spawn(async move {
-                        if let Some(response) = handler_clone
-                            .handle_raw_udp_fallback(&owned_buf, client_ip)
-                            .await
+                        if let Some(response) =
+                            handler_clone.handle_udp_query(&owned_buf, client_ip).await
                         {
                             let _ = pktinfo::try_send_with_src_ip(
                                 socket_clone.get_ref(),
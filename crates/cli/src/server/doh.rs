@@ -3,11 +3,11 @@ use axum::http::{header, HeaderMap, Method, StatusCode};
 use axum::response::{IntoResponse, Response};
 use axum::Extension;
 use base64::Engine;
+use ferrous_dns_api::dto::DnsJsonResponse;
 use ferrous_dns_infrastructure::dns::server::DnsServerHandler;
 use hickory_proto::op::{Edns, Message, MessageType, OpCode, Query as DnsQuery};
-use hickory_proto::rr::{DNSClass, Name, RData, RecordType as HickoryRecordType};
+use hickory_proto::rr::{DNSClass, Name, RecordType as HickoryRecordType};
 use hickory_proto::serialize::binary::{BinEncodable, BinEncoder};
-use serde_json::{json, Value};
 use std::net::IpAddr;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -139,78 +139,5 @@ fn build_wire_query(name: &str, record_type_str: &str) -> anyhow::Result<Vec<u8>
 }
 
 fn wire_to_dns_json(wire: &[u8]) -> anyhow::Result<String> {
-    let msg = Message::from_vec(wire)?;
-
-    let questions: Vec<Value> = msg
-        .queries()
-        .iter()
-        .map(|q| json!({ "name": q.name().to_string(), "type": u16::from(q.query_type()) }))
-        .collect();
-
-    let answers: Vec<Value> = msg
-        .answers()
-        .iter()
-        .filter(|r| r.record_type() != HickoryRecordType::OPT)
-        .map(|r| {
-            json!({
-                "name": r.name().to_string(),
-                "type": u16::from(r.record_type()),
-                "TTL": r.ttl(),
-                "data": format_rdata(r.data()),
-            })
-        })
-        .collect();
-
-    let authority: Vec<Value> = msg
-        .name_servers()
-        .iter()
-        .filter(|r| r.record_type() != HickoryRecordType::OPT)
-        .map(|r| {
-            json!({
-                "name": r.name().to_string(),
-                "type": u16::from(r.record_type()),
-                "TTL": r.ttl(),
-                "data": format_rdata(r.data()),
-            })
-        })
-        .collect();
-
-    Ok(serde_json::to_string(&json!({
-        "Status": u16::from(msg.response_code()),
-        "TC": msg.truncated(),
-        "RD": msg.recursion_desired(),
-        "RA": msg.recursion_available(),
-        "AD": msg.authentic_data(),
-        "CD": msg.checking_disabled(),
-        "Question": questions,
-        "Answer": answers,
-        "Authority": authority,
-    }))?)
-}
-
-fn format_rdata(data: &RData) -> String {
-    match data {
-        RData::A(a) => a.0.to_string(),
-        RData::AAAA(aaaa) => aaaa.0.to_string(),
-        RData::CNAME(name) => name.to_utf8(),
-        RData::NS(name) => name.to_utf8(),
-        RData::PTR(name) => name.to_utf8(),
-        RData::MX(mx) => format!("{} {}", mx.preference(), mx.exchange()),
-        RData::TXT(txt) => txt
-            .iter()
-            .map(|b| String::from_utf8_lossy(b).to_string())
-            .collect::<Vec<_>>()
-            .join(" "),
-        RData::SOA(soa) => format!(
-            "{} {} {} {} {} {} {}",
-            soa.mname(),
-            soa.rname(),
-            soa.serial(),
-            soa.refresh(),
-            soa.retry(),
-            soa.expire(),
-            soa.minimum()
-        ),
-        _ => data.to_string(),
-    }
+    Ok(serde_json::to_string(&DnsJsonResponse::from_wire(wire)?)?)
 }
@@ -0,0 +1,46 @@
+use ferrous_dns_api_grpc::{AdminService, GrpcAppState};
+use hyper::body::Incoming;
+use hyper_util::rt::{TokioExecutor, TokioIo};
+use hyper_util::server::conn::auto::Builder;
+use std::net::SocketAddr;
+use tokio::net::TcpListener;
+use tracing::{debug, info, warn};
+
+/// Serves the gRPC admin API over plaintext HTTP/2 (h2c).
+pub async fn start_grpc_server(bind_addr: SocketAddr, state: GrpcAppState) -> anyhow::Result<()> {
+    info!(bind_address = %bind_addr, "Starting gRPC admin API");
+
+    let listener = TcpListener::bind(bind_addr).await?;
+    let service = AdminService::new(state);
+
+    info!("gRPC admin API ready on {}", bind_addr);
+
+    loop {
+        let (stream, peer_addr) = match listener.accept().await {
+            Ok(conn) => conn,
+            Err(e) => {
+                warn!(error = %e, "gRPC accept error");
+                continue;
+            }
+        };
+
+        let service = service.clone();
+        tokio::spawn(async move {
+            let hyper_svc = hyper::service::service_fn(move |req: hyper::Request<Incoming>| {
+                let mut svc = service.clone();
+                async move {
+                    use tower::Service;
+                    svc.call(req).await
+                }
+            });
+
+            if let Err(e) = Builder::new(TokioExecutor::new())
+                .http2_only()
+                .serve_connection(TokioIo::new(stream), hyper_svc)
+                .await
+            {
+                debug!(client = %peer_addr, error = %e, "gRPC connection error");
+            }
+        });
+    }
+}
@@ -1,12 +1,34 @@
+#[cfg(feature = "web")]
+pub mod admin_access;
+#[cfg(feature = "web")]
+pub mod block_page;
 pub mod dns;
+#[cfg(feature = "web")]
 pub mod doh;
+#[cfg(feature = "web")]
+pub mod grpc;
+pub mod sinkhole;
+#[cfg(feature = "web")]
 pub mod web;
+#[cfg(feature = "web")]
 mod web_tls;
 
+#[cfg(feature = "web")]
+pub use admin_access::AdminAccessGuard;
+#[cfg(feature = "web")]
+pub use block_page::{start_block_page_server, start_https_block_page_server, BlockPageState};
 pub use dns::dot::start_dot_server;
 pub use dns::start_dns_server;
-pub use dns::tls_config::load_server_tls_config;
+pub use dns::tls_config::{
+    load_client_cert_verifier, load_server_tls_config, load_server_tls_config_with_client_auth,
+    reloadable_server_tls_config, reloadable_server_tls_config_with_client_auth,
+};
+#[cfg(feature = "web")]
+pub use grpc::start_grpc_server;
+pub use sinkhole::start_sinkhole_responder;
+#[cfg(feature = "web")]
 pub use web::start_doh_server;
+#[cfg(feature = "web")]
 pub use web::start_web_server;
 
 /// Abstraction for a DNS protocol server that can be started independently.
@@ -0,0 +1,42 @@
+use ferrous_dns_infrastructure::dns::sinkhole::inspect_connection;
+use ferrous_dns_infrastructure::dns::{SinkholeProtocol, SinkholeTelemetry};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tokio::sync::Semaphore;
+use tracing::{debug, error, info};
+
+const MAX_CONNECTIONS: usize = 256;
+
+/// Accepts HTTP or HTTPS connections on a sinkhole address and feeds the
+/// requested Host/SNI into the telemetry.
+pub async fn start_sinkhole_responder(
+    bind_addr: SocketAddr,
+    protocol: SinkholeProtocol,
+    telemetry: Arc<SinkholeTelemetry>,
+) -> anyhow::Result<()> {
+    let listener = TcpListener::bind(bind_addr).await?;
+    info!(bind_address = %bind_addr, ?protocol, "Sinkhole telemetry responder ready");
+
+    let permits = Arc::new(Semaphore::new(MAX_CONNECTIONS));
+    loop {
+        let (mut stream, peer_addr) = match listener.accept().await {
+            Ok(conn) => conn,
+            Err(e) => {
+                error!(error = %e, "Sinkhole accept error");
+                continue;
+            }
+        };
+        let Ok(permit) = permits.clone().try_acquire_owned() else {
+            debug!(client = %peer_addr, "Sinkhole connection rejected: too many open connections");
+            continue;
+        };
+        let telemetry = telemetry.clone();
+        tokio::spawn(async move {
+            if let Some(host) = inspect_connection(&mut stream, protocol).await {
+                telemetry.record_attempt(peer_addr.ip(), &host, protocol);
+            }
+            drop(permit);
+        });
+    }
+}
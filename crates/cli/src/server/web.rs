@@ -1,12 +1,15 @@
 use axum::{
-    extract::State,
+    extract::{DefaultBodyLimit, State},
     http::{header, HeaderValue, Method},
+    middleware,
     response::{Html, IntoResponse},
     routing::get,
     Router,
 };
-use ferrous_dns_api::{create_api_routes, AppState};
+use ferrous_dns_api::middleware::{limit_by_address, ApiRateLimiter};
+use ferrous_dns_api::{create_api_routes_with_limits, create_public_routes, AppState};
 use ferrous_dns_api_pihole::{create_pihole_routes, PiholeAppState};
+use ferrous_dns_domain::ApiLimitsConfig;
 use ferrous_dns_infrastructure::dns::server::DnsServerHandler;
 use std::net::SocketAddr;
 use std::sync::Arc;
@@ -14,6 +17,7 @@ use tower_http::compression::CompressionLayer;
 use tower_http::cors::CorsLayer;
 use tracing::info;
 
+use super::admin_access::{enforce_admin_access, AdminAccessGuard};
 use super::web_tls;
 
 pub async fn start_doh_server(
@@ -42,6 +46,7 @@ pub async fn start_doh_server(
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn start_web_server(
     bind_addr: SocketAddr,
     ferrous_state: AppState,
@@ -50,6 +55,8 @@ pub async fn start_web_server(
     pihole_compat: bool,
     doh_handler: Option<Arc<DnsServerHandler>>,
     tls_config: Option<Arc<rustls::ServerConfig>>,
+    admin_access: AdminAccessGuard,
+    api_limits: &ApiLimitsConfig,
 ) -> anyhow::Result<()> {
     let scheme = if tls_config.is_some() {
         "https"
@@ -80,6 +87,8 @@ pub async fn start_web_server(
         cors_allowed_origins,
         pihole_compat,
         doh_handler,
+        admin_access,
+        api_limits,
     );
 
     if let Some(tls_cfg) = tls_config {
@@ -88,7 +97,11 @@ pub async fn start_web_server(
     } else {
         let listener = tokio::net::TcpListener::bind(&bind_addr).await?;
         info!("Web server started successfully");
-        axum::serve(listener, app).await?;
+        axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .await?;
     }
 
     Ok(())
@@ -108,7 +121,13 @@ fn build_strict_cors(allowed_origins: &[String]) -> CorsLayer {
         .collect();
     CorsLayer::new()
         .allow_origin(origins)
-        .allow_methods([Method::GET, Method::POST, Method::PUT, Method::DELETE])
+        .allow_methods([
+            Method::GET,
+            Method::POST,
+            Method::PUT,
+            Method::PATCH,
+            Method::DELETE,
+        ])
         .allow_headers([header::CONTENT_TYPE, header::AUTHORIZATION])
 }
 
@@ -118,13 +137,25 @@ fn create_app(
     cors_allowed_origins: &[String],
     pihole_compat: bool,
     doh_handler: Option<Arc<DnsServerHandler>>,
+    admin_access: AdminAccessGuard,
+    api_limits: &ApiLimitsConfig,
 ) -> Router {
+    let rate_limiter = ApiRateLimiter::new(api_limits);
+    let public_routes =
+        create_public_routes(ferrous_state.clone(), api_limits, rate_limiter.clone());
+    let api_routes = create_api_routes_with_limits(ferrous_state, api_limits, rate_limiter.clone());
     let router = if pihole_compat {
+        let pihole_routes = create_pihole_routes(pihole_state)
+            .layer(DefaultBodyLimit::max(api_limits.max_body_bytes))
+            .layer(middleware::from_fn_with_state(
+                rate_limiter,
+                limit_by_address,
+            ));
         Router::new()
-            .nest("/api", create_pihole_routes(pihole_state))
-            .nest("/ferrous/api", create_api_routes(ferrous_state))
+            .nest("/api", pihole_routes)
+            .nest("/ferrous/api", api_routes)
     } else {
-        Router::new().nest("/api", create_api_routes(ferrous_state))
+        Router::new().nest("/api", api_routes)
     };
 
     let mut app = router
@@ -173,8 +204,15 @@ fn create_app(
         .route("/dns-filter.html", get(dns_filter_handler))
         .route("/block-services.html", get(block_services_handler))
         .layer(CompressionLayer::new().gzip(true))
-        .layer(build_cors_layer(cors_allowed_origins));
-
+        .layer(build_cors_layer(cors_allowed_origins))
+        .layer(middleware::from_fn_with_state(
+            admin_access,
+            enforce_admin_access,
+        ));
+
+    // Added after the admin access layer so DoH and the public stats stay
+    // reachable for clients outside `server.admin_access`.
+    app = app.merge(public_routes);
     if let Some(handler) = doh_handler {
         app = app
             .route(
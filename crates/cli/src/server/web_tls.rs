@@ -1,3 +1,4 @@
+use axum::extract::ConnectInfo;
 use axum::Router;
 use hyper::body::Incoming;
 use hyper_util::rt::{TokioExecutor, TokioIo};
@@ -9,6 +10,8 @@ use tokio::net::{TcpListener, TcpStream};
 use tokio_rustls::TlsAcceptor;
 use tracing::{debug, error, warn};
 
+use super::admin_access::VerifiedClientCertificate;
+
 /// Runs the web server over HTTPS with automatic HTTP → HTTPS redirect.
 ///
 /// On each accepted TCP connection the first byte is peeked:
@@ -16,6 +19,9 @@ use tracing::{debug, error, warn};
 /// - Anything else (plain HTTP) → respond with 301 redirect to `https://`.
 ///
 /// This allows the same port to handle both protocols transparently.
+///
+/// Requests carry the peer address as [`ConnectInfo`] and, when the client
+/// presented an accepted certificate, [`VerifiedClientCertificate`].
 pub(super) async fn start_https_web_server(
     bind_addr: SocketAddr,
     app: Router,
@@ -61,13 +67,18 @@ pub(super) async fn start_https_web_server(
                 }
             };
 
+            let client_certificate = tls_stream.get_ref().1.peer_certificates().is_some();
             let io = TokioIo::new(tls_stream);
 
             let hyper_svc = hyper::service::service_fn(move |req: hyper::Request<Incoming>| {
                 let mut svc = tower_service.clone();
                 async move {
                     use tower::Service;
-                    let (parts, body) = req.into_parts();
+                    let (mut parts, body) = req.into_parts();
+                    parts.extensions.insert(ConnectInfo(peer_addr));
+                    if client_certificate {
+                        parts.extensions.insert(VerifiedClientCertificate);
+                    }
                     let req = hyper::Request::from_parts(parts, axum::body::Body::new(body));
                     svc.call(req).await
                 }
@@ -0,0 +1,80 @@
+use anyhow::Context;
+use ferrous_dns_application::ports::TlsCertificatePort;
+use ferrous_dns_domain::{AcmeChallenge, Config};
+use ferrous_dns_infrastructure::dns::LocalZoneStore;
+use ferrous_dns_infrastructure::tls::acme::{AcmeChallengeSolver, Dns01Publisher};
+use ferrous_dns_infrastructure::tls::{
+    AcmeCertificateManager, ReloadableCertResolver, TlsCertificateService,
+};
+use ferrous_dns_jobs::AcmeRenewalJob;
+use std::net::{IpAddr, SocketAddr};
+use std::path::Path;
+use std::sync::Arc;
+use tracing::info;
+
+use super::Repositories;
+
+/// Certificate management for the encrypted DNS listeners when
+/// `server.encrypted_dns.acme` is enabled.
+pub struct AcmeServices {
+    /// Serves the managed certificate; the listeners built on it pick up
+    /// renewals without a restart.
+    pub cert_resolver: Arc<ReloadableCertResolver>,
+    pub renewal_job: AcmeRenewalJob,
+}
+
+impl AcmeServices {
+    pub async fn new(
+        config: &Config,
+        repos: &Repositories,
+        local_zone: Arc<LocalZoneStore>,
+    ) -> anyhow::Result<Option<Self>> {
+        let encrypted_dns = &config.server.encrypted_dns;
+        let acme = &encrypted_dns.acme;
+        if !acme.enabled {
+            return Ok(None);
+        }
+        let (cert_path, key_path) = (&encrypted_dns.tls_cert_path, &encrypted_dns.tls_key_path);
+
+        // The listeners need a certificate before the first order completes
+        // (DNS-01 is answered by this very server), so start on a
+        // self-signed one that the renewal job replaces.
+        if !Path::new(cert_path).exists() || !Path::new(key_path).exists() {
+            info!(path = %cert_path, "No certificate yet; starting with a self-signed one until ACME issues it");
+            TlsCertificateService
+                .generate_self_signed(cert_path, key_path)
+                .await
+                .context("Failed to create placeholder certificate")?;
+        }
+        let cert_resolver = Arc::new(
+            ReloadableCertResolver::from_pem_files(cert_path, key_path)
+                .context("Failed to load TLS certificate")?,
+        );
+
+        let solver = match acme.challenge {
+            AcmeChallenge::Http01 => {
+                let ip: IpAddr = config
+                    .server
+                    .bind_address
+                    .parse()
+                    .context("Invalid bind address for the ACME HTTP-01 responder")?;
+                AcmeChallengeSolver::Http01 {
+                    bind: SocketAddr::new(ip, acme.http_port),
+                }
+            }
+            AcmeChallenge::Dns01 => AcmeChallengeSolver::Dns01(Dns01Publisher::new(
+                repos.local_record.clone(),
+                local_zone,
+            )),
+        };
+        let manager =
+            AcmeCertificateManager::new(acme.clone(), cert_path.clone(), key_path.clone(), solver)
+                .with_resolver(cert_resolver.clone());
+
+        Ok(Some(Self {
+            cert_resolver,
+            renewal_job: AcmeRenewalJob::new(Arc::new(manager))
+                .with_interval(acme.check_interval_secs),
+        }))
+    }
+}
@@ -1,23 +1,34 @@
 use ferrous_dns_api::{
     AppState, AuthUseCases, BackupUseCases, BlockingUseCases, ClientUseCases, DnsUseCases,
-    GroupUseCases, QueryUseCases, SafeSearchUseCases, ScheduleUseCases, ServiceUseCases,
+    GroupUseCases, QueryPolicyUseCases, QueryUseCases, SafeSearchUseCases, ScheduleUseCases,
+    ServiceUseCases, TenantUseCases,
 };
 use ferrous_dns_application::ports::{
-    BlocklistSourceCreator, ConfigFilePersistence, GroupCreator, LocalRecordCreator, UserProvider,
+    BlocklistSourceCreator, ConfigFilePersistence, DnsCachePort, GroupCreator, LocalRecordCreator,
+    LocalZonePort, LogLevelPort, SplitHorizonPort, UpstreamHealthPort, UserProvider,
 };
 use ferrous_dns_application::use_cases::{
-    ChangePasswordUseCase, CreateApiTokenUseCase, CreateLocalRecordUseCase, CreateUserUseCase,
-    DeleteApiTokenUseCase, DeleteLocalRecordUseCase, DeleteUserUseCase, ExportConfigUseCase,
-    GetActiveSessionsUseCase, GetApiTokensUseCase, GetAuthStatusUseCase, GetUsersUseCase,
-    ImportConfigUseCase, LoginUseCase, LogoutUseCase, SetupPasswordUseCase, UpdateApiTokenUseCase,
-    UpdateLocalRecordUseCase, ValidateApiTokenUseCase, ValidateSessionUseCase,
+    ChangePasswordUseCase, CreateApiTokenUseCase, CreateBackupUseCase, CreateLocalRecordUseCase,
+    CreateTenantGroupUseCase, CreateTenantUseCase, CreateUserUseCase, DeleteApiTokenUseCase,
+    DeleteLocalRecordUseCase, DeleteTenantUseCase, DeleteUserUseCase, DiagnoseDomainUseCase,
+    ExportConfigUseCase, ExportPrimaryStateUseCase, GetActiveSessionsUseCase, GetApiTokensUseCase,
+    GetAuthStatusUseCase, GetDnssecStatsUseCase, GetDnssecStatusUseCase, GetLocalRecordsUseCase,
+    GetTenantsUseCase, GetUsersUseCase, ImportConfigUseCase, ImportExternalConfigUseCase,
+    LoginUseCase, LogoutUseCase, RestoreBackupUseCase, SetupPasswordUseCase, SetupWizardUseCase,
+    SyncFromPrimaryUseCase, TenantBlocklistSourcesUseCase, TraceResolveUseCase,
+    UpdateApiTokenUseCase, UpdateLocalRecordUseCase, UpdateTenantUseCase, ValidateApiTokenUseCase,
+    ValidateSessionUseCase,
 };
 use ferrous_dns_domain::Config;
 use ferrous_dns_infrastructure::auth::{
     Argon2PasswordHasher, CompositeUserProvider, TomlAdminProvider,
 };
+use ferrous_dns_infrastructure::backup::TarGzBackupArchiver;
+use ferrous_dns_infrastructure::dns::transport::udp;
 use ferrous_dns_infrastructure::dns::UpstreamHealthAdapter;
-use ferrous_dns_infrastructure::repositories::{TomlConfigFilePersistence, TomlConfigRepository};
+use ferrous_dns_infrastructure::external_import::ExternalConfigFileReader;
+use ferrous_dns_infrastructure::repositories::TomlConfigFilePersistence;
+use ferrous_dns_infrastructure::system::{ProcessMetricsCollector, SystemNetworkInterfaces};
 use ferrous_dns_infrastructure::tls::TlsCertificateService;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -30,6 +41,8 @@ pub async fn build_app_state(
     dns_services: &DnsServices,
     config: Arc<RwLock<Config>>,
     config_path: Option<Arc<str>>,
+    log_levels: Arc<dyn LogLevelPort>,
+    standby: Option<Arc<SyncFromPrimaryUseCase>>,
 ) -> AppState {
     let effective_path = config_path
         .as_deref()
@@ -37,9 +50,6 @@ pub async fn build_app_state(
         .or_else(Config::get_config_path)
         .unwrap_or_else(|| "ferrous-dns.toml".to_string());
 
-    let config_repo: Arc<dyn ferrous_dns_application::ports::ConfigRepository> =
-        Arc::new(TomlConfigRepository::new(effective_path.clone()));
-
     let auth_config = {
         let cfg = config.read().await;
         Arc::new(cfg.auth.clone())
@@ -56,10 +66,16 @@ pub async fn build_app_state(
         toml_admin,
         repos.user.clone(),
         config.clone(),
-        Some(effective_path),
+        Some(effective_path.clone()),
         config_persistence.clone(),
     ));
 
+    let setup_password = Arc::new(SetupPasswordUseCase::new(
+        user_provider.clone(),
+        password_hasher.clone(),
+        auth_config.admin.username.clone(),
+    ));
+
     let auth = AuthUseCases {
         login: Arc::new(LoginUseCase::new(
             user_provider.clone(),
@@ -69,11 +85,18 @@ pub async fn build_app_state(
         )),
         logout: Arc::new(LogoutUseCase::new(repos.session.clone())),
         validate_session: Arc::new(ValidateSessionUseCase::new(repos.session.clone())),
-        setup_password: Arc::new(SetupPasswordUseCase::new(
-            user_provider.clone(),
-            password_hasher.clone(),
-            auth_config.admin.username.clone(),
-        )),
+        setup_password: setup_password.clone(),
+        setup_wizard: Arc::new(
+            SetupWizardUseCase::new(
+                config.clone(),
+                config_persistence.clone(),
+                Some(effective_path),
+                setup_password,
+                use_cases.create_blocklist_source.clone(),
+            )
+            .with_interfaces(Arc::new(SystemNetworkInterfaces))
+            .with_block_filter_engine(repos.block_filter_engine.clone()),
+        ),
         change_password: Arc::new(ChangePasswordUseCase::new(
             user_provider.clone(),
             password_hasher.clone(),
@@ -94,15 +117,39 @@ pub async fn build_app_state(
         delete_user: Arc::new(DeleteUserUseCase::new(repos.user.clone())),
     };
 
+    let split_horizon: Arc<dyn SplitHorizonPort> = dns_services.split_horizon.clone();
+    let local_zone: Arc<dyn LocalZonePort> = dns_services.local_zone.clone();
+    let dns_cache: Arc<dyn DnsCachePort> = dns_services.cache.clone();
+    let upstream_health: Arc<dyn UpstreamHealthPort> = Arc::new(UpstreamHealthAdapter::new(
+        dns_services.pool_manager.clone(),
+        dns_services.health_checker.clone(),
+    ));
+    let diagnose_domain = Arc::new(
+        DiagnoseDomainUseCase::new(
+            repos.block_filter_engine.clone(),
+            dns_cache,
+            upstream_health.clone(),
+        )
+        .with_query_policy(repos.query_policy_engine.clone())
+        .with_dns_rewrites(repos.dns_rewrite_engine.clone())
+        .with_safe_search(repos.safe_search_engine.clone()),
+    );
+
     let backup = {
         let group_creator: Arc<dyn GroupCreator> = use_cases.create_group.clone();
         let blocklist_source_creator: Arc<dyn BlocklistSourceCreator> =
             use_cases.create_blocklist_source.clone();
         let local_record_creator_for_import = Arc::new(
-            CreateLocalRecordUseCase::new(config.clone(), config_repo.clone())
-                .with_ptr_registry(dns_services.ptr_registry.clone())
-                .with_dns_cache(Some(dns_services.cache.clone()
-                    as Arc<dyn ferrous_dns_application::ports::DnsCachePort>)),
+            CreateLocalRecordUseCase::new(
+                config.clone(),
+                repos.local_record.clone(),
+                local_zone.clone(),
+            )
+            .with_ptr_registry(dns_services.ptr_registry.clone())
+            .with_dns_cache(Some(
+                dns_services.cache.clone() as Arc<dyn ferrous_dns_application::ports::DnsCachePort>
+            ))
+            .with_split_horizon(Some(split_horizon.clone())),
         );
         let local_record_creator: Arc<dyn LocalRecordCreator> = local_record_creator_for_import;
         let resolved_path = config_path
@@ -110,19 +157,57 @@ pub async fn build_app_state(
             .map(String::from)
             .or_else(Config::get_config_path);
         BackupUseCases {
-            export: Arc::new(ExportConfigUseCase::new(
-                config.clone(),
-                repos.group.clone(),
-                repos.blocklist_source.clone(),
-            )),
+            export: Arc::new(
+                ExportConfigUseCase::new(
+                    config.clone(),
+                    repos.group.clone(),
+                    repos.blocklist_source.clone(),
+                )
+                .with_local_record_repository(repos.local_record.clone()),
+            ),
             import: Arc::new(ImportConfigUseCase::new(
                 config.clone(),
                 config_persistence.clone(),
-                resolved_path,
+                resolved_path.clone(),
                 group_creator,
                 blocklist_source_creator,
-                local_record_creator,
+                local_record_creator.clone(),
+            )),
+            create: Arc::new(CreateBackupUseCase::new(
+                config.clone(),
+                repos.backup_store.clone(),
+                Arc::new(TarGzBackupArchiver),
+            )),
+            restore: Arc::new(
+                RestoreBackupUseCase::new(
+                    config.clone(),
+                    config_persistence.clone(),
+                    resolved_path,
+                    repos.backup_store.clone(),
+                    Arc::new(TarGzBackupArchiver),
+                )
+                .with_block_filter_engine(repos.block_filter_engine.clone()),
+            ),
+            external_import: Arc::new(
+                ImportExternalConfigUseCase::new(
+                    Arc::new(ExternalConfigFileReader),
+                    config.clone(),
+                    repos.group.clone(),
+                    repos.blocklist_source.clone(),
+                    repos.whitelist_source.clone(),
+                    repos.managed_domain.clone(),
+                    repos.regex_filter.clone(),
+                    repos.client.clone(),
+                    repos.client_subnet.clone(),
+                    local_record_creator,
+                )
+                .with_block_filter_engine(repos.block_filter_engine.clone()),
+            ),
+            primary_state: Arc::new(ExportPrimaryStateUseCase::new(
+                repos.block_filter_engine.clone(),
+                dns_services.cache.clone() as Arc<dyn DnsCachePort>,
             )),
+            standby,
         }
     };
 
@@ -135,32 +220,67 @@ pub async fn build_app_state(
             get_cache_stats: use_cases.get_cache_stats,
             get_top_blocked_domains: use_cases.get_top_blocked_domains,
             get_top_clients: use_cases.get_top_clients,
+            database_maintenance: use_cases.database_maintenance,
+            get_alerts: use_cases.get_alerts,
+            delete_alert: use_cases.delete_alert,
+            get_audit_log: use_cases.get_audit_log,
+            live_metrics: dns_services.query_metrics.clone(),
         },
         dns: DnsUseCases {
             cache: dns_services.cache.clone()
                 as Arc<dyn ferrous_dns_application::ports::DnsCachePort>,
             create_local_record: Arc::new(
-                CreateLocalRecordUseCase::new(config.clone(), config_repo.clone())
-                    .with_ptr_registry(dns_services.ptr_registry.clone())
-                    .with_dns_cache(Some(dns_services.cache.clone()
-                        as Arc<dyn ferrous_dns_application::ports::DnsCachePort>)),
+                CreateLocalRecordUseCase::new(
+                    config.clone(),
+                    repos.local_record.clone(),
+                    local_zone.clone(),
+                )
+                .with_ptr_registry(dns_services.ptr_registry.clone())
+                .with_dns_cache(Some(dns_services.cache.clone()
+                    as Arc<dyn ferrous_dns_application::ports::DnsCachePort>))
+                .with_split_horizon(Some(split_horizon.clone())),
             ),
             update_local_record: Arc::new(
-                UpdateLocalRecordUseCase::new(config.clone(), config_repo.clone())
-                    .with_ptr_registry(dns_services.ptr_registry.clone())
-                    .with_dns_cache(Some(dns_services.cache.clone()
-                        as Arc<dyn ferrous_dns_application::ports::DnsCachePort>)),
+                UpdateLocalRecordUseCase::new(
+                    config.clone(),
+                    repos.local_record.clone(),
+                    local_zone.clone(),
+                )
+                .with_ptr_registry(dns_services.ptr_registry.clone())
+                .with_dns_cache(Some(dns_services.cache.clone()
+                    as Arc<dyn ferrous_dns_application::ports::DnsCachePort>))
+                .with_split_horizon(Some(split_horizon.clone())),
             ),
+            get_local_records: Arc::new(GetLocalRecordsUseCase::new(repos.local_record.clone())),
             delete_local_record: Arc::new(
-                DeleteLocalRecordUseCase::new(config.clone(), config_repo)
-                    .with_ptr_registry(dns_services.ptr_registry.clone())
-                    .with_dns_cache(Some(dns_services.cache.clone()
-                        as Arc<dyn ferrous_dns_application::ports::DnsCachePort>)),
+                DeleteLocalRecordUseCase::new(
+                    config.clone(),
+                    repos.local_record.clone(),
+                    local_zone.clone(),
+                )
+                .with_ptr_registry(dns_services.ptr_registry.clone())
+                .with_dns_cache(Some(dns_services.cache.clone()
+                    as Arc<dyn ferrous_dns_application::ports::DnsCachePort>))
+                .with_split_horizon(Some(split_horizon.clone())),
             ),
-            upstream_health: Arc::new(UpstreamHealthAdapter::new(
-                dns_services.pool_manager.clone(),
-                dns_services.health_checker.clone(),
+            upstream_health,
+            access_control: dns_services.access_control.clone(),
+            sinkhole_telemetry: dns_services.sinkhole_telemetry.clone(),
+            conditional_forwards: dns_services.conditional_forwards.clone(),
+            slow_query_log: dns_services.slow_query_log.clone(),
+            inflight: dns_services.inflight_registry.clone(),
+            diagnose_domain: diagnose_domain.clone(),
+            trace_resolve: Arc::new(TraceResolveUseCase::new(
+                diagnose_domain,
+                dns_services.resolver.clone(),
+            )),
+            get_dnssec_status: Arc::new(GetDnssecStatusUseCase::new(
+                dns_services.cache.dnssec_cache(),
+                repos.dnssec_history.clone(),
             )),
+            get_dnssec_stats: Arc::new(GetDnssecStatsUseCase::new(repos.dnssec_history.clone())),
+            resolve_query: dns_services.handler_use_case.clone(),
+            faults: dns_services.faults.clone(),
         },
         groups: GroupUseCases {
             get_groups: use_cases.get_groups,
@@ -174,6 +294,7 @@ pub async fn build_app_state(
             create_manual_client: use_cases.create_manual_client,
             update_client: use_cases.update_client,
             delete_client: use_cases.delete_client,
+            get_client_activity: use_cases.get_client_activity,
             get_client_subnets: use_cases.get_client_subnets,
             create_client_subnet: use_cases.create_client_subnet,
             delete_client_subnet: use_cases.delete_client_subnet,
@@ -181,15 +302,21 @@ pub async fn build_app_state(
         },
         blocking: BlockingUseCases {
             get_blocklist: use_cases.get_blocklist,
+            bulk_add_blocklist: use_cases.bulk_add_blocklist,
             get_blocklist_sources: use_cases.get_blocklist_sources,
             create_blocklist_source: use_cases.create_blocklist_source,
             update_blocklist_source: use_cases.update_blocklist_source,
             delete_blocklist_source: use_cases.delete_blocklist_source,
             get_whitelist: use_cases.get_whitelist,
+            bulk_add_whitelist: use_cases.bulk_add_whitelist,
             get_whitelist_sources: use_cases.get_whitelist_sources,
             create_whitelist_source: use_cases.create_whitelist_source,
             update_whitelist_source: use_cases.update_whitelist_source,
             delete_whitelist_source: use_cases.delete_whitelist_source,
+            get_ip_blocklist_sources: use_cases.get_ip_blocklist_sources,
+            create_ip_blocklist_source: use_cases.create_ip_blocklist_source,
+            update_ip_blocklist_source: use_cases.update_ip_blocklist_source,
+            delete_ip_blocklist_source: use_cases.delete_ip_blocklist_source,
             get_managed_domains: use_cases.get_managed_domains,
             create_managed_domain: use_cases.create_managed_domain,
             update_managed_domain: use_cases.update_managed_domain,
@@ -199,6 +326,11 @@ pub async fn build_app_state(
             update_regex_filter: use_cases.update_regex_filter,
             delete_regex_filter: use_cases.delete_regex_filter,
             get_block_filter_stats: use_cases.get_block_filter_stats,
+            get_unblock_requests: use_cases.get_unblock_requests,
+            submit_unblock_request: use_cases.submit_unblock_request,
+            approve_unblock_request: use_cases.approve_unblock_request,
+            deny_unblock_request: use_cases.deny_unblock_request,
+            delete_unblock_request: use_cases.delete_unblock_request,
         },
         services: ServiceUseCases {
             get_service_catalog: use_cases.get_service_catalog,
@@ -223,12 +355,70 @@ pub async fn build_app_state(
             manage_slots: use_cases.manage_time_slots,
             assign_profile: use_cases.assign_schedule_profile,
         },
+        policies: QueryPolicyUseCases {
+            get_policies: use_cases.get_query_policies,
+            create_policy: use_cases.create_query_policy,
+            update_policy: use_cases.update_query_policy,
+            delete_policy: use_cases.delete_query_policy,
+            get_record_type_policies: use_cases.get_record_type_policies,
+            set_record_type_policy: use_cases.set_record_type_policy,
+            delete_record_type_policy: use_cases.delete_record_type_policy,
+            get_tld_policies: use_cases.get_tld_policies,
+            set_tld_policy: use_cases.set_tld_policy,
+            delete_tld_policy: use_cases.delete_tld_policy,
+            get_rewrites: use_cases.get_dns_rewrites,
+            create_rewrite: use_cases.create_dns_rewrite,
+            update_rewrite: use_cases.update_dns_rewrite,
+            delete_rewrite: use_cases.delete_dns_rewrite,
+            get_ttl_overrides: use_cases.get_cache_ttl_overrides,
+            create_ttl_override: use_cases.create_cache_ttl_override,
+            update_ttl_override: use_cases.update_cache_ttl_override,
+            delete_ttl_override: use_cases.delete_cache_ttl_override,
+        },
         auth,
         backup,
+        tenants: TenantUseCases {
+            get_tenants: Arc::new(GetTenantsUseCase::new(
+                repos.tenant.clone(),
+                repos.group.clone(),
+            )),
+            create_tenant: Arc::new(CreateTenantUseCase::new(
+                repos.tenant.clone(),
+                repos.group.clone(),
+                repos.block_filter_engine.clone(),
+            )),
+            update_tenant: Arc::new(UpdateTenantUseCase::new(
+                repos.tenant.clone(),
+                repos.block_filter_engine.clone(),
+            )),
+            delete_tenant: Arc::new(DeleteTenantUseCase::new(
+                repos.tenant.clone(),
+                repos.group.clone(),
+                repos.blocklist_source.clone(),
+                repos.block_filter_engine.clone(),
+                local_zone.clone(),
+            )),
+            create_tenant_group: Arc::new(CreateTenantGroupUseCase::new(
+                repos.tenant.clone(),
+                repos.group.clone(),
+                repos.block_filter_engine.clone(),
+            )),
+            blocklist_sources: Arc::new(TenantBlocklistSourcesUseCase::new(
+                repos.tenant.clone(),
+                repos.blocklist_source.clone(),
+            )),
+        },
         tls_enabled,
         config,
         config_file_persistence: config_persistence,
         config_path,
         tls_cert: Arc::new(TlsCertificateService),
+        log_levels,
+        system_metrics: Arc::new(
+            ProcessMetricsCollector::new()
+                .with_channel(repos.query_log.clone())
+                .with_channel(Arc::new(dns_services.query_events.clone()))
+                .with_udp_pool(udp::shared_pool()),
+        ),
     }
 }
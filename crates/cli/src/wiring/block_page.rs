@@ -0,0 +1,80 @@
+use anyhow::Context;
+use ferrous_dns_domain::Config;
+use ferrous_dns_infrastructure::tls::BlockPageCertResolver;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::warn;
+
+use super::{DnsServices, Repositories, UseCases};
+use crate::server::block_page::{BlockPageSettings, BlockPageState};
+
+/// Block page served on the sinkhole addresses when
+/// `blocking.sinkhole.block_page` is enabled.
+pub struct BlockPageServices {
+    pub state: BlockPageState,
+    /// Present when the page is also served over HTTPS.
+    pub cert_resolver: Option<Arc<BlockPageCertResolver>>,
+}
+
+impl BlockPageServices {
+    pub async fn new(
+        config: &Config,
+        repos: &Repositories,
+        dns_services: &DnsServices,
+        use_cases: &UseCases,
+    ) -> anyhow::Result<Option<Self>> {
+        let sinkhole = &config.blocking.sinkhole;
+        let block_page = &sinkhole.block_page;
+        if !block_page.enabled || dns_services.sinkhole.is_none() {
+            return Ok(None);
+        }
+
+        let cert_resolver = match (&block_page.ca_cert_path, &block_page.ca_key_path) {
+            (Some(cert_path), Some(key_path)) => Some(Arc::new(
+                BlockPageCertResolver::load_or_create(cert_path, key_path)
+                    .context("Failed to load the block page CA")?,
+            )),
+            _ => None,
+        };
+
+        let defaults = BlockPageSettings::from_config(block_page);
+        let mut groups = HashMap::with_capacity(block_page.groups.len());
+        for group_config in &block_page.groups {
+            match repos.group.get_by_name(&group_config.group).await {
+                Ok(Some(group)) => {
+                    if let Some(id) = group.id {
+                        groups.insert(id, defaults.with_group(group_config));
+                    }
+                }
+                Ok(None) => {
+                    warn!(
+                        group = %group_config.group,
+                        "Block page group not found; its clients get the default page"
+                    );
+                }
+                Err(e) => {
+                    warn!(
+                        group = %group_config.group,
+                        error = %e,
+                        "Failed to resolve block page group"
+                    );
+                }
+            }
+        }
+
+        Ok(Some(Self {
+            state: BlockPageState {
+                defaults: Arc::new(defaults),
+                groups: Arc::new(groups),
+                block_filter: repos.block_filter_engine.clone(),
+                recent_blocks: dns_services.recent_blocks.clone(),
+                telemetry: sinkhole
+                    .telemetry
+                    .enabled
+                    .then(|| dns_services.sinkhole_telemetry.clone()),
+                submit_unblock_request: use_cases.submit_unblock_request.clone(),
+            },
+            cert_resolver,
+        }))
+    }
+}
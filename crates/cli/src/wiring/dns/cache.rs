@@ -16,25 +16,30 @@ pub(super) fn build_cache(config: &Config) -> Arc<DnsCache> {
         info!(
             strategy = config.dns.cache_eviction_strategy.as_str(),
             max_entries = config.dns.cache_max_entries,
+            max_memory_mb = config.dns.cache_max_memory_mb,
             "Cache enabled"
         );
-        Arc::new(DnsCache::new(DnsCacheConfig {
-            max_entries: config.dns.cache_max_entries,
-            eviction_strategy,
-            min_threshold: config.dns.cache_min_hit_rate,
-            refresh_threshold: config.dns.cache_refresh_threshold,
-            batch_eviction_percentage: config.dns.cache_batch_eviction_percentage,
-            adaptive_thresholds: config.dns.cache_adaptive_thresholds,
-            min_frequency: config.dns.cache_min_frequency,
-            min_lfuk_score: config.dns.cache_min_lfuk_score,
-            shard_amount: config.dns.cache_shard_amount,
-            access_window_secs: config.dns.cache_access_window_secs,
-            eviction_sample_size: config.dns.cache_eviction_sample_size,
-            lfuk_k_value: 0.5,
-            refresh_sample_rate: 1.0,
-            min_ttl: config.dns.cache_min_ttl,
-            max_ttl: config.dns.cache_max_ttl,
-        }))
+        Arc::new(
+            DnsCache::new(DnsCacheConfig {
+                max_entries: config.dns.cache_max_entries,
+                eviction_strategy,
+                min_threshold: config.dns.cache_min_hit_rate,
+                refresh_threshold: config.dns.cache_refresh_threshold,
+                batch_eviction_percentage: config.dns.cache_batch_eviction_percentage,
+                adaptive_thresholds: config.dns.cache_adaptive_thresholds,
+                min_frequency: config.dns.cache_min_frequency,
+                min_lfuk_score: config.dns.cache_min_lfuk_score,
+                shard_amount: config.dns.cache_shard_amount,
+                access_window_secs: config.dns.cache_access_window_secs,
+                eviction_sample_size: config.dns.cache_eviction_sample_size,
+                lfuk_k_value: 0.5,
+                refresh_sample_rate: 1.0,
+                min_ttl: config.dns.cache_min_ttl,
+                max_ttl: config.dns.cache_max_ttl,
+                l1_capacity: l1_capacity(config),
+            })
+            .with_max_memory_mb(config.dns.cache_max_memory_mb),
+        )
     } else {
         Arc::new(DnsCache::new(DnsCacheConfig {
             max_entries: 0,
@@ -52,10 +57,19 @@ pub(super) fn build_cache(config: &Config) -> Arc<DnsCache> {
             refresh_sample_rate: 1.0,
             min_ttl: config.dns.cache_min_ttl,
             max_ttl: config.dns.cache_max_ttl,
+            l1_capacity: l1_capacity(config),
         }))
     }
 }
 
+fn l1_capacity(config: &Config) -> usize {
+    if config.dns.cache_l1_enabled {
+        config.dns.cache_l1_size
+    } else {
+        0
+    }
+}
+
 pub(super) fn preload_local_records_into_cache(
     cache: &Arc<DnsCache>,
     records: &[ferrous_dns_domain::LocalDnsRecord],
@@ -67,6 +81,11 @@ pub(super) fn preload_local_records_into_cache(
     let mut error_count = 0;
 
     for record in records {
+        // View records are answered per client by the split-horizon views.
+        if record.view.is_some() {
+            continue;
+        }
+
         let fqdn = record.fqdn(default_domain);
 
         let ip: std::net::IpAddr = match record.ip.parse() {
@@ -4,52 +4,93 @@ mod resolver;
 
 use crate::server::dns::connection_limiter::ConnectionLimiter;
 use ferrous_dns_application::ports::{
-    CacheMaintenancePort, DgaEvictionTarget, DgaFlagStore, NxdomainHijackIpStore,
-    NxdomainHijackProbeTarget, PtrRecordRegistry, ResponseIpFilterEvictionTarget,
-    ResponseIpFilterStore, TunnelingEvictionTarget, TunnelingFlagStore,
+    CacheMaintenancePort, DgaEvictionTarget, DgaFlagStore, DnsCachePort, DnsResolver,
+    FaultInjectionPort, IpBlocklistSourceRepository, LocalRecordRepository, LocalZonePort,
+    NxdomainHijackIpStore, NxdomainHijackProbeTarget, PluginHookPort, PtrRecordRegistry,
+    RecordSource, ResponseIpFilterEvictionTarget, ResponseIpFilterStore, SecondaryZonePort,
+    SecondaryZoneRepository, SplitHorizonPort, TunnelingEvictionTarget, TunnelingFlagStore,
 };
+use ferrous_dns_application::use_cases::dns::query_budget::QueryBudget;
 use ferrous_dns_application::use_cases::dns::rate_limiter::DnsRateLimiter;
 use ferrous_dns_application::use_cases::dns::tsc_timer;
 use ferrous_dns_application::use_cases::dns::DnsCookieGuard;
-use ferrous_dns_application::use_cases::HandleDnsQueryUseCase;
+use ferrous_dns_application::use_cases::{
+    ApplyDnsUpdateUseCase, HandleDnsQueryUseCase, SyncRecordSourceUseCase,
+};
 use ferrous_dns_domain::Config;
+use ferrous_dns_infrastructure::discovery::{
+    DockerRecordSource, KubernetesRecordSource, VpnPeerSource,
+};
+use ferrous_dns_infrastructure::dns::resolver::ConditionalForwards;
 use ferrous_dns_infrastructure::dns::{
     cache::DnsCache, cache_maintenance::DnsCacheMaintenance, events::QueryEventEmitter,
-    resolver::LocalPtrResolver, DgaDetector, HealthChecker, HickoryDnsResolver,
-    NxdomainHijackDetector, PoolManager, ResponseIpFilterDetector, TunnelingDetector,
+    forwarding::TsigKeyring, resolver::LocalPtrResolver, transport, transport::BootstrapResolver,
+    AccessControlRegistry, DgaDetector, DynamicUpdateHandler, HealthChecker, HickoryDnsResolver,
+    InflightRegistry, LocalZoneStore, NxdomainHijackDetector, PoolManager, QueryMetrics,
+    RecentBlocks, RefreshBudget, ResponseIpFilterDetector, SecondaryZoneStore, Sinkhole,
+    SinkholeTelemetry, SlowQueryLog, SplitHorizonStore, TunnelingDetector,
+    UpstreamAddressRefresher,
 };
 use ferrous_dns_jobs::{
-    DgaEvictionJob, NxdomainHijackEvictionJob, ResponseIpFilterEvictionJob, TunnelingEvictionJob,
+    DgaEvictionJob, NxdomainHijackEvictionJob, RecordSourceSyncJob, ResponseIpFilterEvictionJob,
+    SecondaryZoneRefreshJob, TunnelingEvictionJob, UpstreamAddressRefreshJob,
 };
 use std::sync::Arc;
-use tracing::info;
+use tokio::sync::RwLock;
+use tracing::{error, info};
 
 use super::Repositories;
 
 pub struct DnsServices {
     pub cache: Arc<DnsCache>,
     pub handler_use_case: Arc<HandleDnsQueryUseCase>,
+    pub resolver: Arc<dyn DnsResolver>,
     pub pool_manager: Arc<PoolManager>,
     pub health_checker: Option<Arc<HealthChecker>>,
     pub cache_maintenance: Option<Arc<dyn CacheMaintenancePort>>,
     pub ptr_registry: Option<Arc<dyn PtrRecordRegistry>>,
+    pub client_ptr_registry: Option<Arc<dyn PtrRecordRegistry>>,
     pub tcp_conn_limiter: ConnectionLimiter,
     pub dot_conn_limiter: ConnectionLimiter,
+    pub access_control: Arc<AccessControlRegistry>,
+    pub sinkhole: Option<Arc<Sinkhole>>,
+    pub sinkhole_telemetry: Arc<SinkholeTelemetry>,
+    pub recent_blocks: Arc<RecentBlocks>,
+    pub conditional_forwards: Arc<ConditionalForwards>,
+    pub slow_query_log: Arc<SlowQueryLog>,
+    pub query_metrics: Arc<QueryMetrics>,
+    pub inflight_registry: Arc<InflightRegistry>,
+    pub faults: Option<Arc<dyn FaultInjectionPort>>,
+    pub query_events: QueryEventEmitter,
+    pub split_horizon: Arc<SplitHorizonStore>,
+    pub local_zone: Arc<LocalZoneStore>,
     pub tunneling_eviction_job: Option<TunnelingEvictionJob>,
     pub nxdomain_hijack_eviction_job: Option<NxdomainHijackEvictionJob>,
     pub response_ip_filter_eviction_job: Option<ResponseIpFilterEvictionJob>,
     pub dga_eviction_job: Option<DgaEvictionJob>,
+    pub secondary_zone_refresh_job: Option<SecondaryZoneRefreshJob>,
+    pub upstream_address_refresh_job: Option<UpstreamAddressRefreshJob>,
+    pub tsig_keys: Arc<TsigKeyring>,
 }
 
 impl DnsServices {
     pub async fn new(config: &Config, repos: &Repositories) -> anyhow::Result<Self> {
         info!("Initializing DNS services with load balancing");
         tsc_timer::init();
+        transport::https::configure(&config.dns.doh_upstream);
 
         let emitter = pool::setup_event_logger(repos);
         let health_checker = pool::setup_health_checker(config);
-        let pool_manager =
-            pool::setup_pool_manager(config, health_checker.clone(), emitter.clone()).await?;
+        let tsig_keys = pool::load_tsig_keys(config)?;
+        let bootstrap = pool::setup_bootstrap_resolver(config, repos).await;
+        let pool_manager = pool::setup_pool_manager(
+            config,
+            health_checker.clone(),
+            emitter.clone(),
+            &tsig_keys,
+            &bootstrap,
+        )
+        .await?;
 
         pool::start_health_checker_task(health_checker.clone(), &pool_manager, config);
         let stored_health_checker = health_checker.clone();
@@ -58,67 +99,138 @@ impl DnsServices {
         let pool_manager_clone = Arc::clone(&pool_manager);
 
         let pool_manager_for_dnssec = Arc::new(
-            PoolManager::new(
+            PoolManager::new_with_bootstrap(
                 config.dns.pools.clone(),
                 health_checker,
                 QueryEventEmitter::new_disabled(),
+                bootstrap.clone(),
             )
-            .await?,
+            .await?
+            .with_tsig_keys(&tsig_keys)?,
         );
+        let mut refreshed_pools = vec![pool_manager.clone(), pool_manager_for_dnssec.clone()];
 
+        let conditional_forwards =
+            Arc::new(ConditionalForwards::new(&config.dns.conditional_forwards));
         let mut dns_resolver = resolver::build_resolver(
             pool_manager,
             pool_manager_for_dnssec,
             config,
             repos,
+            &conditional_forwards,
             timeout_ms,
         )?;
         let dns_cache = cache::build_cache(config);
+        dns_cache.set_ttl_overrides(repos.cache_ttl_override_engine.clone());
+        let inflight_registry = Arc::new(InflightRegistry::new(config.dns.cache_inflight_shards));
 
         if config.dns.cache_enabled {
             dns_resolver = dns_resolver
                 .with_inflight_shards(config.dns.cache_inflight_shards)
+                .with_inflight_registry(inflight_registry.clone())
                 .with_cache(dns_cache.clone(), config.dns.cache_ttl);
+        } else if config.dns.dnssec_enabled {
+            dns_resolver = dns_resolver.with_dnssec_cache(dns_cache.dnssec_cache());
         }
 
         let cache_maintenance = Self::setup_cache_maintenance(
             config,
             &dns_cache,
             stored_health_checker.clone(),
+            &tsig_keys,
+            &bootstrap,
+            &mut refreshed_pools,
             timeout_ms,
             repos,
         )
         .await?;
+        let upstream_address_refresh_job =
+            (config.dns.bootstrap.refresh_interval_secs > 0).then(|| {
+                UpstreamAddressRefreshJob::new(Arc::new(UpstreamAddressRefresher::new(
+                    refreshed_pools,
+                )))
+                .with_interval(config.dns.bootstrap.refresh_interval_secs)
+            });
 
-        let ptr_registry: Option<Arc<dyn PtrRecordRegistry>> =
-            if !config.dns.local_records.is_empty() {
-                info!(
-                    count = config.dns.local_records.len(),
-                    "Preloading local DNS records into permanent cache..."
-                );
-                cache::preload_local_records_into_cache(
-                    &dns_cache,
-                    &config.dns.local_records,
-                    &config.dns.local_domain,
-                );
-                info!("✓ Local DNS records preloaded (cached permanently, <0.1ms resolution)");
-
-                let dummy_inner: Arc<dyn ferrous_dns_application::ports::DnsResolver> =
-                    Arc::new(HickoryDnsResolver::new_with_pools(
-                        pool_manager_clone.clone(),
-                        timeout_ms,
-                        false,
-                        None,
-                    )?);
-                let local_ptr = Arc::new(LocalPtrResolver::from_local_records(
-                    &config.dns.local_records,
-                    &config.dns.local_domain,
-                    dummy_inner,
-                ));
-                dns_resolver = dns_resolver.with_local_ptr_map(Arc::clone(&local_ptr.map));
-                Some(local_ptr as Arc<dyn PtrRecordRegistry>)
-            } else {
+        if !config.dns.local_records.is_empty() {
+            info!(
+                count = config.dns.local_records.len(),
+                "Preloading local DNS records into permanent cache..."
+            );
+            cache::preload_local_records_into_cache(
+                &dns_cache,
+                &config.dns.local_records,
+                &config.dns.local_domain,
+            );
+            info!("✓ Local DNS records preloaded (cached permanently, <0.1ms resolution)");
+        }
+
+        let local_zone = LocalZoneStore::new(
+            repos.local_record.clone() as Arc<dyn LocalRecordRepository>,
+            config.dns.local_domain.clone(),
+        )
+        .await?;
+        let secondary_zones = if config.dns.secondary_zones.is_empty() {
+            None
+        } else {
+            let store = SecondaryZoneStore::new(
+                &config.dns.secondary_zones,
+                &tsig_keys,
+                repos.secondary_zone.clone() as Arc<dyn SecondaryZoneRepository>,
+                std::time::Duration::from_secs(config.dns.query_timeout.max(1) * 10),
+            )
+            .await?;
+            info!(
+                zones = config.dns.secondary_zones.len(),
+                "Secondary zones enabled"
+            );
+            Some(store as Arc<dyn SecondaryZonePort>)
+        };
+        let secondary_zone_refresh_job = secondary_zones.clone().map(SecondaryZoneRefreshJob::new);
+
+        let stored_local_records = repos.local_record.get_all().await?;
+        if !stored_local_records.is_empty() {
+            info!(
+                count = stored_local_records.len(),
+                "Loaded local DNS records from the database"
+            );
+        }
+
+        // Always present so that records created through the API get PTR
+        // answers without a restart.
+        let ptr_registry: Option<Arc<dyn PtrRecordRegistry>> = {
+            let dummy_inner: Arc<dyn ferrous_dns_application::ports::DnsResolver> =
+                Arc::new(HickoryDnsResolver::new_with_pools(
+                    pool_manager_clone.clone(),
+                    timeout_ms,
+                    false,
+                    None,
+                )?);
+            let local_ptr = Arc::new(LocalPtrResolver::from_local_records(
+                &config.dns.local_records,
+                &config.dns.local_domain,
+                dummy_inner,
+            ));
+            for record in &stored_local_records {
+                if let Some(ip) = record.ptr_address() {
+                    local_ptr.register(
+                        ip,
+                        Arc::from(record.fqdn(&config.dns.local_domain)),
+                        record.ttl,
+                    );
+                }
+            }
+            dns_resolver = dns_resolver.with_local_ptr_map(Arc::clone(&local_ptr.map));
+            Some(local_ptr as Arc<dyn PtrRecordRegistry>)
+        };
+
+        let client_ptr_registry: Option<Arc<dyn PtrRecordRegistry>> =
+            if config.dns.local_networks.is_empty() {
                 None
+            } else {
+                let zone = resolver::setup_client_ptr_zone(config, repos).await;
+                dns_resolver = dns_resolver.with_client_ptr_zone(Arc::clone(&zone));
+                Some(zone as Arc<dyn PtrRecordRegistry>)
             };
 
         let resolver = Arc::new(dns_resolver);
@@ -184,23 +296,41 @@ impl DnsServices {
                 (None, None)
             };
 
+        let split_horizon = {
+            let mut dns = config.dns.clone();
+            dns.local_records.extend(local_zone.view_records());
+            SplitHorizonStore::new(&dns)
+        };
+
         let mut handler = HandleDnsQueryUseCase::new(
             resolver.clone(),
             repos.block_filter_engine.clone(),
             repos.query_log.clone(),
         )
         .with_safe_search(repos.safe_search_engine.clone())
+        .with_query_policy(repos.query_policy_engine.clone())
+        .with_dns_rewrites(repos.dns_rewrite_engine.clone())
+        .with_split_horizon(split_horizon.clone() as Arc<dyn SplitHorizonPort>)
+        .with_local_zone(local_zone.clone() as Arc<dyn LocalZonePort>)
+        .with_record_type_filter(repos.record_type_filter.clone())
+        .with_group_cache_partitions(config.dns.cache_partition_by_group)
+        .with_max_cname_chain(config.dns.response_limits.max_cname_chain)
+        .with_minimal_any_responses(config.dns.minimal_any_responses)
+        .with_aaaa_filter(repos.aaaa_filter.clone())
         .with_client_tracking(
             repos.client.clone(),
             config.database.client_tracking_interval,
         )
-        .with_rebinding_protection(
-            config.dns.rebinding_protection_enabled,
-            config.dns.local_domain.as_deref(),
-            &config.dns.rebinding_allowlist,
-        )
         .with_rate_limiter(rate_limiter);
 
+        if let Some(zones) = secondary_zones {
+            handler = handler.with_secondary_zones(zones);
+        }
+
+        if let Some(plugins) = plugin_host(config)? {
+            handler = handler.with_plugins(plugins);
+        }
+
         if let Some((ref detector, ref tx)) = tunneling_detector {
             handler = handler
                 .with_tunneling_detection(&config.dns.tunneling_detection)
@@ -220,11 +350,12 @@ impl DnsServices {
             .dns
             .response_ip_filter
             .enabled
-            && !config.dns.response_ip_filter.ip_list_urls.is_empty()
         {
-            let detector = Arc::new(ResponseIpFilterDetector::new(
-                &config.dns.response_ip_filter,
-            ));
+            let detector = Arc::new(
+                ResponseIpFilterDetector::new(&config.dns.response_ip_filter).with_sources(
+                    repos.ip_blocklist_source.clone() as Arc<dyn IpBlocklistSourceRepository>,
+                ),
+            );
             let http_client = reqwest::Client::builder()
                 .user_agent(format!(
                     "ferrous-dns/{} (response-ip-filter)",
@@ -303,6 +434,33 @@ impl DnsServices {
             );
         }
 
+        let slow_query_config = &config.logging.slow_queries;
+        let slow_query_log = Arc::new(SlowQueryLog::new(slow_query_config.capacity));
+        if slow_query_config.enabled {
+            handler = handler.with_slow_query_log(
+                slow_query_log.clone(),
+                std::time::Duration::from_millis(slow_query_config.threshold_ms),
+            );
+            info!(
+                threshold_ms = slow_query_config.threshold_ms,
+                capacity = slow_query_config.capacity,
+                "Slow-query log enabled"
+            );
+        }
+
+        let query_metrics = Arc::new(QueryMetrics::new());
+        handler = handler.with_query_metrics(query_metrics.clone());
+
+        let query_budget =
+            QueryBudget::from_config(&config.dns.query_budget, config.dns.dnssec_enabled);
+        if let Some(budget) = query_budget {
+            info!(
+                total_ms = budget.total().as_millis() as u64,
+                "Per-query time budget enabled"
+            );
+        }
+        handler = handler.with_query_budget(query_budget);
+
         let handler_use_case = Arc::new(handler);
 
         let tcp_conn_limiter =
@@ -310,28 +468,158 @@ impl DnsServices {
         let dot_conn_limiter =
             ConnectionLimiter::new(config.dns.rate_limit.dot_max_connections_per_ip);
 
+        let sinkhole_telemetry = Arc::new(SinkholeTelemetry::new());
+        let recent_blocks = Arc::new(RecentBlocks::new());
+        let sinkhole = Sinkhole::from_config(&config.blocking).map(|mut sinkhole| {
+            info!(
+                ipv4 = ?config.blocking.sinkhole.ipv4,
+                ipv6 = ?config.blocking.sinkhole.ipv6,
+                telemetry = config.blocking.sinkhole.telemetry.enabled,
+                "Blocked queries answered with sinkhole addresses"
+            );
+            if config.blocking.sinkhole.telemetry.enabled {
+                sinkhole = sinkhole.with_telemetry(sinkhole_telemetry.clone());
+            }
+            if config.blocking.sinkhole.block_page.enabled {
+                sinkhole = sinkhole.with_recent_blocks(recent_blocks.clone());
+            }
+            Arc::new(sinkhole)
+        });
+
         info!("DNS services initialized successfully with load balancing");
 
         Ok(Self {
             cache: dns_cache,
             handler_use_case,
+            resolver,
             pool_manager: pool_manager_clone,
             health_checker: stored_health_checker,
             cache_maintenance,
             ptr_registry,
+            client_ptr_registry,
             tcp_conn_limiter,
             dot_conn_limiter,
+            access_control: Arc::new(AccessControlRegistry::new()),
+            sinkhole,
+            sinkhole_telemetry,
+            recent_blocks,
+            conditional_forwards,
+            slow_query_log,
+            query_metrics,
+            inflight_registry,
+            faults: fault_injection(config)?,
+            query_events: emitter,
+            split_horizon,
+            local_zone,
             tunneling_eviction_job,
             nxdomain_hijack_eviction_job,
             response_ip_filter_eviction_job,
             dga_eviction_job,
+            secondary_zone_refresh_job,
+            upstream_address_refresh_job,
+            tsig_keys: Arc::new(tsig_keys),
         })
     }
 
+    /// Builds the UPDATE handler when `dns.update_zones` is set. Updates are
+    /// written through the local records repository, so they reach the local
+    /// zone, the PTR registry and the cache like records from the web UI.
+    pub fn dynamic_update_handler(
+        &self,
+        config: &Config,
+        config_arc: Arc<RwLock<Config>>,
+        repos: &Repositories,
+    ) -> anyhow::Result<Option<Arc<DynamicUpdateHandler>>> {
+        if config.dns.update_zones.is_empty() {
+            return Ok(None);
+        }
+        let use_case = ApplyDnsUpdateUseCase::new(
+            config_arc,
+            repos.local_record.clone() as Arc<dyn LocalRecordRepository>,
+            self.local_zone.clone() as Arc<dyn LocalZonePort>,
+        )
+        .with_ptr_registry(self.ptr_registry.clone())
+        .with_dns_cache(Some(self.cache.clone() as Arc<dyn DnsCachePort>))
+        .with_audit_log(repos.audit_log.clone());
+        let handler = DynamicUpdateHandler::new(
+            Arc::new(use_case),
+            self.tsig_keys.clone(),
+            &config.dns.update_zones,
+        )?;
+        info!(
+            zones = config.dns.update_zones.len(),
+            "Dynamic updates enabled"
+        );
+        Ok(Some(Arc::new(handler)))
+    }
+
+    /// Builds a sync job for every enabled record source. A source that
+    /// cannot be set up is logged and left out.
+    pub fn record_source_jobs(
+        &self,
+        config: &Config,
+        config_arc: Arc<RwLock<Config>>,
+    ) -> Vec<RecordSourceSyncJob> {
+        let mut sources: Vec<Arc<dyn RecordSource>> = Vec::new();
+        let kubernetes = &config.dns.kubernetes;
+        if kubernetes.enabled {
+            match KubernetesRecordSource::new(kubernetes) {
+                Ok(source) => {
+                    info!(
+                        server = %source.server(),
+                        domain = %kubernetes.domain,
+                        "Kubernetes records enabled"
+                    );
+                    sources.push(Arc::new(source));
+                }
+                Err(e) => error!(error = %e, "Failed to set up Kubernetes records"),
+            }
+        }
+        let docker = &config.dns.docker;
+        if docker.enabled {
+            let source = DockerRecordSource::new(docker);
+            info!(
+                socket = %source.socket().display(),
+                domain = %docker.domain,
+                "Docker records enabled"
+            );
+            sources.push(Arc::new(source));
+        }
+        let vpn_peers = &config.dns.vpn_peers;
+        if vpn_peers.enabled {
+            let source = VpnPeerSource::new(vpn_peers);
+            info!(
+                provider = source.name(),
+                location = source.location(),
+                domain = %vpn_peers.domain,
+                "VPN peer records enabled"
+            );
+            sources.push(Arc::new(source));
+        }
+
+        sources
+            .into_iter()
+            .map(|source| {
+                let sync = SyncRecordSourceUseCase::new(
+                    config_arc.clone(),
+                    source,
+                    self.local_zone.clone() as Arc<dyn LocalZonePort>,
+                )
+                .with_ptr_registry(self.ptr_registry.clone())
+                .with_dns_cache(Some(self.cache.clone() as Arc<dyn DnsCachePort>));
+                RecordSourceSyncJob::new(Arc::new(sync))
+            })
+            .collect()
+    }
+
+    #[allow(clippy::too_many_arguments)]
     async fn setup_cache_maintenance(
         config: &Config,
         cache: &Arc<DnsCache>,
         health_checker: Option<Arc<HealthChecker>>,
+        tsig_keys: &TsigKeyring,
+        bootstrap: &Arc<BootstrapResolver>,
+        refreshed_pools: &mut Vec<Arc<PoolManager>>,
         timeout_ms: u64,
         repos: &Repositories,
     ) -> anyhow::Result<Option<Arc<dyn CacheMaintenancePort>>> {
@@ -339,17 +627,41 @@ impl DnsServices {
             return Ok(None);
         }
 
+        const REFRESH_INTERVAL_SECS: u64 = 60;
+
         let (stale_tx, stale_rx) = tokio::sync::mpsc::channel(256);
         cache.set_stale_refresh_sender(stale_tx);
 
+        if config.dns.cache_refresh_budget_fraction > 0.0 {
+            let mut budget = RefreshBudget::new(
+                config.dns.cache_refresh_budget_fraction,
+                config.dns.cache_refresh_budget_min_qps,
+                REFRESH_INTERVAL_SECS,
+            );
+            if let Some(checker) = &health_checker {
+                budget = budget.with_health_checker(Arc::clone(checker));
+            }
+            cache.set_refresh_budget(Arc::new(budget));
+            info!(
+                fraction = config.dns.cache_refresh_budget_fraction,
+                min_qps = config.dns.cache_refresh_budget_min_qps,
+                "Cache refresh budget enabled"
+            );
+        }
+
         let pool_manager_for_maintenance = Arc::new(
-            PoolManager::new(
+            PoolManager::new_with_bootstrap(
                 config.dns.pools.clone(),
                 health_checker,
                 QueryEventEmitter::new_disabled(),
+                bootstrap.clone(),
             )
-            .await?,
+            .await?
+            .with_case_randomization(config.dns.case_randomization)
+            .with_cookies(config.dns.dns_cookies.upstream)
+            .with_tsig_keys(tsig_keys)?,
         );
+        refreshed_pools.push(pool_manager_for_maintenance.clone());
 
         let resolver_for_maintenance: Arc<dyn ferrous_dns_application::ports::DnsResolver> =
             Arc::new(HickoryDnsResolver::new_with_pools(
@@ -370,7 +682,7 @@ impl DnsServices {
             cache.clone(),
             resolver_for_maintenance,
             Some(repos.query_log.clone()),
-            60,
+            REFRESH_INTERVAL_SECS,
         )) as Arc<dyn CacheMaintenancePort>))
     }
 }
@@ -405,3 +717,41 @@ fn resolve_cookie_secret(config: &ferrous_dns_domain::DnsCookiesConfig) -> [u8;
         bytes
     }
 }
+
+#[cfg(feature = "fault-injection")]
+fn fault_injection(config: &Config) -> anyhow::Result<Option<Arc<dyn FaultInjectionPort>>> {
+    if !config.dns.fault_injection {
+        return Ok(None);
+    }
+    tracing::warn!("Fault injection is enabled: /api/debug/faults can break upstream resolution");
+    Ok(Some(Arc::new(
+        ferrous_dns_infrastructure::dns::FaultInjectionControl,
+    )))
+}
+
+#[cfg(not(feature = "fault-injection"))]
+fn fault_injection(config: &Config) -> anyhow::Result<Option<Arc<dyn FaultInjectionPort>>> {
+    if config.dns.fault_injection {
+        anyhow::bail!(
+            "dns.fault_injection is enabled but this build lacks the `fault-injection` feature"
+        );
+    }
+    Ok(None)
+}
+
+#[cfg(feature = "lua-plugins")]
+fn plugin_host(config: &Config) -> anyhow::Result<Option<Arc<dyn PluginHookPort>>> {
+    if !config.dns.plugins.enabled {
+        return Ok(None);
+    }
+    let host = ferrous_dns_infrastructure::dns::LuaPluginHost::load(&config.dns.plugins)?;
+    Ok(Some(Arc::new(host)))
+}
+
+#[cfg(not(feature = "lua-plugins"))]
+fn plugin_host(config: &Config) -> anyhow::Result<Option<Arc<dyn PluginHookPort>>> {
+    if config.dns.plugins.enabled {
+        anyhow::bail!("dns.plugins is enabled but this build lacks the `lua-plugins` feature");
+    }
+    Ok(None)
+}
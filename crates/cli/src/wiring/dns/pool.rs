@@ -1,6 +1,7 @@
-use ferrous_dns_domain::Config;
+use ferrous_dns_domain::{Config, TsigKeyFile};
 use ferrous_dns_infrastructure::dns::{
-    events::QueryEventEmitter, query_logger::QueryEventLogger, HealthChecker, PoolManager,
+    events::QueryEventEmitter, forwarding::TsigKeyring, query_logger::QueryEventLogger,
+    transport::BootstrapResolver, HealthChecker, PoolManager,
 };
 use std::sync::Arc;
 use tracing::info;
@@ -33,13 +34,55 @@ pub(super) fn setup_health_checker(config: &Config) -> Option<Arc<HealthChecker>
     Some(checker)
 }
 
+/// Reads the TSIG keys from `dns.tsig_keys_file`, if one is configured.
+pub(super) fn load_tsig_keys(config: &Config) -> anyhow::Result<TsigKeyring> {
+    let Some(ref path) = config.dns.tsig_keys_file else {
+        return Ok(TsigKeyring::default());
+    };
+    let keyring = TsigKeyring::from_file(&TsigKeyFile::load(path)?)?;
+    info!(path = %path, "TSIG keys loaded");
+    Ok(keyring)
+}
+
+/// Resolver for the upstream hostnames, seeded with the addresses saved
+/// by earlier runs when `dns.bootstrap.persist` is on.
+pub(super) async fn setup_bootstrap_resolver(
+    config: &Config,
+    repos: &Repositories,
+) -> Arc<BootstrapResolver> {
+    let bootstrap = &config.dns.bootstrap;
+    let mut resolver = BootstrapResolver::from_config(bootstrap);
+    if bootstrap.persist {
+        resolver = resolver.with_store(repos.upstream_address.clone()).await;
+    }
+    if !bootstrap.servers.is_empty() || !bootstrap.pinned.is_empty() {
+        info!(
+            servers = bootstrap.servers.len(),
+            pinned = bootstrap.pinned.len(),
+            "Bootstrap resolver configured"
+        );
+    }
+    Arc::new(resolver)
+}
+
 pub(super) async fn setup_pool_manager(
     config: &Config,
     health_checker: Option<Arc<HealthChecker>>,
     emitter: QueryEventEmitter,
+    tsig_keys: &TsigKeyring,
+    bootstrap: &Arc<BootstrapResolver>,
 ) -> anyhow::Result<Arc<PoolManager>> {
     Ok(Arc::new(
-        PoolManager::new(config.dns.pools.clone(), health_checker, emitter).await?,
+        PoolManager::new_with_bootstrap(
+            config.dns.pools.clone(),
+            health_checker,
+            emitter,
+            bootstrap.clone(),
+        )
+        .await?
+        .with_case_randomization(config.dns.case_randomization)
+        .with_cookies(config.dns.dns_cookies.upstream)
+        .with_tsig_keys(tsig_keys)?,
     ))
 }
 
@@ -1,7 +1,9 @@
+use ferrous_dns_application::ports::{ClientRepository, PtrRecordRegistry, CLIENT_PTR_TTL};
 use ferrous_dns_domain::Config;
+use ferrous_dns_infrastructure::dns::resolver::{ClientPtrZone, ConditionalForwards};
 use ferrous_dns_infrastructure::dns::{HickoryDnsResolver, PoolManager};
 use std::sync::Arc;
-use tracing::info;
+use tracing::{info, warn};
 
 use crate::wiring::Repositories;
 
@@ -10,6 +12,7 @@ pub(super) fn build_resolver(
     pool_manager_for_dnssec: Arc<PoolManager>,
     config: &Config,
     repos: &Repositories,
+    conditional_forwards: &Arc<ConditionalForwards>,
     timeout_ms: u64,
 ) -> anyhow::Result<HickoryDnsResolver> {
     let mut resolver = HickoryDnsResolver::new_with_pools(
@@ -30,6 +33,17 @@ pub(super) fn build_resolver(
         resolver = resolver.with_dnssec_pool_manager(pool_manager_for_dnssec);
     }
 
+    if config.dns.rebinding_protection_enabled {
+        resolver = resolver.with_rebinding_protection(
+            config.dns.local_domain.as_deref(),
+            &config.dns.rebinding_allowlist,
+        );
+    }
+
+    if !conditional_forwards.is_empty() {
+        resolver = resolver.with_conditional_forwards(Arc::clone(conditional_forwards));
+    }
+
     info!(
         dnssec_enabled = config.dns.dnssec_enabled,
         pools = config.dns.pools.len(),
@@ -37,8 +51,52 @@ pub(super) fn build_resolver(
         block_non_fqdn = config.dns.block_non_fqdn,
         local_domain = ?config.dns.local_domain,
         local_dns_server = ?config.dns.local_dns_server,
+        rebinding_protection = config.dns.rebinding_protection_enabled,
+        conditional_forwards = config.dns.conditional_forwards.len(),
         "DNS resolver created with all features"
     );
 
     Ok(resolver)
 }
+
+const CLIENT_PRELOAD_PAGE: u32 = 1000;
+
+/// Builds the reverse zone for `dns.local_networks` and fills it with the
+/// hostnames of already known clients.
+pub(super) async fn setup_client_ptr_zone(
+    config: &Config,
+    repos: &Repositories,
+) -> Arc<ClientPtrZone> {
+    let zone = Arc::new(ClientPtrZone::new(
+        &config.dns.local_networks,
+        config.dns.local_domain.clone(),
+    ));
+
+    let mut offset = 0u32;
+    loop {
+        let clients = match repos.client.get_all(CLIENT_PRELOAD_PAGE, offset).await {
+            Ok(clients) => clients,
+            Err(e) => {
+                warn!(error = %e, "Failed to preload client hostnames for PTR answers");
+                break;
+            }
+        };
+        let page_len = clients.len() as u32;
+        for client in clients {
+            if let Some(hostname) = client.hostname {
+                zone.register(client.ip_address, hostname, CLIENT_PTR_TTL);
+            }
+        }
+        if page_len < CLIENT_PRELOAD_PAGE {
+            break;
+        }
+        offset += page_len;
+    }
+
+    info!(
+        networks = config.dns.local_networks.len(),
+        clients = zone.len(),
+        "Client PTR zone ready"
+    );
+    zone
+}
@@ -0,0 +1,23 @@
+use ferrous_dns_api::AppState;
+use ferrous_dns_api_grpc::GrpcAppState;
+use std::time::Duration;
+
+const QUERY_STREAM_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Constructs [`GrpcAppState`] from the dashboard [`AppState`] so both APIs
+/// share the same use cases, config handle and persistence.
+pub fn build_grpc_state(app_state: &AppState) -> GrpcAppState {
+    GrpcAppState {
+        get_stats: app_state.query.get_stats.clone(),
+        get_recent_queries: app_state.query.get_queries.clone(),
+        get_blocklist_sources: app_state.blocking.get_blocklist_sources.clone(),
+        create_blocklist_source: app_state.blocking.create_blocklist_source.clone(),
+        update_blocklist_source: app_state.blocking.update_blocklist_source.clone(),
+        delete_blocklist_source: app_state.blocking.delete_blocklist_source.clone(),
+        validate_api_token: app_state.auth.validate_api_token.clone(),
+        config: app_state.config.clone(),
+        config_file_persistence: app_state.config_file_persistence.clone(),
+        config_path: app_state.config_path.clone(),
+        stream_poll_interval: QUERY_STREAM_POLL_INTERVAL,
+    }
+}
@@ -0,0 +1,87 @@
+use ferrous_dns_application::ports::{GroupRepository, TenantRepository};
+use ferrous_dns_domain::Config;
+use ferrous_dns_infrastructure::dns::access_control::DEFAULT_ACL_NAME;
+use ferrous_dns_infrastructure::dns::{AccessControlRegistry, ListenerPolicy};
+use tracing::warn;
+
+use super::Repositories;
+
+/// A DNS listener address together with the client policy it enforces.
+pub struct ListenerSpec {
+    pub bind_addr: String,
+    pub policy: ListenerPolicy,
+}
+
+/// Policy for the listeners bound to `bind_address`, guarded by `[server.acl]`.
+pub fn default_listener_policy(config: &Config, acl: &AccessControlRegistry) -> ListenerPolicy {
+    let acl = acl.register(
+        DEFAULT_ACL_NAME,
+        &config.server.acl.allowed_clients,
+        config.server.acl.action,
+    );
+    ListenerPolicy::new(acl, None)
+}
+
+/// Resolves `[[server.listeners]]` into listener specs, registering each
+/// listener's ACL. Without configured listeners, a single listener on
+/// `bind_address:dns_port` guarded by `[server.acl]` is used.
+///
+/// A listener without `default_group` that is claimed by a tenant uses the
+/// tenant's default group. Tenant listener changes apply on restart.
+pub async fn build_listeners(
+    config: &Config,
+    repos: &Repositories,
+    acl: &AccessControlRegistry,
+) -> Vec<ListenerSpec> {
+    if config.server.listeners.is_empty() {
+        return vec![ListenerSpec {
+            bind_addr: format!("{}:{}", config.server.bind_address, config.server.dns_port),
+            policy: default_listener_policy(config, acl),
+        }];
+    }
+
+    let tenants = repos.tenant.get_all().await.unwrap_or_else(|e| {
+        warn!(error = %e, "Failed to load tenants for listener routing");
+        Vec::new()
+    });
+
+    let mut specs = Vec::with_capacity(config.server.listeners.len());
+    for listener in &config.server.listeners {
+        let default_group_id = match listener.default_group.as_deref() {
+            Some(name) => match repos.group.get_by_name(name).await {
+                Ok(Some(group)) => group.id,
+                Ok(None) => {
+                    warn!(
+                        listener = %listener.bind,
+                        group = name,
+                        "Listener default group not found; using the global default group"
+                    );
+                    None
+                }
+                Err(e) => {
+                    warn!(
+                        listener = %listener.bind,
+                        error = %e,
+                        "Failed to resolve listener default group"
+                    );
+                    None
+                }
+            },
+            None => tenants
+                .iter()
+                .find(|t| t.listener.as_deref() == Some(listener.bind.as_str()))
+                .and_then(|t| t.default_group_id),
+        };
+
+        let listener_acl = acl.register(
+            &listener.bind,
+            &listener.allowed_clients,
+            listener.action.unwrap_or(config.server.acl.action),
+        );
+        specs.push(ListenerSpec {
+            bind_addr: listener.bind.clone(),
+            policy: ListenerPolicy::new(listener_acl, default_group_id),
+        });
+    }
+    specs
+}
@@ -1,11 +1,29 @@
+pub mod acme;
+#[cfg(feature = "web")]
 pub mod app_state;
+#[cfg(feature = "web")]
+pub mod block_page;
 pub mod dns;
+#[cfg(feature = "web")]
+pub mod grpc_state;
+pub mod listeners;
+#[cfg(feature = "web")]
 pub mod pihole_state;
 pub mod repositories;
+pub mod standby;
 pub mod use_cases;
 
+pub use acme::AcmeServices;
+#[cfg(feature = "web")]
 pub use app_state::build_app_state;
+#[cfg(feature = "web")]
+pub use block_page::BlockPageServices;
 pub use dns::DnsServices;
+#[cfg(feature = "web")]
+pub use grpc_state::build_grpc_state;
+pub use listeners::{build_listeners, default_listener_policy};
+#[cfg(feature = "web")]
 pub use pihole_state::build_pihole_state;
 pub use repositories::Repositories;
+pub use standby::StandbyServices;
 pub use use_cases::UseCases;
@@ -1,42 +1,69 @@
-use ferrous_dns_application::ports::{ApiTokenRepository, SessionRepository, UserRepository};
 use ferrous_dns_application::ports::{
-    BlockFilterEnginePort, CustomServiceRepository, SafeSearchConfigRepository,
-    SafeSearchEnginePort, ScheduleProfileRepository, ScheduleStatePort, ServiceCatalogPort,
+    AaaaFilterPort, AlertRepository, AuditLogRepository, BackupStore, BlockFilterEnginePort,
+    CacheTtlOverrideEnginePort, CacheTtlOverrideRepository, CustomServiceRepository,
+    DatabaseMaintenancePort, DnsRewriteEnginePort, DnsRewriteRepository, DnssecHistoryRepository,
+    GroupRepository, QueryPolicyEnginePort, QueryPolicyRepository, RecordTypeFilterPort,
+    RecordTypePolicyRepository, SafeSearchConfigRepository, SafeSearchEnginePort,
+    ScheduleProfileRepository, ScheduleStatePort, ServiceCatalogPort, UnblockRequestRepository,
 };
+use ferrous_dns_application::ports::{ApiTokenRepository, SessionRepository, UserRepository};
 use ferrous_dns_application::use_cases::custom_services::custom_to_definition;
-use ferrous_dns_domain::config::DatabaseConfig;
-use ferrous_dns_infrastructure::dns::{BlockFilterEngine, SafeSearchEnforcer};
+use ferrous_dns_domain::config::{BlockingConfig, DatabaseConfig};
+use ferrous_dns_infrastructure::backup::SqliteBackupStore;
+use ferrous_dns_infrastructure::database::SqliteDatabaseMaintenance;
+use ferrous_dns_infrastructure::dns::{
+    AaaaFilterEnforcer, BlockFilterEngine, CacheTtlOverrideEnforcer, DnsRewriteEnforcer,
+    QueryPolicyEnforcer, RecordTypeEnforcer, SafeSearchEnforcer,
+};
 use ferrous_dns_infrastructure::repositories::{
-    api_token_repository::SqliteApiTokenRepository,
+    alert_repository::SqliteAlertRepository, api_token_repository::SqliteApiTokenRepository,
+    audit_log_repository::SqliteAuditLogRepository,
     blocked_service_repository::SqliteBlockedServiceRepository,
     blocklist_repository::SqliteBlocklistRepository,
     blocklist_source_repository::SqliteBlocklistSourceRepository,
+    cache_ttl_override_repository::SqliteCacheTtlOverrideRepository,
     client_repository::SqliteClientRepository,
     client_subnet_repository::SqliteClientSubnetRepository,
     custom_service_repository::SqliteCustomServiceRepository,
+    device_repository::SqliteDeviceRepository, dns_rewrite_repository::SqliteDnsRewriteRepository,
+    dnssec_history_repository::SqliteDnssecHistoryRepository,
     group_repository::SqliteGroupRepository,
+    ip_blocklist_source_repository::SqliteIpBlocklistSourceRepository,
+    local_record_repository::SqliteLocalRecordRepository,
     managed_domain_repository::SqliteManagedDomainRepository,
     query_log_repository::SqliteQueryLogRepository,
+    query_policy_repository::SqliteQueryPolicyRepository,
+    record_type_policy_repository::SqliteRecordTypePolicyRepository,
     regex_filter_repository::SqliteRegexFilterRepository,
     schedule_profile_repository::SqliteScheduleProfileRepository,
+    secondary_zone_repository::SqliteSecondaryZoneRepository,
     session_repository::SqliteSessionRepository,
     sqlite_safe_search_config_repository::SqliteSafeSearchConfigRepository,
+    tenant_repository::SqliteTenantRepository, tld_policy_repository::SqliteTldPolicyRepository,
+    unblock_request_repository::SqliteUnblockRequestRepository,
+    upstream_address_repository::SqliteUpstreamAddressRepository,
     user_repository::SqliteUserRepository, whitelist_repository::SqliteWhitelistRepository,
     whitelist_source_repository::SqliteWhitelistSourceRepository,
 };
 use ferrous_dns_infrastructure::schedule::ScheduleStateStore;
 use ferrous_dns_infrastructure::service_catalog::{CompositeServiceCatalog, ServiceCatalog};
 use sqlx::{Row, SqlitePool};
+use std::path::Path;
 use std::sync::Arc;
 use tracing::{info, warn};
 
+/// Written next to the database file when `blocking.index_snapshot` is on.
+const BLOCK_INDEX_SNAPSHOT_FILE: &str = "blocklist-index.snapshot";
+
 pub struct Repositories {
     pub query_log: Arc<SqliteQueryLogRepository>,
     pub blocklist: Arc<SqliteBlocklistRepository>,
     pub blocklist_source: Arc<SqliteBlocklistSourceRepository>,
     pub whitelist: Arc<SqliteWhitelistRepository>,
     pub whitelist_source: Arc<SqliteWhitelistSourceRepository>,
+    pub ip_blocklist_source: Arc<SqliteIpBlocklistSourceRepository>,
     pub client: Arc<SqliteClientRepository>,
+    pub device: Arc<SqliteDeviceRepository>,
     pub group: Arc<SqliteGroupRepository>,
     pub client_subnet: Arc<SqliteClientSubnetRepository>,
     pub managed_domain: Arc<SqliteManagedDomainRepository>,
@@ -47,11 +74,31 @@ pub struct Repositories {
     pub block_filter_engine: Arc<dyn BlockFilterEnginePort>,
     pub safe_search_config: Arc<SqliteSafeSearchConfigRepository>,
     pub safe_search_engine: Arc<dyn SafeSearchEnginePort>,
+    pub query_policy: Arc<SqliteQueryPolicyRepository>,
+    pub query_policy_engine: Arc<dyn QueryPolicyEnginePort>,
+    pub dns_rewrite: Arc<SqliteDnsRewriteRepository>,
+    pub dns_rewrite_engine: Arc<dyn DnsRewriteEnginePort>,
+    pub cache_ttl_override: Arc<SqliteCacheTtlOverrideRepository>,
+    pub cache_ttl_override_engine: Arc<dyn CacheTtlOverrideEnginePort>,
+    pub local_record: Arc<SqliteLocalRecordRepository>,
+    pub secondary_zone: Arc<SqliteSecondaryZoneRepository>,
+    pub upstream_address: Arc<SqliteUpstreamAddressRepository>,
+    pub tenant: Arc<SqliteTenantRepository>,
+    pub record_type_policy: Arc<SqliteRecordTypePolicyRepository>,
+    pub record_type_filter: Arc<dyn RecordTypeFilterPort>,
+    pub tld_policy: Arc<SqliteTldPolicyRepository>,
+    pub aaaa_filter: Arc<dyn AaaaFilterPort>,
     pub schedule_profile: Arc<dyn ScheduleProfileRepository>,
     pub schedule_state: Arc<dyn ScheduleStatePort>,
     pub session: Arc<dyn SessionRepository>,
     pub user: Arc<dyn UserRepository>,
     pub api_token: Arc<dyn ApiTokenRepository>,
+    pub database_maintenance: Arc<dyn DatabaseMaintenancePort>,
+    pub backup_store: Arc<dyn BackupStore>,
+    pub alert: Arc<dyn AlertRepository>,
+    pub dnssec_history: Arc<dyn DnssecHistoryRepository>,
+    pub audit_log: Arc<dyn AuditLogRepository>,
+    pub unblock_request: Arc<dyn UnblockRequestRepository>,
 }
 
 impl Repositories {
@@ -60,7 +107,7 @@ impl Repositories {
         query_log_pool: SqlitePool,
         read_pool: SqlitePool,
         db_config: &DatabaseConfig,
-        blocking_enabled: bool,
+        blocking: &BlockingConfig,
     ) -> Result<Self, ferrous_dns_domain::DomainError> {
         let blocklist = SqliteBlocklistRepository::load(write_pool.clone()).await?;
         let whitelist = SqliteWhitelistRepository::load(write_pool.clone()).await?;
@@ -80,13 +127,18 @@ impl Repositories {
 
         let schedule_state: Arc<dyn ScheduleStatePort> = Arc::new(ScheduleStateStore::new());
 
-        let block_filter_engine: Arc<dyn BlockFilterEnginePort> = BlockFilterEngine::new(
-            write_pool.clone(),
-            default_group_id,
-            schedule_state.clone(),
-            blocking_enabled,
-        )
-        .await?;
+        let index_snapshot = blocking
+            .index_snapshot
+            .then(|| Path::new(&db_config.path).with_file_name(BLOCK_INDEX_SNAPSHOT_FILE));
+        let block_filter_engine: Arc<dyn BlockFilterEnginePort> =
+            BlockFilterEngine::with_index_snapshot(
+                write_pool.clone(),
+                default_group_id,
+                schedule_state.clone(),
+                blocking.enabled,
+                index_snapshot,
+            )
+            .await?;
 
         let composite = CompositeServiceCatalog::new(ServiceCatalog::load());
         let service_catalog: Arc<dyn ServiceCatalogPort> = Arc::new(composite);
@@ -109,6 +161,38 @@ impl Repositories {
             SafeSearchEnforcer::new(repo).await?
         };
 
+        let query_policy = Arc::new(SqliteQueryPolicyRepository::new(write_pool.clone()));
+        let query_policy_engine: Arc<dyn QueryPolicyEnginePort> = {
+            let repo: Arc<dyn QueryPolicyRepository> = query_policy.clone();
+            QueryPolicyEnforcer::new(repo).await?
+        };
+
+        let dns_rewrite = Arc::new(SqliteDnsRewriteRepository::new(write_pool.clone()));
+        let dns_rewrite_engine: Arc<dyn DnsRewriteEnginePort> = {
+            let repo: Arc<dyn DnsRewriteRepository> = dns_rewrite.clone();
+            DnsRewriteEnforcer::new(repo).await?
+        };
+
+        let cache_ttl_override =
+            Arc::new(SqliteCacheTtlOverrideRepository::new(write_pool.clone()));
+        let cache_ttl_override_engine: Arc<dyn CacheTtlOverrideEnginePort> = {
+            let repo: Arc<dyn CacheTtlOverrideRepository> = cache_ttl_override.clone();
+            CacheTtlOverrideEnforcer::new(repo).await?
+        };
+
+        let record_type_policy =
+            Arc::new(SqliteRecordTypePolicyRepository::new(write_pool.clone()));
+        let record_type_filter: Arc<dyn RecordTypeFilterPort> = {
+            let repo: Arc<dyn RecordTypePolicyRepository> = record_type_policy.clone();
+            RecordTypeEnforcer::new(repo).await?
+        };
+
+        let group = Arc::new(SqliteGroupRepository::new(write_pool.clone()));
+        let aaaa_filter: Arc<dyn AaaaFilterPort> = {
+            let repo: Arc<dyn GroupRepository> = group.clone();
+            AaaaFilterEnforcer::new(repo).await?
+        };
+
         Ok(Self {
             query_log: Arc::new(SqliteQueryLogRepository::new(
                 write_pool.clone(),
@@ -120,8 +204,12 @@ impl Repositories {
             blocklist_source: Arc::new(SqliteBlocklistSourceRepository::new(write_pool.clone())),
             whitelist: Arc::new(whitelist),
             whitelist_source: Arc::new(SqliteWhitelistSourceRepository::new(write_pool.clone())),
+            ip_blocklist_source: Arc::new(SqliteIpBlocklistSourceRepository::new(
+                write_pool.clone(),
+            )),
             client: Arc::new(SqliteClientRepository::new(write_pool.clone(), db_config)),
-            group: Arc::new(SqliteGroupRepository::new(write_pool.clone())),
+            device: Arc::new(SqliteDeviceRepository::new(write_pool.clone())),
+            group,
             client_subnet: Arc::new(SqliteClientSubnetRepository::new(write_pool.clone())),
             managed_domain: Arc::new(SqliteManagedDomainRepository::new(write_pool.clone())),
             regex_filter: Arc::new(SqliteRegexFilterRepository::new(write_pool.clone())),
@@ -131,11 +219,31 @@ impl Repositories {
             block_filter_engine,
             safe_search_config: safe_search_config.clone(),
             safe_search_engine,
+            query_policy,
+            query_policy_engine,
+            dns_rewrite,
+            dns_rewrite_engine,
+            cache_ttl_override,
+            cache_ttl_override_engine,
+            local_record: Arc::new(SqliteLocalRecordRepository::new(write_pool.clone())),
+            secondary_zone: Arc::new(SqliteSecondaryZoneRepository::new(write_pool.clone())),
+            upstream_address: Arc::new(SqliteUpstreamAddressRepository::new(write_pool.clone())),
+            tenant: Arc::new(SqliteTenantRepository::new(write_pool.clone())),
+            record_type_policy,
+            record_type_filter,
+            tld_policy: Arc::new(SqliteTldPolicyRepository::new(write_pool.clone())),
+            aaaa_filter,
             schedule_profile: Arc::new(SqliteScheduleProfileRepository::new(write_pool.clone())),
             schedule_state,
             session: Arc::new(SqliteSessionRepository::new(Arc::new(write_pool.clone()))),
             user: Arc::new(SqliteUserRepository::new(Arc::new(write_pool.clone()))),
-            api_token: Arc::new(SqliteApiTokenRepository::new(Arc::new(write_pool))),
+            api_token: Arc::new(SqliteApiTokenRepository::new(Arc::new(write_pool.clone()))),
+            database_maintenance: Arc::new(SqliteDatabaseMaintenance::new(write_pool.clone())),
+            backup_store: Arc::new(SqliteBackupStore::new(write_pool.clone())),
+            alert: Arc::new(SqliteAlertRepository::new(write_pool.clone())),
+            dnssec_history: Arc::new(SqliteDnssecHistoryRepository::new(write_pool.clone())),
+            audit_log: Arc::new(SqliteAuditLogRepository::new(write_pool.clone())),
+            unblock_request: Arc::new(SqliteUnblockRequestRepository::new(write_pool)),
         })
     }
 }
@@ -0,0 +1,66 @@
+use anyhow::Context;
+use ferrous_dns_application::ports::DnsCachePort;
+use ferrous_dns_application::use_cases::{RestoreBackupUseCase, SyncFromPrimaryUseCase};
+use ferrous_dns_domain::Config;
+use ferrous_dns_infrastructure::backup::TarGzBackupArchiver;
+use ferrous_dns_infrastructure::repositories::TomlConfigFilePersistence;
+use ferrous_dns_infrastructure::standby::HttpPrimaryClient;
+use ferrous_dns_jobs::StandbySyncJob;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::{info, warn};
+
+use super::{DnsServices, Repositories};
+
+/// Keeps this instance a warm copy of a primary when `standby.enabled`.
+pub struct StandbyServices {
+    /// Shared with the API, which reports the sync status.
+    pub sync: Arc<SyncFromPrimaryUseCase>,
+    pub sync_job: StandbySyncJob,
+}
+
+impl StandbyServices {
+    pub fn new(
+        config: &Config,
+        config_arc: Arc<RwLock<Config>>,
+        config_path: Option<&str>,
+        repos: &Repositories,
+        dns_services: &DnsServices,
+    ) -> anyhow::Result<Option<Self>> {
+        let standby = &config.standby;
+        if !standby.enabled {
+            return Ok(None);
+        }
+
+        let primary =
+            HttpPrimaryClient::new(standby).context("Failed to set up the standby sync client")?;
+        info!(primary = %primary.base_url(), "Running as a standby");
+        if !config.blocking.index_snapshot {
+            warn!("blocking.index_snapshot is off; the primary's block lists cannot be copied");
+        }
+
+        // The restore leaves the block filter alone: the sync compiles the
+        // primary's lists itself.
+        let restore = RestoreBackupUseCase::new(
+            config_arc,
+            Arc::new(TomlConfigFilePersistence),
+            config_path.map(String::from),
+            repos.backup_store.clone(),
+            Arc::new(TarGzBackupArchiver),
+        );
+        let sync = Arc::new(
+            SyncFromPrimaryUseCase::new(
+                Arc::new(primary),
+                Arc::new(restore),
+                repos.block_filter_engine.clone(),
+                standby,
+            )
+            .with_cache(dns_services.cache.clone() as Arc<dyn DnsCachePort>),
+        );
+
+        Ok(Some(Self {
+            sync_job: StandbySyncJob::new(sync.clone()),
+            sync,
+        }))
+    }
+}
@@ -1,28 +1,44 @@
 use super::Repositories;
+use ferrous_dns_application::ports::{DeviceAddressRegistry, PtrRecordRegistry};
 use ferrous_dns_application::services::SubnetMatcherService;
 use ferrous_dns_application::use_cases::{
-    AssignClientGroupUseCase, AssignScheduleProfileUseCase, BlockServiceUseCase,
+    ApproveUnblockRequestUseCase, AssignClientGroupUseCase, AssignScheduleProfileUseCase,
+    BlockServiceUseCase, BulkAddBlocklistUseCase, BulkAddWhitelistUseCase,
     CleanupOldClientsUseCase, CleanupOldQueryLogsUseCase, CreateBlocklistSourceUseCase,
-    CreateClientSubnetUseCase, CreateCustomServiceUseCase, CreateGroupUseCase,
-    CreateManagedDomainUseCase, CreateManualClientUseCase, CreateRegexFilterUseCase,
-    CreateScheduleProfileUseCase, CreateWhitelistSourceUseCase, DeleteBlocklistSourceUseCase,
-    DeleteClientSubnetUseCase, DeleteClientUseCase, DeleteCustomServiceUseCase, DeleteGroupUseCase,
-    DeleteManagedDomainUseCase, DeleteRegexFilterUseCase, DeleteSafeSearchConfigsUseCase,
-    DeleteScheduleProfileUseCase, DeleteWhitelistSourceUseCase, GetBlockFilterStatsUseCase,
-    GetBlockedServicesUseCase, GetBlocklistSourcesUseCase, GetBlocklistUseCase,
-    GetCacheStatsUseCase, GetClientSubnetsUseCase, GetClientsUseCase, GetCustomServicesUseCase,
-    GetGroupsUseCase, GetManagedDomainsUseCase, GetQueryRateUseCase, GetQueryStatsUseCase,
-    GetRecentQueriesUseCase, GetRegexFiltersUseCase, GetSafeSearchConfigsUseCase,
-    GetScheduleProfilesUseCase, GetServiceCatalogUseCase, GetTimelineUseCase,
-    GetTopAllowedDomainsUseCase, GetTopBlockedDomainsUseCase, GetTopClientsUseCase,
-    GetWhitelistSourcesUseCase, GetWhitelistUseCase, ManageTimeSlotsUseCase, SyncArpCacheUseCase,
-    SyncHostnamesUseCase, ToggleSafeSearchUseCase, UnblockServiceUseCase,
-    UpdateBlocklistSourceUseCase, UpdateClientUseCase, UpdateCustomServiceUseCase,
-    UpdateGroupUseCase, UpdateManagedDomainUseCase, UpdateRegexFilterUseCase,
+    CreateCacheTtlOverrideUseCase, CreateClientSubnetUseCase, CreateCustomServiceUseCase,
+    CreateDnsRewriteUseCase, CreateGroupUseCase, CreateIpBlocklistSourceUseCase,
+    CreateManagedDomainUseCase, CreateManualClientUseCase, CreateQueryPolicyUseCase,
+    CreateRegexFilterUseCase, CreateScheduleProfileUseCase, CreateWhitelistSourceUseCase,
+    DatabaseMaintenanceUseCase, DeleteAlertUseCase, DeleteBlocklistSourceUseCase,
+    DeleteCacheTtlOverrideUseCase, DeleteClientSubnetUseCase, DeleteClientUseCase,
+    DeleteCustomServiceUseCase, DeleteDnsRewriteUseCase, DeleteGroupUseCase,
+    DeleteIpBlocklistSourceUseCase, DeleteManagedDomainUseCase, DeleteQueryPolicyUseCase,
+    DeleteRecordTypePolicyUseCase, DeleteRegexFilterUseCase, DeleteSafeSearchConfigsUseCase,
+    DeleteScheduleProfileUseCase, DeleteTldPolicyUseCase, DeleteUnblockRequestUseCase,
+    DeleteWhitelistSourceUseCase, DenyUnblockRequestUseCase, GetAlertsUseCase, GetAuditLogUseCase,
+    GetBlockFilterStatsUseCase, GetBlockedServicesUseCase, GetBlocklistSourcesUseCase,
+    GetBlocklistUseCase, GetCacheStatsUseCase, GetCacheTtlOverridesUseCase,
+    GetClientActivityUseCase, GetClientSubnetsUseCase, GetClientsUseCase, GetCustomServicesUseCase,
+    GetDnsRewritesUseCase, GetGroupsUseCase, GetIpBlocklistSourcesUseCase,
+    GetManagedDomainsUseCase, GetQueryPoliciesUseCase, GetQueryRateUseCase, GetQueryStatsUseCase,
+    GetRecentQueriesUseCase, GetRecordTypePoliciesUseCase, GetRegexFiltersUseCase,
+    GetSafeSearchConfigsUseCase, GetScheduleProfilesUseCase, GetServiceCatalogUseCase,
+    GetTimelineUseCase, GetTldPoliciesUseCase, GetTopAllowedDomainsUseCase,
+    GetTopBlockedDomainsUseCase, GetTopClientsUseCase, GetUnblockRequestsUseCase,
+    GetWhitelistSourcesUseCase, GetWhitelistUseCase, ManageTimeSlotsUseCase,
+    MergeDuplicateClientsUseCase, SetRecordTypePolicyUseCase, SetTldPolicyUseCase,
+    SubmitUnblockRequestUseCase, SyncArpCacheUseCase, SyncHostnamesUseCase,
+    ToggleSafeSearchUseCase, UnblockServiceUseCase, UpdateBlocklistSourceUseCase,
+    UpdateCacheTtlOverrideUseCase, UpdateClientUseCase, UpdateCustomServiceUseCase,
+    UpdateDnsRewriteUseCase, UpdateGroupUseCase, UpdateIpBlocklistSourceUseCase,
+    UpdateManagedDomainUseCase, UpdateQueryPolicyUseCase, UpdateRegexFilterUseCase,
     UpdateScheduleProfileUseCase, UpdateWhitelistSourceUseCase,
 };
 use ferrous_dns_infrastructure::dns::PoolManager;
-use ferrous_dns_infrastructure::system::{LinuxArpReader, PtrHostnameResolver};
+use ferrous_dns_infrastructure::system::{
+    ChainedHostnameResolver, LinuxArpReader, LlmnrHostnameResolver, NetbiosHostnameResolver,
+    PtrHostnameResolver,
+};
 use std::sync::Arc;
 
 pub struct UseCases {
@@ -31,6 +47,7 @@ pub struct UseCases {
     pub get_timeline: Arc<GetTimelineUseCase>,
     pub get_query_rate: Arc<GetQueryRateUseCase>,
     pub get_blocklist: Arc<GetBlocklistUseCase>,
+    pub bulk_add_blocklist: Arc<BulkAddBlocklistUseCase>,
     pub get_block_filter_stats: Arc<GetBlockFilterStatsUseCase>,
     pub get_cache_stats: Arc<GetCacheStatsUseCase>,
     pub get_top_blocked_domains: Arc<GetTopBlockedDomainsUseCase>,
@@ -38,9 +55,14 @@ pub struct UseCases {
     pub get_top_clients: Arc<GetTopClientsUseCase>,
     pub get_clients: Arc<GetClientsUseCase>,
     pub sync_arp: Arc<SyncArpCacheUseCase>,
+    pub merge_duplicate_clients: Arc<MergeDuplicateClientsUseCase>,
     pub sync_hostnames: Arc<SyncHostnamesUseCase>,
     pub cleanup_clients: Arc<CleanupOldClientsUseCase>,
     pub cleanup_query_logs: Arc<CleanupOldQueryLogsUseCase>,
+    pub database_maintenance: Arc<DatabaseMaintenanceUseCase>,
+    pub get_alerts: Arc<GetAlertsUseCase>,
+    pub delete_alert: Arc<DeleteAlertUseCase>,
+    pub get_audit_log: Arc<GetAuditLogUseCase>,
     pub get_groups: Arc<GetGroupsUseCase>,
     pub create_group: Arc<CreateGroupUseCase>,
     pub update_group: Arc<UpdateGroupUseCase>,
@@ -52,15 +74,21 @@ pub struct UseCases {
     pub create_manual_client: Arc<CreateManualClientUseCase>,
     pub update_client: Arc<UpdateClientUseCase>,
     pub delete_client: Arc<DeleteClientUseCase>,
+    pub get_client_activity: Arc<GetClientActivityUseCase>,
     pub get_blocklist_sources: Arc<GetBlocklistSourcesUseCase>,
     pub create_blocklist_source: Arc<CreateBlocklistSourceUseCase>,
     pub update_blocklist_source: Arc<UpdateBlocklistSourceUseCase>,
     pub delete_blocklist_source: Arc<DeleteBlocklistSourceUseCase>,
     pub get_whitelist: Arc<GetWhitelistUseCase>,
+    pub bulk_add_whitelist: Arc<BulkAddWhitelistUseCase>,
     pub get_whitelist_sources: Arc<GetWhitelistSourcesUseCase>,
     pub create_whitelist_source: Arc<CreateWhitelistSourceUseCase>,
     pub update_whitelist_source: Arc<UpdateWhitelistSourceUseCase>,
     pub delete_whitelist_source: Arc<DeleteWhitelistSourceUseCase>,
+    pub get_ip_blocklist_sources: Arc<GetIpBlocklistSourcesUseCase>,
+    pub create_ip_blocklist_source: Arc<CreateIpBlocklistSourceUseCase>,
+    pub update_ip_blocklist_source: Arc<UpdateIpBlocklistSourceUseCase>,
+    pub delete_ip_blocklist_source: Arc<DeleteIpBlocklistSourceUseCase>,
     pub get_managed_domains: Arc<GetManagedDomainsUseCase>,
     pub create_managed_domain: Arc<CreateManagedDomainUseCase>,
     pub update_managed_domain: Arc<UpdateManagedDomainUseCase>,
@@ -69,6 +97,11 @@ pub struct UseCases {
     pub create_regex_filter: Arc<CreateRegexFilterUseCase>,
     pub update_regex_filter: Arc<UpdateRegexFilterUseCase>,
     pub delete_regex_filter: Arc<DeleteRegexFilterUseCase>,
+    pub get_unblock_requests: Arc<GetUnblockRequestsUseCase>,
+    pub submit_unblock_request: Arc<SubmitUnblockRequestUseCase>,
+    pub approve_unblock_request: Arc<ApproveUnblockRequestUseCase>,
+    pub deny_unblock_request: Arc<DenyUnblockRequestUseCase>,
+    pub delete_unblock_request: Arc<DeleteUnblockRequestUseCase>,
     pub get_service_catalog: Arc<GetServiceCatalogUseCase>,
     pub get_blocked_services: Arc<GetBlockedServicesUseCase>,
     pub block_service: Arc<BlockServiceUseCase>,
@@ -87,6 +120,24 @@ pub struct UseCases {
     pub delete_schedule_profile: Arc<DeleteScheduleProfileUseCase>,
     pub manage_time_slots: Arc<ManageTimeSlotsUseCase>,
     pub assign_schedule_profile: Arc<AssignScheduleProfileUseCase>,
+    pub get_query_policies: Arc<GetQueryPoliciesUseCase>,
+    pub create_query_policy: Arc<CreateQueryPolicyUseCase>,
+    pub update_query_policy: Arc<UpdateQueryPolicyUseCase>,
+    pub delete_query_policy: Arc<DeleteQueryPolicyUseCase>,
+    pub get_record_type_policies: Arc<GetRecordTypePoliciesUseCase>,
+    pub set_record_type_policy: Arc<SetRecordTypePolicyUseCase>,
+    pub delete_record_type_policy: Arc<DeleteRecordTypePolicyUseCase>,
+    pub get_tld_policies: Arc<GetTldPoliciesUseCase>,
+    pub set_tld_policy: Arc<SetTldPolicyUseCase>,
+    pub delete_tld_policy: Arc<DeleteTldPolicyUseCase>,
+    pub get_dns_rewrites: Arc<GetDnsRewritesUseCase>,
+    pub create_dns_rewrite: Arc<CreateDnsRewriteUseCase>,
+    pub update_dns_rewrite: Arc<UpdateDnsRewriteUseCase>,
+    pub delete_dns_rewrite: Arc<DeleteDnsRewriteUseCase>,
+    pub get_cache_ttl_overrides: Arc<GetCacheTtlOverridesUseCase>,
+    pub create_cache_ttl_override: Arc<CreateCacheTtlOverrideUseCase>,
+    pub update_cache_ttl_override: Arc<UpdateCacheTtlOverrideUseCase>,
+    pub delete_cache_ttl_override: Arc<DeleteCacheTtlOverrideUseCase>,
 }
 
 impl UseCases {
@@ -94,11 +145,19 @@ impl UseCases {
         repos: &Repositories,
         pool_manager: Arc<PoolManager>,
         local_dns_server: Option<String>,
+        client_ptr_registry: Option<Arc<dyn PtrRecordRegistry>>,
+        device_addresses: Option<Arc<dyn DeviceAddressRegistry>>,
+        max_db_size_mb: u64,
+        audit_log_retention_days: u32,
     ) -> Self {
         let arp_reader = Arc::new(LinuxArpReader::new());
-        let hostname_resolver = Arc::new(
-            PtrHostnameResolver::new(pool_manager, 5).with_local_dns_server(local_dns_server),
-        );
+        let hostname_resolver = Arc::new(ChainedHostnameResolver::new(vec![
+            Arc::new(
+                PtrHostnameResolver::new(pool_manager, 5).with_local_dns_server(local_dns_server),
+            ),
+            Arc::new(NetbiosHostnameResolver::new(1000)),
+            Arc::new(LlmnrHostnameResolver::new(1000)),
+        ]));
 
         let subnet_matcher = Arc::new(SubnetMatcherService::new(repos.client_subnet.clone()));
 
@@ -111,6 +170,10 @@ impl UseCases {
             get_timeline: Arc::new(GetTimelineUseCase::new(repos.query_log.clone())),
             get_query_rate: Arc::new(GetQueryRateUseCase::new(repos.query_log.clone())),
             get_blocklist: Arc::new(GetBlocklistUseCase::new(repos.blocklist.clone())),
+            bulk_add_blocklist: Arc::new(BulkAddBlocklistUseCase::new(
+                repos.blocklist.clone(),
+                repos.block_filter_engine.clone(),
+            )),
             get_block_filter_stats: Arc::new(GetBlockFilterStatsUseCase::new(
                 repos.block_filter_engine.clone(),
             )),
@@ -123,16 +186,34 @@ impl UseCases {
             )),
             get_top_clients: Arc::new(GetTopClientsUseCase::new(repos.query_log.clone())),
             get_clients: Arc::new(GetClientsUseCase::new(repos.client.clone())),
-            sync_arp: Arc::new(SyncArpCacheUseCase::new(arp_reader, repos.client.clone())),
-            sync_hostnames: Arc::new(SyncHostnamesUseCase::new(
-                repos.client.clone(),
-                hostname_resolver,
+            sync_arp: Arc::new(
+                SyncArpCacheUseCase::new(arp_reader, repos.client.clone())
+                    .with_device_repo(repos.device.clone())
+                    .with_address_registry(device_addresses),
+            ),
+            merge_duplicate_clients: Arc::new(MergeDuplicateClientsUseCase::new(
+                repos.device.clone(),
             )),
+            sync_hostnames: Arc::new(
+                SyncHostnamesUseCase::new(repos.client.clone(), hostname_resolver)
+                    .with_ptr_registry(client_ptr_registry.clone()),
+            ),
             cleanup_clients: Arc::new(CleanupOldClientsUseCase::new(repos.client.clone())),
             cleanup_query_logs: Arc::new(CleanupOldQueryLogsUseCase::new(repos.query_log.clone())),
+            database_maintenance: Arc::new(
+                DatabaseMaintenanceUseCase::new(repos.database_maintenance.clone())
+                    .with_max_size_bytes(max_db_size_mb * 1024 * 1024)
+                    .with_audit_log(repos.audit_log.clone(), audit_log_retention_days),
+            ),
+            get_alerts: Arc::new(GetAlertsUseCase::new(repos.alert.clone())),
+            delete_alert: Arc::new(DeleteAlertUseCase::new(repos.alert.clone())),
+            get_audit_log: Arc::new(GetAuditLogUseCase::new(repos.audit_log.clone())),
             get_groups: Arc::new(GetGroupsUseCase::new(repos.group.clone())),
             create_group: Arc::new(CreateGroupUseCase::new(repos.group.clone())),
-            update_group: Arc::new(UpdateGroupUseCase::new(repos.group.clone())),
+            update_group: Arc::new(
+                UpdateGroupUseCase::new(repos.group.clone())
+                    .with_aaaa_filter(repos.aaaa_filter.clone()),
+            ),
             delete_group: Arc::new(DeleteGroupUseCase::new(repos.group.clone())),
             assign_client_group: Arc::new(AssignClientGroupUseCase::new(
                 repos.client.clone(),
@@ -151,17 +232,25 @@ impl UseCases {
             )),
             create_manual_client: Arc::new(
                 CreateManualClientUseCase::new(repos.client.clone(), repos.group.clone())
-                    .with_block_filter(repos.block_filter_engine.clone()),
+                    .with_block_filter(repos.block_filter_engine.clone())
+                    .with_subnet_matcher(subnet_matcher.clone())
+                    .with_ptr_registry(client_ptr_registry.clone()),
             ),
             update_client: Arc::new(
                 UpdateClientUseCase::new(repos.client.clone())
                     .with_group_repo(repos.group.clone())
-                    .with_block_filter(repos.block_filter_engine.clone()),
+                    .with_block_filter(repos.block_filter_engine.clone())
+                    .with_ptr_registry(client_ptr_registry.clone()),
             ),
             delete_client: Arc::new(
                 DeleteClientUseCase::new(repos.client.clone())
-                    .with_block_filter(repos.block_filter_engine.clone()),
+                    .with_block_filter(repos.block_filter_engine.clone())
+                    .with_ptr_registry(client_ptr_registry),
             ),
+            get_client_activity: Arc::new(GetClientActivityUseCase::new(
+                repos.client.clone(),
+                repos.query_log.clone(),
+            )),
             get_blocklist_sources: Arc::new(GetBlocklistSourcesUseCase::new(
                 repos.blocklist_source.clone(),
             )),
@@ -177,6 +266,10 @@ impl UseCases {
                 repos.blocklist_source.clone(),
             )),
             get_whitelist: Arc::new(GetWhitelistUseCase::new(repos.whitelist.clone())),
+            bulk_add_whitelist: Arc::new(BulkAddWhitelistUseCase::new(
+                repos.whitelist.clone(),
+                repos.block_filter_engine.clone(),
+            )),
             get_whitelist_sources: Arc::new(GetWhitelistSourcesUseCase::new(
                 repos.whitelist_source.clone(),
             )),
@@ -191,6 +284,18 @@ impl UseCases {
             delete_whitelist_source: Arc::new(DeleteWhitelistSourceUseCase::new(
                 repos.whitelist_source.clone(),
             )),
+            get_ip_blocklist_sources: Arc::new(GetIpBlocklistSourcesUseCase::new(
+                repos.ip_blocklist_source.clone(),
+            )),
+            create_ip_blocklist_source: Arc::new(CreateIpBlocklistSourceUseCase::new(
+                repos.ip_blocklist_source.clone(),
+            )),
+            update_ip_blocklist_source: Arc::new(UpdateIpBlocklistSourceUseCase::new(
+                repos.ip_blocklist_source.clone(),
+            )),
+            delete_ip_blocklist_source: Arc::new(DeleteIpBlocklistSourceUseCase::new(
+                repos.ip_blocklist_source.clone(),
+            )),
             get_managed_domains: Arc::new(GetManagedDomainsUseCase::new(
                 repos.managed_domain.clone(),
             )),
@@ -223,6 +328,25 @@ impl UseCases {
                 repos.regex_filter.clone(),
                 repos.block_filter_engine.clone(),
             )),
+            get_unblock_requests: Arc::new(GetUnblockRequestsUseCase::new(
+                repos.unblock_request.clone(),
+            )),
+            submit_unblock_request: Arc::new(SubmitUnblockRequestUseCase::new(
+                repos.unblock_request.clone(),
+                repos.block_filter_engine.clone(),
+            )),
+            approve_unblock_request: Arc::new(ApproveUnblockRequestUseCase::new(
+                repos.unblock_request.clone(),
+                repos.managed_domain.clone(),
+                repos.group.clone(),
+                repos.block_filter_engine.clone(),
+            )),
+            deny_unblock_request: Arc::new(DenyUnblockRequestUseCase::new(
+                repos.unblock_request.clone(),
+            )),
+            delete_unblock_request: Arc::new(DeleteUnblockRequestUseCase::new(
+                repos.unblock_request.clone(),
+            )),
             get_service_catalog: Arc::new(GetServiceCatalogUseCase::new(
                 repos.service_catalog.clone(),
             )),
@@ -296,6 +420,77 @@ impl UseCases {
                 repos.schedule_profile.clone(),
                 repos.group.clone(),
             )),
+            get_query_policies: Arc::new(GetQueryPoliciesUseCase::new(repos.query_policy.clone())),
+            create_query_policy: Arc::new(CreateQueryPolicyUseCase::new(
+                repos.query_policy.clone(),
+                repos.group.clone(),
+                repos.query_policy_engine.clone(),
+            )),
+            update_query_policy: Arc::new(UpdateQueryPolicyUseCase::new(
+                repos.query_policy.clone(),
+                repos.group.clone(),
+                repos.query_policy_engine.clone(),
+            )),
+            delete_query_policy: Arc::new(DeleteQueryPolicyUseCase::new(
+                repos.query_policy.clone(),
+                repos.query_policy_engine.clone(),
+            )),
+            get_record_type_policies: Arc::new(GetRecordTypePoliciesUseCase::new(
+                repos.record_type_policy.clone(),
+                repos.group.clone(),
+            )),
+            set_record_type_policy: Arc::new(SetRecordTypePolicyUseCase::new(
+                repos.record_type_policy.clone(),
+                repos.group.clone(),
+                repos.record_type_filter.clone(),
+            )),
+            delete_record_type_policy: Arc::new(DeleteRecordTypePolicyUseCase::new(
+                repos.record_type_policy.clone(),
+                repos.group.clone(),
+                repos.record_type_filter.clone(),
+            )),
+            get_tld_policies: Arc::new(GetTldPoliciesUseCase::new(
+                repos.tld_policy.clone(),
+                repos.group.clone(),
+            )),
+            set_tld_policy: Arc::new(SetTldPolicyUseCase::new(
+                repos.tld_policy.clone(),
+                repos.group.clone(),
+                repos.block_filter_engine.clone(),
+            )),
+            delete_tld_policy: Arc::new(DeleteTldPolicyUseCase::new(
+                repos.tld_policy.clone(),
+                repos.group.clone(),
+                repos.block_filter_engine.clone(),
+            )),
+            get_dns_rewrites: Arc::new(GetDnsRewritesUseCase::new(repos.dns_rewrite.clone())),
+            create_dns_rewrite: Arc::new(CreateDnsRewriteUseCase::new(
+                repos.dns_rewrite.clone(),
+                repos.dns_rewrite_engine.clone(),
+            )),
+            update_dns_rewrite: Arc::new(UpdateDnsRewriteUseCase::new(
+                repos.dns_rewrite.clone(),
+                repos.dns_rewrite_engine.clone(),
+            )),
+            delete_dns_rewrite: Arc::new(DeleteDnsRewriteUseCase::new(
+                repos.dns_rewrite.clone(),
+                repos.dns_rewrite_engine.clone(),
+            )),
+            get_cache_ttl_overrides: Arc::new(GetCacheTtlOverridesUseCase::new(
+                repos.cache_ttl_override.clone(),
+            )),
+            create_cache_ttl_override: Arc::new(CreateCacheTtlOverrideUseCase::new(
+                repos.cache_ttl_override.clone(),
+                repos.cache_ttl_override_engine.clone(),
+            )),
+            update_cache_ttl_override: Arc::new(UpdateCacheTtlOverrideUseCase::new(
+                repos.cache_ttl_override.clone(),
+                repos.cache_ttl_override_engine.clone(),
+            )),
+            delete_cache_ttl_override: Arc::new(DeleteCacheTtlOverrideUseCase::new(
+                repos.cache_ttl_override.clone(),
+                repos.cache_ttl_override_engine.clone(),
+            )),
         }
     }
 }
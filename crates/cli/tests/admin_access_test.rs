@@ -0,0 +1,78 @@
+#![cfg(feature = "web")]
+
+use ferrous_dns::server::{load_client_cert_verifier, AdminAccessGuard};
+use ferrous_dns_application::ports::TlsCertificatePort;
+use ferrous_dns_domain::AdminAccessConfig;
+use ferrous_dns_infrastructure::tls::TlsCertificateService;
+use std::net::SocketAddr;
+
+fn peer(addr: &str) -> Option<SocketAddr> {
+    Some(addr.parse().unwrap())
+}
+
+#[test]
+fn default_config_admits_everyone() {
+    let guard = AdminAccessGuard::new(&AdminAccessConfig::default());
+    assert!(guard.permits(peer("203.0.113.7:50000"), false));
+    assert!(guard.permits(None, false));
+}
+
+#[test]
+fn allowlist_admits_only_listed_networks() {
+    let guard = AdminAccessGuard::new(&AdminAccessConfig {
+        allowed_networks: vec!["10.8.0.0/24".into(), "fd00::/8".into()],
+        ..Default::default()
+    });
+    assert!(guard.permits(peer("10.8.0.12:50000"), false));
+    assert!(guard.permits(peer("[fd00::1]:50000"), false));
+    assert!(!guard.permits(peer("192.168.1.5:50000"), false));
+    assert!(!guard.permits(None, false));
+}
+
+#[test]
+fn client_ca_requires_a_certificate() {
+    let guard = AdminAccessGuard::new(&AdminAccessConfig {
+        client_ca_path: Some("/data/admin-ca.pem".into()),
+        ..Default::default()
+    });
+    assert!(!guard.permits(peer("10.8.0.12:50000"), false));
+    assert!(guard.permits(peer("10.8.0.12:50000"), true));
+}
+
+#[test]
+fn certificate_does_not_bypass_the_allowlist() {
+    let guard = AdminAccessGuard::new(&AdminAccessConfig {
+        allowed_networks: vec!["10.8.0.0/24".into()],
+        client_ca_path: Some("/data/admin-ca.pem".into()),
+    });
+    assert!(!guard.permits(peer("192.168.1.5:50000"), true));
+    assert!(guard.permits(peer("10.8.0.12:50000"), true));
+}
+
+#[tokio::test]
+async fn client_cert_verifier_loads_ca_bundle() {
+    let _ = rustls::crypto::aws_lc_rs::default_provider().install_default();
+    let dir = tempfile::tempdir().unwrap();
+    let ca = dir.path().join("ca.pem");
+    let key = dir.path().join("ca-key.pem");
+    TlsCertificateService
+        .generate_self_signed(ca.to_str().unwrap(), key.to_str().unwrap())
+        .await
+        .unwrap();
+
+    let required = load_client_cert_verifier(ca.to_str().unwrap(), false).unwrap();
+    assert!(required.client_auth_mandatory());
+    let optional = load_client_cert_verifier(ca.to_str().unwrap(), true).unwrap();
+    assert!(!optional.client_auth_mandatory());
+}
+
+#[test]
+fn client_cert_verifier_rejects_missing_or_empty_bundle() {
+    let dir = tempfile::tempdir().unwrap();
+    let missing = dir.path().join("missing.pem");
+    assert!(load_client_cert_verifier(missing.to_str().unwrap(), false).is_err());
+
+    let empty = dir.path().join("empty.pem");
+    std::fs::write(&empty, "").unwrap();
+    assert!(load_client_cert_verifier(empty.to_str().unwrap(), false).is_err());
+}
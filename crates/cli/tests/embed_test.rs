@@ -0,0 +1,58 @@
+use ferrous_dns::{Config, RecordType, Resolver};
+use ferrous_dns_domain::config::LocalDnsRecord;
+use std::net::{IpAddr, Ipv4Addr};
+
+const CLIENT: IpAddr = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 50));
+
+fn config_with_local_record() -> Config {
+    let mut config = Config::default();
+    config.dns.local_records.push(LocalDnsRecord {
+        hostname: "nas".to_string(),
+        domain: Some("home".to_string()),
+        ip: "192.168.1.10".to_string(),
+        record_type: "A".to_string(),
+        ttl: Some(300),
+        view: None,
+    });
+    config
+}
+
+async fn build(dir: &tempfile::TempDir) -> Resolver {
+    let db = dir.path().join("embed.db");
+    Resolver::builder(config_with_local_record())
+        .database_path(db.to_string_lossy())
+        .log_queries(false)
+        .build()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_resolves_local_record_in_process() {
+    let dir = tempfile::tempdir().unwrap();
+    let resolver = build(&dir).await;
+
+    let resolution = resolver
+        .resolve("nas.home", RecordType::A, CLIENT)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        resolution.addresses.as_slice(),
+        &[IpAddr::V4(Ipv4Addr::new(192, 168, 1, 10))]
+    );
+}
+
+#[tokio::test]
+async fn test_build_rejects_config_without_upstreams() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut config = config_with_local_record();
+    config.dns.upstream_servers.clear();
+
+    let result = Resolver::builder(config)
+        .database_path(dir.path().join("embed.db").to_string_lossy())
+        .build()
+        .await;
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+/// Access control for the listeners bound to `bind_address` (plain DNS, DoT
+/// and DoH). Listeners declared in `[[server.listeners]]` carry their own
+/// client list and fall back to this `action`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct AccessControlConfig {
+    /// Client CIDRs allowed to query. Empty allows everyone.
+    #[serde(default)]
+    pub allowed_clients: Vec<String>,
+
+    /// What to do with queries from clients outside `allowed_clients`.
+    #[serde(default)]
+    pub action: AclAction,
+}
+
+/// Action taken for a query from a client not allowed by an ACL.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AclAction {
+    /// Answer with REFUSED (Extended DNS Error 18, Prohibited).
+    #[default]
+    Refuse,
+    /// Send no response at all.
+    Drop,
+}
+
+impl AccessControlConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        validate_cidrs(&self.allowed_clients, "server.acl")
+    }
+}
+
+pub(super) fn validate_cidrs(cidrs: &[String], owner: &str) -> Result<(), String> {
+    for cidr in cidrs {
+        cidr.parse::<ipnetwork::IpNetwork>()
+            .map_err(|e| format!("Invalid allowed client '{}' for {}: {}", cidr, owner, e))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,166 @@
+use serde::{Deserialize, Serialize};
+
+/// Let's Encrypt production directory.
+pub const LETS_ENCRYPT_DIRECTORY: &str = "https://acme-v02.api.letsencrypt.org/directory";
+
+/// Let's Encrypt staging directory, for testing without hitting rate limits.
+pub const LETS_ENCRYPT_STAGING_DIRECTORY: &str =
+    "https://acme-staging-v02.api.letsencrypt.org/directory";
+
+/// Longest renewal window accepted; Let's Encrypt certificates last 90 days.
+const MAX_RENEW_BEFORE_DAYS: u32 = 60;
+
+/// ACME challenge used to prove control of the certificate's domains.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AcmeChallenge {
+    /// Serves `/.well-known/acme-challenge/<token>` over plain HTTP on
+    /// `http_port` while an order is pending.
+    #[default]
+    Http01,
+    /// Publishes `_acme-challenge.<domain>` TXT records in the local zone.
+    /// Only works when this server is authoritative for the domains on the
+    /// public internet.
+    Dns01,
+}
+
+/// Automatic certificate management for the DoT and DoH listeners. The
+/// certificate is written to `tls_cert_path`/`tls_key_path` and picked up by
+/// the listeners without a restart.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AcmeConfig {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Names the certificate is issued for; the first one is the subject.
+    #[serde(default)]
+    pub domains: Vec<String>,
+
+    /// Contact address registered with the ACME account.
+    #[serde(default)]
+    pub contact_email: Option<String>,
+
+    #[serde(default = "default_directory_url")]
+    pub directory_url: String,
+
+    #[serde(default)]
+    pub challenge: AcmeChallenge,
+
+    /// Port the HTTP-01 responder binds. The CA always connects to port
+    /// 80, so anything else needs a port forward.
+    #[serde(default = "default_http_port")]
+    pub http_port: u16,
+
+    /// Renew once the certificate expires within this many days.
+    #[serde(default = "default_renew_before_days")]
+    pub renew_before_days: u32,
+
+    /// How often the certificate is checked for renewal.
+    #[serde(default = "default_check_interval_secs")]
+    pub check_interval_secs: u64,
+
+    /// Where the ACME account key is kept between restarts.
+    #[serde(default = "default_account_path")]
+    pub account_path: String,
+}
+
+fn default_directory_url() -> String {
+    LETS_ENCRYPT_DIRECTORY.to_string()
+}
+
+fn default_http_port() -> u16 {
+    80
+}
+
+fn default_renew_before_days() -> u32 {
+    30
+}
+
+fn default_check_interval_secs() -> u64 {
+    43_200
+}
+
+fn default_account_path() -> String {
+    "/data/acme-account.json".to_string()
+}
+
+impl Default for AcmeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            domains: Vec::new(),
+            contact_email: None,
+            directory_url: default_directory_url(),
+            challenge: AcmeChallenge::default(),
+            http_port: default_http_port(),
+            renew_before_days: default_renew_before_days(),
+            check_interval_secs: default_check_interval_secs(),
+            account_path: default_account_path(),
+        }
+    }
+}
+
+impl AcmeConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.enabled {
+            return Ok(());
+        }
+        if self.domains.is_empty() {
+            return Err("server.encrypted_dns.acme.domains must list at least one domain".into());
+        }
+        for domain in &self.domains {
+            validate_domain(domain, self.challenge)?;
+        }
+        if !self.directory_url.starts_with("https://") {
+            return Err(format!(
+                "server.encrypted_dns.acme.directory_url must be an https:// URL, got '{}'",
+                self.directory_url
+            ));
+        }
+        if self
+            .contact_email
+            .as_deref()
+            .is_some_and(|email| !email.contains('@'))
+        {
+            return Err("server.encrypted_dns.acme.contact_email is not an email address".into());
+        }
+        if self.challenge == AcmeChallenge::Http01 && self.http_port == 0 {
+            return Err("server.encrypted_dns.acme.http_port cannot be 0".into());
+        }
+        if !(1..=MAX_RENEW_BEFORE_DAYS).contains(&self.renew_before_days) {
+            return Err(format!(
+                "server.encrypted_dns.acme.renew_before_days must be between 1 and {MAX_RENEW_BEFORE_DAYS}"
+            ));
+        }
+        if self.check_interval_secs == 0 {
+            return Err("server.encrypted_dns.acme.check_interval_secs cannot be 0".into());
+        }
+        Ok(())
+    }
+}
+
+fn validate_domain(domain: &str, challenge: AcmeChallenge) -> Result<(), String> {
+    let (wildcard, name) = match domain.strip_prefix("*.") {
+        Some(rest) => (true, rest),
+        None => (false, domain),
+    };
+    if wildcard && challenge != AcmeChallenge::Dns01 {
+        return Err(format!(
+            "ACME domain '{domain}': wildcard certificates need the dns-01 challenge"
+        ));
+    }
+    let valid = !name.is_empty()
+        && name.len() <= 253
+        && name.contains('.')
+        && name.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        });
+    if !valid {
+        return Err(format!(
+            "ACME domain '{domain}' is not a fully qualified domain name"
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+use super::access_control::validate_cidrs;
+
+/// Restricts who can reach the web dashboard and REST API, e.g. to a VPN
+/// subnet or to holders of a client certificate. A DoH endpoint co-hosted
+/// on `web_port` is not affected and stays public.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct AdminAccessConfig {
+    /// Source CIDRs allowed to reach the admin API. Empty allows everyone.
+    #[serde(default)]
+    pub allowed_networks: Vec<String>,
+
+    /// PEM file with the CA certificates that client certificates must
+    /// chain to. When set, admin requests need a valid client certificate
+    /// (mutual TLS), which requires `server.web_tls.enabled`.
+    #[serde(default)]
+    pub client_ca_path: Option<String>,
+}
+
+impl AdminAccessConfig {
+    pub fn validate(&self, web_tls_enabled: bool) -> Result<(), String> {
+        validate_cidrs(&self.allowed_networks, "server.admin_access")?;
+        if self.client_ca_path.is_some() && !web_tls_enabled {
+            return Err(
+                "server.admin_access.client_ca_path requires server.web_tls.enabled = true"
+                    .to_string(),
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+
+/// Configuration for per-client anomaly detection over the query log.
+///
+/// A background job scans each window of logged client queries and records an
+/// alert when a client shows a high NXDOMAIN rate, a high entropy of unique
+/// subdomains under one apex (tunneling/DGA-like), or a sudden query volume
+/// spike against its own moving baseline.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AnomalyDetectionConfig {
+    /// Disabled by default; requires `database.log_queries`.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Seconds per analysis window.
+    #[serde(default = "default_interval_secs")]
+    pub interval_secs: u64,
+
+    /// Clients with fewer queries in a window are not evaluated.
+    #[serde(default = "default_min_queries")]
+    pub min_queries: u64,
+
+    /// Fraction of a client's queries (0.0–1.0) answered NXDOMAIN that raises an alert.
+    #[serde(default = "default_nxdomain_rate_threshold")]
+    pub nxdomain_rate_threshold: f64,
+
+    /// Shannon entropy (bits/char) of the subdomains queried under one apex.
+    #[serde(default = "default_subdomain_entropy_threshold")]
+    pub subdomain_entropy_threshold: f64,
+
+    /// Unique subdomains under one apex needed before entropy is evaluated.
+    #[serde(default = "default_min_unique_subdomains")]
+    pub min_unique_subdomains: usize,
+
+    /// Window volume this many times the client's moving average is a spike.
+    #[serde(default = "default_volume_spike_factor")]
+    pub volume_spike_factor: f64,
+
+    /// Days to keep alerts before they are deleted.
+    #[serde(default = "default_retention_days")]
+    pub retention_days: u32,
+}
+
+impl Default for AnomalyDetectionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_secs: default_interval_secs(),
+            min_queries: default_min_queries(),
+            nxdomain_rate_threshold: default_nxdomain_rate_threshold(),
+            subdomain_entropy_threshold: default_subdomain_entropy_threshold(),
+            min_unique_subdomains: default_min_unique_subdomains(),
+            volume_spike_factor: default_volume_spike_factor(),
+            retention_days: default_retention_days(),
+        }
+    }
+}
+
+impl AnomalyDetectionConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.enabled {
+            return Ok(());
+        }
+        if self.interval_secs == 0 {
+            return Err("dns.anomaly_detection.interval_secs must be greater than 0".to_string());
+        }
+        if !(0.0..=1.0).contains(&self.nxdomain_rate_threshold) {
+            return Err(
+                "dns.anomaly_detection.nxdomain_rate_threshold must be between 0.0 and 1.0"
+                    .to_string(),
+            );
+        }
+        if self.subdomain_entropy_threshold <= 0.0 {
+            return Err(
+                "dns.anomaly_detection.subdomain_entropy_threshold must be greater than 0"
+                    .to_string(),
+            );
+        }
+        if self.volume_spike_factor <= 1.0 {
+            return Err(
+                "dns.anomaly_detection.volume_spike_factor must be greater than 1.0".to_string(),
+            );
+        }
+        if self.retention_days == 0 {
+            return Err("dns.anomaly_detection.retention_days must be greater than 0".to_string());
+        }
+        Ok(())
+    }
+}
+
+fn default_interval_secs() -> u64 {
+    300
+}
+
+fn default_min_queries() -> u64 {
+    50
+}
+
+fn default_nxdomain_rate_threshold() -> f64 {
+    0.5
+}
+
+fn default_subdomain_entropy_threshold() -> f64 {
+    4.5
+}
+
+fn default_min_unique_subdomains() -> usize {
+    20
+}
+
+fn default_volume_spike_factor() -> f64 {
+    5.0
+}
+
+fn default_retention_days() -> u32 {
+    30
+}
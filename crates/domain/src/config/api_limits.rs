@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+
+/// Request rate and body size limits on the REST API, so a runaway
+/// dashboard tab or script cannot keep the SQLite pools busy that the DNS
+/// path writes its query log through.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ApiLimitsConfig {
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Sustained requests per second allowed from one client address.
+    #[serde(default = "default_requests_per_second")]
+    pub requests_per_second: u32,
+
+    /// Requests a client address may send at once before the per-second
+    /// rate applies, e.g. when the dashboard loads.
+    #[serde(default = "default_burst_size")]
+    pub burst_size: u32,
+
+    /// Sustained requests per second allowed for one API token, on top of
+    /// the per-address limit, so a script cannot starve others behind the
+    /// same address.
+    #[serde(default = "default_token_requests_per_second")]
+    pub token_requests_per_second: u32,
+
+    #[serde(default = "default_token_burst_size")]
+    pub token_burst_size: u32,
+
+    /// Largest request body accepted by regular endpoints, in bytes.
+    #[serde(default = "default_max_body_bytes")]
+    pub max_body_bytes: usize,
+
+    /// Largest request body accepted by the bulk import endpoints
+    /// (`/blocklist/bulk`, `/whitelist/bulk`), in bytes.
+    #[serde(default = "default_max_bulk_body_bytes")]
+    pub max_bulk_body_bytes: usize,
+}
+
+impl Default for ApiLimitsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            requests_per_second: default_requests_per_second(),
+            burst_size: default_burst_size(),
+            token_requests_per_second: default_token_requests_per_second(),
+            token_burst_size: default_token_burst_size(),
+            max_body_bytes: default_max_body_bytes(),
+            max_bulk_body_bytes: default_max_bulk_body_bytes(),
+        }
+    }
+}
+
+impl ApiLimitsConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.max_body_bytes == 0 || self.max_bulk_body_bytes == 0 {
+            return Err("server.api_limits body limits must be greater than 0".to_string());
+        }
+        if self.max_bulk_body_bytes < self.max_body_bytes {
+            return Err(
+                "server.api_limits.max_bulk_body_bytes cannot be smaller than max_body_bytes"
+                    .to_string(),
+            );
+        }
+        if !self.enabled {
+            return Ok(());
+        }
+        if self.requests_per_second == 0 || self.burst_size == 0 {
+            return Err(
+                "server.api_limits.requests_per_second and burst_size must be greater than 0"
+                    .to_string(),
+            );
+        }
+        if self.token_requests_per_second == 0 || self.token_burst_size == 0 {
+            return Err(
+                "server.api_limits.token_requests_per_second and token_burst_size must be greater than 0"
+                    .to_string(),
+            );
+        }
+        Ok(())
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_requests_per_second() -> u32 {
+    50
+}
+
+fn default_burst_size() -> u32 {
+    200
+}
+
+fn default_token_requests_per_second() -> u32 {
+    20
+}
+
+fn default_token_burst_size() -> u32 {
+    100
+}
+
+fn default_max_body_bytes() -> usize {
+    2 * 1024 * 1024
+}
+
+/// Room for the largest bulk import of full-length domain names.
+fn default_max_bulk_body_bytes() -> usize {
+    16 * 1024 * 1024
+}
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::net::{Ipv4Addr, Ipv6Addr};
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct BlockingConfig {
@@ -9,6 +10,22 @@ pub struct BlockingConfig {
 
     #[serde(default)]
     pub whitelist: Vec<String>,
+
+    /// How blocked queries are answered.
+    #[serde(default)]
+    pub mode: BlockingMode,
+
+    /// Sinkhole addresses used when `mode = "sinkhole"`.
+    #[serde(default)]
+    pub sinkhole: SinkholeConfig,
+
+    /// Save the downloaded blocklists next to the database and build the
+    /// index from them at startup, before the lists are fetched again.
+    #[serde(default = "default_index_snapshot")]
+    pub index_snapshot: bool,
+
+    #[serde(default)]
+    pub unblock_requests: UnblockRequestsConfig,
 }
 
 impl Default for BlockingConfig {
@@ -17,6 +34,256 @@ impl Default for BlockingConfig {
             enabled: true,
             custom_blocked: vec![],
             whitelist: vec![],
+            mode: BlockingMode::default(),
+            sinkhole: SinkholeConfig::default(),
+            index_snapshot: default_index_snapshot(),
+            unblock_requests: UnblockRequestsConfig::default(),
+        }
+    }
+}
+
+fn default_index_snapshot() -> bool {
+    true
+}
+
+impl BlockingConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.mode == BlockingMode::Sinkhole
+            && self.sinkhole.ipv4.is_none()
+            && self.sinkhole.ipv6.is_none()
+        {
+            return Err(
+                "blocking.mode = \"sinkhole\" requires blocking.sinkhole.ipv4 or ipv6".to_string(),
+            );
+        }
+        if self.sinkhole.telemetry.enabled
+            && self.sinkhole.telemetry.http_port == self.sinkhole.telemetry.https_port
+        {
+            return Err(
+                "blocking.sinkhole.telemetry http_port and https_port must differ".to_string(),
+            );
+        }
+        self.sinkhole.block_page.validate(&self.sinkhole.telemetry)
+    }
+}
+
+/// How clients file unblock requests outside the block page.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct UnblockRequestsConfig {
+    /// Accept requests on the unauthenticated `POST /api/public/requests`,
+    /// for clients that never see the block page.
+    #[serde(default)]
+    pub public_endpoint: bool,
+}
+
+/// Answer sent for a blocked query.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BlockingMode {
+    /// REFUSED with Extended DNS Error 15 (Blocked).
+    #[default]
+    Refused,
+    /// A/AAAA answers pointing at the sinkhole address; other record types
+    /// get an empty NOERROR answer.
+    Sinkhole,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SinkholeConfig {
+    /// Address returned for blocked A queries. Without it, A queries get NODATA.
+    #[serde(default)]
+    pub ipv4: Option<Ipv4Addr>,
+
+    /// Address returned for blocked AAAA queries. Without it, AAAA queries get NODATA.
+    #[serde(default)]
+    pub ipv6: Option<Ipv6Addr>,
+
+    /// TTL of sinkhole answers, in seconds.
+    #[serde(default = "default_sinkhole_ttl")]
+    pub ttl: u32,
+
+    #[serde(default)]
+    pub telemetry: SinkholeTelemetryConfig,
+
+    #[serde(default)]
+    pub block_page: BlockPageConfig,
+}
+
+impl Default for SinkholeConfig {
+    fn default() -> Self {
+        Self {
+            ipv4: None,
+            ipv6: None,
+            ttl: default_sinkhole_ttl(),
+            telemetry: SinkholeTelemetryConfig::default(),
+            block_page: BlockPageConfig::default(),
+        }
+    }
+}
+
+/// HTTP/HTTPS responder bound to the sinkhole addresses that records which
+/// hosts clients still try to reach after being blocked.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SinkholeTelemetryConfig {
+    #[serde(default)]
+    pub enabled: bool,
+
+    #[serde(default = "default_http_port")]
+    pub http_port: u16,
+
+    #[serde(default = "default_https_port")]
+    pub https_port: u16,
+}
+
+impl Default for SinkholeTelemetryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            http_port: default_http_port(),
+            https_port: default_https_port(),
+        }
+    }
+}
+
+/// Page served on the sinkhole addresses to browsers that open a blocked
+/// site, naming the domain and why it was blocked.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BlockPageConfig {
+    #[serde(default)]
+    pub enabled: bool,
+
+    #[serde(default = "default_http_port")]
+    pub http_port: u16,
+
+    #[serde(default = "default_https_port")]
+    pub https_port: u16,
+
+    /// Local CA that signs a certificate for each blocked host, so HTTPS
+    /// visits get the page too on clients that trust it. Generated on first
+    /// start when missing. Without both paths the page is HTTP only.
+    #[serde(default)]
+    pub ca_cert_path: Option<String>,
+
+    #[serde(default)]
+    pub ca_key_path: Option<String>,
+
+    #[serde(default = "default_block_page_title")]
+    pub title: String,
+
+    #[serde(default = "default_block_page_message")]
+    pub message: String,
+
+    /// Who to ask about blocks, shown under the message.
+    #[serde(default)]
+    pub contact: Option<String>,
+
+    /// Show a button that files an unblock request for the admins to review.
+    #[serde(default = "default_unblock_requests")]
+    pub unblock_requests: bool,
+
+    /// Overrides for the clients of one group, by group name.
+    #[serde(default)]
+    pub groups: Vec<BlockPageGroupConfig>,
+}
+
+impl Default for BlockPageConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            http_port: default_http_port(),
+            https_port: default_https_port(),
+            ca_cert_path: None,
+            ca_key_path: None,
+            title: default_block_page_title(),
+            message: default_block_page_message(),
+            contact: None,
+            unblock_requests: default_unblock_requests(),
+            groups: Vec::new(),
         }
     }
 }
+
+impl BlockPageConfig {
+    /// Whether the page is also served over HTTPS.
+    pub fn https_enabled(&self) -> bool {
+        self.ca_cert_path.is_some() && self.ca_key_path.is_some()
+    }
+
+    fn validate(&self, telemetry: &SinkholeTelemetryConfig) -> Result<(), String> {
+        if !self.enabled {
+            return Ok(());
+        }
+        if self.http_port == self.https_port {
+            return Err(
+                "blocking.sinkhole.block_page http_port and https_port must differ".to_string(),
+            );
+        }
+        if self.ca_cert_path.is_some() != self.ca_key_path.is_some() {
+            return Err(
+                "blocking.sinkhole.block_page needs both ca_cert_path and ca_key_path".to_string(),
+            );
+        }
+        // Without a CA, HTTPS stays with the telemetry responder.
+        if telemetry.enabled && !self.https_enabled() && telemetry.https_port == self.http_port {
+            return Err(
+                "blocking.sinkhole.block_page http_port clashes with telemetry https_port"
+                    .to_string(),
+            );
+        }
+        let mut names = std::collections::HashSet::new();
+        for group in &self.groups {
+            if group.group.trim().is_empty() {
+                return Err("blocking.sinkhole.block_page.groups: group name is empty".to_string());
+            }
+            if !names.insert(group.group.as_str()) {
+                return Err(format!(
+                    "blocking.sinkhole.block_page.groups: group '{}' is listed twice",
+                    group.group
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Block page settings for one group; unset fields keep the global ones.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BlockPageGroupConfig {
+    pub group: String,
+
+    #[serde(default)]
+    pub title: Option<String>,
+
+    #[serde(default)]
+    pub message: Option<String>,
+
+    #[serde(default)]
+    pub contact: Option<String>,
+
+    #[serde(default)]
+    pub unblock_requests: Option<bool>,
+}
+
+fn default_block_page_title() -> String {
+    "Site blocked".to_string()
+}
+
+fn default_block_page_message() -> String {
+    "This site is blocked on this network.".to_string()
+}
+
+fn default_unblock_requests() -> bool {
+    true
+}
+
+fn default_sinkhole_ttl() -> u32 {
+    60
+}
+
+fn default_http_port() -> u16 {
+    80
+}
+
+fn default_https_port() -> u16 {
+    443
+}
@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+
+const DEFAULT_BOOTSTRAP_PORT: u16 = 53;
+
+/// How the hostnames of DoT/DoH/DoQ upstreams are turned into addresses.
+/// The upstreams cannot resolve their own names, so this is done before
+/// they are used and repeated every `refresh_interval_secs`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BootstrapConfig {
+    /// Plain DNS resolvers (`IP` or `IP:port`) used to look up upstream
+    /// hostnames. Empty uses the system resolver.
+    #[serde(default)]
+    pub servers: Vec<String>,
+
+    /// Upstream hostnames pinned to fixed addresses; these are never looked
+    /// up.
+    #[serde(default)]
+    pub pinned: HashMap<String, Vec<String>>,
+
+    /// Remember the last addresses found for each hostname in the database
+    /// and use them when a lookup fails, e.g. at boot before the network or
+    /// the system resolver is up.
+    #[serde(default = "default_persist")]
+    pub persist: bool,
+
+    /// Seconds between re-resolutions of upstream hostnames; 0 disables
+    /// them. Hostnames still without an address are retried sooner.
+    #[serde(default = "default_refresh_interval_secs")]
+    pub refresh_interval_secs: u64,
+}
+
+fn default_persist() -> bool {
+    true
+}
+
+fn default_refresh_interval_secs() -> u64 {
+    3600
+}
+
+impl Default for BootstrapConfig {
+    fn default() -> Self {
+        Self {
+            servers: Vec::new(),
+            pinned: HashMap::new(),
+            persist: default_persist(),
+            refresh_interval_secs: default_refresh_interval_secs(),
+        }
+    }
+}
+
+impl BootstrapConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        for server in &self.servers {
+            parse_server(server).ok_or_else(|| {
+                format!("Invalid dns.bootstrap server '{server}': expected an IP or IP:port")
+            })?;
+        }
+        for (hostname, addrs) in &self.pinned {
+            if hostname.trim().is_empty() {
+                return Err("dns.bootstrap.pinned contains an empty hostname".into());
+            }
+            if addrs.is_empty() {
+                return Err(format!(
+                    "dns.bootstrap.pinned.\"{hostname}\" must list at least one address"
+                ));
+            }
+            for addr in addrs {
+                addr.parse::<IpAddr>().map_err(|_| {
+                    format!(
+                        "Invalid pinned address '{addr}' for dns.bootstrap.pinned.\"{hostname}\""
+                    )
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The bootstrap resolvers; entries without a port use 53. Invalid
+    /// entries are skipped; validation rejects them at load time.
+    pub fn server_addrs(&self) -> Vec<SocketAddr> {
+        self.servers
+            .iter()
+            .filter_map(|s| parse_server(s))
+            .collect()
+    }
+
+    /// Pinned addresses keyed by lowercase hostname without a trailing dot.
+    pub fn pinned_addrs(&self) -> HashMap<String, Vec<IpAddr>> {
+        self.pinned
+            .iter()
+            .map(|(hostname, addrs)| {
+                (
+                    hostname.trim_end_matches('.').to_ascii_lowercase(),
+                    addrs.iter().filter_map(|a| a.parse().ok()).collect(),
+                )
+            })
+            .collect()
+    }
+}
+
+fn parse_server(server: &str) -> Option<SocketAddr> {
+    server.parse::<SocketAddr>().ok().or_else(|| {
+        server
+            .parse::<IpAddr>()
+            .ok()
+            .map(|ip| SocketAddr::new(ip, DEFAULT_BOOTSTRAP_PORT))
+    })
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+/// Longest string a single TXT character-string can carry.
+const MAX_TXT_STRING_LEN: usize = 255;
+
+/// Answers to CHAOS-class TXT queries (`version.bind`, `hostname.bind`,
+/// `id.server`), used to tell which instance answered behind anycast or a
+/// shared VIP.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ChaosIdentityConfig {
+    /// Off by default so the server does not disclose its version or host;
+    /// while disabled every CHAOS query is answered with REFUSED.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Answer to `version.bind`; defaults to `ferrous-dns <version>`. An
+    /// empty string refuses the query.
+    #[serde(default)]
+    pub version: Option<String>,
+
+    /// Answer to `hostname.bind`; defaults to the machine's hostname. An
+    /// empty string refuses the query.
+    #[serde(default)]
+    pub hostname: Option<String>,
+
+    /// Answer to `id.server`; defaults to the `hostname.bind` answer. An
+    /// empty string refuses the query.
+    #[serde(default)]
+    pub server_id: Option<String>,
+}
+
+impl ChaosIdentityConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        for (key, value) in [
+            ("version", &self.version),
+            ("hostname", &self.hostname),
+            ("server_id", &self.server_id),
+        ] {
+            if value.as_ref().is_some_and(|v| v.len() > MAX_TXT_STRING_LEN) {
+                return Err(format!(
+                    "dns.chaos_identity.{key} must be at most {MAX_TXT_STRING_LEN} bytes"
+                ));
+            }
+        }
+        Ok(())
+    }
+}
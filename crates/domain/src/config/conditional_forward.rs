@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, SocketAddr};
+
+const DEFAULT_DNS_PORT: u16 = 53;
+
+/// Sends every query at or under `zone` to `servers` instead of the upstream
+/// pools, e.g. `168.192.in-addr.arpa` to the router that hands out the LAN's
+/// DHCP leases.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ConditionalForwardConfig {
+    /// Zone to forward. Reverse zones (`*.in-addr.arpa`, `*.ip6.arpa`) must
+    /// be made of whole octets or nibbles.
+    pub zone: String,
+
+    /// Plain DNS servers, as `ip` or `ip:port`, tried in order until one
+    /// answers.
+    pub servers: Vec<String>,
+}
+
+impl ConditionalForwardConfig {
+    /// `zone` in lowercase, without leading or trailing dots.
+    pub fn normalized_zone(&self) -> String {
+        self.zone.trim().trim_matches('.').to_ascii_lowercase()
+    }
+
+    /// `servers` as socket addresses, port 53 unless given.
+    pub fn server_addrs(&self) -> Result<Vec<SocketAddr>, String> {
+        self.servers
+            .iter()
+            .map(|server| {
+                let server = server.trim();
+                server
+                    .parse::<SocketAddr>()
+                    .or_else(|_| {
+                        server
+                            .parse::<IpAddr>()
+                            .map(|ip| SocketAddr::new(ip, DEFAULT_DNS_PORT))
+                    })
+                    .map_err(|_| {
+                        format!(
+                            "dns.conditional_forwards: server '{server}' must be an IP address \
+                             or IP:port"
+                        )
+                    })
+            })
+            .collect()
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        validate_zone(&self.normalized_zone())
+            .map_err(|e| format!("dns.conditional_forwards: zone '{}' {e}", self.zone))?;
+        if self.servers.is_empty() {
+            return Err(format!(
+                "dns.conditional_forwards: zone '{}' needs at least one server",
+                self.zone
+            ));
+        }
+        self.server_addrs().map(|_| ())
+    }
+}
+
+pub(super) fn validate_conditional_forwards(
+    forwards: &[ConditionalForwardConfig],
+) -> Result<(), String> {
+    let mut zones = std::collections::HashSet::new();
+    for forward in forwards {
+        forward.validate()?;
+        if !zones.insert(forward.normalized_zone()) {
+            return Err(format!(
+                "dns.conditional_forwards: zone '{}' is defined more than once",
+                forward.zone
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Checks a normalized zone name; reverse zones must only hold complete
+/// octets (`in-addr.arpa`) or nibbles (`ip6.arpa`).
+fn validate_zone(zone: &str) -> Result<(), &'static str> {
+    if zone.is_empty() {
+        return Err("cannot be empty");
+    }
+    if zone.len() > 253 {
+        return Err("is longer than 253 characters");
+    }
+    let labels: Vec<&str> = zone.split('.').collect();
+    if labels.iter().any(|label| {
+        label.is_empty()
+            || label.len() > 63
+            || !label
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    }) {
+        return Err("is not a valid domain name");
+    }
+
+    if zone == "in-addr.arpa" || zone == "ip6.arpa" {
+        return Err("must name a network, e.g. 168.192.in-addr.arpa");
+    }
+    if let Some(octets) = zone.strip_suffix(".in-addr.arpa") {
+        let octets: Vec<&str> = octets.split('.').collect();
+        if octets.len() > 4 {
+            return Err("has more than four octets");
+        }
+        if !octets.iter().all(|octet| is_octet(octet)) {
+            return Err("must only hold octets from 0 to 255 before in-addr.arpa");
+        }
+    } else if let Some(nibbles) = zone.strip_suffix(".ip6.arpa") {
+        let nibbles: Vec<&str> = nibbles.split('.').collect();
+        if nibbles.len() > 32 {
+            return Err("has more than 32 nibbles");
+        }
+        if !nibbles
+            .iter()
+            .all(|nibble| nibble.len() == 1 && nibble.chars().all(|c| c.is_ascii_hexdigit()))
+        {
+            return Err("must only hold single hex digits before ip6.arpa");
+        }
+    }
+    Ok(())
+}
+
+fn is_octet(label: &str) -> bool {
+    (label == "0" || !label.starts_with('0'))
+        && label.len() <= 3
+        && label.chars().all(|c| c.is_ascii_digit())
+        && label.parse::<u16>().is_ok_and(|n| n <= 255)
+}
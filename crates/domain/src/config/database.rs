@@ -1,5 +1,18 @@
 use serde::{Deserialize, Serialize};
 
+/// Time span of each query log partition table.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QueryLogPartitioning {
+    /// Everything stays in the single `query_log` table.
+    None,
+    /// One `query_log_YYYYMMDD` table per UTC day.
+    Day,
+    /// One `query_log_YYYYWW` table per ISO week.
+    #[default]
+    Week,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct DatabaseConfig {
     #[serde(default = "default_db_path")]
@@ -26,6 +39,11 @@ pub struct DatabaseConfig {
     #[serde(default = "default_query_log_sample_rate")]
     pub query_log_sample_rate: u32,
 
+    /// Splits the query log into day or week tables so retention can drop
+    /// whole tables instead of deleting rows.
+    #[serde(default)]
+    pub query_log_partitioning: QueryLogPartitioning,
+
     #[serde(default = "default_client_channel_capacity")]
     pub client_channel_capacity: usize,
 
@@ -58,6 +76,20 @@ pub struct DatabaseConfig {
 
     #[serde(default = "default_wal_checkpoint_interval_secs")]
     pub wal_checkpoint_interval_secs: u64,
+
+    /// Seconds between maintenance runs (size cap, incremental vacuum, optimize).
+    #[serde(default = "default_maintenance_interval_secs")]
+    pub maintenance_interval_secs: u64,
+
+    /// Database size cap in MB; the oldest query log rows are trimmed to stay
+    /// under it. `0` disables the cap.
+    #[serde(default)]
+    pub max_size_mb: u64,
+
+    /// Days audit log entries are kept; older ones are deleted by the
+    /// maintenance runs.
+    #[serde(default = "default_audit_log_retention_days")]
+    pub audit_log_retention_days: u32,
 }
 
 impl Default for DatabaseConfig {
@@ -71,6 +103,7 @@ impl Default for DatabaseConfig {
             query_log_max_batch_size: default_query_log_max_batch_size(),
             query_log_flush_interval_ms: default_query_log_flush_interval_ms(),
             query_log_sample_rate: default_query_log_sample_rate(),
+            query_log_partitioning: QueryLogPartitioning::default(),
             client_channel_capacity: default_client_channel_capacity(),
             write_pool_max_connections: default_write_pool_max_connections(),
             query_log_pool_max_connections: default_query_log_pool_max_connections(),
@@ -82,6 +115,9 @@ impl Default for DatabaseConfig {
             sqlite_cache_size_kb: default_sqlite_cache_size_kb(),
             sqlite_mmap_size_mb: default_sqlite_mmap_size_mb(),
             wal_checkpoint_interval_secs: default_wal_checkpoint_interval_secs(),
+            maintenance_interval_secs: default_maintenance_interval_secs(),
+            max_size_mb: 0,
+            audit_log_retention_days: default_audit_log_retention_days(),
         }
     }
 }
@@ -161,3 +197,11 @@ fn default_sqlite_mmap_size_mb() -> u32 {
 fn default_wal_checkpoint_interval_secs() -> u64 {
     120
 }
+
+fn default_maintenance_interval_secs() -> u64 {
+    3600
+}
+
+fn default_audit_log_retention_days() -> u32 {
+    90
+}
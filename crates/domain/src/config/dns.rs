@@ -1,15 +1,31 @@
 use serde::{Deserialize, Serialize};
 
+use super::anomaly_detection::AnomalyDetectionConfig;
+use super::bootstrap::BootstrapConfig;
+use super::chaos_identity::ChaosIdentityConfig;
+use super::conditional_forward::ConditionalForwardConfig;
 use super::dga_detection::DgaDetectionConfig;
 use super::dns_cookies::DnsCookiesConfig;
+use super::docker::DockerConfig;
+use super::doh_upstream::DohUpstreamConfig;
+use super::edns_client_id::EdnsClientIdConfig;
 use super::health::HealthCheckConfig;
+use super::kubernetes::KubernetesConfig;
 use super::local_records::LocalDnsRecord;
 use super::nxdomain_hijack::NxdomainHijackConfig;
+use super::plugins::PluginsConfig;
+use super::query_budget::QueryBudgetConfig;
 use super::rate_limit::RateLimitConfig;
 use super::response_ip_filter::ResponseIpFilterConfig;
+use super::response_limits::ResponseLimitsConfig;
+use super::secondary_zones::SecondaryZoneConfig;
 use super::tunneling::TunnelingDetectionConfig;
+use super::update_zones::UpdateZoneConfig;
 use super::upstream::UpstreamPool;
 use super::upstream::UpstreamStrategy;
+use super::upstream_preset::UpstreamPreset;
+use super::views::DnsViewConfig;
+use super::vpn_peers::VpnPeersConfig;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct DnsConfig {
@@ -28,12 +44,27 @@ pub struct DnsConfig {
     #[serde(default = "default_false")]
     pub dnssec_enabled: bool,
 
+    /// Randomize the letter case of upstream query names (DNS 0x20) and
+    /// discard UDP responses that do not echo it exactly. Off by default:
+    /// a few upstreams rewrite the question name and would stop answering.
+    #[serde(default = "default_false")]
+    pub case_randomization: bool,
+
     #[serde(default)]
     pub default_strategy: UpstreamStrategy,
 
     #[serde(default)]
     pub pools: Vec<UpstreamPool>,
 
+    /// Named upstream setup expanded into `pools` when none are configured.
+    #[serde(default)]
+    pub upstream_preset: Option<UpstreamPreset>,
+
+    /// TOML file holding the TSIG keys referenced by `pools[].tsig_key`,
+    /// `secondary_zones[].tsig_key` and `update_zones[].tsig_keys`.
+    #[serde(default)]
+    pub tsig_keys_file: Option<String>,
+
     #[serde(default)]
     pub health_check: HealthCheckConfig,
 
@@ -78,12 +109,43 @@ pub struct DnsConfig {
     #[serde(default = "default_cache_max_ttl")]
     pub cache_max_ttl: u32,
 
+    /// Background refreshes allowed as a fraction of the client query rate;
+    /// `0` leaves refreshes unlimited.
+    #[serde(default = "default_cache_refresh_budget_fraction")]
+    pub cache_refresh_budget_fraction: f64,
+
+    /// Refreshes per second always allowed, however quiet client traffic is.
+    #[serde(default = "default_cache_refresh_budget_min_qps")]
+    pub cache_refresh_budget_min_qps: f64,
+
+    /// Approximate memory cap for cached entries in MB; `0` disables the cap.
+    #[serde(default)]
+    pub cache_max_memory_mb: usize,
+
+    /// Give each client group other than the default its own cache entries;
+    /// `false` keeps the single cache shared by every client.
+    #[serde(default = "default_true")]
+    pub cache_partition_by_group: bool,
+
+    /// Per-thread L1 cache in front of the shared L2 cache, holding A/AAAA answers.
+    #[serde(default = "default_true")]
+    pub cache_l1_enabled: bool,
+
+    /// Entries per thread in the L1 cache.
+    #[serde(default = "default_cache_l1_size")]
+    pub cache_l1_size: usize,
+
     #[serde(default = "default_true")]
     pub block_private_ptr: bool,
 
     #[serde(default = "default_false")]
     pub block_non_fqdn: bool,
 
+    /// Answer ANY queries with a single synthesized HINFO record (RFC 8482)
+    /// instead of forwarding them upstream.
+    #[serde(default = "default_true")]
+    pub minimal_any_responses: bool,
+
     #[serde(default)]
     pub local_domain: Option<String>,
 
@@ -93,14 +155,39 @@ pub struct DnsConfig {
     #[serde(default)]
     pub local_records: Vec<LocalDnsRecord>,
 
+    /// Split-horizon views. Local records tagged with a view answer only for
+    /// the view's clients; everyone else gets the untagged records.
+    #[serde(default)]
+    pub views: Vec<DnsViewConfig>,
+
+    /// Zones transferred from a primary (AXFR/IXFR) and answered
+    /// authoritatively.
+    #[serde(default)]
+    pub secondary_zones: Vec<SecondaryZoneConfig>,
+
+    /// Local zones that accept dynamic updates (RFC 2136).
+    #[serde(default)]
+    pub update_zones: Vec<UpdateZoneConfig>,
+
+    /// Zones, typically private reverse zones, forwarded to specific servers
+    /// instead of the upstream pools.
+    #[serde(default)]
+    pub conditional_forwards: Vec<ConditionalForwardConfig>,
+
+    /// Local subnets (CIDR) whose PTR queries are answered from known client
+    /// hostnames. Addresses without a name, and every other range, keep
+    /// resolving through the normal PTR path.
+    #[serde(default)]
+    pub local_networks: Vec<String>,
+
     /// Whether DNS rebinding protection is enabled. When `true`, responses that
     /// resolve a public domain to a private/RFC1918 IP are blocked.
     /// Defaults to `true` — opt-out rather than opt-in for security-sensitive features.
     #[serde(default = "default_true")]
     pub rebinding_protection_enabled: bool,
 
-    /// Domains that are always exempt from rebinding protection, regardless of the
-    /// resolved IP address. Useful for split-horizon DNS scenarios where an external
+    /// Internal domains (and their subdomains) that are always exempt from rebinding
+    /// protection, regardless of the resolved IP address. Useful for split-horizon DNS scenarios where an external
     /// name intentionally resolves to a private address (e.g. VPN or router admin panels).
     #[serde(default)]
     pub rebinding_allowlist: Vec<String>,
@@ -121,13 +208,64 @@ pub struct DnsConfig {
     #[serde(default)]
     pub response_ip_filter: ResponseIpFilterConfig,
 
+    /// Per-query caps on answers, UDP response size and CNAME chain length.
+    #[serde(default)]
+    pub response_limits: ResponseLimitsConfig,
+
+    /// Answers to CHAOS-class identity queries such as `id.server`.
+    #[serde(default)]
+    pub chaos_identity: ChaosIdentityConfig,
+
+    /// Attribution of forwarded queries to devices by the MAC address the
+    /// forwarder adds as an EDNS option.
+    #[serde(default)]
+    pub edns_client_id: EdnsClientIdConfig,
+
+    /// Resolution of upstream hostnames (DoT/DoH/DoQ servers given by name).
+    #[serde(default)]
+    pub bootstrap: BootstrapConfig,
+
+    /// End-to-end time budget per query, split across cache, upstream and
+    /// DNSSEC phases.
+    #[serde(default)]
+    pub query_budget: QueryBudgetConfig,
+
     /// DGA (Domain Generation Algorithm) detection configuration.
     #[serde(default)]
     pub dga_detection: DgaDetectionConfig,
 
+    /// Per-client anomaly detection over the query log.
+    #[serde(default)]
+    pub anomaly_detection: AnomalyDetectionConfig,
+
     /// DNS Cookies anti-spoofing configuration (RFC 7873).
     #[serde(default)]
     pub dns_cookies: DnsCookiesConfig,
+
+    /// DNS-over-HTTPS upstream client settings (method, HTTP caching, keep-alive).
+    #[serde(default)]
+    pub doh_upstream: DohUpstreamConfig,
+
+    /// Lua scripts hooked into the resolve pipeline.
+    #[serde(default)]
+    pub plugins: PluginsConfig,
+
+    /// Allows injecting upstream faults through `/api/debug/faults`, in
+    /// builds with the `fault-injection` feature. For staging only.
+    #[serde(default)]
+    pub fault_injection: bool,
+
+    /// Kubernetes Services and Ingresses published as local records.
+    #[serde(default)]
+    pub kubernetes: KubernetesConfig,
+
+    /// Running Docker containers published as local records.
+    #[serde(default)]
+    pub docker: DockerConfig,
+
+    /// Tailscale or WireGuard peers published as local records.
+    #[serde(default)]
+    pub vpn_peers: VpnPeersConfig,
 }
 
 impl Default for DnsConfig {
@@ -138,8 +276,11 @@ impl Default for DnsConfig {
             cache_enabled: true,
             cache_ttl: default_cache_ttl(),
             dnssec_enabled: false,
+            case_randomization: false,
             default_strategy: UpstreamStrategy::Parallel,
             pools: vec![],
+            upstream_preset: None,
+            tsig_keys_file: None,
             health_check: HealthCheckConfig::default(),
             cache_max_entries: default_cache_max_entries(),
             cache_eviction_strategy: default_cache_eviction_strategy(),
@@ -158,19 +299,43 @@ impl Default for DnsConfig {
             cache_eviction_sample_size: default_cache_eviction_sample_size(),
             cache_min_ttl: default_cache_min_ttl(),
             cache_max_ttl: default_cache_max_ttl(),
+            cache_refresh_budget_fraction: default_cache_refresh_budget_fraction(),
+            cache_refresh_budget_min_qps: default_cache_refresh_budget_min_qps(),
+            cache_max_memory_mb: 0,
+            cache_partition_by_group: true,
+            cache_l1_enabled: true,
+            cache_l1_size: default_cache_l1_size(),
             block_private_ptr: true,
             block_non_fqdn: false,
+            minimal_any_responses: true,
             local_domain: None,
             local_dns_server: None,
             local_records: vec![],
+            views: vec![],
+            secondary_zones: vec![],
+            update_zones: vec![],
+            conditional_forwards: vec![],
+            local_networks: vec![],
             rebinding_protection_enabled: true,
             rebinding_allowlist: vec![],
             rate_limit: RateLimitConfig::default(),
             tunneling_detection: TunnelingDetectionConfig::default(),
             nxdomain_hijack: NxdomainHijackConfig::default(),
             response_ip_filter: ResponseIpFilterConfig::default(),
+            response_limits: ResponseLimitsConfig::default(),
+            chaos_identity: ChaosIdentityConfig::default(),
+            edns_client_id: EdnsClientIdConfig::default(),
+            bootstrap: BootstrapConfig::default(),
+            query_budget: QueryBudgetConfig::default(),
             dga_detection: DgaDetectionConfig::default(),
+            anomaly_detection: AnomalyDetectionConfig::default(),
             dns_cookies: DnsCookiesConfig::default(),
+            doh_upstream: DohUpstreamConfig::default(),
+            plugins: PluginsConfig::default(),
+            fault_injection: false,
+            kubernetes: KubernetesConfig::default(),
+            docker: DockerConfig::default(),
+            vpn_peers: VpnPeersConfig::default(),
         }
     }
 }
@@ -251,6 +416,18 @@ fn default_cache_max_ttl() -> u32 {
     86_400
 }
 
+fn default_cache_refresh_budget_fraction() -> f64 {
+    0.2
+}
+
+fn default_cache_refresh_budget_min_qps() -> f64 {
+    5.0
+}
+
+fn default_cache_l1_size() -> usize {
+    1024
+}
+
 fn default_cache_shard_amount() -> usize {
     let cpus = std::thread::available_parallelism()
         .map(|n| n.get())
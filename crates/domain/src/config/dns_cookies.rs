@@ -25,6 +25,12 @@ pub struct DnsCookiesConfig {
     /// echoes a fresh server cookie so clients can learn and cache it.
     #[serde(default = "default_false")]
     pub require_valid_cookie: bool,
+
+    /// Send client cookies on queries to plain UDP and TCP upstreams and
+    /// echo the server cookies they return. Independent of `enabled`, which
+    /// covers cookies from downstream clients.
+    #[serde(default = "default_true")]
+    pub upstream: bool,
 }
 
 impl Default for DnsCookiesConfig {
@@ -34,6 +40,7 @@ impl Default for DnsCookiesConfig {
             server_secret: String::new(),
             secret_rotation_secs: default_rotation_secs(),
             require_valid_cookie: default_false(),
+            upstream: default_true(),
         }
     }
 }
@@ -61,6 +68,7 @@ mod tests {
         assert!(config.server_secret.is_empty());
         assert_eq!(config.secret_rotation_secs, 3600);
         assert!(!config.require_valid_cookie);
+        assert!(config.upstream);
     }
 
     #[test]
@@ -83,6 +91,7 @@ mod tests {
             server_secret: "aabbcc".repeat(10).chars().take(64).collect(),
             secret_rotation_secs: 1800,
             require_valid_cookie: true,
+            upstream: false,
         };
         let toml_str = toml::to_string(&original).unwrap();
         let restored: DnsCookiesConfig = toml::from_str(&toml_str).unwrap();
@@ -90,5 +99,6 @@ mod tests {
         assert_eq!(restored.server_secret, original.server_secret);
         assert_eq!(restored.secret_rotation_secs, original.secret_rotation_secs);
         assert_eq!(restored.require_valid_cookie, original.require_valid_cookie);
+        assert_eq!(restored.upstream, original.upstream);
     }
 }
@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+
+/// Publishes running Docker containers as local records under `domain`,
+/// from the Docker Engine API on a unix socket. Container start and stop
+/// events trigger a sync; `sync_interval_secs` is the fallback.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DockerConfig {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Zone the records are published under: container `web` answers as
+    /// `web.<domain>`.
+    #[serde(default = "default_domain")]
+    pub domain: String,
+
+    /// Path to the Docker Engine API socket.
+    #[serde(default = "default_socket")]
+    pub socket: String,
+
+    /// Networks whose addresses are published; empty publishes the
+    /// addresses of every network a container is attached to.
+    #[serde(default)]
+    pub networks: Vec<String>,
+
+    /// Also publish the containers' network aliases, such as Compose
+    /// service names.
+    #[serde(default = "default_true")]
+    pub include_aliases: bool,
+
+    #[serde(default = "default_sync_interval_secs")]
+    pub sync_interval_secs: u64,
+
+    /// TTL of the published records.
+    #[serde(default = "default_ttl")]
+    pub ttl: u32,
+}
+
+fn default_domain() -> String {
+    "docker.lan".to_string()
+}
+
+fn default_socket() -> String {
+    "/var/run/docker.sock".to_string()
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_sync_interval_secs() -> u64 {
+    300
+}
+
+fn default_ttl() -> u32 {
+    30
+}
+
+impl Default for DockerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            domain: default_domain(),
+            socket: default_socket(),
+            networks: Vec::new(),
+            include_aliases: default_true(),
+            sync_interval_secs: default_sync_interval_secs(),
+            ttl: default_ttl(),
+        }
+    }
+}
+
+impl DockerConfig {
+    /// `domain` in lowercase, without leading or trailing dots.
+    pub fn normalized_domain(&self) -> String {
+        self.domain.trim().trim_matches('.').to_ascii_lowercase()
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.enabled {
+            return Ok(());
+        }
+        if self.normalized_domain().is_empty() {
+            return Err("dns.docker.domain cannot be empty".to_string());
+        }
+        if self.socket.trim().is_empty() {
+            return Err("dns.docker.socket cannot be empty".to_string());
+        }
+        if self.sync_interval_secs < 5 {
+            return Err("dns.docker.sync_interval_secs must be at least 5".to_string());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+
+/// Client-side settings for DNS-over-HTTPS upstreams (RFC 8484).
+///
+/// Applies to every `https://` server in `[[dns.pools]]`. Each upstream host
+/// keeps a single HTTP/2 connection and multiplexes concurrent queries as
+/// separate streams on it.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DohUpstreamConfig {
+    /// HTTP method used to send queries. `get` encodes the message in the
+    /// `?dns=` parameter (base64url) with the query ID zeroed, which lets
+    /// HTTP caches between Ferrous and the provider share responses.
+    #[serde(default)]
+    pub method: DohMethod,
+
+    /// When `true`, GET responses carrying `Cache-Control: max-age` are kept
+    /// in a small per-upstream HTTP cache and reused until they go stale.
+    /// Responses marked `no-store` or `no-cache` are never reused.
+    #[serde(default = "default_respect_cache_headers")]
+    pub respect_cache_headers: bool,
+
+    /// Maximum number of responses held in each upstream's HTTP cache.
+    #[serde(default = "default_http_cache_max_entries")]
+    pub http_cache_max_entries: usize,
+
+    /// Seconds between HTTP/2 PING frames that keep the multiplexed
+    /// connection open while idle. `0` disables keep-alive pings.
+    #[serde(default = "default_keep_alive_interval_secs")]
+    pub keep_alive_interval_secs: u64,
+}
+
+/// HTTP method for upstream DoH queries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DohMethod {
+    /// `POST` with an `application/dns-message` body (RFC 8484 §4.1).
+    #[default]
+    Post,
+    /// `GET` with the message in the `dns` query parameter (RFC 8484 §4.1).
+    Get,
+}
+
+impl Default for DohUpstreamConfig {
+    fn default() -> Self {
+        Self {
+            method: DohMethod::default(),
+            respect_cache_headers: default_respect_cache_headers(),
+            http_cache_max_entries: default_http_cache_max_entries(),
+            keep_alive_interval_secs: default_keep_alive_interval_secs(),
+        }
+    }
+}
+
+fn default_respect_cache_headers() -> bool {
+    true
+}
+
+fn default_http_cache_max_entries() -> usize {
+    1024
+}
+
+fn default_keep_alive_interval_secs() -> u64 {
+    30
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_empty_toml_with_defaults() {
+        let config: DohUpstreamConfig = toml::from_str("").unwrap();
+        assert_eq!(config.method, DohMethod::Post);
+        assert!(config.respect_cache_headers);
+        assert_eq!(config.http_cache_max_entries, 1024);
+        assert_eq!(config.keep_alive_interval_secs, 30);
+    }
+
+    #[test]
+    fn deserializes_get_method() {
+        let config: DohUpstreamConfig = toml::from_str(r#"method = "get""#).unwrap();
+        assert_eq!(config.method, DohMethod::Get);
+        assert!(config.respect_cache_headers);
+    }
+}
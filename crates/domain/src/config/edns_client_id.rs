@@ -0,0 +1,137 @@
+use crate::entities::client::MAX_DISPLAY_NAME_LEN;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::net::IpAddr;
+
+/// EDNS option dnsmasq adds with `--add-mac`, carrying the requester's MAC.
+const DNSMASQ_MAC_OPTION: u16 = 65001;
+
+/// Option codes already given a meaning by the server: Client Subnet (8)
+/// and DNS Cookie (10).
+const RESERVED_OPTION_CODES: [u16; 2] = [8, 10];
+
+/// Attribution of queries relayed by a forwarder, such as a router running
+/// dnsmasq, to the devices behind it. The forwarder tags each query with the
+/// requester's MAC address in an EDNS option; the query is then counted for
+/// the address that MAC holds in the ARP table instead of the forwarder's.
+/// Forwarders that pass on the client address as EDNS Client Subnet are
+/// attributed the same way, and traffic that carries neither stays with the
+/// forwarder, under its label when one is configured.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct EdnsClientIdConfig {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// EDNS option codes read for a MAC address, first match wins. The
+    /// payload is either the 6 raw bytes or the address as text.
+    #[serde(default = "default_option_codes")]
+    pub option_codes: Vec<u16>,
+
+    /// CIDRs of the forwarders trusted to tag queries. Options from any
+    /// other client are ignored, as they would let it pose as another
+    /// device.
+    #[serde(default)]
+    pub forwarders: Vec<String>,
+
+    /// Attribute a forwarder's query to the address in its EDNS Client
+    /// Subnet option when that names a single host (a /32 or /128 prefix).
+    /// The MAC option takes precedence when both are present.
+    #[serde(default = "default_true")]
+    pub use_client_subnet: bool,
+
+    /// Display names for forwarder addresses, applied to their client
+    /// entries at startup so untagged traffic shows up per forwarder.
+    /// Names set by hand in the client list are left alone.
+    #[serde(default)]
+    pub forwarder_labels: BTreeMap<String, String>,
+}
+
+impl Default for EdnsClientIdConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            option_codes: default_option_codes(),
+            forwarders: vec![],
+            use_client_subnet: true,
+            forwarder_labels: BTreeMap::new(),
+        }
+    }
+}
+
+impl EdnsClientIdConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.enabled {
+            return Ok(());
+        }
+        if self.option_codes.is_empty() {
+            return Err("dns.edns_client_id.option_codes cannot be empty".to_string());
+        }
+        if let Some(code) = self
+            .option_codes
+            .iter()
+            .find(|c| RESERVED_OPTION_CODES.contains(c))
+        {
+            return Err(format!(
+                "dns.edns_client_id.option_codes cannot include {code}, which is already used by the server"
+            ));
+        }
+        if self.forwarders.is_empty() {
+            return Err(
+                "dns.edns_client_id.forwarders must list the forwarders trusted to tag queries"
+                    .to_string(),
+            );
+        }
+        for cidr in &self.forwarders {
+            cidr.parse::<ipnetwork::IpNetwork>().map_err(|e| {
+                format!("Invalid forwarder '{cidr}' in dns.edns_client_id.forwarders: {e}")
+            })?;
+        }
+        for (address, label) in &self.forwarder_labels {
+            let ip: IpAddr = address.parse().map_err(|_| {
+                format!(
+                    "Invalid address '{address}' in dns.edns_client_id.forwarder_labels, expected a single IP"
+                )
+            })?;
+            if !self.is_forwarder(ip) {
+                return Err(format!(
+                    "dns.edns_client_id.forwarder_labels names '{address}', which is not covered by dns.edns_client_id.forwarders"
+                ));
+            }
+            let label = label.trim();
+            if label.is_empty() || label.len() > MAX_DISPLAY_NAME_LEN {
+                return Err(format!(
+                    "Label for forwarder '{address}' must be between 1 and {MAX_DISPLAY_NAME_LEN} characters"
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Labelled forwarder addresses with their trimmed labels. Entries that
+    /// fail validation are skipped.
+    pub fn labelled_forwarders(&self) -> Vec<(IpAddr, String)> {
+        self.forwarder_labels
+            .iter()
+            .filter_map(|(address, label)| {
+                let label = label.trim();
+                let ip = address.parse().ok()?;
+                (!label.is_empty()).then(|| (ip, label.to_string()))
+            })
+            .collect()
+    }
+
+    fn is_forwarder(&self, ip: IpAddr) -> bool {
+        self.forwarders.iter().any(|cidr| {
+            cidr.parse::<ipnetwork::IpNetwork>()
+                .is_ok_and(|net| net.contains(ip))
+        })
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_option_codes() -> Vec<u16> {
+    vec![DNSMASQ_MAC_OPTION]
+}
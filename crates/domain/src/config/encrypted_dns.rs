@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use super::acme::AcmeConfig;
+
 /// Configuration for DoT and DoH server-side listeners.
 ///
 /// Both protocols are disabled by default. Enabling either requires a valid TLS
@@ -38,6 +40,10 @@ pub struct EncryptedDnsConfig {
     /// Path to the PEM private key file shared by DoT and DoH.
     #[serde(default = "default_key_path")]
     pub tls_key_path: String,
+
+    /// Obtain and renew the certificate automatically over ACME.
+    #[serde(default)]
+    pub acme: AcmeConfig,
 }
 
 fn default_dot_port() -> u16 {
@@ -61,6 +67,7 @@ impl Default for EncryptedDnsConfig {
             doh_port: None,
             tls_cert_path: default_cert_path(),
             tls_key_path: default_key_path(),
+            acme: AcmeConfig::default(),
         }
     }
 }
@@ -0,0 +1,162 @@
+use serde::Serialize;
+
+use super::root::Config;
+use crate::value_objects::dns_protocol::DnsProtocol;
+
+const EVICTION_STRATEGIES: [&str; 3] = ["hit_rate", "lfu", "lfu-k"];
+
+/// A setting that would keep the server from starting or working, named by
+/// its dotted path, e.g. `dns.pools[1].servers[0]`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConfigFieldError {
+    pub field: String,
+    pub message: String,
+}
+
+impl ConfigFieldError {
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ConfigFieldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+impl Config {
+    /// Checks the settings that can be edited through the API, reporting
+    /// every problem found instead of stopping at the first one like
+    /// [`Config::validate`] does.
+    pub fn field_errors(&self) -> Vec<ConfigFieldError> {
+        let mut errors = Vec::new();
+        let dns = &self.dns;
+
+        if !(dns.cache_refresh_threshold > 0.0 && dns.cache_refresh_threshold < 1.0) {
+            errors.push(ConfigFieldError::new(
+                "dns.cache_refresh_threshold",
+                "must be greater than 0 and less than 1",
+            ));
+        }
+        if dns.cache_min_hit_rate < 0.0 {
+            errors.push(ConfigFieldError::new(
+                "dns.cache_min_hit_rate",
+                "cannot be negative",
+            ));
+        }
+        if dns.cache_min_ttl > dns.cache_max_ttl {
+            errors.push(ConfigFieldError::new(
+                "dns.cache_min_ttl",
+                format!("cannot exceed dns.cache_max_ttl ({})", dns.cache_max_ttl),
+            ));
+        }
+        if !EVICTION_STRATEGIES.contains(&dns.cache_eviction_strategy.as_str()) {
+            errors.push(ConfigFieldError::new(
+                "dns.cache_eviction_strategy",
+                format!("must be one of {}", EVICTION_STRATEGIES.join(", ")),
+            ));
+        }
+        if dns.cache_enabled && dns.cache_max_entries == 0 {
+            errors.push(ConfigFieldError::new(
+                "dns.cache_max_entries",
+                "must be at least 1 while the cache is enabled",
+            ));
+        }
+
+        if dns.pools.is_empty() && dns.upstream_servers.is_empty() && dns.upstream_preset.is_none()
+        {
+            errors.push(ConfigFieldError::new(
+                "dns.pools",
+                "at least one upstream server is required",
+            ));
+        }
+        for (i, pool) in dns.pools.iter().enumerate() {
+            let field = format!("dns.pools[{i}]");
+            if pool.name.trim().is_empty() {
+                errors.push(ConfigFieldError::new(
+                    format!("{field}.name"),
+                    "cannot be empty",
+                ));
+            } else if dns.pools[..i].iter().any(|other| other.name == pool.name) {
+                errors.push(ConfigFieldError::new(
+                    format!("{field}.name"),
+                    format!("pool '{}' is defined more than once", pool.name),
+                ));
+            }
+            if pool.servers.is_empty() {
+                errors.push(ConfigFieldError::new(
+                    format!("{field}.servers"),
+                    "needs at least one server",
+                ));
+            }
+            for (j, server) in pool.servers.iter().enumerate() {
+                check_server(&mut errors, format!("{field}.servers[{j}]"), server);
+            }
+        }
+
+        let rate_limit = &dns.rate_limit;
+        if !(1..=32).contains(&rate_limit.ipv4_prefix_len) {
+            errors.push(ConfigFieldError::new(
+                "dns.rate_limit.ipv4_prefix_len",
+                "must be between 1 and 32",
+            ));
+        }
+        if !(1..=128).contains(&rate_limit.ipv6_prefix_len) {
+            errors.push(ConfigFieldError::new(
+                "dns.rate_limit.ipv6_prefix_len",
+                "must be between 1 and 128",
+            ));
+        }
+
+        let web_tls = &self.server.web_tls;
+        if web_tls.enabled {
+            if web_tls.tls_cert_path.trim().is_empty() {
+                errors.push(ConfigFieldError::new(
+                    "server.web_tls.tls_cert_path",
+                    "is required when HTTPS is enabled",
+                ));
+            }
+            if web_tls.tls_key_path.trim().is_empty() {
+                errors.push(ConfigFieldError::new(
+                    "server.web_tls.tls_key_path",
+                    "is required when HTTPS is enabled",
+                ));
+            }
+        }
+
+        if self.auth.session_ttl_hours == 0 {
+            errors.push(ConfigFieldError::new(
+                "auth.session_ttl_hours",
+                "must be at least 1",
+            ));
+        }
+
+        errors
+    }
+}
+
+/// Encrypted upstreams need a name to verify the server's certificate
+/// against, so `tls://:853` is rejected even though it parses.
+fn check_server(errors: &mut Vec<ConfigFieldError>, field: String, server: &str) {
+    match server.parse::<DnsProtocol>() {
+        Ok(protocol) => {
+            if protocol
+                .hostname()
+                .is_some_and(|name| name.trim().is_empty())
+            {
+                errors.push(ConfigFieldError::new(
+                    field,
+                    format!(
+                        "{} server '{server}' needs a hostname or IP address",
+                        protocol.protocol_name()
+                    ),
+                ));
+            }
+        }
+        Err(e) => errors.push(ConfigFieldError::new(field, e)),
+    }
+}
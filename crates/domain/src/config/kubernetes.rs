@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+
+/// Publishes a Kubernetes cluster's Services and Ingresses as local records
+/// under `domain`, refreshed every `sync_interval_secs`.
+///
+/// The API server is reached through `kubeconfig` when set, otherwise
+/// through `api_server` with `token_file` and `ca_file`, and otherwise with
+/// the in-cluster service account.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct KubernetesConfig {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Zone the records are published under: a Service `web` in namespace
+    /// `apps` answers as `web.apps.<domain>`.
+    #[serde(default = "default_domain")]
+    pub domain: String,
+
+    /// Path to a kubeconfig file.
+    #[serde(default)]
+    pub kubeconfig: Option<String>,
+
+    /// Kubeconfig context to use; defaults to its `current-context`.
+    #[serde(default)]
+    pub context: Option<String>,
+
+    /// API server URL, e.g. `https://10.0.0.10:6443`, when no kubeconfig is
+    /// given.
+    #[serde(default)]
+    pub api_server: Option<String>,
+
+    /// File holding the bearer token sent to `api_server`. Read again on
+    /// every sync, so rotated tokens are picked up.
+    #[serde(default)]
+    pub token_file: Option<String>,
+
+    /// PEM bundle the API server's certificate is checked against.
+    #[serde(default)]
+    pub ca_file: Option<String>,
+
+    /// Accept any API server certificate. Only for test clusters.
+    #[serde(default)]
+    pub insecure_skip_tls_verify: bool,
+
+    /// Namespaces to publish; empty publishes every namespace.
+    #[serde(default)]
+    pub namespaces: Vec<String>,
+
+    #[serde(default = "default_true")]
+    pub publish_services: bool,
+
+    #[serde(default = "default_true")]
+    pub publish_ingresses: bool,
+
+    /// Also publish Services' cluster IPs. They are only reachable from
+    /// inside the cluster network, so by default only load balancer and
+    /// external IPs are published.
+    #[serde(default)]
+    pub include_cluster_ip: bool,
+
+    #[serde(default = "default_sync_interval_secs")]
+    pub sync_interval_secs: u64,
+
+    /// TTL of the published records.
+    #[serde(default = "default_ttl")]
+    pub ttl: u32,
+}
+
+fn default_domain() -> String {
+    "k8s.lan".to_string()
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_sync_interval_secs() -> u64 {
+    30
+}
+
+fn default_ttl() -> u32 {
+    60
+}
+
+impl Default for KubernetesConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            domain: default_domain(),
+            kubeconfig: None,
+            context: None,
+            api_server: None,
+            token_file: None,
+            ca_file: None,
+            insecure_skip_tls_verify: false,
+            namespaces: Vec::new(),
+            publish_services: default_true(),
+            publish_ingresses: default_true(),
+            include_cluster_ip: false,
+            sync_interval_secs: default_sync_interval_secs(),
+            ttl: default_ttl(),
+        }
+    }
+}
+
+impl KubernetesConfig {
+    /// `domain` in lowercase, without leading or trailing dots.
+    pub fn normalized_domain(&self) -> String {
+        self.domain.trim().trim_matches('.').to_ascii_lowercase()
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.enabled {
+            return Ok(());
+        }
+        if self.normalized_domain().is_empty() {
+            return Err("dns.kubernetes.domain cannot be empty".to_string());
+        }
+        if let Some(server) = &self.api_server {
+            if !server.starts_with("https://") && !server.starts_with("http://") {
+                return Err(format!(
+                    "dns.kubernetes.api_server '{server}' must be an http:// or https:// URL"
+                ));
+            }
+        }
+        if self.sync_interval_secs < 5 {
+            return Err("dns.kubernetes.sync_interval_secs must be at least 5".to_string());
+        }
+        if !self.publish_services && !self.publish_ingresses {
+            return Err("dns.kubernetes must publish services, ingresses or both".to_string());
+        }
+        Ok(())
+    }
+}
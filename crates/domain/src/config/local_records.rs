@@ -13,6 +13,11 @@ pub struct LocalDnsRecord {
 
     #[serde(default)]
     pub ttl: Option<u32>,
+
+    /// Split-horizon view this record belongs to. Records without a view are
+    /// answered for every client.
+    #[serde(default)]
+    pub view: Option<String>,
 }
 
 impl LocalDnsRecord {
@@ -1,15 +1,217 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+const LOG_LEVELS: &[&str] = &["off", "error", "warn", "info", "debug", "trace"];
+
+/// Output format of log events on stdout.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    #[default]
+    Text,
+    /// One JSON object per line, for Loki, ELK and similar collectors.
+    Json,
+}
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct LoggingConfig {
     #[serde(default = "default_log_level")]
     pub level: String,
+
+    #[serde(default)]
+    pub format: LogFormat,
+
+    /// Level overrides keyed by module path, e.g. `"dns::cache" = "debug"`.
+    /// Paths without a `ferrous_dns` crate prefix also match inside each crate.
+    #[serde(default)]
+    pub modules: BTreeMap<String, String>,
+
+    /// Per-query OpenTelemetry spans exported over OTLP.
+    #[serde(default)]
+    pub otel: OtelConfig,
+
+    /// Capture of queries slower than a threshold, with a per-phase breakdown.
+    #[serde(default)]
+    pub slow_queries: SlowQueryLogConfig,
+
+    /// Periodic push of query aggregates to a time-series database.
+    #[serde(default)]
+    pub metrics_export: MetricsExportConfig,
 }
 
 impl Default for LoggingConfig {
     fn default() -> Self {
         Self {
             level: default_log_level(),
+            format: LogFormat::default(),
+            modules: BTreeMap::new(),
+            otel: OtelConfig::default(),
+            slow_queries: SlowQueryLogConfig::default(),
+            metrics_export: MetricsExportConfig::default(),
+        }
+    }
+}
+
+impl LoggingConfig {
+    /// Whether `level` names a log level (`off` included), ignoring case.
+    pub fn is_valid_level(level: &str) -> bool {
+        LOG_LEVELS
+            .iter()
+            .any(|known| known.eq_ignore_ascii_case(level.trim()))
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if !Self::is_valid_level(&self.level) {
+            return Err(format!("logging.level '{}' is not a log level", self.level));
+        }
+        for (module, level) in &self.modules {
+            if module.trim().is_empty() {
+                return Err("logging.modules keys cannot be empty".to_string());
+            }
+            if !Self::is_valid_level(level) {
+                return Err(format!(
+                    "logging.modules.\"{module}\" level '{level}' is not a log level"
+                ));
+            }
+        }
+        if self.otel.enabled {
+            if self.otel.endpoint.trim().is_empty() {
+                return Err("logging.otel.endpoint cannot be empty".to_string());
+            }
+            if !(0.0..=1.0).contains(&self.otel.sample_ratio) {
+                return Err("logging.otel.sample_ratio must be between 0.0 and 1.0".to_string());
+            }
+        }
+        if self.slow_queries.enabled {
+            if self.slow_queries.threshold_ms == 0 {
+                return Err("logging.slow_queries.threshold_ms must be greater than 0".to_string());
+            }
+            if self.slow_queries.capacity == 0 {
+                return Err("logging.slow_queries.capacity must be greater than 0".to_string());
+            }
+        }
+        if self.metrics_export.enabled {
+            let url = self.metrics_export.url.trim();
+            if !url.starts_with("http://") && !url.starts_with("https://") {
+                return Err(
+                    "logging.metrics_export.url must be an http:// or https:// URL".to_string(),
+                );
+            }
+            if self.metrics_export.interval_secs < 10 {
+                return Err("logging.metrics_export.interval_secs must be at least 10".to_string());
+            }
+            if self.metrics_export.measurement.trim().is_empty() {
+                return Err("logging.metrics_export.measurement cannot be empty".to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OtelConfig {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// OTLP/HTTP traces endpoint.
+    #[serde(default = "default_otel_endpoint")]
+    pub endpoint: String,
+
+    /// `service.name` resource attribute attached to every span.
+    #[serde(default = "default_otel_service_name")]
+    pub service_name: String,
+
+    /// Fraction of queries traced, from 0.0 to 1.0.
+    #[serde(default = "default_otel_sample_ratio")]
+    pub sample_ratio: f64,
+}
+
+impl Default for OtelConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            endpoint: default_otel_endpoint(),
+            service_name: default_otel_service_name(),
+            sample_ratio: default_otel_sample_ratio(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SlowQueryLogConfig {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Queries taking at least this long are captured.
+    #[serde(default = "default_slow_query_threshold_ms")]
+    pub threshold_ms: u64,
+
+    /// Number of slow queries kept in memory; the oldest are dropped first.
+    #[serde(default = "default_slow_query_capacity")]
+    pub capacity: usize,
+}
+
+impl Default for SlowQueryLogConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            threshold_ms: default_slow_query_threshold_ms(),
+            capacity: default_slow_query_capacity(),
+        }
+    }
+}
+
+/// Time-series database the exporter writes to. Both accept InfluxDB line
+/// protocol; they differ in how the token is sent.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MetricsExportBackend {
+    /// InfluxDB 2.x `/api/v2/write`, token sent as `Authorization: Token`.
+    #[default]
+    Influxdb,
+    /// VictoriaMetrics `/write`, token sent as `Authorization: Bearer`.
+    Victoriametrics,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MetricsExportConfig {
+    #[serde(default)]
+    pub enabled: bool,
+
+    #[serde(default)]
+    pub backend: MetricsExportBackend,
+
+    /// Full write URL, including `org`/`bucket` parameters for InfluxDB.
+    #[serde(default)]
+    pub url: String,
+
+    #[serde(default)]
+    pub token: Option<String>,
+
+    /// Length of each aggregation window; one set of points is written per
+    /// window.
+    #[serde(default = "default_metrics_export_interval_secs")]
+    pub interval_secs: u64,
+
+    /// Prefix of the measurement names (`<measurement>`, `<measurement>_upstream`).
+    #[serde(default = "default_metrics_export_measurement")]
+    pub measurement: String,
+
+    /// Extra tags added to every point, e.g. `host = "pi"`.
+    #[serde(default)]
+    pub tags: BTreeMap<String, String>,
+}
+
+impl Default for MetricsExportConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            backend: MetricsExportBackend::default(),
+            url: String::new(),
+            token: None,
+            interval_secs: default_metrics_export_interval_secs(),
+            measurement: default_metrics_export_measurement(),
+            tags: BTreeMap::new(),
         }
     }
 }
@@ -17,3 +219,31 @@ impl Default for LoggingConfig {
 fn default_log_level() -> String {
     "info".to_string()
 }
+
+fn default_otel_endpoint() -> String {
+    "http://localhost:4318/v1/traces".to_string()
+}
+
+fn default_otel_service_name() -> String {
+    "ferrous-dns".to_string()
+}
+
+fn default_otel_sample_ratio() -> f64 {
+    1.0
+}
+
+fn default_slow_query_threshold_ms() -> u64 {
+    500
+}
+
+fn default_slow_query_capacity() -> usize {
+    1000
+}
+
+fn default_metrics_export_interval_secs() -> u64 {
+    60
+}
+
+fn default_metrics_export_measurement() -> String {
+    "ferrous_dns".to_string()
+}
@@ -1,39 +1,97 @@
+pub mod access_control;
+pub mod acme;
+pub mod admin_access;
+pub mod anomaly_detection;
+pub mod api_limits;
 pub mod auth;
 pub mod blocking;
+pub mod bootstrap;
+pub mod chaos_identity;
+pub mod conditional_forward;
 pub mod database;
 pub mod dga_detection;
 pub mod dns;
 pub mod dns_cookies;
+pub mod docker;
+pub mod doh_upstream;
+pub mod edns_client_id;
 pub mod encrypted_dns;
 pub mod errors;
+pub mod field_errors;
 pub mod health;
+pub mod kubernetes;
 pub mod local_records;
 pub mod logging;
+pub mod notifications;
 pub mod nxdomain_hijack;
+pub mod plugins;
+pub mod public_stats;
+pub mod query_budget;
 pub mod rate_limit;
 pub mod response_ip_filter;
+pub mod response_limits;
 pub mod root;
+pub mod secondary_zones;
 pub mod server;
+pub mod standby;
+pub mod tsig;
 pub mod tunneling;
+pub mod update_zones;
 pub mod upstream;
+pub mod upstream_preset;
+pub mod views;
+pub mod vpn_peers;
 pub mod web_tls;
 
+pub use access_control::{AccessControlConfig, AclAction};
+pub use acme::{AcmeChallenge, AcmeConfig};
+pub use admin_access::AdminAccessConfig;
+pub use anomaly_detection::AnomalyDetectionConfig;
+pub use api_limits::ApiLimitsConfig;
 pub use auth::{AdminConfig, AuthConfig};
-pub use blocking::BlockingConfig;
-pub use database::DatabaseConfig;
+pub use blocking::{
+    BlockPageConfig, BlockPageGroupConfig, BlockingConfig, BlockingMode, SinkholeConfig,
+    SinkholeTelemetryConfig, UnblockRequestsConfig,
+};
+pub use bootstrap::BootstrapConfig;
+pub use chaos_identity::ChaosIdentityConfig;
+pub use conditional_forward::ConditionalForwardConfig;
+pub use database::{DatabaseConfig, QueryLogPartitioning};
 pub use dga_detection::{DgaDetectionAction, DgaDetectionConfig};
 pub use dns::DnsConfig;
 pub use dns_cookies::DnsCookiesConfig;
+pub use docker::DockerConfig;
+pub use doh_upstream::{DohMethod, DohUpstreamConfig};
+pub use edns_client_id::EdnsClientIdConfig;
 pub use encrypted_dns::EncryptedDnsConfig;
 pub use errors::ConfigError;
+pub use field_errors::ConfigFieldError;
 pub use health::HealthCheckConfig;
+pub use kubernetes::KubernetesConfig;
 pub use local_records::LocalDnsRecord;
-pub use logging::LoggingConfig;
+pub use logging::{
+    LogFormat, LoggingConfig, MetricsExportBackend, MetricsExportConfig, OtelConfig,
+    SlowQueryLogConfig,
+};
+pub use notifications::{
+    NotificationEventsConfig, NotificationsConfig, ReportsConfig, SmtpConfig, TelegramConfig,
+};
 pub use nxdomain_hijack::{NxdomainHijackAction, NxdomainHijackConfig};
+pub use plugins::PluginsConfig;
+pub use public_stats::PublicStatsConfig;
+pub use query_budget::QueryBudgetConfig;
 pub use rate_limit::RateLimitConfig;
 pub use response_ip_filter::{ResponseIpFilterAction, ResponseIpFilterConfig};
+pub use response_limits::ResponseLimitsConfig;
 pub use root::{CliOverrides, Config};
-pub use server::ServerConfig;
+pub use secondary_zones::SecondaryZoneConfig;
+pub use server::{DnsListenerConfig, ServerConfig};
+pub use standby::StandbyConfig;
+pub use tsig::{TsigAlgorithm, TsigKeyConfig, TsigKeyFile};
 pub use tunneling::{TunnelingAction, TunnelingDetectionConfig};
-pub use upstream::{UpstreamPool, UpstreamStrategy};
+pub use update_zones::UpdateZoneConfig;
+pub use upstream::{UdpSocketMode, UpstreamPool, UpstreamStrategy};
+pub use upstream_preset::UpstreamPreset;
+pub use views::DnsViewConfig;
+pub use vpn_peers::{VpnPeerProvider, VpnPeersConfig};
 pub use web_tls::WebTlsConfig;
@@ -0,0 +1,274 @@
+use serde::{Deserialize, Serialize};
+
+use crate::NotificationKind;
+
+/// Alerts for operational events, delivered to a webhook and optionally to
+/// Telegram and SMTP.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct NotificationsConfig {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Receives a JSON `POST` per event.
+    #[serde(default)]
+    pub webhook_url: Option<String>,
+
+    #[serde(default)]
+    pub telegram: Option<TelegramConfig>,
+
+    #[serde(default)]
+    pub smtp: Option<SmtpConfig>,
+
+    #[serde(default)]
+    pub events: NotificationEventsConfig,
+
+    /// Scheduled usage reports, emailed through `smtp`. Independent of
+    /// `enabled`.
+    #[serde(default)]
+    pub reports: ReportsConfig,
+
+    /// Seconds between monitor checks; also the window for spike and
+    /// DNSSEC Bogus counts.
+    #[serde(default = "default_check_interval_secs")]
+    pub check_interval_secs: u64,
+
+    /// Minimum seconds between two alerts for the same event and subject.
+    #[serde(default = "default_cooldown_secs")]
+    pub cooldown_secs: u64,
+
+    /// Alert when the filesystem holding the database is this full.
+    #[serde(default = "default_disk_usage_percent")]
+    pub disk_usage_percent: u8,
+
+    /// Blocked queries from one client within a check interval that count
+    /// as a spike.
+    #[serde(default = "default_client_block_spike_threshold")]
+    pub client_block_spike_threshold: u64,
+}
+
+impl Default for NotificationsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            webhook_url: None,
+            telegram: None,
+            smtp: None,
+            events: NotificationEventsConfig::default(),
+            reports: ReportsConfig::default(),
+            check_interval_secs: default_check_interval_secs(),
+            cooldown_secs: default_cooldown_secs(),
+            disk_usage_percent: default_disk_usage_percent(),
+            client_block_spike_threshold: default_client_block_spike_threshold(),
+        }
+    }
+}
+
+impl NotificationsConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        self.reports.validate(self.smtp.is_some())?;
+        if !self.enabled {
+            return Ok(());
+        }
+        if let Some(url) = &self.webhook_url {
+            if !url.starts_with("http://") && !url.starts_with("https://") {
+                return Err("notifications.webhook_url must be an http(s) URL".to_string());
+            }
+        }
+        if let Some(telegram) = &self.telegram {
+            if telegram.bot_token.trim().is_empty() || telegram.chat_id.trim().is_empty() {
+                return Err("notifications.telegram requires bot_token and chat_id".to_string());
+            }
+        }
+        if let Some(smtp) = &self.smtp {
+            if smtp.host.trim().is_empty() {
+                return Err("notifications.smtp.host cannot be empty".to_string());
+            }
+            if smtp.from.trim().is_empty() || smtp.to.is_empty() {
+                return Err("notifications.smtp requires from and at least one to".to_string());
+            }
+        }
+        if self.webhook_url.is_none() && self.telegram.is_none() && self.smtp.is_none() {
+            return Err("notifications.enabled requires webhook_url, telegram or smtp".to_string());
+        }
+        if self.check_interval_secs == 0 {
+            return Err("notifications.check_interval_secs must be greater than 0".to_string());
+        }
+        if !(1..=100).contains(&self.disk_usage_percent) {
+            return Err("notifications.disk_usage_percent must be between 1 and 100".to_string());
+        }
+        if self.client_block_spike_threshold == 0 {
+            return Err(
+                "notifications.client_block_spike_threshold must be greater than 0".to_string(),
+            );
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TelegramConfig {
+    pub bot_token: String,
+    pub chat_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SmtpConfig {
+    pub host: String,
+
+    #[serde(default = "default_smtp_port")]
+    pub port: u16,
+
+    #[serde(default)]
+    pub username: Option<String>,
+
+    #[serde(default)]
+    pub password: Option<String>,
+
+    pub from: String,
+
+    pub to: Vec<String>,
+
+    /// Upgrade with STARTTLS; when `false` the port must speak implicit TLS.
+    #[serde(default = "default_true")]
+    pub starttls: bool,
+}
+
+/// Per-event enable flags.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct NotificationEventsConfig {
+    #[serde(default = "default_true")]
+    pub upstream_degraded: bool,
+
+    #[serde(default = "default_true")]
+    pub blocklist_update_failed: bool,
+
+    #[serde(default = "default_true")]
+    pub disk_nearly_full: bool,
+
+    #[serde(default = "default_true")]
+    pub client_block_spike: bool,
+
+    #[serde(default = "default_true")]
+    pub dnssec_bogus: bool,
+}
+
+impl Default for NotificationEventsConfig {
+    fn default() -> Self {
+        Self {
+            upstream_degraded: true,
+            blocklist_update_failed: true,
+            disk_nearly_full: true,
+            client_block_spike: true,
+            dnssec_bogus: true,
+        }
+    }
+}
+
+impl NotificationEventsConfig {
+    pub fn is_enabled(&self, kind: NotificationKind) -> bool {
+        match kind {
+            NotificationKind::UpstreamDegraded => self.upstream_degraded,
+            NotificationKind::BlocklistUpdateFailed => self.blocklist_update_failed,
+            NotificationKind::DiskNearlyFull => self.disk_nearly_full,
+            NotificationKind::ClientBlockSpike => self.client_block_spike,
+            NotificationKind::DnssecBogus => self.dnssec_bogus,
+        }
+    }
+}
+
+/// Weekly and monthly usage reports sent as HTML email.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ReportsConfig {
+    /// Every Monday, covering the previous seven days.
+    #[serde(default)]
+    pub weekly: bool,
+
+    /// On the first of each month, covering the previous month.
+    #[serde(default)]
+    pub monthly: bool,
+
+    /// Hour of the day (0-23) reports are sent, in `timezone`.
+    #[serde(default = "default_report_hour")]
+    pub send_hour: u8,
+
+    /// IANA time zone name, e.g. `Europe/Lisbon`.
+    #[serde(default = "default_report_timezone")]
+    pub timezone: String,
+
+    /// Rows in the top domain and client tables.
+    #[serde(default = "default_report_top_count")]
+    pub top_count: u32,
+}
+
+impl Default for ReportsConfig {
+    fn default() -> Self {
+        Self {
+            weekly: false,
+            monthly: false,
+            send_hour: default_report_hour(),
+            timezone: default_report_timezone(),
+            top_count: default_report_top_count(),
+        }
+    }
+}
+
+impl ReportsConfig {
+    pub fn is_enabled(&self) -> bool {
+        self.weekly || self.monthly
+    }
+
+    fn validate(&self, has_smtp: bool) -> Result<(), String> {
+        if !self.is_enabled() {
+            return Ok(());
+        }
+        if !has_smtp {
+            return Err("notifications.reports requires notifications.smtp".to_string());
+        }
+        if self.send_hour > 23 {
+            return Err("notifications.reports.send_hour must be between 0 and 23".to_string());
+        }
+        if self.timezone.trim().is_empty() {
+            return Err("notifications.reports.timezone cannot be empty".to_string());
+        }
+        if !(1..=100).contains(&self.top_count) {
+            return Err("notifications.reports.top_count must be between 1 and 100".to_string());
+        }
+        Ok(())
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_check_interval_secs() -> u64 {
+    60
+}
+
+fn default_cooldown_secs() -> u64 {
+    900
+}
+
+fn default_disk_usage_percent() -> u8 {
+    90
+}
+
+fn default_client_block_spike_threshold() -> u64 {
+    500
+}
+
+fn default_report_hour() -> u8 {
+    8
+}
+
+fn default_report_timezone() -> String {
+    "UTC".to_string()
+}
+
+fn default_report_top_count() -> u32 {
+    10
+}
+
+fn default_smtp_port() -> u16 {
+    587
+}
@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+
+/// User scripts run inside the resolve pipeline.
+///
+/// Each script may define a `pre_filter(query)` function, run before the
+/// block filter, and a `post_resolve(query, answers)` function, run on every
+/// successful answer. Scripts run in a sandbox without file, OS or module
+/// access, and a call that exceeds `timeout_ms` is aborted and treated as
+/// "continue".
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PluginsConfig {
+    /// Master switch — disabled by default.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Paths of the Lua scripts, run in order. The first script returning a
+    /// verdict other than "continue" decides the query.
+    #[serde(default)]
+    pub scripts: Vec<String>,
+
+    /// Wall-clock budget of a single hook call, in milliseconds.
+    #[serde(default = "default_timeout_ms")]
+    pub timeout_ms: u64,
+
+    /// Memory a script's interpreter may allocate, in KiB.
+    #[serde(default = "default_memory_limit_kb")]
+    pub memory_limit_kb: usize,
+}
+
+impl Default for PluginsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            scripts: Vec::new(),
+            timeout_ms: default_timeout_ms(),
+            memory_limit_kb: default_memory_limit_kb(),
+        }
+    }
+}
+
+fn default_timeout_ms() -> u64 {
+    5
+}
+
+fn default_memory_limit_kb() -> usize {
+    8 * 1024
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_empty_toml_with_defaults() {
+        let config: PluginsConfig = toml::from_str("").unwrap();
+        assert!(!config.enabled);
+        assert!(config.scripts.is_empty());
+        assert_eq!(config.timeout_ms, 5);
+        assert_eq!(config.memory_limit_kb, 8192);
+    }
+
+    #[test]
+    fn deserializes_script_list() {
+        let toml = r#"
+            enabled = true
+            scripts = ["/etc/ferrous-dns/plugins/rate.lua"]
+            timeout_ms = 2
+        "#;
+        let config: PluginsConfig = toml::from_str(toml).unwrap();
+        assert!(config.enabled);
+        assert_eq!(config.scripts, vec!["/etc/ferrous-dns/plugins/rate.lua"]);
+        assert_eq!(config.timeout_ms, 2);
+    }
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+/// Longest window the public summary may cover, matching the dashboard.
+const MAX_PERIOD_HOURS: u32 = 720;
+
+/// An unauthenticated, read-only stats summary for status widgets: query
+/// totals, block percentage and cache hit rate, without domains or client
+/// addresses.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PublicStatsConfig {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Window the summary covers, in hours.
+    #[serde(default = "default_period_hours")]
+    pub period_hours: u32,
+}
+
+impl Default for PublicStatsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            period_hours: default_period_hours(),
+        }
+    }
+}
+
+impl PublicStatsConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.period_hours == 0 || self.period_hours > MAX_PERIOD_HOURS {
+            return Err(format!(
+                "server.public_stats.period_hours must be between 1 and {MAX_PERIOD_HOURS}"
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn default_period_hours() -> u32 {
+    24
+}
@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+
+/// End-to-end time allowed for one client query, shared by its phases so
+/// that retries and failover give up with SERVFAIL before the client's own
+/// timeout (typically 5 s) fires.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct QueryBudgetConfig {
+    /// Milliseconds from receipt to answer; 0 disables the budget and every
+    /// upstream attempt gets the full `query_timeout`.
+    #[serde(default)]
+    pub total_ms: u64,
+
+    /// Milliseconds held back for DNSSEC validation when `dnssec_enabled`:
+    /// upstream attempts must finish this long before the budget ends.
+    #[serde(default = "default_dnssec_ms")]
+    pub dnssec_ms: u64,
+
+    /// An upstream attempt (retry, failover, next pool) is not started with
+    /// less than this many milliseconds left.
+    #[serde(default = "default_min_attempt_ms")]
+    pub min_attempt_ms: u64,
+}
+
+fn default_dnssec_ms() -> u64 {
+    200
+}
+
+fn default_min_attempt_ms() -> u64 {
+    50
+}
+
+impl Default for QueryBudgetConfig {
+    fn default() -> Self {
+        Self {
+            total_ms: 0,
+            dnssec_ms: default_dnssec_ms(),
+            min_attempt_ms: default_min_attempt_ms(),
+        }
+    }
+}
+
+impl QueryBudgetConfig {
+    pub fn is_enabled(&self) -> bool {
+        self.total_ms > 0
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.is_enabled() {
+            return Ok(());
+        }
+        if self.dnssec_ms >= self.total_ms {
+            return Err(format!(
+                "dns.query_budget.dnssec_ms ({}) must be less than total_ms ({})",
+                self.dnssec_ms, self.total_ms
+            ));
+        }
+        if self.min_attempt_ms >= self.total_ms {
+            return Err(format!(
+                "dns.query_budget.min_attempt_ms ({}) must be less than total_ms ({})",
+                self.min_attempt_ms, self.total_ms
+            ));
+        }
+        Ok(())
+    }
+}
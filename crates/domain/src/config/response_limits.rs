@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+/// Caps on what a single query may produce, guarding against pathological
+/// zones and against the server being used to amplify traffic.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ResponseLimitsConfig {
+    /// Answer records returned to a client; extra records are dropped.
+    #[serde(default = "default_max_answers")]
+    pub max_answers: usize,
+
+    /// Largest UDP response in bytes. Responses bigger than this, or than the
+    /// client's advertised EDNS size, are sent truncated (TC=1) so the client
+    /// retries over TCP.
+    #[serde(default = "default_max_udp_response_size")]
+    pub max_udp_response_size: u16,
+
+    /// CNAME records a single answer may chain through, counting aliases
+    /// followed from local records, rewrites and Safe Search. Longer chains
+    /// are answered with SERVFAIL.
+    #[serde(default = "default_max_cname_chain")]
+    pub max_cname_chain: usize,
+}
+
+impl Default for ResponseLimitsConfig {
+    fn default() -> Self {
+        Self {
+            max_answers: default_max_answers(),
+            max_udp_response_size: default_max_udp_response_size(),
+            max_cname_chain: default_max_cname_chain(),
+        }
+    }
+}
+
+impl ResponseLimitsConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.max_answers == 0 {
+            return Err("dns.response_limits.max_answers must be greater than 0".to_string());
+        }
+        if self.max_udp_response_size < 512 {
+            return Err(
+                "dns.response_limits.max_udp_response_size must be at least 512".to_string(),
+            );
+        }
+        if self.max_cname_chain == 0 {
+            return Err("dns.response_limits.max_cname_chain must be greater than 0".to_string());
+        }
+        Ok(())
+    }
+}
+
+fn default_max_answers() -> usize {
+    64
+}
+
+/// The EDNS buffer size recommended by DNS Flag Day 2020.
+fn default_max_udp_response_size() -> u16 {
+    1232
+}
+
+fn default_max_cname_chain() -> usize {
+    8
+}
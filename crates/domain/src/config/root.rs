@@ -1,13 +1,21 @@
 use serde::{Deserialize, Serialize};
 
+use super::access_control::validate_cidrs;
 use super::auth::AuthConfig;
 use super::blocking::BlockingConfig;
+use super::conditional_forward::validate_conditional_forwards;
 use super::database::DatabaseConfig;
 use super::dns::DnsConfig;
 use super::errors::ConfigError;
 use super::logging::LoggingConfig;
+use super::notifications::NotificationsConfig;
+use super::secondary_zones::validate_secondary_zones;
 use super::server::ServerConfig;
-use super::upstream::UpstreamPool;
+use super::standby::StandbyConfig;
+use super::update_zones::validate_update_zones;
+use super::upstream::{UdpSocketMode, UpstreamPool};
+use super::upstream_preset::UpstreamPreset;
+use super::views::validate_views;
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct Config {
@@ -23,6 +31,12 @@ pub struct Config {
 
     #[serde(default)]
     pub auth: AuthConfig,
+
+    #[serde(default)]
+    pub notifications: NotificationsConfig,
+
+    #[serde(default)]
+    pub standby: StandbyConfig,
 }
 
 impl Config {
@@ -64,16 +78,31 @@ impl Config {
         if let Some(level) = overrides.log_level {
             self.logging.level = level;
         }
+        if let Some(preset) = overrides.upstream_preset {
+            // An explicit flag wins over the pools in the config file.
+            self.dns.upstream_preset = Some(preset);
+            self.dns.pools.clear();
+        }
     }
 
-    fn normalize_pools(&mut self) {
-        if self.dns.pools.is_empty() && !self.dns.upstream_servers.is_empty() {
+    /// Fills in the pools when none are configured: from `upstream_preset`
+    /// if set, otherwise by turning the flat `upstream_servers` list into a
+    /// single default pool. Idempotent.
+    pub fn normalize_pools(&mut self) {
+        if !self.dns.pools.is_empty() {
+            return;
+        }
+        if let Some(preset) = self.dns.upstream_preset {
+            self.dns.pools = preset.pools();
+        } else if !self.dns.upstream_servers.is_empty() {
             self.dns.pools.push(UpstreamPool {
                 name: "default".to_string(),
                 strategy: self.dns.default_strategy,
                 priority: 1,
                 servers: self.dns.upstream_servers.clone(),
                 weight: None,
+                tsig_key: None,
+                udp_socket: UdpSocketMode::Pooled,
             });
         }
     }
@@ -83,7 +112,98 @@ impl Config {
             return Err(ConfigError::Validation("DNS port cannot be 0".to_string()));
         }
 
-        if self.dns.pools.is_empty() && self.dns.upstream_servers.is_empty() {
+        self.server
+            .acl
+            .validate()
+            .map_err(ConfigError::Validation)?;
+        self.server
+            .admin_access
+            .validate(self.server.web_tls.enabled)
+            .map_err(ConfigError::Validation)?;
+        self.server
+            .api_limits
+            .validate()
+            .map_err(ConfigError::Validation)?;
+        self.server
+            .public_stats
+            .validate()
+            .map_err(ConfigError::Validation)?;
+        validate_cidrs(&self.dns.local_networks, "dns.local_networks")
+            .map_err(ConfigError::Validation)?;
+        validate_views(&self.dns.views, &self.dns.local_records)
+            .map_err(ConfigError::Validation)?;
+        validate_secondary_zones(
+            &self.dns.secondary_zones,
+            self.dns.tsig_keys_file.as_deref(),
+        )
+        .map_err(ConfigError::Validation)?;
+        validate_update_zones(&self.dns.update_zones, self.dns.tsig_keys_file.as_deref())
+            .map_err(ConfigError::Validation)?;
+        validate_conditional_forwards(&self.dns.conditional_forwards)
+            .map_err(ConfigError::Validation)?;
+        self.dns
+            .anomaly_detection
+            .validate()
+            .map_err(ConfigError::Validation)?;
+        self.dns
+            .response_limits
+            .validate()
+            .map_err(ConfigError::Validation)?;
+        self.dns
+            .chaos_identity
+            .validate()
+            .map_err(ConfigError::Validation)?;
+        self.dns
+            .edns_client_id
+            .validate()
+            .map_err(ConfigError::Validation)?;
+        self.dns
+            .bootstrap
+            .validate()
+            .map_err(ConfigError::Validation)?;
+        self.dns
+            .query_budget
+            .validate()
+            .map_err(ConfigError::Validation)?;
+        self.dns
+            .kubernetes
+            .validate()
+            .map_err(ConfigError::Validation)?;
+        self.dns
+            .docker
+            .validate()
+            .map_err(ConfigError::Validation)?;
+        self.dns
+            .vpn_peers
+            .validate()
+            .map_err(ConfigError::Validation)?;
+        self.server
+            .encrypted_dns
+            .acme
+            .validate()
+            .map_err(ConfigError::Validation)?;
+        self.blocking.validate().map_err(ConfigError::Validation)?;
+        self.logging.validate().map_err(ConfigError::Validation)?;
+        self.notifications
+            .validate()
+            .map_err(ConfigError::Validation)?;
+        self.standby.validate().map_err(ConfigError::Validation)?;
+
+        let mut listener_binds = std::collections::HashSet::new();
+        for listener in &self.server.listeners {
+            listener.validate().map_err(ConfigError::Validation)?;
+            if !listener_binds.insert(listener.bind.as_str()) {
+                return Err(ConfigError::Validation(format!(
+                    "Listener {} is configured more than once",
+                    listener.bind
+                )));
+            }
+        }
+
+        if self.dns.pools.is_empty()
+            && self.dns.upstream_servers.is_empty()
+            && self.dns.upstream_preset.is_none()
+        {
             return Err(ConfigError::Validation(
                 "No upstream servers configured".to_string(),
             ));
@@ -96,6 +216,12 @@ impl Config {
                     pool.name
                 )));
             }
+            if pool.tsig_key.is_some() && self.dns.tsig_keys_file.is_none() {
+                return Err(ConfigError::Validation(format!(
+                    "Pool '{}' sets tsig_key but dns.tsig_keys_file is not configured",
+                    pool.name
+                )));
+            }
         }
 
         Ok(())
@@ -119,4 +245,5 @@ pub struct CliOverrides {
     pub bind_address: Option<String>,
     pub database_path: Option<String>,
     pub log_level: Option<String>,
+    pub upstream_preset: Option<UpstreamPreset>,
 }
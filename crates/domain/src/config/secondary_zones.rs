@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+use std::net::SocketAddr;
+
+/// Port zone transfers use when a primary is given without one.
+const DEFAULT_TRANSFER_PORT: u16 = 53;
+
+/// A zone kept in sync with its primaries by zone transfer and answered
+/// authoritatively. Refresh, retry and expiry follow the zone's SOA timers.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SecondaryZoneConfig {
+    /// Zone apex, e.g. `corp.internal`.
+    pub zone: String,
+
+    /// Primary servers as `IP` or `IP:port`, tried in order.
+    pub primaries: Vec<String>,
+
+    /// Name of the key in `dns.tsig_keys_file` that signs transfers.
+    #[serde(default)]
+    pub tsig_key: Option<String>,
+
+    /// Ask for incremental transfers (IXFR) once a copy of the zone exists.
+    /// Primaries without IXFR answer with a full transfer either way.
+    #[serde(default = "default_true")]
+    pub ixfr: bool,
+}
+
+impl SecondaryZoneConfig {
+    /// Zone apex in lowercase, without the trailing dot.
+    pub fn normalized_zone(&self) -> String {
+        self.zone.trim().trim_end_matches('.').to_ascii_lowercase()
+    }
+
+    /// Primaries parsed as socket addresses, defaulting to port 53.
+    pub fn primary_addrs(&self) -> Result<Vec<SocketAddr>, String> {
+        self.primaries
+            .iter()
+            .map(|primary| {
+                let primary = primary.trim();
+                primary
+                    .parse::<SocketAddr>()
+                    .or_else(|_| {
+                        primary
+                            .parse::<std::net::IpAddr>()
+                            .map(|ip| SocketAddr::new(ip, DEFAULT_TRANSFER_PORT))
+                    })
+                    .map_err(|_| {
+                        format!(
+                            "Secondary zone '{}' has an invalid primary '{}'",
+                            self.zone, primary
+                        )
+                    })
+            })
+            .collect()
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+pub(super) fn validate_secondary_zones(
+    zones: &[SecondaryZoneConfig],
+    tsig_keys_file: Option<&str>,
+) -> Result<(), String> {
+    let mut names = std::collections::HashSet::new();
+    for zone in zones {
+        let name = zone.normalized_zone();
+        if name.is_empty() {
+            return Err("dns.secondary_zones: zone name cannot be empty".to_string());
+        }
+        if !names.insert(name) {
+            return Err(format!(
+                "dns.secondary_zones: zone '{}' is defined more than once",
+                zone.zone
+            ));
+        }
+        if zone.primaries.is_empty() {
+            return Err(format!(
+                "Secondary zone '{}' must list at least one primary",
+                zone.zone
+            ));
+        }
+        zone.primary_addrs()?;
+        if zone.tsig_key.is_some() && tsig_keys_file.is_none() {
+            return Err(format!(
+                "Secondary zone '{}' sets tsig_key but dns.tsig_keys_file is not configured",
+                zone.zone
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zone(name: &str, primaries: &[&str]) -> SecondaryZoneConfig {
+        SecondaryZoneConfig {
+            zone: name.to_string(),
+            primaries: primaries.iter().map(|p| p.to_string()).collect(),
+            tsig_key: None,
+            ixfr: true,
+        }
+    }
+
+    #[test]
+    fn deserializes_with_defaults() {
+        let config: SecondaryZoneConfig = toml::from_str(
+            r#"
+            zone = "corp.internal."
+            primaries = ["10.0.0.2"]
+            "#,
+        )
+        .unwrap();
+        assert!(config.ixfr);
+        assert!(config.tsig_key.is_none());
+        assert_eq!(config.normalized_zone(), "corp.internal");
+    }
+
+    #[test]
+    fn primaries_default_to_port_53() {
+        let config = zone(
+            "corp.internal",
+            &["10.0.0.2", "10.0.0.3:5353", "[fd00::2]:53"],
+        );
+        let addrs = config.primary_addrs().unwrap();
+        assert_eq!(addrs[0].to_string(), "10.0.0.2:53");
+        assert_eq!(addrs[1].to_string(), "10.0.0.3:5353");
+        assert_eq!(addrs[2].to_string(), "[fd00::2]:53");
+    }
+
+    #[test]
+    fn rejects_invalid_zones() {
+        assert!(validate_secondary_zones(&[zone("corp.internal", &[])], None).is_err());
+        assert!(validate_secondary_zones(&[zone("corp.internal", &["primary"])], None).is_err());
+        assert!(validate_secondary_zones(
+            &[
+                zone("corp.internal", &["10.0.0.2"]),
+                zone("CORP.internal.", &["10.0.0.3"])
+            ],
+            None
+        )
+        .is_err());
+
+        let mut signed = zone("corp.internal", &["10.0.0.2"]);
+        signed.tsig_key = Some("xfer".to_string());
+        assert!(validate_secondary_zones(std::slice::from_ref(&signed), None).is_err());
+        assert!(validate_secondary_zones(&[signed], Some("/etc/ferrous-dns/tsig.toml")).is_ok());
+    }
+}
@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 
+use super::access_control::{validate_cidrs, AccessControlConfig, AclAction};
+use super::admin_access::AdminAccessConfig;
+use super::api_limits::ApiLimitsConfig;
 use super::encrypted_dns::EncryptedDnsConfig;
+use super::public_stats::PublicStatsConfig;
 use super::web_tls::WebTlsConfig;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -29,6 +33,57 @@ pub struct ServerConfig {
 
     #[serde(default)]
     pub web_tls: WebTlsConfig,
+
+    /// Port for the gRPC admin API. Disabled when unset.
+    #[serde(default)]
+    pub grpc_port: Option<u16>,
+
+    #[serde(default)]
+    pub acl: AccessControlConfig,
+
+    #[serde(default)]
+    pub admin_access: AdminAccessConfig,
+
+    #[serde(default)]
+    pub api_limits: ApiLimitsConfig,
+
+    #[serde(default)]
+    pub public_stats: PublicStatsConfig,
+
+    /// Plain DNS listeners. When empty, a single listener is started on
+    /// `bind_address:dns_port` that accepts every client.
+    #[serde(default)]
+    pub listeners: Vec<DnsListenerConfig>,
+}
+
+/// One plain DNS (UDP + TCP) listener with its own client policy.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DnsListenerConfig {
+    /// Socket address to bind, e.g. `"10.8.0.1:53"`.
+    pub bind: String,
+
+    /// Client CIDRs allowed to query this listener. Empty allows everyone.
+    #[serde(default)]
+    pub allowed_clients: Vec<String>,
+
+    /// Action for clients outside `allowed_clients`; defaults to
+    /// `server.acl.action`.
+    #[serde(default)]
+    pub action: Option<AclAction>,
+
+    /// Name of the group used for clients of this listener that are not
+    /// assigned to a group or matched by a client subnet.
+    #[serde(default)]
+    pub default_group: Option<String>,
+}
+
+impl DnsListenerConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        self.bind
+            .parse::<std::net::SocketAddr>()
+            .map_err(|e| format!("Invalid listener bind address '{}': {}", self.bind, e))?;
+        validate_cidrs(&self.allowed_clients, &format!("listener {}", self.bind))
+    }
 }
 
 fn default_cors_origins() -> Vec<String> {
@@ -46,6 +101,12 @@ impl Default for ServerConfig {
             proxy_protocol_enabled: false,
             pihole_compat: false,
             web_tls: WebTlsConfig::default(),
+            grpc_port: None,
+            acl: AccessControlConfig::default(),
+            admin_access: AdminAccessConfig::default(),
+            api_limits: ApiLimitsConfig::default(),
+            public_stats: PublicStatsConfig::default(),
+            listeners: Vec::new(),
         }
     }
 }
@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+
+/// Runs this instance as a hot standby of another one: its configuration,
+/// block lists and hottest cache entries are pulled from the primary every
+/// `sync_interval_secs`, so a VRRP failover (keepalived) lands on a warm
+/// resolver.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct StandbyConfig {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Base URL of the primary's web server, e.g. `http://192.168.1.2:8080`.
+    #[serde(default)]
+    pub primary_url: String,
+
+    /// API token created on the primary, sent as `X-Api-Key`. Not needed
+    /// when the primary runs without authentication.
+    #[serde(default)]
+    pub api_token: Option<String>,
+
+    #[serde(default = "default_sync_interval_secs")]
+    pub sync_interval_secs: u64,
+
+    /// Hottest cache entries copied from the primary on every sync; `0`
+    /// leaves the cache alone.
+    #[serde(default = "default_cache_entries")]
+    pub cache_entries: usize,
+
+    /// Syncs that may fail in a row before the standby stops reporting
+    /// itself ready.
+    #[serde(default = "default_max_failed_syncs")]
+    pub max_failed_syncs: u32,
+
+    /// Accept any certificate from an `https` primary.
+    #[serde(default)]
+    pub insecure_skip_tls_verify: bool,
+}
+
+fn default_sync_interval_secs() -> u64 {
+    60
+}
+
+fn default_cache_entries() -> usize {
+    10_000
+}
+
+fn default_max_failed_syncs() -> u32 {
+    3
+}
+
+impl Default for StandbyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            primary_url: String::new(),
+            api_token: None,
+            sync_interval_secs: default_sync_interval_secs(),
+            cache_entries: default_cache_entries(),
+            max_failed_syncs: default_max_failed_syncs(),
+            insecure_skip_tls_verify: false,
+        }
+    }
+}
+
+impl StandbyConfig {
+    /// `primary_url` without surrounding whitespace or trailing slashes.
+    pub fn normalized_primary_url(&self) -> &str {
+        self.primary_url.trim().trim_end_matches('/')
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.enabled {
+            return Ok(());
+        }
+        let url = self.normalized_primary_url();
+        if !url.starts_with("http://") && !url.starts_with("https://") {
+            return Err("standby.primary_url must be an http(s) URL".to_string());
+        }
+        if self.sync_interval_secs < 10 {
+            return Err("standby.sync_interval_secs must be at least 10".to_string());
+        }
+        if self.max_failed_syncs == 0 {
+            return Err("standby.max_failed_syncs must be at least 1".to_string());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+use serde::{Deserialize, Serialize};
+
+use super::errors::ConfigError;
+
+/// HMAC algorithm of a TSIG key (RFC 8945).
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, Default)]
+pub enum TsigAlgorithm {
+    #[default]
+    #[serde(rename = "hmac-sha256")]
+    HmacSha256,
+
+    #[serde(rename = "hmac-sha384")]
+    HmacSha384,
+
+    #[serde(rename = "hmac-sha512")]
+    HmacSha512,
+}
+
+impl TsigAlgorithm {
+    /// Algorithm name as it appears in the TSIG record.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::HmacSha256 => "hmac-sha256",
+            Self::HmacSha384 => "hmac-sha384",
+            Self::HmacSha512 => "hmac-sha512",
+        }
+    }
+}
+
+/// A shared secret used to sign queries to one or more upstream pools.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TsigKeyConfig {
+    /// Key name, which must match the name configured on the server.
+    pub name: String,
+
+    #[serde(default)]
+    pub algorithm: TsigAlgorithm,
+
+    /// Base64-encoded secret, as printed by `tsig-keygen`.
+    pub secret: String,
+}
+
+/// Contents of the file named by `dns.tsig_keys_file`. Key material lives
+/// outside the main config so the config can be shared or exported without
+/// leaking secrets.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct TsigKeyFile {
+    #[serde(default)]
+    pub keys: Vec<TsigKeyConfig>,
+}
+
+impl TsigKeyFile {
+    pub fn load(path: &str) -> Result<Self, ConfigError> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| ConfigError::FileRead(path.to_string(), e.to_string()))?;
+        Self::parse(&contents)
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, ConfigError> {
+        let file: Self = toml::from_str(contents).map_err(|e| ConfigError::Parse(e.to_string()))?;
+        let mut names = std::collections::HashSet::new();
+        for key in &file.keys {
+            if !names.insert(key.name.trim_end_matches('.').to_ascii_lowercase()) {
+                return Err(ConfigError::Validation(format!(
+                    "TSIG key '{}' is defined more than once",
+                    key.name
+                )));
+            }
+        }
+        Ok(file)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&TsigKeyConfig> {
+        let name = name.trim_end_matches('.');
+        self.keys
+            .iter()
+            .find(|key| key.name.trim_end_matches('.').eq_ignore_ascii_case(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_keys_with_default_algorithm() {
+        let file = TsigKeyFile::parse(
+            r#"
+            [[keys]]
+            name = "ferrous-internal."
+            secret = "c2VjcmV0"
+
+            [[keys]]
+            name = "ad-key"
+            algorithm = "hmac-sha512"
+            secret = "c2VjcmV0"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(file.keys.len(), 2);
+        assert_eq!(file.keys[0].algorithm, TsigAlgorithm::HmacSha256);
+        assert_eq!(file.keys[1].algorithm, TsigAlgorithm::HmacSha512);
+        assert!(file.get("ferrous-internal").is_some());
+        assert!(file.get("AD-KEY.").is_some());
+        assert!(file.get("missing").is_none());
+    }
+
+    #[test]
+    fn rejects_duplicate_key_names() {
+        let err = TsigKeyFile::parse(
+            r#"
+            [[keys]]
+            name = "dup"
+            secret = "c2VjcmV0"
+
+            [[keys]]
+            name = "dup."
+            secret = "c2VjcmV0"
+            "#,
+        )
+        .unwrap_err();
+        assert!(matches!(err, ConfigError::Validation(_)));
+    }
+}
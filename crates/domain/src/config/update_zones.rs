@@ -0,0 +1,125 @@
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+
+use super::access_control::validate_cidrs;
+
+/// A local zone that accepts dynamic updates (RFC 2136), e.g. from a DHCP
+/// server registering its leases. Names in the zone are written to the
+/// local records database.
+///
+/// An update must come from a client in `allow_clients` when that list is
+/// set, and be signed with one of `tsig_keys` when that list is set.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct UpdateZoneConfig {
+    /// Zone apex, e.g. `home.lan`.
+    pub zone: String,
+
+    /// Client IPs or CIDRs allowed to send updates.
+    #[serde(default)]
+    pub allow_clients: Vec<String>,
+
+    /// Names of the keys in `dns.tsig_keys_file` allowed to sign updates.
+    #[serde(default)]
+    pub tsig_keys: Vec<String>,
+}
+
+impl UpdateZoneConfig {
+    /// Zone apex in lowercase, without the trailing dot.
+    pub fn normalized_zone(&self) -> String {
+        self.zone.trim().trim_end_matches('.').to_ascii_lowercase()
+    }
+
+    /// Whether an update from `client_ip`, signed with `key` if any, passes
+    /// the zone's client list and key list.
+    pub fn allows(&self, client_ip: IpAddr, key: Option<&str>) -> bool {
+        let client_ok = self.allow_clients.is_empty()
+            || self.allow_clients.iter().any(|cidr| {
+                cidr.parse::<ipnetwork::IpNetwork>()
+                    .is_ok_and(|network| network.contains(client_ip))
+            });
+        let key_ok = self.tsig_keys.is_empty()
+            || key.is_some_and(|key| {
+                let key = key.trim_end_matches('.');
+                self.tsig_keys
+                    .iter()
+                    .any(|allowed| allowed.trim_end_matches('.').eq_ignore_ascii_case(key))
+            });
+        client_ok && key_ok
+    }
+}
+
+pub(super) fn validate_update_zones(
+    zones: &[UpdateZoneConfig],
+    tsig_keys_file: Option<&str>,
+) -> Result<(), String> {
+    let mut names = std::collections::HashSet::new();
+    for zone in zones {
+        let name = zone.normalized_zone();
+        if name.is_empty() {
+            return Err("dns.update_zones: zone name cannot be empty".to_string());
+        }
+        if !names.insert(name) {
+            return Err(format!(
+                "dns.update_zones: zone '{}' is defined more than once",
+                zone.zone
+            ));
+        }
+        if zone.allow_clients.is_empty() && zone.tsig_keys.is_empty() {
+            return Err(format!(
+                "Update zone '{}' must list at least one allowed client or TSIG key",
+                zone.zone
+            ));
+        }
+        validate_cidrs(&zone.allow_clients, &format!("update zone '{}'", zone.zone))?;
+        if !zone.tsig_keys.is_empty() && tsig_keys_file.is_none() {
+            return Err(format!(
+                "Update zone '{}' sets tsig_keys but dns.tsig_keys_file is not configured",
+                zone.zone
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zone(clients: &[&str], keys: &[&str]) -> UpdateZoneConfig {
+        UpdateZoneConfig {
+            zone: "home.lan".to_string(),
+            allow_clients: clients.iter().map(|c| c.to_string()).collect(),
+            tsig_keys: keys.iter().map(|k| k.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn requires_both_client_and_key_when_both_are_set() {
+        let config = zone(&["192.168.1.0/24"], &["dhcp-update"]);
+        let dhcp: IpAddr = "192.168.1.1".parse().unwrap();
+        let other: IpAddr = "10.0.0.1".parse().unwrap();
+
+        assert!(config.allows(dhcp, Some("DHCP-update.")));
+        assert!(!config.allows(dhcp, None));
+        assert!(!config.allows(dhcp, Some("other")));
+        assert!(!config.allows(other, Some("dhcp-update")));
+        assert!(zone(&["192.168.1.0/24"], &[]).allows(dhcp, None));
+        assert!(zone(&[], &["dhcp-update"]).allows(other, Some("dhcp-update")));
+    }
+
+    #[test]
+    fn rejects_invalid_zones() {
+        let keys_file = Some("/etc/ferrous-dns/tsig.toml");
+        assert!(validate_update_zones(&[zone(&[], &[])], keys_file).is_err());
+        assert!(validate_update_zones(&[zone(&["not-an-ip"], &[])], keys_file).is_err());
+        assert!(validate_update_zones(&[zone(&[], &["dhcp-update"])], None).is_err());
+        assert!(validate_update_zones(
+            &[zone(&["192.168.1.1"], &[]), zone(&["192.168.1.2"], &[])],
+            keys_file
+        )
+        .is_err());
+        assert!(
+            validate_update_zones(&[zone(&["192.168.1.1"], &["dhcp-update"])], keys_file).is_ok()
+        );
+    }
+}
@@ -13,6 +13,36 @@ pub struct UpstreamPool {
 
     #[serde(default)]
     pub weight: Option<u32>,
+
+    /// Name of the key in `dns.tsig_keys_file` that signs this pool's
+    /// queries. Responses must carry a valid signature from the same key.
+    #[serde(default)]
+    pub tsig_key: Option<String>,
+
+    /// How this pool's plain UDP queries get their socket.
+    #[serde(default)]
+    pub udp_socket: UdpSocketMode,
+}
+
+/// Socket a pool sends plain UDP queries from.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum UdpSocketMode {
+    /// Long-lived connected sockets shared through the UDP socket pool.
+    #[default]
+    Pooled,
+    /// A new socket bound to a random source port for every query, so an
+    /// off-path spoofer cannot learn the port from earlier queries.
+    PerQuery,
+}
+
+impl UdpSocketMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pooled => "pooled",
+            Self::PerQuery => "per_query",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, Default)]
@@ -0,0 +1,172 @@
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+use super::upstream::{UdpSocketMode, UpstreamPool, UpstreamStrategy};
+
+/// Ready-made upstream setups selected with `dns.upstream_preset` or
+/// `--upstream-preset`. Each expands into complete `dns.pools` entries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum UpstreamPreset {
+    /// Cloudflare over DoH and DoT, plain 1.1.1.1 as fallback.
+    Cloudflare,
+    /// Quad9 (malware blocking) over DoH and DoT, plain 9.9.9.9 as fallback.
+    Quad9,
+    /// Google Public DNS over DoH and DoT, plain 8.8.8.8 as fallback.
+    Google,
+    /// Mullvad over DoH and DoT. Mullvad serves no plain DNS, so there is no
+    /// fallback pool.
+    Mullvad,
+    /// Several no-logging providers over DoT only; nothing leaves the host
+    /// unencrypted.
+    DotPrivacy,
+    /// Family filtering resolvers (adult content blocked, safe search
+    /// enforced) from Cloudflare and CleanBrowsing.
+    Family,
+}
+
+impl UpstreamPreset {
+    pub const ALL: [UpstreamPreset; 6] = [
+        Self::Cloudflare,
+        Self::Quad9,
+        Self::Google,
+        Self::Mullvad,
+        Self::DotPrivacy,
+        Self::Family,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Cloudflare => "cloudflare",
+            Self::Quad9 => "quad9",
+            Self::Google => "google",
+            Self::Mullvad => "mullvad",
+            Self::DotPrivacy => "dot-privacy",
+            Self::Family => "family",
+        }
+    }
+
+    /// The pools this preset stands for: an encrypted pool at priority 1
+    /// and, where the provider offers one, a plain DNS pool at priority 2
+    /// that is only used while every encrypted server is down.
+    pub fn pools(&self) -> Vec<UpstreamPool> {
+        match self {
+            Self::Cloudflare => vec![
+                pool(
+                    "cloudflare",
+                    UpstreamStrategy::Parallel,
+                    1,
+                    &[
+                        "https://cloudflare-dns.com/dns-query",
+                        "tls://one.one.one.one:853",
+                    ],
+                ),
+                pool(
+                    "cloudflare-fallback",
+                    UpstreamStrategy::Failover,
+                    2,
+                    &["1.1.1.1:53", "1.0.0.1:53"],
+                ),
+            ],
+            Self::Quad9 => vec![
+                pool(
+                    "quad9",
+                    UpstreamStrategy::Parallel,
+                    1,
+                    &["https://dns.quad9.net/dns-query", "tls://dns.quad9.net:853"],
+                ),
+                pool(
+                    "quad9-fallback",
+                    UpstreamStrategy::Failover,
+                    2,
+                    &["9.9.9.9:53", "149.112.112.112:53"],
+                ),
+            ],
+            Self::Google => vec![
+                pool(
+                    "google",
+                    UpstreamStrategy::Parallel,
+                    1,
+                    &["https://dns.google/dns-query", "tls://dns.google:853"],
+                ),
+                pool(
+                    "google-fallback",
+                    UpstreamStrategy::Failover,
+                    2,
+                    &["8.8.8.8:53", "8.8.4.4:53"],
+                ),
+            ],
+            Self::Mullvad => vec![pool(
+                "mullvad",
+                UpstreamStrategy::Parallel,
+                1,
+                &[
+                    "https://dns.mullvad.net/dns-query",
+                    "tls://dns.mullvad.net:853",
+                ],
+            )],
+            Self::DotPrivacy => vec![pool(
+                "dot-privacy",
+                UpstreamStrategy::Balanced,
+                1,
+                &[
+                    "tls://dns.quad9.net:853",
+                    "tls://dns.mullvad.net:853",
+                    "tls://one.one.one.one:853",
+                ],
+            )],
+            Self::Family => vec![
+                pool(
+                    "family",
+                    UpstreamStrategy::Parallel,
+                    1,
+                    &[
+                        "https://family.cloudflare-dns.com/dns-query",
+                        "https://doh.cleanbrowsing.org/doh/family-filter/",
+                    ],
+                ),
+                pool(
+                    "family-fallback",
+                    UpstreamStrategy::Failover,
+                    2,
+                    &["1.1.1.3:53", "1.0.0.3:53", "185.228.168.168:53"],
+                ),
+            ],
+        }
+    }
+}
+
+fn pool(name: &str, strategy: UpstreamStrategy, priority: u8, servers: &[&str]) -> UpstreamPool {
+    UpstreamPool {
+        name: name.to_string(),
+        strategy,
+        priority,
+        servers: servers.iter().map(|s| s.to_string()).collect(),
+        weight: None,
+        tsig_key: None,
+        udp_socket: UdpSocketMode::Pooled,
+    }
+}
+
+impl FromStr for UpstreamPreset {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|preset| preset.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                let names: Vec<&str> = Self::ALL.iter().map(|p| p.as_str()).collect();
+                format!(
+                    "Unknown upstream preset '{s}': expected one of {}",
+                    names.join(", ")
+                )
+            })
+    }
+}
+
+impl std::fmt::Display for UpstreamPreset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+use super::access_control::validate_cidrs;
+use super::local_records::LocalDnsRecord;
+
+/// A split-horizon view: local records tagged with the view's name answer
+/// only for clients in `clients` (CIDRs) or in one of `groups`. Views are
+/// evaluated in file order and the first one a client matches is used.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DnsViewConfig {
+    pub name: String,
+
+    #[serde(default)]
+    pub clients: Vec<String>,
+
+    #[serde(default)]
+    pub groups: Vec<i64>,
+}
+
+pub(super) fn validate_views(
+    views: &[DnsViewConfig],
+    records: &[LocalDnsRecord],
+) -> Result<(), String> {
+    let mut names = std::collections::HashSet::new();
+    for view in views {
+        if view.name.trim().is_empty() {
+            return Err("dns.views: view name cannot be empty".to_string());
+        }
+        if !names.insert(view.name.as_str()) {
+            return Err(format!(
+                "dns.views: view '{}' is defined more than once",
+                view.name
+            ));
+        }
+        if view.clients.is_empty() && view.groups.is_empty() {
+            return Err(format!(
+                "dns.views: view '{}' must list at least one client CIDR or group",
+                view.name
+            ));
+        }
+        validate_cidrs(&view.clients, &format!("view '{}'", view.name))?;
+    }
+
+    for record in records {
+        if let Some(ref view) = record.view {
+            if !names.contains(view.as_str()) {
+                return Err(format!(
+                    "Local record '{}' references unknown view '{}'",
+                    record.hostname, view
+                ));
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use serde::{Deserialize, Serialize};
+
+/// Where VPN peers are read from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VpnPeerProvider {
+    /// The peer list of the local `tailscaled`, through its LocalAPI socket.
+    #[default]
+    Tailscale,
+    /// The `[Peer]` sections of a WireGuard configuration file.
+    Wireguard,
+}
+
+/// Publishes the peers of a Tailscale tailnet or a WireGuard interface as
+/// local records under `domain`, refreshed every `sync_interval_secs`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct VpnPeersConfig {
+    #[serde(default)]
+    pub enabled: bool,
+
+    #[serde(default)]
+    pub provider: VpnPeerProvider,
+
+    /// Zone the records are published under: peer `laptop` answers as
+    /// `laptop.<domain>`.
+    #[serde(default = "default_domain")]
+    pub domain: String,
+
+    /// Path to the `tailscaled` LocalAPI socket.
+    #[serde(default = "default_tailscale_socket")]
+    pub tailscale_socket: String,
+
+    /// WireGuard configuration file whose peers are published. Peers are
+    /// named by a `# Name = <host>` comment in their `[Peer]` section.
+    #[serde(default = "default_wireguard_config")]
+    pub wireguard_config: String,
+
+    #[serde(default = "default_sync_interval_secs")]
+    pub sync_interval_secs: u64,
+
+    /// TTL of the published records.
+    #[serde(default = "default_ttl")]
+    pub ttl: u32,
+}
+
+fn default_domain() -> String {
+    "vpn.lan".to_string()
+}
+
+fn default_tailscale_socket() -> String {
+    "/var/run/tailscale/tailscaled.sock".to_string()
+}
+
+fn default_wireguard_config() -> String {
+    "/etc/wireguard/wg0.conf".to_string()
+}
+
+fn default_sync_interval_secs() -> u64 {
+    60
+}
+
+fn default_ttl() -> u32 {
+    60
+}
+
+impl Default for VpnPeersConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            provider: VpnPeerProvider::default(),
+            domain: default_domain(),
+            tailscale_socket: default_tailscale_socket(),
+            wireguard_config: default_wireguard_config(),
+            sync_interval_secs: default_sync_interval_secs(),
+            ttl: default_ttl(),
+        }
+    }
+}
+
+impl VpnPeersConfig {
+    /// `domain` in lowercase, without leading or trailing dots.
+    pub fn normalized_domain(&self) -> String {
+        self.domain.trim().trim_matches('.').to_ascii_lowercase()
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.enabled {
+            return Ok(());
+        }
+        if self.normalized_domain().is_empty() {
+            return Err("dns.vpn_peers.domain cannot be empty".to_string());
+        }
+        let (field, path) = match self.provider {
+            VpnPeerProvider::Tailscale => ("tailscale_socket", &self.tailscale_socket),
+            VpnPeerProvider::Wireguard => ("wireguard_config", &self.wireguard_config),
+        };
+        if path.trim().is_empty() {
+            return Err(format!("dns.vpn_peers.{field} cannot be empty"));
+        }
+        if self.sync_interval_secs < 5 {
+            return Err("dns.vpn_peers.sync_interval_secs must be at least 5".to_string());
+        }
+        Ok(())
+    }
+}
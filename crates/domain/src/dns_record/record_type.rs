@@ -45,6 +45,8 @@ pub enum RecordType {
     ZONEMD,
 
     ANAME,
+
+    ANY,
 }
 
 macro_rules! impl_record_type_conversions {
@@ -114,6 +116,7 @@ impl_record_type_conversions! {
     (ZONEMD,     "ZONEMD",     63),
     (SVCB,       "SVCB",       64),
     (HTTPS,      "HTTPS",      65),
+    (ANY,        "ANY",        255),
     (CAA,        "CAA",        257),
     (ANAME,      "ANAME",      32769),
 }
@@ -154,7 +157,7 @@ impl RecordType {
 
             RecordType::NULL | RecordType::HINFO | RecordType::WKS => RecordCategory::Legacy,
 
-            RecordType::OPT => RecordCategory::Protocol,
+            RecordType::OPT | RecordType::ANY => RecordCategory::Protocol,
 
             RecordType::ZONEMD => RecordCategory::Integrity,
         }
@@ -192,7 +195,7 @@ impl RecordType {
             }
             RecordCategory::Security => vec![CAA, TLSA, SSHFP, IPSECKEY, OPENPGPKEY],
             RecordCategory::Legacy => vec![NULL, HINFO, WKS],
-            RecordCategory::Protocol => vec![OPT],
+            RecordCategory::Protocol => vec![OPT, ANY],
             RecordCategory::Integrity => vec![ZONEMD],
         }
     }
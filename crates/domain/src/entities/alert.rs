@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+
+/// Client behaviour flagged by anomaly detection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertKind {
+    /// A large share of the client's queries were answered NXDOMAIN.
+    NxdomainRate,
+    /// Many random-looking subdomains under one apex (tunneling/DGA-like).
+    SubdomainEntropy,
+    /// Query volume jumped well above the client's moving average.
+    VolumeSpike,
+}
+
+impl AlertKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::NxdomainRate => "nxdomain_rate",
+            Self::SubdomainEntropy => "subdomain_entropy",
+            Self::VolumeSpike => "volume_spike",
+        }
+    }
+}
+
+impl std::str::FromStr for AlertKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "nxdomain_rate" => Ok(Self::NxdomainRate),
+            "subdomain_entropy" => Ok(Self::SubdomainEntropy),
+            "volume_spike" => Ok(Self::VolumeSpike),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A single anomaly finding for one client, persisted in the `alerts` table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Alert {
+    pub id: Option<i64>,
+    pub client_ip: String,
+    pub kind: AlertKind,
+    /// Measured value (rate, bits/char or volume ratio).
+    pub score: f64,
+    /// Configured threshold the score crossed.
+    pub threshold: f64,
+    /// Apex domain involved, for entropy alerts.
+    pub domain: Option<String>,
+    pub details: String,
+    pub created_at: Option<String>,
+}
+
+impl Alert {
+    pub fn new(
+        client_ip: impl Into<String>,
+        kind: AlertKind,
+        score: f64,
+        threshold: f64,
+        details: impl Into<String>,
+    ) -> Self {
+        Self {
+            id: None,
+            client_ip: client_ip.into(),
+            kind,
+            score,
+            threshold,
+            domain: None,
+            details: details.into(),
+            created_at: None,
+        }
+    }
+
+    pub fn with_domain(mut self, domain: impl Into<String>) -> Self {
+        self.domain = Some(domain.into());
+        self
+    }
+}
@@ -15,6 +15,8 @@ pub struct ApiToken {
     pub key_hash: Arc<str>,
     /// The raw token value, persisted for admin display.
     pub key_raw: Option<Arc<str>>,
+    /// Tenant the token is scoped to; `None` for admin tokens.
+    pub tenant_id: Option<i64>,
     pub created_at: Option<String>,
     pub last_used_at: Option<String>,
 }
@@ -27,6 +29,7 @@ impl ApiToken {
             key_prefix,
             key_hash,
             key_raw: None,
+            tenant_id: None,
             created_at: None,
             last_used_at: None,
         }
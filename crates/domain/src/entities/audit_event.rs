@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+
+/// Kind of change recorded in the audit log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+    /// A dynamic update (RFC 2136) received over DNS.
+    DnsUpdate,
+}
+
+impl AuditAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::DnsUpdate => "dns_update",
+        }
+    }
+}
+
+impl std::str::FromStr for AuditAction {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dns_update" => Ok(Self::DnsUpdate),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A change made outside the web UI, persisted in the `audit_log` table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEvent {
+    pub id: Option<i64>,
+    pub action: AuditAction,
+    pub client_ip: String,
+    /// Who made the change beyond the client address, e.g. the TSIG key
+    /// that signed an update.
+    pub actor: Option<String>,
+    /// What was changed, e.g. the updated zone.
+    pub target: String,
+    /// `NOERROR` when the change was applied, the rejecting RCODE otherwise.
+    pub result: String,
+    pub details: String,
+    pub created_at: Option<String>,
+}
+
+impl AuditEvent {
+    pub fn new(
+        action: AuditAction,
+        client_ip: impl Into<String>,
+        target: impl Into<String>,
+        result: impl Into<String>,
+        details: impl Into<String>,
+    ) -> Self {
+        Self {
+            id: None,
+            action,
+            client_ip: client_ip.into(),
+            actor: None,
+            target: target.into(),
+            result: result.into(),
+            details: details.into(),
+            created_at: None,
+        }
+    }
+
+    pub fn with_actor(mut self, actor: impl Into<String>) -> Self {
+        self.actor = Some(actor.into());
+        self
+    }
+}
@@ -29,6 +29,14 @@ pub enum BlockSource {
     ResponseIpFilter,
     /// Blocked by DGA (Domain Generation Algorithm) detection.
     DgaDetection,
+    /// Blocked by a query policy rule with the `block` action.
+    QueryPolicy,
+    /// Refused by the client group's record type policy.
+    RecordTypeFilter,
+    /// Blocked by a plugin script hook.
+    Plugin,
+    /// Blocked by the client group's TLD policy.
+    TldPolicy,
 }
 
 impl BlockSource {
@@ -45,6 +53,10 @@ impl BlockSource {
             BlockSource::NxdomainHijack => "nxdomain_hijack",
             BlockSource::ResponseIpFilter => "response_ip_filter",
             BlockSource::DgaDetection => "dga_detection",
+            BlockSource::QueryPolicy => "query_policy",
+            BlockSource::RecordTypeFilter => "record_type_filter",
+            BlockSource::Plugin => "plugin",
+            BlockSource::TldPolicy => "tld_policy",
         }
     }
 
@@ -61,6 +73,10 @@ impl BlockSource {
             8 => Some(BlockSource::NxdomainHijack),
             9 => Some(BlockSource::ResponseIpFilter),
             10 => Some(BlockSource::DgaDetection),
+            11 => Some(BlockSource::QueryPolicy),
+            12 => Some(BlockSource::RecordTypeFilter),
+            13 => Some(BlockSource::Plugin),
+            14 => Some(BlockSource::TldPolicy),
             _ => None,
         }
     }
@@ -78,6 +94,10 @@ impl BlockSource {
             BlockSource::NxdomainHijack => 8,
             BlockSource::ResponseIpFilter => 9,
             BlockSource::DgaDetection => 10,
+            BlockSource::QueryPolicy => 11,
+            BlockSource::RecordTypeFilter => 12,
+            BlockSource::Plugin => 13,
+            BlockSource::TldPolicy => 14,
         }
     }
 }
@@ -1,3 +1,5 @@
+use super::managed_domain::ManagedDomain;
+
 #[derive(Debug, Clone)]
 pub struct BlockedDomain {
     pub id: Option<i64>,
@@ -6,6 +8,9 @@ pub struct BlockedDomain {
 }
 
 impl BlockedDomain {
+    /// Fields accepted by `PageRequest::sorted_by` for blocklist listings.
+    pub const SORT_FIELDS: &'static [&'static str] = &["domain", "added_at"];
+
     pub fn new(domain: String) -> Self {
         Self {
             id: None,
@@ -13,4 +18,13 @@ impl BlockedDomain {
             added_at: None,
         }
     }
+
+    /// Manual blocklist entries are exact names; wildcards belong in managed
+    /// domains or regex filters.
+    pub fn validate_domain(domain: &str) -> Result<(), String> {
+        if domain.contains('*') {
+            return Err("Wildcards are not supported in the blocklist".to_string());
+        }
+        ManagedDomain::validate_domain(domain)
+    }
 }
@@ -10,11 +10,20 @@ pub struct BlocklistSource {
     pub group_ids: Vec<i64>,
     pub comment: Option<Arc<str>>,
     pub enabled: bool,
+    /// Matches are logged as "would block" and the query is answered
+    /// normally, so a list can be trialled before it is enforced.
+    pub audit_mode: bool,
+    /// Tenant owning the source; `None` for sources outside any tenant.
+    pub tenant_id: Option<i64>,
     pub created_at: Option<String>,
     pub updated_at: Option<String>,
 }
 
 impl BlocklistSource {
+    /// Fields accepted by `PageRequest::sorted_by` for source listings.
+    pub const SORT_FIELDS: &'static [&'static str] =
+        &["name", "enabled", "created_at", "updated_at"];
+
     pub fn new(
         id: Option<i64>,
         name: Arc<str>,
@@ -30,6 +39,8 @@ impl BlocklistSource {
             group_ids,
             comment,
             enabled,
+            audit_mode: false,
+            tenant_id: None,
             created_at: None,
             updated_at: None,
         }
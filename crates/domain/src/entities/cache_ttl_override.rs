@@ -0,0 +1,121 @@
+use std::sync::Arc;
+
+/// Longest TTL an override may force, matching the local record cap.
+pub const MAX_CACHE_TTL_OVERRIDE: u32 = 604_800;
+
+/// Most enabled wildcard overrides the cache evaluates.
+pub const MAX_WILDCARD_TTL_OVERRIDES: usize = 64;
+
+/// TTL bounds forced on cached answers for a domain, in place of the
+/// global `cache_min_ttl`/`cache_max_ttl`. An unset bound falls back to
+/// the global one; setting both to the same value pins the TTL.
+#[derive(Debug, Clone)]
+pub struct CacheTtlOverride {
+    pub id: Option<i64>,
+    pub domain: Arc<str>,
+    pub min_ttl: Option<u32>,
+    pub max_ttl: Option<u32>,
+    pub enabled: bool,
+    pub comment: Option<Arc<str>>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+impl CacheTtlOverride {
+    pub fn new(domain: Arc<str>, min_ttl: Option<u32>, max_ttl: Option<u32>) -> Self {
+        Self {
+            id: None,
+            domain,
+            min_ttl,
+            max_ttl,
+            enabled: true,
+            comment: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    /// Whether the override applies to the subdomains of a `*.` pattern.
+    pub fn is_wildcard(&self) -> bool {
+        self.domain.starts_with("*.")
+    }
+
+    pub fn bounds(&self) -> TtlBounds {
+        TtlBounds {
+            min_ttl: self.min_ttl,
+            max_ttl: self.max_ttl,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        Self::validate_domain(&self.domain)?;
+        match (self.min_ttl, self.max_ttl) {
+            (None, None) => {
+                return Err("Set min_ttl, max_ttl or both".to_string());
+            }
+            (Some(min), Some(max)) if min > max => {
+                return Err(format!("min_ttl ({}) cannot exceed max_ttl ({})", min, max));
+            }
+            _ => {}
+        }
+        for ttl in [self.min_ttl, self.max_ttl].into_iter().flatten() {
+            if ttl > MAX_CACHE_TTL_OVERRIDE {
+                return Err(format!(
+                    "TTL cannot exceed {} seconds",
+                    MAX_CACHE_TTL_OVERRIDE
+                ));
+            }
+        }
+        if let Some(c) = &self.comment {
+            if c.len() > 500 {
+                return Err("Comment cannot exceed 500 characters".to_string());
+            }
+        }
+        Ok(())
+    }
+
+    /// Accepts an exact name (`cdn.example.com`) or a leading wildcard
+    /// (`*.internal.corp`, which matches subdomains but not the apex), the
+    /// same patterns as blocklist wildcards.
+    pub fn validate_domain(domain: &str) -> Result<(), String> {
+        let base = domain.strip_prefix("*.").unwrap_or(domain);
+        if base.is_empty() || base.len() > 253 {
+            return Err(format!("Invalid override domain '{}'", domain));
+        }
+        if base.contains('*') || base.starts_with('.') || base.ends_with('.') || base.contains("..")
+        {
+            return Err(format!(
+                "Invalid override domain '{}': only a leading '*.' wildcard is supported",
+                domain
+            ));
+        }
+        if !base
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        {
+            return Err(format!(
+                "Invalid override domain '{}': contains invalid characters",
+                domain
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Bounds of the override matching a name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TtlBounds {
+    pub min_ttl: Option<u32>,
+    pub max_ttl: Option<u32>,
+}
+
+impl TtlBounds {
+    /// Clamps `ttl` to these bounds, using `default_min`/`default_max` for
+    /// the unset ones. A forced minimum wins over a lower default maximum.
+    #[inline]
+    pub fn clamp(&self, ttl: u32, default_min: u32, default_max: u32) -> u32 {
+        let min = self.min_ttl.unwrap_or(default_min);
+        let max = self.max_ttl.unwrap_or(default_max).max(min);
+        ttl.clamp(min, max)
+    }
+}
@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use std::net::IpAddr;
 use std::sync::Arc;
 
@@ -5,6 +6,72 @@ const MAC_UPDATE_THRESHOLD_SECS: i64 = 300;
 
 const HOSTNAME_UPDATE_THRESHOLD_SECS: i64 = 3600;
 
+pub const MAX_DISPLAY_NAME_LEN: usize = 64;
+
+pub const MAX_NOTES_LEN: usize = 1024;
+
+/// Device category chosen by the user; the dashboard maps it to an icon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ClientCategory {
+    Phone,
+    Tablet,
+    Computer,
+    Laptop,
+    Tv,
+    Console,
+    Speaker,
+    Camera,
+    Printer,
+    Server,
+    Router,
+    Iot,
+    Other,
+}
+
+impl ClientCategory {
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            ClientCategory::Phone => "phone",
+            ClientCategory::Tablet => "tablet",
+            ClientCategory::Computer => "computer",
+            ClientCategory::Laptop => "laptop",
+            ClientCategory::Tv => "tv",
+            ClientCategory::Console => "console",
+            ClientCategory::Speaker => "speaker",
+            ClientCategory::Camera => "camera",
+            ClientCategory::Printer => "printer",
+            ClientCategory::Server => "server",
+            ClientCategory::Router => "router",
+            ClientCategory::Iot => "iot",
+            ClientCategory::Other => "other",
+        }
+    }
+}
+
+impl std::str::FromStr for ClientCategory {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "phone" => Ok(ClientCategory::Phone),
+            "tablet" => Ok(ClientCategory::Tablet),
+            "computer" => Ok(ClientCategory::Computer),
+            "laptop" => Ok(ClientCategory::Laptop),
+            "tv" => Ok(ClientCategory::Tv),
+            "console" => Ok(ClientCategory::Console),
+            "speaker" => Ok(ClientCategory::Speaker),
+            "camera" => Ok(ClientCategory::Camera),
+            "printer" => Ok(ClientCategory::Printer),
+            "server" => Ok(ClientCategory::Server),
+            "router" => Ok(ClientCategory::Router),
+            "iot" => Ok(ClientCategory::Iot),
+            "other" => Ok(ClientCategory::Other),
+            _ => Err(()),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Client {
     pub id: Option<i64>,
@@ -17,9 +84,21 @@ pub struct Client {
     pub last_mac_update: Option<i64>,
     pub last_hostname_update: Option<i64>,
     pub group_id: Option<i64>,
+    pub display_name: Option<Arc<str>>,
+    pub category: Option<ClientCategory>,
+    pub notes: Option<Arc<str>>,
 }
 
 impl Client {
+    /// Fields accepted by `PageRequest::sorted_by` for client listings.
+    pub const SORT_FIELDS: &'static [&'static str] = &[
+        "ip_address",
+        "hostname",
+        "first_seen",
+        "last_seen",
+        "query_count",
+    ];
+
     pub fn new(ip_address: IpAddr) -> Self {
         Self {
             id: None,
@@ -32,6 +111,9 @@ impl Client {
             last_mac_update: None,
             last_hostname_update: None,
             group_id: None,
+            display_name: None,
+            category: None,
+            notes: None,
         }
     }
 
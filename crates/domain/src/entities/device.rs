@@ -0,0 +1,59 @@
+use std::net::IpAddr;
+use std::sync::Arc;
+
+/// A physical device identified by its MAC address. A device survives DHCP
+/// renumbering, while `Client` rows are keyed by IP.
+#[derive(Debug, Clone)]
+pub struct Device {
+    pub id: Option<i64>,
+    pub mac_address: Arc<str>,
+    pub current_ip: Option<IpAddr>,
+    pub first_seen: Option<String>,
+    pub last_seen: Option<String>,
+}
+
+/// One IP address a device has held, with the window it was observed in.
+#[derive(Debug, Clone)]
+pub struct DeviceIpHistory {
+    pub ip_address: IpAddr,
+    pub first_seen: String,
+    pub last_seen: String,
+}
+
+/// Client rows that share a MAC address, ordered most recently seen first.
+#[derive(Debug, Clone)]
+pub struct DuplicateClients {
+    pub mac_address: Arc<str>,
+    pub client_ids: Vec<i64>,
+}
+
+impl Device {
+    /// Normalizes a MAC address to lowercase colon-separated form. Returns
+    /// `None` for malformed, all-zero or broadcast addresses, which ARP uses
+    /// for incomplete entries and must never be used to merge clients.
+    pub fn normalize_mac(mac: &str) -> Option<String> {
+        let octets: Vec<u8> = mac
+            .split([':', '-'])
+            .map(|part| {
+                if part.len() == 2 {
+                    u8::from_str_radix(part, 16).ok()
+                } else {
+                    None
+                }
+            })
+            .collect::<Option<_>>()?;
+
+        if octets.len() != 6 || octets.iter().all(|&b| b == 0) || octets.iter().all(|&b| b == 0xff)
+        {
+            return None;
+        }
+
+        Some(
+            octets
+                .iter()
+                .map(|b| format!("{b:02x}"))
+                .collect::<Vec<_>>()
+                .join(":"),
+        )
+    }
+}
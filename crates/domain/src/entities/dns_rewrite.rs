@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+/// A user-defined answer for a domain, evaluated before the cache and
+/// upstream layers. `answer` is either an IP address (answered directly for
+/// A/AAAA queries) or a hostname (resolved in place of the queried name).
+#[derive(Debug, Clone)]
+pub struct DnsRewrite {
+    pub id: Option<i64>,
+    pub domain: Arc<str>,
+    pub answer: Arc<str>,
+    pub enabled: bool,
+    pub comment: Option<Arc<str>>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+impl DnsRewrite {
+    pub fn new(domain: Arc<str>, answer: Arc<str>) -> Self {
+        Self {
+            id: None,
+            domain,
+            answer,
+            enabled: true,
+            comment: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        Self::validate_domain(&self.domain)?;
+        Self::validate_answer(&self.answer)?;
+        if let RewriteAnswer::Cname(target) = RewriteAnswer::parse(&self.answer) {
+            let domain = self.domain.trim_end_matches('.');
+            if target.eq_ignore_ascii_case(domain) {
+                return Err(format!("Rewrite for '{}' cannot point to itself", domain));
+            }
+        }
+        if let Some(c) = &self.comment {
+            if c.len() > 500 {
+                return Err("Comment cannot exceed 500 characters".to_string());
+            }
+        }
+        Ok(())
+    }
+
+    /// Accepts an exact name (`nas.lan`) or a leading wildcard
+    /// (`*.example.com`, which matches subdomains but not the apex).
+    pub fn validate_domain(domain: &str) -> Result<(), String> {
+        let base = domain.strip_prefix("*.").unwrap_or(domain);
+        if base.is_empty() || base.len() > 253 {
+            return Err(format!("Invalid rewrite domain '{}'", domain));
+        }
+        if base.contains('*') || base.starts_with('.') || base.ends_with('.') || base.contains("..")
+        {
+            return Err(format!(
+                "Invalid rewrite domain '{}': only a leading '*.' wildcard is supported",
+                domain
+            ));
+        }
+        if !base
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        {
+            return Err(format!(
+                "Invalid rewrite domain '{}': contains invalid characters",
+                domain
+            ));
+        }
+        Ok(())
+    }
+
+    /// Accepts an IPv4/IPv6 address or a hostname to use as a CNAME target.
+    pub fn validate_answer(answer: &str) -> Result<(), String> {
+        if answer.parse::<IpAddr>().is_ok() {
+            return Ok(());
+        }
+        let name = answer.trim_end_matches('.');
+        if name.is_empty() || name.len() > 253 {
+            return Err(format!("Invalid rewrite answer '{}'", answer));
+        }
+        let valid = name.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && label
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_'))
+        });
+        if !valid {
+            return Err(format!(
+                "Invalid rewrite answer '{}': expected an IP address or a hostname",
+                answer
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Parsed form of a rewrite answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RewriteAnswer {
+    Address(IpAddr),
+    /// Lowercase hostname without a trailing dot.
+    Cname(Arc<str>),
+}
+
+impl RewriteAnswer {
+    pub fn parse(answer: &str) -> Self {
+        match answer.parse::<IpAddr>() {
+            Ok(ip) => RewriteAnswer::Address(ip),
+            Err(_) => {
+                RewriteAnswer::Cname(Arc::from(answer.trim_end_matches('.').to_ascii_lowercase()))
+            }
+        }
+    }
+}
+
+/// What a matching rewrite answers with. A CNAME rewrite takes precedence
+/// over address rewrites for the same domain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RewriteTarget {
+    Cname(Arc<str>),
+    Addresses(Arc<[IpAddr]>),
+}
+
+impl RewriteTarget {
+    fn from_answers(answers: Vec<RewriteAnswer>) -> Self {
+        let mut addresses = Vec::with_capacity(answers.len());
+        for answer in answers {
+            match answer {
+                RewriteAnswer::Cname(target) => return RewriteTarget::Cname(target),
+                RewriteAnswer::Address(ip) => {
+                    if !addresses.contains(&ip) {
+                        addresses.push(ip);
+                    }
+                }
+            }
+        }
+        RewriteTarget::Addresses(Arc::from(addresses))
+    }
+}
+
+/// Read-optimised form of the enabled rewrites. Exact names win over
+/// wildcards, and among wildcards the longest suffix wins.
+pub struct DnsRewriteMatcher {
+    exact: HashMap<Box<str>, RewriteTarget>,
+    /// Suffixes stored with their leading dot, longest first.
+    wildcards: Vec<(Box<str>, RewriteTarget)>,
+}
+
+impl DnsRewriteMatcher {
+    pub fn empty() -> Self {
+        Self {
+            exact: HashMap::new(),
+            wildcards: Vec::new(),
+        }
+    }
+
+    pub fn new(mut rewrites: Vec<DnsRewrite>) -> Self {
+        rewrites.retain(|r| r.enabled);
+        rewrites.sort_by_key(|r| r.id.unwrap_or(i64::MAX));
+
+        let mut exact: HashMap<Box<str>, Vec<RewriteAnswer>> = HashMap::new();
+        let mut wildcards: HashMap<Box<str>, Vec<RewriteAnswer>> = HashMap::new();
+        for rewrite in rewrites {
+            let domain = rewrite.domain.trim_end_matches('.').to_ascii_lowercase();
+            let answer = RewriteAnswer::parse(&rewrite.answer);
+            match domain.strip_prefix('*') {
+                Some(suffix) => wildcards.entry(Box::from(suffix)).or_default().push(answer),
+                None => exact.entry(Box::from(domain)).or_default().push(answer),
+            }
+        }
+
+        let exact = exact
+            .into_iter()
+            .map(|(domain, answers)| (domain, RewriteTarget::from_answers(answers)))
+            .collect();
+        let mut wildcards: Vec<_> = wildcards
+            .into_iter()
+            .map(|(suffix, answers)| (suffix, RewriteTarget::from_answers(answers)))
+            .collect();
+        wildcards.sort_by(|a, b| b.0.len().cmp(&a.0.len()).then_with(|| a.0.cmp(&b.0)));
+
+        Self { exact, wildcards }
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.exact.is_empty() && self.wildcards.is_empty()
+    }
+
+    pub fn lookup(&self, domain: &str) -> Option<RewriteTarget> {
+        if self.is_empty() {
+            return None;
+        }
+        let domain = domain.trim_end_matches('.');
+        let found = if domain.bytes().any(|b| b.is_ascii_uppercase()) {
+            self.exact.get(domain.to_ascii_lowercase().as_str())
+        } else {
+            self.exact.get(domain)
+        };
+        if let Some(target) = found {
+            return Some(target.clone());
+        }
+        self.wildcards
+            .iter()
+            .find(|(suffix, _)| {
+                domain.len() > suffix.len()
+                    && domain.is_char_boundary(domain.len() - suffix.len())
+                    && domain[domain.len() - suffix.len()..].eq_ignore_ascii_case(suffix)
+            })
+            .map(|(_, target)| target.clone())
+    }
+}
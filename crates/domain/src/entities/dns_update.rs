@@ -0,0 +1,135 @@
+use crate::dns_record::RecordType;
+use crate::errors::dns_rcode::DnsRcode;
+use std::fmt;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+/// A dynamic update (RFC 2136) for one zone. Names are lowercase FQDNs
+/// without the trailing dot, and record data is in the presentation form
+/// of [`LocalRecord::value`](crate::LocalRecord).
+#[derive(Debug, Clone)]
+pub struct DnsUpdate {
+    pub zone: Arc<str>,
+    pub client_ip: IpAddr,
+    /// TSIG key the update was signed with, once the signature is verified.
+    pub key_name: Option<Arc<str>>,
+    pub prerequisites: Vec<UpdatePrerequisite>,
+    pub changes: Vec<UpdateChange>,
+}
+
+/// A condition the zone must meet before the changes are applied
+/// (RFC 2136 §2.4).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UpdatePrerequisite {
+    NameInUse(Arc<str>),
+    NameNotInUse(Arc<str>),
+    RrsetExists {
+        name: Arc<str>,
+        record_type: RecordType,
+    },
+    RrsetDoesNotExist {
+        name: Arc<str>,
+        record_type: RecordType,
+    },
+    /// The RRset holds exactly `values`.
+    RrsetEquals {
+        name: Arc<str>,
+        record_type: RecordType,
+        values: Vec<Arc<str>>,
+    },
+}
+
+impl UpdatePrerequisite {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::NameInUse(name) | Self::NameNotInUse(name) => name,
+            Self::RrsetExists { name, .. }
+            | Self::RrsetDoesNotExist { name, .. }
+            | Self::RrsetEquals { name, .. } => name,
+        }
+    }
+}
+
+/// One change of the update section (RFC 2136 §2.5).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UpdateChange {
+    Add {
+        name: Arc<str>,
+        record_type: RecordType,
+        value: Arc<str>,
+        ttl: u32,
+    },
+    /// Deletes every record owned by the name.
+    DeleteName(Arc<str>),
+    DeleteRrset {
+        name: Arc<str>,
+        record_type: RecordType,
+    },
+    DeleteRecord {
+        name: Arc<str>,
+        record_type: RecordType,
+        value: Arc<str>,
+    },
+}
+
+impl UpdateChange {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::DeleteName(name) => name,
+            Self::Add { name, .. }
+            | Self::DeleteRrset { name, .. }
+            | Self::DeleteRecord { name, .. } => name,
+        }
+    }
+}
+
+impl fmt::Display for UpdateChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Add {
+                name,
+                record_type,
+                value,
+                ttl,
+            } => write!(f, "add {} {} {} {}", name, ttl, record_type, value),
+            Self::DeleteName(name) => write!(f, "delete {}", name),
+            Self::DeleteRrset { name, record_type } => {
+                write!(f, "delete {} {}", name, record_type)
+            }
+            Self::DeleteRecord {
+                name,
+                record_type,
+                value,
+            } => write!(f, "delete {} {} {}", name, record_type, value),
+        }
+    }
+}
+
+/// What became of a dynamic update.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DnsUpdateOutcome {
+    /// The prerequisites held and the changes were applied. A change that
+    /// had nothing to do, such as adding an existing record, counts for
+    /// neither total.
+    Applied { added: usize, removed: usize },
+    /// The update was refused or failed; `rcode` is the answer to the
+    /// client. Only a storage failure can leave some changes applied.
+    Rejected { rcode: DnsRcode, reason: String },
+}
+
+impl DnsUpdateOutcome {
+    pub fn rejected(rcode: DnsRcode, reason: impl Into<String>) -> Self {
+        Self::Rejected {
+            rcode,
+            reason: reason.into(),
+        }
+    }
+
+    /// RCODE name for logs and the audit log.
+    pub fn rcode_str(&self) -> &'static str {
+        match self {
+            Self::Applied { .. } => "NOERROR",
+            Self::Rejected { rcode, .. } => rcode.as_str(),
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use crate::RecordType;
+
+/// Validations per DNSSEC outcome over some interval.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DnssecValidationCounts {
+    pub secure: u64,
+    pub insecure: u64,
+    pub bogus: u64,
+    pub indeterminate: u64,
+}
+
+impl DnssecValidationCounts {
+    /// Counts one validation with the given status; unknown statuses are
+    /// ignored.
+    pub fn record(&mut self, status: &str) {
+        match status {
+            "Secure" => self.secure += 1,
+            "Insecure" => self.insecure += 1,
+            "Bogus" => self.bogus += 1,
+            "Indeterminate" => self.indeterminate += 1,
+            _ => {}
+        }
+    }
+
+    pub fn add(&mut self, other: &Self) {
+        self.secure += other.secure;
+        self.insecure += other.insecure;
+        self.bogus += other.bogus;
+        self.indeterminate += other.indeterminate;
+    }
+
+    pub fn total(&self) -> u64 {
+        self.secure + self.insecure + self.bogus + self.indeterminate
+    }
+}
+
+/// Outcome counts of one history interval, persisted in `dnssec_stats`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DnssecStatsSample {
+    pub counts: DnssecValidationCounts,
+    /// End of the interval the counts cover.
+    pub recorded_at: String,
+}
+
+/// A validation whose outcome differed from the previous one for the same
+/// name and type, persisted in `dnssec_status_changes`. The first
+/// validation of a name is recorded only when it is `Bogus`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DnssecStatusChange {
+    pub id: Option<i64>,
+    pub domain: String,
+    pub record_type: RecordType,
+    /// `Secure`, `Insecure`, `Bogus` or `Indeterminate`.
+    pub status: &'static str,
+    pub previous_status: Option<&'static str>,
+    pub observed_at: String,
+}
@@ -8,11 +8,22 @@ pub struct Group {
     pub enabled: bool,
     pub comment: Option<Arc<str>>,
     pub is_default: bool,
+    /// Answer AAAA queries from this group's clients with NODATA, for
+    /// networks whose IPv6 connectivity is broken.
+    pub filter_aaaa: bool,
+    /// Blocklist matches for this group's clients are logged as "would
+    /// block" instead of being blocked.
+    pub audit_mode: bool,
+    /// Tenant owning the group; `None` for groups outside any tenant.
+    pub tenant_id: Option<i64>,
     pub created_at: Option<String>,
     pub updated_at: Option<String>,
 }
 
 impl Group {
+    /// Fields accepted by `PageRequest::sorted_by` for group listings.
+    pub const SORT_FIELDS: &'static [&'static str] = &["name", "created_at", "client_count"];
+
     pub fn new(
         id: Option<i64>,
         name: Arc<str>,
@@ -26,6 +37,9 @@ impl Group {
             enabled,
             comment,
             is_default,
+            filter_aaaa: false,
+            audit_mode: false,
+            tenant_id: None,
             created_at: None,
             updated_at: None,
         }
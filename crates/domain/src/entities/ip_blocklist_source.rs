@@ -0,0 +1,51 @@
+use crate::value_objects::validators;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// A threat-intelligence feed of IPs and CIDR ranges checked against the
+/// A/AAAA records of every answer (see `[dns.response_ip_filter]`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IpBlocklistSource {
+    pub id: Option<i64>,
+    pub name: Arc<str>,
+    pub url: Arc<str>,
+    pub comment: Option<Arc<str>>,
+    pub enabled: bool,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+impl IpBlocklistSource {
+    pub fn new(
+        id: Option<i64>,
+        name: Arc<str>,
+        url: Arc<str>,
+        comment: Option<Arc<str>>,
+        enabled: bool,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            url,
+            comment,
+            enabled,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    pub fn validate_name(name: &str) -> Result<(), String> {
+        validators::validate_source_name(name, "IP blocklist source")
+    }
+
+    pub fn validate_url(url: &str) -> Result<(), String> {
+        if url.is_empty() {
+            return Err("IP blocklist source URL cannot be empty".to_string());
+        }
+        validators::validate_url(&Some(Arc::from(url)))
+    }
+
+    pub fn validate_comment(comment: &Option<Arc<str>>) -> Result<(), String> {
+        validators::validate_comment(comment)
+    }
+}
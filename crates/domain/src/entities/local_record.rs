@@ -0,0 +1,424 @@
+use crate::config::LocalDnsRecord;
+use crate::dns_record::RecordType;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::sync::Arc;
+
+/// TTL used when a record does not set one.
+pub const DEFAULT_LOCAL_RECORD_TTL: u32 = 300;
+
+/// Largest TTL accepted for a local record (one week).
+pub const MAX_LOCAL_RECORD_TTL: u32 = 604_800;
+
+/// A/AAAA value that answers with the address embedded in the queried name.
+/// Only allowed on wildcard records.
+pub const EMBEDDED_IP_TEMPLATE: &str = "{ip}";
+
+/// Record types that can be stored in the local zone.
+pub const LOCAL_RECORD_TYPES: [RecordType; 6] = [
+    RecordType::A,
+    RecordType::AAAA,
+    RecordType::CNAME,
+    RecordType::TXT,
+    RecordType::MX,
+    RecordType::SRV,
+];
+
+/// A record of the local zone, managed through the API and answered before
+/// the cache and upstream layers. `value` holds the record data in its
+/// presentation form: an address for A/AAAA, a hostname for CNAME, free
+/// text for TXT, `<preference> <exchange>` for MX and
+/// `<priority> <weight> <port> <target>` for SRV.
+///
+/// A hostname of `*` or starting with `*.` makes the record a wildcard that
+/// answers every name below it which has no record of its own.
+#[derive(Debug, Clone)]
+pub struct LocalRecord {
+    pub id: Option<i64>,
+    pub hostname: Arc<str>,
+    pub domain: Option<Arc<str>>,
+    pub record_type: RecordType,
+    pub value: Arc<str>,
+    pub ttl: u32,
+    /// Split-horizon view this record belongs to. Only A/AAAA records can
+    /// be tagged; they are then answered by the view instead of the zone.
+    pub view: Option<Arc<str>>,
+    /// Tenant owning the record. Tenant records are only answered to the
+    /// tenant's clients; `None` records are answered to everyone.
+    pub tenant_id: Option<i64>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+impl LocalRecord {
+    pub fn new(hostname: Arc<str>, record_type: RecordType, value: Arc<str>) -> Self {
+        Self {
+            id: None,
+            hostname,
+            domain: None,
+            record_type,
+            value,
+            ttl: DEFAULT_LOCAL_RECORD_TTL,
+            view: None,
+            tenant_id: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    /// Fully qualified name in lowercase, without a trailing dot. Falls back
+    /// to `default_domain` when the record has no domain of its own.
+    pub fn fqdn(&self, default_domain: &Option<String>) -> String {
+        let hostname = self.hostname.trim_end_matches('.');
+        let fqdn = match (self.domain.as_deref(), default_domain.as_deref()) {
+            (Some(domain), _) | (None, Some(domain)) => {
+                format!("{}.{}", hostname, domain.trim_matches('.'))
+            }
+            (None, None) => hostname.to_string(),
+        };
+        fqdn.to_ascii_lowercase()
+    }
+
+    pub fn is_wildcard(&self) -> bool {
+        self.hostname.as_ref() == "*" || self.hostname.starts_with("*.")
+    }
+
+    /// Address to publish a PTR record for: set for A/AAAA records with a
+    /// fixed address, never for wildcards or tenant records, as the PTR
+    /// registry is shared by every client.
+    pub fn ptr_address(&self) -> Option<IpAddr> {
+        match self.data() {
+            Ok(LocalRecordData::Address(ip)) if !self.is_wildcard() && self.tenant_id.is_none() => {
+                Some(ip)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn data(&self) -> Result<LocalRecordData, String> {
+        LocalRecordData::parse(self.record_type, &self.value)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        let name = match self.hostname.as_ref() {
+            "*" => None,
+            hostname => Some(hostname.strip_prefix("*.").unwrap_or(hostname)),
+        };
+        if let Some(name) = name {
+            validate_name(name)
+                .map_err(|e| format!("Invalid hostname '{}': {}", self.hostname, e))?;
+        }
+        if let Some(domain) = &self.domain {
+            validate_name(domain.trim_matches('.'))
+                .map_err(|e| format!("Invalid domain '{}': {}", domain, e))?;
+        }
+        let data = self.data()?;
+        if matches!(data, LocalRecordData::EmbeddedAddress { .. }) && !self.is_wildcard() {
+            return Err(format!(
+                "The {} template is only allowed on wildcard records",
+                EMBEDDED_IP_TEMPLATE
+            ));
+        }
+        if self.ttl > MAX_LOCAL_RECORD_TTL {
+            return Err(format!(
+                "TTL cannot exceed {} seconds",
+                MAX_LOCAL_RECORD_TTL
+            ));
+        }
+        if self.view.is_some() && !matches!(self.record_type, RecordType::A | RecordType::AAAA) {
+            return Err("Only A and AAAA records can belong to a split-horizon view".to_string());
+        }
+        if self.view.is_some() && self.is_wildcard() {
+            return Err("Wildcard records cannot belong to a split-horizon view".to_string());
+        }
+        if self.view.is_some() && self.tenant_id.is_some() {
+            return Err("Tenant records cannot belong to a split-horizon view".to_string());
+        }
+        Ok(())
+    }
+
+    /// The record in the shape used by `[[dns.local_records]]`, so that
+    /// view records can be compiled into the split-horizon views.
+    pub fn to_config_record(&self) -> LocalDnsRecord {
+        LocalDnsRecord {
+            hostname: self.hostname.to_string(),
+            domain: self.domain.as_deref().map(String::from),
+            ip: self.value.to_string(),
+            record_type: self.record_type.as_str().to_string(),
+            ttl: Some(self.ttl),
+            view: self.view.as_deref().map(String::from),
+        }
+    }
+}
+
+/// Parsed record data of a [`LocalRecord`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LocalRecordData {
+    Address(IpAddr),
+    /// The `{ip}` template: the address is decoded from the queried name.
+    EmbeddedAddress {
+        ipv6: bool,
+    },
+    /// Lowercase hostname without a trailing dot.
+    Cname(Arc<str>),
+    Txt(Arc<str>),
+    Mx {
+        preference: u16,
+        exchange: Arc<str>,
+    },
+    Srv {
+        priority: u16,
+        weight: u16,
+        port: u16,
+        target: Arc<str>,
+    },
+}
+
+impl LocalRecordData {
+    pub fn parse(record_type: RecordType, value: &str) -> Result<Self, String> {
+        let value = value.trim();
+        if value == EMBEDDED_IP_TEMPLATE && matches!(record_type, RecordType::A | RecordType::AAAA)
+        {
+            return Ok(Self::EmbeddedAddress {
+                ipv6: record_type == RecordType::AAAA,
+            });
+        }
+        match record_type {
+            RecordType::A => match value.parse::<IpAddr>() {
+                Ok(ip @ IpAddr::V4(_)) => Ok(Self::Address(ip)),
+                _ => Err(format!("A record value '{}' is not an IPv4 address", value)),
+            },
+            RecordType::AAAA => match value.parse::<IpAddr>() {
+                Ok(ip @ IpAddr::V6(_)) => Ok(Self::Address(ip)),
+                _ => Err(format!(
+                    "AAAA record value '{}' is not an IPv6 address",
+                    value
+                )),
+            },
+            RecordType::CNAME => parse_target(value).map(Self::Cname),
+            RecordType::TXT => {
+                if value.is_empty() || value.len() > 4000 {
+                    return Err("TXT record value must be 1 to 4000 bytes".to_string());
+                }
+                Ok(Self::Txt(Arc::from(value)))
+            }
+            RecordType::MX => {
+                let fields: Vec<&str> = value.split_whitespace().collect();
+                let [preference, exchange] = fields[..] else {
+                    return Err(format!(
+                        "MX record value '{}' must be '<preference> <exchange>'",
+                        value
+                    ));
+                };
+                Ok(Self::Mx {
+                    preference: parse_u16(preference, "MX preference")?,
+                    exchange: parse_target(exchange)?,
+                })
+            }
+            RecordType::SRV => {
+                let fields: Vec<&str> = value.split_whitespace().collect();
+                let [priority, weight, port, target] = fields[..] else {
+                    return Err(format!(
+                        "SRV record value '{}' must be '<priority> <weight> <port> <target>'",
+                        value
+                    ));
+                };
+                Ok(Self::Srv {
+                    priority: parse_u16(priority, "SRV priority")?,
+                    weight: parse_u16(weight, "SRV weight")?,
+                    port: parse_u16(port, "SRV port")?,
+                    target: parse_target(target)?,
+                })
+            }
+            other => Err(format!(
+                "Unsupported record type {} (must be one of A, AAAA, CNAME, TXT, MX, SRV)",
+                other
+            )),
+        }
+    }
+
+    pub fn record_type(&self) -> RecordType {
+        match self {
+            Self::Address(IpAddr::V4(_)) => RecordType::A,
+            Self::Address(IpAddr::V6(_)) => RecordType::AAAA,
+            Self::EmbeddedAddress { ipv6: false } => RecordType::A,
+            Self::EmbeddedAddress { ipv6: true } => RecordType::AAAA,
+            Self::Cname(_) => RecordType::CNAME,
+            Self::Txt(_) => RecordType::TXT,
+            Self::Mx { .. } => RecordType::MX,
+            Self::Srv { .. } => RecordType::SRV,
+        }
+    }
+}
+
+/// Accepts dot-separated labels of letters, digits, `-` and `_` (the latter
+/// for SRV owner names such as `_sip._tcp`).
+fn validate_name(name: &str) -> Result<(), &'static str> {
+    if name.is_empty() || name.len() > 253 {
+        return Err("must be 1 to 253 characters");
+    }
+    let valid = name.split('.').all(|label| {
+        !label.is_empty()
+            && label.len() <= 63
+            && label
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_'))
+    });
+    if !valid {
+        return Err("expected dot-separated labels of letters, digits, '-' or '_'");
+    }
+    Ok(())
+}
+
+fn parse_target(value: &str) -> Result<Arc<str>, String> {
+    let name = value.trim_end_matches('.');
+    validate_name(name).map_err(|e| format!("Invalid target '{}': {}", value, e))?;
+    Ok(Arc::from(name.to_ascii_lowercase()))
+}
+
+fn parse_u16(value: &str, field: &str) -> Result<u16, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{} '{}' must be a number from 0 to 65535", field, value))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalZoneEntry {
+    pub data: LocalRecordData,
+    pub ttl: u32,
+}
+
+/// Read-optimised form of the local records, keyed by lowercase FQDN.
+/// Wildcard records are keyed by the name below the `*` label. View
+/// records are left out; the split-horizon views answer them.
+pub struct LocalZone {
+    names: HashMap<Box<str>, Vec<LocalZoneEntry>>,
+    wildcards: HashMap<Box<str>, Vec<LocalZoneEntry>>,
+}
+
+impl LocalZone {
+    pub fn empty() -> Self {
+        Self {
+            names: HashMap::new(),
+            wildcards: HashMap::new(),
+        }
+    }
+
+    /// Builds the zone, skipping view records, records whose value does not
+    /// parse and wildcards with no name below them.
+    pub fn new(records: &[LocalRecord], default_domain: &Option<String>) -> Self {
+        let mut names: HashMap<Box<str>, Vec<LocalZoneEntry>> = HashMap::new();
+        let mut wildcards: HashMap<Box<str>, Vec<LocalZoneEntry>> = HashMap::new();
+        for record in records.iter().filter(|r| r.view.is_none()) {
+            let Ok(data) = record.data() else {
+                continue;
+            };
+            let fqdn = record.fqdn(default_domain);
+            let entries = match fqdn.strip_prefix("*.") {
+                Some(parent) => wildcards.entry(Box::from(parent)).or_default(),
+                None if fqdn == "*" => continue,
+                None => names.entry(Box::from(fqdn)).or_default(),
+            };
+            let entry = LocalZoneEntry {
+                data,
+                ttl: record.ttl,
+            };
+            if !entries.contains(&entry) {
+                entries.push(entry);
+            }
+        }
+        Self { names, wildcards }
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty() && self.wildcards.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.names
+            .values()
+            .chain(self.wildcards.values())
+            .map(Vec::len)
+            .sum()
+    }
+
+    /// Returns every record stored for `domain`, whatever its type.
+    ///
+    /// A name with records of its own never matches a wildcard; otherwise
+    /// the closest wildcard above it answers. `{ip}` entries come back as
+    /// the address decoded from the labels below the wildcard, and are left
+    /// out when the name does not embed one.
+    pub fn lookup(&self, domain: &str) -> Option<Cow<'_, [LocalZoneEntry]>> {
+        if self.is_empty() {
+            return None;
+        }
+        let domain = domain.trim_end_matches('.');
+        let lowercase;
+        let domain = if domain.bytes().any(|b| b.is_ascii_uppercase()) {
+            lowercase = domain.to_ascii_lowercase();
+            lowercase.as_str()
+        } else {
+            domain
+        };
+        if let Some(entries) = self.names.get(domain) {
+            return Some(Cow::Borrowed(entries));
+        }
+        if self.wildcards.is_empty() {
+            return None;
+        }
+
+        let (prefix, entries) = domain
+            .match_indices('.')
+            .find_map(|(i, _)| Some((&domain[..i], self.wildcards.get(&domain[i + 1..])?)))?;
+        if !entries
+            .iter()
+            .any(|e| matches!(e.data, LocalRecordData::EmbeddedAddress { .. }))
+        {
+            return Some(Cow::Borrowed(entries));
+        }
+
+        let expanded: Vec<LocalZoneEntry> = entries
+            .iter()
+            .filter_map(|entry| match entry.data {
+                LocalRecordData::EmbeddedAddress { ipv6 } => Some(LocalZoneEntry {
+                    data: LocalRecordData::Address(embedded_ip(prefix, ipv6)?),
+                    ttl: entry.ttl,
+                }),
+                _ => Some(entry.clone()),
+            })
+            .collect();
+        (!expanded.is_empty()).then_some(Cow::Owned(expanded))
+    }
+}
+
+/// Decodes the address carried by the labels below a wildcard, in the forms
+/// used by services such as nip.io: `10.0.0.5`, `app.10.0.0.5` and
+/// `app-10-0-0-5` for IPv4, `fd00--1` and `app.2001-db8--1` for IPv6.
+fn embedded_ip(prefix: &str, ipv6: bool) -> Option<IpAddr> {
+    let last = prefix.rsplit('.').next()?;
+    if ipv6 {
+        return last
+            .replace('-', ":")
+            .parse::<Ipv6Addr>()
+            .ok()
+            .map(IpAddr::V6);
+    }
+
+    let dotted: Vec<&str> = prefix.rsplit('.').take(4).collect();
+    if dotted.len() == 4 {
+        let octets: Vec<&str> = dotted.into_iter().rev().collect();
+        if let Ok(ip) = octets.join(".").parse::<Ipv4Addr>() {
+            return Some(IpAddr::V4(ip));
+        }
+    }
+    let dashed: Vec<&str> = last.rsplit('-').take(4).collect();
+    if dashed.len() == 4 {
+        let octets: Vec<&str> = dashed.into_iter().rev().collect();
+        if let Ok(ip) = octets.join(".").parse::<Ipv4Addr>() {
+            return Some(IpAddr::V4(ip));
+        }
+    }
+    None
+}
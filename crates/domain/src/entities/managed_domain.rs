@@ -44,6 +44,16 @@ pub struct ManagedDomain {
 }
 
 impl ManagedDomain {
+    /// Fields accepted by `PageRequest::sorted_by` for managed domain listings.
+    pub const SORT_FIELDS: &'static [&'static str] = &[
+        "name",
+        "domain",
+        "action",
+        "group_id",
+        "enabled",
+        "created_at",
+    ];
+
     pub fn new(
         id: Option<i64>,
         name: Arc<str>,
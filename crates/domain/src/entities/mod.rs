@@ -1,19 +1,38 @@
+pub mod alert;
 pub mod api_token;
+pub mod audit_event;
 pub mod auth_session;
 pub mod block_source;
 pub mod blocked_service;
 pub mod blocklist;
 pub mod blocklist_source;
+pub mod cache_ttl_override;
 pub mod client;
 pub mod client_subnet;
 pub mod custom_service;
+pub mod device;
+pub mod dns_rewrite;
+pub mod dns_update;
+pub mod dnssec_history;
 pub mod group;
+pub mod ip_blocklist_source;
+pub mod local_record;
 pub mod managed_domain;
+pub mod notification;
 pub mod query_log;
+pub mod query_policy;
+pub mod record_type_policy;
 pub mod regex_filter;
+pub mod report;
 pub mod safe_search;
 pub mod schedule;
+pub mod secondary_zone;
 pub mod service_catalog;
+pub mod split_horizon;
+pub mod tenant;
+pub mod tld_policy;
+pub mod unblock_request;
 pub mod user;
 pub mod whitelist;
 pub mod whitelist_source;
+pub mod zone_file;
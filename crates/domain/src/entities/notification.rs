@@ -0,0 +1,58 @@
+use serde::Serialize;
+
+/// Operational events that can raise a notification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationKind {
+    /// A pool has unhealthy or partially reachable servers.
+    UpstreamDegraded,
+    /// Reloading blocklist sources failed.
+    BlocklistUpdateFailed,
+    /// The filesystem holding the database crossed its usage threshold.
+    DiskNearlyFull,
+    /// A client's blocked queries crossed the spike threshold.
+    ClientBlockSpike,
+    /// Upstream answers failed DNSSEC validation.
+    DnssecBogus,
+}
+
+impl NotificationKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::UpstreamDegraded => "upstream_degraded",
+            Self::BlocklistUpdateFailed => "blocklist_update_failed",
+            Self::DiskNearlyFull => "disk_nearly_full",
+            Self::ClientBlockSpike => "client_block_spike",
+            Self::DnssecBogus => "dnssec_bogus",
+        }
+    }
+}
+
+/// One alert published on the notification bus.
+#[derive(Debug, Clone, Serialize)]
+pub struct Notification {
+    pub event: NotificationKind,
+    /// What the event is about (pool name, client IP, ...); alerts are
+    /// rate-limited per event and subject.
+    pub subject: String,
+    pub title: String,
+    pub message: String,
+    pub timestamp: String,
+}
+
+impl Notification {
+    pub fn new(
+        event: NotificationKind,
+        subject: impl Into<String>,
+        title: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            event,
+            subject: subject.into(),
+            title: title.into(),
+            message: message.into(),
+            timestamp: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use super::block_source::BlockSource;
 use crate::dns_record::RecordType;
+use chrono::{DateTime, Utc};
 use std::collections::HashMap;
 use std::net::IpAddr;
 use std::str::FromStr;
@@ -21,6 +22,28 @@ pub struct QueryLogFilter {
     pub record_type: Option<RecordType>,
     /// Exact match on upstream server address.
     pub upstream: Option<String>,
+    /// Only entries logged at or after this instant; replaces the period cutoff.
+    pub from: Option<DateTime<Utc>>,
+    /// Only entries logged strictly before this instant.
+    pub to: Option<DateTime<Utc>>,
+    /// Blocked (`true`) or allowed (`false`) queries.
+    pub blocked: Option<bool>,
+    /// Exact match on DNSSEC validation status (`Secure`, `Bogus`, ...).
+    pub dnssec_status: Option<&'static str>,
+    /// Query origin; `None` lists client queries only.
+    pub query_source: Option<QuerySource>,
+}
+
+/// DNSSEC statuses recorded in the query log.
+pub const DNSSEC_STATUSES: [&str; 5] = ["Secure", "Insecure", "Bogus", "Indeterminate", "Unknown"];
+
+/// Maps a case-insensitive DNSSEC status name to its canonical logged form.
+pub fn parse_dnssec_status(s: &str) -> Result<&'static str, String> {
+    DNSSEC_STATUSES
+        .iter()
+        .find(|status| status.eq_ignore_ascii_case(s))
+        .copied()
+        .ok_or_else(|| format!("invalid dnssec status: '{s}'"))
 }
 
 /// Category filter for query log pagination.
@@ -32,6 +55,8 @@ pub enum QueryCategory {
     Upstream,
     RateLimited,
     Malware,
+    /// Allowed queries that a blocklist in audit mode would have blocked.
+    Audited,
 }
 
 impl FromStr for QueryCategory {
@@ -45,6 +70,7 @@ impl FromStr for QueryCategory {
             "upstream" => Ok(Self::Upstream),
             "rate-limited" => Ok(Self::RateLimited),
             "malware" => Ok(Self::Malware),
+            "audited" => Ok(Self::Audited),
             other => Err(format!("invalid query category: '{other}'")),
         }
     }
@@ -113,6 +139,8 @@ pub struct QueryLog {
     pub record_type: RecordType,
     pub client_ip: IpAddr,
     pub client_hostname: Option<Arc<str>>,
+    /// MAC address of the client, joined from the client list on read.
+    pub client_mac: Option<Arc<str>>,
     pub blocked: bool,
     pub response_time_us: Option<u64>,
     pub cache_hit: bool,
@@ -120,13 +148,26 @@ pub struct QueryLog {
     pub dnssec_status: Option<&'static str>,
     pub upstream_server: Option<Arc<str>>,
     pub upstream_pool: Option<Arc<str>>,
+    /// Load-balancing strategy of the pool that answered (`parallel`,
+    /// `balanced` or `failover`).
+    pub upstream_strategy: Option<&'static str>,
+    /// 1-based position of the answering server among the servers the
+    /// strategy tried in that pool.
+    pub upstream_attempt: Option<u32>,
+    /// Transport actually used for the answer (`UDP`, `TCP`, `TLS`, ...),
+    /// which differs from the configured one after a truncation retry.
+    pub upstream_protocol: Option<&'static str>,
     pub response_status: Option<&'static str>,
     pub timestamp: Option<String>,
 
     pub query_source: QuerySource,
 
     pub group_id: Option<i64>,
+    /// Name of `group_id`, joined on read.
+    pub group_name: Option<Arc<str>>,
     pub block_source: Option<BlockSource>,
+    /// Plugin script that decided the query.
+    pub plugin: Option<Arc<str>>,
 }
 
 #[derive(Debug, Clone)]
@@ -134,6 +175,8 @@ pub struct QueryStats {
     pub queries_total: u64,
     pub queries_blocked: u64,
     pub queries_rate_limited: u64,
+    /// ANY queries answered with the minimal RFC 8482 response.
+    pub queries_any_minimized: u64,
     pub queries_malware_detected: u64,
     pub unique_clients: u64,
     pub uptime_seconds: u64,
@@ -141,15 +184,57 @@ pub struct QueryStats {
     pub avg_query_time_ms: f64,
     pub avg_cache_time_ms: f64,
     pub avg_upstream_time_ms: f64,
+    /// Response time percentiles over the window.
+    pub latency_p50_ms: f64,
+    pub latency_p95_ms: f64,
+    pub latency_p99_ms: f64,
 
     pub source_stats: HashMap<String, u64>,
 
     pub queries_by_type: HashMap<RecordType, u64>,
     pub most_queried_type: Option<RecordType>,
     pub record_type_distribution: Vec<(RecordType, f64)>,
+
+    /// Every group that sent queries in the window, busiest first.
+    pub queries_by_group: Vec<QueryCountBreakdown>,
+    /// Busiest clients in the window.
+    pub top_clients: Vec<QueryCountBreakdown>,
+}
+
+/// Query counts of one client or group within a stats window.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QueryCountBreakdown {
+    /// Client IP or group id.
+    pub key: String,
+    /// Client display name or hostname, or group name.
+    pub label: Option<String>,
+    pub queries_total: u64,
+    pub queries_blocked: u64,
+    pub cache_hits: u64,
+}
+
+impl QueryCountBreakdown {
+    pub fn blocked_percentage(&self) -> f64 {
+        percentage(self.queries_blocked, self.queries_total)
+    }
+
+    pub fn cache_hit_rate(&self) -> f64 {
+        percentage(self.cache_hits, self.queries_total)
+    }
+}
+
+fn percentage(part: u64, total: u64) -> f64 {
+    if total == 0 {
+        return 0.0;
+    }
+    (part as f64 / total as f64) * 100.0
 }
 
 impl QueryStats {
+    pub fn blocked_percentage(&self) -> f64 {
+        percentage(self.queries_blocked, self.queries_total)
+    }
+
     pub fn with_analytics(mut self, queries_by_type: HashMap<RecordType, u64>) -> Self {
         self.queries_by_type = queries_by_type;
 
@@ -212,6 +297,7 @@ impl Default for QueryStats {
             queries_total: 0,
             queries_blocked: 0,
             queries_rate_limited: 0,
+            queries_any_minimized: 0,
             queries_malware_detected: 0,
             unique_clients: 0,
             uptime_seconds: 0,
@@ -219,10 +305,15 @@ impl Default for QueryStats {
             avg_query_time_ms: 0.0,
             avg_cache_time_ms: 0.0,
             avg_upstream_time_ms: 0.0,
+            latency_p50_ms: 0.0,
+            latency_p95_ms: 0.0,
+            latency_p99_ms: 0.0,
             source_stats: HashMap::new(),
             queries_by_type: HashMap::new(),
             most_queried_type: None,
             record_type_distribution: Vec::new(),
+            queries_by_group: Vec::new(),
+            top_clients: Vec::new(),
         }
     }
 }
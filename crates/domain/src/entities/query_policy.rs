@@ -0,0 +1,363 @@
+use super::schedule::TimeSlot;
+use crate::dns_record::RecordType;
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+pub const DEFAULT_POLICY_PRIORITY: i64 = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PolicyAction {
+    Allow,
+    Block,
+    ForwardToPool,
+    Rewrite,
+    LogOnly,
+}
+
+impl PolicyAction {
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            PolicyAction::Allow => "allow",
+            PolicyAction::Block => "block",
+            PolicyAction::ForwardToPool => "forward_to_pool",
+            PolicyAction::Rewrite => "rewrite",
+            PolicyAction::LogOnly => "log_only",
+        }
+    }
+
+    /// Whether `action_target` is required (pool name or rewrite target).
+    pub fn needs_target(&self) -> bool {
+        matches!(self, PolicyAction::ForwardToPool | PolicyAction::Rewrite)
+    }
+}
+
+impl std::str::FromStr for PolicyAction {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "allow" => Ok(PolicyAction::Allow),
+            "block" => Ok(PolicyAction::Block),
+            "forward_to_pool" => Ok(PolicyAction::ForwardToPool),
+            "rewrite" => Ok(PolicyAction::Rewrite),
+            "log_only" => Ok(PolicyAction::LogOnly),
+            _ => Err(()),
+        }
+    }
+}
+
+/// An ordered rule evaluated before blocking. Every condition left unset
+/// matches any query; the first enabled rule (lowest `priority`, then
+/// lowest `id`) whose conditions all match decides the action.
+#[derive(Debug, Clone)]
+pub struct QueryPolicy {
+    pub id: Option<i64>,
+    pub name: Arc<str>,
+    pub priority: i64,
+    pub enabled: bool,
+    pub client_cidr: Option<Arc<str>>,
+    pub group_id: Option<i64>,
+    pub domain_pattern: Option<Arc<str>>,
+    pub record_types: Vec<RecordType>,
+    pub days: Option<u8>,
+    pub start_time: Option<Arc<str>>,
+    pub end_time: Option<Arc<str>>,
+    pub action: PolicyAction,
+    pub action_target: Option<Arc<str>>,
+    pub comment: Option<Arc<str>>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+impl QueryPolicy {
+    pub fn new(name: Arc<str>, action: PolicyAction) -> Self {
+        Self {
+            id: None,
+            name,
+            priority: DEFAULT_POLICY_PRIORITY,
+            enabled: true,
+            client_cidr: None,
+            group_id: None,
+            domain_pattern: None,
+            record_types: Vec::new(),
+            days: None,
+            start_time: None,
+            end_time: None,
+            action,
+            action_target: None,
+            comment: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        Self::validate_name(&self.name)?;
+        if let Some(cidr) = &self.client_cidr {
+            cidr.parse::<ipnetwork::IpNetwork>()
+                .map_err(|e| format!("Invalid client CIDR '{}': {}", cidr, e))?;
+        }
+        if let Some(pattern) = &self.domain_pattern {
+            Self::validate_domain_pattern(pattern)?;
+        }
+        if let Some(days) = self.days {
+            TimeSlot::validate_days(days)?;
+        }
+        match (&self.start_time, &self.end_time) {
+            (None, None) => {}
+            (Some(start), Some(end)) => {
+                TimeSlot::validate_time_format(start)?;
+                TimeSlot::validate_time_format(end)?;
+                TimeSlot::validate_time_range(start, end)?;
+            }
+            _ => return Err("start_time and end_time must be set together".to_string()),
+        }
+        match (&self.action_target, self.action.needs_target()) {
+            (None, true) => {
+                return Err(format!(
+                    "Action '{}' requires an action_target",
+                    self.action.to_str()
+                ))
+            }
+            (Some(_), false) => {
+                return Err(format!(
+                    "Action '{}' does not take an action_target",
+                    self.action.to_str()
+                ))
+            }
+            (Some(target), true) if target.is_empty() || target.len() > 253 => {
+                return Err("action_target must be 1–253 characters".to_string())
+            }
+            _ => {}
+        }
+        if let Some(c) = &self.comment {
+            if c.len() > 500 {
+                return Err("Comment cannot exceed 500 characters".to_string());
+            }
+        }
+        Ok(())
+    }
+
+    pub fn validate_name(name: &str) -> Result<(), String> {
+        if name.is_empty() {
+            return Err("Policy name cannot be empty".to_string());
+        }
+        if name.len() > 200 {
+            return Err("Policy name cannot exceed 200 characters".to_string());
+        }
+        Ok(())
+    }
+
+    /// Accepts an exact name (`example.com`) or a leading wildcard
+    /// (`*.example.com`, which matches subdomains but not the apex).
+    pub fn validate_domain_pattern(pattern: &str) -> Result<(), String> {
+        let base = pattern.strip_prefix("*.").unwrap_or(pattern);
+        if base.is_empty() || base.len() > 253 {
+            return Err(format!("Invalid domain pattern '{}'", pattern));
+        }
+        if base.contains('*') || base.starts_with('.') || base.ends_with('.') {
+            return Err(format!(
+                "Invalid domain pattern '{}': only a leading '*.' wildcard is supported",
+                pattern
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Outcome of evaluating the policy list against one query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolicyMatch {
+    pub policy_id: i64,
+    pub action: PolicyAction,
+    pub target: Option<Arc<str>>,
+}
+
+enum DomainPattern {
+    Any,
+    Exact(Box<str>),
+    /// Stored with its leading dot, e.g. `.example.com`.
+    Subdomain(Box<str>),
+}
+
+impl DomainPattern {
+    fn parse(pattern: Option<&str>) -> Self {
+        match pattern {
+            None => DomainPattern::Any,
+            Some(p) => {
+                let p = p.trim_end_matches('.').to_ascii_lowercase();
+                match p.strip_prefix('*') {
+                    Some(suffix) => DomainPattern::Subdomain(Box::from(suffix)),
+                    None => DomainPattern::Exact(Box::from(p.as_str())),
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn matches(&self, domain: &str) -> bool {
+        match self {
+            DomainPattern::Any => true,
+            DomainPattern::Exact(name) => domain.eq_ignore_ascii_case(name),
+            DomainPattern::Subdomain(suffix) => {
+                domain.len() > suffix.len()
+                    && domain.is_char_boundary(domain.len() - suffix.len())
+                    && domain[domain.len() - suffix.len()..].eq_ignore_ascii_case(suffix)
+            }
+        }
+    }
+}
+
+struct CompiledPolicy {
+    id: i64,
+    network: Option<ipnetwork::IpNetwork>,
+    group_id: Option<i64>,
+    domain: DomainPattern,
+    record_types: Vec<RecordType>,
+    days: u8,
+    window: Option<(u16, u16)>,
+    action: PolicyAction,
+    target: Option<Arc<str>>,
+}
+
+impl CompiledPolicy {
+    #[inline]
+    fn matches(
+        &self,
+        client_ip: IpAddr,
+        group_id: i64,
+        domain: &str,
+        record_type: RecordType,
+        clock: Option<(u8, u16)>,
+    ) -> bool {
+        if self.group_id.is_some_and(|g| g != group_id) {
+            return false;
+        }
+        if !self.record_types.is_empty() && !self.record_types.contains(&record_type) {
+            return false;
+        }
+        if self.network.is_some_and(|n| !n.contains(client_ip)) {
+            return false;
+        }
+        if !self.domain.matches(domain) {
+            return false;
+        }
+        if let Some((start, end)) = self.window {
+            let Some((weekday_bit, minute)) = clock else {
+                return false;
+            };
+            if self.days & weekday_bit == 0 || minute < start || minute >= end {
+                return false;
+            }
+        } else if self.days != ALL_DAYS {
+            let Some((weekday_bit, _)) = clock else {
+                return false;
+            };
+            if self.days & weekday_bit == 0 {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+const ALL_DAYS: u8 = 0x7F;
+
+/// Read-optimised, priority-ordered form of the enabled policies.
+pub struct QueryPolicyMatcher {
+    rules: Vec<CompiledPolicy>,
+    needs_clock: bool,
+}
+
+impl QueryPolicyMatcher {
+    pub fn empty() -> Self {
+        Self {
+            rules: Vec::new(),
+            needs_clock: false,
+        }
+    }
+
+    pub fn new(mut policies: Vec<QueryPolicy>) -> Result<Self, String> {
+        policies.retain(|p| p.enabled);
+        policies.sort_by_key(|p| (p.priority, p.id.unwrap_or(i64::MAX)));
+
+        let mut rules = Vec::with_capacity(policies.len());
+        for policy in policies {
+            let network = policy
+                .client_cidr
+                .as_deref()
+                .map(|c| {
+                    c.parse::<ipnetwork::IpNetwork>()
+                        .map_err(|e| format!("Invalid CIDR {}: {}", c, e))
+                })
+                .transpose()?;
+            let window = match (&policy.start_time, &policy.end_time) {
+                (Some(start), Some(end)) => Some((parse_minutes(start)?, parse_minutes(end)?)),
+                _ => None,
+            };
+            rules.push(CompiledPolicy {
+                id: policy.id.unwrap_or(0),
+                network,
+                group_id: policy.group_id,
+                domain: DomainPattern::parse(policy.domain_pattern.as_deref()),
+                record_types: policy.record_types,
+                days: policy.days.unwrap_or(ALL_DAYS),
+                window,
+                action: policy.action,
+                target: policy.action_target,
+            });
+        }
+
+        let needs_clock = rules
+            .iter()
+            .any(|r| r.window.is_some() || r.days != ALL_DAYS);
+        Ok(Self { rules, needs_clock })
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Whether any rule is time-restricted, i.e. `evaluate` needs a clock.
+    #[inline]
+    pub fn needs_clock(&self) -> bool {
+        self.needs_clock
+    }
+
+    /// Returns the first matching rule. `clock` is `(weekday_bit, minute_of_day)`
+    /// using the same Monday = bit 0 convention as schedule time slots;
+    /// time-restricted rules never match without it.
+    pub fn evaluate(
+        &self,
+        client_ip: IpAddr,
+        group_id: i64,
+        domain: &str,
+        record_type: RecordType,
+        clock: Option<(u8, u16)>,
+    ) -> Option<PolicyMatch> {
+        self.rules
+            .iter()
+            .find(|r| r.matches(client_ip, group_id, domain, record_type, clock))
+            .map(|r| PolicyMatch {
+                policy_id: r.id,
+                action: r.action,
+                target: r.target.clone(),
+            })
+    }
+}
+
+fn parse_minutes(time: &str) -> Result<u16, String> {
+    let (h, m) = time
+        .split_once(':')
+        .ok_or_else(|| format!("time must be in HH:MM format, got '{time}'"))?;
+    let h: u16 = h
+        .parse()
+        .map_err(|_| format!("invalid hours in '{time}'"))?;
+    let m: u16 = m
+        .parse()
+        .map_err(|_| format!("invalid minutes in '{time}'"))?;
+    Ok(h * 60 + m)
+}
@@ -0,0 +1,74 @@
+use crate::dns_record::RecordType;
+use serde::{Deserialize, Serialize};
+
+/// How a group's record type list is interpreted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RecordTypeFilterMode {
+    /// Only the listed types are answered; every other type is refused.
+    Allow,
+    /// The listed types are refused; every other type is answered.
+    Deny,
+}
+
+impl RecordTypeFilterMode {
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            RecordTypeFilterMode::Allow => "allow",
+            RecordTypeFilterMode::Deny => "deny",
+        }
+    }
+}
+
+impl std::str::FromStr for RecordTypeFilterMode {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "allow" => Ok(RecordTypeFilterMode::Allow),
+            "deny" => Ok(RecordTypeFilterMode::Deny),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Per-group record type policy. A group has at most one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordTypePolicy {
+    pub id: Option<i64>,
+    pub group_id: i64,
+    pub mode: RecordTypeFilterMode,
+    pub record_types: Vec<RecordType>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+impl RecordTypePolicy {
+    pub fn new(group_id: i64, mode: RecordTypeFilterMode, record_types: Vec<RecordType>) -> Self {
+        Self {
+            id: None,
+            group_id,
+            mode,
+            record_types,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.record_types.is_empty() {
+            return Err("record_types cannot be empty".to_string());
+        }
+        Ok(())
+    }
+
+    /// Whether a query of `record_type` must be refused under this policy.
+    #[inline]
+    pub fn blocks(&self, record_type: RecordType) -> bool {
+        let listed = self.record_types.contains(&record_type);
+        match self.mode {
+            RecordTypeFilterMode::Allow => !listed,
+            RecordTypeFilterMode::Deny => listed,
+        }
+    }
+}
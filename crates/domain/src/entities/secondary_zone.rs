@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+/// A copy of a secondary zone as last transferred from its primary.
+#[derive(Debug, Clone)]
+pub struct SecondaryZone {
+    /// Zone apex in lowercase, without the trailing dot.
+    pub zone: Arc<str>,
+    pub serial: u32,
+    /// SOA timers, in seconds.
+    pub refresh: u32,
+    pub retry: u32,
+    pub expire: u32,
+    /// Unix time of the last transfer that changed the zone.
+    pub transferred_at: i64,
+    /// Unix time the primary last confirmed the copy is current. The zone
+    /// expires `expire` seconds after this.
+    pub checked_at: i64,
+    /// Resource records in wire format, one per entry, SOA first.
+    pub records: Vec<Vec<u8>>,
+}
+
+impl SecondaryZone {
+    /// Whether the copy has gone `expire` seconds without reaching a primary
+    /// and must no longer be served.
+    pub fn is_expired(&self, now: i64) -> bool {
+        now.saturating_sub(self.checked_at) >= i64::from(self.expire)
+    }
+
+    /// Unix time of the next scheduled check with the primary.
+    pub fn refresh_due_at(&self) -> i64 {
+        self.checked_at.saturating_add(i64::from(self.refresh))
+    }
+}
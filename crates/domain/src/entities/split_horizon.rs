@@ -0,0 +1,145 @@
+use std::collections::{HashMap, HashSet};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use crate::config::{DnsViewConfig, LocalDnsRecord};
+use crate::RecordType;
+
+/// A view-specific answer for an A/AAAA query. `addresses` is empty when the
+/// view defines the name but not for the queried address family (NODATA).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ViewAnswer {
+    pub view: Arc<str>,
+    pub addresses: Vec<IpAddr>,
+    pub ttl: u32,
+}
+
+struct ViewRecord {
+    ip: IpAddr,
+    ttl: u32,
+}
+
+struct CompiledView {
+    name: Arc<str>,
+    networks: Vec<ipnetwork::IpNetwork>,
+    groups: Vec<i64>,
+    records: HashMap<Box<str>, Vec<ViewRecord>>,
+}
+
+impl CompiledView {
+    fn matches(&self, client_ip: IpAddr, group_id: i64) -> bool {
+        self.groups.contains(&group_id) || self.networks.iter().any(|n| n.contains(client_ip))
+    }
+}
+
+/// Read-optimised form of the split-horizon views and their local records.
+/// A client is assigned the first view it matches; if that view defines the
+/// queried name, its records answer instead of the global ones.
+pub struct SplitHorizonMatcher {
+    views: Vec<CompiledView>,
+    /// Every name defined in at least one view, for a cheap early exit.
+    names: HashSet<Box<str>>,
+}
+
+impl SplitHorizonMatcher {
+    pub fn empty() -> Self {
+        Self {
+            views: Vec::new(),
+            names: HashSet::new(),
+        }
+    }
+
+    /// Compiles the views and the records tagged with them. Invalid CIDRs,
+    /// unparsable IPs and records naming an unknown view are skipped.
+    pub fn new(
+        views: &[DnsViewConfig],
+        records: &[LocalDnsRecord],
+        default_domain: &Option<String>,
+    ) -> Self {
+        let mut compiled: Vec<CompiledView> = views
+            .iter()
+            .map(|view| CompiledView {
+                name: Arc::from(view.name.as_str()),
+                networks: view.clients.iter().filter_map(|c| c.parse().ok()).collect(),
+                groups: view.groups.clone(),
+                records: HashMap::new(),
+            })
+            .collect();
+
+        let mut names = HashSet::new();
+        for record in records {
+            let Some(ref view_name) = record.view else {
+                continue;
+            };
+            let Some(view) = compiled.iter_mut().find(|v| *v.name == **view_name) else {
+                continue;
+            };
+            let Ok(ip) = record.ip.parse::<IpAddr>() else {
+                continue;
+            };
+            let fqdn = record.fqdn(default_domain).to_ascii_lowercase();
+            names.insert(Box::from(fqdn.as_str()));
+            view.records
+                .entry(Box::from(fqdn))
+                .or_default()
+                .push(ViewRecord {
+                    ip,
+                    ttl: record.ttl_or_default(),
+                });
+        }
+
+        Self {
+            views: compiled,
+            names,
+        }
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Returns the view answer for `domain` as seen by this client, or `None`
+    /// when the client's view does not override the name (or the query is
+    /// not A/AAAA) and normal resolution should continue.
+    pub fn lookup(
+        &self,
+        client_ip: IpAddr,
+        group_id: i64,
+        domain: &str,
+        record_type: RecordType,
+    ) -> Option<ViewAnswer> {
+        if self.is_empty() || !matches!(record_type, RecordType::A | RecordType::AAAA) {
+            return None;
+        }
+        let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+        if !self.names.contains(domain.as_str()) {
+            return None;
+        }
+
+        let view = self.views.iter().find(|v| v.matches(client_ip, group_id))?;
+        let records = view.records.get(domain.as_str())?;
+
+        let mut addresses = Vec::with_capacity(records.len());
+        let mut ttl = u32::MAX;
+        for record in records {
+            let family_matches = match record_type {
+                RecordType::A => record.ip.is_ipv4(),
+                _ => record.ip.is_ipv6(),
+            };
+            if family_matches && !addresses.contains(&record.ip) {
+                addresses.push(record.ip);
+                ttl = ttl.min(record.ttl);
+            }
+        }
+        if addresses.is_empty() {
+            ttl = records.iter().map(|r| r.ttl).min().unwrap_or(300);
+        }
+
+        Some(ViewAnswer {
+            view: Arc::clone(&view.name),
+            addresses,
+            ttl,
+        })
+    }
+}
@@ -0,0 +1,149 @@
+use std::net::IpAddr;
+use std::sync::Arc;
+
+/// A policy namespace for one downstream network, e.g. one customer of a
+/// managed deployment. A tenant owns its groups (and through them its
+/// blocklists and stats), its local records and its API tokens.
+///
+/// Queries are attributed to a tenant by the listener they arrive on or by
+/// the client subnet they come from; clients without a group assignment of
+/// their own then fall into the tenant's default group.
+#[derive(Debug, Clone)]
+pub struct Tenant {
+    pub id: Option<i64>,
+    pub name: Arc<str>,
+    /// Bind address of a `[[server.listeners]]` entry served by this tenant.
+    pub listener: Option<Arc<str>>,
+    /// Client CIDRs attributed to this tenant.
+    pub subnets: Vec<Arc<str>>,
+    /// Group used for the tenant's clients that have no group assignment.
+    pub default_group_id: Option<i64>,
+    pub comment: Option<Arc<str>>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+impl Tenant {
+    pub fn new(name: Arc<str>) -> Self {
+        Self {
+            id: None,
+            name,
+            listener: None,
+            subnets: Vec::new(),
+            default_group_id: None,
+            comment: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    /// Name of the group created alongside the tenant.
+    pub fn default_group_name(&self) -> String {
+        format!("{} Default", self.name)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        Self::validate_name(&self.name)?;
+        if let Some(listener) = &self.listener {
+            listener
+                .parse::<std::net::SocketAddr>()
+                .map_err(|e| format!("Invalid listener address '{}': {}", listener, e))?;
+        }
+        for subnet in &self.subnets {
+            subnet
+                .parse::<ipnetwork::IpNetwork>()
+                .map_err(|e| format!("Invalid tenant subnet '{}': {}", subnet, e))?;
+        }
+        if let Some(c) = &self.comment {
+            if c.len() > 500 {
+                return Err("Comment cannot exceed 500 characters".to_string());
+            }
+        }
+        Ok(())
+    }
+
+    /// Same rules as group names, leaving room for the " Default" suffix of
+    /// the tenant's default group.
+    pub fn validate_name(name: &str) -> Result<(), String> {
+        if name.is_empty() {
+            return Err("Tenant name cannot be empty".to_string());
+        }
+        if name.len() > 64 {
+            return Err("Tenant name cannot exceed 64 characters".to_string());
+        }
+        if !name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == ' ' || c == '-' || c == '_')
+        {
+            return Err(
+                "Tenant name can only contain alphanumeric characters, spaces, hyphens, and underscores"
+                    .to_string(),
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Query and client counts of one tenant over a period.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TenantStats {
+    pub queries_total: u64,
+    pub queries_blocked: u64,
+    pub unique_clients: u64,
+    pub groups: u64,
+    pub local_records: u64,
+}
+
+/// Read-optimised tenant lookup by client address. The most specific
+/// subnet wins when tenants overlap.
+pub struct TenantMatcher {
+    subnets: Vec<(ipnetwork::IpNetwork, i64, Option<i64>)>,
+}
+
+impl TenantMatcher {
+    pub fn empty() -> Self {
+        Self {
+            subnets: Vec::new(),
+        }
+    }
+
+    /// Builds the matcher from `tenants`, skipping subnets that do not
+    /// parse and tenants without an id.
+    pub fn new(tenants: &[Tenant]) -> Self {
+        let mut subnets: Vec<_> = tenants
+            .iter()
+            .filter_map(|t| t.id.map(|id| (id, t)))
+            .flat_map(|(id, tenant)| {
+                tenant.subnets.iter().filter_map(move |cidr| {
+                    cidr.parse::<ipnetwork::IpNetwork>()
+                        .ok()
+                        .map(|net| (net, id, tenant.default_group_id))
+                })
+            })
+            .collect();
+        subnets.sort_by_key(|s| std::cmp::Reverse(s.0.prefix()));
+        Self { subnets }
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.subnets.is_empty()
+    }
+
+    /// Tenant whose subnet contains `ip`.
+    pub fn find_tenant_for_ip(&self, ip: IpAddr) -> Option<i64> {
+        self.find(ip).map(|(tenant_id, _)| tenant_id)
+    }
+
+    /// Default group of the tenant whose subnet contains `ip`.
+    pub fn find_default_group_for_ip(&self, ip: IpAddr) -> Option<i64> {
+        self.find(ip).and_then(|(_, group_id)| group_id)
+    }
+
+    fn find(&self, ip: IpAddr) -> Option<(i64, Option<i64>)> {
+        self.subnets
+            .iter()
+            .find(|(net, _, _)| net.contains(ip))
+            .map(|(_, tenant_id, group_id)| (*tenant_id, *group_id))
+    }
+}
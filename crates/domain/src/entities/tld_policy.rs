@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+
+/// How a group's TLD list is interpreted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TldFilterMode {
+    /// Only names under the listed TLDs resolve; every other TLD is blocked.
+    Allow,
+    /// Names under the listed TLDs are blocked; every other TLD resolves.
+    Deny,
+}
+
+impl TldFilterMode {
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            TldFilterMode::Allow => "allow",
+            TldFilterMode::Deny => "deny",
+        }
+    }
+}
+
+impl std::str::FromStr for TldFilterMode {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "allow" => Ok(TldFilterMode::Allow),
+            "deny" => Ok(TldFilterMode::Deny),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Per-group top-level domain policy. A group has at most one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TldPolicy {
+    pub id: Option<i64>,
+    pub group_id: i64,
+    pub mode: TldFilterMode,
+    /// Lowercase TLDs without the leading dot, e.g. `zip`.
+    pub tlds: Vec<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+impl TldPolicy {
+    /// Builds a policy, normalising `.ZIP` and `zip` to the same entry.
+    pub fn new(group_id: i64, mode: TldFilterMode, tlds: Vec<String>) -> Self {
+        let mut tlds: Vec<String> = tlds.iter().map(|t| Self::normalize_tld(t)).collect();
+        tlds.sort();
+        tlds.dedup();
+        Self {
+            id: None,
+            group_id,
+            mode,
+            tlds,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    pub fn normalize_tld(tld: &str) -> String {
+        tld.trim().trim_start_matches('.').to_ascii_lowercase()
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.tlds.is_empty() {
+            return Err("tlds cannot be empty".to_string());
+        }
+        for tld in &self.tlds {
+            Self::validate_tld(tld)?;
+        }
+        Ok(())
+    }
+
+    fn validate_tld(tld: &str) -> Result<(), String> {
+        if tld.is_empty() || tld.len() > 63 {
+            return Err(format!("Invalid TLD '{tld}': must be 1-63 characters"));
+        }
+        if tld.starts_with('-') || tld.ends_with('-') {
+            return Err(format!(
+                "Invalid TLD '{tld}': cannot start or end with a hyphen"
+            ));
+        }
+        if !tld
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+        {
+            return Err(format!(
+                "Invalid TLD '{tld}': only letters, digits and hyphens are allowed"
+            ));
+        }
+        Ok(())
+    }
+
+    /// The top-level label of `domain`, or `None` for a single-label name.
+    #[inline]
+    pub fn tld_of(domain: &str) -> Option<&str> {
+        let domain = domain.strip_suffix('.').unwrap_or(domain);
+        domain.rsplit_once('.').map(|(_, tld)| tld)
+    }
+
+    /// Whether a query for `domain` must be blocked under this policy.
+    /// Single-label names have no TLD and are never blocked.
+    pub fn blocks(&self, domain: &str) -> bool {
+        let Some(tld) = Self::tld_of(domain) else {
+            return false;
+        };
+        let listed = self.tlds.iter().any(|t| t.eq_ignore_ascii_case(tld));
+        match self.mode {
+            TldFilterMode::Allow => !listed,
+            TldFilterMode::Deny => listed,
+        }
+    }
+}
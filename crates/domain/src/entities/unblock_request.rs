@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Most pending unblock requests kept at once; new ones are refused beyond
+/// it until an admin reviews or deletes some.
+pub const MAX_UNBLOCK_REQUESTS: usize = 1_000;
+
+/// Review state of an unblock request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UnblockRequestStatus {
+    Pending,
+    /// Allowed for the requesting group through a managed domain.
+    Approved,
+    Denied,
+}
+
+impl UnblockRequestStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Approved => "approved",
+            Self::Denied => "denied",
+        }
+    }
+}
+
+impl std::str::FromStr for UnblockRequestStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(Self::Pending),
+            "approved" => Ok(Self::Approved),
+            "denied" => Ok(Self::Denied),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A request to unblock a domain, filed by the client that hit the block,
+/// from the sinkhole block page or the public endpoint. One request is kept
+/// per domain and client; while it is pending, a repeated request replaces
+/// its message and time.
+#[derive(Debug, Clone)]
+pub struct UnblockRequest {
+    pub id: Option<i64>,
+    pub domain: Arc<str>,
+    pub client_ip: Arc<str>,
+    /// Group the client was in when it filed the request.
+    pub group_id: Option<i64>,
+    /// Why the domain was blocked, as a [`BlockSource`](super::block_source::BlockSource) string.
+    pub block_source: Option<Arc<str>>,
+    pub message: Option<Arc<str>>,
+    pub status: UnblockRequestStatus,
+    /// Allow entry created when the request was approved.
+    pub managed_domain_id: Option<i64>,
+    pub created_at: Option<String>,
+    pub reviewed_at: Option<String>,
+}
+
+impl UnblockRequest {
+    pub fn new(domain: Arc<str>, client_ip: Arc<str>) -> Self {
+        Self {
+            id: None,
+            domain,
+            client_ip,
+            group_id: None,
+            block_source: None,
+            message: None,
+            status: UnblockRequestStatus::Pending,
+            managed_domain_id: None,
+            created_at: None,
+            reviewed_at: None,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        let domain = self.domain.as_ref();
+        if domain.is_empty() || domain.len() > 253 {
+            return Err(format!("Invalid domain '{}'", domain));
+        }
+        if domain.starts_with('.')
+            || domain.ends_with('.')
+            || domain.contains("..")
+            || !domain
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        {
+            return Err(format!("Invalid domain '{}'", domain));
+        }
+        if let Some(message) = &self.message {
+            if message.chars().count() > 500 {
+                return Err("Message cannot exceed 500 characters".to_string());
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,3 +1,5 @@
+use super::managed_domain::ManagedDomain;
+
 #[derive(Debug, Clone)]
 pub struct WhitelistedDomain {
     pub id: Option<i64>,
@@ -6,6 +8,9 @@ pub struct WhitelistedDomain {
 }
 
 impl WhitelistedDomain {
+    /// Fields accepted by `PageRequest::sorted_by` for whitelist listings.
+    pub const SORT_FIELDS: &'static [&'static str] = &["domain", "added_at"];
+
     pub fn new(domain: String) -> Self {
         Self {
             id: None,
@@ -13,4 +18,9 @@ impl WhitelistedDomain {
             added_at: None,
         }
     }
+
+    /// Accepts exact names and `*.example.com` wildcards.
+    pub fn validate_domain(domain: &str) -> Result<(), String> {
+        ManagedDomain::validate_domain(domain)
+    }
 }
@@ -15,6 +15,10 @@ pub struct WhitelistSource {
 }
 
 impl WhitelistSource {
+    /// Fields accepted by `PageRequest::sorted_by` for source listings.
+    pub const SORT_FIELDS: &'static [&'static str] =
+        &["name", "enabled", "created_at", "updated_at"];
+
     pub fn new(
         id: Option<i64>,
         name: Arc<str>,
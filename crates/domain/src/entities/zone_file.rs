@@ -0,0 +1,173 @@
+use crate::dns_record::RecordType;
+use crate::entities::local_record::{LocalRecord, LocalRecordData};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// SOA timers written into exported zones: refresh, retry, expire and
+/// negative-caching TTL, in seconds.
+const SOA_TIMERS: [u32; 4] = [3600, 600, 604_800, 300];
+
+/// Longest character-string of a TXT record (RFC 1035 §3.3).
+const MAX_TXT_CHUNK: usize = 255;
+
+/// One record of a [`ZoneFile`] in presentation form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZoneFileRecord {
+    /// Owner name relative to the zone origin, `@` for the apex.
+    pub name: String,
+    pub ttl: u32,
+    pub record_type: RecordType,
+    /// Record data as written in a zone file, targets fully qualified.
+    pub data: String,
+}
+
+/// The local records under one domain, ready to be written as an RFC 1035
+/// zone file for BIND, Knot and other authoritative servers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZoneFile {
+    /// Zone apex in lowercase, without the trailing dot; `.` for records
+    /// that have no domain at all.
+    pub origin: String,
+    pub records: Vec<ZoneFileRecord>,
+    /// Records of the zone that a zone file cannot carry: `{ip}` wildcards,
+    /// whose answer depends on the queried name.
+    pub skipped: usize,
+}
+
+impl ZoneFile {
+    /// Groups `records` into one zone per domain, falling back to
+    /// `default_domain` like [`LocalRecord::fqdn`]. View and tenant records
+    /// are left out, as they are not part of the zone every client sees.
+    /// Zones are sorted by origin and records keep their order.
+    pub fn from_records(records: &[LocalRecord], default_domain: &Option<String>) -> Vec<Self> {
+        let mut zones: BTreeMap<String, ZoneFile> = BTreeMap::new();
+        for record in records
+            .iter()
+            .filter(|r| r.view.is_none() && r.tenant_id.is_none())
+        {
+            let origin = record
+                .domain
+                .as_deref()
+                .or(default_domain.as_deref())
+                .map(|d| d.trim_matches('.').to_ascii_lowercase())
+                .filter(|d| !d.is_empty())
+                .unwrap_or_else(|| ".".to_string());
+            let zone = zones.entry(origin.clone()).or_insert_with(|| ZoneFile {
+                origin,
+                records: Vec::new(),
+                skipped: 0,
+            });
+            match record.data() {
+                Ok(data) => match zone_data(&data) {
+                    Some(data) => zone.records.push(ZoneFileRecord {
+                        name: relative_name(&record.fqdn(default_domain), &zone.origin),
+                        ttl: record.ttl,
+                        record_type: record.record_type,
+                        data,
+                    }),
+                    None => zone.skipped += 1,
+                },
+                Err(_) => zone.skipped += 1,
+            }
+        }
+        zones.into_values().collect()
+    }
+
+    /// Renders the zone with a placeholder SOA and NS at the apex, which
+    /// authoritative servers require before they load a zone. `serial` is
+    /// written into the SOA.
+    pub fn to_text(&self, serial: u32) -> String {
+        let origin = absolute(&self.origin);
+        let [refresh, retry, expire, minimum] = SOA_TIMERS;
+        let mut text = String::new();
+        let _ = writeln!(text, "$ORIGIN {origin}");
+        let _ = writeln!(text, "$TTL {minimum}");
+        let _ = writeln!(
+            text,
+            "@\t{minimum}\tIN\tSOA\tlocalhost. hostmaster.localhost. \
+             {serial} {refresh} {retry} {expire} {minimum}"
+        );
+        let _ = writeln!(text, "@\t{minimum}\tIN\tNS\tlocalhost.");
+        for record in &self.records {
+            let _ = writeln!(
+                text,
+                "{}\t{}\tIN\t{}\t{}",
+                record.name, record.ttl, record.record_type, record.data
+            );
+        }
+        if self.skipped > 0 {
+            let _ = writeln!(
+                text,
+                "; {} record(s) not representable in a zone file were skipped",
+                self.skipped
+            );
+        }
+        text
+    }
+}
+
+fn absolute(name: &str) -> String {
+    if name == "." {
+        name.to_string()
+    } else {
+        format!("{name}.")
+    }
+}
+
+fn relative_name(fqdn: &str, origin: &str) -> String {
+    if fqdn == origin {
+        return "@".to_string();
+    }
+    if origin != "." {
+        if let Some(name) = fqdn.strip_suffix(origin).and_then(|n| n.strip_suffix('.')) {
+            return name.to_string();
+        }
+    }
+    absolute(fqdn)
+}
+
+fn zone_data(data: &LocalRecordData) -> Option<String> {
+    match data {
+        LocalRecordData::Address(ip) => Some(ip.to_string()),
+        LocalRecordData::EmbeddedAddress { .. } => None,
+        LocalRecordData::Cname(target) => Some(absolute(target)),
+        LocalRecordData::Txt(text) => Some(txt_strings(text)),
+        LocalRecordData::Mx {
+            preference,
+            exchange,
+        } => Some(format!("{preference} {}", absolute(exchange))),
+        LocalRecordData::Srv {
+            priority,
+            weight,
+            port,
+            target,
+        } => Some(format!("{priority} {weight} {port} {}", absolute(target))),
+    }
+}
+
+/// Quotes `text` as one or more character-strings of at most 255 bytes,
+/// escaping quotes, backslashes and non-printable bytes.
+fn txt_strings(text: &str) -> String {
+    text.as_bytes()
+        .chunks(MAX_TXT_CHUNK)
+        .map(|chunk| {
+            let mut quoted = String::with_capacity(chunk.len() + 2);
+            quoted.push('"');
+            for &byte in chunk {
+                match byte {
+                    b'"' | b'\\' => {
+                        quoted.push('\\');
+                        quoted.push(byte as char);
+                    }
+                    0x20..=0x7e => quoted.push(byte as char),
+                    _ => {
+                        let _ = write!(quoted, "\\{byte:03}");
+                    }
+                }
+            }
+            quoted.push('"');
+            quoted
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -0,0 +1,57 @@
+/// Response code a failed query is answered with (RFC 1035 §4.1.1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DnsRcode {
+    /// The query could not be interpreted.
+    FormErr,
+    /// The server failed to produce an answer.
+    ServFail,
+    /// The queried name does not exist.
+    NxDomain,
+    /// The query kind is not supported.
+    NotImp,
+    /// The server declined to answer for policy reasons.
+    Refused,
+    /// An update prerequisite expected a name not to exist (RFC 2136).
+    YxDomain,
+    /// An update prerequisite expected an RRset not to exist.
+    YxRrset,
+    /// An update prerequisite expected an RRset to exist.
+    NxRrset,
+    /// The server is not authoritative for the zone of an update.
+    NotAuth,
+    /// A name of an update lies outside the zone.
+    NotZone,
+}
+
+impl DnsRcode {
+    /// Numeric value carried in the RCODE header field.
+    pub const fn code(self) -> u16 {
+        match self {
+            DnsRcode::FormErr => 1,
+            DnsRcode::ServFail => 2,
+            DnsRcode::NxDomain => 3,
+            DnsRcode::NotImp => 4,
+            DnsRcode::Refused => 5,
+            DnsRcode::YxDomain => 6,
+            DnsRcode::YxRrset => 7,
+            DnsRcode::NxRrset => 8,
+            DnsRcode::NotAuth => 9,
+            DnsRcode::NotZone => 10,
+        }
+    }
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            DnsRcode::FormErr => "FORMERR",
+            DnsRcode::ServFail => "SERVFAIL",
+            DnsRcode::NxDomain => "NXDOMAIN",
+            DnsRcode::NotImp => "NOTIMP",
+            DnsRcode::Refused => "REFUSED",
+            DnsRcode::YxDomain => "YXDOMAIN",
+            DnsRcode::YxRrset => "YXRRSET",
+            DnsRcode::NxRrset => "NXRRSET",
+            DnsRcode::NotAuth => "NOTAUTH",
+            DnsRcode::NotZone => "NOTZONE",
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use super::dns_rcode::DnsRcode;
+use crate::entities::block_source::BlockSource;
 use thiserror::Error;
 
 #[derive(Error, Debug, Clone)]
@@ -5,6 +7,12 @@ pub enum DomainError {
     #[error("Invalid domain name: {0}")]
     InvalidDomainName(String),
 
+    #[error("Malformed DNS query: {0}")]
+    MalformedQuery(String),
+
+    #[error("Unsupported DNS query: {0}")]
+    UnsupportedQuery(String),
+
     #[error("Invalid Safe Search engine: {0}")]
     InvalidSafeSearchEngine(String),
 
@@ -26,8 +34,8 @@ pub enum DomainError {
     #[error("I/O error: {0}")]
     IoError(String),
 
-    #[error("Domain is blocked")]
-    Blocked,
+    #[error("Domain is blocked by {0}")]
+    Blocked(BlockSource),
 
     #[error("Domain not found (NXDOMAIN)")]
     NxDomain,
@@ -38,21 +46,33 @@ pub enum DomainError {
     #[error("Query timeout")]
     QueryTimeout,
 
+    #[error("Query time budget exhausted")]
+    QueryBudgetExceeded,
+
     #[error("DNS query rate limited")]
     DnsRateLimited,
 
     #[error("DNS query rate limited (truncated, retry via TCP)")]
     DnsRateLimitedSlip,
 
+    #[error("ANY query answered with a minimal response (RFC 8482)")]
+    AnyQueryMinimized,
+
     #[error("DNS tunneling detected")]
     DnsTunnelingDetected,
 
     #[error("DGA domain detected")]
     DgaDomainDetected,
 
+    #[error("DNS rebinding attempt blocked")]
+    DnsRebindingBlocked,
+
     #[error("DNS cookie validation failed")]
     DnsCookieInvalid,
 
+    #[error("Client not allowed on this listener")]
+    ClientNotAllowed,
+
     #[error("Query filtered: {0}")]
     FilteredQuery(String),
 
@@ -98,18 +118,39 @@ pub enum DomainError {
     #[error("Invalid whitelist source: {0}")]
     InvalidWhitelistSource(String),
 
+    #[error("IP blocklist source not found: {0}")]
+    IpBlocklistSourceNotFound(i64),
+
+    #[error("Invalid IP blocklist source: {0}")]
+    InvalidIpBlocklistSource(String),
+
     #[error("Block filter fetch error: {0}")]
     BlockFilterFetchError(String),
 
     #[error("Block filter compile error: {0}")]
     BlockFilterCompileError(String),
 
+    #[error("Notification delivery failed: {0}")]
+    NotificationDeliveryFailed(String),
+
+    #[error("Metrics export failed: {0}")]
+    MetricsExportFailed(String),
+
+    #[error("Record source unavailable: {0}")]
+    RecordSourceUnavailable(String),
+
+    #[error("Standby sync failed: {0}")]
+    StandbySyncFailed(String),
+
     #[error("Managed domain not found: {0}")]
     ManagedDomainNotFound(i64),
 
     #[error("Invalid managed domain: {0}")]
     InvalidManagedDomain(String),
 
+    #[error("Invalid plugin: {0}")]
+    InvalidPlugin(String),
+
     #[error("Regex filter not found: {0}")]
     RegexFilterNotFound(i64),
 
@@ -128,6 +169,57 @@ pub enum DomainError {
     #[error("Invalid regex filter: {0}")]
     InvalidRegexFilter(String),
 
+    #[error("Query policy not found: {0}")]
+    QueryPolicyNotFound(i64),
+
+    #[error("Alert not found: {0}")]
+    AlertNotFound(i64),
+
+    #[error("Invalid query policy: {0}")]
+    InvalidQueryPolicy(String),
+
+    #[error("DNS rewrite not found: {0}")]
+    DnsRewriteNotFound(i64),
+
+    #[error("Invalid DNS rewrite: {0}")]
+    InvalidDnsRewrite(String),
+
+    #[error("Cache TTL override not found: {0}")]
+    CacheTtlOverrideNotFound(i64),
+
+    #[error("Invalid cache TTL override: {0}")]
+    InvalidCacheTtlOverride(String),
+
+    #[error("Unblock request not found: {0}")]
+    UnblockRequestNotFound(i64),
+
+    #[error("Unblock request {0} was already reviewed")]
+    UnblockRequestAlreadyReviewed(i64),
+
+    #[error("Invalid unblock request: {0}")]
+    InvalidUnblockRequest(String),
+
+    #[error("Local record not found: {0}")]
+    LocalRecordNotFound(i64),
+
+    #[error("Invalid local record: {0}")]
+    InvalidLocalRecord(String),
+
+    #[error("Local record conflict: {0}")]
+    LocalRecordConflict(String),
+
+    #[error("Tenant not found: {0}")]
+    TenantNotFound(i64),
+
+    #[error("Invalid tenant: {0}")]
+    InvalidTenant(String),
+
+    #[error("Invalid record type policy: {0}")]
+    InvalidRecordTypePolicy(String),
+
+    #[error("Invalid TLD policy: {0}")]
+    InvalidTldPolicy(String),
+
     #[error("Transport timeout connecting to {server}")]
     TransportTimeout { server: String },
 
@@ -213,3 +305,26 @@ pub enum DomainError {
     #[error("Invalid input: {0}")]
     InvalidInput(String),
 }
+
+impl DomainError {
+    /// RCODE a DNS client receives when resolving its query fails with this
+    /// error. Policy decisions answer REFUSED, problems with the query itself
+    /// FORMERR or NOTIMP, and anything on our or the upstream side SERVFAIL.
+    pub fn rcode(&self) -> DnsRcode {
+        match self {
+            DomainError::NxDomain | DomainError::LocalNxDomain => DnsRcode::NxDomain,
+            DomainError::Blocked(_)
+            | DomainError::ClientNotAllowed
+            | DomainError::DgaDomainDetected
+            | DomainError::DnsTunnelingDetected
+            | DomainError::DnsRebindingBlocked
+            | DomainError::DnsRateLimited
+            | DomainError::DnsRateLimitedSlip
+            | DomainError::DnsCookieInvalid
+            | DomainError::FilteredQuery(_) => DnsRcode::Refused,
+            DomainError::MalformedQuery(_) | DomainError::InvalidDomainName(_) => DnsRcode::FormErr,
+            DomainError::UnsupportedQuery(_) => DnsRcode::NotImp,
+            _ => DnsRcode::ServFail,
+        }
+    }
+}
@@ -1 +1,2 @@
+pub mod dns_rcode;
 pub mod domain_error;
@@ -11,38 +11,74 @@ pub use entities::schedule;
 pub use entities::whitelist;
 
 pub use config::{
-    AdminConfig, AuthConfig, CliOverrides, Config, ConfigError, DgaDetectionAction,
-    DgaDetectionConfig, DnsConfig, DnsCookiesConfig, EncryptedDnsConfig, HealthCheckConfig,
-    LocalDnsRecord, NxdomainHijackAction, NxdomainHijackConfig, RateLimitConfig,
-    ResponseIpFilterAction, ResponseIpFilterConfig, TunnelingAction, TunnelingDetectionConfig,
-    UpstreamPool, UpstreamStrategy,
+    AccessControlConfig, AclAction, AcmeChallenge, AcmeConfig, AdminAccessConfig, AdminConfig,
+    AnomalyDetectionConfig, ApiLimitsConfig, AuthConfig, BlockPageConfig, BlockPageGroupConfig,
+    BlockingConfig, BlockingMode, BootstrapConfig, ChaosIdentityConfig, CliOverrides,
+    ConditionalForwardConfig, Config, ConfigError, ConfigFieldError, DgaDetectionAction,
+    DgaDetectionConfig, DnsConfig, DnsCookiesConfig, DnsViewConfig, DockerConfig, DohMethod,
+    DohUpstreamConfig, EdnsClientIdConfig, EncryptedDnsConfig, HealthCheckConfig, KubernetesConfig,
+    LocalDnsRecord, LogFormat, LoggingConfig, MetricsExportBackend, MetricsExportConfig,
+    NotificationEventsConfig, NotificationsConfig, NxdomainHijackAction, NxdomainHijackConfig,
+    OtelConfig, PluginsConfig, PublicStatsConfig, QueryBudgetConfig, RateLimitConfig,
+    ReportsConfig, ResponseIpFilterAction, ResponseIpFilterConfig, ResponseLimitsConfig,
+    SecondaryZoneConfig, SlowQueryLogConfig, StandbyConfig, TsigAlgorithm, TsigKeyConfig,
+    TsigKeyFile, TunnelingAction, TunnelingDetectionConfig, UdpSocketMode, UnblockRequestsConfig,
+    UpdateZoneConfig, UpstreamPool, UpstreamPreset, UpstreamStrategy, VpnPeerProvider,
+    VpnPeersConfig,
 };
 pub use dns_record::{DnsRecord, RecordCategory, RecordType};
+pub use entities::alert::{Alert, AlertKind};
 pub use entities::api_token::ApiToken;
+pub use entities::audit_event::{AuditAction, AuditEvent};
 pub use entities::auth_session::AuthSession;
 pub use entities::block_source::BlockSource;
 pub use entities::blocked_service::BlockedService;
 pub use entities::blocklist::BlockedDomain;
 pub use entities::blocklist_source::BlocklistSource;
-pub use entities::client::{Client, ClientStats};
+pub use entities::cache_ttl_override::{
+    CacheTtlOverride, TtlBounds, MAX_CACHE_TTL_OVERRIDE, MAX_WILDCARD_TTL_OVERRIDES,
+};
+pub use entities::client::{Client, ClientCategory, ClientStats};
 pub use entities::client_subnet::{ClientSubnet, SubnetMatcher};
 pub use entities::custom_service::CustomService;
+pub use entities::device::{Device, DeviceIpHistory, DuplicateClients};
+pub use entities::dns_rewrite::{DnsRewrite, DnsRewriteMatcher, RewriteAnswer, RewriteTarget};
+pub use entities::dns_update::{DnsUpdate, DnsUpdateOutcome, UpdateChange, UpdatePrerequisite};
+pub use entities::dnssec_history::{DnssecStatsSample, DnssecStatusChange, DnssecValidationCounts};
 pub use entities::group::{Group, GroupStats};
+pub use entities::ip_blocklist_source::IpBlocklistSource;
+pub use entities::local_record::{
+    LocalRecord, LocalRecordData, LocalZone, LocalZoneEntry, DEFAULT_LOCAL_RECORD_TTL,
+    EMBEDDED_IP_TEMPLATE, LOCAL_RECORD_TYPES, MAX_LOCAL_RECORD_TTL,
+};
 pub use entities::managed_domain::{DomainAction, ManagedDomain};
+pub use entities::notification::{Notification, NotificationKind};
 pub use entities::query_log::{
-    CacheStats, QueryCategory, QueryLog, QueryLogFilter, QuerySource, QueryStats,
+    CacheStats, QueryCategory, QueryCountBreakdown, QueryLog, QueryLogFilter, QuerySource,
+    QueryStats,
 };
+pub use entities::query_policy::{PolicyAction, PolicyMatch, QueryPolicy, QueryPolicyMatcher};
+pub use entities::record_type_policy::{RecordTypeFilterMode, RecordTypePolicy};
 pub use entities::regex_filter::RegexFilter;
+pub use entities::report::ReportPeriod;
 pub use entities::safe_search::{SafeSearchConfig, SafeSearchEngine, YouTubeMode};
 pub use entities::schedule::{
     evaluate_slots, GroupOverride, ScheduleAction, ScheduleProfile, TimeSlot, UnknownScheduleAction,
 };
+pub use entities::secondary_zone::SecondaryZone;
 pub use entities::service_catalog::ServiceDefinition;
+pub use entities::split_horizon::{SplitHorizonMatcher, ViewAnswer};
+pub use entities::tenant::{Tenant, TenantMatcher, TenantStats};
+pub use entities::tld_policy::{TldFilterMode, TldPolicy};
+pub use entities::unblock_request::{UnblockRequest, UnblockRequestStatus, MAX_UNBLOCK_REQUESTS};
 pub use entities::user::{User, UserRole, UserSource};
 pub use entities::whitelist::WhitelistedDomain;
 pub use entities::whitelist_source::WhitelistSource;
+pub use entities::zone_file::{ZoneFile, ZoneFileRecord};
+pub use errors::dns_rcode::DnsRcode;
 pub use errors::domain_error::DomainError;
 pub use value_objects::dns_protocol::{DnsProtocol, UpstreamAddr};
 pub use value_objects::dns_query::DnsQuery;
 pub use value_objects::dns_request::{DnsRequest, EdnsCookie};
+pub use value_objects::page::{PageRequest, SortKey, SortOrder};
 pub use value_objects::query_filters::{FqdnFilter, PrivateIpFilter};
@@ -5,6 +5,9 @@ use std::sync::Arc;
 pub struct DnsQuery {
     pub domain: Arc<str>,
     pub record_type: RecordType,
+    /// Cache partition the answer is looked up in and stored under; `0` is
+    /// the partition shared by every client.
+    pub cache_partition: u32,
 }
 
 impl DnsQuery {
@@ -12,6 +15,12 @@ impl DnsQuery {
         Self {
             domain: domain.into(),
             record_type,
+            cache_partition: 0,
         }
     }
+
+    pub fn with_cache_partition(mut self, cache_partition: u32) -> Self {
+        self.cache_partition = cache_partition;
+        self
+    }
 }
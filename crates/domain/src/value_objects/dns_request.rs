@@ -1,4 +1,5 @@
 use crate::dns_record::RecordType;
+use std::borrow::Cow;
 use std::net::IpAddr;
 use std::sync::Arc;
 
@@ -59,15 +60,32 @@ pub struct DnsRequest {
     /// does not include option code 10.
     /// Stored inline — zero heap allocation.
     pub edns_cookie: Option<EdnsCookie>,
+    /// Group used instead of the global default group when the client has
+    /// no group assignment. Set by listeners configured with a default group.
+    pub listener_group_id: Option<i64>,
 }
 
 impl DnsRequest {
+    /// Canonical form every domain takes at ingress: the trailing root dot
+    /// stripped and ASCII lowercased (RFC 1035 §2.3.3 — DNS is
+    /// case-insensitive). Borrows when the name is already canonical, so the
+    /// common case allocates nothing until the request's `Arc<str>` is built.
+    pub fn normalize_domain(domain: &str) -> Cow<'_, str> {
+        let trimmed = domain.trim_end_matches('.');
+        if trimmed.bytes().all(|b| !b.is_ascii_uppercase()) {
+            Cow::Borrowed(trimmed)
+        } else {
+            Cow::Owned(trimmed.to_ascii_lowercase())
+        }
+    }
+
     pub fn new(domain: impl Into<Arc<str>>, record_type: RecordType, client_ip: IpAddr) -> Self {
         Self {
             domain: domain.into(),
             record_type,
             client_ip,
             edns_cookie: None,
+            listener_group_id: None,
         }
     }
 
@@ -76,4 +94,9 @@ impl DnsRequest {
         self.edns_cookie = Some(EdnsCookie::from_bytes(&data));
         self
     }
+
+    pub fn with_listener_group(mut self, group_id: Option<i64>) -> Self {
+        self.listener_group_id = group_id;
+        self
+    }
 }
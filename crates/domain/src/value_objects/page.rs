@@ -0,0 +1,95 @@
+use crate::DomainError;
+use std::str::FromStr;
+
+/// Direction of a sorted listing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+impl SortOrder {
+    pub const fn as_sql(self) -> &'static str {
+        match self {
+            SortOrder::Asc => "ASC",
+            SortOrder::Desc => "DESC",
+        }
+    }
+}
+
+impl FromStr for SortOrder {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("asc") {
+            Ok(SortOrder::Asc)
+        } else if s.eq_ignore_ascii_case("desc") {
+            Ok(SortOrder::Desc)
+        } else {
+            Err(DomainError::InvalidInput(format!(
+                "invalid sort order '{s}', expected 'asc' or 'desc'"
+            )))
+        }
+    }
+}
+
+/// A sort field taken from an entity's `SORT_FIELDS` allowlist.
+///
+/// `field` is always one of those `'static` names, so repositories may use it
+/// directly as a column name in `ORDER BY`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortKey {
+    pub field: &'static str,
+    pub order: SortOrder,
+}
+
+/// Limit/offset window and optional sort key for repository list methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageRequest {
+    pub limit: u32,
+    pub offset: u32,
+    /// `None` keeps the repository's default ordering.
+    pub sort: Option<SortKey>,
+}
+
+impl PageRequest {
+    pub const DEFAULT_LIMIT: u32 = 100;
+    pub const MAX_LIMIT: u32 = 1_000;
+
+    /// Builds a window with `limit` clamped to `1..=MAX_LIMIT`.
+    pub fn new(limit: u32, offset: u32) -> Self {
+        Self {
+            limit: limit.clamp(1, Self::MAX_LIMIT),
+            offset,
+            sort: None,
+        }
+    }
+
+    /// Sorts by `field`, which must appear in `allowed`.
+    pub fn sorted_by(
+        mut self,
+        field: &str,
+        order: SortOrder,
+        allowed: &[&'static str],
+    ) -> Result<Self, DomainError> {
+        let field = allowed
+            .iter()
+            .copied()
+            .find(|f| *f == field)
+            .ok_or_else(|| {
+                DomainError::InvalidInput(format!(
+                    "cannot sort by '{field}', expected one of: {}",
+                    allowed.join(", ")
+                ))
+            })?;
+        self.sort = Some(SortKey { field, order });
+        Ok(self)
+    }
+}
+
+impl Default for PageRequest {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_LIMIT, 0)
+    }
+}
@@ -0,0 +1,90 @@
+use ferrous_dns_domain::{AcmeChallenge, AcmeConfig};
+
+fn enabled(domains: &[&str]) -> AcmeConfig {
+    AcmeConfig {
+        enabled: true,
+        domains: domains.iter().map(|d| d.to_string()).collect(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn disabled_by_default() {
+    let config: AcmeConfig = toml::from_str("").unwrap();
+    assert!(!config.enabled);
+    assert_eq!(config.challenge, AcmeChallenge::Http01);
+    assert_eq!(config.renew_before_days, 30);
+    assert!(config.directory_url.starts_with("https://"));
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn parses_challenge_names() {
+    let config: AcmeConfig = toml::from_str(r#"challenge = "dns-01""#).unwrap();
+    assert_eq!(config.challenge, AcmeChallenge::Dns01);
+    let config: AcmeConfig = toml::from_str(r#"challenge = "http-01""#).unwrap();
+    assert_eq!(config.challenge, AcmeChallenge::Http01);
+}
+
+#[test]
+fn accepts_fully_qualified_domains() {
+    assert!(enabled(&["dns.example.com", "example.org"])
+        .validate()
+        .is_ok());
+}
+
+#[test]
+fn requires_at_least_one_domain() {
+    assert!(enabled(&[]).validate().is_err());
+}
+
+#[test]
+fn rejects_unqualified_or_malformed_domains() {
+    assert!(enabled(&["localhost"]).validate().is_err());
+    assert!(enabled(&["bad..example.com"]).validate().is_err());
+    assert!(enabled(&["under_score.example.com"]).validate().is_err());
+}
+
+#[test]
+fn wildcards_need_dns01() {
+    let mut config = enabled(&["*.example.com"]);
+    assert!(config.validate().is_err());
+    config.challenge = AcmeChallenge::Dns01;
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn rejects_plain_http_directory() {
+    let mut config = enabled(&["dns.example.com"]);
+    config.directory_url = "http://acme.example.com/directory".into();
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn rejects_contact_without_at_sign() {
+    let mut config = enabled(&["dns.example.com"]);
+    config.contact_email = Some("admin".into());
+    assert!(config.validate().is_err());
+    config.contact_email = Some("admin@example.com".into());
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn renew_window_is_bounded() {
+    let mut config = enabled(&["dns.example.com"]);
+    config.renew_before_days = 0;
+    assert!(config.validate().is_err());
+    config.renew_before_days = 61;
+    assert!(config.validate().is_err());
+    config.renew_before_days = 60;
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn http_port_zero_only_allowed_for_dns01() {
+    let mut config = enabled(&["dns.example.com"]);
+    config.http_port = 0;
+    assert!(config.validate().is_err());
+    config.challenge = AcmeChallenge::Dns01;
+    assert!(config.validate().is_ok());
+}
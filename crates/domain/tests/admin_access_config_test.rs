@@ -0,0 +1,39 @@
+use ferrous_dns_domain::{AdminAccessConfig, Config};
+
+#[test]
+fn unrestricted_by_default() {
+    let config = AdminAccessConfig::default();
+    assert!(config.allowed_networks.is_empty());
+    assert!(config.client_ca_path.is_none());
+    assert!(config.validate(false).is_ok());
+}
+
+#[test]
+fn rejects_invalid_networks() {
+    let config = AdminAccessConfig {
+        allowed_networks: vec!["10.8.0.0/24".into(), "not-a-cidr".into()],
+        ..Default::default()
+    };
+    assert!(config.validate(true).is_err());
+}
+
+#[test]
+fn client_ca_needs_web_tls() {
+    let config = AdminAccessConfig {
+        client_ca_path: Some("/data/admin-ca.pem".into()),
+        ..Default::default()
+    };
+    assert!(config.validate(false).is_err());
+    assert!(config.validate(true).is_ok());
+}
+
+#[test]
+fn config_validation_checks_admin_access() {
+    let mut config = Config::default();
+    assert!(config.validate().is_ok());
+
+    config.server.admin_access.client_ca_path = Some("/data/admin-ca.pem".into());
+    assert!(config.validate().is_err());
+    config.server.web_tls.enabled = true;
+    assert!(config.validate().is_ok());
+}
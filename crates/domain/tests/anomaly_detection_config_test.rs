@@ -0,0 +1,86 @@
+use ferrous_dns_domain::{AlertKind, AnomalyDetectionConfig};
+
+// ── Defaults ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn default_values_are_sane() {
+    let config = AnomalyDetectionConfig::default();
+    assert!(!config.enabled);
+    assert_eq!(config.interval_secs, 300);
+    assert_eq!(config.min_queries, 50);
+    assert_eq!(config.min_unique_subdomains, 20);
+    assert_eq!(config.retention_days, 30);
+    assert!(config.validate().is_ok());
+}
+
+// ── TOML deserialization ─────────────────────────────────────────────────────
+
+#[test]
+fn deserializes_partial_toml_with_defaults() {
+    let config: AnomalyDetectionConfig = toml::from_str(
+        r#"
+        enabled = true
+        nxdomain_rate_threshold = 0.3
+        "#,
+    )
+    .unwrap();
+
+    assert!(config.enabled);
+    assert!((config.nxdomain_rate_threshold - 0.3).abs() < f64::EPSILON);
+    assert!((config.volume_spike_factor - 5.0).abs() < f64::EPSILON);
+    assert!(config.validate().is_ok());
+}
+
+// ── Validation ───────────────────────────────────────────────────────────────
+
+#[test]
+fn rejects_out_of_range_values_when_enabled() {
+    let base = AnomalyDetectionConfig {
+        enabled: true,
+        ..Default::default()
+    };
+
+    for config in [
+        AnomalyDetectionConfig {
+            interval_secs: 0,
+            ..base.clone()
+        },
+        AnomalyDetectionConfig {
+            nxdomain_rate_threshold: 1.5,
+            ..base.clone()
+        },
+        AnomalyDetectionConfig {
+            volume_spike_factor: 1.0,
+            ..base.clone()
+        },
+        AnomalyDetectionConfig {
+            retention_days: 0,
+            ..base.clone()
+        },
+    ] {
+        assert!(config.validate().is_err());
+    }
+}
+
+#[test]
+fn disabled_config_skips_validation() {
+    let config = AnomalyDetectionConfig {
+        interval_secs: 0,
+        ..Default::default()
+    };
+    assert!(config.validate().is_ok());
+}
+
+// ── Alert kinds ──────────────────────────────────────────────────────────────
+
+#[test]
+fn alert_kind_round_trips_through_str() {
+    for kind in [
+        AlertKind::NxdomainRate,
+        AlertKind::SubdomainEntropy,
+        AlertKind::VolumeSpike,
+    ] {
+        assert_eq!(kind.as_str().parse::<AlertKind>(), Ok(kind));
+    }
+    assert!("bogus".parse::<AlertKind>().is_err());
+}
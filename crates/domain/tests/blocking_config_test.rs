@@ -0,0 +1,206 @@
+use ferrous_dns_domain::{BlockingConfig, BlockingMode};
+
+fn parse(toml: &str) -> BlockingConfig {
+    toml::from_str(toml).unwrap()
+}
+
+#[test]
+fn test_blocking_defaults_to_refused() {
+    let config = parse("enabled = true");
+
+    assert_eq!(config.mode, BlockingMode::Refused);
+    assert!(config.sinkhole.ipv4.is_none());
+    assert_eq!(config.sinkhole.ttl, 60);
+    assert!(!config.sinkhole.telemetry.enabled);
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn test_sinkhole_mode_parses_addresses_and_telemetry() {
+    let config = parse(
+        r#"
+        enabled = true
+        mode = "sinkhole"
+
+        [sinkhole]
+        ipv4 = "192.168.1.250"
+        ipv6 = "fd00::250"
+        ttl = 10
+
+        [sinkhole.telemetry]
+        enabled = true
+        http_port = 8080
+        "#,
+    );
+
+    assert_eq!(config.mode, BlockingMode::Sinkhole);
+    assert_eq!(config.sinkhole.ipv4, Some("192.168.1.250".parse().unwrap()));
+    assert_eq!(config.sinkhole.ipv6, Some("fd00::250".parse().unwrap()));
+    assert_eq!(config.sinkhole.ttl, 10);
+    assert_eq!(config.sinkhole.telemetry.http_port, 8080);
+    assert_eq!(config.sinkhole.telemetry.https_port, 443);
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn test_sinkhole_mode_requires_an_address() {
+    let config = parse(
+        r#"
+        enabled = true
+        mode = "sinkhole"
+        "#,
+    );
+
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_sinkhole_telemetry_ports_must_differ() {
+    let config = parse(
+        r#"
+        enabled = true
+        mode = "sinkhole"
+
+        [sinkhole]
+        ipv4 = "10.0.0.250"
+
+        [sinkhole.telemetry]
+        enabled = true
+        http_port = 8443
+        https_port = 8443
+        "#,
+    );
+
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_index_snapshot_defaults_to_enabled() {
+    assert!(parse("enabled = true").index_snapshot);
+    assert!(BlockingConfig::default().index_snapshot);
+    assert!(!parse("enabled = true\nindex_snapshot = false").index_snapshot);
+}
+
+#[test]
+fn test_block_page_parses_group_overrides() {
+    let config = parse(
+        r#"
+        enabled = true
+        mode = "sinkhole"
+
+        [sinkhole]
+        ipv4 = "10.0.0.250"
+
+        [sinkhole.block_page]
+        enabled = true
+        ca_cert_path = "/tmp/block-page-ca.pem"
+        ca_key_path = "/tmp/block-page-ca.key"
+        contact = "it@example.com"
+
+        [[sinkhole.block_page.groups]]
+        group = "Kids"
+        message = "Ask a parent."
+        unblock_requests = false
+        "#,
+    );
+
+    let block_page = &config.sinkhole.block_page;
+    assert!(block_page.enabled);
+    assert!(block_page.https_enabled());
+    assert_eq!(block_page.title, "Site blocked");
+    assert!(block_page.unblock_requests);
+    assert_eq!(block_page.groups.len(), 1);
+    assert_eq!(block_page.groups[0].group, "Kids");
+    assert_eq!(
+        block_page.groups[0].message.as_deref(),
+        Some("Ask a parent.")
+    );
+    assert_eq!(block_page.groups[0].unblock_requests, Some(false));
+    assert!(block_page.groups[0].title.is_none());
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn test_block_page_needs_both_ca_paths() {
+    let config = parse(
+        r#"
+        enabled = true
+        mode = "sinkhole"
+
+        [sinkhole]
+        ipv4 = "10.0.0.250"
+
+        [sinkhole.block_page]
+        enabled = true
+        ca_cert_path = "/tmp/block-page-ca.pem"
+        "#,
+    );
+
+    assert!(!config.sinkhole.block_page.https_enabled());
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_block_page_http_port_must_not_clash_with_telemetry_https() {
+    let config = parse(
+        r#"
+        enabled = true
+        mode = "sinkhole"
+
+        [sinkhole]
+        ipv4 = "10.0.0.250"
+
+        [sinkhole.telemetry]
+        enabled = true
+        http_port = 8080
+        https_port = 8443
+
+        [sinkhole.block_page]
+        enabled = true
+        http_port = 8443
+        https_port = 9443
+        "#,
+    );
+
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_block_page_rejects_duplicate_groups() {
+    let config = parse(
+        r#"
+        enabled = true
+        mode = "sinkhole"
+
+        [sinkhole]
+        ipv4 = "10.0.0.250"
+
+        [sinkhole.block_page]
+        enabled = true
+
+        [[sinkhole.block_page.groups]]
+        group = "Kids"
+
+        [[sinkhole.block_page.groups]]
+        group = "Kids"
+        "#,
+    );
+
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_unblock_requests_public_endpoint_defaults_to_off() {
+    let config = parse("enabled = true");
+    assert!(!config.unblock_requests.public_endpoint);
+
+    let config = parse(
+        r#"
+        enabled = true
+
+        [unblock_requests]
+        public_endpoint = true
+        "#,
+    );
+    assert!(config.unblock_requests.public_endpoint);
+}
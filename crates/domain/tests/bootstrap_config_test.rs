@@ -0,0 +1,93 @@
+use ferrous_dns_domain::{BootstrapConfig, Config};
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+
+#[test]
+fn defaults_use_system_resolver_and_persist() {
+    let config = BootstrapConfig::default();
+    assert!(config.servers.is_empty());
+    assert!(config.pinned.is_empty());
+    assert!(config.persist);
+    assert_eq!(config.refresh_interval_secs, 3600);
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn server_addrs_default_to_port_53() {
+    let config = BootstrapConfig {
+        servers: vec!["9.9.9.9".into(), "[2620:fe::fe]:5353".into()],
+        ..Default::default()
+    };
+    assert!(config.validate().is_ok());
+    assert_eq!(
+        config.server_addrs(),
+        vec![
+            "9.9.9.9:53".parse::<SocketAddr>().unwrap(),
+            "[2620:fe::fe]:5353".parse::<SocketAddr>().unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn rejects_invalid_server() {
+    let config = BootstrapConfig {
+        servers: vec!["dns.quad9.net".into()],
+        ..Default::default()
+    };
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn rejects_invalid_or_empty_pins() {
+    let bad_addr = BootstrapConfig {
+        pinned: HashMap::from([("dns.quad9.net".into(), vec!["not-an-ip".into()])]),
+        ..Default::default()
+    };
+    assert!(bad_addr.validate().is_err());
+
+    let no_addrs = BootstrapConfig {
+        pinned: HashMap::from([("dns.quad9.net".into(), Vec::new())]),
+        ..Default::default()
+    };
+    assert!(no_addrs.validate().is_err());
+}
+
+#[test]
+fn pinned_hostnames_are_normalized() {
+    let config = BootstrapConfig {
+        pinned: HashMap::from([("DNS.Quad9.net.".into(), vec!["9.9.9.9".into()])]),
+        ..Default::default()
+    };
+    let pinned = config.pinned_addrs();
+    assert_eq!(
+        pinned.get("dns.quad9.net"),
+        Some(&vec!["9.9.9.9".parse::<IpAddr>().unwrap()])
+    );
+}
+
+#[test]
+fn parses_from_toml() {
+    let bootstrap: BootstrapConfig = toml::from_str(
+        r#"
+        servers = ["1.1.1.1"]
+        refresh_interval_secs = 600
+
+        [pinned]
+        "dns.quad9.net" = ["9.9.9.9", "149.112.112.112"]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(bootstrap.servers, vec!["1.1.1.1".to_string()]);
+    assert_eq!(bootstrap.refresh_interval_secs, 600);
+    assert!(bootstrap.persist);
+    assert_eq!(bootstrap.pinned["dns.quad9.net"].len(), 2);
+}
+
+#[test]
+fn config_validation_checks_bootstrap() {
+    let mut config = Config::default();
+    assert!(config.validate().is_ok());
+
+    config.dns.bootstrap.servers = vec!["not-an-ip".into()];
+    assert!(config.validate().is_err());
+}
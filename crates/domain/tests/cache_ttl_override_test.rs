@@ -0,0 +1,76 @@
+use ferrous_dns_domain::{CacheTtlOverride, TtlBounds, MAX_CACHE_TTL_OVERRIDE};
+use std::sync::Arc;
+
+fn ttl_override(domain: &str, min: Option<u32>, max: Option<u32>) -> CacheTtlOverride {
+    CacheTtlOverride::new(Arc::from(domain), min, max)
+}
+
+#[test]
+fn test_validate_domain() {
+    assert!(CacheTtlOverride::validate_domain("cdn.example.com").is_ok());
+    assert!(CacheTtlOverride::validate_domain("*.internal.corp").is_ok());
+    assert!(CacheTtlOverride::validate_domain("a.*.internal.corp").is_err());
+    assert!(CacheTtlOverride::validate_domain("*.").is_err());
+    assert!(CacheTtlOverride::validate_domain("example.com.").is_err());
+    assert!(CacheTtlOverride::validate_domain("bad domain.com").is_err());
+    assert!(CacheTtlOverride::validate_domain("").is_err());
+}
+
+#[test]
+fn test_validate_requires_a_bound() {
+    assert!(ttl_override("example.com", None, None).validate().is_err());
+    assert!(ttl_override("example.com", Some(600), None)
+        .validate()
+        .is_ok());
+    assert!(ttl_override("example.com", None, Some(30))
+        .validate()
+        .is_ok());
+}
+
+#[test]
+fn test_validate_rejects_inverted_or_oversized_bounds() {
+    assert!(ttl_override("example.com", Some(60), Some(30))
+        .validate()
+        .is_err());
+    assert!(ttl_override("example.com", Some(30), Some(30))
+        .validate()
+        .is_ok());
+    assert!(
+        ttl_override("example.com", Some(MAX_CACHE_TTL_OVERRIDE + 1), None)
+            .validate()
+            .is_err()
+    );
+}
+
+#[test]
+fn test_is_wildcard() {
+    assert!(ttl_override("*.internal.corp", None, Some(30)).is_wildcard());
+    assert!(!ttl_override("internal.corp", None, Some(30)).is_wildcard());
+}
+
+#[test]
+fn test_bounds_clamp_uses_defaults_for_unset_bounds() {
+    let cap = TtlBounds {
+        min_ttl: None,
+        max_ttl: Some(30),
+    };
+    assert_eq!(cap.clamp(300, 0, 86_400), 30);
+    assert_eq!(cap.clamp(10, 0, 86_400), 10);
+    assert_eq!(cap.clamp(0, 5, 86_400), 5);
+
+    let floor = TtlBounds {
+        min_ttl: Some(600),
+        max_ttl: None,
+    };
+    assert_eq!(floor.clamp(60, 0, 86_400), 600);
+    assert_eq!(floor.clamp(90_000, 0, 86_400), 86_400);
+}
+
+#[test]
+fn test_bounds_clamp_forced_minimum_beats_lower_default_maximum() {
+    let floor = TtlBounds {
+        min_ttl: Some(7_200),
+        max_ttl: None,
+    };
+    assert_eq!(floor.clamp(60, 0, 3_600), 7_200);
+}
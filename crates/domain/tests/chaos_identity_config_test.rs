@@ -0,0 +1,19 @@
+use ferrous_dns_domain::{ChaosIdentityConfig, DnsConfig};
+
+#[test]
+fn disabled_by_default() {
+    let config: DnsConfig = toml::from_str("").unwrap();
+    assert!(!config.chaos_identity.enabled);
+    assert!(config.chaos_identity.version.is_none());
+    assert!(config.chaos_identity.validate().is_ok());
+}
+
+#[test]
+fn rejects_values_longer_than_a_txt_string() {
+    let config = ChaosIdentityConfig {
+        enabled: true,
+        hostname: Some("h".repeat(256)),
+        ..Default::default()
+    };
+    assert!(config.validate().is_err());
+}
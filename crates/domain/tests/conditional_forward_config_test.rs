@@ -0,0 +1,111 @@
+use ferrous_dns_domain::{ConditionalForwardConfig, Config, DnsConfig};
+use std::net::SocketAddr;
+
+fn forward(zone: &str, servers: &[&str]) -> ConditionalForwardConfig {
+    ConditionalForwardConfig {
+        zone: zone.to_string(),
+        servers: servers.iter().map(|s| s.to_string()).collect(),
+    }
+}
+
+#[test]
+fn test_no_forwards_by_default() {
+    assert!(DnsConfig::default().conditional_forwards.is_empty());
+}
+
+#[test]
+fn test_forwards_parse_from_toml() {
+    let config: DnsConfig = toml::from_str(
+        r#"
+        [[conditional_forwards]]
+        zone = "168.192.In-Addr.Arpa."
+        servers = ["192.168.1.1", "192.168.1.2:5353"]
+        "#,
+    )
+    .unwrap();
+
+    let rule = &config.conditional_forwards[0];
+    assert_eq!(rule.normalized_zone(), "168.192.in-addr.arpa");
+    assert_eq!(
+        rule.server_addrs().unwrap(),
+        vec![
+            "192.168.1.1:53".parse::<SocketAddr>().unwrap(),
+            "192.168.1.2:5353".parse::<SocketAddr>().unwrap(),
+        ]
+    );
+    assert!(rule.validate().is_ok());
+}
+
+#[test]
+fn test_ipv6_servers_default_to_port_53() {
+    let rule = forward("lan", &["fd00::1", "[fd00::2]:5300"]);
+
+    assert_eq!(
+        rule.server_addrs().unwrap(),
+        vec![
+            "[fd00::1]:53".parse::<SocketAddr>().unwrap(),
+            "[fd00::2]:5300".parse::<SocketAddr>().unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn test_valid_reverse_zones() {
+    for zone in [
+        "10.in-addr.arpa",
+        "0.168.192.in-addr.arpa",
+        "42.1.168.192.in-addr.arpa",
+        "d.f.ip6.arpa",
+        "8.b.d.0.1.0.0.2.ip6.arpa",
+        "corp.internal",
+    ] {
+        assert!(
+            forward(zone, &["10.0.0.1"]).validate().is_ok(),
+            "{zone} should be valid"
+        );
+    }
+}
+
+#[test]
+fn test_invalid_reverse_zones() {
+    for zone in [
+        "in-addr.arpa",
+        "ip6.arpa",
+        "256.168.192.in-addr.arpa",
+        "01.168.192.in-addr.arpa",
+        "0/24.168.192.in-addr.arpa",
+        "1.2.3.4.5.in-addr.arpa",
+        "fd.ip6.arpa",
+        "g.ip6.arpa",
+        "",
+        "bad..zone",
+    ] {
+        assert!(
+            forward(zone, &["10.0.0.1"]).validate().is_err(),
+            "{zone} should be rejected"
+        );
+    }
+}
+
+#[test]
+fn test_servers_are_required_and_checked() {
+    assert!(forward("lan", &[]).validate().is_err());
+    assert!(forward("lan", &["router.lan"]).validate().is_err());
+    assert!(forward("lan", &["udp://10.0.0.1"]).validate().is_err());
+}
+
+#[test]
+fn test_config_validation_rejects_duplicate_zones() {
+    let mut config = Config::default();
+    config.dns.conditional_forwards = vec![
+        forward("168.192.in-addr.arpa", &["192.168.1.1"]),
+        forward("1.168.192.in-addr.arpa", &["192.168.1.1"]),
+    ];
+    assert!(config.validate().is_ok());
+
+    config
+        .dns
+        .conditional_forwards
+        .push(forward("168.192.IN-ADDR.arpa.", &["192.168.1.2"]));
+    assert!(config.validate().is_err());
+}
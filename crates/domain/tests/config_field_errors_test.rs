@@ -0,0 +1,117 @@
+use ferrous_dns_domain::{Config, UdpSocketMode, UpstreamPool, UpstreamStrategy};
+
+fn pool(name: &str, servers: &[&str]) -> UpstreamPool {
+    UpstreamPool {
+        name: name.to_string(),
+        strategy: UpstreamStrategy::Parallel,
+        priority: 1,
+        servers: servers.iter().map(|s| s.to_string()).collect(),
+        weight: None,
+        tsig_key: None,
+        udp_socket: UdpSocketMode::Pooled,
+    }
+}
+
+fn fields(config: &Config) -> Vec<String> {
+    config.field_errors().into_iter().map(|e| e.field).collect()
+}
+
+#[test]
+fn test_default_config_has_no_field_errors() {
+    assert!(Config::default().field_errors().is_empty());
+}
+
+#[test]
+fn test_refresh_threshold_must_be_below_one() {
+    let mut config = Config::default();
+    config.dns.cache_refresh_threshold = 1.0;
+    assert_eq!(fields(&config), vec!["dns.cache_refresh_threshold"]);
+
+    config.dns.cache_refresh_threshold = 0.9;
+    assert!(config.field_errors().is_empty());
+}
+
+#[test]
+fn test_reports_every_invalid_field() {
+    let mut config = Config::default();
+    config.dns.cache_refresh_threshold = 1.5;
+    config.dns.cache_min_ttl = 600;
+    config.dns.cache_max_ttl = 60;
+    config.dns.cache_eviction_strategy = "lru".to_string();
+
+    assert_eq!(
+        fields(&config),
+        vec![
+            "dns.cache_refresh_threshold",
+            "dns.cache_min_ttl",
+            "dns.cache_eviction_strategy",
+        ]
+    );
+}
+
+#[test]
+fn test_pool_needs_servers_and_unique_name() {
+    let mut config = Config::default();
+    config.dns.pools = vec![
+        pool("main", &["8.8.8.8:53"]),
+        pool("main", &["1.1.1.1:53"]),
+        pool("empty", &[]),
+    ];
+
+    assert_eq!(
+        fields(&config),
+        vec!["dns.pools[1].name", "dns.pools[2].servers"]
+    );
+}
+
+#[test]
+fn test_pool_server_must_parse() {
+    let mut config = Config::default();
+    config.dns.pools = vec![pool("main", &["8.8.8.8:53", "not a server"])];
+
+    let errors = config.field_errors();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].field, "dns.pools[0].servers[1]");
+}
+
+#[test]
+fn test_encrypted_server_needs_hostname() {
+    let mut config = Config::default();
+    config.dns.pools = vec![pool(
+        "secure",
+        &["tls://dns.google:853", "tls://1.1.1.1:853", "tls://:853"],
+    )];
+
+    let errors = config.field_errors();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].field, "dns.pools[0].servers[2]");
+    assert!(errors[0].message.contains("TLS"));
+}
+
+#[test]
+fn test_web_tls_needs_certificate_paths() {
+    let mut config = Config::default();
+    config.server.web_tls.enabled = true;
+    config.server.web_tls.tls_cert_path = String::new();
+    config.server.web_tls.tls_key_path = " ".to_string();
+
+    assert_eq!(
+        fields(&config),
+        vec![
+            "server.web_tls.tls_cert_path",
+            "server.web_tls.tls_key_path"
+        ]
+    );
+}
+
+#[test]
+fn test_field_error_displays_field_and_message() {
+    let mut config = Config::default();
+    config.auth.session_ttl_hours = 0;
+
+    let errors = config.field_errors();
+    assert_eq!(
+        errors[0].to_string(),
+        "auth.session_ttl_hours: must be at least 1"
+    );
+}
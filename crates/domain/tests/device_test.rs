@@ -0,0 +1,23 @@
+use ferrous_dns_domain::Device;
+
+#[test]
+fn test_normalize_mac_lowercases_and_uses_colons() {
+    assert_eq!(
+        Device::normalize_mac("AA-BB-CC-0D-0E-0F"),
+        Some("aa:bb:cc:0d:0e:0f".to_string())
+    );
+}
+
+#[test]
+fn test_normalize_mac_rejects_incomplete_entries() {
+    assert_eq!(Device::normalize_mac("00:00:00:00:00:00"), None);
+    assert_eq!(Device::normalize_mac("ff:ff:ff:ff:ff:ff"), None);
+}
+
+#[test]
+fn test_normalize_mac_rejects_malformed() {
+    assert_eq!(Device::normalize_mac(""), None);
+    assert_eq!(Device::normalize_mac("aa:bb:cc:dd:ee"), None);
+    assert_eq!(Device::normalize_mac("aa:bb:cc:dd:ee:zz"), None);
+    assert_eq!(Device::normalize_mac("aabb:cc:dd:ee:ff"), None);
+}
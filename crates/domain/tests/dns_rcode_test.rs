@@ -0,0 +1,63 @@
+use ferrous_dns_domain::{BlockSource, DnsRcode, DomainError};
+
+#[test]
+fn test_nxdomain_errors_map_to_nxdomain() {
+    assert_eq!(DomainError::NxDomain.rcode(), DnsRcode::NxDomain);
+    assert_eq!(DomainError::LocalNxDomain.rcode(), DnsRcode::NxDomain);
+}
+
+#[test]
+fn test_policy_errors_map_to_refused() {
+    for err in [
+        DomainError::Blocked(BlockSource::Blocklist),
+        DomainError::DgaDomainDetected,
+        DomainError::DnsTunnelingDetected,
+        DomainError::DnsRebindingBlocked,
+        DomainError::DnsRateLimited,
+        DomainError::DnsCookieInvalid,
+        DomainError::FilteredQuery("type ANY".into()),
+    ] {
+        assert_eq!(err.rcode(), DnsRcode::Refused, "{err}");
+    }
+}
+
+#[test]
+fn test_query_errors_map_to_formerr_and_notimp() {
+    assert_eq!(
+        DomainError::MalformedQuery("no question".into()).rcode(),
+        DnsRcode::FormErr
+    );
+    assert_eq!(
+        DomainError::InvalidDomainName("a..b".into()).rcode(),
+        DnsRcode::FormErr
+    );
+    assert_eq!(
+        DomainError::UnsupportedQuery("opcode Update".into()).rcode(),
+        DnsRcode::NotImp
+    );
+}
+
+#[test]
+fn test_server_side_errors_map_to_servfail() {
+    for err in [
+        DomainError::QueryTimeout,
+        DomainError::TransportAllServersUnreachable,
+        DomainError::TransportNoHealthyServers,
+        DomainError::InvalidDnsResponse("bad header".into()),
+        DomainError::DnssecValidationFailed("bogus".into()),
+        DomainError::DatabaseError("locked".into()),
+        DomainError::ConfigError("no pools".into()),
+    ] {
+        assert_eq!(err.rcode(), DnsRcode::ServFail, "{err}");
+    }
+}
+
+#[test]
+fn test_rcode_wire_values() {
+    assert_eq!(DnsRcode::FormErr.code(), 1);
+    assert_eq!(DnsRcode::ServFail.code(), 2);
+    assert_eq!(DnsRcode::NxDomain.code(), 3);
+    assert_eq!(DnsRcode::NotImp.code(), 4);
+    assert_eq!(DnsRcode::Refused.code(), 5);
+    assert_eq!(DnsRcode::NotImp.as_str(), "NOTIMP");
+}
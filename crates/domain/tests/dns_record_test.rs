@@ -125,3 +125,11 @@ fn test_record_category_consistency() {
     strings.dedup();
     assert_eq!(strings.len(), categories.len());
 }
+
+#[test]
+fn test_any_record_type_round_trips() {
+    assert_eq!("any".parse::<RecordType>().unwrap(), RecordType::ANY);
+    assert_eq!(RecordType::ANY.to_u16(), 255);
+    assert_eq!(RecordType::from_u16(255), Some(RecordType::ANY));
+    assert_eq!(RecordType::ANY.category(), RecordCategory::Protocol);
+}
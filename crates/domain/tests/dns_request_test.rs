@@ -0,0 +1,26 @@
+use ferrous_dns_domain::DnsRequest;
+use std::borrow::Cow;
+
+#[test]
+fn test_normalize_domain_borrows_canonical_names() {
+    assert!(matches!(
+        DnsRequest::normalize_domain("example.com"),
+        Cow::Borrowed("example.com")
+    ));
+    assert!(matches!(
+        DnsRequest::normalize_domain("example.com."),
+        Cow::Borrowed("example.com")
+    ));
+}
+
+#[test]
+fn test_normalize_domain_lowercases_ascii() {
+    assert_eq!(
+        DnsRequest::normalize_domain("WWW.Example.COM."),
+        "www.example.com"
+    );
+    assert_eq!(
+        DnsRequest::normalize_domain("xn--BCHER-kva.example"),
+        "xn--bcher-kva.example"
+    );
+}
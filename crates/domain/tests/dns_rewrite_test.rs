@@ -0,0 +1,131 @@
+use ferrous_dns_domain::{DnsRewrite, DnsRewriteMatcher, RewriteAnswer, RewriteTarget};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+fn ip(s: &str) -> IpAddr {
+    s.parse().unwrap()
+}
+
+fn rewrite(id: i64, domain: &str, answer: &str) -> DnsRewrite {
+    let mut r = DnsRewrite::new(Arc::from(domain), Arc::from(answer));
+    r.id = Some(id);
+    r
+}
+
+fn addresses(ips: &[&str]) -> RewriteTarget {
+    RewriteTarget::Addresses(ips.iter().map(|s| ip(s)).collect())
+}
+
+#[test]
+fn test_validate_domain() {
+    assert!(DnsRewrite::validate_domain("nas.lan").is_ok());
+    assert!(DnsRewrite::validate_domain("*.example.com").is_ok());
+    assert!(DnsRewrite::validate_domain("a.*.example.com").is_err());
+    assert!(DnsRewrite::validate_domain("*.").is_err());
+    assert!(DnsRewrite::validate_domain("bad domain.com").is_err());
+    assert!(DnsRewrite::validate_domain("").is_err());
+}
+
+#[test]
+fn test_validate_answer() {
+    assert!(DnsRewrite::validate_answer("192.168.1.10").is_ok());
+    assert!(DnsRewrite::validate_answer("fd00::1").is_ok());
+    assert!(DnsRewrite::validate_answer("cdn.example.net").is_ok());
+    assert!(DnsRewrite::validate_answer("cdn.example.net.").is_ok());
+    assert!(DnsRewrite::validate_answer("").is_err());
+    assert!(DnsRewrite::validate_answer("not a host").is_err());
+    assert!(DnsRewrite::validate_answer("a..b").is_err());
+}
+
+#[test]
+fn test_validate_rejects_self_reference() {
+    let r = DnsRewrite::new(Arc::from("example.com"), Arc::from("EXAMPLE.com."));
+    assert!(r.validate().unwrap_err().contains("itself"));
+}
+
+#[test]
+fn test_answer_parse() {
+    assert_eq!(
+        RewriteAnswer::parse("10.0.0.1"),
+        RewriteAnswer::Address(ip("10.0.0.1"))
+    );
+    assert_eq!(
+        RewriteAnswer::parse("Target.Example.NET."),
+        RewriteAnswer::Cname(Arc::from("target.example.net"))
+    );
+}
+
+#[test]
+fn test_empty_matcher() {
+    let m = DnsRewriteMatcher::new(vec![]);
+    assert!(m.is_empty());
+    assert_eq!(m.lookup("example.com"), None);
+}
+
+#[test]
+fn test_exact_match_is_case_insensitive() {
+    let m = DnsRewriteMatcher::new(vec![rewrite(1, "nas.lan", "192.168.1.10")]);
+    assert_eq!(m.lookup("NAS.lan"), Some(addresses(&["192.168.1.10"])));
+    assert_eq!(m.lookup("nas.lan."), Some(addresses(&["192.168.1.10"])));
+    assert_eq!(m.lookup("other.lan"), None);
+}
+
+#[test]
+fn test_wildcard_matches_subdomains_only() {
+    let m = DnsRewriteMatcher::new(vec![rewrite(1, "*.example.com", "10.0.0.1")]);
+    assert_eq!(m.lookup("a.example.com"), Some(addresses(&["10.0.0.1"])));
+    assert_eq!(m.lookup("a.b.example.com"), Some(addresses(&["10.0.0.1"])));
+    assert_eq!(m.lookup("example.com"), None);
+    assert_eq!(m.lookup("badexample.com"), None);
+}
+
+#[test]
+fn test_exact_beats_wildcard_and_longest_wildcard_wins() {
+    let m = DnsRewriteMatcher::new(vec![
+        rewrite(1, "*.example.com", "10.0.0.1"),
+        rewrite(2, "*.lab.example.com", "10.0.0.2"),
+        rewrite(3, "host.lab.example.com", "10.0.0.3"),
+    ]);
+    assert_eq!(m.lookup("x.example.com"), Some(addresses(&["10.0.0.1"])));
+    assert_eq!(
+        m.lookup("x.lab.example.com"),
+        Some(addresses(&["10.0.0.2"]))
+    );
+    assert_eq!(
+        m.lookup("host.lab.example.com"),
+        Some(addresses(&["10.0.0.3"]))
+    );
+}
+
+#[test]
+fn test_multiple_addresses_are_combined() {
+    let m = DnsRewriteMatcher::new(vec![
+        rewrite(1, "nas.lan", "192.168.1.10"),
+        rewrite(2, "nas.lan", "fd00::10"),
+        rewrite(3, "nas.lan", "192.168.1.11"),
+    ]);
+    assert_eq!(
+        m.lookup("nas.lan"),
+        Some(addresses(&["192.168.1.10", "fd00::10", "192.168.1.11"]))
+    );
+}
+
+#[test]
+fn test_cname_takes_precedence_over_addresses() {
+    let m = DnsRewriteMatcher::new(vec![
+        rewrite(1, "www.example.com", "10.0.0.1"),
+        rewrite(2, "www.example.com", "cdn.example.net"),
+    ]);
+    assert_eq!(
+        m.lookup("www.example.com"),
+        Some(RewriteTarget::Cname(Arc::from("cdn.example.net")))
+    );
+}
+
+#[test]
+fn test_disabled_rewrites_are_ignored() {
+    let mut r = rewrite(1, "nas.lan", "192.168.1.10");
+    r.enabled = false;
+    let m = DnsRewriteMatcher::new(vec![r]);
+    assert!(m.is_empty());
+}
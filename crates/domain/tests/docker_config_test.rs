@@ -0,0 +1,62 @@
+use ferrous_dns_domain::{DnsConfig, DockerConfig};
+
+#[test]
+fn test_docker_disabled_by_default() {
+    let config = DnsConfig::default().docker;
+
+    assert!(!config.enabled);
+    assert_eq!(config.domain, "docker.lan");
+    assert_eq!(config.socket, "/var/run/docker.sock");
+    assert!(config.networks.is_empty());
+    assert!(config.include_aliases);
+}
+
+#[test]
+fn test_docker_section_parses() {
+    let config: DnsConfig = toml::from_str(
+        r#"
+        [docker]
+        enabled = true
+        domain = "Containers.Home."
+        socket = "/run/user/1000/docker.sock"
+        networks = ["proxy"]
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(config.docker.normalized_domain(), "containers.home");
+    assert_eq!(config.docker.networks, ["proxy"]);
+    assert!(config.docker.validate().is_ok());
+}
+
+#[test]
+fn test_docker_socket_cannot_be_empty() {
+    let config = DockerConfig {
+        enabled: true,
+        socket: " ".to_string(),
+        ..Default::default()
+    };
+
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_docker_sync_interval_has_a_floor() {
+    let config = DockerConfig {
+        enabled: true,
+        sync_interval_secs: 1,
+        ..Default::default()
+    };
+
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_disabled_docker_is_not_validated() {
+    let config = DockerConfig {
+        domain: String::new(),
+        ..Default::default()
+    };
+
+    assert!(config.validate().is_ok());
+}
@@ -0,0 +1,97 @@
+use ferrous_dns_domain::{DnsConfig, EdnsClientIdConfig};
+
+fn enabled(forwarders: &[&str]) -> EdnsClientIdConfig {
+    EdnsClientIdConfig {
+        enabled: true,
+        forwarders: forwarders.iter().map(|f| f.to_string()).collect(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn disabled_by_default_with_dnsmasq_option() {
+    let config: DnsConfig = toml::from_str("").unwrap();
+    assert!(!config.edns_client_id.enabled);
+    assert_eq!(config.edns_client_id.option_codes, vec![65001]);
+    assert!(config.edns_client_id.validate().is_ok());
+}
+
+#[test]
+fn parses_from_toml() {
+    let config: DnsConfig = toml::from_str(
+        r#"
+        [edns_client_id]
+        enabled = true
+        option_codes = [65001, 65074]
+        forwarders = ["192.168.1.1/32"]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(config.edns_client_id.option_codes, vec![65001, 65074]);
+    assert!(config.edns_client_id.validate().is_ok());
+}
+
+#[test]
+fn requires_trusted_forwarders() {
+    assert!(enabled(&[]).validate().is_err());
+    assert!(enabled(&["not-a-cidr"]).validate().is_err());
+    assert!(enabled(&["10.0.0.1", "fd00::/64"]).validate().is_ok());
+}
+
+#[test]
+fn rejects_option_codes_used_by_the_server() {
+    let mut config = enabled(&["10.0.0.1"]);
+    config.option_codes = vec![65001, 10];
+    assert!(config.validate().is_err());
+
+    config.option_codes.clear();
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn parses_forwarder_labels() {
+    let config: DnsConfig = toml::from_str(
+        r#"
+        [edns_client_id]
+        enabled = true
+        forwarders = ["192.168.1.1", "10.8.0.0/24"]
+        use_client_subnet = false
+
+        [edns_client_id.forwarder_labels]
+        "192.168.1.1" = " Main router "
+        "10.8.0.2" = "VPN gateway"
+        "#,
+    )
+    .unwrap();
+    let edns_client_id = config.edns_client_id;
+    assert!(!edns_client_id.use_client_subnet);
+    assert!(edns_client_id.validate().is_ok());
+    assert_eq!(
+        edns_client_id.labelled_forwarders(),
+        vec![
+            ("10.8.0.2".parse().unwrap(), "VPN gateway".to_string()),
+            ("192.168.1.1".parse().unwrap(), "Main router".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn rejects_labels_for_unknown_or_invalid_forwarders() {
+    let mut config = enabled(&["192.168.1.1"]);
+    config
+        .forwarder_labels
+        .insert("192.168.1.0/24".to_string(), "LAN".to_string());
+    assert!(config.validate().is_err());
+
+    config.forwarder_labels.clear();
+    config
+        .forwarder_labels
+        .insert("192.168.1.2".to_string(), "Other".to_string());
+    assert!(config.validate().is_err());
+
+    config.forwarder_labels.clear();
+    config
+        .forwarder_labels
+        .insert("192.168.1.1".to_string(), "  ".to_string());
+    assert!(config.validate().is_err());
+}
@@ -73,6 +73,7 @@ impl QueryLogBuilder {
             record_type: self.record_type,
             client_ip: self.client_ip,
             client_hostname: None,
+            client_mac: None,
             blocked: self.blocked,
             response_time_us: self.response_time_us,
             cache_hit: self.cache_hit,
@@ -80,11 +81,16 @@ impl QueryLogBuilder {
             dnssec_status: None,
             upstream_server: None,
             upstream_pool: None,
+            upstream_strategy: None,
+            upstream_attempt: None,
+            upstream_protocol: None,
             response_status: None,
             timestamp: None,
             query_source: self.query_source,
             group_id: None,
+            group_name: None,
             block_source: self.block_source,
+            plugin: None,
         }
     }
 }
@@ -0,0 +1,64 @@
+use ferrous_dns_domain::{DnsConfig, KubernetesConfig};
+
+#[test]
+fn test_kubernetes_disabled_by_default() {
+    let config = DnsConfig::default().kubernetes;
+
+    assert!(!config.enabled);
+    assert_eq!(config.domain, "k8s.lan");
+    assert!(config.publish_services && config.publish_ingresses);
+    assert!(!config.include_cluster_ip);
+}
+
+#[test]
+fn test_kubernetes_section_parses() {
+    let config: DnsConfig = toml::from_str(
+        r#"
+        [kubernetes]
+        enabled = true
+        domain = ".Cluster.Home."
+        api_server = "https://192.168.1.10:6443"
+        token_file = "/etc/ferrous-dns/k8s-token"
+        namespaces = ["apps", "media"]
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(config.kubernetes.normalized_domain(), "cluster.home");
+    assert_eq!(config.kubernetes.namespaces, ["apps", "media"]);
+    assert!(config.kubernetes.validate().is_ok());
+}
+
+#[test]
+fn test_api_server_must_be_a_url() {
+    let config = KubernetesConfig {
+        enabled: true,
+        api_server: Some("192.168.1.10:6443".to_string()),
+        ..Default::default()
+    };
+
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_nothing_to_publish_is_rejected() {
+    let config = KubernetesConfig {
+        enabled: true,
+        publish_services: false,
+        publish_ingresses: false,
+        ..Default::default()
+    };
+
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_invalid_settings_ignored_while_disabled() {
+    let config = KubernetesConfig {
+        domain: String::new(),
+        sync_interval_secs: 0,
+        ..Default::default()
+    };
+
+    assert!(config.validate().is_ok());
+}
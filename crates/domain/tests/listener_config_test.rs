@@ -0,0 +1,110 @@
+use ferrous_dns_domain::config::{DnsListenerConfig, ServerConfig};
+use ferrous_dns_domain::{AclAction, Config};
+
+fn listener(bind: &str, allowed: &[&str]) -> DnsListenerConfig {
+    DnsListenerConfig {
+        bind: bind.to_string(),
+        allowed_clients: allowed.iter().map(|s| s.to_string()).collect(),
+        action: None,
+        default_group: None,
+    }
+}
+
+#[test]
+fn test_server_config_has_no_listeners_by_default() {
+    assert!(ServerConfig::default().listeners.is_empty());
+}
+
+#[test]
+fn test_listener_valid() {
+    assert!(listener("192.168.1.1:53", &["192.168.1.0/24"])
+        .validate()
+        .is_ok());
+    assert!(listener("[fd00::1]:53", &["fd00::/64"]).validate().is_ok());
+}
+
+#[test]
+fn test_listener_rejects_invalid_bind() {
+    assert!(listener("192.168.1.1", &[]).validate().is_err());
+    assert!(listener("lan:53", &[]).validate().is_err());
+}
+
+#[test]
+fn test_listener_rejects_invalid_cidr() {
+    assert!(listener("10.8.0.1:53", &["10.8.0.0/33"])
+        .validate()
+        .is_err());
+    assert!(listener("10.8.0.1:53", &["not-a-cidr"]).validate().is_err());
+}
+
+#[test]
+fn test_config_rejects_duplicate_listener_bind() {
+    let mut config = Config::default();
+    config.server.listeners = vec![
+        listener("10.8.0.1:53", &[]),
+        listener("10.8.0.1:53", &["10.8.0.0/24"]),
+    ];
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_config_accepts_multiple_listeners() {
+    let mut config = Config::default();
+    config.server.listeners = vec![
+        listener("192.168.1.1:53", &["192.168.1.0/24"]),
+        listener("10.8.0.1:53", &["10.8.0.0/24"]),
+    ];
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn test_listeners_deserialize_from_toml() {
+    let toml_str = r#"
+        dns_port = 53
+        web_port = 8080
+        bind_address = "0.0.0.0"
+
+        [[listeners]]
+        bind = "10.8.0.1:53"
+        allowed_clients = ["10.8.0.0/24"]
+        default_group = "VPN"
+    "#;
+    let server: ServerConfig = toml::from_str(toml_str).unwrap();
+    assert_eq!(server.listeners.len(), 1);
+    assert_eq!(server.listeners[0].bind, "10.8.0.1:53");
+    assert_eq!(server.listeners[0].default_group.as_deref(), Some("VPN"));
+}
+
+#[test]
+fn test_acl_defaults_to_refuse_everyone_allowed() {
+    let server = ServerConfig::default();
+    assert!(server.acl.allowed_clients.is_empty());
+    assert_eq!(server.acl.action, AclAction::Refuse);
+}
+
+#[test]
+fn test_config_rejects_invalid_acl_cidr() {
+    let mut config = Config::default();
+    config.server.acl.allowed_clients = vec!["192.168.1.0/40".to_string()];
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_acl_action_deserializes_from_toml() {
+    let toml_str = r#"
+        dns_port = 53
+        web_port = 8080
+        bind_address = "0.0.0.0"
+
+        [acl]
+        allowed_clients = ["192.168.0.0/16"]
+        action = "drop"
+
+        [[listeners]]
+        bind = "10.8.0.1:53"
+        action = "refuse"
+    "#;
+    let server: ServerConfig = toml::from_str(toml_str).unwrap();
+    assert_eq!(server.acl.action, AclAction::Drop);
+    assert_eq!(server.listeners[0].action, Some(AclAction::Refuse));
+}
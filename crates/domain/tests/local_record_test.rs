@@ -0,0 +1,292 @@
+use ferrous_dns_domain::{
+    LocalRecord, LocalRecordData, LocalZone, LocalZoneEntry, RecordType, MAX_LOCAL_RECORD_TTL,
+};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+fn record(hostname: &str, record_type: RecordType, value: &str) -> LocalRecord {
+    LocalRecord::new(Arc::from(hostname), record_type, Arc::from(value))
+}
+
+fn ip(s: &str) -> IpAddr {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_fqdn_prefers_record_domain_then_default() {
+    let mut r = record("NAS", RecordType::A, "10.0.0.1");
+    assert_eq!(r.fqdn(&None), "nas");
+    assert_eq!(r.fqdn(&Some("home.lan".to_string())), "nas.home.lan");
+
+    r.domain = Some(Arc::from("Lab.Local."));
+    assert_eq!(r.fqdn(&Some("home.lan".to_string())), "nas.lab.local");
+}
+
+#[test]
+fn test_parse_address_records_check_family() {
+    assert_eq!(
+        LocalRecordData::parse(RecordType::A, "10.0.0.1"),
+        Ok(LocalRecordData::Address(ip("10.0.0.1")))
+    );
+    assert!(LocalRecordData::parse(RecordType::A, "fd00::1").is_err());
+    assert!(LocalRecordData::parse(RecordType::AAAA, "10.0.0.1").is_err());
+    assert!(LocalRecordData::parse(RecordType::A, "nas.lan").is_err());
+}
+
+#[test]
+fn test_parse_cname_normalises_target() {
+    assert_eq!(
+        LocalRecordData::parse(RecordType::CNAME, "NAS.Home.Lan."),
+        Ok(LocalRecordData::Cname(Arc::from("nas.home.lan")))
+    );
+    assert!(LocalRecordData::parse(RecordType::CNAME, "not a host").is_err());
+}
+
+#[test]
+fn test_parse_txt_bounds() {
+    assert!(LocalRecordData::parse(RecordType::TXT, "v=spf1 -all").is_ok());
+    assert!(LocalRecordData::parse(RecordType::TXT, "").is_err());
+    assert!(LocalRecordData::parse(RecordType::TXT, &"x".repeat(4001)).is_err());
+}
+
+#[test]
+fn test_parse_mx() {
+    assert_eq!(
+        LocalRecordData::parse(RecordType::MX, "10 Mail.Home.Lan"),
+        Ok(LocalRecordData::Mx {
+            preference: 10,
+            exchange: Arc::from("mail.home.lan"),
+        })
+    );
+    assert!(LocalRecordData::parse(RecordType::MX, "mail.home.lan").is_err());
+    assert!(LocalRecordData::parse(RecordType::MX, "70000 mail.home.lan").is_err());
+}
+
+#[test]
+fn test_parse_srv() {
+    assert_eq!(
+        LocalRecordData::parse(RecordType::SRV, "0 5 5060 sip.home.lan"),
+        Ok(LocalRecordData::Srv {
+            priority: 0,
+            weight: 5,
+            port: 5060,
+            target: Arc::from("sip.home.lan"),
+        })
+    );
+    assert!(LocalRecordData::parse(RecordType::SRV, "0 5 sip.home.lan").is_err());
+}
+
+#[test]
+fn test_parse_rejects_unsupported_type() {
+    assert!(LocalRecordData::parse(RecordType::PTR, "nas.home.lan").is_err());
+}
+
+#[test]
+fn test_validate() {
+    assert!(record("nas", RecordType::A, "10.0.0.1").validate().is_ok());
+    assert!(record("_sip._tcp", RecordType::SRV, "0 5 5060 sip.lan")
+        .validate()
+        .is_ok());
+    assert!(record("bad host", RecordType::A, "10.0.0.1")
+        .validate()
+        .is_err());
+    assert!(record("nas", RecordType::A, "bogus").validate().is_err());
+
+    let mut r = record("nas", RecordType::A, "10.0.0.1");
+    r.ttl = MAX_LOCAL_RECORD_TTL + 1;
+    assert!(r.validate().is_err());
+}
+
+#[test]
+fn test_validate_view_only_for_address_records() {
+    let mut a = record("nas", RecordType::A, "10.0.0.1");
+    a.view = Some(Arc::from("vpn"));
+    assert!(a.validate().is_ok());
+
+    let mut txt = record("nas", RecordType::TXT, "hello");
+    txt.view = Some(Arc::from("vpn"));
+    assert!(txt.validate().is_err());
+}
+
+#[test]
+fn test_to_config_record() {
+    let mut r = record("nas", RecordType::AAAA, "fd00::10");
+    r.domain = Some(Arc::from("home.lan"));
+    r.ttl = 60;
+    r.view = Some(Arc::from("vpn"));
+
+    let config = r.to_config_record();
+    assert_eq!(config.hostname, "nas");
+    assert_eq!(config.domain.as_deref(), Some("home.lan"));
+    assert_eq!(config.ip, "fd00::10");
+    assert_eq!(config.record_type, "AAAA");
+    assert_eq!(config.ttl, Some(60));
+    assert_eq!(config.view.as_deref(), Some("vpn"));
+}
+
+#[test]
+fn test_zone_groups_records_by_fqdn() {
+    let records = vec![
+        record("nas", RecordType::A, "10.0.0.1"),
+        record("nas", RecordType::AAAA, "fd00::1"),
+        record("nas", RecordType::TXT, "storage"),
+        record("files", RecordType::CNAME, "nas.home.lan"),
+    ];
+    let zone = LocalZone::new(&records, &Some("home.lan".to_string()));
+
+    assert_eq!(zone.len(), 4);
+    assert_eq!(zone.lookup("nas.home.lan").unwrap().len(), 3);
+    assert_eq!(zone.lookup("NAS.Home.Lan.").unwrap().len(), 3);
+    assert_eq!(zone.lookup("files.home.lan").unwrap().len(), 1);
+    assert!(zone.lookup("other.home.lan").is_none());
+}
+
+#[test]
+fn test_zone_skips_view_and_invalid_records() {
+    let mut view = record("nas", RecordType::A, "10.8.0.1");
+    view.view = Some(Arc::from("vpn"));
+    let records = vec![view, record("broken", RecordType::A, "bogus")];
+    let zone = LocalZone::new(&records, &None);
+
+    assert!(zone.is_empty());
+    assert!(zone.lookup("nas").is_none());
+}
+
+#[test]
+fn test_empty_zone() {
+    let zone = LocalZone::empty();
+    assert!(zone.is_empty());
+    assert_eq!(zone.len(), 0);
+    assert!(zone.lookup("nas").is_none());
+}
+
+fn addresses(zone: &LocalZone, domain: &str) -> Vec<IpAddr> {
+    zone.lookup(domain)
+        .map(|entries| {
+            entries
+                .iter()
+                .filter_map(|e: &LocalZoneEntry| match e.data {
+                    LocalRecordData::Address(ip) => Some(ip),
+                    _ => None,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[test]
+fn test_wildcard_hostnames_validate() {
+    assert!(record("*", RecordType::A, "127.0.0.1").validate().is_ok());
+    assert!(record("*.dev", RecordType::A, "127.0.0.1")
+        .validate()
+        .is_ok());
+    assert!(record("a.*", RecordType::A, "127.0.0.1")
+        .validate()
+        .is_err());
+    assert!(record("*app", RecordType::A, "127.0.0.1")
+        .validate()
+        .is_err());
+
+    let mut in_view = record("*", RecordType::A, "10.8.0.1");
+    in_view.view = Some(Arc::from("vpn"));
+    assert!(in_view.validate().is_err());
+}
+
+#[test]
+fn test_ip_template_requires_wildcard_and_address_type() {
+    assert_eq!(
+        LocalRecordData::parse(RecordType::AAAA, "{ip}"),
+        Ok(LocalRecordData::EmbeddedAddress { ipv6: true })
+    );
+    assert!(LocalRecordData::parse(RecordType::TXT, "{ip}").is_ok());
+    assert!(record("*", RecordType::A, "{ip}").validate().is_ok());
+    assert!(record("nas", RecordType::A, "{ip}").validate().is_err());
+}
+
+#[test]
+fn test_ptr_address_skips_wildcards() {
+    assert_eq!(
+        record("nas", RecordType::A, "10.0.0.1").ptr_address(),
+        Some(ip("10.0.0.1"))
+    );
+    assert_eq!(record("*", RecordType::A, "10.0.0.1").ptr_address(), None);
+    assert_eq!(record("www", RecordType::CNAME, "nas").ptr_address(), None);
+}
+
+#[test]
+fn test_zone_wildcard_matches_names_below_it() {
+    let mut wildcard = record("*", RecordType::A, "127.0.0.1");
+    wildcard.domain = Some(Arc::from("dev.lan"));
+    let records = vec![wildcard, record("api.dev.lan", RecordType::A, "10.0.0.9")];
+    let zone = LocalZone::new(&records, &None);
+
+    assert_eq!(zone.len(), 2);
+    assert_eq!(addresses(&zone, "web.dev.lan"), vec![ip("127.0.0.1")]);
+    assert_eq!(addresses(&zone, "A.B.Dev.Lan."), vec![ip("127.0.0.1")]);
+    assert_eq!(addresses(&zone, "api.dev.lan"), vec![ip("10.0.0.9")]);
+    assert!(zone.lookup("dev.lan").is_none());
+    assert!(zone.lookup("web.prod.lan").is_none());
+}
+
+#[test]
+fn test_zone_closest_wildcard_wins() {
+    let records = vec![
+        record("*.lan", RecordType::A, "10.0.0.1"),
+        record("*.dev.lan", RecordType::A, "10.0.0.2"),
+    ];
+    let zone = LocalZone::new(&records, &None);
+
+    assert_eq!(addresses(&zone, "web.dev.lan"), vec![ip("10.0.0.2")]);
+    assert_eq!(addresses(&zone, "web.lan"), vec![ip("10.0.0.1")]);
+}
+
+#[test]
+fn test_zone_skips_bare_wildcard_without_domain() {
+    let zone = LocalZone::new(&[record("*", RecordType::A, "127.0.0.1")], &None);
+    assert!(zone.is_empty());
+}
+
+#[test]
+fn test_zone_decodes_embedded_ipv4() {
+    let zone = LocalZone::new(&[record("*.nip.lan", RecordType::A, "{ip}")], &None);
+
+    assert_eq!(addresses(&zone, "10.0.0.5.nip.lan"), vec![ip("10.0.0.5")]);
+    assert_eq!(
+        addresses(&zone, "app.10.0.0.5.nip.lan"),
+        vec![ip("10.0.0.5")]
+    );
+    assert_eq!(
+        addresses(&zone, "192-168-1-20.nip.lan"),
+        vec![ip("192.168.1.20")]
+    );
+    assert_eq!(
+        addresses(&zone, "app-192-168-1-20.nip.lan"),
+        vec![ip("192.168.1.20")]
+    );
+    assert!(zone.lookup("app.nip.lan").is_none());
+    assert!(zone.lookup("10.0.0.300.nip.lan").is_none());
+}
+
+#[test]
+fn test_zone_decodes_embedded_ipv6() {
+    let zone = LocalZone::new(&[record("*.nip.lan", RecordType::AAAA, "{ip}")], &None);
+
+    assert_eq!(addresses(&zone, "fd00--1.nip.lan"), vec![ip("fd00::1")]);
+    assert_eq!(
+        addresses(&zone, "app.2001-db8--5.nip.lan"),
+        vec![ip("2001:db8::5")]
+    );
+    assert!(zone.lookup("10-0-0-5.nip.lan").is_none());
+}
+
+#[test]
+fn test_zone_template_keeps_other_wildcard_records() {
+    let records = vec![
+        record("*.nip.lan", RecordType::A, "{ip}"),
+        record("*.nip.lan", RecordType::TXT, "lab"),
+    ];
+    let zone = LocalZone::new(&records, &None);
+
+    assert_eq!(zone.lookup("10.0.0.5.nip.lan").unwrap().len(), 2);
+    assert_eq!(zone.lookup("app.nip.lan").unwrap().len(), 1);
+}
@@ -0,0 +1,221 @@
+use ferrous_dns_domain::{LogFormat, LoggingConfig, MetricsExportBackend};
+
+fn parse(toml: &str) -> LoggingConfig {
+    toml::from_str(toml).unwrap()
+}
+
+#[test]
+fn test_otel_disabled_by_default() {
+    let config = parse(r#"level = "debug""#);
+
+    assert_eq!(config.level, "debug");
+    assert!(!config.otel.enabled);
+    assert_eq!(config.otel.endpoint, "http://localhost:4318/v1/traces");
+    assert_eq!(config.otel.service_name, "ferrous-dns");
+    assert_eq!(config.otel.sample_ratio, 1.0);
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn test_otel_section_parses() {
+    let config = parse(
+        r#"
+        level = "info"
+
+        [otel]
+        enabled = true
+        endpoint = "http://collector:4318/v1/traces"
+        service_name = "dns-edge"
+        sample_ratio = 0.25
+        "#,
+    );
+
+    assert!(config.otel.enabled);
+    assert_eq!(config.otel.endpoint, "http://collector:4318/v1/traces");
+    assert_eq!(config.otel.service_name, "dns-edge");
+    assert_eq!(config.otel.sample_ratio, 0.25);
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn test_otel_sample_ratio_out_of_range_is_rejected() {
+    let config = parse(
+        r#"
+        [otel]
+        enabled = true
+        sample_ratio = 1.5
+        "#,
+    );
+
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_otel_empty_endpoint_is_rejected() {
+    let config = parse(
+        r#"
+        [otel]
+        enabled = true
+        endpoint = ""
+        "#,
+    );
+
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_otel_settings_ignored_when_disabled() {
+    let config = parse(
+        r#"
+        [otel]
+        sample_ratio = 7.0
+        "#,
+    );
+
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn test_slow_queries_disabled_by_default() {
+    let config = parse(r#"level = "info""#);
+
+    assert!(!config.slow_queries.enabled);
+    assert_eq!(config.slow_queries.threshold_ms, 500);
+    assert_eq!(config.slow_queries.capacity, 1000);
+}
+
+#[test]
+fn test_slow_queries_section_parses() {
+    let config = parse(
+        r#"
+        [slow_queries]
+        enabled = true
+        threshold_ms = 250
+        capacity = 200
+        "#,
+    );
+
+    assert!(config.slow_queries.enabled);
+    assert_eq!(config.slow_queries.threshold_ms, 250);
+    assert_eq!(config.slow_queries.capacity, 200);
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn test_slow_queries_zero_capacity_is_rejected() {
+    let config = parse(
+        r#"
+        [slow_queries]
+        enabled = true
+        capacity = 0
+        "#,
+    );
+
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_text_format_and_no_module_overrides_by_default() {
+    let config = parse(r#"level = "info""#);
+
+    assert_eq!(config.format, LogFormat::Text);
+    assert!(config.modules.is_empty());
+}
+
+#[test]
+fn test_json_format_and_module_overrides_parse() {
+    let config = parse(
+        r#"
+        level = "warn"
+        format = "json"
+
+        [modules]
+        "dns::cache" = "debug"
+        "ferrous_dns_api" = "TRACE"
+        "#,
+    );
+
+    assert_eq!(config.format, LogFormat::Json);
+    assert_eq!(config.modules["dns::cache"], "debug");
+    assert_eq!(config.modules["ferrous_dns_api"], "TRACE");
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn test_unknown_module_level_is_rejected() {
+    let config = parse(
+        r#"
+        [modules]
+        "dns::cache" = "verbose"
+        "#,
+    );
+
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_unknown_level_is_rejected() {
+    let config = parse(r#"level = "loud""#);
+
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_metrics_export_disabled_by_default() {
+    let config = parse(r#"level = "info""#);
+
+    assert!(!config.metrics_export.enabled);
+    assert_eq!(config.metrics_export.interval_secs, 60);
+    assert_eq!(config.metrics_export.measurement, "ferrous_dns");
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn test_metrics_export_parses_backend_and_tags() {
+    let config = parse(
+        r#"
+        [metrics_export]
+        enabled = true
+        backend = "victoriametrics"
+        url = "http://victoria:8428/write"
+        token = "secret"
+
+        [metrics_export.tags]
+        host = "pi"
+        "#,
+    );
+
+    assert_eq!(
+        config.metrics_export.backend,
+        MetricsExportBackend::Victoriametrics
+    );
+    assert_eq!(config.metrics_export.tags["host"], "pi");
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn test_enabled_metrics_export_requires_http_url() {
+    let config = parse(
+        r#"
+        [metrics_export]
+        enabled = true
+        url = "victoria:8428/write"
+        "#,
+    );
+
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_metrics_export_interval_below_ten_seconds_is_rejected() {
+    let config = parse(
+        r#"
+        [metrics_export]
+        enabled = true
+        url = "http://influx:8086/api/v2/write?org=home&bucket=dns"
+        interval_secs = 5
+        "#,
+    );
+
+    assert!(config.validate().is_err());
+}
@@ -0,0 +1,144 @@
+use ferrous_dns_domain::{NotificationKind, NotificationsConfig};
+
+fn parse(toml: &str) -> NotificationsConfig {
+    toml::from_str(toml).unwrap()
+}
+
+#[test]
+fn test_notifications_disabled_by_default() {
+    let config = parse("");
+
+    assert!(!config.enabled);
+    assert!(config.webhook_url.is_none());
+    assert_eq!(config.check_interval_secs, 60);
+    assert_eq!(config.cooldown_secs, 900);
+    assert_eq!(config.disk_usage_percent, 90);
+    assert!(config.events.is_enabled(NotificationKind::DnssecBogus));
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn test_notifications_section_parses() {
+    let config = parse(
+        r#"
+        enabled = true
+        webhook_url = "https://hooks.example.com/dns"
+        cooldown_secs = 60
+
+        [telegram]
+        bot_token = "123:abc"
+        chat_id = "-100200"
+
+        [smtp]
+        host = "smtp.example.com"
+        from = "dns@example.com"
+        to = ["ops@example.com"]
+
+        [events]
+        client_block_spike = false
+        "#,
+    );
+
+    assert_eq!(config.telegram.as_ref().unwrap().chat_id, "-100200");
+    let smtp = config.smtp.as_ref().unwrap();
+    assert_eq!(smtp.port, 587);
+    assert!(smtp.starttls);
+    assert!(!config.events.is_enabled(NotificationKind::ClientBlockSpike));
+    assert!(config.events.is_enabled(NotificationKind::UpstreamDegraded));
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn test_enabled_without_channel_is_rejected() {
+    let config = parse("enabled = true");
+
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_non_http_webhook_is_rejected() {
+    let config = parse(
+        r#"
+        enabled = true
+        webhook_url = "ftp://example.com/hook"
+        "#,
+    );
+
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_disk_usage_percent_out_of_range_is_rejected() {
+    let config = parse(
+        r#"
+        enabled = true
+        webhook_url = "https://hooks.example.com/dns"
+        disk_usage_percent = 0
+        "#,
+    );
+
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_reports_disabled_by_default() {
+    let config = parse("");
+
+    assert!(!config.reports.is_enabled());
+    assert_eq!(config.reports.send_hour, 8);
+    assert_eq!(config.reports.timezone, "UTC");
+    assert_eq!(config.reports.top_count, 10);
+}
+
+#[test]
+fn test_reports_section_parses_without_enabling_alerts() {
+    let config = parse(
+        r#"
+        [smtp]
+        host = "smtp.example.com"
+        from = "dns@example.com"
+        to = ["ops@example.com"]
+
+        [reports]
+        weekly = true
+        send_hour = 7
+        timezone = "Europe/Lisbon"
+        "#,
+    );
+
+    assert!(!config.enabled);
+    assert!(config.reports.weekly);
+    assert!(!config.reports.monthly);
+    assert_eq!(config.reports.send_hour, 7);
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn test_reports_without_smtp_are_rejected() {
+    let config = parse(
+        r#"
+        [reports]
+        monthly = true
+        "#,
+    );
+
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_reports_send_hour_out_of_range_is_rejected() {
+    let config = parse(
+        r#"
+        [smtp]
+        host = "smtp.example.com"
+        from = "dns@example.com"
+        to = ["ops@example.com"]
+
+        [reports]
+        weekly = true
+        send_hour = 24
+        "#,
+    );
+
+    assert!(config.validate().is_err());
+}
@@ -0,0 +1,43 @@
+use ferrous_dns_domain::{Client, DomainError, PageRequest, SortOrder};
+
+#[test]
+fn test_new_clamps_limit() {
+    assert_eq!(PageRequest::new(0, 0).limit, 1);
+    assert_eq!(PageRequest::new(50, 10).limit, 50);
+    assert_eq!(PageRequest::new(1_000_000, 0).limit, PageRequest::MAX_LIMIT);
+}
+
+#[test]
+fn test_default_page() {
+    let page = PageRequest::default();
+    assert_eq!(page.limit, PageRequest::DEFAULT_LIMIT);
+    assert_eq!(page.offset, 0);
+    assert!(page.sort.is_none());
+}
+
+#[test]
+fn test_sorted_by_allowed_field() {
+    let page = PageRequest::new(10, 0)
+        .sorted_by("hostname", SortOrder::Desc, Client::SORT_FIELDS)
+        .unwrap();
+    let sort = page.sort.unwrap();
+    assert_eq!(sort.field, "hostname");
+    assert_eq!(sort.order, SortOrder::Desc);
+}
+
+#[test]
+fn test_sorted_by_rejects_unknown_field() {
+    let result = PageRequest::new(10, 0).sorted_by(
+        "hostname; DROP TABLE clients",
+        SortOrder::Asc,
+        Client::SORT_FIELDS,
+    );
+    assert!(matches!(result, Err(DomainError::InvalidInput(_))));
+}
+
+#[test]
+fn test_sort_order_from_str() {
+    assert_eq!("ASC".parse::<SortOrder>().unwrap(), SortOrder::Asc);
+    assert_eq!("desc".parse::<SortOrder>().unwrap(), SortOrder::Desc);
+    assert!("up".parse::<SortOrder>().is_err());
+}
@@ -0,0 +1,53 @@
+use ferrous_dns_domain::{DnsConfig, QueryBudgetConfig};
+
+// ── Defaults ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn disabled_by_default() {
+    let config = QueryBudgetConfig::default();
+    assert!(!config.is_enabled());
+    assert_eq!(config.dnssec_ms, 200);
+    assert_eq!(config.min_attempt_ms, 50);
+    assert!(config.validate().is_ok());
+}
+
+// ── TOML deserialization ─────────────────────────────────────────────────────
+
+#[test]
+fn deserializes_partial_toml_with_defaults() {
+    let config: DnsConfig = toml::from_str("[query_budget]\ntotal_ms = 800").unwrap();
+    assert!(config.query_budget.is_enabled());
+    assert_eq!(config.query_budget.total_ms, 800);
+    assert_eq!(config.query_budget.dnssec_ms, 200);
+}
+
+// ── Validation ───────────────────────────────────────────────────────────────
+
+#[test]
+fn rejects_dnssec_share_covering_the_budget() {
+    let config = QueryBudgetConfig {
+        total_ms: 200,
+        dnssec_ms: 200,
+        ..Default::default()
+    };
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn rejects_min_attempt_covering_the_budget() {
+    let config = QueryBudgetConfig {
+        total_ms: 800,
+        min_attempt_ms: 800,
+        ..Default::default()
+    };
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn accepts_budget_with_room_for_every_phase() {
+    let config = QueryBudgetConfig {
+        total_ms: 800,
+        ..Default::default()
+    };
+    assert!(config.validate().is_ok());
+}
@@ -202,3 +202,23 @@ fn test_common_fqdns() {
         assert!(FqdnFilter::is_fqdn(fqdn), "{} should be FQDN", fqdn);
     }
 }
+
+#[test]
+fn test_private_ipv6_detection() {
+    for ip in [
+        "::1",
+        "fc00::1",
+        "fd12:3456::1",
+        "fe80::1",
+        "febf::1",
+        "::ffff:192.168.1.1",
+    ] {
+        assert!(PrivateIpFilter::is_private_ip(&ip.parse().unwrap()), "{ip}");
+    }
+    for ip in ["2001:4860:4860::8888", "fec0::1", "::ffff:8.8.8.8", "::"] {
+        assert!(
+            !PrivateIpFilter::is_private_ip(&ip.parse().unwrap()),
+            "{ip}"
+        );
+    }
+}
@@ -0,0 +1,177 @@
+use ferrous_dns_domain::{PolicyAction, QueryPolicy, QueryPolicyMatcher, RecordType};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+const MONDAY: u8 = 1;
+const SATURDAY: u8 = 1 << 5;
+
+fn ip(s: &str) -> IpAddr {
+    s.parse().unwrap()
+}
+
+fn policy(id: i64, priority: i64, action: PolicyAction) -> QueryPolicy {
+    let mut p = QueryPolicy::new(Arc::from(format!("policy-{id}").as_str()), action);
+    p.id = Some(id);
+    p.priority = priority;
+    p
+}
+
+#[test]
+fn test_validate_requires_target_for_rewrite() {
+    let p = QueryPolicy::new(Arc::from("rw"), PolicyAction::Rewrite);
+    assert!(p
+        .validate()
+        .unwrap_err()
+        .contains("requires an action_target"));
+
+    let mut p = QueryPolicy::new(Arc::from("blk"), PolicyAction::Block);
+    p.action_target = Some(Arc::from("pool"));
+    assert!(p.validate().unwrap_err().contains("does not take"));
+}
+
+#[test]
+fn test_validate_rejects_partial_time_window() {
+    let mut p = QueryPolicy::new(Arc::from("p"), PolicyAction::Block);
+    p.start_time = Some(Arc::from("08:00"));
+    assert!(p.validate().is_err());
+
+    p.end_time = Some(Arc::from("17:00"));
+    assert!(p.validate().is_ok());
+}
+
+#[test]
+fn test_validate_domain_pattern() {
+    assert!(QueryPolicy::validate_domain_pattern("example.com").is_ok());
+    assert!(QueryPolicy::validate_domain_pattern("*.example.com").is_ok());
+    assert!(QueryPolicy::validate_domain_pattern("a.*.example.com").is_err());
+    assert!(QueryPolicy::validate_domain_pattern("*.").is_err());
+}
+
+#[test]
+fn test_validate_rejects_bad_cidr() {
+    let mut p = QueryPolicy::new(Arc::from("p"), PolicyAction::Block);
+    p.client_cidr = Some(Arc::from("10.0.0.0/99"));
+    assert!(p.validate().is_err());
+}
+
+#[test]
+fn test_action_round_trip() {
+    for action in [
+        PolicyAction::Allow,
+        PolicyAction::Block,
+        PolicyAction::ForwardToPool,
+        PolicyAction::Rewrite,
+        PolicyAction::LogOnly,
+    ] {
+        assert_eq!(action.to_str().parse::<PolicyAction>(), Ok(action));
+    }
+    assert!("drop".parse::<PolicyAction>().is_err());
+}
+
+#[test]
+fn test_matcher_first_match_by_priority_wins() {
+    let mut allow = policy(2, 10, PolicyAction::Allow);
+    allow.domain_pattern = Some(Arc::from("example.com"));
+    let block = policy(1, 50, PolicyAction::Block);
+
+    let matcher = QueryPolicyMatcher::new(vec![block, allow]).unwrap();
+
+    let hit = matcher
+        .evaluate(ip("10.0.0.1"), 1, "example.com", RecordType::A, None)
+        .unwrap();
+    assert_eq!(hit.policy_id, 2);
+    assert_eq!(hit.action, PolicyAction::Allow);
+
+    let hit = matcher
+        .evaluate(ip("10.0.0.1"), 1, "other.com", RecordType::A, None)
+        .unwrap();
+    assert_eq!(hit.policy_id, 1);
+}
+
+#[test]
+fn test_matcher_equal_priority_orders_by_id() {
+    let matcher = QueryPolicyMatcher::new(vec![
+        policy(7, 100, PolicyAction::Block),
+        policy(3, 100, PolicyAction::LogOnly),
+    ])
+    .unwrap();
+
+    let hit = matcher
+        .evaluate(ip("10.0.0.1"), 1, "a.com", RecordType::A, None)
+        .unwrap();
+    assert_eq!(hit.policy_id, 3);
+}
+
+#[test]
+fn test_matcher_skips_disabled_policies() {
+    let mut p = policy(1, 1, PolicyAction::Block);
+    p.enabled = false;
+    let matcher = QueryPolicyMatcher::new(vec![p]).unwrap();
+
+    assert!(matcher.is_empty());
+    assert!(matcher
+        .evaluate(ip("10.0.0.1"), 1, "a.com", RecordType::A, None)
+        .is_none());
+}
+
+#[test]
+fn test_matcher_wildcard_matches_subdomains_only() {
+    let mut p = policy(1, 1, PolicyAction::Block);
+    p.domain_pattern = Some(Arc::from("*.example.com"));
+    let matcher = QueryPolicyMatcher::new(vec![p]).unwrap();
+
+    let eval = |d: &str| {
+        matcher
+            .evaluate(ip("10.0.0.1"), 1, d, RecordType::A, None)
+            .is_some()
+    };
+    assert!(eval("www.example.com"));
+    assert!(eval("a.b.Example.COM"));
+    assert!(!eval("example.com"));
+    assert!(!eval("badexample.com"));
+}
+
+#[test]
+fn test_matcher_client_cidr_group_and_record_type() {
+    let mut p = policy(1, 1, PolicyAction::Block);
+    p.client_cidr = Some(Arc::from("192.168.30.0/24"));
+    p.group_id = Some(4);
+    p.record_types = vec![RecordType::AAAA];
+    let matcher = QueryPolicyMatcher::new(vec![p]).unwrap();
+
+    let eval = |addr: &str, group: i64, rt: RecordType| {
+        matcher
+            .evaluate(ip(addr), group, "a.com", rt, None)
+            .is_some()
+    };
+    assert!(eval("192.168.30.5", 4, RecordType::AAAA));
+    assert!(!eval("192.168.31.5", 4, RecordType::AAAA));
+    assert!(!eval("192.168.30.5", 1, RecordType::AAAA));
+    assert!(!eval("192.168.30.5", 4, RecordType::A));
+}
+
+#[test]
+fn test_matcher_time_window() {
+    let mut p = policy(1, 1, PolicyAction::Block);
+    p.days = Some(0b0011111);
+    p.start_time = Some(Arc::from("08:00"));
+    p.end_time = Some(Arc::from("15:00"));
+    let matcher = QueryPolicyMatcher::new(vec![p]).unwrap();
+    assert!(matcher.needs_clock());
+
+    let eval = |clock| {
+        matcher
+            .evaluate(ip("10.0.0.1"), 1, "a.com", RecordType::A, clock)
+            .is_some()
+    };
+    assert!(eval(Some((MONDAY, 8 * 60))));
+    assert!(!eval(Some((MONDAY, 15 * 60))));
+    assert!(!eval(Some((SATURDAY, 9 * 60))));
+    assert!(!eval(None));
+}
+
+#[test]
+fn test_matcher_without_time_restriction_needs_no_clock() {
+    let matcher = QueryPolicyMatcher::new(vec![policy(1, 1, PolicyAction::Block)]).unwrap();
+    assert!(!matcher.needs_clock());
+}
@@ -0,0 +1,41 @@
+use ferrous_dns_domain::{RecordType, RecordTypeFilterMode, RecordTypePolicy};
+
+#[test]
+fn test_deny_mode_blocks_listed_types_only() {
+    let policy = RecordTypePolicy::new(
+        2,
+        RecordTypeFilterMode::Deny,
+        vec![RecordType::HTTPS, RecordType::SVCB],
+    );
+
+    assert!(policy.blocks(RecordType::HTTPS));
+    assert!(policy.blocks(RecordType::SVCB));
+    assert!(!policy.blocks(RecordType::A));
+}
+
+#[test]
+fn test_allow_mode_blocks_unlisted_types() {
+    let policy = RecordTypePolicy::new(
+        2,
+        RecordTypeFilterMode::Allow,
+        vec![RecordType::A, RecordType::AAAA],
+    );
+
+    assert!(!policy.blocks(RecordType::A));
+    assert!(!policy.blocks(RecordType::AAAA));
+    assert!(policy.blocks(RecordType::TXT));
+}
+
+#[test]
+fn test_validate_rejects_empty_type_list() {
+    let policy = RecordTypePolicy::new(2, RecordTypeFilterMode::Deny, vec![]);
+    assert!(policy.validate().is_err());
+}
+
+#[test]
+fn test_mode_round_trip() {
+    for mode in [RecordTypeFilterMode::Allow, RecordTypeFilterMode::Deny] {
+        assert_eq!(mode.to_str().parse::<RecordTypeFilterMode>(), Ok(mode));
+    }
+    assert!("block".parse::<RecordTypeFilterMode>().is_err());
+}
@@ -0,0 +1,51 @@
+use ferrous_dns_domain::{DnsConfig, ResponseLimitsConfig};
+
+// ── Defaults ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn default_values_are_sane() {
+    let config = ResponseLimitsConfig::default();
+    assert_eq!(config.max_answers, 64);
+    assert_eq!(config.max_udp_response_size, 1232);
+    assert_eq!(config.max_cname_chain, 8);
+    assert!(config.validate().is_ok());
+}
+
+// ── TOML deserialization ─────────────────────────────────────────────────────
+
+#[test]
+fn deserializes_partial_toml_with_defaults() {
+    let config: ResponseLimitsConfig = toml::from_str("max_answers = 16").unwrap();
+    assert_eq!(config.max_answers, 16);
+    assert_eq!(config.max_udp_response_size, 1232);
+    assert_eq!(config.max_cname_chain, 8);
+}
+
+#[test]
+fn dns_config_without_section_uses_defaults() {
+    let config: DnsConfig = toml::from_str("").unwrap();
+    assert_eq!(config.response_limits.max_answers, 64);
+}
+
+// ── Validation ───────────────────────────────────────────────────────────────
+
+#[test]
+fn rejects_zero_and_undersized_limits() {
+    let base = ResponseLimitsConfig::default();
+    for config in [
+        ResponseLimitsConfig {
+            max_answers: 0,
+            ..base.clone()
+        },
+        ResponseLimitsConfig {
+            max_udp_response_size: 511,
+            ..base.clone()
+        },
+        ResponseLimitsConfig {
+            max_cname_chain: 0,
+            ..base.clone()
+        },
+    ] {
+        assert!(config.validate().is_err());
+    }
+}
@@ -0,0 +1,200 @@
+use ferrous_dns_domain::{
+    Config, DnsConfig, DnsViewConfig, LocalDnsRecord, RecordType, SplitHorizonMatcher,
+};
+use std::net::IpAddr;
+
+const LAN_CLIENT: IpAddr = IpAddr::V4(std::net::Ipv4Addr::new(192, 168, 1, 100));
+const VPN_CLIENT: IpAddr = IpAddr::V4(std::net::Ipv4Addr::new(10, 8, 0, 5));
+
+fn ip(s: &str) -> IpAddr {
+    s.parse().unwrap()
+}
+
+fn view(name: &str, clients: &[&str], groups: &[i64]) -> DnsViewConfig {
+    DnsViewConfig {
+        name: name.to_string(),
+        clients: clients.iter().map(|c| c.to_string()).collect(),
+        groups: groups.to_vec(),
+    }
+}
+
+fn record(hostname: &str, ip: &str, view: Option<&str>) -> LocalDnsRecord {
+    LocalDnsRecord {
+        hostname: hostname.to_string(),
+        domain: None,
+        ip: ip.to_string(),
+        record_type: if ip.contains(':') { "AAAA" } else { "A" }.to_string(),
+        ttl: None,
+        view: view.map(String::from),
+    }
+}
+
+fn matcher(views: &[DnsViewConfig], records: &[LocalDnsRecord]) -> SplitHorizonMatcher {
+    SplitHorizonMatcher::new(views, records, &Some("home.lan".to_string()))
+}
+
+// ── Matcher ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_untagged_records_are_not_compiled() {
+    let m = matcher(
+        &[view("vpn", &["10.8.0.0/24"], &[])],
+        &[record("nas", "192.168.1.10", None)],
+    );
+
+    assert!(m.is_empty());
+    assert!(m
+        .lookup(VPN_CLIENT, 1, "nas.home.lan", RecordType::A)
+        .is_none());
+}
+
+#[test]
+fn test_client_in_view_gets_view_records() {
+    let m = matcher(
+        &[view("vpn", &["10.8.0.0/24"], &[])],
+        &[
+            record("nas", "192.168.1.10", None),
+            record("nas", "10.8.0.10", Some("vpn")),
+        ],
+    );
+
+    let answer = m
+        .lookup(VPN_CLIENT, 1, "NAS.home.lan.", RecordType::A)
+        .unwrap();
+    assert_eq!(&*answer.view, "vpn");
+    assert_eq!(answer.addresses, vec![ip("10.8.0.10")]);
+    assert_eq!(answer.ttl, 300);
+    assert!(m
+        .lookup(LAN_CLIENT, 1, "nas.home.lan", RecordType::A)
+        .is_none());
+}
+
+#[test]
+fn test_view_matches_by_group() {
+    let m = matcher(
+        &[view("remote", &[], &[7])],
+        &[record("nas", "10.8.0.10", Some("remote"))],
+    );
+
+    assert!(m
+        .lookup(LAN_CLIENT, 7, "nas.home.lan", RecordType::A)
+        .is_some());
+    assert!(m
+        .lookup(LAN_CLIENT, 1, "nas.home.lan", RecordType::A)
+        .is_none());
+}
+
+#[test]
+fn test_first_matching_view_wins() {
+    let m = matcher(
+        &[
+            view("vpn", &["10.8.0.0/24"], &[]),
+            view("private", &["10.0.0.0/8"], &[]),
+        ],
+        &[
+            record("nas", "10.8.0.10", Some("vpn")),
+            record("nas", "10.0.0.10", Some("private")),
+            record("git", "10.0.0.20", Some("private")),
+        ],
+    );
+
+    let answer = m
+        .lookup(VPN_CLIENT, 1, "nas.home.lan", RecordType::A)
+        .unwrap();
+    assert_eq!(&*answer.view, "vpn");
+    // The vpn view does not define git, so the private view is not consulted.
+    assert!(m
+        .lookup(VPN_CLIENT, 1, "git.home.lan", RecordType::A)
+        .is_none());
+}
+
+#[test]
+fn test_missing_family_is_nodata() {
+    let m = matcher(
+        &[view("vpn", &["10.8.0.0/24"], &[])],
+        &[record("nas", "10.8.0.10", Some("vpn"))],
+    );
+
+    let answer = m
+        .lookup(VPN_CLIENT, 1, "nas.home.lan", RecordType::AAAA)
+        .unwrap();
+    assert!(answer.addresses.is_empty());
+    assert!(m
+        .lookup(VPN_CLIENT, 1, "nas.home.lan", RecordType::MX)
+        .is_none());
+}
+
+#[test]
+fn test_records_in_unknown_view_are_skipped() {
+    let m = matcher(
+        &[view("vpn", &["10.8.0.0/24"], &[])],
+        &[record("nas", "10.8.0.10", Some("guest"))],
+    );
+
+    assert!(m
+        .lookup(VPN_CLIENT, 1, "nas.home.lan", RecordType::A)
+        .is_none());
+}
+
+// ── Config validation ────────────────────────────────────────────────────────
+
+fn config_with(views: Vec<DnsViewConfig>, records: Vec<LocalDnsRecord>) -> Config {
+    Config {
+        dns: DnsConfig {
+            views,
+            local_records: records,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_views_deserialize_from_toml() {
+    let dns: DnsConfig = toml::from_str(
+        r#"
+        [[views]]
+        name = "vpn"
+        clients = ["10.8.0.0/24"]
+
+        [[local_records]]
+        hostname = "nas"
+        ip = "10.8.0.10"
+        record_type = "A"
+        view = "vpn"
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(dns.views.len(), 1);
+    assert!(dns.views[0].groups.is_empty());
+    assert_eq!(dns.local_records[0].view.as_deref(), Some("vpn"));
+}
+
+#[test]
+fn test_valid_views_pass_validation() {
+    let config = config_with(
+        vec![view("vpn", &["10.8.0.0/24"], &[]), view("kids", &[], &[2])],
+        vec![record("nas", "10.8.0.10", Some("vpn"))],
+    );
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn test_invalid_views_fail_validation() {
+    for config in [
+        config_with(vec![view("", &["10.8.0.0/24"], &[])], vec![]),
+        config_with(
+            vec![
+                view("vpn", &["10.8.0.0/24"], &[]),
+                view("vpn", &["10.9.0.0/24"], &[]),
+            ],
+            vec![],
+        ),
+        config_with(vec![view("vpn", &[], &[])], vec![]),
+        config_with(vec![view("vpn", &["not-a-cidr"], &[])], vec![]),
+        config_with(vec![], vec![record("nas", "10.8.0.10", Some("vpn"))]),
+    ] {
+        assert!(config.validate().is_err());
+    }
+}
@@ -0,0 +1,61 @@
+use ferrous_dns_domain::{Config, StandbyConfig};
+
+fn standby(url: &str) -> StandbyConfig {
+    StandbyConfig {
+        enabled: true,
+        primary_url: url.to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_standby_is_disabled_by_default() {
+    let config = Config::default();
+    assert!(!config.standby.enabled);
+    assert!(config.standby.validate().is_ok());
+}
+
+#[test]
+fn test_standby_parses_from_toml() {
+    let config: StandbyConfig = toml::from_str(
+        r#"
+        enabled = true
+        primary_url = " https://dns1.lan:8443/ "
+        api_token = "secret"
+        sync_interval_secs = 30
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(config.normalized_primary_url(), "https://dns1.lan:8443");
+    assert_eq!(config.api_token.as_deref(), Some("secret"));
+    assert_eq!(config.sync_interval_secs, 30);
+    assert_eq!(config.cache_entries, 10_000);
+    assert_eq!(config.max_failed_syncs, 3);
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn test_enabled_standby_needs_http_url() {
+    assert!(standby("").validate().is_err());
+    assert!(standby("192.168.1.2:8080").validate().is_err());
+    assert!(standby("http://192.168.1.2:8080").validate().is_ok());
+}
+
+#[test]
+fn test_sync_interval_has_a_floor() {
+    let config = StandbyConfig {
+        sync_interval_secs: 5,
+        ..standby("http://192.168.1.2:8080")
+    };
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_max_failed_syncs_must_be_positive() {
+    let config = StandbyConfig {
+        max_failed_syncs: 0,
+        ..standby("http://192.168.1.2:8080")
+    };
+    assert!(config.validate().is_err());
+}
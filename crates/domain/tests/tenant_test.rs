@@ -0,0 +1,91 @@
+use ferrous_dns_domain::{Tenant, TenantMatcher};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+fn tenant(id: i64, name: &str, subnets: &[&str], default_group_id: Option<i64>) -> Tenant {
+    Tenant {
+        id: Some(id),
+        subnets: subnets.iter().map(|s| Arc::from(*s)).collect(),
+        default_group_id,
+        ..Tenant::new(Arc::from(name))
+    }
+}
+
+fn ip(s: &str) -> IpAddr {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_default_group_name() {
+    let t = Tenant::new(Arc::from("Acme"));
+    assert_eq!(t.default_group_name(), "Acme Default");
+}
+
+#[test]
+fn test_validate_accepts_listener_and_subnets() {
+    let t = Tenant {
+        listener: Some(Arc::from("0.0.0.0:5353")),
+        ..tenant(1, "Acme", &["10.1.0.0/16", "fd00:1::/64"], None)
+    };
+    assert!(t.validate().is_ok());
+}
+
+#[test]
+fn test_validate_rejects_bad_listener() {
+    let t = Tenant {
+        listener: Some(Arc::from("not-an-address")),
+        ..Tenant::new(Arc::from("Acme"))
+    };
+    assert!(t.validate().unwrap_err().contains("listener"));
+}
+
+#[test]
+fn test_validate_rejects_bad_subnet() {
+    let t = tenant(1, "Acme", &["10.1.0.0/40"], None);
+    assert!(t.validate().unwrap_err().contains("subnet"));
+}
+
+#[test]
+fn test_validate_name() {
+    assert!(Tenant::validate_name("Acme Corp_1").is_ok());
+    assert!(Tenant::validate_name("").is_err());
+    assert!(Tenant::validate_name("acme/corp").is_err());
+    assert!(Tenant::validate_name(&"a".repeat(65)).is_err());
+}
+
+#[test]
+fn test_matcher_finds_tenant_by_subnet() {
+    let matcher = TenantMatcher::new(&[
+        tenant(1, "Acme", &["10.1.0.0/16"], Some(10)),
+        tenant(2, "Globex", &["10.2.0.0/16"], Some(20)),
+    ]);
+
+    assert_eq!(matcher.find_tenant_for_ip(ip("10.1.4.5")), Some(1));
+    assert_eq!(matcher.find_default_group_for_ip(ip("10.2.0.9")), Some(20));
+    assert_eq!(matcher.find_tenant_for_ip(ip("192.168.1.1")), None);
+}
+
+#[test]
+fn test_matcher_prefers_most_specific_subnet() {
+    let matcher = TenantMatcher::new(&[
+        tenant(1, "Acme", &["10.0.0.0/8"], Some(10)),
+        tenant(2, "Globex", &["10.2.0.0/16"], Some(20)),
+    ]);
+
+    assert_eq!(matcher.find_tenant_for_ip(ip("10.2.3.4")), Some(2));
+    assert_eq!(matcher.find_tenant_for_ip(ip("10.3.3.4")), Some(1));
+}
+
+#[test]
+fn test_matcher_without_default_group() {
+    let matcher = TenantMatcher::new(&[tenant(1, "Acme", &["10.1.0.0/16"], None)]);
+
+    assert_eq!(matcher.find_tenant_for_ip(ip("10.1.0.1")), Some(1));
+    assert_eq!(matcher.find_default_group_for_ip(ip("10.1.0.1")), None);
+}
+
+#[test]
+fn test_empty_matcher() {
+    assert!(TenantMatcher::empty().is_empty());
+    assert!(TenantMatcher::new(&[tenant(1, "Acme", &[], None)]).is_empty());
+}
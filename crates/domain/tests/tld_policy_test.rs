@@ -0,0 +1,64 @@
+use ferrous_dns_domain::{TldFilterMode, TldPolicy};
+
+fn tlds(values: &[&str]) -> Vec<String> {
+    values.iter().map(|t| t.to_string()).collect()
+}
+
+#[test]
+fn test_deny_mode_blocks_listed_tlds_only() {
+    let policy = TldPolicy::new(2, TldFilterMode::Deny, tlds(&["zip", "mov"]));
+
+    assert!(policy.blocks("invoice.zip"));
+    assert!(policy.blocks("cdn.clip.mov."));
+    assert!(!policy.blocks("example.com"));
+}
+
+#[test]
+fn test_allow_mode_blocks_unlisted_tlds() {
+    let policy = TldPolicy::new(2, TldFilterMode::Allow, tlds(&["com", "net"]));
+
+    assert!(!policy.blocks("example.com"));
+    assert!(!policy.blocks("example.net"));
+    assert!(policy.blocks("example.io"));
+}
+
+#[test]
+fn test_single_label_names_are_never_blocked() {
+    let policy = TldPolicy::new(2, TldFilterMode::Allow, tlds(&["com"]));
+
+    assert!(!policy.blocks("router"));
+    assert_eq!(TldPolicy::tld_of("router"), None);
+    assert_eq!(TldPolicy::tld_of("a.b.example."), Some("example"));
+}
+
+#[test]
+fn test_new_normalises_and_dedups_tlds() {
+    let policy = TldPolicy::new(2, TldFilterMode::Deny, tlds(&[".ZIP", " zip ", "Mov"]));
+
+    assert_eq!(policy.tlds, tlds(&["mov", "zip"]));
+    assert!(policy.validate().is_ok());
+}
+
+#[test]
+fn test_validate_rejects_invalid_tlds() {
+    assert!(TldPolicy::new(2, TldFilterMode::Deny, vec![])
+        .validate()
+        .is_err());
+    assert!(TldPolicy::new(2, TldFilterMode::Deny, tlds(&["co.uk"]))
+        .validate()
+        .is_err());
+    assert!(TldPolicy::new(2, TldFilterMode::Deny, tlds(&["-zip"]))
+        .validate()
+        .is_err());
+    assert!(TldPolicy::new(2, TldFilterMode::Deny, tlds(&["."]))
+        .validate()
+        .is_err());
+}
+
+#[test]
+fn test_mode_round_trip() {
+    for mode in [TldFilterMode::Allow, TldFilterMode::Deny] {
+        assert_eq!(mode.to_str().parse::<TldFilterMode>(), Ok(mode));
+    }
+    assert!("block".parse::<TldFilterMode>().is_err());
+}
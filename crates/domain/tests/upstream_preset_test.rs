@@ -0,0 +1,106 @@
+use ferrous_dns_domain::{Config, DnsConfig, DnsProtocol, UpstreamPreset, UpstreamStrategy};
+
+#[test]
+fn test_every_preset_expands_to_parseable_servers() {
+    for preset in UpstreamPreset::ALL {
+        let pools = preset.pools();
+        assert!(!pools.is_empty(), "{preset} has no pools");
+        for pool in &pools {
+            assert!(!pool.servers.is_empty(), "{} has no servers", pool.name);
+            for server in &pool.servers {
+                assert!(
+                    server.parse::<DnsProtocol>().is_ok(),
+                    "{preset}: invalid server {server}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_pool_names_are_unique_within_a_preset() {
+    for preset in UpstreamPreset::ALL {
+        let pools = preset.pools();
+        let mut names: Vec<&str> = pools.iter().map(|p| p.name.as_str()).collect();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), pools.len(), "{preset} repeats a pool name");
+    }
+}
+
+#[test]
+fn test_dot_privacy_uses_only_dot() {
+    for pool in UpstreamPreset::DotPrivacy.pools() {
+        assert!(pool.servers.iter().all(|s| s.starts_with("tls://")));
+    }
+}
+
+#[test]
+fn test_fallback_pool_has_lower_priority() {
+    let pools = UpstreamPreset::Quad9.pools();
+    assert_eq!(pools.len(), 2);
+    assert_eq!(pools[0].priority, 1);
+    assert_eq!(pools[1].priority, 2);
+    assert_eq!(pools[1].strategy, UpstreamStrategy::Failover);
+}
+
+#[test]
+fn test_preset_from_str_round_trips() {
+    for preset in UpstreamPreset::ALL {
+        assert_eq!(preset.as_str().parse::<UpstreamPreset>(), Ok(preset));
+    }
+    assert_eq!("Quad9".parse::<UpstreamPreset>(), Ok(UpstreamPreset::Quad9));
+}
+
+#[test]
+fn test_unknown_preset_lists_the_valid_names() {
+    let err = "opendns".parse::<UpstreamPreset>().unwrap_err();
+    assert!(err.contains("opendns"));
+    assert!(err.contains("dot-privacy"));
+}
+
+#[test]
+fn test_preset_deserializes_in_dns_config() {
+    let config: DnsConfig = toml::from_str(r#"upstream_preset = "dot-privacy""#).unwrap();
+    assert_eq!(config.upstream_preset, Some(UpstreamPreset::DotPrivacy));
+}
+
+#[test]
+fn test_preset_defaults_to_none() {
+    assert!(DnsConfig::default().upstream_preset.is_none());
+}
+
+#[test]
+fn test_normalize_expands_preset_instead_of_upstream_servers() {
+    let mut config = Config::default();
+    config.dns.upstream_preset = Some(UpstreamPreset::Cloudflare);
+
+    config.normalize_pools();
+
+    let names: Vec<&str> = config.dns.pools.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, ["cloudflare", "cloudflare-fallback"]);
+}
+
+#[test]
+fn test_normalize_keeps_explicit_pools_over_preset() {
+    let mut config = Config::default();
+    config.normalize_pools();
+    config.dns.upstream_preset = Some(UpstreamPreset::Google);
+
+    config.normalize_pools();
+
+    assert_eq!(config.dns.pools.len(), 1);
+    assert_eq!(config.dns.pools[0].name, "default");
+}
+
+#[test]
+fn test_normalize_preset_is_idempotent() {
+    let mut config = Config::default();
+    config.dns.upstream_preset = Some(UpstreamPreset::Family);
+
+    config.normalize_pools();
+    let first = config.dns.pools.len();
+    config.normalize_pools();
+
+    assert_eq!(config.dns.pools.len(), first);
+}
@@ -0,0 +1,66 @@
+use ferrous_dns_domain::{DnsConfig, VpnPeerProvider, VpnPeersConfig};
+
+#[test]
+fn test_vpn_peers_disabled_by_default() {
+    let config = DnsConfig::default().vpn_peers;
+
+    assert!(!config.enabled);
+    assert_eq!(config.provider, VpnPeerProvider::Tailscale);
+    assert_eq!(config.domain, "vpn.lan");
+    assert_eq!(
+        config.tailscale_socket,
+        "/var/run/tailscale/tailscaled.sock"
+    );
+}
+
+#[test]
+fn test_wireguard_provider_parses() {
+    let config: DnsConfig = toml::from_str(
+        r#"
+        [vpn_peers]
+        enabled = true
+        provider = "wireguard"
+        domain = "WG.Home."
+        wireguard_config = "/etc/wireguard/wg1.conf"
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(config.vpn_peers.provider, VpnPeerProvider::Wireguard);
+    assert_eq!(config.vpn_peers.normalized_domain(), "wg.home");
+    assert!(config.vpn_peers.validate().is_ok());
+}
+
+#[test]
+fn test_selected_provider_path_cannot_be_empty() {
+    let config = VpnPeersConfig {
+        enabled: true,
+        provider: VpnPeerProvider::Wireguard,
+        wireguard_config: String::new(),
+        ..Default::default()
+    };
+
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_other_provider_path_is_not_checked() {
+    let config = VpnPeersConfig {
+        enabled: true,
+        wireguard_config: String::new(),
+        ..Default::default()
+    };
+
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn test_vpn_peers_sync_interval_has_a_floor() {
+    let config = VpnPeersConfig {
+        enabled: true,
+        sync_interval_secs: 2,
+        ..Default::default()
+    };
+
+    assert!(config.validate().is_err());
+}
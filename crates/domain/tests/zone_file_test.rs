@@ -0,0 +1,112 @@
+use ferrous_dns_domain::{LocalRecord, RecordType, ZoneFile};
+use std::sync::Arc;
+
+fn record(hostname: &str, record_type: RecordType, value: &str) -> LocalRecord {
+    LocalRecord::new(Arc::from(hostname), record_type, Arc::from(value))
+}
+
+fn in_domain(mut record: LocalRecord, domain: &str) -> LocalRecord {
+    record.domain = Some(Arc::from(domain));
+    record
+}
+
+fn home() -> Option<String> {
+    Some("home.lan".to_string())
+}
+
+#[test]
+fn test_groups_records_by_domain() {
+    let records = vec![
+        record("nas", RecordType::A, "10.0.0.5"),
+        in_domain(record("printer", RecordType::A, "10.0.1.9"), "Office.Lan"),
+        record("router", RecordType::AAAA, "fd00::1"),
+    ];
+
+    let zones = ZoneFile::from_records(&records, &home());
+    let origins: Vec<&str> = zones.iter().map(|z| z.origin.as_str()).collect();
+    assert_eq!(origins, vec!["home.lan", "office.lan"]);
+
+    let names: Vec<&str> = zones[0].records.iter().map(|r| r.name.as_str()).collect();
+    assert_eq!(names, vec!["nas", "router"]);
+    assert_eq!(zones[1].records[0].name, "printer");
+}
+
+#[test]
+fn test_record_data_targets_are_fully_qualified() {
+    let records = vec![
+        record("www", RecordType::CNAME, "nas.home.lan"),
+        record("office", RecordType::MX, "10 mail.home.lan"),
+        record("_sip._tcp", RecordType::SRV, "10 60 5060 sip.home.lan"),
+    ];
+
+    let zone = &ZoneFile::from_records(&records, &home())[0];
+    let data: Vec<&str> = zone.records.iter().map(|r| r.data.as_str()).collect();
+    assert_eq!(
+        data,
+        vec![
+            "nas.home.lan.",
+            "10 mail.home.lan.",
+            "10 60 5060 sip.home.lan."
+        ]
+    );
+}
+
+#[test]
+fn test_txt_is_quoted_and_split() {
+    let long = "a".repeat(300);
+    let records = vec![
+        record("quote", RecordType::TXT, r#"say "hi" \o/"#),
+        record("long", RecordType::TXT, &long),
+    ];
+
+    let zone = &ZoneFile::from_records(&records, &home())[0];
+    assert_eq!(zone.records[0].data, r#""say \"hi\" \\o/""#);
+    assert_eq!(
+        zone.records[1].data,
+        format!("\"{}\" \"{}\"", "a".repeat(255), "a".repeat(45))
+    );
+}
+
+#[test]
+fn test_skips_templates_views_and_tenant_records() {
+    let mut view = record("nas", RecordType::A, "192.168.1.5");
+    view.view = Some(Arc::from("lan"));
+    let mut tenant = record("nas", RecordType::A, "10.9.0.5");
+    tenant.tenant_id = Some(3);
+    let records = vec![
+        record("nas", RecordType::A, "10.0.0.5"),
+        record("*.nip", RecordType::A, "{ip}"),
+        view,
+        tenant,
+    ];
+
+    let zone = &ZoneFile::from_records(&records, &home())[0];
+    assert_eq!(zone.records.len(), 1);
+    assert_eq!(zone.skipped, 1);
+}
+
+#[test]
+fn test_records_without_domain_use_root_origin() {
+    let records = vec![record("nas", RecordType::A, "10.0.0.5")];
+
+    let zone = &ZoneFile::from_records(&records, &None)[0];
+    assert_eq!(zone.origin, ".");
+    assert_eq!(zone.records[0].name, "nas.");
+}
+
+#[test]
+fn test_to_text_writes_origin_soa_and_records() {
+    let mut nas = record("nas", RecordType::A, "10.0.0.5");
+    nas.ttl = 60;
+    let records = vec![nas, record("*.nip", RecordType::A, "{ip}")];
+
+    let text = ZoneFile::from_records(&records, &home())[0].to_text(1_700_000_000);
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines[0], "$ORIGIN home.lan.");
+    assert_eq!(lines[1], "$TTL 300");
+    assert!(lines[2].starts_with("@\t300\tIN\tSOA\t"));
+    assert!(lines[2].contains(" 1700000000 "));
+    assert_eq!(lines[3], "@\t300\tIN\tNS\tlocalhost.");
+    assert_eq!(lines[4], "nas\t60\tIN\tA\t10.0.0.5");
+    assert!(lines[5].starts_with("; 1 record(s)"));
+}
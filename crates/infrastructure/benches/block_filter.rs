@@ -0,0 +1,76 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use ferrous_dns_application::ports::BlockFilterEnginePort;
+use ferrous_dns_domain::config::DatabaseConfig;
+use ferrous_dns_infrastructure::database::create_write_pool;
+use ferrous_dns_infrastructure::dns::BlockFilterEngine;
+use ferrous_dns_infrastructure::schedule::ScheduleStateStore;
+use std::hint::black_box;
+use std::sync::Arc;
+
+const BLOCKED_DOMAINS: usize = 100_000;
+const DEFAULT_GROUP: i64 = 1;
+
+/// Compiles an engine over `BLOCKED_DOMAINS` manual blocklist entries stored
+/// in a throwaway database.
+fn build_engine(runtime: &tokio::runtime::Runtime) -> (Arc<BlockFilterEngine>, tempfile::TempDir) {
+    let dir = tempfile::tempdir().unwrap();
+    let url = format!("sqlite:{}", dir.path().join("bench.db").display());
+
+    let engine = runtime.block_on(async {
+        let pool = create_write_pool(&url, &DatabaseConfig::default())
+            .await
+            .unwrap();
+        let mut tx = pool.begin().await.unwrap();
+        for i in 0..BLOCKED_DOMAINS {
+            sqlx::query("INSERT INTO blocklist (domain) VALUES (?)")
+                .bind(format!("ads{i}.tracker.example"))
+                .execute(&mut *tx)
+                .await
+                .unwrap();
+        }
+        tx.commit().await.unwrap();
+
+        let engine = BlockFilterEngine::new(
+            pool,
+            DEFAULT_GROUP,
+            Arc::new(ScheduleStateStore::new()),
+            true,
+        )
+        .await
+        .unwrap();
+        engine.reload().await.unwrap();
+        engine
+    });
+
+    (engine, dir)
+}
+
+fn bench_check(c: &mut Criterion) {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let (engine, _dir) = build_engine(&runtime);
+
+    let blocked: Vec<String> = (0..10_000)
+        .map(|i| format!("ads{}.tracker.example", i * 7 % BLOCKED_DOMAINS))
+        .collect();
+    let allowed: Vec<String> = (0..10_000)
+        .map(|i| format!("www{i}.allowed.example"))
+        .collect();
+
+    let mut group = c.benchmark_group("block_filter_check");
+    group.throughput(Throughput::Elements(1));
+
+    for (name, domains) in [("blocked", &blocked), ("allowed", &allowed)] {
+        group.bench_function(BenchmarkId::new(name, domains.len()), |b| {
+            let mut i = 0;
+            b.iter(|| {
+                i = (i + 1) % domains.len();
+                black_box(engine.check(black_box(&domains[i]), DEFAULT_GROUP))
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_check);
+criterion_main!(benches);
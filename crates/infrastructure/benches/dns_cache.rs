@@ -0,0 +1,119 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use ferrous_dns_domain::RecordType;
+use ferrous_dns_infrastructure::dns::cache::coarse_clock;
+use ferrous_dns_infrastructure::dns::{
+    CachedAddresses, CachedData, DnsCache, DnsCacheConfig, EvictionStrategy,
+};
+use std::hint::black_box;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+const ENTRIES: usize = 50_000;
+
+fn create_cache() -> DnsCache {
+    DnsCache::new(DnsCacheConfig {
+        max_entries: ENTRIES * 2,
+        eviction_strategy: EvictionStrategy::HitRate,
+        min_threshold: 2.0,
+        refresh_threshold: 0.75,
+        batch_eviction_percentage: 0.2,
+        adaptive_thresholds: false,
+        min_frequency: 0,
+        min_lfuk_score: 0.0,
+        shard_amount: 64,
+        access_window_secs: 3600,
+        eviction_sample_size: 8,
+        lfuk_k_value: 0.5,
+        refresh_sample_rate: 1.0,
+        min_ttl: 0,
+        max_ttl: 86_400,
+        l1_capacity: 1024,
+    })
+}
+
+fn ip_data(i: usize) -> CachedData {
+    let addr: IpAddr = format!("10.{}.{}.{}", (i >> 16) & 0xff, (i >> 8) & 0xff, i & 0xff)
+        .parse()
+        .unwrap();
+    CachedData::IpAddresses(CachedAddresses {
+        addresses: Arc::new(vec![addr]),
+    })
+}
+
+fn domains() -> Vec<String> {
+    (0..ENTRIES)
+        .map(|i| format!("host{i}.bench.example"))
+        .collect()
+}
+
+fn populated_cache(domains: &[String]) -> DnsCache {
+    let cache = create_cache();
+    for (i, domain) in domains.iter().enumerate() {
+        cache.insert(domain, RecordType::A, ip_data(i), 300, None);
+        cache.insert(
+            domain,
+            RecordType::CNAME,
+            CachedData::CanonicalName(Arc::from("target.bench.example")),
+            300,
+            None,
+        );
+    }
+    cache
+}
+
+fn bench_get(c: &mut Criterion) {
+    coarse_clock::tick();
+    let domains = domains();
+    let cache = populated_cache(&domains);
+
+    let mut group = c.benchmark_group("dns_cache_get");
+    group.throughput(Throughput::Elements(1));
+
+    // A records are promoted to the per-thread L1 after the first hit.
+    group.bench_function(BenchmarkId::new("hit", "l1"), |b| {
+        let mut i = 0;
+        b.iter(|| {
+            i = (i + 1) % 64;
+            black_box(cache.get(black_box(&domains[i]), &RecordType::A))
+        })
+    });
+
+    // CNAME data never enters L1, so every lookup goes to the sharded L2.
+    group.bench_function(BenchmarkId::new("hit", "l2"), |b| {
+        let mut i = 0;
+        b.iter(|| {
+            i = (i + 1) % ENTRIES;
+            black_box(cache.get(black_box(&domains[i]), &RecordType::CNAME))
+        })
+    });
+
+    group.bench_function("miss", |b| {
+        let mut i = 0;
+        b.iter(|| {
+            i = (i + 1) % ENTRIES;
+            black_box(cache.get(black_box(&domains[i]), &RecordType::AAAA))
+        })
+    });
+
+    group.finish();
+}
+
+fn bench_insert(c: &mut Criterion) {
+    coarse_clock::tick();
+    let domains = domains();
+    let cache = create_cache();
+
+    let mut group = c.benchmark_group("dns_cache_insert");
+    group.throughput(Throughput::Elements(1));
+    group.bench_function("overwrite", |b| {
+        let mut i = 0;
+        b.iter(|| {
+            i = (i + 1) % ENTRIES;
+            cache.insert(black_box(&domains[i]), RecordType::A, ip_data(i), 300, None);
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_get, bench_insert);
+criterion_main!(benches);
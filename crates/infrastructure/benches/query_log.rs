@@ -0,0 +1,133 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use ferrous_dns_application::ports::{QueryLogRepository, QueryMetricsPort};
+use ferrous_dns_domain::config::{DatabaseConfig, QueryLogPartitioning};
+use ferrous_dns_domain::{QueryLog, QuerySource, RecordType};
+use ferrous_dns_infrastructure::database::create_write_pool;
+use ferrous_dns_infrastructure::dns::QueryMetrics;
+use ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::hint::black_box;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+/// Counts the allocations made by the thread that logs, so the flush task
+/// draining the channel on the runtime's workers does not skew the numbers.
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|n| n.set(n.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|n| n.set(n.get() + 1));
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn allocations() -> u64 {
+    ALLOCATIONS.with(Cell::get)
+}
+
+const SAMPLE_QUERIES: u64 = 10_000;
+
+fn make_log(domain: &str, client_ip: IpAddr, cache_hit: bool) -> QueryLog {
+    QueryLog {
+        id: None,
+        domain: Arc::from(domain),
+        record_type: RecordType::AAAA,
+        client_ip,
+        client_hostname: None,
+        client_mac: None,
+        blocked: false,
+        response_time_us: Some(if cache_hit { 40 } else { 12_000 }),
+        cache_hit,
+        cache_refresh: false,
+        dnssec_status: None,
+        upstream_server: (!cache_hit).then(|| Arc::from("dns.quad9.net")),
+        upstream_pool: (!cache_hit).then(|| Arc::from("primary")),
+        upstream_strategy: None,
+        upstream_attempt: None,
+        upstream_protocol: None,
+        response_status: Some("NOERROR"),
+        timestamp: None,
+        query_source: QuerySource::Client,
+        group_id: Some(1),
+        group_name: None,
+        block_source: None,
+        plugin: None,
+    }
+}
+
+fn cases() -> Vec<(&'static str, QueryLog)> {
+    let v4: IpAddr = "192.168.1.10".parse().unwrap();
+    let v6: IpAddr = "2001:db8:85a3:1234:5678:8a2e:370:7334".parse().unwrap();
+    vec![
+        ("cache_hit_short", make_log("example.com", v4, true)),
+        (
+            "upstream_long_ipv6",
+            make_log("telemetry.eu-west-1.service.example.com", v6, false),
+        ),
+    ]
+}
+
+fn bench_query_log_path(c: &mut Criterion) {
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(2)
+        .enable_all()
+        .build()
+        .unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let url = format!("sqlite:{}", dir.path().join("bench.db").display());
+    let cfg = DatabaseConfig {
+        query_log_partitioning: QueryLogPartitioning::None,
+        ..DatabaseConfig::default()
+    };
+    let repo = runtime.block_on(async {
+        let pool = create_write_pool(&url, &cfg).await.unwrap();
+        SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool, &cfg)
+    });
+    let metrics = QueryMetrics::new();
+
+    // What the DNS handler does for every answered client query.
+    let log = |query: &QueryLog| {
+        metrics.record(query);
+        repo.log_query_sync(query).unwrap();
+    };
+
+    let cases = cases();
+    for (name, query) in &cases {
+        log(query);
+        let before = allocations();
+        for _ in 0..SAMPLE_QUERIES {
+            log(black_box(query));
+        }
+        let per_query = (allocations() - before) as f64 / SAMPLE_QUERIES as f64;
+        println!("query_log/{name}: {per_query:.2} allocations per query");
+    }
+
+    let mut group = c.benchmark_group("query_log");
+    group.throughput(Throughput::Elements(1));
+    for (name, query) in &cases {
+        group.bench_with_input(BenchmarkId::new("log", name), query, |b, query| {
+            b.iter(|| log(black_box(query)));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_query_log_path);
+criterion_main!(benches);
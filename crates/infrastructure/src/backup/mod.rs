@@ -0,0 +1,5 @@
+mod sqlite_store;
+mod tar_gz;
+
+pub use sqlite_store::SqliteBackupStore;
+pub use tar_gz::TarGzBackupArchiver;
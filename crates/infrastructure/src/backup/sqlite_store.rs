@@ -0,0 +1,225 @@
+use std::collections::{BTreeMap, HashSet};
+
+use crate::repositories::query_log_repository::query_log_tables;
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{BackupRow, BackupStore, BackupTables};
+use ferrous_dns_domain::DomainError;
+use serde_json::Value;
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Column, Row, SqliteConnection, SqlitePool, TypeInfo, ValueRef};
+use tracing::{error, instrument, warn};
+
+/// Tables covered by a full backup, parents before children.
+///
+/// Runtime data (query log, sessions, devices) and credentials (users,
+/// API tokens) are deliberately left out.
+const BACKUP_TABLES: &[&str] = &[
+    "tenants",
+    "tenant_subnets",
+    "groups",
+    "clients",
+    "client_subnets",
+    "blocklist",
+    "whitelist",
+    "blocklist_sources",
+    "blocklist_source_groups",
+    "whitelist_sources",
+    "whitelist_source_groups",
+    "ip_blocklist_sources",
+    "managed_domains",
+    "regex_filters",
+    "blocked_services",
+    "custom_services",
+    "safe_search_configs",
+    "schedule_profiles",
+    "time_slots",
+    "group_schedule_profiles",
+    "query_policies",
+    "dns_rewrites",
+    "cache_ttl_overrides",
+    "local_records",
+    "group_record_type_policies",
+    "group_tld_policies",
+];
+
+/// Dumps and restores the backup tables column-by-column, so rows survive
+/// schema additions in either direction.
+pub struct SqliteBackupStore {
+    pool: SqlitePool,
+}
+
+impl SqliteBackupStore {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+fn db_error(e: sqlx::Error) -> DomainError {
+    error!(error = %e, "Backup database query failed");
+    DomainError::DatabaseError(e.to_string())
+}
+
+/// Column names of `table`; empty when the table does not exist.
+async fn table_columns(
+    conn: &mut SqliteConnection,
+    table: &str,
+) -> Result<Vec<String>, DomainError> {
+    let rows = sqlx::query(&format!("PRAGMA table_info(\"{table}\")"))
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(db_error)?;
+    Ok(rows.iter().map(|r| r.get::<String, _>("name")).collect())
+}
+
+fn row_to_json(row: &SqliteRow) -> Result<BackupRow, DomainError> {
+    let mut out = BackupRow::new();
+    for (i, column) in row.columns().iter().enumerate() {
+        let raw = row.try_get_raw(i).map_err(db_error)?;
+        let value = if raw.is_null() {
+            Value::Null
+        } else {
+            match raw.type_info().name() {
+                "INTEGER" => Value::from(row.try_get_unchecked::<i64, _>(i).map_err(db_error)?),
+                "REAL" => Value::from(row.try_get_unchecked::<f64, _>(i).map_err(db_error)?),
+                "TEXT" => Value::from(row.try_get_unchecked::<String, _>(i).map_err(db_error)?),
+                other => {
+                    return Err(DomainError::DatabaseError(format!(
+                        "Unsupported column type {} in backup",
+                        other
+                    )))
+                }
+            }
+        };
+        out.insert(column.name().to_string(), value);
+    }
+    Ok(out)
+}
+
+async fn insert_row(
+    conn: &mut SqliteConnection,
+    table: &str,
+    columns: &HashSet<String>,
+    row: &BackupRow,
+) -> Result<(), DomainError> {
+    let fields: Vec<(&String, &Value)> = row.iter().filter(|(k, _)| columns.contains(*k)).collect();
+    if fields.is_empty() {
+        return Ok(());
+    }
+    let names = fields
+        .iter()
+        .map(|(k, _)| format!("\"{k}\""))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let placeholders = vec!["?"; fields.len()].join(", ");
+    let sql = format!("INSERT INTO \"{table}\" ({names}) VALUES ({placeholders})");
+
+    let mut query = sqlx::query(&sql);
+    for (_, value) in fields {
+        query = match value {
+            Value::Null => query.bind(None::<i64>),
+            Value::Bool(b) => query.bind(*b as i64),
+            Value::Number(n) => match n.as_i64() {
+                Some(i) => query.bind(i),
+                None => query.bind(n.as_f64()),
+            },
+            Value::String(s) => query.bind(s.as_str()),
+            other => query.bind(other.to_string()),
+        };
+    }
+    query.execute(&mut *conn).await.map_err(|e| {
+        DomainError::InvalidInput(format!("Failed to restore a row into {}: {}", table, e))
+    })?;
+    Ok(())
+}
+
+#[async_trait]
+impl BackupStore for SqliteBackupStore {
+    #[instrument(skip(self))]
+    async fn dump(&self) -> Result<BackupTables, DomainError> {
+        let mut conn = self.pool.acquire().await.map_err(db_error)?;
+        let mut tables = BackupTables::new();
+
+        for table in BACKUP_TABLES {
+            if table_columns(&mut conn, table).await?.is_empty() {
+                continue;
+            }
+            let rows = sqlx::query(&format!("SELECT * FROM \"{table}\""))
+                .fetch_all(&mut *conn)
+                .await
+                .map_err(db_error)?;
+            let rows = rows.iter().map(row_to_json).collect::<Result<_, _>>()?;
+            tables.insert(table.to_string(), rows);
+        }
+
+        Ok(tables)
+    }
+
+    #[instrument(skip(self, tables))]
+    async fn restore(&self, tables: &BackupTables) -> Result<BTreeMap<String, usize>, DomainError> {
+        for name in tables.keys() {
+            if !BACKUP_TABLES.contains(&name.as_str()) {
+                warn!(table = %name, "Ignoring unknown table in backup");
+            }
+        }
+
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+        // Rows are re-inserted with their original ids, so references only
+        // need to hold once every table is back.
+        sqlx::query("PRAGMA defer_foreign_keys = ON")
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+
+        let mut present = Vec::new();
+        for table in BACKUP_TABLES {
+            if !tables.contains_key(*table) {
+                continue;
+            }
+            let columns = table_columns(&mut tx, table).await?;
+            if !columns.is_empty() {
+                present.push((*table, columns.into_iter().collect::<HashSet<_>>()));
+            }
+        }
+
+        for (table, _) in present.iter().rev() {
+            sqlx::query(&format!("DELETE FROM \"{table}\""))
+                .execute(&mut *tx)
+                .await
+                .map_err(db_error)?;
+        }
+
+        let mut restored = BTreeMap::new();
+        for (table, columns) in &present {
+            let rows = &tables[*table];
+            for row in rows {
+                insert_row(&mut tx, table, columns, row).await?;
+            }
+            restored.insert(table.to_string(), rows.len());
+        }
+
+        if restored.contains_key("groups") {
+            for table in query_log_tables(&mut *tx).await? {
+                if !table_columns(&mut tx, &table)
+                    .await?
+                    .iter()
+                    .any(|c| c == "group_id")
+                {
+                    continue;
+                }
+                sqlx::query(&format!(
+                    "UPDATE {table} SET group_id = NULL
+                     WHERE group_id IS NOT NULL AND group_id NOT IN (SELECT id FROM groups)"
+                ))
+                .execute(&mut *tx)
+                .await
+                .map_err(db_error)?;
+            }
+        }
+
+        tx.commit().await.map_err(|e| {
+            DomainError::InvalidInput(format!("Backup violates database constraints: {}", e))
+        })?;
+
+        Ok(restored)
+    }
+}
@@ -0,0 +1,73 @@
+use std::io::Read;
+
+use ferrous_dns_application::ports::BackupArchiver;
+use ferrous_dns_domain::DomainError;
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+
+/// Decompressed size above which an archive is rejected.
+const MAX_UNPACKED_BYTES: u64 = 512 * 1024 * 1024;
+
+/// Gzipped tar archiver for full backups; entries are kept in memory.
+#[derive(Default)]
+pub struct TarGzBackupArchiver;
+
+impl BackupArchiver for TarGzBackupArchiver {
+    fn pack(&self, files: &[(String, Vec<u8>)]) -> Result<Vec<u8>, DomainError> {
+        let encoder = GzEncoder::new(Vec::new(), Compression::default());
+        let mut builder = tar::Builder::new(encoder);
+        let mtime = chrono::Utc::now().timestamp().max(0) as u64;
+
+        for (name, contents) in files {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(contents.len() as u64);
+            header.set_mode(0o644);
+            header.set_mtime(mtime);
+            header.set_cksum();
+            builder
+                .append_data(&mut header, name, contents.as_slice())
+                .map_err(archive_error)?;
+        }
+
+        builder
+            .into_inner()
+            .and_then(|encoder| encoder.finish())
+            .map_err(archive_error)
+    }
+
+    fn unpack(&self, bytes: &[u8]) -> Result<Vec<(String, Vec<u8>)>, DomainError> {
+        let mut archive = tar::Archive::new(GzDecoder::new(bytes));
+        let mut files = Vec::new();
+        let mut total = 0u64;
+
+        for entry in archive.entries().map_err(invalid_archive)? {
+            let mut entry = entry.map_err(invalid_archive)?;
+            if !entry.header().entry_type().is_file() {
+                continue;
+            }
+            total += entry.size();
+            if total > MAX_UNPACKED_BYTES {
+                return Err(DomainError::InvalidInput(
+                    "Backup archive exceeds the maximum unpacked size".to_string(),
+                ));
+            }
+            let name = entry
+                .path()
+                .map_err(invalid_archive)?
+                .to_string_lossy()
+                .into_owned();
+            let mut contents = Vec::with_capacity(entry.size() as usize);
+            entry.read_to_end(&mut contents).map_err(invalid_archive)?;
+            files.push((name, contents));
+        }
+
+        Ok(files)
+    }
+}
+
+fn archive_error(e: std::io::Error) -> DomainError {
+    DomainError::IoError(format!("Failed to build backup archive: {}", e))
+}
+
+fn invalid_archive(e: std::io::Error) -> DomainError {
+    DomainError::InvalidInput(format!("Invalid backup archive: {}", e))
+}
@@ -0,0 +1,134 @@
+use crate::repositories::query_log_repository::delete_oldest_query_logs;
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{DatabaseMaintenancePort, DatabaseUsage};
+use ferrous_dns_domain::DomainError;
+use sqlx::{Row, SqlitePool};
+use tracing::{debug, error, instrument};
+
+/// SQLite maintenance adapter over the write pool.
+pub struct SqliteDatabaseMaintenance {
+    pool: SqlitePool,
+}
+
+impl SqliteDatabaseMaintenance {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    async fn pragma_u64(&self, pragma: &str) -> Result<u64, DomainError> {
+        sqlx::query_scalar::<_, i64>(pragma)
+            .fetch_one(&self.pool)
+            .await
+            .map(|v| v.max(0) as u64)
+            .map_err(db_error)
+    }
+
+    /// Path of the main database file; `None` for in-memory databases.
+    async fn main_file_path(&self) -> Result<Option<String>, DomainError> {
+        let rows = sqlx::query("PRAGMA database_list")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(rows
+            .into_iter()
+            .find(|r| r.get::<String, _>("name") == "main")
+            .map(|r| r.get::<String, _>("file"))
+            .filter(|file| !file.is_empty()))
+    }
+}
+
+fn db_error(e: sqlx::Error) -> DomainError {
+    error!(error = %e, "Database maintenance query failed");
+    DomainError::DatabaseError(e.to_string())
+}
+
+/// Total and available bytes of the filesystem holding `path`.
+fn filesystem_space(path: &str) -> (u64, u64) {
+    #[cfg(target_os = "linux")]
+    {
+        use std::ffi::CString;
+        use std::mem::MaybeUninit;
+        let Ok(path) = CString::new(path) else {
+            return (0, 0);
+        };
+        let mut stat = MaybeUninit::<libc::statvfs>::uninit();
+        // SAFETY: statvfs is a standard POSIX syscall, path is a valid C string.
+        if unsafe { libc::statvfs(path.as_ptr(), stat.as_mut_ptr()) } == 0 {
+            // SAFETY: statvfs returned 0, so the struct is initialised.
+            let stat = unsafe { stat.assume_init() };
+            return (stat.f_blocks * stat.f_frsize, stat.f_bavail * stat.f_frsize);
+        }
+    }
+    (0, 0)
+}
+
+async fn file_len(path: &str) -> u64 {
+    tokio::fs::metadata(path)
+        .await
+        .map(|m| m.len())
+        .unwrap_or(0)
+}
+
+#[async_trait]
+impl DatabaseMaintenancePort for SqliteDatabaseMaintenance {
+    async fn usage(&self) -> Result<DatabaseUsage, DomainError> {
+        let page_size = self.pragma_u64("PRAGMA page_size").await?;
+        let page_count = self.pragma_u64("PRAGMA page_count").await?;
+        let freelist_count = self.pragma_u64("PRAGMA freelist_count").await?;
+        let auto_vacuum = self.pragma_u64("PRAGMA auto_vacuum").await?;
+
+        let (file_bytes, wal_bytes, (disk_total_bytes, disk_available_bytes)) =
+            match self.main_file_path().await? {
+                Some(path) => (
+                    file_len(&path).await,
+                    file_len(&format!("{path}-wal")).await,
+                    filesystem_space(&path),
+                ),
+                None => (page_count * page_size, 0, (0, 0)),
+            };
+
+        Ok(DatabaseUsage {
+            file_bytes,
+            wal_bytes,
+            used_bytes: page_count.saturating_sub(freelist_count) * page_size,
+            free_bytes: freelist_count * page_size,
+            incremental_vacuum: auto_vacuum == 2,
+            disk_total_bytes,
+            disk_available_bytes,
+        })
+    }
+
+    #[instrument(skip(self))]
+    async fn optimize(&self) -> Result<(), DomainError> {
+        sqlx::query("PRAGMA optimize")
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn incremental_vacuum(&self) -> Result<u64, DomainError> {
+        let page_size = self.pragma_u64("PRAGMA page_size").await?;
+        let before = self.pragma_u64("PRAGMA freelist_count").await?;
+        // The freelist must be read on the connection that ran the vacuum.
+        let mut conn = self.pool.acquire().await.map_err(db_error)?;
+        sqlx::query("PRAGMA incremental_vacuum")
+            .execute(&mut *conn)
+            .await
+            .map_err(db_error)?;
+        let after = sqlx::query_scalar::<_, i64>("PRAGMA freelist_count")
+            .fetch_one(&mut *conn)
+            .await
+            .map_err(db_error)?
+            .max(0) as u64;
+        let reclaimed = before.saturating_sub(after) * page_size;
+        debug!(reclaimed, "Incremental vacuum completed");
+        Ok(reclaimed)
+    }
+
+    #[instrument(skip(self))]
+    async fn delete_oldest_query_logs(&self, limit: u32) -> Result<u64, DomainError> {
+        delete_oldest_query_logs(&self.pool, limit).await
+    }
+}
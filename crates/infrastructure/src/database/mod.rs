@@ -1,10 +1,15 @@
+mod maintenance;
+
+pub use maintenance::SqliteDatabaseMaintenance;
+
 use ferrous_dns_domain::config::DatabaseConfig;
 use sqlx::sqlite::{
-    SqliteConnectOptions, SqliteConnection, SqliteJournalMode, SqlitePool, SqlitePoolOptions,
-    SqliteSynchronous,
+    SqliteAutoVacuum, SqliteConnectOptions, SqliteConnection, SqliteJournalMode, SqlitePool,
+    SqlitePoolOptions, SqliteSynchronous,
 };
 use std::str::FromStr;
 use std::time::Duration;
+use tracing::info;
 
 fn base_options(database_url: &str) -> Result<SqliteConnectOptions, sqlx::Error> {
     SqliteConnectOptions::from_str(database_url).map(|o| {
@@ -34,8 +39,9 @@ pub async fn create_write_pool(
     database_url: &str,
     cfg: &DatabaseConfig,
 ) -> Result<SqlitePool, sqlx::Error> {
-    let options =
-        base_options(database_url)?.busy_timeout(Duration::from_secs(cfg.write_busy_timeout_secs));
+    let options = base_options(database_url)?
+        .busy_timeout(Duration::from_secs(cfg.write_busy_timeout_secs))
+        .auto_vacuum(SqliteAutoVacuum::Incremental);
 
     let cache_kb = cfg.sqlite_cache_size_kb;
     let mmap_mb = cfg.sqlite_mmap_size_mb;
@@ -58,11 +64,31 @@ pub async fn create_write_pool(
 
     sqlx::migrate!("../../migrations").run(&pool).await?;
 
+    enable_incremental_vacuum(&pool).await?;
+
     sqlx::query("PRAGMA optimize").execute(&pool).await?;
 
     Ok(pool)
 }
 
+/// Databases created before incremental auto-vacuum was enabled need a full
+/// `VACUUM` once before `PRAGMA incremental_vacuum` can release free pages.
+async fn enable_incremental_vacuum(pool: &SqlitePool) -> Result<(), sqlx::Error> {
+    let mut conn = pool.acquire().await?;
+    let mode: i64 = sqlx::query_scalar("PRAGMA auto_vacuum")
+        .fetch_one(&mut *conn)
+        .await?;
+    if mode == 2 {
+        return Ok(());
+    }
+    info!("Converting database to incremental auto-vacuum (one-time full VACUUM)");
+    sqlx::query("PRAGMA auto_vacuum = INCREMENTAL")
+        .execute(&mut *conn)
+        .await?;
+    sqlx::query("VACUUM").execute(&mut *conn).await?;
+    Ok(())
+}
+
 pub async fn create_query_log_pool(
     database_url: &str,
     cfg: &DatabaseConfig,
@@ -0,0 +1,225 @@
+use super::unix_http::{self, REQUEST_TIMEOUT};
+use async_trait::async_trait;
+use ferrous_dns_application::ports::RecordSource;
+use ferrous_dns_domain::{DockerConfig, DomainError, LocalRecord, RecordType};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::net::UnixStream;
+use tokio::sync::Mutex;
+use tracing::debug;
+
+const RESUBSCRIBE_DELAY: Duration = Duration::from_secs(5);
+
+/// `/events` filtered to
+/// `{"type":["container","network"],"event":["start","die","rename","connect","disconnect"]}`.
+const EVENTS_PATH: &str = "/events?filters=%7B%22type%22%3A%5B%22container%22%2C%22network%22%5D%2C%22event%22%3A%5B%22start%22%2C%22die%22%2C%22rename%22%2C%22connect%22%2C%22disconnect%22%5D%7D";
+
+/// An entry of the Engine API's `GET /containers/json`.
+#[derive(Debug, Deserialize)]
+pub struct Container {
+    #[serde(rename = "Id", default)]
+    id: String,
+    #[serde(rename = "Names", default)]
+    names: Vec<String>,
+    #[serde(rename = "NetworkSettings", default)]
+    network_settings: NetworkSettings,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct NetworkSettings {
+    #[serde(rename = "Networks", default)]
+    networks: HashMap<String, EndpointSettings>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EndpointSettings {
+    #[serde(rename = "Aliases", default)]
+    aliases: Option<Vec<String>>,
+    #[serde(rename = "IPAddress", default)]
+    ip_address: String,
+    #[serde(rename = "GlobalIPv6Address", default)]
+    global_ipv6_address: String,
+}
+
+/// Turns running containers into records under `config.domain`.
+///
+/// A container `web` is published as `web` with the address it has on
+/// every network (or every network listed in `config.networks`), and with
+/// the per-network aliases of `include_aliases`, such as Compose service
+/// names, on that network's address. Aliases that are just the container's
+/// short ID are left out. Containers without a network address, such as
+/// `--network host` ones, publish nothing.
+pub fn container_records(config: &DockerConfig, containers: &[Container]) -> Vec<LocalRecord> {
+    let domain = config.normalized_domain();
+    let mut records = Vec::new();
+
+    for container in containers {
+        let names: Vec<String> = container
+            .names
+            .iter()
+            .map(|name| name.trim_start_matches('/'))
+            // Legacy links show up as `/other/alias`.
+            .filter(|name| !name.is_empty() && !name.contains('/'))
+            .map(str::to_ascii_lowercase)
+            .collect();
+
+        let mut networks: Vec<_> = container.network_settings.networks.iter().collect();
+        networks.sort_by(|a, b| a.0.cmp(b.0));
+        for (network, endpoint) in networks {
+            if !config.networks.is_empty() && !config.networks.contains(network) {
+                continue;
+            }
+            let mut hostnames = names.clone();
+            if config.include_aliases {
+                for alias in endpoint.aliases.iter().flatten() {
+                    let alias = alias.to_ascii_lowercase();
+                    if !container.id.starts_with(&alias) && !hostnames.contains(&alias) {
+                        hostnames.push(alias);
+                    }
+                }
+            }
+
+            for address in [&endpoint.ip_address, &endpoint.global_ipv6_address] {
+                let record_type = match address.parse::<IpAddr>() {
+                    Ok(IpAddr::V4(_)) => RecordType::A,
+                    Ok(IpAddr::V6(_)) => RecordType::AAAA,
+                    Err(_) => continue,
+                };
+                for hostname in &hostnames {
+                    records.push(LocalRecord {
+                        domain: Some(Arc::from(domain.as_str())),
+                        ttl: config.ttl,
+                        ..LocalRecord::new(
+                            Arc::from(hostname.as_str()),
+                            record_type,
+                            Arc::from(address.as_str()),
+                        )
+                    });
+                }
+            }
+        }
+    }
+
+    records
+}
+
+/// Lists running containers from the Docker Engine API on every sync and
+/// watches its event stream for containers starting, stopping or changing
+/// networks.
+pub struct DockerRecordSource {
+    config: DockerConfig,
+    socket: PathBuf,
+    events: Mutex<EventStream>,
+}
+
+#[derive(Default)]
+struct EventStream {
+    reader: Option<BufReader<UnixStream>>,
+    /// Whether a subscription existed before, so that events missed while
+    /// resubscribing are made up for with a sync.
+    subscribed: bool,
+}
+
+impl DockerRecordSource {
+    pub fn new(config: &DockerConfig) -> Self {
+        Self {
+            config: config.clone(),
+            socket: PathBuf::from(&config.socket),
+            events: Mutex::new(EventStream::default()),
+        }
+    }
+
+    pub fn socket(&self) -> &std::path::Path {
+        &self.socket
+    }
+
+    /// Opens the event stream and skips past its response headers.
+    async fn subscribe(&self) -> Result<BufReader<UnixStream>, DomainError> {
+        let stream = unix_http::send_get(&self.socket, "docker", EVENTS_PATH)
+            .await
+            .map_err(unavailable)?;
+        let mut reader = BufReader::new(stream);
+        let mut line = String::new();
+        reader
+            .read_line(&mut line)
+            .await
+            .map_err(|e| unavailable(format!("GET /events: {e}")))?;
+        unix_http::check_status(&line).map_err(|e| unavailable(format!("GET /events: {e}")))?;
+        loop {
+            line.clear();
+            let read = reader
+                .read_line(&mut line)
+                .await
+                .map_err(|e| unavailable(format!("GET /events: {e}")))?;
+            if read == 0 || line.trim().is_empty() {
+                return Ok(reader);
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl RecordSource for DockerRecordSource {
+    fn name(&self) -> &'static str {
+        "docker"
+    }
+
+    fn sync_interval_secs(&self) -> u64 {
+        self.config.sync_interval_secs
+    }
+
+    async fn fetch(&self) -> Result<Vec<LocalRecord>, DomainError> {
+        let path = "/containers/json";
+        let body = unix_http::get(&self.socket, "docker", path)
+            .await
+            .map_err(unavailable)?;
+        let containers: Vec<Container> =
+            serde_json::from_slice(&body).map_err(|e| unavailable(format!("GET {path}: {e}")))?;
+        debug!(containers = containers.len(), "Listed Docker containers");
+        Ok(container_records(&self.config, &containers))
+    }
+
+    /// Resolves on every container event. Dropping the future halfway
+    /// through a line leaves the rest of it to be read as the next event,
+    /// which at worst costs one extra sync.
+    async fn changed(&self) {
+        let mut events = self.events.lock().await;
+        loop {
+            let Some(reader) = events.reader.as_mut() else {
+                match tokio::time::timeout(REQUEST_TIMEOUT, self.subscribe()).await {
+                    Ok(Ok(reader)) => {
+                        events.reader = Some(reader);
+                        if std::mem::replace(&mut events.subscribed, true) {
+                            return;
+                        }
+                    }
+                    Ok(Err(e)) => {
+                        debug!(error = %e, "Docker event stream unavailable");
+                        tokio::time::sleep(RESUBSCRIBE_DELAY).await;
+                    }
+                    Err(_) => tokio::time::sleep(RESUBSCRIBE_DELAY).await,
+                }
+                continue;
+            };
+
+            let mut line = String::new();
+            match reader.read_line(&mut line).await {
+                Ok(0) | Err(_) => {
+                    debug!("Docker event stream closed; resubscribing");
+                    events.reader = None;
+                }
+                Ok(_) if line.trim().is_empty() => {}
+                Ok(_) => return,
+            }
+        }
+    }
+}
+
+fn unavailable(message: String) -> DomainError {
+    DomainError::RecordSourceUnavailable(format!("docker: {message}"))
+}
@@ -0,0 +1,233 @@
+use base64::Engine;
+use ferrous_dns_domain::{DomainError, KubernetesConfig};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+const SERVICE_ACCOUNT_DIR: &str = "/var/run/secrets/kubernetes.io/serviceaccount";
+
+/// How requests to the API server are authorised.
+#[derive(Debug, Clone)]
+pub(super) enum ApiToken {
+    None,
+    Static(String),
+    /// Read on every request, so rotated service account tokens work.
+    File(PathBuf),
+}
+
+impl ApiToken {
+    pub(super) fn current(&self) -> Result<Option<String>, DomainError> {
+        match self {
+            Self::None => Ok(None),
+            Self::Static(token) => Ok(Some(token.clone())),
+            Self::File(path) => std::fs::read_to_string(path)
+                .map(|token| Some(token.trim().to_string()))
+                .map_err(|e| unavailable(format!("cannot read token {}: {e}", path.display()))),
+        }
+    }
+}
+
+/// Everything needed to reach the API server.
+#[derive(Debug, Clone)]
+pub(super) struct ApiConnection {
+    /// Base URL without a trailing slash.
+    pub server: String,
+    pub token: ApiToken,
+    pub ca_pem: Option<Vec<u8>>,
+    /// Client certificate followed by its private key, both PEM.
+    pub identity_pem: Option<Vec<u8>>,
+    pub insecure: bool,
+}
+
+impl ApiConnection {
+    /// Resolves the connection from `kubeconfig`, then `api_server`, then
+    /// the in-cluster service account.
+    pub(super) fn resolve(config: &KubernetesConfig) -> Result<Self, DomainError> {
+        let mut connection = if let Some(path) = &config.kubeconfig {
+            Self::from_kubeconfig(Path::new(path), config.context.as_deref())?
+        } else if let Some(server) = &config.api_server {
+            Self {
+                server: server.clone(),
+                token: ApiToken::None,
+                ca_pem: None,
+                identity_pem: None,
+                insecure: false,
+            }
+        } else {
+            Self::in_cluster()?
+        };
+
+        if let Some(path) = &config.token_file {
+            connection.token = ApiToken::File(PathBuf::from(path));
+        }
+        if let Some(path) = &config.ca_file {
+            connection.ca_pem = Some(read_file(Path::new(path))?);
+        }
+        connection.insecure |= config.insecure_skip_tls_verify;
+        connection.server = connection.server.trim_end_matches('/').to_string();
+        Ok(connection)
+    }
+
+    fn in_cluster() -> Result<Self, DomainError> {
+        let host = std::env::var("KUBERNETES_SERVICE_HOST").map_err(|_| {
+            unavailable(
+                "no kubeconfig or api_server configured and not running in a cluster".to_string(),
+            )
+        })?;
+        let port = std::env::var("KUBERNETES_SERVICE_PORT").unwrap_or_else(|_| "443".to_string());
+        let host = if host.contains(':') {
+            format!("[{host}]")
+        } else {
+            host
+        };
+        let dir = Path::new(SERVICE_ACCOUNT_DIR);
+        Ok(Self {
+            server: format!("https://{host}:{port}"),
+            token: ApiToken::File(dir.join("token")),
+            ca_pem: Some(read_file(&dir.join("ca.crt"))?),
+            identity_pem: None,
+            insecure: false,
+        })
+    }
+
+    fn from_kubeconfig(path: &Path, context: Option<&str>) -> Result<Self, DomainError> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| unavailable(format!("cannot read {}: {e}", path.display())))?;
+        let kubeconfig: Kubeconfig = serde_yaml::from_str(&contents)
+            .map_err(|e| unavailable(format!("invalid kubeconfig {}: {e}", path.display())))?;
+        kubeconfig.connection(context, path.parent().unwrap_or(Path::new(".")))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Kubeconfig {
+    #[serde(default)]
+    clusters: Vec<Named<Cluster>>,
+    #[serde(default)]
+    users: Vec<Named<User>>,
+    #[serde(default)]
+    contexts: Vec<Named<Context>>,
+    #[serde(rename = "current-context", default)]
+    current_context: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Named<T> {
+    name: String,
+    #[serde(alias = "cluster", alias = "user", alias = "context")]
+    value: T,
+}
+
+#[derive(Debug, Deserialize)]
+struct Cluster {
+    server: String,
+    #[serde(rename = "certificate-authority")]
+    certificate_authority: Option<String>,
+    #[serde(rename = "certificate-authority-data")]
+    certificate_authority_data: Option<String>,
+    #[serde(rename = "insecure-skip-tls-verify", default)]
+    insecure_skip_tls_verify: bool,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct User {
+    token: Option<String>,
+    #[serde(rename = "tokenFile")]
+    token_file: Option<String>,
+    #[serde(rename = "client-certificate")]
+    client_certificate: Option<String>,
+    #[serde(rename = "client-certificate-data")]
+    client_certificate_data: Option<String>,
+    #[serde(rename = "client-key")]
+    client_key: Option<String>,
+    #[serde(rename = "client-key-data")]
+    client_key_data: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Context {
+    cluster: String,
+    user: Option<String>,
+}
+
+impl Kubeconfig {
+    /// The connection of `context`, or of `current-context`. Relative file
+    /// paths are resolved against `base`, the kubeconfig's directory.
+    fn connection(&self, context: Option<&str>, base: &Path) -> Result<ApiConnection, DomainError> {
+        let name = context
+            .or(self.current_context.as_deref())
+            .ok_or_else(|| unavailable("kubeconfig has no current-context".to_string()))?;
+        let context = find(&self.contexts, name, "context")?;
+        let cluster = find(&self.clusters, &context.cluster, "cluster")?;
+        let default_user = User::default();
+        let user = match &context.user {
+            Some(user) => find(&self.users, user, "user")?,
+            None => &default_user,
+        };
+
+        let token = match (&user.token, &user.token_file) {
+            (Some(token), _) => ApiToken::Static(token.clone()),
+            (None, Some(path)) => ApiToken::File(base.join(path)),
+            (None, None) => ApiToken::None,
+        };
+        let ca_pem = pem_field(
+            &cluster.certificate_authority_data,
+            &cluster.certificate_authority,
+            base,
+        )?;
+        let certificate = pem_field(
+            &user.client_certificate_data,
+            &user.client_certificate,
+            base,
+        )?;
+        let key = pem_field(&user.client_key_data, &user.client_key, base)?;
+        let identity_pem = match (certificate, key) {
+            (Some(mut certificate), Some(key)) => {
+                certificate.push(b'\n');
+                certificate.extend_from_slice(&key);
+                Some(certificate)
+            }
+            _ => None,
+        };
+
+        Ok(ApiConnection {
+            server: cluster.server.clone(),
+            token,
+            ca_pem,
+            identity_pem,
+            insecure: cluster.insecure_skip_tls_verify,
+        })
+    }
+}
+
+fn find<'a, T>(entries: &'a [Named<T>], name: &str, kind: &str) -> Result<&'a T, DomainError> {
+    entries
+        .iter()
+        .find(|entry| entry.name == name)
+        .map(|entry| &entry.value)
+        .ok_or_else(|| unavailable(format!("kubeconfig has no {kind} named '{name}'")))
+}
+
+/// A PEM given inline as base64 (`*-data`) or as a file path.
+fn pem_field(
+    data: &Option<String>,
+    path: &Option<String>,
+    base: &Path,
+) -> Result<Option<Vec<u8>>, DomainError> {
+    if let Some(data) = data {
+        return base64::engine::general_purpose::STANDARD
+            .decode(data.trim())
+            .map(Some)
+            .map_err(|e| unavailable(format!("invalid base64 in kubeconfig: {e}")));
+    }
+    path.as_ref()
+        .map(|path| read_file(&base.join(path)))
+        .transpose()
+}
+
+fn read_file(path: &Path) -> Result<Vec<u8>, DomainError> {
+    std::fs::read(path).map_err(|e| unavailable(format!("cannot read {}: {e}", path.display())))
+}
+
+pub(super) fn unavailable(message: String) -> DomainError {
+    DomainError::RecordSourceUnavailable(format!("kubernetes: {message}"))
+}
@@ -0,0 +1,345 @@
+use super::kubeconfig::{unavailable, ApiConnection};
+use async_trait::async_trait;
+use ferrous_dns_application::ports::RecordSource;
+use ferrous_dns_domain::{DomainError, KubernetesConfig, LocalRecord, RecordType};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::debug;
+
+/// A `*List` response of the Kubernetes API.
+#[derive(Debug, Deserialize)]
+pub struct ObjectList<T> {
+    #[serde(default)]
+    items: Vec<T>,
+}
+
+impl<T> Default for ObjectList<T> {
+    fn default() -> Self {
+        Self { items: Vec::new() }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ObjectMeta {
+    name: String,
+    #[serde(default)]
+    namespace: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct LoadBalancerStatus {
+    #[serde(default)]
+    ingress: Vec<LoadBalancerIngress>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LoadBalancerIngress {
+    ip: Option<String>,
+    hostname: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Service {
+    metadata: ObjectMeta,
+    #[serde(default)]
+    spec: ServiceSpec,
+    #[serde(default)]
+    status: ServiceStatus,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ServiceSpec {
+    #[serde(rename = "type", default)]
+    service_type: String,
+    #[serde(rename = "clusterIPs", default)]
+    cluster_ips: Vec<String>,
+    #[serde(rename = "externalIPs", default)]
+    external_ips: Vec<String>,
+    #[serde(rename = "externalName")]
+    external_name: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ServiceStatus {
+    #[serde(rename = "loadBalancer", default)]
+    load_balancer: LoadBalancerStatus,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Ingress {
+    metadata: ObjectMeta,
+    #[serde(default)]
+    spec: IngressSpec,
+    #[serde(default)]
+    status: IngressStatus,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct IngressSpec {
+    #[serde(default)]
+    rules: Vec<IngressRule>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IngressRule {
+    host: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct IngressStatus {
+    #[serde(rename = "loadBalancer", default)]
+    load_balancer: LoadBalancerStatus,
+}
+
+/// Turns Services and Ingresses into records under `config.domain`.
+///
+/// A Service `web` in namespace `apps` is published as `web.apps`, with its
+/// load balancer and external IPs (and cluster IPs when
+/// `include_cluster_ip` is set); an `ExternalName` Service becomes a CNAME.
+/// An Ingress is published under its own name and under every rule host
+/// that falls within the domain, with its load balancer IPs. A load
+/// balancer that only reports a hostname is published as a CNAME to it.
+pub fn cluster_records(
+    config: &KubernetesConfig,
+    services: &ObjectList<Service>,
+    ingresses: &ObjectList<Ingress>,
+) -> Vec<LocalRecord> {
+    let domain = config.normalized_domain();
+    let mut records = Vec::new();
+
+    if config.publish_services {
+        for service in &services.items {
+            let name = object_name(&service.metadata);
+            if service.spec.service_type == "ExternalName" {
+                if let Some(target) = &service.spec.external_name {
+                    records.push(record(config, &domain, &name, RecordType::CNAME, target));
+                }
+                continue;
+            }
+            let mut addresses: Vec<&str> = service
+                .status
+                .load_balancer
+                .ingress
+                .iter()
+                .filter_map(|lb| lb.ip.as_deref())
+                .chain(service.spec.external_ips.iter().map(String::as_str))
+                .collect();
+            if config.include_cluster_ip {
+                addresses.extend(
+                    service
+                        .spec
+                        .cluster_ips
+                        .iter()
+                        .map(String::as_str)
+                        .filter(|ip| *ip != "None"),
+                );
+            }
+            push_targets(
+                &mut records,
+                config,
+                &domain,
+                &name,
+                &addresses,
+                &service.status.load_balancer,
+            );
+        }
+    }
+
+    if config.publish_ingresses {
+        for ingress in &ingresses.items {
+            let addresses: Vec<&str> = ingress
+                .status
+                .load_balancer
+                .ingress
+                .iter()
+                .filter_map(|lb| lb.ip.as_deref())
+                .collect();
+            let suffix = format!(".{domain}");
+            let hosts = ingress.spec.rules.iter().filter_map(|rule| {
+                let host = rule.host.as_deref()?.trim_end_matches('.');
+                let host = host.to_ascii_lowercase();
+                host.strip_suffix(&suffix).map(str::to_string)
+            });
+            for name in std::iter::once(object_name(&ingress.metadata)).chain(hosts) {
+                push_targets(
+                    &mut records,
+                    config,
+                    &domain,
+                    &name,
+                    &addresses,
+                    &ingress.status.load_balancer,
+                );
+            }
+        }
+    }
+
+    records
+}
+
+/// `<name>.<namespace>`, lowercase.
+fn object_name(metadata: &ObjectMeta) -> String {
+    format!("{}.{}", metadata.name, metadata.namespace).to_ascii_lowercase()
+}
+
+/// A/AAAA records for `addresses`, or a CNAME to the load balancer's
+/// hostname when there are none.
+fn push_targets(
+    records: &mut Vec<LocalRecord>,
+    config: &KubernetesConfig,
+    domain: &str,
+    name: &str,
+    addresses: &[&str],
+    load_balancer: &LoadBalancerStatus,
+) {
+    let before = records.len();
+    for address in addresses {
+        let record_type = match address.parse::<IpAddr>() {
+            Ok(IpAddr::V4(_)) => RecordType::A,
+            Ok(IpAddr::V6(_)) => RecordType::AAAA,
+            Err(_) => continue,
+        };
+        records.push(record(config, domain, name, record_type, address));
+    }
+    if records.len() == before {
+        if let Some(hostname) = load_balancer
+            .ingress
+            .iter()
+            .find_map(|lb| lb.hostname.as_deref())
+        {
+            records.push(record(config, domain, name, RecordType::CNAME, hostname));
+        }
+    }
+}
+
+fn record(
+    config: &KubernetesConfig,
+    domain: &str,
+    name: &str,
+    record_type: RecordType,
+    value: &str,
+) -> LocalRecord {
+    LocalRecord {
+        domain: Some(Arc::from(domain)),
+        ttl: config.ttl,
+        ..LocalRecord::new(Arc::from(name), record_type, Arc::from(value))
+    }
+}
+
+/// Lists Services and Ingresses from the Kubernetes API on every sync.
+pub struct KubernetesRecordSource {
+    client: reqwest::Client,
+    connection: ApiConnection,
+    config: KubernetesConfig,
+}
+
+impl KubernetesRecordSource {
+    pub fn new(config: &KubernetesConfig) -> Result<Self, DomainError> {
+        let connection = ApiConnection::resolve(config)?;
+        let mut builder = reqwest::Client::builder()
+            .user_agent(concat!("ferrous-dns/", env!("CARGO_PKG_VERSION")))
+            .timeout(Duration::from_secs(10))
+            .danger_accept_invalid_certs(connection.insecure);
+        if let Some(pem) = &connection.ca_pem {
+            for certificate in reqwest::Certificate::from_pem_bundle(pem)
+                .map_err(|e| unavailable(format!("invalid CA certificate: {e}")))?
+            {
+                builder = builder.add_root_certificate(certificate);
+            }
+        }
+        if let Some(pem) = &connection.identity_pem {
+            builder = builder.identity(
+                reqwest::Identity::from_pem(pem)
+                    .map_err(|e| unavailable(format!("invalid client certificate: {e}")))?,
+            );
+        }
+        let client = builder
+            .build()
+            .map_err(|e| unavailable(format!("cannot build HTTP client: {e}")))?;
+
+        Ok(Self {
+            client,
+            connection,
+            config: config.clone(),
+        })
+    }
+
+    pub fn server(&self) -> &str {
+        &self.connection.server
+    }
+
+    /// Lists `resource` from every configured namespace, or cluster-wide.
+    async fn list<T: DeserializeOwned>(
+        &self,
+        group_path: &str,
+        resource: &str,
+    ) -> Result<ObjectList<T>, DomainError> {
+        if self.config.namespaces.is_empty() {
+            return self.get(&format!("{group_path}/{resource}")).await;
+        }
+        let mut all = ObjectList::default();
+        for namespace in &self.config.namespaces {
+            let list: ObjectList<T> = self
+                .get(&format!("{group_path}/namespaces/{namespace}/{resource}"))
+                .await?;
+            all.items.extend(list.items);
+        }
+        Ok(all)
+    }
+
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, DomainError> {
+        let url = format!("{}{path}", self.connection.server);
+        let mut request = self.client.get(&url);
+        if let Some(token) = self.connection.token.current()? {
+            request = request.bearer_auth(token);
+        }
+        let response = request
+            .send()
+            .await
+            .map_err(|e| unavailable(format!("GET {path}: {e}")))?;
+        if !response.status().is_success() {
+            return Err(unavailable(format!(
+                "GET {path}: HTTP {}",
+                response.status()
+            )));
+        }
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| unavailable(format!("GET {path}: {e}")))?;
+        serde_json::from_slice(&body).map_err(|e| unavailable(format!("GET {path}: {e}")))
+    }
+}
+
+#[async_trait]
+impl RecordSource for KubernetesRecordSource {
+    fn name(&self) -> &'static str {
+        "kubernetes"
+    }
+
+    fn sync_interval_secs(&self) -> u64 {
+        self.config.sync_interval_secs
+    }
+
+    async fn fetch(&self) -> Result<Vec<LocalRecord>, DomainError> {
+        let services = if self.config.publish_services {
+            self.list::<Service>("/api/v1", "services").await?
+        } else {
+            ObjectList::default()
+        };
+        let ingresses = if self.config.publish_ingresses {
+            self.list::<Ingress>("/apis/networking.k8s.io/v1", "ingresses")
+                .await?
+        } else {
+            ObjectList::default()
+        };
+        debug!(
+            services = services.items.len(),
+            ingresses = ingresses.items.len(),
+            "Listed Kubernetes objects"
+        );
+        Ok(cluster_records(&self.config, &services, &ingresses))
+    }
+}
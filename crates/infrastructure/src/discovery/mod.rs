@@ -0,0 +1,9 @@
+mod docker;
+mod kubeconfig;
+mod kubernetes;
+mod unix_http;
+mod vpn;
+
+pub use docker::{container_records, Container, DockerRecordSource};
+pub use kubernetes::{cluster_records, Ingress, KubernetesRecordSource, ObjectList, Service};
+pub use vpn::{tailscale_records, wireguard_records, TailscaleStatus, VpnPeerSource};
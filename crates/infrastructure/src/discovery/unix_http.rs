@@ -0,0 +1,59 @@
+use std::path::Path;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::UnixStream;
+
+/// Time allowed for a whole [`get`].
+pub(super) const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Connects to `socket` and sends `GET path`. HTTP/1.0 keeps the server
+/// from chunking the body: it is simply everything up to the end of the
+/// connection.
+pub(super) async fn send_get(socket: &Path, host: &str, path: &str) -> Result<UnixStream, String> {
+    let mut stream = UnixStream::connect(socket)
+        .await
+        .map_err(|e| format!("cannot connect to {}: {e}", socket.display()))?;
+    let request = format!(
+        "GET {path} HTTP/1.0\r\nHost: {host}\r\nUser-Agent: ferrous-dns/{}\r\n\r\n",
+        env!("CARGO_PKG_VERSION")
+    );
+    stream
+        .write_all(request.as_bytes())
+        .await
+        .map_err(|e| format!("GET {path}: {e}"))?;
+    Ok(stream)
+}
+
+/// The body of `GET path`, which must answer 200.
+pub(super) async fn get(socket: &Path, host: &str, path: &str) -> Result<Vec<u8>, String> {
+    let request = async {
+        let mut stream = send_get(socket, host, path).await?;
+        let mut response = Vec::new();
+        stream
+            .read_to_end(&mut response)
+            .await
+            .map_err(|e| format!("GET {path}: {e}"))?;
+        response_body(response).map_err(|e| format!("GET {path}: {e}"))
+    };
+    tokio::time::timeout(REQUEST_TIMEOUT, request)
+        .await
+        .map_err(|_| format!("GET {path}: timed out"))?
+}
+
+fn response_body(mut response: Vec<u8>) -> Result<Vec<u8>, String> {
+    let split = response
+        .windows(4)
+        .position(|window| window == b"\r\n\r\n")
+        .ok_or_else(|| "truncated response".to_string())?;
+    let head = String::from_utf8_lossy(&response[..split]);
+    check_status(head.lines().next().unwrap_or_default())?;
+    Ok(response.split_off(split + 4))
+}
+
+pub(super) fn check_status(status_line: &str) -> Result<(), String> {
+    match status_line.split_whitespace().nth(1) {
+        Some("200") => Ok(()),
+        Some(code) => Err(format!("HTTP {code}")),
+        None => Err("malformed response".to_string()),
+    }
+}
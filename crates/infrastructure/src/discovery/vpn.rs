@@ -0,0 +1,231 @@
+use super::unix_http;
+use async_trait::async_trait;
+use ferrous_dns_application::ports::RecordSource;
+use ferrous_dns_domain::{DomainError, LocalRecord, RecordType, VpnPeerProvider, VpnPeersConfig};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::path::Path;
+use std::sync::Arc;
+use tracing::debug;
+
+/// Host the `tailscaled` LocalAPI insists on.
+const TAILSCALE_HOST: &str = "local-tailscaled.sock";
+
+/// The parts of the LocalAPI's `GET /localapi/v0/status` that name nodes.
+#[derive(Debug, Deserialize)]
+pub struct TailscaleStatus {
+    #[serde(rename = "Self")]
+    this_node: Option<TailscaleNode>,
+    #[serde(rename = "Peer", default)]
+    peers: Option<HashMap<String, TailscaleNode>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TailscaleNode {
+    #[serde(rename = "HostName", default)]
+    host_name: String,
+    #[serde(rename = "DNSName", default)]
+    dns_name: String,
+    #[serde(rename = "TailscaleIPs", default)]
+    tailscale_ips: Option<Vec<String>>,
+}
+
+/// Turns the nodes of a tailnet, this one included, into records under
+/// `config.domain`. A node is named by the first label of its MagicDNS
+/// name, or by its hostname when it has none, and answers with its
+/// Tailscale addresses.
+pub fn tailscale_records(config: &VpnPeersConfig, status: &TailscaleStatus) -> Vec<LocalRecord> {
+    let domain = config.normalized_domain();
+    let mut nodes: Vec<&TailscaleNode> = status
+        .this_node
+        .iter()
+        .chain(status.peers.iter().flat_map(|peers| peers.values()))
+        .collect();
+    nodes.sort_by(|a, b| a.dns_name.cmp(&b.dns_name));
+
+    let mut records = Vec::new();
+    for node in nodes {
+        let magic_name = node.dns_name.split('.').next().unwrap_or_default();
+        let name = if magic_name.is_empty() {
+            &node.host_name
+        } else {
+            magic_name
+        };
+        let Some(name) = hostname_label(name) else {
+            continue;
+        };
+        for address in node.tailscale_ips.iter().flatten() {
+            push_address(&mut records, config, &domain, &name, address);
+        }
+    }
+    records
+}
+
+/// Turns the `[Peer]` sections of a WireGuard configuration into records
+/// under `config.domain`.
+///
+/// A peer is named by a `# Name = <host>` (or `# friendly_name`) comment
+/// inside its section, or by a PiVPN-style `### begin <host> ###` line
+/// before it, and answers with the single-host routes of its `AllowedIPs`.
+/// Unnamed peers are skipped.
+pub fn wireguard_records(config: &VpnPeersConfig, contents: &str) -> Vec<LocalRecord> {
+    let domain = config.normalized_domain();
+    let mut records = Vec::new();
+    let mut in_peer = false;
+    let mut next_name: Option<String> = None;
+    let mut name: Option<String> = None;
+    let mut addresses: Vec<&str> = Vec::new();
+
+    let mut flush = |name: &mut Option<String>, addresses: &mut Vec<&str>| {
+        if let Some(name) = name.take().as_deref().and_then(hostname_label) {
+            for address in addresses.iter() {
+                push_address(&mut records, config, &domain, &name, address);
+            }
+        }
+        addresses.clear();
+    };
+
+    for line in contents.lines().map(str::trim) {
+        if line.starts_with('[') {
+            flush(&mut name, &mut addresses);
+            in_peer = line.eq_ignore_ascii_case("[peer]");
+            if in_peer {
+                name = next_name.take();
+            }
+        } else if let Some(comment) = line.strip_prefix('#') {
+            let comment = comment.trim_matches('#').trim();
+            if let Some(begin) = comment.strip_prefix("begin ") {
+                next_name = Some(begin.trim().to_string());
+            } else if let Some((key, value)) = comment.split_once('=') {
+                let key = key.trim().to_ascii_lowercase();
+                if in_peer && (key == "name" || key == "friendly_name") {
+                    name = Some(value.trim().to_string());
+                }
+            }
+        } else if let Some((key, value)) = line.split_once('=') {
+            if in_peer && key.trim().eq_ignore_ascii_case("allowedips") {
+                addresses.extend(
+                    value
+                        .split(',')
+                        .filter_map(|route| host_route(route.trim())),
+                );
+            }
+        }
+    }
+    flush(&mut name, &mut addresses);
+
+    records
+}
+
+/// The address of a `/32` or `/128` route.
+fn host_route(route: &str) -> Option<&str> {
+    let (address, prefix) = route.split_once('/').unwrap_or((route, ""));
+    let host_prefix = match address.parse::<IpAddr>().ok()? {
+        IpAddr::V4(_) => "32",
+        IpAddr::V6(_) => "128",
+    };
+    (prefix.is_empty() || prefix == host_prefix).then_some(address)
+}
+
+/// `name` as a single DNS label: lowercase, with anything other than
+/// letters, digits and hyphens turned into hyphens.
+fn hostname_label(name: &str) -> Option<String> {
+    let label: String = name
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    let label = label.trim_matches('-');
+    (!label.is_empty()).then(|| label.to_string())
+}
+
+fn push_address(
+    records: &mut Vec<LocalRecord>,
+    config: &VpnPeersConfig,
+    domain: &str,
+    name: &str,
+    address: &str,
+) {
+    let record_type = match address.parse::<IpAddr>() {
+        Ok(IpAddr::V4(_)) => RecordType::A,
+        Ok(IpAddr::V6(_)) => RecordType::AAAA,
+        Err(_) => return,
+    };
+    records.push(LocalRecord {
+        domain: Some(Arc::from(domain)),
+        ttl: config.ttl,
+        ..LocalRecord::new(Arc::from(name), record_type, Arc::from(address))
+    });
+}
+
+/// Reads VPN peers from `tailscaled` or a WireGuard configuration on every
+/// sync.
+pub struct VpnPeerSource {
+    config: VpnPeersConfig,
+}
+
+impl VpnPeerSource {
+    pub fn new(config: &VpnPeersConfig) -> Self {
+        Self {
+            config: config.clone(),
+        }
+    }
+
+    /// The socket or file peers are read from.
+    pub fn location(&self) -> &str {
+        match self.config.provider {
+            VpnPeerProvider::Tailscale => &self.config.tailscale_socket,
+            VpnPeerProvider::Wireguard => &self.config.wireguard_config,
+        }
+    }
+
+    fn unavailable(&self, message: String) -> DomainError {
+        DomainError::RecordSourceUnavailable(format!("{}: {message}", self.name()))
+    }
+}
+
+#[async_trait]
+impl RecordSource for VpnPeerSource {
+    fn name(&self) -> &'static str {
+        match self.config.provider {
+            VpnPeerProvider::Tailscale => "tailscale",
+            VpnPeerProvider::Wireguard => "wireguard",
+        }
+    }
+
+    fn sync_interval_secs(&self) -> u64 {
+        self.config.sync_interval_secs
+    }
+
+    async fn fetch(&self) -> Result<Vec<LocalRecord>, DomainError> {
+        let location = Path::new(self.location());
+        match self.config.provider {
+            VpnPeerProvider::Tailscale => {
+                let path = "/localapi/v0/status";
+                let body = unix_http::get(location, TAILSCALE_HOST, path)
+                    .await
+                    .map_err(|e| self.unavailable(e))?;
+                let status: TailscaleStatus = serde_json::from_slice(&body)
+                    .map_err(|e| self.unavailable(format!("GET {path}: {e}")))?;
+                debug!(
+                    peers = status.peers.as_ref().map_or(0, HashMap::len),
+                    "Read Tailscale status"
+                );
+                Ok(tailscale_records(&self.config, &status))
+            }
+            VpnPeerProvider::Wireguard => {
+                let contents = tokio::fs::read_to_string(location).await.map_err(|e| {
+                    self.unavailable(format!("cannot read {}: {e}", location.display()))
+                })?;
+                Ok(wireguard_records(&self.config, &contents))
+            }
+        }
+    }
+}
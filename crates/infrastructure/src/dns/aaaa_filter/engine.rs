@@ -0,0 +1,61 @@
+use arc_swap::ArcSwap;
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{AaaaFilterPort, GroupRepository};
+use ferrous_dns_domain::DomainError;
+use rustc_hash::FxHashSet;
+use std::sync::Arc;
+use tracing::{error, info};
+
+/// Set of groups whose AAAA answers are dropped, backed by an `ArcSwap`.
+///
+/// Meant for networks with broken IPv6 connectivity: clients fall back to
+/// IPv4 immediately instead of waiting for IPv6 connections to time out.
+pub struct AaaaFilterEnforcer {
+    groups: ArcSwap<FxHashSet<i64>>,
+    repo: Arc<dyn GroupRepository>,
+}
+
+impl AaaaFilterEnforcer {
+    /// Initialises the filter by loading the flagged groups from the repository.
+    pub async fn new(repo: Arc<dyn GroupRepository>) -> Result<Arc<Self>, DomainError> {
+        let engine = Arc::new(Self {
+            groups: ArcSwap::from_pointee(FxHashSet::default()),
+            repo,
+        });
+
+        engine.reload_inner().await?;
+        info!("AaaaFilterEnforcer initialised");
+        Ok(engine)
+    }
+
+    async fn reload_inner(&self) -> Result<(), DomainError> {
+        let groups: FxHashSet<i64> = self
+            .repo
+            .get_all()
+            .await?
+            .into_iter()
+            .filter(|g| g.filter_aaaa)
+            .filter_map(|g| g.id)
+            .collect();
+        self.groups.store(Arc::new(groups));
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AaaaFilterPort for AaaaFilterEnforcer {
+    #[inline]
+    fn filters_aaaa(&self, group_id: i64) -> bool {
+        let groups = self.groups.load();
+        !groups.is_empty() && groups.contains(&group_id)
+    }
+
+    async fn reload(&self) -> Result<(), DomainError> {
+        if let Err(e) = self.reload_inner().await {
+            error!(error = %e, "Failed to reload AAAA filter groups");
+            return Err(e);
+        }
+        info!("AAAA filter groups reloaded");
+        Ok(())
+    }
+}
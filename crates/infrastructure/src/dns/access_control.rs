@@ -0,0 +1,190 @@
+use arc_swap::ArcSwap;
+use ferrous_dns_application::ports::{AccessControlPort, AccessControlStats};
+use ferrous_dns_domain::config::ServerConfig;
+use ferrous_dns_domain::AclAction;
+use ipnetwork::IpNetwork;
+use std::net::IpAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use tracing::{info, warn};
+
+/// Name of the ACL guarding the `bind_address` listeners (plain DNS, DoT, DoH).
+pub const DEFAULT_ACL_NAME: &str = "default";
+
+/// Outcome of checking a client against an ACL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AclVerdict {
+    Allow,
+    Refuse,
+    Drop,
+}
+
+struct AclRules {
+    networks: Vec<IpNetwork>,
+    cidrs: Vec<String>,
+    action: AclAction,
+}
+
+impl AclRules {
+    /// Entries that are not valid CIDRs are skipped; config validation
+    /// rejects them at load time.
+    fn new(allowed_clients: &[String], action: AclAction) -> Self {
+        Self {
+            networks: allowed_clients
+                .iter()
+                .filter_map(|cidr| cidr.parse::<IpNetwork>().ok())
+                .collect(),
+            cidrs: allowed_clients.to_vec(),
+            action,
+        }
+    }
+
+    #[inline]
+    fn allows(&self, ip: IpAddr) -> bool {
+        self.networks.is_empty() || self.networks.iter().any(|n| n.contains(ip))
+    }
+}
+
+/// Client allow-list of one listener. Rules are swapped atomically on reload;
+/// the counters survive reloads.
+pub struct AccessControlList {
+    name: Arc<str>,
+    rules: ArcSwap<AclRules>,
+    refused: AtomicU64,
+    dropped: AtomicU64,
+}
+
+impl AccessControlList {
+    pub fn new(name: &str, allowed_clients: &[String], action: AclAction) -> Self {
+        Self {
+            name: Arc::from(name),
+            rules: ArcSwap::from_pointee(AclRules::new(allowed_clients, action)),
+            refused: AtomicU64::new(0),
+            dropped: AtomicU64::new(0),
+        }
+    }
+
+    /// An ACL that admits every client.
+    pub fn permissive(name: &str) -> Self {
+        Self::new(name, &[], AclAction::Refuse)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn update(&self, allowed_clients: &[String], action: AclAction) {
+        self.rules
+            .store(Arc::new(AclRules::new(allowed_clients, action)));
+    }
+
+    /// Side-effect free check for the cache fast path. Rejected clients fall
+    /// through to the slow path, where [`check`](Self::check) counts them.
+    #[inline]
+    pub fn allows(&self, ip: IpAddr) -> bool {
+        self.rules.load().allows(ip)
+    }
+
+    /// Whether the ACL lists no networks and so admits every client.
+    pub fn allows_everyone(&self) -> bool {
+        self.rules.load().networks.is_empty()
+    }
+
+    pub fn check(&self, ip: IpAddr) -> AclVerdict {
+        let rules = self.rules.load();
+        if rules.allows(ip) {
+            return AclVerdict::Allow;
+        }
+        match rules.action {
+            AclAction::Refuse => {
+                self.refused.fetch_add(1, Ordering::Relaxed);
+                AclVerdict::Refuse
+            }
+            AclAction::Drop => {
+                self.dropped.fetch_add(1, Ordering::Relaxed);
+                AclVerdict::Drop
+            }
+        }
+    }
+
+    pub fn stats(&self) -> AccessControlStats {
+        let rules = self.rules.load();
+        AccessControlStats {
+            name: self.name.to_string(),
+            allowed_clients: rules.cidrs.clone(),
+            action: rules.action,
+            refused: self.refused.load(Ordering::Relaxed),
+            dropped: self.dropped.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Registry of the ACLs of all running listeners, keyed by name.
+#[derive(Default)]
+pub struct AccessControlRegistry {
+    lists: RwLock<Vec<Arc<AccessControlList>>>,
+}
+
+impl AccessControlRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the ACL registered under `name`, creating it if needed.
+    /// Listeners sharing a name (DoT and DoH reuse [`DEFAULT_ACL_NAME`]) share
+    /// rules and counters.
+    pub fn register(
+        &self,
+        name: &str,
+        allowed_clients: &[String],
+        action: AclAction,
+    ) -> Arc<AccessControlList> {
+        let mut lists = self.lists.write().unwrap_or_else(|e| e.into_inner());
+        if let Some(existing) = lists.iter().find(|acl| acl.name() == name) {
+            return Arc::clone(existing);
+        }
+        let acl = Arc::new(AccessControlList::new(name, allowed_clients, action));
+        lists.push(Arc::clone(&acl));
+        acl
+    }
+}
+
+impl AccessControlPort for AccessControlRegistry {
+    fn apply(&self, server: &ServerConfig) {
+        let lists = self.lists.read().unwrap_or_else(|e| e.into_inner());
+        for acl in lists.iter() {
+            if acl.name() == DEFAULT_ACL_NAME {
+                acl.update(&server.acl.allowed_clients, server.acl.action);
+                continue;
+            }
+            match server.listeners.iter().find(|l| l.bind == acl.name()) {
+                Some(listener) => acl.update(
+                    &listener.allowed_clients,
+                    listener.action.unwrap_or(server.acl.action),
+                ),
+                None => warn!(
+                    listener = acl.name(),
+                    "Listener removed from config; keeping its ACL until restart"
+                ),
+            }
+        }
+        for listener in &server.listeners {
+            if !lists.iter().any(|acl| acl.name() == listener.bind) {
+                warn!(
+                    listener = %listener.bind,
+                    "New listener in config; restart required to start it"
+                );
+            }
+        }
+        info!(acls = lists.len(), "Access control lists reloaded");
+    }
+
+    fn stats(&self) -> Vec<AccessControlStats> {
+        self.lists
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .map(|acl| acl.stats())
+            .collect()
+    }
+}
@@ -4,7 +4,8 @@ use aho_corasick::AhoCorasick;
 use compact_str::CompactString;
 use dashmap::{DashMap, DashSet};
 use fancy_regex::Regex;
-use ferrous_dns_domain::BlockSource;
+use ferrous_dns_application::ports::{AllowlistMatch, BlocklistMatch, FilterRule, SourceBit};
+use ferrous_dns_domain::{BlockSource, TldFilterMode, TldPolicy};
 use rustc_hash::FxBuildHasher;
 use std::collections::{HashMap, HashSet};
 
@@ -55,6 +56,31 @@ impl AllowlistIndex {
         }
         false
     }
+
+    fn matches(&self, domain: &str, group_id: i64) -> Vec<AllowlistMatch> {
+        let mut matches = Vec::new();
+        if self
+            .group_exact
+            .get(&group_id)
+            .is_some_and(|set| set.contains(domain))
+        {
+            matches.push(AllowlistMatch::Group(FilterRule::Exact));
+        }
+        if self
+            .group_wildcard
+            .get(&group_id)
+            .is_some_and(|trie| trie.lookup(domain) != 0)
+        {
+            matches.push(AllowlistMatch::Group(FilterRule::Wildcard));
+        }
+        if self.global_exact.contains(domain) {
+            matches.push(AllowlistMatch::Global(FilterRule::Exact));
+        }
+        if self.global_wildcard.lookup(domain) != 0 {
+            matches.push(AllowlistMatch::Global(FilterRule::Wildcard));
+        }
+        matches
+    }
 }
 
 impl Default for AllowlistIndex {
@@ -63,8 +89,36 @@ impl Default for AllowlistIndex {
     }
 }
 
+/// A group's TLD policy, compiled for the query path.
+pub struct TldRule {
+    pub mode: TldFilterMode,
+    pub tlds: HashSet<CompactString, FxBuildHasher>,
+}
+
+impl TldRule {
+    #[inline]
+    pub fn blocks(&self, domain: &str) -> bool {
+        let Some(tld) = TldPolicy::tld_of(domain) else {
+            return false;
+        };
+        let listed = self.tlds.contains(tld);
+        match self.mode {
+            TldFilterMode::Allow => !listed,
+            TldFilterMode::Deny => listed,
+        }
+    }
+}
+
 pub struct BlockIndex {
     pub group_masks: HashMap<i64, SourceBitSet>,
+    /// Bit assignment of every enabled source, plus the manual blocklist.
+    pub source_bits: Vec<SourceBit>,
+    /// Exact, wildcard and pattern entries per source bit.
+    pub source_entries: [u64; 64],
+    /// Sources in audit mode: their matches alone do not block.
+    pub audit_bits: SourceBitSet,
+    /// Groups in audit mode: no blocklist match blocks for them.
+    pub audit_groups: HashSet<i64>,
     pub total_blocked_domains: usize,
     pub exact: DashMap<CompactString, SourceBitSet, FxBuildHasher>,
     pub bloom: AtomicBloom,
@@ -76,12 +130,17 @@ pub struct BlockIndex {
     pub allow_regex_patterns: HashMap<i64, Vec<Regex>>,
     pub block_regex_patterns: HashMap<i64, Vec<Regex>>,
     pub groups_with_advanced_rules: HashSet<i64>,
+    pub tld_rules: HashMap<i64, TldRule>,
 }
 
 impl BlockIndex {
     pub fn empty() -> Self {
         Self {
             group_masks: HashMap::new(),
+            source_bits: Vec::new(),
+            source_entries: [0; 64],
+            audit_bits: 0,
+            audit_groups: HashSet::new(),
             total_blocked_domains: 0,
             exact: DashMap::with_hasher(FxBuildHasher),
             bloom: AtomicBloom::new(1000, 0.001),
@@ -93,6 +152,7 @@ impl BlockIndex {
             allow_regex_patterns: HashMap::new(),
             block_regex_patterns: HashMap::new(),
             groups_with_advanced_rules: HashSet::new(),
+            tld_rules: HashMap::new(),
         }
     }
 
@@ -106,10 +166,19 @@ impl BlockIndex {
             .unwrap_or(MANUAL_SOURCE_BIT)
     }
 
+    /// What blocks `domain` for `group_id`, and the bits of every source in
+    /// the group's mask that matches a domain the blocklists block.
+    ///
+    /// When only audit-mode sources match, or the group is in audit mode,
+    /// the domain is not blocked and the matching bits come with `None`.
     #[inline]
-    pub fn is_blocked(&self, domain: &str, group_id: i64) -> Option<BlockSource> {
+    pub fn is_blocked_with_bits(
+        &self,
+        domain: &str,
+        group_id: i64,
+    ) -> (Option<BlockSource>, SourceBitSet) {
         if self.allowlists.is_allowed(domain, group_id) {
-            return None;
+            return (None, 0);
         }
 
         let mask = self.group_mask(group_id);
@@ -119,74 +188,172 @@ impl BlockIndex {
             if let Some(regexes) = self.allow_regex_patterns.get(&group_id) {
                 for r in regexes {
                     if r.is_match(domain).unwrap_or(false) {
-                        return None;
+                        return (None, 0);
                     }
                 }
             }
 
             if let Some(set) = self.managed_denies.get(&group_id) {
                 if set.contains(domain) {
-                    return Some(BlockSource::ManagedDomain);
+                    return (Some(BlockSource::ManagedDomain), 0);
                 }
             }
 
             if let Some(trie) = self.managed_deny_wildcards.get(&group_id) {
                 if trie.lookup(domain) != 0 {
-                    return Some(BlockSource::ManagedDomain);
+                    return (Some(BlockSource::ManagedDomain), 0);
                 }
             }
         }
 
-        if !self.bloom.check(&domain) {
-            if has_advanced {
-                if let Some(regexes) = self.block_regex_patterns.get(&group_id) {
-                    for r in regexes {
-                        if r.is_match(domain).unwrap_or(false) {
-                            return Some(BlockSource::RegexFilter);
-                        }
-                    }
-                }
+        if let Some(rule) = self.tld_rules.get(&group_id) {
+            if rule.blocks(domain) {
+                return (Some(BlockSource::TldPolicy), 0);
             }
-            return None;
         }
 
-        if let Some(entry) = self.exact.get(domain) {
-            if entry.value() & mask != 0 {
-                return Some(BlockSource::Blocklist);
-            }
+        // The bloom filter only holds exact entries, so a miss skips the
+        // exact table but not the wildcard and pattern lookups.
+        let exact_bits = if self.bloom.check(&domain) {
+            self.exact
+                .get(domain)
+                .map_or(0, |entry| *entry.value() & mask)
+        } else {
+            0
+        };
+        let bits = exact_bits | self.wildcard_and_pattern_bits(domain, mask);
+        if bits != 0 && self.enforces(bits, group_id) {
+            return (Some(BlockSource::Blocklist), bits);
         }
 
-        let wildcard_result = self.check_wildcard_and_patterns(domain, mask);
-        if wildcard_result.is_some() {
-            return wildcard_result;
+        if has_advanced && self.matches_block_regex(domain, group_id) {
+            return (Some(BlockSource::RegexFilter), 0);
         }
 
-        if has_advanced {
-            if let Some(regexes) = self.block_regex_patterns.get(&group_id) {
-                for r in regexes {
-                    if r.is_match(domain).unwrap_or(false) {
-                        return Some(BlockSource::RegexFilter);
-                    }
-                }
-            }
-        }
+        (None, bits)
+    }
 
-        None
+    /// Whether a blocklist match by `bits` blocks for `group_id`, rather
+    /// than only being audited.
+    #[inline]
+    fn enforces(&self, bits: SourceBitSet, group_id: i64) -> bool {
+        bits & !self.audit_bits != 0 && !self.audit_groups.contains(&group_id)
     }
 
     #[inline]
-    fn check_wildcard_and_patterns(&self, domain: &str, mask: SourceBitSet) -> Option<BlockSource> {
-        let wildcard_bits = self.wildcard.lookup(domain);
-        if wildcard_bits & mask != 0 {
-            return Some(BlockSource::Blocklist);
-        }
+    fn matches_block_regex(&self, domain: &str, group_id: i64) -> bool {
+        self.block_regex_patterns
+            .get(&group_id)
+            .is_some_and(|regexes| regexes.iter().any(|r| r.is_match(domain).unwrap_or(false)))
+    }
 
+    #[inline]
+    fn wildcard_and_pattern_bits(&self, domain: &str, mask: SourceBitSet) -> SourceBitSet {
+        let mut bits = self.wildcard.lookup(domain) & mask;
         for (ac, source_mask) in &self.patterns {
-            if source_mask & mask != 0 && ac.is_match(domain) {
-                return Some(BlockSource::Blocklist);
+            if source_mask & mask & !bits != 0 && ac.is_match(domain) {
+                bits |= source_mask & mask;
+            }
+        }
+        bits
+    }
+
+    /// Lists every allowlist and blocklist entry matching `domain`, including
+    /// blocklist entries the group's mask excludes. Bypasses the bloom filter
+    /// so the result does not depend on it.
+    pub fn explain(
+        &self,
+        domain: &str,
+        group_id: i64,
+    ) -> (Vec<AllowlistMatch>, Vec<BlocklistMatch>) {
+        let mut allowlist = self.allowlists.matches(domain, group_id);
+        if self
+            .allow_regex_patterns
+            .get(&group_id)
+            .is_some_and(|regexes| regexes.iter().any(|r| r.is_match(domain).unwrap_or(false)))
+        {
+            allowlist.push(AllowlistMatch::Regex);
+        }
+
+        let mask = self.group_mask(group_id);
+        let mut blocklist = Vec::new();
+        let mut push_bits = |rule: FilterRule, bits: SourceBitSet| {
+            if bits != 0 {
+                blocklist.push(BlocklistMatch {
+                    source: BlockSource::Blocklist,
+                    rule,
+                    bits,
+                    active: bits & mask != 0,
+                });
             }
+        };
+
+        push_bits(
+            FilterRule::Exact,
+            self.exact.get(domain).map_or(0, |entry| *entry.value()),
+        );
+        push_bits(FilterRule::Wildcard, self.wildcard.lookup(domain));
+        let pattern_bits = self
+            .patterns
+            .iter()
+            .filter(|(ac, _)| ac.is_match(domain))
+            .fold(0, |bits, (_, source_mask)| bits | source_mask);
+        push_bits(FilterRule::Pattern, pattern_bits);
+
+        let managed = |rule: FilterRule| BlocklistMatch {
+            source: BlockSource::ManagedDomain,
+            rule,
+            bits: 0,
+            active: true,
+        };
+        if self
+            .managed_denies
+            .get(&group_id)
+            .is_some_and(|set| set.contains(domain))
+        {
+            blocklist.push(managed(FilterRule::Exact));
+        }
+        if self
+            .managed_deny_wildcards
+            .get(&group_id)
+            .is_some_and(|trie| trie.lookup(domain) != 0)
+        {
+            blocklist.push(managed(FilterRule::Wildcard));
         }
+        if self
+            .block_regex_patterns
+            .get(&group_id)
+            .is_some_and(|regexes| regexes.iter().any(|r| r.is_match(domain).unwrap_or(false)))
+        {
+            blocklist.push(BlocklistMatch {
+                source: BlockSource::RegexFilter,
+                rule: FilterRule::Regex,
+                bits: 0,
+                active: true,
+            });
+        }
+        if self
+            .tld_rules
+            .get(&group_id)
+            .is_some_and(|rule| rule.blocks(domain))
+        {
+            blocklist.push(BlocklistMatch {
+                source: BlockSource::TldPolicy,
+                rule: FilterRule::Tld,
+                bits: 0,
+                active: true,
+            });
+        }
+
+        (allowlist, blocklist)
+    }
 
-        None
+    /// Returns the sources assigned any of `bits`.
+    pub fn sources_for_bits(&self, bits: SourceBitSet) -> Vec<SourceBit> {
+        self.source_bits
+            .iter()
+            .filter(|source| bits & (1u64 << source.bit) != 0)
+            .cloned()
+            .collect()
     }
 }
@@ -1,17 +1,25 @@
-use super::block_index::{AllowlistIndex, BlockIndex, SourceBitSet, SourceMeta, MANUAL_SOURCE_BIT};
+use super::block_index::{
+    AllowlistIndex, BlockIndex, SourceBitSet, SourceMeta, TldRule, MANUAL_SOURCE_BIT,
+};
+use super::index_cache::{self, SourceDigest};
+use super::progress::CompileProgress;
+use super::snapshot;
 use super::suffix_trie::SuffixTrie;
 use crate::dns::cache::bloom::AtomicBloom;
+use crate::dns::idn::to_ascii_domain;
 use aho_corasick::AhoCorasick;
 use compact_str::CompactString;
 use dashmap::{DashMap, DashSet};
 use fancy_regex::Regex;
-use ferrous_dns_domain::DomainError;
+use ferrous_dns_application::ports::{BlockFilterCompilePhase, SourceBit};
+use ferrous_dns_domain::{DomainError, TldFilterMode};
 use futures::future::join_all;
 use rayon::prelude::*;
 use rustc_hash::FxBuildHasher;
 use sqlx::{Row, SqlitePool};
-use std::collections::HashMap;
-use std::sync::LazyLock;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::{Arc, LazyLock};
 use tracing::{info, warn};
 
 static BLOCKLIST_BUILD_POOL: LazyLock<rayon::ThreadPool> = LazyLock::new(|| {
@@ -53,7 +61,7 @@ pub fn parse_list_line(line: &str) -> Option<ParsedEntry> {
             Some(pos) => &inner[..pos],
             None => inner,
         };
-        let domain = domain.trim().to_ascii_lowercase();
+        let domain = to_ascii_domain(domain.trim()).into_owned();
         if domain.is_empty() || !domain.contains('.') {
             return None;
         }
@@ -64,7 +72,7 @@ pub fn parse_list_line(line: &str) -> Option<ParsedEntry> {
     }
 
     if line.starts_with("*.") {
-        let pattern = line.to_ascii_lowercase();
+        let pattern = to_ascii_domain(line).into_owned();
         return Some(ParsedEntry::Wildcard(pattern));
     }
 
@@ -85,12 +93,12 @@ pub fn parse_list_line(line: &str) -> Option<ParsedEntry> {
             if !domain.contains('.') {
                 return None;
             }
-            return Some(ParsedEntry::Exact(domain.to_ascii_lowercase()));
+            return Some(ParsedEntry::Exact(to_ascii_domain(domain).into_owned()));
         }
     }
 
     if parts.len() == 1 && parts[0].contains('.') {
-        return Some(ParsedEntry::Exact(parts[0].to_ascii_lowercase()));
+        return Some(ParsedEntry::Exact(to_ascii_domain(parts[0]).into_owned()));
     }
 
     None
@@ -121,8 +129,10 @@ async fn fetch_url(url: &str, client: &reqwest::Client) -> Result<String, String
 struct SourceLoad {
     default_group_id: i64,
     sources: Vec<SourceMeta>,
+    source_bits: Vec<SourceBit>,
     url_tasks: Vec<(u8, String)>,
     all_group_ids: Vec<i64>,
+    audit_groups: HashSet<i64>,
 }
 
 async fn load_sources(pool: &SqlitePool) -> Result<SourceLoad, DomainError> {
@@ -134,11 +144,12 @@ async fn load_sources(pool: &SqlitePool) -> Result<SourceLoad, DomainError> {
         .unwrap_or(1);
 
     // Step 1: Load distinct enabled sources for bit assignment (max 63)
-    let source_rows =
-        sqlx::query("SELECT id, url FROM blocklist_sources WHERE enabled = 1 ORDER BY id")
-            .fetch_all(pool)
-            .await
-            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+    let source_rows = sqlx::query(
+        "SELECT id, name, url, audit_mode FROM blocklist_sources WHERE enabled = 1 ORDER BY id",
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
 
     if source_rows.len() > 63 {
         warn!(
@@ -179,6 +190,24 @@ async fn load_sources(pool: &SqlitePool) -> Result<SourceLoad, DomainError> {
         })
         .collect();
 
+    let mut source_bits: Vec<SourceBit> = source_rows
+        .iter()
+        .take(63)
+        .enumerate()
+        .map(|(idx, row)| SourceBit {
+            bit: idx as u8,
+            source_id: Some(row.get("id")),
+            name: Arc::from(row.get::<String, _>("name")),
+            audit_mode: row.get("audit_mode"),
+        })
+        .collect();
+    source_bits.push(SourceBit {
+        bit: MANUAL_SOURCE_BIT.trailing_zeros() as u8,
+        source_id: None,
+        name: Arc::from("Manual blocklist"),
+        audit_mode: false,
+    });
+
     let url_tasks: Vec<(u8, String)> = source_rows
         .iter()
         .take(63)
@@ -190,19 +219,27 @@ async fn load_sources(pool: &SqlitePool) -> Result<SourceLoad, DomainError> {
         .collect();
 
     // Load ALL group IDs so every group gets a mask entry (even if no blocklists)
-    let all_group_ids: Vec<i64> = sqlx::query("SELECT id FROM groups")
+    let group_rows = sqlx::query("SELECT id, audit_mode FROM groups")
         .fetch_all(pool)
         .await
-        .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+    let all_group_ids: Vec<i64> = group_rows
+        .iter()
+        .map(|row| row.get::<i64, _>("id"))
+        .collect();
+    let audit_groups: HashSet<i64> = group_rows
         .iter()
+        .filter(|row| row.get::<bool, _>("audit_mode"))
         .map(|row| row.get::<i64, _>("id"))
         .collect();
 
     Ok(SourceLoad {
         default_group_id,
         sources,
+        source_bits,
         url_tasks,
         all_group_ids,
+        audit_groups,
     })
 }
 
@@ -223,49 +260,91 @@ fn build_group_masks(sources: &[SourceMeta], all_group_ids: &[i64]) -> HashMap<i
     group_masks
 }
 
-async fn fetch_sources_parallel(
-    url_tasks: Vec<(u8, String)>,
-    client: &reqwest::Client,
-) -> HashMap<u8, Vec<ParsedEntry>> {
-    struct FetchResult {
-        bit: u8,
-        text: Option<String>,
+/// Where a compile reads source lists from.
+pub(super) struct ListSources<'a> {
+    /// `None` reads every list from the snapshot, without network access.
+    pub(super) client: Option<&'a reqwest::Client>,
+    /// Lists that cannot be fetched are read from this snapshot instead.
+    pub(super) snapshot: Option<&'a Path>,
+    /// Binary copy of the source tables, reused while the sources match.
+    pub(super) tables: Option<&'a Path>,
+    pub(super) progress: &'a CompileProgress,
+}
+
+/// A compiled index and the source lists it was built from, keyed by URL.
+pub(super) struct CompiledIndex {
+    pub(super) index: BlockIndex,
+    pub(super) lists: HashMap<String, Vec<ParsedEntry>>,
+}
+
+async fn load_lists(
+    urls: HashSet<String>,
+    sources: &ListSources<'_>,
+) -> HashMap<String, Vec<ParsedEntry>> {
+    sources.progress.add_lists(urls.len());
+    let mut lists: HashMap<String, Vec<ParsedEntry>> = HashMap::with_capacity(urls.len());
+
+    if let Some(client) = sources.client {
+        let tasks: Vec<_> = urls
+            .iter()
+            .map(|url| {
+                let client = client.clone();
+                let url = url.clone();
+                tokio::spawn(async move {
+                    let text = fetch_url(&url, &client).await;
+                    (url, text)
+                })
+            })
+            .collect();
+
+        for result in join_all(tasks).await {
+            match result {
+                Ok((url, Ok(text))) => {
+                    info!(url = %url, "Fetched list source");
+                    sources.progress.list_done();
+                    lists.insert(url, parse_list_text(&text));
+                }
+                Ok((url, Err(e))) => {
+                    warn!(url = %url, error = %e, "Failed to fetch list source");
+                }
+                Err(e) => {
+                    warn!(error = %e, "Fetch task panicked");
+                }
+            }
+        }
     }
 
-    let tasks: Vec<_> = url_tasks
+    let missing: HashSet<String> = urls
         .into_iter()
-        .map(|(bit, u)| {
-            let client = client.clone();
-            tokio::spawn(async move {
-                let text = match fetch_url(&u, &client).await {
-                    Ok(t) => {
-                        info!(url = %u, "Fetched blocklist source");
-                        Some(t)
-                    }
-                    Err(e) => {
-                        warn!(url = %u, error = %e, "Failed to fetch blocklist source");
-                        None
-                    }
-                };
-                FetchResult { bit, text }
-            })
-        })
+        .filter(|url| !lists.contains_key(url))
         .collect();
+    let Some(path) = sources.snapshot.filter(|_| !missing.is_empty()) else {
+        return lists;
+    };
 
-    let mut source_entries: HashMap<u8, Vec<ParsedEntry>> = HashMap::new();
-    for result in join_all(tasks).await {
-        match result {
-            Ok(fr) => {
-                if let Some(text) = fr.text {
-                    source_entries.insert(fr.bit, parse_list_text(&text));
-                }
+    let path = path.to_path_buf();
+    match tokio::task::spawn_blocking(move || snapshot::read_lists(&path, &missing)).await {
+        Ok(Ok(saved)) => {
+            if sources.client.is_some() && !saved.is_empty() {
+                warn!(
+                    lists = saved.len(),
+                    "Using the last snapshot for lists that could not be fetched"
+                );
             }
-            Err(e) => {
-                warn!(error = %e, "Fetch task panicked");
+            for (url, entries) in saved {
+                sources.progress.list_done();
+                lists.insert(url, entries);
             }
         }
+        Ok(Err(e)) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Ok(Err(e)) => {
+            warn!(error = %e, "Failed to read list snapshot");
+        }
+        Err(e) => {
+            warn!(error = %e, "List snapshot read task panicked");
+        }
     }
-    source_entries
+    lists
 }
 
 struct ManagedDomainEntry {
@@ -286,7 +365,7 @@ async fn load_managed_domains_for_index(
     let entries: Vec<ManagedDomainEntry> = rows
         .iter()
         .map(|row| ManagedDomainEntry {
-            domain: row.get::<String, _>("domain").to_ascii_lowercase(),
+            domain: to_ascii_domain(&row.get::<String, _>("domain")).into_owned(),
             action: row.get::<String, _>("action"),
             group_id: row.get::<i64, _>("group_id"),
         })
@@ -304,53 +383,47 @@ async fn load_manual_domains(pool: &SqlitePool) -> Result<Vec<String>, DomainErr
 
     let domains: Vec<String> = rows
         .iter()
-        .map(|row| row.get::<String, _>("domain").to_ascii_lowercase())
+        .map(|row| to_ascii_domain(&row.get::<String, _>("domain")).into_owned())
         .collect();
 
     info!(count = domains.len(), "Loaded manual blocklist entries");
     Ok(domains)
 }
 
+/// Blocklist source entries merged across sources, before the manual
+/// blocklist is added.
+pub(super) struct SourceTables {
+    pub(super) exact: DashMap<CompactString, SourceBitSet, FxBuildHasher>,
+    pub(super) wildcards: Vec<(CompactString, SourceBitSet)>,
+    pub(super) patterns: Vec<(u8, Vec<String>)>,
+}
+
 struct BlockIndexData {
     total_exact: usize,
+    source_entries: [u64; 64],
     bloom: AtomicBloom,
     exact: DashMap<CompactString, SourceBitSet, FxBuildHasher>,
     wildcard: SuffixTrie,
     patterns: Vec<(AhoCorasick, SourceBitSet)>,
 }
 
-fn build_exact_and_wildcard(
-    manual_domains: &[String],
-    source_entries: &HashMap<u8, Vec<ParsedEntry>>,
-) -> BlockIndexData {
-    let exact_count: usize = manual_domains.len()
-        + source_entries
-            .values()
-            .flat_map(|entries| entries.iter())
-            .filter(|e| matches!(e, ParsedEntry::Exact(_)))
-            .count();
-
-    let bloom_capacity = (exact_count + 100).max(1000);
-    let bloom = AtomicBloom::new(bloom_capacity, 0.001);
+fn collect_source_tables(source_entries: &[(u8, &[ParsedEntry])]) -> SourceTables {
+    let exact_count: usize = source_entries
+        .iter()
+        .flat_map(|(_, entries)| entries.iter())
+        .filter(|e| matches!(e, ParsedEntry::Exact(_)))
+        .count();
+
     let exact: DashMap<CompactString, SourceBitSet, FxBuildHasher> =
         DashMap::with_capacity_and_hasher(exact_count, FxBuildHasher);
-    let mut wildcard = SuffixTrie::new();
+    let mut wildcards: Vec<(CompactString, SourceBitSet)> = Vec::new();
     let mut patterns_by_source: HashMap<u8, Vec<String>> = HashMap::new();
 
-    for domain in manual_domains {
-        bloom.set(domain);
-        exact
-            .entry(CompactString::new(domain))
-            .and_modify(|bits| *bits |= MANUAL_SOURCE_BIT)
-            .or_insert(MANUAL_SOURCE_BIT);
-    }
-
     BLOCKLIST_BUILD_POOL.install(|| {
         source_entries.par_iter().for_each(|(bit, entries)| {
             let source_bit: SourceBitSet = 1u64 << *bit;
-            for entry in entries {
+            for entry in entries.iter() {
                 if let ParsedEntry::Exact(domain) = entry {
-                    bloom.set(domain);
                     exact
                         .entry(CompactString::new(domain))
                         .and_modify(|bits| *bits |= source_bit)
@@ -362,11 +435,11 @@ fn build_exact_and_wildcard(
 
     for (bit, entries) in source_entries {
         let source_bit: SourceBitSet = 1u64 << *bit;
-        for entry in entries {
+        for entry in entries.iter() {
             match entry {
                 ParsedEntry::Exact(_) => {}
                 ParsedEntry::Wildcard(pattern) => {
-                    wildcard.insert_wildcard(pattern, source_bit);
+                    wildcards.push((CompactString::new(pattern), source_bit));
                 }
                 ParsedEntry::Pattern(pat) => {
                     patterns_by_source
@@ -378,11 +451,57 @@ fn build_exact_and_wildcard(
         }
     }
 
+    let mut patterns: Vec<(u8, Vec<String>)> = patterns_by_source.into_iter().collect();
+    patterns.sort_unstable_by_key(|(bit, _)| *bit);
+
+    SourceTables {
+        exact,
+        wildcards,
+        patterns,
+    }
+}
+
+fn build_exact_and_wildcard(manual_domains: &[String], tables: SourceTables) -> BlockIndexData {
+    let SourceTables {
+        exact,
+        wildcards,
+        patterns: patterns_by_source,
+    } = tables;
+
+    let bloom_capacity = (exact.len() + manual_domains.len() + 100).max(1000);
+    let bloom = AtomicBloom::new(bloom_capacity, 0.001);
+
+    for domain in manual_domains {
+        exact
+            .entry(CompactString::new(domain))
+            .and_modify(|bits| *bits |= MANUAL_SOURCE_BIT)
+            .or_insert(MANUAL_SOURCE_BIT);
+    }
+    let mut source_entries = [0u64; 64];
+    let mut count_bits = |bits: SourceBitSet| {
+        let mut rest = bits;
+        while rest != 0 {
+            source_entries[rest.trailing_zeros() as usize] += 1;
+            rest &= rest - 1;
+        }
+    };
+    for entry in exact.iter() {
+        bloom.set(&entry.key().as_str());
+        count_bits(*entry.value());
+    }
+
+    let mut wildcard = SuffixTrie::new();
+    for (pattern, source_bit) in &wildcards {
+        wildcard.insert_wildcard(pattern, *source_bit);
+        count_bits(*source_bit);
+    }
+
     let mut patterns: Vec<(AhoCorasick, SourceBitSet)> = Vec::new();
     for (bit, pats) in patterns_by_source {
         if pats.is_empty() {
             continue;
         }
+        source_entries[bit as usize] += pats.len() as u64;
         match AhoCorasick::builder()
             .ascii_case_insensitive(true)
             .build(&pats)
@@ -398,6 +517,7 @@ fn build_exact_and_wildcard(
 
     BlockIndexData {
         total_exact: exact.len(),
+        source_entries,
         bloom,
         exact,
         wildcard,
@@ -405,6 +525,41 @@ fn build_exact_and_wildcard(
     }
 }
 
+/// Returns the cached source tables when they were built from the same
+/// sources, or `None` so the caller rebuilds them.
+fn read_source_tables(
+    path: &Path,
+    accept: impl FnOnce(&[SourceDigest]) -> bool,
+) -> Option<SourceTables> {
+    match index_cache::read(path, accept) {
+        Ok(Some(tables)) => {
+            info!(
+                exact = tables.exact.len(),
+                "Loaded blocklist tables from index cache"
+            );
+            Some(tables)
+        }
+        Ok(None) => {
+            info!("Block index cache does not match the current sources");
+            None
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+        Err(e) => {
+            warn!(error = %e, "Ignoring unreadable block index cache");
+            None
+        }
+    }
+}
+
+/// Whether `cached` covers exactly the sources assigned in `url_tasks`.
+fn same_sources(cached: &[SourceDigest], url_tasks: &[(u8, String)]) -> bool {
+    cached.len() == url_tasks.len()
+        && cached
+            .iter()
+            .zip(url_tasks)
+            .all(|(source, (bit, url))| source.bit == *bit && source.url == *url)
+}
+
 struct RegexFilterMaps {
     block_patterns: HashMap<i64, Vec<Regex>>,
     allow_patterns: HashMap<i64, Vec<Regex>>,
@@ -454,31 +609,100 @@ async fn load_regex_filters_for_index(pool: &SqlitePool) -> Result<RegexFilterMa
     })
 }
 
-pub async fn compile_block_index(
+async fn load_tld_rules(pool: &SqlitePool) -> Result<HashMap<i64, TldRule>, DomainError> {
+    let rows = sqlx::query("SELECT group_id, mode, tlds FROM group_tld_policies")
+        .fetch_all(pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+    let mut rules = HashMap::with_capacity(rows.len());
+    for row in &rows {
+        let group_id: i64 = row.get("group_id");
+        let mode: String = row.get("mode");
+        let Ok(mode) = mode.parse::<TldFilterMode>() else {
+            warn!(group_id, mode = %mode, "Skipping TLD policy with unrecognised mode");
+            continue;
+        };
+        let tlds: String = row.get("tlds");
+        let tlds = tlds
+            .split(',')
+            .map(str::trim)
+            .filter(|tld| !tld.is_empty())
+            .map(CompactString::new)
+            .collect();
+        rules.insert(group_id, TldRule { mode, tlds });
+    }
+
+    info!(groups = rules.len(), "Loaded TLD policies");
+    Ok(rules)
+}
+
+pub(super) async fn compile_block_index(
     pool: &SqlitePool,
-    client: &reqwest::Client,
-) -> Result<BlockIndex, DomainError> {
+    sources: &ListSources<'_>,
+) -> Result<CompiledIndex, DomainError> {
     let SourceLoad {
         default_group_id,
-        sources,
+        sources: source_metas,
+        source_bits,
         url_tasks,
         all_group_ids,
+        audit_groups,
     } = load_sources(pool).await?;
 
-    let group_masks = build_group_masks(&sources, &all_group_ids);
-    let source_entries = fetch_sources_parallel(url_tasks, client).await;
+    let group_masks = build_group_masks(&source_metas, &all_group_ids);
+    let whitelist_urls = load_whitelist_source_groups(pool).await?;
     let manual_domains = load_manual_domains(pool).await?;
     let managed_domain_entries = load_managed_domains_for_index(pool).await?;
     let regex_filter_maps = load_regex_filters_for_index(pool).await?;
+    let tld_rules = load_tld_rules(pool).await?;
+
+    // At startup the cache stands in for the block lists, so only the
+    // allowlists are read from the snapshot.
+    let mut cached_tables = None;
+    if let (None, Some(path)) = (sources.client, sources.tables) {
+        let path = path.to_path_buf();
+        let tasks = url_tasks.clone();
+        cached_tables = tokio::task::spawn_blocking(move || {
+            read_source_tables(&path, |cached| same_sources(cached, &tasks))
+        })
+        .await
+        .ok()
+        .flatten();
+    }
 
-    let BlockIndexData {
-        total_exact,
-        bloom,
-        exact,
-        wildcard,
-        patterns,
-    } = tokio::task::spawn_blocking(move || {
-        build_exact_and_wildcard(&manual_domains, &source_entries)
+    let urls: HashSet<String> = url_tasks
+        .iter()
+        .filter(|_| cached_tables.is_none())
+        .map(|(_, url)| url.clone())
+        .chain(whitelist_urls.keys().cloned())
+        .collect();
+    let lists = load_lists(urls, sources).await;
+    sources
+        .progress
+        .set_phase(BlockFilterCompilePhase::Building);
+
+    // A startup compile has already tried the cache above.
+    let tables_path = sources
+        .tables
+        .filter(|_| sources.client.is_some())
+        .map(Path::to_path_buf);
+    let (
+        BlockIndexData {
+            total_exact,
+            source_entries,
+            bloom,
+            exact,
+            wildcard,
+            patterns,
+        },
+        lists,
+    ) = tokio::task::spawn_blocking(move || {
+        let tables = match cached_tables {
+            Some(tables) => tables,
+            None => source_tables(&url_tasks, &lists, tables_path.as_deref()),
+        };
+        (build_exact_and_wildcard(&manual_domains, tables), lists)
     })
     .await
     .map_err(|e| {
@@ -510,8 +734,14 @@ pub async fn compile_block_index(
         "Block index compiled"
     );
 
-    let allowlists =
-        build_allowlist_index(pool, client, default_group_id, &managed_domain_entries).await?;
+    let allowlists = build_allowlist_index(
+        pool,
+        default_group_id,
+        &whitelist_urls,
+        &lists,
+        &managed_domain_entries,
+    )
+    .await?;
 
     let mut groups_with_advanced_rules = std::collections::HashSet::new();
     for gid in managed_denies.keys() {
@@ -527,8 +757,17 @@ pub async fn compile_block_index(
         groups_with_advanced_rules.insert(*gid);
     }
 
-    Ok(BlockIndex {
+    let audit_bits = source_bits
+        .iter()
+        .filter(|source| source.audit_mode)
+        .fold(0, |bits, source| bits | 1u64 << source.bit);
+
+    let index = BlockIndex {
         group_masks,
+        source_bits,
+        source_entries,
+        audit_bits,
+        audit_groups,
         total_blocked_domains: total_exact,
         exact,
         bloom,
@@ -540,13 +779,83 @@ pub async fn compile_block_index(
         allow_regex_patterns: regex_filter_maps.allow_patterns,
         block_regex_patterns: regex_filter_maps.block_patterns,
         groups_with_advanced_rules,
-    })
+        tld_rules,
+    };
+    Ok(CompiledIndex { index, lists })
+}
+
+/// Builds the source tables for `lists`, reusing the cache at `path` when
+/// every source's content hash matches, and rewriting it otherwise.
+fn source_tables(
+    url_tasks: &[(u8, String)],
+    lists: &HashMap<String, Vec<ParsedEntry>>,
+    path: Option<&Path>,
+) -> SourceTables {
+    let Some(path) = path else {
+        return collect_source_tables(&entries_by_bit(url_tasks, lists));
+    };
+
+    let digests: Vec<SourceDigest> = url_tasks
+        .iter()
+        .map(|(bit, url)| SourceDigest {
+            bit: *bit,
+            url: url.clone(),
+            digest: index_cache::list_digest(lists.get(url).map_or(&[][..], Vec::as_slice)),
+        })
+        .collect();
+    if let Some(tables) = read_source_tables(path, |cached| cached == digests.as_slice()) {
+        return tables;
+    }
+
+    let tables = collect_source_tables(&entries_by_bit(url_tasks, lists));
+    if let Err(e) = index_cache::write(path, &digests, &tables) {
+        warn!(error = %e, "Failed to write block index cache");
+        // A stale cache must not be paired with the new snapshot at startup.
+        let _ = std::fs::remove_file(path);
+    }
+    tables
+}
+
+fn entries_by_bit<'a>(
+    url_tasks: &[(u8, String)],
+    lists: &'a HashMap<String, Vec<ParsedEntry>>,
+) -> Vec<(u8, &'a [ParsedEntry])> {
+    url_tasks
+        .iter()
+        .filter_map(|(bit, url)| lists.get(url).map(|entries| (*bit, entries.as_slice())))
+        .collect()
+}
+
+/// Enabled whitelist source URLs and the groups each one applies to. A URL
+/// shared by several sources is fetched once.
+async fn load_whitelist_source_groups(
+    pool: &SqlitePool,
+) -> Result<HashMap<String, Vec<i64>>, DomainError> {
+    let ws_rows = sqlx::query(
+        "SELECT wsg.source_id, wsg.group_id, ws.url
+         FROM whitelist_source_groups wsg
+         JOIN whitelist_sources ws ON ws.id = wsg.source_id
+         WHERE ws.enabled = 1 AND ws.url IS NOT NULL",
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+    let mut url_to_groups: HashMap<String, Vec<i64>> = HashMap::new();
+    for row in &ws_rows {
+        if let Some(url) = row.get::<Option<String>, _>("url") {
+            let group_id: i64 = row.get("group_id");
+            url_to_groups.entry(url).or_default().push(group_id);
+        }
+    }
+    Ok(url_to_groups)
 }
 
 async fn build_allowlist_index(
     pool: &SqlitePool,
-    client: &reqwest::Client,
     _default_group_id: i64,
+    url_to_groups: &HashMap<String, Vec<i64>>,
+    lists: &HashMap<String, Vec<ParsedEntry>>,
     managed_entries: &[ManagedDomainEntry],
 ) -> Result<AllowlistIndex, DomainError> {
     let whitelist_rows = sqlx::query("SELECT domain FROM whitelist")
@@ -558,7 +867,7 @@ async fn build_allowlist_index(
 
     for row in &whitelist_rows {
         let domain: String = row.get("domain");
-        let domain_lc = domain.to_ascii_lowercase();
+        let domain_lc = to_ascii_domain(&domain).into_owned();
         if domain_lc.starts_with("*.") {
             allowlists.global_wildcard.insert_wildcard(&domain_lc, 1u64);
         } else {
@@ -586,78 +895,26 @@ async fn build_allowlist_index(
         }
     }
 
-    let ws_rows = sqlx::query(
-        "SELECT wsg.source_id, wsg.group_id, ws.url
-         FROM whitelist_source_groups wsg
-         JOIN whitelist_sources ws ON ws.id = wsg.source_id
-         WHERE ws.enabled = 1 AND ws.url IS NOT NULL",
-    )
-    .fetch_all(pool)
-    .await
-    .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
-
-    // Deduplicate URLs: each URL is fetched once, applied to all associated groups
-    let mut url_to_groups: HashMap<String, Vec<i64>> = HashMap::new();
-    for row in &ws_rows {
-        if let Some(url) = row.get::<Option<String>, _>("url") {
-            let group_id: i64 = row.get("group_id");
-            url_to_groups.entry(url).or_default().push(group_id);
-        }
-    }
-
-    let deduped_tasks: Vec<(i64, String)> = url_to_groups
-        .keys()
-        .map(|url| (0i64, url.clone()))
-        .collect();
-
-    // Fetch each unique URL once; we'll re-map to group_ids after
-    let fetched: Vec<(String, Option<String>)> = {
-        let tasks: Vec<_> = deduped_tasks
-            .into_iter()
-            .map(|(_, url)| {
-                let client = client.clone();
-                let url_clone = url.clone();
-                let url_for_task = url.clone();
-                tokio::spawn(async move {
-                    let text = match fetch_url(&url_for_task, &client).await {
-                        Ok(t) => Some(t),
-                        Err(e) => {
-                            tracing::warn!(url = %url_for_task, error = %e, "Failed to fetch whitelist source");
-                            None
-                        }
-                    };
-                    (url_clone, text)
-                })
-            })
-            .collect();
-        join_all(tasks)
-            .await
-            .into_iter()
-            .filter_map(|r| r.ok())
-            .collect()
-    };
+    for (url, group_ids) in url_to_groups {
+        let Some(entries) = lists.get(url) else {
+            continue;
+        };
+        for &group_id in group_ids {
+            let exact_set = allowlists
+                .group_exact
+                .entry(group_id)
+                .or_insert_with(|| DashSet::with_hasher(FxBuildHasher));
+            let trie = allowlists.group_wildcard.entry(group_id).or_default();
 
-    for (url, text_opt) in fetched {
-        if let Some(text) = text_opt {
-            let entries = parse_list_text(&text);
-            let group_ids = url_to_groups.get(&url).cloned().unwrap_or_default();
-            for group_id in group_ids {
-                let exact_set = allowlists
-                    .group_exact
-                    .entry(group_id)
-                    .or_insert_with(|| DashSet::with_hasher(FxBuildHasher));
-                let trie = allowlists.group_wildcard.entry(group_id).or_default();
-
-                for entry in &entries {
-                    match entry {
-                        ParsedEntry::Exact(domain) => {
-                            exact_set.insert(CompactString::new(domain));
-                        }
-                        ParsedEntry::Wildcard(pattern) => {
-                            trie.insert_wildcard(pattern, 1u64);
-                        }
-                        ParsedEntry::Pattern(_) => {}
+            for entry in entries {
+                match entry {
+                    ParsedEntry::Exact(domain) => {
+                        exact_set.insert(CompactString::new(domain));
+                    }
+                    ParsedEntry::Wildcard(pattern) => {
+                        trie.insert_wildcard(pattern, 1u64);
                     }
+                    ParsedEntry::Pattern(_) => {}
                 }
             }
         }
@@ -1,3 +1,4 @@
+use super::block_index::SourceBitSet;
 use crate::dns::cache::coarse_clock::coarse_now_secs;
 use ahash::RandomState as AHashRandomState;
 use dashmap::DashMap;
@@ -58,8 +59,11 @@ pub fn decision_key(domain: &str, group_id: i64) -> u64 {
     h.finish()
 }
 
-/// Cached entry: (encoded_source, inserted_at_secs, epoch_at_insert).
-type BlockL0Cache = LruCache<u64, (u8, u64, u64), FxBuildHasher>;
+/// A cached decision and the blocklist source bits behind it.
+pub type CachedDecision = (Option<BlockSource>, SourceBitSet);
+
+/// Cached entry: (encoded_source, source_bits, inserted_at_secs, epoch_at_insert).
+type BlockL0Cache = LruCache<u64, (u8, SourceBitSet, u64, u64), FxBuildHasher>;
 
 thread_local! {
     static BLOCK_L0: RefCell<BlockL0Cache> =
@@ -70,13 +74,13 @@ thread_local! {
 }
 
 #[inline]
-pub fn decision_l0_get_by_key(key: u64) -> Option<Option<BlockSource>> {
+pub fn decision_l0_get_by_key(key: u64) -> Option<CachedDecision> {
     let current_epoch = DECISION_EPOCH.load(Ordering::Acquire);
     BLOCK_L0.with(|c| {
         let mut c = c.borrow_mut();
-        if let Some(&(encoded, inserted_at, epoch)) = c.get(&key) {
+        if let Some(&(encoded, bits, inserted_at, epoch)) = c.get(&key) {
             if epoch == current_epoch && coarse_now_secs().saturating_sub(inserted_at) < TTL_SECS {
-                return Some(decode_source(encoded));
+                return Some((decode_source(encoded), bits));
             }
             c.pop(&key);
         }
@@ -85,12 +89,17 @@ pub fn decision_l0_get_by_key(key: u64) -> Option<Option<BlockSource>> {
 }
 
 #[inline]
-pub fn decision_l0_set_by_key(key: u64, source: Option<BlockSource>) {
+pub fn decision_l0_set_by_key(key: u64, (source, bits): CachedDecision) {
     let current_epoch = DECISION_EPOCH.load(Ordering::Acquire);
     BLOCK_L0.with(|c| {
         c.borrow_mut().put(
             key,
-            (encode_source(source), coarse_now_secs(), current_epoch),
+            (
+                encode_source(source),
+                bits,
+                coarse_now_secs(),
+                current_epoch,
+            ),
         );
     });
 }
@@ -100,7 +109,8 @@ pub fn decision_l0_clear() {
 }
 
 pub struct BlockDecisionCache {
-    inner: DashMap<u64, (u8, u64), FxBuildHasher>,
+    /// (encoded_source, source_bits, expires_at_secs)
+    inner: DashMap<u64, (u8, SourceBitSet, u64), FxBuildHasher>,
 }
 
 impl BlockDecisionCache {
@@ -111,11 +121,11 @@ impl BlockDecisionCache {
     }
 
     #[inline]
-    pub fn get_by_key(&self, key: u64) -> Option<Option<BlockSource>> {
+    pub fn get_by_key(&self, key: u64) -> Option<CachedDecision> {
         if let Some(entry) = self.inner.get(&key) {
-            let (encoded, expires_at) = *entry;
+            let (encoded, bits, expires_at) = *entry;
             if coarse_now_secs() < expires_at {
-                return Some(decode_source(encoded));
+                return Some((decode_source(encoded), bits));
             }
             drop(entry);
             self.inner.remove(&key);
@@ -131,7 +141,7 @@ impl BlockDecisionCache {
         let expired: Vec<u64> = self
             .inner
             .iter()
-            .filter(|e| now >= e.value().1)
+            .filter(|e| now >= e.value().2)
             .map(|e| *e.key())
             .take(EVICTION_BATCH_SIZE)
             .collect();
@@ -142,7 +152,7 @@ impl BlockDecisionCache {
             let oldest = self
                 .inner
                 .iter()
-                .min_by_key(|e| e.value().1)
+                .min_by_key(|e| e.value().2)
                 .map(|e| *e.key());
             if let Some(k) = oldest {
                 self.inner.remove(&k);
@@ -151,17 +161,21 @@ impl BlockDecisionCache {
     }
 
     #[inline]
-    pub fn set_by_key(&self, key: u64, source: Option<BlockSource>) {
+    pub fn set_by_key(&self, key: u64, (source, bits): CachedDecision) {
         self.evict_if_full();
-        self.inner
-            .insert(key, (encode_source(source), coarse_now_secs() + TTL_SECS));
+        self.inner.insert(
+            key,
+            (encode_source(source), bits, coarse_now_secs() + TTL_SECS),
+        );
     }
 
     #[inline]
     pub fn set_by_key_with_ttl(&self, key: u64, source: Option<BlockSource>, ttl_secs: u64) {
         self.evict_if_full();
-        self.inner
-            .insert(key, (encode_source(source), coarse_now_secs() + ttl_secs));
+        self.inner.insert(
+            key,
+            (encode_source(source), 0, coarse_now_secs() + ttl_secs),
+        );
     }
 
     pub fn clear(&self) {
@@ -1,23 +1,33 @@
 use super::block_index::BlockIndex;
-use super::compiler::compile_block_index;
+use super::compiler::{compile_block_index, ListSources};
 use super::decision_cache::{
     decision_key, decision_l0_clear, decision_l0_get_by_key, decision_l0_set_by_key,
-    BlockDecisionCache,
+    BlockDecisionCache, CachedDecision,
 };
+use super::progress::CompileProgress;
+use super::snapshot;
+use super::source_stats::{self, SourceHitCounters};
 use crate::dns::cache::coarse_clock::coarse_now_secs;
 use arc_swap::ArcSwap;
 use async_trait::async_trait;
 use dashmap::DashMap;
-use ferrous_dns_application::ports::{BlockFilterEnginePort, FilterDecision, ScheduleStatePort};
-use ferrous_dns_domain::{BlockSource, ClientSubnet, DomainError, GroupOverride, SubnetMatcher};
+use ferrous_dns_application::ports::{
+    BlockFilterCompilePhase, BlockFilterCompileStatus, BlockFilterEnginePort, BlockIndexOrigin,
+    FilterDecision, FilterExplanation, ScheduleStatePort, SourceHitReport, SourceHitStats,
+};
+use ferrous_dns_domain::{
+    BlockSource, ClientSubnet, DomainError, GroupOverride, SubnetMatcher, Tenant, TenantMatcher,
+};
 use lru::LruCache;
-use rustc_hash::FxBuildHasher;
+use rustc_hash::{FxBuildHasher, FxHashMap};
 use sqlx::{Row, SqlitePool};
 use std::cell::RefCell;
 use std::net::IpAddr;
 use std::num::NonZeroUsize;
+use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Instant;
 use tracing::{error, info, warn};
 
 /// Cached entry: (group_id, expiry_secs, epoch_at_insert).
@@ -42,6 +52,10 @@ pub struct BlockFilterEngine {
     decision_cache: BlockDecisionCache,
     client_groups: Arc<DashMap<IpAddr, i64, FxBuildHasher>>,
     subnet_matcher: ArcSwap<Option<SubnetMatcher>>,
+    /// Tenant subnets, consulted for clients with no group assignment.
+    tenant_matcher: ArcSwap<TenantMatcher>,
+    /// Owning tenant of every tenant group.
+    group_tenants: ArcSwap<FxHashMap<i64, i64>>,
     /// Shared in-memory store of active schedule overrides per group.
     /// Written by `ScheduleEvaluatorJob` every 60 s; read in `check()` on every query.
     schedule_state: Arc<dyn ScheduleStatePort>,
@@ -50,6 +64,15 @@ pub struct BlockFilterEngine {
     default_group_id: i64,
     pool: SqlitePool,
     http_client: reqwest::Client,
+    /// Source lists saved after each compile, used to start filtering
+    /// before the sources can be fetched again.
+    snapshot_path: Option<PathBuf>,
+    /// Binary copy of the merged block lists, written beside the snapshot.
+    tables_path: Option<PathBuf>,
+    progress: CompileProgress,
+    source_hits: SourceHitCounters,
+    /// Serializes compiles so they do not race on the index and snapshot.
+    compile_lock: tokio::sync::Mutex<()>,
 }
 
 impl BlockFilterEngine {
@@ -58,6 +81,27 @@ impl BlockFilterEngine {
         default_group_id: i64,
         schedule_state: Arc<dyn ScheduleStatePort>,
         blocking_enabled: bool,
+    ) -> Result<Arc<Self>, DomainError> {
+        Self::with_index_snapshot(
+            pool,
+            default_group_id,
+            schedule_state,
+            blocking_enabled,
+            None,
+        )
+        .await
+    }
+
+    /// Like `new`, but saves the fetched source lists to `snapshot_path`
+    /// after every compile. When the file exists, the index is first built
+    /// from it, so queries are filtered from the start while the sources are
+    /// fetched again in the background.
+    pub async fn with_index_snapshot(
+        pool: SqlitePool,
+        default_group_id: i64,
+        schedule_state: Arc<dyn ScheduleStatePort>,
+        blocking_enabled: bool,
+        snapshot_path: Option<PathBuf>,
     ) -> Result<Arc<Self>, DomainError> {
         let http_client = reqwest::Client::builder()
             .user_agent("ferrous-dns/1.0 (blocklist-sync)")
@@ -70,26 +114,43 @@ impl BlockFilterEngine {
             decision_cache: BlockDecisionCache::new(),
             client_groups: Arc::new(DashMap::with_hasher(FxBuildHasher)),
             subnet_matcher: ArcSwap::from_pointee(None),
+            tenant_matcher: ArcSwap::from_pointee(TenantMatcher::empty()),
+            group_tenants: ArcSwap::from_pointee(FxHashMap::default()),
             schedule_state,
             blocking_enabled: AtomicBool::new(blocking_enabled),
             default_group_id,
             pool,
             http_client,
+            tables_path: snapshot_path
+                .as_ref()
+                .map(|path| path.with_extension("tables")),
+            snapshot_path,
+            progress: CompileProgress::default(),
+            source_hits: SourceHitCounters::new(),
+            compile_lock: tokio::sync::Mutex::new(()),
         });
 
         engine.load_client_groups_inner().await?;
 
+        if engine
+            .snapshot_path
+            .as_ref()
+            .is_some_and(|path| path.exists())
+        {
+            match engine.compile(false).await {
+                Ok(()) => info!(
+                    domains = engine.compiled_domain_count(),
+                    "Block index loaded from snapshot"
+                ),
+                Err(e) => warn!(error = %e, "Failed to load block index snapshot"),
+            }
+        }
+
         info!("BlockFilterEngine initialized; blocklist compilation starting in background");
         let background_engine = Arc::clone(&engine);
         tokio::spawn(async move {
-            match compile_block_index(&background_engine.pool, &background_engine.http_client).await
-            {
-                Ok(new_index) => {
-                    background_engine.index.store(Arc::new(new_index));
-                    background_engine.decision_cache.clear();
-                    decision_l0_clear();
-                    info!("Block filter compilation completed");
-                }
+            match background_engine.compile(true).await {
+                Ok(()) => info!("Block filter compilation completed"),
                 Err(e) => {
                     error!(
                         error = %e,
@@ -102,19 +163,152 @@ impl BlockFilterEngine {
         Ok(engine)
     }
 
-    fn resolve_group_uncached(&self, ip: IpAddr) -> i64 {
-        if let Some(gid) = self.client_groups.get(&ip) {
-            return *gid;
+    /// Compiles a new index and swaps it in. With `fetch`, lists are
+    /// downloaded and the snapshot is rewritten; without it, every list is
+    /// read from the snapshot.
+    async fn compile(&self, fetch: bool) -> Result<(), DomainError> {
+        let _guard = self.compile_lock.lock().await;
+        let started = Instant::now();
+        self.progress.start(if fetch {
+            BlockFilterCompilePhase::Fetching
+        } else {
+            BlockFilterCompilePhase::LoadingSnapshot
+        });
+
+        let sources = ListSources {
+            client: fetch.then_some(&self.http_client),
+            snapshot: self.snapshot_path.as_deref(),
+            tables: self.tables_path.as_deref(),
+            progress: &self.progress,
+        };
+        let compiled = match compile_block_index(&self.pool, &sources).await {
+            Ok(compiled) => compiled,
+            Err(e) => {
+                self.progress.fail(e.to_string());
+                return Err(e);
+            }
+        };
+
+        let previous = self.index.swap(Arc::new(compiled.index));
+        self.reset_reassigned_bits(&previous);
+        self.decision_cache.clear();
+        decision_l0_clear();
+
+        let origin = if fetch {
+            BlockIndexOrigin::Sources
+        } else {
+            BlockIndexOrigin::Snapshot
+        };
+        self.progress
+            .finish(origin, started.elapsed().as_millis() as u64);
+
+        if let (true, Some(path)) = (fetch, self.snapshot_path.clone()) {
+            let lists = compiled.lists;
+            match tokio::task::spawn_blocking(move || snapshot::write_lists(&path, &lists)).await {
+                Ok(Ok(())) => {}
+                Ok(Err(e)) => warn!(error = %e, "Failed to save block index snapshot"),
+                Err(e) => warn!(error = %e, "Block index snapshot task panicked"),
+            }
+        }
+        Ok(())
+    }
+
+    /// Turns a cached or computed decision into the answer.
+    #[inline]
+    fn decide((source, bits): CachedDecision) -> FilterDecision {
+        match source {
+            Some(source) => FilterDecision::Block(source),
+            None if bits != 0 => FilterDecision::Audit(BlockSource::Blocklist),
+            None => FilterDecision::Allow,
         }
+    }
 
-        let guard = self.subnet_matcher.load();
-        if let Some(matcher) = guard.as_ref() {
-            if let Some(gid) = matcher.find_group_for_ip(ip) {
-                return gid;
+    /// The decision for `domain` with the blocklist sources behind it, from
+    /// the schedule overrides, the decision caches or the index.
+    #[inline]
+    fn lookup(&self, domain: &str, group_id: i64) -> CachedDecision {
+        if !self.blocking_enabled.load(Ordering::Acquire) {
+            return (None, 0);
+        }
+
+        // Schedule override check: O(1) is_empty() guard keeps cost zero when
+        // no schedules are configured. Not cached per-domain — schedule state
+        // changes every minute, not per query.
+        let mut skip_decision_cache = false;
+
+        if !self.schedule_state.is_empty() {
+            match self.schedule_state.get(group_id) {
+                Some(GroupOverride::BlockAll) => {
+                    return (Some(BlockSource::Schedule), 0);
+                }
+                Some(GroupOverride::AllowAll) => {
+                    return (None, 0);
+                }
+                Some(GroupOverride::TimedBypassUntil(t)) if coarse_now_secs() < t => {
+                    return (None, 0);
+                }
+                Some(GroupOverride::TimedBypassUntil(_)) => {
+                    skip_decision_cache = true;
+                }
+                Some(GroupOverride::TimedBlockUntil(t)) if coarse_now_secs() < t => {
+                    return (Some(BlockSource::Schedule), 0);
+                }
+                _ => {} // expired or no override — fall through to normal check
             }
         }
 
-        self.default_group_id
+        let key = decision_key(domain, group_id);
+
+        if !skip_decision_cache {
+            if let Some(cached) = decision_l0_get_by_key(key) {
+                return cached;
+            }
+
+            if let Some(cached) = self.decision_cache.get_by_key(key) {
+                decision_l0_set_by_key(key, cached);
+                return cached;
+            }
+        }
+
+        let guard = self.index.load();
+        let decision = guard.is_blocked_with_bits(domain, group_id);
+
+        self.decision_cache.set_by_key(key, decision);
+        decision_l0_set_by_key(key, decision);
+
+        decision
+    }
+
+    /// Drops the hit counts of bits now carried by a different source.
+    fn reset_reassigned_bits(&self, previous: &BlockIndex) {
+        let current = self.index.load();
+        for source in &current.source_bits {
+            let unchanged = previous
+                .source_bits
+                .iter()
+                .any(|old| old.bit == source.bit && old.source_id == source.source_id);
+            if !unchanged {
+                self.source_hits.reset_bit(source.bit);
+            }
+        }
+    }
+
+    fn resolve_group_uncached(&self, ip: IpAddr) -> i64 {
+        self.resolve_assigned_group(ip)
+            .or_else(|| self.tenant_matcher.load().find_default_group_for_ip(ip))
+            .unwrap_or(self.default_group_id)
+    }
+
+    fn resolve_assigned_group(&self, ip: IpAddr) -> Option<i64> {
+        if let Some(gid) = self.client_groups.get(&ip) {
+            return Some(*gid);
+        }
+
+        let guard = self.subnet_matcher.load();
+        guard
+            .as_ref()
+            .as_ref()
+            .and_then(|matcher| matcher.find_group_for_ip(ip))
     }
 
     async fn load_client_groups_inner(&self) -> Result<(), DomainError> {
@@ -161,10 +355,47 @@ impl BlockFilterEngine {
         };
         self.subnet_matcher.store(Arc::new(matcher));
 
+        self.load_tenants().await?;
+
         info!(clients = client_rows.len(), "Client groups loaded");
 
         Ok(())
     }
+
+    async fn load_tenants(&self) -> Result<(), DomainError> {
+        let tenant_rows = sqlx::query(
+            "SELECT t.id, t.default_group_id, s.subnet_cidr
+             FROM tenants t JOIN tenant_subnets s ON s.tenant_id = t.id",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let tenants: Vec<Tenant> = tenant_rows
+            .iter()
+            .map(|row| Tenant {
+                id: Some(row.get("id")),
+                subnets: vec![Arc::from(row.get::<String, _>("subnet_cidr").as_str())],
+                default_group_id: row.get("default_group_id"),
+                ..Tenant::new(Arc::from(""))
+            })
+            .collect();
+        self.tenant_matcher
+            .store(Arc::new(TenantMatcher::new(&tenants)));
+
+        let group_rows =
+            sqlx::query("SELECT id, tenant_id FROM groups WHERE tenant_id IS NOT NULL")
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        let group_tenants: FxHashMap<i64, i64> = group_rows
+            .iter()
+            .map(|row| (row.get("id"), row.get("tenant_id")))
+            .collect();
+        self.group_tenants.store(Arc::new(group_tenants));
+
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -194,66 +425,72 @@ impl BlockFilterEnginePort for BlockFilterEngine {
         gid
     }
 
+    fn resolve_group_with_default(&self, ip: IpAddr, default_group_id: i64) -> i64 {
+        // Bypasses GROUP_L0: the same IP can reach several listeners.
+        self.resolve_assigned_group(ip).unwrap_or(default_group_id)
+    }
+
     #[inline]
-    fn check(&self, domain: &str, group_id: i64) -> FilterDecision {
-        if !self.blocking_enabled.load(Ordering::Acquire) {
-            return FilterDecision::Allow;
+    fn tenant_of_group(&self, group_id: i64) -> Option<i64> {
+        let group_tenants = self.group_tenants.load();
+        if group_tenants.is_empty() {
+            return None;
         }
+        group_tenants.get(&group_id).copied()
+    }
 
-        // Schedule override check: O(1) is_empty() guard keeps cost zero when
-        // no schedules are configured. Not cached per-domain — schedule state
-        // changes every minute, not per query.
-        let mut skip_decision_cache = false;
-
-        if !self.schedule_state.is_empty() {
-            match self.schedule_state.get(group_id) {
-                Some(GroupOverride::BlockAll) => {
-                    return FilterDecision::Block(BlockSource::Schedule);
-                }
-                Some(GroupOverride::AllowAll) => {
-                    return FilterDecision::Allow;
-                }
-                Some(GroupOverride::TimedBypassUntil(t)) if coarse_now_secs() < t => {
-                    return FilterDecision::Allow;
-                }
-                Some(GroupOverride::TimedBypassUntil(_)) => {
-                    skip_decision_cache = true;
-                }
-                Some(GroupOverride::TimedBlockUntil(t)) if coarse_now_secs() < t => {
-                    return FilterDecision::Block(BlockSource::Schedule);
-                }
-                _ => {} // expired or no override — fall through to normal check
-            }
-        }
+    fn default_group_id(&self) -> Option<i64> {
+        Some(self.default_group_id)
+    }
 
-        let key = decision_key(domain, group_id);
+    #[inline]
+    fn check(&self, domain: &str, group_id: i64) -> FilterDecision {
+        let decision = self.lookup(domain, group_id);
+        self.source_hits.record(decision.1);
+        Self::decide(decision)
+    }
 
-        if !skip_decision_cache {
-            if let Some(cached_source) = decision_l0_get_by_key(key) {
-                return match cached_source {
-                    Some(source) => FilterDecision::Block(source),
-                    None => FilterDecision::Allow,
-                };
-            }
+    #[inline]
+    fn peek(&self, domain: &str, group_id: i64) -> FilterDecision {
+        Self::decide(self.lookup(domain, group_id))
+    }
 
-            if let Some(cached_source) = self.decision_cache.get_by_key(key) {
-                decision_l0_set_by_key(key, cached_source);
-                return match cached_source {
-                    Some(source) => FilterDecision::Block(source),
-                    None => FilterDecision::Allow,
-                };
-            }
-        }
+    fn explain(&self, domain: &str, group_id: i64) -> FilterExplanation {
+        let blocking_enabled = self.blocking_enabled.load(Ordering::Acquire);
+        let schedule_override = if self.schedule_state.is_empty() {
+            None
+        } else {
+            self.schedule_state.get(group_id)
+        };
 
         let guard = self.index.load();
-        let block_source = guard.is_blocked(domain, group_id);
-
-        self.decision_cache.set_by_key(key, block_source);
-        decision_l0_set_by_key(key, block_source);
+        let (allowlist, blocklist) = guard.explain(domain, group_id);
+        let bits = blocklist.iter().fold(0, |bits, m| bits | m.bits);
+
+        let now = coarse_now_secs();
+        let decision = match schedule_override {
+            _ if !blocking_enabled => FilterDecision::Allow,
+            Some(GroupOverride::BlockAll) => FilterDecision::Block(BlockSource::Schedule),
+            Some(GroupOverride::TimedBlockUntil(t)) if now < t => {
+                FilterDecision::Block(BlockSource::Schedule)
+            }
+            Some(GroupOverride::AllowAll) => FilterDecision::Allow,
+            Some(GroupOverride::TimedBypassUntil(t)) if now < t => FilterDecision::Allow,
+            _ => match guard.is_blocked_with_bits(domain, group_id) {
+                (Some(source), _) => FilterDecision::Block(source),
+                (None, 0) => FilterDecision::Allow,
+                (None, _) => FilterDecision::Audit(BlockSource::Blocklist),
+            },
+        };
 
-        match block_source {
-            Some(source) => FilterDecision::Block(source),
-            None => FilterDecision::Allow,
+        FilterExplanation {
+            decision,
+            blocking_enabled,
+            schedule_override,
+            group_mask: guard.group_mask(group_id),
+            allowlist,
+            blocklist,
+            sources: guard.sources_for_bits(bits),
         }
     }
 
@@ -263,28 +500,62 @@ impl BlockFilterEnginePort for BlockFilterEngine {
         let source = Some(ferrous_dns_domain::BlockSource::CnameCloaking);
         self.decision_cache
             .set_by_key_with_ttl(key, source, ttl_secs);
-        decision_l0_set_by_key(key, source);
+        decision_l0_set_by_key(key, (source, 0));
     }
 
     async fn reload(&self) -> Result<(), DomainError> {
         info!("Block filter reload started");
 
-        let new_index = compile_block_index(&self.pool, &self.http_client)
-            .await
-            .map_err(|e| {
-                error!(error = %e, "Block filter reload failed");
-                e
-            })?;
-
-        self.index.store(Arc::new(new_index));
-
-        self.decision_cache.clear();
-        decision_l0_clear();
+        self.compile(true).await.map_err(|e| {
+            error!(error = %e, "Block filter reload failed");
+            e
+        })?;
 
         info!("Block filter reload completed");
         Ok(())
     }
 
+    async fn export_list_snapshot(&self) -> Result<Option<Vec<u8>>, DomainError> {
+        let Some(path) = self.snapshot_path.as_ref() else {
+            return Ok(None);
+        };
+        match tokio::fs::read(path).await {
+            Ok(contents) => Ok(Some(contents)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(DomainError::IoError(format!(
+                "cannot read {}: {e}",
+                path.display()
+            ))),
+        }
+    }
+
+    async fn import_list_snapshot(&self, snapshot: Vec<u8>) -> Result<(), DomainError> {
+        let Some(path) = self.snapshot_path.clone() else {
+            return Err(DomainError::BlockFilterCompileError(
+                "no list snapshot path is configured".to_string(),
+            ));
+        };
+        {
+            let _guard = self.compile_lock.lock().await;
+            // The tables cache is keyed by source URLs only, so it would
+            // shadow lists that changed under the same URLs.
+            let tables = self.tables_path.clone();
+            tokio::task::spawn_blocking(move || {
+                snapshot::write_raw(&path, &snapshot)?;
+                match tables.map(std::fs::remove_file) {
+                    Some(Err(e)) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+                    _ => Ok(()),
+                }
+            })
+            .await
+            .map_err(|e| DomainError::BlockFilterCompileError(e.to_string()))?
+            .map_err(|e| {
+                DomainError::BlockFilterCompileError(format!("cannot save list snapshot: {e}"))
+            })?;
+        }
+        self.compile(false).await
+    }
+
     async fn load_client_groups(&self) -> Result<(), DomainError> {
         self.load_client_groups_inner().await?;
         // Client-to-group mappings changed — invalidate all cached lookups.
@@ -298,6 +569,32 @@ impl BlockFilterEnginePort for BlockFilterEngine {
         self.index.load().total_blocked_domains
     }
 
+    fn compile_status(&self) -> BlockFilterCompileStatus {
+        self.progress.status(self.compiled_domain_count())
+    }
+
+    fn source_hit_stats(&self) -> SourceHitReport {
+        let index = self.index.load();
+        let totals = self.source_hits.totals();
+        let sources = index
+            .source_bits
+            .iter()
+            .map(|source| {
+                let (unique_blocks, redundant_blocks) = totals[source.bit as usize];
+                SourceHitStats {
+                    source: source.clone(),
+                    entries: index.source_entries[source.bit as usize],
+                    unique_blocks,
+                    redundant_blocks,
+                }
+            })
+            .collect();
+        SourceHitReport {
+            window_secs: source_stats::WINDOW_SECS,
+            sources,
+        }
+    }
+
     fn is_blocking_enabled(&self) -> bool {
         self.blocking_enabled.load(Ordering::Acquire)
     }
@@ -0,0 +1,279 @@
+//! Binary copy of the blocklist source tables, so a restart can skip
+//! parsing and merging every list again.
+//!
+//! Layout, integers little-endian:
+//!
+//! ```text
+//! magic "FDNSIDX\0" | version u32
+//! sources u32   { bit u8 | url str32 | digest [u8; 32] }
+//! exact u64     { domain str16 | bits u64 }
+//! wildcards u32 { pattern str16 | bits u64 }
+//! patterns u32  { bit u8 | count u32 { pattern str32 } }
+//! sha256 of everything above
+//! ```
+//!
+//! A file with another version, a bad checksum or sources that do not match
+//! is ignored and the tables are rebuilt from the lists.
+
+use super::block_index::SourceBitSet;
+use super::compiler::{ParsedEntry, SourceTables};
+use compact_str::CompactString;
+use dashmap::DashMap;
+use rustc_hash::FxBuildHasher;
+use sha2::{Digest, Sha256};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+const MAGIC: &[u8; 8] = b"FDNSIDX\0";
+const VERSION: u32 = 1;
+const CHECKSUM_LEN: usize = 32;
+
+/// A source the tables were built from and the hash of its entries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct SourceDigest {
+    pub(super) bit: u8,
+    pub(super) url: String,
+    pub(super) digest: [u8; 32],
+}
+
+/// Hashes a parsed list, so unchanged content is recognised whichever way
+/// it was loaded.
+pub(super) fn list_digest(entries: &[ParsedEntry]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    for entry in entries {
+        let (tag, value) = match entry {
+            ParsedEntry::Exact(domain) => (b'e', domain),
+            ParsedEntry::Wildcard(pattern) => (b'w', pattern),
+            ParsedEntry::Pattern(pattern) => (b'p', pattern),
+        };
+        hasher.update([tag]);
+        hasher.update(value.as_bytes());
+        hasher.update(b"\n");
+    }
+    hasher.finalize().into()
+}
+
+/// Writes `tables` to `path`, replacing the previous file atomically.
+pub(super) fn write(
+    path: &Path,
+    sources: &[SourceDigest],
+    tables: &SourceTables,
+) -> io::Result<()> {
+    let tmp = path.with_extension("tables.tmp");
+    {
+        let mut out = ChecksumWriter::new(BufWriter::new(File::create(&tmp)?));
+        out.write_all(MAGIC)?;
+        out.write_all(&VERSION.to_le_bytes())?;
+
+        out.write_all(&len_u32(sources.len())?.to_le_bytes())?;
+        for source in sources {
+            out.write_all(&[source.bit])?;
+            write_str32(&mut out, &source.url)?;
+            out.write_all(&source.digest)?;
+        }
+
+        out.write_all(&(tables.exact.len() as u64).to_le_bytes())?;
+        for entry in tables.exact.iter() {
+            write_str16(&mut out, entry.key())?;
+            out.write_all(&entry.value().to_le_bytes())?;
+        }
+
+        out.write_all(&len_u32(tables.wildcards.len())?.to_le_bytes())?;
+        for (pattern, bits) in &tables.wildcards {
+            write_str16(&mut out, pattern)?;
+            out.write_all(&bits.to_le_bytes())?;
+        }
+
+        out.write_all(&len_u32(tables.patterns.len())?.to_le_bytes())?;
+        for (bit, patterns) in &tables.patterns {
+            out.write_all(&[*bit])?;
+            out.write_all(&len_u32(patterns.len())?.to_le_bytes())?;
+            for pattern in patterns {
+                write_str32(&mut out, pattern)?;
+            }
+        }
+
+        let (mut inner, checksum) = out.finish();
+        inner.write_all(&checksum)?;
+        inner.into_inner().map_err(|e| e.into_error())?.sync_all()?;
+    }
+    std::fs::rename(&tmp, path)
+}
+
+/// Reads the tables at `path` if `accept` takes the sources they were built
+/// from. Returns `Ok(None)` when it does not.
+pub(super) fn read(
+    path: &Path,
+    accept: impl FnOnce(&[SourceDigest]) -> bool,
+) -> io::Result<Option<SourceTables>> {
+    let data = std::fs::read(path)?;
+    if data.len() < MAGIC.len() + 4 + CHECKSUM_LEN {
+        return Err(invalid("truncated"));
+    }
+    let (body, checksum) = data.split_at(data.len() - CHECKSUM_LEN);
+    if Sha256::digest(body).as_slice() != checksum {
+        return Err(invalid("checksum mismatch"));
+    }
+
+    let mut reader = Reader { data: body };
+    if reader.take(MAGIC.len())? != MAGIC {
+        return Err(invalid("not a block index file"));
+    }
+    let version = reader.u32()?;
+    if version != VERSION {
+        return Err(invalid(&format!("unsupported version {version}")));
+    }
+
+    let source_count = reader.u32()? as usize;
+    let mut sources = Vec::with_capacity(source_count.min(64));
+    for _ in 0..source_count {
+        let bit = reader.u8()?;
+        let url = reader.str32()?.to_string();
+        let digest = reader.take(32)?.try_into().expect("32-byte slice");
+        sources.push(SourceDigest { bit, url, digest });
+    }
+    if !accept(&sources) {
+        return Ok(None);
+    }
+
+    let exact_count = reader.u64()? as usize;
+    let exact = DashMap::with_capacity_and_hasher(exact_count.min(body.len()), FxBuildHasher);
+    for _ in 0..exact_count {
+        let domain = CompactString::new(reader.str16()?);
+        exact.insert(domain, reader.u64()?);
+    }
+
+    let wildcard_count = reader.u32()? as usize;
+    let mut wildcards: Vec<(CompactString, SourceBitSet)> =
+        Vec::with_capacity(wildcard_count.min(body.len()));
+    for _ in 0..wildcard_count {
+        let pattern = CompactString::new(reader.str16()?);
+        wildcards.push((pattern, reader.u64()?));
+    }
+
+    let group_count = reader.u32()? as usize;
+    let mut patterns = Vec::with_capacity(group_count.min(64));
+    for _ in 0..group_count {
+        let bit = reader.u8()?;
+        let count = reader.u32()? as usize;
+        let mut group = Vec::with_capacity(count.min(body.len()));
+        for _ in 0..count {
+            group.push(reader.str32()?.to_string());
+        }
+        patterns.push((bit, group));
+    }
+
+    if !reader.data.is_empty() {
+        return Err(invalid("trailing data"));
+    }
+    Ok(Some(SourceTables {
+        exact,
+        wildcards,
+        patterns,
+    }))
+}
+
+fn invalid(reason: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("block index file: {reason}"),
+    )
+}
+
+fn len_u32(len: usize) -> io::Result<u32> {
+    u32::try_from(len).map_err(|_| invalid("table too large"))
+}
+
+fn write_str16(out: &mut impl Write, value: &str) -> io::Result<()> {
+    let len = u16::try_from(value.len()).map_err(|_| invalid("entry too long"))?;
+    out.write_all(&len.to_le_bytes())?;
+    out.write_all(value.as_bytes())
+}
+
+fn write_str32(out: &mut impl Write, value: &str) -> io::Result<()> {
+    out.write_all(&len_u32(value.len())?.to_le_bytes())?;
+    out.write_all(value.as_bytes())
+}
+
+/// Hashes everything written through it for the trailing checksum.
+struct ChecksumWriter<W> {
+    inner: W,
+    hasher: Sha256,
+}
+
+impl<W: Write> ChecksumWriter<W> {
+    fn new(inner: W) -> Self {
+        Self {
+            inner,
+            hasher: Sha256::new(),
+        }
+    }
+
+    fn finish(self) -> (W, [u8; 32]) {
+        (self.inner, self.hasher.finalize().into())
+    }
+}
+
+impl<W: Write> Write for ChecksumWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.hasher.update(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> io::Result<&'a [u8]> {
+        if self.data.len() < len {
+            return Err(invalid("truncated"));
+        }
+        let (head, rest) = self.data.split_at(len);
+        self.data = rest;
+        Ok(head)
+    }
+
+    fn u8(&mut self) -> io::Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> io::Result<u16> {
+        Ok(u16::from_le_bytes(
+            self.take(2)?.try_into().expect("2 bytes"),
+        ))
+    }
+
+    fn u32(&mut self) -> io::Result<u32> {
+        Ok(u32::from_le_bytes(
+            self.take(4)?.try_into().expect("4 bytes"),
+        ))
+    }
+
+    fn u64(&mut self) -> io::Result<u64> {
+        Ok(u64::from_le_bytes(
+            self.take(8)?.try_into().expect("8 bytes"),
+        ))
+    }
+
+    fn str16(&mut self) -> io::Result<&'a str> {
+        let len = self.u16()? as usize;
+        self.str(len)
+    }
+
+    fn str32(&mut self) -> io::Result<&'a str> {
+        let len = self.u32()? as usize;
+        self.str(len)
+    }
+
+    fn str(&mut self, len: usize) -> io::Result<&'a str> {
+        std::str::from_utf8(self.take(len)?).map_err(|_| invalid("invalid utf-8"))
+    }
+}
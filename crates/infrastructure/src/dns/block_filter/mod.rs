@@ -2,6 +2,12 @@ mod block_index;
 mod compiler;
 mod decision_cache;
 mod engine;
+mod index_cache;
+mod progress;
+mod snapshot;
+mod source_stats;
 mod suffix_trie;
 
+pub(crate) use compiler::{parse_list_line, ParsedEntry};
 pub use engine::BlockFilterEngine;
+pub(crate) use suffix_trie::SuffixTrie;
@@ -0,0 +1,71 @@
+use ferrous_dns_application::ports::{
+    BlockFilterCompilePhase, BlockFilterCompileStatus, BlockIndexOrigin,
+};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// Progress of the compile in flight and the outcome of the last one.
+#[derive(Default)]
+pub(super) struct CompileProgress {
+    lists_total: AtomicUsize,
+    lists_done: AtomicUsize,
+    state: Mutex<CompileState>,
+}
+
+#[derive(Default)]
+struct CompileState {
+    phase: BlockFilterCompilePhase,
+    origin: BlockIndexOrigin,
+    built_at: Option<i64>,
+    last_duration_ms: Option<u64>,
+    last_error: Option<String>,
+}
+
+impl CompileProgress {
+    pub(super) fn start(&self, phase: BlockFilterCompilePhase) {
+        self.lists_total.store(0, Ordering::Relaxed);
+        self.lists_done.store(0, Ordering::Relaxed);
+        self.set_phase(phase);
+    }
+
+    pub(super) fn set_phase(&self, phase: BlockFilterCompilePhase) {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).phase = phase;
+    }
+
+    pub(super) fn add_lists(&self, count: usize) {
+        self.lists_total.fetch_add(count, Ordering::Relaxed);
+    }
+
+    pub(super) fn list_done(&self) {
+        self.lists_done.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(super) fn finish(&self, origin: BlockIndexOrigin, duration_ms: u64) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.phase = BlockFilterCompilePhase::Idle;
+        state.origin = origin;
+        state.built_at = Some(chrono::Utc::now().timestamp());
+        state.last_duration_ms = Some(duration_ms);
+        state.last_error = None;
+    }
+
+    pub(super) fn fail(&self, error: String) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.phase = BlockFilterCompilePhase::Idle;
+        state.last_error = Some(error);
+    }
+
+    pub(super) fn status(&self, compiled_domains: usize) -> BlockFilterCompileStatus {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        BlockFilterCompileStatus {
+            phase: state.phase,
+            origin: state.origin,
+            lists_total: self.lists_total.load(Ordering::Relaxed),
+            lists_done: self.lists_done.load(Ordering::Relaxed),
+            compiled_domains,
+            built_at: state.built_at,
+            last_duration_ms: state.last_duration_ms,
+            last_error: state.last_error.clone(),
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//! On-disk copy of the source lists behind the last compiled block index.
+//!
+//! The file is plain list text, one normalized entry per line, with a
+//! `!source <url>` line ahead of each list. `!` lines are comments to
+//! `parse_list_line`, so each section parses back into the same entries.
+
+use super::compiler::{parse_list_line, ParsedEntry};
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+const HEADER: &str = "!ferrous-dns list snapshot v1";
+const SOURCE_MARKER: &str = "!source ";
+
+/// Writes `lists` to `path`, replacing the previous snapshot atomically.
+pub(super) fn write_lists(
+    path: &Path,
+    lists: &HashMap<String, Vec<ParsedEntry>>,
+) -> std::io::Result<()> {
+    let tmp = path.with_extension("tmp");
+    {
+        let mut out = BufWriter::new(File::create(&tmp)?);
+        writeln!(out, "{HEADER}")?;
+        for (url, entries) in lists {
+            writeln!(out, "{SOURCE_MARKER}{url}")?;
+            for entry in entries {
+                match entry {
+                    ParsedEntry::Exact(domain) => writeln!(out, "{domain}")?,
+                    ParsedEntry::Wildcard(pattern) => writeln!(out, "{pattern}")?,
+                    ParsedEntry::Pattern(pattern) => writeln!(out, "/{pattern}/")?,
+                }
+            }
+        }
+        out.into_inner().map_err(|e| e.into_error())?.sync_all()?;
+    }
+    std::fs::rename(&tmp, path)
+}
+
+/// Replaces the snapshot at `path` with `contents`, a snapshot written by
+/// another instance.
+pub(super) fn write_raw(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    if contents.split(|&b| b == b'\n').next() != Some(HEADER.as_bytes()) {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "not a list snapshot",
+        ));
+    }
+    let tmp = path.with_extension("tmp");
+    {
+        let mut out = File::create(&tmp)?;
+        out.write_all(contents)?;
+        out.sync_all()?;
+    }
+    std::fs::rename(&tmp, path)
+}
+
+/// Reads the lists of the `wanted` URLs from `path`. URLs missing from the
+/// snapshot are absent from the result.
+pub(super) fn read_lists(
+    path: &Path,
+    wanted: &HashSet<String>,
+) -> std::io::Result<HashMap<String, Vec<ParsedEntry>>> {
+    let mut lines = BufReader::new(File::open(path)?).lines();
+    match lines.next().transpose()? {
+        Some(header) if header == HEADER => {}
+        _ => {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "not a list snapshot",
+            ))
+        }
+    }
+
+    let mut lists: HashMap<String, Vec<ParsedEntry>> = HashMap::new();
+    let mut current: Option<String> = None;
+    for line in lines {
+        let line = line?;
+        if let Some(url) = line.strip_prefix(SOURCE_MARKER) {
+            current = wanted.contains(url).then(|| url.to_string());
+            if let Some(url) = &current {
+                lists.entry(url.clone()).or_default();
+            }
+            continue;
+        }
+        if let Some(url) = &current {
+            if let Some(entry) = parse_list_line(&line) {
+                if let Some(entries) = lists.get_mut(url) {
+                    entries.push(entry);
+                }
+            }
+        }
+    }
+    Ok(lists)
+}
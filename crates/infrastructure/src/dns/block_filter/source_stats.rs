@@ -0,0 +1,111 @@
+use super::block_index::SourceBitSet;
+use crate::dns::cache::coarse_clock::coarse_now_secs;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+const BUCKET_SECS: u64 = 3600;
+const BUCKETS: usize = 24;
+
+/// Span of the rolling window the hit counters cover.
+pub(super) const WINDOW_SECS: u64 = BUCKET_SECS * BUCKETS as u64;
+
+struct Bucket {
+    /// Hour the counters belong to, offset by one so zero means unused.
+    period: AtomicU64,
+    unique: [AtomicU64; 64],
+    redundant: [AtomicU64; 64],
+}
+
+impl Bucket {
+    fn new() -> Self {
+        Self {
+            period: AtomicU64::new(0),
+            unique: std::array::from_fn(|_| AtomicU64::new(0)),
+            redundant: std::array::from_fn(|_| AtomicU64::new(0)),
+        }
+    }
+}
+
+/// Hourly per-source-bit counts of blocklist blocks over the last day.
+///
+/// A block matched by a single source counts as unique to it; a block
+/// several sources matched counts as redundant for each of them. Increments
+/// racing with the hourly reset of a bucket may be lost.
+pub(super) struct SourceHitCounters {
+    buckets: Box<[Bucket]>,
+}
+
+impl SourceHitCounters {
+    pub(super) fn new() -> Self {
+        Self {
+            buckets: (0..BUCKETS).map(|_| Bucket::new()).collect(),
+        }
+    }
+
+    /// Records one block by the sources in `bits`.
+    #[inline]
+    pub(super) fn record(&self, bits: SourceBitSet) {
+        if bits == 0 {
+            return;
+        }
+        let bucket = self.current_bucket();
+        let counters = if bits.count_ones() == 1 {
+            &bucket.unique
+        } else {
+            &bucket.redundant
+        };
+        let mut rest = bits;
+        while rest != 0 {
+            counters[rest.trailing_zeros() as usize].fetch_add(1, Ordering::Relaxed);
+            rest &= rest - 1;
+        }
+    }
+
+    fn current_bucket(&self) -> &Bucket {
+        let period = coarse_now_secs() / BUCKET_SECS + 1;
+        let bucket = &self.buckets[(period % BUCKETS as u64) as usize];
+        let seen = bucket.period.load(Ordering::Acquire);
+        if seen != period
+            && bucket
+                .period
+                .compare_exchange(seen, period, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+        {
+            for counter in bucket.unique.iter().chain(bucket.redundant.iter()) {
+                counter.store(0, Ordering::Relaxed);
+            }
+        }
+        bucket
+    }
+
+    /// Unique and redundant blocks per bit within the window.
+    pub(super) fn totals(&self) -> [(u64, u64); 64] {
+        let period = coarse_now_secs() / BUCKET_SECS + 1;
+        let mut totals = [(0, 0); 64];
+        for bucket in self.buckets.iter() {
+            let seen = bucket.period.load(Ordering::Acquire);
+            if seen == 0 || period.saturating_sub(seen) >= BUCKETS as u64 {
+                continue;
+            }
+            for (bit, total) in totals.iter_mut().enumerate() {
+                total.0 += bucket.unique[bit].load(Ordering::Relaxed);
+                total.1 += bucket.redundant[bit].load(Ordering::Relaxed);
+            }
+        }
+        totals
+    }
+
+    /// Forgets the counts of `bit`, after it was assigned to another source.
+    pub(super) fn reset_bit(&self, bit: u8) {
+        let bit = bit as usize;
+        for bucket in self.buckets.iter() {
+            bucket.unique[bit].store(0, Ordering::Relaxed);
+            bucket.redundant[bit].store(0, Ordering::Relaxed);
+        }
+    }
+}
+
+impl Default for SourceHitCounters {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,4 +1,7 @@
-use super::{coarse_clock::coarse_now_secs, storage::DnsCache};
+use super::{
+    coarse_clock::coarse_now_secs,
+    storage::{entry_bytes, DnsCache},
+};
 use std::sync::atomic::Ordering as AtomicOrdering;
 use tracing::debug;
 
@@ -6,9 +9,13 @@ impl DnsCache {
     pub fn compact(&self) -> usize {
         let before = self.cache.len();
         let now = coarse_now_secs();
-        self.cache.retain(|_, record| {
-            !record.is_marked_for_deletion()
-                && (!record.is_expired_at_secs(now) || record.is_stale_usable_at_secs(now))
+        self.cache.retain(|key, record| {
+            let keep = !record.is_marked_for_deletion()
+                && (!record.is_expired_at_secs(now) || record.is_stale_usable_at_secs(now));
+            if !keep {
+                self.release_bytes(entry_bytes(key, &record.data));
+            }
+            keep
         });
         let removed = before.saturating_sub(self.cache.len());
 
@@ -70,6 +70,19 @@ impl CachedData {
         }
     }
 
+    /// Approximate heap bytes held by the payload.
+    pub fn heap_bytes(&self) -> usize {
+        match self {
+            CachedData::IpAddresses(entry) => {
+                std::mem::size_of::<Vec<IpAddr>>()
+                    + entry.addresses.len() * std::mem::size_of::<IpAddr>()
+            }
+            CachedData::CanonicalName(name) => name.len(),
+            CachedData::WireData(bytes) => bytes.len(),
+            CachedData::NegativeResponse => 0,
+        }
+    }
+
     pub fn is_negative(&self) -> bool {
         matches!(self, CachedData::NegativeResponse)
     }
@@ -0,0 +1,240 @@
+use super::coarse_clock::coarse_now_secs;
+use super::key::{BorrowedKey, CacheKey};
+use rustc_hash::FxHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU32, AtomicU64, AtomicUsize, Ordering as AtomicOrdering};
+use std::sync::Mutex;
+
+const SKETCH_DEPTH: usize = 4;
+const SKETCH_WIDTH: usize = 2048;
+const MAX_CANDIDATES: usize = 64;
+
+/// Length of one sketch generation. Estimates sum the active and the previous
+/// generation, so they cover the last one to two generations of hits.
+pub const HOT_KEY_GENERATION_SECS: u64 = 30;
+
+/// Sliding window reported alongside hot-key estimates.
+pub const HOT_KEY_WINDOW_SECS: u64 = HOT_KEY_GENERATION_SECS * 2;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HotKey {
+    pub key: CacheKey,
+    pub hits: u64,
+}
+
+struct Generation {
+    counters: Vec<AtomicU32>,
+    total: AtomicU64,
+}
+
+impl Generation {
+    fn new() -> Self {
+        Self {
+            counters: (0..SKETCH_DEPTH * SKETCH_WIDTH)
+                .map(|_| AtomicU32::new(0))
+                .collect(),
+            total: AtomicU64::new(0),
+        }
+    }
+
+    fn clear(&self) {
+        for counter in &self.counters {
+            counter.store(0, AtomicOrdering::Relaxed);
+        }
+        self.total.store(0, AtomicOrdering::Relaxed);
+    }
+}
+
+/// Count-min sketch of cache hits over a sliding window, plus a small set of
+/// candidate keys whose estimates are the highest seen so far.
+///
+/// Recording a hit costs a few relaxed atomics; the candidate set is only
+/// touched when a key's estimate beats the coldest candidate, and then only
+/// with `try_lock`, so contended hits skip the update instead of waiting.
+pub struct HotKeyTracker {
+    generations: [Generation; 2],
+    active: AtomicUsize,
+    generation_started: AtomicU64,
+    candidates: Mutex<Vec<HotKey>>,
+    floor: AtomicU64,
+}
+
+impl Default for HotKeyTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HotKeyTracker {
+    pub fn new() -> Self {
+        Self {
+            generations: [Generation::new(), Generation::new()],
+            active: AtomicUsize::new(0),
+            generation_started: AtomicU64::new(coarse_now_secs()),
+            candidates: Mutex::new(Vec::with_capacity(MAX_CANDIDATES)),
+            floor: AtomicU64::new(0),
+        }
+    }
+
+    #[inline]
+    pub fn record(&self, key: &BorrowedKey<'_>) {
+        self.maybe_rotate(coarse_now_secs());
+
+        let a = self.active.load(AtomicOrdering::Acquire);
+        let (current, previous) = (&self.generations[a], &self.generations[1 - a]);
+        current.total.fetch_add(1, AtomicOrdering::Relaxed);
+
+        let (h1, h2) = Self::double_hash(key);
+        let mut estimate = u64::MAX;
+        for row in 0..SKETCH_DEPTH {
+            let idx = Self::index(h1, h2, row);
+            let hits = current.counters[idx].fetch_add(1, AtomicOrdering::Relaxed) as u64 + 1;
+            let hits = hits + previous.counters[idx].load(AtomicOrdering::Relaxed) as u64;
+            estimate = estimate.min(hits);
+        }
+
+        if estimate > self.floor.load(AtomicOrdering::Relaxed) {
+            self.offer(key, estimate);
+        }
+    }
+
+    /// Returns up to `limit` candidates ordered by estimated hits, highest first.
+    pub fn top(&self, limit: usize) -> Vec<HotKey> {
+        self.maybe_rotate(coarse_now_secs());
+        let mut keys: Vec<HotKey> = self
+            .candidates
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .map(|candidate| HotKey {
+                key: candidate.key.clone(),
+                hits: self.estimate(&candidate.key),
+            })
+            .filter(|candidate| candidate.hits > 0)
+            .collect();
+        keys.sort_unstable_by(|a, b| {
+            b.hits
+                .cmp(&a.hits)
+                .then_with(|| a.key.domain.cmp(&b.key.domain))
+        });
+        keys.truncate(limit);
+        keys
+    }
+
+    /// Hits recorded across both generations of the window.
+    pub fn total_hits(&self) -> u64 {
+        self.generations
+            .iter()
+            .map(|generation| generation.total.load(AtomicOrdering::Relaxed))
+            .sum()
+    }
+
+    pub fn clear(&self) {
+        for generation in &self.generations {
+            generation.clear();
+        }
+        self.candidates
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+        self.floor.store(0, AtomicOrdering::Relaxed);
+        self.generation_started
+            .store(coarse_now_secs(), AtomicOrdering::Relaxed);
+    }
+
+    fn offer(&self, key: &BorrowedKey<'_>, estimate: u64) {
+        let Ok(mut candidates) = self.candidates.try_lock() else {
+            return;
+        };
+
+        if let Some(existing) = candidates.iter_mut().find(|c| *key == c.key) {
+            existing.hits = estimate;
+        } else if candidates.len() < MAX_CANDIDATES {
+            candidates.push(HotKey {
+                key: CacheKey::partitioned(key.domain, key.record_type, key.partition),
+                hits: estimate,
+            });
+        } else if let Some(coldest) = candidates.iter_mut().min_by_key(|c| c.hits) {
+            if estimate > coldest.hits {
+                *coldest = HotKey {
+                    key: CacheKey::partitioned(key.domain, key.record_type, key.partition),
+                    hits: estimate,
+                };
+            }
+        }
+
+        self.floor
+            .store(Self::floor_of(&candidates), AtomicOrdering::Relaxed);
+    }
+
+    fn maybe_rotate(&self, now_secs: u64) {
+        let started = self.generation_started.load(AtomicOrdering::Relaxed);
+        let elapsed = now_secs.saturating_sub(started);
+        if elapsed < HOT_KEY_GENERATION_SECS
+            || self
+                .generation_started
+                .compare_exchange(
+                    started,
+                    now_secs,
+                    AtomicOrdering::AcqRel,
+                    AtomicOrdering::Relaxed,
+                )
+                .is_err()
+        {
+            return;
+        }
+
+        let old_active = self.active.load(AtomicOrdering::Relaxed);
+        let new_active = 1 - old_active;
+        if elapsed >= HOT_KEY_WINDOW_SECS {
+            self.generations[old_active].clear();
+        }
+        self.generations[new_active].clear();
+        self.active.store(new_active, AtomicOrdering::Release);
+
+        let mut candidates = self.candidates.lock().unwrap_or_else(|e| e.into_inner());
+        for candidate in candidates.iter_mut() {
+            candidate.hits = self.estimate(&candidate.key);
+        }
+        candidates.retain(|candidate| candidate.hits > 0);
+        self.floor
+            .store(Self::floor_of(&candidates), AtomicOrdering::Relaxed);
+    }
+
+    fn estimate<K: Hash>(&self, key: &K) -> u64 {
+        let (h1, h2) = Self::double_hash(key);
+        (0..SKETCH_DEPTH)
+            .map(|row| {
+                let idx = Self::index(h1, h2, row);
+                self.generations
+                    .iter()
+                    .map(|g| g.counters[idx].load(AtomicOrdering::Relaxed) as u64)
+                    .sum::<u64>()
+            })
+            .min()
+            .unwrap_or(0)
+    }
+
+    fn floor_of(candidates: &[HotKey]) -> u64 {
+        if candidates.len() < MAX_CANDIDATES {
+            0
+        } else {
+            candidates.iter().map(|c| c.hits).min().unwrap_or(0)
+        }
+    }
+
+    #[inline]
+    fn double_hash<K: Hash>(key: &K) -> (u64, u64) {
+        let mut hasher = FxHasher::default();
+        key.hash(&mut hasher);
+        let h1 = hasher.finish();
+        let h2 = h1.wrapping_mul(0x9e3779b97f4a7c15).rotate_right(23) | 1;
+        (h1, h2)
+    }
+
+    #[inline]
+    fn index(h1: u64, h2: u64, row: usize) -> usize {
+        let column = (h1.wrapping_add((row as u64).wrapping_mul(h2)) as usize) & (SKETCH_WIDTH - 1);
+        row * SKETCH_WIDTH + column
+    }
+}
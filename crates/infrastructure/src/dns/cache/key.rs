@@ -7,6 +7,8 @@ use std::hash::{Hash, Hasher};
 pub struct CacheKey {
     pub domain: CompactString,
     pub record_type: RecordType,
+    /// Policy partition; `0` is shared by every client.
+    pub partition: u32,
 }
 
 impl CacheKey {
@@ -19,12 +21,38 @@ impl CacheKey {
     /// materialized into the `CompactString`.
     #[inline]
     pub fn new(domain: &str, record_type: RecordType) -> Self {
+        Self::partitioned(domain, record_type, 0)
+    }
+
+    /// Like [`CacheKey::new`], for an entry that only clients of
+    /// `partition` may be served.
+    #[inline]
+    pub fn partitioned(domain: &str, record_type: RecordType, partition: u32) -> Self {
         let domain = normalize_domain_to_compact(domain);
         Self {
             domain,
             record_type,
+            partition,
         }
     }
+
+    /// Whether the key's domain is `suffix` or a name below it. `suffix`
+    /// must be lowercased and carry no trailing dot.
+    #[inline]
+    pub fn is_under(&self, suffix: &str) -> bool {
+        is_under_suffix(&self.domain, suffix)
+    }
+}
+
+/// Whether `domain` is `suffix` or a name below it, comparing lowercased
+/// names without a trailing dot.
+#[inline]
+pub(crate) fn is_under_suffix(domain: &str, suffix: &str) -> bool {
+    match domain.strip_suffix(suffix) {
+        Some("") => true,
+        Some(rest) => rest.ends_with('.'),
+        None => false,
+    }
 }
 
 /// Builds a lowercased `CompactString` from `domain` with zero heap allocation
@@ -63,13 +91,16 @@ impl Hash for CacheKey {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.domain.as_str().hash(state);
         std::mem::discriminant(&self.record_type).hash(state);
+        self.partition.hash(state);
     }
 }
 
 impl PartialEq for CacheKey {
     #[inline]
     fn eq(&self, other: &CacheKey) -> bool {
-        self.record_type == other.record_type && self.domain == other.domain
+        self.record_type == other.record_type
+            && self.partition == other.partition
+            && self.domain == other.domain
     }
 }
 
@@ -77,6 +108,7 @@ impl PartialEq for CacheKey {
 pub struct BorrowedKey<'a> {
     pub domain: &'a str,
     pub record_type: RecordType,
+    pub partition: u32,
 }
 
 impl<'a> BorrowedKey<'a> {
@@ -86,6 +118,11 @@ impl<'a> BorrowedKey<'a> {
     /// domain lowercased.
     #[inline]
     pub fn new(domain: &'a str, record_type: RecordType) -> Self {
+        Self::partitioned(domain, record_type, 0)
+    }
+
+    #[inline]
+    pub fn partitioned(domain: &'a str, record_type: RecordType, partition: u32) -> Self {
         debug_assert!(
             domain.bytes().all(|b| !b.is_ascii_uppercase()),
             "BorrowedKey domain must be ASCII-lowercased by the caller; got `{}`",
@@ -94,6 +131,7 @@ impl<'a> BorrowedKey<'a> {
         Self {
             domain,
             record_type,
+            partition,
         }
     }
 }
@@ -103,13 +141,16 @@ impl<'a> Hash for BorrowedKey<'a> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.domain.hash(state);
         std::mem::discriminant(&self.record_type).hash(state);
+        self.partition.hash(state);
     }
 }
 
 impl<'a> PartialEq for BorrowedKey<'a> {
     #[inline]
     fn eq(&self, other: &BorrowedKey<'a>) -> bool {
-        self.record_type == other.record_type && self.domain == other.domain
+        self.record_type == other.record_type
+            && self.partition == other.partition
+            && self.domain == other.domain
     }
 }
 
@@ -118,20 +159,26 @@ impl<'a> Eq for BorrowedKey<'a> {}
 impl<'a> PartialEq<CacheKey> for BorrowedKey<'a> {
     #[inline]
     fn eq(&self, other: &CacheKey) -> bool {
-        self.record_type == other.record_type && self.domain == other.domain.as_str()
+        self.record_type == other.record_type
+            && self.partition == other.partition
+            && self.domain == other.domain.as_str()
     }
 }
 
 impl<'a> PartialEq<BorrowedKey<'a>> for CacheKey {
     #[inline]
     fn eq(&self, other: &BorrowedKey<'a>) -> bool {
-        self.record_type == other.record_type && self.domain.as_str() == other.domain
+        self.record_type == other.record_type
+            && self.partition == other.partition
+            && self.domain.as_str() == other.domain
     }
 }
 
 impl<'a> Equivalent<CacheKey> for BorrowedKey<'a> {
     #[inline]
     fn equivalent(&self, key: &CacheKey) -> bool {
-        self.record_type == key.record_type && self.domain == key.domain.as_str()
+        self.record_type == key.record_type
+            && self.partition == key.partition
+            && self.domain == key.domain.as_str()
     }
 }
@@ -6,11 +6,14 @@ use rustc_hash::FxBuildHasher;
 use std::cell::RefCell;
 use std::net::IpAddr;
 use std::num::NonZeroUsize;
-use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering as AtomicOrdering};
 use std::sync::Arc;
 
 type L1Hit = (Arc<Vec<IpAddr>>, u32);
 
+/// Per-thread entry count used when no cache has configured one.
+pub const DEFAULT_L1_CAPACITY: usize = 1024;
+
 struct L1Entry {
     addresses: Arc<Vec<IpAddr>>,
     expires_secs: u64,
@@ -22,18 +25,31 @@ struct L1State {
 }
 
 static L1_GLOBAL_GENERATION: AtomicU64 = AtomicU64::new(0);
+static L1_CAPACITY: AtomicUsize = AtomicUsize::new(DEFAULT_L1_CAPACITY);
 
 thread_local! {
     static L1_CACHE: RefCell<L1State> =
         RefCell::new(L1State {
-            cache: LruCache::with_hasher(
-                NonZeroUsize::new(1024).unwrap(),
-                FxBuildHasher
-            ),
+            cache: LruCache::with_hasher(l1_capacity(), FxBuildHasher),
             generation: 0,
         });
 }
 
+fn l1_capacity() -> NonZeroUsize {
+    NonZeroUsize::new(L1_CAPACITY.load(AtomicOrdering::Relaxed)).unwrap_or(NonZeroUsize::MIN)
+}
+
+/// Sets the per-thread L1 capacity. Threads drop their entries and resize on
+/// their next access; a capacity of zero is ignored.
+pub fn l1_set_capacity(capacity: usize) {
+    if capacity == 0 {
+        return;
+    }
+    if L1_CAPACITY.swap(capacity, AtomicOrdering::Relaxed) != capacity {
+        L1_GLOBAL_GENERATION.fetch_add(1, AtomicOrdering::Release);
+    }
+}
+
 /// Looks up a domain in the thread-local L1 cache, returning addresses and remaining TTL.
 ///
 /// The composite key `"Type:domain"` is built byte-by-byte with ASCII-lowercasing
@@ -74,6 +90,10 @@ fn lookup_l1(key_str: &str) -> Option<L1Hit> {
         let global_gen = L1_GLOBAL_GENERATION.load(AtomicOrdering::Acquire);
         if state.generation != global_gen {
             state.cache.clear();
+            let capacity = l1_capacity();
+            if state.cache.cap() != capacity {
+                state.cache.resize(capacity);
+            }
             state.generation = global_gen;
             return None;
         }
@@ -136,6 +156,14 @@ pub fn l1_insert(
     });
 }
 
+/// Bumps the global generation so every thread drops its L1 entries on its
+/// next access. Used when an L2 entry is removed, since the L1 copy on other
+/// threads would otherwise keep answering until it expires.
+#[inline]
+pub fn l1_invalidate() {
+    L1_GLOBAL_GENERATION.fetch_add(1, AtomicOrdering::Release);
+}
+
 /// Clears this thread's L1 cache and bumps the global generation
 /// so all other threads invalidate on next access.
 #[inline]
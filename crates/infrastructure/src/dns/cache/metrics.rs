@@ -5,7 +5,9 @@ use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
 pub struct CacheMetrics {
     pub hits: AtomicU64,
     pub misses: AtomicU64,
-    _hot_pad: [u64; 6],
+    /// Hits answered by the per-thread L1 cache; also counted in `hits`.
+    pub l1_hits: AtomicU64,
+    _hot_pad: [u64; 5],
 
     pub insertions: AtomicU64,
     pub evictions: AtomicU64,
@@ -3,6 +3,7 @@ pub mod coarse_clock;
 pub mod compaction;
 pub mod data;
 pub mod eviction;
+pub mod hot_keys;
 pub mod key;
 pub mod l1;
 pub mod metrics;
@@ -16,6 +17,7 @@ pub mod storage;
 pub use bloom::AtomicBloom;
 pub use data::{CachedAddresses, CachedData, DnssecStatus};
 pub use eviction::EvictionStrategy;
+pub use hot_keys::{HotKey, HotKeyTracker, HOT_KEY_WINDOW_SECS};
 pub use key::{BorrowedKey, CacheKey};
 pub use metrics::CacheMetrics;
 pub use negative_ttl::{NegativeQueryTracker, TrackerStats};
@@ -1,5 +1,5 @@
 use super::coarse_clock::coarse_now_secs;
-use super::key::CacheKey;
+use super::key::{is_under_suffix, CacheKey};
 use dashmap::DashMap;
 use ferrous_dns_domain::RecordType;
 use rustc_hash::FxBuildHasher;
@@ -55,12 +55,21 @@ impl NegativeDnsCache {
     }
 
     pub fn get(&self, domain: &str, record_type: &RecordType) -> Option<u32> {
+        self.get_partitioned(domain, record_type, 0)
+    }
+
+    pub fn get_partitioned(
+        &self,
+        domain: &str,
+        record_type: &RecordType,
+        partition: u32,
+    ) -> Option<u32> {
         debug_assert!(
             domain.bytes().all(|b| !b.is_ascii_uppercase()),
             "NegativeDnsCache::get expects caller to pass ASCII-lowercased domain; got `{}`",
             domain
         );
-        let key = CacheKey::new(domain, *record_type);
+        let key = CacheKey::partitioned(domain, *record_type, partition);
         let now = coarse_now_secs();
 
         match self.cache.get(&key) {
@@ -77,7 +86,27 @@ impl NegativeDnsCache {
         }
     }
 
+    /// Remaining TTL of a live entry, leaving expired entries in place.
+    pub fn peek(&self, domain: &str, record_type: &RecordType) -> Option<u32> {
+        let now = coarse_now_secs();
+        self.cache
+            .get(&CacheKey::new(domain, *record_type))
+            .map(|entry| entry.value().expires_at_secs)
+            .filter(|&expires| now < expires)
+            .map(|expires| expires.saturating_sub(now) as u32)
+    }
+
     pub fn insert(&self, domain: &str, record_type: RecordType, ttl: u32) {
+        self.insert_partitioned(domain, record_type, 0, ttl);
+    }
+
+    pub fn insert_partitioned(
+        &self,
+        domain: &str,
+        record_type: RecordType,
+        partition: u32,
+        ttl: u32,
+    ) {
         debug_assert!(
             domain.bytes().all(|b| !b.is_ascii_uppercase()),
             "NegativeDnsCache::insert expects caller to pass ASCII-lowercased domain; got `{}`",
@@ -104,7 +133,7 @@ impl NegativeDnsCache {
             }
         }
         let expires_at_secs = coarse_now_secs() + ttl as u64;
-        let key = CacheKey::new(domain, record_type);
+        let key = CacheKey::partitioned(domain, record_type, partition);
         self.cache.insert(key, NegativeEntry { expires_at_secs });
     }
 
@@ -118,6 +147,18 @@ impl NegativeDnsCache {
         self.cache.remove(&key);
     }
 
+    /// Removes the entries of `suffix` and every name below it, in all
+    /// partitions. Returns how many were removed.
+    pub fn remove_suffix(&self, suffix: &str) -> usize {
+        let mut removed = 0;
+        self.cache.retain(|key, _| {
+            let matches = is_under_suffix(&key.domain, suffix);
+            removed += usize::from(matches);
+            !matches
+        });
+        removed
+    }
+
     pub fn clear(&self) {
         self.cache.clear();
     }
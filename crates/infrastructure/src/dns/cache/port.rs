@@ -17,6 +17,37 @@ pub trait DnsCacheAccess: Send + Sync {
         dnssec_status: Option<DnssecStatus>,
     );
 
+    /// Lookup restricted to one cache partition. Caches that do not
+    /// partition serve only the shared partition `0`.
+    fn get_partitioned(
+        &self,
+        domain: &str,
+        record_type: &RecordType,
+        partition: u32,
+    ) -> Option<(CachedData, Option<DnssecStatus>, Option<u32>)> {
+        if partition == 0 {
+            self.get(domain, record_type)
+        } else {
+            None
+        }
+    }
+
+    /// Insert into one cache partition. Caches that do not partition only
+    /// store entries of the shared partition `0`.
+    fn insert_partitioned(
+        &self,
+        domain: &str,
+        record_type: RecordType,
+        partition: u32,
+        data: CachedData,
+        ttl: u32,
+        dnssec_status: Option<DnssecStatus>,
+    ) {
+        if partition == 0 {
+            self.insert(domain, record_type, data, ttl, dnssec_status);
+        }
+    }
+
     /// Phase 6: records a transient upstream error that was explicitly NOT
     /// cached as a negative response (timeout, connection refused/reset,
     /// no healthy servers, etc.). Default is a no-op so test doubles don't
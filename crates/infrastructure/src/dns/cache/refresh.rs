@@ -1,11 +1,11 @@
 use super::coarse_clock::coarse_now_secs;
+use super::key::CacheKey;
 use super::storage::DnsCache;
-use compact_str::CompactString;
 use ferrous_dns_domain::RecordType;
 use std::sync::atomic::Ordering as AtomicOrdering;
 
 impl DnsCache {
-    pub fn get_refresh_candidates(&self) -> Vec<(CompactString, RecordType)> {
+    pub fn get_refresh_candidates(&self) -> Vec<CacheKey> {
         let mut candidates = Vec::with_capacity(16);
         let now = coarse_now_secs();
         let sample_period = self.refresh_sample_period;
@@ -37,7 +37,7 @@ impl DnsCache {
 
             if record.is_expired_at_secs(now) {
                 if record.is_stale_usable_at_secs(now) && record.try_set_refreshing() {
-                    candidates.push((key.domain.clone(), key.record_type));
+                    candidates.push(key.clone());
                 }
                 continue;
             }
@@ -47,7 +47,7 @@ impl DnsCache {
             }
 
             if record.try_set_refreshing() {
-                candidates.push((key.domain.clone(), key.record_type));
+                candidates.push(key.clone());
             }
         }
 
@@ -55,10 +55,11 @@ impl DnsCache {
     }
 
     pub fn reset_refreshing(&self, domain: &str, record_type: &RecordType) {
-        use super::key::CacheKey;
+        self.reset_refreshing_key(&CacheKey::new(domain, *record_type));
+    }
 
-        let key = CacheKey::new(domain, *record_type);
-        if let Some(entry) = self.cache.get(&key) {
+    pub fn reset_refreshing_key(&self, key: &CacheKey) {
+        if let Some(entry) = self.cache.get(key) {
             entry.clear_refreshing();
         }
     }
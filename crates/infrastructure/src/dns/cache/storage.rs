@@ -1,16 +1,23 @@
 use super::bloom::AtomicBloom;
 use super::coarse_clock::coarse_now_secs;
 use super::eviction::{ActiveEvictionPolicy, EvictionStrategy};
+use super::hot_keys::{HotKey, HotKeyTracker};
 use super::key::{BorrowedKey, CacheKey};
-use super::l1::{l1_clear, l1_get, l1_insert};
+use super::l1::{l1_clear, l1_get, l1_insert, l1_invalidate, l1_set_capacity};
 use super::negative_cache::NegativeDnsCache;
 use super::port::DnsCacheAccess;
 use super::{CacheMetrics, CachedData, CachedRecord, DnssecStatus};
+use crate::dns::dnssec::DnssecCache;
+use crate::dns::refresh_budget::RefreshBudget;
 use dashmap::{DashMap, DashSet};
+use ferrous_dns_application::ports::{
+    CacheExportData, CacheExportEntry, CacheTtlOverrideEnginePort,
+};
 use ferrous_dns_domain::RecordType;
 use rustc_hash::FxBuildHasher;
 use std::borrow::Cow;
 use std::collections::BinaryHeap;
+use std::net::IpAddr;
 use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering as AtomicOrdering};
 use std::sync::{Arc, OnceLock};
 use tokio::sync::mpsc;
@@ -29,6 +36,25 @@ fn normalize_domain(domain: &str) -> Cow<'_, str> {
     }
 }
 
+/// Approximate bytes one L2 entry holds: the key and record structs plus
+/// their heap-allocated domain and payload.
+#[inline]
+pub(super) fn entry_bytes(key: &CacheKey, data: &CachedData) -> u64 {
+    (ENTRY_OVERHEAD_BYTES + key.domain.len() + data.heap_bytes()) as u64
+}
+
+/// Lower scores are evicted first. Scaling by the entry's size relative to
+/// the average pushes large entries down; non-positive scores are shifted
+/// below zero first so cold entries are still ordered by size.
+#[inline]
+fn size_weighted_score(score: f64, relative_size: f64) -> f64 {
+    if score > 0.0 {
+        score / relative_size
+    } else {
+        (score - 1.0) * relative_size
+    }
+}
+
 struct EvictionCandidate {
     score: f64,
     key: CacheKey,
@@ -55,6 +81,8 @@ impl Ord for EvictionCandidate {
 }
 
 const BLOOM_TARGET_FP_RATE: f64 = 0.01;
+const ENTRY_OVERHEAD_BYTES: usize =
+    std::mem::size_of::<CacheKey>() + std::mem::size_of::<CachedRecord>();
 const PERMANENT_TTL_SECS: u32 = 365 * 24 * 60 * 60;
 const STALE_SERVE_TTL: u32 = 2;
 
@@ -74,6 +102,8 @@ pub struct DnsCacheConfig {
     pub refresh_sample_rate: f64,
     pub min_ttl: u32,
     pub max_ttl: u32,
+    /// Entries per thread in the L1 cache; `0` disables L1.
+    pub l1_capacity: usize,
 }
 
 pub struct DnsCache {
@@ -92,11 +122,23 @@ pub struct DnsCache {
     pub(super) eviction_sample_size: usize,
     pub(super) refresh_sample_period: u64,
     pub(super) negative: NegativeDnsCache,
+    /// DNSSEC chain cache of the validation layer, kept here so suffix
+    /// flushes reach it too.
+    dnssec: Arc<DnssecCache>,
     pub(crate) eviction_pending: AtomicBool,
+    pub(super) memory_bytes: AtomicU64,
+    max_memory_bytes: u64,
     permanent_keys: Arc<DashSet<CacheKey, FxBuildHasher>>,
+    hot_keys: HotKeyTracker,
+    l1_enabled: bool,
     min_ttl: u32,
     max_ttl: u32,
-    stale_refresh_tx: OnceLock<mpsc::Sender<(Arc<str>, RecordType)>>,
+    /// Partitions other than the shared one that have held an entry, so
+    /// removals probe each of them instead of scanning the cache.
+    partitions: DashSet<u32, FxBuildHasher>,
+    stale_refresh_tx: OnceLock<mpsc::Sender<CacheKey>>,
+    refresh_budget: OnceLock<Arc<RefreshBudget>>,
+    ttl_overrides: OnceLock<Arc<dyn CacheTtlOverrideEnginePort>>,
 }
 
 impl DnsCache {
@@ -123,6 +165,7 @@ impl DnsCache {
             config.shard_amount,
         );
         let bloom = AtomicBloom::new(config.max_entries * 2, BLOOM_TARGET_FP_RATE);
+        l1_set_capacity(config.l1_capacity);
 
         Self {
             cache: Arc::new(cache),
@@ -143,17 +186,37 @@ impl DnsCache {
                 (1.0 / r).ceil() as u64
             },
             negative: NegativeDnsCache::new(config.max_entries),
+            dnssec: Arc::new(DnssecCache::new()),
             eviction_pending: AtomicBool::new(false),
+            memory_bytes: AtomicU64::new(0),
+            max_memory_bytes: 0,
             permanent_keys: Arc::new(DashSet::with_hasher(FxBuildHasher)),
+            hot_keys: HotKeyTracker::new(),
+            l1_enabled: config.l1_capacity > 0,
             min_ttl: config.min_ttl,
             max_ttl: config.max_ttl,
+            partitions: DashSet::with_hasher(FxBuildHasher),
             stale_refresh_tx: OnceLock::new(),
+            refresh_budget: OnceLock::new(),
+            ttl_overrides: OnceLock::new(),
         }
     }
 
+    /// Caps the approximate memory held by cached entries; `0` leaves it
+    /// unlimited. Over the cap, eviction prefers large entries.
+    pub fn with_max_memory_mb(mut self, max_memory_mb: usize) -> Self {
+        self.max_memory_bytes = (max_memory_mb as u64) * 1024 * 1024;
+        self
+    }
+
+    /// Clamps `ttl` to the bounds of the override matching the lowercased
+    /// `domain`, or to the global bounds when none does.
     #[inline(always)]
-    fn clamp_ttl(&self, ttl: u32) -> u32 {
-        ttl.clamp(self.min_ttl, self.max_ttl)
+    fn clamp_ttl(&self, domain: &str, ttl: u32) -> u32 {
+        match self.ttl_overrides.get().and_then(|o| o.bounds(domain)) {
+            Some(bounds) => bounds.clamp(ttl, self.min_ttl, self.max_ttl),
+            None => ttl.clamp(self.min_ttl, self.max_ttl),
+        }
     }
 
     pub(super) fn get_threshold(&self) -> f64 {
@@ -178,14 +241,27 @@ impl DnsCache {
         &self,
         domain: &str,
         record_type: &RecordType,
+    ) -> Option<(CachedData, Option<DnssecStatus>, Option<u32>)> {
+        self.get_partitioned(domain, record_type, 0)
+    }
+
+    /// Looks up an entry in `partition`. The per-thread L1 only holds the
+    /// shared partition, so partitioned lookups always go to L2.
+    pub fn get_partitioned(
+        &self,
+        domain: &str,
+        record_type: &RecordType,
+        partition: u32,
     ) -> Option<(CachedData, Option<DnssecStatus>, Option<u32>)> {
         let domain = normalize_domain(domain);
         let domain = domain.as_ref();
-        let borrowed = BorrowedKey::new(domain, *record_type);
+        let borrowed = BorrowedKey::partitioned(domain, *record_type, partition);
 
-        if let Some((arc_data, remaining_ttl)) = l1_get(domain, record_type) {
+        if let Some((arc_data, remaining_ttl)) = self.l1_get(domain, record_type, partition) {
             self.metrics.hits.fetch_add(1, AtomicOrdering::Relaxed);
+            self.metrics.l1_hits.fetch_add(1, AtomicOrdering::Relaxed);
             self.bloom.refresh(&borrowed);
+            self.hot_keys.record(&borrowed);
             return Some((
                 CachedData::IpAddresses(super::data::CachedAddresses {
                     addresses: arc_data,
@@ -198,7 +274,10 @@ impl DnsCache {
         let in_bloom = self.bloom.check(&borrowed);
 
         if !in_bloom {
-            if let Some(remaining_ttl) = self.negative.get(domain, record_type) {
+            if let Some(remaining_ttl) =
+                self.negative
+                    .get_partitioned(domain, record_type, partition)
+            {
                 self.metrics.hits.fetch_add(1, AtomicOrdering::Relaxed);
                 return Some((CachedData::NegativeResponse, None, Some(remaining_ttl)));
             }
@@ -206,7 +285,7 @@ impl DnsCache {
             return None;
         }
 
-        let key = CacheKey::new(domain, *record_type);
+        let key = CacheKey::partitioned(domain, *record_type, partition);
 
         if let Some(entry) = self.cache.get(&key) {
             let record = entry.value();
@@ -220,10 +299,9 @@ impl DnsCache {
                     .fetch_add(1, AtomicOrdering::Relaxed);
                 record.record_hit();
                 self.bloom.refresh(&borrowed);
+                self.hot_keys.record(&borrowed);
                 if let Some(tx) = self.stale_refresh_tx.get() {
-                    if record.try_set_refreshing()
-                        && tx.try_send((Arc::from(domain), key.record_type)).is_err()
-                    {
+                    if record.try_set_refreshing() && tx.try_send(key.clone()).is_err() {
                         record.clear_refreshing();
                     }
                 }
@@ -244,8 +322,11 @@ impl DnsCache {
                 self.metrics.hits.fetch_add(1, AtomicOrdering::Relaxed);
                 record.record_hit();
                 self.bloom.refresh(&borrowed);
+                self.hot_keys.record(&borrowed);
                 let remaining_ttl = record.expires_at_secs.saturating_sub(now_secs) as u32;
-                self.promote_to_l1(domain, record_type, record, now_secs);
+                if partition == 0 {
+                    self.promote_to_l1(domain, record_type, record, now_secs);
+                }
                 return Some((
                     record.data.clone(),
                     Some(record.dnssec_status),
@@ -254,7 +335,10 @@ impl DnsCache {
             }
         }
 
-        if let Some(remaining_ttl) = self.negative.get(domain, record_type) {
+        if let Some(remaining_ttl) = self
+            .negative
+            .get_partitioned(domain, record_type, partition)
+        {
             self.metrics.hits.fetch_add(1, AtomicOrdering::Relaxed);
             return Some((CachedData::NegativeResponse, None, Some(remaining_ttl)));
         }
@@ -270,9 +354,25 @@ impl DnsCache {
         data: CachedData,
         ttl: u32,
         dnssec_status: Option<DnssecStatus>,
+    ) {
+        self.insert_partitioned(domain, record_type, 0, data, ttl, dnssec_status);
+    }
+
+    /// Stores an entry that only lookups in `partition` will see.
+    pub fn insert_partitioned(
+        &self,
+        domain: &str,
+        record_type: RecordType,
+        partition: u32,
+        data: CachedData,
+        ttl: u32,
+        dnssec_status: Option<DnssecStatus>,
     ) {
         let domain = normalize_domain(domain);
         let domain = domain.as_ref();
+        if partition != 0 && !self.partitions.contains(&partition) {
+            self.partitions.insert(partition);
+        }
 
         if data.is_negative() {
             // Negative cache enforces its own `[MIN_NEGATIVE_TTL, MAX_NEGATIVE_TTL]`
@@ -282,23 +382,26 @@ impl DnsCache {
             // break the refresh/access-window cycle, while deflating negatives
             // would defeat the 300s floor that keeps NXDOMAINs from escaping
             // to upstream on every repeated miss.
-            self.negative.insert(domain, record_type, ttl);
+            self.negative
+                .insert_partitioned(domain, record_type, partition, ttl);
             return;
         }
 
-        let ttl = self.clamp_ttl(ttl);
-        let key = CacheKey::new(domain, record_type);
+        let ttl = self.clamp_ttl(domain, ttl);
+        let key = CacheKey::partitioned(domain, record_type, partition);
 
-        if self.cache.len() >= self.max_entries {
+        if self.cache.len() >= self.max_entries || self.over_memory_limit() {
             self.eviction_pending.store(true, AtomicOrdering::Relaxed);
         }
 
-        let maybe_l1_addresses = if let CachedData::IpAddresses(ref entry) = data {
-            Some(Arc::clone(&entry.addresses))
-        } else {
-            None
+        let maybe_l1_addresses = match data {
+            CachedData::IpAddresses(ref entry) if partition == 0 => {
+                Some(Arc::clone(&entry.addresses))
+            }
+            _ => None,
         };
 
+        let size = entry_bytes(&key, &data);
         let record = CachedRecord::new(data, ttl, record_type, dnssec_status);
         let expires_secs = record.expires_at_secs;
 
@@ -311,11 +414,13 @@ impl DnsCache {
                     .fetch_add(1, AtomicOrdering::Relaxed);
             }
             dashmap::Entry::Occupied(mut e) => {
-                e.insert(record);
+                let old = e.insert(record);
+                self.release_bytes(entry_bytes(e.key(), &old.data));
             }
         }
+        self.memory_bytes.fetch_add(size, AtomicOrdering::Relaxed);
 
-        if let Some(addresses) = maybe_l1_addresses {
+        if let Some(addresses) = maybe_l1_addresses.filter(|_| self.l1_enabled) {
             l1_insert(domain, &record_type, addresses, expires_secs);
         }
 
@@ -323,6 +428,7 @@ impl DnsCache {
             domain = %domain,
             record_type = %record_type,
             ttl,
+            partition,
             "Inserted record into cache"
         );
     }
@@ -350,37 +456,107 @@ impl DnsCache {
             None
         };
 
+        let size = entry_bytes(&key, &data);
         let record = CachedRecord::permanent(data, PERMANENT_TTL_SECS, record_type);
-        self.cache.insert(key, record);
+        if let Some(old) = self.cache.insert(key.clone(), record) {
+            self.release_bytes(entry_bytes(&key, &old.data));
+        }
+        self.memory_bytes.fetch_add(size, AtomicOrdering::Relaxed);
 
-        if let Some(addresses) = maybe_l1_addresses {
+        if let Some(addresses) = maybe_l1_addresses.filter(|_| self.l1_enabled) {
             l1_insert(domain, &record_type, addresses, u64::MAX);
         }
     }
 
+    /// Removes `domain`/`record_type` from every partition.
     pub fn remove(&self, domain: &str, record_type: &RecordType) -> bool {
         let domain = normalize_domain(domain);
         let domain = domain.as_ref();
-        let key = CacheKey::new(domain, *record_type);
+        let mut key = CacheKey::new(domain, *record_type);
 
-        if self.cache.remove(&key).is_some() {
+        let mut removed = 0u64;
+        if let Some((key, record)) = self.cache.remove(&key) {
+            self.release_bytes(entry_bytes(&key, &record.data));
             self.permanent_keys.remove(&key);
-            self.metrics.evictions.fetch_add(1, AtomicOrdering::Relaxed);
-            info!(domain = %domain, record_type = %record_type, "Removed record from cache");
-            true
-        } else {
-            false
+            removed += 1;
+        }
+        for partition in self.partitions.iter() {
+            key.partition = *partition;
+            if let Some((key, record)) = self.cache.remove(&key) {
+                self.release_bytes(entry_bytes(&key, &record.data));
+                removed += 1;
+            }
+        }
+
+        if removed == 0 {
+            return false;
+        }
+        if self.l1_enabled {
+            l1_invalidate();
         }
+        self.metrics
+            .evictions
+            .fetch_add(removed, AtomicOrdering::Relaxed);
+        info!(domain = %domain, record_type = %record_type, "Removed record from cache");
+        true
+    }
+
+    /// Removes every entry of `suffix` and the names below it, of any
+    /// record type and partition, from the positive, negative and DNSSEC
+    /// caches. Local records stay. Returns how many entries were removed.
+    pub fn flush_suffix(&self, suffix: &str) -> usize {
+        let suffix = normalize_domain(suffix.trim_end_matches('.'));
+        let suffix = suffix.as_ref();
+
+        let mut removed = 0u64;
+        self.cache.retain(|key, record| {
+            let matches = !record.is_permanent() && key.is_under(suffix);
+            if matches {
+                self.release_bytes(entry_bytes(key, &record.data));
+                removed += 1;
+            }
+            !matches
+        });
+        if removed > 0 {
+            if self.l1_enabled {
+                l1_invalidate();
+            }
+            self.metrics
+                .evictions
+                .fetch_add(removed, AtomicOrdering::Relaxed);
+        }
+
+        let negative = self.negative.remove_suffix(suffix);
+        let dnssec = self.dnssec.remove_suffix(suffix);
+        let total = removed as usize + negative + dnssec;
+        info!(
+            suffix = %suffix,
+            positive = removed,
+            negative,
+            dnssec,
+            "Flushed cache entries under suffix"
+        );
+        total
+    }
+
+    /// DNSSEC chain cache to hand to the validation layer.
+    pub fn dnssec_cache(&self) -> Arc<DnssecCache> {
+        Arc::clone(&self.dnssec)
     }
 
     pub fn clear(&self) {
         self.cache.clear();
+        self.memory_bytes.store(0, AtomicOrdering::Relaxed);
         self.bloom.clear();
         self.negative.clear();
+        self.dnssec.clear();
         self.permanent_keys.clear();
+        self.partitions.clear();
+        self.hot_keys.clear();
         l1_clear();
         self.metrics.hits.store(0, AtomicOrdering::Relaxed);
         self.metrics.misses.store(0, AtomicOrdering::Relaxed);
+        self.metrics.l1_hits.store(0, AtomicOrdering::Relaxed);
         self.metrics.evictions.store(0, AtomicOrdering::Relaxed);
         info!("Cache cleared (L1 generation bumped for cross-thread invalidation)");
     }
@@ -393,6 +569,28 @@ impl DnsCache {
         self.cache.len()
     }
 
+    /// Approximate bytes held by cached entries, keys included.
+    pub fn memory_bytes(&self) -> u64 {
+        self.memory_bytes.load(AtomicOrdering::Relaxed)
+    }
+
+    pub fn max_memory_bytes(&self) -> u64 {
+        self.max_memory_bytes
+    }
+
+    #[inline]
+    fn over_memory_limit(&self) -> bool {
+        self.max_memory_bytes > 0 && self.memory_bytes() >= self.max_memory_bytes
+    }
+
+    pub(super) fn release_bytes(&self, bytes: u64) {
+        let _ = self.memory_bytes.fetch_update(
+            AtomicOrdering::Relaxed,
+            AtomicOrdering::Relaxed,
+            |current| Some(current.saturating_sub(bytes)),
+        );
+    }
+
     pub fn get_ttl(&self, domain: &str, record_type: &RecordType) -> Option<u32> {
         let key = CacheKey::new(domain, *record_type);
         self.cache.get(&key).map(|entry| entry.ttl)
@@ -405,6 +603,121 @@ impl DnsCache {
             .map(|entry| entry.expires_at_secs.saturating_sub(coarse_now_secs()) as u32)
     }
 
+    /// Entries currently held by each shard of the cache map.
+    pub fn shard_occupancy(&self) -> Vec<usize> {
+        self.cache
+            .shards()
+            .iter()
+            .map(|shard| shard.read().len())
+            .collect()
+    }
+
+    /// Most-hit keys in the sliding window, highest estimate first.
+    pub fn hot_keys(&self, limit: usize) -> Vec<HotKey> {
+        self.hot_keys.top(limit)
+    }
+
+    /// Up to `limit` fresh, positive entries of the shared partition, most
+    /// hit first, for a standby to import. Local records are left out.
+    pub fn export_entries(&self, limit: usize) -> Vec<CacheExportEntry> {
+        let now_secs = coarse_now_secs();
+        let mut entries: Vec<(u64, CacheExportEntry)> = self
+            .cache
+            .iter()
+            .filter(|entry| {
+                let record = entry.value();
+                entry.key().partition == 0
+                    && !record.is_permanent()
+                    && !record.is_marked_for_deletion()
+                    && !record.is_expired_at_secs(now_secs)
+            })
+            .filter_map(|entry| {
+                let record = entry.value();
+                let data = match &record.data {
+                    CachedData::IpAddresses(cached) => {
+                        CacheExportData::Addresses(cached.addresses.to_vec())
+                    }
+                    CachedData::CanonicalName(name) => {
+                        CacheExportData::CanonicalName(name.to_string())
+                    }
+                    CachedData::WireData(bytes) => CacheExportData::WireData(bytes.to_vec()),
+                    CachedData::NegativeResponse => return None,
+                };
+                let hits = record.counters.hit_count.load(AtomicOrdering::Relaxed);
+                Some((
+                    hits,
+                    CacheExportEntry {
+                        domain: entry.key().domain.to_string(),
+                        record_type: record.record_type.to_string(),
+                        data,
+                        remaining_ttl: record
+                            .expires_at_secs
+                            .saturating_sub(now_secs)
+                            .min(u32::MAX as u64) as u32,
+                        dnssec_status: (record.dnssec_status != DnssecStatus::Unknown)
+                            .then(|| record.dnssec_status.as_str().to_string()),
+                    },
+                ))
+            })
+            .collect();
+
+        if entries.len() > limit {
+            entries.select_nth_unstable_by(limit, |a, b| b.0.cmp(&a.0));
+            entries.truncate(limit);
+        }
+        entries.sort_unstable_by(|a, b| b.0.cmp(&a.0));
+        entries.into_iter().map(|(_, entry)| entry).collect()
+    }
+
+    /// Stores entries exported by another instance with the TTL they had
+    /// left. Unknown record types, expired entries and keys held by local
+    /// records are skipped. Returns how many entries were stored.
+    pub fn import_entries(&self, entries: &[CacheExportEntry]) -> usize {
+        let mut imported = 0;
+        for entry in entries {
+            let Ok(record_type) = entry.record_type.parse::<RecordType>() else {
+                continue;
+            };
+            if entry.remaining_ttl == 0
+                || self
+                    .permanent_keys
+                    .contains(&CacheKey::new(&entry.domain, record_type))
+            {
+                continue;
+            }
+            let data = match &entry.data {
+                CacheExportData::Addresses(addresses) => {
+                    CachedData::IpAddresses(super::data::CachedAddresses {
+                        addresses: Arc::new(addresses.clone()),
+                    })
+                }
+                CacheExportData::CanonicalName(name) => {
+                    CachedData::CanonicalName(Arc::from(name.as_str()))
+                }
+                CacheExportData::WireData(bytes) => {
+                    CachedData::WireData(bytes::Bytes::copy_from_slice(bytes))
+                }
+            };
+            if data.is_empty() {
+                continue;
+            }
+            let dnssec_status = entry
+                .dnssec_status
+                .as_deref()
+                .and_then(|status| status.parse::<DnssecStatus>().ok());
+            self.insert(
+                &entry.domain,
+                record_type,
+                data,
+                entry.remaining_ttl,
+                dnssec_status,
+            );
+            imported += 1;
+        }
+        debug!(entries = imported, "Imported cache entries");
+        imported
+    }
+
     pub fn strategy(&self) -> EvictionStrategy {
         self.eviction_policy.strategy()
     }
@@ -424,12 +737,38 @@ impl DnsCache {
         self.access_window_secs
     }
 
-    pub fn set_stale_refresh_sender(&self, tx: mpsc::Sender<(Arc<str>, RecordType)>) {
+    pub fn set_stale_refresh_sender(&self, tx: mpsc::Sender<CacheKey>) {
         if self.stale_refresh_tx.set(tx).is_err() {
             tracing::warn!("Stale refresh sender already configured — second sender dropped");
         }
     }
 
+    /// Applies per-domain TTL overrides to every answer stored from now on.
+    pub fn set_ttl_overrides(&self, overrides: Arc<dyn CacheTtlOverrideEnginePort>) {
+        if self.ttl_overrides.set(overrides).is_err() {
+            tracing::warn!("Cache TTL overrides already configured — second engine dropped");
+        }
+    }
+
+    pub fn set_refresh_budget(&self, budget: Arc<RefreshBudget>) {
+        if self.refresh_budget.set(budget).is_err() {
+            tracing::warn!("Refresh budget already configured — second budget dropped");
+        }
+    }
+
+    /// Grants up to `wanted` background refreshes; all of them when no
+    /// refresh budget is configured.
+    pub fn acquire_refresh_budget(&self, wanted: usize) -> usize {
+        match self.refresh_budget.get() {
+            Some(budget) => {
+                let client_queries = self.metrics.hits.load(AtomicOrdering::Relaxed)
+                    + self.metrics.misses.load(AtomicOrdering::Relaxed);
+                budget.acquire(wanted, client_queries)
+            }
+            None => wanted,
+        }
+    }
+
     pub fn refresh_record(
         &self,
         domain: &str,
@@ -438,17 +777,26 @@ impl DnsCache {
         new_data: CachedData,
         dnssec_status: Option<DnssecStatus>,
     ) -> bool {
-        let domain = normalize_domain(domain);
-        let domain = domain.as_ref();
         let key = CacheKey::new(domain, *record_type);
+        self.refresh_key(&key, new_ttl, new_data, dnssec_status)
+    }
+
+    /// Replaces the data of the entry at `key`, keeping its hit counters.
+    pub fn refresh_key(
+        &self,
+        key: &CacheKey,
+        new_ttl: Option<u32>,
+        new_data: CachedData,
+        dnssec_status: Option<DnssecStatus>,
+    ) -> bool {
         let now = coarse_now_secs();
 
-        if let Some(mut entry) = self.cache.get_mut(&key) {
+        if let Some(mut entry) = self.cache.get_mut(key) {
             let record = entry.value_mut();
             if record.is_permanent() || record.is_marked_for_deletion() {
                 return false;
             }
-            let ttl = self.clamp_ttl(new_ttl.unwrap_or(record.ttl));
+            let ttl = self.clamp_ttl(&key.domain, new_ttl.unwrap_or(record.ttl));
             record.expires_at_secs = now + ttl as u64;
             record.inserted_at_secs = now;
             record.ttl = ttl;
@@ -457,15 +805,26 @@ impl DnsCache {
             }
             record.clear_refreshing();
 
-            let maybe_l1_addresses = if let CachedData::IpAddresses(ref entry) = new_data {
-                Some(Arc::clone(&entry.addresses))
-            } else {
-                None
+            let maybe_l1_addresses = match new_data {
+                CachedData::IpAddresses(ref entry) if key.partition == 0 => {
+                    Some(Arc::clone(&entry.addresses))
+                }
+                _ => None,
             };
+            let old_size = entry_bytes(key, &record.data);
+            let new_size = entry_bytes(key, &new_data);
             record.data = new_data;
-
-            if let Some(addresses) = maybe_l1_addresses {
-                l1_insert(domain, record_type, addresses, record.expires_at_secs);
+            self.release_bytes(old_size);
+            self.memory_bytes
+                .fetch_add(new_size, AtomicOrdering::Relaxed);
+
+            if let Some(addresses) = maybe_l1_addresses.filter(|_| self.l1_enabled) {
+                l1_insert(
+                    &key.domain,
+                    &key.record_type,
+                    addresses,
+                    record.expires_at_secs,
+                );
             }
             true
         } else {
@@ -473,6 +832,20 @@ impl DnsCache {
         }
     }
 
+    #[inline]
+    fn l1_get(
+        &self,
+        domain: &str,
+        record_type: &RecordType,
+        partition: u32,
+    ) -> Option<(Arc<Vec<IpAddr>>, u32)> {
+        if self.l1_enabled && partition == 0 {
+            l1_get(domain, record_type)
+        } else {
+            None
+        }
+    }
+
     /// L1 thread-local cache only holds IP addresses (A/AAAA).
     /// WireData, CanonicalName, and NegativeResponse stay in L2 only.
     fn promote_to_l1(
@@ -482,6 +855,9 @@ impl DnsCache {
         record: &CachedRecord,
         now_secs: u64,
     ) {
+        if !self.l1_enabled {
+            return;
+        }
         if let CachedData::IpAddresses(ref entry) = record.data {
             if record.expires_at_secs <= now_secs {
                 return;
@@ -499,17 +875,26 @@ impl DnsCache {
         if let Some(entry) = self.cache.iter().next() {
             let key = entry.key().clone();
             drop(entry);
-            self.cache.remove(&key);
+            if let Some((key, record)) = self.cache.remove(&key) {
+                self.release_bytes(entry_bytes(&key, &record.data));
+            }
             self.metrics.evictions.fetch_add(1, AtomicOrdering::Relaxed);
         }
     }
 
     pub fn evict_entries(&self) {
+        if self.over_memory_limit() {
+            self.evict_to_memory_limit();
+            if self.cache.len() < self.max_entries {
+                return;
+            }
+        }
+
         let num_to_evict = ((self.max_entries as f64) * self.batch_eviction_percentage) as usize;
         let num_to_evict = num_to_evict.max(1);
 
         if self.use_probabilistic_eviction && self.cache.len() > self.max_entries / 2 {
-            self.evict_by_strategy(num_to_evict);
+            self.evict_by_strategy(num_to_evict, None);
         } else {
             for _ in 0..num_to_evict {
                 self.evict_random_entry();
@@ -517,16 +902,43 @@ impl DnsCache {
         }
     }
 
-    fn evict_by_strategy(&self, count: usize) {
+    /// Evicts by size-weighted score until memory drops below the cap minus
+    /// one eviction batch, so the next few inserts do not trigger it again.
+    fn evict_to_memory_limit(&self) {
+        const MAX_ROUNDS: usize = 16;
+
+        let target =
+            ((self.max_memory_bytes as f64) * (1.0 - self.batch_eviction_percentage)) as u64;
+        for _ in 0..MAX_ROUNDS {
+            let memory = self.memory_bytes();
+            let len = self.cache.len();
+            if memory <= target || len == 0 {
+                break;
+            }
+            let average = (memory / len as u64).max(1);
+            let count = (memory - target).div_ceil(average) as usize;
+            self.evict_by_strategy(count.clamp(1, len), Some(memory - target));
+            if self.cache.len() == len {
+                break;
+            }
+        }
+    }
+
+    /// With `bytes_to_free`, scores are size-weighted and eviction stops
+    /// once that many bytes have been released.
+    fn evict_by_strategy(&self, count: usize, bytes_to_free: Option<u64>) {
         if self.cache.is_empty() {
             return;
         }
+        let size_weighted = bytes_to_free.is_some();
 
         let now_secs = coarse_now_secs();
         let total_to_sample = count * self.eviction_sample_size;
+        let average_bytes = (self.memory_bytes() as f64 / self.cache.len() as f64).max(1.0);
 
         struct RecordSnapshot {
             key: CacheKey,
+            bytes: u64,
             hit_count: u64,
             last_access: u64,
             inserted_at: u64,
@@ -547,6 +959,7 @@ impl DnsCache {
             }
             snapshots.push(RecordSnapshot {
                 key: entry.key().clone(),
+                bytes: entry_bytes(entry.key(), &record.data),
                 hit_count: record.counters.hit_count.load(AtomicOrdering::Relaxed),
                 last_access: record.counters.last_access.load(AtomicOrdering::Relaxed),
                 inserted_at: record.inserted_at_secs,
@@ -570,13 +983,16 @@ impl DnsCache {
                 }
             }
 
-            let score = self.eviction_policy.compute_score_from_snapshot(
+            let mut score = self.eviction_policy.compute_score_from_snapshot(
                 snap.hit_count,
                 snap.last_access,
                 snap.inserted_at,
                 snap.expires_at,
                 now_secs,
             );
+            if size_weighted {
+                score = size_weighted_score(score, snap.bytes as f64 / average_bytes);
+            }
             let candidate = EvictionCandidate {
                 score,
                 key: snap.key.clone(),
@@ -603,17 +1019,30 @@ impl DnsCache {
 
         let mut scored_evicted = 0usize;
         let mut last_worst_score = f64::MAX;
+        let mut freed = 0u64;
 
         for candidate in candidates.into_iter().take(evict_count) {
+            if bytes_to_free.is_some_and(|needed| freed >= needed) {
+                break;
+            }
             last_worst_score = candidate.score;
-            self.cache.remove(&candidate.key);
+            if let Some((key, record)) = self.cache.remove(&candidate.key) {
+                let bytes = entry_bytes(&key, &record.data);
+                freed += bytes;
+                self.release_bytes(bytes);
+            }
             scored_evicted += 1;
         }
 
         let retain_removed = if !urgent_keys.is_empty() {
             let before = self.cache.len();
-            self.cache
-                .retain(|_, record| !record.is_marked_for_deletion());
+            self.cache.retain(|key, record| {
+                let keep = !record.is_marked_for_deletion();
+                if !keep {
+                    self.release_bytes(entry_bytes(key, &record.data));
+                }
+                keep
+            });
             before.saturating_sub(self.cache.len())
         } else {
             0
@@ -627,7 +1056,7 @@ impl DnsCache {
             .batch_evictions
             .fetch_add(1, AtomicOrdering::Relaxed);
 
-        if self.adaptive_thresholds && last_worst_score < f64::MAX {
+        if self.adaptive_thresholds && !size_weighted && last_worst_score < f64::MAX {
             let current = self.get_threshold();
             self.set_threshold((current * 0.9) + (last_worst_score * 0.1));
             self.metrics
@@ -654,6 +1083,9 @@ impl ferrous_dns_application::ports::DnsCachePort for DnsCache {
             evictions: metrics.evictions.load(AtomicOrdering::Relaxed),
             optimistic_refreshes: metrics.optimistic_refreshes.load(AtomicOrdering::Relaxed),
             stale_hits: metrics.stale_hits.load(AtomicOrdering::Relaxed),
+            memory_bytes: self.memory_bytes(),
+            max_memory_bytes: self.max_memory_bytes,
+            l1_hits: metrics.l1_hits.load(AtomicOrdering::Relaxed),
             lazy_deletions: metrics.lazy_deletions.load(AtomicOrdering::Relaxed),
             compactions: metrics.compactions.load(AtomicOrdering::Relaxed),
             batch_evictions: metrics.batch_evictions.load(AtomicOrdering::Relaxed),
@@ -661,6 +1093,7 @@ impl ferrous_dns_application::ports::DnsCachePort for DnsCache {
             transient_upstream_errors: metrics
                 .transient_upstream_errors
                 .load(AtomicOrdering::Relaxed),
+            refresh_budget: self.refresh_budget.get().map(|budget| budget.snapshot()),
         }
     }
 
@@ -679,6 +1112,86 @@ impl ferrous_dns_application::ports::DnsCachePort for DnsCache {
     fn remove_record(&self, domain: &str, record_type: &ferrous_dns_domain::RecordType) -> bool {
         self.remove(domain, record_type)
     }
+
+    fn flush_suffix(&self, suffix: &str) -> usize {
+        DnsCache::flush_suffix(self, suffix)
+    }
+
+    fn shard_occupancy(&self) -> Vec<usize> {
+        DnsCache::shard_occupancy(self)
+    }
+
+    fn export_entries(&self, limit: usize) -> Vec<CacheExportEntry> {
+        DnsCache::export_entries(self, limit)
+    }
+
+    fn import_entries(&self, entries: &[CacheExportEntry]) -> usize {
+        DnsCache::import_entries(self, entries)
+    }
+
+    fn hot_keys(&self, limit: usize) -> ferrous_dns_application::ports::HotKeysSnapshot {
+        use ferrous_dns_application::ports::{HotCacheKey, HotKeysSnapshot};
+
+        HotKeysSnapshot {
+            window_secs: super::hot_keys::HOT_KEY_WINDOW_SECS,
+            total_hits: self.hot_keys.total_hits(),
+            keys: self
+                .hot_keys(limit)
+                .into_iter()
+                .map(|hot| HotCacheKey {
+                    domain: hot.key.domain.to_string(),
+                    record_type: hot.key.record_type,
+                    hits: hot.hits,
+                })
+                .collect(),
+        }
+    }
+
+    fn peek(
+        &self,
+        domain: &str,
+        record_type: RecordType,
+    ) -> Option<ferrous_dns_application::ports::CacheEntrySnapshot> {
+        use ferrous_dns_application::ports::{CacheEntrySnapshot, CacheEntryState};
+
+        let domain = normalize_domain(domain);
+        let now_secs = coarse_now_secs();
+
+        if let Some(entry) = self.cache.get(&CacheKey::new(&domain, record_type)) {
+            let record = entry.value();
+            let state = if matches!(record.data, CachedData::NegativeResponse) {
+                CacheEntryState::Negative
+            } else if record.is_permanent() {
+                CacheEntryState::Permanent
+            } else if !record.is_expired_at_secs(now_secs) {
+                CacheEntryState::Fresh
+            } else if record.is_stale_usable_at_secs(now_secs) {
+                CacheEntryState::Stale
+            } else {
+                return None;
+            };
+            return Some(CacheEntrySnapshot {
+                state,
+                ttl: Some(record.ttl),
+                remaining_ttl: record
+                    .expires_at_secs
+                    .saturating_sub(now_secs)
+                    .min(u32::MAX as u64) as u32,
+                dnssec_status: Some(record.dnssec_status.as_str()),
+                hit_count: record.counters.hit_count.load(AtomicOrdering::Relaxed),
+            });
+        }
+
+        self.negative
+            .peek(&domain, &record_type)
+            .map(|remaining_ttl| CacheEntrySnapshot {
+                state: CacheEntryState::Negative,
+                ttl: None,
+                remaining_ttl,
+                dnssec_status: None,
+                hit_count: 0,
+            })
+    }
 }
 
 impl DnsCacheAccess for DnsCache {
@@ -701,6 +1214,35 @@ impl DnsCacheAccess for DnsCache {
         DnsCache::insert(self, domain, record_type, data, ttl, dnssec_status);
     }
 
+    fn get_partitioned(
+        &self,
+        domain: &str,
+        record_type: &RecordType,
+        partition: u32,
+    ) -> Option<(CachedData, Option<DnssecStatus>, Option<u32>)> {
+        DnsCache::get_partitioned(self, domain, record_type, partition)
+    }
+
+    fn insert_partitioned(
+        &self,
+        domain: &str,
+        record_type: RecordType,
+        partition: u32,
+        data: CachedData,
+        ttl: u32,
+        dnssec_status: Option<DnssecStatus>,
+    ) {
+        DnsCache::insert_partitioned(
+            self,
+            domain,
+            record_type,
+            partition,
+            data,
+            ttl,
+            dnssec_status,
+        );
+    }
+
     #[inline]
     fn record_transient_upstream_error(&self) {
         self.metrics
@@ -1,11 +1,11 @@
-use super::cache::{coarse_clock, CachedAddresses, CachedData, DnsCache};
+use super::cache::{coarse_clock, CacheKey, CachedAddresses, CachedData, DnsCache};
 
 use async_trait::async_trait;
 use ferrous_dns_application::ports::{
     CacheCompactionOutcome, CacheMaintenancePort, CacheRefreshOutcome, DnsResolver,
     QueryLogRepository,
 };
-use ferrous_dns_domain::{DnsQuery, DomainError, QueryLog, QuerySource, RecordType};
+use ferrous_dns_domain::{DnsQuery, DomainError, QueryLog, QuerySource};
 use std::net::IpAddr;
 use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
 use std::sync::Arc;
@@ -67,10 +67,11 @@ impl DnsCacheMaintenance {
         cache: &Arc<DnsCache>,
         resolver: &Arc<dyn DnsResolver>,
         query_log: &Option<Arc<dyn QueryLogRepository>>,
-        domain: &str,
-        record_type: &ferrous_dns_domain::RecordType,
+        key: &CacheKey,
     ) -> Result<bool, DomainError> {
         let start = Instant::now();
+        let domain = key.domain.as_str();
+        let record_type = &key.record_type;
 
         debug!(
             domain = %domain,
@@ -78,7 +79,7 @@ impl DnsCacheMaintenance {
             "Refreshing cache entry (will revalidate DNSSEC if enabled)"
         );
 
-        let query = DnsQuery::new(domain, *record_type);
+        let query = DnsQuery::new(domain, *record_type).with_cache_partition(key.partition);
 
         match resolver.resolve(&query).await {
             Ok(resolution)
@@ -99,13 +100,7 @@ impl DnsCacheMaintenance {
                     return Ok(false);
                 };
 
-                let refreshed = cache.refresh_record(
-                    domain,
-                    record_type,
-                    resolution.min_ttl,
-                    new_data,
-                    dnssec_status,
-                );
+                let refreshed = cache.refresh_key(key, resolution.min_ttl, new_data, dnssec_status);
 
                 if !refreshed {
                     return Ok(false);
@@ -118,6 +113,7 @@ impl DnsCacheMaintenance {
                         record_type: *record_type,
                         client_ip: IpAddr::from([127, 0, 0, 1]),
                         client_hostname: None,
+                        client_mac: None,
                         blocked: false,
                         response_time_us: Some(response_time),
                         cache_hit: false,
@@ -125,11 +121,16 @@ impl DnsCacheMaintenance {
                         dnssec_status: resolution.dnssec_status,
                         upstream_server: resolution.upstream_server.clone(),
                         upstream_pool: resolution.upstream_pool.clone(),
+                        upstream_strategy: resolution.upstream_strategy,
+                        upstream_attempt: resolution.upstream_attempt,
+                        upstream_protocol: resolution.upstream_protocol,
                         response_status: Some("NOERROR"),
                         timestamp: None,
                         query_source: QuerySource::Internal,
                         group_id: None,
+                        group_name: None,
                         block_source: None,
+                        plugin: None,
                     };
 
                     if let Err(e) = log.log_query(&log_entry).await {
@@ -159,7 +160,7 @@ impl DnsCacheMaintenance {
         cache: Arc<DnsCache>,
         resolver: Arc<dyn DnsResolver>,
         query_log: Option<Arc<dyn QueryLogRepository>>,
-        mut rx: mpsc::Receiver<(Arc<str>, RecordType)>,
+        mut rx: mpsc::Receiver<CacheKey>,
     ) {
         const MAX_CONCURRENT_REFRESHES: usize = 16;
         let semaphore = Arc::new(tokio::sync::Semaphore::new(MAX_CONCURRENT_REFRESHES));
@@ -167,7 +168,16 @@ impl DnsCacheMaintenance {
         tokio::spawn(async move {
             loop {
                 match rx.recv().await {
-                    Some((domain, record_type)) => {
+                    Some(key) => {
+                        if cache.acquire_refresh_budget(1) == 0 {
+                            debug!(
+                                domain = %key.domain,
+                                record_type = %key.record_type,
+                                "Stale refresh deferred: refresh budget exhausted"
+                            );
+                            cache.reset_refreshing_key(&key);
+                            continue;
+                        }
                         let permit = match semaphore.clone().acquire_owned().await {
                             Ok(p) => p,
                             Err(_) => break,
@@ -176,32 +186,24 @@ impl DnsCacheMaintenance {
                         let resolver = Arc::clone(&resolver);
                         let query_log = query_log.clone();
                         tokio::spawn(async move {
-                            match Self::refresh_entry(
-                                &cache,
-                                &resolver,
-                                &query_log,
-                                &domain,
-                                &record_type,
-                            )
-                            .await
-                            {
+                            match Self::refresh_entry(&cache, &resolver, &query_log, &key).await {
                                 Ok(true) => {
                                     debug!(
-                                        domain = %domain,
-                                        record_type = %record_type,
+                                        domain = %key.domain,
+                                        record_type = %key.record_type,
                                         "Stale entry refreshed immediately"
                                     );
                                 }
                                 Ok(false) => {
-                                    cache.reset_refreshing(&domain, &record_type);
+                                    cache.reset_refreshing_key(&key);
                                 }
                                 Err(e) => {
                                     debug!(
-                                        domain = %domain,
+                                        domain = %key.domain,
                                         error = %e,
                                         "Stale refresh failed"
                                     );
-                                    cache.reset_refreshing(&domain, &record_type);
+                                    cache.reset_refreshing_key(&key);
                                 }
                             }
                             drop(permit);
@@ -256,20 +258,24 @@ impl CacheMaintenancePort for DnsCacheMaintenance {
             });
         }
 
+        let candidate_count = candidates.len();
+        let granted = self.cache.acquire_refresh_budget(candidate_count);
+        for key in &candidates[granted..] {
+            self.cache.reset_refreshing_key(key);
+        }
+        let deferred = candidate_count - granted;
+        if deferred > 0 {
+            debug!(
+                granted,
+                deferred, "Refresh budget exhausted, deferring candidates"
+            );
+        }
+
         let mut refreshed = 0;
         let mut failed = 0;
-        let candidate_count = candidates.len();
 
-        for (domain, record_type) in &candidates {
-            match Self::refresh_entry(
-                &self.cache,
-                &self.resolver,
-                &self.query_log,
-                domain,
-                record_type,
-            )
-            .await
-            {
+        for key in &candidates[..granted] {
+            match Self::refresh_entry(&self.cache, &self.resolver, &self.query_log, key).await {
                 Ok(true) => {
                     refreshed += 1;
                     self.cache
@@ -278,17 +284,17 @@ impl CacheMaintenancePort for DnsCacheMaintenance {
                         .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
                 }
                 Ok(false) => {
-                    self.cache.reset_refreshing(domain, record_type);
+                    self.cache.reset_refreshing_key(key);
                 }
                 Err(_) => {
-                    self.cache.reset_refreshing(domain, record_type);
+                    self.cache.reset_refreshing_key(key);
                     failed += 1;
                 }
             }
         }
 
         sleep(Duration::from_millis(
-            candidate_count as u64 * BACKPRESSURE_MS_PER_CANDIDATE,
+            granted as u64 * BACKPRESSURE_MS_PER_CANDIDATE,
         ))
         .await;
 
@@ -296,6 +302,7 @@ impl CacheMaintenancePort for DnsCacheMaintenance {
             candidates_found: candidate_count,
             refreshed,
             failed,
+            deferred,
             cache_size: self.cache.size(),
         })
     }
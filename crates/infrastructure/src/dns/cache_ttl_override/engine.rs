@@ -0,0 +1,133 @@
+use crate::dns::block_filter::SuffixTrie;
+use arc_swap::ArcSwap;
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{CacheTtlOverrideEnginePort, CacheTtlOverrideRepository};
+use ferrous_dns_domain::{CacheTtlOverride, DomainError, TtlBounds, MAX_WILDCARD_TTL_OVERRIDES};
+use rustc_hash::FxBuildHasher;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::{error, info, warn};
+
+/// Read-optimised form of the enabled overrides. Exact names win over
+/// wildcards, and among wildcards the longest suffix wins.
+///
+/// Wildcards live in the blocklist [`SuffixTrie`], one mask bit each,
+/// assigned deepest suffix first: the lowest bit of a lookup result is
+/// therefore the most specific match.
+pub struct CacheTtlOverrideMatcher {
+    exact: HashMap<Box<str>, TtlBounds, FxBuildHasher>,
+    wildcard: SuffixTrie,
+    wildcard_bounds: Vec<TtlBounds>,
+}
+
+impl CacheTtlOverrideMatcher {
+    pub fn empty() -> Self {
+        Self {
+            exact: HashMap::with_hasher(FxBuildHasher),
+            wildcard: SuffixTrie::new(),
+            wildcard_bounds: Vec::new(),
+        }
+    }
+
+    pub fn new(overrides: Vec<CacheTtlOverride>) -> Self {
+        let mut matcher = Self::empty();
+        let mut wildcards = Vec::new();
+        for ttl_override in overrides.into_iter().filter(|o| o.enabled) {
+            let domain = ttl_override.domain.to_ascii_lowercase();
+            match domain.strip_prefix("*.") {
+                Some(suffix) => wildcards.push((suffix.to_string(), ttl_override.bounds())),
+                None => {
+                    matcher
+                        .exact
+                        .insert(Box::from(domain), ttl_override.bounds());
+                }
+            }
+        }
+
+        wildcards.sort_by(|a, b| {
+            let labels = |s: &str| s.split('.').count();
+            labels(&b.0).cmp(&labels(&a.0)).then_with(|| a.0.cmp(&b.0))
+        });
+        if wildcards.len() > MAX_WILDCARD_TTL_OVERRIDES {
+            warn!(
+                count = wildcards.len(),
+                max = MAX_WILDCARD_TTL_OVERRIDES,
+                "Too many wildcard cache TTL overrides; ignoring the least specific"
+            );
+            wildcards.truncate(MAX_WILDCARD_TTL_OVERRIDES);
+        }
+        for (bit, (suffix, bounds)) in wildcards.into_iter().enumerate() {
+            matcher.wildcard.insert_wildcard(&suffix, 1u64 << bit);
+            matcher.wildcard_bounds.push(bounds);
+        }
+
+        matcher
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.exact.is_empty() && self.wildcard_bounds.is_empty()
+    }
+
+    /// Bounds for the lowercased `domain`, if an override matches it.
+    #[inline]
+    pub fn lookup(&self, domain: &str) -> Option<TtlBounds> {
+        if let Some(bounds) = self.exact.get(domain) {
+            return Some(*bounds);
+        }
+        if self.wildcard_bounds.is_empty() {
+            return None;
+        }
+        let mask = self.wildcard.lookup(domain);
+        (mask != 0).then(|| self.wildcard_bounds[mask.trailing_zeros() as usize])
+    }
+}
+
+/// Cache TTL override engine backed by an `ArcSwap<CacheTtlOverrideMatcher>`,
+/// swapped whenever the overrides change so cache inserts never take a lock.
+pub struct CacheTtlOverrideEnforcer {
+    matcher: ArcSwap<CacheTtlOverrideMatcher>,
+    repo: Arc<dyn CacheTtlOverrideRepository>,
+}
+
+impl CacheTtlOverrideEnforcer {
+    /// Initialises the engine by loading all overrides from the repository.
+    pub async fn new(repo: Arc<dyn CacheTtlOverrideRepository>) -> Result<Arc<Self>, DomainError> {
+        let engine = Arc::new(Self {
+            matcher: ArcSwap::from_pointee(CacheTtlOverrideMatcher::empty()),
+            repo,
+        });
+
+        engine.reload_inner().await?;
+        info!("CacheTtlOverrideEnforcer initialised");
+        Ok(engine)
+    }
+
+    async fn reload_inner(&self) -> Result<(), DomainError> {
+        let overrides = self.repo.get_all().await?;
+        self.matcher
+            .store(Arc::new(CacheTtlOverrideMatcher::new(overrides)));
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl CacheTtlOverrideEnginePort for CacheTtlOverrideEnforcer {
+    #[inline]
+    fn bounds(&self, domain: &str) -> Option<TtlBounds> {
+        let matcher = self.matcher.load();
+        if matcher.is_empty() {
+            return None;
+        }
+        matcher.lookup(domain)
+    }
+
+    async fn reload(&self) -> Result<(), DomainError> {
+        if let Err(e) = self.reload_inner().await {
+            error!(error = %e, "Failed to reload cache TTL overrides");
+            return Err(e);
+        }
+        info!("Cache TTL overrides reloaded");
+        Ok(())
+    }
+}
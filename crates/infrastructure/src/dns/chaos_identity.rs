@@ -0,0 +1,70 @@
+use ferrous_dns_domain::config::ChaosIdentityConfig;
+use hickory_proto::rr::rdata::TXT;
+use hickory_proto::rr::{DNSClass, Name, RData, Record, RecordType};
+
+/// Identity answers are never cached, so every query reaches the instance
+/// that is currently answering.
+const CHAOS_TTL: u32 = 0;
+
+/// Answers CHAOS-class TXT queries for `version.bind`, `hostname.bind` and
+/// `id.server` from `dns.chaos_identity`.
+pub struct ChaosIdentity {
+    version: Option<String>,
+    hostname: Option<String>,
+    server_id: Option<String>,
+}
+
+impl ChaosIdentity {
+    /// Returns `None` when identity queries are disabled, in which case the
+    /// server refuses every CHAOS query.
+    pub fn from_config(config: &ChaosIdentityConfig) -> Option<Self> {
+        if !config.enabled {
+            return None;
+        }
+
+        let hostname = config
+            .hostname
+            .clone()
+            .or_else(|| hostname::get().ok().and_then(|h| h.into_string().ok()));
+        let server_id = config.server_id.clone().or_else(|| hostname.clone());
+        let version = config
+            .version
+            .clone()
+            .unwrap_or_else(|| concat!("ferrous-dns ", env!("CARGO_PKG_VERSION")).to_string());
+
+        Some(Self {
+            version: non_empty(Some(version)),
+            hostname: non_empty(hostname),
+            server_id: non_empty(server_id),
+        })
+    }
+
+    /// The TXT record answering `name`, or `None` when the query must be
+    /// refused: an unknown name, a type other than TXT, or an identity the
+    /// operator chose to hide.
+    pub fn answer(&self, name: &Name, record_type: RecordType) -> Option<Record> {
+        if record_type != RecordType::TXT {
+            return None;
+        }
+
+        let ascii = name.to_ascii();
+        let value = match ascii.trim_end_matches('.').to_ascii_lowercase().as_str() {
+            "version.bind" | "version.server" => self.version.as_ref(),
+            "hostname.bind" => self.hostname.as_ref(),
+            "id.server" => self.server_id.as_ref(),
+            _ => None,
+        }?;
+
+        let mut record = Record::from_rdata(
+            name.clone(),
+            CHAOS_TTL,
+            RData::TXT(TXT::new(vec![value.clone()])),
+        );
+        record.set_dns_class(DNSClass::CH);
+        Some(record)
+    }
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.filter(|v| !v.is_empty())
+}
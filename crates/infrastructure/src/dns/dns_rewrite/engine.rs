@@ -0,0 +1,58 @@
+use arc_swap::ArcSwap;
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{DnsRewriteEnginePort, DnsRewriteRepository};
+use ferrous_dns_domain::{DnsRewriteMatcher, DomainError, RewriteTarget};
+use std::sync::Arc;
+use tracing::{error, info};
+
+/// DNS rewrite engine backed by an `ArcSwap<DnsRewriteMatcher>`.
+///
+/// The matcher is rebuilt and swapped atomically whenever rewrites change,
+/// so lookups on the DNS hot path never take a lock. With no enabled
+/// rewrites, `lookup` is a single `is_empty()` check.
+pub struct DnsRewriteEnforcer {
+    matcher: ArcSwap<DnsRewriteMatcher>,
+    repo: Arc<dyn DnsRewriteRepository>,
+}
+
+impl DnsRewriteEnforcer {
+    /// Initialises the engine by loading all rewrites from the repository.
+    pub async fn new(repo: Arc<dyn DnsRewriteRepository>) -> Result<Arc<Self>, DomainError> {
+        let engine = Arc::new(Self {
+            matcher: ArcSwap::from_pointee(DnsRewriteMatcher::empty()),
+            repo,
+        });
+
+        engine.reload_inner().await?;
+        info!("DnsRewriteEnforcer initialised");
+        Ok(engine)
+    }
+
+    async fn reload_inner(&self) -> Result<(), DomainError> {
+        let rewrites = self.repo.get_all().await?;
+        self.matcher
+            .store(Arc::new(DnsRewriteMatcher::new(rewrites)));
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl DnsRewriteEnginePort for DnsRewriteEnforcer {
+    #[inline]
+    fn lookup(&self, domain: &str) -> Option<RewriteTarget> {
+        let matcher = self.matcher.load();
+        if matcher.is_empty() {
+            return None;
+        }
+        matcher.lookup(domain)
+    }
+
+    async fn reload(&self) -> Result<(), DomainError> {
+        if let Err(e) = self.reload_inner().await {
+            error!(error = %e, "Failed to reload DNS rewrites");
+            return Err(e);
+        }
+        info!("DNS rewrites reloaded");
+        Ok(())
+    }
+}
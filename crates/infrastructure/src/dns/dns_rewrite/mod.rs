@@ -0,0 +1,3 @@
+mod engine;
+
+pub use engine::DnsRewriteEnforcer;
@@ -6,14 +6,17 @@ use std::time::Instant;
 #[derive(Debug, Clone)]
 pub struct ValidationEntry {
     pub(super) result: ValidationResult,
+    pub(super) validated_at: Instant,
     pub(super) expires_at: Instant,
 }
 
 impl ValidationEntry {
     pub fn new(result: ValidationResult, ttl_secs: u32) -> Self {
+        let validated_at = Instant::now();
         Self {
             result,
-            expires_at: Instant::now() + std::time::Duration::from_secs(ttl_secs as u64),
+            validated_at,
+            expires_at: validated_at + std::time::Duration::from_secs(ttl_secs as u64),
         }
     }
 
@@ -2,14 +2,27 @@ use super::super::types::{DnskeyRecord, DsRecord};
 use super::super::validation::ValidationResult;
 use super::entries::{DnskeyEntry, DsEntry, ValidationEntry};
 use super::stats::{CacheStats, CacheStatsSnapshot};
+use crate::dns::cache::key::is_under_suffix;
 use dashmap::DashMap;
-use ferrous_dns_domain::RecordType;
-use std::sync::Arc;
+use ferrous_dns_application::ports::{DnssecCachedValidation, DnssecOutcomes, DnssecStatusPort};
+use ferrous_dns_domain::{DnssecStatusChange, RecordType};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
 use tracing::{debug, trace};
 
+/// Status changes held until the history job drains them; later ones are
+/// dropped.
+const MAX_PENDING_CHANGES: usize = 1_000;
+
+/// How long a validation result is kept past its TTL so the next
+/// validation of the name can be compared against it.
+const EXPIRED_RESULT_GRACE: Duration = Duration::from_secs(3600);
+
 pub struct DnssecCache {
     validations: DashMap<(Arc<str>, RecordType), ValidationEntry>,
 
+    outcomes: Mutex<DnssecOutcomes>,
+
     dnskeys: DashMap<Arc<str>, DnskeyEntry>,
 
     ds_records: DashMap<Arc<str>, DsEntry>,
@@ -21,6 +34,7 @@ impl DnssecCache {
     pub fn new() -> Self {
         Self {
             validations: DashMap::new(),
+            outcomes: Mutex::new(DnssecOutcomes::default()),
             dnskeys: DashMap::new(),
             ds_records: DashMap::new(),
             stats: Arc::new(CacheStats::default()),
@@ -47,6 +61,43 @@ impl DnssecCache {
         );
     }
 
+    /// Caches the result of a validation done by the validation layer and
+    /// counts its outcome. A result that differs from the previous one for
+    /// the name and type, or a first result that is `Bogus`, is also kept as
+    /// a status change.
+    pub fn record_validation(
+        &self,
+        domain: &str,
+        record_type: RecordType,
+        result: ValidationResult,
+        ttl_seconds: u32,
+    ) {
+        let previous = self
+            .validations
+            .insert(
+                (Arc::from(domain), record_type),
+                ValidationEntry::new(result, ttl_seconds),
+            )
+            .map(|entry| entry.result);
+        let changed = match previous {
+            Some(previous) => previous != result,
+            None => result == ValidationResult::Bogus,
+        };
+
+        let mut outcomes = self.outcomes.lock().unwrap_or_else(PoisonError::into_inner);
+        outcomes.counts.record(result.as_str());
+        if changed && outcomes.changes.len() < MAX_PENDING_CHANGES {
+            outcomes.changes.push(DnssecStatusChange {
+                id: None,
+                domain: domain.to_string(),
+                record_type,
+                status: result.as_str(),
+                previous_status: previous.map(|previous| previous.as_str()),
+                observed_at: chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            });
+        }
+    }
+
     pub fn get_validation(
         &self,
         domain: &str,
@@ -173,6 +224,22 @@ impl DnssecCache {
         }
     }
 
+    /// Drops validation results, DNSKEYs and DS records of `suffix` and
+    /// every name below it. Returns how many entries were removed.
+    pub fn remove_suffix(&self, suffix: &str) -> usize {
+        let mut removed = 0;
+        let mut keep = |domain: &str| {
+            let matches = is_under_suffix(domain, suffix);
+            removed += usize::from(matches);
+            !matches
+        };
+        self.validations.retain(|(domain, _), _| keep(domain));
+        self.dnskeys.retain(|domain, _| keep(domain));
+        self.ds_records.retain(|domain, _| keep(domain));
+        debug!(suffix = %suffix, removed, "DNSSEC cache entries flushed");
+        removed
+    }
+
     pub fn clear(&self) {
         self.validations.clear();
         self.dnskeys.clear();
@@ -181,6 +248,31 @@ impl DnssecCache {
     }
 }
 
+impl DnssecStatusPort for DnssecCache {
+    fn cached(&self, domain: &str) -> Vec<DnssecCachedValidation> {
+        let now = Instant::now();
+        self.validations
+            .iter()
+            .filter(|entry| &*entry.key().0 == domain)
+            .map(|entry| DnssecCachedValidation {
+                record_type: entry.key().1,
+                status: entry.result.as_str(),
+                validated_secs_ago: now.duration_since(entry.validated_at).as_secs(),
+                expired: now >= entry.expires_at,
+            })
+            .collect()
+    }
+
+    fn take_outcomes(&self) -> DnssecOutcomes {
+        let outcomes =
+            std::mem::take(&mut *self.outcomes.lock().unwrap_or_else(PoisonError::into_inner));
+        let now = Instant::now();
+        self.validations
+            .retain(|_, entry| entry.expires_at + EXPIRED_RESULT_GRACE > now);
+        outcomes
+    }
+}
+
 impl Default for DnssecCache {
     fn default() -> Self {
         Self::new()
@@ -56,59 +56,51 @@ impl ValidatedResponse {
     }
 }
 
+/// How long a validation result is cached when the answer has no records
+/// to take a TTL from.
+const DEFAULT_VALIDATION_TTL: u32 = 300;
+
 pub struct DnssecValidator {
     pool_manager: Arc<PoolManager>,
 
     chain_verifier: ChainVerifier,
 
+    cache: Arc<DnssecCache>,
+
     timeout_ms: u64,
 }
 
 impl DnssecValidator {
     pub fn new(pool_manager: Arc<PoolManager>) -> Self {
-        let trust_store = TrustAnchorStore::new();
-        let dnssec_cache = Arc::new(DnssecCache::new());
-        let chain_verifier = ChainVerifier::new(pool_manager.clone(), trust_store, dnssec_cache);
-
-        Self {
+        Self::with_trust_store_and_cache(
             pool_manager,
-            chain_verifier,
-            timeout_ms: 5000,
-        }
+            TrustAnchorStore::new(),
+            Arc::new(DnssecCache::new()),
+        )
     }
 
     pub fn with_cache(pool_manager: Arc<PoolManager>, dnssec_cache: Arc<DnssecCache>) -> Self {
-        let trust_store = TrustAnchorStore::new();
-        let chain_verifier = ChainVerifier::new(pool_manager.clone(), trust_store, dnssec_cache);
-
-        Self {
-            pool_manager,
-            chain_verifier,
-            timeout_ms: 5000,
-        }
+        Self::with_trust_store_and_cache(pool_manager, TrustAnchorStore::new(), dnssec_cache)
     }
 
     pub fn with_trust_store(pool_manager: Arc<PoolManager>, trust_store: TrustAnchorStore) -> Self {
-        let dnssec_cache = Arc::new(DnssecCache::new());
-        let chain_verifier = ChainVerifier::new(pool_manager.clone(), trust_store, dnssec_cache);
-
-        Self {
-            pool_manager,
-            chain_verifier,
-            timeout_ms: 5000,
-        }
+        Self::with_trust_store_and_cache(pool_manager, trust_store, Arc::new(DnssecCache::new()))
     }
 
+    /// Validation results are recorded in `dnssec_cache`, which also backs
+    /// the chain of trust lookups.
     pub fn with_trust_store_and_cache(
         pool_manager: Arc<PoolManager>,
         trust_store: TrustAnchorStore,
         dnssec_cache: Arc<DnssecCache>,
     ) -> Self {
-        let chain_verifier = ChainVerifier::new(pool_manager.clone(), trust_store, dnssec_cache);
+        let chain_verifier =
+            ChainVerifier::new(pool_manager.clone(), trust_store, dnssec_cache.clone());
 
         Self {
             pool_manager,
             chain_verifier,
+            cache: dnssec_cache,
             timeout_ms: 5000,
         }
     }
@@ -156,6 +148,12 @@ impl DnssecValidator {
             let all_answers: Vec<Record> = upstream_result.response.message.answers().to_vec();
             validation_status = self.verify_rrset_signatures(domain, &all_answers);
         }
+        self.cache.record_validation(
+            domain,
+            record_type,
+            validation_status,
+            Self::validation_ttl(upstream_result.response.message.answers()),
+        );
 
         let elapsed = start.elapsed().as_millis() as u64;
 
@@ -209,6 +207,12 @@ impl DnssecValidator {
             let all_answers: Vec<Record> = message.answers().to_vec();
             validation_status = self.verify_rrset_signatures(domain, &all_answers);
         }
+        self.cache.record_validation(
+            domain,
+            record_type,
+            validation_status,
+            Self::validation_ttl(message.answers()),
+        );
 
         let elapsed = start.elapsed().as_millis() as u64;
 
@@ -274,6 +278,14 @@ impl DnssecValidator {
         }
     }
 
+    fn validation_ttl(answers: &[Record]) -> u32 {
+        answers
+            .iter()
+            .map(Record::ttl)
+            .min()
+            .unwrap_or(DEFAULT_VALIDATION_TTL)
+    }
+
     fn extract_signer_zone(answers: &[Record]) -> Option<String> {
         for record in answers {
             if let RData::DNSSEC(DNSSECRData::RRSIG(rrsig)) = record.data() {
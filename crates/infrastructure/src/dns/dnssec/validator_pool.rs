@@ -14,7 +14,17 @@ pub struct DnssecValidatorPool {
 
 impl DnssecValidatorPool {
     pub fn new(pool_manager: Arc<PoolManager>, timeout_ms: u64, size: usize) -> Self {
-        let cache = Arc::new(DnssecCache::new());
+        Self::with_cache(pool_manager, timeout_ms, size, Arc::new(DnssecCache::new()))
+    }
+
+    /// Builds the pool around a chain cache owned elsewhere, e.g. by the
+    /// DNS cache so flushes can reach it.
+    pub fn with_cache(
+        pool_manager: Arc<PoolManager>,
+        timeout_ms: u64,
+        size: usize,
+        cache: Arc<DnssecCache>,
+    ) -> Self {
         let validators = (0..size)
             .map(|_| {
                 Mutex::new(
@@ -0,0 +1,241 @@
+use crate::dns::forwarding::{tsig_key_name, RecordTypeMapper, TsigKey, TsigKeyring};
+use crate::dns::server::response_code;
+use ferrous_dns_application::use_cases::ApplyDnsUpdateUseCase;
+use ferrous_dns_domain::{
+    DnsRcode, DnsUpdate, DnsUpdateOutcome, DomainError, RecordType, UpdateChange,
+    UpdatePrerequisite, UpdateZoneConfig,
+};
+use hickory_proto::op::{Message, MessageType, OpCode, Query, ResponseCode};
+use hickory_proto::rr::{DNSClass, Name, RData, RecordType as HickoryRecordType};
+use std::net::IpAddr;
+use std::sync::Arc;
+use tracing::debug;
+
+/// Answers UPDATE messages (RFC 2136) for the zones in `dns.update_zones`.
+pub struct DynamicUpdateHandler {
+    use_case: Arc<ApplyDnsUpdateUseCase>,
+    keyring: Arc<TsigKeyring>,
+}
+
+/// The key that signed a request, kept to sign the response.
+struct Signer {
+    key: Arc<TsigKey>,
+    request_mac: Vec<u8>,
+}
+
+impl DynamicUpdateHandler {
+    /// Fails when one of `zones` names a key the keyring does not hold.
+    pub fn new(
+        use_case: Arc<ApplyDnsUpdateUseCase>,
+        keyring: Arc<TsigKeyring>,
+        zones: &[UpdateZoneConfig],
+    ) -> Result<Self, DomainError> {
+        for zone in zones {
+            if let Some(key) = zone.tsig_keys.iter().find(|key| keyring.get(key).is_none()) {
+                return Err(DomainError::ConfigError(format!(
+                    "Update zone '{}' references unknown TSIG key '{}'",
+                    zone.zone, key
+                )));
+            }
+        }
+        Ok(Self { use_case, keyring })
+    }
+
+    /// Applies the UPDATE message `raw` and returns the wire response, or
+    /// `None` when `raw` cannot be parsed at all.
+    pub async fn handle(&self, raw: &[u8], client_ip: IpAddr) -> Option<Vec<u8>> {
+        let (message, signer) = match tsig_key_name(raw) {
+            None => (Message::from_vec(raw).ok()?, None),
+            Some(name) => match self.verify(raw, &name, client_ip) {
+                Some((unsigned, signer)) => (Message::from_vec(&unsigned).ok()?, Some(signer)),
+                None => {
+                    let message = Message::from_vec(raw).ok()?;
+                    return build_response(&message, ResponseCode::NotAuth, None);
+                }
+            },
+        };
+
+        let key_name = signer.as_ref().map(|s| Arc::clone(s.key.name()));
+        let rcode = match decode(&message, client_ip, key_name) {
+            Ok(update) => match self.use_case.execute(&update).await {
+                DnsUpdateOutcome::Applied { .. } => ResponseCode::NoError,
+                DnsUpdateOutcome::Rejected { rcode, .. } => response_code(rcode),
+            },
+            Err(reason) => {
+                debug!(client = %client_ip, reason = %reason, "Malformed dynamic update");
+                response_code(DnsRcode::FormErr)
+            }
+        };
+        build_response(&message, rcode, signer.as_ref())
+    }
+
+    /// Checks the signature of `raw` against key `name`, returning the
+    /// message without its TSIG record.
+    fn verify(&self, raw: &[u8], name: &str, client_ip: IpAddr) -> Option<(Vec<u8>, Signer)> {
+        let Some(key) = self.keyring.get(name) else {
+            debug!(client = %client_ip, key = %name, "Dynamic update signed with an unknown key");
+            return None;
+        };
+        match key.verify(raw, None) {
+            Ok((unsigned, request_mac)) => Some((unsigned, Signer { key, request_mac })),
+            Err(e) => {
+                debug!(client = %client_ip, key = %name, error = %e, "Dynamic update signature rejected");
+                None
+            }
+        }
+    }
+
+    /// REFUSED answer for an update from a client the listener refuses.
+    pub fn refused(raw: &[u8]) -> Option<Vec<u8>> {
+        let message = Message::from_vec(raw).ok()?;
+        build_response(&message, ResponseCode::Refused, None)
+    }
+}
+
+fn build_response(
+    request: &Message,
+    rcode: ResponseCode,
+    signer: Option<&Signer>,
+) -> Option<Vec<u8>> {
+    let mut response = Message::new(request.id(), MessageType::Response, OpCode::Update);
+    response.set_response_code(rcode);
+    for query in request.queries() {
+        response.add_query(query.clone());
+    }
+    let mut wire = response.to_vec().ok()?;
+    if let Some(signer) = signer {
+        signer.key.sign(&mut wire, Some(&signer.request_mac)).ok()?;
+    }
+    Some(wire)
+}
+
+/// Turns an UPDATE message into a [`DnsUpdate`], or explains why it is
+/// malformed (RFC 2136 §3.1–3.4).
+fn decode(
+    message: &Message,
+    client_ip: IpAddr,
+    key_name: Option<Arc<str>>,
+) -> Result<DnsUpdate, String> {
+    let zone: &Query = match message.queries() {
+        [zone] if zone.query_type() == HickoryRecordType::SOA => zone,
+        _ => return Err("the zone section must hold one SOA question".to_string()),
+    };
+    let zone_class = zone.query_class();
+
+    let mut prerequisites: Vec<UpdatePrerequisite> = Vec::new();
+    for record in message.answers() {
+        let name = owner(record.name());
+        let record_type = record.record_type();
+        let prerequisite = match (record.dns_class(), record_type) {
+            (DNSClass::ANY, HickoryRecordType::ANY) => UpdatePrerequisite::NameInUse(name),
+            (DNSClass::ANY, _) => UpdatePrerequisite::RrsetExists {
+                name,
+                record_type: domain_type(record_type),
+            },
+            (DNSClass::NONE, HickoryRecordType::ANY) => UpdatePrerequisite::NameNotInUse(name),
+            (DNSClass::NONE, _) => UpdatePrerequisite::RrsetDoesNotExist {
+                name,
+                record_type: domain_type(record_type),
+            },
+            (class, _) if class == zone_class => {
+                let record_type = domain_type(record_type);
+                let value = value(record.data());
+                let existing = prerequisites.iter_mut().find_map(|p| match p {
+                    UpdatePrerequisite::RrsetEquals {
+                        name: n,
+                        record_type: t,
+                        values,
+                    } if *n == name && *t == record_type => Some(values),
+                    _ => None,
+                });
+                match existing {
+                    Some(values) => values.push(value),
+                    None => prerequisites.push(UpdatePrerequisite::RrsetEquals {
+                        name,
+                        record_type,
+                        values: vec![value],
+                    }),
+                }
+                continue;
+            }
+            (class, _) => return Err(format!("prerequisite with class {}", class)),
+        };
+        prerequisites.push(prerequisite);
+    }
+
+    let mut changes = Vec::with_capacity(message.name_servers().len());
+    for record in message.name_servers() {
+        let name = owner(record.name());
+        let record_type = record.record_type();
+        let change = match (record.dns_class(), record_type) {
+            (DNSClass::ANY, HickoryRecordType::ANY) => UpdateChange::DeleteName(name),
+            (DNSClass::ANY, _) => UpdateChange::DeleteRrset {
+                name,
+                record_type: domain_type(record_type),
+            },
+            (DNSClass::NONE, HickoryRecordType::ANY) => {
+                return Err("delete from class NONE with type ANY".to_string())
+            }
+            (DNSClass::NONE, _) => UpdateChange::DeleteRecord {
+                name,
+                record_type: domain_type(record_type),
+                value: value(record.data()),
+            },
+            (class, HickoryRecordType::ANY) if class == zone_class => {
+                return Err("add with type ANY".to_string())
+            }
+            (class, _) if class == zone_class => UpdateChange::Add {
+                name,
+                record_type: domain_type(record_type),
+                value: value(record.data()),
+                ttl: record.ttl(),
+            },
+            (class, _) => return Err(format!("update with class {}", class)),
+        };
+        changes.push(change);
+    }
+
+    Ok(DnsUpdate {
+        zone: owner(zone.name()),
+        client_ip,
+        key_name,
+        prerequisites,
+        changes,
+    })
+}
+
+/// Lowercase name without the trailing dot.
+fn owner(name: &Name) -> Arc<str> {
+    Arc::from(name.to_ascii().trim_end_matches('.').to_ascii_lowercase())
+}
+
+/// Types local records cannot hold map to `NULL`, which the local zone never
+/// contains.
+fn domain_type(record_type: HickoryRecordType) -> RecordType {
+    RecordTypeMapper::from_hickory(record_type).unwrap_or(RecordType::NULL)
+}
+
+/// Record data in the presentation form of a local record value; empty for
+/// types local records cannot hold.
+fn value(data: &RData) -> Arc<str> {
+    let value = match data {
+        RData::A(a) => a.0.to_string(),
+        RData::AAAA(aaaa) => aaaa.0.to_string(),
+        RData::CNAME(cname) => owner(&cname.0).to_string(),
+        RData::TXT(txt) => txt
+            .txt_data()
+            .iter()
+            .map(|chunk| String::from_utf8_lossy(chunk))
+            .collect(),
+        RData::MX(mx) => format!("{} {}", mx.preference(), owner(mx.exchange())),
+        RData::SRV(srv) => format!(
+            "{} {} {} {}",
+            srv.priority(),
+            srv.weight(),
+            srv.port(),
+            owner(srv.target())
+        ),
+        _ => String::new(),
+    };
+    Arc::from(value)
+}
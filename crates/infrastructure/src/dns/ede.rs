@@ -1,4 +1,4 @@
-use ferrous_dns_domain::DomainError;
+use ferrous_dns_domain::{BlockSource, DomainError};
 
 /// EDNS option code for Extended DNS Errors (RFC 8914, Section 2).
 /// hickory-proto 0.26.0-alpha.1 does not have a native EDE variant in
@@ -10,7 +10,11 @@ pub mod codes {
     pub const DNSSEC_BOGUS: u16 = 6;
     pub const DNSKEY_MISSING: u16 = 9;
     pub const BLOCKED: u16 = 15;
+    /// RFC 8914 §4.18 — blocked by a filter the operator configured for
+    /// this client (blocklists, regex filters, policies).
+    pub const FILTERED: u16 = 17;
     pub const PROHIBITED: u16 = 18;
+    pub const NOT_SUPPORTED: u16 = 21;
     pub const NO_REACHABLE_AUTHORITY: u16 = 22;
     pub const NETWORK_ERROR: u16 = 23;
     /// RFC 8914 §4.26 — Bad or Missing EDNS Cookie.
@@ -24,17 +28,26 @@ pub struct ExtendedDnsError {
 
 pub fn from_domain_error(err: &DomainError) -> Option<ExtendedDnsError> {
     let (code, text) = match err {
-        DomainError::Blocked => (codes::BLOCKED, "domain is in blocklist"),
+        DomainError::Blocked(source) => (codes::FILTERED, blocked_text(*source)),
+        DomainError::ClientNotAllowed => (codes::PROHIBITED, "client not allowed on this listener"),
         DomainError::DgaDomainDetected => (codes::BLOCKED, "DGA domain detected"),
         DomainError::DnsCookieInvalid => (codes::BAD_COOKIE, "bad or missing EDNS cookie"),
-        DomainError::FilteredQuery(_) => (codes::BLOCKED, "query filtered by policy"),
+        DomainError::FilteredQuery(_) => (codes::FILTERED, "query filtered by policy"),
         DomainError::DnsTunnelingDetected => (codes::PROHIBITED, "DNS tunneling detected"),
         DomainError::DnsRateLimited => (codes::PROHIBITED, "rate limit exceeded"),
         DomainError::DnssecValidationFailed(_) => {
             (codes::DNSSEC_BOGUS, "DNSSEC signature validation failed")
         }
         DomainError::InsecureDelegation => (codes::DNSKEY_MISSING, "insecure delegation"),
+        DomainError::UnsupportedQuery(_) => (codes::NOT_SUPPORTED, "query type not supported"),
+        DomainError::MalformedQuery(_) | DomainError::InvalidDomainName(_) => {
+            (codes::OTHER, "malformed query")
+        }
+        DomainError::InvalidDnsResponse(_) => (codes::OTHER, "invalid upstream response"),
         DomainError::QueryTimeout => (codes::NO_REACHABLE_AUTHORITY, "upstream query timed out"),
+        DomainError::QueryBudgetExceeded => {
+            (codes::NO_REACHABLE_AUTHORITY, "query time budget exhausted")
+        }
         DomainError::TransportNoHealthyServers => {
             (codes::NO_REACHABLE_AUTHORITY, "no healthy upstream servers")
         }
@@ -58,3 +71,24 @@ pub fn from_domain_error(err: &DomainError) -> Option<ExtendedDnsError> {
         extra_text: Some(text),
     })
 }
+
+/// Names the filter that blocked a query in the EDE extra text.
+fn blocked_text(source: BlockSource) -> &'static str {
+    match source {
+        BlockSource::Blocklist => "blocked by blocklist",
+        BlockSource::ManagedDomain => "blocked by managed domain rule",
+        BlockSource::RegexFilter => "blocked by regex filter",
+        BlockSource::CnameCloaking => "blocked by blocklist (CNAME target)",
+        BlockSource::Schedule => "blocked by schedule",
+        BlockSource::DnsRebinding => "blocked by DNS rebinding protection",
+        BlockSource::RateLimit => "blocked by rate limit",
+        BlockSource::DnsTunneling => "blocked by DNS tunneling detection",
+        BlockSource::NxdomainHijack => "blocked by NXDOMAIN hijack detection",
+        BlockSource::ResponseIpFilter => "blocked by response IP filter",
+        BlockSource::DgaDetection => "blocked by DGA detection",
+        BlockSource::QueryPolicy => "blocked by query policy",
+        BlockSource::RecordTypeFilter => "blocked by record type filter",
+        BlockSource::Plugin => "blocked by plugin",
+        BlockSource::TldPolicy => "blocked by TLD policy",
+    }
+}
@@ -0,0 +1,136 @@
+use arc_swap::ArcSwap;
+use ferrous_dns_application::ports::DeviceAddressRegistry;
+use ferrous_dns_domain::{Device, EdnsClientIdConfig};
+use hickory_proto::rr::rdata::opt::{ClientSubnet, EdnsCode, EdnsOption};
+use ipnetwork::IpNetwork;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::Arc;
+use tracing::debug;
+
+type Mac = [u8; 6];
+
+/// Attributes queries relayed by trusted forwarders to the devices behind
+/// them, from the MAC address the forwarder adds as an EDNS option or the
+/// host address it passes on as EDNS Client Subnet (see
+/// `dns.edns_client_id`). MACs are looked up in the ARP table kept current
+/// through [`DeviceAddressRegistry`].
+pub struct EdnsClientIdentifier {
+    option_codes: Vec<u16>,
+    forwarders: Vec<IpNetwork>,
+    use_client_subnet: bool,
+    devices: ArcSwap<HashMap<Mac, IpAddr>>,
+}
+
+impl EdnsClientIdentifier {
+    /// Returns `None` when client identification is disabled. Invalid
+    /// forwarder CIDRs are rejected by config validation and skipped here.
+    pub fn from_config(config: &EdnsClientIdConfig) -> Option<Self> {
+        if !config.enabled {
+            return None;
+        }
+        Some(Self {
+            option_codes: config.option_codes.clone(),
+            forwarders: config
+                .forwarders
+                .iter()
+                .filter_map(|cidr| cidr.parse().ok())
+                .collect(),
+            use_client_subnet: config.use_client_subnet,
+            devices: ArcSwap::from_pointee(HashMap::new()),
+        })
+    }
+
+    /// Whether `client_ip` may tag its queries. Queries from forwarders skip
+    /// the cache fast path, which cannot read EDNS options.
+    #[inline]
+    pub fn is_forwarder(&self, client_ip: IpAddr) -> bool {
+        self.forwarders.iter().any(|net| net.contains(client_ip))
+    }
+
+    /// The address of the device a forwarder relayed the query for, or
+    /// `client_ip` when the query comes from an untrusted client or carries
+    /// neither a MAC address present in the ARP table nor a single-host
+    /// client subnet.
+    pub fn identify(&self, client_ip: IpAddr, options: &[(EdnsCode, EdnsOption)]) -> IpAddr {
+        if !self.is_forwarder(client_ip) {
+            return client_ip;
+        }
+        if let Some(mac) = self.requester_mac(options) {
+            if let Some(&device_ip) = self.devices.load().get(&mac) {
+                return device_ip;
+            }
+            debug!(
+                forwarder = %client_ip,
+                mac = %format_mac(&mac),
+                "Unknown MAC in EDNS option"
+            );
+        }
+        if self.use_client_subnet {
+            if let Some(host) = options.iter().find_map(|(_, option)| match option {
+                EdnsOption::Subnet(subnet) => subnet_host(subnet),
+                _ => None,
+            }) {
+                return host;
+            }
+        }
+        client_ip
+    }
+
+    fn requester_mac(&self, options: &[(EdnsCode, EdnsOption)]) -> Option<Mac> {
+        self.option_codes.iter().find_map(|&wanted| {
+            options.iter().find_map(|(_, option)| match option {
+                EdnsOption::Unknown(code, data) if *code == wanted => parse_mac_option(data),
+                _ => None,
+            })
+        })
+    }
+}
+
+impl DeviceAddressRegistry for EdnsClientIdentifier {
+    fn replace(&self, devices: Vec<(String, IpAddr)>) {
+        let devices: HashMap<Mac, IpAddr> = devices
+            .into_iter()
+            .filter_map(|(mac, ip)| Some((parse_mac_text(&mac)?, ip)))
+            .collect();
+        debug!(
+            devices = devices.len(),
+            "EDNS client identification map updated"
+        );
+        self.devices.store(Arc::new(devices));
+    }
+}
+
+/// The address of a client subnet that names one host. Shorter prefixes
+/// are truncated by the forwarder for privacy and do not identify a device.
+fn subnet_host(subnet: &ClientSubnet) -> Option<IpAddr> {
+    let address = subnet.addr();
+    let host_prefix = if address.is_ipv4() { 32 } else { 128 };
+    (subnet.source_prefix() == host_prefix && !address.is_unspecified()).then_some(address)
+}
+
+/// Reads the option payload as 6 raw bytes, the dnsmasq default, or as
+/// text such as `aa:bb:cc:dd:ee:ff`.
+fn parse_mac_option(data: &[u8]) -> Option<Mac> {
+    if let Ok(mac) = Mac::try_from(data) {
+        let placeholder = mac.iter().all(|&b| b == 0) || mac.iter().all(|&b| b == 0xff);
+        return (!placeholder).then_some(mac);
+    }
+    parse_mac_text(std::str::from_utf8(data).ok()?.trim())
+}
+
+fn parse_mac_text(text: &str) -> Option<Mac> {
+    let normalized = Device::normalize_mac(text)?;
+    let mut mac = [0u8; 6];
+    for (byte, part) in mac.iter_mut().zip(normalized.split(':')) {
+        *byte = u8::from_str_radix(part, 16).ok()?;
+    }
+    Some(mac)
+}
+
+fn format_mac(mac: &Mac) -> String {
+    mac.iter()
+        .map(|b| format!("{b:02x}"))
+        .collect::<Vec<_>>()
+        .join(":")
+}
@@ -1,4 +1,5 @@
 use super::QueryEvent;
+use ferrous_dns_application::ports::{ChannelDepth, ChannelDepthSource};
 use tokio::sync::mpsc;
 use tracing::warn;
 
@@ -33,6 +34,17 @@ impl QueryEventEmitter {
     }
 }
 
+impl ChannelDepthSource for QueryEventEmitter {
+    fn channel_depth(&self) -> Option<ChannelDepth> {
+        let tx = self.sender.as_ref()?;
+        Some(ChannelDepth {
+            name: "query_events",
+            queued: tx.max_capacity() - tx.capacity(),
+            capacity: tx.max_capacity(),
+        })
+    }
+}
+
 impl Default for QueryEventEmitter {
     fn default() -> Self {
         Self::new_disabled()
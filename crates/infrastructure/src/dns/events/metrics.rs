@@ -1,158 +1,239 @@
-use super::QueryEvent;
 use dashmap::DashMap;
-use ferrous_dns_domain::RecordType;
+use ferrous_dns_application::ports::QueryMetricsPort;
+use ferrous_dns_domain::{BlockSource, QueryLog, QuerySource, QueryStats, RecordType};
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Instant;
 
+/// Upstream pool and server an answer came from, keyed by the `Arc<str>`s the
+/// query log already holds so counting an upstream answer never allocates.
+type UpstreamKey = (Option<Arc<str>>, Option<Arc<str>>);
+
+/// Live counters of the client queries answered since startup. Clones share
+/// the same counters, so the DNS handler records into the instance the API
+/// reads from.
 #[derive(Clone)]
 pub struct QueryMetrics {
-    total_events: Arc<AtomicU64>,
+    started_at: Instant,
 
-    successful_queries: Arc<AtomicU64>,
+    total_queries: Arc<AtomicU64>,
 
-    failed_queries: Arc<AtomicU64>,
+    blocked_queries: Arc<AtomicU64>,
 
-    dnssec_queries: Arc<AtomicU64>,
+    rate_limited_queries: Arc<AtomicU64>,
 
-    domain_counts: Arc<DashMap<Arc<str>, u64>>,
+    any_minimized_queries: Arc<AtomicU64>,
 
-    record_type_counts: Arc<DashMap<RecordType, u64>>,
+    malware_queries: Arc<AtomicU64>,
+
+    cache_hits: Arc<AtomicU64>,
 
-    upstream_counts: Arc<DashMap<Arc<str>, u64>>,
+    upstream_queries: Arc<AtomicU64>,
 
     total_response_time_us: Arc<AtomicU64>,
+
+    cache_response_time_us: Arc<AtomicU64>,
+
+    upstream_response_time_us: Arc<AtomicU64>,
+
+    record_type_counts: Arc<DashMap<RecordType, u64>>,
+
+    /// Same keys as the `source_stats` of the query log stats: `cache`,
+    /// `local_dns`, `any_minimized` and block sources.
+    source_counts: Arc<DashMap<String, u64>>,
+
+    /// Reported as the `pool:server` entries of `source_stats`.
+    upstream_counts: Arc<DashMap<UpstreamKey, u64>>,
 }
 
 impl QueryMetrics {
     pub fn new() -> Self {
         Self {
-            total_events: Arc::new(AtomicU64::new(0)),
-            successful_queries: Arc::new(AtomicU64::new(0)),
-            failed_queries: Arc::new(AtomicU64::new(0)),
-            dnssec_queries: Arc::new(AtomicU64::new(0)),
-            domain_counts: Arc::new(DashMap::new()),
+            started_at: Instant::now(),
+            total_queries: Arc::new(AtomicU64::new(0)),
+            blocked_queries: Arc::new(AtomicU64::new(0)),
+            rate_limited_queries: Arc::new(AtomicU64::new(0)),
+            any_minimized_queries: Arc::new(AtomicU64::new(0)),
+            malware_queries: Arc::new(AtomicU64::new(0)),
+            cache_hits: Arc::new(AtomicU64::new(0)),
+            upstream_queries: Arc::new(AtomicU64::new(0)),
+            total_response_time_us: Arc::new(AtomicU64::new(0)),
+            cache_response_time_us: Arc::new(AtomicU64::new(0)),
+            upstream_response_time_us: Arc::new(AtomicU64::new(0)),
             record_type_counts: Arc::new(DashMap::new()),
+            source_counts: Arc::new(DashMap::new()),
             upstream_counts: Arc::new(DashMap::new()),
-            total_response_time_us: Arc::new(AtomicU64::new(0)),
         }
     }
 
-    pub fn track(&self, event: &QueryEvent) {
-        self.total_events.fetch_add(1, Ordering::Relaxed);
-
-        if event.success {
-            self.successful_queries.fetch_add(1, Ordering::Relaxed);
-        } else {
-            self.failed_queries.fetch_add(1, Ordering::Relaxed);
+    fn count_source(&self, source: &str) {
+        if let Some(mut count) = self.source_counts.get_mut(source) {
+            *count += 1;
+            return;
         }
-
-        if event.is_dnssec_query() {
-            self.dnssec_queries.fetch_add(1, Ordering::Relaxed);
-        }
-
-        self.total_response_time_us
-            .fetch_add(event.response_time_us, Ordering::Relaxed);
-
-        self.domain_counts
-            .entry(event.domain.clone())
-            .and_modify(|c| *c += 1)
-            .or_insert(1);
-
-        self.record_type_counts
-            .entry(event.record_type)
-            .and_modify(|c| *c += 1)
-            .or_insert(1);
-
-        self.upstream_counts
-            .entry(event.upstream_server.clone())
-            .and_modify(|c| *c += 1)
-            .or_insert(1);
+        *self.source_counts.entry(source.to_string()).or_insert(0) += 1;
     }
 
-    pub fn total_events(&self) -> u64 {
-        self.total_events.load(Ordering::Relaxed)
+    pub fn total_queries(&self) -> u64 {
+        self.total_queries.load(Ordering::Relaxed)
     }
 
-    pub fn successful_queries(&self) -> u64 {
-        self.successful_queries.load(Ordering::Relaxed)
+    pub fn blocked_queries(&self) -> u64 {
+        self.blocked_queries.load(Ordering::Relaxed)
     }
 
-    pub fn failed_queries(&self) -> u64 {
-        self.failed_queries.load(Ordering::Relaxed)
+    pub fn cache_hits(&self) -> u64 {
+        self.cache_hits.load(Ordering::Relaxed)
     }
 
-    pub fn dnssec_queries(&self) -> u64 {
-        self.dnssec_queries.load(Ordering::Relaxed)
+    pub fn record_type_count(&self, record_type: RecordType) -> u64 {
+        self.record_type_counts
+            .get(&record_type)
+            .map(|v| *v)
+            .unwrap_or(0)
     }
 
-    pub fn success_rate(&self) -> f64 {
-        let total = self.total_events();
-        if total == 0 {
-            return 0.0;
-        }
-        self.successful_queries() as f64 / total as f64
+    pub fn source_count(&self, source: &str) -> u64 {
+        self.source_counts.get(source).map(|v| *v).unwrap_or(0)
     }
 
-    pub fn avg_response_time_us(&self) -> f64 {
-        let total = self.total_events();
-        if total == 0 {
-            return 0.0;
-        }
-        self.total_response_time_us.load(Ordering::Relaxed) as f64 / total as f64
+    pub fn reset(&self) {
+        self.total_queries.store(0, Ordering::Relaxed);
+        self.blocked_queries.store(0, Ordering::Relaxed);
+        self.rate_limited_queries.store(0, Ordering::Relaxed);
+        self.any_minimized_queries.store(0, Ordering::Relaxed);
+        self.malware_queries.store(0, Ordering::Relaxed);
+        self.cache_hits.store(0, Ordering::Relaxed);
+        self.upstream_queries.store(0, Ordering::Relaxed);
+        self.total_response_time_us.store(0, Ordering::Relaxed);
+        self.cache_response_time_us.store(0, Ordering::Relaxed);
+        self.upstream_response_time_us.store(0, Ordering::Relaxed);
+        self.record_type_counts.clear();
+        self.source_counts.clear();
+        self.upstream_counts.clear();
     }
+}
 
-    pub fn avg_response_time_ms(&self) -> f64 {
-        self.avg_response_time_us() / 1000.0
-    }
+fn is_malware(source: BlockSource) -> bool {
+    matches!(
+        source,
+        BlockSource::DnsTunneling
+            | BlockSource::DnsRebinding
+            | BlockSource::NxdomainHijack
+            | BlockSource::ResponseIpFilter
+            | BlockSource::DgaDetection
+    )
+}
 
-    pub fn domain_count(&self, domain: &str) -> u64 {
-        self.domain_counts.get(domain).map(|v| *v).unwrap_or(0)
+fn mean_ms(sum_us: &AtomicU64, count: u64) -> f64 {
+    if count == 0 {
+        return 0.0;
     }
+    sum_us.load(Ordering::Relaxed) as f64 / count as f64 / 1000.0
+}
 
-    pub fn record_type_count(&self, record_type: RecordType) -> u64 {
+impl QueryMetricsPort for QueryMetrics {
+    fn record(&self, query: &QueryLog) {
+        // Mirrors the query log stats, which count timed client queries.
+        let Some(time_us) = query.response_time_us else {
+            return;
+        };
+        if query.query_source != QuerySource::Client {
+            return;
+        }
+
+        self.total_queries.fetch_add(1, Ordering::Relaxed);
+        self.total_response_time_us
+            .fetch_add(time_us, Ordering::Relaxed);
         self.record_type_counts
-            .get(&record_type)
-            .map(|v| *v)
-            .unwrap_or(0)
-    }
+            .entry(query.record_type)
+            .and_modify(|c| *c += 1)
+            .or_insert(1);
 
-    pub fn upstream_count(&self, upstream: &str) -> u64 {
-        self.upstream_counts.get(upstream).map(|v| *v).unwrap_or(0)
-    }
+        let status = query.response_status;
+        if matches!(status, Some("RATE_LIMITED" | "RATE_LIMITED_TC")) {
+            self.rate_limited_queries.fetch_add(1, Ordering::Relaxed);
+        }
+        match status {
+            Some("LOCAL_DNS") => self.count_source("local_dns"),
+            Some("ANY_MINIMIZED") => {
+                self.any_minimized_queries.fetch_add(1, Ordering::Relaxed);
+                self.count_source("any_minimized");
+            }
+            _ => {}
+        }
 
-    pub fn top_domains(&self, n: usize) -> Vec<(String, u64)> {
-        let mut domains: Vec<_> = self
-            .domain_counts
-            .iter()
-            .map(|entry| (entry.key().to_string(), *entry.value()))
-            .collect();
+        if query.blocked {
+            self.blocked_queries.fetch_add(1, Ordering::Relaxed);
+            if let Some(source) = query.block_source {
+                self.count_source(source.to_str());
+                if is_malware(source) {
+                    self.malware_queries.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+        }
 
-        domains.sort_by_key(|b| std::cmp::Reverse(b.1));
-        domains.truncate(n);
-        domains
+        if query.cache_hit {
+            self.cache_hits.fetch_add(1, Ordering::Relaxed);
+            self.cache_response_time_us
+                .fetch_add(time_us, Ordering::Relaxed);
+            self.count_source("cache");
+        } else if !query.blocked && !matches!(status, Some("LOCAL_DNS" | "ANY_MINIMIZED")) {
+            self.upstream_queries.fetch_add(1, Ordering::Relaxed);
+            self.upstream_response_time_us
+                .fetch_add(time_us, Ordering::Relaxed);
+            let key = (query.upstream_pool.clone(), query.upstream_server.clone());
+            *self.upstream_counts.entry(key).or_insert(0) += 1;
+        }
     }
 
-    pub fn top_record_types(&self, n: usize) -> Vec<(RecordType, u64)> {
-        let mut types: Vec<_> = self
+    fn snapshot(&self) -> QueryStats {
+        let total = self.total_queries();
+        let cache_hits = self.cache_hits();
+        let cache_hit_rate = if total > 0 {
+            (cache_hits as f64 / total as f64) * 100.0
+        } else {
+            0.0
+        };
+        let queries_by_type: HashMap<RecordType, u64> = self
             .record_type_counts
             .iter()
             .map(|entry| (*entry.key(), *entry.value()))
             .collect();
+        let mut source_stats: HashMap<String, u64> = self
+            .source_counts
+            .iter()
+            .map(|entry| (entry.key().clone(), *entry.value()))
+            .collect();
+        for entry in self.upstream_counts.iter() {
+            let (pool, server) = entry.key();
+            let source = format!(
+                "{}:{}",
+                pool.as_deref().unwrap_or("unknown"),
+                server.as_deref().unwrap_or("unknown"),
+            );
+            *source_stats.entry(source).or_insert(0) += *entry.value();
+        }
 
-        types.sort_by_key(|b| std::cmp::Reverse(b.1));
-        types.truncate(n);
-        types
-    }
-
-    pub fn reset(&self) {
-        self.total_events.store(0, Ordering::Relaxed);
-        self.successful_queries.store(0, Ordering::Relaxed);
-        self.failed_queries.store(0, Ordering::Relaxed);
-        self.dnssec_queries.store(0, Ordering::Relaxed);
-        self.total_response_time_us.store(0, Ordering::Relaxed);
-        self.domain_counts.clear();
-        self.record_type_counts.clear();
-        self.upstream_counts.clear();
+        QueryStats {
+            queries_total: total,
+            queries_blocked: self.blocked_queries(),
+            queries_rate_limited: self.rate_limited_queries.load(Ordering::Relaxed),
+            queries_any_minimized: self.any_minimized_queries.load(Ordering::Relaxed),
+            queries_malware_detected: self.malware_queries.load(Ordering::Relaxed),
+            uptime_seconds: self.started_at.elapsed().as_secs(),
+            cache_hit_rate,
+            avg_query_time_ms: mean_ms(&self.total_response_time_us, total),
+            avg_cache_time_ms: mean_ms(&self.cache_response_time_us, cache_hits),
+            avg_upstream_time_ms: mean_ms(
+                &self.upstream_response_time_us,
+                self.upstream_queries.load(Ordering::Relaxed),
+            ),
+            source_stats,
+            ..QueryStats::default()
+        }
+        .with_analytics(queries_by_type)
     }
 }
 
@@ -9,6 +9,12 @@ pub struct QueryEvent {
     pub response_time_us: u64,
     pub success: bool,
     pub pool_name: Option<Arc<str>>,
+    /// Strategy of the pool the server belongs to.
+    pub strategy: &'static str,
+    /// 1-based position of the server among the servers tried in the pool.
+    pub attempt: u32,
+    /// Transport used for this exchange.
+    pub protocol: &'static str,
 }
 
 impl QueryEvent {
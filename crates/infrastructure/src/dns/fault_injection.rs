@@ -0,0 +1,251 @@
+use ferrous_dns_application::ports::{FaultInjectionPort, FaultKind, InjectedFault};
+use ferrous_dns_domain::{DnsProtocol, DomainError};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::time::{Duration, Instant};
+use tracing::{debug, info};
+
+/// Longest delay a latency fault may add; upstream timeouts are far shorter.
+const MAX_DELAY_MS: u64 = 60_000;
+
+struct ActiveFault {
+    id: u64,
+    kind: FaultKind,
+    expires_at: Option<Instant>,
+    hits: AtomicU64,
+}
+
+impl ActiveFault {
+    fn is_expired(&self, now: Instant) -> bool {
+        self.expires_at.is_some_and(|at| at <= now)
+    }
+
+    fn snapshot(&self, now: Instant) -> InjectedFault {
+        InjectedFault {
+            id: self.id,
+            kind: self.kind.clone(),
+            remaining_secs: self
+                .expires_at
+                .map(|at| at.saturating_duration_since(now).as_secs()),
+            hits: self.hits.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Faults injected into upstream queries and health checks. Queries are
+/// sent from free functions with no shared state, so the faults live in
+/// the [`FAULTS`] static rather than being threaded through; a flag keeps
+/// the lookup off the query path while no fault is active.
+pub struct FaultInjector {
+    active: AtomicBool,
+    next_id: AtomicU64,
+    faults: RwLock<Vec<ActiveFault>>,
+}
+
+pub static FAULTS: FaultInjector = FaultInjector::new();
+
+impl FaultInjector {
+    const fn new() -> Self {
+        Self {
+            active: AtomicBool::new(false),
+            next_id: AtomicU64::new(1),
+            faults: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// Applies the faults matching an exchange with `server`, `domain` being
+    /// `None` for health checks. Sleeps for the longest matching delay and
+    /// returns the timeout left for the exchange itself.
+    pub async fn apply(
+        &self,
+        server: &DnsProtocol,
+        domain: Option<&str>,
+        timeout_ms: u64,
+    ) -> Result<u64, DomainError> {
+        if !self.active.load(Ordering::Relaxed) {
+            return Ok(timeout_ms);
+        }
+
+        let mut delay_ms = 0;
+        let mut failure = None;
+        {
+            let now = Instant::now();
+            let faults = self.read();
+            for fault in faults.iter().filter(|f| !f.is_expired(now)) {
+                let applies = match &fault.kind {
+                    FaultKind::UpstreamDown { server: target } => {
+                        let applies = matches_server(target, server);
+                        if applies && failure.is_none() {
+                            failure = Some(DomainError::IoError(format!(
+                                "{server} is unreachable (injected fault {})",
+                                fault.id
+                            )));
+                        }
+                        applies
+                    }
+                    FaultKind::Latency {
+                        server: target,
+                        delay_ms: delay,
+                    } => {
+                        let applies = target.as_deref().is_none_or(|t| matches_server(t, server));
+                        if applies {
+                            delay_ms = delay_ms.max(*delay);
+                        }
+                        applies
+                    }
+                    FaultKind::Servfail { pattern } => {
+                        let applies = domain.is_some_and(|d| matches_domain(pattern, d));
+                        if applies && failure.is_none() {
+                            failure = Some(DomainError::InvalidDnsResponse(format!(
+                                "{server} answered SERVFAIL (injected fault {})",
+                                fault.id
+                            )));
+                        }
+                        applies
+                    }
+                };
+                if applies {
+                    fault.hits.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+        }
+
+        if delay_ms > 0 {
+            tokio::time::sleep(Duration::from_millis(delay_ms.min(timeout_ms))).await;
+            if delay_ms >= timeout_ms {
+                return Err(DomainError::QueryTimeout);
+            }
+        }
+        if let Some(e) = failure {
+            debug!(server = %server, domain = ?domain, error = %e, "Injected upstream fault");
+            return Err(e);
+        }
+        Ok(timeout_ms - delay_ms)
+    }
+
+    fn read(&self) -> RwLockReadGuard<'_, Vec<ActiveFault>> {
+        self.faults.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, Vec<ActiveFault>> {
+        self.faults.write().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Drops expired faults and lowers the flag once none is left.
+    fn prune(&self, faults: &mut Vec<ActiveFault>) {
+        let now = Instant::now();
+        faults.retain(|f| !f.is_expired(now));
+        self.active.store(!faults.is_empty(), Ordering::Relaxed);
+    }
+}
+
+/// `target` is a server as configured (`tls://dns.google:853`), its
+/// address (`8.8.8.8:53`), IP or hostname.
+fn matches_server(target: &str, server: &DnsProtocol) -> bool {
+    let target = target.trim();
+    server.to_string().eq_ignore_ascii_case(target)
+        || server
+            .hostname()
+            .is_some_and(|h| h.eq_ignore_ascii_case(target))
+        || server
+            .socket_addr()
+            .is_some_and(|addr| addr.to_string() == target || addr.ip().to_string() == target)
+}
+
+/// `pattern` matches itself and every name under it; `*` matches all names.
+fn matches_domain(pattern: &str, domain: &str) -> bool {
+    let pattern = pattern.trim().trim_start_matches("*.").trim_matches('.');
+    if pattern == "*" {
+        return true;
+    }
+    let domain = domain.trim_end_matches('.');
+    domain.eq_ignore_ascii_case(pattern)
+        || (domain.len() > pattern.len()
+            && domain.as_bytes()[domain.len() - pattern.len() - 1] == b'.'
+            && domain.as_bytes()[domain.len() - pattern.len()..]
+                .eq_ignore_ascii_case(pattern.as_bytes()))
+}
+
+fn validate(kind: &FaultKind) -> Result<(), DomainError> {
+    let invalid = |message: &str| Err(DomainError::InvalidInput(message.to_string()));
+    match kind {
+        FaultKind::UpstreamDown { server } if server.trim().is_empty() => {
+            invalid("server cannot be empty")
+        }
+        FaultKind::Latency { server, .. }
+            if server.as_deref().is_some_and(|s| s.trim().is_empty()) =>
+        {
+            invalid("server cannot be empty")
+        }
+        FaultKind::Latency { delay_ms, .. } if *delay_ms == 0 || *delay_ms > MAX_DELAY_MS => {
+            invalid("delay_ms must be between 1 and 60000")
+        }
+        FaultKind::Servfail { pattern } if pattern.trim().trim_matches('.').is_empty() => {
+            invalid("pattern cannot be empty")
+        }
+        _ => Ok(()),
+    }
+}
+
+/// [`FaultInjectionPort`] over the process-wide [`FAULTS`].
+pub struct FaultInjectionControl;
+
+impl FaultInjectionPort for FaultInjectionControl {
+    fn inject(
+        &self,
+        kind: FaultKind,
+        duration_secs: Option<u64>,
+    ) -> Result<InjectedFault, DomainError> {
+        validate(&kind)?;
+        let now = Instant::now();
+        let fault = ActiveFault {
+            id: FAULTS.next_id.fetch_add(1, Ordering::Relaxed),
+            kind,
+            expires_at: duration_secs.map(|secs| now + Duration::from_secs(secs)),
+            hits: AtomicU64::new(0),
+        };
+        let snapshot = fault.snapshot(now);
+        info!(
+            id = snapshot.id,
+            kind = snapshot.kind.as_str(),
+            duration_secs = ?duration_secs,
+            "Fault injected"
+        );
+
+        let mut faults = FAULTS.write();
+        faults.push(fault);
+        FAULTS.prune(&mut faults);
+        Ok(snapshot)
+    }
+
+    fn faults(&self) -> Vec<InjectedFault> {
+        let mut faults = FAULTS.write();
+        FAULTS.prune(&mut faults);
+        let now = Instant::now();
+        faults.iter().map(|f| f.snapshot(now)).collect()
+    }
+
+    fn remove(&self, id: u64) -> bool {
+        let mut faults = FAULTS.write();
+        let before = faults.len();
+        faults.retain(|f| f.id != id);
+        let removed = faults.len() < before;
+        FAULTS.prune(&mut faults);
+        if removed {
+            info!(id, "Fault removed");
+        }
+        removed
+    }
+
+    fn clear(&self) -> usize {
+        let mut faults = FAULTS.write();
+        FAULTS.prune(&mut faults);
+        let cleared = faults.len();
+        faults.clear();
+        FAULTS.active.store(false, Ordering::Relaxed);
+        if cleared > 0 {
+            info!(cleared, "Faults cleared");
+        }
+        cleared
+    }
+}
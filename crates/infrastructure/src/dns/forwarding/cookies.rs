@@ -0,0 +1,241 @@
+use super::response_validation::RESPONSE_VALIDATION;
+use bytes::Bytes;
+use dashmap::DashMap;
+use ferrous_dns_domain::DomainError;
+use ring::rand::{SecureRandom, SystemRandom};
+use std::net::SocketAddr;
+use std::ops::Range;
+use std::sync::LazyLock;
+use tracing::warn;
+
+const HEADER_LEN: usize = 12;
+const OPT_TYPE: u16 = 41;
+const COOKIE_OPTION: u16 = 10;
+const CLIENT_COOKIE_LEN: usize = 8;
+const MIN_SERVER_COOKIE_LEN: usize = 8;
+const MAX_SERVER_COOKIE_LEN: usize = 32;
+
+/// Extended RCODE a server returns when the query's server cookie is
+/// missing or stale (RFC 7873 §8).
+const BADCOOKIE: u16 = 23;
+
+/// Client and server cookies of one upstream server address.
+struct ServerCookies {
+    client: [u8; CLIENT_COOKIE_LEN],
+    server: [u8; MAX_SERVER_COOKIE_LEN],
+    server_len: usize,
+}
+
+impl ServerCookies {
+    fn new() -> Self {
+        let mut client = [0u8; CLIENT_COOKIE_LEN];
+        if SystemRandom::new().fill(&mut client).is_err() {
+            client.iter_mut().for_each(|b| *b = fastrand::u8(..));
+        }
+        Self {
+            client,
+            server: [0u8; MAX_SERVER_COOKIE_LEN],
+            server_len: 0,
+        }
+    }
+
+    fn option_data(&self) -> ([u8; CLIENT_COOKIE_LEN + MAX_SERVER_COOKIE_LEN], usize) {
+        let mut data = [0u8; CLIENT_COOKIE_LEN + MAX_SERVER_COOKIE_LEN];
+        data[..CLIENT_COOKIE_LEN].copy_from_slice(&self.client);
+        data[CLIENT_COOKIE_LEN..CLIENT_COOKIE_LEN + self.server_len]
+            .copy_from_slice(&self.server[..self.server_len]);
+        (data, CLIENT_COOKIE_LEN + self.server_len)
+    }
+}
+
+/// What to do with an upstream response after its cookie was checked.
+#[derive(Debug)]
+pub enum CookieReply {
+    /// The response to use, with the COOKIE option removed so the upstream's
+    /// cookies are neither cached nor passed on to clients.
+    Answer(Bytes),
+    /// The server rejected our server cookie and sent a fresh one; the query
+    /// should be sent again.
+    Retry,
+}
+
+/// DNS Cookies (RFC 7873) sent to plain UDP and TCP upstreams, by server
+/// address. Each server gets its own random client cookie, and the server
+/// cookie it last returned is echoed on every later query to it.
+pub struct UpstreamCookieJar {
+    servers: DashMap<SocketAddr, ServerCookies>,
+}
+
+/// Cookies outlive pool reloads the same way the transport cache does.
+pub static UPSTREAM_COOKIES: LazyLock<UpstreamCookieJar> = LazyLock::new(UpstreamCookieJar::new);
+
+impl UpstreamCookieJar {
+    pub fn new() -> Self {
+        Self {
+            servers: DashMap::new(),
+        }
+    }
+
+    /// Returns `query` with a COOKIE option for `server` added to its OPT
+    /// record, or `None` when the query does not end with an OPT record
+    /// (e.g. it is TSIG-signed) or already carries a cookie.
+    pub fn attach(&self, server: SocketAddr, query: &[u8]) -> Option<Vec<u8>> {
+        let opt = find_opt(query)?;
+        if opt.rdata.end != query.len() || find_cookie(query, &opt).is_some() {
+            return None;
+        }
+        let (data, len) = self
+            .servers
+            .entry(server)
+            .or_insert_with(ServerCookies::new)
+            .option_data();
+
+        let mut out = Vec::with_capacity(query.len() + 4 + len);
+        out.extend_from_slice(query);
+        out.extend_from_slice(&COOKIE_OPTION.to_be_bytes());
+        out.extend_from_slice(&(len as u16).to_be_bytes());
+        out.extend_from_slice(&data[..len]);
+        let rdlen = (opt.rdata.len() + 4 + len) as u16;
+        out[opt.rdata.start - 2..opt.rdata.start].copy_from_slice(&rdlen.to_be_bytes());
+        Some(out)
+    }
+
+    /// Checks the COOKIE option of a response from `server` to a query sent
+    /// with [`Self::attach`] and remembers the server cookie it carries.
+    ///
+    /// Responses without a cookie are accepted, since servers that do not
+    /// implement RFC 7873 omit it. A cookie that does not echo our client
+    /// cookie means the response was not sent to us and is rejected.
+    pub fn accept(&self, server: SocketAddr, response: Bytes) -> Result<CookieReply, DomainError> {
+        let Some(opt) = find_opt(&response) else {
+            return Ok(CookieReply::Answer(response));
+        };
+        let Some(option) = find_cookie(&response, &opt) else {
+            return Ok(CookieReply::Answer(response));
+        };
+
+        let cookie = &response[option.start + 4..option.end];
+        let server_len = cookie.len().saturating_sub(CLIENT_COOKIE_LEN);
+        let well_formed = cookie.len() >= CLIENT_COOKIE_LEN
+            && (server_len == 0
+                || (MIN_SERVER_COOKIE_LEN..=MAX_SERVER_COOKIE_LEN).contains(&server_len));
+        {
+            let Some(mut state) = self.servers.get_mut(&server) else {
+                return Ok(CookieReply::Answer(strip_option(&response, &opt, option)));
+            };
+            if !well_formed || cookie[..CLIENT_COOKIE_LEN] != state.client {
+                RESPONSE_VALIDATION.record_cookie_mismatch();
+                warn!(
+                    %server,
+                    "Upstream response does not echo our DNS client cookie — discarding"
+                );
+                return Err(DomainError::IoError(format!(
+                    "DNS cookie mismatch from {}",
+                    server
+                )));
+            }
+            if server_len > 0 {
+                state.server[..server_len].copy_from_slice(&cookie[CLIENT_COOKIE_LEN..]);
+                state.server_len = server_len;
+            }
+        }
+
+        let rcode = (u16::from((opt.ttl >> 24) as u8) << 4) | u16::from(response[3] & 0x0F);
+        if rcode == BADCOOKIE {
+            return Ok(CookieReply::Retry);
+        }
+        Ok(CookieReply::Answer(strip_option(&response, &opt, option)))
+    }
+
+    /// Server cookie last received from `server`, if any.
+    pub fn server_cookie(&self, server: SocketAddr) -> Option<Vec<u8>> {
+        let state = self.servers.get(&server)?;
+        (state.server_len > 0).then(|| state.server[..state.server_len].to_vec())
+    }
+}
+
+impl Default for UpstreamCookieJar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Location of the OPT pseudo-record in a message.
+struct OptRecord {
+    ttl: u32,
+    rdata: Range<usize>,
+}
+
+fn read_u16(message: &[u8], at: usize) -> Option<u16> {
+    message
+        .get(at..at + 2)
+        .map(|b| u16::from_be_bytes([b[0], b[1]]))
+}
+
+fn skip_name(message: &[u8], mut pos: usize) -> Option<usize> {
+    loop {
+        let len = *message.get(pos)?;
+        match len {
+            0 => return Some(pos + 1),
+            l if l & 0xC0 == 0xC0 => return Some(pos + 2),
+            l => pos += 1 + l as usize,
+        }
+    }
+}
+
+fn find_opt(message: &[u8]) -> Option<OptRecord> {
+    let qdcount = read_u16(message, 4)?;
+    let rr_count = [6, 8, 10]
+        .iter()
+        .map(|&at| read_u16(message, at).map(usize::from))
+        .sum::<Option<usize>>()?;
+    let additional = usize::from(read_u16(message, 10)?);
+
+    let mut pos = HEADER_LEN;
+    for _ in 0..qdcount {
+        pos = skip_name(message, pos)? + 4;
+    }
+    for index in 0..rr_count {
+        pos = skip_name(message, pos)?;
+        let rtype = read_u16(message, pos)?;
+        let ttl = message
+            .get(pos + 4..pos + 8)
+            .map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]))?;
+        let rdlen = usize::from(read_u16(message, pos + 8)?);
+        let rdata = pos + 10..pos + 10 + rdlen;
+        if rdata.end > message.len() {
+            return None;
+        }
+        if rtype == OPT_TYPE && index >= rr_count - additional {
+            return Some(OptRecord { ttl, rdata });
+        }
+        pos = rdata.end;
+    }
+    None
+}
+
+/// Range of the whole COOKIE option (code, length and data) in `message`.
+fn find_cookie(message: &[u8], opt: &OptRecord) -> Option<Range<usize>> {
+    let mut pos = opt.rdata.start;
+    while pos + 4 <= opt.rdata.end {
+        let code = read_u16(message, pos)?;
+        let end = pos + 4 + usize::from(read_u16(message, pos + 2)?);
+        if end > opt.rdata.end {
+            return None;
+        }
+        if code == COOKIE_OPTION {
+            return Some(pos..end);
+        }
+        pos = end;
+    }
+    None
+}
+
+fn strip_option(message: &[u8], opt: &OptRecord, option: Range<usize>) -> Bytes {
+    let mut out = Vec::with_capacity(message.len() - option.len());
+    out.extend_from_slice(&message[..option.start]);
+    out.extend_from_slice(&message[option.end..]);
+    let rdlen = (opt.rdata.len() - option.len()) as u16;
+    out[opt.rdata.start - 2..opt.rdata.start].copy_from_slice(&rdlen.to_be_bytes());
+    Bytes::from(out)
+}
@@ -1,11 +1,13 @@
 use super::record_type_map::RecordTypeMapper;
+use super::tsig::{TsigKey, TsigRequest};
 use ferrous_dns_domain::{DomainError, RecordType};
 use hickory_proto::op::{Edns, Message, MessageType, OpCode, Query};
 use hickory_proto::rr::Name;
 use hickory_proto::serialize::binary::{BinEncodable, BinEncoder};
 use ring::rand::{SecureRandom, SystemRandom};
-use std::str::FromStr;
-use std::sync::LazyLock;
+use std::sync::{Arc, LazyLock};
+
+static SECURE_RNG: LazyLock<SystemRandom> = LazyLock::new(SystemRandom::new);
 
 pub struct MessageBuilder;
 
@@ -24,7 +26,14 @@ impl MessageBuilder {
         record_type: &RecordType,
         dnssec_ok: bool,
     ) -> Result<(u16, Vec<u8>), DomainError> {
-        let name = Name::from_str(domain).map_err(|e| {
+        // `from_ascii` keeps letter case (needed for 0x20); Unicode names go
+        // through UTS-46 and come out as lowercase punycode.
+        let parsed = if domain.is_ascii() {
+            Name::from_ascii(domain)
+        } else {
+            Name::from_utf8(domain)
+        };
+        let name = parsed.map_err(|e| {
             DomainError::InvalidDomainName(format!("Invalid domain '{}': {}", domain, e))
         })?;
 
@@ -45,9 +54,48 @@ impl MessageBuilder {
         Ok((id, bytes))
     }
 
-    fn secure_random_id() -> u16 {
-        static SECURE_RNG: LazyLock<SystemRandom> = LazyLock::new(SystemRandom::new);
+    /// Appends a TSIG signature made with `key` to a built query. The
+    /// returned request verifies the response with
+    /// [`ResponseParser::verify_tsig`](super::ResponseParser::verify_tsig).
+    pub fn sign_tsig(
+        query: &[u8],
+        key: &Arc<TsigKey>,
+    ) -> Result<(Vec<u8>, TsigRequest), DomainError> {
+        let mut signed = Vec::with_capacity(query.len() + 128);
+        signed.extend_from_slice(query);
+        let mac = key.sign(&mut signed, None)?;
+        Ok((
+            signed,
+            TsigRequest {
+                key: Arc::clone(key),
+                mac,
+            },
+        ))
+    }
+
+    /// Randomizes the letter case of `domain` (DNS 0x20). Upstreams echo the
+    /// question name verbatim, so every letter adds one bit an off-path
+    /// spoofer has to guess on top of the message ID and source port.
+    pub fn randomize_case(domain: &str) -> String {
+        let mut bits = [0u8; 32];
+        if SECURE_RNG.fill(&mut bits).is_err() {
+            bits.iter_mut().for_each(|b| *b = fastrand::u8(..));
+        }
+        domain
+            .bytes()
+            .enumerate()
+            .map(|(i, b)| {
+                let flip = bits[(i / 8) % bits.len()] & (1 << (i % 8)) != 0;
+                if flip && b.is_ascii_alphabetic() {
+                    (b ^ 0x20) as char
+                } else {
+                    b as char
+                }
+            })
+            .collect()
+    }
 
+    pub(crate) fn secure_random_id() -> u16 {
         let mut bytes = [0u8; 2];
         SECURE_RNG
             .fill(&mut bytes)
@@ -68,7 +116,7 @@ impl MessageBuilder {
         let mut encoder = BinEncoder::new(&mut buf);
 
         message.emit(&mut encoder).map_err(|e| {
-            DomainError::InvalidDomainName(format!("Failed to serialize DNS message: {}", e))
+            DomainError::MalformedQuery(format!("Failed to serialize DNS message: {}", e))
         })?;
 
         Ok(buf)
@@ -1,9 +1,15 @@
+pub mod cookies;
 pub mod forwarder;
 pub mod message_builder;
 pub mod record_type_map;
 pub mod response_parser;
+pub mod response_validation;
+pub mod tsig;
 
+pub use cookies::{CookieReply, UpstreamCookieJar, UPSTREAM_COOKIES};
 pub use forwarder::DnsForwarder;
 pub use message_builder::MessageBuilder;
 pub use record_type_map::RecordTypeMapper;
 pub use response_parser::{DnsResponse, ResponseParser};
+pub use response_validation::RESPONSE_VALIDATION;
+pub use tsig::{is_tsig_signed, tsig_key_name, TsigKey, TsigKeyring, TsigRequest};
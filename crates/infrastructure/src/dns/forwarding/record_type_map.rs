@@ -46,6 +46,8 @@ impl RecordTypeMapper {
 
             RecordType::ZONEMD => HickoryRecordType::Unknown(63),
 
+            RecordType::ANY => HickoryRecordType::ANY,
+
             RecordType::ANAME => HickoryRecordType::ANAME,
         }
     }
@@ -89,6 +91,8 @@ impl RecordTypeMapper {
             HickoryRecordType::Unknown(45) => Some(RecordType::IPSECKEY),
             HickoryRecordType::Unknown(63) => Some(RecordType::ZONEMD),
 
+            HickoryRecordType::ANY => Some(RecordType::ANY),
+
             HickoryRecordType::OPENPGPKEY => Some(RecordType::OPENPGPKEY),
 
             HickoryRecordType::ANAME => Some(RecordType::ANAME),
@@ -1,10 +1,12 @@
+use super::response_validation::RESPONSE_VALIDATION;
+use super::tsig::TsigRequest;
 use bytes::Bytes;
 use ferrous_dns_domain::DomainError;
 use hickory_proto::op::{Message, ResponseCode};
-use hickory_proto::rr::{RData, Record};
+use hickory_proto::rr::{Name, RData, Record};
 use std::net::IpAddr;
 use std::sync::Arc;
-use tracing::debug;
+use tracing::{debug, warn};
 
 #[derive(Debug, Clone)]
 pub struct DnsResponse {
@@ -47,15 +49,36 @@ impl DnsResponse {
     }
 }
 
+/// RFC 6672; hickory has no named `RecordType` variant for it.
+const DNAME_TYPE: u16 = 39;
+
 pub struct ResponseParser;
 
 impl ResponseParser {
     /// Parses DNS response from owned bytes (zero-copy for raw_bytes).
+    ///
+    /// Answer records outside the query's bailiwick are dropped before
+    /// anything is extracted, and `raw_bytes` is re-encoded without them so
+    /// they never reach the cache.
     pub fn parse_bytes(response_bytes: Bytes) -> Result<DnsResponse, DomainError> {
-        let message = Message::from_vec(&response_bytes).map_err(|e| {
-            DomainError::InvalidDomainName(format!("Failed to parse DNS response: {}", e))
+        let mut message = Message::from_vec(&response_bytes).map_err(|e| {
+            DomainError::InvalidDnsResponse(format!("Failed to parse DNS response: {}", e))
         })?;
 
+        let dropped = Self::drop_out_of_bailiwick(&mut message);
+        let response_bytes = if dropped == 0 {
+            response_bytes
+        } else {
+            RESPONSE_VALIDATION.record_out_of_bailiwick(dropped as u64);
+            warn!(
+                dropped,
+                "Dropped out-of-bailiwick answer records from upstream response"
+            );
+            message.to_vec().map(Bytes::from).map_err(|e| {
+                DomainError::InvalidDnsResponse(format!("Failed to re-encode DNS response: {}", e))
+            })?
+        };
+
         let rcode = message.response_code();
         let truncated = message.truncated();
 
@@ -76,7 +99,7 @@ impl ResponseParser {
                     addresses.push(IpAddr::V6(aaaa.0));
                 }
                 RData::CNAME(canonical) => {
-                    let name = canonical.to_utf8();
+                    let name = canonical.to_ascii();
                     debug!(cname = %name, "CNAME record found");
                     cname_chain.push(Arc::from(name.as_str()));
                 }
@@ -115,10 +138,127 @@ impl ResponseParser {
         })
     }
 
+    /// Checks the TSIG signature of a response to a signed query and strips
+    /// the TSIG record so the rest of the pipeline sees a plain response.
+    /// Unsigned or badly signed responses are rejected.
+    pub fn verify_tsig(response_bytes: Bytes, request: &TsigRequest) -> Result<Bytes, DomainError> {
+        let (unsigned, _) = request.key.verify(&response_bytes, Some(&request.mac))?;
+        Ok(Bytes::from(unsigned))
+    }
+
     pub fn parse(response_bytes: &[u8]) -> Result<DnsResponse, DomainError> {
         Self::parse_bytes(Bytes::copy_from_slice(response_bytes))
     }
 
+    /// Removes answers whose owner is neither the question name nor a name
+    /// reached through the response's own CNAME chain (DNAMEs are accepted
+    /// at ancestors of the question). Returns how many records were removed.
+    fn drop_out_of_bailiwick(message: &mut Message) -> usize {
+        let Some(qname) = message.queries().first().map(|q| q.name().clone()) else {
+            return 0;
+        };
+
+        let mut owners: Vec<Name> = vec![qname.clone()];
+        loop {
+            let before = owners.len();
+            for record in message.answers() {
+                if let RData::CNAME(target) = record.data() {
+                    if owners.contains(record.name()) && !owners.contains(&target.0) {
+                        owners.push(target.0.clone());
+                    }
+                }
+            }
+            if owners.len() == before {
+                break;
+            }
+        }
+
+        let answers = message.answers_mut();
+        let total = answers.len();
+        answers.retain(|record| {
+            owners.contains(record.name())
+                || (u16::from(record.record_type()) == DNAME_TYPE && record.name().zone_of(&qname))
+        });
+        total - answers.len()
+    }
+
+    /// Whether the response's question section is the query's: same name
+    /// (case-insensitively), type and class. Error responses without a
+    /// question carry nothing to cache and are accepted.
+    pub fn question_matches(query_bytes: &[u8], response_bytes: &[u8]) -> bool {
+        if response_bytes.len() >= 12
+            && u16::from_be_bytes([response_bytes[4], response_bytes[5]]) == 0
+            && response_bytes[3] & 0x0F != 0
+        {
+            return true;
+        }
+        match (
+            Self::question_end(query_bytes),
+            Self::question_end(response_bytes),
+        ) {
+            (Some(q_end), Some(r_end)) => {
+                query_bytes[12..q_end].eq_ignore_ascii_case(&response_bytes[12..r_end])
+            }
+            _ => false,
+        }
+    }
+
+    /// Offset just past the first question's type and class.
+    fn question_end(buf: &[u8]) -> Option<usize> {
+        let end = Self::question_name_end(buf)? + 4;
+        (end <= buf.len()).then_some(end)
+    }
+
+    /// Whether the response echoes the query's question name byte-for-byte,
+    /// letter case included. Used to verify DNS 0x20 randomized queries.
+    pub fn question_name_matches(query_bytes: &[u8], response_bytes: &[u8]) -> bool {
+        match (
+            Self::question_name_end(query_bytes),
+            Self::question_name_end(response_bytes),
+        ) {
+            (Some(q_end), Some(r_end)) => query_bytes[12..q_end] == response_bytes[12..r_end],
+            _ => false,
+        }
+    }
+
+    /// Lowercases the question name of a wire response so a 0x20 randomized
+    /// name is not cached or relayed to clients. Answer owners compressed
+    /// against the question name follow along.
+    pub fn lowercase_question_name(response_bytes: Bytes) -> Bytes {
+        let Some(end) = Self::question_name_end(&response_bytes) else {
+            return response_bytes;
+        };
+        if !response_bytes[12..end].iter().any(u8::is_ascii_uppercase) {
+            return response_bytes;
+        }
+        let mut owned = response_bytes.to_vec();
+        let mut pos = 12;
+        while pos < end {
+            let label_len = owned[pos] as usize;
+            owned[pos + 1..pos + 1 + label_len].make_ascii_lowercase();
+            pos += label_len + 1;
+        }
+        Bytes::from(owned)
+    }
+
+    /// Offset just past the first question's name, which is never compressed.
+    fn question_name_end(buf: &[u8]) -> Option<usize> {
+        if buf.len() < 12 || u16::from_be_bytes([buf[4], buf[5]]) == 0 {
+            return None;
+        }
+        let mut pos = 12;
+        loop {
+            let label_len = *buf.get(pos)? as usize;
+            if label_len == 0 {
+                return Some(pos + 1);
+            }
+            if label_len & 0xC0 != 0 || pos + 1 + label_len > buf.len() {
+                return None;
+            }
+            pos += label_len + 1;
+        }
+    }
+
     pub fn is_transport_error(error: &DomainError) -> bool {
         matches!(
             error,
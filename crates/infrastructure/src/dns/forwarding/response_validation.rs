@@ -0,0 +1,54 @@
+use ferrous_dns_application::ports::ResponseValidationStats;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Process-wide counters for upstream responses rejected by validation.
+/// Transports validate in free functions with no shared state, so the
+/// counters live in a static rather than being threaded through.
+pub struct ResponseValidationCounters {
+    id_mismatches: AtomicU64,
+    source_mismatches: AtomicU64,
+    question_mismatches: AtomicU64,
+    cookie_mismatches: AtomicU64,
+    out_of_bailiwick_records: AtomicU64,
+}
+
+pub static RESPONSE_VALIDATION: ResponseValidationCounters = ResponseValidationCounters {
+    id_mismatches: AtomicU64::new(0),
+    source_mismatches: AtomicU64::new(0),
+    question_mismatches: AtomicU64::new(0),
+    cookie_mismatches: AtomicU64::new(0),
+    out_of_bailiwick_records: AtomicU64::new(0),
+};
+
+impl ResponseValidationCounters {
+    pub fn record_id_mismatch(&self) {
+        self.id_mismatches.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_source_mismatch(&self) {
+        self.source_mismatches.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_question_mismatch(&self) {
+        self.question_mismatches.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_cookie_mismatch(&self) {
+        self.cookie_mismatches.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_out_of_bailiwick(&self, records: u64) {
+        self.out_of_bailiwick_records
+            .fetch_add(records, Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self) -> ResponseValidationStats {
+        ResponseValidationStats {
+            id_mismatches: self.id_mismatches.load(Ordering::Relaxed),
+            source_mismatches: self.source_mismatches.load(Ordering::Relaxed),
+            question_mismatches: self.question_mismatches.load(Ordering::Relaxed),
+            cookie_mismatches: self.cookie_mismatches.load(Ordering::Relaxed),
+            out_of_bailiwick_records: self.out_of_bailiwick_records.load(Ordering::Relaxed),
+        }
+    }
+}
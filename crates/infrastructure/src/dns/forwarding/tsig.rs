@@ -0,0 +1,449 @@
+use base64::Engine;
+use ferrous_dns_domain::{DomainError, TsigAlgorithm, TsigKeyConfig, TsigKeyFile};
+use ring::hmac;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const TSIG_TYPE: u16 = 250;
+const CLASS_ANY: u16 = 255;
+const HEADER_LEN: usize = 12;
+const ARCOUNT_OFFSET: usize = 10;
+
+/// Clock skew tolerated between us and the server (RFC 8945 recommends 300s).
+const FUDGE_SECS: u16 = 300;
+
+/// TSIG error codes the server may return in a signed response.
+const BADSIG: u16 = 16;
+const BADKEY: u16 = 17;
+const BADTIME: u16 = 18;
+
+/// A TSIG key ready to sign and verify messages (RFC 8945).
+pub struct TsigKey {
+    name: Arc<str>,
+    /// Key name in uncompressed, lowercase wire format.
+    wire_name: Vec<u8>,
+    algorithm: TsigAlgorithm,
+    algorithm_wire: Vec<u8>,
+    key: hmac::Key,
+}
+
+/// MAC of a signed query, needed to verify the matching response.
+pub struct TsigRequest {
+    pub key: Arc<TsigKey>,
+    pub mac: Vec<u8>,
+}
+
+/// Keys from `dns.tsig_keys_file`, by lowercase name without the trailing dot.
+#[derive(Default)]
+pub struct TsigKeyring {
+    keys: HashMap<String, Arc<TsigKey>>,
+}
+
+impl TsigKeyring {
+    pub fn from_file(file: &TsigKeyFile) -> Result<Self, DomainError> {
+        let keys = file
+            .keys
+            .iter()
+            .map(|config| {
+                let key = TsigKey::new(config)?;
+                Ok((normalize(&config.name), Arc::new(key)))
+            })
+            .collect::<Result<_, DomainError>>()?;
+        Ok(Self { keys })
+    }
+
+    pub fn get(&self, name: &str) -> Option<Arc<TsigKey>> {
+        self.keys.get(&normalize(name)).cloned()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+fn normalize(name: &str) -> String {
+    name.trim_end_matches('.').to_ascii_lowercase()
+}
+
+impl TsigKey {
+    pub fn new(config: &TsigKeyConfig) -> Result<Self, DomainError> {
+        let secret = base64::engine::general_purpose::STANDARD
+            .decode(config.secret.trim())
+            .map_err(|e| {
+                DomainError::ConfigError(format!(
+                    "TSIG key '{}' has an invalid base64 secret: {}",
+                    config.name, e
+                ))
+            })?;
+        if secret.is_empty() {
+            return Err(DomainError::ConfigError(format!(
+                "TSIG key '{}' has an empty secret",
+                config.name
+            )));
+        }
+        let hmac_algorithm = match config.algorithm {
+            TsigAlgorithm::HmacSha256 => hmac::HMAC_SHA256,
+            TsigAlgorithm::HmacSha384 => hmac::HMAC_SHA384,
+            TsigAlgorithm::HmacSha512 => hmac::HMAC_SHA512,
+        };
+        Ok(Self {
+            name: Arc::from(normalize(&config.name).as_str()),
+            wire_name: wire_name(&config.name)?,
+            algorithm: config.algorithm,
+            algorithm_wire: wire_name(config.algorithm.as_str())?,
+            key: hmac::Key::new(hmac_algorithm, &secret),
+        })
+    }
+
+    pub fn name(&self) -> &Arc<str> {
+        &self.name
+    }
+
+    pub fn algorithm(&self) -> TsigAlgorithm {
+        self.algorithm
+    }
+
+    /// Appends a TSIG record to `message` and returns its MAC. `prior_mac`
+    /// is the request MAC when signing a response.
+    pub fn sign(
+        &self,
+        message: &mut Vec<u8>,
+        prior_mac: Option<&[u8]>,
+    ) -> Result<Vec<u8>, DomainError> {
+        if message.len() < HEADER_LEN {
+            return Err(DomainError::MalformedQuery(
+                "DNS message too short to sign".into(),
+            ));
+        }
+        let time_signed = unix_now();
+        let original_id = u16::from_be_bytes([message[0], message[1]]);
+
+        let input = self.digest_input(message, prior_mac, time_signed, FUDGE_SECS, 0, &[]);
+        let mac = hmac::sign(&self.key, &input).as_ref().to_vec();
+
+        let mut rdata = Vec::with_capacity(self.algorithm_wire.len() + 16 + mac.len());
+        rdata.extend_from_slice(&self.algorithm_wire);
+        rdata.extend_from_slice(&time_signed.to_be_bytes()[2..]);
+        rdata.extend_from_slice(&FUDGE_SECS.to_be_bytes());
+        rdata.extend_from_slice(&(mac.len() as u16).to_be_bytes());
+        rdata.extend_from_slice(&mac);
+        rdata.extend_from_slice(&original_id.to_be_bytes());
+        rdata.extend_from_slice(&0u16.to_be_bytes());
+        rdata.extend_from_slice(&0u16.to_be_bytes());
+
+        message.extend_from_slice(&self.wire_name);
+        message.extend_from_slice(&TSIG_TYPE.to_be_bytes());
+        message.extend_from_slice(&CLASS_ANY.to_be_bytes());
+        message.extend_from_slice(&0u32.to_be_bytes());
+        message.extend_from_slice(&(rdata.len() as u16).to_be_bytes());
+        message.extend_from_slice(&rdata);
+        adjust_arcount(message, 1)?;
+
+        Ok(mac)
+    }
+
+    /// Checks the TSIG record that must end `message`, returning the message
+    /// without it and the record's MAC. `prior_mac` is the request MAC when
+    /// verifying a response.
+    pub fn verify(
+        &self,
+        message: &[u8],
+        prior_mac: Option<&[u8]>,
+    ) -> Result<(Vec<u8>, Vec<u8>), DomainError> {
+        let (record, mut unsigned) = self.strip_record(message)?;
+        let input = self.digest_input(
+            &unsigned,
+            prior_mac,
+            record.time_signed,
+            record.fudge,
+            record.error,
+            &record.other,
+        );
+        self.check_mac(&record, &input)?;
+
+        // Hand back the message with the ID it arrived with.
+        unsigned[..2].copy_from_slice(&message[..2]);
+        Ok((unsigned, record.mac))
+    }
+
+    /// Checks a later message of a signed zone transfer (RFC 8945 §5.3.1),
+    /// which covers the prior MAC, the unsigned messages received since it
+    /// (`unsigned_before`) and the timers only.
+    pub fn verify_continuation(
+        &self,
+        message: &[u8],
+        prior_mac: &[u8],
+        unsigned_before: &[u8],
+    ) -> Result<(Vec<u8>, Vec<u8>), DomainError> {
+        let (record, mut unsigned) = self.strip_record(message)?;
+        let mut input = Vec::with_capacity(prior_mac.len() + unsigned_before.len() + message.len());
+        input.extend_from_slice(&(prior_mac.len() as u16).to_be_bytes());
+        input.extend_from_slice(prior_mac);
+        input.extend_from_slice(unsigned_before);
+        input.extend_from_slice(&unsigned);
+        input.extend_from_slice(&record.time_signed.to_be_bytes()[2..]);
+        input.extend_from_slice(&record.fudge.to_be_bytes());
+        self.check_mac(&record, &input)?;
+
+        unsigned[..2].copy_from_slice(&message[..2]);
+        Ok((unsigned, record.mac))
+    }
+
+    /// Finds our TSIG record at the end of `message` and returns it with the
+    /// message it signed: record removed and original ID restored.
+    fn strip_record(&self, message: &[u8]) -> Result<(TsigRecord, Vec<u8>), DomainError> {
+        let record =
+            TsigRecord::find(message)?.ok_or_else(|| tsig_error("message is not TSIG-signed"))?;
+
+        if record.key_name != self.wire_name {
+            return Err(tsig_error("signed with an unknown key"));
+        }
+        if record.algorithm != self.algorithm_wire {
+            return Err(tsig_error("signed with an unexpected algorithm"));
+        }
+        match record.error {
+            0 => {}
+            BADSIG => return Err(tsig_error("server rejected our signature (BADSIG)")),
+            BADKEY => return Err(tsig_error("server does not know our key (BADKEY)")),
+            BADTIME => return Err(tsig_error("server clock differs from ours (BADTIME)")),
+            other => return Err(tsig_error(&format!("server returned TSIG error {other}"))),
+        }
+
+        let mut unsigned = message[..record.offset].to_vec();
+        adjust_arcount(&mut unsigned, -1)?;
+        unsigned[..2].copy_from_slice(&record.original_id.to_be_bytes());
+        Ok((record, unsigned))
+    }
+
+    fn check_mac(&self, record: &TsigRecord, input: &[u8]) -> Result<(), DomainError> {
+        // Truncated MACs (RFC 8945 §5.2.2.1) are not accepted.
+        hmac::verify(&self.key, input, &record.mac)
+            .map_err(|_| tsig_error("signature does not match"))?;
+
+        if unix_now().abs_diff(record.time_signed) > u64::from(record.fudge) {
+            return Err(tsig_error("signature time is outside the allowed window"));
+        }
+        Ok(())
+    }
+
+    /// Bytes covered by the MAC: the prior MAC, the message without its
+    /// TSIG record, then the TSIG variables.
+    fn digest_input(
+        &self,
+        message: &[u8],
+        prior_mac: Option<&[u8]>,
+        time_signed: u64,
+        fudge: u16,
+        error: u16,
+        other: &[u8],
+    ) -> Vec<u8> {
+        let mut input = Vec::with_capacity(
+            message.len() + self.wire_name.len() + self.algorithm_wire.len() + 96,
+        );
+        if let Some(mac) = prior_mac {
+            input.extend_from_slice(&(mac.len() as u16).to_be_bytes());
+            input.extend_from_slice(mac);
+        }
+        input.extend_from_slice(message);
+        input.extend_from_slice(&self.wire_name);
+        input.extend_from_slice(&CLASS_ANY.to_be_bytes());
+        input.extend_from_slice(&0u32.to_be_bytes());
+        input.extend_from_slice(&self.algorithm_wire);
+        input.extend_from_slice(&time_signed.to_be_bytes()[2..]);
+        input.extend_from_slice(&fudge.to_be_bytes());
+        input.extend_from_slice(&error.to_be_bytes());
+        input.extend_from_slice(&(other.len() as u16).to_be_bytes());
+        input.extend_from_slice(other);
+        input
+    }
+}
+
+/// The TSIG record ending a message.
+struct TsigRecord {
+    /// Offset of the record in the message.
+    offset: usize,
+    key_name: Vec<u8>,
+    algorithm: Vec<u8>,
+    time_signed: u64,
+    fudge: u16,
+    mac: Vec<u8>,
+    original_id: u16,
+    error: u16,
+    other: Vec<u8>,
+}
+
+impl TsigRecord {
+    /// Walks `message` to its last additional record and parses it if it is
+    /// a TSIG record.
+    fn find(message: &[u8]) -> Result<Option<Self>, DomainError> {
+        if message.len() < HEADER_LEN {
+            return Err(tsig_error("message too short"));
+        }
+        let count = |at: usize| u16::from_be_bytes([message[at], message[at + 1]]) as usize;
+        let (qd, an, ns, ar) = (count(4), count(6), count(8), count(10));
+        if ar == 0 {
+            return Ok(None);
+        }
+
+        let mut pos = HEADER_LEN;
+        for _ in 0..qd {
+            pos = skip_name(message, pos)? + 4;
+        }
+        let mut last = pos;
+        for _ in 0..an + ns + ar {
+            last = pos;
+            pos = skip_name(message, pos)?;
+            let rdlen = read_u16(message, pos + 8)? as usize;
+            pos += 10 + rdlen;
+        }
+        if pos != message.len() {
+            return Err(tsig_error("trailing data after the last record"));
+        }
+
+        let (key_name, name_end) = read_name(message, last)?;
+        if read_u16(message, name_end)? != TSIG_TYPE {
+            return Ok(None);
+        }
+        let rdata_start = name_end + 10;
+        let (algorithm, mut at) = read_name(message, rdata_start)?;
+        let time_high = read_u16(message, at)? as u64;
+        let time_low = read_u32(message, at + 2)? as u64;
+        let fudge = read_u16(message, at + 6)?;
+        let mac_len = read_u16(message, at + 8)? as usize;
+        at += 10;
+        let mac = slice(message, at, mac_len)?.to_vec();
+        at += mac_len;
+        let original_id = read_u16(message, at)?;
+        let error = read_u16(message, at + 2)?;
+        let other_len = read_u16(message, at + 4)? as usize;
+        let other = slice(message, at + 6, other_len)?.to_vec();
+
+        Ok(Some(Self {
+            offset: last,
+            key_name,
+            algorithm,
+            time_signed: (time_high << 32) | time_low,
+            fudge,
+            mac,
+            original_id,
+            error,
+            other,
+        }))
+    }
+}
+
+/// Whether `message` ends with a TSIG record. Later messages of a zone
+/// transfer may be sent unsigned.
+pub fn is_tsig_signed(message: &[u8]) -> bool {
+    matches!(TsigRecord::find(message), Ok(Some(_)))
+}
+
+/// Name of the key that signed `message`, lowercase and without the
+/// trailing dot, or `None` when it is not TSIG-signed.
+pub fn tsig_key_name(message: &[u8]) -> Option<String> {
+    let record = TsigRecord::find(message).ok()??;
+    let mut labels = Vec::new();
+    let mut pos = 0;
+    while let Some(&len) = record.key_name.get(pos) {
+        if len == 0 {
+            break;
+        }
+        let label = record.key_name.get(pos + 1..pos + 1 + len as usize)?;
+        labels.push(String::from_utf8_lossy(label).into_owned());
+        pos += 1 + len as usize;
+    }
+    Some(labels.join("."))
+}
+
+fn tsig_error(reason: &str) -> DomainError {
+    DomainError::IoError(format!("TSIG verification failed: {}", reason))
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn adjust_arcount(message: &mut [u8], delta: i32) -> Result<(), DomainError> {
+    let current = u16::from_be_bytes([message[ARCOUNT_OFFSET], message[ARCOUNT_OFFSET + 1]]);
+    let updated = u16::try_from(i32::from(current) + delta)
+        .map_err(|_| tsig_error("additional record count out of range"))?;
+    message[ARCOUNT_OFFSET..ARCOUNT_OFFSET + 2].copy_from_slice(&updated.to_be_bytes());
+    Ok(())
+}
+
+/// Encodes `name` as uncompressed, lowercase wire-format labels.
+fn wire_name(name: &str) -> Result<Vec<u8>, DomainError> {
+    let mut wire = Vec::with_capacity(name.len() + 2);
+    for label in name.trim_end_matches('.').split('.') {
+        if label.is_empty() || label.len() > 63 {
+            return Err(DomainError::InvalidDomainName(format!(
+                "Invalid TSIG key name '{}'",
+                name
+            )));
+        }
+        wire.push(label.len() as u8);
+        wire.extend(label.bytes().map(|b| b.to_ascii_lowercase()));
+    }
+    wire.push(0);
+    Ok(wire)
+}
+
+fn skip_name(message: &[u8], mut pos: usize) -> Result<usize, DomainError> {
+    loop {
+        let len = *message
+            .get(pos)
+            .ok_or_else(|| tsig_error("truncated name"))?;
+        match len {
+            0 => return Ok(pos + 1),
+            l if l & 0xC0 == 0xC0 => return Ok(pos + 2),
+            l => pos += 1 + l as usize,
+        }
+    }
+}
+
+/// Reads a possibly compressed name as uncompressed, lowercase wire format,
+/// returning it and the offset just past it.
+fn read_name(message: &[u8], start: usize) -> Result<(Vec<u8>, usize), DomainError> {
+    let mut wire = Vec::new();
+    let mut pos = start;
+    let mut end = None;
+    for _ in 0..128 {
+        let len = *message
+            .get(pos)
+            .ok_or_else(|| tsig_error("truncated name"))?;
+        if len & 0xC0 == 0xC0 {
+            let low = *message
+                .get(pos + 1)
+                .ok_or_else(|| tsig_error("truncated name"))?;
+            end.get_or_insert(pos + 2);
+            pos = (((len & 0x3F) as usize) << 8) | low as usize;
+            continue;
+        }
+        wire.push(len);
+        if len == 0 {
+            return Ok((wire, end.unwrap_or(pos + 1)));
+        }
+        let label = slice(message, pos + 1, len as usize)?;
+        wire.extend(label.iter().map(u8::to_ascii_lowercase));
+        pos += 1 + len as usize;
+    }
+    Err(tsig_error("name compression loop"))
+}
+
+fn slice(message: &[u8], at: usize, len: usize) -> Result<&[u8], DomainError> {
+    message
+        .get(at..at + len)
+        .ok_or_else(|| tsig_error("truncated record"))
+}
+
+fn read_u16(message: &[u8], at: usize) -> Result<u16, DomainError> {
+    slice(message, at, 2).map(|b| u16::from_be_bytes([b[0], b[1]]))
+}
+
+fn read_u32(message: &[u8], at: usize) -> Result<u32, DomainError> {
+    slice(message, at, 4).map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+}
@@ -0,0 +1,27 @@
+use std::borrow::Cow;
+
+/// Canonical ASCII form of a domain name: lowercase, with internationalized
+/// labels converted to punycode per UTS-46 (`bücher.de` → `xn--bcher-kva.de`).
+///
+/// Queries arrive on the wire in punycode, so list entries and user input are
+/// run through this before being compared against them. A leading `*.`
+/// wildcard is preserved. Names that fail IDNA processing are returned
+/// lowercased but otherwise untouched.
+pub fn to_ascii_domain(domain: &str) -> Cow<'_, str> {
+    if domain.is_ascii() {
+        return if domain.bytes().any(|b| b.is_ascii_uppercase()) {
+            Cow::Owned(domain.to_ascii_lowercase())
+        } else {
+            Cow::Borrowed(domain)
+        };
+    }
+
+    let (prefix, base) = match domain.strip_prefix("*.") {
+        Some(rest) => ("*.", rest),
+        None => ("", domain),
+    };
+    match idna::domain_to_ascii(base) {
+        Ok(ascii) if !ascii.is_empty() => Cow::Owned(format!("{prefix}{ascii}")),
+        _ => Cow::Owned(domain.to_lowercase()),
+    }
+}
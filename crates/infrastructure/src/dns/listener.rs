@@ -0,0 +1,44 @@
+use super::access_control::{AccessControlList, AclVerdict, DEFAULT_ACL_NAME};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+/// Client policy of one DNS listener: the ACL deciding which clients may
+/// query it and the group clients without an assignment fall into.
+#[derive(Clone)]
+pub struct ListenerPolicy {
+    acl: Arc<AccessControlList>,
+    default_group_id: Option<i64>,
+}
+
+impl Default for ListenerPolicy {
+    fn default() -> Self {
+        Self::new(
+            Arc::new(AccessControlList::permissive(DEFAULT_ACL_NAME)),
+            None,
+        )
+    }
+}
+
+impl ListenerPolicy {
+    pub fn new(acl: Arc<AccessControlList>, default_group_id: Option<i64>) -> Self {
+        Self {
+            acl,
+            default_group_id,
+        }
+    }
+
+    #[inline]
+    pub fn allows(&self, ip: IpAddr) -> bool {
+        self.acl.allows(ip)
+    }
+
+    #[inline]
+    pub fn check(&self, ip: IpAddr) -> AclVerdict {
+        self.acl.check(ip)
+    }
+
+    #[inline]
+    pub fn default_group_id(&self) -> Option<i64> {
+        self.default_group_id
+    }
+}
@@ -0,0 +1,29 @@
+use super::PoolManager;
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{UpstreamAddressRefresh, UpstreamAddressRefreshPort};
+use std::sync::Arc;
+
+/// Re-resolves the upstream hostnames of every pool manager built from the
+/// same configuration (forwarding, DNSSEC, cache refresh).
+pub struct UpstreamAddressRefresher {
+    pool_managers: Vec<Arc<PoolManager>>,
+}
+
+impl UpstreamAddressRefresher {
+    pub fn new(pool_managers: Vec<Arc<PoolManager>>) -> Self {
+        Self { pool_managers }
+    }
+}
+
+#[async_trait]
+impl UpstreamAddressRefreshPort for UpstreamAddressRefresher {
+    async fn refresh_addresses(&self) -> UpstreamAddressRefresh {
+        let mut total = UpstreamAddressRefresh::default();
+        for pool_manager in &self.pool_managers {
+            let refresh = pool_manager.refresh_addresses().await;
+            total.changed += refresh.changed;
+            total.unresolved += refresh.unresolved;
+        }
+        total
+    }
+}
@@ -16,6 +16,11 @@ impl BalancedStrategy {
         }
     }
 
+    /// Index the next query will start from, without advancing the rotation.
+    pub fn peek_start(&self, servers: usize) -> usize {
+        self.counter.load(Ordering::Relaxed) % servers.max(1)
+    }
+
     pub async fn query_refs(&self, ctx: &QueryContext<'_>) -> Result<UpstreamResult, DomainError> {
         if ctx.servers.is_empty() {
             return Err(DomainError::TransportNoHealthyServers);
@@ -33,7 +38,13 @@ impl BalancedStrategy {
                 ctx.timeout_ms,
                 ctx.emitter,
                 ctx.pool_name,
+                ctx.strategy,
+                i as u32 + 1,
                 ctx.server_displays,
+                ctx.case_randomized,
+                ctx.cookies,
+                ctx.tsig.as_deref(),
+                ctx.udp_socket,
             )
             .await
             {
@@ -45,8 +56,14 @@ impl BalancedStrategy {
                         latency_ms: r.latency_ms,
                         pool_name: Arc::clone(ctx.pool_name),
                         server_display: r.server_display,
+                        strategy: ctx.strategy,
+                        attempt: i as u32 + 1,
+                        protocol: r.protocol,
                     });
                 }
+                Err(DomainError::QueryBudgetExceeded) => {
+                    return Err(DomainError::QueryBudgetExceeded)
+                }
                 Err(e) => {
                     warn!(protocol = %ctx.servers[index], error = %e, "Server failed, trying next");
                 }
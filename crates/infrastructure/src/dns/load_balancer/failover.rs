@@ -26,7 +26,13 @@ impl FailoverStrategy {
                 ctx.timeout_ms,
                 ctx.emitter,
                 ctx.pool_name,
+                ctx.strategy,
+                index as u32 + 1,
                 ctx.server_displays,
+                ctx.case_randomized,
+                ctx.cookies,
+                ctx.tsig.as_deref(),
+                ctx.udp_socket,
             )
             .await
             {
@@ -38,8 +44,14 @@ impl FailoverStrategy {
                         latency_ms: r.latency_ms,
                         pool_name: Arc::clone(ctx.pool_name),
                         server_display: r.server_display,
+                        strategy: ctx.strategy,
+                        attempt: index as u32 + 1,
+                        protocol: r.protocol,
                     });
                 }
+                Err(DomainError::QueryBudgetExceeded) => {
+                    return Err(DomainError::QueryBudgetExceeded)
+                }
                 Err(e) => {
                     warn!(protocol = %protocol, error = %e, position = index, "Failing over");
                 }
@@ -7,6 +7,13 @@ use std::time::Duration;
 use tokio::time::interval;
 use tracing::{debug, info, warn};
 
+/// Smoothing applied to recent check latency.
+const LATENCY_EWMA_ALPHA: f64 = 0.3;
+/// Smoothing of the slow-moving latency baseline recent latency is compared to.
+const BASELINE_EWMA_ALPHA: f64 = 0.05;
+/// Smoothing of the failure rate; each check counts as 1 (failed) or 0 (ok).
+const FAILURE_EWMA_ALPHA: f64 = 0.3;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ServerStatus {
     Healthy,
@@ -21,6 +28,44 @@ pub struct ServerHealth {
     pub consecutive_successes: u16,
     pub last_check_latency_ms: Option<u64>,
     pub last_error: Option<String>,
+    /// Exponentially weighted latency of recent successful checks.
+    pub latency_ewma_ms: Option<f64>,
+    /// Slow-moving latency average used as the server's normal latency.
+    pub baseline_latency_ms: Option<f64>,
+    /// Exponentially weighted share of recent checks that failed.
+    pub failure_ewma: f64,
+}
+
+impl ServerHealth {
+    /// Share of this server's capacity considered available, in `[0, 1]`:
+    /// the recent success rate, scaled down when recent latency is above
+    /// the baseline.
+    pub fn capacity_factor(&self) -> f64 {
+        let latency_factor = match (self.latency_ewma_ms, self.baseline_latency_ms) {
+            (Some(recent), Some(baseline)) => (baseline.max(1.0) / recent.max(1.0)).min(1.0),
+            _ => 1.0,
+        };
+        ((1.0 - self.failure_ewma) * latency_factor).clamp(0.0, 1.0)
+    }
+
+    fn record_latency(&mut self, latency_ms: u64) {
+        let sample = latency_ms as f64;
+        self.latency_ewma_ms = Some(ewma(self.latency_ewma_ms, sample, LATENCY_EWMA_ALPHA));
+        self.baseline_latency_ms =
+            Some(ewma(self.baseline_latency_ms, sample, BASELINE_EWMA_ALPHA));
+    }
+
+    fn record_outcome(&mut self, failed: bool) {
+        let sample = if failed { 1.0 } else { 0.0 };
+        self.failure_ewma = ewma(Some(self.failure_ewma), sample, FAILURE_EWMA_ALPHA);
+    }
+}
+
+fn ewma(current: Option<f64>, sample: f64, alpha: f64) -> f64 {
+    match current {
+        Some(current) => current + alpha * (sample - current),
+        None => sample,
+    }
 }
 
 impl Default for ServerHealth {
@@ -31,6 +76,9 @@ impl Default for ServerHealth {
             consecutive_successes: 0,
             last_check_latency_ms: None,
             last_error: None,
+            latency_ewma_ms: None,
+            baseline_latency_ms: None,
+            failure_ewma: 0.0,
         }
     }
 }
@@ -95,6 +143,19 @@ impl HealthChecker {
         timeout_ms: u64,
     ) {
         let start = std::time::Instant::now();
+        #[cfg(feature = "fault-injection")]
+        let timeout_ms = match crate::dns::fault_injection::FAULTS
+            .apply(&protocol, None, timeout_ms)
+            .await
+        {
+            Ok(remaining_ms) => remaining_ms,
+            Err(e) => {
+                warn!(server = %protocol, error = %e, "Health check: FAILED");
+                let latency_ms = start.elapsed().as_millis() as u64;
+                self.mark_failed(&protocol, Some(latency_ms), Some(e.to_string()));
+                return;
+            }
+        };
         let timeout_duration = Duration::from_millis(timeout_ms);
 
         let dns_transport = match transport::get_or_create_transport(&protocol) {
@@ -150,6 +211,8 @@ impl HealthChecker {
         entry.consecutive_successes = entry.consecutive_successes.saturating_add(1);
         entry.last_check_latency_ms = Some(latency_ms);
         entry.last_error = None;
+        entry.record_latency(latency_ms);
+        entry.record_outcome(false);
         if entry.consecutive_successes >= self.success_threshold as u16 {
             if entry.status != ServerStatus::Healthy {
                 info!(server = %protocol, latency_ms, "Server marked HEALTHY");
@@ -169,6 +232,7 @@ impl HealthChecker {
         entry.consecutive_failures = entry.consecutive_failures.saturating_add(1);
         entry.last_check_latency_ms = latency_ms;
         entry.last_error = error;
+        entry.record_outcome(true);
         if entry.consecutive_failures >= self.failure_threshold as u16 {
             if entry.status != ServerStatus::Unhealthy {
                 warn!(server = %protocol, "Server marked UNHEALTHY");
@@ -199,6 +263,22 @@ impl HealthChecker {
             .unwrap_or(ServerStatus::Unknown)
     }
 
+    /// Average [`ServerHealth::capacity_factor`] across checked servers;
+    /// `1.0` before the first check.
+    pub fn capacity_factor(&self) -> f64 {
+        let (sum, count) = self
+            .health_map
+            .iter()
+            .fold((0.0, 0usize), |(sum, count), entry| {
+                (sum + entry.value().capacity_factor(), count + 1)
+            });
+        if count == 0 {
+            1.0
+        } else {
+            sum / count as f64
+        }
+    }
+
     pub fn get_health_info(&self, protocol: &Arc<DnsProtocol>) -> Option<ServerHealth> {
         self.health_map.get(protocol).map(|h| h.clone())
     }
@@ -1,3 +1,4 @@
+pub mod address_refresher;
 pub mod balanced;
 pub mod failover;
 pub mod health;
@@ -7,10 +8,11 @@ pub mod query;
 pub mod strategy;
 pub mod upstream_health_adapter;
 
+pub use address_refresher::UpstreamAddressRefresher;
 pub use balanced::BalancedStrategy;
 pub use failover::FailoverStrategy;
 pub use health::{HealthChecker, ServerHealth, ServerStatus};
 pub use parallel::ParallelStrategy;
-pub use pool::{PoolGroupEntry, PoolManager};
+pub use pool::{PoolGroupEntry, PoolManager, RoutePreview};
 pub use strategy::{Strategy, UpstreamResult};
 pub use upstream_health_adapter::UpstreamHealthAdapter;
@@ -34,7 +34,13 @@ impl ParallelStrategy {
                     ctx.timeout_ms,
                     &emitter,
                     &pool_name,
+                    ctx.strategy,
+                    1,
                     &sd,
+                    ctx.case_randomized,
+                    ctx.cookies,
+                    ctx.tsig.as_deref(),
+                    ctx.udp_socket,
                 )
                 .await
                 .map(|r| UpstreamResult {
@@ -43,6 +49,9 @@ impl ParallelStrategy {
                     latency_ms: r.latency_ms,
                     pool_name,
                     server_display: r.server_display,
+                    strategy: ctx.strategy,
+                    attempt: 1,
+                    protocol: r.protocol,
                 })
             }
             2 => {
@@ -57,25 +66,36 @@ impl ParallelStrategy {
                 let sd = Arc::clone(ctx.server_displays);
                 let qb = Arc::clone(&ctx.query_bytes);
                 let timeout_ms = ctx.timeout_ms;
+                let case_randomized = ctx.case_randomized;
+                let cookies = ctx.cookies;
+                let tsig = ctx.tsig.clone();
+                let strategy = ctx.strategy;
+                let udp_socket = ctx.udp_socket;
 
                 let result = timeout(Duration::from_millis(timeout_ms), async move {
                     tokio::select! {
-                        r = query_server(&s0, &qb, &domain, &record_type, timeout_ms, &emitter0, &pool_name, &sd) => {
+                        r = query_server(&s0, &qb, &domain, &record_type, timeout_ms, &emitter0, &pool_name, strategy, 1, &sd, case_randomized, cookies, tsig.as_deref(), udp_socket) => {
                             r.map(|r| UpstreamResult {
                                 response: r.response,
                                 server: r.server_addr,
                                 latency_ms: r.latency_ms,
                                 pool_name: Arc::clone(&pool_name),
                                 server_display: r.server_display,
+                                strategy,
+                                attempt: 1,
+                                protocol: r.protocol,
                             })
                         }
-                        r = query_server(&s1, &qb, &domain, &record_type, timeout_ms, &emitter1, &pool_name, &sd) => {
+                        r = query_server(&s1, &qb, &domain, &record_type, timeout_ms, &emitter1, &pool_name, strategy, 1, &sd, case_randomized, cookies, tsig.as_deref(), udp_socket) => {
                             r.map(|r| UpstreamResult {
                                 response: r.response,
                                 server: r.server_addr,
                                 latency_ms: r.latency_ms,
                                 pool_name: Arc::clone(&pool_name),
                                 server_display: r.server_display,
+                                strategy,
+                                attempt: 1,
+                                protocol: r.protocol,
                             })
                         }
                     }
@@ -93,6 +113,10 @@ impl ParallelStrategy {
                 let mut futs = FuturesUnordered::new();
 
                 let per_server_timeout_ms = ctx.timeout_ms;
+                let case_randomized = ctx.case_randomized;
+                let cookies = ctx.cookies;
+                let strategy = ctx.strategy;
+                let udp_socket = ctx.udp_socket;
                 let domain_arc = Arc::clone(ctx.domain);
                 for &protocol in ctx.servers {
                     let protocol = Arc::clone(protocol);
@@ -102,6 +126,7 @@ impl ParallelStrategy {
                     let pool_name = Arc::clone(ctx.pool_name);
                     let server_displays = Arc::clone(ctx.server_displays);
                     let query_bytes = Arc::clone(&ctx.query_bytes);
+                    let tsig = ctx.tsig.clone();
 
                     futs.push(async move {
                         query_server(
@@ -112,7 +137,13 @@ impl ParallelStrategy {
                             per_server_timeout_ms,
                             &emitter,
                             &pool_name,
+                            strategy,
+                            1,
                             &server_displays,
+                            case_randomized,
+                            cookies,
+                            tsig.as_deref(),
+                            udp_socket,
                         )
                         .await
                     });
@@ -129,6 +160,9 @@ impl ParallelStrategy {
                                 latency_ms: r.latency_ms,
                                 pool_name: Arc::clone(ctx.pool_name),
                                 server_display: r.server_display,
+                                strategy: ctx.strategy,
+                                attempt: 1,
+                                protocol: r.protocol,
                             });
                         }
                     }
@@ -4,8 +4,11 @@ use super::health::HealthChecker;
 use super::parallel::ParallelStrategy;
 use super::strategy::{QueryContext, Strategy, UpstreamResult};
 use crate::dns::events::QueryEventEmitter;
-use crate::dns::forwarding::{MessageBuilder, ResponseParser};
-use crate::dns::transport::resolver;
+use crate::dns::forwarding::{MessageBuilder, ResponseParser, TsigKey, TsigKeyring};
+use crate::dns::transport::BootstrapResolver;
+use arc_swap::ArcSwap;
+use ferrous_dns_application::ports::UpstreamAddressRefresh;
+use ferrous_dns_application::use_cases::dns::query_budget;
 use ferrous_dns_domain::{
     Config, DnsProtocol, DomainError, RecordType, UpstreamPool, UpstreamStrategy,
 };
@@ -20,21 +23,60 @@ pub struct PoolManager {
     pools: Vec<PoolWithStrategy>,
     health_checker: Option<Arc<HealthChecker>>,
     emitter: QueryEventEmitter,
+    case_randomization: bool,
+    cookies: bool,
+    bootstrap: Arc<BootstrapResolver>,
 }
 
 /// Maps one original configured server string to its resolved protocol entries.
+#[derive(Clone)]
 pub struct ServerGroup {
     pub original: Arc<str>,
     pub protocols: Vec<Arc<DnsProtocol>>,
 }
 
+impl ServerGroup {
+    fn is_unresolved(&self) -> bool {
+        self.protocols.iter().any(|p| p.needs_resolution())
+    }
+}
+
+/// The resolved servers of one pool, swapped as a whole when an upstream
+/// hostname resolves to new addresses.
+struct PoolServers {
+    protocols: Vec<Arc<DnsProtocol>>,
+    groups: Vec<ServerGroup>,
+    displays: Arc<HashMap<Arc<DnsProtocol>, Arc<str>>>,
+}
+
+impl PoolServers {
+    fn new(groups: Vec<ServerGroup>) -> Self {
+        let protocols: Vec<Arc<DnsProtocol>> = groups
+            .iter()
+            .flat_map(|g| g.protocols.iter().cloned())
+            .collect();
+        let displays = Arc::new(
+            protocols
+                .iter()
+                .map(|p| (Arc::clone(p), Arc::from(p.to_string())))
+                .collect(),
+        );
+        Self {
+            protocols,
+            groups,
+            displays,
+        }
+    }
+}
+
 struct PoolWithStrategy {
     config: UpstreamPool,
     strategy: Strategy,
-    server_protocols: Vec<Arc<DnsProtocol>>,
-    server_groups: Vec<ServerGroup>,
+    /// Configured servers as parsed, before hostname resolution.
+    entries: Vec<(Arc<str>, DnsProtocol)>,
+    servers: ArcSwap<PoolServers>,
     name_arc: Arc<str>,
-    server_displays: Arc<HashMap<Arc<DnsProtocol>, Arc<str>>>,
+    tsig: Option<Arc<TsigKey>>,
 }
 
 impl PoolManager {
@@ -42,9 +84,25 @@ impl PoolManager {
         pools: Vec<UpstreamPool>,
         health_checker: Option<Arc<HealthChecker>>,
         emitter: QueryEventEmitter,
+    ) -> Result<Self, DomainError> {
+        Self::new_with_bootstrap(
+            pools,
+            health_checker,
+            emitter,
+            Arc::new(BootstrapResolver::default()),
+        )
+        .await
+    }
+
+    /// Like [`Self::new`], resolving upstream hostnames through `bootstrap`.
+    pub async fn new_with_bootstrap(
+        pools: Vec<UpstreamPool>,
+        health_checker: Option<Arc<HealthChecker>>,
+        emitter: QueryEventEmitter,
+        bootstrap: Arc<BootstrapResolver>,
     ) -> Result<Self, DomainError> {
         if pools.is_empty() {
-            return Err(DomainError::InvalidDomainName(
+            return Err(DomainError::ConfigError(
                 "At least one pool must be configured".into(),
             ));
         }
@@ -64,35 +122,26 @@ impl PoolManager {
                     s.parse::<DnsProtocol>()
                         .map(|proto| (Arc::from(s.as_str()), proto))
                         .map_err(|e| {
-                            DomainError::InvalidDomainName(format!(
-                                "Invalid endpoint '{}': {}",
-                                s, e
-                            ))
+                            DomainError::ConfigError(format!("Invalid endpoint '{}': {}", s, e))
                         })
                 })
                 .collect();
 
-            let parsed = server_entries?;
-            let server_groups = Self::expand_hostnames(parsed).await;
+            let entries = server_entries?;
+            let mut server_groups = Vec::with_capacity(entries.len());
+            for (original, protocol) in &entries {
+                server_groups
+                    .push(Self::expand_server(&bootstrap, original, protocol.clone()).await);
+            }
 
             let name_arc: Arc<str> = Arc::from(pool.name.as_str());
-            let server_protocols: Vec<Arc<DnsProtocol>> = server_groups
-                .iter()
-                .flat_map(|g| g.protocols.iter().cloned())
-                .collect();
-            let server_displays: Arc<HashMap<Arc<DnsProtocol>, Arc<str>>> = Arc::new(
-                server_protocols
-                    .iter()
-                    .map(|p| (Arc::clone(p), Arc::from(p.to_string())))
-                    .collect(),
-            );
             pools_with_strategy.push(PoolWithStrategy {
                 config: pool,
                 strategy,
-                server_protocols,
-                server_groups,
+                entries,
+                servers: ArcSwap::from_pointee(PoolServers::new(server_groups)),
                 name_arc,
-                server_displays,
+                tsig: None,
             });
         }
         pools_with_strategy.sort_by_key(|p| p.config.priority);
@@ -101,104 +150,166 @@ impl PoolManager {
             pools: pools_with_strategy,
             health_checker,
             emitter,
+            case_randomization: false,
+            cookies: false,
+            bootstrap,
         })
     }
 
-    async fn expand_hostnames(entries: Vec<(Arc<str>, DnsProtocol)>) -> Vec<ServerGroup> {
-        let mut groups = Vec::new();
-        for (original, protocol) in entries {
-            if protocol.needs_resolution() {
-                match &protocol {
-                    DnsProtocol::Udp { addr }
-                    | DnsProtocol::Tcp { addr }
-                    | DnsProtocol::Tls { addr, .. }
-                    | DnsProtocol::Quic { addr, .. } => {
-                        let (hostname, port) = match addr.unresolved_parts() {
-                            Some((h, p)) => (h.to_string(), p),
-                            None => {
-                                groups.push(ServerGroup {
-                                    original,
-                                    protocols: vec![Arc::new(protocol)],
-                                });
-                                continue;
-                            }
-                        };
-                        match resolver::resolve_all(&hostname, port, Duration::from_secs(5)).await {
-                            Ok(addrs) => {
-                                let limited = Self::limit_resolved_addrs(addrs);
-                                info!(
-                                    "{} resolved to {} upstream servers (limited to {} per family)",
-                                    hostname,
-                                    limited.len(),
-                                    4
-                                );
-                                let protocols: Vec<Arc<DnsProtocol>> = limited
-                                    .iter()
-                                    .map(|addr| {
-                                        let resolved = protocol.with_resolved_addr(*addr);
-                                        info!("  → {}", addr);
-                                        Arc::new(resolved)
-                                    })
-                                    .collect();
-                                groups.push(ServerGroup {
-                                    original,
-                                    protocols,
-                                });
-                            }
-                            Err(e) => {
-                                warn!(
-                                    hostname = %hostname,
-                                    error = %e,
-                                    "Failed to resolve upstream hostname, keeping unresolved"
-                                );
-                                groups.push(ServerGroup {
-                                    original,
-                                    protocols: vec![Arc::new(protocol)],
-                                });
-                            }
+    /// Sends upstream queries with a 0x20 case-randomized name and rejects
+    /// UDP responses that do not echo it exactly.
+    pub fn with_case_randomization(mut self, enabled: bool) -> Self {
+        self.case_randomization = enabled;
+        self
+    }
+
+    /// Sends DNS cookies (RFC 7873) to plain UDP and TCP upstreams and
+    /// rejects responses whose cookie does not echo ours.
+    pub fn with_cookies(mut self, enabled: bool) -> Self {
+        self.cookies = enabled;
+        self
+    }
+
+    /// Signs the queries of every pool that names a `tsig_key` with that
+    /// key from `keyring`.
+    pub fn with_tsig_keys(mut self, keyring: &TsigKeyring) -> Result<Self, DomainError> {
+        for pool in &mut self.pools {
+            let Some(ref key_name) = pool.config.tsig_key else {
+                continue;
+            };
+            let key = keyring.get(key_name).ok_or_else(|| {
+                DomainError::ConfigError(format!(
+                    "Pool '{}' references unknown TSIG key '{}'",
+                    pool.config.name, key_name
+                ))
+            })?;
+            info!(pool = %pool.config.name, key = %key.name(), "TSIG signing enabled");
+            pool.tsig = Some(key);
+        }
+        Ok(self)
+    }
+
+    async fn expand_server(
+        bootstrap: &BootstrapResolver,
+        original: &Arc<str>,
+        protocol: DnsProtocol,
+    ) -> ServerGroup {
+        let unresolved = |protocol: DnsProtocol| ServerGroup {
+            original: Arc::clone(original),
+            protocols: vec![Arc::new(protocol)],
+        };
+        if !protocol.needs_resolution() {
+            return unresolved(protocol);
+        }
+
+        match &protocol {
+            DnsProtocol::Udp { addr }
+            | DnsProtocol::Tcp { addr }
+            | DnsProtocol::Tls { addr, .. }
+            | DnsProtocol::Quic { addr, .. } => {
+                let (hostname, port) = match addr.unresolved_parts() {
+                    Some((h, p)) => (h.to_string(), p),
+                    None => return unresolved(protocol),
+                };
+                match bootstrap
+                    .resolve(&hostname, port, Duration::from_secs(5))
+                    .await
+                {
+                    Ok(addrs) => {
+                        let limited = Self::limit_resolved_addrs(addrs);
+                        info!(
+                            "{} resolved to {} upstream servers (limited to {} per family)",
+                            hostname,
+                            limited.len(),
+                            4
+                        );
+                        let protocols: Vec<Arc<DnsProtocol>> = limited
+                            .iter()
+                            .map(|addr| {
+                                let resolved = protocol.with_resolved_addr(*addr);
+                                info!("  → {}", addr);
+                                Arc::new(resolved)
+                            })
+                            .collect();
+                        ServerGroup {
+                            original: Arc::clone(original),
+                            protocols,
                         }
                     }
-                    DnsProtocol::Https { hostname, .. } | DnsProtocol::H3 { hostname, .. } => {
-                        let host = hostname.to_string();
-                        let port = Self::extract_port_from_hostname(&host, 443);
-                        let clean_host = host.rsplit_once(':').map_or(host.as_str(), |(h, _)| h);
-                        match resolver::resolve_all(clean_host, port, Duration::from_secs(5)).await
-                        {
-                            Ok(addrs) => {
-                                let limited = Self::limit_resolved_addrs(addrs);
-                                info!("{} pre-resolved to {} addresses", clean_host, limited.len());
-                                for addr in &limited {
-                                    info!("  → {}", addr);
-                                }
-                                groups.push(ServerGroup {
-                                    original,
-                                    protocols: vec![Arc::new(
-                                        protocol.with_resolved_addrs(limited),
-                                    )],
-                                });
-                            }
-                            Err(e) => {
-                                warn!(
-                                    hostname = %clean_host,
-                                    error = %e,
-                                    "Failed to pre-resolve, transport will resolve at runtime"
-                                );
-                                groups.push(ServerGroup {
-                                    original,
-                                    protocols: vec![Arc::new(protocol)],
-                                });
-                            }
+                    Err(e) => {
+                        warn!(
+                            hostname = %hostname,
+                            error = %e,
+                            "Failed to resolve upstream hostname, keeping unresolved"
+                        );
+                        unresolved(protocol)
+                    }
+                }
+            }
+            DnsProtocol::Https { hostname, .. } | DnsProtocol::H3 { hostname, .. } => {
+                let host = hostname.to_string();
+                let port = Self::extract_port_from_hostname(&host, 443);
+                let clean_host = host.rsplit_once(':').map_or(host.as_str(), |(h, _)| h);
+                match bootstrap
+                    .resolve(clean_host, port, Duration::from_secs(5))
+                    .await
+                {
+                    Ok(addrs) => {
+                        let limited = Self::limit_resolved_addrs(addrs);
+                        info!("{} pre-resolved to {} addresses", clean_host, limited.len());
+                        for addr in &limited {
+                            info!("  → {}", addr);
                         }
+                        ServerGroup {
+                            original: Arc::clone(original),
+                            protocols: vec![Arc::new(protocol.with_resolved_addrs(limited))],
+                        }
+                    }
+                    Err(e) => {
+                        warn!(
+                            hostname = %clean_host,
+                            error = %e,
+                            "Failed to pre-resolve, transport will resolve at runtime"
+                        );
+                        unresolved(protocol)
                     }
                 }
-            } else {
-                groups.push(ServerGroup {
-                    original,
-                    protocols: vec![Arc::new(protocol)],
-                });
             }
         }
-        groups
+    }
+
+    /// Resolves the upstream hostnames again and swaps in any servers whose
+    /// addresses changed. A hostname that fails to resolve keeps the
+    /// addresses it already had.
+    pub async fn refresh_addresses(&self) -> UpstreamAddressRefresh {
+        let mut refresh = UpstreamAddressRefresh::default();
+        for pool in &self.pools {
+            let current = pool.servers.load_full();
+            let mut groups = current.groups.clone();
+            let mut changed = false;
+            for ((original, protocol), group) in pool.entries.iter().zip(groups.iter_mut()) {
+                if !protocol.needs_resolution() {
+                    continue;
+                }
+                let fresh = Self::expand_server(&self.bootstrap, original, protocol.clone()).await;
+                if fresh.is_unresolved() {
+                    if group.is_unresolved() {
+                        refresh.unresolved += 1;
+                    }
+                    continue;
+                }
+                if fresh.protocols != group.protocols {
+                    *group = fresh;
+                    changed = true;
+                    refresh.changed += 1;
+                }
+            }
+            if changed {
+                info!(pool = %pool.config.name, "Upstream addresses updated");
+                pool.servers.store(Arc::new(PoolServers::new(groups)));
+            }
+        }
+        refresh
     }
 
     fn limit_resolved_addrs(addrs: Vec<SocketAddr>) -> Vec<SocketAddr> {
@@ -249,34 +360,91 @@ impl PoolManager {
             %domain, "Starting load balancer query"
         );
 
-        let query_bytes: Arc<[u8]> =
-            Arc::from(MessageBuilder::build_query(domain, record_type, dnssec_ok)?);
+        self.query_pools(&self.pools, domain, record_type, timeout_ms, dnssec_ok)
+            .await
+    }
 
-        for pool in &self.pools {
-            let healthy_refs: SmallVec<[&Arc<DnsProtocol>; 16]> =
-                if let Some(ref checker) = self.health_checker {
-                    pool.server_protocols
-                        .iter()
-                        .filter(|p| checker.is_healthy(p))
-                        .collect()
-                } else {
-                    pool.server_protocols.iter().collect()
-                };
+    /// Queries only the pool named `pool_name`, without falling back to the
+    /// other pools when it is unreachable.
+    pub async fn query_pool(
+        &self,
+        pool_name: &str,
+        domain: &Arc<str>,
+        record_type: &RecordType,
+        timeout_ms: u64,
+        dnssec_ok: bool,
+    ) -> Result<UpstreamResult, DomainError> {
+        let Some(index) = self.pools.iter().position(|p| p.config.name == pool_name) else {
+            return Err(DomainError::ConfigError(format!(
+                "Unknown upstream pool '{pool_name}'"
+            )));
+        };
+
+        debug!(pool = pool_name, %domain, "Starting single-pool query");
+
+        self.query_pools(
+            &self.pools[index..=index],
+            domain,
+            record_type,
+            timeout_ms,
+            dnssec_ok,
+        )
+        .await
+    }
+
+    async fn query_pools(
+        &self,
+        pools: &[PoolWithStrategy],
+        domain: &Arc<str>,
+        record_type: &RecordType,
+        timeout_ms: u64,
+        dnssec_ok: bool,
+    ) -> Result<UpstreamResult, DomainError> {
+        let query_bytes: Arc<[u8]> = if self.case_randomization {
+            let randomized = MessageBuilder::randomize_case(domain);
+            Arc::from(MessageBuilder::build_query(
+                &randomized,
+                record_type,
+                dnssec_ok,
+            )?)
+        } else {
+            Arc::from(MessageBuilder::build_query(domain, record_type, dnssec_ok)?)
+        };
+
+        for pool in pools {
+            let servers = pool.servers.load_full();
+            let healthy_refs = self.healthy_servers(&servers);
 
             if healthy_refs.is_empty() {
                 debug!(pool = %pool.config.name, "All unhealthy, skipping");
                 continue;
             }
 
+            let (pool_query_bytes, tsig) = match pool.tsig {
+                Some(ref key) => {
+                    let (signed, request) = MessageBuilder::sign_tsig(&query_bytes, key)?;
+                    (Arc::from(signed), Some(Arc::new(request)))
+                }
+                None => (Arc::clone(&query_bytes), None),
+            };
+
+            // Bounds the whole pool (parallel races included) by what is
+            // left of the query budget.
+            let timeout_ms = query_budget::attempt_timeout_ms(timeout_ms)?;
             let ctx = QueryContext {
                 servers: &healthy_refs,
                 domain,
                 record_type,
                 timeout_ms,
-                query_bytes: Arc::clone(&query_bytes),
+                query_bytes: pool_query_bytes,
                 emitter: &self.emitter,
                 pool_name: &pool.name_arc,
-                server_displays: &pool.server_displays,
+                strategy: pool.config.strategy.as_str(),
+                server_displays: &servers.displays,
+                case_randomized: self.case_randomization,
+                cookies: self.cookies,
+                tsig,
+                udp_socket: pool.config.udp_socket,
             };
 
             match pool.strategy.query_refs(&ctx).await {
@@ -298,24 +466,92 @@ impl PoolManager {
         Err(DomainError::TransportAllServersUnreachable)
     }
 
+    fn healthy_servers<'a>(
+        &self,
+        servers: &'a PoolServers,
+    ) -> SmallVec<[&'a Arc<DnsProtocol>; 16]> {
+        match self.health_checker {
+            Some(ref checker) => servers
+                .protocols
+                .iter()
+                .filter(|p| checker.is_healthy(p))
+                .collect(),
+            None => servers.protocols.iter().collect(),
+        }
+    }
+
+    /// Reports the pool and server order the next query would use, following
+    /// the same health filtering and pool fallback as [`Self::query`].
+    pub fn preview_route(&self, pool_name: Option<&str>) -> Option<RoutePreview> {
+        let pools = match pool_name {
+            Some(name) => {
+                let index = self.pools.iter().position(|p| p.config.name == name)?;
+                &self.pools[index..=index]
+            }
+            None => &self.pools[..],
+        };
+
+        let mut skipped_pools = Vec::new();
+        for pool in pools {
+            let pool_servers = pool.servers.load();
+            let healthy = self.healthy_servers(&pool_servers);
+            if healthy.is_empty() {
+                skipped_pools.push(Arc::clone(&pool.name_arc));
+                continue;
+            }
+            let start = pool.strategy.peek_start(healthy.len());
+            let servers = (0..healthy.len())
+                .map(|i| {
+                    let protocol = healthy[(start + i) % healthy.len()];
+                    pool_servers
+                        .displays
+                        .get(protocol)
+                        .cloned()
+                        .unwrap_or_else(|| Arc::from(protocol.to_string()))
+                })
+                .collect();
+            return Some(RoutePreview {
+                pool_name: Arc::clone(&pool.name_arc),
+                strategy: pool.config.strategy,
+                servers,
+                skipped_pools,
+            });
+        }
+        None
+    }
+
     pub fn get_all_servers(&self) -> Vec<std::net::SocketAddr> {
         self.pools
             .iter()
-            .flat_map(|p| p.server_protocols.iter().filter_map(|p| p.socket_addr()))
+            .flat_map(|p| {
+                p.servers
+                    .load()
+                    .protocols
+                    .iter()
+                    .filter_map(|p| p.socket_addr())
+                    .collect::<Vec<_>>()
+            })
             .collect()
     }
 
     pub fn get_all_arc_protocols(&self) -> Vec<Arc<DnsProtocol>> {
         self.pools
             .iter()
-            .flat_map(|p| p.server_protocols.iter().cloned())
+            .flat_map(|p| p.servers.load().protocols.clone())
             .collect()
     }
 
     pub fn get_all_protocols(&self) -> Vec<DnsProtocol> {
         self.pools
             .iter()
-            .flat_map(|p| p.server_protocols.iter().map(|p| (**p).clone()))
+            .flat_map(|p| {
+                p.servers
+                    .load()
+                    .protocols
+                    .iter()
+                    .map(|p| (**p).clone())
+                    .collect::<Vec<_>>()
+            })
             .collect()
     }
 
@@ -327,11 +563,12 @@ impl PoolManager {
             .flat_map(|p| {
                 let pool_name = Arc::clone(&p.name_arc);
                 let strategy = p.config.strategy;
-                p.server_groups.iter().map(move |g| PoolGroupEntry {
+                let groups = p.servers.load().groups.clone();
+                groups.into_iter().map(move |g| PoolGroupEntry {
                     pool_name: Arc::clone(&pool_name),
                     strategy,
-                    original: Arc::clone(&g.original),
-                    protocols: g.protocols.clone(),
+                    original: g.original,
+                    protocols: g.protocols,
                 })
             })
             .collect()
@@ -346,3 +583,12 @@ pub struct PoolGroupEntry {
     pub original: Arc<str>,
     pub protocols: Vec<Arc<DnsProtocol>>,
 }
+
+/// Returned by [`PoolManager::preview_route`]: the pool that would answer and
+/// its healthy servers in try order.
+pub struct RoutePreview {
+    pub pool_name: Arc<str>,
+    pub strategy: UpstreamStrategy,
+    pub servers: Vec<Arc<str>>,
+    pub skipped_pools: Vec<Arc<str>>,
+}
@@ -1,17 +1,25 @@
 use crate::dns::events::{QueryEvent, QueryEventEmitter};
-use crate::dns::forwarding::{DnsResponse, ResponseParser};
-use crate::dns::transport;
-use ferrous_dns_domain::{DnsProtocol, DomainError, RecordType};
+use crate::dns::forwarding::{
+    CookieReply, DnsResponse, ResponseParser, TsigRequest, RESPONSE_VALIDATION, UPSTREAM_COOKIES,
+};
+use crate::dns::transport::{self, DnsTransport};
+use bytes::Bytes;
+use ferrous_dns_application::ports::{record_upstream_attempt, UpstreamAttempt};
+use ferrous_dns_application::use_cases::dns::query_budget;
+use ferrous_dns_domain::{DnsProtocol, DomainError, RecordType, UdpSocketMode};
 use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
+use tracing::warn;
 
 pub struct QueryAttemptResult {
     pub response: DnsResponse,
     pub server_addr: SocketAddr,
     pub latency_ms: u64,
     pub server_display: Arc<str>,
+    /// Transport that produced `response`; `TCP` after a truncated UDP answer.
+    pub protocol: &'static str,
 }
 
 fn get_display(protocol: &DnsProtocol, cache: &HashMap<Arc<DnsProtocol>, Arc<str>>) -> Arc<str> {
@@ -21,6 +29,139 @@ fn get_display(protocol: &DnsProtocol, cache: &HashMap<Arc<DnsProtocol>, Arc<str
         .unwrap_or_else(|| Arc::from(protocol.to_string()))
 }
 
+/// Rejects responses that do not answer the outstanding query. UDP checks
+/// the transaction ID and source address in the transport; stream transports
+/// get the ID check here, and every transport gets the question check.
+fn validate_response(
+    protocol: &DnsProtocol,
+    query_bytes: &[u8],
+    response_bytes: &[u8],
+    domain: &str,
+    case_randomized: bool,
+) -> Result<(), DomainError> {
+    if let DnsProtocol::Tcp { .. } | DnsProtocol::Tls { .. } = protocol {
+        let server = protocol
+            .socket_addr()
+            .unwrap_or_else(|| SocketAddr::from(([0, 0, 0, 0], 0)));
+        transport::udp::validate_response_id(query_bytes, response_bytes, server)?;
+    }
+
+    let exact_case = case_randomized && matches!(protocol, DnsProtocol::Udp { .. });
+    let question_ok = if exact_case {
+        ResponseParser::question_name_matches(query_bytes, response_bytes)
+            && ResponseParser::question_matches(query_bytes, response_bytes)
+    } else {
+        ResponseParser::question_matches(query_bytes, response_bytes)
+    };
+    if !question_ok {
+        RESPONSE_VALIDATION.record_question_mismatch();
+        warn!(
+            server = %protocol,
+            %domain,
+            exact_case,
+            "Upstream response question does not match the query — discarding to prevent spoofing"
+        );
+        return Err(DomainError::IoError(format!(
+            "DNS question mismatch from {}",
+            protocol
+        )));
+    }
+    Ok(())
+}
+
+/// Verifies and strips the TSIG record of a response to a signed query.
+fn check_tsig(
+    protocol: &DnsProtocol,
+    bytes: Bytes,
+    tsig: Option<&TsigRequest>,
+) -> Result<Bytes, DomainError> {
+    match tsig {
+        Some(request) => ResponseParser::verify_tsig(bytes, request).inspect_err(|e| {
+            warn!(
+                server = %protocol,
+                key = %request.key.name(),
+                error = %e,
+                "Discarding upstream response with a bad TSIG signature"
+            );
+        }),
+        None => Ok(bytes),
+    }
+}
+
+/// Server address to keep DNS cookies for, when `protocol` carries them.
+/// Encrypted transports authenticate the server already, and a TSIG
+/// signature must stay the last record of the query.
+fn cookie_server(
+    protocol: &DnsProtocol,
+    cookies: bool,
+    tsig: Option<&TsigRequest>,
+) -> Option<SocketAddr> {
+    match protocol {
+        DnsProtocol::Udp { .. } | DnsProtocol::Tcp { .. } if cookies && tsig.is_none() => {
+            protocol.socket_addr()
+        }
+        _ => None,
+    }
+}
+
+async fn send(
+    protocol: &DnsProtocol,
+    udp_socket: UdpSocketMode,
+    query_bytes: &[u8],
+    timeout: Duration,
+) -> Result<Bytes, DomainError> {
+    let response = match (udp_socket, protocol) {
+        (UdpSocketMode::PerQuery, DnsProtocol::Udp { addr }) => {
+            transport::udp::UdpTransport::per_query(addr.clone())
+                .send(query_bytes, timeout)
+                .await?
+        }
+        _ => {
+            transport::get_or_create_transport(protocol)?
+                .send(query_bytes, timeout)
+                .await?
+        }
+    };
+    Ok(response.bytes)
+}
+
+/// Sends the query with the DNS cookie of `server` and checks the cookie of
+/// the response, resending once when the server hands out a new server
+/// cookie with BADCOOKIE (RFC 7873 §5.3). Returns the query that was sent,
+/// for a TCP retry of a truncated answer, and the response.
+async fn send_with_cookie(
+    protocol: &DnsProtocol,
+    udp_socket: UdpSocketMode,
+    server: SocketAddr,
+    query_bytes: &[u8],
+    domain: &str,
+    case_randomized: bool,
+    timeout: Duration,
+) -> Result<(Option<Vec<u8>>, Bytes), DomainError> {
+    for _ in 0..2 {
+        let with_cookie = UPSTREAM_COOKIES.attach(server, query_bytes);
+        let sent = with_cookie.as_deref().unwrap_or(query_bytes);
+        let response = send(protocol, udp_socket, sent, timeout).await?;
+        validate_response(protocol, sent, &response, domain, case_randomized)?;
+        match UPSTREAM_COOKIES.accept(server, response)? {
+            CookieReply::Answer(bytes) => return Ok((with_cookie, bytes)),
+            CookieReply::Retry => continue,
+        }
+    }
+    Err(DomainError::IoError(format!(
+        "{} rejected the DNS cookie twice",
+        protocol
+    )))
+}
+
+fn restore_case(bytes: Bytes, case_randomized: bool) -> Bytes {
+    if case_randomized {
+        ResponseParser::lowercase_question_name(bytes)
+    } else {
+        bytes
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn query_server(
     protocol: &DnsProtocol,
@@ -30,16 +171,107 @@ pub async fn query_server(
     timeout_ms: u64,
     emitter: &QueryEventEmitter,
     pool_name: &Arc<str>,
+    strategy: &'static str,
+    attempt: u32,
     server_displays: &Arc<HashMap<Arc<DnsProtocol>, Arc<str>>>,
+    case_randomized: bool,
+    cookies: bool,
+    tsig: Option<&TsigRequest>,
+    udp_socket: UdpSocketMode,
 ) -> Result<QueryAttemptResult, DomainError> {
+    let timeout_ms = query_budget::attempt_timeout_ms(timeout_ms)?;
     let start = Instant::now();
-    let timeout_duration = Duration::from_millis(timeout_ms);
+    let result = exchange(
+        protocol,
+        query_bytes,
+        domain,
+        record_type,
+        timeout_ms,
+        emitter,
+        pool_name,
+        strategy,
+        attempt,
+        server_displays,
+        case_randomized,
+        cookies,
+        tsig,
+        udp_socket,
+    )
+    .await;
 
-    let dns_transport = transport::get_or_create_transport(protocol)?;
+    record_upstream_attempt(|| {
+        let (server, protocol_name, error) = match &result {
+            Ok(r) => (Arc::clone(&r.server_display), r.protocol, None),
+            Err(e) => (
+                get_display(protocol, server_displays),
+                protocol.protocol_name(),
+                Some(e.to_string()),
+            ),
+        };
+        UpstreamAttempt {
+            server,
+            pool_name: Arc::clone(pool_name),
+            strategy,
+            protocol: protocol_name,
+            attempt,
+            latency_us: start.elapsed().as_micros() as u64,
+            error,
+        }
+    });
+
+    result
+}
 
-    let transport_response = dns_transport.send(query_bytes, timeout_duration).await?;
+#[allow(clippy::too_many_arguments)]
+async fn exchange(
+    protocol: &DnsProtocol,
+    query_bytes: &[u8],
+    domain: &Arc<str>,
+    record_type: &RecordType,
+    timeout_ms: u64,
+    emitter: &QueryEventEmitter,
+    pool_name: &Arc<str>,
+    strategy: &'static str,
+    attempt: u32,
+    server_displays: &Arc<HashMap<Arc<DnsProtocol>, Arc<str>>>,
+    case_randomized: bool,
+    cookies: bool,
+    tsig: Option<&TsigRequest>,
+    udp_socket: UdpSocketMode,
+) -> Result<QueryAttemptResult, DomainError> {
+    let start = Instant::now();
+    #[cfg(feature = "fault-injection")]
+    let timeout_ms = crate::dns::fault_injection::FAULTS
+        .apply(protocol, Some(domain.as_ref()), timeout_ms)
+        .await?;
+    let timeout_duration = Duration::from_millis(timeout_ms);
+
+    let cookie_addr = cookie_server(protocol, cookies, tsig);
+    let (with_cookie, response_bytes) = match cookie_addr {
+        Some(server) => {
+            send_with_cookie(
+                protocol,
+                udp_socket,
+                server,
+                query_bytes,
+                domain,
+                case_randomized,
+                timeout_duration,
+            )
+            .await?
+        }
+        None => {
+            let bytes = send(protocol, udp_socket, query_bytes, timeout_duration).await?;
+            validate_response(protocol, query_bytes, &bytes, domain, case_randomized)?;
+            (None, bytes)
+        }
+    };
+    // The TCP retry of a truncated answer goes to the same server and
+    // carries the same cookie.
+    let query_bytes = with_cookie.as_deref().unwrap_or(query_bytes);
 
-    let dns_response = ResponseParser::parse_bytes(transport_response.bytes)?;
+    let response_bytes = check_tsig(protocol, response_bytes, tsig)?;
+    let dns_response = ResponseParser::parse_bytes(restore_case(response_bytes, case_randomized))?;
 
     let response_time_us = start.elapsed().as_micros() as u64;
     let server_arc = get_display(protocol, server_displays);
@@ -51,6 +283,9 @@ pub async fn query_server(
             response_time_us,
             success: !dns_response.addresses.is_empty() || !dns_response.cname_chain.is_empty(),
             pool_name: Some(Arc::clone(pool_name)),
+            strategy,
+            attempt,
+            protocol: protocol.protocol_name(),
         });
     }
 
@@ -65,7 +300,28 @@ pub async fn query_server(
 
             let tcp_start = Instant::now();
             let tcp_response = tcp_transport.send(query_bytes, remaining).await?;
-            let tcp_dns_response = ResponseParser::parse_bytes(tcp_response.bytes)?;
+            validate_response(
+                &tcp_protocol,
+                query_bytes,
+                &tcp_response.bytes,
+                domain,
+                case_randomized,
+            )?;
+            let tcp_bytes = match cookie_addr {
+                Some(server) => match UPSTREAM_COOKIES.accept(server, tcp_response.bytes)? {
+                    CookieReply::Answer(bytes) => bytes,
+                    CookieReply::Retry => {
+                        return Err(DomainError::IoError(format!(
+                            "{} rejected the DNS cookie",
+                            tcp_protocol
+                        )))
+                    }
+                },
+                None => tcp_response.bytes,
+            };
+            let tcp_bytes = check_tsig(&tcp_protocol, tcp_bytes, tsig)?;
+            let tcp_dns_response =
+                ResponseParser::parse_bytes(restore_case(tcp_bytes, case_randomized))?;
 
             let tcp_response_time_us = tcp_start.elapsed().as_micros() as u64;
             let tcp_server_arc = get_display(&tcp_protocol, server_displays);
@@ -78,6 +334,9 @@ pub async fn query_server(
                     success: !tcp_dns_response.addresses.is_empty()
                         || !tcp_dns_response.cname_chain.is_empty(),
                     pool_name: Some(Arc::clone(pool_name)),
+                    strategy,
+                    attempt,
+                    protocol: tcp_protocol.protocol_name(),
                 });
             }
 
@@ -91,6 +350,7 @@ pub async fn query_server(
                 server_addr,
                 latency_ms,
                 server_display: tcp_server_arc,
+                protocol: tcp_protocol.protocol_name(),
             });
         }
     }
@@ -105,5 +365,6 @@ pub async fn query_server(
         server_addr,
         latency_ms,
         server_display: server_arc,
+        protocol: protocol.protocol_name(),
     })
 }
@@ -2,8 +2,8 @@ use super::balanced::BalancedStrategy;
 use super::failover::FailoverStrategy;
 use super::parallel::ParallelStrategy;
 use crate::dns::events::QueryEventEmitter;
-use crate::dns::forwarding::DnsResponse;
-use ferrous_dns_domain::{DnsProtocol, DomainError, RecordType};
+use crate::dns::forwarding::{DnsResponse, TsigRequest};
+use ferrous_dns_domain::{DnsProtocol, DomainError, RecordType, UdpSocketMode};
 use std::net::SocketAddr;
 use std::sync::Arc;
 
@@ -14,6 +14,9 @@ pub struct UpstreamResult {
     pub latency_ms: u64,
     pub pool_name: Arc<str>,
     pub server_display: Arc<str>,
+    pub strategy: &'static str,
+    pub attempt: u32,
+    pub protocol: &'static str,
 }
 
 pub struct QueryContext<'a> {
@@ -24,7 +27,18 @@ pub struct QueryContext<'a> {
     pub query_bytes: Arc<[u8]>,
     pub emitter: &'a QueryEventEmitter,
     pub pool_name: &'a Arc<str>,
+    pub strategy: &'static str,
     pub server_displays: &'a Arc<std::collections::HashMap<Arc<DnsProtocol>, Arc<str>>>,
+    /// `query_bytes` carries a 0x20 case-randomized name that UDP responses
+    /// must echo exactly.
+    pub case_randomized: bool,
+    /// UDP and TCP queries carry a DNS cookie for the server they go to.
+    pub cookies: bool,
+    /// Set when `query_bytes` is TSIG-signed; responses must verify against it.
+    pub tsig: Option<Arc<TsigRequest>>,
+    /// Whether UDP queries go through the shared socket pool or a fresh
+    /// socket on a random source port each.
+    pub udp_socket: UdpSocketMode,
 }
 
 pub enum Strategy {
@@ -34,6 +48,14 @@ pub enum Strategy {
 }
 
 impl Strategy {
+    /// Index of the server the next query tries first.
+    pub fn peek_start(&self, servers: usize) -> usize {
+        match self {
+            Self::Balanced(s) => s.peek_start(servers),
+            Self::Parallel(_) | Self::Failover(_) => 0,
+        }
+    }
+
     pub async fn query_refs(&self, ctx: &QueryContext<'_>) -> Result<UpstreamResult, DomainError> {
         match self {
             Self::Parallel(s) => s.query_refs(ctx).await,
@@ -1,7 +1,8 @@
 use super::{HealthChecker, PoolGroupEntry, PoolManager, ServerStatus};
+use crate::dns::forwarding::RESPONSE_VALIDATION;
 use ferrous_dns_application::ports::{
-    AggregateStatus, IpFamily, ResolvedEndpointHealth, UpstreamGroupHealth, UpstreamHealthPort,
-    UpstreamStatus,
+    AggregateStatus, IpFamily, ResolvedEndpointHealth, ResponseValidationStats,
+    UpstreamGroupHealth, UpstreamHealthPort, UpstreamRoute, UpstreamStatus,
 };
 use ferrous_dns_domain::DnsProtocol;
 use std::net::SocketAddr;
@@ -97,6 +98,21 @@ impl UpstreamHealthPort for UpstreamHealthAdapter {
             })
             .collect()
     }
+
+    fn response_validation_stats(&self) -> ResponseValidationStats {
+        RESPONSE_VALIDATION.snapshot()
+    }
+
+    fn preview_route(&self, pool: Option<&str>) -> Option<UpstreamRoute> {
+        self.pool_manager
+            .preview_route(pool)
+            .map(|route| UpstreamRoute {
+                pool_name: route.pool_name.to_string(),
+                strategy: route.strategy,
+                servers: route.servers.iter().map(|s| s.to_string()).collect(),
+                skipped_pools: route.skipped_pools.iter().map(|p| p.to_string()).collect(),
+            })
+    }
 }
 
 /// Expands one `DnsProtocol` into one or more `ResolvedEndpointHealth` entries.
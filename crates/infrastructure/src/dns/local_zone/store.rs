@@ -0,0 +1,278 @@
+use crate::dns::forwarding::RecordTypeMapper;
+use arc_swap::ArcSwap;
+use async_trait::async_trait;
+use bytes::Bytes;
+use ferrous_dns_application::ports::{
+    DnsResolution, LocalRecordRepository, LocalZoneAnswer, LocalZonePort,
+};
+use ferrous_dns_domain::{
+    DomainError, LocalDnsRecord, LocalRecord, LocalRecordData, LocalZone, LocalZoneEntry,
+    RecordType,
+};
+use hickory_proto::op::{Message, MessageType, OpCode, Query, ResponseCode};
+use hickory_proto::rr::rdata::{CNAME, MX, SRV, TXT};
+use hickory_proto::rr::{Name, RData, Record};
+use hickory_proto::serialize::binary::{BinEncodable, BinEncoder};
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use tracing::{error, info, warn};
+
+/// Longest character-string in a TXT record (RFC 1035 §3.3).
+const TXT_CHUNK_LEN: usize = 255;
+
+struct LoadedZone {
+    zone: LocalZone,
+    /// Records owned by a tenant, answered only to that tenant's clients.
+    tenant_zones: HashMap<i64, LocalZone>,
+    view_records: Vec<LocalDnsRecord>,
+}
+
+/// Local zone backed by an `ArcSwap<LocalZone>`.
+///
+/// The zone is rebuilt from the repository and swapped atomically whenever
+/// local records change, so lookups on the DNS hot path never take a lock.
+/// With no records, `lookup` is a single `is_empty()` check.
+pub struct LocalZoneStore {
+    loaded: ArcSwap<LoadedZone>,
+    repo: Arc<dyn LocalRecordRepository>,
+    local_domain: Option<String>,
+    /// Records published by record sources, keyed by source name.
+    source_records: Mutex<BTreeMap<String, Vec<LocalRecord>>>,
+}
+
+impl LocalZoneStore {
+    /// Initialises the zone by loading all records from the repository.
+    pub async fn new(
+        repo: Arc<dyn LocalRecordRepository>,
+        local_domain: Option<String>,
+    ) -> Result<Arc<Self>, DomainError> {
+        let store = Arc::new(Self {
+            loaded: ArcSwap::from_pointee(LoadedZone {
+                zone: LocalZone::empty(),
+                tenant_zones: HashMap::new(),
+                view_records: Vec::new(),
+            }),
+            repo,
+            local_domain,
+            source_records: Mutex::new(BTreeMap::new()),
+        });
+
+        store.reload_inner().await?;
+        info!(
+            records = store.loaded.load().zone.len(),
+            "LocalZoneStore initialised"
+        );
+        Ok(store)
+    }
+
+    async fn reload_inner(&self) -> Result<(), DomainError> {
+        let (tenant_records, mut records): (Vec<LocalRecord>, Vec<LocalRecord>) = self
+            .repo
+            .get_all()
+            .await?
+            .into_iter()
+            .partition(|r| r.tenant_id.is_some());
+        records.extend(
+            self.source_records
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .values()
+                .flatten()
+                .cloned(),
+        );
+
+        let mut by_tenant: HashMap<i64, Vec<LocalRecord>> = HashMap::new();
+        for record in tenant_records {
+            if let Some(tenant_id) = record.tenant_id {
+                by_tenant.entry(tenant_id).or_default().push(record);
+            }
+        }
+        let tenant_zones = by_tenant
+            .into_iter()
+            .map(|(tenant_id, records)| (tenant_id, LocalZone::new(&records, &self.local_domain)))
+            .collect();
+
+        self.loaded.store(Arc::new(LoadedZone {
+            zone: LocalZone::new(&records, &self.local_domain),
+            tenant_zones,
+            view_records: records
+                .iter()
+                .filter(|r| r.view.is_some())
+                .map(LocalRecord::to_config_record)
+                .collect(),
+        }));
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl LocalZonePort for LocalZoneStore {
+    #[inline]
+    fn lookup(&self, domain: &str, record_type: RecordType) -> Option<LocalZoneAnswer> {
+        zone_answer(&self.loaded.load().zone, domain, record_type)
+    }
+
+    #[inline]
+    fn lookup_for_tenant(
+        &self,
+        tenant_id: Option<i64>,
+        domain: &str,
+        record_type: RecordType,
+    ) -> Option<LocalZoneAnswer> {
+        let loaded = self.loaded.load();
+        if let Some(zone) = tenant_id.and_then(|id| loaded.tenant_zones.get(&id)) {
+            if let Some(answer) = zone_answer(zone, domain, record_type) {
+                return Some(answer);
+            }
+        }
+        zone_answer(&loaded.zone, domain, record_type)
+    }
+
+    fn view_records(&self) -> Vec<LocalDnsRecord> {
+        self.loaded.load().view_records.clone()
+    }
+
+    fn set_source_records(&self, source: &str, records: Vec<LocalRecord>) {
+        let mut sources = self
+            .source_records
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        if records.is_empty() {
+            sources.remove(source);
+        } else {
+            sources.insert(source.to_string(), records);
+        }
+    }
+
+    async fn reload(&self) -> Result<(), DomainError> {
+        if let Err(e) = self.reload_inner().await {
+            error!(error = %e, "Failed to reload local zone");
+            return Err(e);
+        }
+        info!("Local zone reloaded");
+        Ok(())
+    }
+}
+
+/// Answer of one compiled zone for `domain`.
+#[inline]
+fn zone_answer(zone: &LocalZone, domain: &str, record_type: RecordType) -> Option<LocalZoneAnswer> {
+    if zone.is_empty() {
+        return None;
+    }
+    let entries = zone.lookup(domain)?;
+
+    if record_type != RecordType::CNAME {
+        if let Some(target) = entries.iter().find_map(|e| match &e.data {
+            LocalRecordData::Cname(target) => Some(target),
+            _ => None,
+        }) {
+            return Some(LocalZoneAnswer::Cname(Arc::clone(target)));
+        }
+    }
+
+    let matching: Vec<&LocalZoneEntry> = entries
+        .iter()
+        .filter(|e| e.data.record_type() == record_type)
+        .collect();
+    let min_ttl = matching.iter().map(|e| e.ttl).min()?;
+
+    let resolution = match record_type {
+        RecordType::A | RecordType::AAAA => {
+            let addresses = matching
+                .iter()
+                .filter_map(|e| match e.data {
+                    LocalRecordData::Address(ip) => Some(ip),
+                    _ => None,
+                })
+                .collect();
+            DnsResolution::new(addresses, false)
+        }
+        _ => DnsResolution {
+            upstream_wire_data: Some(build_wire_answer(domain, record_type, &matching)?),
+            ..DnsResolution::new(Vec::new(), false)
+        },
+    };
+
+    Some(LocalZoneAnswer::Records(DnsResolution {
+        local_dns: true,
+        min_ttl: Some(min_ttl),
+        ..resolution
+    }))
+}
+
+/// Encodes an authoritative response for the non-address records in
+/// `entries`. The message ID is left at zero for the server to patch.
+fn build_wire_answer(
+    domain: &str,
+    record_type: RecordType,
+    entries: &[&LocalZoneEntry],
+) -> Option<Bytes> {
+    let name = Name::from_str(domain)
+        .map_err(|e| warn!(domain = %domain, error = %e, "Local zone: invalid query name"))
+        .ok()?;
+
+    let mut message = Message::new(0, MessageType::Response, OpCode::Query);
+    message.set_response_code(ResponseCode::NoError);
+    message.set_authoritative(true);
+    message.set_recursion_available(true);
+    message.add_query(Query::query(
+        name.clone(),
+        RecordTypeMapper::to_hickory(&record_type),
+    ));
+    for entry in entries {
+        let Some(rdata) = to_rdata(&entry.data) else {
+            continue;
+        };
+        message.add_answer(Record::from_rdata(name.clone(), entry.ttl, rdata));
+    }
+
+    let mut buf = Vec::with_capacity(256);
+    let mut encoder = BinEncoder::new(&mut buf);
+    message
+        .emit(&mut encoder)
+        .map_err(|e| warn!(domain = %domain, error = %e, "Local zone: failed to encode answer"))
+        .ok()?;
+    Some(Bytes::from(buf))
+}
+
+fn to_rdata(data: &LocalRecordData) -> Option<RData> {
+    let target = |name: &str| {
+        Name::from_str(&format!("{}.", name))
+            .map_err(|e| warn!(target = %name, error = %e, "Local zone: invalid target name"))
+            .ok()
+    };
+    Some(match data {
+        LocalRecordData::Address(_) | LocalRecordData::EmbeddedAddress { .. } => return None,
+        LocalRecordData::Cname(name) => RData::CNAME(CNAME(target(name)?)),
+        LocalRecordData::Txt(text) => RData::TXT(TXT::new(txt_chunks(text))),
+        LocalRecordData::Mx {
+            preference,
+            exchange,
+        } => RData::MX(MX::new(*preference, target(exchange)?)),
+        LocalRecordData::Srv {
+            priority,
+            weight,
+            port,
+            target: srv_target,
+        } => RData::SRV(SRV::new(*priority, *weight, *port, target(srv_target)?)),
+    })
+}
+
+/// Splits `text` into character-strings of at most 255 bytes without
+/// breaking a UTF-8 sequence.
+fn txt_chunks(text: &str) -> Vec<String> {
+    let mut chunks = Vec::with_capacity(text.len() / TXT_CHUNK_LEN + 1);
+    let mut rest = text;
+    while rest.len() > TXT_CHUNK_LEN {
+        let mut split = TXT_CHUNK_LEN;
+        while !rest.is_char_boundary(split) {
+            split -= 1;
+        }
+        chunks.push(rest[..split].to_string());
+        rest = &rest[split..];
+    }
+    chunks.push(rest.to_string());
+    chunks
+}
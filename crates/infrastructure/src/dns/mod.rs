@@ -1,42 +1,83 @@
+pub mod aaaa_filter;
+pub mod access_control;
 pub mod block_filter;
 pub mod cache;
 pub mod cache_maintenance;
+pub mod cache_ttl_override;
+pub mod chaos_identity;
 pub mod dga_detection;
+pub mod dns_rewrite;
 pub mod dnssec;
+pub mod dynamic_update;
 pub mod ede;
+pub mod edns_client_id;
 pub mod events;
 pub mod fast_path;
+#[cfg(feature = "fault-injection")]
+pub mod fault_injection;
 pub mod forwarding;
+pub mod idn;
+pub mod listener;
 pub mod load_balancer;
+pub mod local_zone;
 pub mod nxdomain_hijack;
+#[cfg(feature = "lua-plugins")]
+pub mod plugins;
 pub mod prefetch;
 pub mod proxy_protocol;
 pub mod query_logger;
+pub mod query_policy;
+pub mod record_type_filter;
+pub mod refresh_budget;
 pub mod resolver;
 pub mod response_ip_filter;
 pub mod safe_search;
+pub mod secondary_zone;
 pub mod server;
+pub mod sinkhole;
+pub mod slow_query_log;
+pub mod split_horizon;
 pub mod transport;
 pub mod tunneling;
 pub mod wire_response;
 
+pub use aaaa_filter::AaaaFilterEnforcer;
+pub use access_control::{AccessControlList, AccessControlRegistry, AclVerdict};
 pub use block_filter::BlockFilterEngine;
 pub use cache::{
     CacheKey, CacheMetrics, CachedAddresses, CachedData, CachedRecord, DnsCache, DnsCacheAccess,
-    DnsCacheConfig, DnssecStatus, EvictionStrategy, NegativeQueryTracker,
+    DnsCacheConfig, DnssecStatus, EvictionStrategy, HotKey, NegativeQueryTracker,
 };
 pub use cache_maintenance::DnsCacheMaintenance;
+pub use cache_ttl_override::CacheTtlOverrideEnforcer;
+pub use chaos_identity::ChaosIdentity;
 pub use dga_detection::DgaDetector;
-pub use events::{QueryEvent, QueryEventEmitter};
+pub use dns_rewrite::DnsRewriteEnforcer;
+pub use dynamic_update::DynamicUpdateHandler;
+pub use edns_client_id::EdnsClientIdentifier;
+pub use events::{QueryEvent, QueryEventEmitter, QueryMetrics};
+#[cfg(feature = "fault-injection")]
+pub use fault_injection::{FaultInjectionControl, FaultInjector, FAULTS};
+pub use listener::ListenerPolicy;
 pub use load_balancer::{
     BalancedStrategy, FailoverStrategy, HealthChecker, ParallelStrategy, PoolManager, ServerHealth,
-    ServerStatus, UpstreamHealthAdapter,
+    ServerStatus, UpstreamAddressRefresher, UpstreamHealthAdapter,
 };
+pub use local_zone::LocalZoneStore;
 pub use nxdomain_hijack::NxdomainHijackDetector;
+#[cfg(feature = "lua-plugins")]
+pub use plugins::LuaPluginHost;
 pub use prefetch::PrefetchPredictor;
 pub use proxy_protocol::read_proxy_v2_client_ip;
 pub use query_logger::QueryEventLogger;
-pub use resolver::HickoryDnsResolver;
+pub use query_policy::QueryPolicyEnforcer;
+pub use record_type_filter::RecordTypeEnforcer;
+pub use refresh_budget::RefreshBudget;
+pub use resolver::{HickoryDnsResolver, InflightRegistry};
 pub use response_ip_filter::ResponseIpFilterDetector;
 pub use safe_search::SafeSearchEnforcer;
+pub use secondary_zone::SecondaryZoneStore;
+pub use sinkhole::{RecentBlocks, Sinkhole, SinkholeProtocol, SinkholeTelemetry};
+pub use slow_query_log::SlowQueryLog;
+pub use split_horizon::SplitHorizonStore;
 pub use tunneling::TunnelingDetector;
@@ -0,0 +1,389 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{PluginDecision, PluginHookPort, PluginQuery, PluginVerdict};
+use ferrous_dns_domain::{DomainError, PluginsConfig};
+use mlua::{Function, HookTriggers, Lua, LuaOptions, StdLib, Table, Value};
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+
+const PRE_FILTER: &str = "pre_filter";
+const POST_RESOLVE: &str = "post_resolve";
+
+/// Required script global listing the domains (and their subdomains) the
+/// hooks run for. Queries for any other domain keep the cache fast paths
+/// and never wait on the script's interpreter.
+const DOMAINS: &str = "domains";
+
+/// VM instructions executed between two deadline checks.
+const DEADLINE_CHECK_INTERVAL: u32 = 1_000;
+
+/// Base library functions that reach the file system, load bytecode, print
+/// to stdout or could swallow the deadline error.
+const REMOVED_GLOBALS: [&str; 7] = [
+    "dofile",
+    "loadfile",
+    "load",
+    "print",
+    "pcall",
+    "xpcall",
+    "collectgarbage",
+];
+
+/// Largest string `string.rep` may build.
+const MAX_REP_BYTES: i64 = 64 * 1024;
+
+/// Longest subject the pattern functions accept; query names are at most
+/// 253 bytes.
+const MAX_PATTERN_SUBJECT: i64 = 4 * 1024;
+
+/// The instruction hook cannot interrupt a single C call, so the string
+/// functions whose one call can run long get their input bounded first.
+const STRING_GUARDS: &str = r#"
+local max_rep, max_subject = ...
+local rep, tointeger, tostring, type, error = string.rep, math.tointeger, tostring, type, error
+
+string.rep = function(s, n, sep)
+    local count = tointeger(n)
+    if count == nil then
+        error("bad argument #2 to 'rep' (number has no integer representation)", 2)
+    end
+    local size = #tostring(s) * (count + 0.0)
+    if sep ~= nil and count > 1 then
+        size = size + #tostring(sep) * (count - 1.0)
+    end
+    if size > max_rep then
+        error("string.rep result exceeds the plugin limit", 2)
+    end
+    return rep(s, count, sep)
+end
+
+for _, name in ipairs({ "find", "match", "gmatch", "gsub" }) do
+    local f = string[name]
+    string[name] = function(s, ...)
+        if type(s) == "string" and #s > max_subject then
+            error("string." .. name .. " subject exceeds the plugin limit", 2)
+        end
+        return f(s, ...)
+    end
+end
+"#;
+
+/// Time by which the running hook must return, checked from the VM hook.
+struct Deadline(Instant);
+
+struct LuaScript {
+    name: Arc<str>,
+    /// Locked asynchronously so queries wait for a busy script without
+    /// holding a runtime worker; the call itself runs on the blocking pool.
+    lua: Arc<Mutex<Lua>>,
+    domains: Vec<Box<str>>,
+    has_pre_filter: bool,
+    has_post_resolve: bool,
+}
+
+/// Runs Lua plugin scripts at the pre-filter and post-resolve hook points.
+///
+/// Each script gets its own interpreter with only the `table`, `string`,
+/// `math` and `utf8` libraries and a memory cap. Every call is bounded by
+/// the configured timeout: the wait for a busy script and the call share
+/// one deadline, and a query whose script overruns it continues without
+/// the script's verdict. State kept in script globals persists across
+/// queries, so a script can keep counters such as per-domain query rates.
+pub struct LuaPluginHost {
+    scripts: Vec<LuaScript>,
+    timeout: Duration,
+}
+
+impl LuaPluginHost {
+    /// Loads the scripts listed in the configuration.
+    pub fn load(config: &PluginsConfig) -> Result<Self, DomainError> {
+        let sources = config
+            .scripts
+            .iter()
+            .map(|path| {
+                std::fs::read_to_string(path)
+                    .map(|source| (path.as_str(), source))
+                    .map_err(|e| DomainError::InvalidPlugin(format!("{path}: {e}")))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let host = Self::from_sources(
+            sources
+                .iter()
+                .map(|(name, source)| (*name, source.as_str())),
+            config,
+        )?;
+        info!(scripts = host.scripts.len(), "Plugin scripts loaded");
+        Ok(host)
+    }
+
+    /// Compiles `(name, source)` pairs with the limits of `config`.
+    pub fn from_sources<'a>(
+        sources: impl IntoIterator<Item = (&'a str, &'a str)>,
+        config: &PluginsConfig,
+    ) -> Result<Self, DomainError> {
+        let timeout = Duration::from_millis(config.timeout_ms.max(1));
+        let memory_limit = config.memory_limit_kb.saturating_mul(1024);
+        let scripts = sources
+            .into_iter()
+            .map(|(name, source)| LuaScript::compile(name, source, memory_limit, timeout))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { scripts, timeout })
+    }
+
+    async fn run(
+        &self,
+        hook: &'static str,
+        query: &PluginQuery,
+        answers: Option<&[IpAddr]>,
+    ) -> Option<PluginDecision> {
+        let answers: Option<Arc<[IpAddr]>> = answers.map(Arc::from);
+        for script in &self.scripts {
+            if !script.defines(hook) || !script.covers(&query.domain) {
+                continue;
+            }
+            match script
+                .call(hook, query, answers.clone(), self.timeout)
+                .await
+            {
+                Ok(None) => {}
+                Ok(Some(verdict)) => {
+                    return Some(PluginDecision {
+                        plugin: Arc::clone(&script.name),
+                        verdict,
+                    })
+                }
+                Err(e) => {
+                    warn!(plugin = %script.name, hook, error = %e, "Plugin hook failed");
+                }
+            }
+        }
+        None
+    }
+}
+
+#[async_trait]
+impl PluginHookPort for LuaPluginHost {
+    fn intercepts(&self, domain: &str) -> bool {
+        self.scripts.iter().any(|script| script.covers(domain))
+    }
+
+    async fn pre_filter(&self, query: &PluginQuery) -> Option<PluginDecision> {
+        self.run(PRE_FILTER, query, None).await
+    }
+
+    async fn post_resolve(
+        &self,
+        query: &PluginQuery,
+        addresses: &[IpAddr],
+    ) -> Option<PluginDecision> {
+        self.run(POST_RESOLVE, query, Some(addresses)).await
+    }
+}
+
+impl LuaScript {
+    fn compile(
+        name: &str,
+        source: &str,
+        memory_limit: usize,
+        timeout: Duration,
+    ) -> Result<Self, DomainError> {
+        let invalid = |e: mlua::Error| DomainError::InvalidPlugin(format!("{name}: {e}"));
+
+        let lua = Lua::new_with(
+            StdLib::TABLE | StdLib::STRING | StdLib::MATH | StdLib::UTF8,
+            LuaOptions::default(),
+        )
+        .map_err(invalid)?;
+        lua.set_memory_limit(memory_limit).map_err(invalid)?;
+        lua.set_hook(
+            HookTriggers::new().every_nth_instruction(DEADLINE_CHECK_INTERVAL),
+            |lua, _debug| match lua.app_data_ref::<Deadline>() {
+                Some(deadline) if Instant::now() > deadline.0 => {
+                    Err(mlua::Error::runtime("plugin time limit exceeded"))
+                }
+                _ => Ok(()),
+            },
+        );
+        Self::install_globals(&lua, name).map_err(invalid)?;
+
+        // The top-level chunk runs under the same limit as a hook call.
+        lua.set_app_data(Deadline(Instant::now() + timeout));
+        lua.load(source).set_name(name).exec().map_err(invalid)?;
+
+        let (has_pre_filter, has_post_resolve, domains) = {
+            let globals = lua.globals();
+            let defines = |hook: &str| {
+                globals
+                    .get::<_, Option<Function>>(hook)
+                    .map(|f| f.is_some())
+            };
+            (
+                defines(PRE_FILTER).map_err(invalid)?,
+                defines(POST_RESOLVE).map_err(invalid)?,
+                globals
+                    .get::<_, Option<Vec<String>>>(DOMAINS)
+                    .map_err(invalid)?,
+            )
+        };
+        if !has_pre_filter && !has_post_resolve {
+            return Err(DomainError::InvalidPlugin(format!(
+                "{name}: defines neither {PRE_FILTER} nor {POST_RESOLVE}"
+            )));
+        }
+        let domains: Vec<Box<str>> = domains
+            .unwrap_or_default()
+            .iter()
+            .map(|d| {
+                d.trim_end_matches('.')
+                    .to_ascii_lowercase()
+                    .into_boxed_str()
+            })
+            .collect();
+        if domains.is_empty() || domains.iter().any(|d| d.is_empty()) {
+            return Err(DomainError::InvalidPlugin(format!(
+                "{name}: must set {DOMAINS} to a list of the domains its hooks run for"
+            )));
+        }
+
+        Ok(Self {
+            name: Arc::from(name),
+            lua: Arc::new(Mutex::new(lua)),
+            domains,
+            has_pre_filter,
+            has_post_resolve,
+        })
+    }
+
+    /// Strips file, bytecode and error-trapping access from the base
+    /// library, bounds the long-running string functions and adds the
+    /// `now()` and `log(message)` helpers.
+    fn install_globals(lua: &Lua, name: &str) -> mlua::Result<()> {
+        let globals = lua.globals();
+        for removed in REMOVED_GLOBALS {
+            globals.set(removed, Value::Nil)?;
+        }
+        lua.load(STRING_GUARDS)
+            .set_name("string_guards")
+            .call::<_, ()>((MAX_REP_BYTES, MAX_PATTERN_SUBJECT))?;
+        globals.set(
+            "now",
+            lua.create_function(|_, ()| {
+                Ok(SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|d| d.as_secs_f64())
+                    .unwrap_or(0.0))
+            })?,
+        )?;
+        let plugin: Arc<str> = Arc::from(name);
+        globals.set(
+            "log",
+            lua.create_function(move |_, message: String| {
+                info!(plugin = %plugin, "{}", message);
+                Ok(())
+            })?,
+        )?;
+        Ok(())
+    }
+
+    fn defines(&self, hook: &str) -> bool {
+        match hook {
+            PRE_FILTER => self.has_pre_filter,
+            _ => self.has_post_resolve,
+        }
+    }
+
+    fn covers(&self, domain: &str) -> bool {
+        self.domains.iter().any(|suffix| {
+            domain == suffix.as_ref()
+                || domain
+                    .strip_suffix(suffix.as_ref())
+                    .is_some_and(|rest| rest.ends_with('.'))
+        })
+    }
+
+    /// Waits for the interpreter and runs `hook` on the blocking pool, both
+    /// within one deadline. A call that overruns it keeps the interpreter
+    /// until it returns; later calls then time out waiting for it.
+    async fn call(
+        &self,
+        hook: &'static str,
+        query: &PluginQuery,
+        answers: Option<Arc<[IpAddr]>>,
+        timeout: Duration,
+    ) -> mlua::Result<Option<PluginVerdict>> {
+        let deadline = Instant::now() + timeout;
+        let lua = tokio::time::timeout_at(deadline.into(), Arc::clone(&self.lua).lock_owned())
+            .await
+            .map_err(|_| mlua::Error::runtime("plugin busy past its time limit"))?;
+
+        let query = query.clone();
+        let call = tokio::task::spawn_blocking(move || {
+            Self::call_blocking(&lua, hook, &query, answers.as_deref(), deadline)
+        });
+        match tokio::time::timeout_at(deadline.into(), call).await {
+            Ok(Ok(result)) => result,
+            Ok(Err(e)) => Err(mlua::Error::runtime(format!("plugin task failed: {e}"))),
+            Err(_) => Err(mlua::Error::runtime("plugin time limit exceeded")),
+        }
+    }
+
+    fn call_blocking(
+        lua: &Lua,
+        hook: &str,
+        query: &PluginQuery,
+        answers: Option<&[IpAddr]>,
+        deadline: Instant,
+    ) -> mlua::Result<Option<PluginVerdict>> {
+        lua.set_app_data(Deadline(deadline));
+
+        let function: Function = lua.globals().get(hook)?;
+        let table = lua.create_table()?;
+        table.set("domain", query.domain.as_ref())?;
+        table.set("type", query.record_type.as_str())?;
+        table.set("client", query.client_ip.to_string())?;
+        table.set("group", query.group_id)?;
+
+        let value: Value = match answers {
+            Some(addresses) => {
+                let answers =
+                    lua.create_sequence_from(addresses.iter().map(|ip| ip.to_string()))?;
+                function.call((table, answers))?
+            }
+            None => function.call(table)?,
+        };
+        Self::verdict(value)
+    }
+
+    /// `nil` or `"continue"` lets the query through, `"block"` and
+    /// `"nxdomain"` decide it, and a list of addresses answers it.
+    fn verdict(value: Value) -> mlua::Result<Option<PluginVerdict>> {
+        match value {
+            Value::Nil => Ok(None),
+            Value::String(s) => match s.to_str()? {
+                "continue" => Ok(None),
+                "block" => Ok(Some(PluginVerdict::Block)),
+                "nxdomain" => Ok(Some(PluginVerdict::NxDomain)),
+                other => Err(mlua::Error::runtime(format!("unknown verdict '{other}'"))),
+            },
+            Value::Table(addresses) => Self::answer(addresses).map(Some),
+            other => Err(mlua::Error::runtime(format!(
+                "unexpected {} verdict",
+                other.type_name()
+            ))),
+        }
+    }
+
+    fn answer(addresses: Table) -> mlua::Result<PluginVerdict> {
+        addresses
+            .sequence_values::<String>()
+            .map(|ip| {
+                let ip = ip?;
+                ip.parse::<IpAddr>()
+                    .map_err(|_| mlua::Error::runtime(format!("invalid address '{ip}'")))
+            })
+            .collect::<mlua::Result<Vec<_>>>()
+            .map(PluginVerdict::Answer)
+    }
+}
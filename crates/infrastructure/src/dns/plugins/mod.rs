@@ -0,0 +1,3 @@
+mod lua;
+
+pub use lua::LuaPluginHost;
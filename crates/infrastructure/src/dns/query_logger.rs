@@ -72,6 +72,7 @@ impl QueryEventLogger {
                 record_type: event.record_type,
                 client_ip: IpAddr::from([127, 0, 0, 1]),
                 client_hostname: None,
+                client_mac: None,
                 blocked: false,
                 response_time_us: Some(event.response_time_us),
                 cache_hit: false,
@@ -79,11 +80,16 @@ impl QueryEventLogger {
                 dnssec_status: None,
                 upstream_server: Some(Arc::clone(&event.upstream_server)),
                 upstream_pool: event.pool_name.clone(),
+                upstream_strategy: Some(event.strategy),
+                upstream_attempt: Some(event.attempt),
+                upstream_protocol: Some(event.protocol),
                 response_status: Some(if event.success { "NOERROR" } else { "NXDOMAIN" }),
                 timestamp: None,
                 query_source: QuerySource::Internal,
                 group_id: None,
+                group_name: None,
                 block_source: None,
+                plugin: None,
             };
 
             match repo.log_query(&query_log).await {
@@ -0,0 +1,74 @@
+use arc_swap::ArcSwap;
+use async_trait::async_trait;
+use chrono::{Datelike, Timelike};
+use ferrous_dns_application::ports::{QueryPolicyEnginePort, QueryPolicyRepository};
+use ferrous_dns_domain::{DomainError, PolicyMatch, QueryPolicyMatcher, RecordType};
+use std::net::IpAddr;
+use std::sync::Arc;
+use tracing::{error, info};
+
+/// Query policy engine backed by an `ArcSwap<QueryPolicyMatcher>`.
+///
+/// The matcher is rebuilt and swapped atomically whenever policies change,
+/// so evaluation on the DNS hot path never takes a lock. With no enabled
+/// policies, `evaluate` is a single `is_empty()` check.
+pub struct QueryPolicyEnforcer {
+    matcher: ArcSwap<QueryPolicyMatcher>,
+    repo: Arc<dyn QueryPolicyRepository>,
+}
+
+impl QueryPolicyEnforcer {
+    /// Initialises the engine by loading all policies from the repository.
+    pub async fn new(repo: Arc<dyn QueryPolicyRepository>) -> Result<Arc<Self>, DomainError> {
+        let engine = Arc::new(Self {
+            matcher: ArcSwap::from_pointee(QueryPolicyMatcher::empty()),
+            repo,
+        });
+
+        engine.reload_inner().await?;
+        info!("QueryPolicyEnforcer initialised");
+        Ok(engine)
+    }
+
+    async fn reload_inner(&self) -> Result<(), DomainError> {
+        let policies = self.repo.get_all().await?;
+        let matcher = QueryPolicyMatcher::new(policies).map_err(DomainError::InvalidQueryPolicy)?;
+        self.matcher.store(Arc::new(matcher));
+        Ok(())
+    }
+
+    /// Current `(weekday_bit, minute_of_day)` in server local time.
+    fn local_clock() -> (u8, u16) {
+        let now = chrono::Local::now();
+        let weekday_bit = 1u8 << now.weekday().num_days_from_monday();
+        (weekday_bit, (now.hour() * 60 + now.minute()) as u16)
+    }
+}
+
+#[async_trait]
+impl QueryPolicyEnginePort for QueryPolicyEnforcer {
+    #[inline]
+    fn evaluate(
+        &self,
+        client_ip: IpAddr,
+        group_id: i64,
+        domain: &str,
+        record_type: RecordType,
+    ) -> Option<PolicyMatch> {
+        let matcher = self.matcher.load();
+        if matcher.is_empty() {
+            return None;
+        }
+        let clock = matcher.needs_clock().then(Self::local_clock);
+        matcher.evaluate(client_ip, group_id, domain, record_type, clock)
+    }
+
+    async fn reload(&self) -> Result<(), DomainError> {
+        if let Err(e) = self.reload_inner().await {
+            error!(error = %e, "Failed to reload query policies");
+            return Err(e);
+        }
+        info!("Query policies reloaded");
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+use arc_swap::ArcSwap;
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{RecordTypeFilterPort, RecordTypePolicyRepository};
+use ferrous_dns_domain::{DomainError, RecordType, RecordTypePolicy};
+use rustc_hash::FxHashMap;
+use std::sync::Arc;
+use tracing::{error, info};
+
+/// Per-group record type filter backed by an `ArcSwap` index.
+///
+/// The index is swapped atomically when a policy changes, so the DNS hot
+/// path never takes a lock. With no policies, `is_blocked` is a single
+/// `is_empty()` check.
+pub struct RecordTypeEnforcer {
+    index: ArcSwap<FxHashMap<i64, RecordTypePolicy>>,
+    repo: Arc<dyn RecordTypePolicyRepository>,
+}
+
+impl RecordTypeEnforcer {
+    /// Initialises the filter by loading all policies from the repository.
+    pub async fn new(repo: Arc<dyn RecordTypePolicyRepository>) -> Result<Arc<Self>, DomainError> {
+        let engine = Arc::new(Self {
+            index: ArcSwap::from_pointee(FxHashMap::default()),
+            repo,
+        });
+
+        engine.reload_inner().await?;
+        info!("RecordTypeEnforcer initialised");
+        Ok(engine)
+    }
+
+    async fn reload_inner(&self) -> Result<(), DomainError> {
+        let index: FxHashMap<i64, RecordTypePolicy> = self
+            .repo
+            .get_all()
+            .await?
+            .into_iter()
+            .map(|p| (p.group_id, p))
+            .collect();
+        self.index.store(Arc::new(index));
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl RecordTypeFilterPort for RecordTypeEnforcer {
+    #[inline]
+    fn is_blocked(&self, group_id: i64, record_type: RecordType) -> bool {
+        let index = self.index.load();
+        if index.is_empty() {
+            return false;
+        }
+        index
+            .get(&group_id)
+            .is_some_and(|policy| policy.blocks(record_type))
+    }
+
+    async fn reload(&self) -> Result<(), DomainError> {
+        if let Err(e) = self.reload_inner().await {
+            error!(error = %e, "Failed to reload record type policies");
+            return Err(e);
+        }
+        info!("Record type policies reloaded");
+        Ok(())
+    }
+}
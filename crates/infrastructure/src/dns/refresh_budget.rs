@@ -0,0 +1,108 @@
+use super::load_balancer::HealthChecker;
+use ferrous_dns_application::ports::RefreshBudgetSnapshot;
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Minimum time between two recomputations of the allowed refresh rate.
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+struct BudgetState {
+    tokens: f64,
+    limit_qps: f64,
+    client_qps: f64,
+    health_factor: f64,
+    last_sample: Instant,
+    last_client_queries: u64,
+}
+
+/// Token bucket capping background cache refreshes.
+///
+/// The refill rate follows client traffic: `fraction` of the client query
+/// rate, never below `min_qps`, scaled by the upstream capacity factor the
+/// health checker derives from its latency and failure EWMAs. The bucket
+/// holds at most `burst_secs` worth of refreshes, so a periodic refresh
+/// cycle can spend what accumulated since the previous one.
+pub struct RefreshBudget {
+    fraction: f64,
+    min_qps: f64,
+    burst_secs: f64,
+    health_checker: Option<Arc<HealthChecker>>,
+    state: Mutex<BudgetState>,
+    granted: AtomicU64,
+    deferred: AtomicU64,
+}
+
+impl RefreshBudget {
+    pub fn new(fraction: f64, min_qps: f64, burst_secs: u64) -> Self {
+        let min_qps = min_qps.max(0.0);
+        let burst_secs = burst_secs.max(1) as f64;
+        Self {
+            fraction: fraction.max(0.0),
+            min_qps,
+            burst_secs,
+            health_checker: None,
+            state: Mutex::new(BudgetState {
+                tokens: min_qps * burst_secs,
+                limit_qps: min_qps,
+                client_qps: 0.0,
+                health_factor: 1.0,
+                last_sample: Instant::now(),
+                last_client_queries: 0,
+            }),
+            granted: AtomicU64::new(0),
+            deferred: AtomicU64::new(0),
+        }
+    }
+
+    /// Backs the budget off as upstream latency and failure rates rise.
+    pub fn with_health_checker(mut self, health_checker: Arc<HealthChecker>) -> Self {
+        self.health_checker = Some(health_checker);
+        self
+    }
+
+    /// Grants up to `wanted` refreshes. `client_queries` is the running total
+    /// of client lookups, used to measure the client query rate.
+    pub fn acquire(&self, wanted: usize, client_queries: u64) -> usize {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+
+        let now = Instant::now();
+        let elapsed = now.duration_since(state.last_sample);
+        if elapsed >= SAMPLE_INTERVAL {
+            let secs = elapsed.as_secs_f64();
+            let new_queries = client_queries.saturating_sub(state.last_client_queries);
+            state.client_qps = new_queries as f64 / secs;
+            state.health_factor = self
+                .health_checker
+                .as_ref()
+                .map_or(1.0, |checker| checker.capacity_factor());
+            state.limit_qps =
+                (state.client_qps * self.fraction).max(self.min_qps) * state.health_factor;
+            state.tokens =
+                (state.tokens + state.limit_qps * secs).min(state.limit_qps * self.burst_secs);
+            state.last_sample = now;
+            state.last_client_queries = client_queries;
+        }
+
+        let granted = (state.tokens.max(0.0) as usize).min(wanted);
+        state.tokens -= granted as f64;
+        drop(state);
+
+        self.granted
+            .fetch_add(granted as u64, AtomicOrdering::Relaxed);
+        self.deferred
+            .fetch_add((wanted - granted) as u64, AtomicOrdering::Relaxed);
+        granted
+    }
+
+    pub fn snapshot(&self) -> RefreshBudgetSnapshot {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        RefreshBudgetSnapshot {
+            limit_qps: state.limit_qps,
+            client_qps: state.client_qps,
+            health_factor: state.health_factor,
+            granted: self.granted.load(AtomicOrdering::Relaxed),
+            deferred: self.deferred.load(AtomicOrdering::Relaxed),
+        }
+    }
+}
@@ -1,13 +1,17 @@
 use super::super::cache::{DnsCache, NegativeQueryTracker};
+use super::super::dnssec::DnssecCache;
 use super::super::load_balancer::PoolManager;
 use super::super::prefetch::PrefetchPredictor;
 use super::cache_layer::CachedResolver;
+use super::conditional_forward::{ConditionalForwardResolver, ConditionalForwards};
 use super::config::ResolverConfig;
 use super::core::CoreResolver;
 use super::dnssec_layer::DnssecResolver;
 use super::filtered_resolver::FilteredResolver;
 use super::filters::QueryFilters;
-use super::local_ptr::{LocalPtrResolver, PtrMap};
+use super::inflight::InflightRegistry;
+use super::local_ptr::{ClientPtrZone, LocalPtrResolver, PtrMap};
+use super::rebinding_layer::{RebindingPolicy, RebindingResolver};
 use ferrous_dns_application::ports::DnsResolver;
 use std::sync::Arc;
 use tracing::info;
@@ -17,11 +21,16 @@ pub struct ResolverBuilder {
     dnssec_pool_manager: Option<Arc<PoolManager>>,
     config: ResolverConfig,
     cache: Option<Arc<DnsCache>>,
+    dnssec_cache: Option<Arc<DnssecCache>>,
     local_domain: Option<String>,
     local_dns_server: Option<String>,
     prefetch_predictor: Option<Arc<PrefetchPredictor>>,
     filters: Option<QueryFilters>,
     local_ptr_map: Option<Arc<PtrMap>>,
+    client_ptr_zone: Option<Arc<ClientPtrZone>>,
+    rebinding_policy: Option<RebindingPolicy>,
+    inflight_registry: Option<Arc<InflightRegistry>>,
+    conditional_forwards: Option<Arc<ConditionalForwards>>,
 }
 
 impl ResolverBuilder {
@@ -31,11 +40,16 @@ impl ResolverBuilder {
             dnssec_pool_manager: None,
             config: ResolverConfig::default(),
             cache: None,
+            dnssec_cache: None,
             local_domain: None,
             local_dns_server: None,
             prefetch_predictor: None,
             filters: None,
             local_ptr_map: None,
+            client_ptr_zone: None,
+            rebinding_policy: None,
+            inflight_registry: None,
+            conditional_forwards: None,
         }
     }
 
@@ -54,6 +68,13 @@ impl ResolverBuilder {
         self
     }
 
+    /// Records validation results in `cache` instead of the DNS cache's
+    /// own, e.g. to read them back while the DNS cache is disabled.
+    pub fn with_dnssec_cache(mut self, cache: Arc<DnssecCache>) -> Self {
+        self.dnssec_cache = Some(cache);
+        self
+    }
+
     pub fn with_dnssec(mut self) -> Self {
         self.config.dnssec_enabled = true;
         self
@@ -86,12 +107,42 @@ impl ResolverBuilder {
         self
     }
 
+    /// Attaches the client hostname reverse zone; like the PTR map, it enables
+    /// the outermost `LocalPtrResolver` layer.
+    pub fn with_client_ptr_zone(mut self, zone: Arc<ClientPtrZone>) -> Self {
+        self.client_ptr_zone = Some(zone);
+        self
+    }
+
+    /// Enables DNS rebinding protection below the cache, so answers rebinding
+    /// a public name to an internal address are never cached.
+    pub fn with_rebinding_protection(mut self, policy: RebindingPolicy) -> Self {
+        self.rebinding_policy = Some(policy);
+        self
+    }
+
+    /// Uses `registry` for query coalescing in the cache layer instead of a
+    /// private map.
+    pub fn with_inflight_registry(mut self, registry: Arc<InflightRegistry>) -> Self {
+        self.inflight_registry = Some(registry);
+        self
+    }
+
+    /// Forwards the rules' zones to their own servers below the cache, and
+    /// lets their private PTR queries past the filters.
+    pub fn with_conditional_forwards(mut self, forwards: Arc<ConditionalForwards>) -> Self {
+        self.conditional_forwards = Some(forwards);
+        self
+    }
+
     pub fn build(self) -> Arc<dyn DnsResolver> {
         info!(
             dnssec = self.config.dnssec_enabled,
             cache = self.cache.is_some(),
             filters = self.filters.is_some(),
-            local_ptr = self.local_ptr_map.is_some(),
+            rebinding_protection = self.rebinding_policy.is_some(),
+            conditional_forwards = self.conditional_forwards.is_some(),
+            local_ptr = self.local_ptr_map.is_some() || self.client_ptr_zone.is_some(),
             "Building DNS resolver"
         );
 
@@ -110,10 +161,29 @@ impl ResolverBuilder {
                 .dnssec_pool_manager
                 .clone()
                 .unwrap_or_else(|| self.pool_manager.clone());
-            resolver = Arc::new(DnssecResolver::new(
+            let dnssec_cache = self.dnssec_cache.clone().unwrap_or_else(|| {
+                self.cache.as_ref().map_or_else(
+                    || Arc::new(DnssecCache::new()),
+                    |cache| cache.dnssec_cache(),
+                )
+            });
+            resolver = Arc::new(DnssecResolver::with_cache(
                 resolver,
                 dnssec_pm,
                 self.config.query_timeout_ms,
+                dnssec_cache,
+            ));
+        }
+
+        if let Some(policy) = self.rebinding_policy {
+            resolver = Arc::new(RebindingResolver::new(resolver, policy));
+        }
+
+        if let Some(forwards) = &self.conditional_forwards {
+            resolver = Arc::new(ConditionalForwardResolver::new(
+                resolver,
+                Arc::clone(forwards),
+                self.config.query_timeout_ms,
             ));
         }
 
@@ -132,15 +202,23 @@ impl ResolverBuilder {
                 cached = cached.with_prefetch(predictor);
             }
 
+            if let Some(registry) = self.inflight_registry {
+                cached = cached.with_inflight_registry(registry);
+            }
+
             resolver = Arc::new(cached);
         }
 
-        if let Some(filters) = self.filters {
+        if let Some(mut filters) = self.filters {
+            filters.conditional_forwards = self.conditional_forwards;
             resolver = Arc::new(FilteredResolver::new(resolver, filters));
         }
 
-        if let Some(map) = self.local_ptr_map {
-            resolver = Arc::new(LocalPtrResolver::new(resolver, map));
+        if self.local_ptr_map.is_some() || self.client_ptr_zone.is_some() {
+            let map = self.local_ptr_map.unwrap_or_default();
+            resolver = Arc::new(
+                LocalPtrResolver::new(resolver, map).with_client_zone(self.client_ptr_zone),
+            );
         }
 
         info!("DNS resolver built successfully");
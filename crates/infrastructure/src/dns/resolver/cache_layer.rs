@@ -4,32 +4,24 @@ use super::super::cache::{
     CachedAddresses, CachedData, DnsCacheAccess, DnssecStatus, NegativeQueryTracker,
 };
 use super::super::prefetch::PrefetchPredictor;
+use super::inflight::{InflightReceiver, InflightRegistry, InflightResult};
 use async_trait::async_trait;
-use bytes::Bytes;
-use dashmap::DashMap;
-use ferrous_dns_application::ports::{DnsResolution, DnsResolver, EMPTY_CNAME_CHAIN};
+use ferrous_dns_application::ports::{
+    DnsResolution, DnsResolver, EMPTY_CNAME_CHAIN, QUERY_SPAN_TARGET,
+};
 use std::cell::Cell;
 use std::sync::LazyLock;
 
 static EMPTY_ADDRESSES: LazyLock<Arc<Vec<IpAddr>>> = LazyLock::new(|| Arc::new(vec![]));
+use ferrous_dns_application::use_cases::dns::phase_timings::{self, QueryPhase};
+use ferrous_dns_application::use_cases::dns::query_budget;
 use ferrous_dns_domain::{DnsQuery, DomainError, RecordType};
-use rustc_hash::FxBuildHasher;
 use std::net::IpAddr;
 use std::sync::Arc;
-use tokio::sync::watch;
-
-struct InflightResult {
-    addresses: Arc<Vec<IpAddr>>,
-    cname_chain: Arc<[Arc<str>]>,
-    dnssec_status: Option<&'static str>,
-    min_ttl: Option<u32>,
-    upstream_wire_data: Option<Bytes>,
-}
-
-type InflightSender = Arc<watch::Sender<Option<Arc<InflightResult>>>>;
+use tracing::Instrument;
 
 struct InflightLeaderGuard {
-    inflight: Arc<DashMap<CacheKey, InflightSender, FxBuildHasher>>,
+    inflight: Arc<InflightRegistry>,
     key: CacheKey,
     defused: Cell<bool>,
 }
@@ -43,8 +35,8 @@ impl InflightLeaderGuard {
 impl Drop for InflightLeaderGuard {
     fn drop(&mut self) {
         if !self.defused.get() {
-            if let Some((_, tx)) = self.inflight.remove(&self.key) {
-                let _ = tx.send(None);
+            if let Some(entry) = self.inflight.remove(&self.key) {
+                let _ = entry.tx.send(None);
             }
         }
     }
@@ -56,7 +48,7 @@ pub struct CachedResolver {
     cache_ttl: u32,
     negative_ttl_tracker: Arc<NegativeQueryTracker>,
     prefetch_predictor: Option<Arc<PrefetchPredictor>>,
-    inflight: Arc<DashMap<CacheKey, InflightSender, FxBuildHasher>>,
+    inflight: Arc<InflightRegistry>,
 }
 
 impl CachedResolver {
@@ -75,22 +67,34 @@ impl CachedResolver {
             prefetch_predictor: None,
             // In-flight entries are transient — use caller-configured shard count
             // (default = cache_inflight_shards from TOML, typically cpus*2 next_power_of_two).
-            inflight: Arc::new(DashMap::with_capacity_and_hasher_and_shard_amount(
-                0,
-                FxBuildHasher,
-                inflight_shards,
-            )),
+            inflight: Arc::new(InflightRegistry::new(inflight_shards)),
         }
     }
 
+    /// Shares `registry` as the coalescing map, so its in-flight resolutions
+    /// can be inspected from outside the resolver chain.
+    pub fn with_inflight_registry(mut self, registry: Arc<InflightRegistry>) -> Self {
+        self.inflight = registry;
+        self
+    }
+
+    pub fn inflight_registry(&self) -> Arc<InflightRegistry> {
+        Arc::clone(&self.inflight)
+    }
+
     pub fn with_prefetch(mut self, predictor: Arc<PrefetchPredictor>) -> Self {
         self.prefetch_predictor = Some(predictor);
         self
     }
 
-    fn check_cache_str(&self, domain: &str, record_type: RecordType) -> Option<DnsResolution> {
+    fn check_cache_str(
+        &self,
+        domain: &str,
+        record_type: RecordType,
+        partition: u32,
+    ) -> Option<DnsResolution> {
         self.cache
-            .get(domain, &record_type)
+            .get_partitioned(domain, &record_type, partition)
             .map(|(data, dnssec_status, remaining_ttl)| {
                 let dnssec_str = dnssec_status.map(|s| s.as_str());
                 match data {
@@ -102,6 +106,9 @@ impl CachedResolver {
                         cname_chain: Arc::clone(&EMPTY_CNAME_CHAIN),
                         upstream_server: None,
                         upstream_pool: None,
+                        upstream_strategy: None,
+                        upstream_attempt: None,
+                        upstream_protocol: None,
                         min_ttl: remaining_ttl,
                         negative_soa_ttl: None,
                         upstream_wire_data: None,
@@ -114,6 +121,9 @@ impl CachedResolver {
                         cname_chain: Arc::from([Arc::clone(&name)]),
                         upstream_server: None,
                         upstream_pool: None,
+                        upstream_strategy: None,
+                        upstream_attempt: None,
+                        upstream_protocol: None,
                         min_ttl: remaining_ttl,
                         negative_soa_ttl: None,
                         upstream_wire_data: None,
@@ -126,6 +136,9 @@ impl CachedResolver {
                         cname_chain: Arc::clone(&EMPTY_CNAME_CHAIN),
                         upstream_server: None,
                         upstream_pool: None,
+                        upstream_strategy: None,
+                        upstream_attempt: None,
+                        upstream_protocol: None,
                         min_ttl: remaining_ttl,
                         negative_soa_ttl: None,
                         upstream_wire_data: Some(bytes),
@@ -138,6 +151,9 @@ impl CachedResolver {
                         cname_chain: Arc::clone(&EMPTY_CNAME_CHAIN),
                         upstream_server: None,
                         upstream_pool: None,
+                        upstream_strategy: None,
+                        upstream_attempt: None,
+                        upstream_protocol: None,
                         min_ttl: remaining_ttl,
                         negative_soa_ttl: None,
                         upstream_wire_data: None,
@@ -147,14 +163,19 @@ impl CachedResolver {
     }
 
     fn check_cache(&self, query: &DnsQuery) -> Option<DnsResolution> {
-        self.check_cache_str(query.domain.as_ref(), query.record_type)
+        self.check_cache_str(
+            query.domain.as_ref(),
+            query.record_type,
+            query.cache_partition,
+        )
     }
 
     fn insert_negative(&self, query: &DnsQuery) {
         let ttl = self.negative_ttl_tracker.record_and_get_ttl(&query.domain);
-        self.cache.insert(
+        self.cache.insert_partitioned(
             query.domain.as_ref(),
             query.record_type,
+            query.cache_partition,
             CachedData::NegativeResponse,
             ttl,
             Some(DnssecStatus::Insecure),
@@ -169,9 +190,10 @@ impl CachedResolver {
                     .dnssec_status
                     .and_then(|s| s.parse().ok())
                     .unwrap_or(DnssecStatus::Insecure);
-                self.cache.insert(
+                self.cache.insert_partitioned(
                     query.domain.as_ref(),
                     query.record_type,
+                    query.cache_partition,
                     CachedData::WireData(wire_data.clone()),
                     ttl,
                     Some(dnssec_status),
@@ -181,9 +203,10 @@ impl CachedResolver {
                     .negative_soa_ttl
                     .map(clamp_negative_ttl)
                     .unwrap_or_else(|| self.negative_ttl_tracker.record_and_get_ttl(&query.domain));
-                self.cache.insert(
+                self.cache.insert_partitioned(
                     query.domain.as_ref(),
                     query.record_type,
+                    query.cache_partition,
                     CachedData::NegativeResponse,
                     ttl,
                     Some(DnssecStatus::Insecure),
@@ -198,9 +221,10 @@ impl CachedResolver {
 
             let ttl = resolution.min_ttl.unwrap_or(self.cache_ttl);
 
-            self.cache.insert(
+            self.cache.insert_partitioned(
                 query.domain.as_ref(),
                 query.record_type,
+                query.cache_partition,
                 CachedData::IpAddresses(CachedAddresses { addresses }),
                 ttl,
                 Some(dnssec_status),
@@ -229,9 +253,10 @@ impl CachedResolver {
                 // case-insensitively to avoid writing an identical entry twice.
                 if !target_name.eq_ignore_ascii_case(query.domain.as_ref()) {
                     let target_addresses = Arc::clone(&resolution.addresses);
-                    self.cache.insert(
+                    self.cache.insert_partitioned(
                         target_name,
                         query.record_type,
+                        query.cache_partition,
                         CachedData::IpAddresses(CachedAddresses {
                             addresses: target_addresses,
                         }),
@@ -247,30 +272,13 @@ impl CachedResolver {
         }
     }
 
-    fn register_or_join_inflight(
-        &self,
-        key: &CacheKey,
-    ) -> (bool, watch::Receiver<Option<Arc<InflightResult>>>) {
-        match self.inflight.entry(key.clone()) {
-            dashmap::Entry::Occupied(e) => {
-                let rx = e.get().subscribe();
-                drop(e);
-                (false, rx)
-            }
-            dashmap::Entry::Vacant(e) => {
-                let (tx, rx) = watch::channel(None::<Arc<InflightResult>>);
-                e.insert(Arc::new(tx));
-                (true, rx)
-            }
-        }
-    }
-
     async fn resolve_as_follower(
         &self,
         query: &DnsQuery,
-        mut rx: watch::Receiver<Option<Arc<InflightResult>>>,
+        mut rx: InflightReceiver,
     ) -> Result<DnsResolution, DomainError> {
-        if let Ok(()) = rx.changed().await {
+        let changed = query_budget::within(async { Ok(rx.changed().await) }).await?;
+        if let Ok(()) = changed {
             if let Some(result) = rx.borrow().clone() {
                 return Ok(DnsResolution {
                     addresses: Arc::clone(&result.addresses),
@@ -280,6 +288,9 @@ impl CachedResolver {
                     cname_chain: Arc::clone(&result.cname_chain),
                     upstream_server: None,
                     upstream_pool: None,
+                    upstream_strategy: None,
+                    upstream_attempt: None,
+                    upstream_protocol: None,
                     min_ttl: result.min_ttl,
                     negative_soa_ttl: None,
                     upstream_wire_data: result.upstream_wire_data.clone(),
@@ -296,6 +307,9 @@ impl CachedResolver {
                 cname_chain: Arc::clone(&result.cname_chain),
                 upstream_server: None,
                 upstream_pool: None,
+                upstream_strategy: None,
+                upstream_attempt: None,
+                upstream_protocol: None,
                 min_ttl: result.min_ttl,
                 negative_soa_ttl: None,
                 upstream_wire_data: result.upstream_wire_data.clone(),
@@ -380,8 +394,8 @@ impl CachedResolver {
     /// so the failure path has a single, explicit call site.
     #[inline]
     fn fail_inflight(&self, key: &CacheKey) {
-        if let Some((_, tx)) = self.inflight.remove(key) {
-            let _ = tx.send(None);
+        if let Some(entry) = self.inflight.remove(key) {
+            let _ = entry.tx.send(None);
         }
     }
 
@@ -396,11 +410,11 @@ impl CachedResolver {
     /// translate it into `DomainError::NxDomain`.
     #[inline]
     fn publish_inflight(&self, key: &CacheKey, resolution: &DnsResolution) {
-        let Some((_, tx)) = self.inflight.remove(key) else {
+        let Some(entry) = self.inflight.remove(key) else {
             return;
         };
         if !resolution.has_response_data() {
-            let _ = tx.send(None);
+            let _ = entry.tx.send(None);
             return;
         }
         let inflight = Arc::new(InflightResult {
@@ -410,7 +424,7 @@ impl CachedResolver {
             min_ttl: resolution.min_ttl,
             upstream_wire_data: resolution.upstream_wire_data.clone(),
         });
-        let _ = tx.send(Some(inflight));
+        let _ = entry.tx.send(Some(inflight));
     }
 }
 
@@ -434,11 +448,27 @@ impl DnsResolver for CachedResolver {
     }
 
     fn try_cache_str(&self, domain: &str, record_type: RecordType) -> Option<DnsResolution> {
-        self.check_cache_str(domain, record_type)
+        self.check_cache_str(domain, record_type, 0)
+    }
+
+    fn try_cache_partitioned(
+        &self,
+        domain: &str,
+        record_type: RecordType,
+        partition: u32,
+    ) -> Option<DnsResolution> {
+        self.check_cache_str(domain, record_type, partition)
     }
 
     async fn resolve(&self, query: &DnsQuery) -> Result<DnsResolution, DomainError> {
+        let span = tracing::trace_span!(
+            target: QUERY_SPAN_TARGET,
+            "cache",
+            hit = tracing::field::Empty,
+            coalesced = tracing::field::Empty,
+        );
         if let Some(cached) = self.check_cache(query) {
+            span.record("hit", true);
             return if !cached.has_response_data() {
                 Err(DomainError::NxDomain)
             } else {
@@ -446,19 +476,43 @@ impl DnsResolver for CachedResolver {
             };
         }
 
-        let key = CacheKey::new(query.domain.as_ref(), query.record_type);
-        let (is_leader, rx) = self.register_or_join_inflight(&key);
+        let key = CacheKey::partitioned(
+            query.domain.as_ref(),
+            query.record_type,
+            query.cache_partition,
+        );
+        let (is_leader, rx) = self.inflight.register_or_join(&key);
+        span.record("hit", false);
+        span.record("coalesced", !is_leader);
 
         if !is_leader {
-            return self.resolve_as_follower(query, rx).await;
+            // Waiting on another query's upstream round trip counts as cache time.
+            return phase_timings::measure(
+                QueryPhase::Cache,
+                self.resolve_as_follower(query, rx).instrument(span),
+            )
+            .await;
         }
 
+        // Only followers count as waiters on the leader's resolution.
+        drop(rx);
+
         // Phase 5: the second cache-check that used to live here (and its
         // non-atomic `self.inflight.remove`) moved into `resolve_as_leader`,
         // where it runs *after* the guard is already in place. That closes
         // the TOCTOU race: a follower arriving between the leader election
         // above and the cache check could previously be orphaned by the
         // leader taking the shortcut and unregistering the inflight entry.
-        self.resolve_as_leader(query, key).await
+        self.resolve_as_leader(query, key).instrument(span).await
+    }
+
+    /// Pool-routed answers are never cached: the cache is shared by every
+    /// client, and this answer may differ from what the default pools return.
+    async fn resolve_via_pool(
+        &self,
+        query: &DnsQuery,
+        pool: &str,
+    ) -> Result<DnsResolution, DomainError> {
+        self.inner.resolve_via_pool(query, pool).await
     }
 }
@@ -0,0 +1,239 @@
+use crate::dns::forwarding::{DnsForwarder, DnsResponse};
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{
+    ConditionalForwardStats, ConditionalForwardStatsPort, DnsResolution, DnsResolver,
+    EMPTY_CNAME_CHAIN, QUERY_SPAN_TARGET,
+};
+use ferrous_dns_application::use_cases::dns::phase_timings::{self, QueryPhase};
+use ferrous_dns_application::use_cases::dns::query_budget;
+use ferrous_dns_domain::{ConditionalForwardConfig, DnsQuery, DomainError};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+use tracing::{debug, Instrument};
+
+#[derive(Default)]
+struct RuleCounters {
+    queries: AtomicU64,
+    answered: AtomicU64,
+    nxdomain: AtomicU64,
+    failures: AtomicU64,
+    latency_us: AtomicU64,
+}
+
+struct ForwardRule {
+    zone: Arc<str>,
+    /// `.zone`, to match names under the zone.
+    suffix: String,
+    /// `ip:port` of every server, in the configured order.
+    servers: Vec<Arc<str>>,
+    counters: RuleCounters,
+}
+
+impl ForwardRule {
+    fn matches(&self, domain: &str) -> bool {
+        let domain = domain.trim_end_matches('.');
+        domain.eq_ignore_ascii_case(&self.zone)
+            || (domain.len() > self.suffix.len()
+                && domain[domain.len() - self.suffix.len()..].eq_ignore_ascii_case(&self.suffix))
+    }
+
+    fn record_latency(&self, started: Instant) {
+        self.counters
+            .latency_us
+            .fetch_add(started.elapsed().as_micros() as u64, Ordering::Relaxed);
+    }
+}
+
+/// The `dns.conditional_forwards` rules, with their counters.
+pub struct ConditionalForwards {
+    rules: Vec<ForwardRule>,
+}
+
+impl ConditionalForwards {
+    /// Rules with unparseable servers are skipped; config validation rejects
+    /// them at load time.
+    pub fn new(configs: &[ConditionalForwardConfig]) -> Self {
+        let rules = configs
+            .iter()
+            .filter_map(|config| {
+                let servers = config.server_addrs().ok()?;
+                let zone = config.normalized_zone();
+                Some(ForwardRule {
+                    suffix: format!(".{zone}"),
+                    zone: Arc::from(zone),
+                    servers: servers
+                        .iter()
+                        .map(|addr| Arc::from(addr.to_string()))
+                        .collect(),
+                    counters: RuleCounters::default(),
+                })
+            })
+            .collect();
+        Self { rules }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Whether `domain` is at or under a forwarded zone.
+    pub fn covers(&self, domain: &str) -> bool {
+        self.rule_for(domain).is_some()
+    }
+
+    /// The rule of the most specific zone containing `domain`.
+    fn rule_for(&self, domain: &str) -> Option<&ForwardRule> {
+        self.rules
+            .iter()
+            .filter(|rule| rule.matches(domain))
+            .max_by_key(|rule| rule.zone.len())
+    }
+}
+
+impl ConditionalForwardStatsPort for ConditionalForwards {
+    fn rule_stats(&self) -> Vec<ConditionalForwardStats> {
+        self.rules
+            .iter()
+            .map(|rule| {
+                let answered = rule.counters.answered.load(Ordering::Relaxed);
+                let nxdomain = rule.counters.nxdomain.load(Ordering::Relaxed);
+                let timed = answered + nxdomain;
+                let latency_us = rule.counters.latency_us.load(Ordering::Relaxed);
+                ConditionalForwardStats {
+                    zone: rule.zone.to_string(),
+                    servers: rule.servers.iter().map(|s| s.to_string()).collect(),
+                    queries: rule.counters.queries.load(Ordering::Relaxed),
+                    answered,
+                    nxdomain,
+                    failures: rule.counters.failures.load(Ordering::Relaxed),
+                    avg_latency_ms: (timed > 0).then(|| latency_us as f64 / timed as f64 / 1000.0),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Sends queries in a conditionally forwarded zone to the rule's servers,
+/// trying them in order, and everything else to the inner resolver. Sits
+/// below the cache so forwarded answers are cached like upstream ones.
+pub struct ConditionalForwardResolver {
+    inner: Arc<dyn DnsResolver>,
+    forwards: Arc<ConditionalForwards>,
+    query_timeout_ms: u64,
+    forwarder: DnsForwarder,
+}
+
+impl ConditionalForwardResolver {
+    pub fn new(
+        inner: Arc<dyn DnsResolver>,
+        forwards: Arc<ConditionalForwards>,
+        query_timeout_ms: u64,
+    ) -> Self {
+        Self {
+            inner,
+            forwards,
+            query_timeout_ms,
+            forwarder: DnsForwarder::new(),
+        }
+    }
+
+    async fn forward(
+        &self,
+        rule: &ForwardRule,
+        query: &DnsQuery,
+    ) -> Result<DnsResolution, DomainError> {
+        rule.counters.queries.fetch_add(1, Ordering::Relaxed);
+        let started = Instant::now();
+        let mut last_error = DomainError::QueryTimeout;
+
+        for server in &rule.servers {
+            let timeout_ms = query_budget::attempt_timeout_ms(self.query_timeout_ms)?;
+            match self
+                .forwarder
+                .query(server, &query.domain, &query.record_type, timeout_ms)
+                .await
+            {
+                Ok(response) if response.is_server_error() => {
+                    debug!(zone = %rule.zone, server = %server, rcode = %response.rcode, "Conditional forward server failed");
+                    last_error = DomainError::InvalidDnsResponse(format!(
+                        "{server} answered {}",
+                        response.rcode
+                    ));
+                }
+                Ok(response) if response.is_nxdomain() => {
+                    rule.counters.nxdomain.fetch_add(1, Ordering::Relaxed);
+                    rule.record_latency(started);
+                    return Err(DomainError::NxDomain);
+                }
+                Ok(response) => {
+                    rule.counters.answered.fetch_add(1, Ordering::Relaxed);
+                    rule.record_latency(started);
+                    debug!(domain = %query.domain, zone = %rule.zone, server = %server, "Conditionally forwarded");
+                    return Ok(Self::to_resolution(server, response));
+                }
+                Err(e) => {
+                    debug!(zone = %rule.zone, server = %server, error = %e, "Conditional forward server failed");
+                    last_error = e;
+                }
+            }
+        }
+
+        rule.counters.failures.fetch_add(1, Ordering::Relaxed);
+        Err(last_error)
+    }
+
+    fn to_resolution(server: &Arc<str>, response: DnsResponse) -> DnsResolution {
+        DnsResolution {
+            addresses: Arc::new(response.addresses),
+            cache_hit: false,
+            local_dns: false,
+            dnssec_status: None,
+            cname_chain: if response.cname_chain.is_empty() {
+                Arc::clone(&EMPTY_CNAME_CHAIN)
+            } else {
+                response.cname_chain.into_iter().collect::<Arc<[_]>>()
+            },
+            upstream_server: Some(Arc::clone(server)),
+            upstream_pool: None,
+            upstream_strategy: None,
+            upstream_attempt: None,
+            upstream_protocol: Some("UDP"),
+            min_ttl: response.min_ttl,
+            negative_soa_ttl: response.negative_soa_ttl,
+            upstream_wire_data: Some(response.raw_bytes),
+        }
+    }
+}
+
+#[async_trait]
+impl DnsResolver for ConditionalForwardResolver {
+    fn try_cache(&self, query: &DnsQuery) -> Option<DnsResolution> {
+        self.inner.try_cache(query)
+    }
+
+    async fn resolve(&self, query: &DnsQuery) -> Result<DnsResolution, DomainError> {
+        let Some(rule) = self.forwards.rule_for(&query.domain) else {
+            return self.inner.resolve(query).await;
+        };
+        let span = tracing::trace_span!(
+            target: QUERY_SPAN_TARGET,
+            "conditional_forward",
+            zone = rule.zone.as_ref(),
+        );
+        phase_timings::measure(
+            QueryPhase::Upstream,
+            self.forward(rule, query).instrument(span),
+        )
+        .await
+    }
+
+    /// An explicit pool, chosen by a query policy, wins over the zone rule.
+    async fn resolve_via_pool(
+        &self,
+        query: &DnsQuery,
+        pool: &str,
+    ) -> Result<DnsResolution, DomainError> {
+        self.inner.resolve_via_pool(query, pool).await
+    }
+}
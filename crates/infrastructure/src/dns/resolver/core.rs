@@ -1,10 +1,14 @@
 use crate::dns::forwarding::DnsForwarder;
-use crate::dns::load_balancer::PoolManager;
+use crate::dns::load_balancer::{PoolManager, UpstreamResult};
 use async_trait::async_trait;
-use ferrous_dns_application::ports::{DnsResolution, DnsResolver, EMPTY_CNAME_CHAIN};
+use ferrous_dns_application::ports::{
+    DnsResolution, DnsResolver, EMPTY_CNAME_CHAIN, QUERY_SPAN_TARGET,
+};
+use ferrous_dns_application::use_cases::dns::phase_timings::{self, QueryPhase};
+use ferrous_dns_application::use_cases::dns::query_budget;
 use ferrous_dns_domain::{DnsQuery, DomainError, PrivateIpFilter};
 use std::sync::Arc;
-use tracing::{debug, info};
+use tracing::{debug, info, Instrument};
 
 pub struct CoreResolver {
     pool_manager: Arc<PoolManager>,
@@ -60,14 +64,10 @@ impl CoreResolver {
 
     async fn resolve_local_tld(&self, query: &DnsQuery) -> Result<DnsResolution, DomainError> {
         if let Some(ref server) = self.local_dns_server {
+            let timeout_ms = query_budget::attempt_timeout_ms(self.query_timeout_ms)?;
             let forwarder = DnsForwarder::new();
             match forwarder
-                .query(
-                    server,
-                    &query.domain,
-                    &query.record_type,
-                    self.query_timeout_ms,
-                )
+                .query(server, &query.domain, &query.record_type, timeout_ms)
                 .await
             {
                 Ok(response) if !response.is_nxdomain() && !response.is_server_error() => {
@@ -84,6 +84,9 @@ impl CoreResolver {
                         cname_chain: Arc::clone(&EMPTY_CNAME_CHAIN),
                         upstream_server: Some(Arc::clone(server)),
                         upstream_pool: None,
+                        upstream_strategy: None,
+                        upstream_attempt: None,
+                        upstream_protocol: None,
                         min_ttl: response.min_ttl,
                         negative_soa_ttl: response.negative_soa_ttl,
                         upstream_wire_data: None,
@@ -104,35 +107,22 @@ impl CoreResolver {
         debug!(domain = %query.domain, "Local TLD query not in cache — returning NXDOMAIN");
         Err(DomainError::NxDomain)
     }
-}
-
-#[async_trait]
-impl DnsResolver for CoreResolver {
-    async fn resolve(&self, query: &DnsQuery) -> Result<DnsResolution, DomainError> {
-        debug!(
-            domain = %query.domain,
-            record_type = %query.record_type,
-            "CoreResolver: performing upstream query"
-        );
-
-        if self.local_dns_server.is_some() && PrivateIpFilter::is_private_ptr_query(&query.domain) {
-            return self.resolve_local_tld(query).await;
-        }
 
-        if self.is_local_tld(&query.domain) {
-            return self.resolve_local_tld(query).await;
-        }
+    fn upstream_span(pool: Option<&str>) -> tracing::Span {
+        tracing::trace_span!(
+            target: QUERY_SPAN_TARGET,
+            "upstream",
+            pool = pool,
+            server = tracing::field::Empty,
+        )
+    }
 
-        let result = self
-            .pool_manager
-            .query(
-                &query.domain,
-                &query.record_type,
-                self.query_timeout_ms,
-                self.dnssec_enabled,
-            )
-            .await?;
+    fn record_upstream(span: &tracing::Span, result: &UpstreamResult) {
+        span.record("pool", result.pool_name.as_ref());
+        span.record("server", result.server_display.as_ref());
+    }
 
+    fn to_resolution(query: &DnsQuery, result: UpstreamResult) -> DnsResolution {
         let addresses = Arc::new(result.response.addresses);
         let upstream_server = Some(result.server_display);
         let cname_chain = result.response.cname_chain;
@@ -148,7 +138,7 @@ impl DnsResolver for CoreResolver {
             "CoreResolver: query successful"
         );
 
-        Ok(DnsResolution {
+        DnsResolution {
             addresses,
             cache_hit: false,
             local_dns: false,
@@ -160,9 +150,75 @@ impl DnsResolver for CoreResolver {
             },
             upstream_server,
             upstream_pool: Some(result.pool_name),
+            upstream_strategy: Some(result.strategy),
+            upstream_attempt: Some(result.attempt),
+            upstream_protocol: Some(result.protocol),
             min_ttl,
             negative_soa_ttl,
             upstream_wire_data: Some(raw_bytes),
-        })
+        }
+    }
+}
+
+#[async_trait]
+impl DnsResolver for CoreResolver {
+    async fn resolve(&self, query: &DnsQuery) -> Result<DnsResolution, DomainError> {
+        debug!(
+            domain = %query.domain,
+            record_type = %query.record_type,
+            "CoreResolver: performing upstream query"
+        );
+
+        let span = Self::upstream_span(None);
+
+        if (self.local_dns_server.is_some() && PrivateIpFilter::is_private_ptr_query(&query.domain))
+            || self.is_local_tld(&query.domain)
+        {
+            return phase_timings::measure(
+                QueryPhase::Upstream,
+                self.resolve_local_tld(query).instrument(span),
+            )
+            .await;
+        }
+
+        let result = phase_timings::measure(
+            QueryPhase::Upstream,
+            self.pool_manager
+                .query(
+                    &query.domain,
+                    &query.record_type,
+                    self.query_timeout_ms,
+                    self.dnssec_enabled,
+                )
+                .instrument(span.clone()),
+        )
+        .await?;
+        Self::record_upstream(&span, &result);
+
+        Ok(Self::to_resolution(query, result))
+    }
+
+    async fn resolve_via_pool(
+        &self,
+        query: &DnsQuery,
+        pool: &str,
+    ) -> Result<DnsResolution, DomainError> {
+        let span = Self::upstream_span(Some(pool));
+        let result = phase_timings::measure(
+            QueryPhase::Upstream,
+            self.pool_manager
+                .query_pool(
+                    pool,
+                    &query.domain,
+                    &query.record_type,
+                    self.query_timeout_ms,
+                    self.dnssec_enabled,
+                )
+                .instrument(span.clone()),
+        )
+        .await?;
+        Self::record_upstream(&span, &result);
+
+        Ok(Self::to_resolution(query, result))
     }
 }
@@ -1,11 +1,13 @@
-use super::super::dnssec::DnssecValidatorPool;
+use super::super::dnssec::{DnssecCache, DnssecValidatorPool};
 use super::super::load_balancer::PoolManager;
 use async_trait::async_trait;
-use ferrous_dns_application::ports::{DnsResolution, DnsResolver};
+use ferrous_dns_application::ports::{DnsResolution, DnsResolver, QUERY_SPAN_TARGET};
+use ferrous_dns_application::use_cases::dns::phase_timings::{self, QueryPhase};
+use ferrous_dns_application::use_cases::dns::query_budget;
 use ferrous_dns_domain::{DnsQuery, DomainError};
 use hickory_proto::op::Message;
 use std::sync::Arc;
-use tracing::{debug, info, warn};
+use tracing::{debug, info, warn, Instrument};
 
 pub struct DnssecResolver {
     inner: Arc<dyn DnsResolver>,
@@ -17,6 +19,21 @@ impl DnssecResolver {
         inner: Arc<dyn DnsResolver>,
         pool_manager: Arc<PoolManager>,
         query_timeout_ms: u64,
+    ) -> Self {
+        Self::with_cache(
+            inner,
+            pool_manager,
+            query_timeout_ms,
+            Arc::new(DnssecCache::new()),
+        )
+    }
+
+    /// Like [`DnssecResolver::new`], validating against a shared chain cache.
+    pub fn with_cache(
+        inner: Arc<dyn DnsResolver>,
+        pool_manager: Arc<PoolManager>,
+        query_timeout_ms: u64,
+        cache: Arc<DnssecCache>,
     ) -> Self {
         let pool_size = std::thread::available_parallelism()
             .map(|n| n.get())
@@ -26,10 +43,11 @@ impl DnssecResolver {
 
         Self {
             inner,
-            validator: Arc::new(DnssecValidatorPool::new(
+            validator: Arc::new(DnssecValidatorPool::with_cache(
                 pool_manager,
                 query_timeout_ms,
                 pool_size,
+                cache,
             )),
         }
     }
@@ -39,11 +57,33 @@ impl DnssecResolver {
     }
 }
 
-#[async_trait]
-impl DnsResolver for DnssecResolver {
-    async fn resolve(&self, query: &DnsQuery) -> Result<DnsResolution, DomainError> {
-        let mut resolution = self.inner.resolve(query).await?;
+impl DnssecResolver {
+    async fn validate_traced(
+        &self,
+        query: &DnsQuery,
+        resolution: DnsResolution,
+    ) -> Result<DnsResolution, DomainError> {
+        let span = tracing::trace_span!(
+            target: QUERY_SPAN_TARGET,
+            "dnssec",
+            status = tracing::field::Empty,
+        );
+        let resolution = phase_timings::measure(
+            QueryPhase::Dnssec,
+            query_budget::within(self.validate(query, resolution)).instrument(span.clone()),
+        )
+        .await?;
+        if let Some(status) = resolution.dnssec_status {
+            span.record("status", status);
+        }
+        Ok(resolution)
+    }
 
+    async fn validate(
+        &self,
+        query: &DnsQuery,
+        mut resolution: DnsResolution,
+    ) -> Result<DnsResolution, DomainError> {
         if resolution.cache_hit || resolution.local_dns {
             return Ok(resolution);
         }
@@ -101,3 +141,20 @@ impl DnsResolver for DnssecResolver {
         }
     }
 }
+
+#[async_trait]
+impl DnsResolver for DnssecResolver {
+    async fn resolve(&self, query: &DnsQuery) -> Result<DnsResolution, DomainError> {
+        let resolution = self.inner.resolve(query).await?;
+        self.validate_traced(query, resolution).await
+    }
+
+    async fn resolve_via_pool(
+        &self,
+        query: &DnsQuery,
+        pool: &str,
+    ) -> Result<DnsResolution, DomainError> {
+        let resolution = self.inner.resolve_via_pool(query, pool).await?;
+        self.validate_traced(query, resolution).await
+    }
+}
@@ -27,9 +27,30 @@ impl DnsResolver for FilteredResolver {
         self.inner.try_cache_str(transformed.as_ref(), record_type)
     }
 
+    fn try_cache_partitioned(
+        &self,
+        domain: &str,
+        record_type: RecordType,
+        partition: u32,
+    ) -> Option<DnsResolution> {
+        let transformed = self.filters.apply_str(domain)?;
+        self.inner
+            .try_cache_partitioned(transformed.as_ref(), record_type, partition)
+    }
+
     async fn resolve(&self, query: &DnsQuery) -> Result<DnsResolution, DomainError> {
         let filtered_query = self.filters.apply(query.clone())?;
 
         self.inner.resolve(&filtered_query).await
     }
+
+    async fn resolve_via_pool(
+        &self,
+        query: &DnsQuery,
+        pool: &str,
+    ) -> Result<DnsResolution, DomainError> {
+        let filtered_query = self.filters.apply(query.clone())?;
+
+        self.inner.resolve_via_pool(&filtered_query, pool).await
+    }
 }
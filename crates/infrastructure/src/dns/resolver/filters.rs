@@ -1,3 +1,4 @@
+use super::conditional_forward::ConditionalForwards;
 use ferrous_dns_domain::{DnsQuery, DomainError, FqdnFilter, PrivateIpFilter};
 use std::borrow::Cow;
 use std::sync::Arc;
@@ -9,6 +10,9 @@ pub struct QueryFilters {
     pub block_non_fqdn: bool,
     pub local_domain: Option<String>,
     pub has_local_dns_server: bool,
+    /// Private reverse zones listed here are forwarded, so their PTR
+    /// queries are not blocked.
+    pub conditional_forwards: Option<Arc<ConditionalForwards>>,
 }
 
 impl QueryFilters {
@@ -23,14 +27,23 @@ impl QueryFilters {
             block_non_fqdn,
             local_domain,
             has_local_dns_server,
+            conditional_forwards: None,
         }
     }
 
-    pub fn apply(&self, mut query: DnsQuery) -> Result<DnsQuery, DomainError> {
-        if self.block_private_ptr
+    /// Whether a private PTR query for `domain` is dropped.
+    fn blocks_private_ptr(&self, domain: &str) -> bool {
+        self.block_private_ptr
             && !self.has_local_dns_server
-            && PrivateIpFilter::is_private_ptr_query(&query.domain)
-        {
+            && PrivateIpFilter::is_private_ptr_query(domain)
+            && !self
+                .conditional_forwards
+                .as_ref()
+                .is_some_and(|forwards| forwards.covers(domain))
+    }
+
+    pub fn apply(&self, mut query: DnsQuery) -> Result<DnsQuery, DomainError> {
+        if self.blocks_private_ptr(&query.domain) {
             return Err(DomainError::FilteredQuery(format!(
                 "Private PTR query blocked: {}",
                 query.domain
@@ -62,10 +75,7 @@ impl QueryFilters {
     /// domain as a `Cow<str>`. Returns `None` if the query should be dropped.
     /// Avoids `Arc::from` allocation on the fast-path cache lookup.
     pub fn apply_str<'a>(&self, domain: &'a str) -> Option<Cow<'a, str>> {
-        if self.block_private_ptr
-            && !self.has_local_dns_server
-            && PrivateIpFilter::is_private_ptr_query(domain)
-        {
+        if self.blocks_private_ptr(domain) {
             return None;
         }
 
@@ -94,6 +104,7 @@ impl Default for QueryFilters {
             block_non_fqdn: false,
             local_domain: None,
             has_local_dns_server: false,
+            conditional_forwards: None,
         }
     }
 }
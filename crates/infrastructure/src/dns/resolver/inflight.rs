@@ -0,0 +1,108 @@
+use super::super::cache::key::CacheKey;
+use bytes::Bytes;
+use dashmap::DashMap;
+use ferrous_dns_application::ports::{InflightQueriesPort, InflightQueriesSnapshot, InflightQuery};
+use rustc_hash::FxBuildHasher;
+use std::net::IpAddr;
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::watch;
+
+pub(super) struct InflightResult {
+    pub(super) addresses: Arc<Vec<IpAddr>>,
+    pub(super) cname_chain: Arc<[Arc<str>]>,
+    pub(super) dnssec_status: Option<&'static str>,
+    pub(super) min_ttl: Option<u32>,
+    pub(super) upstream_wire_data: Option<Bytes>,
+}
+
+pub(super) type InflightReceiver = watch::Receiver<Option<Arc<InflightResult>>>;
+
+/// One upstream resolution and the channel its followers wait on.
+pub(super) struct InflightEntry {
+    pub(super) tx: watch::Sender<Option<Arc<InflightResult>>>,
+    started_at: Instant,
+}
+
+/// Map of upstream resolutions in flight, keyed like the cache, so that
+/// concurrent misses for the same name share one upstream query.
+pub struct InflightRegistry {
+    entries: DashMap<CacheKey, Arc<InflightEntry>, FxBuildHasher>,
+    leaders: AtomicU64,
+    coalesced: AtomicU64,
+}
+
+impl InflightRegistry {
+    pub fn new(shards: usize) -> Self {
+        Self {
+            entries: DashMap::with_capacity_and_hasher_and_shard_amount(0, FxBuildHasher, shards),
+            leaders: AtomicU64::new(0),
+            coalesced: AtomicU64::new(0),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Joins the resolution in flight for `key`, or registers a new one.
+    /// Returns `true` when the caller became the leader and must resolve.
+    pub(super) fn register_or_join(&self, key: &CacheKey) -> (bool, InflightReceiver) {
+        match self.entries.entry(key.clone()) {
+            dashmap::Entry::Occupied(e) => {
+                let rx = e.get().tx.subscribe();
+                drop(e);
+                self.coalesced.fetch_add(1, AtomicOrdering::Relaxed);
+                (false, rx)
+            }
+            dashmap::Entry::Vacant(e) => {
+                let (tx, rx) = watch::channel(None::<Arc<InflightResult>>);
+                e.insert(Arc::new(InflightEntry {
+                    tx,
+                    started_at: Instant::now(),
+                }));
+                self.leaders.fetch_add(1, AtomicOrdering::Relaxed);
+                (true, rx)
+            }
+        }
+    }
+
+    /// Unregisters `key`, handing back its entry so the leader can publish.
+    pub(super) fn remove(&self, key: &CacheKey) -> Option<Arc<InflightEntry>> {
+        self.entries.remove(key).map(|(_, entry)| entry)
+    }
+}
+
+impl InflightQueriesPort for InflightRegistry {
+    fn inflight(&self, limit: usize) -> InflightQueriesSnapshot {
+        let now = Instant::now();
+        let mut queries: Vec<InflightQuery> = self
+            .entries
+            .iter()
+            .map(|item| {
+                let entry = item.value();
+                InflightQuery {
+                    domain: item.key().domain.to_string(),
+                    record_type: item.key().record_type,
+                    waiters: entry.tx.receiver_count(),
+                    elapsed_ms: now.duration_since(entry.started_at).as_millis() as u64,
+                }
+            })
+            .collect();
+        let total = queries.len();
+        queries.sort_unstable_by(|a, b| b.elapsed_ms.cmp(&a.elapsed_ms));
+        queries.truncate(limit);
+
+        InflightQueriesSnapshot {
+            queries,
+            total,
+            leaders: self.leaders.load(AtomicOrdering::Relaxed),
+            coalesced: self.coalesced.load(AtomicOrdering::Relaxed),
+        }
+    }
+}
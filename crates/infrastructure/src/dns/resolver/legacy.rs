@@ -1,10 +1,14 @@
 use super::super::cache::DnsCache;
+use super::super::dnssec::DnssecCache;
 use super::super::load_balancer::PoolManager;
 use super::super::prefetch::PrefetchPredictor;
 use super::builder::ResolverBuilder;
+use super::conditional_forward::ConditionalForwards;
 use super::config::ResolverConfig;
 use super::filters::QueryFilters;
-use super::local_ptr::PtrMap;
+use super::inflight::InflightRegistry;
+use super::local_ptr::{ClientPtrZone, PtrMap};
+use super::rebinding_layer::RebindingPolicy;
 use async_trait::async_trait;
 use ferrous_dns_application::ports::{DnsResolution, DnsResolver, QueryLogRepository};
 use ferrous_dns_domain::{DnsQuery, DomainError};
@@ -23,11 +27,16 @@ struct BuilderState {
     config: ResolverConfig,
     cache: Option<Arc<DnsCache>>,
     cache_ttl: u32,
+    dnssec_cache: Option<Arc<DnssecCache>>,
     local_domain: Option<String>,
     local_dns_server: Option<String>,
     prefetch_predictor: Option<Arc<PrefetchPredictor>>,
     filters: Option<QueryFilters>,
     local_ptr_map: Option<Arc<PtrMap>>,
+    client_ptr_zone: Option<Arc<ClientPtrZone>>,
+    rebinding_policy: Option<RebindingPolicy>,
+    inflight_registry: Option<Arc<InflightRegistry>>,
+    conditional_forwards: Option<Arc<ConditionalForwards>>,
 }
 
 impl HickoryDnsResolver {
@@ -49,11 +58,16 @@ impl HickoryDnsResolver {
             config: config.clone(),
             cache: None,
             cache_ttl: DEFAULT_CACHE_TTL,
+            dnssec_cache: None,
             local_domain: None,
             local_dns_server: None,
             prefetch_predictor: None,
             filters: None,
             local_ptr_map: None,
+            client_ptr_zone: None,
+            rebinding_policy: None,
+            inflight_registry: None,
+            conditional_forwards: None,
         };
 
         let inner = ResolverBuilder::new(pool_manager)
@@ -80,11 +94,27 @@ impl HickoryDnsResolver {
         self
     }
 
+    /// Records DNSSEC validation results in `cache`; see
+    /// [`ResolverBuilder::with_dnssec_cache`].
+    pub fn with_dnssec_cache(mut self, cache: Arc<DnssecCache>) -> Self {
+        self.builder_state.dnssec_cache = Some(cache);
+        self.rebuild();
+        self
+    }
+
     pub fn with_inflight_shards(mut self, shards: usize) -> Self {
         self.builder_state.config.inflight_shards = shards;
         self
     }
 
+    /// Coalesces concurrent cache misses through `registry`, which the API
+    /// reads to list in-flight upstream resolutions.
+    pub fn with_inflight_registry(mut self, registry: Arc<InflightRegistry>) -> Self {
+        self.builder_state.inflight_registry = Some(registry);
+        self.rebuild();
+        self
+    }
+
     pub fn with_query_filters(
         mut self,
         block_private_ptr: bool,
@@ -97,6 +127,7 @@ impl HickoryDnsResolver {
             block_non_fqdn,
             local_domain: local_domain.clone(),
             has_local_dns_server,
+            conditional_forwards: None,
         });
         self.builder_state.local_domain = local_domain;
         self.rebuild();
@@ -123,6 +154,34 @@ impl HickoryDnsResolver {
         self
     }
 
+    /// Attaches the reverse zone answered from client hostnames.
+    pub fn with_client_ptr_zone(mut self, zone: Arc<ClientPtrZone>) -> Self {
+        self.builder_state.client_ptr_zone = Some(zone);
+        self.rebuild();
+        self
+    }
+
+    /// Refuses upstream answers that resolve a public name to a private,
+    /// link-local or loopback address. Names under `local_domain` or an
+    /// `allowlist` entry are exempt.
+    pub fn with_rebinding_protection(
+        mut self,
+        local_domain: Option<&str>,
+        allowlist: &[String],
+    ) -> Self {
+        self.builder_state.rebinding_policy = Some(RebindingPolicy::new(local_domain, allowlist));
+        self.rebuild();
+        self
+    }
+
+    /// Sends queries in the rules' zones to their own servers instead of
+    /// the upstream pools.
+    pub fn with_conditional_forwards(mut self, forwards: Arc<ConditionalForwards>) -> Self {
+        self.builder_state.conditional_forwards = Some(forwards);
+        self.rebuild();
+        self
+    }
+
     fn rebuild(&mut self) {
         let mut builder = ResolverBuilder::new(self.builder_state.pool_manager.clone())
             .with_config(self.builder_state.config.clone())
@@ -137,6 +196,10 @@ impl HickoryDnsResolver {
             builder = builder.with_cache(cache.clone());
         }
 
+        if let Some(dnssec_cache) = &self.builder_state.dnssec_cache {
+            builder = builder.with_dnssec_cache(dnssec_cache.clone());
+        }
+
         if let Some(predictor) = &self.builder_state.prefetch_predictor {
             builder = builder.with_prefetch(predictor.clone());
         }
@@ -149,6 +212,22 @@ impl HickoryDnsResolver {
             builder = builder.with_local_ptr_map(Arc::clone(map));
         }
 
+        if let Some(zone) = &self.builder_state.client_ptr_zone {
+            builder = builder.with_client_ptr_zone(Arc::clone(zone));
+        }
+
+        if let Some(policy) = &self.builder_state.rebinding_policy {
+            builder = builder.with_rebinding_protection(policy.clone());
+        }
+
+        if let Some(registry) = &self.builder_state.inflight_registry {
+            builder = builder.with_inflight_registry(Arc::clone(registry));
+        }
+
+        if let Some(forwards) = &self.builder_state.conditional_forwards {
+            builder = builder.with_conditional_forwards(Arc::clone(forwards));
+        }
+
         self.inner = builder.build();
     }
 }
@@ -9,6 +9,7 @@ use hickory_proto::op::{Message, MessageType, OpCode, ResponseCode};
 use hickory_proto::rr::rdata::PTR;
 use hickory_proto::rr::{Name, RData};
 use hickory_proto::serialize::binary::{BinEncodable, BinEncoder};
+use ipnetwork::IpNetwork;
 use rustc_hash::FxBuildHasher;
 use std::net::IpAddr;
 use std::str::FromStr;
@@ -18,7 +19,71 @@ use tracing::{debug, info, warn};
 /// Concurrent map of IP address → (FQDN, TTL) used for PTR auto-generation.
 pub type PtrMap = DashMap<IpAddr, (Arc<str>, u32), FxBuildHasher>;
 
-/// DNS resolver layer that intercepts PTR queries and answers from local record mappings.
+/// Reverse zone of the configured local networks, filled with known client
+/// hostnames. Addresses outside these networks are never answered from it.
+pub struct ClientPtrZone {
+    networks: Vec<IpNetwork>,
+    local_domain: Option<String>,
+    map: PtrMap,
+}
+
+impl ClientPtrZone {
+    /// Entries that are not valid CIDRs are skipped; config validation
+    /// rejects them at load time.
+    pub fn new(networks: &[String], local_domain: Option<String>) -> Self {
+        Self {
+            networks: networks
+                .iter()
+                .filter_map(|cidr| cidr.parse::<IpNetwork>().ok())
+                .collect(),
+            local_domain,
+            map: DashMap::with_hasher(FxBuildHasher),
+        }
+    }
+
+    #[inline]
+    pub fn covers(&self, ip: IpAddr) -> bool {
+        self.networks.iter().any(|net| net.contains(ip))
+    }
+
+    pub fn lookup(&self, ip: IpAddr) -> Option<(Arc<str>, u32)> {
+        self.map.get(&ip).map(|entry| entry.value().clone())
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Qualifies a single-label hostname with the local domain; names that
+    /// already contain a dot are kept as they are.
+    fn qualify(&self, hostname: &str) -> Arc<str> {
+        let hostname = hostname.trim_end_matches('.');
+        match &self.local_domain {
+            Some(domain) if !hostname.contains('.') => Arc::from(format!("{hostname}.{domain}")),
+            _ => Arc::from(hostname),
+        }
+    }
+}
+
+impl PtrRecordRegistry for ClientPtrZone {
+    fn register(&self, ip: IpAddr, fqdn: Arc<str>, ttl: u32) {
+        if !self.covers(ip) || fqdn.trim().is_empty() {
+            return;
+        }
+        self.map.insert(ip, (self.qualify(&fqdn), ttl));
+    }
+
+    fn unregister(&self, ip: IpAddr) {
+        self.map.remove(&ip);
+    }
+}
+
+/// DNS resolver layer that intercepts PTR queries and answers from local record
+/// mappings, then from client hostnames inside the local networks.
 ///
 /// Non-PTR queries pass through to the inner resolver immediately with a single
 /// `RecordType` comparison — zero overhead on the A/AAAA hot path.
@@ -26,12 +91,24 @@ pub struct LocalPtrResolver {
     inner: Arc<dyn DnsResolver>,
     /// Live mapping of IP address → (FQDN, TTL).
     pub map: Arc<PtrMap>,
+    client_zone: Option<Arc<ClientPtrZone>>,
 }
 
 impl LocalPtrResolver {
     /// Creates a resolver wrapping `inner` with an existing live PTR map.
     pub fn new(inner: Arc<dyn DnsResolver>, map: Arc<PtrMap>) -> Self {
-        Self { inner, map }
+        Self {
+            inner,
+            map,
+            client_zone: None,
+        }
+    }
+
+    /// Answers PTR queries for addresses without a local record from client
+    /// hostnames in `zone`.
+    pub fn with_client_zone(mut self, zone: Option<Arc<ClientPtrZone>>) -> Self {
+        self.client_zone = zone;
+        self
     }
 
     /// Builds a resolver pre-populated from local DNS records declared in config.
@@ -66,10 +143,7 @@ impl LocalPtrResolver {
             "PTR auto-generation: preloaded local records at startup"
         );
 
-        Self {
-            inner,
-            map: Arc::new(map),
-        }
+        Self::new(inner, Arc::new(map))
     }
 }
 
@@ -102,15 +176,19 @@ impl DnsResolver for LocalPtrResolver {
             None => return self.inner.resolve(query).await,
         };
 
-        if let Some(entry) = self.map.get(&ip) {
-            let (fqdn, ttl) = entry.value();
+        let found = match self.map.get(&ip) {
+            Some(entry) => Some(entry.value().clone()),
+            None => self.client_zone.as_ref().and_then(|zone| zone.lookup(ip)),
+        };
+
+        if let Some((fqdn, ttl)) = found {
             debug!(
                 domain = %query.domain,
                 ip = %ip,
                 ptr = %fqdn,
-                "LocalPtrResolver: PTR answered from local records"
+                "LocalPtrResolver: PTR answered locally"
             );
-            return match build_ptr_resolution(query, fqdn, *ttl) {
+            return match build_ptr_resolution(query, &fqdn, ttl) {
                 Some(resolution) => Ok(resolution),
                 None => self.inner.resolve(query).await,
             };
@@ -118,6 +196,14 @@ impl DnsResolver for LocalPtrResolver {
 
         self.inner.resolve(query).await
     }
+
+    async fn resolve_via_pool(
+        &self,
+        query: &DnsQuery,
+        pool: &str,
+    ) -> Result<DnsResolution, DomainError> {
+        self.inner.resolve_via_pool(query, pool).await
+    }
 }
 
 fn build_ptr_resolution(query: &DnsQuery, hostname: &str, ttl: u32) -> Option<DnsResolution> {
@@ -157,6 +243,9 @@ fn build_ptr_resolution(query: &DnsQuery, hostname: &str, ttl: u32) -> Option<Dn
         cname_chain: Arc::clone(&EMPTY_CNAME_CHAIN),
         upstream_server: None,
         upstream_pool: None,
+        upstream_strategy: None,
+        upstream_attempt: None,
+        upstream_protocol: None,
         min_ttl: Some(ttl),
         negative_soa_ttl: None,
         upstream_wire_data: Some(Bytes::from(buf)),
@@ -1,19 +1,25 @@
 pub mod builder;
 pub mod cache_layer;
+pub mod conditional_forward;
 pub mod config;
 pub mod core;
 pub mod dnssec_layer;
 pub mod filtered_resolver;
 pub mod filters;
+pub mod inflight;
 pub mod legacy;
 pub mod local_ptr;
+pub mod rebinding_layer;
 
 pub use builder::ResolverBuilder;
 pub use cache_layer::CachedResolver;
+pub use conditional_forward::{ConditionalForwardResolver, ConditionalForwards};
 pub use config::{QueryFiltersConfig, ResolverConfig};
 pub use core::CoreResolver;
 pub use dnssec_layer::DnssecResolver;
 pub use filtered_resolver::FilteredResolver;
 pub use filters::QueryFilters;
+pub use inflight::InflightRegistry;
 pub use legacy::HickoryDnsResolver;
-pub use local_ptr::LocalPtrResolver;
+pub use local_ptr::{ClientPtrZone, LocalPtrResolver};
+pub use rebinding_layer::{RebindingPolicy, RebindingResolver};
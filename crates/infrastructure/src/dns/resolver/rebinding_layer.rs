@@ -0,0 +1,98 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{DnsResolution, DnsResolver};
+use ferrous_dns_domain::{DnsQuery, DomainError, PrivateIpFilter};
+use std::sync::Arc;
+use tracing::debug;
+
+/// Decides which domains may legitimately resolve to private addresses.
+///
+/// Names under `local_domain` and names under any allowlisted internal domain
+/// (the domain itself or any of its subdomains) are exempt.
+#[derive(Clone, Debug, Default)]
+pub struct RebindingPolicy {
+    exempt_suffixes: Arc<[Arc<str>]>,
+}
+
+impl RebindingPolicy {
+    pub fn new(local_domain: Option<&str>, allowlist: &[String]) -> Self {
+        let exempt_suffixes = local_domain
+            .into_iter()
+            .chain(allowlist.iter().map(String::as_str))
+            .map(|d| d.trim_end_matches('.').to_ascii_lowercase())
+            .filter(|d| !d.is_empty())
+            .map(|d| Arc::from(d.as_str()))
+            .collect::<Vec<_>>();
+        Self {
+            exempt_suffixes: exempt_suffixes.into(),
+        }
+    }
+
+    pub fn is_exempt(&self, domain: &str) -> bool {
+        let domain = domain.trim_end_matches('.');
+        self.exempt_suffixes.iter().any(|suffix| {
+            domain.len() >= suffix.len()
+                && domain[domain.len() - suffix.len()..].eq_ignore_ascii_case(suffix)
+                && (domain.len() == suffix.len()
+                    || domain.as_bytes()[domain.len() - suffix.len() - 1] == b'.')
+        })
+    }
+
+    /// Returns `true` when `resolution` points a non-exempt name at a private,
+    /// link-local or loopback address.
+    pub fn is_rebinding_attempt(&self, domain: &str, resolution: &DnsResolution) -> bool {
+        !resolution.local_dns
+            && resolution
+                .addresses
+                .iter()
+                .any(PrivateIpFilter::is_private_ip)
+            && !self.is_exempt(domain)
+    }
+}
+
+/// Refuses upstream answers that rebind a public name to an internal address.
+///
+/// Sits below the cache so rejected answers are never stored or served from
+/// the fast path; the use case logs them with the `REBIND_BLOCKED` status.
+pub struct RebindingResolver {
+    inner: Arc<dyn DnsResolver>,
+    policy: RebindingPolicy,
+}
+
+impl RebindingResolver {
+    pub fn new(inner: Arc<dyn DnsResolver>, policy: RebindingPolicy) -> Self {
+        Self { inner, policy }
+    }
+
+    fn check(
+        &self,
+        query: &DnsQuery,
+        resolution: DnsResolution,
+    ) -> Result<DnsResolution, DomainError> {
+        if self.policy.is_rebinding_attempt(&query.domain, &resolution) {
+            debug!(domain = %query.domain, "Upstream answer rebinds to a private address");
+            return Err(DomainError::DnsRebindingBlocked);
+        }
+        Ok(resolution)
+    }
+}
+
+#[async_trait]
+impl DnsResolver for RebindingResolver {
+    fn try_cache(&self, query: &DnsQuery) -> Option<DnsResolution> {
+        self.inner.try_cache(query)
+    }
+
+    async fn resolve(&self, query: &DnsQuery) -> Result<DnsResolution, DomainError> {
+        let resolution = self.inner.resolve(query).await?;
+        self.check(query, resolution)
+    }
+
+    async fn resolve_via_pool(
+        &self,
+        query: &DnsQuery,
+        pool: &str,
+    ) -> Result<DnsResolution, DomainError> {
+        let resolution = self.inner.resolve_via_pool(query, pool).await?;
+        self.check(query, resolution)
+    }
+}
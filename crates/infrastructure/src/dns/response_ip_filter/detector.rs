@@ -1,25 +1,38 @@
+use arc_swap::ArcSwap;
 use dashmap::{DashMap, DashSet};
-use ferrous_dns_application::ports::{ResponseIpFilterEvictionTarget, ResponseIpFilterStore};
+use ferrous_dns_application::ports::{
+    IpBlocklistSourceRepository, ResponseIpFilterEvictionTarget, ResponseIpFilterStore,
+};
 use ferrous_dns_application::use_cases::dns::coarse_timer::coarse_now_ns;
 use ferrous_dns_domain::ResponseIpFilterConfig;
+use ipnetwork::IpNetwork;
 use rustc_hash::FxBuildHasher;
+use std::collections::HashSet;
 use std::net::IpAddr;
+use std::sync::Arc;
 use std::time::Duration;
 use tracing::{debug, info, warn};
 
 const NS_PER_SEC: u64 = 1_000_000_000;
 
-/// Downloads C2 IP threat feeds and provides O(1) hot-path lookup.
+/// Downloads C2 IP threat feeds and provides hot-path lookup.
 ///
-/// Maintains a `DashSet` of known C2 IPs for lock-free hot-path checks, and a
-/// `DashMap` with TTL metadata for background eviction. The fetch loop runs as
-/// an async task, downloading feeds at the configured interval.
+/// Maintains a `DashSet` of known C2 IPs for lock-free O(1) checks, and an
+/// `ArcSwap` snapshot of feed CIDR ranges scanned after the exact lookup misses.
+/// `DashMap`s with TTL metadata drive background eviction. The fetch loop runs
+/// as an async task, downloading the configured feeds plus the enabled
+/// `ip_blocklist_sources` at the configured interval.
 pub struct ResponseIpFilterDetector {
     config: ResponseIpFilterConfig,
+    sources: Option<Arc<dyn IpBlocklistSourceRepository>>,
     /// O(1) hot-path lookup set.
     pub blocked_ips: DashSet<IpAddr, FxBuildHasher>,
     /// Last confirmation timestamp (ns) per IP, for TTL-based eviction.
     pub blocked_ip_confirmed_at: DashMap<IpAddr, u64, FxBuildHasher>,
+    /// Hot-path snapshot of `blocked_network_confirmed_at` keys.
+    blocked_networks: ArcSwap<Vec<IpNetwork>>,
+    /// Last confirmation timestamp (ns) per CIDR range, for TTL-based eviction.
+    blocked_network_confirmed_at: DashMap<IpNetwork, u64, FxBuildHasher>,
 }
 
 impl ResponseIpFilterDetector {
@@ -27,11 +40,47 @@ impl ResponseIpFilterDetector {
     pub fn new(config: &ResponseIpFilterConfig) -> Self {
         Self {
             config: config.clone(),
+            sources: None,
             blocked_ips: DashSet::with_hasher(FxBuildHasher),
             blocked_ip_confirmed_at: DashMap::with_hasher(FxBuildHasher),
+            blocked_networks: ArcSwap::from_pointee(Vec::new()),
+            blocked_network_confirmed_at: DashMap::with_hasher(FxBuildHasher),
         }
     }
 
+    /// Also downloads the enabled feeds managed through `/api/ip-blocklist-sources`.
+    pub fn with_sources(mut self, sources: Arc<dyn IpBlocklistSourceRepository>) -> Self {
+        self.sources = Some(sources);
+        self
+    }
+
+    /// Records a CIDR range as confirmed at `now_ns`. Single-host ranges are
+    /// stored as exact IPs.
+    pub fn insert_network(&self, network: IpNetwork, now_ns: u64) -> bool {
+        if is_single_host(&network) {
+            self.blocked_ip_confirmed_at.insert(network.ip(), now_ns);
+            return self.blocked_ips.insert(network.ip());
+        }
+        let network = IpNetwork::new(network.network(), network.prefix()).unwrap_or(network);
+        let is_new = self
+            .blocked_network_confirmed_at
+            .insert(network, now_ns)
+            .is_none();
+        if is_new {
+            self.publish_networks();
+        }
+        is_new
+    }
+
+    fn publish_networks(&self) {
+        let networks: Vec<IpNetwork> = self
+            .blocked_network_confirmed_at
+            .iter()
+            .map(|entry| *entry.key())
+            .collect();
+        self.blocked_networks.store(Arc::new(networks));
+    }
+
     /// Runs the fetch loop, downloading IP feeds at the configured interval.
     ///
     /// Fetches immediately on startup so protection is active from the first
@@ -40,6 +89,7 @@ impl ResponseIpFilterDetector {
     pub async fn run_fetch_loop(self: std::sync::Arc<Self>, http_client: reqwest::Client) {
         info!(
             urls = self.config.ip_list_urls.len(),
+            managed_sources = self.sources.is_some(),
             refresh_secs = self.config.refresh_interval_secs,
             "Response IP filter fetch loop starting"
         );
@@ -51,20 +101,45 @@ impl ResponseIpFilterDetector {
         }
     }
 
+    /// Config feed URLs followed by the enabled managed sources, deduplicated.
+    async fn feed_urls(&self) -> Vec<String> {
+        let mut urls = self.config.ip_list_urls.clone();
+        if let Some(ref sources) = self.sources {
+            match sources.get_all().await {
+                Ok(sources) => urls.extend(
+                    sources
+                        .into_iter()
+                        .filter(|s| s.enabled)
+                        .map(|s| s.url.to_string()),
+                ),
+                Err(e) => warn!(error = %e, "Failed to load IP blocklist sources"),
+            }
+        }
+        let mut seen = HashSet::new();
+        urls.retain(|url| seen.insert(url.clone()));
+        urls
+    }
+
     async fn fetch_all_lists(&self, http_client: &reqwest::Client) {
         let now_ns = coarse_now_ns();
         let mut total_new = 0usize;
         let mut fetch_errors = 0usize;
+        let urls = self.feed_urls().await;
 
-        for url in &self.config.ip_list_urls {
+        for url in &urls {
             match fetch_ip_list(url, http_client).await {
-                Ok(ips) => {
-                    for ip in ips {
+                Ok(feed) => {
+                    for ip in feed.ips {
                         self.blocked_ip_confirmed_at.insert(ip, now_ns);
                         if self.blocked_ips.insert(ip) {
                             total_new += 1;
                         }
                     }
+                    for network in feed.networks {
+                        if self.insert_network(network, now_ns) {
+                            total_new += 1;
+                        }
+                    }
                 }
                 Err(e) => {
                     fetch_errors += 1;
@@ -73,22 +148,31 @@ impl ResponseIpFilterDetector {
             }
         }
 
-        let total = self.blocked_ips.len();
+        let total = self.blocked_ip_count();
         if fetch_errors > 0 && total == 0 {
             warn!(
                 failed = fetch_errors,
-                urls = self.config.ip_list_urls.len(),
+                urls = urls.len(),
                 "All C2 IP feeds failed — no IPs loaded for response filtering"
             );
         } else {
-            info!(new_ips = total_new, total, "C2 IP list updated");
+            info!(
+                new_entries = total_new,
+                total,
+                networks = self.blocked_network_confirmed_at.len(),
+                "C2 IP list updated"
+            );
         }
     }
 }
 
 impl ResponseIpFilterStore for ResponseIpFilterDetector {
     fn is_blocked_ip(&self, ip: &IpAddr) -> bool {
-        self.blocked_ips.contains(ip)
+        if self.blocked_ips.contains(ip) {
+            return true;
+        }
+        let networks = self.blocked_networks.load();
+        !networks.is_empty() && networks.iter().any(|net| net.contains(*ip))
     }
 }
 
@@ -108,14 +192,29 @@ impl ResponseIpFilterEvictionTarget for ResponseIpFilterDetector {
                     true
                 }
             });
+
+        let networks_before = self.blocked_network_confirmed_at.len();
+        self.blocked_network_confirmed_at
+            .retain(|network, &mut confirmed_ns| {
+                let age_ns = now_ns.saturating_sub(confirmed_ns);
+                if age_ns > ttl_ns {
+                    debug!(network = %network, "Evicted stale C2 network");
+                    false
+                } else {
+                    true
+                }
+            });
+        if self.blocked_network_confirmed_at.len() != networks_before {
+            self.publish_networks();
+        }
     }
 
     fn blocked_ip_count(&self) -> usize {
-        self.blocked_ips.len()
+        self.blocked_ips.len() + self.blocked_networks.load().len()
     }
 }
 
-async fn fetch_ip_list(url: &str, client: &reqwest::Client) -> Result<Vec<IpAddr>, String> {
+async fn fetch_ip_list(url: &str, client: &reqwest::Client) -> Result<ParsedFeed, String> {
     let response = client
         .get(url)
         .timeout(Duration::from_secs(30))
@@ -135,13 +234,40 @@ async fn fetch_ip_list(url: &str, client: &reqwest::Client) -> Result<Vec<IpAddr
     Ok(parse_ip_list(&text))
 }
 
-/// Parses an IP list in standard format: one IP per line, `#` comments, blank lines ignored.
-fn parse_ip_list(text: &str) -> Vec<IpAddr> {
-    text.lines()
-        .map(|line| line.split('#').next().unwrap_or("").trim())
-        .filter(|line| !line.is_empty())
-        .filter_map(|line| line.parse::<IpAddr>().ok())
-        .collect()
+/// Entries of one downloaded feed, split by hot-path lookup structure.
+#[derive(Debug, Default)]
+struct ParsedFeed {
+    ips: Vec<IpAddr>,
+    networks: Vec<IpNetwork>,
+}
+
+fn is_single_host(network: &IpNetwork) -> bool {
+    match network {
+        IpNetwork::V4(net) => net.prefix() == 32,
+        IpNetwork::V6(net) => net.prefix() == 128,
+    }
+}
+
+/// Parses an IP list: one IP or CIDR range per line, `#` or `;` comments,
+/// blank lines ignored. Single-host ranges (`/32`, `/128`) count as IPs.
+fn parse_ip_list(text: &str) -> ParsedFeed {
+    let mut feed = ParsedFeed::default();
+    for line in text.lines() {
+        let entry = line.split(['#', ';']).next().unwrap_or("").trim();
+        if entry.is_empty() {
+            continue;
+        }
+        if let Ok(ip) = entry.parse::<IpAddr>() {
+            feed.ips.push(ip);
+        } else if let Ok(network) = entry.parse::<IpNetwork>() {
+            if is_single_host(&network) {
+                feed.ips.push(network.ip());
+            } else {
+                feed.networks.push(network);
+            }
+        }
+    }
+    feed
 }
 
 #[cfg(test)]
@@ -157,7 +283,7 @@ mod tests {
                      # another comment\n\
                      2001:db8::1\n\
                      not_an_ip\n";
-        let ips = parse_ip_list(text);
+        let ips = parse_ip_list(text).ips;
         assert_eq!(ips.len(), 3);
         assert_eq!(ips[0], "1.2.3.4".parse::<IpAddr>().unwrap());
         assert_eq!(ips[1], "5.6.7.8".parse::<IpAddr>().unwrap());
@@ -166,14 +292,14 @@ mod tests {
 
     #[test]
     fn parse_ip_list_empty_input() {
-        assert!(parse_ip_list("").is_empty());
-        assert!(parse_ip_list("# only comments\n# here").is_empty());
+        assert!(parse_ip_list("").ips.is_empty());
+        assert!(parse_ip_list("# only comments\n# here").ips.is_empty());
     }
 
     #[test]
     fn parse_ip_list_whitespace_only_lines() {
         let text = "  \n\t\n1.2.3.4\n   \n";
-        let ips = parse_ip_list(text);
+        let ips = parse_ip_list(text).ips;
         assert_eq!(ips.len(), 1);
         assert_eq!(ips[0], "1.2.3.4".parse::<IpAddr>().unwrap());
     }
@@ -181,21 +307,37 @@ mod tests {
     #[test]
     fn parse_ip_list_ipv6_addresses() {
         let text = "2001:db8::1\n::1\nfe80::1\n";
-        let ips = parse_ip_list(text);
+        let ips = parse_ip_list(text).ips;
         assert_eq!(ips.len(), 3);
     }
 
     #[test]
     fn parse_ip_list_mixed_v4_v6() {
         let text = "1.2.3.4\n2001:db8::1\n5.6.7.8\n::1\n";
-        let ips = parse_ip_list(text);
+        let ips = parse_ip_list(text).ips;
         assert_eq!(ips.len(), 4);
     }
 
     #[test]
     fn parse_ip_list_skips_invalid_lines() {
         let text = "1.2.3.4\nnot_an_ip\nexample.com\n999.999.999.999\n5.6.7.8\n";
-        let ips = parse_ip_list(text);
+        let ips = parse_ip_list(text).ips;
         assert_eq!(ips.len(), 2);
     }
+
+    #[test]
+    fn parse_ip_list_splits_cidr_ranges() {
+        let text = "1.10.16.0/20 ; SBL256894\n\
+                     5.6.7.8/32\n\
+                     2001:db8::/32\n\
+                     9.9.9.9\n";
+        let feed = parse_ip_list(text);
+        assert_eq!(feed.ips.len(), 2);
+        assert_eq!(feed.ips[0], "5.6.7.8".parse::<IpAddr>().unwrap());
+        assert_eq!(feed.networks.len(), 2);
+        assert_eq!(
+            feed.networks[0],
+            "1.10.16.0/20".parse::<IpNetwork>().unwrap()
+        );
+    }
 }
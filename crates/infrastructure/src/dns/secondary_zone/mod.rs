@@ -0,0 +1,6 @@
+mod store;
+mod transfer;
+mod zone;
+
+pub use store::SecondaryZoneStore;
+pub use transfer::{apply_transfer, TransferOutcome};
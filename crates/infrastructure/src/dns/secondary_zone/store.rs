@@ -0,0 +1,322 @@
+use super::transfer::{query_serial, serial_newer, transfer, TransferOutcome};
+use super::zone::{servfail, soa_timers, zone_name, ZoneData};
+use crate::dns::forwarding::{RecordTypeMapper, TsigKey, TsigKeyring};
+use arc_swap::ArcSwap;
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{DnsResolution, SecondaryZonePort, SecondaryZoneRepository};
+use ferrous_dns_domain::{DomainError, RecordType, SecondaryZoneConfig};
+use hickory_proto::rr::Name;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::Mutex;
+use tracing::{debug, error, info, warn};
+
+/// Floor on SOA refresh and retry timers, so a zone with tiny timers does
+/// not keep the primary busy.
+const MIN_TIMER_SECS: u32 = 30;
+
+/// Retry interval for a zone that has never been transferred.
+const INITIAL_RETRY_SECS: i64 = 60;
+
+/// Wait reported when no zone is configured.
+const IDLE_SECS: i64 = 3600;
+
+/// A zone copy on the hot path.
+struct ServedZone {
+    data: Arc<ZoneData>,
+    /// Unix time after which the zone is answered with SERVFAIL.
+    expires_at: i64,
+}
+
+/// Refresh state of one configured zone.
+struct ZoneState {
+    /// Apex in lowercase, without the trailing dot.
+    zone: Arc<str>,
+    apex: Name,
+    primaries: Vec<SocketAddr>,
+    tsig: Option<Arc<TsigKey>>,
+    ixfr: bool,
+    data: Option<Arc<ZoneData>>,
+    checked_at: i64,
+    next_check: i64,
+}
+
+/// Secondary zones served from an `ArcSwap` snapshot.
+///
+/// Transfers run in `refresh_due`, driven by the refresh job; each one
+/// persists the new copy and swaps it in atomically, so lookups on the DNS
+/// hot path never take a lock. With no zone loaded, `serves` is a single
+/// `is_empty()` check.
+pub struct SecondaryZoneStore {
+    served: ArcSwap<Vec<ServedZone>>,
+    zones: Mutex<Vec<ZoneState>>,
+    repo: Arc<dyn SecondaryZoneRepository>,
+    timeout: Duration,
+}
+
+impl SecondaryZoneStore {
+    /// Loads the stored copies of the configured zones and drops the copies
+    /// of zones no longer configured. Zones without a copy are transferred
+    /// on the first `refresh_due`.
+    pub async fn new(
+        configs: &[SecondaryZoneConfig],
+        keyring: &TsigKeyring,
+        repo: Arc<dyn SecondaryZoneRepository>,
+        timeout: Duration,
+    ) -> Result<Arc<Self>, DomainError> {
+        let mut stored: HashMap<String, _> = repo
+            .get_all()
+            .await?
+            .into_iter()
+            .map(|zone| (zone.zone.to_string(), zone))
+            .collect();
+
+        let mut zones = Vec::with_capacity(configs.len());
+        for config in configs {
+            let name = config.normalized_zone();
+            let tsig = match &config.tsig_key {
+                Some(key) => Some(keyring.get(key).ok_or_else(|| {
+                    DomainError::ConfigError(format!(
+                        "Secondary zone '{}' references unknown TSIG key '{}'",
+                        config.zone, key
+                    ))
+                })?),
+                None => None,
+            };
+            let mut state = ZoneState {
+                zone: Arc::from(name.as_str()),
+                apex: zone_name(&name)?,
+                primaries: config.primary_addrs().map_err(DomainError::ConfigError)?,
+                tsig,
+                ixfr: config.ixfr,
+                data: None,
+                checked_at: 0,
+                next_check: 0,
+            };
+            if let Some(snapshot) = stored.remove(&name) {
+                match ZoneData::from_snapshot(&snapshot) {
+                    Ok(data) => {
+                        state.next_check = snapshot
+                            .checked_at
+                            .saturating_add(i64::from(snapshot.refresh.max(MIN_TIMER_SECS)));
+                        state.checked_at = snapshot.checked_at;
+                        state.data = Some(Arc::new(data));
+                    }
+                    Err(e) => warn!(zone = %name, error = %e, "Discarding stored secondary zone"),
+                }
+            }
+            zones.push(state);
+        }
+
+        for zone in stored.keys() {
+            if let Err(e) = repo.delete(zone).await {
+                warn!(zone = %zone, error = %e, "Failed to delete unconfigured secondary zone");
+            }
+        }
+
+        let store = Arc::new(Self {
+            served: ArcSwap::from_pointee(Vec::new()),
+            zones: Mutex::new(zones),
+            repo,
+            timeout,
+        });
+        let zones = store.zones.lock().await;
+        store.publish(&zones);
+        info!(
+            zones = zones.len(),
+            loaded = store.served.load().len(),
+            "SecondaryZoneStore initialised"
+        );
+        drop(zones);
+        Ok(store)
+    }
+
+    /// Swaps in the zones that have a copy, most specific apex first.
+    fn publish(&self, zones: &[ZoneState]) {
+        let mut served: Vec<ServedZone> = zones
+            .iter()
+            .filter_map(|state| {
+                let data = state.data.as_ref()?;
+                let (_, _, _, expire) = soa_timers(data.soa());
+                Some(ServedZone {
+                    data: Arc::clone(data),
+                    expires_at: state.checked_at.saturating_add(i64::from(expire)),
+                })
+            })
+            .collect();
+        served.sort_by_key(|zone| std::cmp::Reverse(zone.data.apex().num_labels()));
+        self.served.store(Arc::new(served));
+    }
+
+    async fn refresh_zone(&self, state: &mut ZoneState, now: i64) {
+        for primary in state.primaries.clone() {
+            match self.sync_from(state, primary, now).await {
+                Ok(()) => return,
+                Err(e) => warn!(
+                    zone = %state.zone,
+                    primary = %primary,
+                    error = %e,
+                    "Secondary zone refresh failed"
+                ),
+            }
+        }
+        let retry = match &state.data {
+            Some(data) => i64::from(soa_timers(data.soa()).2.max(MIN_TIMER_SECS)),
+            None => INITIAL_RETRY_SECS,
+        };
+        state.next_check = now.saturating_add(retry);
+    }
+
+    async fn sync_from(
+        &self,
+        state: &mut ZoneState,
+        primary: SocketAddr,
+        now: i64,
+    ) -> Result<(), DomainError> {
+        let tsig = state.tsig.clone();
+        if let Some(data) = &state.data {
+            let serial = query_serial(primary, &state.apex, tsig.as_ref(), self.timeout).await?;
+            if !serial_newer(serial, data.serial()) {
+                return self.mark_checked(state, now).await;
+            }
+        }
+
+        let current = state.data.clone();
+        let records = current.as_ref().map(|data| data.records());
+        let incremental = state.ixfr && records.is_some();
+        let outcome = match transfer(
+            primary,
+            &state.apex,
+            records,
+            incremental,
+            tsig.as_ref(),
+            self.timeout,
+        )
+        .await
+        {
+            Err(e) if incremental => {
+                debug!(zone = %state.zone, error = %e, "IXFR failed, falling back to AXFR");
+                transfer(
+                    primary,
+                    &state.apex,
+                    records,
+                    false,
+                    tsig.as_ref(),
+                    self.timeout,
+                )
+                .await?
+            }
+            outcome => outcome?,
+        };
+
+        match outcome {
+            TransferOutcome::UpToDate => self.mark_checked(state, now).await,
+            TransferOutcome::Updated {
+                records,
+                incremental,
+            } => {
+                let data = ZoneData::new(state.apex.clone(), records)?;
+                self.repo.save(&data.to_snapshot(now, now)?).await?;
+                info!(
+                    zone = %data.apex_str,
+                    primary = %primary,
+                    serial = data.serial(),
+                    records = data.records().len(),
+                    incremental,
+                    "Secondary zone transferred"
+                );
+                state.next_check = now.saturating_add(refresh_secs(&data));
+                state.data = Some(Arc::new(data));
+                state.checked_at = now;
+                Ok(())
+            }
+        }
+    }
+
+    async fn mark_checked(&self, state: &mut ZoneState, now: i64) -> Result<(), DomainError> {
+        let Some(data) = &state.data else {
+            return Ok(());
+        };
+        self.repo.mark_checked(&state.zone, now).await?;
+        debug!(zone = %state.zone, serial = data.serial(), "Secondary zone is current");
+        state.next_check = now.saturating_add(refresh_secs(data));
+        state.checked_at = now;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SecondaryZonePort for SecondaryZoneStore {
+    #[inline]
+    fn serves(&self, domain: &str) -> bool {
+        let served = self.served.load();
+        !served.is_empty()
+            && served
+                .iter()
+                .any(|zone| in_zone(domain, &zone.data.apex_str))
+    }
+
+    fn lookup(&self, domain: &str, record_type: RecordType) -> Option<DnsResolution> {
+        let served = self.served.load();
+        let zone = served
+            .iter()
+            .find(|zone| in_zone(domain, &zone.data.apex_str))?;
+        let qname = Name::from_str(&format!("{}.", domain.trim_end_matches('.')))
+            .map_err(|e| warn!(domain = %domain, error = %e, "Secondary zone: invalid query name"))
+            .ok()?;
+        let qtype = RecordTypeMapper::to_hickory(&record_type);
+
+        if unix_now() >= zone.expires_at {
+            return Some(servfail(&qname, qtype));
+        }
+        zone.data.answer(&qname, qtype)
+    }
+
+    async fn refresh_due(&self) -> Duration {
+        let mut zones = self.zones.lock().await;
+        let now = unix_now();
+        for state in zones.iter_mut().filter(|state| state.next_check <= now) {
+            self.refresh_zone(state, now).await;
+        }
+        self.publish(&zones);
+
+        for zone in self.served.load().iter() {
+            if now >= zone.expires_at {
+                error!(zone = %zone.data.apex_str, "Secondary zone expired; answering SERVFAIL");
+            }
+        }
+
+        let next = zones
+            .iter()
+            .map(|state| state.next_check)
+            .min()
+            .unwrap_or(now.saturating_add(IDLE_SECS));
+        Duration::from_secs(next.saturating_sub(unix_now()).max(0) as u64)
+    }
+}
+
+fn refresh_secs(data: &ZoneData) -> i64 {
+    i64::from(soa_timers(data.soa()).1.max(MIN_TIMER_SECS))
+}
+
+/// Whether `domain` is `apex` or a name below it, ignoring case.
+#[inline]
+fn in_zone(domain: &str, apex: &str) -> bool {
+    let domain = domain.trim_end_matches('.').as_bytes();
+    let apex = apex.as_bytes();
+    if domain.len() < apex.len() {
+        return false;
+    }
+    let split = domain.len() - apex.len();
+    domain[split..].eq_ignore_ascii_case(apex) && (split == 0 || domain[split - 1] == b'.')
+}
+
+fn unix_now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
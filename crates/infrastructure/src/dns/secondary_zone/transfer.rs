@@ -0,0 +1,351 @@
+use super::zone::transfer_error;
+use crate::dns::forwarding::{is_tsig_signed, MessageBuilder, TsigKey};
+use crate::dns::transport::tcp::{read_with_length_prefix, send_with_length_prefix};
+use ferrous_dns_domain::DomainError;
+use hickory_proto::op::{Message, MessageType, OpCode, Query, ResponseCode};
+use hickory_proto::rr::{Name, RData, Record, RecordType};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::TcpStream;
+
+/// Messages a primary may send without a TSIG record before the next signed
+/// one (RFC 8945 §5.3.1).
+const MAX_UNSIGNED_MESSAGES: usize = 99;
+
+/// Upper bound on the records of a single transfer.
+const MAX_TRANSFER_RECORDS: usize = 1_000_000;
+
+/// What a transfer produced.
+#[derive(Debug)]
+pub enum TransferOutcome {
+    /// The primary holds no newer version of the zone.
+    UpToDate,
+    /// The new version of the zone, SOA first.
+    Updated {
+        records: Vec<Record>,
+        incremental: bool,
+    },
+}
+
+/// Serial of `apex` on `primary`, asked over TCP.
+pub(super) async fn query_serial(
+    primary: SocketAddr,
+    apex: &Name,
+    tsig: Option<&Arc<TsigKey>>,
+    timeout: Duration,
+) -> Result<u32, DomainError> {
+    let query = build_query(apex, RecordType::SOA, None);
+    let mut exchange = Exchange::open(primary, apex, &query, tsig, timeout).await?;
+    let response = exchange.next().await?;
+    exchange.finish()?;
+    check_response(apex, &query, &response)?;
+
+    response
+        .answers()
+        .iter()
+        .find_map(|r| match r.data() {
+            RData::SOA(soa) if r.name() == apex => Some(soa.serial()),
+            _ => None,
+        })
+        .ok_or_else(|| transfer_error(apex, "primary did not return the zone's SOA"))
+}
+
+/// Transfers `apex` from `primary`: IXFR from `current` when `incremental`
+/// is set and a copy exists, AXFR otherwise.
+pub(super) async fn transfer(
+    primary: SocketAddr,
+    apex: &Name,
+    current: Option<&[Record]>,
+    incremental: bool,
+    tsig: Option<&Arc<TsigKey>>,
+    timeout: Duration,
+) -> Result<TransferOutcome, DomainError> {
+    let base_soa = current
+        .and_then(|records| records.first())
+        .filter(|_| incremental);
+    let query = match base_soa {
+        Some(soa) => build_query(apex, RecordType::IXFR, Some(soa.clone())),
+        None => build_query(apex, RecordType::AXFR, None),
+    };
+    let mut exchange = Exchange::open(primary, apex, &query, tsig, timeout).await?;
+
+    let mut answers = Vec::new();
+    loop {
+        let mut response = exchange.next().await?;
+        check_response(apex, &query, &response)?;
+        answers.extend(response.take_answers());
+        if answers.len() > MAX_TRANSFER_RECORDS {
+            return Err(transfer_error(apex, "zone is too large"));
+        }
+        if transfer_complete(&answers, base_soa.is_some()) {
+            break;
+        }
+    }
+    exchange.finish()?;
+
+    apply_transfer(apex, current, answers)
+}
+
+/// Turns the answer records of an AXFR or IXFR response (RFC 1995 §4) into
+/// the new version of the zone. `current` is the local copy an IXFR
+/// applies to.
+pub fn apply_transfer(
+    apex: &Name,
+    current: Option<&[Record]>,
+    answers: Vec<Record>,
+) -> Result<TransferOutcome, DomainError> {
+    let new_serial = answers
+        .first()
+        .and_then(soa_serial)
+        .ok_or_else(|| transfer_error(apex, "response does not start with the zone's SOA"))?;
+    let current_serial = current
+        .and_then(|records| records.first())
+        .and_then(soa_serial);
+
+    if answers.len() == 1 {
+        return match current_serial {
+            Some(serial) if !serial_newer(new_serial, serial) => Ok(TransferOutcome::UpToDate),
+            _ => Err(transfer_error(apex, "transfer ended after the first SOA")),
+        };
+    }
+
+    if !is_incremental(&answers) {
+        if answers.last().and_then(soa_serial) != Some(new_serial) {
+            return Err(transfer_error(
+                apex,
+                "transfer does not end with the zone's SOA",
+            ));
+        }
+        let mut records = answers;
+        records.pop();
+        return Ok(TransferOutcome::Updated {
+            records,
+            incremental: false,
+        });
+    }
+
+    let mut zone = current
+        .ok_or_else(|| transfer_error(apex, "incremental response without a local copy"))?
+        .to_vec();
+    let mut rest = answers[1..].iter().peekable();
+    loop {
+        let from = rest
+            .next()
+            .and_then(soa_serial)
+            .ok_or_else(|| transfer_error(apex, "malformed incremental response"))?;
+        if from == new_serial && rest.peek().is_none() {
+            break;
+        }
+        if Some(from) != zone.first().and_then(soa_serial) {
+            return Err(transfer_error(
+                apex,
+                "incremental response does not start from our serial",
+            ));
+        }
+        while let Some(deleted) = rest.next_if(|r| r.record_type() != RecordType::SOA) {
+            if let Some(at) = zone.iter().skip(1).position(|r| r == deleted) {
+                zone.remove(at + 1);
+            }
+        }
+        let to = rest
+            .next()
+            .filter(|r| r.record_type() == RecordType::SOA)
+            .ok_or_else(|| transfer_error(apex, "malformed incremental response"))?;
+        zone[0] = to.clone();
+        while let Some(added) = rest.next_if(|r| r.record_type() != RecordType::SOA) {
+            if !zone.iter().skip(1).any(|r| r == added) {
+                zone.push(added.clone());
+            }
+        }
+    }
+
+    Ok(TransferOutcome::Updated {
+        records: zone,
+        incremental: true,
+    })
+}
+
+/// Whether RFC 1982 serial `a` is newer than `b`.
+pub(super) fn serial_newer(a: u32, b: u32) -> bool {
+    a != b && (a.wrapping_sub(b) as i32) > 0
+}
+
+/// An incremental response has the old serial's SOA right after the new
+/// one. `[SOA, SOA]` is a full transfer of a zone holding only its SOA.
+fn is_incremental(answers: &[Record]) -> bool {
+    answers.len() > 2 && answers[1].record_type() == RecordType::SOA
+}
+
+/// Whether `answers` holds a complete response, given how the transfer
+/// ends: after the closing SOA of an AXFR, after the final SOA of an IXFR,
+/// or after a lone SOA when an IXFR finds us up to date.
+fn transfer_complete(answers: &[Record], asked_incremental: bool) -> bool {
+    let Some(new_serial) = answers.first().and_then(soa_serial) else {
+        return !answers.is_empty(); // let apply_transfer reject it
+    };
+    if answers.len() == 1 {
+        return asked_incremental;
+    }
+    if answers.last().and_then(soa_serial) != Some(new_serial) {
+        return false;
+    }
+    if !is_incremental(answers) {
+        return true;
+    }
+
+    // Walk the difference sequences: SOA, deletions, SOA, additions.
+    let is_soa = |r: &Record| r.record_type() == RecordType::SOA;
+    let mut pos = 1;
+    loop {
+        if soa_serial(&answers[pos]) == Some(new_serial) && pos == answers.len() - 1 {
+            return true;
+        }
+        pos += 1;
+        while pos < answers.len() && !is_soa(&answers[pos]) {
+            pos += 1;
+        }
+        pos += 1;
+        while pos < answers.len() && !is_soa(&answers[pos]) {
+            pos += 1;
+        }
+        if pos >= answers.len() {
+            return false;
+        }
+    }
+}
+
+fn soa_serial(record: &Record) -> Option<u32> {
+    match record.data() {
+        RData::SOA(soa) => Some(soa.serial()),
+        _ => None,
+    }
+}
+
+fn build_query(apex: &Name, record_type: RecordType, authority: Option<Record>) -> Message {
+    let mut message = Message::new(
+        MessageBuilder::secure_random_id(),
+        MessageType::Query,
+        OpCode::Query,
+    );
+    message.add_query(Query::query(apex.clone(), record_type));
+    if let Some(soa) = authority {
+        message.add_name_server(soa);
+    }
+    message
+}
+
+fn check_response(apex: &Name, query: &Message, response: &Message) -> Result<(), DomainError> {
+    if response.id() != query.id() || response.message_type() != MessageType::Response {
+        return Err(transfer_error(apex, "unexpected message from primary"));
+    }
+    match response.response_code() {
+        ResponseCode::NoError => Ok(()),
+        code => Err(transfer_error(apex, &format!("primary answered {code}"))),
+    }
+}
+
+/// A TCP exchange with a primary, verifying TSIG across messages.
+struct Exchange<'a> {
+    stream: TcpStream,
+    apex: &'a Name,
+    tsig: Option<TsigStream>,
+    timeout: Duration,
+}
+
+struct TsigStream {
+    key: Arc<TsigKey>,
+    prior_mac: Vec<u8>,
+    first: bool,
+    unsigned: Vec<u8>,
+    unsigned_count: usize,
+}
+
+impl<'a> Exchange<'a> {
+    async fn open(
+        primary: SocketAddr,
+        apex: &'a Name,
+        query: &Message,
+        tsig: Option<&Arc<TsigKey>>,
+        timeout: Duration,
+    ) -> Result<Exchange<'a>, DomainError> {
+        let mut wire = query
+            .to_vec()
+            .map_err(|e| transfer_error(apex, &format!("cannot encode query: {e}")))?;
+        let tsig = match tsig {
+            Some(key) => Some(TsigStream {
+                prior_mac: key.sign(&mut wire, None)?,
+                key: Arc::clone(key),
+                first: true,
+                unsigned: Vec::new(),
+                unsigned_count: 0,
+            }),
+            None => None,
+        };
+
+        let mut stream = tokio::time::timeout(timeout, TcpStream::connect(primary))
+            .await
+            .map_err(|_| transfer_error(apex, &format!("timeout connecting to {primary}")))?
+            .map_err(|e| transfer_error(apex, &format!("cannot connect to {primary}: {e}")))?;
+        tokio::time::timeout(timeout, send_with_length_prefix(&mut stream, &wire))
+            .await
+            .map_err(|_| transfer_error(apex, &format!("timeout sending to {primary}")))??;
+
+        Ok(Self {
+            stream,
+            apex,
+            tsig,
+            timeout,
+        })
+    }
+
+    async fn next(&mut self) -> Result<Message, DomainError> {
+        let wire = tokio::time::timeout(self.timeout, read_with_length_prefix(&mut self.stream))
+            .await
+            .map_err(|_| transfer_error(self.apex, "timeout waiting for the primary"))??;
+        let wire = match self.tsig.as_mut() {
+            Some(tsig) => tsig.verify(wire)?,
+            None => wire,
+        };
+        Message::from_vec(&wire)
+            .map_err(|e| transfer_error(self.apex, &format!("malformed message: {e}")))
+    }
+
+    /// The last message of a signed exchange must itself be signed.
+    fn finish(&self) -> Result<(), DomainError> {
+        match &self.tsig {
+            Some(tsig) if tsig.unsigned_count > 0 => {
+                Err(transfer_error(self.apex, "last message is not TSIG-signed"))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl TsigStream {
+    fn verify(&mut self, wire: Vec<u8>) -> Result<Vec<u8>, DomainError> {
+        if self.first {
+            let (unsigned, mac) = self.key.verify(&wire, Some(&self.prior_mac))?;
+            self.first = false;
+            self.prior_mac = mac;
+            return Ok(unsigned);
+        }
+        if !is_tsig_signed(&wire) {
+            self.unsigned_count += 1;
+            if self.unsigned_count > MAX_UNSIGNED_MESSAGES {
+                return Err(DomainError::IoError(
+                    "TSIG verification failed: too many unsigned messages".into(),
+                ));
+            }
+            self.unsigned.extend_from_slice(&wire);
+            return Ok(wire);
+        }
+        let (unsigned, mac) =
+            self.key
+                .verify_continuation(&wire, &self.prior_mac, &self.unsigned)?;
+        self.prior_mac = mac;
+        self.unsigned.clear();
+        self.unsigned_count = 0;
+        Ok(unsigned)
+    }
+}
@@ -0,0 +1,280 @@
+use bytes::Bytes;
+use ferrous_dns_application::ports::DnsResolution;
+use ferrous_dns_domain::{DomainError, SecondaryZone};
+use hickory_proto::op::{Message, MessageType, OpCode, Query, ResponseCode};
+use hickory_proto::rr::{Name, RData, Record, RecordType};
+use hickory_proto::serialize::binary::{BinDecodable, BinEncodable, BinEncoder};
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use std::sync::Arc;
+use tracing::warn;
+
+/// Aliases followed inside the zone before the answer is returned as is.
+const MAX_CNAME_HOPS: usize = 8;
+
+/// A transferred zone indexed for lookups.
+pub(super) struct ZoneData {
+    /// Apex in lowercase, without the trailing dot, for cheap suffix checks.
+    pub(super) apex_str: Arc<str>,
+    apex: Name,
+    soa: Record,
+    /// Records in transfer order, SOA first; the base for the next IXFR.
+    records: Vec<Record>,
+    nodes: HashMap<Name, Vec<Record>>,
+    /// Every owner name and each of its ancestors inside the zone, so empty
+    /// non-terminals answer NODATA instead of NXDOMAIN.
+    names: HashSet<Name>,
+}
+
+impl ZoneData {
+    /// Indexes `records`, which must start with the zone's SOA.
+    pub(super) fn new(apex: Name, records: Vec<Record>) -> Result<Self, DomainError> {
+        let soa = records
+            .first()
+            .filter(|r| r.record_type() == RecordType::SOA && r.name() == &apex)
+            .cloned()
+            .ok_or_else(|| transfer_error(&apex, "zone does not start with its SOA"))?;
+
+        let mut nodes: HashMap<Name, Vec<Record>> = HashMap::new();
+        let mut names = HashSet::new();
+        for record in &records {
+            if !apex.zone_of(record.name()) {
+                continue; // out-of-zone data is never served
+            }
+            let mut name = record.name().to_lowercase();
+            nodes.entry(name.clone()).or_default().push(record.clone());
+            while names.insert(name.clone()) && name != apex {
+                name = name.base_name();
+            }
+        }
+
+        let apex_str = apex.to_ascii().trim_end_matches('.').to_ascii_lowercase();
+        Ok(Self {
+            apex_str: Arc::from(apex_str.as_str()),
+            apex,
+            soa,
+            records,
+            nodes,
+            names,
+        })
+    }
+
+    /// Rebuilds the zone from its stored copy.
+    pub(super) fn from_snapshot(snapshot: &SecondaryZone) -> Result<Self, DomainError> {
+        let apex = zone_name(&snapshot.zone)?;
+        let records = snapshot
+            .records
+            .iter()
+            .map(|wire| {
+                Record::from_bytes(wire)
+                    .map_err(|e| transfer_error(&apex, &format!("stored record is invalid: {e}")))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::new(apex, records)
+    }
+
+    /// Stored copy of the zone, stamped with the given check times.
+    pub(super) fn to_snapshot(
+        &self,
+        transferred_at: i64,
+        checked_at: i64,
+    ) -> Result<SecondaryZone, DomainError> {
+        let (serial, refresh, retry, expire) = soa_timers(&self.soa);
+        let records = self
+            .records
+            .iter()
+            .map(|record| {
+                record
+                    .to_bytes()
+                    .map_err(|e| transfer_error(&self.apex, &format!("cannot encode record: {e}")))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(SecondaryZone {
+            zone: Arc::clone(&self.apex_str),
+            serial,
+            refresh,
+            retry,
+            expire,
+            transferred_at,
+            checked_at,
+            records,
+        })
+    }
+
+    pub(super) fn apex(&self) -> &Name {
+        &self.apex
+    }
+
+    pub(super) fn soa(&self) -> &Record {
+        &self.soa
+    }
+
+    pub(super) fn serial(&self) -> u32 {
+        soa_timers(&self.soa).0
+    }
+
+    pub(super) fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    /// Authoritative answer for `qname`, or `None` when it sits at or below
+    /// a delegation and belongs to another server.
+    pub(super) fn answer(&self, qname: &Name, qtype: RecordType) -> Option<DnsResolution> {
+        if self.is_delegated(qname) {
+            return None;
+        }
+        let mut message = response(qname, qtype);
+        message.set_authoritative(true);
+
+        let mut owner = qname.clone();
+        for _ in 0..MAX_CNAME_HOPS {
+            let Some(rrs) = self.node(&owner) else {
+                if message.answers().is_empty() {
+                    message.set_response_code(ResponseCode::NXDomain);
+                }
+                return Some(self.negative(message));
+            };
+
+            let matching: Vec<Record> = rrs
+                .iter()
+                .filter(|r| r.record_type() == qtype)
+                .map(|r| with_owner(r, &owner))
+                .collect();
+            if !matching.is_empty() {
+                message.add_answers(matching);
+                let ttl = message.answers().iter().map(Record::ttl).min();
+                return Some(resolution(&message, ttl));
+            }
+
+            let cname = rrs.iter().find_map(|r| match r.data() {
+                RData::CNAME(target) if qtype != RecordType::CNAME => Some((r, target.0.clone())),
+                _ => None,
+            });
+            let Some((record, target)) = cname else {
+                return Some(self.negative(message));
+            };
+            message.add_answer(with_owner(record, &owner));
+            if !self.apex.zone_of(&target) || self.is_delegated(&target) {
+                let ttl = message.answers().iter().map(Record::ttl).min();
+                return Some(resolution(&message, ttl));
+            }
+            owner = target;
+        }
+        let ttl = message.answers().iter().map(Record::ttl).min();
+        Some(resolution(&message, ttl))
+    }
+
+    /// Records owned by `name`, or by the wildcard covering it (RFC 4592).
+    /// `None` means the name does not exist.
+    fn node(&self, name: &Name) -> Option<&[Record]> {
+        if let Some(rrs) = self.nodes.get(name) {
+            return Some(rrs);
+        }
+        if self.names.contains(name) {
+            return Some(&[]); // empty non-terminal
+        }
+        let mut encloser = name.base_name();
+        while !self.names.contains(&encloser) {
+            if encloser.is_root() || !self.apex.zone_of(&encloser) {
+                return None;
+            }
+            encloser = encloser.base_name();
+        }
+        let wildcard = encloser.prepend_label("*").ok()?;
+        self.nodes.get(&wildcard).map(Vec::as_slice)
+    }
+
+    /// Whether a zone cut below the apex sits at or above `name`.
+    fn is_delegated(&self, name: &Name) -> bool {
+        let mut current = name.clone();
+        while current.num_labels() > self.apex.num_labels() {
+            if self
+                .nodes
+                .get(&current)
+                .is_some_and(|rrs| rrs.iter().any(|r| r.record_type() == RecordType::NS))
+            {
+                return true;
+            }
+            current = current.base_name();
+        }
+        false
+    }
+
+    /// NODATA or NXDOMAIN: the SOA goes in the authority section with the
+    /// negative-caching TTL of RFC 2308 §3.
+    fn negative(&self, mut message: Message) -> DnsResolution {
+        let minimum = match self.soa.data() {
+            RData::SOA(soa) => soa.minimum(),
+            _ => 0,
+        };
+        let negative_ttl = self.soa.ttl().min(minimum);
+        let mut soa = self.soa.clone();
+        soa.set_ttl(negative_ttl);
+        message.add_name_server(soa);
+        let mut resolution = resolution(&message, Some(negative_ttl));
+        resolution.negative_soa_ttl = Some(negative_ttl);
+        resolution
+    }
+}
+
+/// SERVFAIL for a query into a zone whose copy has expired (RFC 1035 §4.3.5).
+pub(super) fn servfail(qname: &Name, qtype: RecordType) -> DnsResolution {
+    let mut message = response(qname, qtype);
+    message.set_response_code(ResponseCode::ServFail);
+    resolution(&message, None)
+}
+
+fn response(qname: &Name, qtype: RecordType) -> Message {
+    let mut message = Message::new(0, MessageType::Response, OpCode::Query);
+    message.set_recursion_available(true);
+    message.add_query(Query::query(qname.clone(), qtype));
+    message
+}
+
+/// `record` renamed to `owner`, for answers synthesized from a wildcard.
+fn with_owner(record: &Record, owner: &Name) -> Record {
+    if record.name() == owner {
+        return record.clone();
+    }
+    Record::from_rdata(owner.clone(), record.ttl(), record.data().clone())
+}
+
+fn resolution(message: &Message, min_ttl: Option<u32>) -> DnsResolution {
+    let mut buf = Vec::with_capacity(512);
+    let mut encoder = BinEncoder::new(&mut buf);
+    if let Err(e) = message.emit(&mut encoder) {
+        warn!(error = %e, "Secondary zone: failed to encode answer");
+        buf.clear();
+    }
+    DnsResolution {
+        local_dns: true,
+        min_ttl,
+        upstream_wire_data: (!buf.is_empty()).then(|| Bytes::from(buf)),
+        ..DnsResolution::new(Vec::new(), false)
+    }
+}
+
+/// Serial, refresh, retry and expire of an SOA record, with negative
+/// timers read as zero.
+pub(super) fn soa_timers(soa: &Record) -> (u32, u32, u32, u32) {
+    match soa.data() {
+        RData::SOA(soa) => (
+            soa.serial(),
+            soa.refresh().max(0) as u32,
+            soa.retry().max(0) as u32,
+            soa.expire().max(0) as u32,
+        ),
+        _ => (0, 0, 0, 0),
+    }
+}
+
+/// Fully qualified, lowercase name of a zone apex.
+pub(super) fn zone_name(zone: &str) -> Result<Name, DomainError> {
+    Name::from_str(&format!("{}.", zone.trim_end_matches('.')))
+        .map(|name| name.to_lowercase())
+        .map_err(|e| DomainError::InvalidDomainName(format!("Invalid zone '{}': {}", zone, e)))
+}
+
+pub(super) fn transfer_error(zone: &Name, reason: &str) -> DomainError {
+    DomainError::IoError(format!("Zone transfer of {} failed: {}", zone, reason))
+}
@@ -1,43 +1,104 @@
+use crate::dns::access_control::AclVerdict;
+use crate::dns::chaos_identity::ChaosIdentity;
+use crate::dns::dynamic_update::DynamicUpdateHandler;
 use crate::dns::ede::{self, ExtendedDnsError};
+use crate::dns::edns_client_id::EdnsClientIdentifier;
 use crate::dns::forwarding::RecordTypeMapper;
+use crate::dns::listener::ListenerPolicy;
+use crate::dns::sinkhole::Sinkhole;
+use crate::dns::wire_response;
 use bytes::Bytes;
 use ferrous_dns_application::use_cases::HandleDnsQueryUseCase;
-use ferrous_dns_domain::{DomainError, RecordType};
+use ferrous_dns_domain::config::ResponseLimitsConfig;
+use ferrous_dns_domain::{DnsRcode, DnsRequest, DomainError, RecordType};
 use hickory_proto::op::{Edns, Message, MessageType, OpCode, ResponseCode};
 use hickory_proto::rr::rdata::opt::EdnsOption;
-use hickory_proto::rr::{RData, Record};
+use hickory_proto::rr::{DNSClass, RData, Record};
 use hickory_proto::serialize::binary::{BinEncodable, BinEncoder};
 use hickory_server::authority::MessageResponseBuilder;
 use hickory_server::server::{Request, RequestHandler, ResponseHandler, ResponseInfo};
-use std::borrow::Cow;
 use std::net::IpAddr;
 use std::sync::Arc;
 use tracing::{debug, error, warn};
 
 const DEFAULT_TTL: u32 = 60;
+/// TTL of the synthesized RFC 8482 answer to ANY queries.
+const MINIMAL_ANY_TTL: u32 = 3600;
 
 #[derive(Clone)]
 pub struct DnsServerHandler {
     use_case: Arc<HandleDnsQueryUseCase>,
+    listener: Arc<ListenerPolicy>,
+    sinkhole: Option<Arc<Sinkhole>>,
+    dynamic_updates: Option<Arc<DynamicUpdateHandler>>,
+    chaos_identity: Option<Arc<ChaosIdentity>>,
+    edns_client_id: Option<Arc<EdnsClientIdentifier>>,
+    max_answers: usize,
+    max_udp_response_size: u16,
 }
 
 impl DnsServerHandler {
     pub fn new(use_case: Arc<HandleDnsQueryUseCase>) -> Self {
-        Self { use_case }
+        Self {
+            use_case,
+            listener: Arc::new(ListenerPolicy::default()),
+            sinkhole: None,
+            dynamic_updates: None,
+            chaos_identity: None,
+            edns_client_id: None,
+            max_answers: usize::MAX,
+            max_udp_response_size: u16::MAX,
+        }
     }
 
-    /// Normalizes a domain received from Hickory for downstream use: strips the
-    /// trailing root dot and lowercases ASCII bytes (RFC 1035 §2.3.3 — DNS is
-    /// case-insensitive). Returns `Cow::Borrowed` when the trimmed slice is
-    /// already lowercase (zero-alloc fast path); otherwise owns a lowercased
-    /// copy.
-    fn normalize_domain(domain: &str) -> Cow<'_, str> {
-        let trimmed = domain.trim_end_matches('.');
-        if trimmed.bytes().all(|b| !b.is_ascii_uppercase()) {
-            Cow::Borrowed(trimmed)
-        } else {
-            Cow::Owned(trimmed.to_ascii_lowercase())
-        }
+    pub fn with_listener_policy(mut self, policy: ListenerPolicy) -> Self {
+        self.listener = Arc::new(policy);
+        self
+    }
+
+    /// Answers blocked queries from the sinkhole instead of with REFUSED.
+    pub fn with_sinkhole(mut self, sinkhole: Option<Arc<Sinkhole>>) -> Self {
+        self.sinkhole = sinkhole;
+        self
+    }
+
+    /// Accepts UPDATE messages for `dns.update_zones`; without a handler they
+    /// are answered with NOTIMP like any other unsupported opcode.
+    pub fn with_dynamic_updates(mut self, handler: Option<Arc<DynamicUpdateHandler>>) -> Self {
+        self.dynamic_updates = handler;
+        self
+    }
+
+    /// Answers CHAOS-class identity queries; without it they are refused.
+    pub fn with_chaos_identity(mut self, identity: Option<Arc<ChaosIdentity>>) -> Self {
+        self.chaos_identity = identity;
+        self
+    }
+
+    /// Attributes queries from trusted forwarders to the device named by
+    /// their EDNS MAC option; without it queries count for their source.
+    pub fn with_edns_client_id(mut self, identifier: Option<Arc<EdnsClientIdentifier>>) -> Self {
+        self.edns_client_id = identifier;
+        self
+    }
+
+    /// Caps the answers in every response and the size of UDP responses.
+    pub fn with_response_limits(mut self, limits: &ResponseLimitsConfig) -> Self {
+        self.max_answers = limits.max_answers;
+        self.max_udp_response_size = limits.max_udp_response_size;
+        self
+    }
+
+    /// Largest UDP response for a client that advertised `client_max_size`.
+    #[inline]
+    pub fn udp_payload_limit(&self, client_max_size: u16) -> u16 {
+        client_max_size.min(self.max_udp_response_size)
+    }
+
+    /// Whether the listener this handler serves accepts queries from `client_ip`.
+    #[inline]
+    pub fn allows_client(&self, client_ip: IpAddr) -> bool {
+        self.listener.allows(client_ip)
     }
 
     pub fn try_fast_path(
@@ -46,46 +107,222 @@ impl DnsServerHandler {
         record_type: RecordType,
         client_ip: IpAddr,
     ) -> Option<(Arc<Vec<IpAddr>>, u32)> {
-        self.use_case
-            .try_cache_direct(domain, record_type, client_ip)
+        if !self.listener.allows(client_ip) || self.is_tagging_forwarder(client_ip) {
+            return None;
+        }
+        let (addresses, ttl) = self.use_case.try_cache_direct(
+            domain,
+            record_type,
+            client_ip,
+            self.listener.default_group_id(),
+        )?;
+        if addresses.len() > self.max_answers {
+            let capped = addresses[..self.max_answers].to_vec();
+            return Some((Arc::new(capped), ttl));
+        }
+        Some((addresses, ttl))
     }
 
     /// Returns cached wire bytes for non-IP record types (NS, CNAME, SOA, PTR,
-    /// MX, TXT). The caller must patch the query ID before sending.
+    /// MX, TXT). The caller must patch the query ID before sending. Answers
+    /// over `max_size` bytes or the answer limit are left to the slow path,
+    /// which trims or truncates them.
     pub fn try_fast_path_wire(
         &self,
         domain: &str,
         record_type: RecordType,
         client_ip: IpAddr,
+        max_size: u16,
     ) -> Option<(Bytes, u32)> {
-        self.use_case
-            .try_cache_wire_direct(domain, record_type, client_ip)
+        if !self.listener.allows(client_ip) || self.is_tagging_forwarder(client_ip) {
+            return None;
+        }
+        let (wire, ttl) = self.use_case.try_cache_wire_direct(
+            domain,
+            record_type,
+            client_ip,
+            self.listener.default_group_id(),
+        )?;
+        if wire.len() > max_size as usize || self.exceeds_answer_limit(&wire) {
+            return None;
+        }
+        Some((wire, ttl))
+    }
+
+    /// Queries from forwarders that tag them with the requester's MAC must
+    /// reach the slow path, where EDNS options are read.
+    #[inline]
+    fn is_tagging_forwarder(&self, client_ip: IpAddr) -> bool {
+        self.edns_client_id
+            .as_ref()
+            .is_some_and(|id| id.is_forwarder(client_ip))
+    }
+
+    /// The client a query is attributed to: the device behind a trusted
+    /// forwarder when the query carries its MAC address or host subnet, else
+    /// `client_ip`.
+    fn requester_ip(&self, client_ip: IpAddr, edns: Option<&Edns>) -> IpAddr {
+        match (&self.edns_client_id, edns) {
+            (Some(identifier), Some(edns)) => {
+                identifier.identify(client_ip, edns.options().as_ref())
+            }
+            _ => client_ip,
+        }
+    }
+
+    #[inline]
+    fn exceeds_answer_limit(&self, wire: &[u8]) -> bool {
+        wire_response::answer_count(wire).is_some_and(|count| count as usize > self.max_answers)
+    }
+
+    /// Answers a query received over UDP. Responses larger than the client's
+    /// EDNS buffer or `max_udp_response_size` are replaced by an empty
+    /// truncated one so the client retries over TCP.
+    pub async fn handle_udp_query(&self, raw: &[u8], client_ip: IpAddr) -> Option<Vec<u8>> {
+        let response = self.handle_raw_udp_fallback(raw, client_ip).await?;
+        if response.len() <= 512 {
+            return Some(response);
+        }
+        let query = Message::from_vec(raw).ok()?;
+        let client_max_size = query
+            .extensions()
+            .as_ref()
+            .map_or(512, |edns| edns.max_payload().max(512));
+        if response.len() <= self.udp_payload_limit(client_max_size) as usize {
+            return Some(response);
+        }
+        debug!(
+            client = %client_ip,
+            size = response.len(),
+            "Response exceeds UDP size limit, sending truncated"
+        );
+        build_truncated_wire(query.id(), query.recursion_desired(), query.queries())
+    }
+
+    fn chaos_answer(
+        &self,
+        name: &hickory_proto::rr::Name,
+        record_type: hickory_proto::rr::RecordType,
+    ) -> Option<Record> {
+        self.chaos_identity
+            .as_ref()
+            .and_then(|identity| identity.answer(name, record_type))
     }
 
     pub async fn handle_raw_udp_fallback(&self, raw: &[u8], client_ip: IpAddr) -> Option<Vec<u8>> {
+        let verdict = self.listener.check(client_ip);
+        if verdict == AclVerdict::Drop {
+            debug!(client = %client_ip, "Dropping query from client not allowed on this listener");
+            return None;
+        }
+
         let query_msg = Message::from_vec(raw).ok()?;
 
+        if query_msg.op_code() == OpCode::Update {
+            if let Some(ref updates) = self.dynamic_updates {
+                if verdict == AclVerdict::Refuse {
+                    debug!(client = %client_ip, "Client not allowed on this listener");
+                    return DynamicUpdateHandler::refused(raw);
+                }
+                return updates.handle(raw, client_ip).await;
+            }
+        }
+
         let queries: Vec<_> = query_msg.queries().to_vec();
-        let query_info = queries.first()?;
+        let query_id = query_msg.id();
+        let rd = query_msg.recursion_desired();
+        let has_edns = query_msg.extensions().is_some();
+
+        let rejection = if query_msg.op_code() != OpCode::Query {
+            Some(DomainError::UnsupportedQuery(format!(
+                "opcode {:?}",
+                query_msg.op_code()
+            )))
+        } else if queries.len() != 1 {
+            Some(DomainError::MalformedQuery(format!(
+                "expected one question, got {}",
+                queries.len()
+            )))
+        } else {
+            None
+        };
+        if let Some(e) = rejection {
+            debug!(client = %client_ip, error = %e, "Rejecting query");
+            return build_error_wire(
+                query_id,
+                rd,
+                &queries,
+                response_code(e.rcode()),
+                has_edns,
+                ede::from_domain_error(&e),
+            );
+        }
+        let query_info = &queries[0];
 
-        let domain_name = query_info.name().to_utf8();
-        let domain_cow = Self::normalize_domain(&domain_name);
+        let domain_name = query_info.name().to_ascii();
+        // The ASCII (punycode) form, so IDN queries match the wire fast path
+        // and the blocklists.
+        let domain_cow = DnsRequest::normalize_domain(&domain_name);
         let domain: &str = domain_cow.as_ref();
         let hickory_rt = query_info.query_type();
 
-        let our_rt = RecordTypeMapper::from_hickory(hickory_rt)?;
+        let Some(our_rt) = RecordTypeMapper::from_hickory(hickory_rt) else {
+            let e = DomainError::UnsupportedQuery(format!("record type {}", hickory_rt));
+            debug!(client = %client_ip, error = %e, "Rejecting query");
+            return build_error_wire(
+                query_id,
+                rd,
+                &queries,
+                response_code(e.rcode()),
+                has_edns,
+                ede::from_domain_error(&e),
+            );
+        };
 
-        let query_id = query_msg.id();
-        let rd = query_msg.recursion_desired();
-        let has_edns = query_msg.extensions().is_some();
         let edns_cookie: Option<Vec<u8>> = query_msg
             .extensions()
             .as_ref()
             .and_then(|edns| extract_edns_cookie(edns.options().as_ref().iter()));
+        let requester_ip = self.requester_ip(client_ip, query_msg.extensions().as_ref());
         drop(query_msg);
 
+        if verdict == AclVerdict::Refuse {
+            debug!(client = %client_ip, "Client not allowed on this listener");
+            return build_error_wire(
+                query_id,
+                rd,
+                &queries,
+                response_code(DomainError::ClientNotAllowed.rcode()),
+                has_edns,
+                ede::from_domain_error(&DomainError::ClientNotAllowed),
+            );
+        }
+
+        if query_info.query_class() == DNSClass::CH {
+            return match self.chaos_answer(query_info.name(), hickory_rt) {
+                Some(answer) => build_wire(
+                    query_id,
+                    rd,
+                    &queries,
+                    ResponseCode::NoError,
+                    vec![answer],
+                    has_edns,
+                    None,
+                ),
+                None => build_error_wire(
+                    query_id,
+                    rd,
+                    &queries,
+                    ResponseCode::Refused,
+                    has_edns,
+                    None,
+                ),
+            };
+        }
+
         let dns_request = {
-            let base = ferrous_dns_domain::DnsRequest::new(domain, our_rt, client_ip);
+            let base = DnsRequest::new(domain, our_rt, requester_ip)
+                .with_listener_group(self.listener.default_group_id());
             if let Some(c) = edns_cookie {
                 base.with_cookie(c)
             } else {
@@ -95,52 +332,44 @@ impl DnsServerHandler {
 
         let resolution = match self.use_case.execute(&dns_request).await {
             Ok(res) => res,
-            Err(ref e @ DomainError::Blocked)
-            | Err(ref e @ DomainError::DgaDomainDetected)
-            | Err(ref e @ DomainError::DnsTunnelingDetected)
-            | Err(ref e @ DomainError::DnsRateLimited)
-            | Err(ref e @ DomainError::FilteredQuery(_)) => {
-                return build_error_wire(
+            Err(ref e @ DomainError::Blocked(source)) if self.sinkhole.is_some() => {
+                let sinkhole = self.sinkhole.as_deref()?;
+                sinkhole.record_answer(requester_ip, domain, source);
+                let answers = sinkhole.answer(query_info.name(), hickory_rt);
+                return build_wire(
                     query_id,
                     rd,
                     &queries,
-                    ResponseCode::Refused,
-                    has_edns,
-                    ede::from_domain_error(e),
-                )
-            }
-            Err(ref e @ DomainError::DnsCookieInvalid) => {
-                return build_error_wire(
-                    query_id,
-                    rd,
-                    &queries,
-                    ResponseCode::Refused,
+                    ResponseCode::NoError,
+                    answers,
                     has_edns,
                     ede::from_domain_error(e),
-                )
+                );
             }
             Err(DomainError::DnsRateLimitedSlip) => {
                 return build_truncated_wire(query_id, rd, &queries)
             }
-            Err(DomainError::NxDomain) | Err(DomainError::LocalNxDomain) => {
-                return build_error_wire(
+            Err(DomainError::AnyQueryMinimized) => {
+                return build_wire(
                     query_id,
                     rd,
                     &queries,
-                    ResponseCode::NXDomain,
+                    ResponseCode::NoError,
+                    vec![minimal_any_answer(query_info.name().clone())],
                     has_edns,
                     None,
-                )
+                );
             }
             Err(ref e) => {
+                log_resolution_error(e, domain, client_ip);
                 return build_error_wire(
                     query_id,
                     rd,
                     &queries,
-                    ResponseCode::ServFail,
+                    response_code(e.rcode()),
                     has_edns,
                     ede::from_domain_error(e),
-                )
+                );
             }
         };
 
@@ -161,11 +390,11 @@ impl DnsServerHandler {
                     .as_ref()
                     .is_some_and(|c| c.len() >= 8);
 
-                if has_cookie_to_inject {
+                if has_cookie_to_inject || self.exceeds_answer_limit(wire_data) {
                     match Message::from_vec(wire_data) {
                         Ok(upstream_msg) => {
                             resp.set_response_code(upstream_msg.response_code());
-                            for record in upstream_msg.answers() {
+                            for record in upstream_msg.answers().iter().take(self.max_answers) {
                                 resp.add_answer(record.clone());
                             }
                             for record in upstream_msg.name_servers() {
@@ -190,7 +419,7 @@ impl DnsServerHandler {
                         }
                     }
                 } else {
-                    // no cookie to inject — return raw bytes (fast path unchanged)
+                    // nothing to inject or trim — return raw bytes (fast path unchanged)
                     let mut response = wire_data.to_vec();
                     if response.len() >= 2 {
                         response[0] = (query_id >> 8) as u8;
@@ -201,7 +430,7 @@ impl DnsServerHandler {
             }
         } else {
             let record_name = query_info.name().clone();
-            for addr in addresses.iter() {
+            for addr in addresses.iter().take(self.max_answers) {
                 let rdata = match *addr {
                     IpAddr::V4(ipv4) => RData::A(hickory_proto::rr::rdata::A(ipv4)),
                     IpAddr::V6(ipv6) => RData::AAAA(hickory_proto::rr::rdata::AAAA(ipv6)),
@@ -219,7 +448,7 @@ impl DnsServerHandler {
                 let server_cookie = self
                     .use_case
                     .cookie_guard()
-                    .generate_server_cookie(client_ip, &client_cookie);
+                    .generate_server_cookie(dns_request.client_ip, &client_cookie);
                 let mut opt_data = Vec::with_capacity(16);
                 opt_data.extend_from_slice(&raw[..8]);
                 opt_data.extend_from_slice(&server_cookie);
@@ -244,20 +473,21 @@ impl RequestHandler for DnsServerHandler {
         let request_info = match request.request_info() {
             Ok(info) => info,
             Err(e) => {
-                error!(error = %e, "Failed to parse request info");
+                let e = DomainError::MalformedQuery(e.to_string());
+                debug!(error = %e, "Failed to parse request info");
                 return send_error_response(
                     request,
                     &mut response_handle,
-                    ResponseCode::FormErr,
-                    None,
+                    response_code(e.rcode()),
+                    ede::from_domain_error(&e),
                 )
                 .await;
             }
         };
 
         let query = &request_info.query;
-        let raw_domain = query.name().to_utf8();
-        let domain_cow = Self::normalize_domain(&raw_domain);
+        let raw_domain = query.name().to_ascii();
+        let domain_cow = DnsRequest::normalize_domain(&raw_domain);
         let domain: &str = domain_cow.as_ref();
         let hickory_record_type = query.query_type();
         let client_ip = request.src().ip();
@@ -267,23 +497,63 @@ impl RequestHandler for DnsServerHandler {
         let our_record_type = match RecordTypeMapper::from_hickory(hickory_record_type) {
             Some(rt) => rt,
             None => {
-                warn!(record_type = ?hickory_record_type, "Unsupported record type");
+                let e =
+                    DomainError::UnsupportedQuery(format!("record type {}", hickory_record_type));
+                warn!(error = %e, "Unsupported record type");
                 return send_error_response(
                     request,
                     &mut response_handle,
-                    ResponseCode::NotImp,
-                    None,
+                    response_code(e.rcode()),
+                    ede::from_domain_error(&e),
                 )
                 .await;
             }
         };
 
+        let verdict = self.listener.check(client_ip);
+        if verdict == AclVerdict::Drop {
+            debug!(client = %client_ip, "Dropping query from client not allowed on this listener");
+            return ResponseInfo::from(*request.header());
+        }
+        if verdict == AclVerdict::Refuse {
+            debug!(client = %client_ip, "Client not allowed on this listener");
+            return send_error_response(
+                request,
+                &mut response_handle,
+                response_code(DomainError::ClientNotAllowed.rcode()),
+                ede::from_domain_error(&DomainError::ClientNotAllowed),
+            )
+            .await;
+        }
+
+        if query.query_class() == DNSClass::CH {
+            debug!(domain = %domain, client = %client_ip, "CHAOS identity query");
+            return match self.chaos_answer(&query.name().clone().into(), hickory_record_type) {
+                Some(answer) => {
+                    send_answer_response(
+                        request,
+                        &mut response_handle,
+                        ResponseCode::NoError,
+                        &[answer],
+                        None,
+                    )
+                    .await
+                }
+                None => {
+                    send_error_response(request, &mut response_handle, ResponseCode::Refused, None)
+                        .await
+                }
+            };
+        }
+
         let edns_cookie: Option<Vec<u8>> = request
             .edns()
             .and_then(|edns| extract_edns_cookie(edns.options().as_ref().iter()));
+        let requester_ip = self.requester_ip(client_ip, request.edns());
 
         let dns_request = {
-            let base = ferrous_dns_domain::DnsRequest::new(domain, our_record_type, client_ip);
+            let base = DnsRequest::new(domain, our_record_type, requester_ip)
+                .with_listener_group(self.listener.default_group_id());
             if let Some(c) = edns_cookie {
                 base.with_cookie(c)
             } else {
@@ -294,62 +564,25 @@ impl RequestHandler for DnsServerHandler {
 
         let resolution = match self.use_case.execute(&dns_request).await {
             Ok(res) => res,
-            Err(ref e @ DomainError::Blocked) => {
-                warn!(domain = %domain_ref, "Domain blocked");
-                return send_error_response(
-                    request,
-                    &mut response_handle,
-                    ResponseCode::Refused,
-                    ede::from_domain_error(e),
-                )
-                .await;
-            }
-            Err(ref e @ DomainError::DnsTunnelingDetected) => {
-                debug!(domain = %domain_ref, client = %client_ip, "DNS tunneling detected");
-                return send_error_response(
-                    request,
-                    &mut response_handle,
-                    ResponseCode::Refused,
-                    ede::from_domain_error(e),
-                )
-                .await;
-            }
-            Err(ref e @ DomainError::DnsRateLimited) => {
-                debug!(domain = %domain_ref, client = %client_ip, "Rate limited");
-                return send_error_response(
-                    request,
-                    &mut response_handle,
-                    ResponseCode::Refused,
-                    ede::from_domain_error(e),
-                )
-                .await;
-            }
-            Err(ref e @ DomainError::DgaDomainDetected) => {
-                debug!(domain = %domain_ref, client = %client_ip, "DGA domain detected");
-                return send_error_response(
-                    request,
-                    &mut response_handle,
-                    ResponseCode::Refused,
-                    ede::from_domain_error(e),
-                )
-                .await;
-            }
-            Err(ref e @ DomainError::FilteredQuery(ref reason)) => {
-                debug!(domain = %domain_ref, reason = %reason, "Query filtered by policy");
-                return send_error_response(
-                    request,
-                    &mut response_handle,
-                    ResponseCode::Refused,
-                    ede::from_domain_error(e),
-                )
-                .await;
-            }
-            Err(ref e @ DomainError::DnsCookieInvalid) => {
-                debug!(domain = %domain_ref, client = %client_ip, "DNS cookie invalid");
+            Err(ref e @ DomainError::Blocked(source)) => {
+                warn!(domain = %domain_ref, source = %source, "Domain blocked");
+                if let Some(sinkhole) = &self.sinkhole {
+                    sinkhole.record_answer(requester_ip, domain_ref, source);
+                    let answers =
+                        sinkhole.answer(&query.name().clone().into(), hickory_record_type);
+                    return send_answer_response(
+                        request,
+                        &mut response_handle,
+                        ResponseCode::NoError,
+                        &answers,
+                        ede::from_domain_error(e),
+                    )
+                    .await;
+                }
                 return send_error_response(
                     request,
                     &mut response_handle,
-                    ResponseCode::Refused,
+                    response_code(e.rcode()),
                     ede::from_domain_error(e),
                 )
                 .await;
@@ -358,21 +591,23 @@ impl RequestHandler for DnsServerHandler {
                 debug!(domain = %domain_ref, client = %client_ip, "Rate limited (TC=1 slip)");
                 return send_truncated_response(request, &mut response_handle).await;
             }
-            Err(DomainError::NxDomain) | Err(DomainError::LocalNxDomain) => {
-                return send_error_response(
+            Err(DomainError::AnyQueryMinimized) => {
+                debug!(domain = %domain_ref, client = %client_ip, "Answering ANY query with HINFO (RFC 8482)");
+                return send_answer_response(
                     request,
                     &mut response_handle,
-                    ResponseCode::NXDomain,
+                    ResponseCode::NoError,
+                    &[minimal_any_answer(query.name().clone().into())],
                     None,
                 )
                 .await;
             }
             Err(e) => {
-                error!(error = %e, "Query resolution failed");
+                log_resolution_error(&e, domain_ref, client_ip);
                 return send_error_response(
                     request,
                     &mut response_handle,
-                    ResponseCode::ServFail,
+                    response_code(e.rcode()),
                     ede::from_domain_error(&e),
                 )
                 .await;
@@ -385,12 +620,18 @@ impl RequestHandler for DnsServerHandler {
         if addresses.is_empty() {
             if let Some(ref wire_data) = resolution.upstream_wire_data {
                 if let Ok(message) = Message::from_vec(wire_data) {
-                    let answers: Vec<Record> = message.answers().to_vec();
+                    let answers: Vec<Record> = message
+                        .answers()
+                        .iter()
+                        .take(self.max_answers)
+                        .cloned()
+                        .collect();
                     let authority: Vec<Record> = message.name_servers().to_vec();
                     let additional: Vec<Record> = message.additionals().to_vec();
                     let builder = MessageResponseBuilder::from_message_request(request);
                     let mut header = *request.header();
                     header.set_message_type(MessageType::Response);
+                    header.set_response_code(message.response_code());
                     header.set_recursion_available(true);
                     let response = builder.build(
                         header,
@@ -426,8 +667,8 @@ impl RequestHandler for DnsServerHandler {
         let record_name: hickory_proto::rr::Name = query.name().clone().into();
 
         let builder = MessageResponseBuilder::from_message_request(request);
-        let mut answers = Vec::with_capacity(addresses.len());
-        for addr in addresses.iter() {
+        let mut answers = Vec::with_capacity(addresses.len().min(self.max_answers));
+        for addr in addresses.iter().take(self.max_answers) {
             let rdata = match *addr {
                 IpAddr::V4(ipv4) => RData::A(hickory_proto::rr::rdata::A(ipv4)),
                 IpAddr::V6(ipv6) => RData::AAAA(hickory_proto::rr::rdata::AAAA(ipv6)),
@@ -435,7 +676,7 @@ impl RequestHandler for DnsServerHandler {
             answers.push(Record::from_rdata(record_name.clone(), ttl, rdata));
         }
 
-        debug!(domain = %domain_ref, answers = addresses.len(), "Sending response");
+        debug!(domain = %domain_ref, answers = answers.len(), "Sending response");
         let mut header = *request.header();
         header.set_message_type(MessageType::Response);
         header.set_recursion_available(true);
@@ -450,6 +691,32 @@ impl RequestHandler for DnsServerHandler {
     }
 }
 
+/// Maps the domain-level RCODE onto hickory's header value.
+pub(crate) fn response_code(rcode: DnsRcode) -> ResponseCode {
+    match rcode {
+        DnsRcode::FormErr => ResponseCode::FormErr,
+        DnsRcode::ServFail => ResponseCode::ServFail,
+        DnsRcode::NxDomain => ResponseCode::NXDomain,
+        DnsRcode::NotImp => ResponseCode::NotImp,
+        DnsRcode::Refused => ResponseCode::Refused,
+        DnsRcode::YxDomain => ResponseCode::YXDomain,
+        DnsRcode::YxRrset => ResponseCode::YXRRSet,
+        DnsRcode::NxRrset => ResponseCode::NXRRSet,
+        DnsRcode::NotAuth => ResponseCode::NotAuth,
+        DnsRcode::NotZone => ResponseCode::NotZone,
+    }
+}
+
+fn log_resolution_error(err: &DomainError, domain: &str, client_ip: IpAddr) {
+    match err.rcode() {
+        DnsRcode::NxDomain => {}
+        DnsRcode::ServFail => error!(domain = %domain, error = %err, "Query resolution failed"),
+        rcode => {
+            debug!(domain = %domain, client = %client_ip, rcode = rcode.as_str(), reason = %err, "Query rejected")
+        }
+    }
+}
+
 /// Extracts the raw EDNS option-10 (DNS Cookie, RFC 7873) bytes from an
 /// iterator over EDNS options. Returns `None` when no cookie option is present.
 fn extract_edns_cookie<'a>(
@@ -464,6 +731,13 @@ fn extract_edns_cookie<'a>(
     })
 }
 
+/// The single HINFO record RFC 8482 §4.2 suggests in place of a full ANY
+/// answer.
+fn minimal_any_answer(name: hickory_proto::rr::Name) -> Record {
+    let hinfo = hickory_proto::rr::rdata::HINFO::new("RFC8482".to_string(), String::new());
+    Record::from_rdata(name, MINIMAL_ANY_TTL, RData::HINFO(hinfo))
+}
+
 fn encode_message(msg: &Message) -> Option<Vec<u8>> {
     let mut buf = Vec::with_capacity(512);
     let mut encoder = BinEncoder::new(&mut buf);
@@ -478,6 +752,18 @@ fn build_error_wire(
     code: ResponseCode,
     has_edns: bool,
     ede: Option<ExtendedDnsError>,
+) -> Option<Vec<u8>> {
+    build_wire(id, rd, queries, code, Vec::new(), has_edns, ede)
+}
+
+fn build_wire(
+    id: u16,
+    rd: bool,
+    queries: &[hickory_proto::op::Query],
+    code: ResponseCode,
+    answers: Vec<Record>,
+    has_edns: bool,
+    ede: Option<ExtendedDnsError>,
 ) -> Option<Vec<u8>> {
     let mut resp = Message::new(id, MessageType::Response, OpCode::Query);
     resp.set_recursion_desired(rd);
@@ -486,6 +772,7 @@ fn build_error_wire(
     for q in queries {
         resp.add_query(q.clone());
     }
+    resp.add_answers(answers);
     if has_edns {
         let mut edns = Edns::new();
         edns.set_max_payload(4096);
@@ -546,7 +833,17 @@ async fn send_error_response<R: ResponseHandler>(
     code: ResponseCode,
     ede: Option<ExtendedDnsError>,
 ) -> ResponseInfo {
-    debug!(code = ?code, "Sending error response");
+    send_answer_response(request, response_handle, code, &[], ede).await
+}
+
+async fn send_answer_response<R: ResponseHandler>(
+    request: &Request,
+    response_handle: &mut R,
+    code: ResponseCode,
+    answers: &[Record],
+    ede: Option<ExtendedDnsError>,
+) -> ResponseInfo {
+    debug!(code = ?code, answers = answers.len(), "Sending response");
     let mut builder = MessageResponseBuilder::from_message_request(request);
     let mut header = *request.header();
     header.set_message_type(MessageType::Response);
@@ -569,7 +866,7 @@ async fn send_error_response<R: ResponseHandler>(
         }
     }
 
-    let response = builder.build(header, &[], &[], &[], &[]);
+    let response = builder.build(header, answers.iter(), &[], &[], &[]);
     match response_handle.send_response(response).await {
         Ok(info) => info,
         Err(e) => {
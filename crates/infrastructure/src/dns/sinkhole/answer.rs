@@ -0,0 +1,74 @@
+use super::recent_blocks::RecentBlocks;
+use super::telemetry::SinkholeTelemetry;
+use ferrous_dns_domain::{BlockSource, BlockingConfig, BlockingMode};
+use hickory_proto::rr::rdata::{A, AAAA};
+use hickory_proto::rr::{Name, RData, Record, RecordType};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::sync::Arc;
+
+/// Answers blocked queries with the configured sinkhole addresses instead of
+/// REFUSED.
+pub struct Sinkhole {
+    ipv4: Option<Ipv4Addr>,
+    ipv6: Option<Ipv6Addr>,
+    ttl: u32,
+    telemetry: Option<Arc<SinkholeTelemetry>>,
+    recent_blocks: Option<Arc<RecentBlocks>>,
+}
+
+impl Sinkhole {
+    /// Returns `None` unless blocking is in sinkhole mode.
+    pub fn from_config(config: &BlockingConfig) -> Option<Self> {
+        (config.mode == BlockingMode::Sinkhole).then(|| Self {
+            ipv4: config.sinkhole.ipv4,
+            ipv6: config.sinkhole.ipv6,
+            ttl: config.sinkhole.ttl,
+            telemetry: None,
+            recent_blocks: None,
+        })
+    }
+
+    /// Remembers every sinkhole answer so later connection attempts can be
+    /// matched to the blocked query.
+    pub fn with_telemetry(mut self, telemetry: Arc<SinkholeTelemetry>) -> Self {
+        self.telemetry = Some(telemetry);
+        self
+    }
+
+    /// Remembers why each sinkhole answer was given, for the block page.
+    pub fn with_recent_blocks(mut self, recent_blocks: Arc<RecentBlocks>) -> Self {
+        self.recent_blocks = Some(recent_blocks);
+        self
+    }
+
+    /// Answer records for a blocked query; empty (NODATA) for record types
+    /// without a sinkhole address.
+    pub fn answer(&self, name: &Name, record_type: RecordType) -> Vec<Record> {
+        let rdata = match record_type {
+            RecordType::A => self.ipv4.map(|ip| RData::A(A(ip))),
+            RecordType::AAAA => self.ipv6.map(|ip| RData::AAAA(AAAA(ip))),
+            _ => None,
+        };
+        rdata
+            .map(|rdata| vec![Record::from_rdata(name.clone(), self.ttl, rdata)])
+            .unwrap_or_default()
+    }
+
+    pub fn record_answer(&self, client: IpAddr, domain: &str, source: BlockSource) {
+        if let Some(telemetry) = &self.telemetry {
+            telemetry.record_blocked_answer(client, domain);
+        }
+        if let Some(recent_blocks) = &self.recent_blocks {
+            recent_blocks.record(client, domain, source);
+        }
+    }
+
+    /// Addresses the telemetry responder and the block page bind to.
+    pub fn addresses(&self) -> Vec<IpAddr> {
+        self.ipv4
+            .map(IpAddr::V4)
+            .into_iter()
+            .chain(self.ipv6.map(IpAddr::V6))
+            .collect()
+    }
+}
@@ -0,0 +1,9 @@
+pub mod answer;
+pub mod recent_blocks;
+pub mod sniff;
+pub mod telemetry;
+
+pub use answer::Sinkhole;
+pub use recent_blocks::RecentBlocks;
+pub use sniff::{inspect_connection, SinkholeProtocol};
+pub use telemetry::SinkholeTelemetry;
@@ -0,0 +1,50 @@
+use dashmap::DashMap;
+use ferrous_dns_domain::BlockSource;
+use rustc_hash::FxBuildHasher;
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+const MAX_ENTRIES: usize = 65_536;
+
+/// How long the reason for a sinkhole answer is kept for the block page.
+const RETENTION: Duration = Duration::from_secs(3600);
+
+/// Why each client was recently given a sinkhole answer, so the block page
+/// can tell a browser that lands on the sinkhole what blocked the site.
+#[derive(Default)]
+pub struct RecentBlocks {
+    entries: DashMap<(IpAddr, Arc<str>), (BlockSource, Instant), FxBuildHasher>,
+}
+
+impl RecentBlocks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&self, client: IpAddr, domain: &str, source: BlockSource) {
+        let now = Instant::now();
+        if self.entries.len() >= MAX_ENTRIES {
+            self.entries
+                .retain(|_, (_, at)| now.duration_since(*at) < RETENTION);
+            if self.entries.len() >= MAX_ENTRIES {
+                self.entries.clear();
+            }
+        }
+        let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+        self.entries
+            .insert((client, Arc::from(domain)), (source, now));
+    }
+
+    /// Source of the block `client` got for `host` within the last hour.
+    pub fn source(&self, client: IpAddr, host: &str) -> Option<BlockSource> {
+        let key = (
+            client,
+            Arc::from(host.trim_end_matches('.').to_ascii_lowercase()),
+        );
+        self.entries
+            .get(&key)
+            .filter(|entry| entry.1.elapsed() < RETENTION)
+            .map(|entry| entry.0)
+    }
+}
@@ -0,0 +1,168 @@
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+const MAX_HEAD_BYTES: usize = 16 * 1024;
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+const TLS_HANDSHAKE: u8 = 0x16;
+const TLS_CLIENT_HELLO: u8 = 0x01;
+const TLS_EXT_SERVER_NAME: u16 = 0x0000;
+const HTTP_NO_CONTENT: &[u8] =
+    b"HTTP/1.1 204 No Content\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SinkholeProtocol {
+    Http,
+    Https,
+}
+
+/// Reads the start of a connection to the sinkhole and returns the host the
+/// client wanted: the `Host` header for HTTP, the SNI for HTTPS. HTTP clients
+/// get an empty `204`; TLS handshakes are abandoned since there is no
+/// certificate to present.
+pub async fn inspect_connection<S>(stream: &mut S, protocol: SinkholeProtocol) -> Option<String>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let mut buf = Vec::with_capacity(2048);
+    let host = tokio::time::timeout(READ_TIMEOUT, async {
+        loop {
+            if buf.len() >= MAX_HEAD_BYTES {
+                return None;
+            }
+            let mut chunk = [0u8; 2048];
+            let n = stream.read(&mut chunk).await.ok()?;
+            if n == 0 {
+                return None;
+            }
+            buf.extend_from_slice(&chunk[..n]);
+            let complete = match protocol {
+                SinkholeProtocol::Http => buf.windows(4).any(|w| w == b"\r\n\r\n"),
+                SinkholeProtocol::Https => tls_record_complete(&buf),
+            };
+            if complete {
+                return match protocol {
+                    SinkholeProtocol::Http => http_host(&buf),
+                    SinkholeProtocol::Https => tls_sni(&buf),
+                };
+            }
+        }
+    })
+    .await
+    .ok()
+    .flatten();
+
+    if protocol == SinkholeProtocol::Http && !buf.is_empty() {
+        let _ = stream.write_all(HTTP_NO_CONTENT).await;
+        let _ = stream.shutdown().await;
+    }
+    host
+}
+
+/// Extracts the `Host` header from an HTTP/1.x request head, without port.
+pub fn http_host(head: &[u8]) -> Option<String> {
+    let head = std::str::from_utf8(head).ok()?;
+    let value = head.split("\r\n").skip(1).find_map(|line| {
+        let (name, value) = line.split_once(':')?;
+        name.trim()
+            .eq_ignore_ascii_case("host")
+            .then(|| value.trim())
+    })?;
+    let host = if let Some(rest) = value.strip_prefix('[') {
+        rest.split_once(']')?.0
+    } else {
+        value.split(':').next()?
+    };
+    normalize_host(host)
+}
+
+/// Extracts the `server_name` extension from a TLS ClientHello record.
+pub fn tls_sni(record: &[u8]) -> Option<String> {
+    let mut r = Reader::new(record);
+    if r.u8()? != TLS_HANDSHAKE {
+        return None;
+    }
+    r.skip(2)?;
+    let record_len = r.u16()? as usize;
+    let mut r = Reader::new(r.take(record_len.min(r.remaining()))?);
+
+    if r.u8()? != TLS_CLIENT_HELLO {
+        return None;
+    }
+    r.skip(3 + 2 + 32)?;
+    let session_id_len = r.u8()? as usize;
+    r.skip(session_id_len)?;
+    let cipher_suites_len = r.u16()? as usize;
+    r.skip(cipher_suites_len)?;
+    let compression_len = r.u8()? as usize;
+    r.skip(compression_len)?;
+
+    let extensions_len = r.u16()? as usize;
+    let mut exts = Reader::new(r.take(extensions_len)?);
+    while exts.remaining() >= 4 {
+        let ext_type = exts.u16()?;
+        let ext_len = exts.u16()? as usize;
+        let data = exts.take(ext_len)?;
+        if ext_type != TLS_EXT_SERVER_NAME {
+            continue;
+        }
+        let mut sni = Reader::new(data);
+        let list_len = sni.u16()? as usize;
+        let mut list = Reader::new(sni.take(list_len)?);
+        while list.remaining() >= 3 {
+            let name_type = list.u8()?;
+            let name_len = list.u16()? as usize;
+            let name = list.take(name_len)?;
+            if name_type == 0 {
+                return normalize_host(std::str::from_utf8(name).ok()?);
+            }
+        }
+    }
+    None
+}
+
+fn tls_record_complete(buf: &[u8]) -> bool {
+    if buf.first() != Some(&TLS_HANDSHAKE) {
+        return !buf.is_empty();
+    }
+    buf.len() >= 5 && buf.len() >= 5 + u16::from_be_bytes([buf[3], buf[4]]) as usize
+}
+
+fn normalize_host(host: &str) -> Option<String> {
+    let host = host.trim().trim_end_matches('.');
+    (!host.is_empty()).then(|| host.to_ascii_lowercase())
+}
+
+struct Reader<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        Self { buf }
+    }
+
+    fn remaining(&self) -> usize {
+        self.buf.len()
+    }
+
+    fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+        if n > self.buf.len() {
+            return None;
+        }
+        let (head, tail) = self.buf.split_at(n);
+        self.buf = tail;
+        Some(head)
+    }
+
+    fn skip(&mut self, n: usize) -> Option<()> {
+        self.take(n).map(|_| ())
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.take(1).map(|b| b[0])
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        self.take(2).map(|b| u16::from_be_bytes([b[0], b[1]]))
+    }
+}
@@ -0,0 +1,134 @@
+use super::sniff::SinkholeProtocol;
+use chrono::{DateTime, Duration, Utc};
+use dashmap::DashMap;
+use ferrous_dns_application::ports::{SinkholeHostStats, SinkholeTelemetryPort};
+use rustc_hash::FxBuildHasher;
+use std::collections::HashSet;
+use std::net::IpAddr;
+use std::sync::Arc;
+use tracing::{debug, info};
+
+const MAX_BLOCKED_ANSWERS: usize = 65_536;
+const MAX_HOSTS: usize = 4_096;
+const MAX_CLIENTS_PER_HOST: usize = 1_024;
+
+/// A connection attempt counts as following a blocked query when the same
+/// client got a sinkhole answer for the host within this window.
+fn correlation_window() -> Duration {
+    Duration::hours(1)
+}
+
+struct HostEntry {
+    http_attempts: u64,
+    https_attempts: u64,
+    correlated_attempts: u64,
+    clients: HashSet<IpAddr>,
+    last_client: IpAddr,
+    last_attempt_at: DateTime<Utc>,
+    last_blocked_query_at: Option<DateTime<Utc>>,
+}
+
+/// Records sinkhole answers handed out by the DNS server and the connections
+/// clients open to the sinkhole afterwards.
+#[derive(Default)]
+pub struct SinkholeTelemetry {
+    blocked_answers: DashMap<(IpAddr, Arc<str>), DateTime<Utc>, FxBuildHasher>,
+    hosts: DashMap<Arc<str>, HostEntry, FxBuildHasher>,
+}
+
+impl SinkholeTelemetry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record_blocked_answer(&self, client: IpAddr, domain: &str) {
+        let now = Utc::now();
+        if self.blocked_answers.len() >= MAX_BLOCKED_ANSWERS {
+            let cutoff = now - correlation_window();
+            self.blocked_answers.retain(|_, at| *at > cutoff);
+            if self.blocked_answers.len() >= MAX_BLOCKED_ANSWERS {
+                self.blocked_answers.clear();
+            }
+        }
+        let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+        self.blocked_answers
+            .insert((client, Arc::from(domain)), now);
+    }
+
+    pub fn record_attempt(&self, client: IpAddr, host: &str, protocol: SinkholeProtocol) {
+        let now = Utc::now();
+        let host: Arc<str> = Arc::from(host);
+        let blocked_at = self
+            .blocked_answers
+            .get(&(client, Arc::clone(&host)))
+            .map(|at| *at)
+            .filter(|at| now - *at <= correlation_window());
+
+        if let Some(mut entry) = self.hosts.get_mut(&host) {
+            entry.count(protocol, blocked_at.is_some());
+            if entry.clients.len() < MAX_CLIENTS_PER_HOST {
+                entry.clients.insert(client);
+            }
+            entry.last_client = client;
+            entry.last_attempt_at = now;
+            entry.last_blocked_query_at = blocked_at.or(entry.last_blocked_query_at);
+            debug!(client = %client, host = %host, ?protocol, correlated = blocked_at.is_some(), "Sinkhole connection attempt");
+            return;
+        }
+
+        if self.hosts.len() >= MAX_HOSTS {
+            debug!(host = %host, "Sinkhole telemetry host table full");
+            return;
+        }
+        info!(client = %client, host = %host, ?protocol, correlated = blocked_at.is_some(), "First connection attempt to sinkholed host");
+        let mut entry = HostEntry {
+            http_attempts: 0,
+            https_attempts: 0,
+            correlated_attempts: 0,
+            clients: HashSet::from([client]),
+            last_client: client,
+            last_attempt_at: now,
+            last_blocked_query_at: blocked_at,
+        };
+        entry.count(protocol, blocked_at.is_some());
+        self.hosts.insert(host, entry);
+    }
+}
+
+impl HostEntry {
+    fn count(&mut self, protocol: SinkholeProtocol, correlated: bool) {
+        match protocol {
+            SinkholeProtocol::Http => self.http_attempts += 1,
+            SinkholeProtocol::Https => self.https_attempts += 1,
+        }
+        if correlated {
+            self.correlated_attempts += 1;
+        }
+    }
+}
+
+impl SinkholeTelemetryPort for SinkholeTelemetry {
+    fn hosts(&self, limit: usize) -> Vec<SinkholeHostStats> {
+        let mut hosts: Vec<SinkholeHostStats> = self
+            .hosts
+            .iter()
+            .map(|entry| SinkholeHostStats {
+                host: entry.key().to_string(),
+                http_attempts: entry.http_attempts,
+                https_attempts: entry.https_attempts,
+                correlated_attempts: entry.correlated_attempts,
+                clients: entry.clients.len(),
+                last_client: entry.last_client,
+                last_attempt_at: entry.last_attempt_at,
+                last_blocked_query_at: entry.last_blocked_query_at,
+            })
+            .collect();
+        hosts.sort_by(|a, b| {
+            (b.http_attempts + b.https_attempts)
+                .cmp(&(a.http_attempts + a.https_attempts))
+                .then_with(|| a.host.cmp(&b.host))
+        });
+        hosts.truncate(limit);
+        hosts
+    }
+}
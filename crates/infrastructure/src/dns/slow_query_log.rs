@@ -0,0 +1,49 @@
+use ferrous_dns_application::ports::{SlowQueryEntry, SlowQueryLogPort};
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+/// In-memory ring buffer of the most recent slow queries.
+///
+/// Only queries over the threshold reach it, so a plain mutex is not on the
+/// common path.
+pub struct SlowQueryLog {
+    entries: Mutex<VecDeque<SlowQueryEntry>>,
+    capacity: usize,
+}
+
+impl SlowQueryLog {
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            entries: Mutex::new(VecDeque::with_capacity(capacity.min(1024))),
+            capacity,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.lock().map(|e| e.len()).unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl SlowQueryLogPort for SlowQueryLog {
+    fn record(&self, entry: SlowQueryEntry) {
+        let Ok(mut entries) = self.entries.lock() else {
+            return;
+        };
+        if entries.len() >= self.capacity {
+            entries.pop_front();
+        }
+        entries.push_back(entry);
+    }
+
+    fn recent(&self, limit: usize) -> Vec<SlowQueryEntry> {
+        let Ok(entries) = self.entries.lock() else {
+            return Vec::new();
+        };
+        entries.iter().rev().take(limit).cloned().collect()
+    }
+}
@@ -0,0 +1,53 @@
+use arc_swap::ArcSwap;
+use ferrous_dns_application::ports::SplitHorizonPort;
+use ferrous_dns_domain::{DnsConfig, RecordType, SplitHorizonMatcher, ViewAnswer};
+use std::net::IpAddr;
+use std::sync::Arc;
+use tracing::info;
+
+/// Split-horizon views backed by an `ArcSwap<SplitHorizonMatcher>`.
+///
+/// The matcher is rebuilt from the DNS configuration and swapped atomically
+/// whenever view-tagged local records change. With no such records, `lookup`
+/// is a single `is_empty()` check.
+pub struct SplitHorizonStore {
+    matcher: ArcSwap<SplitHorizonMatcher>,
+}
+
+impl SplitHorizonStore {
+    pub fn new(dns: &DnsConfig) -> Arc<Self> {
+        let store = Arc::new(Self {
+            matcher: ArcSwap::from_pointee(SplitHorizonMatcher::empty()),
+        });
+        store.reload(dns);
+        store
+    }
+}
+
+impl SplitHorizonPort for SplitHorizonStore {
+    #[inline]
+    fn lookup(
+        &self,
+        client_ip: IpAddr,
+        group_id: i64,
+        domain: &str,
+        record_type: RecordType,
+    ) -> Option<ViewAnswer> {
+        let matcher = self.matcher.load();
+        if matcher.is_empty() {
+            return None;
+        }
+        matcher.lookup(client_ip, group_id, domain, record_type)
+    }
+
+    fn reload(&self, dns: &DnsConfig) {
+        self.matcher.store(Arc::new(SplitHorizonMatcher::new(
+            &dns.views,
+            &dns.local_records,
+            &dns.local_domain,
+        )));
+        if !dns.views.is_empty() {
+            info!(views = dns.views.len(), "Split-horizon views loaded");
+        }
+    }
+}
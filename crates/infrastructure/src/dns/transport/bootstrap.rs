@@ -0,0 +1,152 @@
+use super::udp::UdpTransport;
+use super::{resolver, DnsTransport};
+use crate::dns::forwarding::{MessageBuilder, ResponseParser};
+use dashmap::DashMap;
+use ferrous_dns_application::ports::UpstreamAddressRepository;
+use ferrous_dns_domain::{BootstrapConfig, DomainError, RecordType, UpstreamAddr};
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+/// Turns upstream hostnames into addresses without depending on the
+/// upstreams themselves.
+///
+/// Pinned hostnames are answered from config. Others are looked up through
+/// the configured bootstrap servers, or the system resolver when there are
+/// none. When a lookup fails, the last addresses found for the hostname are
+/// used instead; with a store attached they survive restarts.
+#[derive(Default)]
+pub struct BootstrapResolver {
+    servers: Vec<SocketAddr>,
+    pinned: HashMap<String, Vec<IpAddr>>,
+    last_known: DashMap<String, Vec<IpAddr>>,
+    store: Option<Arc<dyn UpstreamAddressRepository>>,
+}
+
+impl BootstrapResolver {
+    pub fn from_config(config: &BootstrapConfig) -> Self {
+        Self {
+            servers: config.server_addrs(),
+            pinned: config.pinned_addrs(),
+            ..Default::default()
+        }
+    }
+
+    /// Seeds the last-known addresses from `store` and saves every new set
+    /// of addresses to it.
+    pub async fn with_store(mut self, store: Arc<dyn UpstreamAddressRepository>) -> Self {
+        match store.get_all().await {
+            Ok(saved) => {
+                let count = saved.len();
+                for (hostname, addrs) in saved {
+                    if !addrs.is_empty() {
+                        self.last_known.insert(hostname, addrs);
+                    }
+                }
+                if count > 0 {
+                    info!(hostnames = count, "Loaded last known upstream addresses");
+                }
+            }
+            Err(e) => warn!(error = %e, "Failed to load last known upstream addresses"),
+        }
+        self.store = Some(store);
+        self
+    }
+
+    pub async fn resolve(
+        &self,
+        hostname: &str,
+        port: u16,
+        timeout: Duration,
+    ) -> Result<Vec<SocketAddr>, DomainError> {
+        let key = hostname.trim_end_matches('.').to_ascii_lowercase();
+        if let Some(addrs) = self.pinned.get(&key) {
+            return Ok(with_port(addrs, port));
+        }
+
+        match self.lookup(&key, port, timeout).await {
+            Ok(addrs) => {
+                self.remember(&key, &addrs).await;
+                Ok(with_port(&addrs, port))
+            }
+            Err(e) => match self.last_known.get(&key) {
+                Some(addrs) => {
+                    warn!(
+                        hostname = %key,
+                        error = %e,
+                        "Upstream hostname lookup failed, using last known addresses"
+                    );
+                    Ok(with_port(&addrs, port))
+                }
+                None => Err(e),
+            },
+        }
+    }
+
+    async fn lookup(
+        &self,
+        hostname: &str,
+        port: u16,
+        timeout: Duration,
+    ) -> Result<Vec<IpAddr>, DomainError> {
+        if self.servers.is_empty() {
+            let addrs = resolver::resolve_all(hostname, port, timeout).await?;
+            return Ok(addrs.into_iter().map(|addr| addr.ip()).collect());
+        }
+
+        let mut last_error = None;
+        for server in &self.servers {
+            match query_server(*server, hostname, timeout).await {
+                Ok(addrs) if !addrs.is_empty() => return Ok(addrs),
+                Ok(_) => {
+                    last_error = Some(DomainError::IoError(format!(
+                        "No addresses found for {hostname} via {server}"
+                    )))
+                }
+                Err(e) => {
+                    debug!(server = %server, hostname, error = %e, "Bootstrap lookup failed");
+                    last_error = Some(e);
+                }
+            }
+        }
+        Err(last_error.unwrap_or(DomainError::TransportAllServersUnreachable))
+    }
+
+    async fn remember(&self, hostname: &str, addrs: &[IpAddr]) {
+        if self
+            .last_known
+            .get(hostname)
+            .is_some_and(|known| known.as_slice() == addrs)
+        {
+            return;
+        }
+        self.last_known.insert(hostname.to_string(), addrs.to_vec());
+        if let Some(store) = &self.store {
+            if let Err(e) = store.save(hostname, addrs).await {
+                warn!(hostname, error = %e, "Failed to save upstream addresses");
+            }
+        }
+    }
+}
+
+/// Asks one bootstrap server for the A and AAAA records of `hostname`.
+async fn query_server(
+    server: SocketAddr,
+    hostname: &str,
+    timeout: Duration,
+) -> Result<Vec<IpAddr>, DomainError> {
+    let transport = UdpTransport::new(UpstreamAddr::Resolved(server));
+    let mut addrs = Vec::new();
+    for record_type in [RecordType::A, RecordType::AAAA] {
+        let query = MessageBuilder::build_query(hostname, &record_type, false)?;
+        let response = transport.send(&query, timeout).await?;
+        addrs.extend(ResponseParser::parse(&response.bytes)?.addresses);
+    }
+    Ok(addrs)
+}
+
+fn with_port(addrs: &[IpAddr], port: u16) -> Vec<SocketAddr> {
+    addrs.iter().map(|ip| SocketAddr::new(*ip, port)).collect()
+}
@@ -1,21 +1,20 @@
 use super::{DnsTransport, TransportResponse};
 use async_trait::async_trait;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use bytes::Bytes;
 use dashmap::DashMap;
-use ferrous_dns_domain::DomainError;
+use ferrous_dns_domain::{DohMethod, DohUpstreamConfig, DomainError};
+use reqwest::header::{HeaderMap, AGE, CACHE_CONTROL};
 use std::net::SocketAddr;
-use std::sync::LazyLock;
+use std::sync::{LazyLock, OnceLock};
 use std::time::{Duration, Instant};
 use tracing::debug;
 
-static SHARED_CLIENT: LazyLock<reqwest::Client> = LazyLock::new(|| {
-    reqwest::Client::builder()
-        .use_rustls_tls()
-        .pool_max_idle_per_host(4)
-        .http2_prior_knowledge()
-        .tcp_keepalive(Duration::from_secs(15))
-        .build()
-        .unwrap_or_else(|_| reqwest::Client::new())
-});
+static DOH_OPTIONS: OnceLock<DohUpstreamConfig> = OnceLock::new();
+
+static SHARED_CLIENT: LazyLock<reqwest::Client> =
+    LazyLock::new(|| build_client(None).unwrap_or_else(|_| reqwest::Client::new()));
 
 const CLIENT_TTL: Duration = Duration::from_secs(300);
 
@@ -24,21 +23,120 @@ static HTTPS_CLIENT_POOL: LazyLock<DashMap<String, (reqwest::Client, Instant)>>
 
 const DNS_MESSAGE_CONTENT_TYPE: &str = "application/dns-message";
 
+/// Installs the process-wide DoH upstream settings. Must be called before the
+/// first upstream query; later calls are ignored.
+pub fn configure(config: &DohUpstreamConfig) {
+    let _ = DOH_OPTIONS.set(config.clone());
+}
+
+fn options() -> &'static DohUpstreamConfig {
+    static DEFAULT: LazyLock<DohUpstreamConfig> = LazyLock::new(DohUpstreamConfig::default);
+    DOH_OPTIONS.get().unwrap_or(&DEFAULT)
+}
+
+/// Builds an HTTP/2-only client that keeps a single connection per host, so
+/// concurrent queries are multiplexed as streams instead of opening new
+/// TCP+TLS handshakes.
+fn build_client(resolve: Option<(&str, &[SocketAddr])>) -> reqwest::Result<reqwest::Client> {
+    let opts = options();
+    let mut builder = reqwest::Client::builder()
+        .use_rustls_tls()
+        .pool_max_idle_per_host(1)
+        .http2_prior_knowledge()
+        .http2_adaptive_window(true)
+        .tcp_keepalive(Duration::from_secs(15));
+    if opts.keep_alive_interval_secs > 0 {
+        builder = builder
+            .http2_keep_alive_interval(Duration::from_secs(opts.keep_alive_interval_secs))
+            .http2_keep_alive_while_idle(true);
+    }
+    if let Some((hostname, addrs)) = resolve {
+        builder = builder.resolve_to_addrs(hostname, addrs);
+    }
+    builder.build()
+}
+
+/// Builds the RFC 8484 GET URL for `message`. The DNS ID is zeroed before
+/// encoding so identical questions map to the same URL (RFC 8484 §4.1).
+pub fn build_get_url(base_url: &str, message: &[u8]) -> String {
+    let mut wire = message.to_vec();
+    if wire.len() >= 2 {
+        wire[0] = 0;
+        wire[1] = 0;
+    }
+    let encoded = URL_SAFE_NO_PAD.encode(&wire);
+    let separator = if base_url.contains('?') { '&' } else { '?' };
+    format!("{base_url}{separator}dns={encoded}")
+}
+
+/// Returns how long a response may be reused according to its
+/// `Cache-Control` and `Age` headers, or `None` when it must not be cached.
+pub fn cache_lifetime(cache_control: &str, age_secs: u64) -> Option<Duration> {
+    let mut max_age = None;
+    for directive in cache_control.split(',').map(str::trim) {
+        let lower = directive.to_ascii_lowercase();
+        if lower == "no-store" || lower == "no-cache" {
+            return None;
+        }
+        if let Some(value) = lower.strip_prefix("max-age=") {
+            max_age = value.trim_matches('"').parse::<u64>().ok();
+        }
+    }
+    let remaining = max_age?.checked_sub(age_secs)?;
+    (remaining > 0).then(|| Duration::from_secs(remaining))
+}
+
+fn header_lifetime(headers: &HeaderMap) -> Option<Duration> {
+    let cache_control = headers.get(CACHE_CONTROL)?.to_str().ok()?;
+    let age = headers
+        .get(AGE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .unwrap_or(0);
+    cache_lifetime(cache_control, age)
+}
+
+struct CachedHttpResponse {
+    body: Bytes,
+    expires_at: Instant,
+}
+
 pub struct HttpsTransport {
     url: String,
     hostname: String,
     resolved_addrs: Vec<SocketAddr>,
+    method: DohMethod,
+    respect_cache_headers: bool,
+    http_cache_max_entries: usize,
+    http_cache: DashMap<String, CachedHttpResponse>,
 }
 
 impl HttpsTransport {
     pub fn new(url: String, hostname: String, resolved_addrs: Vec<SocketAddr>) -> Self {
+        Self::with_options(url, hostname, resolved_addrs, options())
+    }
+
+    pub fn with_options(
+        url: String,
+        hostname: String,
+        resolved_addrs: Vec<SocketAddr>,
+        options: &DohUpstreamConfig,
+    ) -> Self {
         Self {
             url,
             hostname,
             resolved_addrs,
+            method: options.method,
+            respect_cache_headers: options.respect_cache_headers,
+            http_cache_max_entries: options.http_cache_max_entries,
+            http_cache: DashMap::new(),
         }
     }
 
+    pub fn method(&self) -> DohMethod {
+        self.method
+    }
+
     fn get_or_create_client(hostname: &str, addrs: &[SocketAddr]) -> reqwest::Client {
         if let Some(entry) = HTTPS_CLIENT_POOL.get(hostname) {
             let (client, created_at) = entry.value();
@@ -49,14 +147,8 @@ impl HttpsTransport {
             HTTPS_CLIENT_POOL.remove(hostname);
         }
 
-        let client = reqwest::Client::builder()
-            .use_rustls_tls()
-            .pool_max_idle_per_host(4)
-            .http2_prior_knowledge()
-            .tcp_keepalive(Duration::from_secs(15))
-            .resolve_to_addrs(hostname, addrs)
-            .build()
-            .unwrap_or_else(|_| SHARED_CLIENT.clone());
+        let client =
+            build_client(Some((hostname, addrs))).unwrap_or_else(|_| SHARED_CLIENT.clone());
 
         HTTPS_CLIENT_POOL
             .entry(hostname.to_string())
@@ -64,6 +156,48 @@ impl HttpsTransport {
             .clone()
             .0
     }
+
+    fn http_cache_enabled(&self) -> bool {
+        self.method == DohMethod::Get
+            && self.respect_cache_headers
+            && self.http_cache_max_entries > 0
+    }
+
+    fn cached_response(&self, url: &str) -> Option<Bytes> {
+        let entry = self.http_cache.get(url)?;
+        if entry.expires_at > Instant::now() {
+            return Some(entry.body.clone());
+        }
+        drop(entry);
+        self.http_cache.remove(url);
+        None
+    }
+
+    fn store_response(&self, url: String, body: Bytes, lifetime: Duration) {
+        if self.http_cache.len() >= self.http_cache_max_entries {
+            let now = Instant::now();
+            self.http_cache.retain(|_, cached| cached.expires_at > now);
+            if self.http_cache.len() >= self.http_cache_max_entries {
+                return;
+            }
+        }
+        self.http_cache.insert(
+            url,
+            CachedHttpResponse {
+                body,
+                expires_at: Instant::now() + lifetime,
+            },
+        );
+    }
+}
+
+/// Copies `body` and stamps `id` into the DNS header.
+fn with_query_id(body: &[u8], id: [u8; 2]) -> Bytes {
+    let mut patched = body.to_vec();
+    if patched.len() >= 2 {
+        patched[..2].copy_from_slice(&id);
+    }
+    Bytes::from(patched)
 }
 
 #[async_trait]
@@ -76,10 +210,30 @@ impl DnsTransport for HttpsTransport {
         debug!(
             url = %self.url,
             message_len = message_bytes.len(),
+            method = ?self.method,
             "Sending DoH query"
         );
 
         let start = Instant::now();
+        let query_id = match message_bytes {
+            [hi, lo, ..] => [*hi, *lo],
+            _ => [0, 0],
+        };
+
+        let get_url = match self.method {
+            DohMethod::Get => Some(build_get_url(&self.url, message_bytes)),
+            DohMethod::Post => None,
+        };
+
+        if self.http_cache_enabled() {
+            if let Some(body) = get_url.as_deref().and_then(|u| self.cached_response(u)) {
+                debug!(url = %self.url, "DoH response served from HTTP cache");
+                return Ok(TransportResponse {
+                    bytes: with_query_id(&body, query_id),
+                    protocol_used: "HTTPS",
+                });
+            }
+        }
 
         let client = if self.resolved_addrs.is_empty() {
             SHARED_CLIENT.clone()
@@ -87,14 +241,17 @@ impl DnsTransport for HttpsTransport {
             Self::get_or_create_client(&self.hostname, &self.resolved_addrs)
         };
 
-        let response = tokio::time::timeout(
-            timeout,
-            client
+        let request = match get_url.as_deref() {
+            Some(url) => client.get(url),
+            None => client
                 .post(&self.url)
                 .header("Content-Type", DNS_MESSAGE_CONTENT_TYPE)
-                .header("Accept", DNS_MESSAGE_CONTENT_TYPE)
-                .body(bytes::Bytes::copy_from_slice(message_bytes))
-                .send(),
+                .body(Bytes::copy_from_slice(message_bytes)),
+        };
+
+        let response = tokio::time::timeout(
+            timeout,
+            request.header("Accept", DNS_MESSAGE_CONTENT_TYPE).send(),
         )
         .await
         .map_err(|_| DomainError::IoError(format!("Timeout sending DoH query to {}", self.url)))?
@@ -110,6 +267,12 @@ impl DnsTransport for HttpsTransport {
             )));
         }
 
+        let lifetime = if self.http_cache_enabled() {
+            header_lifetime(response.headers())
+        } else {
+            None
+        };
+
         let remaining = timeout
             .checked_sub(start.elapsed())
             .unwrap_or(Duration::ZERO);
@@ -132,6 +295,15 @@ impl DnsTransport for HttpsTransport {
             "DoH response received"
         );
 
+        let response_bytes = match (get_url, lifetime) {
+            (Some(url), Some(lifetime)) => {
+                self.store_response(url, response_bytes.clone(), lifetime);
+                with_query_id(&response_bytes, query_id)
+            }
+            (Some(_), None) => with_query_id(&response_bytes, query_id),
+            (None, _) => response_bytes,
+        };
+
         Ok(TransportResponse {
             bytes: response_bytes,
             protocol_used: "HTTPS",
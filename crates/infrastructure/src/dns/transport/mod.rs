@@ -1,3 +1,4 @@
+pub mod bootstrap;
 #[cfg(feature = "dns-over-h3")]
 pub mod h3;
 pub mod https;
@@ -15,7 +16,8 @@ use ferrous_dns_domain::{DnsProtocol, DomainError};
 use std::sync::{Arc, LazyLock};
 use std::time::Duration;
 
-pub use udp_pool::{PoolStats, UdpSocketPool};
+pub use bootstrap::BootstrapResolver;
+pub use udp_pool::UdpSocketPool;
 
 #[derive(Debug)]
 pub struct TransportResponse {
@@ -127,7 +129,7 @@ fn create_transport(protocol: &DnsProtocol) -> Result<Transport, DomainError> {
         ))),
 
         #[cfg(not(feature = "dns-over-https"))]
-        DnsProtocol::Https { url, .. } => Err(DomainError::InvalidDomainName(format!(
+        DnsProtocol::Https { url, .. } => Err(DomainError::ConfigError(format!(
             "HTTPS feature not enabled. Enable 'dns-over-https' feature to use: {}",
             url
         ))),
@@ -139,7 +141,7 @@ fn create_transport(protocol: &DnsProtocol) -> Result<Transport, DomainError> {
         ))),
 
         #[cfg(not(feature = "dns-over-quic"))]
-        DnsProtocol::Quic { addr, .. } => Err(DomainError::InvalidDomainName(format!(
+        DnsProtocol::Quic { addr, .. } => Err(DomainError::ConfigError(format!(
             "QUIC feature not enabled. Enable 'dns-over-quic' feature to use: {}",
             addr
         ))),
@@ -155,7 +157,7 @@ fn create_transport(protocol: &DnsProtocol) -> Result<Transport, DomainError> {
         ))),
 
         #[cfg(not(feature = "dns-over-h3"))]
-        DnsProtocol::H3 { url, .. } => Err(DomainError::InvalidDomainName(format!(
+        DnsProtocol::H3 { url, .. } => Err(DomainError::ConfigError(format!(
             "H3 feature not enabled. Enable 'dns-over-h3' feature to use: {}",
             url
         ))),
@@ -79,10 +79,7 @@ impl TlsTransport {
         let connector = tokio_rustls::TlsConnector::from(SHARED_TLS_CONFIG.clone());
 
         let server_name = ServerName::try_from(self.hostname.to_string()).map_err(|e| {
-            DomainError::InvalidDomainName(format!(
-                "Invalid TLS hostname '{}': {}",
-                self.hostname, e
-            ))
+            DomainError::ConfigError(format!("Invalid TLS hostname '{}': {}", self.hostname, e))
         })?;
 
         let tcp_stream = tokio::time::timeout(timeout, TcpStream::connect(server_addr))
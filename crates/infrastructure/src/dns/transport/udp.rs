@@ -1,15 +1,21 @@
 use super::udp_pool::UdpSocketPool;
 use super::{DnsTransport, TransportResponse};
+use crate::dns::forwarding::{MessageBuilder, RESPONSE_VALIDATION};
 use async_trait::async_trait;
 use ferrous_dns_domain::{DomainError, UpstreamAddr};
-use std::net::SocketAddr;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
 use std::sync::{Arc, LazyLock};
 use std::time::Duration;
 use tokio::net::UdpSocket;
 use tracing::{debug, warn};
 
 static DEFAULT_UDP_POOL: LazyLock<Arc<UdpSocketPool>> =
-    LazyLock::new(|| Arc::new(UdpSocketPool::new(4, 64)));
+    LazyLock::new(|| Arc::new(UdpSocketPool::new(32, 64)));
+
+/// The pool every `UdpTransport::new` sends through.
+pub fn shared_pool() -> Arc<UdpSocketPool> {
+    Arc::clone(&DEFAULT_UDP_POOL)
+}
 
 pub fn validate_response_id(
     query_bytes: &[u8],
@@ -25,6 +31,7 @@ pub fn validate_response_id(
     let query_id = u16::from_be_bytes([query_bytes[0], query_bytes[1]]);
     let response_id = u16::from_be_bytes([response_bytes[0], response_bytes[1]]);
     if query_id != response_id {
+        RESPONSE_VALIDATION.record_id_mismatch();
         warn!(
             server = %server,
             query_id,
@@ -41,6 +48,7 @@ pub fn validate_response_id(
 
 fn validate_response_source(from: SocketAddr, expected: SocketAddr) -> Result<(), DomainError> {
     if from.ip() != expected.ip() {
+        RESPONSE_VALIDATION.record_source_mismatch();
         warn!(
             expected = %expected.ip(),
             actual = %from.ip(),
@@ -57,6 +65,37 @@ fn validate_response_source(from: SocketAddr, expected: SocketAddr) -> Result<()
 
 const MAX_UDP_RESPONSE_SIZE: usize = 4096;
 
+/// Lowest source port picked for per-query sockets; everything above the
+/// well-known range is eligible, which is wider than the kernel's ephemeral
+/// range.
+const RANDOM_PORT_MIN: u16 = 1024;
+
+/// Random ports tried before leaving the choice to the kernel.
+const RANDOM_PORT_ATTEMPTS: usize = 8;
+
+fn random_source_port() -> u16 {
+    let span = u32::from(u16::MAX - RANDOM_PORT_MIN) + 1;
+    RANDOM_PORT_MIN + (u32::from(MessageBuilder::secure_random_id()) % span) as u16
+}
+
+/// Binds to a random source port for a single query, retrying on ports in
+/// use and falling back to a kernel-assigned ephemeral port.
+async fn bind_random_port(server_addr: SocketAddr) -> std::io::Result<UdpSocket> {
+    let ip: IpAddr = if server_addr.is_ipv4() {
+        Ipv4Addr::UNSPECIFIED.into()
+    } else {
+        Ipv6Addr::UNSPECIFIED.into()
+    };
+    for _ in 0..RANDOM_PORT_ATTEMPTS {
+        match UdpSocket::bind((ip, random_source_port())).await {
+            Ok(socket) => return Ok(socket),
+            Err(e) if e.kind() == std::io::ErrorKind::AddrInUse => {}
+            Err(e) => return Err(e),
+        }
+    }
+    UdpSocket::bind((ip, 0)).await
+}
+
 pub struct UdpTransport {
     upstream_addr: UpstreamAddr,
     pool: Option<Arc<UdpSocketPool>>,
@@ -70,6 +109,15 @@ impl UdpTransport {
         }
     }
 
+    /// Sends every query from a new socket bound to a random source port, so
+    /// an off-path attacker has to guess the port as well as the message ID.
+    pub fn per_query(upstream_addr: UpstreamAddr) -> Self {
+        Self {
+            upstream_addr,
+            pool: None,
+        }
+    }
+
     pub fn with_pool(upstream_addr: UpstreamAddr, pool: Arc<UdpSocketPool>) -> Self {
         Self {
             upstream_addr,
@@ -98,23 +146,24 @@ impl UdpTransport {
                 DomainError::IoError(format!("Failed to acquire UDP socket: {}", e))
             })?;
 
-            let socket = pooled.socket();
-
-            let bytes_sent =
-                tokio::time::timeout(timeout, socket.send_to(message_bytes, server_addr))
-                    .await
-                    .map_err(|_| {
-                        DomainError::IoError(format!(
-                            "Timeout sending UDP query to {}",
-                            server_addr
-                        ))
-                    })?
-                    .map_err(|e| {
-                        DomainError::IoError(format!(
-                            "Failed to send UDP query to {}: {}",
-                            server_addr, e
-                        ))
-                    })?;
+            let sent = tokio::time::timeout(timeout, pooled.socket().send(message_bytes)).await;
+            let bytes_sent = match sent {
+                Ok(Ok(bytes_sent)) => bytes_sent,
+                Ok(Err(e)) => {
+                    pooled.record_error(&e);
+                    return Err(DomainError::IoError(format!(
+                        "Failed to send UDP query to {}: {}",
+                        server_addr, e
+                    )));
+                }
+                Err(_) => {
+                    pooled.record_timeout();
+                    return Err(DomainError::IoError(format!(
+                        "Timeout sending UDP query to {}",
+                        server_addr
+                    )));
+                }
+            };
 
             debug!(
                 server = %server_addr,
@@ -125,21 +174,26 @@ impl UdpTransport {
 
             let mut recv_buf = [0u8; MAX_UDP_RESPONSE_SIZE];
 
-            let (bytes_received, from_addr) =
-                tokio::time::timeout(timeout, socket.recv_from(&mut recv_buf))
-                    .await
-                    .map_err(|_| {
-                        DomainError::IoError(format!(
-                            "Timeout waiting for UDP response from {}",
-                            server_addr
-                        ))
-                    })?
-                    .map_err(|e| {
-                        DomainError::IoError(format!(
-                            "Failed to receive UDP response from {}: {}",
-                            server_addr, e
-                        ))
-                    })?;
+            let received =
+                tokio::time::timeout(timeout, pooled.socket().recv_from(&mut recv_buf)).await;
+            let (bytes_received, from_addr) = match received {
+                Ok(Ok(received)) => received,
+                // ICMP errors for the connected server surface here.
+                Ok(Err(e)) => {
+                    pooled.record_error(&e);
+                    return Err(DomainError::IoError(format!(
+                        "Failed to receive UDP response from {}: {}",
+                        server_addr, e
+                    )));
+                }
+                Err(_) => {
+                    pooled.record_timeout();
+                    return Err(DomainError::IoError(format!(
+                        "Timeout waiting for UDP response from {}",
+                        server_addr
+                    )));
+                }
+            };
 
             if let Err(e) = validate_response_source(from_addr, server_addr) {
                 pooled.poison();
@@ -152,6 +206,8 @@ impl UdpTransport {
                 return Err(e);
             }
 
+            pooled.record_success();
+
             debug!(
                 server = %server_addr,
                 bytes_received = bytes_received,
@@ -175,17 +231,17 @@ impl UdpTransport {
     ) -> Result<TransportResponse, DomainError> {
         let server_addr = self.resolved_addr()?;
 
-        let bind_addr: SocketAddr = if server_addr.is_ipv4() {
-            "0.0.0.0:0".parse().unwrap()
-        } else {
-            "[::]:0".parse().unwrap()
-        };
-
-        let socket = UdpSocket::bind(bind_addr)
+        let socket = bind_random_port(server_addr)
             .await
             .map_err(|e| DomainError::IoError(format!("Failed to bind UDP socket: {}", e)))?;
+        socket.connect(server_addr).await.map_err(|e| {
+            DomainError::IoError(format!(
+                "Failed to connect UDP socket to {}: {}",
+                server_addr, e
+            ))
+        })?;
 
-        let bytes_sent = tokio::time::timeout(timeout, socket.send_to(message_bytes, server_addr))
+        let bytes_sent = tokio::time::timeout(timeout, socket.send(message_bytes))
             .await
             .map_err(|_| {
                 DomainError::IoError(format!("Timeout sending UDP query to {}", server_addr))
@@ -1,13 +1,116 @@
 use dashmap::DashMap;
+use ferrous_dns_application::ports::{UdpPoolMetrics, UdpPoolMetricsSource, UdpServerPoolMetrics};
+use std::io;
 use std::net::SocketAddr;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::net::UdpSocket;
 use tokio::sync::Semaphore;
-use tracing::info;
+use tracing::{info, warn};
+
+/// Idle sockets kept for a server however quiet it gets, so its next query
+/// does not wait for a bind.
+const MIN_IDLE_PER_SERVER: usize = 1;
+
+/// How long a burst of concurrent queries keeps its sockets in the pool
+/// before the pool shrinks back to the concurrency seen since.
+const RESIZE_WINDOW: Duration = Duration::from_secs(30);
+
+/// Consecutive errors after which a socket is closed instead of reused.
+const MAX_SOCKET_ERRORS: u32 = 3;
+
+/// Idle sockets and counters of one upstream server.
+struct ServerPool {
+    idle: Vec<IdleSocket>,
+    in_flight: usize,
+    peak_in_flight: usize,
+    window_started: Instant,
+    target_idle: usize,
+    unreachable_errors: u64,
+    timeouts: u64,
+    other_errors: u64,
+}
+
+struct IdleSocket {
+    socket: Arc<UdpSocket>,
+    errors: u32,
+}
+
+impl ServerPool {
+    fn new() -> Self {
+        Self {
+            idle: Vec::new(),
+            in_flight: 0,
+            peak_in_flight: 0,
+            window_started: Instant::now(),
+            target_idle: MIN_IDLE_PER_SERVER,
+            unreachable_errors: 0,
+            timeouts: 0,
+            other_errors: 0,
+        }
+    }
+
+    /// Grows the target right away when concurrency climbs past it.
+    fn begin_query(&mut self, max_idle: usize) {
+        self.in_flight += 1;
+        self.peak_in_flight = self.peak_in_flight.max(self.in_flight);
+        self.target_idle = self.target_idle.max(self.in_flight.min(max_idle));
+    }
+
+    /// Shrinks the target to the peak of the window that just ended, once
+    /// per window, and closes the idle sockets above it.
+    fn end_query(&mut self, max_idle: usize) {
+        self.in_flight = self.in_flight.saturating_sub(1);
+        if self.window_started.elapsed() >= RESIZE_WINDOW {
+            self.target_idle = self.peak_in_flight.clamp(MIN_IDLE_PER_SERVER, max_idle);
+            self.peak_in_flight = self.in_flight;
+            self.window_started = Instant::now();
+            self.idle.truncate(self.target_idle);
+        }
+    }
+
+    fn record_error(&mut self, error: SocketError) {
+        match error {
+            SocketError::Unreachable => self.unreachable_errors += 1,
+            SocketError::Timeout => self.timeouts += 1,
+            SocketError::Other => self.other_errors += 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SocketError {
+    Unreachable,
+    Timeout,
+    Other,
+}
+
+impl SocketError {
+    fn classify(error: &io::Error) -> Self {
+        match error.kind() {
+            io::ErrorKind::ConnectionRefused
+            | io::ErrorKind::HostUnreachable
+            | io::ErrorKind::NetworkUnreachable => Self::Unreachable,
+            io::ErrorKind::TimedOut => Self::Timeout,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Binding to port 0 fails this way once the ephemeral port range is used up.
+fn is_port_exhaustion(error: &io::Error) -> bool {
+    matches!(
+        error.kind(),
+        io::ErrorKind::AddrInUse | io::ErrorKind::AddrNotAvailable
+    )
+}
 
+/// Connected UDP sockets for upstream queries, keyed by server. The idle
+/// sockets kept per server follow the number of queries in flight to it,
+/// up to `max_per_server`.
 pub struct UdpSocketPool {
-    pools: DashMap<SocketAddr, Vec<Arc<UdpSocket>>>,
+    pools: DashMap<SocketAddr, ServerPool>,
 
     max_per_server: usize,
 
@@ -16,6 +119,10 @@ pub struct UdpSocketPool {
     total_created: AtomicU64,
 
     total_reused: AtomicU64,
+
+    port_exhaustion_errors: AtomicU64,
+
+    socket_errors: AtomicU64,
 }
 
 impl UdpSocketPool {
@@ -24,35 +131,63 @@ impl UdpSocketPool {
 
         Self {
             pools: DashMap::new(),
-            max_per_server,
+            max_per_server: max_per_server.max(MIN_IDLE_PER_SERVER),
             semaphore: Arc::new(Semaphore::new(total_limit)),
             total_created: AtomicU64::new(0),
             total_reused: AtomicU64::new(0),
+            port_exhaustion_errors: AtomicU64::new(0),
+            socket_errors: AtomicU64::new(0),
         }
     }
 
-    pub async fn acquire(&self, server: SocketAddr) -> Result<PooledUdpSocket<'_>, std::io::Error> {
-        if let Some(mut entry) = self.pools.get_mut(&server) {
-            if let Some(socket) = entry.pop() {
-                self.total_reused.fetch_add(1, Ordering::Relaxed);
-
-                return Ok(PooledUdpSocket {
-                    socket,
-                    server,
-                    pool: self,
-                    _permit: None,
-                    poisoned: false,
-                });
+    pub async fn acquire(&self, server: SocketAddr) -> Result<PooledUdpSocket<'_>, io::Error> {
+        let reused = {
+            let mut entry = self.pools.entry(server).or_insert_with(ServerPool::new);
+            let idle = entry.idle.pop();
+            if idle.is_some() {
+                entry.begin_query(self.max_per_server);
             }
+            idle
+        };
+        if let Some(idle) = reused {
+            self.total_reused.fetch_add(1, Ordering::Relaxed);
+            return Ok(PooledUdpSocket {
+                socket: idle.socket,
+                errors: idle.errors,
+                server,
+                pool: self,
+                _permit: None,
+                poisoned: false,
+            });
         }
 
         let permit = self.semaphore.clone().acquire_owned().await.ok();
 
-        let socket = self.create_socket(server).await?;
+        let socket = match self.create_socket(server).await {
+            Ok(socket) => socket,
+            Err(e) => {
+                if is_port_exhaustion(&e) {
+                    let total = self.port_exhaustion_errors.fetch_add(1, Ordering::Relaxed) + 1;
+                    warn!(
+                        server = %server,
+                        error = %e,
+                        total,
+                        "No local UDP port available for upstream query; ephemeral ports exhausted"
+                    );
+                } else {
+                    self.socket_errors.fetch_add(1, Ordering::Relaxed);
+                }
+                return Err(e);
+            }
+        };
         self.total_created.fetch_add(1, Ordering::Relaxed);
+        if let Some(mut entry) = self.pools.get_mut(&server) {
+            entry.begin_query(self.max_per_server);
+        }
 
         Ok(PooledUdpSocket {
             socket: Arc::new(socket),
+            errors: 0,
             server,
             pool: self,
             _permit: permit,
@@ -60,7 +195,9 @@ impl UdpSocketPool {
         })
     }
 
-    async fn create_socket(&self, server: SocketAddr) -> Result<UdpSocket, std::io::Error> {
+    /// Connecting makes the kernel drop datagrams from other sources and
+    /// report ICMP errors for the server on the socket.
+    async fn create_socket(&self, server: SocketAddr) -> Result<UdpSocket, io::Error> {
         use socket2::{Domain, Protocol, Socket, Type};
 
         let domain = if server.is_ipv4() {
@@ -83,44 +220,81 @@ impl UdpSocketPool {
         };
 
         socket.bind(&bind_addr.into())?;
+        socket.connect(&server.into())?;
         socket.set_nonblocking(true)?;
 
         let std_socket: std::net::UdpSocket = socket.into();
         UdpSocket::from_std(std_socket)
     }
 
-    fn release(&self, server: SocketAddr, socket: Arc<UdpSocket>) {
-        let mut entry = self.pools.entry(server).or_default();
+    fn release(&self, server: SocketAddr, idle: Option<IdleSocket>) {
+        let mut entry = self.pools.entry(server).or_insert_with(ServerPool::new);
+        entry.end_query(self.max_per_server);
 
-        if entry.len() < self.max_per_server {
-            entry.push(socket);
+        if let Some(idle) = idle {
+            if entry.idle.len() < entry.target_idle {
+                entry.idle.push(idle);
+            }
         }
     }
 
-    pub fn stats(&self) -> PoolStats {
-        let total_pooled: usize = self.pools.iter().map(|e| e.len()).sum();
-
-        PoolStats {
-            total_created: self.total_created.load(Ordering::Relaxed),
-            total_reused: self.total_reused.load(Ordering::Relaxed),
-            total_pooled,
-            servers: self.pools.len(),
+    fn record_error(&self, server: SocketAddr, error: SocketError) {
+        if let Some(mut entry) = self.pools.get_mut(&server) {
+            entry.record_error(error);
         }
     }
 
+    /// Closes the idle sockets of `server`; its counters are kept.
     pub fn clear_server(&self, server: &SocketAddr) {
-        self.pools.remove(server);
+        if let Some(mut entry) = self.pools.get_mut(server) {
+            entry.idle.clear();
+        }
     }
 
     pub fn clear_all(&self) {
-        let count: usize = self.pools.iter().map(|e| e.len()).sum();
-        self.pools.clear();
+        let mut count = 0;
+        for mut entry in self.pools.iter_mut() {
+            count += entry.idle.len();
+            entry.idle.clear();
+        }
         info!(count, "Cleared all socket pools");
     }
 }
 
+impl UdpPoolMetricsSource for UdpSocketPool {
+    fn udp_pool_metrics(&self) -> UdpPoolMetrics {
+        let mut servers: Vec<UdpServerPoolMetrics> = self
+            .pools
+            .iter()
+            .map(|entry| UdpServerPoolMetrics {
+                server: entry.key().to_string(),
+                idle_sockets: entry.idle.len(),
+                in_flight: entry.in_flight,
+                target_idle: entry.target_idle,
+                unreachable_errors: entry.unreachable_errors,
+                timeouts: entry.timeouts,
+                other_errors: entry.other_errors,
+            })
+            .collect();
+        servers.sort_by(|a, b| a.server.cmp(&b.server));
+
+        UdpPoolMetrics {
+            sockets_created: self.total_created.load(Ordering::Relaxed),
+            sockets_reused: self.total_reused.load(Ordering::Relaxed),
+            idle_sockets: servers.iter().map(|s| s.idle_sockets).sum(),
+            in_flight: servers.iter().map(|s| s.in_flight).sum(),
+            max_idle_per_server: self.max_per_server,
+            port_exhaustion_errors: self.port_exhaustion_errors.load(Ordering::Relaxed),
+            socket_errors: self.socket_errors.load(Ordering::Relaxed),
+            servers,
+        }
+    }
+}
+
 pub struct PooledUdpSocket<'a> {
     socket: Arc<UdpSocket>,
+    /// Consecutive errors of this socket, carried across reuses.
+    errors: u32,
     server: SocketAddr,
     pool: &'a UdpSocketPool,
     _permit: Option<tokio::sync::OwnedSemaphorePermit>,
@@ -139,33 +313,36 @@ impl<'a> PooledUdpSocket<'a> {
     pub fn poison(&mut self) {
         self.poisoned = true;
     }
-}
 
-impl<'a> Drop for PooledUdpSocket<'a> {
-    fn drop(&mut self) {
-        if !self.poisoned {
-            self.pool.release(self.server, self.socket.clone());
-        }
+    pub fn record_success(&mut self) {
+        self.errors = 0;
     }
-}
-
-#[derive(Debug, Clone)]
-pub struct PoolStats {
-    pub total_created: u64,
 
-    pub total_reused: u64,
+    /// Counts a failed send or receive against the server and the socket.
+    pub fn record_error(&mut self, error: &io::Error) {
+        self.record(SocketError::classify(error));
+    }
 
-    pub total_pooled: usize,
+    /// A late answer would arrive on this socket and be read as the answer
+    /// to its next query, so a socket that timed out is not reused.
+    pub fn record_timeout(&mut self) {
+        self.record(SocketError::Timeout);
+        self.poisoned = true;
+    }
 
-    pub servers: usize,
+    fn record(&mut self, error: SocketError) {
+        self.errors += 1;
+        self.pool.record_error(self.server, error);
+    }
 }
 
-impl PoolStats {
-    pub fn reuse_rate(&self) -> f64 {
-        if self.total_created == 0 {
-            0.0
-        } else {
-            self.total_reused as f64 / self.total_created as f64
-        }
+impl<'a> Drop for PooledUdpSocket<'a> {
+    fn drop(&mut self) {
+        let keep = !self.poisoned && self.errors < MAX_SOCKET_ERRORS;
+        let idle = keep.then(|| IdleSocket {
+            socket: self.socket.clone(),
+            errors: self.errors,
+        });
+        self.pool.release(self.server, idle);
     }
 }
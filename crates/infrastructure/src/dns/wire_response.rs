@@ -19,6 +19,14 @@ pub fn patch_wire_id(wire: &[u8], new_id: u16) -> Option<Vec<u8>> {
     Some(buf)
 }
 
+/// Reads ANCOUNT from the header of `wire`, or `None` if the header is cut
+/// short.
+#[inline]
+pub fn answer_count(wire: &[u8]) -> Option<u16> {
+    wire.get(6..8)
+        .map(|count| u16::from_be_bytes([count[0], count[1]]))
+}
+
 pub fn build_cache_hit_response(
     query: &FastPathQuery,
     query_buf: &[u8],
@@ -0,0 +1,185 @@
+use ferrous_dns_application::ports::{
+    ExternalClient, ExternalConfig, ExternalDomainRule, ExternalList, ExternalRecord,
+};
+use ferrous_dns_domain::{DomainAction, DomainError};
+use serde::Deserialize;
+
+use crate::dns::block_filter::{parse_list_line, ParsedEntry};
+
+use super::invalid_export;
+
+fn enabled_by_default() -> bool {
+    true
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct AdGuardHomeYaml {
+    #[serde(default)]
+    filters: Vec<Filter>,
+    #[serde(default)]
+    whitelist_filters: Vec<Filter>,
+    #[serde(default)]
+    user_rules: Vec<String>,
+    #[serde(default)]
+    filtering: Option<Rewrites>,
+    /// Schema versions before 0.107.37 kept rewrites under `dns`.
+    #[serde(default)]
+    dns: Option<Rewrites>,
+    #[serde(default)]
+    clients: Option<Clients>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Filter {
+    #[serde(default = "enabled_by_default")]
+    enabled: bool,
+    url: String,
+    #[serde(default)]
+    name: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Rewrites {
+    #[serde(default)]
+    rewrites: Vec<Rewrite>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Rewrite {
+    domain: String,
+    answer: String,
+    #[serde(default = "enabled_by_default")]
+    enabled: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Clients {
+    Current {
+        #[serde(default)]
+        persistent: Vec<PersistentClient>,
+    },
+    Legacy(Vec<PersistentClient>),
+}
+
+#[derive(Debug, Deserialize)]
+struct PersistentClient {
+    name: String,
+    #[serde(default)]
+    ids: Vec<String>,
+}
+
+/// Reads `AdGuardHome.yaml`. AdGuard Home has no groups, so everything
+/// lands in the default group.
+pub(super) fn read(bytes: &[u8]) -> Result<ExternalConfig, DomainError> {
+    let yaml: AdGuardHomeYaml =
+        serde_yaml::from_slice(bytes).map_err(|e| invalid_export(format!("YAML: {}", e)))?;
+    let mut config = ExternalConfig::default();
+
+    for (filters, allow) in [(yaml.filters, false), (yaml.whitelist_filters, true)] {
+        for filter in filters {
+            config.lists.push(ExternalList {
+                name: filter.name.filter(|n| !n.trim().is_empty()),
+                url: filter.url,
+                comment: None,
+                enabled: filter.enabled,
+                allow,
+                groups: Vec::new(),
+            });
+        }
+    }
+
+    for rule in &yaml.user_rules {
+        translate_rule(rule, &mut config);
+    }
+
+    let rewrites = yaml
+        .filtering
+        .into_iter()
+        .chain(yaml.dns)
+        .flat_map(|r| r.rewrites);
+    for rewrite in rewrites {
+        if !rewrite.enabled {
+            continue;
+        }
+        if rewrite.domain.contains('*') {
+            config.warnings.push(format!(
+                "Rewrite '{}': wildcard rewrites are not supported",
+                rewrite.domain
+            ));
+            continue;
+        }
+        config.records.push(ExternalRecord {
+            name: rewrite.domain,
+            answer: rewrite.answer,
+        });
+    }
+
+    let clients = match yaml.clients {
+        Some(Clients::Current { persistent }) => persistent,
+        Some(Clients::Legacy(list)) => list,
+        None => Vec::new(),
+    };
+    for client in clients {
+        for id in client.ids {
+            config.clients.push(ExternalClient {
+                id,
+                name: Some(client.name.clone()),
+                comment: None,
+                groups: Vec::new(),
+            });
+        }
+    }
+
+    Ok(config)
+}
+
+/// Translates one adblock-syntax user rule. `||example.com^` also covers
+/// subdomains in AdGuard Home, so it yields both `example.com` and
+/// `*.example.com`.
+fn translate_rule(rule: &str, config: &mut ExternalConfig) {
+    let line = rule.trim();
+    if line.is_empty() || line.starts_with('!') || line.starts_with('#') {
+        return;
+    }
+    let (action, body) = match line.strip_prefix("@@") {
+        Some(rest) => (DomainAction::Allow, rest),
+        None => (DomainAction::Deny, line),
+    };
+    let unsupported = || format!("Rule '{}': unsupported syntax, skipped", line);
+
+    if body.contains('$') && !(body.starts_with('/') && body.ends_with('/')) {
+        config.warnings.push(format!(
+            "Rule '{}': rule modifiers are not supported, skipped",
+            line
+        ));
+        return;
+    }
+
+    let domain_rule = |value: String, regex: bool| ExternalDomainRule {
+        value,
+        regex,
+        action,
+        comment: None,
+        enabled: true,
+        groups: Vec::new(),
+    };
+
+    match parse_list_line(body) {
+        Some(ParsedEntry::Pattern(pattern)) => {
+            config.domain_rules.push(domain_rule(pattern, true));
+        }
+        Some(ParsedEntry::Exact(domain)) => {
+            if body.starts_with("||") {
+                config
+                    .domain_rules
+                    .push(domain_rule(format!("*.{}", domain), false));
+            }
+            config.domain_rules.push(domain_rule(domain, false));
+        }
+        Some(ParsedEntry::Wildcard(domain)) => {
+            config.domain_rules.push(domain_rule(domain, false));
+        }
+        None => config.warnings.push(unsupported()),
+    }
+}
@@ -0,0 +1,30 @@
+mod adguard;
+mod pihole;
+
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{ExternalConfig, ExternalConfigReader, ExternalFormat};
+use ferrous_dns_domain::DomainError;
+use tracing::instrument;
+
+/// Parses Pi-hole Teleporter archives and `AdGuardHome.yaml` files.
+#[derive(Default)]
+pub struct ExternalConfigFileReader;
+
+#[async_trait]
+impl ExternalConfigReader for ExternalConfigFileReader {
+    #[instrument(skip(self, bytes), fields(size = bytes.len()))]
+    async fn read(
+        &self,
+        format: ExternalFormat,
+        bytes: &[u8],
+    ) -> Result<ExternalConfig, DomainError> {
+        match format {
+            ExternalFormat::PiHole => pihole::read(bytes).await,
+            ExternalFormat::AdGuardHome => adguard::read(bytes),
+        }
+    }
+}
+
+fn invalid_export(detail: String) -> DomainError {
+    DomainError::InvalidInput(format!("Invalid import file: {}", detail))
+}
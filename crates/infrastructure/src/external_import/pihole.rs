@@ -0,0 +1,435 @@
+use std::collections::HashMap;
+use std::io::{Cursor, Read};
+use std::path::Path;
+
+use ferrous_dns_application::ports::{
+    BackupArchiver, ExternalClient, ExternalConfig, ExternalDomainRule, ExternalGroup,
+    ExternalList, ExternalRecord,
+};
+use ferrous_dns_domain::{DomainAction, DomainError};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer};
+use sqlx::sqlite::SqliteConnectOptions;
+use sqlx::{ConnectOptions, Connection, SqliteConnection};
+
+use crate::backup::TarGzBackupArchiver;
+
+use super::invalid_export;
+
+/// Pi-hole's built-in group; everything linked to it maps to the Ferrous default group.
+const PIHOLE_DEFAULT_GROUP_ID: i64 = 0;
+
+/// Largest `gravity.db` accepted from a v6 Teleporter zip.
+const MAX_GRAVITY_DB_BYTES: u64 = 512 * 1024 * 1024;
+
+fn enabled_by_default() -> bool {
+    true
+}
+
+/// Pi-hole stores flags as 0/1 integers; accept real booleans too.
+fn flag<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Flag {
+        Bool(bool),
+        Int(i64),
+    }
+    Ok(match Flag::deserialize(deserializer)? {
+        Flag::Bool(b) => b,
+        Flag::Int(i) => i != 0,
+    })
+}
+
+#[derive(Debug, Deserialize)]
+struct GroupRow {
+    id: i64,
+    #[serde(deserialize_with = "flag", default = "enabled_by_default")]
+    enabled: bool,
+    name: String,
+    #[serde(default)]
+    description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AdlistRow {
+    id: i64,
+    address: String,
+    #[serde(deserialize_with = "flag", default = "enabled_by_default")]
+    enabled: bool,
+    #[serde(default)]
+    comment: Option<String>,
+    /// 0 = blocklist, 1 = allowlist (v6 only).
+    #[serde(default, rename = "type")]
+    kind: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct DomainRow {
+    id: i64,
+    /// 0 = exact allow, 1 = exact deny, 2 = regex allow, 3 = regex deny.
+    #[serde(rename = "type")]
+    kind: i64,
+    domain: String,
+    #[serde(deserialize_with = "flag", default = "enabled_by_default")]
+    enabled: bool,
+    #[serde(default)]
+    comment: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ClientRow {
+    id: i64,
+    ip: String,
+    #[serde(default)]
+    comment: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GroupLink {
+    #[serde(alias = "adlist_id", alias = "domainlist_id", alias = "client_id")]
+    item_id: i64,
+    group_id: i64,
+}
+
+/// The subset of Pi-hole's gravity database and local DNS settings we translate.
+#[derive(Debug, Default)]
+struct Gravity {
+    groups: Vec<GroupRow>,
+    adlists: Vec<AdlistRow>,
+    adlist_groups: Vec<GroupLink>,
+    domains: Vec<DomainRow>,
+    domain_groups: Vec<GroupLink>,
+    clients: Vec<ClientRow>,
+    client_groups: Vec<GroupLink>,
+    /// `IP name [name…]` lines.
+    hosts: Vec<String>,
+    /// `alias,target[,ttl]` entries.
+    cnames: Vec<String>,
+}
+
+/// Reads a Teleporter export: v5 gzipped tar of JSON tables, or v6 zip
+/// carrying `gravity.db` and `pihole.toml`.
+pub(super) async fn read(bytes: &[u8]) -> Result<ExternalConfig, DomainError> {
+    let gravity = if bytes.starts_with(b"PK\x03\x04") {
+        read_v6(bytes).await?
+    } else if bytes.starts_with(&[0x1f, 0x8b]) {
+        read_v5(bytes)?
+    } else {
+        return Err(DomainError::InvalidInput(
+            "Not a Pi-hole Teleporter archive (expected .tar.gz or .zip)".to_string(),
+        ));
+    };
+    Ok(translate(gravity))
+}
+
+fn file_name(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or(path)
+}
+
+fn parse_json<T: DeserializeOwned>(name: &str, contents: &[u8]) -> Result<Vec<T>, DomainError> {
+    serde_json::from_slice(contents).map_err(|e| invalid_export(format!("{}: {}", name, e)))
+}
+
+fn read_v5(bytes: &[u8]) -> Result<Gravity, DomainError> {
+    let mut gravity = Gravity::default();
+
+    for (path, contents) in TarGzBackupArchiver.unpack(bytes)? {
+        let name = file_name(&path);
+        match name {
+            "group.json" => gravity.groups = parse_json(name, &contents)?,
+            "adlist.json" => gravity.adlists = parse_json(name, &contents)?,
+            "adlist_by_group.json" => gravity.adlist_groups = parse_json(name, &contents)?,
+            "domainlist_by_group.json" => gravity.domain_groups = parse_json(name, &contents)?,
+            "client.json" => gravity.clients = parse_json(name, &contents)?,
+            "client_by_group.json" => gravity.client_groups = parse_json(name, &contents)?,
+            "whitelist.exact.json"
+            | "blacklist.exact.json"
+            | "whitelist.regex.json"
+            | "blacklist.regex.json" => gravity
+                .domains
+                .extend(parse_json::<DomainRow>(name, &contents)?),
+            "custom.list" => gravity.hosts.extend(
+                String::from_utf8_lossy(&contents)
+                    .lines()
+                    .map(str::to_string),
+            ),
+            "05-pihole-custom-cname.conf" => gravity.cnames.extend(
+                String::from_utf8_lossy(&contents)
+                    .lines()
+                    .filter_map(|l| l.trim().strip_prefix("cname="))
+                    .map(str::to_string),
+            ),
+            _ => {}
+        }
+    }
+
+    Ok(gravity)
+}
+
+async fn read_v6(bytes: &[u8]) -> Result<Gravity, DomainError> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes))
+        .map_err(|e| invalid_export(format!("zip: {}", e)))?;
+    let mut database = None;
+    let mut settings = None;
+
+    for i in 0..archive.len() {
+        let mut entry = archive
+            .by_index(i)
+            .map_err(|e| invalid_export(format!("zip: {}", e)))?;
+        let target = match file_name(entry.name()) {
+            "gravity.db" => &mut database,
+            "pihole.toml" => &mut settings,
+            _ => continue,
+        };
+        if entry.size() > MAX_GRAVITY_DB_BYTES {
+            return Err(invalid_export(format!("{} is too large", entry.name())));
+        }
+        let mut contents = Vec::with_capacity(entry.size() as usize);
+        entry
+            .by_ref()
+            .take(MAX_GRAVITY_DB_BYTES)
+            .read_to_end(&mut contents)
+            .map_err(|e| invalid_export(format!("zip: {}", e)))?;
+        *target = Some(contents);
+    }
+
+    let database = database
+        .ok_or_else(|| invalid_export("gravity.db is missing from the archive".to_string()))?;
+    let mut gravity = read_gravity_db(&database).await?;
+
+    if let Some(settings) = settings {
+        let toml: toml::Table = toml::from_str(&String::from_utf8_lossy(&settings))
+            .map_err(|e| invalid_export(format!("pihole.toml: {}", e)))?;
+        let strings = |key: &str| -> Vec<String> {
+            toml.get("dns")
+                .and_then(|dns| dns.get(key))
+                .and_then(|v| v.as_array())
+                .map(|items| {
+                    items
+                        .iter()
+                        .filter_map(|v| v.as_str().map(str::to_string))
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        gravity.hosts = strings("hosts");
+        gravity.cnames = strings("cnameRecords");
+    }
+
+    Ok(gravity)
+}
+
+/// Copies the database to a temporary file; SQLite cannot open it from memory.
+async fn read_gravity_db(database: &[u8]) -> Result<Gravity, DomainError> {
+    let path = std::env::temp_dir().join(format!("ferrous-pihole-{:016x}.db", fastrand::u64(..)));
+    tokio::fs::write(&path, database)
+        .await
+        .map_err(|e| DomainError::IoError(format!("Failed to stage gravity.db: {}", e)))?;
+
+    let result = query_gravity_db(&path).await;
+    let _ = tokio::fs::remove_file(&path).await;
+    result
+}
+
+async fn query_gravity_db(path: &Path) -> Result<Gravity, DomainError> {
+    let mut conn = SqliteConnectOptions::new()
+        .filename(path)
+        .read_only(true)
+        .connect()
+        .await
+        .map_err(|e| invalid_export(format!("gravity.db: {}", e)))?;
+
+    let gravity = load_gravity(&mut conn)
+        .await
+        .map_err(|e| invalid_export(format!("gravity.db: {}", e)));
+    let _ = conn.close().await;
+    gravity
+}
+
+async fn load_gravity(conn: &mut SqliteConnection) -> Result<Gravity, sqlx::Error> {
+    let groups: Vec<(i64, bool, String, Option<String>)> =
+        sqlx::query_as("SELECT id, enabled, name, description FROM \"group\"")
+            .fetch_all(&mut *conn)
+            .await?;
+    let adlists: Vec<(i64, String, bool, Option<String>, i64)> =
+        sqlx::query_as("SELECT id, address, enabled, comment, type FROM adlist")
+            .fetch_all(&mut *conn)
+            .await?;
+    let domains: Vec<(i64, i64, String, bool, Option<String>)> =
+        sqlx::query_as("SELECT id, type, domain, enabled, comment FROM domainlist")
+            .fetch_all(&mut *conn)
+            .await?;
+    let clients: Vec<(i64, String, Option<String>)> =
+        sqlx::query_as("SELECT id, ip, comment FROM client")
+            .fetch_all(&mut *conn)
+            .await?;
+
+    let mut links = Vec::new();
+    for sql in [
+        "SELECT adlist_id, group_id FROM adlist_by_group",
+        "SELECT domainlist_id, group_id FROM domainlist_by_group",
+        "SELECT client_id, group_id FROM client_by_group",
+    ] {
+        let rows: Vec<(i64, i64)> = sqlx::query_as(sql).fetch_all(&mut *conn).await?;
+        links.push(
+            rows.into_iter()
+                .map(|(item_id, group_id)| GroupLink { item_id, group_id })
+                .collect::<Vec<_>>(),
+        );
+    }
+    let client_groups = links.pop().unwrap_or_default();
+    let domain_groups = links.pop().unwrap_or_default();
+    let adlist_groups = links.pop().unwrap_or_default();
+
+    Ok(Gravity {
+        groups: groups
+            .into_iter()
+            .map(|(id, enabled, name, description)| GroupRow {
+                id,
+                enabled,
+                name,
+                description,
+            })
+            .collect(),
+        adlists: adlists
+            .into_iter()
+            .map(|(id, address, enabled, comment, kind)| AdlistRow {
+                id,
+                address,
+                enabled,
+                comment,
+                kind,
+            })
+            .collect(),
+        adlist_groups,
+        domains: domains
+            .into_iter()
+            .map(|(id, kind, domain, enabled, comment)| DomainRow {
+                id,
+                kind,
+                domain,
+                enabled,
+                comment,
+            })
+            .collect(),
+        domain_groups,
+        clients: clients
+            .into_iter()
+            .map(|(id, ip, comment)| ClientRow { id, ip, comment })
+            .collect(),
+        client_groups,
+        hosts: Vec::new(),
+        cnames: Vec::new(),
+    })
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.filter(|v| !v.trim().is_empty())
+}
+
+fn translate(gravity: Gravity) -> ExternalConfig {
+    let mut config = ExternalConfig::default();
+    let mut names: HashMap<i64, String> = HashMap::new();
+
+    for group in gravity.groups {
+        names.insert(group.id, group.name.clone());
+        if group.id == PIHOLE_DEFAULT_GROUP_ID {
+            continue;
+        }
+        config.groups.push(ExternalGroup {
+            name: group.name,
+            comment: non_empty(group.description),
+            enabled: group.enabled,
+        });
+    }
+
+    // Names that are not imported (Pi-hole's Default) resolve to the default group.
+    let group_refs = |links: &[GroupLink], item_id: i64| -> Vec<String> {
+        links
+            .iter()
+            .filter(|l| l.item_id == item_id)
+            .map(|l| {
+                names
+                    .get(&l.group_id)
+                    .cloned()
+                    .unwrap_or_else(|| "Default".to_string())
+            })
+            .collect()
+    };
+
+    for list in gravity.adlists {
+        config.lists.push(ExternalList {
+            name: None,
+            groups: group_refs(&gravity.adlist_groups, list.id),
+            url: list.address,
+            comment: non_empty(list.comment),
+            enabled: list.enabled,
+            allow: list.kind == 1,
+        });
+    }
+
+    for domain in gravity.domains {
+        let (regex, action) = match domain.kind {
+            0 => (false, DomainAction::Allow),
+            1 => (false, DomainAction::Deny),
+            2 => (true, DomainAction::Allow),
+            3 => (true, DomainAction::Deny),
+            other => {
+                config.warnings.push(format!(
+                    "Domain '{}': unknown Pi-hole list type {}",
+                    domain.domain, other
+                ));
+                continue;
+            }
+        };
+        if regex && domain.domain.contains(";querytype=") {
+            config.warnings.push(format!(
+                "Regex '{}': Pi-hole query type extensions are not supported",
+                domain.domain
+            ));
+            continue;
+        }
+        config.domain_rules.push(ExternalDomainRule {
+            groups: group_refs(&gravity.domain_groups, domain.id),
+            value: domain.domain,
+            regex,
+            action,
+            comment: non_empty(domain.comment),
+            enabled: domain.enabled,
+        });
+    }
+
+    for client in gravity.clients {
+        config.clients.push(ExternalClient {
+            groups: group_refs(&gravity.client_groups, client.id),
+            id: client.ip,
+            name: None,
+            comment: non_empty(client.comment),
+        });
+    }
+
+    for line in &gravity.hosts {
+        let mut parts = line.split_whitespace();
+        let Some(answer) = parts.next().filter(|p| !p.starts_with('#')) else {
+            continue;
+        };
+        for name in parts.take_while(|p| !p.starts_with('#')) {
+            config.records.push(ExternalRecord {
+                name: name.to_string(),
+                answer: answer.to_string(),
+            });
+        }
+    }
+
+    for entry in &gravity.cnames {
+        let mut parts = entry.split(',').map(str::trim);
+        if let (Some(alias), Some(target)) = (parts.next(), parts.next()) {
+            config.records.push(ExternalRecord {
+                name: alias.to_string(),
+                answer: target.to_string(),
+            });
+        }
+    }
+
+    config
+}
@@ -1,8 +1,14 @@
 pub mod auth;
+pub mod backup;
 pub mod database;
+pub mod discovery;
 pub mod dns;
+pub mod external_import;
+pub mod metrics;
+pub mod notifications;
 pub mod repositories;
 pub mod schedule;
 pub mod service_catalog;
+pub mod standby;
 pub mod system;
 pub mod tls;
@@ -0,0 +1,57 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::MetricsSink;
+use ferrous_dns_domain::{DomainError, MetricsExportBackend, MetricsExportConfig};
+use std::time::Duration;
+
+/// Posts line protocol to an InfluxDB 2.x or VictoriaMetrics write endpoint.
+pub struct LineProtocolMetricsSink {
+    client: reqwest::Client,
+    url: String,
+    authorization: Option<String>,
+}
+
+impl LineProtocolMetricsSink {
+    pub fn new(config: &MetricsExportConfig) -> Result<Self, DomainError> {
+        let client = reqwest::Client::builder()
+            .user_agent(concat!("ferrous-dns/", env!("CARGO_PKG_VERSION")))
+            .timeout(Duration::from_secs(10))
+            .build()
+            .map_err(export_error)?;
+        let scheme = match config.backend {
+            MetricsExportBackend::Influxdb => "Token",
+            MetricsExportBackend::Victoriametrics => "Bearer",
+        };
+        Ok(Self {
+            client,
+            url: config.url.trim().to_string(),
+            authorization: config
+                .token
+                .as_deref()
+                .filter(|t| !t.is_empty())
+                .map(|token| format!("{scheme} {token}")),
+        })
+    }
+}
+
+fn export_error(e: impl std::fmt::Display) -> DomainError {
+    DomainError::MetricsExportFailed(e.to_string())
+}
+
+#[async_trait]
+impl MetricsSink for LineProtocolMetricsSink {
+    async fn write(&self, lines: String) -> Result<(), DomainError> {
+        let mut request = self
+            .client
+            .post(&self.url)
+            .header(reqwest::header::CONTENT_TYPE, "text/plain; charset=utf-8")
+            .body(lines);
+        if let Some(authorization) = &self.authorization {
+            request = request.header(reqwest::header::AUTHORIZATION, authorization);
+        }
+        let response = request.send().await.map_err(export_error)?;
+        if !response.status().is_success() {
+            return Err(export_error(format!("HTTP {}", response.status())));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,3 @@
+mod line_protocol;
+
+pub use line_protocol::LineProtocolMetricsSink;
@@ -0,0 +1,68 @@
+mod smtp;
+mod telegram;
+mod webhook;
+
+pub use smtp::{SmtpNotificationSender, SmtpReportSender};
+pub use telegram::TelegramNotificationSender;
+pub use webhook::WebhookNotificationSender;
+
+use ferrous_dns_application::ports::NotificationSender;
+use ferrous_dns_domain::{DomainError, NotificationsConfig};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Builds one sender per channel configured under `[notifications]`.
+pub fn build_notification_senders(
+    config: &NotificationsConfig,
+) -> Result<Vec<Arc<dyn NotificationSender>>, DomainError> {
+    let mut senders: Vec<Arc<dyn NotificationSender>> = Vec::new();
+    if config.webhook_url.is_some() || config.telegram.is_some() {
+        let client = http_client()?;
+        if let Some(url) = &config.webhook_url {
+            senders.push(Arc::new(WebhookNotificationSender::new(
+                client.clone(),
+                url.clone(),
+            )));
+        }
+        if let Some(telegram) = &config.telegram {
+            senders.push(Arc::new(TelegramNotificationSender::new(
+                client,
+                telegram.clone(),
+            )));
+        }
+    }
+    if let Some(smtp) = &config.smtp {
+        senders.push(Arc::new(SmtpNotificationSender::new(smtp)?));
+    }
+    Ok(senders)
+}
+
+fn http_client() -> Result<reqwest::Client, DomainError> {
+    reqwest::Client::builder()
+        .user_agent(concat!("ferrous-dns/", env!("CARGO_PKG_VERSION")))
+        .timeout(Duration::from_secs(10))
+        .build()
+        .map_err(delivery_error)
+}
+
+fn delivery_error(e: impl std::fmt::Display) -> DomainError {
+    DomainError::NotificationDeliveryFailed(e.to_string())
+}
+
+async fn post_json(
+    client: &reqwest::Client,
+    url: &str,
+    body: &serde_json::Value,
+) -> Result<(), DomainError> {
+    let response = client
+        .post(url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .body(body.to_string())
+        .send()
+        .await
+        .map_err(delivery_error)?;
+    if !response.status().is_success() {
+        return Err(delivery_error(format!("HTTP {}", response.status())));
+    }
+    Ok(())
+}
@@ -0,0 +1,134 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{NotificationSender, ReportSender};
+use ferrous_dns_domain::config::SmtpConfig;
+use ferrous_dns_domain::{DomainError, Notification};
+use lettre::message::header::ContentType;
+use lettre::message::{Mailbox, Message};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Tokio1Executor};
+
+use super::delivery_error;
+
+/// Transport and addresses from `[notifications.smtp]`, shared by the
+/// notification and report senders.
+struct Mailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+    to: Vec<Mailbox>,
+}
+
+impl Mailer {
+    fn new(config: &SmtpConfig) -> Result<Self, DomainError> {
+        let invalid = |e: String| DomainError::ConfigError(format!("notifications.smtp: {}", e));
+
+        let builder = if config.starttls {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.host)
+        } else {
+            AsyncSmtpTransport::<Tokio1Executor>::relay(&config.host)
+        }
+        .map_err(|e| invalid(e.to_string()))?
+        .port(config.port);
+        let builder = match (&config.username, &config.password) {
+            (Some(username), Some(password)) => {
+                builder.credentials(Credentials::new(username.clone(), password.clone()))
+            }
+            _ => builder,
+        };
+
+        let from = config
+            .from
+            .parse()
+            .map_err(|e| invalid(format!("from: {}", e)))?;
+        let to = config
+            .to
+            .iter()
+            .map(|to| {
+                to.parse()
+                    .map_err(|e| invalid(format!("to '{}': {}", to, e)))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            transport: builder.build(),
+            from,
+            to,
+        })
+    }
+
+    async fn send(
+        &self,
+        subject: &str,
+        content_type: ContentType,
+        body: String,
+    ) -> Result<(), DomainError> {
+        let mut message = Message::builder()
+            .from(self.from.clone())
+            .subject(format!("[Ferrous DNS] {}", subject))
+            .header(content_type);
+        for to in &self.to {
+            message = message.to(to.clone());
+        }
+        let message = message.body(body).map_err(delivery_error)?;
+
+        self.transport
+            .send(message)
+            .await
+            .map(|_| ())
+            .map_err(delivery_error)
+    }
+}
+
+/// Sends each notification as a plain-text email.
+pub struct SmtpNotificationSender {
+    mailer: Mailer,
+}
+
+impl SmtpNotificationSender {
+    pub fn new(config: &SmtpConfig) -> Result<Self, DomainError> {
+        Ok(Self {
+            mailer: Mailer::new(config)?,
+        })
+    }
+}
+
+#[async_trait]
+impl NotificationSender for SmtpNotificationSender {
+    fn name(&self) -> &'static str {
+        "smtp"
+    }
+
+    async fn send(&self, notification: &Notification) -> Result<(), DomainError> {
+        let body = format!(
+            "{}\n\nEvent: {}\nSubject: {}\nTime: {}\n",
+            notification.message,
+            notification.event.as_str(),
+            notification.subject,
+            notification.timestamp
+        );
+        self.mailer
+            .send(&notification.title, ContentType::TEXT_PLAIN, body)
+            .await
+    }
+}
+
+/// Sends usage reports as HTML emails.
+pub struct SmtpReportSender {
+    mailer: Mailer,
+}
+
+impl SmtpReportSender {
+    pub fn new(config: &SmtpConfig) -> Result<Self, DomainError> {
+        Ok(Self {
+            mailer: Mailer::new(config)?,
+        })
+    }
+}
+
+#[async_trait]
+impl ReportSender for SmtpReportSender {
+    async fn send(&self, subject: &str, html: &str) -> Result<(), DomainError> {
+        self.mailer
+            .send(subject, ContentType::TEXT_HTML, html.to_string())
+            .await
+    }
+}
@@ -0,0 +1,48 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::NotificationSender;
+use ferrous_dns_domain::config::TelegramConfig;
+use ferrous_dns_domain::{DomainError, Notification};
+
+use super::post_json;
+
+const TELEGRAM_API: &str = "https://api.telegram.org";
+
+/// Sends notifications through the Telegram Bot API `sendMessage` method.
+pub struct TelegramNotificationSender {
+    client: reqwest::Client,
+    config: TelegramConfig,
+    api_base: String,
+}
+
+impl TelegramNotificationSender {
+    pub fn new(client: reqwest::Client, config: TelegramConfig) -> Self {
+        Self {
+            client,
+            config,
+            api_base: TELEGRAM_API.to_string(),
+        }
+    }
+
+    /// Overrides the Bot API endpoint (self-hosted Bot API servers, tests).
+    pub fn with_api_base(mut self, api_base: impl Into<String>) -> Self {
+        self.api_base = api_base.into();
+        self
+    }
+}
+
+#[async_trait]
+impl NotificationSender for TelegramNotificationSender {
+    fn name(&self) -> &'static str {
+        "telegram"
+    }
+
+    async fn send(&self, notification: &Notification) -> Result<(), DomainError> {
+        let url = format!("{}/bot{}/sendMessage", self.api_base, self.config.bot_token);
+        let body = serde_json::json!({
+            "chat_id": self.config.chat_id,
+            "text": format!("{}\n\n{}", notification.title, notification.message),
+            "disable_web_page_preview": true,
+        });
+        post_json(&self.client, &url, &body).await
+    }
+}
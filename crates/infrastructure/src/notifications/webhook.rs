@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::NotificationSender;
+use ferrous_dns_domain::{DomainError, Notification};
+
+use super::post_json;
+
+/// Posts each notification as JSON:
+/// `{"event", "subject", "title", "message", "timestamp"}`.
+pub struct WebhookNotificationSender {
+    client: reqwest::Client,
+    url: String,
+}
+
+impl WebhookNotificationSender {
+    pub fn new(client: reqwest::Client, url: String) -> Self {
+        Self { client, url }
+    }
+}
+
+#[async_trait]
+impl NotificationSender for WebhookNotificationSender {
+    fn name(&self) -> &'static str {
+        "webhook"
+    }
+
+    async fn send(&self, notification: &Notification) -> Result<(), DomainError> {
+        let body = serde_json::to_value(notification)
+            .map_err(|e| DomainError::NotificationDeliveryFailed(e.to_string()))?;
+        post_json(&self.client, &self.url, &body).await
+    }
+}
@@ -0,0 +1,140 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::AlertRepository;
+use ferrous_dns_domain::{Alert, AlertKind, DomainError};
+use sqlx::SqlitePool;
+use tracing::{error, instrument, warn};
+
+type AlertRow = (
+    i64,
+    String,
+    String,
+    f64,
+    f64,
+    Option<String>,
+    String,
+    String,
+);
+
+const ALERT_COLUMNS: &str = "id, client_ip, kind, score, threshold, domain, details, created_at";
+
+pub struct SqliteAlertRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteAlertRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_alert(row: AlertRow) -> Option<Alert> {
+        let (id, client_ip, kind, score, threshold, domain, details, created_at) = row;
+        let Ok(kind) = kind.parse::<AlertKind>() else {
+            warn!(kind = %kind, "Unknown alert kind in DB, skipping");
+            return None;
+        };
+        Some(Alert {
+            id: Some(id),
+            client_ip,
+            kind,
+            score,
+            threshold,
+            domain,
+            details,
+            created_at: Some(created_at),
+        })
+    }
+}
+
+fn db_error(context: &'static str) -> impl FnOnce(sqlx::Error) -> DomainError {
+    move |e| {
+        error!(error = %e, "{}", context);
+        DomainError::DatabaseError(e.to_string())
+    }
+}
+
+#[async_trait]
+impl AlertRepository for SqliteAlertRepository {
+    #[instrument(skip(self, alert))]
+    async fn insert(&self, alert: &Alert) -> Result<Alert, DomainError> {
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        let row = sqlx::query_as::<_, AlertRow>(&format!(
+            "INSERT INTO alerts (client_ip, kind, score, threshold, domain, details, created_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?)
+             RETURNING {ALERT_COLUMNS}"
+        ))
+        .bind(&alert.client_ip)
+        .bind(alert.kind.as_str())
+        .bind(alert.score)
+        .bind(alert.threshold)
+        .bind(&alert.domain)
+        .bind(&alert.details)
+        .bind(&now)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(db_error("Failed to insert alert"))?;
+
+        Self::row_to_alert(row)
+            .ok_or_else(|| DomainError::DatabaseError("Inserted alert has unknown kind".into()))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_recent(
+        &self,
+        limit: u32,
+        client_ip: Option<&str>,
+        kind: Option<AlertKind>,
+    ) -> Result<Vec<Alert>, DomainError> {
+        let mut sql = format!("SELECT {ALERT_COLUMNS} FROM alerts WHERE 1 = 1");
+        if client_ip.is_some() {
+            sql.push_str(" AND client_ip = ?");
+        }
+        if kind.is_some() {
+            sql.push_str(" AND kind = ?");
+        }
+        sql.push_str(" ORDER BY created_at DESC, id DESC LIMIT ?");
+
+        let mut query = sqlx::query_as::<_, AlertRow>(&sql);
+        if let Some(client_ip) = client_ip {
+            query = query.bind(client_ip);
+        }
+        if let Some(kind) = kind {
+            query = query.bind(kind.as_str());
+        }
+        let rows = query
+            .bind(limit as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(db_error("Failed to fetch alerts"))?;
+
+        Ok(rows.into_iter().filter_map(Self::row_to_alert).collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete(&self, id: i64) -> Result<(), DomainError> {
+        let result = sqlx::query("DELETE FROM alerts WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(db_error("Failed to delete alert"))?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::AlertNotFound(id));
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete_older_than(&self, days: u32) -> Result<u64, DomainError> {
+        let cutoff = (chrono::Utc::now() - chrono::Duration::days(days as i64))
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string();
+        let result = sqlx::query("DELETE FROM alerts WHERE created_at < ?")
+            .bind(cutoff)
+            .execute(&self.pool)
+            .await
+            .map_err(db_error("Failed to delete old alerts"))?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -24,6 +24,7 @@ struct TokenRow {
     key_prefix: String,
     key_hash: String,
     key_raw: Option<String>,
+    tenant_id: Option<i64>,
     created_at: String,
     last_used_at: Option<String>,
 }
@@ -37,18 +38,20 @@ impl ApiTokenRepository for SqliteApiTokenRepository {
         key_prefix: &str,
         key_hash: &str,
         key_raw: &str,
+        tenant_id: Option<i64>,
     ) -> Result<ApiToken, DomainError> {
         let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
 
         let row: TokenRow = sqlx::query_as(
-            "INSERT INTO api_tokens (name, key_prefix, key_hash, key_raw, created_at)
-             VALUES (?, ?, ?, ?, ?)
-             RETURNING id, name, key_prefix, key_hash, key_raw, created_at, last_used_at",
+            "INSERT INTO api_tokens (name, key_prefix, key_hash, key_raw, tenant_id, created_at)
+             VALUES (?, ?, ?, ?, ?, ?)
+             RETURNING id, name, key_prefix, key_hash, key_raw, tenant_id, created_at, last_used_at",
         )
         .bind(name)
         .bind(key_prefix)
         .bind(key_hash)
         .bind(key_raw)
+        .bind(tenant_id)
         .bind(&now)
         .fetch_one(self.pool.as_ref())
         .await
@@ -69,7 +72,7 @@ impl ApiTokenRepository for SqliteApiTokenRepository {
     #[instrument(skip(self))]
     async fn get_all(&self) -> Result<Vec<ApiToken>, DomainError> {
         let rows: Vec<TokenRow> = sqlx::query_as(
-            "SELECT id, name, key_prefix, key_hash, key_raw, created_at, last_used_at
+            "SELECT id, name, key_prefix, key_hash, key_raw, tenant_id, created_at, last_used_at
              FROM api_tokens ORDER BY id",
         )
         .fetch_all(self.pool.as_ref())
@@ -85,7 +88,7 @@ impl ApiTokenRepository for SqliteApiTokenRepository {
     #[instrument(skip(self))]
     async fn get_by_id(&self, id: i64) -> Result<Option<ApiToken>, DomainError> {
         let row: Option<TokenRow> = sqlx::query_as(
-            "SELECT id, name, key_prefix, key_hash, key_raw, created_at, last_used_at
+            "SELECT id, name, key_prefix, key_hash, key_raw, tenant_id, created_at, last_used_at
              FROM api_tokens WHERE id = ?",
         )
         .bind(id)
@@ -102,7 +105,7 @@ impl ApiTokenRepository for SqliteApiTokenRepository {
     #[instrument(skip(self))]
     async fn get_by_name(&self, name: &str) -> Result<Option<ApiToken>, DomainError> {
         let row: Option<TokenRow> = sqlx::query_as(
-            "SELECT id, name, key_prefix, key_hash, key_raw, created_at, last_used_at
+            "SELECT id, name, key_prefix, key_hash, key_raw, tenant_id, created_at, last_used_at
              FROM api_tokens WHERE name = ?",
         )
         .bind(name)
@@ -130,7 +133,7 @@ impl ApiTokenRepository for SqliteApiTokenRepository {
                 sqlx::query_as(
                     "UPDATE api_tokens SET name = ?, key_prefix = ?, key_hash = ?, key_raw = ?
                  WHERE id = ?
-                 RETURNING id, name, key_prefix, key_hash, key_raw, created_at, last_used_at",
+                 RETURNING id, name, key_prefix, key_hash, key_raw, tenant_id, created_at, last_used_at",
                 )
                 .bind(name)
                 .bind(prefix)
@@ -143,7 +146,7 @@ impl ApiTokenRepository for SqliteApiTokenRepository {
                 sqlx::query_as(
                     "UPDATE api_tokens SET name = ?
                  WHERE id = ?
-                 RETURNING id, name, key_prefix, key_hash, key_raw, created_at, last_used_at",
+                 RETURNING id, name, key_prefix, key_hash, key_raw, tenant_id, created_at, last_used_at",
                 )
                 .bind(name)
                 .bind(id)
@@ -225,6 +228,23 @@ impl ApiTokenRepository for SqliteApiTokenRepository {
 
         Ok(row.map(|(id,)| id))
     }
+
+    #[instrument(skip(self, key_hash))]
+    async fn get_by_hash(&self, key_hash: &str) -> Result<Option<ApiToken>, DomainError> {
+        let row: Option<TokenRow> = sqlx::query_as(
+            "SELECT id, name, key_prefix, key_hash, key_raw, tenant_id, created_at, last_used_at
+             FROM api_tokens WHERE key_hash = ?",
+        )
+        .bind(key_hash)
+        .fetch_optional(self.pool.as_ref())
+        .await
+        .map_err(|e| {
+            error!("Failed to get API token by hash: {e}");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(row.map(row_to_token))
+    }
 }
 
 fn row_to_token(row: TokenRow) -> ApiToken {
@@ -234,6 +254,7 @@ fn row_to_token(row: TokenRow) -> ApiToken {
         key_prefix: Arc::from(row.key_prefix.as_str()),
         key_hash: Arc::from(row.key_hash.as_str()),
         key_raw: row.key_raw.map(|s| Arc::from(s.as_str())),
+        tenant_id: row.tenant_id,
         created_at: Some(row.created_at),
         last_used_at: row.last_used_at,
     }
@@ -0,0 +1,120 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::AuditLogRepository;
+use ferrous_dns_domain::{AuditAction, AuditEvent, DomainError};
+use sqlx::SqlitePool;
+use tracing::{error, instrument, warn};
+
+type AuditEventRow = (
+    i64,
+    String,
+    String,
+    Option<String>,
+    String,
+    String,
+    String,
+    String,
+);
+
+const AUDIT_COLUMNS: &str = "id, action, client_ip, actor, target, result, details, created_at";
+
+pub struct SqliteAuditLogRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteAuditLogRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_event(row: AuditEventRow) -> Option<AuditEvent> {
+        let (id, action, client_ip, actor, target, result, details, created_at) = row;
+        let Ok(action) = action.parse::<AuditAction>() else {
+            warn!(action = %action, "Unknown audit action in DB, skipping");
+            return None;
+        };
+        Some(AuditEvent {
+            id: Some(id),
+            action,
+            client_ip,
+            actor,
+            target,
+            result,
+            details,
+            created_at: Some(created_at),
+        })
+    }
+}
+
+fn db_error(context: &'static str) -> impl FnOnce(sqlx::Error) -> DomainError {
+    move |e| {
+        error!(error = %e, "{}", context);
+        DomainError::DatabaseError(e.to_string())
+    }
+}
+
+#[async_trait]
+impl AuditLogRepository for SqliteAuditLogRepository {
+    #[instrument(skip(self, event))]
+    async fn insert(&self, event: &AuditEvent) -> Result<AuditEvent, DomainError> {
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        let row = sqlx::query_as::<_, AuditEventRow>(&format!(
+            "INSERT INTO audit_log (action, client_ip, actor, target, result, details, created_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?)
+             RETURNING {AUDIT_COLUMNS}"
+        ))
+        .bind(event.action.as_str())
+        .bind(&event.client_ip)
+        .bind(&event.actor)
+        .bind(&event.target)
+        .bind(&event.result)
+        .bind(&event.details)
+        .bind(&now)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(db_error("Failed to insert audit event"))?;
+
+        Self::row_to_event(row).ok_or_else(|| {
+            DomainError::DatabaseError("Inserted audit event has unknown action".into())
+        })
+    }
+
+    #[instrument(skip(self))]
+    async fn get_recent(
+        &self,
+        limit: u32,
+        action: Option<AuditAction>,
+    ) -> Result<Vec<AuditEvent>, DomainError> {
+        let mut sql = format!("SELECT {AUDIT_COLUMNS} FROM audit_log");
+        if action.is_some() {
+            sql.push_str(" WHERE action = ?");
+        }
+        sql.push_str(" ORDER BY created_at DESC, id DESC LIMIT ?");
+
+        let mut query = sqlx::query_as::<_, AuditEventRow>(&sql);
+        if let Some(action) = action {
+            query = query.bind(action.as_str());
+        }
+        let rows = query
+            .bind(limit as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(db_error("Failed to fetch audit log"))?;
+
+        Ok(rows.into_iter().filter_map(Self::row_to_event).collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete_older_than(&self, days: u32) -> Result<u64, DomainError> {
+        let cutoff = (chrono::Utc::now() - chrono::Duration::days(days as i64))
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string();
+        let result = sqlx::query("DELETE FROM audit_log WHERE created_at < ?")
+            .bind(cutoff)
+            .execute(&self.pool)
+            .await
+            .map_err(db_error("Failed to delete old audit events"))?;
+
+        Ok(result.rows_affected())
+    }
+}
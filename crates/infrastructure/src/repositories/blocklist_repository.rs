@@ -1,7 +1,8 @@
+use super::paging::order_by;
 use async_trait::async_trait;
 use dashmap::DashSet;
 use ferrous_dns_application::ports::BlocklistRepository;
-use ferrous_dns_domain::{blocklist::BlockedDomain, DomainError};
+use ferrous_dns_domain::{blocklist::BlockedDomain, DomainError, PageRequest};
 use rustc_hash::FxBuildHasher;
 use sqlx::{Row, SqlitePool};
 use std::sync::Arc;
@@ -18,7 +19,7 @@ impl SqliteBlocklistRepository {
         let rows = sqlx::query("SELECT domain FROM blocklist")
             .fetch_all(&pool)
             .await
-            .map_err(|e| DomainError::InvalidDomainName(format!("Database error: {}", e)))?;
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
         for row in &rows {
             blocked_domains.insert(row.get("domain"));
         }
@@ -42,7 +43,7 @@ impl BlocklistRepository for SqliteBlocklistRepository {
     async fn get_all(&self) -> Result<Vec<BlockedDomain>, DomainError> {
         let rows = sqlx::query("SELECT id, domain, datetime(added_at) as added_at FROM blocklist ORDER BY added_at DESC")
             .fetch_all(&self.pool).await
-            .map_err(|e| DomainError::InvalidDomainName(format!("Database error: {}", e)))?;
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
         Ok(rows
             .into_iter()
             .map(|row| BlockedDomain {
@@ -55,8 +56,7 @@ impl BlocklistRepository for SqliteBlocklistRepository {
 
     async fn get_all_paged(
         &self,
-        limit: u32,
-        offset: u32,
+        page: &PageRequest,
     ) -> Result<(Vec<BlockedDomain>, u64), DomainError> {
         let count_row = sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM blocklist")
             .fetch_one(&self.pool)
@@ -64,9 +64,13 @@ impl BlocklistRepository for SqliteBlocklistRepository {
             .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
         let total = count_row.0 as u64;
 
-        let rows = sqlx::query("SELECT id, domain, datetime(added_at) as added_at FROM blocklist ORDER BY added_at DESC LIMIT ? OFFSET ?")
-            .bind(limit as i64)
-            .bind(offset as i64)
+        let sql = format!(
+            "SELECT id, domain, datetime(added_at) as added_at FROM blocklist {} LIMIT ? OFFSET ?",
+            order_by(page, "added_at DESC")
+        );
+        let rows = sqlx::query(&sql)
+            .bind(page.limit as i64)
+            .bind(page.offset as i64)
             .fetch_all(&self.pool)
             .await
             .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
@@ -88,18 +92,50 @@ impl BlocklistRepository for SqliteBlocklistRepository {
             .bind(&domain.domain)
             .execute(&self.pool)
             .await
-            .map_err(|e| DomainError::InvalidDomainName(format!("Database error: {}", e)))?;
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
         self.blocked_domains.insert(domain.domain.clone());
         debug!(domain = %domain.domain, "Domain added to blocklist");
         Ok(())
     }
 
+    async fn add_domains(&self, domains: &[String]) -> Result<Vec<bool>, DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let mut added = Vec::with_capacity(domains.len());
+        for domain in domains {
+            let result = sqlx::query("INSERT OR IGNORE INTO blocklist (domain) VALUES (?)")
+                .bind(domain)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+            added.push(result.rows_affected() > 0);
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        for (domain, _) in domains.iter().zip(&added).filter(|(_, added)| **added) {
+            self.blocked_domains.insert(domain.clone());
+        }
+        debug!(
+            requested = domains.len(),
+            added = added.iter().filter(|a| **a).count(),
+            "Domains bulk-added to blocklist"
+        );
+        Ok(added)
+    }
+
     async fn remove_domain(&self, domain: &str) -> Result<(), DomainError> {
         sqlx::query("DELETE FROM blocklist WHERE domain = ?")
             .bind(domain)
             .execute(&self.pool)
             .await
-            .map_err(|e| DomainError::InvalidDomainName(format!("Database error: {}", e)))?;
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
         self.blocked_domains.remove(domain);
         debug!(domain = %domain, "Domain removed from blocklist");
         Ok(())
@@ -1,6 +1,7 @@
+use super::paging::order_by;
 use async_trait::async_trait;
 use ferrous_dns_application::ports::BlocklistSourceRepository;
-use ferrous_dns_domain::{BlocklistSource, DomainError};
+use ferrous_dns_domain::{BlocklistSource, DomainError, PageRequest};
 use sqlx::{Row, SqlitePool};
 use std::sync::Arc;
 use tracing::{error, instrument};
@@ -11,6 +12,8 @@ type BlocklistSourceRow = (
     Option<String>,
     Option<String>,
     i64,
+    i64,
+    Option<i64>,
     String,
     String,
 );
@@ -25,7 +28,7 @@ impl SqliteBlocklistSourceRepository {
     }
 
     fn row_to_source(row: BlocklistSourceRow, group_ids: Vec<i64>) -> BlocklistSource {
-        let (id, name, url, comment, enabled, created_at, updated_at) = row;
+        let (id, name, url, comment, enabled, audit_mode, tenant_id, created_at, updated_at) = row;
         BlocklistSource {
             id: Some(id),
             name: Arc::from(name.as_str()),
@@ -33,6 +36,8 @@ impl SqliteBlocklistSourceRepository {
             group_ids,
             comment: comment.map(|s| Arc::from(s.as_str())),
             enabled: enabled != 0,
+            audit_mode: audit_mode != 0,
+            tenant_id,
             created_at: Some(created_at),
             updated_at: Some(updated_at),
         }
@@ -52,13 +57,22 @@ impl SqliteBlocklistSourceRepository {
 
         Ok(rows.iter().map(|r| r.get::<i64, _>("group_id")).collect())
     }
-}
 
-#[async_trait]
-impl BlocklistSourceRepository for SqliteBlocklistSourceRepository {
-    #[instrument(skip(self))]
-    async fn create(
+    async fn rows_to_sources(
         &self,
+        rows: Vec<BlocklistSourceRow>,
+    ) -> Result<Vec<BlocklistSource>, DomainError> {
+        let mut sources = Vec::with_capacity(rows.len());
+        for row in rows {
+            let group_ids = self.fetch_group_ids(row.0).await?;
+            sources.push(Self::row_to_source(row, group_ids));
+        }
+        Ok(sources)
+    }
+
+    async fn insert(
+        &self,
+        tenant_id: Option<i64>,
         name: String,
         url: Option<String>,
         group_ids: Vec<i64>,
@@ -76,15 +90,17 @@ impl BlocklistSourceRepository for SqliteBlocklistSourceRepository {
         })?;
 
         let row = sqlx::query_as::<_, BlocklistSourceRow>(
-            "INSERT INTO blocklist_sources (name, url, group_id, comment, enabled, created_at, updated_at)
-             VALUES (?, ?, ?, ?, ?, ?, ?)
-             RETURNING id, name, url, comment, enabled, created_at, updated_at",
+            "INSERT INTO blocklist_sources
+                 (name, url, group_id, comment, enabled, tenant_id, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+             RETURNING id, name, url, comment, enabled, audit_mode, tenant_id, created_at, updated_at",
         )
         .bind(&name)
         .bind(&url)
         .bind(legacy_group_id)
         .bind(&comment)
         .bind(if enabled { 1i64 } else { 0i64 })
+        .bind(tenant_id)
         .bind(&now)
         .bind(&now)
         .fetch_one(&mut *tx)
@@ -122,11 +138,41 @@ impl BlocklistSourceRepository for SqliteBlocklistSourceRepository {
 
         Ok(Self::row_to_source(row, group_ids))
     }
+}
+
+#[async_trait]
+impl BlocklistSourceRepository for SqliteBlocklistSourceRepository {
+    #[instrument(skip(self))]
+    async fn create(
+        &self,
+        name: String,
+        url: Option<String>,
+        group_ids: Vec<i64>,
+        comment: Option<String>,
+        enabled: bool,
+    ) -> Result<BlocklistSource, DomainError> {
+        self.insert(None, name, url, group_ids, comment, enabled)
+            .await
+    }
+
+    #[instrument(skip(self))]
+    async fn create_in_tenant(
+        &self,
+        tenant_id: i64,
+        name: String,
+        url: Option<String>,
+        group_ids: Vec<i64>,
+        comment: Option<String>,
+        enabled: bool,
+    ) -> Result<BlocklistSource, DomainError> {
+        self.insert(Some(tenant_id), name, url, group_ids, comment, enabled)
+            .await
+    }
 
     #[instrument(skip(self))]
     async fn get_by_id(&self, id: i64) -> Result<Option<BlocklistSource>, DomainError> {
         let row = sqlx::query_as::<_, BlocklistSourceRow>(
-            "SELECT id, name, url, comment, enabled, created_at, updated_at
+            "SELECT id, name, url, comment, enabled, audit_mode, tenant_id, created_at, updated_at
              FROM blocklist_sources WHERE id = ?",
         )
         .bind(id)
@@ -146,10 +192,38 @@ impl BlocklistSourceRepository for SqliteBlocklistSourceRepository {
         }
     }
 
+    #[instrument(skip(self))]
+    async fn get_by_id_in_tenant(
+        &self,
+        tenant_id: i64,
+        id: i64,
+    ) -> Result<Option<BlocklistSource>, DomainError> {
+        let row = sqlx::query_as::<_, BlocklistSourceRow>(
+            "SELECT id, name, url, comment, enabled, audit_mode, tenant_id, created_at, updated_at
+             FROM blocklist_sources WHERE id = ? AND tenant_id = ?",
+        )
+        .bind(id)
+        .bind(tenant_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to query tenant blocklist source by id");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+        match row {
+            None => Ok(None),
+            Some(r) => {
+                let group_ids = self.fetch_group_ids(r.0).await?;
+                Ok(Some(Self::row_to_source(r, group_ids)))
+            }
+        }
+    }
+
     #[instrument(skip(self))]
     async fn get_all(&self) -> Result<Vec<BlocklistSource>, DomainError> {
         let rows = sqlx::query_as::<_, BlocklistSourceRow>(
-            "SELECT id, name, url, comment, enabled, created_at, updated_at
+            "SELECT id, name, url, comment, enabled, audit_mode, tenant_id, created_at, updated_at
              FROM blocklist_sources ORDER BY name ASC",
         )
         .fetch_all(&self.pool)
@@ -159,12 +233,55 @@ impl BlocklistSourceRepository for SqliteBlocklistSourceRepository {
             DomainError::DatabaseError(e.to_string())
         })?;
 
-        let mut sources = Vec::with_capacity(rows.len());
-        for row in rows {
-            let group_ids = self.fetch_group_ids(row.0).await?;
-            sources.push(Self::row_to_source(row, group_ids));
-        }
-        Ok(sources)
+        self.rows_to_sources(rows).await
+    }
+
+    #[instrument(skip(self))]
+    async fn get_all_in_tenant(&self, tenant_id: i64) -> Result<Vec<BlocklistSource>, DomainError> {
+        let rows = sqlx::query_as::<_, BlocklistSourceRow>(
+            "SELECT id, name, url, comment, enabled, audit_mode, tenant_id, created_at, updated_at
+             FROM blocklist_sources WHERE tenant_id = ? ORDER BY name ASC",
+        )
+        .bind(tenant_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to query tenant blocklist sources");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+        self.rows_to_sources(rows).await
+    }
+
+    #[instrument(skip(self))]
+    async fn get_page(
+        &self,
+        page: &PageRequest,
+    ) -> Result<(Vec<BlocklistSource>, u64), DomainError> {
+        let (total,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM blocklist_sources")
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to count blocklist sources");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        let sql = format!(
+            "SELECT id, name, url, comment, enabled, audit_mode, tenant_id, created_at, updated_at
+             FROM blocklist_sources {} LIMIT ? OFFSET ?",
+            order_by(page, "name ASC")
+        );
+        let rows = sqlx::query_as::<_, BlocklistSourceRow>(&sql)
+            .bind(page.limit as i64)
+            .bind(page.offset as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to query page of blocklist sources");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        Ok((self.rows_to_sources(rows).await?, total as u64))
     }
 
     #[instrument(skip(self))]
@@ -176,6 +293,7 @@ impl BlocklistSourceRepository for SqliteBlocklistSourceRepository {
         group_ids: Option<Vec<i64>>,
         comment: Option<String>,
         enabled: Option<bool>,
+        audit_mode: Option<bool>,
     ) -> Result<BlocklistSource, DomainError> {
         let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
 
@@ -193,6 +311,7 @@ impl BlocklistSourceRepository for SqliteBlocklistSourceRepository {
         let final_comment: Option<String> =
             comment.or_else(|| current.comment.as_ref().map(|s| s.to_string()));
         let final_enabled = enabled.unwrap_or(current.enabled);
+        let final_audit_mode = audit_mode.unwrap_or(current.audit_mode);
         let legacy_group_id = final_group_ids.first().copied().unwrap_or(1);
 
         let mut tx = self.pool.begin().await.map_err(|e| {
@@ -202,15 +321,17 @@ impl BlocklistSourceRepository for SqliteBlocklistSourceRepository {
 
         let row = sqlx::query_as::<_, BlocklistSourceRow>(
             "UPDATE blocklist_sources
-             SET name = ?, url = ?, group_id = ?, comment = ?, enabled = ?, updated_at = ?
+             SET name = ?, url = ?, group_id = ?, comment = ?, enabled = ?, audit_mode = ?,
+                 updated_at = ?
              WHERE id = ?
-             RETURNING id, name, url, comment, enabled, created_at, updated_at",
+             RETURNING id, name, url, comment, enabled, audit_mode, tenant_id, created_at, updated_at",
         )
         .bind(&final_name)
         .bind(&final_url)
         .bind(legacy_group_id)
         .bind(&final_comment)
         .bind(if final_enabled { 1i64 } else { 0i64 })
+        .bind(if final_audit_mode { 1i64 } else { 0i64 })
         .bind(&now)
         .bind(id)
         .fetch_optional(&mut *tx)
@@ -275,4 +396,23 @@ impl BlocklistSourceRepository for SqliteBlocklistSourceRepository {
 
         Ok(())
     }
+
+    #[instrument(skip(self))]
+    async fn delete_in_tenant(&self, tenant_id: i64, id: i64) -> Result<(), DomainError> {
+        let result = sqlx::query("DELETE FROM blocklist_sources WHERE id = ? AND tenant_id = ?")
+            .bind(id)
+            .bind(tenant_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to delete tenant blocklist source");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::BlocklistSourceNotFound(id));
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,174 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::CacheTtlOverrideRepository;
+use ferrous_dns_domain::{CacheTtlOverride, DomainError};
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use tracing::{error, instrument};
+
+const OVERRIDE_COLUMNS: &str =
+    "id, domain, min_ttl, max_ttl, enabled, comment, created_at, updated_at";
+
+#[derive(sqlx::FromRow)]
+struct CacheTtlOverrideRow {
+    id: i64,
+    domain: String,
+    min_ttl: Option<i64>,
+    max_ttl: Option<i64>,
+    enabled: i64,
+    comment: Option<String>,
+    created_at: Option<String>,
+    updated_at: Option<String>,
+}
+
+pub struct SqliteCacheTtlOverrideRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteCacheTtlOverrideRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_override(row: CacheTtlOverrideRow) -> CacheTtlOverride {
+        CacheTtlOverride {
+            id: Some(row.id),
+            domain: Arc::from(row.domain.as_str()),
+            min_ttl: row.min_ttl.map(|ttl| ttl as u32),
+            max_ttl: row.max_ttl.map(|ttl| ttl as u32),
+            enabled: row.enabled != 0,
+            comment: row.comment.map(|s| Arc::from(s.as_str())),
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+
+    fn map_write_error(
+        e: sqlx::Error,
+        ttl_override: &CacheTtlOverride,
+        context: &str,
+    ) -> DomainError {
+        if e.to_string().contains("UNIQUE constraint failed") {
+            DomainError::InvalidCacheTtlOverride(format!(
+                "An override for '{}' already exists",
+                ttl_override.domain
+            ))
+        } else {
+            error!(error = %e, "{}", context);
+            DomainError::DatabaseError(e.to_string())
+        }
+    }
+}
+
+#[async_trait]
+impl CacheTtlOverrideRepository for SqliteCacheTtlOverrideRepository {
+    #[instrument(skip(self))]
+    async fn create(
+        &self,
+        ttl_override: &CacheTtlOverride,
+    ) -> Result<CacheTtlOverride, DomainError> {
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        let sql = format!(
+            "INSERT INTO cache_ttl_overrides
+                 (domain, min_ttl, max_ttl, enabled, comment, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?)
+             RETURNING {OVERRIDE_COLUMNS}"
+        );
+        let row: CacheTtlOverrideRow = sqlx::query_as(&sql)
+            .bind(ttl_override.domain.as_ref())
+            .bind(ttl_override.min_ttl.map(i64::from))
+            .bind(ttl_override.max_ttl.map(i64::from))
+            .bind(if ttl_override.enabled { 1i64 } else { 0i64 })
+            .bind(ttl_override.comment.as_deref())
+            .bind(&now)
+            .bind(&now)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| {
+                Self::map_write_error(e, ttl_override, "Failed to create cache TTL override")
+            })?;
+
+        Ok(Self::row_to_override(row))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_by_id(&self, id: i64) -> Result<Option<CacheTtlOverride>, DomainError> {
+        let sql = format!("SELECT {OVERRIDE_COLUMNS} FROM cache_ttl_overrides WHERE id = ?");
+        let row: Option<CacheTtlOverrideRow> = sqlx::query_as(&sql)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to query cache TTL override by id");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        Ok(row.map(Self::row_to_override))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_all(&self) -> Result<Vec<CacheTtlOverride>, DomainError> {
+        let sql = format!("SELECT {OVERRIDE_COLUMNS} FROM cache_ttl_overrides ORDER BY domain ASC");
+        let rows: Vec<CacheTtlOverrideRow> = sqlx::query_as(&sql)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to query all cache TTL overrides");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        Ok(rows.into_iter().map(Self::row_to_override).collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn update(
+        &self,
+        ttl_override: &CacheTtlOverride,
+    ) -> Result<CacheTtlOverride, DomainError> {
+        let id = ttl_override.id.ok_or_else(|| {
+            DomainError::InvalidCacheTtlOverride("override id is required".to_string())
+        })?;
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        let sql = format!(
+            "UPDATE cache_ttl_overrides SET
+                 domain = ?, min_ttl = ?, max_ttl = ?, enabled = ?, comment = ?, updated_at = ?
+             WHERE id = ?
+             RETURNING {OVERRIDE_COLUMNS}"
+        );
+        let row: Option<CacheTtlOverrideRow> = sqlx::query_as(&sql)
+            .bind(ttl_override.domain.as_ref())
+            .bind(ttl_override.min_ttl.map(i64::from))
+            .bind(ttl_override.max_ttl.map(i64::from))
+            .bind(if ttl_override.enabled { 1i64 } else { 0i64 })
+            .bind(ttl_override.comment.as_deref())
+            .bind(&now)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| {
+                Self::map_write_error(e, ttl_override, "Failed to update cache TTL override")
+            })?;
+
+        row.map(Self::row_to_override)
+            .ok_or(DomainError::CacheTtlOverrideNotFound(id))
+    }
+
+    #[instrument(skip(self))]
+    async fn delete(&self, id: i64) -> Result<(), DomainError> {
+        let result = sqlx::query("DELETE FROM cache_ttl_overrides WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to delete cache TTL override");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::CacheTtlOverrideNotFound(id));
+        }
+
+        Ok(())
+    }
+}
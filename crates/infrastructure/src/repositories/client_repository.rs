@@ -1,17 +1,21 @@
 use super::client_row_mapper::{
-    row_to_client, ClientRow, CLIENT_SELECT_ACTIVE, CLIENT_SELECT_ALL, CLIENT_SELECT_BY_ID,
-    CLIENT_SELECT_BY_IP, CLIENT_SELECT_NEEDS_HOSTNAME_UPDATE, CLIENT_SELECT_NEEDS_MAC_UPDATE,
+    row_to_client, ClientRow, CLIENT_SELECT, CLIENT_SELECT_ACTIVE, CLIENT_SELECT_ALL,
+    CLIENT_SELECT_BY_ID, CLIENT_SELECT_BY_IP, CLIENT_SELECT_NEEDS_HOSTNAME_UPDATE,
+    CLIENT_SELECT_NEEDS_MAC_UPDATE,
 };
+use super::paging::order_by;
 use async_trait::async_trait;
 use ferrous_dns_application::ports::ClientRepository;
-use ferrous_dns_domain::{config::DatabaseConfig, Client, ClientStats, DomainError};
+use ferrous_dns_domain::{
+    config::DatabaseConfig, Client, ClientCategory, ClientStats, DomainError, PageRequest,
+};
 use sqlx::SqlitePool;
 use std::net::IpAddr;
 use tokio::sync::{mpsc, oneshot};
 use tracing::{error, instrument, warn};
 
 enum ClientMsg {
-    IpSeen(IpAddr),
+    IpSeen(IpAddr, Option<i64>),
     Flush(oneshot::Sender<()>),
 }
 
@@ -33,13 +37,13 @@ impl SqliteClientRepository {
     async fn track_loop(pool: SqlitePool, mut receiver: mpsc::Receiver<ClientMsg>) {
         while let Some(msg) = receiver.recv().await {
             match msg {
-                ClientMsg::IpSeen(ip) => {
+                ClientMsg::IpSeen(ip, initial_group_id) => {
                     let ip_str = ip.to_string();
                     let timestamp = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
 
                     if let Err(e) = sqlx::query(
-                        "INSERT INTO clients (ip_address, first_seen, last_seen, query_count)
-                         VALUES (?, ?, ?, 1)
+                        "INSERT INTO clients (ip_address, first_seen, last_seen, query_count, group_id)
+                         VALUES (?, ?, ?, 1, COALESCE(?, 1))
                          ON CONFLICT(ip_address) DO UPDATE SET
                              last_seen = ?,
                              query_count = query_count + 1,
@@ -48,6 +52,7 @@ impl SqliteClientRepository {
                     .bind(&ip_str)
                     .bind(&timestamp)
                     .bind(&timestamp)
+                    .bind(initial_group_id)
                     .bind(&timestamp)
                     .bind(&timestamp)
                     .execute(&pool)
@@ -107,7 +112,18 @@ impl ClientRepository for SqliteClientRepository {
     }
 
     async fn update_last_seen(&self, ip_address: IpAddr) -> Result<(), DomainError> {
-        let _ = self.sender.try_send(ClientMsg::IpSeen(ip_address));
+        let _ = self.sender.try_send(ClientMsg::IpSeen(ip_address, None));
+        Ok(())
+    }
+
+    async fn update_last_seen_in_group(
+        &self,
+        ip_address: IpAddr,
+        initial_group_id: i64,
+    ) -> Result<(), DomainError> {
+        let _ = self
+            .sender
+            .try_send(ClientMsg::IpSeen(ip_address, Some(initial_group_id)));
         Ok(())
     }
 
@@ -246,6 +262,53 @@ impl ClientRepository for SqliteClientRepository {
         Ok(rows.into_iter().filter_map(row_to_client).collect())
     }
 
+    #[instrument(skip(self))]
+    async fn get_page(
+        &self,
+        page: &PageRequest,
+        active_days: Option<u32>,
+    ) -> Result<(Vec<Client>, u64), DomainError> {
+        let filter = if active_days.is_some() {
+            " WHERE last_seen > datetime('now', ?)"
+        } else {
+            ""
+        };
+        let since = active_days.map(|days| format!("-{} days", days));
+
+        let count_sql = format!("SELECT COUNT(*) FROM clients{filter}");
+        let mut count_query = sqlx::query_as::<_, (i64,)>(&count_sql);
+        if let Some(since) = &since {
+            count_query = count_query.bind(since);
+        }
+        let (total,) = count_query.fetch_one(&self.pool).await.map_err(|e| {
+            error!(error = %e, "Failed to count clients");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+        let sql = format!(
+            "{CLIENT_SELECT}{filter} {} LIMIT ? OFFSET ?",
+            order_by(page, "last_seen DESC")
+        );
+        let mut query = sqlx::query_as::<_, ClientRow>(&sql);
+        if let Some(since) = &since {
+            query = query.bind(since);
+        }
+        let rows = query
+            .bind(page.limit as i64)
+            .bind(page.offset as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to fetch page of clients");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        Ok((
+            rows.into_iter().filter_map(row_to_client).collect(),
+            total as u64,
+        ))
+    }
+
     #[instrument(skip(self))]
     async fn get_stats(&self) -> Result<ClientStats, DomainError> {
         let row = sqlx::query_as::<_, (i64, i64, i64, i64, i64)>(
@@ -376,6 +439,39 @@ impl ClientRepository for SqliteClientRepository {
         Ok(())
     }
 
+    #[instrument(skip(self))]
+    async fn update_metadata(
+        &self,
+        client_id: i64,
+        display_name: Option<String>,
+        category: Option<ClientCategory>,
+        notes: Option<String>,
+    ) -> Result<(), DomainError> {
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        let result = sqlx::query(
+            "UPDATE clients SET display_name = ?, category = ?, notes = ?, updated_at = ?
+             WHERE id = ?",
+        )
+        .bind(&display_name)
+        .bind(category.map(|c| c.to_str()))
+        .bind(&notes)
+        .bind(&now)
+        .bind(client_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to update client metadata");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::ClientNotFound(client_id.to_string()));
+        }
+
+        Ok(())
+    }
+
     #[instrument(skip(self))]
     async fn delete(&self, id: i64) -> Result<(), DomainError> {
         let result = sqlx::query("DELETE FROM clients WHERE id = ?")
@@ -1,4 +1,4 @@
-use ferrous_dns_domain::Client;
+use ferrous_dns_domain::{Client, ClientCategory};
 use std::sync::Arc;
 
 pub(crate) type ClientRow = (
@@ -12,6 +12,9 @@ pub(crate) type ClientRow = (
     Option<i64>,
     Option<i64>,
     Option<i64>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
 );
 
 pub(crate) const CLIENT_SELECT: &str = "SELECT id, ip_address, mac_address, hostname,
@@ -20,7 +23,7 @@ pub(crate) const CLIENT_SELECT: &str = "SELECT id, ip_address, mac_address, host
             query_count,
             CAST(strftime('%s', last_mac_update) AS INTEGER) as last_mac_update,
             CAST(strftime('%s', last_hostname_update) AS INTEGER) as last_hostname_update,
-            group_id
+            group_id, display_name, category, notes
      FROM clients";
 
 pub(crate) const CLIENT_SELECT_BY_IP: &str = "SELECT id, ip_address, mac_address, hostname,
@@ -29,7 +32,7 @@ pub(crate) const CLIENT_SELECT_BY_IP: &str = "SELECT id, ip_address, mac_address
             query_count,
             CAST(strftime('%s', last_mac_update) AS INTEGER) as last_mac_update,
             CAST(strftime('%s', last_hostname_update) AS INTEGER) as last_hostname_update,
-            group_id
+            group_id, display_name, category, notes
      FROM clients WHERE ip_address = ?";
 
 pub(crate) const CLIENT_SELECT_BY_ID: &str = "SELECT id, ip_address, mac_address, hostname,
@@ -38,7 +41,7 @@ pub(crate) const CLIENT_SELECT_BY_ID: &str = "SELECT id, ip_address, mac_address
             query_count,
             CAST(strftime('%s', last_mac_update) AS INTEGER) as last_mac_update,
             CAST(strftime('%s', last_hostname_update) AS INTEGER) as last_hostname_update,
-            group_id
+            group_id, display_name, category, notes
      FROM clients WHERE id = ?";
 
 pub(crate) const CLIENT_SELECT_ALL: &str = "SELECT id, ip_address, mac_address, hostname,
@@ -47,7 +50,7 @@ pub(crate) const CLIENT_SELECT_ALL: &str = "SELECT id, ip_address, mac_address,
             query_count,
             CAST(strftime('%s', last_mac_update) AS INTEGER) as last_mac_update,
             CAST(strftime('%s', last_hostname_update) AS INTEGER) as last_hostname_update,
-            group_id
+            group_id, display_name, category, notes
      FROM clients ORDER BY last_seen DESC LIMIT ? OFFSET ?";
 
 pub(crate) const CLIENT_SELECT_ACTIVE: &str = "SELECT id, ip_address, mac_address, hostname,
@@ -56,7 +59,7 @@ pub(crate) const CLIENT_SELECT_ACTIVE: &str = "SELECT id, ip_address, mac_addres
             query_count,
             CAST(strftime('%s', last_mac_update) AS INTEGER) as last_mac_update,
             CAST(strftime('%s', last_hostname_update) AS INTEGER) as last_hostname_update,
-            group_id
+            group_id, display_name, category, notes
      FROM clients WHERE last_seen > datetime('now', ?) ORDER BY last_seen DESC LIMIT ?";
 
 pub(crate) const CLIENT_SELECT_NEEDS_MAC_UPDATE: &str =
@@ -66,7 +69,7 @@ pub(crate) const CLIENT_SELECT_NEEDS_MAC_UPDATE: &str =
             query_count,
             CAST(strftime('%s', last_mac_update) AS INTEGER) as last_mac_update,
             CAST(strftime('%s', last_hostname_update) AS INTEGER) as last_hostname_update,
-            group_id
+            group_id, display_name, category, notes
      FROM clients WHERE (last_mac_update IS NULL
                          OR last_mac_update < datetime('now', '-5 minutes'))
      AND last_seen > datetime('now', '-1 day')
@@ -79,7 +82,7 @@ pub(crate) const CLIENT_SELECT_NEEDS_HOSTNAME_UPDATE: &str =
             query_count,
             CAST(strftime('%s', last_mac_update) AS INTEGER) as last_mac_update,
             CAST(strftime('%s', last_hostname_update) AS INTEGER) as last_hostname_update,
-            group_id
+            group_id, display_name, category, notes
      FROM clients WHERE (last_hostname_update IS NULL
                          OR last_hostname_update < datetime('now', '-1 hour'))
      AND last_seen > datetime('now', '-7 days')
@@ -97,6 +100,9 @@ pub(crate) fn row_to_client(row: ClientRow) -> Option<Client> {
         last_mac_update,
         last_hostname_update,
         group_id,
+        display_name,
+        category,
+        notes,
     ) = row;
 
     Some(Client {
@@ -110,5 +116,8 @@ pub(crate) fn row_to_client(row: ClientRow) -> Option<Client> {
         last_mac_update,
         last_hostname_update,
         group_id,
+        display_name: display_name.map(|s| Arc::from(s.as_str())),
+        category: category.and_then(|c| c.parse::<ClientCategory>().ok()),
+        notes: notes.map(|s| Arc::from(s.as_str())),
     })
 }
@@ -1,5 +1,5 @@
 use ferrous_dns_application::ports::ConfigFilePersistence;
-use ferrous_dns_domain::{config::errors::ConfigError, Config};
+use ferrous_dns_domain::{config::errors::ConfigError, Config, UdpSocketMode};
 
 pub struct TomlConfigFilePersistence;
 
@@ -158,6 +158,11 @@ pub fn save_config_to_file(config: &Config, path: &str) -> Result<(), ConfigErro
             "dnssec_enabled",
             toml_edit::Value::from(config.dns.dnssec_enabled),
         );
+        set_val(
+            t,
+            "case_randomization",
+            toml_edit::Value::from(config.dns.case_randomization),
+        );
         set_val(
             t,
             "default_strategy",
@@ -266,6 +271,12 @@ pub fn save_config_to_file(config: &Config, path: &str) -> Result<(), ConfigErro
                 if let Some(weight) = pool.weight {
                     table.insert("weight", toml_edit::value(weight as i64));
                 }
+                if let Some(ref key) = pool.tsig_key {
+                    table.insert("tsig_key", toml_edit::value(key.clone()));
+                }
+                if pool.udp_socket != UdpSocketMode::Pooled {
+                    table.insert("udp_socket", toml_edit::value(pool.udp_socket.as_str()));
+                }
                 aot.push(table);
             }
             dns.insert("pools", toml_edit::Item::ArrayOfTables(aot));
@@ -538,6 +549,9 @@ pub fn save_local_records_to_file(config: &Config, path: &str) -> Result<(), Con
             if let Some(ttl) = record.ttl {
                 table.insert("ttl", toml_edit::value(ttl as i64));
             }
+            if let Some(ref view) = record.view {
+                table.insert("view", toml_edit::value(view.clone()));
+            }
             aot.push(table);
         }
         dns.insert("local_records", toml_edit::Item::ArrayOfTables(aot));
@@ -0,0 +1,259 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::DeviceRepository;
+use ferrous_dns_domain::{Device, DeviceIpHistory, DomainError, DuplicateClients};
+use sqlx::SqlitePool;
+use std::net::IpAddr;
+use std::sync::Arc;
+use tracing::{error, instrument};
+
+type DeviceRow = (i64, String, Option<String>, String, String);
+type HistoryRow = (String, String, String);
+
+pub struct SqliteDeviceRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteDeviceRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_device(row: DeviceRow) -> Device {
+        let (id, mac_address, current_ip, first_seen, last_seen) = row;
+
+        Device {
+            id: Some(id),
+            mac_address: Arc::from(mac_address.as_str()),
+            current_ip: current_ip.and_then(|ip| ip.parse().ok()),
+            first_seen: Some(first_seen),
+            last_seen: Some(last_seen),
+        }
+    }
+
+    fn placeholders(count: usize) -> String {
+        vec!["?"; count].join(", ")
+    }
+}
+
+fn db_error(context: &'static str) -> impl Fn(sqlx::Error) -> DomainError {
+    move |e| {
+        error!(error = %e, "{}", context);
+        DomainError::DatabaseError(e.to_string())
+    }
+}
+
+#[async_trait]
+impl DeviceRepository for SqliteDeviceRepository {
+    #[instrument(skip(self))]
+    async fn record_sighting(
+        &self,
+        mac_address: &str,
+        ip_address: IpAddr,
+    ) -> Result<(), DomainError> {
+        let ip_str = ip_address.to_string();
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(db_error("Failed to begin transaction"))?;
+
+        sqlx::query(
+            "INSERT INTO devices (mac_address, current_ip, first_seen, last_seen)
+             VALUES (?, ?, ?, ?)
+             ON CONFLICT(mac_address) DO UPDATE SET
+                 current_ip = excluded.current_ip,
+                 last_seen = excluded.last_seen",
+        )
+        .bind(mac_address)
+        .bind(&ip_str)
+        .bind(&now)
+        .bind(&now)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error("Failed to upsert device"))?;
+
+        sqlx::query(
+            "INSERT INTO device_ip_history (device_id, ip_address, first_seen, last_seen)
+             SELECT id, ?, ?, ? FROM devices WHERE mac_address = ?
+             ON CONFLICT(device_id, ip_address) DO UPDATE SET
+                 last_seen = excluded.last_seen",
+        )
+        .bind(&ip_str)
+        .bind(&now)
+        .bind(&now)
+        .bind(mac_address)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error("Failed to record device IP history"))?;
+
+        tx.commit()
+            .await
+            .map_err(db_error("Failed to commit device sighting"))?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn get_all(&self) -> Result<Vec<Device>, DomainError> {
+        let rows = sqlx::query_as::<_, DeviceRow>(
+            "SELECT id, mac_address, current_ip, first_seen, last_seen
+             FROM devices ORDER BY last_seen DESC",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error("Failed to fetch devices"))?;
+
+        Ok(rows.into_iter().map(Self::row_to_device).collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn get_by_mac(&self, mac_address: &str) -> Result<Option<Device>, DomainError> {
+        let row = sqlx::query_as::<_, DeviceRow>(
+            "SELECT id, mac_address, current_ip, first_seen, last_seen
+             FROM devices WHERE mac_address = ?",
+        )
+        .bind(mac_address)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(db_error("Failed to fetch device"))?;
+
+        Ok(row.map(Self::row_to_device))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_ip_history(&self, device_id: i64) -> Result<Vec<DeviceIpHistory>, DomainError> {
+        let rows = sqlx::query_as::<_, HistoryRow>(
+            "SELECT ip_address, first_seen, last_seen
+             FROM device_ip_history
+             WHERE device_id = ?
+             ORDER BY last_seen DESC",
+        )
+        .bind(device_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error("Failed to fetch device IP history"))?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(ip, first_seen, last_seen)| {
+                Some(DeviceIpHistory {
+                    ip_address: ip.parse().ok()?,
+                    first_seen,
+                    last_seen,
+                })
+            })
+            .collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn find_duplicate_clients(&self) -> Result<Vec<DuplicateClients>, DomainError> {
+        let rows = sqlx::query_as::<_, (String, i64)>(
+            "SELECT lower(mac_address) AS mac, id
+             FROM clients
+             WHERE lower(mac_address) IN (
+                 SELECT lower(mac_address)
+                 FROM clients
+                 WHERE mac_address IS NOT NULL
+                   AND lower(mac_address) NOT IN ('00:00:00:00:00:00', 'ff:ff:ff:ff:ff:ff')
+                 GROUP BY lower(mac_address)
+                 HAVING COUNT(*) > 1
+             )
+             ORDER BY mac, last_seen DESC, id DESC",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error("Failed to find duplicate clients"))?;
+
+        let mut groups: Vec<DuplicateClients> = Vec::new();
+        for (mac, id) in rows {
+            match groups.last_mut() {
+                Some(group) if *group.mac_address == *mac => group.client_ids.push(id),
+                _ => groups.push(DuplicateClients {
+                    mac_address: Arc::from(mac.as_str()),
+                    client_ids: vec![id],
+                }),
+            }
+        }
+        Ok(groups)
+    }
+
+    #[instrument(skip(self))]
+    async fn merge_clients(&self, keep_id: i64, duplicate_ids: &[i64]) -> Result<u64, DomainError> {
+        let duplicate_ids: Vec<i64> = duplicate_ids
+            .iter()
+            .copied()
+            .filter(|&id| id != keep_id)
+            .collect();
+        if duplicate_ids.is_empty() {
+            return Ok(0);
+        }
+
+        let in_list = Self::placeholders(duplicate_ids.len());
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(db_error("Failed to begin transaction"))?;
+
+        let update_sql = format!(
+            "UPDATE clients SET
+                 query_count = query_count + (
+                     SELECT COALESCE(SUM(query_count), 0) FROM clients WHERE id IN ({in_list})
+                 ),
+                 first_seen = MIN(first_seen, COALESCE(
+                     (SELECT MIN(first_seen) FROM clients WHERE id IN ({in_list})),
+                     first_seen
+                 )),
+                 hostname = COALESCE(hostname, (
+                     SELECT hostname FROM clients
+                     WHERE id IN ({in_list}) AND hostname IS NOT NULL
+                     ORDER BY last_seen DESC LIMIT 1
+                 )),
+                 group_id = COALESCE(group_id, (
+                     SELECT group_id FROM clients
+                     WHERE id IN ({in_list}) AND group_id IS NOT NULL
+                     ORDER BY last_seen DESC LIMIT 1
+                 )),
+                 updated_at = ?
+             WHERE id = ?"
+        );
+
+        let mut update = sqlx::query(&update_sql);
+        for _ in 0..4 {
+            for id in &duplicate_ids {
+                update = update.bind(id);
+            }
+        }
+        let result = update
+            .bind(&now)
+            .bind(keep_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error("Failed to merge client statistics"))?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::ClientNotFound(keep_id.to_string()));
+        }
+
+        let delete_sql = format!("DELETE FROM clients WHERE id IN ({in_list})");
+        let mut delete = sqlx::query(&delete_sql);
+        for id in &duplicate_ids {
+            delete = delete.bind(id);
+        }
+        let removed = delete
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error("Failed to delete merged clients"))?
+            .rows_affected();
+
+        tx.commit()
+            .await
+            .map_err(db_error("Failed to commit client merge"))?;
+
+        Ok(removed)
+    }
+}
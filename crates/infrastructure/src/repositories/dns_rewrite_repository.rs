@@ -0,0 +1,155 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::DnsRewriteRepository;
+use ferrous_dns_domain::{DnsRewrite, DomainError};
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use tracing::{error, instrument};
+
+const REWRITE_COLUMNS: &str = "id, domain, answer, enabled, comment, created_at, updated_at";
+
+#[derive(sqlx::FromRow)]
+struct DnsRewriteRow {
+    id: i64,
+    domain: String,
+    answer: String,
+    enabled: i64,
+    comment: Option<String>,
+    created_at: Option<String>,
+    updated_at: Option<String>,
+}
+
+pub struct SqliteDnsRewriteRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteDnsRewriteRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_rewrite(row: DnsRewriteRow) -> DnsRewrite {
+        DnsRewrite {
+            id: Some(row.id),
+            domain: Arc::from(row.domain.as_str()),
+            answer: Arc::from(row.answer.as_str()),
+            enabled: row.enabled != 0,
+            comment: row.comment.map(|s| Arc::from(s.as_str())),
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+
+    fn map_write_error(e: sqlx::Error, rewrite: &DnsRewrite, context: &str) -> DomainError {
+        if e.to_string().contains("UNIQUE constraint failed") {
+            DomainError::InvalidDnsRewrite(format!(
+                "Rewrite '{}' -> '{}' already exists",
+                rewrite.domain, rewrite.answer
+            ))
+        } else {
+            error!(error = %e, "{}", context);
+            DomainError::DatabaseError(e.to_string())
+        }
+    }
+}
+
+#[async_trait]
+impl DnsRewriteRepository for SqliteDnsRewriteRepository {
+    #[instrument(skip(self))]
+    async fn create(&self, rewrite: &DnsRewrite) -> Result<DnsRewrite, DomainError> {
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        let sql = format!(
+            "INSERT INTO dns_rewrites (domain, answer, enabled, comment, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?)
+             RETURNING {REWRITE_COLUMNS}"
+        );
+        let row: DnsRewriteRow = sqlx::query_as(&sql)
+            .bind(rewrite.domain.as_ref())
+            .bind(rewrite.answer.as_ref())
+            .bind(if rewrite.enabled { 1i64 } else { 0i64 })
+            .bind(rewrite.comment.as_deref())
+            .bind(&now)
+            .bind(&now)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| Self::map_write_error(e, rewrite, "Failed to create DNS rewrite"))?;
+
+        Ok(Self::row_to_rewrite(row))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_by_id(&self, id: i64) -> Result<Option<DnsRewrite>, DomainError> {
+        let sql = format!("SELECT {REWRITE_COLUMNS} FROM dns_rewrites WHERE id = ?");
+        let row: Option<DnsRewriteRow> = sqlx::query_as(&sql)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to query DNS rewrite by id");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        Ok(row.map(Self::row_to_rewrite))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_all(&self) -> Result<Vec<DnsRewrite>, DomainError> {
+        let sql = format!("SELECT {REWRITE_COLUMNS} FROM dns_rewrites ORDER BY domain ASC, id ASC");
+        let rows: Vec<DnsRewriteRow> =
+            sqlx::query_as(&sql)
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| {
+                    error!(error = %e, "Failed to query all DNS rewrites");
+                    DomainError::DatabaseError(e.to_string())
+                })?;
+
+        Ok(rows.into_iter().map(Self::row_to_rewrite).collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn update(&self, rewrite: &DnsRewrite) -> Result<DnsRewrite, DomainError> {
+        let id = rewrite
+            .id
+            .ok_or_else(|| DomainError::InvalidDnsRewrite("rewrite id is required".to_string()))?;
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        let sql = format!(
+            "UPDATE dns_rewrites SET
+                 domain = ?, answer = ?, enabled = ?, comment = ?, updated_at = ?
+             WHERE id = ?
+             RETURNING {REWRITE_COLUMNS}"
+        );
+        let row: Option<DnsRewriteRow> = sqlx::query_as(&sql)
+            .bind(rewrite.domain.as_ref())
+            .bind(rewrite.answer.as_ref())
+            .bind(if rewrite.enabled { 1i64 } else { 0i64 })
+            .bind(rewrite.comment.as_deref())
+            .bind(&now)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| Self::map_write_error(e, rewrite, "Failed to update DNS rewrite"))?;
+
+        row.map(Self::row_to_rewrite)
+            .ok_or(DomainError::DnsRewriteNotFound(id))
+    }
+
+    #[instrument(skip(self))]
+    async fn delete(&self, id: i64) -> Result<(), DomainError> {
+        let result = sqlx::query("DELETE FROM dns_rewrites WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to delete DNS rewrite");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::DnsRewriteNotFound(id));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,188 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::DnssecHistoryRepository;
+use ferrous_dns_domain::query_log::parse_dnssec_status;
+use ferrous_dns_domain::{
+    DnssecStatsSample, DnssecStatusChange, DnssecValidationCounts, DomainError, RecordType,
+};
+use sqlx::SqlitePool;
+use tracing::{error, instrument, warn};
+
+type SampleRow = (i64, i64, i64, i64, String);
+
+type ChangeRow = (i64, String, String, String, Option<String>, String);
+
+const CHANGE_COLUMNS: &str = "id, domain, record_type, status, previous_status, observed_at";
+
+pub struct SqliteDnssecHistoryRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteDnssecHistoryRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_change(row: ChangeRow) -> Option<DnssecStatusChange> {
+        let (id, domain, record_type, status, previous_status, observed_at) = row;
+        let Ok(record_type) = record_type.parse::<RecordType>() else {
+            warn!(record_type = %record_type, "Unknown record type in DNSSEC history, skipping");
+            return None;
+        };
+        let Ok(status) = parse_dnssec_status(&status) else {
+            warn!(status = %status, "Unknown DNSSEC status in history, skipping");
+            return None;
+        };
+        Some(DnssecStatusChange {
+            id: Some(id),
+            domain,
+            record_type,
+            status,
+            previous_status: previous_status.and_then(|s| parse_dnssec_status(&s).ok()),
+            observed_at,
+        })
+    }
+}
+
+fn db_error(context: &'static str) -> impl FnOnce(sqlx::Error) -> DomainError {
+    move |e| {
+        error!(error = %e, "{}", context);
+        DomainError::DatabaseError(e.to_string())
+    }
+}
+
+fn cutoff(hours: i64) -> String {
+    (chrono::Utc::now() - chrono::Duration::hours(hours))
+        .format("%Y-%m-%d %H:%M:%S")
+        .to_string()
+}
+
+#[async_trait]
+impl DnssecHistoryRepository for SqliteDnssecHistoryRepository {
+    #[instrument(skip(self))]
+    async fn insert_sample(&self, counts: &DnssecValidationCounts) -> Result<(), DomainError> {
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        sqlx::query(
+            "INSERT INTO dnssec_stats (secure, insecure, bogus, indeterminate, recorded_at)
+             VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(counts.secure as i64)
+        .bind(counts.insecure as i64)
+        .bind(counts.bogus as i64)
+        .bind(counts.indeterminate as i64)
+        .bind(&now)
+        .execute(&self.pool)
+        .await
+        .map_err(db_error("Failed to insert DNSSEC stats sample"))?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, changes), fields(count = changes.len()))]
+    async fn insert_changes(&self, changes: &[DnssecStatusChange]) -> Result<(), DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(db_error("Failed to begin DNSSEC history transaction"))?;
+
+        for change in changes {
+            sqlx::query(
+                "INSERT INTO dnssec_status_changes
+                     (domain, record_type, status, previous_status, observed_at)
+                 VALUES (?, ?, ?, ?, ?)",
+            )
+            .bind(&change.domain)
+            .bind(change.record_type.as_str())
+            .bind(change.status)
+            .bind(change.previous_status)
+            .bind(&change.observed_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error("Failed to insert DNSSEC status change"))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(db_error("Failed to commit DNSSEC status changes"))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_samples(&self, period_hours: u32) -> Result<Vec<DnssecStatsSample>, DomainError> {
+        let rows = sqlx::query_as::<_, SampleRow>(
+            "SELECT secure, insecure, bogus, indeterminate, recorded_at
+             FROM dnssec_stats
+             WHERE recorded_at >= ?
+             ORDER BY recorded_at ASC, id ASC",
+        )
+        .bind(cutoff(period_hours as i64))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error("Failed to fetch DNSSEC stats"))?;
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(secure, insecure, bogus, indeterminate, recorded_at)| DnssecStatsSample {
+                    counts: DnssecValidationCounts {
+                        secure: secure as u64,
+                        insecure: insecure as u64,
+                        bogus: bogus as u64,
+                        indeterminate: indeterminate as u64,
+                    },
+                    recorded_at,
+                },
+            )
+            .collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn get_changes(
+        &self,
+        domain: Option<&str>,
+        status: Option<&'static str>,
+        limit: u32,
+    ) -> Result<Vec<DnssecStatusChange>, DomainError> {
+        let mut sql = format!("SELECT {CHANGE_COLUMNS} FROM dnssec_status_changes WHERE 1 = 1");
+        if domain.is_some() {
+            sql.push_str(" AND domain = ?");
+        }
+        if status.is_some() {
+            sql.push_str(" AND status = ?");
+        }
+        sql.push_str(" ORDER BY observed_at DESC, id DESC LIMIT ?");
+
+        let mut query = sqlx::query_as::<_, ChangeRow>(&sql);
+        if let Some(domain) = domain {
+            query = query.bind(domain);
+        }
+        if let Some(status) = status {
+            query = query.bind(status);
+        }
+        let rows = query
+            .bind(limit as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(db_error("Failed to fetch DNSSEC status changes"))?;
+
+        Ok(rows.into_iter().filter_map(Self::row_to_change).collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete_older_than(&self, days: u32) -> Result<u64, DomainError> {
+        let cutoff = cutoff(days as i64 * 24);
+
+        let samples = sqlx::query("DELETE FROM dnssec_stats WHERE recorded_at < ?")
+            .bind(&cutoff)
+            .execute(&self.pool)
+            .await
+            .map_err(db_error("Failed to delete old DNSSEC stats"))?;
+        let changes = sqlx::query("DELETE FROM dnssec_status_changes WHERE observed_at < ?")
+            .bind(&cutoff)
+            .execute(&self.pool)
+            .await
+            .map_err(db_error("Failed to delete old DNSSEC status changes"))?;
+
+        Ok(samples.rows_affected() + changes.rows_affected())
+    }
+}
@@ -1,12 +1,37 @@
 use super::client_row_mapper::{row_to_client, ClientRow, CLIENT_SELECT};
+use super::paging::order_by;
 use async_trait::async_trait;
 use ferrous_dns_application::ports::GroupRepository;
-use ferrous_dns_domain::{Client, DomainError, Group};
+use ferrous_dns_domain::{Client, DomainError, Group, PageRequest};
 use sqlx::SqlitePool;
 use std::sync::Arc;
 use tracing::{error, instrument};
 
-type GroupRow = (i64, String, i64, Option<String>, i64, String, String);
+type GroupRow = (
+    i64,
+    String,
+    i64,
+    Option<String>,
+    i64,
+    i64,
+    i64,
+    Option<i64>,
+    String,
+    String,
+);
+type GroupCountRow = (
+    i64,
+    String,
+    i64,
+    Option<String>,
+    i64,
+    i64,
+    i64,
+    Option<i64>,
+    String,
+    String,
+    i64,
+);
 
 pub struct SqliteGroupRepository {
     pool: SqlitePool,
@@ -18,7 +43,18 @@ impl SqliteGroupRepository {
     }
 
     fn row_to_group(row: GroupRow) -> Group {
-        let (id, name, enabled, comment, is_default, created_at, updated_at) = row;
+        let (
+            id,
+            name,
+            enabled,
+            comment,
+            is_default,
+            filter_aaaa,
+            audit_mode,
+            tenant_id,
+            created_at,
+            updated_at,
+        ) = row;
 
         Group {
             id: Some(id),
@@ -26,10 +62,42 @@ impl SqliteGroupRepository {
             enabled: enabled != 0,
             comment: comment.map(|s| Arc::from(s.as_str())),
             is_default: is_default != 0,
+            filter_aaaa: filter_aaaa != 0,
+            audit_mode: audit_mode != 0,
+            tenant_id,
             created_at: Some(created_at),
             updated_at: Some(updated_at),
         }
     }
+
+    fn row_to_group_with_count(row: GroupCountRow) -> (Group, u64) {
+        let (
+            id,
+            name,
+            enabled,
+            comment,
+            is_default,
+            filter_aaaa,
+            audit_mode,
+            tenant_id,
+            created_at,
+            updated_at,
+            count,
+        ) = row;
+        let group = Self::row_to_group((
+            id,
+            name,
+            enabled,
+            comment,
+            is_default,
+            filter_aaaa,
+            audit_mode,
+            tenant_id,
+            created_at,
+            updated_at,
+        ));
+        (group, count as u64)
+    }
 }
 
 #[async_trait]
@@ -41,7 +109,8 @@ impl GroupRepository for SqliteGroupRepository {
         let row = sqlx::query_as::<_, GroupRow>(
             "INSERT INTO groups (name, enabled, comment, is_default, created_at, updated_at)
              VALUES (?, 1, ?, 0, ?, ?)
-             RETURNING id, name, enabled, comment, is_default, created_at, updated_at",
+             RETURNING id, name, enabled, comment, is_default, filter_aaaa, audit_mode, tenant_id,
+                      created_at, updated_at",
         )
         .bind(&name)
         .bind(&comment)
@@ -64,7 +133,8 @@ impl GroupRepository for SqliteGroupRepository {
     #[instrument(skip(self))]
     async fn get_by_id(&self, id: i64) -> Result<Option<Group>, DomainError> {
         let row = sqlx::query_as::<_, GroupRow>(
-            "SELECT id, name, enabled, comment, is_default, created_at, updated_at
+            "SELECT id, name, enabled, comment, is_default, filter_aaaa, audit_mode, tenant_id,
+                      created_at, updated_at
              FROM groups WHERE id = ?",
         )
         .bind(id)
@@ -81,7 +151,8 @@ impl GroupRepository for SqliteGroupRepository {
     #[instrument(skip(self))]
     async fn get_by_name(&self, name: &str) -> Result<Option<Group>, DomainError> {
         let row = sqlx::query_as::<_, GroupRow>(
-            "SELECT id, name, enabled, comment, is_default, created_at, updated_at
+            "SELECT id, name, enabled, comment, is_default, filter_aaaa, audit_mode, tenant_id,
+                      created_at, updated_at
              FROM groups WHERE name = ?",
         )
         .bind(name)
@@ -98,7 +169,8 @@ impl GroupRepository for SqliteGroupRepository {
     #[instrument(skip(self))]
     async fn get_all(&self) -> Result<Vec<Group>, DomainError> {
         let rows = sqlx::query_as::<_, GroupRow>(
-            "SELECT id, name, enabled, comment, is_default, created_at, updated_at
+            "SELECT id, name, enabled, comment, is_default, filter_aaaa, audit_mode, tenant_id,
+                      created_at, updated_at
              FROM groups ORDER BY is_default DESC, name ASC",
         )
         .fetch_all(&self.pool)
@@ -113,8 +185,9 @@ impl GroupRepository for SqliteGroupRepository {
 
     #[instrument(skip(self))]
     async fn get_all_with_client_counts(&self) -> Result<Vec<(Group, u64)>, DomainError> {
-        let rows = sqlx::query_as::<_, (i64, String, i64, Option<String>, i64, String, String, i64)>(
-            "SELECT g.id, g.name, g.enabled, g.comment, g.is_default, g.created_at, g.updated_at,
+        let rows = sqlx::query_as::<_, GroupCountRow>(
+            "SELECT g.id, g.name, g.enabled, g.comment, g.is_default, g.filter_aaaa, g.audit_mode,
+                    g.tenant_id, g.created_at, g.updated_at,
                     COUNT(c.id) as client_count
              FROM groups g
              LEFT JOIN clients c ON c.group_id = g.id
@@ -130,23 +203,83 @@ impl GroupRepository for SqliteGroupRepository {
 
         Ok(rows
             .into_iter()
-            .map(
-                |(id, name, enabled, comment, is_default, created_at, updated_at, count)| {
-                    let group = Group {
-                        id: Some(id),
-                        name: Arc::from(name.as_str()),
-                        enabled: enabled != 0,
-                        comment: comment.map(|s| Arc::from(s.as_str())),
-                        is_default: is_default != 0,
-                        created_at: Some(created_at),
-                        updated_at: Some(updated_at),
-                    };
-                    (group, count as u64)
-                },
-            )
+            .map(Self::row_to_group_with_count)
+            .collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn get_in_tenant_with_client_counts(
+        &self,
+        tenant_id: i64,
+    ) -> Result<Vec<(Group, u64)>, DomainError> {
+        let rows = sqlx::query_as::<_, GroupCountRow>(
+            "SELECT g.id, g.name, g.enabled, g.comment, g.is_default, g.filter_aaaa, g.audit_mode,
+                    g.tenant_id, g.created_at, g.updated_at,
+                    COUNT(c.id) as client_count
+             FROM groups g
+             LEFT JOIN clients c ON c.group_id = g.id
+             WHERE g.tenant_id = ?
+             GROUP BY g.id
+             ORDER BY g.name ASC",
+        )
+        .bind(tenant_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to query tenant groups");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(rows
+            .into_iter()
+            .map(Self::row_to_group_with_count)
             .collect())
     }
 
+    #[instrument(skip(self))]
+    async fn get_page_with_client_counts(
+        &self,
+        page: &PageRequest,
+    ) -> Result<(Vec<(Group, u64)>, u64), DomainError> {
+        let (total,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM groups")
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to count groups");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        let sql = format!(
+            "SELECT * FROM (
+                SELECT g.id, g.name, g.enabled, g.comment, g.is_default, g.filter_aaaa, g.audit_mode,
+                       g.tenant_id, g.created_at, g.updated_at,
+                       COUNT(c.id) as client_count
+                FROM groups g
+                LEFT JOIN clients c ON c.group_id = g.id
+                GROUP BY g.id
+             )
+             {}
+             LIMIT ? OFFSET ?",
+            order_by(page, "is_default DESC, name ASC")
+        );
+        let rows = sqlx::query_as::<_, GroupCountRow>(&sql)
+            .bind(page.limit as i64)
+            .bind(page.offset as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to query page of groups");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        Ok((
+            rows.into_iter()
+                .map(Self::row_to_group_with_count)
+                .collect(),
+            total as u64,
+        ))
+    }
+
     #[instrument(skip(self))]
     async fn update(
         &self,
@@ -154,6 +287,8 @@ impl GroupRepository for SqliteGroupRepository {
         name: Option<String>,
         enabled: Option<bool>,
         comment: Option<String>,
+        filter_aaaa: Option<bool>,
+        audit_mode: Option<bool>,
     ) -> Result<Group, DomainError> {
         let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
 
@@ -165,15 +300,21 @@ impl GroupRepository for SqliteGroupRepository {
         let final_name = name.unwrap_or_else(|| current.name.to_string());
         let final_enabled = enabled.unwrap_or(current.enabled);
         let final_comment = comment.or_else(|| current.comment.as_ref().map(|s| s.to_string()));
+        let final_filter_aaaa = filter_aaaa.unwrap_or(current.filter_aaaa);
+        let final_audit_mode = audit_mode.unwrap_or(current.audit_mode);
 
         let row = sqlx::query_as::<_, GroupRow>(
-            "UPDATE groups SET name = ?, enabled = ?, comment = ?, updated_at = ?
+            "UPDATE groups SET name = ?, enabled = ?, comment = ?, filter_aaaa = ?, audit_mode = ?,
+                 updated_at = ?
              WHERE id = ?
-             RETURNING id, name, enabled, comment, is_default, created_at, updated_at",
+             RETURNING id, name, enabled, comment, is_default, filter_aaaa, audit_mode, tenant_id,
+                      created_at, updated_at",
         )
         .bind(&final_name)
         .bind(if final_enabled { 1 } else { 0 })
         .bind(&final_comment)
+        .bind(if final_filter_aaaa { 1 } else { 0 })
+        .bind(if final_audit_mode { 1 } else { 0 })
         .bind(&now)
         .bind(id)
         .fetch_optional(&self.pool)
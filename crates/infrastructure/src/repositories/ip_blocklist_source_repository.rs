@@ -0,0 +1,167 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::IpBlocklistSourceRepository;
+use ferrous_dns_domain::{DomainError, IpBlocklistSource};
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use tracing::{error, instrument};
+
+type IpBlocklistSourceRow = (i64, String, String, Option<String>, i64, String, String);
+
+pub struct SqliteIpBlocklistSourceRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteIpBlocklistSourceRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_source(row: IpBlocklistSourceRow) -> IpBlocklistSource {
+        let (id, name, url, comment, enabled, created_at, updated_at) = row;
+        IpBlocklistSource {
+            id: Some(id),
+            name: Arc::from(name.as_str()),
+            url: Arc::from(url.as_str()),
+            comment: comment.map(|s| Arc::from(s.as_str())),
+            enabled: enabled != 0,
+            created_at: Some(created_at),
+            updated_at: Some(updated_at),
+        }
+    }
+
+    fn map_write_error(e: sqlx::Error, name: &str, context: &str) -> DomainError {
+        if e.to_string().contains("UNIQUE constraint failed") {
+            DomainError::InvalidIpBlocklistSource(format!(
+                "IP blocklist source '{}' already exists",
+                name
+            ))
+        } else {
+            error!(error = %e, "{}", context);
+            DomainError::DatabaseError(e.to_string())
+        }
+    }
+}
+
+#[async_trait]
+impl IpBlocklistSourceRepository for SqliteIpBlocklistSourceRepository {
+    #[instrument(skip(self))]
+    async fn create(
+        &self,
+        name: String,
+        url: String,
+        comment: Option<String>,
+        enabled: bool,
+    ) -> Result<IpBlocklistSource, DomainError> {
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        let row = sqlx::query_as::<_, IpBlocklistSourceRow>(
+            "INSERT INTO ip_blocklist_sources (name, url, comment, enabled, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?)
+             RETURNING id, name, url, comment, enabled, created_at, updated_at",
+        )
+        .bind(&name)
+        .bind(&url)
+        .bind(&comment)
+        .bind(if enabled { 1i64 } else { 0i64 })
+        .bind(&now)
+        .bind(&now)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| Self::map_write_error(e, &name, "Failed to create IP blocklist source"))?;
+
+        Ok(Self::row_to_source(row))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_by_id(&self, id: i64) -> Result<Option<IpBlocklistSource>, DomainError> {
+        let row = sqlx::query_as::<_, IpBlocklistSourceRow>(
+            "SELECT id, name, url, comment, enabled, created_at, updated_at
+             FROM ip_blocklist_sources WHERE id = ?",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to query IP blocklist source by id");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(row.map(Self::row_to_source))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_all(&self) -> Result<Vec<IpBlocklistSource>, DomainError> {
+        let rows = sqlx::query_as::<_, IpBlocklistSourceRow>(
+            "SELECT id, name, url, comment, enabled, created_at, updated_at
+             FROM ip_blocklist_sources ORDER BY name ASC",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to query all IP blocklist sources");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(rows.into_iter().map(Self::row_to_source).collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn update(
+        &self,
+        id: i64,
+        name: Option<String>,
+        url: Option<String>,
+        comment: Option<String>,
+        enabled: Option<bool>,
+    ) -> Result<IpBlocklistSource, DomainError> {
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        let current = self
+            .get_by_id(id)
+            .await?
+            .ok_or(DomainError::IpBlocklistSourceNotFound(id))?;
+
+        let final_name = name.unwrap_or_else(|| current.name.to_string());
+        let final_url = url.unwrap_or_else(|| current.url.to_string());
+        let final_comment: Option<String> =
+            comment.or_else(|| current.comment.as_ref().map(|s| s.to_string()));
+        let final_enabled = enabled.unwrap_or(current.enabled);
+
+        let row = sqlx::query_as::<_, IpBlocklistSourceRow>(
+            "UPDATE ip_blocklist_sources
+             SET name = ?, url = ?, comment = ?, enabled = ?, updated_at = ?
+             WHERE id = ?
+             RETURNING id, name, url, comment, enabled, created_at, updated_at",
+        )
+        .bind(&final_name)
+        .bind(&final_url)
+        .bind(&final_comment)
+        .bind(if final_enabled { 1i64 } else { 0i64 })
+        .bind(&now)
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| Self::map_write_error(e, &final_name, "Failed to update IP blocklist source"))?
+        .ok_or(DomainError::IpBlocklistSourceNotFound(id))?;
+
+        Ok(Self::row_to_source(row))
+    }
+
+    #[instrument(skip(self))]
+    async fn delete(&self, id: i64) -> Result<(), DomainError> {
+        let result = sqlx::query("DELETE FROM ip_blocklist_sources WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to delete IP blocklist source");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::IpBlocklistSourceNotFound(id));
+        }
+
+        Ok(())
+    }
+}
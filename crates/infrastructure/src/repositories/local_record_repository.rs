@@ -0,0 +1,223 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::LocalRecordRepository;
+use ferrous_dns_domain::{DomainError, LocalRecord, RecordType};
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use tracing::{error, instrument, warn};
+
+const LOCAL_RECORD_COLUMNS: &str =
+    "id, hostname, domain, record_type, value, ttl, view, tenant_id, created_at, updated_at";
+
+#[derive(sqlx::FromRow)]
+struct LocalRecordRow {
+    id: i64,
+    hostname: String,
+    domain: Option<String>,
+    record_type: String,
+    value: String,
+    ttl: i64,
+    view: Option<String>,
+    tenant_id: Option<i64>,
+    created_at: Option<String>,
+    updated_at: Option<String>,
+}
+
+pub struct SqliteLocalRecordRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteLocalRecordRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_record(row: LocalRecordRow) -> Option<LocalRecord> {
+        let record_type = match row.record_type.parse::<RecordType>() {
+            Ok(rt) => rt,
+            Err(e) => {
+                warn!(id = row.id, error = %e, "Skipping local record with unknown type");
+                return None;
+            }
+        };
+        Some(LocalRecord {
+            id: Some(row.id),
+            hostname: Arc::from(row.hostname.as_str()),
+            domain: row.domain.map(|s| Arc::from(s.as_str())),
+            record_type,
+            value: Arc::from(row.value.as_str()),
+            ttl: row.ttl.clamp(0, u32::MAX as i64) as u32,
+            view: row.view.map(|s| Arc::from(s.as_str())),
+            tenant_id: row.tenant_id,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+
+    fn map_write_error(e: sqlx::Error, record: &LocalRecord, context: &str) -> DomainError {
+        if e.to_string().contains("UNIQUE constraint failed") {
+            DomainError::LocalRecordConflict(format!(
+                "{} record '{}' -> '{}' already exists",
+                record.record_type, record.hostname, record.value
+            ))
+        } else {
+            error!(error = %e, "{}", context);
+            DomainError::DatabaseError(e.to_string())
+        }
+    }
+}
+
+#[async_trait]
+impl LocalRecordRepository for SqliteLocalRecordRepository {
+    #[instrument(skip(self))]
+    async fn create(&self, record: &LocalRecord) -> Result<LocalRecord, DomainError> {
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        let sql = format!(
+            "INSERT INTO local_records
+                 (hostname, domain, record_type, value, ttl, view, tenant_id, created_at,
+                  updated_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+             RETURNING {LOCAL_RECORD_COLUMNS}"
+        );
+        let row: LocalRecordRow = sqlx::query_as(&sql)
+            .bind(record.hostname.as_ref())
+            .bind(record.domain.as_deref())
+            .bind(record.record_type.as_str())
+            .bind(record.value.as_ref())
+            .bind(record.ttl as i64)
+            .bind(record.view.as_deref())
+            .bind(record.tenant_id)
+            .bind(&now)
+            .bind(&now)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| Self::map_write_error(e, record, "Failed to create local record"))?;
+
+        Self::row_to_record(row).ok_or_else(|| {
+            DomainError::DatabaseError("created local record could not be read back".to_string())
+        })
+    }
+
+    #[instrument(skip(self))]
+    async fn get_by_id(&self, id: i64) -> Result<Option<LocalRecord>, DomainError> {
+        let sql = format!("SELECT {LOCAL_RECORD_COLUMNS} FROM local_records WHERE id = ?");
+        let row: Option<LocalRecordRow> = sqlx::query_as(&sql)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to query local record by id");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        Ok(row.and_then(Self::row_to_record))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_by_id_in_tenant(
+        &self,
+        tenant_id: i64,
+        id: i64,
+    ) -> Result<Option<LocalRecord>, DomainError> {
+        let sql = format!(
+            "SELECT {LOCAL_RECORD_COLUMNS} FROM local_records WHERE id = ? AND tenant_id = ?"
+        );
+        let row: Option<LocalRecordRow> = sqlx::query_as(&sql)
+            .bind(id)
+            .bind(tenant_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to query tenant local record by id");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        Ok(row.and_then(Self::row_to_record))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_all(&self) -> Result<Vec<LocalRecord>, DomainError> {
+        let sql = format!(
+            "SELECT {LOCAL_RECORD_COLUMNS} FROM local_records
+             ORDER BY hostname ASC, domain ASC, id ASC"
+        );
+        let rows: Vec<LocalRecordRow> =
+            sqlx::query_as(&sql)
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| {
+                    error!(error = %e, "Failed to query all local records");
+                    DomainError::DatabaseError(e.to_string())
+                })?;
+
+        Ok(rows.into_iter().filter_map(Self::row_to_record).collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn get_all_in_tenant(&self, tenant_id: i64) -> Result<Vec<LocalRecord>, DomainError> {
+        let sql = format!(
+            "SELECT {LOCAL_RECORD_COLUMNS} FROM local_records
+             WHERE tenant_id = ?
+             ORDER BY hostname ASC, domain ASC, id ASC"
+        );
+        let rows: Vec<LocalRecordRow> = sqlx::query_as(&sql)
+            .bind(tenant_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to query tenant local records");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        Ok(rows.into_iter().filter_map(Self::row_to_record).collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn update(&self, record: &LocalRecord) -> Result<LocalRecord, DomainError> {
+        let id = record
+            .id
+            .ok_or_else(|| DomainError::InvalidLocalRecord("record id is required".to_string()))?;
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        let sql = format!(
+            "UPDATE local_records SET
+                 hostname = ?, domain = ?, record_type = ?, value = ?, ttl = ?, view = ?,
+                 updated_at = ?
+             WHERE id = ?
+             RETURNING {LOCAL_RECORD_COLUMNS}"
+        );
+        let row: Option<LocalRecordRow> = sqlx::query_as(&sql)
+            .bind(record.hostname.as_ref())
+            .bind(record.domain.as_deref())
+            .bind(record.record_type.as_str())
+            .bind(record.value.as_ref())
+            .bind(record.ttl as i64)
+            .bind(record.view.as_deref())
+            .bind(&now)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| Self::map_write_error(e, record, "Failed to update local record"))?;
+
+        row.and_then(Self::row_to_record)
+            .ok_or(DomainError::LocalRecordNotFound(id))
+    }
+
+    #[instrument(skip(self))]
+    async fn delete(&self, id: i64) -> Result<(), DomainError> {
+        let result = sqlx::query("DELETE FROM local_records WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to delete local record");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::LocalRecordNotFound(id));
+        }
+
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
+use super::paging::order_by;
 use async_trait::async_trait;
 use ferrous_dns_application::ports::ManagedDomainRepository;
-use ferrous_dns_domain::{DomainAction, DomainError, ManagedDomain};
+use ferrous_dns_domain::{DomainAction, DomainError, ManagedDomain, PageRequest};
 use sqlx::SqlitePool;
 use std::sync::Arc;
 use tracing::{error, instrument};
@@ -138,8 +139,7 @@ impl ManagedDomainRepository for SqliteManagedDomainRepository {
     #[instrument(skip(self))]
     async fn get_all_paged(
         &self,
-        limit: u32,
-        offset: u32,
+        page: &PageRequest,
     ) -> Result<(Vec<ManagedDomain>, u64), DomainError> {
         let count_row = sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM managed_domains")
             .fetch_one(&self.pool)
@@ -150,18 +150,20 @@ impl ManagedDomainRepository for SqliteManagedDomainRepository {
             })?;
         let total = count_row.0 as u64;
 
-        let rows = sqlx::query_as::<_, ManagedDomainRow>(
+        let sql = format!(
             "SELECT id, name, domain, action, group_id, comment, enabled, service_id, created_at, updated_at
-             FROM managed_domains ORDER BY name ASC LIMIT ? OFFSET ?",
-        )
-        .bind(limit as i64)
-        .bind(offset as i64)
-        .fetch_all(&self.pool)
-        .await
-        .map_err(|e| {
-            error!(error = %e, "Failed to query managed domains paged");
-            DomainError::DatabaseError(e.to_string())
-        })?;
+             FROM managed_domains {} LIMIT ? OFFSET ?",
+            order_by(page, "name ASC")
+        );
+        let rows = sqlx::query_as::<_, ManagedDomainRow>(&sql)
+            .bind(page.limit as i64)
+            .bind(page.offset as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to query managed domains paged");
+                DomainError::DatabaseError(e.to_string())
+            })?;
 
         Ok((rows.into_iter().map(Self::row_to_domain).collect(), total))
     }
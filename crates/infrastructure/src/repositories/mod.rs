@@ -1,18 +1,34 @@
+pub mod alert_repository;
+pub mod audit_log_repository;
 pub mod blocked_service_repository;
 pub mod blocklist_repository;
 pub mod blocklist_source_repository;
+pub mod cache_ttl_override_repository;
 pub mod client_repository;
 pub(crate) mod client_row_mapper;
 pub mod client_subnet_repository;
 pub mod config_persistence;
 pub mod config_repository;
 pub mod custom_service_repository;
+pub mod device_repository;
+pub mod dns_rewrite_repository;
+pub mod dnssec_history_repository;
 pub mod group_repository;
+pub mod ip_blocklist_source_repository;
+pub mod local_record_repository;
 pub mod managed_domain_repository;
+pub(crate) mod paging;
 pub mod query_log_repository;
+pub mod query_policy_repository;
+pub mod record_type_policy_repository;
 pub mod regex_filter_repository;
 pub mod schedule_profile_repository;
+pub mod secondary_zone_repository;
 pub mod sqlite_safe_search_config_repository;
+pub mod tenant_repository;
+pub mod tld_policy_repository;
+pub mod unblock_request_repository;
+pub mod upstream_address_repository;
 pub mod whitelist_repository;
 pub mod whitelist_source_repository;
 
@@ -20,20 +36,33 @@ pub mod api_token_repository;
 pub mod session_repository;
 pub mod user_repository;
 
+pub use alert_repository::SqliteAlertRepository;
 pub use api_token_repository::SqliteApiTokenRepository;
+pub use audit_log_repository::SqliteAuditLogRepository;
 pub use blocked_service_repository::SqliteBlockedServiceRepository;
 pub use blocklist_source_repository::SqliteBlocklistSourceRepository;
+pub use cache_ttl_override_repository::SqliteCacheTtlOverrideRepository;
 pub use client_repository::SqliteClientRepository;
 pub use client_subnet_repository::SqliteClientSubnetRepository;
 pub use config_persistence::TomlConfigFilePersistence;
 pub use config_repository::TomlConfigRepository;
 pub use custom_service_repository::SqliteCustomServiceRepository;
+pub use device_repository::SqliteDeviceRepository;
+pub use dns_rewrite_repository::SqliteDnsRewriteRepository;
+pub use dnssec_history_repository::SqliteDnssecHistoryRepository;
 pub use group_repository::SqliteGroupRepository;
+pub use ip_blocklist_source_repository::SqliteIpBlocklistSourceRepository;
+pub use local_record_repository::SqliteLocalRecordRepository;
 pub use managed_domain_repository::SqliteManagedDomainRepository;
+pub use query_policy_repository::SqliteQueryPolicyRepository;
 pub use regex_filter_repository::SqliteRegexFilterRepository;
 pub use schedule_profile_repository::SqliteScheduleProfileRepository;
+pub use secondary_zone_repository::SqliteSecondaryZoneRepository;
 pub use session_repository::SqliteSessionRepository;
 pub use sqlite_safe_search_config_repository::SqliteSafeSearchConfigRepository;
+pub use tenant_repository::SqliteTenantRepository;
+pub use unblock_request_repository::SqliteUnblockRequestRepository;
+pub use upstream_address_repository::SqliteUpstreamAddressRepository;
 pub use user_repository::SqliteUserRepository;
 pub use whitelist_repository::SqliteWhitelistRepository;
 pub use whitelist_source_repository::SqliteWhitelistSourceRepository;
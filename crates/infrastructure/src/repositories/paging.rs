@@ -0,0 +1,13 @@
+use ferrous_dns_domain::PageRequest;
+
+/// Builds the `ORDER BY` clause for a paged query.
+///
+/// The sort field comes from an entity's `SORT_FIELDS` allowlist, so it is
+/// safe to interpolate. `id` is appended as a tiebreaker so pages stay
+/// stable when several rows share the same sort value.
+pub(crate) fn order_by(page: &PageRequest, default: &str) -> String {
+    match page.sort {
+        Some(key) => format!("ORDER BY {} {}, id ASC", key.field, key.order.as_sql()),
+        None => format!("ORDER BY {default}, id ASC"),
+    }
+}
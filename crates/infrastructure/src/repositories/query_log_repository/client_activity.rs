@@ -0,0 +1,132 @@
+use super::helpers::{
+    granularity_to_sql, hours_ago_cutoff, row_to_timeline_bucket, TIMELINE_SERIES_SQL,
+};
+use super::partitions::query_log_source;
+use ferrous_dns_application::ports::{ClientActivity, TimeGranularity, TimelineBucket};
+use ferrous_dns_domain::DomainError;
+use sqlx::{Row, SqlitePool};
+use tracing::{debug, error, instrument};
+
+fn build_client_timeline_sql(logs: &str, bucket_expr: &'static str) -> String {
+    format!(
+        "SELECT {bucket_expr} as time_bucket, \
+         {TIMELINE_SERIES_SQL} \
+         FROM {logs} \
+         WHERE client_ip = ? \
+           AND created_at >= ? \
+           AND query_source = 'client' \
+         GROUP BY time_bucket \
+         ORDER BY time_bucket ASC"
+    )
+}
+
+async fn top_domains(
+    pool: &SqlitePool,
+    logs: &str,
+    client_ip: &str,
+    cutoff: &str,
+    blocked: bool,
+    limit: u32,
+) -> Result<Vec<(String, u64)>, DomainError> {
+    let rows = sqlx::query(&format!(
+        "SELECT domain, COUNT(*) as count
+         FROM {logs}
+         WHERE client_ip = ?
+           AND created_at >= ?
+           AND blocked = ?
+           AND query_source = 'client'
+         GROUP BY domain
+         ORDER BY count DESC
+         LIMIT ?"
+    ))
+    .bind(client_ip)
+    .bind(cutoff)
+    .bind(blocked as i64)
+    .bind(limit as i64)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        error!(error = %e, "Failed to fetch client top domains");
+        DomainError::DatabaseError(e.to_string())
+    })?;
+
+    Ok(rows
+        .into_iter()
+        .map(|r| {
+            let domain: String = r.get("domain");
+            let count = r.get::<i64, _>("count") as u64;
+            (domain, count)
+        })
+        .collect())
+}
+
+#[instrument(skip(pool))]
+pub(super) async fn get_client_activity(
+    pool: &SqlitePool,
+    client_ip: &str,
+    period_hours: u32,
+    granularity: TimeGranularity,
+    limit: u32,
+) -> Result<ClientActivity, DomainError> {
+    debug!("Fetching client activity");
+
+    let cutoff = hours_ago_cutoff(period_hours as f32);
+    let logs = query_log_source(pool, &cutoff, None).await?;
+    let sql = build_client_timeline_sql(&logs, granularity_to_sql(granularity));
+
+    let rows = sqlx::query(&sql)
+        .bind(client_ip)
+        .bind(&cutoff)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to fetch client timeline");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+    let timeline: Vec<TimelineBucket> = rows
+        .into_iter()
+        .map(|row| row_to_timeline_bucket(&row))
+        .collect();
+
+    let totals = sqlx::query(&format!(
+        "SELECT
+            COUNT(*) as total,
+            COALESCE(SUM(CASE WHEN blocked = 1 THEN 1 ELSE 0 END), 0) as blocked,
+            COALESCE(SUM(CASE WHEN cache_hit = 1 AND cache_refresh = 0 THEN 1 ELSE 0 END), 0) as hits
+         FROM {logs}
+         WHERE client_ip = ?
+           AND created_at >= ?
+           AND query_source = 'client'"
+    ))
+    .bind(client_ip)
+    .bind(&cutoff)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| {
+        error!(error = %e, "Failed to fetch client totals");
+        DomainError::DatabaseError(e.to_string())
+    })?;
+
+    let total_queries = totals.get::<i64, _>("total") as u64;
+    let blocked_queries = totals.get::<i64, _>("blocked") as u64;
+    let cache_hits = totals.get::<i64, _>("hits") as u64;
+    let cache_hit_rate = if total_queries > 0 {
+        (cache_hits as f64 / total_queries as f64) * 100.0
+    } else {
+        0.0
+    };
+
+    let top_domains_list = top_domains(pool, &logs, client_ip, &cutoff, false, limit).await?;
+    let top_blocked_domains = top_domains(pool, &logs, client_ip, &cutoff, true, limit).await?;
+
+    Ok(ClientActivity {
+        total_queries,
+        blocked_queries,
+        cache_hits,
+        cache_hit_rate,
+        timeline,
+        top_domains: top_domains_list,
+        top_blocked_domains,
+    })
+}
@@ -1,5 +1,5 @@
 use chrono::Utc;
-use ferrous_dns_application::ports::TimeGranularity;
+use ferrous_dns_application::ports::{TimeGranularity, TimelineBucket};
 use ferrous_dns_domain::{BlockSource, QueryLog, QuerySource, RecordType};
 use sqlx::sqlite::SqliteRow;
 use sqlx::Row;
@@ -7,6 +7,26 @@ use std::str::FromStr;
 use std::sync::Arc;
 use std::time::SystemTime;
 
+/// Per-bucket series selected by every timeline query.
+pub const TIMELINE_SERIES_SQL: &str = "COUNT(*) as total, \
+     COALESCE(SUM(CASE WHEN blocked = 1 THEN 1 ELSE 0 END), 0) as blocked, \
+     COALESCE(SUM(CASE WHEN blocked = 0 THEN 1 ELSE 0 END), 0) as unblocked, \
+     COALESCE(SUM(CASE WHEN response_status IN ('TUNNELING_BLOCKED', 'DGA_BLOCKED', 'NXDOMAIN_HIJACK', 'RESPONSE_IP_BLOCKED') THEN 1 ELSE 0 END), 0) as malware_detected, \
+     COALESCE(SUM(CASE WHEN cache_hit = 1 THEN 1 ELSE 0 END), 0) as cached, \
+     COALESCE(SUM(CASE WHEN cache_hit = 0 AND blocked = 0 AND (response_status IS NULL OR response_status NOT IN ('LOCAL_DNS', 'RATE_LIMITED', 'RATE_LIMITED_TC', 'ANY_MINIMIZED')) THEN 1 ELSE 0 END), 0) as forwarded";
+
+pub fn row_to_timeline_bucket(row: &SqliteRow) -> TimelineBucket {
+    TimelineBucket {
+        timestamp: row.get("time_bucket"),
+        total: row.get::<i64, _>("total") as u64,
+        blocked: row.get::<i64, _>("blocked") as u64,
+        unblocked: row.get::<i64, _>("unblocked") as u64,
+        malware_detected: row.get::<i64, _>("malware_detected") as u64,
+        cached: row.get::<i64, _>("cached") as u64,
+        forwarded: row.get::<i64, _>("forwarded") as u64,
+    }
+}
+
 pub fn granularity_to_sql(g: TimeGranularity) -> &'static str {
     match g {
         TimeGranularity::Minute => "strftime('%Y-%m-%d %H:%M:00', created_at)",
@@ -55,18 +75,44 @@ fn to_static_dnssec(s: &str) -> Option<&'static str> {
     }
 }
 
+fn to_static_upstream_strategy(s: &str) -> Option<&'static str> {
+    match s {
+        "parallel" => Some("parallel"),
+        "balanced" => Some("balanced"),
+        "failover" => Some("failover"),
+        _ => None,
+    }
+}
+
+fn to_static_upstream_protocol(s: &str) -> Option<&'static str> {
+    match s {
+        "UDP" => Some("UDP"),
+        "TCP" => Some("TCP"),
+        "TLS" => Some("TLS"),
+        "HTTPS" => Some("HTTPS"),
+        "QUIC" => Some("QUIC"),
+        "H3" => Some("H3"),
+        _ => None,
+    }
+}
+
 fn to_static_response_status(s: &str) -> Option<&'static str> {
     match s {
         "NOERROR" => Some("NOERROR"),
         "NXDOMAIN" => Some("NXDOMAIN"),
         "SERVFAIL" => Some("SERVFAIL"),
         "REFUSED" => Some("REFUSED"),
+        "FORMERR" => Some("FORMERR"),
+        "NOTIMP" => Some("NOTIMP"),
         "TIMEOUT" => Some("TIMEOUT"),
         "BLOCKED" => Some("BLOCKED"),
         "LOCAL_DNS" => Some("LOCAL_DNS"),
         "RATE_LIMITED" => Some("RATE_LIMITED"),
         "RATE_LIMITED_TC" => Some("RATE_LIMITED_TC"),
         "SAFE_SEARCH" => Some("SAFE_SEARCH"),
+        "REWRITTEN" => Some("REWRITTEN"),
+        "AAAA_FILTERED" => Some("AAAA_FILTERED"),
+        "ANY_MINIMIZED" => Some("ANY_MINIMIZED"),
         _ => None,
     }
 }
@@ -102,6 +148,10 @@ pub fn row_to_query_log(row: SqliteRow) -> Option<QueryLog> {
                 "nxdomain_hijack" => Some(BlockSource::NxdomainHijack),
                 "response_ip_filter" => Some(BlockSource::ResponseIpFilter),
                 "dga_detection" => Some(BlockSource::DgaDetection),
+                "query_policy" => Some(BlockSource::QueryPolicy),
+                "record_type_filter" => Some(BlockSource::RecordTypeFilter),
+                "plugin" => Some(BlockSource::Plugin),
+                "tld_policy" => Some(BlockSource::TldPolicy),
                 _ => None,
             });
 
@@ -113,6 +163,11 @@ pub fn row_to_query_log(row: SqliteRow) -> Option<QueryLog> {
         client_hostname: row
             .get::<Option<String>, _>("hostname")
             .map(|s| Arc::from(s.as_str())),
+        client_mac: row
+            .try_get::<Option<String>, _>("mac_address")
+            .ok()
+            .flatten()
+            .map(|s| Arc::from(s.as_str())),
         blocked: row.get::<i64, _>("blocked") != 0,
         response_time_us: row
             .get::<Option<i64>, _>("response_time_ms")
@@ -128,11 +183,36 @@ pub fn row_to_query_log(row: SqliteRow) -> Option<QueryLog> {
             .ok()
             .flatten()
             .map(|s| Arc::from(s.as_str())),
+        upstream_strategy: row
+            .try_get::<Option<String>, _>("upstream_strategy")
+            .ok()
+            .flatten()
+            .and_then(|s| to_static_upstream_strategy(&s)),
+        upstream_attempt: row
+            .try_get::<Option<i64>, _>("upstream_attempt")
+            .ok()
+            .flatten()
+            .map(|a| a as u32),
+        upstream_protocol: row
+            .try_get::<Option<String>, _>("upstream_protocol")
+            .ok()
+            .flatten()
+            .and_then(|s| to_static_upstream_protocol(&s)),
         response_status,
         timestamp: Some(row.get("created_at")),
         query_source,
         group_id: row.get("group_id"),
+        group_name: row
+            .try_get::<Option<String>, _>("group_name")
+            .ok()
+            .flatten()
+            .map(|s| Arc::from(s.as_str())),
         block_source,
+        plugin: row
+            .try_get::<Option<String>, _>("plugin")
+            .ok()
+            .flatten()
+            .map(|s| Arc::from(s.as_str())),
     })
 }
 
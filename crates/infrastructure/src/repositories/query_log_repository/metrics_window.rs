@@ -0,0 +1,115 @@
+use super::helpers::seconds_ago_cutoff;
+use super::partitions::query_log_source;
+use ferrous_dns_application::ports::{MetricsWindow, UpstreamWindowStats};
+use ferrous_dns_domain::DomainError;
+use sqlx::{Row, SqlitePool};
+use tracing::{error, instrument};
+
+const PERCENTILES: [f64; 3] = [0.50, 0.90, 0.99];
+
+fn db_error(context: &'static str) -> impl Fn(sqlx::Error) -> DomainError {
+    move |e| {
+        error!(error = %e, "{context}");
+        DomainError::DatabaseError(e.to_string())
+    }
+}
+
+/// Nearest-rank position of `percentile` among `samples` sorted values.
+fn rank(percentile: f64, samples: u64) -> i64 {
+    ((percentile * samples as f64).ceil() as i64 - 1).max(0)
+}
+
+async fn latency_at(
+    pool: &SqlitePool,
+    logs: &str,
+    cutoff: &str,
+    offset: i64,
+) -> Result<f64, DomainError> {
+    let row = sqlx::query(&format!(
+        "SELECT response_time_ms
+         FROM {logs}
+         WHERE query_source = 'client'
+           AND created_at >= ?
+           AND response_time_ms IS NOT NULL
+         ORDER BY response_time_ms
+         LIMIT 1 OFFSET ?"
+    ))
+    .bind(cutoff)
+    .bind(offset)
+    .fetch_one(pool)
+    .await
+    .map_err(db_error("Failed to fetch latency percentile"))?;
+    Ok(row.get::<i64, _>("response_time_ms") as f64 / 1000.0)
+}
+
+#[instrument(skip(pool))]
+pub(super) async fn get_metrics_window(
+    pool: &SqlitePool,
+    seconds_ago: i64,
+) -> Result<MetricsWindow, DomainError> {
+    let cutoff = seconds_ago_cutoff(seconds_ago);
+    let logs = query_log_source(pool, &cutoff, None).await?;
+
+    let totals_sql = format!(
+        "SELECT
+            COUNT(*) as total,
+            COUNT(response_time_ms) as timed,
+            SUM(CASE WHEN blocked = 1 THEN 1 ELSE 0 END) as blocked,
+            SUM(CASE WHEN cache_hit = 1 THEN 1 ELSE 0 END) as cache_hits,
+            SUM(CASE WHEN response_status IN ('SERVFAIL', 'TIMEOUT') THEN 1 ELSE 0 END) as errors
+         FROM {logs}
+         WHERE query_source = 'client'
+           AND created_at >= ?"
+    );
+    let upstreams_sql = format!(
+        "SELECT
+            COALESCE(upstream_pool, 'unknown') as pool,
+            upstream_server as server,
+            COUNT(*) as count,
+            SUM(CASE WHEN response_status IN ('SERVFAIL', 'TIMEOUT') THEN 1 ELSE 0 END) as errors,
+            AVG(response_time_ms) as avg_time
+         FROM {logs}
+         WHERE query_source = 'client'
+           AND created_at >= ?
+           AND cache_hit = 0
+           AND upstream_server IS NOT NULL
+         GROUP BY upstream_pool, upstream_server
+         ORDER BY count DESC"
+    );
+
+    let (totals, upstream_rows) = tokio::join!(
+        sqlx::query(&totals_sql).bind(&cutoff).fetch_one(pool),
+        sqlx::query(&upstreams_sql).bind(&cutoff).fetch_all(pool),
+    );
+    let totals = totals.map_err(db_error("Failed to fetch metrics window"))?;
+    let upstream_rows = upstream_rows.map_err(db_error("Failed to fetch upstream metrics"))?;
+
+    let timed = totals.get::<i64, _>("timed") as u64;
+    let mut percentiles = [None; 3];
+    if timed > 0 {
+        for (slot, percentile) in percentiles.iter_mut().zip(PERCENTILES) {
+            *slot = Some(latency_at(pool, &logs, &cutoff, rank(percentile, timed)).await?);
+        }
+    }
+    let [latency_p50_ms, latency_p90_ms, latency_p99_ms] = percentiles;
+
+    Ok(MetricsWindow {
+        queries: totals.get::<i64, _>("total") as u64,
+        blocked: totals.get::<Option<i64>, _>("blocked").unwrap_or(0) as u64,
+        cache_hits: totals.get::<Option<i64>, _>("cache_hits").unwrap_or(0) as u64,
+        errors: totals.get::<Option<i64>, _>("errors").unwrap_or(0) as u64,
+        latency_p50_ms,
+        latency_p90_ms,
+        latency_p99_ms,
+        upstreams: upstream_rows
+            .into_iter()
+            .map(|r| UpstreamWindowStats {
+                pool: r.get("pool"),
+                server: r.get("server"),
+                queries: r.get::<i64, _>("count") as u64,
+                errors: r.get::<i64, _>("errors") as u64,
+                avg_latency_ms: r.get::<Option<f64>, _>("avg_time").unwrap_or(0.0) / 1000.0,
+            })
+            .collect(),
+    })
+}
@@ -1,14 +1,26 @@
+mod client_activity;
 mod helpers;
+mod metrics_window;
+mod partitions;
 mod reader;
+mod rollup;
 mod timeline;
 mod writer;
 
+pub(crate) use helpers::hours_ago_cutoff;
+pub use partitions::query_log_tables;
+pub(crate) use partitions::{delete_oldest_query_logs, query_log_source};
+
 use async_trait::async_trait;
 use ferrous_dns_application::ports::{
-    PagedQueryResult, QueryLogRepository, TimeGranularity, TimelineBucket,
+    ChannelDepth, ChannelDepthSource, ClientActivity, ClientDomainCount, MetricsWindow,
+    PagedQueryResult, QueryLogRepository, TimeGranularity, TimelineBreakdown, TimelineBucket,
+    TimelineSeries,
 };
 use ferrous_dns_domain::query_log::QueryLogFilter;
-use ferrous_dns_domain::{config::DatabaseConfig, DomainError, QueryLog, QueryStats};
+use ferrous_dns_domain::{
+    config::DatabaseConfig, DomainError, QueryCountBreakdown, QueryLog, QueryStats,
+};
 use sqlx::SqlitePool;
 use std::sync::atomic::{AtomicU64, Ordering};
 use timeline::TimelineCache;
@@ -35,11 +47,19 @@ impl SqliteQueryLogRepository {
         let channel_capacity = cfg.query_log_channel_capacity;
         let max_batch_size = cfg.query_log_max_batch_size;
         let flush_interval_ms = cfg.query_log_flush_interval_ms;
+        let partitioning = cfg.query_log_partitioning;
 
         let (sender, receiver) = mpsc::channel(channel_capacity);
 
         tokio::spawn(async move {
-            writer::flush_loop(query_log_pool, receiver, max_batch_size, flush_interval_ms).await;
+            writer::flush_loop(
+                query_log_pool,
+                receiver,
+                max_batch_size,
+                flush_interval_ms,
+                partitioning,
+            )
+            .await;
         });
 
         info!(
@@ -47,6 +67,7 @@ impl SqliteQueryLogRepository {
             batch_size = max_batch_size,
             flush_interval_ms,
             sample_rate = cfg.query_log_sample_rate,
+            ?partitioning,
             "Query log batching enabled"
         );
 
@@ -61,6 +82,17 @@ impl SqliteQueryLogRepository {
     }
 }
 
+impl ChannelDepthSource for SqliteQueryLogRepository {
+    fn channel_depth(&self) -> Option<ChannelDepth> {
+        let capacity = self.sender.max_capacity();
+        Some(ChannelDepth {
+            name: "query_log",
+            queued: capacity - self.sender.capacity(),
+            capacity,
+        })
+    }
+}
+
 #[async_trait]
 impl QueryLogRepository for SqliteQueryLogRepository {
     async fn log_query(&self, query: &QueryLog) -> Result<(), DomainError> {
@@ -126,10 +158,40 @@ impl QueryLogRepository for SqliteQueryLogRepository {
         .await
     }
 
+    async fn get_timeline_breakdown(
+        &self,
+        period_hours: u32,
+        granularity: TimeGranularity,
+        breakdown: TimelineBreakdown,
+        limit: u32,
+    ) -> Result<Vec<TimelineSeries>, DomainError> {
+        timeline::get_timeline_breakdown(
+            &self.read_pool,
+            period_hours,
+            granularity,
+            breakdown,
+            limit,
+        )
+        .await
+    }
+
+    async fn get_query_counts_by(
+        &self,
+        period_hours: f32,
+        breakdown: TimelineBreakdown,
+        limit: Option<u32>,
+    ) -> Result<Vec<QueryCountBreakdown>, DomainError> {
+        reader::get_query_counts_by(&self.read_pool, period_hours, breakdown, limit).await
+    }
+
     async fn count_queries_since(&self, seconds_ago: i64) -> Result<u64, DomainError> {
         reader::count_queries_since(&self.read_pool, seconds_ago).await
     }
 
+    async fn get_metrics_window(&self, seconds_ago: i64) -> Result<MetricsWindow, DomainError> {
+        metrics_window::get_metrics_window(&self.read_pool, seconds_ago).await
+    }
+
     async fn get_cache_stats(
         &self,
         period_hours: f32,
@@ -161,6 +223,38 @@ impl QueryLogRepository for SqliteQueryLogRepository {
         reader::get_top_clients(&self.read_pool, limit, period_hours).await
     }
 
+    async fn get_top_blocked_clients(
+        &self,
+        limit: u32,
+        period_hours: f32,
+    ) -> Result<Vec<(String, u64)>, DomainError> {
+        reader::get_top_blocked_clients(&self.read_pool, limit, period_hours).await
+    }
+
+    async fn get_client_domain_counts(
+        &self,
+        seconds_ago: i64,
+    ) -> Result<Vec<ClientDomainCount>, DomainError> {
+        reader::get_client_domain_counts(&self.read_pool, seconds_ago).await
+    }
+
+    async fn get_client_activity(
+        &self,
+        client_ip: &str,
+        period_hours: u32,
+        granularity: TimeGranularity,
+        limit: u32,
+    ) -> Result<ClientActivity, DomainError> {
+        client_activity::get_client_activity(
+            &self.read_pool,
+            client_ip,
+            period_hours,
+            granularity,
+            limit,
+        )
+        .await
+    }
+
     async fn delete_older_than(&self, days: u32) -> Result<u64, DomainError> {
         reader::delete_older_than(&self.write_pool, days).await
     }
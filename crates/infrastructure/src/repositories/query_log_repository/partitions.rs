@@ -0,0 +1,378 @@
+//! Time-partitioned query log storage.
+//!
+//! Queries are written to one table per UTC day (`query_log_YYYYMMDD`) or ISO
+//! week (`query_log_YYYYWW`), created on demand from the schema of the
+//! `query_log` table. That table keeps the rows logged before partitioning was
+//! enabled and stays the template that migrations alter. Range reads union only
+//! the tables that can hold matching rows, and retention drops whole tables.
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Weekday};
+use ferrous_dns_domain::config::QueryLogPartitioning;
+use ferrous_dns_domain::DomainError;
+use sqlx::{Executor, Row, Sqlite, SqliteConnection, SqlitePool};
+use tracing::{debug, error, info};
+
+/// The unpartitioned table and schema template of every partition.
+pub(super) const TEMPLATE_TABLE: &str = "query_log";
+
+const TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+fn db_error(e: sqlx::Error) -> DomainError {
+    DomainError::DatabaseError(e.to_string())
+}
+
+/// Table that rows created at `at` are written to.
+pub(super) fn table_for(partitioning: QueryLogPartitioning, at: NaiveDateTime) -> String {
+    match partitioning {
+        QueryLogPartitioning::None => TEMPLATE_TABLE.to_string(),
+        QueryLogPartitioning::Day => format!("{TEMPLATE_TABLE}_{}", at.format("%Y%m%d")),
+        QueryLogPartitioning::Week => {
+            let week = at.iso_week();
+            format!("{TEMPLATE_TABLE}_{:04}{:02}", week.year(), week.week())
+        }
+    }
+}
+
+/// Half-open `[start, end)` span of a partition table, or `None` for the
+/// template table and names that are not partitions.
+fn span_of(table: &str) -> Option<(NaiveDateTime, NaiveDateTime)> {
+    let digits = table.strip_prefix(TEMPLATE_TABLE)?.strip_prefix('_')?;
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let (start, days) = match digits.len() {
+        6 => (
+            NaiveDate::from_isoywd_opt(
+                digits[..4].parse().ok()?,
+                digits[4..].parse().ok()?,
+                Weekday::Mon,
+            )?,
+            7,
+        ),
+        8 => (NaiveDate::parse_from_str(digits, "%Y%m%d").ok()?, 1),
+        _ => return None,
+    };
+    let start = start.and_hms_opt(0, 0, 0)?;
+    Some((start, start + Duration::days(days)))
+}
+
+struct QueryLogTable {
+    name: String,
+    span: Option<(NaiveDateTime, NaiveDateTime)>,
+}
+
+async fn list_tables<'e, E>(executor: E) -> Result<Vec<QueryLogTable>, sqlx::Error>
+where
+    E: Executor<'e, Database = Sqlite>,
+{
+    let names: Vec<String> = sqlx::query_scalar(
+        "SELECT name FROM sqlite_master
+         WHERE type = 'table' AND (name = ? OR name GLOB 'query_log_[0-9]*')",
+    )
+    .bind(TEMPLATE_TABLE)
+    .fetch_all(executor)
+    .await?;
+
+    let mut tables: Vec<QueryLogTable> = names
+        .into_iter()
+        .filter_map(|name| {
+            let span = span_of(&name);
+            (span.is_some() || name == TEMPLATE_TABLE).then_some(QueryLogTable { name, span })
+        })
+        .collect();
+    tables.sort_by_key(|t| t.span.map(|(start, _)| start));
+    Ok(tables)
+}
+
+/// Names of the query log tables: `query_log` first, then the partitions from
+/// oldest to newest.
+pub async fn query_log_tables<'e, E>(executor: E) -> Result<Vec<String>, DomainError>
+where
+    E: Executor<'e, Database = Sqlite>,
+{
+    Ok(list_tables(executor)
+        .await
+        .map_err(db_error)?
+        .into_iter()
+        .map(|t| t.name)
+        .collect())
+}
+
+/// `FROM` source covering every query log row created in `[from, until)`: a
+/// table name when a single table can hold them, otherwise a `UNION ALL`
+/// subquery over the tables whose span overlaps the range.
+pub(crate) async fn query_log_source(
+    pool: &SqlitePool,
+    from: &str,
+    until: Option<&str>,
+) -> Result<String, DomainError> {
+    let from = NaiveDateTime::parse_from_str(from, TIME_FORMAT).ok();
+    let until = until.and_then(|u| NaiveDateTime::parse_from_str(u, TIME_FORMAT).ok());
+    let tables: Vec<String> = list_tables(pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to list query log partitions");
+            db_error(e)
+        })?
+        .into_iter()
+        .filter(|t| match t.span {
+            Some((start, end)) => {
+                from.is_none_or(|from| end > from) && until.is_none_or(|until| start < until)
+            }
+            None => true,
+        })
+        .map(|t| t.name)
+        .collect();
+
+    if let [table] = tables.as_slice() {
+        return Ok(table.clone());
+    }
+
+    // Partitions may list their columns in another order than the template
+    // when a migration rebuilt it, so select them by name.
+    let columns: Vec<String> = sqlx::query_scalar("SELECT name FROM pragma_table_info(?)")
+        .bind(TEMPLATE_TABLE)
+        .fetch_all(pool)
+        .await
+        .map_err(db_error)?;
+    let columns = columns
+        .iter()
+        .map(|c| format!("\"{c}\""))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let selects = tables
+        .iter()
+        .map(|t| format!("SELECT {columns} FROM {t}"))
+        .collect::<Vec<_>>()
+        .join(" UNION ALL ");
+    Ok(format!("({selects})"))
+}
+
+/// Creates partition `table` from the template unless it exists, then adds the
+/// template columns and indexes it lacks, so partitions follow migrations.
+pub(super) async fn prepare_partition(pool: &SqlitePool, table: &str) -> Result<(), sqlx::Error> {
+    let Some(suffix) = table
+        .strip_prefix(TEMPLATE_TABLE)
+        .and_then(|s| s.strip_prefix('_'))
+    else {
+        return Ok(());
+    };
+    let mut tx = pool.begin().await?;
+
+    let template: Vec<(String, String, String)> = sqlx::query_as(
+        "SELECT type, name, sql FROM sqlite_master
+         WHERE tbl_name = ? AND type IN ('table', 'index') AND sql IS NOT NULL",
+    )
+    .bind(TEMPLATE_TABLE)
+    .fetch_all(&mut *tx)
+    .await?;
+
+    let exists: Option<i64> =
+        sqlx::query_scalar("SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?")
+            .bind(table)
+            .fetch_optional(&mut *tx)
+            .await?;
+
+    if exists.is_none() {
+        let Some((_, _, create)) = template.iter().find(|(kind, _, _)| kind == "table") else {
+            return Err(sqlx::Error::Protocol(format!(
+                "{TEMPLATE_TABLE} table not found"
+            )));
+        };
+        let columns = create.find('(').map_or("", |i| &create[i..]);
+        sqlx::query(&format!("CREATE TABLE {table} {columns}"))
+            .execute(&mut *tx)
+            .await?;
+        seed_sequence(&mut tx, table).await?;
+        info!(table, "Query log partition created");
+    } else {
+        add_missing_columns(&mut tx, table).await?;
+    }
+
+    for (_, index, sql) in template.iter().filter(|(kind, _, _)| kind == "index") {
+        let Some(open) = sql.find('(') else {
+            continue;
+        };
+        let unique = if sql.to_ascii_uppercase().starts_with("CREATE UNIQUE") {
+            "UNIQUE "
+        } else {
+            ""
+        };
+        sqlx::query(&format!(
+            "CREATE {unique}INDEX IF NOT EXISTS {index}_{suffix} ON {table} {}",
+            &sql[open..]
+        ))
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await
+}
+
+/// Starts the id sequence of a new partition after the highest id handed out
+/// by any query log table, so ids keep growing across partitions.
+async fn seed_sequence(conn: &mut SqliteConnection, table: &str) -> Result<(), sqlx::Error> {
+    if !has_sequence(conn).await? {
+        return Ok(());
+    }
+    sqlx::query(
+        "INSERT INTO sqlite_sequence (name, seq)
+         SELECT ?, COALESCE(MAX(seq), 0) FROM sqlite_sequence
+         WHERE name = ? OR name GLOB 'query_log_[0-9]*'",
+    )
+    .bind(table)
+    .bind(TEMPLATE_TABLE)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+/// Whether any table uses `AUTOINCREMENT`, which creates `sqlite_sequence`.
+async fn has_sequence(conn: &mut SqliteConnection) -> Result<bool, sqlx::Error> {
+    let found: Option<i64> =
+        sqlx::query_scalar("SELECT 1 FROM sqlite_master WHERE name = 'sqlite_sequence'")
+            .fetch_optional(&mut *conn)
+            .await?;
+    Ok(found.is_some())
+}
+
+async fn add_missing_columns(conn: &mut SqliteConnection, table: &str) -> Result<(), sqlx::Error> {
+    let missing = sqlx::query(
+        "SELECT t.name, t.type, t.\"notnull\", t.dflt_value FROM pragma_table_info(?) t
+         WHERE t.name NOT IN (SELECT name FROM pragma_table_info(?))
+         ORDER BY t.cid",
+    )
+    .bind(TEMPLATE_TABLE)
+    .bind(table)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    for column in missing {
+        let name: String = column.get("name");
+        let kind: String = column.get("type");
+        let default: Option<String> = column.get("dflt_value");
+        let mut sql = format!("ALTER TABLE {table} ADD COLUMN \"{name}\" {kind}");
+        if let Some(default) = default {
+            if column.get::<i64, _>("notnull") != 0 {
+                sql.push_str(" NOT NULL");
+            }
+            sql.push_str(&format!(" DEFAULT {default}"));
+        }
+        sqlx::query(&sql).execute(&mut *conn).await?;
+        debug!(table, column = name, "Query log partition column added");
+    }
+    Ok(())
+}
+
+/// Brings every existing partition up to date with the template schema.
+pub(super) async fn sync_partitions(pool: &SqlitePool) -> Result<(), sqlx::Error> {
+    for table in list_tables(pool).await? {
+        if table.span.is_some() {
+            prepare_partition(pool, &table.name).await?;
+        }
+    }
+    Ok(())
+}
+
+/// Drops the partitions that end at or before `cutoff`, or all of them when
+/// `cutoff` is `None`, and returns how many rows they held. The template table
+/// is left to row deletes.
+pub(super) async fn drop_partitions_before(
+    pool: &SqlitePool,
+    cutoff: Option<&str>,
+) -> Result<u64, DomainError> {
+    let cutoff = match cutoff {
+        Some(cutoff) => match NaiveDateTime::parse_from_str(cutoff, TIME_FORMAT) {
+            Ok(cutoff) => Some(cutoff),
+            Err(_) => return Ok(0),
+        },
+        None => None,
+    };
+    let mut dropped_rows = 0u64;
+    for table in list_tables(pool).await.map_err(db_error)? {
+        let Some((_, end)) = table.span else {
+            continue;
+        };
+        if cutoff.is_none_or(|cutoff| end <= cutoff) {
+            dropped_rows += drop_partition(pool, &table.name).await?;
+        }
+    }
+    Ok(dropped_rows)
+}
+
+/// Drops partition `table`, keeping its id sequence on the template so ids
+/// handed out later never go back. Returns the rows it held.
+async fn drop_partition(pool: &SqlitePool, table: &str) -> Result<u64, DomainError> {
+    let mut tx = pool.begin().await.map_err(db_error)?;
+    let rows: i64 = sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {table}"))
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(db_error)?;
+    if has_sequence(&mut tx).await.map_err(db_error)? {
+        sqlx::query(
+            "UPDATE sqlite_sequence
+             SET seq = MAX(seq, COALESCE((SELECT seq FROM sqlite_sequence WHERE name = ?), 0))
+             WHERE name = ?",
+        )
+        .bind(table)
+        .bind(TEMPLATE_TABLE)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+    }
+    sqlx::query(&format!("DROP TABLE {table}"))
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+    tx.commit().await.map_err(db_error)?;
+    info!(table, rows, "Query log partition dropped");
+    Ok(rows as u64)
+}
+
+/// Deletes up to `limit` of the oldest query log rows. A partition that fits
+/// in `limit` is dropped whole unless it is the newest one, which the writer
+/// is still filling.
+pub(crate) async fn delete_oldest_query_logs(
+    pool: &SqlitePool,
+    limit: u32,
+) -> Result<u64, DomainError> {
+    let tables = list_tables(pool).await.map_err(db_error)?;
+    let newest = tables.last().map(|t| t.name.clone());
+
+    let mut oldest: Option<(i64, &QueryLogTable)> = None;
+    for table in &tables {
+        let min_id: Option<i64> =
+            sqlx::query_scalar(&format!("SELECT MIN(id) FROM {}", table.name))
+                .fetch_one(pool)
+                .await
+                .map_err(db_error)?;
+        if let Some(min_id) = min_id {
+            if oldest.is_none_or(|(id, _)| min_id < id) {
+                oldest = Some((min_id, table));
+            }
+        }
+    }
+    let Some((_, table)) = oldest else {
+        return Ok(0);
+    };
+
+    if table.span.is_some() && newest.as_deref() != Some(table.name.as_str()) {
+        let rows: i64 = sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {}", table.name))
+            .fetch_one(pool)
+            .await
+            .map_err(db_error)?;
+        if rows <= i64::from(limit) {
+            return drop_partition(pool, &table.name).await;
+        }
+    }
+
+    let result = sqlx::query(&format!(
+        "DELETE FROM {0} WHERE id IN (SELECT id FROM {0} ORDER BY id ASC LIMIT ?)",
+        table.name
+    ))
+    .bind(i64::from(limit))
+    .execute(pool)
+    .await
+    .map_err(db_error)?;
+    Ok(result.rows_affected())
+}
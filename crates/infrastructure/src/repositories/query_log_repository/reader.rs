@@ -1,9 +1,11 @@
 use super::helpers::{
     days_ago_cutoff, get_uptime, hours_ago_cutoff, row_to_query_log, seconds_ago_cutoff,
 };
-use ferrous_dns_application::ports::PagedQueryResult;
+use super::partitions::{self, query_log_source, TEMPLATE_TABLE};
+use super::rollup::{self, StatsRollup};
+use ferrous_dns_application::ports::{ClientDomainCount, PagedQueryResult, TimelineBreakdown};
 use ferrous_dns_domain::query_log::{QueryCategory, QueryLogFilter};
-use ferrous_dns_domain::{DomainError, QueryLog, QueryStats};
+use ferrous_dns_domain::{DomainError, QueryCountBreakdown, QueryLog, QueryStats};
 use sqlx::{Row, SqlitePool};
 use std::time::Duration;
 use tracing::{debug, error, info, instrument};
@@ -20,18 +22,22 @@ pub(super) async fn get_recent(
     );
 
     let cutoff = hours_ago_cutoff(period_hours);
-    let rows = sqlx::query(
+    let logs = query_log_source(pool, &cutoff, None).await?;
+    let rows = sqlx::query(&format!(
         "SELECT q.id, q.domain, q.record_type, q.client_ip, q.blocked, q.response_time_ms,
                 q.cache_hit, q.cache_refresh, q.dnssec_status, q.upstream_server,
-                q.upstream_pool, q.response_status, q.query_source, q.group_id, q.block_source,
-                datetime(q.created_at) as created_at, c.hostname
-         FROM query_log q
+                q.upstream_pool, q.upstream_strategy, q.upstream_attempt, q.upstream_protocol,
+                q.response_status, q.query_source, q.group_id, q.block_source, q.plugin,
+                datetime(q.created_at) as created_at, c.hostname, c.mac_address,
+                g.name AS group_name
+         FROM {logs} q
          LEFT JOIN clients c ON q.client_ip = c.ip_address
+         LEFT JOIN groups g ON g.id = q.group_id
          WHERE q.created_at >= ?
            AND q.query_source = 'client'
          ORDER BY q.created_at DESC
-         LIMIT ?",
-    )
+         LIMIT ?"
+    ))
     .bind(cutoff)
     .bind(limit as i64)
     .fetch_all(pool)
@@ -65,7 +71,14 @@ pub(super) async fn get_recent_paged(
     );
 
     let fetch_limit = limit as i64 + 1;
-    let cutoff = hours_ago_cutoff(period_hours);
+    let cutoff = filter
+        .from
+        .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| hours_ago_cutoff(period_hours));
+    let upper_bound = filter.to.map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string());
+    let logs = query_log_source(pool, &cutoff, upper_bound.as_deref()).await?;
+    // Interpolated below; `as_str` only yields fixed source names.
+    let source = filter.query_source.unwrap_or_default().as_str();
     let domain_pattern = filter
         .domain
         .as_deref()
@@ -77,9 +90,10 @@ pub(super) async fn get_recent_paged(
         Some(QueryCategory::Allowed) => " AND q.blocked = 0",
         Some(QueryCategory::Blocked) => " AND q.blocked = 1",
         Some(QueryCategory::Cache) => " AND q.cache_hit = 1",
-        Some(QueryCategory::Upstream) => " AND q.cache_hit = 0 AND q.blocked = 0 AND (q.response_status IS NULL OR q.response_status NOT IN ('LOCAL_DNS', 'RATE_LIMITED', 'RATE_LIMITED_TC'))",
+        Some(QueryCategory::Upstream) => " AND q.cache_hit = 0 AND q.blocked = 0 AND (q.response_status IS NULL OR q.response_status NOT IN ('LOCAL_DNS', 'RATE_LIMITED', 'RATE_LIMITED_TC', 'ANY_MINIMIZED'))",
         Some(QueryCategory::RateLimited) => " AND q.response_status IN ('RATE_LIMITED', 'RATE_LIMITED_TC')",
         Some(QueryCategory::Malware) => " AND q.block_source IN ('dns_tunneling', 'dns_rebinding', 'nxdomain_hijack', 'response_ip_filter', 'dga_detection')",
+        Some(QueryCategory::Audited) => " AND q.blocked = 0 AND q.block_source IS NOT NULL",
         None => "",
     };
 
@@ -103,6 +117,24 @@ pub(super) async fn get_recent_paged(
     } else {
         ""
     };
+    let to_clause = if upper_bound.is_some() {
+        " AND q.created_at < ?"
+    } else {
+        ""
+    };
+    let blocked_clause = match filter.blocked {
+        Some(true) => " AND q.blocked = 1",
+        Some(false) => " AND q.blocked = 0",
+        None => "",
+    };
+    let dnssec_clause = if filter.dnssec_status.is_some() {
+        " AND q.dnssec_status = ?"
+    } else {
+        ""
+    };
+    let filter_clauses = format!(
+        "{domain_clause}{category_clause}{client_clause}{type_clause}{upstream_clause}{to_clause}{blocked_clause}{dnssec_clause}"
+    );
 
     // Binds the conditional filter parameters in a fixed order.
     macro_rules! bind_filters {
@@ -120,6 +152,12 @@ pub(super) async fn get_recent_paged(
             if let Some(ref up) = $filter.upstream {
                 q = q.bind(up);
             }
+            if let Some(ref to) = upper_bound {
+                q = q.bind(to);
+            }
+            if let Some(status) = $filter.dnssec_status {
+                q = q.bind(status);
+            }
             q
         }};
     }
@@ -130,14 +168,17 @@ pub(super) async fn get_recent_paged(
                 let sql = format!(
                     "SELECT q.id, q.domain, q.record_type, q.client_ip, q.blocked, q.response_time_ms,
                             q.cache_hit, q.cache_refresh, q.dnssec_status, q.upstream_server,
-                            q.upstream_pool, q.response_status, q.query_source, q.group_id, q.block_source,
-                            datetime(q.created_at) as created_at, c.hostname
-                     FROM query_log q
+                            q.upstream_pool, q.upstream_strategy, q.upstream_attempt, q.upstream_protocol,
+                            q.response_status, q.query_source, q.group_id, q.block_source, q.plugin,
+                            datetime(q.created_at) as created_at, c.hostname, c.mac_address,
+                            g.name AS group_name
+                     FROM {logs} q
                      LEFT JOIN clients c ON q.client_ip = c.ip_address
+                     LEFT JOIN groups g ON g.id = q.group_id
                      WHERE q.id < ?
-                       AND q.query_source = 'client'
+                       AND q.query_source = '{source}'
                        AND q.created_at >= ?
-                       {domain_clause}{category_clause}{client_clause}{type_clause}{upstream_clause}
+                       {filter_clauses}
                      ORDER BY q.id DESC
                      LIMIT ?"
                 );
@@ -148,13 +189,16 @@ pub(super) async fn get_recent_paged(
                 let sql = format!(
                     "SELECT q.id, q.domain, q.record_type, q.client_ip, q.blocked, q.response_time_ms,
                             q.cache_hit, q.cache_refresh, q.dnssec_status, q.upstream_server,
-                            q.upstream_pool, q.response_status, q.query_source, q.group_id, q.block_source,
-                            datetime(q.created_at) as created_at, c.hostname
-                     FROM query_log q
+                            q.upstream_pool, q.upstream_strategy, q.upstream_attempt, q.upstream_protocol,
+                            q.response_status, q.query_source, q.group_id, q.block_source, q.plugin,
+                            datetime(q.created_at) as created_at, c.hostname, c.mac_address,
+                            g.name AS group_name
+                     FROM {logs} q
                      LEFT JOIN clients c ON q.client_ip = c.ip_address
+                     LEFT JOIN groups g ON g.id = q.group_id
                      WHERE q.created_at >= ?
-                       AND q.query_source = 'client'
-                       {domain_clause}{category_clause}{client_clause}{type_clause}{upstream_clause}
+                       AND q.query_source = '{source}'
+                       {filter_clauses}
                      ORDER BY q.created_at DESC
                      LIMIT ? OFFSET ?"
                 );
@@ -168,21 +212,24 @@ pub(super) async fn get_recent_paged(
         },
         async {
             let count_sql = format!(
-                "SELECT COUNT(*) as cnt FROM query_log q
-                 WHERE q.query_source = 'client' AND q.created_at >= ?{domain_clause}{category_clause}{client_clause}{type_clause}{upstream_clause}"
+                "SELECT COUNT(*) as cnt FROM {logs} q
+                 WHERE q.query_source = '{source}' AND q.created_at >= ?{filter_clauses}"
             );
             let q = sqlx::query(&count_sql).bind(&cutoff);
             let q = bind_filters!(q, filter);
             q.fetch_one(pool).await
         },
         async {
-            sqlx::query(
-                "SELECT COUNT(*) as cnt FROM query_log q
-                 WHERE q.query_source = 'client' AND q.created_at >= ?",
-            )
-            .bind(&cutoff)
-            .fetch_one(pool)
-            .await
+            let total_sql = format!(
+                "SELECT COUNT(*) as cnt FROM {logs} q
+                 WHERE q.query_source = '{source}' AND q.created_at >= ?{to_clause}"
+            );
+            let q = sqlx::query(&total_sql).bind(&cutoff);
+            let q = match upper_bound {
+                Some(ref to) => q.bind(to),
+                None => q,
+            };
+            q.fetch_one(pool).await
         }
     );
 
@@ -239,68 +286,49 @@ pub(super) async fn get_stats(
     debug!(period_hours, "Fetching query statistics");
 
     let cutoff = hours_ago_cutoff(period_hours);
+    let logs = query_log_source(pool, &cutoff, None).await?;
 
-    let (row_result, type_rows_result, block_source_rows_result, upstream_rows_result) =
-        tokio::join!(
-            sqlx::query(
-                "SELECT
-                    COUNT(*) as total,
-                    SUM(CASE WHEN blocked = 1 THEN 1 ELSE 0 END) as blocked,
-                    SUM(CASE WHEN response_status IN ('RATE_LIMITED', 'RATE_LIMITED_TC') THEN 1 ELSE 0 END) as rate_limited,
-                    SUM(CASE WHEN cache_hit = 1 THEN 1 ELSE 0 END) as cache_hits,
-                    AVG(response_time_ms) as avg_time,
-                    AVG(CASE WHEN cache_hit = 1 THEN response_time_ms END) as avg_cache_time,
-                    AVG(CASE WHEN cache_hit = 0 AND blocked = 0 AND response_status != 'LOCAL_DNS' THEN response_time_ms END) as avg_upstream_time,
-                    SUM(CASE WHEN response_status = 'LOCAL_DNS' THEN 1 ELSE 0 END) as local_dns_count
-                 FROM query_log
-                 WHERE response_time_ms IS NOT NULL
-                   AND created_at >= ?
-                   AND query_source = 'client'",
-            )
-            .bind(&cutoff)
-            .fetch_one(pool),
-            sqlx::query(
-                "SELECT record_type, COUNT(*) as count
-                 FROM query_log
-                 WHERE created_at >= ?
-                   AND query_source = 'client'
-                 GROUP BY record_type",
-            )
-            .bind(&cutoff)
-            .fetch_all(pool),
-            sqlx::query(
-                "SELECT block_source, COUNT(*) as count
-                 FROM query_log
-                 WHERE blocked = 1
-                   AND block_source IS NOT NULL
-                   AND response_time_ms IS NOT NULL
-                   AND created_at >= ?
-                   AND query_source = 'client'
-                 GROUP BY block_source",
-            )
-            .bind(&cutoff)
-            .fetch_all(pool),
-            sqlx::query(
-                "SELECT
-                    COALESCE(upstream_pool, 'unknown') as pool,
-                    COALESCE(upstream_server, 'unknown') as server,
-                    COUNT(*) as count
-                 FROM query_log
-                 WHERE cache_hit = 0 AND blocked = 0
-                   AND (response_status IS NULL OR response_status != 'LOCAL_DNS')
-                   AND response_time_ms IS NOT NULL
-                   AND created_at >= ?
-                   AND query_source = 'client'
-                 GROUP BY upstream_pool, upstream_server",
-            )
+    let types_sql = format!(
+        "SELECT record_type, COUNT(*) as count
+         FROM {logs}
+         WHERE created_at >= ?
+           AND query_source = 'client'
+         GROUP BY record_type"
+    );
+    let block_sources_sql = format!(
+        "SELECT block_source, COUNT(*) as count
+         FROM {logs}
+         WHERE blocked = 1
+           AND block_source IS NOT NULL
+           AND response_time_ms IS NOT NULL
+           AND created_at >= ?
+           AND query_source = 'client'
+         GROUP BY block_source"
+    );
+    let upstreams_sql = format!(
+        "SELECT
+            COALESCE(upstream_pool, 'unknown') as pool,
+            COALESCE(upstream_server, 'unknown') as server,
+            COUNT(*) as count
+         FROM {logs}
+         WHERE cache_hit = 0 AND blocked = 0
+           AND (response_status IS NULL OR response_status NOT IN ('LOCAL_DNS', 'ANY_MINIMIZED'))
+           AND response_time_ms IS NOT NULL
+           AND created_at >= ?
+           AND query_source = 'client'
+         GROUP BY upstream_pool, upstream_server"
+    );
+
+    let (totals_result, type_rows_result, block_source_rows_result, upstream_rows_result) = tokio::join!(
+        rollup::window(pool, &logs, &cutoff),
+        sqlx::query(&types_sql).bind(&cutoff).fetch_all(pool),
+        sqlx::query(&block_sources_sql)
             .bind(&cutoff)
             .fetch_all(pool),
-        );
+        sqlx::query(&upstreams_sql).bind(&cutoff).fetch_all(pool),
+    );
 
-    let row = row_result.map_err(|e| {
-        error!(error = %e, "Failed to fetch statistics");
-        DomainError::DatabaseError(e.to_string())
-    })?;
+    let totals = totals_result?;
     let type_rows = type_rows_result.map_err(|e| {
         error!(error = %e, "Failed to fetch type distribution");
         DomainError::DatabaseError(e.to_string())
@@ -314,8 +342,8 @@ pub(super) async fn get_stats(
         DomainError::DatabaseError(e.to_string())
     })?;
 
-    let total = row.get::<i64, _>("total") as u64;
-    let cache_hits = row.get::<i64, _>("cache_hits") as u64;
+    let total = totals.queries;
+    let cache_hits = totals.cache_hits;
     let cache_hit_rate = if total > 0 {
         (cache_hits as f64 / total as f64) * 100.0
     } else {
@@ -333,10 +361,8 @@ pub(super) async fn get_stats(
 
     let mut source_stats = std::collections::HashMap::new();
     source_stats.insert("cache".to_string(), cache_hits);
-    source_stats.insert(
-        "local_dns".to_string(),
-        row.get::<i64, _>("local_dns_count") as u64,
-    );
+    source_stats.insert("local_dns".to_string(), totals.local_dns);
+    source_stats.insert("any_minimized".to_string(), totals.any_minimized);
     for upstream_row in upstream_rows {
         let pool: String = upstream_row.get("pool");
         let server: String = upstream_row.get("server");
@@ -361,22 +387,28 @@ pub(super) async fn get_stats(
 
     let stats = QueryStats {
         queries_total: total,
-        queries_blocked: row.get::<i64, _>("blocked") as u64,
-        queries_rate_limited: row.get::<i64, _>("rate_limited") as u64,
+        queries_blocked: totals.blocked,
+        queries_rate_limited: totals.rate_limited,
+        queries_any_minimized: totals.any_minimized,
         queries_malware_detected: malware_detected,
         unique_clients: 0,
         uptime_seconds: get_uptime(),
         cache_hit_rate,
-        avg_query_time_ms: row.get::<Option<f64>, _>("avg_time").unwrap_or(0.0) / 1000.0,
-        avg_cache_time_ms: row.get::<Option<f64>, _>("avg_cache_time").unwrap_or(0.0) / 1000.0,
-        avg_upstream_time_ms: row
-            .get::<Option<f64>, _>("avg_upstream_time")
-            .unwrap_or(0.0)
-            / 1000.0,
+        avg_query_time_ms: StatsRollup::mean_ms(totals.time_sum, totals.queries),
+        avg_cache_time_ms: StatsRollup::mean_ms(totals.cache_time_sum, totals.cache_hits),
+        avg_upstream_time_ms: StatsRollup::mean_ms(
+            totals.upstream_time_sum,
+            totals.upstream_queries,
+        ),
+        latency_p50_ms: totals.percentile_ms(0.50),
+        latency_p95_ms: totals.percentile_ms(0.95),
+        latency_p99_ms: totals.percentile_ms(0.99),
         source_stats,
         queries_by_type: std::collections::HashMap::new(),
         most_queried_type: None,
         record_type_distribution: Vec::new(),
+        queries_by_group: Vec::new(),
+        top_clients: Vec::new(),
     }
     .with_analytics(queries_by_type);
 
@@ -395,9 +427,10 @@ pub(super) async fn count_queries_since(
     seconds_ago: i64,
 ) -> Result<u64, DomainError> {
     let cutoff = seconds_ago_cutoff(seconds_ago);
-    let row = sqlx::query(
-        "SELECT COUNT(*) as count FROM query_log WHERE query_source = 'client' AND created_at >= ?",
-    )
+    let logs = query_log_source(pool, &cutoff, None).await?;
+    let row = sqlx::query(&format!(
+        "SELECT COUNT(*) as count FROM {logs} WHERE query_source = 'client' AND created_at >= ?"
+    ))
     .bind(cutoff)
     .fetch_one(pool)
     .await
@@ -417,15 +450,16 @@ pub(super) async fn get_cache_stats(
     debug!(period_hours, "Fetching cache statistics");
 
     let cutoff = hours_ago_cutoff(period_hours);
-    let row = sqlx::query(
+    let logs = query_log_source(pool, &cutoff, None).await?;
+    let row = sqlx::query(&format!(
         "SELECT
             SUM(CASE WHEN query_source = 'client' THEN 1 ELSE 0 END) as total_queries,
             SUM(CASE WHEN cache_hit = 1 AND cache_refresh = 0 AND query_source = 'client' THEN 1 ELSE 0 END) as hits,
             SUM(CASE WHEN cache_refresh = 1 THEN 1 ELSE 0 END) as refreshes,
             SUM(CASE WHEN cache_hit = 0 AND cache_refresh = 0 AND blocked = 0 AND query_source = 'client' THEN 1 ELSE 0 END) as misses
-         FROM query_log
-         WHERE created_at >= ?",
-    )
+         FROM {logs}
+         WHERE created_at >= ?"
+    ))
     .bind(cutoff)
     .fetch_one(pool)
     .await
@@ -467,16 +501,17 @@ pub(super) async fn get_top_blocked_domains(
     period_hours: f32,
 ) -> Result<Vec<(String, u64)>, DomainError> {
     let cutoff = hours_ago_cutoff(period_hours);
-    let rows = sqlx::query(
+    let logs = query_log_source(pool, &cutoff, None).await?;
+    let rows = sqlx::query(&format!(
         "SELECT domain, COUNT(*) as count
-         FROM query_log
+         FROM {logs}
          WHERE blocked = 1
            AND created_at >= ?
            AND query_source = 'client'
          GROUP BY domain
          ORDER BY count DESC
-         LIMIT ?",
-    )
+         LIMIT ?"
+    ))
     .bind(cutoff)
     .bind(limit as i64)
     .fetch_all(pool)
@@ -503,16 +538,17 @@ pub(super) async fn get_top_allowed_domains(
     period_hours: f32,
 ) -> Result<Vec<(String, u64)>, DomainError> {
     let cutoff = hours_ago_cutoff(period_hours);
-    let rows = sqlx::query(
+    let logs = query_log_source(pool, &cutoff, None).await?;
+    let rows = sqlx::query(&format!(
         "SELECT domain, COUNT(*) as count
-         FROM query_log
+         FROM {logs}
          WHERE blocked = 0
            AND created_at >= ?
            AND query_source = 'client'
          GROUP BY domain
          ORDER BY count DESC
-         LIMIT ?",
-    )
+         LIMIT ?"
+    ))
     .bind(cutoff)
     .bind(limit as i64)
     .fetch_all(pool)
@@ -539,16 +575,17 @@ pub(super) async fn get_top_clients(
     period_hours: f32,
 ) -> Result<Vec<(String, Option<String>, u64)>, DomainError> {
     let cutoff = hours_ago_cutoff(period_hours);
-    let rows = sqlx::query(
+    let logs = query_log_source(pool, &cutoff, None).await?;
+    let rows = sqlx::query(&format!(
         "SELECT q.client_ip, c.hostname, COUNT(*) as count
-         FROM query_log q
+         FROM {logs} q
          LEFT JOIN clients c ON q.client_ip = c.ip_address
          WHERE q.created_at >= ?
            AND q.query_source = 'client'
          GROUP BY q.client_ip
          ORDER BY count DESC
-         LIMIT ?",
-    )
+         LIMIT ?"
+    ))
     .bind(cutoff)
     .bind(limit as i64)
     .fetch_all(pool)
@@ -569,14 +606,151 @@ pub(super) async fn get_top_clients(
         .collect())
 }
 
+fn build_query_counts_sql(logs: &str, breakdown: TimelineBreakdown) -> String {
+    let (key_expr, label_join, label_expr) = match breakdown {
+        TimelineBreakdown::Client => (
+            "client_ip",
+            "LEFT JOIN clients c ON c.ip_address = s.breakdown_key",
+            "COALESCE(c.display_name, c.hostname)",
+        ),
+        TimelineBreakdown::Group => (
+            "group_id",
+            "LEFT JOIN groups g ON g.id = s.breakdown_key",
+            "g.name",
+        ),
+    };
+    // The counts only read columns of idx_query_log_source_created, so the
+    // aggregate never touches the table itself.
+    format!(
+        "SELECT CAST(s.breakdown_key AS TEXT) as breakdown_key, {label_expr} as label, s.total, s.blocked, s.cache_hits \
+         FROM ( \
+             SELECT {key_expr} as breakdown_key, COUNT(*) as total, \
+                    SUM(CASE WHEN blocked = 1 THEN 1 ELSE 0 END) as blocked, \
+                    SUM(CASE WHEN cache_hit = 1 THEN 1 ELSE 0 END) as cache_hits \
+             FROM {logs} \
+             WHERE query_source = 'client' \
+               AND created_at >= ? \
+               AND {key_expr} IS NOT NULL \
+             GROUP BY {key_expr} \
+             ORDER BY total DESC \
+             LIMIT ? \
+         ) s \
+         {label_join} \
+         ORDER BY s.total DESC"
+    )
+}
+
+#[instrument(skip(pool))]
+pub(super) async fn get_query_counts_by(
+    pool: &SqlitePool,
+    period_hours: f32,
+    breakdown: TimelineBreakdown,
+    limit: Option<u32>,
+) -> Result<Vec<QueryCountBreakdown>, DomainError> {
+    let cutoff = hours_ago_cutoff(period_hours);
+    let logs = query_log_source(pool, &cutoff, None).await?;
+    let rows = sqlx::query(&build_query_counts_sql(&logs, breakdown))
+        .bind(cutoff)
+        // A negative LIMIT means no limit in SQLite.
+        .bind(limit.map_or(-1, i64::from))
+        .fetch_all(pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to fetch query counts breakdown");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+    Ok(rows
+        .into_iter()
+        .map(|r| QueryCountBreakdown {
+            key: r.get("breakdown_key"),
+            label: r.get("label"),
+            queries_total: r.get::<i64, _>("total") as u64,
+            queries_blocked: r.get::<i64, _>("blocked") as u64,
+            cache_hits: r.get::<i64, _>("cache_hits") as u64,
+        })
+        .collect())
+}
+
+pub(super) async fn get_top_blocked_clients(
+    pool: &SqlitePool,
+    limit: u32,
+    period_hours: f32,
+) -> Result<Vec<(String, u64)>, DomainError> {
+    let cutoff = hours_ago_cutoff(period_hours);
+    let logs = query_log_source(pool, &cutoff, None).await?;
+    let rows = sqlx::query(&format!(
+        "SELECT client_ip, COUNT(*) as count
+         FROM {logs}
+         WHERE created_at >= ?
+           AND blocked = 1
+           AND query_source = 'client'
+         GROUP BY client_ip
+         ORDER BY count DESC
+         LIMIT ?"
+    ))
+    .bind(cutoff)
+    .bind(limit as i64)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        error!(error = %e, "Failed to fetch top blocked clients");
+        DomainError::DatabaseError(e.to_string())
+    })?;
+
+    Ok(rows
+        .into_iter()
+        .map(|r| (r.get("client_ip"), r.get::<i64, _>("count") as u64))
+        .collect())
+}
+
+pub(super) async fn get_client_domain_counts(
+    pool: &SqlitePool,
+    seconds_ago: i64,
+) -> Result<Vec<ClientDomainCount>, DomainError> {
+    let cutoff = seconds_ago_cutoff(seconds_ago);
+    let logs = query_log_source(pool, &cutoff, None).await?;
+    let rows = sqlx::query(&format!(
+        "SELECT client_ip, domain, COUNT(*) as count,
+                SUM(CASE WHEN response_status = 'NXDOMAIN' THEN 1 ELSE 0 END) as nxdomain
+         FROM {logs}
+         WHERE created_at >= ?
+           AND query_source = 'client'
+         GROUP BY client_ip, domain"
+    ))
+    .bind(cutoff)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        error!(error = %e, "Failed to fetch client domain counts");
+        DomainError::DatabaseError(e.to_string())
+    })?;
+
+    Ok(rows
+        .into_iter()
+        .map(|r| ClientDomainCount {
+            client_ip: r.get("client_ip"),
+            domain: r.get("domain"),
+            queries: r.get::<i64, _>("count") as u64,
+            nxdomain: r.get::<i64, _>("nxdomain") as u64,
+        })
+        .collect())
+}
+
 pub(super) async fn delete_older_than(pool: &SqlitePool, days: u32) -> Result<u64, DomainError> {
     let cutoff = days_ago_cutoff(days);
-    let mut total_deleted: u64 = 0;
+    // Partitions go whole once all their rows are past retention, so rows may
+    // outlive it by up to one partition span. A flush (`days == 0`) drops all.
+    let drop_before = (days > 0).then_some(cutoff.as_str());
+    let mut total_deleted = partitions::drop_partitions_before(pool, drop_before)
+        .await
+        .inspect_err(|e| error!(error = %e, "Failed to drop old query log partitions"))?;
 
+    // Rows logged before partitioning still need deleting from `query_log`.
     loop {
-        let result = sqlx::query(
-            "DELETE FROM query_log WHERE rowid IN (SELECT rowid FROM query_log WHERE created_at < ? LIMIT 5000)",
-        )
+        let result = sqlx::query(&format!(
+            "DELETE FROM {TEMPLATE_TABLE} WHERE rowid IN (SELECT rowid FROM {TEMPLATE_TABLE} WHERE created_at < ? LIMIT 5000)"
+        ))
         .bind(&cutoff)
         .execute(pool)
         .await
@@ -593,6 +767,13 @@ pub(super) async fn delete_older_than(pool: &SqlitePool, days: u32) -> Result<u6
         tokio::time::sleep(Duration::from_millis(50)).await;
     }
 
+    rollup::delete_before(pool, drop_before)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to delete old query stats rollups");
+            DomainError::DatabaseError(format!("Failed to delete old query stats rollups: {}", e))
+        })?;
+
     info!(
         deleted = total_deleted,
         days, "Old query logs deleted (batched)"
@@ -0,0 +1,422 @@
+//! Per-minute aggregates of timed client queries.
+//!
+//! The writer folds every flushed batch into `query_stats_rollup`, so window
+//! stats read at most one row per minute instead of scanning `query_log`.
+//! Each row also records the highest query log id it covers; rows above that
+//! watermark (logged by anything but the writer) are aggregated from the raw
+//! table, which keeps the totals exact.
+
+use super::partitions::query_log_tables;
+use chrono::{NaiveDateTime, Timelike};
+use ferrous_dns_domain::DomainError;
+use sqlx::{Row, SqliteConnection, SqlitePool};
+use std::collections::BTreeMap;
+use tracing::{error, info};
+
+/// Values below this are stored exactly; above it every power of two is split
+/// into `SUB_BUCKETS` buckets, bounding the percentile error to about 3%.
+const EXACT_LIMIT: u64 = 64;
+const SUB_BUCKET_BITS: u32 = 5;
+const SUB_BUCKETS: u64 = 1 << SUB_BUCKET_BITS;
+const FIRST_EXPONENT: u32 = 6;
+
+/// Log-linear histogram of response times in microseconds, in the spirit of
+/// HDR histograms.
+#[derive(Debug, Default, Clone)]
+pub(super) struct LatencyHistogram {
+    counts: BTreeMap<u16, u64>,
+}
+
+impl LatencyHistogram {
+    fn index_of(value: u64) -> u16 {
+        if value < EXACT_LIMIT {
+            return value as u16;
+        }
+        let exponent = 63 - value.leading_zeros();
+        let sub = (value >> (exponent - SUB_BUCKET_BITS)) & (SUB_BUCKETS - 1);
+        (EXACT_LIMIT + u64::from(exponent - FIRST_EXPONENT) * SUB_BUCKETS + sub) as u16
+    }
+
+    /// Midpoint of the values that fall in bucket `index`.
+    fn value_of(index: u16) -> u64 {
+        let index = u64::from(index);
+        if index < EXACT_LIMIT {
+            return index;
+        }
+        let exponent = ((index - EXACT_LIMIT) / SUB_BUCKETS) as u32 + FIRST_EXPONENT;
+        let sub = (index - EXACT_LIMIT) % SUB_BUCKETS;
+        let width = 1u64 << (exponent - SUB_BUCKET_BITS);
+        (SUB_BUCKETS + sub) * width + width / 2
+    }
+
+    pub(super) fn record(&mut self, value: u64) {
+        *self.counts.entry(Self::index_of(value)).or_default() += 1;
+    }
+
+    fn merge(&mut self, other: &LatencyHistogram) {
+        for (&index, &count) in &other.counts {
+            *self.counts.entry(index).or_default() += count;
+        }
+    }
+
+    /// Nearest-rank `percentile` (0.0–1.0), or `None` when empty.
+    pub(super) fn percentile(&self, percentile: f64) -> Option<u64> {
+        let total: u64 = self.counts.values().sum();
+        if total == 0 {
+            return None;
+        }
+        let rank = ((percentile * total as f64).ceil() as u64).clamp(1, total);
+        let mut seen = 0;
+        for (&index, &count) in &self.counts {
+            seen += count;
+            if seen >= rank {
+                return Some(Self::value_of(index));
+            }
+        }
+        None
+    }
+
+    /// Sparse encoding: little-endian `u16` bucket index and `u64` count per
+    /// non-empty bucket.
+    fn encode(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(self.counts.len() * 10);
+        for (&index, &count) in &self.counts {
+            out.extend_from_slice(&index.to_le_bytes());
+            out.extend_from_slice(&count.to_le_bytes());
+        }
+        out
+    }
+
+    fn decode(bytes: &[u8]) -> Self {
+        let mut counts = BTreeMap::new();
+        for pair in bytes.chunks_exact(10) {
+            let index = u16::from_le_bytes([pair[0], pair[1]]);
+            let mut count = [0u8; 8];
+            count.copy_from_slice(&pair[2..]);
+            *counts.entry(index).or_default() += u64::from_le_bytes(count);
+        }
+        Self { counts }
+    }
+}
+
+/// Aggregates of timed client queries, matching the `query_log` stats
+/// filters.
+#[derive(Debug, Default, Clone)]
+pub(super) struct StatsRollup {
+    pub queries: u64,
+    pub blocked: u64,
+    pub rate_limited: u64,
+    pub cache_hits: u64,
+    pub local_dns: u64,
+    pub any_minimized: u64,
+    pub time_sum: u64,
+    pub cache_time_sum: u64,
+    pub upstream_queries: u64,
+    pub upstream_time_sum: u64,
+    pub latency: LatencyHistogram,
+}
+
+impl StatsRollup {
+    pub(super) fn record(
+        &mut self,
+        blocked: bool,
+        cache_hit: bool,
+        response_status: Option<&str>,
+        response_time_us: u64,
+    ) {
+        self.queries += 1;
+        self.time_sum += response_time_us;
+        self.latency.record(response_time_us);
+        if blocked {
+            self.blocked += 1;
+        }
+        if cache_hit {
+            self.cache_hits += 1;
+            self.cache_time_sum += response_time_us;
+        }
+        match response_status {
+            Some("RATE_LIMITED" | "RATE_LIMITED_TC") => self.rate_limited += 1,
+            Some("LOCAL_DNS") => self.local_dns += 1,
+            Some("ANY_MINIMIZED") => self.any_minimized += 1,
+            _ => {}
+        }
+        // Same rule as the SQL `response_status NOT IN (...)`, which never
+        // matches a NULL status.
+        let upstream = !cache_hit
+            && !blocked
+            && response_status.is_some_and(|s| s != "LOCAL_DNS" && s != "ANY_MINIMIZED");
+        if upstream {
+            self.upstream_queries += 1;
+            self.upstream_time_sum += response_time_us;
+        }
+    }
+
+    fn merge(&mut self, other: &StatsRollup) {
+        self.queries += other.queries;
+        self.blocked += other.blocked;
+        self.rate_limited += other.rate_limited;
+        self.cache_hits += other.cache_hits;
+        self.local_dns += other.local_dns;
+        self.any_minimized += other.any_minimized;
+        self.time_sum += other.time_sum;
+        self.cache_time_sum += other.cache_time_sum;
+        self.upstream_queries += other.upstream_queries;
+        self.upstream_time_sum += other.upstream_time_sum;
+        self.latency.merge(&other.latency);
+    }
+
+    fn from_row(row: &sqlx::sqlite::SqliteRow) -> Self {
+        let count = |column: &str| row.get::<i64, _>(column).max(0) as u64;
+        Self {
+            queries: count("queries"),
+            blocked: count("blocked"),
+            rate_limited: count("rate_limited"),
+            cache_hits: count("cache_hits"),
+            local_dns: count("local_dns"),
+            any_minimized: count("any_minimized"),
+            time_sum: count("time_sum"),
+            cache_time_sum: count("cache_time_sum"),
+            upstream_queries: count("upstream_queries"),
+            upstream_time_sum: count("upstream_time_sum"),
+            latency: row
+                .get::<Option<Vec<u8>>, _>("latency_histogram")
+                .map(|blob| LatencyHistogram::decode(&blob))
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Mean of `sum` over `count` microseconds, in milliseconds.
+    pub(super) fn mean_ms(sum: u64, count: u64) -> f64 {
+        if count == 0 {
+            0.0
+        } else {
+            sum as f64 / count as f64 / 1000.0
+        }
+    }
+
+    pub(super) fn percentile_ms(&self, percentile: f64) -> f64 {
+        self.latency
+            .percentile(percentile)
+            .map_or(0.0, |us| us as f64 / 1000.0)
+    }
+}
+
+fn db_error(e: sqlx::Error) -> DomainError {
+    DomainError::DatabaseError(e.to_string())
+}
+
+/// Minute bucket key of `at`, in the `created_at` text format.
+pub(super) fn bucket_of(at: NaiveDateTime) -> String {
+    at.with_second(0)
+        .and_then(|t| t.with_nanosecond(0))
+        .unwrap_or(at)
+        .format("%Y-%m-%d %H:%M:%S")
+        .to_string()
+}
+
+/// Adds `batch` to the rollup of `bucket` and moves its watermark to
+/// `last_id`. Runs inside the writer's transaction.
+pub(super) async fn add_batch(
+    conn: &mut SqliteConnection,
+    bucket: &str,
+    batch: &StatsRollup,
+    last_id: i64,
+) -> Result<(), sqlx::Error> {
+    let existing: Option<Option<Vec<u8>>> =
+        sqlx::query_scalar("SELECT latency_histogram FROM query_stats_rollup WHERE bucket = ?")
+            .bind(bucket)
+            .fetch_optional(&mut *conn)
+            .await?;
+    let mut latency = existing
+        .flatten()
+        .map(|blob| LatencyHistogram::decode(&blob))
+        .unwrap_or_default();
+    latency.merge(&batch.latency);
+
+    sqlx::query(
+        "INSERT INTO query_stats_rollup
+            (bucket, queries, blocked, rate_limited, cache_hits, local_dns, any_minimized,
+             time_sum, cache_time_sum, upstream_queries, upstream_time_sum,
+             latency_histogram, last_id)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+         ON CONFLICT(bucket) DO UPDATE SET
+            queries = queries + excluded.queries,
+            blocked = blocked + excluded.blocked,
+            rate_limited = rate_limited + excluded.rate_limited,
+            cache_hits = cache_hits + excluded.cache_hits,
+            local_dns = local_dns + excluded.local_dns,
+            any_minimized = any_minimized + excluded.any_minimized,
+            time_sum = time_sum + excluded.time_sum,
+            cache_time_sum = cache_time_sum + excluded.cache_time_sum,
+            upstream_queries = upstream_queries + excluded.upstream_queries,
+            upstream_time_sum = upstream_time_sum + excluded.upstream_time_sum,
+            latency_histogram = excluded.latency_histogram,
+            last_id = MAX(last_id, excluded.last_id)",
+    )
+    .bind(bucket)
+    .bind(batch.queries as i64)
+    .bind(batch.blocked as i64)
+    .bind(batch.rate_limited as i64)
+    .bind(batch.cache_hits as i64)
+    .bind(batch.local_dns as i64)
+    .bind(batch.any_minimized as i64)
+    .bind(batch.time_sum as i64)
+    .bind(batch.cache_time_sum as i64)
+    .bind(batch.upstream_queries as i64)
+    .bind(batch.upstream_time_sum as i64)
+    .bind(latency.encode())
+    .bind(last_id)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+/// Aggregates of the timed client queries created at or after `cutoff`, read
+/// from `logs` (a query log `FROM` source) only above the rollup watermark.
+/// The rollup side is exact to the minute.
+pub(super) async fn window(
+    pool: &SqlitePool,
+    logs: &str,
+    cutoff: &str,
+) -> Result<StatsRollup, DomainError> {
+    let bucket = NaiveDateTime::parse_from_str(cutoff, "%Y-%m-%d %H:%M:%S")
+        .map(bucket_of)
+        .unwrap_or_else(|_| cutoff.to_string());
+    let tail_sql = format!(
+        "SELECT blocked, cache_hit, response_status, response_time_ms
+         FROM {logs}
+         WHERE id > ?
+           AND created_at >= ?
+           AND query_source = 'client'
+           AND response_time_ms IS NOT NULL"
+    );
+
+    // One read transaction, so a batch committed meanwhile is counted either
+    // in the rollups or above the watermark, never in both.
+    let mut tx = pool.begin().await.map_err(db_error)?;
+    let watermark: i64 =
+        sqlx::query_scalar("SELECT COALESCE(MAX(last_id), 0) FROM query_stats_rollup")
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to read stats rollup watermark");
+                db_error(e)
+            })?;
+    let rollup_rows = sqlx::query("SELECT * FROM query_stats_rollup WHERE bucket >= ?")
+        .bind(&bucket)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to read stats rollups");
+            db_error(e)
+        })?;
+    let tail_rows = sqlx::query(&tail_sql)
+        .bind(watermark)
+        .bind(cutoff)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to read query logs above the rollup watermark");
+            db_error(e)
+        })?;
+    tx.commit().await.map_err(db_error)?;
+
+    let mut total = StatsRollup::default();
+    for row in rollup_rows {
+        total.merge(&StatsRollup::from_row(&row));
+    }
+    for row in tail_rows {
+        total.record(
+            row.get::<i64, _>("blocked") != 0,
+            row.get::<i64, _>("cache_hit") != 0,
+            row.get::<Option<String>, _>("response_status").as_deref(),
+            row.get::<i64, _>("response_time_ms").max(0) as u64,
+        );
+    }
+    Ok(total)
+}
+
+/// Deletes the rollups of minutes before `cutoff`, or all of them when
+/// `cutoff` is `None`.
+pub(super) async fn delete_before(
+    pool: &SqlitePool,
+    cutoff: Option<&str>,
+) -> Result<u64, sqlx::Error> {
+    let result = match cutoff {
+        Some(cutoff) => {
+            sqlx::query("DELETE FROM query_stats_rollup WHERE bucket < ?")
+                .bind(cutoff)
+                .execute(pool)
+                .await?
+        }
+        None => {
+            sqlx::query("DELETE FROM query_stats_rollup")
+                .execute(pool)
+                .await?
+        }
+    };
+    Ok(result.rows_affected())
+}
+
+/// Builds the rollups from the query log tables when there are none yet, as
+/// after upgrading. Counts and sums are rebuilt; latency histograms start
+/// with the rows logged from then on.
+pub(super) async fn backfill(pool: &SqlitePool) -> Result<(), DomainError> {
+    let existing: Option<i64> = sqlx::query_scalar("SELECT 1 FROM query_stats_rollup LIMIT 1")
+        .fetch_optional(pool)
+        .await
+        .map_err(db_error)?;
+    if existing.is_some() {
+        return Ok(());
+    }
+
+    let tables = query_log_tables(pool).await?;
+    let mut tx = pool.begin().await.map_err(db_error)?;
+    for table in &tables {
+        sqlx::query(&format!(
+            "INSERT INTO query_stats_rollup
+                (bucket, queries, blocked, rate_limited, cache_hits, local_dns, any_minimized,
+                 time_sum, cache_time_sum, upstream_queries, upstream_time_sum, last_id)
+             SELECT strftime('%Y-%m-%d %H:%M:00', created_at) AS minute,
+                    COUNT(*),
+                    SUM(blocked = 1),
+                    SUM(response_status IN ('RATE_LIMITED', 'RATE_LIMITED_TC')),
+                    SUM(cache_hit = 1),
+                    SUM(response_status = 'LOCAL_DNS'),
+                    SUM(response_status = 'ANY_MINIMIZED'),
+                    SUM(response_time_ms),
+                    SUM(CASE WHEN cache_hit = 1 THEN response_time_ms ELSE 0 END),
+                    SUM(cache_hit = 0 AND blocked = 0 AND response_status NOT IN ('LOCAL_DNS', 'ANY_MINIMIZED')),
+                    SUM(CASE WHEN cache_hit = 0 AND blocked = 0 AND response_status NOT IN ('LOCAL_DNS', 'ANY_MINIMIZED') THEN response_time_ms ELSE 0 END),
+                    MAX(id)
+             FROM {table}
+             WHERE query_source = 'client' AND response_time_ms IS NOT NULL
+             GROUP BY minute
+             ON CONFLICT(bucket) DO UPDATE SET
+                queries = queries + excluded.queries,
+                blocked = blocked + excluded.blocked,
+                rate_limited = rate_limited + excluded.rate_limited,
+                cache_hits = cache_hits + excluded.cache_hits,
+                local_dns = local_dns + excluded.local_dns,
+                any_minimized = any_minimized + excluded.any_minimized,
+                time_sum = time_sum + excluded.time_sum,
+                cache_time_sum = cache_time_sum + excluded.cache_time_sum,
+                upstream_queries = upstream_queries + excluded.upstream_queries,
+                upstream_time_sum = upstream_time_sum + excluded.upstream_time_sum,
+                last_id = MAX(last_id, excluded.last_id)"
+        ))
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+    }
+    tx.commit().await.map_err(db_error)?;
+
+    let buckets: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM query_stats_rollup")
+        .fetch_one(pool)
+        .await
+        .map_err(db_error)?;
+    if buckets > 0 {
+        info!(buckets, "Query stats rollups rebuilt from the query log");
+    }
+    Ok(())
+}
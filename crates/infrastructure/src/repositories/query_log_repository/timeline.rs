@@ -1,6 +1,11 @@
-use super::helpers::{granularity_to_sql, hours_ago_cutoff};
+use super::helpers::{
+    granularity_to_sql, hours_ago_cutoff, row_to_timeline_bucket, TIMELINE_SERIES_SQL,
+};
+use super::partitions::query_log_source;
 use dashmap::DashMap;
-use ferrous_dns_application::ports::{TimeGranularity, TimelineBucket};
+use ferrous_dns_application::ports::{
+    TimeGranularity, TimelineBreakdown, TimelineBucket, TimelineSeries,
+};
 use ferrous_dns_domain::DomainError;
 use sqlx::{Row, SqlitePool};
 use std::time::Instant;
@@ -20,14 +25,11 @@ impl TimelineCache {
     }
 }
 
-fn build_timeline_sql(bucket_expr: &'static str) -> String {
+fn build_timeline_sql(logs: &str, bucket_expr: &'static str) -> String {
     format!(
         "SELECT {bucket_expr} as time_bucket, \
-         COUNT(*) as total, \
-         COALESCE(SUM(CASE WHEN blocked = 1 THEN 1 ELSE 0 END), 0) as blocked, \
-         COALESCE(SUM(CASE WHEN blocked = 0 THEN 1 ELSE 0 END), 0) as unblocked, \
-         COALESCE(SUM(CASE WHEN response_status IN ('TUNNELING_BLOCKED', 'DGA_BLOCKED', 'NXDOMAIN_HIJACK', 'RESPONSE_IP_BLOCKED') THEN 1 ELSE 0 END), 0) as malware_detected \
-         FROM query_log \
+         {TIMELINE_SERIES_SQL} \
+         FROM {logs} \
          WHERE created_at >= ? \
            AND query_source = 'client' \
          GROUP BY time_bucket \
@@ -66,8 +68,9 @@ pub(super) async fn get_timeline(
 
     debug!(period_hours, "Fetching query timeline");
 
-    let sql = build_timeline_sql(bucket_expr);
     let cutoff = hours_ago_cutoff(period_hours as f32);
+    let logs = query_log_source(pool, &cutoff, None).await?;
+    let sql = build_timeline_sql(&logs, bucket_expr);
 
     let rows = sqlx::query(&sql)
         .bind(cutoff)
@@ -80,13 +83,7 @@ pub(super) async fn get_timeline(
 
     let timeline: Vec<TimelineBucket> = rows
         .into_iter()
-        .map(|row| TimelineBucket {
-            timestamp: row.get("time_bucket"),
-            total: row.get::<i64, _>("total") as u64,
-            blocked: row.get::<i64, _>("blocked") as u64,
-            unblocked: row.get::<i64, _>("unblocked") as u64,
-            malware_detected: row.get::<i64, _>("malware_detected") as u64,
-        })
+        .map(|row| row_to_timeline_bucket(&row))
         .collect();
 
     debug!(buckets = timeline.len(), "Timeline fetched successfully");
@@ -95,3 +92,89 @@ pub(super) async fn get_timeline(
         .insert(cache_key, (timeline.clone(), Instant::now()));
     Ok(timeline)
 }
+
+fn build_breakdown_sql(
+    logs: &str,
+    bucket_expr: &'static str,
+    breakdown: TimelineBreakdown,
+) -> String {
+    // Each arm is a static SQL fragment — no user input is interpolated.
+    let (key_expr, label_join, label_expr) = match breakdown {
+        TimelineBreakdown::Client => (
+            "client_ip",
+            "LEFT JOIN clients c ON c.ip_address = s.series_key",
+            "COALESCE(c.display_name, c.hostname)",
+        ),
+        TimelineBreakdown::Group => (
+            "group_id",
+            "LEFT JOIN groups g ON g.id = s.series_key",
+            "g.name",
+        ),
+    };
+    format!(
+        "WITH top_keys AS ( \
+             SELECT {key_expr} as series_key FROM {logs} \
+             WHERE created_at >= ? AND query_source = 'client' AND {key_expr} IS NOT NULL \
+             GROUP BY series_key ORDER BY COUNT(*) DESC LIMIT ? \
+         ) \
+         SELECT CAST(s.series_key AS TEXT) as series_key, {label_expr} as label, s.* \
+         FROM ( \
+             SELECT {key_expr} as series_key, {bucket_expr} as time_bucket, \
+             {TIMELINE_SERIES_SQL} \
+             FROM {logs} \
+             WHERE created_at >= ? \
+               AND query_source = 'client' \
+               AND {key_expr} IN (SELECT series_key FROM top_keys) \
+             GROUP BY series_key, time_bucket \
+         ) s \
+         {label_join} \
+         ORDER BY s.series_key, s.time_bucket ASC"
+    )
+}
+
+#[instrument(skip(pool))]
+pub(super) async fn get_timeline_breakdown(
+    pool: &SqlitePool,
+    period_hours: u32,
+    granularity: TimeGranularity,
+    breakdown: TimelineBreakdown,
+    limit: u32,
+) -> Result<Vec<TimelineSeries>, DomainError> {
+    debug!("Fetching timeline breakdown");
+
+    let cutoff = hours_ago_cutoff(period_hours as f32);
+    let logs = query_log_source(pool, &cutoff, None).await?;
+    let sql = build_breakdown_sql(&logs, granularity_to_sql(granularity), breakdown);
+
+    let rows = sqlx::query(&sql)
+        .bind(&cutoff)
+        .bind(limit as i64)
+        .bind(&cutoff)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to fetch timeline breakdown");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+    let mut series: Vec<TimelineSeries> = Vec::new();
+    for row in rows {
+        let key: String = row.get(0);
+        let bucket = row_to_timeline_bucket(&row);
+        match series.last_mut() {
+            Some(last) if last.key == key => last.buckets.push(bucket),
+            _ => series.push(TimelineSeries {
+                key,
+                label: row.get("label"),
+                buckets: vec![bucket],
+            }),
+        }
+    }
+    series.sort_by_key(|s| std::cmp::Reverse(s.buckets.iter().map(|b| b.total).sum::<u64>()));
+
+    debug!(
+        series = series.len(),
+        "Timeline breakdown fetched successfully"
+    );
+    Ok(series)
+}
@@ -1,18 +1,24 @@
-use compact_str::{CompactString, ToCompactString};
+use super::partitions::{self, TEMPLATE_TABLE};
+use super::rollup::{self, StatsRollup};
+use chrono::{NaiveDateTime, Utc};
+use ferrous_dns_domain::config::QueryLogPartitioning;
 use ferrous_dns_domain::QueryLog;
 use sqlx::SqlitePool;
+use std::net::IpAddr;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc;
 use tracing::{debug, error, info, warn};
 
-const COLS_PER_ROW: usize = 14;
+const COLS_PER_ROW: usize = 20;
 const ROWS_PER_CHUNK: usize = 999 / COLS_PER_ROW;
 
+/// Built on the DNS hot path, so it only shares or copies what the query
+/// already holds; text formatting waits for the flush task.
 pub(super) struct QueryLogEntry {
-    domain: CompactString,
-    record_type: CompactString,
-    client_ip: CompactString,
+    domain: Arc<str>,
+    record_type: &'static str,
+    client_ip: IpAddr,
     blocked: bool,
     response_time_ms: Option<i64>,
     cache_hit: bool,
@@ -20,18 +26,22 @@ pub(super) struct QueryLogEntry {
     dnssec_status: Option<&'static str>,
     upstream_server: Option<Arc<str>>,
     upstream_pool: Option<Arc<str>>,
+    upstream_strategy: Option<&'static str>,
+    upstream_attempt: Option<u32>,
+    upstream_protocol: Option<&'static str>,
     response_status: Option<&'static str>,
-    query_source: CompactString,
+    query_source: &'static str,
     group_id: Option<i64>,
     block_source: Option<&'static str>,
+    plugin: Option<Arc<str>>,
 }
 
 impl QueryLogEntry {
     pub fn from_query_log(q: &QueryLog) -> Self {
         Self {
-            domain: CompactString::from(q.domain.as_ref()),
-            record_type: CompactString::from(q.record_type.as_str()),
-            client_ip: q.client_ip.to_compact_string(),
+            domain: Arc::clone(&q.domain),
+            record_type: q.record_type.as_str(),
+            client_ip: q.client_ip,
             blocked: q.blocked,
             response_time_ms: q.response_time_us.map(|t| t as i64),
             cache_hit: q.cache_hit,
@@ -39,23 +49,36 @@ impl QueryLogEntry {
             dnssec_status: q.dnssec_status,
             upstream_server: q.upstream_server.clone(),
             upstream_pool: q.upstream_pool.clone(),
+            upstream_strategy: q.upstream_strategy,
+            upstream_attempt: q.upstream_attempt,
+            upstream_protocol: q.upstream_protocol,
             response_status: q.response_status,
-            query_source: CompactString::from(q.query_source.as_str()),
+            query_source: q.query_source.as_str(),
             group_id: q.group_id,
             block_source: q.block_source.map(|s| s.to_str()),
+            plugin: q.plugin.clone(),
         }
     }
 }
 
-fn build_multi_insert_sql(n: usize) -> String {
+fn build_multi_insert_sql(table: &str, n: usize) -> String {
     debug_assert!(n > 0 && n <= ROWS_PER_CHUNK);
-    const HEADER: &str = "INSERT INTO query_log \
+    const COLUMNS: &str = " \
         (domain, record_type, client_ip, blocked, response_time_ms, cache_hit, \
-         cache_refresh, dnssec_status, upstream_server, upstream_pool, response_status, query_source, group_id, block_source) \
+         cache_refresh, dnssec_status, upstream_server, upstream_pool, upstream_strategy, \
+         upstream_attempt, upstream_protocol, response_status, query_source, group_id, tenant_id, \
+         block_source, plugin, created_at) \
         VALUES ";
-    const PLACEHOLDER: &str = "(?,?,?,?,?,?,?,?,?,?,?,?,?,?)";
-    let mut sql = String::with_capacity(HEADER.len() + n * (PLACEHOLDER.len() + 1));
-    sql.push_str(HEADER);
+    // The tenant is resolved from the group at write time, so moving a group
+    // later does not move its past queries to another tenant.
+    const PLACEHOLDER: &str =
+        "(?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,(SELECT tenant_id FROM groups WHERE id = ?),?,?,?)";
+    let mut sql = String::with_capacity(
+        "INSERT INTO ".len() + table.len() + COLUMNS.len() + n * (PLACEHOLDER.len() + 1),
+    );
+    sql.push_str("INSERT INTO ");
+    sql.push_str(table);
+    sql.push_str(COLUMNS);
     for i in 0..n {
         if i > 0 {
             sql.push(',');
@@ -65,12 +88,49 @@ fn build_multi_insert_sql(n: usize) -> String {
     sql
 }
 
+/// Routes each batch to the partition of the time it is flushed, creating
+/// the partition the first time it is needed.
+struct PartitionRouter {
+    partitioning: QueryLogPartitioning,
+    prepared: Option<String>,
+}
+
+impl PartitionRouter {
+    async fn table_at(&mut self, pool: &SqlitePool, at: NaiveDateTime) -> String {
+        let table = partitions::table_for(self.partitioning, at);
+        if table == TEMPLATE_TABLE || self.prepared.as_ref() == Some(&table) {
+            return table;
+        }
+        match partitions::prepare_partition(pool, &table).await {
+            Ok(()) => {
+                self.prepared = Some(table.clone());
+                table
+            }
+            Err(e) => {
+                error!(error = %e, table, "Failed to prepare query log partition, writing to query_log");
+                TEMPLATE_TABLE.to_string()
+            }
+        }
+    }
+}
+
 pub(super) async fn flush_loop(
     pool: SqlitePool,
     mut receiver: mpsc::Receiver<QueryLogEntry>,
     max_batch_size: usize,
     flush_interval_ms: u64,
+    partitioning: QueryLogPartitioning,
 ) {
+    if let Err(e) = partitions::sync_partitions(&pool).await {
+        error!(error = %e, "Failed to bring query log partitions up to date");
+    }
+    if let Err(e) = rollup::backfill(&pool).await {
+        error!(error = %e, "Failed to rebuild query stats rollups");
+    }
+    let mut router = PartitionRouter {
+        partitioning,
+        prepared: None,
+    };
     let mut batch: Vec<QueryLogEntry> = Vec::with_capacity(max_batch_size);
     let mut flush_interval = tokio::time::interval(Duration::from_millis(flush_interval_ms));
 
@@ -87,30 +147,39 @@ pub(super) async fn flush_loop(
                             }
                         }
                         if batch.len() >= max_batch_size {
-                            flush_batch(&pool, &mut batch).await;
+                            flush_batch(&pool, &mut router, &mut batch).await;
                         }
                     }
                     None => {
-                        if !batch.is_empty() { flush_batch(&pool, &mut batch).await; }
+                        if !batch.is_empty() { flush_batch(&pool, &mut router, &mut batch).await; }
                         info!("Query log flush task shutting down");
                         return;
                     }
                 }
             }
             _ = flush_interval.tick() => {
-                if !batch.is_empty() { flush_batch(&pool, &mut batch).await; }
+                if !batch.is_empty() { flush_batch(&pool, &mut router, &mut batch).await; }
             }
         }
     }
 }
 
-async fn flush_batch(pool: &SqlitePool, batch: &mut Vec<QueryLogEntry>) {
+async fn flush_batch(
+    pool: &SqlitePool,
+    router: &mut PartitionRouter,
+    batch: &mut Vec<QueryLogEntry>,
+) {
     let count = batch.len();
     if count == 0 {
         return;
     }
 
     let start = std::time::Instant::now();
+    // The whole batch shares one timestamp so its rows land in the partition
+    // their `created_at` belongs to.
+    let now = Utc::now().naive_utc();
+    let created_at = now.format("%Y-%m-%d %H:%M:%S").to_string();
+    let table = router.table_at(pool, now).await;
 
     let mut tx = match pool.begin().await {
         Ok(tx) => tx,
@@ -123,15 +192,17 @@ async fn flush_batch(pool: &SqlitePool, batch: &mut Vec<QueryLogEntry>) {
 
     let mut inserted = 0usize;
     let mut errors = 0usize;
+    let mut stats = StatsRollup::default();
+    let mut last_id = 0i64;
 
     for chunk in batch.chunks(ROWS_PER_CHUNK) {
-        let sql = build_multi_insert_sql(chunk.len());
+        let sql = build_multi_insert_sql(&table, chunk.len());
         let mut q = sqlx::query(&sql);
         for entry in chunk {
             q = q
-                .bind(entry.domain.as_str())
-                .bind(entry.record_type.as_str())
-                .bind(entry.client_ip.as_str())
+                .bind(entry.domain.as_ref())
+                .bind(entry.record_type)
+                .bind(entry.client_ip.to_string())
                 .bind(if entry.blocked { 1i64 } else { 0i64 })
                 .bind(entry.response_time_ms)
                 .bind(if entry.cache_hit { 1i64 } else { 0i64 })
@@ -139,20 +210,51 @@ async fn flush_batch(pool: &SqlitePool, batch: &mut Vec<QueryLogEntry>) {
                 .bind(entry.dnssec_status)
                 .bind(entry.upstream_server.as_deref())
                 .bind(entry.upstream_pool.as_deref())
+                .bind(entry.upstream_strategy)
+                .bind(entry.upstream_attempt.map(i64::from))
+                .bind(entry.upstream_protocol)
                 .bind(entry.response_status)
-                .bind(entry.query_source.as_str())
+                .bind(entry.query_source)
+                .bind(entry.group_id)
                 .bind(entry.group_id)
-                .bind(entry.block_source);
+                .bind(entry.block_source)
+                .bind(entry.plugin.as_deref())
+                .bind(created_at.as_str());
         }
         match q.execute(&mut *tx).await {
-            Ok(r) => inserted += r.rows_affected() as usize,
+            Ok(r) => {
+                inserted += r.rows_affected() as usize;
+                last_id = last_id.max(r.last_insert_rowid());
+                // Same rows the stats queries count: timed client queries.
+                let timed = chunk
+                    .iter()
+                    .filter(|entry| entry.query_source == "client")
+                    .filter_map(|entry| entry.response_time_ms.map(|time| (entry, time)));
+                for (entry, time) in timed {
+                    stats.record(
+                        entry.blocked,
+                        entry.cache_hit,
+                        entry.response_status,
+                        time.max(0) as u64,
+                    );
+                }
+            }
             Err(e) => {
+                // A log flush may have dropped the partition; prepare it again.
+                router.prepared = None;
                 errors += chunk.len();
                 warn!(error = %e, chunk_size = chunk.len(), "Failed to insert query log chunk");
             }
         }
     }
 
+    if last_id > 0 {
+        if let Err(e) = rollup::add_batch(&mut *tx, &rollup::bucket_of(now), &stats, last_id).await
+        {
+            warn!(error = %e, "Failed to update query stats rollup");
+        }
+    }
+
     match tx.commit().await {
         Ok(_) => {
             let elapsed = start.elapsed();
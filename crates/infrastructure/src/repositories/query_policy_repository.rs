@@ -0,0 +1,219 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::QueryPolicyRepository;
+use ferrous_dns_domain::{DomainError, PolicyAction, QueryPolicy, RecordType};
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use tracing::{error, instrument, warn};
+
+const POLICY_COLUMNS: &str = "id, name, priority, enabled, client_cidr, group_id, domain_pattern,
+     record_types, days, start_time, end_time, action, action_target, comment,
+     created_at, updated_at";
+
+#[derive(sqlx::FromRow)]
+struct QueryPolicyRow {
+    id: i64,
+    name: String,
+    priority: i64,
+    enabled: i64,
+    client_cidr: Option<String>,
+    group_id: Option<i64>,
+    domain_pattern: Option<String>,
+    record_types: Option<String>,
+    days: Option<i64>,
+    start_time: Option<String>,
+    end_time: Option<String>,
+    action: String,
+    action_target: Option<String>,
+    comment: Option<String>,
+    created_at: Option<String>,
+    updated_at: Option<String>,
+}
+
+pub struct SqliteQueryPolicyRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteQueryPolicyRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_policy(row: QueryPolicyRow) -> QueryPolicy {
+        let action = row.action.parse::<PolicyAction>().unwrap_or_else(|_| {
+            warn!(policy_id = row.id, action = %row.action, "Unknown policy action, treating as log_only");
+            PolicyAction::LogOnly
+        });
+        QueryPolicy {
+            id: Some(row.id),
+            name: Arc::from(row.name.as_str()),
+            priority: row.priority,
+            enabled: row.enabled != 0,
+            client_cidr: row.client_cidr.map(|s| Arc::from(s.as_str())),
+            group_id: row.group_id,
+            domain_pattern: row.domain_pattern.map(|s| Arc::from(s.as_str())),
+            record_types: row
+                .record_types
+                .as_deref()
+                .map(decode_record_types)
+                .unwrap_or_default(),
+            days: row.days.map(|d| d as u8),
+            start_time: row.start_time.map(|s| Arc::from(s.as_str())),
+            end_time: row.end_time.map(|s| Arc::from(s.as_str())),
+            action,
+            action_target: row.action_target.map(|s| Arc::from(s.as_str())),
+            comment: row.comment.map(|s| Arc::from(s.as_str())),
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+fn encode_record_types(types: &[RecordType]) -> Option<String> {
+    if types.is_empty() {
+        return None;
+    }
+    Some(
+        types
+            .iter()
+            .map(|t| t.as_str())
+            .collect::<Vec<_>>()
+            .join(","),
+    )
+}
+
+fn decode_record_types(s: &str) -> Vec<RecordType> {
+    s.split(',')
+        .filter_map(|t| t.trim().parse::<RecordType>().ok())
+        .collect()
+}
+
+#[async_trait]
+impl QueryPolicyRepository for SqliteQueryPolicyRepository {
+    #[instrument(skip(self))]
+    async fn create(&self, policy: &QueryPolicy) -> Result<QueryPolicy, DomainError> {
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        let sql = format!(
+            "INSERT INTO query_policies (name, priority, enabled, client_cidr, group_id,
+                 domain_pattern, record_types, days, start_time, end_time, action,
+                 action_target, comment, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+             RETURNING {POLICY_COLUMNS}"
+        );
+        let row: QueryPolicyRow = sqlx::query_as(&sql)
+            .bind(policy.name.as_ref())
+            .bind(policy.priority)
+            .bind(if policy.enabled { 1i64 } else { 0i64 })
+            .bind(policy.client_cidr.as_deref())
+            .bind(policy.group_id)
+            .bind(policy.domain_pattern.as_deref())
+            .bind(encode_record_types(&policy.record_types))
+            .bind(policy.days.map(i64::from))
+            .bind(policy.start_time.as_deref())
+            .bind(policy.end_time.as_deref())
+            .bind(policy.action.to_str())
+            .bind(policy.action_target.as_deref())
+            .bind(policy.comment.as_deref())
+            .bind(&now)
+            .bind(&now)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to create query policy");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        Ok(Self::row_to_policy(row))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_by_id(&self, id: i64) -> Result<Option<QueryPolicy>, DomainError> {
+        let sql = format!("SELECT {POLICY_COLUMNS} FROM query_policies WHERE id = ?");
+        let row: Option<QueryPolicyRow> = sqlx::query_as(&sql)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to query query policy by id");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        Ok(row.map(Self::row_to_policy))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_all(&self) -> Result<Vec<QueryPolicy>, DomainError> {
+        let sql =
+            format!("SELECT {POLICY_COLUMNS} FROM query_policies ORDER BY priority ASC, id ASC");
+        let rows: Vec<QueryPolicyRow> =
+            sqlx::query_as(&sql)
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| {
+                    error!(error = %e, "Failed to query all query policies");
+                    DomainError::DatabaseError(e.to_string())
+                })?;
+
+        Ok(rows.into_iter().map(Self::row_to_policy).collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn update(&self, policy: &QueryPolicy) -> Result<QueryPolicy, DomainError> {
+        let id = policy
+            .id
+            .ok_or_else(|| DomainError::InvalidQueryPolicy("policy id is required".to_string()))?;
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        let sql = format!(
+            "UPDATE query_policies SET
+                 name = ?, priority = ?, enabled = ?, client_cidr = ?, group_id = ?,
+                 domain_pattern = ?, record_types = ?, days = ?, start_time = ?, end_time = ?,
+                 action = ?, action_target = ?, comment = ?, updated_at = ?
+             WHERE id = ?
+             RETURNING {POLICY_COLUMNS}"
+        );
+        let row: Option<QueryPolicyRow> = sqlx::query_as(&sql)
+            .bind(policy.name.as_ref())
+            .bind(policy.priority)
+            .bind(if policy.enabled { 1i64 } else { 0i64 })
+            .bind(policy.client_cidr.as_deref())
+            .bind(policy.group_id)
+            .bind(policy.domain_pattern.as_deref())
+            .bind(encode_record_types(&policy.record_types))
+            .bind(policy.days.map(i64::from))
+            .bind(policy.start_time.as_deref())
+            .bind(policy.end_time.as_deref())
+            .bind(policy.action.to_str())
+            .bind(policy.action_target.as_deref())
+            .bind(policy.comment.as_deref())
+            .bind(&now)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to update query policy");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        row.map(Self::row_to_policy)
+            .ok_or(DomainError::QueryPolicyNotFound(id))
+    }
+
+    #[instrument(skip(self))]
+    async fn delete(&self, id: i64) -> Result<(), DomainError> {
+        let result = sqlx::query("DELETE FROM query_policies WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to delete query policy");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::QueryPolicyNotFound(id));
+        }
+
+        Ok(())
+    }
+}
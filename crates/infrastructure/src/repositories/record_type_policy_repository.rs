@@ -0,0 +1,109 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::RecordTypePolicyRepository;
+use ferrous_dns_domain::{DomainError, RecordType, RecordTypeFilterMode, RecordTypePolicy};
+use sqlx::SqlitePool;
+use tracing::warn;
+
+type PolicyRow = (i64, i64, String, String, String, String);
+
+pub struct SqliteRecordTypePolicyRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteRecordTypePolicyRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_policy(
+        (id, group_id, mode, record_types, created_at, updated_at): PolicyRow,
+    ) -> Option<RecordTypePolicy> {
+        let mode = mode
+            .parse::<RecordTypeFilterMode>()
+            .map_err(|_| {
+                warn!(group_id, mode = %mode, "Unrecognised record type filter mode, skipping row");
+            })
+            .ok()?;
+        Some(RecordTypePolicy {
+            id: Some(id),
+            group_id,
+            mode,
+            record_types: record_types
+                .split(',')
+                .filter_map(|t| t.trim().parse::<RecordType>().ok())
+                .collect(),
+            created_at: Some(created_at),
+            updated_at: Some(updated_at),
+        })
+    }
+}
+
+#[async_trait]
+impl RecordTypePolicyRepository for SqliteRecordTypePolicyRepository {
+    async fn get_all(&self) -> Result<Vec<RecordTypePolicy>, DomainError> {
+        let rows = sqlx::query_as::<_, PolicyRow>(
+            "SELECT id, group_id, mode, record_types, created_at, updated_at
+             FROM group_record_type_policies
+             ORDER BY group_id",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().filter_map(Self::row_to_policy).collect())
+    }
+
+    async fn get_by_group(&self, group_id: i64) -> Result<Option<RecordTypePolicy>, DomainError> {
+        let row = sqlx::query_as::<_, PolicyRow>(
+            "SELECT id, group_id, mode, record_types, created_at, updated_at
+             FROM group_record_type_policies
+             WHERE group_id = ?",
+        )
+        .bind(group_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(row.and_then(Self::row_to_policy))
+    }
+
+    async fn upsert(&self, policy: &RecordTypePolicy) -> Result<RecordTypePolicy, DomainError> {
+        let now = chrono::Utc::now().to_rfc3339();
+        let record_types = policy
+            .record_types
+            .iter()
+            .map(|t| t.as_str())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let row = sqlx::query_as::<_, PolicyRow>(
+            "INSERT INTO group_record_type_policies (group_id, mode, record_types, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?)
+             ON CONFLICT(group_id) DO UPDATE SET
+               mode         = excluded.mode,
+               record_types = excluded.record_types,
+               updated_at   = excluded.updated_at
+             RETURNING id, group_id, mode, record_types, created_at, updated_at",
+        )
+        .bind(policy.group_id)
+        .bind(policy.mode.to_str())
+        .bind(&record_types)
+        .bind(&now)
+        .bind(&now)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Self::row_to_policy(row)
+            .ok_or_else(|| DomainError::DatabaseError("Invalid record type policy row".into()))
+    }
+
+    async fn delete_by_group(&self, group_id: i64) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM group_record_type_policies WHERE group_id = ?")
+            .bind(group_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,169 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::SecondaryZoneRepository;
+use ferrous_dns_domain::{DomainError, SecondaryZone};
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::{error, instrument};
+
+#[derive(sqlx::FromRow)]
+struct SecondaryZoneRow {
+    zone: String,
+    serial: i64,
+    refresh: i64,
+    retry: i64,
+    expire: i64,
+    transferred_at: i64,
+    checked_at: i64,
+}
+
+pub struct SqliteSecondaryZoneRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteSecondaryZoneRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_zone(row: SecondaryZoneRow, records: Vec<Vec<u8>>) -> SecondaryZone {
+        let timer = |value: i64| value.clamp(0, u32::MAX as i64) as u32;
+        SecondaryZone {
+            zone: Arc::from(row.zone.as_str()),
+            serial: timer(row.serial),
+            refresh: timer(row.refresh),
+            retry: timer(row.retry),
+            expire: timer(row.expire),
+            transferred_at: row.transferred_at,
+            checked_at: row.checked_at,
+            records,
+        }
+    }
+}
+
+fn db_error(context: &str) -> impl FnOnce(sqlx::Error) -> DomainError + '_ {
+    move |e| {
+        error!(error = %e, "{}", context);
+        DomainError::DatabaseError(e.to_string())
+    }
+}
+
+#[async_trait]
+impl SecondaryZoneRepository for SqliteSecondaryZoneRepository {
+    #[instrument(skip(self))]
+    async fn get_all(&self) -> Result<Vec<SecondaryZone>, DomainError> {
+        let rows = sqlx::query_as::<_, SecondaryZoneRow>(
+            "SELECT zone, serial, refresh, retry, expire, transferred_at, checked_at
+             FROM secondary_zones ORDER BY zone",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error("Failed to load secondary zones"))?;
+
+        let record_rows = sqlx::query_as::<_, (String, Vec<u8>)>(
+            "SELECT zone, record FROM secondary_zone_records ORDER BY id",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error("Failed to load secondary zone records"))?;
+
+        let mut records: HashMap<String, Vec<Vec<u8>>> = HashMap::new();
+        for (zone, record) in record_rows {
+            records.entry(zone).or_default().push(record);
+        }
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let zone_records = records.remove(&row.zone).unwrap_or_default();
+                Self::row_to_zone(row, zone_records)
+            })
+            .collect())
+    }
+
+    #[instrument(skip(self, zone), fields(zone = %zone.zone, serial = zone.serial))]
+    async fn save(&self, zone: &SecondaryZone) -> Result<(), DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(db_error("Failed to begin transaction"))?;
+
+        sqlx::query("DELETE FROM secondary_zone_records WHERE zone = ?")
+            .bind(zone.zone.as_ref())
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error("Failed to clear secondary zone records"))?;
+
+        sqlx::query(
+            "INSERT INTO secondary_zones
+                 (zone, serial, refresh, retry, expire, transferred_at, checked_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?)
+             ON CONFLICT(zone) DO UPDATE SET
+                 serial = excluded.serial,
+                 refresh = excluded.refresh,
+                 retry = excluded.retry,
+                 expire = excluded.expire,
+                 transferred_at = excluded.transferred_at,
+                 checked_at = excluded.checked_at",
+        )
+        .bind(zone.zone.as_ref())
+        .bind(i64::from(zone.serial))
+        .bind(i64::from(zone.refresh))
+        .bind(i64::from(zone.retry))
+        .bind(i64::from(zone.expire))
+        .bind(zone.transferred_at)
+        .bind(zone.checked_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error("Failed to save secondary zone"))?;
+
+        for record in &zone.records {
+            sqlx::query("INSERT INTO secondary_zone_records (zone, record) VALUES (?, ?)")
+                .bind(zone.zone.as_ref())
+                .bind(record.as_slice())
+                .execute(&mut *tx)
+                .await
+                .map_err(db_error("Failed to insert secondary zone record"))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(db_error("Failed to commit secondary zone"))?;
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn mark_checked(&self, zone: &str, checked_at: i64) -> Result<(), DomainError> {
+        sqlx::query("UPDATE secondary_zones SET checked_at = ? WHERE zone = ?")
+            .bind(checked_at)
+            .bind(zone)
+            .execute(&self.pool)
+            .await
+            .map_err(db_error("Failed to update secondary zone"))?;
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete(&self, zone: &str) -> Result<(), DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(db_error("Failed to begin transaction"))?;
+        sqlx::query("DELETE FROM secondary_zone_records WHERE zone = ?")
+            .bind(zone)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error("Failed to delete secondary zone records"))?;
+        sqlx::query("DELETE FROM secondary_zones WHERE zone = ?")
+            .bind(zone)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error("Failed to delete secondary zone"))?;
+        tx.commit()
+            .await
+            .map_err(db_error("Failed to commit secondary zone deletion"))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,322 @@
+use super::query_log_repository::{hours_ago_cutoff, query_log_source};
+use async_trait::async_trait;
+use ferrous_dns_application::ports::TenantRepository;
+use ferrous_dns_domain::{DomainError, Tenant, TenantStats};
+use sqlx::{Sqlite, SqlitePool, Transaction};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::{error, instrument};
+
+const TENANT_COLUMNS: &str =
+    "id, name, listener, default_group_id, comment, created_at, updated_at";
+
+#[derive(sqlx::FromRow)]
+struct TenantRow {
+    id: i64,
+    name: String,
+    listener: Option<String>,
+    default_group_id: Option<i64>,
+    comment: Option<String>,
+    created_at: Option<String>,
+    updated_at: Option<String>,
+}
+
+pub struct SqliteTenantRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteTenantRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_tenant(row: TenantRow, subnets: Vec<Arc<str>>) -> Tenant {
+        Tenant {
+            id: Some(row.id),
+            name: Arc::from(row.name.as_str()),
+            listener: row.listener.map(|s| Arc::from(s.as_str())),
+            subnets,
+            default_group_id: row.default_group_id,
+            comment: row.comment.map(|s| Arc::from(s.as_str())),
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+
+    fn db_error(e: sqlx::Error, context: &str) -> DomainError {
+        error!(error = %e, "{}", context);
+        DomainError::DatabaseError(e.to_string())
+    }
+
+    fn map_write_error(e: sqlx::Error, tenant: &Tenant, context: &str) -> DomainError {
+        let message = e.to_string();
+        if message.contains("UNIQUE constraint failed: tenant_subnets") {
+            DomainError::InvalidTenant(format!(
+                "A subnet of tenant '{}' already belongs to a tenant",
+                tenant.name
+            ))
+        } else if message.contains("UNIQUE constraint failed: tenants.listener") {
+            DomainError::InvalidTenant(format!(
+                "Listener '{}' already belongs to a tenant",
+                tenant.listener.as_deref().unwrap_or_default()
+            ))
+        } else if message.contains("UNIQUE constraint failed") {
+            DomainError::InvalidTenant(format!("Tenant '{}' already exists", tenant.name))
+        } else {
+            Self::db_error(e, context)
+        }
+    }
+
+    async fn replace_subnets(
+        tx: &mut Transaction<'_, Sqlite>,
+        tenant: &Tenant,
+        tenant_id: i64,
+    ) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM tenant_subnets WHERE tenant_id = ?")
+            .bind(tenant_id)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| Self::db_error(e, "Failed to delete tenant subnets"))?;
+
+        for subnet in &tenant.subnets {
+            sqlx::query("INSERT INTO tenant_subnets (tenant_id, subnet_cidr) VALUES (?, ?)")
+                .bind(tenant_id)
+                .bind(subnet.as_ref())
+                .execute(&mut **tx)
+                .await
+                .map_err(|e| Self::map_write_error(e, tenant, "Failed to insert tenant subnet"))?;
+        }
+        Ok(())
+    }
+
+    async fn subnets_of(&self, tenant_id: i64) -> Result<Vec<Arc<str>>, DomainError> {
+        let rows: Vec<(String,)> = sqlx::query_as(
+            "SELECT subnet_cidr FROM tenant_subnets WHERE tenant_id = ? ORDER BY subnet_cidr",
+        )
+        .bind(tenant_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| Self::db_error(e, "Failed to query tenant subnets"))?;
+
+        Ok(rows.into_iter().map(|(s,)| Arc::from(s.as_str())).collect())
+    }
+}
+
+#[async_trait]
+impl TenantRepository for SqliteTenantRepository {
+    #[instrument(skip(self))]
+    async fn create(&self, tenant: &Tenant) -> Result<Tenant, DomainError> {
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| Self::db_error(e, "Failed to begin transaction"))?;
+
+        let sql = format!(
+            "INSERT INTO tenants (name, listener, default_group_id, comment, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?)
+             RETURNING {TENANT_COLUMNS}"
+        );
+        let row: TenantRow = sqlx::query_as(&sql)
+            .bind(tenant.name.as_ref())
+            .bind(tenant.listener.as_deref())
+            .bind(tenant.default_group_id)
+            .bind(tenant.comment.as_deref())
+            .bind(&now)
+            .bind(&now)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| Self::map_write_error(e, tenant, "Failed to create tenant"))?;
+
+        Self::replace_subnets(&mut tx, tenant, row.id).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| Self::db_error(e, "Failed to commit tenant creation"))?;
+
+        Ok(Self::row_to_tenant(row, tenant.subnets.clone()))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_by_id(&self, id: i64) -> Result<Option<Tenant>, DomainError> {
+        let sql = format!("SELECT {TENANT_COLUMNS} FROM tenants WHERE id = ?");
+        let row: Option<TenantRow> = sqlx::query_as(&sql)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| Self::db_error(e, "Failed to query tenant by id"))?;
+
+        match row {
+            Some(row) => {
+                let subnets = self.subnets_of(row.id).await?;
+                Ok(Some(Self::row_to_tenant(row, subnets)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn get_all(&self) -> Result<Vec<Tenant>, DomainError> {
+        let sql = format!("SELECT {TENANT_COLUMNS} FROM tenants ORDER BY name ASC");
+        let rows: Vec<TenantRow> = sqlx::query_as(&sql)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| Self::db_error(e, "Failed to query all tenants"))?;
+
+        let subnet_rows: Vec<(i64, String)> = sqlx::query_as(
+            "SELECT tenant_id, subnet_cidr FROM tenant_subnets ORDER BY subnet_cidr",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| Self::db_error(e, "Failed to query tenant subnets"))?;
+
+        let mut subnets: HashMap<i64, Vec<Arc<str>>> = HashMap::new();
+        for (tenant_id, cidr) in subnet_rows {
+            subnets
+                .entry(tenant_id)
+                .or_default()
+                .push(Arc::from(cidr.as_str()));
+        }
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let tenant_subnets = subnets.remove(&row.id).unwrap_or_default();
+                Self::row_to_tenant(row, tenant_subnets)
+            })
+            .collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn update(&self, tenant: &Tenant) -> Result<Tenant, DomainError> {
+        let id = tenant
+            .id
+            .ok_or_else(|| DomainError::InvalidTenant("tenant id is required".to_string()))?;
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| Self::db_error(e, "Failed to begin transaction"))?;
+
+        let sql = format!(
+            "UPDATE tenants SET name = ?, listener = ?, default_group_id = ?, comment = ?,
+                 updated_at = ?
+             WHERE id = ?
+             RETURNING {TENANT_COLUMNS}"
+        );
+        let row: TenantRow = sqlx::query_as(&sql)
+            .bind(tenant.name.as_ref())
+            .bind(tenant.listener.as_deref())
+            .bind(tenant.default_group_id)
+            .bind(tenant.comment.as_deref())
+            .bind(&now)
+            .bind(id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| Self::map_write_error(e, tenant, "Failed to update tenant"))?
+            .ok_or(DomainError::TenantNotFound(id))?;
+
+        Self::replace_subnets(&mut tx, tenant, id).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| Self::db_error(e, "Failed to commit tenant update"))?;
+
+        Ok(Self::row_to_tenant(row, tenant.subnets.clone()))
+    }
+
+    #[instrument(skip(self))]
+    async fn delete(&self, id: i64) -> Result<(), DomainError> {
+        let result = sqlx::query("DELETE FROM tenants WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                if e.to_string().contains("FOREIGN KEY constraint failed") {
+                    DomainError::InvalidTenant(format!("Tenant {} still owns groups", id))
+                } else {
+                    Self::db_error(e, "Failed to delete tenant")
+                }
+            })?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::TenantNotFound(id));
+        }
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn assign_group(&self, tenant_id: i64, group_id: i64) -> Result<(), DomainError> {
+        let result = sqlx::query("UPDATE groups SET tenant_id = ? WHERE id = ?")
+            .bind(tenant_id)
+            .bind(group_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| Self::db_error(e, "Failed to assign group to tenant"))?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::GroupNotFound(group_id));
+        }
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn get_group_ids(&self, tenant_id: i64) -> Result<Vec<i64>, DomainError> {
+        let rows: Vec<(i64,)> =
+            sqlx::query_as("SELECT id FROM groups WHERE tenant_id = ? ORDER BY id")
+                .bind(tenant_id)
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| Self::db_error(e, "Failed to query tenant groups"))?;
+
+        Ok(rows.into_iter().map(|(id,)| id).collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn get_stats(
+        &self,
+        tenant_id: i64,
+        period_hours: f32,
+    ) -> Result<TenantStats, DomainError> {
+        let cutoff = hours_ago_cutoff(period_hours);
+        let logs = query_log_source(&self.pool, &cutoff, None).await?;
+
+        let (queries_total, queries_blocked, unique_clients): (i64, i64, i64) =
+            sqlx::query_as(&format!(
+                "SELECT COUNT(*),
+                        COALESCE(SUM(CASE WHEN q.blocked = 1 THEN 1 ELSE 0 END), 0),
+                        COUNT(DISTINCT q.client_ip)
+                 FROM {logs} q
+                 WHERE q.tenant_id = ? AND q.created_at >= ?"
+            ))
+            .bind(tenant_id)
+            .bind(&cutoff)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| Self::db_error(e, "Failed to query tenant stats"))?;
+
+        let (groups, local_records): (i64, i64) = sqlx::query_as(
+            "SELECT (SELECT COUNT(*) FROM groups WHERE tenant_id = ?),
+                    (SELECT COUNT(*) FROM local_records WHERE tenant_id = ?)",
+        )
+        .bind(tenant_id)
+        .bind(tenant_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| Self::db_error(e, "Failed to count tenant data"))?;
+
+        Ok(TenantStats {
+            queries_total: queries_total as u64,
+            queries_blocked: queries_blocked as u64,
+            unique_clients: unique_clients as u64,
+            groups: groups as u64,
+            local_records: local_records as u64,
+        })
+    }
+}
@@ -0,0 +1,106 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::TldPolicyRepository;
+use ferrous_dns_domain::{DomainError, TldFilterMode, TldPolicy};
+use sqlx::SqlitePool;
+use tracing::warn;
+
+type PolicyRow = (i64, i64, String, String, String, String);
+
+pub struct SqliteTldPolicyRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteTldPolicyRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_policy(
+        (id, group_id, mode, tlds, created_at, updated_at): PolicyRow,
+    ) -> Option<TldPolicy> {
+        let mode = mode
+            .parse::<TldFilterMode>()
+            .map_err(|_| {
+                warn!(group_id, mode = %mode, "Unrecognised TLD filter mode, skipping row");
+            })
+            .ok()?;
+        Some(TldPolicy {
+            id: Some(id),
+            group_id,
+            mode,
+            tlds: tlds
+                .split(',')
+                .map(str::trim)
+                .filter(|t| !t.is_empty())
+                .map(str::to_string)
+                .collect(),
+            created_at: Some(created_at),
+            updated_at: Some(updated_at),
+        })
+    }
+}
+
+#[async_trait]
+impl TldPolicyRepository for SqliteTldPolicyRepository {
+    async fn get_all(&self) -> Result<Vec<TldPolicy>, DomainError> {
+        let rows = sqlx::query_as::<_, PolicyRow>(
+            "SELECT id, group_id, mode, tlds, created_at, updated_at
+             FROM group_tld_policies
+             ORDER BY group_id",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().filter_map(Self::row_to_policy).collect())
+    }
+
+    async fn get_by_group(&self, group_id: i64) -> Result<Option<TldPolicy>, DomainError> {
+        let row = sqlx::query_as::<_, PolicyRow>(
+            "SELECT id, group_id, mode, tlds, created_at, updated_at
+             FROM group_tld_policies
+             WHERE group_id = ?",
+        )
+        .bind(group_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(row.and_then(Self::row_to_policy))
+    }
+
+    async fn upsert(&self, policy: &TldPolicy) -> Result<TldPolicy, DomainError> {
+        let now = chrono::Utc::now().to_rfc3339();
+        let tlds = policy.tlds.join(",");
+
+        let row = sqlx::query_as::<_, PolicyRow>(
+            "INSERT INTO group_tld_policies (group_id, mode, tlds, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?)
+             ON CONFLICT(group_id) DO UPDATE SET
+               mode       = excluded.mode,
+               tlds       = excluded.tlds,
+               updated_at = excluded.updated_at
+             RETURNING id, group_id, mode, tlds, created_at, updated_at",
+        )
+        .bind(policy.group_id)
+        .bind(policy.mode.to_str())
+        .bind(&tlds)
+        .bind(&now)
+        .bind(&now)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Self::row_to_policy(row)
+            .ok_or_else(|| DomainError::DatabaseError("Invalid TLD policy row".into()))
+    }
+
+    async fn delete_by_group(&self, group_id: i64) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM group_tld_policies WHERE group_id = ?")
+            .bind(group_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,215 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::UnblockRequestRepository;
+use ferrous_dns_domain::{DomainError, UnblockRequest, UnblockRequestStatus};
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use tracing::{error, instrument, warn};
+
+const REQUEST_COLUMNS: &str = "id, domain, client_ip, group_id, block_source, message, status, \
+                               managed_domain_id, created_at, reviewed_at";
+
+#[derive(sqlx::FromRow)]
+struct UnblockRequestRow {
+    id: i64,
+    domain: String,
+    client_ip: String,
+    group_id: Option<i64>,
+    block_source: Option<String>,
+    message: Option<String>,
+    status: String,
+    managed_domain_id: Option<i64>,
+    created_at: Option<String>,
+    reviewed_at: Option<String>,
+}
+
+pub struct SqliteUnblockRequestRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteUnblockRequestRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_request(row: UnblockRequestRow) -> UnblockRequest {
+        let status = row.status.parse().unwrap_or_else(|_| {
+            warn!(request_id = row.id, status = %row.status, "Unknown unblock request status");
+            UnblockRequestStatus::Pending
+        });
+        UnblockRequest {
+            id: Some(row.id),
+            domain: Arc::from(row.domain.as_str()),
+            client_ip: Arc::from(row.client_ip.as_str()),
+            group_id: row.group_id,
+            block_source: row.block_source.map(|s| Arc::from(s.as_str())),
+            message: row.message.map(|s| Arc::from(s.as_str())),
+            status,
+            managed_domain_id: row.managed_domain_id,
+            created_at: row.created_at,
+            reviewed_at: row.reviewed_at,
+        }
+    }
+
+    async fn find(&self, domain: &str, client_ip: &str) -> Result<UnblockRequest, DomainError> {
+        let sql = format!(
+            "SELECT {REQUEST_COLUMNS} FROM pending_requests WHERE domain = ? AND client_ip = ?"
+        );
+        let row: UnblockRequestRow = sqlx::query_as(&sql)
+            .bind(domain)
+            .bind(client_ip)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to query unblock request by domain");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        Ok(Self::row_to_request(row))
+    }
+}
+
+#[async_trait]
+impl UnblockRequestRepository for SqliteUnblockRequestRepository {
+    #[instrument(skip(self))]
+    async fn upsert(&self, request: &UnblockRequest) -> Result<UnblockRequest, DomainError> {
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        let sql = format!(
+            "INSERT INTO pending_requests
+                 (domain, client_ip, group_id, block_source, message, created_at)
+             VALUES (?, ?, ?, ?, ?, ?)
+             ON CONFLICT (domain, client_ip) DO UPDATE SET
+                 group_id = excluded.group_id,
+                 block_source = excluded.block_source,
+                 message = excluded.message,
+                 created_at = excluded.created_at
+             WHERE pending_requests.status = 'pending'
+             RETURNING {REQUEST_COLUMNS}"
+        );
+        let row: Option<UnblockRequestRow> = sqlx::query_as(&sql)
+            .bind(request.domain.as_ref())
+            .bind(request.client_ip.as_ref())
+            .bind(request.group_id)
+            .bind(request.block_source.as_deref())
+            .bind(request.message.as_deref())
+            .bind(&now)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to store unblock request");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        match row {
+            Some(row) => Ok(Self::row_to_request(row)),
+            None => self.find(&request.domain, &request.client_ip).await,
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn get_by_id(&self, id: i64) -> Result<Option<UnblockRequest>, DomainError> {
+        let sql = format!("SELECT {REQUEST_COLUMNS} FROM pending_requests WHERE id = ?");
+        let row: Option<UnblockRequestRow> = sqlx::query_as(&sql)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to query unblock request by id");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        Ok(row.map(Self::row_to_request))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_all(
+        &self,
+        status: Option<UnblockRequestStatus>,
+    ) -> Result<Vec<UnblockRequest>, DomainError> {
+        let sql = format!(
+            "SELECT {REQUEST_COLUMNS} FROM pending_requests
+             WHERE ? IS NULL OR status = ?
+             ORDER BY created_at DESC, id DESC"
+        );
+        let status = status.map(|s| s.as_str());
+        let rows: Vec<UnblockRequestRow> = sqlx::query_as(&sql)
+            .bind(status)
+            .bind(status)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to query unblock requests");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        Ok(rows.into_iter().map(Self::row_to_request).collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn count_pending(&self) -> Result<u64, DomainError> {
+        let count: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM pending_requests WHERE status = 'pending'")
+                .fetch_one(&self.pool)
+                .await
+                .map_err(|e| {
+                    error!(error = %e, "Failed to count pending unblock requests");
+                    DomainError::DatabaseError(e.to_string())
+                })?;
+
+        Ok(count as u64)
+    }
+
+    #[instrument(skip(self))]
+    async fn review(
+        &self,
+        id: i64,
+        status: UnblockRequestStatus,
+        managed_domain_id: Option<i64>,
+    ) -> Result<UnblockRequest, DomainError> {
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        let sql = format!(
+            "UPDATE pending_requests
+             SET status = ?, managed_domain_id = ?, reviewed_at = ?
+             WHERE id = ? AND status = 'pending'
+             RETURNING {REQUEST_COLUMNS}"
+        );
+        let row: Option<UnblockRequestRow> = sqlx::query_as(&sql)
+            .bind(status.as_str())
+            .bind(managed_domain_id)
+            .bind(&now)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to review unblock request");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        match row {
+            Some(row) => Ok(Self::row_to_request(row)),
+            None if self.get_by_id(id).await?.is_some() => {
+                Err(DomainError::UnblockRequestAlreadyReviewed(id))
+            }
+            None => Err(DomainError::UnblockRequestNotFound(id)),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn delete(&self, id: i64) -> Result<(), DomainError> {
+        let result = sqlx::query("DELETE FROM pending_requests WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to delete unblock request");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::UnblockRequestNotFound(id));
+        }
+
+        Ok(())
+    }
+}
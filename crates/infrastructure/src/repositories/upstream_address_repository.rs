@@ -0,0 +1,70 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::UpstreamAddressRepository;
+use ferrous_dns_domain::DomainError;
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use tracing::{error, instrument};
+
+pub struct SqliteUpstreamAddressRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteUpstreamAddressRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl UpstreamAddressRepository for SqliteUpstreamAddressRepository {
+    #[instrument(skip(self))]
+    async fn get_all(&self) -> Result<HashMap<String, Vec<IpAddr>>, DomainError> {
+        let rows = sqlx::query_as::<_, (String, String)>(
+            "SELECT hostname, addresses FROM upstream_addresses",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to load upstream addresses");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(hostname, addresses)| {
+                let addrs = addresses
+                    .split(',')
+                    .filter_map(|a| a.trim().parse().ok())
+                    .collect();
+                (hostname, addrs)
+            })
+            .collect())
+    }
+
+    #[instrument(skip(self, addrs))]
+    async fn save(&self, hostname: &str, addrs: &[IpAddr]) -> Result<(), DomainError> {
+        let addresses = addrs
+            .iter()
+            .map(IpAddr::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+        sqlx::query(
+            "INSERT INTO upstream_addresses (hostname, addresses, updated_at)
+             VALUES (?, ?, ?)
+             ON CONFLICT(hostname) DO UPDATE SET
+               addresses  = excluded.addresses,
+               updated_at = excluded.updated_at",
+        )
+        .bind(hostname)
+        .bind(addresses)
+        .bind(chrono::Utc::now().to_rfc3339())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, hostname, "Failed to save upstream addresses");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+        Ok(())
+    }
+}
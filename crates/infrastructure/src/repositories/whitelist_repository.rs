@@ -1,7 +1,8 @@
+use super::paging::order_by;
 use async_trait::async_trait;
 use dashmap::DashSet;
 use ferrous_dns_application::ports::WhitelistRepository;
-use ferrous_dns_domain::{whitelist::WhitelistedDomain, DomainError};
+use ferrous_dns_domain::{whitelist::WhitelistedDomain, DomainError, PageRequest};
 use rustc_hash::FxBuildHasher;
 use sqlx::{Row, SqlitePool};
 use std::sync::Arc;
@@ -18,7 +19,7 @@ impl SqliteWhitelistRepository {
         let rows = sqlx::query("SELECT domain FROM whitelist")
             .fetch_all(&pool)
             .await
-            .map_err(|e| DomainError::InvalidDomainName(format!("Database error: {}", e)))?;
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
         for row in &rows {
             whitelisted_domains.insert(row.get("domain"));
         }
@@ -42,7 +43,7 @@ impl WhitelistRepository for SqliteWhitelistRepository {
     async fn get_all(&self) -> Result<Vec<WhitelistedDomain>, DomainError> {
         let rows = sqlx::query("SELECT id, domain, datetime(added_at) as added_at FROM whitelist ORDER BY added_at DESC")
             .fetch_all(&self.pool).await
-            .map_err(|e| DomainError::InvalidDomainName(format!("Database error: {}", e)))?;
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
         Ok(rows
             .into_iter()
             .map(|row| WhitelistedDomain {
@@ -53,23 +54,88 @@ impl WhitelistRepository for SqliteWhitelistRepository {
             .collect())
     }
 
+    async fn get_all_paged(
+        &self,
+        page: &PageRequest,
+    ) -> Result<(Vec<WhitelistedDomain>, u64), DomainError> {
+        let count_row = sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM whitelist")
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        let total = count_row.0 as u64;
+
+        let sql = format!(
+            "SELECT id, domain, datetime(added_at) as added_at FROM whitelist {} LIMIT ? OFFSET ?",
+            order_by(page, "added_at DESC")
+        );
+        let rows = sqlx::query(&sql)
+            .bind(page.limit as i64)
+            .bind(page.offset as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let domains = rows
+            .into_iter()
+            .map(|row| WhitelistedDomain {
+                id: Some(row.get("id")),
+                domain: row.get("domain"),
+                added_at: Some(row.get("added_at")),
+            })
+            .collect();
+
+        Ok((domains, total))
+    }
+
     async fn add_domain(&self, domain: &WhitelistedDomain) -> Result<(), DomainError> {
         sqlx::query("INSERT INTO whitelist (domain) VALUES (?)")
             .bind(&domain.domain)
             .execute(&self.pool)
             .await
-            .map_err(|e| DomainError::InvalidDomainName(format!("Database error: {}", e)))?;
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
         self.whitelisted_domains.insert(domain.domain.clone());
         debug!(domain = %domain.domain, "Domain added to whitelist");
         Ok(())
     }
 
+    async fn add_domains(&self, domains: &[String]) -> Result<Vec<bool>, DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let mut added = Vec::with_capacity(domains.len());
+        for domain in domains {
+            let result = sqlx::query("INSERT OR IGNORE INTO whitelist (domain) VALUES (?)")
+                .bind(domain)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+            added.push(result.rows_affected() > 0);
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        for (domain, _) in domains.iter().zip(&added).filter(|(_, added)| **added) {
+            self.whitelisted_domains.insert(domain.clone());
+        }
+        debug!(
+            requested = domains.len(),
+            added = added.iter().filter(|a| **a).count(),
+            "Domains bulk-added to whitelist"
+        );
+        Ok(added)
+    }
+
     async fn remove_domain(&self, domain: &str) -> Result<(), DomainError> {
         sqlx::query("DELETE FROM whitelist WHERE domain = ?")
             .bind(domain)
             .execute(&self.pool)
             .await
-            .map_err(|e| DomainError::InvalidDomainName(format!("Database error: {}", e)))?;
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
         self.whitelisted_domains.remove(domain);
         debug!(domain = %domain, "Domain removed from whitelist");
         Ok(())
@@ -1,6 +1,7 @@
+use super::paging::order_by;
 use async_trait::async_trait;
 use ferrous_dns_application::ports::WhitelistSourceRepository;
-use ferrous_dns_domain::{DomainError, WhitelistSource};
+use ferrous_dns_domain::{DomainError, PageRequest, WhitelistSource};
 use sqlx::{Row, SqlitePool};
 use std::sync::Arc;
 use tracing::{error, instrument};
@@ -166,6 +167,42 @@ impl WhitelistSourceRepository for SqliteWhitelistSourceRepository {
         Ok(sources)
     }
 
+    #[instrument(skip(self))]
+    async fn get_page(
+        &self,
+        page: &PageRequest,
+    ) -> Result<(Vec<WhitelistSource>, u64), DomainError> {
+        let (total,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM whitelist_sources")
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to count whitelist sources");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        let sql = format!(
+            "SELECT id, name, url, comment, enabled, created_at, updated_at
+             FROM whitelist_sources {} LIMIT ? OFFSET ?",
+            order_by(page, "name ASC")
+        );
+        let rows = sqlx::query_as::<_, WhitelistSourceRow>(&sql)
+            .bind(page.limit as i64)
+            .bind(page.offset as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to query page of whitelist sources");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        let mut sources = Vec::with_capacity(rows.len());
+        for row in rows {
+            let group_ids = self.fetch_group_ids(row.0).await?;
+            sources.push(Self::row_to_source(row, group_ids));
+        }
+        Ok((sources, total as u64))
+    }
+
     #[instrument(skip(self))]
     async fn update(
         &self,
@@ -0,0 +1,3 @@
+mod primary_client;
+
+pub use primary_client::HttpPrimaryClient;
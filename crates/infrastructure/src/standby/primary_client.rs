@@ -0,0 +1,86 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{CacheExportEntry, PrimaryInstanceClient};
+use ferrous_dns_domain::{DomainError, StandbyConfig};
+use reqwest::StatusCode;
+use std::time::Duration;
+
+/// Pulls a primary's state from its `/api/backup` and `/api/standby/*`
+/// endpoints.
+pub struct HttpPrimaryClient {
+    client: reqwest::Client,
+    base_url: String,
+    api_token: Option<String>,
+}
+
+impl HttpPrimaryClient {
+    pub fn new(config: &StandbyConfig) -> Result<Self, DomainError> {
+        let client = reqwest::Client::builder()
+            .user_agent(concat!("ferrous-dns/", env!("CARGO_PKG_VERSION")))
+            .timeout(Duration::from_secs(60))
+            .danger_accept_invalid_certs(config.insecure_skip_tls_verify)
+            .build()
+            .map_err(sync_error)?;
+        Ok(Self {
+            client,
+            base_url: config.normalized_primary_url().to_string(),
+            api_token: config.api_token.clone().filter(|t| !t.is_empty()),
+        })
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    async fn get(&self, path: &str) -> Result<reqwest::Response, DomainError> {
+        let mut request = self.client.get(format!("{}{path}", self.base_url));
+        if let Some(token) = &self.api_token {
+            request = request.header("X-Api-Key", token);
+        }
+        request
+            .send()
+            .await
+            .map_err(|e| sync_error(format!("GET {path}: {e}")))
+    }
+}
+
+fn sync_error(e: impl std::fmt::Display) -> DomainError {
+    DomainError::StandbySyncFailed(e.to_string())
+}
+
+/// The body of a successful response.
+async fn body(path: &str, response: reqwest::Response) -> Result<Vec<u8>, DomainError> {
+    if !response.status().is_success() {
+        return Err(sync_error(format!(
+            "GET {path}: HTTP {}",
+            response.status()
+        )));
+    }
+    let body = response
+        .bytes()
+        .await
+        .map_err(|e| sync_error(format!("GET {path}: {e}")))?;
+    Ok(body.to_vec())
+}
+
+#[async_trait]
+impl PrimaryInstanceClient for HttpPrimaryClient {
+    async fn fetch_backup(&self) -> Result<Vec<u8>, DomainError> {
+        let path = "/api/backup?format=json";
+        body(path, self.get(path).await?).await
+    }
+
+    async fn fetch_block_lists(&self) -> Result<Option<Vec<u8>>, DomainError> {
+        let path = "/api/standby/block-lists";
+        let response = self.get(path).await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        body(path, response).await.map(Some)
+    }
+
+    async fn fetch_cache(&self, limit: usize) -> Result<Vec<CacheExportEntry>, DomainError> {
+        let path = format!("/api/standby/cache?limit={limit}");
+        let body = body(&path, self.get(&path).await?).await?;
+        serde_json::from_slice(&body).map_err(|e| sync_error(format!("GET {path}: {e}")))
+    }
+}
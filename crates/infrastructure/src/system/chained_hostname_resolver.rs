@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::HostnameResolver;
+use ferrous_dns_domain::DomainError;
+use std::net::IpAddr;
+use std::sync::Arc;
+use tracing::debug;
+
+/// Tries each resolver in order and returns the first hostname found.
+/// Errors from one resolver fall through to the next.
+pub struct ChainedHostnameResolver {
+    resolvers: Vec<Arc<dyn HostnameResolver>>,
+}
+
+impl ChainedHostnameResolver {
+    pub fn new(resolvers: Vec<Arc<dyn HostnameResolver>>) -> Self {
+        Self { resolvers }
+    }
+}
+
+#[async_trait]
+impl HostnameResolver for ChainedHostnameResolver {
+    async fn resolve_hostname(&self, ip: IpAddr) -> Result<Option<String>, DomainError> {
+        for resolver in &self.resolvers {
+            match resolver.resolve_hostname(ip).await {
+                Ok(Some(hostname)) if !hostname.is_empty() => return Ok(Some(hostname)),
+                Ok(_) => {}
+                Err(e) => debug!(ip = %ip, error = %e, "Hostname resolver failed, trying next"),
+            }
+        }
+        Ok(None)
+    }
+}
@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::HostnameResolver;
+use ferrous_dns_domain::DomainError;
+use hickory_proto::op::{Message, MessageType, OpCode, Query};
+use hickory_proto::rr::{Name, RData, RecordType};
+use std::net::{IpAddr, SocketAddr};
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::net::UdpSocket;
+use tracing::debug;
+
+use super::PtrHostnameResolver;
+
+const LLMNR_PORT: u16 = 5355;
+
+/// Resolves hostnames with a unicast LLMNR (RFC 4795) reverse query sent to
+/// the client itself; Windows hosts answer for their own addresses.
+pub struct LlmnrHostnameResolver {
+    timeout: Duration,
+}
+
+impl LlmnrHostnameResolver {
+    pub fn new(timeout_ms: u64) -> Self {
+        Self {
+            timeout: Duration::from_millis(timeout_ms),
+        }
+    }
+
+    pub fn build_reverse_query(ip: &IpAddr, id: u16) -> Result<Vec<u8>, DomainError> {
+        let reverse_domain = PtrHostnameResolver::ip_to_reverse_domain(ip);
+        let name = Name::from_str(&format!("{reverse_domain}."))
+            .map_err(|e| DomainError::InvalidDomainName(e.to_string()))?;
+
+        let mut message = Message::new(id, MessageType::Query, OpCode::Query);
+        message.add_query(Query::query(name, RecordType::PTR));
+        message
+            .to_vec()
+            .map_err(|e| DomainError::IoError(format!("Failed to encode LLMNR query: {}", e)))
+    }
+
+    pub fn parse_reverse_response(response: &[u8], id: u16) -> Option<String> {
+        let message = Message::from_vec(response).ok()?;
+        if message.id() != id || message.message_type() != MessageType::Response {
+            return None;
+        }
+        message
+            .answers()
+            .iter()
+            .find_map(|record| match record.data() {
+                RData::PTR(ptr) => {
+                    let hostname = ptr.to_utf8();
+                    let hostname = hostname.trim_end_matches('.');
+                    (!hostname.is_empty()).then(|| hostname.to_string())
+                }
+                _ => None,
+            })
+    }
+}
+
+#[async_trait]
+impl HostnameResolver for LlmnrHostnameResolver {
+    async fn resolve_hostname(&self, ip: IpAddr) -> Result<Option<String>, DomainError> {
+        let bind_addr = if ip.is_ipv4() { "0.0.0.0:0" } else { "[::]:0" };
+        let socket = UdpSocket::bind(bind_addr)
+            .await
+            .map_err(|e| DomainError::IoError(format!("LLMNR socket bind failed: {}", e)))?;
+
+        let id = fastrand::u16(..);
+        let query = Self::build_reverse_query(&ip, id)?;
+
+        if let Err(e) = socket
+            .send_to(&query, SocketAddr::new(ip, LLMNR_PORT))
+            .await
+        {
+            debug!(ip = %ip, error = %e, "LLMNR query failed");
+            return Ok(None);
+        }
+
+        let mut buf = [0u8; 1500];
+        let len = match tokio::time::timeout(self.timeout, socket.recv_from(&mut buf)).await {
+            Ok(Ok((len, from))) if from.ip() == ip => len,
+            _ => {
+                debug!(ip = %ip, "LLMNR query got no response");
+                return Ok(None);
+            }
+        };
+
+        let hostname = Self::parse_reverse_response(&buf[..len], id);
+        if let Some(ref name) = hostname {
+            debug!(ip = %ip, hostname = %name, "LLMNR lookup successful");
+        }
+        Ok(hostname)
+    }
+}
@@ -1,5 +1,15 @@
 pub mod arp_reader;
+pub mod chained_hostname_resolver;
 pub mod hostname_resolver;
+pub mod llmnr_resolver;
+pub mod netbios_resolver;
+pub mod network_interfaces;
+pub mod process_metrics;
 
 pub use arp_reader::LinuxArpReader;
+pub use chained_hostname_resolver::ChainedHostnameResolver;
 pub use hostname_resolver::PtrHostnameResolver;
+pub use llmnr_resolver::LlmnrHostnameResolver;
+pub use netbios_resolver::NetbiosHostnameResolver;
+pub use network_interfaces::SystemNetworkInterfaces;
+pub use process_metrics::ProcessMetricsCollector;
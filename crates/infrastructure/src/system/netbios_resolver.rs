@@ -0,0 +1,134 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::HostnameResolver;
+use ferrous_dns_domain::DomainError;
+use std::net::{IpAddr, SocketAddr};
+use std::time::Duration;
+use tokio::net::UdpSocket;
+use tracing::debug;
+
+const NETBIOS_NS_PORT: u16 = 137;
+const NBSTAT_TYPE: u16 = 0x0021;
+const IN_CLASS: u16 = 0x0001;
+const WORKSTATION_SUFFIX: u8 = 0x00;
+const GROUP_NAME_FLAG: u16 = 0x8000;
+const NAME_ENTRY_LEN: usize = 18;
+
+/// Resolves hostnames with a NetBIOS Node Status (NBSTAT) query sent
+/// directly to the client, which Windows machines and many NAS/IoT devices
+/// answer even when no PTR record exists.
+pub struct NetbiosHostnameResolver {
+    timeout: Duration,
+}
+
+impl NetbiosHostnameResolver {
+    pub fn new(timeout_ms: u64) -> Self {
+        Self {
+            timeout: Duration::from_millis(timeout_ms),
+        }
+    }
+
+    /// Builds an RFC 1002 node status request for the wildcard name `*`.
+    pub fn build_node_status_query(transaction_id: u16) -> Vec<u8> {
+        let mut packet = Vec::with_capacity(50);
+        packet.extend_from_slice(&transaction_id.to_be_bytes());
+        packet.extend_from_slice(&[0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+
+        let mut raw_name = [0u8; 16];
+        raw_name[0] = b'*';
+        packet.push(32);
+        for byte in raw_name {
+            packet.push(b'A' + (byte >> 4));
+            packet.push(b'A' + (byte & 0x0f));
+        }
+        packet.push(0x00);
+
+        packet.extend_from_slice(&NBSTAT_TYPE.to_be_bytes());
+        packet.extend_from_slice(&IN_CLASS.to_be_bytes());
+        packet
+    }
+
+    /// Extracts the unique workstation name (suffix `0x00`) from a node
+    /// status response.
+    pub fn parse_node_status_response(response: &[u8]) -> Option<String> {
+        let mut pos = 12;
+        loop {
+            let len = *response.get(pos)? as usize;
+            if len == 0 {
+                pos += 1;
+                break;
+            }
+            if len & 0xc0 == 0xc0 {
+                pos += 2;
+                break;
+            }
+            pos += len + 1;
+        }
+
+        let rr_type = u16::from_be_bytes([*response.get(pos)?, *response.get(pos + 1)?]);
+        if rr_type != NBSTAT_TYPE {
+            return None;
+        }
+        pos += 10;
+
+        let num_names = *response.get(pos)? as usize;
+        pos += 1;
+
+        for _ in 0..num_names {
+            let entry = response.get(pos..pos + NAME_ENTRY_LEN)?;
+            pos += NAME_ENTRY_LEN;
+
+            let flags = u16::from_be_bytes([entry[16], entry[17]]);
+            if entry[15] != WORKSTATION_SUFFIX || flags & GROUP_NAME_FLAG != 0 {
+                continue;
+            }
+
+            let name = String::from_utf8_lossy(&entry[..15]);
+            let name = name.trim_end_matches([' ', '\0']);
+            if !name.is_empty() {
+                return Some(name.to_ascii_lowercase());
+            }
+        }
+        None
+    }
+}
+
+#[async_trait]
+impl HostnameResolver for NetbiosHostnameResolver {
+    async fn resolve_hostname(&self, ip: IpAddr) -> Result<Option<String>, DomainError> {
+        if !ip.is_ipv4() {
+            return Ok(None);
+        }
+
+        let socket = UdpSocket::bind("0.0.0.0:0")
+            .await
+            .map_err(|e| DomainError::IoError(format!("NetBIOS socket bind failed: {}", e)))?;
+
+        let transaction_id = fastrand::u16(..);
+        let query = Self::build_node_status_query(transaction_id);
+        let target = SocketAddr::new(ip, NETBIOS_NS_PORT);
+
+        if let Err(e) = socket.send_to(&query, target).await {
+            debug!(ip = %ip, error = %e, "NetBIOS node status query failed");
+            return Ok(None);
+        }
+
+        let mut buf = [0u8; 1024];
+        let len = match tokio::time::timeout(self.timeout, socket.recv_from(&mut buf)).await {
+            Ok(Ok((len, from))) if from.ip() == ip => len,
+            _ => {
+                debug!(ip = %ip, "NetBIOS node status query got no response");
+                return Ok(None);
+            }
+        };
+
+        if len < 2 || buf[..2] != transaction_id.to_be_bytes() {
+            return Ok(None);
+        }
+
+        let hostname = Self::parse_node_status_response(&buf[..len]);
+        if let Some(ref name) = hostname {
+            debug!(ip = %ip, hostname = %name, "NetBIOS lookup successful");
+        }
+        Ok(hostname)
+    }
+}
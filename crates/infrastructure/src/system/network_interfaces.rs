@@ -0,0 +1,63 @@
+use ferrous_dns_application::ports::{NetworkInterface, NetworkInterfacePort};
+use std::ffi::CStr;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use tracing::warn;
+
+/// Reads interface addresses with `getifaddrs(3)`. Interfaces that are down
+/// and IPv6 link-local addresses (unusable without a scope id) are left out.
+pub struct SystemNetworkInterfaces;
+
+impl NetworkInterfacePort for SystemNetworkInterfaces {
+    fn list_interfaces(&self) -> Vec<NetworkInterface> {
+        let mut head: *mut libc::ifaddrs = std::ptr::null_mut();
+        if unsafe { libc::getifaddrs(&mut head) } != 0 {
+            warn!(
+                error = %std::io::Error::last_os_error(),
+                "Failed to list network interfaces"
+            );
+            return Vec::new();
+        }
+
+        let mut interfaces = Vec::new();
+        let mut cursor = head;
+        while !cursor.is_null() {
+            // SAFETY: `cursor` walks the list returned by getifaddrs, which
+            // stays valid until freeifaddrs below.
+            let entry = unsafe { &*cursor };
+            cursor = entry.ifa_next;
+
+            if entry.ifa_flags & libc::IFF_UP as libc::c_uint == 0 || entry.ifa_addr.is_null() {
+                continue;
+            }
+            let Some(address) = (unsafe { socket_ip(entry.ifa_addr) }) else {
+                continue;
+            };
+            if matches!(address, IpAddr::V6(v6) if v6.segments()[0] & 0xffc0 == 0xfe80) {
+                continue;
+            }
+            let name = unsafe { CStr::from_ptr(entry.ifa_name) }
+                .to_string_lossy()
+                .into_owned();
+            interfaces.push(NetworkInterface { name, address });
+        }
+        unsafe { libc::freeifaddrs(head) };
+
+        interfaces
+    }
+}
+
+/// # Safety
+/// `addr` must point to a valid `sockaddr` of the size its family implies.
+unsafe fn socket_ip(addr: *const libc::sockaddr) -> Option<IpAddr> {
+    match (*addr).sa_family as libc::c_int {
+        libc::AF_INET => {
+            let v4 = &*(addr as *const libc::sockaddr_in);
+            Some(IpAddr::V4(Ipv4Addr::from(u32::from_be(v4.sin_addr.s_addr))))
+        }
+        libc::AF_INET6 => {
+            let v6 = &*(addr as *const libc::sockaddr_in6);
+            Some(IpAddr::V6(Ipv6Addr::from(v6.sin6_addr.s6_addr)))
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,77 @@
+use ferrous_dns_application::ports::{
+    ChannelDepthSource, ProcessMetrics, SystemMetricsPort, UdpPoolMetricsSource,
+};
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::runtime::Handle;
+
+const PROC_SELF_STATUS: &str = "/proc/self/status";
+const PROC_SELF_FD: &str = "/proc/self/fd";
+
+/// Reads resource usage of the running process from procfs and the tokio
+/// runtime it was created on.
+pub struct ProcessMetricsCollector {
+    started_at: Instant,
+    runtime: Option<Handle>,
+    channels: Vec<Arc<dyn ChannelDepthSource>>,
+    udp_pool: Option<Arc<dyn UdpPoolMetricsSource>>,
+}
+
+impl ProcessMetricsCollector {
+    /// Uptime is measured from this call; create the collector at startup.
+    pub fn new() -> Self {
+        Self {
+            started_at: Instant::now(),
+            runtime: Handle::try_current().ok(),
+            channels: Vec::new(),
+            udp_pool: None,
+        }
+    }
+
+    pub fn with_channel(mut self, channel: Arc<dyn ChannelDepthSource>) -> Self {
+        self.channels.push(channel);
+        self
+    }
+
+    pub fn with_udp_pool(mut self, pool: Arc<dyn UdpPoolMetricsSource>) -> Self {
+        self.udp_pool = Some(pool);
+        self
+    }
+}
+
+impl Default for ProcessMetricsCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SystemMetricsPort for ProcessMetricsCollector {
+    fn process_metrics(&self) -> ProcessMetrics {
+        let runtime = self.runtime.as_ref().map(Handle::metrics);
+        ProcessMetrics {
+            rss_bytes: std::fs::read_to_string(PROC_SELF_STATUS)
+                .ok()
+                .and_then(|raw| parse_vm_rss(&raw)),
+            open_fds: std::fs::read_dir(PROC_SELF_FD)
+                .ok()
+                .map(|entries| entries.count() as u64),
+            runtime_workers: runtime.as_ref().map(|m| m.num_workers()),
+            alive_tasks: runtime.as_ref().map(|m| m.num_alive_tasks()),
+            global_queue_depth: runtime.as_ref().map(|m| m.global_queue_depth()),
+            channels: self
+                .channels
+                .iter()
+                .filter_map(|channel| channel.channel_depth())
+                .collect(),
+            udp_pool: self.udp_pool.as_ref().map(|pool| pool.udp_pool_metrics()),
+            uptime_secs: self.started_at.elapsed().as_secs(),
+        }
+    }
+}
+
+/// Parses the `VmRSS:   123456 kB` line of `/proc/self/status` into bytes.
+fn parse_vm_rss(raw: &str) -> Option<u64> {
+    let line = raw.lines().find(|line| line.starts_with("VmRSS:"))?;
+    let kb = line.split_whitespace().nth(1)?.parse::<u64>().ok()?;
+    Some(kb * 1024)
+}
@@ -0,0 +1,230 @@
+use ferrous_dns_application::ports::{LocalRecordRepository, LocalZonePort};
+use ferrous_dns_domain::{DomainError, LocalRecord, RecordType};
+use std::collections::HashMap;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+use tracing::{debug, warn};
+
+/// Path prefix the CA fetches HTTP-01 key authorizations from (RFC 8555 §8.3).
+pub const HTTP01_PATH_PREFIX: &str = "/.well-known/acme-challenge/";
+
+/// Label the CA queries for DNS-01 TXT records (RFC 8555 §8.4).
+const DNS01_LABEL: &str = "_acme-challenge";
+
+/// Short TTL so a stale challenge value does not outlive the order.
+const DNS01_TTL: u32 = 60;
+
+/// Largest request head read before answering 404.
+const MAX_REQUEST_LEN: usize = 4096;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// One pending authorization of an order.
+#[derive(Debug, Clone)]
+pub struct PendingChallenge {
+    /// Name being validated, as listed in the order (may start with `*.`).
+    pub domain: String,
+    pub token: String,
+    /// Body served for HTTP-01.
+    pub key_authorization: String,
+    /// Base64url SHA-256 of the key authorization, published for DNS-01.
+    pub dns_value: String,
+}
+
+/// How control of the certificate's names is proven to the CA.
+pub enum AcmeChallengeSolver {
+    /// Plain HTTP responder bound to `bind` while the order is pending.
+    Http01 {
+        bind: SocketAddr,
+    },
+    Dns01(Dns01Publisher),
+}
+
+/// What has to be undone once the order settles.
+pub enum PresentedChallenges {
+    Http01(Http01Responder),
+    Dns01(Vec<i64>),
+}
+
+impl AcmeChallengeSolver {
+    /// Makes every challenge answerable before the CA is told to check.
+    pub async fn present(
+        &self,
+        challenges: &[PendingChallenge],
+    ) -> Result<PresentedChallenges, DomainError> {
+        match self {
+            Self::Http01 { bind } => {
+                let tokens = challenges
+                    .iter()
+                    .map(|c| (c.token.clone(), c.key_authorization.clone()))
+                    .collect();
+                let responder = Http01Responder::start(*bind, tokens).await.map_err(|e| {
+                    DomainError::IoError(format!(
+                        "Failed to bind ACME HTTP-01 responder on {bind}: {e}"
+                    ))
+                })?;
+                Ok(PresentedChallenges::Http01(responder))
+            }
+            Self::Dns01(publisher) => publisher
+                .publish(challenges)
+                .await
+                .map(PresentedChallenges::Dns01),
+        }
+    }
+
+    pub async fn clean_up(&self, presented: PresentedChallenges) {
+        match presented {
+            PresentedChallenges::Http01(responder) => drop(responder),
+            PresentedChallenges::Dns01(ids) => {
+                if let Self::Dns01(publisher) = self {
+                    publisher.withdraw(&ids).await;
+                }
+            }
+        }
+    }
+}
+
+/// Answers `GET /.well-known/acme-challenge/<token>` with the token's key
+/// authorization and everything else with 404. Stops when dropped.
+pub struct Http01Responder {
+    addr: SocketAddr,
+    task: JoinHandle<()>,
+}
+
+impl Http01Responder {
+    pub async fn start(bind: SocketAddr, tokens: HashMap<String, String>) -> io::Result<Self> {
+        let listener = TcpListener::bind(bind).await?;
+        let addr = listener.local_addr()?;
+        let tokens = Arc::new(tokens);
+
+        let task = tokio::spawn(async move {
+            loop {
+                match listener.accept().await {
+                    Ok((stream, peer)) => {
+                        debug!(client = %peer, "ACME HTTP-01 request");
+                        tokio::spawn(serve_http01(stream, tokens.clone()));
+                    }
+                    Err(e) => warn!(error = %e, "ACME HTTP-01 accept error"),
+                }
+            }
+        });
+        Ok(Self { addr, task })
+    }
+
+    pub fn local_addr(&self) -> SocketAddr {
+        self.addr
+    }
+}
+
+impl Drop for Http01Responder {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn serve_http01(mut stream: TcpStream, tokens: Arc<HashMap<String, String>>) {
+    let mut buf = vec![0u8; MAX_REQUEST_LEN];
+    let mut len = 0;
+    let read_head = async {
+        while len < buf.len() {
+            match stream.read(&mut buf[len..]).await {
+                Ok(0) | Err(_) => break,
+                Ok(n) => len += n,
+            }
+            if buf[..len].windows(4).any(|w| w == b"\r\n\r\n") {
+                break;
+            }
+        }
+    };
+    if tokio::time::timeout(REQUEST_TIMEOUT, read_head)
+        .await
+        .is_err()
+    {
+        return;
+    }
+
+    let head = String::from_utf8_lossy(&buf[..len]);
+    let body = head
+        .lines()
+        .next()
+        .and_then(|line| line.strip_prefix("GET "))
+        .and_then(|rest| rest.split(' ').next())
+        .and_then(|path| path.strip_prefix(HTTP01_PATH_PREFIX))
+        .and_then(|token| tokens.get(token));
+
+    let response = match body {
+        Some(body) => format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/octet-stream\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        ),
+        None => "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string(),
+    };
+    let _ = stream.write_all(response.as_bytes()).await;
+    let _ = stream.shutdown().await;
+}
+
+/// Publishes DNS-01 TXT records as local records, so the CA's queries are
+/// answered by this server's own local zone.
+pub struct Dns01Publisher {
+    records: Arc<dyn LocalRecordRepository>,
+    zone: Arc<dyn LocalZonePort>,
+}
+
+impl Dns01Publisher {
+    pub fn new(records: Arc<dyn LocalRecordRepository>, zone: Arc<dyn LocalZonePort>) -> Self {
+        Self { records, zone }
+    }
+
+    /// Creates one `_acme-challenge` TXT record per challenge and returns
+    /// their ids. A wildcard shares the record name of its base domain.
+    pub async fn publish(&self, challenges: &[PendingChallenge]) -> Result<Vec<i64>, DomainError> {
+        let mut ids = Vec::with_capacity(challenges.len());
+        for challenge in challenges {
+            let domain = challenge
+                .domain
+                .strip_prefix("*.")
+                .unwrap_or(&challenge.domain);
+            let mut record = LocalRecord::new(
+                Arc::from(DNS01_LABEL),
+                RecordType::TXT,
+                Arc::from(challenge.dns_value.as_str()),
+            );
+            record.domain = Some(Arc::from(domain));
+            record.ttl = DNS01_TTL;
+
+            match self.records.create(&record).await {
+                Ok(created) => ids.extend(created.id),
+                Err(e) => {
+                    self.withdraw(&ids).await;
+                    return Err(e);
+                }
+            }
+        }
+        if let Err(e) = self.zone.reload().await {
+            self.withdraw(&ids).await;
+            return Err(e);
+        }
+        Ok(ids)
+    }
+
+    /// Deletes the records created by [`Self::publish`]. Failures are only
+    /// logged: a leftover TXT record is harmless.
+    pub async fn withdraw(&self, ids: &[i64]) {
+        if ids.is_empty() {
+            return;
+        }
+        for &id in ids {
+            if let Err(e) = self.records.delete(id).await {
+                warn!(id, error = %e, "Failed to delete ACME DNS-01 record");
+            }
+        }
+        if let Err(e) = self.zone.reload().await {
+            warn!(error = %e, "Failed to reload local zone after ACME DNS-01 cleanup");
+        }
+    }
+}
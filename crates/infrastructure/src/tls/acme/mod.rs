@@ -0,0 +1,284 @@
+mod challenge;
+
+pub use challenge::{
+    AcmeChallengeSolver, Dns01Publisher, Http01Responder, PendingChallenge, PresentedChallenges,
+    HTTP01_PATH_PREFIX,
+};
+
+use super::{ensure_parent_dir, ReloadableCertResolver};
+use ferrous_dns_application::ports::AcmeCertificatePort;
+use ferrous_dns_domain::{AcmeChallenge, AcmeConfig, DomainError};
+use instant_acme::{
+    Account, AccountCredentials, AuthorizationStatus, ChallengeType, Identifier, NewAccount,
+    NewOrder, Order, OrderStatus,
+};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tracing::{info, warn};
+
+/// Polls of an order before giving up on the CA.
+const MAX_POLLS: usize = 20;
+const FIRST_POLL_DELAY: Duration = Duration::from_millis(500);
+const MAX_POLL_DELAY: Duration = Duration::from_secs(10);
+
+const SECS_PER_DAY: u64 = 86_400;
+
+/// Obtains and renews the encrypted DNS certificate from an ACME CA
+/// (Let's Encrypt by default), writes it to the configured PEM paths and
+/// swaps it into the running TLS listeners.
+pub struct AcmeCertificateManager {
+    config: AcmeConfig,
+    cert_path: String,
+    key_path: String,
+    solver: AcmeChallengeSolver,
+    resolver: Option<Arc<ReloadableCertResolver>>,
+}
+
+impl AcmeCertificateManager {
+    pub fn new(
+        config: AcmeConfig,
+        cert_path: String,
+        key_path: String,
+        solver: AcmeChallengeSolver,
+    ) -> Self {
+        Self {
+            config,
+            cert_path,
+            key_path,
+            solver,
+            resolver: None,
+        }
+    }
+
+    /// Resolver to hand each new certificate to.
+    pub fn with_resolver(mut self, resolver: Arc<ReloadableCertResolver>) -> Self {
+        self.resolver = Some(resolver);
+        self
+    }
+
+    async fn account(&self) -> Result<Account, DomainError> {
+        let path = &self.config.account_path;
+        if let Ok(raw) = tokio::fs::read(path).await {
+            let credentials: AccountCredentials = serde_json::from_slice(&raw).map_err(|e| {
+                DomainError::InvalidInput(format!("Invalid ACME account file {path}: {e}"))
+            })?;
+            return Account::from_credentials(credentials)
+                .await
+                .map_err(acme_error);
+        }
+
+        let contact = self
+            .config
+            .contact_email
+            .as_ref()
+            .map(|email| format!("mailto:{email}"));
+        let contact: Vec<&str> = contact.iter().map(String::as_str).collect();
+        let (account, credentials) = Account::create(
+            &NewAccount {
+                contact: &contact,
+                terms_of_service_agreed: true,
+                only_return_existing: false,
+            },
+            &self.config.directory_url,
+            None,
+        )
+        .await
+        .map_err(acme_error)?;
+
+        let raw = serde_json::to_vec_pretty(&credentials)
+            .map_err(|e| DomainError::IoError(format!("Failed to encode ACME account: {e}")))?;
+        ensure_parent_dir(path).await?;
+        tokio::fs::write(path, raw)
+            .await
+            .map_err(|e| DomainError::IoError(format!("Failed to write {path}: {e}")))?;
+        info!(directory = %self.config.directory_url, "Registered ACME account");
+        Ok(account)
+    }
+
+    /// Runs one order to completion and returns the certificate chain and
+    /// its private key, both PEM.
+    async fn issue(&self) -> Result<(String, String), DomainError> {
+        let account = self.account().await?;
+        let identifiers: Vec<Identifier> = self
+            .config
+            .domains
+            .iter()
+            .map(|domain| Identifier::Dns(domain.clone()))
+            .collect();
+        let mut order = account
+            .new_order(&NewOrder {
+                identifiers: &identifiers,
+            })
+            .await
+            .map_err(acme_error)?;
+
+        let challenge_type = match self.config.challenge {
+            AcmeChallenge::Http01 => ChallengeType::Http01,
+            AcmeChallenge::Dns01 => ChallengeType::Dns01,
+        };
+        let mut pending = Vec::new();
+        let mut ready_urls = Vec::new();
+        for authorization in order.authorizations().await.map_err(acme_error)? {
+            match authorization.status {
+                AuthorizationStatus::Pending => {}
+                AuthorizationStatus::Valid => continue,
+                status => {
+                    return Err(DomainError::IoError(format!(
+                        "ACME authorization is {status:?}"
+                    )))
+                }
+            }
+            let Identifier::Dns(domain) = &authorization.identifier;
+            let challenge = authorization
+                .challenges
+                .iter()
+                .find(|c| c.r#type == challenge_type)
+                .ok_or_else(|| {
+                    DomainError::IoError(format!(
+                        "ACME server offers no {challenge_type:?} challenge for {domain}"
+                    ))
+                })?;
+            let key_authorization = order.key_authorization(challenge);
+            pending.push(PendingChallenge {
+                domain: domain.clone(),
+                token: challenge.token.clone(),
+                key_authorization: key_authorization.as_str().to_string(),
+                dns_value: key_authorization.dns_value(),
+            });
+            ready_urls.push(challenge.url.clone());
+        }
+
+        let presented = self.solver.present(&pending).await?;
+        let validated = async {
+            for url in &ready_urls {
+                order.set_challenge_ready(url).await.map_err(acme_error)?;
+            }
+            wait_for_order(&mut order).await
+        }
+        .await;
+        self.solver.clean_up(presented).await;
+        validated?;
+
+        let key_pair = rcgen::KeyPair::generate()
+            .map_err(|e| DomainError::IoError(format!("Key generation failed: {e}")))?;
+        let mut params = rcgen::CertificateParams::new(self.config.domains.clone())
+            .map_err(|e| DomainError::InvalidInput(format!("Invalid ACME domain: {e}")))?;
+        params.distinguished_name = rcgen::DistinguishedName::new();
+        let csr = params
+            .serialize_request(&key_pair)
+            .map_err(|e| DomainError::IoError(format!("CSR generation failed: {e}")))?;
+        order.finalize(csr.der()).await.map_err(acme_error)?;
+
+        let mut delay = FIRST_POLL_DELAY;
+        for _ in 0..MAX_POLLS {
+            if let Some(chain) = order.certificate().await.map_err(acme_error)? {
+                return Ok((chain, key_pair.serialize_pem()));
+            }
+            tokio::time::sleep(delay).await;
+            delay = (delay * 2).min(MAX_POLL_DELAY);
+        }
+        Err(DomainError::IoError(
+            "ACME server did not issue the certificate in time".into(),
+        ))
+    }
+
+    async fn store(&self, cert_pem: &str, key_pem: &str) -> Result<(), DomainError> {
+        ensure_parent_dir(&self.key_path).await?;
+        ensure_parent_dir(&self.cert_path).await?;
+        tokio::fs::write(&self.key_path, key_pem)
+            .await
+            .map_err(|e| DomainError::IoError(format!("Failed to write key: {e}")))?;
+        tokio::fs::write(&self.cert_path, cert_pem)
+            .await
+            .map_err(|e| DomainError::IoError(format!("Failed to write certificate: {e}")))
+    }
+}
+
+#[async_trait::async_trait]
+impl AcmeCertificatePort for AcmeCertificateManager {
+    async fn renew_if_due(&self) -> Result<bool, DomainError> {
+        let renew_before = Duration::from_secs(self.config.renew_before_days as u64 * SECS_PER_DAY);
+        let due = match tokio::fs::read(&self.cert_path).await {
+            Ok(pem) => certificate_due(&pem, &self.config.domains, renew_before, SystemTime::now()),
+            Err(_) => true,
+        };
+        if !due {
+            return Ok(false);
+        }
+
+        info!(domains = ?self.config.domains, "Requesting ACME certificate");
+        let (cert_pem, key_pem) = self.issue().await?;
+        self.store(&cert_pem, &key_pem).await?;
+        if let Some(resolver) = &self.resolver {
+            resolver.reload(cert_pem.as_bytes(), key_pem.as_bytes())?;
+        }
+        info!(path = %self.cert_path, "ACME certificate installed");
+        Ok(true)
+    }
+}
+
+/// Whether the PEM certificate needs replacing at `now`: it cannot be
+/// parsed, lacks one of `domains` in its subject alternative names, or
+/// expires within `renew_before`.
+pub fn certificate_due(
+    cert_pem: &[u8],
+    domains: &[String],
+    renew_before: Duration,
+    now: SystemTime,
+) -> bool {
+    let Some(Ok(pem)) = x509_parser::pem::Pem::iter_from_buffer(cert_pem).next() else {
+        return true;
+    };
+    let Ok((_, cert)) = x509_parser::parse_x509_certificate(&pem.contents) else {
+        warn!("ACME certificate on disk is not a valid X.509 certificate");
+        return true;
+    };
+
+    let names: Vec<&str> = match cert.subject_alternative_name() {
+        Ok(Some(san)) => san
+            .value
+            .general_names
+            .iter()
+            .filter_map(|name| match name {
+                x509_parser::extensions::GeneralName::DNSName(dns) => Some(*dns),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+    if !domains
+        .iter()
+        .all(|domain| names.iter().any(|name| name.eq_ignore_ascii_case(domain)))
+    {
+        return true;
+    }
+
+    let now = now.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs() as i64;
+    let remaining = cert.validity().not_after.timestamp() - now;
+    remaining < renew_before.as_secs() as i64
+}
+
+/// Waits until the CA has validated every authorization of `order`.
+async fn wait_for_order(order: &mut Order) -> Result<(), DomainError> {
+    let mut delay = FIRST_POLL_DELAY;
+    for _ in 0..MAX_POLLS {
+        tokio::time::sleep(delay).await;
+        match order.refresh().await.map_err(acme_error)?.status {
+            OrderStatus::Ready | OrderStatus::Valid => return Ok(()),
+            OrderStatus::Invalid => {
+                return Err(DomainError::IoError(
+                    "ACME order is invalid: the CA could not validate the challenges".into(),
+                ))
+            }
+            OrderStatus::Pending | OrderStatus::Processing => {}
+        }
+        delay = (delay * 2).min(MAX_POLL_DELAY);
+    }
+    Err(DomainError::IoError(
+        "ACME order was not validated in time".into(),
+    ))
+}
+
+fn acme_error(e: instant_acme::Error) -> DomainError {
+    DomainError::IoError(format!("ACME request failed: {e}"))
+}
@@ -0,0 +1,218 @@
+use ferrous_dns_domain::DomainError;
+use lru::LruCache;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, PrivatePkcs8KeyDer};
+use rustls::server::{ClientHello, ResolvesServerCert};
+use rustls::sign::CertifiedKey;
+use std::num::NonZeroUsize;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+use tracing::{info, warn};
+
+const CA_COMMON_NAME: &str = "Ferrous DNS Block Page CA";
+const CA_ORGANIZATION: &str = "Ferrous DNS";
+const CA_VALIDITY: Duration = Duration::from_secs(10 * 365 * 24 * 3600);
+const LEAF_VALIDITY: Duration = Duration::from_secs(90 * 24 * 3600);
+/// Issued certificates are reused this long, well inside their validity.
+const LEAF_REUSE: Duration = Duration::from_secs(30 * 24 * 3600);
+const MAX_CACHED_LEAVES: usize = 1_024;
+
+/// Local CA of the sinkhole block page. It signs a certificate for the SNI
+/// of each HTTPS connection, so clients that trust the CA get the block page
+/// instead of a certificate error.
+///
+/// The CA is generated on first use and written to the configured paths.
+/// Only a CA generated this way can be loaded: the signing certificate is
+/// rebuilt from the stored key and the fixed subject.
+pub struct BlockPageCertResolver {
+    ca_cert: rcgen::Certificate,
+    ca_cert_der: CertificateDer<'static>,
+    ca_key: rcgen::KeyPair,
+    issued: Mutex<LruCache<String, (Arc<CertifiedKey>, Instant)>>,
+}
+
+impl std::fmt::Debug for BlockPageCertResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BlockPageCertResolver")
+            .finish_non_exhaustive()
+    }
+}
+
+impl BlockPageCertResolver {
+    /// Loads the CA from `cert_path`/`key_path`, generating both files when
+    /// neither exists.
+    pub fn load_or_create(cert_path: &str, key_path: &str) -> Result<Self, DomainError> {
+        let cert_exists = Path::new(cert_path).exists();
+        let key_exists = Path::new(key_path).exists();
+        let (ca_cert_pem, ca_key) = match (cert_exists, key_exists) {
+            (true, true) => {
+                let cert_pem = read_file(cert_path)?;
+                let key_pem = read_file(key_path)?;
+                let key = rcgen::KeyPair::from_pem(&key_pem).map_err(|e| {
+                    DomainError::InvalidInput(format!("Invalid block page CA key: {e}"))
+                })?;
+                (cert_pem, key)
+            }
+            (false, false) => {
+                let key = rcgen::KeyPair::generate().map_err(|e| {
+                    DomainError::IoError(format!("Block page CA generation failed: {e}"))
+                })?;
+                let cert = ca_params(SystemTime::now())?
+                    .self_signed(&key)
+                    .map_err(|e| {
+                        DomainError::IoError(format!("Block page CA generation failed: {e}"))
+                    })?;
+                write_file(cert_path, cert.pem().as_bytes())?;
+                write_file(key_path, key.serialize_pem().as_bytes())?;
+                info!(path = cert_path, "Generated block page CA certificate");
+                (cert.pem(), key)
+            }
+            _ => {
+                return Err(DomainError::InvalidInput(format!(
+                    "Block page CA needs both {cert_path} and {key_path}; remove the other to generate a new CA"
+                )))
+            }
+        };
+
+        let ca_cert_der = check_ca_certificate(&ca_cert_pem, &ca_key)?;
+        let ca_cert = ca_params(SystemTime::now())?
+            .self_signed(&ca_key)
+            .map_err(|e| DomainError::InvalidInput(format!("Invalid block page CA: {e}")))?;
+
+        Ok(Self {
+            ca_cert,
+            ca_cert_der,
+            ca_key,
+            issued: Mutex::new(LruCache::new(
+                NonZeroUsize::new(MAX_CACHED_LEAVES).expect("non-zero cache size"),
+            )),
+        })
+    }
+
+    /// Certificate chain, leaf first, for `host`.
+    pub fn certificate_for(&self, host: &str) -> Result<Arc<CertifiedKey>, DomainError> {
+        let host = host.trim_end_matches('.').to_ascii_lowercase();
+        if let Ok(mut issued) = self.issued.lock() {
+            if let Some((key, at)) = issued.get(&host) {
+                if at.elapsed() < LEAF_REUSE {
+                    return Ok(key.clone());
+                }
+            }
+        }
+
+        let key = Arc::new(self.issue(&host)?);
+        if let Ok(mut issued) = self.issued.lock() {
+            issued.put(host, (key.clone(), Instant::now()));
+        }
+        Ok(key)
+    }
+
+    fn issue(&self, host: &str) -> Result<CertifiedKey, DomainError> {
+        let invalid = |e: rcgen::Error| {
+            DomainError::InvalidInput(format!("Cannot issue a certificate for {host}: {e}"))
+        };
+        let mut params = rcgen::CertificateParams::new(vec![host.to_string()]).map_err(invalid)?;
+        params
+            .distinguished_name
+            .push(rcgen::DnType::CommonName, host);
+        let now = SystemTime::now();
+        params.not_before = (now - Duration::from_secs(3600)).into();
+        params.not_after = (now + LEAF_VALIDITY).into();
+        params.extended_key_usages = vec![rcgen::ExtendedKeyUsagePurpose::ServerAuth];
+
+        let leaf_key = rcgen::KeyPair::generate().map_err(invalid)?;
+        let leaf = params
+            .signed_by(&leaf_key, &self.ca_cert, &self.ca_key)
+            .map_err(invalid)?;
+
+        let private_key = PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(leaf_key.serialize_der()));
+        let signing_key = rustls::crypto::aws_lc_rs::sign::any_supported_type(&private_key)
+            .map_err(|e| DomainError::InvalidInput(format!("Unsupported private key: {e}")))?;
+
+        Ok(CertifiedKey::new(
+            vec![leaf.der().clone(), self.ca_cert_der.clone()],
+            signing_key,
+        ))
+    }
+}
+
+impl ResolvesServerCert for BlockPageCertResolver {
+    fn resolve(&self, client_hello: ClientHello<'_>) -> Option<Arc<CertifiedKey>> {
+        let host = client_hello.server_name()?;
+        match self.certificate_for(host) {
+            Ok(key) => Some(key),
+            Err(e) => {
+                warn!(host, error = %e, "Failed to issue block page certificate");
+                None
+            }
+        }
+    }
+}
+
+fn ca_params(now: SystemTime) -> Result<rcgen::CertificateParams, DomainError> {
+    let mut params = rcgen::CertificateParams::new(Vec::<String>::new())
+        .map_err(|e| DomainError::IoError(e.to_string()))?;
+    params
+        .distinguished_name
+        .push(rcgen::DnType::CommonName, CA_COMMON_NAME);
+    params
+        .distinguished_name
+        .push(rcgen::DnType::OrganizationName, CA_ORGANIZATION);
+    params.is_ca = rcgen::IsCa::Ca(rcgen::BasicConstraints::Constrained(0));
+    params.key_usages = vec![
+        rcgen::KeyUsagePurpose::KeyCertSign,
+        rcgen::KeyUsagePurpose::CrlSign,
+        rcgen::KeyUsagePurpose::DigitalSignature,
+    ];
+    params.not_before = now.into();
+    params.not_after = (now + CA_VALIDITY).into();
+    Ok(params)
+}
+
+/// Checks that `cert_pem` is the block page CA for `key` and returns its DER.
+fn check_ca_certificate(
+    cert_pem: &str,
+    key: &rcgen::KeyPair,
+) -> Result<CertificateDer<'static>, DomainError> {
+    let invalid =
+        |reason: &str| DomainError::InvalidInput(format!("Invalid block page CA: {reason}"));
+
+    let (_, pem) = x509_parser::pem::parse_x509_pem(cert_pem.as_bytes())
+        .map_err(|_| invalid("not a PEM certificate"))?;
+    let cert = pem
+        .parse_x509()
+        .map_err(|_| invalid("not an X.509 certificate"))?;
+
+    let common_name = cert
+        .subject()
+        .iter_common_name()
+        .next()
+        .and_then(|cn| cn.as_str().ok());
+    if common_name != Some(CA_COMMON_NAME) {
+        return Err(invalid("not a CA generated by Ferrous DNS"));
+    }
+    if cert.public_key().subject_public_key.data.as_ref() != key.public_key_raw() {
+        return Err(invalid("certificate and key do not match"));
+    }
+    if !cert.validity().is_valid() {
+        return Err(invalid("certificate has expired"));
+    }
+
+    Ok(CertificateDer::from(pem.contents.clone()))
+}
+
+fn read_file(path: &str) -> Result<String, DomainError> {
+    std::fs::read_to_string(path)
+        .map_err(|e| DomainError::IoError(format!("Failed to read {path}: {e}")))
+}
+
+fn write_file(path: &str, contents: &[u8]) -> Result<(), DomainError> {
+    if let Some(parent) = Path::new(path).parent() {
+        if !parent.as_os_str().is_empty() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| DomainError::IoError(format!("Failed to create directory: {e}")))?;
+        }
+    }
+    std::fs::write(path, contents)
+        .map_err(|e| DomainError::IoError(format!("Failed to write {path}: {e}")))
+}
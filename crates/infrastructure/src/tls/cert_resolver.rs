@@ -0,0 +1,68 @@
+use arc_swap::ArcSwap;
+use ferrous_dns_domain::DomainError;
+use rustls::pki_types::CertificateDer;
+use rustls::server::{ClientHello, ResolvesServerCert};
+use rustls::sign::CertifiedKey;
+use std::io::BufReader;
+use std::sync::Arc;
+
+/// Serves one certificate to every TLS handshake and lets it be swapped
+/// while listeners are running, so a renewed certificate takes effect on
+/// the next connection without rebuilding the acceptors.
+#[derive(Debug)]
+pub struct ReloadableCertResolver {
+    current: ArcSwap<CertifiedKey>,
+}
+
+impl ReloadableCertResolver {
+    pub fn from_pem(cert_pem: &[u8], key_pem: &[u8]) -> Result<Self, DomainError> {
+        Ok(Self {
+            current: ArcSwap::from_pointee(certified_key(cert_pem, key_pem)?),
+        })
+    }
+
+    pub fn from_pem_files(cert_path: &str, key_path: &str) -> Result<Self, DomainError> {
+        let cert_pem = std::fs::read(cert_path)
+            .map_err(|e| DomainError::IoError(format!("Failed to read {cert_path}: {e}")))?;
+        let key_pem = std::fs::read(key_path)
+            .map_err(|e| DomainError::IoError(format!("Failed to read {key_path}: {e}")))?;
+        Self::from_pem(&cert_pem, &key_pem)
+    }
+
+    /// Replaces the served certificate. On error the previous one stays.
+    pub fn reload(&self, cert_pem: &[u8], key_pem: &[u8]) -> Result<(), DomainError> {
+        self.current
+            .store(Arc::new(certified_key(cert_pem, key_pem)?));
+        Ok(())
+    }
+
+    /// The certificate chain currently served, leaf first.
+    pub fn certificate_chain(&self) -> Vec<CertificateDer<'static>> {
+        self.current.load().cert.clone()
+    }
+}
+
+impl ResolvesServerCert for ReloadableCertResolver {
+    fn resolve(&self, _client_hello: ClientHello<'_>) -> Option<Arc<CertifiedKey>> {
+        Some(self.current.load_full())
+    }
+}
+
+fn certified_key(cert_pem: &[u8], key_pem: &[u8]) -> Result<CertifiedKey, DomainError> {
+    let certs: Vec<CertificateDer<'static>> = rustls_pemfile::certs(&mut BufReader::new(cert_pem))
+        .collect::<Result<_, _>>()
+        .map_err(|e| DomainError::InvalidInput(format!("Invalid certificate PEM: {e}")))?;
+    if certs.is_empty() {
+        return Err(DomainError::InvalidInput(
+            "No certificates found in PEM data".into(),
+        ));
+    }
+
+    let key = rustls_pemfile::private_key(&mut BufReader::new(key_pem))
+        .map_err(|e| DomainError::InvalidInput(format!("Invalid key PEM: {e}")))?
+        .ok_or_else(|| DomainError::InvalidInput("No private key found in PEM data".into()))?;
+    let signing_key = rustls::crypto::aws_lc_rs::sign::any_supported_type(&key)
+        .map_err(|e| DomainError::InvalidInput(format!("Unsupported private key: {e}")))?;
+
+    Ok(CertifiedKey::new(certs, signing_key))
+}
@@ -1,3 +1,11 @@
+pub mod acme;
+mod block_page_ca;
+mod cert_resolver;
+
+pub use acme::{certificate_due, AcmeCertificateManager};
+pub use block_page_ca::BlockPageCertResolver;
+pub use cert_resolver::ReloadableCertResolver;
+
 use ferrous_dns_application::ports::{TlsCertificateInfo, TlsCertificatePort};
 use ferrous_dns_domain::DomainError;
 use std::io::BufReader;
@@ -0,0 +1,102 @@
+use ferrous_dns_application::ports::AccessControlPort;
+use ferrous_dns_domain::config::{DnsListenerConfig, ServerConfig};
+use ferrous_dns_domain::AclAction;
+use ferrous_dns_infrastructure::dns::access_control::DEFAULT_ACL_NAME;
+use ferrous_dns_infrastructure::dns::{
+    AccessControlList, AccessControlRegistry, AclVerdict, ListenerPolicy,
+};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+fn ip(s: &str) -> IpAddr {
+    s.parse().unwrap()
+}
+
+fn cidrs(list: &[&str]) -> Vec<String> {
+    list.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn test_default_policy_allows_everyone() {
+    let policy = ListenerPolicy::default();
+    assert!(policy.allows(ip("203.0.113.9")));
+    assert_eq!(policy.check(ip("2001:db8::1")), AclVerdict::Allow);
+    assert_eq!(policy.default_group_id(), None);
+}
+
+#[test]
+fn test_acl_restricts_to_allowed_cidrs() {
+    let acl = Arc::new(AccessControlList::new(
+        "10.8.0.1:53",
+        &cidrs(&["10.8.0.0/24", "fd00::/64"]),
+        AclAction::Refuse,
+    ));
+    let policy = ListenerPolicy::new(acl, Some(3));
+
+    assert_eq!(policy.check(ip("10.8.0.42")), AclVerdict::Allow);
+    assert_eq!(policy.check(ip("fd00::5")), AclVerdict::Allow);
+    assert_eq!(policy.check(ip("192.168.1.10")), AclVerdict::Refuse);
+    assert_eq!(policy.check(ip("fd01::5")), AclVerdict::Refuse);
+    assert_eq!(policy.default_group_id(), Some(3));
+}
+
+#[test]
+fn test_acl_counts_rejections_by_action() {
+    let acl = AccessControlList::new("lan", &cidrs(&["192.168.1.0/24"]), AclAction::Drop);
+
+    assert_eq!(acl.check(ip("192.168.1.5")), AclVerdict::Allow);
+    assert_eq!(acl.check(ip("8.8.8.8")), AclVerdict::Drop);
+    assert_eq!(acl.check(ip("1.1.1.1")), AclVerdict::Drop);
+
+    let stats = acl.stats();
+    assert_eq!(stats.dropped, 2);
+    assert_eq!(stats.refused, 0);
+    assert_eq!(stats.action, AclAction::Drop);
+}
+
+#[test]
+fn test_allows_does_not_count() {
+    let acl = AccessControlList::new("lan", &cidrs(&["192.168.1.0/24"]), AclAction::Refuse);
+    assert!(!acl.allows(ip("8.8.8.8")));
+    assert_eq!(acl.stats().refused, 0);
+}
+
+#[test]
+fn test_registry_shares_acl_by_name() {
+    let registry = AccessControlRegistry::new();
+    let first = registry.register(DEFAULT_ACL_NAME, &[], AclAction::Refuse);
+    let second = registry.register(DEFAULT_ACL_NAME, &cidrs(&["10.0.0.0/8"]), AclAction::Drop);
+
+    assert!(Arc::ptr_eq(&first, &second));
+    assert_eq!(registry.stats().len(), 1);
+}
+
+#[test]
+fn test_apply_hot_reloads_rules_and_keeps_counters() {
+    let registry = AccessControlRegistry::new();
+    let default_acl = registry.register(DEFAULT_ACL_NAME, &[], AclAction::Refuse);
+    let vpn_acl = registry.register("10.8.0.1:53", &cidrs(&["10.8.0.0/24"]), AclAction::Refuse);
+
+    assert_eq!(vpn_acl.check(ip("192.168.1.10")), AclVerdict::Refuse);
+
+    let mut server = ServerConfig::default();
+    server.acl.allowed_clients = cidrs(&["192.168.1.0/24"]);
+    server.acl.action = AclAction::Drop;
+    server.listeners = vec![DnsListenerConfig {
+        bind: "10.8.0.1:53".to_string(),
+        allowed_clients: cidrs(&["10.8.0.0/24", "192.168.1.0/24"]),
+        action: None,
+        default_group: None,
+    }];
+    registry.apply(&server);
+
+    assert_eq!(default_acl.check(ip("8.8.8.8")), AclVerdict::Drop);
+    assert_eq!(default_acl.check(ip("192.168.1.10")), AclVerdict::Allow);
+    assert_eq!(vpn_acl.check(ip("192.168.1.10")), AclVerdict::Allow);
+    assert_eq!(vpn_acl.check(ip("172.16.0.1")), AclVerdict::Drop);
+
+    let vpn_stats = vpn_acl.stats();
+    assert_eq!(vpn_stats.refused, 1);
+    assert_eq!(vpn_stats.dropped, 1);
+    assert_eq!(vpn_stats.allowed_clients.len(), 2);
+}
@@ -0,0 +1,133 @@
+use ferrous_dns_infrastructure::tls::acme::Http01Responder;
+use ferrous_dns_infrastructure::tls::{certificate_due, ReloadableCertResolver};
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+const DAY: Duration = Duration::from_secs(86_400);
+
+fn cert_pem(names: &[&str], valid_for: Duration) -> (String, String) {
+    let mut params =
+        rcgen::CertificateParams::new(names.iter().map(|n| n.to_string()).collect::<Vec<_>>())
+            .unwrap();
+    let now = SystemTime::now();
+    params.not_before = (now - DAY).into();
+    params.not_after = (now + valid_for).into();
+    let key_pair = rcgen::KeyPair::generate().unwrap();
+    let cert = params.self_signed(&key_pair).unwrap();
+    (cert.pem(), key_pair.serialize_pem())
+}
+
+fn domains(names: &[&str]) -> Vec<String> {
+    names.iter().map(|n| n.to_string()).collect()
+}
+
+#[test]
+fn fresh_certificate_covering_all_domains_is_not_due() {
+    let (cert, _) = cert_pem(&["dns.example.com", "example.com"], 80 * DAY);
+    assert!(!certificate_due(
+        cert.as_bytes(),
+        &domains(&["dns.example.com", "example.com"]),
+        30 * DAY,
+        SystemTime::now(),
+    ));
+}
+
+#[test]
+fn certificate_inside_renewal_window_is_due() {
+    let (cert, _) = cert_pem(&["dns.example.com"], 10 * DAY);
+    assert!(certificate_due(
+        cert.as_bytes(),
+        &domains(&["dns.example.com"]),
+        30 * DAY,
+        SystemTime::now(),
+    ));
+}
+
+#[test]
+fn certificate_missing_a_domain_is_due() {
+    let (cert, _) = cert_pem(&["ferrous-dns", "localhost"], 365 * DAY);
+    assert!(certificate_due(
+        cert.as_bytes(),
+        &domains(&["dns.example.com"]),
+        30 * DAY,
+        SystemTime::now(),
+    ));
+}
+
+#[test]
+fn domain_match_ignores_case() {
+    let (cert, _) = cert_pem(&["dns.example.com"], 80 * DAY);
+    assert!(!certificate_due(
+        cert.as_bytes(),
+        &domains(&["DNS.Example.com"]),
+        30 * DAY,
+        SystemTime::now(),
+    ));
+}
+
+#[test]
+fn unparsable_certificate_is_due() {
+    assert!(certificate_due(
+        b"not a certificate",
+        &domains(&["dns.example.com"]),
+        30 * DAY,
+        SystemTime::now(),
+    ));
+}
+
+#[test]
+fn resolver_reload_swaps_the_served_certificate() {
+    let (first_cert, first_key) = cert_pem(&["dns.example.com"], 80 * DAY);
+    let (second_cert, second_key) = cert_pem(&["dns.example.com"], 80 * DAY);
+    let resolver =
+        ReloadableCertResolver::from_pem(first_cert.as_bytes(), first_key.as_bytes()).unwrap();
+    let before = resolver.certificate_chain();
+
+    resolver
+        .reload(second_cert.as_bytes(), second_key.as_bytes())
+        .unwrap();
+    let after = resolver.certificate_chain();
+
+    assert_eq!(before.len(), 1);
+    assert_eq!(after.len(), 1);
+    assert_ne!(before[0], after[0]);
+}
+
+#[test]
+fn failed_reload_keeps_the_previous_certificate() {
+    let (cert, key) = cert_pem(&["dns.example.com"], 80 * DAY);
+    let resolver = ReloadableCertResolver::from_pem(cert.as_bytes(), key.as_bytes()).unwrap();
+    let before = resolver.certificate_chain();
+
+    assert!(resolver.reload(b"garbage", key.as_bytes()).is_err());
+    assert_eq!(resolver.certificate_chain(), before);
+}
+
+async fn http_get(responder: &Http01Responder, path: &str) -> String {
+    let mut stream = TcpStream::connect(responder.local_addr()).await.unwrap();
+    let request = format!("GET {path} HTTP/1.1\r\nHost: dns.example.com\r\n\r\n");
+    stream.write_all(request.as_bytes()).await.unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    response
+}
+
+#[tokio::test]
+async fn http01_responder_serves_known_tokens_only() {
+    let tokens = HashMap::from([("tok123".to_string(), "tok123.thumbprint".to_string())]);
+    let responder = Http01Responder::start("127.0.0.1:0".parse().unwrap(), tokens)
+        .await
+        .unwrap();
+
+    let found = http_get(&responder, "/.well-known/acme-challenge/tok123").await;
+    assert!(found.starts_with("HTTP/1.1 200"));
+    assert!(found.ends_with("\r\n\r\ntok123.thumbprint"));
+
+    let missing = http_get(&responder, "/.well-known/acme-challenge/other").await;
+    assert!(missing.starts_with("HTTP/1.1 404"));
+
+    let elsewhere = http_get(&responder, "/tok123").await;
+    assert!(elsewhere.starts_with("HTTP/1.1 404"));
+}
@@ -0,0 +1,116 @@
+use ferrous_dns_application::ports::AlertRepository;
+use ferrous_dns_domain::{Alert, AlertKind, DomainError};
+use ferrous_dns_infrastructure::repositories::SqliteAlertRepository;
+use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
+
+async fn create_test_db() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::query(include_str!(
+        "../../../migrations/20260311000001_create_alerts.sql"
+    ))
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    pool
+}
+
+fn nxdomain_alert(client_ip: &str) -> Alert {
+    Alert::new(
+        client_ip,
+        AlertKind::NxdomainRate,
+        0.8,
+        0.5,
+        "40 of 50 queries answered NXDOMAIN",
+    )
+}
+
+#[tokio::test]
+async fn test_insert_returns_stored_alert() {
+    let repo = SqliteAlertRepository::new(create_test_db().await);
+
+    let alert = Alert::new(
+        "10.0.0.7",
+        AlertKind::SubdomainEntropy,
+        4.9,
+        4.5,
+        "30 unique",
+    )
+    .with_domain("example.net");
+    let stored = repo.insert(&alert).await.unwrap();
+
+    assert!(stored.id.is_some());
+    assert!(stored.created_at.is_some());
+    assert_eq!(stored.kind, AlertKind::SubdomainEntropy);
+    assert_eq!(stored.domain.as_deref(), Some("example.net"));
+}
+
+#[tokio::test]
+async fn test_get_recent_newest_first_with_filters() {
+    let repo = SqliteAlertRepository::new(create_test_db().await);
+    repo.insert(&nxdomain_alert("10.0.0.1")).await.unwrap();
+    repo.insert(&nxdomain_alert("10.0.0.2")).await.unwrap();
+    repo.insert(&Alert::new(
+        "10.0.0.2",
+        AlertKind::VolumeSpike,
+        8.0,
+        5.0,
+        "spike",
+    ))
+    .await
+    .unwrap();
+
+    let all = repo.get_recent(10, None, None).await.unwrap();
+    let client = repo.get_recent(10, Some("10.0.0.2"), None).await.unwrap();
+    let kind = repo
+        .get_recent(10, Some("10.0.0.2"), Some(AlertKind::NxdomainRate))
+        .await
+        .unwrap();
+    let limited = repo.get_recent(1, None, None).await.unwrap();
+
+    assert_eq!(all.len(), 3);
+    assert_eq!(all[0].kind, AlertKind::VolumeSpike);
+    assert_eq!(client.len(), 2);
+    assert_eq!(kind.len(), 1);
+    assert_eq!(limited.len(), 1);
+}
+
+#[tokio::test]
+async fn test_delete_and_missing_alert() {
+    let repo = SqliteAlertRepository::new(create_test_db().await);
+    let stored = repo.insert(&nxdomain_alert("10.0.0.1")).await.unwrap();
+    let id = stored.id.unwrap();
+
+    repo.delete(id).await.unwrap();
+
+    assert!(repo.get_recent(10, None, None).await.unwrap().is_empty());
+    assert!(matches!(
+        repo.delete(id).await,
+        Err(DomainError::AlertNotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_delete_older_than_keeps_recent_alerts() {
+    let pool = create_test_db().await;
+    let repo = SqliteAlertRepository::new(pool.clone());
+    repo.insert(&nxdomain_alert("10.0.0.1")).await.unwrap();
+    repo.insert(&nxdomain_alert("10.0.0.2")).await.unwrap();
+    sqlx::query(
+        "UPDATE alerts SET created_at = datetime('now', '-40 days') WHERE client_ip = '10.0.0.1'",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let deleted = repo.delete_older_than(30).await.unwrap();
+    let remaining = repo.get_recent(10, None, None).await.unwrap();
+
+    assert_eq!(deleted, 1);
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].client_ip, "10.0.0.2");
+}
@@ -16,6 +16,7 @@ async fn create_test_db() -> sqlx::SqlitePool {
             key_prefix   TEXT    NOT NULL,
             key_hash     TEXT    NOT NULL,
             key_raw      TEXT,
+            tenant_id    INTEGER,
             created_at   TEXT    NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%S', 'now')),
             last_used_at TEXT
         )",
@@ -51,7 +52,7 @@ async fn create_returns_token_with_all_fields() {
     let repo = make_repo(pool);
 
     let token = repo
-        .create("test-token", "abc12345", "sha256hash", "rawvalue")
+        .create("test-token", "abc12345", "sha256hash", "rawvalue", None)
         .await
         .unwrap();
 
@@ -69,9 +70,11 @@ async fn create_duplicate_name_returns_error() {
     let pool = create_test_db().await;
     let repo = make_repo(pool);
 
-    repo.create("dup", "pre", "hash1", "raw1").await.unwrap();
+    repo.create("dup", "pre", "hash1", "raw1", None)
+        .await
+        .unwrap();
     let err = repo
-        .create("dup", "pre", "hash2", "raw2")
+        .create("dup", "pre", "hash2", "raw2", None)
         .await
         .unwrap_err();
 
@@ -98,8 +101,10 @@ async fn get_all_returns_multiple() {
     let pool = create_test_db().await;
     let repo = make_repo(pool);
 
-    repo.create("first", "pre1", "hash1", "raw1").await.unwrap();
-    repo.create("second", "pre2", "hash2", "raw2")
+    repo.create("first", "pre1", "hash1", "raw1", None)
+        .await
+        .unwrap();
+    repo.create("second", "pre2", "hash2", "raw2", None)
         .await
         .unwrap();
 
@@ -116,7 +121,10 @@ async fn get_by_id_found() {
     let pool = create_test_db().await;
     let repo = make_repo(pool);
 
-    let created = repo.create("find-me", "pre", "hash", "raw").await.unwrap();
+    let created = repo
+        .create("find-me", "pre", "hash", "raw", None)
+        .await
+        .unwrap();
     let id = created.id.unwrap();
 
     let found = repo.get_by_id(id).await.unwrap();
@@ -137,7 +145,9 @@ async fn get_by_name_found() {
     let pool = create_test_db().await;
     let repo = make_repo(pool);
 
-    repo.create("named", "pre", "hash", "raw").await.unwrap();
+    repo.create("named", "pre", "hash", "raw", None)
+        .await
+        .unwrap();
 
     let found = repo.get_by_name("named").await.unwrap();
     assert!(found.is_some());
@@ -160,7 +170,10 @@ async fn update_name_only() {
     let pool = create_test_db().await;
     let repo = make_repo(pool);
 
-    let created = repo.create("old-name", "pre", "hash", "raw").await.unwrap();
+    let created = repo
+        .create("old-name", "pre", "hash", "raw", None)
+        .await
+        .unwrap();
     let id = created.id.unwrap();
 
     let updated = repo.update(id, "new-name", None, None, None).await.unwrap();
@@ -173,7 +186,10 @@ async fn update_name_and_key() {
     let pool = create_test_db().await;
     let repo = make_repo(pool);
 
-    let created = repo.create("token", "pre", "hash", "raw").await.unwrap();
+    let created = repo
+        .create("token", "pre", "hash", "raw", None)
+        .await
+        .unwrap();
     let id = created.id.unwrap();
 
     let updated = repo
@@ -206,8 +222,13 @@ async fn update_duplicate_name_returns_error() {
     let pool = create_test_db().await;
     let repo = make_repo(pool);
 
-    repo.create("taken", "pre1", "hash1", "raw1").await.unwrap();
-    let second = repo.create("other", "pre2", "hash2", "raw2").await.unwrap();
+    repo.create("taken", "pre1", "hash1", "raw1", None)
+        .await
+        .unwrap();
+    let second = repo
+        .create("other", "pre2", "hash2", "raw2", None)
+        .await
+        .unwrap();
     let id2 = second.id.unwrap();
 
     let err = repo
@@ -229,7 +250,10 @@ async fn delete_existing() {
     let pool = create_test_db().await;
     let repo = make_repo(pool);
 
-    let created = repo.create("del", "pre", "hash", "raw").await.unwrap();
+    let created = repo
+        .create("del", "pre", "hash", "raw", None)
+        .await
+        .unwrap();
     let id = created.id.unwrap();
 
     repo.delete(id).await.unwrap();
@@ -257,7 +281,10 @@ async fn update_last_used_sets_timestamp() {
     let pool = create_test_db().await;
     let repo = make_repo(pool);
 
-    let created = repo.create("used", "pre", "hash", "raw").await.unwrap();
+    let created = repo
+        .create("used", "pre", "hash", "raw", None)
+        .await
+        .unwrap();
     let id = created.id.unwrap();
     assert!(created.last_used_at.is_none());
 
@@ -276,8 +303,12 @@ async fn get_all_hashes_returns_pairs() {
     let pool = create_test_db().await;
     let repo = make_repo(pool);
 
-    repo.create("a", "pre1", "hash_a", "raw1").await.unwrap();
-    repo.create("b", "pre2", "hash_b", "raw2").await.unwrap();
+    repo.create("a", "pre1", "hash_a", "raw1", None)
+        .await
+        .unwrap();
+    repo.create("b", "pre2", "hash_b", "raw2", None)
+        .await
+        .unwrap();
 
     let hashes = repo.get_all_hashes().await.unwrap();
     assert_eq!(hashes.len(), 2);
@@ -293,7 +324,7 @@ async fn get_id_by_hash_found() {
     let repo = make_repo(pool);
 
     let created = repo
-        .create("token", "pre", "unique_hash", "raw")
+        .create("token", "pre", "unique_hash", "raw", None)
         .await
         .unwrap();
     let expected_id = created.id.unwrap();
@@ -310,3 +341,18 @@ async fn get_id_by_hash_not_found() {
     let result = repo.get_id_by_hash("nonexistent").await.unwrap();
     assert!(result.is_none());
 }
+
+#[tokio::test]
+async fn get_by_hash_returns_tenant_scope() {
+    let pool = create_test_db().await;
+    let repo = make_repo(pool);
+
+    repo.create("tenant", "pre", "tenant_hash", "raw", Some(3))
+        .await
+        .unwrap();
+
+    let token = repo.get_by_hash("tenant_hash").await.unwrap().unwrap();
+    assert_eq!(token.name.as_ref(), "tenant");
+    assert_eq!(token.tenant_id, Some(3));
+    assert!(repo.get_by_hash("nonexistent").await.unwrap().is_none());
+}
@@ -0,0 +1,160 @@
+use ferrous_dns_application::ports::{BackupArchiver, BackupStore};
+use ferrous_dns_domain::config::DatabaseConfig;
+use ferrous_dns_infrastructure::backup::{SqliteBackupStore, TarGzBackupArchiver};
+use ferrous_dns_infrastructure::database::create_write_pool;
+use serde_json::json;
+use sqlx::SqlitePool;
+use tempfile::TempDir;
+
+async fn create_migrated_db() -> (TempDir, SqlitePool) {
+    let dir = tempfile::tempdir().unwrap();
+    let url = format!("sqlite:{}", dir.path().join("ferrous.db").display());
+    let pool = create_write_pool(&url, &DatabaseConfig::default())
+        .await
+        .unwrap();
+    (dir, pool)
+}
+
+async fn seed(pool: &SqlitePool) {
+    sqlx::query("INSERT INTO groups (id, name, enabled) VALUES (2, 'Kids', 1)")
+        .execute(pool)
+        .await
+        .unwrap();
+    sqlx::query(
+        "INSERT INTO clients (ip_address, hostname, group_id) VALUES ('10.0.0.5', 'tablet', 2)",
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+    sqlx::query("INSERT INTO schedule_profiles (id, name, timezone, created_at, updated_at) VALUES (1, 'School nights', 'UTC', datetime('now'), datetime('now'))")
+        .execute(pool)
+        .await
+        .unwrap();
+    sqlx::query("INSERT INTO group_schedule_profiles (group_id, profile_id) VALUES (2, 1)")
+        .execute(pool)
+        .await
+        .unwrap();
+}
+
+async fn count(pool: &SqlitePool, table: &str) -> i64 {
+    sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {table}"))
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_dump_covers_configuration_tables_only() {
+    let (_dir, pool) = create_migrated_db().await;
+    seed(&pool).await;
+    let store = SqliteBackupStore::new(pool);
+
+    let tables = store.dump().await.unwrap();
+
+    assert!(tables["groups"].iter().any(|g| g["name"] == "Kids"));
+    assert_eq!(tables["clients"][0]["hostname"], "tablet");
+    assert_eq!(tables["group_schedule_profiles"].len(), 1);
+    assert!(!tables.contains_key("query_log"));
+    assert!(!tables.contains_key("users"));
+    assert!(!tables.contains_key("api_tokens"));
+}
+
+#[tokio::test]
+async fn test_restore_round_trip_preserves_ids_and_links() {
+    let (_dir, pool) = create_migrated_db().await;
+    seed(&pool).await;
+    let store = SqliteBackupStore::new(pool.clone());
+    let tables = store.dump().await.unwrap();
+
+    sqlx::query("DELETE FROM group_schedule_profiles")
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM clients")
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM groups WHERE id = 2")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let restored = store.restore(&tables).await.unwrap();
+
+    assert_eq!(restored["clients"], 1);
+    let group_id: i64 =
+        sqlx::query_scalar("SELECT group_id FROM clients WHERE hostname = 'tablet'")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(group_id, 2);
+    assert_eq!(count(&pool, "group_schedule_profiles").await, 1);
+}
+
+#[tokio::test]
+async fn test_restore_rolls_back_on_constraint_violation() {
+    let (_dir, pool) = create_migrated_db().await;
+    seed(&pool).await;
+    let store = SqliteBackupStore::new(pool.clone());
+    let mut tables = store.dump().await.unwrap();
+    tables.get_mut("clients").unwrap()[0].insert("group_id".to_string(), json!(999));
+
+    assert!(store.restore(&tables).await.is_err());
+
+    assert_eq!(count(&pool, "clients").await, 1);
+    assert_eq!(count(&pool, "group_schedule_profiles").await, 1);
+}
+
+#[tokio::test]
+async fn test_restore_ignores_unknown_columns_and_tables() {
+    let (_dir, pool) = create_migrated_db().await;
+    let store = SqliteBackupStore::new(pool.clone());
+    let mut tables = store.dump().await.unwrap();
+    tables.get_mut("groups").unwrap()[0].insert("retired_column".to_string(), json!("x"));
+    tables.insert("retired_table".to_string(), vec![]);
+
+    let restored = store.restore(&tables).await.unwrap();
+
+    assert!(!restored.contains_key("retired_table"));
+    assert_eq!(restored["groups"] as i64, count(&pool, "groups").await);
+}
+
+#[tokio::test]
+async fn test_restore_clears_query_log_references_to_dropped_groups() {
+    let (_dir, pool) = create_migrated_db().await;
+    let store = SqliteBackupStore::new(pool.clone());
+    let tables = store.dump().await.unwrap();
+    seed(&pool).await;
+    sqlx::query("INSERT INTO query_log (domain, record_type, client_ip, group_id) VALUES ('a.com', 'A', '10.0.0.5', 2)")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    store.restore(&tables).await.unwrap();
+
+    let group_id: Option<i64> = sqlx::query_scalar("SELECT group_id FROM query_log")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(group_id, None);
+}
+
+#[test]
+fn test_tar_gz_archiver_round_trip() {
+    let archiver = TarGzBackupArchiver;
+    let files = vec![
+        ("manifest.json".to_string(), b"{}".to_vec()),
+        ("tables/groups.json".to_string(), b"[]".to_vec()),
+    ];
+
+    let packed = archiver.pack(&files).unwrap();
+    let unpacked = archiver.unpack(&packed).unwrap();
+
+    assert_eq!(&packed[..2], &[0x1f, 0x8b]);
+    assert_eq!(unpacked, files);
+}
+
+#[test]
+fn test_tar_gz_archiver_rejects_garbage() {
+    assert!(TarGzBackupArchiver.unpack(&[0x1f, 0x8b, 0, 1, 2]).is_err());
+}
@@ -0,0 +1,133 @@
+use ferrous_dns_application::ports::{BlockFilterEnginePort, FilterDecision};
+use ferrous_dns_domain::config::DatabaseConfig;
+use ferrous_dns_domain::BlockSource;
+use ferrous_dns_infrastructure::database::create_write_pool;
+use ferrous_dns_infrastructure::dns::BlockFilterEngine;
+use ferrous_dns_infrastructure::schedule::ScheduleStateStore;
+use sqlx::SqlitePool;
+use std::path::Path;
+use std::sync::Arc;
+
+const DEFAULT_GROUP: i64 = 1;
+const ADS_URL: &str = "http://127.0.0.1:1/ads.txt";
+const TRACKERS_URL: &str = "http://127.0.0.1:1/trackers.txt";
+
+async fn create_pool(dir: &Path) -> SqlitePool {
+    let url = format!("sqlite:{}", dir.join("test.db").display());
+    create_write_pool(&url, &DatabaseConfig::default())
+        .await
+        .unwrap()
+}
+
+async fn add_source(pool: &SqlitePool, name: &str, url: &str, audit_mode: bool) {
+    let id: i64 = sqlx::query_scalar(
+        "INSERT INTO blocklist_sources (name, url, group_id, enabled, audit_mode)
+         VALUES (?, ?, ?, 1, ?) RETURNING id",
+    )
+    .bind(name)
+    .bind(url)
+    .bind(DEFAULT_GROUP)
+    .bind(audit_mode)
+    .fetch_one(pool)
+    .await
+    .unwrap();
+    sqlx::query("INSERT INTO blocklist_source_groups (source_id, group_id) VALUES (?, ?)")
+        .bind(id)
+        .bind(DEFAULT_GROUP)
+        .execute(pool)
+        .await
+        .unwrap();
+}
+
+/// `shared.example` is on both lists, `ads-only.example` only on the ads
+/// list.
+async fn build_engine(dir: &Path, pool: &SqlitePool) -> Arc<BlockFilterEngine> {
+    let snapshot = dir.join("blocklist-index.snapshot");
+    std::fs::write(
+        &snapshot,
+        format!(
+            "!ferrous-dns list snapshot v1\n\
+             !source {ADS_URL}\nshared.example\nads-only.example\n\
+             !source {TRACKERS_URL}\nshared.example\n"
+        ),
+    )
+    .unwrap();
+
+    BlockFilterEngine::with_index_snapshot(
+        pool.clone(),
+        DEFAULT_GROUP,
+        Arc::new(ScheduleStateStore::new()),
+        true,
+        Some(snapshot),
+    )
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn audit_source_logs_instead_of_blocking() {
+    let dir = tempfile::tempdir().unwrap();
+    let pool = create_pool(dir.path()).await;
+    add_source(&pool, "ads", ADS_URL, true).await;
+    add_source(&pool, "trackers", TRACKERS_URL, false).await;
+    let engine = build_engine(dir.path(), &pool).await;
+
+    assert_eq!(
+        engine.check("ads-only.example", DEFAULT_GROUP),
+        FilterDecision::Audit(BlockSource::Blocklist)
+    );
+    assert_eq!(
+        engine.check("shared.example", DEFAULT_GROUP),
+        FilterDecision::Block(BlockSource::Blocklist)
+    );
+    assert_eq!(
+        engine.check("allowed.example", DEFAULT_GROUP),
+        FilterDecision::Allow
+    );
+}
+
+#[tokio::test]
+async fn audit_group_logs_every_list_match() {
+    let dir = tempfile::tempdir().unwrap();
+    let pool = create_pool(dir.path()).await;
+    add_source(&pool, "ads", ADS_URL, false).await;
+    add_source(&pool, "trackers", TRACKERS_URL, false).await;
+    sqlx::query("UPDATE groups SET audit_mode = 1 WHERE id = ?")
+        .bind(DEFAULT_GROUP)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let engine = build_engine(dir.path(), &pool).await;
+
+    assert_eq!(
+        engine.check("shared.example", DEFAULT_GROUP),
+        FilterDecision::Audit(BlockSource::Blocklist)
+    );
+    assert_eq!(
+        engine.explain("ads-only.example", DEFAULT_GROUP).decision,
+        FilterDecision::Audit(BlockSource::Blocklist)
+    );
+}
+
+#[tokio::test]
+async fn leaving_audit_mode_blocks_after_reload() {
+    let dir = tempfile::tempdir().unwrap();
+    let pool = create_pool(dir.path()).await;
+    add_source(&pool, "ads", ADS_URL, true).await;
+    let engine = build_engine(dir.path(), &pool).await;
+    assert_eq!(
+        engine.check("ads-only.example", DEFAULT_GROUP),
+        FilterDecision::Audit(BlockSource::Blocklist)
+    );
+
+    sqlx::query("UPDATE blocklist_sources SET audit_mode = 0")
+        .execute(&pool)
+        .await
+        .unwrap();
+    engine.reload().await.unwrap();
+
+    assert_eq!(
+        engine.check("ads-only.example", DEFAULT_GROUP),
+        FilterDecision::Block(BlockSource::Blocklist)
+    );
+}
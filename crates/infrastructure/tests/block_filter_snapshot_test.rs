@@ -0,0 +1,177 @@
+use ferrous_dns_application::ports::{
+    BlockFilterCompilePhase, BlockFilterEnginePort, BlockIndexOrigin, FilterDecision,
+};
+use ferrous_dns_domain::config::DatabaseConfig;
+use ferrous_dns_infrastructure::database::create_write_pool;
+use ferrous_dns_infrastructure::dns::BlockFilterEngine;
+use ferrous_dns_infrastructure::schedule::ScheduleStateStore;
+use sqlx::SqlitePool;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+const DEFAULT_GROUP: i64 = 1;
+const UNREACHABLE_URL: &str = "http://127.0.0.1:1/ads.txt";
+
+async fn create_pool(dir: &Path) -> SqlitePool {
+    let url = format!("sqlite:{}", dir.join("test.db").display());
+    create_write_pool(&url, &DatabaseConfig::default())
+        .await
+        .unwrap()
+}
+
+async fn add_source(pool: &SqlitePool, url: &str) {
+    let id: i64 = sqlx::query_scalar(
+        "INSERT INTO blocklist_sources (name, url, group_id, enabled)
+         VALUES ('ads', ?, ?, 1) RETURNING id",
+    )
+    .bind(url)
+    .bind(DEFAULT_GROUP)
+    .fetch_one(pool)
+    .await
+    .unwrap();
+    sqlx::query("INSERT INTO blocklist_source_groups (source_id, group_id) VALUES (?, ?)")
+        .bind(id)
+        .bind(DEFAULT_GROUP)
+        .execute(pool)
+        .await
+        .unwrap();
+}
+
+fn write_snapshot(dir: &Path, body: &str) -> PathBuf {
+    let path = dir.join("blocklist-index.snapshot");
+    std::fs::write(&path, body).unwrap();
+    path
+}
+
+async fn build_engine(pool: SqlitePool, snapshot: PathBuf) -> Arc<BlockFilterEngine> {
+    BlockFilterEngine::with_index_snapshot(
+        pool,
+        DEFAULT_GROUP,
+        Arc::new(ScheduleStateStore::new()),
+        true,
+        Some(snapshot),
+    )
+    .await
+    .unwrap()
+}
+
+fn is_blocked(engine: &BlockFilterEngine, domain: &str) -> bool {
+    matches!(
+        engine.check(domain, DEFAULT_GROUP),
+        FilterDecision::Block(_)
+    )
+}
+
+#[tokio::test]
+async fn snapshot_lists_block_before_sources_are_fetched() {
+    let dir = tempfile::tempdir().unwrap();
+    let pool = create_pool(dir.path()).await;
+    add_source(&pool, UNREACHABLE_URL).await;
+    let snapshot = write_snapshot(
+        dir.path(),
+        &format!("!ferrous-dns list snapshot v1\n!source {UNREACHABLE_URL}\nads.example\n"),
+    );
+
+    let engine = build_engine(pool, snapshot).await;
+
+    assert!(is_blocked(&engine, "ads.example"));
+    assert!(!is_blocked(&engine, "news.example"));
+    let status = engine.compile_status();
+    assert!(status.built_at.is_some());
+    assert_eq!(status.compiled_domains, 1);
+}
+
+#[tokio::test]
+async fn failed_fetch_keeps_snapshot_entries() {
+    let dir = tempfile::tempdir().unwrap();
+    let pool = create_pool(dir.path()).await;
+    add_source(&pool, UNREACHABLE_URL).await;
+    let snapshot = write_snapshot(
+        dir.path(),
+        &format!("!ferrous-dns list snapshot v1\n!source {UNREACHABLE_URL}\nads.example\n"),
+    );
+
+    let engine = build_engine(pool, snapshot.clone()).await;
+    engine.reload().await.unwrap();
+
+    assert!(is_blocked(&engine, "ads.example"));
+    let status = engine.compile_status();
+    assert_eq!(status.phase, BlockFilterCompilePhase::Idle);
+    assert_eq!(status.origin, BlockIndexOrigin::Sources);
+    assert_eq!(status.lists_total, 1);
+    assert!(status.last_error.is_none());
+
+    let saved = std::fs::read_to_string(&snapshot).unwrap();
+    assert!(saved.contains(UNREACHABLE_URL));
+    assert!(saved.contains("ads.example"));
+}
+
+#[tokio::test]
+async fn snapshot_ignores_lists_no_longer_configured() {
+    let dir = tempfile::tempdir().unwrap();
+    let pool = create_pool(dir.path()).await;
+    add_source(&pool, UNREACHABLE_URL).await;
+    let snapshot = write_snapshot(
+        dir.path(),
+        "!ferrous-dns list snapshot v1\n!source http://127.0.0.1:1/removed.txt\nold.example\n",
+    );
+
+    let engine = build_engine(pool, snapshot).await;
+
+    assert!(!is_blocked(&engine, "old.example"));
+}
+
+#[tokio::test]
+async fn unreadable_snapshot_starts_with_empty_index() {
+    let dir = tempfile::tempdir().unwrap();
+    let pool = create_pool(dir.path()).await;
+    add_source(&pool, UNREACHABLE_URL).await;
+    let snapshot = write_snapshot(dir.path(), "not a snapshot\nads.example\n");
+
+    let engine = build_engine(pool, snapshot).await;
+
+    assert!(!is_blocked(&engine, "ads.example"));
+}
+
+#[tokio::test]
+async fn imported_snapshot_replaces_lists_under_the_same_urls() {
+    let dir = tempfile::tempdir().unwrap();
+    let pool = create_pool(dir.path()).await;
+    add_source(&pool, UNREACHABLE_URL).await;
+    let snapshot = write_snapshot(
+        dir.path(),
+        &format!("!ferrous-dns list snapshot v1\n!source {UNREACHABLE_URL}\nads.example\n"),
+    );
+    let engine = build_engine(pool, snapshot).await;
+    engine.reload().await.unwrap();
+
+    let imported =
+        format!("!ferrous-dns list snapshot v1\n!source {UNREACHABLE_URL}\ntracker.example\n");
+    engine
+        .import_list_snapshot(imported.clone().into_bytes())
+        .await
+        .unwrap();
+
+    assert!(is_blocked(&engine, "tracker.example"));
+    assert!(!is_blocked(&engine, "ads.example"));
+    let exported = engine.export_list_snapshot().await.unwrap().unwrap();
+    assert_eq!(exported, imported.into_bytes());
+}
+
+#[tokio::test]
+async fn import_rejects_data_that_is_not_a_snapshot() {
+    let dir = tempfile::tempdir().unwrap();
+    let pool = create_pool(dir.path()).await;
+    add_source(&pool, UNREACHABLE_URL).await;
+    let snapshot = write_snapshot(
+        dir.path(),
+        &format!("!ferrous-dns list snapshot v1\n!source {UNREACHABLE_URL}\nads.example\n"),
+    );
+    let engine = build_engine(pool, snapshot).await;
+
+    assert!(engine
+        .import_list_snapshot(b"<html>login</html>".to_vec())
+        .await
+        .is_err());
+    assert!(is_blocked(&engine, "ads.example"));
+}
@@ -0,0 +1,140 @@
+use ferrous_dns_application::ports::{BlockFilterEnginePort, SourceHitStats};
+use ferrous_dns_domain::config::DatabaseConfig;
+use ferrous_dns_infrastructure::database::create_write_pool;
+use ferrous_dns_infrastructure::dns::BlockFilterEngine;
+use ferrous_dns_infrastructure::schedule::ScheduleStateStore;
+use sqlx::SqlitePool;
+use std::path::Path;
+use std::sync::Arc;
+
+const DEFAULT_GROUP: i64 = 1;
+const ADS_URL: &str = "http://127.0.0.1:1/ads.txt";
+const TRACKERS_URL: &str = "http://127.0.0.1:1/trackers.txt";
+
+async fn create_pool(dir: &Path) -> SqlitePool {
+    let url = format!("sqlite:{}", dir.join("test.db").display());
+    create_write_pool(&url, &DatabaseConfig::default())
+        .await
+        .unwrap()
+}
+
+async fn add_source(pool: &SqlitePool, name: &str, url: &str) -> i64 {
+    let id: i64 = sqlx::query_scalar(
+        "INSERT INTO blocklist_sources (name, url, group_id, enabled)
+         VALUES (?, ?, ?, 1) RETURNING id",
+    )
+    .bind(name)
+    .bind(url)
+    .bind(DEFAULT_GROUP)
+    .fetch_one(pool)
+    .await
+    .unwrap();
+    sqlx::query("INSERT INTO blocklist_source_groups (source_id, group_id) VALUES (?, ?)")
+        .bind(id)
+        .bind(DEFAULT_GROUP)
+        .execute(pool)
+        .await
+        .unwrap();
+    id
+}
+
+/// Two sources served from a snapshot: `shared.example` is on both lists,
+/// `ads-only.example` only on the ads list.
+async fn build_engine(dir: &Path) -> (SqlitePool, Arc<BlockFilterEngine>, i64, i64) {
+    let pool = create_pool(dir).await;
+    let ads = add_source(&pool, "ads", ADS_URL).await;
+    let trackers = add_source(&pool, "trackers", TRACKERS_URL).await;
+    let snapshot = dir.join("blocklist-index.snapshot");
+    std::fs::write(
+        &snapshot,
+        format!(
+            "!ferrous-dns list snapshot v1\n\
+             !source {ADS_URL}\nshared.example\nads-only.example\n\
+             !source {TRACKERS_URL}\nshared.example\n"
+        ),
+    )
+    .unwrap();
+
+    let engine = BlockFilterEngine::with_index_snapshot(
+        pool.clone(),
+        DEFAULT_GROUP,
+        Arc::new(ScheduleStateStore::new()),
+        true,
+        Some(snapshot),
+    )
+    .await
+    .unwrap();
+    (pool, engine, ads, trackers)
+}
+
+fn stats_for(engine: &BlockFilterEngine, source_id: i64) -> SourceHitStats {
+    engine
+        .source_hit_stats()
+        .sources
+        .into_iter()
+        .find(|stats| stats.source.source_id == Some(source_id))
+        .expect("source in report")
+}
+
+#[tokio::test]
+async fn blocks_are_split_into_unique_and_redundant_hits() {
+    let dir = tempfile::tempdir().unwrap();
+    let (_pool, engine, ads, trackers) = build_engine(dir.path()).await;
+
+    engine.check("ads-only.example", DEFAULT_GROUP);
+    engine.check("ads-only.example", DEFAULT_GROUP);
+    engine.check("shared.example", DEFAULT_GROUP);
+    engine.check("allowed.example", DEFAULT_GROUP);
+
+    let report = engine.source_hit_stats();
+    assert_eq!(report.window_secs, 86_400);
+    assert_eq!(report.sources.len(), 3);
+
+    let ads = stats_for(&engine, ads);
+    assert_eq!(ads.entries, 2);
+    assert_eq!(ads.unique_blocks, 2);
+    assert_eq!(ads.redundant_blocks, 1);
+
+    let trackers = stats_for(&engine, trackers);
+    assert_eq!(trackers.entries, 1);
+    assert_eq!(trackers.unique_blocks, 0);
+    assert_eq!(trackers.redundant_blocks, 1);
+}
+
+#[tokio::test]
+async fn reassigned_bits_start_from_zero() {
+    let dir = tempfile::tempdir().unwrap();
+    let (pool, engine, ads, trackers) = build_engine(dir.path()).await;
+
+    engine.check("shared.example", DEFAULT_GROUP);
+    assert_eq!(stats_for(&engine, trackers).redundant_blocks, 1);
+
+    sqlx::query("UPDATE blocklist_sources SET enabled = 0 WHERE id = ?")
+        .bind(ads)
+        .execute(&pool)
+        .await
+        .unwrap();
+    engine.reload().await.unwrap();
+
+    let trackers = stats_for(&engine, trackers);
+    assert_eq!(trackers.source.bit, 0);
+    assert_eq!(trackers.unique_blocks, 0);
+    assert_eq!(trackers.redundant_blocks, 0);
+
+    engine.check("shared.example", DEFAULT_GROUP);
+    assert_eq!(
+        stats_for(&engine, trackers.source.source_id.unwrap()).unique_blocks,
+        1
+    );
+}
+
+#[tokio::test]
+async fn peek_does_not_count_hits() {
+    let dir = tempfile::tempdir().unwrap();
+    let (_pool, engine, ads, _trackers) = build_engine(dir.path()).await;
+
+    engine.peek("ads-only.example", DEFAULT_GROUP);
+    engine.check("ads-only.example", DEFAULT_GROUP);
+
+    assert_eq!(stats_for(&engine, ads).unique_blocks, 1);
+}
@@ -0,0 +1,124 @@
+use ferrous_dns_application::ports::{BlockFilterEnginePort, FilterDecision, FilterRule};
+use ferrous_dns_domain::config::DatabaseConfig;
+use ferrous_dns_domain::BlockSource;
+use ferrous_dns_infrastructure::database::create_write_pool;
+use ferrous_dns_infrastructure::dns::BlockFilterEngine;
+use ferrous_dns_infrastructure::schedule::ScheduleStateStore;
+use sqlx::SqlitePool;
+use std::path::Path;
+use std::sync::Arc;
+
+const DEFAULT_GROUP: i64 = 1;
+
+async fn create_pool(dir: &Path) -> SqlitePool {
+    let url = format!("sqlite:{}", dir.join("test.db").display());
+    create_write_pool(&url, &DatabaseConfig::default())
+        .await
+        .unwrap()
+}
+
+async fn add_group(pool: &SqlitePool, name: &str) -> i64 {
+    sqlx::query_scalar("INSERT INTO groups (name) VALUES (?) RETURNING id")
+        .bind(name)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+async fn set_policy(pool: &SqlitePool, group_id: i64, mode: &str, tlds: &str) {
+    sqlx::query(
+        "INSERT INTO group_tld_policies (group_id, mode, tlds, created_at, updated_at)
+         VALUES (?, ?, ?, '', '')",
+    )
+    .bind(group_id)
+    .bind(mode)
+    .bind(tlds)
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+async fn build_engine(pool: &SqlitePool) -> Arc<BlockFilterEngine> {
+    let engine = BlockFilterEngine::new(
+        pool.clone(),
+        DEFAULT_GROUP,
+        Arc::new(ScheduleStateStore::new()),
+        true,
+    )
+    .await
+    .unwrap();
+    engine.reload().await.unwrap();
+    engine
+}
+
+const TLD_BLOCK: FilterDecision = FilterDecision::Block(BlockSource::TldPolicy);
+
+#[tokio::test]
+async fn deny_policy_blocks_listed_tlds() {
+    let dir = tempfile::tempdir().unwrap();
+    let pool = create_pool(dir.path()).await;
+    set_policy(&pool, DEFAULT_GROUP, "deny", "zip,mov").await;
+    let engine = build_engine(&pool).await;
+
+    assert_eq!(engine.check("invoice.zip", DEFAULT_GROUP), TLD_BLOCK);
+    assert_eq!(engine.check("cdn.clip.mov", DEFAULT_GROUP), TLD_BLOCK);
+    assert_eq!(
+        engine.check("example.com", DEFAULT_GROUP),
+        FilterDecision::Allow
+    );
+    assert_eq!(engine.check("zip", DEFAULT_GROUP), FilterDecision::Allow);
+}
+
+#[tokio::test]
+async fn allow_policy_applies_only_to_its_group() {
+    let dir = tempfile::tempdir().unwrap();
+    let pool = create_pool(dir.path()).await;
+    let iot = add_group(&pool, "IoT").await;
+    set_policy(&pool, iot, "allow", "com,net").await;
+    let engine = build_engine(&pool).await;
+
+    assert_eq!(engine.check("api.vendor.com", iot), FilterDecision::Allow);
+    assert_eq!(engine.check("telemetry.vendor.io", iot), TLD_BLOCK);
+    assert_eq!(
+        engine.check("telemetry.vendor.io", DEFAULT_GROUP),
+        FilterDecision::Allow
+    );
+}
+
+#[tokio::test]
+async fn allowlisted_domain_overrides_tld_policy() {
+    let dir = tempfile::tempdir().unwrap();
+    let pool = create_pool(dir.path()).await;
+    set_policy(&pool, DEFAULT_GROUP, "deny", "zip").await;
+    sqlx::query(
+        "INSERT INTO managed_domains (name, domain, action, group_id, enabled, created_at, updated_at)
+         VALUES ('release', 'release.zip', 'allow', ?, 1, '', '')",
+    )
+    .bind(DEFAULT_GROUP)
+    .execute(&pool)
+    .await
+    .unwrap();
+    let engine = build_engine(&pool).await;
+
+    assert_eq!(
+        engine.check("release.zip", DEFAULT_GROUP),
+        FilterDecision::Allow
+    );
+    assert_eq!(engine.check("other.zip", DEFAULT_GROUP), TLD_BLOCK);
+}
+
+#[tokio::test]
+async fn explain_reports_tld_match() {
+    let dir = tempfile::tempdir().unwrap();
+    let pool = create_pool(dir.path()).await;
+    set_policy(&pool, DEFAULT_GROUP, "deny", "zip").await;
+    let engine = build_engine(&pool).await;
+
+    let explanation = engine.explain("invoice.zip", DEFAULT_GROUP);
+
+    assert_eq!(explanation.decision, TLD_BLOCK);
+    assert!(explanation
+        .blocklist
+        .iter()
+        .any(|m| m.source == BlockSource::TldPolicy && m.rule == FilterRule::Tld));
+}
@@ -0,0 +1,202 @@
+use ferrous_dns_application::ports::{BlockFilterEnginePort, FilterDecision};
+use ferrous_dns_domain::config::DatabaseConfig;
+use ferrous_dns_infrastructure::database::create_write_pool;
+use ferrous_dns_infrastructure::dns::BlockFilterEngine;
+use ferrous_dns_infrastructure::schedule::ScheduleStateStore;
+use sqlx::SqlitePool;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+const DEFAULT_GROUP: i64 = 1;
+
+/// Serves `body` for every request until `serving` is cleared; afterwards
+/// requests hang, so background fetches never complete during a test.
+struct ListServer {
+    base_url: String,
+    serving: Arc<AtomicBool>,
+}
+
+async fn start_list_server(body: &'static str) -> ListServer {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let serving = Arc::new(AtomicBool::new(true));
+    let flag = Arc::clone(&serving);
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let flag = Arc::clone(&flag);
+            tokio::spawn(async move {
+                let mut request = [0u8; 1024];
+                let _ = stream.read(&mut request).await;
+                if !flag.load(Ordering::SeqCst) {
+                    std::future::pending::<()>().await;
+                }
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+            });
+        }
+    });
+    ListServer { base_url, serving }
+}
+
+async fn create_pool(dir: &Path) -> SqlitePool {
+    let url = format!("sqlite:{}", dir.join("test.db").display());
+    create_write_pool(&url, &DatabaseConfig::default())
+        .await
+        .unwrap()
+}
+
+async fn add_source(pool: &SqlitePool, name: &str, url: &str) {
+    let id: i64 = sqlx::query_scalar(
+        "INSERT INTO blocklist_sources (name, url, group_id, enabled)
+         VALUES (?, ?, ?, 1) RETURNING id",
+    )
+    .bind(name)
+    .bind(url)
+    .bind(DEFAULT_GROUP)
+    .fetch_one(pool)
+    .await
+    .unwrap();
+    sqlx::query("INSERT INTO blocklist_source_groups (source_id, group_id) VALUES (?, ?)")
+        .bind(id)
+        .bind(DEFAULT_GROUP)
+        .execute(pool)
+        .await
+        .unwrap();
+}
+
+async fn build_engine(pool: &SqlitePool, snapshot: &Path) -> Arc<BlockFilterEngine> {
+    BlockFilterEngine::with_index_snapshot(
+        pool.clone(),
+        DEFAULT_GROUP,
+        Arc::new(ScheduleStateStore::new()),
+        true,
+        Some(snapshot.to_path_buf()),
+    )
+    .await
+    .unwrap()
+}
+
+fn is_blocked(engine: &BlockFilterEngine, domain: &str) -> bool {
+    matches!(
+        engine.check(domain, DEFAULT_GROUP),
+        FilterDecision::Block(_)
+    )
+}
+
+/// Lets the startup compile fetch `url` from the server, leaving the
+/// snapshot and the table cache on disk, then stops the server answering.
+async fn prime_cache(
+    dir: &Path,
+    pool: &SqlitePool,
+    server: &ListServer,
+    url: &str,
+) -> (PathBuf, PathBuf) {
+    let snapshot = dir.join("blocklist-index.snapshot");
+    let tables = dir.join("blocklist-index.tables");
+
+    let engine = build_engine(pool, &snapshot).await;
+    for _ in 0..250 {
+        if snapshot.exists() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert!(is_blocked(&engine, "ads.example"));
+    assert!(tables.exists());
+
+    server.serving.store(false, Ordering::SeqCst);
+    // Later runs must take their entries from disk, not the old lists.
+    std::fs::write(
+        &snapshot,
+        format!("!ferrous-dns list snapshot v1\n!source {url}\nother.example\n"),
+    )
+    .unwrap();
+    (snapshot, tables)
+}
+
+#[tokio::test]
+async fn unchanged_sources_start_from_cached_tables() {
+    let server = start_list_server("ads.example\n").await;
+    let url = format!("{}/ads.txt", server.base_url);
+    let dir = tempfile::tempdir().unwrap();
+    let pool = create_pool(dir.path()).await;
+    add_source(&pool, "ads", &url).await;
+    let (snapshot, _) = prime_cache(dir.path(), &pool, &server, &url).await;
+
+    let engine = build_engine(&pool, &snapshot).await;
+
+    assert!(is_blocked(&engine, "ads.example"));
+    assert!(!is_blocked(&engine, "other.example"));
+}
+
+#[tokio::test]
+async fn corrupt_cache_is_rebuilt_from_snapshot() {
+    let server = start_list_server("ads.example\n").await;
+    let url = format!("{}/ads.txt", server.base_url);
+    let dir = tempfile::tempdir().unwrap();
+    let pool = create_pool(dir.path()).await;
+    add_source(&pool, "ads", &url).await;
+    let (snapshot, tables) = prime_cache(dir.path(), &pool, &server, &url).await;
+
+    let mut data = std::fs::read(&tables).unwrap();
+    let middle = data.len() / 2;
+    data[middle] ^= 0xff;
+    std::fs::write(&tables, data).unwrap();
+
+    let engine = build_engine(&pool, &snapshot).await;
+
+    assert!(is_blocked(&engine, "other.example"));
+    assert!(!is_blocked(&engine, "ads.example"));
+}
+
+#[tokio::test]
+async fn changed_sources_are_rebuilt_from_snapshot() {
+    let server = start_list_server("ads.example\n").await;
+    let url = format!("{}/ads.txt", server.base_url);
+    let dir = tempfile::tempdir().unwrap();
+    let pool = create_pool(dir.path()).await;
+    add_source(&pool, "ads", &url).await;
+    let (snapshot, _) = prime_cache(dir.path(), &pool, &server, &url).await;
+
+    add_source(
+        &pool,
+        "trackers",
+        &format!("{}/trackers.txt", server.base_url),
+    )
+    .await;
+    let engine = build_engine(&pool, &snapshot).await;
+
+    assert!(is_blocked(&engine, "other.example"));
+    assert!(!is_blocked(&engine, "ads.example"));
+}
+
+#[tokio::test]
+async fn unchanged_content_keeps_cache_file() {
+    let server = start_list_server("ads.example\n*.tracker.example\n/adserv/\n").await;
+    let url = format!("{}/ads.txt", server.base_url);
+    let dir = tempfile::tempdir().unwrap();
+    let pool = create_pool(dir.path()).await;
+    add_source(&pool, "ads", &url).await;
+    let snapshot = dir.path().join("blocklist-index.snapshot");
+    let tables = dir.path().join("blocklist-index.tables");
+
+    let engine = build_engine(&pool, &snapshot).await;
+    engine.reload().await.unwrap();
+    let written = std::fs::metadata(&tables).unwrap().modified().unwrap();
+
+    engine.reload().await.unwrap();
+
+    assert_eq!(
+        std::fs::metadata(&tables).unwrap().modified().unwrap(),
+        written
+    );
+    assert!(is_blocked(&engine, "ads.example"));
+    assert!(is_blocked(&engine, "cdn.tracker.example"));
+    assert!(is_blocked(&engine, "img.adserv.example"));
+}
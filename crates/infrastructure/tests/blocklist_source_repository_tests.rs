@@ -20,6 +20,9 @@ async fn create_test_db() -> SqlitePool {
             enabled BOOLEAN NOT NULL DEFAULT 1,
             comment TEXT,
             is_default BOOLEAN NOT NULL DEFAULT 0,
+            filter_aaaa BOOLEAN NOT NULL DEFAULT 0,
+            audit_mode  BOOLEAN NOT NULL DEFAULT 0,
+            tenant_id INTEGER,
             created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
             updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
         )",
@@ -36,6 +39,8 @@ async fn create_test_db() -> SqlitePool {
             group_id    INTEGER NOT NULL DEFAULT 1 REFERENCES groups(id) ON DELETE RESTRICT,
             comment     TEXT,
             enabled     BOOLEAN NOT NULL DEFAULT 1,
+            audit_mode  BOOLEAN NOT NULL DEFAULT 0,
+            tenant_id   INTEGER,
             created_at  DATETIME DEFAULT CURRENT_TIMESTAMP,
             updated_at  DATETIME DEFAULT CURRENT_TIMESTAMP
         )",
@@ -208,7 +213,7 @@ async fn test_update_enabled_field() {
     let id = source.id.unwrap();
 
     let updated = repo
-        .update(id, None, None, None, None, Some(false))
+        .update(id, None, None, None, None, Some(false), None)
         .await
         .unwrap();
 
@@ -218,6 +223,30 @@ async fn test_update_enabled_field() {
     assert!(!fetched.enabled);
 }
 
+#[tokio::test]
+async fn test_update_audit_mode() {
+    let pool = create_test_db().await;
+    let repo = SqliteBlocklistSourceRepository::new(pool);
+
+    let source = repo
+        .create("Trial List".to_string(), None, vec![1], None, true)
+        .await
+        .unwrap();
+    let id = source.id.unwrap();
+    assert!(!source.audit_mode);
+
+    let updated = repo
+        .update(id, None, None, None, None, None, Some(true))
+        .await
+        .unwrap();
+
+    assert!(updated.audit_mode);
+    assert!(updated.enabled);
+
+    let fetched = repo.get_by_id(id).await.unwrap().unwrap();
+    assert!(fetched.audit_mode);
+}
+
 #[tokio::test]
 async fn test_update_name() {
     let pool = create_test_db().await;
@@ -236,6 +265,7 @@ async fn test_update_name() {
             None,
             None,
             None,
+            None,
         )
         .await
         .unwrap();
@@ -259,7 +289,15 @@ async fn test_update_group_ids() {
         .unwrap();
 
     let updated = repo
-        .update(source.id.unwrap(), None, None, Some(vec![2]), None, None)
+        .update(
+            source.id.unwrap(),
+            None,
+            None,
+            Some(vec![2]),
+            None,
+            None,
+            None,
+        )
         .await
         .unwrap();
 
@@ -286,7 +324,7 @@ async fn test_update_assign_multiple_groups() {
     let id = source.id.unwrap();
 
     let updated = repo
-        .update(id, None, None, Some(vec![1, 2]), None, None)
+        .update(id, None, None, Some(vec![1, 2]), None, None, None)
         .await
         .unwrap();
 
@@ -303,7 +341,9 @@ async fn test_update_not_found() {
     let pool = create_test_db().await;
     let repo = SqliteBlocklistSourceRepository::new(pool);
 
-    let result = repo.update(999, None, None, None, None, Some(false)).await;
+    let result = repo
+        .update(999, None, None, None, None, Some(false), None)
+        .await;
 
     assert!(result.is_err());
     let err_str = format!("{:?}", result.unwrap_err());
@@ -331,7 +371,7 @@ async fn test_update_clear_url() {
         .unwrap();
 
     let updated = repo
-        .update(source.id.unwrap(), None, Some(None), None, None, None)
+        .update(source.id.unwrap(), None, Some(None), None, None, None, None)
         .await
         .unwrap();
 
@@ -0,0 +1,148 @@
+use ferrous_dns_application::ports::{UpstreamAddressRefresh, UpstreamAddressRepository};
+use ferrous_dns_domain::{BootstrapConfig, UdpSocketMode, UpstreamPool, UpstreamStrategy};
+use ferrous_dns_infrastructure::dns::events::QueryEventEmitter;
+use ferrous_dns_infrastructure::dns::load_balancer::PoolManager;
+use ferrous_dns_infrastructure::dns::transport::BootstrapResolver;
+use ferrous_dns_infrastructure::repositories::SqliteUpstreamAddressRepository;
+use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_millis(300);
+
+async fn create_test_db() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::query(include_str!(
+        "../../../migrations/20260323000001_create_upstream_addresses.sql"
+    ))
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    pool
+}
+
+fn ip(s: &str) -> IpAddr {
+    s.parse().unwrap()
+}
+
+fn pinned_config(hostname: &str, addrs: &[&str]) -> BootstrapConfig {
+    BootstrapConfig {
+        pinned: HashMap::from([(
+            hostname.to_string(),
+            addrs.iter().map(|a| a.to_string()).collect(),
+        )]),
+        ..Default::default()
+    }
+}
+
+/// Bootstrap config whose only server never answers, so every lookup fails.
+fn unreachable_config() -> BootstrapConfig {
+    BootstrapConfig {
+        servers: vec!["127.0.0.1:9".into()],
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_repository_round_trips_addresses() {
+    let repo = SqliteUpstreamAddressRepository::new(create_test_db().await);
+
+    repo.save("dns.example", &[ip("192.0.2.1"), ip("2001:db8::1")])
+        .await
+        .unwrap();
+    repo.save("dns.example", &[ip("192.0.2.2")]).await.unwrap();
+
+    let all = repo.get_all().await.unwrap();
+    assert_eq!(all.len(), 1);
+    assert_eq!(all["dns.example"], vec![ip("192.0.2.2")]);
+}
+
+#[tokio::test]
+async fn test_pinned_hostname_is_not_looked_up() {
+    let mut config = pinned_config("DNS.Example.", &["192.0.2.10", "2001:db8::10"]);
+    config.servers = unreachable_config().servers;
+    let resolver = BootstrapResolver::from_config(&config);
+
+    let addrs = resolver.resolve("dns.example", 853, TIMEOUT).await.unwrap();
+
+    assert_eq!(
+        addrs,
+        vec![
+            SocketAddr::new(ip("192.0.2.10"), 853),
+            SocketAddr::new(ip("2001:db8::10"), 853),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_failed_lookup_without_history_is_an_error() {
+    let resolver = BootstrapResolver::from_config(&unreachable_config());
+
+    assert!(resolver.resolve("dns.example", 853, TIMEOUT).await.is_err());
+}
+
+#[tokio::test]
+async fn test_failed_lookup_falls_back_to_persisted_addresses() {
+    let repo = Arc::new(SqliteUpstreamAddressRepository::new(create_test_db().await));
+    repo.save("dns.example", &[ip("192.0.2.20")]).await.unwrap();
+
+    let resolver = BootstrapResolver::from_config(&unreachable_config())
+        .with_store(repo)
+        .await;
+    let addrs = resolver.resolve("dns.example", 443, TIMEOUT).await.unwrap();
+
+    assert_eq!(addrs, vec![SocketAddr::new(ip("192.0.2.20"), 443)]);
+}
+
+#[tokio::test]
+async fn test_pool_manager_expands_hostnames_through_bootstrap() {
+    let pool = UpstreamPool {
+        name: "bootstrap".into(),
+        strategy: UpstreamStrategy::Parallel,
+        priority: 1,
+        servers: vec![
+            "tls://dns.example:853".into(),
+            "https://dns.example/dns-query".into(),
+        ],
+        weight: None,
+        tsig_key: None,
+        udp_socket: UdpSocketMode::Pooled,
+    };
+    let bootstrap = Arc::new(BootstrapResolver::from_config(&pinned_config(
+        "dns.example",
+        &["192.0.2.30", "192.0.2.31"],
+    )));
+
+    let pm = PoolManager::new_with_bootstrap(
+        vec![pool],
+        None,
+        QueryEventEmitter::new_disabled(),
+        bootstrap,
+    )
+    .await
+    .unwrap();
+
+    let protocols = pm.get_all_protocols();
+    assert_eq!(protocols.len(), 3, "two DoT addresses plus one DoH entry");
+    assert!(protocols.iter().all(|p| !p.needs_resolution()));
+    assert_eq!(
+        pm.get_all_servers(),
+        vec![
+            SocketAddr::new(ip("192.0.2.30"), 853),
+            SocketAddr::new(ip("192.0.2.31"), 853),
+        ]
+    );
+
+    assert_eq!(
+        pm.refresh_addresses().await,
+        UpstreamAddressRefresh::default(),
+        "unchanged pinned addresses leave the pool as it is"
+    );
+}
@@ -70,6 +70,9 @@ impl DnsResolver for CnameChainResolver {
             cname_chain,
             upstream_server: None,
             upstream_pool: None,
+            upstream_strategy: None,
+            upstream_attempt: None,
+            upstream_protocol: None,
             min_ttl: Some(300),
             negative_soa_ttl: None,
             upstream_wire_data: None,
@@ -94,6 +97,7 @@ fn make_cache() -> Arc<dyn DnsCacheAccess> {
         refresh_sample_rate: 1.0,
         min_ttl: 0,
         max_ttl: 86_400,
+        l1_capacity: 1024,
     }))
 }
 
@@ -101,6 +105,7 @@ fn make_query(domain: &str) -> DnsQuery {
     DnsQuery {
         domain: Arc::from(domain),
         record_type: RecordType::A,
+        cache_partition: 0,
     }
 }
 
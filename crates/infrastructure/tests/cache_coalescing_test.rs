@@ -69,6 +69,7 @@ fn make_cache() -> Arc<dyn DnsCacheAccess> {
         refresh_sample_rate: 1.0,
         min_ttl: 0,
         max_ttl: 86_400,
+        l1_capacity: 1024,
     }))
 }
 
@@ -76,6 +77,7 @@ fn make_query(domain: &str, record_type: RecordType) -> DnsQuery {
     DnsQuery {
         domain: Arc::from(domain),
         record_type,
+        cache_partition: 0,
     }
 }
 
@@ -0,0 +1,148 @@
+use ferrous_dns_application::ports::{CacheExportData, CacheExportEntry};
+use ferrous_dns_domain::RecordType;
+use ferrous_dns_infrastructure::dns::{
+    CachedAddresses, CachedData, DnsCache, DnsCacheConfig, DnssecStatus, EvictionStrategy,
+};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+fn make_ip_data(ip: &str) -> CachedData {
+    let addr: IpAddr = ip.parse().unwrap();
+    CachedData::IpAddresses(CachedAddresses {
+        addresses: Arc::new(vec![addr]),
+    })
+}
+
+fn create_cache() -> DnsCache {
+    DnsCache::new(DnsCacheConfig {
+        max_entries: 1000,
+        eviction_strategy: EvictionStrategy::HitRate,
+        min_threshold: 0.0,
+        refresh_threshold: 0.75,
+        batch_eviction_percentage: 0.2,
+        adaptive_thresholds: false,
+        min_frequency: 0,
+        min_lfuk_score: 0.0,
+        shard_amount: 4,
+        access_window_secs: 7200,
+        eviction_sample_size: 8,
+        lfuk_k_value: 0.5,
+        refresh_sample_rate: 1.0,
+        min_ttl: 0,
+        max_ttl: 86_400,
+        l1_capacity: 1024,
+    })
+}
+
+fn hit(cache: &DnsCache, domain: &str, times: usize) {
+    for _ in 0..times {
+        assert!(cache.get(domain, &RecordType::A).is_some());
+    }
+}
+
+#[test]
+fn export_returns_the_most_hit_entries_first() {
+    let cache = create_cache();
+    for domain in ["a.example", "b.example", "c.example"] {
+        cache.insert(domain, RecordType::A, make_ip_data("10.0.0.1"), 300, None);
+    }
+    hit(&cache, "a.example", 5);
+    hit(&cache, "b.example", 20);
+
+    let exported = cache.export_entries(2);
+
+    let domains: Vec<&str> = exported.iter().map(|e| e.domain.as_str()).collect();
+    assert_eq!(domains, vec!["b.example", "a.example"]);
+    assert_eq!(exported[0].record_type, "A");
+    assert!(exported[0].remaining_ttl > 0 && exported[0].remaining_ttl <= 300);
+}
+
+#[test]
+fn export_skips_local_and_partitioned_entries() {
+    let cache = create_cache();
+    cache.insert_permanent("nas.lan", RecordType::A, make_ip_data("192.168.1.10"), None);
+    cache.insert_partitioned(
+        "kids.example",
+        RecordType::A,
+        7,
+        make_ip_data("10.0.0.7"),
+        300,
+        None,
+    );
+    cache.insert(
+        "shared.example",
+        RecordType::A,
+        make_ip_data("10.0.0.1"),
+        300,
+        None,
+    );
+
+    let exported = cache.export_entries(10);
+
+    assert_eq!(exported.len(), 1);
+    assert_eq!(exported[0].domain, "shared.example");
+}
+
+#[test]
+fn exported_entries_import_into_another_cache() {
+    let primary = create_cache();
+    primary.insert(
+        "signed.example",
+        RecordType::A,
+        make_ip_data("10.0.0.1"),
+        300,
+        Some(DnssecStatus::Secure),
+    );
+    primary.insert(
+        "www.example",
+        RecordType::CNAME,
+        CachedData::CanonicalName(Arc::from("cdn.example")),
+        300,
+        None,
+    );
+    let exported = primary.export_entries(10);
+    let json = serde_json::to_vec(&exported).unwrap();
+    let received: Vec<CacheExportEntry> = serde_json::from_slice(&json).unwrap();
+
+    let standby = create_cache();
+    assert_eq!(standby.import_entries(&received), 2);
+
+    let (data, dnssec, _) = standby.get("signed.example", &RecordType::A).unwrap();
+    assert!(matches!(data, CachedData::IpAddresses(_)));
+    assert_eq!(dnssec, Some(DnssecStatus::Secure));
+    assert!(standby.get("www.example", &RecordType::CNAME).is_some());
+}
+
+#[test]
+fn import_never_overrides_local_records() {
+    let cache = create_cache();
+    cache.insert_permanent("nas.lan", RecordType::A, make_ip_data("192.168.1.10"), None);
+    let entries = vec![
+        CacheExportEntry {
+            domain: "nas.lan".to_string(),
+            record_type: "A".to_string(),
+            data: CacheExportData::Addresses(vec!["10.9.9.9".parse().unwrap()]),
+            remaining_ttl: 300,
+            dnssec_status: None,
+        },
+        CacheExportEntry {
+            domain: "odd.example".to_string(),
+            record_type: "NOT-A-TYPE".to_string(),
+            data: CacheExportData::Addresses(vec!["10.9.9.9".parse().unwrap()]),
+            remaining_ttl: 300,
+            dnssec_status: None,
+        },
+    ];
+
+    assert_eq!(cache.import_entries(&entries), 0);
+    let (data, _, _) = cache.get("nas.lan", &RecordType::A).unwrap();
+    match data {
+        CachedData::IpAddresses(cached) => {
+            assert_eq!(
+                cached.addresses[0],
+                "192.168.1.10".parse::<IpAddr>().unwrap()
+            )
+        }
+        other => panic!("unexpected {other:?}"),
+    }
+}
@@ -0,0 +1,183 @@
+use ferrous_dns_domain::RecordType;
+use ferrous_dns_infrastructure::dns::dnssec::DsRecord;
+use ferrous_dns_infrastructure::dns::{
+    CachedAddresses, CachedData, DnsCache, DnsCacheConfig, EvictionStrategy,
+};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+fn make_ip_data(ip: &str) -> CachedData {
+    let addr: IpAddr = ip.parse().unwrap();
+    CachedData::IpAddresses(CachedAddresses {
+        addresses: Arc::new(vec![addr]),
+    })
+}
+
+fn create_cache() -> DnsCache {
+    DnsCache::new(DnsCacheConfig {
+        max_entries: 100,
+        eviction_strategy: EvictionStrategy::HitRate,
+        min_threshold: 0.0,
+        refresh_threshold: 0.75,
+        batch_eviction_percentage: 0.2,
+        adaptive_thresholds: false,
+        min_frequency: 0,
+        min_lfuk_score: 0.0,
+        shard_amount: 4,
+        access_window_secs: 7200,
+        eviction_sample_size: 8,
+        lfuk_k_value: 0.5,
+        refresh_sample_rate: 1.0,
+        min_ttl: 0,
+        max_ttl: 86_400,
+        l1_capacity: 1024,
+    })
+}
+
+fn ds_record() -> DsRecord {
+    DsRecord {
+        key_tag: 12345,
+        algorithm: 8,
+        digest_type: 2,
+        digest: vec![0xAB; 32],
+    }
+}
+
+#[test]
+fn test_flush_suffix_removes_all_record_types_below_the_suffix() {
+    let cache = create_cache();
+    cache.insert(
+        "example.com",
+        RecordType::A,
+        make_ip_data("10.0.0.1"),
+        300,
+        None,
+    );
+    cache.insert(
+        "www.example.com",
+        RecordType::A,
+        make_ip_data("10.0.0.2"),
+        300,
+        None,
+    );
+    cache.insert(
+        "www.example.com",
+        RecordType::AAAA,
+        make_ip_data("2001:db8::1"),
+        300,
+        None,
+    );
+    cache.insert_partitioned(
+        "kids.example.com",
+        RecordType::A,
+        7,
+        make_ip_data("10.0.0.7"),
+        300,
+        None,
+    );
+    cache.insert(
+        "other.net",
+        RecordType::A,
+        make_ip_data("10.0.0.9"),
+        300,
+        None,
+    );
+
+    assert_eq!(cache.flush_suffix("example.com"), 4);
+
+    assert!(cache.get("example.com", &RecordType::A).is_none());
+    assert!(cache.get("www.example.com", &RecordType::A).is_none());
+    assert!(cache.get("www.example.com", &RecordType::AAAA).is_none());
+    assert!(cache
+        .get_partitioned("kids.example.com", &RecordType::A, 7)
+        .is_none());
+    assert!(cache.get("other.net", &RecordType::A).is_some());
+}
+
+#[test]
+fn test_flush_suffix_matches_whole_labels_only() {
+    let cache = create_cache();
+    cache.insert(
+        "badexample.com",
+        RecordType::A,
+        make_ip_data("10.0.0.1"),
+        300,
+        None,
+    );
+    cache.insert(
+        "example.com.evil",
+        RecordType::A,
+        make_ip_data("10.0.0.2"),
+        300,
+        None,
+    );
+
+    assert_eq!(cache.flush_suffix("example.com"), 0);
+    assert!(cache.get("badexample.com", &RecordType::A).is_some());
+    assert!(cache.get("example.com.evil", &RecordType::A).is_some());
+}
+
+#[test]
+fn test_flush_suffix_normalizes_case_and_trailing_dot() {
+    let cache = create_cache();
+    cache.insert(
+        "www.example.com",
+        RecordType::A,
+        make_ip_data("10.0.0.1"),
+        300,
+        None,
+    );
+
+    assert_eq!(cache.flush_suffix("Example.COM."), 1);
+    assert!(cache.get("www.example.com", &RecordType::A).is_none());
+}
+
+#[test]
+fn test_flush_suffix_clears_negative_and_dnssec_entries() {
+    let cache = create_cache();
+    cache.insert(
+        "gone.example.com",
+        RecordType::A,
+        CachedData::NegativeResponse,
+        300,
+        None,
+    );
+    cache.insert(
+        "gone.other.net",
+        RecordType::A,
+        CachedData::NegativeResponse,
+        300,
+        None,
+    );
+    let dnssec = cache.dnssec_cache();
+    dnssec.cache_ds("example.com", vec![ds_record()], 300);
+    dnssec.cache_ds("other.net", vec![ds_record()], 300);
+
+    assert_eq!(cache.flush_suffix("example.com"), 2);
+
+    assert!(cache.get("gone.example.com", &RecordType::A).is_none());
+    assert!(cache.get("gone.other.net", &RecordType::A).is_some());
+    assert!(dnssec.get_ds("example.com").is_none());
+    assert!(dnssec.get_ds("other.net").is_some());
+}
+
+#[test]
+fn test_flush_suffix_keeps_local_records() {
+    let cache = create_cache();
+    cache.insert_permanent(
+        "nas.example.com",
+        RecordType::A,
+        make_ip_data("192.168.1.10"),
+        None,
+    );
+    cache.insert(
+        "www.example.com",
+        RecordType::A,
+        make_ip_data("10.0.0.1"),
+        300,
+        None,
+    );
+
+    assert_eq!(cache.flush_suffix("example.com"), 1);
+    assert!(cache.get("nas.example.com", &RecordType::A).is_some());
+}
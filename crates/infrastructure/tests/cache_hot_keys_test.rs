@@ -0,0 +1,145 @@
+use ferrous_dns_application::ports::DnsCachePort;
+use ferrous_dns_domain::RecordType;
+use ferrous_dns_infrastructure::dns::{
+    CachedAddresses, CachedData, DnsCache, DnsCacheConfig, EvictionStrategy,
+};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+fn make_ip_data(ip: &str) -> CachedData {
+    let addr: IpAddr = ip.parse().unwrap();
+    CachedData::IpAddresses(CachedAddresses {
+        addresses: Arc::new(vec![addr]),
+    })
+}
+
+fn create_cache() -> DnsCache {
+    DnsCache::new(DnsCacheConfig {
+        max_entries: 1000,
+        eviction_strategy: EvictionStrategy::HitRate,
+        min_threshold: 0.0,
+        refresh_threshold: 0.75,
+        batch_eviction_percentage: 0.2,
+        adaptive_thresholds: false,
+        min_frequency: 0,
+        min_lfuk_score: 0.0,
+        shard_amount: 4,
+        access_window_secs: 7200,
+        eviction_sample_size: 8,
+        lfuk_k_value: 0.5,
+        refresh_sample_rate: 1.0,
+        min_ttl: 0,
+        max_ttl: 86_400,
+        l1_capacity: 1024,
+    })
+}
+
+fn hit(cache: &DnsCache, domain: &str, times: usize) {
+    for _ in 0..times {
+        assert!(cache.get(domain, &RecordType::A).is_some());
+    }
+}
+
+#[test]
+fn hot_keys_are_ranked_by_hits() {
+    let cache = create_cache();
+    for domain in ["a.example", "b.example", "c.example"] {
+        cache.insert(domain, RecordType::A, make_ip_data("10.0.0.1"), 300, None);
+    }
+
+    hit(&cache, "a.example", 50);
+    hit(&cache, "b.example", 10);
+    hit(&cache, "c.example", 1);
+
+    let top = cache.hot_keys(2);
+    assert_eq!(top.len(), 2);
+    assert_eq!(top[0].key.domain.as_str(), "a.example");
+    assert_eq!(top[1].key.domain.as_str(), "b.example");
+    assert!(top[0].hits >= 50);
+    assert!(top[1].hits >= 10);
+}
+
+#[test]
+fn snapshot_reports_total_hits_in_the_window() {
+    let cache = create_cache();
+    cache.insert(
+        "a.example",
+        RecordType::A,
+        make_ip_data("10.0.0.1"),
+        300,
+        None,
+    );
+    cache.insert(
+        "b.example",
+        RecordType::A,
+        make_ip_data("10.0.0.2"),
+        300,
+        None,
+    );
+
+    hit(&cache, "a.example", 30);
+    hit(&cache, "b.example", 10);
+
+    let snapshot = DnsCachePort::hot_keys(&cache, 10);
+    assert_eq!(snapshot.window_secs, 60);
+    assert_eq!(snapshot.total_hits, 40);
+    assert_eq!(snapshot.keys[0].domain, "a.example");
+    assert_eq!(snapshot.keys[0].record_type, RecordType::A);
+}
+
+#[test]
+fn dominant_key_surfaces_among_many_cold_keys() {
+    let cache = create_cache();
+    for i in 0..300 {
+        let domain = format!("host{i}.example");
+        cache.insert(&domain, RecordType::A, make_ip_data("10.0.0.1"), 300, None);
+        hit(&cache, &domain, 2);
+    }
+    cache.insert(
+        "hot.example",
+        RecordType::A,
+        make_ip_data("10.0.0.9"),
+        300,
+        None,
+    );
+    hit(&cache, "hot.example", 200);
+
+    let top = cache.hot_keys(1);
+    assert_eq!(top[0].key.domain.as_str(), "hot.example");
+}
+
+#[test]
+fn clear_forgets_hot_keys() {
+    let cache = create_cache();
+    cache.insert(
+        "a.example",
+        RecordType::A,
+        make_ip_data("10.0.0.1"),
+        300,
+        None,
+    );
+    hit(&cache, "a.example", 5);
+
+    cache.clear();
+
+    assert!(cache.hot_keys(10).is_empty());
+    assert_eq!(DnsCachePort::hot_keys(&cache, 10).total_hits, 0);
+}
+
+#[test]
+fn shard_occupancy_covers_every_entry() {
+    let cache = create_cache();
+    for i in 0..100 {
+        cache.insert(
+            &format!("host{i}.example"),
+            RecordType::A,
+            make_ip_data("10.0.0.1"),
+            300,
+            None,
+        );
+    }
+
+    let shards = cache.shard_occupancy();
+    assert_eq!(shards.len(), 4);
+    assert_eq!(shards.iter().sum::<usize>(), 100);
+}
@@ -0,0 +1,112 @@
+use ferrous_dns_domain::RecordType;
+use ferrous_dns_infrastructure::dns::{
+    CachedAddresses, CachedData, DnsCache, DnsCacheConfig, EvictionStrategy,
+};
+use std::net::IpAddr;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+fn make_ip_data(ip: &str) -> CachedData {
+    let addr: IpAddr = ip.parse().unwrap();
+    CachedData::IpAddresses(CachedAddresses {
+        addresses: Arc::new(vec![addr]),
+    })
+}
+
+fn create_cache(l1_capacity: usize) -> DnsCache {
+    DnsCache::new(DnsCacheConfig {
+        max_entries: 100,
+        eviction_strategy: EvictionStrategy::HitRate,
+        min_threshold: 0.0,
+        refresh_threshold: 0.75,
+        batch_eviction_percentage: 0.2,
+        adaptive_thresholds: false,
+        min_frequency: 0,
+        min_lfuk_score: 0.0,
+        shard_amount: 4,
+        access_window_secs: 7200,
+        eviction_sample_size: 8,
+        lfuk_k_value: 0.5,
+        refresh_sample_rate: 1.0,
+        min_ttl: 0,
+        max_ttl: 86_400,
+        l1_capacity,
+    })
+}
+
+#[test]
+fn repeated_address_lookups_are_counted_as_l1_hits() {
+    let cache = create_cache(1024);
+    cache.insert(
+        "l1-enabled.example",
+        RecordType::A,
+        make_ip_data("10.0.0.1"),
+        300,
+        None,
+    );
+
+    for _ in 0..10 {
+        assert!(cache.get("l1-enabled.example", &RecordType::A).is_some());
+    }
+
+    // Removals in the other tests invalidate L1 at most twice.
+    let metrics = cache.metrics();
+    assert_eq!(metrics.hits.load(Ordering::Relaxed), 10);
+    assert!(metrics.l1_hits.load(Ordering::Relaxed) >= 8);
+}
+
+#[test]
+fn disabled_l1_serves_every_hit_from_l2() {
+    let cache = create_cache(0);
+    cache.insert(
+        "l1-disabled.example",
+        RecordType::A,
+        make_ip_data("10.0.0.1"),
+        300,
+        None,
+    );
+
+    for _ in 0..3 {
+        assert!(cache.get("l1-disabled.example", &RecordType::A).is_some());
+    }
+
+    let metrics = cache.metrics();
+    assert_eq!(metrics.hits.load(Ordering::Relaxed), 3);
+    assert_eq!(metrics.l1_hits.load(Ordering::Relaxed), 0);
+}
+
+#[test]
+fn removed_entry_is_not_served_from_l1() {
+    let cache = create_cache(1024);
+    cache.insert_permanent(
+        "nas.home.lan",
+        RecordType::A,
+        make_ip_data("192.168.1.10"),
+        None,
+    );
+    assert!(cache.get("nas.home.lan", &RecordType::A).is_some());
+
+    assert!(cache.remove("nas.home.lan", &RecordType::A));
+
+    assert!(cache.get("nas.home.lan", &RecordType::A).is_none());
+}
+
+#[test]
+fn removal_on_another_thread_invalidates_this_threads_l1() {
+    let cache = Arc::new(create_cache(1024));
+    cache.insert(
+        "printer.home.lan",
+        RecordType::A,
+        make_ip_data("192.168.1.20"),
+        300,
+        None,
+    );
+    assert!(cache.get("printer.home.lan", &RecordType::A).is_some());
+
+    let remote = Arc::clone(&cache);
+    std::thread::spawn(move || remote.remove("printer.home.lan", &RecordType::A))
+        .join()
+        .unwrap();
+
+    assert!(cache.get("printer.home.lan", &RecordType::A).is_none());
+}
@@ -53,6 +53,7 @@ fn make_cache() -> Arc<dyn DnsCacheAccess> {
         refresh_sample_rate: 1.0,
         min_ttl: 0,
         max_ttl: 86_400,
+        l1_capacity: 1024,
     }))
 }
 
@@ -60,6 +61,7 @@ fn make_query(domain: &str) -> DnsQuery {
     DnsQuery {
         domain: Arc::from(domain),
         record_type: RecordType::A,
+        cache_partition: 0,
     }
 }
 
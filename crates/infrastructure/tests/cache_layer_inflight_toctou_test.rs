@@ -145,6 +145,7 @@ fn make_inner_cache() -> Arc<dyn DnsCacheAccess> {
         refresh_sample_rate: 1.0,
         min_ttl: 0,
         max_ttl: 86_400,
+        l1_capacity: 1024,
     }))
 }
 
@@ -152,6 +153,7 @@ fn make_query(domain: &str, record_type: RecordType) -> DnsQuery {
     DnsQuery {
         domain: Arc::from(domain),
         record_type,
+        cache_partition: 0,
     }
 }
 
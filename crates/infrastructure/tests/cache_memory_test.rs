@@ -0,0 +1,167 @@
+use bytes::Bytes;
+use ferrous_dns_application::ports::DnsCachePort;
+use ferrous_dns_domain::RecordType;
+use ferrous_dns_infrastructure::dns::{
+    CachedAddresses, CachedData, DnsCache, DnsCacheConfig, EvictionStrategy,
+};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+const MB: u64 = 1024 * 1024;
+
+fn make_ip_data(ip: &str) -> CachedData {
+    let addr: IpAddr = ip.parse().unwrap();
+    CachedData::IpAddresses(CachedAddresses {
+        addresses: Arc::new(vec![addr]),
+    })
+}
+
+fn make_wire_data(len: usize) -> CachedData {
+    CachedData::WireData(Bytes::from(vec![0u8; len]))
+}
+
+fn create_cache() -> DnsCache {
+    DnsCache::new(DnsCacheConfig {
+        max_entries: 100_000,
+        eviction_strategy: EvictionStrategy::HitRate,
+        min_threshold: 0.0,
+        refresh_threshold: 0.75,
+        batch_eviction_percentage: 0.2,
+        adaptive_thresholds: false,
+        min_frequency: 0,
+        min_lfuk_score: 0.0,
+        shard_amount: 4,
+        access_window_secs: 7200,
+        eviction_sample_size: 8,
+        lfuk_k_value: 0.5,
+        refresh_sample_rate: 1.0,
+        min_ttl: 0,
+        max_ttl: 86_400,
+        l1_capacity: 1024,
+    })
+}
+
+#[test]
+fn memory_tracks_inserts_replacements_and_removals() {
+    let cache = create_cache();
+    assert_eq!(cache.memory_bytes(), 0);
+
+    cache.insert(
+        "txt.example",
+        RecordType::TXT,
+        make_wire_data(4096),
+        300,
+        None,
+    );
+    let one_entry = cache.memory_bytes();
+    assert!(one_entry > 4096);
+
+    cache.insert(
+        "txt.example",
+        RecordType::TXT,
+        make_wire_data(4096),
+        300,
+        None,
+    );
+    assert_eq!(cache.memory_bytes(), one_entry);
+
+    cache.insert(
+        "txt.example",
+        RecordType::TXT,
+        make_wire_data(1024),
+        300,
+        None,
+    );
+    assert_eq!(cache.memory_bytes(), one_entry - 3072);
+
+    assert!(cache.remove("txt.example", &RecordType::TXT));
+    assert_eq!(cache.memory_bytes(), 0);
+}
+
+#[test]
+fn refreshed_payload_is_accounted() {
+    let cache = create_cache();
+    cache.insert("mx.example", RecordType::MX, make_wire_data(100), 300, None);
+    let before = cache.memory_bytes();
+
+    assert!(cache.refresh_record(
+        "mx.example",
+        &RecordType::MX,
+        Some(300),
+        make_wire_data(600),
+        None,
+    ));
+
+    assert_eq!(cache.memory_bytes(), before + 500);
+}
+
+#[test]
+fn clear_resets_memory() {
+    let cache = create_cache();
+    cache.insert(
+        "a.example",
+        RecordType::A,
+        make_ip_data("10.0.0.1"),
+        300,
+        None,
+    );
+    cache.insert(
+        "txt.example",
+        RecordType::TXT,
+        make_wire_data(512),
+        300,
+        None,
+    );
+
+    cache.clear();
+
+    assert_eq!(cache.memory_bytes(), 0);
+}
+
+#[test]
+fn memory_cap_evicts_large_entries_first() {
+    let cache = create_cache().with_max_memory_mb(1);
+    for i in 0..50 {
+        let domain = format!("host{i}.example");
+        cache.insert(&domain, RecordType::A, make_ip_data("10.0.0.1"), 300, None);
+    }
+    for i in 0..20 {
+        cache.insert(
+            &format!("txt{i}.example"),
+            RecordType::TXT,
+            make_wire_data(100 * 1024),
+            300,
+            None,
+        );
+    }
+    assert!(cache.memory_bytes() > MB);
+
+    cache.evict_entries();
+
+    assert!(cache.memory_bytes() <= MB);
+    for i in 0..50 {
+        assert!(
+            cache
+                .get_ttl(&format!("host{i}.example"), &RecordType::A)
+                .is_some(),
+            "small entry host{i} must survive memory eviction"
+        );
+    }
+}
+
+#[test]
+fn metrics_snapshot_reports_memory() {
+    let cache = create_cache().with_max_memory_mb(8);
+    cache.insert(
+        "txt.example",
+        RecordType::TXT,
+        make_wire_data(2048),
+        300,
+        None,
+    );
+
+    let snapshot = cache.cache_metrics_snapshot();
+
+    assert_eq!(snapshot.memory_bytes, cache.memory_bytes());
+    assert_eq!(snapshot.max_memory_bytes, 8 * MB);
+}
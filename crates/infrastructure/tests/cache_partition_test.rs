@@ -0,0 +1,228 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{DnsResolution, DnsResolver};
+use ferrous_dns_domain::{DnsQuery, DomainError, RecordType};
+use ferrous_dns_infrastructure::dns::resolver::CachedResolver;
+use ferrous_dns_infrastructure::dns::{
+    CachedAddresses, CachedData, DnsCache, DnsCacheAccess, DnsCacheConfig, EvictionStrategy,
+    NegativeQueryTracker,
+};
+use std::net::IpAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+fn make_ip_data(ip: &str) -> CachedData {
+    let addr: IpAddr = ip.parse().unwrap();
+    CachedData::IpAddresses(CachedAddresses {
+        addresses: Arc::new(vec![addr]),
+    })
+}
+
+fn create_cache() -> DnsCache {
+    DnsCache::new(DnsCacheConfig {
+        max_entries: 100,
+        eviction_strategy: EvictionStrategy::HitRate,
+        min_threshold: 0.0,
+        refresh_threshold: 0.75,
+        batch_eviction_percentage: 0.2,
+        adaptive_thresholds: false,
+        min_frequency: 0,
+        min_lfuk_score: 0.0,
+        shard_amount: 4,
+        access_window_secs: 7200,
+        eviction_sample_size: 8,
+        lfuk_k_value: 0.5,
+        refresh_sample_rate: 1.0,
+        min_ttl: 0,
+        max_ttl: 86_400,
+        l1_capacity: 1024,
+    })
+}
+
+fn addresses(data: CachedData) -> Vec<IpAddr> {
+    match data {
+        CachedData::IpAddresses(entry) => entry.addresses.as_ref().clone(),
+        other => panic!("expected addresses, got {other:?}"),
+    }
+}
+
+/// Answers every query with a fixed address and counts upstream calls.
+struct CountingResolver {
+    calls: AtomicUsize,
+}
+
+#[async_trait]
+impl DnsResolver for CountingResolver {
+    async fn resolve(&self, _query: &DnsQuery) -> Result<DnsResolution, DomainError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        Ok(DnsResolution {
+            min_ttl: Some(300),
+            ..DnsResolution::new(vec!["10.0.0.1".parse().unwrap()], false)
+        })
+    }
+}
+
+#[test]
+fn partitions_do_not_share_entries() {
+    let cache = create_cache();
+    cache.insert(
+        "shared.example",
+        RecordType::A,
+        make_ip_data("10.0.0.1"),
+        300,
+        None,
+    );
+    cache.insert_partitioned(
+        "kids.example",
+        RecordType::A,
+        7,
+        make_ip_data("10.0.0.7"),
+        300,
+        None,
+    );
+
+    assert!(cache
+        .get_partitioned("shared.example", &RecordType::A, 7)
+        .is_none());
+    assert!(cache.get("kids.example", &RecordType::A).is_none());
+    assert!(cache
+        .get_partitioned("kids.example", &RecordType::A, 3)
+        .is_none());
+
+    let (data, _, _) = cache
+        .get_partitioned("kids.example", &RecordType::A, 7)
+        .expect("entry visible in its own partition");
+    assert_eq!(addresses(data), vec!["10.0.0.7".parse::<IpAddr>().unwrap()]);
+}
+
+#[test]
+fn same_name_holds_different_answers_per_partition() {
+    let cache = create_cache();
+    cache.insert(
+        "search.example",
+        RecordType::A,
+        make_ip_data("10.0.0.1"),
+        300,
+        None,
+    );
+    cache.insert_partitioned(
+        "search.example",
+        RecordType::A,
+        2,
+        make_ip_data("10.0.0.2"),
+        300,
+        None,
+    );
+
+    let (shared, _, _) = cache.get("search.example", &RecordType::A).unwrap();
+    let (partitioned, _, _) = cache
+        .get_partitioned("search.example", &RecordType::A, 2)
+        .unwrap();
+
+    assert_eq!(
+        addresses(shared),
+        vec!["10.0.0.1".parse::<IpAddr>().unwrap()]
+    );
+    assert_eq!(
+        addresses(partitioned),
+        vec!["10.0.0.2".parse::<IpAddr>().unwrap()]
+    );
+}
+
+#[test]
+fn negative_entries_are_partitioned() {
+    let cache = create_cache();
+    cache.insert_partitioned(
+        "missing.example",
+        RecordType::A,
+        4,
+        CachedData::NegativeResponse,
+        300,
+        None,
+    );
+
+    assert!(cache.get("missing.example", &RecordType::A).is_none());
+    assert!(cache
+        .get_partitioned("missing.example", &RecordType::A, 4)
+        .is_some());
+}
+
+#[test]
+fn remove_clears_every_partition() {
+    let cache = create_cache();
+    for partition in [0, 2, 5] {
+        cache.insert_partitioned(
+            "gone.example",
+            RecordType::A,
+            partition,
+            make_ip_data("10.0.0.1"),
+            300,
+            None,
+        );
+    }
+    assert_eq!(cache.len(), 3);
+
+    assert!(cache.remove("gone.example", &RecordType::A));
+
+    assert_eq!(cache.len(), 0);
+    assert_eq!(cache.memory_bytes(), 0);
+    for partition in [0, 2, 5] {
+        assert!(cache
+            .get_partitioned("gone.example", &RecordType::A, partition)
+            .is_none());
+    }
+}
+
+#[test]
+fn remove_keeps_other_entries_of_the_partitions() {
+    let cache = create_cache();
+    for (domain, record_type) in [
+        ("gone.example", RecordType::A),
+        ("gone.example", RecordType::AAAA),
+        ("kept.example", RecordType::A),
+    ] {
+        cache.insert_partitioned(domain, record_type, 3, make_ip_data("10.0.0.1"), 300, None);
+    }
+
+    assert!(cache.remove("gone.example", &RecordType::A));
+    assert!(!cache.remove("gone.example", &RecordType::A));
+
+    assert_eq!(cache.len(), 2);
+    assert!(cache
+        .get_partitioned("gone.example", &RecordType::AAAA, 3)
+        .is_some());
+    assert!(cache
+        .get_partitioned("kept.example", &RecordType::A, 3)
+        .is_some());
+}
+
+#[tokio::test]
+async fn cached_resolver_stores_under_the_query_partition() {
+    let upstream = Arc::new(CountingResolver {
+        calls: AtomicUsize::new(0),
+    });
+    let resolver = CachedResolver::new(
+        Arc::clone(&upstream) as Arc<dyn DnsResolver>,
+        Arc::new(create_cache()) as Arc<dyn DnsCacheAccess>,
+        300,
+        Arc::new(NegativeQueryTracker::new()),
+        4,
+    );
+
+    let restricted = DnsQuery::new("video.example", RecordType::A).with_cache_partition(9);
+    let first = resolver.resolve(&restricted).await.unwrap();
+    assert!(!first.cache_hit);
+
+    let again = resolver.resolve(&restricted).await.unwrap();
+    assert!(again.cache_hit);
+    assert!(resolver
+        .try_cache_partitioned("video.example", RecordType::A, 9)
+        .is_some());
+
+    assert!(resolver
+        .try_cache_str("video.example", RecordType::A)
+        .is_none());
+    let shared = DnsQuery::new("video.example", RecordType::A);
+    let other = resolver.resolve(&shared).await.unwrap();
+    assert!(!other.cache_hit);
+    assert_eq!(upstream.calls.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,288 @@
+use ferrous_dns_domain::RecordType;
+use ferrous_dns_infrastructure::dns::cache::coarse_clock;
+use ferrous_dns_infrastructure::dns::{
+    CachedAddresses, CachedData, CachedRecord, DnsCache, DnsCacheConfig, EvictionStrategy,
+};
+use proptest::prelude::*;
+use std::net::{IpAddr, Ipv4Addr};
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+const DOMAINS: u16 = 48;
+const MAX_GENERATED_TTL: u32 = 3_600;
+const WORKERS: usize = 4;
+
+#[derive(Debug, Clone)]
+enum Op {
+    Insert { domain: u16, kind: Kind, ttl: u32 },
+    Get { domain: u16, kind: Kind },
+    Remove { domain: u16, kind: Kind },
+    Evict,
+    Compact,
+    RotateBloom,
+}
+
+/// Record type of an operation. Each one is stored as a different kind of
+/// data so a lookup answered from the wrong entry is detectable.
+#[derive(Debug, Clone, Copy)]
+enum Kind {
+    Address,
+    Alias,
+    Negative,
+}
+
+impl Kind {
+    fn record_type(self) -> RecordType {
+        match self {
+            Kind::Address => RecordType::A,
+            Kind::Alias => RecordType::CNAME,
+            Kind::Negative => RecordType::MX,
+        }
+    }
+}
+
+fn domain_name(domain: u16) -> String {
+    format!("host-{domain}.prop.test")
+}
+
+fn address_of(domain: u16) -> IpAddr {
+    IpAddr::V4(Ipv4Addr::new(10, 0, (domain >> 8) as u8, domain as u8))
+}
+
+fn alias_of(domain: u16) -> String {
+    format!("target-{domain}.prop.test")
+}
+
+/// The only data ever stored for `domain`/`kind`.
+fn data_for(domain: u16, kind: Kind) -> CachedData {
+    match kind {
+        Kind::Address => CachedData::IpAddresses(CachedAddresses {
+            addresses: Arc::new(vec![address_of(domain)]),
+        }),
+        Kind::Alias => CachedData::CanonicalName(Arc::from(alias_of(domain).as_str())),
+        Kind::Negative => CachedData::NegativeResponse,
+    }
+}
+
+fn assert_served_data(domain: u16, kind: Kind, data: &CachedData) {
+    match (kind, data) {
+        (Kind::Address, CachedData::IpAddresses(entry)) => {
+            assert_eq!(entry.addresses.as_slice(), &[address_of(domain)]);
+        }
+        (Kind::Alias, CachedData::CanonicalName(target)) => {
+            assert_eq!(target.as_ref(), alias_of(domain));
+        }
+        (Kind::Negative, CachedData::NegativeResponse) => {}
+        (kind, data) => panic!("{kind:?} lookup for domain {domain} served {data:?}"),
+    }
+}
+
+fn kind_strategy() -> impl Strategy<Value = Kind> {
+    prop_oneof![Just(Kind::Address), Just(Kind::Alias), Just(Kind::Negative)]
+}
+
+fn op_strategy() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        8 => (0..DOMAINS, kind_strategy(), 1..=MAX_GENERATED_TTL)
+            .prop_map(|(domain, kind, ttl)| Op::Insert { domain, kind, ttl }),
+        8 => (0..DOMAINS, kind_strategy()).prop_map(|(domain, kind)| Op::Get { domain, kind }),
+        2 => (0..DOMAINS, kind_strategy()).prop_map(|(domain, kind)| Op::Remove { domain, kind }),
+        1 => Just(Op::Evict),
+        1 => Just(Op::Compact),
+        1 => Just(Op::RotateBloom),
+    ]
+}
+
+fn create_cache(max_entries: usize, strategy: EvictionStrategy) -> DnsCache {
+    DnsCache::new(DnsCacheConfig {
+        max_entries,
+        eviction_strategy: strategy,
+        min_threshold: 0.0,
+        refresh_threshold: 0.75,
+        batch_eviction_percentage: 0.2,
+        adaptive_thresholds: false,
+        min_frequency: 0,
+        min_lfuk_score: 0.0,
+        shard_amount: 4,
+        access_window_secs: 7200,
+        eviction_sample_size: 8,
+        lfuk_k_value: 0.5,
+        refresh_sample_rate: 1.0,
+        min_ttl: 0,
+        max_ttl: 86_400,
+        l1_capacity: 64,
+    })
+}
+
+fn strategy_strategy() -> impl Strategy<Value = EvictionStrategy> {
+    prop_oneof![
+        Just(EvictionStrategy::HitRate),
+        Just(EvictionStrategy::LRU),
+        Just(EvictionStrategy::LFU),
+        Just(EvictionStrategy::LFUK),
+    ]
+}
+
+/// Runs `ops` against `cache`, checking every lookup. Returns how many
+/// lookups were made.
+fn apply(cache: &DnsCache, ops: &[Op]) -> u64 {
+    let mut gets = 0;
+    for op in ops {
+        match *op {
+            Op::Insert { domain, kind, ttl } => cache.insert(
+                &domain_name(domain),
+                kind.record_type(),
+                data_for(domain, kind),
+                ttl,
+                None,
+            ),
+            Op::Get { domain, kind } => {
+                gets += 1;
+                if let Some((data, _, ttl)) = cache.get(&domain_name(domain), &kind.record_type()) {
+                    assert_served_data(domain, kind, &data);
+                    let ttl = ttl.expect("cache hits carry a remaining TTL");
+                    assert!(
+                        ttl <= MAX_GENERATED_TTL,
+                        "served TTL {ttl} above any inserted"
+                    );
+                }
+            }
+            Op::Remove { domain, kind } => {
+                cache.remove(&domain_name(domain), &kind.record_type());
+            }
+            Op::Evict => cache.evict_entries(),
+            Op::Compact => {
+                cache.compact();
+            }
+            Op::RotateBloom => cache.rotate_bloom(),
+        }
+    }
+    gets
+}
+
+/// Compacts, then evicts until the cache is back under `max_entries`.
+/// Every eviction round removes at least one entry, so this must settle
+/// within as many rounds as there are entries.
+fn stabilize(cache: &DnsCache, max_entries: usize) {
+    cache.compact();
+    let mut rounds = cache.len();
+    while cache.len() > max_entries {
+        assert!(
+            rounds > 0,
+            "cache stuck at {} entries above max_entries {max_entries}",
+            cache.len()
+        );
+        cache.evict_entries();
+        rounds -= 1;
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn concurrent_operations_keep_cache_invariants(
+        max_entries in 4usize..32,
+        strategy in strategy_strategy(),
+        ops in prop::collection::vec(op_strategy(), 1..400),
+    ) {
+        coarse_clock::tick();
+        let cache = Arc::new(create_cache(max_entries, strategy));
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(WORKERS)
+            .enable_all()
+            .build()
+            .unwrap();
+
+        let chunk_len = ops.len().div_ceil(WORKERS);
+        let gets: u64 = runtime.block_on(async {
+            let tasks: Vec<_> = ops
+                .chunks(chunk_len)
+                .map(|chunk| {
+                    let cache = Arc::clone(&cache);
+                    let chunk = chunk.to_vec();
+                    tokio::spawn(async move { apply(&cache, &chunk) })
+                })
+                .collect();
+            let mut gets = 0;
+            for task in tasks {
+                gets += task.await.expect("worker panicked");
+            }
+            gets
+        });
+
+        let metrics = cache.metrics();
+        let hits = metrics.hits.load(Ordering::Relaxed);
+        let misses = metrics.misses.load(Ordering::Relaxed);
+        prop_assert_eq!(hits + misses, gets, "every lookup is exactly one hit or miss");
+        prop_assert!(metrics.l1_hits.load(Ordering::Relaxed) <= hits);
+        prop_assert!(metrics.stale_hits.load(Ordering::Relaxed) <= hits);
+        prop_assert!(cache.len() as u64 <= metrics.insertions.load(Ordering::Relaxed));
+
+        stabilize(&cache, max_entries);
+        prop_assert!(cache.len() <= max_entries);
+    }
+
+    #[test]
+    fn sequential_operations_account_memory_exactly(
+        ops in prop::collection::vec(op_strategy(), 1..200),
+    ) {
+        coarse_clock::tick();
+        let cache = create_cache(16, EvictionStrategy::HitRate);
+        apply(&cache, &ops);
+        prop_assert_eq!(cache.memory_bytes() == 0, cache.is_empty());
+
+        for domain in 0..DOMAINS {
+            for kind in [Kind::Address, Kind::Alias] {
+                cache.remove(&domain_name(domain), &kind.record_type());
+            }
+        }
+        prop_assert!(cache.is_empty());
+        prop_assert_eq!(cache.memory_bytes(), 0);
+    }
+
+    #[test]
+    fn fresh_entries_survive_one_bloom_rotation(
+        domains in prop::collection::hash_set(0..DOMAINS, 1..16),
+    ) {
+        coarse_clock::tick();
+        let cache = create_cache(1_000, EvictionStrategy::HitRate);
+        for &domain in &domains {
+            cache.insert(
+                &domain_name(domain),
+                RecordType::CNAME,
+                data_for(domain, Kind::Alias),
+                300,
+                None,
+            );
+        }
+        cache.rotate_bloom();
+
+        for &domain in &domains {
+            let served = cache.get(&domain_name(domain), &RecordType::CNAME);
+            prop_assert!(served.is_some(), "domain {} lost after one rotation", domain);
+            assert_served_data(domain, Kind::Alias, &served.unwrap().0);
+        }
+    }
+
+    #[test]
+    fn expired_entries_are_never_served_beyond_the_stale_window(
+        ttl in 0u32..=MAX_GENERATED_TTL,
+        elapsed in 0u64..=3 * MAX_GENERATED_TTL as u64,
+    ) {
+        coarse_clock::tick();
+        let record = CachedRecord::new(data_for(0, Kind::Alias), ttl, RecordType::CNAME, None);
+        let now = record.inserted_at_secs + elapsed;
+
+        let fresh = !record.is_expired_at_secs(now);
+        let stale = record.is_stale_usable_at_secs(now);
+        prop_assert!(!(fresh && stale), "an entry is either fresh or stale");
+        if fresh || stale {
+            prop_assert!(
+                elapsed < 2 * ttl as u64,
+                "served {elapsed}s after insertion with ttl {ttl}"
+            );
+        }
+        prop_assert_eq!(fresh, elapsed < ttl as u64);
+    }
+}
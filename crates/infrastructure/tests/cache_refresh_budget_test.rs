@@ -0,0 +1,109 @@
+use ferrous_dns_application::ports::DnsCachePort;
+use ferrous_dns_infrastructure::dns::{
+    DnsCache, DnsCacheConfig, EvictionStrategy, HealthChecker, RefreshBudget, ServerHealth,
+};
+use std::sync::Arc;
+use std::time::Duration;
+
+fn create_cache() -> DnsCache {
+    DnsCache::new(DnsCacheConfig {
+        max_entries: 100,
+        eviction_strategy: EvictionStrategy::HitRate,
+        min_threshold: 0.0,
+        refresh_threshold: 0.75,
+        batch_eviction_percentage: 0.2,
+        adaptive_thresholds: false,
+        min_frequency: 0,
+        min_lfuk_score: 0.0,
+        shard_amount: 4,
+        access_window_secs: 7200,
+        eviction_sample_size: 8,
+        lfuk_k_value: 0.5,
+        refresh_sample_rate: 1.0,
+        min_ttl: 0,
+        max_ttl: 86_400,
+        l1_capacity: 1024,
+    })
+}
+
+#[test]
+fn budget_starts_with_min_rate_burst_and_then_defers() {
+    let budget = RefreshBudget::new(0.1, 2.0, 5);
+
+    assert_eq!(budget.acquire(100, 0), 10);
+    assert_eq!(budget.acquire(1, 0), 0);
+
+    let snapshot = budget.snapshot();
+    assert_eq!(snapshot.granted, 10);
+    assert_eq!(snapshot.deferred, 91);
+}
+
+#[test]
+fn budget_follows_client_query_rate() {
+    let budget = RefreshBudget::new(0.1, 0.0, 10);
+    assert_eq!(budget.acquire(1, 0), 0);
+
+    std::thread::sleep(Duration::from_millis(1100));
+
+    let granted = budget.acquire(1000, 1000);
+    assert!(
+        (99..=100).contains(&granted),
+        "10% of 1000 client queries should allow ~100 refreshes, got {granted}"
+    );
+    let snapshot = budget.snapshot();
+    assert!(snapshot.client_qps > 0.0);
+    assert_eq!(snapshot.health_factor, 1.0);
+}
+
+#[test]
+fn cache_without_budget_grants_every_refresh() {
+    let cache = create_cache();
+
+    assert_eq!(cache.acquire_refresh_budget(500), 500);
+    assert!(cache.cache_metrics_snapshot().refresh_budget.is_none());
+}
+
+#[test]
+fn cache_reports_installed_budget() {
+    let cache = create_cache();
+    cache.set_refresh_budget(Arc::new(RefreshBudget::new(0.2, 1.0, 3)));
+
+    assert_eq!(cache.acquire_refresh_budget(5), 3);
+
+    let snapshot = cache
+        .cache_metrics_snapshot()
+        .refresh_budget
+        .expect("budget installed");
+    assert_eq!(snapshot.granted, 3);
+    assert_eq!(snapshot.deferred, 2);
+    assert_eq!(snapshot.limit_qps, 1.0);
+}
+
+#[test]
+fn unchecked_upstreams_leave_full_capacity() {
+    let checker = HealthChecker::new(3, 2);
+    assert_eq!(checker.capacity_factor(), 1.0);
+}
+
+#[test]
+fn failures_and_latency_above_baseline_reduce_capacity() {
+    let healthy = ServerHealth {
+        latency_ewma_ms: Some(20.0),
+        baseline_latency_ms: Some(20.0),
+        ..ServerHealth::default()
+    };
+    assert_eq!(healthy.capacity_factor(), 1.0);
+
+    let slow = ServerHealth {
+        latency_ewma_ms: Some(80.0),
+        baseline_latency_ms: Some(20.0),
+        ..ServerHealth::default()
+    };
+    assert!((slow.capacity_factor() - 0.25).abs() < 1e-9);
+
+    let failing = ServerHealth {
+        failure_ewma: 0.6,
+        ..ServerHealth::default()
+    };
+    assert!((failing.capacity_factor() - 0.4).abs() < 1e-9);
+}
@@ -83,6 +83,7 @@ fn make_cache() -> Arc<DnsCache> {
         refresh_sample_rate: 1.0,
         min_ttl: 0,
         max_ttl: 86_400,
+        l1_capacity: 1024,
     }))
 }
 
@@ -90,6 +91,7 @@ fn make_query(domain: &str) -> DnsQuery {
     DnsQuery {
         domain: Arc::from(domain),
         record_type: RecordType::A,
+        cache_partition: 0,
     }
 }
 
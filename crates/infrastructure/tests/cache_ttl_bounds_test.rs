@@ -22,6 +22,7 @@ fn make_cache() -> DnsCache {
         refresh_sample_rate: 1.0,
         min_ttl: 0,
         max_ttl: 86_400,
+        l1_capacity: 1024,
     })
 }
 
@@ -0,0 +1,112 @@
+use ferrous_dns_application::ports::CacheTtlOverrideRepository;
+use ferrous_dns_domain::{CacheTtlOverride, DomainError};
+use ferrous_dns_infrastructure::repositories::cache_ttl_override_repository::SqliteCacheTtlOverrideRepository;
+use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
+use std::sync::Arc;
+
+async fn create_test_db() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::query(include_str!(
+        "../../../migrations/20260326000001_create_cache_ttl_overrides.sql"
+    ))
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    pool
+}
+
+fn new_override(domain: &str, min: Option<u32>, max: Option<u32>) -> CacheTtlOverride {
+    CacheTtlOverride::new(Arc::from(domain), min, max)
+}
+
+#[tokio::test]
+async fn test_create_round_trips_all_fields() {
+    let repo = SqliteCacheTtlOverrideRepository::new(create_test_db().await);
+
+    let mut o = new_override("*.internal.corp", None, Some(30));
+    o.enabled = false;
+    o.comment = Some(Arc::from("short-lived service records"));
+
+    let created = repo.create(&o).await.unwrap();
+    assert!(created.id.is_some());
+    assert_eq!(created.domain.as_ref(), "*.internal.corp");
+    assert_eq!(created.min_ttl, None);
+    assert_eq!(created.max_ttl, Some(30));
+    assert!(!created.enabled);
+    assert_eq!(
+        created.comment.as_deref(),
+        Some("short-lived service records")
+    );
+    assert!(created.created_at.is_some());
+
+    let fetched = repo.get_by_id(created.id.unwrap()).await.unwrap().unwrap();
+    assert_eq!(fetched.domain, created.domain);
+    assert_eq!(fetched.max_ttl, Some(30));
+}
+
+#[tokio::test]
+async fn test_duplicate_domain_is_rejected() {
+    let repo = SqliteCacheTtlOverrideRepository::new(create_test_db().await);
+
+    repo.create(&new_override("cdn.example.com", Some(600), None))
+        .await
+        .unwrap();
+    let err = repo
+        .create(&new_override("cdn.example.com", Some(60), None))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DomainError::InvalidCacheTtlOverride(_)));
+}
+
+#[tokio::test]
+async fn test_update_replaces_bounds() {
+    let repo = SqliteCacheTtlOverrideRepository::new(create_test_db().await);
+
+    let mut created = repo
+        .create(&new_override("cdn.example.com", Some(600), None))
+        .await
+        .unwrap();
+    created.min_ttl = None;
+    created.max_ttl = Some(120);
+
+    let updated = repo.update(&created).await.unwrap();
+    assert_eq!(updated.min_ttl, None);
+    assert_eq!(updated.max_ttl, Some(120));
+}
+
+#[tokio::test]
+async fn test_update_and_delete_missing_return_not_found() {
+    let repo = SqliteCacheTtlOverrideRepository::new(create_test_db().await);
+
+    let mut missing = new_override("cdn.example.com", Some(600), None);
+    missing.id = Some(42);
+    assert!(matches!(
+        repo.update(&missing).await.unwrap_err(),
+        DomainError::CacheTtlOverrideNotFound(42)
+    ));
+    assert!(matches!(
+        repo.delete(42).await.unwrap_err(),
+        DomainError::CacheTtlOverrideNotFound(42)
+    ));
+}
+
+#[tokio::test]
+async fn test_get_all_is_sorted_by_domain() {
+    let repo = SqliteCacheTtlOverrideRepository::new(create_test_db().await);
+
+    repo.create(&new_override("zeta.example", None, Some(30)))
+        .await
+        .unwrap();
+    repo.create(&new_override("*.alpha.example", None, Some(30)))
+        .await
+        .unwrap();
+
+    let all = repo.get_all().await.unwrap();
+    let domains: Vec<&str> = all.iter().map(|o| o.domain.as_ref()).collect();
+    assert_eq!(domains, vec!["*.alpha.example", "zeta.example"]);
+}
@@ -0,0 +1,171 @@
+use ferrous_dns_application::ports::{CacheTtlOverrideEnginePort, CacheTtlOverrideRepository};
+use ferrous_dns_domain::{CacheTtlOverride, RecordType, TtlBounds};
+use ferrous_dns_infrastructure::dns::cache_ttl_override::{
+    CacheTtlOverrideEnforcer, CacheTtlOverrideMatcher,
+};
+use ferrous_dns_infrastructure::dns::{
+    CachedAddresses, CachedData, DnsCache, DnsCacheConfig, EvictionStrategy,
+};
+use ferrous_dns_infrastructure::repositories::cache_ttl_override_repository::SqliteCacheTtlOverrideRepository;
+use sqlx::sqlite::SqlitePoolOptions;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+fn ttl_override(domain: &str, min: Option<u32>, max: Option<u32>) -> CacheTtlOverride {
+    CacheTtlOverride::new(Arc::from(domain), min, max)
+}
+
+fn cap(max: u32) -> Option<TtlBounds> {
+    Some(TtlBounds {
+        min_ttl: None,
+        max_ttl: Some(max),
+    })
+}
+
+fn make_ip_data(ip: &str) -> CachedData {
+    let addr: IpAddr = ip.parse().unwrap();
+    CachedData::IpAddresses(CachedAddresses {
+        addresses: Arc::new(vec![addr]),
+    })
+}
+
+fn create_cache() -> DnsCache {
+    DnsCache::new(DnsCacheConfig {
+        max_entries: 100,
+        eviction_strategy: EvictionStrategy::HitRate,
+        min_threshold: 0.0,
+        refresh_threshold: 0.75,
+        batch_eviction_percentage: 0.2,
+        adaptive_thresholds: false,
+        min_frequency: 0,
+        min_lfuk_score: 0.0,
+        shard_amount: 4,
+        access_window_secs: 7200,
+        eviction_sample_size: 8,
+        lfuk_k_value: 0.5,
+        refresh_sample_rate: 1.0,
+        min_ttl: 0,
+        max_ttl: 86_400,
+        l1_capacity: 0,
+    })
+}
+
+async fn create_repo() -> Arc<dyn CacheTtlOverrideRepository> {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    sqlx::query(include_str!(
+        "../../../migrations/20260326000001_create_cache_ttl_overrides.sql"
+    ))
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    Arc::new(SqliteCacheTtlOverrideRepository::new(pool))
+}
+
+async fn create_enforcer(overrides: Vec<CacheTtlOverride>) -> Arc<dyn CacheTtlOverrideEnginePort> {
+    let repo = create_repo().await;
+    for o in &overrides {
+        repo.create(o).await.unwrap();
+    }
+    CacheTtlOverrideEnforcer::new(repo).await.unwrap()
+}
+
+fn remaining_ttl(cache: &DnsCache, domain: &str) -> u32 {
+    cache.get(domain, &RecordType::A).unwrap().2.unwrap()
+}
+
+#[test]
+fn test_wildcard_matches_subdomains_but_not_apex() {
+    let matcher =
+        CacheTtlOverrideMatcher::new(vec![ttl_override("*.internal.corp", None, Some(30))]);
+
+    assert_eq!(matcher.lookup("db.internal.corp"), cap(30));
+    assert_eq!(matcher.lookup("a.b.internal.corp"), cap(30));
+    assert_eq!(matcher.lookup("internal.corp"), None);
+    assert_eq!(matcher.lookup("notinternal.corp"), None);
+}
+
+#[test]
+fn test_exact_beats_wildcard_and_deepest_wildcard_wins() {
+    let matcher = CacheTtlOverrideMatcher::new(vec![
+        ttl_override("*.corp", None, Some(300)),
+        ttl_override("*.internal.corp", None, Some(30)),
+        ttl_override("db.internal.corp", None, Some(5)),
+    ]);
+
+    assert_eq!(matcher.lookup("db.internal.corp"), cap(5));
+    assert_eq!(matcher.lookup("web.internal.corp"), cap(30));
+    assert_eq!(matcher.lookup("mail.corp"), cap(300));
+}
+
+#[test]
+fn test_disabled_overrides_are_ignored() {
+    let mut disabled = ttl_override("cdn.example.com", Some(600), None);
+    disabled.enabled = false;
+    let matcher = CacheTtlOverrideMatcher::new(vec![disabled]);
+
+    assert!(matcher.is_empty());
+    assert_eq!(matcher.lookup("cdn.example.com"), None);
+}
+
+#[tokio::test]
+async fn test_cache_insert_applies_matching_override() {
+    let cache = create_cache();
+    cache.set_ttl_overrides(
+        create_enforcer(vec![
+            ttl_override("*.internal.corp", None, Some(30)),
+            ttl_override("cdn.example.com", Some(600), None),
+        ])
+        .await,
+    );
+
+    cache.insert(
+        "db.internal.corp",
+        RecordType::A,
+        make_ip_data("10.0.0.1"),
+        3_600,
+        None,
+    );
+    cache.insert(
+        "cdn.example.com",
+        RecordType::A,
+        make_ip_data("10.0.0.2"),
+        20,
+        None,
+    );
+    cache.insert(
+        "www.example.com",
+        RecordType::A,
+        make_ip_data("10.0.0.3"),
+        20,
+        None,
+    );
+
+    assert!(remaining_ttl(&cache, "db.internal.corp") <= 30);
+    assert!(remaining_ttl(&cache, "cdn.example.com") > 500);
+    assert!(remaining_ttl(&cache, "www.example.com") <= 20);
+}
+
+#[tokio::test]
+async fn test_override_reload_applies_to_later_inserts() {
+    let repo = create_repo().await;
+    let engine = CacheTtlOverrideEnforcer::new(repo.clone()).await.unwrap();
+
+    assert_eq!(engine.bounds("cdn.example.com"), None);
+
+    repo.create(&ttl_override("cdn.example.com", Some(600), None))
+        .await
+        .unwrap();
+    engine.reload().await.unwrap();
+
+    assert_eq!(
+        engine.bounds("cdn.example.com"),
+        Some(TtlBounds {
+            min_ttl: Some(600),
+            max_ttl: None,
+        })
+    );
+}
@@ -34,6 +34,7 @@ fn make_cache() -> Arc<dyn DnsCacheAccess> {
         refresh_sample_rate: 1.0,
         min_ttl: 0,
         max_ttl: 86_400,
+        l1_capacity: 1024,
     }))
 }
 
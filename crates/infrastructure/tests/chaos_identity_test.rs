@@ -0,0 +1,64 @@
+use ferrous_dns_domain::config::ChaosIdentityConfig;
+use ferrous_dns_infrastructure::dns::ChaosIdentity;
+use hickory_proto::rr::{DNSClass, Name, RData, RecordType};
+use std::str::FromStr;
+
+fn identity(version: Option<&str>, hostname: Option<&str>) -> ChaosIdentity {
+    ChaosIdentity::from_config(&ChaosIdentityConfig {
+        enabled: true,
+        version: version.map(str::to_string),
+        hostname: hostname.map(str::to_string),
+        server_id: None,
+    })
+    .expect("enabled identity")
+}
+
+fn txt(identity: &ChaosIdentity, name: &str) -> Option<String> {
+    let record = identity.answer(&Name::from_str(name).unwrap(), RecordType::TXT)?;
+    assert_eq!(record.dns_class(), DNSClass::CH);
+    match record.data() {
+        RData::TXT(txt) => Some(String::from_utf8(txt.txt_data()[0].to_vec()).unwrap()),
+        other => panic!("expected TXT, got {other:?}"),
+    }
+}
+
+#[test]
+fn disabled_config_builds_no_identity() {
+    assert!(ChaosIdentity::from_config(&ChaosIdentityConfig::default()).is_none());
+}
+
+#[test]
+fn answers_configured_values() {
+    let identity = identity(Some("resolver"), Some("edge-1"));
+    assert_eq!(
+        txt(&identity, "version.bind."),
+        Some("resolver".to_string())
+    );
+    assert_eq!(txt(&identity, "HOSTNAME.BIND."), Some("edge-1".to_string()));
+    assert_eq!(txt(&identity, "id.server."), Some("edge-1".to_string()));
+}
+
+#[test]
+fn version_defaults_to_package_version() {
+    let identity = identity(None, Some("edge-1"));
+    assert_eq!(
+        txt(&identity, "version.bind."),
+        Some(format!("ferrous-dns {}", env!("CARGO_PKG_VERSION")))
+    );
+}
+
+#[test]
+fn empty_value_refuses_that_name() {
+    let identity = identity(Some(""), Some("edge-1"));
+    assert_eq!(txt(&identity, "version.bind."), None);
+    assert_eq!(txt(&identity, "hostname.bind."), Some("edge-1".to_string()));
+}
+
+#[test]
+fn unknown_names_and_types_are_refused() {
+    let identity = identity(Some("resolver"), Some("edge-1"));
+    assert_eq!(txt(&identity, "authors.bind."), None);
+    assert!(identity
+        .answer(&Name::from_str("version.bind.").unwrap(), RecordType::A)
+        .is_none());
+}
@@ -19,6 +19,9 @@ async fn create_test_db() -> sqlx::SqlitePool {
             enabled BOOLEAN NOT NULL DEFAULT 1,
             comment TEXT,
             is_default BOOLEAN NOT NULL DEFAULT 0,
+            filter_aaaa BOOLEAN NOT NULL DEFAULT 0,
+            audit_mode  BOOLEAN NOT NULL DEFAULT 0,
+            tenant_id INTEGER,
             created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
             updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
         )
@@ -52,7 +55,10 @@ async fn create_test_db() -> sqlx::SqlitePool {
             last_hostname_update DATETIME,
             group_id INTEGER NOT NULL DEFAULT 1 REFERENCES groups(id) ON DELETE RESTRICT,
             created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
-            updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+            updated_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            display_name TEXT,
+            category TEXT,
+            notes TEXT
         )
         "#,
     )
@@ -93,6 +99,31 @@ async fn test_update_last_seen_increments_count() {
     assert_eq!(client.query_count, 3);
 }
 
+#[tokio::test]
+async fn test_update_last_seen_in_group_only_applies_to_new_clients() {
+    let pool = create_test_db().await;
+    sqlx::query("INSERT INTO groups (id, name) VALUES (2, 'iot')")
+        .execute(&pool)
+        .await
+        .unwrap();
+    let repo = SqliteClientRepository::new(pool, &DatabaseConfig::default());
+
+    let new_ip: IpAddr = "192.168.30.5".parse().unwrap();
+    let known_ip: IpAddr = "192.168.30.6".parse().unwrap();
+    repo.update_last_seen(known_ip).await.unwrap();
+    repo.flush_writes().await;
+
+    repo.update_last_seen_in_group(new_ip, 2).await.unwrap();
+    repo.update_last_seen_in_group(known_ip, 2).await.unwrap();
+    repo.flush_writes().await;
+
+    let new_client = repo.get_or_create(new_ip).await.unwrap();
+    let known_client = repo.get_or_create(known_ip).await.unwrap();
+    assert_eq!(new_client.group_id, Some(2));
+    assert_eq!(known_client.group_id, Some(1));
+    assert_eq!(known_client.query_count, 2);
+}
+
 #[tokio::test]
 async fn test_update_mac_address() {
     let pool = create_test_db().await;
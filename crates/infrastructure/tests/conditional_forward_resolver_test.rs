@@ -0,0 +1,192 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{ConditionalForwardStatsPort, DnsResolution, DnsResolver};
+use ferrous_dns_domain::{ConditionalForwardConfig, DnsQuery, DomainError, RecordType};
+use ferrous_dns_infrastructure::dns::resolver::filters::QueryFilters;
+use ferrous_dns_infrastructure::dns::resolver::{ConditionalForwardResolver, ConditionalForwards};
+use hickory_proto::op::{Message, MessageType, OpCode, ResponseCode};
+use hickory_proto::rr::rdata::A;
+use hickory_proto::rr::{RData, Record};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio::net::UdpSocket;
+
+const NAS_IP: Ipv4Addr = Ipv4Addr::new(192, 168, 178, 20);
+
+struct CountingResolver {
+    calls: AtomicUsize,
+}
+
+#[async_trait]
+impl DnsResolver for CountingResolver {
+    async fn resolve(&self, _query: &DnsQuery) -> Result<DnsResolution, DomainError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        Ok(DnsResolution::new(
+            vec![IpAddr::V4(Ipv4Addr::new(93, 184, 216, 34))],
+            false,
+        ))
+    }
+}
+
+/// Router that knows `nas.fritz.box` and answers NXDOMAIN for anything else.
+fn router_answer(query_bytes: &[u8]) -> Vec<u8> {
+    let query = Message::from_vec(query_bytes).unwrap();
+    let question = query.queries()[0].clone();
+    let mut response = Message::new(query.id(), MessageType::Response, OpCode::Query);
+    response.add_query(question.clone());
+    if question.name().to_ascii().trim_end_matches('.') == "nas.fritz.box" {
+        response.add_answer(Record::from_rdata(
+            question.name().clone(),
+            60,
+            RData::A(A(NAS_IP)),
+        ));
+    } else {
+        response.set_response_code(ResponseCode::NXDomain);
+    }
+    response.to_vec().unwrap()
+}
+
+fn servfail_answer(query_bytes: &[u8]) -> Vec<u8> {
+    let query = Message::from_vec(query_bytes).unwrap();
+    let mut response = Message::new(query.id(), MessageType::Response, OpCode::Query);
+    response.add_query(query.queries()[0].clone());
+    response.set_response_code(ResponseCode::ServFail);
+    response.to_vec().unwrap()
+}
+
+async fn spawn_server(answer: fn(&[u8]) -> Vec<u8>) -> SocketAddr {
+    let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let addr = socket.local_addr().unwrap();
+    tokio::spawn(async move {
+        let mut buf = [0u8; 512];
+        while let Ok((len, peer)) = socket.recv_from(&mut buf).await {
+            let _ = socket.send_to(&answer(&buf[..len]), peer).await;
+        }
+    });
+    addr
+}
+
+fn forwards(zone: &str, servers: &[SocketAddr]) -> Arc<ConditionalForwards> {
+    Arc::new(ConditionalForwards::new(&[ConditionalForwardConfig {
+        zone: zone.to_string(),
+        servers: servers.iter().map(SocketAddr::to_string).collect(),
+    }]))
+}
+
+fn resolver(
+    forwards: &Arc<ConditionalForwards>,
+) -> (ConditionalForwardResolver, Arc<CountingResolver>) {
+    let inner = Arc::new(CountingResolver {
+        calls: AtomicUsize::new(0),
+    });
+    let resolver = ConditionalForwardResolver::new(inner.clone(), Arc::clone(forwards), 1000);
+    (resolver, inner)
+}
+
+#[test]
+fn test_covers_zone_and_names_under_it() {
+    let forwards = forwards("168.192.in-addr.arpa", &["192.168.1.1:53".parse().unwrap()]);
+
+    assert!(forwards.covers("168.192.in-addr.arpa"));
+    assert!(forwards.covers("42.1.168.192.IN-ADDR.ARPA."));
+    assert!(!forwards.covers("1.10.in-addr.arpa"));
+    assert!(!forwards.covers("x168.192.in-addr.arpa"));
+}
+
+#[test]
+fn test_forwarded_private_ptr_is_not_blocked() {
+    let filters = QueryFilters {
+        block_private_ptr: true,
+        block_non_fqdn: false,
+        local_domain: None,
+        has_local_dns_server: false,
+        conditional_forwards: Some(forwards(
+            "168.192.in-addr.arpa",
+            &["192.168.1.1:53".parse().unwrap()],
+        )),
+    };
+
+    assert!(filters
+        .apply(DnsQuery::new("42.1.168.192.in-addr.arpa", RecordType::PTR))
+        .is_ok());
+    assert!(filters
+        .apply(DnsQuery::new("1.0.0.10.in-addr.arpa", RecordType::PTR))
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_zone_queries_go_to_the_rule_servers() {
+    let router = spawn_server(router_answer).await;
+    let forwards = forwards("fritz.box", &[router]);
+    let (resolver, inner) = resolver(&forwards);
+
+    let resolution = resolver
+        .resolve(&DnsQuery::new("nas.fritz.box", RecordType::A))
+        .await
+        .unwrap();
+
+    assert_eq!(resolution.addresses.as_slice(), &[IpAddr::V4(NAS_IP)]);
+    assert_eq!(
+        resolution.upstream_server.as_deref(),
+        Some(router.to_string().as_str())
+    );
+    assert_eq!(inner.calls.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn test_other_queries_go_to_the_inner_resolver() {
+    let router = spawn_server(router_answer).await;
+    let forwards = forwards("fritz.box", &[router]);
+    let (resolver, inner) = resolver(&forwards);
+
+    resolver
+        .resolve(&DnsQuery::new("example.com", RecordType::A))
+        .await
+        .unwrap();
+
+    assert_eq!(inner.calls.load(Ordering::SeqCst), 1);
+    assert_eq!(forwards.rule_stats()[0].queries, 0);
+}
+
+#[tokio::test]
+async fn test_servfail_moves_to_next_server_and_nxdomain_is_final() {
+    let broken = spawn_server(servfail_answer).await;
+    let router = spawn_server(router_answer).await;
+    let forwards = forwards("fritz.box", &[broken, router]);
+    let (resolver, _) = resolver(&forwards);
+
+    assert!(resolver
+        .resolve(&DnsQuery::new("nas.fritz.box", RecordType::A))
+        .await
+        .is_ok());
+    assert!(matches!(
+        resolver
+            .resolve(&DnsQuery::new("printer.fritz.box", RecordType::A))
+            .await,
+        Err(DomainError::NxDomain)
+    ));
+
+    let stats = &forwards.rule_stats()[0];
+    assert_eq!(stats.zone, "fritz.box");
+    assert_eq!(stats.queries, 2);
+    assert_eq!(stats.answered, 1);
+    assert_eq!(stats.nxdomain, 1);
+    assert_eq!(stats.failures, 0);
+    assert!(stats.avg_latency_ms.is_some());
+}
+
+#[tokio::test]
+async fn test_failure_counted_when_no_server_answers() {
+    let broken = spawn_server(servfail_answer).await;
+    let forwards = forwards("fritz.box", &[broken]);
+    let (resolver, _) = resolver(&forwards);
+
+    assert!(resolver
+        .resolve(&DnsQuery::new("nas.fritz.box", RecordType::A))
+        .await
+        .is_err());
+
+    let stats = &forwards.rule_stats()[0];
+    assert_eq!(stats.failures, 1);
+    assert_eq!(stats.avg_latency_ms, None);
+}
@@ -1,4 +1,4 @@
-use ferrous_dns_domain::{Config, UpstreamPool, UpstreamStrategy};
+use ferrous_dns_domain::{Config, UdpSocketMode, UpstreamPool, UpstreamStrategy};
 use ferrous_dns_infrastructure::repositories::config_persistence::save_config_to_file;
 
 fn default_config_toml() -> &'static str {
@@ -127,6 +127,8 @@ fn test_save_pools_writes_all_fields() {
             "https://cloudflare-dns.com/dns-query".to_string(),
         ],
         weight: Some(10),
+        tsig_key: None,
+        udp_socket: UdpSocketMode::Pooled,
     }];
 
     let doc = save_and_reparse(&config, default_config_toml());
@@ -162,6 +164,8 @@ fn test_save_pools_without_weight_omits_field() {
         priority: 1,
         servers: vec!["https://example.com".to_string()],
         weight: None,
+        tsig_key: None,
+        udp_socket: UdpSocketMode::Pooled,
     }];
 
     let doc = save_and_reparse(&config, default_config_toml());
@@ -178,6 +182,47 @@ fn test_save_pools_without_weight_omits_field() {
     assert!(pool.get("weight").is_none());
 }
 
+#[test]
+fn test_save_pools_writes_per_query_udp_socket_only() {
+    let mut config = load_config(default_config_toml());
+    config.dns.pools = vec![
+        UpstreamPool {
+            name: "pooled".to_string(),
+            strategy: UpstreamStrategy::Parallel,
+            priority: 1,
+            servers: vec!["udp://9.9.9.9:53".to_string()],
+            weight: None,
+            tsig_key: None,
+            udp_socket: UdpSocketMode::Pooled,
+        },
+        UpstreamPool {
+            name: "per-query".to_string(),
+            strategy: UpstreamStrategy::Parallel,
+            priority: 2,
+            servers: vec!["udp://1.1.1.1:53".to_string()],
+            weight: None,
+            tsig_key: None,
+            udp_socket: UdpSocketMode::PerQuery,
+        },
+    ];
+
+    let doc = save_and_reparse(&config, default_config_toml());
+    let dns = doc.get("dns").unwrap().as_table().unwrap();
+    let pools: Vec<_> = dns
+        .get("pools")
+        .unwrap()
+        .as_array_of_tables()
+        .unwrap()
+        .iter()
+        .collect();
+
+    assert!(pools[0].get("udp_socket").is_none());
+    assert_eq!(
+        pools[1].get("udp_socket").unwrap().as_str().unwrap(),
+        "per_query"
+    );
+}
+
 #[test]
 fn test_save_multiple_pools_preserves_order() {
     let mut config = load_config(default_config_toml());
@@ -188,6 +233,8 @@ fn test_save_multiple_pools_preserves_order() {
             priority: 1,
             servers: vec!["https://a.example.com".to_string()],
             weight: None,
+            tsig_key: None,
+            udp_socket: UdpSocketMode::Pooled,
         },
         UpstreamPool {
             name: "second".to_string(),
@@ -195,6 +242,8 @@ fn test_save_multiple_pools_preserves_order() {
             priority: 2,
             servers: vec!["https://b.example.com".to_string()],
             weight: None,
+            tsig_key: None,
+            udp_socket: UdpSocketMode::Pooled,
         },
         UpstreamPool {
             name: "third".to_string(),
@@ -202,6 +251,8 @@ fn test_save_multiple_pools_preserves_order() {
             priority: 3,
             servers: vec!["https://c.example.com".to_string()],
             weight: None,
+            tsig_key: None,
+            udp_socket: UdpSocketMode::Pooled,
         },
     ];
 
@@ -240,6 +291,8 @@ fn test_pool_strategy_parallel_roundtrips() {
         priority: 1,
         servers: vec!["https://example.com".to_string()],
         weight: None,
+        tsig_key: None,
+        udp_socket: UdpSocketMode::Pooled,
     }];
 
     let doc = save_and_reparse(&config, default_config_toml());
@@ -268,6 +321,8 @@ fn test_pool_strategy_failover_roundtrips() {
         priority: 1,
         servers: vec!["https://example.com".to_string()],
         weight: None,
+        tsig_key: None,
+        udp_socket: UdpSocketMode::Pooled,
     }];
 
     let doc = save_and_reparse(&config, default_config_toml());
@@ -296,6 +351,8 @@ fn test_pool_strategy_balanced_roundtrips() {
         priority: 1,
         servers: vec!["https://example.com".to_string()],
         weight: None,
+        tsig_key: None,
+        udp_socket: UdpSocketMode::Pooled,
     }];
 
     let doc = save_and_reparse(&config, default_config_toml());
@@ -326,6 +383,8 @@ fn test_save_pools_preserves_health_check() {
         priority: 1,
         servers: vec!["https://example.com".to_string()],
         weight: None,
+        tsig_key: None,
+        udp_socket: UdpSocketMode::Pooled,
     }];
 
     let doc = save_and_reparse(&config, default_config_toml());
@@ -357,6 +416,8 @@ fn test_save_pools_preserves_upstream_servers() {
         priority: 1,
         servers: vec!["https://primary.example.com".to_string()],
         weight: None,
+        tsig_key: None,
+        udp_socket: UdpSocketMode::Pooled,
     }];
 
     let doc = save_and_reparse(&config, default_config_toml());
@@ -404,6 +465,8 @@ fn test_full_config_save_and_reload_preserves_weight() {
         priority: 1,
         servers: vec!["https://example.com".to_string()],
         weight: Some(10),
+        tsig_key: None,
+        udp_socket: UdpSocketMode::Pooled,
     }];
 
     let dir = tempfile::tempdir().unwrap();
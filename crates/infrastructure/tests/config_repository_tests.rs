@@ -105,6 +105,7 @@ async fn test_save_local_records_writes_to_correct_path() {
         ip: "10.0.0.10".to_string(),
         record_type: "A".to_string(),
         ttl: Some(300),
+        view: None,
     }]);
 
     let repo = TomlConfigRepository::new(path.to_str().unwrap().to_string());
@@ -129,6 +130,7 @@ async fn test_save_local_records_roundtrips_multiple_records() {
             ip: "192.168.1.10".to_string(),
             record_type: "A".to_string(),
             ttl: Some(60),
+            view: None,
         },
         LocalDnsRecord {
             hostname: "host2".to_string(),
@@ -136,6 +138,7 @@ async fn test_save_local_records_roundtrips_multiple_records() {
             ip: "192.168.1.20".to_string(),
             record_type: "A".to_string(),
             ttl: None,
+            view: None,
         },
     ]);
 
@@ -196,6 +199,7 @@ async fn test_toml_repository_uses_injected_path_not_cwd() {
         ip: "10.99.99.99".to_string(),
         record_type: "A".to_string(),
         ttl: Some(120),
+        view: None,
     }]);
 
     let repo = TomlConfigRepository::new(path_a.to_str().unwrap().to_string());
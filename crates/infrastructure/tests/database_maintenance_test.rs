@@ -0,0 +1,87 @@
+use ferrous_dns_application::ports::DatabaseMaintenancePort;
+use ferrous_dns_domain::config::DatabaseConfig;
+use ferrous_dns_infrastructure::database::{create_write_pool, SqliteDatabaseMaintenance};
+use sqlx::SqlitePool;
+use tempfile::TempDir;
+
+async fn create_file_db() -> (TempDir, SqlitePool) {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("ferrous.db");
+    let url = format!("sqlite:{}", path.display());
+    let pool = create_write_pool(&url, &DatabaseConfig::default())
+        .await
+        .unwrap();
+    (dir, pool)
+}
+
+async fn insert_query_logs(pool: &SqlitePool, count: usize) {
+    let mut tx = pool.begin().await.unwrap();
+    let padding = "x".repeat(200);
+    for i in 0..count {
+        sqlx::query("INSERT INTO query_log (domain, record_type, client_ip) VALUES (?, 'A', ?)")
+            .bind(format!("host-{i}-{padding}.example.com"))
+            .bind("192.168.1.10")
+            .execute(&mut *tx)
+            .await
+            .unwrap();
+    }
+    tx.commit().await.unwrap();
+}
+
+async fn query_log_ids(pool: &SqlitePool) -> Vec<i64> {
+    sqlx::query_scalar("SELECT id FROM query_log ORDER BY id")
+        .fetch_all(pool)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_usage_reports_file_size_and_incremental_mode() {
+    let (_dir, pool) = create_file_db().await;
+    let maintenance = SqliteDatabaseMaintenance::new(pool);
+
+    let usage = maintenance.usage().await.unwrap();
+
+    assert!(usage.incremental_vacuum);
+    assert!(usage.file_bytes > 0);
+    assert!(usage.used_bytes > 0);
+}
+
+#[tokio::test]
+async fn test_delete_oldest_query_logs_removes_lowest_ids() {
+    let (_dir, pool) = create_file_db().await;
+    insert_query_logs(&pool, 10).await;
+    let ids = query_log_ids(&pool).await;
+    let maintenance = SqliteDatabaseMaintenance::new(pool.clone());
+
+    let deleted = maintenance.delete_oldest_query_logs(4).await.unwrap();
+
+    assert_eq!(deleted, 4);
+    assert_eq!(query_log_ids(&pool).await, ids[4..].to_vec());
+}
+
+#[tokio::test]
+async fn test_delete_oldest_query_logs_on_empty_table_returns_zero() {
+    let (_dir, pool) = create_file_db().await;
+    let maintenance = SqliteDatabaseMaintenance::new(pool);
+
+    assert_eq!(maintenance.delete_oldest_query_logs(100).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_incremental_vacuum_reclaims_freed_pages() {
+    let (_dir, pool) = create_file_db().await;
+    insert_query_logs(&pool, 2_000).await;
+    let maintenance = SqliteDatabaseMaintenance::new(pool);
+
+    maintenance.delete_oldest_query_logs(2_000).await.unwrap();
+    let before = maintenance.usage().await.unwrap();
+    assert!(before.free_bytes > 0);
+
+    let reclaimed = maintenance.incremental_vacuum().await.unwrap();
+    let after = maintenance.usage().await.unwrap();
+
+    assert!(reclaimed > 0);
+    assert_eq!(after.free_bytes, 0);
+    maintenance.optimize().await.unwrap();
+}
@@ -0,0 +1,246 @@
+use ferrous_dns_application::ports::DeviceRepository;
+use ferrous_dns_infrastructure::repositories::SqliteDeviceRepository;
+use sqlx::sqlite::SqlitePoolOptions;
+use sqlx::Row;
+
+async fn create_test_db() -> sqlx::SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE clients (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            ip_address TEXT NOT NULL UNIQUE,
+            mac_address TEXT,
+            hostname TEXT,
+            first_seen DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            last_seen DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            query_count INTEGER NOT NULL DEFAULT 0,
+            last_mac_update DATETIME,
+            last_hostname_update DATETIME,
+            created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            updated_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            group_id INTEGER,
+            display_name TEXT,
+            category TEXT,
+            notes TEXT
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE devices (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            mac_address TEXT NOT NULL UNIQUE,
+            current_ip TEXT,
+            first_seen DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            last_seen DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE device_ip_history (
+            device_id INTEGER NOT NULL REFERENCES devices(id) ON DELETE CASCADE,
+            ip_address TEXT NOT NULL,
+            first_seen DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            last_seen DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            PRIMARY KEY (device_id, ip_address)
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    pool
+}
+
+async fn insert_client(
+    pool: &sqlx::SqlitePool,
+    ip: &str,
+    mac: Option<&str>,
+    hostname: Option<&str>,
+    query_count: i64,
+    first_seen: &str,
+    last_seen: &str,
+) -> i64 {
+    sqlx::query(
+        "INSERT INTO clients (ip_address, mac_address, hostname, query_count, first_seen, last_seen)
+         VALUES (?, ?, ?, ?, ?, ?)",
+    )
+    .bind(ip)
+    .bind(mac)
+    .bind(hostname)
+    .bind(query_count)
+    .bind(first_seen)
+    .bind(last_seen)
+    .execute(pool)
+    .await
+    .unwrap()
+    .last_insert_rowid()
+}
+
+#[tokio::test]
+async fn test_record_sighting_tracks_ip_history() {
+    let pool = create_test_db().await;
+    let repo = SqliteDeviceRepository::new(pool.clone());
+
+    let mac = "aa:bb:cc:dd:ee:ff";
+    repo.record_sighting(mac, "192.168.1.10".parse().unwrap())
+        .await
+        .unwrap();
+    repo.record_sighting(mac, "192.168.1.42".parse().unwrap())
+        .await
+        .unwrap();
+    repo.record_sighting(mac, "192.168.1.42".parse().unwrap())
+        .await
+        .unwrap();
+
+    let devices = repo.get_all().await.unwrap();
+    assert_eq!(devices.len(), 1);
+    assert_eq!(devices[0].current_ip, Some("192.168.1.42".parse().unwrap()));
+
+    let device = repo.get_by_mac(mac).await.unwrap().unwrap();
+    let history = repo.get_ip_history(device.id.unwrap()).await.unwrap();
+    let mut ips: Vec<String> = history.iter().map(|h| h.ip_address.to_string()).collect();
+    ips.sort();
+    assert_eq!(ips, vec!["192.168.1.10", "192.168.1.42"]);
+}
+
+#[tokio::test]
+async fn test_find_duplicate_clients_groups_by_mac_newest_first() {
+    let pool = create_test_db().await;
+    let repo = SqliteDeviceRepository::new(pool.clone());
+
+    let old = insert_client(
+        &pool,
+        "192.168.1.10",
+        Some("AA:BB:CC:DD:EE:FF"),
+        None,
+        5,
+        "2026-01-01 00:00:00",
+        "2026-01-02 00:00:00",
+    )
+    .await;
+    let new = insert_client(
+        &pool,
+        "192.168.1.42",
+        Some("aa:bb:cc:dd:ee:ff"),
+        None,
+        1,
+        "2026-01-03 00:00:00",
+        "2026-01-04 00:00:00",
+    )
+    .await;
+    insert_client(
+        &pool,
+        "192.168.1.50",
+        Some("11:22:33:44:55:66"),
+        None,
+        1,
+        "2026-01-03 00:00:00",
+        "2026-01-04 00:00:00",
+    )
+    .await;
+    insert_client(
+        &pool,
+        "192.168.1.60",
+        Some("00:00:00:00:00:00"),
+        None,
+        1,
+        "2026-01-03 00:00:00",
+        "2026-01-04 00:00:00",
+    )
+    .await;
+    insert_client(
+        &pool,
+        "192.168.1.61",
+        Some("00:00:00:00:00:00"),
+        None,
+        1,
+        "2026-01-03 00:00:00",
+        "2026-01-04 00:00:00",
+    )
+    .await;
+
+    let duplicates = repo.find_duplicate_clients().await.unwrap();
+    assert_eq!(duplicates.len(), 1);
+    assert_eq!(&*duplicates[0].mac_address, "aa:bb:cc:dd:ee:ff");
+    assert_eq!(duplicates[0].client_ids, vec![new, old]);
+}
+
+#[tokio::test]
+async fn test_merge_clients_folds_stats_into_kept_row() {
+    let pool = create_test_db().await;
+    let repo = SqliteDeviceRepository::new(pool.clone());
+
+    let old = insert_client(
+        &pool,
+        "192.168.1.10",
+        Some("aa:bb:cc:dd:ee:ff"),
+        Some("laptop"),
+        5,
+        "2026-01-01 00:00:00",
+        "2026-01-02 00:00:00",
+    )
+    .await;
+    let new = insert_client(
+        &pool,
+        "192.168.1.42",
+        Some("aa:bb:cc:dd:ee:ff"),
+        None,
+        2,
+        "2026-01-03 00:00:00",
+        "2026-01-04 00:00:00",
+    )
+    .await;
+
+    let removed = repo.merge_clients(new, &[old]).await.unwrap();
+    assert_eq!(removed, 1);
+
+    let rows = sqlx::query("SELECT id, ip_address, hostname, query_count, first_seen FROM clients")
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+    assert_eq!(rows.len(), 1);
+    let row = &rows[0];
+    assert_eq!(row.get::<i64, _>("id"), new);
+    assert_eq!(row.get::<String, _>("ip_address"), "192.168.1.42");
+    assert_eq!(
+        row.get::<Option<String>, _>("hostname").as_deref(),
+        Some("laptop")
+    );
+    assert_eq!(row.get::<i64, _>("query_count"), 7);
+    assert_eq!(row.get::<String, _>("first_seen"), "2026-01-01 00:00:00");
+}
+
+#[tokio::test]
+async fn test_merge_clients_ignores_keep_id_in_duplicates() {
+    let pool = create_test_db().await;
+    let repo = SqliteDeviceRepository::new(pool.clone());
+
+    let id = insert_client(
+        &pool,
+        "192.168.1.10",
+        None,
+        None,
+        1,
+        "2026-01-01 00:00:00",
+        "2026-01-02 00:00:00",
+    )
+    .await;
+
+    assert_eq!(repo.merge_clients(id, &[id]).await.unwrap(), 0);
+}
@@ -0,0 +1,103 @@
+use bytes::Bytes;
+use ferrous_dns_domain::RecordType;
+use ferrous_dns_infrastructure::dns::forwarding::{MessageBuilder, ResponseParser};
+use hickory_proto::op::{Message, MessageType, OpCode};
+use hickory_proto::rr::rdata::A;
+use hickory_proto::rr::{Name, RData, Record};
+use std::net::Ipv4Addr;
+
+fn answer_for(query_bytes: &[u8]) -> Vec<u8> {
+    let query = Message::from_vec(query_bytes).unwrap();
+    let name = query.queries()[0].name().clone();
+    let mut response = Message::new(query.id(), MessageType::Response, OpCode::Query);
+    response.add_query(query.queries()[0].clone());
+    response.add_answer(Record::from_rdata(
+        name,
+        300,
+        RData::A(A(Ipv4Addr::new(93, 184, 216, 34))),
+    ));
+    response.to_vec().unwrap()
+}
+
+fn with_question_name(response_bytes: &[u8], name: &str) -> Vec<u8> {
+    let original = Message::from_vec(response_bytes).unwrap();
+    let mut query = original.queries()[0].clone();
+    query.set_name(Name::from_ascii(name).unwrap());
+    let mut message = Message::new(original.id(), MessageType::Response, OpCode::Query);
+    message.add_query(query);
+    for answer in original.answers() {
+        message.add_answer(answer.clone());
+    }
+    message.to_vec().unwrap()
+}
+
+#[test]
+fn test_randomize_case_only_changes_letter_case() {
+    let domain = "www.example-1.com";
+    for _ in 0..20 {
+        let randomized = MessageBuilder::randomize_case(domain);
+        assert_eq!(randomized.len(), domain.len());
+        assert!(randomized.eq_ignore_ascii_case(domain));
+        assert!(randomized.contains("-1."));
+    }
+}
+
+#[test]
+fn test_randomize_case_varies_between_calls() {
+    let domain = "abcdefghijklmnopqrstuvwxyz.example.com";
+    let first = MessageBuilder::randomize_case(domain);
+    let varied = (0..20).any(|_| MessageBuilder::randomize_case(domain) != first);
+    assert!(varied, "case randomization should not be constant");
+}
+
+#[test]
+fn test_randomized_query_keeps_case_on_the_wire() {
+    let randomized = "wWw.ExAmPlE.cOm";
+    let bytes = MessageBuilder::build_query(randomized, &RecordType::A, false).unwrap();
+    let parsed = Message::from_vec(&bytes).unwrap();
+    assert_eq!(parsed.queries()[0].name().to_ascii(), "wWw.ExAmPlE.cOm.");
+}
+
+#[test]
+fn test_question_name_matches_exact_echo() {
+    let query = MessageBuilder::build_query("wWw.ExAmPlE.cOm", &RecordType::A, false).unwrap();
+    let response = answer_for(&query);
+    assert!(ResponseParser::question_name_matches(&query, &response));
+}
+
+#[test]
+fn test_question_name_rejects_case_mismatch() {
+    let query = MessageBuilder::build_query("wWw.ExAmPlE.cOm", &RecordType::A, false).unwrap();
+    let response = with_question_name(&answer_for(&query), "www.example.com");
+    assert!(!ResponseParser::question_name_matches(&query, &response));
+}
+
+#[test]
+fn test_question_name_rejects_truncated_response() {
+    let query = MessageBuilder::build_query("example.com", &RecordType::A, false).unwrap();
+    assert!(!ResponseParser::question_name_matches(&query, &query[..10]));
+}
+
+#[test]
+fn test_lowercase_question_name_restores_canonical_case() {
+    let query = MessageBuilder::build_query("wWw.ExAmPlE.cOm", &RecordType::A, false).unwrap();
+    let response = Bytes::from(answer_for(&query));
+
+    let restored = ResponseParser::lowercase_question_name(response);
+    let parsed = ResponseParser::parse_bytes(restored.clone()).unwrap();
+
+    let message = Message::from_vec(&restored).unwrap();
+    assert_eq!(message.queries()[0].name().to_ascii(), "www.example.com.");
+    assert_eq!(
+        parsed.addresses,
+        vec![std::net::IpAddr::V4(Ipv4Addr::new(93, 184, 216, 34))]
+    );
+}
+
+#[test]
+fn test_lowercase_question_name_is_noop_for_lowercase() {
+    let query = MessageBuilder::build_query("www.example.com", &RecordType::A, false).unwrap();
+    let response = Bytes::from(answer_for(&query));
+    let restored = ResponseParser::lowercase_question_name(response.clone());
+    assert_eq!(restored, response);
+}
@@ -29,6 +29,7 @@ fn create_refresh_cache(access_window_secs: u64) -> DnsCache {
         refresh_sample_rate: 1.0,
         min_ttl: 0,
         max_ttl: 86_400,
+        l1_capacity: 1024,
     })
 }
 
@@ -67,6 +68,7 @@ fn create_cache(
         refresh_sample_rate: 1.0,
         min_ttl: 0,
         max_ttl: 86_400,
+        l1_capacity: 1024,
     })
 }
 
@@ -87,6 +89,7 @@ fn create_cache_with_ttl_limits(min_ttl: u32, max_ttl: u32) -> DnsCache {
         refresh_sample_rate: 1.0,
         min_ttl,
         max_ttl,
+        l1_capacity: 1024,
     })
 }
 
@@ -334,7 +337,7 @@ fn test_refresh_candidates_includes_entries_within_access_window() {
 
     let candidates = cache.get_refresh_candidates();
     assert!(
-        candidates.iter().any(|(d, _)| d == "never-hit.com"),
+        candidates.iter().any(|k| k.domain.as_str() == "never-hit.com"),
         "Entrada dentro da access window deve ser candidata mesmo sem hits; candidates={:?}",
         candidates
     );
@@ -360,7 +363,7 @@ fn test_refresh_candidates_with_recent_access() {
 
     let candidates = cache.get_refresh_candidates();
     assert!(
-        candidates.iter().any(|(d, _)| d == "popular.com"),
+        candidates.iter().any(|k| k.domain.as_str() == "popular.com"),
         "Entrada com hit deve ser candidata; candidates={:?}",
         candidates
     );
@@ -382,7 +385,7 @@ fn test_refresh_candidates_access_window_zero_includes_same_tick_entries() {
 
     let candidates = cache_no_window.get_refresh_candidates();
     assert!(
-        candidates.iter().any(|(d, _)| d == "zero-window.com"),
+        candidates.iter().any(|k| k.domain.as_str() == "zero-window.com"),
         "Entry inserida no mesmo tick deve ser candidata com window=0; candidates={:?}",
         candidates
     );
@@ -453,7 +456,7 @@ fn test_refresh_record_preserves_hit_count_for_subsequent_candidates() {
     // Antes do refresh: deve ser candidata
     let before = cache.get_refresh_candidates();
     assert!(
-        before.iter().any(|(d, _)| d == "keep-alive.com"),
+        before.iter().any(|k| k.domain.as_str() == "keep-alive.com"),
         "Entrada deve ser candidata antes do refresh; candidates={:?}",
         before
     );
@@ -470,7 +473,7 @@ fn test_refresh_record_preserves_hit_count_for_subsequent_candidates() {
     // Depois do refresh_record: AINDA deve ser candidata porque hit_count foi preservado
     let after = cache.get_refresh_candidates();
     assert!(
-        after.iter().any(|(d, _)| d == "keep-alive.com"),
+        after.iter().any(|k| k.domain.as_str() == "keep-alive.com"),
         "Entrada deve continuar candidata após refresh_record (hit_count preservado); candidates={:?}",
         after
     );
@@ -549,6 +552,7 @@ fn test_lru_eviction_protects_recently_accessed_entry() {
         refresh_sample_rate: 1.0,
         min_ttl: 0,
         max_ttl: 86_400,
+        l1_capacity: 1024,
     });
 
     // Inserir 3 entradas no tick T: last_access = T para todas
@@ -597,6 +601,7 @@ fn test_hit_rate_eviction_protects_high_hit_entries() {
         refresh_sample_rate: 1.0,
         min_ttl: 0,
         max_ttl: 86_400,
+        l1_capacity: 1024,
     });
 
     cache.insert(
@@ -678,6 +683,7 @@ fn test_lfu_negative_score_below_min_frequency_leads_to_eviction() {
         refresh_sample_rate: 1.0,
         min_ttl: 0,
         max_ttl: 86_400,
+        l1_capacity: 1024,
     });
 
     // Entradas com poucos hits (abaixo do min_frequency=5) têm score negativo
@@ -766,6 +772,7 @@ fn test_access_window_preserved_in_refactored_cache() {
             refresh_sample_rate: 1.0,
             min_ttl: 0,
             max_ttl: 86_400,
+            l1_capacity: 1024,
         });
 
         assert_eq!(
@@ -802,6 +809,7 @@ fn test_single_scan_evicts_exact_count() {
         refresh_sample_rate: 1.0,
         min_ttl: 0,
         max_ttl: 86_400,
+        l1_capacity: 1024,
     });
 
     // Inserir max_entries entradas
@@ -1083,6 +1091,7 @@ fn test_lru_score_is_last_access_timestamp() {
         refresh_sample_rate: 1.0,
         min_ttl: 0,
         max_ttl: 86_400,
+        l1_capacity: 1024,
     });
 
     coarse_clock::tick();
@@ -1113,6 +1122,7 @@ fn test_lru_evicts_least_recently_used() {
         refresh_sample_rate: 1.0,
         min_ttl: 0,
         max_ttl: 86_400,
+        l1_capacity: 1024,
     });
 
     coarse_clock::tick();
@@ -1338,7 +1348,7 @@ fn test_stale_record_is_refresh_candidate_after_client_access() {
 
     let candidates = cache.get_refresh_candidates();
     assert!(
-        candidates.iter().any(|(d, _)| d == "stale-refresh.com"),
+        candidates.iter().any(|k| k.domain.as_str() == "stale-refresh.com"),
         "Stale record must appear in refresh candidates after get(); candidates={candidates:?}"
     );
 }
@@ -0,0 +1,123 @@
+use ferrous_dns_application::ports::DnsRewriteRepository;
+use ferrous_dns_domain::{DnsRewrite, DomainError};
+use ferrous_dns_infrastructure::repositories::dns_rewrite_repository::SqliteDnsRewriteRepository;
+use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
+use std::sync::Arc;
+
+async fn create_test_db() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::query(include_str!(
+        "../../../migrations/20260313000001_create_dns_rewrites.sql"
+    ))
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    pool
+}
+
+fn new_rewrite(domain: &str, answer: &str) -> DnsRewrite {
+    DnsRewrite::new(Arc::from(domain), Arc::from(answer))
+}
+
+#[tokio::test]
+async fn test_create_round_trips_all_fields() {
+    let repo = SqliteDnsRewriteRepository::new(create_test_db().await);
+
+    let mut r = new_rewrite("*.lab.example.com", "192.168.1.10");
+    r.enabled = false;
+    r.comment = Some(Arc::from("lab hosts"));
+
+    let created = repo.create(&r).await.unwrap();
+    assert!(created.id.is_some());
+    assert_eq!(created.domain.as_ref(), "*.lab.example.com");
+    assert_eq!(created.answer.as_ref(), "192.168.1.10");
+    assert!(!created.enabled);
+    assert_eq!(created.comment.as_deref(), Some("lab hosts"));
+    assert!(created.created_at.is_some());
+
+    let fetched = repo.get_by_id(created.id.unwrap()).await.unwrap().unwrap();
+    assert_eq!(fetched.domain, created.domain);
+    assert_eq!(fetched.answer, created.answer);
+}
+
+#[tokio::test]
+async fn test_same_domain_allows_multiple_answers() {
+    let repo = SqliteDnsRewriteRepository::new(create_test_db().await);
+
+    repo.create(&new_rewrite("nas.lan", "192.168.1.10"))
+        .await
+        .unwrap();
+    repo.create(&new_rewrite("nas.lan", "fd00::10"))
+        .await
+        .unwrap();
+
+    let err = repo
+        .create(&new_rewrite("nas.lan", "192.168.1.10"))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DomainError::InvalidDnsRewrite(_)));
+}
+
+#[tokio::test]
+async fn test_get_all_orders_by_domain() {
+    let repo = SqliteDnsRewriteRepository::new(create_test_db().await);
+
+    repo.create(&new_rewrite("zeta.lan", "10.0.0.3"))
+        .await
+        .unwrap();
+    repo.create(&new_rewrite("alpha.lan", "10.0.0.1"))
+        .await
+        .unwrap();
+
+    let all = repo.get_all().await.unwrap();
+    let domains: Vec<&str> = all.iter().map(|r| r.domain.as_ref()).collect();
+    assert_eq!(domains, ["alpha.lan", "zeta.lan"]);
+}
+
+#[tokio::test]
+async fn test_update_replaces_fields() {
+    let repo = SqliteDnsRewriteRepository::new(create_test_db().await);
+    let created = repo
+        .create(&new_rewrite("www.example.com", "10.0.0.1"))
+        .await
+        .unwrap();
+
+    let mut r = new_rewrite("www.example.com", "cdn.example.net");
+    r.id = created.id;
+    let updated = repo.update(&r).await.unwrap();
+
+    assert_eq!(updated.answer.as_ref(), "cdn.example.net");
+    assert!(updated.enabled);
+}
+
+#[tokio::test]
+async fn test_update_missing_returns_not_found() {
+    let repo = SqliteDnsRewriteRepository::new(create_test_db().await);
+
+    let mut r = new_rewrite("www.example.com", "10.0.0.1");
+    r.id = Some(42);
+    let err = repo.update(&r).await.unwrap_err();
+    assert!(matches!(err, DomainError::DnsRewriteNotFound(42)));
+}
+
+#[tokio::test]
+async fn test_delete() {
+    let repo = SqliteDnsRewriteRepository::new(create_test_db().await);
+    let created = repo
+        .create(&new_rewrite("nas.lan", "192.168.1.10"))
+        .await
+        .unwrap();
+    let id = created.id.unwrap();
+
+    repo.delete(id).await.unwrap();
+    assert!(repo.get_by_id(id).await.unwrap().is_none());
+    assert!(matches!(
+        repo.delete(id).await.unwrap_err(),
+        DomainError::DnsRewriteNotFound(_)
+    ));
+}
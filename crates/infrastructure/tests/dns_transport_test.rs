@@ -1,4 +1,4 @@
-use ferrous_dns_domain::{DomainError, UpstreamAddr};
+use ferrous_dns_domain::{BlockSource, DohMethod, DohUpstreamConfig, DomainError, UpstreamAddr};
 use ferrous_dns_infrastructure::dns::fast_path;
 use ferrous_dns_infrastructure::dns::forwarding::ResponseParser;
 #[cfg(feature = "dns-over-h3")]
@@ -7,11 +7,12 @@ use ferrous_dns_infrastructure::dns::transport::h3::H3Transport;
 use ferrous_dns_infrastructure::dns::transport::quic::QuicTransport;
 use ferrous_dns_infrastructure::dns::transport::DnsTransport;
 use ferrous_dns_infrastructure::dns::transport::{
-    https::HttpsTransport, tcp::TcpTransport, tls::TlsTransport, udp::UdpTransport,
+    https, https::HttpsTransport, tcp::TcpTransport, tls::TlsTransport, udp::UdpTransport,
 };
 use ferrous_dns_infrastructure::dns::wire_response;
 use std::net::IpAddr;
 use std::sync::Arc;
+use std::time::Duration;
 
 mod helpers;
 use helpers::{DnsServerBuilder, UdpPoolBuilder};
@@ -170,6 +171,54 @@ fn test_https_transport_various_providers() {
     }
 }
 
+#[test]
+fn test_https_transport_get_method_from_options() {
+    let options = DohUpstreamConfig {
+        method: DohMethod::Get,
+        ..Default::default()
+    };
+    let transport = HttpsTransport::with_options(
+        DnsServerBuilder::google_https(),
+        "dns.google".to_string(),
+        vec![],
+        &options,
+    );
+
+    assert_eq!(transport.method(), DohMethod::Get);
+}
+
+#[test]
+fn test_https_get_url_zeroes_query_id() {
+    let first = https::build_get_url("https://dns.google/dns-query", &[0x12, 0x34, 0x01, 0x00]);
+    let second = https::build_get_url("https://dns.google/dns-query", &[0xab, 0xcd, 0x01, 0x00]);
+
+    assert_eq!(first, second);
+    assert_eq!(first, "https://dns.google/dns-query?dns=AAABAA");
+}
+
+#[test]
+fn test_https_get_url_appends_to_existing_query() {
+    let url = https::build_get_url("https://example.com/resolve?ct=1", &[0, 0]);
+
+    assert_eq!(url, "https://example.com/resolve?ct=1&dns=AAA");
+}
+
+#[test]
+fn test_https_cache_lifetime_uses_max_age_minus_age() {
+    assert_eq!(
+        https::cache_lifetime("public, max-age=300", 100),
+        Some(Duration::from_secs(200))
+    );
+}
+
+#[test]
+fn test_https_cache_lifetime_rejects_uncacheable_responses() {
+    assert_eq!(https::cache_lifetime("no-store", 0), None);
+    assert_eq!(https::cache_lifetime("max-age=60, no-cache", 0), None);
+    assert_eq!(https::cache_lifetime("private", 0), None);
+    assert_eq!(https::cache_lifetime("max-age=60", 60), None);
+}
+
 #[cfg(feature = "dns-over-quic")]
 #[test]
 fn test_quic_transport_protocol_name() {
@@ -365,7 +414,9 @@ fn test_transport_error_classification_typed_variants() {
     ));
 
     assert!(!ResponseParser::is_transport_error(&DomainError::NxDomain));
-    assert!(!ResponseParser::is_transport_error(&DomainError::Blocked));
+    assert!(!ResponseParser::is_transport_error(&DomainError::Blocked(
+        BlockSource::Blocklist
+    )));
 }
 
 #[test]
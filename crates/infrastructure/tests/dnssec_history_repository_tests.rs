@@ -0,0 +1,126 @@
+use ferrous_dns_application::ports::DnssecHistoryRepository;
+use ferrous_dns_domain::{DnssecStatusChange, DnssecValidationCounts, RecordType};
+use ferrous_dns_infrastructure::repositories::SqliteDnssecHistoryRepository;
+use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
+
+async fn create_test_db() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::raw_sql(include_str!(
+        "../../../migrations/20260329000001_create_dnssec_history.sql"
+    ))
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    pool
+}
+
+fn change(
+    domain: &str,
+    status: &'static str,
+    previous: Option<&'static str>,
+    observed_at: &str,
+) -> DnssecStatusChange {
+    DnssecStatusChange {
+        id: None,
+        domain: domain.to_string(),
+        record_type: RecordType::A,
+        status,
+        previous_status: previous,
+        observed_at: observed_at.to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_samples_are_returned_oldest_first() {
+    let repo = SqliteDnssecHistoryRepository::new(create_test_db().await);
+    let first = DnssecValidationCounts {
+        secure: 10,
+        insecure: 4,
+        bogus: 1,
+        indeterminate: 0,
+    };
+    let second = DnssecValidationCounts {
+        secure: 3,
+        ..Default::default()
+    };
+    repo.insert_sample(&first).await.unwrap();
+    repo.insert_sample(&second).await.unwrap();
+
+    let samples = repo.get_samples(24).await.unwrap();
+
+    assert_eq!(samples.len(), 2);
+    assert_eq!(samples[0].counts, first);
+    assert_eq!(samples[1].counts, second);
+}
+
+#[tokio::test]
+async fn test_changes_newest_first_with_filters() {
+    let repo = SqliteDnssecHistoryRepository::new(create_test_db().await);
+    repo.insert_changes(&[
+        change(
+            "example.com",
+            "Bogus",
+            Some("Secure"),
+            "2026-01-01 10:00:00",
+        ),
+        change("example.org", "Bogus", None, "2026-01-01 11:00:00"),
+        change(
+            "example.com",
+            "Secure",
+            Some("Bogus"),
+            "2026-01-01 12:00:00",
+        ),
+    ])
+    .await
+    .unwrap();
+
+    let domain = repo
+        .get_changes(Some("example.com"), None, 10)
+        .await
+        .unwrap();
+    assert_eq!(domain.len(), 2);
+    assert!(domain.iter().all(|c| c.id.is_some()));
+    assert_eq!(domain[0].status, "Secure");
+    assert_eq!(domain[0].previous_status, Some("Bogus"));
+    assert_eq!(domain[0].record_type, RecordType::A);
+
+    let bogus = repo.get_changes(None, Some("Bogus"), 10).await.unwrap();
+    let domains: Vec<&str> = bogus.iter().map(|c| c.domain.as_str()).collect();
+    assert_eq!(domains, vec!["example.org", "example.com"]);
+
+    let limited = repo.get_changes(None, None, 1).await.unwrap();
+    assert_eq!(limited.len(), 1);
+}
+
+#[tokio::test]
+async fn test_delete_older_than_prunes_old_history() {
+    let pool = create_test_db().await;
+    let repo = SqliteDnssecHistoryRepository::new(pool.clone());
+    repo.insert_sample(&DnssecValidationCounts::default())
+        .await
+        .unwrap();
+    sqlx::query("INSERT INTO dnssec_stats (secure, recorded_at) VALUES (1, '2020-01-01 00:00:00')")
+        .execute(&pool)
+        .await
+        .unwrap();
+    let recent = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    repo.insert_changes(&[
+        change("old.example", "Bogus", None, "2020-01-01 00:00:00"),
+        change("new.example", "Bogus", None, &recent),
+    ])
+    .await
+    .unwrap();
+
+    let deleted = repo.delete_older_than(30).await.unwrap();
+
+    assert_eq!(deleted, 2);
+    assert_eq!(repo.get_samples(24).await.unwrap().len(), 1);
+    let changes = repo.get_changes(None, None, 10).await.unwrap();
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].domain, "new.example");
+}
@@ -1,4 +1,5 @@
-use ferrous_dns_domain::{UpstreamPool, UpstreamStrategy};
+use ferrous_dns_application::ports::DnssecStatusPort;
+use ferrous_dns_domain::{RecordType, UdpSocketMode, UpstreamPool, UpstreamStrategy};
 use ferrous_dns_infrastructure::dns::dnssec::{
     cache::{DnskeyEntry, DsEntry, ValidationEntry},
     ChainVerifier, DnskeyRecord, DnssecCache, DsRecord, SignatureVerifier, TrustAnchorStore,
@@ -472,6 +473,8 @@ fn make_chain_verifier_for_test() -> ChainVerifier {
         priority: 1,
         servers: vec!["udp://127.0.0.1:5353".into()],
         weight: None,
+        tsig_key: None,
+        udp_socket: UdpSocketMode::Pooled,
     };
     let rt = tokio::runtime::Runtime::new().unwrap();
     let pm = Arc::new(
@@ -516,3 +519,73 @@ fn test_validation_result_as_str_bogus() {
 fn test_validation_result_as_str_indeterminate() {
     assert_eq!(ValidationResult::Indeterminate.as_str(), "Indeterminate");
 }
+
+// ============================================================================
+// DnssecCache — recorded outcomes
+// ============================================================================
+
+#[test]
+fn test_record_validation_counts_outcomes_and_drains() {
+    let cache = DnssecCache::new();
+    cache.record_validation("example.com", RecordType::A, ValidationResult::Secure, 300);
+    cache.record_validation(
+        "example.org",
+        RecordType::A,
+        ValidationResult::Insecure,
+        300,
+    );
+    cache.record_validation(
+        "example.com",
+        RecordType::AAAA,
+        ValidationResult::Secure,
+        300,
+    );
+
+    let outcomes = cache.take_outcomes();
+    assert_eq!(outcomes.counts.secure, 2);
+    assert_eq!(outcomes.counts.insecure, 1);
+    assert!(outcomes.changes.is_empty());
+
+    let drained = cache.take_outcomes();
+    assert_eq!(drained.counts.total(), 0);
+}
+
+#[test]
+fn test_record_validation_reports_status_changes() {
+    let cache = DnssecCache::new();
+    cache.record_validation("example.com", RecordType::A, ValidationResult::Secure, 300);
+    cache.record_validation("example.com", RecordType::A, ValidationResult::Secure, 300);
+    cache.record_validation("example.com", RecordType::A, ValidationResult::Bogus, 300);
+    cache.record_validation("broken.test", RecordType::A, ValidationResult::Bogus, 300);
+
+    let changes = cache.take_outcomes().changes;
+    assert_eq!(changes.len(), 2);
+    assert_eq!(changes[0].domain, "example.com");
+    assert_eq!(changes[0].status, "Bogus");
+    assert_eq!(changes[0].previous_status, Some("Secure"));
+    assert_eq!(changes[1].domain, "broken.test");
+    assert_eq!(changes[1].previous_status, None);
+}
+
+#[test]
+fn test_cached_lists_results_for_domain() {
+    let cache = DnssecCache::new();
+    cache.record_validation("example.com", RecordType::A, ValidationResult::Secure, 300);
+    cache.record_validation("example.com", RecordType::MX, ValidationResult::Secure, 0);
+    cache.record_validation(
+        "example.org",
+        RecordType::A,
+        ValidationResult::Insecure,
+        300,
+    );
+
+    let mut cached = cache.cached("example.com");
+    cached.sort_by_key(|validation| validation.record_type.as_str());
+
+    assert_eq!(cached.len(), 2);
+    assert_eq!(cached[0].record_type, RecordType::A);
+    assert!(!cached[0].expired);
+    assert_eq!(cached[1].record_type, RecordType::MX);
+    assert!(cached[1].expired);
+    assert!(cache.cached("missing.test").is_empty());
+}
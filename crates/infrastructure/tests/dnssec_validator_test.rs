@@ -1,4 +1,4 @@
-use ferrous_dns_domain::{UpstreamPool, UpstreamStrategy};
+use ferrous_dns_domain::{UdpSocketMode, UpstreamPool, UpstreamStrategy};
 use ferrous_dns_infrastructure::dns::dnssec::trust_anchor::TrustAnchorStore;
 use ferrous_dns_infrastructure::dns::dnssec::{DnskeyRecord, DnssecValidator, ValidationResult};
 use ferrous_dns_infrastructure::dns::PoolManager;
@@ -16,6 +16,8 @@ fn make_validator() -> DnssecValidator {
         priority: 1,
         servers: vec!["udp://127.0.0.1:5353".into()],
         weight: None,
+        tsig_key: None,
+        udp_socket: UdpSocketMode::Pooled,
     };
     let rt = tokio::runtime::Runtime::new().unwrap();
     let pm = Arc::new(
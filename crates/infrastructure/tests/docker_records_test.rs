@@ -0,0 +1,217 @@
+use ferrous_dns_application::ports::RecordSource;
+use ferrous_dns_domain::{DockerConfig, LocalRecord, RecordType};
+use ferrous_dns_infrastructure::discovery::{container_records, Container, DockerRecordSource};
+use serde_json::json;
+use std::path::Path;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::UnixListener;
+
+fn containers(value: serde_json::Value) -> Vec<Container> {
+    serde_json::from_value(value).unwrap()
+}
+
+fn summary(records: &[LocalRecord]) -> Vec<(String, RecordType, String)> {
+    let mut summary: Vec<_> = records
+        .iter()
+        .map(|r| (r.fqdn(&None), r.record_type, r.value.to_string()))
+        .collect();
+    summary.sort_by(|a, b| a.0.cmp(&b.0).then(a.2.cmp(&b.2)));
+    summary
+}
+
+fn compose_container() -> serde_json::Value {
+    json!([{
+        "Id": "4f1c2b3a9e8d7c6b5a4f3e2d1c0b9a8f7e6d5c4b3a2f1e0d9c8b7a6f5e4d3c2b",
+        "Names": ["/media-jellyfin-1"],
+        "State": "running",
+        "NetworkSettings": {
+            "Networks": {
+                "media_default": {
+                    "Aliases": ["media-jellyfin-1", "jellyfin", "4f1c2b3a9e8d"],
+                    "IPAddress": "172.18.0.5",
+                    "GlobalIPv6Address": ""
+                },
+                "proxy": {
+                    "Aliases": null,
+                    "IPAddress": "172.20.0.3",
+                    "GlobalIPv6Address": "fd00:20::3"
+                }
+            }
+        }
+    }])
+}
+
+fn entry(name: &str, record_type: RecordType, value: &str) -> (String, RecordType, String) {
+    (name.to_string(), record_type, value.to_string())
+}
+
+#[test]
+fn test_container_is_published_on_every_network() {
+    let config = DockerConfig::default();
+
+    let records = container_records(&config, &containers(compose_container()));
+
+    assert_eq!(
+        summary(&records),
+        vec![
+            entry("jellyfin.docker.lan", RecordType::A, "172.18.0.5"),
+            entry("media-jellyfin-1.docker.lan", RecordType::A, "172.18.0.5"),
+            entry("media-jellyfin-1.docker.lan", RecordType::A, "172.20.0.3"),
+            entry(
+                "media-jellyfin-1.docker.lan",
+                RecordType::AAAA,
+                "fd00:20::3"
+            ),
+        ]
+    );
+    assert_eq!(records[0].ttl, 30);
+}
+
+#[test]
+fn test_networks_filter_limits_published_addresses() {
+    let config = DockerConfig {
+        networks: vec!["proxy".to_string()],
+        ..Default::default()
+    };
+
+    let records = container_records(&config, &containers(compose_container()));
+
+    assert_eq!(
+        summary(&records),
+        vec![
+            entry("media-jellyfin-1.docker.lan", RecordType::A, "172.20.0.3"),
+            entry(
+                "media-jellyfin-1.docker.lan",
+                RecordType::AAAA,
+                "fd00:20::3"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_aliases_can_be_left_out() {
+    let config = DockerConfig {
+        include_aliases: false,
+        networks: vec!["media_default".to_string()],
+        ..Default::default()
+    };
+
+    let records = container_records(&config, &containers(compose_container()));
+
+    assert_eq!(
+        summary(&records),
+        vec![entry(
+            "media-jellyfin-1.docker.lan",
+            RecordType::A,
+            "172.18.0.5"
+        )]
+    );
+}
+
+#[test]
+fn test_host_network_container_publishes_nothing() {
+    let config = DockerConfig::default();
+    let host = containers(json!([{
+        "Id": "9a8b7c",
+        "Names": ["/pihole"],
+        "NetworkSettings": {
+            "Networks": { "host": { "IPAddress": "", "GlobalIPv6Address": "" } }
+        }
+    }]));
+
+    assert!(container_records(&config, &host).is_empty());
+}
+
+/// Serves `response` to every connection on a socket in `dir`.
+fn serve(dir: &Path, response: String) -> DockerConfig {
+    let socket = dir.join("docker.sock");
+    let listener = UnixListener::bind(&socket).unwrap();
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let response = response.clone();
+            tokio::spawn(async move {
+                let mut request = Vec::new();
+                let mut buf = [0u8; 512];
+                while !request.ends_with(b"\r\n\r\n") {
+                    match stream.read(&mut buf).await {
+                        Ok(0) | Err(_) => return,
+                        Ok(n) => request.extend_from_slice(&buf[..n]),
+                    }
+                }
+                let _ = stream.write_all(response.as_bytes()).await;
+                tokio::time::sleep(Duration::from_millis(200)).await;
+            });
+        }
+    });
+    DockerConfig {
+        enabled: true,
+        socket: socket.to_string_lossy().into_owned(),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_fetch_lists_containers_over_the_socket() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = serve(
+        dir.path(),
+        format!(
+            "HTTP/1.0 200 OK\r\nContent-Type: application/json\r\n\r\n{}",
+            compose_container()
+        ),
+    );
+    let source = DockerRecordSource::new(&config);
+
+    let records = tokio::time::timeout(Duration::from_secs(5), source.fetch())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(records.len(), 4);
+}
+
+#[tokio::test]
+async fn test_fetch_reports_engine_errors() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = serve(
+        dir.path(),
+        "HTTP/1.0 500 Internal Server Error\r\n\r\n{\"message\":\"boom\"}".to_string(),
+    );
+    let source = DockerRecordSource::new(&config);
+
+    let error = source.fetch().await.unwrap_err();
+
+    assert!(error.to_string().contains("HTTP 500"));
+}
+
+#[tokio::test]
+async fn test_fetch_fails_without_a_socket() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = DockerConfig {
+        enabled: true,
+        socket: dir
+            .path()
+            .join("missing.sock")
+            .to_string_lossy()
+            .into_owned(),
+        ..Default::default()
+    };
+
+    assert!(DockerRecordSource::new(&config).fetch().await.is_err());
+}
+
+#[tokio::test]
+async fn test_changed_resolves_on_container_event() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = serve(
+        dir.path(),
+        "HTTP/1.0 200 OK\r\n\r\n{\"Type\":\"container\",\"Action\":\"start\"}\n".to_string(),
+    );
+    let source = DockerRecordSource::new(&config);
+
+    tokio::time::timeout(Duration::from_secs(5), source.changed())
+        .await
+        .expect("event should trigger a change");
+}
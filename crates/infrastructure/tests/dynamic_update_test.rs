@@ -0,0 +1,294 @@
+use ferrous_dns_application::ports::{AuditLogRepository, LocalRecordRepository, LocalZonePort};
+use ferrous_dns_application::use_cases::ApplyDnsUpdateUseCase;
+use ferrous_dns_domain::{
+    AuditAction, AuditEvent, Config, TsigAlgorithm, TsigKeyConfig, TsigKeyFile, UpdateZoneConfig,
+};
+use ferrous_dns_infrastructure::dns::forwarding::TsigKeyring;
+use ferrous_dns_infrastructure::dns::{DynamicUpdateHandler, LocalZoneStore};
+use ferrous_dns_infrastructure::repositories::{
+    SqliteAuditLogRepository, SqliteLocalRecordRepository,
+};
+use hickory_proto::op::{Message, MessageType, OpCode, Query, ResponseCode};
+use hickory_proto::rr::rdata::A;
+use hickory_proto::rr::{DNSClass, Name, RData, Record, RecordType};
+use sqlx::sqlite::SqlitePoolOptions;
+use sqlx::SqlitePool;
+use std::net::IpAddr;
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+const CLIENT: IpAddr = IpAddr::V4(std::net::Ipv4Addr::new(192, 168, 1, 1));
+const SECRET: &str = "c2VjcmV0LWtleS1tYXRlcmlhbC0wMTIzNDU2Nzg5";
+
+async fn create_pool() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    for migration in [
+        include_str!("../../../migrations/20260316000001_create_local_records.sql"),
+        include_str!("../../../migrations/20260317000001_create_tenants.sql"),
+        include_str!("../../../migrations/20260317000003_add_tenant_to_local_records.sql"),
+        include_str!("../../../migrations/20260320000001_create_audit_log.sql"),
+    ] {
+        sqlx::raw_sql(migration).execute(&pool).await.unwrap();
+    }
+    pool
+}
+
+fn keyring() -> TsigKeyring {
+    TsigKeyring::from_file(&TsigKeyFile {
+        keys: vec![TsigKeyConfig {
+            name: "dhcp-update".to_string(),
+            algorithm: TsigAlgorithm::HmacSha256,
+            secret: SECRET.to_string(),
+        }],
+    })
+    .unwrap()
+}
+
+struct Fixture {
+    records: Arc<SqliteLocalRecordRepository>,
+    zone: Arc<LocalZoneStore>,
+    audit: Arc<SqliteAuditLogRepository>,
+    handler: DynamicUpdateHandler,
+}
+
+impl Fixture {
+    async fn new() -> Self {
+        let mut config = Config::default();
+        config.dns.tsig_keys_file = Some("/etc/ferrous-dns/tsig.toml".to_string());
+        config.dns.update_zones = vec![
+            UpdateZoneConfig {
+                zone: "home.lan".to_string(),
+                allow_clients: vec!["192.168.1.0/24".to_string()],
+                tsig_keys: vec![],
+            },
+            UpdateZoneConfig {
+                zone: "secure.lan".to_string(),
+                allow_clients: vec![],
+                tsig_keys: vec!["dhcp-update".to_string()],
+            },
+        ];
+
+        let pool = create_pool().await;
+        let records = Arc::new(SqliteLocalRecordRepository::new(pool.clone()));
+        let zone = LocalZoneStore::new(records.clone(), None).await.unwrap();
+        let audit = Arc::new(SqliteAuditLogRepository::new(pool));
+        let zones = config.dns.update_zones.clone();
+        let use_case = ApplyDnsUpdateUseCase::new(
+            Arc::new(RwLock::new(config)),
+            records.clone(),
+            zone.clone(),
+        )
+        .with_audit_log(audit.clone());
+        Self {
+            records,
+            zone,
+            audit,
+            handler: DynamicUpdateHandler::new(Arc::new(use_case), Arc::new(keyring()), &zones)
+                .unwrap(),
+        }
+    }
+
+    async fn send(&self, request: &Message) -> Message {
+        let wire = self
+            .handler
+            .handle(&request.to_vec().unwrap(), CLIENT)
+            .await
+            .expect("response");
+        Message::from_vec(&wire).unwrap()
+    }
+}
+
+fn name(s: &str) -> Name {
+    Name::from_str(s).unwrap()
+}
+
+fn update_message(zone: &str, updates: Vec<Record>) -> Message {
+    let mut message = Message::new(0x4242, MessageType::Query, OpCode::Update);
+    let mut query = Query::query(name(zone), RecordType::SOA);
+    query.set_query_class(DNSClass::IN);
+    message.add_query(query);
+    for record in updates {
+        message.add_name_server(record);
+    }
+    message
+}
+
+fn a(owner: &str, ip: &str) -> Record {
+    Record::from_rdata(name(owner), 300, RData::A(A::from_str(ip).unwrap()))
+}
+
+fn delete_rrset(owner: &str, record_type: RecordType) -> Record {
+    let mut record = Record::update0(name(owner), 0, record_type);
+    record.set_dns_class(DNSClass::ANY);
+    record
+}
+
+// ── Updates ──────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn added_record_is_stored_and_served() {
+    let fixture = Fixture::new().await;
+
+    let response = fixture
+        .send(&update_message(
+            "home.lan.",
+            vec![a("laptop.home.lan.", "192.168.1.60")],
+        ))
+        .await;
+
+    assert_eq!(response.id(), 0x4242);
+    assert_eq!(response.op_code(), OpCode::Update);
+    assert_eq!(response.message_type(), MessageType::Response);
+    assert_eq!(response.response_code(), ResponseCode::NoError);
+    assert_eq!(response.queries().len(), 1);
+
+    let stored = fixture.records.get_all().await.unwrap();
+    assert_eq!(stored.len(), 1);
+    assert_eq!(&*stored[0].hostname, "laptop");
+    assert_eq!(stored[0].domain.as_deref(), Some("home.lan"));
+    assert!(fixture
+        .zone
+        .lookup("laptop.home.lan", ferrous_dns_domain::RecordType::A)
+        .is_some());
+}
+
+#[tokio::test]
+async fn class_any_deletes_the_rrset() {
+    let fixture = Fixture::new().await;
+    fixture
+        .send(&update_message(
+            "home.lan.",
+            vec![
+                a("laptop.home.lan.", "192.168.1.60"),
+                a("laptop.home.lan.", "192.168.1.61"),
+            ],
+        ))
+        .await;
+
+    let response = fixture
+        .send(&update_message(
+            "home.lan.",
+            vec![delete_rrset("laptop.home.lan.", RecordType::A)],
+        ))
+        .await;
+
+    assert_eq!(response.response_code(), ResponseCode::NoError);
+    assert!(fixture.records.get_all().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn failed_prerequisite_answers_its_rcode() {
+    let fixture = Fixture::new().await;
+    let mut request = update_message("home.lan.", vec![a("laptop.home.lan.", "192.168.1.60")]);
+    let mut prerequisite = Record::update0(name("laptop.home.lan."), 0, RecordType::A);
+    prerequisite.set_dns_class(DNSClass::ANY);
+    request.add_answer(prerequisite);
+
+    let response = fixture.send(&request).await;
+
+    assert_eq!(response.response_code(), ResponseCode::NXRRSet);
+    assert!(fixture.records.get_all().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn zone_section_must_be_one_soa_question() {
+    let fixture = Fixture::new().await;
+    let mut request = Message::new(1, MessageType::Query, OpCode::Update);
+    request.add_query(Query::query(name("home.lan."), RecordType::A));
+
+    let response = fixture.send(&request).await;
+
+    assert_eq!(response.response_code(), ResponseCode::FormErr);
+}
+
+// ── TSIG ─────────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn signed_update_is_applied_and_answered_signed() {
+    let fixture = Fixture::new().await;
+    let key = keyring().get("dhcp-update").unwrap();
+    let mut wire = update_message("secure.lan.", vec![a("nas.secure.lan.", "10.0.0.10")])
+        .to_vec()
+        .unwrap();
+    let request_mac = key.sign(&mut wire, None).unwrap();
+
+    let response = fixture.handler.handle(&wire, CLIENT).await.unwrap();
+
+    let (unsigned, _) = key.verify(&response, Some(&request_mac)).unwrap();
+    let response = Message::from_vec(&unsigned).unwrap();
+    assert_eq!(response.response_code(), ResponseCode::NoError);
+    assert_eq!(fixture.records.get_all().await.unwrap().len(), 1);
+
+    let events = fixture.audit.get_recent(10, None).await.unwrap();
+    assert_eq!(events[0].actor.as_deref(), Some("dhcp-update"));
+    assert_eq!(events[0].target, "secure.lan");
+}
+
+#[tokio::test]
+async fn unsigned_update_to_a_key_only_zone_is_refused() {
+    let fixture = Fixture::new().await;
+
+    let response = fixture
+        .send(&update_message(
+            "secure.lan.",
+            vec![a("nas.secure.lan.", "10.0.0.10")],
+        ))
+        .await;
+
+    assert_eq!(response.response_code(), ResponseCode::Refused);
+    assert!(fixture.records.get_all().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn update_signed_with_an_unknown_key_is_not_authorized() {
+    let fixture = Fixture::new().await;
+    let stranger = ferrous_dns_infrastructure::dns::forwarding::TsigKey::new(&TsigKeyConfig {
+        name: "stranger".to_string(),
+        algorithm: TsigAlgorithm::HmacSha256,
+        secret: SECRET.to_string(),
+    })
+    .unwrap();
+    let mut wire = update_message("secure.lan.", vec![a("nas.secure.lan.", "10.0.0.10")])
+        .to_vec()
+        .unwrap();
+    stranger.sign(&mut wire, None).unwrap();
+
+    let response = fixture.handler.handle(&wire, CLIENT).await.unwrap();
+
+    let response = Message::from_vec(&response).unwrap();
+    assert_eq!(response.response_code(), ResponseCode::NotAuth);
+    assert!(fixture.records.get_all().await.unwrap().is_empty());
+}
+
+// ── Audit log ────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn audit_log_returns_newest_first_and_filters_by_action() {
+    let repo = SqliteAuditLogRepository::new(create_pool().await);
+    for zone in ["home.lan", "secure.lan"] {
+        let event = AuditEvent::new(
+            AuditAction::DnsUpdate,
+            "192.168.1.1",
+            zone,
+            "NOERROR",
+            "added 1, removed 0",
+        );
+        let stored = repo.insert(&event).await.unwrap();
+        assert!(stored.id.is_some());
+        assert!(stored.created_at.is_some());
+    }
+
+    let events = repo
+        .get_recent(1, Some(AuditAction::DnsUpdate))
+        .await
+        .unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].target, "secure.lan");
+    assert_eq!(events[0].actor, None);
+
+    assert_eq!(repo.delete_older_than(1).await.unwrap(), 0);
+}
@@ -1,10 +1,18 @@
-use ferrous_dns_domain::DomainError;
+use ferrous_dns_domain::{BlockSource, DomainError};
 use ferrous_dns_infrastructure::dns::ede::{self, codes, OPTION_CODE};
 
 #[test]
-fn should_return_blocked_when_domain_blocked() {
-    let ede = ede::from_domain_error(&DomainError::Blocked).unwrap();
-    assert_eq!(ede.info_code, codes::BLOCKED);
+fn should_return_filtered_when_domain_blocked() {
+    let ede = ede::from_domain_error(&DomainError::Blocked(BlockSource::Blocklist)).unwrap();
+    assert_eq!(ede.info_code, codes::FILTERED);
+    assert_eq!(codes::FILTERED, 17);
+}
+
+#[test]
+fn should_return_prohibited_when_client_not_allowed() {
+    let ede = ede::from_domain_error(&DomainError::ClientNotAllowed).unwrap();
+    assert_eq!(ede.info_code, codes::PROHIBITED);
+    assert_eq!(ede.extra_text, Some("client not allowed on this listener"));
 }
 
 #[test]
@@ -44,6 +52,13 @@ fn should_return_no_reachable_authority_when_query_timeout() {
     assert_eq!(ede.info_code, codes::NO_REACHABLE_AUTHORITY);
 }
 
+#[test]
+fn should_return_no_reachable_authority_when_query_budget_exhausted() {
+    let ede = ede::from_domain_error(&DomainError::QueryBudgetExceeded).unwrap();
+    assert_eq!(ede.info_code, codes::NO_REACHABLE_AUTHORITY);
+    assert_eq!(ede.extra_text, Some("query time budget exhausted"));
+}
+
 #[test]
 fn should_return_no_reachable_authority_when_no_healthy_servers() {
     let ede = ede::from_domain_error(&DomainError::TransportNoHealthyServers).unwrap();
@@ -94,15 +109,48 @@ fn should_return_none_when_unrelated_error() {
 }
 
 #[test]
-fn should_return_blocked_when_filtered_query() {
+fn should_return_filtered_when_filtered_query() {
     let ede = ede::from_domain_error(&DomainError::FilteredQuery("private PTR".into())).unwrap();
-    assert_eq!(ede.info_code, codes::BLOCKED);
+    assert_eq!(ede.info_code, codes::FILTERED);
+}
+
+#[test]
+fn should_return_not_supported_when_query_unsupported() {
+    let ede =
+        ede::from_domain_error(&DomainError::UnsupportedQuery("opcode Update".into())).unwrap();
+    assert_eq!(ede.info_code, codes::NOT_SUPPORTED);
+}
+
+#[test]
+fn should_return_other_when_query_malformed() {
+    let ede = ede::from_domain_error(&DomainError::MalformedQuery("no question".into())).unwrap();
+    assert_eq!(ede.info_code, codes::OTHER);
+    assert_eq!(ede.extra_text, Some("malformed query"));
+}
+
+#[test]
+fn should_return_other_when_upstream_response_invalid() {
+    let ede = ede::from_domain_error(&DomainError::InvalidDnsResponse("truncated header".into()))
+        .unwrap();
+    assert_eq!(ede.info_code, codes::OTHER);
 }
 
 #[test]
-fn should_return_extra_text_when_blocked() {
-    let ede = ede::from_domain_error(&DomainError::Blocked).unwrap();
-    assert_eq!(ede.extra_text, Some("domain is in blocklist"));
+fn should_return_extra_text_naming_block_source() {
+    let cases = [
+        (BlockSource::Blocklist, "blocked by blocklist"),
+        (BlockSource::RegexFilter, "blocked by regex filter"),
+        (BlockSource::ManagedDomain, "blocked by managed domain rule"),
+        (BlockSource::QueryPolicy, "blocked by query policy"),
+        (
+            BlockSource::ResponseIpFilter,
+            "blocked by response IP filter",
+        ),
+    ];
+    for (source, text) in cases {
+        let ede = ede::from_domain_error(&DomainError::Blocked(source)).unwrap();
+        assert_eq!(ede.extra_text, Some(text));
+    }
 }
 
 #[test]
@@ -0,0 +1,159 @@
+use ferrous_dns_application::ports::DeviceAddressRegistry;
+use ferrous_dns_domain::EdnsClientIdConfig;
+use ferrous_dns_infrastructure::dns::EdnsClientIdentifier;
+use hickory_proto::rr::rdata::opt::{ClientSubnet, EdnsCode, EdnsOption};
+use std::net::IpAddr;
+
+const FORWARDER: &str = "192.168.1.1";
+const DEVICE_MAC: [u8; 6] = [0xaa, 0xbb, 0xcc, 0x00, 0x11, 0x22];
+
+fn ip(s: &str) -> IpAddr {
+    s.parse().unwrap()
+}
+
+fn identifier(option_codes: Vec<u16>) -> EdnsClientIdentifier {
+    let identifier = EdnsClientIdentifier::from_config(&EdnsClientIdConfig {
+        enabled: true,
+        option_codes,
+        forwarders: vec![format!("{FORWARDER}/32")],
+        ..Default::default()
+    })
+    .expect("enabled identifier");
+    identifier.replace(vec![("aa:bb:cc:00:11:22".to_string(), ip("192.168.1.50"))]);
+    identifier
+}
+
+fn option(code: u16, data: &[u8]) -> (EdnsCode, EdnsOption) {
+    (
+        EdnsCode::from(code),
+        EdnsOption::Unknown(code, data.to_vec()),
+    )
+}
+
+#[test]
+fn test_disabled_config_builds_nothing() {
+    assert!(EdnsClientIdentifier::from_config(&EdnsClientIdConfig::default()).is_none());
+}
+
+#[test]
+fn test_raw_mac_from_forwarder_resolves_to_device() {
+    let identifier = identifier(vec![65001]);
+    let options = [option(65001, &DEVICE_MAC)];
+
+    assert_eq!(
+        identifier.identify(ip(FORWARDER), &options),
+        ip("192.168.1.50")
+    );
+}
+
+#[test]
+fn test_text_mac_is_accepted() {
+    let identifier = identifier(vec![65001]);
+    let options = [option(65001, b"AA-BB-CC-00-11-22")];
+
+    assert_eq!(
+        identifier.identify(ip(FORWARDER), &options),
+        ip("192.168.1.50")
+    );
+}
+
+#[test]
+fn test_untrusted_client_keeps_its_address() {
+    let identifier = identifier(vec![65001]);
+    let options = [option(65001, &DEVICE_MAC)];
+
+    assert!(!identifier.is_forwarder(ip("192.168.1.77")));
+    assert_eq!(
+        identifier.identify(ip("192.168.1.77"), &options),
+        ip("192.168.1.77")
+    );
+}
+
+#[test]
+fn test_unknown_mac_or_unconfigured_code_keeps_forwarder() {
+    let identifier = identifier(vec![65001]);
+
+    let unknown = [option(65001, &[0x02, 0, 0, 0, 0, 0x01])];
+    assert_eq!(identifier.identify(ip(FORWARDER), &unknown), ip(FORWARDER));
+
+    let other_code = [option(65074, &DEVICE_MAC)];
+    assert_eq!(
+        identifier.identify(ip(FORWARDER), &other_code),
+        ip(FORWARDER)
+    );
+
+    let placeholder = [option(65001, &[0; 6])];
+    assert_eq!(
+        identifier.identify(ip(FORWARDER), &placeholder),
+        ip(FORWARDER)
+    );
+}
+
+#[test]
+fn test_replace_drops_devices_no_longer_seen() {
+    let identifier = identifier(vec![65074, 65001]);
+    let options = [option(65001, &DEVICE_MAC)];
+    assert_eq!(
+        identifier.identify(ip(FORWARDER), &options),
+        ip("192.168.1.50")
+    );
+
+    identifier.replace(vec![]);
+    assert_eq!(identifier.identify(ip(FORWARDER), &options), ip(FORWARDER));
+}
+
+fn subnet(address: &str, prefix: u8) -> (EdnsCode, EdnsOption) {
+    (
+        EdnsCode::Subnet,
+        EdnsOption::Subnet(ClientSubnet::new(ip(address), prefix, 0)),
+    )
+}
+
+#[test]
+fn test_host_client_subnet_from_forwarder_is_used() {
+    let identifier = identifier(vec![65001]);
+
+    let v4 = [subnet("192.168.1.60", 32)];
+    assert_eq!(identifier.identify(ip(FORWARDER), &v4), ip("192.168.1.60"));
+
+    let v6 = [subnet("fd00::60", 128)];
+    assert_eq!(identifier.identify(ip(FORWARDER), &v6), ip("fd00::60"));
+
+    assert_eq!(
+        identifier.identify(ip("192.168.1.77"), &v4),
+        ip("192.168.1.77")
+    );
+}
+
+#[test]
+fn test_truncated_client_subnet_keeps_forwarder() {
+    let identifier = identifier(vec![65001]);
+    let options = [subnet("192.168.1.0", 24)];
+
+    assert_eq!(identifier.identify(ip(FORWARDER), &options), ip(FORWARDER));
+}
+
+#[test]
+fn test_known_mac_wins_over_client_subnet() {
+    let identifier = identifier(vec![65001]);
+    let options = [subnet("192.168.1.60", 32), option(65001, &DEVICE_MAC)];
+
+    assert_eq!(
+        identifier.identify(ip(FORWARDER), &options),
+        ip("192.168.1.50")
+    );
+}
+
+#[test]
+fn test_client_subnet_can_be_disabled() {
+    let identifier = EdnsClientIdentifier::from_config(&EdnsClientIdConfig {
+        enabled: true,
+        forwarders: vec![FORWARDER.to_string()],
+        use_client_subnet: false,
+        ..Default::default()
+    })
+    .expect("enabled identifier");
+    let options = [subnet("192.168.1.60", 32)];
+
+    assert_eq!(identifier.identify(ip(FORWARDER), &options), ip(FORWARDER));
+}
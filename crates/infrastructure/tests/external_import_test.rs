@@ -0,0 +1,420 @@
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{
+    BlocklistSourceRepository, ClientSubnetRepository, ExternalConfigReader, ExternalFormat,
+    GroupRepository, LocalRecordCreator, ManagedDomainRepository, RegexFilterRepository,
+    WhitelistSourceRepository,
+};
+use ferrous_dns_application::use_cases::ImportExternalConfigUseCase;
+use ferrous_dns_domain::config::DatabaseConfig;
+use ferrous_dns_domain::{Config, DomainAction, DomainError, LocalDnsRecord};
+use ferrous_dns_infrastructure::database::create_write_pool;
+use ferrous_dns_infrastructure::external_import::ExternalConfigFileReader;
+use ferrous_dns_infrastructure::repositories::{
+    SqliteBlocklistSourceRepository, SqliteClientRepository, SqliteClientSubnetRepository,
+    SqliteGroupRepository, SqliteManagedDomainRepository, SqliteRegexFilterRepository,
+    SqliteWhitelistSourceRepository,
+};
+use flate2::{write::GzEncoder, Compression};
+use sqlx::SqlitePool;
+use tempfile::TempDir;
+use tokio::sync::RwLock;
+
+const ADGUARD_YAML: &str = r#"
+schema_version: 28
+filters:
+  - enabled: true
+    url: https://adguardteam.github.io/HostlistsRegistry/assets/filter_1.txt
+    name: AdGuard DNS filter
+    id: 1
+  - enabled: false
+    url: https://example.org/disabled.txt
+    name: Disabled list
+    id: 2
+whitelist_filters:
+  - enabled: true
+    url: https://example.org/allow.txt
+    name: Allow list
+    id: 3
+user_rules:
+  - '! comment'
+  - '||tracker.example.com^'
+  - '@@||cdn.example.net^'
+  - '/^ads[0-9]+\./'
+  - '||example.org^$client=10.0.0.5'
+filtering:
+  rewrites:
+    - domain: nas.home.lan
+      answer: 192.168.1.10
+    - domain: '*.wild.lan'
+      answer: 192.168.1.11
+    - domain: alias.home.lan
+      answer: nas.home.lan
+clients:
+  persistent:
+    - name: Kid tablet
+      ids:
+        - 192.168.1.20
+        - 10.20.0.0/16
+        - aa:bb:cc:dd:ee:ff
+"#;
+
+fn tar_gz(files: &[(&str, &str)]) -> Vec<u8> {
+    let mut builder = tar::Builder::new(GzEncoder::new(Vec::new(), Compression::default()));
+    for (name, contents) in files {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(contents.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder
+            .append_data(&mut header, name, contents.as_bytes())
+            .unwrap();
+    }
+    builder.into_inner().unwrap().finish().unwrap()
+}
+
+fn pihole_v5_archive() -> Vec<u8> {
+    tar_gz(&[
+        (
+            "group.json",
+            r#"[{"id":0,"enabled":1,"name":"Default","description":"The default group"},
+                {"id":1,"enabled":0,"name":"Kids (school)","description":"Homework"}]"#,
+        ),
+        (
+            "adlist.json",
+            r#"[{"id":1,"address":"https://example.org/hosts.txt","enabled":1,"comment":"Main"}]"#,
+        ),
+        (
+            "adlist_by_group.json",
+            r#"[{"adlist_id":1,"group_id":0},{"adlist_id":1,"group_id":1}]"#,
+        ),
+        (
+            "blacklist.exact.json",
+            r#"[{"id":1,"type":1,"domain":"ads.example.com","enabled":1,"comment":null}]"#,
+        ),
+        (
+            "whitelist.exact.json",
+            r#"[{"id":2,"type":0,"domain":"good.example.com","enabled":1,"comment":""}]"#,
+        ),
+        (
+            "blacklist.regex.json",
+            r#"[{"id":3,"type":3,"domain":"(\\.|^)doubleclick\\.net$","enabled":1},
+                {"id":4,"type":3,"domain":"^x;querytype=AAAA","enabled":1}]"#,
+        ),
+        (
+            "domainlist_by_group.json",
+            r#"[{"domainlist_id":1,"group_id":1},{"domainlist_id":2,"group_id":0}]"#,
+        ),
+        (
+            "client.json",
+            r#"[{"id":1,"ip":"192.168.1.50","comment":"Tablet"},{"id":2,"ip":":eth0","comment":null}]"#,
+        ),
+        ("client_by_group.json", r#"[{"client_id":1,"group_id":1}]"#),
+        ("custom.list", "192.168.1.10 nas.lan\n# comment\n"),
+        ("05-pihole-custom-cname.conf", "cname=files.lan,nas.lan\n"),
+    ])
+}
+
+async fn pihole_v6_archive(dir: &TempDir) -> Vec<u8> {
+    let db_path = dir.path().join("gravity.db");
+    let pool = SqlitePool::connect(&format!("sqlite:{}?mode=rwc", db_path.display()))
+        .await
+        .unwrap();
+    for sql in [
+        "CREATE TABLE \"group\" (id INTEGER PRIMARY KEY, enabled BOOLEAN, name TEXT, description TEXT)",
+        "CREATE TABLE adlist (id INTEGER PRIMARY KEY, address TEXT, enabled BOOLEAN, comment TEXT, type INTEGER)",
+        "CREATE TABLE adlist_by_group (adlist_id INTEGER, group_id INTEGER)",
+        "CREATE TABLE domainlist (id INTEGER PRIMARY KEY, type INTEGER, domain TEXT, enabled BOOLEAN, comment TEXT)",
+        "CREATE TABLE domainlist_by_group (domainlist_id INTEGER, group_id INTEGER)",
+        "CREATE TABLE client (id INTEGER PRIMARY KEY, ip TEXT, comment TEXT)",
+        "CREATE TABLE client_by_group (client_id INTEGER, group_id INTEGER)",
+        "INSERT INTO \"group\" VALUES (0, 1, 'Default', NULL), (1, 1, 'IoT', 'Devices')",
+        "INSERT INTO adlist VALUES (1, 'https://example.org/block.txt', 1, NULL, 0), (2, 'https://example.org/allow.txt', 1, NULL, 1)",
+        "INSERT INTO adlist_by_group VALUES (1, 1), (2, 0)",
+        "INSERT INTO domainlist VALUES (1, 1, 'telemetry.example.com', 1, NULL), (2, 2, '^good\\.', 0, NULL)",
+        "INSERT INTO domainlist_by_group VALUES (1, 1)",
+        "INSERT INTO client VALUES (1, '10.0.0.0/24', 'IoT VLAN')",
+        "INSERT INTO client_by_group VALUES (1, 1)",
+    ] {
+        sqlx::query(sql).execute(&pool).await.unwrap();
+    }
+    pool.close().await;
+
+    let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    let options = zip::write::SimpleFileOptions::default();
+    zip.start_file("etc/pihole/gravity.db", options).unwrap();
+    zip.write_all(&std::fs::read(&db_path).unwrap()).unwrap();
+    zip.start_file("etc/pihole/pihole.toml", options).unwrap();
+    zip.write_all(b"[dns]\nhosts = [\"192.168.1.30 printer.lan\"]\ncnameRecords = []\n")
+        .unwrap();
+    zip.finish().unwrap().into_inner()
+}
+
+#[tokio::test]
+async fn test_adguard_yaml_translates_filters_rules_rewrites_and_clients() {
+    let config = ExternalConfigFileReader
+        .read(ExternalFormat::AdGuardHome, ADGUARD_YAML.as_bytes())
+        .await
+        .unwrap();
+
+    assert_eq!(config.lists.len(), 3);
+    assert!(!config.lists[1].enabled);
+    assert!(config.lists[2].allow);
+
+    let rules: Vec<(&str, bool, DomainAction)> = config
+        .domain_rules
+        .iter()
+        .map(|r| (r.value.as_str(), r.regex, r.action))
+        .collect();
+    assert!(rules.contains(&("tracker.example.com", false, DomainAction::Deny)));
+    assert!(rules.contains(&("*.tracker.example.com", false, DomainAction::Deny)));
+    assert!(rules.contains(&("cdn.example.net", false, DomainAction::Allow)));
+    assert!(rules.contains(&("^ads[0-9]+\\.", true, DomainAction::Deny)));
+    assert!(!rules.iter().any(|(v, _, _)| v.contains("example.org")));
+
+    assert_eq!(config.records.len(), 2);
+    assert_eq!(config.clients.len(), 3);
+    assert_eq!(config.clients[0].name.as_deref(), Some("Kid tablet"));
+    assert_eq!(config.warnings.len(), 2);
+}
+
+#[tokio::test]
+async fn test_adguard_rejects_invalid_yaml() {
+    let result = ExternalConfigFileReader
+        .read(ExternalFormat::AdGuardHome, b"filters: [")
+        .await;
+
+    assert!(matches!(result, Err(DomainError::InvalidInput(_))));
+}
+
+#[tokio::test]
+async fn test_pihole_v5_teleporter_maps_groups_and_lists() {
+    let config = ExternalConfigFileReader
+        .read(ExternalFormat::PiHole, &pihole_v5_archive())
+        .await
+        .unwrap();
+
+    assert_eq!(config.groups.len(), 1);
+    assert_eq!(config.groups[0].name, "Kids (school)");
+    assert!(!config.groups[0].enabled);
+
+    assert_eq!(config.lists[0].groups, vec!["Default", "Kids (school)"]);
+    assert!(!config.lists[0].allow);
+
+    let regex = config.domain_rules.iter().find(|r| r.regex).unwrap();
+    assert_eq!(regex.action, DomainAction::Deny);
+    assert_eq!(config.domain_rules.len(), 3);
+    assert_eq!(config.warnings.len(), 1);
+
+    assert_eq!(config.records.len(), 2);
+    assert_eq!(config.clients[0].groups, vec!["Kids (school)"]);
+}
+
+#[tokio::test]
+async fn test_pihole_v6_zip_reads_gravity_database() {
+    let dir = tempfile::tempdir().unwrap();
+    let archive = pihole_v6_archive(&dir).await;
+
+    let config = ExternalConfigFileReader
+        .read(ExternalFormat::PiHole, &archive)
+        .await
+        .unwrap();
+
+    assert_eq!(config.groups[0].name, "IoT");
+    assert_eq!(config.lists.len(), 2);
+    assert!(config.lists.iter().any(|l| l.allow));
+    assert_eq!(config.domain_rules.len(), 2);
+    assert!(!config.domain_rules[1].enabled);
+    assert_eq!(config.records[0].name, "printer.lan");
+    assert_eq!(config.clients[0].id, "10.0.0.0/24");
+}
+
+#[tokio::test]
+async fn test_pihole_rejects_unknown_archive() {
+    let result = ExternalConfigFileReader
+        .read(ExternalFormat::PiHole, b"not an archive")
+        .await;
+
+    assert!(matches!(result, Err(DomainError::InvalidInput(_))));
+}
+
+#[derive(Default)]
+struct RecordingLocalRecords {
+    created: Mutex<Vec<LocalDnsRecord>>,
+}
+
+#[async_trait]
+impl LocalRecordCreator for RecordingLocalRecords {
+    async fn create_local_record(
+        &self,
+        hostname: String,
+        domain: Option<String>,
+        ip: String,
+        record_type: String,
+        ttl: Option<u32>,
+        view: Option<String>,
+    ) -> Result<LocalDnsRecord, DomainError> {
+        let record = LocalDnsRecord {
+            hostname,
+            domain,
+            ip,
+            record_type,
+            ttl,
+            view,
+        };
+        self.created.lock().unwrap().push(record.clone());
+        Ok(record)
+    }
+
+    async fn existing_local_records(&self) -> Result<Vec<LocalDnsRecord>, DomainError> {
+        Ok(self.created.lock().unwrap().clone())
+    }
+}
+
+struct Fixture {
+    _dir: TempDir,
+    pool: SqlitePool,
+    records: Arc<RecordingLocalRecords>,
+    use_case: ImportExternalConfigUseCase,
+}
+
+async fn fixture() -> Fixture {
+    let dir = tempfile::tempdir().unwrap();
+    let url = format!("sqlite:{}", dir.path().join("ferrous.db").display());
+    let db_config = DatabaseConfig::default();
+    let pool = create_write_pool(&url, &db_config).await.unwrap();
+    let records = Arc::new(RecordingLocalRecords::default());
+    let use_case = ImportExternalConfigUseCase::new(
+        Arc::new(ExternalConfigFileReader),
+        Arc::new(RwLock::new(Config::default())),
+        Arc::new(SqliteGroupRepository::new(pool.clone())),
+        Arc::new(SqliteBlocklistSourceRepository::new(pool.clone())),
+        Arc::new(SqliteWhitelistSourceRepository::new(pool.clone())),
+        Arc::new(SqliteManagedDomainRepository::new(pool.clone())),
+        Arc::new(SqliteRegexFilterRepository::new(pool.clone())),
+        Arc::new(SqliteClientRepository::new(pool.clone(), &db_config)),
+        Arc::new(SqliteClientSubnetRepository::new(pool.clone())),
+        records.clone(),
+    );
+    Fixture {
+        _dir: dir,
+        pool,
+        records,
+        use_case,
+    }
+}
+
+#[tokio::test]
+async fn test_pihole_import_writes_groups_lists_domains_and_clients() {
+    let f = fixture().await;
+
+    let summary = f
+        .use_case
+        .execute(ExternalFormat::PiHole, &pihole_v5_archive())
+        .await
+        .unwrap();
+
+    assert_eq!(summary.groups_imported, 1);
+    assert_eq!(summary.lists_imported, 1);
+    assert_eq!(summary.domains_imported, 2);
+    assert_eq!(summary.regex_filters_imported, 1);
+    assert_eq!(summary.local_records_imported, 1);
+    assert_eq!(summary.local_records_skipped, 1);
+    assert_eq!(summary.clients_imported, 1);
+    assert_eq!(summary.clients_skipped, 1);
+
+    let groups = SqliteGroupRepository::new(f.pool.clone());
+    let kids = groups.get_by_name("Kids _school_").await.unwrap().unwrap();
+    assert!(!kids.enabled);
+
+    let sources = SqliteBlocklistSourceRepository::new(f.pool.clone())
+        .get_all()
+        .await
+        .unwrap();
+    let imported = sources
+        .iter()
+        .find(|s| s.url.as_deref() == Some("https://example.org/hosts.txt"))
+        .unwrap();
+    assert_eq!(imported.group_ids.len(), 2);
+
+    let domains = SqliteManagedDomainRepository::new(f.pool.clone())
+        .get_all()
+        .await
+        .unwrap();
+    let ads = domains
+        .iter()
+        .find(|d| &*d.domain == "ads.example.com")
+        .unwrap();
+    assert_eq!(Some(ads.group_id), kids.id);
+
+    let record = &f.records.created.lock().unwrap()[0];
+    assert_eq!(record.hostname, "nas");
+    assert_eq!(record.domain.as_deref(), Some("lan"));
+}
+
+#[tokio::test]
+async fn test_reimport_skips_existing_entries() {
+    let f = fixture().await;
+    let archive = pihole_v5_archive();
+    f.use_case
+        .execute(ExternalFormat::PiHole, &archive)
+        .await
+        .unwrap();
+
+    let summary = f
+        .use_case
+        .execute(ExternalFormat::PiHole, &archive)
+        .await
+        .unwrap();
+
+    assert_eq!(summary.groups_imported, 0);
+    assert_eq!(summary.lists_imported, 0);
+    assert_eq!(summary.domains_imported, 0);
+    assert_eq!(summary.regex_filters_imported, 0);
+    assert_eq!(
+        SqliteRegexFilterRepository::new(f.pool.clone())
+            .get_all()
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn test_adguard_import_creates_subnet_and_named_client() {
+    let f = fixture().await;
+
+    let summary = f
+        .use_case
+        .execute(ExternalFormat::AdGuardHome, ADGUARD_YAML.as_bytes())
+        .await
+        .unwrap();
+
+    assert_eq!(summary.lists_imported, 3);
+    assert_eq!(
+        WhitelistSourceRepository::get_all(&SqliteWhitelistSourceRepository::new(f.pool.clone()))
+            .await
+            .unwrap()
+            .iter()
+            .filter(|s| s.url.is_some())
+            .count(),
+        1
+    );
+    assert!(SqliteClientSubnetRepository::new(f.pool.clone())
+        .exists("10.20.0.0/16")
+        .await
+        .unwrap());
+    let display_name: Option<String> =
+        sqlx::query_scalar("SELECT display_name FROM clients WHERE ip_address = '192.168.1.20'")
+            .fetch_one(&f.pool)
+            .await
+            .unwrap();
+    assert_eq!(display_name.as_deref(), Some("Kid tablet"));
+    assert!(summary
+        .warnings
+        .iter()
+        .any(|w| w.contains("aa:bb:cc:dd:ee:ff")));
+}
@@ -0,0 +1,146 @@
+#![cfg(feature = "fault-injection")]
+
+// Faults live in a process-wide static, so every test targets its own
+// servers and domains to stay independent of the others.
+
+use ferrous_dns_application::ports::{FaultInjectionPort, FaultKind};
+use ferrous_dns_domain::{DnsProtocol, DomainError};
+use ferrous_dns_infrastructure::dns::{FaultInjectionControl, FAULTS};
+use std::time::Instant;
+
+fn server(s: &str) -> DnsProtocol {
+    s.parse().unwrap()
+}
+
+#[tokio::test]
+async fn test_upstream_down_fails_only_the_target_server() {
+    let control = FaultInjectionControl;
+    let fault = control
+        .inject(
+            FaultKind::UpstreamDown {
+                server: "192.0.2.1:53".to_string(),
+            },
+            None,
+        )
+        .unwrap();
+
+    let result = FAULTS
+        .apply(&server("udp://192.0.2.1:53"), Some("example.com"), 1000)
+        .await;
+    assert!(matches!(result, Err(DomainError::IoError(_))));
+    let result = FAULTS
+        .apply(&server("udp://192.0.2.1:53"), None, 1000)
+        .await;
+    assert!(matches!(result, Err(DomainError::IoError(_))));
+    let result = FAULTS
+        .apply(&server("udp://192.0.2.2:53"), Some("example.com"), 1000)
+        .await;
+    assert_eq!(result.unwrap(), 1000);
+
+    let hits = control
+        .faults()
+        .into_iter()
+        .find(|f| f.id == fault.id)
+        .unwrap()
+        .hits;
+    assert_eq!(hits, 2);
+    assert!(control.remove(fault.id));
+    assert!(!control.remove(fault.id));
+}
+
+#[tokio::test]
+async fn test_servfail_matches_domain_and_subdomains() {
+    let control = FaultInjectionControl;
+    let fault = control
+        .inject(
+            FaultKind::Servfail {
+                pattern: "broken.test".to_string(),
+            },
+            None,
+        )
+        .unwrap();
+    let upstream = server("udp://198.51.100.1:53");
+
+    for domain in ["broken.test", "www.BROKEN.test."] {
+        let result = FAULTS.apply(&upstream, Some(domain), 1000).await;
+        assert!(matches!(result, Err(DomainError::InvalidDnsResponse(_))));
+    }
+    for domain in ["notbroken.test", "broken.test.example"] {
+        assert!(FAULTS.apply(&upstream, Some(domain), 1000).await.is_ok());
+    }
+    // Health checks carry no domain and are left alone.
+    assert!(FAULTS.apply(&upstream, None, 1000).await.is_ok());
+
+    control.remove(fault.id);
+}
+
+#[tokio::test]
+async fn test_latency_delays_and_shortens_timeout() {
+    let control = FaultInjectionControl;
+    let fault = control
+        .inject(
+            FaultKind::Latency {
+                server: Some("203.0.113.1".to_string()),
+                delay_ms: 30,
+            },
+            None,
+        )
+        .unwrap();
+    let upstream = server("udp://203.0.113.1:53");
+
+    let start = Instant::now();
+    let remaining = FAULTS.apply(&upstream, Some("example.com"), 1000).await;
+    assert!(start.elapsed().as_millis() >= 30);
+    assert_eq!(remaining.unwrap(), 970);
+
+    let result = FAULTS.apply(&upstream, Some("example.com"), 20).await;
+    assert!(matches!(result, Err(DomainError::QueryTimeout)));
+
+    control.remove(fault.id);
+}
+
+#[tokio::test]
+async fn test_expired_fault_is_lifted() {
+    let control = FaultInjectionControl;
+    let fault = control
+        .inject(
+            FaultKind::UpstreamDown {
+                server: "192.0.2.50:53".to_string(),
+            },
+            Some(0),
+        )
+        .unwrap();
+
+    let result = FAULTS
+        .apply(&server("udp://192.0.2.50:53"), Some("example.com"), 1000)
+        .await;
+    assert!(result.is_ok());
+    assert!(control.faults().iter().all(|f| f.id != fault.id));
+}
+
+#[test]
+fn test_rejects_invalid_faults() {
+    let control = FaultInjectionControl;
+    let invalid = [
+        FaultKind::UpstreamDown {
+            server: " ".to_string(),
+        },
+        FaultKind::Latency {
+            server: None,
+            delay_ms: 0,
+        },
+        FaultKind::Latency {
+            server: None,
+            delay_ms: 120_000,
+        },
+        FaultKind::Servfail {
+            pattern: ".".to_string(),
+        },
+    ];
+    for kind in invalid {
+        assert!(matches!(
+            control.inject(kind, None),
+            Err(DomainError::InvalidInput(_))
+        ));
+    }
+}
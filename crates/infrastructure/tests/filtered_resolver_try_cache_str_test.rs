@@ -55,6 +55,7 @@ fn passthrough_filters() -> QueryFilters {
         block_non_fqdn: false,
         local_domain: None,
         has_local_dns_server: false,
+        conditional_forwards: None,
     }
 }
 
@@ -99,6 +100,7 @@ fn try_cache_str_returns_none_for_private_ptr_when_blocked() {
             block_non_fqdn: false,
             local_domain: None,
             has_local_dns_server: false,
+            conditional_forwards: None,
         },
     );
 
@@ -121,6 +123,7 @@ fn try_cache_str_allows_private_ptr_when_local_dns_server_configured() {
             block_non_fqdn: false,
             local_domain: None,
             has_local_dns_server: true,
+            conditional_forwards: None,
         },
     );
 
@@ -142,6 +145,7 @@ fn try_cache_str_appends_local_domain_and_finds_rewritten_cache_entry() {
             block_non_fqdn: false,
             local_domain: Some("lan".to_string()),
             has_local_dns_server: false,
+            conditional_forwards: None,
         },
     );
 
@@ -167,6 +171,7 @@ fn try_cache_str_does_not_rewrite_fqdn_when_local_domain_configured() {
             block_non_fqdn: false,
             local_domain: Some("lan".to_string()),
             has_local_dns_server: false,
+            conditional_forwards: None,
         },
     );
 
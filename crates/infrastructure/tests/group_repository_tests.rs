@@ -1,4 +1,5 @@
 use ferrous_dns_application::ports::GroupRepository;
+use ferrous_dns_domain::{Group, PageRequest, SortOrder};
 use ferrous_dns_infrastructure::repositories::group_repository::SqliteGroupRepository;
 use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
 
@@ -15,6 +16,9 @@ async fn create_test_db() -> SqlitePool {
             enabled BOOLEAN NOT NULL DEFAULT 1,
             comment TEXT,
             is_default BOOLEAN NOT NULL DEFAULT 0,
+            filter_aaaa BOOLEAN NOT NULL DEFAULT 0,
+            audit_mode  BOOLEAN NOT NULL DEFAULT 0,
+            tenant_id INTEGER,
             created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
             updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
         )",
@@ -34,7 +38,10 @@ async fn create_test_db() -> SqlitePool {
             query_count INTEGER DEFAULT 0,
             last_mac_update DATETIME,
             last_hostname_update DATETIME,
-            group_id INTEGER DEFAULT 1 REFERENCES groups(id) ON DELETE RESTRICT
+            group_id INTEGER DEFAULT 1 REFERENCES groups(id) ON DELETE RESTRICT,
+            display_name TEXT,
+            category TEXT,
+            notes TEXT
         )",
     )
     .execute(&pool)
@@ -119,6 +126,8 @@ async fn test_update_group() {
             Some("Updated".to_string()),
             Some(false),
             Some("New comment".to_string()),
+            None,
+            None,
         )
         .await
         .unwrap();
@@ -131,6 +140,50 @@ async fn test_update_group() {
     );
 }
 
+#[tokio::test]
+async fn test_update_group_filter_aaaa() {
+    let pool = create_test_db().await;
+    let repo = SqliteGroupRepository::new(pool);
+
+    let group = repo.create("Broken IPv6".to_string(), None).await.unwrap();
+    let id = group.id.unwrap();
+    assert!(!group.filter_aaaa);
+
+    let updated = repo
+        .update(id, None, None, None, Some(true), None)
+        .await
+        .unwrap();
+    assert!(updated.filter_aaaa);
+    assert!(updated.enabled);
+
+    let fetched = repo.get_by_id(id).await.unwrap().unwrap();
+    assert!(fetched.filter_aaaa);
+    let listed = repo.get_all_with_client_counts().await.unwrap();
+    assert!(listed
+        .iter()
+        .any(|(g, _)| g.id == Some(id) && g.filter_aaaa));
+}
+
+#[tokio::test]
+async fn test_update_group_audit_mode() {
+    let pool = create_test_db().await;
+    let repo = SqliteGroupRepository::new(pool);
+
+    let group = repo.create("Trial".to_string(), None).await.unwrap();
+    let id = group.id.unwrap();
+    assert!(!group.audit_mode);
+
+    let updated = repo
+        .update(id, None, None, None, None, Some(true))
+        .await
+        .unwrap();
+    assert!(updated.audit_mode);
+    assert!(!updated.filter_aaaa);
+
+    let listed = repo.get_all_with_client_counts().await.unwrap();
+    assert!(listed.iter().any(|(g, _)| g.id == Some(id) && g.audit_mode));
+}
+
 #[tokio::test]
 async fn test_delete_group() {
     let pool = create_test_db().await;
@@ -200,3 +253,48 @@ async fn test_get_clients_in_group() {
     assert_eq!(clients.len(), 1);
     assert_eq!(clients[0].ip_address.to_string(), "192.168.1.1");
 }
+
+#[tokio::test]
+async fn test_get_page_with_client_counts_default_order() {
+    let pool = create_test_db().await;
+    let repo = SqliteGroupRepository::new(pool);
+
+    repo.create("Kids".to_string(), None).await.unwrap();
+    repo.create("Guests".to_string(), None).await.unwrap();
+
+    let (groups, total) = repo
+        .get_page_with_client_counts(&PageRequest::new(2, 0))
+        .await
+        .unwrap();
+
+    assert_eq!(total, 3);
+    let names: Vec<&str> = groups.iter().map(|(g, _)| g.name.as_ref()).collect();
+    assert_eq!(names, vec!["Protected", "Guests"]);
+}
+
+#[tokio::test]
+async fn test_get_page_with_client_counts_sorted_by_client_count() {
+    let pool = create_test_db().await;
+    let repo = SqliteGroupRepository::new(pool.clone());
+
+    let kids = repo.create("Kids".to_string(), None).await.unwrap();
+    sqlx::query(
+        "INSERT INTO clients (ip_address, group_id)
+         VALUES ('192.168.1.1', ?), ('192.168.1.2', ?), ('192.168.1.3', 1)",
+    )
+    .bind(kids.id.unwrap())
+    .bind(kids.id.unwrap())
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let page = PageRequest::new(10, 0)
+        .sorted_by("client_count", SortOrder::Desc, Group::SORT_FIELDS)
+        .unwrap();
+    let (groups, total) = repo.get_page_with_client_counts(&page).await.unwrap();
+
+    assert_eq!(total, 2);
+    assert_eq!(groups[0].0.name.as_ref(), "Kids");
+    assert_eq!(groups[0].1, 2);
+    assert_eq!(groups[1].1, 1);
+}
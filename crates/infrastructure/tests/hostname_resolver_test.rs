@@ -1,5 +1,15 @@
-use ferrous_dns_infrastructure::system::PtrHostnameResolver;
+use async_trait::async_trait;
+use ferrous_dns_application::ports::HostnameResolver;
+use ferrous_dns_domain::DomainError;
+use ferrous_dns_infrastructure::system::{
+    ChainedHostnameResolver, LlmnrHostnameResolver, NetbiosHostnameResolver, PtrHostnameResolver,
+};
+use hickory_proto::op::{Message, MessageType, OpCode};
+use hickory_proto::rr::rdata::PTR;
+use hickory_proto::rr::{Name, RData, Record};
 use std::net::IpAddr;
+use std::str::FromStr;
+use std::sync::Arc;
 
 #[test]
 fn test_ip_to_reverse_domain_ipv4() {
@@ -22,3 +32,124 @@ fn test_ip_to_reverse_domain_ipv6() {
     assert!(reverse.ends_with(".ip6.arpa"));
     assert!(reverse.contains("8.b.d.0.1.0.0.2"));
 }
+
+fn netbios_name_entry(name: &str, suffix: u8, flags: u16) -> Vec<u8> {
+    let mut entry = format!("{:<15}", name).into_bytes();
+    entry.push(suffix);
+    entry.extend_from_slice(&flags.to_be_bytes());
+    entry
+}
+
+fn netbios_response(entries: &[Vec<u8>]) -> Vec<u8> {
+    let query = NetbiosHostnameResolver::build_node_status_query(0x1234);
+    let mut response = query[..query.len() - 4].to_vec();
+    response[2] = 0x84;
+    response[6..8].copy_from_slice(&[0x00, 0x01]);
+    response[4..6].copy_from_slice(&[0x00, 0x00]);
+    response.extend_from_slice(&[0x00, 0x21, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00]);
+    let rdlength = (1 + entries.len() * 18) as u16;
+    response.extend_from_slice(&rdlength.to_be_bytes());
+    response.push(entries.len() as u8);
+    for entry in entries {
+        response.extend_from_slice(entry);
+    }
+    response
+}
+
+#[test]
+fn test_netbios_query_encodes_wildcard_name() {
+    let query = NetbiosHostnameResolver::build_node_status_query(0xabcd);
+    assert_eq!(&query[..2], &[0xab, 0xcd]);
+    assert_eq!(query[12], 32);
+    assert_eq!(&query[13..15], b"CK");
+    assert!(query[15..45].iter().all(|&b| b == b'A'));
+    assert_eq!(&query[query.len() - 4..], &[0x00, 0x21, 0x00, 0x01]);
+}
+
+#[test]
+fn test_netbios_response_picks_unique_workstation_name() {
+    let response = netbios_response(&[
+        netbios_name_entry("WORKGROUP", 0x00, 0x8400),
+        netbios_name_entry("DESKTOP-42", 0x20, 0x0400),
+        netbios_name_entry("DESKTOP-42", 0x00, 0x0400),
+    ]);
+    assert_eq!(
+        NetbiosHostnameResolver::parse_node_status_response(&response),
+        Some("desktop-42".to_string())
+    );
+}
+
+#[test]
+fn test_netbios_response_truncated_returns_none() {
+    let response = netbios_response(&[netbios_name_entry("NAS", 0x00, 0x0400)]);
+    assert_eq!(
+        NetbiosHostnameResolver::parse_node_status_response(&response[..response.len() - 5]),
+        None
+    );
+}
+
+#[test]
+fn test_llmnr_query_asks_for_ptr_of_reverse_domain() {
+    let ip: IpAddr = "192.168.1.20".parse().unwrap();
+    let bytes = LlmnrHostnameResolver::build_reverse_query(&ip, 7).unwrap();
+    let message = Message::from_vec(&bytes).unwrap();
+    assert_eq!(message.id(), 7);
+    assert_eq!(
+        message.queries()[0].name().to_utf8(),
+        "20.1.168.192.in-addr.arpa."
+    );
+}
+
+#[test]
+fn test_llmnr_response_returns_hostname_without_trailing_dot() {
+    let name = Name::from_str("20.1.168.192.in-addr.arpa.").unwrap();
+    let mut response = Message::new(7, MessageType::Response, OpCode::Query);
+    response.add_answer(Record::from_rdata(
+        name,
+        30,
+        RData::PTR(PTR(Name::from_str("laptop.").unwrap())),
+    ));
+    let bytes = response.to_vec().unwrap();
+
+    assert_eq!(
+        LlmnrHostnameResolver::parse_reverse_response(&bytes, 7),
+        Some("laptop".to_string())
+    );
+    assert_eq!(
+        LlmnrHostnameResolver::parse_reverse_response(&bytes, 8),
+        None
+    );
+}
+
+struct FixedResolver(Result<Option<String>, ()>);
+
+#[async_trait]
+impl HostnameResolver for FixedResolver {
+    async fn resolve_hostname(&self, _ip: IpAddr) -> Result<Option<String>, DomainError> {
+        self.0
+            .clone()
+            .map_err(|_| DomainError::IoError("unreachable".to_string()))
+    }
+}
+
+#[tokio::test]
+async fn test_chained_resolver_falls_through_to_first_hit() {
+    let chain = ChainedHostnameResolver::new(vec![
+        Arc::new(FixedResolver(Ok(None))),
+        Arc::new(FixedResolver(Err(()))),
+        Arc::new(FixedResolver(Ok(Some("printer".to_string())))),
+        Arc::new(FixedResolver(Ok(Some("ignored".to_string())))),
+    ]);
+    let ip: IpAddr = "10.0.0.5".parse().unwrap();
+    assert_eq!(
+        chain.resolve_hostname(ip).await.unwrap(),
+        Some("printer".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_chained_resolver_returns_none_when_all_miss() {
+    let chain = ChainedHostnameResolver::new(vec![Arc::new(FixedResolver(Ok(None)))]);
+    let ip: IpAddr = "10.0.0.5".parse().unwrap();
+    assert_eq!(chain.resolve_hostname(ip).await.unwrap(), None);
+}
@@ -0,0 +1,30 @@
+use ferrous_dns_infrastructure::dns::idn::to_ascii_domain;
+
+#[test]
+fn test_ascii_domain_is_lowercased() {
+    assert_eq!(to_ascii_domain("Example.COM"), "example.com");
+}
+
+#[test]
+fn test_lowercase_ascii_domain_is_borrowed() {
+    assert!(matches!(
+        to_ascii_domain("example.com"),
+        std::borrow::Cow::Borrowed(_)
+    ));
+}
+
+#[test]
+fn test_unicode_domain_becomes_punycode() {
+    assert_eq!(to_ascii_domain("bücher.de"), "xn--bcher-kva.de");
+    assert_eq!(to_ascii_domain("BÜCHER.de"), "xn--bcher-kva.de");
+}
+
+#[test]
+fn test_unicode_wildcard_keeps_prefix() {
+    assert_eq!(to_ascii_domain("*.bücher.de"), "*.xn--bcher-kva.de");
+}
+
+#[test]
+fn test_punycode_is_unchanged() {
+    assert_eq!(to_ascii_domain("xn--bcher-kva.de"), "xn--bcher-kva.de");
+}
@@ -0,0 +1,128 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{DnsResolution, DnsResolver, InflightQueriesPort};
+use ferrous_dns_domain::{DnsQuery, DomainError, RecordType};
+use ferrous_dns_infrastructure::dns::resolver::CachedResolver;
+use ferrous_dns_infrastructure::dns::{
+    DnsCache, DnsCacheAccess, DnsCacheConfig, EvictionStrategy, InflightRegistry,
+    NegativeQueryTracker,
+};
+use std::sync::Arc;
+use std::time::Duration;
+
+struct SlowResolver {
+    delay_ms: u64,
+}
+
+#[async_trait]
+impl DnsResolver for SlowResolver {
+    async fn resolve(&self, _query: &DnsQuery) -> Result<DnsResolution, DomainError> {
+        tokio::time::sleep(Duration::from_millis(self.delay_ms)).await;
+        Ok(DnsResolution::new(vec!["10.0.0.1".parse().unwrap()], false))
+    }
+}
+
+fn make_cache() -> Arc<dyn DnsCacheAccess> {
+    Arc::new(DnsCache::new(DnsCacheConfig {
+        max_entries: 1000,
+        eviction_strategy: EvictionStrategy::LRU,
+        min_threshold: 2.0,
+        refresh_threshold: 0.75,
+        batch_eviction_percentage: 0.2,
+        adaptive_thresholds: false,
+        min_frequency: 0,
+        min_lfuk_score: 0.0,
+        shard_amount: 4,
+        access_window_secs: 7200,
+        eviction_sample_size: 8,
+        lfuk_k_value: 0.5,
+        refresh_sample_rate: 1.0,
+        min_ttl: 0,
+        max_ttl: 86_400,
+        l1_capacity: 1024,
+    }))
+}
+
+fn make_resolver(delay_ms: u64, registry: Arc<InflightRegistry>) -> Arc<CachedResolver> {
+    Arc::new(
+        CachedResolver::new(
+            Arc::new(SlowResolver { delay_ms }),
+            make_cache(),
+            300,
+            Arc::new(NegativeQueryTracker::new()),
+            4,
+        )
+        .with_inflight_registry(registry),
+    )
+}
+
+#[test]
+fn empty_registry_reports_nothing() {
+    let registry = InflightRegistry::new(4);
+    let snapshot = registry.inflight(10);
+
+    assert!(registry.is_empty());
+    assert!(snapshot.queries.is_empty());
+    assert_eq!(snapshot.total, 0);
+    assert_eq!(snapshot.leaders, 0);
+    assert_eq!(snapshot.coalesced, 0);
+}
+
+#[tokio::test]
+async fn pending_resolution_lists_its_waiters() {
+    let registry = Arc::new(InflightRegistry::new(4));
+    let resolver = make_resolver(300, Arc::clone(&registry));
+
+    let mut handles = Vec::new();
+    for _ in 0..4 {
+        let resolver = Arc::clone(&resolver);
+        handles.push(tokio::spawn(async move {
+            resolver
+                .resolve(&DnsQuery::new("hanging.example", RecordType::A))
+                .await
+        }));
+    }
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let snapshot = registry.inflight(10);
+    assert_eq!(snapshot.total, 1);
+    let query = &snapshot.queries[0];
+    assert_eq!(query.domain, "hanging.example");
+    assert_eq!(query.record_type, RecordType::A);
+    assert_eq!(query.waiters, 3);
+    assert!(query.elapsed_ms >= 50, "elapsed {}ms", query.elapsed_ms);
+
+    for handle in handles {
+        assert!(handle.await.unwrap().is_ok());
+    }
+
+    let snapshot = registry.inflight(10);
+    assert_eq!(snapshot.total, 0);
+    assert_eq!(snapshot.leaders, 1);
+    assert_eq!(snapshot.coalesced, 3);
+}
+
+#[tokio::test]
+async fn snapshot_limit_keeps_the_oldest_resolutions() {
+    let registry = Arc::new(InflightRegistry::new(4));
+    let resolver = make_resolver(300, Arc::clone(&registry));
+
+    let mut handles = Vec::new();
+    for domain in ["first.example", "second.example", "third.example"] {
+        let resolver = Arc::clone(&resolver);
+        handles.push(tokio::spawn(async move {
+            resolver
+                .resolve(&DnsQuery::new(domain, RecordType::A))
+                .await
+        }));
+        tokio::time::sleep(Duration::from_millis(30)).await;
+    }
+
+    let snapshot = registry.inflight(1);
+    assert_eq!(snapshot.total, 3);
+    assert_eq!(snapshot.queries.len(), 1);
+    assert_eq!(snapshot.queries[0].domain, "first.example");
+
+    for handle in handles {
+        assert!(handle.await.unwrap().is_ok());
+    }
+}
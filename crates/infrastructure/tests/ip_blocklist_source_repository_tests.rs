@@ -0,0 +1,146 @@
+use ferrous_dns_application::ports::IpBlocklistSourceRepository;
+use ferrous_dns_domain::DomainError;
+use ferrous_dns_infrastructure::repositories::SqliteIpBlocklistSourceRepository;
+use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
+
+const FEODO: &str = "https://feodotracker.abuse.ch/downloads/ipblocklist.txt";
+const DROP: &str = "https://www.spamhaus.org/drop/drop.txt";
+
+async fn create_test_db() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::query(include_str!(
+        "../../../migrations/20260312000001_create_ip_blocklist_sources.sql"
+    ))
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    pool
+}
+
+#[tokio::test]
+async fn test_create_and_get_source() {
+    let pool = create_test_db().await;
+    let repo = SqliteIpBlocklistSourceRepository::new(pool);
+
+    let source = repo
+        .create(
+            "Feodo Tracker".to_string(),
+            FEODO.to_string(),
+            Some("Botnet C2".to_string()),
+            true,
+        )
+        .await
+        .unwrap();
+
+    assert!(source.id.is_some());
+    assert_eq!(source.name.as_ref(), "Feodo Tracker");
+    assert_eq!(source.url.as_ref(), FEODO);
+    assert_eq!(source.comment.as_deref(), Some("Botnet C2"));
+    assert!(source.enabled);
+    assert!(source.created_at.is_some());
+
+    let fetched = repo.get_by_id(source.id.unwrap()).await.unwrap().unwrap();
+    assert_eq!(fetched.url.as_ref(), FEODO);
+}
+
+#[tokio::test]
+async fn test_create_unique_name_constraint() {
+    let pool = create_test_db().await;
+    let repo = SqliteIpBlocklistSourceRepository::new(pool);
+
+    repo.create("Duplicate".to_string(), FEODO.to_string(), None, true)
+        .await
+        .unwrap();
+    let result = repo
+        .create("Duplicate".to_string(), DROP.to_string(), None, true)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(DomainError::InvalidIpBlocklistSource(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_get_all_ordered_by_name() {
+    let pool = create_test_db().await;
+    let repo = SqliteIpBlocklistSourceRepository::new(pool);
+
+    repo.create("Spamhaus DROP".to_string(), DROP.to_string(), None, true)
+        .await
+        .unwrap();
+    repo.create("Feodo Tracker".to_string(), FEODO.to_string(), None, false)
+        .await
+        .unwrap();
+
+    let sources = repo.get_all().await.unwrap();
+    assert_eq!(sources.len(), 2);
+    assert_eq!(sources[0].name.as_ref(), "Feodo Tracker");
+    assert!(!sources[0].enabled);
+    assert_eq!(sources[1].name.as_ref(), "Spamhaus DROP");
+}
+
+#[tokio::test]
+async fn test_update_keeps_unset_fields() {
+    let pool = create_test_db().await;
+    let repo = SqliteIpBlocklistSourceRepository::new(pool);
+
+    let source = repo
+        .create(
+            "Feodo Tracker".to_string(),
+            FEODO.to_string(),
+            Some("Botnet C2".to_string()),
+            true,
+        )
+        .await
+        .unwrap();
+    let id = source.id.unwrap();
+
+    let updated = repo
+        .update(id, None, Some(DROP.to_string()), None, Some(false))
+        .await
+        .unwrap();
+
+    assert_eq!(updated.name.as_ref(), "Feodo Tracker");
+    assert_eq!(updated.url.as_ref(), DROP);
+    assert_eq!(updated.comment.as_deref(), Some("Botnet C2"));
+    assert!(!updated.enabled);
+}
+
+#[tokio::test]
+async fn test_update_not_found() {
+    let pool = create_test_db().await;
+    let repo = SqliteIpBlocklistSourceRepository::new(pool);
+
+    let result = repo.update(999, None, None, None, Some(false)).await;
+
+    assert!(matches!(
+        result,
+        Err(DomainError::IpBlocklistSourceNotFound(999))
+    ));
+}
+
+#[tokio::test]
+async fn test_delete() {
+    let pool = create_test_db().await;
+    let repo = SqliteIpBlocklistSourceRepository::new(pool);
+
+    let source = repo
+        .create("Feodo Tracker".to_string(), FEODO.to_string(), None, true)
+        .await
+        .unwrap();
+    let id = source.id.unwrap();
+
+    repo.delete(id).await.unwrap();
+
+    assert!(repo.get_by_id(id).await.unwrap().is_none());
+    assert!(matches!(
+        repo.delete(id).await,
+        Err(DomainError::IpBlocklistSourceNotFound(_))
+    ));
+}
@@ -0,0 +1,224 @@
+use ferrous_dns_domain::{KubernetesConfig, LocalRecord, RecordType};
+use ferrous_dns_infrastructure::discovery::{
+    cluster_records, Ingress, KubernetesRecordSource, ObjectList, Service,
+};
+use serde_json::json;
+use std::io::Write;
+
+fn services(value: serde_json::Value) -> ObjectList<Service> {
+    serde_json::from_value(json!({ "items": value })).unwrap()
+}
+
+fn ingresses(value: serde_json::Value) -> ObjectList<Ingress> {
+    serde_json::from_value(json!({ "items": value })).unwrap()
+}
+
+fn summary(records: &[LocalRecord]) -> Vec<(String, RecordType, String)> {
+    let mut summary: Vec<_> = records
+        .iter()
+        .map(|r| (r.fqdn(&None), r.record_type, r.value.to_string()))
+        .collect();
+    summary.sort_by(|a, b| a.0.cmp(&b.0).then(a.2.cmp(&b.2)));
+    summary
+}
+
+fn load_balancer_service(name: &str, ip: &str) -> serde_json::Value {
+    json!({
+        "metadata": { "name": name, "namespace": "apps" },
+        "spec": { "type": "LoadBalancer", "clusterIPs": ["10.43.0.12"] },
+        "status": { "loadBalancer": { "ingress": [{ "ip": ip }] } }
+    })
+}
+
+#[test]
+fn test_load_balancer_service_is_published_with_its_ip() {
+    let config = KubernetesConfig::default();
+
+    let records = cluster_records(
+        &config,
+        &services(json!([load_balancer_service("web", "192.168.1.50")])),
+        &ObjectList::default(),
+    );
+
+    assert_eq!(
+        summary(&records),
+        vec![(
+            "web.apps.k8s.lan".to_string(),
+            RecordType::A,
+            "192.168.1.50".to_string()
+        )]
+    );
+    assert_eq!(records[0].ttl, 60);
+}
+
+#[test]
+fn test_cluster_ip_only_published_when_enabled() {
+    let cluster_ip = services(json!([{
+        "metadata": { "name": "db", "namespace": "apps" },
+        "spec": { "type": "ClusterIP", "clusterIPs": ["10.43.0.20", "fd00::20"] }
+    }]));
+
+    let default = cluster_records(
+        &KubernetesConfig::default(),
+        &cluster_ip,
+        &ObjectList::default(),
+    );
+    let included = cluster_records(
+        &KubernetesConfig {
+            include_cluster_ip: true,
+            ..Default::default()
+        },
+        &cluster_ip,
+        &ObjectList::default(),
+    );
+
+    assert!(default.is_empty());
+    let types: Vec<RecordType> = summary(&included).into_iter().map(|r| r.1).collect();
+    assert_eq!(types, vec![RecordType::A, RecordType::AAAA]);
+}
+
+#[test]
+fn test_headless_service_is_skipped() {
+    let config = KubernetesConfig {
+        include_cluster_ip: true,
+        ..Default::default()
+    };
+    let headless = services(json!([{
+        "metadata": { "name": "db", "namespace": "apps" },
+        "spec": { "type": "ClusterIP", "clusterIPs": ["None"] }
+    }]));
+
+    assert!(cluster_records(&config, &headless, &ObjectList::default()).is_empty());
+}
+
+#[test]
+fn test_external_name_service_becomes_cname() {
+    let records = cluster_records(
+        &KubernetesConfig::default(),
+        &services(json!([{
+            "metadata": { "name": "search", "namespace": "default" },
+            "spec": { "type": "ExternalName", "externalName": "search.example.com" }
+        }])),
+        &ObjectList::default(),
+    );
+
+    assert_eq!(
+        summary(&records),
+        vec![(
+            "search.default.k8s.lan".to_string(),
+            RecordType::CNAME,
+            "search.example.com".to_string()
+        )]
+    );
+}
+
+#[test]
+fn test_ingress_publishes_name_and_hosts_within_domain() {
+    let records = cluster_records(
+        &KubernetesConfig::default(),
+        &ObjectList::default(),
+        &ingresses(json!([{
+            "metadata": { "name": "dashboard", "namespace": "monitoring" },
+            "spec": { "rules": [
+                { "host": "grafana.K8S.lan" },
+                { "host": "grafana.example.com" },
+                {}
+            ] },
+            "status": { "loadBalancer": { "ingress": [{ "ip": "192.168.1.60" }] } }
+        }])),
+    );
+
+    let names: Vec<String> = summary(&records).into_iter().map(|r| r.0).collect();
+    assert_eq!(
+        names,
+        vec![
+            "dashboard.monitoring.k8s.lan".to_string(),
+            "grafana.k8s.lan".to_string()
+        ]
+    );
+}
+
+#[test]
+fn test_load_balancer_hostname_becomes_cname() {
+    let records = cluster_records(
+        &KubernetesConfig::default(),
+        &services(json!([{
+            "metadata": { "name": "web", "namespace": "apps" },
+            "spec": { "type": "LoadBalancer" },
+            "status": { "loadBalancer": { "ingress": [{ "hostname": "lb.example.net" }] } }
+        }])),
+        &ObjectList::default(),
+    );
+
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].record_type, RecordType::CNAME);
+    assert_eq!(records[0].value.as_ref(), "lb.example.net");
+}
+
+#[test]
+fn test_disabled_kinds_are_not_published() {
+    let config = KubernetesConfig {
+        publish_services: false,
+        ..Default::default()
+    };
+
+    let records = cluster_records(
+        &config,
+        &services(json!([load_balancer_service("web", "192.168.1.50")])),
+        &ObjectList::default(),
+    );
+
+    assert!(records.is_empty());
+}
+
+#[test]
+fn test_source_reads_server_from_kubeconfig_context() {
+    let mut kubeconfig = tempfile::NamedTempFile::new().unwrap();
+    write!(
+        kubeconfig,
+        r#"
+apiVersion: v1
+kind: Config
+current-context: home
+clusters:
+  - name: homelab
+    cluster:
+      server: https://192.168.1.10:6443/
+      insecure-skip-tls-verify: true
+users:
+  - name: admin
+    user:
+      token: abc123
+contexts:
+  - name: home
+    context:
+      cluster: homelab
+      user: admin
+"#
+    )
+    .unwrap();
+    let config = KubernetesConfig {
+        enabled: true,
+        kubeconfig: Some(kubeconfig.path().to_string_lossy().into_owned()),
+        ..Default::default()
+    };
+
+    let source = KubernetesRecordSource::new(&config).unwrap();
+
+    assert_eq!(source.server(), "https://192.168.1.10:6443");
+}
+
+#[test]
+fn test_unknown_kubeconfig_context_is_rejected() {
+    let mut kubeconfig = tempfile::NamedTempFile::new().unwrap();
+    write!(kubeconfig, "current-context: home\ncontexts: []\n").unwrap();
+    let config = KubernetesConfig {
+        enabled: true,
+        kubeconfig: Some(kubeconfig.path().to_string_lossy().into_owned()),
+        ..Default::default()
+    };
+
+    let err = KubernetesRecordSource::new(&config).err().unwrap();
+
+    assert!(err.to_string().contains("no context named 'home'"));
+}
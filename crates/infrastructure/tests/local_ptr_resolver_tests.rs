@@ -1,7 +1,9 @@
 use async_trait::async_trait;
 use ferrous_dns_application::ports::{DnsResolution, DnsResolver, PtrRecordRegistry};
 use ferrous_dns_domain::{DnsQuery, DomainError, LocalDnsRecord, RecordType};
-use ferrous_dns_infrastructure::dns::resolver::LocalPtrResolver;
+use ferrous_dns_infrastructure::dns::resolver::{ClientPtrZone, LocalPtrResolver};
+use hickory_proto::op::Message;
+use hickory_proto::rr::RData;
 use std::net::IpAddr;
 use std::sync::Arc;
 
@@ -21,6 +23,7 @@ fn make_record(hostname: &str, domain: &str, ip: &str, record_type: &str) -> Loc
         ip: ip.to_string(),
         record_type: record_type.to_string(),
         ttl: Some(300),
+        view: None,
     }
 }
 
@@ -135,3 +138,92 @@ async fn test_unregister_removes_entry_from_map() {
 
     assert!(matches!(result, Err(DomainError::NxDomain)));
 }
+
+fn client_zone() -> Arc<ClientPtrZone> {
+    Arc::new(ClientPtrZone::new(
+        &["192.168.1.0/24".to_string()],
+        Some("lan".to_string()),
+    ))
+}
+
+fn ptr_target(resolution: &DnsResolution) -> String {
+    let wire = resolution.upstream_wire_data.as_ref().unwrap();
+    let message = Message::from_vec(wire).unwrap();
+    match message.answers()[0].data() {
+        RData::PTR(ptr) => ptr.0.to_ascii(),
+        other => panic!("Expected PTR, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_client_zone_answers_with_qualified_hostname() {
+    let zone = client_zone();
+    zone.register(
+        "192.168.1.73".parse().unwrap(),
+        Arc::from("livingroom-tv"),
+        300,
+    );
+    let inner: Arc<dyn DnsResolver> = Arc::new(MockInner);
+    let resolver =
+        LocalPtrResolver::from_local_records(&[], &None, inner).with_client_zone(Some(zone));
+
+    let resolution = resolver
+        .resolve(&ptr_query("73.1.168.192.in-addr.arpa"))
+        .await
+        .unwrap();
+
+    assert!(resolution.local_dns);
+    assert_eq!(ptr_target(&resolution), "livingroom-tv.lan.");
+}
+
+#[tokio::test]
+async fn test_client_zone_ignores_addresses_outside_local_networks() {
+    let zone = client_zone();
+    zone.register("10.0.0.7".parse().unwrap(), Arc::from("laptop"), 300);
+
+    assert!(zone.is_empty());
+}
+
+#[tokio::test]
+async fn test_client_zone_unknown_address_falls_back_to_inner() {
+    let inner: Arc<dyn DnsResolver> = Arc::new(MockInner);
+    let resolver = LocalPtrResolver::from_local_records(&[], &None, inner)
+        .with_client_zone(Some(client_zone()));
+
+    let result = resolver
+        .resolve(&ptr_query("9.1.168.192.in-addr.arpa"))
+        .await;
+
+    assert!(matches!(result, Err(DomainError::NxDomain)));
+}
+
+#[tokio::test]
+async fn test_local_record_takes_precedence_over_client_hostname() {
+    let zone = client_zone();
+    zone.register("192.168.1.10".parse().unwrap(), Arc::from("dhcp-name"), 300);
+    let records = vec![make_record("nas", "lan", "192.168.1.10", "A")];
+    let inner: Arc<dyn DnsResolver> = Arc::new(MockInner);
+    let resolver =
+        LocalPtrResolver::from_local_records(&records, &None, inner).with_client_zone(Some(zone));
+
+    let resolution = resolver
+        .resolve(&ptr_query("10.1.168.192.in-addr.arpa"))
+        .await
+        .unwrap();
+
+    assert_eq!(ptr_target(&resolution), "nas.lan.");
+}
+
+#[tokio::test]
+async fn test_client_zone_keeps_fqdn_and_unregisters() {
+    let zone = client_zone();
+    let ip: IpAddr = "192.168.1.20".parse().unwrap();
+    zone.register(ip, Arc::from("printer.office.example"), 300);
+    assert_eq!(
+        zone.lookup(ip).map(|(name, _)| name.to_string()),
+        Some("printer.office.example".to_string())
+    );
+
+    zone.unregister(ip);
+    assert!(zone.lookup(ip).is_none());
+}
@@ -0,0 +1,165 @@
+use ferrous_dns_application::ports::LocalRecordRepository;
+use ferrous_dns_domain::{DomainError, LocalRecord, RecordType};
+use ferrous_dns_infrastructure::repositories::local_record_repository::SqliteLocalRecordRepository;
+use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
+use std::sync::Arc;
+
+async fn create_test_db() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    for migration in [
+        include_str!("../../../migrations/20260316000001_create_local_records.sql"),
+        include_str!("../../../migrations/20260317000001_create_tenants.sql"),
+        include_str!("../../../migrations/20260317000003_add_tenant_to_local_records.sql"),
+    ] {
+        sqlx::query(migration).execute(&pool).await.unwrap();
+    }
+
+    pool
+}
+
+fn new_record(hostname: &str, record_type: RecordType, value: &str) -> LocalRecord {
+    LocalRecord::new(Arc::from(hostname), record_type, Arc::from(value))
+}
+
+#[tokio::test]
+async fn test_create_round_trips_all_fields() {
+    let repo = SqliteLocalRecordRepository::new(create_test_db().await);
+
+    let mut r = new_record("_sip._tcp", RecordType::SRV, "0 5 5060 sip.home.lan");
+    r.domain = Some(Arc::from("home.lan"));
+    r.ttl = 60;
+
+    let created = repo.create(&r).await.unwrap();
+    assert!(created.id.is_some());
+    assert_eq!(created.hostname.as_ref(), "_sip._tcp");
+    assert_eq!(created.domain.as_deref(), Some("home.lan"));
+    assert_eq!(created.record_type, RecordType::SRV);
+    assert_eq!(created.value.as_ref(), "0 5 5060 sip.home.lan");
+    assert_eq!(created.ttl, 60);
+    assert!(created.view.is_none());
+    assert!(created.created_at.is_some());
+
+    let fetched = repo.get_by_id(created.id.unwrap()).await.unwrap().unwrap();
+    assert_eq!(fetched.hostname, created.hostname);
+    assert_eq!(fetched.record_type, RecordType::SRV);
+    assert_eq!(fetched.value, created.value);
+}
+
+#[tokio::test]
+async fn test_duplicate_record_is_a_conflict() {
+    let repo = SqliteLocalRecordRepository::new(create_test_db().await);
+
+    repo.create(&new_record("nas", RecordType::A, "10.0.0.1"))
+        .await
+        .unwrap();
+    repo.create(&new_record("nas", RecordType::A, "10.0.0.2"))
+        .await
+        .unwrap();
+
+    let err = repo
+        .create(&new_record("nas", RecordType::A, "10.0.0.1"))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DomainError::LocalRecordConflict(_)));
+}
+
+#[tokio::test]
+async fn test_same_value_in_view_is_not_a_duplicate() {
+    let repo = SqliteLocalRecordRepository::new(create_test_db().await);
+
+    repo.create(&new_record("nas", RecordType::A, "10.0.0.1"))
+        .await
+        .unwrap();
+    let mut in_view = new_record("nas", RecordType::A, "10.0.0.1");
+    in_view.view = Some(Arc::from("vpn"));
+
+    assert!(repo.create(&in_view).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_get_all_orders_by_hostname() {
+    let repo = SqliteLocalRecordRepository::new(create_test_db().await);
+
+    repo.create(&new_record("zeta", RecordType::A, "10.0.0.3"))
+        .await
+        .unwrap();
+    repo.create(&new_record("alpha", RecordType::TXT, "hello"))
+        .await
+        .unwrap();
+
+    let all = repo.get_all().await.unwrap();
+    let names: Vec<&str> = all.iter().map(|r| r.hostname.as_ref()).collect();
+    assert_eq!(names, vec!["alpha", "zeta"]);
+}
+
+#[tokio::test]
+async fn test_update_replaces_fields() {
+    let repo = SqliteLocalRecordRepository::new(create_test_db().await);
+    let created = repo
+        .create(&new_record("nas", RecordType::A, "10.0.0.1"))
+        .await
+        .unwrap();
+
+    let mut changed = new_record("mail", RecordType::MX, "10 mx.home.lan");
+    changed.id = created.id;
+    changed.ttl = 900;
+    let updated = repo.update(&changed).await.unwrap();
+
+    assert_eq!(updated.id, created.id);
+    assert_eq!(updated.hostname.as_ref(), "mail");
+    assert_eq!(updated.record_type, RecordType::MX);
+    assert_eq!(updated.ttl, 900);
+    let fetched = repo.get_by_id(created.id.unwrap()).await.unwrap().unwrap();
+    assert_eq!(fetched.value.as_ref(), "10 mx.home.lan");
+}
+
+#[tokio::test]
+async fn test_update_missing_record_is_not_found() {
+    let repo = SqliteLocalRecordRepository::new(create_test_db().await);
+    let mut r = new_record("nas", RecordType::A, "10.0.0.1");
+    r.id = Some(42);
+
+    let err = repo.update(&r).await.unwrap_err();
+    assert!(matches!(err, DomainError::LocalRecordNotFound(42)));
+}
+
+#[tokio::test]
+async fn test_delete() {
+    let repo = SqliteLocalRecordRepository::new(create_test_db().await);
+    let created = repo
+        .create(&new_record("nas", RecordType::A, "10.0.0.1"))
+        .await
+        .unwrap();
+    let id = created.id.unwrap();
+
+    repo.delete(id).await.unwrap();
+
+    assert!(repo.get_by_id(id).await.unwrap().is_none());
+    assert!(matches!(
+        repo.delete(id).await.unwrap_err(),
+        DomainError::LocalRecordNotFound(_)
+    ));
+}
+
+#[tokio::test]
+async fn test_row_with_unknown_type_is_skipped() {
+    let pool = create_test_db().await;
+    sqlx::query(
+        "INSERT INTO local_records (hostname, record_type, value) VALUES ('odd', 'BOGUS', 'x')",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    let repo = SqliteLocalRecordRepository::new(pool);
+    repo.create(&new_record("nas", RecordType::A, "10.0.0.1"))
+        .await
+        .unwrap();
+
+    let all = repo.get_all().await.unwrap();
+    assert_eq!(all.len(), 1);
+    assert_eq!(all[0].hostname.as_ref(), "nas");
+}
@@ -0,0 +1,199 @@
+use ferrous_dns_application::ports::{LocalRecordRepository, LocalZoneAnswer, LocalZonePort};
+use ferrous_dns_domain::{LocalRecord, RecordType};
+use ferrous_dns_infrastructure::dns::LocalZoneStore;
+use ferrous_dns_infrastructure::repositories::local_record_repository::SqliteLocalRecordRepository;
+use hickory_proto::op::Message;
+use hickory_proto::rr::{RData, RecordType as HickoryRecordType};
+use sqlx::sqlite::SqlitePoolOptions;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+async fn create_repo() -> Arc<SqliteLocalRecordRepository> {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    for migration in [
+        include_str!("../../../migrations/20260316000001_create_local_records.sql"),
+        include_str!("../../../migrations/20260317000001_create_tenants.sql"),
+        include_str!("../../../migrations/20260317000003_add_tenant_to_local_records.sql"),
+    ] {
+        sqlx::query(migration).execute(&pool).await.unwrap();
+    }
+    Arc::new(SqliteLocalRecordRepository::new(pool))
+}
+
+async fn add(repo: &SqliteLocalRecordRepository, hostname: &str, rt: RecordType, value: &str) {
+    let mut record = LocalRecord::new(Arc::from(hostname), rt, Arc::from(value));
+    record.ttl = 120;
+    repo.create(&record).await.unwrap();
+}
+
+async fn store_with(records: &[(&str, RecordType, &str)]) -> Arc<LocalZoneStore> {
+    let repo = create_repo().await;
+    for (hostname, rt, value) in records {
+        add(&repo, hostname, *rt, value).await;
+    }
+    LocalZoneStore::new(repo, Some("home.lan".to_string()))
+        .await
+        .unwrap()
+}
+
+fn records(answer: Option<LocalZoneAnswer>) -> ferrous_dns_application::ports::DnsResolution {
+    match answer {
+        Some(LocalZoneAnswer::Records(resolution)) => resolution,
+        other => panic!("expected records, got {:?}", other),
+    }
+}
+
+fn wire(answer: Option<LocalZoneAnswer>) -> Message {
+    let resolution = records(answer);
+    assert!(resolution.local_dns);
+    Message::from_vec(&resolution.upstream_wire_data.unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn test_address_lookup() {
+    let store = store_with(&[
+        ("nas", RecordType::A, "192.168.1.10"),
+        ("nas", RecordType::A, "192.168.1.11"),
+        ("nas", RecordType::AAAA, "fd00::10"),
+    ])
+    .await;
+
+    let a = records(store.lookup("nas.home.lan", RecordType::A));
+    assert!(a.local_dns);
+    assert_eq!(a.min_ttl, Some(120));
+    assert_eq!(a.addresses.len(), 2);
+
+    let aaaa = records(store.lookup("NAS.home.lan.", RecordType::AAAA));
+    assert_eq!(
+        aaaa.addresses.as_slice(),
+        &["fd00::10".parse::<IpAddr>().unwrap()]
+    );
+}
+
+#[tokio::test]
+async fn test_missing_type_or_name_falls_through() {
+    let store = store_with(&[("nas", RecordType::A, "192.168.1.10")]).await;
+
+    assert!(store.lookup("nas.home.lan", RecordType::AAAA).is_none());
+    assert!(store.lookup("other.home.lan", RecordType::A).is_none());
+}
+
+#[tokio::test]
+async fn test_cname_is_returned_as_alias() {
+    let store = store_with(&[("files", RecordType::CNAME, "nas.home.lan")]).await;
+
+    match store.lookup("files.home.lan", RecordType::A) {
+        Some(LocalZoneAnswer::Cname(target)) => assert_eq!(target.as_ref(), "nas.home.lan"),
+        other => panic!("expected alias, got {:?}", other),
+    }
+
+    let message = wire(store.lookup("files.home.lan", RecordType::CNAME));
+    assert_eq!(message.answers().len(), 1);
+    assert_eq!(message.answers()[0].record_type(), HickoryRecordType::CNAME);
+}
+
+#[tokio::test]
+async fn test_txt_mx_srv_are_encoded_on_the_wire() {
+    let store = store_with(&[
+        ("mail", RecordType::MX, "10 mx1.home.lan"),
+        ("mail", RecordType::MX, "20 mx2.home.lan"),
+        ("mail", RecordType::TXT, "v=spf1 -all"),
+        ("_sip._tcp", RecordType::SRV, "0 5 5060 sip.home.lan"),
+    ])
+    .await;
+
+    let mx = wire(store.lookup("mail.home.lan", RecordType::MX));
+    assert!(mx.authoritative());
+    assert_eq!(mx.queries().len(), 1);
+    assert_eq!(mx.answers().len(), 2);
+    assert_eq!(mx.answers()[0].ttl(), 120);
+
+    let txt = wire(store.lookup("mail.home.lan", RecordType::TXT));
+    match txt.answers()[0].data() {
+        RData::TXT(data) => assert_eq!(data.to_string(), "v=spf1 -all"),
+        other => panic!("unexpected rdata {:?}", other),
+    }
+
+    let srv = wire(store.lookup("_sip._tcp.home.lan", RecordType::SRV));
+    match srv.answers()[0].data() {
+        RData::SRV(data) => {
+            assert_eq!(data.port(), 5060);
+            assert_eq!(data.target().to_string(), "sip.home.lan.");
+        }
+        other => panic!("unexpected rdata {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_long_txt_is_split_into_character_strings() {
+    let text = "a".repeat(600);
+    let store = store_with(&[("long", RecordType::TXT, text.as_str())]).await;
+
+    let message = wire(store.lookup("long.home.lan", RecordType::TXT));
+    match message.answers()[0].data() {
+        RData::TXT(data) => {
+            let lens: Vec<usize> = data.iter().map(|s| s.len()).collect();
+            assert_eq!(lens, vec![255, 255, 90]);
+        }
+        other => panic!("unexpected rdata {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_reload_picks_up_changes() {
+    let repo = create_repo().await;
+    let store = LocalZoneStore::new(repo.clone(), None).await.unwrap();
+    assert!(store.lookup("nas", RecordType::A).is_none());
+
+    add(&repo, "nas", RecordType::A, "10.0.0.1").await;
+    assert!(store.lookup("nas", RecordType::A).is_none());
+
+    store.reload().await.unwrap();
+    assert!(store.lookup("nas", RecordType::A).is_some());
+}
+
+#[tokio::test]
+async fn test_view_records_are_kept_out_of_the_zone() {
+    let repo = create_repo().await;
+    let mut record = LocalRecord::new(Arc::from("nas"), RecordType::A, Arc::from("10.8.0.1"));
+    record.view = Some(Arc::from("vpn"));
+    repo.create(&record).await.unwrap();
+
+    let store = LocalZoneStore::new(repo, None).await.unwrap();
+
+    assert!(store.lookup("nas", RecordType::A).is_none());
+    let views = store.view_records();
+    assert_eq!(views.len(), 1);
+    assert_eq!(views[0].ip, "10.8.0.1");
+    assert_eq!(views[0].view.as_deref(), Some("vpn"));
+}
+
+#[tokio::test]
+async fn test_wildcard_and_ip_template() {
+    let store = store_with(&[
+        ("*.dev", RecordType::A, "127.0.0.1"),
+        ("*.dev", RecordType::TXT, "lab"),
+        ("*.nip", RecordType::A, "{ip}"),
+    ])
+    .await;
+
+    let a = records(store.lookup("app.dev.home.lan", RecordType::A));
+    assert_eq!(
+        a.addresses.as_slice(),
+        &["127.0.0.1".parse::<IpAddr>().unwrap()]
+    );
+
+    let txt = wire(store.lookup("app.dev.home.lan", RecordType::TXT));
+    assert_eq!(txt.answers().len(), 1);
+    assert_eq!(txt.answers()[0].name().to_ascii(), "app.dev.home.lan.");
+
+    let embedded = records(store.lookup("10.0.0.7.nip.home.lan", RecordType::A));
+    assert_eq!(
+        embedded.addresses.as_slice(),
+        &["10.0.0.7".parse::<IpAddr>().unwrap()]
+    );
+    assert!(store.lookup("web.nip.home.lan", RecordType::A).is_none());
+}
@@ -0,0 +1,410 @@
+#![cfg(feature = "lua-plugins")]
+
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{
+    BlockFilterEnginePort, DnsResolution, DnsResolver, FilterDecision, PluginDecision,
+    PluginHookPort, PluginQuery, PluginVerdict,
+};
+use ferrous_dns_application::use_cases::HandleDnsQueryUseCase;
+use ferrous_dns_domain::config::DatabaseConfig;
+use ferrous_dns_domain::{DnsQuery, DomainError, PluginsConfig, RecordType};
+use ferrous_dns_infrastructure::database::create_write_pool;
+use ferrous_dns_infrastructure::dns::LuaPluginHost;
+use ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+fn config() -> PluginsConfig {
+    PluginsConfig {
+        enabled: true,
+        timeout_ms: 20,
+        ..Default::default()
+    }
+}
+
+fn verdict(decision: Option<PluginDecision>) -> Option<PluginVerdict> {
+    decision.map(|d| d.verdict)
+}
+
+/// Prefixes `source` with a `domains` global covering the `.com` names the
+/// tests query.
+fn script(source: &str) -> String {
+    format!("domains = {{ \"com\" }}\n{source}")
+}
+
+fn host(source: &str) -> Result<LuaPluginHost, DomainError> {
+    LuaPluginHost::from_sources([("test.lua", script(source).as_str())], &config())
+}
+
+fn query(domain: &str) -> PluginQuery {
+    PluginQuery {
+        domain: Arc::from(domain),
+        record_type: RecordType::A,
+        client_ip: "192.168.1.10".parse().unwrap(),
+        group_id: 1,
+    }
+}
+
+#[tokio::test]
+async fn pre_filter_verdicts_map_to_port_verdicts() {
+    let host = host(
+        r#"
+        function pre_filter(q)
+            if q.domain == "ads.example.com" then return "block" end
+            if q.domain == "gone.example.com" then return "nxdomain" end
+            if q.domain == "nas.example.com" then return { "10.0.0.5" } end
+            return nil
+        end
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        verdict(host.pre_filter(&query("ads.example.com")).await),
+        Some(PluginVerdict::Block)
+    );
+    assert_eq!(
+        verdict(host.pre_filter(&query("gone.example.com")).await),
+        Some(PluginVerdict::NxDomain)
+    );
+    assert_eq!(
+        verdict(host.pre_filter(&query("nas.example.com")).await),
+        Some(PluginVerdict::Answer(vec!["10.0.0.5".parse().unwrap()]))
+    );
+    assert_eq!(verdict(host.pre_filter(&query("example.com")).await), None);
+}
+
+#[tokio::test]
+async fn query_fields_are_visible_to_the_script() {
+    let host = host(
+        r#"
+        function pre_filter(q)
+            if q.type == "A" and q.client == "192.168.1.10" and q.group == 1 then
+                return "block"
+            end
+        end
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        verdict(host.pre_filter(&query("example.com")).await),
+        Some(PluginVerdict::Block)
+    );
+}
+
+#[tokio::test]
+async fn globals_persist_across_calls() {
+    let host = host(
+        r#"
+        local counts = {}
+        function pre_filter(q)
+            counts[q.domain] = (counts[q.domain] or 0) + 1
+            if counts[q.domain] > 2 then return "block" end
+        end
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(verdict(host.pre_filter(&query("a.com")).await), None);
+    assert_eq!(verdict(host.pre_filter(&query("a.com")).await), None);
+    assert_eq!(
+        verdict(host.pre_filter(&query("a.com")).await),
+        Some(PluginVerdict::Block)
+    );
+    assert_eq!(verdict(host.pre_filter(&query("b.com")).await), None);
+}
+
+#[tokio::test]
+async fn post_resolve_receives_answers() {
+    let host = host(
+        r#"
+        function post_resolve(q, answers)
+            if answers[1] == "1.2.3.4" then return { "5.6.7.8" } end
+        end
+        "#,
+    )
+    .unwrap();
+    let answers: Vec<IpAddr> = vec!["1.2.3.4".parse().unwrap()];
+
+    assert_eq!(
+        verdict(host.post_resolve(&query("example.com"), &answers).await),
+        Some(PluginVerdict::Answer(vec!["5.6.7.8".parse().unwrap()]))
+    );
+    assert_eq!(verdict(host.pre_filter(&query("example.com")).await), None);
+}
+
+#[tokio::test]
+async fn runaway_script_is_stopped_and_continues() {
+    let host = host(
+        r#"
+        function pre_filter(q)
+            while true do end
+        end
+        "#,
+    )
+    .unwrap();
+
+    let started = std::time::Instant::now();
+    assert_eq!(verdict(host.pre_filter(&query("example.com")).await), None);
+    assert!(started.elapsed() < std::time::Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn memory_limit_is_enforced() {
+    let config = PluginsConfig {
+        memory_limit_kb: 512,
+        ..config()
+    };
+    let source = script(
+        r#"
+        function pre_filter(q)
+            local t = {}
+            for i = 1, 1000000 do t[i] = string.rep("x", 64) .. i end
+            return "block"
+        end
+        "#,
+    );
+    let host = LuaPluginHost::from_sources([("test.lua", source.as_str())], &config).unwrap();
+
+    assert_eq!(verdict(host.pre_filter(&query("example.com")).await), None);
+}
+
+#[tokio::test]
+async fn file_and_os_access_are_unavailable() {
+    let host = host(
+        r#"
+        function pre_filter(q)
+            if io == nil and os == nil and dofile == nil and load == nil and require == nil
+                and pcall == nil and xpcall == nil then
+                return "block"
+            end
+        end
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        verdict(host.pre_filter(&query("example.com")).await),
+        Some(PluginVerdict::Block)
+    );
+}
+
+#[test]
+fn script_without_hooks_is_rejected() {
+    assert!(matches!(
+        host("local x = 1"),
+        Err(DomainError::InvalidPlugin(_))
+    ));
+}
+
+#[test]
+fn script_without_domains_is_rejected() {
+    for source in [
+        r#"function pre_filter(q) return "block" end"#,
+        r#"domains = {} function pre_filter(q) return "block" end"#,
+        r#"domains = { "." } function pre_filter(q) return "block" end"#,
+    ] {
+        assert!(matches!(
+            LuaPluginHost::from_sources([("test.lua", source)], &config()),
+            Err(DomainError::InvalidPlugin(_))
+        ));
+    }
+}
+
+#[test]
+fn syntax_error_is_rejected() {
+    assert!(matches!(
+        host("function pre_filter(q"),
+        Err(DomainError::InvalidPlugin(_))
+    ));
+}
+
+#[tokio::test]
+async fn unknown_verdict_is_ignored() {
+    let host = host(r#"function pre_filter(q) return "drop" end"#).unwrap();
+
+    assert_eq!(verdict(host.pre_filter(&query("example.com")).await), None);
+}
+
+#[tokio::test]
+async fn first_deciding_script_wins() {
+    let first = script(r#"function pre_filter(q) return nil end"#);
+    let second = script(r#"function pre_filter(q) return "nxdomain" end"#);
+    let third = script(r#"function pre_filter(q) return "block" end"#);
+    let host = LuaPluginHost::from_sources(
+        [
+            ("first.lua", first.as_str()),
+            ("second.lua", second.as_str()),
+            ("third.lua", third.as_str()),
+        ],
+        &config(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        host.pre_filter(&query("example.com")).await,
+        Some(PluginDecision {
+            plugin: Arc::from("second.lua"),
+            verdict: PluginVerdict::NxDomain,
+        })
+    );
+}
+
+#[tokio::test]
+async fn runaway_string_rep_is_cut_off() {
+    let config = PluginsConfig {
+        memory_limit_kb: 1024 * 1024,
+        ..config()
+    };
+    let source = script(
+        r#"
+        function pre_filter(q)
+            local s = string.rep("x", 1e9)
+            return "block"
+        end
+        "#,
+    );
+    let host = LuaPluginHost::from_sources([("test.lua", source.as_str())], &config).unwrap();
+
+    let started = std::time::Instant::now();
+    assert_eq!(verdict(host.pre_filter(&query("example.com")).await), None);
+    assert!(started.elapsed() < std::time::Duration::from_millis(500));
+}
+
+#[tokio::test]
+async fn string_rep_within_limit_still_works() {
+    let host = host(
+        r#"
+        function pre_filter(q)
+            if string.rep("ab", 3, "-") == "ab-ab-ab" and ("x"):rep(2) == "xx" then
+                return "block"
+            end
+        end
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        verdict(host.pre_filter(&query("example.com")).await),
+        Some(PluginVerdict::Block)
+    );
+}
+
+#[tokio::test]
+async fn pattern_functions_reject_long_subjects() {
+    let host = host(
+        r#"
+        local long = ("a"):rep(8192)
+        function pre_filter(q)
+            string.find(long, "(.-)(.-)(.-)b")
+            return "block"
+        end
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(verdict(host.pre_filter(&query("example.com")).await), None);
+}
+
+#[tokio::test]
+async fn domains_global_limits_the_hooks() {
+    let host = host(
+        r#"
+        domains = { "Example.com." }
+        function pre_filter(q) return "block" end
+        "#,
+    )
+    .unwrap();
+
+    assert!(host.intercepts("example.com"));
+    assert!(host.intercepts("www.example.com"));
+    assert!(!host.intercepts("notexample.com"));
+    assert!(!host.intercepts("example.org"));
+    assert_eq!(verdict(host.pre_filter(&query("example.org")).await), None);
+    assert_eq!(
+        verdict(host.pre_filter(&query("www.example.com")).await),
+        Some(PluginVerdict::Block)
+    );
+}
+
+/// Answers every query from "cache" with one address.
+struct CachedResolver;
+
+#[async_trait]
+impl DnsResolver for CachedResolver {
+    async fn resolve(&self, _query: &DnsQuery) -> Result<DnsResolution, DomainError> {
+        Ok(DnsResolution::new(
+            vec!["192.0.2.1".parse().unwrap()],
+            false,
+        ))
+    }
+
+    fn try_cache(&self, _query: &DnsQuery) -> Option<DnsResolution> {
+        Some(DnsResolution::new(vec!["192.0.2.1".parse().unwrap()], true))
+    }
+}
+
+struct AllowAll;
+
+#[async_trait]
+impl BlockFilterEnginePort for AllowAll {
+    fn resolve_group(&self, _ip: IpAddr) -> i64 {
+        1
+    }
+    fn resolve_group_with_default(&self, _ip: IpAddr, default_group_id: i64) -> i64 {
+        default_group_id
+    }
+    fn check(&self, _domain: &str, _group_id: i64) -> FilterDecision {
+        FilterDecision::Allow
+    }
+    fn store_cname_decision(&self, _domain: &str, _group_id: i64, _ttl_secs: u64) {}
+    async fn reload(&self) -> Result<(), DomainError> {
+        Ok(())
+    }
+    async fn load_client_groups(&self) -> Result<(), DomainError> {
+        Ok(())
+    }
+    fn compiled_domain_count(&self) -> usize {
+        0
+    }
+    fn is_blocking_enabled(&self) -> bool {
+        true
+    }
+    fn set_blocking_enabled(&self, _enabled: bool) {}
+}
+
+#[tokio::test]
+async fn uncovered_domains_keep_the_cache_fast_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let url = format!("sqlite:{}", dir.path().join("test.db").display());
+    let pool = create_write_pool(&url, &DatabaseConfig::default())
+        .await
+        .unwrap();
+    let plugins = host(
+        r#"
+        domains = { "example.com" }
+        function pre_filter(q) return "block" end
+        "#,
+    )
+    .unwrap();
+    let use_case = HandleDnsQueryUseCase::new(
+        Arc::new(CachedResolver),
+        Arc::new(AllowAll),
+        Arc::new(SqliteQueryLogRepository::new(
+            pool.clone(),
+            pool.clone(),
+            pool,
+            &DatabaseConfig::default(),
+        )),
+    )
+    .with_plugins(Arc::new(plugins));
+    let client: IpAddr = "192.168.1.10".parse().unwrap();
+
+    assert!(use_case
+        .try_cache_direct("example.org", RecordType::A, client, None)
+        .is_some());
+    assert!(use_case
+        .try_cache_direct("www.example.com", RecordType::A, client, None)
+        .is_none());
+}
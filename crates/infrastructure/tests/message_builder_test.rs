@@ -237,3 +237,10 @@ fn test_query_with_underscores() {
     let result = MessageBuilder::build_query("_service._tcp.example.com", &RecordType::SRV, false);
     assert!(result.is_ok());
 }
+
+#[test]
+fn test_unicode_domain_is_sent_as_punycode() {
+    let bytes = MessageBuilder::build_query("bücher.de", &RecordType::A, false).unwrap();
+    let parsed = hickory_proto::op::Message::from_vec(&bytes).unwrap();
+    assert_eq!(parsed.queries()[0].name().to_ascii(), "xn--bcher-kva.de.");
+}
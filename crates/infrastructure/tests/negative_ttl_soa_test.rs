@@ -51,6 +51,9 @@ impl DnsResolver for MockNegativeResolver {
             cname_chain: Arc::from(vec![]),
             upstream_server: None,
             upstream_pool: None,
+            upstream_strategy: None,
+            upstream_attempt: None,
+            upstream_protocol: None,
             min_ttl: None,
             negative_soa_ttl: self.negative_soa_ttl,
             upstream_wire_data: None,
@@ -75,6 +78,7 @@ fn make_cache() -> Arc<DnsCache> {
         refresh_sample_rate: 1.0,
         min_ttl: 0,
         max_ttl: 86_400,
+        l1_capacity: 1024,
     }))
 }
 
@@ -95,6 +99,7 @@ async fn test_soa_ttl_used_when_present() {
     let query = DnsQuery {
         domain: Arc::from("nxdomain.example.com"),
         record_type: RecordType::A,
+        cache_partition: 0,
     };
     let _ = resolver.resolve(&query).await;
 
@@ -126,6 +131,7 @@ async fn test_soa_ttl_below_min_clamped_to_300() {
     let query = DnsQuery {
         domain: Arc::from("low-ttl.example.com"),
         record_type: RecordType::A,
+        cache_partition: 0,
     };
     let _ = resolver.resolve(&query).await;
 
@@ -155,6 +161,7 @@ async fn test_soa_ttl_above_max_clamped_to_3600() {
     let query = DnsQuery {
         domain: Arc::from("high-ttl.example.com"),
         record_type: RecordType::A,
+        cache_partition: 0,
     };
     let _ = resolver.resolve(&query).await;
 
@@ -185,6 +192,7 @@ async fn test_fallback_to_tracker_when_no_soa() {
     let query = DnsQuery {
         domain: Arc::from("no-soa.example.com"),
         record_type: RecordType::A,
+        cache_partition: 0,
     };
     let _ = resolver.resolve(&query).await;
 
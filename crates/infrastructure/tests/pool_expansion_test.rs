@@ -1,4 +1,4 @@
-use ferrous_dns_domain::{DnsProtocol, UpstreamPool, UpstreamStrategy};
+use ferrous_dns_domain::{DnsProtocol, UdpSocketMode, UpstreamPool, UpstreamStrategy};
 use ferrous_dns_infrastructure::dns::events::QueryEventEmitter;
 use ferrous_dns_infrastructure::dns::load_balancer::PoolManager;
 
@@ -10,6 +10,8 @@ async fn test_pool_manager_expands_hostnames() {
         priority: 1,
         servers: vec!["udp://dns.google:53".into()],
         weight: None,
+        tsig_key: None,
+        udp_socket: UdpSocketMode::Pooled,
     };
 
     let pm = PoolManager::new(vec![pool], None, QueryEventEmitter::new_disabled())
@@ -44,6 +46,8 @@ async fn test_pool_manager_expansion_includes_ipv6() {
         priority: 1,
         servers: vec!["udp://dns.google:53".into()],
         weight: None,
+        tsig_key: None,
+        udp_socket: UdpSocketMode::Pooled,
     };
 
     let pm = PoolManager::new(vec![pool], None, QueryEventEmitter::new_disabled())
@@ -70,6 +74,8 @@ async fn test_pool_manager_keeps_literal_ips_unchanged() {
         priority: 1,
         servers: vec!["udp://8.8.8.8:53".into(), "udp://1.1.1.1:53".into()],
         weight: None,
+        tsig_key: None,
+        udp_socket: UdpSocketMode::Pooled,
     };
 
     let pm = PoolManager::new(vec![pool], None, QueryEventEmitter::new_disabled())
@@ -93,6 +99,8 @@ async fn test_pool_manager_mixed_literal_and_hostname() {
         priority: 1,
         servers: vec!["udp://8.8.8.8:53".into(), "udp://dns.google:53".into()],
         weight: None,
+        tsig_key: None,
+        udp_socket: UdpSocketMode::Pooled,
     };
 
     let pm = PoolManager::new(vec![pool], None, QueryEventEmitter::new_disabled())
@@ -121,6 +129,8 @@ async fn test_pool_manager_tls_hostname_expansion() {
         priority: 1,
         servers: vec!["tls://dns.google:853".into()],
         weight: None,
+        tsig_key: None,
+        udp_socket: UdpSocketMode::Pooled,
     };
 
     let pm = PoolManager::new(vec![pool], None, QueryEventEmitter::new_disabled())
@@ -157,6 +167,8 @@ async fn test_pool_manager_https_not_expanded_but_preresolved() {
         priority: 1,
         servers: vec!["https://dns.google/dns-query".into()],
         weight: None,
+        tsig_key: None,
+        udp_socket: UdpSocketMode::Pooled,
     };
 
     let pm = PoolManager::new(vec![pool], None, QueryEventEmitter::new_disabled())
@@ -188,6 +200,8 @@ async fn test_pool_manager_preresolves_h3_hostnames() {
         priority: 1,
         servers: vec!["h3://dns.google/dns-query".into()],
         weight: None,
+        tsig_key: None,
+        udp_socket: UdpSocketMode::Pooled,
     };
 
     let pm = PoolManager::new(vec![pool], None, QueryEventEmitter::new_disabled())
@@ -219,6 +233,8 @@ async fn test_pool_manager_https_ip_no_resolution() {
         priority: 1,
         servers: vec!["https://1.1.1.1/dns-query".into()],
         weight: None,
+        tsig_key: None,
+        udp_socket: UdpSocketMode::Pooled,
     };
 
     let pm = PoolManager::new(vec![pool], None, QueryEventEmitter::new_disabled())
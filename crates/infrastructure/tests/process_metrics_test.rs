@@ -0,0 +1,73 @@
+use ferrous_dns_application::ports::{ChannelDepth, ChannelDepthSource, SystemMetricsPort};
+use ferrous_dns_infrastructure::dns::transport::UdpSocketPool;
+use ferrous_dns_infrastructure::system::ProcessMetricsCollector;
+use std::sync::Arc;
+
+struct FixedChannel(Option<usize>);
+
+impl ChannelDepthSource for FixedChannel {
+    fn channel_depth(&self) -> Option<ChannelDepth> {
+        self.0.map(|queued| ChannelDepth {
+            name: "fixed",
+            queued,
+            capacity: 64,
+        })
+    }
+}
+
+#[tokio::test]
+async fn reports_runtime_task_counts() {
+    let collector = ProcessMetricsCollector::new();
+    let metrics = collector.process_metrics();
+
+    assert!(metrics.runtime_workers.unwrap() >= 1);
+    assert!(metrics.alive_tasks.is_some());
+    assert!(metrics.global_queue_depth.is_some());
+}
+
+#[test]
+fn runtime_fields_are_empty_outside_a_runtime() {
+    let metrics = ProcessMetricsCollector::new().process_metrics();
+
+    assert!(metrics.runtime_workers.is_none());
+    assert!(metrics.alive_tasks.is_none());
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn reads_rss_and_open_fds_from_procfs() {
+    let metrics = ProcessMetricsCollector::new().process_metrics();
+
+    assert!(metrics.rss_bytes.unwrap() > 0);
+    assert!(metrics.open_fds.unwrap() > 0);
+}
+
+#[test]
+fn lists_only_channels_in_use() {
+    let collector = ProcessMetricsCollector::new()
+        .with_channel(Arc::new(FixedChannel(Some(5))))
+        .with_channel(Arc::new(FixedChannel(None)));
+
+    let channels = collector.process_metrics().channels;
+
+    assert_eq!(channels.len(), 1);
+    assert_eq!(channels[0].queued, 5);
+    assert_eq!(channels[0].capacity, 64);
+}
+
+#[test]
+fn reports_udp_pool_only_when_attached() {
+    let pool = Arc::new(UdpSocketPool::new(8, 16));
+
+    assert!(ProcessMetricsCollector::new()
+        .process_metrics()
+        .udp_pool
+        .is_none());
+
+    let metrics = ProcessMetricsCollector::new()
+        .with_udp_pool(pool)
+        .process_metrics();
+    let udp_pool = metrics.udp_pool.unwrap();
+    assert_eq!(udp_pool.max_idle_per_server, 8);
+    assert_eq!(udp_pool.sockets_created, 0);
+}
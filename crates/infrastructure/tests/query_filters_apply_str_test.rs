@@ -7,6 +7,7 @@ fn filters_passthrough() -> QueryFilters {
         block_non_fqdn: false,
         local_domain: None,
         has_local_dns_server: false,
+        conditional_forwards: None,
     }
 }
 
@@ -33,6 +34,7 @@ fn apply_str_returns_none_for_private_ptr_when_no_local_dns_server() {
         block_non_fqdn: false,
         local_domain: None,
         has_local_dns_server: false,
+        conditional_forwards: None,
     };
     assert!(filters.apply_str("1.10.0.10.in-addr.arpa").is_none());
 }
@@ -44,6 +46,7 @@ fn apply_str_allows_private_ptr_when_local_dns_server_configured() {
         block_non_fqdn: false,
         local_domain: None,
         has_local_dns_server: true,
+        conditional_forwards: None,
     };
     let result = filters.apply_str("1.10.0.10.in-addr.arpa");
     assert!(result.is_some());
@@ -57,6 +60,7 @@ fn apply_str_never_blocks_public_ptr() {
         block_non_fqdn: false,
         local_domain: None,
         has_local_dns_server: false,
+        conditional_forwards: None,
     };
     assert!(filters.apply_str("8.8.8.8.in-addr.arpa").is_some());
 }
@@ -70,6 +74,7 @@ fn apply_str_returns_none_for_single_label_when_block_non_fqdn() {
         block_non_fqdn: true,
         local_domain: None,
         has_local_dns_server: false,
+        conditional_forwards: None,
     };
     assert!(filters.apply_str("printer").is_none());
 }
@@ -81,6 +86,7 @@ fn apply_str_allows_fqdn_when_block_non_fqdn_enabled() {
         block_non_fqdn: true,
         local_domain: None,
         has_local_dns_server: false,
+        conditional_forwards: None,
     };
     let result = filters.apply_str("printer.local");
     assert!(result.is_some());
@@ -96,6 +102,7 @@ fn apply_str_appends_local_domain_to_single_label_and_returns_owned() {
         block_non_fqdn: false,
         local_domain: Some("lan".to_string()),
         has_local_dns_server: false,
+        conditional_forwards: None,
     };
     let result = filters.apply_str("printer");
     assert!(result.is_some());
@@ -114,6 +121,7 @@ fn apply_str_returns_borrowed_for_fqdn_even_when_local_domain_configured() {
         block_non_fqdn: false,
         local_domain: Some("lan".to_string()),
         has_local_dns_server: false,
+        conditional_forwards: None,
     };
     let result = filters.apply_str("google.com");
     assert!(result.is_some());
@@ -136,6 +144,7 @@ fn apply_str_and_apply_agree_on_private_ptr_block() {
         block_non_fqdn: false,
         local_domain: None,
         has_local_dns_server: false,
+        conditional_forwards: None,
     };
     let domain = "1.168.192.in-addr.arpa";
     let query = DnsQuery::new(domain, RecordType::PTR);
@@ -157,6 +166,7 @@ fn apply_str_and_apply_agree_on_local_domain_append() {
         block_non_fqdn: false,
         local_domain: Some("home".to_string()),
         has_local_dns_server: false,
+        conditional_forwards: None,
     };
     let domain = "nas";
     let query = DnsQuery::new(domain, RecordType::A);
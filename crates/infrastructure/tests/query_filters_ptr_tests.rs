@@ -12,6 +12,7 @@ fn test_private_ptr_blocked_when_no_local_dns_server() {
         block_non_fqdn: false,
         local_domain: None,
         has_local_dns_server: false,
+        conditional_forwards: None,
     };
 
     let query = ptr_query("1.10.0.10.in-addr.arpa");
@@ -27,6 +28,7 @@ fn test_private_ptr_allowed_when_local_dns_server_configured() {
         block_non_fqdn: false,
         local_domain: None,
         has_local_dns_server: true,
+        conditional_forwards: None,
     };
 
     let query = ptr_query("1.10.0.10.in-addr.arpa");
@@ -46,6 +48,7 @@ fn test_public_ptr_never_blocked_by_private_filter() {
         block_non_fqdn: false,
         local_domain: None,
         has_local_dns_server: false,
+        conditional_forwards: None,
     };
 
     let query = ptr_query("8.8.8.8.in-addr.arpa");
@@ -0,0 +1,297 @@
+use chrono::{Datelike, Utc};
+use ferrous_dns_application::ports::{DatabaseMaintenancePort, QueryLogRepository};
+use ferrous_dns_domain::config::{DatabaseConfig, QueryLogPartitioning};
+use ferrous_dns_domain::{QueryLog, QuerySource, RecordType};
+use ferrous_dns_infrastructure::database::{create_write_pool, SqliteDatabaseMaintenance};
+use ferrous_dns_infrastructure::repositories::query_log_repository::{
+    query_log_tables, SqliteQueryLogRepository,
+};
+use sqlx::SqlitePool;
+use std::net::IpAddr;
+use std::time::Duration;
+use tempfile::TempDir;
+
+async fn create_file_db() -> (TempDir, SqlitePool) {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("ferrous.db");
+    let url = format!("sqlite:{}", path.display());
+    let pool = create_write_pool(&url, &DatabaseConfig::default())
+        .await
+        .unwrap();
+    (dir, pool)
+}
+
+fn repository(pool: &SqlitePool, partitioning: QueryLogPartitioning) -> SqliteQueryLogRepository {
+    let cfg = DatabaseConfig {
+        query_log_partitioning: partitioning,
+        ..DatabaseConfig::default()
+    };
+    SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &cfg)
+}
+
+fn make_log(domain: &str) -> QueryLog {
+    QueryLog {
+        id: None,
+        domain: domain.into(),
+        record_type: RecordType::A,
+        client_ip: IpAddr::from([192, 168, 1, 10]),
+        client_hostname: None,
+        client_mac: None,
+        blocked: false,
+        response_time_us: Some(100),
+        cache_hit: false,
+        cache_refresh: false,
+        dnssec_status: None,
+        upstream_server: None,
+        upstream_pool: None,
+        upstream_strategy: None,
+        upstream_attempt: None,
+        upstream_protocol: None,
+        response_status: Some("NOERROR"),
+        timestamp: None,
+        query_source: QuerySource::Client,
+        group_id: None,
+        group_name: None,
+        block_source: None,
+        plugin: None,
+    }
+}
+
+fn current_week_table() -> String {
+    let week = Utc::now().iso_week();
+    format!("query_log_{:04}{:02}", week.year(), week.week())
+}
+
+async fn count_rows(pool: &SqlitePool, table: &str) -> i64 {
+    sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {table}"))
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+/// Waits for the batched writer to flush `expected` rows into `table`.
+async fn wait_for_rows(pool: &SqlitePool, table: &str, expected: i64) {
+    for _ in 0..50 {
+        let exists: Option<i64> =
+            sqlx::query_scalar("SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?")
+                .bind(table)
+                .fetch_optional(pool)
+                .await
+                .unwrap();
+        if exists.is_some() && count_rows(pool, table).await >= expected {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    panic!("{table} never reached {expected} rows");
+}
+
+async fn insert_into(pool: &SqlitePool, table: &str, domain: &str, created_at: &str) {
+    sqlx::query(&format!(
+        "INSERT INTO {table} (domain, record_type, client_ip, created_at) VALUES (?, 'A', '192.168.1.10', ?)"
+    ))
+    .bind(domain)
+    .bind(created_at)
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+async fn create_old_partition(pool: &SqlitePool, table: &str, first_id: i64, rows: i64) {
+    sqlx::query(&format!(
+        "CREATE TABLE {table} AS SELECT * FROM query_log WHERE 0"
+    ))
+    .execute(pool)
+    .await
+    .unwrap();
+    for i in 0..rows {
+        sqlx::query(&format!(
+            "INSERT INTO {table} (id, domain, record_type, client_ip, created_at) VALUES (?, ?, 'A', '192.168.1.10', '2020-01-08 12:00:00')"
+        ))
+        .bind(first_id + i)
+        .bind(format!("old-{i}.example.com"))
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+}
+
+#[tokio::test]
+async fn test_writes_go_to_current_week_partition() {
+    let (_dir, pool) = create_file_db().await;
+    let repo = repository(&pool, QueryLogPartitioning::Week);
+
+    repo.log_query(&make_log("example.com")).await.unwrap();
+    let table = current_week_table();
+    wait_for_rows(&pool, &table, 1).await;
+
+    assert_eq!(count_rows(&pool, "query_log").await, 0);
+    let tables = query_log_tables(&pool).await.unwrap();
+    assert_eq!(tables, vec!["query_log".to_string(), table]);
+}
+
+#[tokio::test]
+async fn test_day_partitioning_names_tables_by_date() {
+    let (_dir, pool) = create_file_db().await;
+    let repo = repository(&pool, QueryLogPartitioning::Day);
+
+    repo.log_query(&make_log("example.com")).await.unwrap();
+
+    let table = format!("query_log_{}", Utc::now().format("%Y%m%d"));
+    wait_for_rows(&pool, &table, 1).await;
+}
+
+#[tokio::test]
+async fn test_no_partitioning_writes_to_query_log() {
+    let (_dir, pool) = create_file_db().await;
+    let repo = repository(&pool, QueryLogPartitioning::None);
+
+    repo.log_query(&make_log("example.com")).await.unwrap();
+    wait_for_rows(&pool, "query_log", 1).await;
+
+    assert_eq!(
+        query_log_tables(&pool).await.unwrap(),
+        vec!["query_log".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn test_partition_gets_template_indexes() {
+    let (_dir, pool) = create_file_db().await;
+    let repo = repository(&pool, QueryLogPartitioning::Week);
+
+    repo.log_query(&make_log("example.com")).await.unwrap();
+    let table = current_week_table();
+    wait_for_rows(&pool, &table, 1).await;
+
+    let count_indexes = |t: String| {
+        let pool = pool.clone();
+        async move {
+            sqlx::query_scalar::<_, i64>(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type = 'index' AND tbl_name = ? AND sql IS NOT NULL",
+            )
+            .bind(t)
+            .fetch_one(&pool)
+            .await
+            .unwrap()
+        }
+    };
+    let template = count_indexes("query_log".to_string()).await;
+    assert!(template > 0);
+    assert_eq!(count_indexes(table).await, template);
+}
+
+#[tokio::test]
+async fn test_reads_span_query_log_and_partitions() {
+    let (_dir, pool) = create_file_db().await;
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    insert_into(&pool, "query_log", "legacy.example.com", &now).await;
+    let repo = repository(&pool, QueryLogPartitioning::Week);
+
+    repo.log_query(&make_log("partitioned.example.com"))
+        .await
+        .unwrap();
+    wait_for_rows(&pool, &current_week_table(), 1).await;
+
+    assert_eq!(repo.count_queries_since(3600).await.unwrap(), 2);
+    let recent = repo.get_recent(10, 1.0).await.unwrap();
+    let domains: Vec<&str> = recent.iter().map(|q| q.domain.as_ref()).collect();
+    assert!(domains.contains(&"legacy.example.com"));
+    assert!(domains.contains(&"partitioned.example.com"));
+}
+
+#[tokio::test]
+async fn test_partition_ids_continue_after_query_log() {
+    let (_dir, pool) = create_file_db().await;
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    for i in 0..3 {
+        insert_into(&pool, "query_log", &format!("legacy-{i}.example.com"), &now).await;
+    }
+    let repo = repository(&pool, QueryLogPartitioning::Week);
+
+    repo.log_query(&make_log("partitioned.example.com"))
+        .await
+        .unwrap();
+    let table = current_week_table();
+    wait_for_rows(&pool, &table, 1).await;
+
+    let id: i64 = sqlx::query_scalar(&format!("SELECT id FROM {table}"))
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(id, 4);
+}
+
+#[tokio::test]
+async fn test_retention_drops_expired_partitions() {
+    let (_dir, pool) = create_file_db().await;
+    create_old_partition(&pool, "query_log_202002", 1, 3).await;
+    let repo = repository(&pool, QueryLogPartitioning::Week);
+    repo.log_query(&make_log("example.com")).await.unwrap();
+    let table = current_week_table();
+    wait_for_rows(&pool, &table, 1).await;
+
+    let deleted = repo.delete_older_than(30).await.unwrap();
+
+    assert_eq!(deleted, 3);
+    assert_eq!(
+        query_log_tables(&pool).await.unwrap(),
+        vec!["query_log".to_string(), table]
+    );
+}
+
+#[tokio::test]
+async fn test_retention_still_deletes_old_query_log_rows() {
+    let (_dir, pool) = create_file_db().await;
+    insert_into(&pool, "query_log", "old.example.com", "2020-01-08 12:00:00").await;
+    let repo = repository(&pool, QueryLogPartitioning::Week);
+
+    assert_eq!(repo.delete_older_than(30).await.unwrap(), 1);
+    assert_eq!(count_rows(&pool, "query_log").await, 0);
+}
+
+#[tokio::test]
+async fn test_flush_drops_every_partition() {
+    let (_dir, pool) = create_file_db().await;
+    let repo = repository(&pool, QueryLogPartitioning::Week);
+    repo.log_query(&make_log("example.com")).await.unwrap();
+    let table = current_week_table();
+    wait_for_rows(&pool, &table, 1).await;
+
+    assert_eq!(repo.delete_older_than(0).await.unwrap(), 1);
+    assert_eq!(
+        query_log_tables(&pool).await.unwrap(),
+        vec!["query_log".to_string()]
+    );
+
+    // The writer recreates the partition for the next batch.
+    repo.log_query(&make_log("example.com")).await.unwrap();
+    repo.log_query(&make_log("example.com")).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    repo.log_query(&make_log("example.com")).await.unwrap();
+    wait_for_rows(&pool, &table, 1).await;
+}
+
+#[tokio::test]
+async fn test_size_cap_drops_oldest_partition_whole() {
+    let (_dir, pool) = create_file_db().await;
+    create_old_partition(&pool, "query_log_202002", 1, 3).await;
+    create_old_partition(&pool, "query_log_202003", 10, 3).await;
+    let maintenance = SqliteDatabaseMaintenance::new(pool.clone());
+
+    assert_eq!(maintenance.delete_oldest_query_logs(10).await.unwrap(), 3);
+    assert_eq!(
+        query_log_tables(&pool).await.unwrap(),
+        vec!["query_log".to_string(), "query_log_202003".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn test_size_cap_trims_rows_of_newest_partition() {
+    let (_dir, pool) = create_file_db().await;
+    create_old_partition(&pool, "query_log_202002", 1, 3).await;
+    let maintenance = SqliteDatabaseMaintenance::new(pool.clone());
+
+    assert_eq!(maintenance.delete_oldest_query_logs(2).await.unwrap(), 2);
+    assert_eq!(count_rows(&pool, "query_log_202002").await, 1);
+}
@@ -1,8 +1,9 @@
-use ferrous_dns_application::ports::{QueryLogRepository, TimeGranularity};
+use ferrous_dns_application::ports::{QueryLogRepository, TimeGranularity, TimelineBreakdown};
 use ferrous_dns_domain::config::DatabaseConfig;
-use ferrous_dns_domain::{QueryCategory, QueryLogFilter};
+use ferrous_dns_domain::{QueryCategory, QueryLog, QueryLogFilter, QuerySource, RecordType};
 use ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository;
 use sqlx::sqlite::SqlitePoolOptions;
+use std::sync::Arc;
 
 async fn create_test_db() -> sqlx::SqlitePool {
     let pool = SqlitePoolOptions::new()
@@ -24,10 +25,15 @@ async fn create_test_db() -> sqlx::SqlitePool {
             dnssec_status TEXT,
             upstream_server TEXT,
             upstream_pool TEXT,
+            upstream_strategy TEXT,
+            upstream_attempt INTEGER,
+            upstream_protocol TEXT,
             response_status TEXT,
             query_source TEXT NOT NULL DEFAULT 'client',
             group_id INTEGER,
+            tenant_id INTEGER,
             block_source TEXT,
+            plugin TEXT,
             created_at DATETIME NOT NULL DEFAULT (datetime('now'))
         )
         "#,
@@ -36,6 +42,29 @@ async fn create_test_db() -> sqlx::SqlitePool {
     .await
     .unwrap();
 
+    sqlx::query(
+        r#"
+        CREATE TABLE query_stats_rollup (
+            bucket TEXT PRIMARY KEY,
+            queries INTEGER NOT NULL DEFAULT 0,
+            blocked INTEGER NOT NULL DEFAULT 0,
+            rate_limited INTEGER NOT NULL DEFAULT 0,
+            cache_hits INTEGER NOT NULL DEFAULT 0,
+            local_dns INTEGER NOT NULL DEFAULT 0,
+            any_minimized INTEGER NOT NULL DEFAULT 0,
+            time_sum INTEGER NOT NULL DEFAULT 0,
+            cache_time_sum INTEGER NOT NULL DEFAULT 0,
+            upstream_queries INTEGER NOT NULL DEFAULT 0,
+            upstream_time_sum INTEGER NOT NULL DEFAULT 0,
+            latency_histogram BLOB,
+            last_id INTEGER NOT NULL DEFAULT 0
+        ) WITHOUT ROWID
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
     sqlx::query(
         r#"
         CREATE TABLE clients (
@@ -50,7 +79,10 @@ async fn create_test_db() -> sqlx::SqlitePool {
             last_hostname_update DATETIME,
             group_id INTEGER NOT NULL DEFAULT 1,
             created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
-            updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+            updated_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            display_name TEXT,
+            category TEXT,
+            notes TEXT
         )
         "#,
     )
@@ -58,6 +90,13 @@ async fn create_test_db() -> sqlx::SqlitePool {
     .await
     .unwrap();
 
+    sqlx::query(
+        "CREATE TABLE groups (id INTEGER PRIMARY KEY, name TEXT NOT NULL, tenant_id INTEGER)",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
     pool
 }
 
@@ -282,6 +321,112 @@ async fn test_get_timeline_returns_buckets() {
     assert_eq!(buckets[0].unblocked, 1);
 }
 
+#[tokio::test]
+async fn test_get_timeline_splits_cached_and_forwarded() {
+    let pool = create_test_db().await;
+
+    insert_log(&pool, false, false, None, "client", None).await;
+    insert_log(&pool, true, false, None, "client", None).await;
+    insert_log(&pool, true, false, None, "client", None).await;
+    insert_log(&pool, false, true, Some("blocklist"), "client", None).await;
+    insert_query(&pool, "nas.home.lan", false, false, None, Some("LOCAL_DNS")).await;
+
+    let repo = SqliteQueryLogRepository::new(
+        pool.clone(),
+        pool.clone(),
+        pool.clone(),
+        &DatabaseConfig::default(),
+    );
+    let buckets = repo.get_timeline(24, TimeGranularity::Hour).await.unwrap();
+
+    assert_eq!(buckets.len(), 1);
+    assert_eq!(buckets[0].total, 5);
+    assert_eq!(buckets[0].cached, 2);
+    assert_eq!(buckets[0].forwarded, 1);
+    assert_eq!(buckets[0].blocked, 1);
+}
+
+#[tokio::test]
+async fn test_timeline_breakdown_by_client_keeps_busiest() {
+    let pool = create_test_db().await;
+
+    for _ in 0..3 {
+        insert_log_with_domain(&pool, "a.com", "10.0.0.1", false, None, "client").await;
+    }
+    for _ in 0..2 {
+        insert_log_with_domain(
+            &pool,
+            "b.com",
+            "10.0.0.2",
+            true,
+            Some("blocklist"),
+            "client",
+        )
+        .await;
+    }
+    insert_log_with_domain(&pool, "c.com", "10.0.0.3", false, None, "client").await;
+    sqlx::query("INSERT INTO clients (ip_address, hostname) VALUES ('10.0.0.1', 'laptop')")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let repo = SqliteQueryLogRepository::new(
+        pool.clone(),
+        pool.clone(),
+        pool.clone(),
+        &DatabaseConfig::default(),
+    );
+    let series = repo
+        .get_timeline_breakdown(24, TimeGranularity::Hour, TimelineBreakdown::Client, 2)
+        .await
+        .unwrap();
+
+    assert_eq!(series.len(), 2);
+    assert_eq!(series[0].key, "10.0.0.1");
+    assert_eq!(series[0].label.as_deref(), Some("laptop"));
+    assert_eq!(series[0].buckets[0].total, 3);
+    assert_eq!(series[1].key, "10.0.0.2");
+    assert_eq!(series[1].label, None);
+    assert_eq!(series[1].buckets[0].blocked, 2);
+}
+
+#[tokio::test]
+async fn test_timeline_breakdown_by_group_uses_group_names() {
+    let pool = create_test_db().await;
+    sqlx::query("INSERT INTO groups (id, name) VALUES (1, 'Protected'), (2, 'Kids')")
+        .execute(&pool)
+        .await
+        .unwrap();
+    for group_id in [1i64, 2, 2] {
+        sqlx::query("INSERT INTO query_log (domain, group_id) VALUES ('example.com', ?)")
+            .bind(group_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+    sqlx::query("INSERT INTO query_log (domain) VALUES ('ungrouped.com')")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let repo = SqliteQueryLogRepository::new(
+        pool.clone(),
+        pool.clone(),
+        pool.clone(),
+        &DatabaseConfig::default(),
+    );
+    let series = repo
+        .get_timeline_breakdown(24, TimeGranularity::Hour, TimelineBreakdown::Group, 10)
+        .await
+        .unwrap();
+
+    assert_eq!(series.len(), 2);
+    assert_eq!(series[0].key, "2");
+    assert_eq!(series[0].label.as_deref(), Some("Kids"));
+    assert_eq!(series[0].buckets[0].total, 2);
+    assert_eq!(series[1].label.as_deref(), Some("Protected"));
+}
+
 #[tokio::test]
 async fn test_get_timeline_cache_hit_returns_stale_data() {
     let pool = create_test_db().await;
@@ -452,6 +597,130 @@ async fn test_get_top_clients_returns_sorted_with_hostname() {
     assert_eq!(result[1].2, 2);
 }
 
+#[tokio::test]
+async fn test_get_client_activity_scoped_to_client() {
+    let pool = create_test_db().await;
+
+    for _ in 0..3 {
+        insert_log_with_domain(&pool, "example.com", "192.168.1.10", false, None, "client").await;
+    }
+    insert_log_with_domain(&pool, "news.com", "192.168.1.10", false, None, "client").await;
+    for _ in 0..2 {
+        insert_log_with_domain(&pool, "ads.com", "192.168.1.10", true, None, "client").await;
+    }
+    insert_log_with_domain(&pool, "other.com", "192.168.1.20", false, None, "client").await;
+    insert_log_with_domain(
+        &pool,
+        "example.com",
+        "192.168.1.10",
+        false,
+        None,
+        "internal",
+    )
+    .await;
+    sqlx::query("UPDATE query_log SET cache_hit = 1 WHERE domain = 'news.com'")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let repo = SqliteQueryLogRepository::new(
+        pool.clone(),
+        pool.clone(),
+        pool.clone(),
+        &DatabaseConfig::default(),
+    );
+
+    let activity = repo
+        .get_client_activity("192.168.1.10", 24, TimeGranularity::Hour, 10)
+        .await
+        .unwrap();
+
+    assert_eq!(activity.total_queries, 6);
+    assert_eq!(activity.blocked_queries, 2);
+    assert_eq!(activity.cache_hits, 1);
+    assert_eq!(
+        activity.top_domains,
+        vec![("example.com".to_string(), 3), ("news.com".to_string(), 1)]
+    );
+    assert_eq!(
+        activity.top_blocked_domains,
+        vec![("ads.com".to_string(), 2)]
+    );
+    assert_eq!(activity.timeline.iter().map(|b| b.total).sum::<u64>(), 6);
+}
+
+#[tokio::test]
+async fn test_get_client_domain_counts_groups_by_client_and_domain() {
+    let pool = create_test_db().await;
+
+    for _ in 0..3 {
+        insert_query_full(
+            &pool,
+            "gone.example.com",
+            "192.168.1.10",
+            "A",
+            false,
+            false,
+            None,
+            Some("NXDOMAIN"),
+            None,
+        )
+        .await;
+    }
+    insert_query_full(
+        &pool,
+        "gone.example.com",
+        "192.168.1.10",
+        "A",
+        false,
+        true,
+        None,
+        Some("NOERROR"),
+        None,
+    )
+    .await;
+    insert_query_full(
+        &pool,
+        "example.com",
+        "192.168.1.20",
+        "A",
+        false,
+        false,
+        None,
+        None,
+        None,
+    )
+    .await;
+    insert_log_with_domain(
+        &pool,
+        "example.com",
+        "192.168.1.20",
+        false,
+        None,
+        "internal",
+    )
+    .await;
+
+    let repo = SqliteQueryLogRepository::new(
+        pool.clone(),
+        pool.clone(),
+        pool.clone(),
+        &DatabaseConfig::default(),
+    );
+
+    let mut counts = repo.get_client_domain_counts(300).await.unwrap();
+    counts.sort_by(|a, b| a.client_ip.cmp(&b.client_ip));
+
+    assert_eq!(counts.len(), 2);
+    assert_eq!(counts[0].client_ip, "192.168.1.10");
+    assert_eq!(counts[0].domain, "gone.example.com");
+    assert_eq!(counts[0].queries, 4);
+    assert_eq!(counts[0].nxdomain, 3);
+    assert_eq!(counts[1].client_ip, "192.168.1.20");
+    assert_eq!(counts[1].queries, 1);
+    assert_eq!(counts[1].nxdomain, 0);
+}
+
 // --- Category filter tests for get_recent_paged ---
 
 async fn insert_query(
@@ -1104,6 +1373,7 @@ async fn test_combined_all_filters() {
         client_ip: Some("10.0.0.1".parse().unwrap()),
         record_type: Some(ferrous_dns_domain::RecordType::AAAA),
         upstream: Some("8.8.8.8".to_string()),
+        ..Default::default()
     };
     let result = repo
         .get_recent_paged(100, 0, 24.0, None, &filter)
@@ -1191,3 +1461,273 @@ async fn test_cursor_pagination_with_client_filter() {
     let ids2: Vec<_> = page2.queries.iter().filter_map(|q| q.id).collect();
     assert!(ids1.iter().all(|id| !ids2.contains(id)));
 }
+
+#[tokio::test]
+async fn test_recent_queries_carry_client_and_group_details() {
+    let pool = create_test_db().await;
+    sqlx::query(
+        "INSERT INTO clients (ip_address, hostname, mac_address, group_id)
+         VALUES ('10.0.0.1', 'laptop', 'aa:bb:cc:dd:ee:ff', 2)",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    sqlx::query("INSERT INTO groups (id, name) VALUES (2, 'Kids')")
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query(
+        "INSERT INTO query_log (domain, client_ip, group_id)
+         VALUES ('known.com', '10.0.0.1', 2), ('unknown.com', '10.0.0.9', NULL)",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let repo = SqliteQueryLogRepository::new(
+        pool.clone(),
+        pool.clone(),
+        pool.clone(),
+        &DatabaseConfig::default(),
+    );
+    let page = repo
+        .get_recent_paged(10, 0, 24.0, None, &no_filter())
+        .await
+        .unwrap();
+
+    let known = page
+        .queries
+        .iter()
+        .find(|q| q.domain.as_ref() == "known.com")
+        .unwrap();
+    assert_eq!(known.client_hostname.as_deref(), Some("laptop"));
+    assert_eq!(known.client_mac.as_deref(), Some("aa:bb:cc:dd:ee:ff"));
+    assert_eq!(known.group_name.as_deref(), Some("Kids"));
+
+    let unknown = page
+        .queries
+        .iter()
+        .find(|q| q.domain.as_ref() == "unknown.com")
+        .unwrap();
+    assert!(unknown.client_hostname.is_none());
+    assert!(unknown.client_mac.is_none());
+    assert!(unknown.group_name.is_none());
+}
+
+async fn insert_filter_row(
+    pool: &sqlx::SqlitePool,
+    domain: &str,
+    dnssec_status: Option<&str>,
+    query_source: &str,
+    created_at: &str,
+) {
+    sqlx::query(
+        "INSERT INTO query_log (domain, record_type, client_ip, blocked, response_time_ms, cache_hit, dnssec_status, query_source, created_at)
+         VALUES (?, 'A', '10.0.0.1', 0, 100, 0, ?, ?, ?)",
+    )
+    .bind(domain)
+    .bind(dnssec_status)
+    .bind(query_source)
+    .bind(created_at)
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+fn minutes_ago(minutes: i64) -> chrono::DateTime<chrono::Utc> {
+    chrono::Utc::now() - chrono::Duration::minutes(minutes)
+}
+
+fn sql_time(t: chrono::DateTime<chrono::Utc>) -> String {
+    t.format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
+#[tokio::test]
+async fn test_time_range_filter_bounds_results_and_totals() {
+    let pool = create_test_db().await;
+    for (domain, age) in [("old.com", 120), ("mid.com", 60), ("new.com", 5)] {
+        insert_filter_row(&pool, domain, None, "client", &sql_time(minutes_ago(age))).await;
+    }
+    let repo = SqliteQueryLogRepository::new(
+        pool.clone(),
+        pool.clone(),
+        pool.clone(),
+        &DatabaseConfig::default(),
+    );
+
+    let filter = QueryLogFilter {
+        from: Some(minutes_ago(90)),
+        to: Some(minutes_ago(30)),
+        ..Default::default()
+    };
+    let result = repo
+        .get_recent_paged(100, 0, 24.0, None, &filter)
+        .await
+        .unwrap();
+
+    assert_eq!(result.queries.len(), 1);
+    assert_eq!(&*result.queries[0].domain, "mid.com");
+    assert_eq!(result.records_filtered, 1);
+    assert_eq!(result.records_total, 1);
+}
+
+#[tokio::test]
+async fn test_from_filter_overrides_period() {
+    let pool = create_test_db().await;
+    insert_filter_row(
+        &pool,
+        "yesterday.com",
+        None,
+        "client",
+        &sql_time(minutes_ago(30 * 60)),
+    )
+    .await;
+    let repo = SqliteQueryLogRepository::new(
+        pool.clone(),
+        pool.clone(),
+        pool.clone(),
+        &DatabaseConfig::default(),
+    );
+
+    let filter = QueryLogFilter {
+        from: Some(minutes_ago(48 * 60)),
+        ..Default::default()
+    };
+    let result = repo
+        .get_recent_paged(100, 0, 1.0, None, &filter)
+        .await
+        .unwrap();
+
+    assert_eq!(result.queries.len(), 1);
+}
+
+#[tokio::test]
+async fn test_dnssec_and_blocked_filters() {
+    let pool = create_test_db().await;
+    let now = sql_time(minutes_ago(1));
+    insert_filter_row(&pool, "signed.com", Some("Secure"), "client", &now).await;
+    insert_filter_row(&pool, "broken.com", Some("Bogus"), "client", &now).await;
+    insert_filter_row(&pool, "plain.com", None, "client", &now).await;
+    insert_query(&pool, "ads.com", true, false, Some("blocklist"), None).await;
+    let repo = SqliteQueryLogRepository::new(
+        pool.clone(),
+        pool.clone(),
+        pool.clone(),
+        &DatabaseConfig::default(),
+    );
+
+    let bogus = QueryLogFilter {
+        dnssec_status: Some("Bogus"),
+        ..Default::default()
+    };
+    let result = repo
+        .get_recent_paged(100, 0, 24.0, None, &bogus)
+        .await
+        .unwrap();
+    assert_eq!(result.queries.len(), 1);
+    assert_eq!(&*result.queries[0].domain, "broken.com");
+
+    let allowed = QueryLogFilter {
+        blocked: Some(false),
+        ..Default::default()
+    };
+    let result = repo
+        .get_recent_paged(100, 0, 24.0, None, &allowed)
+        .await
+        .unwrap();
+    assert_eq!(result.records_filtered, 3);
+    assert!(result.queries.iter().all(|q| !q.blocked));
+}
+
+#[tokio::test]
+async fn test_query_source_filter_selects_internal_queries() {
+    let pool = create_test_db().await;
+    let now = sql_time(minutes_ago(1));
+    insert_filter_row(&pool, "client.com", None, "client", &now).await;
+    insert_filter_row(&pool, "refresh.com", None, "internal", &now).await;
+    insert_filter_row(&pool, "ds.com", None, "dnssec_validation", &now).await;
+    let repo = SqliteQueryLogRepository::new(
+        pool.clone(),
+        pool.clone(),
+        pool.clone(),
+        &DatabaseConfig::default(),
+    );
+
+    let default = repo
+        .get_recent_paged(100, 0, 24.0, None, &no_filter())
+        .await
+        .unwrap();
+    assert_eq!(default.queries.len(), 1);
+    assert_eq!(&*default.queries[0].domain, "client.com");
+
+    let filter = QueryLogFilter {
+        query_source: Some(QuerySource::Internal),
+        ..Default::default()
+    };
+    let internal = repo
+        .get_recent_paged(100, 0, 24.0, None, &filter)
+        .await
+        .unwrap();
+    assert_eq!(internal.queries.len(), 1);
+    assert_eq!(&*internal.queries[0].domain, "refresh.com");
+    assert_eq!(internal.records_total, 1);
+}
+
+#[tokio::test]
+async fn test_upstream_metadata_round_trip() {
+    let pool = create_test_db().await;
+    let repo = SqliteQueryLogRepository::new(
+        pool.clone(),
+        pool.clone(),
+        pool.clone(),
+        &DatabaseConfig::default(),
+    );
+
+    repo.log_query(&QueryLog {
+        id: None,
+        domain: Arc::from("example.com"),
+        record_type: RecordType::A,
+        client_ip: "192.168.1.10".parse().unwrap(),
+        client_hostname: None,
+        client_mac: None,
+        blocked: false,
+        response_time_us: Some(1_500),
+        cache_hit: false,
+        cache_refresh: false,
+        dnssec_status: None,
+        upstream_server: Some(Arc::from("udp://9.9.9.9:53")),
+        upstream_pool: Some(Arc::from("secondary")),
+        upstream_strategy: Some("failover"),
+        upstream_attempt: Some(2),
+        upstream_protocol: Some("TCP"),
+        response_status: Some("NOERROR"),
+        timestamp: None,
+        query_source: QuerySource::Client,
+        group_id: Some(1),
+        group_name: None,
+        block_source: None,
+        plugin: None,
+    })
+    .await
+    .unwrap();
+
+    let mut queries = Vec::new();
+    for _ in 0..50 {
+        queries = repo
+            .get_recent_paged(10, 0, 24.0, None, &no_filter())
+            .await
+            .unwrap()
+            .queries;
+        if !queries.is_empty() {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    }
+
+    assert_eq!(queries.len(), 1);
+    let q = &queries[0];
+    assert_eq!(q.upstream_pool.as_deref(), Some("secondary"));
+    assert_eq!(q.upstream_strategy, Some("failover"));
+    assert_eq!(q.upstream_attempt, Some(2));
+    assert_eq!(q.upstream_protocol, Some("TCP"));
+}
@@ -0,0 +1,126 @@
+use ferrous_dns_application::ports::QueryMetricsPort;
+use ferrous_dns_domain::{BlockSource, QueryLog, QuerySource, RecordType};
+use ferrous_dns_infrastructure::dns::QueryMetrics;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+fn make_log(record_type: RecordType, response_time_us: u64) -> QueryLog {
+    QueryLog {
+        id: None,
+        domain: "example.com".into(),
+        record_type,
+        client_ip: IpAddr::from([192, 168, 1, 10]),
+        client_hostname: None,
+        client_mac: None,
+        blocked: false,
+        response_time_us: Some(response_time_us),
+        cache_hit: false,
+        cache_refresh: false,
+        dnssec_status: None,
+        upstream_server: Some(Arc::from("dns.google")),
+        upstream_pool: Some(Arc::from("pool1")),
+        upstream_strategy: None,
+        upstream_attempt: None,
+        upstream_protocol: None,
+        response_status: Some("NOERROR"),
+        timestamp: None,
+        query_source: QuerySource::Client,
+        group_id: None,
+        group_name: None,
+        block_source: None,
+        plugin: None,
+    }
+}
+
+#[test]
+fn test_snapshot_counts_recorded_queries() {
+    let metrics = QueryMetrics::new();
+
+    metrics.record(&make_log(RecordType::A, 2_000));
+    metrics.record(&QueryLog {
+        cache_hit: true,
+        upstream_server: None,
+        upstream_pool: None,
+        ..make_log(RecordType::A, 100)
+    });
+    metrics.record(&QueryLog {
+        blocked: true,
+        block_source: Some(BlockSource::Blocklist),
+        upstream_server: None,
+        upstream_pool: None,
+        ..make_log(RecordType::AAAA, 300)
+    });
+
+    let stats = metrics.snapshot();
+    assert_eq!(stats.queries_total, 3);
+    assert_eq!(stats.queries_blocked, 1);
+    assert_eq!(stats.source_stats.get("cache"), Some(&1));
+    assert_eq!(stats.source_stats.get("blocklist"), Some(&1));
+    assert_eq!(stats.source_stats.get("pool1:dns.google"), Some(&1));
+    assert_eq!(stats.queries_by_type.get(&RecordType::A), Some(&2));
+    assert_eq!(stats.most_queried_type, Some(RecordType::A));
+    assert!((stats.avg_query_time_ms - 0.8).abs() < 1e-9);
+    assert!((stats.avg_upstream_time_ms - 2.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_malware_blocks_counted_separately() {
+    let metrics = QueryMetrics::new();
+
+    metrics.record(&QueryLog {
+        blocked: true,
+        block_source: Some(BlockSource::DnsTunneling),
+        ..make_log(RecordType::TXT, 100)
+    });
+
+    let stats = metrics.snapshot();
+    assert_eq!(stats.queries_malware_detected, 1);
+    assert_eq!(stats.source_stats.get("dns_tunneling"), Some(&1));
+}
+
+#[test]
+fn test_internal_and_untimed_queries_ignored() {
+    let metrics = QueryMetrics::new();
+
+    metrics.record(&QueryLog {
+        query_source: QuerySource::Internal,
+        ..make_log(RecordType::A, 100)
+    });
+    metrics.record(&QueryLog {
+        response_time_us: None,
+        ..make_log(RecordType::A, 0)
+    });
+
+    assert_eq!(metrics.snapshot().queries_total, 0);
+}
+
+#[test]
+fn test_clones_share_counters() {
+    let metrics = QueryMetrics::new();
+    let reader = metrics.clone();
+
+    metrics.record(&make_log(RecordType::A, 100));
+
+    assert_eq!(reader.total_queries(), 1);
+    reader.reset();
+    assert_eq!(metrics.snapshot().queries_total, 0);
+}
+
+#[test]
+fn test_minimized_any_queries_counted_separately() {
+    let metrics = QueryMetrics::new();
+
+    metrics.record(&QueryLog {
+        response_status: Some("ANY_MINIMIZED"),
+        upstream_server: None,
+        upstream_pool: None,
+        ..make_log(RecordType::ANY, 50)
+    });
+    metrics.record(&make_log(RecordType::A, 2_000));
+
+    let stats = metrics.snapshot();
+    assert_eq!(stats.queries_total, 2);
+    assert_eq!(stats.queries_any_minimized, 1);
+    assert_eq!(stats.source_stats.get("any_minimized"), Some(&1));
+    assert_eq!(stats.source_stats.get("pool1:dns.google"), Some(&1));
+}
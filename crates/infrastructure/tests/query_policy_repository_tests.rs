@@ -0,0 +1,126 @@
+use ferrous_dns_application::ports::QueryPolicyRepository;
+use ferrous_dns_domain::{DomainError, PolicyAction, QueryPolicy, RecordType};
+use ferrous_dns_infrastructure::repositories::query_policy_repository::SqliteQueryPolicyRepository;
+use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
+use std::sync::Arc;
+
+async fn create_test_db() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS query_policies (
+            id             INTEGER PRIMARY KEY AUTOINCREMENT,
+            name           TEXT    NOT NULL,
+            priority       INTEGER NOT NULL DEFAULT 100,
+            enabled        BOOLEAN NOT NULL DEFAULT 1,
+            client_cidr    TEXT,
+            group_id       INTEGER,
+            domain_pattern TEXT,
+            record_types   TEXT,
+            days           INTEGER,
+            start_time     TEXT,
+            end_time       TEXT,
+            action         TEXT    NOT NULL,
+            action_target  TEXT,
+            comment        TEXT,
+            created_at     DATETIME DEFAULT CURRENT_TIMESTAMP,
+            updated_at     DATETIME DEFAULT CURRENT_TIMESTAMP
+        )",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    pool
+}
+
+fn new_policy(name: &str, priority: i64, action: PolicyAction) -> QueryPolicy {
+    let mut p = QueryPolicy::new(Arc::from(name), action);
+    p.priority = priority;
+    p
+}
+
+#[tokio::test]
+async fn test_create_round_trips_all_fields() {
+    let repo = SqliteQueryPolicyRepository::new(create_test_db().await);
+
+    let mut p = new_policy("iot-dns", 5, PolicyAction::ForwardToPool);
+    p.client_cidr = Some(Arc::from("192.168.30.0/24"));
+    p.domain_pattern = Some(Arc::from("*.vendor.com"));
+    p.record_types = vec![RecordType::A, RecordType::AAAA];
+    p.days = Some(31);
+    p.start_time = Some(Arc::from("08:00"));
+    p.end_time = Some(Arc::from("17:00"));
+    p.action_target = Some(Arc::from("secure"));
+
+    let created = repo.create(&p).await.unwrap();
+    assert!(created.id.is_some());
+
+    let fetched = repo.get_by_id(created.id.unwrap()).await.unwrap().unwrap();
+    assert_eq!(fetched.priority, 5);
+    assert_eq!(fetched.action, PolicyAction::ForwardToPool);
+    assert_eq!(fetched.action_target.as_deref(), Some("secure"));
+    assert_eq!(fetched.record_types, vec![RecordType::A, RecordType::AAAA]);
+    assert_eq!(fetched.domain_pattern.as_deref(), Some("*.vendor.com"));
+    assert_eq!(fetched.days, Some(31));
+}
+
+#[tokio::test]
+async fn test_get_all_orders_by_priority_then_id() {
+    let repo = SqliteQueryPolicyRepository::new(create_test_db().await);
+
+    repo.create(&new_policy("late", 200, PolicyAction::Block))
+        .await
+        .unwrap();
+    repo.create(&new_policy("first", 10, PolicyAction::Allow))
+        .await
+        .unwrap();
+    repo.create(&new_policy("second", 10, PolicyAction::LogOnly))
+        .await
+        .unwrap();
+
+    let names: Vec<String> = repo
+        .get_all()
+        .await
+        .unwrap()
+        .iter()
+        .map(|p| p.name.to_string())
+        .collect();
+    assert_eq!(names, vec!["first", "second", "late"]);
+}
+
+#[tokio::test]
+async fn test_update_replaces_fields() {
+    let repo = SqliteQueryPolicyRepository::new(create_test_db().await);
+
+    let mut created = repo
+        .create(&new_policy("p", 100, PolicyAction::Block))
+        .await
+        .unwrap();
+    created.action = PolicyAction::Rewrite;
+    created.action_target = Some(Arc::from("safe.example.com"));
+    created.enabled = false;
+
+    let updated = repo.update(&created).await.unwrap();
+    assert_eq!(updated.action, PolicyAction::Rewrite);
+    assert!(!updated.enabled);
+}
+
+#[tokio::test]
+async fn test_update_and_delete_unknown_id_return_not_found() {
+    let repo = SqliteQueryPolicyRepository::new(create_test_db().await);
+
+    let mut p = new_policy("ghost", 100, PolicyAction::Block);
+    p.id = Some(42);
+    assert!(matches!(
+        repo.update(&p).await,
+        Err(DomainError::QueryPolicyNotFound(42))
+    ));
+    assert!(matches!(
+        repo.delete(42).await,
+        Err(DomainError::QueryPolicyNotFound(42))
+    ));
+}
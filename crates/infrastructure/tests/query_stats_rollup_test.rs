@@ -0,0 +1,189 @@
+use chrono::Utc;
+use ferrous_dns_application::ports::QueryLogRepository;
+use ferrous_dns_domain::config::{DatabaseConfig, QueryLogPartitioning};
+use ferrous_dns_domain::{QueryLog, QuerySource, RecordType};
+use ferrous_dns_infrastructure::database::create_write_pool;
+use ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository;
+use sqlx::SqlitePool;
+use std::net::IpAddr;
+use std::time::Duration;
+use tempfile::TempDir;
+
+async fn create_file_db() -> (TempDir, SqlitePool) {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("ferrous.db");
+    let url = format!("sqlite:{}", path.display());
+    let pool = create_write_pool(&url, &DatabaseConfig::default())
+        .await
+        .unwrap();
+    (dir, pool)
+}
+
+fn repository(pool: &SqlitePool) -> SqliteQueryLogRepository {
+    let cfg = DatabaseConfig {
+        query_log_partitioning: QueryLogPartitioning::None,
+        ..DatabaseConfig::default()
+    };
+    SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &cfg)
+}
+
+fn make_log(response_time_us: u64) -> QueryLog {
+    QueryLog {
+        id: None,
+        domain: "example.com".into(),
+        record_type: RecordType::A,
+        client_ip: IpAddr::from([192, 168, 1, 10]),
+        client_hostname: None,
+        client_mac: None,
+        blocked: false,
+        response_time_us: Some(response_time_us),
+        cache_hit: false,
+        cache_refresh: false,
+        dnssec_status: None,
+        upstream_server: None,
+        upstream_pool: None,
+        upstream_strategy: None,
+        upstream_attempt: None,
+        upstream_protocol: None,
+        response_status: Some("NOERROR"),
+        timestamp: None,
+        query_source: QuerySource::Client,
+        group_id: None,
+        group_name: None,
+        block_source: None,
+        plugin: None,
+    }
+}
+
+async fn rolled_up_queries(pool: &SqlitePool) -> i64 {
+    sqlx::query_scalar("SELECT COALESCE(SUM(queries), 0) FROM query_stats_rollup")
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+/// Waits for the batched writer to fold `expected` queries into the rollups.
+async fn wait_for_rollup(pool: &SqlitePool, expected: i64) {
+    for _ in 0..50 {
+        if rolled_up_queries(pool).await >= expected {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    panic!("rollups never reached {expected} queries");
+}
+
+#[tokio::test]
+async fn test_writer_rolls_up_flushed_queries() {
+    let (_dir, pool) = create_file_db().await;
+    let repo = repository(&pool);
+
+    let cached = QueryLog {
+        cache_hit: true,
+        ..make_log(50)
+    };
+    let blocked = QueryLog {
+        blocked: true,
+        ..make_log(150)
+    };
+    repo.log_query(&make_log(1_000)).await.unwrap();
+    repo.log_query(&cached).await.unwrap();
+    repo.log_query(&blocked).await.unwrap();
+    wait_for_rollup(&pool, 3).await;
+
+    let (cache_hits, blocked, upstream): (i64, i64, i64) = sqlx::query_as(
+        "SELECT SUM(cache_hits), SUM(blocked), SUM(upstream_queries) FROM query_stats_rollup",
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!((cache_hits, blocked, upstream), (1, 1, 1));
+}
+
+#[tokio::test]
+async fn test_stats_report_latency_percentiles() {
+    let (_dir, pool) = create_file_db().await;
+    let repo = repository(&pool);
+
+    for i in 1..=100 {
+        repo.log_query(&make_log(i * 1_000)).await.unwrap();
+    }
+    wait_for_rollup(&pool, 100).await;
+
+    let stats = repo.get_stats(24.0).await.unwrap();
+
+    assert_eq!(stats.queries_total, 100);
+    assert!((stats.avg_query_time_ms - 50.5).abs() < 0.01);
+    // Histogram buckets are within ~3% of the values they hold.
+    assert!((stats.latency_p50_ms - 50.0).abs() <= 1.6);
+    assert!((stats.latency_p95_ms - 95.0).abs() <= 3.0);
+    assert!((stats.latency_p99_ms - 99.0).abs() <= 3.1);
+}
+
+#[tokio::test]
+async fn test_stats_include_rows_above_rollup_watermark() {
+    let (_dir, pool) = create_file_db().await;
+    let repo = repository(&pool);
+    repo.log_query(&make_log(2_000)).await.unwrap();
+    wait_for_rollup(&pool, 1).await;
+
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    sqlx::query(
+        "INSERT INTO query_log (domain, record_type, client_ip, response_time_ms, response_status, created_at)
+         VALUES ('direct.example.com', 'A', '192.168.1.10', 4000, 'NOERROR', ?)",
+    )
+    .bind(&now)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let stats = repo.get_stats(24.0).await.unwrap();
+
+    assert_eq!(stats.queries_total, 2);
+    assert!((stats.avg_query_time_ms - 3.0).abs() < 0.01);
+    assert_eq!(rolled_up_queries(&pool).await, 1);
+}
+
+#[tokio::test]
+async fn test_rollups_rebuilt_from_existing_query_log() {
+    let (_dir, pool) = create_file_db().await;
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    for time in [1_000, 3_000] {
+        sqlx::query(
+            "INSERT INTO query_log (domain, record_type, client_ip, response_time_ms, response_status, created_at)
+             VALUES ('legacy.example.com', 'A', '192.168.1.10', ?, 'NOERROR', ?)",
+        )
+        .bind(time)
+        .bind(&now)
+        .execute(&pool)
+        .await
+        .unwrap();
+    }
+
+    let repo = repository(&pool);
+    wait_for_rollup(&pool, 2).await;
+
+    let stats = repo.get_stats(24.0).await.unwrap();
+    assert_eq!(stats.queries_total, 2);
+    assert!((stats.avg_query_time_ms - 2.0).abs() < 0.01);
+}
+
+#[tokio::test]
+async fn test_retention_deletes_rollups() {
+    let (_dir, pool) = create_file_db().await;
+    sqlx::query(
+        "INSERT INTO query_stats_rollup (bucket, queries) VALUES ('2020-01-08 12:00:00', 5)",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    let repo = repository(&pool);
+    repo.log_query(&make_log(1_000)).await.unwrap();
+    wait_for_rollup(&pool, 6).await;
+
+    repo.delete_older_than(30).await.unwrap();
+    assert_eq!(rolled_up_queries(&pool).await, 1);
+
+    repo.delete_older_than(0).await.unwrap();
+    assert_eq!(rolled_up_queries(&pool).await, 0);
+}
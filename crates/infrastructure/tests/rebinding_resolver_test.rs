@@ -0,0 +1,250 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{DnsResolution, DnsResolver};
+use ferrous_dns_domain::{DnsQuery, DomainError, RecordType};
+use ferrous_dns_infrastructure::dns::resolver::{
+    CachedResolver, RebindingPolicy, RebindingResolver,
+};
+use ferrous_dns_infrastructure::dns::{
+    DnsCache, DnsCacheAccess, DnsCacheConfig, EvictionStrategy, NegativeQueryTracker,
+};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+struct FixedResolver {
+    resolution: DnsResolution,
+    calls: AtomicUsize,
+}
+
+impl FixedResolver {
+    fn new(resolution: DnsResolution) -> Arc<Self> {
+        Arc::new(Self {
+            resolution,
+            calls: AtomicUsize::new(0),
+        })
+    }
+}
+
+#[async_trait]
+impl DnsResolver for FixedResolver {
+    async fn resolve(&self, _query: &DnsQuery) -> Result<DnsResolution, DomainError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        Ok(self.resolution.clone())
+    }
+}
+
+fn resolution_with_ip(ip: IpAddr) -> DnsResolution {
+    DnsResolution::new(vec![ip], false)
+}
+
+fn query(domain: &str) -> DnsQuery {
+    DnsQuery::new(Arc::from(domain), RecordType::A)
+}
+
+async fn resolve_with(
+    domain: &str,
+    ip: IpAddr,
+    local_domain: Option<&str>,
+    allowlist: &[String],
+) -> Result<DnsResolution, DomainError> {
+    let inner = FixedResolver::new(resolution_with_ip(ip));
+    RebindingResolver::new(inner, RebindingPolicy::new(local_domain, allowlist))
+        .resolve(&query(domain))
+        .await
+}
+
+fn make_cache() -> Arc<DnsCache> {
+    Arc::new(DnsCache::new(DnsCacheConfig {
+        max_entries: 1000,
+        eviction_strategy: EvictionStrategy::LRU,
+        min_threshold: 2.0,
+        refresh_threshold: 0.75,
+        batch_eviction_percentage: 0.2,
+        adaptive_thresholds: false,
+        min_frequency: 0,
+        min_lfuk_score: 0.0,
+        shard_amount: 4,
+        access_window_secs: 7200,
+        eviction_sample_size: 8,
+        lfuk_k_value: 0.5,
+        refresh_sample_rate: 1.0,
+        min_ttl: 0,
+        max_ttl: 86_400,
+        l1_capacity: 1024,
+    }))
+}
+
+#[tokio::test]
+async fn test_public_domain_resolves_to_private_ip_is_blocked() {
+    let result = resolve_with("evil.com", Ipv4Addr::new(192, 168, 1, 1).into(), None, &[]).await;
+
+    assert!(matches!(result, Err(DomainError::DnsRebindingBlocked)));
+}
+
+#[tokio::test]
+async fn test_public_domain_resolves_to_loopback_is_blocked() {
+    let result = resolve_with("evil.com", Ipv4Addr::new(127, 0, 0, 1).into(), None, &[]).await;
+
+    assert!(matches!(result, Err(DomainError::DnsRebindingBlocked)));
+}
+
+#[tokio::test]
+async fn test_public_domain_resolves_to_link_local_is_blocked() {
+    let result = resolve_with("evil.com", Ipv4Addr::new(169, 254, 1, 1).into(), None, &[]).await;
+
+    assert!(matches!(result, Err(DomainError::DnsRebindingBlocked)));
+}
+
+#[tokio::test]
+async fn test_public_domain_resolves_to_10_range_is_blocked() {
+    let result = resolve_with("evil.com", Ipv4Addr::new(10, 0, 0, 1).into(), None, &[]).await;
+
+    assert!(matches!(result, Err(DomainError::DnsRebindingBlocked)));
+}
+
+#[tokio::test]
+async fn test_public_domain_resolves_to_172_16_range_is_blocked() {
+    let result = resolve_with("evil.com", Ipv4Addr::new(172, 20, 0, 1).into(), None, &[]).await;
+
+    assert!(matches!(result, Err(DomainError::DnsRebindingBlocked)));
+}
+
+#[tokio::test]
+async fn test_public_domain_resolves_to_ipv6_loopback_is_blocked() {
+    let result = resolve_with("evil.com", Ipv6Addr::LOCALHOST.into(), None, &[]).await;
+
+    assert!(matches!(result, Err(DomainError::DnsRebindingBlocked)));
+}
+
+#[tokio::test]
+async fn test_public_domain_resolves_to_public_ip_is_allowed() {
+    let result = resolve_with(
+        "example.com",
+        Ipv4Addr::new(93, 184, 216, 34).into(),
+        None,
+        &[],
+    )
+    .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_local_domain_resolves_to_private_ip_is_allowed() {
+    let result = resolve_with(
+        "server.local",
+        Ipv4Addr::new(192, 168, 1, 10).into(),
+        Some("local"),
+        &[],
+    )
+    .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_local_domain_suffix_match_is_case_insensitive() {
+    let result = resolve_with(
+        "Server.LOCAL",
+        Ipv4Addr::new(192, 168, 1, 10).into(),
+        Some("local"),
+        &[],
+    )
+    .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_allowlisted_domain_resolves_to_private_ip_is_allowed() {
+    let allowlist = vec!["myrouter.corp".to_string()];
+    let result = resolve_with(
+        "myrouter.corp",
+        Ipv4Addr::new(10, 0, 0, 1).into(),
+        None,
+        &allowlist,
+    )
+    .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_allowlist_match_is_case_insensitive() {
+    let allowlist = vec!["myrouter.corp".to_string()];
+    let result = resolve_with(
+        "MyRouter.Corp",
+        Ipv4Addr::new(10, 0, 0, 1).into(),
+        None,
+        &allowlist,
+    )
+    .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_allowlisted_internal_domain_exempts_subdomains() {
+    let allowlist = vec!["corp.example.com".to_string()];
+    let result = resolve_with(
+        "wiki.corp.example.com",
+        Ipv4Addr::new(10, 1, 2, 3).into(),
+        None,
+        &allowlist,
+    )
+    .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_allowlist_does_not_match_on_partial_label() {
+    let allowlist = vec!["corp.example.com".to_string()];
+    let result = resolve_with(
+        "evilcorp.example.com",
+        Ipv4Addr::new(10, 1, 2, 3).into(),
+        None,
+        &allowlist,
+    )
+    .await;
+
+    assert!(matches!(result, Err(DomainError::DnsRebindingBlocked)));
+}
+
+#[tokio::test]
+async fn test_local_dns_resolution_is_exempt() {
+    let mut resolution = resolution_with_ip(Ipv4Addr::new(10, 10, 0, 5).into());
+    resolution.local_dns = true;
+    let resolver = RebindingResolver::new(
+        FixedResolver::new(resolution),
+        RebindingPolicy::new(None, &[]),
+    );
+
+    let result = resolver.resolve(&query("internal.example")).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_blocked_answer_is_not_cached() {
+    let inner = FixedResolver::new(resolution_with_ip(Ipv4Addr::new(192, 168, 1, 1).into()));
+    let rebinding: Arc<dyn DnsResolver> = Arc::new(RebindingResolver::new(
+        Arc::clone(&inner) as Arc<dyn DnsResolver>,
+        RebindingPolicy::new(None, &[]),
+    ));
+    let cached = CachedResolver::new(
+        rebinding,
+        make_cache() as Arc<dyn DnsCacheAccess>,
+        300,
+        Arc::new(NegativeQueryTracker::new()),
+        4,
+    );
+
+    for _ in 0..2 {
+        let result = cached.resolve(&query("evil.com")).await;
+        assert!(matches!(result, Err(DomainError::DnsRebindingBlocked)));
+    }
+
+    assert_eq!(inner.calls.load(Ordering::SeqCst), 2);
+    assert!(cached.try_cache(&query("evil.com")).is_none());
+}
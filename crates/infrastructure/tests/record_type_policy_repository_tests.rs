@@ -0,0 +1,92 @@
+use ferrous_dns_application::ports::{RecordTypeFilterPort, RecordTypePolicyRepository};
+use ferrous_dns_domain::{RecordType, RecordTypeFilterMode, RecordTypePolicy};
+use ferrous_dns_infrastructure::dns::RecordTypeEnforcer;
+use ferrous_dns_infrastructure::repositories::record_type_policy_repository::SqliteRecordTypePolicyRepository;
+use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
+use std::sync::Arc;
+
+async fn create_test_db() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS group_record_type_policies (
+            id           INTEGER PRIMARY KEY AUTOINCREMENT,
+            group_id     INTEGER NOT NULL UNIQUE,
+            mode         TEXT    NOT NULL CHECK(mode IN ('allow','deny')),
+            record_types TEXT    NOT NULL,
+            created_at   TEXT    NOT NULL,
+            updated_at   TEXT    NOT NULL
+        )",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    pool
+}
+
+#[tokio::test]
+async fn test_upsert_replaces_existing_group_policy() {
+    let repo = SqliteRecordTypePolicyRepository::new(create_test_db().await);
+
+    repo.upsert(&RecordTypePolicy::new(
+        2,
+        RecordTypeFilterMode::Deny,
+        vec![RecordType::HTTPS],
+    ))
+    .await
+    .unwrap();
+    let updated = repo
+        .upsert(&RecordTypePolicy::new(
+            2,
+            RecordTypeFilterMode::Allow,
+            vec![RecordType::A, RecordType::AAAA],
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(updated.mode, RecordTypeFilterMode::Allow);
+    assert_eq!(updated.record_types, vec![RecordType::A, RecordType::AAAA]);
+    assert_eq!(repo.get_all().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_delete_by_group_removes_policy() {
+    let repo = SqliteRecordTypePolicyRepository::new(create_test_db().await);
+
+    repo.upsert(&RecordTypePolicy::new(
+        3,
+        RecordTypeFilterMode::Deny,
+        vec![RecordType::SVCB],
+    ))
+    .await
+    .unwrap();
+    repo.delete_by_group(3).await.unwrap();
+
+    assert!(repo.get_by_group(3).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_enforcer_applies_policy_per_group_after_reload() {
+    let repo = Arc::new(SqliteRecordTypePolicyRepository::new(
+        create_test_db().await,
+    ));
+    let enforcer = RecordTypeEnforcer::new(repo.clone()).await.unwrap();
+    assert!(!enforcer.is_blocked(2, RecordType::HTTPS));
+
+    repo.upsert(&RecordTypePolicy::new(
+        2,
+        RecordTypeFilterMode::Deny,
+        vec![RecordType::HTTPS],
+    ))
+    .await
+    .unwrap();
+    enforcer.reload().await.unwrap();
+
+    assert!(enforcer.is_blocked(2, RecordType::HTTPS));
+    assert!(!enforcer.is_blocked(2, RecordType::A));
+    assert!(!enforcer.is_blocked(1, RecordType::HTTPS));
+}
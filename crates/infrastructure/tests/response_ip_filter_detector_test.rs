@@ -150,6 +150,62 @@ fn blocked_ip_count_tracks_set_size() {
     assert_eq!(detector.blocked_ip_count(), 2);
 }
 
+// ── CIDR ranges ──────────────────────────────────────────────────────────────
+
+#[test]
+fn ip_inside_blocked_network_is_detected() {
+    let detector = ResponseIpFilterDetector::new(&test_config());
+    detector.insert_network("198.51.100.0/24".parse().unwrap(), coarse_now_ns());
+
+    assert!(detector.is_blocked_ip(&"198.51.100.77".parse().unwrap()));
+    assert!(!detector.is_blocked_ip(&"198.51.101.1".parse().unwrap()));
+    assert_eq!(detector.blocked_ip_count(), 1);
+}
+
+#[test]
+fn ipv6_network_is_detected() {
+    let detector = ResponseIpFilterDetector::new(&test_config());
+    detector.insert_network("2001:db8:abcd::/48".parse().unwrap(), coarse_now_ns());
+
+    assert!(detector.is_blocked_ip(&"2001:db8:abcd:12::1".parse().unwrap()));
+    assert!(!detector.is_blocked_ip(&"2001:db8:abce::1".parse().unwrap()));
+}
+
+#[test]
+fn single_host_network_is_stored_as_ip() {
+    let detector = ResponseIpFilterDetector::new(&test_config());
+    let inserted = detector.insert_network("203.0.113.5/32".parse().unwrap(), coarse_now_ns());
+
+    assert!(inserted);
+    assert!(detector
+        .blocked_ips
+        .contains(&"203.0.113.5".parse().unwrap()));
+}
+
+#[test]
+fn unnormalized_network_is_deduplicated() {
+    let detector = ResponseIpFilterDetector::new(&test_config());
+    let now = coarse_now_ns();
+
+    assert!(detector.insert_network("10.1.0.0/16".parse().unwrap(), now));
+    assert!(!detector.insert_network("10.1.2.3/16".parse().unwrap(), now));
+    assert_eq!(detector.blocked_ip_count(), 1);
+}
+
+#[test]
+fn stale_networks_are_evicted() {
+    let detector = ResponseIpFilterDetector::new(&test_config());
+    let stale_ns = coarse_now_ns() - 10_000_000_000;
+    detector.insert_network("198.51.100.0/24".parse().unwrap(), stale_ns);
+    detector.insert_network("192.0.2.0/24".parse().unwrap(), coarse_now_ns());
+
+    detector.evict_stale_ips();
+
+    assert!(!detector.is_blocked_ip(&"198.51.100.1".parse().unwrap()));
+    assert!(detector.is_blocked_ip(&"192.0.2.1".parse().unwrap()));
+    assert_eq!(detector.blocked_ip_count(), 1);
+}
+
 // ── IP list parsing ──────────────────────────────────────────────────────────
 
 #[test]
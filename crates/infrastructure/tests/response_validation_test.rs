@@ -0,0 +1,166 @@
+use bytes::Bytes;
+use ferrous_dns_domain::RecordType;
+use ferrous_dns_infrastructure::dns::forwarding::{
+    MessageBuilder, ResponseParser, RESPONSE_VALIDATION,
+};
+use hickory_proto::op::{Message, MessageType, OpCode, ResponseCode};
+use hickory_proto::rr::rdata::{A, CNAME};
+use hickory_proto::rr::{Name, RData, Record};
+use std::net::{IpAddr, Ipv4Addr};
+
+fn name(s: &str) -> Name {
+    Name::from_ascii(s).unwrap()
+}
+
+fn a_record(owner: &str, ip: [u8; 4]) -> Record {
+    Record::from_rdata(
+        name(owner),
+        300,
+        RData::A(A(Ipv4Addr::new(ip[0], ip[1], ip[2], ip[3]))),
+    )
+}
+
+fn cname_record(owner: &str, target: &str) -> Record {
+    Record::from_rdata(name(owner), 300, RData::CNAME(CNAME(name(target))))
+}
+
+fn response_to(query_bytes: &[u8], answers: Vec<Record>) -> Vec<u8> {
+    let query = Message::from_vec(query_bytes).unwrap();
+    let mut response = Message::new(query.id(), MessageType::Response, OpCode::Query);
+    response.add_query(query.queries()[0].clone());
+    for answer in answers {
+        response.add_answer(answer);
+    }
+    response.to_vec().unwrap()
+}
+
+#[test]
+fn test_question_matches_same_query() {
+    let query = MessageBuilder::build_query("example.com", &RecordType::A, false).unwrap();
+    let response = response_to(&query, vec![]);
+    assert!(ResponseParser::question_matches(&query, &response));
+}
+
+#[test]
+fn test_question_matches_ignores_case() {
+    let query = MessageBuilder::build_query("example.com", &RecordType::A, false).unwrap();
+    let other = MessageBuilder::build_query("EXAMPLE.com", &RecordType::A, false).unwrap();
+    let response = response_to(&other, vec![]);
+    assert!(ResponseParser::question_matches(&query, &response));
+}
+
+#[test]
+fn test_question_rejects_different_name() {
+    let query = MessageBuilder::build_query("example.com", &RecordType::A, false).unwrap();
+    let other = MessageBuilder::build_query("attacker.com", &RecordType::A, false).unwrap();
+    let response = response_to(&other, vec![]);
+    assert!(!ResponseParser::question_matches(&query, &response));
+}
+
+#[test]
+fn test_question_rejects_different_type() {
+    let query = MessageBuilder::build_query("example.com", &RecordType::A, false).unwrap();
+    let other = MessageBuilder::build_query("example.com", &RecordType::AAAA, false).unwrap();
+    let response = response_to(&other, vec![]);
+    assert!(!ResponseParser::question_matches(&query, &response));
+}
+
+#[test]
+fn test_question_rejects_missing_question_on_noerror() {
+    let query = MessageBuilder::build_query("example.com", &RecordType::A, false).unwrap();
+    let response = Message::new(1, MessageType::Response, OpCode::Query)
+        .to_vec()
+        .unwrap();
+    assert!(!ResponseParser::question_matches(&query, &response));
+}
+
+#[test]
+fn test_question_accepts_error_without_question() {
+    let query = MessageBuilder::build_query("example.com", &RecordType::A, false).unwrap();
+    let response = Message::error_msg(1, OpCode::Query, ResponseCode::Refused)
+        .to_vec()
+        .unwrap();
+    assert!(ResponseParser::question_matches(&query, &response));
+}
+
+#[test]
+fn test_in_bailiwick_answers_are_kept() {
+    let query = MessageBuilder::build_query("www.example.com", &RecordType::A, false).unwrap();
+    let bytes = response_to(
+        &query,
+        vec![
+            cname_record("www.example.com.", "cdn.example.net."),
+            a_record("cdn.example.net.", [192, 0, 2, 1]),
+        ],
+    );
+
+    let parsed = ResponseParser::parse_bytes(Bytes::from(bytes.clone())).unwrap();
+
+    assert_eq!(
+        parsed.addresses,
+        vec![IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1))]
+    );
+    assert_eq!(parsed.raw_bytes.as_ref(), bytes.as_slice());
+}
+
+#[test]
+fn test_out_of_order_cname_chain_is_kept() {
+    let query = MessageBuilder::build_query("www.example.com", &RecordType::A, false).unwrap();
+    let bytes = response_to(
+        &query,
+        vec![
+            a_record("edge.example.org.", [192, 0, 2, 7]),
+            cname_record("cdn.example.net.", "edge.example.org."),
+            cname_record("www.example.com.", "cdn.example.net."),
+        ],
+    );
+
+    let parsed = ResponseParser::parse_bytes(Bytes::from(bytes)).unwrap();
+
+    assert_eq!(
+        parsed.addresses,
+        vec![IpAddr::V4(Ipv4Addr::new(192, 0, 2, 7))]
+    );
+    assert_eq!(parsed.cname_chain.len(), 2);
+}
+
+#[test]
+fn test_out_of_bailiwick_answers_are_dropped() {
+    let before = RESPONSE_VALIDATION.snapshot().out_of_bailiwick_records;
+    let query = MessageBuilder::build_query("www.example.com", &RecordType::A, false).unwrap();
+    let bytes = response_to(
+        &query,
+        vec![
+            a_record("www.example.com.", [192, 0, 2, 1]),
+            a_record("bank.example.", [203, 0, 113, 66]),
+        ],
+    );
+
+    let parsed = ResponseParser::parse_bytes(Bytes::from(bytes)).unwrap();
+
+    assert_eq!(
+        parsed.addresses,
+        vec![IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1))]
+    );
+    let reparsed = Message::from_vec(&parsed.raw_bytes).unwrap();
+    assert_eq!(reparsed.answers().len(), 1);
+    assert_eq!(reparsed.answers()[0].name(), &name("www.example.com."));
+    assert!(RESPONSE_VALIDATION.snapshot().out_of_bailiwick_records > before);
+}
+
+#[test]
+fn test_cname_from_foreign_owner_does_not_extend_chain() {
+    let query = MessageBuilder::build_query("www.example.com", &RecordType::A, false).unwrap();
+    let bytes = response_to(
+        &query,
+        vec![
+            cname_record("other.example.", "evil.example."),
+            a_record("evil.example.", [203, 0, 113, 66]),
+        ],
+    );
+
+    let parsed = ResponseParser::parse_bytes(Bytes::from(bytes)).unwrap();
+
+    assert!(parsed.addresses.is_empty());
+    assert!(parsed.cname_chain.is_empty());
+}
@@ -21,6 +21,9 @@ async fn create_test_db() -> SqlitePool {
             enabled    INTEGER NOT NULL DEFAULT 1,
             comment    TEXT,
             is_default INTEGER NOT NULL DEFAULT 0,
+            filter_aaaa INTEGER NOT NULL DEFAULT 0,
+            audit_mode  INTEGER NOT NULL DEFAULT 0,
+            tenant_id INTEGER,
             created_at TEXT,
             updated_at TEXT
         )",
@@ -0,0 +1,283 @@
+use ferrous_dns_application::ports::{SecondaryZonePort, SecondaryZoneRepository};
+use ferrous_dns_domain::{RecordType, SecondaryZone, SecondaryZoneConfig};
+use ferrous_dns_infrastructure::dns::forwarding::TsigKeyring;
+use ferrous_dns_infrastructure::dns::secondary_zone::{apply_transfer, TransferOutcome};
+use ferrous_dns_infrastructure::dns::SecondaryZoneStore;
+use ferrous_dns_infrastructure::repositories::SqliteSecondaryZoneRepository;
+use hickory_proto::op::{Message, ResponseCode};
+use hickory_proto::rr::rdata::{A, CNAME, NS, SOA};
+use hickory_proto::rr::{Name, RData, Record};
+use hickory_proto::serialize::binary::BinEncodable;
+use sqlx::sqlite::SqlitePoolOptions;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const ZONE: &str = "corp.internal";
+
+fn name(s: &str) -> Name {
+    Name::from_str(s).unwrap()
+}
+
+fn soa(serial: u32) -> Record {
+    Record::from_rdata(
+        name("corp.internal."),
+        3600,
+        RData::SOA(SOA::new(
+            name("ns1.corp.internal."),
+            name("hostmaster.corp.internal."),
+            serial,
+            3600,
+            600,
+            86400,
+            300,
+        )),
+    )
+}
+
+fn a(owner: &str, ip: &str) -> Record {
+    Record::from_rdata(name(owner), 300, RData::A(A::from_str(ip).unwrap()))
+}
+
+fn zone_records() -> Vec<Record> {
+    vec![
+        soa(10),
+        a("nas.corp.internal.", "10.0.0.10"),
+        a("a.b.corp.internal.", "10.0.0.11"),
+        a("*.lab.corp.internal.", "10.0.0.12"),
+        Record::from_rdata(
+            name("files.corp.internal."),
+            300,
+            RData::CNAME(CNAME(name("nas.corp.internal."))),
+        ),
+        Record::from_rdata(
+            name("dev.corp.internal."),
+            300,
+            RData::NS(NS(name("ns.dev.corp.internal."))),
+        ),
+    ]
+}
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64
+}
+
+async fn create_repo() -> Arc<SqliteSecondaryZoneRepository> {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    sqlx::query(include_str!(
+        "../../../migrations/20260319000001_create_secondary_zones.sql"
+    ))
+    .execute(&pool)
+    .await
+    .unwrap();
+    Arc::new(SqliteSecondaryZoneRepository::new(pool))
+}
+
+fn snapshot(records: &[Record], checked_at: i64) -> SecondaryZone {
+    SecondaryZone {
+        zone: Arc::from(ZONE),
+        serial: 10,
+        refresh: 3600,
+        retry: 600,
+        expire: 86400,
+        transferred_at: checked_at,
+        checked_at,
+        records: records.iter().map(|r| r.to_bytes().unwrap()).collect(),
+    }
+}
+
+async fn store_with(records: &[Record], checked_at: i64) -> Arc<SecondaryZoneStore> {
+    let repo = create_repo().await;
+    repo.save(&snapshot(records, checked_at)).await.unwrap();
+    let config = SecondaryZoneConfig {
+        zone: ZONE.to_string(),
+        primaries: vec!["127.0.0.1".to_string()],
+        tsig_key: None,
+        ixfr: true,
+    };
+    SecondaryZoneStore::new(
+        &[config],
+        &TsigKeyring::default(),
+        repo as Arc<dyn SecondaryZoneRepository>,
+        Duration::from_secs(1),
+    )
+    .await
+    .unwrap()
+}
+
+fn answer(store: &SecondaryZoneStore, domain: &str, record_type: RecordType) -> Message {
+    let resolution = store.lookup(domain, record_type).expect("zone answer");
+    assert!(resolution.local_dns);
+    Message::from_vec(&resolution.upstream_wire_data.unwrap()).unwrap()
+}
+
+// ── Transfers ────────────────────────────────────────────────────────────────
+
+#[test]
+fn axfr_drops_the_closing_soa() {
+    let mut answers = zone_records();
+    answers.push(soa(10));
+
+    match apply_transfer(&name("corp.internal."), None, answers).unwrap() {
+        TransferOutcome::Updated {
+            records,
+            incremental,
+        } => {
+            assert!(!incremental);
+            assert_eq!(records, zone_records());
+        }
+        other => panic!("unexpected outcome: {other:?}"),
+    }
+}
+
+#[test]
+fn axfr_without_closing_soa_is_rejected() {
+    assert!(apply_transfer(&name("corp.internal."), None, zone_records()).is_err());
+}
+
+#[test]
+fn ixfr_applies_deletions_and_additions() {
+    let current = zone_records();
+    let answers = vec![
+        soa(12),
+        soa(10),
+        a("nas.corp.internal.", "10.0.0.10"),
+        soa(11),
+        a("nas.corp.internal.", "10.0.0.20"),
+        soa(11),
+        soa(12),
+        a("printer.corp.internal.", "10.0.0.30"),
+        soa(12),
+    ];
+
+    let TransferOutcome::Updated {
+        records,
+        incremental,
+    } = apply_transfer(&name("corp.internal."), Some(&current), answers).unwrap()
+    else {
+        panic!("expected an update");
+    };
+    assert!(incremental);
+    assert!(matches!(records[0].data(), RData::SOA(s) if s.serial() == 12));
+    assert!(!records.contains(&a("nas.corp.internal.", "10.0.0.10")));
+    assert!(records.contains(&a("nas.corp.internal.", "10.0.0.20")));
+    assert!(records.contains(&a("printer.corp.internal.", "10.0.0.30")));
+    assert_eq!(records.len(), current.len() + 1);
+}
+
+#[test]
+fn ixfr_from_another_serial_is_rejected() {
+    let answers = vec![soa(12), soa(9), soa(12), soa(12)];
+    assert!(apply_transfer(&name("corp.internal."), Some(&zone_records()), answers).is_err());
+}
+
+#[test]
+fn lone_soa_means_up_to_date() {
+    let outcome = apply_transfer(
+        &name("corp.internal."),
+        Some(&zone_records()),
+        vec![soa(10)],
+    );
+    assert!(matches!(outcome, Ok(TransferOutcome::UpToDate)));
+}
+
+// ── Repository ───────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn repository_round_trips_and_replaces_records() {
+    let repo = create_repo().await;
+    repo.save(&snapshot(&zone_records(), 100)).await.unwrap();
+    repo.save(&snapshot(&zone_records()[..2], 200))
+        .await
+        .unwrap();
+    repo.mark_checked(ZONE, 300).await.unwrap();
+
+    let zones = repo.get_all().await.unwrap();
+    assert_eq!(zones.len(), 1);
+    assert_eq!(zones[0].records.len(), 2);
+    assert_eq!(zones[0].transferred_at, 200);
+    assert_eq!(zones[0].checked_at, 300);
+
+    repo.delete(ZONE).await.unwrap();
+    assert!(repo.get_all().await.unwrap().is_empty());
+}
+
+// ── Answers ──────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn answers_authoritatively_from_the_stored_copy() {
+    let store = store_with(&zone_records(), now()).await;
+
+    assert!(store.serves("nas.corp.internal"));
+    assert!(store.serves("CORP.internal"));
+    assert!(!store.serves("notcorp.internal"));
+
+    let message = answer(&store, "nas.corp.internal", RecordType::A);
+    assert!(message.authoritative());
+    assert_eq!(message.response_code(), ResponseCode::NoError);
+    assert_eq!(message.answers().len(), 1);
+}
+
+#[tokio::test]
+async fn follows_in_zone_aliases() {
+    let store = store_with(&zone_records(), now()).await;
+
+    let message = answer(&store, "files.corp.internal", RecordType::A);
+    assert_eq!(message.answers().len(), 2);
+    assert_eq!(
+        message.answers()[1].data(),
+        &RData::A(A::from_str("10.0.0.10").unwrap())
+    );
+}
+
+#[tokio::test]
+async fn missing_names_and_types_carry_the_soa() {
+    let store = store_with(&zone_records(), now()).await;
+
+    let nxdomain = answer(&store, "missing.corp.internal", RecordType::A);
+    assert_eq!(nxdomain.response_code(), ResponseCode::NXDomain);
+    assert_eq!(nxdomain.name_servers().len(), 1);
+
+    let nodata = answer(&store, "nas.corp.internal", RecordType::AAAA);
+    assert_eq!(nodata.response_code(), ResponseCode::NoError);
+    assert!(nodata.answers().is_empty());
+    assert_eq!(nodata.name_servers()[0].ttl(), 300);
+
+    let empty_non_terminal = answer(&store, "b.corp.internal", RecordType::A);
+    assert_eq!(empty_non_terminal.response_code(), ResponseCode::NoError);
+}
+
+#[tokio::test]
+async fn wildcards_answer_with_the_query_name() {
+    let store = store_with(&zone_records(), now()).await;
+
+    let message = answer(&store, "host1.lab.corp.internal", RecordType::A);
+    assert_eq!(message.answers().len(), 1);
+    assert_eq!(
+        message.answers()[0].name(),
+        &name("host1.lab.corp.internal.")
+    );
+}
+
+#[tokio::test]
+async fn delegated_names_take_the_normal_path() {
+    let store = store_with(&zone_records(), now()).await;
+    assert!(store
+        .lookup("www.dev.corp.internal", RecordType::A)
+        .is_none());
+}
+
+#[tokio::test]
+async fn expired_copy_answers_servfail() {
+    let store = store_with(&zone_records(), now() - 86400 - 1).await;
+
+    let message = answer(&store, "nas.corp.internal", RecordType::A);
+    assert_eq!(message.response_code(), ResponseCode::ServFail);
+    assert!(message.answers().is_empty());
+}
@@ -0,0 +1,220 @@
+use ferrous_dns_application::ports::SinkholeTelemetryPort;
+use ferrous_dns_domain::{BlockSource, BlockingConfig, BlockingMode};
+use ferrous_dns_infrastructure::dns::sinkhole::sniff::{http_host, tls_sni};
+use ferrous_dns_infrastructure::dns::sinkhole::{inspect_connection, Sinkhole, SinkholeProtocol};
+use ferrous_dns_infrastructure::dns::{RecentBlocks, SinkholeTelemetry};
+use hickory_proto::rr::{Name, RData, RecordType};
+use std::net::IpAddr;
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+const CLIENT: IpAddr = IpAddr::V4(std::net::Ipv4Addr::new(192, 168, 1, 42));
+
+fn client_hello(sni: Option<&str>) -> Vec<u8> {
+    let mut extensions = Vec::new();
+    // supported_versions, to make sure unrelated extensions are skipped
+    extensions.extend_from_slice(&[0x00, 0x2b, 0x00, 0x03, 0x02, 0x03, 0x04]);
+    if let Some(name) = sni {
+        let name = name.as_bytes();
+        let list_len = 3 + name.len();
+        extensions.extend_from_slice(&[0x00, 0x00]);
+        extensions.extend_from_slice(&((list_len + 2) as u16).to_be_bytes());
+        extensions.extend_from_slice(&(list_len as u16).to_be_bytes());
+        extensions.push(0);
+        extensions.extend_from_slice(&(name.len() as u16).to_be_bytes());
+        extensions.extend_from_slice(name);
+    }
+
+    let mut hello = vec![0x03, 0x03];
+    hello.extend_from_slice(&[0u8; 32]);
+    hello.push(0);
+    hello.extend_from_slice(&[0x00, 0x02, 0x13, 0x01]);
+    hello.extend_from_slice(&[0x01, 0x00]);
+    hello.extend_from_slice(&(extensions.len() as u16).to_be_bytes());
+    hello.extend_from_slice(&extensions);
+
+    let mut handshake = vec![0x01];
+    handshake.extend_from_slice(&(hello.len() as u32).to_be_bytes()[1..]);
+    handshake.extend_from_slice(&hello);
+
+    let mut record = vec![0x16, 0x03, 0x01];
+    record.extend_from_slice(&(handshake.len() as u16).to_be_bytes());
+    record.extend_from_slice(&handshake);
+    record
+}
+
+fn sinkhole_config(ipv6: bool) -> BlockingConfig {
+    let mut config = BlockingConfig {
+        mode: BlockingMode::Sinkhole,
+        ..Default::default()
+    };
+    config.sinkhole.ipv4 = Some("10.0.0.250".parse().unwrap());
+    if ipv6 {
+        config.sinkhole.ipv6 = Some("fd00::250".parse().unwrap());
+    }
+    config
+}
+
+#[test]
+fn test_http_host_strips_port_and_lowercases() {
+    let head = b"GET /track HTTP/1.1\r\nUser-Agent: app\r\nHOST: Ads.Example.com:8080\r\n\r\n";
+
+    assert_eq!(http_host(head).as_deref(), Some("ads.example.com"));
+}
+
+#[test]
+fn test_http_host_handles_ipv6_literal_and_missing_header() {
+    assert_eq!(
+        http_host(b"GET / HTTP/1.1\r\nHost: [fd00::1]:80\r\n\r\n").as_deref(),
+        Some("fd00::1")
+    );
+    assert_eq!(http_host(b"GET / HTTP/1.0\r\n\r\n"), None);
+}
+
+#[test]
+fn test_tls_sni_extracted_from_client_hello() {
+    assert_eq!(
+        tls_sni(&client_hello(Some("Tracker.Example.net"))).as_deref(),
+        Some("tracker.example.net")
+    );
+}
+
+#[test]
+fn test_tls_sni_absent_or_malformed() {
+    assert_eq!(tls_sni(&client_hello(None)), None);
+    let hello = client_hello(Some("tracker.example.net"));
+    assert_eq!(tls_sni(&hello[..hello.len() - 4]), None);
+    assert_eq!(tls_sni(b"GET / HTTP/1.1\r\n"), None);
+}
+
+#[tokio::test]
+async fn test_inspect_http_connection_replies_no_content() {
+    let (mut client, mut server) = tokio::io::duplex(4096);
+    client
+        .write_all(b"GET / HTTP/1.1\r\nHost: ads.example.com\r\n\r\n")
+        .await
+        .unwrap();
+
+    let host = inspect_connection(&mut server, SinkholeProtocol::Http).await;
+    drop(server);
+
+    assert_eq!(host.as_deref(), Some("ads.example.com"));
+    let mut reply = String::new();
+    client.read_to_string(&mut reply).await.unwrap();
+    assert!(reply.starts_with("HTTP/1.1 204"));
+}
+
+#[tokio::test]
+async fn test_inspect_https_connection_reads_split_client_hello() {
+    let (mut client, mut server) = tokio::io::duplex(4096);
+    let hello = client_hello(Some("tracker.example.net"));
+    let (first, second) = hello.split_at(20);
+    client.write_all(first).await.unwrap();
+    let writer = tokio::spawn({
+        let second = second.to_vec();
+        async move {
+            client.write_all(&second).await.unwrap();
+            client
+        }
+    });
+
+    let host = inspect_connection(&mut server, SinkholeProtocol::Https).await;
+    writer.await.unwrap();
+
+    assert_eq!(host.as_deref(), Some("tracker.example.net"));
+}
+
+#[test]
+fn test_sinkhole_disabled_in_refused_mode() {
+    assert!(Sinkhole::from_config(&BlockingConfig::default()).is_none());
+}
+
+#[test]
+fn test_sinkhole_answers_by_record_type() {
+    let sinkhole = Sinkhole::from_config(&sinkhole_config(false)).unwrap();
+    let name = Name::from_str("ads.example.com.").unwrap();
+
+    let a = sinkhole.answer(&name, RecordType::A);
+    assert_eq!(a.len(), 1);
+    assert_eq!(a[0].ttl(), 60);
+    assert!(
+        matches!(a[0].data(), RData::A(ip) if ip.0 == "10.0.0.250".parse::<std::net::Ipv4Addr>().unwrap())
+    );
+
+    assert!(sinkhole.answer(&name, RecordType::AAAA).is_empty());
+    assert!(sinkhole.answer(&name, RecordType::MX).is_empty());
+    assert_eq!(sinkhole.addresses().len(), 1);
+}
+
+#[test]
+fn test_sinkhole_answers_aaaa_when_configured() {
+    let sinkhole = Sinkhole::from_config(&sinkhole_config(true)).unwrap();
+    let name = Name::from_str("ads.example.com.").unwrap();
+
+    let aaaa = sinkhole.answer(&name, RecordType::AAAA);
+    assert!(matches!(aaaa[0].data(), RData::AAAA(_)));
+    assert_eq!(sinkhole.addresses().len(), 2);
+}
+
+#[test]
+fn test_telemetry_correlates_attempts_with_blocked_answers() {
+    let telemetry = Arc::new(SinkholeTelemetry::new());
+    let sinkhole = Sinkhole::from_config(&sinkhole_config(false))
+        .unwrap()
+        .with_telemetry(telemetry.clone());
+
+    sinkhole.record_answer(CLIENT, "Ads.Example.com", BlockSource::Blocklist);
+    telemetry.record_attempt(CLIENT, "ads.example.com", SinkholeProtocol::Https);
+    telemetry.record_attempt(CLIENT, "ads.example.com", SinkholeProtocol::Http);
+    telemetry.record_attempt(
+        "192.168.1.7".parse().unwrap(),
+        "ads.example.com",
+        SinkholeProtocol::Https,
+    );
+
+    let hosts = telemetry.hosts(10);
+    assert_eq!(hosts.len(), 1);
+    let host = &hosts[0];
+    assert_eq!(host.host, "ads.example.com");
+    assert_eq!(host.https_attempts, 2);
+    assert_eq!(host.http_attempts, 1);
+    assert_eq!(host.correlated_attempts, 2);
+    assert_eq!(host.clients, 2);
+    assert!(host.last_blocked_query_at.is_some());
+}
+
+#[test]
+fn test_recent_blocks_remember_the_source_per_client() {
+    let recent_blocks = Arc::new(RecentBlocks::new());
+    let sinkhole = Sinkhole::from_config(&sinkhole_config(false))
+        .unwrap()
+        .with_recent_blocks(recent_blocks.clone());
+
+    sinkhole.record_answer(CLIENT, "Ads.Example.com.", BlockSource::RegexFilter);
+
+    assert_eq!(
+        recent_blocks.source(CLIENT, "ads.example.com"),
+        Some(BlockSource::RegexFilter)
+    );
+    assert_eq!(
+        recent_blocks.source("192.168.1.7".parse().unwrap(), "ads.example.com"),
+        None
+    );
+    assert_eq!(recent_blocks.source(CLIENT, "other.example.com"), None);
+}
+
+#[test]
+fn test_telemetry_orders_hosts_by_attempts_and_limits() {
+    let telemetry = SinkholeTelemetry::new();
+    telemetry.record_attempt(CLIENT, "once.example", SinkholeProtocol::Http);
+    for _ in 0..3 {
+        telemetry.record_attempt(CLIENT, "often.example", SinkholeProtocol::Https);
+    }
+
+    let hosts = telemetry.hosts(1);
+    assert_eq!(hosts.len(), 1);
+    assert_eq!(hosts[0].host, "often.example");
+    assert_eq!(hosts[0].correlated_attempts, 0);
+    assert!(hosts[0].last_blocked_query_at.is_none());
+}
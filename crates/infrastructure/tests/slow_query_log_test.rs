@@ -0,0 +1,64 @@
+use chrono::Utc;
+use ferrous_dns_application::ports::{QueryPhaseTimings, SlowQueryEntry, SlowQueryLogPort};
+use ferrous_dns_domain::RecordType;
+use ferrous_dns_infrastructure::dns::SlowQueryLog;
+use std::sync::Arc;
+
+fn entry(domain: &str, total_us: u64) -> SlowQueryEntry {
+    SlowQueryEntry {
+        domain: Arc::from(domain),
+        record_type: RecordType::A,
+        client_ip: "192.168.1.10".parse().unwrap(),
+        response_status: Some("NOERROR"),
+        cache_hit: false,
+        upstream_server: Some(Arc::from("udp://9.9.9.9:53")),
+        upstream_pool: Some(Arc::from("default")),
+        phases: QueryPhaseTimings {
+            upstream_us: total_us,
+            ..QueryPhaseTimings::default()
+        },
+        total_us,
+        timestamp: Utc::now(),
+    }
+}
+
+#[test]
+fn test_recent_returns_newest_first() {
+    let log = SlowQueryLog::new(10);
+    log.record(entry("a.example.com", 600_000));
+    log.record(entry("b.example.com", 700_000));
+
+    let recent = log.recent(10);
+
+    assert_eq!(recent.len(), 2);
+    assert_eq!(recent[0].domain.as_ref(), "b.example.com");
+    assert_eq!(recent[1].domain.as_ref(), "a.example.com");
+}
+
+#[test]
+fn test_oldest_entries_are_dropped_at_capacity() {
+    let log = SlowQueryLog::new(3);
+    for i in 0..5 {
+        log.record(entry(&format!("q{i}.example.com"), 500_000 + i));
+    }
+
+    let recent = log.recent(10);
+
+    assert_eq!(log.len(), 3);
+    let domains: Vec<&str> = recent.iter().map(|e| e.domain.as_ref()).collect();
+    assert_eq!(
+        domains,
+        ["q4.example.com", "q3.example.com", "q2.example.com"]
+    );
+}
+
+#[test]
+fn test_recent_respects_limit() {
+    let log = SlowQueryLog::new(10);
+    for i in 0..5 {
+        log.record(entry(&format!("q{i}.example.com"), 500_000));
+    }
+
+    assert_eq!(log.recent(2).len(), 2);
+    assert!(SlowQueryLog::new(10).is_empty());
+}
@@ -22,6 +22,7 @@ fn create_stale_cache() -> DnsCache {
         refresh_sample_rate: 1.0,
         min_ttl: 0,
         max_ttl: 86_400,
+        l1_capacity: 1024,
     })
 }
 
@@ -53,9 +54,9 @@ fn test_stale_entry_sends_to_refresh_channel() {
 
     let msg = rx.try_recv();
     assert!(msg.is_ok(), "Stale hit must send domain to refresh channel");
-    let (domain, record_type) = msg.unwrap();
-    assert_eq!(&*domain, "stale-chan.com");
-    assert_eq!(record_type, RecordType::CNAME);
+    let key = msg.unwrap();
+    assert_eq!(key.domain.as_str(), "stale-chan.com");
+    assert_eq!(key.record_type, RecordType::CNAME);
 
     let stale_hits = cache.metrics().stale_hits.load(Ordering::Relaxed);
     assert!(
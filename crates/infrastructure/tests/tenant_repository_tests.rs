@@ -0,0 +1,414 @@
+use ferrous_dns_application::ports::{
+    GroupRepository, LocalRecordRepository, LocalZoneAnswer, LocalZonePort, QueryLogRepository,
+    TenantRepository,
+};
+use ferrous_dns_application::use_cases::TenantBlocklistSourcesUseCase;
+use ferrous_dns_domain::config::{DatabaseConfig, QueryLogPartitioning};
+use ferrous_dns_domain::{DomainError, LocalRecord, QueryLog, QuerySource, RecordType, Tenant};
+use ferrous_dns_infrastructure::database::create_write_pool;
+use ferrous_dns_infrastructure::dns::LocalZoneStore;
+use ferrous_dns_infrastructure::repositories::{
+    group_repository::SqliteGroupRepository, local_record_repository::SqliteLocalRecordRepository,
+    query_log_repository::SqliteQueryLogRepository, SqliteBlocklistSourceRepository,
+    SqliteTenantRepository,
+};
+use sqlx::SqlitePool;
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tempfile::TempDir;
+
+async fn create_migrated_db() -> (TempDir, SqlitePool) {
+    let dir = tempfile::tempdir().unwrap();
+    let url = format!("sqlite:{}", dir.path().join("ferrous.db").display());
+    let pool = create_write_pool(&url, &DatabaseConfig::default())
+        .await
+        .unwrap();
+    (dir, pool)
+}
+
+fn new_tenant(name: &str, subnets: &[&str]) -> Tenant {
+    Tenant {
+        subnets: subnets.iter().map(|s| Arc::from(*s)).collect(),
+        ..Tenant::new(Arc::from(name))
+    }
+}
+
+fn tenant_record(tenant_id: Option<i64>, hostname: &str, ip: &str) -> LocalRecord {
+    LocalRecord {
+        tenant_id,
+        ..LocalRecord::new(Arc::from(hostname), RecordType::A, Arc::from(ip))
+    }
+}
+
+#[tokio::test]
+async fn test_create_and_get_round_trips_subnets() {
+    let (_dir, pool) = create_migrated_db().await;
+    let repo = SqliteTenantRepository::new(pool);
+
+    let mut tenant = new_tenant("Acme", &["10.1.0.0/16", "10.9.0.0/24"]);
+    tenant.listener = Some(Arc::from("0.0.0.0:5353"));
+    let created = repo.create(&tenant).await.unwrap();
+    let id = created.id.unwrap();
+
+    let fetched = repo.get_by_id(id).await.unwrap().unwrap();
+    assert_eq!(fetched.name.as_ref(), "Acme");
+    assert_eq!(fetched.listener.as_deref(), Some("0.0.0.0:5353"));
+    assert_eq!(fetched.subnets.len(), 2);
+
+    let all = repo.get_all().await.unwrap();
+    assert_eq!(all.len(), 1);
+    assert_eq!(all[0].subnets, fetched.subnets);
+}
+
+#[tokio::test]
+async fn test_subnet_cannot_belong_to_two_tenants() {
+    let (_dir, pool) = create_migrated_db().await;
+    let repo = SqliteTenantRepository::new(pool);
+
+    repo.create(&new_tenant("Acme", &["10.1.0.0/16"]))
+        .await
+        .unwrap();
+    let err = repo
+        .create(&new_tenant("Globex", &["10.1.0.0/16"]))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, DomainError::InvalidTenant(_)));
+    assert_eq!(repo.get_all().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_update_replaces_subnets() {
+    let (_dir, pool) = create_migrated_db().await;
+    let repo = SqliteTenantRepository::new(pool);
+
+    let mut tenant = repo
+        .create(&new_tenant("Acme", &["10.1.0.0/16"]))
+        .await
+        .unwrap();
+    tenant.subnets = vec![Arc::from("10.2.0.0/16")];
+    repo.update(&tenant).await.unwrap();
+
+    let fetched = repo.get_by_id(tenant.id.unwrap()).await.unwrap().unwrap();
+    assert_eq!(fetched.subnets, vec![Arc::from("10.2.0.0/16")]);
+}
+
+#[tokio::test]
+async fn test_groups_are_listed_per_tenant() {
+    let (_dir, pool) = create_migrated_db().await;
+    let tenants = SqliteTenantRepository::new(pool.clone());
+    let groups = SqliteGroupRepository::new(pool);
+
+    let acme = tenants.create(&new_tenant("Acme", &[])).await.unwrap();
+    let globex = tenants.create(&new_tenant("Globex", &[])).await.unwrap();
+    let acme_group = groups.create("Acme Kids".to_string(), None).await.unwrap();
+    let globex_group = groups
+        .create("Globex Kids".to_string(), None)
+        .await
+        .unwrap();
+    tenants
+        .assign_group(acme.id.unwrap(), acme_group.id.unwrap())
+        .await
+        .unwrap();
+    tenants
+        .assign_group(globex.id.unwrap(), globex_group.id.unwrap())
+        .await
+        .unwrap();
+
+    let in_acme = groups
+        .get_in_tenant_with_client_counts(acme.id.unwrap())
+        .await
+        .unwrap();
+    assert_eq!(in_acme.len(), 1);
+    assert_eq!(in_acme[0].0.name.as_ref(), "Acme Kids");
+    assert_eq!(in_acme[0].0.tenant_id, acme.id);
+    assert_eq!(
+        tenants.get_group_ids(globex.id.unwrap()).await.unwrap(),
+        vec![globex_group.id.unwrap()]
+    );
+}
+
+#[tokio::test]
+async fn test_delete_with_groups_is_rejected() {
+    let (_dir, pool) = create_migrated_db().await;
+    let tenants = SqliteTenantRepository::new(pool.clone());
+    let groups = SqliteGroupRepository::new(pool);
+
+    let acme = tenants.create(&new_tenant("Acme", &[])).await.unwrap();
+    let group = groups.create("Acme Kids".to_string(), None).await.unwrap();
+    tenants
+        .assign_group(acme.id.unwrap(), group.id.unwrap())
+        .await
+        .unwrap();
+
+    let err = tenants.delete(acme.id.unwrap()).await.unwrap_err();
+    assert!(matches!(err, DomainError::InvalidTenant(_)));
+
+    groups.delete(group.id.unwrap()).await.unwrap();
+    tenants.delete(acme.id.unwrap()).await.unwrap();
+    assert!(tenants.get_by_id(acme.id.unwrap()).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_delete_removes_tenant_records() {
+    let (_dir, pool) = create_migrated_db().await;
+    let tenants = SqliteTenantRepository::new(pool.clone());
+    let records = SqliteLocalRecordRepository::new(pool);
+
+    let acme = tenants.create(&new_tenant("Acme", &[])).await.unwrap();
+    records
+        .create(&tenant_record(acme.id, "nas", "10.1.0.10"))
+        .await
+        .unwrap();
+    records
+        .create(&tenant_record(None, "nas", "192.168.1.10"))
+        .await
+        .unwrap();
+
+    tenants.delete(acme.id.unwrap()).await.unwrap();
+
+    let remaining = records.get_all().await.unwrap();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].tenant_id, None);
+}
+
+#[tokio::test]
+async fn test_local_records_are_isolated_per_tenant() {
+    let (_dir, pool) = create_migrated_db().await;
+    let tenants = SqliteTenantRepository::new(pool.clone());
+    let records = Arc::new(SqliteLocalRecordRepository::new(pool));
+
+    let acme = tenants.create(&new_tenant("Acme", &[])).await.unwrap();
+    let globex = tenants.create(&new_tenant("Globex", &[])).await.unwrap();
+    records
+        .create(&tenant_record(acme.id, "nas", "10.1.0.10"))
+        .await
+        .unwrap();
+    records
+        .create(&tenant_record(globex.id, "nas", "10.2.0.10"))
+        .await
+        .unwrap();
+    records
+        .create(&tenant_record(None, "printer", "192.168.1.20"))
+        .await
+        .unwrap();
+
+    let in_acme = records.get_all_in_tenant(acme.id.unwrap()).await.unwrap();
+    assert_eq!(in_acme.len(), 1);
+    assert_eq!(in_acme[0].value.as_ref(), "10.1.0.10");
+
+    let zone = LocalZoneStore::new(records, Some("home.lan".to_string()))
+        .await
+        .unwrap();
+    let address = |tenant_id: Option<i64>, name: &str| match zone.lookup_for_tenant(
+        tenant_id,
+        name,
+        RecordType::A,
+    ) {
+        Some(LocalZoneAnswer::Records(resolution)) => resolution.addresses.first().copied(),
+        _ => None,
+    };
+
+    assert_eq!(
+        address(acme.id, "nas.home.lan"),
+        Some("10.1.0.10".parse::<IpAddr>().unwrap())
+    );
+    assert_eq!(
+        address(globex.id, "nas.home.lan"),
+        Some("10.2.0.10".parse::<IpAddr>().unwrap())
+    );
+    assert_eq!(address(None, "nas.home.lan"), None);
+    assert!(zone.lookup("nas.home.lan", RecordType::A).is_none());
+    assert_eq!(
+        address(acme.id, "printer.home.lan"),
+        Some("192.168.1.20".parse::<IpAddr>().unwrap())
+    );
+}
+
+#[tokio::test]
+async fn test_local_record_lookup_by_id_is_tenant_scoped() {
+    let (_dir, pool) = create_migrated_db().await;
+    let tenants = SqliteTenantRepository::new(pool.clone());
+    let records = SqliteLocalRecordRepository::new(pool);
+
+    let acme = tenants.create(&new_tenant("Acme", &[])).await.unwrap();
+    let globex = tenants.create(&new_tenant("Globex", &[])).await.unwrap();
+    let record = records
+        .create(&tenant_record(acme.id, "nas", "10.1.0.10"))
+        .await
+        .unwrap();
+    let id = record.id.unwrap();
+
+    assert!(records
+        .get_by_id_in_tenant(acme.id.unwrap(), id)
+        .await
+        .unwrap()
+        .is_some());
+    assert!(records
+        .get_by_id_in_tenant(globex.id.unwrap(), id)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_blocklist_sources_are_isolated_per_tenant() {
+    let (_dir, pool) = create_migrated_db().await;
+    let tenant_repo = Arc::new(SqliteTenantRepository::new(pool.clone()));
+    let groups = SqliteGroupRepository::new(pool.clone());
+    let sources = TenantBlocklistSourcesUseCase::new(
+        tenant_repo.clone(),
+        Arc::new(SqliteBlocklistSourceRepository::new(pool)),
+    );
+
+    let acme = tenant_repo.create(&new_tenant("Acme", &[])).await.unwrap();
+    let globex = tenant_repo
+        .create(&new_tenant("Globex", &[]))
+        .await
+        .unwrap();
+    let (acme_id, globex_id) = (acme.id.unwrap(), globex.id.unwrap());
+    let acme_group = groups.create("Acme Kids".to_string(), None).await.unwrap();
+    let acme_group_id = acme_group.id.unwrap();
+    tenant_repo
+        .assign_group(acme_id, acme_group_id)
+        .await
+        .unwrap();
+
+    let source = sources
+        .create(
+            acme_id,
+            "Acme Ads".to_string(),
+            Some("https://example.com/ads.txt".to_string()),
+            vec![acme_group_id],
+            None,
+            true,
+        )
+        .await
+        .unwrap();
+    let source_id = source.id.unwrap();
+    assert_eq!(source.tenant_id, Some(acme_id));
+
+    let err = sources
+        .create(
+            globex_id,
+            "Globex Ads".to_string(),
+            None,
+            vec![acme_group_id],
+            None,
+            true,
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DomainError::GroupNotFound(_)));
+    let err = sources
+        .create(acme_id, "Shared".to_string(), None, vec![1], None, true)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DomainError::GroupNotFound(1)));
+
+    assert_eq!(sources.get_all(acme_id).await.unwrap().len(), 1);
+    assert!(sources.get_all(globex_id).await.unwrap().is_empty());
+
+    let err = sources
+        .update(
+            globex_id,
+            source_id,
+            None,
+            None,
+            None,
+            None,
+            Some(false),
+            None,
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DomainError::BlocklistSourceNotFound(_)));
+    let err = sources.delete(globex_id, source_id).await.unwrap_err();
+    assert!(matches!(err, DomainError::BlocklistSourceNotFound(_)));
+
+    sources.delete(acme_id, source_id).await.unwrap();
+    assert!(sources.get_all(acme_id).await.unwrap().is_empty());
+}
+
+fn client_query(client: [u8; 4], blocked: bool, group_id: i64) -> QueryLog {
+    QueryLog {
+        id: None,
+        domain: "example.com".into(),
+        record_type: RecordType::A,
+        client_ip: IpAddr::from(client),
+        client_hostname: None,
+        client_mac: None,
+        blocked,
+        response_time_us: Some(100),
+        cache_hit: false,
+        cache_refresh: false,
+        dnssec_status: None,
+        upstream_server: None,
+        upstream_pool: None,
+        upstream_strategy: None,
+        upstream_attempt: None,
+        upstream_protocol: None,
+        response_status: Some("NOERROR"),
+        timestamp: None,
+        query_source: QuerySource::Client,
+        group_id: Some(group_id),
+        group_name: None,
+        block_source: None,
+        plugin: None,
+    }
+}
+
+#[tokio::test]
+async fn test_stats_count_only_tenant_queries() {
+    let (_dir, pool) = create_migrated_db().await;
+    let tenants = SqliteTenantRepository::new(pool.clone());
+    let groups = SqliteGroupRepository::new(pool.clone());
+    let cfg = DatabaseConfig {
+        query_log_partitioning: QueryLogPartitioning::None,
+        ..DatabaseConfig::default()
+    };
+    let query_log = SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &cfg);
+
+    let acme = tenants.create(&new_tenant("Acme", &[])).await.unwrap();
+    let globex = tenants.create(&new_tenant("Globex", &[])).await.unwrap();
+    let group = groups.create("Acme Kids".to_string(), None).await.unwrap();
+    let group_id = group.id.unwrap();
+    tenants
+        .assign_group(acme.id.unwrap(), group_id)
+        .await
+        .unwrap();
+
+    for query in [
+        client_query([10, 1, 0, 5], false, group_id),
+        client_query([10, 1, 0, 6], true, group_id),
+        client_query([192, 168, 1, 5], false, 1),
+    ] {
+        query_log.log_query(&query).await.unwrap();
+    }
+
+    let mut stats = tenants.get_stats(acme.id.unwrap(), 24.0).await.unwrap();
+    for _ in 0..50 {
+        if stats.queries_total >= 2 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        stats = tenants.get_stats(acme.id.unwrap(), 24.0).await.unwrap();
+    }
+    assert_eq!(stats.queries_total, 2);
+    assert_eq!(stats.queries_blocked, 1);
+    assert_eq!(stats.unique_clients, 2);
+    assert_eq!(stats.groups, 1);
+    assert_eq!(stats.local_records, 0);
+
+    // Queries stay with the tenant that owned the group when they were logged.
+    tenants
+        .assign_group(globex.id.unwrap(), group_id)
+        .await
+        .unwrap();
+    let stats = tenants.get_stats(acme.id.unwrap(), 24.0).await.unwrap();
+    assert_eq!(stats.queries_total, 2);
+    assert_eq!(stats.groups, 0);
+    let stats = tenants.get_stats(globex.id.unwrap(), 24.0).await.unwrap();
+    assert_eq!(stats.queries_total, 0);
+}
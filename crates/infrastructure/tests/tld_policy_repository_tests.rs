@@ -0,0 +1,81 @@
+use ferrous_dns_application::ports::TldPolicyRepository;
+use ferrous_dns_domain::{TldFilterMode, TldPolicy};
+use ferrous_dns_infrastructure::repositories::tld_policy_repository::SqliteTldPolicyRepository;
+use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
+
+async fn create_test_db() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS group_tld_policies (
+            id         INTEGER PRIMARY KEY AUTOINCREMENT,
+            group_id   INTEGER NOT NULL UNIQUE,
+            mode       TEXT    NOT NULL CHECK(mode IN ('allow','deny')),
+            tlds       TEXT    NOT NULL,
+            created_at TEXT    NOT NULL,
+            updated_at TEXT    NOT NULL
+        )",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    pool
+}
+
+fn tlds(values: &[&str]) -> Vec<String> {
+    values.iter().map(|t| t.to_string()).collect()
+}
+
+#[tokio::test]
+async fn test_upsert_replaces_existing_group_policy() {
+    let repo = SqliteTldPolicyRepository::new(create_test_db().await);
+
+    repo.upsert(&TldPolicy::new(2, TldFilterMode::Deny, tlds(&["zip"])))
+        .await
+        .unwrap();
+    let updated = repo
+        .upsert(&TldPolicy::new(
+            2,
+            TldFilterMode::Allow,
+            tlds(&["net", "com"]),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(updated.mode, TldFilterMode::Allow);
+    assert_eq!(updated.tlds, tlds(&["com", "net"]));
+    assert_eq!(repo.get_all().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_get_by_group_returns_stored_tlds() {
+    let repo = SqliteTldPolicyRepository::new(create_test_db().await);
+
+    repo.upsert(&TldPolicy::new(
+        4,
+        TldFilterMode::Deny,
+        tlds(&[".ZIP", "mov"]),
+    ))
+    .await
+    .unwrap();
+
+    let policy = repo.get_by_group(4).await.unwrap().unwrap();
+    assert_eq!(policy.tlds, tlds(&["mov", "zip"]));
+    assert!(repo.get_by_group(5).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_delete_by_group_removes_policy() {
+    let repo = SqliteTldPolicyRepository::new(create_test_db().await);
+
+    repo.upsert(&TldPolicy::new(3, TldFilterMode::Deny, tlds(&["zip"])))
+        .await
+        .unwrap();
+    repo.delete_by_group(3).await.unwrap();
+
+    assert!(repo.get_by_group(3).await.unwrap().is_none());
+}
@@ -0,0 +1,146 @@
+use bytes::Bytes;
+use ferrous_dns_domain::{RecordType, TsigAlgorithm, TsigKeyConfig, TsigKeyFile};
+use ferrous_dns_infrastructure::dns::forwarding::{
+    MessageBuilder, ResponseParser, TsigKey, TsigKeyring,
+};
+use std::sync::Arc;
+
+fn key(name: &str, secret: &str) -> Arc<TsigKey> {
+    Arc::new(
+        TsigKey::new(&TsigKeyConfig {
+            name: name.to_string(),
+            algorithm: TsigAlgorithm::HmacSha256,
+            secret: secret.to_string(),
+        })
+        .unwrap(),
+    )
+}
+
+fn internal_key() -> Arc<TsigKey> {
+    key(
+        "ferrous-internal.",
+        "c2VjcmV0LWtleS1tYXRlcmlhbC0wMTIzNDU2Nzg5",
+    )
+}
+
+/// Turns a query into a minimal response the way a server would echo it.
+fn as_response(query: &[u8]) -> Vec<u8> {
+    let mut response = query.to_vec();
+    response[2] |= 0x80;
+    response
+}
+
+#[test]
+fn signed_query_carries_one_more_additional_record() {
+    let query = MessageBuilder::build_query("host.corp.internal", &RecordType::A, false).unwrap();
+    let (signed, request) = MessageBuilder::sign_tsig(&query, &internal_key()).unwrap();
+
+    let arcount = |m: &[u8]| u16::from_be_bytes([m[10], m[11]]);
+    assert_eq!(arcount(&signed), arcount(&query) + 1);
+    assert!(signed.starts_with(&query[..10]));
+    assert_eq!(request.mac.len(), 32);
+}
+
+#[test]
+fn server_verifies_signed_query() {
+    let key = internal_key();
+    let query = MessageBuilder::build_query("host.corp.internal", &RecordType::A, false).unwrap();
+    let (signed, request) = MessageBuilder::sign_tsig(&query, &key).unwrap();
+
+    let (unsigned, mac) = key.verify(&signed, None).unwrap();
+    assert_eq!(unsigned, query);
+    assert_eq!(mac, request.mac);
+}
+
+#[test]
+fn signed_response_is_verified_and_stripped() {
+    let key = internal_key();
+    let query = MessageBuilder::build_query("host.corp.internal", &RecordType::A, false).unwrap();
+    let (_, request) = MessageBuilder::sign_tsig(&query, &key).unwrap();
+
+    let plain = as_response(&query);
+    let mut signed = plain.clone();
+    key.sign(&mut signed, Some(&request.mac)).unwrap();
+
+    let verified = ResponseParser::verify_tsig(Bytes::from(signed), &request).unwrap();
+    assert_eq!(verified.as_ref(), plain.as_slice());
+    assert!(ResponseParser::parse_bytes(verified).is_ok());
+}
+
+#[test]
+fn unsigned_response_is_rejected() {
+    let key = internal_key();
+    let query = MessageBuilder::build_query("host.corp.internal", &RecordType::A, false).unwrap();
+    let (_, request) = MessageBuilder::sign_tsig(&query, &key).unwrap();
+
+    let result = ResponseParser::verify_tsig(Bytes::from(as_response(&query)), &request);
+    assert!(result.is_err());
+    assert!(ResponseParser::is_transport_error(&result.unwrap_err()));
+}
+
+#[test]
+fn tampered_response_is_rejected() {
+    let key = internal_key();
+    let query = MessageBuilder::build_query("host.corp.internal", &RecordType::A, false).unwrap();
+    let (_, request) = MessageBuilder::sign_tsig(&query, &key).unwrap();
+
+    let mut signed = as_response(&query);
+    key.sign(&mut signed, Some(&request.mac)).unwrap();
+    signed[3] ^= 0x03; // flip the RCODE bits
+
+    assert!(ResponseParser::verify_tsig(Bytes::from(signed), &request).is_err());
+}
+
+#[test]
+fn response_signed_with_another_key_is_rejected() {
+    let ours = internal_key();
+    let theirs = key("ferrous-internal.", "b3RoZXItc2VjcmV0LW1hdGVyaWFs");
+    let query = MessageBuilder::build_query("host.corp.internal", &RecordType::A, false).unwrap();
+    let (_, request) = MessageBuilder::sign_tsig(&query, &ours).unwrap();
+
+    let mut signed = as_response(&query);
+    theirs.sign(&mut signed, Some(&request.mac)).unwrap();
+
+    assert!(ResponseParser::verify_tsig(Bytes::from(signed), &request).is_err());
+}
+
+#[test]
+fn response_without_request_mac_is_rejected() {
+    let key = internal_key();
+    let query = MessageBuilder::build_query("host.corp.internal", &RecordType::A, false).unwrap();
+    let (_, request) = MessageBuilder::sign_tsig(&query, &key).unwrap();
+
+    let mut signed = as_response(&query);
+    key.sign(&mut signed, None).unwrap();
+
+    assert!(ResponseParser::verify_tsig(Bytes::from(signed), &request).is_err());
+}
+
+#[test]
+fn keyring_looks_up_keys_by_normalized_name() {
+    let file = TsigKeyFile::parse(
+        r#"
+        [[keys]]
+        name = "Ferrous-Internal."
+        secret = "c2VjcmV0LWtleS1tYXRlcmlhbC0wMTIzNDU2Nzg5"
+        "#,
+    )
+    .unwrap();
+    let keyring = TsigKeyring::from_file(&file).unwrap();
+
+    assert!(keyring.get("ferrous-internal").is_some());
+    assert!(keyring.get("other").is_none());
+}
+
+#[test]
+fn invalid_base64_secret_is_rejected() {
+    let file = TsigKeyFile::parse(
+        r#"
+        [[keys]]
+        name = "broken"
+        secret = "not base64!"
+        "#,
+    )
+    .unwrap();
+    assert!(TsigKeyring::from_file(&file).is_err());
+}
@@ -0,0 +1,102 @@
+use ferrous_dns_application::ports::{UdpPoolMetricsSource, UdpServerPoolMetrics};
+use ferrous_dns_domain::UpstreamAddr;
+use ferrous_dns_infrastructure::dns::transport::udp::UdpTransport;
+use ferrous_dns_infrastructure::dns::transport::{DnsTransport, UdpSocketPool};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::UdpSocket;
+
+const QUERY: [u8; 12] = [0x12, 0x34, 0x01, 0x00, 0, 1, 0, 0, 0, 0, 0, 0];
+
+/// Answers every datagram with the same bytes, which carry the query ID.
+async fn echo_server() -> SocketAddr {
+    let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let addr = socket.local_addr().unwrap();
+    tokio::spawn(async move {
+        let mut buf = [0u8; 512];
+        while let Ok((len, from)) = socket.recv_from(&mut buf).await {
+            let _ = socket.send_to(&buf[..len], from).await;
+        }
+    });
+    addr
+}
+
+fn server_metrics(pool: &UdpSocketPool, server: SocketAddr) -> UdpServerPoolMetrics {
+    pool.udp_pool_metrics()
+        .servers
+        .into_iter()
+        .find(|s| s.server == server.to_string())
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_sequential_queries_reuse_one_socket() {
+    let server = echo_server().await;
+    let pool = Arc::new(UdpSocketPool::new(8, 16));
+    let transport = UdpTransport::with_pool(UpstreamAddr::Resolved(server), pool.clone());
+
+    for _ in 0..3 {
+        transport
+            .send(&QUERY, Duration::from_secs(1))
+            .await
+            .unwrap();
+    }
+
+    let metrics = pool.udp_pool_metrics();
+    assert_eq!(metrics.sockets_created, 1);
+    assert_eq!(metrics.sockets_reused, 2);
+    assert_eq!(metrics.idle_sockets, 1);
+    assert_eq!(metrics.in_flight, 0);
+}
+
+#[tokio::test]
+async fn test_idle_sockets_follow_concurrency_up_to_the_cap() {
+    let server: SocketAddr = "127.0.0.1:9".parse().unwrap();
+    let pool = UdpSocketPool::new(4, 16);
+
+    let mut held = Vec::new();
+    for _ in 0..6 {
+        held.push(pool.acquire(server).await.unwrap());
+    }
+    assert_eq!(server_metrics(&pool, server).in_flight, 6);
+    drop(held);
+
+    let metrics = server_metrics(&pool, server);
+    assert_eq!(metrics.in_flight, 0);
+    assert_eq!(metrics.target_idle, 4);
+    assert_eq!(metrics.idle_sockets, 4);
+}
+
+#[tokio::test]
+async fn test_timed_out_socket_is_counted_and_not_reused() {
+    let silent = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let server = silent.local_addr().unwrap();
+    let pool = Arc::new(UdpSocketPool::new(4, 16));
+    let transport = UdpTransport::with_pool(UpstreamAddr::Resolved(server), pool.clone());
+
+    let result = transport.send(&QUERY, Duration::from_millis(50)).await;
+
+    assert!(result.is_err());
+    let metrics = server_metrics(&pool, server);
+    assert_eq!(metrics.timeouts, 1);
+    assert_eq!(metrics.idle_sockets, 0);
+}
+
+#[cfg(target_os = "linux")]
+#[tokio::test]
+async fn test_icmp_port_unreachable_is_counted() {
+    let closed = {
+        let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        socket.local_addr().unwrap()
+    };
+    let pool = Arc::new(UdpSocketPool::new(4, 16));
+    let transport = UdpTransport::with_pool(UpstreamAddr::Resolved(closed), pool.clone());
+
+    let result = transport.send(&QUERY, Duration::from_secs(1)).await;
+
+    assert!(result.is_err());
+    let metrics = server_metrics(&pool, closed);
+    assert_eq!(metrics.unreachable_errors, 1);
+    assert_eq!(metrics.timeouts, 0);
+}
@@ -0,0 +1,186 @@
+use ferrous_dns_application::ports::UnblockRequestRepository;
+use ferrous_dns_domain::{DomainError, UnblockRequest, UnblockRequestStatus};
+use ferrous_dns_infrastructure::repositories::SqliteUnblockRequestRepository;
+use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
+use std::sync::Arc;
+
+async fn create_test_db() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    for migration in [
+        include_str!("../../../migrations/20260327000001_create_unblock_requests.sql"),
+        include_str!("../../../migrations/20260328000001_pending_requests.sql"),
+    ] {
+        sqlx::raw_sql(migration).execute(&pool).await.unwrap();
+    }
+
+    pool
+}
+
+fn request(domain: &str, client_ip: &str, message: Option<&str>) -> UnblockRequest {
+    let mut request = UnblockRequest::new(Arc::from(domain), Arc::from(client_ip));
+    request.group_id = Some(1);
+    request.block_source = Some(Arc::from("blocklist"));
+    request.message = message.map(Arc::from);
+    request
+}
+
+#[tokio::test]
+async fn test_upsert_returns_stored_request() {
+    let repo = SqliteUnblockRequestRepository::new(create_test_db().await);
+
+    let stored = repo
+        .upsert(&request(
+            "ads.example.com",
+            "10.0.0.7",
+            Some("needed for work"),
+        ))
+        .await
+        .unwrap();
+
+    assert!(stored.id.is_some());
+    assert!(stored.created_at.is_some());
+    assert_eq!(stored.domain.as_ref(), "ads.example.com");
+    assert_eq!(stored.group_id, Some(1));
+    assert_eq!(stored.block_source.as_deref(), Some("blocklist"));
+    assert_eq!(stored.message.as_deref(), Some("needed for work"));
+    assert_eq!(stored.status, UnblockRequestStatus::Pending);
+    assert!(stored.reviewed_at.is_none());
+}
+
+#[tokio::test]
+async fn test_repeated_request_replaces_the_earlier_one() {
+    let repo = SqliteUnblockRequestRepository::new(create_test_db().await);
+
+    let first = repo
+        .upsert(&request("ads.example.com", "10.0.0.7", None))
+        .await
+        .unwrap();
+    let second = repo
+        .upsert(&request("ads.example.com", "10.0.0.7", Some("please")))
+        .await
+        .unwrap();
+    repo.upsert(&request("ads.example.com", "10.0.0.8", None))
+        .await
+        .unwrap();
+
+    assert_eq!(first.id, second.id);
+    assert_eq!(second.message.as_deref(), Some("please"));
+    assert_eq!(repo.count_pending().await.unwrap(), 2);
+}
+
+#[tokio::test]
+async fn test_get_all_lists_newest_first() {
+    let repo = SqliteUnblockRequestRepository::new(create_test_db().await);
+    repo.upsert(&request("one.example.com", "10.0.0.1", None))
+        .await
+        .unwrap();
+    repo.upsert(&request("two.example.com", "10.0.0.1", None))
+        .await
+        .unwrap();
+
+    let all = repo.get_all(None).await.unwrap();
+
+    let domains: Vec<&str> = all.iter().map(|r| r.domain.as_ref()).collect();
+    assert_eq!(domains, vec!["two.example.com", "one.example.com"]);
+}
+
+#[tokio::test]
+async fn test_review_records_status_and_filters_by_it() {
+    let repo = SqliteUnblockRequestRepository::new(create_test_db().await);
+    let approved = repo
+        .upsert(&request("one.example.com", "10.0.0.1", None))
+        .await
+        .unwrap();
+    repo.upsert(&request("two.example.com", "10.0.0.1", None))
+        .await
+        .unwrap();
+
+    let reviewed = repo
+        .review(
+            approved.id.unwrap(),
+            UnblockRequestStatus::Approved,
+            Some(42),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(reviewed.status, UnblockRequestStatus::Approved);
+    assert_eq!(reviewed.managed_domain_id, Some(42));
+    assert!(reviewed.reviewed_at.is_some());
+    assert_eq!(repo.count_pending().await.unwrap(), 1);
+
+    let pending = repo
+        .get_all(Some(UnblockRequestStatus::Pending))
+        .await
+        .unwrap();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].domain.as_ref(), "two.example.com");
+}
+
+#[tokio::test]
+async fn test_review_rejects_request_that_is_no_longer_pending() {
+    let repo = SqliteUnblockRequestRepository::new(create_test_db().await);
+    let stored = repo
+        .upsert(&request("ads.example.com", "10.0.0.7", None))
+        .await
+        .unwrap();
+    let id = stored.id.unwrap();
+
+    repo.review(id, UnblockRequestStatus::Denied, None)
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        repo.review(id, UnblockRequestStatus::Approved, Some(1)).await,
+        Err(DomainError::UnblockRequestAlreadyReviewed(reviewed)) if reviewed == id
+    ));
+    assert!(matches!(
+        repo.review(id + 1, UnblockRequestStatus::Denied, None)
+            .await,
+        Err(DomainError::UnblockRequestNotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_repeated_request_keeps_review() {
+    let repo = SqliteUnblockRequestRepository::new(create_test_db().await);
+    let stored = repo
+        .upsert(&request("ads.example.com", "10.0.0.7", None))
+        .await
+        .unwrap();
+    repo.review(stored.id.unwrap(), UnblockRequestStatus::Denied, None)
+        .await
+        .unwrap();
+
+    let again = repo
+        .upsert(&request("ads.example.com", "10.0.0.7", Some("really")))
+        .await
+        .unwrap();
+
+    assert_eq!(again.id, stored.id);
+    assert_eq!(again.status, UnblockRequestStatus::Denied);
+    assert!(again.message.is_none());
+    assert_eq!(repo.count_pending().await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_delete_removes_request() {
+    let repo = SqliteUnblockRequestRepository::new(create_test_db().await);
+    let stored = repo
+        .upsert(&request("ads.example.com", "10.0.0.7", None))
+        .await
+        .unwrap();
+    let id = stored.id.unwrap();
+
+    repo.delete(id).await.unwrap();
+
+    assert!(repo.get_by_id(id).await.unwrap().is_none());
+    assert!(matches!(
+        repo.delete(id).await,
+        Err(DomainError::UnblockRequestNotFound(missing)) if missing == id
+    ));
+}
@@ -0,0 +1,206 @@
+use bytes::Bytes;
+use ferrous_dns_domain::{RecordType, UdpSocketMode, UpstreamPool, UpstreamStrategy};
+use ferrous_dns_infrastructure::dns::events::QueryEventEmitter;
+use ferrous_dns_infrastructure::dns::forwarding::{CookieReply, MessageBuilder, UpstreamCookieJar};
+use ferrous_dns_infrastructure::dns::load_balancer::PoolManager;
+use hickory_proto::op::{Edns, Message, MessageType, OpCode};
+use hickory_proto::rr::rdata::opt::EdnsOption;
+use hickory_proto::rr::rdata::A;
+use hickory_proto::rr::{RData, Record};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio::net::UdpSocket;
+
+const SERVER: SocketAddr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 53)), 53);
+const SERVER_COOKIE: [u8; 8] = [0x5A; 8];
+
+fn query() -> Vec<u8> {
+    MessageBuilder::build_query("example.com", &RecordType::A, false).unwrap()
+}
+
+fn cookie_of(message: &[u8]) -> Option<Vec<u8>> {
+    let message = Message::from_vec(message).unwrap();
+    message
+        .extensions()
+        .as_ref()?
+        .options()
+        .as_ref()
+        .iter()
+        .find_map(|(_, option)| match option {
+            EdnsOption::Unknown(10, data) => Some(data.clone()),
+            _ => None,
+        })
+}
+
+/// Answers `query`, echoing `cookie` and, with `bad_cookie`, setting the
+/// BADCOOKIE extended RCODE (23: OPT high bits 1, header low bits 7).
+fn respond(query: &[u8], cookie: Option<Vec<u8>>, bad_cookie: bool) -> Vec<u8> {
+    let query = Message::from_vec(query).unwrap();
+    let mut response = Message::new(query.id(), MessageType::Response, OpCode::Query);
+    response.add_query(query.queries()[0].clone());
+    if !bad_cookie {
+        response.add_answer(Record::from_rdata(
+            query.queries()[0].name().clone(),
+            300,
+            RData::A(A(Ipv4Addr::new(93, 184, 216, 34))),
+        ));
+    }
+    let mut edns = Edns::new();
+    let rdlen = cookie.as_ref().map_or(0, |c| c.len() + 4);
+    if let Some(cookie) = cookie {
+        edns.options_mut().insert(EdnsOption::Unknown(10, cookie));
+    }
+    response.set_edns(edns);
+
+    let mut bytes = response.to_vec().unwrap();
+    if bad_cookie {
+        // The OPT record is last: TTL (4), RDLENGTH (2), then the options.
+        let ttl_at = bytes.len() - rdlen - 6;
+        bytes[ttl_at] = 1;
+        bytes[3] = (bytes[3] & 0xF0) | 7;
+    }
+    bytes
+}
+
+fn server_reply(client_cookie: &[u8], server_cookie: &[u8]) -> Vec<u8> {
+    [client_cookie, server_cookie].concat()
+}
+
+#[test]
+fn test_attach_adds_stable_client_cookie_per_server() {
+    let jar = UpstreamCookieJar::new();
+    let other: SocketAddr = "198.51.100.53:53".parse().unwrap();
+
+    let first = cookie_of(&jar.attach(SERVER, &query()).unwrap()).unwrap();
+    let second = cookie_of(&jar.attach(SERVER, &query()).unwrap()).unwrap();
+    let elsewhere = cookie_of(&jar.attach(other, &query()).unwrap()).unwrap();
+
+    assert_eq!(first.len(), 8);
+    assert_eq!(first, second);
+    assert_ne!(first, elsewhere);
+}
+
+#[test]
+fn test_server_cookie_is_remembered_and_stripped() {
+    let jar = UpstreamCookieJar::new();
+    let sent = jar.attach(SERVER, &query()).unwrap();
+    let client = cookie_of(&sent).unwrap();
+
+    let response = respond(&sent, Some(server_reply(&client, &SERVER_COOKIE)), false);
+    let reply = jar.accept(SERVER, Bytes::from(response)).unwrap();
+
+    let CookieReply::Answer(bytes) = reply else {
+        panic!("expected an answer, got {reply:?}");
+    };
+    assert_eq!(cookie_of(&bytes), None);
+    assert_eq!(Message::from_vec(&bytes).unwrap().answers().len(), 1);
+    assert_eq!(
+        jar.server_cookie(SERVER).as_deref(),
+        Some(&SERVER_COOKIE[..])
+    );
+
+    let next = cookie_of(&jar.attach(SERVER, &query()).unwrap()).unwrap();
+    assert_eq!(next, server_reply(&client, &SERVER_COOKIE));
+}
+
+#[test]
+fn test_response_with_foreign_client_cookie_is_rejected() {
+    let jar = UpstreamCookieJar::new();
+    let sent = jar.attach(SERVER, &query()).unwrap();
+    let mut forged = cookie_of(&sent).unwrap();
+    forged[0] ^= 0xFF;
+
+    let response = respond(&sent, Some(server_reply(&forged, &SERVER_COOKIE)), false);
+
+    assert!(jar.accept(SERVER, Bytes::from(response)).is_err());
+    assert_eq!(jar.server_cookie(SERVER), None);
+}
+
+#[test]
+fn test_response_without_cookie_is_accepted() {
+    let jar = UpstreamCookieJar::new();
+    let sent = jar.attach(SERVER, &query()).unwrap();
+
+    let response = respond(&sent, None, false);
+
+    assert!(matches!(
+        jar.accept(SERVER, Bytes::from(response)),
+        Ok(CookieReply::Answer(_))
+    ));
+}
+
+#[test]
+fn test_bad_cookie_asks_for_retry_with_new_server_cookie() {
+    let jar = UpstreamCookieJar::new();
+    let sent = jar.attach(SERVER, &query()).unwrap();
+    let client = cookie_of(&sent).unwrap();
+
+    let response = respond(&sent, Some(server_reply(&client, &SERVER_COOKIE)), true);
+
+    assert!(matches!(
+        jar.accept(SERVER, Bytes::from(response)),
+        Ok(CookieReply::Retry)
+    ));
+    assert_eq!(
+        jar.server_cookie(SERVER).as_deref(),
+        Some(&SERVER_COOKIE[..])
+    );
+}
+
+/// UDP upstream that answers only queries carrying its server cookie and
+/// returns BADCOOKIE with the cookie otherwise.
+async fn spawn_cookie_enforcing_upstream() -> (SocketAddr, Arc<AtomicUsize>) {
+    let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let addr = socket.local_addr().unwrap();
+    let queries = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&queries);
+    tokio::spawn(async move {
+        let mut buf = [0u8; 512];
+        while let Ok((len, peer)) = socket.recv_from(&mut buf).await {
+            counter.fetch_add(1, Ordering::SeqCst);
+            let query = &buf[..len];
+            let cookie = cookie_of(query).unwrap_or_default();
+            let client = &cookie[..cookie.len().min(8)];
+            let valid = cookie.len() == 16 && cookie[8..] == SERVER_COOKIE;
+            let response = respond(query, Some(server_reply(client, &SERVER_COOKIE)), !valid);
+            let _ = socket.send_to(&response, peer).await;
+        }
+    });
+    (addr, queries)
+}
+
+#[tokio::test]
+async fn test_pool_learns_server_cookie_from_bad_cookie() {
+    let (upstream, queries) = spawn_cookie_enforcing_upstream().await;
+    let pm = PoolManager::new(
+        vec![UpstreamPool {
+            name: "cookies".into(),
+            strategy: UpstreamStrategy::Failover,
+            priority: 1,
+            servers: vec![format!("udp://{upstream}")],
+            weight: None,
+            tsig_key: None,
+            udp_socket: UdpSocketMode::Pooled,
+        }],
+        None,
+        QueryEventEmitter::new_disabled(),
+    )
+    .await
+    .unwrap()
+    .with_cookies(true);
+    let domain: Arc<str> = Arc::from("example.com");
+
+    let first = pm
+        .query(&domain, &RecordType::A, 1000, false)
+        .await
+        .unwrap();
+    assert_eq!(queries.load(Ordering::SeqCst), 2);
+    assert_eq!(first.response.addresses.len(), 1);
+    assert_eq!(cookie_of(&first.response.raw_bytes), None);
+
+    pm.query(&domain, &RecordType::A, 1000, false)
+        .await
+        .unwrap();
+    assert_eq!(queries.load(Ordering::SeqCst), 3);
+}
@@ -0,0 +1,96 @@
+use ferrous_dns_domain::{RecordType, UpstreamAddr};
+use ferrous_dns_infrastructure::dns::forwarding::MessageBuilder;
+use ferrous_dns_infrastructure::dns::transport::udp::UdpTransport;
+use ferrous_dns_infrastructure::dns::transport::{DnsTransport, UdpSocketPool};
+use std::collections::HashSet;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::net::UdpSocket;
+
+const QUERY: [u8; 12] = [0x12, 0x34, 0x01, 0x00, 0, 1, 0, 0, 0, 0, 0, 0];
+
+/// Echoes every datagram back and records the source port it came from.
+async fn port_recording_server() -> (SocketAddr, Arc<Mutex<Vec<u16>>>) {
+    let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let addr = socket.local_addr().unwrap();
+    let ports = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&ports);
+    tokio::spawn(async move {
+        let mut buf = [0u8; 512];
+        while let Ok((len, from)) = socket.recv_from(&mut buf).await {
+            recorded.lock().unwrap().push(from.port());
+            let _ = socket.send_to(&buf[..len], from).await;
+        }
+    });
+    (addr, ports)
+}
+
+#[test]
+fn test_query_ids_are_unpredictable() {
+    const SAMPLES: usize = 4096;
+    let ids: Vec<u16> = (0..SAMPLES)
+        .map(|_| {
+            MessageBuilder::build_query_with_id("example.com", &RecordType::A, false)
+                .unwrap()
+                .0
+        })
+        .collect();
+
+    // 4096 draws from 65536 IDs collide about 128 times.
+    let distinct = ids.iter().collect::<HashSet<_>>().len();
+    assert!(distinct > 3800, "only {distinct} distinct IDs in {SAMPLES}");
+
+    for bit in 0..16 {
+        let set = ids.iter().filter(|id| *id & (1 << bit) != 0).count();
+        assert!(
+            (1748..=2348).contains(&set),
+            "ID bit {bit} set in {set} of {SAMPLES} queries"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_per_query_sockets_use_random_source_ports() {
+    const SAMPLES: usize = 200;
+    let (server, ports) = port_recording_server().await;
+    let transport = UdpTransport::per_query(UpstreamAddr::Resolved(server));
+
+    for _ in 0..SAMPLES {
+        transport
+            .send(&QUERY, Duration::from_secs(1))
+            .await
+            .unwrap();
+    }
+
+    let ports = ports.lock().unwrap().clone();
+    assert_eq!(ports.len(), SAMPLES);
+    let distinct = ports.iter().collect::<HashSet<_>>().len();
+    assert!(
+        distinct > 190,
+        "only {distinct} distinct ports in {SAMPLES}"
+    );
+    assert!(ports.iter().all(|&port| port >= 1024));
+    // Linux hands out ephemeral ports from 32768 up; random ports also come
+    // from below that.
+    assert!(ports.iter().any(|&port| port < 32768));
+    assert!(ports.iter().any(|&port| port >= 32768));
+}
+
+#[tokio::test]
+async fn test_pooled_sockets_reuse_their_source_port() {
+    let (server, ports) = port_recording_server().await;
+    let pool = Arc::new(UdpSocketPool::new(4, 16));
+    let transport = UdpTransport::with_pool(UpstreamAddr::Resolved(server), pool);
+
+    for _ in 0..5 {
+        transport
+            .send(&QUERY, Duration::from_secs(1))
+            .await
+            .unwrap();
+    }
+
+    let ports = ports.lock().unwrap().clone();
+    assert_eq!(ports.len(), 5);
+    assert_eq!(ports.iter().collect::<HashSet<_>>().len(), 1);
+}
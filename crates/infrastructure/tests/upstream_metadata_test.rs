@@ -0,0 +1,166 @@
+use ferrous_dns_domain::{RecordType, UdpSocketMode, UpstreamPool, UpstreamStrategy};
+use ferrous_dns_infrastructure::dns::events::QueryEventEmitter;
+use ferrous_dns_infrastructure::dns::load_balancer::PoolManager;
+use hickory_proto::op::{Message, MessageType, OpCode};
+use hickory_proto::rr::rdata::A;
+use hickory_proto::rr::{RData, Record};
+use std::net::{Ipv4Addr, SocketAddr};
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, UdpSocket};
+
+fn answer_for(query_bytes: &[u8], truncated: bool) -> Vec<u8> {
+    let query = Message::from_vec(query_bytes).unwrap();
+    let mut response = Message::new(query.id(), MessageType::Response, OpCode::Query);
+    response.add_query(query.queries()[0].clone());
+    if truncated {
+        response.set_truncated(true);
+    } else {
+        response.add_answer(Record::from_rdata(
+            query.queries()[0].name().clone(),
+            300,
+            RData::A(A(Ipv4Addr::new(93, 184, 216, 34))),
+        ));
+    }
+    response.to_vec().unwrap()
+}
+
+/// UDP upstream that answers every query, optionally with the TC bit set.
+async fn spawn_udp_upstream(truncated: bool) -> SocketAddr {
+    let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let addr = socket.local_addr().unwrap();
+    tokio::spawn(async move {
+        let mut buf = [0u8; 512];
+        while let Ok((len, peer)) = socket.recv_from(&mut buf).await {
+            let _ = socket
+                .send_to(&answer_for(&buf[..len], truncated), peer)
+                .await;
+        }
+    });
+    addr
+}
+
+/// UDP upstream that accepts queries but never answers.
+async fn spawn_silent_upstream() -> SocketAddr {
+    let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let addr = socket.local_addr().unwrap();
+    tokio::spawn(async move {
+        let mut buf = [0u8; 512];
+        while socket.recv_from(&mut buf).await.is_ok() {}
+    });
+    addr
+}
+
+/// TCP upstream on the same port as a truncating UDP upstream.
+async fn spawn_truncating_upstream_with_tcp() -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let socket = UdpSocket::bind(addr).await.unwrap();
+    tokio::spawn(async move {
+        let mut buf = [0u8; 512];
+        while let Ok((len, peer)) = socket.recv_from(&mut buf).await {
+            let _ = socket.send_to(&answer_for(&buf[..len], true), peer).await;
+        }
+    });
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            tokio::spawn(async move {
+                loop {
+                    let Ok(len) = stream.read_u16().await else {
+                        return;
+                    };
+                    let mut query = vec![0u8; len as usize];
+                    if stream.read_exact(&mut query).await.is_err() {
+                        return;
+                    }
+                    let response = answer_for(&query, false);
+                    let _ = stream.write_u16(response.len() as u16).await;
+                    let _ = stream.write_all(&response).await;
+                }
+            });
+        }
+    });
+    addr
+}
+
+fn pool(name: &str, strategy: UpstreamStrategy, servers: &[SocketAddr]) -> UpstreamPool {
+    UpstreamPool {
+        name: name.into(),
+        strategy,
+        priority: 1,
+        servers: servers.iter().map(|s| format!("udp://{s}")).collect(),
+        weight: None,
+        tsig_key: None,
+        udp_socket: UdpSocketMode::Pooled,
+    }
+}
+
+#[tokio::test]
+async fn test_failover_reports_strategy_and_attempt() {
+    let silent = spawn_silent_upstream().await;
+    let live = spawn_udp_upstream(false).await;
+    let (emitter, mut events) = QueryEventEmitter::new_enabled();
+    let pm = PoolManager::new(
+        vec![pool("primary", UpstreamStrategy::Failover, &[silent, live])],
+        None,
+        emitter,
+    )
+    .await
+    .unwrap();
+
+    let domain: Arc<str> = Arc::from("example.com");
+    let result = pm.query(&domain, &RecordType::A, 300, false).await.unwrap();
+
+    assert_eq!(&*result.pool_name, "primary");
+    assert_eq!(result.strategy, "failover");
+    assert_eq!(result.attempt, 2);
+    assert_eq!(result.protocol, "UDP");
+
+    let event = events.recv().await.unwrap();
+    assert_eq!(event.pool_name.as_deref(), Some("primary"));
+    assert_eq!(event.strategy, "failover");
+    assert_eq!(event.attempt, 2);
+    assert_eq!(event.protocol, "UDP");
+}
+
+#[tokio::test]
+async fn test_parallel_reports_single_attempt() {
+    let first = spawn_udp_upstream(false).await;
+    let second = spawn_udp_upstream(false).await;
+    let pm = PoolManager::new(
+        vec![pool("race", UpstreamStrategy::Parallel, &[first, second])],
+        None,
+        QueryEventEmitter::new_disabled(),
+    )
+    .await
+    .unwrap();
+
+    let domain: Arc<str> = Arc::from("example.com");
+    let result = pm.query(&domain, &RecordType::A, 500, false).await.unwrap();
+
+    assert_eq!(result.strategy, "parallel");
+    assert_eq!(result.attempt, 1);
+}
+
+#[tokio::test]
+async fn test_truncated_answer_reports_tcp_protocol() {
+    let upstream = spawn_truncating_upstream_with_tcp().await;
+    let pm = PoolManager::new(
+        vec![pool("tc", UpstreamStrategy::Balanced, &[upstream])],
+        None,
+        QueryEventEmitter::new_disabled(),
+    )
+    .await
+    .unwrap();
+
+    let domain: Arc<str> = Arc::from("example.com");
+    let result = pm
+        .query(&domain, &RecordType::A, 1000, false)
+        .await
+        .unwrap();
+
+    assert_eq!(result.strategy, "balanced");
+    assert_eq!(result.attempt, 1);
+    assert_eq!(result.protocol, "TCP");
+    assert!(!result.response.addresses.is_empty());
+}
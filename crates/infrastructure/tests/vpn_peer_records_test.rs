@@ -0,0 +1,149 @@
+use ferrous_dns_application::ports::RecordSource;
+use ferrous_dns_domain::{LocalRecord, RecordType, VpnPeerProvider, VpnPeersConfig};
+use ferrous_dns_infrastructure::discovery::{
+    tailscale_records, wireguard_records, TailscaleStatus, VpnPeerSource,
+};
+use serde_json::json;
+use std::io::Write;
+
+fn summary(records: &[LocalRecord]) -> Vec<(String, RecordType, String)> {
+    let mut summary: Vec<_> = records
+        .iter()
+        .map(|r| (r.fqdn(&None), r.record_type, r.value.to_string()))
+        .collect();
+    summary.sort_by(|a, b| a.0.cmp(&b.0).then(a.2.cmp(&b.2)));
+    summary
+}
+
+fn entry(name: &str, record_type: RecordType, value: &str) -> (String, RecordType, String) {
+    (name.to_string(), record_type, value.to_string())
+}
+
+#[test]
+fn test_tailnet_nodes_are_published_by_magicdns_name() {
+    let status: TailscaleStatus = serde_json::from_value(json!({
+        "Self": {
+            "HostName": "pi",
+            "DNSName": "pi.tail1234.ts.net.",
+            "TailscaleIPs": ["100.64.0.1", "fd7a:115c:a1e0::1"]
+        },
+        "Peer": {
+            "nodekey:abc": {
+                "HostName": "Anna's MacBook Pro",
+                "DNSName": "annas-macbook-pro.tail1234.ts.net.",
+                "TailscaleIPs": ["100.64.0.2"]
+            },
+            "nodekey:def": {
+                "HostName": "Phone",
+                "DNSName": "",
+                "TailscaleIPs": ["100.64.0.3"]
+            }
+        }
+    }))
+    .unwrap();
+
+    let records = tailscale_records(&VpnPeersConfig::default(), &status);
+
+    assert_eq!(
+        summary(&records),
+        vec![
+            entry("annas-macbook-pro.vpn.lan", RecordType::A, "100.64.0.2"),
+            entry("phone.vpn.lan", RecordType::A, "100.64.0.3"),
+            entry("pi.vpn.lan", RecordType::A, "100.64.0.1"),
+            entry("pi.vpn.lan", RecordType::AAAA, "fd7a:115c:a1e0::1"),
+        ]
+    );
+}
+
+#[test]
+fn test_tailnet_without_peers() {
+    let status: TailscaleStatus = serde_json::from_value(json!({
+        "Self": { "DNSName": "pi.tail1234.ts.net.", "TailscaleIPs": ["100.64.0.1"] },
+        "Peer": null
+    }))
+    .unwrap();
+
+    let records = tailscale_records(&VpnPeersConfig::default(), &status);
+
+    assert_eq!(records.len(), 1);
+}
+
+const WG_CONF: &str = "\
+[Interface]
+# Name = server
+Address = 10.8.0.1/24
+ListenPort = 51820
+
+[Peer]
+# Name = Laptop
+PublicKey = aaaa
+AllowedIPs = 10.8.0.2/32, fd42::2/128
+
+### begin phone ###
+[Peer]
+PublicKey = bbbb
+AllowedIPs = 10.8.0.3/32
+### end phone ###
+
+[Peer]
+# friendly_name = site-b
+PublicKey = cccc
+AllowedIPs = 10.8.0.4/32, 192.168.50.0/24
+
+[Peer]
+PublicKey = dddd
+AllowedIPs = 10.8.0.5/32
+";
+
+#[test]
+fn test_named_wireguard_peers_are_published() {
+    let records = wireguard_records(&VpnPeersConfig::default(), WG_CONF);
+
+    assert_eq!(
+        summary(&records),
+        vec![
+            entry("laptop.vpn.lan", RecordType::A, "10.8.0.2"),
+            entry("laptop.vpn.lan", RecordType::AAAA, "fd42::2"),
+            entry("phone.vpn.lan", RecordType::A, "10.8.0.3"),
+            entry("site-b.vpn.lan", RecordType::A, "10.8.0.4"),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_wireguard_source_reads_the_config_file() {
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    file.write_all(WG_CONF.as_bytes()).unwrap();
+    let config = VpnPeersConfig {
+        enabled: true,
+        provider: VpnPeerProvider::Wireguard,
+        wireguard_config: file.path().to_string_lossy().into_owned(),
+        ttl: 120,
+        ..Default::default()
+    };
+    let source = VpnPeerSource::new(&config);
+
+    let records = source.fetch().await.unwrap();
+
+    assert_eq!(source.name(), "wireguard");
+    assert_eq!(records.len(), 4);
+    assert!(records.iter().all(|r| r.ttl == 120));
+}
+
+#[tokio::test]
+async fn test_missing_tailscale_socket_is_an_error() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = VpnPeersConfig {
+        enabled: true,
+        tailscale_socket: dir
+            .path()
+            .join("tailscaled.sock")
+            .to_string_lossy()
+            .into_owned(),
+        ..Default::default()
+    };
+
+    let error = VpnPeerSource::new(&config).fetch().await.unwrap_err();
+
+    assert!(error.to_string().contains("tailscale"));
+}
@@ -1,4 +1,4 @@
-use ferrous_dns_infrastructure::dns::wire_response::patch_wire_id;
+use ferrous_dns_infrastructure::dns::wire_response::{answer_count, patch_wire_id};
 
 #[test]
 fn patch_wire_id_overwrites_first_two_bytes() {
@@ -42,3 +42,14 @@ fn patch_wire_id_id_zero() {
     assert_eq!(patched[1], 0x00);
     assert_eq!(&patched[2..], &[0x01, 0x02]);
 }
+
+#[test]
+fn answer_count_reads_ancount() {
+    let wire = [0x12, 0x34, 0x81, 0x80, 0x00, 0x01, 0x01, 0x02, 0x00, 0x00];
+    assert_eq!(answer_count(&wire), Some(0x0102));
+}
+
+#[test]
+fn answer_count_returns_none_for_short_header() {
+    assert!(answer_count(&[0x12, 0x34, 0x81, 0x80, 0x00, 0x01, 0x01]).is_none());
+}
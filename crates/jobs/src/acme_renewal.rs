@@ -0,0 +1,73 @@
+use ferrous_dns_application::ports::AcmeCertificatePort;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, error, info};
+
+/// Pause before retrying a failed order, kept well above Let's Encrypt's
+/// failed-validation rate limit.
+const RETRY_DELAY: Duration = Duration::from_secs(3600);
+
+/// Checks the ACME certificate at startup and then every `interval_secs`,
+/// ordering a new one when it is missing or close to expiry.
+pub struct AcmeRenewalJob {
+    certificates: Arc<dyn AcmeCertificatePort>,
+    interval_secs: u64,
+    shutdown: CancellationToken,
+}
+
+impl AcmeRenewalJob {
+    pub fn new(certificates: Arc<dyn AcmeCertificatePort>) -> Self {
+        Self {
+            certificates,
+            interval_secs: 43_200,
+            shutdown: CancellationToken::new(),
+        }
+    }
+
+    pub fn with_interval(mut self, interval_secs: u64) -> Self {
+        self.interval_secs = interval_secs;
+        self
+    }
+
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.shutdown = token;
+        self
+    }
+
+    pub async fn start(self: Arc<Self>) {
+        info!(
+            interval_secs = self.interval_secs,
+            "Starting ACME certificate renewal job"
+        );
+
+        tokio::spawn(async move {
+            let interval = Duration::from_secs(self.interval_secs);
+            loop {
+                let wait = tokio::select! {
+                    _ = self.shutdown.cancelled() => break,
+                    result = self.certificates.renew_if_due() => match result {
+                        Ok(true) => {
+                            info!("ACME certificate renewed");
+                            interval
+                        }
+                        Ok(false) => {
+                            debug!("ACME certificate not due for renewal");
+                            interval
+                        }
+                        Err(e) => {
+                            error!(error = %e, "ACME certificate renewal failed");
+                            interval.min(RETRY_DELAY)
+                        }
+                    },
+                };
+
+                tokio::select! {
+                    _ = self.shutdown.cancelled() => break,
+                    _ = tokio::time::sleep(wait) => {}
+                }
+            }
+            info!("AcmeRenewalJob: shutting down");
+        });
+    }
+}
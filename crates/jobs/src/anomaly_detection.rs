@@ -0,0 +1,53 @@
+use ferrous_dns_application::use_cases::DetectAnomaliesUseCase;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, error, info};
+
+/// Runs anomaly detection once per analysis window and prunes expired alerts.
+pub struct AnomalyDetectionJob {
+    detector: Arc<DetectAnomaliesUseCase>,
+    shutdown: CancellationToken,
+}
+
+impl AnomalyDetectionJob {
+    pub fn new(detector: Arc<DetectAnomaliesUseCase>) -> Self {
+        Self {
+            detector,
+            shutdown: CancellationToken::new(),
+        }
+    }
+
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.shutdown = token;
+        self
+    }
+
+    pub async fn start(self: Arc<Self>) {
+        let interval_secs = self.detector.interval_secs();
+        info!(interval_secs, "Starting anomaly detection job");
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+            loop {
+                tokio::select! {
+                    _ = self.shutdown.cancelled() => {
+                        info!("AnomalyDetectionJob: shutting down");
+                        break;
+                    }
+                    _ = interval.tick() => {
+                        match self.detector.execute().await {
+                            Ok(alerts) => debug!(alerts = alerts.len(), "Anomaly detection completed"),
+                            Err(e) => error!(error = %e, "Anomaly detection failed"),
+                        }
+                        if let Err(e) = self.detector.purge_expired().await {
+                            error!(error = %e, "Failed to purge expired alerts");
+                        }
+                    }
+                }
+            }
+        });
+    }
+}
@@ -1,4 +1,6 @@
+use crate::NotificationBus;
 use ferrous_dns_application::ports::BlockFilterEnginePort;
+use ferrous_dns_domain::{Notification, NotificationKind};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio_util::sync::CancellationToken;
@@ -6,6 +8,7 @@ use tracing::{error, info};
 
 pub struct BlocklistSyncJob {
     engine: Arc<dyn BlockFilterEnginePort>,
+    notifications: Option<NotificationBus>,
     interval_secs: u64,
     shutdown: CancellationToken,
 }
@@ -14,6 +17,7 @@ impl BlocklistSyncJob {
     pub fn new(engine: Arc<dyn BlockFilterEnginePort>) -> Self {
         Self {
             engine,
+            notifications: None,
             interval_secs: 86400,
             shutdown: CancellationToken::new(),
         }
@@ -24,6 +28,11 @@ impl BlocklistSyncJob {
         self
     }
 
+    pub fn with_notifications(mut self, bus: NotificationBus) -> Self {
+        self.notifications = Some(bus);
+        self
+    }
+
     pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
         self.shutdown = token;
         self
@@ -49,7 +58,17 @@ impl BlocklistSyncJob {
                         info!("BlocklistSyncJob: reloading blocklist sources");
                         match self.engine.reload().await {
                             Ok(()) => info!("BlocklistSyncJob: reload completed successfully"),
-                            Err(e) => error!(error = %e, "BlocklistSyncJob: reload failed"),
+                            Err(e) => {
+                                error!(error = %e, "BlocklistSyncJob: reload failed");
+                                if let Some(bus) = &self.notifications {
+                                    bus.publish(Notification::new(
+                                        NotificationKind::BlocklistUpdateFailed,
+                                        "blocklists",
+                                        "Blocklist update failed",
+                                        e.to_string(),
+                                    ));
+                                }
+                            }
                         }
                     }
                 }
@@ -57,6 +57,7 @@ impl CacheMaintenanceJob {
                                         candidates = outcome.candidates_found,
                                         refreshed = outcome.refreshed,
                                         failed = outcome.failed,
+                                        deferred = outcome.deferred,
                                         cache_size = outcome.cache_size,
                                         "Cache refresh cycle completed"
                                     );
@@ -1,4 +1,6 @@
-use ferrous_dns_application::use_cases::{SyncArpCacheUseCase, SyncHostnamesUseCase};
+use ferrous_dns_application::use_cases::{
+    MergeDuplicateClientsUseCase, SyncArpCacheUseCase, SyncHostnamesUseCase,
+};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio_util::sync::CancellationToken;
@@ -7,6 +9,7 @@ use tracing::{error, info};
 pub struct ClientSyncJob {
     sync_arp: Arc<SyncArpCacheUseCase>,
     sync_hostnames: Arc<SyncHostnamesUseCase>,
+    merge_duplicates: Option<Arc<MergeDuplicateClientsUseCase>>,
     arp_interval_secs: u64,
     hostname_interval_secs: u64,
     shutdown: CancellationToken,
@@ -20,6 +23,7 @@ impl ClientSyncJob {
         Self {
             sync_arp,
             sync_hostnames,
+            merge_duplicates: None,
             arp_interval_secs: 60,
             hostname_interval_secs: 300,
             shutdown: CancellationToken::new(),
@@ -32,6 +36,11 @@ impl ClientSyncJob {
         self
     }
 
+    pub fn with_merge_duplicates(mut self, merge: Arc<MergeDuplicateClientsUseCase>) -> Self {
+        self.merge_duplicates = Some(merge);
+        self
+    }
+
     pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
         self.shutdown = token;
         self
@@ -55,6 +64,11 @@ impl ClientSyncJob {
                         if let Err(e) = arp_job.sync_arp.execute().await {
                             error!(error = %e, "ARP sync failed");
                         }
+                        if let Some(ref merge) = arp_job.merge_duplicates {
+                            if let Err(e) = merge.execute().await {
+                                error!(error = %e, "Duplicate client merge failed");
+                            }
+                        }
                     }
                 }
             }
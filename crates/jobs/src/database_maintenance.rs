@@ -0,0 +1,66 @@
+use ferrous_dns_application::use_cases::DatabaseMaintenanceUseCase;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info};
+
+pub struct DatabaseMaintenanceJob {
+    maintenance: Arc<DatabaseMaintenanceUseCase>,
+    interval_secs: u64,
+    shutdown: CancellationToken,
+}
+
+impl DatabaseMaintenanceJob {
+    pub fn new(maintenance: Arc<DatabaseMaintenanceUseCase>) -> Self {
+        Self {
+            maintenance,
+            interval_secs: 3600,
+            shutdown: CancellationToken::new(),
+        }
+    }
+
+    pub fn with_interval(mut self, interval_secs: u64) -> Self {
+        self.interval_secs = interval_secs;
+        self
+    }
+
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.shutdown = token;
+        self
+    }
+
+    pub async fn start(self: Arc<Self>) {
+        info!(
+            interval_secs = self.interval_secs,
+            "Starting database maintenance job"
+        );
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(self.interval_secs));
+            loop {
+                tokio::select! {
+                    _ = self.shutdown.cancelled() => {
+                        info!("DatabaseMaintenanceJob: shutting down");
+                        break;
+                    }
+                    _ = interval.tick() => {
+                        match self.maintenance.run().await {
+                            Ok(report) => {
+                                info!(
+                                    trimmed_rows = report.trimmed_rows,
+                                    reclaimed_bytes = report.reclaimed_bytes,
+                                    file_bytes = report.size_after.file_bytes,
+                                    duration_ms = report.duration_ms,
+                                    "Database maintenance completed"
+                                );
+                            }
+                            Err(e) => {
+                                error!(error = %e, "Database maintenance failed");
+                            }
+                        }
+                    }
+                }
+            }
+        });
+    }
+}
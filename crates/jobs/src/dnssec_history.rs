@@ -0,0 +1,64 @@
+use ferrous_dns_application::use_cases::RecordDnssecHistoryUseCase;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info};
+
+/// Every `interval_secs`, stores the DNSSEC outcomes counted since the last
+/// run and the status changes seen, then prunes old history.
+pub struct DnssecHistoryJob {
+    history: Arc<RecordDnssecHistoryUseCase>,
+    interval_secs: u64,
+    shutdown: CancellationToken,
+}
+
+impl DnssecHistoryJob {
+    pub fn new(history: Arc<RecordDnssecHistoryUseCase>) -> Self {
+        Self {
+            history,
+            interval_secs: 300,
+            shutdown: CancellationToken::new(),
+        }
+    }
+
+    pub fn with_interval(mut self, interval_secs: u64) -> Self {
+        self.interval_secs = interval_secs;
+        self
+    }
+
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.shutdown = token;
+        self
+    }
+
+    pub async fn start(self: Arc<Self>) {
+        info!(
+            interval_secs = self.interval_secs,
+            "Starting DNSSEC history job"
+        );
+
+        tokio::spawn(async move {
+            let period = Duration::from_secs(self.interval_secs);
+            let mut interval =
+                tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+            loop {
+                tokio::select! {
+                    _ = self.shutdown.cancelled() => {
+                        info!("DnssecHistoryJob: shutting down");
+                        break;
+                    }
+                    _ = interval.tick() => {
+                        if let Err(e) = self.history.execute().await {
+                            error!(error = %e, "Failed to record DNSSEC history");
+                        }
+                        if let Err(e) = self.history.purge_expired().await {
+                            error!(error = %e, "Failed to purge old DNSSEC history");
+                        }
+                    }
+                }
+            }
+        });
+    }
+}
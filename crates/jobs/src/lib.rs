@@ -1,27 +1,53 @@
+pub mod acme_renewal;
+pub mod anomaly_detection;
 pub mod blocklist_sync;
 pub mod cache_maintenance;
 pub mod client_sync;
+pub mod database_maintenance;
 pub mod dga_eviction;
+pub mod dnssec_history;
+pub mod metrics_export;
+pub mod notification_bus;
+pub mod notification_dispatch;
+pub mod notification_monitor;
 pub mod nxdomain_hijack_eviction;
 pub mod query_log_retention;
+pub mod record_source_sync;
 pub mod response_ip_filter_eviction;
 pub mod retention;
 pub mod runner;
 pub mod schedule_evaluator;
+pub mod secondary_zone_refresh;
 pub mod session_cleanup;
+pub mod standby_sync;
 pub mod tunneling_eviction;
+pub mod upstream_address_refresh;
+pub mod usage_report;
 pub mod wal_checkpoint;
 
+pub use acme_renewal::AcmeRenewalJob;
+pub use anomaly_detection::AnomalyDetectionJob;
 pub use blocklist_sync::BlocklistSyncJob;
 pub use cache_maintenance::CacheMaintenanceJob;
 pub use client_sync::ClientSyncJob;
+pub use database_maintenance::DatabaseMaintenanceJob;
 pub use dga_eviction::DgaEvictionJob;
+pub use dnssec_history::DnssecHistoryJob;
+pub use metrics_export::MetricsExportJob;
+pub use notification_bus::NotificationBus;
+pub use notification_dispatch::NotificationDispatchJob;
+pub use notification_monitor::NotificationMonitorJob;
 pub use nxdomain_hijack_eviction::NxdomainHijackEvictionJob;
 pub use query_log_retention::QueryLogRetentionJob;
+pub use record_source_sync::RecordSourceSyncJob;
 pub use response_ip_filter_eviction::ResponseIpFilterEvictionJob;
 pub use retention::RetentionJob;
 pub use runner::JobRunner;
 pub use schedule_evaluator::ScheduleEvaluatorJob;
+pub use secondary_zone_refresh::SecondaryZoneRefreshJob;
 pub use session_cleanup::SessionCleanupJob;
+pub use standby_sync::StandbySyncJob;
 pub use tunneling_eviction::TunnelingEvictionJob;
+pub use upstream_address_refresh::UpstreamAddressRefreshJob;
+pub use usage_report::UsageReportJob;
 pub use wal_checkpoint::WalCheckpointJob;
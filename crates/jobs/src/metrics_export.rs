@@ -0,0 +1,53 @@
+use ferrous_dns_application::use_cases::ExportMetricsUseCase;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+/// Pushes one window of query aggregates to the time-series database per
+/// export interval.
+pub struct MetricsExportJob {
+    exporter: Arc<ExportMetricsUseCase>,
+    shutdown: CancellationToken,
+}
+
+impl MetricsExportJob {
+    pub fn new(exporter: Arc<ExportMetricsUseCase>) -> Self {
+        Self {
+            exporter,
+            shutdown: CancellationToken::new(),
+        }
+    }
+
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.shutdown = token;
+        self
+    }
+
+    pub async fn start(self: Arc<Self>) {
+        let interval_secs = self.exporter.interval_secs();
+        info!(interval_secs, "Starting metrics export job");
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            // The first tick fires at once; skip it so every export covers a
+            // full window.
+            interval.tick().await;
+
+            loop {
+                tokio::select! {
+                    _ = self.shutdown.cancelled() => {
+                        info!("MetricsExportJob: shutting down");
+                        break;
+                    }
+                    _ = interval.tick() => {
+                        if let Err(e) = self.exporter.execute().await {
+                            warn!(error = %e, "Metrics export failed");
+                        }
+                    }
+                }
+            }
+        });
+    }
+}
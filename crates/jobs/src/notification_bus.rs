@@ -0,0 +1,35 @@
+use ferrous_dns_domain::Notification;
+use tokio::sync::broadcast;
+use tracing::debug;
+
+/// In-process event bus that jobs publish notifications to.
+///
+/// Cheap to clone; publishing never blocks and is a no-op when nothing is
+/// subscribed.
+#[derive(Clone)]
+pub struct NotificationBus {
+    sender: broadcast::Sender<Notification>,
+}
+
+impl NotificationBus {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        Self { sender }
+    }
+
+    pub fn publish(&self, notification: Notification) {
+        if self.sender.send(notification).is_err() {
+            debug!("NotificationBus: no subscribers, notification dropped");
+        }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<Notification> {
+        self.sender.subscribe()
+    }
+}
+
+impl Default for NotificationBus {
+    fn default() -> Self {
+        Self::new(256)
+    }
+}
@@ -0,0 +1,112 @@
+use crate::NotificationBus;
+use ferrous_dns_application::ports::NotificationSender;
+use ferrous_dns_domain::{Notification, NotificationEventsConfig, NotificationKind};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+
+/// Delivers bus notifications to every configured sender, honouring the
+/// per-event enable flags and a cooldown per event and subject.
+pub struct NotificationDispatchJob {
+    receiver: tokio::sync::Mutex<broadcast::Receiver<Notification>>,
+    senders: Vec<Arc<dyn NotificationSender>>,
+    events: NotificationEventsConfig,
+    cooldown: Duration,
+    last_sent: Mutex<HashMap<(NotificationKind, String), Instant>>,
+    shutdown: CancellationToken,
+}
+
+impl NotificationDispatchJob {
+    pub fn new(bus: &NotificationBus, senders: Vec<Arc<dyn NotificationSender>>) -> Self {
+        Self {
+            receiver: tokio::sync::Mutex::new(bus.subscribe()),
+            senders,
+            events: NotificationEventsConfig::default(),
+            cooldown: Duration::from_secs(900),
+            last_sent: Mutex::new(HashMap::new()),
+            shutdown: CancellationToken::new(),
+        }
+    }
+
+    pub fn with_events(mut self, events: NotificationEventsConfig) -> Self {
+        self.events = events;
+        self
+    }
+
+    pub fn with_cooldown(mut self, cooldown_secs: u64) -> Self {
+        self.cooldown = Duration::from_secs(cooldown_secs);
+        self
+    }
+
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.shutdown = token;
+        self
+    }
+
+    pub async fn start(self: Arc<Self>) {
+        info!(
+            senders = self.senders.len(),
+            cooldown_secs = self.cooldown.as_secs(),
+            "Starting notification dispatch job"
+        );
+
+        tokio::spawn(async move {
+            let mut receiver = self.receiver.lock().await;
+            loop {
+                tokio::select! {
+                    _ = self.shutdown.cancelled() => {
+                        info!("NotificationDispatchJob: shutting down");
+                        break;
+                    }
+                    received = receiver.recv() => match received {
+                        Ok(notification) => self.dispatch(&notification).await,
+                        Err(RecvError::Lagged(skipped)) => {
+                            warn!(skipped, "NotificationDispatchJob: lagging, notifications dropped");
+                        }
+                        Err(RecvError::Closed) => break,
+                    }
+                }
+            }
+        });
+    }
+
+    async fn dispatch(&self, notification: &Notification) {
+        if !self.events.is_enabled(notification.event) || !self.claim(notification) {
+            debug!(
+                event = notification.event.as_str(),
+                subject = %notification.subject,
+                "NotificationDispatchJob: suppressed"
+            );
+            return;
+        }
+
+        for sender in &self.senders {
+            if let Err(e) = sender.send(notification).await {
+                warn!(
+                    sender = sender.name(),
+                    event = notification.event.as_str(),
+                    error = %e,
+                    "Failed to deliver notification"
+                );
+            }
+        }
+    }
+
+    /// Records the send time, or returns `false` while the event and subject
+    /// are still cooling down.
+    fn claim(&self, notification: &Notification) -> bool {
+        let key = (notification.event, notification.subject.clone());
+        let now = Instant::now();
+        let mut last_sent = self.last_sent.lock().unwrap_or_else(|e| e.into_inner());
+        match last_sent.get(&key) {
+            Some(at) if now.duration_since(*at) < self.cooldown => false,
+            _ => {
+                last_sent.insert(key, now);
+                true
+            }
+        }
+    }
+}
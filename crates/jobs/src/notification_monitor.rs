@@ -0,0 +1,243 @@
+use crate::NotificationBus;
+use chrono::Utc;
+use ferrous_dns_application::ports::{
+    AggregateStatus, DatabaseMaintenancePort, QueryLogRepository, UpstreamHealthPort,
+};
+use ferrous_dns_domain::{Notification, NotificationKind, QueryLogFilter};
+use std::collections::{BTreeMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+const SPIKE_CLIENT_LIMIT: u32 = 10;
+
+/// Periodically checks upstream health, disk usage and the query log, and
+/// publishes a notification for each condition found. Cooldowns are left to
+/// the dispatcher.
+pub struct NotificationMonitorJob {
+    bus: NotificationBus,
+    upstream_health: Option<Arc<dyn UpstreamHealthPort>>,
+    query_log: Option<Arc<dyn QueryLogRepository>>,
+    database: Option<Arc<dyn DatabaseMaintenancePort>>,
+    disk_usage_percent: u8,
+    client_block_spike_threshold: u64,
+    degraded_pools: Mutex<HashSet<String>>,
+    interval_secs: u64,
+    shutdown: CancellationToken,
+}
+
+impl NotificationMonitorJob {
+    pub fn new(bus: NotificationBus) -> Self {
+        Self {
+            bus,
+            upstream_health: None,
+            query_log: None,
+            database: None,
+            disk_usage_percent: 90,
+            client_block_spike_threshold: 500,
+            degraded_pools: Mutex::new(HashSet::new()),
+            interval_secs: 60,
+            shutdown: CancellationToken::new(),
+        }
+    }
+
+    pub fn with_interval(mut self, interval_secs: u64) -> Self {
+        self.interval_secs = interval_secs;
+        self
+    }
+
+    pub fn with_upstream_health(mut self, upstream_health: Arc<dyn UpstreamHealthPort>) -> Self {
+        self.upstream_health = Some(upstream_health);
+        self
+    }
+
+    /// Enables the client block spike and DNSSEC Bogus checks.
+    pub fn with_query_log(
+        mut self,
+        query_log: Arc<dyn QueryLogRepository>,
+        client_block_spike_threshold: u64,
+    ) -> Self {
+        self.query_log = Some(query_log);
+        self.client_block_spike_threshold = client_block_spike_threshold;
+        self
+    }
+
+    pub fn with_database(
+        mut self,
+        database: Arc<dyn DatabaseMaintenancePort>,
+        disk_usage_percent: u8,
+    ) -> Self {
+        self.database = Some(database);
+        self.disk_usage_percent = disk_usage_percent;
+        self
+    }
+
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.shutdown = token;
+        self
+    }
+
+    pub async fn start(self: Arc<Self>) {
+        info!(
+            interval_secs = self.interval_secs,
+            "Starting notification monitor job"
+        );
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(self.interval_secs));
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+            loop {
+                tokio::select! {
+                    _ = self.shutdown.cancelled() => {
+                        info!("NotificationMonitorJob: shutting down");
+                        break;
+                    }
+                    _ = interval.tick() => {
+                        self.check_upstreams();
+                        self.check_disk().await;
+                        self.check_query_log().await;
+                    }
+                }
+            }
+        });
+    }
+
+    /// Publishes once when a pool turns degraded; it re-arms after the pool
+    /// recovers.
+    fn check_upstreams(&self) {
+        let Some(upstream_health) = &self.upstream_health else {
+            return;
+        };
+
+        let mut pools: BTreeMap<String, (usize, Vec<String>)> = BTreeMap::new();
+        for server in upstream_health.get_grouped_upstream_health() {
+            let (total, failing) = pools.entry(server.pool_name).or_default();
+            *total += 1;
+            match server.status {
+                AggregateStatus::Unhealthy => failing.push(format!("{} (down)", server.address)),
+                AggregateStatus::Partial => failing.push(format!("{} (partial)", server.address)),
+                AggregateStatus::Healthy | AggregateStatus::Unknown => {}
+            }
+        }
+
+        let mut degraded = self
+            .degraded_pools
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        for (pool, (total, failing)) in pools {
+            if failing.is_empty() {
+                degraded.remove(&pool);
+                continue;
+            }
+            if degraded.insert(pool.clone()) {
+                self.bus.publish(Notification::new(
+                    NotificationKind::UpstreamDegraded,
+                    pool.as_str(),
+                    format!("Upstream pool '{}' degraded", pool),
+                    format!(
+                        "{} of {} servers failing: {}",
+                        failing.len(),
+                        total,
+                        failing.join(", ")
+                    ),
+                ));
+            }
+        }
+    }
+
+    async fn check_disk(&self) {
+        let Some(database) = &self.database else {
+            return;
+        };
+        let usage = match database.usage().await {
+            Ok(usage) => usage,
+            Err(e) => {
+                warn!(error = %e, "NotificationMonitorJob: failed to read disk usage");
+                return;
+            }
+        };
+        if usage.disk_total_bytes == 0 {
+            return;
+        }
+
+        let used = usage
+            .disk_total_bytes
+            .saturating_sub(usage.disk_available_bytes);
+        let percent = used * 100 / usage.disk_total_bytes;
+        if percent >= self.disk_usage_percent as u64 {
+            self.bus.publish(Notification::new(
+                NotificationKind::DiskNearlyFull,
+                "database",
+                format!("Disk {}% full", percent),
+                format!(
+                    "The filesystem holding the database has {} MB available; the database uses {} MB",
+                    usage.disk_available_bytes / 1_048_576,
+                    (usage.file_bytes + usage.wal_bytes) / 1_048_576
+                ),
+            ));
+        }
+    }
+
+    async fn check_query_log(&self) {
+        let Some(query_log) = &self.query_log else {
+            return;
+        };
+        let period_hours = self.interval_secs as f32 / 3600.0;
+
+        match query_log
+            .get_top_blocked_clients(SPIKE_CLIENT_LIMIT, period_hours)
+            .await
+        {
+            Ok(clients) => {
+                for (client_ip, blocked) in clients {
+                    if blocked < self.client_block_spike_threshold {
+                        break;
+                    }
+                    self.bus.publish(Notification::new(
+                        NotificationKind::ClientBlockSpike,
+                        client_ip.as_str(),
+                        format!("Blocked query spike from {}", client_ip),
+                        format!(
+                            "{} blocked queries in the last {}s (threshold {})",
+                            blocked, self.interval_secs, self.client_block_spike_threshold
+                        ),
+                    ));
+                }
+            }
+            Err(e) => warn!(error = %e, "NotificationMonitorJob: failed to read blocked clients"),
+        }
+
+        let filter = QueryLogFilter {
+            dnssec_status: Some("Bogus"),
+            from: Some(Utc::now() - chrono::Duration::seconds(self.interval_secs as i64)),
+            ..Default::default()
+        };
+        match query_log
+            .get_recent_paged(1, 0, period_hours, None, &filter)
+            .await
+        {
+            Ok(page) if page.records_filtered > 0 => {
+                let latest = page
+                    .queries
+                    .first()
+                    .map(|q| format!(" (latest: {})", q.domain))
+                    .unwrap_or_default();
+                self.bus.publish(Notification::new(
+                    NotificationKind::DnssecBogus,
+                    "dnssec",
+                    "DNSSEC validation failures",
+                    format!(
+                        "{} answers were DNSSEC Bogus in the last {}s{}",
+                        page.records_filtered, self.interval_secs, latest
+                    ),
+                ));
+            }
+            Ok(_) => {}
+            Err(e) => {
+                warn!(error = %e, "NotificationMonitorJob: failed to count DNSSEC Bogus answers")
+            }
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use ferrous_dns_application::use_cases::SyncRecordSourceUseCase;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+/// Keeps the records of one record source, such as a Kubernetes cluster,
+/// published in the local zone. Syncs on the interval and whenever the
+/// source reports a change.
+pub struct RecordSourceSyncJob {
+    sync: Arc<SyncRecordSourceUseCase>,
+    shutdown: CancellationToken,
+}
+
+impl RecordSourceSyncJob {
+    pub fn new(sync: Arc<SyncRecordSourceUseCase>) -> Self {
+        Self {
+            sync,
+            shutdown: CancellationToken::new(),
+        }
+    }
+
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.shutdown = token;
+        self
+    }
+
+    pub async fn start(self: Arc<Self>) {
+        let source = self.sync.source_name();
+        let interval_secs = self.sync.sync_interval_secs();
+        info!(source, interval_secs, "Starting record source sync job");
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+            loop {
+                tokio::select! {
+                    _ = self.shutdown.cancelled() => {
+                        info!(source, "RecordSourceSyncJob: shutting down");
+                        break;
+                    }
+                    _ = interval.tick() => {}
+                    _ = self.sync.changed() => {}
+                }
+                if let Err(e) = self.sync.execute().await {
+                    warn!(source, error = %e, "Record source sync failed; keeping the last records");
+                }
+            }
+        });
+    }
+}
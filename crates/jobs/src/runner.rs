@@ -1,7 +1,10 @@
 use crate::{
-    BlocklistSyncJob, CacheMaintenanceJob, ClientSyncJob, DgaEvictionJob,
-    NxdomainHijackEvictionJob, QueryLogRetentionJob, ResponseIpFilterEvictionJob, RetentionJob,
-    ScheduleEvaluatorJob, SessionCleanupJob, TunnelingEvictionJob, WalCheckpointJob,
+    AcmeRenewalJob, AnomalyDetectionJob, BlocklistSyncJob, CacheMaintenanceJob, ClientSyncJob,
+    DatabaseMaintenanceJob, DgaEvictionJob, DnssecHistoryJob, MetricsExportJob,
+    NotificationDispatchJob, NotificationMonitorJob, NxdomainHijackEvictionJob,
+    QueryLogRetentionJob, RecordSourceSyncJob, ResponseIpFilterEvictionJob, RetentionJob,
+    ScheduleEvaluatorJob, SecondaryZoneRefreshJob, SessionCleanupJob, StandbySyncJob,
+    TunnelingEvictionJob, UpstreamAddressRefreshJob, UsageReportJob, WalCheckpointJob,
 };
 use std::sync::Arc;
 use tokio_util::sync::CancellationToken;
@@ -31,6 +34,7 @@ impl_spawnable_job!(RetentionJob);
 impl_spawnable_job!(QueryLogRetentionJob);
 impl_spawnable_job!(BlocklistSyncJob);
 impl_spawnable_job!(WalCheckpointJob);
+impl_spawnable_job!(DatabaseMaintenanceJob);
 impl_spawnable_job!(CacheMaintenanceJob);
 impl_spawnable_job!(ScheduleEvaluatorJob);
 impl_spawnable_job!(SessionCleanupJob);
@@ -38,6 +42,17 @@ impl_spawnable_job!(TunnelingEvictionJob);
 impl_spawnable_job!(NxdomainHijackEvictionJob);
 impl_spawnable_job!(ResponseIpFilterEvictionJob);
 impl_spawnable_job!(DgaEvictionJob);
+impl_spawnable_job!(NotificationDispatchJob);
+impl_spawnable_job!(NotificationMonitorJob);
+impl_spawnable_job!(AnomalyDetectionJob);
+impl_spawnable_job!(SecondaryZoneRefreshJob);
+impl_spawnable_job!(AcmeRenewalJob);
+impl_spawnable_job!(UpstreamAddressRefreshJob);
+impl_spawnable_job!(MetricsExportJob);
+impl_spawnable_job!(RecordSourceSyncJob);
+impl_spawnable_job!(StandbySyncJob);
+impl_spawnable_job!(UsageReportJob);
+impl_spawnable_job!(DnssecHistoryJob);
 
 fn spawn_job<J: SpawnableJob>(job: Option<J>, shutdown: &Option<CancellationToken>) {
     if let Some(job) = job {
@@ -55,6 +70,7 @@ pub struct JobRunner {
     query_log_retention: Option<QueryLogRetentionJob>,
     blocklist_sync: Option<BlocklistSyncJob>,
     wal_checkpoint: Option<WalCheckpointJob>,
+    database_maintenance: Option<DatabaseMaintenanceJob>,
     cache_maintenance: Option<CacheMaintenanceJob>,
     schedule_evaluator: Option<ScheduleEvaluatorJob>,
     session_cleanup: Option<SessionCleanupJob>,
@@ -62,6 +78,17 @@ pub struct JobRunner {
     nxdomain_hijack_eviction: Option<NxdomainHijackEvictionJob>,
     response_ip_filter_eviction: Option<ResponseIpFilterEvictionJob>,
     dga_eviction: Option<DgaEvictionJob>,
+    notification_dispatch: Option<NotificationDispatchJob>,
+    notification_monitor: Option<NotificationMonitorJob>,
+    anomaly_detection: Option<AnomalyDetectionJob>,
+    secondary_zone_refresh: Option<SecondaryZoneRefreshJob>,
+    acme_renewal: Option<AcmeRenewalJob>,
+    upstream_address_refresh: Option<UpstreamAddressRefreshJob>,
+    metrics_export: Option<MetricsExportJob>,
+    record_source_sync: Vec<RecordSourceSyncJob>,
+    standby_sync: Option<StandbySyncJob>,
+    usage_report: Option<UsageReportJob>,
+    dnssec_history: Option<DnssecHistoryJob>,
     shutdown: Option<CancellationToken>,
 }
 
@@ -73,6 +100,7 @@ impl JobRunner {
             query_log_retention: None,
             blocklist_sync: None,
             wal_checkpoint: None,
+            database_maintenance: None,
             cache_maintenance: None,
             schedule_evaluator: None,
             session_cleanup: None,
@@ -80,6 +108,17 @@ impl JobRunner {
             nxdomain_hijack_eviction: None,
             response_ip_filter_eviction: None,
             dga_eviction: None,
+            notification_dispatch: None,
+            notification_monitor: None,
+            anomaly_detection: None,
+            secondary_zone_refresh: None,
+            acme_renewal: None,
+            upstream_address_refresh: None,
+            metrics_export: None,
+            record_source_sync: Vec::new(),
+            standby_sync: None,
+            usage_report: None,
+            dnssec_history: None,
             shutdown: None,
         }
     }
@@ -109,6 +148,11 @@ impl JobRunner {
         self
     }
 
+    pub fn with_database_maintenance(mut self, job: DatabaseMaintenanceJob) -> Self {
+        self.database_maintenance = Some(job);
+        self
+    }
+
     pub fn with_cache_maintenance(mut self, job: CacheMaintenanceJob) -> Self {
         self.cache_maintenance = Some(job);
         self
@@ -144,6 +188,62 @@ impl JobRunner {
         self
     }
 
+    pub fn with_notification_dispatch(mut self, job: NotificationDispatchJob) -> Self {
+        self.notification_dispatch = Some(job);
+        self
+    }
+
+    pub fn with_notification_monitor(mut self, job: NotificationMonitorJob) -> Self {
+        self.notification_monitor = Some(job);
+        self
+    }
+
+    pub fn with_anomaly_detection(mut self, job: AnomalyDetectionJob) -> Self {
+        self.anomaly_detection = Some(job);
+        self
+    }
+
+    pub fn with_secondary_zone_refresh(mut self, job: SecondaryZoneRefreshJob) -> Self {
+        self.secondary_zone_refresh = Some(job);
+        self
+    }
+
+    pub fn with_acme_renewal(mut self, job: AcmeRenewalJob) -> Self {
+        self.acme_renewal = Some(job);
+        self
+    }
+
+    pub fn with_upstream_address_refresh(mut self, job: UpstreamAddressRefreshJob) -> Self {
+        self.upstream_address_refresh = Some(job);
+        self
+    }
+
+    pub fn with_metrics_export(mut self, job: MetricsExportJob) -> Self {
+        self.metrics_export = Some(job);
+        self
+    }
+
+    /// Adds a sync job; one runs per configured record source.
+    pub fn with_record_source_sync(mut self, job: RecordSourceSyncJob) -> Self {
+        self.record_source_sync.push(job);
+        self
+    }
+
+    pub fn with_standby_sync(mut self, job: StandbySyncJob) -> Self {
+        self.standby_sync = Some(job);
+        self
+    }
+
+    pub fn with_usage_report(mut self, job: UsageReportJob) -> Self {
+        self.usage_report = Some(job);
+        self
+    }
+
+    pub fn with_dnssec_history(mut self, job: DnssecHistoryJob) -> Self {
+        self.dnssec_history = Some(job);
+        self
+    }
+
     pub fn with_shutdown_token(mut self, token: CancellationToken) -> Self {
         self.shutdown = Some(token);
         self
@@ -157,6 +257,7 @@ impl JobRunner {
         spawn_job(self.query_log_retention, &self.shutdown);
         spawn_job(self.blocklist_sync, &self.shutdown);
         spawn_job(self.wal_checkpoint, &self.shutdown);
+        spawn_job(self.database_maintenance, &self.shutdown);
         spawn_job(self.cache_maintenance, &self.shutdown);
         spawn_job(self.schedule_evaluator, &self.shutdown);
         spawn_job(self.session_cleanup, &self.shutdown);
@@ -164,6 +265,19 @@ impl JobRunner {
         spawn_job(self.nxdomain_hijack_eviction, &self.shutdown);
         spawn_job(self.response_ip_filter_eviction, &self.shutdown);
         spawn_job(self.dga_eviction, &self.shutdown);
+        spawn_job(self.notification_dispatch, &self.shutdown);
+        spawn_job(self.notification_monitor, &self.shutdown);
+        spawn_job(self.anomaly_detection, &self.shutdown);
+        spawn_job(self.secondary_zone_refresh, &self.shutdown);
+        spawn_job(self.acme_renewal, &self.shutdown);
+        spawn_job(self.upstream_address_refresh, &self.shutdown);
+        spawn_job(self.metrics_export, &self.shutdown);
+        for job in self.record_source_sync {
+            spawn_job(Some(job), &self.shutdown);
+        }
+        spawn_job(self.standby_sync, &self.shutdown);
+        spawn_job(self.usage_report, &self.shutdown);
+        spawn_job(self.dnssec_history, &self.shutdown);
 
         info!("All background jobs started");
     }
@@ -0,0 +1,55 @@
+use ferrous_dns_application::ports::SecondaryZonePort;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info};
+
+/// Shortest pause between two refresh passes.
+const MIN_WAIT: Duration = Duration::from_secs(1);
+
+/// Longest pause, so a clock jump never delays a refresh for long.
+const MAX_WAIT: Duration = Duration::from_secs(300);
+
+/// Drives the secondary zones' SOA timers: each pass refreshes the zones
+/// that are due, then sleeps until the next timer fires.
+pub struct SecondaryZoneRefreshJob {
+    zones: Arc<dyn SecondaryZonePort>,
+    shutdown: CancellationToken,
+}
+
+impl SecondaryZoneRefreshJob {
+    pub fn new(zones: Arc<dyn SecondaryZonePort>) -> Self {
+        Self {
+            zones,
+            shutdown: CancellationToken::new(),
+        }
+    }
+
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.shutdown = token;
+        self
+    }
+
+    pub async fn start(self: Arc<Self>) {
+        info!("Starting secondary zone refresh job");
+
+        tokio::spawn(async move {
+            loop {
+                let wait = tokio::select! {
+                    _ = self.shutdown.cancelled() => break,
+                    wait = self.zones.refresh_due() => wait.clamp(MIN_WAIT, MAX_WAIT),
+                };
+                debug!(
+                    wait_secs = wait.as_secs(),
+                    "Secondary zone refresh pass done"
+                );
+
+                tokio::select! {
+                    _ = self.shutdown.cancelled() => break,
+                    _ = tokio::time::sleep(wait) => {}
+                }
+            }
+            info!("SecondaryZoneRefreshJob: shutting down");
+        });
+    }
+}
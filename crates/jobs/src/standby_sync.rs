@@ -0,0 +1,50 @@
+use ferrous_dns_application::use_cases::SyncFromPrimaryUseCase;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+/// Copies the primary's state into this standby once at startup and then
+/// every sync interval.
+pub struct StandbySyncJob {
+    sync: Arc<SyncFromPrimaryUseCase>,
+    shutdown: CancellationToken,
+}
+
+impl StandbySyncJob {
+    pub fn new(sync: Arc<SyncFromPrimaryUseCase>) -> Self {
+        Self {
+            sync,
+            shutdown: CancellationToken::new(),
+        }
+    }
+
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.shutdown = token;
+        self
+    }
+
+    pub async fn start(self: Arc<Self>) {
+        let interval_secs = self.sync.interval_secs();
+        info!(interval_secs, "Starting standby sync job");
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+            loop {
+                tokio::select! {
+                    _ = self.shutdown.cancelled() => {
+                        info!("StandbySyncJob: shutting down");
+                        break;
+                    }
+                    _ = interval.tick() => {
+                        if let Err(e) = self.sync.execute().await {
+                            warn!(error = %e, "Standby sync failed");
+                        }
+                    }
+                }
+            }
+        });
+    }
+}
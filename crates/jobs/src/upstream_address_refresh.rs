@@ -0,0 +1,77 @@
+use ferrous_dns_application::ports::UpstreamAddressRefreshPort;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+
+/// Pause before retrying while some upstream hostname has no address yet,
+/// e.g. when the network was not up at startup.
+const RETRY_DELAY: Duration = Duration::from_secs(30);
+
+/// Re-resolves the upstream hostnames every `interval_secs`, and sooner
+/// while any of them is still unresolved.
+pub struct UpstreamAddressRefreshJob {
+    upstreams: Arc<dyn UpstreamAddressRefreshPort>,
+    interval_secs: u64,
+    shutdown: CancellationToken,
+}
+
+impl UpstreamAddressRefreshJob {
+    pub fn new(upstreams: Arc<dyn UpstreamAddressRefreshPort>) -> Self {
+        Self {
+            upstreams,
+            interval_secs: 3600,
+            shutdown: CancellationToken::new(),
+        }
+    }
+
+    pub fn with_interval(mut self, interval_secs: u64) -> Self {
+        self.interval_secs = interval_secs;
+        self
+    }
+
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.shutdown = token;
+        self
+    }
+
+    pub async fn start(self: Arc<Self>) {
+        info!(
+            interval_secs = self.interval_secs,
+            "Starting upstream address refresh job"
+        );
+
+        tokio::spawn(async move {
+            let interval = Duration::from_secs(self.interval_secs);
+            // The pool managers resolved every hostname at startup; check
+            // back early in case some of them failed.
+            let mut wait = interval.min(RETRY_DELAY);
+            loop {
+                tokio::select! {
+                    _ = self.shutdown.cancelled() => break,
+                    _ = tokio::time::sleep(wait) => {}
+                }
+
+                let refresh = tokio::select! {
+                    _ = self.shutdown.cancelled() => break,
+                    refresh = self.upstreams.refresh_addresses() => refresh,
+                };
+                if refresh.changed > 0 {
+                    info!(changed = refresh.changed, "Upstream addresses refreshed");
+                } else {
+                    debug!("Upstream addresses unchanged");
+                }
+                wait = if refresh.unresolved > 0 {
+                    warn!(
+                        unresolved = refresh.unresolved,
+                        "Some upstream hostnames are still unresolved"
+                    );
+                    interval.min(RETRY_DELAY)
+                } else {
+                    interval
+                };
+            }
+            info!("UpstreamAddressRefreshJob: shutting down");
+        });
+    }
+}
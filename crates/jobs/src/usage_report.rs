@@ -0,0 +1,160 @@
+use chrono::{DateTime, Datelike, Duration as ChronoDuration, NaiveDate, TimeZone, Utc};
+use chrono_tz::Tz;
+use ferrous_dns_application::use_cases::SendUsageReportUseCase;
+use ferrous_dns_domain::{ReportPeriod, ReportsConfig};
+use std::sync::Arc;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+/// A report due at `due`, covering the `period_hours` before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DueReport {
+    pub period: ReportPeriod,
+    pub due: DateTime<Utc>,
+    pub period_hours: u32,
+}
+
+/// Emails the weekly report every Monday and the monthly report on the 1st,
+/// both at `send_hour` in the configured timezone.
+///
+/// Send times are not persisted: a report whose time passes while the server
+/// is down is skipped.
+pub struct UsageReportJob {
+    reports: Arc<SendUsageReportUseCase>,
+    config: ReportsConfig,
+    shutdown: CancellationToken,
+}
+
+impl UsageReportJob {
+    pub fn new(reports: Arc<SendUsageReportUseCase>, config: ReportsConfig) -> Self {
+        Self {
+            reports,
+            config,
+            shutdown: CancellationToken::new(),
+        }
+    }
+
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.shutdown = token;
+        self
+    }
+
+    pub async fn start(self: Arc<Self>) {
+        let tz: Tz = self.config.timezone.parse().unwrap_or_else(|_| {
+            warn!(
+                timezone = %self.config.timezone,
+                "UsageReportJob: invalid timezone, using UTC"
+            );
+            Tz::UTC
+        });
+        info!(
+            weekly = self.config.weekly,
+            monthly = self.config.monthly,
+            send_hour = self.config.send_hour,
+            timezone = tz.name(),
+            "Starting usage report job"
+        );
+
+        let mut after = Utc::now();
+        loop {
+            let due = next_due_reports(&self.config, tz, after);
+            let Some(at) = due.first().map(|report| report.due) else {
+                return;
+            };
+            let wait = (at - Utc::now()).to_std().unwrap_or_default();
+
+            tokio::select! {
+                _ = self.shutdown.cancelled() => {
+                    info!("UsageReportJob: shutting down");
+                    break;
+                }
+                _ = tokio::time::sleep(wait) => {
+                    for report in &due {
+                        if let Err(e) = self
+                            .reports
+                            .execute(report.period, report.period_hours)
+                            .await
+                        {
+                            warn!(
+                                period = report.period.as_str(),
+                                error = %e,
+                                "Failed to send usage report"
+                            );
+                        }
+                    }
+                    after = at.max(Utc::now());
+                }
+            }
+        }
+    }
+}
+
+/// Reports due next strictly after `after`: one entry, or two when the
+/// weekly and monthly reports fall on the same instant. Empty when both
+/// reports are disabled.
+pub fn next_due_reports(config: &ReportsConfig, tz: Tz, after: DateTime<Utc>) -> Vec<DueReport> {
+    let local = after.with_timezone(&tz).date_naive();
+    let hour = u32::from(config.send_hour.min(23));
+
+    let mut candidates = Vec::with_capacity(2);
+    if config.weekly {
+        let monday =
+            local - ChronoDuration::days(i64::from(local.weekday().num_days_from_monday()));
+        let due = [monday, monday + ChronoDuration::days(7)]
+            .into_iter()
+            .map(|date| local_time(tz, date, hour))
+            .find(|due| *due > after);
+        if let Some(due) = due {
+            candidates.push(DueReport {
+                period: ReportPeriod::Weekly,
+                due,
+                period_hours: 7 * 24,
+            });
+        }
+    }
+    if config.monthly {
+        let first = local.with_day(1).unwrap_or(local);
+        let due = [first, next_month(first)]
+            .into_iter()
+            .map(|date| (date, local_time(tz, date, hour)))
+            .find(|(_, due)| *due > after);
+        if let Some((date, due)) = due {
+            let previous = previous_month(date);
+            candidates.push(DueReport {
+                period: ReportPeriod::Monthly,
+                due,
+                period_hours: (date - previous).num_days() as u32 * 24,
+            });
+        }
+    }
+
+    let Some(earliest) = candidates.iter().map(|report| report.due).min() else {
+        return candidates;
+    };
+    candidates.retain(|report| report.due == earliest);
+    candidates
+}
+
+/// `hour:00` on `date` in `tz`; an hour skipped by a DST change moves to the
+/// next one.
+fn local_time(tz: Tz, date: NaiveDate, hour: u32) -> DateTime<Utc> {
+    let mut naive = date.and_hms_opt(hour, 0, 0).unwrap_or_default();
+    loop {
+        if let Some(time) = tz.from_local_datetime(&naive).earliest() {
+            return time.with_timezone(&Utc);
+        }
+        naive += ChronoDuration::hours(1);
+    }
+}
+
+fn next_month(first: NaiveDate) -> NaiveDate {
+    first
+        .checked_add_months(chrono::Months::new(1))
+        .unwrap_or(first)
+}
+
+fn previous_month(first: NaiveDate) -> NaiveDate {
+    first
+        .checked_sub_months(chrono::Months::new(1))
+        .unwrap_or(first)
+}
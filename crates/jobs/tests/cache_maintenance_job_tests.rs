@@ -138,6 +138,7 @@ async fn test_cache_maintenance_job_with_custom_intervals() {
                 candidates_found: 5,
                 refreshed: 3,
                 failed: 1,
+                deferred: 0,
                 cache_size: 100,
             })
             .with_compaction_outcome(CacheCompactionOutcome {
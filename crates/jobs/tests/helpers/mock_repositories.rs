@@ -3,10 +3,14 @@
 use async_trait::async_trait;
 use ferrous_dns_application::ports::{
     ArpReader, ArpTable, CacheCompactionOutcome, CacheMaintenancePort, CacheRefreshOutcome,
-    CacheStats, ClientRepository, HostnameResolver, QueryLogRepository, TimeGranularity,
-    TimelineBucket,
+    CacheStats, ClientActivity, ClientDomainCount, ClientRepository, HostnameResolver,
+    MetricsWindow, QueryLogRepository, TimeGranularity, TimelineBreakdown, TimelineBucket,
+    TimelineSeries,
+};
+use ferrous_dns_domain::{
+    Client, ClientCategory, ClientStats, DomainError, PageRequest, QueryCountBreakdown, QueryLog,
+    QueryStats, RecordType,
 };
-use ferrous_dns_domain::{Client, ClientStats, DomainError, QueryLog, QueryStats, RecordType};
 use std::collections::HashMap;
 use std::net::IpAddr;
 use std::sync::atomic::{AtomicU64, Ordering};
@@ -200,6 +204,9 @@ pub fn make_client(id: i64, ip: &str) -> Client {
         last_mac_update: None,
         last_hostname_update: None,
         group_id: Some(1),
+        display_name: None,
+        category: None,
+        notes: None,
     }
 }
 
@@ -216,6 +223,9 @@ pub fn make_old_client(id: i64, ip: &str, days_old: i64) -> Client {
         last_mac_update: None,
         last_hostname_update: None,
         group_id: Some(1),
+        display_name: None,
+        category: None,
+        notes: None,
     }
 }
 
@@ -241,6 +251,9 @@ impl ClientRepository for MockClientRepository {
             last_mac_update: None,
             last_hostname_update: None,
             group_id: Some(1),
+            display_name: None,
+            category: None,
+            notes: None,
         };
         clients.insert(id, client.clone());
         Ok(client)
@@ -255,6 +268,14 @@ impl ClientRepository for MockClientRepository {
         Ok(())
     }
 
+    async fn update_last_seen_in_group(
+        &self,
+        ip_address: IpAddr,
+        _initial_group_id: i64,
+    ) -> Result<(), DomainError> {
+        self.update_last_seen(ip_address).await
+    }
+
     async fn update_mac_address(&self, ip_address: IpAddr, mac: String) -> Result<(), DomainError> {
         let mut clients = self.clients.write().await;
         if let Some(c) = clients.values_mut().find(|c| c.ip_address == ip_address) {
@@ -315,6 +336,16 @@ impl ClientRepository for MockClientRepository {
         Ok(Vec::new())
     }
 
+    async fn get_page(
+        &self,
+        page: &PageRequest,
+        _active_days: Option<u32>,
+    ) -> Result<(Vec<Client>, u64), DomainError> {
+        let total = self.clients.read().await.len() as u64;
+        let clients = self.get_all(page.limit, page.offset).await?;
+        Ok((clients, total))
+    }
+
     async fn get_stats(&self) -> Result<ClientStats, DomainError> {
         let clients = self.clients.read().await;
         Ok(ClientStats {
@@ -387,6 +418,25 @@ impl ClientRepository for MockClientRepository {
         }
     }
 
+    async fn update_metadata(
+        &self,
+        client_id: i64,
+        display_name: Option<String>,
+        category: Option<ClientCategory>,
+        notes: Option<String>,
+    ) -> Result<(), DomainError> {
+        let mut clients = self.clients.write().await;
+        match clients.get_mut(&client_id) {
+            Some(client) => {
+                client.display_name = display_name.map(Arc::from);
+                client.category = category;
+                client.notes = notes.map(Arc::from);
+                Ok(())
+            }
+            None => Err(DomainError::ClientNotFound(client_id.to_string())),
+        }
+    }
+
     async fn delete(&self, id: i64) -> Result<(), DomainError> {
         let mut clients = self.clients.write().await;
         if clients.remove(&id).is_some() {
@@ -422,24 +472,39 @@ impl MockQueryLogRepository {
     }
 
     pub async fn add_log_at(&self, ip: &str, timestamp: &str) {
+        self.push_log(ip, timestamp, false).await;
+    }
+
+    pub async fn add_blocked_log(&self, ip: &str) {
+        let ts = chrono::Utc::now().to_rfc3339();
+        self.push_log(ip, &ts, true).await;
+    }
+
+    async fn push_log(&self, ip: &str, timestamp: &str, blocked: bool) {
         let log = QueryLog {
             id: None,
             domain: "test.example.com".into(),
             record_type: RecordType::A,
             client_ip: ip.parse().unwrap(),
             client_hostname: None,
-            blocked: false,
+            client_mac: None,
+            blocked,
             response_time_us: Some(10),
             cache_hit: false,
             cache_refresh: false,
             dnssec_status: None,
             upstream_server: None,
             upstream_pool: None,
+            upstream_strategy: None,
+            upstream_attempt: None,
+            upstream_protocol: None,
             response_status: None,
             timestamp: Some(timestamp.to_string()),
             query_source: Default::default(),
             group_id: None,
+            group_name: None,
             block_source: None,
+            plugin: None,
         };
         self.logs.write().await.push((log, timestamp.to_string()));
     }
@@ -505,6 +570,7 @@ impl QueryLogRepository for MockQueryLogRepository {
             queries_total: logs.len() as u64,
             queries_blocked: logs.iter().filter(|(l, _)| l.blocked).count() as u64,
             queries_rate_limited: 0,
+            queries_any_minimized: 0,
             queries_malware_detected: 0,
             unique_clients: 0,
             uptime_seconds: 0,
@@ -512,10 +578,15 @@ impl QueryLogRepository for MockQueryLogRepository {
             avg_query_time_ms: 0.0,
             avg_cache_time_ms: 0.0,
             avg_upstream_time_ms: 0.0,
+            latency_p50_ms: 0.0,
+            latency_p95_ms: 0.0,
+            latency_p99_ms: 0.0,
             source_stats: HashMap::new(),
             queries_by_type: HashMap::new(),
             most_queried_type: None,
             record_type_distribution: Vec::new(),
+            queries_by_group: Vec::new(),
+            top_clients: Vec::new(),
         })
     }
 
@@ -527,10 +598,33 @@ impl QueryLogRepository for MockQueryLogRepository {
         Ok(Vec::new())
     }
 
+    async fn get_timeline_breakdown(
+        &self,
+        _period_hours: u32,
+        _granularity: TimeGranularity,
+        _breakdown: TimelineBreakdown,
+        _limit: u32,
+    ) -> Result<Vec<TimelineSeries>, DomainError> {
+        Ok(Vec::new())
+    }
+
+    async fn get_query_counts_by(
+        &self,
+        _period_hours: f32,
+        _breakdown: TimelineBreakdown,
+        _limit: Option<u32>,
+    ) -> Result<Vec<QueryCountBreakdown>, DomainError> {
+        Ok(Vec::new())
+    }
+
     async fn count_queries_since(&self, _seconds_ago: i64) -> Result<u64, DomainError> {
         Ok(self.logs.read().await.len() as u64)
     }
 
+    async fn get_metrics_window(&self, _seconds_ago: i64) -> Result<MetricsWindow, DomainError> {
+        Ok(MetricsWindow::default())
+    }
+
     async fn get_cache_stats(&self, _period_hours: f32) -> Result<CacheStats, DomainError> {
         Ok(CacheStats {
             total_hits: 0,
@@ -565,6 +659,39 @@ impl QueryLogRepository for MockQueryLogRepository {
         Ok(Vec::new())
     }
 
+    async fn get_top_blocked_clients(
+        &self,
+        limit: u32,
+        _period_hours: f32,
+    ) -> Result<Vec<(String, u64)>, DomainError> {
+        let logs = self.logs.read().await;
+        let mut counts: HashMap<String, u64> = HashMap::new();
+        for (log, _) in logs.iter().filter(|(l, _)| l.blocked) {
+            *counts.entry(log.client_ip.to_string()).or_default() += 1;
+        }
+        let mut clients: Vec<(String, u64)> = counts.into_iter().collect();
+        clients.sort_by_key(|c| std::cmp::Reverse(c.1));
+        clients.truncate(limit as usize);
+        Ok(clients)
+    }
+
+    async fn get_client_domain_counts(
+        &self,
+        _seconds_ago: i64,
+    ) -> Result<Vec<ClientDomainCount>, DomainError> {
+        Ok(Vec::new())
+    }
+
+    async fn get_client_activity(
+        &self,
+        _client_ip: &str,
+        _period_hours: u32,
+        _granularity: TimeGranularity,
+        _limit: u32,
+    ) -> Result<ClientActivity, DomainError> {
+        Ok(ClientActivity::default())
+    }
+
     async fn delete_older_than(&self, days: u32) -> Result<u64, DomainError> {
         let cutoff = (chrono::Utc::now() - chrono::Duration::days(days as i64)).to_rfc3339();
         let mut logs = self.logs.write().await;
@@ -0,0 +1,252 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{
+    AggregateStatus, NotificationSender, ResponseValidationStats, UpstreamGroupHealth,
+    UpstreamHealthPort, UpstreamRoute, UpstreamStatus,
+};
+use ferrous_dns_domain::{
+    DomainError, Notification, NotificationEventsConfig, NotificationKind, UpstreamStrategy,
+};
+use ferrous_dns_jobs::{NotificationBus, NotificationDispatchJob, NotificationMonitorJob};
+use std::sync::{Arc, Mutex};
+use tokio::sync::broadcast;
+use tokio::time::{sleep, Duration};
+
+mod helpers;
+use helpers::MockQueryLogRepository;
+
+#[derive(Default)]
+struct RecordingSender {
+    sent: Mutex<Vec<Notification>>,
+}
+
+impl RecordingSender {
+    fn events(&self) -> Vec<(NotificationKind, String)> {
+        self.sent
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|n| (n.event, n.subject.clone()))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl NotificationSender for RecordingSender {
+    fn name(&self) -> &'static str {
+        "recording"
+    }
+
+    async fn send(&self, notification: &Notification) -> Result<(), DomainError> {
+        self.sent.lock().unwrap().push(notification.clone());
+        Ok(())
+    }
+}
+
+struct FailingSender;
+
+#[async_trait]
+impl NotificationSender for FailingSender {
+    fn name(&self) -> &'static str {
+        "failing"
+    }
+
+    async fn send(&self, _notification: &Notification) -> Result<(), DomainError> {
+        Err(DomainError::NotificationDeliveryFailed("down".into()))
+    }
+}
+
+struct FakeUpstreamHealth {
+    servers: Mutex<Vec<(&'static str, &'static str, AggregateStatus)>>,
+}
+
+impl FakeUpstreamHealth {
+    fn new(servers: Vec<(&'static str, &'static str, AggregateStatus)>) -> Self {
+        Self {
+            servers: Mutex::new(servers),
+        }
+    }
+
+    fn set(&self, servers: Vec<(&'static str, &'static str, AggregateStatus)>) {
+        *self.servers.lock().unwrap() = servers;
+    }
+}
+
+impl UpstreamHealthPort for FakeUpstreamHealth {
+    fn get_all_upstream_status(&self) -> Vec<(String, UpstreamStatus)> {
+        Vec::new()
+    }
+
+    fn get_grouped_upstream_health(&self) -> Vec<UpstreamGroupHealth> {
+        self.servers
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(pool, address, status)| UpstreamGroupHealth {
+                address: address.to_string(),
+                status: *status,
+                resolved: Vec::new(),
+                pool_name: pool.to_string(),
+                strategy: UpstreamStrategy::Parallel,
+            })
+            .collect()
+    }
+
+    fn response_validation_stats(&self) -> ResponseValidationStats {
+        ResponseValidationStats::default()
+    }
+
+    fn preview_route(&self, _pool: Option<&str>) -> Option<UpstreamRoute> {
+        None
+    }
+}
+
+fn notification(event: NotificationKind, subject: &str) -> Notification {
+    Notification::new(event, subject, "title", "message")
+}
+
+fn drain(rx: &mut broadcast::Receiver<Notification>) -> Vec<(NotificationKind, String)> {
+    let mut events = Vec::new();
+    while let Ok(n) = rx.try_recv() {
+        events.push((n.event, n.subject));
+    }
+    events
+}
+
+#[tokio::test]
+async fn test_dispatch_delivers_to_every_sender() {
+    let bus = NotificationBus::default();
+    let first = Arc::new(RecordingSender::default());
+    let second = Arc::new(RecordingSender::default());
+    let job = Arc::new(NotificationDispatchJob::new(
+        &bus,
+        vec![Arc::new(FailingSender), first.clone(), second.clone()],
+    ));
+    job.start().await;
+
+    bus.publish(notification(
+        NotificationKind::BlocklistUpdateFailed,
+        "blocklists",
+    ));
+    sleep(Duration::from_millis(50)).await;
+
+    let expected = vec![(
+        NotificationKind::BlocklistUpdateFailed,
+        "blocklists".to_string(),
+    )];
+    assert_eq!(first.events(), expected);
+    assert_eq!(second.events(), expected);
+}
+
+#[tokio::test]
+async fn test_dispatch_skips_disabled_events() {
+    let bus = NotificationBus::default();
+    let sender = Arc::new(RecordingSender::default());
+    let events = NotificationEventsConfig {
+        dnssec_bogus: false,
+        ..Default::default()
+    };
+    let job =
+        Arc::new(NotificationDispatchJob::new(&bus, vec![sender.clone()]).with_events(events));
+    job.start().await;
+
+    bus.publish(notification(NotificationKind::DnssecBogus, "dnssec"));
+    bus.publish(notification(NotificationKind::DiskNearlyFull, "database"));
+    sleep(Duration::from_millis(50)).await;
+
+    assert_eq!(
+        sender.events(),
+        vec![(NotificationKind::DiskNearlyFull, "database".to_string())]
+    );
+}
+
+#[tokio::test]
+async fn test_dispatch_cooldown_is_per_event_and_subject() {
+    let bus = NotificationBus::default();
+    let sender = Arc::new(RecordingSender::default());
+    let job =
+        Arc::new(NotificationDispatchJob::new(&bus, vec![sender.clone()]).with_cooldown(3600));
+    job.start().await;
+
+    bus.publish(notification(NotificationKind::ClientBlockSpike, "10.0.0.1"));
+    bus.publish(notification(NotificationKind::ClientBlockSpike, "10.0.0.1"));
+    bus.publish(notification(NotificationKind::ClientBlockSpike, "10.0.0.2"));
+    bus.publish(notification(NotificationKind::UpstreamDegraded, "10.0.0.1"));
+    sleep(Duration::from_millis(50)).await;
+
+    assert_eq!(sender.events().len(), 3);
+}
+
+#[tokio::test]
+async fn test_dispatch_without_cooldown_repeats() {
+    let bus = NotificationBus::default();
+    let sender = Arc::new(RecordingSender::default());
+    let job = Arc::new(NotificationDispatchJob::new(&bus, vec![sender.clone()]).with_cooldown(0));
+    job.start().await;
+
+    bus.publish(notification(NotificationKind::DiskNearlyFull, "database"));
+    bus.publish(notification(NotificationKind::DiskNearlyFull, "database"));
+    sleep(Duration::from_millis(50)).await;
+
+    assert_eq!(sender.events().len(), 2);
+}
+
+#[tokio::test]
+async fn test_monitor_reports_degraded_pool_once_until_recovery() {
+    let bus = NotificationBus::default();
+    let mut rx = bus.subscribe();
+    let health = Arc::new(FakeUpstreamHealth::new(vec![
+        ("primary", "1.1.1.1:53", AggregateStatus::Healthy),
+        ("primary", "8.8.8.8:53", AggregateStatus::Unhealthy),
+        ("backup", "9.9.9.9:53", AggregateStatus::Healthy),
+    ]));
+    let job = Arc::new(
+        NotificationMonitorJob::new(bus)
+            .with_interval(1)
+            .with_upstream_health(health.clone()),
+    );
+    job.start().await;
+
+    sleep(Duration::from_millis(1100)).await;
+    assert_eq!(
+        drain(&mut rx),
+        vec![(NotificationKind::UpstreamDegraded, "primary".to_string())]
+    );
+
+    health.set(vec![("primary", "1.1.1.1:53", AggregateStatus::Healthy)]);
+    sleep(Duration::from_millis(1000)).await;
+    health.set(vec![("primary", "1.1.1.1:53", AggregateStatus::Partial)]);
+    sleep(Duration::from_millis(1000)).await;
+
+    assert_eq!(
+        drain(&mut rx),
+        vec![(NotificationKind::UpstreamDegraded, "primary".to_string())]
+    );
+}
+
+#[tokio::test]
+async fn test_monitor_reports_clients_over_spike_threshold() {
+    let bus = NotificationBus::default();
+    let mut rx = bus.subscribe();
+    let query_log = Arc::new(MockQueryLogRepository::new());
+    for _ in 0..3 {
+        query_log.add_blocked_log("192.168.1.10").await;
+    }
+    query_log.add_blocked_log("192.168.1.20").await;
+    query_log.add_recent_log("192.168.1.30").await;
+
+    let job = Arc::new(NotificationMonitorJob::new(bus).with_query_log(query_log, 3));
+    job.start().await;
+    sleep(Duration::from_millis(50)).await;
+
+    let spikes: Vec<_> = drain(&mut rx)
+        .into_iter()
+        .filter(|(event, _)| *event == NotificationKind::ClientBlockSpike)
+        .collect();
+    assert_eq!(
+        spikes,
+        vec![(
+            NotificationKind::ClientBlockSpike,
+            "192.168.1.10".to_string()
+        )]
+    );
+}
@@ -0,0 +1,85 @@
+use chrono::{DateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use ferrous_dns_domain::{ReportPeriod, ReportsConfig};
+use ferrous_dns_jobs::usage_report::{next_due_reports, DueReport};
+
+fn config(weekly: bool, monthly: bool) -> ReportsConfig {
+    ReportsConfig {
+        weekly,
+        monthly,
+        send_hour: 8,
+        ..Default::default()
+    }
+}
+
+fn utc(year: i32, month: u32, day: u32, hour: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(year, month, day, hour, 0, 0).unwrap()
+}
+
+#[test]
+fn test_weekly_report_is_due_next_monday() {
+    // 2026-10-16 is a Friday.
+    let due = next_due_reports(&config(true, false), Tz::UTC, utc(2026, 10, 16, 12));
+
+    assert_eq!(
+        due,
+        vec![DueReport {
+            period: ReportPeriod::Weekly,
+            due: utc(2026, 10, 19, 8),
+            period_hours: 168,
+        }]
+    );
+}
+
+#[test]
+fn test_weekly_report_on_monday_before_and_at_send_hour() {
+    let before = next_due_reports(&config(true, false), Tz::UTC, utc(2026, 10, 19, 7));
+    let at = next_due_reports(&config(true, false), Tz::UTC, utc(2026, 10, 19, 8));
+
+    assert_eq!(before[0].due, utc(2026, 10, 19, 8));
+    assert_eq!(at[0].due, utc(2026, 10, 26, 8));
+}
+
+#[test]
+fn test_monthly_report_covers_previous_month() {
+    let october = next_due_reports(&config(false, true), Tz::UTC, utc(2026, 10, 16, 12));
+    let february = next_due_reports(&config(false, true), Tz::UTC, utc(2026, 3, 1, 7));
+
+    assert_eq!(october[0].period, ReportPeriod::Monthly);
+    assert_eq!(october[0].due, utc(2026, 11, 1, 8));
+    assert_eq!(october[0].period_hours, 31 * 24);
+    assert_eq!(february[0].due, utc(2026, 3, 1, 8));
+    assert_eq!(february[0].period_hours, 28 * 24);
+}
+
+#[test]
+fn test_earliest_report_is_returned_first() {
+    let due = next_due_reports(&config(true, true), Tz::UTC, utc(2026, 10, 16, 12));
+
+    assert_eq!(due.len(), 1);
+    assert_eq!(due[0].period, ReportPeriod::Weekly);
+}
+
+#[test]
+fn test_reports_on_the_same_instant_are_both_due() {
+    // 2026-06-01 is a Monday.
+    let due = next_due_reports(&config(true, true), Tz::UTC, utc(2026, 5, 31, 12));
+
+    let periods: Vec<ReportPeriod> = due.iter().map(|report| report.period).collect();
+    assert_eq!(periods, vec![ReportPeriod::Weekly, ReportPeriod::Monthly]);
+    assert!(due.iter().all(|report| report.due == utc(2026, 6, 1, 8)));
+}
+
+#[test]
+fn test_send_hour_uses_configured_timezone() {
+    let tz: Tz = "Europe/Berlin".parse().unwrap();
+
+    let due = next_due_reports(&config(true, false), tz, utc(2026, 10, 16, 12));
+
+    assert_eq!(due[0].due, utc(2026, 10, 19, 6));
+}
+
+#[test]
+fn test_no_reports_due_when_disabled() {
+    assert!(next_due_reports(&config(false, false), Tz::UTC, utc(2026, 10, 16, 12)).is_empty());
+}
@@ -0,0 +1,84 @@
+use anyhow::Context;
+use hickory_proto::op::{Edns, Message, MessageType, OpCode, Query};
+use hickory_proto::rr::{RData, RecordType};
+use std::net::{IpAddr, SocketAddr};
+use std::sync::atomic::{AtomicU16, Ordering};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpStream, UdpSocket};
+use tokio::time::timeout;
+
+use crate::records::fqdn;
+
+/// Long enough for a failover across two upstreams with a one second
+/// query timeout each.
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(5);
+
+static NEXT_ID: AtomicU16 = AtomicU16::new(1);
+
+fn build_query(name: &str, record_type: RecordType) -> anyhow::Result<Vec<u8>> {
+    let mut message = Message::new(
+        NEXT_ID.fetch_add(1, Ordering::Relaxed),
+        MessageType::Query,
+        OpCode::Query,
+    );
+    message.set_recursion_desired(true);
+    message.add_query(Query::query(fqdn(name), record_type));
+    let mut edns = Edns::new();
+    edns.set_max_payload(1232);
+    message.set_edns(edns);
+    Ok(message.to_vec()?)
+}
+
+/// Sends one query over UDP and waits for the reply.
+pub async fn query_udp(
+    server: SocketAddr,
+    name: &str,
+    record_type: RecordType,
+) -> anyhow::Result<Message> {
+    let socket = UdpSocket::bind(("127.0.0.1", 0)).await?;
+    socket
+        .send_to(&build_query(name, record_type)?, server)
+        .await?;
+
+    let mut buf = vec![0u8; 65_535];
+    let len = timeout(CLIENT_TIMEOUT, socket.recv(&mut buf))
+        .await
+        .context("UDP query timed out")??;
+    Ok(Message::from_vec(&buf[..len])?)
+}
+
+/// Sends one query over TCP and waits for the reply.
+pub async fn query_tcp(
+    server: SocketAddr,
+    name: &str,
+    record_type: RecordType,
+) -> anyhow::Result<Message> {
+    let exchange = async {
+        let mut stream = TcpStream::connect(server).await?;
+        let query = build_query(name, record_type)?;
+        stream.write_u16(query.len() as u16).await?;
+        stream.write_all(&query).await?;
+
+        let len = stream.read_u16().await?;
+        let mut buf = vec![0u8; len as usize];
+        stream.read_exact(&mut buf).await?;
+        anyhow::Ok(Message::from_vec(&buf)?)
+    };
+    timeout(CLIENT_TIMEOUT, exchange)
+        .await
+        .context("TCP query timed out")?
+}
+
+/// A and AAAA addresses in the answer section, in order.
+pub fn addresses(response: &Message) -> Vec<IpAddr> {
+    response
+        .answers()
+        .iter()
+        .filter_map(|record| match record.data() {
+            RData::A(a) => Some(IpAddr::V4(a.0)),
+            RData::AAAA(aaaa) => Some(IpAddr::V6(aaaa.0)),
+            _ => None,
+        })
+        .collect()
+}
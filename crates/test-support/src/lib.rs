@@ -0,0 +1,19 @@
+//! Test support for end-to-end tests of Ferrous DNS.
+//!
+//! [`MockUpstream`] is an in-process upstream DNS server answering scripted
+//! records over UDP and TCP, with optional delays, truncation and DNSSEC
+//! signatures from a [`ZoneSigner`]. [`FerrousStack`] runs the full server
+//! — listeners, cache, block filter and upstream pools — on an ephemeral
+//! localhost port, forwarding to one or more mocks.
+
+mod client;
+mod mock_upstream;
+mod records;
+mod stack;
+mod zone_signer;
+
+pub use client::{addresses, query_tcp, query_udp};
+pub use mock_upstream::{MockAnswer, MockUpstream, ReceivedQuery, Transport};
+pub use records::{a_record, aaaa_record, cname_record};
+pub use stack::FerrousStack;
+pub use zone_signer::ZoneSigner;
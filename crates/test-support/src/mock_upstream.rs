@@ -0,0 +1,275 @@
+use hickory_proto::op::{Edns, Message, MessageType, OpCode, ResponseCode};
+use hickory_proto::rr::{Name, Record, RecordType};
+use std::collections::HashMap;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream, UdpSocket};
+use tokio::task::JoinHandle;
+
+use crate::records::fqdn;
+
+/// Attempts at finding a port free for both UDP and TCP.
+const BIND_ATTEMPTS: usize = 16;
+
+/// Transport a query reached the mock on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transport {
+    Udp,
+    Tcp,
+}
+
+/// A query received by a [`MockUpstream`], in arrival order.
+#[derive(Debug, Clone)]
+pub struct ReceivedQuery {
+    pub name: Name,
+    pub record_type: RecordType,
+    pub transport: Transport,
+    /// Whether the query set the EDNS DO bit.
+    pub dnssec_ok: bool,
+}
+
+/// Scripted reply to one name and record type.
+#[derive(Debug, Clone)]
+pub struct MockAnswer {
+    rcode: ResponseCode,
+    answers: Vec<Record>,
+    delay: Option<Duration>,
+    truncate_udp: bool,
+    respond: bool,
+}
+
+impl MockAnswer {
+    /// NOERROR with `answers`. RRSIG records are only sent to queries with
+    /// the DO bit set, like a signed authoritative server.
+    pub fn records(answers: impl IntoIterator<Item = Record>) -> Self {
+        Self {
+            rcode: ResponseCode::NoError,
+            answers: answers.into_iter().collect(),
+            delay: None,
+            truncate_udp: false,
+            respond: true,
+        }
+    }
+
+    /// An empty answer with `rcode`, e.g. NXDOMAIN or SERVFAIL.
+    pub fn rcode(rcode: ResponseCode) -> Self {
+        Self {
+            rcode,
+            ..Self::records([])
+        }
+    }
+
+    /// Never answered, so the resolver asking times out.
+    pub fn no_response() -> Self {
+        Self {
+            respond: false,
+            ..Self::records([])
+        }
+    }
+
+    /// Waits `delay` before replying.
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    /// Replies to UDP queries with an empty TC=1 response, so the full
+    /// answer is only available over TCP.
+    pub fn truncated_over_udp(mut self) -> Self {
+        self.truncate_udp = true;
+        self
+    }
+}
+
+struct MockState {
+    answers: Mutex<HashMap<(Name, RecordType), MockAnswer>>,
+    fallback: Mutex<MockAnswer>,
+    received: Mutex<Vec<ReceivedQuery>>,
+}
+
+impl MockState {
+    async fn respond(&self, raw: &[u8], transport: Transport) -> Option<Vec<u8>> {
+        let request = Message::from_vec(raw).ok()?;
+        let query = request.queries().first()?.clone();
+        let dnssec_ok = request
+            .extensions()
+            .as_ref()
+            .is_some_and(|edns| edns.flags().dnssec_ok);
+        let key = (query.name().to_lowercase(), query.query_type());
+
+        self.received.lock().unwrap().push(ReceivedQuery {
+            name: key.0.clone(),
+            record_type: key.1,
+            transport,
+            dnssec_ok,
+        });
+        let script = self
+            .answers
+            .lock()
+            .unwrap()
+            .get(&key)
+            .cloned()
+            .unwrap_or_else(|| self.fallback.lock().unwrap().clone());
+
+        if let Some(delay) = script.delay {
+            tokio::time::sleep(delay).await;
+        }
+        if !script.respond {
+            return None;
+        }
+
+        let mut response = Message::new(request.id(), MessageType::Response, OpCode::Query);
+        response.set_recursion_desired(request.recursion_desired());
+        response.set_recursion_available(true);
+        response.set_response_code(script.rcode);
+        response.add_query(query);
+        if transport == Transport::Udp && script.truncate_udp {
+            response.set_truncated(true);
+        } else {
+            response.add_answers(
+                script
+                    .answers
+                    .into_iter()
+                    .filter(|record| dnssec_ok || record.record_type() != RecordType::RRSIG),
+            );
+        }
+        if request.extensions().is_some() {
+            let mut edns = Edns::new();
+            edns.set_max_payload(4096);
+            edns.set_dnssec_ok(dnssec_ok);
+            response.set_edns(edns);
+        }
+        response.to_vec().ok()
+    }
+}
+
+/// In-process upstream DNS server listening on UDP and TCP on the same
+/// ephemeral localhost port. Unscripted queries get NXDOMAIN unless
+/// [`MockUpstream::set_fallback`] says otherwise.
+pub struct MockUpstream {
+    addr: SocketAddr,
+    state: Arc<MockState>,
+    tasks: Vec<JoinHandle<()>>,
+}
+
+impl MockUpstream {
+    pub async fn start() -> io::Result<Self> {
+        let (udp, tcp) = bind_udp_and_tcp().await?;
+        let addr = udp.local_addr()?;
+        let state = Arc::new(MockState {
+            answers: Mutex::new(HashMap::new()),
+            fallback: Mutex::new(MockAnswer::rcode(ResponseCode::NXDomain)),
+            received: Mutex::new(Vec::new()),
+        });
+
+        let tasks = vec![
+            tokio::spawn(serve_udp(Arc::new(udp), state.clone())),
+            tokio::spawn(serve_tcp(tcp, state.clone())),
+        ];
+        Ok(Self { addr, state, tasks })
+    }
+
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Upstream URL for `dns.upstream_servers` or a pool.
+    pub fn url(&self) -> String {
+        format!("udp://{}", self.addr)
+    }
+
+    /// Scripts the reply to `name`/`record_type`, replacing any earlier one.
+    pub fn answer(&self, name: &str, record_type: RecordType, answer: MockAnswer) {
+        self.state
+            .answers
+            .lock()
+            .unwrap()
+            .insert((fqdn(name).to_lowercase(), record_type), answer);
+    }
+
+    /// Reply to every query that has no scripted answer.
+    pub fn set_fallback(&self, answer: MockAnswer) {
+        *self.state.fallback.lock().unwrap() = answer;
+    }
+
+    /// Every query received so far.
+    pub fn queries(&self) -> Vec<ReceivedQuery> {
+        self.state.received.lock().unwrap().clone()
+    }
+
+    /// How many times `name`/`record_type` was asked, over any transport.
+    pub fn query_count(&self, name: &str, record_type: RecordType) -> usize {
+        let name = fqdn(name).to_lowercase();
+        self.state
+            .received
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|q| q.name == name && q.record_type == record_type)
+            .count()
+    }
+}
+
+impl Drop for MockUpstream {
+    fn drop(&mut self) {
+        for task in &self.tasks {
+            task.abort();
+        }
+    }
+}
+
+async fn bind_udp_and_tcp() -> io::Result<(UdpSocket, TcpListener)> {
+    let mut last_error = None;
+    for _ in 0..BIND_ATTEMPTS {
+        let udp = UdpSocket::bind(("127.0.0.1", 0)).await?;
+        match TcpListener::bind(udp.local_addr()?).await {
+            Ok(tcp) => return Ok((udp, tcp)),
+            Err(e) => last_error = Some(e),
+        }
+    }
+    Err(last_error.unwrap_or_else(|| io::Error::other("no free port")))
+}
+
+async fn serve_udp(socket: Arc<UdpSocket>, state: Arc<MockState>) {
+    let mut buf = [0u8; 4096];
+    while let Ok((len, peer)) = socket.recv_from(&mut buf).await {
+        let raw = buf[..len].to_vec();
+        let socket = socket.clone();
+        let state = state.clone();
+        tokio::spawn(async move {
+            if let Some(reply) = state.respond(&raw, Transport::Udp).await {
+                let _ = socket.send_to(&reply, peer).await;
+            }
+        });
+    }
+}
+
+async fn serve_tcp(listener: TcpListener, state: Arc<MockState>) {
+    while let Ok((stream, _)) = listener.accept().await {
+        tokio::spawn(serve_tcp_connection(stream, state.clone()));
+    }
+}
+
+async fn serve_tcp_connection(mut stream: TcpStream, state: Arc<MockState>) {
+    loop {
+        let Ok(len) = stream.read_u16().await else {
+            return;
+        };
+        let mut raw = vec![0u8; len as usize];
+        if stream.read_exact(&mut raw).await.is_err() {
+            return;
+        }
+        let Some(reply) = state.respond(&raw, Transport::Tcp).await else {
+            continue;
+        };
+        let Ok(reply_len) = u16::try_from(reply.len()) else {
+            return;
+        };
+        if stream.write_u16(reply_len).await.is_err() || stream.write_all(&reply).await.is_err() {
+            return;
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use hickory_proto::rr::rdata::{A, AAAA, CNAME};
+use hickory_proto::rr::{Name, RData, Record};
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// Parses `name` as a fully qualified name, with or without the root dot.
+pub(crate) fn fqdn(name: &str) -> Name {
+    let mut parsed =
+        Name::from_ascii(name).unwrap_or_else(|e| panic!("invalid domain name {name}: {e}"));
+    parsed.set_fqdn(true);
+    parsed
+}
+
+pub fn a_record(owner: &str, ttl: u32, ip: Ipv4Addr) -> Record {
+    Record::from_rdata(fqdn(owner), ttl, RData::A(A(ip)))
+}
+
+pub fn aaaa_record(owner: &str, ttl: u32, ip: Ipv6Addr) -> Record {
+    Record::from_rdata(fqdn(owner), ttl, RData::AAAA(AAAA(ip)))
+}
+
+pub fn cname_record(owner: &str, ttl: u32, target: &str) -> Record {
+    Record::from_rdata(fqdn(owner), ttl, RData::CNAME(CNAME(fqdn(target))))
+}
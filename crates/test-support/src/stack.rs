@@ -0,0 +1,164 @@
+use crate::client::{query_tcp, query_udp};
+use crate::mock_upstream::MockUpstream;
+use ferrous_dns::{bootstrap, server, wiring};
+use ferrous_dns_application::ports::{BlockFilterEnginePort, ManagedDomainRepository};
+use ferrous_dns_domain::{Config, DomainAction};
+use ferrous_dns_infrastructure::dns::server::DnsServerHandler;
+use ferrous_dns_infrastructure::dns::ChaosIdentity;
+use hickory_proto::op::Message;
+use hickory_proto::rr::RecordType;
+use std::net::{SocketAddr, TcpListener, UdpSocket};
+use std::sync::Arc;
+use std::time::Duration;
+use tempfile::TempDir;
+use tokio::task::JoinHandle;
+
+const DEFAULT_GROUP_ID: i64 = 1;
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A Ferrous DNS server with the full resolver stack, listening on UDP and
+/// TCP on an ephemeral localhost port with its own SQLite database.
+///
+/// Background jobs (health checks, list refreshes, log retention) are not
+/// started, so the stack only talks to the upstreams it is queried through.
+pub struct FerrousStack {
+    addr: SocketAddr,
+    repos: wiring::Repositories,
+    server: JoinHandle<anyhow::Result<()>>,
+    _data_dir: TempDir,
+}
+
+impl FerrousStack {
+    /// Default config forwarding every query to `upstreams`, with a one
+    /// second upstream timeout and the query log off.
+    pub fn config(upstreams: &[&MockUpstream]) -> Config {
+        let mut config = Config::default();
+        config.dns.upstream_servers = upstreams.iter().map(|upstream| upstream.url()).collect();
+        config.dns.pools.clear();
+        config.dns.query_timeout = 1;
+        config.database.log_queries = false;
+        config
+    }
+
+    pub async fn start(mut config: Config) -> anyhow::Result<Self> {
+        let data_dir = tempfile::tempdir()?;
+        config.database.path = data_dir
+            .path()
+            .join("ferrous-dns.db")
+            .to_string_lossy()
+            .into_owned();
+        config.normalize_pools();
+        config.validate()?;
+
+        ferrous_dns_infrastructure::dns::cache::coarse_clock::start_clock_ticker();
+
+        let database_url = format!("sqlite:{}", config.database.path);
+        let (write_pool, query_log_pool, read_pool) =
+            bootstrap::init_database(&database_url, &config.database).await?;
+        let repos = wiring::Repositories::new(
+            write_pool,
+            query_log_pool,
+            read_pool,
+            &config.database,
+            &config.blocking,
+        )
+        .await?;
+        let dns_services = wiring::DnsServices::new(&config, &repos).await?;
+
+        let handler = DnsServerHandler::new(dns_services.handler_use_case.clone())
+            .with_listener_policy(wiring::default_listener_policy(
+                &config,
+                &dns_services.access_control,
+            ))
+            .with_sinkhole(dns_services.sinkhole.clone())
+            .with_chaos_identity(
+                ChaosIdentity::from_config(&config.dns.chaos_identity).map(Arc::new),
+            )
+            .with_response_limits(&config.dns.response_limits);
+
+        let addr = reserve_local_port()?;
+        let server = tokio::spawn(server::start_dns_server(
+            addr.to_string(),
+            handler,
+            1,
+            false,
+            Vec::new(),
+            dns_services.tcp_conn_limiter.clone(),
+        ));
+        wait_until_listening(addr, &server).await?;
+
+        Ok(Self {
+            addr,
+            repos,
+            server,
+            _data_dir: data_dir,
+        })
+    }
+
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    pub async fn query(&self, name: &str, record_type: RecordType) -> anyhow::Result<Message> {
+        query_udp(self.addr, name, record_type).await
+    }
+
+    pub async fn query_tcp(&self, name: &str, record_type: RecordType) -> anyhow::Result<Message> {
+        query_tcp(self.addr, name, record_type).await
+    }
+
+    /// Adds `domain` to the default group's deny list, as the managed
+    /// domains API does, and rebuilds the block index.
+    pub async fn block(&self, domain: &str) -> anyhow::Result<()> {
+        self.repos
+            .managed_domain
+            .create(
+                domain.to_string(),
+                domain.to_string(),
+                DomainAction::Deny,
+                DEFAULT_GROUP_ID,
+                None,
+                true,
+            )
+            .await?;
+        self.repos.block_filter_engine.reload().await?;
+        Ok(())
+    }
+}
+
+impl Drop for FerrousStack {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
+
+/// A localhost port that was free for both UDP and TCP a moment ago. The
+/// server binds it with SO_REUSEPORT, so it cannot report an ephemeral
+/// port back itself.
+fn reserve_local_port() -> anyhow::Result<SocketAddr> {
+    for _ in 0..16 {
+        let udp = UdpSocket::bind(("127.0.0.1", 0))?;
+        let addr = udp.local_addr()?;
+        if TcpListener::bind(addr).is_ok() {
+            return Ok(addr);
+        }
+    }
+    anyhow::bail!("no localhost port free for both UDP and TCP")
+}
+
+async fn wait_until_listening(
+    addr: SocketAddr,
+    server: &JoinHandle<anyhow::Result<()>>,
+) -> anyhow::Result<()> {
+    let deadline = tokio::time::Instant::now() + STARTUP_TIMEOUT;
+    while tokio::time::Instant::now() < deadline {
+        if server.is_finished() {
+            anyhow::bail!("DNS server on {addr} stopped during startup");
+        }
+        if tokio::net::TcpStream::connect(addr).await.is_ok() {
+            return Ok(());
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    anyhow::bail!("DNS server on {addr} did not start within {STARTUP_TIMEOUT:?}")
+}
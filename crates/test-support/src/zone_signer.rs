@@ -0,0 +1,74 @@
+use hickory_proto::dnssec::crypto::Ed25519SigningKey;
+use hickory_proto::dnssec::rdata::{DNSSECRData, DNSKEY, RRSIG};
+use hickory_proto::dnssec::{Algorithm, PublicKey, PublicKeyBuf, SigSigner, SigningKey};
+use hickory_proto::rr::{DNSClass, Name, RData, Record, RecordSet};
+use time::OffsetDateTime;
+
+use crate::records::fqdn;
+
+/// How long signatures stay valid; inception is backdated by five minutes
+/// to tolerate clock skew.
+const SIGNATURE_VALIDITY: std::time::Duration = std::time::Duration::from_secs(86_400);
+
+/// Ed25519 key for one zone, used to serve DNSSEC-signed answers from a
+/// [`MockUpstream`](crate::MockUpstream).
+pub struct ZoneSigner {
+    zone: Name,
+    dnskey: DNSKEY,
+    signer: SigSigner,
+}
+
+impl ZoneSigner {
+    /// Generates a fresh key-signing key for `zone`.
+    pub fn generate(zone: &str) -> Self {
+        let zone = fqdn(zone);
+        let pkcs8 = Ed25519SigningKey::generate_pkcs8().expect("generate Ed25519 key");
+        let signing_key = Ed25519SigningKey::from_pkcs8(&pkcs8).expect("load Ed25519 key");
+        let public_key = signing_key.to_public_key().expect("derive public key");
+        let dnskey = DNSKEY::with_flags(
+            257,
+            PublicKeyBuf::new(public_key.public_bytes().to_vec(), Algorithm::ED25519),
+        );
+        let signer = SigSigner::dnssec(
+            dnskey.clone(),
+            Box::new(signing_key),
+            zone.clone(),
+            SIGNATURE_VALIDITY,
+        );
+        Self {
+            zone,
+            dnskey,
+            signer,
+        }
+    }
+
+    /// The zone's DNSKEY record, unsigned.
+    pub fn dnskey_record(&self, ttl: u32) -> Record {
+        Record::from_rdata(
+            self.zone.clone(),
+            ttl,
+            RData::DNSSEC(DNSSECRData::DNSKEY(self.dnskey.clone())),
+        )
+    }
+
+    /// `records` — one RRset — followed by their RRSIG.
+    pub fn sign(&self, mut records: Vec<Record>) -> Vec<Record> {
+        let first = records.first().expect("at least one record to sign");
+        let (name, record_type, ttl) = (first.name().clone(), first.record_type(), first.ttl());
+
+        let mut rrset = RecordSet::new(name.clone(), record_type, 0);
+        for record in &records {
+            rrset.insert(record.clone(), 0);
+        }
+        let inception = OffsetDateTime::now_utc() - time::Duration::minutes(5);
+        let rrsig = RRSIG::from_rrset(&rrset, DNSClass::IN, inception, &self.signer)
+            .expect("sign record set");
+
+        records.push(Record::from_rdata(
+            name,
+            ttl,
+            RData::DNSSEC(DNSSECRData::RRSIG(rrsig)),
+        ));
+        records
+    }
+}
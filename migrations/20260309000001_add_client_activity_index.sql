@@ -0,0 +1,2 @@
+CREATE INDEX IF NOT EXISTS idx_query_log_client_created
+    ON query_log(client_ip, created_at DESC, blocked, cache_hit);
@@ -0,0 +1,19 @@
+CREATE TABLE IF NOT EXISTS devices (
+    id          INTEGER  PRIMARY KEY AUTOINCREMENT,
+    mac_address TEXT     NOT NULL UNIQUE,
+    current_ip  TEXT,
+    first_seen  DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    last_seen   DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE IF NOT EXISTS device_ip_history (
+    device_id  INTEGER  NOT NULL REFERENCES devices(id) ON DELETE CASCADE,
+    ip_address TEXT     NOT NULL,
+    first_seen DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    last_seen  DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (device_id, ip_address)
+);
+
+CREATE INDEX IF NOT EXISTS idx_clients_mac
+    ON clients(mac_address)
+    WHERE mac_address IS NOT NULL;
@@ -0,0 +1,3 @@
+ALTER TABLE clients ADD COLUMN display_name TEXT;
+ALTER TABLE clients ADD COLUMN category TEXT;
+ALTER TABLE clients ADD COLUMN notes TEXT;
@@ -0,0 +1,20 @@
+CREATE TABLE IF NOT EXISTS query_policies (
+    id             INTEGER PRIMARY KEY AUTOINCREMENT,
+    name           TEXT    NOT NULL,
+    priority       INTEGER NOT NULL DEFAULT 100,
+    enabled        BOOLEAN NOT NULL DEFAULT 1,
+    client_cidr    TEXT,
+    group_id       INTEGER REFERENCES groups(id) ON DELETE CASCADE,
+    domain_pattern TEXT,
+    record_types   TEXT,
+    days           INTEGER,
+    start_time     TEXT,
+    end_time       TEXT,
+    action         TEXT    NOT NULL,
+    action_target  TEXT,
+    comment        TEXT,
+    created_at     DATETIME DEFAULT CURRENT_TIMESTAMP,
+    updated_at     DATETIME DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_query_policies_order ON query_policies(priority, id);
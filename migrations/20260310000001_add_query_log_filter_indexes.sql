@@ -0,0 +1,13 @@
+CREATE INDEX IF NOT EXISTS idx_query_log_source_type_created
+    ON query_log(query_source, record_type, created_at DESC);
+
+CREATE INDEX IF NOT EXISTS idx_query_log_source_upstream_created
+    ON query_log(query_source, upstream_server, created_at DESC);
+
+CREATE INDEX IF NOT EXISTS idx_query_log_source_dnssec_created
+    ON query_log(query_source, dnssec_status, created_at DESC);
+
+CREATE INDEX IF NOT EXISTS idx_query_log_source_blocked_created
+    ON query_log(query_source, blocked, created_at DESC);
+
+ANALYZE query_log;
@@ -0,0 +1,13 @@
+CREATE TABLE IF NOT EXISTS alerts (
+    id         INTEGER PRIMARY KEY AUTOINCREMENT,
+    client_ip  TEXT    NOT NULL,
+    kind       TEXT    NOT NULL,
+    score      REAL    NOT NULL,
+    threshold  REAL    NOT NULL,
+    domain     TEXT,
+    details    TEXT    NOT NULL,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_alerts_created_at ON alerts(created_at);
+CREATE INDEX IF NOT EXISTS idx_alerts_client_ip ON alerts(client_ip, created_at);
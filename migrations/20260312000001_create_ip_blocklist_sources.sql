@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS ip_blocklist_sources (
+    id          INTEGER PRIMARY KEY AUTOINCREMENT,
+    name        TEXT    NOT NULL UNIQUE,
+    url         TEXT    NOT NULL,
+    comment     TEXT,
+    enabled     BOOLEAN NOT NULL DEFAULT 1,
+    created_at  DATETIME DEFAULT CURRENT_TIMESTAMP,
+    updated_at  DATETIME DEFAULT CURRENT_TIMESTAMP
+);
+CREATE INDEX IF NOT EXISTS idx_ip_blocklist_sources_enabled
+    ON ip_blocklist_sources(enabled);
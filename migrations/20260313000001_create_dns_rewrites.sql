@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS dns_rewrites (
+    id          INTEGER PRIMARY KEY AUTOINCREMENT,
+    domain      TEXT    NOT NULL,
+    answer      TEXT    NOT NULL,
+    enabled     BOOLEAN NOT NULL DEFAULT 1,
+    comment     TEXT,
+    created_at  DATETIME DEFAULT CURRENT_TIMESTAMP,
+    updated_at  DATETIME DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(domain, answer)
+);
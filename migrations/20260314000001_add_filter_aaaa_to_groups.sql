@@ -0,0 +1 @@
+ALTER TABLE groups ADD COLUMN filter_aaaa BOOLEAN NOT NULL DEFAULT 0;
@@ -0,0 +1,3 @@
+ALTER TABLE query_log ADD COLUMN upstream_strategy TEXT;
+ALTER TABLE query_log ADD COLUMN upstream_attempt INTEGER;
+ALTER TABLE query_log ADD COLUMN upstream_protocol TEXT;
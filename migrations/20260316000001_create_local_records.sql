@@ -0,0 +1,13 @@
+CREATE TABLE IF NOT EXISTS local_records (
+    id          INTEGER PRIMARY KEY AUTOINCREMENT,
+    hostname    TEXT    NOT NULL,
+    domain      TEXT,
+    record_type TEXT    NOT NULL,
+    value       TEXT    NOT NULL,
+    ttl         INTEGER NOT NULL DEFAULT 300,
+    view        TEXT,
+    created_at  DATETIME DEFAULT CURRENT_TIMESTAMP,
+    updated_at  DATETIME DEFAULT CURRENT_TIMESTAMP
+);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_local_records_unique
+    ON local_records(hostname, COALESCE(domain, ''), record_type, value, COALESCE(view, ''));
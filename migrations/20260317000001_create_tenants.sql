@@ -0,0 +1,15 @@
+CREATE TABLE IF NOT EXISTS tenants (
+    id               INTEGER PRIMARY KEY AUTOINCREMENT,
+    name             TEXT    NOT NULL UNIQUE,
+    listener         TEXT    UNIQUE,
+    default_group_id INTEGER REFERENCES groups(id) ON DELETE SET NULL,
+    comment          TEXT,
+    created_at       DATETIME DEFAULT CURRENT_TIMESTAMP,
+    updated_at       DATETIME DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE IF NOT EXISTS tenant_subnets (
+    tenant_id   INTEGER NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    subnet_cidr TEXT    NOT NULL UNIQUE,
+    PRIMARY KEY (tenant_id, subnet_cidr)
+);
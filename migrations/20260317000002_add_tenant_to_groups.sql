@@ -0,0 +1,3 @@
+ALTER TABLE groups ADD COLUMN tenant_id INTEGER REFERENCES tenants(id);
+
+CREATE INDEX IF NOT EXISTS idx_groups_tenant ON groups(tenant_id) WHERE tenant_id IS NOT NULL;
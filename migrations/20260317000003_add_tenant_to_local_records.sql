@@ -0,0 +1,6 @@
+ALTER TABLE local_records ADD COLUMN tenant_id INTEGER REFERENCES tenants(id) ON DELETE CASCADE;
+
+DROP INDEX IF EXISTS idx_local_records_unique;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_local_records_unique
+    ON local_records(hostname, COALESCE(domain, ''), record_type, value, COALESCE(view, ''),
+                     COALESCE(tenant_id, 0));
@@ -0,0 +1 @@
+ALTER TABLE api_tokens ADD COLUMN tenant_id INTEGER REFERENCES tenants(id) ON DELETE CASCADE;
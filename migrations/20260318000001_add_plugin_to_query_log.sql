@@ -0,0 +1 @@
+ALTER TABLE query_log ADD COLUMN plugin TEXT;
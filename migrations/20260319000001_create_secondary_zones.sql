@@ -0,0 +1,17 @@
+CREATE TABLE IF NOT EXISTS secondary_zones (
+    zone           TEXT PRIMARY KEY,
+    serial         INTEGER NOT NULL,
+    refresh        INTEGER NOT NULL,
+    retry          INTEGER NOT NULL,
+    expire         INTEGER NOT NULL,
+    transferred_at INTEGER NOT NULL,
+    checked_at     INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS secondary_zone_records (
+    id     INTEGER PRIMARY KEY AUTOINCREMENT,
+    zone   TEXT NOT NULL REFERENCES secondary_zones(zone) ON DELETE CASCADE,
+    record BLOB NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_secondary_zone_records_zone ON secondary_zone_records(zone);
@@ -0,0 +1,2 @@
+ALTER TABLE blocklist_sources ADD COLUMN audit_mode BOOLEAN NOT NULL DEFAULT 0;
+ALTER TABLE groups ADD COLUMN audit_mode BOOLEAN NOT NULL DEFAULT 0;
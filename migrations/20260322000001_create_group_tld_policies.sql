@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS group_tld_policies (
+    id         INTEGER PRIMARY KEY AUTOINCREMENT,
+    group_id   INTEGER NOT NULL UNIQUE REFERENCES groups(id) ON DELETE CASCADE,
+    mode       TEXT    NOT NULL CHECK(mode IN ('allow','deny')),
+    tlds       TEXT    NOT NULL,
+    created_at TEXT    NOT NULL,
+    updated_at TEXT    NOT NULL
+);
@@ -0,0 +1,5 @@
+CREATE TABLE IF NOT EXISTS upstream_addresses (
+    hostname   TEXT PRIMARY KEY,
+    addresses  TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
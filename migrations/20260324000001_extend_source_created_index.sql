@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS idx_query_log_source_created;
+CREATE INDEX idx_query_log_source_created
+    ON query_log(query_source, created_at DESC, blocked, record_type, cache_hit, group_id, client_ip);
+
+ANALYZE query_log;
@@ -0,0 +1,17 @@
+-- Per-minute aggregates of timed client queries, kept up to date by the query
+-- log writer so dashboard stats and latency percentiles skip query_log scans.
+CREATE TABLE IF NOT EXISTS query_stats_rollup (
+    bucket            TEXT    PRIMARY KEY,
+    queries           INTEGER NOT NULL DEFAULT 0,
+    blocked           INTEGER NOT NULL DEFAULT 0,
+    rate_limited      INTEGER NOT NULL DEFAULT 0,
+    cache_hits        INTEGER NOT NULL DEFAULT 0,
+    local_dns         INTEGER NOT NULL DEFAULT 0,
+    any_minimized     INTEGER NOT NULL DEFAULT 0,
+    time_sum          INTEGER NOT NULL DEFAULT 0,
+    cache_time_sum    INTEGER NOT NULL DEFAULT 0,
+    upstream_queries  INTEGER NOT NULL DEFAULT 0,
+    upstream_time_sum INTEGER NOT NULL DEFAULT 0,
+    latency_histogram BLOB,
+    last_id           INTEGER NOT NULL DEFAULT 0
+) WITHOUT ROWID;
@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS cache_ttl_overrides (
+    id          INTEGER PRIMARY KEY AUTOINCREMENT,
+    domain      TEXT    NOT NULL UNIQUE,
+    min_ttl     INTEGER,
+    max_ttl     INTEGER,
+    enabled     BOOLEAN NOT NULL DEFAULT 1,
+    comment     TEXT,
+    created_at  DATETIME DEFAULT CURRENT_TIMESTAMP,
+    updated_at  DATETIME DEFAULT CURRENT_TIMESTAMP
+);
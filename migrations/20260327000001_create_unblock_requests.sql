@@ -0,0 +1,12 @@
+CREATE TABLE IF NOT EXISTS unblock_requests (
+    id            INTEGER PRIMARY KEY AUTOINCREMENT,
+    domain        TEXT    NOT NULL,
+    client_ip     TEXT    NOT NULL,
+    group_id      INTEGER,
+    block_source  TEXT,
+    message       TEXT,
+    created_at    DATETIME DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (domain, client_ip)
+);
+
+CREATE INDEX IF NOT EXISTS idx_unblock_requests_created_at ON unblock_requests(created_at);
@@ -0,0 +1,11 @@
+-- Unblock requests are now reviewed: approved ones become an allow entry
+-- for the requesting group, denied ones stay on record.
+ALTER TABLE unblock_requests RENAME TO pending_requests;
+
+ALTER TABLE pending_requests ADD COLUMN status TEXT NOT NULL DEFAULT 'pending';
+ALTER TABLE pending_requests ADD COLUMN managed_domain_id INTEGER;
+ALTER TABLE pending_requests ADD COLUMN reviewed_at DATETIME;
+
+DROP INDEX IF EXISTS idx_unblock_requests_created_at;
+CREATE INDEX IF NOT EXISTS idx_pending_requests_created_at ON pending_requests(created_at);
+CREATE INDEX IF NOT EXISTS idx_pending_requests_status ON pending_requests(status);
@@ -0,0 +1,26 @@
+-- DNSSEC validation history written by the DNSSEC history job: outcome
+-- counts per interval, and every change of a name's validation status.
+CREATE TABLE IF NOT EXISTS dnssec_stats (
+    id            INTEGER PRIMARY KEY AUTOINCREMENT,
+    secure        INTEGER NOT NULL DEFAULT 0,
+    insecure      INTEGER NOT NULL DEFAULT 0,
+    bogus         INTEGER NOT NULL DEFAULT 0,
+    indeterminate INTEGER NOT NULL DEFAULT 0,
+    recorded_at   DATETIME DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_dnssec_stats_recorded_at ON dnssec_stats(recorded_at);
+
+CREATE TABLE IF NOT EXISTS dnssec_status_changes (
+    id              INTEGER PRIMARY KEY AUTOINCREMENT,
+    domain          TEXT    NOT NULL,
+    record_type     TEXT    NOT NULL,
+    status          TEXT    NOT NULL,
+    previous_status TEXT,
+    observed_at     DATETIME DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_dnssec_status_changes_domain
+    ON dnssec_status_changes(domain, observed_at);
+CREATE INDEX IF NOT EXISTS idx_dnssec_status_changes_status
+    ON dnssec_status_changes(status, observed_at);
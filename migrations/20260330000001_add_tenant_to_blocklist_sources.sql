@@ -0,0 +1,4 @@
+ALTER TABLE blocklist_sources ADD COLUMN tenant_id INTEGER REFERENCES tenants(id) ON DELETE CASCADE;
+
+CREATE INDEX IF NOT EXISTS idx_blocklist_sources_tenant
+    ON blocklist_sources(tenant_id) WHERE tenant_id IS NOT NULL;
@@ -0,0 +1,8 @@
+ALTER TABLE query_log ADD COLUMN tenant_id INTEGER;
+
+UPDATE query_log
+SET tenant_id = (SELECT g.tenant_id FROM groups g WHERE g.id = query_log.group_id)
+WHERE group_id IS NOT NULL;
+
+CREATE INDEX IF NOT EXISTS idx_query_log_tenant_created
+    ON query_log(tenant_id, created_at DESC) WHERE tenant_id IS NOT NULL;
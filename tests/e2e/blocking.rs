@@ -0,0 +1,52 @@
+use ferrous_dns_domain::BlockingMode;
+use ferrous_dns_test_support::{a_record, addresses, FerrousStack, MockAnswer, MockUpstream};
+use hickory_proto::op::ResponseCode;
+use hickory_proto::rr::RecordType;
+use std::net::{IpAddr, Ipv4Addr};
+
+fn upstream_with(upstream: &MockUpstream, names: &[&str]) {
+    for name in names {
+        upstream.answer(
+            name,
+            RecordType::A,
+            MockAnswer::records([a_record(name, 300, Ipv4Addr::new(192, 0, 2, 20))]),
+        );
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn blocked_domain_is_refused_without_reaching_upstream() {
+    let upstream = MockUpstream::start().await.unwrap();
+    upstream_with(&upstream, &["ads.example", "news.example"]);
+    let stack = FerrousStack::start(FerrousStack::config(&[&upstream]))
+        .await
+        .unwrap();
+    stack.block("ads.example").await.unwrap();
+
+    let blocked = stack.query("ads.example", RecordType::A).await.unwrap();
+    assert_eq!(blocked.response_code(), ResponseCode::Refused);
+    assert!(blocked.answers().is_empty());
+    assert_eq!(upstream.query_count("ads.example", RecordType::A), 0);
+
+    let allowed = stack.query("news.example", RecordType::A).await.unwrap();
+    assert_eq!(allowed.response_code(), ResponseCode::NoError);
+    assert_eq!(upstream.query_count("news.example", RecordType::A), 1);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn sinkhole_mode_answers_blocked_queries_with_the_sinkhole_address() {
+    let sinkhole = Ipv4Addr::new(10, 0, 0, 53);
+    let upstream = MockUpstream::start().await.unwrap();
+    upstream_with(&upstream, &["tracker.example"]);
+    let mut config = FerrousStack::config(&[&upstream]);
+    config.blocking.mode = BlockingMode::Sinkhole;
+    config.blocking.sinkhole.ipv4 = Some(sinkhole);
+    let stack = FerrousStack::start(config).await.unwrap();
+    stack.block("tracker.example").await.unwrap();
+
+    let response = stack.query("tracker.example", RecordType::A).await.unwrap();
+
+    assert_eq!(response.response_code(), ResponseCode::NoError);
+    assert_eq!(addresses(&response), vec![IpAddr::V4(sinkhole)]);
+    assert_eq!(upstream.query_count("tracker.example", RecordType::A), 0);
+}
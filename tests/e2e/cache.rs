@@ -0,0 +1,68 @@
+use ferrous_dns_test_support::{a_record, addresses, FerrousStack, MockAnswer, MockUpstream};
+use hickory_proto::op::ResponseCode;
+use hickory_proto::rr::RecordType;
+use std::net::{IpAddr, Ipv4Addr};
+
+const ADDR: Ipv4Addr = Ipv4Addr::new(192, 0, 2, 10);
+
+#[tokio::test(flavor = "multi_thread")]
+async fn repeated_queries_are_answered_from_cache() {
+    let upstream = MockUpstream::start().await.unwrap();
+    upstream.answer(
+        "cached.example",
+        RecordType::A,
+        MockAnswer::records([a_record("cached.example", 300, ADDR)]),
+    );
+    let stack = FerrousStack::start(FerrousStack::config(&[&upstream]))
+        .await
+        .unwrap();
+
+    for _ in 0..3 {
+        let response = stack.query("cached.example", RecordType::A).await.unwrap();
+        assert_eq!(response.response_code(), ResponseCode::NoError);
+        assert_eq!(addresses(&response), vec![IpAddr::V4(ADDR)]);
+    }
+    let response = stack
+        .query_tcp("cached.example", RecordType::A)
+        .await
+        .unwrap();
+    assert_eq!(addresses(&response), vec![IpAddr::V4(ADDR)]);
+
+    assert_eq!(upstream.query_count("cached.example", RecordType::A), 1);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn cache_disabled_forwards_every_query() {
+    let upstream = MockUpstream::start().await.unwrap();
+    upstream.answer(
+        "uncached.example",
+        RecordType::A,
+        MockAnswer::records([a_record("uncached.example", 300, ADDR)]),
+    );
+    let mut config = FerrousStack::config(&[&upstream]);
+    config.dns.cache_enabled = false;
+    let stack = FerrousStack::start(config).await.unwrap();
+
+    for _ in 0..2 {
+        let response = stack
+            .query("uncached.example", RecordType::A)
+            .await
+            .unwrap();
+        assert_eq!(addresses(&response), vec![IpAddr::V4(ADDR)]);
+    }
+
+    assert_eq!(upstream.query_count("uncached.example", RecordType::A), 2);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn nxdomain_is_passed_to_the_client() {
+    let upstream = MockUpstream::start().await.unwrap();
+    let stack = FerrousStack::start(FerrousStack::config(&[&upstream]))
+        .await
+        .unwrap();
+
+    let response = stack.query("missing.example", RecordType::A).await.unwrap();
+
+    assert_eq!(response.response_code(), ResponseCode::NXDomain);
+    assert!(response.answers().is_empty());
+}
@@ -0,0 +1,61 @@
+use ferrous_dns_test_support::{
+    a_record, addresses, FerrousStack, MockAnswer, MockUpstream, ZoneSigner,
+};
+use hickory_proto::rr::RecordType;
+use std::net::{IpAddr, Ipv4Addr};
+
+const ADDR: Ipv4Addr = Ipv4Addr::new(192, 0, 2, 30);
+
+/// Serves a signed A record and the zone's DNSKEY. The mock holds no DS
+/// for the zone, so the chain from the root cannot be completed and the
+/// answer is still delivered, unvalidated.
+fn signed_upstream(upstream: &MockUpstream) {
+    let signer = ZoneSigner::generate("secure.example");
+    upstream.answer(
+        "secure.example",
+        RecordType::A,
+        MockAnswer::records(signer.sign(vec![a_record("secure.example", 300, ADDR)])),
+    );
+    upstream.answer(
+        "secure.example",
+        RecordType::DNSKEY,
+        MockAnswer::records(signer.sign(vec![signer.dnskey_record(3600)])),
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn validation_requests_dnssec_records_and_walks_the_chain() {
+    let upstream = MockUpstream::start().await.unwrap();
+    signed_upstream(&upstream);
+    let mut config = FerrousStack::config(&[&upstream]);
+    config.dns.dnssec_enabled = true;
+    let stack = FerrousStack::start(config).await.unwrap();
+
+    let response = stack.query("secure.example", RecordType::A).await.unwrap();
+
+    assert_eq!(addresses(&response), vec![IpAddr::V4(ADDR)]);
+    let queries = upstream.queries();
+    assert!(queries
+        .iter()
+        .filter(|q| q.record_type == RecordType::A)
+        .all(|q| q.dnssec_ok));
+    assert!(upstream.query_count("example", RecordType::DS) >= 1);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn validation_disabled_leaves_the_do_bit_clear() {
+    let upstream = MockUpstream::start().await.unwrap();
+    signed_upstream(&upstream);
+    let stack = FerrousStack::start(FerrousStack::config(&[&upstream]))
+        .await
+        .unwrap();
+
+    let response = stack.query("secure.example", RecordType::A).await.unwrap();
+
+    assert_eq!(addresses(&response), vec![IpAddr::V4(ADDR)]);
+    let queries = upstream.queries();
+    assert!(queries.iter().all(|q| !q.dnssec_ok));
+    assert!(queries
+        .iter()
+        .all(|q| !matches!(q.record_type, RecordType::DS | RecordType::DNSKEY)));
+}
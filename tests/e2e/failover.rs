@@ -0,0 +1,71 @@
+use ferrous_dns_domain::UpstreamStrategy;
+use ferrous_dns_test_support::{a_record, addresses, FerrousStack, MockAnswer, MockUpstream};
+use hickory_proto::op::ResponseCode;
+use hickory_proto::rr::RecordType;
+use std::net::{IpAddr, Ipv4Addr};
+use std::time::Duration;
+
+const PRIMARY_ADDR: Ipv4Addr = Ipv4Addr::new(192, 0, 2, 1);
+const SECONDARY_ADDR: Ipv4Addr = Ipv4Addr::new(192, 0, 2, 2);
+
+async fn start_failover_stack(primary: &MockUpstream, secondary: &MockUpstream) -> FerrousStack {
+    let mut config = FerrousStack::config(&[primary, secondary]);
+    config.dns.default_strategy = UpstreamStrategy::Failover;
+    FerrousStack::start(config).await.unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn healthy_primary_answers_alone() {
+    let primary = MockUpstream::start().await.unwrap();
+    let secondary = MockUpstream::start().await.unwrap();
+    primary.answer(
+        "app.example",
+        RecordType::A,
+        MockAnswer::records([a_record("app.example", 300, PRIMARY_ADDR)]),
+    );
+    let stack = start_failover_stack(&primary, &secondary).await;
+
+    let response = stack.query("app.example", RecordType::A).await.unwrap();
+
+    assert_eq!(addresses(&response), vec![IpAddr::V4(PRIMARY_ADDR)]);
+    assert!(secondary.queries().is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn slow_primary_fails_over_to_secondary() {
+    let primary = MockUpstream::start().await.unwrap();
+    let secondary = MockUpstream::start().await.unwrap();
+    primary.answer(
+        "app.example",
+        RecordType::A,
+        MockAnswer::records([a_record("app.example", 300, PRIMARY_ADDR)])
+            .with_delay(Duration::from_secs(3)),
+    );
+    secondary.answer(
+        "app.example",
+        RecordType::A,
+        MockAnswer::records([a_record("app.example", 300, SECONDARY_ADDR)]),
+    );
+    let stack = start_failover_stack(&primary, &secondary).await;
+
+    let response = stack.query("app.example", RecordType::A).await.unwrap();
+
+    assert_eq!(addresses(&response), vec![IpAddr::V4(SECONDARY_ADDR)]);
+    assert_eq!(primary.query_count("app.example", RecordType::A), 1);
+    assert_eq!(secondary.query_count("app.example", RecordType::A), 1);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn unreachable_upstreams_answer_servfail() {
+    let primary = MockUpstream::start().await.unwrap();
+    let secondary = MockUpstream::start().await.unwrap();
+    primary.set_fallback(MockAnswer::no_response());
+    secondary.set_fallback(MockAnswer::no_response());
+    let stack = start_failover_stack(&primary, &secondary).await;
+
+    let response = stack.query("down.example", RecordType::A).await.unwrap();
+
+    assert_eq!(response.response_code(), ResponseCode::ServFail);
+    assert_eq!(primary.query_count("down.example", RecordType::A), 1);
+    assert_eq!(secondary.query_count("down.example", RecordType::A), 1);
+}
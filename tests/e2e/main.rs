@@ -0,0 +1,5 @@
+mod blocking;
+mod cache;
+mod dnssec;
+mod failover;
+mod truncation;
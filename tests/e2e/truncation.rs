@@ -0,0 +1,30 @@
+use ferrous_dns_test_support::{
+    a_record, addresses, FerrousStack, MockAnswer, MockUpstream, Transport,
+};
+use hickory_proto::rr::RecordType;
+use std::net::{IpAddr, Ipv4Addr};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn truncated_upstream_answer_is_retried_over_tcp() {
+    let records: Vec<_> = (1..=4)
+        .map(|i| a_record("big.example", 300, Ipv4Addr::new(192, 0, 2, i)))
+        .collect();
+    let upstream = MockUpstream::start().await.unwrap();
+    upstream.answer(
+        "big.example",
+        RecordType::A,
+        MockAnswer::records(records).truncated_over_udp(),
+    );
+    let stack = FerrousStack::start(FerrousStack::config(&[&upstream]))
+        .await
+        .unwrap();
+
+    let response = stack.query("big.example", RecordType::A).await.unwrap();
+
+    let expected: Vec<IpAddr> = (1..=4)
+        .map(|i| IpAddr::V4(Ipv4Addr::new(192, 0, 2, i)))
+        .collect();
+    assert_eq!(addresses(&response), expected);
+    let transports: Vec<Transport> = upstream.queries().iter().map(|q| q.transport).collect();
+    assert_eq!(transports, vec![Transport::Udp, Transport::Tcp]);
+}
@@ -57,7 +57,7 @@ function app() {
                     fetch(`${API_BASE}/services/catalog`).then(r => r.json()),
                     fetch(`${API_BASE}/custom-services`).then(r => r.json()),
                 ]);
-                this.groups = groupsRes || [];
+                this.groups = groupsRes?.data || [];
                 this.catalog = catalogRes || [];
                 this.customServices = customRes || [];
                 if (this.groups.length > 0) {
@@ -38,7 +38,7 @@
             async loadClients() {
                 try {
                     const res = await fetch(`${API_BASE}/clients?limit=1000`);
-                    if (res.ok) this.clients = await res.json();
+                    if (res.ok) this.clients = (await res.json()).data;
                 } catch (e) {
                     console.error('Failed to load clients:', e);
                 }
@@ -48,7 +48,7 @@
                 try {
                     const res = await fetch(`${API_BASE}/groups`);
                     if (res.ok) {
-                        this.groups = await res.json();
+                        this.groups = (await res.json()).data;
                         const defaultGroup = this.groups.find(g => g.name === 'Protected') || this.groups[0];
                         if (defaultGroup && !this.form.group_id) this.form.group_id = defaultGroup.id;
                     }
@@ -79,7 +79,7 @@
                 try {
                     const res = await fetch(`${API_BASE}/blocklist-sources`);
                     if (res.ok) {
-                        this.sources = await res.json();
+                        this.sources = (await res.json()).data;
                         await this.$nextTick();
                         scheduleLucide(0);
                     }
@@ -94,7 +94,7 @@
                 try {
                     const res = await fetch(`${API_BASE}/groups`);
                     if (res.ok) {
-                        this.groups = await res.json();
+                        this.groups = (await res.json()).data;
                     }
                 } catch (e) {
                     console.error('Failed to load groups:', e);
@@ -245,7 +245,7 @@
                 try {
                     const res = await fetch(`${API_BASE}/whitelist-sources`);
                     if (res.ok) {
-                        this.wSources = await res.json();
+                        this.wSources = (await res.json()).data;
                         await this.$nextTick();
                         scheduleLucide(0);
                     }
@@ -25,7 +25,7 @@
                 try {
                     const res = await fetch(`${API_BASE}/groups`);
                     if (res.ok) {
-                        this.groups = await res.json();
+                        this.groups = (await res.json()).data;
                         await this.$nextTick();
                         scheduleLucide(0);
                     }
@@ -4,7 +4,7 @@
             queryRate: {queries: 0, rate: '0 q/s'},
             stats: {queries_total: 0},
             records: [],
-            newRecord: {hostname: '', domain: '', ip: '', record_type: 'A', ttl: 300},
+            newRecord: {hostname: '', domain: '', value: '', record_type: 'A', ttl: 300},
             editingRecord: null,
             addError: '',
             _ctrl: {},
@@ -53,6 +53,16 @@
             get form() {
                 return this.editingRecord ?? this.newRecord;
             },
+            get valuePlaceholder() {
+                return {
+                    A: 'e.g. 10.0.1.1, or {ip} on a wildcard',
+                    AAAA: 'e.g. fd00::1, or {ip} on a wildcard',
+                    CNAME: 'e.g. nas.local',
+                    TXT: 'e.g. v=spf1 -all',
+                    MX: 'e.g. 10 mail.local',
+                    SRV: 'e.g. 0 5 5060 sip.local',
+                }[this.form.record_type] ?? '';
+            },
             async loadRecords() {
                 try {
                     const r = await fetch(`${API_BASE}/local-records`);
@@ -66,21 +76,22 @@
                     id: record.id,
                     hostname: record.hostname,
                     domain: record.domain ?? '',
-                    ip: record.ip,
+                    value: record.value,
                     record_type: record.record_type,
                     ttl: record.ttl,
+                    view: record.view,
                 };
                 this.addError = '';
             },
             cancelEdit() {
                 this.editingRecord = null;
-                this.newRecord = {hostname: '', domain: '', ip: '', record_type: 'A', ttl: 300};
+                this.newRecord = {hostname: '', domain: '', value: '', record_type: 'A', ttl: 300};
                 this.addError = '';
             },
             async updateRecord() {
                 this.addError = '';
-                if (!this.editingRecord.hostname || !this.editingRecord.ip) {
-                    this.addError = 'Hostname and IP address are required.';
+                if (!this.editingRecord.hostname || !this.editingRecord.value) {
+                    this.addError = 'Hostname and value are required.';
                     return;
                 }
                 try {
@@ -90,9 +101,10 @@
                         body: JSON.stringify({
                             hostname: this.editingRecord.hostname,
                             domain: (this.editingRecord.domain || '').trim() || null,
-                            ip: this.editingRecord.ip,
+                            value: this.editingRecord.value,
                             record_type: this.editingRecord.record_type,
-                            ttl: parseInt(this.editingRecord.ttl) || 300
+                            ttl: parseInt(this.editingRecord.ttl) || 300,
+                            view: this.editingRecord.view ?? null
                         })
                     });
                     if (r.ok) {
@@ -109,8 +121,8 @@
             },
             async addRecord() {
                 this.addError = '';
-                if (!this.newRecord.hostname || !this.newRecord.ip) {
-                    this.addError = 'Hostname and IP address are required.';
+                if (!this.newRecord.hostname || !this.newRecord.value) {
+                    this.addError = 'Hostname and value are required.';
                     return;
                 }
                 try {
@@ -120,14 +132,14 @@
                         body: JSON.stringify({
                             hostname: this.newRecord.hostname,
                             domain: (this.newRecord.domain || '').trim() || null,
-                            ip: this.newRecord.ip,
+                            value: this.newRecord.value,
                             record_type: this.newRecord.record_type,
                             ttl: parseInt(this.newRecord.ttl) || 300
                         })
                     });
                     if (r.ok) {
                         await this.loadRecords();
-                        this.newRecord = {hostname: '', domain: '', ip: '', record_type: 'A', ttl: 300};
+                        this.newRecord = {hostname: '', domain: '', value: '', record_type: 'A', ttl: 300};
                         this.$nextTick(() => scheduleLucide());
                     } else {
                         this.addError = await r.text() || 'Failed to add record';
@@ -6,6 +6,15 @@ function loginApp() {
         setupRequired: false,
         setupPassword: '',
         setupConfirm: '',
+        steps: ['Upstream', 'Blocklists', 'Network', 'Password'],
+        step: 0,
+        options: { upstream_presets: [], blocklists: [], interfaces: [] },
+        upstreamPreset: '',
+        blocklists: [],
+        listeners: { bind_address: '0.0.0.0', dns_port: 53, web_port: 8080 },
+        restartNotice: false,
+        restartRequired: false,
+        setupWarnings: [],
         error: '',
         loading: false,
 
@@ -22,6 +31,7 @@ function loginApp() {
                     }
                     this.setupRequired = data.setup_required;
                 }
+                if (this.setupRequired) await this.loadSetupOptions();
             } catch (e) {
                 this.error = 'Cannot connect to server';
                 scheduleLucide(100);
@@ -40,24 +50,57 @@ function loginApp() {
             scheduleLucide(100);
         },
 
-        async setupAdmin() {
+        async loadSetupOptions() {
+            const res = await fetch(`${API_BASE}/setup`);
+            if (!res.ok) return;
+            this.options = await res.json();
+            this.upstreamPreset = this.options.upstream_preset || '';
+            this.blocklists = this.options.blocklists.filter(l => l.recommended).map(l => l.id);
+            this.listeners = { ...this.options.listeners };
+        },
+
+        presetSummary(preset) {
+            return preset.pools
+                .map(p => `${p.name}: ${p.servers.length} server${p.servers.length === 1 ? '' : 's'} (${p.strategy})`)
+                .join(' · ');
+        },
+
+        listenersValid() {
+            const { dns_port, web_port } = this.listeners;
+            return dns_port >= 1 && dns_port <= 65535 && web_port >= 1 && web_port <= 65535 && dns_port !== web_port;
+        },
+
+        async completeSetup() {
             if (!this.setupPassword || this.setupPassword.length < 8 || this.setupPassword !== this.setupConfirm) return;
             this.error = '';
             this.loading = true;
             try {
-                const res = await fetch(`${API_BASE}/auth/setup`, {
+                const res = await fetch(`${API_BASE}/setup`, {
                     method: 'POST',
                     headers: {'Content-Type': 'application/json'},
-                    body: JSON.stringify({password: this.setupPassword})
+                    body: JSON.stringify({
+                        password: this.setupPassword,
+                        upstream_preset: this.upstreamPreset || null,
+                        blocklists: this.blocklists,
+                        listeners: this.listeners
+                    })
                 });
-                if (res.ok || res.status === 204) {
+                if (res.ok) {
+                    const summary = await res.json();
                     this.setupRequired = false;
                     this.password = this.setupPassword;
                     this.username = 'admin';
                     this.setupPassword = '';
                     this.setupConfirm = '';
-                    await this.login();
+                    this.setupWarnings = summary.warnings;
+                    this.restartRequired = summary.restart_required;
+                    const redirect = !summary.restart_required && summary.warnings.length === 0;
+                    await this.login(redirect);
                     this.password = '';
+                    if (!redirect) {
+                        this.restartNotice = true;
+                        scheduleLucide(50);
+                    }
                 } else {
                     const data = await res.json().catch(() => ({}));
                     this.error = data.error || 'Setup failed';
@@ -69,7 +112,7 @@ function loginApp() {
             }
         },
 
-        async login() {
+        async login(redirect = true) {
             if (!this.username || !this.password) return;
             this.error = '';
             this.loading = true;
@@ -85,7 +128,7 @@ function loginApp() {
                 });
                 if (res.ok) {
                     this.password = '';
-                    window.location.href = '/dashboard.html';
+                    if (redirect) window.location.href = '/dashboard.html';
                 } else {
                     const data = await res.json().catch(() => ({}));
                     this.error = data.error || 'Invalid username or password';